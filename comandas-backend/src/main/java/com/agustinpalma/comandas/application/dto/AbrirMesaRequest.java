@@ -1,15 +1,37 @@
 package com.agustinpalma.comandas.application.dto;
 
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+
 /**
  * DTO de entrada para el caso de uso AbrirMesa.
  * Representa la intención del usuario de abrir una mesa específica.
+ *
+ * HU-108: El canal define la lista de precios del pedido (null = SALON).
+ * HU-111: Mozo a cargo (null = sin asignar) y cubiertos (null = no informados).
  */
 public record AbrirMesaRequest(
-    String mesaId     // ID de la mesa a abrir (viene como String desde REST)
+    String mesaId,     // ID de la mesa a abrir (viene como String desde REST)
+    CanalVenta canal,
+    String mozoId,
+    Integer cubiertos
 ) {
     public AbrirMesaRequest {
         if (mesaId == null || mesaId.isBlank()) {
             throw new IllegalArgumentException("El mesaId es obligatorio");
         }
+        if (canal == null) {
+            canal = CanalVenta.SALON;
+        }
+        if (cubiertos == null) {
+            cubiertos = 0;
+        }
+    }
+
+    public AbrirMesaRequest(String mesaId, CanalVenta canal) {
+        this(mesaId, canal, null, null);
+    }
+
+    public AbrirMesaRequest(String mesaId) {
+        this(mesaId, CanalVenta.SALON);
     }
 }
@@ -14,7 +14,9 @@ public record AbrirMesaResponse(
     String pedidoId,
     int numeroPedido,
     String estadoPedido,
-    String fechaApertura
+    String fechaApertura,
+    String canal,               // HU-108
+    String nombreListaPrecios   // HU-108: null si el canal vende a precio de catálogo
 ) {
     /**
      * Factory method para construir desde entidades de dominio.
@@ -27,7 +29,9 @@ public record AbrirMesaResponse(
             pedido.getId().getValue().toString(),
             pedido.getNumero(),
             pedido.getEstado().name(),
-            pedido.getFechaApertura().toString()
+            pedido.getFechaApertura().toString(),
+            pedido.getCanal().name(),
+            pedido.getNombreListaPrecios()
         );
     }
 }
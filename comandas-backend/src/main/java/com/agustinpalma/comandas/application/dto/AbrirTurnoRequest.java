@@ -0,0 +1,17 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoTurno;
+
+import java.math.BigDecimal;
+
+/**
+ * DTO de request para abrir un turno de caja (HU-106).
+ *
+ * @param tipo         MANANA, TARDE o NOCHE
+ * @param montoInicial efectivo contado en el cajón al tomar el turno (≥ 0)
+ */
+public record AbrirTurnoRequest(
+    TipoTurno tipo,
+    BigDecimal montoInicial
+) {
+}
@@ -0,0 +1,15 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.util.List;
+
+/**
+ * DTO de salida al editar un insumo (HU-118).
+ *
+ * @param productosAPerdida productos que con el nuevo costo pasaron a venderse a pérdida
+ *                          (los que ya estaban a pérdida antes del cambio no se repiten)
+ */
+public record ActualizacionInsumoResponse(
+    InsumoResponse insumo,
+    List<RentabilidadProductoResponse> productosAPerdida
+) {
+}
@@ -1,8 +1,12 @@
 package com.agustinpalma.comandas.application.dto;
 
+import com.agustinpalma.comandas.domain.model.DomainEnums.Alergeno;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TiempoServicio;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
 import java.util.List;
+import java.util.UUID;
 
 /**
  * DTO de entrada para el caso de uso AgregarProducto.
@@ -10,6 +14,11 @@ import java.util.List;
  * 
  * HU-05: Agregar productos a un pedido
  * HU-05.1 + HU-22: Soporte para extras controlados y selección de variante
+ * HU-103: Soporte para elección de opciones en combos
+ * HU-110: Paso de servicio del ítem (entrada, principal, postre)
+ * HU-147: Alergias del comensal para el que se carga el ítem
+ * HU-148: Ingredientes de la receta que el cliente pidió sacar
+ * HU-164: Id del ítem en el borrador del POS, para no cargarlo dos veces
  */
 public record AgregarProductoRequest(
     PedidoId pedidoId,         // ID del pedido al que se agregará el producto
@@ -17,7 +26,12 @@ public record AgregarProductoRequest(
     int cantidad,              // Cantidad de unidades (debe ser > 0)
     String observaciones,      // Notas adicionales (ej: "sin cebolla"), puede ser null
     List<ProductoId> extrasIds, // IDs de extras a agregar (opcional, puede ser null o vacío)
-    ProductoId varianteId      // ID de la variante seleccionada explícitamente (null = auto-normalización)
+    ProductoId varianteId,     // ID de la variante seleccionada explícitamente (null = auto-normalización)
+    List<ProductoId> componentesComboIds, // HU-103: Opción elegida por componente, en orden (null si no es combo)
+    TiempoServicio tiempoServicio, // HU-110: Paso de servicio (null = sin paso)
+    List<Alergeno> advertencias,  // HU-147: Alergias del comensal (null o vacía = sin advertencia)
+    List<InsumoId> ingredientesQuitados, // HU-148: Insumos de la receta a sacar (null o vacía = receta completa)
+    UUID borradorId               // HU-164: Ítem del borrador del POS (null = carga sin borrador)
 ) {
     public AgregarProductoRequest {
         if (pedidoId == null) {
@@ -31,6 +45,94 @@ public record AgregarProductoRequest(
         }
         // extrasIds puede ser null o vacío
         // varianteId puede ser null (auto-normalización)
+        // componentesComboIds puede ser null (producto simple o combo fijo)
+        // tiempoServicio puede ser null (sale con la próxima comanda)
+        // advertencias puede ser null (comensal sin alergias declaradas)
+        // ingredientesQuitados puede ser null (receta completa)
+        // borradorId puede ser null (autopedido, tests, carga sin borrador)
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (sin ítem de borrador).
+     */
+    public AgregarProductoRequest(
+        PedidoId pedidoId,
+        ProductoId productoId,
+        int cantidad,
+        String observaciones,
+        List<ProductoId> extrasIds,
+        ProductoId varianteId,
+        List<ProductoId> componentesComboIds,
+        TiempoServicio tiempoServicio,
+        List<Alergeno> advertencias,
+        List<InsumoId> ingredientesQuitados
+    ) {
+        this(pedidoId, productoId, cantidad, observaciones, extrasIds, varianteId, componentesComboIds,
+            tiempoServicio, advertencias, ingredientesQuitados, null);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (sin ingredientes quitados).
+     */
+    public AgregarProductoRequest(
+        PedidoId pedidoId,
+        ProductoId productoId,
+        int cantidad,
+        String observaciones,
+        List<ProductoId> extrasIds,
+        ProductoId varianteId,
+        List<ProductoId> componentesComboIds,
+        TiempoServicio tiempoServicio,
+        List<Alergeno> advertencias
+    ) {
+        this(pedidoId, productoId, cantidad, observaciones, extrasIds, varianteId, componentesComboIds,
+            tiempoServicio, advertencias, null);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (sin advertencias de alergia).
+     */
+    public AgregarProductoRequest(
+        PedidoId pedidoId,
+        ProductoId productoId,
+        int cantidad,
+        String observaciones,
+        List<ProductoId> extrasIds,
+        ProductoId varianteId,
+        List<ProductoId> componentesComboIds,
+        TiempoServicio tiempoServicio
+    ) {
+        this(pedidoId, productoId, cantidad, observaciones, extrasIds, varianteId, componentesComboIds,
+            tiempoServicio, null);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (sin paso de servicio).
+     */
+    public AgregarProductoRequest(
+        PedidoId pedidoId,
+        ProductoId productoId,
+        int cantidad,
+        String observaciones,
+        List<ProductoId> extrasIds,
+        ProductoId varianteId,
+        List<ProductoId> componentesComboIds
+    ) {
+        this(pedidoId, productoId, cantidad, observaciones, extrasIds, varianteId, componentesComboIds, null);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (sin opciones de combo).
+     */
+    public AgregarProductoRequest(
+        PedidoId pedidoId,
+        ProductoId productoId,
+        int cantidad,
+        String observaciones,
+        List<ProductoId> extrasIds,
+        ProductoId varianteId
+    ) {
+        this(pedidoId, productoId, cantidad, observaciones, extrasIds, varianteId, null);
     }
     
     /**
@@ -43,7 +145,7 @@ public record AgregarProductoRequest(
         int cantidad,
         String observaciones
     ) {
-        this(pedidoId, productoId, cantidad, observaciones, null, null);
+        this(pedidoId, productoId, cantidad, observaciones, null, null, null);
     }
 
     /**
@@ -57,6 +159,6 @@ public record AgregarProductoRequest(
         String observaciones,
         List<ProductoId> extrasIds
     ) {
-        this(pedidoId, productoId, cantidad, observaciones, extrasIds, null);
+        this(pedidoId, productoId, cantidad, observaciones, extrasIds, null, null);
     }
 }
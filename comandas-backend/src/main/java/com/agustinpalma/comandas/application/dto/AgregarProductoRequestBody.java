@@ -1,5 +1,7 @@
 package com.agustinpalma.comandas.application.dto;
 
+import com.agustinpalma.comandas.domain.model.DomainEnums.Alergeno;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TiempoServicio;
 import java.util.List;
 
 /**
@@ -8,6 +10,11 @@ import java.util.List;
  * 
  * HU-05: Agregar productos a un pedido
  * HU-05.1: Soporte para extras controlados (extrasIds opcionales)
+ * HU-103: Opciones elegidas para combos (componentesComboIds opcionales)
+ * HU-110: Paso de servicio del ítem (tiempoServicio opcional)
+ * HU-147: Alergias del comensal (advertencias opcionales)
+ * HU-148: Ingredientes de la receta a sacar (ingredientesQuitados opcionales)
+ * HU-164: Id del ítem en el borrador del POS (borradorId opcional)
  */
 public record AgregarProductoRequestBody(
     String productoId,
@@ -16,5 +23,15 @@ public record AgregarProductoRequestBody(
     /** IDs de productos extra (esExtra=true). Si qty > 1 del mismo extra, repetir el ID. Puede ser null. */
     List<String> extrasIds,
     /** ID de la variante seleccionada explícitamente. Si es null, se usa auto-normalización. */
-    String varianteId
+    String varianteId,
+    /** HU-103: ID del producto elegido para cada componente del combo, en orden. Puede ser null. */
+    List<String> componentesComboIds,
+    /** HU-110: Paso de servicio (ENTRADA, PRINCIPAL, POSTRE). Null = sin paso. */
+    TiempoServicio tiempoServicio,
+    /** HU-147: Alergias del comensal (TACC, MANI, LACTOSA...). Puede ser null. */
+    List<Alergeno> advertencias,
+    /** HU-148: IDs de los insumos de la receta que el cliente pidió sacar. Puede ser null. */
+    List<String> ingredientesQuitados,
+    /** HU-164: Id del ítem en el borrador del POS. Un reintento con el mismo id no duplica el ítem. Puede ser null. */
+    String borradorId
 ) {}
@@ -20,7 +20,8 @@ public record AgregarProductoResponse(
     BigDecimal subtotal,
     BigDecimal totalDescuentos,
     BigDecimal total,
-    String fechaApertura
+    String fechaApertura,
+    long version  // HU-165: versión del pedido después del cambio
 ) {
     /**
      * Factory method para construir desde la entidad de dominio.
@@ -48,7 +49,8 @@ public record AgregarProductoResponse(
             subtotal,
             totalDescuentos,
             total,
-            pedido.getFechaApertura().toString()
+            pedido.getFechaApertura().toString(),
+            pedido.getVersion()
         );
     }
 
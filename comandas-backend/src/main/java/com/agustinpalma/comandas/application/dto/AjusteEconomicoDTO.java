@@ -10,12 +10,15 @@ import java.math.BigDecimal;
  * 
  * El frontend recibe esta lista y puede renderizar el desglose completo
  * sin recalcular ni deducir valores.
+ *
+ * HU-122: autorizadoPor es el empleado que autorizó un descuento manual (null si no aplica).
  */
 public record AjusteEconomicoDTO(
     String tipo,        // "PROMOCION" | "MANUAL"
     String ambito,      // "ITEM" | "TOTAL"
     String descripcion,
-    BigDecimal monto
+    BigDecimal monto,
+    String autorizadoPor
 ) {
     public AjusteEconomicoDTO {
         if (tipo == null || tipo.isBlank()) {
@@ -0,0 +1,19 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.Max;
+import jakarta.validation.constraints.Min;
+
+/**
+ * DTO de entrada para configurar la alerta de mesas olvidadas (HU-113).
+ *
+ * @param minutosSinActividad minutos sin atención a partir de los cuales avisar
+ */
+public record AlertaMesasOlvidadasRequest(
+
+    boolean activa,
+
+    @Min(value = 5, message = "El aviso debe ser de 5 minutos o más")
+    @Max(value = 240, message = "El aviso no puede superar los 240 minutos")
+    int minutosSinActividad
+) {
+}
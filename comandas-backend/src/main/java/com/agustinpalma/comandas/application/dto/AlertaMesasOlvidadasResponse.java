@@ -0,0 +1,15 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.AlertaMesasOlvidadas;
+
+/**
+ * DTO de salida con la configuración de la alerta de mesas olvidadas (HU-113).
+ */
+public record AlertaMesasOlvidadasResponse(
+    boolean activa,
+    int minutosSinActividad
+) {
+    public static AlertaMesasOlvidadasResponse fromDomain(AlertaMesasOlvidadas alerta) {
+        return new AlertaMesasOlvidadasResponse(alerta.isActiva(), alerta.getMinutosSinActividad());
+    }
+}
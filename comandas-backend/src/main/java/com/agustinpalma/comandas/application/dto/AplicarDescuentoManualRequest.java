@@ -2,6 +2,7 @@ package com.agustinpalma.comandas.application.dto;
 
 import com.agustinpalma.comandas.domain.model.DomainEnums.ModoDescuento;
 import com.agustinpalma.comandas.domain.model.DomainIds.ItemPedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
 
 import java.math.BigDecimal;
@@ -18,12 +19,17 @@ import java.util.UUID;
  * - PORCENTAJE: valor entre 0.01 y 100
  * - MONTO_FIJO: valor monetario positivo
  * 
+ * HU-122: Sin autorizante se aplica con el tope del mozo (10%). Para superarlo,
+ * un encargado lo autoriza con su PIN.
+ * 
  * @param pedidoId ID del pedido al cual aplicar el descuento (obligatorio)
  * @param itemPedidoId ID del ítem específico (opcional, null para descuento global)
  * @param tipoDescuento Tipo de descuento (PORCENTAJE o MONTO_FIJO)
  * @param valor Valor del descuento (porcentaje 0.01–100 o monto fijo positivo)
  * @param razon Motivo del descuento (ej: "Cliente frecuente", "Compensación por demora")
  * @param usuarioId ID del usuario que aplica el descuento (auditoría)
+ * @param autorizanteId encargado que autoriza (opcional, null = tope del mozo)
+ * @param pin PIN del encargado (obligatorio si hay autorizante)
  */
 public record AplicarDescuentoManualRequest(
     PedidoId pedidoId,
@@ -31,8 +37,19 @@ public record AplicarDescuentoManualRequest(
     ModoDescuento tipoDescuento,
     BigDecimal valor,
     String razon,
-    UUID usuarioId
+    UUID usuarioId,
+    MozoId autorizanteId,
+    String pin
 ) {
+    /**
+     * Descuento sin autorización de encargado (queda sujeto al tope del mozo).
+     */
+    public AplicarDescuentoManualRequest(PedidoId pedidoId, ItemPedidoId itemPedidoId, ModoDescuento tipoDescuento,
+                                         BigDecimal valor, String razon, UUID usuarioId) {
+        this(pedidoId, itemPedidoId, tipoDescuento, valor, razon, usuarioId, null, null);
+    }
+
+
     /**
      * Indica si este descuento es global (afecta todo el pedido).
      * 
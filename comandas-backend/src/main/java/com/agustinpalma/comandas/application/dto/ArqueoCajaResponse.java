@@ -0,0 +1,26 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * Arqueo de una caja al cierre de la jornada (HU-159).
+ *
+ * balanceEfectivo = fondoInicial + ventasEfectivo + ingresos − egresos − devoluciones
+ *
+ * @param cajaId         null para la caja principal
+ * @param ventasEfectivo efectivo cobrado, incluido el recargo del efectivo (HU-143)
+ * @param pedidos        pedidos cobrados en la caja
+ */
+public record ArqueoCajaResponse(
+    UUID cajaId,
+    String nombre,
+    BigDecimal fondoInicial,
+    BigDecimal ventasEfectivo,
+    BigDecimal ingresos,
+    BigDecimal egresos,
+    BigDecimal devoluciones,
+    BigDecimal balanceEfectivo,
+    int pedidos
+) {
+}
@@ -0,0 +1,21 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.Size;
+
+import java.util.UUID;
+
+/**
+ * DTO de entrada para cargar la entrega de un pedido de delivery (HU-139).
+ *
+ * @param zonaId zona elegida a mano; null la busca por los barrios de la dirección
+ */
+public record AsignarEntregaRequest(
+
+    @NotBlank(message = "La dirección de entrega es obligatoria")
+    @Size(max = 200, message = "La dirección de entrega no puede superar los 200 caracteres")
+    String direccion,
+
+    UUID zonaId
+) {
+}
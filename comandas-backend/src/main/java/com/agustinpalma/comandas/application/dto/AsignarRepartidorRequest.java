@@ -0,0 +1,15 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotNull;
+
+import java.util.UUID;
+
+/**
+ * DTO de entrada para asignar el repartidor de un pedido de delivery (HU-140).
+ */
+public record AsignarRepartidorRequest(
+
+    @NotNull(message = "El repartidor es obligatorio")
+    UUID repartidorId
+) {
+}
@@ -0,0 +1,37 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.DecimalMax;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Positive;
+import jakarta.validation.constraints.Size;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.util.UUID;
+
+/**
+ * DTO de entrada para un aumento masivo de precios (HU-145).
+ * El mismo request sirve para la vista previa y para confirmar.
+ *
+ * @param categoriaId      categoría a aumentar (null = todo el catálogo)
+ * @param multiploRedondeo redondea hacia arriba a múltiplos de este monto (null = sin redondeo)
+ * @param fechaVigencia    día en que entra en vigencia (null = hoy)
+ * @param responsable      HU-146: quién carga el aumento, para el historial de precios (opcional)
+ */
+public record AumentoPreciosRequest(
+    UUID categoriaId,
+
+    @NotNull(message = "El porcentaje es obligatorio")
+    @Positive(message = "El porcentaje debe ser mayor a cero")
+    @DecimalMax(value = "100", message = "El porcentaje no puede superar el 100%")
+    BigDecimal porcentaje,
+
+    @Positive(message = "El múltiplo de redondeo debe ser mayor a cero")
+    BigDecimal multiploRedondeo,
+
+    LocalDate fechaVigencia,
+
+    @Size(max = 60, message = "El responsable no puede superar los 60 caracteres")
+    String responsable
+) {
+}
@@ -0,0 +1,45 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.AumentoPrecios;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoAumentoPrecios;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Aumento masivo de precios programado o aplicado (HU-145).
+ *
+ * @param categoriaId null = todo el catálogo
+ * @param cambios     detalle por producto; vacío mientras está programado
+ */
+public record AumentoPreciosResponse(
+    UUID id,
+    UUID categoriaId,
+    BigDecimal porcentaje,
+    BigDecimal multiploRedondeo,
+    LocalDate fechaVigencia,
+    LocalDateTime fechaCreacion,
+    String responsable,
+    EstadoAumentoPrecios estado,
+    LocalDateTime fechaAplicacion,
+    List<CambioPrecioResponse> cambios
+) {
+
+    public static AumentoPreciosResponse fromDomain(AumentoPrecios aumento) {
+        return new AumentoPreciosResponse(
+            aumento.getId().getValue(),
+            aumento.getCategoriaId() != null ? aumento.getCategoriaId().getValue() : null,
+            aumento.getPorcentaje(),
+            aumento.getMultiploRedondeo(),
+            aumento.getFechaVigencia(),
+            aumento.getFechaCreacion(),
+            aumento.getResponsable(),
+            aumento.getEstado(),
+            aumento.getFechaAplicacion(),
+            aumento.getCambios().stream().map(CambioPrecioResponse::fromDomain).toList()
+        );
+    }
+}
@@ -0,0 +1,17 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotNull;
+
+import java.util.UUID;
+
+/**
+ * DTO de entrada para avisarle a un cliente en espera que tiene mesa (HU-149).
+ *
+ * @param mesaId mesa libre que se le guarda
+ */
+public record AvisarClienteEnEsperaRequest(
+
+    @NotNull(message = "La mesa es obligatoria")
+    UUID mesaId
+) {
+}
@@ -0,0 +1,15 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.Size;
+
+/**
+ * DTO de entrada para registrar una terminal como caja del local (HU-159).
+ */
+public record CajaRequest(
+
+    @NotBlank(message = "El nombre de la caja es obligatorio")
+    @Size(max = 40, message = "El nombre de la caja no puede superar los 40 caracteres")
+    String nombre
+) {
+}
@@ -0,0 +1,33 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.AperturaCaja;
+import com.agustinpalma.comandas.domain.model.Caja;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * DTO de salida con los datos de una caja del local (HU-159).
+ *
+ * @param abierta      si ya declaró su fondo en la jornada en curso
+ * @param fondoInicial fondo declarado en la jornada en curso (null si no abrió)
+ */
+public record CajaResponse(
+    UUID id,
+    String nombre,
+    boolean abierta,
+    BigDecimal fondoInicial
+) {
+
+    /**
+     * @param apertura apertura en la jornada en curso (nullable)
+     */
+    public static CajaResponse fromDomain(Caja caja, AperturaCaja apertura) {
+        return new CajaResponse(
+            caja.getId().getValue(),
+            caja.getNombre(),
+            apertura != null,
+            apertura != null ? apertura.getFondoInicial() : null
+        );
+    }
+}
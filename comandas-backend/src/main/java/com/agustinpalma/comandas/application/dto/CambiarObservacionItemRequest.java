@@ -0,0 +1,21 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.ItemPedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+
+/**
+ * DTO de entrada para cambiar la observación libre de un ítem.
+ *
+ * HU-120: Observaciones libres por ítem impresas en comanda.
+ * observacion null o vacía quita la nota.
+ */
+public record CambiarObservacionItemRequest(
+    PedidoId pedidoId,
+    ItemPedidoId itemPedidoId,
+    String observacion
+) {
+    public CambiarObservacionItemRequest {
+        if (pedidoId == null) throw new IllegalArgumentException("El pedidoId es obligatorio");
+        if (itemPedidoId == null) throw new IllegalArgumentException("El itemPedidoId es obligatorio");
+    }
+}
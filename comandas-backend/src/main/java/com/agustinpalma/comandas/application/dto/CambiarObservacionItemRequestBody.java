@@ -0,0 +1,13 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.Size;
+
+/**
+ * Request body para el endpoint PATCH de observación de un ítem.
+ *
+ * HU-120: Texto libre para cocina. Null o vacío quita la nota.
+ */
+public record CambiarObservacionItemRequestBody(
+    @Size(max = 255, message = "La observación no puede superar los 255 caracteres")
+    String observacion
+) {}
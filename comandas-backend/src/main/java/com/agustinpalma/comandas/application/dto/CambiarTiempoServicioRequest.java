@@ -0,0 +1,22 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TiempoServicio;
+import com.agustinpalma.comandas.domain.model.DomainIds.ItemPedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+
+/**
+ * DTO de entrada para cambiar el paso de servicio de un ítem.
+ *
+ * HU-110: Secuencia de pasos a cocina.
+ * tiempoServicio null quita el paso: el ítem sale con la próxima comanda.
+ */
+public record CambiarTiempoServicioRequest(
+    PedidoId pedidoId,
+    ItemPedidoId itemPedidoId,
+    TiempoServicio tiempoServicio
+) {
+    public CambiarTiempoServicioRequest {
+        if (pedidoId == null) throw new IllegalArgumentException("El pedidoId es obligatorio");
+        if (itemPedidoId == null) throw new IllegalArgumentException("El itemPedidoId es obligatorio");
+    }
+}
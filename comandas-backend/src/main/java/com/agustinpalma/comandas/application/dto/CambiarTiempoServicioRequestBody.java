@@ -0,0 +1,12 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TiempoServicio;
+
+/**
+ * Request body para el endpoint PATCH de paso de servicio de un ítem.
+ *
+ * HU-110: ENTRADA, PRINCIPAL o POSTRE. Null quita el paso.
+ */
+public record CambiarTiempoServicioRequestBody(
+    TiempoServicio tiempoServicio
+) {}
@@ -0,0 +1,44 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.CambioPrecioProducto;
+import com.agustinpalma.comandas.domain.model.DomainEnums.OrigenCambioPrecio;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Un cambio de precio del historial del catálogo (HU-146).
+ *
+ * @param variacionPorcentual null si el precio anterior era cero
+ * @param aumentoId           aumento masivo que lo provocó (null si fue una edición)
+ * @param responsable         quién lo cambió, si se indicó
+ */
+public record CambioPrecioProductoResponse(
+    UUID id,
+    UUID productoId,
+    String nombreProducto,
+    BigDecimal precioAnterior,
+    BigDecimal precioNuevo,
+    BigDecimal variacionPorcentual,
+    LocalDateTime fecha,
+    OrigenCambioPrecio origen,
+    UUID aumentoId,
+    String responsable
+) {
+
+    public static CambioPrecioProductoResponse fromDomain(CambioPrecioProducto cambio) {
+        return new CambioPrecioProductoResponse(
+            cambio.getId().getValue(),
+            cambio.getProductoId().getValue(),
+            cambio.getNombreProducto(),
+            cambio.getPrecioAnterior(),
+            cambio.getPrecioNuevo(),
+            cambio.getVariacionPorcentual(),
+            cambio.getFecha(),
+            cambio.getOrigen(),
+            cambio.getAumentoId() != null ? cambio.getAumentoId().getValue() : null,
+            cambio.getResponsable()
+        );
+    }
+}
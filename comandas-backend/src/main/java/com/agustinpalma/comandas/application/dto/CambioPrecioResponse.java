@@ -0,0 +1,26 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.CambioPrecio;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * Precio de un producto antes y después del aumento (HU-145).
+ */
+public record CambioPrecioResponse(
+    UUID productoId,
+    String nombreProducto,
+    BigDecimal precioAnterior,
+    BigDecimal precioNuevo
+) {
+
+    public static CambioPrecioResponse fromDomain(CambioPrecio cambio) {
+        return new CambioPrecioResponse(
+            cambio.getProductoId().getValue(),
+            cambio.getNombreProducto(),
+            cambio.getPrecioAnterior(),
+            cambio.getPrecioNuevo()
+        );
+    }
+}
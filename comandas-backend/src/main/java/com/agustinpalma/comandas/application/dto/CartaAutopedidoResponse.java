@@ -0,0 +1,34 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Carta que ve el cliente al escanear el QR de su mesa (HU-126).
+ *
+ * Solo trae lo que el cliente puede pedir solo: productos activos, con stock,
+ * sin combos que obliguen a elegir opciones. Los precios son los del salón.
+ *
+ * @param categorias en el orden del POS; "Otros" agrupa los productos sin categoría
+ */
+public record CartaAutopedidoResponse(
+    String nombreLocal,
+    int numeroMesa,
+    List<CategoriaCarta> categorias
+) {
+
+    public record CategoriaCarta(
+        String nombre,
+        List<ProductoCarta> productos
+    ) {
+    }
+
+    public record ProductoCarta(
+        UUID id,
+        String nombre,
+        BigDecimal precio,
+        String colorHex
+    ) {
+    }
+}
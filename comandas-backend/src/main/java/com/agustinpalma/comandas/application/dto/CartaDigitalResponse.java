@@ -0,0 +1,42 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Carta digital del local, generada en el momento desde el catálogo (HU-127).
+ *
+ * A diferencia de la carta del autopedido, es solo para mirar: muestra todo lo
+ * que se vende (incluidos combos) y marca lo que hoy no hay en vez de ocultarlo.
+ *
+ * @param numeroMesa mesa del QR escaneado; null si se abrió sin mesa o el QR ya no corresponde
+ * @param categorias en el orden del POS; "Otros" agrupa los productos sin categoría
+ */
+public record CartaDigitalResponse(
+    String nombreLocal,
+    Integer numeroMesa,
+    List<CategoriaMenu> categorias
+) {
+
+    public record CategoriaMenu(
+        String nombre,
+        String colorHex,
+        List<ProductoMenu> productos
+    ) {
+    }
+
+    /**
+     * @param fotoActualizadaEn versión de la foto para la URL de la imagen; null si no tiene foto
+     * @param disponible false si está 86 o sin stock
+     */
+    public record ProductoMenu(
+        UUID id,
+        String nombre,
+        BigDecimal precio,
+        LocalDateTime fotoActualizadaEn,
+        boolean disponible
+    ) {
+    }
+}
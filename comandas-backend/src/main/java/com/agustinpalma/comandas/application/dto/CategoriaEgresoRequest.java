@@ -0,0 +1,19 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.Size;
+
+/**
+ * DTO de entrada para dar de alta o editar una categoría de egresos (HU-130).
+ *
+ * @param activa false la saca de las opciones al registrar egresos (null = activa)
+ */
+public record CategoriaEgresoRequest(
+
+    @NotBlank(message = "El nombre de la categoría es obligatorio")
+    @Size(max = 40, message = "El nombre de la categoría no puede superar los 40 caracteres")
+    String nombre,
+
+    Boolean activa
+) {
+}
@@ -0,0 +1,23 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.CategoriaEgreso;
+
+import java.util.UUID;
+
+/**
+ * DTO de salida con los datos de una categoría de egresos (HU-130).
+ */
+public record CategoriaEgresoResponse(
+    UUID id,
+    String nombre,
+    boolean activa
+) {
+
+    public static CategoriaEgresoResponse fromDomain(CategoriaEgreso categoria) {
+        return new CategoriaEgresoResponse(
+            categoria.getId().getValue(),
+            categoria.getNombre(),
+            categoria.isActiva()
+        );
+    }
+}
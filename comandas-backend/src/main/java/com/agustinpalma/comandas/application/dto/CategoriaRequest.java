@@ -21,6 +21,12 @@ public record CategoriaRequest(
     Integer orden,  // Opcional, default 0 en creación
 
     /** UUID de la categoría de modificadores asociada. Nullable. */
-    String categoriaModificadoresId
+    String categoriaModificadoresId,
+
+    /** HU-119: Minutos de alerta de demora en cocina. Nullable; en edición, 0 quita el umbral. */
+    Integer minutosAlertaCocina,
+
+    /** HU-161: Horario en que se ofrecen sus productos. Nullable; en edición, sin horas lo quita. */
+    FranjaHorariaRequest horario
 ) {
 }
@@ -1,6 +1,9 @@
 package com.agustinpalma.comandas.application.dto;
 
 import com.agustinpalma.comandas.domain.model.Categoria;
+import com.agustinpalma.comandas.domain.model.FranjaHoraria;
+
+import java.time.LocalTime;
 
 /**
  * DTO de salida para categorías.
@@ -14,13 +17,19 @@ public record CategoriaResponse(
     boolean esCategoriaExtra,
     int orden,
     /** ID de la categoría de modificadores asociada (nullable). */
-    String categoriaModificadoresId
+    String categoriaModificadoresId,
+    /** HU-119: Minutos de alerta de demora en cocina (nullable = umbral por defecto). */
+    Integer minutosAlertaCocina,
+    /** HU-161: Horario en que se ofrecen sus productos (ambos null = todo el día). */
+    LocalTime horarioDesde,
+    LocalTime horarioHasta
 ) {
 
     /**
      * Factory method para construir el DTO desde la entidad de dominio.
      */
     public static CategoriaResponse fromDomain(Categoria categoria) {
+        FranjaHoraria horario = categoria.getFranjaHoraria();
         return new CategoriaResponse(
             categoria.getId().getValue().toString(),
             categoria.getNombre(),
@@ -30,7 +39,10 @@ public record CategoriaResponse(
             categoria.getOrden(),
             categoria.getCategoriaModificadoresId() != null
                 ? categoria.getCategoriaModificadoresId().getValue().toString()
-                : null
+                : null,
+            categoria.getMinutosAlertaCocina(),
+            horario != null ? horario.getDesde() : null,
+            horario != null ? horario.getHasta() : null
         );
     }
 }
@@ -0,0 +1,65 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Command para cerrar una mesa: los pagos y, opcionalmente, lo que se
+ * indica al cobrar. Se arma con {@link #de} y los {@code con...} que hagan falta.
+ *
+ * @param localId identificador del local (tenant)
+ * @param mesaId identificador de la mesa a cerrar
+ * @param pagos lista de pagos para cubrir el total
+ * @param clienteId HU-104/105: cliente de la cuenta corriente y de los puntos (nullable)
+ * @param puntosACanjear HU-105: puntos a usar como descuento (null o 0 = sin canje)
+ * @param propina HU-111: propina de la mesa (null = sin propina)
+ * @param empleadoId HU-132: empleado al que se imputan los pagos A_CUENTA (null = sin imputar)
+ * @param versionPedido HU-165: versión del pedido en la terminal (null = sin control)
+ */
+public record CerrarMesaCommand(
+    LocalId localId,
+    MesaId mesaId,
+    List<PagoRequest> pagos,
+    ClienteId clienteId,
+    Integer puntosACanjear,
+    BigDecimal propina,
+    MozoId empleadoId,
+    Long versionPedido
+) {
+
+    public CerrarMesaCommand {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(mesaId, "El mesaId es obligatorio");
+        Objects.requireNonNull(pagos, "La lista de pagos es obligatoria");
+    }
+
+    public static CerrarMesaCommand de(LocalId localId, MesaId mesaId, List<PagoRequest> pagos) {
+        return new CerrarMesaCommand(localId, mesaId, pagos, null, null, null, null, null);
+    }
+
+    public CerrarMesaCommand conCliente(ClienteId clienteId) {
+        return new CerrarMesaCommand(localId, mesaId, pagos, clienteId, puntosACanjear, propina, empleadoId, versionPedido);
+    }
+
+    public CerrarMesaCommand conCanje(Integer puntosACanjear) {
+        return new CerrarMesaCommand(localId, mesaId, pagos, clienteId, puntosACanjear, propina, empleadoId, versionPedido);
+    }
+
+    public CerrarMesaCommand conPropina(BigDecimal propina) {
+        return new CerrarMesaCommand(localId, mesaId, pagos, clienteId, puntosACanjear, propina, empleadoId, versionPedido);
+    }
+
+    public CerrarMesaCommand conEmpleado(MozoId empleadoId) {
+        return new CerrarMesaCommand(localId, mesaId, pagos, clienteId, puntosACanjear, propina, empleadoId, versionPedido);
+    }
+
+    public CerrarMesaCommand conVersion(long versionPedido) {
+        return new CerrarMesaCommand(localId, mesaId, pagos, clienteId, puntosACanjear, propina, empleadoId, versionPedido);
+    }
+}
@@ -1,5 +1,6 @@
 package com.agustinpalma.comandas.application.dto;
 
+import java.math.BigDecimal;
 import java.util.List;
 
 /**
@@ -8,9 +9,46 @@ import java.util.List;
  * 
  * @param mesaId identificador de la mesa a cerrar
  * @param pagos lista de pagos para cubrir el total del pedido
+ * @param clienteId HU-104/105: cliente al que se cargan los pagos CUENTA_CORRIENTE
+ *                  y que acumula puntos por el pedido (nullable)
+ * @param puntosACanjear HU-105: puntos del cliente a usar como descuento (nullable)
+ * @param propina HU-111: propina dejada por la mesa, aparte de los pagos (nullable)
+ * @param empleadoConsumoId HU-132: empleado al que se imputan los pagos A_CUENTA (nullable)
  */
 public record CerrarMesaRequest(
     String mesaId,
-    List<PagoRequest> pagos
+    List<PagoRequest> pagos,
+    String clienteId,
+    Integer puntosACanjear,
+    BigDecimal propina,
+    String empleadoConsumoId
 ) {
+    /**
+     * Constructor de retrocompatibilidad (sin cliente de cuenta corriente).
+     */
+    public CerrarMesaRequest(String mesaId, List<PagoRequest> pagos) {
+        this(mesaId, pagos, null, null, null);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (sin canje de puntos).
+     */
+    public CerrarMesaRequest(String mesaId, List<PagoRequest> pagos, String clienteId) {
+        this(mesaId, pagos, clienteId, null, null);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (sin propina).
+     */
+    public CerrarMesaRequest(String mesaId, List<PagoRequest> pagos, String clienteId, Integer puntosACanjear) {
+        this(mesaId, pagos, clienteId, puntosACanjear, null);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (sin empleado de consumo interno).
+     */
+    public CerrarMesaRequest(String mesaId, List<PagoRequest> pagos, String clienteId, Integer puntosACanjear,
+                             BigDecimal propina) {
+        this(mesaId, pagos, clienteId, puntosACanjear, propina, null);
+    }
 }
@@ -24,7 +24,11 @@ import java.util.List;
  * @param montoDescuentos monto total de descuentos aplicados
  * @param montoTotal total final congelado
  * @param pagos lista de pagos registrados
+ * @param totalRecargos HU-143: recargos por medio de pago cobrados encima del total
+ * @param ajusteRedondeo HU-152: ajuste por redondeo del efectivo (negativo si se redondeó para abajo)
  * @param fechaCierre timestamp del cierre
+ * @param avisoConsumoInterno HU-132: aviso si el empleado superó su tope de consumo del mes (nullable)
+ * @param numeroComprobante HU-166: número de la serie de la caja que cobró (null si la caja no tiene serie)
  */
 public record CerrarMesaResponse(
     String mesaId,
@@ -36,18 +40,29 @@ public record CerrarMesaResponse(
     BigDecimal montoDescuentos,
     BigDecimal montoTotal,
     List<PagoResponse> pagos,
-    LocalDateTime fechaCierre
+    BigDecimal totalRecargos,
+    LocalDateTime fechaCierre,
+    String avisoConsumoInterno,
+    BigDecimal ajusteRedondeo,
+    String numeroComprobante
 ) {
     /**
      * DTO anidado para representar un pago en la respuesta.
+     * HU-143: recargo congelado del medio, aparte del monto.
+     * HU-152: ajuste por redondeo, solo en el efectivo en pesos.
+     * HU-156: referencia del comprobante (null si no se cargó).
      */
     public record PagoResponse(
         MedioPago medio,
         BigDecimal monto,
-        LocalDateTime fecha
+        BigDecimal recargo,
+        LocalDateTime fecha,
+        BigDecimal ajusteRedondeo,
+        String referencia
     ) {
         public static PagoResponse fromDomain(Pago pago) {
-            return new PagoResponse(pago.getMedio(), pago.getMonto(), pago.getFecha());
+            return new PagoResponse(pago.getMedio(), pago.getMonto(), pago.getRecargo(), pago.getFecha(),
+                pago.getAjusteRedondeo(), pago.getReferencia());
         }
     }
 
@@ -59,6 +74,13 @@ public record CerrarMesaResponse(
      * @return el DTO de respuesta
      */
     public static CerrarMesaResponse fromDomain(Mesa mesa, Pedido pedido) {
+        return fromDomain(mesa, pedido, null);
+    }
+
+    /**
+     * HU-132: Respuesta con el aviso de tope de consumo superado.
+     */
+    public static CerrarMesaResponse fromDomain(Mesa mesa, Pedido pedido, String avisoConsumoInterno) {
         List<PagoResponse> pagosResponse = pedido.getPagos().stream()
             .map(PagoResponse::fromDomain)
             .toList();
@@ -73,7 +95,11 @@ public record CerrarMesaResponse(
             pedido.getMontoDescuentosFinal(),
             pedido.getMontoTotalFinal(),
             pagosResponse,
-            pedido.getFechaCierre()
+            pedido.calcularTotalRecargos(),
+            pedido.getFechaCierre(),
+            avisoConsumoInterno,
+            pedido.calcularAjusteRedondeo(),
+            pedido.getNumeroComprobante()
         );
     }
 }
@@ -0,0 +1,42 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.util.List;
+
+/**
+ * DTO de request para cerrar el turno de caja activo (HU-106).
+ *
+ * HU-123: Si llega el conteo por denominación, el efectivo declarado se
+ * calcula a partir de él y {@code efectivoDeclarado} se ignora.
+ *
+ * @param efectivoDeclarado efectivo contado en el cajón al entregar el turno (≥ 0)
+ * @param denominaciones    conteo del cajón por denominación (opcional)
+ * @param responsableId     HU-129: cajero a cargo del turno (obligatorio si la caja no cierra)
+ * @param observaciones     HU-129: explicación de la diferencia (opcional)
+ */
+public record CerrarTurnoRequest(
+    BigDecimal efectivoDeclarado,
+    List<Denominacion> denominaciones,
+    String responsableId,
+    String observaciones
+) {
+
+    public CerrarTurnoRequest(BigDecimal efectivoDeclarado) {
+        this(efectivoDeclarado, null);
+    }
+
+    public CerrarTurnoRequest(BigDecimal efectivoDeclarado, List<Denominacion> denominaciones) {
+        this(efectivoDeclarado, denominaciones, null, null);
+    }
+
+    public boolean tieneConteo() {
+        return denominaciones != null && !denominaciones.isEmpty();
+    }
+
+    /**
+     * @param denominacion valor del billete o moneda
+     * @param cantidad     unidades contadas (≥ 0)
+     */
+    public record Denominacion(BigDecimal denominacion, int cantidad) {
+    }
+}
@@ -1,5 +1,7 @@
 package com.agustinpalma.comandas.application.dto;
 
+import java.math.BigDecimal;
+import java.util.List;
 import java.util.UUID;
 
 /**
@@ -7,5 +9,48 @@ import java.util.UUID;
  *
  * Devuelve el ID de la jornada recién creada para que el frontend
  * pueda solicitar inmediatamente la descarga del reporte PDF.
+ *
+ * HU-106: incluye el consolidado de turnos de la jornada con la diferencia
+ * de caja de cada uno. Si el local no trabaja con turnos, la lista viene vacía.
+ *
+ * HU-159: incluye el arqueo de cada caja (la principal primero) y el
+ * efectivo que tiene que haber entre todos los cajones.
+ *
+ * @param jornadaId       UUID de la jornada cerrada
+ * @param turnos          turnos de la jornada en orden de apertura
+ * @param diferenciaTotal suma de las diferencias de todos los turnos
+ * @param cajas           arqueo de cada caja de la jornada
+ * @param efectivoTotal   suma de los balances de efectivo de todas las cajas
  */
-public record CierreJornadaResponse(UUID jornadaId) {}
+public record CierreJornadaResponse(
+    UUID jornadaId,
+    List<TurnoCajaResponse> turnos,
+    BigDecimal diferenciaTotal,
+    List<ArqueoCajaResponse> cajas,
+    BigDecimal efectivoTotal
+) {
+
+    /**
+     * Constructor de retrocompatibilidad (sin arqueo por caja).
+     */
+    public CierreJornadaResponse(UUID jornadaId, List<TurnoCajaResponse> turnos, BigDecimal diferenciaTotal) {
+        this(jornadaId, turnos, diferenciaTotal, List.of(), BigDecimal.ZERO);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (jornada sin turnos).
+     */
+    public CierreJornadaResponse(UUID jornadaId) {
+        this(jornadaId, List.of(), BigDecimal.ZERO);
+    }
+
+    /**
+     * HU-159: La misma respuesta con el arqueo de cada caja y el total global.
+     */
+    public CierreJornadaResponse conCajas(List<ArqueoCajaResponse> cajas) {
+        BigDecimal total = cajas.stream()
+            .map(ArqueoCajaResponse::balanceEfectivo)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+        return new CierreJornadaResponse(jornadaId, turnos, diferenciaTotal, List.copyOf(cajas), total);
+    }
+}
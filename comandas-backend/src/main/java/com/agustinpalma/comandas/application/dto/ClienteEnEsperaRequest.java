@@ -0,0 +1,29 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Positive;
+import jakarta.validation.constraints.Size;
+
+/**
+ * DTO de entrada para anotar un cliente en la lista de espera (HU-149).
+ *
+ * @param telefono para mandarle el aviso por WhatsApp o SMS (opcional)
+ */
+public record ClienteEnEsperaRequest(
+
+    @NotBlank(message = "El nombre del cliente es obligatorio")
+    @Size(max = 80, message = "El nombre del cliente no puede superar los 80 caracteres")
+    String nombreCliente,
+
+    @NotNull(message = "La cantidad de personas es obligatoria")
+    @Positive(message = "La espera tiene que ser para al menos una persona")
+    Integer personas,
+
+    @Size(max = 30, message = "El teléfono no puede superar los 30 caracteres")
+    String telefono,
+
+    @Size(max = 200, message = "Las observaciones no pueden superar los 200 caracteres")
+    String observaciones
+) {
+}
@@ -0,0 +1,86 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.ClienteEnEspera;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoEspera;
+import com.agustinpalma.comandas.domain.model.Mesa;
+
+import java.net.URLEncoder;
+import java.nio.charset.StandardCharsets;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Cliente de la lista de espera tal como lo ve el encargado (HU-149).
+ *
+ * @param minutosEsperando minutos desde que se anotó (hasta que salió de la lista)
+ * @param numeroMesa       mesa con la que se le avisó (null si todavía no)
+ * @param mesaSugerida     mesa libre en la que entra el grupo y que no le toca a
+ *                         nadie antes en la lista (null si no hay o ya se le avisó)
+ * @param whatsappUrl      abre WhatsApp con el aviso listo (null sin teléfono o sin mesa avisada)
+ * @param smsUrl           mismo aviso por SMS (null sin teléfono o sin mesa avisada)
+ */
+public record ClienteEnEsperaResponse(
+    UUID id,
+    String nombreCliente,
+    int personas,
+    String telefono,
+    String observaciones,
+    EstadoEspera estado,
+    LocalDateTime fechaAlta,
+    long minutosEsperando,
+    UUID mesaId,
+    Integer numeroMesa,
+    LocalDateTime fechaAviso,
+    MesaSugerida mesaSugerida,
+    String whatsappUrl,
+    String smsUrl
+) {
+
+    /**
+     * @param capacidad personas que entran (null si la mesa no tiene capacidad definida)
+     */
+    public record MesaSugerida(UUID mesaId, int numero, String sector, Integer capacidad) {
+
+        public static MesaSugerida fromDomain(Mesa mesa) {
+            return new MesaSugerida(mesa.getId().getValue(), mesa.getNumero(), mesa.getSector(),
+                mesa.getCapacidad());
+        }
+    }
+
+    public static ClienteEnEsperaResponse fromDomain(ClienteEnEspera cliente, Integer numeroMesa,
+                                                     Mesa mesaSugerida, String nombreLocal,
+                                                     LocalDateTime ahora) {
+        String mensaje = cliente.getTelefono() != null && numeroMesa != null
+            ? armarAviso(cliente, numeroMesa, nombreLocal)
+            : null;
+        // wa.me y sms: solo aceptan el número sin símbolos
+        String numero = cliente.getTelefono() != null ? cliente.getTelefono().replaceAll("\\D", "") : "";
+        return new ClienteEnEsperaResponse(
+            cliente.getId().getValue(),
+            cliente.getNombreCliente(),
+            cliente.getPersonas(),
+            cliente.getTelefono(),
+            cliente.getObservaciones(),
+            cliente.getEstado(),
+            cliente.getFechaAlta(),
+            cliente.minutosEsperando(ahora),
+            cliente.getMesaId() != null ? cliente.getMesaId().getValue() : null,
+            numeroMesa,
+            cliente.getFechaAviso(),
+            mesaSugerida != null ? MesaSugerida.fromDomain(mesaSugerida) : null,
+            mensaje != null ? "https://wa.me/" + numero + "?text=" + codificar(mensaje) : null,
+            mensaje != null ? "sms:" + numero + "?body=" + codificar(mensaje) : null
+        );
+    }
+
+    private static String armarAviso(ClienteEnEspera cliente, int numeroMesa, String nombreLocal) {
+        String enDonde = nombreLocal == null || nombreLocal.isBlank() ? "" : " en " + nombreLocal;
+        return String.format(
+            "¡Hola %s! Ya se liberó tu mesa%s (mesa %d, para %d). Acercate a la entrada y te ubicamos.",
+            cliente.getNombreCliente(), enDonde, numeroMesa, cliente.getPersonas());
+    }
+
+    private static String codificar(String texto) {
+        return URLEncoder.encode(texto, StandardCharsets.UTF_8).replace("+", "%20");
+    }
+}
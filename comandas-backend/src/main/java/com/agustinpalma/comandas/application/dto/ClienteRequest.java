@@ -0,0 +1,41 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.PastOrPresent;
+import jakarta.validation.constraints.PositiveOrZero;
+import jakarta.validation.constraints.Size;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+
+/**
+ * DTO de entrada para crear o editar un cliente con cuenta corriente (HU-104).
+ * Valida los datos básicos a nivel de presentación.
+ * Las validaciones de negocio se ejecutan en el dominio.
+ *
+ * HU-168: Suma los datos de la ficha (todos opcionales).
+ */
+public record ClienteRequest(
+
+    @NotBlank(message = "El nombre del cliente es obligatorio")
+    String nombre,
+
+    String telefono,  // Opcional, para reclamar la deuda
+
+    @NotNull(message = "El límite de crédito es obligatorio")
+    @PositiveOrZero(message = "El límite de crédito no puede ser negativo")
+    BigDecimal limiteCredito,
+
+    Boolean activo,  // Opcional, default true en creación; null preserva en edición
+
+    @PastOrPresent(message = "La fecha de nacimiento no puede ser futura")
+    LocalDate fechaNacimiento,
+
+    @Size(max = 300, message = "Las preferencias no pueden superar los 300 caracteres")
+    String preferencias,
+
+    @Size(max = 300, message = "Las alergias no pueden superar los 300 caracteres")
+    String alergias
+) {
+}
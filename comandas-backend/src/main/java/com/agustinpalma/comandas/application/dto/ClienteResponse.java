@@ -0,0 +1,43 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.Cliente;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+
+/**
+ * DTO de salida para clientes con cuenta corriente (HU-104).
+ * Incluye el estado de deuda derivado de los movimientos.
+ *
+ * @param deuda saldo deudor actual (Σ cargos - Σ pagos)
+ * @param creditoDisponible cuánto más puede consumir a cuenta corriente
+ * @param fechaNacimiento HU-168: null si no se cargó
+ */
+public record ClienteResponse(
+    String id,
+    String nombre,
+    String telefono,
+    BigDecimal limiteCredito,
+    boolean activo,
+    BigDecimal deuda,
+    BigDecimal creditoDisponible,
+    LocalDate fechaNacimiento,
+    String preferencias,
+    String alergias
+) {
+
+    public static ClienteResponse fromDomain(Cliente cliente, BigDecimal deuda) {
+        return new ClienteResponse(
+            cliente.getId().getValue().toString(),
+            cliente.getNombre(),
+            cliente.getTelefono(),
+            cliente.getLimiteCredito(),
+            cliente.isActivo(),
+            deuda,
+            cliente.creditoDisponible(deuda),
+            cliente.getFechaNacimiento(),
+            cliente.getPreferencias(),
+            cliente.getAlergias()
+        );
+    }
+}
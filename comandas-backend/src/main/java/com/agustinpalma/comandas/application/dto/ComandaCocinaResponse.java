@@ -0,0 +1,66 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.ComandaCocina;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Alergeno;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Comanda tal como la ve la pantalla de cocina (HU-119).
+ *
+ * @param minutosEnCocina minutos desde el envío hasta ahora (o hasta que se marcó lista)
+ * @param minutosAlerta umbral de demora fijado al enviar
+ * @param demorada true si el tiempo en cocina supera el umbral
+ * @param urgente HU-137: true si el pedido o algún ítem se marcó urgente
+ * @param horaPrometida HU-138: retiro prometido al cliente (null si no es take away)
+ * @param atrasada HU-138: true si cocina pasó el horario prometido
+ *
+ * Cada ítem trae las alergias declaradas para su comensal (HU-147), vacías si no hay.
+ */
+public record ComandaCocinaResponse(
+    UUID id,
+    int numeroMesa,
+    int numeroPedido,
+    LocalDateTime enviadaEn,
+    LocalDateTime listaEn,
+    long minutosEnCocina,
+    int minutosAlerta,
+    boolean demorada,
+    boolean urgente,
+    LocalDateTime horaPrometida,
+    boolean atrasada,
+    List<ItemComandaCocinaResponse> items
+) {
+
+    public record ItemComandaCocinaResponse(
+        String nombreProducto,
+        int cantidad,
+        String observacion,
+        boolean urgente,
+        List<Alergeno> advertencias
+    ) {
+    }
+
+    public static ComandaCocinaResponse fromDomain(ComandaCocina comanda, LocalDateTime ahora) {
+        return new ComandaCocinaResponse(
+            comanda.getId().getValue(),
+            comanda.getNumeroMesa(),
+            comanda.getNumeroPedido(),
+            comanda.getEnviadaEn(),
+            comanda.getListaEn(),
+            comanda.tiempoEnCocina(ahora).toMinutes(),
+            comanda.getMinutosAlerta(),
+            comanda.estaDemorada(ahora),
+            comanda.esUrgente(),
+            comanda.getHoraPrometida(),
+            comanda.estaAtrasada(ahora),
+            comanda.getItems().stream()
+                .map(item -> new ItemComandaCocinaResponse(
+                    item.getNombreProducto(), item.getCantidad(), item.getObservacion(), item.isUrgente(),
+                    List.copyOf(item.getAdvertencias())))
+                .toList()
+        );
+    }
+}
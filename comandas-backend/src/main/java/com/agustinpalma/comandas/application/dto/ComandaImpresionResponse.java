@@ -43,10 +43,14 @@ public record ComandaImpresionResponse(
         }
     }
 
+    /**
+     * @param componentesCombo HU-103: armado del combo para cocina (ej: "Bebida: Coca-Cola"), vacía si no es combo
+     */
     public record ItemComanda(
             int cantidad,
             String nombreProducto,
-            String observaciones
+            String observaciones,
+            List<String> componentesCombo
     ) {
         public ItemComanda {
             if (cantidad <= 0) {
@@ -55,6 +59,11 @@ public record ComandaImpresionResponse(
             if (nombreProducto == null || nombreProducto.isBlank()) {
                 throw new IllegalArgumentException("El nombre del producto no puede ser nulo o vacío");
             }
+            componentesCombo = componentesCombo != null ? List.copyOf(componentesCombo) : List.of();
+        }
+
+        public ItemComanda(int cantidad, String nombreProducto, String observaciones) {
+            this(cantidad, nombreProducto, observaciones, List.of());
         }
     }
 }
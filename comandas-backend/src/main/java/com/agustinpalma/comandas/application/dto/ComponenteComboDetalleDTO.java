@@ -0,0 +1,29 @@
+package com.agustinpalma.comandas.application.dto;
+
+/**
+ * DTO que representa la opción elegida para un componente de combo (HU-103).
+ *
+ * Usado en ItemDetalleDTO para que el frontend muestre el armado del combo
+ * debajo del ítem (ej: "Bebida: Coca-Cola 500ml").
+ */
+public record ComponenteComboDetalleDTO(
+    String componente,
+    String productoId,
+    String nombreProducto
+) {
+    public ComponenteComboDetalleDTO {
+        if (componente == null || componente.isBlank()) {
+            throw new IllegalArgumentException("El componente no puede ser nulo o vacío");
+        }
+        if (nombreProducto == null || nombreProducto.isBlank()) {
+            throw new IllegalArgumentException("El nombre del producto elegido no puede ser nulo o vacío");
+        }
+    }
+
+    /**
+     * Texto compacto para comandas e impresiones: "Bebida: Coca-Cola 500ml".
+     */
+    public String descripcion() {
+        return componente + ": " + nombreProducto;
+    }
+}
@@ -0,0 +1,29 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.ComponenteCombo;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.NotEmpty;
+import java.util.List;
+
+/**
+ * DTO de entrada para un componente de combo (HU-103).
+ *
+ * @param nombre nombre del componente (ej: "Bebida")
+ * @param opcionesIds UUIDs de los productos elegibles para el componente
+ */
+public record ComponenteComboRequest(
+    @NotBlank(message = "El nombre del componente es obligatorio")
+    String nombre,
+
+    @NotEmpty(message = "El componente debe tener al menos una opción")
+    List<String> opcionesIds
+) {
+
+    public ComponenteCombo toDomain() {
+        return new ComponenteCombo(
+            nombre,
+            opcionesIds == null ? List.of() : opcionesIds.stream().map(ProductoId::from).toList()
+        );
+    }
+}
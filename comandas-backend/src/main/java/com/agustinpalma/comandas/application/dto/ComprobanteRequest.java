@@ -0,0 +1,19 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotBlank;
+
+/**
+ * Archivo del comprobante de un gasto, codificado en Base64 (HU-117).
+ */
+public record ComprobanteRequest(
+
+    @NotBlank(message = "El nombre del archivo es obligatorio")
+    String nombreArchivo,
+
+    @NotBlank(message = "El tipo de archivo es obligatorio")
+    String contentType,
+
+    @NotBlank(message = "El contenido del archivo es obligatorio")
+    String contenidoBase64
+) {
+}
@@ -0,0 +1,20 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CondicionIva;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoFactura;
+
+/**
+ * DTO de salida de la validación de un CUIT/CUIL (HU-115).
+ *
+ * El CUIT siempre llega con formato y dígito verificador válidos.
+ * Si el padrón no pudo consultarse, validadoEnPadron = false y razón social,
+ * condición y letra sugerida quedan en null para que el operador las cargue.
+ */
+public record ContribuyenteResponse(
+    String cuit,
+    boolean validadoEnPadron,
+    String razonSocial,
+    CondicionIva condicionIva,
+    TipoFactura tipoFactura
+) {
+}
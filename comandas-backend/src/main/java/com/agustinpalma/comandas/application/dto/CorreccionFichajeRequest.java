@@ -0,0 +1,38 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Size;
+
+import java.time.LocalDateTime;
+
+/**
+ * DTO de entrada para corregir un fichaje o cargarlo a mano (HU-124).
+ * Lo autoriza siempre el dueño con su PIN.
+ *
+ * @param mozoId empleado del fichaje; solo se usa en el alta manual
+ * @param salida null deja al empleado como presente
+ * @param motivo por qué se corrige (queda en la auditoría del fichaje)
+ * @param duenoId dueño que autoriza
+ * @param pin PIN del dueño
+ */
+public record CorreccionFichajeRequest(
+
+    String mozoId,
+
+    @NotNull(message = "La hora de entrada es obligatoria")
+    LocalDateTime entrada,
+
+    LocalDateTime salida,
+
+    @NotBlank(message = "El motivo de la corrección es obligatorio")
+    @Size(max = 200, message = "El motivo no puede superar los 200 caracteres")
+    String motivo,
+
+    @NotBlank(message = "El dueño que autoriza es obligatorio")
+    String duenoId,
+
+    @NotBlank(message = "El PIN del dueño es obligatorio")
+    String pin
+) {
+}
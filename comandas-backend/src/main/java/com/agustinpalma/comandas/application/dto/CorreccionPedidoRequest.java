@@ -1,6 +1,7 @@
 package com.agustinpalma.comandas.application.dto;
 
 import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Moneda;
 
 import java.math.BigDecimal;
 import java.util.List;
@@ -13,11 +14,21 @@ import java.util.List;
  * 
  * @param items lista de correcciones por ítem (solo los que cambian)
  * @param pagos nueva lista completa de pagos (reemplaza los existentes)
+ * @param clienteId HU-104: cliente para los pagos CUENTA_CORRIENTE (nullable: si el pedido
+ *                  ya estaba cargado a un cliente, se mantiene el mismo)
  */
 public record CorreccionPedidoRequest(
     List<ItemCorreccion> items,
-    List<PagoCorreccion> pagos
+    List<PagoCorreccion> pagos,
+    String clienteId
 ) {
+    /**
+     * Constructor de retrocompatibilidad (sin cliente de cuenta corriente).
+     */
+    public CorreccionPedidoRequest(List<ItemCorreccion> items, List<PagoCorreccion> pagos) {
+        this(items, pagos, null);
+    }
+
     /**
      * Corrección de un ítem individual.
      * 
@@ -33,10 +44,29 @@ public record CorreccionPedidoRequest(
      * Pago corregido.
      * 
      * @param medio medio de pago (EFECTIVO, TARJETA, etc.)
-     * @param monto monto del pago
+     * @param monto monto del pago, en pesos
+     * @param moneda HU-151: moneda en que pagó el cliente (null = pesos); se
+     *               mantiene el tipo de cambio con el que se cobró
+     * @param referencia HU-156: número de cupón u operación del comprobante (opcional)
      */
     public record PagoCorreccion(
         MedioPago medio,
-        BigDecimal monto
-    ) {}
+        BigDecimal monto,
+        Moneda moneda,
+        String referencia
+    ) {
+        /**
+         * Constructor de retrocompatibilidad (pago en pesos).
+         */
+        public PagoCorreccion(MedioPago medio, BigDecimal monto) {
+            this(medio, monto, null, null);
+        }
+
+        /**
+         * Constructor de retrocompatibilidad (sin comprobante).
+         */
+        public PagoCorreccion(MedioPago medio, BigDecimal monto, Moneda moneda) {
+            this(medio, monto, moneda, null);
+        }
+    }
 }
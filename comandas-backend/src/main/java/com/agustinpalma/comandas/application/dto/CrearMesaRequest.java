@@ -3,5 +3,11 @@ package com.agustinpalma.comandas.application.dto;
 /**
  * DTO para la creación de mesas.
  * Solo requiere el número; el ID y localId se asignan en el caso de uso.
+ * HU-112: sector y celda son opcionales (alta desde el editor del mapa).
  */
-public record CrearMesaRequest(int numero) {}
+public record CrearMesaRequest(int numero, String sector, Integer posicionX, Integer posicionY) {
+
+    public CrearMesaRequest(int numero) {
+        this(numero, null, null, null);
+    }
+}
@@ -0,0 +1,14 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.util.List;
+
+/**
+ * Cuenta corriente de un proveedor: su saldo y el detalle de facturas (HU-131).
+ *
+ * @param facturas todas las facturas del proveedor, de la que vence primero a la última
+ */
+public record CuentaProveedorResponse(
+    SaldoProveedorResponse saldo,
+    List<FacturaProveedorResponse> facturas
+) {
+}
@@ -0,0 +1,74 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+
+/**
+ * Tablero en vivo de la jornada operativa (HU-114).
+ *
+ * Todo viene agregado: el frontend solo dibuja. La comparación es contra el
+ * mismo día de la semana pasada hasta la misma hora, para que a las 21:00
+ * no se compare media noche contra una noche completa.
+ *
+ * @param ventaAcumulada cobrado en la jornada hasta ahora (sin consumo interno A_CUENTA)
+ * @param ventaSemanaPasadaMismaHora cobrado el mismo día de la semana pasada hasta la misma hora
+ * @param ventaSemanaPasadaTotal cobrado en toda la jornada de la semana pasada
+ * @param variacionPorcentual (acumulada / semana pasada a la misma hora − 1) × 100;
+ *                            null si la semana pasada no hubo ventas a esta hora
+ * @param cantidadPedidos pedidos cobrados (los de consumo interno puro no cuentan)
+ * @param ticketPromedio ventaAcumulada / cantidadPedidos
+ * @param ventasPorHora una fila por hora de la jornada (06 a 05) con ventas en alguno de los dos días
+ * @param topProductos los 10 productos con mayor recaudación de la jornada
+ * @param mediosPago mezcla de medios de pago, de mayor a menor monto
+ */
+public record DashboardVentasResponse(
+    LocalDate fechaOperativa,
+    LocalDateTime generadoEn,
+    BigDecimal ventaAcumulada,
+    BigDecimal ventaSemanaPasadaMismaHora,
+    BigDecimal ventaSemanaPasadaTotal,
+    BigDecimal variacionPorcentual,
+    int cantidadPedidos,
+    BigDecimal ticketPromedio,
+    List<VentaPorHora> ventasPorHora,
+    List<ProductoVendidoReporte> topProductos,
+    List<MedioPagoVenta> mediosPago,
+    MesasAbiertas mesasAbiertas
+) {
+
+    /**
+     * @param hora hora del día (0-23) en que se cerraron los pedidos
+     */
+    public record VentaPorHora(
+        int hora,
+        BigDecimal ventas,
+        BigDecimal ventasSemanaPasada
+    ) {
+    }
+
+    /**
+     * @param porcentaje participación sobre ventaAcumulada (2 decimales)
+     */
+    public record MedioPagoVenta(
+        MedioPago medio,
+        BigDecimal monto,
+        BigDecimal porcentaje
+    ) {
+    }
+
+    /**
+     * @param cantidad mesas ocupadas en este momento
+     * @param totalMesas mesas del salón
+     * @param montoEnCurso suma de lo consumido en las mesas ocupadas (todavía sin cobrar)
+     */
+    public record MesasAbiertas(
+        int cantidad,
+        int totalMesas,
+        BigDecimal montoEnCurso
+    ) {
+    }
+}
@@ -0,0 +1,18 @@
+package com.agustinpalma.comandas.application.dto;
+
+/**
+ * DTO de salida con los datos que el cajero le pasa al cliente para que
+ * pague por transferencia (HU-156).
+ *
+ * @param titular nombre del local, como figura en la cuenta
+ * @param cuit    CUIT del local (vacío si no está configurado)
+ * @param alias   alias de la cuenta (null si no está configurado)
+ * @param cvu     CVU o CBU de la cuenta (null si no está configurado)
+ */
+public record DatosTransferenciaResponse(
+    String titular,
+    String cuit,
+    String alias,
+    String cvu
+) {
+}
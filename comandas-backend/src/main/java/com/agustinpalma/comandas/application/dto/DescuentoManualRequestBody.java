@@ -9,11 +9,14 @@ import java.util.UUID;
  * Contiene validaciones Bean Validation para la capa de presentación.
  * 
  * HU-14: Aplicar descuento manual por porcentaje o monto fijo
+ * HU-122: Motivo obligatorio y autorización opcional de encargado
  * 
  * Validaciones:
  * - tipoDescuento: obligatorio, PORCENTAJE o MONTO_FIJO
  * - valor: obligatorio, mayor a 0, máximo 2 decimales
+ * - razon: obligatoria
  * - usuarioId: obligatorio
+ * - autorizanteId + pin: solo para superar el tope del mozo
  */
 public record DescuentoManualRequestBody(
     @NotNull(message = "El tipo de descuento es obligatorio")
@@ -25,9 +28,15 @@ public record DescuentoManualRequestBody(
     @Digits(integer = 10, fraction = 2, message = "El valor debe tener máximo 2 decimales")
     BigDecimal valor,
 
+    @NotBlank(message = "El motivo del descuento es obligatorio")
     @Size(max = 255, message = "La razón no puede exceder 255 caracteres")
     String razon,
 
     @NotNull(message = "El usuarioId es obligatorio")
-    UUID usuarioId
+    UUID usuarioId,
+
+    UUID autorizanteId,
+
+    @Pattern(regexp = "\\d{4,6}", message = "El PIN debe tener entre 4 y 6 dígitos")
+    String pin
 ) {}
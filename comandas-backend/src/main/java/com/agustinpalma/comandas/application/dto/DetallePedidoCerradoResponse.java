@@ -1,6 +1,7 @@
 package com.agustinpalma.comandas.application.dto;
 
 import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Moneda;
 import com.agustinpalma.comandas.domain.model.ItemPedido;
 import com.agustinpalma.comandas.domain.model.Pago;
 import com.agustinpalma.comandas.domain.model.Pedido;
@@ -96,13 +97,23 @@ public record DetallePedidoCerradoResponse(
 
     /**
      * Detalle de un pago para edición en el modal de corrección.
+     * HU-143: el recargo se informa aparte; al corregir se recalcula con el vigente.
+     * HU-151: moneda y monto en esa moneda, para conservarlos al corregir.
+     * HU-152: el ajuste por redondeo es informativo; al corregir se recalcula.
+     * HU-156: la referencia del comprobante, para conservarla al corregir.
      */
     public record PagoDetalle(
         MedioPago medio,
-        BigDecimal monto
+        BigDecimal monto,
+        BigDecimal recargo,
+        Moneda moneda,
+        BigDecimal montoOriginal,
+        BigDecimal ajusteRedondeo,
+        String referencia
     ) {
         public static PagoDetalle fromDomain(Pago pago) {
-            return new PagoDetalle(pago.getMedio(), pago.getMonto());
+            return new PagoDetalle(pago.getMedio(), pago.getMonto(), pago.getRecargo(),
+                pago.getMoneda(), pago.getMontoOriginal(), pago.getAjusteRedondeo(), pago.getReferencia());
         }
     }
 
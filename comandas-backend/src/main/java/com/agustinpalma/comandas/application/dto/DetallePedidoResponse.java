@@ -11,6 +11,24 @@ import java.util.List;
  * - subtotal: total sin descuentos
  * - totalDescuentos: suma de todos los descuentos
  * - totalParcial: lo que paga el cliente (subtotal - descuentos)
+ *
+ * HU-108: Informa el canal y la lista de precios con la que se cotiza el pedido.
+ *
+ * HU-110: Informa el paso en marcha y el próximo paso que se puede marchar.
+ *
+ * HU-137: Informa si el pedido está marcado urgente para cocina.
+ *
+ * HU-138: Informa el horario de retiro prometido de un take away.
+ *
+ * HU-139: Informa la dirección y la zona de entrega de un delivery.
+ *
+ * HU-140: Informa el repartidor asignado y si ya salió.
+ *
+ * HU-142: Informa la seña de la reserva descontada del total.
+ *
+ * HU-160: Informa lo pagado a cuenta y el saldo que falta cobrar.
+ *
+ * HU-168: Informa el cliente de la ficha vinculado al pedido.
  */
 public record DetallePedidoResponse(
     String pedidoId,
@@ -22,7 +40,22 @@ public record DetallePedidoResponse(
     BigDecimal subtotal,           // Total sin descuentos
     BigDecimal totalDescuentos,    // Suma de descuentos de todos los ítems
     BigDecimal totalParcial,       // Lo que paga el cliente
-    List<AjusteEconomicoDTO> ajustesEconomicos  // Narrativa económica explícita
+    List<AjusteEconomicoDTO> ajustesEconomicos,  // Narrativa económica explícita
+    String canal,                  // HU-108: SALON, TAKE_AWAY o DELIVERY
+    String nombreListaPrecios,     // HU-108: null = precio de catálogo
+    String tiempoEnMarcha,         // HU-110: null si todavía no se envió ningún paso
+    String siguienteTiempo,        // HU-110: próximo paso a marchar, null si no hay retenidos
+    boolean urgente,               // HU-137: prioridad en cocina
+    LocalDateTime horaPrometida,   // HU-138: null si no se prometió retiro
+    String direccionEntrega,       // HU-139: null si no se cargó la entrega
+    String zonaDeliveryId,         // HU-139: null si no se cargó la entrega
+    String repartidorId,           // HU-140: null si no se asignó repartidor
+    LocalDateTime salidaReparto,   // HU-140: null si el repartidor no salió
+    BigDecimal montoSenia,         // HU-142: cero si no se sentó una reserva con seña
+    BigDecimal pagadoACuenta,      // HU-160: cero si nadie pagó a cuenta
+    BigDecimal saldoPendiente,     // HU-160: totalParcial - pagadoACuenta
+    long version,                  // HU-165: se manda de vuelta con cada cambio del pedido
+    String clienteId               // HU-168: null si no se vinculó un cliente
 ) {
     /**
      * Valida que los campos obligatorios no sean nulos.
@@ -0,0 +1,34 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.DecimalMin;
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Size;
+
+import java.math.BigDecimal;
+
+/**
+ * DTO de entrada para registrar una devolución sobre un pedido cerrado (HU-121).
+ *
+ * @param monto importe a reintegrar (no puede superar lo cobrado menos lo ya devuelto)
+ * @param motivo por qué se devuelve (queda en la descripción del egreso)
+ * @param encargadoId encargado que autoriza
+ * @param pin PIN del encargado
+ */
+public record DevolucionRequest(
+
+    @NotNull(message = "El monto es obligatorio")
+    @DecimalMin(value = "0.01", message = "El monto debe ser mayor a cero")
+    BigDecimal monto,
+
+    @NotBlank(message = "El motivo de la devolución es obligatorio")
+    @Size(max = 200, message = "El motivo no puede superar los 200 caracteres")
+    String motivo,
+
+    @NotBlank(message = "El encargado que autoriza es obligatorio")
+    String encargadoId,
+
+    @NotBlank(message = "El PIN del encargado es obligatorio")
+    String pin
+) {
+}
@@ -0,0 +1,35 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+
+/**
+ * DTO de salida con las devoluciones de un pedido cerrado (HU-121).
+ *
+ * @param totalCobrado pagos comerciales del pedido (sin A_CUENTA)
+ * @param totalDevuelto suma de las devoluciones registradas
+ * @param disponible lo que todavía se puede devolver
+ */
+public record DevolucionesPedidoResponse(
+    String pedidoId,
+    int numeroPedido,
+    BigDecimal totalCobrado,
+    BigDecimal totalDevuelto,
+    BigDecimal disponible,
+    List<DevolucionResumen> devoluciones
+) {
+
+    /**
+     * @param autorizadoPor nombre del encargado que autorizó
+     */
+    public record DevolucionResumen(
+        String id,
+        BigDecimal monto,
+        String descripcion,
+        LocalDateTime fecha,
+        String numeroComprobante,
+        String autorizadoPor
+    ) {
+    }
+}
@@ -0,0 +1,23 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.Producto;
+
+/**
+ * Payload del evento en tiempo real "producto-disponibilidad" (HU-125).
+ * Alcanza para que cada terminal actualice su catálogo y avise al mozo.
+ */
+public record DisponibilidadProductoEvento(
+    String productoId,
+    String nombre,
+    boolean agotado
+) {
+    public static final String NOMBRE = "producto-disponibilidad";
+
+    public static DisponibilidadProductoEvento fromDomain(Producto producto) {
+        return new DisponibilidadProductoEvento(
+            producto.getId().getValue().toString(),
+            producto.getNombre(),
+            producto.isAgotado()
+        );
+    }
+}
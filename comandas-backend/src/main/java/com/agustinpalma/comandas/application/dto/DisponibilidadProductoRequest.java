@@ -0,0 +1,13 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotNull;
+
+/**
+ * Body del switch rápido "sin stock" (HU-125).
+ *
+ * @param agotado true marca el producto como 86, false lo vuelve a habilitar
+ */
+public record DisponibilidadProductoRequest(
+    @NotNull(message = "Indicá si el producto está agotado")
+    Boolean agotado
+) {}
@@ -0,0 +1,57 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.Valid;
+import jakarta.validation.constraints.NotEmpty;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Positive;
+import jakarta.validation.constraints.Size;
+
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * DTO de entrada para guardar el mapa del salón (HU-112).
+ * Cada elemento fija número, sector, celda y capacidad de una mesa; las mesas del
+ * local que no vienen en el listado conservan su ubicación actual.
+ *
+ * @param mesas ubicación de cada mesa editada
+ */
+public record DisposicionSalonRequest(
+
+    @NotEmpty(message = "La disposición debe incluir al menos una mesa")
+    @Valid
+    List<UbicacionMesa> mesas
+) {
+
+    /**
+     * @param sector nombre del sector (vacío = sector por defecto)
+     * @param posicionX columna en el mapa (null = ubicación automática)
+     * @param posicionY fila en el mapa (null = ubicación automática)
+     * @param capacidad HU-149: personas que entran (null = sin definir)
+     */
+    public record UbicacionMesa(
+        @NotNull(message = "La mesa es obligatoria")
+        UUID mesaId,
+
+        @Positive(message = "El número de mesa debe ser mayor a 0")
+        int numero,
+
+        @Size(max = 30, message = "El nombre del sector no puede superar los 30 caracteres")
+        String sector,
+
+        Integer posicionX,
+
+        Integer posicionY,
+
+        @Positive(message = "La capacidad de la mesa debe ser mayor a 0")
+        Integer capacidad
+    ) {
+
+        /**
+         * Constructor de retrocompatibilidad (sin capacidad).
+         */
+        public UbicacionMesa(UUID mesaId, int numero, String sector, Integer posicionX, Integer posicionY) {
+            this(mesaId, numero, sector, posicionX, posicionY, null);
+        }
+    }
+}
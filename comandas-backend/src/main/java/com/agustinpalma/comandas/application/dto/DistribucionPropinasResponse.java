@@ -0,0 +1,76 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DistribucionPropinas;
+import com.agustinpalma.comandas.domain.model.DomainEnums.ModoRepartoPropinas;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoTurno;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Mozo;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * Reparto del pozo de propinas de un turno cerrado (HU-141).
+ *
+ * @param tipoTurno null si el turno ya no se encuentra
+ * @param sinRepartir parte del pozo que no se repartió porque nadie participó
+ */
+public record DistribucionPropinasResponse(
+    UUID id,
+    UUID turnoId,
+    TipoTurno tipoTurno,
+    LocalDateTime fecha,
+    ModoRepartoPropinas modo,
+    BigDecimal pozo,
+    BigDecimal sinRepartir,
+    List<PartePropinaDTO> partes
+) {
+
+    /**
+     * @param personal personal del local por id, para los nombres y roles
+     */
+    public static DistribucionPropinasResponse fromDomain(DistribucionPropinas distribucion, TipoTurno tipoTurno,
+                                                          Map<MozoId, Mozo> personal) {
+        List<PartePropinaDTO> partes = distribucion.getPartes().stream()
+            .map(parte -> {
+                Mozo empleado = personal.get(parte.getMozoId());
+                return new PartePropinaDTO(
+                    parte.getMozoId().getValue(),
+                    empleado != null ? empleado.getNombre() : "Empleado eliminado",
+                    empleado != null ? empleado.getRol() : null,
+                    parte.getMinutos(),
+                    parte.getPeso(),
+                    parte.getMonto()
+                );
+            })
+            .toList();
+        return new DistribucionPropinasResponse(
+            distribucion.getId().getValue(),
+            distribucion.getTurnoId().getValue(),
+            tipoTurno,
+            distribucion.getFecha(),
+            distribucion.getModo(),
+            distribucion.getPozo(),
+            distribucion.getSinRepartir(),
+            partes
+        );
+    }
+
+    /**
+     * @param minutos minutos fichados dentro del turno
+     * @param peso con cuánto entró al reparto: 1, los minutos o los puntos del puesto según el modo
+     */
+    public record PartePropinaDTO(
+        UUID mozoId,
+        String nombre,
+        RolPersonal rol,
+        long minutos,
+        long peso,
+        BigDecimal monto
+    ) {
+    }
+}
@@ -8,8 +8,15 @@ import java.math.BigDecimal;
  *
  * @param monto monto del egreso (debe ser > 0)
  * @param descripcion descripción del egreso (ej: "Productos de limpieza")
+ * @param categoriaId HU-130: categoría del egreso (nullable = sin categoría)
  */
 public record EgresoRequestBody(
     BigDecimal monto,
-    String descripcion
-) {}
+    String descripcion,
+    String categoriaId
+) {
+
+    public EgresoRequestBody(BigDecimal monto, String descripcion) {
+        this(monto, descripcion, null);
+    }
+}
@@ -14,6 +14,7 @@ import java.time.LocalDateTime;
  * @param fecha fecha y hora del registro
  * @param tipo tipo de movimiento (EGRESO)
  * @param numeroComprobante número de comprobante generado automáticamente
+ * @param categoriaId HU-130: categoría del egreso (null = sin categoría)
  */
 public record EgresoResponse(
     String id,
@@ -21,7 +22,8 @@ public record EgresoResponse(
     String descripcion,
     LocalDateTime fecha,
     String tipo,
-    String numeroComprobante
+    String numeroComprobante,
+    String categoriaId
 ) {
     /**
      * Factory method para crear la respuesta desde el modelo de dominio.
@@ -36,7 +38,8 @@ public record EgresoResponse(
             movimiento.getDescripcion(),
             movimiento.getFecha(),
             movimiento.getTipo().name(),
-            movimiento.getNumeroComprobante()
+            movimiento.getNumeroComprobante(),
+            movimiento.getCategoriaId() != null ? movimiento.getCategoriaId().toString() : null
         );
     }
 }
@@ -0,0 +1,29 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CondicionIva;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Size;
+
+import java.util.UUID;
+
+/**
+ * DTO de entrada para facturar un pedido cerrado (HU-115).
+ *
+ * @param cuit         CUIT/CUIL del receptor, con o sin guiones. Null para consumidor final
+ * @param razonSocial  se usa si el padrón no está disponible; si responde, manda el padrón
+ * @param condicionIva condición frente al IVA del receptor, idem razonSocial
+ */
+public record EmitirFacturaRequest(
+
+    @NotNull(message = "El pedido a facturar es obligatorio")
+    UUID pedidoId,
+
+    String cuit,
+
+    @Size(max = 120, message = "La razón social no puede superar los 120 caracteres")
+    String razonSocial,
+
+    @NotNull(message = "La condición frente al IVA del receptor es obligatoria")
+    CondicionIva condicionIva
+) {
+}
@@ -0,0 +1,33 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Positive;
+import jakarta.validation.constraints.Size;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+
+/**
+ * DTO de entrada para vender una tarjeta de regalo (HU-144).
+ *
+ * @param monto        saldo con el que se emite; se cobra en efectivo y entra a la caja
+ * @param vencimiento  último día en que se puede usar
+ * @param comprador    quien la compra (opcional)
+ * @param destinatario a quien se regala (opcional)
+ */
+public record EmitirTarjetaRegaloRequest(
+
+    @NotNull(message = "El monto de la tarjeta de regalo es obligatorio")
+    @Positive(message = "El monto de la tarjeta de regalo tiene que ser mayor a cero")
+    BigDecimal monto,
+
+    @NotNull(message = "La fecha de vencimiento es obligatoria")
+    LocalDate vencimiento,
+
+    @Size(max = 80, message = "El comprador no puede superar los 80 caracteres")
+    String comprador,
+
+    @Size(max = 80, message = "El destinatario no puede superar los 80 caracteres")
+    String destinatario
+) {
+}
@@ -9,6 +9,7 @@ import java.time.LocalDateTime;
  * - El buffer ESC/POS codificado en Base64, listo para enviar a la impresora
  * - El timestamp del envío (para que el frontend sepa cuándo fue el último envío)
  * - Si había ítems nuevos para imprimir
+ * - HU-138: El horario de retiro prometido, si el pedido es take away
  *
  * El frontend recibe el Base64, lo decodifica y lo envía vía Tauri al dispositivo USB/LAN.
  * En modo mock (sin Tauri), puede mostrarlo como descarga o log de consola.
@@ -17,8 +18,14 @@ public record EnviarComandaResponse(
     String escPosBase64,
     LocalDateTime timestampEnvio,
     int cantidadItemsNuevos,
-    int cantidadItemsTotal
+    int cantidadItemsTotal,
+    LocalDateTime horaPrometida
 ) {
+    public EnviarComandaResponse(String escPosBase64, LocalDateTime timestampEnvio,
+                                 int cantidadItemsNuevos, int cantidadItemsTotal) {
+        this(escPosBase64, timestampEnvio, cantidadItemsNuevos, cantidadItemsTotal, null);
+    }
+
     public EnviarComandaResponse {
         if (escPosBase64 == null || escPosBase64.isBlank()) {
             throw new IllegalArgumentException("El buffer ESC/POS no puede ser nulo o vacío");
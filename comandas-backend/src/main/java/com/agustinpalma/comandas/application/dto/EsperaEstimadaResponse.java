@@ -0,0 +1,29 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.service.EsperaCocinaService.EsperaEstimada;
+
+import java.time.LocalDateTime;
+
+/**
+ * HU-138: Espera estimada de un pedido que entra ahora a cocina.
+ *
+ * @param comandasEnCola comandas en preparación delante del pedido
+ * @param minutosPreparacion tiempo promedio reciente de una comanda
+ * @param minutosEspera espera a informar al cliente
+ * @param horaEstimada horario de retiro estimado
+ */
+public record EsperaEstimadaResponse(
+    int comandasEnCola,
+    int minutosPreparacion,
+    int minutosEspera,
+    LocalDateTime horaEstimada
+) {
+    public static EsperaEstimadaResponse fromDomain(EsperaEstimada espera) {
+        return new EsperaEstimadaResponse(
+            espera.comandasEnCola(),
+            espera.minutosPreparacion(),
+            espera.minutosEspera(),
+            espera.horaEstimada()
+        );
+    }
+}
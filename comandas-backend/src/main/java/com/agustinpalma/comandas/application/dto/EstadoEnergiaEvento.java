@@ -0,0 +1,29 @@
+package com.agustinpalma.comandas.application.dto;
+
+/**
+ * Aviso de la terminal conectada a la UPS y payload del evento en tiempo real
+ * "energia" (HU-155).
+ *
+ * La app de escritorio lo manda cuando el SO informa un corte de luz, cuando
+ * está por apagar el servidor y cuando vuelve la luz; el backend lo reenvía a
+ * todas las terminales para que nadie siga cargando pedidos a ciegas.
+ *
+ * @param bateria            porcentaje de la batería de la UPS (null si el SO no lo informa)
+ * @param segundosParaApagar cuánto falta para el apagado ordenado (null si no hay apagado en curso)
+ */
+public record EstadoEnergiaEvento(
+    Estado estado,
+    Integer bateria,
+    Integer segundosParaApagar
+) {
+    public static final String NOMBRE = "energia";
+
+    public enum Estado {
+        /** Se cortó la luz y la terminal sigue con la batería de la UPS */
+        SIN_RED,
+        /** El servidor se apaga en segundos: no hay que cobrar ni cargar nada */
+        APAGANDO,
+        /** Volvió la luz */
+        RESTABLECIDA
+    }
+}
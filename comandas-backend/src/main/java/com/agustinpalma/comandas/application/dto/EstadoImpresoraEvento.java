@@ -0,0 +1,37 @@
+package com.agustinpalma.comandas.application.dto;
+
+/**
+ * Aviso de la app de escritorio y payload del evento en tiempo real
+ * "impresora" (HU-158).
+ *
+ * La app consulta periódicamente el estado de la impresora de cada estación
+ * (ESC/POS DLE EOT y conexión de red) y manda este aviso cuando cambia: así
+ * la caja se entera de que la cocina se quedó sin papel antes de que el
+ * cocinero reclame que no le llega nada.
+ *
+ * @param estacion estación que atiende la impresora (caja, cocina, barra)
+ * @param detalle  texto para el operador, ya en el idioma de la terminal que consultó
+ */
+public record EstadoImpresoraEvento(
+    String estacion,
+    Estado estado,
+    String detalle
+) {
+    public static final String NOMBRE = "impresora";
+
+    public enum Estado {
+        /** Imprime normalmente */
+        LISTA,
+        /** El sensor avisa que el rollo está por terminarse */
+        POCO_PAPEL,
+        /** No tiene papel: los tickets no salen */
+        SIN_PAPEL,
+        TAPA_ABIERTA,
+        /** Papel trabado, cortador o cabezal: hay que reiniciarla */
+        ERROR,
+        /** No acepta la conexión o el dispositivo USB no está */
+        DESCONECTADA,
+        /** Conecta pero no contesta la consulta de estado */
+        SIN_RESPUESTA
+    }
+}
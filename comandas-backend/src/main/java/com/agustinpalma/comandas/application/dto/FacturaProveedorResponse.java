@@ -0,0 +1,59 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoFacturaProveedor;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.FacturaProveedor;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * DTO de salida con una factura de proveedor y sus pagos (HU-131).
+ *
+ * @param gastoId        compra que originó la deuda
+ * @param diasParaVencer negativo si ya venció
+ */
+public record FacturaProveedorResponse(
+    UUID id,
+    UUID proveedorId,
+    String nombreProveedor,
+    UUID gastoId,
+    String numero,
+    LocalDate fechaEmision,
+    LocalDate fechaVencimiento,
+    BigDecimal monto,
+    BigDecimal totalPagado,
+    BigDecimal saldo,
+    EstadoFacturaProveedor estado,
+    boolean vencida,
+    long diasParaVencer,
+    List<PagoResponse> pagos
+) {
+
+    public record PagoResponse(LocalDateTime fecha, BigDecimal monto, MedioPago medioPago, boolean pagadoConCaja) {
+    }
+
+    public static FacturaProveedorResponse fromDomain(FacturaProveedor factura, String nombreProveedor, LocalDate hoy) {
+        return new FacturaProveedorResponse(
+            factura.getId().getValue(),
+            factura.getProveedorId().getValue(),
+            nombreProveedor,
+            factura.getGastoId().getValue(),
+            factura.getNumero(),
+            factura.getFechaEmision(),
+            factura.getFechaVencimiento(),
+            factura.getMonto(),
+            factura.getTotalPagado(),
+            factura.getSaldo(),
+            factura.getEstado(),
+            factura.estaVencida(hoy),
+            factura.diasParaVencer(hoy),
+            factura.getPagos().stream()
+                .map(p -> new PagoResponse(p.getFecha(), p.getMonto(), p.getMedioPago(), p.fuePagadoConCaja()))
+                .toList()
+        );
+    }
+}
@@ -0,0 +1,57 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CondicionIva;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoFactura;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoFactura;
+import com.agustinpalma.comandas.domain.model.Factura;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * DTO de salida con una factura emitida (HU-115).
+ *
+ * @param numero     formato de impresión PPPPP-NNNNNNNN
+ * @param cuit       formateado XX-XXXXXXXX-X, null para consumidor final
+ * @param cae        null mientras esté PENDIENTE_AUTORIZACION
+ */
+public record FacturaResponse(
+    UUID id,
+    UUID pedidoId,
+    TipoFactura tipo,
+    String numero,
+    LocalDateTime fechaEmision,
+    String cuit,
+    String razonSocial,
+    CondicionIva condicionIva,
+    BigDecimal alicuotaIva,
+    BigDecimal importeNeto,
+    BigDecimal importeIva,
+    BigDecimal importeTotal,
+    EstadoFactura estado,
+    String cae,
+    LocalDate caeVencimiento
+) {
+
+    public static FacturaResponse fromDomain(Factura factura) {
+        return new FacturaResponse(
+            factura.getId().getValue(),
+            factura.getPedidoId().getValue(),
+            factura.getTipo(),
+            factura.numeroFormateado(),
+            factura.getFechaEmision(),
+            factura.getReceptorCuit() != null ? factura.getReceptorCuit().formateado() : null,
+            factura.getReceptorRazonSocial(),
+            factura.getReceptorCondicionIva(),
+            factura.getAlicuotaIva(),
+            factura.getImporteNeto(),
+            factura.getImporteIva(),
+            factura.getImporteTotal(),
+            factura.getEstado(),
+            factura.getCae(),
+            factura.getCaeVencimiento()
+        );
+    }
+}
@@ -0,0 +1,52 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioFichaje;
+import com.agustinpalma.comandas.domain.model.Fichaje;
+
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * DTO de salida de un fichaje (HU-124).
+ *
+ * @param salida null mientras el empleado está presente
+ * @param minutosTrabajados cero mientras no tiene salida
+ * @param incompleto salida olvidada: sigue abierto después de la duración máxima
+ * @param entradaOriginal marca de la terminal antes de la primera corrección
+ * @param corregidoPor nombre del dueño que autorizó la corrección
+ */
+public record FichajeResponse(
+    UUID id,
+    UUID mozoId,
+    String nombreEmpleado,
+    MedioFichaje medio,
+    LocalDateTime entrada,
+    LocalDateTime salida,
+    long minutosTrabajados,
+    boolean incompleto,
+    LocalDateTime entradaOriginal,
+    LocalDateTime salidaOriginal,
+    String motivoCorreccion,
+    String corregidoPor,
+    LocalDateTime fechaCorreccion
+) {
+
+    public static FichajeResponse fromDomain(Fichaje fichaje, String nombreEmpleado, String corregidoPor,
+                                             LocalDateTime ahora) {
+        return new FichajeResponse(
+            fichaje.getId().getValue(),
+            fichaje.getMozoId().getValue(),
+            nombreEmpleado,
+            fichaje.getMedio(),
+            fichaje.getEntrada(),
+            fichaje.getSalida(),
+            fichaje.duracion().toMinutes(),
+            fichaje.estaVencido(ahora),
+            fichaje.getEntradaOriginal(),
+            fichaje.getSalidaOriginal(),
+            fichaje.getMotivoCorreccion(),
+            corregidoPor,
+            fichaje.getFechaCorreccion()
+        );
+    }
+}
@@ -0,0 +1,16 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotBlank;
+
+/**
+ * Foto de un producto para la carta digital, codificada en Base64 (HU-127).
+ */
+public record FotoProductoRequest(
+
+    @NotBlank(message = "El tipo de archivo es obligatorio")
+    String contentType,
+
+    @NotBlank(message = "El contenido del archivo es obligatorio")
+    String contenidoBase64
+) {
+}
@@ -0,0 +1,15 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Foto vigente de un producto (HU-127).
+ *
+ * @param actualizadaEn versión de la foto; va en la URL de la imagen para no mostrar una vieja en caché
+ */
+public record FotoProductoResponse(
+    UUID productoId,
+    LocalDateTime actualizadaEn
+) {
+}
@@ -0,0 +1,28 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.FranjaHoraria;
+
+import java.time.LocalTime;
+
+/**
+ * Horario en que se ofrece un producto o una categoría (HU-161).
+ *
+ * En la edición, omitir el horario lo deja como está; mandarlo con las dos
+ * horas vacías lo quita (se ofrece todo el día).
+ *
+ * @param desde hora (HH:mm) desde la que se ofrece
+ * @param hasta hora (HH:mm) en que deja de ofrecerse; si es anterior a desde, cruza la medianoche
+ */
+public record FranjaHorariaRequest(
+    LocalTime desde,
+    LocalTime hasta
+) {
+
+    /**
+     * @return la franja, o null si viene sin horas
+     * @throws IllegalArgumentException si viene una sola hora o las dos iguales
+     */
+    public FranjaHoraria toDomain() {
+        return FranjaHoraria.de(desde, hasta);
+    }
+}
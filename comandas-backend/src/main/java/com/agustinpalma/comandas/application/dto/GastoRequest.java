@@ -0,0 +1,84 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaGasto;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import jakarta.validation.Valid;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Positive;
+import jakarta.validation.constraints.Size;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * DTO de entrada para registrar una compra o un gasto (HU-117).
+ *
+ * @param fecha          fecha del comprobante (null = hoy)
+ * @param items          productos que ingresan al stock (solo MERCADERIA; null o vacío = sin stock)
+ * @param pagadoConCaja  true si el efectivo salió de la caja: registra además el egreso de caja
+ * @param comprobante    archivo adjunto opcional; también se puede adjuntar después
+ * @param cuentaCorriente HU-131: datos de la factura a pagar; obligatorio si el medio es CUENTA_CORRIENTE
+ */
+public record GastoRequest(
+
+    LocalDate fecha,
+
+    @NotNull(message = "La categoría es obligatoria")
+    CategoriaGasto categoria,
+
+    @Size(max = 100, message = "El proveedor no puede superar los 100 caracteres")
+    String proveedor,
+
+    @Size(max = 200, message = "La descripción no puede superar los 200 caracteres")
+    String descripcion,
+
+    @NotNull(message = "El monto es obligatorio")
+    @Positive(message = "El monto debe ser mayor a cero")
+    BigDecimal monto,
+
+    @NotNull(message = "El medio de pago es obligatorio")
+    MedioPago medioPago,
+
+    @Valid
+    List<ItemCompraRequest> items,
+
+    boolean pagadoConCaja,
+
+    @Valid
+    ComprobanteRequest comprobante,
+
+    @Valid
+    CuentaCorrienteRequest cuentaCorriente
+) {
+
+    public GastoRequest(LocalDate fecha, CategoriaGasto categoria, String proveedor, String descripcion,
+                        BigDecimal monto, MedioPago medioPago, List<ItemCompraRequest> items,
+                        boolean pagadoConCaja, ComprobanteRequest comprobante) {
+        this(fecha, categoria, proveedor, descripcion, monto, medioPago, items, pagadoConCaja, comprobante, null);
+    }
+
+    public record ItemCompraRequest(
+        @NotNull(message = "El producto es obligatorio")
+        UUID productoId,
+
+        @Positive(message = "La cantidad debe ser mayor a cero")
+        int cantidad
+    ) {
+    }
+
+    /**
+     * @param fechaVencimiento null = según el plazo de pago del proveedor
+     */
+    public record CuentaCorrienteRequest(
+        @NotNull(message = "El proveedor es obligatorio")
+        UUID proveedorId,
+
+        @Size(max = 30, message = "El número de factura no puede superar los 30 caracteres")
+        String numeroFactura,
+
+        LocalDate fechaVencimiento
+    ) {
+    }
+}
@@ -0,0 +1,49 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaGasto;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.Gasto;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * DTO de salida con los datos de una compra o gasto (HU-117).
+ *
+ * @param nombreArchivoComprobante null si todavía no se adjuntó el comprobante
+ */
+public record GastoResponse(
+    UUID id,
+    LocalDate fecha,
+    CategoriaGasto categoria,
+    String proveedor,
+    String descripcion,
+    BigDecimal monto,
+    MedioPago medioPago,
+    List<ItemCompraResponse> items,
+    boolean pagadoConCaja,
+    String nombreArchivoComprobante
+) {
+
+    public record ItemCompraResponse(UUID productoId, String nombreProducto, int cantidad) {
+    }
+
+    public static GastoResponse fromDomain(Gasto gasto) {
+        return new GastoResponse(
+            gasto.getId().getValue(),
+            gasto.getFecha(),
+            gasto.getCategoria(),
+            gasto.getProveedor(),
+            gasto.getDescripcion(),
+            gasto.getMonto(),
+            gasto.getMedioPago(),
+            gasto.getItems().stream()
+                .map(i -> new ItemCompraResponse(i.getProductoId().getValue(), i.getNombreProducto(), i.getCantidad()))
+                .toList(),
+            gasto.fuePagadoConCaja(),
+            gasto.getNombreArchivoComprobante()
+        );
+    }
+}
@@ -0,0 +1,16 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ProveedorLinkPago;
+import jakarta.validation.constraints.NotNull;
+
+/**
+ * Pedido de un link de pago para un delivery (HU-128).
+ *
+ * @param telefono WhatsApp del cliente (opcional): sin teléfono, WhatsApp deja elegir el contacto
+ */
+public record GenerarLinkPagoRequest(
+    @NotNull(message = "Elegí la pasarela de pago")
+    ProveedorLinkPago proveedor,
+    String telefono
+) {
+}
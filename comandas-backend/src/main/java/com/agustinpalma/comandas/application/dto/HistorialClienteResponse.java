@@ -0,0 +1,56 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.Pedido;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+
+/**
+ * Historial de consumo de un cliente de la ficha (HU-168).
+ *
+ * ticketPromedio = totalConsumido / cantidadPedidos
+ *
+ * @param ultimaVisita fecha de cierre del pedido más reciente (null si nunca consumió)
+ * @param proximoCumpleanios próxima fecha de cumpleaños desde hoy (null si no se cargó la fecha de nacimiento)
+ * @param productosFavoritos los productos que más pidió, por unidades
+ * @param pedidos pedidos cerrados, los más recientes primero
+ */
+public record HistorialClienteResponse(
+    String clienteId,
+    String nombre,
+    String alergias,
+    String preferencias,
+    int cantidadPedidos,
+    BigDecimal totalConsumido,
+    BigDecimal ticketPromedio,
+    LocalDateTime ultimaVisita,
+    LocalDate proximoCumpleanios,
+    List<ProductoFavoritoDTO> productosFavoritos,
+    List<PedidoHistorialDTO> pedidos
+) {
+
+    public record ProductoFavoritoDTO(String nombre, int unidades) {
+    }
+
+    public record PedidoHistorialDTO(
+        String pedidoId,
+        int numero,
+        String canal,
+        LocalDateTime fechaCierre,
+        BigDecimal total,
+        BigDecimal promoCumpleanios
+    ) {
+        public static PedidoHistorialDTO fromDomain(Pedido pedido) {
+            return new PedidoHistorialDTO(
+                pedido.getId().getValue().toString(),
+                pedido.getNumero(),
+                pedido.getCanal().name(),
+                pedido.getFechaCierre(),
+                pedido.getMontoTotalFinal(),
+                pedido.getMontoPromoCumpleanios()
+            );
+        }
+    }
+}
@@ -0,0 +1,18 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Historial de precios de un producto (HU-146).
+ *
+ * @param cambios del más nuevo al más viejo
+ */
+public record HistorialPreciosProductoResponse(
+    UUID productoId,
+    String nombre,
+    BigDecimal precioActual,
+    List<CambioPrecioProductoResponse> cambios
+) {
+}
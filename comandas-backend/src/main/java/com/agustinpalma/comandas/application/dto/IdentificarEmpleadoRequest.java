@@ -0,0 +1,22 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.NotNull;
+
+import java.util.UUID;
+
+/**
+ * DTO de entrada para identificarse con el PIN en la terminal (HU-169).
+ *
+ * @param empleadoId empleado elegido de la lista
+ * @param pin PIN del empleado
+ */
+public record IdentificarEmpleadoRequest(
+
+    @NotNull(message = "El empleado es obligatorio")
+    UUID empleadoId,
+
+    @NotBlank(message = "El PIN es obligatorio")
+    String pin
+) {
+}
@@ -0,0 +1,29 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.PositiveOrZero;
+import jakarta.validation.constraints.Size;
+
+import java.math.BigDecimal;
+
+/**
+ * DTO de entrada para dar de alta o editar un insumo (HU-118).
+ *
+ * @param costoUnitario costo por unidad de medida (por kilo, por litro o por unidad)
+ */
+public record InsumoRequest(
+
+    @NotBlank(message = "El nombre del insumo es obligatorio")
+    @Size(max = 60, message = "El nombre del insumo no puede superar los 60 caracteres")
+    String nombre,
+
+    @NotNull(message = "La unidad es obligatoria")
+    UnidadInsumo unidad,
+
+    @NotNull(message = "El costo es obligatorio")
+    @PositiveOrZero(message = "El costo no puede ser negativo")
+    BigDecimal costoUnitario
+) {
+}
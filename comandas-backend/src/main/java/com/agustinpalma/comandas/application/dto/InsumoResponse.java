@@ -0,0 +1,41 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import com.agustinpalma.comandas.domain.model.Insumo;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * DTO de salida con los datos de un insumo (HU-118).
+ *
+ * @param stockActual HU-133: stock teórico en la unidad del insumo (solo significativo si controlaStock)
+ * @param stockMinimo HU-134: null si no se repone automáticamente
+ * @param proveedorHabitualId HU-134: null si no tiene proveedor habitual
+ */
+public record InsumoResponse(
+    UUID id,
+    String nombre,
+    UnidadInsumo unidad,
+    BigDecimal costoUnitario,
+    boolean controlaStock,
+    BigDecimal stockActual,
+    BigDecimal stockMinimo,
+    UUID proveedorHabitualId,
+    boolean bajoMinimo
+) {
+
+    public static InsumoResponse fromDomain(Insumo insumo) {
+        return new InsumoResponse(
+            insumo.getId().getValue(),
+            insumo.getNombre(),
+            insumo.getUnidad(),
+            insumo.getCostoUnitario(),
+            insumo.isControlaStock(),
+            insumo.getStockActual(),
+            insumo.getStockMinimo(),
+            insumo.getProveedorHabitualId() != null ? insumo.getProveedorHabitualId().getValue() : null,
+            insumo.estaBajoMinimo()
+        );
+    }
+}
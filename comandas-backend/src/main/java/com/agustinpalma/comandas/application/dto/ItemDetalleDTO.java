@@ -16,6 +16,18 @@ import java.util.List;
  * HU-05.1 + HU-22: Incluye lista de extras como sub-elementos.
  * Los extras se muestran debajo del producto principal, NO como líneas independientes.
  * 
+ * HU-103: Incluye las opciones elegidas de cada componente si el ítem es un combo.
+ * 
+ * HU-110: Incluye el paso de servicio y si el ítem está retenido esperando su marcha.
+ * 
+ * HU-137: Incluye la marca de urgencia del ítem.
+ * 
+ * HU-139: Marca el ítem de costo de envío (no se edita ni se quita a mano).
+ * 
+ * HU-147: Incluye las alergias declaradas para el comensal del ítem.
+ * 
+ * HU-148: Incluye los ingredientes de la receta que el cliente pidió sacar.
+ * 
  * Se usa como parte de la respuesta en la consulta de detalle de pedido.
  * 
  * puedeAgregarDiscoExtra: true si el ítem está en la variante estructural máxima
@@ -39,7 +51,27 @@ public record ItemDetalleDTO(
     boolean puedeAgregarDiscoExtra,   // true si está en la variante máxima del grupo
 
     // HU-29: Flag de ítem nuevo respecto al último envío a cocina
-    boolean esNuevo                   // true si fue agregado después del último envío
+    boolean esNuevo,                  // true si fue agregado después del último envío
+
+    // HU-103: Armado del combo (vacía si no es combo)
+    List<ComponenteComboDetalleDTO> componentesCombo,
+
+    // HU-110: Secuencia de pasos a cocina
+    String tiempoServicio,            // ENTRADA, PRINCIPAL, POSTRE o null
+    boolean enEspera,                 // true si espera a que se marche su paso
+    boolean enviadoACocina,           // true si cocina ya recibió alguna unidad (paso fijo)
+
+    // HU-137: Prioridad en cocina
+    boolean urgente,
+
+    // HU-139: Costo de envío del delivery
+    boolean envio,
+
+    // HU-147: Alergias del comensal (TACC, MANI...), vacía si no hay
+    List<String> advertencias,
+
+    // HU-148: Nombres de los insumos quitados ("Cebolla"), vacía si no hay
+    List<String> ingredientesQuitados
 ) {
     /**
      * Valida que los campos obligatorios no sean nulos.
@@ -70,5 +102,8 @@ public record ItemDetalleDTO(
         if (extras == null) {
             throw new IllegalArgumentException("La lista de extras no puede ser nula");
         }
+        if (componentesCombo == null) {
+            componentesCombo = List.of();
+        }
     }
 }
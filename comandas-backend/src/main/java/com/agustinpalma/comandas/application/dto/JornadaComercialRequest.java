@@ -0,0 +1,17 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotNull;
+
+import java.time.LocalTime;
+
+/**
+ * DTO de entrada para configurar el límite de la jornada comercial (HU-153).
+ *
+ * @param horaCorte hora (HH:mm) en que termina la jornada y empieza la siguiente
+ */
+public record JornadaComercialRequest(
+
+    @NotNull(message = "La hora de corte de la jornada es obligatoria")
+    LocalTime horaCorte
+) {
+}
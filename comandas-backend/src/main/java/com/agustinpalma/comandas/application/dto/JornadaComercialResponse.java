@@ -0,0 +1,32 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.LocalTime;
+
+/**
+ * DTO de salida con el límite de la jornada comercial (HU-153).
+ *
+ * @param horaCorte      hora en que termina la jornada
+ * @param fechaOperativa día de la jornada comercial en curso
+ * @param inicio         primer instante de la jornada en curso
+ * @param fin            último instante de la jornada en curso
+ */
+public record JornadaComercialResponse(
+    LocalTime horaCorte,
+    LocalDate fechaOperativa,
+    LocalDateTime inicio,
+    LocalDateTime fin
+) {
+    public static JornadaComercialResponse fromDomain(JornadaComercial jornada, LocalDateTime ahora) {
+        LocalDate fechaOperativa = jornada.fechaOperativa(ahora);
+        return new JornadaComercialResponse(
+            jornada.getHoraCorte(),
+            fechaOperativa,
+            jornada.inicio(fechaOperativa),
+            jornada.fin(fechaOperativa)
+        );
+    }
+}
@@ -0,0 +1,67 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoLinkPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.ProveedorLinkPago;
+import com.agustinpalma.comandas.domain.model.LinkPago;
+
+import java.math.BigDecimal;
+import java.net.URLEncoder;
+import java.nio.charset.StandardCharsets;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Link de pago de un delivery tal como lo ve el operador (HU-128).
+ *
+ * También es el payload del evento en tiempo real que avisa que un link
+ * se pagó y el pedido ya pasó a cocina.
+ *
+ * @param whatsappUrl abre WhatsApp con el mensaje listo para mandarle al cliente
+ */
+public record LinkPagoResponse(
+    UUID id,
+    UUID pedidoId,
+    UUID mesaId,
+    int numeroMesa,
+    int numeroPedido,
+    ProveedorLinkPago proveedor,
+    BigDecimal monto,
+    String telefonoCliente,
+    String url,
+    String whatsappUrl,
+    EstadoLinkPago estado,
+    LocalDateTime creadoEn,
+    LocalDateTime pagadoEn
+) {
+
+    /** Evento que avisa a las terminales que se acreditó un link y el pedido pasó a cocina */
+    public static final String EVENTO_PAGADO = "link-pago-pagado";
+
+    public static LinkPagoResponse fromDomain(LinkPago link, String nombreLocal) {
+        return new LinkPagoResponse(
+            link.getId().getValue(),
+            link.getPedidoId().getValue(),
+            link.getMesaId().getValue(),
+            link.getNumeroMesa(),
+            link.getNumeroPedido(),
+            link.getProveedor(),
+            link.getMonto(),
+            link.getTelefonoCliente(),
+            link.getUrl(),
+            armarWhatsappUrl(link, nombreLocal),
+            link.getEstado(),
+            link.getCreadoEn(),
+            link.getPagadoEn()
+        );
+    }
+
+    private static String armarWhatsappUrl(LinkPago link, String nombreLocal) {
+        String deQuien = nombreLocal == null || nombreLocal.isBlank() ? "" : " de " + nombreLocal;
+        String mensaje = String.format(
+            "¡Hola! Tu pedido%s es de $%,.2f. Podés pagarlo desde este link: %s%nApenas se acredite el pago lo empezamos a preparar.",
+            deQuien, link.getMonto(), link.getUrl());
+        // wa.me solo acepta el número en formato internacional, sin símbolos
+        String numero = link.getTelefonoCliente() == null ? "" : link.getTelefonoCliente().replaceAll("\\D", "");
+        return "https://wa.me/" + numero + "?text=" + URLEncoder.encode(mensaje, StandardCharsets.UTF_8).replace("+", "%20");
+    }
+}
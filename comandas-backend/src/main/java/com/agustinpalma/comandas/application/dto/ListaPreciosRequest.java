@@ -0,0 +1,43 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.Valid;
+import jakarta.validation.constraints.DecimalMax;
+import jakarta.validation.constraints.DecimalMin;
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Positive;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * DTO de entrada para configurar la lista de precios de un canal (HU-108).
+ * El canal viaja en la URL; los precios enviados reemplazan a los anteriores.
+ *
+ * @param porcentajeRecargo recargo sobre el precio de catálogo (null = 0%)
+ * @param precios precios especiales por producto (null o vacío = solo recargo)
+ */
+public record ListaPreciosRequest(
+
+    @NotBlank(message = "El nombre de la lista es obligatorio")
+    String nombre,
+
+    @DecimalMin(value = "0", message = "El recargo no puede ser negativo")
+    @DecimalMax(value = "100", message = "El recargo no puede superar el 100%")
+    BigDecimal porcentajeRecargo,
+
+    @Valid
+    List<PrecioProducto> precios
+) {
+
+    public record PrecioProducto(
+        @NotNull(message = "El producto es obligatorio")
+        UUID productoId,
+
+        @NotNull(message = "El precio es obligatorio")
+        @Positive(message = "El precio debe ser mayor a cero")
+        BigDecimal precio
+    ) {
+    }
+}
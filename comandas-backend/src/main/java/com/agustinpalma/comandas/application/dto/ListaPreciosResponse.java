@@ -0,0 +1,37 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * DTO de salida con la lista de precios de un canal (HU-108).
+ *
+ * Un canal sin lista se devuelve con configurada = false: vende a precio de catálogo.
+ */
+public record ListaPreciosResponse(
+    UUID id,
+    CanalVenta canal,
+    boolean configurada,
+    String nombre,
+    BigDecimal porcentajeRecargo,
+    List<PrecioProducto> precios
+) {
+
+    /**
+     * Precio especial de un producto, junto al precio de catálogo para comparar.
+     */
+    public record PrecioProducto(
+        UUID productoId,
+        String nombreProducto,
+        BigDecimal precioCatalogo,
+        BigDecimal precio
+    ) {
+    }
+
+    public static ListaPreciosResponse sinConfigurar(CanalVenta canal) {
+        return new ListaPreciosResponse(null, canal, false, null, BigDecimal.ZERO, List.of());
+    }
+}
@@ -0,0 +1,53 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.Comparator;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * Lote de ventas cerradas que una sucursal envía al consolidado (HU-107).
+ *
+ * Es el contrato de la sincronización: la sucursal lo expone en
+ * GET /api/sync/ventas-cerradas y la capa de sync de Tauri lo reenvía
+ * tal cual a POST /api/sync/ventas de la instalación central.
+ *
+ * @param sucursalId identidad de la instalación de origen
+ * @param nombreSucursal nombre visible en el reporte comparativo
+ * @param ventas pedidos cerrados, ordenados por fecha de cierre y pedido
+ */
+public record LoteVentasSucursalDTO(
+    UUID sucursalId,
+    String nombreSucursal,
+    List<VentaCerradaDTO> ventas
+) {
+
+    public LoteVentasSucursalDTO {
+        ventas = ventas == null ? List.of() : List.copyOf(ventas);
+    }
+
+    /**
+     * Resumen de un pedido cerrado: lo justo para comparar sucursales,
+     * sin ítems ni datos de clientes.
+     *
+     * @param pagos monto cobrado por medio de pago (A_CUENTA incluido)
+     */
+    public record VentaCerradaDTO(
+        UUID pedidoId,
+        int numeroPedido,
+        LocalDateTime fechaCierre,
+        Map<MedioPago, BigDecimal> pagos
+    ) {
+
+        /**
+         * Orden del cursor de sincronización: por cierre y, entre las ventas
+         * cerradas en el mismo instante, por pedido.
+         */
+        public static final Comparator<VentaCerradaDTO> ORDEN_CURSOR =
+            Comparator.comparing(VentaCerradaDTO::fechaCierre).thenComparing(VentaCerradaDTO::pedidoId);
+    }
+}
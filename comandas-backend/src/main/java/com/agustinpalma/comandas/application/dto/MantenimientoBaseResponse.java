@@ -0,0 +1,21 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.time.LocalDateTime;
+
+/**
+ * Resultado de una corrida del mantenimiento de la base (HU-163).
+ *
+ * La app de escritorio lo guarda en su historial para ver, con los meses,
+ * cuánto tarda y cuánto espacio recupera cada corrida.
+ *
+ * @param bytesRecuperados diferencia de tamaño; puede ser negativa si el reindexado agrandó la base
+ */
+public record MantenimientoBaseResponse(
+    String motor,
+    LocalDateTime inicio,
+    long milisegundos,
+    long bytesAntes,
+    long bytesDespues,
+    long bytesRecuperados
+) {
+}
@@ -0,0 +1,19 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.time.LocalDateTime;
+
+/**
+ * DTO de salida de la terminal de fichaje (HU-124).
+ *
+ * @param tipo ENTRADA o SALIDA, según lo que se registró
+ * @param minutosTrabajados duración del fichaje que se cerró (cero en la entrada)
+ * @param quedoIncompleto true si había una entrada sin salida vencida y se abrió un fichaje nuevo
+ */
+public record MarcaFichajeResponse(
+    String tipo,
+    String nombreEmpleado,
+    LocalDateTime hora,
+    long minutosTrabajados,
+    boolean quedoIncompleto
+) {
+}
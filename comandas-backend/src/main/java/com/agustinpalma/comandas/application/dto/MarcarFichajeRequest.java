@@ -0,0 +1,24 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.Size;
+
+/**
+ * DTO de entrada para marcar entrada o salida en la terminal (HU-124).
+ *
+ * El empleado se identifica con su PIN (eligiéndose de la lista) o pasando
+ * la tarjeta por el lector. Si llega la tarjeta, mozoId y pin se ignoran.
+ *
+ * @param mozoId empleado que ficha con PIN
+ * @param pin PIN del empleado
+ * @param tarjeta código leído de la tarjeta
+ */
+public record MarcarFichajeRequest(
+
+    String mozoId,
+
+    String pin,
+
+    @Size(max = 32, message = "El código de tarjeta no puede superar los 32 caracteres")
+    String tarjeta
+) {
+}
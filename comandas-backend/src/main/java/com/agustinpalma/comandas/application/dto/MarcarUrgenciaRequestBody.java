@@ -0,0 +1,10 @@
+package com.agustinpalma.comandas.application.dto;
+
+/**
+ * Request body para los endpoints PATCH de urgencia de un pedido o ítem.
+ *
+ * HU-137: true marca la prioridad en cocina, false la quita.
+ */
+public record MarcarUrgenciaRequestBody(
+    boolean urgente
+) {}
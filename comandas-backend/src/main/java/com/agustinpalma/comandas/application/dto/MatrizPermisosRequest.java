@@ -0,0 +1,27 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import jakarta.validation.constraints.NotNull;
+
+import java.util.Map;
+import java.util.Set;
+import java.util.UUID;
+
+/**
+ * DTO de entrada para configurar la matriz de permisos del local (HU-169).
+ *
+ * @param activa si la matriz restringe las acciones (inactiva, cualquiera puede todo)
+ * @param permisosPorRol acciones habilitadas de cada rol; un rol sin enviar queda sin permisos
+ * @param excepciones por empleado, permisos que se le conceden (true) o quitan (false) respecto de su rol
+ */
+public record MatrizPermisosRequest(
+
+    boolean activa,
+
+    @NotNull(message = "Los permisos por rol son obligatorios")
+    Map<RolPersonal, Set<Permiso>> permisosPorRol,
+
+    Map<UUID, Map<Permiso, Boolean>> excepciones
+) {
+}
@@ -0,0 +1,26 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import com.agustinpalma.comandas.domain.model.MatrizPermisos;
+
+import java.util.HashMap;
+import java.util.Map;
+import java.util.Set;
+import java.util.UUID;
+
+/**
+ * DTO de salida con la matriz de permisos del local (HU-169).
+ * Las excepciones solo traen a los empleados que tienen alguna.
+ */
+public record MatrizPermisosResponse(
+    boolean activa,
+    Map<RolPersonal, Set<Permiso>> permisosPorRol,
+    Map<UUID, Map<Permiso, Boolean>> excepciones
+) {
+    public static MatrizPermisosResponse fromDomain(MatrizPermisos matriz) {
+        Map<UUID, Map<Permiso, Boolean>> excepciones = new HashMap<>();
+        matriz.getExcepciones().forEach((empleadoId, permisos) -> excepciones.put(empleadoId.getValue(), permisos));
+        return new MatrizPermisosResponse(matriz.isActiva(), matriz.getPermisosPorRol(), excepciones);
+    }
+}
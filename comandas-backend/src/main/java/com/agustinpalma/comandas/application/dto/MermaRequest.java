@@ -0,0 +1,56 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaMerma;
+import jakarta.validation.Valid;
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Positive;
+import jakarta.validation.constraints.Size;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * DTO de entrada para registrar una merma (HU-135).
+ *
+ * Se carga por producto (productoId + unidades: se descuenta su receta) o
+ * por insumo (la lista de insumos); no las dos cosas a la vez.
+ *
+ * @param unidades unidades del producto perdidas (1 si no se indica)
+ */
+public record MermaRequest(
+
+    @NotNull(message = "La categoría es obligatoria")
+    CategoriaMerma categoria,
+
+    @NotBlank(message = "El motivo es obligatorio")
+    @Size(max = 100, message = "El motivo no puede superar los 100 caracteres")
+    String motivo,
+
+    @NotBlank(message = "El responsable es obligatorio")
+    @Size(max = 60, message = "El responsable no puede superar los 60 caracteres")
+    String responsable,
+
+    UUID productoId,
+
+    @Positive(message = "Las unidades perdidas deben ser mayores a cero")
+    Integer unidades,
+
+    @Valid
+    List<InsumoMermaRequest> insumos
+) {
+
+    /**
+     * @param cantidad lo perdido, en la unidad del insumo (kilos, litros o unidades)
+     */
+    public record InsumoMermaRequest(
+        @NotNull(message = "El insumo es obligatorio")
+        UUID insumoId,
+
+        @NotNull(message = "La cantidad perdida es obligatoria")
+        @Positive(message = "La cantidad perdida debe ser mayor a cero")
+        BigDecimal cantidad
+    ) {
+    }
+}
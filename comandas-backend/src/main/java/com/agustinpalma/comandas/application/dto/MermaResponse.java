@@ -0,0 +1,66 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaMerma;
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import com.agustinpalma.comandas.domain.model.Merma;
+import com.agustinpalma.comandas.domain.model.RenglonMerma;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * DTO de salida de una merma registrada (HU-135).
+ *
+ * @param productoId null si la merma se cargó por insumo
+ */
+public record MermaResponse(
+    UUID id,
+    LocalDateTime fecha,
+    CategoriaMerma categoria,
+    String motivo,
+    String responsable,
+    UUID productoId,
+    String nombreProducto,
+    Integer unidadesProducto,
+    List<RenglonMermaResponse> insumos,
+    BigDecimal costoTotal
+) {
+
+    public record RenglonMermaResponse(
+        UUID insumoId,
+        String nombre,
+        UnidadInsumo unidad,
+        BigDecimal cantidad,
+        BigDecimal costoUnitario,
+        BigDecimal costoValorizado
+    ) {
+
+        static RenglonMermaResponse fromDomain(RenglonMerma renglon) {
+            return new RenglonMermaResponse(
+                renglon.getInsumoId().getValue(),
+                renglon.getNombreInsumo(),
+                renglon.getUnidad(),
+                renglon.getCantidad(),
+                renglon.getCostoUnitario(),
+                renglon.getCostoValorizado()
+            );
+        }
+    }
+
+    public static MermaResponse fromDomain(Merma merma) {
+        return new MermaResponse(
+            merma.getId().getValue(),
+            merma.getFecha(),
+            merma.getCategoria(),
+            merma.getMotivo(),
+            merma.getResponsable(),
+            merma.esDeProducto() ? merma.getProductoId().getValue() : null,
+            merma.getNombreProducto(),
+            merma.getUnidadesProducto(),
+            merma.getRenglones().stream().map(RenglonMermaResponse::fromDomain).toList(),
+            merma.getCostoTotal()
+        );
+    }
+}
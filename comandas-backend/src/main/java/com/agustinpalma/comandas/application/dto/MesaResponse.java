@@ -5,13 +5,25 @@ import com.agustinpalma.comandas.domain.model.Mesa;
 public record MesaResponse(
     String id,       // El ID como String es más seguro para JSON/REST
     int numero, 
-    String estado    // Usamos String para que el frontend no dependa del Enum de Java
+    String estado,   // Usamos String para que el frontend no dependa del Enum de Java
+    String sector,   // HU-112: ubicación en el mapa del salón
+    Integer posicionX,
+    Integer posicionY,
+    Integer capacidad // HU-149: personas que entran (null = sin definir)
 ) {
+    public MesaResponse(String id, int numero, String estado) {
+        this(id, numero, estado, Mesa.SECTOR_POR_DEFECTO, null, null, null);
+    }
+
     public static MesaResponse fromDomain(Mesa mesa) {
         return new MesaResponse(
             mesa.getId().getValue().toString(), // Mantenemos la fidelidad del UUID
             mesa.getNumero(),
-            mesa.getEstado().name()
+            mesa.getEstado().name(),
+            mesa.getSector(),
+            mesa.getPosicionX(),
+            mesa.getPosicionY(),
+            mesa.getCapacidad()
         );
     }
 }
\ No newline at end of file
@@ -0,0 +1,32 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.time.LocalDateTime;
+import java.util.List;
+
+/**
+ * DTO de salida de la alerta de mesas olvidadas (HU-113).
+ * Incluye la configuración vigente para que las terminales no tengan
+ * que pedirla por separado.
+ *
+ * @param mesas mesas ocupadas que superaron el umbral, de la más olvidada a la menos
+ */
+public record MesasOlvidadasResponse(
+    boolean alertaActiva,
+    int minutosSinActividad,
+    List<MesaOlvidada> mesas
+) {
+
+    /**
+     * @param ultimaActividad última atención registrada (apertura, ítems, comanda o cuenta)
+     */
+    public record MesaOlvidada(
+        String mesaId,
+        int numeroMesa,
+        String sector,
+        String pedidoId,
+        int numeroPedido,
+        LocalDateTime ultimaActividad,
+        long minutosSinActividad
+    ) {
+    }
+}
@@ -0,0 +1,37 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoCuenta;
+import com.agustinpalma.comandas.domain.model.MovimientoCuentaCorriente;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+
+/**
+ * DTO de salida para un movimiento de cuenta corriente (HU-104).
+ *
+ * @param pedidoId pedido que originó el cargo (null para pagos)
+ * @param medioPago medio con que se abonó (null para cargos)
+ */
+public record MovimientoCuentaCorrienteResponse(
+    String id,
+    TipoMovimientoCuenta tipo,
+    BigDecimal monto,
+    LocalDateTime fecha,
+    String descripcion,
+    String pedidoId,
+    MedioPago medioPago
+) {
+
+    public static MovimientoCuentaCorrienteResponse fromDomain(MovimientoCuentaCorriente movimiento) {
+        return new MovimientoCuentaCorrienteResponse(
+            movimiento.getId().getValue().toString(),
+            movimiento.getTipo(),
+            movimiento.getMonto(),
+            movimiento.getFecha(),
+            movimiento.getDescripcion(),
+            movimiento.getPedidoId() != null ? movimiento.getPedidoId().getValue().toString() : null,
+            movimiento.getMedioPago()
+        );
+    }
+}
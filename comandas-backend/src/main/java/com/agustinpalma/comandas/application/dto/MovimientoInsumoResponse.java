@@ -0,0 +1,32 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoInsumo;
+import com.agustinpalma.comandas.domain.model.MovimientoInsumo;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * DTO de salida con un movimiento de stock de un insumo (HU-133).
+ *
+ * @param cantidad positiva si entró, negativa si salió
+ */
+public record MovimientoInsumoResponse(
+    UUID id,
+    TipoMovimientoInsumo tipo,
+    BigDecimal cantidad,
+    LocalDateTime fecha,
+    String motivo
+) {
+
+    public static MovimientoInsumoResponse fromDomain(MovimientoInsumo movimiento) {
+        return new MovimientoInsumoResponse(
+            movimiento.getId().getValue(),
+            movimiento.getTipo(),
+            movimiento.getCantidad(),
+            movimiento.getFecha(),
+            movimiento.getMotivo()
+        );
+    }
+}
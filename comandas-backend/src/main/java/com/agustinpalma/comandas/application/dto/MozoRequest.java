@@ -0,0 +1,32 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.Pattern;
+import jakarta.validation.constraints.Size;
+
+/**
+ * DTO de entrada para dar de alta o editar un mozo (HU-111).
+ *
+ * @param activo null = activo (alta) o sin cambios en la edición
+ * @param rol HU-121: null = MOZO (alta) o sin cambios en la edición
+ * @param pin HU-121: PIN de autorización; null = sin cambios, vacío = quitarlo
+ * @param tarjeta HU-124: código de la tarjeta de fichaje; null = sin cambios, vacío = quitarla
+ */
+public record MozoRequest(
+
+    @NotBlank(message = "El nombre del mozo es obligatorio")
+    @Size(max = 60, message = "El nombre del mozo no puede superar los 60 caracteres")
+    String nombre,
+
+    Boolean activo,
+
+    RolPersonal rol,
+
+    @Pattern(regexp = "\\d{4,6}|", message = "El PIN debe tener entre 4 y 6 dígitos")
+    String pin,
+
+    @Size(max = 32, message = "El código de tarjeta no puede superar los 32 caracteres")
+    String tarjeta
+) {
+}
@@ -0,0 +1,30 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import com.agustinpalma.comandas.domain.model.Mozo;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * DTO de salida con los datos de un mozo (HU-111).
+ *
+ * @param tienePin HU-121: el PIN nunca sale del backend, solo si está configurado
+ * @param tieneTarjeta HU-124: tampoco sale el código de la tarjeta de fichaje
+ * @param topeConsumoMensual HU-132: tope de consumo interno (null = sin tope)
+ */
+public record MozoResponse(
+    UUID id,
+    String nombre,
+    boolean activo,
+    RolPersonal rol,
+    boolean tienePin,
+    boolean tieneTarjeta,
+    BigDecimal topeConsumoMensual
+) {
+
+    public static MozoResponse fromDomain(Mozo mozo) {
+        return new MozoResponse(mozo.getId().getValue(), mozo.getNombre(), mozo.isActivo(),
+            mozo.getRol(), mozo.tienePin(), mozo.tieneTarjeta(), mozo.getTopeConsumoMensual());
+    }
+}
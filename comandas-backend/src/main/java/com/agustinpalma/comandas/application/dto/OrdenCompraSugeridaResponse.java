@@ -0,0 +1,70 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import com.agustinpalma.comandas.domain.model.Insumo;
+import com.agustinpalma.comandas.domain.model.Proveedor;
+import com.agustinpalma.comandas.domain.service.InventarioService.OrdenCompraSugerida;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * DTO de salida con una orden de compra sugerida por stock mínimo (HU-134).
+ *
+ * Los datos de contacto del proveedor vienen para enviarle la orden por
+ * WhatsApp o email. Sin proveedor habitual, proveedorId y los contactos son null.
+ *
+ * @param costoEstimado al costo unitario vigente de cada insumo
+ */
+public record OrdenCompraSugeridaResponse(
+    UUID proveedorId,
+    String nombreProveedor,
+    String telefono,
+    String email,
+    List<RenglonOrdenCompra> insumos,
+    BigDecimal costoEstimado
+) {
+
+    /**
+     * @param cantidadSugerida lo que falta para llegar al doble del mínimo, en la unidad del insumo
+     */
+    public record RenglonOrdenCompra(
+        UUID insumoId,
+        String nombre,
+        UnidadInsumo unidad,
+        BigDecimal stockActual,
+        BigDecimal stockMinimo,
+        BigDecimal cantidadSugerida,
+        BigDecimal costoUnitario,
+        BigDecimal costoEstimado
+    ) {
+
+        static RenglonOrdenCompra fromDomain(Insumo insumo) {
+            BigDecimal cantidad = insumo.getCantidadAReponer();
+            return new RenglonOrdenCompra(
+                insumo.getId().getValue(),
+                insumo.getNombre(),
+                insumo.getUnidad(),
+                insumo.getStockActual(),
+                insumo.getStockMinimo(),
+                cantidad,
+                insumo.getCostoUnitario(),
+                cantidad.multiply(insumo.getCostoUnitario()).setScale(2, RoundingMode.HALF_UP)
+            );
+        }
+    }
+
+    public static OrdenCompraSugeridaResponse fromDomain(OrdenCompraSugerida orden) {
+        Proveedor proveedor = orden.proveedor();
+        return new OrdenCompraSugeridaResponse(
+            proveedor != null ? proveedor.getId().getValue() : null,
+            proveedor != null ? proveedor.getNombre() : null,
+            proveedor != null ? proveedor.getTelefono() : null,
+            proveedor != null ? proveedor.getEmail() : null,
+            orden.insumos().stream().map(RenglonOrdenCompra::fromDomain).toList(),
+            orden.getCostoEstimado()
+        );
+    }
+}
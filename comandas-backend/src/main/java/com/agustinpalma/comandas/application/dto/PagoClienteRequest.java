@@ -0,0 +1,27 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Positive;
+
+import java.math.BigDecimal;
+
+/**
+ * DTO de entrada para registrar un pago (total o parcial) de la deuda de un cliente.
+ * HU-104: Cuenta corriente de clientes.
+ *
+ * @param medio medio con el que abona (EFECTIVO suma a la caja del día)
+ * @param monto monto abonado (no puede superar la deuda)
+ * @param descripcion detalle opcional (ej: "Pago quincena")
+ */
+public record PagoClienteRequest(
+    @NotNull(message = "El medio de pago es obligatorio")
+    MedioPago medio,
+
+    @NotNull(message = "El monto es obligatorio")
+    @Positive(message = "El monto debe ser mayor a cero")
+    BigDecimal monto,
+
+    String descripcion
+) {
+}
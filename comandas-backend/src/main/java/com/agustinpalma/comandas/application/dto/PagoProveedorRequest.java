@@ -0,0 +1,27 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Positive;
+
+import java.math.BigDecimal;
+
+/**
+ * DTO de entrada para asentar un pago (total o parcial) contra una factura de proveedor.
+ * HU-131: Cuenta corriente de proveedores.
+ *
+ * @param monto         no puede superar el saldo de la factura
+ * @param pagadoConCaja true si el efectivo salió de la caja: registra además el egreso de caja
+ */
+public record PagoProveedorRequest(
+
+    @NotNull(message = "El monto es obligatorio")
+    @Positive(message = "El monto debe ser mayor a cero")
+    BigDecimal monto,
+
+    @NotNull(message = "El medio de pago es obligatorio")
+    MedioPago medioPago,
+
+    boolean pagadoConCaja
+) {
+}
@@ -1,6 +1,7 @@
 package com.agustinpalma.comandas.application.dto;
 
 import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Moneda;
 
 import java.math.BigDecimal;
 
@@ -8,10 +9,39 @@ import java.math.BigDecimal;
  * DTO de entrada para un pago individual dentro del cierre de mesa.
  * 
  * @param medio medio de pago utilizado (EFECTIVO, TARJETA, TRANSFERENCIA, QR)
- * @param monto monto del pago (debe ser > 0)
+ * @param monto monto del pago en pesos (debe ser > 0), aunque el cliente pague en otra moneda
+ * @param codigoTarjetaRegalo HU-144: código de la tarjeta de regalo, obligatorio
+ *                            solo para pagos TARJETA_REGALO
+ * @param moneda HU-151: moneda en que paga el cliente (null = pesos); se convierte
+ *               con el tipo de cambio del día
+ * @param referencia HU-156: número de cupón u operación del comprobante (opcional,
+ *                   no aplica al efectivo)
  */
 public record PagoRequest(
     MedioPago medio,
-    BigDecimal monto
+    BigDecimal monto,
+    String codigoTarjetaRegalo,
+    Moneda moneda,
+    String referencia
 ) {
+    /**
+     * Constructor de retrocompatibilidad (sin tarjeta de regalo).
+     */
+    public PagoRequest(MedioPago medio, BigDecimal monto) {
+        this(medio, monto, null, null, null);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (en pesos).
+     */
+    public PagoRequest(MedioPago medio, BigDecimal monto, String codigoTarjetaRegalo) {
+        this(medio, monto, codigoTarjetaRegalo, null, null);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (sin comprobante).
+     */
+    public PagoRequest(MedioPago medio, BigDecimal monto, String codigoTarjetaRegalo, Moneda moneda) {
+        this(medio, monto, codigoTarjetaRegalo, moneda, null);
+    }
 }
@@ -0,0 +1,41 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.application.dto.CerrarMesaResponse.PagoResponse;
+import com.agustinpalma.comandas.domain.model.Pedido;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Pagos a cuenta de una mesa abierta y lo que falta cobrar (HU-160).
+ *
+ * También es el payload del evento en tiempo real que avisa a las demás
+ * terminales que cambió el saldo de la mesa.
+ *
+ * @param pagos          pagos a cuenta en el orden en que se cobraron (el índice sirve para anularlos)
+ * @param saldoPendiente total vigente menos lo pagado a cuenta; negativo si se quitaron ítems ya pagados
+ */
+public record PagosACuentaResponse(
+    UUID pedidoId,
+    UUID mesaId,
+    BigDecimal total,
+    BigDecimal pagadoACuenta,
+    BigDecimal saldoPendiente,
+    List<PagoResponse> pagos
+) {
+
+    /** Evento que avisa a las terminales que se cobró o anuló un pago a cuenta */
+    public static final String EVENTO = "pago-a-cuenta";
+
+    public static PagosACuentaResponse fromDomain(Pedido pedido) {
+        return new PagosACuentaResponse(
+            pedido.getId().getValue(),
+            pedido.getMesaId().getValue(),
+            pedido.calcularTotal(),
+            pedido.calcularPagadoACuenta(),
+            pedido.calcularSaldoPendiente(),
+            pedido.getPagos().stream().map(PagoResponse::fromDomain).toList()
+        );
+    }
+}
@@ -0,0 +1,18 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ConceptoContable;
+import jakarta.validation.constraints.NotNull;
+
+import java.util.Map;
+
+/**
+ * DTO de entrada para asignar las cuentas contables del local (HU-167).
+ *
+ * @param cuentas código de cuenta de cada concepto; un concepto sin enviar o vacío queda sin cuenta
+ */
+public record PlanCuentasContableRequest(
+
+    @NotNull(message = "Las cuentas son obligatorias")
+    Map<ConceptoContable, String> cuentas
+) {
+}
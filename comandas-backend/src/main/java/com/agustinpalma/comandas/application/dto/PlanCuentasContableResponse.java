@@ -0,0 +1,18 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ConceptoContable;
+import com.agustinpalma.comandas.domain.model.PlanCuentasContable;
+
+import java.util.Map;
+
+/**
+ * DTO de salida con las cuentas contables del local (HU-167).
+ * Solo trae los conceptos que tienen cuenta asignada.
+ */
+public record PlanCuentasContableResponse(
+    Map<ConceptoContable, String> cuentas
+) {
+    public static PlanCuentasContableResponse fromDomain(PlanCuentasContable plan) {
+        return new PlanCuentasContableResponse(plan.getCuentas());
+    }
+}
@@ -0,0 +1,29 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.Producto;
+
+/**
+ * HU-101: DTO de salida para el listado "para reponer".
+ * Cada fila representa un producto que alcanzó o perforó su stock mínimo,
+ * junto con la cantidad sugerida para la compra del día.
+ */
+public record ProductoParaReponerResponse(
+    String productoId,
+    String nombre,
+    String categoriaId,       // UUID de la categoría — puede ser null
+    int stockActual,
+    int stockMinimo,
+    int cantidadSugerida      // Unidades necesarias para volver a superar el umbral
+) {
+
+    public static ProductoParaReponerResponse fromDomain(Producto producto) {
+        return new ProductoParaReponerResponse(
+            producto.getId().getValue().toString(),
+            producto.getNombre(),
+            producto.getCategoriaId() != null ? producto.getCategoriaId().getValue().toString() : null,
+            producto.getStockActual(),
+            producto.getStockMinimo(),
+            producto.calcularCantidadParaReponer()
+        );
+    }
+}
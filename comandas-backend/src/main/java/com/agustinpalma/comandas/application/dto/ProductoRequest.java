@@ -1,9 +1,14 @@
 package com.agustinpalma.comandas.application.dto;
 
+import com.agustinpalma.comandas.domain.model.DomainEnums.Alergeno;
+import jakarta.validation.Valid;
 import jakarta.validation.constraints.DecimalMin;
 import jakarta.validation.constraints.NotBlank;
 import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.PositiveOrZero;
+import jakarta.validation.constraints.Size;
 import java.math.BigDecimal;
+import java.util.List;
 
 /**
  * DTO de entrada para crear o editar un producto.
@@ -33,6 +38,124 @@ public record ProductoRequest(
 
     Boolean permiteExtras,  // Si el producto acepta extras/agregados. Default: true en creación
 
-    Boolean requiereConfiguracion  // Si el POS debe abrir modal de configuración. Default: true en creación
+    Boolean requiereConfiguracion,  // Si el POS debe abrir modal de configuración. Default: true en creación
+
+    @PositiveOrZero(message = "El stock mínimo no puede ser negativo")
+    Integer stockMinimo,  // HU-101: Umbral de reposición. Opcional, si es null se preserva el valor actual (edición)
+
+    @Valid
+    List<ComponenteComboRequest> componentesCombo,  // HU-103: null preserva (edición), lista vacía deja de ser combo
+
+    @Size(max = 60, message = "El responsable no puede superar los 60 caracteres")
+    String responsable,  // HU-146: quién cambia el precio, para el historial. Opcional
+
+    List<Alergeno> alergenos,  // HU-147: null preserva (edición), lista vacía quita los alérgenos
+
+    FranjaHorariaRequest horario  // HU-161: null preserva (edición), sin horas lo quita
 ) {
+
+    /**
+     * Constructor de retrocompatibilidad (sin horario).
+     */
+    public ProductoRequest(
+        String nombre,
+        BigDecimal precio,
+        Boolean activo,
+        String colorHex,
+        Boolean controlaStock,
+        Boolean esExtra,
+        Boolean esModificadorEstructural,
+        String categoriaId,
+        Boolean permiteExtras,
+        Boolean requiereConfiguracion,
+        Integer stockMinimo,
+        List<ComponenteComboRequest> componentesCombo,
+        String responsable,
+        List<Alergeno> alergenos
+    ) {
+        this(nombre, precio, activo, colorHex, controlaStock, esExtra, esModificadorEstructural,
+            categoriaId, permiteExtras, requiereConfiguracion, stockMinimo, componentesCombo, responsable, alergenos, null);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (sin alérgenos).
+     */
+    public ProductoRequest(
+        String nombre,
+        BigDecimal precio,
+        Boolean activo,
+        String colorHex,
+        Boolean controlaStock,
+        Boolean esExtra,
+        Boolean esModificadorEstructural,
+        String categoriaId,
+        Boolean permiteExtras,
+        Boolean requiereConfiguracion,
+        Integer stockMinimo,
+        List<ComponenteComboRequest> componentesCombo,
+        String responsable
+    ) {
+        this(nombre, precio, activo, colorHex, controlaStock, esExtra, esModificadorEstructural,
+            categoriaId, permiteExtras, requiereConfiguracion, stockMinimo, componentesCombo, responsable, null);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (sin responsable del cambio de precio).
+     */
+    public ProductoRequest(
+        String nombre,
+        BigDecimal precio,
+        Boolean activo,
+        String colorHex,
+        Boolean controlaStock,
+        Boolean esExtra,
+        Boolean esModificadorEstructural,
+        String categoriaId,
+        Boolean permiteExtras,
+        Boolean requiereConfiguracion,
+        Integer stockMinimo,
+        List<ComponenteComboRequest> componentesCombo
+    ) {
+        this(nombre, precio, activo, colorHex, controlaStock, esExtra, esModificadorEstructural,
+            categoriaId, permiteExtras, requiereConfiguracion, stockMinimo, componentesCombo, null);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (sin componentes de combo).
+     */
+    public ProductoRequest(
+        String nombre,
+        BigDecimal precio,
+        Boolean activo,
+        String colorHex,
+        Boolean controlaStock,
+        Boolean esExtra,
+        Boolean esModificadorEstructural,
+        String categoriaId,
+        Boolean permiteExtras,
+        Boolean requiereConfiguracion,
+        Integer stockMinimo
+    ) {
+        this(nombre, precio, activo, colorHex, controlaStock, esExtra, esModificadorEstructural,
+            categoriaId, permiteExtras, requiereConfiguracion, stockMinimo, null, null);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (sin stock mínimo).
+     */
+    public ProductoRequest(
+        String nombre,
+        BigDecimal precio,
+        Boolean activo,
+        String colorHex,
+        Boolean controlaStock,
+        Boolean esExtra,
+        Boolean esModificadorEstructural,
+        String categoriaId,
+        Boolean permiteExtras,
+        Boolean requiereConfiguracion
+    ) {
+        this(nombre, precio, activo, colorHex, controlaStock, esExtra, esModificadorEstructural,
+            categoriaId, permiteExtras, requiereConfiguracion, null, null, null);
+    }
 }
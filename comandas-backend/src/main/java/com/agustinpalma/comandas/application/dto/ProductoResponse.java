@@ -1,7 +1,10 @@
 package com.agustinpalma.comandas.application.dto;
 
+import com.agustinpalma.comandas.domain.model.DomainEnums.Alergeno;
+import com.agustinpalma.comandas.domain.model.FranjaHoraria;
 import com.agustinpalma.comandas.domain.model.Producto;
 import java.math.BigDecimal;
+import java.time.LocalTime;
 import java.util.List;
 
 /**
@@ -11,6 +14,11 @@ import java.util.List;
  * Incluye información de stock para transparencia del inventario.
  * Incluye las promociones activas asociadas al producto (cruce en capa de aplicación).
  * Incluye puedeAgregarDiscoExtra para que el frontend filtre extras estructurales.
+ * Incluye los componentes del combo (HU-103) para que el POS pida la elección de opciones.
+ * Incluye la etiqueta de variante (HU-109) para el selector rápido de tamaño.
+ * Incluye el flag agotado (HU-125) para que el POS bloquee los productos 86.
+ * Incluye los alérgenos (HU-147) para avisar al mozo si el comensal declaró alguno.
+ * Incluye el horario propio y si está fuera de horario (HU-161) para que el POS lo oculte.
  */
 public record ProductoResponse(
     String id,              // UUID como String para JSON/REST
@@ -20,6 +28,9 @@ public record ProductoResponse(
     String colorHex,        // Siempre normalizado a mayúsculas (ej: #FF0000)
     Integer stockActual,    // Cantidad actual en inventario
     Boolean controlaStock,  // Si el producto tiene control de inventario activo
+    Integer stockMinimo,    // HU-101: Umbral de reposición — puede ser null
+    boolean bajoStockMinimo, // HU-101: true si el stock actual alcanzó el umbral
+    boolean agotado,        // HU-125: marcado 86 — no se puede cargar en pedidos
     boolean esExtra,        // true si es un extra (huevo, queso, disco de carne, etc.)
     boolean esModificadorEstructural, // true si este extra activa normalización de variantes
     String categoriaId,     // UUID de la categoría del catálogo — puede ser null
@@ -27,8 +38,14 @@ public record ProductoResponse(
     boolean requiereConfiguracion, // Si true, el POS abre modal de configuración antes de agregar
     String grupoVarianteId, // UUID del grupo de variantes — puede ser null
     Integer cantidadDiscosCarne, // Cantidad de discos de carne (variantes) — puede ser null
+    String etiquetaVariante, // HU-109: Rótulo corto de la variante (ej: "Grande") — puede ser null
     List<PromocionActivaInfo> promocionesActivas, // Promociones vigentes que aplican a este producto
-    boolean puedeAgregarDiscoExtra // true si el producto puede recibir un modificador estructural como extra
+    boolean puedeAgregarDiscoExtra, // true si el producto puede recibir un modificador estructural como extra
+    List<ComponenteComboInfo> componentesCombo, // HU-103: vacía si el producto no es combo
+    List<Alergeno> alergenos, // HU-147: vacía si no se cargaron
+    LocalTime horarioDesde, // HU-161: Horario propio — null si rige el de la categoría
+    LocalTime horarioHasta,
+    boolean fueraDeHorario  // HU-161: a la hora de la consulta no se ofrece (horario propio o de la categoría)
 ) {
 
     /**
@@ -41,6 +58,15 @@ public record ProductoResponse(
         String tipoEstrategia // ej: "DESCUENTO_DIRECTO", "CANTIDAD_FIJA", etc.
     ) {}
 
+    /**
+     * Componente de un combo con los IDs de sus opciones elegibles.
+     * El frontend resuelve nombres y precios desde el catálogo ya cargado.
+     */
+    public record ComponenteComboInfo(
+        String nombre,
+        List<String> opcionesIds
+    ) {}
+
     /**
      * Factory method para construir el DTO desde la entidad de dominio.
      * Sin enriquecimiento de promociones (lista vacía).
@@ -59,6 +85,17 @@ public record ProductoResponse(
      * @param puedeAgregarDiscoExtra true si el producto está en la variante máxima de su grupo
      */
     public static ProductoResponse fromDomain(Producto producto, List<PromocionActivaInfo> promociones, boolean puedeAgregarDiscoExtra) {
+        return fromDomain(producto, promociones, puedeAgregarDiscoExtra, false);
+    }
+
+    /**
+     * Factory method completo, con el flag de horario resuelto contra la categoría (HU-161).
+     *
+     * @param fueraDeHorario true si a la hora de la consulta el producto no se ofrece
+     */
+    public static ProductoResponse fromDomain(Producto producto, List<PromocionActivaInfo> promociones,
+                                              boolean puedeAgregarDiscoExtra, boolean fueraDeHorario) {
+        FranjaHoraria horario = producto.getFranjaHoraria();
         return new ProductoResponse(
             producto.getId().getValue().toString(),
             producto.getNombre(),
@@ -67,6 +104,9 @@ public record ProductoResponse(
             producto.getColorHex(),
             producto.getStockActual(),
             producto.isControlaStock(),
+            producto.getStockMinimo(),
+            producto.estaBajoStockMinimo(),
+            producto.isAgotado(),
             producto.isEsExtra(),
             producto.isEsModificadorEstructural(),
             producto.getCategoriaId() != null ? producto.getCategoriaId().getValue().toString() : null,
@@ -74,8 +114,19 @@ public record ProductoResponse(
             producto.isRequiereConfiguracion(),
             producto.getGrupoVarianteId() != null ? producto.getGrupoVarianteId().getValue().toString() : null,
             producto.getCantidadDiscosCarne(),
+            producto.getEtiquetaVariante(),
             promociones != null ? List.copyOf(promociones) : List.of(),
-            puedeAgregarDiscoExtra
+            puedeAgregarDiscoExtra,
+            producto.getComponentesCombo().stream()
+                .map(c -> new ComponenteComboInfo(
+                    c.getNombre(),
+                    c.getOpciones().stream().map(o -> o.getValue().toString()).toList()
+                ))
+                .toList(),
+            List.copyOf(producto.getAlergenos()),
+            horario != null ? horario.getDesde() : null,
+            horario != null ? horario.getHasta() : null,
+            fueraDeHorario
         );
     }
 }
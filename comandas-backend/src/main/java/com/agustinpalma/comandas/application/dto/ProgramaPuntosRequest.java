@@ -0,0 +1,30 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Positive;
+
+import java.math.BigDecimal;
+
+/**
+ * DTO de entrada para configurar el programa de puntos del local (HU-105).
+ * Valida los datos básicos a nivel de presentación.
+ * Las validaciones de negocio se ejecutan en el dominio.
+ *
+ * @param diasVencimiento vida de los puntos en días (null = no vencen)
+ */
+public record ProgramaPuntosRequest(
+
+    boolean activo,
+
+    @NotNull(message = "El monto por punto es obligatorio")
+    @Positive(message = "El monto por punto debe ser mayor a cero")
+    BigDecimal montoPorPunto,
+
+    @NotNull(message = "El valor del punto es obligatorio")
+    @Positive(message = "El valor del punto debe ser mayor a cero")
+    BigDecimal valorPunto,
+
+    @Positive(message = "Los días de vencimiento deben ser mayores a cero")
+    Integer diasVencimiento
+) {
+}
@@ -0,0 +1,24 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.ProgramaPuntos;
+
+import java.math.BigDecimal;
+
+/**
+ * DTO de salida con la configuración del programa de puntos (HU-105).
+ */
+public record ProgramaPuntosResponse(
+    boolean activo,
+    BigDecimal montoPorPunto,
+    BigDecimal valorPunto,
+    Integer diasVencimiento
+) {
+    public static ProgramaPuntosResponse fromDomain(ProgramaPuntos programa) {
+        return new ProgramaPuntosResponse(
+            programa.isActivo(),
+            programa.getMontoPorPunto(),
+            programa.getValorPunto(),
+            programa.getDiasVencimiento()
+        );
+    }
+}
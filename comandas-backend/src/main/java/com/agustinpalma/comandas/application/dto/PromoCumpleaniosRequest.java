@@ -0,0 +1,29 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.DecimalMax;
+import jakarta.validation.constraints.DecimalMin;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Positive;
+
+import java.math.BigDecimal;
+
+/**
+ * DTO de entrada para configurar la promo de cumpleaños del local (HU-168).
+ * Valida los datos básicos a nivel de presentación.
+ * Las validaciones de negocio se ejecutan en el dominio.
+ *
+ * @param topeDescuento descuento máximo en pesos (null = sin tope)
+ */
+public record PromoCumpleaniosRequest(
+
+    boolean activa,
+
+    @NotNull(message = "El porcentaje es obligatorio")
+    @DecimalMin(value = "1", message = "El porcentaje debe ser al menos 1")
+    @DecimalMax(value = "100", message = "El porcentaje no puede superar 100")
+    BigDecimal porcentaje,
+
+    @Positive(message = "El tope de descuento debe ser mayor a cero")
+    BigDecimal topeDescuento
+) {
+}
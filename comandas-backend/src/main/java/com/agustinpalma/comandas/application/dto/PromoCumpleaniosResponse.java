@@ -0,0 +1,22 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.PromoCumpleanios;
+
+import java.math.BigDecimal;
+
+/**
+ * DTO de salida con la configuración de la promo de cumpleaños (HU-168).
+ */
+public record PromoCumpleaniosResponse(
+    boolean activa,
+    BigDecimal porcentaje,
+    BigDecimal topeDescuento
+) {
+    public static PromoCumpleaniosResponse fromDomain(PromoCumpleanios promo) {
+        return new PromoCumpleaniosResponse(
+            promo.isActiva(),
+            promo.getPorcentaje(),
+            promo.getTopeDescuento()
+        );
+    }
+}
@@ -0,0 +1,37 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.Max;
+import jakarta.validation.constraints.Min;
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.Size;
+
+/**
+ * DTO de entrada para dar de alta o editar un proveedor (HU-131).
+ *
+ * @param cuit          opcional, con o sin guiones
+ * @param email         opcional; HU-134: a donde se envían las órdenes de compra
+ * @param plazoPagoDias días hasta el vencimiento de sus facturas (null = 30)
+ * @param activo        false lo saca de las opciones al registrar compras (null = activo)
+ */
+public record ProveedorRequest(
+
+    @NotBlank(message = "El nombre del proveedor es obligatorio")
+    @Size(max = 100, message = "El nombre del proveedor no puede superar los 100 caracteres")
+    String nombre,
+
+    @Size(max = 13, message = "El CUIT no puede superar los 13 caracteres")
+    String cuit,
+
+    @Size(max = 30, message = "El teléfono no puede superar los 30 caracteres")
+    String telefono,
+
+    @Size(max = 100, message = "El email no puede superar los 100 caracteres")
+    String email,
+
+    @Min(value = 0, message = "El plazo de pago no puede ser negativo")
+    @Max(value = 180, message = "El plazo de pago no puede superar los 180 días")
+    Integer plazoPagoDias,
+
+    Boolean activo
+) {
+}
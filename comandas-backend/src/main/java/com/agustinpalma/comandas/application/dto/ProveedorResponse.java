@@ -0,0 +1,33 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.Proveedor;
+
+import java.util.UUID;
+
+/**
+ * DTO de salida con los datos de un proveedor (HU-131).
+ *
+ * @param cuit formateado XX-XXXXXXXX-X, null si no se cargó
+ */
+public record ProveedorResponse(
+    UUID id,
+    String nombre,
+    String cuit,
+    String telefono,
+    String email,
+    int plazoPagoDias,
+    boolean activo
+) {
+
+    public static ProveedorResponse fromDomain(Proveedor proveedor) {
+        return new ProveedorResponse(
+            proveedor.getId().getValue(),
+            proveedor.getNombre(),
+            proveedor.getCuit() != null ? proveedor.getCuit().formateado() : null,
+            proveedor.getTelefono(),
+            proveedor.getEmail(),
+            proveedor.getPlazoPagoDias(),
+            proveedor.isActivo()
+        );
+    }
+}
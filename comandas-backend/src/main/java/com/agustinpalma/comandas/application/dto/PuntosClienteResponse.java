@@ -0,0 +1,27 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+
+/**
+ * Saldo de puntos de un cliente (HU-105).
+ *
+ * @param disponible puntos que puede canjear hoy
+ * @param valorDisponible descuento en pesos equivalente al saldo disponible
+ * @param vencidos puntos que vencieron sin usarse (histórico)
+ * @param lotes lotes vigentes, del que vence primero al último
+ */
+public record PuntosClienteResponse(
+    String clienteId,
+    int disponible,
+    BigDecimal valorDisponible,
+    int vencidos,
+    List<LotePuntosResponse> lotes
+) {
+
+    /**
+     * @param fechaVencimiento null si el lote no vence
+     */
+    public record LotePuntosResponse(int puntos, LocalDateTime fechaVencimiento) {}
+}
@@ -0,0 +1,19 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import jakarta.validation.constraints.NotNull;
+
+import java.math.BigDecimal;
+import java.util.Map;
+
+/**
+ * DTO de entrada para configurar los recargos por medio de pago (HU-143).
+ *
+ * @param porcentajes recargo de cada medio; un medio sin enviar o en cero no tiene recargo
+ */
+public record RecargosMedioPagoRequest(
+
+    @NotNull(message = "Los porcentajes de recargo son obligatorios")
+    Map<MedioPago, BigDecimal> porcentajes
+) {
+}
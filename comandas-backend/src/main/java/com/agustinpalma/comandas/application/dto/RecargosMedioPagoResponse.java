@@ -0,0 +1,19 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.RecargosMedioPago;
+
+import java.math.BigDecimal;
+import java.util.Map;
+
+/**
+ * DTO de salida con los recargos por medio de pago (HU-143).
+ * Solo trae los medios que tienen recargo.
+ */
+public record RecargosMedioPagoResponse(
+    Map<MedioPago, BigDecimal> porcentajes
+) {
+    public static RecargosMedioPagoResponse fromDomain(RecargosMedioPago recargos) {
+        return new RecargosMedioPagoResponse(recargos.getPorcentajes());
+    }
+}
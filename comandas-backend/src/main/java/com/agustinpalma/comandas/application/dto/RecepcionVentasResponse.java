@@ -0,0 +1,22 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Acuse de recibo de un lote de ventas en el consolidado (HU-107).
+ *
+ * @param recibidas cantidad de ventas guardadas (nuevas o reemplazadas)
+ * @param cursor fecha de cierre de la última venta del lote; la sucursal
+ *               la usa como "desde" en la próxima sincronización.
+ *               Null si el lote vino vacío.
+ * @param cursorPedidoId pedido de esa última venta: desempata las ventas
+ *                       cerradas en el mismo instante que el cursor.
+ *                       La sucursal lo usa como "desdePedido".
+ */
+public record RecepcionVentasResponse(
+    int recibidas,
+    LocalDateTime cursor,
+    UUID cursorPedidoId
+) {
+}
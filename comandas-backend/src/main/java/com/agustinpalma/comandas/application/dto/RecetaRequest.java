@@ -0,0 +1,36 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.Valid;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Positive;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * DTO de entrada para guardar la receta de un producto (HU-118).
+ *
+ * @param ingredientes insumos de una unidad del producto (para quitar la receta, DELETE)
+ */
+public record RecetaRequest(
+
+    @NotNull(message = "Los ingredientes son obligatorios")
+    @Valid
+    List<IngredienteRequest> ingredientes
+) {
+
+    /**
+     * @param cantidad en la unidad del insumo (ej: 0.180 kg)
+     */
+    public record IngredienteRequest(
+
+        @NotNull(message = "El insumo es obligatorio")
+        UUID insumoId,
+
+        @NotNull(message = "La cantidad es obligatoria")
+        @Positive(message = "La cantidad debe ser mayor a cero")
+        BigDecimal cantidad
+    ) {
+    }
+}
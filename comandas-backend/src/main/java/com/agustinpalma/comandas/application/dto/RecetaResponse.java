@@ -0,0 +1,32 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * DTO de salida con la receta de un producto y su costo (HU-118).
+ *
+ * @param ingredientes  cada insumo con lo que aporta al costo
+ * @param rentabilidad  precio, costo y margen del producto
+ */
+public record RecetaResponse(
+    UUID productoId,
+    List<IngredienteResponse> ingredientes,
+    RentabilidadProductoResponse rentabilidad
+) {
+
+    /**
+     * @param costo cantidad × costo unitario vigente del insumo
+     */
+    public record IngredienteResponse(
+        UUID insumoId,
+        String nombreInsumo,
+        UnidadInsumo unidad,
+        BigDecimal cantidad,
+        BigDecimal costo
+    ) {
+    }
+}
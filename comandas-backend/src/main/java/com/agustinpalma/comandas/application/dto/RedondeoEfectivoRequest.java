@@ -0,0 +1,17 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ModoRedondeo;
+
+import java.math.BigDecimal;
+
+/**
+ * DTO de entrada para configurar el redondeo del efectivo (HU-152).
+ *
+ * @param multiplo múltiplo al que se redondea (ej: 100); null o cero = cobrar al centavo
+ * @param modo     CERCANO, ABAJO o ARRIBA (null = CERCANO)
+ */
+public record RedondeoEfectivoRequest(
+    BigDecimal multiplo,
+    ModoRedondeo modo
+) {
+}
@@ -0,0 +1,20 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ModoRedondeo;
+import com.agustinpalma.comandas.domain.model.RedondeoEfectivo;
+
+import java.math.BigDecimal;
+
+/**
+ * DTO de salida con la regla de redondeo del efectivo (HU-152).
+ *
+ * @param multiplo null si el local cobra al centavo
+ */
+public record RedondeoEfectivoResponse(
+    BigDecimal multiplo,
+    ModoRedondeo modo
+) {
+    public static RedondeoEfectivoResponse fromDomain(RedondeoEfectivo redondeo) {
+        return new RedondeoEfectivoResponse(redondeo.getMultiplo(), redondeo.getModo());
+    }
+}
@@ -0,0 +1,22 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ModoRepartoPropinas;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import jakarta.validation.constraints.NotNull;
+
+import java.util.Map;
+
+/**
+ * DTO de entrada para configurar el reparto de propinas del local (HU-141).
+ *
+ * @param puntosPorRol puntos de cada rol; un rol sin enviar o en cero no participa
+ */
+public record ReglaPropinasRequest(
+
+    @NotNull(message = "El modo de reparto es obligatorio")
+    ModoRepartoPropinas modo,
+
+    @NotNull(message = "Los puntos por rol son obligatorios")
+    Map<RolPersonal, Integer> puntosPorRol
+) {
+}
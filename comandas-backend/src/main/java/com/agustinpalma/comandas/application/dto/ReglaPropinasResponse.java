@@ -0,0 +1,19 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ModoRepartoPropinas;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import com.agustinpalma.comandas.domain.model.ReglaPropinas;
+
+import java.util.Map;
+
+/**
+ * DTO de salida con la regla de reparto de propinas (HU-141).
+ */
+public record ReglaPropinasResponse(
+    ModoRepartoPropinas modo,
+    Map<RolPersonal, Integer> puntosPorRol
+) {
+    public static ReglaPropinasResponse fromDomain(ReglaPropinas regla) {
+        return new ReglaPropinasResponse(regla.getModo(), regla.getPuntosPorRol());
+    }
+}
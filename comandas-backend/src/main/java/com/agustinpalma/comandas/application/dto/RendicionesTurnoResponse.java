@@ -0,0 +1,39 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Rendición de efectivo de los repartidores en el turno abierto (HU-140).
+ *
+ * Los que todavía no rindieron muestran lo esperado hasta el momento, con
+ * rendido y diferencia en null.
+ */
+public record RendicionesTurnoResponse(
+    UUID turnoId,
+    List<RendicionRepartidorDTO> repartidores,
+    BigDecimal totalEsperado,
+    BigDecimal totalRendido
+) {
+
+    /**
+     * @param pedidos pedidos que llevó en el turno (entregados y cobrados)
+     * @param enLaCalle pedidos con los que salió y todavía no volvió
+     * @param sinCobrar pedidos que volvieron sin cobrarse en caja
+     * @param esperado efectivo cobrado en sus entregas del turno
+     * @param diferencia rendido − esperado (negativo = faltante)
+     */
+    public record RendicionRepartidorDTO(
+        UUID repartidorId,
+        String nombre,
+        int pedidos,
+        int enLaCalle,
+        int sinCobrar,
+        BigDecimal esperado,
+        BigDecimal rendido,
+        BigDecimal diferencia,
+        String observaciones
+    ) {
+    }
+}
@@ -0,0 +1,28 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.PositiveOrZero;
+import jakarta.validation.constraints.Size;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * DTO de entrada para la rendición de efectivo de un repartidor (HU-140).
+ *
+ * @param rendido efectivo que entrega el repartidor en caja
+ * @param observaciones obligatorias si lo rendido no coincide con lo esperado
+ */
+public record RendirEfectivoRequest(
+
+    @NotNull(message = "El repartidor es obligatorio")
+    UUID repartidorId,
+
+    @NotNull(message = "El efectivo rendido es obligatorio")
+    @PositiveOrZero(message = "El efectivo rendido no puede ser negativo")
+    BigDecimal rendido,
+
+    @Size(max = 200, message = "Las observaciones no pueden superar los 200 caracteres")
+    String observaciones
+) {
+}
@@ -0,0 +1,35 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.RentabilidadProducto;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * DTO de salida con el margen teórico de un producto según su receta (HU-118).
+ *
+ * @param margenPorcentaje margen sobre el precio de venta
+ * @param aPerdida         true si el costo de la receta supera al precio
+ */
+public record RentabilidadProductoResponse(
+    UUID productoId,
+    String nombre,
+    BigDecimal precio,
+    BigDecimal costo,
+    BigDecimal margen,
+    BigDecimal margenPorcentaje,
+    boolean aPerdida
+) {
+
+    public static RentabilidadProductoResponse fromDomain(RentabilidadProducto rentabilidad) {
+        return new RentabilidadProductoResponse(
+            rentabilidad.getProductoId().getValue(),
+            rentabilidad.getNombre(),
+            rentabilidad.getPrecio(),
+            rentabilidad.getCosto(),
+            rentabilidad.getMargen(),
+            rentabilidad.getMargenPorcentaje(),
+            rentabilidad.estaAPerdida()
+        );
+    }
+}
@@ -0,0 +1,46 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.Pedido;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Reparto de un pedido de delivery (HU-140).
+ *
+ * @param total total del pedido (congelado si ya se cobró)
+ * @param cobradoEfectivo lo cobrado en EFECTIVO: lo que trae el repartidor
+ * @param minutosReparto desde la salida hasta el regreso (null si no volvió)
+ */
+public record RepartoResponse(
+    UUID pedidoId,
+    int numeroPedido,
+    String direccionEntrega,
+    UUID repartidorId,
+    String repartidor,
+    EstadoPedido estado,
+    BigDecimal total,
+    BigDecimal cobradoEfectivo,
+    LocalDateTime salida,
+    LocalDateTime regreso,
+    Long minutosReparto
+) {
+
+    public static RepartoResponse fromDomain(Pedido pedido, String repartidor) {
+        return new RepartoResponse(
+            pedido.getId().getValue(),
+            pedido.getNumero(),
+            pedido.getDireccionEntrega(),
+            pedido.getRepartidorId() != null ? pedido.getRepartidorId().getValue() : null,
+            repartidor,
+            pedido.getEstado(),
+            pedido.getMontoTotalFinal() != null ? pedido.getMontoTotalFinal() : pedido.calcularTotal(),
+            pedido.calcularCobradoEfectivo(),
+            pedido.getSalidaReparto(),
+            pedido.getRegresoReparto(),
+            pedido.getRegresoReparto() != null ? pedido.minutosReparto() : null
+        );
+    }
+}
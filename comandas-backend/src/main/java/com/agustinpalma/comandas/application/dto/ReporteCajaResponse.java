@@ -1,6 +1,8 @@
 package com.agustinpalma.comandas.application.dto;
 
 import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Moneda;
+import com.agustinpalma.comandas.domain.model.EfectivoMoneda;
 import com.agustinpalma.comandas.domain.model.MovimientoCaja;
 import com.agustinpalma.comandas.domain.model.Pago;
 import com.agustinpalma.comandas.domain.model.Pedido;
@@ -15,33 +17,59 @@ import java.util.Map;
 /**
  * Response DTO para el reporte de caja diario (arqueo).
  *
- * @param totalVentasReales suma de pedidos cerrados excluyendo pagos A_CUENTA
+ * @param totalVentasReales suma de pedidos cerrados excluyendo pagos A_CUENTA, neta de devoluciones
  * @param totalConsumoInterno suma de pagos A_CUENTA
  * @param totalIngresos suma de ingresos manuales de efectivo
- * @param totalEgresos suma de egresos de caja (salidas de efectivo)
- * @param balanceEfectivo (total pagos EFECTIVO) + totalIngresos − totalEgresos
+ * @param totalEgresos suma de egresos de caja (salidas de efectivo), sin devoluciones
+ * @param totalDevoluciones HU-121: reintegros de pedidos cerrados registrados en el día
+ * @param balanceEfectivo (total pagos EFECTIVO) + totalIngresos − totalEgresos − totalDevoluciones
  * @param desglosePorMedioPago mapa con el total por cada medio de pago comercial
+ * @param totalRecargos HU-143: recargos por medio de pago cobrados encima de las ventas
+ * @param recargosPorMedioPago HU-143: recargos discriminados por medio de pago
  * @param movimientos lista de movimientos de caja del día (egresos + ingresos)
  * @param ventas lista de pedidos cerrados del día (historial de ventas)
  * @param pagosDetalle lista plana de pagos individuales con contexto del pedido/mesa
  * @param jornadaCerrada indica si ya existe una jornada cerrada para la fecha consultada
+ * @param efectivoPesos HU-151: pesos físicos esperados en el cajón (balance sin moneda extranjera)
+ * @param efectivoPorMoneda HU-151: efectivo en cada moneda extranjera, con su equivalente en pesos
+ * @param totalAjusteRedondeo HU-152: ajustes por redondeo del efectivo (incluidos en el balance)
  */
 public record ReporteCajaResponse(
     BigDecimal totalVentasReales,
     BigDecimal totalConsumoInterno,
     BigDecimal totalIngresos,
     BigDecimal totalEgresos,
+    BigDecimal totalDevoluciones,
     BigDecimal balanceEfectivo,
     Map<MedioPago, BigDecimal> desglosePorMedioPago,
+    BigDecimal totalRecargos,
+    Map<MedioPago, BigDecimal> recargosPorMedioPago,
     List<MovimientoResumen> movimientos,
     List<VentaResumen> ventas,
     List<PagoDetalle> pagosDetalle,
-    boolean jornadaCerrada
+    boolean jornadaCerrada,
+    BigDecimal efectivoPesos,
+    List<EfectivoMonedaResumen> efectivoPorMoneda,
+    BigDecimal totalAjusteRedondeo
 ) {
 
+    /**
+     * HU-151: Efectivo de una moneda extranjera para contarlo aparte al cerrar.
+     */
+    public record EfectivoMonedaResumen(
+        Moneda moneda,
+        BigDecimal monto,
+        BigDecimal equivalentePesos
+    ) {
+        public static EfectivoMonedaResumen fromDomain(EfectivoMoneda efectivo) {
+            return new EfectivoMonedaResumen(efectivo.getMoneda(), efectivo.getMonto(), efectivo.getEquivalentePesos());
+        }
+    }
+
     /**
      * Resumen de un movimiento de caja para el reporte.
      * Incluye el tipo (EGRESO/INGRESO) para diferenciación visual en la UI.
+     * HU-121: pedidoId solo viene en las devoluciones.
      */
     public record MovimientoResumen(
         String id,
@@ -49,7 +77,8 @@ public record ReporteCajaResponse(
         String descripcion,
         LocalDateTime fecha,
         String numeroComprobante,
-        String tipo
+        String tipo,
+        String pedidoId
     ) {
         public static MovimientoResumen fromDomain(MovimientoCaja movimiento) {
             return new MovimientoResumen(
@@ -58,7 +87,8 @@ public record ReporteCajaResponse(
                 movimiento.getDescripcion(),
                 movimiento.getFecha(),
                 movimiento.getNumeroComprobante(),
-                movimiento.getTipo().name()
+                movimiento.getTipo().name(),
+                movimiento.getPedidoId() != null ? movimiento.getPedidoId().getValue().toString() : null
             );
         }
     }
@@ -126,7 +156,12 @@ public record ReporteCajaResponse(
                     mesaNum,
                     pago.getMedio(),
                     pago.getMonto(),
-                    pago.getFecha()
+                    pago.getRecargo(),
+                    pago.getFecha(),
+                    pago.getMoneda(),
+                    pago.getMontoOriginal(),
+                    pago.getAjusteRedondeo(),
+                    pago.getReferencia()
                 ));
             }
         }
@@ -136,12 +171,18 @@ public record ReporteCajaResponse(
             reporte.getTotalConsumoInterno(),
             reporte.getTotalIngresos(),
             reporte.getTotalEgresos(),
+            reporte.getTotalDevoluciones(),
             reporte.getBalanceEfectivo(),
             reporte.getDesglosePorMedioPago(),
+            reporte.getTotalRecargos(),
+            reporte.getRecargosPorMedioPago(),
             movimientos,
             ventas,
             pagosDetalle,
-            jornadaCerrada
+            jornadaCerrada,
+            reporte.getEfectivoPesos(),
+            reporte.getEfectivoMonedaExtranjera().stream().map(EfectivoMonedaResumen::fromDomain).toList(),
+            reporte.getTotalAjusteRedondeo()
         );
     }
 
@@ -155,7 +196,12 @@ public record ReporteCajaResponse(
      * @param mesaNumero número de la mesa donde se consumió
      * @param medioPago medio de pago utilizado (EFECTIVO, TARJETA, QR, etc.)
      * @param monto monto individual de este pago
+     * @param recargo HU-143: recargo del medio cobrado encima del monto
      * @param fecha momento en que se registró el pago
+     * @param moneda HU-151: moneda en que pagó el cliente (ARS salvo pagos en moneda extranjera)
+     * @param montoOriginal HU-151: monto en esa moneda (igual a monto si son pesos)
+     * @param ajusteRedondeo HU-152: redondeo del efectivo sumado o restado al monto
+     * @param referencia HU-156: número de cupón u operación del comprobante (nullable)
      */
     public record PagoDetalle(
         String pedidoId,
@@ -163,6 +209,11 @@ public record ReporteCajaResponse(
         int mesaNumero,
         MedioPago medioPago,
         BigDecimal monto,
-        LocalDateTime fecha
+        BigDecimal recargo,
+        LocalDateTime fecha,
+        Moneda moneda,
+        BigDecimal montoOriginal,
+        BigDecimal ajusteRedondeo,
+        String referencia
     ) {}
 }
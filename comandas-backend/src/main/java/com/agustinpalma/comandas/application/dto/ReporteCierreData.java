@@ -41,9 +41,80 @@ public record ReporteCierreData(
     Map<MedioPago, BigDecimal> desglosePorMedioPago,
 
     // ── Movimientos manuales del día ────────────────────────────────────────
-    List<MovimientoDetalle> movimientos
+    List<MovimientoDetalle> movimientos,
+
+    // ── HU-106: Arqueo por turno (vacío si no se usaron turnos) ─────────────
+    List<TurnoDetalle> turnos,
+
+    // ── HU-143: Recargos por medio de pago, aparte de las ventas ────────────
+    Map<MedioPago, BigDecimal> recargosPorMedioPago,
+
+    // ── HU-151: Efectivo en moneda extranjera (vacío si todo fue en pesos) ──
+    List<EfectivoMonedaDetalle> efectivoPorMoneda,
+
+    // ── HU-152: Ajustes por redondeo del efectivo (incluidos en el balance) ─
+    BigDecimal totalAjusteRedondeo
 ) {
 
+    /**
+     * Constructor de retrocompatibilidad (sin redondeo del efectivo).
+     */
+    public ReporteCierreData(
+        String nombreLocal, String direccion, String telefono, String cuit,
+        LocalDate fechaOperativa, LocalDateTime fechaCierre,
+        BigDecimal totalVentasReales, BigDecimal totalConsumoInterno,
+        BigDecimal totalIngresos, BigDecimal totalEgresos,
+        BigDecimal balanceEfectivo, int pedidosCerradosCount,
+        Map<MedioPago, BigDecimal> desglosePorMedioPago,
+        List<MovimientoDetalle> movimientos,
+        List<TurnoDetalle> turnos,
+        Map<MedioPago, BigDecimal> recargosPorMedioPago,
+        List<EfectivoMonedaDetalle> efectivoPorMoneda
+    ) {
+        this(nombreLocal, direccion, telefono, cuit, fechaOperativa, fechaCierre,
+            totalVentasReales, totalConsumoInterno, totalIngresos, totalEgresos,
+            balanceEfectivo, pedidosCerradosCount, desglosePorMedioPago, movimientos,
+            turnos, recargosPorMedioPago, efectivoPorMoneda, BigDecimal.ZERO);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (sin efectivo en moneda extranjera).
+     */
+    public ReporteCierreData(
+        String nombreLocal, String direccion, String telefono, String cuit,
+        LocalDate fechaOperativa, LocalDateTime fechaCierre,
+        BigDecimal totalVentasReales, BigDecimal totalConsumoInterno,
+        BigDecimal totalIngresos, BigDecimal totalEgresos,
+        BigDecimal balanceEfectivo, int pedidosCerradosCount,
+        Map<MedioPago, BigDecimal> desglosePorMedioPago,
+        List<MovimientoDetalle> movimientos,
+        List<TurnoDetalle> turnos,
+        Map<MedioPago, BigDecimal> recargosPorMedioPago
+    ) {
+        this(nombreLocal, direccion, telefono, cuit, fechaOperativa, fechaCierre,
+            totalVentasReales, totalConsumoInterno, totalIngresos, totalEgresos,
+            balanceEfectivo, pedidosCerradosCount, desglosePorMedioPago, movimientos,
+            turnos, recargosPorMedioPago, List.of(), BigDecimal.ZERO);
+    }
+
+    /**
+     * Constructor de retrocompatibilidad (jornada sin turnos).
+     */
+    public ReporteCierreData(
+        String nombreLocal, String direccion, String telefono, String cuit,
+        LocalDate fechaOperativa, LocalDateTime fechaCierre,
+        BigDecimal totalVentasReales, BigDecimal totalConsumoInterno,
+        BigDecimal totalIngresos, BigDecimal totalEgresos,
+        BigDecimal balanceEfectivo, int pedidosCerradosCount,
+        Map<MedioPago, BigDecimal> desglosePorMedioPago,
+        List<MovimientoDetalle> movimientos
+    ) {
+        this(nombreLocal, direccion, telefono, cuit, fechaOperativa, fechaCierre,
+            totalVentasReales, totalConsumoInterno, totalIngresos, totalEgresos,
+            balanceEfectivo, pedidosCerradosCount, desglosePorMedioPago, movimientos, List.of(), Map.of(), List.of(),
+            BigDecimal.ZERO);
+    }
+
     /**
      * Detalle de un movimiento manual (egreso o ingreso) para el reporte PDF.
      */
@@ -54,4 +125,36 @@ public record ReporteCierreData(
         String numeroComprobante,
         LocalDateTime fecha
     ) {}
+
+    /**
+     * HU-106: Arqueo de un turno para el consolidado del PDF.
+     * Los montos del arqueo son null si el turno quedó sin cerrar.
+     */
+    public record TurnoDetalle(
+        String tipo,
+        LocalDateTime fechaApertura,
+        LocalDateTime fechaCierre,
+        BigDecimal montoInicial,
+        BigDecimal efectivoEsperado,
+        BigDecimal efectivoDeclarado,
+        BigDecimal diferencia
+    ) {}
+
+    /**
+     * HU-151: Efectivo cobrado en una moneda extranjera y su equivalente en pesos.
+     */
+    public record EfectivoMonedaDetalle(
+        String moneda,
+        BigDecimal monto,
+        BigDecimal equivalentePesos
+    ) {}
+
+    /**
+     * HU-151: Pesos físicos en el cajón: el balance menos el equivalente de las otras monedas.
+     */
+    public BigDecimal efectivoPesos() {
+        return efectivoPorMoneda.stream()
+            .map(EfectivoMonedaDetalle::equivalentePesos)
+            .reduce(balanceEfectivo, BigDecimal::subtract);
+    }
 }
@@ -0,0 +1,40 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.time.YearMonth;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Resumen mensual de consumo interno por empleado (HU-132).
+ *
+ * Un consumo se cuenta en el mes del cierre del pedido. El excedente sobre
+ * el tope es lo que se descuenta del sueldo.
+ *
+ * @param totalConsumido suma de los consumos imputados del mes
+ * @param totalADescontar suma de los excedentes de todos los empleados
+ */
+public record ReporteConsumoPersonalResponse(
+    YearMonth periodo,
+    List<ConsumoEmpleado> empleados,
+    BigDecimal totalConsumido,
+    BigDecimal totalADescontar
+) {
+
+    /**
+     * @param topeMensual null si el empleado no tiene tope
+     * @param consumos cantidad de pedidos a cuenta imputados en el mes
+     * @param excedente parte del consumo que supera el tope (cero si no lo supera o no tiene tope)
+     * @param disponible lo que le queda del beneficio en el mes (null si no tiene tope)
+     */
+    public record ConsumoEmpleado(
+        UUID mozoId,
+        String nombre,
+        BigDecimal topeMensual,
+        int consumos,
+        BigDecimal consumido,
+        BigDecimal excedente,
+        BigDecimal disponible
+    ) {
+    }
+}
@@ -0,0 +1,68 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoTurno;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Histórico de faltantes y sobrantes de caja por cajero (HU-129).
+ *
+ * Los turnos cerrados sin responsable (anteriores a la HU o sin diferencia)
+ * se agrupan en una fila con responsableId null.
+ *
+ * @param cajeros       una fila por responsable, de mayor a menor faltante acumulado
+ * @param diferencias   los turnos del rango que no cerraron, en orden de cierre
+ * @param totalFaltante suma (en positivo) de los faltantes del rango
+ * @param totalSobrante suma de los sobrantes del rango
+ */
+public record ReporteDiferenciasCajaResponse(
+    LocalDate desde,
+    LocalDate hasta,
+    List<CajeroResumen> cajeros,
+    List<DiferenciaTurno> diferencias,
+    BigDecimal totalFaltante,
+    BigDecimal totalSobrante
+) {
+
+    /**
+     * @param turnosCerrados    turnos que cerró en el rango (con o sin diferencia)
+     * @param turnosConFaltante turnos con declarado menor al esperado
+     * @param turnosConSobrante turnos con declarado mayor al esperado
+     * @param totalFaltante     faltantes acumulados, en positivo
+     * @param totalSobrante     sobrantes acumulados
+     * @param neto              sobrantes − faltantes (negativo = le falta plata)
+     * @param mayorFaltante     el peor faltante del rango, en positivo (cero si no tuvo)
+     */
+    public record CajeroResumen(
+        UUID responsableId,
+        String nombre,
+        int turnosCerrados,
+        int turnosConFaltante,
+        int turnosConSobrante,
+        BigDecimal totalFaltante,
+        BigDecimal totalSobrante,
+        BigDecimal neto,
+        BigDecimal mayorFaltante
+    ) {
+    }
+
+    /**
+     * @param diferencia declarado − esperado (negativo = faltante)
+     */
+    public record DiferenciaTurno(
+        UUID turnoId,
+        TipoTurno tipo,
+        LocalDateTime fechaCierre,
+        UUID responsableId,
+        String nombreResponsable,
+        BigDecimal efectivoEsperado,
+        BigDecimal efectivoDeclarado,
+        BigDecimal diferencia,
+        String observaciones
+    ) {
+    }
+}
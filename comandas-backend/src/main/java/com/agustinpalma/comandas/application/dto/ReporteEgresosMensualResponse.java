@@ -0,0 +1,42 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.time.YearMonth;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Egresos de caja de un mes por categoría, contra el mes anterior (HU-130).
+ *
+ * @param categorias las categorías con egresos en alguno de los dos meses, de mayor a menor
+ *                   gasto del mes; "Sin categoría" al final
+ * @param variacion total - totalAnterior (positivo = se gastó más)
+ * @param variacionPorcentual variación sobre el mes anterior (null si el mes anterior no tuvo egresos)
+ */
+public record ReporteEgresosMensualResponse(
+    YearMonth periodo,
+    YearMonth periodoAnterior,
+    List<CategoriaMes> categorias,
+    BigDecimal total,
+    BigDecimal totalAnterior,
+    BigDecimal variacion,
+    BigDecimal variacionPorcentual
+) {
+
+    /**
+     * @param categoriaId null para los egresos sin categoría
+     * @param activa false si la categoría se desactivó (sigue apareciendo por sus egresos)
+     */
+    public record CategoriaMes(
+        UUID categoriaId,
+        String nombre,
+        boolean activa,
+        int cantidad,
+        BigDecimal total,
+        int cantidadAnterior,
+        BigDecimal totalAnterior,
+        BigDecimal variacion,
+        BigDecimal variacionPorcentual
+    ) {
+    }
+}
@@ -0,0 +1,31 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.util.List;
+
+/**
+ * Costos de envío cobrados en un rango, separados de la venta de productos (HU-139).
+ *
+ * @param ventaProductos lo vendido en productos en el mismo rango, sin envíos
+ * @param zonas          envíos cobrados por zona, de mayor a menor total
+ */
+public record ReporteEnviosResponse(
+    LocalDate desde,
+    LocalDate hasta,
+    long envios,
+    BigDecimal totalEnvios,
+    BigDecimal ventaProductos,
+    List<EnviosZona> zonas
+) {
+
+    /**
+     * @param zona nombre de la zona al momento de cada venta
+     */
+    public record EnviosZona(
+        String zona,
+        long envios,
+        BigDecimal total
+    ) {
+    }
+}
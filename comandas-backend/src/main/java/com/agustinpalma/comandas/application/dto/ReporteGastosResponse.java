@@ -0,0 +1,39 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaGasto;
+
+import java.math.BigDecimal;
+import java.time.YearMonth;
+import java.util.List;
+
+/**
+ * Reporte mensual de gastos por categoría contra las ventas (HU-117).
+ *
+ * @param totalVentas ventas reales del mes: suma de las jornadas cerradas (sin consumo interno)
+ * @param categorias solo las categorías con gastos en el mes, de mayor a menor
+ * @param otrosEgresosCaja egresos de caja cargados sin registrar un gasto (no están en ninguna categoría)
+ * @param totalGastos suma de las categorías más los otros egresos de caja
+ * @param resultado totalVentas - totalGastos
+ * @param porcentajeGastos totalGastos sobre totalVentas (cero si no hubo ventas)
+ */
+public record ReporteGastosResponse(
+    YearMonth periodo,
+    BigDecimal totalVentas,
+    List<CategoriaResumen> categorias,
+    BigDecimal otrosEgresosCaja,
+    BigDecimal totalGastos,
+    BigDecimal resultado,
+    BigDecimal porcentajeGastos
+) {
+
+    /**
+     * @param porcentajeSobreVentas total de la categoría sobre las ventas del mes (cero si no hubo ventas)
+     */
+    public record CategoriaResumen(
+        CategoriaGasto categoria,
+        int cantidad,
+        BigDecimal total,
+        BigDecimal porcentajeSobreVentas
+    ) {
+    }
+}
@@ -0,0 +1,36 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.time.YearMonth;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Reporte mensual de horas por empleado (HU-124).
+ *
+ * Un fichaje se cuenta en el mes de su entrada. Los fichajes sin salida
+ * no suman horas hasta que se corrigen.
+ *
+ * @param totalMinutos suma de los minutos de todos los empleados
+ */
+public record ReporteHorasResponse(
+    YearMonth periodo,
+    List<HorasEmpleado> empleados,
+    long totalMinutos
+) {
+
+    /**
+     * @param diasTrabajados días distintos con al menos una entrada
+     * @param fichajesIncompletos fichajes sin salida (presentes o salida olvidada)
+     * @param fichajesCorregidos fichajes corregidos o cargados a mano por el dueño
+     */
+    public record HorasEmpleado(
+        UUID mozoId,
+        String nombre,
+        long minutos,
+        int diasTrabajados,
+        int fichajesIncompletos,
+        int fichajesCorregidos,
+        List<FichajeResponse> fichajes
+    ) {
+    }
+}
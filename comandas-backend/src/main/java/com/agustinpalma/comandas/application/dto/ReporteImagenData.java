@@ -0,0 +1,82 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+
+/**
+ * Datos de un reporte para exportarlo como imagen (HU-157).
+ *
+ * Es una vista ya resumida: secciones con filas de etiqueta y valor, listas
+ * para dibujar en una imagen angosta que el dueño reenvía por WhatsApp.
+ *
+ * @param nombreLocal nombre del local para el encabezado
+ * @param titulo      título de la vista ("Ventas", "Caja", "Productos")
+ * @param desde       primer día operativo del período
+ * @param hasta       último día operativo del período (igual a desde si es un solo día)
+ * @param secciones   bloques del reporte en el orden en que se dibujan
+ * @param generadoEn  momento de generación, para el pie
+ */
+public record ReporteImagenData(
+    String nombreLocal,
+    String titulo,
+    LocalDate desde,
+    LocalDate hasta,
+    List<Seccion> secciones,
+    LocalDateTime generadoEn
+) {
+
+    /**
+     * Reportes que se pueden exportar como imagen.
+     */
+    public enum Vista {
+        /** Total vendido, pedidos, ticket promedio, medios de pago y productos más vendidos */
+        VENTAS,
+        /** Arqueo: ventas, ingresos, egresos, devoluciones y balance de efectivo */
+        CAJA,
+        /** Todos los productos vendidos con cantidad y recaudación */
+        PRODUCTOS
+    }
+
+    /**
+     * Ancho de la imagen en píxeles.
+     */
+    public enum Ancho {
+        /** Ancho de un ticket de 80 mm a 203 dpi */
+        TICKET(576),
+        /** Ancho de la pantalla de un teléfono */
+        TELEFONO(1080);
+
+        private final int pixeles;
+
+        Ancho(int pixeles) {
+            this.pixeles = pixeles;
+        }
+
+        public int pixeles() {
+            return pixeles;
+        }
+    }
+
+    /**
+     * @param total fila de total al pie de la sección; null si no lleva
+     */
+    public record Seccion(
+        String titulo,
+        List<Fila> filas,
+        Fila total
+    ) {
+    }
+
+    /**
+     * @param detalle texto secundario (cantidad, porcentaje); null si no lleva
+     * @param monto   importe de la fila; null si la fila es solo un conteo en el detalle
+     */
+    public record Fila(
+        String etiqueta,
+        String detalle,
+        BigDecimal monto
+    ) {
+    }
+}
@@ -0,0 +1,47 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaMerma;
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+
+import java.math.BigDecimal;
+import java.time.YearMonth;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Merma valorizada de un mes (HU-135).
+ *
+ * @param categorias categorías con mermas en el mes, de mayor a menor pérdida
+ * @param insumos lo perdido de cada insumo en el mes, de mayor a menor pérdida
+ */
+public record ReporteMermasResponse(
+    YearMonth periodo,
+    int registros,
+    BigDecimal total,
+    List<CategoriaMes> categorias,
+    List<InsumoMes> insumos
+) {
+
+    /**
+     * @param porcentaje participación sobre el total del mes
+     */
+    public record CategoriaMes(
+        CategoriaMerma categoria,
+        int registros,
+        BigDecimal total,
+        BigDecimal porcentaje
+    ) {
+    }
+
+    /**
+     * @param cantidad total perdido, en la unidad del insumo
+     */
+    public record InsumoMes(
+        UUID insumoId,
+        String nombre,
+        UnidadInsumo unidad,
+        BigDecimal cantidad,
+        BigDecimal total
+    ) {
+    }
+}
@@ -0,0 +1,44 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Reporte de desempeño por mozo en un rango de fechas (HU-111).
+ *
+ * Los pedidos sin mozo asignado se agrupan en una fila con mozoId null.
+ *
+ * @param totalVentas suma de las ventas de todas las filas
+ * @param totalPropinas suma de las propinas de todas las filas
+ */
+public record ReporteMozosResponse(
+    LocalDate desde,
+    LocalDate hasta,
+    List<MozoResumen> mozos,
+    BigDecimal totalVentas,
+    BigDecimal totalPropinas
+) {
+
+    /**
+     * @param ventas total cobrado de sus pedidos (snapshot contable, sin propinas)
+     * @param ticketPromedio ventas / cantidadPedidos
+     * @param promedioPorCubierto ventas / cubiertos (cero si no se informaron cubiertos)
+     * @param unidadesAnuladas unidades quitadas después de enviarse a cocina
+     * @param montoAnulado importe de esas unidades a precio de lista
+     */
+    public record MozoResumen(
+        UUID mozoId,
+        String nombre,
+        int cantidadPedidos,
+        BigDecimal ventas,
+        int cubiertos,
+        BigDecimal ticketPromedio,
+        BigDecimal promedioPorCubierto,
+        int unidadesAnuladas,
+        BigDecimal montoAnulado,
+        BigDecimal propinas
+    ) {
+    }
+}
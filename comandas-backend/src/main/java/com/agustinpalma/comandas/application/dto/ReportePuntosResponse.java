@@ -0,0 +1,26 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+
+/**
+ * Reporte de puntos del local en un rango de fechas (HU-105).
+ *
+ * @param emitidos puntos acumulados por pedidos cerrados en el rango
+ * @param canjeados puntos usados como descuento en el rango
+ * @param vencidos puntos que vencieron sin usarse en el rango
+ * @param montoDescontado pesos descontados por canjes en el rango
+ * @param puntosVigentes saldo total de los clientes hoy (pasivo del programa)
+ * @param valorPuntosVigentes descuento potencial de ese saldo con el valor de punto actual
+ */
+public record ReportePuntosResponse(
+    LocalDate desde,
+    LocalDate hasta,
+    int emitidos,
+    int canjeados,
+    int vencidos,
+    BigDecimal montoDescontado,
+    int puntosVigentes,
+    BigDecimal valorPuntosVigentes
+) {
+}
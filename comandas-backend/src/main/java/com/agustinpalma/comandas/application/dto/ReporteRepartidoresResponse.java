@@ -0,0 +1,36 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Entregas y tiempos por repartidor en un rango de fechas (HU-140).
+ *
+ * Solo cuentan los repartos terminados (con salida y regreso).
+ *
+ * @param minutosPromedio promedio de salida a regreso de todas las entregas
+ */
+public record ReporteRepartidoresResponse(
+    LocalDate desde,
+    LocalDate hasta,
+    int entregas,
+    long minutosPromedio,
+    List<RepartidorResumen> repartidores
+) {
+
+    /**
+     * @param minutosMaximo el reparto más largo del rango
+     * @param cobradoEfectivo efectivo cobrado en sus entregas
+     */
+    public record RepartidorResumen(
+        UUID repartidorId,
+        String nombre,
+        int entregas,
+        long minutosPromedio,
+        long minutosMaximo,
+        BigDecimal cobradoEfectivo
+    ) {
+    }
+}
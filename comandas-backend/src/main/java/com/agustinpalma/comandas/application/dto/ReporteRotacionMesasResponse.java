@@ -0,0 +1,51 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.time.DayOfWeek;
+import java.time.LocalDate;
+import java.util.List;
+
+/**
+ * Reporte de rotación de mesas en un rango de fechas (HU-150).
+ *
+ * Una ocupación es un pedido de salón cerrado: va de la apertura de la mesa
+ * al cierre de la cuenta. Los minutos y promedios se informan con un decimal.
+ *
+ * @param diaSemana            día de la semana filtrado (null = todos los días del rango)
+ * @param diasAnalizados       días del rango que entran en el filtro
+ * @param mesas                mesas del local hoy
+ * @param rotacionPorMesa      ocupaciones por mesa por día
+ * @param franjas              una fila por hora de apertura con ocupaciones, ordenadas por hora
+ */
+public record ReporteRotacionMesasResponse(
+    LocalDate desde,
+    LocalDate hasta,
+    DayOfWeek diaSemana,
+    int diasAnalizados,
+    int mesas,
+    int ocupaciones,
+    BigDecimal promedioMinutos,
+    int cubiertos,
+    BigDecimal rotacionPorMesa,
+    List<FranjaOcupacion> franjas
+) {
+
+    /**
+     * @param hora                  hora de apertura (0-23): la franja 21 va de 21:00 a 21:59
+     * @param ocupacionesPorDia     mesas que se abrieron en la franja, en promedio por día
+     * @param cubiertosPromedio     comensales por mesa (null si ninguna informó cubiertos)
+     * @param capacidadPorHora      mesas que el salón puede sentar por hora con esa duración promedio
+     * @param cubiertosDisponibles  estimado de comensales más que entran por día en la franja
+     *                              (null si no hay cubiertos informados)
+     */
+    public record FranjaOcupacion(
+        int hora,
+        int ocupaciones,
+        BigDecimal ocupacionesPorDia,
+        BigDecimal promedioMinutos,
+        BigDecimal cubiertosPromedio,
+        BigDecimal capacidadPorHora,
+        Integer cubiertosDisponibles
+    ) {
+    }
+}
@@ -0,0 +1,46 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * Reporte comparativo de ventas por sucursal (HU-107).
+ *
+ * Cada sucursal se reporta por separado: el consolidado nunca suma
+ * cajas de distintas instalaciones en un mismo arqueo.
+ *
+ * @param totalVentas suma de las ventas reales de todas las sucursales
+ */
+public record ReporteSucursalesResponse(
+    LocalDate desde,
+    LocalDate hasta,
+    List<SucursalResumen> sucursales,
+    BigDecimal totalVentas
+) {
+
+    /**
+     * @param esEstaInstalacion true para la sucursal que genera el reporte
+     *                          (sus datos se leen en vivo, no del consolidado)
+     * @param totalVentas pagos comerciales, sin A_CUENTA
+     * @param ticketPromedio totalVentas / cantidadPedidos
+     * @param porMedioPago desglose del total por medio de pago (incluye A_CUENTA)
+     * @param ultimaVenta cierre más reciente recibido; sirve para ver si la sucursal está sincronizando
+     */
+    public record SucursalResumen(
+        UUID sucursalId,
+        String nombre,
+        boolean esEstaInstalacion,
+        int cantidadPedidos,
+        BigDecimal totalVentas,
+        BigDecimal ticketPromedio,
+        Map<MedioPago, BigDecimal> porMedioPago,
+        LocalDateTime ultimaVenta
+    ) {
+    }
+}
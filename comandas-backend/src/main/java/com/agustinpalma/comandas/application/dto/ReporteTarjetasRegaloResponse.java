@@ -0,0 +1,29 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.util.List;
+
+/**
+ * Vouchers emitidos, usados y vencidos en un rango de días (HU-144).
+ *
+ * @param montoEmitido       suma de lo vendido en tarjetas emitidas en el rango
+ * @param cantidadUsos       pagos hechos con tarjetas en el rango
+ * @param montoUsado         suma de esos pagos
+ * @param montoVencido       saldo que quedó sin usar en las tarjetas vencidas en el rango
+ * @param saldoVigente       saldo pendiente de todas las tarjetas vigentes hoy (lo que el local debe)
+ * @param tarjetas           tarjetas emitidas, usadas o vencidas en el rango, de la más nueva a la más vieja
+ */
+public record ReporteTarjetasRegaloResponse(
+    LocalDate desde,
+    LocalDate hasta,
+    int cantidadEmitidas,
+    BigDecimal montoEmitido,
+    int cantidadUsos,
+    BigDecimal montoUsado,
+    int cantidadVencidas,
+    BigDecimal montoVencido,
+    BigDecimal saldoVigente,
+    List<TarjetaRegaloResponse> tarjetas
+) {
+}
@@ -0,0 +1,36 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.util.List;
+
+/**
+ * Reporte de tiempos de cocina en un rango de fechas (HU-119).
+ *
+ * Solo cuentan las comandas marcadas como listas. Los minutos se informan
+ * con un decimal.
+ *
+ * @param franjas una fila por hora del día con comandas, ordenadas por hora
+ */
+public record ReporteTiemposCocinaResponse(
+    LocalDate desde,
+    LocalDate hasta,
+    int cantidadComandas,
+    BigDecimal promedioMinutos,
+    int demoradas,
+    List<FranjaHoraria> franjas
+) {
+
+    /**
+     * @param hora hora del envío a cocina (0-23): la franja 20 va de 20:00 a 20:59
+     * @param maximoMinutos la comanda más lenta de la franja
+     */
+    public record FranjaHoraria(
+        int hora,
+        int cantidadComandas,
+        BigDecimal promedioMinutos,
+        BigDecimal maximoMinutos,
+        int demoradas
+    ) {
+    }
+}
@@ -0,0 +1,21 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.Positive;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * DTO de entrada para definir la reposición de un insumo (HU-134).
+ *
+ * @param stockMinimo en la unidad del insumo; null para no sugerirlo en las órdenes de compra
+ * @param proveedorHabitualId null si no tiene proveedor habitual
+ */
+public record ReposicionInsumoRequest(
+
+    @Positive(message = "El stock mínimo debe ser mayor a cero")
+    BigDecimal stockMinimo,
+
+    UUID proveedorHabitualId
+) {
+}
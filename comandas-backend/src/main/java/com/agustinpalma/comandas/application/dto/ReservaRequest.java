@@ -0,0 +1,43 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.DecimalMin;
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Positive;
+import jakarta.validation.constraints.Size;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * DTO de entrada para tomar una reserva (HU-142).
+ *
+ * @param mesaId mesa reservada (null = se elige al sentar al cliente)
+ * @param senia  seña que deja el cliente en efectivo (null o cero = sin seña)
+ */
+public record ReservaRequest(
+
+    @NotBlank(message = "El nombre del cliente es obligatorio")
+    @Size(max = 80, message = "El nombre del cliente no puede superar los 80 caracteres")
+    String nombreCliente,
+
+    @Size(max = 30, message = "El teléfono no puede superar los 30 caracteres")
+    String telefono,
+
+    @NotNull(message = "La fecha y hora de la reserva es obligatoria")
+    LocalDateTime fechaHora,
+
+    @NotNull(message = "La cantidad de personas es obligatoria")
+    @Positive(message = "La reserva tiene que ser para al menos una persona")
+    Integer personas,
+
+    UUID mesaId,
+
+    @Size(max = 200, message = "Las observaciones no pueden superar los 200 caracteres")
+    String observaciones,
+
+    @DecimalMin(value = "0", message = "La seña no puede ser negativa")
+    BigDecimal senia
+) {
+}
@@ -0,0 +1,47 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoReserva;
+import com.agustinpalma.comandas.domain.model.Reserva;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Reserva de mesa con su seña (HU-142).
+ *
+ * @param numeroMesa número de la mesa asignada (null si todavía no se eligió)
+ * @param pedidoId   pedido en el que se descontó la seña (null si el cliente no llegó)
+ */
+public record ReservaResponse(
+    UUID id,
+    String nombreCliente,
+    String telefono,
+    LocalDateTime fechaHora,
+    int personas,
+    String observaciones,
+    UUID mesaId,
+    Integer numeroMesa,
+    EstadoReserva estado,
+    BigDecimal montoSenia,
+    LocalDateTime fechaSenia,
+    UUID pedidoId
+) {
+
+    public static ReservaResponse fromDomain(Reserva reserva, Integer numeroMesa) {
+        return new ReservaResponse(
+            reserva.getId().getValue(),
+            reserva.getNombreCliente(),
+            reserva.getTelefono(),
+            reserva.getFechaHora(),
+            reserva.getPersonas(),
+            reserva.getObservaciones(),
+            reserva.getMesaId() != null ? reserva.getMesaId().getValue() : null,
+            numeroMesa,
+            reserva.getEstado(),
+            reserva.getMontoSenia(),
+            reserva.getFechaSenia(),
+            reserva.getPedidoId() != null ? reserva.getPedidoId().getValue() : null
+        );
+    }
+}
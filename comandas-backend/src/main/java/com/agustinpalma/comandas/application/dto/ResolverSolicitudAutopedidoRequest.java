@@ -0,0 +1,18 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.Size;
+
+/**
+ * DTO de entrada para confirmar o rechazar un pedido del QR (HU-126).
+ *
+ * @param mozoId mozo que lo resuelve; al confirmar en una mesa libre queda a cargo del pedido
+ * @param motivo obligatorio solo al rechazar; se le muestra al cliente
+ */
+public record ResolverSolicitudAutopedidoRequest(
+
+    String mozoId,
+
+    @Size(max = 140, message = "El motivo no puede superar los 140 caracteres")
+    String motivo
+) {
+}
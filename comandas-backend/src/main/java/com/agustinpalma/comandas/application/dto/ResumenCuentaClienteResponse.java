@@ -0,0 +1,25 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.util.List;
+
+/**
+ * Resumen mensual de la cuenta corriente de un cliente (HU-104).
+ * Es la base del archivo que se exporta para reclamar la deuda.
+ *
+ * saldoFinal = saldoAnterior + totalCargos - totalPagos
+ *
+ * @param periodo mes del resumen en formato YYYY-MM
+ * @param saldoAnterior deuda arrastrada de meses anteriores
+ * @param movimientos movimientos del mes en orden cronológico
+ */
+public record ResumenCuentaClienteResponse(
+    ClienteResponse cliente,
+    String periodo,
+    BigDecimal saldoAnterior,
+    BigDecimal totalCargos,
+    BigDecimal totalPagos,
+    BigDecimal saldoFinal,
+    List<MovimientoCuentaCorrienteResponse> movimientos
+) {
+}
@@ -0,0 +1,21 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.util.UUID;
+
+/**
+ * Saldo de la cuenta corriente de un proveedor (HU-131).
+ *
+ * @param saldo               deuda total: Σ saldos de las facturas impagas
+ * @param saldoVencido        parte de la deuda ya vencida
+ * @param proximoVencimiento  vencimiento de la factura impaga más antigua; null si no se le debe nada
+ */
+public record SaldoProveedorResponse(
+    ProveedorResponse proveedor,
+    int facturasImpagas,
+    BigDecimal saldo,
+    BigDecimal saldoVencido,
+    LocalDate proximoVencimiento
+) {
+}
@@ -0,0 +1,24 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoTurno;
+
+import java.math.BigDecimal;
+
+/**
+ * DTO de respuesta con el saldo teórico del turno abierto (HU-123).
+ *
+ * Es el mismo cálculo que congela el cierre, para que el asistente de
+ * conteo compare lo contado antes de confirmar.
+ *
+ * @param efectivoEsperado montoInicial + ventasEfectivo + ingresos − egresos
+ */
+public record SaldoTeoricoTurnoResponse(
+    String turnoId,
+    TipoTurno tipo,
+    BigDecimal montoInicial,
+    BigDecimal ventasEfectivo,
+    BigDecimal totalIngresos,
+    BigDecimal totalEgresos,
+    BigDecimal efectivoEsperado
+) {
+}
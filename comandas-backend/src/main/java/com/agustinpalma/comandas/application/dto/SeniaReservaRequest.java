@@ -0,0 +1,17 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Positive;
+
+import java.math.BigDecimal;
+
+/**
+ * DTO de entrada para cobrar la seña de una reserva ya tomada (HU-142).
+ */
+public record SeniaReservaRequest(
+
+    @NotNull(message = "El monto de la seña es obligatorio")
+    @Positive(message = "La seña tiene que ser mayor a cero")
+    BigDecimal monto
+) {
+}
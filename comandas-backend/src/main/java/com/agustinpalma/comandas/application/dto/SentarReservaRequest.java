@@ -0,0 +1,15 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.NotNull;
+
+import java.util.UUID;
+
+/**
+ * DTO de entrada para sentar una reserva en una mesa abierta (HU-142).
+ */
+public record SentarReservaRequest(
+
+    @NotNull(message = "La mesa es obligatoria")
+    UUID mesaId
+) {
+}
@@ -0,0 +1,30 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.Min;
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Size;
+
+/**
+ * DTO de entrada para configurar la serie de comprobantes de una caja (HU-166).
+ *
+ * @param cajaId        caja de la serie (null = caja principal)
+ * @param prefijo       de 1 a 8 letras o números
+ * @param proximoNumero número que sale en el próximo cierre
+ * @param reinicioAnual si la cuenta vuelve a 1 con cada año (el año va en el número)
+ */
+public record SerieComprobantesRequest(
+
+    String cajaId,
+
+    @NotBlank(message = "El prefijo de la serie es obligatorio")
+    @Size(max = 8, message = "El prefijo de la serie no puede superar los 8 caracteres")
+    String prefijo,
+
+    @NotNull(message = "El próximo número es obligatorio")
+    @Min(value = 1, message = "El próximo número tiene que ser 1 o más")
+    Long proximoNumero,
+
+    boolean reinicioAnual
+) {
+}
@@ -0,0 +1,34 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.SerieComprobantes;
+
+import java.time.LocalDate;
+import java.util.UUID;
+
+/**
+ * DTO de salida con la serie de comprobantes de una caja (HU-166).
+ *
+ * @param cajaId             caja de la serie (null = caja principal)
+ * @param caja               nombre de la caja
+ * @param proximoComprobante número que sale en el próximo cierre, ya formateado
+ */
+public record SerieComprobantesResponse(
+    UUID cajaId,
+    String caja,
+    String prefijo,
+    long proximoNumero,
+    boolean reinicioAnual,
+    String proximoComprobante
+) {
+
+    public static SerieComprobantesResponse fromDomain(SerieComprobantes serie, String caja, LocalDate hoy) {
+        return new SerieComprobantesResponse(
+            serie.getCajaId() != null ? serie.getCajaId().getValue() : null,
+            caja,
+            serie.getPrefijo(),
+            serie.getProximoNumero(),
+            serie.isReinicioAnual(),
+            serie.proximoComprobante(hoy)
+        );
+    }
+}
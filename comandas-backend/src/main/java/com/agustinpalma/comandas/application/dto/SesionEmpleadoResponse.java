@@ -0,0 +1,27 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+
+import java.time.LocalDateTime;
+import java.util.Set;
+import java.util.UUID;
+
+/**
+ * DTO de salida con el empleado identificado en la terminal y lo que puede hacer (HU-169).
+ *
+ * @param permisos acciones habilitadas (todas si la matriz está inactiva)
+ * @param matrizActiva si el local restringe las acciones con la matriz
+ * @param token token de la sesión, que la terminal manda en cada request
+ * @param venceEn cuándo vence la sesión y hay que volver a poner el PIN
+ */
+public record SesionEmpleadoResponse(
+    UUID empleadoId,
+    String nombre,
+    RolPersonal rol,
+    Set<Permiso> permisos,
+    boolean matrizActiva,
+    String token,
+    LocalDateTime venceEn
+) {
+}
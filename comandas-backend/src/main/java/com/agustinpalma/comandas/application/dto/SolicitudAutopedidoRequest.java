@@ -0,0 +1,38 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.Valid;
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.NotEmpty;
+import jakarta.validation.constraints.Positive;
+import jakarta.validation.constraints.Size;
+
+import java.util.List;
+
+/**
+ * DTO de entrada del pedido que arma el cliente desde la carta del QR (HU-126).
+ *
+ * @param nombreCliente opcional; ayuda al mozo a saber a quién llevarle qué
+ */
+public record SolicitudAutopedidoRequest(
+
+    @Size(max = 40, message = "El nombre no puede superar los 40 caracteres")
+    String nombreCliente,
+
+    @NotEmpty(message = "El pedido debe tener al menos un producto")
+    @Valid
+    List<ItemSolicitudRequest> items
+) {
+
+    public record ItemSolicitudRequest(
+
+        @NotBlank(message = "El producto es obligatorio")
+        String productoId,
+
+        @Positive(message = "La cantidad debe ser mayor a cero")
+        int cantidad,
+
+        @Size(max = 140, message = "La aclaración no puede superar los 140 caracteres")
+        String observacion
+    ) {
+    }
+}
@@ -0,0 +1,65 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoSolicitudAutopedido;
+import com.agustinpalma.comandas.domain.model.SolicitudAutopedido;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Pedido del QR tal como lo ven el mozo y el cliente (HU-126).
+ *
+ * También es el payload de los eventos en tiempo real que avisan a las
+ * terminales que llegó un pedido o que otro mozo ya lo resolvió.
+ *
+ * @param total según los precios que vio el cliente en la carta
+ */
+public record SolicitudAutopedidoResponse(
+    UUID id,
+    UUID mesaId,
+    int numeroMesa,
+    String nombreCliente,
+    LocalDateTime creadaEn,
+    EstadoSolicitudAutopedido estado,
+    String motivoRechazo,
+    BigDecimal total,
+    List<ItemSolicitudResponse> items
+) {
+
+    /** Evento que avisa a las terminales de un pedido nuevo esperando al mozo */
+    public static final String EVENTO_NUEVA = "autopedido-nuevo";
+    /** Evento que avisa que un pedido ya fue confirmado o rechazado */
+    public static final String EVENTO_RESUELTA = "autopedido-resuelto";
+
+    public record ItemSolicitudResponse(
+        UUID productoId,
+        String nombreProducto,
+        BigDecimal precioUnitario,
+        int cantidad,
+        String observacion
+    ) {
+    }
+
+    public static SolicitudAutopedidoResponse fromDomain(SolicitudAutopedido solicitud) {
+        return new SolicitudAutopedidoResponse(
+            solicitud.getId().getValue(),
+            solicitud.getMesaId().getValue(),
+            solicitud.getNumeroMesa(),
+            solicitud.getNombreCliente(),
+            solicitud.getCreadaEn(),
+            solicitud.getEstado(),
+            solicitud.getMotivoRechazo(),
+            solicitud.calcularTotal(),
+            solicitud.getItems().stream()
+                .map(item -> new ItemSolicitudResponse(
+                    item.getProductoId().getValue(),
+                    item.getNombreProducto(),
+                    item.getPrecioUnitario(),
+                    item.getCantidad(),
+                    item.getObservacion()))
+                .toList()
+        );
+    }
+}
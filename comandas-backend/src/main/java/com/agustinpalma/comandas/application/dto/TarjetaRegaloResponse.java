@@ -0,0 +1,44 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoTarjetaRegalo;
+import com.agustinpalma.comandas.domain.model.TarjetaRegalo;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Tarjeta de regalo con su saldo actual (HU-144).
+ *
+ * @param estado estado al día de la consulta
+ * @param usos   cantidad de pagos hechos con la tarjeta
+ */
+public record TarjetaRegaloResponse(
+    UUID id,
+    String codigo,
+    BigDecimal montoInicial,
+    BigDecimal saldo,
+    LocalDateTime fechaEmision,
+    LocalDate vencimiento,
+    String comprador,
+    String destinatario,
+    EstadoTarjetaRegalo estado,
+    int usos
+) {
+
+    public static TarjetaRegaloResponse fromDomain(TarjetaRegalo tarjeta, LocalDate hoy) {
+        return new TarjetaRegaloResponse(
+            tarjeta.getId().getValue(),
+            tarjeta.getCodigo(),
+            tarjeta.getMontoInicial(),
+            tarjeta.getSaldo(),
+            tarjeta.getFechaEmision(),
+            tarjeta.getVencimiento(),
+            tarjeta.getComprador(),
+            tarjeta.getDestinatario(),
+            tarjeta.getEstado(hoy),
+            tarjeta.getConsumos().size()
+        );
+    }
+}
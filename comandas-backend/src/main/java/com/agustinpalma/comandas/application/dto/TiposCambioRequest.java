@@ -0,0 +1,23 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.Moneda;
+import jakarta.validation.constraints.NotNull;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.util.Map;
+
+/**
+ * DTO de entrada para cargar los tipos de cambio de un día (HU-151).
+ *
+ * @param fecha        día operativo al que aplican (null = hoy)
+ * @param cotizaciones pesos por unidad de cada moneda; una moneda sin enviar no se acepta ese día
+ */
+public record TiposCambioRequest(
+
+    LocalDate fecha,
+
+    @NotNull(message = "Las cotizaciones son obligatorias")
+    Map<Moneda, BigDecimal> cotizaciones
+) {
+}
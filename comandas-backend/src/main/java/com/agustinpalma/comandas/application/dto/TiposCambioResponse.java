@@ -0,0 +1,21 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.Moneda;
+import com.agustinpalma.comandas.domain.model.TiposCambio;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.util.Map;
+
+/**
+ * DTO de salida con los tipos de cambio de un día (HU-151).
+ * Solo trae las monedas cotizadas.
+ */
+public record TiposCambioResponse(
+    LocalDate fecha,
+    Map<Moneda, BigDecimal> cotizaciones
+) {
+    public static TiposCambioResponse fromDomain(TiposCambio tiposCambio) {
+        return new TiposCambioResponse(tiposCambio.getFecha(), tiposCambio.getCotizaciones());
+    }
+}
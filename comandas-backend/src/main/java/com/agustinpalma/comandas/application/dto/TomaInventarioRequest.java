@@ -0,0 +1,44 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.Valid;
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.NotEmpty;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.PositiveOrZero;
+import jakarta.validation.constraints.Size;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * DTO de entrada con el conteo físico de una toma de inventario (HU-133).
+ * Se usa igual para previsualizar las diferencias y para confirmar la toma.
+ *
+ * @param responsable quien hizo el conteo
+ * @param conteos solo los insumos contados; los demás no se tocan
+ */
+public record TomaInventarioRequest(
+
+    @NotBlank(message = "El responsable es obligatorio")
+    @Size(max = 60, message = "El responsable no puede superar los 60 caracteres")
+    String responsable,
+
+    @NotEmpty(message = "Hay que contar al menos un insumo")
+    @Valid
+    List<ConteoRequest> conteos
+) {
+
+    /**
+     * @param cantidad lo contado, en la unidad del insumo (kilos, litros o unidades)
+     */
+    public record ConteoRequest(
+        @NotNull(message = "El insumo es obligatorio")
+        UUID insumoId,
+
+        @NotNull(message = "La cantidad contada es obligatoria")
+        @PositiveOrZero(message = "La cantidad contada no puede ser negativa")
+        BigDecimal cantidad
+    ) {
+    }
+}
@@ -0,0 +1,89 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.ConteoInsumo;
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import com.agustinpalma.comandas.domain.model.TomaInventario;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.Comparator;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * DTO de salida con el reporte de diferencias de una toma de inventario (HU-133).
+ *
+ * Los renglones van de la mayor pérdida a la mayor ganancia, para ver primero
+ * dónde se pierde plata.
+ *
+ * @param id null en la previsualización (la toma todavía no se guardó)
+ * @param faltanteValorizado valor de lo que faltó, en positivo
+ * @param diferenciaValorizada saldo neto: negativo si en total faltó mercadería
+ */
+public record TomaInventarioResponse(
+    UUID id,
+    LocalDateTime fecha,
+    String responsable,
+    List<RenglonInventario> insumos,
+    long insumosConDiferencia,
+    BigDecimal faltanteValorizado,
+    BigDecimal sobranteValorizado,
+    BigDecimal diferenciaValorizada
+) {
+
+    /**
+     * @param diferencia contado menos teórico, en la unidad del insumo
+     */
+    public record RenglonInventario(
+        UUID insumoId,
+        String nombre,
+        UnidadInsumo unidad,
+        BigDecimal stockTeorico,
+        BigDecimal stockContado,
+        BigDecimal diferencia,
+        BigDecimal costoUnitario,
+        BigDecimal diferenciaValorizada
+    ) {
+
+        static RenglonInventario fromDomain(ConteoInsumo conteo) {
+            return new RenglonInventario(
+                conteo.getInsumoId().getValue(),
+                conteo.getNombreInsumo(),
+                conteo.getUnidad(),
+                conteo.getStockTeorico(),
+                conteo.getStockContado(),
+                conteo.getDiferencia(),
+                conteo.getCostoUnitario(),
+                conteo.getDiferenciaValorizada()
+            );
+        }
+    }
+
+    public static TomaInventarioResponse fromDomain(TomaInventario toma) {
+        return armar(toma, toma.getId().getValue());
+    }
+
+    /**
+     * Reporte de una toma armada pero no guardada.
+     */
+    public static TomaInventarioResponse previsualizacion(TomaInventario toma) {
+        return armar(toma, null);
+    }
+
+    private static TomaInventarioResponse armar(TomaInventario toma, UUID id) {
+        return new TomaInventarioResponse(
+            id,
+            toma.getFecha(),
+            toma.getResponsable(),
+            toma.getConteos().stream()
+                .sorted(Comparator.comparing(ConteoInsumo::getDiferenciaValorizada)
+                    .thenComparing(ConteoInsumo::getNombreInsumo, String.CASE_INSENSITIVE_ORDER))
+                .map(RenglonInventario::fromDomain)
+                .toList(),
+            toma.getConteos().stream().filter(ConteoInsumo::tieneDiferencia).count(),
+            toma.getFaltanteValorizado(),
+            toma.getSobranteValorizado(),
+            toma.getDiferenciaValorizada()
+        );
+    }
+}
@@ -0,0 +1,16 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.PositiveOrZero;
+
+import java.math.BigDecimal;
+
+/**
+ * DTO de entrada para fijar el tope mensual de consumo interno de un empleado (HU-132).
+ *
+ * @param topeMensual beneficio mensual acordado; null = sin tope
+ */
+public record TopeConsumoRequest(
+    @PositiveOrZero(message = "El tope de consumo no puede ser negativo")
+    BigDecimal topeMensual
+) {
+}
@@ -0,0 +1,74 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoTurno;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoTurno;
+import com.agustinpalma.comandas.domain.model.ConteoDenominacion;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+
+/**
+ * DTO de respuesta de un turno de caja (HU-106).
+ *
+ * Los campos del arqueo son null mientras el turno está ABIERTO.
+ *
+ * @param efectivoEsperado  montoInicial + ventasEfectivo + ingresos − egresos
+ * @param efectivoDeclarado efectivo contado por el cajero al cerrar
+ * @param diferencia        declarado − esperado (negativo = faltante)
+ * @param denominaciones    HU-123: conteo del cajón al cerrar (vacío si se declaró solo el total)
+ * @param responsableId     HU-129: cajero que respondió por el arqueo (null si no se indicó)
+ * @param observaciones     HU-129: explicación de la diferencia cargada al cerrar
+ */
+public record TurnoCajaResponse(
+    String id,
+    String jornadaId,
+    TipoTurno tipo,
+    EstadoTurno estado,
+    LocalDateTime fechaApertura,
+    LocalDateTime fechaCierre,
+    BigDecimal montoInicial,
+    BigDecimal totalVentas,
+    BigDecimal ventasEfectivo,
+    BigDecimal totalIngresos,
+    BigDecimal totalEgresos,
+    BigDecimal efectivoEsperado,
+    BigDecimal efectivoDeclarado,
+    BigDecimal diferencia,
+    int pedidosCerradosCount,
+    List<DenominacionContada> denominaciones,
+    String responsableId,
+    String observaciones
+) {
+
+    public record DenominacionContada(BigDecimal denominacion, int cantidad, BigDecimal subtotal) {
+
+        static DenominacionContada fromDomain(ConteoDenominacion conteo) {
+            return new DenominacionContada(conteo.getDenominacion(), conteo.getCantidad(), conteo.subtotal());
+        }
+    }
+
+    public static TurnoCajaResponse fromDomain(TurnoCaja turno) {
+        return new TurnoCajaResponse(
+            turno.getId().getValue().toString(),
+            turno.getJornadaId().getValue().toString(),
+            turno.getTipo(),
+            turno.getEstado(),
+            turno.getFechaApertura(),
+            turno.getFechaCierre(),
+            turno.getMontoInicial(),
+            turno.getTotalVentas(),
+            turno.getVentasEfectivo(),
+            turno.getTotalIngresos(),
+            turno.getTotalEgresos(),
+            turno.getEfectivoEsperado(),
+            turno.getEfectivoDeclarado(),
+            turno.getDiferencia(),
+            turno.getPedidosCerradosCount(),
+            turno.getDenominaciones().stream().map(DenominacionContada::fromDomain).toList(),
+            turno.getResponsableId() != null ? turno.getResponsableId().getValue().toString() : null,
+            turno.getObservaciones()
+        );
+    }
+}
@@ -0,0 +1,36 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.math.BigDecimal;
+import java.time.YearMonth;
+import java.util.List;
+
+/**
+ * Variación promedio de los precios del menú mes a mes (HU-146).
+ *
+ * @param meses              un renglón por mes del rango, aunque no haya habido cambios
+ * @param variacionAcumulada variación compuesta de todo el rango, en porcentaje
+ */
+public record VariacionPreciosMenuResponse(
+    YearMonth desde,
+    YearMonth hasta,
+    List<MesResumen> meses,
+    BigDecimal variacionAcumulada
+) {
+
+    /**
+     * @param productosEnMenu              productos activos hoy más los que cambiaron de precio en el mes
+     * @param productosModificados         productos que cambiaron de precio en el mes
+     * @param cambios                      cantidad de cambios registrados (un producto puede cambiar varias veces)
+     * @param variacionPromedio            promedio sobre todo el menú: los que no cambiaron cuentan como 0%
+     * @param variacionPromedioModificados promedio solo entre los que cambiaron (cero si no cambió ninguno)
+     */
+    public record MesResumen(
+        YearMonth periodo,
+        int productosEnMenu,
+        int productosModificados,
+        int cambios,
+        BigDecimal variacionPromedio,
+        BigDecimal variacionPromedioModificados
+    ) {
+    }
+}
@@ -1,9 +1,9 @@
 package com.agustinpalma.comandas.application.dto;
 
 import jakarta.validation.constraints.DecimalMin;
-import jakarta.validation.constraints.NotBlank;
 import jakarta.validation.constraints.NotNull;
 import jakarta.validation.constraints.Min;
+import jakarta.validation.constraints.Size;
 import java.math.BigDecimal;
 
 /**
@@ -14,19 +14,21 @@ import java.math.BigDecimal;
  * 
  * El productoBaseId viene del path de la URL, no del body.
  * El grupoVarianteId se determina automáticamente en el use case.
+ *
+ * HU-109: Para variantes por tamaño alcanza con etiqueta + precio:
+ * - Sin nombre, se arma como "{nombre del base} {etiqueta}".
+ * - Sin cantidadDiscosCarne, la variante va a la siguiente posición del grupo.
  */
 public record VarianteProductoRequest(
 
-    @NotBlank(message = "El nombre de la variante es obligatorio")
-    String nombre,
+    String nombre,  // Opcional si hay etiqueta (HU-109)
 
     @NotNull(message = "El precio es obligatorio")
     @DecimalMin(value = "0.0", inclusive = true, message = "El precio no puede ser negativo")
     BigDecimal precio,
 
-    @NotNull(message = "La cantidad de discos es obligatoria para definir la jerarquía de la variante")
     @Min(value = 1, message = "La cantidad de discos debe ser al menos 1")
-    Integer cantidadDiscosCarne,
+    Integer cantidadDiscosCarne,  // Opcional (HU-109): null = siguiente posición del grupo
 
     Boolean activo,  // Opcional, default true en creación
 
@@ -38,6 +40,27 @@ public record VarianteProductoRequest(
 
     Boolean requiereConfiguracion,  // Opcional, hereda del base si es null
 
-    Boolean controlaStock  // Opcional, default false en creación
+    Boolean controlaStock,  // Opcional, default false en creación
+
+    @Size(max = 30, message = "La etiqueta de la variante no puede superar los 30 caracteres")
+    String etiqueta  // HU-109: Rótulo corto en el POS (ej: "Grande", "1.5 L"). Opcional
 ) {
+
+    /**
+     * Constructor de retrocompatibilidad (sin etiqueta).
+     */
+    public VarianteProductoRequest(
+        String nombre,
+        BigDecimal precio,
+        Integer cantidadDiscosCarne,
+        Boolean activo,
+        String colorHex,
+        String categoriaId,
+        Boolean permiteExtras,
+        Boolean requiereConfiguracion,
+        Boolean controlaStock
+    ) {
+        this(nombre, precio, cantidadDiscosCarne, activo, colorHex, categoriaId,
+            permiteExtras, requiereConfiguracion, controlaStock, null);
+    }
 }
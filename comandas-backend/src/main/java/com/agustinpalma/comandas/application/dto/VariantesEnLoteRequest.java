@@ -0,0 +1,26 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.Valid;
+import jakarta.validation.constraints.NotEmpty;
+import jakarta.validation.constraints.Size;
+import java.util.List;
+
+/**
+ * DTO de entrada para dar de alta varios tamaños de un producto de una sola vez.
+ * HU-109: Variantes de producto (tamaños).
+ *
+ * Ejemplo: base "Pizza Muzzarella" con etiquetaBase "Chica" y variantes
+ * [{etiqueta: "Mediana", precio: 6000}, {etiqueta: "Grande", precio: 8000}].
+ * El base pasa a llamarse "Pizza Muzzarella Chica" para que la comanda y los
+ * reportes distingan cada tamaño.
+ */
+public record VariantesEnLoteRequest(
+
+    @Size(max = 30, message = "La etiqueta del producto base no puede superar los 30 caracteres")
+    String etiquetaBase,  // Opcional: rótulo del producto base dentro del grupo
+
+    @NotEmpty(message = "Debe indicar al menos una variante")
+    @Valid
+    List<VarianteProductoRequest> variantes
+) {
+}
@@ -0,0 +1,12 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.util.UUID;
+
+/**
+ * Request body para vincular un cliente de la ficha a un pedido.
+ *
+ * HU-168: null desvincula al cliente que tuviera el pedido.
+ */
+public record VincularClienteRequestBody(
+    UUID clienteId
+) {}
@@ -0,0 +1,16 @@
+package com.agustinpalma.comandas.application.dto;
+
+import java.time.LocalDate;
+import java.util.List;
+
+/**
+ * Cómo quedarían los precios con el aumento, sin aplicarlo (HU-145).
+ *
+ * @param cambios         productos alcanzados con su precio actual y el nuevo
+ * @param proximoDiaHabil fecha sugerida para programar la vigencia
+ */
+public record VistaPreviaAumentoPreciosResponse(
+    List<CambioPrecioResponse> cambios,
+    LocalDate proximoDiaHabil
+) {
+}
@@ -0,0 +1,35 @@
+package com.agustinpalma.comandas.application.dto;
+
+import jakarta.validation.constraints.DecimalMin;
+import jakarta.validation.constraints.NotBlank;
+import jakarta.validation.constraints.NotNull;
+import jakarta.validation.constraints.Size;
+
+import java.math.BigDecimal;
+import java.util.List;
+
+/**
+ * DTO de entrada para dar de alta o editar una zona de reparto (HU-139).
+ *
+ * @param montoMinimo mínimo de compra en productos (null = sin mínimo)
+ * @param barrios     nombres que se buscan en la dirección para elegir la zona sola
+ * @param activa      false deja de ofrecerla para pedidos nuevos (null = activa)
+ */
+public record ZonaDeliveryRequest(
+
+    @NotBlank(message = "El nombre de la zona es obligatorio")
+    @Size(max = 60, message = "El nombre de la zona no puede superar los 60 caracteres")
+    String nombre,
+
+    @NotNull(message = "El costo de envío es obligatorio")
+    @DecimalMin(value = "0", message = "El costo de envío no puede ser negativo")
+    BigDecimal costoEnvio,
+
+    @DecimalMin(value = "0", message = "El mínimo de compra no puede ser negativo")
+    BigDecimal montoMinimo,
+
+    List<String> barrios,
+
+    Boolean activa
+) {
+}
@@ -0,0 +1,31 @@
+package com.agustinpalma.comandas.application.dto;
+
+import com.agustinpalma.comandas.domain.model.ZonaDelivery;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * DTO de salida con los datos de una zona de reparto (HU-139).
+ */
+public record ZonaDeliveryResponse(
+    UUID id,
+    String nombre,
+    BigDecimal costoEnvio,
+    BigDecimal montoMinimo,
+    List<String> barrios,
+    boolean activa
+) {
+
+    public static ZonaDeliveryResponse fromDomain(ZonaDelivery zona) {
+        return new ZonaDeliveryResponse(
+            zona.getId().getValue(),
+            zona.getNombre(),
+            zona.getCostoEnvio(),
+            zona.getMontoMinimo(),
+            zona.getBarrios(),
+            zona.isActiva()
+        );
+    }
+}
@@ -4,6 +4,7 @@ import com.agustinpalma.comandas.application.dto.ProductoVendidoReporte;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 
 import java.time.LocalDate;
+import java.time.LocalDateTime;
 import java.util.List;
 
 /**
@@ -30,4 +31,42 @@ public interface AnalyticsRepositoryPort {
      *         ordenada por total recaudado descendente
      */
     List<ProductoVendidoReporte> obtenerVentasPorProducto(LocalDate fecha, LocalId localId);
+
+    /**
+     * HU-103: Igual que {@link #obtenerVentasPorProducto}, pero cada combo vendido se
+     * reemplaza por los productos elegidos en sus componentes.
+     *
+     * El importe del combo se prorratea entre sus componentes en proporción a
+     * su precio de lista al momento de la venta (en partes iguales si todos valen cero).
+     *
+     * @param fecha   fecha operativa del reporte (YYYY-MM-DD)
+     * @param localId tenant del local
+     * @return lista de productos con cantidad vendida y total recaudado,
+     *         ordenada por total recaudado descendente
+     */
+    List<ProductoVendidoReporte> obtenerVentasPorProductoDesglosandoCombos(LocalDate fecha, LocalId localId);
+
+    /**
+     * HU-114: Igual que {@link #obtenerVentasPorProducto}, pero sobre un rango
+     * horario arbitrario (ej: la jornada operativa de 06:00 a 06:00).
+     *
+     * @param inicio  inicio del rango (inclusive)
+     * @param fin     fin del rango (exclusive)
+     * @param localId tenant del local
+     * @return lista de productos con cantidad vendida y total recaudado,
+     *         ordenada por total recaudado descendente
+     */
+    List<ProductoVendidoReporte> obtenerVentasPorProductoEntre(LocalDateTime inicio, LocalDateTime fin, LocalId localId);
+
+    /**
+     * HU-139: Costos de envío cobrados en pedidos CERRADOS del rango, agrupados
+     * por zona. Los reportes de productos no incluyen estos ítems.
+     *
+     * @param inicio  inicio del rango (inclusive)
+     * @param fin     fin del rango (exclusive)
+     * @param localId tenant del local
+     * @return una fila por zona ("Envío {zona}") con la cantidad de envíos y el total cobrado,
+     *         ordenada por total descendente
+     */
+    List<ProductoVendidoReporte> obtenerEnviosPorZonaEntre(LocalDateTime inicio, LocalDateTime fin, LocalId localId);
 }
@@ -0,0 +1,17 @@
+package com.agustinpalma.comandas.application.ports.output;
+
+/**
+ * Puerto de salida para apagar el backend de forma ordenada (HU-155).
+ *
+ * Ordenada quiere decir: terminar los requests en curso, cerrar las
+ * conexiones a la base y recién ahí salir, así un corte de luz no deja el
+ * archivo de la base a medio escribir.
+ */
+public interface ApagadoAplicacionPort {
+
+    /**
+     * Programa el apagado y vuelve enseguida, para que quien lo pidió reciba
+     * la respuesta antes de que el servidor deje de atender.
+     */
+    void apagar();
+}
@@ -0,0 +1,20 @@
+package com.agustinpalma.comandas.application.ports.output;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+
+import java.util.Optional;
+
+/**
+ * Puerto de salida para saber desde qué caja se hace la operación actual.
+ *
+ * HU-159: Cada terminal que cobra se registra como caja y manda su id en
+ * cada request. Una terminal sin caja registrada opera sobre la caja
+ * principal (la de la jornada).
+ */
+public interface CajaContextProvider {
+
+    /**
+     * @return la caja de la terminal que hizo el request, vacío si es la caja principal
+     */
+    Optional<CajaId> getCajaActual();
+}
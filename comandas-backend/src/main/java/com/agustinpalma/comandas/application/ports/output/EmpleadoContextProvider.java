@@ -0,0 +1,21 @@
+package com.agustinpalma.comandas.application.ports.output;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+
+import java.util.Optional;
+
+/**
+ * Puerto de salida para saber qué empleado está operando la terminal.
+ *
+ * HU-169: El empleado se identifica con su PIN en la terminal, que recibe
+ * un token de sesión y lo manda en cada request para que el backend valide
+ * los permisos de las acciones sensibles.
+ */
+public interface EmpleadoContextProvider {
+
+    /**
+     * @return el empleado de la sesión vigente de la terminal, vacío si nadie se identificó
+     *         o la sesión venció
+     */
+    Optional<MozoId> getEmpleadoActual();
+}
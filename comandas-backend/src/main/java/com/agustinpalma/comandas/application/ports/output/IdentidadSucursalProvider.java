@@ -0,0 +1,24 @@
+package com.agustinpalma.comandas.application.ports.output;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.SucursalId;
+
+/**
+ * Puerto de salida para conocer la identidad de esta instalación
+ * dentro de la red de sucursales (HU-107).
+ *
+ * A diferencia del {@link LocalContextProvider}, que resuelve el tenant de
+ * los datos, la sucursal identifica la instalación física: dos locales del
+ * mismo dueño tienen sucursales distintas aunque compartan configuración.
+ */
+public interface IdentidadSucursalProvider {
+
+    /**
+     * @return identidad estable de esta instalación
+     */
+    SucursalId getSucursalId();
+
+    /**
+     * @return nombre visible en el reporte comparativo (ej: "Centro")
+     */
+    String getNombreSucursal();
+}
@@ -0,0 +1,28 @@
+package com.agustinpalma.comandas.application.ports.output;
+
+/**
+ * Puerto de salida para el mantenimiento periódico de la base (HU-163).
+ *
+ * Con los meses la base se fragmenta y las estadísticas del planificador
+ * quedan viejas: las consultas del mapa de mesas y de los reportes se ponen
+ * lentas. El mantenimiento compacta el archivo, recalcula las estadísticas y
+ * reconstruye los índices.
+ */
+public interface MantenimientoBasePort {
+
+    /**
+     * Corre el mantenimiento completo. Puede tardar minutos y bloquea la base
+     * mientras dura: solo se llama en la ventana fuera de horario.
+     *
+     * @return el motor y el tamaño de la base antes y después
+     */
+    Resultado optimizar();
+
+    /**
+     * @param motor        nombre del motor de base ("SQLite", "PostgreSQL")
+     * @param bytesAntes   tamaño de la base antes del mantenimiento
+     * @param bytesDespues tamaño de la base después del mantenimiento
+     */
+    record Resultado(String motor, long bytesAntes, long bytesDespues) {
+    }
+}
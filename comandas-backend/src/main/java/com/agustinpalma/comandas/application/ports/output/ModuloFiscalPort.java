@@ -0,0 +1,52 @@
+package com.agustinpalma.comandas.application.ports.output;
+
+import com.agustinpalma.comandas.domain.model.Cuit;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CondicionIva;
+import com.agustinpalma.comandas.domain.model.Factura;
+
+import java.time.LocalDate;
+import java.util.Optional;
+
+/**
+ * Puerto de salida hacia el módulo fiscal (HU-115).
+ *
+ * Agrupa las dos consultas que dependen del organismo fiscal:
+ * - Padrón de contribuyentes: razón social y condición frente al IVA de un CUIT
+ * - Autorización de comprobantes: CAE de una factura emitida
+ *
+ * Ambas pueden no estar disponibles (sin conexión o sin módulo configurado).
+ * En ese caso la implementación lanza {@link ModuloFiscalNoDisponibleException}
+ * y el caso de uso sigue con los datos que cargó el operador, dejando la
+ * factura pendiente de autorización.
+ */
+public interface ModuloFiscalPort {
+
+    /**
+     * @param cuit CUIT/CUIL ya validado (formato y dígito verificador)
+     * @return datos del padrón, vacío si el CUIT no está inscripto
+     * @throws ModuloFiscalNoDisponibleException si no se puede consultar el padrón
+     */
+    Optional<Contribuyente> consultarPadron(Cuit cuit);
+
+    /**
+     * Solicita el CAE de una factura. No modifica la factura: el caso de uso
+     * registra la autorización en el aggregate.
+     *
+     * @throws ModuloFiscalNoDisponibleException si no se puede autorizar ahora
+     * @throws IllegalStateException si el organismo rechaza el comprobante
+     */
+    Autorizacion autorizar(Factura factura);
+
+    record Contribuyente(Cuit cuit, String razonSocial, CondicionIva condicionIva) {}
+
+    record Autorizacion(String cae, LocalDate vencimiento) {}
+
+    /**
+     * El módulo fiscal no respondió o no está configurado en esta instalación.
+     */
+    class ModuloFiscalNoDisponibleException extends RuntimeException {
+        public ModuloFiscalNoDisponibleException(String message) {
+            super(message);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+package com.agustinpalma.comandas.application.ports.output;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+/**
+ * Puerto de salida para avisar a todas las terminales conectadas de un
+ * cambio que tienen que ver ya, sin esperar al próximo polling (HU-125).
+ *
+ * La entrega es "al mejor esfuerzo": una terminal desconectada se pone al
+ * día con su polling habitual al reconectarse.
+ */
+public interface NotificadorTiempoRealPort {
+
+    /**
+     * @param localId local cuyas terminales reciben el evento
+     * @param evento  nombre del evento (ej: "producto-disponibilidad")
+     * @param datos   payload serializable a JSON
+     */
+    void publicar(LocalId localId, String evento, Object datos);
+}
@@ -0,0 +1,49 @@
+package com.agustinpalma.comandas.application.ports.output;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ProveedorLinkPago;
+
+import java.math.BigDecimal;
+import java.util.Optional;
+
+/**
+ * Puerto de salida hacia las pasarelas de pago (HU-128).
+ *
+ * El caso de uso no confía en el contenido de los avisos que llegan por
+ * webhook: ante cada aviso (o cuando el operador pide verificar) le pregunta
+ * a la pasarela si hay un pago aprobado para la referencia del link.
+ *
+ * Si la pasarela elegida no está configurada o no responde, la
+ * implementación lanza {@link PasarelaPagoNoDisponibleException}.
+ */
+public interface PasarelaPagoPort {
+
+    /**
+     * @throws PasarelaPagoNoDisponibleException si no se puede generar el link ahora
+     */
+    LinkGenerado crearLink(ProveedorLinkPago proveedor, SolicitudLink solicitud);
+
+    /**
+     * @param referencia la misma que se mandó en {@link SolicitudLink#referencia()}
+     * @return el pago aprobado, vacío si el cliente todavía no pagó
+     * @throws PasarelaPagoNoDisponibleException si no se puede consultar ahora
+     */
+    Optional<PagoAprobado> buscarPagoAprobado(ProveedorLinkPago proveedor, String referencia);
+
+    /**
+     * @param referencia identifica el link en la pasarela (el id del link); vuelve en los avisos de pago
+     */
+    record SolicitudLink(String referencia, String descripcion, BigDecimal monto) {}
+
+    record LinkGenerado(String url) {}
+
+    record PagoAprobado(String idPago) {}
+
+    /**
+     * La pasarela no respondió o no está configurada en esta instalación.
+     */
+    class PasarelaPagoNoDisponibleException extends RuntimeException {
+        public PasarelaPagoNoDisponibleException(String message) {
+            super(message);
+        }
+    }
+}
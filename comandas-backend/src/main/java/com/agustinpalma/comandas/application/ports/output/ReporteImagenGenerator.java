@@ -0,0 +1,19 @@
+package com.agustinpalma.comandas.application.ports.output;
+
+import com.agustinpalma.comandas.application.dto.ReporteImagenData;
+
+/**
+ * Puerto de salida para dibujar un reporte como imagen PNG (HU-157).
+ *
+ * Igual que {@link ReportePdfGenerator}, abstrae la tecnología de
+ * renderizado de la orquestación del caso de uso.
+ */
+public interface ReporteImagenGenerator {
+
+    /**
+     * @param data  reporte ya resumido en secciones
+     * @param ancho ancho de la imagen; el alto sale del contenido
+     * @return bytes de la imagen PNG
+     */
+    byte[] generarPng(ReporteImagenData data, ReporteImagenData.Ancho ancho);
+}
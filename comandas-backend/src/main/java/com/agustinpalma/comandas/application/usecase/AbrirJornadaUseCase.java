@@ -7,7 +7,9 @@ import com.agustinpalma.comandas.domain.exception.TrialExpiredException;
 import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.JornadaCaja;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
 import com.agustinpalma.comandas.domain.repository.JornadaCajaRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
 import org.springframework.transaction.annotation.Transactional;
 
 import java.math.BigDecimal;
@@ -27,19 +29,24 @@ import java.util.Objects;
  * - El monto inicial debe ser ≥ 0
  *
  * La fecha operativa se calcula automáticamente según la hora de apertura
- * (regla de turno noche: antes de las 06:00 → día anterior).
+ * (regla de turno noche: antes de la hora de corte del local → día anterior;
+ * HU-153, 06:00 si no la configuró).
  */
 @Transactional
 public class AbrirJornadaUseCase {
 
     private final JornadaCajaRepository jornadaCajaRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
     private final Clock clock;
     private final LocalDate fechaExpiracion;
 
-    public AbrirJornadaUseCase(JornadaCajaRepository jornadaCajaRepository, Clock clock,
+    public AbrirJornadaUseCase(JornadaCajaRepository jornadaCajaRepository,
+                               JornadaComercialRepository jornadaComercialRepository, Clock clock,
                                LocalDate fechaExpiracion) {
         this.jornadaCajaRepository = Objects.requireNonNull(jornadaCajaRepository,
             "jornadaCajaRepository es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository,
+            "jornadaComercialRepository es obligatorio");
         this.clock = Objects.requireNonNull(clock, "clock es obligatorio");
         this.fechaExpiracion = Objects.requireNonNull(fechaExpiracion,
             "fechaExpiracion es obligatorio");
@@ -70,7 +77,9 @@ public class AbrirJornadaUseCase {
 
         // 2. Calcular fecha operativa para validar que no exista jornada previa
         LocalDateTime ahora = LocalDateTime.now(clock);
-        LocalDate fechaOperativa = JornadaCaja.calcularFechaOperativa(ahora);
+        JornadaComercial jornadaComercial = jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId));
+        LocalDate fechaOperativa = jornadaComercial.fechaOperativa(ahora);
 
         if (jornadaCajaRepository.existePorFechaOperativa(localId, fechaOperativa)) {
             throw new JornadaYaCerradaException(fechaOperativa);
@@ -79,7 +88,8 @@ public class AbrirJornadaUseCase {
         // 3. Crear jornada ABIERTA (la validación de monto ≥ 0 la hace el dominio)
         JornadaCajaId jornadaId = JornadaCajaId.generate();
 
-        JornadaCaja jornada = new JornadaCaja(jornadaId, localId, montoInicial, ahora);
+        JornadaCaja jornada = new JornadaCaja(jornadaId, localId, montoInicial, ahora,
+            jornadaComercial.getHoraCorte());
         jornadaCajaRepository.guardar(jornada);
 
         return new AbrirCajaResponse(
@@ -5,11 +5,18 @@ import com.agustinpalma.comandas.application.dto.AbrirMesaResponse;
 import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.ListaPrecios;
 import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.Mozo;
 import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Reserva;
+import com.agustinpalma.comandas.domain.repository.ListaPreciosRepository;
 import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.ReservaRepository;
 
 import java.time.LocalDateTime;
 import java.util.Objects;
@@ -27,12 +34,19 @@ import org.springframework.transaction.annotation.Transactional;
  * 3. La mesa debe pertenecer al local del usuario
  * 4. Una mesa solo puede tener un único pedido ABIERTO a la vez
  * 5. El pedido queda vinculado inmutablemente a MesaId y LocalId
+ * 6. HU-108: El pedido toma y congela la lista de precios de su canal
+ * 7. HU-111: El pedido registra el mozo a cargo (solo mozos activos) y los cubiertos
+ * 8. HU-142: Si la mesa está reservada para hoy, la reserva se sienta y su seña
+ *    se descuenta del total del pedido
  */
 @Transactional
 public class AbrirMesaUseCase {
 
     private final MesaRepository mesaRepository;
     private final PedidoRepository pedidoRepository;
+    private final ListaPreciosRepository listaPreciosRepository;
+    private final MozoRepository mozoRepository;
+    private final ReservaRepository reservaRepository;
 
     /**
      * Constructor con inyección de dependencias.
@@ -40,20 +54,32 @@ public class AbrirMesaUseCase {
      *
      * @param mesaRepository repositorio de mesas
      * @param pedidoRepository repositorio de pedidos
+     * @param listaPreciosRepository repositorio de listas de precios por canal (HU-108)
+     * @param mozoRepository repositorio de mozos (HU-111)
+     * @param reservaRepository repositorio de reservas (HU-142)
      */
-    public AbrirMesaUseCase(MesaRepository mesaRepository, PedidoRepository pedidoRepository) {
+    public AbrirMesaUseCase(
+            MesaRepository mesaRepository,
+            PedidoRepository pedidoRepository,
+            ListaPreciosRepository listaPreciosRepository,
+            MozoRepository mozoRepository,
+            ReservaRepository reservaRepository
+    ) {
         this.mesaRepository = Objects.requireNonNull(mesaRepository, "El mesaRepository es obligatorio");
         this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.listaPreciosRepository = Objects.requireNonNull(listaPreciosRepository, "El listaPreciosRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.reservaRepository = Objects.requireNonNull(reservaRepository, "El reservaRepository es obligatorio");
     }
 
     /**
      * Ejecuta el caso de uso: abrir una mesa y crear su pedido.
      *
      * @param localId identificador del local (tenant) del usuario autenticado
-     * @param request DTO con el ID de la mesa a abrir
+     * @param request DTO con el ID de la mesa a abrir y el canal de venta
      * @return DTO con la información de la mesa abierta y el pedido creado
      * @throws IllegalArgumentException si la validación falla
-     * @throws IllegalStateException si la mesa no se encuentra
+     * @throws IllegalStateException si la mesa no se encuentra o el mozo está inactivo
      */
     public AbrirMesaResponse ejecutar(LocalId localId, AbrirMesaRequest request) {
         Objects.requireNonNull(localId, "El localId es obligatorio");
@@ -92,11 +118,43 @@ public class AbrirMesaUseCase {
             LocalDateTime.now()
         );
 
+        // 6.1 HU-108: Congelar la lista del canal (sin lista → precio de catálogo)
+        ListaPrecios lista = listaPreciosRepository.buscarPorLocalYCanal(localId, request.canal())
+            .orElse(null);
+        nuevoPedido.asignarCanal(request.canal(), lista);
+
+        // 6.2 HU-111: Mozo a cargo y cubiertos
+        nuevoPedido.asignarAtencion(resolverMozo(localId, request.mozoId()), request.cubiertos());
+
+        // 6.3 HU-142: Sentar la reserva del día de la mesa (descuenta la seña)
+        Reserva reserva = reservaRepository.buscarPendientesPorMesa(mesaId, localId).stream()
+            .filter(r -> r.esParaLaMesa(mesaId, nuevoPedido.getFechaApertura().toLocalDate()))
+            .findFirst()
+            .orElse(null);
+        if (reserva != null) {
+            reserva.sentar(nuevoPedido);
+        }
+
         // 7. Persistir los cambios (orden: primero pedido, luego mesa)
         Pedido pedidoGuardado = pedidoRepository.guardar(nuevoPedido);
         Mesa mesaGuardada = mesaRepository.guardar(mesa);
+        if (reserva != null) {
+            reservaRepository.guardar(reserva);
+        }
 
         // 8. Retornar DTO de respuesta
         return AbrirMesaResponse.fromDomain(mesaGuardada, pedidoGuardado);
     }
+
+    private MozoId resolverMozo(LocalId localId, String mozoId) {
+        if (mozoId == null || mozoId.isBlank()) {
+            return null;
+        }
+        Mozo mozo = mozoRepository.buscarPorId(MozoId.from(mozoId), localId)
+            .orElseThrow(() -> new IllegalArgumentException("El mozo no existe en este local"));
+        if (!mozo.isActivo()) {
+            throw new IllegalStateException("El mozo " + mozo.getNombre() + " está inactivo");
+        }
+        return mozo.getId();
+    }
 }
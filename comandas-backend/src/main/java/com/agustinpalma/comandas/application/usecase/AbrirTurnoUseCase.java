@@ -0,0 +1,84 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.TurnoCajaResponse;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoTurno;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+import com.agustinpalma.comandas.domain.model.JornadaCaja;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.repository.JornadaCajaRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Caso de uso: abrir un turno de caja.
+ *
+ * HU-106: Gestión de turnos con arqueo por turno.
+ *
+ * Validaciones:
+ * - Debe haber una jornada ABIERTA (el turno vive dentro de ella)
+ * - No puede haber otro turno ABIERTO en el local
+ * - El mismo tipo de turno no puede repetirse en la jornada
+ * - El monto inicial debe ser ≥ 0 (lo valida el dominio)
+ */
+@Transactional
+public class AbrirTurnoUseCase {
+
+    private final JornadaCajaRepository jornadaCajaRepository;
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final Clock clock;
+
+    public AbrirTurnoUseCase(JornadaCajaRepository jornadaCajaRepository,
+                             TurnoCajaRepository turnoCajaRepository,
+                             Clock clock) {
+        this.jornadaCajaRepository = Objects.requireNonNull(jornadaCajaRepository,
+            "jornadaCajaRepository es obligatorio");
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository,
+            "turnoCajaRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "clock es obligatorio");
+    }
+
+    /**
+     * @param localId      identificador del local (tenant)
+     * @param tipo         turno a abrir
+     * @param montoInicial efectivo contado en el cajón
+     * @return el turno abierto
+     * @throws IllegalStateException si no hay jornada abierta, ya hay un turno abierto
+     *         o el tipo ya se usó en la jornada
+     */
+    public TurnoCajaResponse ejecutar(LocalId localId, TipoTurno tipo, BigDecimal montoInicial) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(tipo, "El tipo de turno es obligatorio");
+
+        JornadaCaja jornada = jornadaCajaRepository.buscarAbierta(localId)
+            .orElseThrow(() -> new IllegalStateException(
+                "No se puede abrir un turno: no hay una jornada abierta. Abra la caja primero."));
+
+        turnoCajaRepository.buscarAbierto(localId).ifPresent(abierto -> {
+            throw new IllegalStateException(
+                "Ya hay un turno abierto (" + abierto.getTipo() + "). Ciérrelo antes de abrir otro.");
+        });
+
+        boolean tipoYaUsado = turnoCajaRepository.buscarPorJornada(jornada.getId(), localId).stream()
+            .anyMatch(turno -> turno.getTipo() == tipo);
+        if (tipoYaUsado) {
+            throw new IllegalStateException("El turno " + tipo + " ya se abrió en esta jornada");
+        }
+
+        TurnoCaja turno = new TurnoCaja(
+            TurnoCajaId.generate(),
+            localId,
+            jornada.getId(),
+            tipo,
+            montoInicial,
+            LocalDateTime.now(clock)
+        );
+
+        return TurnoCajaResponse.fromDomain(turnoCajaRepository.guardar(turno));
+    }
+}
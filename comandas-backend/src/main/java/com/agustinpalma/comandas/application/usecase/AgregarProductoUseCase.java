@@ -2,25 +2,46 @@ package com.agustinpalma.comandas.application.usecase;
 
 import com.agustinpalma.comandas.application.dto.AgregarProductoRequest;
 import com.agustinpalma.comandas.application.dto.AgregarProductoResponse;
+import com.agustinpalma.comandas.domain.exception.PedidoModificadoException;
+import com.agustinpalma.comandas.domain.model.Categoria;
+import com.agustinpalma.comandas.domain.model.ComponenteCombo;
 import com.agustinpalma.comandas.domain.model.ExtraPedido;
+import com.agustinpalma.comandas.domain.model.IngredienteQuitado;
+import com.agustinpalma.comandas.domain.model.Insumo;
 import com.agustinpalma.comandas.domain.model.ItemPedido;
+import com.agustinpalma.comandas.domain.model.ListaPrecios;
 import com.agustinpalma.comandas.domain.model.Pedido;
 import com.agustinpalma.comandas.domain.model.Producto;
 import com.agustinpalma.comandas.domain.model.Promocion;
+import com.agustinpalma.comandas.domain.model.Receta;
+import com.agustinpalma.comandas.domain.model.SeleccionCombo;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Alergeno;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.repository.CategoriaRepository;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
+import com.agustinpalma.comandas.domain.repository.ListaPreciosRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
 import com.agustinpalma.comandas.domain.repository.ProductoRepository;
 import com.agustinpalma.comandas.domain.repository.PromocionRepository;
+import com.agustinpalma.comandas.domain.repository.RecetaRepository;
 import com.agustinpalma.comandas.domain.service.MotorReglasService;
 import com.agustinpalma.comandas.domain.service.NormalizadorVariantesService;
 
 import java.time.Clock;
 import java.time.LocalDateTime;
+import java.time.LocalTime;
 import java.util.ArrayList;
 import java.util.Collections;
+import java.util.Comparator;
+import java.util.EnumSet;
+import java.util.HashSet;
 import java.util.List;
 import java.util.Objects;
 import java.util.Optional;
+import java.util.Set;
+import java.util.UUID;
+import java.util.function.Consumer;
 import java.util.stream.Collectors;
 import org.springframework.transaction.annotation.Transactional;
 
@@ -30,6 +51,12 @@ import org.springframework.transaction.annotation.Transactional;
  * 
  * HU-10: Integra el MotorReglasService para aplicar promociones automáticamente.
  * HU-05.1 + HU-22: Integra normalización de variantes y extras controlados.
+ * HU-103: Valida y registra la opción elegida para cada componente de un combo.
+ * HU-108: Cotiza producto y extras con la lista de precios congelada en el pedido.
+ * HU-110: Registra el paso de servicio del ítem; no fusiona líneas de pasos distintos.
+ * HU-125: Rechaza productos, extras y opciones de combo marcados como agotados (86).
+ * HU-148: Valida que los ingredientes a quitar sean de la receta y guarda su snapshot.
+ * HU-161: Rechaza productos fuera de su horario (el propio o el de su categoría).
  * 
  * Flujo actualizado:
  * 1. Recuperar Pedido y Producto
@@ -48,6 +75,10 @@ public class AgregarProductoUseCase {
     private final PromocionRepository promocionRepository;
     private final MotorReglasService motorReglasService;
     private final NormalizadorVariantesService normalizadorVariantesService;
+    private final ListaPreciosRepository listaPreciosRepository;
+    private final RecetaRepository recetaRepository;
+    private final InsumoRepository insumoRepository;
+    private final CategoriaRepository categoriaRepository;
     private final Clock clock;
 
     /**
@@ -59,6 +90,10 @@ public class AgregarProductoUseCase {
      * @param promocionRepository repositorio de promociones (HU-10)
      * @param motorReglasService servicio de dominio para evaluar promociones (HU-10)
      * @param normalizadorVariantesService servicio de dominio para normalizar variantes (HU-22)
+     * @param listaPreciosRepository repositorio de listas de precios por canal (HU-108)
+     * @param recetaRepository repositorio de recetas, para los ingredientes quitados (HU-148)
+     * @param insumoRepository repositorio de insumos, para el snapshot de los ingredientes quitados (HU-148)
+     * @param categoriaRepository repositorio de categorías, para el horario de la categoría (HU-161)
      * @param clock reloj del sistema configurado para zona horaria de Argentina
      */
     public AgregarProductoUseCase(
@@ -67,6 +102,10 @@ public class AgregarProductoUseCase {
             PromocionRepository promocionRepository,
             MotorReglasService motorReglasService,
             NormalizadorVariantesService normalizadorVariantesService,
+            ListaPreciosRepository listaPreciosRepository,
+            RecetaRepository recetaRepository,
+            InsumoRepository insumoRepository,
+            CategoriaRepository categoriaRepository,
             Clock clock
     ) {
         this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
@@ -74,6 +113,10 @@ public class AgregarProductoUseCase {
         this.promocionRepository = Objects.requireNonNull(promocionRepository, "El promocionRepository es obligatorio");
         this.motorReglasService = Objects.requireNonNull(motorReglasService, "El motorReglasService es obligatorio");
         this.normalizadorVariantesService = Objects.requireNonNull(normalizadorVariantesService, "El normalizadorVariantesService es obligatorio");
+        this.listaPreciosRepository = Objects.requireNonNull(listaPreciosRepository, "El listaPreciosRepository es obligatorio");
+        this.recetaRepository = Objects.requireNonNull(recetaRepository, "El recetaRepository es obligatorio");
+        this.insumoRepository = Objects.requireNonNull(insumoRepository, "El insumoRepository es obligatorio");
+        this.categoriaRepository = Objects.requireNonNull(categoriaRepository, "El categoriaRepository es obligatorio");
         this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
     }
 
@@ -102,6 +145,20 @@ public class AgregarProductoUseCase {
      * @throws IllegalStateException si el pedido no está ABIERTO (delegado al dominio)
      */
     public AgregarProductoResponse ejecutar(AgregarProductoRequest request) {
+        return ejecutar(request, Pedido.SIN_CONTROL_DE_VERSION);
+    }
+
+    /**
+     * HU-165: Agrega el producto solo si el pedido sigue en la versión que ve la terminal.
+     *
+     * @param versionEsperada versión del pedido en la terminal
+     * @throws PedidoModificadoException si otra terminal modificó el pedido
+     */
+    public AgregarProductoResponse ejecutar(AgregarProductoRequest request, long versionEsperada) {
+        return ejecutar(request, pedido -> pedido.verificarVersion(versionEsperada));
+    }
+
+    private AgregarProductoResponse ejecutar(AgregarProductoRequest request, Consumer<Pedido> controlVersion) {
         Objects.requireNonNull(request, "El request no puede ser null");
 
         // 1. Recuperar Pedido
@@ -109,6 +166,11 @@ public class AgregarProductoUseCase {
             .orElseThrow(() -> new IllegalArgumentException(
                 "No se encontró el pedido con ID: " + request.pedidoId().getValue()
             ));
+        // HU-164: El ítem del borrador ya llegó (se perdió la respuesta): no se carga dos veces
+        if (request.borradorId() != null && pedido.tieneItemDeBorrador(request.borradorId())) {
+            return AgregarProductoResponse.fromDomain(pedido);
+        }
+        controlVersion.accept(pedido);
 
         // 2. Recuperar Producto seleccionado
         Producto productoSeleccionado = productoRepository.buscarPorId(request.productoId())
@@ -163,8 +225,11 @@ public class AgregarProductoUseCase {
             );
         }
 
+        // 2.2 HU-108: Lista de precios congelada al crear el pedido (null = precio de catálogo)
+        ListaPrecios listaPrecios = resolverListaPrecios(pedido);
+
         // 3. Procesar extras solicitados (convertir ProductoId → ExtraPedido con snapshot)
-        List<ExtraPedido> extrasOriginales = procesarExtras(request.extrasIds(), pedido.getLocalId());
+        List<ExtraPedido> extrasOriginales = procesarExtras(request.extrasIds(), pedido.getLocalId(), listaPrecios);
 
         // 4. HU-22: Normalizar variantes (conversión automática de discos de carne)
         // Si el cliente ya seleccionó una variante explícita, se salta la normalización.
@@ -186,6 +251,17 @@ public class AgregarProductoUseCase {
         Producto productoFinal = normalizacion.getProductoFinal();
         List<ExtraPedido> extrasFiltrados = normalizacion.getExtrasFiltrados();
 
+        // 4.0 HU-125: Se valida la variante final, que puede no ser la elegida
+        productoFinal.validarDisponible();
+        // HU-161: Fuera de horario tampoco se carga (desayuno a las 12, ejecutivo a la noche)
+        productoFinal.validarEnHorario(LocalTime.now(clock), buscarCategoria(productoFinal));
+
+        // 4.1 HU-108: Cotizar la variante final con la lista del canal.
+        // Se hace después de normalizar porque la variante puede haber cambiado.
+        if (listaPrecios != null) {
+            productoFinal = productoFinal.conPrecioDeLista(listaPrecios.precioPara(productoFinal));
+        }
+
         // 4.2 Validación de modificadores estructurales como extras.
         //
         // Regla dinámica: Si después de la normalización todavía quedan extras
@@ -196,27 +272,63 @@ public class AgregarProductoUseCase {
         // no agregar el modificador como extra suelto.
         validarModificadoresEstructuralesComoExtras(productoFinal, extrasFiltrados, pedido.getLocalId());
 
-        // 4.3 MERGE INTELIGENTE: Solo fusionar si la CONFIGURACIÓN es idéntica.
+        // 4.3 HU-103: Resolver las opciones elegidas si el producto es un combo
+        List<SeleccionCombo> seleccionCombo = procesarSeleccionCombo(
+            productoFinal, request.componentesComboIds(), pedido.getLocalId()
+        );
+
+        // 4.3b HU-148: Ingredientes de la receta que el cliente pidió sacar.
+        // Se validan contra la variante final, que es la que se descuenta del stock.
+        List<IngredienteQuitado> ingredientesQuitados = procesarIngredientesQuitados(
+            productoFinal, request.ingredientesQuitados(), pedido.getLocalId()
+        );
+
+        // 4.4 MERGE INTELIGENTE: Solo fusionar si la CONFIGURACIÓN es idéntica.
         //
         // Regla de negocio: Cada plato personalizado es una unidad independiente.
         // Dos ítems son fusionables SOLO si comparten:
         //   - Mismo productoId (post-normalización)
         //   - Misma observación
         //   - Mismos extras
+        //   - Mismas opciones de combo (HU-103)
+        //   - Mismo paso de servicio (HU-110)
+        //   - Mismas advertencias de alergia (HU-147)
+        //   - Mismos ingredientes quitados (HU-148)
         //
         // Si no coinciden → crear nuevo ItemPedido (línea independiente).
         // Esto evita el bug donde "Hamburguesa" + "Hamburguesa sin cebolla"
         // se fusionaban incorrectamente en "2x Hamburguesa sin cebolla".
         int cantidadFinal = request.cantidad();
         String observacionesFinal = request.observaciones();
+        Set<Alergeno> advertencias = request.advertencias() == null || request.advertencias().isEmpty()
+            ? Set.of()
+            : EnumSet.copyOf(request.advertencias());
         List<ExtraPedido> extrasCombinados = new ArrayList<>(extrasFiltrados);
 
         // HU-29: Preservar cuántas unidades ya se enviaron a cocina (para merge)
         int cantidadYaEnviadaCocina = 0;
 
+        // HU-164: La línea recuerda los ítems del borrador que la cargaron, los del fusionado también
+        Set<UUID> borradores = new HashSet<>();
+        if (request.borradorId() != null) {
+            borradores.add(request.borradorId());
+        }
+
+        // 5. Recuperar promociones activas del local
+        List<Promocion> promocionesActivas = promocionRepository.buscarActivasPorLocal(pedido.getLocalId());
+        LocalDateTime ahora = LocalDateTime.now(clock);
+
+        // HU-102: No fusionar líneas cargadas en franjas promocionales distintas
+        // (ej: cerveza pedida en Happy Hour + cerveza pedida después del horario).
         Optional<ItemPedido> itemExistente = pedido.buscarItemConMismaConfiguracion(
             productoFinal.getId(), observacionesFinal, extrasFiltrados
-        );
+        ).filter(existente -> existente.getComponentesCombo().equals(seleccionCombo))
+         .filter(existente -> existente.getTiempoServicio() == request.tiempoServicio())
+         .filter(existente -> existente.getAdvertencias().equals(advertencias))
+         .filter(existente -> Set.copyOf(existente.getIngredientesQuitados()).equals(Set.copyOf(ingredientesQuitados)))
+         .filter(existente -> motorReglasService.compartenFranjaPromocional(
+            pedido, existente, promocionesActivas, ahora
+        ));
 
         if (itemExistente.isPresent()) {
             ItemPedido existente = itemExistente.get();
@@ -228,14 +340,12 @@ public class AgregarProductoUseCase {
             // Sin esto, el merge (delete + recreate) haría que TODAS las unidades
             // parezcan "nuevas" en la próxima comanda.
             cantidadYaEnviadaCocina = existente.getCantidadEnviadaCocina();
+            borradores.addAll(existente.getBorradores());
             
             // Remover ítem existente (será reemplazado con cantidad acumulada)
             pedido.eliminarItem(existente.getId());
         }
 
-        // 5. Recuperar promociones activas del local
-        List<Promocion> promocionesActivas = promocionRepository.buscarActivasPorLocal(pedido.getLocalId());
-
         // 6. HU-10: Invocar motor de reglas para evaluar promociones
         // CRÍTICO: El motor SOLO descuenta sobre precio base, NO sobre extras
         // Usa cantidadFinal y extrasCombinados para que las promos calculen sobre el acumulado
@@ -246,15 +356,32 @@ public class AgregarProductoUseCase {
             observacionesFinal,
             extrasCombinados,
             promocionesActivas,
-            LocalDateTime.now(clock)
+            ahora
         );
 
+        // HU-103: El combo se cobra a precio cerrado; las opciones elegidas se guardan como snapshot
+        if (!seleccionCombo.isEmpty()) {
+            itemConPromocion = itemConPromocion.conComponentesCombo(seleccionCombo);
+        }
+
         // HU-29: Si hubo merge, heredar la cantidad ya enviada del ítem original
         // para que el delta (cantidadNueva) refleje solo las unidades realmente nuevas
         if (cantidadYaEnviadaCocina > 0) {
             itemConPromocion.heredarEnvioCocina(cantidadYaEnviadaCocina);
         }
 
+        // HU-110: Paso de servicio elegido al cargar el ítem
+        itemConPromocion.asignarTiempoServicio(request.tiempoServicio());
+
+        // HU-147: Alergias del comensal, para resaltarlas en cocina
+        itemConPromocion.registrarAdvertencias(advertencias);
+
+        // HU-148: Ingredientes quitados, para la comanda y el consumo de insumos
+        itemConPromocion.registrarIngredientesQuitados(ingredientesQuitados);
+
+        // HU-164: Ítems del borrador del POS que cargaron la línea
+        itemConPromocion.registrarBorradores(borradores);
+
         // 7. Agregar ítem al pedido (con extras y descuentos aplicados)
         pedido.agregarItem(itemConPromocion);
 
@@ -263,7 +390,10 @@ public class AgregarProductoUseCase {
         // (ej: "hamburguesa" y "hamburguesa sin cebolla") deben sumar cantidades
         // para evaluar promos basadas en cantidad (2x1, PrecioFijo).
         pedido.limpiarPromocionesItems();
-        motorReglasService.aplicarPromociones(pedido, promocionesActivas, LocalDateTime.now(clock));
+        motorReglasService.aplicarPromociones(pedido, promocionesActivas, ahora);
+
+        // HU-113: Ítems nuevos = la mesa está siendo atendida
+        pedido.registrarActividad(ahora);
 
         // 9. Persistir cambios
         Pedido pedidoActualizado = pedidoRepository.guardar(pedido);
@@ -281,10 +411,15 @@ public class AgregarProductoUseCase {
      * 
      * @param extrasIds lista de ProductoId de extras solicitados (puede ser null)
      * @param localId ID del local para validación multi-tenancy
+     * @param listaPrecios lista del pedido (HU-108), null para usar el precio de catálogo
      * @return lista de ExtraPedido con snapshot de precio
      * @throws IllegalArgumentException si algún extra no existe, no es extra, o pertenece a otro local
      */
-    private List<ExtraPedido> procesarExtras(List<ProductoId> extrasIds, com.agustinpalma.comandas.domain.model.DomainIds.LocalId localId) {
+    private List<ExtraPedido> procesarExtras(
+            List<ProductoId> extrasIds,
+            com.agustinpalma.comandas.domain.model.DomainIds.LocalId localId,
+            ListaPrecios listaPrecios
+    ) {
         if (extrasIds == null || extrasIds.isEmpty()) {
             return Collections.emptyList();
         }
@@ -311,6 +446,13 @@ public class AgregarProductoUseCase {
                 );
             }
 
+            productoExtra.validarDisponible();
+
+            // HU-108: El extra también se cobra al precio de la lista del canal
+            if (listaPrecios != null) {
+                productoExtra = productoExtra.conPrecioDeLista(listaPrecios.precioPara(productoExtra));
+            }
+
             // Crear ExtraPedido con snapshot de precio actual
             extras.add(ExtraPedido.crearDesdeProducto(productoExtra));
         }
@@ -318,6 +460,121 @@ public class AgregarProductoUseCase {
         return extras;
     }
 
+    /**
+     * HU-148: Resuelve los insumos que el cliente pidió sacar del producto.
+     *
+     * @param producto variante final del ítem
+     * @param insumosIds insumos a quitar (puede ser null)
+     * @return snapshot de cada ingrediente quitado, por nombre
+     * @throws IllegalArgumentException si el producto no tiene receta, un insumo no está
+     *         en ella o se repite
+     */
+    private List<IngredienteQuitado> procesarIngredientesQuitados(
+            Producto producto,
+            List<InsumoId> insumosIds,
+            com.agustinpalma.comandas.domain.model.DomainIds.LocalId localId
+    ) {
+        if (insumosIds == null || insumosIds.isEmpty()) {
+            return List.of();
+        }
+        if (Set.copyOf(insumosIds).size() != insumosIds.size()) {
+            throw new IllegalArgumentException("Un ingrediente no puede quitarse dos veces");
+        }
+
+        Receta receta = recetaRepository.buscarPorProducto(producto.getId(), localId)
+            .orElseThrow(() -> new IllegalArgumentException(
+                String.format("El producto '%s' no tiene receta: no se le pueden quitar ingredientes",
+                    producto.getNombre())
+            ));
+
+        List<IngredienteQuitado> quitados = new ArrayList<>();
+        for (InsumoId insumoId : insumosIds) {
+            if (!receta.usaInsumo(insumoId)) {
+                throw new IllegalArgumentException(
+                    String.format("Uno de los ingredientes a quitar no está en la receta de '%s'",
+                        producto.getNombre())
+                );
+            }
+            Insumo insumo = insumoRepository.buscarPorId(insumoId, localId)
+                .orElseThrow(() -> new IllegalArgumentException(
+                    "No se encontró el insumo con ID: " + insumoId.getValue()
+                ));
+            quitados.add(IngredienteQuitado.de(insumo));
+        }
+        quitados.sort(Comparator.comparing(IngredienteQuitado::getNombre, String.CASE_INSENSITIVE_ORDER));
+        return quitados;
+    }
+
+    /**
+     * HU-108: Recupera la lista de precios que el pedido congeló al crearse.
+     *
+     * Se busca por id y no por canal: si después se reconfigura la lista del
+     * canal, el pedido sigue cotizando con la misma lista (con sus valores vigentes).
+     * Si la lista ya no existe, se cae al precio de catálogo.
+     *
+     * @param pedido pedido al que se agrega el producto
+     * @return la lista del pedido, o null si vende a precio de catálogo
+     */
+    private ListaPrecios resolverListaPrecios(Pedido pedido) {
+        if (pedido.getListaPreciosId() == null) {
+            return null;
+        }
+        return listaPreciosRepository.buscarPorId(pedido.getListaPreciosId(), pedido.getLocalId())
+            .orElse(null);
+    }
+
+    /**
+     * HU-103: Resuelve las opciones elegidas para cada componente del combo.
+     *
+     * Si el combo solo tiene componentes fijos, la elección puede omitirse
+     * y se toma la única opción de cada componente.
+     *
+     * @param producto producto final a agregar
+     * @param componentesComboIds IDs elegidos en el orden de los componentes (puede ser null)
+     * @param localId ID del local para validación multi-tenancy
+     * @return selecciones snapshot (vacía si el producto no es combo)
+     * @throws IllegalArgumentException si se informan opciones para un producto que no es combo,
+     *         o si alguna opción no existe o no corresponde a su componente
+     */
+    private List<SeleccionCombo> procesarSeleccionCombo(
+            Producto producto,
+            List<ProductoId> componentesComboIds,
+            com.agustinpalma.comandas.domain.model.DomainIds.LocalId localId
+    ) {
+        boolean sinEleccion = componentesComboIds == null || componentesComboIds.isEmpty();
+
+        if (!producto.esCombo()) {
+            if (!sinEleccion) {
+                throw new IllegalArgumentException(
+                    String.format("El producto '%s' no es un combo", producto.getNombre())
+                );
+            }
+            return Collections.emptyList();
+        }
+
+        List<ProductoId> ids = componentesComboIds;
+        if (sinEleccion && producto.getComponentesCombo().stream().allMatch(ComponenteCombo::esFijo)) {
+            ids = producto.getComponentesCombo().stream()
+                .map(componente -> componente.getOpciones().get(0))
+                .toList();
+        }
+
+        List<Producto> elegidos = new ArrayList<>();
+        if (ids != null) {
+            for (ProductoId id : ids) {
+                Producto opcion = productoRepository.buscarPorIdYLocal(id, localId)
+                    .orElseThrow(() -> new IllegalArgumentException(
+                        "No se encontró la opción de combo con ID: " + id.getValue()
+                    ));
+                opcion.validarDisponible();
+                elegidos.add(opcion);
+            }
+        }
+
+        // El dominio valida cantidad de elecciones y pertenencia a cada componente
+        return producto.armarSeleccionCombo(elegidos);
+    }
+
     /**
      * Normaliza las variantes según la regla de modificadores estructurales.
      * 
@@ -439,4 +696,14 @@ public class AgregarProductoUseCase {
         }
         // discosActuales == maxEstructural → OK
     }
+
+    /**
+     * HU-161: Categoría del producto, para resolver su horario (null si no tiene).
+     */
+    private Categoria buscarCategoria(Producto producto) {
+        if (producto.getCategoriaId() == null) {
+            return null;
+        }
+        return categoriaRepository.buscarPorId(producto.getCategoriaId()).orElse(null);
+    }
 }
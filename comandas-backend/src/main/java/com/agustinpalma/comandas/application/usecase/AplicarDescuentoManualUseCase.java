@@ -3,14 +3,22 @@ package com.agustinpalma.comandas.application.usecase;
 import com.agustinpalma.comandas.application.dto.AplicarDescuentoManualRequest;
 import com.agustinpalma.comandas.application.dto.AplicarDescuentoManualResponse;
 import com.agustinpalma.comandas.domain.model.DescuentoManual;
+import com.agustinpalma.comandas.domain.exception.PedidoModificadoException;
+import com.agustinpalma.comandas.domain.exception.PermisoDenegadoException;
 import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
 import com.agustinpalma.comandas.domain.model.ItemPedido;
+import com.agustinpalma.comandas.domain.model.Mozo;
 import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
 
+import java.math.BigDecimal;
 import java.time.Clock;
 import java.time.LocalDateTime;
 import java.util.Objects;
+import java.util.function.Consumer;
 
 import org.springframework.transaction.annotation.Transactional;
 
@@ -30,24 +38,39 @@ import org.springframework.transaction.annotation.Transactional;
  * 1° Promociones automáticas (HU-10) → Snapshot fijo
  * 2° Descuentos manuales por ítem → Dinámico sobre remanente
  * 3° Descuento global → Dinámico sobre total de ítems
+ * 
+ * HU-122: Tope por rol. Sin autorización, el descuento lo aplica el mozo de la mesa
+ * y no puede superar el 10%. Con el PIN de un encargado no hay tope. En ambos casos
+ * el motivo es obligatorio y queda registrado quién autorizó.
+ *
+ * HU-169: Con la matriz de permisos activa, el descuento exige APLICAR_DESCUENTOS
+ * al encargado que autoriza o, sin autorización, al empleado identificado en la terminal.
  */
 @Transactional
 public class AplicarDescuentoManualUseCase {
 
     private final PedidoRepository pedidoRepository;
+    private final MozoRepository mozoRepository;
+    private final GestionarPermisosUseCase gestionarPermisosUseCase;
     private final Clock clock;
 
     /**
      * Constructor con inyección de dependencias.
      * 
      * @param pedidoRepository repositorio de pedidos
+     * @param mozoRepository repositorio del personal (HU-122: quién autoriza)
+     * @param gestionarPermisosUseCase matriz de permisos (HU-169)
      * @param clock reloj del sistema para timestamp de auditoría
      */
     public AplicarDescuentoManualUseCase(
             PedidoRepository pedidoRepository,
+            MozoRepository mozoRepository,
+            GestionarPermisosUseCase gestionarPermisosUseCase,
             Clock clock
     ) {
         this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.gestionarPermisosUseCase = Objects.requireNonNull(gestionarPermisosUseCase, "El gestionarPermisosUseCase es obligatorio");
         this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
     }
 
@@ -57,25 +80,46 @@ public class AplicarDescuentoManualUseCase {
      * Flujo:
      * 1. Recuperar pedido por ID
      * 2. Validar que el pedido está ABIERTO
-     * 3. Crear DescuentoManual VO con timestamp actual
-     * 4. Si es descuento por ítem → buscar ítem y aplicar
+     * 3. Resolver quién autoriza (encargado con PIN o mozo de la mesa)
+     * 4. Crear DescuentoManual VO con timestamp actual y validar el tope del rol
+     * 5. Si es descuento por ítem → buscar ítem y aplicar
      *    Si es descuento global → aplicar directamente al pedido
-     * 5. Persistir cambios
-     * 6. Retornar respuesta con desglose transparente
+     * 6. Persistir cambios
+     * 7. Retornar respuesta con desglose transparente
      * 
      * @param request DTO con pedidoId, itemPedidoId (opcional), porcentaje, razón y usuarioId
      * @return DTO con desglose completo de descuentos y totales
-     * @throws IllegalArgumentException si el pedido no existe o el ítem no existe
-     * @throws IllegalStateException si el pedido no está ABIERTO
+     * @throws IllegalArgumentException si el pedido o el ítem no existen, falta el motivo o el PIN no coincide
+     * @throws IllegalStateException si el pedido no está ABIERTO o se supera el tope del rol
+     * @throws PermisoDenegadoException si quien descuenta no tiene el permiso (HU-169)
      */
     public AplicarDescuentoManualResponse ejecutar(AplicarDescuentoManualRequest request) {
+        return ejecutar(request, Pedido.SIN_CONTROL_DE_VERSION);
+    }
+
+    /**
+     * HU-165: Aplica el descuento solo si el pedido sigue en la versión que ve
+     * la terminal, para no descontar sobre un total que otra terminal cambió.
+     *
+     * @param versionEsperada versión del pedido en la terminal
+     * @throws PedidoModificadoException si otra terminal modificó el pedido
+     */
+    public AplicarDescuentoManualResponse ejecutar(AplicarDescuentoManualRequest request, long versionEsperada) {
+        return ejecutar(request, pedido -> pedido.verificarVersion(versionEsperada));
+    }
+
+    private AplicarDescuentoManualResponse ejecutar(AplicarDescuentoManualRequest request, Consumer<Pedido> controlVersion) {
         Objects.requireNonNull(request, "El request no puede ser null");
+        if (request.razon() == null || request.razon().isBlank()) {
+            throw new IllegalArgumentException("El motivo del descuento es obligatorio");
+        }
 
         // 1. Recuperar pedido
         Pedido pedido = pedidoRepository.buscarPorId(request.pedidoId())
             .orElseThrow(() -> new IllegalArgumentException(
                 "No se encontró el pedido con ID: " + request.pedidoId().getValue()
             ));
+        controlVersion.accept(pedido);
 
         // 2. Validar que el pedido está abierto
         if (!pedido.getEstado().equals(EstadoPedido.ABIERTO)) {
@@ -84,48 +128,81 @@ public class AplicarDescuentoManualUseCase {
             );
         }
 
-        // 3. Crear DescuentoManual VO con timestamp actual
+        // 3. Resolver quién autoriza
+        Mozo autorizante = resolverAutorizante(pedido, request);
+        RolPersonal rol = request.autorizanteId() != null ? RolPersonal.ENCARGADO : RolPersonal.MOZO;
+
+        // 4. Crear DescuentoManual VO con timestamp actual
         DescuentoManual descuento = new DescuentoManual(
             request.tipoDescuento(),
             request.valor(),
-            request.razon(),
+            request.razon().trim(),
             request.usuarioId(),
-            LocalDateTime.now(clock)
+            LocalDateTime.now(clock),
+            autorizante != null ? autorizante.getId() : null,
+            autorizante != null ? autorizante.getNombre() : null
         );
 
-        // 4. Aplicar descuento según modalidad
+        // 5. Aplicar descuento según modalidad, dentro del tope del rol
         if (request.esDescuentoPorItem()) {
-            aplicarDescuentoPorItem(pedido, request.itemPedidoId().getValue(), descuento);
+            ItemPedido item = buscarItem(pedido, request.itemPedidoId().getValue());
+            descuento.validarTope(rol, item.calcularSubtotal().subtract(item.getMontoDescuento()));
+            item.aplicarDescuentoManual(descuento);
         } else {
+            BigDecimal baseGravable = pedido.getItems().stream()
+                .map(ItemPedido::calcularPrecioFinal)
+                .reduce(BigDecimal.ZERO, BigDecimal::add);
+            descuento.validarTope(rol, baseGravable);
             aplicarDescuentoGlobal(pedido, descuento);
         }
 
-        // 5. Persistir cambios
+        // 6. Persistir cambios
         Pedido pedidoActualizado = pedidoRepository.guardar(pedido);
 
-        // 6. Retornar respuesta con desglose transparente
+        // 7. Retornar respuesta con desglose transparente
         return AplicarDescuentoManualResponse.fromDomain(pedidoActualizado);
     }
 
     /**
-     * Aplica descuento manual a un ítem específico del pedido.
-     * Sobrescribe cualquier descuento manual previo del ítem.
+     * HU-122: Determina quién queda registrado como autorizante del descuento.
+     * 
+     * Con autorizanteId: el encargado valida su PIN. Sin él: el mozo asignado a la mesa,
+     * si lo hay, con su tope. HU-169: en ambos casos se valida la matriz de permisos.
+     * 
+     * @return el empleado que autoriza, o null si la mesa no tiene mozo asignado
+     * @throws IllegalArgumentException si el encargado no existe o el PIN no coincide
+     * @throws IllegalStateException si quien autoriza no es un encargado activo
+     */
+    private Mozo resolverAutorizante(Pedido pedido, AplicarDescuentoManualRequest request) {
+        if (request.autorizanteId() != null) {
+            Mozo encargado = mozoRepository.buscarPorId(request.autorizanteId(), pedido.getLocalId())
+                .orElseThrow(() -> new IllegalArgumentException("El encargado no existe en este local"));
+            encargado.validarAutorizacion(request.pin());
+            gestionarPermisosUseCase.exigir(encargado, Permiso.APLICAR_DESCUENTOS);
+            return encargado;
+        }
+        gestionarPermisosUseCase.exigir(pedido.getLocalId(), Permiso.APLICAR_DESCUENTOS);
+        if (pedido.getMozoId() == null) {
+            return null;
+        }
+        return mozoRepository.buscarPorId(pedido.getMozoId(), pedido.getLocalId()).orElse(null);
+    }
+
+    /**
+     * Busca el ítem a descontar dentro del pedido.
      * 
      * @param pedido Pedido de dominio
      * @param itemPedidoId ID del ítem a descontar
-     * @param descuento DescuentoManual VO
      * @throws IllegalArgumentException si el ítem no existe en el pedido
      */
-    private void aplicarDescuentoPorItem(Pedido pedido, java.util.UUID itemPedidoId, DescuentoManual descuento) {
-        ItemPedido item = pedido.getItems().stream()
+    private ItemPedido buscarItem(Pedido pedido, java.util.UUID itemPedidoId) {
+        return pedido.getItems().stream()
             .filter(i -> i.getId().getValue().equals(itemPedidoId))
             .findFirst()
             .orElseThrow(() -> new IllegalArgumentException(
                 String.format("No se encontró el ítem %s en el pedido %s", 
                     itemPedidoId, pedido.getId().getValue())
             ));
-
-        item.aplicarDescuentoManual(descuento);
     }
 
     /**
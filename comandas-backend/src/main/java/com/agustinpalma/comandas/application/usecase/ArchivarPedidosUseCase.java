@@ -0,0 +1,89 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.ArchivoPedidosRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * HU-162: Archivo de pedidos para mantener rápida la base operativa.
+ *
+ * Después de un par de años de uso la tabla de pedidos tiene cientos de miles
+ * de filas y las búsquedas y el mapa de mesas se ponen lentos. Los pedidos
+ * cerrados antes del horizonte de retención se copian al archivo (comprimidos)
+ * y se borran de la base operativa; los reportes y exportaciones por fecha los
+ * siguen leyendo desde ahí.
+ *
+ * Trabaja por lotes para no tener una transacción larga con el local abierto:
+ * cada llamada a {@link #archivarLote()} mueve a lo sumo {@value #TAMANIO_LOTE}
+ * pedidos en su propia transacción. Los pedidos abiertos nunca se archivan.
+ */
+@Transactional
+public class ArchivarPedidosUseCase {
+
+    private static final Logger log = LoggerFactory.getLogger(ArchivarPedidosUseCase.class);
+
+    static final int TAMANIO_LOTE = 200;
+
+    private final PedidoRepository pedidoRepository;
+    private final ArchivoPedidosRepository archivoPedidosRepository;
+    private final int mesesRetencion;
+    private final Clock clock;
+
+    /**
+     * @param mesesRetencion meses que un pedido cerrado queda en la base operativa; 0 desactiva el archivado
+     */
+    public ArchivarPedidosUseCase(
+            PedidoRepository pedidoRepository,
+            ArchivoPedidosRepository archivoPedidosRepository,
+            int mesesRetencion,
+            Clock clock
+    ) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.archivoPedidosRepository = Objects.requireNonNull(archivoPedidosRepository, "El archivoPedidosRepository es obligatorio");
+        if (mesesRetencion < 0) {
+            throw new IllegalArgumentException("Los meses de retención de pedidos no pueden ser negativos");
+        }
+        this.mesesRetencion = mesesRetencion;
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * Mueve al archivo un lote de pedidos vencidos, los más viejos primero.
+     *
+     * @return cantidad de pedidos archivados; 0 si no queda ninguno (o el archivado está desactivado)
+     */
+    public int archivarLote() {
+        if (mesesRetencion == 0) {
+            return 0;
+        }
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        List<Pedido> vencidos = pedidoRepository.buscarCerradosAntesDe(limite(ahora), TAMANIO_LOTE);
+        if (vencidos.isEmpty()) {
+            return 0;
+        }
+
+        archivoPedidosRepository.archivar(vencidos, ahora);
+        pedidoRepository.eliminar(vencidos.stream().map(Pedido::getId).toList());
+
+        log.debug("Archivados {} pedidos cerrados antes de {}", vencidos.size(), limite(ahora));
+        return vencidos.size();
+    }
+
+    /**
+     * Se archiva por día entero: los pedidos de una misma jornada no quedan
+     * repartidos entre la base operativa y el archivo según la hora.
+     */
+    private LocalDateTime limite(LocalDateTime ahora) {
+        LocalDate dia = ahora.toLocalDate().minusMonths(mesesRetencion);
+        return dia.atStartOfDay();
+    }
+}
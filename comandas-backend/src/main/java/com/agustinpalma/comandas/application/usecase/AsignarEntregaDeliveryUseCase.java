@@ -0,0 +1,79 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.AgregarProductoResponse;
+import com.agustinpalma.comandas.application.dto.AsignarEntregaRequest;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ZonaDeliveryId;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.ZonaDelivery;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.ZonaDeliveryRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Comparator;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Optional;
+
+/**
+ * HU-139: Carga la dirección de entrega de un pedido de delivery y le
+ * agrega el costo de envío de su zona como ítem.
+ *
+ * Si no se elige la zona, se busca entre las zonas activas la que tenga un
+ * barrio mencionado en la dirección (el nombre de barrio más largo gana:
+ * "Villa Belgrano" antes que "Belgrano"). Volver a cargar la entrega
+ * reemplaza el envío anterior.
+ */
+@Transactional
+public class AsignarEntregaDeliveryUseCase {
+
+    private final PedidoRepository pedidoRepository;
+    private final ZonaDeliveryRepository zonaDeliveryRepository;
+
+    public AsignarEntregaDeliveryUseCase(PedidoRepository pedidoRepository,
+                                         ZonaDeliveryRepository zonaDeliveryRepository) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.zonaDeliveryRepository = Objects.requireNonNull(zonaDeliveryRepository, "El zonaDeliveryRepository es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si el pedido o la zona no existen en el local,
+     *                                  o la dirección no coincide con ninguna zona
+     * @throws IllegalStateException si el pedido no es delivery, no está ABIERTO o la zona está inactiva
+     */
+    public AgregarProductoResponse ejecutar(LocalId localId, PedidoId pedidoId, AsignarEntregaRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(pedidoId, "El pedidoId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Pedido pedido = pedidoRepository.buscarPorId(pedidoId)
+            .filter(p -> p.getLocalId().equals(localId))
+            .orElseThrow(() -> new IllegalArgumentException(
+                "No se encontró el pedido con ID: " + pedidoId.getValue()
+            ));
+
+        ZonaDelivery zona = request.zonaId() != null
+            ? zonaDeliveryRepository.buscarPorId(new ZonaDeliveryId(request.zonaId()), localId)
+                .orElseThrow(() -> new IllegalArgumentException("La zona de reparto no existe en este local"))
+            : zonaPorDireccion(localId, request.direccion())
+                .orElseThrow(() -> new IllegalArgumentException(
+                    "No se encontró la zona de \"" + request.direccion().trim() + "\": elegila a mano"
+                ));
+
+        pedido.asignarEntrega(request.direccion(), zona);
+
+        return AgregarProductoResponse.fromDomain(pedidoRepository.guardar(pedido));
+    }
+
+    private Optional<ZonaDelivery> zonaPorDireccion(LocalId localId, String direccion) {
+        List<ZonaDelivery> activas = zonaDeliveryRepository.buscarPorLocal(localId).stream()
+            .filter(ZonaDelivery::isActiva)
+            .toList();
+        return activas.stream()
+            .flatMap(zona -> zona.barrioEn(direccion).map(barrio -> Map.entry(zona, barrio)).stream())
+            .max(Comparator.comparingInt(coincidencia -> coincidencia.getValue().length()))
+            .map(Map.Entry::getKey);
+    }
+}
@@ -0,0 +1,55 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.DisponibilidadProductoEvento;
+import com.agustinpalma.comandas.application.dto.ProductoResponse;
+import com.agustinpalma.comandas.application.ports.output.NotificadorTiempoRealPort;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso del switch "sin stock" (86) de un producto.
+ *
+ * HU-125: Cualquier terminal puede marcar un producto como agotado apenas
+ * la cocina avisa. El cambio se difunde en el momento a todas las terminales
+ * del local para que nadie cargue algo que ya no hay.
+ */
+@Transactional
+public class CambiarDisponibilidadProductoUseCase {
+
+    private final ProductoRepository productoRepository;
+    private final NotificadorTiempoRealPort notificador;
+
+    public CambiarDisponibilidadProductoUseCase(ProductoRepository productoRepository,
+                                                NotificadorTiempoRealPort notificador) {
+        this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+        this.notificador = Objects.requireNonNull(notificador, "El notificador es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si el producto no existe en el local
+     */
+    public ProductoResponse ejecutar(LocalId localId, ProductoId productoId, boolean agotado) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(productoId, "El productoId es obligatorio");
+
+        Producto producto = productoRepository.buscarPorIdYLocal(productoId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("Producto no encontrado"));
+
+        // Repetir la marca no vuelve a notificar: dos terminales pueden tocar el switch a la vez
+        if (producto.isAgotado() == agotado) {
+            return ProductoResponse.fromDomain(producto);
+        }
+
+        producto.marcarAgotado(agotado);
+        Producto guardado = productoRepository.guardar(producto);
+        notificador.publicar(localId, DisponibilidadProductoEvento.NOMBRE,
+            DisponibilidadProductoEvento.fromDomain(guardado));
+
+        return ProductoResponse.fromDomain(guardado);
+    }
+}
@@ -1,26 +1,30 @@
 package com.agustinpalma.comandas.application.usecase;
 
 import com.agustinpalma.comandas.domain.exception.MesasAbiertasException;
+import com.agustinpalma.comandas.domain.model.AperturaCaja;
 import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoMesa;
 import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.JornadaCaja;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
 import com.agustinpalma.comandas.domain.model.Mesa;
 import com.agustinpalma.comandas.domain.model.MovimientoCaja;
 import com.agustinpalma.comandas.domain.model.Pedido;
 import com.agustinpalma.comandas.domain.model.Pago;
 import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.repository.CajaRepository;
 import com.agustinpalma.comandas.domain.repository.JornadaCajaRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
 import com.agustinpalma.comandas.domain.repository.MesaRepository;
 import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
 import org.springframework.transaction.annotation.Transactional;
 
 import java.math.BigDecimal;
 import java.time.Clock;
 import java.time.LocalDate;
 import java.time.LocalDateTime;
-import java.time.LocalTime;
 import java.util.EnumMap;
 import java.util.List;
 import java.util.Map;
@@ -32,11 +36,21 @@ import java.util.Objects;
  * Orquesta las siguientes validaciones y acciones:
  * 1. Busca la jornada ABIERTA del local (obligatoria)
  * 2. Valida que no existan mesas con estado ABIERTA
+ *    y (HU-106) que no quede un turno de caja abierto
  * 3. Calcula el snapshot contable del día
  * 4. Transiciona la jornada de ABIERTA → CERRADA
  * 
  * Fórmula del arqueo de efectivo:
- *   balanceEfectivo = fondoInicial + ventasEFECTIVO + ingresosManuales − egresos
+ *   balanceEfectivo = fondoInicial + ventasEFECTIVO + ingresosManuales − egresos − devoluciones
+ *
+ * HU-121: Las devoluciones del día se descuentan de las ventas reales del
+ * snapshot y no se suman a los egresos (no son gastos).
+ *
+ * HU-153: El snapshot toma la jornada comercial del local (de la hora de corte
+ * a la del día siguiente), así lo cobrado después de medianoche entra al cierre.
+ *
+ * HU-159: El fondo inicial del arqueo suma el de todas las cajas abiertas en
+ * la jornada: el balance es el efectivo que tiene que haber entre todos los cajones.
  * 
  * No contiene lógica de negocio: delega al dominio (JornadaCaja, excepciones).
  * Solo coordina repositorios y reglas ya definidas.
@@ -48,12 +62,18 @@ public class CerrarJornadaUseCase {
     private final PedidoRepository pedidoRepository;
     private final MovimientoCajaRepository movimientoCajaRepository;
     private final JornadaCajaRepository jornadaCajaRepository;
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
+    private final CajaRepository cajaRepository;
     private final Clock clock;
 
     public CerrarJornadaUseCase(MesaRepository mesaRepository,
                                  PedidoRepository pedidoRepository,
                                  MovimientoCajaRepository movimientoCajaRepository,
                                  JornadaCajaRepository jornadaCajaRepository,
+                                 TurnoCajaRepository turnoCajaRepository,
+                                 JornadaComercialRepository jornadaComercialRepository,
+                                 CajaRepository cajaRepository,
                                  Clock clock) {
         this.mesaRepository = Objects.requireNonNull(mesaRepository, "mesaRepository es obligatorio");
         this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "pedidoRepository es obligatorio");
@@ -61,6 +81,11 @@ public class CerrarJornadaUseCase {
             "movimientoCajaRepository es obligatorio");
         this.jornadaCajaRepository = Objects.requireNonNull(jornadaCajaRepository, 
             "jornadaCajaRepository es obligatorio");
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository,
+            "turnoCajaRepository es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository,
+            "jornadaComercialRepository es obligatorio");
+        this.cajaRepository = Objects.requireNonNull(cajaRepository, "cajaRepository es obligatorio");
         this.clock = Objects.requireNonNull(clock, "clock es obligatorio");
     }
 
@@ -71,6 +96,7 @@ public class CerrarJornadaUseCase {
      * @return el ID de la jornada cerrada
      * @throws IllegalStateException si no hay jornada abierta
      * @throws MesasAbiertasException si existen mesas con estado ABIERTA
+     * @throws IllegalStateException si hay un turno de caja sin cerrar
      */
     public JornadaCajaId ejecutar(LocalId localId) {
         Objects.requireNonNull(localId, "El localId es obligatorio");
@@ -85,9 +111,19 @@ public class CerrarJornadaUseCase {
         // 2. Validar que no existan mesas abiertas
         validarMesasCerradas(localId);
 
+        // 2b. HU-106: el arqueo de cada turno debe estar hecho antes de consolidar
+        turnoCajaRepository.buscarAbierto(localId).ifPresent(turno -> {
+            throw new IllegalStateException(
+                "No se puede cerrar la jornada: el turno " + turno.getTipo() + " sigue abierto. Ciérrelo primero.");
+        });
+
         // 3. Calcular snapshot contable del día usando la fecha operativa de la jornada abierta
         LocalDate fechaOperativa = jornada.getFechaOperativa();
-        SnapshotContable snapshot = calcularSnapshot(localId, fechaOperativa, jornada.getFondoInicial());
+        //    HU-159: con el fondo de la caja principal y el de cada caja abierta
+        BigDecimal fondoCajas = cajaRepository.buscarAperturas(jornada.getId(), localId).stream()
+            .map(AperturaCaja::getFondoInicial)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+        SnapshotContable snapshot = calcularSnapshot(localId, fechaOperativa, jornada.getFondoInicial().add(fondoCajas));
 
         // 4. Transicionar ABIERTA → CERRADA (el dominio valida el estado)
         jornada.cerrar(
@@ -124,12 +160,14 @@ public class CerrarJornadaUseCase {
     /**
      * Calcula el snapshot contable del día incluyendo el fondo inicial en el arqueo.
      *
-     * Fórmula: balanceEfectivo = fondoInicial + entradasEfectivo + totalIngresos − totalEgresos
+     * Fórmula: balanceEfectivo = fondoInicial + entradasEfectivo + totalIngresos − totalEgresos − totalDevoluciones
      */
     private SnapshotContable calcularSnapshot(LocalId localId, LocalDate fechaOperativa,
                                                BigDecimal fondoInicial) {
-        LocalDateTime inicio = fechaOperativa.atStartOfDay();
-        LocalDateTime fin = fechaOperativa.atTime(LocalTime.of(23, 59, 59));
+        JornadaComercial jornadaComercial = jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId));
+        LocalDateTime inicio = jornadaComercial.inicio(fechaOperativa);
+        LocalDateTime fin = jornadaComercial.fin(fechaOperativa);
 
         List<Pedido> pedidosCerrados = pedidoRepository.buscarCerradosPorFecha(localId, inicio, fin);
         List<MovimientoCaja> movimientos = movimientoCajaRepository.buscarPorFecha(localId, inicio, fin);
@@ -150,13 +188,20 @@ public class CerrarJornadaUseCase {
                 }
 
                 if (medio == MedioPago.EFECTIVO) {
-                    entradasEfectivo = entradasEfectivo.add(montoPago);
+                    // HU-143: el recargo del efectivo también entra al cajón
+                    entradasEfectivo = entradasEfectivo.add(pago.getMontoCobrado());
                 }
             }
         }
 
         BigDecimal totalEgresos = movimientos.stream()
             .filter(MovimientoCaja::esEgreso)
+            .filter(m -> !m.esDevolucion())
+            .map(MovimientoCaja::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        BigDecimal totalDevoluciones = movimientos.stream()
+            .filter(MovimientoCaja::esDevolucion)
             .map(MovimientoCaja::getMonto)
             .reduce(BigDecimal.ZERO, BigDecimal::add);
 
@@ -165,14 +210,15 @@ public class CerrarJornadaUseCase {
             .map(MovimientoCaja::getMonto)
             .reduce(BigDecimal.ZERO, BigDecimal::add);
 
-        // Arqueo = Fondo Inicial + Ventas Efectivo + Ingresos Manuales − Egresos
+        // Arqueo = Fondo Inicial + Ventas Efectivo + Ingresos Manuales − Egresos − Devoluciones
         BigDecimal balanceEfectivo = fondoInicial
             .add(entradasEfectivo)
             .add(totalIngresos)
-            .subtract(totalEgresos);
+            .subtract(totalEgresos)
+            .subtract(totalDevoluciones);
 
         return new SnapshotContable(
-            totalVentasReales, totalConsumoInterno, totalEgresos,
+            totalVentasReales.subtract(totalDevoluciones), totalConsumoInterno, totalEgresos,
             balanceEfectivo, pedidosCerrados.size()
         );
     }
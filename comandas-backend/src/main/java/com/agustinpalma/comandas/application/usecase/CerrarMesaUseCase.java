@@ -1,33 +1,64 @@
 package com.agustinpalma.comandas.application.usecase;
 
+import com.agustinpalma.comandas.application.dto.CerrarMesaCommand;
 import com.agustinpalma.comandas.application.dto.CerrarMesaResponse;
 import com.agustinpalma.comandas.application.dto.PagoRequest;
+import com.agustinpalma.comandas.domain.exception.PedidoModificadoException;
+import com.agustinpalma.comandas.domain.model.Cliente;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.ExtraPedido;
+import com.agustinpalma.comandas.domain.model.Insumo;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
 import com.agustinpalma.comandas.domain.model.ItemPedido;
 import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.MovimientoInsumo;
+import com.agustinpalma.comandas.domain.model.MovimientoPuntos;
 import com.agustinpalma.comandas.domain.model.MovimientoStock;
 import com.agustinpalma.comandas.domain.model.Pago;
 import com.agustinpalma.comandas.domain.model.Pedido;
 import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.model.ProgramaPuntos;
+import com.agustinpalma.comandas.domain.model.PromoCumpleanios;
 import com.agustinpalma.comandas.domain.model.Promocion;
+import com.agustinpalma.comandas.domain.model.Receta;
+import com.agustinpalma.comandas.domain.repository.ClienteRepository;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
 import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoInsumoRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoPuntosRepository;
 import com.agustinpalma.comandas.domain.repository.MovimientoStockRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
 import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.domain.repository.ProgramaPuntosRepository;
+import com.agustinpalma.comandas.domain.repository.PromoCumpleaniosRepository;
 import com.agustinpalma.comandas.domain.repository.PromocionRepository;
+import com.agustinpalma.comandas.domain.repository.RecetaRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
 import com.agustinpalma.comandas.domain.service.GestorStockService;
 import com.agustinpalma.comandas.domain.service.GestorStockService.ResultadoStock;
+import com.agustinpalma.comandas.domain.service.InventarioService;
+import com.agustinpalma.comandas.domain.service.InventarioService.ResultadoInsumos;
 import com.agustinpalma.comandas.domain.service.MotorReglasService;
+import com.agustinpalma.comandas.domain.service.ProgramaPuntosService;
 import org.springframework.transaction.annotation.Transactional;
 
+import java.math.BigDecimal;
 import java.time.Clock;
+import java.time.LocalDate;
 import java.time.LocalDateTime;
 import java.util.List;
 import java.util.Map;
 import java.util.Objects;
+import java.util.Optional;
+import java.util.function.Function;
 import java.util.stream.Collectors;
+import java.util.stream.Stream;
 
 /**
  * Caso de uso para cerrar una mesa abierta y finalizar su pedido activo.
@@ -42,10 +73,23 @@ import java.util.stream.Collectors;
  * Flujo:
  * 1. Recuperar Mesa y su Pedido activo
  * 2. Re-evaluar promociones una última vez (MotorReglasService)
- * 3. pedido.cerrar(pagos) → congela snapshot + valida montos
- * 4. HU-22: Descontar stock de los productos vendidos (atómico con el cierre)
- * 5. mesa.liberar() → devuelve la mesa a estado LIBRE
- * 6. Persistir cambios
+ * 3. HU-168: Aplicar la promo de cumpleaños si el cliente cumple años esa semana
+ *    HU-105: Aplicar el canje de puntos del cliente como descuento
+ * 4. pedido.cerrar(pagos) → congela snapshot + valida montos
+ *    HU-143: cada pago lleva congelado el recargo vigente de su medio (fuera del total)
+ *    HU-151: los pagos en USD/EUR llevan congelado el tipo de cambio del día operativo
+ *    HU-106: el pedido queda asociado al turno de caja abierto (si hay)
+ *    HU-159: y a la caja de la terminal que cobró
+ *    HU-166: con el próximo número de comprobante de la serie de esa caja
+ *    HU-111: se registra la propina del mozo (fuera del total y de los pagos)
+ *    HU-144: los pagos TARJETA_REGALO descuentan el saldo de la tarjeta indicada
+ * 5. HU-22: Descontar stock de los productos vendidos (atómico con el cierre)
+ *    HU-133: Descontar los insumos que lleva cada producto según su receta
+ * 6. HU-104: Cargar a la cuenta corriente del cliente los pagos CUENTA_CORRIENTE
+ * 7. HU-105: Acumular los puntos que gana el cliente por el pedido
+ *    HU-132: Imputar los pagos A_CUENTA al empleado que consumió (avisa si supera su tope)
+ * 8. mesa.liberar() → devuelve la mesa a estado LIBRE
+ * 9. Persistir cambios
  */
 @Transactional
 public class CerrarMesaUseCase {
@@ -57,6 +101,23 @@ public class CerrarMesaUseCase {
     private final MovimientoStockRepository movimientoStockRepository;
     private final MotorReglasService motorReglasService;
     private final GestorStockService gestorStockService;
+    private final ClienteRepository clienteRepository;
+    private final ProgramaPuntosRepository programaPuntosRepository;
+    private final MovimientoPuntosRepository movimientoPuntosRepository;
+    private final ProgramaPuntosService programaPuntosService;
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final RecetaRepository recetaRepository;
+    private final InsumoRepository insumoRepository;
+    private final MovimientoInsumoRepository movimientoInsumoRepository;
+    private final InventarioService inventarioService;
+    private final JornadaComercialRepository jornadaComercialRepository;
+    private final PromoCumpleaniosRepository promoCumpleaniosRepository;
+    private final GestionarCajasUseCase gestionarCajasUseCase;
+    private final GestionarPagosACuentaUseCase gestionarPagosACuentaUseCase;
+    private final GestionarSeriesComprobantesUseCase gestionarSeriesComprobantesUseCase;
+    private final GestionarTarjetasRegaloUseCase gestionarTarjetasRegaloUseCase;
+    private final RegistrarCargoCuentaCorrienteUseCase registrarCargoCuentaCorrienteUseCase;
+    private final ImputarConsumoPersonalUseCase imputarConsumoPersonalUseCase;
     private final Clock clock;
 
     public CerrarMesaUseCase(
@@ -67,6 +128,23 @@ public class CerrarMesaUseCase {
             MovimientoStockRepository movimientoStockRepository,
             MotorReglasService motorReglasService,
             GestorStockService gestorStockService,
+            ClienteRepository clienteRepository,
+            ProgramaPuntosRepository programaPuntosRepository,
+            MovimientoPuntosRepository movimientoPuntosRepository,
+            ProgramaPuntosService programaPuntosService,
+            TurnoCajaRepository turnoCajaRepository,
+            RecetaRepository recetaRepository,
+            InsumoRepository insumoRepository,
+            MovimientoInsumoRepository movimientoInsumoRepository,
+            InventarioService inventarioService,
+            JornadaComercialRepository jornadaComercialRepository,
+            PromoCumpleaniosRepository promoCumpleaniosRepository,
+            GestionarCajasUseCase gestionarCajasUseCase,
+            GestionarPagosACuentaUseCase gestionarPagosACuentaUseCase,
+            GestionarSeriesComprobantesUseCase gestionarSeriesComprobantesUseCase,
+            GestionarTarjetasRegaloUseCase gestionarTarjetasRegaloUseCase,
+            RegistrarCargoCuentaCorrienteUseCase registrarCargoCuentaCorrienteUseCase,
+            ImputarConsumoPersonalUseCase imputarConsumoPersonalUseCase,
             Clock clock
     ) {
         this.mesaRepository = Objects.requireNonNull(mesaRepository, "El mesaRepository es obligatorio");
@@ -76,23 +154,44 @@ public class CerrarMesaUseCase {
         this.movimientoStockRepository = Objects.requireNonNull(movimientoStockRepository, "El movimientoStockRepository es obligatorio");
         this.motorReglasService = Objects.requireNonNull(motorReglasService, "El motorReglasService es obligatorio");
         this.gestorStockService = Objects.requireNonNull(gestorStockService, "El gestorStockService es obligatorio");
+        this.clienteRepository = Objects.requireNonNull(clienteRepository, "El clienteRepository es obligatorio");
+        this.programaPuntosRepository = Objects.requireNonNull(programaPuntosRepository, "El programaPuntosRepository es obligatorio");
+        this.movimientoPuntosRepository = Objects.requireNonNull(movimientoPuntosRepository, "El movimientoPuntosRepository es obligatorio");
+        this.programaPuntosService = Objects.requireNonNull(programaPuntosService, "El programaPuntosService es obligatorio");
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository, "El turnoCajaRepository es obligatorio");
+        this.recetaRepository = Objects.requireNonNull(recetaRepository, "El recetaRepository es obligatorio");
+        this.insumoRepository = Objects.requireNonNull(insumoRepository, "El insumoRepository es obligatorio");
+        this.movimientoInsumoRepository = Objects.requireNonNull(movimientoInsumoRepository, "El movimientoInsumoRepository es obligatorio");
+        this.inventarioService = Objects.requireNonNull(inventarioService, "El inventarioService es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository, "El jornadaComercialRepository es obligatorio");
+        this.promoCumpleaniosRepository = Objects.requireNonNull(promoCumpleaniosRepository, "El promoCumpleaniosRepository es obligatorio");
+        this.gestionarCajasUseCase = Objects.requireNonNull(gestionarCajasUseCase, "El gestionarCajasUseCase es obligatorio");
+        this.gestionarPagosACuentaUseCase = Objects.requireNonNull(gestionarPagosACuentaUseCase, "El gestionarPagosACuentaUseCase es obligatorio");
+        this.gestionarSeriesComprobantesUseCase = Objects.requireNonNull(gestionarSeriesComprobantesUseCase, "El gestionarSeriesComprobantesUseCase es obligatorio");
+        this.gestionarTarjetasRegaloUseCase = Objects.requireNonNull(gestionarTarjetasRegaloUseCase, "El gestionarTarjetasRegaloUseCase es obligatorio");
+        this.registrarCargoCuentaCorrienteUseCase = Objects.requireNonNull(registrarCargoCuentaCorrienteUseCase, "El registrarCargoCuentaCorrienteUseCase es obligatorio");
+        this.imputarConsumoPersonalUseCase = Objects.requireNonNull(imputarConsumoPersonalUseCase, "El imputarConsumoPersonalUseCase es obligatorio");
         this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
     }
 
     /**
      * Ejecuta el caso de uso: cerrar la mesa y finalizar su pedido activo.
      *
-     * @param localId identificador del local (tenant)
-     * @param mesaId identificador de la mesa a cerrar
-     * @param pagos lista de pagos para cubrir el total
+     * @param command mesa, pagos y lo que se indica al cobrar (cliente, canje, propina, empleado, versión)
      * @return DTO con la información de la mesa liberada y el pedido cerrado
-     * @throws IllegalStateException si la mesa no existe o no tiene pedido abierto
-     * @throws IllegalArgumentException si la mesa no pertenece al local o los pagos son inválidos
+     * @throws IllegalStateException si la mesa no existe o no tiene pedido abierto,
+     *                               el cargo excede el límite de crédito del cliente (HU-104),
+     *                               el saldo de puntos no alcanza (HU-105) o el empleado está inactivo (HU-132)
+     * @throws IllegalArgumentException si la mesa no pertenece al local, los pagos son inválidos,
+     *                                  se canjean puntos sin cliente (HU-105), la propina es negativa (HU-111)
+     *                                  o se imputa a un empleado un pedido sin pagos A_CUENTA (HU-132)
+     * @throws PedidoModificadoException si otra terminal modificó el pedido (HU-165)
      */
-    public CerrarMesaResponse ejecutar(LocalId localId, MesaId mesaId, List<PagoRequest> pagos) {
-        Objects.requireNonNull(localId, "El localId es obligatorio");
-        Objects.requireNonNull(mesaId, "El mesaId es obligatorio");
-        Objects.requireNonNull(pagos, "La lista de pagos es obligatoria");
+    public CerrarMesaResponse ejecutar(CerrarMesaCommand command) {
+        Objects.requireNonNull(command, "El command es obligatorio");
+        LocalId localId = command.localId();
+        MesaId mesaId = command.mesaId();
+        List<PagoRequest> pagos = command.pagos();
 
         // 1. Recuperar la mesa
         Mesa mesa = mesaRepository.buscarPorId(mesaId)
@@ -104,33 +203,91 @@ public class CerrarMesaUseCase {
         }
 
         // 3. Buscar el pedido abierto asociado a la mesa
+        //    HU-165: solo si sigue en la versión que ve la terminal
         Pedido pedido = pedidoRepository.buscarAbiertoPorMesa(mesaId, localId)
             .orElseThrow(() -> new IllegalStateException("La mesa no tiene un pedido abierto"));
+        if (command.versionPedido() != null) {
+            pedido.verificarVersion(command.versionPedido());
+        }
 
         // 4. Re-evaluar promociones una última vez antes del cierre
         aplicarPromocionesFinales(pedido, localId);
 
-        // 5. Convertir DTOs de pago a Value Objects de dominio
+        // 5. Resolver el cliente identificado (cuenta corriente y puntos)
+        //    HU-168: si no se indica al cobrar, vale el cliente vinculado al pedido
+        if (command.clienteId() != null) {
+            pedido.vincularCliente(command.clienteId());
+        }
+        ClienteId clienteId = pedido.getClienteId();
+        Cliente cliente = clienteId != null
+            ? clienteRepository.buscarPorIdYLocal(clienteId, localId)
+                .orElseThrow(() -> new IllegalArgumentException("El cliente no existe en este local"))
+            : null;
+        ProgramaPuntos programa = cliente != null
+            ? programaPuntosRepository.buscarPorLocal(localId).orElse(ProgramaPuntos.porDefecto(localId))
+            : null;
+
+        // 6. HU-168: Promo de cumpleaños y HU-105: canje de puntos antes del cierre (reducen el total a cubrir)
         LocalDateTime ahora = LocalDateTime.now(clock);
-        List<Pago> pagosDominio = pagos.stream()
-            .map(pr -> new Pago(pr.medio(), pr.monto(), ahora))
-            .toList();
+        aplicarPromoCumpleanios(pedido, cliente, ahora);
+        MovimientoPuntos canje = aplicarCanjePuntos(pedido, cliente, programa, command.puntosACanjear(), ahora);
+
+        // 7. Convertir DTOs de pago a Value Objects de dominio, cobrados por la
+        //    caja de esta terminal (HU-159; sin caja, la principal)
+        CajaId cajaId = gestionarCajasUseCase.cajaActual(localId);
+        List<Pago> pagosDominio = gestionarPagosACuentaUseCase.convertir(localId, pagos, cajaId, ahora);
 
-        // 6. Cerrar el pedido (valida estado, ítems, montos; congela snapshot)
+        // 8. Cerrar el pedido (valida estado, ítems, montos; congela snapshot)
         pedido.cerrar(pagosDominio, ahora);
 
-        // 7. HU-22: Descontar stock de los productos vendidos (atómico con el cierre)
+        // 8b. HU-106: El cobro entra al cajón del turno activo
+        turnoCajaRepository.buscarAbierto(localId)
+            .ifPresent(turno -> pedido.asignarTurno(turno.getId()));
+        // HU-159: ...de la caja que lo cobró (sin caja, queda en la principal)
+        if (cajaId != null) {
+            pedido.asignarCaja(cajaId);
+        }
+        // HU-166: ...con el número de su serie
+        gestionarSeriesComprobantesUseCase.numerar(pedido, ahora);
+
+        // 8c. HU-111: Propina del mozo
+        if (command.propina() != null) {
+            pedido.registrarPropina(command.propina());
+        }
+
+        // 8d. HU-144: Descontar el saldo de las tarjetas de regalo usadas
+        gestionarTarjetasRegaloUseCase.cobrar(pedido, pagos, ahora);
+
+        // 9. HU-22: Descontar stock de los productos vendidos (atómico con el cierre)
         descontarStockPorVenta(pedido, localId, ahora);
 
-        // 8. Liberar la mesa
+        // 9b. HU-133: Descontar los insumos de las recetas (stock teórico)
+        descontarInsumosPorVenta(pedido, localId, ahora);
+
+        // 10. HU-104: Cargar los pagos CUENTA_CORRIENTE a la cuenta del cliente
+        registrarCargoCuentaCorrienteUseCase.registrar(pedido, mesa.getNumero(), cliente, ahora);
+
+        // 10b. HU-132: Imputar el consumo interno al empleado
+        String avisoConsumo = imputarConsumoPersonalUseCase.imputar(pedido, command.empleadoId(), ahora);
+
+        // 11. Liberar la mesa
         mesa.liberar();
 
-        // 9. Persistir cambios (transacción atómica)
+        // 12. Persistir cambios (transacción atómica)
         pedidoRepository.guardar(pedido);
         mesaRepository.guardar(mesa);
 
-        // 10. Retornar DTO de respuesta
-        return CerrarMesaResponse.fromDomain(mesa, pedido);
+        // 13. HU-105: Registrar el canje y los puntos ganados por el pedido
+        if (canje != null) {
+            movimientoPuntosRepository.guardar(canje);
+        }
+        if (cliente != null) {
+            programaPuntosService.registrarAcumulacion(programa, pedido, cliente, ahora)
+                .ifPresent(movimientoPuntosRepository::guardar);
+        }
+
+        // 14. Retornar DTO de respuesta
+        return CerrarMesaResponse.fromDomain(mesa, pedido, avisoConsumo);
     }
 
     /**
@@ -153,6 +310,63 @@ public class CerrarMesaUseCase {
         motorReglasService.aplicarPromociones(pedido, promocionesActivas, ahora);
     }
 
+    /**
+     * HU-168: Si la promo está activa y el cobro cae en la semana del cumpleaños
+     * del cliente (por día operativo, HU-153), descuenta el porcentaje sobre el
+     * total. Es una vez por cumpleaños: si otro pedido de esa semana ya la tuvo,
+     * no se repite. El descuento nunca supera lo que falta cobrar (HU-160).
+     */
+    private void aplicarPromoCumpleanios(Pedido pedido, Cliente cliente, LocalDateTime ahora) {
+        if (cliente == null) {
+            return;
+        }
+        PromoCumpleanios promo = promoCumpleaniosRepository.buscarPorLocal(pedido.getLocalId())
+            .orElse(PromoCumpleanios.porDefecto(pedido.getLocalId()));
+        if (!promo.isActiva()) {
+            return;
+        }
+
+        JornadaComercial jornada = jornadaComercialRepository.buscarPorLocal(pedido.getLocalId())
+            .orElse(JornadaComercial.porDefecto(pedido.getLocalId()));
+        Optional<LocalDate> semana = cliente.semanaDeCumpleanios(jornada.fechaOperativa(ahora));
+        if (semana.isEmpty()) {
+            return;
+        }
+
+        LocalDate desde = semana.get();
+        LocalDate hasta = desde.plusDays(7);
+        boolean yaLaUso = pedidoRepository.buscarCerradosPorCliente(cliente.getId(), pedido.getLocalId()).stream()
+            .filter(otro -> !otro.getId().equals(pedido.getId()))
+            .filter(Pedido::tienePromoCumpleanios)
+            .map(otro -> jornada.fechaOperativa(otro.getFechaCierre()))
+            .anyMatch(fecha -> !fecha.isBefore(desde) && fecha.isBefore(hasta));
+        if (yaLaUso) {
+            return;
+        }
+
+        BigDecimal descuento = promo.calcularDescuento(pedido.calcularTotal()).min(pedido.calcularSaldoPendiente());
+        pedido.aplicarPromoCumpleanios(descuento.max(BigDecimal.ZERO));
+    }
+
+    /**
+     * HU-105: Valida el saldo de puntos del cliente y aplica el canje sobre el pedido.
+     *
+     * @return el movimiento CANJE a persistir, o null si no se pidió canje
+     */
+    private MovimientoPuntos aplicarCanjePuntos(
+            Pedido pedido, Cliente cliente, ProgramaPuntos programa, Integer puntosACanjear, LocalDateTime fecha) {
+        if (puntosACanjear == null || puntosACanjear == 0) {
+            return null;
+        }
+        if (cliente == null) {
+            throw new IllegalArgumentException("Para canjear puntos hay que indicar el cliente");
+        }
+
+        List<MovimientoPuntos> movimientos =
+            movimientoPuntosRepository.buscarPorCliente(cliente.getId(), pedido.getLocalId());
+        return programaPuntosService.aplicarCanje(programa, pedido, cliente, movimientos, puntosACanjear, fecha);
+    }
+
     /**
      * HU-22: Descuenta el stock de los productos vendidos en el pedido.
      * Carga los productos involucrados, delega al GestorStockService,
@@ -183,4 +397,34 @@ public class CerrarMesaUseCase {
             movimientoStockRepository.guardar(movimiento);
         }
     }
+
+    /**
+     * HU-133: Descuenta del stock teórico los insumos de las recetas de los
+     * productos vendidos. Los productos sin receta no consumen insumos.
+     */
+    private void descontarInsumosPorVenta(Pedido pedido, LocalId localId, LocalDateTime fecha) {
+        // HU-148: También las recetas de los extras ("extra queso")
+        Map<ProductoId, Receta> recetas = pedido.getItems().stream()
+            .flatMap(item -> Stream.concat(Stream.of(item.getProductoId()),
+                item.getExtras().stream().map(ExtraPedido::getProductoId)))
+            .distinct()
+            .map(productoId -> recetaRepository.buscarPorProducto(productoId, localId))
+            .flatMap(Optional::stream)
+            .collect(Collectors.toMap(Receta::getProductoId, Function.identity()));
+
+        if (recetas.isEmpty()) {
+            return;
+        }
+
+        Map<InsumoId, Insumo> insumos = insumoRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Insumo::getId, Function.identity()));
+        ResultadoInsumos resultado = inventarioService.registrarConsumo(pedido, recetas, insumos, fecha);
+
+        for (Insumo insumo : resultado.insumosModificados()) {
+            insumoRepository.guardar(insumo);
+        }
+        for (MovimientoInsumo movimiento : resultado.movimientos()) {
+            movimientoInsumoRepository.guardar(movimiento);
+        }
+    }
 }
@@ -0,0 +1,201 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.CerrarTurnoRequest;
+import com.agustinpalma.comandas.application.dto.SaldoTeoricoTurnoResponse;
+import com.agustinpalma.comandas.application.dto.TurnoCajaResponse;
+import com.agustinpalma.comandas.domain.model.ConteoDenominacion;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Caso de uso: cerrar el turno de caja activo con su arqueo.
+ *
+ * HU-106: Gestión de turnos con arqueo por turno.
+ * HU-123: Arqueo con detalle de denominaciones y consulta del saldo teórico
+ * antes de cerrar.
+ * HU-129: Si la caja no cierra, el faltante o sobrante queda a nombre del
+ * responsable del turno, con sus observaciones.
+ * HU-141: Al cerrar se reparte el pozo de propinas del turno entre el
+ * personal que fichó, con la regla vigente del local.
+ *
+ * Suma lo cobrado y los movimientos de caja asociados al turno, recibe el
+ * efectivo que contó el cajero y congela esperado / declarado / diferencia.
+ *
+ * A diferencia del cierre de jornada, no exige que todas las mesas estén
+ * libres: las que sigan abiertas se cobran en el turno siguiente.
+ */
+@Transactional
+public class CerrarTurnoUseCase {
+
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final PedidoRepository pedidoRepository;
+    private final MovimientoCajaRepository movimientoCajaRepository;
+    private final MozoRepository mozoRepository;
+    private final DistribuirPropinasUseCase distribuirPropinasUseCase;
+    private final Clock clock;
+
+    public CerrarTurnoUseCase(TurnoCajaRepository turnoCajaRepository,
+                              PedidoRepository pedidoRepository,
+                              MovimientoCajaRepository movimientoCajaRepository,
+                              MozoRepository mozoRepository,
+                              DistribuirPropinasUseCase distribuirPropinasUseCase,
+                              Clock clock) {
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository,
+            "turnoCajaRepository es obligatorio");
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "pedidoRepository es obligatorio");
+        this.movimientoCajaRepository = Objects.requireNonNull(movimientoCajaRepository,
+            "movimientoCajaRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "mozoRepository es obligatorio");
+        this.distribuirPropinasUseCase = Objects.requireNonNull(distribuirPropinasUseCase,
+            "distribuirPropinasUseCase es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "clock es obligatorio");
+    }
+
+    /**
+     * @param localId           identificador del local (tenant)
+     * @param efectivoDeclarado efectivo contado en el cajón al entregar el turno
+     * @return el turno cerrado con su arqueo
+     * @throws IllegalStateException si no hay turno abierto
+     */
+    public TurnoCajaResponse ejecutar(LocalId localId, BigDecimal efectivoDeclarado) {
+        return ejecutar(localId, new CerrarTurnoRequest(efectivoDeclarado));
+    }
+
+    /**
+     * HU-123: Cierra con el total declarado o, si viene, con el conteo por denominación.
+     * HU-129: Registra el responsable y las observaciones del arqueo.
+     *
+     * @throws IllegalStateException si no hay turno abierto
+     * @throws IllegalArgumentException si el conteo es inválido, el responsable no
+     *         existe o hay diferencia sin responsable
+     */
+    public TurnoCajaResponse ejecutar(LocalId localId, CerrarTurnoRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        TurnoCaja turno = buscarAbierto(localId);
+        MozoId responsableId = resolverResponsable(localId, request.responsableId());
+        Totales totales = calcularTotales(localId, turno);
+
+        if (request.tieneConteo()) {
+            List<ConteoDenominacion> conteo = request.denominaciones().stream()
+                .map(d -> new ConteoDenominacion(d.denominacion(), d.cantidad()))
+                .toList();
+            turno.cerrarConConteo(
+                LocalDateTime.now(clock),
+                totales.ventas(),
+                totales.ventasEfectivo(),
+                totales.ingresos(),
+                totales.egresos(),
+                totales.pedidosCerrados(),
+                conteo
+            );
+        } else {
+            turno.cerrar(
+                LocalDateTime.now(clock),
+                totales.ventas(),
+                totales.ventasEfectivo(),
+                totales.ingresos(),
+                totales.egresos(),
+                totales.pedidosCerrados(),
+                request.efectivoDeclarado()
+            );
+        }
+        turno.registrarResponsable(responsableId, request.observaciones());
+
+        TurnoCaja cerrado = turnoCajaRepository.guardar(turno);
+        distribuirPropinasUseCase.distribuir(cerrado);
+        return TurnoCajaResponse.fromDomain(cerrado);
+    }
+
+    /**
+     * HU-123: Saldo teórico del turno abierto, sin cerrarlo.
+     *
+     * @throws IllegalStateException si no hay turno abierto
+     */
+    @Transactional(readOnly = true)
+    public SaldoTeoricoTurnoResponse calcularSaldoTeorico(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        TurnoCaja turno = buscarAbierto(localId);
+        Totales totales = calcularTotales(localId, turno);
+
+        return new SaldoTeoricoTurnoResponse(
+            turno.getId().getValue().toString(),
+            turno.getTipo(),
+            turno.getMontoInicial(),
+            totales.ventasEfectivo(),
+            totales.ingresos(),
+            totales.egresos(),
+            turno.getMontoInicial()
+                .add(totales.ventasEfectivo())
+                .add(totales.ingresos())
+                .subtract(totales.egresos())
+        );
+    }
+
+    private TurnoCaja buscarAbierto(LocalId localId) {
+        return turnoCajaRepository.buscarAbierto(localId)
+            .orElseThrow(() -> new IllegalStateException("No hay un turno abierto para cerrar"));
+    }
+
+    private MozoId resolverResponsable(LocalId localId, String responsableId) {
+        if (responsableId == null || responsableId.isBlank()) {
+            return null;
+        }
+        return mozoRepository.buscarPorId(MozoId.from(responsableId), localId)
+            .orElseThrow(() -> new IllegalArgumentException("El responsable no existe en este local"))
+            .getId();
+    }
+
+    private Totales calcularTotales(LocalId localId, TurnoCaja turno) {
+        List<Pedido> pedidos = pedidoRepository.buscarCerradosPorTurno(localId, turno.getId());
+        List<MovimientoCaja> movimientos = movimientoCajaRepository.buscarPorTurno(localId, turno.getId());
+
+        BigDecimal totalVentas = BigDecimal.ZERO;
+        BigDecimal ventasEfectivo = BigDecimal.ZERO;
+        for (Pedido pedido : pedidos) {
+            for (Pago pago : pedido.getPagos()) {
+                if (pago.getMedio() != MedioPago.A_CUENTA) {
+                    totalVentas = totalVentas.add(pago.getMonto());
+                }
+                if (pago.getMedio() == MedioPago.EFECTIVO) {
+                    // HU-143: el recargo del efectivo también entra al cajón
+                    ventasEfectivo = ventasEfectivo.add(pago.getMontoCobrado());
+                }
+            }
+        }
+
+        BigDecimal totalIngresos = movimientos.stream()
+            .filter(MovimientoCaja::esIngreso)
+            .map(MovimientoCaja::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        BigDecimal totalEgresos = movimientos.stream()
+            .filter(MovimientoCaja::esEgreso)
+            .map(MovimientoCaja::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        return new Totales(totalVentas, ventasEfectivo, totalIngresos, totalEgresos, pedidos.size());
+    }
+
+    private record Totales(BigDecimal ventas, BigDecimal ventasEfectivo,
+                           BigDecimal ingresos, BigDecimal egresos, int pedidosCerrados) {
+    }
+}
@@ -0,0 +1,132 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.CartaAutopedidoResponse;
+import com.agustinpalma.comandas.application.dto.CartaAutopedidoResponse.CategoriaCarta;
+import com.agustinpalma.comandas.application.dto.CartaAutopedidoResponse.ProductoCarta;
+import com.agustinpalma.comandas.domain.model.Categoria;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.ListaPrecios;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.CategoriaRepository;
+import com.agustinpalma.comandas.domain.repository.ListaPreciosRepository;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalTime;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * Caso de uso que arma la carta del QR de una mesa.
+ *
+ * HU-126: Es lo único que ve el cliente del sistema, así que solo expone
+ * nombre, precio y color de lo que puede pedir solo
+ * ({@link Producto#admiteAutopedido()}). Los precios salen de la lista del
+ * salón si está configurada, igual que en el pedido que abre el mozo.
+ *
+ * HU-161: Lo que está fuera de horario no se lista.
+ */
+@Transactional(readOnly = true)
+public class ConsultarCartaAutopedidoUseCase {
+
+    private static final String CATEGORIA_SIN_NOMBRE = "Otros";
+
+    private final MesaRepository mesaRepository;
+    private final ProductoRepository productoRepository;
+    private final CategoriaRepository categoriaRepository;
+    private final ListaPreciosRepository listaPreciosRepository;
+    private final MeisenProperties properties;
+    private final Clock clock;
+
+    public ConsultarCartaAutopedidoUseCase(
+            MesaRepository mesaRepository,
+            ProductoRepository productoRepository,
+            CategoriaRepository categoriaRepository,
+            ListaPreciosRepository listaPreciosRepository,
+            MeisenProperties properties,
+            Clock clock
+    ) {
+        this.mesaRepository = Objects.requireNonNull(mesaRepository, "El mesaRepository es obligatorio");
+        this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+        this.categoriaRepository = Objects.requireNonNull(categoriaRepository, "El categoriaRepository es obligatorio");
+        this.listaPreciosRepository = Objects.requireNonNull(listaPreciosRepository, "El listaPreciosRepository es obligatorio");
+        this.properties = Objects.requireNonNull(properties, "Las properties son obligatorias");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si la mesa no existe en el local (QR viejo o de otro local)
+     */
+    public CartaAutopedidoResponse ejecutar(LocalId localId, MesaId mesaId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(mesaId, "El mesaId es obligatorio");
+
+        Mesa mesa = mesaRepository.buscarPorId(mesaId)
+            .filter(m -> m.getLocalId().equals(localId))
+            .orElseThrow(() -> new IllegalArgumentException("Este QR ya no corresponde a ninguna mesa. Pedile ayuda al mozo"));
+
+        ListaPrecios listaSalon = listaPreciosRepository.buscarPorLocalYCanal(localId, CanalVenta.SALON)
+            .orElse(null);
+
+        List<Categoria> categorias = categoriaRepository.buscarPorLocal(localId).stream()
+            .sorted(Comparator.comparingInt(Categoria::getOrden))
+            .toList();
+        Map<CategoriaId, Categoria> categoriasPorId = categorias.stream()
+            .collect(Collectors.toMap(Categoria::getId, Function.identity()));
+        LocalTime ahora = LocalTime.now(clock);
+
+        // Agrupar por categoría respetando el orden del POS; lo que no tiene categoría va al final
+        Map<CategoriaId, List<ProductoCarta>> porCategoria = new LinkedHashMap<>();
+        categorias.forEach(c -> porCategoria.put(c.getId(), new ArrayList<>()));
+        List<ProductoCarta> sinCategoria = new ArrayList<>();
+
+        productoRepository.buscarPorLocal(localId).stream()
+            .filter(Producto::admiteAutopedido)
+            .filter(producto -> producto.seOfreceA(ahora, categoriasPorId.get(producto.getCategoriaId())))
+            .sorted(Comparator.comparing(Producto::getNombre, String.CASE_INSENSITIVE_ORDER))
+            .forEach(producto -> {
+                ProductoCarta item = new ProductoCarta(
+                    producto.getId().getValue(),
+                    producto.getNombre(),
+                    precioSalon(producto, listaSalon),
+                    producto.getColorHex()
+                );
+                porCategoria.getOrDefault(producto.getCategoriaId(), sinCategoria).add(item);
+            });
+
+        List<CategoriaCarta> resultado = new ArrayList<>();
+        for (Categoria categoria : categorias) {
+            List<ProductoCarta> productos = porCategoria.get(categoria.getId());
+            if (!productos.isEmpty()) {
+                resultado.add(new CategoriaCarta(categoria.getNombre(), productos));
+            }
+        }
+        if (!sinCategoria.isEmpty()) {
+            resultado.add(new CategoriaCarta(CATEGORIA_SIN_NOMBRE, sinCategoria));
+        }
+
+        return new CartaAutopedidoResponse(
+            properties.getLocal().getNombreLocal(),
+            mesa.getNumero(),
+            resultado
+        );
+    }
+
+    static BigDecimal precioSalon(Producto producto, ListaPrecios listaSalon) {
+        return listaSalon != null ? listaSalon.precioPara(producto) : producto.getPrecio();
+    }
+}
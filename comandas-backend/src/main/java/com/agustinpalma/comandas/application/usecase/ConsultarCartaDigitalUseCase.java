@@ -0,0 +1,131 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.CartaDigitalResponse;
+import com.agustinpalma.comandas.application.dto.CartaDigitalResponse.CategoriaMenu;
+import com.agustinpalma.comandas.application.dto.CartaDigitalResponse.ProductoMenu;
+import com.agustinpalma.comandas.domain.model.Categoria;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.ListaPrecios;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.CategoriaRepository;
+import com.agustinpalma.comandas.domain.repository.ListaPreciosRepository;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.time.LocalTime;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * Caso de uso que genera la carta digital a partir del catálogo.
+ *
+ * HU-127: No hay una carta "publicada" que mantener: cada consulta se arma
+ * con los precios, fotos y disponibilidad del momento, así que un cambio de
+ * precio o un 86 se ve en la próxima actualización de la página.
+ *
+ * Los precios salen de la lista del salón si está configurada, igual que en
+ * la carta del autopedido.
+ *
+ * HU-161: Lo que está fuera de horario no se lista (el desayuno desaparece a
+ * las 11 y vuelve al otro día).
+ */
+@Transactional(readOnly = true)
+public class ConsultarCartaDigitalUseCase {
+
+    private static final String CATEGORIA_SIN_NOMBRE = "Otros";
+    private static final String COLOR_SIN_CATEGORIA = "#FFFFFF";
+
+    private final MesaRepository mesaRepository;
+    private final ProductoRepository productoRepository;
+    private final CategoriaRepository categoriaRepository;
+    private final ListaPreciosRepository listaPreciosRepository;
+    private final MeisenProperties properties;
+    private final Clock clock;
+
+    public ConsultarCartaDigitalUseCase(
+            MesaRepository mesaRepository,
+            ProductoRepository productoRepository,
+            CategoriaRepository categoriaRepository,
+            ListaPreciosRepository listaPreciosRepository,
+            MeisenProperties properties,
+            Clock clock
+    ) {
+        this.mesaRepository = Objects.requireNonNull(mesaRepository, "El mesaRepository es obligatorio");
+        this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+        this.categoriaRepository = Objects.requireNonNull(categoriaRepository, "El categoriaRepository es obligatorio");
+        this.listaPreciosRepository = Objects.requireNonNull(listaPreciosRepository, "El listaPreciosRepository es obligatorio");
+        this.properties = Objects.requireNonNull(properties, "Las properties son obligatorias");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @param mesaId mesa del QR escaneado (opcional). Si ya no existe se muestra
+     *               la carta igual, sin número de mesa: es solo para mirar.
+     */
+    public CartaDigitalResponse ejecutar(LocalId localId, MesaId mesaId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        Integer numeroMesa = mesaId == null ? null : mesaRepository.buscarPorId(mesaId)
+            .filter(m -> m.getLocalId().equals(localId))
+            .map(Mesa::getNumero)
+            .orElse(null);
+
+        ListaPrecios listaSalon = listaPreciosRepository.buscarPorLocalYCanal(localId, CanalVenta.SALON)
+            .orElse(null);
+        Map<ProductoId, LocalDateTime> versionesFotos = productoRepository.buscarVersionesFotos(localId);
+
+        List<Categoria> categorias = categoriaRepository.buscarPorLocal(localId).stream()
+            .sorted(Comparator.comparingInt(Categoria::getOrden))
+            .toList();
+        Map<CategoriaId, Categoria> categoriasPorId = categorias.stream()
+            .collect(Collectors.toMap(Categoria::getId, Function.identity()));
+        LocalTime ahora = LocalTime.now(clock);
+
+        Map<CategoriaId, List<ProductoMenu>> porCategoria = new LinkedHashMap<>();
+        categorias.forEach(c -> porCategoria.put(c.getId(), new ArrayList<>()));
+        List<ProductoMenu> sinCategoria = new ArrayList<>();
+
+        productoRepository.buscarPorLocal(localId).stream()
+            .filter(Producto::apareceEnCartaDigital)
+            .filter(producto -> producto.seOfreceA(ahora, categoriasPorId.get(producto.getCategoriaId())))
+            .sorted(Comparator.comparing(Producto::getNombre, String.CASE_INSENSITIVE_ORDER))
+            .forEach(producto -> {
+                ProductoMenu item = new ProductoMenu(
+                    producto.getId().getValue(),
+                    producto.getNombre(),
+                    ConsultarCartaAutopedidoUseCase.precioSalon(producto, listaSalon),
+                    versionesFotos.get(producto.getId()),
+                    producto.estaDisponible()
+                );
+                porCategoria.getOrDefault(producto.getCategoriaId(), sinCategoria).add(item);
+            });
+
+        List<CategoriaMenu> resultado = new ArrayList<>();
+        for (Categoria categoria : categorias) {
+            List<ProductoMenu> productos = porCategoria.get(categoria.getId());
+            if (!productos.isEmpty()) {
+                resultado.add(new CategoriaMenu(categoria.getNombre(), categoria.getColorHex(), productos));
+            }
+        }
+        if (!sinCategoria.isEmpty()) {
+            resultado.add(new CategoriaMenu(CATEGORIA_SIN_NOMBRE, COLOR_SIN_CATEGORIA, sinCategoria));
+        }
+
+        return new CartaDigitalResponse(properties.getLocal().getNombreLocal(), numeroMesa, resultado);
+    }
+}
@@ -0,0 +1,48 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ClienteResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.MovimientoCuentaCorriente;
+import com.agustinpalma.comandas.domain.repository.ClienteRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCuentaCorrienteRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Caso de uso para listar los clientes con cuenta corriente y su estado de deuda.
+ *
+ * HU-104: Cuenta corriente de clientes.
+ */
+@Transactional(readOnly = true)
+public class ConsultarClientesUseCase {
+
+    private final ClienteRepository clienteRepository;
+    private final MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository;
+
+    public ConsultarClientesUseCase(
+            ClienteRepository clienteRepository,
+            MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository
+    ) {
+        this.clienteRepository = Objects.requireNonNull(clienteRepository, "El clienteRepository es obligatorio");
+        this.movimientoCuentaCorrienteRepository = Objects.requireNonNull(movimientoCuentaCorrienteRepository, "El movimientoCuentaCorrienteRepository es obligatorio");
+    }
+
+    /**
+     * @param localId identificador del local (tenant)
+     * @return clientes ordenados por nombre, cada uno con su deuda y crédito disponible
+     */
+    public List<ClienteResponse> ejecutar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        return clienteRepository.buscarPorLocal(localId).stream()
+            .map(cliente -> ClienteResponse.fromDomain(
+                cliente,
+                MovimientoCuentaCorriente.calcularSaldo(
+                    movimientoCuentaCorrienteRepository.buscarPorCliente(cliente.getId(), localId)
+                )
+            ))
+            .toList();
+    }
+}
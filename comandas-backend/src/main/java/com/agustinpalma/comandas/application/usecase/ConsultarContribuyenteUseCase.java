@@ -0,0 +1,63 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ContribuyenteResponse;
+import com.agustinpalma.comandas.application.ports.output.ModuloFiscalPort;
+import com.agustinpalma.comandas.application.ports.output.ModuloFiscalPort.Contribuyente;
+import com.agustinpalma.comandas.application.ports.output.ModuloFiscalPort.ModuloFiscalNoDisponibleException;
+import com.agustinpalma.comandas.domain.model.Cuit;
+import com.agustinpalma.comandas.domain.model.Factura;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+
+import java.util.Objects;
+
+/**
+ * HU-115: Caso de uso para validar el CUIT/CUIL del cliente antes de facturar.
+ *
+ * 1. Valida formato y dígito verificador (sin red)
+ * 2. Consulta el padrón para traer razón social y condición frente al IVA
+ * 3. Sugiere la letra según la condición del local y la del cliente
+ *
+ * Si el padrón no está disponible no se bloquea la venta: se devuelve el CUIT
+ * validado localmente y el operador completa los datos a mano.
+ */
+public class ConsultarContribuyenteUseCase {
+
+    private static final Logger log = LoggerFactory.getLogger(ConsultarContribuyenteUseCase.class);
+
+    private final ModuloFiscalPort moduloFiscal;
+    private final MeisenProperties properties;
+
+    public ConsultarContribuyenteUseCase(ModuloFiscalPort moduloFiscal, MeisenProperties properties) {
+        this.moduloFiscal = Objects.requireNonNull(moduloFiscal, "El moduloFiscal es obligatorio");
+        this.properties = Objects.requireNonNull(properties, "Las properties son obligatorias");
+    }
+
+    /**
+     * @param cuitIngresado CUIT/CUIL tal como lo tipeó el operador
+     * @throws IllegalArgumentException si el CUIT es inválido o no figura en el padrón
+     */
+    public ContribuyenteResponse ejecutar(String cuitIngresado) {
+        Cuit cuit = Cuit.of(cuitIngresado);
+
+        Contribuyente contribuyente;
+        try {
+            contribuyente = moduloFiscal.consultarPadron(cuit)
+                .orElseThrow(() -> new IllegalArgumentException(
+                    String.format("El CUIT %s no figura en el padrón", cuit.formateado())
+                ));
+        } catch (ModuloFiscalNoDisponibleException e) {
+            log.warn("Padrón no disponible al validar CUIT {}: {}", cuit.formateado(), e.getMessage());
+            return new ContribuyenteResponse(cuit.formateado(), false, null, null, null);
+        }
+
+        return new ContribuyenteResponse(
+            cuit.formateado(),
+            true,
+            contribuyente.razonSocial(),
+            contribuyente.condicionIva(),
+            Factura.determinarTipo(properties.getFiscal().getCondicionIva(), contribuyente.condicionIva())
+        );
+    }
+}
@@ -0,0 +1,229 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.DashboardVentasResponse;
+import com.agustinpalma.comandas.application.dto.DashboardVentasResponse.MedioPagoVenta;
+import com.agustinpalma.comandas.application.dto.DashboardVentasResponse.MesasAbiertas;
+import com.agustinpalma.comandas.application.dto.DashboardVentasResponse.VentaPorHora;
+import com.agustinpalma.comandas.application.dto.ProductoVendidoReporte;
+import com.agustinpalma.comandas.application.ports.output.AnalyticsRepositoryPort;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.JornadaCaja;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.EnumMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+
+/**
+ * Caso de uso: tablero de ventas en vivo de la jornada.
+ *
+ * HU-114: Venta acumulada contra el mismo día de la semana pasada, ventas por
+ * hora, top 10 de productos, mezcla de medios de pago y mesas abiertas, en
+ * una sola respuesta para que el frontend no recalcule nada.
+ *
+ * La jornada va de la hora de corte del local a la del día siguiente (misma
+ * regla de turno noche que {@link JornadaCaja#calcularFechaOperativa}; HU-153,
+ * 06:00 si no la configuró), así lo cobrado a la 01:00 suma a la noche anterior. Como en el reporte de caja, la venta es lo
+ * cobrado sin el consumo interno (A_CUENTA), imputado a la hora de cierre.
+ */
+@Transactional(readOnly = true)
+public class ConsultarDashboardVentasUseCase {
+
+    private static final int TOP_PRODUCTOS = 10;
+    private static final BigDecimal CIEN = new BigDecimal("100");
+
+    private final PedidoRepository pedidoRepository;
+    private final MesaRepository mesaRepository;
+    private final AnalyticsRepositoryPort analyticsRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
+    private final Clock clock;
+
+    public ConsultarDashboardVentasUseCase(
+            PedidoRepository pedidoRepository,
+            MesaRepository mesaRepository,
+            AnalyticsRepositoryPort analyticsRepository,
+            JornadaComercialRepository jornadaComercialRepository,
+            Clock clock
+    ) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.mesaRepository = Objects.requireNonNull(mesaRepository, "El mesaRepository es obligatorio");
+        this.analyticsRepository = Objects.requireNonNull(analyticsRepository, "El analyticsRepository es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository, "El jornadaComercialRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    public DashboardVentasResponse ejecutar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        JornadaComercial jornada = jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId));
+        LocalDate fechaOperativa = jornada.fechaOperativa(ahora);
+        LocalDateTime inicio = jornada.inicio(fechaOperativa);
+        LocalDateTime fin = inicio.plusDays(1);
+        int horaInicio = jornada.getHoraCorte().getHour();
+
+        List<Pedido> hoy = cerradosEntre(localId, inicio, fin);
+        List<Pedido> semanaPasada = cerradosEntre(localId, inicio.minusWeeks(1), fin.minusWeeks(1));
+        LocalDateTime mismaHoraSemanaPasada = ahora.minusWeeks(1);
+
+        BigDecimal ventaAcumulada = BigDecimal.ZERO;
+        int cantidadPedidos = 0;
+        Map<MedioPago, BigDecimal> porMedio = new EnumMap<>(MedioPago.class);
+        BigDecimal[] porHora = new BigDecimal[24];
+        BigDecimal[] porHoraSemanaPasada = new BigDecimal[24];
+
+        for (Pedido pedido : hoy) {
+            BigDecimal venta = BigDecimal.ZERO;
+            for (Pago pago : pedido.getPagos()) {
+                if (pago.getMedio() != MedioPago.A_CUENTA) {
+                    venta = venta.add(pago.getMonto());
+                    porMedio.merge(pago.getMedio(), pago.getMonto(), BigDecimal::add);
+                }
+            }
+            ventaAcumulada = ventaAcumulada.add(venta);
+            if (venta.signum() > 0) {
+                cantidadPedidos++;
+            }
+            acumular(porHora, pedido.getFechaCierre().getHour(), venta);
+        }
+
+        BigDecimal ventaSemanaPasadaTotal = BigDecimal.ZERO;
+        BigDecimal ventaSemanaPasadaMismaHora = BigDecimal.ZERO;
+        for (Pedido pedido : semanaPasada) {
+            BigDecimal venta = ventaReal(pedido);
+            ventaSemanaPasadaTotal = ventaSemanaPasadaTotal.add(venta);
+            if (!pedido.getFechaCierre().isAfter(mismaHoraSemanaPasada)) {
+                ventaSemanaPasadaMismaHora = ventaSemanaPasadaMismaHora.add(venta);
+            }
+            acumular(porHoraSemanaPasada, pedido.getFechaCierre().getHour(), venta);
+        }
+
+        List<ProductoVendidoReporte> topProductos = analyticsRepository
+            .obtenerVentasPorProductoEntre(inicio, fin, localId).stream()
+            .limit(TOP_PRODUCTOS)
+            .toList();
+
+        return new DashboardVentasResponse(
+            fechaOperativa,
+            ahora,
+            ventaAcumulada,
+            ventaSemanaPasadaMismaHora,
+            ventaSemanaPasadaTotal,
+            variacion(ventaAcumulada, ventaSemanaPasadaMismaHora),
+            cantidadPedidos,
+            cantidadPedidos == 0
+                ? BigDecimal.ZERO
+                : ventaAcumulada.divide(BigDecimal.valueOf(cantidadPedidos), 2, RoundingMode.HALF_UP),
+            ventasPorHora(porHora, porHoraSemanaPasada, ahora.getHour(), horaInicio),
+            topProductos,
+            mediosPago(porMedio, ventaAcumulada),
+            mesasAbiertas(localId)
+        );
+    }
+
+    /** buscarCerradosPorFecha es inclusive en ambos extremos: se descarta el borde superior */
+    private List<Pedido> cerradosEntre(LocalId localId, LocalDateTime inicio, LocalDateTime fin) {
+        return pedidoRepository.buscarCerradosPorFecha(localId, inicio, fin).stream()
+            .filter(p -> p.getFechaCierre() != null && p.getFechaCierre().isBefore(fin))
+            .toList();
+    }
+
+    private static BigDecimal ventaReal(Pedido pedido) {
+        return pedido.getPagos().stream()
+            .filter(pago -> pago.getMedio() != MedioPago.A_CUENTA)
+            .map(Pago::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    private static void acumular(BigDecimal[] porHora, int hora, BigDecimal monto) {
+        porHora[hora] = porHora[hora] == null ? monto : porHora[hora].add(monto);
+    }
+
+    private static BigDecimal variacion(BigDecimal actual, BigDecimal anterior) {
+        if (anterior.signum() == 0) {
+            return null;
+        }
+        return actual.subtract(anterior).multiply(CIEN).divide(anterior, 2, RoundingMode.HALF_UP);
+    }
+
+    /**
+     * Recorre las horas en orden de jornada (ej: 06 → 05) desde la primera con ventas
+     * hasta la última con ventas o la hora actual, sin huecos, para que el
+     * gráfico tenga un eje continuo.
+     */
+    private static List<VentaPorHora> ventasPorHora(BigDecimal[] hoy, BigDecimal[] semanaPasada, int horaActual,
+                                                    int horaInicio) {
+        int primera = -1;
+        int ultima = posicionEnJornada(horaActual, horaInicio);
+        for (int posicion = 0; posicion < 24; posicion++) {
+            int hora = horaDePosicion(posicion, horaInicio);
+            if (hoy[hora] != null || semanaPasada[hora] != null) {
+                if (primera < 0) {
+                    primera = posicion;
+                }
+                ultima = Math.max(ultima, posicion);
+            }
+        }
+        if (primera < 0) {
+            return List.of();
+        }
+
+        List<VentaPorHora> filas = new ArrayList<>();
+        for (int posicion = primera; posicion <= ultima; posicion++) {
+            int hora = horaDePosicion(posicion, horaInicio);
+            filas.add(new VentaPorHora(
+                hora,
+                hoy[hora] != null ? hoy[hora] : BigDecimal.ZERO,
+                semanaPasada[hora] != null ? semanaPasada[hora] : BigDecimal.ZERO
+            ));
+        }
+        return filas;
+    }
+
+    private static int posicionEnJornada(int hora, int horaInicio) {
+        return (hora - horaInicio + 24) % 24;
+    }
+
+    private static int horaDePosicion(int posicion, int horaInicio) {
+        return (posicion + horaInicio) % 24;
+    }
+
+    private static List<MedioPagoVenta> mediosPago(Map<MedioPago, BigDecimal> porMedio, BigDecimal total) {
+        return porMedio.entrySet().stream()
+            .map(entry -> new MedioPagoVenta(
+                entry.getKey(),
+                entry.getValue(),
+                total.signum() == 0
+                    ? BigDecimal.ZERO
+                    : entry.getValue().multiply(CIEN).divide(total, 2, RoundingMode.HALF_UP)
+            ))
+            .sorted(Comparator.comparing(MedioPagoVenta::monto).reversed())
+            .toList();
+    }
+
+    private MesasAbiertas mesasAbiertas(LocalId localId) {
+        List<Pedido> abiertos = pedidoRepository.buscarAbiertosPorLocal(localId);
+        BigDecimal enCurso = abiertos.stream()
+            .map(Pedido::calcularTotal)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+        int cantidad = (int) abiertos.stream().map(Pedido::getMesaId).distinct().count();
+
+        return new MesasAbiertas(cantidad, mesaRepository.buscarPorLocal(localId).size(), enCurso);
+    }
+}
@@ -0,0 +1,37 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.DatosTransferenciaResponse;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso para consultar los datos de la cuenta del local para cobrar
+ * por transferencia.
+ *
+ * HU-156: El cobro los muestra con un botón de copiar para que el cajero se
+ * los mande al cliente sin dictarlos. Salen de la configuración del local
+ * (app.context.local), igual que los datos del ticket.
+ */
+public class ConsultarDatosTransferenciaUseCase {
+
+    private final MeisenProperties properties;
+
+    public ConsultarDatosTransferenciaUseCase(MeisenProperties properties) {
+        this.properties = Objects.requireNonNull(properties, "Las properties son obligatorias");
+    }
+
+    public DatosTransferenciaResponse ejecutar() {
+        MeisenProperties.LocalProperties local = properties.getLocal();
+        return new DatosTransferenciaResponse(
+            local.getNombreLocal(),
+            local.getCuit(),
+            vacioComoNull(local.getAliasTransferencia()),
+            vacioComoNull(local.getCvu())
+        );
+    }
+
+    private static String vacioComoNull(String valor) {
+        return valor == null || valor.isBlank() ? null : valor.strip();
+    }
+}
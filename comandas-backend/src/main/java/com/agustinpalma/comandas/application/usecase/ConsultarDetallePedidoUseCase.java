@@ -1,6 +1,7 @@
 package com.agustinpalma.comandas.application.usecase;
 
 import com.agustinpalma.comandas.application.dto.AjusteEconomicoDTO;
+import com.agustinpalma.comandas.application.dto.ComponenteComboDetalleDTO;
 import com.agustinpalma.comandas.application.dto.DetallePedidoResponse;
 import com.agustinpalma.comandas.application.dto.ExtraDetalleDTO;
 import com.agustinpalma.comandas.application.dto.ItemDetalleDTO;
@@ -8,6 +9,7 @@ import com.agustinpalma.comandas.domain.model.AjusteEconomico;
 import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoMesa;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.IngredienteQuitado;
 import com.agustinpalma.comandas.domain.model.ItemPedido;
 import com.agustinpalma.comandas.domain.model.Mesa;
 import com.agustinpalma.comandas.domain.model.Pedido;
@@ -122,7 +124,8 @@ public class ConsultarDetallePedidoUseCase {
                 a.getTipo().name(),
                 a.getAmbito().name(),
                 a.getDescripcion(),
-                a.getMonto()
+                a.getMonto(),
+                a.getAutorizadoPor()
             ))
             .toList();
 
@@ -145,7 +148,22 @@ public class ConsultarDetallePedidoUseCase {
             subtotal,
             totalDescuentos,
             totalParcial,
-            ajustesDTO
+            ajustesDTO,
+            pedido.getCanal().name(),
+            pedido.getNombreListaPrecios(),
+            pedido.getTiempoEnMarcha() != null ? pedido.getTiempoEnMarcha().name() : null,
+            pedido.siguienteTiempoPendiente().map(Enum::name).orElse(null),
+            pedido.isUrgente(),
+            pedido.getHoraPrometida(),
+            pedido.getDireccionEntrega(),
+            pedido.getZonaDeliveryId() != null ? pedido.getZonaDeliveryId().getValue().toString() : null,
+            pedido.getRepartidorId() != null ? pedido.getRepartidorId().getValue().toString() : null,
+            pedido.getSalidaReparto(),
+            pedido.getMontoSenia(),
+            pedido.calcularPagadoACuenta(),
+            pedido.calcularSaldoPendiente(),
+            pedido.getVersion(),
+            pedido.getClienteId() != null ? pedido.getClienteId().getValue().toString() : null
         );
     }
 
@@ -184,6 +202,15 @@ public class ConsultarDetallePedidoUseCase {
             ))
             .toList();
 
+        // HU-103: Opciones elegidas del combo
+        List<ComponenteComboDetalleDTO> componentesComboDTO = item.getComponentesCombo().stream()
+            .map(seleccion -> new ComponenteComboDetalleDTO(
+                seleccion.getComponente(),
+                seleccion.getProductoId().getValue().toString(),
+                seleccion.getNombreProducto()
+            ))
+            .toList();
+
         // Regla única: ¿puede recibir un modificador estructural como extra?
         // true si no tiene grupo de variantes (sin restricción) o si está en el máximo del grupo.
         boolean puedeAgregarDiscoExtra = true;
@@ -208,7 +235,15 @@ public class ConsultarDetallePedidoUseCase {
             item.tienePromocion() || item.tieneDescuentoManual(),
             extrasDTO,
             puedeAgregarDiscoExtra,
-            pedido.esItemNuevo(item)
+            pedido.esItemNuevo(item),
+            componentesComboDTO,
+            item.getTiempoServicio() != null ? item.getTiempoServicio().name() : null,
+            item.tieneCantidadNueva() && pedido.estaRetenido(item),
+            item.getCantidadEnviadaCocina() > 0,
+            item.isUrgente(),
+            item.isEnvio(),
+            item.getAdvertencias().stream().map(Enum::name).toList(),
+            item.getIngredientesQuitados().stream().map(IngredienteQuitado::getNombre).toList()
         );
     }
 }
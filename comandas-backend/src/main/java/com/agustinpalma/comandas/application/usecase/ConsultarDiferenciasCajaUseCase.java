@@ -0,0 +1,163 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteDiferenciasCajaResponse;
+import com.agustinpalma.comandas.application.dto.ReporteDiferenciasCajaResponse.CajeroResumen;
+import com.agustinpalma.comandas.application.dto.ReporteDiferenciasCajaResponse.DiferenciaTurno;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Optional;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * Caso de uso: histórico de diferencias de caja por cajero.
+ *
+ * HU-129: Registro de faltantes y sobrantes de caja.
+ *
+ * Toma los turnos cerrados en el rango y agrupa sus arqueos por responsable.
+ * Un cajero que falta seguido, aunque sea poco, queda arriba de la lista:
+ * el orden es por faltante acumulado, no por neto, para que los sobrantes
+ * no tapen los faltantes.
+ */
+@Transactional(readOnly = true)
+public class ConsultarDiferenciasCajaUseCase {
+
+    private static final String SIN_RESPONSABLE = "Sin responsable";
+
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final MozoRepository mozoRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
+
+    public ConsultarDiferenciasCajaUseCase(TurnoCajaRepository turnoCajaRepository, MozoRepository mozoRepository,
+                                           JornadaComercialRepository jornadaComercialRepository) {
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository, "El turnoCajaRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository, "El jornadaComercialRepository es obligatorio");
+    }
+
+    /**
+     * @param localId tenant que consulta
+     * @param desde   primera jornada comercial del rango (inclusive, HU-153)
+     * @param hasta   última jornada comercial del rango (inclusive)
+     * @throws IllegalArgumentException si el rango está invertido
+     */
+    public ReporteDiferenciasCajaResponse ejecutar(LocalId localId, LocalDate desde, LocalDate hasta) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(desde, "La fecha desde es obligatoria");
+        Objects.requireNonNull(hasta, "La fecha hasta es obligatoria");
+        if (hasta.isBefore(desde)) {
+            throw new IllegalArgumentException("La fecha hasta no puede ser anterior a la fecha desde");
+        }
+
+        JornadaComercial jornada = jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId));
+        LocalDateTime inicio = jornada.inicio(desde);
+        LocalDateTime fin = jornada.inicio(hasta.plusDays(1));
+
+        // buscarCerradosPorFecha es inclusive en ambos extremos: se descarta el borde superior
+        List<TurnoCaja> turnos = turnoCajaRepository.buscarCerradosPorFecha(localId, inicio, fin).stream()
+            .filter(t -> t.getFechaCierre().isBefore(fin))
+            .toList();
+
+        Map<MozoId, Mozo> mozos = turnos.isEmpty()
+            ? Map.of()
+            : mozoRepository.buscarPorLocal(localId).stream()
+                .collect(Collectors.toMap(Mozo::getId, Function.identity()));
+
+        Map<Optional<MozoId>, List<TurnoCaja>> porResponsable = new LinkedHashMap<>();
+        for (TurnoCaja turno : turnos) {
+            porResponsable.computeIfAbsent(Optional.ofNullable(turno.getResponsableId()), id -> new ArrayList<>())
+                .add(turno);
+        }
+
+        List<CajeroResumen> cajeros = new ArrayList<>();
+        porResponsable.entrySet().stream()
+            .filter(entry -> entry.getKey().isPresent())
+            .map(entry -> resumen(entry.getKey().get(), nombreDe(mozos, entry.getKey().get()), entry.getValue()))
+            .sorted(Comparator.comparing(CajeroResumen::totalFaltante).reversed()
+                .thenComparing(CajeroResumen::nombre))
+            .forEach(cajeros::add);
+
+        List<TurnoCaja> sinResponsable = porResponsable.get(Optional.<MozoId>empty());
+        if (sinResponsable != null) {
+            cajeros.add(resumen(null, SIN_RESPONSABLE, sinResponsable));
+        }
+
+        List<DiferenciaTurno> diferencias = turnos.stream()
+            .filter(TurnoCaja::tieneDiferencia)
+            .map(t -> new DiferenciaTurno(
+                t.getId().getValue(),
+                t.getTipo(),
+                t.getFechaCierre(),
+                t.getResponsableId() != null ? t.getResponsableId().getValue() : null,
+                t.getResponsableId() != null ? nombreDe(mozos, t.getResponsableId()) : SIN_RESPONSABLE,
+                t.getEfectivoEsperado(),
+                t.getEfectivoDeclarado(),
+                t.getDiferencia(),
+                t.getObservaciones()
+            ))
+            .toList();
+
+        BigDecimal totalFaltante = cajeros.stream().map(CajeroResumen::totalFaltante).reduce(BigDecimal.ZERO, BigDecimal::add);
+        BigDecimal totalSobrante = cajeros.stream().map(CajeroResumen::totalSobrante).reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        return new ReporteDiferenciasCajaResponse(desde, hasta, cajeros, diferencias, totalFaltante, totalSobrante);
+    }
+
+    private static String nombreDe(Map<MozoId, Mozo> mozos, MozoId mozoId) {
+        Mozo mozo = mozos.get(mozoId);
+        return mozo != null ? mozo.getNombre() : SIN_RESPONSABLE;
+    }
+
+    private static CajeroResumen resumen(MozoId responsableId, String nombre, List<TurnoCaja> turnos) {
+        int conFaltante = 0;
+        int conSobrante = 0;
+        BigDecimal faltante = BigDecimal.ZERO;
+        BigDecimal sobrante = BigDecimal.ZERO;
+        BigDecimal mayorFaltante = BigDecimal.ZERO;
+
+        for (TurnoCaja turno : turnos) {
+            BigDecimal diferencia = turno.getDiferencia();
+            if (diferencia == null || diferencia.signum() == 0) {
+                continue;
+            }
+            if (diferencia.signum() < 0) {
+                conFaltante++;
+                faltante = faltante.add(diferencia.negate());
+                mayorFaltante = mayorFaltante.max(diferencia.negate());
+            } else {
+                conSobrante++;
+                sobrante = sobrante.add(diferencia);
+            }
+        }
+
+        return new CajeroResumen(
+            responsableId != null ? responsableId.getValue() : null,
+            nombre,
+            turnos.size(),
+            conFaltante,
+            conSobrante,
+            faltante,
+            sobrante,
+            sobrante.subtract(faltante),
+            mayorFaltante
+        );
+    }
+}
@@ -0,0 +1,117 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.HistorialClienteResponse;
+import com.agustinpalma.comandas.application.dto.HistorialClienteResponse.PedidoHistorialDTO;
+import com.agustinpalma.comandas.application.dto.HistorialClienteResponse.ProductoFavoritoDTO;
+import com.agustinpalma.comandas.domain.model.Cliente;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.ItemPedido;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.ClienteRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.MonthDay;
+import java.util.Comparator;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+
+/**
+ * Caso de uso para consultar el historial de consumo de un cliente.
+ *
+ * HU-168: Ficha de clientes con historial.
+ *
+ * Junta los pedidos cerrados vinculados al cliente (salón, take away y
+ * delivery) con los totales, el ticket promedio y lo que más pide, para
+ * que el mozo o quien atiende el teléfono lo tenga a mano.
+ */
+@Transactional(readOnly = true)
+public class ConsultarHistorialClienteUseCase {
+
+    private static final int PRODUCTOS_FAVORITOS = 5;
+
+    private final ClienteRepository clienteRepository;
+    private final PedidoRepository pedidoRepository;
+    private final Clock clock;
+
+    public ConsultarHistorialClienteUseCase(ClienteRepository clienteRepository,
+                                            PedidoRepository pedidoRepository,
+                                            Clock clock) {
+        this.clienteRepository = Objects.requireNonNull(clienteRepository, "El clienteRepository es obligatorio");
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si el cliente no existe en el local
+     */
+    public HistorialClienteResponse ejecutar(ClienteId clienteId, LocalId localId) {
+        Objects.requireNonNull(clienteId, "El clienteId es obligatorio");
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        Cliente cliente = clienteRepository.buscarPorIdYLocal(clienteId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("Cliente no encontrado"));
+        List<Pedido> pedidos = pedidoRepository.buscarCerradosPorCliente(clienteId, localId).stream()
+            .sorted(Comparator.comparing(Pedido::getFechaCierre).reversed())
+            .toList();
+
+        BigDecimal total = pedidos.stream()
+            .map(Pedido::getMontoTotalFinal)
+            .filter(Objects::nonNull)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+        BigDecimal ticketPromedio = pedidos.isEmpty()
+            ? BigDecimal.ZERO
+            : total.divide(BigDecimal.valueOf(pedidos.size()), 2, RoundingMode.HALF_UP);
+
+        return new HistorialClienteResponse(
+            cliente.getId().getValue().toString(),
+            cliente.getNombre(),
+            cliente.getAlergias(),
+            cliente.getPreferencias(),
+            pedidos.size(),
+            total,
+            ticketPromedio,
+            pedidos.isEmpty() ? null : pedidos.get(0).getFechaCierre(),
+            proximoCumpleanios(cliente),
+            productosFavoritos(pedidos),
+            pedidos.stream().map(PedidoHistorialDTO::fromDomain).toList()
+        );
+    }
+
+    private LocalDate proximoCumpleanios(Cliente cliente) {
+        if (cliente.getFechaNacimiento() == null) {
+            return null;
+        }
+        LocalDate hoy = LocalDate.now(clock);
+        MonthDay cumpleanios = MonthDay.from(cliente.getFechaNacimiento());
+        LocalDate esteAnio = cumpleanios.atYear(hoy.getYear());
+        return esteAnio.isBefore(hoy) ? cumpleanios.atYear(hoy.getYear() + 1) : esteAnio;
+    }
+
+    /**
+     * Unidades por producto (sin el costo de envío); ante un empate gana
+     * el que se pidió más recientemente.
+     */
+    private List<ProductoFavoritoDTO> productosFavoritos(List<Pedido> pedidos) {
+        Map<String, Integer> unidades = new LinkedHashMap<>();
+        for (Pedido pedido : pedidos) {
+            for (ItemPedido item : pedido.getItems()) {
+                if (!item.isEnvio()) {
+                    unidades.merge(item.getNombreProducto(), item.getCantidad(), Integer::sum);
+                }
+            }
+        }
+        return unidades.entrySet().stream()
+            .sorted(Map.Entry.<String, Integer>comparingByValue().reversed())
+            .limit(PRODUCTOS_FAVORITOS)
+            .map(entry -> new ProductoFavoritoDTO(entry.getKey(), entry.getValue()))
+            .toList();
+    }
+}
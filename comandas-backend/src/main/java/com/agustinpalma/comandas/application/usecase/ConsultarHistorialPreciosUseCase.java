@@ -0,0 +1,136 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.CambioPrecioProductoResponse;
+import com.agustinpalma.comandas.application.dto.HistorialPreciosProductoResponse;
+import com.agustinpalma.comandas.application.dto.VariacionPreciosMenuResponse;
+import com.agustinpalma.comandas.application.dto.VariacionPreciosMenuResponse.MesResumen;
+import com.agustinpalma.comandas.domain.model.CambioPrecioProducto;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.CambioPrecioProductoRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.YearMonth;
+import java.util.ArrayList;
+import java.util.HashSet;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Set;
+import java.util.stream.Collectors;
+
+/**
+ * HU-146: Historial de precios por producto y variación promedio del menú por mes,
+ * a partir del registro de auditoría de precios del catálogo.
+ *
+ * Variación de un producto en el mes: del precio anterior a su primer cambio
+ * del mes al precio nuevo de su último cambio. El promedio del mes se toma
+ * sobre los productos activos hoy más los que cambiaron en el mes; los que
+ * no cambiaron cuentan como 0%. La acumulada compone los promedios mensuales.
+ */
+@Transactional(readOnly = true)
+public class ConsultarHistorialPreciosUseCase {
+
+    private static final BigDecimal CIEN = new BigDecimal("100");
+    private static final int MAX_MESES = 36;
+
+    private final ProductoRepository productoRepository;
+    private final CambioPrecioProductoRepository cambioPrecioProductoRepository;
+
+    public ConsultarHistorialPreciosUseCase(ProductoRepository productoRepository,
+                                            CambioPrecioProductoRepository cambioPrecioProductoRepository) {
+        this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+        this.cambioPrecioProductoRepository = Objects.requireNonNull(cambioPrecioProductoRepository,
+            "El cambioPrecioProductoRepository es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si el producto no existe en el local
+     */
+    public HistorialPreciosProductoResponse historialProducto(LocalId localId, ProductoId productoId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(productoId, "El productoId es obligatorio");
+        Producto producto = productoRepository.buscarPorIdYLocal(productoId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("Producto no encontrado"));
+
+        List<CambioPrecioProductoResponse> cambios = cambioPrecioProductoRepository
+            .buscarPorProducto(productoId, localId).stream()
+            .map(CambioPrecioProductoResponse::fromDomain)
+            .toList();
+        return new HistorialPreciosProductoResponse(productoId.getValue(), producto.getNombre(),
+            producto.getPrecio(), cambios);
+    }
+
+    /**
+     * @throws IllegalArgumentException si el rango está invertido o supera los 36 meses
+     */
+    public VariacionPreciosMenuResponse variacionMensual(LocalId localId, YearMonth desde, YearMonth hasta) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(desde, "El mes desde es obligatorio");
+        Objects.requireNonNull(hasta, "El mes hasta es obligatorio");
+        if (hasta.isBefore(desde)) {
+            throw new IllegalArgumentException("El mes hasta no puede ser anterior al mes desde");
+        }
+        if (desde.plusMonths(MAX_MESES).isBefore(hasta.plusMonths(1))) {
+            throw new IllegalArgumentException("El rango no puede superar los " + MAX_MESES + " meses");
+        }
+
+        Set<ProductoId> activos = productoRepository.buscarPorLocal(localId).stream()
+            .filter(Producto::isActivo)
+            .map(Producto::getId)
+            .collect(Collectors.toSet());
+
+        Map<YearMonth, List<CambioPrecioProducto>> porMes = cambioPrecioProductoRepository
+            .buscarPorLocalEntre(localId, desde.atDay(1).atStartOfDay(), hasta.plusMonths(1).atDay(1).atStartOfDay())
+            .stream()
+            .collect(Collectors.groupingBy(c -> YearMonth.from(c.getFecha()), LinkedHashMap::new, Collectors.toList()));
+
+        List<MesResumen> meses = new ArrayList<>();
+        BigDecimal factorAcumulado = BigDecimal.ONE;
+        for (YearMonth mes = desde; !mes.isAfter(hasta); mes = mes.plusMonths(1)) {
+            MesResumen resumen = resumirMes(mes, porMes.getOrDefault(mes, List.of()), activos);
+            meses.add(resumen);
+            factorAcumulado = factorAcumulado.multiply(
+                BigDecimal.ONE.add(resumen.variacionPromedio().divide(CIEN, 6, RoundingMode.HALF_UP)));
+        }
+
+        BigDecimal acumulada = factorAcumulado.subtract(BigDecimal.ONE).multiply(CIEN).setScale(2, RoundingMode.HALF_UP);
+        return new VariacionPreciosMenuResponse(desde, hasta, meses, acumulada);
+    }
+
+    private MesResumen resumirMes(YearMonth mes, List<CambioPrecioProducto> cambios, Set<ProductoId> activos) {
+        // Cambios ya ordenados por fecha: el primero da el precio de arranque, el último el de cierre
+        Map<ProductoId, List<CambioPrecioProducto>> porProducto = cambios.stream()
+            .collect(Collectors.groupingBy(CambioPrecioProducto::getProductoId, LinkedHashMap::new,
+                Collectors.toList()));
+
+        BigDecimal suma = BigDecimal.ZERO;
+        int modificados = 0;
+        for (List<CambioPrecioProducto> delProducto : porProducto.values()) {
+            BigDecimal inicial = delProducto.get(0).getPrecioAnterior();
+            BigDecimal fin = delProducto.get(delProducto.size() - 1).getPrecioNuevo();
+            if (inicial.signum() == 0) {
+                continue;
+            }
+            suma = suma.add(fin.subtract(inicial).multiply(CIEN).divide(inicial, 6, RoundingMode.HALF_UP));
+            modificados++;
+        }
+
+        Set<ProductoId> menu = new HashSet<>(activos);
+        menu.addAll(porProducto.keySet());
+
+        BigDecimal promedio = menu.isEmpty()
+            ? BigDecimal.ZERO
+            : suma.divide(BigDecimal.valueOf(menu.size()), 2, RoundingMode.HALF_UP);
+        BigDecimal promedioModificados = modificados == 0
+            ? BigDecimal.ZERO
+            : suma.divide(BigDecimal.valueOf(modificados), 2, RoundingMode.HALF_UP);
+
+        return new MesResumen(mes, menu.size(), porProducto.size(), cambios.size(), promedio, promedioModificados);
+    }
+}
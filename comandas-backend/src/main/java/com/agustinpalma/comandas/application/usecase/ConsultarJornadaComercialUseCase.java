@@ -0,0 +1,36 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.JornadaComercialResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Caso de uso para consultar el límite de la jornada comercial del local.
+ *
+ * HU-153: Si el local nunca lo configuró, la jornada termina a las 06:00.
+ */
+@Transactional(readOnly = true)
+public class ConsultarJornadaComercialUseCase {
+
+    private final JornadaComercialRepository jornadaComercialRepository;
+    private final Clock clock;
+
+    public ConsultarJornadaComercialUseCase(JornadaComercialRepository jornadaComercialRepository, Clock clock) {
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository, "El jornadaComercialRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    public JornadaComercialResponse ejecutar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        JornadaComercial jornada = jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId));
+        return JornadaComercialResponse.fromDomain(jornada, LocalDateTime.now(clock));
+    }
+}
@@ -0,0 +1,86 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ListaPreciosResponse;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.ListaPrecios;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.ListaPreciosRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * Caso de uso para consultar las listas de precios del local.
+ *
+ * HU-108: Devuelve una entrada por canal, en el orden del enum, esté o no
+ * configurada. Los precios especiales de productos que ya no existen se omiten.
+ */
+@Transactional(readOnly = true)
+public class ConsultarListasPreciosUseCase {
+
+    private final ListaPreciosRepository listaPreciosRepository;
+    private final ProductoRepository productoRepository;
+
+    public ConsultarListasPreciosUseCase(
+            ListaPreciosRepository listaPreciosRepository,
+            ProductoRepository productoRepository
+    ) {
+        this.listaPreciosRepository = Objects.requireNonNull(listaPreciosRepository, "El listaPreciosRepository es obligatorio");
+        this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+    }
+
+    public List<ListaPreciosResponse> ejecutar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        Map<CanalVenta, ListaPrecios> porCanal = listaPreciosRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(ListaPrecios::getCanal, Function.identity()));
+
+        Map<ProductoId, Producto> productos = porCanal.isEmpty()
+            ? Map.of()
+            : productoRepository.buscarPorLocal(localId).stream()
+                .collect(Collectors.toMap(Producto::getId, Function.identity()));
+
+        List<ListaPreciosResponse> resultado = new ArrayList<>();
+        for (CanalVenta canal : CanalVenta.values()) {
+            ListaPrecios lista = porCanal.get(canal);
+            resultado.add(lista == null
+                ? ListaPreciosResponse.sinConfigurar(canal)
+                : toResponse(lista, productos));
+        }
+        return resultado;
+    }
+
+    static ListaPreciosResponse toResponse(ListaPrecios lista, Map<ProductoId, Producto> productos) {
+        List<ListaPreciosResponse.PrecioProducto> precios = lista.getPreciosEspeciales().entrySet().stream()
+            .filter(entry -> productos.containsKey(entry.getKey()))
+            .map(entry -> {
+                Producto producto = productos.get(entry.getKey());
+                return new ListaPreciosResponse.PrecioProducto(
+                    producto.getId().getValue(),
+                    producto.getNombre(),
+                    producto.getPrecio(),
+                    entry.getValue()
+                );
+            })
+            .sorted(Comparator.comparing(ListaPreciosResponse.PrecioProducto::nombreProducto))
+            .toList();
+
+        return new ListaPreciosResponse(
+            lista.getId().getValue(),
+            lista.getCanal(),
+            true,
+            lista.getNombre(),
+            lista.getPorcentajeRecargo(),
+            precios
+        );
+    }
+}
@@ -0,0 +1,91 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.MesasOlvidadasResponse;
+import com.agustinpalma.comandas.application.dto.MesasOlvidadasResponse.MesaOlvidada;
+import com.agustinpalma.comandas.domain.model.AlertaMesasOlvidadas;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.AlertaMesasOlvidadasRepository;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.Comparator;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * Caso de uso para listar las mesas ocupadas que nadie atiende hace rato.
+ *
+ * HU-113: Las terminales del salón lo consultan periódicamente y avisan
+ * (sonido + notificación) por cada mesa nueva que aparece en la lista.
+ * Si la alerta está inactiva devuelve la lista vacía.
+ */
+@Transactional(readOnly = true)
+public class ConsultarMesasOlvidadasUseCase {
+
+    private final AlertaMesasOlvidadasRepository alertaRepository;
+    private final PedidoRepository pedidoRepository;
+    private final MesaRepository mesaRepository;
+    private final Clock clock;
+
+    public ConsultarMesasOlvidadasUseCase(
+            AlertaMesasOlvidadasRepository alertaRepository,
+            PedidoRepository pedidoRepository,
+            MesaRepository mesaRepository,
+            Clock clock
+    ) {
+        this.alertaRepository = Objects.requireNonNull(alertaRepository, "El alertaRepository es obligatorio");
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.mesaRepository = Objects.requireNonNull(mesaRepository, "El mesaRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    public MesasOlvidadasResponse ejecutar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        AlertaMesasOlvidadas alerta = alertaRepository.buscarPorLocal(localId)
+            .orElse(AlertaMesasOlvidadas.porDefecto(localId));
+        if (!alerta.isActiva()) {
+            return new MesasOlvidadasResponse(false, alerta.getMinutosSinActividad(), List.of());
+        }
+
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        List<Pedido> olvidados = pedidoRepository.buscarAbiertosPorLocal(localId).stream()
+            .filter(pedido -> alerta.estaOlvidada(pedido, ahora))
+            .sorted(Comparator.comparing(Pedido::getUltimaActividad))
+            .toList();
+
+        if (olvidados.isEmpty()) {
+            return new MesasOlvidadasResponse(true, alerta.getMinutosSinActividad(), List.of());
+        }
+
+        Map<MesaId, Mesa> mesas = mesaRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Mesa::getId, Function.identity()));
+
+        List<MesaOlvidada> resultado = olvidados.stream()
+            .filter(pedido -> mesas.containsKey(pedido.getMesaId()))
+            .map(pedido -> {
+                Mesa mesa = mesas.get(pedido.getMesaId());
+                return new MesaOlvidada(
+                    mesa.getId().getValue().toString(),
+                    mesa.getNumero(),
+                    mesa.getSector(),
+                    pedido.getId().getValue().toString(),
+                    pedido.getNumero(),
+                    pedido.getUltimaActividad(),
+                    pedido.minutosSinActividad(ahora)
+                );
+            })
+            .toList();
+
+        return new MesasOlvidadasResponse(true, alerta.getMinutosSinActividad(), resultado);
+    }
+}
@@ -0,0 +1,33 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.PlanCuentasContableResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.PlanCuentasContable;
+import com.agustinpalma.comandas.domain.repository.PlanCuentasContableRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso para consultar las cuentas contables del local.
+ *
+ * HU-167: La pantalla de exportación las muestra para completarlas antes de
+ * exportar. Si el local nunca las cargó, no hay cuentas asignadas.
+ */
+@Transactional(readOnly = true)
+public class ConsultarPlanCuentasContableUseCase {
+
+    private final PlanCuentasContableRepository planCuentasContableRepository;
+
+    public ConsultarPlanCuentasContableUseCase(PlanCuentasContableRepository planCuentasContableRepository) {
+        this.planCuentasContableRepository = Objects.requireNonNull(planCuentasContableRepository, "El planCuentasContableRepository es obligatorio");
+    }
+
+    public PlanCuentasContableResponse ejecutar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        PlanCuentasContable plan = planCuentasContableRepository.buscarPorLocal(localId)
+            .orElse(PlanCuentasContable.sinCuentas(localId));
+        return PlanCuentasContableResponse.fromDomain(plan);
+    }
+}
@@ -0,0 +1,44 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ProductoParaReponerResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+
+import java.util.Comparator;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * HU-101: Caso de uso para obtener el listado de productos "para reponer".
+ *
+ * Devuelve los productos del local que controlan stock, tienen un stock mínimo
+ * definido y cuyo stock actual lo alcanzó o perforó.
+ *
+ * El listado se ordena por cantidad sugerida descendente (lo más urgente primero)
+ * y luego alfabéticamente, para usarlo directamente como lista de compras del día.
+ * La exportación (CSV) se resuelve en el cliente a partir de esta respuesta.
+ */
+public class ConsultarProductosParaReponerUseCase {
+
+    private final ProductoRepository productoRepository;
+
+    public ConsultarProductosParaReponerUseCase(ProductoRepository productoRepository) {
+        this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+    }
+
+    /**
+     * @param localId identificador del local (tenant)
+     * @return productos bajo stock mínimo (puede estar vacía)
+     */
+    public List<ProductoParaReponerResponse> ejecutar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        return productoRepository.buscarPorLocal(localId).stream()
+            .filter(Producto::estaBajoStockMinimo)
+            .sorted(Comparator.comparingInt(Producto::calcularCantidadParaReponer).reversed()
+                .thenComparing(Producto::getNombre, String.CASE_INSENSITIVE_ORDER))
+            .map(ProductoParaReponerResponse::fromDomain)
+            .toList();
+    }
+}
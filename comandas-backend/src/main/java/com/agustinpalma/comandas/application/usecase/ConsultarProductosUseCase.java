@@ -2,14 +2,20 @@ package com.agustinpalma.comandas.application.usecase;
 
 import com.agustinpalma.comandas.application.dto.ProductoResponse;
 import com.agustinpalma.comandas.application.dto.ProductoResponse.PromocionActivaInfo;
+import com.agustinpalma.comandas.domain.model.Categoria;
 import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
 import com.agustinpalma.comandas.domain.model.Producto;
 import com.agustinpalma.comandas.domain.model.Promocion;
+import com.agustinpalma.comandas.domain.repository.CategoriaRepository;
 import com.agustinpalma.comandas.domain.repository.ProductoRepository;
 import com.agustinpalma.comandas.domain.repository.PromocionRepository;
+import java.time.Clock;
+import java.time.LocalTime;
 import java.util.*;
+import java.util.function.Function;
+import java.util.stream.Collectors;
 
 /**
  * Caso de uso para consultar productos del catálogo.
@@ -18,6 +24,7 @@ import java.util.*;
  * - Listar todos los productos del local
  * - Filtrar productos por color hexadecimal (opcional)
  * - Enriquecer cada producto con las promociones activas que le aplican
+ * - HU-161: Marcar los productos fuera de horario (el propio o el de su categoría)
  * 
  * El cruce entre productos y promociones se realiza en esta capa de aplicación
  * para mantener el dominio desacoplado: Producto no conoce a Promocion.
@@ -29,19 +36,27 @@ public class ConsultarProductosUseCase {
 
     private final ProductoRepository productoRepository;
     private final PromocionRepository promocionRepository;
+    private final CategoriaRepository categoriaRepository;
+    private final Clock clock;
 
     /**
      * Constructor con inyección de dependencias.
      *
      * @param productoRepository repositorio de productos
      * @param promocionRepository repositorio de promociones (para enriquecer con promos activas)
+     * @param categoriaRepository repositorio de categorías (para el horario de cada categoría, HU-161)
+     * @param clock reloj del sistema, para saber qué está fuera de horario
      */
     public ConsultarProductosUseCase(
             ProductoRepository productoRepository,
-            PromocionRepository promocionRepository
+            PromocionRepository promocionRepository,
+            CategoriaRepository categoriaRepository,
+            Clock clock
     ) {
         this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
         this.promocionRepository = Objects.requireNonNull(promocionRepository, "El promocionRepository es obligatorio");
+        this.categoriaRepository = Objects.requireNonNull(categoriaRepository, "El categoriaRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
     }
 
     /**
@@ -98,13 +113,19 @@ public class ConsultarProductosUseCase {
         // Cache de maxEstructural por grupoVarianteId para evitar N+1 queries
         Map<ProductoId, Integer> cacheMaxDiscos = new HashMap<>();
 
+        // HU-161: Horario de cada categoría, evaluado a la hora de la consulta
+        Map<CategoriaId, Categoria> categorias = categoriaRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Categoria::getId, Function.identity()));
+        LocalTime ahora = LocalTime.now(clock);
+
         // Transformar a DTOs enriquecidos con promos y flag de disco extra
         return productos.stream()
             .map(producto -> {
                 UUID productoUuid = producto.getId().getValue();
                 List<PromocionActivaInfo> promos = promosPorProducto.getOrDefault(productoUuid, List.of());
                 boolean puedeAgregarDiscoExtra = calcularPuedeAgregarDiscoExtra(producto, localId, cacheMaxDiscos);
-                return ProductoResponse.fromDomain(producto, promos, puedeAgregarDiscoExtra);
+                boolean fueraDeHorario = !producto.seOfreceA(ahora, categorias.get(producto.getCategoriaId()));
+                return ProductoResponse.fromDomain(producto, promos, puedeAgregarDiscoExtra, fueraDeHorario);
             })
             .toList();
     }
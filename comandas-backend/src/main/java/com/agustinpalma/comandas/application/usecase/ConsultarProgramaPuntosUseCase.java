@@ -0,0 +1,32 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ProgramaPuntosResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.ProgramaPuntos;
+import com.agustinpalma.comandas.domain.repository.ProgramaPuntosRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso para consultar la configuración del programa de puntos del local.
+ *
+ * HU-105: Si el local nunca lo configuró, devuelve el programa por defecto (inactivo).
+ */
+@Transactional(readOnly = true)
+public class ConsultarProgramaPuntosUseCase {
+
+    private final ProgramaPuntosRepository programaPuntosRepository;
+
+    public ConsultarProgramaPuntosUseCase(ProgramaPuntosRepository programaPuntosRepository) {
+        this.programaPuntosRepository = Objects.requireNonNull(programaPuntosRepository, "El programaPuntosRepository es obligatorio");
+    }
+
+    public ProgramaPuntosResponse ejecutar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        ProgramaPuntos programa = programaPuntosRepository.buscarPorLocal(localId)
+            .orElse(ProgramaPuntos.porDefecto(localId));
+        return ProgramaPuntosResponse.fromDomain(programa);
+    }
+}
@@ -0,0 +1,32 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.PromoCumpleaniosResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.PromoCumpleanios;
+import com.agustinpalma.comandas.domain.repository.PromoCumpleaniosRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso para consultar la configuración de la promo de cumpleaños del local.
+ *
+ * HU-168: Si el local nunca la configuró, devuelve la promo por defecto (inactiva).
+ */
+@Transactional(readOnly = true)
+public class ConsultarPromoCumpleaniosUseCase {
+
+    private final PromoCumpleaniosRepository promoCumpleaniosRepository;
+
+    public ConsultarPromoCumpleaniosUseCase(PromoCumpleaniosRepository promoCumpleaniosRepository) {
+        this.promoCumpleaniosRepository = Objects.requireNonNull(promoCumpleaniosRepository, "El promoCumpleaniosRepository es obligatorio");
+    }
+
+    public PromoCumpleaniosResponse ejecutar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        PromoCumpleanios promo = promoCumpleaniosRepository.buscarPorLocal(localId)
+            .orElse(PromoCumpleanios.porDefecto(localId));
+        return PromoCumpleaniosResponse.fromDomain(promo);
+    }
+}
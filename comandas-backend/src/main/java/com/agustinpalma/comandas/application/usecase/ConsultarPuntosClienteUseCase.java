@@ -0,0 +1,82 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.PuntosClienteResponse;
+import com.agustinpalma.comandas.application.dto.PuntosClienteResponse.LotePuntosResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.ProgramaPuntos;
+import com.agustinpalma.comandas.domain.repository.ClienteRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoPuntosRepository;
+import com.agustinpalma.comandas.domain.repository.ProgramaPuntosRepository;
+import com.agustinpalma.comandas.domain.service.ProgramaPuntosService;
+import com.agustinpalma.comandas.domain.service.ProgramaPuntosService.LotePuntos;
+import com.agustinpalma.comandas.domain.service.ProgramaPuntosService.SaldoPuntos;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.Comparator;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Caso de uso para consultar el saldo de puntos de un cliente.
+ *
+ * HU-105: Lo usa el POS al cerrar la mesa para ofrecer el canje,
+ * y la ficha del cliente para mostrar qué puntos están por vencer.
+ */
+@Transactional(readOnly = true)
+public class ConsultarPuntosClienteUseCase {
+
+    private final ClienteRepository clienteRepository;
+    private final ProgramaPuntosRepository programaPuntosRepository;
+    private final MovimientoPuntosRepository movimientoPuntosRepository;
+    private final ProgramaPuntosService programaPuntosService;
+    private final Clock clock;
+
+    public ConsultarPuntosClienteUseCase(
+            ClienteRepository clienteRepository,
+            ProgramaPuntosRepository programaPuntosRepository,
+            MovimientoPuntosRepository movimientoPuntosRepository,
+            ProgramaPuntosService programaPuntosService,
+            Clock clock
+    ) {
+        this.clienteRepository = Objects.requireNonNull(clienteRepository, "El clienteRepository es obligatorio");
+        this.programaPuntosRepository = Objects.requireNonNull(programaPuntosRepository, "El programaPuntosRepository es obligatorio");
+        this.movimientoPuntosRepository = Objects.requireNonNull(movimientoPuntosRepository, "El movimientoPuntosRepository es obligatorio");
+        this.programaPuntosService = Objects.requireNonNull(programaPuntosService, "El programaPuntosService es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si el cliente no existe en el local
+     */
+    public PuntosClienteResponse ejecutar(ClienteId clienteId, LocalId localId) {
+        Objects.requireNonNull(clienteId, "El clienteId es obligatorio");
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        clienteRepository.buscarPorIdYLocal(clienteId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("Cliente no encontrado"));
+
+        ProgramaPuntos programa = programaPuntosRepository.buscarPorLocal(localId)
+            .orElse(ProgramaPuntos.porDefecto(localId));
+        SaldoPuntos saldo = programaPuntosService.calcularSaldo(
+            movimientoPuntosRepository.buscarPorCliente(clienteId, localId),
+            LocalDateTime.now(clock)
+        );
+
+        List<LotePuntosResponse> lotes = saldo.lotesVigentes().stream()
+            .sorted(Comparator.comparing(LotePuntos::fechaVencimiento,
+                Comparator.nullsLast(Comparator.naturalOrder())))
+            .map(lote -> new LotePuntosResponse(lote.puntos(), lote.fechaVencimiento()))
+            .toList();
+
+        return new PuntosClienteResponse(
+            clienteId.getValue().toString(),
+            saldo.disponible(),
+            programa.valorDe(saldo.disponible()),
+            saldo.totalVencido(),
+            lotes
+        );
+    }
+}
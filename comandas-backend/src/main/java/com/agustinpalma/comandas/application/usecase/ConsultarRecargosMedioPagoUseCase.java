@@ -0,0 +1,33 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.RecargosMedioPagoResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.RecargosMedioPago;
+import com.agustinpalma.comandas.domain.repository.RecargosMedioPagoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso para consultar los recargos por medio de pago del local.
+ *
+ * HU-143: El cobro los usa para mostrar el recargo de cada pago antes de
+ * cerrar. Si el local nunca los configuró, no hay recargos.
+ */
+@Transactional(readOnly = true)
+public class ConsultarRecargosMedioPagoUseCase {
+
+    private final RecargosMedioPagoRepository recargosMedioPagoRepository;
+
+    public ConsultarRecargosMedioPagoUseCase(RecargosMedioPagoRepository recargosMedioPagoRepository) {
+        this.recargosMedioPagoRepository = Objects.requireNonNull(recargosMedioPagoRepository, "El recargosMedioPagoRepository es obligatorio");
+    }
+
+    public RecargosMedioPagoResponse ejecutar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        RecargosMedioPago recargos = recargosMedioPagoRepository.buscarPorLocal(localId)
+            .orElse(RecargosMedioPago.sinRecargos(localId));
+        return RecargosMedioPagoResponse.fromDomain(recargos);
+    }
+}
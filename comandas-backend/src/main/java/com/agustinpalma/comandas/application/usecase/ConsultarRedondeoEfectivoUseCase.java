@@ -0,0 +1,33 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.RedondeoEfectivoResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.RedondeoEfectivo;
+import com.agustinpalma.comandas.domain.repository.RedondeoEfectivoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso para consultar la regla de redondeo del efectivo del local.
+ *
+ * HU-152: El cobro la usa para mostrar cuánto se cobra en efectivo antes de
+ * cerrar. Si el local nunca la configuró, se cobra al centavo.
+ */
+@Transactional(readOnly = true)
+public class ConsultarRedondeoEfectivoUseCase {
+
+    private final RedondeoEfectivoRepository redondeoEfectivoRepository;
+
+    public ConsultarRedondeoEfectivoUseCase(RedondeoEfectivoRepository redondeoEfectivoRepository) {
+        this.redondeoEfectivoRepository = Objects.requireNonNull(redondeoEfectivoRepository, "El redondeoEfectivoRepository es obligatorio");
+    }
+
+    public RedondeoEfectivoResponse ejecutar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        RedondeoEfectivo redondeo = redondeoEfectivoRepository.buscarPorLocal(localId)
+            .orElse(RedondeoEfectivo.sinRedondeo(localId));
+        return RedondeoEfectivoResponse.fromDomain(redondeo);
+    }
+}
@@ -0,0 +1,33 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReglaPropinasResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.ReglaPropinas;
+import com.agustinpalma.comandas.domain.repository.ReglaPropinasRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso para consultar la regla de reparto de propinas del local.
+ *
+ * HU-141: Si el local nunca la configuró, devuelve la regla por defecto
+ * (partes iguales entre mozos y encargados).
+ */
+@Transactional(readOnly = true)
+public class ConsultarReglaPropinasUseCase {
+
+    private final ReglaPropinasRepository reglaPropinasRepository;
+
+    public ConsultarReglaPropinasUseCase(ReglaPropinasRepository reglaPropinasRepository) {
+        this.reglaPropinasRepository = Objects.requireNonNull(reglaPropinasRepository, "El reglaPropinasRepository es obligatorio");
+    }
+
+    public ReglaPropinasResponse ejecutar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        ReglaPropinas regla = reglaPropinasRepository.buscarPorLocal(localId)
+            .orElse(ReglaPropinas.porDefecto(localId));
+        return ReglaPropinasResponse.fromDomain(regla);
+    }
+}
@@ -0,0 +1,61 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.RentabilidadProductoResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Insumo;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.model.RentabilidadProducto;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.domain.repository.RecetaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Comparator;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * HU-118: Caso de uso para consultar el margen teórico de los productos con receta.
+ *
+ * Solo entran los productos activos que tienen receta cargada. Se ordenan
+ * de menor a mayor margen porcentual: los que se venden a pérdida quedan arriba.
+ */
+@Transactional(readOnly = true)
+public class ConsultarRentabilidadProductosUseCase {
+
+    private final ProductoRepository productoRepository;
+    private final RecetaRepository recetaRepository;
+    private final InsumoRepository insumoRepository;
+
+    public ConsultarRentabilidadProductosUseCase(
+            ProductoRepository productoRepository,
+            RecetaRepository recetaRepository,
+            InsumoRepository insumoRepository
+    ) {
+        this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+        this.recetaRepository = Objects.requireNonNull(recetaRepository, "El recetaRepository es obligatorio");
+        this.insumoRepository = Objects.requireNonNull(insumoRepository, "El insumoRepository es obligatorio");
+    }
+
+    public List<RentabilidadProductoResponse> ejecutar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        Map<InsumoId, Insumo> insumos = insumoRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Insumo::getId, Function.identity()));
+        Map<ProductoId, Producto> productos = productoRepository.buscarPorLocal(localId).stream()
+            .filter(Producto::isActivo)
+            .collect(Collectors.toMap(Producto::getId, Function.identity()));
+
+        return recetaRepository.buscarPorLocal(localId).stream()
+            .filter(receta -> productos.containsKey(receta.getProductoId()))
+            .map(receta -> new RentabilidadProducto(productos.get(receta.getProductoId()), receta.calcularCosto(insumos)))
+            .sorted(Comparator.comparing(RentabilidadProducto::getMargenPorcentaje))
+            .map(RentabilidadProductoResponse::fromDomain)
+            .toList();
+    }
+}
@@ -0,0 +1,94 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteConsumoPersonalResponse;
+import com.agustinpalma.comandas.application.dto.ReporteConsumoPersonalResponse.ConsumoEmpleado;
+import com.agustinpalma.comandas.domain.model.ConsumoPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.repository.ConsumoPersonalRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.YearMonth;
+import java.util.Comparator;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.stream.Collectors;
+
+/**
+ * Caso de uso: resumen mensual de consumo interno por empleado.
+ *
+ * HU-132: Compara lo que consumió cada empleado a cuenta contra su tope,
+ * para descontar del sueldo lo que exceda el beneficio acordado. El mes es
+ * el de la jornada comercial (HU-153), igual que el tope que se controla al
+ * cerrar la mesa.
+ */
+@Transactional(readOnly = true)
+public class ConsultarReporteConsumoPersonalUseCase {
+
+    private final ConsumoPersonalRepository consumoPersonalRepository;
+    private final MozoRepository mozoRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
+
+    public ConsultarReporteConsumoPersonalUseCase(ConsumoPersonalRepository consumoPersonalRepository,
+                                                  MozoRepository mozoRepository,
+                                                  JornadaComercialRepository jornadaComercialRepository) {
+        this.consumoPersonalRepository = Objects.requireNonNull(consumoPersonalRepository, "El consumoPersonalRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository, "El jornadaComercialRepository es obligatorio");
+    }
+
+    /**
+     * @param localId tenant que consulta
+     * @param periodo mes a liquidar
+     * @return una fila por empleado que consumió en el mes o que tiene tope y está activo, por nombre
+     */
+    public ReporteConsumoPersonalResponse ejecutar(LocalId localId, YearMonth periodo) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(periodo, "El período es obligatorio");
+
+        JornadaComercial jornada = jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId));
+        Map<MozoId, List<ConsumoPersonal>> porEmpleado = consumoPersonalRepository
+            .buscarPorLocal(localId, jornada.inicio(periodo), jornada.inicio(periodo.plusMonths(1)))
+            .stream()
+            .collect(Collectors.groupingBy(ConsumoPersonal::getMozoId));
+
+        List<ConsumoEmpleado> filas = mozoRepository.buscarPorLocal(localId).stream()
+            .filter(m -> porEmpleado.containsKey(m.getId()) || (m.isActivo() && m.tieneTopeConsumo()))
+            .map(m -> armarFila(m, porEmpleado.getOrDefault(m.getId(), List.of())))
+            .sorted(Comparator.comparing(ConsumoEmpleado::nombre, String.CASE_INSENSITIVE_ORDER))
+            .toList();
+
+        return new ReporteConsumoPersonalResponse(
+            periodo,
+            filas,
+            filas.stream().map(ConsumoEmpleado::consumido).reduce(BigDecimal.ZERO, BigDecimal::add),
+            filas.stream().map(ConsumoEmpleado::excedente).reduce(BigDecimal.ZERO, BigDecimal::add)
+        );
+    }
+
+    private ConsumoEmpleado armarFila(Mozo empleado, List<ConsumoPersonal> consumos) {
+        BigDecimal consumido = consumos.stream()
+            .map(ConsumoPersonal::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+        BigDecimal disponible = empleado.tieneTopeConsumo()
+            ? empleado.getTopeConsumoMensual().subtract(consumido).max(BigDecimal.ZERO)
+            : null;
+
+        return new ConsumoEmpleado(
+            empleado.getId().getValue(),
+            empleado.getNombre(),
+            empleado.getTopeConsumoMensual(),
+            consumos.size(),
+            consumido,
+            empleado.excedenteConsumo(consumido),
+            disponible
+        );
+    }
+}
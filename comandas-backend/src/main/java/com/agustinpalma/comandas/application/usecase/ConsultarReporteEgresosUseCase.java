@@ -0,0 +1,125 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteEgresosMensualResponse;
+import com.agustinpalma.comandas.application.dto.ReporteEgresosMensualResponse.CategoriaMes;
+import com.agustinpalma.comandas.domain.model.CategoriaEgreso;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaEgresoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.repository.CategoriaEgresoRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.LocalTime;
+import java.time.YearMonth;
+import java.util.Comparator;
+import java.util.HashSet;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Optional;
+import java.util.Set;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * Caso de uso: egresos de caja del mes por categoría, contra el mes anterior.
+ *
+ * HU-130: Categorías de egresos y reporte mensual de gastos.
+ *
+ * - Cuenta los egresos de caja por la fecha en que se registraron.
+ * - Las devoluciones no entran: son menor venta, no gasto (HU-121).
+ * - Los egresos sin categoría (los anteriores a esta HU, los que salen de
+ *   un gasto pagado con la caja) se suman en "Sin categoría".
+ */
+@Transactional(readOnly = true)
+public class ConsultarReporteEgresosUseCase {
+
+    static final String SIN_CATEGORIA = "Sin categoría";
+
+    private static final BigDecimal CIEN = new BigDecimal("100");
+
+    private final MovimientoCajaRepository movimientoCajaRepository;
+    private final CategoriaEgresoRepository categoriaEgresoRepository;
+
+    public ConsultarReporteEgresosUseCase(MovimientoCajaRepository movimientoCajaRepository,
+                                          CategoriaEgresoRepository categoriaEgresoRepository) {
+        this.movimientoCajaRepository = Objects.requireNonNull(movimientoCajaRepository,
+            "El movimientoCajaRepository es obligatorio");
+        this.categoriaEgresoRepository = Objects.requireNonNull(categoriaEgresoRepository,
+            "El categoriaEgresoRepository es obligatorio");
+    }
+
+    public ReporteEgresosMensualResponse ejecutar(LocalId localId, YearMonth periodo) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(periodo, "El período es obligatorio");
+
+        YearMonth anterior = periodo.minusMonths(1);
+        Map<Optional<CategoriaEgresoId>, List<MovimientoCaja>> delMes = egresosPorCategoria(localId, periodo);
+        Map<Optional<CategoriaEgresoId>, List<MovimientoCaja>> delAnterior = egresosPorCategoria(localId, anterior);
+
+        Map<CategoriaEgresoId, CategoriaEgreso> categorias = categoriaEgresoRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(CategoriaEgreso::getId, Function.identity()));
+
+        Set<Optional<CategoriaEgresoId>> claves = new HashSet<>(delMes.keySet());
+        claves.addAll(delAnterior.keySet());
+
+        List<CategoriaMes> filas = claves.stream()
+            .map(clave -> {
+                List<MovimientoCaja> actuales = delMes.getOrDefault(clave, List.of());
+                List<MovimientoCaja> previos = delAnterior.getOrDefault(clave, List.of());
+                BigDecimal total = sumar(actuales);
+                BigDecimal totalAnterior = sumar(previos);
+                CategoriaEgreso categoria = clave.map(categorias::get).orElse(null);
+                return new CategoriaMes(
+                    clave.map(CategoriaEgresoId::getValue).orElse(null),
+                    categoria != null ? categoria.getNombre() : SIN_CATEGORIA,
+                    categoria == null || categoria.isActiva(),
+                    actuales.size(),
+                    total,
+                    previos.size(),
+                    totalAnterior,
+                    total.subtract(totalAnterior),
+                    variacionPorcentual(total, totalAnterior)
+                );
+            })
+            .sorted(Comparator.comparing((CategoriaMes c) -> c.categoriaId() == null)
+                .thenComparing(CategoriaMes::total, Comparator.reverseOrder())
+                .thenComparing(CategoriaMes::nombre))
+            .toList();
+
+        BigDecimal total = filas.stream().map(CategoriaMes::total).reduce(BigDecimal.ZERO, BigDecimal::add);
+        BigDecimal totalAnterior = filas.stream().map(CategoriaMes::totalAnterior).reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        return new ReporteEgresosMensualResponse(
+            periodo,
+            anterior,
+            filas,
+            total,
+            totalAnterior,
+            total.subtract(totalAnterior),
+            variacionPorcentual(total, totalAnterior)
+        );
+    }
+
+    private Map<Optional<CategoriaEgresoId>, List<MovimientoCaja>> egresosPorCategoria(LocalId localId, YearMonth mes) {
+        return movimientoCajaRepository
+            .buscarPorFecha(localId, mes.atDay(1).atStartOfDay(), mes.atEndOfMonth().atTime(LocalTime.MAX)).stream()
+            .filter(MovimientoCaja::esEgreso)
+            .filter(m -> !m.esDevolucion())
+            .collect(Collectors.groupingBy(m -> Optional.ofNullable(m.getCategoriaId())));
+    }
+
+    private static BigDecimal sumar(List<MovimientoCaja> movimientos) {
+        return movimientos.stream().map(MovimientoCaja::getMonto).reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    private static BigDecimal variacionPorcentual(BigDecimal actual, BigDecimal anterior) {
+        if (anterior.signum() == 0) {
+            return null;
+        }
+        return actual.subtract(anterior).multiply(CIEN).divide(anterior, 2, RoundingMode.HALF_UP);
+    }
+}
@@ -0,0 +1,119 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteGastosResponse;
+import com.agustinpalma.comandas.application.dto.ReporteGastosResponse.CategoriaResumen;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaGasto;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.Gasto;
+import com.agustinpalma.comandas.domain.model.JornadaCaja;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.repository.FacturaProveedorRepository;
+import com.agustinpalma.comandas.domain.repository.GastoRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaCajaRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.LocalDateTime;
+import java.time.LocalTime;
+import java.time.YearMonth;
+import java.util.Comparator;
+import java.util.EnumMap;
+import java.util.HashSet;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Set;
+
+/**
+ * Caso de uso: reporte mensual de gastos por categoría contra las ventas.
+ *
+ * HU-117: Cuánto se fue en mercadería, alquiler, servicios, etc. y qué
+ * porción de lo vendido representa cada categoría.
+ *
+ * - Ventas: suma de las ventas reales de las jornadas cerradas del mes.
+ * - Gastos: los registrados con fecha de comprobante en el mes.
+ * - Los egresos de caja cargados a mano (sin registrar un gasto) se suman
+ *   en una línea aparte para que el resultado no quede inflado.
+ * - HU-131: Una compra a cuenta corriente cuenta en el mes de la compra; los
+ *   pagos posteriores al proveedor (aunque salgan de la caja) no son gasto.
+ */
+@Transactional(readOnly = true)
+public class ConsultarReporteGastosUseCase {
+
+    private static final BigDecimal CIEN = new BigDecimal("100");
+
+    private final GastoRepository gastoRepository;
+    private final JornadaCajaRepository jornadaCajaRepository;
+    private final MovimientoCajaRepository movimientoCajaRepository;
+    private final FacturaProveedorRepository facturaProveedorRepository;
+
+    public ConsultarReporteGastosUseCase(GastoRepository gastoRepository,
+                                         JornadaCajaRepository jornadaCajaRepository,
+                                         MovimientoCajaRepository movimientoCajaRepository,
+                                         FacturaProveedorRepository facturaProveedorRepository) {
+        this.gastoRepository = Objects.requireNonNull(gastoRepository, "El gastoRepository es obligatorio");
+        this.jornadaCajaRepository = Objects.requireNonNull(jornadaCajaRepository, "El jornadaCajaRepository es obligatorio");
+        this.movimientoCajaRepository = Objects.requireNonNull(movimientoCajaRepository, "El movimientoCajaRepository es obligatorio");
+        this.facturaProveedorRepository = Objects.requireNonNull(facturaProveedorRepository, "El facturaProveedorRepository es obligatorio");
+    }
+
+    public ReporteGastosResponse ejecutar(LocalId localId, YearMonth periodo) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(periodo, "El período es obligatorio");
+
+        BigDecimal totalVentas = jornadaCajaRepository
+            .buscarPorRangoFecha(localId, periodo.atDay(1), periodo.atEndOfMonth()).stream()
+            .map(JornadaCaja::getTotalVentasReales)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        // Gastos del mes agrupados por categoría
+        Map<CategoriaGasto, BigDecimal> totales = new EnumMap<>(CategoriaGasto.class);
+        Map<CategoriaGasto, Integer> cantidades = new EnumMap<>(CategoriaGasto.class);
+        for (Gasto gasto : gastoRepository.buscarPorPeriodo(localId, periodo.atDay(1), periodo.atEndOfMonth())) {
+            totales.merge(gasto.getCategoria(), gasto.getMonto(), BigDecimal::add);
+            cantidades.merge(gasto.getCategoria(), 1, Integer::sum);
+        }
+
+        List<CategoriaResumen> categorias = totales.entrySet().stream()
+            .map(e -> new CategoriaResumen(e.getKey(), cantidades.get(e.getKey()), e.getValue(),
+                porcentaje(e.getValue(), totalVentas)))
+            .sorted(Comparator.comparing(CategoriaResumen::total).reversed())
+            .toList();
+
+        // Egresos de caja del mes que no salieron de un gasto registrado
+        // (HU-121: las devoluciones ya se descontaron de las ventas, no son gasto)
+        LocalDateTime inicio = periodo.atDay(1).atStartOfDay();
+        LocalDateTime fin = periodo.atEndOfMonth().atTime(LocalTime.MAX);
+        Set<MovimientoCajaId> asociados = new HashSet<>(gastoRepository.buscarMovimientosCajaAsociados(localId, inicio, fin));
+        asociados.addAll(facturaProveedorRepository.buscarMovimientosCajaAsociados(localId, inicio, fin));
+        BigDecimal otrosEgresosCaja = movimientoCajaRepository.buscarPorFecha(localId, inicio, fin).stream()
+            .filter(MovimientoCaja::esEgreso)
+            .filter(m -> !m.esDevolucion())
+            .filter(m -> !asociados.contains(m.getId()))
+            .map(MovimientoCaja::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        BigDecimal totalGastos = totales.values().stream()
+            .reduce(otrosEgresosCaja, BigDecimal::add);
+
+        return new ReporteGastosResponse(
+            periodo,
+            totalVentas,
+            categorias,
+            otrosEgresosCaja,
+            totalGastos,
+            totalVentas.subtract(totalGastos),
+            porcentaje(totalGastos, totalVentas)
+        );
+    }
+
+    private static BigDecimal porcentaje(BigDecimal parte, BigDecimal total) {
+        if (total.signum() == 0) {
+            return BigDecimal.ZERO;
+        }
+        return parte.multiply(CIEN).divide(total, 2, RoundingMode.HALF_UP);
+    }
+}
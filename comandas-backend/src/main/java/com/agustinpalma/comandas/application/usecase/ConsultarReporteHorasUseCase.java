@@ -0,0 +1,99 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.FichajeResponse;
+import com.agustinpalma.comandas.application.dto.ReporteHorasResponse;
+import com.agustinpalma.comandas.application.dto.ReporteHorasResponse.HorasEmpleado;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Fichaje;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.repository.FichajeRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.time.YearMonth;
+import java.util.Comparator;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * Caso de uso: reporte mensual de horas por empleado.
+ *
+ * HU-124: Suma las horas fichadas de cada empleado en el mes, con el detalle
+ * de sus fichajes para revisar (incompletos y corregidos) antes de liquidar.
+ */
+@Transactional(readOnly = true)
+public class ConsultarReporteHorasUseCase {
+
+    private final FichajeRepository fichajeRepository;
+    private final MozoRepository mozoRepository;
+    private final Clock clock;
+
+    public ConsultarReporteHorasUseCase(FichajeRepository fichajeRepository,
+                                        MozoRepository mozoRepository,
+                                        Clock clock) {
+        this.fichajeRepository = Objects.requireNonNull(fichajeRepository, "El fichajeRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @param localId tenant que consulta
+     * @param periodo mes a liquidar
+     * @return una fila por empleado con fichajes en el mes, por nombre
+     */
+    public ReporteHorasResponse ejecutar(LocalId localId, YearMonth periodo) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(periodo, "El período es obligatorio");
+
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        List<Fichaje> fichajes = fichajeRepository.buscarPorRango(localId,
+            periodo.atDay(1).atStartOfDay(), periodo.plusMonths(1).atDay(1).atStartOfDay());
+
+        Map<MozoId, Mozo> empleados = mozoRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Mozo::getId, Function.identity()));
+
+        Map<MozoId, List<Fichaje>> porEmpleado = fichajes.stream()
+            .collect(Collectors.groupingBy(Fichaje::getMozoId, LinkedHashMap::new, Collectors.toList()));
+
+        List<HorasEmpleado> filas = porEmpleado.entrySet().stream()
+            .map(e -> armarFila(e.getKey(), e.getValue(), empleados, ahora))
+            .sorted(Comparator.comparing(HorasEmpleado::nombre, String.CASE_INSENSITIVE_ORDER))
+            .toList();
+
+        long totalMinutos = filas.stream().mapToLong(HorasEmpleado::minutos).sum();
+        return new ReporteHorasResponse(periodo, filas, totalMinutos);
+    }
+
+    private HorasEmpleado armarFila(MozoId mozoId, List<Fichaje> fichajes, Map<MozoId, Mozo> empleados,
+                                    LocalDateTime ahora) {
+        String nombre = nombreDe(mozoId, empleados);
+
+        List<FichajeResponse> detalle = fichajes.stream()
+            .sorted(Comparator.comparing(Fichaje::getEntrada))
+            .map(f -> FichajeResponse.fromDomain(f, nombre,
+                f.fueCorregido() ? nombreDe(f.getCorregidoPorId(), empleados) : null, ahora))
+            .toList();
+
+        return new HorasEmpleado(
+            mozoId.getValue(),
+            nombre,
+            fichajes.stream().mapToLong(f -> f.duracion().toMinutes()).sum(),
+            (int) fichajes.stream().map(f -> f.getEntrada().toLocalDate()).distinct().count(),
+            (int) fichajes.stream().filter(Fichaje::estaAbierto).count(),
+            (int) fichajes.stream().filter(Fichaje::fueCorregido).count(),
+            detalle
+        );
+    }
+
+    private String nombreDe(MozoId mozoId, Map<MozoId, Mozo> empleados) {
+        Mozo mozo = empleados.get(mozoId);
+        return mozo != null ? mozo.getNombre() : "Empleado eliminado";
+    }
+}
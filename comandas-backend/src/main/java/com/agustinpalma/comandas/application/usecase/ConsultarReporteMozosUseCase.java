@@ -0,0 +1,146 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteMozosResponse;
+import com.agustinpalma.comandas.application.dto.ReporteMozosResponse.MozoResumen;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Optional;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * Caso de uso: reporte de desempeño por mozo.
+ *
+ * HU-111: Ventas, cubiertos, ticket promedio, anulaciones y propinas de cada
+ * mozo en un rango de fechas, para liquidar incentivos.
+ *
+ * Se toman los pedidos cerrados en el rango. La venta de cada pedido es su
+ * total congelado al cierre (incluye A_CUENTA: para el mozo es una mesa
+ * atendida igual); la propina va aparte.
+ */
+@Transactional(readOnly = true)
+public class ConsultarReporteMozosUseCase {
+
+    private static final String SIN_ASIGNAR = "Sin asignar";
+
+    private final PedidoRepository pedidoRepository;
+    private final MozoRepository mozoRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
+
+    public ConsultarReporteMozosUseCase(PedidoRepository pedidoRepository, MozoRepository mozoRepository,
+                                        JornadaComercialRepository jornadaComercialRepository) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository, "El jornadaComercialRepository es obligatorio");
+    }
+
+    /**
+     * @param localId tenant que consulta
+     * @param desde   primera jornada comercial del rango (inclusive, HU-153)
+     * @param hasta   última jornada comercial del rango (inclusive)
+     * @return una fila por mozo con pedidos en el rango, por ventas descendentes;
+     *         la fila "Sin asignar" (si existe) va al final
+     */
+    public ReporteMozosResponse ejecutar(LocalId localId, LocalDate desde, LocalDate hasta) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(desde, "La fecha desde es obligatoria");
+        Objects.requireNonNull(hasta, "La fecha hasta es obligatoria");
+        if (hasta.isBefore(desde)) {
+            throw new IllegalArgumentException("La fecha hasta no puede ser anterior a la fecha desde");
+        }
+
+        JornadaComercial jornada = jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId));
+        LocalDateTime inicio = jornada.inicio(desde);
+        LocalDateTime fin = jornada.inicio(hasta.plusDays(1));
+
+        // buscarCerradosPorFecha es inclusive en ambos extremos: se descarta el borde superior
+        Map<Optional<MozoId>, List<Pedido>> porMozo = new LinkedHashMap<>();
+        for (Pedido pedido : pedidoRepository.buscarCerradosPorFecha(localId, inicio, fin)) {
+            if (pedido.getFechaCierre() != null && pedido.getFechaCierre().isBefore(fin)) {
+                porMozo.computeIfAbsent(Optional.ofNullable(pedido.getMozoId()), id -> new ArrayList<>())
+                    .add(pedido);
+            }
+        }
+
+        Map<MozoId, Mozo> mozos = porMozo.isEmpty()
+            ? Map.of()
+            : mozoRepository.buscarPorLocal(localId).stream()
+                .collect(Collectors.toMap(Mozo::getId, Function.identity()));
+
+        List<MozoResumen> filas = new ArrayList<>();
+        porMozo.entrySet().stream()
+            .filter(entry -> entry.getKey().isPresent())
+            .map(entry -> resumen(entry.getKey().get(), nombreDe(mozos, entry.getKey().get()), entry.getValue()))
+            .sorted(Comparator.comparing(MozoResumen::ventas).reversed())
+            .forEach(filas::add);
+
+        List<Pedido> sinMozo = porMozo.get(Optional.<MozoId>empty());
+        if (sinMozo != null) {
+            filas.add(resumen(null, SIN_ASIGNAR, sinMozo));
+        }
+
+        BigDecimal totalVentas = filas.stream().map(MozoResumen::ventas).reduce(BigDecimal.ZERO, BigDecimal::add);
+        BigDecimal totalPropinas = filas.stream().map(MozoResumen::propinas).reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        return new ReporteMozosResponse(desde, hasta, filas, totalVentas, totalPropinas);
+    }
+
+    private static String nombreDe(Map<MozoId, Mozo> mozos, MozoId mozoId) {
+        Mozo mozo = mozos.get(mozoId);
+        return mozo != null ? mozo.getNombre() : SIN_ASIGNAR;
+    }
+
+    private static MozoResumen resumen(MozoId mozoId, String nombre, List<Pedido> pedidos) {
+        BigDecimal ventas = BigDecimal.ZERO;
+        BigDecimal propinas = BigDecimal.ZERO;
+        BigDecimal montoAnulado = BigDecimal.ZERO;
+        int cubiertos = 0;
+        int unidadesAnuladas = 0;
+
+        for (Pedido pedido : pedidos) {
+            ventas = ventas.add(pedido.getMontoTotalFinal() != null ? pedido.getMontoTotalFinal() : BigDecimal.ZERO);
+            propinas = propinas.add(pedido.getPropina());
+            montoAnulado = montoAnulado.add(pedido.getMontoAnulado());
+            cubiertos += pedido.getCubiertos();
+            unidadesAnuladas += pedido.getUnidadesAnuladas();
+        }
+
+        return new MozoResumen(
+            mozoId != null ? mozoId.getValue() : null,
+            nombre,
+            pedidos.size(),
+            ventas,
+            cubiertos,
+            promedio(ventas, pedidos.size()),
+            promedio(ventas, cubiertos),
+            unidadesAnuladas,
+            montoAnulado,
+            propinas
+        );
+    }
+
+    private static BigDecimal promedio(BigDecimal total, int cantidad) {
+        return cantidad == 0
+            ? BigDecimal.ZERO
+            : total.divide(BigDecimal.valueOf(cantidad), 2, RoundingMode.HALF_UP);
+    }
+}
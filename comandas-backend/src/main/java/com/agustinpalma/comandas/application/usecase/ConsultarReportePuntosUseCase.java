@@ -0,0 +1,111 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReportePuntosResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.MovimientoPuntos;
+import com.agustinpalma.comandas.domain.model.ProgramaPuntos;
+import com.agustinpalma.comandas.domain.repository.MovimientoPuntosRepository;
+import com.agustinpalma.comandas.domain.repository.ProgramaPuntosRepository;
+import com.agustinpalma.comandas.domain.service.ProgramaPuntosService;
+import com.agustinpalma.comandas.domain.service.ProgramaPuntosService.SaldoPuntos;
+import com.agustinpalma.comandas.domain.service.ProgramaPuntosService.VencimientoPuntos;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.stream.Collectors;
+
+/**
+ * Caso de uso para el reporte de puntos emitidos, canjeados y vencidos.
+ *
+ * HU-105: Programa de puntos de clientes.
+ *
+ * Los vencimientos no son movimientos persistidos: se derivan recorriendo
+ * el historial FIFO de cada cliente, por eso el reporte carga todos los
+ * movimientos del local y no solo los del rango.
+ */
+@Transactional(readOnly = true)
+public class ConsultarReportePuntosUseCase {
+
+    private final ProgramaPuntosRepository programaPuntosRepository;
+    private final MovimientoPuntosRepository movimientoPuntosRepository;
+    private final ProgramaPuntosService programaPuntosService;
+    private final Clock clock;
+
+    public ConsultarReportePuntosUseCase(
+            ProgramaPuntosRepository programaPuntosRepository,
+            MovimientoPuntosRepository movimientoPuntosRepository,
+            ProgramaPuntosService programaPuntosService,
+            Clock clock
+    ) {
+        this.programaPuntosRepository = Objects.requireNonNull(programaPuntosRepository, "El programaPuntosRepository es obligatorio");
+        this.movimientoPuntosRepository = Objects.requireNonNull(movimientoPuntosRepository, "El movimientoPuntosRepository es obligatorio");
+        this.programaPuntosService = Objects.requireNonNull(programaPuntosService, "El programaPuntosService es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @param desde primer día del rango (inclusive)
+     * @param hasta último día del rango (inclusive)
+     * @throws IllegalArgumentException si el rango está invertido
+     */
+    public ReportePuntosResponse ejecutar(LocalId localId, LocalDate desde, LocalDate hasta) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(desde, "La fecha desde es obligatoria");
+        Objects.requireNonNull(hasta, "La fecha hasta es obligatoria");
+        if (hasta.isBefore(desde)) {
+            throw new IllegalArgumentException("La fecha hasta no puede ser anterior a la fecha desde");
+        }
+
+        LocalDateTime inicio = desde.atStartOfDay();
+        LocalDateTime fin = hasta.plusDays(1).atStartOfDay();
+        LocalDateTime ahora = LocalDateTime.now(clock);
+
+        List<MovimientoPuntos> movimientos = movimientoPuntosRepository.buscarPorLocal(localId);
+        List<MovimientoPuntos> delRango = movimientos.stream()
+            .filter(m -> !m.getFecha().isBefore(inicio) && m.getFecha().isBefore(fin))
+            .toList();
+
+        int emitidos = delRango.stream().filter(MovimientoPuntos::esAcumulacion)
+            .mapToInt(MovimientoPuntos::getPuntos).sum();
+        int canjeados = delRango.stream().filter(m -> !m.esAcumulacion())
+            .mapToInt(MovimientoPuntos::getPuntos).sum();
+        BigDecimal montoDescontado = delRango.stream().filter(m -> !m.esAcumulacion())
+            .map(MovimientoPuntos::getMontoDescuento)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        Map<ClienteId, List<MovimientoPuntos>> porCliente = movimientos.stream()
+            .collect(Collectors.groupingBy(MovimientoPuntos::getClienteId));
+
+        int vencidos = 0;
+        int vigentes = 0;
+        for (List<MovimientoPuntos> movimientosCliente : porCliente.values()) {
+            SaldoPuntos saldo = programaPuntosService.calcularSaldo(movimientosCliente, ahora);
+            vigentes += saldo.disponible();
+            vencidos += saldo.vencimientos().stream()
+                .filter(v -> !v.fecha().isBefore(inicio) && v.fecha().isBefore(fin))
+                .mapToInt(VencimientoPuntos::puntos)
+                .sum();
+        }
+
+        ProgramaPuntos programa = programaPuntosRepository.buscarPorLocal(localId)
+            .orElse(ProgramaPuntos.porDefecto(localId));
+
+        return new ReportePuntosResponse(
+            desde,
+            hasta,
+            emitidos,
+            canjeados,
+            vencidos,
+            montoDescontado,
+            vigentes,
+            programa.valorDe(vigentes)
+        );
+    }
+}
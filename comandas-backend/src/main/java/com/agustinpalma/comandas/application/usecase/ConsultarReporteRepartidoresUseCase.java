@@ -0,0 +1,109 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteRepartidoresResponse;
+import com.agustinpalma.comandas.application.dto.ReporteRepartidoresResponse.RepartidorResumen;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * Caso de uso: reporte de entregas y tiempos por repartidor.
+ *
+ * HU-140: Cuántas entregas hizo cada cadete en el rango, cuánto tardó en
+ * promedio (de la salida al regreso), su reparto más largo y el efectivo
+ * que cobró. Solo cuentan los repartos terminados; el rango es por la
+ * apertura del pedido.
+ */
+@Transactional(readOnly = true)
+public class ConsultarReporteRepartidoresUseCase {
+
+    private final PedidoRepository pedidoRepository;
+    private final MozoRepository mozoRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
+
+    public ConsultarReporteRepartidoresUseCase(PedidoRepository pedidoRepository, MozoRepository mozoRepository,
+                                               JornadaComercialRepository jornadaComercialRepository) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository, "El jornadaComercialRepository es obligatorio");
+    }
+
+    /**
+     * @param localId tenant que consulta
+     * @param desde   primera jornada comercial del rango (inclusive, HU-153)
+     * @param hasta   última jornada comercial del rango (inclusive)
+     * @return una fila por repartidor con entregas en el rango, por entregas descendentes
+     */
+    public ReporteRepartidoresResponse ejecutar(LocalId localId, LocalDate desde, LocalDate hasta) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(desde, "La fecha desde es obligatoria");
+        Objects.requireNonNull(hasta, "La fecha hasta es obligatoria");
+        if (hasta.isBefore(desde)) {
+            throw new IllegalArgumentException("La fecha hasta no puede ser anterior a la fecha desde");
+        }
+
+        JornadaComercial jornada = jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId));
+        LocalDateTime inicio = jornada.inicio(desde);
+        LocalDateTime fin = jornada.inicio(hasta.plusDays(1));
+
+        Map<MozoId, List<Pedido>> porRepartidor = new LinkedHashMap<>();
+        for (Pedido pedido : pedidoRepository.buscarRepartosEntre(localId, inicio, fin)) {
+            if (pedido.getSalidaReparto() != null && pedido.getRegresoReparto() != null) {
+                porRepartidor.computeIfAbsent(pedido.getRepartidorId(), id -> new ArrayList<>()).add(pedido);
+            }
+        }
+
+        Map<MozoId, Mozo> repartidores = porRepartidor.isEmpty()
+            ? Map.of()
+            : mozoRepository.buscarPorLocal(localId).stream()
+                .collect(Collectors.toMap(Mozo::getId, Function.identity()));
+
+        List<RepartidorResumen> filas = porRepartidor.entrySet().stream()
+            .map(entry -> resumen(entry.getKey(), repartidores.get(entry.getKey()), entry.getValue()))
+            .sorted(Comparator.comparingInt(RepartidorResumen::entregas).reversed()
+                .thenComparing(RepartidorResumen::minutosPromedio))
+            .toList();
+
+        List<Pedido> entregas = porRepartidor.values().stream().flatMap(List::stream).toList();
+        return new ReporteRepartidoresResponse(desde, hasta, entregas.size(), promedioMinutos(entregas), filas);
+    }
+
+    private static RepartidorResumen resumen(MozoId repartidorId, Mozo repartidor, List<Pedido> entregas) {
+        long maximo = entregas.stream().mapToLong(Pedido::minutosReparto).max().orElse(0);
+        BigDecimal efectivo = entregas.stream()
+            .map(Pedido::calcularCobradoEfectivo)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        return new RepartidorResumen(
+            repartidorId.getValue(),
+            repartidor != null ? repartidor.getNombre() : "Repartidor desconocido",
+            entregas.size(),
+            promedioMinutos(entregas),
+            maximo,
+            efectivo
+        );
+    }
+
+    private static long promedioMinutos(List<Pedido> entregas) {
+        return Math.round(entregas.stream().mapToLong(Pedido::minutosReparto).average().orElse(0));
+    }
+}
@@ -0,0 +1,157 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteSucursalesResponse;
+import com.agustinpalma.comandas.application.dto.ReporteSucursalesResponse.SucursalResumen;
+import com.agustinpalma.comandas.application.ports.output.IdentidadSucursalProvider;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.SucursalId;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.VentaSucursal;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.VentaSucursalRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.EnumMap;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+
+/**
+ * Caso de uso: reporte comparativo de ventas por sucursal.
+ *
+ * HU-107: Multi-sucursal con reportes consolidados.
+ *
+ * La sucursal que consulta se calcula en vivo desde sus pedidos cerrados;
+ * las demás, desde las ventas recibidas por sincronización. Cada sucursal
+ * queda en su propia fila: no se mezclan cajas ni arqueos entre locales.
+ *
+ * Criterio de venta real igual al arqueo: todos los pagos salvo A_CUENTA.
+ */
+@Transactional(readOnly = true)
+public class ConsultarReporteSucursalesUseCase {
+
+    private final PedidoRepository pedidoRepository;
+    private final VentaSucursalRepository ventaSucursalRepository;
+    private final IdentidadSucursalProvider identidadSucursalProvider;
+
+    public ConsultarReporteSucursalesUseCase(PedidoRepository pedidoRepository,
+                                             VentaSucursalRepository ventaSucursalRepository,
+                                             IdentidadSucursalProvider identidadSucursalProvider) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository,
+            "El pedidoRepository es obligatorio");
+        this.ventaSucursalRepository = Objects.requireNonNull(ventaSucursalRepository,
+            "El ventaSucursalRepository es obligatorio");
+        this.identidadSucursalProvider = Objects.requireNonNull(identidadSucursalProvider,
+            "El identidadSucursalProvider es obligatorio");
+    }
+
+    /**
+     * @param localId tenant que consolida
+     * @param desde   primer día del rango (inclusive)
+     * @param hasta   último día del rango (inclusive)
+     * @return una fila por sucursal, la propia primero y el resto por ventas descendentes
+     */
+    public ReporteSucursalesResponse ejecutar(LocalId localId, LocalDate desde, LocalDate hasta) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(desde, "La fecha desde es obligatoria");
+        Objects.requireNonNull(hasta, "La fecha hasta es obligatoria");
+        if (hasta.isBefore(desde)) {
+            throw new IllegalArgumentException("La fecha hasta no puede ser anterior a la fecha desde");
+        }
+
+        LocalDateTime inicio = desde.atStartOfDay();
+        LocalDateTime fin = hasta.plusDays(1).atStartOfDay();
+        SucursalId propia = identidadSucursalProvider.getSucursalId();
+
+        List<SucursalResumen> sucursales = new ArrayList<>();
+        sucursales.add(resumirPropia(localId, propia, inicio, fin));
+
+        Map<SucursalId, List<VentaSucursal>> porSucursal = new LinkedHashMap<>();
+        for (VentaSucursal venta : ventaSucursalRepository.buscarPorFechaCierre(localId, inicio, fin)) {
+            if (!venta.getSucursalId().equals(propia)) {
+                porSucursal.computeIfAbsent(venta.getSucursalId(), id -> new ArrayList<>()).add(venta);
+            }
+        }
+
+        porSucursal.entrySet().stream()
+            .map(entry -> resumirRemota(entry.getKey(), entry.getValue()))
+            .sorted(Comparator.comparing(SucursalResumen::totalVentas).reversed())
+            .forEach(sucursales::add);
+
+        BigDecimal total = sucursales.stream()
+            .map(SucursalResumen::totalVentas)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        return new ReporteSucursalesResponse(desde, hasta, sucursales, total);
+    }
+
+    private SucursalResumen resumirPropia(LocalId localId, SucursalId propia,
+                                          LocalDateTime inicio, LocalDateTime fin) {
+        // buscarCerradosPorFecha es inclusive en ambos extremos: se descarta el borde superior
+        List<Pedido> pedidos = pedidoRepository.buscarCerradosPorFecha(localId, inicio, fin).stream()
+            .filter(p -> p.getFechaCierre() != null && p.getFechaCierre().isBefore(fin))
+            .toList();
+
+        Map<MedioPago, BigDecimal> porMedio = new EnumMap<>(MedioPago.class);
+        LocalDateTime ultima = null;
+        for (Pedido pedido : pedidos) {
+            for (Pago pago : pedido.getPagos()) {
+                porMedio.merge(pago.getMedio(), pago.getMonto(), BigDecimal::add);
+            }
+            if (ultima == null || pedido.getFechaCierre().isAfter(ultima)) {
+                ultima = pedido.getFechaCierre();
+            }
+        }
+
+        return resumen(propia, identidadSucursalProvider.getNombreSucursal(), true,
+            pedidos.size(), porMedio, ultima);
+    }
+
+    private SucursalResumen resumirRemota(SucursalId sucursalId, List<VentaSucursal> ventas) {
+        Map<MedioPago, BigDecimal> porMedio = new EnumMap<>(MedioPago.class);
+        VentaSucursal ultima = null;
+        for (VentaSucursal venta : ventas) {
+            venta.getPagos().forEach((medio, monto) -> porMedio.merge(medio, monto, BigDecimal::add));
+            if (ultima == null || venta.getFechaCierre().isAfter(ultima.getFechaCierre())) {
+                ultima = venta;
+            }
+        }
+
+        // El nombre se toma de la venta más reciente: si la sucursal se renombró, gana el actual
+        return resumen(sucursalId, ultima.getNombreSucursal(), false,
+            ventas.size(), porMedio, ultima.getFechaCierre());
+    }
+
+    private static SucursalResumen resumen(SucursalId sucursalId, String nombre, boolean propia,
+                                           int cantidadPedidos, Map<MedioPago, BigDecimal> porMedio,
+                                           LocalDateTime ultimaVenta) {
+        BigDecimal totalVentas = porMedio.entrySet().stream()
+            .filter(e -> e.getKey() != MedioPago.A_CUENTA)
+            .map(Map.Entry::getValue)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        BigDecimal ticketPromedio = cantidadPedidos == 0
+            ? BigDecimal.ZERO
+            : totalVentas.divide(BigDecimal.valueOf(cantidadPedidos), 2, RoundingMode.HALF_UP);
+
+        return new SucursalResumen(
+            sucursalId.getValue(),
+            nombre,
+            propia,
+            cantidadPedidos,
+            totalVentas,
+            ticketPromedio,
+            porMedio,
+            ultimaVenta
+        );
+    }
+}
@@ -0,0 +1,106 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteTiemposCocinaResponse;
+import com.agustinpalma.comandas.application.dto.ReporteTiemposCocinaResponse.FranjaHoraria;
+import com.agustinpalma.comandas.domain.model.ComandaCocina;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.repository.ComandaCocinaRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.LocalDate;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.TreeMap;
+import java.util.stream.Collectors;
+
+/**
+ * Caso de uso: reporte de tiempos de cocina por franja horaria.
+ *
+ * HU-119: Tiempo promedio desde que la comanda llega a cocina hasta que se
+ * marca lista, agrupado por la hora del envío, para ver en qué momentos del
+ * servicio se satura la cocina.
+ *
+ * Las comandas que nunca se marcaron listas no tienen tiempo final y quedan
+ * fuera del reporte.
+ */
+@Transactional(readOnly = true)
+public class ConsultarReporteTiemposCocinaUseCase {
+
+    private static final BigDecimal SEGUNDOS_POR_MINUTO = BigDecimal.valueOf(60);
+
+    private final ComandaCocinaRepository comandaCocinaRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
+
+    public ConsultarReporteTiemposCocinaUseCase(ComandaCocinaRepository comandaCocinaRepository,
+                                                JornadaComercialRepository jornadaComercialRepository) {
+        this.comandaCocinaRepository = Objects.requireNonNull(comandaCocinaRepository, "El comandaCocinaRepository es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository, "El jornadaComercialRepository es obligatorio");
+    }
+
+    /**
+     * @param localId tenant que consulta
+     * @param desde   primera jornada comercial del rango (inclusive, HU-153)
+     * @param hasta   última jornada comercial del rango (inclusive)
+     */
+    public ReporteTiemposCocinaResponse ejecutar(LocalId localId, LocalDate desde, LocalDate hasta) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(desde, "La fecha desde es obligatoria");
+        Objects.requireNonNull(hasta, "La fecha hasta es obligatoria");
+        if (hasta.isBefore(desde)) {
+            throw new IllegalArgumentException("La fecha hasta no puede ser anterior a la fecha desde");
+        }
+
+        JornadaComercial jornada = jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId));
+        List<ComandaCocina> comandas = comandaCocinaRepository.buscarListasEnviadasEntre(
+            localId, jornada.inicio(desde), jornada.inicio(hasta.plusDays(1)));
+
+        Map<Integer, List<ComandaCocina>> porHora = comandas.stream()
+            .collect(Collectors.groupingBy(c -> c.getEnviadaEn().getHour(), TreeMap::new, Collectors.toList()));
+
+        List<FranjaHoraria> franjas = porHora.entrySet().stream()
+            .map(entry -> new FranjaHoraria(
+                entry.getKey(),
+                entry.getValue().size(),
+                promedioMinutos(entry.getValue()),
+                minutos(entry.getValue().stream().mapToLong(ConsultarReporteTiemposCocinaUseCase::segundos).max().orElse(0)),
+                contarDemoradas(entry.getValue())
+            ))
+            .toList();
+
+        return new ReporteTiemposCocinaResponse(
+            desde,
+            hasta,
+            comandas.size(),
+            promedioMinutos(comandas),
+            contarDemoradas(comandas),
+            franjas
+        );
+    }
+
+    private static long segundos(ComandaCocina comanda) {
+        return comanda.tiempoEnCocina(comanda.getListaEn()).getSeconds();
+    }
+
+    private static int contarDemoradas(List<ComandaCocina> comandas) {
+        return (int) comandas.stream().filter(c -> c.estaDemorada(c.getListaEn())).count();
+    }
+
+    private static BigDecimal promedioMinutos(List<ComandaCocina> comandas) {
+        if (comandas.isEmpty()) {
+            return BigDecimal.ZERO.setScale(1);
+        }
+        long total = comandas.stream().mapToLong(ConsultarReporteTiemposCocinaUseCase::segundos).sum();
+        return BigDecimal.valueOf(total)
+            .divide(SEGUNDOS_POR_MINUTO.multiply(BigDecimal.valueOf(comandas.size())), 1, RoundingMode.HALF_UP);
+    }
+
+    private static BigDecimal minutos(long segundos) {
+        return BigDecimal.valueOf(segundos).divide(SEGUNDOS_POR_MINUTO, 1, RoundingMode.HALF_UP);
+    }
+}
@@ -0,0 +1,93 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ClienteResponse;
+import com.agustinpalma.comandas.application.dto.MovimientoCuentaCorrienteResponse;
+import com.agustinpalma.comandas.application.dto.ResumenCuentaClienteResponse;
+import com.agustinpalma.comandas.domain.model.Cliente;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.MovimientoCuentaCorriente;
+import com.agustinpalma.comandas.domain.repository.ClienteRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCuentaCorrienteRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.time.YearMonth;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Caso de uso para obtener el resumen mensual de la cuenta corriente de un cliente.
+ *
+ * HU-104: Cuenta corriente de clientes.
+ *
+ * El resumen arrastra como saldo anterior la deuda acumulada antes del mes
+ * y lista los cargos y pagos del mes en orden cronológico. El frontend lo
+ * exporta como archivo para enviárselo al cliente.
+ */
+@Transactional(readOnly = true)
+public class ConsultarResumenCuentaClienteUseCase {
+
+    private final ClienteRepository clienteRepository;
+    private final MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository;
+
+    public ConsultarResumenCuentaClienteUseCase(
+            ClienteRepository clienteRepository,
+            MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository
+    ) {
+        this.clienteRepository = Objects.requireNonNull(clienteRepository, "El clienteRepository es obligatorio");
+        this.movimientoCuentaCorrienteRepository = Objects.requireNonNull(movimientoCuentaCorrienteRepository, "El movimientoCuentaCorrienteRepository es obligatorio");
+    }
+
+    /**
+     * @param clienteId cliente a consultar
+     * @param localId identificador del local (tenant)
+     * @param mes período del resumen
+     * @return resumen con saldo anterior, totales del mes, saldo final y detalle
+     * @throws IllegalArgumentException si el cliente no existe en el local
+     */
+    public ResumenCuentaClienteResponse ejecutar(ClienteId clienteId, LocalId localId, YearMonth mes) {
+        Objects.requireNonNull(clienteId, "El clienteId es obligatorio");
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(mes, "El mes es obligatorio");
+
+        Cliente cliente = clienteRepository.buscarPorIdYLocal(clienteId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("Cliente no encontrado"));
+
+        List<MovimientoCuentaCorriente> movimientos =
+            movimientoCuentaCorrienteRepository.buscarPorCliente(clienteId, localId);
+
+        LocalDateTime inicioMes = mes.atDay(1).atStartOfDay();
+        LocalDateTime inicioMesSiguiente = mes.plusMonths(1).atDay(1).atStartOfDay();
+
+        List<MovimientoCuentaCorriente> anteriores = movimientos.stream()
+            .filter(m -> m.getFecha().isBefore(inicioMes))
+            .toList();
+        List<MovimientoCuentaCorriente> delMes = movimientos.stream()
+            .filter(m -> !m.getFecha().isBefore(inicioMes) && m.getFecha().isBefore(inicioMesSiguiente))
+            .toList();
+
+        BigDecimal saldoAnterior = MovimientoCuentaCorriente.calcularSaldo(anteriores);
+        BigDecimal totalCargos = sumar(delMes, true);
+        BigDecimal totalPagos = sumar(delMes, false);
+        BigDecimal saldoFinal = saldoAnterior.add(totalCargos).subtract(totalPagos);
+
+        return new ResumenCuentaClienteResponse(
+            ClienteResponse.fromDomain(cliente, MovimientoCuentaCorriente.calcularSaldo(movimientos)),
+            mes.toString(),
+            saldoAnterior,
+            totalCargos,
+            totalPagos,
+            saldoFinal,
+            delMes.stream().map(MovimientoCuentaCorrienteResponse::fromDomain).toList()
+        );
+    }
+
+    private BigDecimal sumar(List<MovimientoCuentaCorriente> movimientos, boolean cargos) {
+        return movimientos.stream()
+            .filter(m -> m.esCargo() == cargos)
+            .map(MovimientoCuentaCorriente::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+}
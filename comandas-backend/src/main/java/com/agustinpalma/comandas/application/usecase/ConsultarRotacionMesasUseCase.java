@@ -0,0 +1,135 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteRotacionMesasResponse;
+import com.agustinpalma.comandas.application.dto.ReporteRotacionMesasResponse.FranjaOcupacion;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.DayOfWeek;
+import java.time.Duration;
+import java.time.LocalDate;
+import java.time.LocalTime;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.TreeMap;
+import java.util.stream.Collectors;
+
+/**
+ * Caso de uso: rotación de mesas por franja horaria.
+ *
+ * HU-150: Cuánto dura en promedio cada ocupación de mesa según la hora en
+ * que se abrió, para dimensionar cuántos cubiertos más se pueden tomar en un
+ * día pico (filtrando, por ejemplo, solo los sábados).
+ *
+ * Solo cuentan los pedidos de salón cerrados. La capacidad por hora es una
+ * estimación de régimen: con N mesas y ocupaciones de M minutos, el salón
+ * sienta N × 60 / M mesas por hora. La diferencia con lo que se abrió en
+ * promedio, por los cubiertos promedio, son los comensales que todavía entran.
+ */
+@Transactional(readOnly = true)
+public class ConsultarRotacionMesasUseCase {
+
+    private static final BigDecimal SEGUNDOS_POR_MINUTO = BigDecimal.valueOf(60);
+    private static final BigDecimal MINUTOS_POR_HORA = BigDecimal.valueOf(60);
+
+    private final PedidoRepository pedidoRepository;
+    private final MesaRepository mesaRepository;
+
+    public ConsultarRotacionMesasUseCase(PedidoRepository pedidoRepository, MesaRepository mesaRepository) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.mesaRepository = Objects.requireNonNull(mesaRepository, "El mesaRepository es obligatorio");
+    }
+
+    /**
+     * @param localId   tenant que consulta
+     * @param desde     primer día del rango (inclusive)
+     * @param hasta     último día del rango (inclusive)
+     * @param diaSemana solo las mesas abiertas ese día de la semana (null = todos)
+     * @throws IllegalArgumentException si el rango está invertido
+     */
+    public ReporteRotacionMesasResponse ejecutar(LocalId localId, LocalDate desde, LocalDate hasta,
+                                                 DayOfWeek diaSemana) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(desde, "La fecha desde es obligatoria");
+        Objects.requireNonNull(hasta, "La fecha hasta es obligatoria");
+        if (hasta.isBefore(desde)) {
+            throw new IllegalArgumentException("La fecha hasta no puede ser anterior a la fecha desde");
+        }
+
+        List<Pedido> ocupaciones = pedidoRepository
+            .buscarCerradosPorFecha(localId, desde.atStartOfDay(), hasta.atTime(LocalTime.MAX))
+            .stream()
+            .filter(p -> p.getCanal() == CanalVenta.SALON && p.getFechaCierre() != null)
+            .filter(p -> !p.getFechaApertura().toLocalDate().isBefore(desde))
+            .filter(p -> diaSemana == null || p.getFechaApertura().getDayOfWeek() == diaSemana)
+            .toList();
+
+        int dias = (int) desde.datesUntil(hasta.plusDays(1))
+            .filter(d -> diaSemana == null || d.getDayOfWeek() == diaSemana)
+            .count();
+        int mesas = mesaRepository.buscarPorLocal(localId).size();
+
+        Map<Integer, List<Pedido>> porHora = ocupaciones.stream()
+            .collect(Collectors.groupingBy(p -> p.getFechaApertura().getHour(), TreeMap::new, Collectors.toList()));
+
+        List<FranjaOcupacion> franjas = porHora.entrySet().stream()
+            .map(entry -> franja(entry.getKey(), entry.getValue(), dias, mesas))
+            .toList();
+
+        return new ReporteRotacionMesasResponse(
+            desde,
+            hasta,
+            diaSemana,
+            dias,
+            mesas,
+            ocupaciones.size(),
+            promedioMinutos(ocupaciones),
+            ocupaciones.stream().mapToInt(Pedido::getCubiertos).sum(),
+            mesas == 0 || dias == 0
+                ? BigDecimal.ZERO.setScale(2)
+                : BigDecimal.valueOf(ocupaciones.size()).divide(BigDecimal.valueOf((long) mesas * dias), 2, RoundingMode.HALF_UP),
+            franjas
+        );
+    }
+
+    private static FranjaOcupacion franja(int hora, List<Pedido> pedidos, int dias, int mesas) {
+        BigDecimal promedio = promedioMinutos(pedidos);
+        BigDecimal porDia = BigDecimal.valueOf(pedidos.size()).divide(BigDecimal.valueOf(Math.max(dias, 1)), 1, RoundingMode.HALF_UP);
+        BigDecimal capacidad = promedio.signum() > 0
+            ? BigDecimal.valueOf(mesas).multiply(MINUTOS_POR_HORA).divide(promedio, 1, RoundingMode.HALF_DOWN)
+            : null;
+
+        List<Pedido> conCubiertos = pedidos.stream().filter(p -> p.getCubiertos() > 0).toList();
+        BigDecimal cubiertosPromedio = conCubiertos.isEmpty()
+            ? null
+            : BigDecimal.valueOf(conCubiertos.stream().mapToInt(Pedido::getCubiertos).sum())
+                .divide(BigDecimal.valueOf(conCubiertos.size()), 1, RoundingMode.HALF_UP);
+
+        Integer disponibles = capacidad != null && cubiertosPromedio != null
+            ? capacidad.subtract(porDia).max(BigDecimal.ZERO).multiply(cubiertosPromedio)
+                .setScale(0, RoundingMode.DOWN).intValue()
+            : null;
+
+        return new FranjaOcupacion(hora, pedidos.size(), porDia, promedio, cubiertosPromedio, capacidad, disponibles);
+    }
+
+    private static long segundos(Pedido pedido) {
+        return Math.max(0, Duration.between(pedido.getFechaApertura(), pedido.getFechaCierre()).getSeconds());
+    }
+
+    private static BigDecimal promedioMinutos(List<Pedido> pedidos) {
+        if (pedidos.isEmpty()) {
+            return BigDecimal.ZERO.setScale(1);
+        }
+        long total = pedidos.stream().mapToLong(ConsultarRotacionMesasUseCase::segundos).sum();
+        return BigDecimal.valueOf(total)
+            .divide(SEGUNDOS_POR_MINUTO.multiply(BigDecimal.valueOf(pedidos.size())), 1, RoundingMode.HALF_UP);
+    }
+}
@@ -0,0 +1,78 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.CierreJornadaResponse;
+import com.agustinpalma.comandas.application.dto.TurnoCajaResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.repository.JornadaCajaRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Caso de uso: consultar los turnos de una jornada.
+ *
+ * HU-106: Gestión de turnos con arqueo por turno.
+ *
+ * - Turnos de la jornada en curso: para saber cuál está activo y cuáles
+ *   quedan disponibles.
+ * - Consolidado de una jornada cerrada: los turnos con la diferencia de
+ *   caja de cada uno y la diferencia total.
+ */
+@Transactional(readOnly = true)
+public class ConsultarTurnosJornadaUseCase {
+
+    private final JornadaCajaRepository jornadaCajaRepository;
+    private final TurnoCajaRepository turnoCajaRepository;
+
+    public ConsultarTurnosJornadaUseCase(JornadaCajaRepository jornadaCajaRepository,
+                                         TurnoCajaRepository turnoCajaRepository) {
+        this.jornadaCajaRepository = Objects.requireNonNull(jornadaCajaRepository,
+            "jornadaCajaRepository es obligatorio");
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository,
+            "turnoCajaRepository es obligatorio");
+    }
+
+    /**
+     * Turnos de la jornada abierta, en orden de apertura.
+     *
+     * @return lista vacía si no hay jornada abierta o todavía no se abrió ningún turno
+     */
+    public List<TurnoCajaResponse> ejecutar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        return jornadaCajaRepository.buscarAbierta(localId)
+            .map(jornada -> turnoCajaRepository.buscarPorJornada(jornada.getId(), localId).stream()
+                .map(TurnoCajaResponse::fromDomain)
+                .toList())
+            .orElse(List.of());
+    }
+
+    /**
+     * Consolidado de turnos de una jornada: cada turno con su diferencia y la suma de todas.
+     *
+     * @param jornadaId jornada a consolidar
+     * @param localId   identificador del local (tenant)
+     */
+    public CierreJornadaResponse consolidar(JornadaCajaId jornadaId, LocalId localId) {
+        Objects.requireNonNull(jornadaId, "El jornadaId es obligatorio");
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        List<TurnoCaja> turnos = turnoCajaRepository.buscarPorJornada(jornadaId, localId);
+
+        BigDecimal diferenciaTotal = turnos.stream()
+            .map(TurnoCaja::getDiferencia)
+            .filter(Objects::nonNull)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        return new CierreJornadaResponse(
+            jornadaId.getValue(),
+            turnos.stream().map(TurnoCajaResponse::fromDomain).toList(),
+            diferenciaTotal
+        );
+    }
+}
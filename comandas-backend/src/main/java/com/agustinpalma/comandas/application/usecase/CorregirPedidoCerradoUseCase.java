@@ -4,16 +4,37 @@ import com.agustinpalma.comandas.application.dto.CorreccionPedidoRequest;
 import com.agustinpalma.comandas.application.dto.CorreccionPedidoRequest.ItemCorreccion;
 import com.agustinpalma.comandas.application.dto.CorreccionPedidoRequest.PagoCorreccion;
 import com.agustinpalma.comandas.application.dto.DetallePedidoCerradoResponse;
+import com.agustinpalma.comandas.domain.model.Cliente;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Moneda;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ItemPedidoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
 import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.MovimientoCuentaCorriente;
+import com.agustinpalma.comandas.domain.model.MovimientoPuntos;
 import com.agustinpalma.comandas.domain.model.Pago;
 import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.ProgramaPuntos;
+import com.agustinpalma.comandas.domain.model.RecargosMedioPago;
+import com.agustinpalma.comandas.domain.model.RedondeoEfectivo;
+import com.agustinpalma.comandas.domain.repository.ClienteRepository;
+import com.agustinpalma.comandas.domain.repository.ConsumoPersonalRepository;
+import com.agustinpalma.comandas.domain.repository.FacturaRepository;
 import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCuentaCorrienteRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoPuntosRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.ProgramaPuntosRepository;
+import com.agustinpalma.comandas.domain.repository.RecargosMedioPagoRepository;
+import com.agustinpalma.comandas.domain.repository.RedondeoEfectivoRepository;
+import com.agustinpalma.comandas.domain.service.ConsumoPersonalService;
+import com.agustinpalma.comandas.domain.service.CuentaCorrienteService;
+import com.agustinpalma.comandas.domain.service.ProgramaPuntosService;
 import org.springframework.transaction.annotation.Transactional;
 
+import java.math.BigDecimal;
 import java.time.Clock;
 import java.time.LocalDateTime;
 import java.util.HashMap;
@@ -36,27 +57,68 @@ import java.util.UUID;
  * 1. Buscar pedido y validar pertenencia al local
  * 2. Convertir DTOs a objetos de dominio
  * 3. pedido.corregir() → valida, recalcula snapshot, reemplaza pagos
- * 4. Persistir cambios
- * 5. Retornar detalle actualizado
+ *    HU-143: los pagos corregidos llevan el recargo vigente de su medio
+ *    HU-144: lo cobrado con tarjeta de regalo no cambia (ya salió del saldo de la tarjeta)
+ *    HU-151: un pago en moneda extranjera conserva el tipo de cambio con que se cobró
+ * 4. HU-104: Reemplazar el cargo en cuenta corriente según los pagos corregidos
+ * 5. HU-105: Recalcular los puntos ganados según los pagos corregidos
+ *    HU-132: Ajustar el consumo interno imputado al empleado
+ * 6. Persistir cambios
+ * 7. Retornar detalle actualizado
  * 
  * Nota sobre stock: las diferencias de stock por cambios de cantidad
  * se registran en un futuro como MovimientoStock de tipo CORRECCION.
  * En esta primera versión, la corrección es contable (snapshot + pagos).
+ *
+ * HU-115: Un pedido facturado no se corrige; su importe quedó en la factura.
  */
 @Transactional
 public class CorregirPedidoCerradoUseCase {
 
     private final PedidoRepository pedidoRepository;
     private final MesaRepository mesaRepository;
+    private final ClienteRepository clienteRepository;
+    private final MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository;
+    private final CuentaCorrienteService cuentaCorrienteService;
+    private final ProgramaPuntosRepository programaPuntosRepository;
+    private final MovimientoPuntosRepository movimientoPuntosRepository;
+    private final ProgramaPuntosService programaPuntosService;
+    private final FacturaRepository facturaRepository;
+    private final ConsumoPersonalRepository consumoPersonalRepository;
+    private final ConsumoPersonalService consumoPersonalService;
+    private final RecargosMedioPagoRepository recargosMedioPagoRepository;
+    private final RedondeoEfectivoRepository redondeoEfectivoRepository;
     private final Clock clock;
 
     public CorregirPedidoCerradoUseCase(
             PedidoRepository pedidoRepository,
             MesaRepository mesaRepository,
+            ClienteRepository clienteRepository,
+            MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository,
+            CuentaCorrienteService cuentaCorrienteService,
+            ProgramaPuntosRepository programaPuntosRepository,
+            MovimientoPuntosRepository movimientoPuntosRepository,
+            ProgramaPuntosService programaPuntosService,
+            FacturaRepository facturaRepository,
+            ConsumoPersonalRepository consumoPersonalRepository,
+            ConsumoPersonalService consumoPersonalService,
+            RecargosMedioPagoRepository recargosMedioPagoRepository,
+            RedondeoEfectivoRepository redondeoEfectivoRepository,
             Clock clock
     ) {
         this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
         this.mesaRepository = Objects.requireNonNull(mesaRepository, "El mesaRepository es obligatorio");
+        this.clienteRepository = Objects.requireNonNull(clienteRepository, "El clienteRepository es obligatorio");
+        this.movimientoCuentaCorrienteRepository = Objects.requireNonNull(movimientoCuentaCorrienteRepository, "El movimientoCuentaCorrienteRepository es obligatorio");
+        this.cuentaCorrienteService = Objects.requireNonNull(cuentaCorrienteService, "El cuentaCorrienteService es obligatorio");
+        this.programaPuntosRepository = Objects.requireNonNull(programaPuntosRepository, "El programaPuntosRepository es obligatorio");
+        this.movimientoPuntosRepository = Objects.requireNonNull(movimientoPuntosRepository, "El movimientoPuntosRepository es obligatorio");
+        this.programaPuntosService = Objects.requireNonNull(programaPuntosService, "El programaPuntosService es obligatorio");
+        this.facturaRepository = Objects.requireNonNull(facturaRepository, "El facturaRepository es obligatorio");
+        this.consumoPersonalRepository = Objects.requireNonNull(consumoPersonalRepository, "El consumoPersonalRepository es obligatorio");
+        this.consumoPersonalService = Objects.requireNonNull(consumoPersonalService, "El consumoPersonalService es obligatorio");
+        this.recargosMedioPagoRepository = Objects.requireNonNull(recargosMedioPagoRepository, "El recargosMedioPagoRepository es obligatorio");
+        this.redondeoEfectivoRepository = Objects.requireNonNull(redondeoEfectivoRepository, "El redondeoEfectivoRepository es obligatorio");
         this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
     }
 
@@ -88,6 +150,9 @@ public class CorregirPedidoCerradoUseCase {
             throw new IllegalArgumentException("El pedido no pertenece a este local");
         }
 
+        // HU-115: Lo facturado no se corrige
+        validarSinFactura(pedidoId, localId);
+
         // 3. Convertir correcciones de ítems a Map de dominio
         Map<ItemPedidoId, Integer> cantidadesCorregidas = new HashMap<>();
         if (request.items() != null) {
@@ -101,20 +166,162 @@ public class CorregirPedidoCerradoUseCase {
 
         // 4. Convertir pagos a Value Objects de dominio
         LocalDateTime ahora = LocalDateTime.now(clock);
-        List<Pago> nuevosPagos = request.pagos().stream()
-            .map(pc -> new Pago(pc.medio(), pc.monto(), ahora))
-            .toList();
+        RecargosMedioPago recargos = recargosMedioPagoRepository.buscarPorLocal(localId)
+            .orElse(RecargosMedioPago.sinRecargos(localId));
+        // HU-152: el efectivo corregido se vuelve a redondear con la regla vigente
+        RedondeoEfectivo redondeo = redondeoEfectivoRepository.buscarPorLocal(localId)
+            .orElse(RedondeoEfectivo.sinRedondeo(localId));
+        List<Pago> nuevosPagos = redondeo.aplicar(request.pagos().stream()
+            .map(pc -> conMonedaOriginal(recargos.cobrar(pc.medio(), pc.monto(), ahora), pc.moneda(), pedido.getPagos())
+                .conReferencia(pc.referencia())
+                // HU-159: la corrección no cambia la caja que cobró
+                .conCaja(pedido.getCajaId()))
+            .toList());
+
+        // HU-144: El saldo de las tarjetas de regalo se ajusta reabriendo el pedido
+        validarMismoCobroConTarjetaRegalo(pedido.getPagos(), nuevosPagos);
 
         // 5. Aplicar corrección (lógica de dominio)
         pedido.corregir(cantidadesCorregidas, nuevosPagos);
 
-        // 6. Persistir cambios
-        pedidoRepository.guardar(pedido);
-
-        // 7. Resolver mesa para el DTO de respuesta
+        // 6. Resolver mesa (descripción del cargo y DTO de respuesta)
         Mesa mesa = mesaRepository.buscarPorId(pedido.getMesaId())
             .orElseThrow(() -> new IllegalStateException("No se encontró la mesa asociada al pedido"));
 
+        // 7. HU-104: Reemplazar el cargo en cuenta corriente (conserva la fecha del cierre original)
+        reemplazarCargoCuentaCorriente(pedido, mesa, request.clienteId(), localId, pedido.getFechaCierre());
+
+        // 8. HU-105: Recalcular la acumulación de puntos (el canje ya aplicado se conserva)
+        recalcularPuntosAcumulados(pedido, localId);
+
+        // 8b. HU-132: Ajustar el consumo interno del empleado
+        ajustarConsumoInterno(pedido, localId);
+
+        // 9. Persistir cambios
+        pedidoRepository.guardar(pedido);
+
         return DetallePedidoCerradoResponse.fromDomain(pedido, mesa.getNumero());
     }
+
+    /**
+     * HU-104: El cargo anterior del pedido (si existía) se elimina y se genera
+     * uno nuevo con el monto CUENTA_CORRIENTE corregido. Si el request no indica
+     * cliente, se mantiene el del cargo original.
+     */
+    private void reemplazarCargoCuentaCorriente(
+            Pedido pedido, Mesa mesa, String clienteIdRequest, LocalId localId, LocalDateTime fecha) {
+        ClienteId clienteId = clienteIdRequest != null
+            ? ClienteId.from(clienteIdRequest)
+            : movimientoCuentaCorrienteRepository.buscarCargosPorPedido(pedido.getId(), localId).stream()
+                .map(MovimientoCuentaCorriente::getClienteId)
+                .findFirst()
+                .orElse(null);
+
+        movimientoCuentaCorrienteRepository.eliminarCargosPorPedido(pedido.getId(), localId);
+
+        if (cuentaCorrienteService.montoACuentaCorriente(pedido).signum() == 0) {
+            return;
+        }
+
+        Cliente cliente = null;
+        List<MovimientoCuentaCorriente> movimientos = List.of();
+        if (clienteId != null) {
+            cliente = clienteRepository.buscarPorIdYLocal(clienteId, localId)
+                .orElseThrow(() -> new IllegalArgumentException("El cliente no existe en este local"));
+            movimientos = movimientoCuentaCorrienteRepository.buscarPorCliente(clienteId, localId);
+        }
+
+        cuentaCorrienteService
+            .registrarCargo(pedido, mesa.getNumero(), cliente, movimientos, fecha)
+            .ifPresent(movimientoCuentaCorrienteRepository::guardar);
+    }
+
+    /**
+     * HU-132: El consumo imputado conserva el empleado y la fecha; toma el nuevo
+     * monto A_CUENTA, o se elimina si la corrección dejó el pedido sin pagos a cuenta.
+     */
+    private void ajustarConsumoInterno(Pedido pedido, LocalId localId) {
+        consumoPersonalRepository.buscarPorPedido(pedido.getId(), localId).ifPresent(consumo -> {
+            BigDecimal monto = consumoPersonalService.montoConsumoInterno(pedido);
+            if (monto.signum() == 0) {
+                consumoPersonalRepository.eliminarPorPedido(pedido.getId(), localId);
+                return;
+            }
+            consumo.corregirMonto(monto);
+            consumoPersonalRepository.guardar(consumo);
+        });
+    }
+
+    /**
+     * HU-105: La acumulación original del pedido se reemplaza por una calculada
+     * sobre los pagos corregidos, con la misma fecha y vencimiento que la original.
+     * Si el pedido no había sumado puntos (no se identificó cliente), no hace nada.
+     */
+    private void recalcularPuntosAcumulados(Pedido pedido, LocalId localId) {
+        MovimientoPuntos original = movimientoPuntosRepository.buscarPorPedido(pedido.getId(), localId).stream()
+            .filter(MovimientoPuntos::esAcumulacion)
+            .findFirst()
+            .orElse(null);
+        if (original == null) {
+            return;
+        }
+
+        ProgramaPuntos programa = programaPuntosRepository.buscarPorLocal(localId)
+            .orElse(ProgramaPuntos.porDefecto(localId));
+        int puntos = programa.puntosPorMonto(programaPuntosService.montoAcumulable(pedido));
+
+        movimientoPuntosRepository.eliminarAcumulacionesPorPedido(pedido.getId(), localId);
+
+        if (puntos > 0) {
+            movimientoPuntosRepository.guardar(MovimientoPuntos.acumulacion(
+                original.getClienteId(), localId, pedido.getId(),
+                puntos, original.getFecha(), original.getFechaVencimiento()
+            ));
+        }
+    }
+
+    /**
+     * HU-115: Un comprobante fiscal emitido no se modifica; el camino es la nota de crédito.
+     */
+    private static void validarMismoCobroConTarjetaRegalo(List<Pago> originales, List<Pago> corregidos) {
+        if (totalTarjetaRegalo(originales).compareTo(totalTarjetaRegalo(corregidos)) != 0) {
+            throw new IllegalStateException(
+                "Lo cobrado con tarjeta de regalo no se corrige; reabrí el pedido para cambiarlo");
+        }
+    }
+
+    /**
+     * HU-151: La corrección no toma el tipo de cambio de hoy: usa el que quedó
+     * congelado en los pagos originales de esa moneda.
+     *
+     * @throws IllegalStateException si el pedido no se había cobrado en esa moneda
+     */
+    private static Pago conMonedaOriginal(Pago enPesos, Moneda moneda, List<Pago> originales) {
+        if (moneda == null || moneda == Moneda.ARS) {
+            return enPesos;
+        }
+        BigDecimal tipoCambio = originales.stream()
+            .filter(pago -> pago.getMoneda() == moneda)
+            .map(Pago::getTipoCambio)
+            .findFirst()
+            .orElseThrow(() -> new IllegalStateException(
+                "El pedido no se cobró en " + moneda + "; reabrí el pedido para cobrarlo en esa moneda"));
+        return enPesos.enMoneda(moneda, tipoCambio);
+    }
+
+    private static BigDecimal totalTarjetaRegalo(List<Pago> pagos) {
+        return pagos.stream()
+            .filter(pago -> pago.getMedio() == MedioPago.TARJETA_REGALO)
+            .map(Pago::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    private void validarSinFactura(PedidoId pedidoId, LocalId localId) {
+        facturaRepository.buscarPorPedido(pedidoId, localId).ifPresent(factura -> {
+            throw new IllegalStateException(String.format(
+                "El pedido tiene la factura %s %s emitida y no se puede corregir",
+                factura.getTipo(), factura.numeroFormateado()
+            ));
+        });
+    }
 }
@@ -66,6 +66,13 @@ public class CrearCategoriaUseCase {
             orden,
             categoriaModificadoresId
         );
+        // HU-119: 0 equivale a no configurar umbral de cocina
+        if (request.minutosAlertaCocina() != null && request.minutosAlertaCocina() != 0) {
+            nuevaCategoria.cambiarMinutosAlertaCocina(request.minutosAlertaCocina());
+        }
+        if (request.horario() != null) {
+            nuevaCategoria.cambiarFranjaHoraria(request.horario().toDomain());
+        }
 
         Categoria categoriaGuardada = categoriaRepository.guardar(nuevaCategoria);
         return CategoriaResponse.fromDomain(categoriaGuardada);
@@ -0,0 +1,63 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ClienteRequest;
+import com.agustinpalma.comandas.application.dto.ClienteResponse;
+import com.agustinpalma.comandas.domain.model.Cliente;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.repository.ClienteRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.util.Objects;
+
+/**
+ * Caso de uso para dar de alta un cliente con cuenta corriente.
+ *
+ * HU-104: Cuenta corriente de clientes.
+ *
+ * Reglas de negocio:
+ * - El nombre debe ser único dentro del local (case insensitive)
+ * - El cliente nace sin deuda
+ */
+@Transactional
+public class CrearClienteUseCase {
+
+    private final ClienteRepository clienteRepository;
+
+    public CrearClienteUseCase(ClienteRepository clienteRepository) {
+        this.clienteRepository = Objects.requireNonNull(clienteRepository, "El clienteRepository es obligatorio");
+    }
+
+    /**
+     * @param localId identificador del local (tenant)
+     * @param request datos del cliente
+     * @return DTO del cliente creado
+     * @throws IllegalArgumentException si el nombre ya existe o los datos son inválidos
+     */
+    public ClienteResponse ejecutar(LocalId localId, ClienteRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        if (clienteRepository.existePorNombreYLocal(request.nombre(), localId)) {
+            throw new IllegalArgumentException(
+                "Ya existe un cliente con el nombre '" + request.nombre() + "' en este local"
+            );
+        }
+
+        Cliente cliente = new Cliente(
+            ClienteId.generate(),
+            localId,
+            request.nombre(),
+            request.telefono(),
+            request.limiteCredito(),
+            request.activo() != null ? request.activo() : true,
+            request.fechaNacimiento(),
+            request.preferencias(),
+            request.alergias()
+        );
+
+        Cliente guardado = clienteRepository.guardar(cliente);
+        return ClienteResponse.fromDomain(guardado, BigDecimal.ZERO);
+    }
+}
@@ -40,6 +40,18 @@ public class CrearMesaUseCase {
      * @throws IllegalArgumentException si el número es inválido (≤ 0) (400 Bad Request)
      */
     public MesaResponse ejecutar(LocalId localId, int numero) {
+        return ejecutar(localId, numero, null, null, null);
+    }
+
+    /**
+     * HU-112: Crea la mesa directamente en un sector y una celda del mapa.
+     *
+     * @param sector sector del salón (null = sector por defecto)
+     * @param posicionX columna en el mapa (null = ubicación automática)
+     * @param posicionY fila en el mapa (null = ubicación automática)
+     * @throws IllegalArgumentException si la celda ya está ocupada por otra mesa del sector
+     */
+    public MesaResponse ejecutar(LocalId localId, int numero, String sector, Integer posicionX, Integer posicionY) {
         Objects.requireNonNull(localId, "El localId es obligatorio");
         
         // Validación de negocio: el número debe ser positivo mayor a 0
@@ -62,6 +74,19 @@ public class CrearMesaUseCase {
         // El estado LIBRE se asigna automáticamente en el constructor
         MesaId nuevoId = MesaId.generate();
         Mesa nuevaMesa = new Mesa(nuevoId, localId, numero);
+        nuevaMesa.ubicar(sector, posicionX, posicionY);
+
+        if (nuevaMesa.getPosicionX() != null) {
+            mesaRepository.buscarPorLocal(localId).stream()
+                .filter(m -> m.getSector().equalsIgnoreCase(nuevaMesa.getSector())
+                    && nuevaMesa.getPosicionX().equals(m.getPosicionX())
+                    && nuevaMesa.getPosicionY().equals(m.getPosicionY()))
+                .findFirst()
+                .ifPresent(ocupante -> {
+                    throw new IllegalArgumentException(
+                        "Ese lugar ya está ocupado por la mesa " + ocupante.getNumero());
+                });
+        }
 
         // Persistir
         Mesa mesaGuardada = mesaRepository.guardar(nuevaMesa);
@@ -1,12 +1,15 @@
 package com.agustinpalma.comandas.application.usecase;
 
+import com.agustinpalma.comandas.application.dto.ComponenteComboRequest;
 import com.agustinpalma.comandas.application.dto.ProductoRequest;
 import com.agustinpalma.comandas.application.dto.ProductoResponse;
+import com.agustinpalma.comandas.domain.model.ComponenteCombo;
 import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
 import com.agustinpalma.comandas.domain.model.Producto;
 import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import java.util.List;
 import java.util.Objects;
 
 /**
@@ -18,6 +21,7 @@ import java.util.Objects;
  * - El color se normaliza automáticamente a mayúsculas
  * - Si no se provee color, se asigna #FFFFFF por defecto
  * - El producto se vincula de forma inmutable al LocalId
+ * - HU-103: Las opciones de un combo deben ser productos del local (ni extras ni combos)
  */
 public class CrearProductoUseCase {
 
@@ -79,6 +83,14 @@ public class CrearProductoUseCase {
             0,      // stockActual inicial
             activarStock
         );
+        nuevoProducto.definirStockMinimo(request.stockMinimo());
+        if (request.componentesCombo() != null) {
+            nuevoProducto.definirComponentesCombo(resolverComponentesCombo(request.componentesCombo(), localId));
+        }
+        nuevoProducto.definirAlergenos(request.alergenos());
+        if (request.horario() != null) {
+            nuevoProducto.definirFranjaHoraria(request.horario().toDomain());
+        }
 
         // Persistir
         Producto productoGuardado = productoRepository.guardar(nuevoProducto);
@@ -86,4 +98,31 @@ public class CrearProductoUseCase {
         // Retornar DTO
         return ProductoResponse.fromDomain(productoGuardado);
     }
+
+    /**
+     * HU-103: Convierte los componentes del request y valida sus opciones.
+     * Cada opción debe existir en el local y ser un producto vendible por sí mismo
+     * (ni extra ni otro combo).
+     */
+    private List<ComponenteCombo> resolverComponentesCombo(List<ComponenteComboRequest> componentes, LocalId localId) {
+        List<ComponenteCombo> resultado = componentes.stream()
+            .map(ComponenteComboRequest::toDomain)
+            .toList();
+
+        for (ComponenteCombo componente : resultado) {
+            for (ProductoId opcionId : componente.getOpciones()) {
+                Producto opcion = productoRepository.buscarPorIdYLocal(opcionId, localId)
+                    .orElseThrow(() -> new IllegalArgumentException(
+                        "No se encontró el producto de la opción con ID: " + opcionId.getValue()
+                    ));
+                if (opcion.isEsExtra() || opcion.esCombo()) {
+                    throw new IllegalArgumentException(String.format(
+                        "El producto '%s' no puede ser opción del componente '%s' (es un extra o un combo)",
+                        opcion.getNombre(), componente.getNombre()
+                    ));
+                }
+            }
+        }
+        return resultado;
+    }
 }
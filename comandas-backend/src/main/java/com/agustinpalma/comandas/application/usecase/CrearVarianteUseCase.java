@@ -3,6 +3,7 @@ package com.agustinpalma.comandas.application.usecase;
 import com.agustinpalma.comandas.application.dto.ProductoResponse;
 import com.agustinpalma.comandas.application.dto.VarianteProductoRequest;
 import com.agustinpalma.comandas.application.dto.VarianteProductoResponse;
+import com.agustinpalma.comandas.application.dto.VariantesEnLoteRequest;
 import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
@@ -37,6 +38,11 @@ import org.springframework.transaction.annotation.Transactional;
  * - El nombre debe ser único dentro del local (como cualquier producto)
  * - La variante hereda colorHex, categoriaId, permiteExtras y requiereConfiguracion
  *   del producto base si no se especifican explícitamente
+ *
+ * HU-109: Variantes por tamaño
+ * - El nombre puede omitirse si hay etiqueta: se arma como "{base} {etiqueta}"
+ * - La cantidadDiscosCarne puede omitirse: se toma la siguiente posición del grupo
+ * - El alta en lote crea todos los tamaños en una sola transacción
  */
 @Transactional
 public class CrearVarianteUseCase {
@@ -65,6 +71,58 @@ public class CrearVarianteUseCase {
         Objects.requireNonNull(request, "El request es obligatorio");
 
         // 1. Buscar producto base con validación multi-tenancy
+        Producto productoBase = buscarProductoBase(localId, productoBaseId);
+
+        // 2. Determinar grupoVarianteId
+        ProductoId grupoId = resolverGrupo(productoBase);
+
+        // 3-6. Validar y persistir la nueva variante
+        Producto varianteGuardada = crearVariante(localId, productoBase, grupoId, request);
+
+        // 7. Retornar respuesta con la variante creada y todas las hermanas
+        return new VarianteProductoResponse(
+            ProductoResponse.fromDomain(varianteGuardada),
+            listarGrupo(localId, grupoId)
+        );
+    }
+
+    /**
+     * HU-109: Crea varios tamaños de un producto en una sola operación.
+     *
+     * Si se indica etiquetaBase, el producto base la adopta y su nombre se
+     * completa con ella (ej: "Pizza Muzzarella" → "Pizza Muzzarella Chica"),
+     * así cada tamaño tiene un nombre propio en la comanda y en los reportes.
+     * Si cualquier variante es inválida no se crea ninguna.
+     *
+     * @param localId identificador del local (tenant)
+     * @param productoBaseId ID del producto base
+     * @param request etiqueta del base y variantes a crear
+     * @return todas las variantes del grupo, incluido el base
+     * @throws IllegalArgumentException si alguna variante no supera las validaciones
+     */
+    public List<ProductoResponse> ejecutarLote(LocalId localId, ProductoId productoBaseId, VariantesEnLoteRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(productoBaseId, "El productoBaseId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+        if (request.variantes() == null || request.variantes().isEmpty()) {
+            throw new IllegalArgumentException("Debe indicar al menos una variante");
+        }
+
+        Producto productoBase = buscarProductoBase(localId, productoBaseId);
+        ProductoId grupoId = resolverGrupo(productoBase);
+
+        if (request.etiquetaBase() != null && !request.etiquetaBase().isBlank()) {
+            etiquetarProductoBase(localId, productoBase, request.etiquetaBase());
+        }
+
+        for (VarianteProductoRequest variante : request.variantes()) {
+            crearVariante(localId, productoBase, grupoId, variante);
+        }
+
+        return listarGrupo(localId, grupoId);
+    }
+
+    private Producto buscarProductoBase(LocalId localId, ProductoId productoBaseId) {
         Producto productoBase = productoRepository.buscarPorIdYLocal(productoBaseId, localId)
             .orElseThrow(() -> new IllegalArgumentException(
                 "No se encontró el producto base con ID: " + productoBaseId.getValue() + " en el local actual"
@@ -76,35 +134,62 @@ public class CrearVarianteUseCase {
                 "El producto '" + productoBase.getNombre() + "' es un extra y no puede tener variantes"
             );
         }
+        return productoBase;
+    }
 
-        // 2. Determinar grupoVarianteId
-        ProductoId grupoId;
+    private ProductoId resolverGrupo(Producto productoBase) {
         if (productoBase.tieneVariantesEstructurales()) {
             // El base ya pertenece a un grupo → usar ese grupo
-            grupoId = productoBase.getGrupoVarianteId();
-        } else {
-            // Primera variante: el base se convierte en líder del grupo
-            grupoId = productoBase.getId();
-            productoBase.asignarGrupoVariante(grupoId, 1);
-            productoRepository.guardar(productoBase);
+            return productoBase.getGrupoVarianteId();
         }
+        // Primera variante: el base se convierte en líder del grupo
+        ProductoId grupoId = productoBase.getId();
+        productoBase.asignarGrupoVariante(grupoId, 1);
+        productoRepository.guardar(productoBase);
+        return grupoId;
+    }
+
+    private void etiquetarProductoBase(LocalId localId, Producto productoBase, String etiqueta) {
+        String raiz = nombreRaiz(productoBase);
+        productoBase.definirEtiquetaVariante(etiqueta);
 
-        // 3. Validar unicidad del nombre dentro del local
-        if (productoRepository.existePorNombreYLocal(request.nombre(), localId)) {
+        String nuevoNombre = raiz + " " + productoBase.getEtiquetaVariante();
+        if (!nuevoNombre.equalsIgnoreCase(productoBase.getNombre())) {
+            if (productoRepository.existePorNombreYLocal(nuevoNombre, localId)) {
+                throw new IllegalArgumentException(
+                    "Ya existe un producto con el nombre '" + nuevoNombre + "' en este local"
+                );
+            }
+            productoBase.actualizarNombre(nuevoNombre);
+        }
+        productoRepository.guardar(productoBase);
+    }
+
+    private Producto crearVariante(LocalId localId, Producto productoBase, ProductoId grupoId,
+                                   VarianteProductoRequest request) {
+        // 3. Resolver y validar unicidad del nombre dentro del local
+        String nombre = resolverNombre(productoBase, request);
+        if (productoRepository.existePorNombreYLocal(nombre, localId)) {
             throw new IllegalArgumentException(
-                "Ya existe un producto con el nombre '" + request.nombre() + "' en este local"
+                "Ya existe un producto con el nombre '" + nombre + "' en este local"
             );
         }
 
-        // 4. Validar unicidad de cantidadDiscosCarne dentro del grupo
+        // 4. Validar unicidad de cantidadDiscosCarne dentro del grupo (o tomar la siguiente posición)
         List<Producto> hermanasExistentes = productoRepository.buscarPorGrupoVariante(localId, grupoId);
-        boolean discosOcupados = hermanasExistentes.stream()
-            .anyMatch(p -> request.cantidadDiscosCarne().equals(p.getCantidadDiscosCarne()));
-        if (discosOcupados) {
-            throw new IllegalArgumentException(
-                "Ya existe una variante con cantidadDiscosCarne=" + request.cantidadDiscosCarne()
-                + " en el grupo de variantes"
-            );
+        Integer cantidadDiscos = request.cantidadDiscosCarne();
+        if (cantidadDiscos == null) {
+            cantidadDiscos = siguientePosicion(productoBase, hermanasExistentes);
+        } else {
+            Integer solicitada = cantidadDiscos;
+            boolean discosOcupados = hermanasExistentes.stream()
+                .anyMatch(p -> solicitada.equals(p.getCantidadDiscosCarne()));
+            if (discosOcupados) {
+                throw new IllegalArgumentException(
+                    "Ya existe una variante con cantidadDiscosCarne=" + solicitada
+                    + " en el grupo de variantes"
+                );
+            }
         }
 
         // 5. Crear el nuevo Producto como variante (hereda campos del base si no se especifican)
@@ -121,33 +206,65 @@ public class CrearVarianteUseCase {
         Producto nuevaVariante = new Producto(
             nuevoId,
             localId,
-            request.nombre(),
+            nombre,
             request.precio(),
             activo,
             colorHex,
             grupoId,
             false,  // esExtra: una variante nunca es un extra
             false,  // esModificadorEstructural: no aplica a variantes
-            request.cantidadDiscosCarne(),
+            cantidadDiscos,
             categoriaId,
             permiteExtras,
             requiereConfig,
             0,      // stockActual inicial
             controlaStock
         );
+        nuevaVariante.definirEtiquetaVariante(request.etiqueta());
 
         // 6. Persistir la nueva variante
-        Producto varianteGuardada = productoRepository.guardar(nuevaVariante);
+        return productoRepository.guardar(nuevaVariante);
+    }
 
-        // 7. Retornar respuesta con la variante creada y todas las hermanas
-        List<Producto> todasLasVariantes = productoRepository.buscarPorGrupoVariante(localId, grupoId);
-        List<ProductoResponse> variantesResponse = todasLasVariantes.stream()
+    /**
+     * Nombre explícito o, si no viene, "{base} {etiqueta}" (HU-109).
+     */
+    private String resolverNombre(Producto productoBase, VarianteProductoRequest request) {
+        if (request.nombre() != null && !request.nombre().isBlank()) {
+            return request.nombre().trim();
+        }
+        if (request.etiqueta() != null && !request.etiqueta().isBlank()) {
+            return nombreRaiz(productoBase) + " " + request.etiqueta().trim();
+        }
+        throw new IllegalArgumentException("El nombre de la variante es obligatorio");
+    }
+
+    /**
+     * Nombre del base sin su propia etiqueta: "Pizza Muzzarella Chica" → "Pizza Muzzarella".
+     */
+    private String nombreRaiz(Producto productoBase) {
+        String nombre = productoBase.getNombre();
+        String etiqueta = productoBase.getEtiquetaVariante();
+        if (etiqueta != null && nombre.length() > etiqueta.length()
+                && nombre.toLowerCase().endsWith(" " + etiqueta.toLowerCase())) {
+            return nombre.substring(0, nombre.length() - etiqueta.length()).trim();
+        }
+        return nombre;
+    }
+
+    private int siguientePosicion(Producto productoBase, List<Producto> hermanas) {
+        int maximo = productoBase.getCantidadDiscosCarne() != null ? productoBase.getCantidadDiscosCarne() : 0;
+        for (Producto hermana : hermanas) {
+            if (hermana.getCantidadDiscosCarne() != null) {
+                maximo = Math.max(maximo, hermana.getCantidadDiscosCarne());
+            }
+        }
+        return maximo + 1;
+    }
+
+    private List<ProductoResponse> listarGrupo(LocalId localId, ProductoId grupoId) {
+        return productoRepository.buscarPorGrupoVariante(localId, grupoId).stream()
             .map(ProductoResponse::fromDomain)
             .toList();
-
-        return new VarianteProductoResponse(
-            ProductoResponse.fromDomain(varianteGuardada),
-            variantesResponse
-        );
     }
 }
@@ -0,0 +1,114 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.DistribucionPropinasResponse;
+import com.agustinpalma.comandas.domain.model.DistribucionPropinas;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoTurno;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+import com.agustinpalma.comandas.domain.model.Fichaje;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.ReglaPropinas;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.repository.DistribucionPropinasRepository;
+import com.agustinpalma.comandas.domain.repository.FichajeRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.ReglaPropinasRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDate;
+import java.time.LocalTime;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * HU-141: Reparto del pozo de propinas al cerrar cada turno de caja.
+ *
+ * El cierre del turno pide el reparto con la regla vigente del local y los
+ * fichajes del personal; queda guardado para consultarlo e imprimir el
+ * comprobante aunque después cambie la regla o el personal.
+ */
+@Transactional
+public class DistribuirPropinasUseCase {
+
+    private final PedidoRepository pedidoRepository;
+    private final FichajeRepository fichajeRepository;
+    private final MozoRepository mozoRepository;
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final ReglaPropinasRepository reglaPropinasRepository;
+    private final DistribucionPropinasRepository distribucionRepository;
+
+    public DistribuirPropinasUseCase(PedidoRepository pedidoRepository,
+                                     FichajeRepository fichajeRepository,
+                                     MozoRepository mozoRepository,
+                                     TurnoCajaRepository turnoCajaRepository,
+                                     ReglaPropinasRepository reglaPropinasRepository,
+                                     DistribucionPropinasRepository distribucionRepository) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.fichajeRepository = Objects.requireNonNull(fichajeRepository, "El fichajeRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository, "El turnoCajaRepository es obligatorio");
+        this.reglaPropinasRepository = Objects.requireNonNull(reglaPropinasRepository, "El reglaPropinasRepository es obligatorio");
+        this.distribucionRepository = Objects.requireNonNull(distribucionRepository, "El distribucionRepository es obligatorio");
+    }
+
+    /**
+     * Reparte el pozo de un turno recién cerrado. Si el turno ya tiene
+     * reparto lo devuelve sin recalcular.
+     *
+     * @throws IllegalStateException si el turno sigue abierto
+     */
+    public DistribucionPropinas distribuir(TurnoCaja turno) {
+        Objects.requireNonNull(turno, "El turno es obligatorio");
+        LocalId localId = turno.getLocalId();
+
+        var existente = distribucionRepository.buscarPorTurno(turno.getId(), localId);
+        if (existente.isPresent()) {
+            return existente.get();
+        }
+
+        ReglaPropinas regla = reglaPropinasRepository.buscarPorLocal(localId)
+            .orElse(ReglaPropinas.porDefecto(localId));
+        List<Pedido> pedidos = pedidoRepository.buscarCerradosPorTurno(localId, turno.getId());
+        // Un fichaje que se solapa con el turno empezó, como mucho, una jornada máxima antes de la apertura
+        List<Fichaje> fichajes = fichajeRepository.buscarPorRango(
+            localId, turno.getFechaApertura().minus(Fichaje.DURACION_MAXIMA), turno.getFechaCierre());
+
+        DistribucionPropinas distribucion = DistribucionPropinas.repartir(
+            turno, regla, pedidos, fichajes, mozoRepository.buscarPorLocal(localId), turno.getFechaCierre());
+        return distribucionRepository.guardar(distribucion);
+    }
+
+    /**
+     * Repartos de los turnos cerrados entre dos fechas, del más reciente al más viejo.
+     *
+     * @param hasta inclusive
+     */
+    @Transactional(readOnly = true)
+    public List<DistribucionPropinasResponse> listar(LocalId localId, LocalDate desde, LocalDate hasta) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(desde, "La fecha desde es obligatoria");
+        Objects.requireNonNull(hasta, "La fecha hasta es obligatoria");
+        if (hasta.isBefore(desde)) {
+            throw new IllegalArgumentException("La fecha hasta no puede ser anterior a la fecha desde");
+        }
+
+        Map<TurnoCajaId, TipoTurno> tipos = turnoCajaRepository
+            .buscarCerradosPorFecha(localId, desde.atStartOfDay(), hasta.atTime(LocalTime.MAX))
+            .stream()
+            .collect(Collectors.toMap(TurnoCaja::getId, TurnoCaja::getTipo));
+        Map<MozoId, Mozo> personal = mozoRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Mozo::getId, Function.identity()));
+
+        return distribucionRepository.buscarEntre(localId, desde.atStartOfDay(), hasta.plusDays(1).atStartOfDay())
+            .stream()
+            .map(d -> DistribucionPropinasResponse.fromDomain(d, tipos.get(d.getTurnoId()), personal))
+            .toList();
+    }
+}
@@ -81,6 +81,18 @@ public class EditarCategoriaUseCase {
             }
         }
 
+        // HU-119: null no modifica el umbral de cocina; 0 lo quita
+        if (request.minutosAlertaCocina() != null) {
+            categoria.cambiarMinutosAlertaCocina(
+                request.minutosAlertaCocina() == 0 ? null : request.minutosAlertaCocina()
+            );
+        }
+
+        // HU-161: null no modifica el horario; sin horas lo quita
+        if (request.horario() != null) {
+            categoria.cambiarFranjaHoraria(request.horario().toDomain());
+        }
+
         Categoria categoriaActualizada = categoriaRepository.guardar(categoria);
         return CategoriaResponse.fromDomain(categoriaActualizada);
     }
@@ -0,0 +1,81 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ClienteRequest;
+import com.agustinpalma.comandas.application.dto.ClienteResponse;
+import com.agustinpalma.comandas.domain.model.Cliente;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.MovimientoCuentaCorriente;
+import com.agustinpalma.comandas.domain.repository.ClienteRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCuentaCorrienteRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.util.Objects;
+
+/**
+ * Caso de uso para editar los datos de un cliente con cuenta corriente.
+ *
+ * HU-104: Cuenta corriente de clientes.
+ *
+ * Reglas de negocio:
+ * - El cliente debe existir y pertenecer al local
+ * - El nombre debe seguir siendo único dentro del local
+ * - El límite puede quedar por debajo de la deuda: bloquea nuevos cargos, no la deuda existente
+ */
+@Transactional
+public class EditarClienteUseCase {
+
+    private final ClienteRepository clienteRepository;
+    private final MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository;
+
+    public EditarClienteUseCase(
+            ClienteRepository clienteRepository,
+            MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository
+    ) {
+        this.clienteRepository = Objects.requireNonNull(clienteRepository, "El clienteRepository es obligatorio");
+        this.movimientoCuentaCorrienteRepository = Objects.requireNonNull(movimientoCuentaCorrienteRepository, "El movimientoCuentaCorrienteRepository es obligatorio");
+    }
+
+    /**
+     * @param clienteId identificador del cliente a editar
+     * @param localId identificador del local (validación multi-tenancy)
+     * @param request nuevos datos del cliente
+     * @return DTO con la información actualizada y su deuda actual
+     * @throws IllegalArgumentException si el cliente no existe o el nombre ya está en uso
+     */
+    public ClienteResponse ejecutar(ClienteId clienteId, LocalId localId, ClienteRequest request) {
+        Objects.requireNonNull(clienteId, "El clienteId es obligatorio");
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Cliente cliente = clienteRepository.buscarPorIdYLocal(clienteId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("Cliente no encontrado"));
+
+        if (!cliente.getNombre().equalsIgnoreCase(request.nombre().trim())
+                && clienteRepository.existePorNombreYLocal(request.nombre(), localId)) {
+            throw new IllegalArgumentException(
+                "Ya existe otro cliente con el nombre '" + request.nombre() + "' en este local"
+            );
+        }
+
+        cliente.actualizarNombre(request.nombre());
+        cliente.actualizarTelefono(request.telefono());
+        cliente.actualizarLimiteCredito(request.limiteCredito());
+        cliente.actualizarFicha(request.fechaNacimiento(), request.preferencias(), request.alergias());
+
+        if (request.activo() != null) {
+            if (request.activo()) {
+                cliente.activar();
+            } else {
+                cliente.desactivar();
+            }
+        }
+
+        Cliente guardado = clienteRepository.guardar(cliente);
+        BigDecimal deuda = MovimientoCuentaCorriente.calcularSaldo(
+            movimientoCuentaCorrienteRepository.buscarPorCliente(clienteId, localId)
+        );
+        return ClienteResponse.fromDomain(guardado, deuda);
+    }
+}
@@ -1,12 +1,23 @@
 package com.agustinpalma.comandas.application.usecase;
 
+import com.agustinpalma.comandas.application.dto.ComponenteComboRequest;
 import com.agustinpalma.comandas.application.dto.ProductoRequest;
 import com.agustinpalma.comandas.application.dto.ProductoResponse;
+import com.agustinpalma.comandas.domain.model.CambioPrecioProducto;
+import com.agustinpalma.comandas.domain.model.ComponenteCombo;
+import com.agustinpalma.comandas.domain.exception.PermisoDenegadoException;
+import com.agustinpalma.comandas.domain.model.DomainEnums.OrigenCambioPrecio;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
 import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
 import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.CambioPrecioProductoRepository;
 import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.List;
 import java.util.Objects;
 
 /**
@@ -17,18 +28,33 @@ import java.util.Objects;
  * - El nombre debe seguir siendo único dentro del local
  * - La edición del precio NO afecta ítems en pedidos ya abiertos (garantizado por Snapshot)
  * - Se pueden actualizar: nombre, precio, estado activo/inactivo, color y clasificación como extra
+ * - HU-146: Si el precio cambia, queda registrado en el historial de precios
+ * - HU-169: Cambiar el precio exige el permiso MODIFICAR_PRECIOS
  */
 public class EditarProductoUseCase {
 
     private final ProductoRepository productoRepository;
+    private final CambioPrecioProductoRepository cambioPrecioProductoRepository;
+    private final GestionarPermisosUseCase gestionarPermisosUseCase;
+    private final Clock clock;
 
     /**
      * Constructor con inyección de dependencias.
      *
      * @param productoRepository repositorio de productos
+     * @param cambioPrecioProductoRepository historial de precios (HU-146)
+     * @param gestionarPermisosUseCase matriz de permisos (HU-169)
+     * @param clock reloj del sistema para la fecha del cambio de precio
      */
-    public EditarProductoUseCase(ProductoRepository productoRepository) {
+    public EditarProductoUseCase(ProductoRepository productoRepository,
+                                 CambioPrecioProductoRepository cambioPrecioProductoRepository,
+                                 GestionarPermisosUseCase gestionarPermisosUseCase, Clock clock) {
         this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+        this.cambioPrecioProductoRepository = Objects.requireNonNull(cambioPrecioProductoRepository,
+            "El cambioPrecioProductoRepository es obligatorio");
+        this.gestionarPermisosUseCase = Objects.requireNonNull(gestionarPermisosUseCase,
+            "El gestionarPermisosUseCase es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
     }
 
     /**
@@ -41,6 +67,7 @@ public class EditarProductoUseCase {
      * @throws IllegalArgumentException si el producto no existe (404 Not Found)
      * @throws IllegalArgumentException si el producto no pertenece al local (403 Forbidden)
      * @throws IllegalArgumentException si el nuevo nombre ya está en uso (409 Conflict)
+     * @throws PermisoDenegadoException si cambia el precio sin el permiso (HU-169)
      */
     public ProductoResponse ejecutar(ProductoId productoId, LocalId localId, ProductoRequest request) {
         Objects.requireNonNull(productoId, "El productoId es obligatorio");
@@ -65,6 +92,11 @@ public class EditarProductoUseCase {
             }
         }
 
+        BigDecimal precioAnterior = producto.getPrecio();
+        if (request.precio() != null && precioAnterior.compareTo(request.precio()) != 0) {
+            gestionarPermisosUseCase.exigir(localId, Permiso.MODIFICAR_PRECIOS);
+        }
+
         // Actualizar campos usando métodos de dominio
         // Estos métodos contienen las validaciones de negocio
         producto.actualizarNombre(request.nombre());
@@ -111,10 +143,63 @@ public class EditarProductoUseCase {
             }
         }
 
+        // Stock mínimo: solo se modifica si el request lo incluye explícitamente
+        if (request.stockMinimo() != null) {
+            producto.definirStockMinimo(request.stockMinimo());
+        }
+
+        // HU-103: Componentes de combo: solo se modifican si el request los incluye (lista vacía = deja de ser combo)
+        if (request.componentesCombo() != null) {
+            producto.definirComponentesCombo(resolverComponentesCombo(request.componentesCombo(), localId));
+        }
+
+        // HU-147: Alérgenos: solo se modifican si el request los incluye (lista vacía = sin alérgenos)
+        if (request.alergenos() != null) {
+            producto.definirAlergenos(request.alergenos());
+        }
+
+        // HU-161: Horario: solo se modifica si el request lo incluye (sin horas = todo el día)
+        if (request.horario() != null) {
+            producto.definirFranjaHoraria(request.horario().toDomain());
+        }
+
         // Persistir cambios
         Producto productoActualizado = productoRepository.guardar(producto);
 
+        // HU-146: Auditoría del cambio de precio
+        if (precioAnterior.compareTo(productoActualizado.getPrecio()) != 0) {
+            cambioPrecioProductoRepository.guardar(CambioPrecioProducto.registrar(productoActualizado,
+                precioAnterior, OrigenCambioPrecio.EDICION, null, request.responsable(), LocalDateTime.now(clock)));
+        }
+
         // Retornar DTO
         return ProductoResponse.fromDomain(productoActualizado);
     }
+
+    /**
+     * HU-103: Convierte los componentes del request y valida sus opciones.
+     * Cada opción debe existir en el local y ser un producto vendible por sí mismo
+     * (ni extra ni otro combo).
+     */
+    private List<ComponenteCombo> resolverComponentesCombo(List<ComponenteComboRequest> componentes, LocalId localId) {
+        List<ComponenteCombo> resultado = componentes.stream()
+            .map(ComponenteComboRequest::toDomain)
+            .toList();
+
+        for (ComponenteCombo componente : resultado) {
+            for (ProductoId opcionId : componente.getOpciones()) {
+                Producto opcion = productoRepository.buscarPorIdYLocal(opcionId, localId)
+                    .orElseThrow(() -> new IllegalArgumentException(
+                        "No se encontró el producto de la opción con ID: " + opcionId.getValue()
+                    ));
+                if (opcion.isEsExtra() || opcion.esCombo()) {
+                    throw new IllegalArgumentException(String.format(
+                        "El producto '%s' no puede ser opción del componente '%s' (es un extra o un combo)",
+                        opcion.getNombre(), componente.getNombre()
+                    ));
+                }
+            }
+        }
+        return resultado;
+    }
 }
@@ -0,0 +1,77 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.MantenimientoBaseResponse;
+import com.agustinpalma.comandas.application.ports.output.MantenimientoBasePort;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+
+import java.time.Clock;
+import java.time.Duration;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * HU-163: Mantenimiento programado de la base.
+ *
+ * Lo dispara la app de escritorio dentro de la ventana fuera de horario que
+ * tiene configurada. Mientras corre la base queda bloqueada, así que solo se
+ * acepta con el salón vacío: si hay mesas abiertas se rechaza y la app lo
+ * reintenta más tarde dentro de la misma ventana.
+ *
+ * No es transaccional: VACUUM no se puede correr dentro de una transacción.
+ */
+public class EjecutarMantenimientoBaseUseCase {
+
+    private static final Logger log = LoggerFactory.getLogger(EjecutarMantenimientoBaseUseCase.class);
+
+    private final PedidoRepository pedidoRepository;
+    private final MantenimientoBasePort mantenimientoBasePort;
+    private final Clock clock;
+
+    public EjecutarMantenimientoBaseUseCase(
+            PedidoRepository pedidoRepository,
+            MantenimientoBasePort mantenimientoBasePort,
+            Clock clock
+    ) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.mantenimientoBasePort = Objects.requireNonNull(mantenimientoBasePort, "El mantenimientoBasePort es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @throws IllegalStateException si hay mesas abiertas en el local
+     */
+    public MantenimientoBaseResponse ejecutar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        List<Pedido> abiertos = pedidoRepository.buscarAbiertosPorLocal(localId);
+        if (!abiertos.isEmpty()) {
+            throw new IllegalStateException(
+                "Todavía hay mesas abiertas (" + abiertos.size() + "): el mantenimiento de la base se hace con el salón vacío"
+            );
+        }
+
+        LocalDateTime inicio = LocalDateTime.now(clock);
+        Instant desde = clock.instant();
+        MantenimientoBasePort.Resultado resultado = mantenimientoBasePort.optimizar();
+        long milisegundos = Duration.between(desde, clock.instant()).toMillis();
+
+        long recuperados = resultado.bytesAntes() - resultado.bytesDespues();
+        log.info("[Mantenimiento] {} optimizada en {} ms: {} -> {} bytes ({} recuperados)",
+            resultado.motor(), milisegundos, resultado.bytesAntes(), resultado.bytesDespues(), recuperados);
+
+        return new MantenimientoBaseResponse(
+            resultado.motor(),
+            inicio,
+            milisegundos,
+            resultado.bytesAntes(),
+            resultado.bytesDespues(),
+            recuperados
+        );
+    }
+}
@@ -0,0 +1,188 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.EmitirFacturaRequest;
+import com.agustinpalma.comandas.application.dto.FacturaResponse;
+import com.agustinpalma.comandas.application.ports.output.ModuloFiscalPort;
+import com.agustinpalma.comandas.application.ports.output.ModuloFiscalPort.Autorizacion;
+import com.agustinpalma.comandas.application.ports.output.ModuloFiscalPort.Contribuyente;
+import com.agustinpalma.comandas.application.ports.output.ModuloFiscalPort.ModuloFiscalNoDisponibleException;
+import com.agustinpalma.comandas.domain.model.Cuit;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CondicionIva;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoFactura;
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.Factura;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.FacturaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties.FiscalProperties;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.Objects;
+import java.util.Optional;
+
+/**
+ * HU-115: Caso de uso para facturar un pedido cerrado.
+ *
+ * Flujo de emisión:
+ * 1. Buscar el pedido y validar tenant (debe estar CERRADO y sin factura)
+ * 2. Resolver el receptor: si el padrón responde, sus datos mandan sobre los cargados
+ * 3. Determinar la letra y tomar el siguiente número de la serie
+ * 4. Emitir la factura (dominio) y pedir el CAE al módulo fiscal
+ * 5. Persistir
+ *
+ * Sin módulo fiscal la factura queda PENDIENTE_AUTORIZACION y se reintenta
+ * con {@link #reintentarAutorizacion}. Un rechazo del organismo deshace la
+ * emisión completa: el número no se consume.
+ */
+@Transactional
+public class EmitirFacturaUseCase {
+
+    private static final Logger log = LoggerFactory.getLogger(EmitirFacturaUseCase.class);
+
+    private final PedidoRepository pedidoRepository;
+    private final FacturaRepository facturaRepository;
+    private final ModuloFiscalPort moduloFiscal;
+    private final MeisenProperties properties;
+    private final Clock clock;
+
+    public EmitirFacturaUseCase(
+        PedidoRepository pedidoRepository,
+        FacturaRepository facturaRepository,
+        ModuloFiscalPort moduloFiscal,
+        MeisenProperties properties,
+        Clock clock
+    ) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.facturaRepository = Objects.requireNonNull(facturaRepository, "El facturaRepository es obligatorio");
+        this.moduloFiscal = Objects.requireNonNull(moduloFiscal, "El moduloFiscal es obligatorio");
+        this.properties = Objects.requireNonNull(properties, "Las properties son obligatorias");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @throws IllegalStateException si el pedido no existe, no está cerrado o ya fue facturado
+     * @throws IllegalArgumentException si el CUIT es inválido o falta un dato del receptor
+     */
+    public FacturaResponse emitir(LocalId localId, EmitirFacturaRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        PedidoId pedidoId = new PedidoId(request.pedidoId());
+        Pedido pedido = pedidoRepository.buscarPorId(pedidoId)
+            .orElseThrow(() -> new IllegalStateException(
+                String.format("No existe un pedido con ID %s", pedidoId.getValue())
+            ));
+        if (!pedido.getLocalId().equals(localId)) {
+            throw new IllegalArgumentException(
+                String.format("El pedido %s no pertenece al local %s", pedidoId.getValue(), localId.getValue())
+            );
+        }
+
+        facturaRepository.buscarPorPedido(pedidoId, localId).ifPresent(existente -> {
+            throw new IllegalStateException(String.format(
+                "El pedido ya tiene la factura %s %s", existente.getTipo(), existente.numeroFormateado()
+            ));
+        });
+
+        // Receptor: datos del padrón si está disponible, si no los del operador
+        Cuit cuit = request.cuit() == null || request.cuit().isBlank() ? null : Cuit.of(request.cuit());
+        String razonSocial = request.razonSocial();
+        CondicionIva condicion = request.condicionIva();
+        if (cuit != null) {
+            Optional<Contribuyente> padron = consultarPadron(cuit);
+            if (padron.isPresent()) {
+                razonSocial = padron.get().razonSocial();
+                condicion = padron.get().condicionIva();
+            }
+        }
+
+        FiscalProperties fiscal = properties.getFiscal();
+        TipoFactura tipo = Factura.determinarTipo(fiscal.getCondicionIva(), condicion);
+        long numero = facturaRepository.ultimoNumero(localId, fiscal.getPuntoVenta(), tipo) + 1;
+
+        Factura factura = Factura.emitir(FacturaId.generate(), pedido, fiscal.getCondicionIva(),
+            fiscal.getPuntoVenta(), numero, cuit, razonSocial, condicion, fiscal.getAlicuotaIva(),
+            LocalDateTime.now(clock));
+
+        intentarAutorizar(factura);
+
+        log.info("Factura {} {} emitida para pedido #{} ({})",
+            factura.getTipo(), factura.numeroFormateado(), pedido.getNumero(), factura.getEstado());
+
+        return FacturaResponse.fromDomain(facturaRepository.guardar(factura));
+    }
+
+    /**
+     * Vuelve a pedir el CAE de una factura pendiente.
+     *
+     * @throws IllegalStateException si no existe, ya está autorizada o el módulo sigue sin responder
+     */
+    public FacturaResponse reintentarAutorizacion(LocalId localId, FacturaId facturaId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(facturaId, "El facturaId es obligatorio");
+
+        Factura factura = buscarFactura(localId, facturaId);
+        if (factura.estaAutorizada()) {
+            throw new IllegalStateException("La factura " + factura.numeroFormateado() + " ya está autorizada");
+        }
+        if (!intentarAutorizar(factura)) {
+            throw new IllegalStateException("El módulo fiscal no está disponible, la factura sigue pendiente");
+        }
+
+        return FacturaResponse.fromDomain(facturaRepository.guardar(factura));
+    }
+
+    /**
+     * @return la factura del pedido, vacío si no fue facturado
+     */
+    @Transactional(readOnly = true)
+    public Optional<FacturaResponse> buscarPorPedido(LocalId localId, PedidoId pedidoId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(pedidoId, "El pedidoId es obligatorio");
+
+        return facturaRepository.buscarPorPedido(pedidoId, localId).map(FacturaResponse::fromDomain);
+    }
+
+    private Factura buscarFactura(LocalId localId, FacturaId facturaId) {
+        return facturaRepository.buscarPorId(facturaId, localId)
+            .orElseThrow(() -> new IllegalStateException(
+                String.format("No existe una factura con ID %s", facturaId.getValue())
+            ));
+    }
+
+    private Optional<Contribuyente> consultarPadron(Cuit cuit) {
+        try {
+            Optional<Contribuyente> padron = moduloFiscal.consultarPadron(cuit);
+            if (padron.isEmpty()) {
+                throw new IllegalArgumentException(
+                    String.format("El CUIT %s no figura en el padrón", cuit.formateado())
+                );
+            }
+            return padron;
+        } catch (ModuloFiscalNoDisponibleException e) {
+            log.warn("Padrón no disponible, se factura con los datos cargados: {}", e.getMessage());
+            return Optional.empty();
+        }
+    }
+
+    /**
+     * @return true si el módulo fiscal devolvió el CAE
+     */
+    private boolean intentarAutorizar(Factura factura) {
+        try {
+            Autorizacion autorizacion = moduloFiscal.autorizar(factura);
+            factura.autorizar(autorizacion.cae(), autorizacion.vencimiento());
+            return true;
+        } catch (ModuloFiscalNoDisponibleException e) {
+            log.warn("Factura {} queda pendiente de autorización: {}", factura.numeroFormateado(), e.getMessage());
+            return false;
+        }
+    }
+}
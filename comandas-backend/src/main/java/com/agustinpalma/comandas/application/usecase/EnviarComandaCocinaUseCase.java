@@ -1,14 +1,24 @@
 package com.agustinpalma.comandas.application.usecase;
 
 import com.agustinpalma.comandas.application.dto.EnviarComandaResponse;
+import com.agustinpalma.comandas.domain.model.Categoria;
+import com.agustinpalma.comandas.domain.model.ComandaCocina;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
 import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoMesa;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TiempoServicio;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.ItemComandaCocina;
 import com.agustinpalma.comandas.domain.model.ItemPedido;
 import com.agustinpalma.comandas.domain.model.Mesa;
 import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.CategoriaRepository;
+import com.agustinpalma.comandas.domain.repository.ComandaCocinaRepository;
+import com.agustinpalma.comandas.domain.repository.LinkPagoRepository;
 import com.agustinpalma.comandas.domain.repository.MesaRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.ZonaDeliveryRepository;
 import com.agustinpalma.comandas.infrastructure.adapter.EscPosGenerator;
 import com.agustinpalma.comandas.infrastructure.adapter.EscPosGenerator.ComandaCocinaData;
 import com.agustinpalma.comandas.infrastructure.adapter.EscPosGenerator.ComandaItemData;
@@ -19,8 +29,11 @@ import org.springframework.transaction.annotation.Transactional;
 
 import java.time.LocalDateTime;
 import java.util.Base64;
+import java.util.Comparator;
 import java.util.List;
+import java.util.Map;
 import java.util.Objects;
+import java.util.stream.Collectors;
 
 /**
  * HU-29: Caso de uso para enviar comanda a cocina.
@@ -42,6 +55,26 @@ import java.util.Objects;
  * Alternativa evaluada: mover la generación a un servicio de dominio. Se descartó
  * porque ESC/POS es un protocolo de hardware — ni dominio ni aplicación deberían
  * conocer bytes. El use case recibe un "generador" como dependencia inyectada.
+ *
+ * HU-110: Los ítems de pasos posteriores al que está en marcha no salen con el
+ * envío normal; se liberan con {@link #marcharTiempo}, que imprime el aviso de
+ * marcha junto con esos ítems.
+ *
+ * HU-119: Cada envío operativo registra una {@link ComandaCocina} con los ítems
+ * que salieron, para medir el tiempo hasta que cocina la marca como lista.
+ *
+ * HU-128: Un delivery con link de pago pendiente no sale a cocina; lo manda
+ * {@link GestionarLinksPagoUseCase} cuando se acredita el pago.
+ *
+ * HU-137: Un pedido o ítem urgente sale con el aviso "URGENTE" en la comanda
+ * impresa y la comanda registrada encabeza la pantalla de cocina.
+ *
+ * HU-138: El primer envío de un pedido take away fija el horario de retiro
+ * prometido con la espera estimada de cocina; sus comandas lo llevan para
+ * que cocina vea si se atrasa.
+ *
+ * HU-139: Un delivery con zona de reparto no sale a cocina si la venta de
+ * productos no llega al mínimo de la zona. El costo de envío nunca se imprime.
  */
 @Transactional
 public class EnviarComandaCocinaUseCase {
@@ -51,15 +84,30 @@ public class EnviarComandaCocinaUseCase {
     private final MesaRepository mesaRepository;
     private final PedidoRepository pedidoRepository;
     private final MeisenProperties properties;
+    private final ComandaCocinaRepository comandaCocinaRepository;
+    private final CategoriaRepository categoriaRepository;
+    private final LinkPagoRepository linkPagoRepository;
+    private final EstimarEsperaCocinaUseCase estimarEsperaCocinaUseCase;
+    private final ZonaDeliveryRepository zonaDeliveryRepository;
 
     public EnviarComandaCocinaUseCase(
         MesaRepository mesaRepository,
         PedidoRepository pedidoRepository,
-        MeisenProperties properties
+        MeisenProperties properties,
+        ComandaCocinaRepository comandaCocinaRepository,
+        CategoriaRepository categoriaRepository,
+        LinkPagoRepository linkPagoRepository,
+        EstimarEsperaCocinaUseCase estimarEsperaCocinaUseCase,
+        ZonaDeliveryRepository zonaDeliveryRepository
     ) {
         this.mesaRepository = Objects.requireNonNull(mesaRepository, "El mesaRepository es obligatorio");
         this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
         this.properties = Objects.requireNonNull(properties, "Las properties son obligatorias");
+        this.comandaCocinaRepository = Objects.requireNonNull(comandaCocinaRepository, "El comandaCocinaRepository es obligatorio");
+        this.categoriaRepository = Objects.requireNonNull(categoriaRepository, "El categoriaRepository es obligatorio");
+        this.linkPagoRepository = Objects.requireNonNull(linkPagoRepository, "El linkPagoRepository es obligatorio");
+        this.estimarEsperaCocinaUseCase = Objects.requireNonNull(estimarEsperaCocinaUseCase, "El estimarEsperaCocinaUseCase es obligatorio");
+        this.zonaDeliveryRepository = Objects.requireNonNull(zonaDeliveryRepository, "El zonaDeliveryRepository es obligatorio");
     }
 
     /**
@@ -98,27 +146,21 @@ public class EnviarComandaCocinaUseCase {
         Objects.requireNonNull(mesaId, "El mesaId es obligatorio");
 
         // 1. Validar mesa
-        Mesa mesa = mesaRepository.buscarPorId(mesaId)
-            .orElseThrow(() -> new IllegalStateException("La mesa no existe"));
-
-        if (!mesa.getLocalId().equals(localId)) {
-            throw new IllegalArgumentException("La mesa no pertenece a este local");
-        }
-
-        if (mesa.getEstado() == EstadoMesa.LIBRE) {
-            throw new IllegalStateException("La mesa no tiene un pedido activo");
-        }
+        Mesa mesa = validarMesa(localId, mesaId);
 
         // 2. Obtener pedido abierto
-        Pedido pedido = pedidoRepository.buscarAbiertoPorMesa(mesaId, localId)
-            .orElseThrow(() -> new IllegalStateException(
-                "La mesa está abierta pero no tiene un pedido activo. Inconsistencia de datos."
-            ));
+        Pedido pedido = obtenerPedidoAbierto(localId, mesaId);
 
         if (pedido.getItems().isEmpty()) {
             throw new IllegalStateException("No se puede enviar una comanda sin ítems");
         }
 
+        // La reimpresión no cocina nada: solo el envío operativo espera el pago
+        if (soloNuevos) {
+            validarSinPagoPendiente(localId, pedido);
+            validarMinimoDeZona(localId, pedido);
+        }
+
         // 3. Identificar ítems nuevos ANTES de actualizar el timestamp
         List<ItemPedido> itemsNuevos = pedido.obtenerItemsNuevos();
         int cantidadNuevos = itemsNuevos.size();
@@ -129,11 +171,13 @@ public class EnviarComandaCocinaUseCase {
 
         // 4. Generar buffer ESC/POS
         LocalDateTime ahora = LocalDateTime.now();
-        ComandaCocinaData data = construirDatosComanda(pedido, mesa.getNumero(), ahora, soloNuevos);
+        ComandaCocinaData data = construirDatosComanda(pedido, mesa.getNumero(), ahora, soloNuevos, null);
         byte[] escPosBuffer = EscPosGenerator.generarComandaCocina(data, soloNuevos);
 
         // 5. Solo actualizar timestamp si es envío operativo (no reimpresión)
         if (soloNuevos) {
+            prometerRetiro(localId, pedido, itemsNuevos, ahora);
+            registrarComandaCocina(localId, pedido, mesa.getNumero(), itemsNuevos, ahora);
             pedido.marcarComoEnviadoACocina(ahora);
             pedidoRepository.guardar(pedido);
         }
@@ -148,26 +192,180 @@ public class EnviarComandaCocinaUseCase {
             base64,
             ahora,
             soloNuevos ? cantidadNuevos : pedido.getItems().size(),
-            pedido.getItems().size()
+            pedido.getItems().size(),
+            pedido.getHoraPrometida()
+        );
+    }
+
+    /**
+     * HU-110: Marcha el siguiente paso a cocina ("marchar postres").
+     *
+     * Libera los ítems retenidos hasta el paso indicado, imprime la comanda
+     * con el aviso "MARCHAR ..." y los marca como enviados.
+     *
+     * @param localId ID del local (tenant)
+     * @param mesaId ID de la mesa
+     * @param tiempo paso a marchar; null = el siguiente paso pendiente
+     * @throws IllegalStateException si no hay pasos pendientes o el paso ya está en marcha
+     */
+    public EnviarComandaResponse marcharTiempo(LocalId localId, MesaId mesaId, TiempoServicio tiempo) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(mesaId, "El mesaId es obligatorio");
+
+        Mesa mesa = validarMesa(localId, mesaId);
+        Pedido pedido = obtenerPedidoAbierto(localId, mesaId);
+        validarSinPagoPendiente(localId, pedido);
+
+        TiempoServicio aMarchar = tiempo != null
+            ? tiempo
+            : pedido.siguienteTiempoPendiente()
+                .orElseThrow(() -> new IllegalStateException("No hay pasos pendientes para marchar"));
+
+        pedido.marcharTiempo(aMarchar);
+        List<ItemPedido> itemsNuevos = pedido.obtenerItemsNuevos();
+        int cantidadNuevos = itemsNuevos.size();
+
+        log.info("Marchando {}: Mesa {}, Pedido #{}, {} ítems liberados",
+            aMarchar, mesa.getNumero(), pedido.getNumero(), cantidadNuevos);
+
+        LocalDateTime ahora = LocalDateTime.now();
+        ComandaCocinaData data = construirDatosComanda(pedido, mesa.getNumero(), ahora, true, aMarchar);
+        byte[] escPosBuffer = EscPosGenerator.generarComandaCocina(data, true);
+
+        registrarComandaCocina(localId, pedido, mesa.getNumero(), itemsNuevos, ahora);
+        pedido.marcarComoEnviadoACocina(ahora);
+        pedidoRepository.guardar(pedido);
+
+        return new EnviarComandaResponse(
+            Base64.getEncoder().encodeToString(escPosBuffer),
+            ahora,
+            cantidadNuevos,
+            pedido.getItems().size(),
+            pedido.getHoraPrometida()
         );
     }
 
+    private Mesa validarMesa(LocalId localId, MesaId mesaId) {
+        Mesa mesa = mesaRepository.buscarPorId(mesaId)
+            .orElseThrow(() -> new IllegalStateException("La mesa no existe"));
+
+        if (!mesa.getLocalId().equals(localId)) {
+            throw new IllegalArgumentException("La mesa no pertenece a este local");
+        }
+
+        if (mesa.getEstado() == EstadoMesa.LIBRE) {
+            throw new IllegalStateException("La mesa no tiene un pedido activo");
+        }
+        return mesa;
+    }
+
+    private Pedido obtenerPedidoAbierto(LocalId localId, MesaId mesaId) {
+        return pedidoRepository.buscarAbiertoPorMesa(mesaId, localId)
+            .orElseThrow(() -> new IllegalStateException(
+                "La mesa está abierta pero no tiene un pedido activo. Inconsistencia de datos."
+            ));
+    }
+
+    /**
+     * HU-128: El pedido se cocina recién cuando se acredita el link de pago.
+     */
+    private void validarSinPagoPendiente(LocalId localId, Pedido pedido) {
+        if (linkPagoRepository.buscarPendientePorPedido(pedido.getId(), localId).isPresent()) {
+            throw new IllegalStateException(
+                "El pedido espera el pago del link: pasa a cocina apenas se acredite");
+        }
+    }
+
+    /**
+     * HU-139: El mínimo se controla contra la zona vigente, sin contar el envío.
+     */
+    private void validarMinimoDeZona(LocalId localId, Pedido pedido) {
+        if (pedido.getCanal() != CanalVenta.DELIVERY || pedido.getZonaDeliveryId() == null) {
+            return;
+        }
+        zonaDeliveryRepository.buscarPorId(pedido.getZonaDeliveryId(), localId)
+            .ifPresent(zona -> zona.validarMinimo(pedido.calcularVentaProductos()));
+    }
+
+    /**
+     * HU-138: Al primer envío con ítems de un take away, promete el retiro para
+     * cuando estime cocina. La estimación se hace antes de registrar la comanda
+     * para que el propio pedido no cuente en la cola.
+     */
+    private void prometerRetiro(LocalId localId, Pedido pedido, List<ItemPedido> itemsNuevos, LocalDateTime ahora) {
+        if (pedido.getCanal() != CanalVenta.TAKE_AWAY || pedido.getHoraPrometida() != null || itemsNuevos.isEmpty()) {
+            return;
+        }
+        pedido.prometerRetiro(estimarEsperaCocinaUseCase.estimar(localId, ahora).horaEstimada());
+    }
+
+    /**
+     * HU-119: Registra la comanda del envío con las cantidades nuevas de cada ítem.
+     * El umbral de demora sale de las categorías de los ítems (snapshot del ítem).
+     * Un envío sin ítems nuevos no genera comanda: no hay nada que medir.
+     */
+    private void registrarComandaCocina(LocalId localId, Pedido pedido, int numeroMesa,
+                                        List<ItemPedido> itemsNuevos, LocalDateTime ahora) {
+        if (itemsNuevos.isEmpty()) {
+            return;
+        }
+
+        Map<CategoriaId, Integer> minutosPorCategoria = categoriaRepository.buscarPorLocal(localId).stream()
+            .filter(categoria -> categoria.getMinutosAlertaCocina() != null)
+            .collect(Collectors.toMap(Categoria::getId, Categoria::getMinutosAlertaCocina));
+
+        int minutosAlerta = ComandaCocina.resolverMinutosAlerta(itemsNuevos.stream()
+            .map(ItemPedido::getCategoriaIdSnapshot)
+            .filter(Objects::nonNull)
+            .map(minutosPorCategoria::get)
+            .toList());
+
+        List<ItemComandaCocina> items = itemsNuevos.stream()
+            .map(item -> new ItemComandaCocina(
+                item.getNombreProducto(), item.obtenerCantidadNueva(), item.getObservacionCocina(), item.isUrgente(),
+                item.getAdvertencias()))
+            .toList();
+
+        ComandaCocina comanda = ComandaCocina.enviar(
+            localId, pedido.getId(), numeroMesa, pedido.getNumero(), ahora, minutosAlerta, items,
+            pedido.isUrgente());
+        comanda.asignarHoraPrometida(pedido.getHoraPrometida());
+        comandaCocinaRepository.guardar(comanda);
+    }
+
     /**
      * Construye el DTO de datos para el generador ESC/POS.
      *
      * Transforma el modelo de dominio en datos planos para impresión.
      * Los extras se representan como strings simples (solo nombre, para cocina).
+     * HU-103: Los combos detallan la opción elegida de cada componente.
      *
      * HU-29 fix: Cuando soloNuevos=true, la cantidad de cada ítem es el DELTA
      * (unidades nuevas = cantidad - cantidadEnviadaCocina), no el total acumulado.
      * Cuando soloNuevos=false (reimpresión), se usa la cantidad total.
      *
+     * HU-110: Los ítems se ordenan por paso (los que no tienen paso, primero)
+     * para que la comanda salga agrupada; los retenidos se marcan en espera.
+     *
+     * HU-137: Viaja la urgencia del pedido y la de cada ítem.
+     *
+     * HU-147: Viajan las alergias declaradas para el comensal de cada ítem.
+     *
+     * HU-148: Los ingredientes quitados salen adelante de la observación ("SIN CEBOLLA").
+     *
+     * HU-139: El costo de envío no es para cocina.
+     *
      * @param soloNuevos true = usar delta como cantidad; false = usar cantidad total
+     * @param marcha paso que se está marchando, o null en un envío normal
      */
-    private ComandaCocinaData construirDatosComanda(Pedido pedido, int numeroMesa, LocalDateTime ahora, boolean soloNuevos) {
+    private ComandaCocinaData construirDatosComanda(Pedido pedido, int numeroMesa, LocalDateTime ahora,
+                                                    boolean soloNuevos, TiempoServicio marcha) {
         List<ComandaItemData> items = pedido.getItems().stream()
+            .filter(item -> !item.isEnvio())
+            .sorted(Comparator.comparing(ItemPedido::getTiempoServicio,
+                Comparator.nullsFirst(Comparator.naturalOrder())))
             .map(item -> {
-                boolean esNuevo = item.tieneCantidadNueva();
+                boolean esNuevo = pedido.esItemNuevo(item);
                 // Si es envío operativo y el ítem tiene cantidad nueva → imprimir solo el delta
                 // Si es reimpresión → siempre imprimir la cantidad total
                 int cantidad = (soloNuevos && esNuevo)
@@ -176,10 +374,19 @@ public class EnviarComandaCocinaUseCase {
                 return new ComandaItemData(
                     cantidad,
                     item.getNombreProducto(),
-                    item.getObservacion(),
+                    item.getObservacionCocina(),
                     esNuevo,
                     item.getExtras().stream()
                         .map(extra -> extra.getNombre())
+                        .toList(),
+                    item.getComponentesCombo().stream()
+                        .map(seleccion -> seleccion.getComponente() + ": " + seleccion.getNombreProducto())
+                        .toList(),
+                    item.getTiempoServicio() != null ? item.getTiempoServicio().name() : null,
+                    pedido.estaRetenido(item),
+                    item.isUrgente(),
+                    item.getAdvertencias().stream()
+                        .map(Enum::name)
                         .toList()
                 );
             })
@@ -189,7 +396,9 @@ public class EnviarComandaCocinaUseCase {
             numeroMesa,
             pedido.getNumero(),
             ahora,
-            items
+            items,
+            marcha != null ? marcha.name() : null,
+            pedido.isUrgente()
         );
     }
 }
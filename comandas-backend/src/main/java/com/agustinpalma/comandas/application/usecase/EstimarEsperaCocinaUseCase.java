@@ -0,0 +1,58 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.EsperaEstimadaResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.repository.ComandaCocinaRepository;
+import com.agustinpalma.comandas.domain.service.EsperaCocinaService;
+import com.agustinpalma.comandas.domain.service.EsperaCocinaService.EsperaEstimada;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Caso de uso: espera estimada en cocina para un pedido take away.
+ *
+ * HU-138: Con la cola actual y los tiempos de las últimas comandas listas,
+ * calcula cuánto tardaría un pedido que se envía ahora. La caja lo consulta
+ * para informarle al cliente antes de enviar; el envío lo usa para fijar el
+ * horario prometido.
+ */
+@Transactional(readOnly = true)
+public class EstimarEsperaCocinaUseCase {
+
+    private final ComandaCocinaRepository comandaCocinaRepository;
+    private final EsperaCocinaService esperaCocinaService;
+    private final Clock clock;
+
+    public EstimarEsperaCocinaUseCase(
+            ComandaCocinaRepository comandaCocinaRepository,
+            EsperaCocinaService esperaCocinaService,
+            Clock clock
+    ) {
+        this.comandaCocinaRepository = Objects.requireNonNull(comandaCocinaRepository, "El comandaCocinaRepository es obligatorio");
+        this.esperaCocinaService = Objects.requireNonNull(esperaCocinaService, "El esperaCocinaService es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    public EsperaEstimadaResponse ejecutar(LocalId localId) {
+        return EsperaEstimadaResponse.fromDomain(estimar(localId, LocalDateTime.now(clock)));
+    }
+
+    /**
+     * @param localId tenant que consulta
+     * @param ahora momento desde el que se estima
+     */
+    public EsperaEstimada estimar(LocalId localId, LocalDateTime ahora) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(ahora, "La fecha es obligatoria");
+
+        return esperaCocinaService.estimar(
+            comandaCocinaRepository.buscarPendientes(localId),
+            comandaCocinaRepository.buscarListasEnviadasEntre(
+                localId, ahora.minus(EsperaCocinaService.VENTANA_HISTORIAL), ahora),
+            ahora
+        );
+    }
+}
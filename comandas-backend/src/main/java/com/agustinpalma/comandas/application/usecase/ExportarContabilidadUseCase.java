@@ -0,0 +1,197 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.usecase.ExportarLibroIvaVentasUseCase.ArchivoExportado;
+import com.agustinpalma.comandas.domain.model.AsientoContable;
+import com.agustinpalma.comandas.domain.model.AsientoContable.Linea;
+import com.agustinpalma.comandas.domain.model.DomainEnums.ConceptoContable;
+import com.agustinpalma.comandas.domain.model.DomainEnums.SistemaContable;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.Factura;
+import com.agustinpalma.comandas.domain.model.Gasto;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.PlanCuentasContable;
+import com.agustinpalma.comandas.domain.repository.FacturaRepository;
+import com.agustinpalma.comandas.domain.repository.GastoRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.PlanCuentasContableRepository;
+import com.agustinpalma.comandas.infrastructure.adapter.ExportacionContableGenerator;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalTime;
+import java.time.YearMonth;
+import java.time.format.DateTimeFormatter;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.EnumMap;
+import java.util.EnumSet;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Set;
+import java.util.TreeMap;
+import java.util.stream.Collectors;
+
+/**
+ * HU-167: Caso de uso para exportar los asientos del mes al sistema contable.
+ *
+ * Arma asientos de partida doble con lo que el estudio contable hoy vuelve
+ * a tipear a mano:
+ * - Ventas: un asiento por día con los pedidos cerrados, al debe de cada
+ *   medio de cobro (con recargos y redondeo) y al haber de ventas. El
+ *   consumo interno no es una venta y no entra.
+ * - Facturas: por cada factura AUTORIZADA que discrimina IVA, el IVA sale de
+ *   ventas y va al débito fiscal. Así el total vendido no se cuenta dos
+ *   veces y el IVA coincide con el libro IVA ventas del mes.
+ * - Gastos: uno por gasto, al debe de su categoría y al haber del medio con
+ *   que se pagó (o del proveedor si quedó a cuenta corriente).
+ *
+ * Cada concepto usado tiene que tener su cuenta en el plan de cuentas del
+ * local: un archivo con cuentas vacías no se puede importar.
+ */
+@Transactional(readOnly = true)
+public class ExportarContabilidadUseCase {
+
+    private static final DateTimeFormatter FECHA_LEYENDA = DateTimeFormatter.ofPattern("dd/MM/yyyy");
+
+    private final PedidoRepository pedidoRepository;
+    private final FacturaRepository facturaRepository;
+    private final GastoRepository gastoRepository;
+    private final PlanCuentasContableRepository planCuentasContableRepository;
+
+    public ExportarContabilidadUseCase(PedidoRepository pedidoRepository,
+                                       FacturaRepository facturaRepository,
+                                       GastoRepository gastoRepository,
+                                       PlanCuentasContableRepository planCuentasContableRepository) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.facturaRepository = Objects.requireNonNull(facturaRepository, "El facturaRepository es obligatorio");
+        this.gastoRepository = Objects.requireNonNull(gastoRepository, "El gastoRepository es obligatorio");
+        this.planCuentasContableRepository = Objects.requireNonNull(planCuentasContableRepository,
+            "El planCuentasContableRepository es obligatorio");
+    }
+
+    /**
+     * @param periodo mes a exportar (por fecha de cierre, de emisión o del comprobante del gasto)
+     * @throws IllegalStateException si algún concepto del mes no tiene cuenta asignada
+     */
+    public ArchivoExportado ejecutar(LocalId localId, YearMonth periodo, SistemaContable sistema) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(periodo, "El período es obligatorio");
+        Objects.requireNonNull(sistema, "El sistema contable es obligatorio");
+
+        List<AsientoContable> asientos = new ArrayList<>();
+        asientos.addAll(asientosDeVentas(localId, periodo));
+        asientos.addAll(asientosDeFacturas(localId, periodo));
+        asientos.addAll(asientosDeGastos(localId, periodo));
+        asientos.sort(Comparator.comparing(AsientoContable::getFecha));
+
+        PlanCuentasContable plan = planCuentasContableRepository.buscarPorLocal(localId)
+            .orElse(PlanCuentasContable.sinCuentas(localId));
+        validarCuentas(asientos, plan);
+
+        String nombre = String.format("ASIENTOS_%s_%d%02d.%s", sistema, periodo.getYear(), periodo.getMonthValue(),
+            ExportacionContableGenerator.extension(sistema));
+        return new ArchivoExportado(nombre, ExportacionContableGenerator.contentType(sistema),
+            ExportacionContableGenerator.generar(asientos, plan, sistema));
+    }
+
+    // ─── Ventas ──────────────────────────────────────────────────────────────────
+
+    private List<AsientoContable> asientosDeVentas(LocalId localId, YearMonth periodo) {
+        Map<LocalDate, List<Pedido>> porDia = pedidoRepository.buscarCerradosPorFecha(
+                localId, periodo.atDay(1).atStartOfDay(), periodo.atEndOfMonth().atTime(LocalTime.MAX)
+            ).stream()
+            .filter(pedido -> pedido.getFechaCierre() != null)
+            .collect(Collectors.groupingBy(pedido -> pedido.getFechaCierre().toLocalDate(), TreeMap::new,
+                Collectors.toList()));
+
+        List<AsientoContable> asientos = new ArrayList<>();
+        porDia.forEach((dia, pedidos) -> {
+            Map<ConceptoContable, BigDecimal> cobrado = new EnumMap<>(ConceptoContable.class);
+            int vendidos = 0;
+            for (Pedido pedido : pedidos) {
+                boolean vendido = false;
+                for (Pago pago : pedido.getPagos()) {
+                    var concepto = PlanCuentasContable.conceptoDeCobro(pago.getMedio());
+                    if (concepto.isPresent()) {
+                        cobrado.merge(concepto.get(), pago.getMontoCobrado(), BigDecimal::add);
+                        vendido = true;
+                    }
+                }
+                if (vendido) {
+                    vendidos++;
+                }
+            }
+            cobrado.values().removeIf(monto -> monto.signum() <= 0);
+            if (cobrado.isEmpty()) {
+                return;
+            }
+
+            List<Linea> lineas = new ArrayList<>();
+            cobrado.forEach((concepto, monto) -> lineas.add(Linea.debe(concepto, monto)));
+            BigDecimal total = cobrado.values().stream().reduce(BigDecimal.ZERO, BigDecimal::add);
+            lineas.add(Linea.haber(ConceptoContable.VENTAS, total));
+
+            asientos.add(new AsientoContable(dia,
+                "Ventas del " + dia.format(FECHA_LEYENDA) + " (" + vendidos + " pedidos)", lineas));
+        });
+        return asientos;
+    }
+
+    // ─── Facturas ────────────────────────────────────────────────────────────────
+
+    private List<AsientoContable> asientosDeFacturas(LocalId localId, YearMonth periodo) {
+        return facturaRepository.buscarPorPeriodo(
+                localId, periodo.atDay(1).atStartOfDay(), periodo.plusMonths(1).atDay(1).atStartOfDay()
+            ).stream()
+            .filter(Factura::estaAutorizada)
+            .filter(factura -> factura.getImporteIva().signum() > 0)
+            .map(factura -> new AsientoContable(
+                factura.getFechaEmision().toLocalDate(),
+                String.format("IVA factura %s %05d-%08d", factura.getTipo(), factura.getPuntoVenta(),
+                    factura.getNumero()),
+                List.of(
+                    Linea.debe(ConceptoContable.VENTAS, factura.getImporteIva()),
+                    Linea.haber(ConceptoContable.IVA_DEBITO_FISCAL, factura.getImporteIva())
+                )))
+            .toList();
+    }
+
+    // ─── Gastos ──────────────────────────────────────────────────────────────────
+
+    private List<AsientoContable> asientosDeGastos(LocalId localId, YearMonth periodo) {
+        return gastoRepository.buscarPorPeriodo(localId, periodo.atDay(1), periodo.atEndOfMonth()).stream()
+            .map(gasto -> new AsientoContable(
+                gasto.getFecha(),
+                leyendaGasto(gasto),
+                List.of(
+                    Linea.debe(PlanCuentasContable.conceptoDeGasto(gasto.getCategoria()), gasto.getMonto()),
+                    Linea.haber(PlanCuentasContable.conceptoDePagoGasto(gasto.getMedioPago()), gasto.getMonto())
+                )))
+            .toList();
+    }
+
+    private static String leyendaGasto(Gasto gasto) {
+        String detalle = gasto.getProveedor() != null ? gasto.getProveedor() : gasto.getDescripcion();
+        return "Gasto " + gasto.getCategoria().name().toLowerCase() + ": " + detalle;
+    }
+
+    // ─── Validación ──────────────────────────────────────────────────────────────
+
+    private static void validarCuentas(List<AsientoContable> asientos, PlanCuentasContable plan) {
+        Set<ConceptoContable> faltantes = EnumSet.noneOf(ConceptoContable.class);
+        for (AsientoContable asiento : asientos) {
+            for (Linea linea : asiento.getLineas()) {
+                if (plan.cuentaDe(linea.concepto()).isEmpty()) {
+                    faltantes.add(linea.concepto());
+                }
+            }
+        }
+        if (!faltantes.isEmpty()) {
+            throw new IllegalStateException("Faltan asignar las cuentas de: "
+                + faltantes.stream().map(Enum::name).collect(Collectors.joining(", ")));
+        }
+    }
+}
@@ -0,0 +1,84 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.Factura;
+import com.agustinpalma.comandas.domain.repository.FacturaRepository;
+import com.agustinpalma.comandas.infrastructure.adapter.LibroIvaVentasGenerator;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.io.ByteArrayOutputStream;
+import java.io.IOException;
+import java.io.UncheckedIOException;
+import java.time.YearMonth;
+import java.util.List;
+import java.util.Objects;
+import java.util.zip.ZipEntry;
+import java.util.zip.ZipOutputStream;
+
+/**
+ * HU-116: Caso de uso para exportar el libro IVA ventas de un mes.
+ *
+ * Incluye las facturas AUTORIZADAS emitidas en el mes calendario: una
+ * pendiente de autorización no es un comprobante válido y no se declara.
+ *
+ * Formatos:
+ * - CSV: una planilla con una fila por comprobante y su alícuota
+ * - TXT: los dos archivos del régimen de información (comprobantes y
+ *   alícuotas) empaquetados en un ZIP, como los pide el sistema contable
+ */
+@Transactional(readOnly = true)
+public class ExportarLibroIvaVentasUseCase {
+
+    public enum Formato { CSV, TXT }
+
+    /** Archivo listo para descargar */
+    public record ArchivoExportado(String nombre, String contentType, byte[] contenido) {}
+
+    private final FacturaRepository facturaRepository;
+
+    public ExportarLibroIvaVentasUseCase(FacturaRepository facturaRepository) {
+        this.facturaRepository = Objects.requireNonNull(facturaRepository, "El facturaRepository es obligatorio");
+    }
+
+    /**
+     * @param periodo mes a exportar (por fecha de emisión)
+     */
+    public ArchivoExportado ejecutar(LocalId localId, YearMonth periodo, Formato formato) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(periodo, "El período es obligatorio");
+        Objects.requireNonNull(formato, "El formato es obligatorio");
+
+        List<Factura> facturas = facturaRepository.buscarPorPeriodo(
+                localId, periodo.atDay(1).atStartOfDay(), periodo.plusMonths(1).atDay(1).atStartOfDay()
+            ).stream()
+            .filter(Factura::estaAutorizada)
+            .toList();
+
+        String base = String.format("LIBRO_IVA_VENTAS_%d%02d", periodo.getYear(), periodo.getMonthValue());
+
+        return switch (formato) {
+            case CSV -> new ArchivoExportado(base + ".csv", "text/csv; charset=UTF-8",
+                LibroIvaVentasGenerator.generarCsv(facturas));
+            case TXT -> new ArchivoExportado(base + ".zip", "application/zip", zip(
+                new ZipEntryData(base + "_CBTE.txt", LibroIvaVentasGenerator.generarComprobantesTxt(facturas)),
+                new ZipEntryData(base + "_ALICUOTAS.txt", LibroIvaVentasGenerator.generarAlicuotasTxt(facturas))
+            ));
+        };
+    }
+
+    private record ZipEntryData(String nombre, byte[] contenido) {}
+
+    private static byte[] zip(ZipEntryData... archivos) {
+        ByteArrayOutputStream out = new ByteArrayOutputStream();
+        try (ZipOutputStream zip = new ZipOutputStream(out)) {
+            for (ZipEntryData archivo : archivos) {
+                zip.putNextEntry(new ZipEntry(archivo.nombre()));
+                zip.write(archivo.contenido());
+                zip.closeEntry();
+            }
+        } catch (IOException e) {
+            throw new UncheckedIOException("Error armando el ZIP del libro IVA", e);
+        }
+        return out.toByteArray();
+    }
+}
@@ -0,0 +1,103 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.LoteVentasSucursalDTO;
+import com.agustinpalma.comandas.application.dto.LoteVentasSucursalDTO.VentaCerradaDTO;
+import com.agustinpalma.comandas.application.ports.output.IdentidadSucursalProvider;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.EnumMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.UUID;
+
+/**
+ * Caso de uso: exportar las ventas cerradas de esta sucursal.
+ *
+ * HU-107: Multi-sucursal con reportes consolidados.
+ *
+ * Lo consume la capa de sync de Tauri, que reenvía el lote al consolidado.
+ * El cursor es el cierre y el pedido de la última venta que aceptó el
+ * consolidado: se exportan las ventas que le siguen en ese orden, así las
+ * cerradas en el mismo instante que el cursor no se pierden. Un cursor sin
+ * pedido (guardado antes del desempate) reenvía las ventas de su mismo
+ * cierre, que el consolidado reemplaza. Sin cursor (primera sincronización)
+ * se exportan los últimos 30 días.
+ */
+@Transactional(readOnly = true)
+public class ExportarVentasCerradasUseCase {
+
+    private static final int DIAS_PRIMERA_SINCRONIZACION = 30;
+
+    private final PedidoRepository pedidoRepository;
+    private final IdentidadSucursalProvider identidadSucursalProvider;
+    private final Clock clock;
+
+    public ExportarVentasCerradasUseCase(PedidoRepository pedidoRepository,
+                                         IdentidadSucursalProvider identidadSucursalProvider,
+                                         Clock clock) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository,
+            "El pedidoRepository es obligatorio");
+        this.identidadSucursalProvider = Objects.requireNonNull(identidadSucursalProvider,
+            "El identidadSucursalProvider es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @param localId identificador del local (tenant)
+     * @param desde   cierre del cursor de la última sincronización, o null
+     * @param desdePedidoId pedido del cursor, o null
+     * @return lote con los pedidos cerrados posteriores al cursor
+     */
+    public LoteVentasSucursalDTO ejecutar(LocalId localId, LocalDateTime desde, UUID desdePedidoId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        LocalDateTime inicio = desde != null ? desde : ahora.minusDays(DIAS_PRIMERA_SINCRONIZACION);
+
+        List<VentaCerradaDTO> ventas = pedidoRepository.buscarCerradosPorFecha(localId, inicio, ahora).stream()
+            .filter(pedido -> pedido.getFechaCierre() != null)
+            .map(ExportarVentasCerradasUseCase::aVentaCerrada)
+            .filter(venta -> posteriorAlCursor(venta, desde, desdePedidoId))
+            .sorted(VentaCerradaDTO.ORDEN_CURSOR)
+            .toList();
+
+        return new LoteVentasSucursalDTO(
+            identidadSucursalProvider.getSucursalId().getValue(),
+            identidadSucursalProvider.getNombreSucursal(),
+            ventas
+        );
+    }
+
+    private static boolean posteriorAlCursor(VentaCerradaDTO venta, LocalDateTime desde, UUID desdePedidoId) {
+        if (desde == null) {
+            return true;
+        }
+        int comparacion = venta.fechaCierre().compareTo(desde);
+        if (comparacion != 0) {
+            return comparacion > 0;
+        }
+        return desdePedidoId == null || venta.pedidoId().compareTo(desdePedidoId) > 0;
+    }
+
+    private static VentaCerradaDTO aVentaCerrada(Pedido pedido) {
+        Map<MedioPago, BigDecimal> pagos = new EnumMap<>(MedioPago.class);
+        for (Pago pago : pedido.getPagos()) {
+            pagos.merge(pago.getMedio(), pago.getMonto(), BigDecimal::add);
+        }
+        return new VentaCerradaDTO(
+            pedido.getId().getValue(),
+            pedido.getNumero(),
+            pedido.getFechaCierre(),
+            pagos
+        );
+    }
+}
@@ -0,0 +1,283 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ProductoVendidoReporte;
+import com.agustinpalma.comandas.application.dto.ReporteCajaResponse;
+import com.agustinpalma.comandas.application.dto.ReporteImagenData;
+import com.agustinpalma.comandas.application.dto.ReporteImagenData.Ancho;
+import com.agustinpalma.comandas.application.dto.ReporteImagenData.Fila;
+import com.agustinpalma.comandas.application.dto.ReporteImagenData.Seccion;
+import com.agustinpalma.comandas.application.dto.ReporteImagenData.Vista;
+import com.agustinpalma.comandas.application.ports.output.ReporteImagenGenerator;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.temporal.ChronoUnit;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.EnumMap;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+
+/**
+ * Caso de uso para exportar un reporte como imagen PNG.
+ * HU-157: el dueño reenvía "las ventas de hoy" por WhatsApp a sus socios.
+ *
+ * Orquestación:
+ * 1. Valida el período (de 1 a 31 días operativos)
+ * 2. Junta el reporte de caja y el de productos de cada día del período
+ *    (HU-153: cada día es una jornada comercial del local)
+ * 3. Resume la vista pedida en secciones de etiqueta y valor
+ * 4. Delega el dibujo de la imagen al puerto ReporteImagenGenerator
+ */
+@Transactional(readOnly = true)
+public class GenerarImagenReporteUseCase {
+
+    private static final int DIAS_MAXIMOS = 31;
+    private static final int TOP_PRODUCTOS = 5;
+
+    private final GenerarReporteCajaUseCase generarReporteCajaUseCase;
+    private final ObtenerReporteVentasUseCase obtenerReporteVentasUseCase;
+    private final ReporteImagenGenerator reporteImagenGenerator;
+    private final MeisenProperties meisenProperties;
+    private final Clock clock;
+
+    public GenerarImagenReporteUseCase(
+            GenerarReporteCajaUseCase generarReporteCajaUseCase,
+            ObtenerReporteVentasUseCase obtenerReporteVentasUseCase,
+            ReporteImagenGenerator reporteImagenGenerator,
+            MeisenProperties meisenProperties,
+            Clock clock) {
+        this.generarReporteCajaUseCase = Objects.requireNonNull(generarReporteCajaUseCase);
+        this.obtenerReporteVentasUseCase = Objects.requireNonNull(obtenerReporteVentasUseCase);
+        this.reporteImagenGenerator = Objects.requireNonNull(reporteImagenGenerator);
+        this.meisenProperties = Objects.requireNonNull(meisenProperties);
+        this.clock = Objects.requireNonNull(clock);
+    }
+
+    /**
+     * @param localId identificador del local (tenant)
+     * @param vista   reporte a exportar
+     * @param desde   primer día operativo
+     * @param hasta   último día operativo (inclusivo)
+     * @param ancho   ancho de la imagen
+     * @return bytes de la imagen PNG
+     */
+    public byte[] ejecutar(LocalId localId, Vista vista, LocalDate desde, LocalDate hasta, Ancho ancho) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(vista, "La vista del reporte es obligatoria");
+        Objects.requireNonNull(desde, "La fecha desde es obligatoria");
+        Objects.requireNonNull(hasta, "La fecha hasta es obligatoria");
+        Objects.requireNonNull(ancho, "El ancho de la imagen es obligatorio");
+
+        if (hasta.isBefore(desde)) {
+            throw new IllegalArgumentException("La fecha hasta no puede ser anterior a la fecha desde");
+        }
+        if (ChronoUnit.DAYS.between(desde, hasta) >= DIAS_MAXIMOS) {
+            throw new IllegalArgumentException("El período de la imagen no puede superar los " + DIAS_MAXIMOS + " días");
+        }
+
+        Periodo periodo = acumular(localId, vista, desde, hasta);
+
+        ReporteImagenData data = new ReporteImagenData(
+            meisenProperties.getLocal().getNombreLocal(),
+            titulo(vista),
+            desde,
+            hasta,
+            switch (vista) {
+                case VENTAS -> seccionesVentas(periodo);
+                case CAJA -> seccionesCaja(periodo);
+                case PRODUCTOS -> seccionesProductos(periodo);
+            },
+            LocalDateTime.now(clock)
+        );
+
+        return reporteImagenGenerator.generarPng(data, ancho);
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Acumulado del período
+    // ════════════════════════════════════════════════════════════════════════════
+
+    private Periodo acumular(LocalId localId, Vista vista, LocalDate desde, LocalDate hasta) {
+        Periodo periodo = new Periodo();
+        for (LocalDate dia = desde; !dia.isAfter(hasta); dia = dia.plusDays(1)) {
+            if (vista != Vista.PRODUCTOS) {
+                periodo.sumar(generarReporteCajaUseCase.ejecutar(localId, dia));
+            }
+            if (vista != Vista.CAJA) {
+                periodo.sumar(obtenerReporteVentasUseCase.ejecutar(localId, dia));
+            }
+        }
+        return periodo;
+    }
+
+    /**
+     * Suma de los reportes diarios del período.
+     */
+    private static final class Periodo {
+        BigDecimal ventas = BigDecimal.ZERO;
+        BigDecimal consumoInterno = BigDecimal.ZERO;
+        BigDecimal ingresos = BigDecimal.ZERO;
+        BigDecimal egresos = BigDecimal.ZERO;
+        BigDecimal devoluciones = BigDecimal.ZERO;
+        BigDecimal recargos = BigDecimal.ZERO;
+        BigDecimal ajusteRedondeo = BigDecimal.ZERO;
+        BigDecimal balanceEfectivo = BigDecimal.ZERO;
+        int pedidos = 0;
+        final Map<MedioPago, BigDecimal> porMedio = new EnumMap<>(MedioPago.class);
+        final Map<String, ProductoVendidoReporte> productos = new LinkedHashMap<>();
+
+        void sumar(ReporteCajaResponse dia) {
+            ventas = ventas.add(dia.totalVentasReales());
+            consumoInterno = consumoInterno.add(dia.totalConsumoInterno());
+            ingresos = ingresos.add(dia.totalIngresos());
+            egresos = egresos.add(dia.totalEgresos());
+            devoluciones = devoluciones.add(dia.totalDevoluciones());
+            recargos = recargos.add(dia.totalRecargos());
+            ajusteRedondeo = ajusteRedondeo.add(dia.totalAjusteRedondeo());
+            balanceEfectivo = balanceEfectivo.add(dia.balanceEfectivo());
+            pedidos += dia.ventas().size();
+            dia.desglosePorMedioPago().forEach((medio, monto) -> porMedio.merge(medio, monto, BigDecimal::add));
+        }
+
+        void sumar(List<ProductoVendidoReporte> dia) {
+            for (ProductoVendidoReporte p : dia) {
+                productos.merge(p.productoNombre(), p, (a, b) -> new ProductoVendidoReporte(
+                    a.productoNombre(),
+                    a.cantidadTotal() + b.cantidadTotal(),
+                    a.totalRecaudado().add(b.totalRecaudado())
+                ));
+            }
+        }
+
+        List<ProductoVendidoReporte> productosPorRecaudacion() {
+            return productos.values().stream()
+                .sorted(Comparator.comparing(ProductoVendidoReporte::totalRecaudado).reversed())
+                .toList();
+        }
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Vistas
+    // ════════════════════════════════════════════════════════════════════════════
+
+    private List<Seccion> seccionesVentas(Periodo periodo) {
+        List<Fila> resumen = new ArrayList<>();
+        resumen.add(new Fila("Pedidos", String.valueOf(periodo.pedidos), null));
+        if (periodo.pedidos > 0) {
+            resumen.add(new Fila("Ticket promedio", null,
+                periodo.ventas.divide(BigDecimal.valueOf(periodo.pedidos), 2, RoundingMode.HALF_UP)));
+        }
+        if (periodo.recargos.signum() != 0) {
+            resumen.add(new Fila("Recargos cobrados", null, periodo.recargos));
+        }
+
+        List<Seccion> secciones = new ArrayList<>();
+        secciones.add(new Seccion("Resumen", resumen, new Fila("Total vendido", null, periodo.ventas)));
+
+        BigDecimal totalMedios = periodo.porMedio.values().stream().reduce(BigDecimal.ZERO, BigDecimal::add);
+        List<Fila> medios = periodo.porMedio.entrySet().stream()
+            .filter(e -> e.getValue().signum() != 0)
+            .sorted(Map.Entry.<MedioPago, BigDecimal>comparingByValue().reversed())
+            .map(e -> new Fila(etiquetaMedio(e.getKey()), porcentaje(e.getValue(), totalMedios), e.getValue()))
+            .toList();
+        if (!medios.isEmpty()) {
+            secciones.add(new Seccion("Medios de pago", medios, null));
+        }
+
+        List<Fila> top = periodo.productosPorRecaudacion().stream()
+            .limit(TOP_PRODUCTOS)
+            .map(this::filaProducto)
+            .toList();
+        if (!top.isEmpty()) {
+            secciones.add(new Seccion("Más vendidos", top, null));
+        }
+        return secciones;
+    }
+
+    private List<Seccion> seccionesCaja(Periodo periodo) {
+        List<Fila> arqueo = new ArrayList<>();
+        arqueo.add(new Fila("Pedidos cerrados", String.valueOf(periodo.pedidos), null));
+        arqueo.add(new Fila("Ventas", null, periodo.ventas));
+        if (periodo.consumoInterno.signum() != 0) {
+            arqueo.add(new Fila("Consumo interno", null, periodo.consumoInterno));
+        }
+        arqueo.add(new Fila("Ingresos manuales", null, periodo.ingresos));
+        arqueo.add(new Fila("Egresos", null, periodo.egresos.negate()));
+        if (periodo.devoluciones.signum() != 0) {
+            arqueo.add(new Fila("Devoluciones", null, periodo.devoluciones.negate()));
+        }
+        if (periodo.ajusteRedondeo.signum() != 0) {
+            arqueo.add(new Fila("Ajuste por redondeo", null, periodo.ajusteRedondeo));
+        }
+
+        List<Seccion> secciones = new ArrayList<>();
+        secciones.add(new Seccion("Arqueo", arqueo, new Fila("Balance de efectivo", null, periodo.balanceEfectivo)));
+
+        List<Fila> medios = periodo.porMedio.entrySet().stream()
+            .filter(e -> e.getValue().signum() != 0)
+            .map(e -> new Fila(etiquetaMedio(e.getKey()), null, e.getValue()))
+            .toList();
+        if (!medios.isEmpty()) {
+            BigDecimal total = medios.stream().map(Fila::monto).reduce(BigDecimal.ZERO, BigDecimal::add);
+            secciones.add(new Seccion("Por medio de pago", medios, new Fila("Total cobrado", null, total)));
+        }
+        return secciones;
+    }
+
+    private List<Seccion> seccionesProductos(Periodo periodo) {
+        List<ProductoVendidoReporte> productos = periodo.productosPorRecaudacion();
+        BigDecimal total = productos.stream()
+            .map(ProductoVendidoReporte::totalRecaudado)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+        return List.of(new Seccion(
+            "Productos",
+            productos.stream().map(this::filaProducto).toList(),
+            new Fila("Total", null, total)
+        ));
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Utilidades
+    // ════════════════════════════════════════════════════════════════════════════
+
+    private Fila filaProducto(ProductoVendidoReporte p) {
+        return new Fila(p.productoNombre(), p.cantidadTotal() + " u.", p.totalRecaudado());
+    }
+
+    private String porcentaje(BigDecimal monto, BigDecimal total) {
+        if (total.signum() == 0) {
+            return null;
+        }
+        return monto.multiply(BigDecimal.valueOf(100)).divide(total, 0, RoundingMode.HALF_UP) + "%";
+    }
+
+    private String titulo(Vista vista) {
+        return switch (vista) {
+            case VENTAS -> "Ventas";
+            case CAJA -> "Caja";
+            case PRODUCTOS -> "Productos vendidos";
+        };
+    }
+
+    private String etiquetaMedio(MedioPago medio) {
+        return switch (medio) {
+            case EFECTIVO -> "Efectivo";
+            case TARJETA -> "Tarjeta";
+            case TRANSFERENCIA -> "Transferencia";
+            case QR -> "QR";
+            case A_CUENTA -> "A cuenta";
+            case CUENTA_CORRIENTE -> "Cuenta corriente";
+            case TARJETA_REGALO -> "Tarjeta de regalo";
+        };
+    }
+}
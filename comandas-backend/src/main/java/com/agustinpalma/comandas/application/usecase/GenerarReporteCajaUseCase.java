@@ -3,6 +3,8 @@ package com.agustinpalma.comandas.application.usecase;
 import com.agustinpalma.comandas.application.dto.ReporteCajaResponse;
 import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.EfectivoMoneda;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
 import com.agustinpalma.comandas.domain.model.Mesa;
 import com.agustinpalma.comandas.domain.model.MovimientoCaja;
 import com.agustinpalma.comandas.domain.model.Pago;
@@ -12,12 +14,12 @@ import com.agustinpalma.comandas.domain.repository.MesaRepository;
 import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
 import com.agustinpalma.comandas.domain.repository.JornadaCajaRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
 import org.springframework.transaction.annotation.Transactional;
 
 import java.math.BigDecimal;
 import java.time.LocalDate;
 import java.time.LocalDateTime;
-import java.time.LocalTime;
 import java.util.EnumMap;
 import java.util.List;
 import java.util.Map;
@@ -39,8 +41,22 @@ import java.util.stream.Collectors;
  * - totalVentasReales = Σ pagos comerciales (EFECTIVO, TARJETA, TRANSFERENCIA, QR)
  * - totalConsumoInterno = Σ pagos A_CUENTA
  * - totalIngresos = Σ movimientos tipo INGRESO
- * - totalEgresos = Σ movimientos tipo EGRESO
- * - balanceEfectivo = (Σ pagos EFECTIVO) + totalIngresos − totalEgresos
+ * - totalEgresos = Σ movimientos tipo EGRESO que no son devoluciones
+ * - totalDevoluciones = Σ devoluciones de pedidos cerrados (HU-121)
+ * - balanceEfectivo = (Σ pagos EFECTIVO) + totalIngresos − totalEgresos − totalDevoluciones
+ * - recargosPorMedioPago = Σ recargos de cada medio (HU-143)
+ *
+ * HU-121: Una devolución es una venta que se revierte, no un gasto: se descuenta
+ * de las ventas reales del día en que se reintegró.
+ *
+ * HU-143: Los recargos por medio de pago no son ventas: se informan aparte.
+ * El recargo del efectivo sí suma al balance, porque entró al cajón.
+ *
+ * HU-151: El efectivo en dólares o euros suma al balance por su equivalente en
+ * pesos y además se informa aparte, en su moneda, para contarlo por separado.
+ *
+ * HU-153: El día del reporte es la jornada comercial del local, de la hora de
+ * corte a la hora de corte del día siguiente; no el día calendario.
  */
 @Transactional(readOnly = true)
 public class GenerarReporteCajaUseCase {
@@ -49,11 +65,13 @@ public class GenerarReporteCajaUseCase {
     private final MovimientoCajaRepository movimientoCajaRepository;
     private final MesaRepository mesaRepository;
     private final JornadaCajaRepository jornadaCajaRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
 
     public GenerarReporteCajaUseCase(PedidoRepository pedidoRepository,
                                      MovimientoCajaRepository movimientoCajaRepository,
                                      MesaRepository mesaRepository,
-                                     JornadaCajaRepository jornadaCajaRepository) {
+                                     JornadaCajaRepository jornadaCajaRepository,
+                                     JornadaComercialRepository jornadaComercialRepository) {
         this.pedidoRepository = Objects.requireNonNull(pedidoRepository, 
             "El pedidoRepository es obligatorio");
         this.movimientoCajaRepository = Objects.requireNonNull(movimientoCajaRepository, 
@@ -62,22 +80,26 @@ public class GenerarReporteCajaUseCase {
             "El mesaRepository es obligatorio");
         this.jornadaCajaRepository = Objects.requireNonNull(jornadaCajaRepository,
             "El jornadaCajaRepository es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository,
+            "El jornadaComercialRepository es obligatorio");
     }
 
     /**
      * Genera el reporte de caja para un día específico.
      *
      * @param localId identificador del local (tenant)
-     * @param fechaReporte fecha del reporte (se construye rango 00:00:00 a 23:59:59)
+     * @param fechaReporte fecha operativa del reporte (rango de la jornada comercial del local)
      * @return DTO con el reporte completo de caja
      */
     public ReporteCajaResponse ejecutar(LocalId localId, LocalDate fechaReporte) {
         Objects.requireNonNull(localId, "El localId es obligatorio");
         Objects.requireNonNull(fechaReporte, "La fecha del reporte es obligatoria");
 
-        // 1. Construir rango temporal del día
-        LocalDateTime inicio = fechaReporte.atStartOfDay();
-        LocalDateTime fin = fechaReporte.atTime(LocalTime.of(23, 59, 59));
+        // 1. Construir rango temporal de la jornada comercial (HU-153)
+        JornadaComercial jornadaComercial = jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId));
+        LocalDateTime inicio = jornadaComercial.inicio(fechaReporte);
+        LocalDateTime fin = jornadaComercial.fin(fechaReporte);
 
         // 2. Obtener pedidos cerrados del día (con pagos cargados via JOIN FETCH)
         List<Pedido> pedidosCerrados = pedidoRepository.buscarCerradosPorFecha(localId, inicio, fin);
@@ -107,11 +129,13 @@ public class GenerarReporteCajaUseCase {
      * 
      * Lógica de clasificación de pagos:
      * - A_CUENTA → consumo interno (no es venta real)
-     * - EFECTIVO, TARJETA, TRANSFERENCIA, QR → venta real
+     * - EFECTIVO, TARJETA, TRANSFERENCIA, QR, CUENTA_CORRIENTE → venta real
+     *   (CUENTA_CORRIENTE se cobra después, pero es venta del día)
      * - EFECTIVO → además suma al balance de efectivo
      * 
      * Lógica de clasificación de movimientos:
      * - EGRESO → salida de efectivo (resta al balance)
+     * - Devolución (EGRESO con pedido) → resta al balance y a las ventas reales
      * - INGRESO → entrada manual de efectivo (suma al balance)
      */
     private ReporteCajaDiario calcularReporte(List<Pedido> pedidosCerrados, 
@@ -120,6 +144,7 @@ public class GenerarReporteCajaUseCase {
         BigDecimal totalConsumoInterno = BigDecimal.ZERO;
         BigDecimal entradasEfectivo = BigDecimal.ZERO;
         Map<MedioPago, BigDecimal> desglose = new EnumMap<>(MedioPago.class);
+        Map<MedioPago, BigDecimal> recargos = new EnumMap<>(MedioPago.class);
 
         // 4a. Iterar todos los pagos de todos los pedidos cerrados
         for (Pedido pedido : pedidosCerrados) {
@@ -136,17 +161,28 @@ public class GenerarReporteCajaUseCase {
                 }
 
                 if (medio == MedioPago.EFECTIVO) {
-                    entradasEfectivo = entradasEfectivo.add(montoPago);
+                    entradasEfectivo = entradasEfectivo.add(pago.getMontoCobrado());
                 }
 
                 // Acumular en desglose por medio de pago
                 desglose.merge(medio, montoPago, BigDecimal::add);
+
+                // HU-143: Recargos discriminados por medio
+                if (pago.tieneRecargo()) {
+                    recargos.merge(medio, pago.getRecargo(), BigDecimal::add);
+                }
             }
         }
 
         // 4b. Calcular totales de movimientos separados por tipo
         BigDecimal totalEgresos = movimientos.stream()
             .filter(MovimientoCaja::esEgreso)
+            .filter(m -> !m.esDevolucion())
+            .map(MovimientoCaja::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        BigDecimal totalDevoluciones = movimientos.stream()
+            .filter(MovimientoCaja::esDevolucion)
             .map(MovimientoCaja::getMonto)
             .reduce(BigDecimal.ZERO, BigDecimal::add);
 
@@ -155,18 +191,23 @@ public class GenerarReporteCajaUseCase {
             .map(MovimientoCaja::getMonto)
             .reduce(BigDecimal.ZERO, BigDecimal::add);
 
-        // 4c. Balance de efectivo = entradas EFECTIVO + ingresos manuales - egresos
-        BigDecimal balanceEfectivo = entradasEfectivo.add(totalIngresos).subtract(totalEgresos);
+        // 4c. Balance de efectivo = entradas EFECTIVO + ingresos manuales - egresos - devoluciones
+        BigDecimal balanceEfectivo = entradasEfectivo.add(totalIngresos)
+            .subtract(totalEgresos)
+            .subtract(totalDevoluciones);
 
         return new ReporteCajaDiario(
-            totalVentasReales,
+            totalVentasReales.subtract(totalDevoluciones),
             totalConsumoInterno,
             totalIngresos,
             totalEgresos,
+            totalDevoluciones,
             balanceEfectivo,
             desglose,
+            recargos,
             movimientos,
-            pedidosCerrados
+            pedidosCerrados,
+            EfectivoMoneda.discriminar(pedidosCerrados)
         );
     }
 }
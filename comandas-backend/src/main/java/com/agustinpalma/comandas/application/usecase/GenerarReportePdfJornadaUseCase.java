@@ -6,20 +6,24 @@ import com.agustinpalma.comandas.domain.exception.JornadaNoEncontradaException;
 import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
 import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.EfectivoMoneda;
 import com.agustinpalma.comandas.domain.model.JornadaCaja;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
 import com.agustinpalma.comandas.domain.model.MovimientoCaja;
 import com.agustinpalma.comandas.domain.model.Pago;
 import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
 import com.agustinpalma.comandas.domain.repository.JornadaCajaRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
 import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
 import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
 import org.springframework.transaction.annotation.Transactional;
 
 import java.math.BigDecimal;
 import java.time.LocalDate;
 import java.time.LocalDateTime;
-import java.time.LocalTime;
 import java.util.EnumMap;
 import java.util.List;
 import java.util.Map;
@@ -32,6 +36,10 @@ import java.util.Objects;
  * 1. Busca la jornada por ID (validando existencia)
  * 2. Recalcula el desglose detallado a partir de pedidos y movimientos del día
  *    (la jornada solo guarda totales; el PDF necesita el desglose completo)
+ * 2b. HU-106: Agrega el arqueo de cada turno de la jornada (si se usaron turnos)
+ * 2c. HU-143: Discrimina los recargos por medio de pago cobrados en el día
+ * 2d. HU-151: Discrimina el efectivo cobrado en moneda extranjera
+ * (HU-153: el día es la jornada comercial del local, no el día calendario)
  * 3. Construye el DTO de datos del reporte con info del local
  * 4. Delega la generación de bytes PDF al puerto ReportePdfGenerator
  *
@@ -43,6 +51,8 @@ public class GenerarReportePdfJornadaUseCase {
     private final JornadaCajaRepository jornadaCajaRepository;
     private final PedidoRepository pedidoRepository;
     private final MovimientoCajaRepository movimientoCajaRepository;
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
     private final ReportePdfGenerator reportePdfGenerator;
     private final MeisenProperties meisenProperties;
 
@@ -50,11 +60,15 @@ public class GenerarReportePdfJornadaUseCase {
             JornadaCajaRepository jornadaCajaRepository,
             PedidoRepository pedidoRepository,
             MovimientoCajaRepository movimientoCajaRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            JornadaComercialRepository jornadaComercialRepository,
             ReportePdfGenerator reportePdfGenerator,
             MeisenProperties meisenProperties) {
         this.jornadaCajaRepository = Objects.requireNonNull(jornadaCajaRepository);
         this.pedidoRepository = Objects.requireNonNull(pedidoRepository);
         this.movimientoCajaRepository = Objects.requireNonNull(movimientoCajaRepository);
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository);
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository);
         this.reportePdfGenerator = Objects.requireNonNull(reportePdfGenerator);
         this.meisenProperties = Objects.requireNonNull(meisenProperties);
     }
@@ -77,14 +91,23 @@ public class GenerarReportePdfJornadaUseCase {
 
         // 2. Recalcular desglose detallado del día
         LocalDate fechaOperativa = jornada.getFechaOperativa();
-        LocalDateTime inicio = fechaOperativa.atStartOfDay();
-        LocalDateTime fin = fechaOperativa.atTime(LocalTime.of(23, 59, 59));
+        JornadaComercial jornadaComercial = jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId));
+        LocalDateTime inicio = jornadaComercial.inicio(fechaOperativa);
+        LocalDateTime fin = jornadaComercial.fin(fechaOperativa);
 
         List<Pedido> pedidosCerrados = pedidoRepository.buscarCerradosPorFecha(localId, inicio, fin);
         List<MovimientoCaja> movimientos = movimientoCajaRepository.buscarPorFecha(localId, inicio, fin);
 
         Map<MedioPago, BigDecimal> desglose = calcularDesglosePorMedioPago(pedidosCerrados);
+        Map<MedioPago, BigDecimal> recargos = calcularRecargosPorMedioPago(pedidosCerrados);
         BigDecimal totalIngresos = calcularTotalIngresos(movimientos);
+        List<ReporteCierreData.EfectivoMonedaDetalle> efectivoPorMoneda = EfectivoMoneda.discriminar(pedidosCerrados)
+            .stream()
+            .map(e -> new ReporteCierreData.EfectivoMonedaDetalle(
+                e.getMoneda().name(), e.getMonto(), e.getEquivalentePesos()))
+            .toList();
+        BigDecimal totalAjusteRedondeo = calcularTotalAjusteRedondeo(pedidosCerrados);
 
         List<ReporteCierreData.MovimientoDetalle> movimientoDetalles = movimientos.stream()
             .map(m -> new ReporteCierreData.MovimientoDetalle(
@@ -96,6 +119,11 @@ public class GenerarReportePdfJornadaUseCase {
             ))
             .toList();
 
+        List<ReporteCierreData.TurnoDetalle> turnoDetalles = turnoCajaRepository
+            .buscarPorJornada(jornadaId, localId).stream()
+            .map(this::toTurnoDetalle)
+            .toList();
+
         // 3. Construir DTO con datos del local
         MeisenProperties.LocalProperties localProps = meisenProperties.getLocal();
 
@@ -113,13 +141,29 @@ public class GenerarReportePdfJornadaUseCase {
             jornada.getBalanceEfectivo(),
             jornada.getPedidosCerradosCount(),
             desglose,
-            movimientoDetalles
+            movimientoDetalles,
+            turnoDetalles,
+            recargos,
+            efectivoPorMoneda,
+            totalAjusteRedondeo
         );
 
         // 4. Generar PDF
         return reportePdfGenerator.generarReporteCierre(data);
     }
 
+    private ReporteCierreData.TurnoDetalle toTurnoDetalle(TurnoCaja turno) {
+        return new ReporteCierreData.TurnoDetalle(
+            turno.getTipo().name(),
+            turno.getFechaApertura(),
+            turno.getFechaCierre(),
+            turno.getMontoInicial(),
+            turno.getEfectivoEsperado(),
+            turno.getEfectivoDeclarado(),
+            turno.getDiferencia()
+        );
+    }
+
     private Map<MedioPago, BigDecimal> calcularDesglosePorMedioPago(List<Pedido> pedidos) {
         Map<MedioPago, BigDecimal> desglose = new EnumMap<>(MedioPago.class);
         for (Pedido pedido : pedidos) {
@@ -130,6 +174,25 @@ public class GenerarReportePdfJornadaUseCase {
         return desglose;
     }
 
+    private Map<MedioPago, BigDecimal> calcularRecargosPorMedioPago(List<Pedido> pedidos) {
+        Map<MedioPago, BigDecimal> recargos = new EnumMap<>(MedioPago.class);
+        for (Pedido pedido : pedidos) {
+            for (Pago pago : pedido.getPagos()) {
+                if (pago.tieneRecargo()) {
+                    recargos.merge(pago.getMedio(), pago.getRecargo(), BigDecimal::add);
+                }
+            }
+        }
+        return recargos;
+    }
+
+    private BigDecimal calcularTotalAjusteRedondeo(List<Pedido> pedidos) {
+        return pedidos.stream()
+            .flatMap(pedido -> pedido.getPagos().stream())
+            .map(Pago::getAjusteRedondeo)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
     private BigDecimal calcularTotalIngresos(List<MovimientoCaja> movimientos) {
         return movimientos.stream()
             .filter(MovimientoCaja::esIngreso)
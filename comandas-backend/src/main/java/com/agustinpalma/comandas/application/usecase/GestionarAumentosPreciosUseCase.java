@@ -0,0 +1,168 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.AumentoPreciosRequest;
+import com.agustinpalma.comandas.application.dto.AumentoPreciosResponse;
+import com.agustinpalma.comandas.application.dto.CambioPrecioResponse;
+import com.agustinpalma.comandas.application.dto.VistaPreviaAumentoPreciosResponse;
+import com.agustinpalma.comandas.domain.model.AumentoPrecios;
+import com.agustinpalma.comandas.domain.model.CambioPrecio;
+import com.agustinpalma.comandas.domain.model.CambioPrecioProducto;
+import com.agustinpalma.comandas.domain.model.DomainEnums.OrigenCambioPrecio;
+import com.agustinpalma.comandas.domain.model.DomainIds.AumentoPreciosId;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.AumentoPreciosRepository;
+import com.agustinpalma.comandas.domain.repository.CambioPrecioProductoRepository;
+import com.agustinpalma.comandas.domain.repository.CategoriaRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * HU-145: Aumentos porcentuales masivos de precios, por categoría o sobre
+ * todo el catálogo, con vista previa, redondeo y vigencia programada.
+ *
+ * Los aumentos con vigencia hoy se aplican al confirmarlos; los de un día
+ * futuro quedan programados y los aplica {@link #aplicarProgramados()},
+ * que corre periódicamente desde infraestructura. Como ItemPedido congela
+ * el precio, los pedidos abiertos no cambian.
+ *
+ * HU-146: Cada precio que cambia queda en el historial de precios.
+ */
+@Transactional
+public class GestionarAumentosPreciosUseCase {
+
+    private final ProductoRepository productoRepository;
+    private final CategoriaRepository categoriaRepository;
+    private final AumentoPreciosRepository aumentoPreciosRepository;
+    private final CambioPrecioProductoRepository cambioPrecioProductoRepository;
+    private final Clock clock;
+
+    public GestionarAumentosPreciosUseCase(ProductoRepository productoRepository,
+                                           CategoriaRepository categoriaRepository,
+                                           AumentoPreciosRepository aumentoPreciosRepository,
+                                           CambioPrecioProductoRepository cambioPrecioProductoRepository,
+                                           Clock clock) {
+        this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+        this.categoriaRepository = Objects.requireNonNull(categoriaRepository, "El categoriaRepository es obligatorio");
+        this.aumentoPreciosRepository = Objects.requireNonNull(aumentoPreciosRepository,
+            "El aumentoPreciosRepository es obligatorio");
+        this.cambioPrecioProductoRepository = Objects.requireNonNull(cambioPrecioProductoRepository,
+            "El cambioPrecioProductoRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * Calcula los precios nuevos con los precios de hoy, sin guardar nada.
+     *
+     * @throws IllegalArgumentException si la categoría no es del local o el request es inválido
+     */
+    @Transactional(readOnly = true)
+    public VistaPreviaAumentoPreciosResponse vistaPrevia(LocalId localId, AumentoPreciosRequest request) {
+        AumentoPrecios aumento = armar(localId, request);
+        List<CambioPrecioResponse> cambios = aumento.vistaPrevia(productosAlcanzados(aumento)).stream()
+            .map(CambioPrecioResponse::fromDomain)
+            .toList();
+        return new VistaPreviaAumentoPreciosResponse(cambios, AumentoPrecios.proximoDiaHabil(LocalDate.now(clock)));
+    }
+
+    /**
+     * Confirma el aumento: lo aplica si la vigencia es hoy, si no lo deja programado.
+     *
+     * @throws IllegalArgumentException si la categoría no es del local o la vigencia ya pasó
+     */
+    public AumentoPreciosResponse confirmar(LocalId localId, AumentoPreciosRequest request) {
+        AumentoPrecios aumento = armar(localId, request);
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        if (!aumento.getFechaVigencia().isAfter(ahora.toLocalDate())) {
+            aplicar(aumento, ahora);
+        }
+        return AumentoPreciosResponse.fromDomain(aumentoPreciosRepository.guardar(aumento));
+    }
+
+    @Transactional(readOnly = true)
+    public List<AumentoPreciosResponse> listar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        return aumentoPreciosRepository.buscarPorLocal(localId).stream()
+            .map(AumentoPreciosResponse::fromDomain)
+            .toList();
+    }
+
+    /**
+     * @throws IllegalArgumentException si el aumento no existe
+     * @throws IllegalStateException si ya se aplicó o se canceló
+     */
+    public AumentoPreciosResponse cancelar(LocalId localId, AumentoPreciosId aumentoId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(aumentoId, "El aumentoId es obligatorio");
+        AumentoPrecios aumento = aumentoPreciosRepository.buscarPorId(aumentoId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("Aumento de precios no encontrado"));
+        aumento.cancelar();
+        return AumentoPreciosResponse.fromDomain(aumentoPreciosRepository.guardar(aumento));
+    }
+
+    /**
+     * Aplica los aumentos programados cuya vigencia llegó, de todos los locales.
+     * Si la app estuvo apagada el día de vigencia, se aplican al volver a levantar.
+     *
+     * @return cantidad de aumentos aplicados
+     */
+    public int aplicarProgramados() {
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        List<AumentoPrecios> pendientes = aumentoPreciosRepository.buscarProgramadosHasta(ahora.toLocalDate());
+        for (AumentoPrecios aumento : pendientes) {
+            aplicar(aumento, ahora);
+            aumentoPreciosRepository.guardar(aumento);
+        }
+        return pendientes.size();
+    }
+
+    private AumentoPrecios armar(LocalId localId, AumentoPreciosRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+        LocalDateTime ahora = LocalDateTime.now(clock);
+
+        CategoriaId categoriaId = null;
+        if (request.categoriaId() != null) {
+            categoriaId = new CategoriaId(request.categoriaId());
+            if (categoriaRepository.buscarPorIdYLocal(categoriaId, localId).isEmpty()) {
+                throw new IllegalArgumentException("Categoría no encontrada");
+            }
+        }
+        LocalDate vigencia = request.fechaVigencia() != null ? request.fechaVigencia() : ahora.toLocalDate();
+        return AumentoPrecios.programar(localId, categoriaId, request.porcentaje(), request.multiploRedondeo(),
+            vigencia, request.responsable(), ahora);
+    }
+
+    private void aplicar(AumentoPrecios aumento, LocalDateTime ahora) {
+        List<Producto> modificados = aumento.aplicar(productosAlcanzados(aumento), ahora);
+        modificados.forEach(productoRepository::guardar);
+
+        Map<ProductoId, Producto> porId = modificados.stream()
+            .collect(Collectors.toMap(Producto::getId, Function.identity()));
+        for (CambioPrecio cambio : aumento.getCambios()) {
+            if (cambio.getPrecioAnterior().compareTo(cambio.getPrecioNuevo()) != 0) {
+                Producto producto = porId.get(cambio.getProductoId());
+                cambioPrecioProductoRepository.guardar(CambioPrecioProducto.registrar(producto,
+                    cambio.getPrecioAnterior(), OrigenCambioPrecio.AUMENTO_MASIVO, aumento.getId(),
+                    aumento.getResponsable(), ahora));
+            }
+        }
+    }
+
+    private List<Producto> productosAlcanzados(AumentoPrecios aumento) {
+        return aumento.getCategoriaId() != null
+            ? productoRepository.buscarPorCategoriaId(aumento.getLocalId(), aumento.getCategoriaId())
+            : productoRepository.buscarPorLocal(aumento.getLocalId());
+    }
+}
@@ -0,0 +1,257 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.AbrirMesaRequest;
+import com.agustinpalma.comandas.application.dto.AgregarProductoRequest;
+import com.agustinpalma.comandas.application.dto.ResolverSolicitudAutopedidoRequest;
+import com.agustinpalma.comandas.application.dto.SolicitudAutopedidoRequest;
+import com.agustinpalma.comandas.application.dto.SolicitudAutopedidoRequest.ItemSolicitudRequest;
+import com.agustinpalma.comandas.application.dto.SolicitudAutopedidoResponse;
+import com.agustinpalma.comandas.application.ports.output.NotificadorTiempoRealPort;
+import com.agustinpalma.comandas.domain.model.Categoria;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.SolicitudAutopedidoId;
+import com.agustinpalma.comandas.domain.model.ItemSolicitudAutopedido;
+import com.agustinpalma.comandas.domain.model.ListaPrecios;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.model.SolicitudAutopedido;
+import com.agustinpalma.comandas.domain.repository.CategoriaRepository;
+import com.agustinpalma.comandas.domain.repository.ListaPreciosRepository;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.domain.repository.SolicitudAutopedidoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.time.LocalTime;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Caso de uso de los pedidos que arman los clientes desde el QR de la mesa.
+ *
+ * HU-126: Autopedido por QR.
+ *
+ * Flujo:
+ * 1. El cliente envía su pedido; queda PENDIENTE y se avisa en tiempo real
+ *    a todas las terminales.
+ * 2. El mozo lo revisa en la mesa y lo confirma o lo rechaza con un motivo.
+ * 3. Al confirmar, los ítems se cargan en el pedido de la mesa (abriéndola si
+ *    estaba libre) con las mismas reglas que si los cargara el mozo: precios
+ *    vigentes, promociones, productos 86. El envío a cocina sigue siendo el
+ *    de siempre, así que nada llega a cocina sin pasar por el mozo.
+ *
+ * Reglas:
+ * - Solo se aceptan productos que el cliente puede pedir solo
+ *   ({@link Producto#admiteAutopedido()}).
+ * - HU-161: Ni fuera de su horario, aunque el cliente tuviera la carta abierta
+ *   desde antes.
+ * - Una mesa no puede acumular más de
+ *   {@link SolicitudAutopedido#MAXIMO_PENDIENTES_POR_MESA} pedidos sin resolver.
+ */
+@Transactional
+public class GestionarAutopedidoUseCase {
+
+    private final SolicitudAutopedidoRepository solicitudRepository;
+    private final MesaRepository mesaRepository;
+    private final PedidoRepository pedidoRepository;
+    private final ProductoRepository productoRepository;
+    private final CategoriaRepository categoriaRepository;
+    private final ListaPreciosRepository listaPreciosRepository;
+    private final MozoRepository mozoRepository;
+    private final AbrirMesaUseCase abrirMesaUseCase;
+    private final AgregarProductoUseCase agregarProductoUseCase;
+    private final NotificadorTiempoRealPort notificador;
+    private final Clock clock;
+
+    public GestionarAutopedidoUseCase(
+            SolicitudAutopedidoRepository solicitudRepository,
+            MesaRepository mesaRepository,
+            PedidoRepository pedidoRepository,
+            ProductoRepository productoRepository,
+            CategoriaRepository categoriaRepository,
+            ListaPreciosRepository listaPreciosRepository,
+            MozoRepository mozoRepository,
+            AbrirMesaUseCase abrirMesaUseCase,
+            AgregarProductoUseCase agregarProductoUseCase,
+            NotificadorTiempoRealPort notificador,
+            Clock clock
+    ) {
+        this.solicitudRepository = Objects.requireNonNull(solicitudRepository, "El solicitudRepository es obligatorio");
+        this.mesaRepository = Objects.requireNonNull(mesaRepository, "El mesaRepository es obligatorio");
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+        this.categoriaRepository = Objects.requireNonNull(categoriaRepository, "El categoriaRepository es obligatorio");
+        this.listaPreciosRepository = Objects.requireNonNull(listaPreciosRepository, "El listaPreciosRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.abrirMesaUseCase = Objects.requireNonNull(abrirMesaUseCase, "El abrirMesaUseCase es obligatorio");
+        this.agregarProductoUseCase = Objects.requireNonNull(agregarProductoUseCase, "El agregarProductoUseCase es obligatorio");
+        this.notificador = Objects.requireNonNull(notificador, "El notificador es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * Registra el pedido que el cliente armó en la carta del QR.
+     *
+     * @throws IllegalArgumentException si la mesa o algún producto no existen en el local
+     * @throws IllegalStateException si algún producto ya no se puede pedir o la mesa tiene demasiados pedidos sin resolver
+     */
+    public SolicitudAutopedidoResponse enviar(LocalId localId, MesaId mesaId, SolicitudAutopedidoRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(mesaId, "El mesaId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Mesa mesa = mesaRepository.buscarPorId(mesaId)
+            .filter(m -> m.getLocalId().equals(localId))
+            .orElseThrow(() -> new IllegalArgumentException("Este QR ya no corresponde a ninguna mesa. Pedile ayuda al mozo"));
+
+        if (solicitudRepository.contarPendientesPorMesa(localId, mesaId) >= SolicitudAutopedido.MAXIMO_PENDIENTES_POR_MESA) {
+            throw new IllegalStateException("Ya hay pedidos de esta mesa esperando al mozo. Aguardá a que los confirme");
+        }
+
+        ListaPrecios listaSalon = listaPreciosRepository.buscarPorLocalYCanal(localId, CanalVenta.SALON)
+            .orElse(null);
+        List<ItemSolicitudAutopedido> items = request.items().stream()
+            .map(item -> armarItem(localId, item, listaSalon))
+            .toList();
+
+        SolicitudAutopedido solicitud = solicitudRepository.guardar(
+            SolicitudAutopedido.enviar(localId, mesa, request.nombreCliente(), items, LocalDateTime.now(clock))
+        );
+
+        SolicitudAutopedidoResponse response = SolicitudAutopedidoResponse.fromDomain(solicitud);
+        notificador.publicar(localId, SolicitudAutopedidoResponse.EVENTO_NUEVA, response);
+        return response;
+    }
+
+    /**
+     * Estado de un pedido; la carta lo consulta para mostrarle al cliente si ya lo confirmaron.
+     *
+     * @throws IllegalArgumentException si no existe en el local
+     */
+    @Transactional(readOnly = true)
+    public SolicitudAutopedidoResponse consultar(LocalId localId, SolicitudAutopedidoId solicitudId) {
+        return SolicitudAutopedidoResponse.fromDomain(buscar(localId, solicitudId));
+    }
+
+    /**
+     * Pedidos del QR todavía sin resolver, del más viejo al más nuevo.
+     */
+    @Transactional(readOnly = true)
+    public List<SolicitudAutopedidoResponse> listarPendientes(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        return solicitudRepository.buscarPendientes(localId).stream()
+            .map(SolicitudAutopedidoResponse::fromDomain)
+            .toList();
+    }
+
+    /**
+     * El mozo valida el pedido: los ítems pasan al pedido abierto de la mesa.
+     * Si la mesa estaba libre se abre en el salón, con el mozo que confirmó a cargo.
+     *
+     * @throws IllegalArgumentException si la solicitud o el mozo no existen
+     * @throws IllegalStateException si ya fue resuelta o algún producto se quedó sin disponibilidad
+     */
+    public SolicitudAutopedidoResponse confirmar(LocalId localId, SolicitudAutopedidoId solicitudId,
+                                                 ResolverSolicitudAutopedidoRequest request) {
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        SolicitudAutopedido solicitud = buscar(localId, solicitudId);
+        MozoId mozoId = resolverMozo(localId, request.mozoId());
+
+        // Primero la transición: si otra terminal ya la resolvió, no se carga nada
+        solicitud.confirmar(mozoId, LocalDateTime.now(clock));
+
+        PedidoId pedidoId = pedidoRepository.buscarPorMesaYEstado(solicitud.getMesaId(), EstadoPedido.ABIERTO)
+            .map(Pedido::getId)
+            .orElseGet(() -> PedidoId.from(abrirMesaUseCase.ejecutar(localId, new AbrirMesaRequest(
+                solicitud.getMesaId().getValue().toString(),
+                CanalVenta.SALON,
+                mozoId != null ? mozoId.getValue().toString() : null,
+                null
+            )).pedidoId()));
+
+        for (ItemSolicitudAutopedido item : solicitud.getItems()) {
+            agregarProductoUseCase.ejecutar(new AgregarProductoRequest(
+                pedidoId, item.getProductoId(), item.getCantidad(), item.getObservacion()
+            ));
+        }
+
+        return resolver(localId, solicitud);
+    }
+
+    /**
+     * El mozo descarta el pedido. El motivo le aparece al cliente en la carta.
+     *
+     * @throws IllegalArgumentException si la solicitud o el mozo no existen, o falta el motivo
+     * @throws IllegalStateException si ya fue resuelta
+     */
+    public SolicitudAutopedidoResponse rechazar(LocalId localId, SolicitudAutopedidoId solicitudId,
+                                                ResolverSolicitudAutopedidoRequest request) {
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        SolicitudAutopedido solicitud = buscar(localId, solicitudId);
+        solicitud.rechazar(request.motivo(), resolverMozo(localId, request.mozoId()), LocalDateTime.now(clock));
+
+        return resolver(localId, solicitud);
+    }
+
+    private SolicitudAutopedidoResponse resolver(LocalId localId, SolicitudAutopedido solicitud) {
+        SolicitudAutopedidoResponse response = SolicitudAutopedidoResponse.fromDomain(
+            solicitudRepository.guardar(solicitud)
+        );
+        notificador.publicar(localId, SolicitudAutopedidoResponse.EVENTO_RESUELTA, response);
+        return response;
+    }
+
+    private SolicitudAutopedido buscar(LocalId localId, SolicitudAutopedidoId solicitudId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(solicitudId, "El solicitudId es obligatorio");
+        return solicitudRepository.buscarPorId(solicitudId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("El pedido no existe en este local"));
+    }
+
+    private ItemSolicitudAutopedido armarItem(LocalId localId, ItemSolicitudRequest item, ListaPrecios listaSalon) {
+        Producto producto = productoRepository.buscarPorIdYLocal(ProductoId.from(item.productoId()), localId)
+            .orElseThrow(() -> new IllegalArgumentException("Uno de los productos ya no está en la carta"));
+        if (!producto.admiteAutopedido()) {
+            throw new IllegalStateException(
+                String.format("'%s' ya no está disponible. Sacalo del pedido y volvé a enviarlo", producto.getNombre())
+            );
+        }
+        Categoria categoria = producto.getCategoriaId() == null ? null
+            : categoriaRepository.buscarPorId(producto.getCategoriaId()).orElse(null);
+        if (!producto.seOfreceA(LocalTime.now(clock), categoria)) {
+            throw new IllegalStateException(
+                String.format("'%s' ya no se sirve a esta hora. Sacalo del pedido y volvé a enviarlo", producto.getNombre())
+            );
+        }
+        return new ItemSolicitudAutopedido(
+            producto.getId(),
+            producto.getNombre(),
+            ConsultarCartaAutopedidoUseCase.precioSalon(producto, listaSalon),
+            item.cantidad(),
+            item.observacion()
+        );
+    }
+
+    private MozoId resolverMozo(LocalId localId, String mozoId) {
+        if (mozoId == null || mozoId.isBlank()) {
+            return null;
+        }
+        Mozo mozo = mozoRepository.buscarPorId(MozoId.from(mozoId), localId)
+            .orElseThrow(() -> new IllegalArgumentException("El mozo no existe en este local"));
+        return mozo.getId();
+    }
+}
@@ -0,0 +1,263 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ArqueoCajaResponse;
+import com.agustinpalma.comandas.application.dto.CajaResponse;
+import com.agustinpalma.comandas.application.ports.output.CajaContextProvider;
+import com.agustinpalma.comandas.domain.model.AperturaCaja;
+import com.agustinpalma.comandas.domain.model.Caja;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.JornadaCaja;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.CajaRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaCajaRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Optional;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * HU-159: Caso de uso para operar con varias cajas en el mismo local.
+ *
+ * - Cada terminal que cobra se registra una vez como caja.
+ * - En cada jornada, cada caja declara su fondo inicial antes de cobrar.
+ * - Los cobros, egresos e ingresos quedan a nombre de la caja de la terminal
+ *   que los hizo. Sin caja registrada, van a la caja principal.
+ * - Al cierre, cada caja tiene su arqueo y la suma da el efectivo del local.
+ *   Las devoluciones salen de la caja principal.
+ */
+@Transactional
+public class GestionarCajasUseCase {
+
+    /** Nombre con el que figura en los arqueos la caja de la jornada */
+    public static final String NOMBRE_CAJA_PRINCIPAL = "Caja principal";
+
+    private final CajaRepository cajaRepository;
+    private final JornadaCajaRepository jornadaCajaRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
+    private final PedidoRepository pedidoRepository;
+    private final MovimientoCajaRepository movimientoCajaRepository;
+    private final CajaContextProvider cajaContextProvider;
+    private final Clock clock;
+
+    public GestionarCajasUseCase(CajaRepository cajaRepository,
+                                 JornadaCajaRepository jornadaCajaRepository,
+                                 JornadaComercialRepository jornadaComercialRepository,
+                                 PedidoRepository pedidoRepository,
+                                 MovimientoCajaRepository movimientoCajaRepository,
+                                 CajaContextProvider cajaContextProvider,
+                                 Clock clock) {
+        this.cajaRepository = Objects.requireNonNull(cajaRepository, "El cajaRepository es obligatorio");
+        this.jornadaCajaRepository = Objects.requireNonNull(jornadaCajaRepository,
+            "El jornadaCajaRepository es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository,
+            "El jornadaComercialRepository es obligatorio");
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.movimientoCajaRepository = Objects.requireNonNull(movimientoCajaRepository,
+            "El movimientoCajaRepository es obligatorio");
+        this.cajaContextProvider = Objects.requireNonNull(cajaContextProvider,
+            "El cajaContextProvider es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * Registra una terminal como caja del local.
+     *
+     * @throws IllegalArgumentException si ya hay una caja con ese nombre
+     */
+    public CajaResponse registrar(LocalId localId, String nombre) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        if (nombre != null && cajaRepository.existePorNombre(localId, nombre)) {
+            throw new IllegalArgumentException("Ya existe una caja llamada " + nombre.trim());
+        }
+        Caja caja = new Caja(CajaId.generate(), localId, nombre, LocalDateTime.now(clock));
+        return CajaResponse.fromDomain(cajaRepository.guardar(caja), null);
+    }
+
+    /**
+     * @return cajas del local con su apertura en la jornada en curso (si la hay)
+     */
+    @Transactional(readOnly = true)
+    public List<CajaResponse> listar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        Map<CajaId, AperturaCaja> aperturas = jornadaCajaRepository.buscarAbierta(localId)
+            .map(jornada -> cajaRepository.buscarAperturas(jornada.getId(), localId).stream()
+                .collect(Collectors.toMap(AperturaCaja::getCajaId, Function.identity())))
+            .orElse(Map.of());
+
+        return cajaRepository.buscarPorLocal(localId).stream()
+            .map(caja -> CajaResponse.fromDomain(caja, aperturas.get(caja.getId())))
+            .toList();
+    }
+
+    /**
+     * Declara el fondo inicial de una caja en la jornada en curso.
+     *
+     * @throws IllegalArgumentException si la caja no existe en el local o el fondo es negativo
+     * @throws IllegalStateException si no hay jornada abierta o la caja ya se abrió en esta jornada
+     */
+    public CajaResponse abrir(LocalId localId, CajaId cajaId, BigDecimal fondoInicial) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(cajaId, "El cajaId es obligatorio");
+
+        Caja caja = buscarCaja(cajaId, localId);
+        JornadaCaja jornada = jornadaCajaRepository.buscarAbierta(localId)
+            .orElseThrow(() -> new IllegalStateException(
+                "No hay una jornada abierta. Abra la caja principal primero."));
+        if (cajaRepository.buscarApertura(jornada.getId(), cajaId, localId).isPresent()) {
+            throw new IllegalStateException("La caja " + caja.getNombre() + " ya se abrió en esta jornada");
+        }
+
+        AperturaCaja apertura = new AperturaCaja(localId, jornada.getId(), cajaId, fondoInicial,
+            LocalDateTime.now(clock));
+        return CajaResponse.fromDomain(caja, cajaRepository.guardarApertura(apertura));
+    }
+
+    /**
+     * Caja a la que se imputa un cobro, egreso o ingreso hecho ahora desde la
+     * terminal del request. Con una jornada en curso, la caja tiene que haber
+     * declarado su fondo: si no, su arqueo no cerraría.
+     *
+     * @return la caja de la terminal, o null si opera la caja principal
+     * @throws IllegalArgumentException si la caja de la terminal no existe en el local
+     * @throws IllegalStateException si la caja no se abrió en la jornada en curso
+     */
+    @Transactional(readOnly = true)
+    public CajaId cajaActual(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        Optional<CajaId> cajaId = cajaContextProvider.getCajaActual();
+        if (cajaId.isEmpty()) {
+            return null;
+        }
+        Caja caja = buscarCaja(cajaId.get(), localId);
+        jornadaCajaRepository.buscarAbierta(localId).ifPresent(jornada -> {
+            if (cajaRepository.buscarApertura(jornada.getId(), caja.getId(), localId).isEmpty()) {
+                throw new IllegalStateException(
+                    "La caja " + caja.getNombre() + " no está abierta: declare su fondo inicial antes de operar");
+            }
+        });
+        return caja.getId();
+    }
+
+    /**
+     * Arqueo de cada caja de una jornada: primero la principal y después las
+     * demás en orden de apertura.
+     *
+     * @throws IllegalArgumentException si la jornada no existe en el local
+     */
+    @Transactional(readOnly = true)
+    public List<ArqueoCajaResponse> arqueo(JornadaCajaId jornadaId, LocalId localId) {
+        Objects.requireNonNull(jornadaId, "El jornadaId es obligatorio");
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        JornadaCaja jornada = jornadaCajaRepository.buscarPorId(jornadaId)
+            .filter(j -> j.getLocalId().equals(localId))
+            .orElseThrow(() -> new IllegalArgumentException("La jornada no existe en este local"));
+
+        // La caja principal va con clave null
+        Map<CajaId, Acumulado> cajas = new LinkedHashMap<>();
+        cajas.put(null, new Acumulado(NOMBRE_CAJA_PRINCIPAL, jornada.getFondoInicial()));
+        for (AperturaCaja apertura : cajaRepository.buscarAperturas(jornadaId, localId)) {
+            cajas.put(apertura.getCajaId(),
+                new Acumulado(nombreDe(apertura.getCajaId(), localId), apertura.getFondoInicial()));
+        }
+
+        JornadaComercial jornadaComercial = jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId));
+        LocalDate fechaOperativa = jornada.getFechaOperativa();
+        LocalDateTime inicio = jornadaComercial.inicio(fechaOperativa);
+        LocalDateTime fin = jornadaComercial.fin(fechaOperativa);
+
+        // El pedido cuenta en la caja que lo cerró; cada pago, en la que lo cobró
+        for (Pedido pedido : pedidoRepository.buscarCerradosPorFecha(localId, inicio, fin)) {
+            acumuladoDe(cajas, pedido.getCajaId(), localId).pedidos++;
+            for (Pago pago : pedido.getPagos()) {
+                if (pago.getMedio() == MedioPago.EFECTIVO) {
+                    // HU-143: el recargo del efectivo también entra al cajón
+                    Acumulado caja = acumuladoDe(cajas, pago.getCajaId(), localId);
+                    caja.ventasEfectivo = caja.ventasEfectivo.add(pago.getMontoCobrado());
+                }
+            }
+        }
+
+        for (MovimientoCaja movimiento : movimientoCajaRepository.buscarPorFecha(localId, inicio, fin)) {
+            Acumulado caja = acumuladoDe(cajas, movimiento.getCajaId(), localId);
+            if (movimiento.esDevolucion()) {
+                caja.devoluciones = caja.devoluciones.add(movimiento.getMonto());
+            } else if (movimiento.esEgreso()) {
+                caja.egresos = caja.egresos.add(movimiento.getMonto());
+            } else if (movimiento.esIngreso()) {
+                caja.ingresos = caja.ingresos.add(movimiento.getMonto());
+            }
+        }
+
+        return cajas.entrySet().stream()
+            .map(e -> e.getValue().toResponse(e.getKey()))
+            .toList();
+    }
+
+    /** Una caja que operó sin declarar fondo en la jornada arranca de cero */
+    private Acumulado acumuladoDe(Map<CajaId, Acumulado> cajas, CajaId cajaId, LocalId localId) {
+        return cajas.computeIfAbsent(cajaId, id -> new Acumulado(nombreDe(id, localId), BigDecimal.ZERO));
+    }
+
+    private Caja buscarCaja(CajaId cajaId, LocalId localId) {
+        return cajaRepository.buscarPorId(cajaId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("La caja no existe en este local"));
+    }
+
+    /** Una caja borrada a mano de la base sigue figurando en el arqueo */
+    private String nombreDe(CajaId cajaId, LocalId localId) {
+        return cajaRepository.buscarPorId(cajaId, localId)
+            .map(Caja::getNombre)
+            .orElse("Caja " + cajaId);
+    }
+
+    /**
+     * Totales de una caja mientras se recorre la jornada.
+     */
+    private static final class Acumulado {
+        private final String nombre;
+        private final BigDecimal fondoInicial;
+        private BigDecimal ventasEfectivo = BigDecimal.ZERO;
+        private BigDecimal ingresos = BigDecimal.ZERO;
+        private BigDecimal egresos = BigDecimal.ZERO;
+        private BigDecimal devoluciones = BigDecimal.ZERO;
+        private int pedidos;
+
+        private Acumulado(String nombre, BigDecimal fondoInicial) {
+            this.nombre = nombre;
+            this.fondoInicial = fondoInicial;
+        }
+
+        private ArqueoCajaResponse toResponse(CajaId cajaId) {
+            // Arqueo = Fondo Inicial + Ventas Efectivo + Ingresos − Egresos − Devoluciones
+            BigDecimal balance = fondoInicial.add(ventasEfectivo).add(ingresos)
+                .subtract(egresos).subtract(devoluciones);
+            return new ArqueoCajaResponse(
+                cajaId != null ? cajaId.getValue() : null, nombre, fondoInicial,
+                ventasEfectivo, ingresos, egresos, devoluciones, balance, pedidos
+            );
+        }
+    }
+}
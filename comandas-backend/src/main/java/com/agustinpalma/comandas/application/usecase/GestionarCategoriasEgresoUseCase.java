@@ -0,0 +1,88 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.CategoriaEgresoRequest;
+import com.agustinpalma.comandas.application.dto.CategoriaEgresoResponse;
+import com.agustinpalma.comandas.domain.model.CategoriaEgreso;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaEgresoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.repository.CategoriaEgresoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * HU-130: Caso de uso para el ABM de categorías de egresos de caja.
+ *
+ * La primera vez que un local consulta sus categorías se crean las de uso
+ * habitual (limpieza, mercadería, mantenimiento, adelantos de sueldo), que
+ * después se renombran o desactivan como cualquier otra.
+ */
+@Transactional
+public class GestionarCategoriasEgresoUseCase {
+
+    private final CategoriaEgresoRepository categoriaEgresoRepository;
+
+    public GestionarCategoriasEgresoUseCase(CategoriaEgresoRepository categoriaEgresoRepository) {
+        this.categoriaEgresoRepository = Objects.requireNonNull(categoriaEgresoRepository,
+            "El categoriaEgresoRepository es obligatorio");
+    }
+
+    /**
+     * @return categorías del local (activas e inactivas) ordenadas por nombre
+     */
+    public List<CategoriaEgresoResponse> listar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        List<CategoriaEgreso> categorias = categoriaEgresoRepository.buscarPorLocal(localId);
+        if (categorias.isEmpty()) {
+            CategoriaEgreso.NOMBRES_INICIALES.forEach(nombre ->
+                categoriaEgresoRepository.guardar(CategoriaEgreso.crear(localId, nombre)));
+            categorias = categoriaEgresoRepository.buscarPorLocal(localId);
+        }
+        return categorias.stream()
+            .map(CategoriaEgresoResponse::fromDomain)
+            .toList();
+    }
+
+    /**
+     * @throws IllegalArgumentException si ya existe una categoría con ese nombre
+     */
+    public CategoriaEgresoResponse crear(LocalId localId, CategoriaEgresoRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        validarNombreLibre(localId, request.nombre(), null);
+        CategoriaEgreso categoria = CategoriaEgreso.crear(localId, request.nombre());
+        return CategoriaEgresoResponse.fromDomain(categoriaEgresoRepository.guardar(categoria));
+    }
+
+    /**
+     * Renombra o activa/desactiva una categoría. Los egresos ya registrados
+     * siguen apuntando a ella y toman el nombre nuevo en los reportes.
+     *
+     * @throws IllegalArgumentException si la categoría no existe en el local o el nombre ya está usado
+     */
+    public CategoriaEgresoResponse actualizar(LocalId localId, CategoriaEgresoId categoriaId,
+                                              CategoriaEgresoRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(categoriaId, "El categoriaId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        CategoriaEgreso categoria = categoriaEgresoRepository.buscarPorId(categoriaId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("La categoría de egreso no existe en este local"));
+        validarNombreLibre(localId, request.nombre(), categoriaId);
+
+        categoria.actualizar(request.nombre(), request.activa() == null || request.activa());
+        return CategoriaEgresoResponse.fromDomain(categoriaEgresoRepository.guardar(categoria));
+    }
+
+    private void validarNombreLibre(LocalId localId, String nombre, CategoriaEgresoId excepto) {
+        boolean repetido = categoriaEgresoRepository.buscarPorLocal(localId).stream()
+            .filter(c -> !c.getId().equals(excepto))
+            .anyMatch(c -> c.tieneNombre(nombre));
+        if (repetido) {
+            throw new IllegalArgumentException("Ya existe una categoría de egreso llamada \"" + nombre.trim() + "\"");
+        }
+    }
+}
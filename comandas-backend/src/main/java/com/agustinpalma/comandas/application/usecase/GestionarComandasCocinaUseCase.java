@@ -0,0 +1,83 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ComandaCocinaResponse;
+import com.agustinpalma.comandas.domain.model.ComandaCocina;
+import com.agustinpalma.comandas.domain.model.DomainIds.ComandaCocinaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.repository.ComandaCocinaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * HU-119: Caso de uso de la pantalla de cocina.
+ *
+ * Lista las comandas en preparación con el tiempo que llevan y si están
+ * demoradas, y permite marcarlas como listas (lo que cierra su medición).
+ * El cálculo de demora se hace en el servidor con el reloj del sistema, para
+ * que todas las pantallas coincidan.
+ *
+ * HU-136: El bump bar puede recuperar la última comanda marcada lista.
+ * HU-137: Las comandas urgentes encabezan la lista.
+ */
+@Transactional
+public class GestionarComandasCocinaUseCase {
+
+    private final ComandaCocinaRepository comandaCocinaRepository;
+    private final Clock clock;
+
+    public GestionarComandasCocinaUseCase(ComandaCocinaRepository comandaCocinaRepository, Clock clock) {
+        this.comandaCocinaRepository = Objects.requireNonNull(comandaCocinaRepository, "El comandaCocinaRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @return comandas en preparación: primero las urgentes, y dentro de cada grupo de la más vieja a la más nueva
+     */
+    @Transactional(readOnly = true)
+    public List<ComandaCocinaResponse> listarPendientes(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        return comandaCocinaRepository.buscarPendientes(localId).stream()
+            .sorted(ComandaCocina.PRIORIDAD_EN_COCINA)
+            .map(comanda -> ComandaCocinaResponse.fromDomain(comanda, ahora))
+            .toList();
+    }
+
+    /**
+     * @throws IllegalArgumentException si la comanda no existe en el local
+     * @throws IllegalStateException si ya estaba lista
+     */
+    public ComandaCocinaResponse marcarLista(LocalId localId, ComandaCocinaId comandaId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(comandaId, "El comandaId es obligatorio");
+
+        ComandaCocina comanda = comandaCocinaRepository.buscarPorId(comandaId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("La comanda no existe"));
+
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        comanda.marcarLista(ahora);
+        return ComandaCocinaResponse.fromDomain(comandaCocinaRepository.guardar(comanda), ahora);
+    }
+
+    /**
+     * HU-136: Vuelve a preparación la última comanda marcada lista en el local,
+     * la haya marcado esta pantalla u otra.
+     *
+     * @throws IllegalStateException si no hay ninguna lista o se marcó hace demasiado
+     */
+    public ComandaCocinaResponse recuperarUltima(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        ComandaCocina comanda = comandaCocinaRepository.buscarUltimaLista(localId)
+            .orElseThrow(() -> new IllegalStateException("No hay comandas listas para recuperar"));
+
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        comanda.recuperar(ahora);
+        return ComandaCocinaResponse.fromDomain(comandaCocinaRepository.guardar(comanda), ahora);
+    }
+}
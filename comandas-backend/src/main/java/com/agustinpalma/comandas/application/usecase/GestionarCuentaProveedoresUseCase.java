@@ -0,0 +1,210 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.CuentaProveedorResponse;
+import com.agustinpalma.comandas.application.dto.FacturaProveedorResponse;
+import com.agustinpalma.comandas.application.dto.PagoProveedorRequest;
+import com.agustinpalma.comandas.application.dto.ProveedorResponse;
+import com.agustinpalma.comandas.application.dto.SaldoProveedorResponse;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimiento;
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaProveedorId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+import com.agustinpalma.comandas.domain.model.FacturaProveedor;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.model.PagoProveedor;
+import com.agustinpalma.comandas.domain.model.Proveedor;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.repository.FacturaProveedorRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.ProveedorRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.Comparator;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Predicate;
+import java.util.stream.Collectors;
+
+/**
+ * HU-131: Caso de uso de la cuenta corriente de proveedores.
+ *
+ * Las facturas nacen al registrar una compra a cuenta corriente
+ * (GestionarGastosUseCase); acá se consultan los saldos, se asientan los
+ * pagos y se arman los recordatorios de vencimientos.
+ *
+ * Un pago en efectivo con la plata de la caja registra el egreso de caja
+ * (asociado al turno abierto, si lo hay), igual que un gasto pagado con la caja.
+ */
+@Transactional
+public class GestionarCuentaProveedoresUseCase {
+
+    /** Ventana máxima de anticipación para los recordatorios */
+    private static final int DIAS_AVISO_MAXIMO = 60;
+
+    private final FacturaProveedorRepository facturaProveedorRepository;
+    private final ProveedorRepository proveedorRepository;
+    private final MovimientoCajaRepository movimientoCajaRepository;
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final Clock clock;
+
+    public GestionarCuentaProveedoresUseCase(
+            FacturaProveedorRepository facturaProveedorRepository,
+            ProveedorRepository proveedorRepository,
+            MovimientoCajaRepository movimientoCajaRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            Clock clock
+    ) {
+        this.facturaProveedorRepository = Objects.requireNonNull(facturaProveedorRepository, "El facturaProveedorRepository es obligatorio");
+        this.proveedorRepository = Objects.requireNonNull(proveedorRepository, "El proveedorRepository es obligatorio");
+        this.movimientoCajaRepository = Objects.requireNonNull(movimientoCajaRepository, "El movimientoCajaRepository es obligatorio");
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository, "El turnoCajaRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * Saldo de cada proveedor del local: primero los que tienen deuda vencida,
+     * después de mayor a menor deuda.
+     */
+    @Transactional(readOnly = true)
+    public List<SaldoProveedorResponse> listarSaldos(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        LocalDate hoy = LocalDate.now(clock);
+        Map<ProveedorId, List<FacturaProveedor>> impagasPorProveedor = facturaProveedorRepository
+            .buscarImpagas(localId).stream()
+            .collect(Collectors.groupingBy(FacturaProveedor::getProveedorId));
+
+        return proveedorRepository.buscarPorLocal(localId).stream()
+            .map(p -> saldo(p, impagasPorProveedor.getOrDefault(p.getId(), List.of()), hoy))
+            .sorted(Comparator.comparing(SaldoProveedorResponse::saldoVencido)
+                .thenComparing(SaldoProveedorResponse::saldo)
+                .reversed()
+                .thenComparing(s -> s.proveedor().nombre()))
+            .toList();
+    }
+
+    /**
+     * @throws IllegalArgumentException si el proveedor no existe en el local
+     */
+    @Transactional(readOnly = true)
+    public CuentaProveedorResponse consultarCuenta(LocalId localId, ProveedorId proveedorId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(proveedorId, "El proveedorId es obligatorio");
+
+        Proveedor proveedor = buscarProveedor(localId, proveedorId);
+        LocalDate hoy = LocalDate.now(clock);
+        List<FacturaProveedor> facturas = facturaProveedorRepository.buscarPorProveedor(proveedorId, localId);
+
+        return new CuentaProveedorResponse(
+            saldo(proveedor, facturas.stream().filter(f -> !f.estaPaga()).toList(), hoy),
+            facturas.stream()
+                .map(f -> FacturaProveedorResponse.fromDomain(f, proveedor.getNombre(), hoy))
+                .toList()
+        );
+    }
+
+    /**
+     * Asienta un pago (total o parcial) contra una factura.
+     *
+     * @throws IllegalArgumentException si la factura no existe en el local o el pago supera el saldo
+     * @throws IllegalStateException si la factura ya está paga o se pide pagar con la caja sin ser efectivo
+     */
+    public FacturaProveedorResponse registrarPago(LocalId localId, FacturaProveedorId facturaId,
+                                                  PagoProveedorRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(facturaId, "El facturaId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        if (request.pagadoConCaja() && request.medioPago() != MedioPago.EFECTIVO) {
+            throw new IllegalStateException("Solo un pago en efectivo puede salir de la caja");
+        }
+
+        FacturaProveedor factura = facturaProveedorRepository.buscarPorId(facturaId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("La factura no existe en este local"));
+        Proveedor proveedor = buscarProveedor(localId, factura.getProveedorId());
+        LocalDateTime ahora = LocalDateTime.now(clock);
+
+        // Se valida contra el saldo antes de mover la caja
+        factura.validarPago(request.monto());
+
+        MovimientoCajaId egresoId = null;
+        if (request.pagadoConCaja()) {
+            MovimientoCaja egreso = movimientoCajaRepository.guardar(new MovimientoCaja(
+                MovimientoCajaId.generate(),
+                localId,
+                request.monto(),
+                descripcionEgreso(proveedor, factura),
+                ahora,
+                TipoMovimiento.EGRESO,
+                turnoCajaRepository.buscarAbierto(localId).map(TurnoCaja::getId).orElse(null)
+            ));
+            egresoId = egreso.getId();
+        }
+        factura.registrarPago(new PagoProveedor(ahora, request.monto(), request.medioPago(), egresoId));
+
+        FacturaProveedor guardada = facturaProveedorRepository.guardar(factura);
+        return FacturaProveedorResponse.fromDomain(guardada, proveedor.getNombre(), ahora.toLocalDate());
+    }
+
+    /**
+     * Facturas impagas vencidas o que vencen dentro de los próximos días,
+     * de la que vence primero a la última. Es la base de los recordatorios.
+     *
+     * @param dias anticipación del aviso (0 = solo las vencidas y las que vencen hoy)
+     * @throws IllegalArgumentException si la anticipación está fuera de rango
+     */
+    @Transactional(readOnly = true)
+    public List<FacturaProveedorResponse> consultarVencimientos(LocalId localId, int dias) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        if (dias < 0 || dias > DIAS_AVISO_MAXIMO) {
+            throw new IllegalArgumentException(
+                "La anticipación del aviso debe estar entre 0 y " + DIAS_AVISO_MAXIMO + " días"
+            );
+        }
+
+        LocalDate hoy = LocalDate.now(clock);
+        Map<ProveedorId, String> nombres = proveedorRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Proveedor::getId, Proveedor::getNombre));
+
+        return facturaProveedorRepository.buscarImpagas(localId).stream()
+            .filter(f -> f.diasParaVencer(hoy) <= dias)
+            .map(f -> FacturaProveedorResponse.fromDomain(f, nombres.get(f.getProveedorId()), hoy))
+            .toList();
+    }
+
+    private SaldoProveedorResponse saldo(Proveedor proveedor, List<FacturaProveedor> impagas, LocalDate hoy) {
+        BigDecimal saldo = sumarSaldos(impagas, f -> true);
+        BigDecimal vencido = sumarSaldos(impagas, f -> f.estaVencida(hoy));
+        LocalDate proximoVencimiento = impagas.stream()
+            .map(FacturaProveedor::getFechaVencimiento)
+            .min(Comparator.naturalOrder())
+            .orElse(null);
+        return new SaldoProveedorResponse(ProveedorResponse.fromDomain(proveedor), impagas.size(), saldo, vencido,
+            proximoVencimiento);
+    }
+
+    private static BigDecimal sumarSaldos(List<FacturaProveedor> facturas, Predicate<FacturaProveedor> filtro) {
+        return facturas.stream()
+            .filter(filtro)
+            .map(FacturaProveedor::getSaldo)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    private static String descripcionEgreso(Proveedor proveedor, FacturaProveedor factura) {
+        String descripcion = "Pago a proveedor - " + proveedor.getNombre();
+        return factura.getNumero() != null ? descripcion + " - Factura " + factura.getNumero() : descripcion;
+    }
+
+    private Proveedor buscarProveedor(LocalId localId, ProveedorId proveedorId) {
+        return proveedorRepository.buscarPorId(proveedorId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("El proveedor no existe en este local"));
+    }
+}
@@ -0,0 +1,181 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.DevolucionRequest;
+import com.agustinpalma.comandas.application.dto.DevolucionesPedidoResponse;
+import com.agustinpalma.comandas.application.dto.DevolucionesPedidoResponse.DevolucionResumen;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * Caso de uso para devoluciones y reintegros sobre pedidos ya cerrados.
+ *
+ * HU-121: Escenario típico: después de cobrar aparece un plato en mal estado
+ * y se le reintegra el importe al cliente. El pedido no se modifica (para eso
+ * está la corrección); se registra un egreso asociado al pedido.
+ *
+ * Reglas:
+ * - Solo sobre pedidos CERRADOS del local.
+ * - Lo autoriza un encargado activo con su PIN.
+ * - El total devuelto no puede superar lo cobrado (pagos comerciales, sin A_CUENTA).
+ * - El egreso se fecha al momento de la devolución y queda en el turno abierto,
+ *   así impacta en el cierre del día en que salió el efectivo.
+ */
+@Transactional
+public class GestionarDevolucionesUseCase {
+
+    private final PedidoRepository pedidoRepository;
+    private final MozoRepository mozoRepository;
+    private final MovimientoCajaRepository movimientoCajaRepository;
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final Clock clock;
+
+    public GestionarDevolucionesUseCase(PedidoRepository pedidoRepository,
+                                        MozoRepository mozoRepository,
+                                        MovimientoCajaRepository movimientoCajaRepository,
+                                        TurnoCajaRepository turnoCajaRepository,
+                                        Clock clock) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.movimientoCajaRepository = Objects.requireNonNull(movimientoCajaRepository,
+            "El movimientoCajaRepository es obligatorio");
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository,
+            "El turnoCajaRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @return lo cobrado, lo devuelto y el detalle de devoluciones del pedido
+     * @throws IllegalStateException si el pedido no existe o no está cerrado
+     */
+    @Transactional(readOnly = true)
+    public DevolucionesPedidoResponse listar(LocalId localId, PedidoId pedidoId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(pedidoId, "El pedidoId es obligatorio");
+
+        Pedido pedido = buscarPedidoCerrado(localId, pedidoId);
+        return armarRespuesta(pedido, movimientoCajaRepository.buscarDevolucionesPorPedido(localId, pedidoId));
+    }
+
+    /**
+     * Registra el reintegro como egreso de caja asociado al pedido.
+     *
+     * @throws IllegalStateException si el pedido no está cerrado o quien autoriza no es encargado
+     * @throws IllegalArgumentException si el PIN no coincide o el monto supera lo disponible
+     */
+    public DevolucionesPedidoResponse registrar(LocalId localId, PedidoId pedidoId, DevolucionRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(pedidoId, "El pedidoId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+        Objects.requireNonNull(request.monto(), "El monto es obligatorio");
+
+        String motivo = request.motivo() != null ? request.motivo().trim() : "";
+        if (motivo.isEmpty()) {
+            throw new IllegalArgumentException("El motivo de la devolución es obligatorio");
+        }
+
+        Pedido pedido = buscarPedidoCerrado(localId, pedidoId);
+
+        Mozo encargado = mozoRepository.buscarPorId(MozoId.from(request.encargadoId()), localId)
+            .orElseThrow(() -> new IllegalArgumentException("El encargado no existe en este local"));
+        encargado.validarAutorizacion(request.pin());
+
+        List<MovimientoCaja> previas = movimientoCajaRepository.buscarDevolucionesPorPedido(localId, pedidoId);
+        BigDecimal disponible = totalCobrado(pedido).subtract(totalDevuelto(previas));
+        if (request.monto().compareTo(disponible) > 0) {
+            throw new IllegalArgumentException(String.format(
+                "La devolución supera lo cobrado en el pedido #%d. Disponible para devolver: $%s",
+                pedido.getNumero(), disponible
+            ));
+        }
+
+        MovimientoCaja devolucion = MovimientoCaja.devolucion(
+            localId,
+            pedidoId,
+            request.monto(),
+            "Devolución pedido #" + pedido.getNumero() + ": " + motivo,
+            LocalDateTime.now(clock),
+            turnoCajaRepository.buscarAbierto(localId).map(TurnoCaja::getId).orElse(null),
+            encargado.getId()
+        );
+        movimientoCajaRepository.guardar(devolucion);
+
+        return armarRespuesta(pedido, movimientoCajaRepository.buscarDevolucionesPorPedido(localId, pedidoId));
+    }
+
+    private Pedido buscarPedidoCerrado(LocalId localId, PedidoId pedidoId) {
+        Pedido pedido = pedidoRepository.buscarPorId(pedidoId)
+            .orElseThrow(() -> new IllegalStateException("El pedido no existe"));
+        if (!pedido.getLocalId().equals(localId)) {
+            throw new IllegalArgumentException("El pedido no pertenece a este local");
+        }
+        if (pedido.getEstado() != EstadoPedido.CERRADO) {
+            throw new IllegalStateException("Solo se pueden registrar devoluciones sobre pedidos cerrados");
+        }
+        return pedido;
+    }
+
+    private DevolucionesPedidoResponse armarRespuesta(Pedido pedido, List<MovimientoCaja> devoluciones) {
+        Map<MozoId, Mozo> encargados = mozoRepository.buscarPorLocal(pedido.getLocalId()).stream()
+            .collect(Collectors.toMap(Mozo::getId, Function.identity()));
+
+        BigDecimal cobrado = totalCobrado(pedido);
+        BigDecimal devuelto = totalDevuelto(devoluciones);
+
+        List<DevolucionResumen> detalle = devoluciones.stream()
+            .map(d -> new DevolucionResumen(
+                d.getId().getValue().toString(),
+                d.getMonto(),
+                d.getDescripcion(),
+                d.getFecha(),
+                d.getNumeroComprobante(),
+                encargados.containsKey(d.getAutorizadoPorId())
+                    ? encargados.get(d.getAutorizadoPorId()).getNombre()
+                    : null
+            ))
+            .toList();
+
+        return new DevolucionesPedidoResponse(
+            pedido.getId().getValue().toString(),
+            pedido.getNumero(),
+            cobrado,
+            devuelto,
+            cobrado.subtract(devuelto).max(BigDecimal.ZERO),
+            detalle
+        );
+    }
+
+    private BigDecimal totalCobrado(Pedido pedido) {
+        return pedido.getPagos().stream()
+            .filter(p -> p.getMedio() != MedioPago.A_CUENTA)
+            .map(Pago::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    private BigDecimal totalDevuelto(List<MovimientoCaja> devoluciones) {
+        return devoluciones.stream()
+            .map(MovimientoCaja::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+}
@@ -0,0 +1,58 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.EstadoEnergiaEvento;
+import com.agustinpalma.comandas.application.ports.output.ApagadoAplicacionPort;
+import com.agustinpalma.comandas.application.ports.output.NotificadorTiempoRealPort;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso de los cortes de luz en la terminal que corre el servidor.
+ *
+ * HU-155: La PC está enchufada a una UPS. La app de escritorio lee del SO si
+ * hay red eléctrica y cuánta batería queda; ante un corte avisa a todas las
+ * terminales y, antes de que se agote la batería, pide el apagado ordenado
+ * del backend para que la base no quede a mitad de una escritura.
+ */
+public class GestionarEnergiaUseCase {
+
+    private final NotificadorTiempoRealPort notificador;
+    private final ApagadoAplicacionPort apagadoAplicacion;
+
+    public GestionarEnergiaUseCase(NotificadorTiempoRealPort notificador,
+                                   ApagadoAplicacionPort apagadoAplicacion) {
+        this.notificador = Objects.requireNonNull(notificador, "El notificador es obligatorio");
+        this.apagadoAplicacion = Objects.requireNonNull(apagadoAplicacion, "El apagadoAplicacion es obligatorio");
+    }
+
+    /**
+     * Difunde el estado de la energía a todas las terminales del local.
+     *
+     * @throws IllegalArgumentException si la batería no es un porcentaje o la cuenta regresiva es negativa
+     */
+    public void notificar(LocalId localId, EstadoEnergiaEvento evento) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(evento, "El estado de la energía es obligatorio");
+        Objects.requireNonNull(evento.estado(), "El estado de la energía es obligatorio");
+        if (evento.bateria() != null && (evento.bateria() < 0 || evento.bateria() > 100)) {
+            throw new IllegalArgumentException("La batería tiene que ser un porcentaje entre 0 y 100");
+        }
+        if (evento.segundosParaApagar() != null && evento.segundosParaApagar() < 0) {
+            throw new IllegalArgumentException("Los segundos para el apagado no pueden ser negativos");
+        }
+
+        notificador.publicar(localId, EstadoEnergiaEvento.NOMBRE, evento);
+    }
+
+    /**
+     * Avisa a las terminales que el servidor se apaga ya y programa el apagado ordenado.
+     */
+    public void apagar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        notificador.publicar(localId, EstadoEnergiaEvento.NOMBRE,
+            new EstadoEnergiaEvento(EstadoEnergiaEvento.Estado.APAGANDO, null, 0));
+        apagadoAplicacion.apagar();
+    }
+}
@@ -0,0 +1,161 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.CorreccionFichajeRequest;
+import com.agustinpalma.comandas.application.dto.FichajeResponse;
+import com.agustinpalma.comandas.application.dto.MarcaFichajeResponse;
+import com.agustinpalma.comandas.application.dto.MarcarFichajeRequest;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioFichaje;
+import com.agustinpalma.comandas.domain.model.DomainIds.FichajeId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Fichaje;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.repository.FichajeRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.Objects;
+import java.util.Optional;
+
+/**
+ * Caso de uso de la terminal de fichaje y sus correcciones.
+ *
+ * HU-124: Reemplaza el cuaderno de entradas y salidas.
+ *
+ * Reglas:
+ * - Cada marca alterna: si el empleado no tiene fichaje abierto es una
+ *   ENTRADA; si lo tiene, es la SALIDA de ese fichaje.
+ * - Un fichaje abierto hace más de la duración máxima se toma como salida
+ *   olvidada: la marca abre uno nuevo y el viejo queda para corregir.
+ * - Correcciones y altas manuales solo con el PIN del dueño.
+ */
+@Transactional
+public class GestionarFichajesUseCase {
+
+    private final FichajeRepository fichajeRepository;
+    private final MozoRepository mozoRepository;
+    private final Clock clock;
+
+    public GestionarFichajesUseCase(FichajeRepository fichajeRepository,
+                                    MozoRepository mozoRepository,
+                                    Clock clock) {
+        this.fichajeRepository = Objects.requireNonNull(fichajeRepository, "El fichajeRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * Registra la marca del empleado identificado por tarjeta o por PIN.
+     *
+     * @throws IllegalArgumentException si la tarjeta no está asignada o el PIN no coincide
+     * @throws IllegalStateException si el empleado está inactivo o no tiene PIN
+     */
+    public MarcaFichajeResponse marcar(LocalId localId, MarcarFichajeRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        Mozo empleado;
+        MedioFichaje medio;
+
+        if (request.tarjeta() != null && !request.tarjeta().isBlank()) {
+            empleado = mozoRepository.buscarPorTarjeta(localId, request.tarjeta().trim())
+                .orElseThrow(() -> new IllegalArgumentException("La tarjeta no está asignada a ningún empleado"));
+            if (!empleado.isActivo()) {
+                throw new IllegalStateException(empleado.getNombre() + " está inactivo y no puede fichar");
+            }
+            medio = MedioFichaje.TARJETA;
+        } else {
+            if (request.mozoId() == null || request.mozoId().isBlank()) {
+                throw new IllegalArgumentException("Indicá quién ficha o pasá la tarjeta");
+            }
+            empleado = mozoRepository.buscarPorId(MozoId.from(request.mozoId()), localId)
+                .orElseThrow(() -> new IllegalArgumentException("El empleado no existe en este local"));
+            empleado.validarPinFichaje(request.pin());
+            medio = MedioFichaje.PIN;
+        }
+
+        Optional<Fichaje> abierto = fichajeRepository.buscarAbierto(localId, empleado.getId());
+
+        if (abierto.isPresent() && !abierto.get().estaVencido(ahora)) {
+            Fichaje fichaje = abierto.get();
+            fichaje.registrarSalida(ahora);
+            fichajeRepository.guardar(fichaje);
+            return new MarcaFichajeResponse("SALIDA", empleado.getNombre(), ahora,
+                fichaje.duracion().toMinutes(), false);
+        }
+
+        fichajeRepository.guardar(new Fichaje(FichajeId.generate(), localId, empleado.getId(), medio, ahora));
+        return new MarcaFichajeResponse("ENTRADA", empleado.getNombre(), ahora, 0, abierto.isPresent());
+    }
+
+    /**
+     * Corrige las marcas de un fichaje con autorización del dueño.
+     *
+     * @throws IllegalArgumentException si el fichaje no existe, el PIN no coincide o las horas son inválidas
+     * @throws IllegalStateException si quien autoriza no es el dueño
+     */
+    public FichajeResponse corregir(LocalId localId, FichajeId fichajeId, CorreccionFichajeRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(fichajeId, "El fichajeId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Fichaje fichaje = fichajeRepository.buscarPorId(fichajeId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("El fichaje no existe en este local"));
+        Mozo dueno = validarDueno(localId, request);
+
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        validarNoFuturo(request, ahora);
+        fichaje.corregir(request.entrada(), request.salida(), request.motivo(), dueno.getId(), ahora);
+
+        return armarRespuesta(localId, fichajeRepository.guardar(fichaje), dueno, ahora);
+    }
+
+    /**
+     * Carga un fichaje que no se marcó en la terminal, con autorización del dueño.
+     *
+     * @throws IllegalArgumentException si el empleado no existe, el PIN no coincide o las horas son inválidas
+     * @throws IllegalStateException si quien autoriza no es el dueño
+     */
+    public FichajeResponse registrarManual(LocalId localId, CorreccionFichajeRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        if (request.mozoId() == null || request.mozoId().isBlank()) {
+            throw new IllegalArgumentException("El empleado del fichaje es obligatorio");
+        }
+        Mozo empleado = mozoRepository.buscarPorId(MozoId.from(request.mozoId()), localId)
+            .orElseThrow(() -> new IllegalArgumentException("El empleado no existe en este local"));
+        Mozo dueno = validarDueno(localId, request);
+
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        validarNoFuturo(request, ahora);
+        Fichaje fichaje = Fichaje.manual(localId, empleado.getId(), request.entrada(), request.salida(),
+            request.motivo(), dueno.getId(), ahora);
+
+        return armarRespuesta(localId, fichajeRepository.guardar(fichaje), dueno, ahora);
+    }
+
+    private Mozo validarDueno(LocalId localId, CorreccionFichajeRequest request) {
+        Mozo dueno = mozoRepository.buscarPorId(MozoId.from(request.duenoId()), localId)
+            .orElseThrow(() -> new IllegalArgumentException("El dueño no existe en este local"));
+        dueno.validarAutorizacionDueno(request.pin());
+        return dueno;
+    }
+
+    private void validarNoFuturo(CorreccionFichajeRequest request, LocalDateTime ahora) {
+        Objects.requireNonNull(request.entrada(), "La hora de entrada es obligatoria");
+        if (request.entrada().isAfter(ahora) || (request.salida() != null && request.salida().isAfter(ahora))) {
+            throw new IllegalArgumentException("No se pueden cargar marcas en el futuro");
+        }
+    }
+
+    private FichajeResponse armarRespuesta(LocalId localId, Fichaje fichaje, Mozo dueno, LocalDateTime ahora) {
+        String nombre = mozoRepository.buscarPorId(fichaje.getMozoId(), localId)
+            .map(Mozo::getNombre)
+            .orElse(null);
+        return FichajeResponse.fromDomain(fichaje, nombre, dueno.getNombre(), ahora);
+    }
+}
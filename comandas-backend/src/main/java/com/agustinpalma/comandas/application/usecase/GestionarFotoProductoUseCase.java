@@ -0,0 +1,83 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.FotoProductoRequest;
+import com.agustinpalma.comandas.application.dto.FotoProductoResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.FotoProducto;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.Base64;
+import java.util.Objects;
+
+/**
+ * Caso de uso de las fotos de productos que muestra la carta digital.
+ *
+ * HU-127: Una foto por producto. Subir una nueva reemplaza la anterior y
+ * cambia su versión, así los celulares que tenían la vieja en caché la
+ * vuelven a pedir.
+ */
+@Transactional
+public class GestionarFotoProductoUseCase {
+
+    private final ProductoRepository productoRepository;
+    private final Clock clock;
+
+    public GestionarFotoProductoUseCase(ProductoRepository productoRepository, Clock clock) {
+        this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * Guarda o reemplaza la foto del producto.
+     *
+     * @throws IllegalArgumentException si el producto no existe en el local o el archivo no es una imagen válida
+     */
+    public FotoProductoResponse subir(LocalId localId, ProductoId productoId, FotoProductoRequest request) {
+        Objects.requireNonNull(request, "El request es obligatorio");
+        validarProducto(localId, productoId);
+
+        byte[] contenido;
+        try {
+            contenido = Base64.getDecoder().decode(request.contenidoBase64());
+        } catch (IllegalArgumentException e) {
+            throw new IllegalArgumentException("El archivo de la foto no es Base64 válido");
+        }
+        FotoProducto foto = new FotoProducto(request.contentType(), contenido, LocalDateTime.now(clock));
+
+        productoRepository.guardarFoto(productoId, localId, foto);
+        return new FotoProductoResponse(productoId.getValue(), foto.getActualizadaEn());
+    }
+
+    /**
+     * @throws IllegalArgumentException si el producto no existe en el local o no tiene foto
+     */
+    @Transactional(readOnly = true)
+    public FotoProducto obtener(LocalId localId, ProductoId productoId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(productoId, "El productoId es obligatorio");
+        return productoRepository.buscarFoto(productoId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("El producto no tiene foto"));
+    }
+
+    /**
+     * Quita la foto: la carta vuelve a mostrar el producto sin imagen.
+     *
+     * @throws IllegalArgumentException si el producto no existe en el local
+     */
+    public void eliminar(LocalId localId, ProductoId productoId) {
+        validarProducto(localId, productoId);
+        productoRepository.eliminarFoto(productoId, localId);
+    }
+
+    private void validarProducto(LocalId localId, ProductoId productoId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(productoId, "El productoId es obligatorio");
+        if (productoRepository.buscarPorIdYLocal(productoId, localId).isEmpty()) {
+            throw new IllegalArgumentException("El producto no existe en este local");
+        }
+    }
+}
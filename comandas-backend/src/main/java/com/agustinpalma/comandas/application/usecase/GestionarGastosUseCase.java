@@ -0,0 +1,268 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ComprobanteRequest;
+import com.agustinpalma.comandas.application.dto.GastoRequest;
+import com.agustinpalma.comandas.application.dto.GastoResponse;
+import com.agustinpalma.comandas.domain.model.ComprobanteAdjunto;
+import com.agustinpalma.comandas.domain.model.FacturaProveedor;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimiento;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoStock;
+import com.agustinpalma.comandas.domain.model.DomainIds.GastoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+import com.agustinpalma.comandas.domain.model.Gasto;
+import com.agustinpalma.comandas.domain.model.ItemCompra;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.model.MovimientoStock;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.model.Proveedor;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.repository.FacturaProveedorRepository;
+import com.agustinpalma.comandas.domain.repository.GastoRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoStockRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.domain.repository.ProveedorRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import com.agustinpalma.comandas.domain.service.GestorStockService;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.Base64;
+import java.util.List;
+import java.util.Objects;
+import java.util.Optional;
+
+/**
+ * HU-117: Caso de uso para registrar compras a proveedores y gastos del local.
+ *
+ * Al registrar un gasto, en la misma transacción:
+ * - Si es una compra de mercadería con ítems, cada producto recibe un
+ *   ingreso de stock (INGRESO_MERCADERIA), igual que un ajuste manual.
+ * - Si se pagó en efectivo con la caja, se registra el egreso de caja
+ *   (asociado al turno abierto, si lo hay) y queda vinculado al gasto.
+ * - Si viene el comprobante, se guarda el archivo.
+ * - HU-131: Si es a cuenta corriente, se genera la factura a pagar en la
+ *   cuenta del proveedor, con el vencimiento indicado o el de su plazo de pago.
+ *
+ * Un gasto registrado no se edita; el comprobante sí se puede adjuntar
+ * o reemplazar después (la factura del proveedor suele llegar más tarde).
+ */
+@Transactional
+public class GestionarGastosUseCase {
+
+    private final GastoRepository gastoRepository;
+    private final ProductoRepository productoRepository;
+    private final MovimientoStockRepository movimientoStockRepository;
+    private final MovimientoCajaRepository movimientoCajaRepository;
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final ProveedorRepository proveedorRepository;
+    private final FacturaProveedorRepository facturaProveedorRepository;
+    private final GestorStockService gestorStockService;
+    private final Clock clock;
+
+    public GestionarGastosUseCase(
+            GastoRepository gastoRepository,
+            ProductoRepository productoRepository,
+            MovimientoStockRepository movimientoStockRepository,
+            MovimientoCajaRepository movimientoCajaRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            ProveedorRepository proveedorRepository,
+            FacturaProveedorRepository facturaProveedorRepository,
+            GestorStockService gestorStockService,
+            Clock clock
+    ) {
+        this.gastoRepository = Objects.requireNonNull(gastoRepository, "El gastoRepository es obligatorio");
+        this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+        this.movimientoStockRepository = Objects.requireNonNull(movimientoStockRepository, "El movimientoStockRepository es obligatorio");
+        this.movimientoCajaRepository = Objects.requireNonNull(movimientoCajaRepository, "El movimientoCajaRepository es obligatorio");
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository, "El turnoCajaRepository es obligatorio");
+        this.proveedorRepository = Objects.requireNonNull(proveedorRepository, "El proveedorRepository es obligatorio");
+        this.facturaProveedorRepository = Objects.requireNonNull(facturaProveedorRepository, "El facturaProveedorRepository es obligatorio");
+        this.gestorStockService = Objects.requireNonNull(gestorStockService, "El gestorStockService es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si un producto o el proveedor no existen en el local o los datos son inválidos
+     * @throws IllegalStateException si se pide pagar con la caja un gasto que no es en efectivo,
+     *                               o comprar a cuenta corriente a un proveedor desactivado
+     */
+    public GastoResponse registrar(LocalId localId, GastoRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        LocalDate fecha = request.fecha() != null ? request.fecha() : ahora.toLocalDate();
+        if (fecha.isAfter(ahora.toLocalDate())) {
+            throw new IllegalArgumentException("La fecha del gasto no puede ser futura");
+        }
+        if (request.pagadoConCaja() && request.medioPago() != MedioPago.EFECTIVO) {
+            throw new IllegalStateException("Solo un gasto en efectivo puede pagarse con la caja");
+        }
+        Proveedor proveedor = resolverProveedorCuentaCorriente(localId, request);
+
+        // 1. Resolver los productos comprados (multi-tenancy)
+        List<Producto> productos = new ArrayList<>();
+        List<ItemCompra> items = new ArrayList<>();
+        for (GastoRequest.ItemCompraRequest itemRequest : Optional.ofNullable(request.items()).orElse(List.of())) {
+            ProductoId productoId = new ProductoId(itemRequest.productoId());
+            Producto producto = productoRepository.buscarPorIdYLocal(productoId, localId)
+                .orElseThrow(() -> new IllegalArgumentException(
+                    String.format("No existe un producto con ID %s en este local", productoId.getValue())
+                ));
+            productos.add(producto);
+            items.add(new ItemCompra(productoId, producto.getNombre(), itemRequest.cantidad()));
+        }
+
+        String nombreProveedor = proveedor != null ? proveedor.getNombre() : request.proveedor();
+        Gasto gasto = new Gasto(GastoId.generate(), localId, fecha, request.categoria(), nombreProveedor,
+            request.descripcion(), request.monto(), request.medioPago(), items, ahora, null, null);
+
+        // 2. Ingreso de stock de la mercadería comprada
+        for (int i = 0; i < items.size(); i++) {
+            Producto producto = productos.get(i);
+            MovimientoStock movimiento = gestorStockService.ajustarStock(
+                producto,
+                items.get(i).getCantidad(),
+                TipoMovimientoStock.INGRESO_MERCADERIA,
+                "Compra - " + gasto.resumen(),
+                ahora
+            );
+            productoRepository.guardar(producto);
+            movimientoStockRepository.guardar(movimiento);
+        }
+
+        // 3. Egreso de caja si el efectivo salió del cajón
+        if (request.pagadoConCaja()) {
+            MovimientoCaja egreso = movimientoCajaRepository.guardar(new MovimientoCaja(
+                MovimientoCajaId.generate(),
+                localId,
+                gasto.getMonto(),
+                gasto.resumen(),
+                ahora,
+                TipoMovimiento.EGRESO,
+                turnoCajaRepository.buscarAbierto(localId).map(TurnoCaja::getId).orElse(null)
+            ));
+            gasto.registrarPagoConCaja(egreso.getId());
+        }
+
+        // 4. Comprobante adjunto
+        ComprobanteAdjunto comprobante = null;
+        if (request.comprobante() != null) {
+            comprobante = decodificar(request.comprobante());
+            gasto.adjuntarComprobante(comprobante);
+        }
+
+        Gasto guardado = gastoRepository.guardar(gasto);
+        if (comprobante != null) {
+            gastoRepository.guardarComprobante(guardado.getId(), comprobante);
+        }
+
+        // 5. Factura a pagar en la cuenta corriente del proveedor
+        if (proveedor != null) {
+            GastoRequest.CuentaCorrienteRequest cuentaCorriente = request.cuentaCorriente();
+            LocalDate vencimiento = cuentaCorriente.fechaVencimiento() != null
+                ? cuentaCorriente.fechaVencimiento()
+                : proveedor.vencimientoPara(fecha);
+            facturaProveedorRepository.guardar(FacturaProveedor.desdeCompra(
+                guardado, proveedor.getId(), cuentaCorriente.numeroFactura(), vencimiento));
+        }
+
+        return GastoResponse.fromDomain(guardado);
+    }
+
+    /**
+     * HU-131: Proveedor de una compra a cuenta corriente; null si el gasto se pagó en el momento.
+     */
+    private Proveedor resolverProveedorCuentaCorriente(LocalId localId, GastoRequest request) {
+        if (request.medioPago() != MedioPago.CUENTA_CORRIENTE) {
+            if (request.cuentaCorriente() != null) {
+                throw new IllegalArgumentException("Solo una compra a cuenta corriente genera deuda con el proveedor");
+            }
+            return null;
+        }
+        if (request.cuentaCorriente() == null) {
+            throw new IllegalArgumentException("Indicá el proveedor de la compra a cuenta corriente");
+        }
+
+        ProveedorId proveedorId = new ProveedorId(request.cuentaCorriente().proveedorId());
+        Proveedor proveedor = proveedorRepository.buscarPorId(proveedorId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("El proveedor no existe en este local"));
+        if (!proveedor.isActivo()) {
+            throw new IllegalStateException("El proveedor " + proveedor.getNombre() + " está desactivado");
+        }
+        return proveedor;
+    }
+
+    /**
+     * Adjunta o reemplaza el comprobante de un gasto ya registrado.
+     *
+     * @throws IllegalArgumentException si el gasto no existe en el local o el archivo no es válido
+     */
+    public GastoResponse adjuntarComprobante(LocalId localId, GastoId gastoId, ComprobanteRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(gastoId, "El gastoId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Gasto gasto = buscarGasto(localId, gastoId);
+        ComprobanteAdjunto comprobante = decodificar(request);
+        gasto.adjuntarComprobante(comprobante);
+        Gasto guardado = gastoRepository.guardar(gasto);
+        gastoRepository.guardarComprobante(guardado.getId(), comprobante);
+        return GastoResponse.fromDomain(guardado);
+    }
+
+    /**
+     * @return gastos con fecha de comprobante en el rango, del más reciente al más antiguo
+     */
+    @Transactional(readOnly = true)
+    public List<GastoResponse> listar(LocalId localId, LocalDate desde, LocalDate hasta) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(desde, "La fecha 'desde' es obligatoria");
+        Objects.requireNonNull(hasta, "La fecha 'hasta' es obligatoria");
+        if (desde.isAfter(hasta)) {
+            throw new IllegalArgumentException(
+                "La fecha 'desde' (%s) no puede ser posterior a 'hasta' (%s)".formatted(desde, hasta)
+            );
+        }
+
+        return gastoRepository.buscarPorPeriodo(localId, desde, hasta).stream()
+            .map(GastoResponse::fromDomain)
+            .toList();
+    }
+
+    /**
+     * @throws IllegalArgumentException si el gasto no existe en el local o no tiene comprobante
+     */
+    @Transactional(readOnly = true)
+    public ComprobanteAdjunto obtenerComprobante(LocalId localId, GastoId gastoId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(gastoId, "El gastoId es obligatorio");
+
+        buscarGasto(localId, gastoId);
+        return gastoRepository.buscarComprobante(gastoId)
+            .orElseThrow(() -> new IllegalArgumentException("El gasto no tiene comprobante adjunto"));
+    }
+
+    private Gasto buscarGasto(LocalId localId, GastoId gastoId) {
+        return gastoRepository.buscarPorId(gastoId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("El gasto no existe en este local"));
+    }
+
+    private ComprobanteAdjunto decodificar(ComprobanteRequest request) {
+        byte[] contenido;
+        try {
+            contenido = Base64.getDecoder().decode(request.contenidoBase64());
+        } catch (IllegalArgumentException e) {
+            throw new IllegalArgumentException("El archivo del comprobante no es Base64 válido");
+        }
+        return new ComprobanteAdjunto(request.nombreArchivo(), request.contentType(), contenido);
+    }
+}
@@ -0,0 +1,120 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ActualizacionInsumoResponse;
+import com.agustinpalma.comandas.application.dto.InsumoRequest;
+import com.agustinpalma.comandas.application.dto.InsumoResponse;
+import com.agustinpalma.comandas.application.dto.RentabilidadProductoResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Insumo;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.model.Receta;
+import com.agustinpalma.comandas.domain.model.RentabilidadProducto;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.domain.repository.RecetaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.ArrayList;
+import java.util.HashSet;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Set;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * HU-118: Caso de uso para el ABM de insumos del local.
+ *
+ * Al cambiar el costo de un insumo se recalcula el costo de las recetas que
+ * lo usan y se informan los productos que pasaron a venderse a pérdida, para
+ * que el encargado actualice el precio antes de seguir vendiendo.
+ */
+@Transactional
+public class GestionarInsumosUseCase {
+
+    private final InsumoRepository insumoRepository;
+    private final RecetaRepository recetaRepository;
+    private final ProductoRepository productoRepository;
+
+    public GestionarInsumosUseCase(
+            InsumoRepository insumoRepository,
+            RecetaRepository recetaRepository,
+            ProductoRepository productoRepository
+    ) {
+        this.insumoRepository = Objects.requireNonNull(insumoRepository, "El insumoRepository es obligatorio");
+        this.recetaRepository = Objects.requireNonNull(recetaRepository, "El recetaRepository es obligatorio");
+        this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+    }
+
+    /**
+     * @return insumos del local ordenados por nombre
+     */
+    @Transactional(readOnly = true)
+    public List<InsumoResponse> listar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        return insumoRepository.buscarPorLocal(localId).stream()
+            .map(InsumoResponse::fromDomain)
+            .toList();
+    }
+
+    public InsumoResponse crear(LocalId localId, InsumoRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Insumo insumo = new Insumo(InsumoId.generate(), localId, request.nombre(), request.unidad(),
+            request.costoUnitario());
+        return InsumoResponse.fromDomain(insumoRepository.guardar(insumo));
+    }
+
+    /**
+     * @throws IllegalArgumentException si el insumo no existe en el local
+     */
+    public ActualizacionInsumoResponse actualizar(LocalId localId, InsumoId insumoId, InsumoRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(insumoId, "El insumoId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Map<InsumoId, Insumo> insumos = insumoRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Insumo::getId, Function.identity()));
+        Insumo insumo = insumos.get(insumoId);
+        if (insumo == null) {
+            throw new IllegalArgumentException("El insumo no existe en este local");
+        }
+
+        List<Receta> afectadas = recetaRepository.buscarPorLocal(localId).stream()
+            .filter(r -> r.usaInsumo(insumoId))
+            .toList();
+        Map<ProductoId, Producto> productos = productoRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Producto::getId, Function.identity()));
+
+        // Foto antes del cambio: el insumo del mapa es el mismo objeto que se actualiza
+        Set<ProductoId> yaAPerdida = new HashSet<>();
+        for (Receta receta : afectadas) {
+            Producto producto = productos.get(receta.getProductoId());
+            if (producto != null && new RentabilidadProducto(producto, receta.calcularCosto(insumos)).estaAPerdida()) {
+                yaAPerdida.add(producto.getId());
+            }
+        }
+
+        insumo.actualizar(request.nombre(), request.unidad(), request.costoUnitario());
+        Insumo guardado = insumoRepository.guardar(insumo);
+
+        List<RentabilidadProductoResponse> nuevosAPerdida = new ArrayList<>();
+        for (Receta receta : afectadas) {
+            Producto producto = productos.get(receta.getProductoId());
+            if (producto == null || yaAPerdida.contains(producto.getId())) {
+                continue;
+            }
+            RentabilidadProducto rentabilidad = new RentabilidadProducto(producto, receta.calcularCosto(insumos));
+            if (rentabilidad.estaAPerdida()) {
+                nuevosAPerdida.add(RentabilidadProductoResponse.fromDomain(rentabilidad));
+            }
+        }
+
+        return new ActualizacionInsumoResponse(InsumoResponse.fromDomain(guardado), nuevosAPerdida);
+    }
+}
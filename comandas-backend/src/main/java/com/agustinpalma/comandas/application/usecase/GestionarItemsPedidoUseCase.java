@@ -1,8 +1,14 @@
 package com.agustinpalma.comandas.application.usecase;
 
 import com.agustinpalma.comandas.application.dto.AgregarProductoResponse;
+import com.agustinpalma.comandas.application.dto.CambiarObservacionItemRequest;
+import com.agustinpalma.comandas.application.dto.CambiarTiempoServicioRequest;
 import com.agustinpalma.comandas.application.dto.EliminarItemPedidoRequest;
 import com.agustinpalma.comandas.application.dto.ModificarCantidadItemRequest;
+import com.agustinpalma.comandas.domain.exception.PedidoModificadoException;
+import com.agustinpalma.comandas.domain.exception.PermisoDenegadoException;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainIds.ItemPedidoId;
 import com.agustinpalma.comandas.domain.model.Pedido;
 import com.agustinpalma.comandas.domain.model.Promocion;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
@@ -15,6 +21,7 @@ import java.time.Clock;
 import java.time.LocalDateTime;
 import java.util.List;
 import java.util.Objects;
+import java.util.function.Consumer;
 import org.springframework.transaction.annotation.Transactional;
 
 /**
@@ -22,6 +29,9 @@ import org.springframework.transaction.annotation.Transactional;
  * 
  * HU-20: Eliminar producto de un pedido abierto.
  * HU-21: Modificar cantidad de un producto en pedido abierto.
+ * HU-110: Cambiar el paso de servicio de un ítem (no recalcula promociones).
+ * HU-120: Cambiar la observación libre de un ítem (no recalcula promociones).
+ * HU-169: Anular lo que ya salió a cocina exige el permiso ANULAR_ITEMS.
  * 
  * Regla fundamental:
  * Cualquier cambio en los ítems dispara un recálculo total del pedido, incluyendo:
@@ -48,17 +58,20 @@ public class GestionarItemsPedidoUseCase {
     private final PedidoRepository pedidoRepository;
     private final PromocionRepository promocionRepository;
     private final MotorReglasService motorReglasService;
+    private final GestionarPermisosUseCase gestionarPermisosUseCase;
     private final Clock clock;
 
     public GestionarItemsPedidoUseCase(
             PedidoRepository pedidoRepository,
             PromocionRepository promocionRepository,
             MotorReglasService motorReglasService,
+            GestionarPermisosUseCase gestionarPermisosUseCase,
             Clock clock
     ) {
         this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
         this.promocionRepository = Objects.requireNonNull(promocionRepository, "El promocionRepository es obligatorio");
         this.motorReglasService = Objects.requireNonNull(motorReglasService, "El motorReglasService es obligatorio");
+        this.gestionarPermisosUseCase = Objects.requireNonNull(gestionarPermisosUseCase, "El gestionarPermisosUseCase es obligatorio");
         this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
     }
 
@@ -77,6 +90,22 @@ public class GestionarItemsPedidoUseCase {
      * @throws IllegalStateException si el pedido no está ABIERTO
      */
     public AgregarProductoResponse modificarCantidad(ModificarCantidadItemRequest request) {
+        return modificarCantidad(request, Pedido.SIN_CONTROL_DE_VERSION);
+    }
+
+    /**
+     * HU-165: Modifica la cantidad solo si el pedido sigue en la versión que ve la terminal.
+     * Sin este control, dos terminales que suben la misma cantidad pisan una a la otra.
+     *
+     * @param versionEsperada versión del pedido en la terminal
+     * @throws PedidoModificadoException si otra terminal modificó el pedido
+     * @throws PermisoDenegadoException si baja la cantidad por debajo de lo enviado a cocina sin permiso
+     */
+    public AgregarProductoResponse modificarCantidad(ModificarCantidadItemRequest request, long versionEsperada) {
+        return modificarCantidad(request, pedido -> pedido.verificarVersion(versionEsperada));
+    }
+
+    private AgregarProductoResponse modificarCantidad(ModificarCantidadItemRequest request, Consumer<Pedido> controlVersion) {
         Objects.requireNonNull(request, "El request no puede ser null");
         
         log.info("Modificando cantidad: pedidoId={}, itemId={}, nuevaCantidad={}",
@@ -88,6 +117,7 @@ public class GestionarItemsPedidoUseCase {
                 "No se encontró el pedido con ID: " + request.pedidoId().getValue()
             ));
         
+        controlVersion.accept(pedido);
         log.debug("Pedido recuperado: items={}", pedido.getItems().size());
 
         // Guardar cantidad actual para detectar idempotencia
@@ -97,6 +127,8 @@ public class GestionarItemsPedidoUseCase {
             .map(item -> item.getCantidad())
             .orElse(-1); // -1 indica que no se encontró (el dominio lanzará la excepción)
 
+        exigirPermisoAnulacion(pedido, request.itemPedidoId(), request.cantidad());
+
         // 2. Ejecutar mutación en el dominio
         pedido.actualizarCantidadItem(request.itemPedidoId(), request.cantidad());
 
@@ -129,6 +161,21 @@ public class GestionarItemsPedidoUseCase {
      * @throws IllegalStateException si el pedido no está ABIERTO
      */
     public AgregarProductoResponse eliminarItem(EliminarItemPedidoRequest request) {
+        return eliminarItem(request, Pedido.SIN_CONTROL_DE_VERSION);
+    }
+
+    /**
+     * HU-165: Elimina el ítem solo si el pedido sigue en la versión que ve la terminal.
+     *
+     * @param versionEsperada versión del pedido en la terminal
+     * @throws PedidoModificadoException si otra terminal modificó el pedido
+     * @throws PermisoDenegadoException si el ítem ya se envió a cocina y no hay permiso para anularlo
+     */
+    public AgregarProductoResponse eliminarItem(EliminarItemPedidoRequest request, long versionEsperada) {
+        return eliminarItem(request, pedido -> pedido.verificarVersion(versionEsperada));
+    }
+
+    private AgregarProductoResponse eliminarItem(EliminarItemPedidoRequest request, Consumer<Pedido> controlVersion) {
         Objects.requireNonNull(request, "El request no puede ser null");
         
         log.info("Eliminando item: pedidoId={}, itemId={}",
@@ -140,10 +187,13 @@ public class GestionarItemsPedidoUseCase {
                 "No se encontró el pedido con ID: " + request.pedidoId().getValue()
             ));
         
+        controlVersion.accept(pedido);
         log.debug("Pedido recuperado antes de eliminar: items={}", pedido.getItems().size());
 
-        // 2. Ejecutar eliminación en el dominio
-        pedido.eliminarItem(request.itemPedidoId());
+        exigirPermisoAnulacion(pedido, request.itemPedidoId(), 0);
+
+        // 2. Ejecutar eliminación en el dominio (HU-111: registra la anulación si ya estaba en cocina)
+        pedido.quitarItem(request.itemPedidoId());
         
         log.debug("Item eliminado del dominio. Items restantes: {}", pedido.getItems().size());
 
@@ -158,6 +208,86 @@ public class GestionarItemsPedidoUseCase {
         return AgregarProductoResponse.fromDomain(pedidoActualizado);
     }
 
+    /**
+     * HU-110: Cambia el paso de servicio (entrada, principal, postre) de un ítem.
+     *
+     * No toca precios ni promociones: solo define con qué paso sale a cocina.
+     *
+     * @param request DTO con pedidoId, itemPedidoId y el paso (null = sin paso)
+     * @return el pedido actualizado
+     * @throws IllegalArgumentException si el pedido o ítem no existe
+     * @throws IllegalStateException si el pedido no está ABIERTO o el ítem ya se envió a cocina
+     */
+    public AgregarProductoResponse cambiarTiempoServicio(CambiarTiempoServicioRequest request) {
+        Objects.requireNonNull(request, "El request no puede ser null");
+
+        log.info("Cambiando tiempo de servicio: pedidoId={}, itemId={}, tiempo={}",
+                request.pedidoId().getValue(), request.itemPedidoId().getValue(), request.tiempoServicio());
+
+        Pedido pedido = pedidoRepository.buscarPorId(request.pedidoId())
+            .orElseThrow(() -> new IllegalArgumentException(
+                "No se encontró el pedido con ID: " + request.pedidoId().getValue()
+            ));
+
+        pedido.cambiarTiempoServicioItem(request.itemPedidoId(), request.tiempoServicio());
+
+        return AgregarProductoResponse.fromDomain(pedidoRepository.guardar(pedido));
+    }
+
+    /**
+     * HU-120: Cambia la observación libre de un ítem ("bien cocida", "sin sal").
+     *
+     * Las promociones no dependen de la observación, así que no se recalculan.
+     *
+     * @param request DTO con pedidoId, itemPedidoId y la observación (null = sin nota)
+     * @return el pedido actualizado
+     * @throws IllegalArgumentException si el pedido o ítem no existe, o la nota es demasiado larga
+     * @throws IllegalStateException si el pedido no está ABIERTO o el ítem ya se envió a cocina
+     */
+    public AgregarProductoResponse cambiarObservacion(CambiarObservacionItemRequest request) {
+        return cambiarObservacion(request, Pedido.SIN_CONTROL_DE_VERSION);
+    }
+
+    /**
+     * HU-165: Cambia la observación solo si el pedido sigue en la versión que ve la terminal.
+     *
+     * @param versionEsperada versión del pedido en la terminal
+     * @throws PedidoModificadoException si otra terminal modificó el pedido
+     */
+    public AgregarProductoResponse cambiarObservacion(CambiarObservacionItemRequest request, long versionEsperada) {
+        return cambiarObservacion(request, pedido -> pedido.verificarVersion(versionEsperada));
+    }
+
+    private AgregarProductoResponse cambiarObservacion(CambiarObservacionItemRequest request, Consumer<Pedido> controlVersion) {
+        Objects.requireNonNull(request, "El request no puede ser null");
+
+        log.info("Cambiando observación: pedidoId={}, itemId={}",
+                request.pedidoId().getValue(), request.itemPedidoId().getValue());
+
+        Pedido pedido = pedidoRepository.buscarPorId(request.pedidoId())
+            .orElseThrow(() -> new IllegalArgumentException(
+                "No se encontró el pedido con ID: " + request.pedidoId().getValue()
+            ));
+
+        controlVersion.accept(pedido);
+        pedido.cambiarObservacionItem(request.itemPedidoId(), request.observacion());
+
+        return AgregarProductoResponse.fromDomain(pedidoRepository.guardar(pedido));
+    }
+
+    /**
+     * HU-169: Quitar unidades que ya salieron a cocina es una anulación y
+     * exige el permiso ANULAR_ITEMS. Lo que todavía no se envió se corrige libremente.
+     */
+    private void exigirPermisoAnulacion(Pedido pedido, ItemPedidoId itemId, int nuevaCantidad) {
+        boolean anulaEnviado = pedido.getItems().stream()
+            .filter(item -> item.getId().equals(itemId))
+            .anyMatch(item -> nuevaCantidad < item.getCantidadEnviadaCocina());
+        if (anulaEnviado) {
+            gestionarPermisosUseCase.exigir(pedido.getLocalId(), Permiso.ANULAR_ITEMS);
+        }
+    }
+
     /**
      * Recalcula las promociones automáticas de todo el pedido.
      * 
@@ -0,0 +1,257 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.GenerarLinkPagoRequest;
+import com.agustinpalma.comandas.application.dto.LinkPagoResponse;
+import com.agustinpalma.comandas.application.ports.output.NotificadorTiempoRealPort;
+import com.agustinpalma.comandas.application.ports.output.PasarelaPagoPort;
+import com.agustinpalma.comandas.application.ports.output.PasarelaPagoPort.LinkGenerado;
+import com.agustinpalma.comandas.application.ports.output.PasarelaPagoPort.PagoAprobado;
+import com.agustinpalma.comandas.application.ports.output.PasarelaPagoPort.PasarelaPagoNoDisponibleException;
+import com.agustinpalma.comandas.application.ports.output.PasarelaPagoPort.SolicitudLink;
+import com.agustinpalma.comandas.domain.model.DomainIds.LinkPagoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.LinkPago;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.LinkPagoRepository;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Objects;
+import java.util.Optional;
+
+/**
+ * Caso de uso de los links de pago de los pedidos telefónicos de delivery.
+ *
+ * HU-128: Links de pago para delivery.
+ *
+ * Flujo:
+ * 1. El operador carga el pedido y genera el link por el total; se lo manda
+ *    al cliente por WhatsApp.
+ * 2. Mientras el link está pendiente, el pedido no se puede enviar a cocina.
+ * 3. Cuando la pasarela confirma el cobro (por webhook o al verificar), el
+ *    link queda PAGADO y el pedido sale a cocina solo, sin esperar al
+ *    operador. Se avisa en tiempo real a todas las terminales.
+ *
+ * Los avisos de la pasarela solo disparan la verificación: el estado del
+ * pago siempre se consulta a la pasarela, nunca se toma del aviso.
+ */
+@Transactional
+public class GestionarLinksPagoUseCase {
+
+    private static final Logger log = LoggerFactory.getLogger(GestionarLinksPagoUseCase.class);
+
+    private final LinkPagoRepository linkPagoRepository;
+    private final MesaRepository mesaRepository;
+    private final PedidoRepository pedidoRepository;
+    private final PasarelaPagoPort pasarelaPago;
+    private final EnviarComandaCocinaUseCase enviarComandaCocinaUseCase;
+    private final NotificadorTiempoRealPort notificador;
+    private final MeisenProperties properties;
+    private final Clock clock;
+
+    public GestionarLinksPagoUseCase(
+            LinkPagoRepository linkPagoRepository,
+            MesaRepository mesaRepository,
+            PedidoRepository pedidoRepository,
+            PasarelaPagoPort pasarelaPago,
+            EnviarComandaCocinaUseCase enviarComandaCocinaUseCase,
+            NotificadorTiempoRealPort notificador,
+            MeisenProperties properties,
+            Clock clock
+    ) {
+        this.linkPagoRepository = Objects.requireNonNull(linkPagoRepository, "El linkPagoRepository es obligatorio");
+        this.mesaRepository = Objects.requireNonNull(mesaRepository, "El mesaRepository es obligatorio");
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.pasarelaPago = Objects.requireNonNull(pasarelaPago, "La pasarelaPago es obligatoria");
+        this.enviarComandaCocinaUseCase = Objects.requireNonNull(enviarComandaCocinaUseCase, "El enviarComandaCocinaUseCase es obligatorio");
+        this.notificador = Objects.requireNonNull(notificador, "El notificador es obligatorio");
+        this.properties = Objects.requireNonNull(properties, "Las properties son obligatorias");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * Genera el link por el total actual del pedido de la mesa.
+     * Si ya había uno pendiente (el pedido cambió), queda cancelado.
+     *
+     * @throws IllegalArgumentException si la mesa no existe en el local
+     * @throws IllegalStateException si el pedido no admite link o la pasarela no está disponible
+     */
+    public LinkPagoResponse generar(LocalId localId, MesaId mesaId, GenerarLinkPagoRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(mesaId, "El mesaId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Mesa mesa = buscarMesa(localId, mesaId);
+        Pedido pedido = pedidoRepository.buscarAbiertoPorMesa(mesaId, localId)
+            .orElseThrow(() -> new IllegalStateException("La mesa no tiene un pedido activo"));
+        LinkPago.validarPedido(pedido);
+
+        linkPagoRepository.buscarPendientePorPedido(pedido.getId(), localId).ifPresent(anterior -> {
+            anterior.cancelar();
+            linkPagoRepository.guardar(anterior);
+        });
+
+        LinkPagoId id = LinkPagoId.generate();
+        SolicitudLink solicitud = new SolicitudLink(id.toString(), describir(pedido), pedido.calcularTotal());
+        LinkGenerado generado;
+        try {
+            generado = pasarelaPago.crearLink(request.proveedor(), solicitud);
+        } catch (PasarelaPagoNoDisponibleException e) {
+            throw new IllegalStateException(e.getMessage());
+        }
+
+        LinkPago link = linkPagoRepository.guardar(LinkPago.emitir(id, pedido, mesa.getNumero(), request.proveedor(),
+            request.telefono(), generado.url(), LocalDateTime.now(clock)));
+
+        log.info("Link de pago {} generado: Mesa {}, Pedido #{}, ${}",
+            request.proveedor(), mesa.getNumero(), pedido.getNumero(), link.getMonto());
+        return toResponse(link);
+    }
+
+    /**
+     * Último link del pedido abierto de la mesa (vacío si nunca se generó uno).
+     */
+    @Transactional(readOnly = true)
+    public Optional<LinkPagoResponse> consultarDeMesa(LocalId localId, MesaId mesaId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(mesaId, "El mesaId es obligatorio");
+
+        return pedidoRepository.buscarAbiertoPorMesa(mesaId, localId)
+            .flatMap(pedido -> linkPagoRepository.buscarUltimoPorPedido(pedido.getId(), localId))
+            .map(this::toResponse);
+    }
+
+    @Transactional(readOnly = true)
+    public List<LinkPagoResponse> listarPendientes(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        return linkPagoRepository.buscarPendientes(localId).stream()
+            .map(this::toResponse)
+            .toList();
+    }
+
+    /**
+     * Le pregunta a la pasarela si el link ya se pagó. Lo usa el botón
+     * "Verificar pago" y también el aviso de la pasarela (webhook).
+     *
+     * @throws IllegalArgumentException si el link no existe en el local
+     * @throws IllegalStateException si la pasarela no está disponible
+     */
+    public LinkPagoResponse verificar(LocalId localId, LinkPagoId linkId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(linkId, "El linkId es obligatorio");
+
+        LinkPago link = buscar(localId, linkId);
+        if (!link.estaPendiente()) {
+            // Los avisos se repiten: un link ya resuelto no se vuelve a procesar
+            return toResponse(link);
+        }
+
+        Optional<PagoAprobado> pago;
+        try {
+            pago = pasarelaPago.buscarPagoAprobado(link.getProveedor(), link.getId().toString());
+        } catch (PasarelaPagoNoDisponibleException e) {
+            throw new IllegalStateException(e.getMessage());
+        }
+        pago.ifPresent(aprobado -> acreditar(link, aprobado));
+        return toResponse(link);
+    }
+
+    /**
+     * Verifica todos los links pendientes. Cubre las instalaciones a las que
+     * la pasarela no puede avisar (sin dirección pública): el salón lo llama
+     * periódicamente mientras haya links esperando.
+     *
+     * @return los links que se acreditaron en esta vuelta
+     */
+    public List<LinkPagoResponse> verificarPendientes(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        List<LinkPagoResponse> acreditados = new ArrayList<>();
+        for (LinkPago link : linkPagoRepository.buscarPendientes(localId)) {
+            try {
+                pasarelaPago.buscarPagoAprobado(link.getProveedor(), link.getId().toString())
+                    .ifPresent(aprobado -> {
+                        acreditar(link, aprobado);
+                        acreditados.add(toResponse(link));
+                    });
+            } catch (PasarelaPagoNoDisponibleException e) {
+                log.warn("No se pudo verificar el link de la mesa {}: {}", link.getNumeroMesa(), e.getMessage());
+            }
+        }
+        return acreditados;
+    }
+
+    /**
+     * El operador descarta el link (el cliente decidió pagar al recibir).
+     * Desde ahí el pedido se manda a cocina como cualquier otro.
+     *
+     * @throws IllegalArgumentException si el link no existe en el local
+     * @throws IllegalStateException si el link ya no está pendiente
+     */
+    public LinkPagoResponse cancelar(LocalId localId, LinkPagoId linkId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(linkId, "El linkId es obligatorio");
+
+        LinkPago link = buscar(localId, linkId);
+        link.cancelar();
+        return toResponse(linkPagoRepository.guardar(link));
+    }
+
+    /**
+     * Marca el link como pagado y manda el pedido a cocina.
+     *
+     * Se comprueba antes que el pedido siga abierto y con ítems: si el envío
+     * fallara adentro de esta transacción, se perdería también el pago.
+     */
+    private void acreditar(LinkPago link, PagoAprobado pago) {
+        LocalId localId = link.getLocalId();
+        link.marcarPagado(pago.idPago(), LocalDateTime.now(clock));
+        linkPagoRepository.guardar(link);
+
+        log.info("Link de pago acreditado: Mesa {}, Pedido #{}, pago {}",
+            link.getNumeroMesa(), link.getNumeroPedido(), pago.idPago());
+
+        Optional<Pedido> pedido = pedidoRepository.buscarAbiertoPorMesa(link.getMesaId(), localId)
+            .filter(p -> p.getId().equals(link.getPedidoId()));
+        if (pedido.isPresent() && !pedido.get().getItems().isEmpty()) {
+            enviarComandaCocinaUseCase.ejecutar(localId, link.getMesaId());
+        } else {
+            log.warn("El pedido #{} ya no está abierto: el pago quedó registrado pero no se envió a cocina",
+                link.getNumeroPedido());
+        }
+
+        notificador.publicar(localId, LinkPagoResponse.EVENTO_PAGADO, toResponse(link));
+    }
+
+    private Mesa buscarMesa(LocalId localId, MesaId mesaId) {
+        return mesaRepository.buscarPorId(mesaId)
+            .filter(m -> m.getLocalId().equals(localId))
+            .orElseThrow(() -> new IllegalArgumentException("La mesa no existe en este local"));
+    }
+
+    private LinkPago buscar(LocalId localId, LinkPagoId linkId) {
+        return linkPagoRepository.buscarPorId(linkId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("El link de pago no existe en este local"));
+    }
+
+    private String describir(Pedido pedido) {
+        String nombreLocal = properties.getLocal().getNombreLocal();
+        return nombreLocal == null || nombreLocal.isBlank()
+            ? "Pedido #" + pedido.getNumero()
+            : nombreLocal + " - Pedido #" + pedido.getNumero();
+    }
+
+    private LinkPagoResponse toResponse(LinkPago link) {
+        return LinkPagoResponse.fromDomain(link, properties.getLocal().getNombreLocal());
+    }
+}
@@ -0,0 +1,216 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.AvisarClienteEnEsperaRequest;
+import com.agustinpalma.comandas.application.dto.ClienteEnEsperaRequest;
+import com.agustinpalma.comandas.application.dto.ClienteEnEsperaResponse;
+import com.agustinpalma.comandas.domain.model.ClienteEnEspera;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoEspera;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoMesa;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoReserva;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteEnEsperaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.Reserva;
+import com.agustinpalma.comandas.domain.repository.ClienteEnEsperaRepository;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.ReservaRepository;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.Duration;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Optional;
+import java.util.Set;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * HU-149: Lista de espera de mesas para cuando el salón está lleno.
+ *
+ * El encargado anota al cliente con nombre, personas y teléfono. Al listar,
+ * cada cliente que sigue esperando recibe como sugerencia la mesa libre más
+ * chica en la que entra su grupo, por orden de llegada: una mesa sugerida al
+ * primero no se le sugiere al siguiente. No se sugieren mesas sin capacidad
+ * definida, mesas ya avisadas a otro cliente ni mesas con una reserva
+ * pendiente que arranca dentro de las próximas dos horas.
+ *
+ * Avisar no manda el mensaje desde el servidor: la respuesta trae el enlace
+ * de WhatsApp y de SMS con el aviso armado, igual que los links de pago.
+ */
+@Transactional
+public class GestionarListaEsperaUseCase {
+
+    /** Una mesa con reserva que arranca dentro de este margen queda guardada para la reserva */
+    private static final Duration MARGEN_RESERVA = Duration.ofHours(2);
+
+    private final ClienteEnEsperaRepository clienteEnEsperaRepository;
+    private final MesaRepository mesaRepository;
+    private final ReservaRepository reservaRepository;
+    private final MeisenProperties properties;
+    private final Clock clock;
+
+    public GestionarListaEsperaUseCase(ClienteEnEsperaRepository clienteEnEsperaRepository,
+                                       MesaRepository mesaRepository, ReservaRepository reservaRepository,
+                                       MeisenProperties properties, Clock clock) {
+        this.clienteEnEsperaRepository = Objects.requireNonNull(clienteEnEsperaRepository,
+            "El clienteEnEsperaRepository es obligatorio");
+        this.mesaRepository = Objects.requireNonNull(mesaRepository, "El mesaRepository es obligatorio");
+        this.reservaRepository = Objects.requireNonNull(reservaRepository, "El reservaRepository es obligatorio");
+        this.properties = Objects.requireNonNull(properties, "Las properties son obligatorias");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @return clientes que esperan mesa o el aviso, por orden de llegada, con
+     *         su tiempo de espera y la mesa que se les puede avisar
+     */
+    @Transactional(readOnly = true)
+    public List<ClienteEnEsperaResponse> listar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        LocalDateTime ahora = LocalDateTime.now(clock);
+
+        List<ClienteEnEspera> enLista = clienteEnEsperaRepository.buscarEnLista(localId);
+        List<Mesa> mesas = mesaRepository.buscarPorLocal(localId);
+        Map<MesaId, Mesa> porId = mesas.stream().collect(Collectors.toMap(Mesa::getId, Function.identity()));
+
+        Set<MesaId> guardadas = enLista.stream()
+            .map(ClienteEnEspera::getMesaId)
+            .filter(Objects::nonNull)
+            .collect(Collectors.toSet());
+        guardadas.addAll(mesasReservadas(localId, ahora));
+
+        List<Mesa> disponibles = new ArrayList<>(mesas.stream()
+            .filter(m -> m.getEstado() == EstadoMesa.LIBRE && m.getCapacidad() != null)
+            .filter(m -> !guardadas.contains(m.getId()))
+            .sorted(Comparator.comparing(Mesa::getCapacidad).thenComparing(Mesa::getNumero))
+            .toList());
+
+        List<ClienteEnEsperaResponse> respuesta = new ArrayList<>();
+        for (ClienteEnEspera cliente : enLista) {
+            Mesa sugerida = null;
+            if (cliente.getEstado() == EstadoEspera.ESPERANDO) {
+                sugerida = disponibles.stream()
+                    .filter(m -> m.alcanzaPara(cliente.getPersonas()))
+                    .findFirst()
+                    .orElse(null);
+                disponibles.remove(sugerida);
+            }
+            Integer numeroMesa = Optional.ofNullable(cliente.getMesaId()).map(porId::get).map(Mesa::getNumero)
+                .orElse(null);
+            respuesta.add(ClienteEnEsperaResponse.fromDomain(cliente, numeroMesa, sugerida, nombreLocal(), ahora));
+        }
+        return respuesta;
+    }
+
+    /**
+     * @throws IllegalArgumentException si los datos son inválidos
+     */
+    public ClienteEnEsperaResponse anotar(LocalId localId, ClienteEnEsperaRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+        Objects.requireNonNull(request.personas(), "La cantidad de personas es obligatoria");
+        LocalDateTime ahora = LocalDateTime.now(clock);
+
+        ClienteEnEspera cliente = ClienteEnEspera.anotar(localId, request.nombreCliente(), request.personas(),
+            request.telefono(), request.observaciones(), ahora);
+
+        return ClienteEnEsperaResponse.fromDomain(clienteEnEsperaRepository.guardar(cliente), null, null,
+            nombreLocal(), ahora);
+    }
+
+    /**
+     * Le guarda la mesa al cliente y devuelve el aviso listo para mandarle.
+     *
+     * @throws IllegalArgumentException si la mesa no existe en el local o el grupo no entra
+     * @throws IllegalStateException si la mesa no está libre, ya se le avisó a otro cliente
+     *                               o el cliente ya salió de la lista
+     */
+    public ClienteEnEsperaResponse avisar(LocalId localId, ClienteEnEsperaId clienteId,
+                                          AvisarClienteEnEsperaRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+        ClienteEnEspera cliente = buscarCliente(localId, clienteId);
+        Mesa mesa = buscarMesa(localId, new MesaId(Objects.requireNonNull(request.mesaId(), "La mesa es obligatoria")));
+
+        clienteEnEsperaRepository.buscarEnLista(localId).stream()
+            .filter(otro -> !otro.equals(cliente) && mesa.getId().equals(otro.getMesaId()))
+            .findFirst()
+            .ifPresent(otro -> {
+                throw new IllegalStateException(
+                    "La mesa " + mesa.getNumero() + " ya se le avisó a " + otro.getNombreCliente());
+            });
+
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        cliente.avisar(mesa, ahora);
+
+        return ClienteEnEsperaResponse.fromDomain(clienteEnEsperaRepository.guardar(cliente), mesa.getNumero(),
+            null, nombreLocal(), ahora);
+    }
+
+    /**
+     * El cliente ocupó su mesa y sale de la lista. La mesa se abre desde el salón.
+     *
+     * @throws IllegalStateException si el cliente ya salió de la lista
+     */
+    public ClienteEnEsperaResponse sentar(LocalId localId, ClienteEnEsperaId clienteId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        ClienteEnEspera cliente = buscarCliente(localId, clienteId);
+        LocalDateTime ahora = LocalDateTime.now(clock);
+
+        cliente.sentar(ahora);
+
+        return respuesta(clienteEnEsperaRepository.guardar(cliente), ahora);
+    }
+
+    /**
+     * @throws IllegalStateException si el cliente ya salió de la lista
+     */
+    public ClienteEnEsperaResponse cancelar(LocalId localId, ClienteEnEsperaId clienteId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        ClienteEnEspera cliente = buscarCliente(localId, clienteId);
+        LocalDateTime ahora = LocalDateTime.now(clock);
+
+        cliente.cancelar(ahora);
+
+        return respuesta(clienteEnEsperaRepository.guardar(cliente), ahora);
+    }
+
+    private Set<MesaId> mesasReservadas(LocalId localId, LocalDateTime ahora) {
+        return reservaRepository.buscarEntre(localId, ahora.toLocalDate().atStartOfDay(), ahora.plus(MARGEN_RESERVA))
+            .stream()
+            .filter(reserva -> reserva.getEstado() == EstadoReserva.PENDIENTE)
+            .map(Reserva::getMesaId)
+            .filter(Objects::nonNull)
+            .collect(Collectors.toSet());
+    }
+
+    private ClienteEnEspera buscarCliente(LocalId localId, ClienteEnEsperaId clienteId) {
+        Objects.requireNonNull(clienteId, "El clienteId es obligatorio");
+        return clienteEnEsperaRepository.buscarPorId(clienteId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("El cliente no está en la lista de espera de este local"));
+    }
+
+    private Mesa buscarMesa(LocalId localId, MesaId mesaId) {
+        return mesaRepository.buscarPorId(mesaId)
+            .filter(mesa -> mesa.getLocalId().equals(localId))
+            .orElseThrow(() -> new IllegalArgumentException("La mesa no existe en este local"));
+    }
+
+    private ClienteEnEsperaResponse respuesta(ClienteEnEspera cliente, LocalDateTime ahora) {
+        Integer numero = cliente.getMesaId() != null
+            ? mesaRepository.buscarPorId(cliente.getMesaId()).map(Mesa::getNumero).orElse(null)
+            : null;
+        return ClienteEnEsperaResponse.fromDomain(cliente, numero, null, nombreLocal(), ahora);
+    }
+
+    private String nombreLocal() {
+        return properties.getLocal().getNombreLocal();
+    }
+}
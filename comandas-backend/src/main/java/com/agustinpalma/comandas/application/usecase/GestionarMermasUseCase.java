@@ -0,0 +1,212 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.MermaRequest;
+import com.agustinpalma.comandas.application.dto.MermaResponse;
+import com.agustinpalma.comandas.application.dto.ReporteMermasResponse;
+import com.agustinpalma.comandas.application.dto.ReporteMermasResponse.CategoriaMes;
+import com.agustinpalma.comandas.application.dto.ReporteMermasResponse.InsumoMes;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaMerma;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Insumo;
+import com.agustinpalma.comandas.domain.model.Merma;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.model.RenglonMerma;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
+import com.agustinpalma.comandas.domain.repository.MermaRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoInsumoRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.domain.repository.RecetaRepository;
+import com.agustinpalma.comandas.domain.service.InventarioService;
+import com.agustinpalma.comandas.domain.service.InventarioService.ResultadoMerma;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.LocalTime;
+import java.time.YearMonth;
+import java.util.Comparator;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * HU-135: Caso de uso del registro de mermas y desperdicio.
+ *
+ * La merma descuenta del stock lo que se perdió (con un movimiento MERMA por
+ * insumo) y queda guardada con su valor al costo del momento, para el
+ * reporte mensual por categoría.
+ */
+@Transactional
+public class GestionarMermasUseCase {
+
+    private static final BigDecimal CIEN = new BigDecimal("100");
+
+    private final MermaRepository mermaRepository;
+    private final InsumoRepository insumoRepository;
+    private final MovimientoInsumoRepository movimientoInsumoRepository;
+    private final ProductoRepository productoRepository;
+    private final RecetaRepository recetaRepository;
+    private final InventarioService inventarioService;
+    private final Clock clock;
+
+    public GestionarMermasUseCase(
+            MermaRepository mermaRepository,
+            InsumoRepository insumoRepository,
+            MovimientoInsumoRepository movimientoInsumoRepository,
+            ProductoRepository productoRepository,
+            RecetaRepository recetaRepository,
+            InventarioService inventarioService,
+            Clock clock
+    ) {
+        this.mermaRepository = Objects.requireNonNull(mermaRepository, "El mermaRepository es obligatorio");
+        this.insumoRepository = Objects.requireNonNull(insumoRepository, "El insumoRepository es obligatorio");
+        this.movimientoInsumoRepository = Objects.requireNonNull(movimientoInsumoRepository, "El movimientoInsumoRepository es obligatorio");
+        this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+        this.recetaRepository = Objects.requireNonNull(recetaRepository, "El recetaRepository es obligatorio");
+        this.inventarioService = Objects.requireNonNull(inventarioService, "El inventarioService es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * Registra la merma y descuenta del stock lo perdido.
+     *
+     * @throws IllegalArgumentException si se carga por producto y por insumo a la vez (o por ninguno),
+     *                                  si el producto o un insumo no existe en el local,
+     *                                  o si el producto no tiene receta
+     */
+    public MermaResponse registrar(LocalId localId, MermaRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        boolean porProducto = request.productoId() != null;
+        boolean porInsumo = request.insumos() != null && !request.insumos().isEmpty();
+        if (porProducto == porInsumo) {
+            throw new IllegalArgumentException("La merma se carga por producto o por insumo, no las dos cosas");
+        }
+
+        Map<InsumoId, Insumo> insumos = insumoRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Insumo::getId, Function.identity()));
+        LocalDateTime ahora = LocalDateTime.now(clock);
+
+        ResultadoMerma resultado;
+        if (porProducto) {
+            ProductoId productoId = new ProductoId(request.productoId());
+            Producto producto = productoRepository.buscarPorIdYLocal(productoId, localId)
+                .orElseThrow(() -> new IllegalArgumentException("El producto no existe en este local"));
+            resultado = inventarioService.registrarMermaProducto(
+                localId,
+                request.categoria(),
+                request.motivo(),
+                request.responsable(),
+                producto,
+                request.unidades() != null ? request.unidades() : 1,
+                recetaRepository.buscarPorProducto(productoId, localId).orElse(null),
+                insumos,
+                ahora
+            );
+        } else {
+            resultado = inventarioService.registrarMerma(
+                localId,
+                request.categoria(),
+                request.motivo(),
+                request.responsable(),
+                cantidades(request),
+                insumos,
+                ahora
+            );
+        }
+
+        resultado.insumosModificados().forEach(insumoRepository::guardar);
+        resultado.movimientos().forEach(movimientoInsumoRepository::guardar);
+        return MermaResponse.fromDomain(mermaRepository.guardar(resultado.merma()));
+    }
+
+    /**
+     * @throws IllegalArgumentException si el rango está invertido
+     */
+    @Transactional(readOnly = true)
+    public List<MermaResponse> listar(LocalId localId, LocalDate desde, LocalDate hasta) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(desde, "La fecha desde es obligatoria");
+        Objects.requireNonNull(hasta, "La fecha hasta es obligatoria");
+        if (hasta.isBefore(desde)) {
+            throw new IllegalArgumentException("La fecha hasta no puede ser anterior a la fecha desde");
+        }
+
+        return mermaRepository.buscarPorFecha(localId, desde.atStartOfDay(), hasta.atTime(LocalTime.MAX)).stream()
+            .map(MermaResponse::fromDomain)
+            .toList();
+    }
+
+    /**
+     * Merma valorizada del mes por categoría y por insumo.
+     */
+    @Transactional(readOnly = true)
+    public ReporteMermasResponse reporteMensual(LocalId localId, YearMonth periodo) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(periodo, "El período es obligatorio");
+
+        List<Merma> mermas = mermaRepository.buscarPorFecha(localId, periodo.atDay(1).atStartOfDay(),
+            periodo.atEndOfMonth().atTime(LocalTime.MAX));
+        BigDecimal total = mermas.stream().map(Merma::getCostoTotal).reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        Map<CategoriaMerma, List<Merma>> porCategoria = mermas.stream()
+            .collect(Collectors.groupingBy(Merma::getCategoria));
+        List<CategoriaMes> categorias = porCategoria.entrySet().stream()
+            .map(e -> {
+                BigDecimal subtotal = e.getValue().stream()
+                    .map(Merma::getCostoTotal)
+                    .reduce(BigDecimal.ZERO, BigDecimal::add);
+                return new CategoriaMes(e.getKey(), e.getValue().size(), subtotal, porcentaje(subtotal, total));
+            })
+            .sorted(Comparator.comparing(CategoriaMes::total).reversed()
+                .thenComparing(CategoriaMes::categoria))
+            .toList();
+
+        Map<InsumoId, InsumoMes> porInsumo = new LinkedHashMap<>();
+        mermas.stream()
+            .flatMap(m -> m.getRenglones().stream())
+            .forEach(r -> porInsumo.merge(r.getInsumoId(), insumoMes(r), GestionarMermasUseCase::acumular));
+        List<InsumoMes> insumos = porInsumo.values().stream()
+            .sorted(Comparator.comparing(InsumoMes::total).reversed()
+                .thenComparing(InsumoMes::nombre, String.CASE_INSENSITIVE_ORDER))
+            .toList();
+
+        return new ReporteMermasResponse(periodo, mermas.size(), total, categorias, insumos);
+    }
+
+    private static InsumoMes insumoMes(RenglonMerma renglon) {
+        return new InsumoMes(renglon.getInsumoId().getValue(), renglon.getNombreInsumo(), renglon.getUnidad(),
+            renglon.getCantidad(), renglon.getCostoValorizado());
+    }
+
+    private static InsumoMes acumular(InsumoMes a, InsumoMes b) {
+        return new InsumoMes(a.insumoId(), a.nombre(), a.unidad(), a.cantidad().add(b.cantidad()),
+            a.total().add(b.total()));
+    }
+
+    private static BigDecimal porcentaje(BigDecimal parte, BigDecimal total) {
+        if (total.signum() == 0) {
+            return BigDecimal.ZERO;
+        }
+        return parte.multiply(CIEN).divide(total, 2, RoundingMode.HALF_UP);
+    }
+
+    private static Map<InsumoId, BigDecimal> cantidades(MermaRequest request) {
+        Map<InsumoId, BigDecimal> cantidades = new LinkedHashMap<>();
+        for (MermaRequest.InsumoMermaRequest renglon : request.insumos()) {
+            if (cantidades.put(new InsumoId(renglon.insumoId()), renglon.cantidad()) != null) {
+                throw new IllegalArgumentException("Hay un insumo cargado dos veces en la merma");
+            }
+        }
+        return cantidades;
+    }
+}
@@ -0,0 +1,110 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.MozoRequest;
+import com.agustinpalma.comandas.application.dto.MozoResponse;
+import com.agustinpalma.comandas.application.dto.TopeConsumoRequest;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Caso de uso para el ABM de mozos del local.
+ *
+ * HU-111: Los mozos no se eliminan, se desactivan; así el reporte de
+ * desempeño sigue mostrando el nombre en los períodos en que trabajaron.
+ * HU-121: También define el rol y el PIN con el que autoriza el encargado.
+ * HU-124: Y la tarjeta de fichaje, que no se puede repetir dentro del local.
+ * HU-132: Y el tope mensual de consumo interno del empleado.
+ */
+@Transactional
+public class GestionarMozosUseCase {
+
+    private final MozoRepository mozoRepository;
+
+    public GestionarMozosUseCase(MozoRepository mozoRepository) {
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+    }
+
+    /**
+     * @return todos los mozos del local, ordenados por nombre
+     */
+    @Transactional(readOnly = true)
+    public List<MozoResponse> listar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        return mozoRepository.buscarPorLocal(localId).stream()
+            .map(MozoResponse::fromDomain)
+            .toList();
+    }
+
+    public MozoResponse crear(LocalId localId, MozoRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Mozo mozo = new Mozo(MozoId.generate(), localId, request.nombre(),
+            request.activo() == null || request.activo());
+        aplicarRolYPin(mozo, request);
+        aplicarTarjeta(mozo, request);
+        return MozoResponse.fromDomain(mozoRepository.guardar(mozo));
+    }
+
+    /**
+     * @throws IllegalArgumentException si el mozo no existe en el local
+     */
+    public MozoResponse actualizar(LocalId localId, MozoId mozoId, MozoRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(mozoId, "El mozoId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Mozo mozo = mozoRepository.buscarPorId(mozoId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("El mozo no existe en este local"));
+        mozo.actualizar(request.nombre(), request.activo() != null ? request.activo() : mozo.isActivo());
+        aplicarRolYPin(mozo, request);
+        aplicarTarjeta(mozo, request);
+        return MozoResponse.fromDomain(mozoRepository.guardar(mozo));
+    }
+
+    /**
+     * HU-132: Fija o quita el tope mensual de consumo interno.
+     *
+     * @throws IllegalArgumentException si el mozo no existe en el local o el tope es negativo
+     */
+    public MozoResponse definirTopeConsumo(LocalId localId, MozoId mozoId, TopeConsumoRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(mozoId, "El mozoId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Mozo mozo = mozoRepository.buscarPorId(mozoId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("El mozo no existe en este local"));
+        mozo.definirTopeConsumo(request.topeMensual());
+        return MozoResponse.fromDomain(mozoRepository.guardar(mozo));
+    }
+
+    private void aplicarRolYPin(Mozo mozo, MozoRequest request) {
+        if (request.rol() != null) {
+            mozo.cambiarRol(request.rol());
+        }
+        if (request.pin() != null) {
+            mozo.cambiarPin(request.pin());
+        }
+    }
+
+    private void aplicarTarjeta(Mozo mozo, MozoRequest request) {
+        if (request.tarjeta() == null) {
+            return;
+        }
+        mozo.asignarTarjeta(request.tarjeta());
+        if (mozo.tieneTarjeta()) {
+            mozoRepository.buscarPorTarjeta(mozo.getLocalId(), mozo.getCodigoTarjeta())
+                .filter(otro -> !otro.getId().equals(mozo.getId()))
+                .ifPresent(otro -> {
+                    throw new IllegalStateException("La tarjeta ya está asignada a " + otro.getNombre());
+                });
+        }
+    }
+}
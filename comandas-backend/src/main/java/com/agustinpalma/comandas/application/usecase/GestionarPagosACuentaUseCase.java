@@ -0,0 +1,189 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.PagoRequest;
+import com.agustinpalma.comandas.application.dto.PagosACuentaResponse;
+import com.agustinpalma.comandas.application.ports.output.NotificadorTiempoRealPort;
+import com.agustinpalma.comandas.domain.exception.PedidoModificadoException;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Moneda;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.RecargosMedioPago;
+import com.agustinpalma.comandas.domain.model.RedondeoEfectivo;
+import com.agustinpalma.comandas.domain.model.TiposCambio;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.RecargosMedioPagoRepository;
+import com.agustinpalma.comandas.domain.repository.RedondeoEfectivoRepository;
+import com.agustinpalma.comandas.domain.repository.TiposCambioRepository;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * HU-160: Pagos a cuenta sobre una mesa abierta.
+ *
+ * Un comensal se va antes y paga lo suyo: el pago queda en el pedido, la
+ * mesa sigue abierta y el cierre solo exige cubrir el saldo pendiente. El
+ * pedido cerrado guarda todos los pagos, los a cuenta primero.
+ *
+ * El pago a cuenta se convierte igual que los del cierre ({@link #convertir}):
+ * recargo del medio (HU-143), tipo de cambio del día (HU-151), redondeo del
+ * efectivo (HU-152) y referencia del comprobante (HU-156). Cuenta corriente,
+ * consumo interno y tarjeta de regalo se cobran recién al cerrar.
+ *
+ * El cobro entra al arqueo con el cierre de la mesa: queda en el turno que la
+ * cierra (HU-106), pero en la caja que cobró el pago a cuenta (HU-159).
+ */
+@Transactional
+public class GestionarPagosACuentaUseCase {
+
+    private static final Logger log = LoggerFactory.getLogger(GestionarPagosACuentaUseCase.class);
+
+    private final PedidoRepository pedidoRepository;
+    private final RecargosMedioPagoRepository recargosMedioPagoRepository;
+    private final TiposCambioRepository tiposCambioRepository;
+    private final RedondeoEfectivoRepository redondeoEfectivoRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
+    private final GestionarCajasUseCase gestionarCajasUseCase;
+    private final NotificadorTiempoRealPort notificador;
+    private final Clock clock;
+
+    public GestionarPagosACuentaUseCase(
+            PedidoRepository pedidoRepository,
+            RecargosMedioPagoRepository recargosMedioPagoRepository,
+            TiposCambioRepository tiposCambioRepository,
+            RedondeoEfectivoRepository redondeoEfectivoRepository,
+            JornadaComercialRepository jornadaComercialRepository,
+            GestionarCajasUseCase gestionarCajasUseCase,
+            NotificadorTiempoRealPort notificador,
+            Clock clock
+    ) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.recargosMedioPagoRepository = Objects.requireNonNull(recargosMedioPagoRepository, "El recargosMedioPagoRepository es obligatorio");
+        this.tiposCambioRepository = Objects.requireNonNull(tiposCambioRepository, "El tiposCambioRepository es obligatorio");
+        this.redondeoEfectivoRepository = Objects.requireNonNull(redondeoEfectivoRepository, "El redondeoEfectivoRepository es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository, "El jornadaComercialRepository es obligatorio");
+        this.gestionarCajasUseCase = Objects.requireNonNull(gestionarCajasUseCase, "El gestionarCajasUseCase es obligatorio");
+        this.notificador = Objects.requireNonNull(notificador, "El notificador es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @param versionPedido versión del pedido en la terminal (HU-165)
+     * @throws IllegalStateException si la mesa no tiene pedido abierto
+     * @throws IllegalArgumentException si el medio se cobra al cierre o el monto supera el saldo
+     * @throws PedidoModificadoException si otra terminal modificó el pedido
+     */
+    public PagosACuentaResponse registrar(LocalId localId, MesaId mesaId, PagoRequest request, long versionPedido) {
+        Objects.requireNonNull(request, "El pago es obligatorio");
+        if (request.codigoTarjetaRegalo() != null && !request.codigoTarjetaRegalo().isBlank()) {
+            throw new IllegalArgumentException("La tarjeta de regalo se cobra al cerrar la mesa");
+        }
+        Pedido pedido = buscarPedidoAbierto(localId, mesaId);
+        pedido.verificarVersion(versionPedido);
+        LocalDateTime ahora = LocalDateTime.now(clock);
+
+        Pago pago = convertir(localId, List.of(request), gestionarCajasUseCase.cajaActual(localId), ahora).get(0);
+
+        pedido.registrarPagoACuenta(pago);
+        // HU-113: cobrarle a un comensal es atender la mesa
+        pedido.registrarActividad(ahora);
+        pedidoRepository.guardar(pedido);
+
+        log.info("Pago a cuenta en pedido #{}: {} {} (saldo {})",
+            pedido.getNumero(), pago.getMedio(), pago.getMonto(), pedido.calcularSaldoPendiente());
+        return notificar(localId, pedido);
+    }
+
+    /**
+     * @param indice posición del pago en la lista de pagos a cuenta
+     * @param versionPedido versión del pedido en la terminal (HU-165): la posición es la que ella ve
+     * @throws IllegalStateException si la mesa no tiene pedido abierto
+     * @throws IllegalArgumentException si no hay un pago en esa posición
+     * @throws PedidoModificadoException si otra terminal modificó el pedido
+     */
+    public PagosACuentaResponse anular(LocalId localId, MesaId mesaId, int indice, long versionPedido) {
+        Pedido pedido = buscarPedidoAbierto(localId, mesaId);
+        pedido.verificarVersion(versionPedido);
+        Pago anulado = pedido.anularPagoACuenta(indice);
+        pedidoRepository.guardar(pedido);
+
+        log.info("Pago a cuenta anulado en pedido #{}: {} {}", pedido.getNumero(), anulado.getMedio(), anulado.getMonto());
+        return notificar(localId, pedido);
+    }
+
+    public PagosACuentaResponse consultar(LocalId localId, MesaId mesaId) {
+        return PagosACuentaResponse.fromDomain(buscarPedidoAbierto(localId, mesaId));
+    }
+
+    /**
+     * Convierte los pagos que se cobran, a cuenta o al cerrar la mesa, a
+     * Value Objects de dominio.
+     *
+     * HU-143: con el recargo vigente de cada medio congelado en el pago
+     * HU-151: y el tipo de cambio del día si se paga en otra moneda
+     * HU-152: con el efectivo en pesos redondeado según la regla del local
+     * HU-156: y la referencia del comprobante, si se cargó
+     * HU-159: cobrados por la caja indicada (null = la principal)
+     *
+     * @throws IllegalStateException si se paga en una moneda sin cotización del día
+     */
+    public List<Pago> convertir(LocalId localId, List<PagoRequest> pagos, CajaId cajaId, LocalDateTime ahora) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(pagos, "La lista de pagos es obligatoria");
+        RecargosMedioPago recargos = recargosMedioPagoRepository.buscarPorLocal(localId)
+            .orElse(RecargosMedioPago.sinRecargos(localId));
+        TiposCambio tiposCambio = tiposCambioDelDia(pagos, localId, ahora);
+        RedondeoEfectivo redondeo = redondeoEfectivoRepository.buscarPorLocal(localId)
+            .orElse(RedondeoEfectivo.sinRedondeo(localId));
+        return redondeo.aplicar(pagos.stream()
+            .map(pr -> tiposCambio.convertir(recargos.cobrar(pr.medio(), pr.monto(), ahora), pr.moneda())
+                .conReferencia(pr.referencia())
+                .conCaja(cajaId))
+            .toList());
+    }
+
+    private Pedido buscarPedidoAbierto(LocalId localId, MesaId mesaId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(mesaId, "El mesaId es obligatorio");
+        return pedidoRepository.buscarAbiertoPorMesa(mesaId, localId)
+            .orElseThrow(() -> new IllegalStateException("La mesa no tiene un pedido abierto"));
+    }
+
+    /**
+     * Las demás terminales que tienen la mesa abierta ven el saldo nuevo sin
+     * esperar al polling.
+     */
+    private PagosACuentaResponse notificar(LocalId localId, Pedido pedido) {
+        PagosACuentaResponse response = PagosACuentaResponse.fromDomain(pedido);
+        notificador.publicar(localId, PagosACuentaResponse.EVENTO, response);
+        return response;
+    }
+
+    /**
+     * HU-151: Cotizaciones del día operativo. Solo se consultan si algún pago
+     * viene en moneda extranjera. El día se corta en la hora de la jornada
+     * comercial del local (HU-153).
+     */
+    private TiposCambio tiposCambioDelDia(List<PagoRequest> pagos, LocalId localId, LocalDateTime ahora) {
+        LocalDate dia = jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId))
+            .fechaOperativa(ahora);
+        boolean enOtraMoneda = pagos.stream().anyMatch(pr -> pr.moneda() != null && pr.moneda() != Moneda.ARS);
+        if (!enOtraMoneda) {
+            return TiposCambio.sinCotizar(localId, dia);
+        }
+        return tiposCambioRepository.buscarPorFecha(localId, dia)
+            .orElse(TiposCambio.sinCotizar(localId, dia));
+    }
+}
@@ -0,0 +1,211 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.MatrizPermisosRequest;
+import com.agustinpalma.comandas.application.dto.MatrizPermisosResponse;
+import com.agustinpalma.comandas.application.dto.SesionEmpleadoResponse;
+import com.agustinpalma.comandas.application.ports.output.EmpleadoContextProvider;
+import com.agustinpalma.comandas.domain.exception.PermisoDenegadoException;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.MatrizPermisos;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.SesionEmpleado;
+import com.agustinpalma.comandas.domain.repository.MatrizPermisosRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.SesionEmpleadoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.Duration;
+import java.time.LocalDateTime;
+import java.util.HashMap;
+import java.util.Map;
+import java.util.Objects;
+
+/**
+ * HU-169: Caso de uso de la matriz de permisos por acción.
+ *
+ * - El dueño habilita cada acción sensible por rol y, si hace falta, le
+ *   concede o le quita una a un empleado puntual.
+ * - En la terminal el empleado se identifica con su PIN y recibe un token
+ *   de sesión, que la terminal manda en cada request, y lo que puede hacer
+ *   para no ofrecerle lo que no. La sesión vence sola.
+ * - Con la matriz activa, solo el dueño identificado cambia la matriz: no
+ *   alcanza con mandar su id.
+ * - Los casos de uso y endpoints de las acciones sensibles llaman a
+ *   {@link #exigir} antes de hacerlas. Con la matriz inactiva no se exige nada.
+ */
+@Transactional
+public class GestionarPermisosUseCase {
+
+    private final MatrizPermisosRepository matrizPermisosRepository;
+    private final MozoRepository mozoRepository;
+    private final EmpleadoContextProvider empleadoContextProvider;
+    private final SesionEmpleadoRepository sesionEmpleadoRepository;
+    private final Duration duracionSesion;
+    private final Clock clock;
+
+    /**
+     * @param duracionSesion cuánto dura la sesión de un empleado desde que se identifica
+     */
+    public GestionarPermisosUseCase(MatrizPermisosRepository matrizPermisosRepository,
+                                    MozoRepository mozoRepository,
+                                    EmpleadoContextProvider empleadoContextProvider,
+                                    SesionEmpleadoRepository sesionEmpleadoRepository,
+                                    Duration duracionSesion,
+                                    Clock clock) {
+        this.matrizPermisosRepository = Objects.requireNonNull(matrizPermisosRepository,
+            "El matrizPermisosRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.empleadoContextProvider = Objects.requireNonNull(empleadoContextProvider,
+            "El empleadoContextProvider es obligatorio");
+        this.sesionEmpleadoRepository = Objects.requireNonNull(sesionEmpleadoRepository,
+            "El sesionEmpleadoRepository es obligatorio");
+        this.duracionSesion = Objects.requireNonNull(duracionSesion, "La duración de la sesión es obligatoria");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    @Transactional(readOnly = true)
+    public MatrizPermisosResponse consultar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        return MatrizPermisosResponse.fromDomain(matrizDe(localId));
+    }
+
+    /**
+     * Guarda la matriz del local. Una vez activa, solo la cambia el dueño
+     * identificado en la terminal.
+     *
+     * @throws IllegalArgumentException si una excepción es de un empleado de otro local
+     * @throws PermisoDenegadoException si la matriz está activa y quien opera no es el dueño
+     */
+    public MatrizPermisosResponse guardar(LocalId localId, MatrizPermisosRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        MatrizPermisos matriz = matrizDe(localId);
+        if (matriz.isActiva()) {
+            Mozo empleado = empleadoActual(localId, null);
+            if (empleado.getRol() != RolPersonal.DUENO) {
+                throw new PermisoDenegadoException(null, "Solo el dueño puede cambiar los permisos");
+            }
+        }
+
+        Map<MozoId, Map<Permiso, Boolean>> excepciones = new HashMap<>();
+        if (request.excepciones() != null) {
+            request.excepciones().forEach((id, permisos) -> {
+                MozoId empleadoId = new MozoId(id);
+                mozoRepository.buscarPorId(empleadoId, localId)
+                    .orElseThrow(() -> new IllegalArgumentException("No se encontró el empleado con ID: " + id));
+                excepciones.put(empleadoId, permisos);
+            });
+        }
+        matriz.actualizar(request.activa(), request.permisosPorRol(), excepciones);
+
+        return MatrizPermisosResponse.fromDomain(matrizPermisosRepository.guardar(matriz));
+    }
+
+    /**
+     * Identifica a un empleado en la terminal con su PIN y le abre una sesión.
+     * De paso borra las sesiones vencidas.
+     *
+     * @return el empleado, las acciones que tiene habilitadas y el token de la sesión
+     * @throws IllegalArgumentException si el empleado no existe o el PIN no coincide
+     * @throws IllegalStateException si el empleado está inactivo o no tiene PIN
+     */
+    public SesionEmpleadoResponse identificar(LocalId localId, MozoId empleadoId, String pin) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(empleadoId, "El empleadoId es obligatorio");
+
+        Mozo empleado = mozoRepository.buscarPorId(empleadoId, localId)
+            .orElseThrow(() -> new IllegalArgumentException(
+                "No se encontró el empleado con ID: " + empleadoId.getValue()));
+        empleado.validarPinFichaje(pin);
+
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        sesionEmpleadoRepository.eliminarVencidas(ahora);
+        SesionEmpleado.Apertura apertura = SesionEmpleado.abrir(localId, empleado.getId(), ahora, duracionSesion);
+        sesionEmpleadoRepository.guardar(apertura.sesion());
+
+        MatrizPermisos matriz = matrizDe(localId);
+        return new SesionEmpleadoResponse(empleado.getId().getValue(), empleado.getNombre(), empleado.getRol(),
+            matriz.permisosDe(empleado), matriz.isActiva(), apertura.token(), apertura.sesion().getVenceEn());
+    }
+
+    /**
+     * Cierra la sesión del token: las acciones siguientes vuelven a pedir el PIN.
+     * Un token vencido o desconocido no hace nada.
+     */
+    public void cerrarSesion(String token) {
+        Objects.requireNonNull(token, "El token es obligatorio");
+        sesionEmpleadoRepository.eliminar(SesionEmpleado.hash(token.trim()));
+    }
+
+    /**
+     * Valida que el empleado identificado en la terminal pueda hacer la acción.
+     * Con la matriz inactiva no exige nada, ni siquiera que haya alguien identificado.
+     *
+     * @throws PermisoDenegadoException si nadie se identificó o el empleado no tiene el permiso
+     */
+    @Transactional(readOnly = true)
+    public void exigir(LocalId localId, Permiso permiso) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(permiso, "El permiso es obligatorio");
+
+        MatrizPermisos matriz = matrizDe(localId);
+        if (!matriz.isActiva()) {
+            return;
+        }
+        Mozo empleado = empleadoActual(localId, permiso);
+        if (!matriz.permite(empleado, permiso)) {
+            throw new PermisoDenegadoException(permiso,
+                empleado.getNombre() + " no tiene permiso para " + descripcion(permiso));
+        }
+    }
+
+    /**
+     * Valida que un empleado que ya se identificó en la operación (por ejemplo,
+     * el encargado que autoriza con su PIN) pueda hacer la acción.
+     *
+     * @throws PermisoDenegadoException si la matriz está activa y el empleado no tiene el permiso
+     */
+    @Transactional(readOnly = true)
+    public void exigir(Mozo empleado, Permiso permiso) {
+        Objects.requireNonNull(empleado, "El empleado es obligatorio");
+        Objects.requireNonNull(permiso, "El permiso es obligatorio");
+
+        if (!matrizDe(empleado.getLocalId()).permite(empleado, permiso)) {
+            throw new PermisoDenegadoException(permiso,
+                empleado.getNombre() + " no tiene permiso para " + descripcion(permiso));
+        }
+    }
+
+    private MatrizPermisos matrizDe(LocalId localId) {
+        return matrizPermisosRepository.buscarPorLocal(localId)
+            .orElse(MatrizPermisos.porDefecto(localId));
+    }
+
+    private Mozo empleadoActual(LocalId localId, Permiso permiso) {
+        MozoId empleadoId = empleadoContextProvider.getEmpleadoActual()
+            .orElseThrow(() -> new PermisoDenegadoException(permiso,
+                "Identificate con tu PIN para hacer esta acción"));
+        Mozo empleado = mozoRepository.buscarPorId(empleadoId, localId)
+            .orElseThrow(() -> new PermisoDenegadoException(permiso,
+                "El empleado identificado no pertenece al local. Identificate de nuevo"));
+        if (!empleado.isActivo()) {
+            throw new PermisoDenegadoException(permiso, empleado.getNombre() + " está inactivo");
+        }
+        return empleado;
+    }
+
+    private static String descripcion(Permiso permiso) {
+        return switch (permiso) {
+            case ANULAR_ITEMS -> "anular ítems enviados a cocina";
+            case APLICAR_DESCUENTOS -> "aplicar descuentos";
+            case ABRIR_CAJON -> "abrir el cajón";
+            case VER_REPORTES -> "ver reportes";
+            case MODIFICAR_PRECIOS -> "modificar precios";
+        };
+    }
+}
@@ -0,0 +1,89 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ProveedorRequest;
+import com.agustinpalma.comandas.application.dto.ProveedorResponse;
+import com.agustinpalma.comandas.domain.model.Cuit;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+import com.agustinpalma.comandas.domain.model.Proveedor;
+import com.agustinpalma.comandas.domain.repository.ProveedorRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * HU-131: Caso de uso para el ABM de proveedores con cuenta corriente.
+ */
+@Transactional
+public class GestionarProveedoresUseCase {
+
+    private final ProveedorRepository proveedorRepository;
+
+    public GestionarProveedoresUseCase(ProveedorRepository proveedorRepository) {
+        this.proveedorRepository = Objects.requireNonNull(proveedorRepository, "El proveedorRepository es obligatorio");
+    }
+
+    /**
+     * @return proveedores del local (activos e inactivos) ordenados por nombre
+     */
+    @Transactional(readOnly = true)
+    public List<ProveedorResponse> listar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        return proveedorRepository.buscarPorLocal(localId).stream()
+            .map(ProveedorResponse::fromDomain)
+            .toList();
+    }
+
+    /**
+     * @throws IllegalArgumentException si ya existe un proveedor con ese nombre o el CUIT no es válido
+     */
+    public ProveedorResponse crear(LocalId localId, ProveedorRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        validarNombreLibre(localId, request.nombre(), null);
+        Proveedor proveedor = Proveedor.crear(localId, request.nombre(), cuit(request), request.telefono(),
+            request.email(), plazoPago(request));
+        return ProveedorResponse.fromDomain(proveedorRepository.guardar(proveedor));
+    }
+
+    /**
+     * Edita los datos del proveedor o lo activa/desactiva. Cambiar el plazo
+     * de pago no mueve el vencimiento de las facturas ya registradas.
+     *
+     * @throws IllegalArgumentException si el proveedor no existe en el local, el nombre ya está usado
+     *                                  o el CUIT no es válido
+     */
+    public ProveedorResponse actualizar(LocalId localId, ProveedorId proveedorId, ProveedorRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(proveedorId, "El proveedorId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Proveedor proveedor = proveedorRepository.buscarPorId(proveedorId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("El proveedor no existe en este local"));
+        validarNombreLibre(localId, request.nombre(), proveedorId);
+
+        proveedor.actualizar(request.nombre(), cuit(request), request.telefono(), request.email(),
+            plazoPago(request), request.activo() == null || request.activo());
+        return ProveedorResponse.fromDomain(proveedorRepository.guardar(proveedor));
+    }
+
+    private void validarNombreLibre(LocalId localId, String nombre, ProveedorId excepto) {
+        boolean repetido = proveedorRepository.buscarPorLocal(localId).stream()
+            .filter(p -> !p.getId().equals(excepto))
+            .anyMatch(p -> p.tieneNombre(nombre));
+        if (repetido) {
+            throw new IllegalArgumentException("Ya existe un proveedor llamado \"" + nombre.trim() + "\"");
+        }
+    }
+
+    private static Cuit cuit(ProveedorRequest request) {
+        return request.cuit() == null || request.cuit().isBlank() ? null : Cuit.of(request.cuit());
+    }
+
+    private static int plazoPago(ProveedorRequest request) {
+        return request.plazoPagoDias() != null ? request.plazoPagoDias() : Proveedor.PLAZO_PAGO_POR_DEFECTO;
+    }
+}
@@ -0,0 +1,141 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.RecetaRequest;
+import com.agustinpalma.comandas.application.dto.RecetaResponse;
+import com.agustinpalma.comandas.application.dto.RentabilidadProductoResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.IngredienteReceta;
+import com.agustinpalma.comandas.domain.model.Insumo;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.model.Receta;
+import com.agustinpalma.comandas.domain.model.RentabilidadProducto;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.domain.repository.RecetaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Optional;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * HU-118: Caso de uso para cargar la receta de un producto.
+ *
+ * La receta se reemplaza completa en cada guardado (es una lista corta que
+ * se edita de una vez). La respuesta trae el costo de cada ingrediente y el
+ * margen resultante, así el editor muestra el impacto mientras se carga.
+ */
+@Transactional
+public class GestionarRecetasUseCase {
+
+    private final RecetaRepository recetaRepository;
+    private final InsumoRepository insumoRepository;
+    private final ProductoRepository productoRepository;
+
+    public GestionarRecetasUseCase(
+            RecetaRepository recetaRepository,
+            InsumoRepository insumoRepository,
+            ProductoRepository productoRepository
+    ) {
+        this.recetaRepository = Objects.requireNonNull(recetaRepository, "El recetaRepository es obligatorio");
+        this.insumoRepository = Objects.requireNonNull(insumoRepository, "El insumoRepository es obligatorio");
+        this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+    }
+
+    /**
+     * @return la receta con su costo, o vacío si el producto todavía no tiene receta
+     * @throws IllegalArgumentException si el producto no existe en el local
+     */
+    @Transactional(readOnly = true)
+    public Optional<RecetaResponse> obtener(LocalId localId, ProductoId productoId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(productoId, "El productoId es obligatorio");
+
+        Producto producto = buscarProducto(localId, productoId);
+        return recetaRepository.buscarPorProducto(productoId, localId)
+            .map(receta -> aResponse(producto, receta, insumosDelLocal(localId)));
+    }
+
+    /**
+     * @throws IllegalArgumentException si el producto o algún insumo no existe en el local,
+     *                                  o la receta no es válida
+     */
+    public RecetaResponse guardar(LocalId localId, ProductoId productoId, RecetaRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(productoId, "El productoId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Producto producto = buscarProducto(localId, productoId);
+        Map<InsumoId, Insumo> insumos = insumosDelLocal(localId);
+
+        List<IngredienteReceta> ingredientes = request.ingredientes().stream()
+            .map(i -> {
+                InsumoId insumoId = new InsumoId(i.insumoId());
+                if (!insumos.containsKey(insumoId)) {
+                    throw new IllegalArgumentException(
+                        String.format("No existe un insumo con ID %s en este local", insumoId.getValue())
+                    );
+                }
+                return new IngredienteReceta(insumoId, i.cantidad());
+            })
+            .toList();
+
+        Receta receta = recetaRepository.buscarPorProducto(productoId, localId)
+            .map(existente -> {
+                existente.reemplazarIngredientes(ingredientes);
+                return existente;
+            })
+            .orElseGet(() -> new Receta(productoId, localId, ingredientes));
+
+        return aResponse(producto, recetaRepository.guardar(receta), insumos);
+    }
+
+    /**
+     * Quita la receta del producto; deja de aparecer en el reporte de rentabilidad.
+     *
+     * @throws IllegalArgumentException si el producto no existe en el local
+     */
+    public void eliminar(LocalId localId, ProductoId productoId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(productoId, "El productoId es obligatorio");
+
+        buscarProducto(localId, productoId);
+        recetaRepository.eliminar(productoId, localId);
+    }
+
+    private Producto buscarProducto(LocalId localId, ProductoId productoId) {
+        return productoRepository.buscarPorIdYLocal(productoId, localId)
+            .orElseThrow(() -> new IllegalArgumentException(
+                String.format("No existe un producto con ID %s en este local", productoId.getValue())
+            ));
+    }
+
+    private Map<InsumoId, Insumo> insumosDelLocal(LocalId localId) {
+        return insumoRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Insumo::getId, Function.identity()));
+    }
+
+    private static RecetaResponse aResponse(Producto producto, Receta receta, Map<InsumoId, Insumo> insumos) {
+        List<RecetaResponse.IngredienteResponse> ingredientes = receta.getIngredientes().stream()
+            .map(i -> {
+                Insumo insumo = insumos.get(i.getInsumoId());
+                BigDecimal costo = insumo.getCostoUnitario().multiply(i.getCantidad())
+                    .setScale(2, RoundingMode.HALF_UP);
+                return new RecetaResponse.IngredienteResponse(
+                    insumo.getId().getValue(), insumo.getNombre(), insumo.getUnidad(), i.getCantidad(), costo
+                );
+            })
+            .toList();
+
+        RentabilidadProducto rentabilidad = new RentabilidadProducto(producto, receta.calcularCosto(insumos));
+        return new RecetaResponse(producto.getId().getValue(), ingredientes,
+            RentabilidadProductoResponse.fromDomain(rentabilidad));
+    }
+}
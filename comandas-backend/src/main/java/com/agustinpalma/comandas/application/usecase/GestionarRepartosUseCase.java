@@ -0,0 +1,128 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.AsignarRepartidorRequest;
+import com.agustinpalma.comandas.application.dto.RepartoResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * HU-140: Asigna cada pedido de delivery a un repartidor y registra la
+ * salida y el regreso.
+ *
+ * El tablero de repartos muestra los pedidos asignados en las últimas 24 horas
+ * cuyo repartidor todavía no volvió; el pedido puede estar ya cobrado o
+ * cobrarse al regreso.
+ */
+@Transactional
+public class GestionarRepartosUseCase {
+
+    private static final int HORAS_TABLERO = 24;
+
+    private final PedidoRepository pedidoRepository;
+    private final MozoRepository mozoRepository;
+    private final Clock clock;
+
+    public GestionarRepartosUseCase(PedidoRepository pedidoRepository, MozoRepository mozoRepository, Clock clock) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @return repartos pendientes de salida o de regreso, por apertura del pedido
+     */
+    @Transactional(readOnly = true)
+    public List<RepartoResponse> listarEnCurso(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        LocalDateTime ahora = LocalDateTime.now(clock);
+
+        List<Pedido> pendientes = pedidoRepository
+            .buscarRepartosEntre(localId, ahora.minusHours(HORAS_TABLERO), ahora.plusMinutes(1)).stream()
+            .filter(pedido -> pedido.getRegresoReparto() == null)
+            .toList();
+        if (pendientes.isEmpty()) {
+            return List.of();
+        }
+
+        Map<MozoId, Mozo> repartidores = mozoRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Mozo::getId, Function.identity()));
+        return pendientes.stream()
+            .map(pedido -> RepartoResponse.fromDomain(pedido, nombreDe(repartidores.get(pedido.getRepartidorId()))))
+            .toList();
+    }
+
+    /**
+     * @throws IllegalArgumentException si el pedido o el repartidor no existen en el local
+     * @throws IllegalStateException si el pedido no es delivery, ya salió o el empleado no es un repartidor activo
+     */
+    public RepartoResponse asignarRepartidor(LocalId localId, PedidoId pedidoId, AsignarRepartidorRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Pedido pedido = buscarPedido(localId, pedidoId);
+        Mozo repartidor = mozoRepository.buscarPorId(new MozoId(request.repartidorId()), localId)
+            .orElseThrow(() -> new IllegalArgumentException("El repartidor no existe en este local"));
+
+        pedido.asignarRepartidor(repartidor);
+
+        return RepartoResponse.fromDomain(pedidoRepository.guardar(pedido), repartidor.getNombre());
+    }
+
+    /**
+     * @throws IllegalStateException si el pedido no tiene repartidor o ya salió
+     */
+    public RepartoResponse registrarSalida(LocalId localId, PedidoId pedidoId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Pedido pedido = buscarPedido(localId, pedidoId);
+
+        pedido.registrarSalida(LocalDateTime.now(clock));
+
+        return respuesta(localId, pedidoRepository.guardar(pedido));
+    }
+
+    /**
+     * @throws IllegalStateException si el pedido no salió o ya volvió
+     */
+    public RepartoResponse registrarRegreso(LocalId localId, PedidoId pedidoId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Pedido pedido = buscarPedido(localId, pedidoId);
+
+        pedido.registrarRegreso(LocalDateTime.now(clock));
+
+        return respuesta(localId, pedidoRepository.guardar(pedido));
+    }
+
+    private Pedido buscarPedido(LocalId localId, PedidoId pedidoId) {
+        Objects.requireNonNull(pedidoId, "El pedidoId es obligatorio");
+        return pedidoRepository.buscarPorId(pedidoId)
+            .filter(p -> p.getLocalId().equals(localId))
+            .orElseThrow(() -> new IllegalArgumentException(
+                "No se encontró el pedido con ID: " + pedidoId.getValue()
+            ));
+    }
+
+    private RepartoResponse respuesta(LocalId localId, Pedido pedido) {
+        String nombre = pedido.getRepartidorId() != null
+            ? nombreDe(mozoRepository.buscarPorId(pedido.getRepartidorId(), localId).orElse(null))
+            : null;
+        return RepartoResponse.fromDomain(pedido, nombre);
+    }
+
+    private static String nombreDe(Mozo repartidor) {
+        return repartidor != null ? repartidor.getNombre() : null;
+    }
+}
@@ -0,0 +1,85 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.InsumoResponse;
+import com.agustinpalma.comandas.application.dto.OrdenCompraSugeridaResponse;
+import com.agustinpalma.comandas.application.dto.ReposicionInsumoRequest;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+import com.agustinpalma.comandas.domain.model.Insumo;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
+import com.agustinpalma.comandas.domain.repository.ProveedorRepository;
+import com.agustinpalma.comandas.domain.service.InventarioService;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * HU-134: Caso de uso de las órdenes de compra automáticas por stock mínimo.
+ *
+ * Cada insumo puede tener un stock mínimo y un proveedor habitual. Cuando el
+ * stock teórico llega al mínimo, el insumo aparece en la orden sugerida de su
+ * proveedor. Las órdenes no se guardan: se editan y se envían desde la pantalla.
+ */
+@Transactional
+public class GestionarReposicionUseCase {
+
+    private final InsumoRepository insumoRepository;
+    private final ProveedorRepository proveedorRepository;
+    private final InventarioService inventarioService;
+
+    public GestionarReposicionUseCase(
+            InsumoRepository insumoRepository,
+            ProveedorRepository proveedorRepository,
+            InventarioService inventarioService
+    ) {
+        this.insumoRepository = Objects.requireNonNull(insumoRepository, "El insumoRepository es obligatorio");
+        this.proveedorRepository = Objects.requireNonNull(proveedorRepository, "El proveedorRepository es obligatorio");
+        this.inventarioService = Objects.requireNonNull(inventarioService, "El inventarioService es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si el insumo o el proveedor no existen en el local,
+     *                                  o el mínimo es inválido
+     * @throws IllegalStateException si el proveedor está inactivo
+     */
+    public InsumoResponse definirReposicion(LocalId localId, InsumoId insumoId, ReposicionInsumoRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(insumoId, "El insumoId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Insumo insumo = insumoRepository.buscarPorId(insumoId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("El insumo no existe en este local"));
+
+        ProveedorId proveedorId = null;
+        if (request.proveedorHabitualId() != null) {
+            proveedorId = new ProveedorId(request.proveedorHabitualId());
+            boolean activo = proveedorRepository.buscarPorId(proveedorId, localId)
+                .orElseThrow(() -> new IllegalArgumentException("El proveedor no existe en este local"))
+                .isActivo();
+            if (!activo && !proveedorId.equals(insumo.getProveedorHabitualId())) {
+                throw new IllegalStateException("El proveedor está inactivo");
+            }
+        }
+
+        insumo.definirReposicion(request.stockMinimo(), proveedorId);
+        return InsumoResponse.fromDomain(insumoRepository.guardar(insumo));
+    }
+
+    /**
+     * Órdenes sugeridas con los insumos que llegaron al mínimo, agrupadas por
+     * proveedor habitual. Lista vacía si no hay nada para reponer.
+     */
+    @Transactional(readOnly = true)
+    public List<OrdenCompraSugeridaResponse> sugerirOrdenes(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        return inventarioService.sugerirOrdenesCompra(
+                insumoRepository.buscarPorLocal(localId),
+                proveedorRepository.buscarPorLocal(localId)
+            ).stream()
+            .map(OrdenCompraSugeridaResponse::fromDomain)
+            .toList();
+    }
+}
@@ -0,0 +1,188 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReservaRequest;
+import com.agustinpalma.comandas.application.dto.ReservaResponse;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimiento;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ReservaId;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Reserva;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.ReservaRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.format.DateTimeFormatter;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.stream.Collectors;
+
+/**
+ * HU-142: Toma las reservas de mesa, cobra su seña y la aplica a la cuenta.
+ *
+ * La seña entra a la caja como un INGRESO del día y del turno en que se cobra.
+ * Cuando el cliente llega, la reserva se sienta en el pedido de su mesa (sola
+ * al abrir la mesa reservada, o a mano desde el listado) y la seña se descuenta
+ * del total: al cerrar la mesa solo se cobra la diferencia.
+ */
+@Transactional
+public class GestionarReservasUseCase {
+
+    private static final DateTimeFormatter FORMATO_RESERVA = DateTimeFormatter.ofPattern("dd/MM HH:mm");
+
+    private final ReservaRepository reservaRepository;
+    private final MesaRepository mesaRepository;
+    private final PedidoRepository pedidoRepository;
+    private final MovimientoCajaRepository movimientoCajaRepository;
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final Clock clock;
+
+    public GestionarReservasUseCase(ReservaRepository reservaRepository, MesaRepository mesaRepository,
+                                    PedidoRepository pedidoRepository, MovimientoCajaRepository movimientoCajaRepository,
+                                    TurnoCajaRepository turnoCajaRepository, Clock clock) {
+        this.reservaRepository = Objects.requireNonNull(reservaRepository, "El reservaRepository es obligatorio");
+        this.mesaRepository = Objects.requireNonNull(mesaRepository, "El mesaRepository es obligatorio");
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.movimientoCajaRepository = Objects.requireNonNull(movimientoCajaRepository,
+            "El movimientoCajaRepository es obligatorio");
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository, "El turnoCajaRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @return reservas de los días [desde, hasta], por horario
+     * @throws IllegalArgumentException si el rango está invertido
+     */
+    @Transactional(readOnly = true)
+    public List<ReservaResponse> listar(LocalId localId, LocalDate desde, LocalDate hasta) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(desde, "La fecha desde es obligatoria");
+        Objects.requireNonNull(hasta, "La fecha hasta es obligatoria");
+        if (hasta.isBefore(desde)) {
+            throw new IllegalArgumentException("La fecha hasta no puede ser anterior a la fecha desde");
+        }
+
+        Map<MesaId, Integer> numeros = mesaRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Mesa::getId, Mesa::getNumero));
+        return reservaRepository.buscarEntre(localId, desde.atStartOfDay(), hasta.plusDays(1).atStartOfDay())
+            .stream()
+            .map(reserva -> ReservaResponse.fromDomain(reserva, numeros.get(reserva.getMesaId())))
+            .toList();
+    }
+
+    /**
+     * Toma la reserva y, si el cliente deja seña, la cobra en el momento.
+     *
+     * @throws IllegalArgumentException si la mesa no existe en el local o los datos son inválidos
+     */
+    public ReservaResponse crear(LocalId localId, ReservaRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Mesa mesa = request.mesaId() != null ? buscarMesa(localId, new MesaId(request.mesaId())) : null;
+        Reserva reserva = Reserva.crear(localId, request.nombreCliente(), request.telefono(), request.fechaHora(),
+            request.personas(), request.observaciones(), mesa != null ? mesa.getId() : null);
+
+        if (request.senia() != null && request.senia().signum() > 0) {
+            cobrarSenia(reserva, request.senia());
+        }
+
+        return ReservaResponse.fromDomain(reservaRepository.guardar(reserva), mesa != null ? mesa.getNumero() : null);
+    }
+
+    /**
+     * @throws IllegalStateException si la reserva no está pendiente o ya tiene seña
+     */
+    public ReservaResponse cobrarSenia(LocalId localId, ReservaId reservaId, BigDecimal monto) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(monto, "El monto de la seña es obligatorio");
+        Reserva reserva = buscarReserva(localId, reservaId);
+
+        cobrarSenia(reserva, monto);
+
+        return respuesta(reservaRepository.guardar(reserva));
+    }
+
+    /**
+     * Sienta la reserva en una mesa que ya se abrió: vincula la reserva al
+     * pedido y le descuenta la seña.
+     *
+     * @throws IllegalArgumentException si la mesa no existe en el local
+     * @throws IllegalStateException si la mesa no tiene pedido abierto, el pedido ya tiene reserva
+     *                               o la reserva no está pendiente
+     */
+    public ReservaResponse sentar(LocalId localId, ReservaId reservaId, MesaId mesaId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Reserva reserva = buscarReserva(localId, reservaId);
+        Mesa mesa = buscarMesa(localId, Objects.requireNonNull(mesaId, "La mesa es obligatoria"));
+        Pedido pedido = pedidoRepository.buscarAbiertoPorMesa(mesa.getId(), localId)
+            .orElseThrow(() -> new IllegalStateException(
+                "Abrí la mesa " + mesa.getNumero() + " antes de sentar la reserva"));
+
+        reserva.sentar(pedido);
+
+        pedidoRepository.guardar(pedido);
+        return ReservaResponse.fromDomain(reservaRepository.guardar(reserva), mesa.getNumero());
+    }
+
+    /**
+     * La seña cobrada no se devuelve: queda en la caja del día en que entró.
+     *
+     * @throws IllegalStateException si la reserva ya se cumplió o se canceló
+     */
+    public ReservaResponse cancelar(LocalId localId, ReservaId reservaId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Reserva reserva = buscarReserva(localId, reservaId);
+
+        reserva.cancelar();
+
+        return respuesta(reservaRepository.guardar(reserva));
+    }
+
+    private void cobrarSenia(Reserva reserva, BigDecimal monto) {
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        MovimientoCaja ingreso = new MovimientoCaja(
+            MovimientoCajaId.generate(),
+            reserva.getLocalId(),
+            monto,
+            "Seña reserva " + reserva.getNombreCliente() + " " + reserva.getFechaHora().format(FORMATO_RESERVA),
+            ahora,
+            TipoMovimiento.INGRESO,
+            turnoCajaRepository.buscarAbierto(reserva.getLocalId()).map(TurnoCaja::getId).orElse(null)
+        );
+
+        reserva.registrarSenia(monto, ingreso.getId(), ahora);
+        movimientoCajaRepository.guardar(ingreso);
+    }
+
+    private Reserva buscarReserva(LocalId localId, ReservaId reservaId) {
+        Objects.requireNonNull(reservaId, "El reservaId es obligatorio");
+        return reservaRepository.buscarPorId(reservaId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("La reserva no existe en este local"));
+    }
+
+    private Mesa buscarMesa(LocalId localId, MesaId mesaId) {
+        return mesaRepository.buscarPorId(mesaId)
+            .filter(mesa -> mesa.getLocalId().equals(localId))
+            .orElseThrow(() -> new IllegalArgumentException("La mesa no existe en este local"));
+    }
+
+    private ReservaResponse respuesta(Reserva reserva) {
+        Integer numero = reserva.getMesaId() != null
+            ? mesaRepository.buscarPorId(reserva.getMesaId()).map(Mesa::getNumero).orElse(null)
+            : null;
+        return ReservaResponse.fromDomain(reserva, numero);
+    }
+}
@@ -0,0 +1,173 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.SerieComprobantesResponse;
+import com.agustinpalma.comandas.domain.model.Caja;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.SerieComprobantes;
+import com.agustinpalma.comandas.domain.repository.CajaRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.SerieComprobantesRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.Comparator;
+import java.util.List;
+import java.util.Objects;
+import java.util.Optional;
+
+/**
+ * HU-166: Caso de uso para numerar los comprobantes de cada caja.
+ *
+ * - Cada caja (y la principal) puede tener su serie: prefijo, próximo número
+ *   y si reinicia con el año operativo.
+ * - El número se asigna al cerrar el pedido, dentro de la transacción del
+ *   cierre y con la serie bloqueada: dos cajas que cierran a la vez no se
+ *   llevan el mismo número y, si el cierre falla, el número no se consume.
+ * - Dos series del local no pueden compartir prefijo (numerarían igual).
+ * - El próximo número tiene que superar al último comprobante emitido con el
+ *   mismo prefijo, aunque lo haya emitido otra configuración: si no, el
+ *   cierre que lo repita choca con el índice único y ya no cierra ninguna mesa.
+ * - Una caja sin serie cierra sin número de comprobante, como hasta ahora.
+ */
+@Transactional
+public class GestionarSeriesComprobantesUseCase {
+
+    private final SerieComprobantesRepository serieComprobantesRepository;
+    private final CajaRepository cajaRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
+    private final PedidoRepository pedidoRepository;
+    private final Clock clock;
+
+    public GestionarSeriesComprobantesUseCase(SerieComprobantesRepository serieComprobantesRepository,
+                                              CajaRepository cajaRepository,
+                                              JornadaComercialRepository jornadaComercialRepository,
+                                              PedidoRepository pedidoRepository,
+                                              Clock clock) {
+        this.serieComprobantesRepository = Objects.requireNonNull(serieComprobantesRepository,
+            "El serieComprobantesRepository es obligatorio");
+        this.cajaRepository = Objects.requireNonNull(cajaRepository, "El cajaRepository es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository,
+            "El jornadaComercialRepository es obligatorio");
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @return series del local: primero la de la caja principal y después las demás por nombre de caja
+     */
+    @Transactional(readOnly = true)
+    public List<SerieComprobantesResponse> listar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        LocalDate hoy = fechaOperativa(localId, LocalDateTime.now(clock));
+        return serieComprobantesRepository.buscarPorLocal(localId).stream()
+            .map(serie -> SerieComprobantesResponse.fromDomain(serie, nombreCaja(serie.getCajaId(), localId), hoy))
+            .sorted(Comparator.comparing((SerieComprobantesResponse s) -> s.cajaId() != null)
+                .thenComparing(SerieComprobantesResponse::caja))
+            .toList();
+    }
+
+    /**
+     * Crea o cambia la serie de una caja.
+     *
+     * @param cajaId caja de la serie (null = caja principal)
+     * @throws IllegalArgumentException si la caja no existe, el prefijo lo usa otra serie
+     *                                  o el próximo número repetiría un comprobante ya emitido
+     */
+    public SerieComprobantesResponse configurar(LocalId localId, CajaId cajaId, String prefijo,
+                                                long proximoNumero, boolean reinicioAnual) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        String nombreCaja = cajaId != null
+            ? cajaRepository.buscarPorId(cajaId, localId)
+                .map(Caja::getNombre)
+                .orElseThrow(() -> new IllegalArgumentException("La caja no existe en este local"))
+            : GestionarCajasUseCase.NOMBRE_CAJA_PRINCIPAL;
+
+        Optional<SerieComprobantes> existente = serieComprobantesRepository.buscar(localId, cajaId);
+        SerieComprobantes serie;
+        if (existente.isPresent()) {
+            serie = existente.get();
+            serie.reconfigurar(prefijo, proximoNumero, reinicioAnual);
+        } else {
+            serie = new SerieComprobantes(localId, cajaId, prefijo, proximoNumero, reinicioAnual, null);
+        }
+
+        serieComprobantesRepository.buscarPorLocal(localId).stream()
+            .filter(otra -> !Objects.equals(otra.getCajaId(), cajaId))
+            .filter(otra -> otra.getPrefijo().equals(serie.getPrefijo()))
+            .findFirst()
+            .ifPresent(otra -> {
+                throw new IllegalArgumentException("El prefijo " + serie.getPrefijo()
+                    + " ya lo usa la serie de " + nombreCaja(otra.getCajaId(), localId));
+            });
+
+        LocalDate hoy = fechaOperativa(localId, LocalDateTime.now(clock));
+        serie.verificarPosteriorA(
+            pedidoRepository.buscarUltimoComprobante(localId, serie.encabezado(hoy)).orElse(null), hoy);
+
+        SerieComprobantes guardada = serieComprobantesRepository.guardar(serie);
+        return SerieComprobantesResponse.fromDomain(guardada, nombreCaja, hoy);
+    }
+
+    /**
+     * Numera el pedido que se cierra con la serie de la caja que lo cobró. Un
+     * pedido reabierto conserva el número que ya tenía. Se llama desde el
+     * cierre de la mesa, en su misma transacción.
+     *
+     * @param cierre momento del cierre (el año sale de su fecha operativa)
+     * @throws IllegalStateException si la serie llegó al último número
+     */
+    public void numerar(Pedido pedido, LocalDateTime cierre) {
+        Objects.requireNonNull(pedido, "El pedido es obligatorio");
+        if (pedido.getNumeroComprobante() != null) {
+            return;
+        }
+        String numero = asignarNumero(pedido.getLocalId(), pedido.getCajaId(), cierre);
+        if (numero != null) {
+            pedido.asignarNumeroComprobante(numero);
+        }
+    }
+
+    /**
+     * Toma el próximo número de la serie de la caja que cobra.
+     *
+     * @param cajaId caja que cobra (null = caja principal)
+     * @param cierre momento del cierre (el año sale de su fecha operativa)
+     * @return el número de comprobante, o null si la caja no tiene serie
+     * @throws IllegalStateException si la serie llegó al último número
+     */
+    public String asignarNumero(LocalId localId, CajaId cajaId, LocalDateTime cierre) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(cierre, "El momento del cierre es obligatorio");
+
+        Optional<SerieComprobantes> serie = serieComprobantesRepository.buscarParaAsignar(localId, cajaId);
+        if (serie.isEmpty()) {
+            return null;
+        }
+        String numero = serie.get().asignar(fechaOperativa(localId, cierre));
+        serieComprobantesRepository.guardar(serie.get());
+        return numero;
+    }
+
+    private LocalDate fechaOperativa(LocalId localId, LocalDateTime momento) {
+        return jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId))
+            .fechaOperativa(momento);
+    }
+
+    private String nombreCaja(CajaId cajaId, LocalId localId) {
+        if (cajaId == null) {
+            return GestionarCajasUseCase.NOMBRE_CAJA_PRINCIPAL;
+        }
+        return cajaRepository.buscarPorId(cajaId, localId)
+            .map(Caja::getNombre)
+            .orElse("Caja " + cajaId);
+    }
+}
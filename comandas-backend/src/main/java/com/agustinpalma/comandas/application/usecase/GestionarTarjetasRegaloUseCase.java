@@ -0,0 +1,202 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.EmitirTarjetaRegaloRequest;
+import com.agustinpalma.comandas.application.dto.PagoRequest;
+import com.agustinpalma.comandas.application.dto.ReporteTarjetasRegaloResponse;
+import com.agustinpalma.comandas.application.dto.TarjetaRegaloResponse;
+import com.agustinpalma.comandas.domain.model.ConsumoTarjetaRegalo;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoTarjetaRegalo;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimiento;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.TarjetaRegalo;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.TarjetaRegaloRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+
+/**
+ * HU-144: Vende tarjetas de regalo, valida su código al cobrar y reporta
+ * los vouchers emitidos, usados y vencidos.
+ *
+ * La venta de la tarjeta entra a la caja como un INGRESO del día y del turno
+ * en que se vende. Cuando se usa para pagar un pedido no vuelve a sumar
+ * efectivo: el pago queda con medio TARJETA_REGALO y descuenta el saldo
+ * al cerrar la mesa ({@link #cobrar}).
+ */
+@Transactional
+public class GestionarTarjetasRegaloUseCase {
+
+    private static final int INTENTOS_CODIGO = 5;
+
+    private final TarjetaRegaloRepository tarjetaRegaloRepository;
+    private final MovimientoCajaRepository movimientoCajaRepository;
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final Clock clock;
+
+    public GestionarTarjetasRegaloUseCase(TarjetaRegaloRepository tarjetaRegaloRepository,
+                                          MovimientoCajaRepository movimientoCajaRepository,
+                                          TurnoCajaRepository turnoCajaRepository, Clock clock) {
+        this.tarjetaRegaloRepository = Objects.requireNonNull(tarjetaRegaloRepository,
+            "El tarjetaRegaloRepository es obligatorio");
+        this.movimientoCajaRepository = Objects.requireNonNull(movimientoCajaRepository,
+            "El movimientoCajaRepository es obligatorio");
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository, "El turnoCajaRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * Vende una tarjeta: genera un código que no exista en el local y cobra
+     * el monto como ingreso de caja.
+     *
+     * @throws IllegalArgumentException si el monto o el vencimiento son inválidos
+     */
+    public TarjetaRegaloResponse emitir(LocalId localId, EmitirTarjetaRegaloRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+        LocalDateTime ahora = LocalDateTime.now(clock);
+
+        MovimientoCajaId ingresoId = MovimientoCajaId.generate();
+        TarjetaRegalo tarjeta = null;
+        for (int intento = 0; intento < INTENTOS_CODIGO && tarjeta == null; intento++) {
+            TarjetaRegalo candidata = TarjetaRegalo.emitir(localId, request.monto(), request.vencimiento(),
+                request.comprador(), request.destinatario(), ingresoId, ahora);
+            if (!tarjetaRegaloRepository.existeCodigo(candidata.getCodigo(), localId)) {
+                tarjeta = candidata;
+            }
+        }
+        if (tarjeta == null) {
+            throw new IllegalStateException("No se pudo generar un código único para la tarjeta de regalo");
+        }
+
+        movimientoCajaRepository.guardar(new MovimientoCaja(
+            ingresoId,
+            localId,
+            tarjeta.getMontoInicial(),
+            "Venta tarjeta de regalo " + tarjeta.getCodigo(),
+            ahora,
+            TipoMovimiento.INGRESO,
+            turnoCajaRepository.buscarAbierto(localId).map(TurnoCaja::getId).orElse(null)
+        ));
+
+        return TarjetaRegaloResponse.fromDomain(tarjetaRegaloRepository.guardar(tarjeta), ahora.toLocalDate());
+    }
+
+    /**
+     * Valida el código antes de cobrar: devuelve el saldo y si se puede usar hoy.
+     *
+     * @throws IllegalArgumentException si el código no existe en el local
+     */
+    @Transactional(readOnly = true)
+    public TarjetaRegaloResponse consultar(LocalId localId, String codigo) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        String normalizado = TarjetaRegalo.normalizarCodigo(codigo);
+        TarjetaRegalo tarjeta = tarjetaRegaloRepository.buscarPorCodigo(normalizado, localId)
+            .orElseThrow(() -> new IllegalArgumentException("La tarjeta de regalo " + normalizado + " no existe"));
+        return TarjetaRegaloResponse.fromDomain(tarjeta, LocalDate.now(clock));
+    }
+
+    /**
+     * Valida el código de cada pago TARJETA_REGALO del cierre y descuenta el
+     * monto del saldo. Una misma tarjeta puede venir en más de un pago. Se
+     * llama desde el cierre de la mesa, en su misma transacción.
+     *
+     * @throws IllegalArgumentException si falta el código, no existe en el local
+     *                                  o se indica un código en un pago de otro medio
+     * @throws IllegalStateException si la tarjeta venció o no le alcanza el saldo
+     */
+    public void cobrar(Pedido pedido, List<PagoRequest> pagos, LocalDateTime fecha) {
+        Objects.requireNonNull(pedido, "El pedido es obligatorio");
+        Objects.requireNonNull(pagos, "La lista de pagos es obligatoria");
+        Map<String, TarjetaRegalo> tarjetas = new LinkedHashMap<>();
+        for (PagoRequest pago : pagos) {
+            boolean conCodigo = pago.codigoTarjetaRegalo() != null && !pago.codigoTarjetaRegalo().isBlank();
+            if (pago.medio() != MedioPago.TARJETA_REGALO) {
+                if (conCodigo) {
+                    throw new IllegalArgumentException("El código de tarjeta de regalo va solo en pagos con tarjeta de regalo");
+                }
+                continue;
+            }
+            if (!conCodigo) {
+                throw new IllegalArgumentException("Falta el código de la tarjeta de regalo");
+            }
+            String codigo = TarjetaRegalo.normalizarCodigo(pago.codigoTarjetaRegalo());
+            TarjetaRegalo tarjeta = tarjetas.computeIfAbsent(codigo, c ->
+                tarjetaRegaloRepository.buscarPorCodigo(c, pedido.getLocalId())
+                    .orElseThrow(() -> new IllegalArgumentException("La tarjeta de regalo " + c + " no existe")));
+            tarjeta.consumir(pedido.getId(), pago.monto(), fecha);
+        }
+        tarjetas.values().forEach(tarjetaRegaloRepository::guardar);
+    }
+
+    /**
+     * Emitidas y usadas cuentan si la venta o el uso cayó en [desde, hasta].
+     * Vencidas son las que vencieron en el rango (y ya están vencidas) con saldo sin usar.
+     *
+     * @throws IllegalArgumentException si el rango está invertido
+     */
+    @Transactional(readOnly = true)
+    public ReporteTarjetasRegaloResponse reporte(LocalId localId, LocalDate desde, LocalDate hasta) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(desde, "La fecha desde es obligatoria");
+        Objects.requireNonNull(hasta, "La fecha hasta es obligatoria");
+        if (hasta.isBefore(desde)) {
+            throw new IllegalArgumentException("La fecha hasta no puede ser anterior a la fecha desde");
+        }
+        LocalDate hoy = LocalDate.now(clock);
+
+        List<TarjetaRegalo> todas = tarjetaRegaloRepository.buscarPorLocal(localId);
+
+        List<TarjetaRegalo> emitidas = todas.stream()
+            .filter(t -> enRango(t.getFechaEmision().toLocalDate(), desde, hasta))
+            .toList();
+        List<ConsumoTarjetaRegalo> usos = todas.stream()
+            .flatMap(t -> t.getConsumos().stream())
+            .filter(c -> enRango(c.getFecha().toLocalDate(), desde, hasta))
+            .toList();
+        List<TarjetaRegalo> vencidas = todas.stream()
+            .filter(t -> t.getEstado(hoy) == EstadoTarjetaRegalo.VENCIDA)
+            .filter(t -> enRango(t.getVencimiento(), desde, hasta))
+            .toList();
+        BigDecimal saldoVigente = todas.stream()
+            .filter(t -> t.getEstado(hoy) == EstadoTarjetaRegalo.VIGENTE)
+            .map(TarjetaRegalo::getSaldo)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        List<TarjetaRegaloResponse> delRango = todas.stream()
+            .filter(t -> emitidas.contains(t) || vencidas.contains(t)
+                || t.getConsumos().stream().anyMatch(c -> enRango(c.getFecha().toLocalDate(), desde, hasta)))
+            .map(t -> TarjetaRegaloResponse.fromDomain(t, hoy))
+            .toList();
+
+        return new ReporteTarjetasRegaloResponse(
+            desde,
+            hasta,
+            emitidas.size(),
+            emitidas.stream().map(TarjetaRegalo::getMontoInicial).reduce(BigDecimal.ZERO, BigDecimal::add),
+            usos.size(),
+            usos.stream().map(ConsumoTarjetaRegalo::getMonto).reduce(BigDecimal.ZERO, BigDecimal::add),
+            vencidas.size(),
+            vencidas.stream().map(TarjetaRegalo::getSaldo).reduce(BigDecimal.ZERO, BigDecimal::add),
+            saldoVigente,
+            delRango
+        );
+    }
+
+    private static boolean enRango(LocalDate dia, LocalDate desde, LocalDate hasta) {
+        return !dia.isBefore(desde) && !dia.isAfter(hasta);
+    }
+}
@@ -0,0 +1,71 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.TiposCambioRequest;
+import com.agustinpalma.comandas.application.dto.TiposCambioResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.model.TiposCambio;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.TiposCambioRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * HU-151: Tipos de cambio del día para cobrar en dólares y euros.
+ *
+ * El encargado carga la cotización de cada moneda al abrir el día. "Hoy" es
+ * el día operativo de la caja: después de medianoche sigue valiendo la
+ * cotización del día que empezó. Los cambios solo afectan a los cobros que
+ * se hagan desde ahora; lo cobrado conserva el tipo de cambio de cada pago.
+ */
+@Transactional
+public class GestionarTiposCambioUseCase {
+
+    private final TiposCambioRepository tiposCambioRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
+    private final Clock clock;
+
+    public GestionarTiposCambioUseCase(TiposCambioRepository tiposCambioRepository,
+                                       JornadaComercialRepository jornadaComercialRepository, Clock clock) {
+        this.tiposCambioRepository = Objects.requireNonNull(tiposCambioRepository, "El tiposCambioRepository es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository, "El jornadaComercialRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @param fecha día operativo (null = hoy); sin cotizaciones cargadas viene vacío
+     */
+    @Transactional(readOnly = true)
+    public TiposCambioResponse consultar(LocalId localId, LocalDate fecha) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        LocalDate dia = fecha != null ? fecha : hoy(localId);
+
+        return TiposCambioResponse.fromDomain(tiposCambioRepository.buscarPorFecha(localId, dia)
+            .orElse(TiposCambio.sinCotizar(localId, dia)));
+    }
+
+    /**
+     * @throws IllegalArgumentException si una cotización es inválida o se cotiza el peso
+     */
+    public TiposCambioResponse guardar(LocalId localId, TiposCambioRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+        LocalDate dia = request.fecha() != null ? request.fecha() : hoy(localId);
+
+        TiposCambio tiposCambio = tiposCambioRepository.buscarPorFecha(localId, dia)
+            .orElse(TiposCambio.sinCotizar(localId, dia));
+        tiposCambio.actualizar(request.cotizaciones());
+
+        return TiposCambioResponse.fromDomain(tiposCambioRepository.guardar(tiposCambio));
+    }
+
+    private LocalDate hoy(LocalId localId) {
+        return jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId))
+            .fechaOperativa(LocalDateTime.now(clock));
+    }
+}
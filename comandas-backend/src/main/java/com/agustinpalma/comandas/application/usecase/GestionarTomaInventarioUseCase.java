@@ -0,0 +1,154 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.MovimientoInsumoResponse;
+import com.agustinpalma.comandas.application.dto.TomaInventarioRequest;
+import com.agustinpalma.comandas.application.dto.TomaInventarioResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TomaInventarioId;
+import com.agustinpalma.comandas.domain.model.Insumo;
+import com.agustinpalma.comandas.domain.model.TomaInventario;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoInsumoRepository;
+import com.agustinpalma.comandas.domain.repository.TomaInventarioRepository;
+import com.agustinpalma.comandas.domain.service.InventarioService;
+import com.agustinpalma.comandas.domain.service.InventarioService.ResultadoToma;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * HU-133: Caso de uso de la toma de inventario físico.
+ *
+ * El encargado carga lo que contó; primero se previsualiza el reporte de
+ * diferencias valorizado y, al confirmar, se guarda la toma y el stock
+ * teórico de cada insumo contado queda igual al conteo, con un movimiento
+ * de ajuste por cada diferencia.
+ */
+@Transactional
+public class GestionarTomaInventarioUseCase {
+
+    private final TomaInventarioRepository tomaInventarioRepository;
+    private final InsumoRepository insumoRepository;
+    private final MovimientoInsumoRepository movimientoInsumoRepository;
+    private final InventarioService inventarioService;
+    private final Clock clock;
+
+    public GestionarTomaInventarioUseCase(
+            TomaInventarioRepository tomaInventarioRepository,
+            InsumoRepository insumoRepository,
+            MovimientoInsumoRepository movimientoInsumoRepository,
+            InventarioService inventarioService,
+            Clock clock
+    ) {
+        this.tomaInventarioRepository = Objects.requireNonNull(tomaInventarioRepository, "El tomaInventarioRepository es obligatorio");
+        this.insumoRepository = Objects.requireNonNull(insumoRepository, "El insumoRepository es obligatorio");
+        this.movimientoInsumoRepository = Objects.requireNonNull(movimientoInsumoRepository, "El movimientoInsumoRepository es obligatorio");
+        this.inventarioService = Objects.requireNonNull(inventarioService, "El inventarioService es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * Reporte de diferencias del conteo contra el stock teórico, sin ajustar nada.
+     *
+     * @throws IllegalArgumentException si un insumo se contó dos veces, no existe o tiene un conteo inválido
+     */
+    @Transactional(readOnly = true)
+    public TomaInventarioResponse previsualizar(LocalId localId, TomaInventarioRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        TomaInventario borrador = TomaInventario.registrar(
+            localId,
+            request.responsable(),
+            inventarioService.compararConteo(conteos(request), insumosDelLocal(localId)),
+            LocalDateTime.now(clock)
+        );
+        return TomaInventarioResponse.previsualizacion(borrador);
+    }
+
+    /**
+     * Guarda la toma y ajusta el stock de los insumos contados.
+     *
+     * @throws IllegalArgumentException si un insumo se contó dos veces, no existe o tiene un conteo inválido
+     */
+    public TomaInventarioResponse confirmar(LocalId localId, TomaInventarioRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        ResultadoToma resultado = inventarioService.registrarToma(
+            localId,
+            request.responsable(),
+            conteos(request),
+            insumosDelLocal(localId),
+            LocalDateTime.now(clock)
+        );
+
+        resultado.insumosAjustados().forEach(insumoRepository::guardar);
+        resultado.movimientos().forEach(movimientoInsumoRepository::guardar);
+        return TomaInventarioResponse.fromDomain(tomaInventarioRepository.guardar(resultado.toma()));
+    }
+
+    @Transactional(readOnly = true)
+    public List<TomaInventarioResponse> listar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        return tomaInventarioRepository.buscarPorLocal(localId).stream()
+            .map(TomaInventarioResponse::fromDomain)
+            .toList();
+    }
+
+    /**
+     * @throws IllegalArgumentException si la toma no existe en el local
+     */
+    @Transactional(readOnly = true)
+    public TomaInventarioResponse consultar(LocalId localId, TomaInventarioId tomaId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(tomaId, "El tomaId es obligatorio");
+
+        return tomaInventarioRepository.buscarPorId(tomaId, localId)
+            .map(TomaInventarioResponse::fromDomain)
+            .orElseThrow(() -> new IllegalArgumentException("La toma de inventario no existe en este local"));
+    }
+
+    /**
+     * Historial de movimientos de stock de un insumo (ventas, reaperturas y ajustes).
+     *
+     * @throws IllegalArgumentException si el insumo no existe en el local
+     */
+    @Transactional(readOnly = true)
+    public List<MovimientoInsumoResponse> listarMovimientos(LocalId localId, InsumoId insumoId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(insumoId, "El insumoId es obligatorio");
+
+        if (insumoRepository.buscarPorId(insumoId, localId).isEmpty()) {
+            throw new IllegalArgumentException("El insumo no existe en este local");
+        }
+        return movimientoInsumoRepository.buscarPorInsumo(insumoId, localId).stream()
+            .map(MovimientoInsumoResponse::fromDomain)
+            .toList();
+    }
+
+    private Map<InsumoId, Insumo> insumosDelLocal(LocalId localId) {
+        return insumoRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Insumo::getId, Function.identity()));
+    }
+
+    private static Map<InsumoId, BigDecimal> conteos(TomaInventarioRequest request) {
+        Map<InsumoId, BigDecimal> conteos = new LinkedHashMap<>();
+        for (TomaInventarioRequest.ConteoRequest conteo : request.conteos()) {
+            if (conteos.put(new InsumoId(conteo.insumoId()), conteo.cantidad()) != null) {
+                throw new IllegalArgumentException("Hay un insumo contado dos veces");
+            }
+        }
+        return conteos;
+    }
+}
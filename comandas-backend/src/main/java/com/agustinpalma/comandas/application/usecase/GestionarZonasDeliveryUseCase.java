@@ -0,0 +1,137 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ProductoVendidoReporte;
+import com.agustinpalma.comandas.application.dto.ReporteEnviosResponse;
+import com.agustinpalma.comandas.application.dto.ReporteEnviosResponse.EnviosZona;
+import com.agustinpalma.comandas.application.dto.ZonaDeliveryRequest;
+import com.agustinpalma.comandas.application.dto.ZonaDeliveryResponse;
+import com.agustinpalma.comandas.application.ports.output.AnalyticsRepositoryPort;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ZonaDeliveryId;
+import com.agustinpalma.comandas.domain.model.ZonaDelivery;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.ZonaDeliveryRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * HU-139: Caso de uso para el ABM de zonas de reparto y el reporte de envíos.
+ */
+@Transactional
+public class GestionarZonasDeliveryUseCase {
+
+    private static final String PREFIJO_ITEM_ENVIO = "Envío ";
+
+    private final ZonaDeliveryRepository zonaDeliveryRepository;
+    private final AnalyticsRepositoryPort analyticsRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
+
+    public GestionarZonasDeliveryUseCase(ZonaDeliveryRepository zonaDeliveryRepository,
+                                         AnalyticsRepositoryPort analyticsRepository,
+                                         JornadaComercialRepository jornadaComercialRepository) {
+        this.zonaDeliveryRepository = Objects.requireNonNull(zonaDeliveryRepository, "El zonaDeliveryRepository es obligatorio");
+        this.analyticsRepository = Objects.requireNonNull(analyticsRepository, "El analyticsRepository es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository, "El jornadaComercialRepository es obligatorio");
+    }
+
+    /**
+     * @return zonas del local (activas e inactivas) ordenadas por nombre
+     */
+    @Transactional(readOnly = true)
+    public List<ZonaDeliveryResponse> listar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+
+        return zonaDeliveryRepository.buscarPorLocal(localId).stream()
+            .map(ZonaDeliveryResponse::fromDomain)
+            .toList();
+    }
+
+    /**
+     * @throws IllegalArgumentException si ya existe una zona con ese nombre
+     */
+    public ZonaDeliveryResponse crear(LocalId localId, ZonaDeliveryRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        validarNombreLibre(localId, request.nombre(), null);
+        ZonaDelivery zona = ZonaDelivery.crear(localId, request.nombre(), request.costoEnvio(),
+            request.montoMinimo(), request.barrios());
+        return ZonaDeliveryResponse.fromDomain(zonaDeliveryRepository.guardar(zona));
+    }
+
+    /**
+     * Edita la zona o la activa/desactiva. Los pedidos abiertos conservan el
+     * costo de envío que ya tenían cargado.
+     *
+     * @throws IllegalArgumentException si la zona no existe en el local o el nombre ya está usado
+     */
+    public ZonaDeliveryResponse actualizar(LocalId localId, ZonaDeliveryId zonaId, ZonaDeliveryRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(zonaId, "El zonaId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        ZonaDelivery zona = zonaDeliveryRepository.buscarPorId(zonaId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("La zona de reparto no existe en este local"));
+        validarNombreLibre(localId, request.nombre(), zonaId);
+
+        zona.actualizar(request.nombre(), request.costoEnvio(), request.montoMinimo(), request.barrios(),
+            request.activa() == null || request.activa());
+        return ZonaDeliveryResponse.fromDomain(zonaDeliveryRepository.guardar(zona));
+    }
+
+    /**
+     * Envíos cobrados en pedidos cerrados entre las dos jornadas comerciales
+     * (inclusive, HU-153), junto a la venta de productos del mismo rango.
+     *
+     * @throws IllegalArgumentException si hasta es anterior a desde
+     */
+    @Transactional(readOnly = true)
+    public ReporteEnviosResponse reporteEnvios(LocalId localId, LocalDate desde, LocalDate hasta) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(desde, "La fecha desde es obligatoria");
+        Objects.requireNonNull(hasta, "La fecha hasta es obligatoria");
+        if (hasta.isBefore(desde)) {
+            throw new IllegalArgumentException("La fecha hasta no puede ser anterior a la fecha desde");
+        }
+
+        JornadaComercial jornada = jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId));
+        LocalDateTime inicio = jornada.inicio(desde);
+        LocalDateTime fin = jornada.inicio(hasta.plusDays(1));
+
+        List<EnviosZona> zonas = analyticsRepository.obtenerEnviosPorZonaEntre(inicio, fin, localId).stream()
+            .map(fila -> new EnviosZona(nombreZona(fila.productoNombre()), fila.cantidadTotal(), fila.totalRecaudado()))
+            .toList();
+        BigDecimal ventaProductos = analyticsRepository.obtenerVentasPorProductoEntre(inicio, fin, localId).stream()
+            .map(ProductoVendidoReporte::totalRecaudado)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        return new ReporteEnviosResponse(
+            desde,
+            hasta,
+            zonas.stream().mapToLong(EnviosZona::envios).sum(),
+            zonas.stream().map(EnviosZona::total).reduce(BigDecimal.ZERO, BigDecimal::add),
+            ventaProductos,
+            zonas
+        );
+    }
+
+    private void validarNombreLibre(LocalId localId, String nombre, ZonaDeliveryId excepto) {
+        boolean repetido = zonaDeliveryRepository.buscarPorLocal(localId).stream()
+            .filter(z -> !z.getId().equals(excepto))
+            .anyMatch(z -> z.tieneNombre(nombre));
+        if (repetido) {
+            throw new IllegalArgumentException("Ya existe una zona llamada \"" + nombre.trim() + "\"");
+        }
+    }
+
+    private static String nombreZona(String nombreItem) {
+        return nombreItem.startsWith(PREFIJO_ITEM_ENVIO) ? nombreItem.substring(PREFIJO_ITEM_ENVIO.length()) : nombreItem;
+    }
+}
@@ -0,0 +1,40 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.AlertaMesasOlvidadasRequest;
+import com.agustinpalma.comandas.application.dto.AlertaMesasOlvidadasResponse;
+import com.agustinpalma.comandas.domain.model.AlertaMesasOlvidadas;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.repository.AlertaMesasOlvidadasRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso para activar, desactivar o cambiar el umbral de la alerta
+ * de mesas olvidadas.
+ *
+ * HU-113: El cambio rige para todas las terminales en su próxima consulta.
+ */
+@Transactional
+public class GuardarAlertaMesasOlvidadasUseCase {
+
+    private final AlertaMesasOlvidadasRepository alertaRepository;
+
+    public GuardarAlertaMesasOlvidadasUseCase(AlertaMesasOlvidadasRepository alertaRepository) {
+        this.alertaRepository = Objects.requireNonNull(alertaRepository, "El alertaRepository es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si el umbral está fuera de rango
+     */
+    public AlertaMesasOlvidadasResponse ejecutar(LocalId localId, AlertaMesasOlvidadasRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        AlertaMesasOlvidadas alerta = alertaRepository.buscarPorLocal(localId)
+            .orElse(AlertaMesasOlvidadas.porDefecto(localId));
+        alerta.actualizar(request.activa(), request.minutosSinActividad());
+
+        return AlertaMesasOlvidadasResponse.fromDomain(alertaRepository.guardar(alerta));
+    }
+}
@@ -0,0 +1,99 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.DisposicionSalonRequest;
+import com.agustinpalma.comandas.application.dto.MesaResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.ArrayList;
+import java.util.HashMap;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+
+/**
+ * Caso de uso para guardar el mapa del salón desde el modo edición.
+ *
+ * HU-112: El encargado mueve, numera y agrupa mesas por sector y guarda
+ * todo junto. La disposición vive en la base, así que todas las terminales
+ * del local la ven en el próximo refresco del listado de mesas.
+ *
+ * HU-149: Junto con la ubicación se guarda la capacidad de cada mesa, que
+ * usa la lista de espera para sugerir mesas.
+ *
+ * Se valida el estado final del local completo (no solo lo enviado):
+ * - Los números de mesa siguen siendo únicos (permite intercambiarlos).
+ * - Dos mesas no pueden ocupar la misma celda de un mismo sector.
+ */
+@Transactional
+public class GuardarDisposicionSalonUseCase {
+
+    private final MesaRepository mesaRepository;
+
+    public GuardarDisposicionSalonUseCase(MesaRepository mesaRepository) {
+        this.mesaRepository = Objects.requireNonNull(mesaRepository, "El mesaRepository es obligatorio");
+    }
+
+    /**
+     * @return todas las mesas del local con su ubicación actualizada
+     * @throws IllegalArgumentException si una mesa no es del local, está repetida,
+     *         o la disposición final repite números o celdas
+     */
+    public List<MesaResponse> ejecutar(LocalId localId, DisposicionSalonRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Map<MesaId, Mesa> mesasDelLocal = new LinkedHashMap<>();
+        mesaRepository.buscarPorLocal(localId).forEach(m -> mesasDelLocal.put(m.getId(), m));
+
+        List<Mesa> editadas = new ArrayList<>();
+        for (DisposicionSalonRequest.UbicacionMesa ubicacion : request.mesas()) {
+            Mesa mesa = mesasDelLocal.get(new MesaId(ubicacion.mesaId()));
+            if (mesa == null) {
+                throw new IllegalArgumentException("No se encontró la mesa con ID: " + ubicacion.mesaId());
+            }
+            if (editadas.contains(mesa)) {
+                throw new IllegalArgumentException(
+                    "La mesa " + mesa.getNumero() + " aparece más de una vez en la disposición");
+            }
+            mesa.renumerar(ubicacion.numero());
+            mesa.ubicar(ubicacion.sector(), ubicacion.posicionX(), ubicacion.posicionY());
+            mesa.definirCapacidad(ubicacion.capacidad());
+            editadas.add(mesa);
+        }
+
+        validarDisposicion(mesasDelLocal.values());
+
+        mesaRepository.guardarTodas(editadas);
+
+        return mesaRepository.buscarPorLocal(localId).stream()
+            .map(MesaResponse::fromDomain)
+            .toList();
+    }
+
+    private void validarDisposicion(Iterable<Mesa> mesas) {
+        Map<Integer, Mesa> porNumero = new HashMap<>();
+        Map<String, Mesa> porCelda = new HashMap<>();
+
+        for (Mesa mesa : mesas) {
+            if (porNumero.putIfAbsent(mesa.getNumero(), mesa) != null) {
+                throw new IllegalArgumentException(
+                    "Ya existe una mesa con el número " + mesa.getNumero() + " en este local");
+            }
+            if (mesa.getPosicionX() == null) {
+                continue;
+            }
+            String celda = mesa.getSector().toLowerCase() + "@" + mesa.getPosicionX() + "," + mesa.getPosicionY();
+            Mesa ocupante = porCelda.putIfAbsent(celda, mesa);
+            if (ocupante != null) {
+                throw new IllegalArgumentException(String.format(
+                    "Las mesas %d y %d ocupan el mismo lugar en %s",
+                    ocupante.getNumero(), mesa.getNumero(), mesa.getSector()));
+            }
+        }
+    }
+}
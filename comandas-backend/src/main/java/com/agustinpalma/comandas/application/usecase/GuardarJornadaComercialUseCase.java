@@ -0,0 +1,44 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.JornadaComercialRequest;
+import com.agustinpalma.comandas.application.dto.JornadaComercialResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Caso de uso para configurar el límite de la jornada comercial.
+ *
+ * HU-153: La jornada de caja abierta conserva su fecha operativa; el nuevo
+ * corte vale para las jornadas que se abran y los reportes desde ahora.
+ */
+@Transactional
+public class GuardarJornadaComercialUseCase {
+
+    private final JornadaComercialRepository jornadaComercialRepository;
+    private final Clock clock;
+
+    public GuardarJornadaComercialUseCase(JornadaComercialRepository jornadaComercialRepository, Clock clock) {
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository, "El jornadaComercialRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si la hora de corte es del mediodía en adelante
+     */
+    public JornadaComercialResponse ejecutar(LocalId localId, JornadaComercialRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        JornadaComercial jornada = jornadaComercialRepository.buscarPorLocal(localId)
+            .orElse(JornadaComercial.porDefecto(localId));
+        jornada.actualizar(request.horaCorte());
+
+        return JornadaComercialResponse.fromDomain(jornadaComercialRepository.guardar(jornada), LocalDateTime.now(clock));
+    }
+}
@@ -0,0 +1,85 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ListaPreciosRequest;
+import com.agustinpalma.comandas.application.dto.ListaPreciosResponse;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainIds.ListaPreciosId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.ListaPrecios;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.ListaPreciosRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.util.HashMap;
+import java.util.Map;
+import java.util.Objects;
+import java.util.UUID;
+
+/**
+ * Caso de uso para crear o reconfigurar la lista de precios de un canal.
+ *
+ * HU-108: Hay una sola lista por canal, así que guardar es un upsert.
+ * Los pedidos abiertos con esta lista cotizan los próximos ítems con los
+ * valores nuevos; los ítems ya cargados conservan su precio snapshot.
+ */
+@Transactional
+public class GuardarListaPreciosUseCase {
+
+    private final ListaPreciosRepository listaPreciosRepository;
+    private final ProductoRepository productoRepository;
+
+    public GuardarListaPreciosUseCase(
+            ListaPreciosRepository listaPreciosRepository,
+            ProductoRepository productoRepository
+    ) {
+        this.listaPreciosRepository = Objects.requireNonNull(listaPreciosRepository, "El listaPreciosRepository es obligatorio");
+        this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si un producto no existe en el local,
+     *         está repetido o los valores de la lista son inválidos
+     */
+    public ListaPreciosResponse ejecutar(LocalId localId, CanalVenta canal, ListaPreciosRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(canal, "El canal es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Map<ProductoId, Producto> productos = new HashMap<>();
+        Map<ProductoId, BigDecimal> precios = new HashMap<>();
+        if (request.precios() != null) {
+            for (ListaPreciosRequest.PrecioProducto item : request.precios()) {
+                ProductoId productoId = new ProductoId(item.productoId());
+                Producto producto = productoRepository.buscarPorIdYLocal(productoId, localId)
+                    .orElseThrow(() -> new IllegalArgumentException(
+                        "No se encontró el producto con ID: " + item.productoId()
+                    ));
+                if (precios.put(productoId, item.precio()) != null) {
+                    throw new IllegalArgumentException(
+                        String.format("El producto '%s' tiene más de un precio en la lista", producto.getNombre())
+                    );
+                }
+                productos.put(productoId, producto);
+            }
+        }
+
+        ListaPrecios lista = listaPreciosRepository.buscarPorLocalYCanal(localId, canal)
+            .map(existente -> {
+                existente.actualizar(request.nombre(), request.porcentajeRecargo(), precios);
+                return existente;
+            })
+            .orElseGet(() -> new ListaPrecios(
+                new ListaPreciosId(UUID.randomUUID()),
+                localId,
+                canal,
+                request.nombre(),
+                request.porcentajeRecargo(),
+                precios
+            ));
+
+        return ConsultarListasPreciosUseCase.toResponse(listaPreciosRepository.guardar(lista), productos);
+    }
+}
@@ -0,0 +1,40 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.PlanCuentasContableRequest;
+import com.agustinpalma.comandas.application.dto.PlanCuentasContableResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.PlanCuentasContable;
+import com.agustinpalma.comandas.domain.repository.PlanCuentasContableRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso para asignar las cuentas contables del local.
+ *
+ * HU-167: Las cuentas valen para cualquier sistema contable; se aplican a
+ * las próximas exportaciones.
+ */
+@Transactional
+public class GuardarPlanCuentasContableUseCase {
+
+    private final PlanCuentasContableRepository planCuentasContableRepository;
+
+    public GuardarPlanCuentasContableUseCase(PlanCuentasContableRepository planCuentasContableRepository) {
+        this.planCuentasContableRepository = Objects.requireNonNull(planCuentasContableRepository, "El planCuentasContableRepository es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si un código de cuenta es inválido
+     */
+    public PlanCuentasContableResponse ejecutar(LocalId localId, PlanCuentasContableRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        PlanCuentasContable plan = planCuentasContableRepository.buscarPorLocal(localId)
+            .orElse(PlanCuentasContable.sinCuentas(localId));
+        plan.actualizar(request.cuentas());
+
+        return PlanCuentasContableResponse.fromDomain(planCuentasContableRepository.guardar(plan));
+    }
+}
@@ -0,0 +1,41 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ProgramaPuntosRequest;
+import com.agustinpalma.comandas.application.dto.ProgramaPuntosResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.ProgramaPuntos;
+import com.agustinpalma.comandas.domain.repository.ProgramaPuntosRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso para activar, desactivar o reconfigurar el programa de puntos.
+ *
+ * HU-105: Los cambios solo afectan a los pedidos que se cierren desde ahora.
+ * Los puntos ya ganados conservan su vencimiento original y se canjean
+ * al valor de punto vigente al momento del canje.
+ */
+@Transactional
+public class GuardarProgramaPuntosUseCase {
+
+    private final ProgramaPuntosRepository programaPuntosRepository;
+
+    public GuardarProgramaPuntosUseCase(ProgramaPuntosRepository programaPuntosRepository) {
+        this.programaPuntosRepository = Objects.requireNonNull(programaPuntosRepository, "El programaPuntosRepository es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si algún parámetro del programa es inválido
+     */
+    public ProgramaPuntosResponse ejecutar(LocalId localId, ProgramaPuntosRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        ProgramaPuntos programa = programaPuntosRepository.buscarPorLocal(localId)
+            .orElse(ProgramaPuntos.porDefecto(localId));
+        programa.actualizar(request.activo(), request.montoPorPunto(), request.valorPunto(), request.diasVencimiento());
+
+        return ProgramaPuntosResponse.fromDomain(programaPuntosRepository.guardar(programa));
+    }
+}
@@ -0,0 +1,40 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.PromoCumpleaniosRequest;
+import com.agustinpalma.comandas.application.dto.PromoCumpleaniosResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.PromoCumpleanios;
+import com.agustinpalma.comandas.domain.repository.PromoCumpleaniosRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso para activar, desactivar o reconfigurar la promo de cumpleaños.
+ *
+ * HU-168: Los cambios valen para los pedidos que se cobren desde ahora;
+ * las promos ya aplicadas quedan con el monto del día del cobro.
+ */
+@Transactional
+public class GuardarPromoCumpleaniosUseCase {
+
+    private final PromoCumpleaniosRepository promoCumpleaniosRepository;
+
+    public GuardarPromoCumpleaniosUseCase(PromoCumpleaniosRepository promoCumpleaniosRepository) {
+        this.promoCumpleaniosRepository = Objects.requireNonNull(promoCumpleaniosRepository, "El promoCumpleaniosRepository es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si el porcentaje o el tope son inválidos
+     */
+    public PromoCumpleaniosResponse ejecutar(LocalId localId, PromoCumpleaniosRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        PromoCumpleanios promo = promoCumpleaniosRepository.buscarPorLocal(localId)
+            .orElse(PromoCumpleanios.porDefecto(localId));
+        promo.actualizar(request.activa(), request.porcentaje(), request.topeDescuento());
+
+        return PromoCumpleaniosResponse.fromDomain(promoCumpleaniosRepository.guardar(promo));
+    }
+}
@@ -0,0 +1,40 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.RecargosMedioPagoRequest;
+import com.agustinpalma.comandas.application.dto.RecargosMedioPagoResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.RecargosMedioPago;
+import com.agustinpalma.comandas.domain.repository.RecargosMedioPagoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso para configurar los recargos por medio de pago.
+ *
+ * HU-143: Los cambios solo afectan a los cobros que se hagan desde ahora;
+ * los pedidos ya cerrados conservan el recargo con el que se cobraron.
+ */
+@Transactional
+public class GuardarRecargosMedioPagoUseCase {
+
+    private final RecargosMedioPagoRepository recargosMedioPagoRepository;
+
+    public GuardarRecargosMedioPagoUseCase(RecargosMedioPagoRepository recargosMedioPagoRepository) {
+        this.recargosMedioPagoRepository = Objects.requireNonNull(recargosMedioPagoRepository, "El recargosMedioPagoRepository es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si un porcentaje es inválido o el medio no admite recargo
+     */
+    public RecargosMedioPagoResponse ejecutar(LocalId localId, RecargosMedioPagoRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        RecargosMedioPago recargos = recargosMedioPagoRepository.buscarPorLocal(localId)
+            .orElse(RecargosMedioPago.sinRecargos(localId));
+        recargos.actualizar(request.porcentajes());
+
+        return RecargosMedioPagoResponse.fromDomain(recargosMedioPagoRepository.guardar(recargos));
+    }
+}
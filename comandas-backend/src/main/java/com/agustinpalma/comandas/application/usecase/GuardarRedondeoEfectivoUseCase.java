@@ -0,0 +1,40 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.RedondeoEfectivoRequest;
+import com.agustinpalma.comandas.application.dto.RedondeoEfectivoResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.RedondeoEfectivo;
+import com.agustinpalma.comandas.domain.repository.RedondeoEfectivoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso para configurar el redondeo del efectivo.
+ *
+ * HU-152: Los cambios solo afectan a los cobros que se hagan desde ahora;
+ * los pedidos ya cerrados conservan el ajuste con el que se cobraron.
+ */
+@Transactional
+public class GuardarRedondeoEfectivoUseCase {
+
+    private final RedondeoEfectivoRepository redondeoEfectivoRepository;
+
+    public GuardarRedondeoEfectivoUseCase(RedondeoEfectivoRepository redondeoEfectivoRepository) {
+        this.redondeoEfectivoRepository = Objects.requireNonNull(redondeoEfectivoRepository, "El redondeoEfectivoRepository es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si el múltiplo es negativo, supera $1000 o tiene más de dos decimales
+     */
+    public RedondeoEfectivoResponse ejecutar(LocalId localId, RedondeoEfectivoRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        RedondeoEfectivo redondeo = redondeoEfectivoRepository.buscarPorLocal(localId)
+            .orElse(RedondeoEfectivo.sinRedondeo(localId));
+        redondeo.actualizar(request.multiplo(), request.modo());
+
+        return RedondeoEfectivoResponse.fromDomain(redondeoEfectivoRepository.guardar(redondeo));
+    }
+}
@@ -0,0 +1,40 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReglaPropinasRequest;
+import com.agustinpalma.comandas.application.dto.ReglaPropinasResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.ReglaPropinas;
+import com.agustinpalma.comandas.domain.repository.ReglaPropinasRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso para configurar cómo se reparte el pozo de propinas.
+ *
+ * HU-141: Los cambios solo afectan a los turnos que se cierren desde ahora;
+ * los repartos ya calculados no se recalculan.
+ */
+@Transactional
+public class GuardarReglaPropinasUseCase {
+
+    private final ReglaPropinasRepository reglaPropinasRepository;
+
+    public GuardarReglaPropinasUseCase(ReglaPropinasRepository reglaPropinasRepository) {
+        this.reglaPropinasRepository = Objects.requireNonNull(reglaPropinasRepository, "El reglaPropinasRepository es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si los puntos son inválidos o no participa ningún puesto
+     */
+    public ReglaPropinasResponse ejecutar(LocalId localId, ReglaPropinasRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        ReglaPropinas regla = reglaPropinasRepository.buscarPorLocal(localId)
+            .orElse(ReglaPropinas.porDefecto(localId));
+        regla.actualizar(request.modo(), request.puntosPorRol());
+
+        return ReglaPropinasResponse.fromDomain(reglaPropinasRepository.guardar(regla));
+    }
+}
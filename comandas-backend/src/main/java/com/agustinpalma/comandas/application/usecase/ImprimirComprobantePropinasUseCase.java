@@ -0,0 +1,102 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.domain.model.DistribucionPropinas;
+import com.agustinpalma.comandas.domain.model.DomainEnums.ModoRepartoPropinas;
+import com.agustinpalma.comandas.domain.model.DomainIds.DistribucionPropinasId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.PartePropina;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.repository.DistribucionPropinasRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import com.agustinpalma.comandas.infrastructure.adapter.EscPosGenerator;
+import com.agustinpalma.comandas.infrastructure.adapter.EscPosGenerator.ComprobantePropinasData;
+import com.agustinpalma.comandas.infrastructure.adapter.EscPosGenerator.ParteComprobanteData;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Base64;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * HU-141: Caso de uso para imprimir el comprobante de reparto de propinas de un turno.
+ *
+ * Operación de solo lectura. Lleva la parte de cada empleado con el detalle
+ * de su peso y una línea de firma por empleado.
+ */
+@Transactional(readOnly = true)
+public class ImprimirComprobantePropinasUseCase {
+
+    private final DistribucionPropinasRepository distribucionRepository;
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final MozoRepository mozoRepository;
+    private final MeisenProperties properties;
+
+    public ImprimirComprobantePropinasUseCase(
+        DistribucionPropinasRepository distribucionRepository,
+        TurnoCajaRepository turnoCajaRepository,
+        MozoRepository mozoRepository,
+        MeisenProperties properties
+    ) {
+        this.distribucionRepository = Objects.requireNonNull(distribucionRepository, "El distribucionRepository es obligatorio");
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository, "El turnoCajaRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.properties = Objects.requireNonNull(properties, "Las properties son obligatorias");
+    }
+
+    /**
+     * @return buffer ESC/POS codificado en Base64
+     * @throws IllegalStateException si el reparto o su turno no existen
+     */
+    public String ejecutar(LocalId localId, DistribucionPropinasId distribucionId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(distribucionId, "El distribucionId es obligatorio");
+
+        DistribucionPropinas distribucion = distribucionRepository.buscarPorId(distribucionId, localId)
+            .orElseThrow(() -> new IllegalStateException("No existe el reparto de propinas " + distribucionId.getValue()));
+        TurnoCaja turno = turnoCajaRepository.buscarPorId(distribucion.getTurnoId(), localId)
+            .orElseThrow(() -> new IllegalStateException("No existe el turno del reparto de propinas"));
+        Map<MozoId, Mozo> personal = mozoRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Mozo::getId, Function.identity()));
+
+        ComprobantePropinasData data = new ComprobantePropinasData(
+            properties.getLocal().getNombreLocal(),
+            turno.getTipo().name(),
+            turno.getFechaApertura(),
+            turno.getFechaCierre(),
+            describir(distribucion.getModo()),
+            distribucion.getPozo(),
+            distribucion.getSinRepartir(),
+            distribucion.getPartes().stream()
+                .map(parte -> toParte(parte, distribucion.getModo(), personal.get(parte.getMozoId())))
+                .toList()
+        );
+
+        return Base64.getEncoder().encodeToString(EscPosGenerator.generarComprobantePropinas(data));
+    }
+
+    private ParteComprobanteData toParte(PartePropina parte, ModoRepartoPropinas modo, Mozo empleado) {
+        String horas = String.format("%d h %02d min", parte.getMinutos() / 60, parte.getMinutos() % 60);
+        String detalle = modo == ModoRepartoPropinas.POR_PUESTO
+            ? horas + " - " + parte.getPeso() + (parte.getPeso() == 1 ? " punto" : " puntos")
+            : horas;
+        return new ParteComprobanteData(
+            empleado != null ? empleado.getNombre() : "Empleado eliminado",
+            detalle,
+            parte.getMonto()
+        );
+    }
+
+    private static String describir(ModoRepartoPropinas modo) {
+        return switch (modo) {
+            case PARTES_IGUALES -> "Partes iguales";
+            case POR_HORAS -> "Por horas trabajadas";
+            case POR_PUESTO -> "Por puesto";
+        };
+    }
+}
@@ -0,0 +1,112 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CondicionIva;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoFactura;
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.Factura;
+import com.agustinpalma.comandas.domain.model.ItemPedido;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.FacturaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.infrastructure.adapter.EscPosGenerator;
+import com.agustinpalma.comandas.infrastructure.adapter.EscPosGenerator.FacturaData;
+import com.agustinpalma.comandas.infrastructure.adapter.EscPosGenerator.TicketItemData;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.util.Base64;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * HU-115: Caso de uso para imprimir (o reimprimir) una factura en ESC/POS.
+ *
+ * Operación de solo lectura. Los importes salen de la factura, que congeló
+ * el total del pedido al emitirse; los ítems se toman del pedido cerrado.
+ * Una factura pendiente se imprime con la leyenda de comprobante no válido.
+ */
+@Transactional(readOnly = true)
+public class ImprimirFacturaUseCase {
+
+    private final FacturaRepository facturaRepository;
+    private final PedidoRepository pedidoRepository;
+    private final MeisenProperties properties;
+
+    public ImprimirFacturaUseCase(
+        FacturaRepository facturaRepository,
+        PedidoRepository pedidoRepository,
+        MeisenProperties properties
+    ) {
+        this.facturaRepository = Objects.requireNonNull(facturaRepository, "El facturaRepository es obligatorio");
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.properties = Objects.requireNonNull(properties, "Las properties son obligatorias");
+    }
+
+    /**
+     * @param reimpresion true para marcar el comprobante como reimpresión
+     * @return buffer ESC/POS codificado en Base64
+     * @throws IllegalStateException si la factura o su pedido no existen
+     */
+    public String ejecutar(LocalId localId, FacturaId facturaId, boolean reimpresion) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(facturaId, "El facturaId es obligatorio");
+
+        Factura factura = facturaRepository.buscarPorId(facturaId, localId)
+            .orElseThrow(() -> new IllegalStateException(
+                String.format("No existe una factura con ID %s", facturaId.getValue())
+            ));
+        Pedido pedido = pedidoRepository.buscarPorId(factura.getPedidoId())
+            .orElseThrow(() -> new IllegalStateException("No existe el pedido de la factura " + factura.numeroFormateado()));
+
+        List<TicketItemData> items = pedido.getItems().stream()
+            .map(this::toItem)
+            .toList();
+        BigDecimal descuentos = pedido.getMontoSubtotalFinal().subtract(factura.getImporteTotal());
+
+        FacturaData data = new FacturaData(
+            properties.getLocal().getNombreLocal(),
+            properties.getLocal().getDireccion(),
+            properties.getLocal().getCuit(),
+            describir(properties.getFiscal().getCondicionIva()),
+            factura.getTipo().name(),
+            factura.numeroFormateado(),
+            factura.getFechaEmision(),
+            factura.getReceptorRazonSocial(),
+            factura.getReceptorCuit() != null ? factura.getReceptorCuit().formateado() : null,
+            describir(factura.getReceptorCondicionIva()),
+            items,
+            descuentos.max(BigDecimal.ZERO),
+            factura.getTipo() == TipoFactura.A,
+            factura.getImporteNeto(),
+            factura.getAlicuotaIva(),
+            factura.getImporteIva(),
+            factura.getImporteTotal(),
+            factura.getCae(),
+            factura.getCaeVencimiento(),
+            reimpresion
+        );
+
+        return Base64.getEncoder().encodeToString(EscPosGenerator.generarFactura(data));
+    }
+
+    /**
+     * Una línea por ítem con los extras incluidos en el importe (precio de lista, sin descuentos).
+     */
+    private TicketItemData toItem(ItemPedido item) {
+        BigDecimal importe = item.calcularSubtotalLinea();
+        BigDecimal unitario = importe.divide(BigDecimal.valueOf(item.getCantidad()), 2, RoundingMode.HALF_UP);
+        return new TicketItemData(item.getCantidad(), item.getNombreProducto(), unitario, importe, List.of());
+    }
+
+    private static String describir(CondicionIva condicion) {
+        return switch (condicion) {
+            case RESPONSABLE_INSCRIPTO -> "IVA Responsable Inscripto";
+            case MONOTRIBUTO -> "Responsable Monotributo";
+            case EXENTO -> "IVA Exento";
+            case CONSUMIDOR_FINAL -> "Consumidor Final";
+        };
+    }
+}
@@ -0,0 +1,82 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.domain.model.ConsumoPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.ConsumoPersonalRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.service.ConsumoPersonalService;
+import com.agustinpalma.comandas.domain.service.ConsumoPersonalService.ConsumoImputado;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDateTime;
+import java.time.YearMonth;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Caso de uso: imputar al empleado el consumo interno de un pedido que se cierra.
+ *
+ * HU-132: Registra los pagos A_CUENTA del pedido como consumo del empleado y,
+ * si con él supera su tope mensual, arma el aviso para quien cierra la mesa.
+ * El mes es el de la jornada comercial (HU-153), igual que en el reporte: el
+ * cierre de la madrugada del 1° todavía cuenta en el mes anterior.
+ *
+ * Se llama desde el cierre de la mesa, en su misma transacción.
+ */
+@Transactional
+public class ImputarConsumoPersonalUseCase {
+
+    private final MozoRepository mozoRepository;
+    private final ConsumoPersonalRepository consumoPersonalRepository;
+    private final JornadaComercialRepository jornadaComercialRepository;
+    private final ConsumoPersonalService consumoPersonalService;
+
+    public ImputarConsumoPersonalUseCase(MozoRepository mozoRepository,
+                                         ConsumoPersonalRepository consumoPersonalRepository,
+                                         JornadaComercialRepository jornadaComercialRepository,
+                                         ConsumoPersonalService consumoPersonalService) {
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.consumoPersonalRepository = Objects.requireNonNull(consumoPersonalRepository, "El consumoPersonalRepository es obligatorio");
+        this.jornadaComercialRepository = Objects.requireNonNull(jornadaComercialRepository, "El jornadaComercialRepository es obligatorio");
+        this.consumoPersonalService = Objects.requireNonNull(consumoPersonalService, "El consumoPersonalService es obligatorio");
+    }
+
+    /**
+     * @param pedido pedido ya cerrado (con sus pagos registrados)
+     * @param empleadoId empleado que consumió (null = sin imputar)
+     * @param fecha momento del cierre
+     * @return el aviso de tope superado, o null
+     * @throws IllegalArgumentException si el empleado no existe o el pedido no tiene pagos A_CUENTA
+     * @throws IllegalStateException si el empleado está inactivo
+     */
+    public String imputar(Pedido pedido, MozoId empleadoId, LocalDateTime fecha) {
+        Objects.requireNonNull(pedido, "El pedido es obligatorio");
+        if (empleadoId == null) {
+            return null;
+        }
+        Mozo empleado = mozoRepository.buscarPorId(empleadoId, pedido.getLocalId())
+            .orElseThrow(() -> new IllegalArgumentException("El empleado no existe en este local"));
+
+        JornadaComercial jornada = jornadaComercialRepository.buscarPorLocal(pedido.getLocalId())
+            .orElse(JornadaComercial.porDefecto(pedido.getLocalId()));
+        YearMonth mes = jornada.mesOperativo(fecha);
+        List<ConsumoPersonal> consumosDelMes = consumoPersonalRepository.buscarPorEmpleado(
+            empleado.getId(), pedido.getLocalId(), jornada.inicio(mes), jornada.inicio(mes.plusMonths(1)));
+
+        return consumoPersonalService.imputarConsumo(pedido, empleado, consumosDelMes, fecha)
+            .map(imputado -> {
+                consumoPersonalRepository.guardar(imputado.consumo());
+                return imputado.superaTope() ? avisoTopeSuperado(empleado, imputado) : null;
+            })
+            .orElse(null);
+    }
+
+    private static String avisoTopeSuperado(Mozo empleado, ConsumoImputado imputado) {
+        return String.format("%s superó su tope de consumo del mes ($%s): lleva $%s, se descuentan $%s del sueldo",
+            empleado.getNombre(), empleado.getTopeConsumoMensual(), imputado.consumoDelMes(), imputado.excedente());
+    }
+}
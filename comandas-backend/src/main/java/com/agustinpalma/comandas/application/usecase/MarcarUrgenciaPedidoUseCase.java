@@ -0,0 +1,81 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.AgregarProductoResponse;
+import com.agustinpalma.comandas.domain.model.ComandaCocina;
+import com.agustinpalma.comandas.domain.model.DomainIds.ItemPedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.ComandaCocinaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * HU-137: Marca de urgencia en pedidos e ítems.
+ *
+ * Un ítem urgente (un plato a rehacer) se marca antes de enviarlo y sale
+ * destacado en su comanda. El pedido completo (cliente apurado) también puede
+ * marcarse después de enviado: las comandas que todavía están en cocina pasan
+ * a urgentes en el momento, sin reimprimir nada.
+ *
+ * Ninguna de las dos marcas recalcula precios ni promociones.
+ */
+@Transactional
+public class MarcarUrgenciaPedidoUseCase {
+
+    private final PedidoRepository pedidoRepository;
+    private final ComandaCocinaRepository comandaCocinaRepository;
+
+    public MarcarUrgenciaPedidoUseCase(PedidoRepository pedidoRepository,
+                                       ComandaCocinaRepository comandaCocinaRepository) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.comandaCocinaRepository = Objects.requireNonNull(comandaCocinaRepository, "El comandaCocinaRepository es obligatorio");
+    }
+
+    /**
+     * Marca o desmarca el pedido y sus comandas en preparación.
+     *
+     * @throws IllegalArgumentException si el pedido no existe en el local
+     * @throws IllegalStateException si el pedido no está ABIERTO
+     */
+    public AgregarProductoResponse marcarPedido(LocalId localId, PedidoId pedidoId, boolean urgente) {
+        Pedido pedido = obtenerPedido(localId, pedidoId);
+
+        pedido.marcarUrgente(urgente);
+        for (ComandaCocina comanda : comandaCocinaRepository.buscarPendientesDePedido(pedidoId, localId)) {
+            comanda.marcarUrgente(urgente);
+            comandaCocinaRepository.guardar(comanda);
+        }
+
+        return AgregarProductoResponse.fromDomain(pedidoRepository.guardar(pedido));
+    }
+
+    /**
+     * Marca o desmarca un ítem para su próximo envío a cocina.
+     *
+     * @throws IllegalArgumentException si el pedido o el ítem no existen
+     * @throws IllegalStateException si el pedido no está ABIERTO o el ítem ya salió completo a cocina
+     */
+    public AgregarProductoResponse marcarItem(LocalId localId, PedidoId pedidoId, ItemPedidoId itemId,
+                                              boolean urgente) {
+        Objects.requireNonNull(itemId, "El itemId es obligatorio");
+        Pedido pedido = obtenerPedido(localId, pedidoId);
+
+        pedido.marcarUrgenteItem(itemId, urgente);
+
+        return AgregarProductoResponse.fromDomain(pedidoRepository.guardar(pedido));
+    }
+
+    private Pedido obtenerPedido(LocalId localId, PedidoId pedidoId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(pedidoId, "El pedidoId es obligatorio");
+
+        return pedidoRepository.buscarPorId(pedidoId)
+            .filter(pedido -> pedido.getLocalId().equals(localId))
+            .orElseThrow(() -> new IllegalArgumentException(
+                "No se encontró el pedido con ID: " + pedidoId.getValue()
+            ));
+    }
+}
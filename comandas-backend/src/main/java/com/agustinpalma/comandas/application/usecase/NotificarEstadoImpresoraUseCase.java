@@ -0,0 +1,52 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.EstadoImpresoraEvento;
+import com.agustinpalma.comandas.application.ports.output.NotificadorTiempoRealPort;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.util.Objects;
+
+/**
+ * Caso de uso que avisa a las terminales un cambio de estado de una impresora.
+ *
+ * HU-158: La app de escritorio vigila la impresora de cada estación (papel,
+ * tapa, conexión). Cuando una cambia, el aviso se difunde a todas las
+ * terminales del local: la que está en la caja ve el problema aunque la
+ * impresora esté en la cocina.
+ */
+public class NotificarEstadoImpresoraUseCase {
+
+    private static final int LARGO_MAXIMO_ESTACION = 40;
+    private static final int LARGO_MAXIMO_DETALLE = 500;
+
+    private final NotificadorTiempoRealPort notificador;
+
+    public NotificarEstadoImpresoraUseCase(NotificadorTiempoRealPort notificador) {
+        this.notificador = Objects.requireNonNull(notificador, "El notificador es obligatorio");
+    }
+
+    /**
+     * @throws IllegalArgumentException si falta la estación o es demasiado larga
+     */
+    public void ejecutar(LocalId localId, EstadoImpresoraEvento evento) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(evento, "El estado de la impresora es obligatorio");
+        Objects.requireNonNull(evento.estado(), "El estado de la impresora es obligatorio");
+        if (evento.estacion() == null || evento.estacion().isBlank()) {
+            throw new IllegalArgumentException("La estación de la impresora es obligatoria");
+        }
+        String estacion = evento.estacion().trim();
+        if (estacion.length() > LARGO_MAXIMO_ESTACION) {
+            throw new IllegalArgumentException(
+                "La estación no puede superar los " + LARGO_MAXIMO_ESTACION + " caracteres");
+        }
+        // El detalle puede traer el error del SO: se recorta, no se rechaza
+        String detalle = evento.detalle() == null ? null : evento.detalle().strip();
+        if (detalle != null && detalle.length() > LARGO_MAXIMO_DETALLE) {
+            detalle = detalle.substring(0, LARGO_MAXIMO_DETALLE);
+        }
+
+        notificador.publicar(localId, EstadoImpresoraEvento.NOMBRE,
+            new EstadoImpresoraEvento(estacion, evento.estado(), detalle));
+    }
+}
@@ -36,9 +36,25 @@ public class ObtenerReporteVentasUseCase {
      * @return lista de {@link ProductoVendidoReporte} ordenada por total descendente
      */
     public List<ProductoVendidoReporte> ejecutar(LocalId localId, LocalDate fecha) {
+        return ejecutar(localId, fecha, false);
+    }
+
+    /**
+     * HU-103: Ejecuta la consulta eligiendo cómo tratar los combos.
+     *
+     * @param localId          tenant del local
+     * @param fecha            fecha operativa del reporte
+     * @param desglosarCombos  true = cada combo suma a los productos elegidos en sus componentes;
+     *                         false = el combo se reporta como una unidad
+     * @return lista de {@link ProductoVendidoReporte} ordenada por total descendente
+     */
+    public List<ProductoVendidoReporte> ejecutar(LocalId localId, LocalDate fecha, boolean desglosarCombos) {
         Objects.requireNonNull(localId, "El localId es obligatorio");
         Objects.requireNonNull(fecha, "La fecha del reporte es obligatoria");
 
+        if (desglosarCombos) {
+            return analyticsRepository.obtenerVentasPorProductoDesglosandoCombos(fecha, localId);
+        }
         return analyticsRepository.obtenerVentasPorProducto(fecha, localId);
     }
 }
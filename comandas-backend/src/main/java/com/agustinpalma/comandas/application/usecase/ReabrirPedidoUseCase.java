@@ -1,27 +1,45 @@
 package com.agustinpalma.comandas.application.usecase;
 
 import com.agustinpalma.comandas.application.dto.ReabrirPedidoResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.ExtraPedido;
+import com.agustinpalma.comandas.domain.model.Insumo;
 import com.agustinpalma.comandas.domain.model.ItemPedido;
 import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.MovimientoInsumo;
 import com.agustinpalma.comandas.domain.model.MovimientoStock;
 import com.agustinpalma.comandas.domain.model.Pedido;
 import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.model.Receta;
+import com.agustinpalma.comandas.domain.repository.ConsumoPersonalRepository;
+import com.agustinpalma.comandas.domain.repository.FacturaRepository;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
 import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCuentaCorrienteRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoInsumoRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoPuntosRepository;
 import com.agustinpalma.comandas.domain.repository.MovimientoStockRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
 import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.domain.repository.RecetaRepository;
+import com.agustinpalma.comandas.domain.repository.TarjetaRegaloRepository;
 import com.agustinpalma.comandas.domain.service.GestorStockService;
 import com.agustinpalma.comandas.domain.service.GestorStockService.ResultadoStock;
+import com.agustinpalma.comandas.domain.service.InventarioService;
+import com.agustinpalma.comandas.domain.service.InventarioService.ResultadoInsumos;
 import org.springframework.transaction.annotation.Transactional;
 
 import java.time.Clock;
 import java.time.LocalDateTime;
 import java.util.Map;
 import java.util.Objects;
+import java.util.Optional;
+import java.util.function.Function;
 import java.util.stream.Collectors;
+import java.util.stream.Stream;
 
 /**
  * HU-14: Caso de uso para reabrir un pedido previamente cerrado.
@@ -39,14 +57,24 @@ import java.util.stream.Collectors;
  * - Mesa: LIBRE → ABIERTA
  * - Snapshot contable: eliminado (montos vuelven a null)
  * - Pagos: eliminados físicamente (orphanRemoval en JPA)
+ * - HU-104: cargo en cuenta corriente del cliente, si lo hubo: eliminado
+ * - HU-105: puntos canjeados y ganados por el pedido: eliminados (el saldo vuelve atrás)
+ * - HU-132: consumo interno imputado a un empleado, si lo hubo: eliminado
+ * 
+ * HU-115: Un pedido facturado no se reabre.
  * 
  * Flujo:
  * 1. Buscar el Pedido por ID y validar tenant
  * 2. Buscar la Mesa asociada al pedido
  * 3. HU-22: Revertir stock ANTES de reabrir (el pedido aún tiene sus ítems)
+ *    HU-133: Devolver los insumos de las recetas (con la receta vigente)
  * 4. pedido.reabrir() → revierte estado y limpia snapshot/pagos
  * 5. mesa.reocupar() → devuelve la mesa a ABIERTA
  * 6. Persistir cambios (transacción atómica)
+ * 7. HU-104: Anular el cargo en cuenta corriente del pedido (si lo hubo)
+ * 8. HU-105: Anular los movimientos de puntos del pedido
+ * 9. HU-132: Anular el consumo interno imputado al empleado
+ * 10. HU-144: Devolver a las tarjetas de regalo el saldo usado en el pedido
  * 
  * ADVERTENCIA: Esta operación es destructiva. Los pagos previos se eliminan.
  * Solo debe usarse para correcciones excepcionales antes del cierre de caja.
@@ -59,6 +87,15 @@ public class ReabrirPedidoUseCase {
     private final ProductoRepository productoRepository;
     private final MovimientoStockRepository movimientoStockRepository;
     private final GestorStockService gestorStockService;
+    private final MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository;
+    private final MovimientoPuntosRepository movimientoPuntosRepository;
+    private final FacturaRepository facturaRepository;
+    private final ConsumoPersonalRepository consumoPersonalRepository;
+    private final RecetaRepository recetaRepository;
+    private final InsumoRepository insumoRepository;
+    private final MovimientoInsumoRepository movimientoInsumoRepository;
+    private final InventarioService inventarioService;
+    private final TarjetaRegaloRepository tarjetaRegaloRepository;
     private final Clock clock;
 
     public ReabrirPedidoUseCase(
@@ -67,6 +104,15 @@ public class ReabrirPedidoUseCase {
             ProductoRepository productoRepository,
             MovimientoStockRepository movimientoStockRepository,
             GestorStockService gestorStockService,
+            MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository,
+            MovimientoPuntosRepository movimientoPuntosRepository,
+            FacturaRepository facturaRepository,
+            ConsumoPersonalRepository consumoPersonalRepository,
+            RecetaRepository recetaRepository,
+            InsumoRepository insumoRepository,
+            MovimientoInsumoRepository movimientoInsumoRepository,
+            InventarioService inventarioService,
+            TarjetaRegaloRepository tarjetaRegaloRepository,
             Clock clock
     ) {
         this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
@@ -74,6 +120,15 @@ public class ReabrirPedidoUseCase {
         this.productoRepository = Objects.requireNonNull(productoRepository, "El productoRepository es obligatorio");
         this.movimientoStockRepository = Objects.requireNonNull(movimientoStockRepository, "El movimientoStockRepository es obligatorio");
         this.gestorStockService = Objects.requireNonNull(gestorStockService, "El gestorStockService es obligatorio");
+        this.movimientoCuentaCorrienteRepository = Objects.requireNonNull(movimientoCuentaCorrienteRepository, "El movimientoCuentaCorrienteRepository es obligatorio");
+        this.movimientoPuntosRepository = Objects.requireNonNull(movimientoPuntosRepository, "El movimientoPuntosRepository es obligatorio");
+        this.facturaRepository = Objects.requireNonNull(facturaRepository, "El facturaRepository es obligatorio");
+        this.consumoPersonalRepository = Objects.requireNonNull(consumoPersonalRepository, "El consumoPersonalRepository es obligatorio");
+        this.recetaRepository = Objects.requireNonNull(recetaRepository, "El recetaRepository es obligatorio");
+        this.insumoRepository = Objects.requireNonNull(insumoRepository, "El insumoRepository es obligatorio");
+        this.movimientoInsumoRepository = Objects.requireNonNull(movimientoInsumoRepository, "El movimientoInsumoRepository es obligatorio");
+        this.inventarioService = Objects.requireNonNull(inventarioService, "El inventarioService es obligatorio");
+        this.tarjetaRegaloRepository = Objects.requireNonNull(tarjetaRegaloRepository, "El tarjetaRegaloRepository es obligatorio");
         this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
     }
 
@@ -83,7 +138,7 @@ public class ReabrirPedidoUseCase {
      * @param localId identificador del local (tenant)
      * @param pedidoId identificador del pedido a reabrir
      * @return DTO con la información del pedido reabierto y la mesa reocupada
-     * @throws IllegalStateException si el pedido no existe, no está CERRADO, o la mesa no está LIBRE
+     * @throws IllegalStateException si el pedido no existe, no está CERRADO, ya fue facturado o la mesa no está LIBRE
      * @throws IllegalArgumentException si el pedido no pertenece al local
      */
     public ReabrirPedidoResponse ejecutar(LocalId localId, PedidoId pedidoId) {
@@ -104,6 +159,14 @@ public class ReabrirPedidoUseCase {
             );
         }
 
+        // HU-115: La factura ya informó el importe al fisco
+        facturaRepository.buscarPorPedido(pedidoId, localId).ifPresent(factura -> {
+            throw new IllegalStateException(String.format(
+                "El pedido tiene la factura %s %s emitida y no se puede reabrir",
+                factura.getTipo(), factura.numeroFormateado()
+            ));
+        });
+
         // 3. Buscar la mesa asociada
         Mesa mesa = mesaRepository.buscarPorId(pedido.getMesaId())
             .orElseThrow(() -> new IllegalStateException(
@@ -117,6 +180,7 @@ public class ReabrirPedidoUseCase {
         // 5. HU-22: Revertir stock ANTES de reabrir
         //    (el pedido aún está CERRADO y tiene sus ítems intactos)
         revertirStockPorReapertura(pedido, localId, ahora);
+        revertirInsumosPorReapertura(pedido, localId, ahora);
 
         // 6. Reabrir el pedido (valida estado CERRADO, limpia snapshot y pagos)
         pedido.reabrir(ahora);
@@ -128,7 +192,22 @@ public class ReabrirPedidoUseCase {
         pedidoRepository.guardar(pedido);
         mesaRepository.guardar(mesa);
 
-        // 9. Retornar DTO de respuesta
+        // 9. HU-104: El pedido deja de estar cobrado, su cargo en cuenta corriente se anula
+        movimientoCuentaCorrienteRepository.eliminarCargosPorPedido(pedidoId, localId);
+
+        // 10. HU-105: El canje y la acumulación se rehacen en el próximo cierre
+        movimientoPuntosRepository.eliminarPorPedido(pedidoId, localId);
+
+        // 10b. HU-132: Se vuelve a imputar en el próximo cierre
+        consumoPersonalRepository.eliminarPorPedido(pedidoId, localId);
+
+        // 10c. HU-144: El saldo usado vuelve a la tarjeta; se consume de nuevo al cerrar
+        tarjetaRegaloRepository.buscarUsadasEnPedido(pedidoId, localId).forEach(tarjeta -> {
+            tarjeta.reintegrar(pedidoId);
+            tarjetaRegaloRepository.guardar(tarjeta);
+        });
+
+        // 11. Retornar DTO de respuesta
         return ReabrirPedidoResponse.fromDomain(mesa, pedido, ahora);
     }
 
@@ -159,4 +238,33 @@ public class ReabrirPedidoUseCase {
             movimientoStockRepository.guardar(movimiento);
         }
     }
+
+    /**
+     * HU-133: Devuelve al stock teórico los insumos que descontó la venta.
+     */
+    private void revertirInsumosPorReapertura(Pedido pedido, LocalId localId, LocalDateTime fecha) {
+        // HU-148: También las recetas de los extras ("extra queso")
+        Map<ProductoId, Receta> recetas = pedido.getItems().stream()
+            .flatMap(item -> Stream.concat(Stream.of(item.getProductoId()),
+                item.getExtras().stream().map(ExtraPedido::getProductoId)))
+            .distinct()
+            .map(productoId -> recetaRepository.buscarPorProducto(productoId, localId))
+            .flatMap(Optional::stream)
+            .collect(Collectors.toMap(Receta::getProductoId, Function.identity()));
+
+        if (recetas.isEmpty()) {
+            return;
+        }
+
+        Map<InsumoId, Insumo> insumos = insumoRepository.buscarPorLocal(localId).stream()
+            .collect(Collectors.toMap(Insumo::getId, Function.identity()));
+        ResultadoInsumos resultado = inventarioService.revertirConsumo(pedido, recetas, insumos, fecha);
+
+        for (Insumo insumo : resultado.insumosModificados()) {
+            insumoRepository.guardar(insumo);
+        }
+        for (MovimientoInsumo movimiento : resultado.movimientos()) {
+            movimientoInsumoRepository.guardar(movimiento);
+        }
+    }
 }
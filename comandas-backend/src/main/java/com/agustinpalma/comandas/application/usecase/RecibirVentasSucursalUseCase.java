@@ -0,0 +1,87 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.LoteVentasSucursalDTO;
+import com.agustinpalma.comandas.application.dto.LoteVentasSucursalDTO.VentaCerradaDTO;
+import com.agustinpalma.comandas.application.dto.RecepcionVentasResponse;
+import com.agustinpalma.comandas.application.ports.output.IdentidadSucursalProvider;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.SucursalId;
+import com.agustinpalma.comandas.domain.model.VentaSucursal;
+import com.agustinpalma.comandas.domain.repository.VentaSucursalRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+
+/**
+ * Caso de uso: recibir en el consolidado un lote de ventas de otra sucursal.
+ *
+ * HU-107: Multi-sucursal con reportes consolidados.
+ *
+ * Las ventas se guardan aparte (VentaSucursal): no generan movimientos de
+ * caja ni tocan jornadas o stock de esta instalación. Reenviar el mismo
+ * lote reemplaza las ventas existentes, por lo que reintentar es seguro.
+ */
+@Transactional
+public class RecibirVentasSucursalUseCase {
+
+    private final VentaSucursalRepository ventaSucursalRepository;
+    private final IdentidadSucursalProvider identidadSucursalProvider;
+    private final Clock clock;
+
+    public RecibirVentasSucursalUseCase(VentaSucursalRepository ventaSucursalRepository,
+                                        IdentidadSucursalProvider identidadSucursalProvider,
+                                        Clock clock) {
+        this.ventaSucursalRepository = Objects.requireNonNull(ventaSucursalRepository,
+            "El ventaSucursalRepository es obligatorio");
+        this.identidadSucursalProvider = Objects.requireNonNull(identidadSucursalProvider,
+            "El identidadSucursalProvider es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @param localId tenant que consolida
+     * @param lote    ventas enviadas por la sucursal de origen
+     * @return cantidad recibida y cursor para la próxima sincronización
+     * @throws IllegalArgumentException si el lote no identifica a su sucursal
+     * @throws IllegalStateException si el lote proviene de esta misma instalación
+     */
+    public RecepcionVentasResponse ejecutar(LocalId localId, LoteVentasSucursalDTO lote) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(lote, "El lote de ventas es obligatorio");
+        if (lote.sucursalId() == null) {
+            throw new IllegalArgumentException("El lote debe indicar la sucursal de origen");
+        }
+
+        SucursalId origen = new SucursalId(lote.sucursalId());
+        if (origen.equals(identidadSucursalProvider.getSucursalId())) {
+            throw new IllegalStateException(
+                "El lote proviene de esta misma sucursal. Revise la URL del consolidado en la configuración de sincronización.");
+        }
+
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        List<VentaSucursal> ventas = lote.ventas().stream()
+            .map(venta -> new VentaSucursal(
+                venta.pedidoId(),
+                localId,
+                origen,
+                lote.nombreSucursal(),
+                venta.numeroPedido(),
+                venta.fechaCierre(),
+                venta.pagos() != null ? venta.pagos() : Map.of(),
+                ahora
+            ))
+            .toList();
+
+        ventaSucursalRepository.guardarTodas(ventas);
+
+        return lote.ventas().stream()
+            .filter(venta -> venta.fechaCierre() != null && venta.pedidoId() != null)
+            .max(VentaCerradaDTO.ORDEN_CURSOR)
+            .map(ultima -> new RecepcionVentasResponse(ventas.size(), ultima.fechaCierre(), ultima.pedidoId()))
+            .orElseGet(() -> new RecepcionVentasResponse(ventas.size(), null, null));
+    }
+}
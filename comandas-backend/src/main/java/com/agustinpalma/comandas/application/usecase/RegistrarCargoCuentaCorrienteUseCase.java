@@ -0,0 +1,59 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.domain.model.Cliente;
+import com.agustinpalma.comandas.domain.model.MovimientoCuentaCorriente;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.MovimientoCuentaCorrienteRepository;
+import com.agustinpalma.comandas.domain.service.CuentaCorrienteService;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Caso de uso para cargar a la cuenta corriente del cliente lo que se cobró
+ * con ese medio al cerrar la mesa.
+ *
+ * HU-104: Cuenta corriente de clientes. Valida el límite de crédito contra la
+ * deuda actual y registra el cargo. Se llama desde el cierre de la mesa, en
+ * su misma transacción.
+ */
+@Transactional
+public class RegistrarCargoCuentaCorrienteUseCase {
+
+    private final MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository;
+    private final CuentaCorrienteService cuentaCorrienteService;
+
+    public RegistrarCargoCuentaCorrienteUseCase(
+            MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository,
+            CuentaCorrienteService cuentaCorrienteService
+    ) {
+        this.movimientoCuentaCorrienteRepository = Objects.requireNonNull(movimientoCuentaCorrienteRepository, "El movimientoCuentaCorrienteRepository es obligatorio");
+        this.cuentaCorrienteService = Objects.requireNonNull(cuentaCorrienteService, "El cuentaCorrienteService es obligatorio");
+    }
+
+    /**
+     * Si no hay pagos a cuenta corriente, no hace nada (el cliente solo suma puntos).
+     *
+     * @param pedido pedido ya cerrado (con sus pagos registrados)
+     * @param numeroMesa mesa del pedido, para la descripción del cargo
+     * @param cliente cliente identificado en el cierre (nullable)
+     * @throws IllegalArgumentException si hay pagos CUENTA_CORRIENTE sin cliente
+     * @throws IllegalStateException si el cargo excede el límite de crédito del cliente
+     */
+    public void registrar(Pedido pedido, int numeroMesa, Cliente cliente, LocalDateTime fecha) {
+        Objects.requireNonNull(pedido, "El pedido es obligatorio");
+        if (cuentaCorrienteService.montoACuentaCorriente(pedido).signum() == 0) {
+            return;
+        }
+
+        List<MovimientoCuentaCorriente> movimientos = cliente != null
+            ? movimientoCuentaCorrienteRepository.buscarPorCliente(cliente.getId(), pedido.getLocalId())
+            : List.of();
+
+        cuentaCorrienteService
+            .registrarCargo(pedido, numeroMesa, cliente, movimientos, fecha)
+            .ifPresent(movimientoCuentaCorrienteRepository::guardar);
+    }
+}
@@ -1,10 +1,16 @@
 package com.agustinpalma.comandas.application.usecase;
 
 import com.agustinpalma.comandas.application.dto.EgresoResponse;
+import com.agustinpalma.comandas.domain.model.CategoriaEgreso;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimiento;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaEgresoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
 import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.repository.CategoriaEgresoRepository;
 import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
 import org.springframework.transaction.annotation.Transactional;
 
 import java.math.BigDecimal;
@@ -19,8 +25,11 @@ import java.util.Objects;
  * por ejemplo: compra de insumos, reparaciones, propinas, etc.
  * 
  * Flujo:
- * 1. Validar datos de entrada
- * 2. Crear MovimientoCaja (genera comprobante automáticamente)
+ * 1. Validar datos de entrada (HU-130: la categoría, si viene, tiene que
+ *    ser del local y estar activa)
+ * 2. Crear MovimientoCaja (genera comprobante automáticamente),
+ *    asociado al turno de caja abierto si lo hay (HU-106) y a la caja
+ *    de la terminal (HU-159)
  * 3. Persistir el movimiento
  * 4. Retornar respuesta con comprobante generado
  */
@@ -28,11 +37,24 @@ import java.util.Objects;
 public class RegistrarEgresoUseCase {
 
     private final MovimientoCajaRepository movimientoCajaRepository;
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final CategoriaEgresoRepository categoriaEgresoRepository;
+    private final GestionarCajasUseCase gestionarCajasUseCase;
     private final Clock clock;
 
-    public RegistrarEgresoUseCase(MovimientoCajaRepository movimientoCajaRepository, Clock clock) {
+    public RegistrarEgresoUseCase(MovimientoCajaRepository movimientoCajaRepository,
+                                  TurnoCajaRepository turnoCajaRepository,
+                                  CategoriaEgresoRepository categoriaEgresoRepository,
+                                  GestionarCajasUseCase gestionarCajasUseCase,
+                                  Clock clock) {
         this.movimientoCajaRepository = Objects.requireNonNull(movimientoCajaRepository, 
             "El movimientoCajaRepository es obligatorio");
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository,
+            "El turnoCajaRepository es obligatorio");
+        this.categoriaEgresoRepository = Objects.requireNonNull(categoriaEgresoRepository,
+            "El categoriaEgresoRepository es obligatorio");
+        this.gestionarCajasUseCase = Objects.requireNonNull(gestionarCajasUseCase,
+            "El gestionarCajasUseCase es obligatorio");
         this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
     }
 
@@ -46,9 +68,30 @@ public class RegistrarEgresoUseCase {
      * @throws IllegalArgumentException si el monto es <= 0 o la descripción está vacía
      */
     public EgresoResponse ejecutar(LocalId localId, BigDecimal monto, String descripcion) {
+        return ejecutar(localId, monto, descripcion, null);
+    }
+
+    /**
+     * HU-130: Registra el egreso clasificado en una categoría.
+     *
+     * @param categoriaId categoría del egreso (nullable = sin categoría)
+     * @throws IllegalArgumentException si la categoría no existe en el local
+     * @throws IllegalStateException si la categoría está desactivada
+     *         o la caja de la terminal no está abierta (HU-159)
+     */
+    public EgresoResponse ejecutar(LocalId localId, BigDecimal monto, String descripcion,
+                                   CategoriaEgresoId categoriaId) {
         Objects.requireNonNull(localId, "El localId es obligatorio");
         Objects.requireNonNull(monto, "El monto es obligatorio");
 
+        if (categoriaId != null) {
+            CategoriaEgreso categoria = categoriaEgresoRepository.buscarPorId(categoriaId, localId)
+                .orElseThrow(() -> new IllegalArgumentException("La categoría de egreso no existe en este local"));
+            if (!categoria.isActiva()) {
+                throw new IllegalStateException("La categoría " + categoria.getNombre() + " está desactivada");
+            }
+        }
+
         LocalDateTime ahora = LocalDateTime.now(clock);
 
         MovimientoCaja movimiento = new MovimientoCaja(
@@ -56,7 +99,11 @@ public class RegistrarEgresoUseCase {
             localId,
             monto,
             descripcion,
-            ahora
+            ahora,
+            TipoMovimiento.EGRESO,
+            turnoCajaRepository.buscarAbierto(localId).map(TurnoCaja::getId).orElse(null),
+            categoriaId,
+            gestionarCajasUseCase.cajaActual(localId)
         );
 
         MovimientoCaja guardado = movimientoCajaRepository.guardar(movimiento);
@@ -5,7 +5,9 @@ import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimiento;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
 import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
 import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
 import org.springframework.transaction.annotation.Transactional;
 
 import java.math.BigDecimal;
@@ -22,7 +24,9 @@ import java.util.Objects;
  * 
  * Flujo:
  * 1. Validar datos de entrada
- * 2. Crear MovimientoCaja con tipo INGRESO (genera comprobante ING-...)
+ * 2. Crear MovimientoCaja con tipo INGRESO (genera comprobante ING-...),
+ *    asociado al turno de caja abierto si lo hay (HU-106) y a la caja
+ *    de la terminal (HU-159)
  * 3. Persistir el movimiento
  * 4. Retornar respuesta con comprobante generado
  */
@@ -30,11 +34,20 @@ import java.util.Objects;
 public class RegistrarIngresoUseCase {
 
     private final MovimientoCajaRepository movimientoCajaRepository;
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final GestionarCajasUseCase gestionarCajasUseCase;
     private final Clock clock;
 
-    public RegistrarIngresoUseCase(MovimientoCajaRepository movimientoCajaRepository, Clock clock) {
+    public RegistrarIngresoUseCase(MovimientoCajaRepository movimientoCajaRepository,
+                                   TurnoCajaRepository turnoCajaRepository,
+                                   GestionarCajasUseCase gestionarCajasUseCase,
+                                   Clock clock) {
         this.movimientoCajaRepository = Objects.requireNonNull(movimientoCajaRepository, 
             "El movimientoCajaRepository es obligatorio");
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository,
+            "El turnoCajaRepository es obligatorio");
+        this.gestionarCajasUseCase = Objects.requireNonNull(gestionarCajasUseCase,
+            "El gestionarCajasUseCase es obligatorio");
         this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
     }
 
@@ -46,6 +59,7 @@ public class RegistrarIngresoUseCase {
      * @param descripcion descripción del ingreso
      * @return DTO con los datos del movimiento registrado y su comprobante
      * @throws IllegalArgumentException si el monto es <= 0 o la descripción está vacía
+     * @throws IllegalStateException si la caja de la terminal no está abierta (HU-159)
      */
     public IngresoResponse ejecutar(LocalId localId, BigDecimal monto, String descripcion) {
         Objects.requireNonNull(localId, "El localId es obligatorio");
@@ -59,7 +73,10 @@ public class RegistrarIngresoUseCase {
             monto,
             descripcion,
             ahora,
-            TipoMovimiento.INGRESO
+            TipoMovimiento.INGRESO,
+            turnoCajaRepository.buscarAbierto(localId).map(TurnoCaja::getId).orElse(null),
+            null,
+            gestionarCajasUseCase.cajaActual(localId)
         );
 
         MovimientoCaja guardado = movimientoCajaRepository.guardar(movimiento);
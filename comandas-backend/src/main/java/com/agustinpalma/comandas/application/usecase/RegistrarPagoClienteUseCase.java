@@ -0,0 +1,113 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.MovimientoCuentaCorrienteResponse;
+import com.agustinpalma.comandas.application.dto.PagoClienteRequest;
+import com.agustinpalma.comandas.domain.model.Cliente;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimiento;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.model.MovimientoCuentaCorriente;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.repository.ClienteRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCuentaCorrienteRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Caso de uso para registrar un pago (total o parcial) de la deuda de un cliente.
+ *
+ * HU-104: Cuenta corriente de clientes.
+ *
+ * Flujo:
+ * 1. Validar que el cliente exista y pertenezca al local
+ * 2. Calcular la deuda actual a partir de sus movimientos
+ * 3. Rechazar el pago si supera la deuda (no se admite saldo a favor)
+ * 4. Registrar el movimiento PAGO
+ * 5. Si se abonó en EFECTIVO, registrar un INGRESO de caja para que el arqueo cierre
+ *    (asociado al turno abierto, HU-106)
+ *
+ * Los pagos con otros medios no generan movimiento de caja: el dinero no pasa por el cajón.
+ */
+@Transactional
+public class RegistrarPagoClienteUseCase {
+
+    private final ClienteRepository clienteRepository;
+    private final MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository;
+    private final MovimientoCajaRepository movimientoCajaRepository;
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final Clock clock;
+
+    public RegistrarPagoClienteUseCase(
+            ClienteRepository clienteRepository,
+            MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository,
+            MovimientoCajaRepository movimientoCajaRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            Clock clock
+    ) {
+        this.clienteRepository = Objects.requireNonNull(clienteRepository, "El clienteRepository es obligatorio");
+        this.movimientoCuentaCorrienteRepository = Objects.requireNonNull(movimientoCuentaCorrienteRepository, "El movimientoCuentaCorrienteRepository es obligatorio");
+        this.movimientoCajaRepository = Objects.requireNonNull(movimientoCajaRepository, "El movimientoCajaRepository es obligatorio");
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository, "El turnoCajaRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * @param clienteId cliente que abona
+     * @param localId identificador del local (tenant)
+     * @param request medio, monto y detalle opcional del pago
+     * @return DTO del movimiento PAGO registrado
+     * @throws IllegalArgumentException si el cliente no existe, el medio no es válido o el pago supera la deuda
+     */
+    public MovimientoCuentaCorrienteResponse ejecutar(ClienteId clienteId, LocalId localId, PagoClienteRequest request) {
+        Objects.requireNonNull(clienteId, "El clienteId es obligatorio");
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+
+        Cliente cliente = clienteRepository.buscarPorIdYLocal(clienteId, localId)
+            .orElseThrow(() -> new IllegalArgumentException("Cliente no encontrado"));
+
+        BigDecimal deudaActual = MovimientoCuentaCorriente.calcularSaldo(
+            movimientoCuentaCorrienteRepository.buscarPorCliente(clienteId, localId)
+        );
+
+        if (request.monto().compareTo(deudaActual) > 0) {
+            throw new IllegalArgumentException(
+                String.format("El pago de %s supera la deuda actual de %s",
+                    request.monto().toPlainString(), deudaActual.toPlainString())
+            );
+        }
+
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        String descripcion = request.descripcion() != null && !request.descripcion().isBlank()
+            ? request.descripcion()
+            : "Pago a cuenta";
+
+        MovimientoCuentaCorriente pago = MovimientoCuentaCorriente.pago(
+            clienteId, localId, request.medio(), request.monto(), ahora, descripcion
+        );
+        MovimientoCuentaCorriente guardado = movimientoCuentaCorrienteRepository.guardar(pago);
+
+        if (request.medio() == MedioPago.EFECTIVO) {
+            movimientoCajaRepository.guardar(new MovimientoCaja(
+                MovimientoCajaId.generate(),
+                localId,
+                request.monto(),
+                "Cobro cuenta corriente: " + cliente.getNombre(),
+                ahora,
+                TipoMovimiento.INGRESO,
+                turnoCajaRepository.buscarAbierto(localId).map(TurnoCaja::getId).orElse(null)
+            ));
+        }
+
+        return MovimientoCuentaCorrienteResponse.fromDomain(guardado);
+    }
+}
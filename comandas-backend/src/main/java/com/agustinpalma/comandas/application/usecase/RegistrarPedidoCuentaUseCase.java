@@ -0,0 +1,39 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * HU-113: Registra que la mesa pidió la cuenta (se imprimió el ticket).
+ *
+ * Pedir la cuenta es atención del mozo, así que reinicia el reloj de la
+ * alerta de mesas olvidadas. Si la mesa ya no tiene pedido abierto no
+ * hace nada: imprimir el ticket no debe fallar por esto.
+ */
+@Transactional
+public class RegistrarPedidoCuentaUseCase {
+
+    private final PedidoRepository pedidoRepository;
+    private final Clock clock;
+
+    public RegistrarPedidoCuentaUseCase(PedidoRepository pedidoRepository, Clock clock) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    public void ejecutar(LocalId localId, MesaId mesaId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(mesaId, "El mesaId es obligatorio");
+
+        pedidoRepository.buscarAbiertoPorMesa(mesaId, localId).ifPresent(pedido -> {
+            pedido.registrarActividad(LocalDateTime.now(clock));
+            pedidoRepository.guardar(pedido);
+        });
+    }
+}
@@ -0,0 +1,175 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.RendicionesTurnoResponse;
+import com.agustinpalma.comandas.application.dto.RendicionesTurnoResponse.RendicionRepartidorDTO;
+import com.agustinpalma.comandas.application.dto.RendirEfectivoRequest;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.RendicionRepartidor;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.RendicionRepartidorRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+import java.util.stream.Stream;
+
+/**
+ * HU-140: Rendición del efectivo de cada repartidor al final del turno.
+ *
+ * El repartidor trae lo cobrado en EFECTIVO de los pedidos que llevó y que
+ * se cobraron en el turno abierto. Se rinde una vez por turno, con todos sus
+ * repartos de vuelta y cobrados.
+ */
+@Transactional
+public class RendirEfectivoRepartidorUseCase {
+
+    private final PedidoRepository pedidoRepository;
+    private final MozoRepository mozoRepository;
+    private final TurnoCajaRepository turnoCajaRepository;
+    private final RendicionRepartidorRepository rendicionRepository;
+    private final Clock clock;
+
+    public RendirEfectivoRepartidorUseCase(PedidoRepository pedidoRepository,
+                                           MozoRepository mozoRepository,
+                                           TurnoCajaRepository turnoCajaRepository,
+                                           RendicionRepartidorRepository rendicionRepository,
+                                           Clock clock) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.mozoRepository = Objects.requireNonNull(mozoRepository, "El mozoRepository es obligatorio");
+        this.turnoCajaRepository = Objects.requireNonNull(turnoCajaRepository, "El turnoCajaRepository es obligatorio");
+        this.rendicionRepository = Objects.requireNonNull(rendicionRepository, "El rendicionRepository es obligatorio");
+        this.clock = Objects.requireNonNull(clock, "El clock es obligatorio");
+    }
+
+    /**
+     * Estado de la rendición de cada repartidor en el turno abierto: los que
+     * ya rindieron y los que tienen pedidos del turno o en la calle.
+     *
+     * @throws IllegalStateException si no hay un turno abierto
+     */
+    @Transactional(readOnly = true)
+    public RendicionesTurnoResponse consultar(LocalId localId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        TurnoCaja turno = buscarTurnoAbierto(localId);
+
+        List<Pedido> pedidos = pedidosDelTurno(localId, turno);
+        Map<MozoId, RendicionRepartidor> rendidas = rendicionRepository.buscarPorTurno(turno.getId(), localId).stream()
+            .collect(Collectors.toMap(RendicionRepartidor::getRepartidorId, Function.identity()));
+
+        List<RendicionRepartidorDTO> filas = new ArrayList<>();
+        for (Mozo repartidor : mozoRepository.buscarPorLocal(localId)) {
+            RendicionRepartidor rendida = rendidas.get(repartidor.getId());
+            List<Pedido> llevados = pedidos.stream()
+                .filter(p -> repartidor.getId().equals(p.getRepartidorId()) && p.getSalidaReparto() != null)
+                .toList();
+            boolean sinMovimiento = rendida == null && llevados.isEmpty();
+            if (sinMovimiento && (repartidor.getRol() != RolPersonal.REPARTIDOR || !repartidor.isActivo())) {
+                continue;
+            }
+            filas.add(rendida != null ? rendida(repartidor, rendida) : pendiente(repartidor, turno, llevados));
+        }
+        filas.sort(Comparator.comparing(RendicionRepartidorDTO::nombre, String.CASE_INSENSITIVE_ORDER));
+
+        BigDecimal totalEsperado = filas.stream().map(RendicionRepartidorDTO::esperado)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+        BigDecimal totalRendido = filas.stream().map(RendicionRepartidorDTO::rendido)
+            .filter(Objects::nonNull)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        return new RendicionesTurnoResponse(turno.getId().getValue(), filas, totalEsperado, totalRendido);
+    }
+
+    /**
+     * @throws IllegalArgumentException si el repartidor no existe en el local
+     * @throws IllegalStateException si no hay turno abierto, ya rindió en el turno, le quedan
+     *                               pedidos en la calle o sin cobrar, o la diferencia no tiene observaciones
+     */
+    public RendicionRepartidorDTO rendir(LocalId localId, RendirEfectivoRequest request) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(request, "El request es obligatorio");
+        TurnoCaja turno = buscarTurnoAbierto(localId);
+
+        Mozo repartidor = mozoRepository.buscarPorId(new MozoId(request.repartidorId()), localId)
+            .orElseThrow(() -> new IllegalArgumentException("El repartidor no existe en este local"));
+        boolean yaRindio = rendicionRepository.buscarPorTurno(turno.getId(), localId).stream()
+            .anyMatch(r -> r.getRepartidorId().equals(repartidor.getId()));
+        if (yaRindio) {
+            throw new IllegalStateException(repartidor.getNombre() + " ya rindió el efectivo de este turno");
+        }
+
+        RendicionRepartidor rendicion = RendicionRepartidor.rendir(turno, repartidor, pedidosDelTurno(localId, turno),
+            request.rendido(), request.observaciones(), LocalDateTime.now(clock));
+
+        return rendida(repartidor, rendicionRepository.guardar(rendicion));
+    }
+
+    private TurnoCaja buscarTurnoAbierto(LocalId localId) {
+        return turnoCajaRepository.buscarAbierto(localId)
+            .orElseThrow(() -> new IllegalStateException("No hay un turno abierto para rendir el efectivo"));
+    }
+
+    /**
+     * Los cobrados en el turno más los abiertos (en la calle o sin cobrar).
+     */
+    private List<Pedido> pedidosDelTurno(LocalId localId, TurnoCaja turno) {
+        return Stream.concat(
+            pedidoRepository.buscarCerradosPorTurno(localId, turno.getId()).stream(),
+            pedidoRepository.buscarAbiertosPorLocal(localId).stream()
+        ).toList();
+    }
+
+    private static RendicionRepartidorDTO rendida(Mozo repartidor, RendicionRepartidor rendicion) {
+        return new RendicionRepartidorDTO(
+            repartidor.getId().getValue(),
+            repartidor.getNombre(),
+            rendicion.getPedidos(),
+            0,
+            0,
+            rendicion.getEsperado(),
+            rendicion.getRendido(),
+            rendicion.getDiferencia(),
+            rendicion.getObservaciones()
+        );
+    }
+
+    private static RendicionRepartidorDTO pendiente(Mozo repartidor, TurnoCaja turno, List<Pedido> llevados) {
+        List<Pedido> cobrados = llevados.stream()
+            .filter(p -> p.getEstado() == EstadoPedido.CERRADO && turno.getId().equals(p.getTurnoId()))
+            .toList();
+        int enLaCalle = (int) llevados.stream().filter(Pedido::estaEnReparto).count();
+        int sinCobrar = (int) llevados.stream()
+            .filter(p -> !p.estaEnReparto() && p.getEstado() != EstadoPedido.CERRADO)
+            .count();
+        BigDecimal esperado = cobrados.stream()
+            .map(Pedido::calcularCobradoEfectivo)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        return new RendicionRepartidorDTO(
+            repartidor.getId().getValue(),
+            repartidor.getNombre(),
+            cobrados.size(),
+            enLaCalle,
+            sinCobrar,
+            esperado,
+            null,
+            null,
+            null
+        );
+    }
+}
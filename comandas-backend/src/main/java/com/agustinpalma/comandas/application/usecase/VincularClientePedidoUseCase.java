@@ -0,0 +1,58 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.AgregarProductoResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.ClienteRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Objects;
+
+/**
+ * HU-168: Vincula un cliente de la ficha a un pedido abierto de salón,
+ * take away o delivery.
+ *
+ * El pedido queda en el historial del cliente al cobrarse y, si la promo
+ * de cumpleaños está activa, el cobro la evalúa sin tener que volver a
+ * elegir al cliente. Vincular otro cliente reemplaza al anterior.
+ */
+@Transactional
+public class VincularClientePedidoUseCase {
+
+    private final PedidoRepository pedidoRepository;
+    private final ClienteRepository clienteRepository;
+
+    public VincularClientePedidoUseCase(PedidoRepository pedidoRepository, ClienteRepository clienteRepository) {
+        this.pedidoRepository = Objects.requireNonNull(pedidoRepository, "El pedidoRepository es obligatorio");
+        this.clienteRepository = Objects.requireNonNull(clienteRepository, "El clienteRepository es obligatorio");
+    }
+
+    /**
+     * @param clienteId cliente a vincular, o null para desvincular
+     * @throws IllegalArgumentException si el pedido o el cliente no existen en el local
+     * @throws IllegalStateException si el pedido no está ABIERTO
+     */
+    public AgregarProductoResponse ejecutar(LocalId localId, PedidoId pedidoId, ClienteId clienteId) {
+        Objects.requireNonNull(localId, "El localId es obligatorio");
+        Objects.requireNonNull(pedidoId, "El pedidoId es obligatorio");
+
+        Pedido pedido = pedidoRepository.buscarPorId(pedidoId)
+            .filter(p -> p.getLocalId().equals(localId))
+            .orElseThrow(() -> new IllegalArgumentException(
+                "No se encontró el pedido con ID: " + pedidoId.getValue()
+            ));
+
+        if (clienteId == null) {
+            pedido.desvincularCliente();
+        } else {
+            clienteRepository.buscarPorIdYLocal(clienteId, localId)
+                .orElseThrow(() -> new IllegalArgumentException("El cliente no existe en este local"));
+            pedido.vincularCliente(clienteId);
+        }
+
+        return AgregarProductoResponse.fromDomain(pedidoRepository.guardar(pedido));
+    }
+}
@@ -0,0 +1,32 @@
+package com.agustinpalma.comandas.domain.exception;
+
+/**
+ * Excepción de dominio lanzada cuando una terminal intenta cambiar un pedido
+ * que otra terminal modificó después de que lo cargó (HU-165).
+ *
+ * La terminal tiene que ver el pedido actualizado y decidir si su cambio
+ * sigue valiendo: así ningún ítem se pierde ni se carga dos veces.
+ */
+public class PedidoModificadoException extends RuntimeException {
+
+    private final long versionEsperada;
+    private final long versionActual;
+
+    /**
+     * @param versionEsperada versión que tenía el pedido en la terminal
+     * @param versionActual   versión vigente del pedido
+     */
+    public PedidoModificadoException(long versionEsperada, long versionActual) {
+        super("La mesa fue modificada desde otra terminal. Revisá el pedido actualizado antes de seguir.");
+        this.versionEsperada = versionEsperada;
+        this.versionActual = versionActual;
+    }
+
+    public long getVersionEsperada() {
+        return versionEsperada;
+    }
+
+    public long getVersionActual() {
+        return versionActual;
+    }
+}
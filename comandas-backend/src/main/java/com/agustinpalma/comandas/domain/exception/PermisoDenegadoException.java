@@ -0,0 +1,28 @@
+package com.agustinpalma.comandas.domain.exception;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+
+/**
+ * Excepción de dominio lanzada cuando el empleado identificado en la terminal
+ * no tiene habilitada la acción que intenta hacer (HU-169).
+ *
+ * La terminal tiene que pedir que se identifique alguien con el permiso
+ * (un encargado o el dueño) antes de reintentar.
+ */
+public class PermisoDenegadoException extends RuntimeException {
+
+    private final Permiso permiso;
+
+    /**
+     * @param permiso la acción que se rechazó
+     * @param mensaje explicación para mostrar en la terminal
+     */
+    public PermisoDenegadoException(Permiso permiso, String mensaje) {
+        super(mensaje);
+        this.permiso = permiso;
+    }
+
+    public Permiso getPermiso() {
+        return permiso;
+    }
+}
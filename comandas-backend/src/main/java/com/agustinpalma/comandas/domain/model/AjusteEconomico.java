@@ -13,6 +13,7 @@ import java.util.Objects;
  * manuales, convirtiendo al agregado en la única fuente de verdad del relato económico.
  * 
  * Inmutable por diseño. El monto es un snapshot monetario final (no un porcentaje).
+ * HU-122: Los ajustes manuales llevan además el nombre de quien los autorizó.
  * 
  * Ejemplos:
  * - AjusteEconomico(PROMOCION, ITEM, "2x1 Cervezas", $1500)
@@ -45,14 +46,21 @@ public final class AjusteEconomico {
     private final AmbitoAjuste ambito;
     private final String descripcion;
     private final BigDecimal monto;
+    private final String autorizadoPor;
+
+    public AjusteEconomico(TipoAjuste tipo, AmbitoAjuste ambito, String descripcion, BigDecimal monto) {
+        this(tipo, ambito, descripcion, monto, null);
+    }
 
     /**
      * @param tipo     origen del ajuste (PROMOCION o MANUAL)
      * @param ambito   nivel de aplicación (ITEM o TOTAL)
      * @param descripcion razón legible para el ticket (ej: "2x1 Cervezas", "Cortesía")
      * @param monto    valor monetario final del descuento (siempre >= 0)
+     * @param autorizadoPor empleado que autorizó el ajuste manual (null si no aplica)
      */
-    public AjusteEconomico(TipoAjuste tipo, AmbitoAjuste ambito, String descripcion, BigDecimal monto) {
+    public AjusteEconomico(TipoAjuste tipo, AmbitoAjuste ambito, String descripcion, BigDecimal monto,
+                           String autorizadoPor) {
         this.tipo = Objects.requireNonNull(tipo, "El tipo de ajuste no puede ser null");
         this.ambito = Objects.requireNonNull(ambito, "El ámbito del ajuste no puede ser null");
         this.descripcion = Objects.requireNonNull(descripcion, "La descripción no puede ser null");
@@ -64,6 +72,7 @@ public final class AjusteEconomico {
             );
         }
         this.monto = monto;
+        this.autorizadoPor = autorizadoPor;
     }
 
     public TipoAjuste getTipo() {
@@ -82,6 +91,10 @@ public final class AjusteEconomico {
         return monto;
     }
 
+    public String getAutorizadoPor() {
+        return autorizadoPor;
+    }
+
     @Override
     public boolean equals(Object o) {
         if (this == o) return true;
@@ -90,12 +103,13 @@ public final class AjusteEconomico {
         return tipo == that.tipo
             && ambito == that.ambito
             && Objects.equals(descripcion, that.descripcion)
-            && monto.compareTo(that.monto) == 0;
+            && monto.compareTo(that.monto) == 0
+            && Objects.equals(autorizadoPor, that.autorizadoPor);
     }
 
     @Override
     public int hashCode() {
-        return Objects.hash(tipo, ambito, descripcion, monto);
+        return Objects.hash(tipo, ambito, descripcion, monto, autorizadoPor);
     }
 
     @Override
@@ -0,0 +1,80 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Configuración de la alerta de mesas olvidadas de un local.
+ * Hay a lo sumo una por local: la identidad es el propio LocalId.
+ *
+ * HU-113: Alerta de mesas olvidadas.
+ *
+ * Una mesa ocupada queda "olvidada" cuando pasan más de
+ * minutosSinActividad desde su última atención (ver Pedido#registrarActividad).
+ * Las terminales consultan las mesas olvidadas y avisan con sonido
+ * y notificación.
+ *
+ * Reglas de negocio:
+ * - El umbral va de 5 a 240 minutos.
+ * - Con la alerta inactiva no se informa ninguna mesa.
+ */
+public class AlertaMesasOlvidadas {
+
+    public static final int MINUTOS_MINIMOS = 5;
+    public static final int MINUTOS_MAXIMOS = 240;
+
+    private final LocalId localId;
+    private boolean activa;
+    private int minutosSinActividad;
+
+    public AlertaMesasOlvidadas(LocalId localId, boolean activa, int minutosSinActividad) {
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.activa = activa;
+        this.minutosSinActividad = validarMinutos(minutosSinActividad);
+    }
+
+    /**
+     * Alerta por defecto de un local que todavía no la configuró:
+     * activa, a los 30 minutos sin atención.
+     */
+    public static AlertaMesasOlvidadas porDefecto(LocalId localId) {
+        return new AlertaMesasOlvidadas(localId, true, 30);
+    }
+
+    public void actualizar(boolean activa, int minutosSinActividad) {
+        this.minutosSinActividad = validarMinutos(minutosSinActividad);
+        this.activa = activa;
+    }
+
+    /**
+     * @param pedido pedido abierto de una mesa ocupada
+     * @param ahora momento de la consulta
+     * @return true si la alerta está activa y la mesa superó el umbral
+     */
+    public boolean estaOlvidada(Pedido pedido, LocalDateTime ahora) {
+        Objects.requireNonNull(pedido, "El pedido no puede ser null");
+        return activa && pedido.minutosSinActividad(ahora) >= minutosSinActividad;
+    }
+
+    private int validarMinutos(int minutos) {
+        if (minutos < MINUTOS_MINIMOS || minutos > MINUTOS_MAXIMOS) {
+            throw new IllegalArgumentException(String.format(
+                "Los minutos sin actividad deben estar entre %d y %d", MINUTOS_MINIMOS, MINUTOS_MAXIMOS));
+        }
+        return minutos;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public boolean isActiva() {
+        return activa;
+    }
+
+    public int getMinutosSinActividad() {
+        return minutosSinActividad;
+    }
+}
@@ -0,0 +1,75 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Apertura de una caja dentro de una jornada: el fondo inicial de su cajón.
+ *
+ * HU-159: Cada caja arranca la jornada con su propio cambio. Su arqueo al
+ * cierre es ese fondo más lo que cobró en efectivo y los ingresos, menos los
+ * egresos y las devoluciones que pasaron por ella.
+ *
+ * Reglas de negocio:
+ * - Una caja se abre a lo sumo una vez por jornada y solo con la jornada ABIERTA.
+ * - El fondo inicial es ≥ 0 y no se modifica después.
+ */
+public class AperturaCaja {
+
+    private final LocalId localId;
+    private final JornadaCajaId jornadaId;
+    private final CajaId cajaId;
+    private final BigDecimal fondoInicial;
+    private final LocalDateTime fechaApertura;
+
+    public AperturaCaja(LocalId localId, JornadaCajaId jornadaId, CajaId cajaId,
+                        BigDecimal fondoInicial, LocalDateTime fechaApertura) {
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.jornadaId = Objects.requireNonNull(jornadaId, "La apertura debe pertenecer a una jornada");
+        this.cajaId = Objects.requireNonNull(cajaId, "La caja es obligatoria");
+        Objects.requireNonNull(fondoInicial, "El fondo inicial es obligatorio");
+        if (fondoInicial.signum() < 0) {
+            throw new IllegalArgumentException("El fondo inicial no puede ser negativo");
+        }
+        this.fondoInicial = fondoInicial;
+        this.fechaApertura = Objects.requireNonNull(fechaApertura, "La fecha de apertura no puede ser null");
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public JornadaCajaId getJornadaId() {
+        return jornadaId;
+    }
+
+    public CajaId getCajaId() {
+        return cajaId;
+    }
+
+    public BigDecimal getFondoInicial() {
+        return fondoInicial;
+    }
+
+    public LocalDateTime getFechaApertura() {
+        return fechaApertura;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        AperturaCaja that = (AperturaCaja) o;
+        return Objects.equals(jornadaId, that.jornadaId) && Objects.equals(cajaId, that.cajaId);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(jornadaId, cajaId);
+    }
+}
@@ -0,0 +1,105 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ConceptoContable;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.LocalDate;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Asiento de partida doble que se exporta al sistema contable.
+ *
+ * HU-167: Exportación al sistema contable (Tango / Xubio / Contabilium).
+ *
+ * Reglas de negocio:
+ * - Cada línea va al debe o al haber de un concepto, con un importe mayor a
+ *   cero y al centavo.
+ * - El asiento lleva al menos dos líneas y el debe suma lo mismo que el haber.
+ * - Las líneas se guardan por concepto; la cuenta la pone el plan de cuentas
+ *   del local al exportar.
+ */
+public class AsientoContable {
+
+    private static final int LONGITUD_MAXIMA_LEYENDA = 100;
+
+    /**
+     * Una línea del asiento: el importe va al debe o al haber, nunca a los dos.
+     */
+    public record Linea(ConceptoContable concepto, BigDecimal debe, BigDecimal haber) {
+
+        public Linea {
+            Objects.requireNonNull(concepto, "El concepto de la línea es obligatorio");
+            debe = centavos(debe);
+            haber = centavos(haber);
+            if (debe.signum() < 0 || haber.signum() < 0 || (debe.signum() == 0) == (haber.signum() == 0)) {
+                throw new IllegalArgumentException(
+                    "La línea de " + concepto + " lleva un importe positivo en el debe o en el haber");
+            }
+        }
+
+        public static Linea debe(ConceptoContable concepto, BigDecimal importe) {
+            return new Linea(concepto, importe, BigDecimal.ZERO);
+        }
+
+        public static Linea haber(ConceptoContable concepto, BigDecimal importe) {
+            return new Linea(concepto, BigDecimal.ZERO, importe);
+        }
+
+        private static BigDecimal centavos(BigDecimal importe) {
+            return Objects.requireNonNull(importe, "El importe de la línea es obligatorio")
+                .setScale(2, RoundingMode.HALF_UP);
+        }
+    }
+
+    private final LocalDate fecha;
+    private final String leyenda;
+    private final List<Linea> lineas;
+
+    public AsientoContable(LocalDate fecha, String leyenda, List<Linea> lineas) {
+        this.fecha = Objects.requireNonNull(fecha, "La fecha del asiento no puede ser null");
+        this.leyenda = validarLeyenda(leyenda);
+        this.lineas = List.copyOf(Objects.requireNonNull(lineas, "Las líneas del asiento no pueden ser null"));
+
+        if (this.lineas.size() < 2) {
+            throw new IllegalArgumentException("Un asiento lleva al menos dos líneas");
+        }
+        if (totalDebe().compareTo(totalHaber()) != 0) {
+            throw new IllegalArgumentException(
+                "El asiento \"" + this.leyenda + "\" no balancea: debe " + totalDebe() + ", haber " + totalHaber());
+        }
+    }
+
+    private static String validarLeyenda(String leyenda) {
+        if (leyenda == null || leyenda.isBlank()) {
+            throw new IllegalArgumentException("La leyenda del asiento es obligatoria");
+        }
+        String limpia = leyenda.trim();
+        return limpia.length() > LONGITUD_MAXIMA_LEYENDA ? limpia.substring(0, LONGITUD_MAXIMA_LEYENDA) : limpia;
+    }
+
+    public BigDecimal totalDebe() {
+        return lineas.stream().map(Linea::debe).reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    public BigDecimal totalHaber() {
+        return lineas.stream().map(Linea::haber).reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public LocalDate getFecha() {
+        return fecha;
+    }
+
+    public String getLeyenda() {
+        return leyenda;
+    }
+
+    public List<Linea> getLineas() {
+        return lineas;
+    }
+}
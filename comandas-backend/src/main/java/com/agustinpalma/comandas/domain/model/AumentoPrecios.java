@@ -0,0 +1,265 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoAumentoPrecios;
+import com.agustinpalma.comandas.domain.model.DomainIds.AumentoPreciosId;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.DayOfWeek;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.format.DateTimeFormatter;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Aumento porcentual masivo de precios, sobre una categoría o todo el catálogo.
+ *
+ * HU-145: Actualización masiva de precios.
+ *
+ * Reglas de negocio:
+ * - El porcentaje va de 0,01% a 100%, con hasta dos decimales.
+ * - Si hay múltiplo de redondeo, el precio nuevo sube al múltiplo siguiente
+ *   (con $50: 1030 pasa a 1050); nunca baja, para no perder el aumento.
+ * - Los productos a precio cero (extras sin cargo) quedan en cero.
+ * - Se calcula sobre el precio que tiene cada producto al aplicarse, no al
+ *   programarse: si alguien edita un precio en el medio, el aumento va encima.
+ * - Programado para un día futuro se puede cancelar hasta que se aplica.
+ * - Al aplicarse guarda el precio anterior y el nuevo de cada producto.
+ */
+public class AumentoPrecios {
+
+    private static final BigDecimal CIEN = new BigDecimal("100");
+    private static final int MAX_RESPONSABLE = 60;
+    private static final DateTimeFormatter FORMATO_FECHA = DateTimeFormatter.ofPattern("dd/MM/yyyy");
+
+    private final AumentoPreciosId id;
+    private final LocalId localId;
+    private final CategoriaId categoriaId;
+    private final BigDecimal porcentaje;
+    private final BigDecimal multiploRedondeo;
+    private final LocalDate fechaVigencia;
+    private final LocalDateTime fechaCreacion;
+    private final String responsable;
+    private EstadoAumentoPrecios estado;
+    private LocalDateTime fechaAplicacion;
+    private final List<CambioPrecio> cambios;
+
+    public AumentoPrecios(AumentoPreciosId id, LocalId localId, CategoriaId categoriaId, BigDecimal porcentaje,
+                          BigDecimal multiploRedondeo, LocalDate fechaVigencia, LocalDateTime fechaCreacion,
+                          String responsable, EstadoAumentoPrecios estado, LocalDateTime fechaAplicacion,
+                          List<CambioPrecio> cambios) {
+        this.id = Objects.requireNonNull(id, "El id del aumento no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.categoriaId = categoriaId;
+        this.porcentaje = validarPorcentaje(porcentaje);
+        this.multiploRedondeo = validarMultiplo(multiploRedondeo);
+        this.fechaVigencia = Objects.requireNonNull(fechaVigencia, "La fecha de vigencia es obligatoria");
+        this.fechaCreacion = Objects.requireNonNull(fechaCreacion, "La fecha de creación es obligatoria");
+        this.responsable = normalizarResponsable(responsable);
+        this.estado = Objects.requireNonNull(estado, "El estado del aumento es obligatorio");
+        this.fechaAplicacion = fechaAplicacion;
+        this.cambios = new ArrayList<>(Objects.requireNonNull(cambios, "Los cambios no pueden ser null"));
+    }
+
+    /**
+     * Programa un aumento. Si la vigencia es hoy hay que aplicarlo enseguida.
+     *
+     * @param categoriaId null = todo el catálogo
+     * @param multiploRedondeo null = sin redondeo (centavos)
+     * @param responsable quién lo carga (opcional); queda en el historial de precios (HU-146)
+     * @throws IllegalArgumentException si la vigencia ya pasó
+     */
+    public static AumentoPrecios programar(LocalId localId, CategoriaId categoriaId, BigDecimal porcentaje,
+                                           BigDecimal multiploRedondeo, LocalDate fechaVigencia,
+                                           String responsable, LocalDateTime ahora) {
+        Objects.requireNonNull(fechaVigencia, "La fecha de vigencia es obligatoria");
+        Objects.requireNonNull(ahora, "La fecha de creación es obligatoria");
+        if (fechaVigencia.isBefore(ahora.toLocalDate())) {
+            throw new IllegalArgumentException("La fecha de vigencia no puede ser anterior a hoy");
+        }
+        return new AumentoPrecios(AumentoPreciosId.generate(), localId, categoriaId, porcentaje, multiploRedondeo,
+            fechaVigencia, ahora, responsable, EstadoAumentoPrecios.PROGRAMADO, null, List.of());
+    }
+
+    /**
+     * Primer día de lunes a viernes posterior al dado.
+     */
+    public static LocalDate proximoDiaHabil(LocalDate dia) {
+        LocalDate siguiente = dia.plusDays(1);
+        while (siguiente.getDayOfWeek() == DayOfWeek.SATURDAY || siguiente.getDayOfWeek() == DayOfWeek.SUNDAY) {
+            siguiente = siguiente.plusDays(1);
+        }
+        return siguiente;
+    }
+
+    private static BigDecimal validarPorcentaje(BigDecimal porcentaje) {
+        Objects.requireNonNull(porcentaje, "El porcentaje de aumento es obligatorio");
+        if (porcentaje.signum() <= 0 || porcentaje.compareTo(CIEN) > 0) {
+            throw new IllegalArgumentException("El porcentaje de aumento tiene que estar entre 0 y 100");
+        }
+        if (porcentaje.stripTrailingZeros().scale() > 2) {
+            throw new IllegalArgumentException("El porcentaje de aumento admite hasta dos decimales");
+        }
+        return porcentaje;
+    }
+
+    private static BigDecimal validarMultiplo(BigDecimal multiplo) {
+        if (multiplo == null) {
+            return null;
+        }
+        if (multiplo.signum() <= 0) {
+            throw new IllegalArgumentException("El múltiplo de redondeo tiene que ser mayor a cero");
+        }
+        return multiplo;
+    }
+
+    private static String normalizarResponsable(String responsable) {
+        if (responsable == null || responsable.isBlank()) {
+            return null;
+        }
+        String limpio = responsable.trim();
+        if (limpio.length() > MAX_RESPONSABLE) {
+            throw new IllegalArgumentException("El responsable no puede superar los 60 caracteres");
+        }
+        return limpio;
+    }
+
+    // ============================================
+    // Comportamiento
+    // ============================================
+
+    /**
+     * Precio con el aumento y el redondeo aplicados.
+     */
+    public BigDecimal calcularPrecio(BigDecimal precioActual) {
+        Objects.requireNonNull(precioActual, "El precio actual es obligatorio");
+        if (precioActual.signum() == 0) {
+            return precioActual;
+        }
+        BigDecimal aumentado = precioActual.multiply(CIEN.add(porcentaje)).divide(CIEN, 2, RoundingMode.HALF_UP);
+        if (multiploRedondeo == null) {
+            return aumentado;
+        }
+        return aumentado.divide(multiploRedondeo, 0, RoundingMode.CEILING).multiply(multiploRedondeo);
+    }
+
+    /**
+     * Cómo quedarían los precios de los productos, sin tocarlos.
+     */
+    public List<CambioPrecio> vistaPrevia(List<Producto> productos) {
+        return productos.stream()
+            .filter(this::alcanza)
+            .map(p -> new CambioPrecio(p.getId(), p.getNombre(), p.getPrecio(), calcularPrecio(p.getPrecio())))
+            .toList();
+    }
+
+    /**
+     * Cambia el precio de los productos alcanzados y guarda el detalle.
+     *
+     * @return los productos modificados, para persistirlos
+     * @throws IllegalStateException si no está programado o todavía no es su día
+     */
+    public List<Producto> aplicar(List<Producto> productos, LocalDateTime ahora) {
+        Objects.requireNonNull(ahora, "La fecha de aplicación es obligatoria");
+        if (estado != EstadoAumentoPrecios.PROGRAMADO) {
+            throw new IllegalStateException("El aumento de precios ya está " + estado.name().toLowerCase());
+        }
+        if (fechaVigencia.isAfter(ahora.toLocalDate())) {
+            throw new IllegalStateException(
+                "El aumento de precios entra en vigencia el " + fechaVigencia.format(FORMATO_FECHA));
+        }
+        List<CambioPrecio> calculados = vistaPrevia(productos);
+        List<Producto> modificados = new ArrayList<>();
+        for (Producto producto : productos) {
+            if (alcanza(producto)) {
+                producto.actualizarPrecio(calcularPrecio(producto.getPrecio()));
+                modificados.add(producto);
+            }
+        }
+        cambios.clear();
+        cambios.addAll(calculados);
+        estado = EstadoAumentoPrecios.APLICADO;
+        fechaAplicacion = ahora;
+        return modificados;
+    }
+
+    /**
+     * @throws IllegalStateException si ya se aplicó o se canceló
+     */
+    public void cancelar() {
+        if (estado != EstadoAumentoPrecios.PROGRAMADO) {
+            throw new IllegalStateException("Solo se puede cancelar un aumento programado");
+        }
+        estado = EstadoAumentoPrecios.CANCELADO;
+    }
+
+    private boolean alcanza(Producto producto) {
+        return producto.getLocalId().equals(localId)
+            && (categoriaId == null || categoriaId.equals(producto.getCategoriaId()));
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public AumentoPreciosId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public CategoriaId getCategoriaId() {
+        return categoriaId;
+    }
+
+    public BigDecimal getPorcentaje() {
+        return porcentaje;
+    }
+
+    public BigDecimal getMultiploRedondeo() {
+        return multiploRedondeo;
+    }
+
+    public LocalDate getFechaVigencia() {
+        return fechaVigencia;
+    }
+
+    public LocalDateTime getFechaCreacion() {
+        return fechaCreacion;
+    }
+
+    public String getResponsable() {
+        return responsable;
+    }
+
+    public EstadoAumentoPrecios getEstado() {
+        return estado;
+    }
+
+    public LocalDateTime getFechaAplicacion() {
+        return fechaAplicacion;
+    }
+
+    public List<CambioPrecio> getCambios() {
+        return List.copyOf(cambios);
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        AumentoPrecios that = (AumentoPrecios) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
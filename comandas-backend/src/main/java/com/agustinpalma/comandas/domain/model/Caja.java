@@ -0,0 +1,89 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Caja (puesto de cobro) del local.
+ *
+ * HU-159: Un local grande cobra en más de una terminal: la barra, el
+ * mostrador de take away, la caja del salón. Cada terminal se registra una
+ * vez como caja y desde entonces los cobros, egresos e ingresos que hace
+ * quedan a su nombre.
+ *
+ * Reglas de negocio:
+ * - El nombre es obligatorio, de hasta 40 caracteres y único en el local.
+ * - Lo que se cobra sin caja registrada va a la caja principal: la de la
+ *   jornada, con el fondo declarado al abrirla.
+ * - Cada caja declara su propio fondo inicial en cada jornada
+ *   ({@link AperturaCaja}).
+ */
+public class Caja {
+
+    private static final int LONGITUD_MAXIMA_NOMBRE = 40;
+
+    private final CajaId id;
+    private final LocalId localId;
+    private final String nombre;
+    private final LocalDateTime fechaAlta;
+
+    public Caja(CajaId id, LocalId localId, String nombre, LocalDateTime fechaAlta) {
+        this.id = Objects.requireNonNull(id, "El id de la caja no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.nombre = validarNombre(nombre);
+        this.fechaAlta = Objects.requireNonNull(fechaAlta, "La fecha de alta no puede ser null");
+    }
+
+    private String validarNombre(String nombre) {
+        if (nombre == null || nombre.isBlank()) {
+            throw new IllegalArgumentException("El nombre de la caja es obligatorio");
+        }
+        String limpio = nombre.trim();
+        if (limpio.length() > LONGITUD_MAXIMA_NOMBRE) {
+            throw new IllegalArgumentException(
+                "El nombre de la caja no puede superar " + LONGITUD_MAXIMA_NOMBRE + " caracteres");
+        }
+        return limpio;
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public CajaId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public LocalDateTime getFechaAlta() {
+        return fechaAlta;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        Caja that = (Caja) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+
+    @Override
+    public String toString() {
+        return String.format("Caja{id=%s, nombre=%s}", id, nombre);
+    }
+}
@@ -0,0 +1,44 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+
+import java.math.BigDecimal;
+import java.util.Objects;
+
+/**
+ * Precio de un producto antes y después de un aumento masivo.
+ *
+ * HU-145: Queda el nombre del producto del momento del cambio, así el
+ * detalle se sigue leyendo aunque el producto se renombre o se borre.
+ */
+public final class CambioPrecio {
+
+    private final ProductoId productoId;
+    private final String nombreProducto;
+    private final BigDecimal precioAnterior;
+    private final BigDecimal precioNuevo;
+
+    public CambioPrecio(ProductoId productoId, String nombreProducto, BigDecimal precioAnterior,
+                        BigDecimal precioNuevo) {
+        this.productoId = Objects.requireNonNull(productoId, "El producto del cambio es obligatorio");
+        this.nombreProducto = Objects.requireNonNull(nombreProducto, "El nombre del producto es obligatorio");
+        this.precioAnterior = Objects.requireNonNull(precioAnterior, "El precio anterior es obligatorio");
+        this.precioNuevo = Objects.requireNonNull(precioNuevo, "El precio nuevo es obligatorio");
+    }
+
+    public ProductoId getProductoId() {
+        return productoId;
+    }
+
+    public String getNombreProducto() {
+        return nombreProducto;
+    }
+
+    public BigDecimal getPrecioAnterior() {
+        return precioAnterior;
+    }
+
+    public BigDecimal getPrecioNuevo() {
+        return precioNuevo;
+    }
+}
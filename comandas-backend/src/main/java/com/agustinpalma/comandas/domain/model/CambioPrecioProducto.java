@@ -0,0 +1,154 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.OrigenCambioPrecio;
+import com.agustinpalma.comandas.domain.model.DomainIds.AumentoPreciosId;
+import com.agustinpalma.comandas.domain.model.DomainIds.CambioPrecioProductoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Registro de auditoría del catálogo: un cambio de precio de un producto.
+ *
+ * HU-146: Historial de cambios de precios.
+ *
+ * Reglas de negocio:
+ * - Solo se registra si el precio cambió de verdad.
+ * - Es inmutable: el historial no se corrige, se agrega un cambio nuevo.
+ * - Guarda el nombre del producto al momento del cambio, para que el
+ *   historial se siga leyendo aunque después lo renombren o lo borren.
+ * - Si vino de un aumento masivo, referencia al aumento.
+ */
+public class CambioPrecioProducto {
+
+    private static final int MAX_RESPONSABLE = 60;
+    private static final BigDecimal CIEN = new BigDecimal("100");
+
+    private final CambioPrecioProductoId id;
+    private final LocalId localId;
+    private final ProductoId productoId;
+    private final String nombreProducto;
+    private final BigDecimal precioAnterior;
+    private final BigDecimal precioNuevo;
+    private final LocalDateTime fecha;
+    private final OrigenCambioPrecio origen;
+    private final AumentoPreciosId aumentoId;
+    private final String responsable;
+
+    public CambioPrecioProducto(CambioPrecioProductoId id, LocalId localId, ProductoId productoId,
+                                String nombreProducto, BigDecimal precioAnterior, BigDecimal precioNuevo,
+                                LocalDateTime fecha, OrigenCambioPrecio origen, AumentoPreciosId aumentoId,
+                                String responsable) {
+        this.id = Objects.requireNonNull(id, "El id del cambio de precio no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.productoId = Objects.requireNonNull(productoId, "El productoId no puede ser null");
+        this.nombreProducto = Objects.requireNonNull(nombreProducto, "El nombre del producto es obligatorio");
+        this.precioAnterior = Objects.requireNonNull(precioAnterior, "El precio anterior es obligatorio");
+        this.precioNuevo = Objects.requireNonNull(precioNuevo, "El precio nuevo es obligatorio");
+        this.fecha = Objects.requireNonNull(fecha, "La fecha del cambio es obligatoria");
+        this.origen = Objects.requireNonNull(origen, "El origen del cambio es obligatorio");
+        this.aumentoId = aumentoId;
+        this.responsable = normalizarResponsable(responsable);
+    }
+
+    /**
+     * Registra el cambio de precio de un producto.
+     *
+     * @param aumentoId   aumento masivo que lo provocó (null si fue una edición)
+     * @param responsable quién hizo el cambio (opcional)
+     * @throws IllegalArgumentException si el precio no cambió
+     */
+    public static CambioPrecioProducto registrar(Producto producto, BigDecimal precioAnterior,
+                                                 OrigenCambioPrecio origen, AumentoPreciosId aumentoId,
+                                                 String responsable, LocalDateTime ahora) {
+        Objects.requireNonNull(producto, "El producto es obligatorio");
+        Objects.requireNonNull(precioAnterior, "El precio anterior es obligatorio");
+        if (precioAnterior.compareTo(producto.getPrecio()) == 0) {
+            throw new IllegalArgumentException("El precio del producto no cambió");
+        }
+        return new CambioPrecioProducto(CambioPrecioProductoId.generate(), producto.getLocalId(), producto.getId(),
+            producto.getNombre(), precioAnterior, producto.getPrecio(), ahora, origen, aumentoId, responsable);
+    }
+
+    private static String normalizarResponsable(String responsable) {
+        if (responsable == null || responsable.isBlank()) {
+            return null;
+        }
+        String limpio = responsable.trim();
+        if (limpio.length() > MAX_RESPONSABLE) {
+            throw new IllegalArgumentException("El responsable no puede superar los 60 caracteres");
+        }
+        return limpio;
+    }
+
+    /**
+     * Variación en porcentaje con dos decimales. Null si el precio anterior era cero.
+     */
+    public BigDecimal getVariacionPorcentual() {
+        if (precioAnterior.signum() == 0) {
+            return null;
+        }
+        return precioNuevo.subtract(precioAnterior).multiply(CIEN).divide(precioAnterior, 2, RoundingMode.HALF_UP);
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public CambioPrecioProductoId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public ProductoId getProductoId() {
+        return productoId;
+    }
+
+    public String getNombreProducto() {
+        return nombreProducto;
+    }
+
+    public BigDecimal getPrecioAnterior() {
+        return precioAnterior;
+    }
+
+    public BigDecimal getPrecioNuevo() {
+        return precioNuevo;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public OrigenCambioPrecio getOrigen() {
+        return origen;
+    }
+
+    public AumentoPreciosId getAumentoId() {
+        return aumentoId;
+    }
+
+    public String getResponsable() {
+        return responsable;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        CambioPrecioProducto that = (CambioPrecioProducto) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
@@ -19,10 +19,14 @@ import java.util.regex.Pattern;
  * - El nombre no puede estar vacío
  * - El colorHex se normaliza a mayúsculas y se valida en formato hexadecimal
  * - El orden define la posición visual en el frontend (0 = primera posición)
+ * - HU-119: Los minutos de alerta de cocina, si se configuran, van de 1 a 240
+ * - HU-161: Con horario, sus productos solo se ofrecen dentro de esa franja
+ *   (salvo los que tienen horario propio)
  */
 public class Categoria {
 
     private static final String COLOR_HEX_DEFAULT = "#FFFFFF";
+    private static final int MINUTOS_ALERTA_COCINA_MAXIMO = 240;
     private static final Pattern COLOR_HEX_PATTERN = Pattern.compile("^#([A-Fa-f0-9]{6}|[A-Fa-f0-9]{3})$");
 
     private final CategoriaId id;
@@ -39,6 +43,17 @@ public class Categoria {
      * Ej: "Panchos" → apunta a "Salsas para Panchos".
      */
     private CategoriaId categoriaModificadoresId;
+    /**
+     * HU-119: Minutos que tiene cocina para despachar los productos de esta
+     * categoría antes de que el pedido figure como demorado (nullable).
+     * Sin valor, rige el umbral por defecto de la comanda.
+     */
+    private Integer minutosAlertaCocina;
+    /**
+     * HU-161: Horario en que se ofrecen los productos de la categoría
+     * (nullable = todo el día). Ej: "Desayunos" de 7 a 11.
+     */
+    private FranjaHoraria franjaHoraria;
 
     /**
      * Constructor completo.
@@ -111,6 +126,15 @@ public class Categoria {
         return colorTrimmed.toUpperCase();
     }
 
+    private Integer validarMinutosAlertaCocina(Integer minutos) {
+        if (minutos != null && (minutos < 1 || minutos > MINUTOS_ALERTA_COCINA_MAXIMO)) {
+            throw new IllegalArgumentException(
+                "Los minutos de alerta de cocina deben estar entre 1 y " + MINUTOS_ALERTA_COCINA_MAXIMO
+            );
+        }
+        return minutos;
+    }
+
     private int validarOrden(int orden) {
         if (orden < 0) {
             throw new IllegalArgumentException("El orden no puede ser negativo");
@@ -204,6 +228,30 @@ public class Categoria {
         this.categoriaModificadoresId = categoriaModificadoresId;
     }
 
+    public Integer getMinutosAlertaCocina() {
+        return minutosAlertaCocina;
+    }
+
+    /**
+     * HU-119: Define el umbral de demora en cocina para esta categoría.
+     * Si se pasa null, la categoría usa el umbral por defecto.
+     */
+    public void cambiarMinutosAlertaCocina(Integer minutos) {
+        this.minutosAlertaCocina = validarMinutosAlertaCocina(minutos);
+    }
+
+    public FranjaHoraria getFranjaHoraria() {
+        return franjaHoraria;
+    }
+
+    /**
+     * HU-161: Define el horario de la categoría.
+     * Si se pasa null, sus productos se ofrecen todo el día.
+     */
+    public void cambiarFranjaHoraria(FranjaHoraria franjaHoraria) {
+        this.franjaHoraria = franjaHoraria;
+    }
+
     // ============================================
     // Identidad
     // ============================================
@@ -0,0 +1,99 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaEgresoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Categoría con la que se clasifican los egresos de caja (limpieza,
+ * mercadería, mantenimiento, adelantos de sueldo...).
+ *
+ * HU-130: Categorías de egresos y reporte mensual de gastos.
+ *
+ * Reglas de negocio:
+ * - El nombre es obligatorio (máximo 40 caracteres) y no se repite en el local.
+ * - Una categoría con egresos no se borra: se desactiva. Deja de ofrecerse
+ *   al registrar egresos, pero los ya registrados la conservan en los reportes.
+ */
+public class CategoriaEgreso {
+
+    /** Las que se crean la primera vez que el local consulta sus categorías */
+    public static final List<String> NOMBRES_INICIALES = List.of(
+        "Limpieza", "Mercadería", "Mantenimiento", "Adelantos de sueldo"
+    );
+
+    private static final int LONGITUD_MAXIMA_NOMBRE = 40;
+
+    private final CategoriaEgresoId id;
+    private final LocalId localId;
+    private String nombre;
+    private boolean activa;
+
+    public CategoriaEgreso(CategoriaEgresoId id, LocalId localId, String nombre, boolean activa) {
+        this.id = Objects.requireNonNull(id, "El id de la categoría no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.nombre = validarNombre(nombre);
+        this.activa = activa;
+    }
+
+    public static CategoriaEgreso crear(LocalId localId, String nombre) {
+        return new CategoriaEgreso(CategoriaEgresoId.generate(), localId, nombre, true);
+    }
+
+    public void actualizar(String nombre, boolean activa) {
+        this.nombre = validarNombre(nombre);
+        this.activa = activa;
+    }
+
+    /**
+     * Compara nombres sin distinguir mayúsculas ni espacios sobrantes,
+     * para no terminar con "Limpieza" y "limpieza " como categorías distintas.
+     */
+    public boolean tieneNombre(String otroNombre) {
+        return otroNombre != null && nombre.equalsIgnoreCase(otroNombre.trim());
+    }
+
+    private String validarNombre(String nombre) {
+        if (nombre == null || nombre.isBlank()) {
+            throw new IllegalArgumentException("El nombre de la categoría no puede estar vacío");
+        }
+        String limpio = nombre.trim();
+        if (limpio.length() > LONGITUD_MAXIMA_NOMBRE) {
+            throw new IllegalArgumentException(
+                "El nombre de la categoría no puede superar los " + LONGITUD_MAXIMA_NOMBRE + " caracteres"
+            );
+        }
+        return limpio;
+    }
+
+    public CategoriaEgresoId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public boolean isActiva() {
+        return activa;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        CategoriaEgreso that = (CategoriaEgreso) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
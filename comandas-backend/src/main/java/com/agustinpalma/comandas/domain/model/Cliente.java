@@ -0,0 +1,299 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.math.BigDecimal;
+import java.time.DayOfWeek;
+import java.time.LocalDate;
+import java.time.MonthDay;
+import java.time.temporal.TemporalAdjusters;
+import java.util.Objects;
+import java.util.Optional;
+
+/**
+ * Entidad de dominio que representa un cliente frecuente con cuenta corriente.
+ * Pertenece al aggregate Local (multi-tenant por fila).
+ *
+ * HU-104: Cuenta corriente de clientes.
+ *
+ * El cliente consume "a pagar a fin de mes": sus pedidos se cierran con
+ * MedioPago.CUENTA_CORRIENTE y la deuda se salda con pagos parciales o totales.
+ * No confundir con A_CUENTA, que es consumo interno del personal.
+ *
+ * Reglas de negocio:
+ * - El nombre no puede estar vacío
+ * - El límite de crédito es obligatorio y no puede ser negativo
+ * - Un cargo no puede llevar la deuda por encima del límite de crédito
+ * - Un cliente inactivo no puede recibir nuevos cargos (sí pagos)
+ *
+ * La deuda NO se guarda en el cliente: se deriva de sus movimientos
+ * de cuenta corriente (ver MovimientoCuentaCorriente).
+ *
+ * HU-168: La ficha suma fecha de nacimiento, preferencias y alergias.
+ * Las alergias se muestran al vincular el cliente a un pedido para que
+ * el salón y la cocina las tengan a la vista.
+ */
+public class Cliente {
+
+    private final ClienteId id;
+    private final LocalId localId;
+    private String nombre;
+    private String telefono;
+    private BigDecimal limiteCredito;
+    private boolean activo;
+    private LocalDate fechaNacimiento;
+    private String preferencias;
+    private String alergias;
+
+    /**
+     * @param id            identidad del cliente
+     * @param localId       identidad del local (tenant)
+     * @param nombre        nombre para identificarlo en el salón (ej: "Juan - Oficina 3B")
+     * @param telefono      teléfono de contacto para reclamar la deuda (nullable)
+     * @param limiteCredito deuda máxima permitida
+     * @param activo        si puede seguir consumiendo a cuenta
+     */
+    public Cliente(
+            ClienteId id,
+            LocalId localId,
+            String nombre,
+            String telefono,
+            BigDecimal limiteCredito,
+            boolean activo
+    ) {
+        this(id, localId, nombre, telefono, limiteCredito, activo, null, null, null);
+    }
+
+    /**
+     * HU-168: Constructor con la ficha completa.
+     *
+     * @param fechaNacimiento fecha de nacimiento (nullable; el año puede ser aproximado)
+     * @param preferencias    gustos a recordar (ej: "Mesa junto a la ventana") (nullable)
+     * @param alergias        alergias o restricciones (ej: "Celíaco") (nullable)
+     */
+    public Cliente(
+            ClienteId id,
+            LocalId localId,
+            String nombre,
+            String telefono,
+            BigDecimal limiteCredito,
+            boolean activo,
+            LocalDate fechaNacimiento,
+            String preferencias,
+            String alergias
+    ) {
+        this.id = Objects.requireNonNull(id, "El id del cliente no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.nombre = validarNombre(nombre);
+        this.telefono = normalizarTelefono(telefono);
+        this.limiteCredito = validarLimiteCredito(limiteCredito);
+        this.activo = activo;
+        this.fechaNacimiento = validarFechaNacimiento(fechaNacimiento);
+        this.preferencias = normalizarNota(preferencias, "Las preferencias");
+        this.alergias = normalizarNota(alergias, "Las alergias");
+    }
+
+    // ============================================
+    // Validaciones
+    // ============================================
+
+    private String validarNombre(String nombre) {
+        if (nombre == null || nombre.isBlank()) {
+            throw new IllegalArgumentException("El nombre del cliente no puede estar vacío");
+        }
+        return nombre.trim();
+    }
+
+    private String normalizarTelefono(String telefono) {
+        if (telefono == null || telefono.isBlank()) {
+            return null;
+        }
+        return telefono.trim();
+    }
+
+    private BigDecimal validarLimiteCredito(BigDecimal limiteCredito) {
+        if (limiteCredito == null) {
+            throw new IllegalArgumentException("El límite de crédito del cliente es obligatorio");
+        }
+        if (limiteCredito.compareTo(BigDecimal.ZERO) < 0) {
+            throw new IllegalArgumentException("El límite de crédito no puede ser negativo");
+        }
+        return limiteCredito;
+    }
+
+    private LocalDate validarFechaNacimiento(LocalDate fechaNacimiento) {
+        if (fechaNacimiento != null && fechaNacimiento.getYear() < 1900) {
+            throw new IllegalArgumentException("La fecha de nacimiento no es válida");
+        }
+        return fechaNacimiento;
+    }
+
+    private String normalizarNota(String nota, String campo) {
+        if (nota == null || nota.isBlank()) {
+            return null;
+        }
+        if (nota.trim().length() > 300) {
+            throw new IllegalArgumentException(campo + " no pueden superar los 300 caracteres");
+        }
+        return nota.trim();
+    }
+
+    // ============================================
+    // Comportamiento
+    // ============================================
+
+    public void actualizarNombre(String nombre) {
+        this.nombre = validarNombre(nombre);
+    }
+
+    public void actualizarTelefono(String telefono) {
+        this.telefono = normalizarTelefono(telefono);
+    }
+
+    /**
+     * Cambia el límite de crédito. Bajarlo por debajo de la deuda actual
+     * está permitido: solo bloquea los cargos siguientes hasta que pague.
+     */
+    public void actualizarLimiteCredito(BigDecimal limiteCredito) {
+        this.limiteCredito = validarLimiteCredito(limiteCredito);
+    }
+
+    /**
+     * HU-168: Actualiza los datos de la ficha. Un valor vacío borra el dato.
+     */
+    public void actualizarFicha(LocalDate fechaNacimiento, String preferencias, String alergias) {
+        this.fechaNacimiento = validarFechaNacimiento(fechaNacimiento);
+        this.preferencias = normalizarNota(preferencias, "Las preferencias");
+        this.alergias = normalizarNota(alergias, "Las alergias");
+    }
+
+    /**
+     * HU-168: Semana (de lunes a domingo) en la que cae el cumpleaños, si la
+     * fecha dada está dentro de ella. Un cumpleaños del 29/2 se festeja el
+     * 28/2 en los años no bisiestos.
+     *
+     * Se mira también el cumpleaños del año anterior y del siguiente, porque
+     * la semana de un cumpleaños de fin de diciembre puede terminar en enero.
+     *
+     * @param fecha día a evaluar (normalmente, el del cobro)
+     * @return el lunes de la semana del cumpleaños, o vacío si la fecha no cae en ella
+     *         o el cliente no tiene fecha de nacimiento
+     */
+    public Optional<LocalDate> semanaDeCumpleanios(LocalDate fecha) {
+        Objects.requireNonNull(fecha, "La fecha no puede ser null");
+        if (fechaNacimiento == null) {
+            return Optional.empty();
+        }
+        LocalDate lunes = fecha.with(TemporalAdjusters.previousOrSame(DayOfWeek.MONDAY));
+        MonthDay cumpleanios = MonthDay.from(fechaNacimiento);
+        for (int anio = fecha.getYear() - 1; anio <= fecha.getYear() + 1; anio++) {
+            LocalDate dia = cumpleanios.atYear(anio);
+            if (dia.with(TemporalAdjusters.previousOrSame(DayOfWeek.MONDAY)).equals(lunes)) {
+                return Optional.of(lunes);
+            }
+        }
+        return Optional.empty();
+    }
+
+    public void activar() {
+        this.activo = true;
+    }
+
+    public void desactivar() {
+        this.activo = false;
+    }
+
+    /**
+     * Crédito que le queda al cliente dada su deuda actual (nunca negativo).
+     */
+    public BigDecimal creditoDisponible(BigDecimal deudaActual) {
+        Objects.requireNonNull(deudaActual, "La deuda actual no puede ser null");
+        BigDecimal disponible = limiteCredito.subtract(deudaActual);
+        return disponible.max(BigDecimal.ZERO);
+    }
+
+    /**
+     * Valida que el cliente pueda tomar un nuevo cargo en su cuenta corriente.
+     *
+     * @param deudaActual saldo deudor antes del cargo
+     * @param monto importe del cargo
+     * @throws IllegalStateException si el cliente está inactivo o el cargo excede el límite
+     */
+    public void validarCargo(BigDecimal deudaActual, BigDecimal monto) {
+        Objects.requireNonNull(deudaActual, "La deuda actual no puede ser null");
+        Objects.requireNonNull(monto, "El monto del cargo no puede ser null");
+
+        if (!activo) {
+            throw new IllegalStateException(
+                String.format("El cliente '%s' está inactivo y no puede consumir a cuenta corriente", nombre)
+            );
+        }
+
+        if (deudaActual.add(monto).compareTo(limiteCredito) > 0) {
+            throw new IllegalStateException(
+                String.format("El cargo de %s supera el crédito disponible de '%s': quedan %s de un límite de %s",
+                    monto.toPlainString(), nombre,
+                    creditoDisponible(deudaActual).toPlainString(), limiteCredito.toPlainString())
+            );
+        }
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public ClienteId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public String getTelefono() {
+        return telefono;
+    }
+
+    public BigDecimal getLimiteCredito() {
+        return limiteCredito;
+    }
+
+    public boolean isActivo() {
+        return activo;
+    }
+
+    public LocalDate getFechaNacimiento() {
+        return fechaNacimiento;
+    }
+
+    public String getPreferencias() {
+        return preferencias;
+    }
+
+    public String getAlergias() {
+        return alergias;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        Cliente cliente = (Cliente) o;
+        return Objects.equals(id, cliente.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+
+    @Override
+    public String toString() {
+        return String.format("Cliente{id=%s, nombre='%s', limiteCredito=%s}", id, nombre, limiteCredito);
+    }
+}
@@ -0,0 +1,218 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoEspera;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoMesa;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteEnEsperaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+
+import java.time.Duration;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Cliente anotado en la lista de espera porque el salón está lleno.
+ *
+ * HU-149: Lista de espera de mesas.
+ *
+ * Reglas de negocio:
+ * - El nombre es obligatorio (máximo 80 caracteres) y las personas, mayor a cero.
+ * - El teléfono es opcional, pero sin teléfono no se le puede mandar el aviso.
+ * - Se le avisa con una mesa LIBRE del local en la que entre el grupo (si la
+ *   mesa tiene capacidad definida). Se le puede volver a avisar con otra mesa.
+ * - Al sentarse o cancelarse sale de la lista y deja de contar la espera.
+ */
+public class ClienteEnEspera {
+
+    private static final int LONGITUD_MAXIMA_NOMBRE = 80;
+    private static final int LONGITUD_MAXIMA_TELEFONO = 30;
+    private static final int LONGITUD_MAXIMA_OBSERVACIONES = 200;
+
+    private final ClienteEnEsperaId id;
+    private final LocalId localId;
+    private final String nombreCliente;
+    private final int personas;
+    private final String telefono;
+    private final String observaciones;
+    private final LocalDateTime fechaAlta;
+    private EstadoEspera estado;
+    private MesaId mesaId;
+    private LocalDateTime fechaAviso;
+    private LocalDateTime fechaSalida;
+
+    public ClienteEnEspera(ClienteEnEsperaId id, LocalId localId, String nombreCliente, int personas,
+                           String telefono, String observaciones, LocalDateTime fechaAlta, EstadoEspera estado,
+                           MesaId mesaId, LocalDateTime fechaAviso, LocalDateTime fechaSalida) {
+        this.id = Objects.requireNonNull(id, "El id del cliente en espera no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.nombreCliente = validarTexto(nombreCliente, LONGITUD_MAXIMA_NOMBRE, "El nombre del cliente");
+        if (this.nombreCliente == null) {
+            throw new IllegalArgumentException("El nombre del cliente es obligatorio");
+        }
+        if (personas <= 0) {
+            throw new IllegalArgumentException("La espera tiene que ser para al menos una persona");
+        }
+        this.personas = personas;
+        this.telefono = validarTexto(telefono, LONGITUD_MAXIMA_TELEFONO, "El teléfono");
+        this.observaciones = validarTexto(observaciones, LONGITUD_MAXIMA_OBSERVACIONES, "Las observaciones");
+        this.fechaAlta = Objects.requireNonNull(fechaAlta, "La fecha de alta es obligatoria");
+        this.estado = Objects.requireNonNull(estado, "El estado de la espera no puede ser null");
+        this.mesaId = mesaId;
+        this.fechaAviso = fechaAviso;
+        this.fechaSalida = fechaSalida;
+    }
+
+    public static ClienteEnEspera anotar(LocalId localId, String nombreCliente, int personas, String telefono,
+                                         String observaciones, LocalDateTime ahora) {
+        return new ClienteEnEspera(ClienteEnEsperaId.generate(), localId, nombreCliente, personas, telefono,
+            observaciones, ahora, EstadoEspera.ESPERANDO, null, null, null);
+    }
+
+    private static String validarTexto(String texto, int maximo, String campo) {
+        if (texto == null || texto.isBlank()) {
+            return null;
+        }
+        String limpio = texto.trim();
+        if (limpio.length() > maximo) {
+            throw new IllegalArgumentException(campo + " no puede superar los " + maximo + " caracteres");
+        }
+        return limpio;
+    }
+
+    // ============================================
+    // Comportamiento
+    // ============================================
+
+    /**
+     * Le reserva la mesa que se liberó y registra el aviso. El mensaje lo
+     * arma la capa de aplicación.
+     *
+     * @throws IllegalStateException si ya se sentó o canceló, o la mesa no está libre
+     * @throws IllegalArgumentException si la mesa es de otro local o el grupo no entra
+     */
+    public void avisar(Mesa mesa, LocalDateTime ahora) {
+        Objects.requireNonNull(mesa, "La mesa no puede ser null");
+        Objects.requireNonNull(ahora, "La fecha del aviso es obligatoria");
+        validarEnLista();
+        if (!mesa.getLocalId().equals(localId)) {
+            throw new IllegalArgumentException("La mesa no pertenece a este local");
+        }
+        if (mesa.getEstado() != EstadoMesa.LIBRE) {
+            throw new IllegalStateException("La mesa " + mesa.getNumero() + " no está libre");
+        }
+        if (mesa.getCapacidad() != null && !mesa.alcanzaPara(personas)) {
+            throw new IllegalArgumentException(String.format(
+                "En la mesa %d entran %d personas y %s son %d",
+                mesa.getNumero(), mesa.getCapacidad(), nombreCliente, personas));
+        }
+        this.mesaId = mesa.getId();
+        this.fechaAviso = ahora;
+        this.estado = EstadoEspera.AVISADO;
+    }
+
+    /**
+     * @throws IllegalStateException si ya se sentó o canceló
+     */
+    public void sentar(LocalDateTime ahora) {
+        validarEnLista();
+        this.fechaSalida = Objects.requireNonNull(ahora, "La fecha es obligatoria");
+        this.estado = EstadoEspera.SENTADO;
+    }
+
+    /**
+     * @throws IllegalStateException si ya se sentó o canceló
+     */
+    public void cancelar(LocalDateTime ahora) {
+        validarEnLista();
+        this.fechaSalida = Objects.requireNonNull(ahora, "La fecha es obligatoria");
+        this.estado = EstadoEspera.CANCELADO;
+    }
+
+    /**
+     * @return true mientras espera mesa o el aviso (ESPERANDO o AVISADO)
+     */
+    public boolean estaEnLista() {
+        return estado == EstadoEspera.ESPERANDO || estado == EstadoEspera.AVISADO;
+    }
+
+    /**
+     * Minutos desde que se anotó; si ya salió de la lista, hasta ese momento.
+     */
+    public long minutosEsperando(LocalDateTime ahora) {
+        LocalDateTime hasta = fechaSalida != null ? fechaSalida : ahora;
+        return Math.max(0, Duration.between(fechaAlta, hasta).toMinutes());
+    }
+
+    private void validarEnLista() {
+        if (!estaEnLista()) {
+            throw new IllegalStateException(nombreCliente + " ya no está en la lista de espera (" + estado + ")");
+        }
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public ClienteEnEsperaId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public String getNombreCliente() {
+        return nombreCliente;
+    }
+
+    public int getPersonas() {
+        return personas;
+    }
+
+    public String getTelefono() {
+        return telefono;
+    }
+
+    public String getObservaciones() {
+        return observaciones;
+    }
+
+    public LocalDateTime getFechaAlta() {
+        return fechaAlta;
+    }
+
+    public EstadoEspera getEstado() {
+        return estado;
+    }
+
+    /**
+     * @return mesa con la que se le avisó, o null si todavía no se le avisó
+     */
+    public MesaId getMesaId() {
+        return mesaId;
+    }
+
+    public LocalDateTime getFechaAviso() {
+        return fechaAviso;
+    }
+
+    /**
+     * @return cuándo se sentó o se canceló, o null si sigue en la lista
+     */
+    public LocalDateTime getFechaSalida() {
+        return fechaSalida;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        ClienteEnEspera that = (ClienteEnEspera) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
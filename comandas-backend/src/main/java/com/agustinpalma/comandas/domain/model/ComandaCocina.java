@@ -0,0 +1,268 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.ComandaCocinaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+
+import java.time.Duration;
+import java.time.LocalDateTime;
+import java.util.Collection;
+import java.util.Comparator;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Comanda enviada a cocina, con su tiempo de preparación.
+ *
+ * HU-119: Tiempos de cocina y alertas de demora.
+ * HU-136: Recuperar la última comanda marcada lista (bump bar).
+ * HU-137: Comandas urgentes primero.
+ * HU-138: Atraso respecto del horario prometido al cliente (take away).
+ *
+ * Cada envío operativo (o marcha de un paso) genera una comanda con los ítems
+ * que salieron en ese envío. El reloj corre desde que llega a cocina hasta que
+ * cocina la marca como lista. La reimpresión no genera comanda.
+ *
+ * Reglas de negocio:
+ * - El umbral de demora se fija al enviar: el mayor de los minutos configurados
+ *   en las categorías de sus ítems, o {@link #MINUTOS_ALERTA_POR_DEFECTO} si
+ *   ninguna tiene. Cambiar la categoría después no altera comandas ya enviadas.
+ * - Una comanda está demorada cuando el tiempo transcurrido supera el umbral.
+ *   Para una comanda lista, se mide hasta el momento en que se marcó.
+ * - Solo se marca lista una vez.
+ * - Una comanda lista se puede recuperar (vuelve a preparación con el reloj
+ *   corriendo desde el envío original) dentro de los
+ *   {@link #MINUTOS_PARA_RECUPERAR} minutos: es para deshacer un "listo"
+ *   apretado por error, no para reabrir comandas viejas.
+ * - Una comanda es urgente si el pedido se marcó urgente (al enviar o mientras
+ *   está en preparación) o si trae algún ítem urgente. Las urgentes van
+ *   primero en la pantalla de cocina, y entre ellas la más vieja.
+ * - Una comanda de un pedido para retirar lleva el horario prometido al
+ *   cliente; está atrasada si sale de cocina (o sigue en preparación)
+ *   después de ese horario.
+ */
+public class ComandaCocina {
+
+    public static final int MINUTOS_ALERTA_POR_DEFECTO = 15;
+    public static final int MINUTOS_PARA_RECUPERAR = 30;
+
+    /** HU-137: Orden de la pantalla de cocina */
+    public static final Comparator<ComandaCocina> PRIORIDAD_EN_COCINA =
+        Comparator.comparing((ComandaCocina comanda) -> !comanda.esUrgente())
+            .thenComparing(ComandaCocina::getEnviadaEn);
+
+    private final ComandaCocinaId id;
+    private final LocalId localId;
+    private final PedidoId pedidoId;
+    private final int numeroMesa;
+    private final int numeroPedido;
+    private final LocalDateTime enviadaEn;
+    private final int minutosAlerta;
+    private final List<ItemComandaCocina> items;
+    private LocalDateTime listaEn;
+    private boolean urgente;
+    private LocalDateTime horaPrometida;
+
+    public ComandaCocina(
+            ComandaCocinaId id,
+            LocalId localId,
+            PedidoId pedidoId,
+            int numeroMesa,
+            int numeroPedido,
+            LocalDateTime enviadaEn,
+            int minutosAlerta,
+            List<ItemComandaCocina> items,
+            LocalDateTime listaEn
+    ) {
+        this(id, localId, pedidoId, numeroMesa, numeroPedido, enviadaEn, minutosAlerta, items, listaEn, false);
+    }
+
+    public ComandaCocina(
+            ComandaCocinaId id,
+            LocalId localId,
+            PedidoId pedidoId,
+            int numeroMesa,
+            int numeroPedido,
+            LocalDateTime enviadaEn,
+            int minutosAlerta,
+            List<ItemComandaCocina> items,
+            LocalDateTime listaEn,
+            boolean urgente
+    ) {
+        this.id = Objects.requireNonNull(id, "El id de la comanda no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.pedidoId = Objects.requireNonNull(pedidoId, "El pedidoId no puede ser null");
+        this.enviadaEn = Objects.requireNonNull(enviadaEn, "La fecha de envío no puede ser null");
+        if (minutosAlerta <= 0) {
+            throw new IllegalArgumentException("Los minutos de alerta deben ser mayores a cero");
+        }
+        if (items == null || items.isEmpty()) {
+            throw new IllegalArgumentException("La comanda debe tener al menos un ítem");
+        }
+        if (listaEn != null && listaEn.isBefore(enviadaEn)) {
+            throw new IllegalArgumentException("La comanda no puede estar lista antes de enviarse");
+        }
+        this.numeroMesa = numeroMesa;
+        this.numeroPedido = numeroPedido;
+        this.minutosAlerta = minutosAlerta;
+        this.items = List.copyOf(items);
+        this.listaEn = listaEn;
+        this.urgente = urgente;
+    }
+
+    /**
+     * Crea la comanda de un envío a cocina, todavía en preparación.
+     */
+    public static ComandaCocina enviar(LocalId localId, PedidoId pedidoId, int numeroMesa, int numeroPedido,
+                                       LocalDateTime enviadaEn, int minutosAlerta,
+                                       List<ItemComandaCocina> items) {
+        return enviar(localId, pedidoId, numeroMesa, numeroPedido, enviadaEn, minutosAlerta, items, false);
+    }
+
+    /**
+     * HU-137: Crea la comanda de un envío, urgente si el pedido está marcado urgente.
+     */
+    public static ComandaCocina enviar(LocalId localId, PedidoId pedidoId, int numeroMesa, int numeroPedido,
+                                       LocalDateTime enviadaEn, int minutosAlerta,
+                                       List<ItemComandaCocina> items, boolean pedidoUrgente) {
+        return new ComandaCocina(ComandaCocinaId.generate(), localId, pedidoId, numeroMesa, numeroPedido,
+            enviadaEn, minutosAlerta, items, null, pedidoUrgente);
+    }
+
+    /**
+     * Resuelve el umbral de una comanda a partir de los minutos de las categorías
+     * de sus ítems (los null se ignoran).
+     */
+    public static int resolverMinutosAlerta(Collection<Integer> minutosPorCategoria) {
+        return minutosPorCategoria.stream()
+            .filter(Objects::nonNull)
+            .max(Integer::compare)
+            .orElse(MINUTOS_ALERTA_POR_DEFECTO);
+    }
+
+    /**
+     * @throws IllegalStateException si ya estaba lista
+     */
+    public void marcarLista(LocalDateTime ahora) {
+        Objects.requireNonNull(ahora, "La fecha es obligatoria");
+        if (estaLista()) {
+            throw new IllegalStateException("La comanda ya está marcada como lista");
+        }
+        this.listaEn = ahora.isBefore(enviadaEn) ? enviadaEn : ahora;
+    }
+
+    /**
+     * HU-136: Devuelve a preparación una comanda marcada lista por error.
+     *
+     * @throws IllegalStateException si no está lista o se marcó hace más de {@link #MINUTOS_PARA_RECUPERAR} minutos
+     */
+    public void recuperar(LocalDateTime ahora) {
+        Objects.requireNonNull(ahora, "La fecha es obligatoria");
+        if (!estaLista()) {
+            throw new IllegalStateException("La comanda sigue en preparación");
+        }
+        if (Duration.between(listaEn, ahora).compareTo(Duration.ofMinutes(MINUTOS_PARA_RECUPERAR)) > 0) {
+            throw new IllegalStateException(
+                "Solo se puede recuperar una comanda marcada lista hace menos de " + MINUTOS_PARA_RECUPERAR + " minutos"
+            );
+        }
+        this.listaEn = null;
+    }
+
+    public boolean estaLista() {
+        return listaEn != null;
+    }
+
+    /**
+     * HU-137: Sigue la marca del pedido mientras la comanda está en cocina.
+     * Desmarcar el pedido no quita la urgencia de los ítems que salieron urgentes.
+     */
+    public void marcarUrgente(boolean urgente) {
+        this.urgente = urgente;
+    }
+
+    /**
+     * HU-137: Urgente por el pedido o por alguno de sus ítems.
+     */
+    public boolean esUrgente() {
+        return urgente || items.stream().anyMatch(ItemComandaCocina::isUrgente);
+    }
+
+    /**
+     * @return true si la urgencia viene del pedido (no solo de sus ítems)
+     */
+    public boolean isPedidoUrgente() {
+        return urgente;
+    }
+
+    /**
+     * Tiempo en cocina: hasta ahora si sigue en preparación, o el tiempo final si ya está lista.
+     */
+    public Duration tiempoEnCocina(LocalDateTime ahora) {
+        LocalDateTime fin = listaEn != null ? listaEn : ahora;
+        return fin.isBefore(enviadaEn) ? Duration.ZERO : Duration.between(enviadaEn, fin);
+    }
+
+    public boolean estaDemorada(LocalDateTime ahora) {
+        return tiempoEnCocina(ahora).compareTo(Duration.ofMinutes(minutosAlerta)) > 0;
+    }
+
+    /**
+     * HU-138: Copia el horario de retiro prometido del pedido (null si no tiene).
+     */
+    public void asignarHoraPrometida(LocalDateTime horaPrometida) {
+        this.horaPrometida = horaPrometida;
+    }
+
+    /**
+     * HU-138: Atrasada respecto de lo prometido al cliente.
+     * Para una comanda lista, cuenta el momento en que se marcó.
+     */
+    public boolean estaAtrasada(LocalDateTime ahora) {
+        if (horaPrometida == null) {
+            return false;
+        }
+        LocalDateTime fin = listaEn != null ? listaEn : ahora;
+        return fin.isAfter(horaPrometida);
+    }
+
+    public ComandaCocinaId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public PedidoId getPedidoId() {
+        return pedidoId;
+    }
+
+    public int getNumeroMesa() {
+        return numeroMesa;
+    }
+
+    public int getNumeroPedido() {
+        return numeroPedido;
+    }
+
+    public LocalDateTime getEnviadaEn() {
+        return enviadaEn;
+    }
+
+    public int getMinutosAlerta() {
+        return minutosAlerta;
+    }
+
+    public List<ItemComandaCocina> getItems() {
+        return items;
+    }
+
+    public LocalDateTime getListaEn() {
+        return listaEn;
+    }
+
+    public LocalDateTime getHoraPrometida() {
+        return horaPrometida;
+    }
+}
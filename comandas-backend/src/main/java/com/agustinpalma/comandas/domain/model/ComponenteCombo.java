@@ -0,0 +1,102 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import java.util.LinkedHashSet;
+import java.util.List;
+import java.util.Objects;
+import java.util.Set;
+
+/**
+ * Value Object que representa un componente de un combo del catálogo.
+ *
+ * HU-103: Combos y menú del día.
+ *
+ * Un combo (ej: "Combo Burger" a precio cerrado) se compone de uno o más
+ * componentes (ej: "Principal", "Acompañamiento", "Bebida"). Cada componente
+ * define las opciones entre las que el cliente puede elegir.
+ *
+ * Reglas de negocio:
+ * - El nombre del componente es obligatorio
+ * - Debe tener al menos una opción (si tiene una sola, el componente es fijo)
+ * - Las opciones no pueden repetirse
+ */
+public final class ComponenteCombo {
+
+    private final String nombre;
+    private final List<ProductoId> opciones;
+
+    /**
+     * @param nombre nombre del componente (ej: "Bebida")
+     * @param opciones productos elegibles para este componente
+     * @throws IllegalArgumentException si el nombre está vacío o no hay opciones válidas
+     */
+    public ComponenteCombo(String nombre, List<ProductoId> opciones) {
+        this.nombre = validarNombre(nombre);
+        this.opciones = validarOpciones(opciones);
+    }
+
+    private String validarNombre(String nombre) {
+        if (nombre == null || nombre.isBlank()) {
+            throw new IllegalArgumentException("El nombre del componente del combo no puede estar vacío");
+        }
+        return nombre.trim();
+    }
+
+    private List<ProductoId> validarOpciones(List<ProductoId> opciones) {
+        if (opciones == null || opciones.isEmpty()) {
+            throw new IllegalArgumentException(
+                String.format("El componente '%s' debe tener al menos una opción", nombre)
+            );
+        }
+        Set<ProductoId> unicas = new LinkedHashSet<>();
+        for (ProductoId opcion : opciones) {
+            Objects.requireNonNull(opcion, "Las opciones del componente no pueden ser null");
+            if (!unicas.add(opcion)) {
+                throw new IllegalArgumentException(
+                    String.format("El componente '%s' tiene opciones repetidas", nombre)
+                );
+            }
+        }
+        return List.copyOf(unicas);
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public List<ProductoId> getOpciones() {
+        return opciones;
+    }
+
+    /**
+     * Indica si el producto es una opción válida para este componente.
+     */
+    public boolean admite(ProductoId productoId) {
+        return opciones.contains(productoId);
+    }
+
+    /**
+     * Un componente con una única opción no requiere elección del cliente.
+     */
+    public boolean esFijo() {
+        return opciones.size() == 1;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        ComponenteCombo that = (ComponenteCombo) o;
+        return Objects.equals(nombre, that.nombre) && Objects.equals(opciones, that.opciones);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(nombre, opciones);
+    }
+
+    @Override
+    public String toString() {
+        return String.format("ComponenteCombo{nombre='%s', opciones=%d}", nombre, opciones.size());
+    }
+}
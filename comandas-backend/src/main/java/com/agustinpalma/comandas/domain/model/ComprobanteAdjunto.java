@@ -0,0 +1,58 @@
+package com.agustinpalma.comandas.domain.model;
+
+import java.util.Objects;
+import java.util.Set;
+
+/**
+ * Archivo del comprobante de una compra o gasto (foto del ticket o PDF de la factura).
+ *
+ * HU-117: Se guarda en la base local junto con el resto de los datos, para que
+ * el respaldo de la base incluya los comprobantes.
+ *
+ * Reglas de negocio:
+ * - Solo PDF o imagen (JPEG, PNG, WEBP)
+ * - Máximo 5 MB
+ */
+public final class ComprobanteAdjunto {
+
+    public static final int TAMANIO_MAXIMO_BYTES = 5 * 1024 * 1024;
+
+    private static final Set<String> TIPOS_PERMITIDOS = Set.of(
+        "application/pdf", "image/jpeg", "image/png", "image/webp"
+    );
+
+    private final String nombreArchivo;
+    private final String contentType;
+    private final byte[] contenido;
+
+    public ComprobanteAdjunto(String nombreArchivo, String contentType, byte[] contenido) {
+        if (nombreArchivo == null || nombreArchivo.isBlank()) {
+            throw new IllegalArgumentException("El nombre del archivo del comprobante es obligatorio");
+        }
+        if (contentType == null || !TIPOS_PERMITIDOS.contains(contentType)) {
+            throw new IllegalArgumentException("El comprobante debe ser un PDF o una imagen (JPEG, PNG, WEBP)");
+        }
+        Objects.requireNonNull(contenido, "El contenido del comprobante no puede ser null");
+        if (contenido.length == 0) {
+            throw new IllegalArgumentException("El archivo del comprobante está vacío");
+        }
+        if (contenido.length > TAMANIO_MAXIMO_BYTES) {
+            throw new IllegalArgumentException("El comprobante no puede superar los 5 MB");
+        }
+        this.nombreArchivo = nombreArchivo.trim();
+        this.contentType = contentType;
+        this.contenido = contenido.clone();
+    }
+
+    public String getNombreArchivo() {
+        return nombreArchivo;
+    }
+
+    public String getContentType() {
+        return contentType;
+    }
+
+    public byte[] getContenido() {
+        return contenido.clone();
+    }
+}
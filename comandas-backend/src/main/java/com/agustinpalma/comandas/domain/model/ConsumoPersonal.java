@@ -0,0 +1,104 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.ConsumoPersonalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Consumo interno de un empleado: la parte de un pedido cobrada A_CUENTA
+ * que se le imputa para controlar su tope mensual.
+ *
+ * HU-132: Límites de consumo interno del personal.
+ *
+ * Reglas de negocio:
+ * - Hay a lo sumo un consumo por pedido; al reabrirlo se elimina y al
+ *   corregirlo se ajusta el monto.
+ * - El monto es la suma de los pagos A_CUENTA del pedido (mayor a cero).
+ * - Se imputa al mes de la fecha de cierre del pedido.
+ */
+public class ConsumoPersonal {
+
+    private final ConsumoPersonalId id;
+    private final LocalId localId;
+    private final MozoId mozoId;
+    private final PedidoId pedidoId;
+    private BigDecimal monto;
+    private final LocalDateTime fecha;
+
+    public ConsumoPersonal(ConsumoPersonalId id, LocalId localId, MozoId mozoId, PedidoId pedidoId,
+                           BigDecimal monto, LocalDateTime fecha) {
+        this.id = Objects.requireNonNull(id, "El id del consumo no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.mozoId = Objects.requireNonNull(mozoId, "El empleado del consumo no puede ser null");
+        this.pedidoId = Objects.requireNonNull(pedidoId, "El pedido del consumo no puede ser null");
+        this.monto = validarMonto(monto);
+        this.fecha = Objects.requireNonNull(fecha, "La fecha del consumo no puede ser null");
+    }
+
+    /**
+     * Imputa al empleado el consumo A_CUENTA de un pedido cerrado.
+     */
+    public static ConsumoPersonal registrar(Mozo empleado, Pedido pedido, BigDecimal monto, LocalDateTime fecha) {
+        Objects.requireNonNull(empleado, "El empleado no puede ser null");
+        Objects.requireNonNull(pedido, "El pedido no puede ser null");
+        return new ConsumoPersonal(ConsumoPersonalId.generate(), pedido.getLocalId(), empleado.getId(),
+            pedido.getId(), monto, fecha);
+    }
+
+    /**
+     * Ajusta el monto cuando se corrigen los pagos del pedido.
+     */
+    public void corregirMonto(BigDecimal monto) {
+        this.monto = validarMonto(monto);
+    }
+
+    private BigDecimal validarMonto(BigDecimal monto) {
+        Objects.requireNonNull(monto, "El monto del consumo no puede ser null");
+        if (monto.signum() <= 0) {
+            throw new IllegalArgumentException("El monto del consumo debe ser mayor a cero");
+        }
+        return monto;
+    }
+
+    public ConsumoPersonalId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public MozoId getMozoId() {
+        return mozoId;
+    }
+
+    public PedidoId getPedidoId() {
+        return pedidoId;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        ConsumoPersonal that = (ConsumoPersonal) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
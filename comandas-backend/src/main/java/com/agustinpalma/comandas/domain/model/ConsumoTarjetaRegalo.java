@@ -0,0 +1,42 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Uso de una tarjeta de regalo para pagar un pedido.
+ *
+ * HU-144: Queda el pedido en el que se usó para poder reintegrar el saldo
+ * si el pedido se reabre.
+ */
+public final class ConsumoTarjetaRegalo {
+
+    private final PedidoId pedidoId;
+    private final BigDecimal monto;
+    private final LocalDateTime fecha;
+
+    public ConsumoTarjetaRegalo(PedidoId pedidoId, BigDecimal monto, LocalDateTime fecha) {
+        this.pedidoId = Objects.requireNonNull(pedidoId, "El pedido del consumo es obligatorio");
+        Objects.requireNonNull(monto, "El monto del consumo es obligatorio");
+        if (monto.signum() <= 0) {
+            throw new IllegalArgumentException("El monto del consumo tiene que ser mayor a cero");
+        }
+        this.monto = monto;
+        this.fecha = Objects.requireNonNull(fecha, "La fecha del consumo es obligatoria");
+    }
+
+    public PedidoId getPedidoId() {
+        return pedidoId;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+}
@@ -0,0 +1,57 @@
+package com.agustinpalma.comandas.domain.model;
+
+import java.math.BigDecimal;
+import java.util.Objects;
+
+/**
+ * Renglón del conteo de cajón: cuántos billetes o monedas de una denominación hay.
+ *
+ * HU-123: El arqueo de turno guarda el detalle para poder reimprimir el
+ * comprobante y revisar cómo se llegó al total contado.
+ */
+public final class ConteoDenominacion {
+
+    private final BigDecimal denominacion;
+    private final int cantidad;
+
+    public ConteoDenominacion(BigDecimal denominacion, int cantidad) {
+        Objects.requireNonNull(denominacion, "La denominación es obligatoria");
+        if (denominacion.compareTo(BigDecimal.ZERO) <= 0) {
+            throw new IllegalArgumentException("La denominación debe ser mayor a cero");
+        }
+        if (cantidad < 0) {
+            throw new IllegalArgumentException("La cantidad de billetes de $" + denominacion.toPlainString()
+                + " no puede ser negativa");
+        }
+        this.denominacion = denominacion;
+        this.cantidad = cantidad;
+    }
+
+    /**
+     * @return denominación × cantidad
+     */
+    public BigDecimal subtotal() {
+        return denominacion.multiply(BigDecimal.valueOf(cantidad));
+    }
+
+    public BigDecimal getDenominacion() {
+        return denominacion;
+    }
+
+    public int getCantidad() {
+        return cantidad;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        ConteoDenominacion that = (ConteoDenominacion) o;
+        return cantidad == that.cantidad && denominacion.compareTo(that.denominacion) == 0;
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(denominacion.stripTrailingZeros(), cantidad);
+    }
+}
@@ -0,0 +1,84 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.util.Objects;
+
+/**
+ * Renglón de una toma de inventario: lo contado de un insumo contra su stock teórico.
+ *
+ * HU-133: El nombre, la unidad y el costo son un snapshot del momento del
+ * conteo, para que el reporte se siga leyendo igual aunque el insumo cambie.
+ */
+public final class ConteoInsumo {
+
+    private final InsumoId insumoId;
+    private final String nombreInsumo;
+    private final UnidadInsumo unidad;
+    private final BigDecimal stockTeorico;
+    private final BigDecimal stockContado;
+    private final BigDecimal costoUnitario;
+
+    public ConteoInsumo(InsumoId insumoId, String nombreInsumo, UnidadInsumo unidad, BigDecimal stockTeorico,
+                        BigDecimal stockContado, BigDecimal costoUnitario) {
+        this.insumoId = Objects.requireNonNull(insumoId, "El insumoId no puede ser null");
+        this.nombreInsumo = Objects.requireNonNull(nombreInsumo, "El nombre del insumo no puede ser null");
+        this.unidad = Objects.requireNonNull(unidad, "La unidad del insumo no puede ser null");
+        this.stockTeorico = Objects.requireNonNull(stockTeorico, "El stock teórico no puede ser null");
+        this.stockContado = Objects.requireNonNull(stockContado, "El stock contado no puede ser null");
+        this.costoUnitario = Objects.requireNonNull(costoUnitario, "El costo unitario no puede ser null");
+    }
+
+    /**
+     * Snapshot del insumo antes de ajustarlo al conteo.
+     */
+    public static ConteoInsumo de(Insumo insumo, BigDecimal contado) {
+        return new ConteoInsumo(insumo.getId(), insumo.getNombre(), insumo.getUnidad(), insumo.getStockActual(),
+            contado, insumo.getCostoUnitario());
+    }
+
+    /**
+     * Contado menos teórico: negativo si falta mercadería, positivo si sobra.
+     */
+    public BigDecimal getDiferencia() {
+        return stockContado.subtract(stockTeorico);
+    }
+
+    /**
+     * Diferencia al costo vigente del insumo, redondeada al centavo.
+     */
+    public BigDecimal getDiferenciaValorizada() {
+        return getDiferencia().multiply(costoUnitario).setScale(2, RoundingMode.HALF_UP);
+    }
+
+    public boolean tieneDiferencia() {
+        return getDiferencia().signum() != 0;
+    }
+
+    public InsumoId getInsumoId() {
+        return insumoId;
+    }
+
+    public String getNombreInsumo() {
+        return nombreInsumo;
+    }
+
+    public UnidadInsumo getUnidad() {
+        return unidad;
+    }
+
+    public BigDecimal getStockTeorico() {
+        return stockTeorico;
+    }
+
+    public BigDecimal getStockContado() {
+        return stockContado;
+    }
+
+    public BigDecimal getCostoUnitario() {
+        return costoUnitario;
+    }
+}
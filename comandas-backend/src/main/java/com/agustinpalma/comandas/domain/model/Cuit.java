@@ -0,0 +1,85 @@
+package com.agustinpalma.comandas.domain.model;
+
+import java.util.Objects;
+import java.util.Set;
+
+/**
+ * CUIT / CUIL de un contribuyente.
+ *
+ * HU-115: Emisión de Factura A/B/C.
+ *
+ * Value object: acepta el número con o sin guiones, y solo existe si
+ * tiene 11 dígitos, un prefijo válido y el dígito verificador correcto
+ * (módulo 11). Esto descarta los errores de tipeo antes de consultar
+ * el padrón.
+ */
+public final class Cuit {
+
+    private static final Set<String> PREFIJOS = Set.of("20", "23", "24", "25", "26", "27", "30", "33", "34");
+    private static final int[] PESOS = { 5, 4, 3, 2, 7, 6, 5, 4, 3, 2 };
+
+    private final String numero;
+
+    private Cuit(String numero) {
+        this.numero = numero;
+    }
+
+    /**
+     * @param valor CUIT/CUIL con o sin guiones ni espacios (ej: "20-12345678-6")
+     * @throws IllegalArgumentException si el formato o el dígito verificador no son válidos
+     */
+    public static Cuit of(String valor) {
+        if (valor == null || valor.isBlank()) {
+            throw new IllegalArgumentException("El CUIT es obligatorio");
+        }
+        String digitos = valor.replaceAll("[\\s-]", "");
+        if (!digitos.matches("\\d{11}")) {
+            throw new IllegalArgumentException("El CUIT debe tener 11 dígitos");
+        }
+        if (!PREFIJOS.contains(digitos.substring(0, 2))) {
+            throw new IllegalArgumentException("El CUIT " + valor + " tiene un prefijo inválido");
+        }
+        if (digitoVerificador(digitos) != digitos.charAt(10) - '0') {
+            throw new IllegalArgumentException("El CUIT " + valor + " no es válido (dígito verificador incorrecto)");
+        }
+        return new Cuit(digitos);
+    }
+
+    private static int digitoVerificador(String digitos) {
+        int suma = 0;
+        for (int i = 0; i < PESOS.length; i++) {
+            suma += (digitos.charAt(i) - '0') * PESOS[i];
+        }
+        int resto = 11 - (suma % 11);
+        if (resto == 11) return 0;
+        if (resto == 10) return 9;
+        return resto;
+    }
+
+    /** 11 dígitos sin separadores, como lo pide el módulo fiscal */
+    public String getNumero() {
+        return numero;
+    }
+
+    /** Formato de impresión: XX-XXXXXXXX-X */
+    public String formateado() {
+        return numero.substring(0, 2) + "-" + numero.substring(2, 10) + "-" + numero.substring(10);
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        return numero.equals(((Cuit) o).numero);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(numero);
+    }
+
+    @Override
+    public String toString() {
+        return formateado();
+    }
+}
@@ -1,6 +1,8 @@
 package com.agustinpalma.comandas.domain.model;
 
 import com.agustinpalma.comandas.domain.model.DomainEnums.ModoDescuento;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
 import java.math.BigDecimal;
 import java.math.RoundingMode;
 import java.time.LocalDateTime;
@@ -11,6 +13,7 @@ import java.util.UUID;
  * Value Object que representa un descuento manual aplicado a un pedido o ítem.
  * 
  * HU-14: Aplicar descuento inmediato por porcentaje o monto fijo.
+ * HU-122: Tope por rol y registro de quién autorizó el descuento.
  * 
  * A diferencia de las promociones automáticas (HU-10) que se capturan como snapshot inmutable,
  * el descuento manual es DINÁMICO: se guarda el tipo y valor, y el monto se recalcula cada vez
@@ -28,17 +31,25 @@ import java.util.UUID;
  * 
  * Regla de Oro: El descuento manual siempre se aplica DESPUÉS de las promociones automáticas.
  * Es decir, se calcula sobre el precio que ya tiene descontadas las promos de la HU-10.
+ * 
+ * Tope por rol (HU-122): un mozo puede descontar hasta el 10% de la base gravable
+ * (un monto fijo se mide contra ese mismo 10%); el encargado no tiene tope.
+ * El nombre de quien autorizó se guarda como snapshot, así el historial no cambia
+ * si después se renombra al empleado.
  */
 public final class DescuentoManual {
 
     private static final BigDecimal PORCENTAJE_MAXIMO = new BigDecimal("100");
     private static final BigDecimal CIEN = new BigDecimal("100");
+    private static final BigDecimal TOPE_PORCENTAJE_MOZO = new BigDecimal("10");
 
     private final ModoDescuento tipo;
     private final BigDecimal valor;
     private final String razon;
     private final UUID usuarioId;
     private final LocalDateTime fechaAplicacion;
+    private final MozoId autorizadoPorId;
+    private final String autorizadoPor;
 
     /**
      * Constructor principal con validación estricta.
@@ -58,6 +69,25 @@ public final class DescuentoManual {
             String razon, 
             UUID usuarioId, 
             LocalDateTime fechaAplicacion
+    ) {
+        this(tipo, valor, razon, usuarioId, fechaAplicacion, null, null);
+    }
+
+    /**
+     * HU-122: Constructor con el empleado que autorizó el descuento.
+     * 
+     * @param autorizadoPorId mozo o encargado que autorizó (null en descuentos previos a la HU-122
+     *                        o si el pedido no tenía mozo asignado)
+     * @param autorizadoPor nombre del empleado al momento de autorizar (snapshot)
+     */
+    public DescuentoManual(
+            ModoDescuento tipo,
+            BigDecimal valor,
+            String razon,
+            UUID usuarioId,
+            LocalDateTime fechaAplicacion,
+            MozoId autorizadoPorId,
+            String autorizadoPor
     ) {
         // Validar tipo
         Objects.requireNonNull(tipo, "El tipo de descuento no puede ser null");
@@ -91,6 +121,34 @@ public final class DescuentoManual {
         this.razon = razon;  // Se permite vacío, solo se valida que no sea null
         this.usuarioId = usuarioId;
         this.fechaAplicacion = fechaAplicacion;
+        this.autorizadoPorId = autorizadoPorId;
+        this.autorizadoPor = autorizadoPor;
+    }
+
+    /**
+     * HU-122: Verifica que el descuento no supere el tope del rol de quien lo autoriza.
+     * 
+     * @param rol rol del empleado que autoriza
+     * @param baseGravable base sobre la que se aplica (remanente del ítem o total de ítems)
+     * @throws IllegalStateException si un mozo intenta superar el 10% de la base
+     */
+    public void validarTope(RolPersonal rol, BigDecimal baseGravable) {
+        Objects.requireNonNull(rol, "El rol es obligatorio");
+        if (rol == RolPersonal.ENCARGADO) {
+            return;
+        }
+
+        BigDecimal tope = baseGravable.multiply(TOPE_PORCENTAJE_MOZO).divide(CIEN, 2, RoundingMode.HALF_UP);
+        boolean excede = tipo == ModoDescuento.PORCENTAJE
+            ? valor.compareTo(TOPE_PORCENTAJE_MOZO) > 0
+            : calcularMonto(baseGravable).compareTo(tope) > 0;
+        if (excede) {
+            throw new IllegalStateException(String.format(
+                "Un mozo puede aplicar hasta %s%% de descuento ($%s en este caso). "
+                    + "Para más se necesita la autorización de un encargado",
+                TOPE_PORCENTAJE_MOZO.toPlainString(), tope.toPlainString()
+            ));
+        }
     }
 
     /**
@@ -174,6 +232,24 @@ public final class DescuentoManual {
         return fechaAplicacion;
     }
 
+    /**
+     * HU-122: Empleado que autorizó el descuento.
+     * 
+     * @return MozoId del mozo o encargado, o null si no se registró
+     */
+    public MozoId getAutorizadoPorId() {
+        return autorizadoPorId;
+    }
+
+    /**
+     * HU-122: Nombre de quien autorizó, tal como se llamaba al momento de aplicar.
+     * 
+     * @return nombre o null si no se registró
+     */
+    public String getAutorizadoPor() {
+        return autorizadoPor;
+    }
+
     // ========================================
     // Value Object: equals, hashCode, toString
     // ========================================
@@ -187,20 +263,22 @@ public final class DescuentoManual {
                Objects.equals(valor, that.valor) &&
                Objects.equals(razon, that.razon) &&
                Objects.equals(usuarioId, that.usuarioId) &&
-               Objects.equals(fechaAplicacion, that.fechaAplicacion);
+               Objects.equals(fechaAplicacion, that.fechaAplicacion) &&
+               Objects.equals(autorizadoPorId, that.autorizadoPorId) &&
+               Objects.equals(autorizadoPor, that.autorizadoPor);
     }
 
     @Override
     public int hashCode() {
-        return Objects.hash(tipo, valor, razon, usuarioId, fechaAplicacion);
+        return Objects.hash(tipo, valor, razon, usuarioId, fechaAplicacion, autorizadoPorId, autorizadoPor);
     }
 
     @Override
     public String toString() {
         String sufijo = tipo == ModoDescuento.PORCENTAJE ? "%" : "$";
         return String.format(
-            "DescuentoManual{tipo=%s, valor=%s%s, razon='%s', usuarioId=%s, fecha=%s}",
-            tipo, valor, sufijo, razon, usuarioId, fechaAplicacion
+            "DescuentoManual{tipo=%s, valor=%s%s, razon='%s', usuarioId=%s, fecha=%s, autorizadoPor=%s}",
+            tipo, valor, sufijo, razon, usuarioId, fechaAplicacion, autorizadoPor
         );
     }
 }
@@ -0,0 +1,205 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ModoRepartoPropinas;
+import com.agustinpalma.comandas.domain.model.DomainIds.DistribucionPropinasId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.Duration;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+
+/**
+ * Reparto del pozo de propinas de un turno de caja entre el personal que trabajó.
+ *
+ * HU-141: Pozo de propinas y distribución.
+ *
+ * Reglas de negocio:
+ * - Se calcula una vez, al cerrar el turno, con la regla vigente del local.
+ * - Pozo = suma de las propinas de los pedidos cerrados en el turno.
+ * - Participan los empleados que ficharon dentro del turno y cuyo rol
+ *   participa según la regla. Un fichaje sin salida cuenta hasta el cierre.
+ * - Cada uno cobra pozo × peso / suma de pesos, al centavo. Los centavos
+ *   que sobran del redondeo van a las partes con mayor resto.
+ * - Si nadie fichó, el pozo queda sin repartir y lo reparte el encargado a mano.
+ */
+public class DistribucionPropinas {
+
+    private final DistribucionPropinasId id;
+    private final LocalId localId;
+    private final TurnoCajaId turnoId;
+    private final LocalDateTime fecha;
+    private final ModoRepartoPropinas modo;
+    private final BigDecimal pozo;
+    private final List<PartePropina> partes;
+
+    public DistribucionPropinas(DistribucionPropinasId id, LocalId localId, TurnoCajaId turnoId,
+                                LocalDateTime fecha, ModoRepartoPropinas modo, BigDecimal pozo,
+                                List<PartePropina> partes) {
+        this.id = Objects.requireNonNull(id, "El id de la distribución no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.turnoId = Objects.requireNonNull(turnoId, "El turno de la distribución no puede ser null");
+        this.fecha = Objects.requireNonNull(fecha, "La fecha de la distribución no puede ser null");
+        this.modo = Objects.requireNonNull(modo, "El modo de reparto no puede ser null");
+        this.pozo = Objects.requireNonNull(pozo, "El pozo no puede ser null");
+        this.partes = List.copyOf(Objects.requireNonNull(partes, "Las partes no pueden ser null"));
+    }
+
+    /**
+     * Arma el pozo del turno y calcula la parte de cada empleado.
+     *
+     * @param pedidos  pedidos cerrados del turno
+     * @param fichajes fichajes que pueden solaparse con el turno
+     * @param personal personal del local, para conocer el rol de cada fichaje
+     * @throws IllegalStateException si el turno sigue abierto
+     */
+    public static DistribucionPropinas repartir(TurnoCaja turno, ReglaPropinas regla, List<Pedido> pedidos,
+                                                List<Fichaje> fichajes, List<Mozo> personal, LocalDateTime fecha) {
+        Objects.requireNonNull(turno, "El turno no puede ser null");
+        Objects.requireNonNull(regla, "La regla de propinas no puede ser null");
+        Objects.requireNonNull(pedidos, "Los pedidos no pueden ser null");
+        Objects.requireNonNull(fichajes, "Los fichajes no pueden ser null");
+        Objects.requireNonNull(personal, "El personal no puede ser null");
+        if (turno.estaAbierto()) {
+            throw new IllegalStateException("El pozo de propinas se reparte al cerrar el turno");
+        }
+
+        BigDecimal pozo = pedidos.stream()
+            .filter(p -> turno.getId().equals(p.getTurnoId()))
+            .map(Pedido::getPropina)
+            .reduce(BigDecimal.ZERO, BigDecimal::add)
+            .setScale(2, RoundingMode.HALF_UP);
+
+        Map<MozoId, Long> minutosPorEmpleado = new LinkedHashMap<>();
+        for (Fichaje fichaje : fichajes) {
+            long minutos = minutosDentroDelTurno(fichaje, turno);
+            if (minutos > 0) {
+                minutosPorEmpleado.merge(fichaje.getMozoId(), minutos, Long::sum);
+            }
+        }
+
+        Map<MozoId, Mozo> porId = personal.stream()
+            .collect(Collectors.toMap(Mozo::getId, Function.identity(), (a, b) -> a));
+        List<MozoId> participantes = new ArrayList<>();
+        List<Long> minutos = new ArrayList<>();
+        List<Long> pesos = new ArrayList<>();
+        minutosPorEmpleado.forEach((mozoId, min) -> {
+            Mozo empleado = porId.get(mozoId);
+            long peso = empleado != null ? regla.pesoDe(empleado.getRol(), min) : 0;
+            if (peso > 0) {
+                participantes.add(mozoId);
+                minutos.add(min);
+                pesos.add(peso);
+            }
+        });
+
+        List<BigDecimal> montos = repartirCentavos(pozo, pesos);
+        List<PartePropina> partes = new ArrayList<>();
+        for (int i = 0; i < participantes.size(); i++) {
+            partes.add(new PartePropina(participantes.get(i), minutos.get(i), pesos.get(i), montos.get(i)));
+        }
+        partes.sort(Comparator.comparing(PartePropina::getMonto).reversed());
+
+        return new DistribucionPropinas(DistribucionPropinasId.generate(), turno.getLocalId(), turno.getId(),
+            fecha, regla.getModo(), pozo, partes);
+    }
+
+    private static long minutosDentroDelTurno(Fichaje fichaje, TurnoCaja turno) {
+        LocalDateTime desde = fichaje.getEntrada().isAfter(turno.getFechaApertura())
+            ? fichaje.getEntrada() : turno.getFechaApertura();
+        LocalDateTime salida = fichaje.getSalida() != null ? fichaje.getSalida() : turno.getFechaCierre();
+        LocalDateTime hasta = salida.isBefore(turno.getFechaCierre()) ? salida : turno.getFechaCierre();
+        return hasta.isAfter(desde) ? Duration.between(desde, hasta).toMinutes() : 0;
+    }
+
+    /**
+     * Reparto al centavo por el método del mayor resto: la suma de las
+     * partes da exactamente el pozo.
+     */
+    private static List<BigDecimal> repartirCentavos(BigDecimal pozo, List<Long> pesos) {
+        long total = pesos.stream().mapToLong(Long::longValue).sum();
+        if (total == 0) {
+            return List.of();
+        }
+        long centavos = pozo.movePointRight(2).longValueExact();
+        long[] partes = new long[pesos.size()];
+        long[] restos = new long[pesos.size()];
+        long asignados = 0;
+        for (int i = 0; i < pesos.size(); i++) {
+            partes[i] = centavos * pesos.get(i) / total;
+            restos[i] = centavos * pesos.get(i) % total;
+            asignados += partes[i];
+        }
+        List<Integer> porResto = new ArrayList<>();
+        for (int i = 0; i < pesos.size(); i++) {
+            porResto.add(i);
+        }
+        porResto.sort(Comparator.comparingLong((Integer i) -> restos[i]).reversed());
+        for (int k = 0; asignados < centavos; k++, asignados++) {
+            partes[porResto.get(k)]++;
+        }
+        List<BigDecimal> montos = new ArrayList<>();
+        for (long parte : partes) {
+            montos.add(BigDecimal.valueOf(parte, 2));
+        }
+        return montos;
+    }
+
+    /**
+     * @return lo que no se pudo repartir porque nadie participó del turno
+     */
+    public BigDecimal getSinRepartir() {
+        return pozo.subtract(partes.stream().map(PartePropina::getMonto).reduce(BigDecimal.ZERO, BigDecimal::add));
+    }
+
+    public DistribucionPropinasId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public TurnoCajaId getTurnoId() {
+        return turnoId;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public ModoRepartoPropinas getModo() {
+        return modo;
+    }
+
+    public BigDecimal getPozo() {
+        return pozo;
+    }
+
+    public List<PartePropina> getPartes() {
+        return partes;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        DistribucionPropinas that = (DistribucionPropinas) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
@@ -20,12 +20,20 @@ public final class DomainEnums {
         CERRADO
     }
 
+    /**
+     * Medios de pago aceptados al cerrar un pedido.
+     * A_CUENTA: consumo interno del personal/dueños (no es venta real).
+     * CUENTA_CORRIENTE: HU-104, venta fiada a un cliente que paga más adelante.
+     */
     public enum MedioPago {
         EFECTIVO,
         TARJETA,
         TRANSFERENCIA,
         QR,
-        A_CUENTA
+        A_CUENTA,
+        CUENTA_CORRIENTE,
+        /** HU-144: Gift card prepaga; el pago consume saldo de la tarjeta indicada */
+        TARJETA_REGALO
     }
 
     public enum TipoEstrategia {
@@ -104,4 +112,359 @@ public final class DomainEnums {
         AJUSTE_MANUAL,
         INGRESO_MERCADERIA
     }
+
+    /**
+     * HU-104: Tipos de movimiento en la cuenta corriente de un cliente.
+     * CARGO: pedido cerrado a cuenta corriente (aumenta la deuda)
+     * PAGO: pago total o parcial del cliente (reduce la deuda)
+     */
+    public enum TipoMovimientoCuenta {
+        CARGO,
+        PAGO
+    }
+
+    /**
+     * HU-105: Tipos de movimiento en el programa de puntos.
+     * ACUMULACION: puntos ganados al cerrar un pedido (con vencimiento opcional)
+     * CANJE: puntos usados como descuento en un pedido
+     */
+    public enum TipoMovimientoPuntos {
+        ACUMULACION,
+        CANJE
+    }
+
+    /**
+     * HU-106: Turnos de caja dentro de una jornada.
+     * Cada tipo puede abrirse a lo sumo una vez por jornada.
+     */
+    public enum TipoTurno {
+        MANANA,
+        TARDE,
+        NOCHE
+    }
+
+    /**
+     * HU-106: Estado de un turno de caja.
+     * Ciclo de vida: ABIERTO → CERRADO (irreversible).
+     */
+    public enum EstadoTurno {
+        ABIERTO,
+        CERRADO
+    }
+
+    /**
+     * HU-108: Canal por el que entra un pedido.
+     * Define qué lista de precios toma el pedido al crearse.
+     */
+    public enum CanalVenta {
+        SALON,
+        TAKE_AWAY,
+        DELIVERY
+    }
+
+    /**
+     * HU-110: Paso de servicio de un ítem (entrada → principal → postre).
+     * El orden de declaración es el orden en que se marchan a cocina.
+     */
+    public enum TiempoServicio {
+        ENTRADA,
+        PRINCIPAL,
+        POSTRE
+    }
+
+    /**
+     * HU-115: Condición frente al IVA del emisor o del receptor de una factura.
+     */
+    public enum CondicionIva {
+        RESPONSABLE_INSCRIPTO,
+        MONOTRIBUTO,
+        EXENTO,
+        CONSUMIDOR_FINAL
+    }
+
+    /**
+     * HU-115: Letra de la factura (A: entre inscriptos, B: inscripto a consumidor
+     * final o exento, C: emisor monotributista o exento).
+     */
+    public enum TipoFactura {
+        A,
+        B,
+        C
+    }
+
+    /**
+     * HU-115: Una factura queda PENDIENTE hasta que el módulo fiscal le asigna CAE.
+     */
+    public enum EstadoFactura {
+        PENDIENTE_AUTORIZACION,
+        AUTORIZADA
+    }
+
+    /**
+     * HU-117: Categoría de una compra o gasto del local.
+     * MERCADERIA es la única que admite ítems que ingresan al stock.
+     */
+    public enum CategoriaGasto {
+        MERCADERIA,
+        ALQUILER,
+        SERVICIOS,
+        SUELDOS,
+        IMPUESTOS,
+        MANTENIMIENTO,
+        OTROS
+    }
+
+    /**
+     * HU-118: Unidad en la que se compra y se dosifica un insumo.
+     * El costo unitario y las cantidades de la receta se expresan en la misma unidad.
+     */
+    public enum UnidadInsumo {
+        UNIDAD,
+        KILOGRAMO,
+        LITRO
+    }
+
+    /**
+     * HU-121: Rol del personal del salón.
+     * ENCARGADO: autoriza con su PIN las operaciones sensibles (devoluciones post-cierre).
+     * HU-122: El mozo descuenta hasta un 10%; el encargado no tiene tope.
+     * HU-124: DUENO: además de todo lo del encargado, autoriza correcciones de fichaje.
+     * HU-140: REPARTIDOR: cadete de delivery; lleva los pedidos y rinde el efectivo cobrado.
+     */
+    public enum RolPersonal {
+        MOZO,
+        ENCARGADO,
+        DUENO,
+        REPARTIDOR
+    }
+
+    /**
+     * HU-124: Cómo se identificó el empleado al fichar en la terminal.
+     * MANUAL: marca cargada o corregida por el dueño.
+     */
+    public enum MedioFichaje {
+        PIN,
+        TARJETA,
+        MANUAL
+    }
+
+    /**
+     * HU-126: Estado de un pedido que el cliente armó desde el QR de la mesa.
+     * PENDIENTE: espera que el mozo lo valide; no llega a cocina hasta confirmarse.
+     */
+    public enum EstadoSolicitudAutopedido {
+        PENDIENTE,
+        CONFIRMADA,
+        RECHAZADA
+    }
+
+    /**
+     * HU-128: Pasarela con la que se genera un link de pago.
+     */
+    public enum ProveedorLinkPago {
+        MERCADO_PAGO,
+        MODO
+    }
+
+    /**
+     * HU-128: Estado de un link de pago.
+     * PENDIENTE: enviado al cliente; el pedido no pasa a cocina hasta que se acredite.
+     * CANCELADO: reemplazado por otro link o descartado por el operador.
+     */
+    public enum EstadoLinkPago {
+        PENDIENTE,
+        PAGADO,
+        CANCELADO
+    }
+
+    /**
+     * HU-131: Situación de pago de una factura de proveedor.
+     * Se deriva de los pagos asentados; que esté vencida depende de la fecha del día.
+     */
+    public enum EstadoFacturaProveedor {
+        PENDIENTE,
+        PAGADA_PARCIAL,
+        PAGADA
+    }
+
+    /**
+     * HU-133: Tipos de movimiento en el stock de un insumo.
+     * CONSUMO_VENTA: lo que descuenta la receta al cerrar un pedido
+     * REAPERTURA_PEDIDO: devuelve el consumo de un pedido reabierto
+     * AJUSTE_INVENTARIO: lleva el stock teórico al conteo físico
+     * HU-135: MERMA: mercadería que se tiró (vencida, plato caído, error de cocina)
+     */
+    public enum TipoMovimientoInsumo {
+        CONSUMO_VENTA,
+        REAPERTURA_PEDIDO,
+        AJUSTE_INVENTARIO,
+        MERMA
+    }
+
+    /**
+     * HU-135: Categoría de una merma, para ver dónde se pierde plata.
+     * PLATO_CAIDO: un plato ya preparado que no llegó a venderse (descuenta su receta)
+     * ERROR_COCINA: preparación mal hecha o pedido equivocado que se rehízo
+     */
+    public enum CategoriaMerma {
+        VENCIMIENTO,
+        PLATO_CAIDO,
+        ERROR_COCINA,
+        OTRO
+    }
+
+    /**
+     * HU-141: Cómo se reparte el pozo de propinas del turno entre el personal que trabajó.
+     * POR_HORAS: en proporción a los minutos fichados dentro del turno
+     * POR_PUESTO: en proporción a los puntos del rol de cada uno
+     */
+    public enum ModoRepartoPropinas {
+        PARTES_IGUALES,
+        POR_HORAS,
+        POR_PUESTO
+    }
+
+    /**
+     * HU-142: Estado de una reserva de mesa.
+     * CUMPLIDA: el cliente llegó y la reserva quedó vinculada al pedido de su mesa
+     * CANCELADA: no viene; la seña cobrada queda en la caja del día en que se cobró
+     */
+    public enum EstadoReserva {
+        PENDIENTE,
+        CUMPLIDA,
+        CANCELADA
+    }
+
+    /**
+     * HU-144: Estado de una tarjeta de regalo, derivado de su saldo y vencimiento.
+     * AGOTADA: se usó todo el saldo
+     * VENCIDA: pasó la fecha de vencimiento con saldo sin usar
+     */
+    public enum EstadoTarjetaRegalo {
+        VIGENTE,
+        AGOTADA,
+        VENCIDA
+    }
+
+    /**
+     * HU-145: Estado de un aumento masivo de precios.
+     * PROGRAMADO: espera su fecha de vigencia; todavía se puede cancelar
+     * APLICADO: los precios del catálogo ya cambiaron
+     */
+    public enum EstadoAumentoPrecios {
+        PROGRAMADO,
+        APLICADO,
+        CANCELADO
+    }
+
+    /**
+     * HU-146: De dónde salió un cambio de precio del catálogo.
+     * EDICION: alguien editó el producto a mano
+     * AUMENTO_MASIVO: lo cambió un aumento porcentual (HU-145)
+     */
+    public enum OrigenCambioPrecio {
+        EDICION,
+        AUMENTO_MASIVO
+    }
+
+    /**
+     * HU-147: Alérgenos que puede contener un producto y que un comensal puede
+     * declarar como advertencia al cargar su ítem.
+     * TACC: trigo, avena, cebada o centeno (celíacos)
+     * LACTOSA: leche y derivados
+     */
+    public enum Alergeno {
+        TACC,
+        MANI,
+        FRUTOS_SECOS,
+        LACTOSA,
+        HUEVO,
+        PESCADO,
+        MARISCOS,
+        SOJA,
+        SESAMO
+    }
+
+    /**
+     * HU-149: Estado de un cliente en la lista de espera de mesas.
+     * AVISADO: se le avisó que tiene una mesa lista y se lo espera
+     * SENTADO: ocupó la mesa; sale de la lista
+     * CANCELADO: se fue o no respondió al aviso
+     */
+    public enum EstadoEspera {
+        ESPERANDO,
+        AVISADO,
+        SENTADO,
+        CANCELADO
+    }
+
+    /**
+     * HU-151: Moneda en la que el cliente entrega un pago.
+     * Los precios y totales siempre están en pesos; USD y EUR se convierten
+     * con el tipo de cambio del día cargado por el local.
+     */
+    public enum Moneda {
+        ARS,
+        USD,
+        EUR
+    }
+
+    /**
+     * HU-152: Sentido del redondeo del efectivo a cobrar.
+     * CERCANO = al múltiplo más cercano (los medios suben),
+     * ABAJO = siempre a favor del cliente, ARRIBA = siempre a favor del local.
+     */
+    public enum ModoRedondeo {
+        CERCANO,
+        ABAJO,
+        ARRIBA
+    }
+
+    /**
+     * HU-167: Sistema contable al que se exportan los asientos del mes.
+     * Cada uno importa los asientos con su propio diseño de archivo.
+     */
+    public enum SistemaContable {
+        TANGO,
+        XUBIO,
+        CONTABILIUM
+    }
+
+    /**
+     * HU-167: Concepto de los asientos exportados, al que el estudio contable
+     * le asigna una cuenta de su plan de cuentas.
+     * Los medios (EFECTIVO, TARJETA...) son de donde entra o sale la plata;
+     * los GASTO_* siguen las categorías de gasto.
+     */
+    public enum ConceptoContable {
+        VENTAS,
+        IVA_DEBITO_FISCAL,
+        EFECTIVO,
+        TARJETA,
+        TRANSFERENCIA,
+        QR,
+        CUENTA_CORRIENTE_CLIENTES,
+        TARJETA_REGALO,
+        PROVEEDORES,
+        GASTO_MERCADERIA,
+        GASTO_ALQUILER,
+        GASTO_SERVICIOS,
+        GASTO_SUELDOS,
+        GASTO_IMPUESTOS,
+        GASTO_MANTENIMIENTO,
+        GASTO_OTROS
+    }
+
+    /**
+     * HU-169: Acción sensible que el dueño habilita o no por rol o por empleado.
+     * ANULAR_ITEMS: quitar o bajar la cantidad de ítems ya enviados a cocina.
+     * ABRIR_CAJON: abrir el cajón de dinero sin un cobro de por medio.
+     */
+    public enum Permiso {
+        ANULAR_ITEMS,
+        APLICAR_DESCUENTOS,
+        ABRIR_CAJON,
+        VER_REPORTES,
+        MODIFICAR_PRECIOS
+    }
 }
@@ -555,4 +555,1360 @@ public final class DomainIds {
             return value.toString();
         }
     }
+
+    // ============================================
+    // CUENTA CORRIENTE AGGREGATE
+    // ============================================
+
+    /**
+     * Identidad de un cliente con cuenta corriente.
+     * HU-104: Cuenta corriente de clientes.
+     */
+    public static final class ClienteId {
+        private final UUID value;
+
+        public ClienteId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("ClienteId no puede ser null");
+            this.value = value;
+        }
+
+        public static ClienteId generate() {
+            return new ClienteId(UUID.randomUUID());
+        }
+
+        public static ClienteId from(String value) {
+            return new ClienteId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            ClienteId that = (ClienteId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de un movimiento (cargo o pago) de cuenta corriente.
+     */
+    public static final class MovimientoCuentaCorrienteId {
+        private final UUID value;
+
+        public MovimientoCuentaCorrienteId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("MovimientoCuentaCorrienteId no puede ser null");
+            this.value = value;
+        }
+
+        public static MovimientoCuentaCorrienteId generate() {
+            return new MovimientoCuentaCorrienteId(UUID.randomUUID());
+        }
+
+        public static MovimientoCuentaCorrienteId from(String value) {
+            return new MovimientoCuentaCorrienteId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            MovimientoCuentaCorrienteId that = (MovimientoCuentaCorrienteId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    // ============================================
+    // PROGRAMA DE PUNTOS
+    // ============================================
+
+    /**
+     * Identidad de un movimiento (acumulación o canje) de puntos.
+     * HU-105: Programa de puntos de clientes.
+     */
+    public static final class MovimientoPuntosId {
+        private final UUID value;
+
+        public MovimientoPuntosId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("MovimientoPuntosId no puede ser null");
+            this.value = value;
+        }
+
+        public static MovimientoPuntosId generate() {
+            return new MovimientoPuntosId(UUID.randomUUID());
+        }
+
+        public static MovimientoPuntosId from(String value) {
+            return new MovimientoPuntosId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            MovimientoPuntosId that = (MovimientoPuntosId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    // ============================================
+    // TURNOS DE CAJA
+    // ============================================
+
+    /**
+     * Identidad de un turno de caja dentro de una jornada.
+     * HU-106: Gestión de turnos con arqueo por turno.
+     */
+    public static final class TurnoCajaId {
+        private final UUID value;
+
+        public TurnoCajaId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("TurnoCajaId no puede ser null");
+            this.value = value;
+        }
+
+        public static TurnoCajaId generate() {
+            return new TurnoCajaId(UUID.randomUUID());
+        }
+
+        public static TurnoCajaId from(String value) {
+            return new TurnoCajaId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            TurnoCajaId that = (TurnoCajaId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de una caja (puesto de cobro) del local.
+     * HU-159: Varias cajas en el mismo local.
+     */
+    public static final class CajaId {
+        private final UUID value;
+
+        public CajaId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("CajaId no puede ser null");
+            this.value = value;
+        }
+
+        public static CajaId generate() {
+            return new CajaId(UUID.randomUUID());
+        }
+
+        public static CajaId from(String value) {
+            return new CajaId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            CajaId that = (CajaId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    // ============================================
+    // SUCURSALES
+    // ============================================
+
+    /**
+     * Identidad de una instalación (sucursal) dentro de una red de locales.
+     * HU-107: Multi-sucursal con reportes consolidados.
+     */
+    public static final class SucursalId {
+        private final UUID value;
+
+        public SucursalId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("SucursalId no puede ser null");
+            this.value = value;
+        }
+
+        public static SucursalId generate() {
+            return new SucursalId(UUID.randomUUID());
+        }
+
+        public static SucursalId from(String value) {
+            return new SucursalId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            SucursalId that = (SucursalId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    // ============================================
+    // LISTAS DE PRECIOS
+    // ============================================
+
+    /**
+     * Identidad de una lista de precios por canal.
+     * HU-108: Listas de precios por canal.
+     */
+    public static final class ListaPreciosId {
+        private final UUID value;
+
+        public ListaPreciosId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("ListaPreciosId no puede ser null");
+            this.value = value;
+        }
+
+        public static ListaPreciosId generate() {
+            return new ListaPreciosId(UUID.randomUUID());
+        }
+
+        public static ListaPreciosId from(String value) {
+            return new ListaPreciosId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            ListaPreciosId that = (ListaPreciosId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    // ============================================
+    // MOZOS
+    // ============================================
+
+    /**
+     * Identidad de un mozo del salón.
+     * HU-111: Reporte de desempeño por mozo.
+     */
+    public static final class MozoId {
+        private final UUID value;
+
+        public MozoId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("MozoId no puede ser null");
+            this.value = value;
+        }
+
+        public static MozoId generate() {
+            return new MozoId(UUID.randomUUID());
+        }
+
+        public static MozoId from(String value) {
+            return new MozoId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            MozoId that = (MozoId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de una factura emitida.
+     * HU-115: Emisión de Factura A/B/C.
+     */
+    public static final class FacturaId {
+        private final UUID value;
+
+        public FacturaId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("FacturaId no puede ser null");
+            this.value = value;
+        }
+
+        public static FacturaId generate() {
+            return new FacturaId(UUID.randomUUID());
+        }
+
+        public static FacturaId from(String value) {
+            return new FacturaId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            FacturaId that = (FacturaId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de una compra o gasto registrado.
+     * HU-117: Compras a proveedores y gastos.
+     */
+    public static final class GastoId {
+        private final UUID value;
+
+        public GastoId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("GastoId no puede ser null");
+            this.value = value;
+        }
+
+        public static GastoId generate() {
+            return new GastoId(UUID.randomUUID());
+        }
+
+        public static GastoId from(String value) {
+            return new GastoId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            GastoId that = (GastoId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de un insumo (materia prima con costo unitario).
+     * HU-118: Costeo de recetas y rentabilidad por producto.
+     */
+    public static final class InsumoId {
+        private final UUID value;
+
+        public InsumoId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("InsumoId no puede ser null");
+            this.value = value;
+        }
+
+        public static InsumoId generate() {
+            return new InsumoId(UUID.randomUUID());
+        }
+
+        public static InsumoId from(String value) {
+            return new InsumoId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            InsumoId that = (InsumoId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de una comanda enviada a cocina.
+     * HU-119: Tiempos de cocina y alertas de demora.
+     */
+    public static final class ComandaCocinaId {
+        private final UUID value;
+
+        public ComandaCocinaId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("ComandaCocinaId no puede ser null");
+            this.value = value;
+        }
+
+        public static ComandaCocinaId generate() {
+            return new ComandaCocinaId(UUID.randomUUID());
+        }
+
+        public static ComandaCocinaId from(String value) {
+            return new ComandaCocinaId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            ComandaCocinaId that = (ComandaCocinaId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de un fichaje (entrada/salida) del personal.
+     * HU-124: Control horario del personal.
+     */
+    public static final class FichajeId {
+        private final UUID value;
+
+        public FichajeId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("FichajeId no puede ser null");
+            this.value = value;
+        }
+
+        public static FichajeId generate() {
+            return new FichajeId(UUID.randomUUID());
+        }
+
+        public static FichajeId from(String value) {
+            return new FichajeId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            FichajeId that = (FichajeId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de un pedido armado por el cliente desde el QR de la mesa.
+     * HU-126: Autopedido por QR.
+     */
+    public static final class SolicitudAutopedidoId {
+        private final UUID value;
+
+        public SolicitudAutopedidoId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("SolicitudAutopedidoId no puede ser null");
+            this.value = value;
+        }
+
+        public static SolicitudAutopedidoId generate() {
+            return new SolicitudAutopedidoId(UUID.randomUUID());
+        }
+
+        public static SolicitudAutopedidoId from(String value) {
+            return new SolicitudAutopedidoId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            SolicitudAutopedidoId that = (SolicitudAutopedidoId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de un link de pago enviado al cliente.
+     * HU-128: Links de pago para delivery.
+     */
+    public static final class LinkPagoId {
+        private final UUID value;
+
+        public LinkPagoId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("LinkPagoId no puede ser null");
+            this.value = value;
+        }
+
+        public static LinkPagoId generate() {
+            return new LinkPagoId(UUID.randomUUID());
+        }
+
+        public static LinkPagoId from(String value) {
+            return new LinkPagoId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            LinkPagoId that = (LinkPagoId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de una categoría de egresos de caja.
+     * HU-130: Categorías de egresos y reporte mensual de gastos.
+     */
+    public static final class CategoriaEgresoId {
+        private final UUID value;
+
+        public CategoriaEgresoId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("CategoriaEgresoId no puede ser null");
+            this.value = value;
+        }
+
+        public static CategoriaEgresoId generate() {
+            return new CategoriaEgresoId(UUID.randomUUID());
+        }
+
+        public static CategoriaEgresoId from(String value) {
+            return new CategoriaEgresoId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            CategoriaEgresoId that = (CategoriaEgresoId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de un proveedor del local.
+     * HU-131: Cuenta corriente de proveedores.
+     */
+    public static final class ProveedorId {
+        private final UUID value;
+
+        public ProveedorId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("ProveedorId no puede ser null");
+            this.value = value;
+        }
+
+        public static ProveedorId generate() {
+            return new ProveedorId(UUID.randomUUID());
+        }
+
+        public static ProveedorId from(String value) {
+            return new ProveedorId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            ProveedorId that = (ProveedorId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de una factura de proveedor a pagar.
+     * HU-131: Cuenta corriente de proveedores.
+     */
+    public static final class FacturaProveedorId {
+        private final UUID value;
+
+        public FacturaProveedorId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("FacturaProveedorId no puede ser null");
+            this.value = value;
+        }
+
+        public static FacturaProveedorId generate() {
+            return new FacturaProveedorId(UUID.randomUUID());
+        }
+
+        public static FacturaProveedorId from(String value) {
+            return new FacturaProveedorId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            FacturaProveedorId that = (FacturaProveedorId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de un consumo interno (A_CUENTA) imputado a un empleado.
+     * HU-132: Límites de consumo interno del personal.
+     */
+    public static final class ConsumoPersonalId {
+        private final UUID value;
+
+        public ConsumoPersonalId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("ConsumoPersonalId no puede ser null");
+            this.value = value;
+        }
+
+        public static ConsumoPersonalId generate() {
+            return new ConsumoPersonalId(UUID.randomUUID());
+        }
+
+        public static ConsumoPersonalId from(String value) {
+            return new ConsumoPersonalId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            ConsumoPersonalId that = (ConsumoPersonalId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de un movimiento de stock de un insumo (auditoría).
+     * HU-133: Inventario físico con reporte de diferencias.
+     */
+    public static final class MovimientoInsumoId {
+        private final UUID value;
+
+        public MovimientoInsumoId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("MovimientoInsumoId no puede ser null");
+            this.value = value;
+        }
+
+        public static MovimientoInsumoId generate() {
+            return new MovimientoInsumoId(UUID.randomUUID());
+        }
+
+        public static MovimientoInsumoId from(String value) {
+            return new MovimientoInsumoId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            MovimientoInsumoId that = (MovimientoInsumoId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de una toma de inventario físico.
+     * HU-133: Inventario físico con reporte de diferencias.
+     */
+    public static final class TomaInventarioId {
+        private final UUID value;
+
+        public TomaInventarioId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("TomaInventarioId no puede ser null");
+            this.value = value;
+        }
+
+        public static TomaInventarioId generate() {
+            return new TomaInventarioId(UUID.randomUUID());
+        }
+
+        public static TomaInventarioId from(String value) {
+            return new TomaInventarioId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            TomaInventarioId that = (TomaInventarioId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de un registro de merma.
+     * HU-135: Registro de mermas y desperdicio.
+     */
+    public static final class MermaId {
+        private final UUID value;
+
+        public MermaId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("MermaId no puede ser null");
+            this.value = value;
+        }
+
+        public static MermaId generate() {
+            return new MermaId(UUID.randomUUID());
+        }
+
+        public static MermaId from(String value) {
+            return new MermaId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            MermaId that = (MermaId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de una zona de reparto.
+     * HU-139: Zonas de delivery con costo de envío.
+     */
+    public static final class ZonaDeliveryId {
+        private final UUID value;
+
+        public ZonaDeliveryId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("ZonaDeliveryId no puede ser null");
+            this.value = value;
+        }
+
+        public static ZonaDeliveryId generate() {
+            return new ZonaDeliveryId(UUID.randomUUID());
+        }
+
+        public static ZonaDeliveryId from(String value) {
+            return new ZonaDeliveryId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            ZonaDeliveryId that = (ZonaDeliveryId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de la rendición de efectivo de un repartidor.
+     * HU-140: Asignación y seguimiento de repartidores.
+     */
+    public static final class RendicionRepartidorId {
+        private final UUID value;
+
+        public RendicionRepartidorId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("RendicionRepartidorId no puede ser null");
+            this.value = value;
+        }
+
+        public static RendicionRepartidorId generate() {
+            return new RendicionRepartidorId(UUID.randomUUID());
+        }
+
+        public static RendicionRepartidorId from(String value) {
+            return new RendicionRepartidorId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            RendicionRepartidorId that = (RendicionRepartidorId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad del reparto del pozo de propinas de un turno.
+     * HU-141: Pozo de propinas y distribución.
+     */
+    public static final class DistribucionPropinasId {
+        private final UUID value;
+
+        public DistribucionPropinasId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("DistribucionPropinasId no puede ser null");
+            this.value = value;
+        }
+
+        public static DistribucionPropinasId generate() {
+            return new DistribucionPropinasId(UUID.randomUUID());
+        }
+
+        public static DistribucionPropinasId from(String value) {
+            return new DistribucionPropinasId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            DistribucionPropinasId that = (DistribucionPropinasId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de una reserva de mesa.
+     * HU-142: Señas de reservas aplicadas a la cuenta.
+     */
+    public static final class ReservaId {
+        private final UUID value;
+
+        public ReservaId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("ReservaId no puede ser null");
+            this.value = value;
+        }
+
+        public static ReservaId generate() {
+            return new ReservaId(UUID.randomUUID());
+        }
+
+        public static ReservaId from(String value) {
+            return new ReservaId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            ReservaId that = (ReservaId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de una tarjeta de regalo (gift card).
+     * HU-144: Gift cards y vouchers prepagos.
+     */
+    public static final class TarjetaRegaloId {
+        private final UUID value;
+
+        public TarjetaRegaloId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("TarjetaRegaloId no puede ser null");
+            this.value = value;
+        }
+
+        public static TarjetaRegaloId generate() {
+            return new TarjetaRegaloId(UUID.randomUUID());
+        }
+
+        public static TarjetaRegaloId from(String value) {
+            return new TarjetaRegaloId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            TarjetaRegaloId that = (TarjetaRegaloId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de un aumento masivo de precios.
+     * HU-145: Actualización masiva de precios.
+     */
+    public static final class AumentoPreciosId {
+        private final UUID value;
+
+        public AumentoPreciosId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("AumentoPreciosId no puede ser null");
+            this.value = value;
+        }
+
+        public static AumentoPreciosId generate() {
+            return new AumentoPreciosId(UUID.randomUUID());
+        }
+
+        public static AumentoPreciosId from(String value) {
+            return new AumentoPreciosId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            AumentoPreciosId that = (AumentoPreciosId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de un registro del historial de precios.
+     * HU-146: Historial de cambios de precios.
+     */
+    public static final class CambioPrecioProductoId {
+        private final UUID value;
+
+        public CambioPrecioProductoId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("CambioPrecioProductoId no puede ser null");
+            this.value = value;
+        }
+
+        public static CambioPrecioProductoId generate() {
+            return new CambioPrecioProductoId(UUID.randomUUID());
+        }
+
+        public static CambioPrecioProductoId from(String value) {
+            return new CambioPrecioProductoId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            CambioPrecioProductoId that = (CambioPrecioProductoId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de un cliente anotado en la lista de espera de mesas.
+     * HU-149: Lista de espera de mesas.
+     */
+    public static final class ClienteEnEsperaId {
+        private final UUID value;
+
+        public ClienteEnEsperaId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("ClienteEnEsperaId no puede ser null");
+            this.value = value;
+        }
+
+        public static ClienteEnEsperaId generate() {
+            return new ClienteEnEsperaId(UUID.randomUUID());
+        }
+
+        public static ClienteEnEsperaId from(String value) {
+            return new ClienteEnEsperaId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            ClienteEnEsperaId that = (ClienteEnEsperaId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
+
+    /**
+     * Identidad de un pago de un pedido.
+     * HU-160: Pagos a cuenta: se persisten por id, igual que los ítems.
+     */
+    public static final class PagoId {
+        private final UUID value;
+
+        public PagoId(UUID value) {
+            if (value == null) throw new IllegalArgumentException("PagoId no puede ser null");
+            this.value = value;
+        }
+
+        public static PagoId generate() {
+            return new PagoId(UUID.randomUUID());
+        }
+
+        public static PagoId from(String value) {
+            return new PagoId(UUID.fromString(value));
+        }
+
+        public UUID getValue() {
+            return value;
+        }
+
+        @Override
+        public boolean equals(Object o) {
+            if (this == o) return true;
+            if (o == null || getClass() != o.getClass()) return false;
+            PagoId that = (PagoId) o;
+            return Objects.equals(value, that.value);
+        }
+
+        @Override
+        public int hashCode() {
+            return Objects.hash(value);
+        }
+
+        @Override
+        public String toString() {
+            return value.toString();
+        }
+    }
 }
@@ -0,0 +1,91 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Moneda;
+
+import java.math.BigDecimal;
+import java.util.EnumMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+
+/**
+ * Value Object con el efectivo en moneda extranjera que entró al cajón.
+ *
+ * HU-151: El cierre discrimina el efectivo por moneda: los dólares y euros
+ * se cuentan aparte de los pesos. El balance de efectivo de la caja sigue
+ * expresado en pesos e incluye el equivalente de cada moneda; los pesos
+ * físicos en el cajón son el balance menos esos equivalentes.
+ *
+ * No es una entidad persistida: se calcula de los pagos EFECTIVO del día.
+ */
+public final class EfectivoMoneda {
+
+    private final Moneda moneda;
+    private final BigDecimal monto;
+    private final BigDecimal equivalentePesos;
+
+    /**
+     * @param monto            efectivo en la moneda, recargo incluido
+     * @param equivalentePesos lo mismo en pesos, al tipo de cambio de cada pago
+     */
+    public EfectivoMoneda(Moneda moneda, BigDecimal monto, BigDecimal equivalentePesos) {
+        this.moneda = Objects.requireNonNull(moneda, "La moneda no puede ser null");
+        this.monto = Objects.requireNonNull(monto, "El monto no puede ser null");
+        this.equivalentePesos = Objects.requireNonNull(equivalentePesos, "El equivalente en pesos no puede ser null");
+    }
+
+    /**
+     * Agrupa por moneda los pagos EFECTIVO en moneda extranjera de los pedidos.
+     *
+     * @return una entrada por moneda con cobros, en el orden de {@link Moneda}
+     */
+    public static List<EfectivoMoneda> discriminar(List<Pedido> pedidos) {
+        Map<Moneda, BigDecimal> montos = new EnumMap<>(Moneda.class);
+        Map<Moneda, BigDecimal> equivalentes = new EnumMap<>(Moneda.class);
+        for (Pedido pedido : pedidos) {
+            for (Pago pago : pedido.getPagos()) {
+                if (pago.getMedio() != MedioPago.EFECTIVO || !pago.esMonedaExtranjera()) {
+                    continue;
+                }
+                montos.merge(pago.getMoneda(), pago.getMontoCobradoOriginal(), BigDecimal::add);
+                equivalentes.merge(pago.getMoneda(), pago.getMontoCobrado(), BigDecimal::add);
+            }
+        }
+        return montos.entrySet().stream()
+            .map(entry -> new EfectivoMoneda(entry.getKey(), entry.getValue(), equivalentes.get(entry.getKey())))
+            .toList();
+    }
+
+    public Moneda getMoneda() {
+        return moneda;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+
+    public BigDecimal getEquivalentePesos() {
+        return equivalentePesos;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        EfectivoMoneda that = (EfectivoMoneda) o;
+        return moneda == that.moneda
+            && monto.compareTo(that.monto) == 0
+            && equivalentePesos.compareTo(that.equivalentePesos) == 0;
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(moneda);
+    }
+
+    @Override
+    public String toString() {
+        return String.format("EfectivoMoneda{moneda=%s, monto=%s, equivalentePesos=%s}", moneda, monto, equivalentePesos);
+    }
+}
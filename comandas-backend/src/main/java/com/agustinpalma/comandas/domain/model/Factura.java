@@ -0,0 +1,248 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CondicionIva;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoFactura;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoFactura;
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Factura electrónica emitida por un pedido cerrado.
+ *
+ * HU-115: Emisión de Factura A/B/C.
+ *
+ * Reglas de negocio:
+ * - Solo se factura un pedido CERRADO, y una sola vez.
+ * - La letra sale de la condición frente al IVA de emisor y receptor
+ *   (ver {@link #determinarTipo}); la A exige CUIT del receptor.
+ * - El importe es el total congelado del pedido. En A y B se discrimina
+ *   el IVA incluido en el precio; en C no hay IVA.
+ * - La numeración es correlativa por local, punto de venta y letra.
+ * - Nace PENDIENTE_AUTORIZACION y pasa a AUTORIZADA cuando el módulo
+ *   fiscal devuelve el CAE. Sin CAE no es un comprobante válido.
+ */
+public class Factura {
+
+    private static final BigDecimal CIEN = new BigDecimal("100");
+
+    private final FacturaId id;
+    private final LocalId localId;
+    private final PedidoId pedidoId;
+    private final TipoFactura tipo;
+    private final int puntoVenta;
+    private final long numero;
+    private final LocalDateTime fechaEmision;
+    private final Cuit receptorCuit;
+    private final String receptorRazonSocial;
+    private final CondicionIva receptorCondicionIva;
+    private final BigDecimal alicuotaIva;
+    private final BigDecimal importeNeto;
+    private final BigDecimal importeIva;
+    private final BigDecimal importeTotal;
+    private EstadoFactura estado;
+    private String cae;
+    private LocalDate caeVencimiento;
+
+    public Factura(FacturaId id, LocalId localId, PedidoId pedidoId, TipoFactura tipo, int puntoVenta,
+                   long numero, LocalDateTime fechaEmision, Cuit receptorCuit, String receptorRazonSocial,
+                   CondicionIva receptorCondicionIva, BigDecimal alicuotaIva, BigDecimal importeNeto,
+                   BigDecimal importeIva, BigDecimal importeTotal, EstadoFactura estado,
+                   String cae, LocalDate caeVencimiento) {
+        this.id = Objects.requireNonNull(id, "El id de la factura no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.pedidoId = Objects.requireNonNull(pedidoId, "El pedido de la factura es obligatorio");
+        this.tipo = Objects.requireNonNull(tipo, "El tipo de factura es obligatorio");
+        if (puntoVenta < 1 || puntoVenta > 99999) {
+            throw new IllegalArgumentException("El punto de venta debe estar entre 1 y 99999");
+        }
+        if (numero < 1) {
+            throw new IllegalArgumentException("El número de factura debe ser positivo");
+        }
+        this.puntoVenta = puntoVenta;
+        this.numero = numero;
+        this.fechaEmision = Objects.requireNonNull(fechaEmision, "La fecha de emisión es obligatoria");
+        this.receptorCuit = receptorCuit;
+        this.receptorRazonSocial = receptorRazonSocial;
+        this.receptorCondicionIva = Objects.requireNonNull(receptorCondicionIva,
+            "La condición frente al IVA del receptor es obligatoria");
+        this.alicuotaIva = Objects.requireNonNull(alicuotaIva, "La alícuota de IVA es obligatoria");
+        this.importeNeto = Objects.requireNonNull(importeNeto, "El importe neto es obligatorio");
+        this.importeIva = Objects.requireNonNull(importeIva, "El importe de IVA es obligatorio");
+        this.importeTotal = Objects.requireNonNull(importeTotal, "El importe total es obligatorio");
+        this.estado = Objects.requireNonNull(estado, "El estado de la factura es obligatorio");
+        this.cae = cae;
+        this.caeVencimiento = caeVencimiento;
+    }
+
+    /**
+     * Emite la factura de un pedido cerrado.
+     *
+     * @param condicionEmisor condición frente al IVA del local
+     * @param alicuotaIva     alícuota general (ej: 21) que se discrimina en A y B
+     * @param receptorCuit    obligatorio salvo para consumidor final
+     * @throws IllegalStateException si el pedido no está CERRADO
+     * @throws IllegalArgumentException si la combinación emisor/receptor no permite facturar
+     */
+    public static Factura emitir(FacturaId id, Pedido pedido, CondicionIva condicionEmisor, int puntoVenta,
+                                 long numero, Cuit receptorCuit, String receptorRazonSocial,
+                                 CondicionIva receptorCondicionIva, BigDecimal alicuotaIva,
+                                 LocalDateTime fechaEmision) {
+        Objects.requireNonNull(pedido, "El pedido es obligatorio");
+        if (pedido.getEstado() != EstadoPedido.CERRADO) {
+            throw new IllegalStateException("Solo se puede facturar un pedido cerrado");
+        }
+
+        TipoFactura tipo = determinarTipo(condicionEmisor, receptorCondicionIva);
+        if (receptorCondicionIva != CondicionIva.CONSUMIDOR_FINAL && receptorCuit == null) {
+            throw new IllegalArgumentException("El CUIT del receptor es obligatorio salvo para consumidor final");
+        }
+        if (tipo == TipoFactura.A && (receptorRazonSocial == null || receptorRazonSocial.isBlank())) {
+            throw new IllegalArgumentException("La razón social del receptor es obligatoria en la factura A");
+        }
+
+        BigDecimal total = pedido.getMontoTotalFinal();
+        BigDecimal alicuota = tipo == TipoFactura.C ? BigDecimal.ZERO : alicuotaIva;
+        BigDecimal neto = total.multiply(CIEN).divide(CIEN.add(alicuota), 2, RoundingMode.HALF_UP);
+
+        return new Factura(id, pedido.getLocalId(), pedido.getId(), tipo, puntoVenta, numero, fechaEmision,
+            receptorCuit, receptorRazonSocial == null ? null : receptorRazonSocial.trim(),
+            receptorCondicionIva, alicuota, neto, total.subtract(neto), total,
+            EstadoFactura.PENDIENTE_AUTORIZACION, null, null);
+    }
+
+    /**
+     * Letra de la factura según la condición frente al IVA.
+     * - Emisor monotributista o exento → C
+     * - Emisor inscripto y receptor inscripto o monotributista → A
+     * - Emisor inscripto y receptor exento o consumidor final → B
+     */
+    public static TipoFactura determinarTipo(CondicionIva emisor, CondicionIva receptor) {
+        Objects.requireNonNull(emisor, "La condición frente al IVA del emisor es obligatoria");
+        Objects.requireNonNull(receptor, "La condición frente al IVA del receptor es obligatoria");
+
+        return switch (emisor) {
+            case MONOTRIBUTO, EXENTO -> TipoFactura.C;
+            case RESPONSABLE_INSCRIPTO -> receptor == CondicionIva.RESPONSABLE_INSCRIPTO
+                    || receptor == CondicionIva.MONOTRIBUTO
+                ? TipoFactura.A
+                : TipoFactura.B;
+            case CONSUMIDOR_FINAL -> throw new IllegalArgumentException(
+                "Un emisor consumidor final no puede emitir facturas");
+        };
+    }
+
+    /**
+     * Registra la autorización del módulo fiscal.
+     *
+     * @throws IllegalStateException si la factura ya estaba autorizada
+     */
+    public void autorizar(String cae, LocalDate caeVencimiento) {
+        if (estado == EstadoFactura.AUTORIZADA) {
+            throw new IllegalStateException("La factura " + numeroFormateado() + " ya está autorizada");
+        }
+        if (cae == null || cae.isBlank()) {
+            throw new IllegalArgumentException("El CAE es obligatorio");
+        }
+        this.cae = cae.trim();
+        this.caeVencimiento = Objects.requireNonNull(caeVencimiento, "El vencimiento del CAE es obligatorio");
+        this.estado = EstadoFactura.AUTORIZADA;
+    }
+
+    public boolean estaAutorizada() {
+        return estado == EstadoFactura.AUTORIZADA;
+    }
+
+    /** Formato de impresión: PPPPP-NNNNNNNN */
+    public String numeroFormateado() {
+        return String.format("%05d-%08d", puntoVenta, numero);
+    }
+
+    public FacturaId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public PedidoId getPedidoId() {
+        return pedidoId;
+    }
+
+    public TipoFactura getTipo() {
+        return tipo;
+    }
+
+    public int getPuntoVenta() {
+        return puntoVenta;
+    }
+
+    public long getNumero() {
+        return numero;
+    }
+
+    public LocalDateTime getFechaEmision() {
+        return fechaEmision;
+    }
+
+    public Cuit getReceptorCuit() {
+        return receptorCuit;
+    }
+
+    public String getReceptorRazonSocial() {
+        return receptorRazonSocial;
+    }
+
+    public CondicionIva getReceptorCondicionIva() {
+        return receptorCondicionIva;
+    }
+
+    public BigDecimal getAlicuotaIva() {
+        return alicuotaIva;
+    }
+
+    public BigDecimal getImporteNeto() {
+        return importeNeto;
+    }
+
+    public BigDecimal getImporteIva() {
+        return importeIva;
+    }
+
+    public BigDecimal getImporteTotal() {
+        return importeTotal;
+    }
+
+    public EstadoFactura getEstado() {
+        return estado;
+    }
+
+    public String getCae() {
+        return cae;
+    }
+
+    public LocalDate getCaeVencimiento() {
+        return caeVencimiento;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        Factura that = (Factura) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
@@ -0,0 +1,205 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoFacturaProveedor;
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaProveedorId;
+import com.agustinpalma.comandas.domain.model.DomainIds.GastoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.temporal.ChronoUnit;
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Factura recibida de un proveedor que queda a pagar.
+ *
+ * HU-131: Cuenta corriente de proveedores.
+ *
+ * Reglas de negocio:
+ * - Nace de una compra registrada a cuenta corriente: el monto es el del gasto,
+ *   así el reporte de gastos la cuenta en el mes de la compra y no en el del pago.
+ * - El vencimiento no puede ser anterior a la emisión.
+ * - Admite pagos parciales; ninguno puede superar el saldo pendiente.
+ * - Una factura paga no admite más pagos.
+ */
+public class FacturaProveedor {
+
+    private static final int LONGITUD_MAXIMA_NUMERO = 30;
+
+    private final FacturaProveedorId id;
+    private final LocalId localId;
+    private final ProveedorId proveedorId;
+    private final GastoId gastoId;
+    private final String numero;
+    private final LocalDate fechaEmision;
+    private final LocalDate fechaVencimiento;
+    private final BigDecimal monto;
+    private final LocalDateTime fechaRegistro;
+    private final List<PagoProveedor> pagos;
+
+    public FacturaProveedor(FacturaProveedorId id, LocalId localId, ProveedorId proveedorId, GastoId gastoId,
+                            String numero, LocalDate fechaEmision, LocalDate fechaVencimiento, BigDecimal monto,
+                            LocalDateTime fechaRegistro, List<PagoProveedor> pagos) {
+        this.id = Objects.requireNonNull(id, "El id de la factura no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.proveedorId = Objects.requireNonNull(proveedorId, "El proveedorId no puede ser null");
+        this.gastoId = Objects.requireNonNull(gastoId, "La factura debe referenciar la compra que la originó");
+        this.numero = validarNumero(numero);
+        this.fechaEmision = Objects.requireNonNull(fechaEmision, "La fecha de emisión no puede ser null");
+        this.fechaVencimiento = Objects.requireNonNull(fechaVencimiento, "La fecha de vencimiento no puede ser null");
+        if (fechaVencimiento.isBefore(fechaEmision)) {
+            throw new IllegalArgumentException("El vencimiento de la factura no puede ser anterior a su emisión");
+        }
+        Objects.requireNonNull(monto, "El monto de la factura no puede ser null");
+        if (monto.compareTo(BigDecimal.ZERO) <= 0) {
+            throw new IllegalArgumentException("El monto de la factura debe ser mayor a cero");
+        }
+        this.monto = monto;
+        this.fechaRegistro = Objects.requireNonNull(fechaRegistro, "La fecha de registro no puede ser null");
+        this.pagos = new ArrayList<>(Objects.requireNonNull(pagos, "Los pagos no pueden ser null"));
+    }
+
+    /**
+     * Factura a pagar por una compra registrada a cuenta corriente del proveedor.
+     */
+    public static FacturaProveedor desdeCompra(Gasto compra, ProveedorId proveedorId, String numero,
+                                               LocalDate fechaVencimiento) {
+        return new FacturaProveedor(FacturaProveedorId.generate(), compra.getLocalId(), proveedorId, compra.getId(),
+            numero, compra.getFecha(), fechaVencimiento, compra.getMonto(), compra.getFechaRegistro(), List.of());
+    }
+
+    /**
+     * Asienta un pago contra la factura.
+     *
+     * @throws IllegalStateException si la factura ya está paga
+     * @throws IllegalArgumentException si el pago supera el saldo pendiente
+     */
+    public void registrarPago(PagoProveedor pago) {
+        Objects.requireNonNull(pago, "El pago no puede ser null");
+        validarPago(pago.getMonto());
+        pagos.add(pago);
+    }
+
+    /**
+     * Verifica que la factura admita un pago por ese monto, sin asentarlo.
+     * Permite validar antes de mover la caja.
+     *
+     * @throws IllegalStateException si la factura ya está paga
+     * @throws IllegalArgumentException si el monto supera el saldo pendiente
+     */
+    public void validarPago(BigDecimal montoPago) {
+        Objects.requireNonNull(montoPago, "El monto del pago no puede ser null");
+        if (estaPaga()) {
+            throw new IllegalStateException("La factura ya está paga");
+        }
+        BigDecimal saldo = getSaldo();
+        if (montoPago.compareTo(saldo) > 0) {
+            throw new IllegalArgumentException(
+                String.format("El pago ($%s) supera el saldo pendiente de la factura ($%s)", montoPago, saldo)
+            );
+        }
+    }
+
+    public BigDecimal getTotalPagado() {
+        return pagos.stream()
+            .map(PagoProveedor::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    public BigDecimal getSaldo() {
+        return monto.subtract(getTotalPagado());
+    }
+
+    public boolean estaPaga() {
+        return getSaldo().signum() == 0;
+    }
+
+    public EstadoFacturaProveedor getEstado() {
+        if (estaPaga()) {
+            return EstadoFacturaProveedor.PAGADA;
+        }
+        return pagos.isEmpty() ? EstadoFacturaProveedor.PENDIENTE : EstadoFacturaProveedor.PAGADA_PARCIAL;
+    }
+
+    public boolean estaVencida(LocalDate hoy) {
+        return !estaPaga() && fechaVencimiento.isBefore(hoy);
+    }
+
+    /**
+     * Días que faltan para el vencimiento; negativo si ya venció.
+     */
+    public long diasParaVencer(LocalDate hoy) {
+        return ChronoUnit.DAYS.between(hoy, fechaVencimiento);
+    }
+
+    private String validarNumero(String numero) {
+        if (numero == null || numero.isBlank()) {
+            return null;
+        }
+        String limpio = numero.trim();
+        if (limpio.length() > LONGITUD_MAXIMA_NUMERO) {
+            throw new IllegalArgumentException(
+                "El número de factura no puede superar los " + LONGITUD_MAXIMA_NUMERO + " caracteres"
+            );
+        }
+        return limpio;
+    }
+
+    public FacturaProveedorId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public ProveedorId getProveedorId() {
+        return proveedorId;
+    }
+
+    public GastoId getGastoId() {
+        return gastoId;
+    }
+
+    public String getNumero() {
+        return numero;
+    }
+
+    public LocalDate getFechaEmision() {
+        return fechaEmision;
+    }
+
+    public LocalDate getFechaVencimiento() {
+        return fechaVencimiento;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+
+    public LocalDateTime getFechaRegistro() {
+        return fechaRegistro;
+    }
+
+    public List<PagoProveedor> getPagos() {
+        return Collections.unmodifiableList(pagos);
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        FacturaProveedor that = (FacturaProveedor) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
@@ -0,0 +1,235 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioFichaje;
+import com.agustinpalma.comandas.domain.model.DomainIds.FichajeId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+
+import java.time.Duration;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Fichaje de un empleado en la terminal: una entrada y, al terminar, su salida.
+ *
+ * HU-124: Control horario del personal.
+ *
+ * Reglas de negocio:
+ * - Mientras no tiene salida, el empleado figura como presente.
+ * - La salida no puede ser anterior a la entrada.
+ * - Un fichaje abierto por más de {@link #DURACION_MAXIMA} se considera una
+ *   salida olvidada: la próxima marca abre un fichaje nuevo y este queda
+ *   incompleto hasta que el dueño lo corrija.
+ * - Las correcciones las autoriza el dueño, con motivo obligatorio. Se
+ *   conservan las marcas originales de la terminal para auditoría.
+ */
+public class Fichaje {
+
+    /** Más que esto sin marcar salida se toma como olvido */
+    public static final Duration DURACION_MAXIMA = Duration.ofHours(16);
+
+    private static final int LONGITUD_MAXIMA_MOTIVO = 200;
+
+    private final FichajeId id;
+    private final LocalId localId;
+    private final MozoId mozoId;
+    private final MedioFichaje medio;
+    private LocalDateTime entrada;
+    private LocalDateTime salida;
+
+    // Auditoría de corrección — null mientras no se corrigió
+    private LocalDateTime entradaOriginal;
+    private LocalDateTime salidaOriginal;
+    private String motivoCorreccion;
+    private MozoId corregidoPorId;
+    private LocalDateTime fechaCorreccion;
+
+    /**
+     * Constructor de entrada: el empleado marca al llegar.
+     */
+    public Fichaje(FichajeId id, LocalId localId, MozoId mozoId, MedioFichaje medio, LocalDateTime entrada) {
+        this(id, localId, mozoId, medio, entrada, null, null, null, null, null, null);
+    }
+
+    /**
+     * Constructor de reconstrucción desde persistencia.
+     */
+    public Fichaje(FichajeId id, LocalId localId, MozoId mozoId, MedioFichaje medio,
+                   LocalDateTime entrada, LocalDateTime salida,
+                   LocalDateTime entradaOriginal, LocalDateTime salidaOriginal,
+                   String motivoCorreccion, MozoId corregidoPorId, LocalDateTime fechaCorreccion) {
+        this.id = Objects.requireNonNull(id, "El id del fichaje no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.mozoId = Objects.requireNonNull(mozoId, "El fichaje debe pertenecer a un empleado");
+        this.medio = Objects.requireNonNull(medio, "El medio de fichaje es obligatorio");
+        this.entrada = Objects.requireNonNull(entrada, "La hora de entrada es obligatoria");
+        this.salida = validarSalida(entrada, salida);
+        this.entradaOriginal = entradaOriginal;
+        this.salidaOriginal = salidaOriginal;
+        this.motivoCorreccion = motivoCorreccion;
+        this.corregidoPorId = corregidoPorId;
+        this.fechaCorreccion = fechaCorreccion;
+    }
+
+    // ============================================
+    // Lógica de dominio
+    // ============================================
+
+    /**
+     * Marca la salida del empleado.
+     *
+     * @throws IllegalStateException si el fichaje ya tiene salida
+     * @throws IllegalArgumentException si la salida es anterior a la entrada
+     */
+    public void registrarSalida(LocalDateTime salida) {
+        if (!estaAbierto()) {
+            throw new IllegalStateException("El fichaje ya tiene registrada la salida");
+        }
+        this.salida = validarSalida(entrada, Objects.requireNonNull(salida, "La hora de salida es obligatoria"));
+    }
+
+    /**
+     * Corrige las marcas con la autorización del dueño. La primera corrección
+     * guarda las marcas originales; las siguientes solo actualizan el motivo.
+     *
+     * @param salida        null deja al empleado como presente
+     * @param autorizadoPor id del dueño que autorizó (ya validado con su PIN)
+     * @throws IllegalArgumentException si falta el motivo o la salida es anterior a la entrada
+     */
+    public void corregir(LocalDateTime entrada, LocalDateTime salida, String motivo,
+                         MozoId autorizadoPor, LocalDateTime fechaCorreccion) {
+        Objects.requireNonNull(entrada, "La hora de entrada es obligatoria");
+        String motivoLimpio = validarMotivo(motivo);
+        LocalDateTime salidaValidada = validarSalida(entrada, salida);
+
+        if (!fueCorregido()) {
+            this.entradaOriginal = this.entrada;
+            this.salidaOriginal = this.salida;
+        }
+        this.entrada = entrada;
+        this.salida = salidaValidada;
+        this.motivoCorreccion = motivoLimpio;
+        this.corregidoPorId = Objects.requireNonNull(autorizadoPor, "Falta quién autorizó la corrección");
+        this.fechaCorreccion = Objects.requireNonNull(fechaCorreccion, "La fecha de corrección es obligatoria");
+    }
+
+    /**
+     * Alta manual de un fichaje que no se marcó en la terminal (autorizada por el dueño).
+     */
+    public static Fichaje manual(LocalId localId, MozoId mozoId, LocalDateTime entrada, LocalDateTime salida,
+                                 String motivo, MozoId autorizadoPor, LocalDateTime fechaCorreccion) {
+        Objects.requireNonNull(autorizadoPor, "Falta quién autorizó el fichaje manual");
+        return new Fichaje(FichajeId.generate(), localId, mozoId, MedioFichaje.MANUAL,
+            entrada, salida, null, null, validarMotivo(motivo), autorizadoPor,
+            Objects.requireNonNull(fechaCorreccion, "La fecha de corrección es obligatoria"));
+    }
+
+    public boolean estaAbierto() {
+        return salida == null;
+    }
+
+    /**
+     * @return true si sigue abierto después de {@link #DURACION_MAXIMA} (salida olvidada)
+     */
+    public boolean estaVencido(LocalDateTime ahora) {
+        return estaAbierto() && Duration.between(entrada, ahora).compareTo(DURACION_MAXIMA) > 0;
+    }
+
+    public boolean fueCorregido() {
+        return corregidoPorId != null;
+    }
+
+    /**
+     * @return tiempo entre entrada y salida; cero mientras no tiene salida
+     */
+    public Duration duracion() {
+        return estaAbierto() ? Duration.ZERO : Duration.between(entrada, salida);
+    }
+
+    private static LocalDateTime validarSalida(LocalDateTime entrada, LocalDateTime salida) {
+        if (salida != null && salida.isBefore(entrada)) {
+            throw new IllegalArgumentException("La salida no puede ser anterior a la entrada");
+        }
+        return salida;
+    }
+
+    private static String validarMotivo(String motivo) {
+        if (motivo == null || motivo.isBlank()) {
+            throw new IllegalArgumentException("El motivo de la corrección es obligatorio");
+        }
+        String limpio = motivo.trim();
+        if (limpio.length() > LONGITUD_MAXIMA_MOTIVO) {
+            throw new IllegalArgumentException(
+                "El motivo no puede superar los " + LONGITUD_MAXIMA_MOTIVO + " caracteres"
+            );
+        }
+        return limpio;
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public FichajeId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public MozoId getMozoId() {
+        return mozoId;
+    }
+
+    public MedioFichaje getMedio() {
+        return medio;
+    }
+
+    public LocalDateTime getEntrada() {
+        return entrada;
+    }
+
+    public LocalDateTime getSalida() {
+        return salida;
+    }
+
+    public LocalDateTime getEntradaOriginal() {
+        return entradaOriginal;
+    }
+
+    public LocalDateTime getSalidaOriginal() {
+        return salidaOriginal;
+    }
+
+    public String getMotivoCorreccion() {
+        return motivoCorreccion;
+    }
+
+    public MozoId getCorregidoPorId() {
+        return corregidoPorId;
+    }
+
+    public LocalDateTime getFechaCorreccion() {
+        return fechaCorreccion;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        Fichaje that = (Fichaje) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+
+    @Override
+    public String toString() {
+        return String.format("Fichaje{mozoId=%s, entrada=%s, salida=%s, medio=%s}",
+            mozoId, entrada, salida, medio);
+    }
+}
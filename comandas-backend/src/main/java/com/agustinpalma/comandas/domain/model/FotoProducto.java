@@ -0,0 +1,58 @@
+package com.agustinpalma.comandas.domain.model;
+
+import java.time.LocalDateTime;
+import java.util.Objects;
+import java.util.Set;
+
+/**
+ * Foto de un producto para la carta digital.
+ *
+ * HU-127: Se guarda en la base local, igual que los comprobantes de gastos,
+ * para que el respaldo de la base incluya las fotos.
+ *
+ * Reglas de negocio:
+ * - Solo imagen (JPEG, PNG, WEBP)
+ * - Máximo 1 MB: la carta se abre desde celulares por el WiFi del local
+ *
+ * La fecha de actualización funciona como versión: cambia con cada foto
+ * nueva y permite que los celulares la guarden en caché sin quedarse con
+ * la anterior.
+ */
+public final class FotoProducto {
+
+    public static final int TAMANIO_MAXIMO_BYTES = 1024 * 1024;
+
+    private static final Set<String> TIPOS_PERMITIDOS = Set.of("image/jpeg", "image/png", "image/webp");
+
+    private final String contentType;
+    private final byte[] contenido;
+    private final LocalDateTime actualizadaEn;
+
+    public FotoProducto(String contentType, byte[] contenido, LocalDateTime actualizadaEn) {
+        if (contentType == null || !TIPOS_PERMITIDOS.contains(contentType)) {
+            throw new IllegalArgumentException("La foto debe ser una imagen JPEG, PNG o WEBP");
+        }
+        Objects.requireNonNull(contenido, "El contenido de la foto no puede ser null");
+        if (contenido.length == 0) {
+            throw new IllegalArgumentException("El archivo de la foto está vacío");
+        }
+        if (contenido.length > TAMANIO_MAXIMO_BYTES) {
+            throw new IllegalArgumentException("La foto no puede superar 1 MB");
+        }
+        this.contentType = contentType;
+        this.contenido = contenido.clone();
+        this.actualizadaEn = Objects.requireNonNull(actualizadaEn, "La fecha de la foto es obligatoria");
+    }
+
+    public String getContentType() {
+        return contentType;
+    }
+
+    public byte[] getContenido() {
+        return contenido.clone();
+    }
+
+    public LocalDateTime getActualizadaEn() {
+        return actualizadaEn;
+    }
+}
@@ -0,0 +1,79 @@
+package com.agustinpalma.comandas.domain.model;
+
+import java.time.LocalTime;
+import java.time.format.DateTimeFormatter;
+import java.util.Objects;
+
+/**
+ * Value Object con el horario en que se ofrece un producto o una categoría.
+ *
+ * HU-161: Desayunos hasta las 11, menú ejecutivo de 12 a 15. Fuera de la
+ * franja el producto no se puede cargar ni aparece en la carta del QR.
+ *
+ * El inicio se incluye y el fin no: un desayuno "de 7 a 11" ya no sale a las
+ * 11:00. Si el fin es anterior al inicio la franja cruza la medianoche
+ * (trasnoche de 22 a 2).
+ */
+public final class FranjaHoraria {
+
+    private static final DateTimeFormatter FORMATO = DateTimeFormatter.ofPattern("HH:mm");
+
+    private final LocalTime desde;
+    private final LocalTime hasta;
+
+    public FranjaHoraria(LocalTime desde, LocalTime hasta) {
+        this.desde = Objects.requireNonNull(desde, "La hora de inicio del horario es obligatoria");
+        this.hasta = Objects.requireNonNull(hasta, "La hora de fin del horario es obligatoria");
+        if (desde.equals(hasta)) {
+            throw new IllegalArgumentException("El horario tiene que empezar y terminar a horas distintas");
+        }
+    }
+
+    /**
+     * @return la franja, o null si no se indicó ninguna de las dos horas (se ofrece todo el día)
+     * @throws IllegalArgumentException si se indicó una sola de las horas
+     */
+    public static FranjaHoraria de(LocalTime desde, LocalTime hasta) {
+        if (desde == null && hasta == null) {
+            return null;
+        }
+        if (desde == null || hasta == null) {
+            throw new IllegalArgumentException("Indicá desde y hasta qué hora se ofrece");
+        }
+        return new FranjaHoraria(desde, hasta);
+    }
+
+    public boolean incluye(LocalTime hora) {
+        Objects.requireNonNull(hora, "La hora no puede ser null");
+        if (desde.isBefore(hasta)) {
+            return !hora.isBefore(desde) && hora.isBefore(hasta);
+        }
+        return !hora.isBefore(desde) || hora.isBefore(hasta);
+    }
+
+    public LocalTime getDesde() {
+        return desde;
+    }
+
+    public LocalTime getHasta() {
+        return hasta;
+    }
+
+    /** Texto para los mensajes al operador (ej: "de 07:00 a 11:00") */
+    @Override
+    public String toString() {
+        return "de " + desde.format(FORMATO) + " a " + hasta.format(FORMATO);
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (!(o instanceof FranjaHoraria that)) return false;
+        return desde.equals(that.desde) && hasta.equals(that.hasta);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(desde, hasta);
+    }
+}
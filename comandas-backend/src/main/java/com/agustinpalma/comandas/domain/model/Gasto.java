@@ -0,0 +1,221 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaGasto;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.GastoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Objects;
+import java.util.Set;
+
+/**
+ * Compra a un proveedor o gasto del local (alquiler, servicios, sueldos...).
+ *
+ * HU-117: Compras a proveedores y gastos.
+ *
+ * Reglas de negocio:
+ * - El monto es lo pagado según el comprobante y debe ser mayor a cero.
+ * - Tiene que decir qué se pagó: proveedor o descripción (al menos uno).
+ * - Solo las compras de MERCADERIA llevan ítems, que ingresan al stock.
+ * - Medios de pago: efectivo, tarjeta, transferencia o QR. A cuenta es un
+ *   medio de cobro a clientes, no de pago.
+ * - HU-131: Cuenta corriente es una compra a pagar al proveedor: exige el
+ *   proveedor y genera la factura de su cuenta corriente.
+ * - Si se pagó en efectivo con la plata de la caja, queda asociado al
+ *   egreso de caja que lo registró, así el arqueo cierra.
+ * - La fecha es la del comprobante; puede ser anterior al registro.
+ */
+public class Gasto {
+
+    private static final int LONGITUD_MAXIMA_PROVEEDOR = 100;
+    private static final int LONGITUD_MAXIMA_DESCRIPCION = 200;
+
+    private static final Set<MedioPago> MEDIOS_PERMITIDOS = Set.of(
+        MedioPago.EFECTIVO, MedioPago.TARJETA, MedioPago.TRANSFERENCIA, MedioPago.QR, MedioPago.CUENTA_CORRIENTE
+    );
+
+    private final GastoId id;
+    private final LocalId localId;
+    private final LocalDate fecha;
+    private final CategoriaGasto categoria;
+    private final String proveedor;
+    private final String descripcion;
+    private final BigDecimal monto;
+    private final MedioPago medioPago;
+    private final List<ItemCompra> items;
+    private final LocalDateTime fechaRegistro;
+    private MovimientoCajaId movimientoCajaId;
+    private String nombreArchivoComprobante;
+
+    public Gasto(GastoId id, LocalId localId, LocalDate fecha, CategoriaGasto categoria, String proveedor,
+                 String descripcion, BigDecimal monto, MedioPago medioPago, List<ItemCompra> items,
+                 LocalDateTime fechaRegistro, MovimientoCajaId movimientoCajaId, String nombreArchivoComprobante) {
+        this.id = Objects.requireNonNull(id, "El id del gasto no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.fecha = Objects.requireNonNull(fecha, "La fecha del gasto no puede ser null");
+        this.categoria = Objects.requireNonNull(categoria, "La categoría del gasto no puede ser null");
+        this.proveedor = limpiar(proveedor, LONGITUD_MAXIMA_PROVEEDOR, "El proveedor");
+        this.descripcion = limpiar(descripcion, LONGITUD_MAXIMA_DESCRIPCION, "La descripción");
+        this.monto = validarMonto(monto);
+        this.medioPago = validarMedioPago(medioPago);
+        this.items = List.copyOf(Objects.requireNonNull(items, "Los ítems no pueden ser null"));
+        this.fechaRegistro = Objects.requireNonNull(fechaRegistro, "La fecha de registro no puede ser null");
+        this.movimientoCajaId = movimientoCajaId;
+        this.nombreArchivoComprobante = nombreArchivoComprobante;
+
+        if (this.proveedor == null && this.descripcion == null) {
+            throw new IllegalArgumentException("Indicá el proveedor o una descripción del gasto");
+        }
+        if (medioPago == MedioPago.CUENTA_CORRIENTE && this.proveedor == null) {
+            throw new IllegalArgumentException("Una compra a cuenta corriente debe indicar el proveedor");
+        }
+        if (!this.items.isEmpty() && categoria != CategoriaGasto.MERCADERIA) {
+            throw new IllegalArgumentException("Solo las compras de mercadería pueden ingresar productos al stock");
+        }
+    }
+
+    /**
+     * Asocia el egreso de caja con el que se pagó el gasto.
+     *
+     * @throws IllegalStateException si no se pagó en efectivo o ya tiene un egreso asociado
+     */
+    public void registrarPagoConCaja(MovimientoCajaId movimientoCajaId) {
+        Objects.requireNonNull(movimientoCajaId, "El movimientoCajaId no puede ser null");
+        if (medioPago != MedioPago.EFECTIVO) {
+            throw new IllegalStateException("Solo un gasto en efectivo puede pagarse con la caja");
+        }
+        if (this.movimientoCajaId != null) {
+            throw new IllegalStateException("El gasto ya tiene un egreso de caja asociado");
+        }
+        this.movimientoCajaId = movimientoCajaId;
+    }
+
+    /**
+     * Registra el archivo del comprobante. Un comprobante nuevo reemplaza al anterior.
+     */
+    public void adjuntarComprobante(ComprobanteAdjunto comprobante) {
+        Objects.requireNonNull(comprobante, "El comprobante no puede ser null");
+        this.nombreArchivoComprobante = comprobante.getNombreArchivo();
+    }
+
+    public boolean tieneComprobante() {
+        return nombreArchivoComprobante != null;
+    }
+
+    public boolean esACuentaCorriente() {
+        return medioPago == MedioPago.CUENTA_CORRIENTE;
+    }
+
+    public boolean fuePagadoConCaja() {
+        return movimientoCajaId != null;
+    }
+
+    /**
+     * Texto corto para el egreso de caja y los movimientos de stock.
+     */
+    public String resumen() {
+        String categoriaTexto = switch (categoria) {
+            case MERCADERIA -> "Mercadería";
+            case ALQUILER -> "Alquiler";
+            case SERVICIOS -> "Servicios";
+            case SUELDOS -> "Sueldos";
+            case IMPUESTOS -> "Impuestos";
+            case MANTENIMIENTO -> "Mantenimiento";
+            case OTROS -> "Otros";
+        };
+        return categoriaTexto + " - " + (proveedor != null ? proveedor : descripcion);
+    }
+
+    private BigDecimal validarMonto(BigDecimal monto) {
+        Objects.requireNonNull(monto, "El monto del gasto no puede ser null");
+        if (monto.compareTo(BigDecimal.ZERO) <= 0) {
+            throw new IllegalArgumentException("El monto del gasto debe ser mayor a cero");
+        }
+        return monto;
+    }
+
+    private MedioPago validarMedioPago(MedioPago medioPago) {
+        Objects.requireNonNull(medioPago, "El medio de pago no puede ser null");
+        if (!MEDIOS_PERMITIDOS.contains(medioPago)) {
+            throw new IllegalArgumentException("Medio de pago no válido para un gasto: " + medioPago);
+        }
+        return medioPago;
+    }
+
+    private static String limpiar(String valor, int longitudMaxima, String campo) {
+        if (valor == null || valor.isBlank()) {
+            return null;
+        }
+        String limpio = valor.trim();
+        if (limpio.length() > longitudMaxima) {
+            throw new IllegalArgumentException(campo + " no puede superar los " + longitudMaxima + " caracteres");
+        }
+        return limpio;
+    }
+
+    public GastoId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public LocalDate getFecha() {
+        return fecha;
+    }
+
+    public CategoriaGasto getCategoria() {
+        return categoria;
+    }
+
+    public String getProveedor() {
+        return proveedor;
+    }
+
+    public String getDescripcion() {
+        return descripcion;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+
+    public MedioPago getMedioPago() {
+        return medioPago;
+    }
+
+    public List<ItemCompra> getItems() {
+        return items;
+    }
+
+    public LocalDateTime getFechaRegistro() {
+        return fechaRegistro;
+    }
+
+    public MovimientoCajaId getMovimientoCajaId() {
+        return movimientoCajaId;
+    }
+
+    public String getNombreArchivoComprobante() {
+        return nombreArchivoComprobante;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        Gasto that = (Gasto) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
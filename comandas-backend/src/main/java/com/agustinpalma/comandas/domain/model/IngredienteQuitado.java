@@ -0,0 +1,61 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+
+import java.util.Objects;
+
+/**
+ * Value Object que representa un ingrediente de la receta que el cliente
+ * pidió sacar ("sin cebolla").
+ *
+ * HU-148: Modificadores de ingredientes con impacto en stock.
+ *
+ * Reglas de negocio:
+ * - Es INMUTABLE (patrón snapshot, igual que ExtraPedido)
+ * - El nombre del insumo se captura al cargar el ítem, para la comanda
+ * - Al vender, el insumo quitado no se descuenta del stock teórico
+ */
+public final class IngredienteQuitado {
+
+    private final InsumoId insumoId;
+    private final String nombre;
+
+    public IngredienteQuitado(InsumoId insumoId, String nombre) {
+        this.insumoId = Objects.requireNonNull(insumoId, "El insumoId del ingrediente quitado no puede ser null");
+        if (nombre == null || nombre.isBlank()) {
+            throw new IllegalArgumentException("El nombre del ingrediente quitado no puede estar vacío");
+        }
+        this.nombre = nombre.trim();
+    }
+
+    public static IngredienteQuitado de(Insumo insumo) {
+        Objects.requireNonNull(insumo, "El insumo no puede ser null");
+        return new IngredienteQuitado(insumo.getId(), insumo.getNombre());
+    }
+
+    public InsumoId getInsumoId() {
+        return insumoId;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        IngredienteQuitado that = (IngredienteQuitado) o;
+        return Objects.equals(insumoId, that.insumoId);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(insumoId);
+    }
+
+    @Override
+    public String toString() {
+        return "Sin " + nombre;
+    }
+}
@@ -0,0 +1,42 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+
+import java.math.BigDecimal;
+import java.util.Objects;
+
+/**
+ * Renglón de una receta: cuánto de un insumo lleva una unidad del producto.
+ *
+ * HU-118: La cantidad se expresa en la unidad del insumo (0.180 kg de carne,
+ * 1 unidad de pan). Admite hasta 3 decimales: gramos o mililitros.
+ */
+public final class IngredienteReceta {
+
+    private static final int ESCALA_MAXIMA = 3;
+
+    private final InsumoId insumoId;
+    private final BigDecimal cantidad;
+
+    public IngredienteReceta(InsumoId insumoId, BigDecimal cantidad) {
+        this.insumoId = Objects.requireNonNull(insumoId, "El insumoId no puede ser null");
+        Objects.requireNonNull(cantidad, "La cantidad del ingrediente no puede ser null");
+        if (cantidad.compareTo(BigDecimal.ZERO) <= 0) {
+            throw new IllegalArgumentException("La cantidad del ingrediente debe ser mayor a cero");
+        }
+        if (cantidad.stripTrailingZeros().scale() > ESCALA_MAXIMA) {
+            throw new IllegalArgumentException(
+                "La cantidad del ingrediente admite hasta " + ESCALA_MAXIMA + " decimales"
+            );
+        }
+        this.cantidad = cantidad;
+    }
+
+    public InsumoId getInsumoId() {
+        return insumoId;
+    }
+
+    public BigDecimal getCantidad() {
+        return cantidad;
+    }
+}
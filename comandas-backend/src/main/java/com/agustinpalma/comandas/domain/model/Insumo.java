@@ -0,0 +1,261 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.util.Objects;
+
+/**
+ * Materia prima que se usa en las recetas (pan, carne, queso, aceite...).
+ *
+ * HU-118: Costeo de recetas y rentabilidad por producto.
+ * HU-133: Inventario físico con reporte de diferencias.
+ * HU-134: Órdenes de compra automáticas por stock mínimo.
+ *
+ * Reglas de negocio:
+ * - El nombre es obligatorio (máximo 60 caracteres).
+ * - El costo es por unidad de medida (por kilo, por litro o por unidad) y no
+ *   puede ser negativo. Se guarda con 4 decimales: un gramo de sal cuesta
+ *   fracciones de centavo.
+ * - El stock teórico se lleva en la unidad del insumo, con 3 decimales.
+ *   Empieza a controlarse con la primera toma de inventario; desde ahí cada
+ *   venta lo descuenta según la receta del producto.
+ * - El stock teórico puede quedar negativo (se vendió más de lo cargado);
+ *   el conteo físico no.
+ * - El stock mínimo es opcional y mayor a cero. Un insumo que controla stock
+ *   y llegó al mínimo se sugiere en la orden de compra de su proveedor habitual,
+ *   reponiendo hasta el doble del mínimo.
+ */
+public class Insumo {
+
+    private static final int LONGITUD_MAXIMA_NOMBRE = 60;
+    private static final int ESCALA_COSTO = 4;
+    private static final int ESCALA_STOCK = 3;
+
+    private final InsumoId id;
+    private final LocalId localId;
+    private String nombre;
+    private UnidadInsumo unidad;
+    private BigDecimal costoUnitario;
+    private boolean controlaStock;
+    private BigDecimal stockActual;
+    private BigDecimal stockMinimo;
+    private ProveedorId proveedorHabitualId;
+
+    public Insumo(InsumoId id, LocalId localId, String nombre, UnidadInsumo unidad, BigDecimal costoUnitario) {
+        this(id, localId, nombre, unidad, costoUnitario, false, BigDecimal.ZERO);
+    }
+
+    /**
+     * HU-133: Constructor completo (reconstrucción desde persistencia).
+     *
+     * @param stockActual stock teórico en la unidad del insumo
+     */
+    public Insumo(InsumoId id, LocalId localId, String nombre, UnidadInsumo unidad, BigDecimal costoUnitario,
+                  boolean controlaStock, BigDecimal stockActual) {
+        this(id, localId, nombre, unidad, costoUnitario, controlaStock, stockActual, null, null);
+    }
+
+    /**
+     * HU-134: Constructor completo con los datos de reposición.
+     *
+     * @param stockMinimo null si el insumo no se repone automáticamente
+     * @param proveedorHabitualId null si no tiene proveedor habitual
+     */
+    public Insumo(InsumoId id, LocalId localId, String nombre, UnidadInsumo unidad, BigDecimal costoUnitario,
+                  boolean controlaStock, BigDecimal stockActual, BigDecimal stockMinimo,
+                  ProveedorId proveedorHabitualId) {
+        this.id = Objects.requireNonNull(id, "El id del insumo no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.nombre = validarNombre(nombre);
+        this.unidad = Objects.requireNonNull(unidad, "La unidad del insumo no puede ser null");
+        this.costoUnitario = validarCosto(costoUnitario);
+        this.controlaStock = controlaStock;
+        this.stockActual = Objects.requireNonNull(stockActual, "El stock del insumo no puede ser null")
+            .setScale(ESCALA_STOCK, RoundingMode.HALF_UP);
+        this.stockMinimo = validarStockMinimo(stockMinimo);
+        this.proveedorHabitualId = proveedorHabitualId;
+    }
+
+    /**
+     * Edición desde la pantalla de insumos. Un cambio de costo impacta de inmediato
+     * en el costo teórico de todas las recetas que lo usan.
+     */
+    public void actualizar(String nombre, UnidadInsumo unidad, BigDecimal costoUnitario) {
+        this.nombre = validarNombre(nombre);
+        this.unidad = Objects.requireNonNull(unidad, "La unidad del insumo no puede ser null");
+        this.costoUnitario = validarCosto(costoUnitario);
+    }
+
+    /**
+     * HU-133: Descuenta lo que consumió una venta.
+     * Si el insumo no controla stock, la operación no tiene efecto.
+     */
+    public void consumir(BigDecimal cantidad) {
+        validarCantidadMovimiento(cantidad);
+        if (controlaStock) {
+            this.stockActual = stockActual.subtract(cantidad).setScale(ESCALA_STOCK, RoundingMode.HALF_UP);
+        }
+    }
+
+    /**
+     * HU-133: Devuelve al stock un consumo revertido.
+     * Si el insumo no controla stock, la operación no tiene efecto.
+     */
+    public void reponer(BigDecimal cantidad) {
+        validarCantidadMovimiento(cantidad);
+        if (controlaStock) {
+            this.stockActual = stockActual.add(cantidad).setScale(ESCALA_STOCK, RoundingMode.HALF_UP);
+        }
+    }
+
+    /**
+     * HU-133: Lleva el stock teórico a lo contado en la toma de inventario
+     * y activa el control de stock si todavía no lo tenía.
+     *
+     * @param contado stock físico contado, en la unidad del insumo
+     * @return diferencia contra el stock teórico (negativa = faltante)
+     * @throws IllegalArgumentException si el conteo es negativo o tiene más de 3 decimales
+     */
+    public BigDecimal ajustarAConteo(BigDecimal contado) {
+        Objects.requireNonNull(contado, "El conteo del insumo no puede ser null");
+        if (contado.signum() < 0) {
+            throw new IllegalArgumentException("El conteo de " + nombre + " no puede ser negativo");
+        }
+        if (contado.stripTrailingZeros().scale() > ESCALA_STOCK) {
+            throw new IllegalArgumentException("El conteo admite hasta " + ESCALA_STOCK + " decimales");
+        }
+        BigDecimal diferencia = contado.subtract(stockActual).setScale(ESCALA_STOCK, RoundingMode.HALF_UP);
+        this.stockActual = contado.setScale(ESCALA_STOCK, RoundingMode.HALF_UP);
+        this.controlaStock = true;
+        return diferencia;
+    }
+
+    /**
+     * HU-134: Define cuándo y a quién se le compra el insumo.
+     *
+     * @param stockMinimo null para dejar de sugerirlo en las órdenes de compra
+     * @throws IllegalArgumentException si el mínimo no es mayor a cero o tiene más de 3 decimales
+     */
+    public void definirReposicion(BigDecimal stockMinimo, ProveedorId proveedorHabitualId) {
+        this.stockMinimo = validarStockMinimo(stockMinimo);
+        this.proveedorHabitualId = proveedorHabitualId;
+    }
+
+    /**
+     * HU-134: Solo los insumos que controlan stock pueden estar bajo el mínimo.
+     */
+    public boolean estaBajoMinimo() {
+        return controlaStock && stockMinimo != null && stockActual.compareTo(stockMinimo) <= 0;
+    }
+
+    /**
+     * HU-134: Cantidad a comprar para llevar el stock al doble del mínimo.
+     * Lo que se compra por unidad se redondea hacia arriba (no se compra medio pan).
+     *
+     * @return cero si el insumo no está bajo el mínimo
+     */
+    public BigDecimal getCantidadAReponer() {
+        if (!estaBajoMinimo()) {
+            return BigDecimal.ZERO;
+        }
+        BigDecimal faltante = stockMinimo.multiply(BigDecimal.valueOf(2)).subtract(stockActual);
+        return unidad == UnidadInsumo.UNIDAD
+            ? faltante.setScale(0, RoundingMode.CEILING)
+            : faltante.setScale(ESCALA_STOCK, RoundingMode.HALF_UP);
+    }
+
+    private BigDecimal validarStockMinimo(BigDecimal stockMinimo) {
+        if (stockMinimo == null) {
+            return null;
+        }
+        if (stockMinimo.signum() <= 0) {
+            throw new IllegalArgumentException("El stock mínimo de " + nombre + " debe ser mayor a cero");
+        }
+        if (stockMinimo.stripTrailingZeros().scale() > ESCALA_STOCK) {
+            throw new IllegalArgumentException("El stock mínimo admite hasta " + ESCALA_STOCK + " decimales");
+        }
+        return stockMinimo.setScale(ESCALA_STOCK, RoundingMode.HALF_UP);
+    }
+
+    private void validarCantidadMovimiento(BigDecimal cantidad) {
+        Objects.requireNonNull(cantidad, "La cantidad no puede ser null");
+        if (cantidad.signum() <= 0) {
+            throw new IllegalArgumentException("La cantidad del movimiento de stock debe ser mayor a cero");
+        }
+    }
+
+    private String validarNombre(String nombre) {
+        if (nombre == null || nombre.isBlank()) {
+            throw new IllegalArgumentException("El nombre del insumo no puede estar vacío");
+        }
+        String limpio = nombre.trim();
+        if (limpio.length() > LONGITUD_MAXIMA_NOMBRE) {
+            throw new IllegalArgumentException(
+                "El nombre del insumo no puede superar los " + LONGITUD_MAXIMA_NOMBRE + " caracteres"
+            );
+        }
+        return limpio;
+    }
+
+    private BigDecimal validarCosto(BigDecimal costo) {
+        Objects.requireNonNull(costo, "El costo del insumo no puede ser null");
+        if (costo.compareTo(BigDecimal.ZERO) < 0) {
+            throw new IllegalArgumentException("El costo del insumo no puede ser negativo");
+        }
+        return costo.setScale(ESCALA_COSTO, RoundingMode.HALF_UP);
+    }
+
+    public InsumoId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public UnidadInsumo getUnidad() {
+        return unidad;
+    }
+
+    public BigDecimal getCostoUnitario() {
+        return costoUnitario;
+    }
+
+    public boolean isControlaStock() {
+        return controlaStock;
+    }
+
+    public BigDecimal getStockActual() {
+        return stockActual;
+    }
+
+    public BigDecimal getStockMinimo() {
+        return stockMinimo;
+    }
+
+    public ProveedorId getProveedorHabitualId() {
+        return proveedorHabitualId;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        Insumo that = (Insumo) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
@@ -0,0 +1,91 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.Alergeno;
+
+import java.util.Collection;
+import java.util.Collections;
+import java.util.EnumSet;
+import java.util.Objects;
+import java.util.Set;
+
+/**
+ * Renglón de una comanda de cocina: lo que salió en ese envío.
+ *
+ * HU-119: Es un snapshot del ítem del pedido al momento del envío. La cantidad
+ * es la que se mandó en esa comanda (el delta), no el total acumulado del ítem.
+ *
+ * HU-137: Guarda si el ítem salió marcado urgente.
+ *
+ * HU-147: Guarda las alergias declaradas para el comensal, para que cocina
+ * las vea junto al plato.
+ */
+public final class ItemComandaCocina {
+
+    private final String nombreProducto;
+    private final int cantidad;
+    private final String observacion;
+    private final boolean urgente;
+    private final Set<Alergeno> advertencias;
+
+    public ItemComandaCocina(String nombreProducto, int cantidad, String observacion) {
+        this(nombreProducto, cantidad, observacion, false);
+    }
+
+    public ItemComandaCocina(String nombreProducto, int cantidad, String observacion, boolean urgente) {
+        this(nombreProducto, cantidad, observacion, urgente, Set.of());
+    }
+
+    public ItemComandaCocina(String nombreProducto, int cantidad, String observacion, boolean urgente,
+                             Collection<Alergeno> advertencias) {
+        if (nombreProducto == null || nombreProducto.isBlank()) {
+            throw new IllegalArgumentException("El nombre del producto es obligatorio");
+        }
+        if (cantidad <= 0) {
+            throw new IllegalArgumentException("La cantidad del ítem debe ser mayor a cero");
+        }
+        this.nombreProducto = nombreProducto;
+        this.cantidad = cantidad;
+        this.observacion = observacion;
+        this.urgente = urgente;
+        this.advertencias = advertencias == null || advertencias.isEmpty()
+            ? Set.of()
+            : Collections.unmodifiableSet(EnumSet.copyOf(advertencias));
+    }
+
+    public String getNombreProducto() {
+        return nombreProducto;
+    }
+
+    public int getCantidad() {
+        return cantidad;
+    }
+
+    public String getObservacion() {
+        return observacion;
+    }
+
+    public boolean isUrgente() {
+        return urgente;
+    }
+
+    public Set<Alergeno> getAdvertencias() {
+        return advertencias;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        ItemComandaCocina that = (ItemComandaCocina) o;
+        return cantidad == that.cantidad
+            && urgente == that.urgente
+            && nombreProducto.equals(that.nombreProducto)
+            && Objects.equals(observacion, that.observacion)
+            && advertencias.equals(that.advertencias);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(nombreProducto, cantidad, observacion, urgente, advertencias);
+    }
+}
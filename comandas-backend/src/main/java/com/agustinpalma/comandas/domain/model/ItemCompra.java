@@ -0,0 +1,39 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+
+import java.util.Objects;
+
+/**
+ * Renglón de una compra de mercadería: qué producto entró y cuántas unidades.
+ *
+ * HU-117: Cada ítem genera un ingreso de stock (INGRESO_MERCADERIA) al registrar la compra.
+ * El nombre es un snapshot para que la compra se siga leyendo aunque el producto cambie.
+ */
+public final class ItemCompra {
+
+    private final ProductoId productoId;
+    private final String nombreProducto;
+    private final int cantidad;
+
+    public ItemCompra(ProductoId productoId, String nombreProducto, int cantidad) {
+        this.productoId = Objects.requireNonNull(productoId, "El productoId no puede ser null");
+        this.nombreProducto = Objects.requireNonNull(nombreProducto, "El nombre del producto no puede ser null");
+        if (cantidad <= 0) {
+            throw new IllegalArgumentException("La cantidad comprada debe ser mayor a cero");
+        }
+        this.cantidad = cantidad;
+    }
+
+    public ProductoId getProductoId() {
+        return productoId;
+    }
+
+    public String getNombreProducto() {
+        return nombreProducto;
+    }
+
+    public int getCantidad() {
+        return cantidad;
+    }
+}
@@ -1,14 +1,21 @@
 package com.agustinpalma.comandas.domain.model;
 
+import com.agustinpalma.comandas.domain.model.DomainEnums.Alergeno;
 import com.agustinpalma.comandas.domain.model.DomainEnums.ModoDescuento;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TiempoServicio;
 import com.agustinpalma.comandas.domain.model.DomainIds.*;
 import java.math.BigDecimal;
 import java.time.LocalDateTime;
 import java.util.ArrayList;
+import java.util.Collection;
 import java.util.Collections;
+import java.util.EnumSet;
+import java.util.HashSet;
 import java.util.List;
 import java.util.Objects;
+import java.util.Set;
 import java.util.UUID;
+import java.util.stream.Collectors;
 
 /**
  * Entidad que representa un ítem dentro de un pedido.
@@ -26,9 +33,42 @@ import java.util.UUID;
  * Los extras se agregan como Value Objects (ExtraPedido) con su propio snapshot de precio.
  * Fórmula de subtotal: cantidad × (precioUnitarioBase + sum(precioExtras))
  * Las promociones SOLO aplican a precioUnitarioBase, NUNCA a extras.
+ * 
+ * HU-103: Si el producto es un combo, el ítem guarda la opción elegida para cada
+ * componente (SeleccionCombo). Las selecciones no alteran el precio: el combo se cobra cerrado.
+ * 
+ * HU-110: Cada ítem puede llevar su paso de servicio (entrada, principal, postre).
+ * Los ítems de pasos posteriores al que está en marcha quedan retenidos hasta que se marchan.
+ * 
+ * HU-120: La observación es texto libre para cocina ("bien cocida", "sin sal").
+ * Se normaliza (trim, vacía → null) para que dos notas iguales fusionen el ítem.
+ *
+ * HU-137: Un ítem puede marcarse urgente (plato a rehacer, cliente apurado):
+ * sale destacado en la comanda y sube al tope de la pantalla de cocina.
+ * No participa de la fusión de ítems ni del precio.
+ *
+ * HU-139: El costo de envío de un delivery viaja como ítem del pedido
+ * (ver {@link #crearEnvio}): suma al total y al ticket, pero no sale a cocina
+ * ni cuenta como venta de productos. Su productoId es el id de la zona,
+ * que no existe en el catálogo.
+ *
+ * HU-147: Las advertencias son las alergias del comensal para el que se cargó
+ * el ítem (celíaco, alérgico al maní). Salen resaltadas en la comanda y en el
+ * KDS, y dos ítems con advertencias distintas no se fusionan.
+ *
+ * HU-148: Los ingredientes quitados ("sin cebolla") son insumos de la receta
+ * del producto que no se descuentan del stock al vender. Los extras con receta
+ * propia ("extra queso") suman su consumo. Dos ítems con ingredientes
+ * quitados distintos no se fusionan.
+ *
+ * HU-164: El ítem recuerda los ids del borrador del POS que lo cargaron (más
+ * de uno si se fusionaron cargas): un reintento con el mismo id no lo duplica.
  */
 public class ItemPedido {
 
+    /** HU-120: Mismo límite que la columna observacion de items_pedido */
+    public static final int OBSERVACION_LONGITUD_MAXIMA = 255;
+
     private final ItemPedidoId id;
     private final PedidoId pedidoId;
     private final ProductoId productoId;
@@ -63,6 +103,27 @@ public class ItemPedido {
     // cuando se reenvía una comanda tras agregar más unidades del mismo producto.
     private int cantidadEnviadaCocina;
 
+    // HU-103: Opciones elegidas por componente (vacía si no es combo)
+    private final List<SeleccionCombo> componentesCombo;
+
+    // HU-110: Paso de servicio (null = sin paso, sale con la primera comanda)
+    private TiempoServicio tiempoServicio;
+
+    // HU-137: Prioridad en cocina
+    private boolean urgente;
+
+    // HU-139: Costo de envío (no es un producto del catálogo)
+    private boolean envio;
+
+    // HU-147: Alergias declaradas para el comensal (vacío = sin advertencia)
+    private Set<Alergeno> advertencias = Set.of();
+
+    // HU-148: Insumos de la receta que el cliente pidió sacar
+    private List<IngredienteQuitado> ingredientesQuitados = List.of();
+
+    // HU-164: Ids de los ítems del borrador del POS que cargaron esta línea
+    private Set<UUID> borradores = Set.of();
+
     /**
      * Constructor completo para reconstrucción desde persistencia.
      * Usado por la capa de infraestructura (JPA).
@@ -96,8 +157,7 @@ public class ItemPedido {
     }
 
     /**
-     * Constructor maestro con todos los campos incluyendo fechaAgregado.
-     * Usado internamente y desde la capa de infraestructura (reconstrucción).
+     * Constructor con fechaAgregado y cantidad enviada a cocina (sin componentes de combo).
      */
     public ItemPedido(
             ItemPedidoId id, 
@@ -117,6 +177,36 @@ public class ItemPedido {
             CategoriaId categoriaIdSnapshot,
             LocalDateTime fechaAgregado,
             int cantidadEnviadaCocina
+    ) {
+        this(id, pedidoId, productoId, nombreProducto, cantidad, precioUnitario,
+             observacion, montoDescuento, nombrePromocion, promocionId,
+             descuentoManual, extras, grupoVarianteIdSnapshot, cantidadDiscosSnapshot,
+             categoriaIdSnapshot, fechaAgregado, cantidadEnviadaCocina, Collections.emptyList());
+    }
+
+    /**
+     * Constructor maestro con todos los campos incluyendo fechaAgregado y componentes de combo.
+     * Usado internamente y desde la capa de infraestructura (reconstrucción).
+     */
+    public ItemPedido(
+            ItemPedidoId id, 
+            PedidoId pedidoId, 
+            ProductoId productoId, 
+            String nombreProducto, 
+            int cantidad, 
+            BigDecimal precioUnitario, 
+            String observacion,
+            BigDecimal montoDescuento,
+            String nombrePromocion,
+            UUID promocionId,
+            DescuentoManual descuentoManual,
+            List<ExtraPedido> extras,
+            ProductoId grupoVarianteIdSnapshot,
+            Integer cantidadDiscosSnapshot,
+            CategoriaId categoriaIdSnapshot,
+            LocalDateTime fechaAgregado,
+            int cantidadEnviadaCocina,
+            List<SeleccionCombo> componentesCombo
     ) {
         this.id = Objects.requireNonNull(id, "El id del item no puede ser null");
         this.pedidoId = Objects.requireNonNull(pedidoId, "El pedidoId no puede ser null");
@@ -124,7 +214,7 @@ public class ItemPedido {
         this.nombreProducto = Objects.requireNonNull(nombreProducto, "El nombre del producto no puede ser null");
         this.cantidad = validarCantidad(cantidad);
         this.precioUnitario = validarPrecioUnitario(precioUnitario);
-        this.observacion = observacion; // Puede ser null
+        this.observacion = normalizarObservacion(observacion); // Puede ser null
         
         // Snapshot de clasificación (inmutables, pueden ser null)
         this.grupoVarianteIdSnapshot = grupoVarianteIdSnapshot;
@@ -147,6 +237,9 @@ public class ItemPedido {
 
         // HU-29: Cantidad ya enviada a cocina (0 para ítems nuevos)
         this.cantidadEnviadaCocina = Math.max(0, cantidadEnviadaCocina);
+
+        // HU-103: Componentes de combo (copia inmutable)
+        this.componentesCombo = componentesCombo != null ? List.copyOf(componentesCombo) : List.of();
     }
 
     /**
@@ -361,6 +454,25 @@ public class ItemPedido {
         );
     }
 
+    /**
+     * HU-120: Normaliza la observación libre del ítem.
+     *
+     * @return la observación sin espacios sobrantes, o null si está vacía
+     * @throws IllegalArgumentException si supera la longitud máxima
+     */
+    public static String normalizarObservacion(String observacion) {
+        if (observacion == null || observacion.isBlank()) {
+            return null;
+        }
+        String normalizada = observacion.trim();
+        if (normalizada.length() > OBSERVACION_LONGITUD_MAXIMA) {
+            throw new IllegalArgumentException(
+                "La observación no puede superar los " + OBSERVACION_LONGITUD_MAXIMA + " caracteres"
+            );
+        }
+        return normalizada;
+    }
+
     private int validarCantidad(int cantidad) {
         if (cantidad <= 0) {
             throw new IllegalArgumentException("La cantidad debe ser mayor a cero");
@@ -416,6 +528,281 @@ public class ItemPedido {
         return fechaAgregado;
     }
 
+    /**
+     * HU-102: Devuelve una copia de este ítem con el momento de carga indicado.
+     *
+     * Los factory methods toman la hora del sistema operativo; el motor de promociones
+     * usa esta copia para que el momento de carga coincida con el reloj del negocio
+     * con el que se evaluaron las franjas horarias.
+     *
+     * @param momento fecha/hora en que el ítem se cargó al pedido
+     * @return nuevo ítem idéntico salvo por fechaAgregado
+     */
+    public ItemPedido registradoEn(LocalDateTime momento) {
+        Objects.requireNonNull(momento, "El momento de carga no puede ser null");
+        return new ItemPedido(
+            id, pedidoId, productoId, nombreProducto, cantidad, precioUnitario,
+            observacion, montoDescuento, nombrePromocion, promocionId,
+            descuentoManual, extras, grupoVarianteIdSnapshot, cantidadDiscosSnapshot,
+            categoriaIdSnapshot, momento, cantidadEnviadaCocina, componentesCombo
+        ).conTiempoServicio(tiempoServicio).conUrgencia(urgente).conEnvio(envio).conAdvertencias(advertencias)
+         .conIngredientesQuitados(ingredientesQuitados).conBorradores(borradores);
+    }
+
+    // ============================================
+    // HU-103: Componentes de combo
+    // ============================================
+
+    /**
+     * Opciones elegidas para cada componente del combo, en el orden del catálogo.
+     *
+     * @return lista inmutable, vacía si el ítem no es un combo
+     */
+    public List<SeleccionCombo> getComponentesCombo() {
+        return componentesCombo;
+    }
+
+    public boolean esCombo() {
+        return !componentesCombo.isEmpty();
+    }
+
+    /**
+     * Devuelve una copia de este ítem con las opciones de combo elegidas.
+     *
+     * @param selecciones snapshot de cada componente (armado por {@link Producto#armarSeleccionCombo})
+     * @return nuevo ítem idéntico salvo por los componentes de combo
+     */
+    public ItemPedido conComponentesCombo(List<SeleccionCombo> selecciones) {
+        return new ItemPedido(
+            id, pedidoId, productoId, nombreProducto, cantidad, precioUnitario,
+            observacion, montoDescuento, nombrePromocion, promocionId,
+            descuentoManual, extras, grupoVarianteIdSnapshot, cantidadDiscosSnapshot,
+            categoriaIdSnapshot, fechaAgregado, cantidadEnviadaCocina, selecciones
+        ).conTiempoServicio(tiempoServicio).conUrgencia(urgente).conEnvio(envio).conAdvertencias(advertencias)
+         .conIngredientesQuitados(ingredientesQuitados).conBorradores(borradores);
+    }
+
+    // ============================================
+    // HU-110: Paso de servicio
+    // ============================================
+
+    /**
+     * Paso de servicio del ítem (entrada, principal, postre).
+     *
+     * @return el paso asignado, o null si el ítem no tiene paso
+     */
+    public TiempoServicio getTiempoServicio() {
+        return tiempoServicio;
+    }
+
+    /**
+     * Asigna el paso de servicio del ítem.
+     *
+     * Las reglas sobre cuándo puede cambiarse (no después de haberse
+     * enviado a cocina) las aplica el aggregate Pedido.
+     *
+     * @param tiempoServicio el paso, o null para quitarlo
+     */
+    public void asignarTiempoServicio(TiempoServicio tiempoServicio) {
+        this.tiempoServicio = tiempoServicio;
+    }
+
+    /**
+     * HU-120: Reemplaza la observación libre del ítem.
+     *
+     * Igual que el paso de servicio, el aggregate Pedido impide cambiarla
+     * una vez que el ítem salió a cocina.
+     *
+     * @param observacion el texto para cocina, o null/vacío para quitarla
+     */
+    public void cambiarObservacion(String observacion) {
+        this.observacion = normalizarObservacion(observacion);
+    }
+
+    private ItemPedido conTiempoServicio(TiempoServicio tiempoServicio) {
+        this.tiempoServicio = tiempoServicio;
+        return this;
+    }
+
+    // ============================================
+    // HU-137: Urgencia
+    // ============================================
+
+    public boolean isUrgente() {
+        return urgente;
+    }
+
+    /**
+     * Marca o desmarca el ítem como urgente para cocina.
+     *
+     * Cuándo se permite lo decide el aggregate Pedido.
+     */
+    public void marcarUrgente(boolean urgente) {
+        this.urgente = urgente;
+    }
+
+    private ItemPedido conUrgencia(boolean urgente) {
+        this.urgente = urgente;
+        return this;
+    }
+
+    // ============================================
+    // HU-139: Costo de envío
+    // ============================================
+
+    /**
+     * Ítem con el costo de envío de la zona (una unidad, sin categoría).
+     *
+     * @param pedidoId pedido de delivery al que se carga
+     * @param zona zona de reparto de la dirección de entrega
+     */
+    public static ItemPedido crearEnvio(PedidoId pedidoId, ZonaDelivery zona) {
+        Objects.requireNonNull(zona, "La zona de reparto no puede ser null");
+        return new ItemPedido(
+            ItemPedidoId.generate(), pedidoId, new ProductoId(zona.getId().getValue()),
+            zona.nombreItemEnvio(), 1, zona.getCostoEnvio(), null,
+            BigDecimal.ZERO, null, null, null, Collections.emptyList(),
+            null, null, null, LocalDateTime.now(), 0, List.of()
+        ).conEnvio(true);
+    }
+
+    public boolean isEnvio() {
+        return envio;
+    }
+
+    /**
+     * Restaura la marca de envío al reconstruir el ítem desde persistencia.
+     */
+    public void marcarComoEnvio() {
+        this.envio = true;
+    }
+
+    private ItemPedido conEnvio(boolean envio) {
+        this.envio = envio;
+        return this;
+    }
+
+    // ============================================
+    // HU-147: Advertencias de alergia
+    // ============================================
+
+    /**
+     * @return alergias declaradas para el comensal (inmutable, vacío si no hay)
+     */
+    public Set<Alergeno> getAdvertencias() {
+        return advertencias;
+    }
+
+    public boolean tieneAdvertencias() {
+        return !advertencias.isEmpty();
+    }
+
+    /**
+     * Registra las alergias del comensal para el que se carga el ítem.
+     *
+     * @param advertencias alérgenos declarados (null o vacío para ninguno)
+     */
+    public void registrarAdvertencias(Collection<Alergeno> advertencias) {
+        this.advertencias = advertencias == null || advertencias.isEmpty()
+            ? Set.of()
+            : Collections.unmodifiableSet(EnumSet.copyOf(advertencias));
+    }
+
+    private ItemPedido conAdvertencias(Set<Alergeno> advertencias) {
+        this.advertencias = advertencias;
+        return this;
+    }
+
+    // ============================================
+    // HU-148: Ingredientes quitados
+    // ============================================
+
+    /**
+     * @return ingredientes de la receta que no lleva el ítem (inmutable, vacío si no hay)
+     */
+    public List<IngredienteQuitado> getIngredientesQuitados() {
+        return ingredientesQuitados;
+    }
+
+    public boolean tieneIngredientesQuitados() {
+        return !ingredientesQuitados.isEmpty();
+    }
+
+    /**
+     * @return true si el cliente pidió sacar este insumo de la receta
+     */
+    public boolean quitaInsumo(InsumoId insumoId) {
+        return ingredientesQuitados.stream().anyMatch(i -> i.getInsumoId().equals(insumoId));
+    }
+
+    /**
+     * Registra los ingredientes que el cliente pidió sacar.
+     *
+     * Que sean insumos de la receta del producto lo valida la capa de aplicación.
+     *
+     * @param ingredientes ingredientes quitados (null o vacío para ninguno)
+     * @throws IllegalArgumentException si un insumo se repite
+     */
+    public void registrarIngredientesQuitados(Collection<IngredienteQuitado> ingredientes) {
+        if (ingredientes == null || ingredientes.isEmpty()) {
+            this.ingredientesQuitados = List.of();
+            return;
+        }
+        if (new HashSet<>(ingredientes).size() != ingredientes.size()) {
+            throw new IllegalArgumentException("Un ingrediente no puede quitarse dos veces");
+        }
+        this.ingredientesQuitados = List.copyOf(ingredientes);
+    }
+
+    /**
+     * Observación tal como sale a cocina: los ingredientes quitados adelante
+     * ("SIN CEBOLLA, SIN TOMATE") y después la nota libre del mozo.
+     *
+     * @return el texto para cocina, o null si no hay nada que indicar
+     */
+    public String getObservacionCocina() {
+        if (ingredientesQuitados.isEmpty()) {
+            return observacion;
+        }
+        String sin = ingredientesQuitados.stream()
+            .map(i -> "SIN " + i.getNombre().toUpperCase())
+            .collect(Collectors.joining(", "));
+        String texto = observacion == null ? sin : sin + " · " + observacion;
+        return texto.length() > OBSERVACION_LONGITUD_MAXIMA
+            ? texto.substring(0, OBSERVACION_LONGITUD_MAXIMA)
+            : texto;
+    }
+
+    private ItemPedido conIngredientesQuitados(List<IngredienteQuitado> ingredientes) {
+        this.ingredientesQuitados = ingredientes;
+        return this;
+    }
+
+    // ============================================
+    // HU-164: Borrador del POS
+    // ============================================
+
+    /**
+     * @return ids de los ítems del borrador que cargaron esta línea (inmutable, vacío si no hay)
+     */
+    public Set<UUID> getBorradores() {
+        return borradores;
+    }
+
+    /**
+     * Registra los ítems del borrador del POS que cargaron esta línea.
+     *
+     * @param borradores ids del borrador (null o vacío para ninguno)
+     */
+    public void registrarBorradores(Collection<UUID> borradores) {
+        this.borradores = borradores == null || borradores.isEmpty() ? Set.of() : Set.copyOf(borradores);
+    }
+
+    private ItemPedido conBorradores(Set<UUID> borradores) {
+        this.borradores = borradores;
+        return this;
+    }
+
     // ============================================
     // HU-29: Control de envío a cocina (delta)
     // ============================================
@@ -820,6 +1207,7 @@ public class ItemPedido {
      * - Mismo productoId
      * - Misma observación (null-safe, case-sensitive)
      * - Mismos extras (mismo conjunto de productoId + nombre + precio)
+     * - HU-103: Mismas opciones de combo
      * 
      * Si cualquiera de estos difiere, son platos personalizados distintos
      * y NO deben fusionarse.
@@ -840,6 +1228,9 @@ public class ItemPedido {
         
         // 2. Misma observación (null-safe)
         if (!Objects.equals(this.observacion, otro.observacion)) return false;
+
+        // HU-103: Un combo con Coca no se fusiona con el mismo combo con agua
+        if (!this.componentesCombo.equals(otro.componentesCombo)) return false;
         
         // 3. Mismos extras (comparación por valor — ExtraPedido implementa equals por valor)
         if (this.extras.size() != otro.extras.size()) return false;
@@ -867,7 +1258,7 @@ public class ItemPedido {
      */
     public boolean coincideConfiguracion(ProductoId productoId, String observacion, List<ExtraPedido> extras) {
         if (!this.productoId.equals(productoId)) return false;
-        if (!Objects.equals(this.observacion, observacion)) return false;
+        if (!Objects.equals(this.observacion, normalizarObservacion(observacion))) return false;
         
         List<ExtraPedido> otrosExtras = extras != null ? extras : Collections.emptyList();
         if (this.extras.size() != otrosExtras.size()) return false;
@@ -0,0 +1,90 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+
+import java.math.BigDecimal;
+import java.util.Objects;
+
+/**
+ * Renglón de un pedido armado por el cliente desde el QR.
+ *
+ * HU-126: Guarda el nombre y el precio que vio el cliente en la carta para
+ * que el mozo valide exactamente eso. Al confirmarse, el ítem del pedido se
+ * arma de nuevo desde el catálogo con el precio vigente.
+ */
+public final class ItemSolicitudAutopedido {
+
+    public static final int CANTIDAD_MAXIMA = 20;
+    private static final int LONGITUD_MAXIMA_OBSERVACION = 140;
+
+    private final ProductoId productoId;
+    private final String nombreProducto;
+    private final BigDecimal precioUnitario;
+    private final int cantidad;
+    private final String observacion;
+
+    public ItemSolicitudAutopedido(ProductoId productoId, String nombreProducto, BigDecimal precioUnitario,
+                                   int cantidad, String observacion) {
+        this.productoId = Objects.requireNonNull(productoId, "El producto es obligatorio");
+        if (nombreProducto == null || nombreProducto.isBlank()) {
+            throw new IllegalArgumentException("El nombre del producto es obligatorio");
+        }
+        if (cantidad <= 0 || cantidad > CANTIDAD_MAXIMA) {
+            throw new IllegalArgumentException(
+                "La cantidad de " + nombreProducto + " debe estar entre 1 y " + CANTIDAD_MAXIMA);
+        }
+        this.nombreProducto = nombreProducto;
+        this.precioUnitario = Objects.requireNonNull(precioUnitario, "El precio es obligatorio");
+        this.cantidad = cantidad;
+        this.observacion = normalizarObservacion(observacion);
+    }
+
+    private static String normalizarObservacion(String observacion) {
+        if (observacion == null || observacion.isBlank()) {
+            return null;
+        }
+        String limpia = observacion.trim();
+        return limpia.length() > LONGITUD_MAXIMA_OBSERVACION
+            ? limpia.substring(0, LONGITUD_MAXIMA_OBSERVACION)
+            : limpia;
+    }
+
+    public BigDecimal calcularSubtotal() {
+        return precioUnitario.multiply(BigDecimal.valueOf(cantidad));
+    }
+
+    public ProductoId getProductoId() {
+        return productoId;
+    }
+
+    public String getNombreProducto() {
+        return nombreProducto;
+    }
+
+    public BigDecimal getPrecioUnitario() {
+        return precioUnitario;
+    }
+
+    public int getCantidad() {
+        return cantidad;
+    }
+
+    public String getObservacion() {
+        return observacion;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        ItemSolicitudAutopedido that = (ItemSolicitudAutopedido) o;
+        return cantidad == that.cantidad
+            && productoId.equals(that.productoId)
+            && Objects.equals(observacion, that.observacion);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(productoId, cantidad, observacion);
+    }
+}
@@ -22,7 +22,7 @@ import java.util.Objects;
  * Reglas de negocio:
  * - Solo puede existir una jornada ABIERTA por local a la vez.
  * - La fecha operativa se calcula al momento de la apertura usando la
- *   hora de corte (06:00) para turnos noche.
+ *   hora de corte del local para turnos noche (06:00 si no la configuró, HU-153).
  * - El fondo inicial se declara al abrir y no puede modificarse después.
  * - La combinación (local_id, fecha_operativa) es única.
  * - Una vez CERRADA, la jornada es inmutable.
@@ -33,11 +33,11 @@ import java.util.Objects;
 public class JornadaCaja {
 
     /**
-     * Hora de corte para determinar la fecha operativa.
+     * Hora de corte por defecto para determinar la fecha operativa.
      * Si la apertura/cierre ocurre ANTES de esta hora, la jornada pertenece
-     * al día anterior. Ningún local gastronómico opera a las 06:00.
+     * al día anterior. HU-153: cada local puede configurar la suya.
      */
-    private static final LocalTime HORA_CORTE_JORNADA = LocalTime.of(6, 0);
+    private static final LocalTime HORA_CORTE_JORNADA = JornadaComercial.HORA_CORTE_POR_DEFECTO;
 
     private final JornadaCajaId id;
     private final LocalId localId;
@@ -71,11 +71,21 @@ public class JornadaCaja {
      */
     public JornadaCaja(JornadaCajaId id, LocalId localId,
                        BigDecimal fondoInicial, LocalDateTime fechaApertura) {
+        this(id, localId, fondoInicial, fechaApertura, HORA_CORTE_JORNADA);
+    }
+
+    /**
+     * Constructor de apertura con la hora de corte del local (HU-153).
+     *
+     * @param horaCorte hora en que termina la jornada comercial del local
+     */
+    public JornadaCaja(JornadaCajaId id, LocalId localId,
+                       BigDecimal fondoInicial, LocalDateTime fechaApertura, LocalTime horaCorte) {
         this.id = Objects.requireNonNull(id, "El id de la jornada no puede ser null");
         this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
         this.fondoInicial = validarFondoInicial(fondoInicial);
         this.fechaApertura = Objects.requireNonNull(fechaApertura, "La fecha de apertura no puede ser null");
-        this.fechaOperativa = calcularFechaOperativa(fechaApertura);
+        this.fechaOperativa = calcularFechaOperativa(fechaApertura, horaCorte);
         this.estado = EstadoJornada.ABIERTA;
 
         // Snapshot vacío — se completa al cerrar
@@ -170,7 +180,17 @@ public class JornadaCaja {
      * @return fecha del día operativo
      */
     public static LocalDate calcularFechaOperativa(LocalDateTime momento) {
-        if (momento.toLocalTime().isBefore(HORA_CORTE_JORNADA)) {
+        return calcularFechaOperativa(momento, HORA_CORTE_JORNADA);
+    }
+
+    /**
+     * HU-153: Igual que {@link #calcularFechaOperativa(LocalDateTime)} con la
+     * hora de corte del local. Con corte a las 00:00 es el día calendario.
+     */
+    public static LocalDate calcularFechaOperativa(LocalDateTime momento, LocalTime horaCorte) {
+        Objects.requireNonNull(momento, "El momento es obligatorio");
+        Objects.requireNonNull(horaCorte, "La hora de corte es obligatoria");
+        if (momento.toLocalTime().isBefore(horaCorte)) {
             return momento.toLocalDate().minusDays(1);
         }
         return momento.toLocalDate();
@@ -0,0 +1,127 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.LocalTime;
+import java.time.YearMonth;
+import java.time.temporal.ChronoUnit;
+import java.util.Objects;
+
+/**
+ * Límite de la jornada comercial de un local.
+ * Hay a lo sumo una configuración por local: la identidad es el propio LocalId.
+ *
+ * HU-153: Límite de jornada comercial configurable.
+ *
+ * Reglas de negocio:
+ * - La jornada comercial va de la hora de corte de un día a la hora de corte
+ *   del día siguiente. Lo que pasa antes del corte pertenece al día anterior.
+ * - La hora de corte va de 00:00 (día calendario) a 11:59, en minutos.
+ * - Sin configurar, el corte es a las 06:00, la regla de turno noche que
+ *   usaba la caja.
+ * - Cambiar el corte no mueve las jornadas ya abiertas o cerradas: vale para
+ *   las que se abran y los reportes que se consulten desde ahora.
+ *
+ * Ejemplo: corte 04:00 → lo cobrado a las 03:30 del 11/10 suma a la jornada
+ * del 10/10, que va del 10/10 04:00 al 11/10 03:59.
+ */
+public class JornadaComercial {
+
+    public static final LocalTime HORA_CORTE_POR_DEFECTO = LocalTime.of(6, 0);
+    private static final LocalTime HORA_CORTE_MAXIMA = LocalTime.NOON;
+
+    private final LocalId localId;
+    private LocalTime horaCorte;
+
+    public JornadaComercial(LocalId localId, LocalTime horaCorte) {
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.horaCorte = validarHoraCorte(horaCorte);
+    }
+
+    /**
+     * Configuración de un local que todavía no cargó la hora de corte.
+     */
+    public static JornadaComercial porDefecto(LocalId localId) {
+        return new JornadaComercial(localId, HORA_CORTE_POR_DEFECTO);
+    }
+
+    // ============================================
+    // Validaciones
+    // ============================================
+
+    private LocalTime validarHoraCorte(LocalTime horaCorte) {
+        Objects.requireNonNull(horaCorte, "La hora de corte de la jornada es obligatoria");
+        if (!horaCorte.isBefore(HORA_CORTE_MAXIMA)) {
+            throw new IllegalArgumentException("La hora de corte de la jornada tiene que ser entre las 00:00 y las 11:59");
+        }
+        return horaCorte.truncatedTo(ChronoUnit.MINUTES);
+    }
+
+    // ============================================
+    // Comportamiento
+    // ============================================
+
+    public void actualizar(LocalTime horaCorte) {
+        this.horaCorte = validarHoraCorte(horaCorte);
+    }
+
+    /**
+     * @return el día de la jornada comercial a la que pertenece el momento
+     */
+    public LocalDate fechaOperativa(LocalDateTime momento) {
+        return JornadaCaja.calcularFechaOperativa(momento, horaCorte);
+    }
+
+    /**
+     * @return primer instante de la jornada comercial del día
+     */
+    public LocalDateTime inicio(LocalDate fechaOperativa) {
+        Objects.requireNonNull(fechaOperativa, "La fecha operativa es obligatoria");
+        return fechaOperativa.atTime(horaCorte);
+    }
+
+    /**
+     * @return último instante de la jornada comercial del día (inclusivo, como
+     *         las consultas por rango de los repositorios)
+     */
+    public LocalDateTime fin(LocalDate fechaOperativa) {
+        return inicio(fechaOperativa.plusDays(1)).minusNanos(1);
+    }
+
+    /**
+     * HU-132: Lo cobrado pasada la medianoche del último día del mes, antes
+     * del corte, todavía es del mes que termina.
+     *
+     * @return el mes de la jornada comercial a la que pertenece el momento
+     */
+    public YearMonth mesOperativo(LocalDateTime momento) {
+        return YearMonth.from(fechaOperativa(momento));
+    }
+
+    /**
+     * @return primer instante de la primera jornada comercial del mes
+     */
+    public LocalDateTime inicio(YearMonth mes) {
+        Objects.requireNonNull(mes, "El mes es obligatorio");
+        return inicio(mes.atDay(1));
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public LocalTime getHoraCorte() {
+        return horaCorte;
+    }
+
+    @Override
+    public String toString() {
+        return String.format("JornadaComercial{localId=%s, horaCorte=%s}", localId, horaCorte);
+    }
+}
@@ -0,0 +1,225 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoLinkPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.ProveedorLinkPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LinkPagoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Link de pago que se le manda al cliente de un pedido telefónico de delivery.
+ *
+ * HU-128: Links de pago para delivery.
+ *
+ * Mientras el link está PENDIENTE el pedido no sale a cocina: se cocina
+ * recién cuando la pasarela confirma el cobro.
+ *
+ * Reglas de negocio:
+ * - Solo para pedidos abiertos del canal DELIVERY, con total mayor a cero.
+ * - El monto es el total del pedido al generar el link. Si el pedido cambia,
+ *   se genera uno nuevo y el anterior queda CANCELADO.
+ * - Solo un link PENDIENTE se puede marcar como pagado o cancelar.
+ */
+public class LinkPago {
+
+    private static final int LONGITUD_MAXIMA_TELEFONO = 30;
+
+    private final LinkPagoId id;
+    private final LocalId localId;
+    private final PedidoId pedidoId;
+    private final MesaId mesaId;
+    private final int numeroMesa;
+    private final int numeroPedido;
+    private final ProveedorLinkPago proveedor;
+    private final BigDecimal monto;
+    private final String telefonoCliente;
+    private final String url;
+    private final LocalDateTime creadoEn;
+    private EstadoLinkPago estado;
+    private LocalDateTime pagadoEn;
+    private String idPagoPasarela;
+
+    public LinkPago(
+            LinkPagoId id,
+            LocalId localId,
+            PedidoId pedidoId,
+            MesaId mesaId,
+            int numeroMesa,
+            int numeroPedido,
+            ProveedorLinkPago proveedor,
+            BigDecimal monto,
+            String telefonoCliente,
+            String url,
+            LocalDateTime creadoEn,
+            EstadoLinkPago estado,
+            LocalDateTime pagadoEn,
+            String idPagoPasarela
+    ) {
+        this.id = Objects.requireNonNull(id, "El id del link no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.pedidoId = Objects.requireNonNull(pedidoId, "El link debe pertenecer a un pedido");
+        this.mesaId = Objects.requireNonNull(mesaId, "El link debe pertenecer a una mesa");
+        this.proveedor = Objects.requireNonNull(proveedor, "La pasarela de pago es obligatoria");
+        this.creadoEn = Objects.requireNonNull(creadoEn, "La fecha del link es obligatoria");
+        this.estado = Objects.requireNonNull(estado, "El estado del link es obligatorio");
+        if (monto == null || monto.signum() <= 0) {
+            throw new IllegalArgumentException("El monto del link de pago debe ser mayor a cero");
+        }
+        if (url == null || url.isBlank()) {
+            throw new IllegalArgumentException("La pasarela no devolvió la dirección del link");
+        }
+        this.numeroMesa = numeroMesa;
+        this.numeroPedido = numeroPedido;
+        this.monto = monto;
+        this.telefonoCliente = normalizarTelefono(telefonoCliente);
+        this.url = url;
+        this.pagadoEn = pagadoEn;
+        this.idPagoPasarela = idPagoPasarela;
+    }
+
+    /**
+     * Verifica que el pedido admita cobro por link, antes de pedirle el link a la pasarela.
+     *
+     * @throws IllegalStateException si el pedido no es de delivery, está cerrado o no tiene nada que cobrar
+     */
+    public static void validarPedido(Pedido pedido) {
+        Objects.requireNonNull(pedido, "El pedido es obligatorio");
+        if (pedido.getCanal() != CanalVenta.DELIVERY) {
+            throw new IllegalStateException("El link de pago es solo para pedidos de delivery");
+        }
+        if (pedido.getEstado() != EstadoPedido.ABIERTO) {
+            throw new IllegalStateException("El pedido ya está cerrado");
+        }
+        if (pedido.calcularTotal().signum() <= 0) {
+            throw new IllegalStateException("El pedido no tiene nada para cobrar");
+        }
+    }
+
+    /**
+     * Registra el link que devolvió la pasarela por el total actual del pedido.
+     */
+    public static LinkPago emitir(LinkPagoId id, Pedido pedido, int numeroMesa, ProveedorLinkPago proveedor,
+                                  String telefonoCliente, String url, LocalDateTime ahora) {
+        validarPedido(pedido);
+        return new LinkPago(id, pedido.getLocalId(), pedido.getId(), pedido.getMesaId(), numeroMesa,
+            pedido.getNumero(), proveedor, pedido.calcularTotal(), telefonoCliente, url, ahora,
+            EstadoLinkPago.PENDIENTE, null, null);
+    }
+
+    // ============================================
+    // Lógica de dominio
+    // ============================================
+
+    /**
+     * La pasarela confirmó el cobro.
+     *
+     * @param idPago identificador del pago en la pasarela, para rastrearlo en su panel
+     * @throws IllegalStateException si el link no está pendiente
+     */
+    public void marcarPagado(String idPago, LocalDateTime ahora) {
+        Objects.requireNonNull(ahora, "La fecha es obligatoria");
+        validarPendiente();
+        this.estado = EstadoLinkPago.PAGADO;
+        this.pagadoEn = ahora;
+        this.idPagoPasarela = idPago;
+    }
+
+    /**
+     * El operador descarta el link (el cliente paga en efectivo, se generó otro).
+     *
+     * @throws IllegalStateException si el link no está pendiente
+     */
+    public void cancelar() {
+        validarPendiente();
+        this.estado = EstadoLinkPago.CANCELADO;
+    }
+
+    public boolean estaPendiente() {
+        return estado == EstadoLinkPago.PENDIENTE;
+    }
+
+    private void validarPendiente() {
+        if (!estaPendiente()) {
+            throw new IllegalStateException(
+                "El link de pago de la mesa " + numeroMesa + " ya está " + estado.name().toLowerCase());
+        }
+    }
+
+    private static String normalizarTelefono(String telefono) {
+        if (telefono == null || telefono.isBlank()) {
+            return null;
+        }
+        String limpio = telefono.trim();
+        if (limpio.length() > LONGITUD_MAXIMA_TELEFONO) {
+            throw new IllegalArgumentException("El teléfono no puede superar " + LONGITUD_MAXIMA_TELEFONO + " caracteres");
+        }
+        return limpio;
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public LinkPagoId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public PedidoId getPedidoId() {
+        return pedidoId;
+    }
+
+    public MesaId getMesaId() {
+        return mesaId;
+    }
+
+    public int getNumeroMesa() {
+        return numeroMesa;
+    }
+
+    public int getNumeroPedido() {
+        return numeroPedido;
+    }
+
+    public ProveedorLinkPago getProveedor() {
+        return proveedor;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+
+    public String getTelefonoCliente() {
+        return telefonoCliente;
+    }
+
+    public String getUrl() {
+        return url;
+    }
+
+    public LocalDateTime getCreadoEn() {
+        return creadoEn;
+    }
+
+    public EstadoLinkPago getEstado() {
+        return estado;
+    }
+
+    public LocalDateTime getPagadoEn() {
+        return pagadoEn;
+    }
+
+    public String getIdPagoPasarela() {
+        return idPagoPasarela;
+    }
+}
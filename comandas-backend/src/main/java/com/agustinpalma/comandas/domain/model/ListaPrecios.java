@@ -0,0 +1,151 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainIds.ListaPreciosId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.util.Collections;
+import java.util.HashMap;
+import java.util.Map;
+import java.util.Objects;
+
+/**
+ * Lista de precios de un canal de venta (salón, take away, delivery).
+ *
+ * HU-108: Listas de precios por canal.
+ *
+ * El precio del catálogo (Producto.precio) es la base. Cada lista define:
+ * - Un recargo porcentual sobre la base (ej: delivery +15%)
+ * - Precios especiales por producto, que reemplazan al cálculo con recargo
+ *
+ * Reglas de negocio:
+ * - Una lista por canal y por local.
+ * - El recargo va de 0% a 100%: las rebajas se modelan como promociones.
+ * - Los precios especiales deben ser positivos, igual que en el catálogo.
+ * - Un canal sin lista configurada vende a precio de catálogo.
+ *
+ * El pedido toma la lista de su canal al crearse y congela cuál usó
+ * (id + nombre); cada ítem congela además su precio unitario.
+ */
+public class ListaPrecios {
+
+    private static final BigDecimal CIEN = new BigDecimal("100");
+
+    private final ListaPreciosId id;
+    private final LocalId localId;
+    private final CanalVenta canal;
+    private String nombre;
+    private BigDecimal porcentajeRecargo;
+    private final Map<ProductoId, BigDecimal> preciosEspeciales;
+
+    public ListaPrecios(ListaPreciosId id, LocalId localId, CanalVenta canal, String nombre,
+                        BigDecimal porcentajeRecargo, Map<ProductoId, BigDecimal> preciosEspeciales) {
+        this.id = Objects.requireNonNull(id, "El id de la lista de precios no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.canal = Objects.requireNonNull(canal, "El canal de la lista de precios es obligatorio");
+        this.nombre = validarNombre(nombre);
+        this.porcentajeRecargo = validarRecargo(porcentajeRecargo);
+        this.preciosEspeciales = new HashMap<>();
+        if (preciosEspeciales != null) {
+            preciosEspeciales.forEach(this::definirPrecioEspecial);
+        }
+    }
+
+    /**
+     * Precio de venta del producto en este canal.
+     *
+     * @param producto producto del catálogo
+     * @return precio especial si está definido; si no, precio base con recargo
+     *         (redondeado a 2 decimales, HALF_UP)
+     */
+    public BigDecimal precioPara(Producto producto) {
+        Objects.requireNonNull(producto, "El producto no puede ser null");
+
+        BigDecimal especial = preciosEspeciales.get(producto.getId());
+        if (especial != null) {
+            return especial;
+        }
+
+        BigDecimal factor = BigDecimal.ONE.add(porcentajeRecargo.divide(CIEN));
+        return producto.getPrecio().multiply(factor).setScale(2, RoundingMode.HALF_UP);
+    }
+
+    /**
+     * Reconfigura nombre, recargo y precios especiales de una sola vez.
+     * Los precios especiales anteriores se reemplazan por completo.
+     */
+    public void actualizar(String nombre, BigDecimal porcentajeRecargo,
+                           Map<ProductoId, BigDecimal> preciosEspeciales) {
+        this.nombre = validarNombre(nombre);
+        this.porcentajeRecargo = validarRecargo(porcentajeRecargo);
+        this.preciosEspeciales.clear();
+        if (preciosEspeciales != null) {
+            preciosEspeciales.forEach(this::definirPrecioEspecial);
+        }
+    }
+
+    private void definirPrecioEspecial(ProductoId productoId, BigDecimal precio) {
+        Objects.requireNonNull(productoId, "El producto del precio especial es obligatorio");
+        if (precio == null || precio.compareTo(BigDecimal.ZERO) <= 0) {
+            throw new IllegalArgumentException("El precio especial debe ser mayor a cero");
+        }
+        this.preciosEspeciales.put(productoId, precio);
+    }
+
+    private String validarNombre(String nombre) {
+        if (nombre == null || nombre.isBlank()) {
+            throw new IllegalArgumentException("El nombre de la lista de precios no puede estar vacío");
+        }
+        return nombre.trim();
+    }
+
+    private BigDecimal validarRecargo(BigDecimal porcentaje) {
+        if (porcentaje == null) {
+            return BigDecimal.ZERO;
+        }
+        if (porcentaje.compareTo(BigDecimal.ZERO) < 0 || porcentaje.compareTo(CIEN) > 0) {
+            throw new IllegalArgumentException("El recargo de la lista debe estar entre 0% y 100%");
+        }
+        return porcentaje;
+    }
+
+    public ListaPreciosId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public CanalVenta getCanal() {
+        return canal;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public BigDecimal getPorcentajeRecargo() {
+        return porcentajeRecargo;
+    }
+
+    public Map<ProductoId, BigDecimal> getPreciosEspeciales() {
+        return Collections.unmodifiableMap(preciosEspeciales);
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        ListaPrecios that = (ListaPrecios) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
@@ -0,0 +1,178 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+
+import java.util.EnumMap;
+import java.util.EnumSet;
+import java.util.HashMap;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Set;
+
+/**
+ * Matriz de permisos por acción de un local: qué puede hacer cada rol y
+ * las excepciones de cada empleado. Hay a lo sumo una por local: la
+ * identidad es el propio LocalId.
+ *
+ * HU-169: Matriz de permisos por rol y por empleado.
+ *
+ * Reglas de negocio:
+ * - Mientras está inactiva no restringe nada: el local sigue operando como
+ *   antes de configurarla.
+ * - Un empleado puede tener una excepción por permiso que le concede o le
+ *   quita lo que dice su rol.
+ * - El dueño siempre tiene todos los permisos, para que nadie pueda dejar
+ *   al local sin quién administre la matriz.
+ * - Un empleado inactivo no tiene ningún permiso.
+ */
+public class MatrizPermisos {
+
+    private final LocalId localId;
+    private boolean activa;
+    private Map<RolPersonal, Set<Permiso>> permisosPorRol;
+    private Map<MozoId, Map<Permiso, Boolean>> excepciones;
+
+    public MatrizPermisos(LocalId localId, boolean activa, Map<RolPersonal, Set<Permiso>> permisosPorRol,
+                          Map<MozoId, Map<Permiso, Boolean>> excepciones) {
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.activa = activa;
+        this.permisosPorRol = validarPermisosPorRol(permisosPorRol);
+        this.excepciones = validarExcepciones(excepciones);
+    }
+
+    /**
+     * Matriz por defecto de un local que todavía no la configuró: inactiva,
+     * con el encargado y el dueño habilitados para todo y el mozo solo para
+     * los descuentos (dentro de su tope, HU-122).
+     */
+    public static MatrizPermisos porDefecto(LocalId localId) {
+        Map<RolPersonal, Set<Permiso>> porRol = new EnumMap<>(RolPersonal.class);
+        porRol.put(RolPersonal.MOZO, EnumSet.of(Permiso.APLICAR_DESCUENTOS));
+        porRol.put(RolPersonal.ENCARGADO, EnumSet.allOf(Permiso.class));
+        return new MatrizPermisos(localId, false, porRol, Map.of());
+    }
+
+    // ============================================
+    // Validaciones
+    // ============================================
+
+    private Map<RolPersonal, Set<Permiso>> validarPermisosPorRol(Map<RolPersonal, Set<Permiso>> permisosPorRol) {
+        Objects.requireNonNull(permisosPorRol, "Los permisos por rol son obligatorios");
+        Map<RolPersonal, Set<Permiso>> limpios = new EnumMap<>(RolPersonal.class);
+        for (RolPersonal rol : RolPersonal.values()) {
+            Set<Permiso> permisos = permisosPorRol.get(rol);
+            limpios.put(rol, permisos == null || permisos.isEmpty()
+                ? EnumSet.noneOf(Permiso.class)
+                : EnumSet.copyOf(permisos));
+        }
+        limpios.put(RolPersonal.DUENO, EnumSet.allOf(Permiso.class));
+        return limpios;
+    }
+
+    private Map<MozoId, Map<Permiso, Boolean>> validarExcepciones(Map<MozoId, Map<Permiso, Boolean>> excepciones) {
+        Objects.requireNonNull(excepciones, "Las excepciones por empleado son obligatorias");
+        Map<MozoId, Map<Permiso, Boolean>> limpias = new HashMap<>();
+        excepciones.forEach((empleadoId, permisos) -> {
+            Objects.requireNonNull(empleadoId, "El empleado de la excepción no puede ser null");
+            Map<Permiso, Boolean> propias = new EnumMap<>(Permiso.class);
+            if (permisos != null) {
+                permisos.forEach((permiso, concedido) -> {
+                    Objects.requireNonNull(permiso, "El permiso de la excepción no puede ser null");
+                    if (concedido != null) {
+                        propias.put(permiso, concedido);
+                    }
+                });
+            }
+            if (!propias.isEmpty()) {
+                limpias.put(empleadoId, propias);
+            }
+        });
+        return limpias;
+    }
+
+    // ============================================
+    // Comportamiento
+    // ============================================
+
+    public void actualizar(boolean activa, Map<RolPersonal, Set<Permiso>> permisosPorRol,
+                           Map<MozoId, Map<Permiso, Boolean>> excepciones) {
+        this.permisosPorRol = validarPermisosPorRol(permisosPorRol);
+        this.excepciones = validarExcepciones(excepciones);
+        this.activa = activa;
+    }
+
+    /**
+     * Indica si el empleado puede hacer la acción.
+     * Con la matriz inactiva cualquiera puede.
+     */
+    public boolean permite(Mozo empleado, Permiso permiso) {
+        Objects.requireNonNull(empleado, "El empleado no puede ser null");
+        Objects.requireNonNull(permiso, "El permiso no puede ser null");
+        if (!activa) {
+            return true;
+        }
+        if (!empleado.isActivo()) {
+            return false;
+        }
+        if (empleado.getRol() == RolPersonal.DUENO) {
+            return true;
+        }
+        Boolean excepcion = excepciones.getOrDefault(empleado.getId(), Map.of()).get(permiso);
+        if (excepcion != null) {
+            return excepcion;
+        }
+        return permisosPorRol.get(empleado.getRol()).contains(permiso);
+    }
+
+    /**
+     * @return las acciones que puede hacer el empleado, con las excepciones aplicadas
+     */
+    public Set<Permiso> permisosDe(Mozo empleado) {
+        Set<Permiso> permisos = EnumSet.noneOf(Permiso.class);
+        for (Permiso permiso : Permiso.values()) {
+            if (permite(empleado, permiso)) {
+                permisos.add(permiso);
+            }
+        }
+        return permisos;
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public boolean isActiva() {
+        return activa;
+    }
+
+    /**
+     * @return los permisos de cada rol (todos los roles presentes)
+     */
+    public Map<RolPersonal, Set<Permiso>> getPermisosPorRol() {
+        Map<RolPersonal, Set<Permiso>> copia = new EnumMap<>(RolPersonal.class);
+        permisosPorRol.forEach((rol, permisos) -> copia.put(rol, Set.copyOf(permisos)));
+        return copia;
+    }
+
+    /**
+     * @return solo los empleados con alguna excepción a su rol
+     */
+    public Map<MozoId, Map<Permiso, Boolean>> getExcepciones() {
+        Map<MozoId, Map<Permiso, Boolean>> copia = new HashMap<>();
+        excepciones.forEach((empleadoId, permisos) -> copia.put(empleadoId, Map.copyOf(permisos)));
+        return copia;
+    }
+
+    @Override
+    public String toString() {
+        return String.format("MatrizPermisos{localId=%s, activa=%s, permisosPorRol=%s, excepciones=%s}",
+            localId, activa, permisosPorRol, excepciones);
+    }
+}
@@ -0,0 +1,183 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaMerma;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MermaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.HashSet;
+import java.util.List;
+import java.util.Objects;
+import java.util.Set;
+
+/**
+ * Mercadería que se perdió sin venderse.
+ *
+ * HU-135: Registro de mermas y desperdicio.
+ *
+ * Reglas de negocio:
+ * - Tiene categoría, motivo y responsable (máximo 60 caracteres). El motivo
+ *   va también en el movimiento de stock, por eso no supera los 100 caracteres.
+ * - Se registra por insumo (el queso vencido) o por producto (el plato que se
+ *   cayó): en ese caso los renglones son los insumos de su receta por las
+ *   unidades perdidas, y se guarda qué producto fue.
+ * - Tiene al menos un insumo y ninguno repetido.
+ * - Es inmutable: el stock ya se descontó; un error se corrige con una toma de inventario.
+ */
+public class Merma {
+
+    private static final int LONGITUD_MAXIMA_MOTIVO = 100;
+    private static final int LONGITUD_MAXIMA_RESPONSABLE = 60;
+
+    private final MermaId id;
+    private final LocalId localId;
+    private final LocalDateTime fecha;
+    private final CategoriaMerma categoria;
+    private final String motivo;
+    private final String responsable;
+    private final ProductoId productoId;
+    private final String nombreProducto;
+    private final Integer unidadesProducto;
+    private final List<RenglonMerma> renglones;
+
+    /**
+     * @param productoId null si la merma se cargó por insumo
+     * @param nombreProducto snapshot del nombre del producto (null si se cargó por insumo)
+     * @param unidadesProducto unidades del producto perdidas (null si se cargó por insumo)
+     */
+    public Merma(MermaId id, LocalId localId, LocalDateTime fecha, CategoriaMerma categoria, String motivo,
+                 String responsable, ProductoId productoId, String nombreProducto, Integer unidadesProducto,
+                 List<RenglonMerma> renglones) {
+        this.id = Objects.requireNonNull(id, "El id de la merma no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.fecha = Objects.requireNonNull(fecha, "La fecha de la merma no puede ser null");
+        this.categoria = Objects.requireNonNull(categoria, "La categoría de la merma no puede ser null");
+        this.motivo = validarTexto(motivo, "El motivo de la merma", LONGITUD_MAXIMA_MOTIVO);
+        this.responsable = validarTexto(responsable, "El responsable de la merma", LONGITUD_MAXIMA_RESPONSABLE);
+        if (productoId != null && (nombreProducto == null || unidadesProducto == null || unidadesProducto <= 0)) {
+            throw new IllegalArgumentException("La merma de un producto necesita su nombre y las unidades perdidas");
+        }
+        this.productoId = productoId;
+        this.nombreProducto = productoId != null ? nombreProducto : null;
+        this.unidadesProducto = productoId != null ? unidadesProducto : null;
+        this.renglones = validarRenglones(renglones);
+    }
+
+    /**
+     * Merma cargada insumo por insumo.
+     */
+    public static Merma deInsumos(LocalId localId, CategoriaMerma categoria, String motivo, String responsable,
+                                  List<RenglonMerma> renglones, LocalDateTime fecha) {
+        return new Merma(MermaId.generate(), localId, fecha, categoria, motivo, responsable,
+            null, null, null, renglones);
+    }
+
+    /**
+     * Merma de unidades de un producto; los renglones salen de su receta.
+     */
+    public static Merma deProducto(LocalId localId, CategoriaMerma categoria, String motivo, String responsable,
+                                   Producto producto, int unidades, List<RenglonMerma> renglones,
+                                   LocalDateTime fecha) {
+        Objects.requireNonNull(producto, "El producto no puede ser null");
+        return new Merma(MermaId.generate(), localId, fecha, categoria, motivo, responsable,
+            producto.getId(), producto.getNombre(), unidades, renglones);
+    }
+
+    /**
+     * Valor de todo lo perdido.
+     */
+    public BigDecimal getCostoTotal() {
+        return renglones.stream()
+            .map(RenglonMerma::getCostoValorizado)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    public boolean esDeProducto() {
+        return productoId != null;
+    }
+
+    private static String validarTexto(String valor, String campo, int maximo) {
+        if (valor == null || valor.isBlank()) {
+            throw new IllegalArgumentException(campo + " es obligatorio");
+        }
+        String limpio = valor.trim();
+        if (limpio.length() > maximo) {
+            throw new IllegalArgumentException(campo + " no puede superar los " + maximo + " caracteres");
+        }
+        return limpio;
+    }
+
+    private List<RenglonMerma> validarRenglones(List<RenglonMerma> renglones) {
+        if (renglones == null || renglones.isEmpty()) {
+            throw new IllegalArgumentException("La merma debe tener al menos un insumo");
+        }
+        Set<InsumoId> vistos = new HashSet<>();
+        for (RenglonMerma renglon : renglones) {
+            Objects.requireNonNull(renglon, "Los renglones de la merma no pueden ser null");
+            if (renglon.getCantidad().signum() <= 0) {
+                throw new IllegalArgumentException("La cantidad perdida de " + renglon.getNombreInsumo()
+                    + " debe ser mayor a cero");
+            }
+            if (!vistos.add(renglon.getInsumoId())) {
+                throw new IllegalArgumentException(renglon.getNombreInsumo() + " está cargado dos veces");
+            }
+        }
+        return List.copyOf(renglones);
+    }
+
+    public MermaId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public CategoriaMerma getCategoria() {
+        return categoria;
+    }
+
+    public String getMotivo() {
+        return motivo;
+    }
+
+    public String getResponsable() {
+        return responsable;
+    }
+
+    public ProductoId getProductoId() {
+        return productoId;
+    }
+
+    public String getNombreProducto() {
+        return nombreProducto;
+    }
+
+    public Integer getUnidadesProducto() {
+        return unidadesProducto;
+    }
+
+    public List<RenglonMerma> getRenglones() {
+        return renglones;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        Merma merma = (Merma) o;
+        return Objects.equals(id, merma.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
@@ -6,16 +6,33 @@ import java.util.Objects;
 
 public class Mesa {
 
+    /** HU-112: Sector de las mesas creadas sin ubicación explícita */
+    public static final String SECTOR_POR_DEFECTO = "Salón";
+    private static final int LONGITUD_MAXIMA_SECTOR = 30;
+    /** HU-112: Tamaño máximo del mapa de cada sector (celdas por lado) */
+    public static final int MAXIMO_CELDAS_MAPA = 50;
+    /** HU-149: Cantidad máxima de personas que se puede cargar como capacidad */
+    public static final int CAPACIDAD_MAXIMA = 50;
+
     private final MesaId id;
     private final LocalId localId;
-    private final int numero;
+    private int numero;
     private EstadoMesa estado;
 
+    // HU-112: Ubicación en el mapa del salón
+    private String sector;
+    private Integer posicionX;
+    private Integer posicionY;
+
+    // HU-149: Cuántas personas entran (null = sin definir)
+    private Integer capacidad;
+
     public Mesa(MesaId id, LocalId localId, int numero) {
         this.id = Objects.requireNonNull(id, "El id de la mesa es obligatorio");
         this.localId = Objects.requireNonNull(localId, "El localId es obligatorio");
         this.numero = validarNumero(numero);
         this.estado = EstadoMesa.LIBRE;
+        this.sector = SECTOR_POR_DEFECTO;
     }
 
     // --- Comportamiento de Negocio ---
@@ -72,6 +89,75 @@ public class Mesa {
         liberar();
     }
 
+    /**
+     * HU-112: Cambia el número visible de la mesa.
+     * La unicidad dentro del local la valida el caso de uso, que ve todas las mesas.
+     */
+    public void renumerar(int numero) {
+        this.numero = validarNumero(numero);
+    }
+
+    /**
+     * HU-112: Ubica la mesa en el mapa del salón.
+     *
+     * La posición es una celda (columna, fila) dentro del sector. Ambas
+     * coordenadas van juntas: una mesa sin posición se acomoda sola en el
+     * primer hueco libre del sector al dibujar el mapa.
+     *
+     * @param sector nombre del sector (salón, vereda, barra...); vacío = sector por defecto
+     * @param posicionX columna (0..49) o null
+     * @param posicionY fila (0..49) o null
+     */
+    public void ubicar(String sector, Integer posicionX, Integer posicionY) {
+        if ((posicionX == null) != (posicionY == null)) {
+            throw new IllegalArgumentException("La posición de la mesa " + numero + " debe indicar columna y fila");
+        }
+        if (posicionX != null && (fueraDelMapa(posicionX) || fueraDelMapa(posicionY))) {
+            throw new IllegalArgumentException(
+                "La posición de la mesa " + numero + " está fuera del mapa (0 a " + (MAXIMO_CELDAS_MAPA - 1) + ")");
+        }
+        this.sector = validarSector(sector);
+        this.posicionX = posicionX;
+        this.posicionY = posicionY;
+    }
+
+    /**
+     * HU-149: Define cuántas personas entran en la mesa.
+     * La lista de espera la usa para sugerir una mesa adecuada al liberarse.
+     *
+     * @param capacidad personas (1..50) o null para dejarla sin definir
+     */
+    public void definirCapacidad(Integer capacidad) {
+        if (capacidad != null && (capacidad <= 0 || capacidad > CAPACIDAD_MAXIMA)) {
+            throw new IllegalArgumentException(
+                "La capacidad de la mesa " + numero + " debe estar entre 1 y " + CAPACIDAD_MAXIMA + " personas");
+        }
+        this.capacidad = capacidad;
+    }
+
+    /**
+     * HU-149: true si la mesa tiene capacidad definida y entran esas personas.
+     */
+    public boolean alcanzaPara(int personas) {
+        return capacidad != null && capacidad >= personas;
+    }
+
+    private boolean fueraDelMapa(int coordenada) {
+        return coordenada < 0 || coordenada >= MAXIMO_CELDAS_MAPA;
+    }
+
+    private String validarSector(String sector) {
+        if (sector == null || sector.isBlank()) {
+            return SECTOR_POR_DEFECTO;
+        }
+        String limpio = sector.trim();
+        if (limpio.length() > LONGITUD_MAXIMA_SECTOR) {
+            throw new IllegalArgumentException(
+                "El nombre del sector no puede superar los " + LONGITUD_MAXIMA_SECTOR + " caracteres");
+        }
+        return limpio;
+    }
+
     private int validarNumero(int numero) {
         if (numero <= 0) {
             throw new IllegalArgumentException("El número de mesa debe ser positivo");
@@ -97,6 +183,25 @@ public class Mesa {
         return estado;
     }
 
+    public String getSector() {
+        return sector;
+    }
+
+    public Integer getPosicionX() {
+        return posicionX;
+    }
+
+    public Integer getPosicionY() {
+        return posicionY;
+    }
+
+    /**
+     * @return personas que entran en la mesa, o null si no se definió
+     */
+    public Integer getCapacidad() {
+        return capacidad;
+    }
+
     // --- Identity ---
 
     @Override
@@ -1,8 +1,13 @@
 package com.agustinpalma.comandas.domain.model;
 
 import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimiento;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaEgresoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
 
 import java.math.BigDecimal;
 import java.time.LocalDateTime;
@@ -21,6 +26,12 @@ import java.util.Objects;
  * - El monto siempre es positivo (representa el valor absoluto del movimiento)
  * - Al crearse genera automáticamente un número de comprobante único
  * - El comprobante tiene formato "EGR-yyyyMMdd-HHmmss-XXXX" o "ING-yyyyMMdd-HHmmss-XXXX"
+ * - HU-106: queda asociado al turno de caja abierto al registrarse (null si no había)
+ * - HU-121: una devolución es un EGRESO asociado al pedido cerrado que se reintegra
+ *   y al encargado que la autorizó; no cuenta como gasto sino como menor venta
+ * - HU-130: un egreso puede llevar una categoría (limpieza, mercadería...);
+ *   los ingresos y las devoluciones no se categorizan
+ * - HU-159: queda asociado a la caja que lo registró (null = caja principal)
  * - Es inmutable después de la creación
  */
 public class MovimientoCaja {
@@ -34,6 +45,11 @@ public class MovimientoCaja {
     private final LocalDateTime fecha;
     private final TipoMovimiento tipo;
     private final String numeroComprobante;
+    private final TurnoCajaId turnoId;
+    private final PedidoId pedidoId;
+    private final MozoId autorizadoPorId;
+    private final CategoriaEgresoId categoriaId;
+    private final CajaId cajaId;
 
     /**
      * Crea un nuevo movimiento de caja tipo EGRESO con generación automática de comprobante.
@@ -65,6 +81,40 @@ public class MovimientoCaja {
      */
     public MovimientoCaja(MovimientoCajaId id, LocalId localId, BigDecimal monto, 
                           String descripcion, LocalDateTime fecha, TipoMovimiento tipo) {
+        this(id, localId, monto, descripcion, fecha, tipo, (TurnoCajaId) null);
+    }
+
+    /**
+     * HU-106: Crea un movimiento asociado al turno de caja activo.
+     *
+     * @param turnoId turno abierto al registrar el movimiento (nullable si no se usan turnos)
+     */
+    public MovimientoCaja(MovimientoCajaId id, LocalId localId, BigDecimal monto,
+                          String descripcion, LocalDateTime fecha, TipoMovimiento tipo,
+                          TurnoCajaId turnoId) {
+        this(id, localId, monto, descripcion, fecha, tipo, turnoId, null);
+    }
+
+    /**
+     * HU-130: Crea un movimiento con la categoría de egreso elegida.
+     *
+     * @param categoriaId categoría del egreso (nullable: sin categoría)
+     * @throws IllegalArgumentException si se categoriza un ingreso
+     */
+    public MovimientoCaja(MovimientoCajaId id, LocalId localId, BigDecimal monto,
+                          String descripcion, LocalDateTime fecha, TipoMovimiento tipo,
+                          TurnoCajaId turnoId, CategoriaEgresoId categoriaId) {
+        this(id, localId, monto, descripcion, fecha, tipo, turnoId, categoriaId, (CajaId) null);
+    }
+
+    /**
+     * HU-159: Crea un movimiento registrado en una caja del local.
+     *
+     * @param cajaId caja que registró el movimiento (nullable: caja principal)
+     */
+    public MovimientoCaja(MovimientoCajaId id, LocalId localId, BigDecimal monto,
+                          String descripcion, LocalDateTime fecha, TipoMovimiento tipo,
+                          TurnoCajaId turnoId, CategoriaEgresoId categoriaId, CajaId cajaId) {
         this.id = Objects.requireNonNull(id, "El id del movimiento no puede ser null");
         this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
         this.monto = validarMonto(Objects.requireNonNull(monto, "El monto no puede ser null"));
@@ -72,6 +122,44 @@ public class MovimientoCaja {
         this.fecha = Objects.requireNonNull(fecha, "La fecha no puede ser null");
         this.tipo = Objects.requireNonNull(tipo, "El tipo de movimiento no puede ser null");
         this.numeroComprobante = generarNumeroComprobante(id, fecha, tipo);
+        this.turnoId = turnoId;
+        this.pedidoId = null;
+        this.autorizadoPorId = null;
+        if (categoriaId != null && tipo != TipoMovimiento.EGRESO) {
+            throw new IllegalArgumentException("Solo los egresos se clasifican por categoría");
+        }
+        this.categoriaId = categoriaId;
+        this.cajaId = cajaId;
+    }
+
+    private MovimientoCaja(MovimientoCajaId id, LocalId localId, BigDecimal monto, String descripcion,
+                           LocalDateTime fecha, TurnoCajaId turnoId, PedidoId pedidoId, MozoId autorizadoPorId) {
+        this.id = Objects.requireNonNull(id, "El id del movimiento no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.monto = validarMonto(Objects.requireNonNull(monto, "El monto no puede ser null"));
+        this.descripcion = validarDescripcion(descripcion);
+        this.fecha = Objects.requireNonNull(fecha, "La fecha no puede ser null");
+        this.tipo = TipoMovimiento.EGRESO;
+        this.numeroComprobante = generarNumeroComprobante(id, fecha, TipoMovimiento.EGRESO);
+        this.turnoId = turnoId;
+        this.pedidoId = Objects.requireNonNull(pedidoId, "El pedido de la devolución es obligatorio");
+        this.autorizadoPorId = Objects.requireNonNull(autorizadoPorId, "El encargado que autoriza es obligatorio");
+        this.categoriaId = null;
+        this.cajaId = null;
+    }
+
+    /**
+     * HU-121: Egreso por devolución de un pedido ya cerrado.
+     *
+     * @param descripcion motivo de la devolución
+     * @param turnoId turno abierto al registrar la devolución (nullable)
+     * @param autorizadoPorId encargado que autorizó con su PIN
+     */
+    public static MovimientoCaja devolucion(LocalId localId, PedidoId pedidoId, BigDecimal monto,
+                                            String descripcion, LocalDateTime fecha,
+                                            TurnoCajaId turnoId, MozoId autorizadoPorId) {
+        return new MovimientoCaja(MovimientoCajaId.generate(), localId, monto, descripcion, fecha,
+            turnoId, pedidoId, autorizadoPorId);
     }
 
     /**
@@ -80,7 +168,39 @@ public class MovimientoCaja {
      */
     public MovimientoCaja(MovimientoCajaId id, LocalId localId, BigDecimal monto,
                           String descripcion, LocalDateTime fecha, TipoMovimiento tipo,
-                          String numeroComprobante) {
+                          String numeroComprobante, TurnoCajaId turnoId) {
+        this(id, localId, monto, descripcion, fecha, tipo, numeroComprobante, turnoId, null, null);
+    }
+
+    /**
+     * HU-121: Reconstrucción desde persistencia incluyendo el pedido y el encargado de una devolución.
+     */
+    public MovimientoCaja(MovimientoCajaId id, LocalId localId, BigDecimal monto,
+                          String descripcion, LocalDateTime fecha, TipoMovimiento tipo,
+                          String numeroComprobante, TurnoCajaId turnoId,
+                          PedidoId pedidoId, MozoId autorizadoPorId) {
+        this(id, localId, monto, descripcion, fecha, tipo, numeroComprobante, turnoId, pedidoId, autorizadoPorId, null);
+    }
+
+    /**
+     * HU-130: Reconstrucción desde persistencia incluyendo la categoría del egreso.
+     */
+    public MovimientoCaja(MovimientoCajaId id, LocalId localId, BigDecimal monto,
+                          String descripcion, LocalDateTime fecha, TipoMovimiento tipo,
+                          String numeroComprobante, TurnoCajaId turnoId,
+                          PedidoId pedidoId, MozoId autorizadoPorId, CategoriaEgresoId categoriaId) {
+        this(id, localId, monto, descripcion, fecha, tipo, numeroComprobante, turnoId, pedidoId, autorizadoPorId,
+            categoriaId, null);
+    }
+
+    /**
+     * HU-159: Reconstrucción desde persistencia incluyendo la caja que lo registró.
+     */
+    public MovimientoCaja(MovimientoCajaId id, LocalId localId, BigDecimal monto,
+                          String descripcion, LocalDateTime fecha, TipoMovimiento tipo,
+                          String numeroComprobante, TurnoCajaId turnoId,
+                          PedidoId pedidoId, MozoId autorizadoPorId, CategoriaEgresoId categoriaId,
+                          CajaId cajaId) {
         this.id = Objects.requireNonNull(id, "El id del movimiento no puede ser null");
         this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
         this.monto = Objects.requireNonNull(monto, "El monto no puede ser null");
@@ -88,6 +208,11 @@ public class MovimientoCaja {
         this.fecha = Objects.requireNonNull(fecha, "La fecha no puede ser null");
         this.tipo = Objects.requireNonNull(tipo, "El tipo no puede ser null");
         this.numeroComprobante = Objects.requireNonNull(numeroComprobante, "El número de comprobante no puede ser null");
+        this.turnoId = turnoId;
+        this.pedidoId = pedidoId;
+        this.autorizadoPorId = autorizadoPorId;
+        this.categoriaId = categoriaId;
+        this.cajaId = cajaId;
     }
 
     private BigDecimal validarMonto(BigDecimal monto) {
@@ -139,6 +264,13 @@ public class MovimientoCaja {
         return tipo == TipoMovimiento.EGRESO;
     }
 
+    /**
+     * HU-121: Indica si este egreso es el reintegro de un pedido cerrado.
+     */
+    public boolean esDevolucion() {
+        return esEgreso() && pedidoId != null;
+    }
+
     // ============================================
     // Getters (inmutable, sin setters)
     // ============================================
@@ -171,6 +303,26 @@ public class MovimientoCaja {
         return numeroComprobante;
     }
 
+    public TurnoCajaId getTurnoId() {
+        return turnoId;
+    }
+
+    public PedidoId getPedidoId() {
+        return pedidoId;
+    }
+
+    public MozoId getAutorizadoPorId() {
+        return autorizadoPorId;
+    }
+
+    public CategoriaEgresoId getCategoriaId() {
+        return categoriaId;
+    }
+
+    public CajaId getCajaId() {
+        return cajaId;
+    }
+
     @Override
     public boolean equals(Object o) {
         if (this == o) return true;
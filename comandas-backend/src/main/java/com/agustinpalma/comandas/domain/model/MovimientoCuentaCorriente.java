@@ -0,0 +1,195 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoCuenta;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCuentaCorrienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.Collection;
+import java.util.Objects;
+
+/**
+ * Entidad que representa un movimiento en la cuenta corriente de un cliente.
+ *
+ * HU-104: Cuenta corriente de clientes.
+ *
+ * Reglas de negocio:
+ * - El monto siempre es positivo; el tipo define si suma o resta deuda
+ * - CARGO: referencia obligatoria al pedido cerrado a cuenta corriente
+ * - PAGO: medio de pago obligatorio (no puede ser A_CUENTA, CUENTA_CORRIENTE
+ *   ni TARJETA_REGALO)
+ * - La descripción es un snapshot legible para el resumen mensual
+ *   (ej: "Pedido #42 - Mesa 5")
+ * - Es inmutable: una corrección reemplaza el cargo del pedido, no lo edita
+ */
+public class MovimientoCuentaCorriente {
+
+    private final MovimientoCuentaCorrienteId id;
+    private final ClienteId clienteId;
+    private final LocalId localId;
+    private final TipoMovimientoCuenta tipo;
+    private final BigDecimal monto;
+    private final LocalDateTime fecha;
+    private final PedidoId pedidoId;       // Solo CARGO
+    private final MedioPago medioPago;     // Solo PAGO
+    private final String descripcion;
+
+    public MovimientoCuentaCorriente(
+            MovimientoCuentaCorrienteId id,
+            ClienteId clienteId,
+            LocalId localId,
+            TipoMovimientoCuenta tipo,
+            BigDecimal monto,
+            LocalDateTime fecha,
+            PedidoId pedidoId,
+            MedioPago medioPago,
+            String descripcion
+    ) {
+        this.id = Objects.requireNonNull(id, "El id del movimiento no puede ser null");
+        this.clienteId = Objects.requireNonNull(clienteId, "El clienteId no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.tipo = Objects.requireNonNull(tipo, "El tipo de movimiento no puede ser null");
+        this.monto = validarMonto(monto);
+        this.fecha = Objects.requireNonNull(fecha, "La fecha no puede ser null");
+        this.descripcion = validarDescripcion(descripcion);
+
+        if (tipo == TipoMovimientoCuenta.CARGO) {
+            this.pedidoId = Objects.requireNonNull(pedidoId, "Un cargo debe referenciar al pedido que lo originó");
+            this.medioPago = null;
+        } else {
+            this.pedidoId = null;
+            this.medioPago = validarMedioPago(medioPago);
+        }
+    }
+
+    /**
+     * Cargo por un pedido cerrado total o parcialmente a cuenta corriente.
+     */
+    public static MovimientoCuentaCorriente cargo(
+            ClienteId clienteId, LocalId localId, PedidoId pedidoId,
+            BigDecimal monto, LocalDateTime fecha, String descripcion) {
+        return new MovimientoCuentaCorriente(
+            MovimientoCuentaCorrienteId.generate(), clienteId, localId,
+            TipoMovimientoCuenta.CARGO, monto, fecha, pedidoId, null, descripcion
+        );
+    }
+
+    /**
+     * Pago (total o parcial) de la deuda por parte del cliente.
+     */
+    public static MovimientoCuentaCorriente pago(
+            ClienteId clienteId, LocalId localId, MedioPago medioPago,
+            BigDecimal monto, LocalDateTime fecha, String descripcion) {
+        return new MovimientoCuentaCorriente(
+            MovimientoCuentaCorrienteId.generate(), clienteId, localId,
+            TipoMovimientoCuenta.PAGO, monto, fecha, null, medioPago, descripcion
+        );
+    }
+
+    /**
+     * Calcula la deuda resultante de una serie de movimientos:
+     * Σ cargos - Σ pagos.
+     */
+    public static BigDecimal calcularSaldo(Collection<MovimientoCuentaCorriente> movimientos) {
+        return movimientos.stream()
+            .map(MovimientoCuentaCorriente::importeConSigno)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    private BigDecimal validarMonto(BigDecimal monto) {
+        Objects.requireNonNull(monto, "El monto no puede ser null");
+        if (monto.compareTo(BigDecimal.ZERO) <= 0) {
+            throw new IllegalArgumentException(
+                String.format("El monto del movimiento debe ser mayor a cero. Recibido: %s", monto)
+            );
+        }
+        return monto;
+    }
+
+    private String validarDescripcion(String descripcion) {
+        if (descripcion == null || descripcion.isBlank()) {
+            throw new IllegalArgumentException("La descripción del movimiento no puede estar vacía");
+        }
+        return descripcion.trim();
+    }
+
+    private MedioPago validarMedioPago(MedioPago medioPago) {
+        Objects.requireNonNull(medioPago, "Un pago debe indicar el medio con el que se abonó");
+        if (medioPago == MedioPago.CUENTA_CORRIENTE || medioPago == MedioPago.A_CUENTA
+                || medioPago == MedioPago.TARJETA_REGALO) {
+            throw new IllegalArgumentException(
+                String.format("La deuda no puede saldarse con el medio %s", medioPago)
+            );
+        }
+        return medioPago;
+    }
+
+    /**
+     * Importe con signo respecto de la deuda: positivo para cargos, negativo para pagos.
+     */
+    public BigDecimal importeConSigno() {
+        return esCargo() ? monto : monto.negate();
+    }
+
+    public boolean esCargo() {
+        return tipo == TipoMovimientoCuenta.CARGO;
+    }
+
+    public MovimientoCuentaCorrienteId getId() {
+        return id;
+    }
+
+    public ClienteId getClienteId() {
+        return clienteId;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public TipoMovimientoCuenta getTipo() {
+        return tipo;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public PedidoId getPedidoId() {
+        return pedidoId;
+    }
+
+    public MedioPago getMedioPago() {
+        return medioPago;
+    }
+
+    public String getDescripcion() {
+        return descripcion;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        MovimientoCuentaCorriente that = (MovimientoCuentaCorriente) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+
+    @Override
+    public String toString() {
+        return String.format("MovimientoCuentaCorriente{tipo=%s, monto=%s, fecha=%s}", tipo, monto, fecha);
+    }
+}
@@ -0,0 +1,90 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoInsumo;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoInsumoId;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Movimiento de stock de un insumo (auditoría).
+ *
+ * HU-133: Inventario físico con reporte de diferencias.
+ *
+ * Reglas de negocio:
+ * - La cantidad va en la unidad del insumo: positiva si entra, negativa si sale.
+ * - El motivo es obligatorio y explica el origen (venta, reapertura, toma de inventario).
+ * - Los movimientos son inmutables: una corrección es otro movimiento.
+ */
+public class MovimientoInsumo {
+
+    private final MovimientoInsumoId id;
+    private final InsumoId insumoId;
+    private final LocalId localId;
+    private final BigDecimal cantidad;
+    private final TipoMovimientoInsumo tipo;
+    private final LocalDateTime fecha;
+    private final String motivo;
+
+    public MovimientoInsumo(MovimientoInsumoId id, InsumoId insumoId, LocalId localId, BigDecimal cantidad,
+                            TipoMovimientoInsumo tipo, LocalDateTime fecha, String motivo) {
+        this.id = Objects.requireNonNull(id, "El id del movimiento no puede ser null");
+        this.insumoId = Objects.requireNonNull(insumoId, "El insumoId no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        Objects.requireNonNull(cantidad, "La cantidad del movimiento no puede ser null");
+        if (cantidad.signum() == 0) {
+            throw new IllegalArgumentException("La cantidad del movimiento no puede ser cero");
+        }
+        this.cantidad = cantidad;
+        this.tipo = Objects.requireNonNull(tipo, "El tipo de movimiento no puede ser null");
+        this.fecha = Objects.requireNonNull(fecha, "La fecha no puede ser null");
+        if (motivo == null || motivo.isBlank()) {
+            throw new IllegalArgumentException("El motivo del movimiento no puede estar vacío");
+        }
+        this.motivo = motivo.trim();
+    }
+
+    public MovimientoInsumoId getId() {
+        return id;
+    }
+
+    public InsumoId getInsumoId() {
+        return insumoId;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public BigDecimal getCantidad() {
+        return cantidad;
+    }
+
+    public TipoMovimientoInsumo getTipo() {
+        return tipo;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public String getMotivo() {
+        return motivo;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        MovimientoInsumo that = (MovimientoInsumo) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
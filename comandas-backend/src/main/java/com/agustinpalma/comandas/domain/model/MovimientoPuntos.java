@@ -0,0 +1,169 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoPuntos;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoPuntosId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Entidad que representa un movimiento en el saldo de puntos de un cliente.
+ *
+ * HU-105: Programa de puntos de clientes.
+ *
+ * Reglas de negocio:
+ * - La cantidad de puntos siempre es positiva; el tipo define si suma o resta
+ * - Todo movimiento referencia al pedido que lo originó
+ * - ACUMULACION: puede tener fecha de vencimiento (null = no vence)
+ * - CANJE: guarda el monto descontado en el pedido (snapshot para el reporte)
+ * - Es inmutable: reabrir o corregir el pedido reemplaza sus movimientos
+ *
+ * El saldo no se guarda: se deriva consumiendo las acumulaciones en orden
+ * FIFO (ver ProgramaPuntosService).
+ */
+public class MovimientoPuntos {
+
+    private final MovimientoPuntosId id;
+    private final ClienteId clienteId;
+    private final LocalId localId;
+    private final TipoMovimientoPuntos tipo;
+    private final int puntos;
+    private final LocalDateTime fecha;
+    private final PedidoId pedidoId;
+    private final LocalDateTime fechaVencimiento;   // Solo ACUMULACION (nullable)
+    private final BigDecimal montoDescuento;        // Solo CANJE
+
+    public MovimientoPuntos(
+            MovimientoPuntosId id,
+            ClienteId clienteId,
+            LocalId localId,
+            TipoMovimientoPuntos tipo,
+            int puntos,
+            LocalDateTime fecha,
+            PedidoId pedidoId,
+            LocalDateTime fechaVencimiento,
+            BigDecimal montoDescuento
+    ) {
+        this.id = Objects.requireNonNull(id, "El id del movimiento no puede ser null");
+        this.clienteId = Objects.requireNonNull(clienteId, "El clienteId no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.tipo = Objects.requireNonNull(tipo, "El tipo de movimiento no puede ser null");
+        this.puntos = validarPuntos(puntos);
+        this.fecha = Objects.requireNonNull(fecha, "La fecha no puede ser null");
+        this.pedidoId = Objects.requireNonNull(pedidoId, "Un movimiento de puntos debe referenciar al pedido que lo originó");
+
+        if (tipo == TipoMovimientoPuntos.ACUMULACION) {
+            if (fechaVencimiento != null && !fechaVencimiento.isAfter(fecha)) {
+                throw new IllegalArgumentException("El vencimiento de los puntos debe ser posterior a la fecha en que se ganan");
+            }
+            this.fechaVencimiento = fechaVencimiento;
+            this.montoDescuento = null;
+        } else {
+            this.fechaVencimiento = null;
+            this.montoDescuento = validarMontoDescuento(montoDescuento);
+        }
+    }
+
+    /**
+     * Puntos ganados al cerrar un pedido.
+     */
+    public static MovimientoPuntos acumulacion(
+            ClienteId clienteId, LocalId localId, PedidoId pedidoId,
+            int puntos, LocalDateTime fecha, LocalDateTime fechaVencimiento) {
+        return new MovimientoPuntos(
+            MovimientoPuntosId.generate(), clienteId, localId,
+            TipoMovimientoPuntos.ACUMULACION, puntos, fecha, pedidoId, fechaVencimiento, null
+        );
+    }
+
+    /**
+     * Puntos usados como descuento en un pedido.
+     */
+    public static MovimientoPuntos canje(
+            ClienteId clienteId, LocalId localId, PedidoId pedidoId,
+            int puntos, LocalDateTime fecha, BigDecimal montoDescuento) {
+        return new MovimientoPuntos(
+            MovimientoPuntosId.generate(), clienteId, localId,
+            TipoMovimientoPuntos.CANJE, puntos, fecha, pedidoId, null, montoDescuento
+        );
+    }
+
+    private int validarPuntos(int puntos) {
+        if (puntos <= 0) {
+            throw new IllegalArgumentException(
+                String.format("La cantidad de puntos debe ser mayor a cero. Recibido: %d", puntos)
+            );
+        }
+        return puntos;
+    }
+
+    private BigDecimal validarMontoDescuento(BigDecimal montoDescuento) {
+        Objects.requireNonNull(montoDescuento, "Un canje debe indicar el monto descontado");
+        if (montoDescuento.signum() < 0) {
+            throw new IllegalArgumentException("El monto descontado por un canje no puede ser negativo");
+        }
+        return montoDescuento;
+    }
+
+    public boolean esAcumulacion() {
+        return tipo == TipoMovimientoPuntos.ACUMULACION;
+    }
+
+    public MovimientoPuntosId getId() {
+        return id;
+    }
+
+    public ClienteId getClienteId() {
+        return clienteId;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public TipoMovimientoPuntos getTipo() {
+        return tipo;
+    }
+
+    public int getPuntos() {
+        return puntos;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public PedidoId getPedidoId() {
+        return pedidoId;
+    }
+
+    public LocalDateTime getFechaVencimiento() {
+        return fechaVencimiento;
+    }
+
+    public BigDecimal getMontoDescuento() {
+        return montoDescuento;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        MovimientoPuntos that = (MovimientoPuntos) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+
+    @Override
+    public String toString() {
+        return String.format("MovimientoPuntos{tipo=%s, puntos=%d, fecha=%s}", tipo, puntos, fecha);
+    }
+}
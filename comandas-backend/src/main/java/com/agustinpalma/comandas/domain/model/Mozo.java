@@ -0,0 +1,316 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+
+import java.math.BigDecimal;
+import java.nio.charset.StandardCharsets;
+import java.security.MessageDigest;
+import java.security.NoSuchAlgorithmException;
+import java.util.HexFormat;
+import java.util.Objects;
+
+/**
+ * Mozo del salón, al que se le asignan las mesas que atiende.
+ *
+ * HU-111: Reporte de desempeño por mozo.
+ * HU-121: El personal tiene un rol; el encargado autoriza operaciones
+ * sensibles con un PIN numérico.
+ * HU-124: Cualquier empleado ficha con su PIN o con una tarjeta asignada;
+ * el dueño autoriza las correcciones de fichaje.
+ * HU-132: Cada empleado puede tener un tope mensual de consumo interno
+ * (A_CUENTA); lo que lo excede se descuenta del sueldo.
+ * HU-140: Los repartidores son personal con rol REPARTIDOR; solo a ellos
+ * se les asignan pedidos de delivery.
+ *
+ * Reglas de negocio:
+ * - El nombre es obligatorio (máximo 60 caracteres).
+ * - Un mozo no se elimina: se desactiva, para que sus pedidos históricos
+ *   sigan apareciendo con su nombre en los reportes.
+ * - Solo los mozos activos pueden tomar mesas nuevas.
+ * - El PIN tiene entre 4 y 6 dígitos y se guarda como hash (salado con el id),
+ *   nunca en claro.
+ * - Solo autoriza un encargado (o el dueño) activo con PIN configurado.
+ * - La tarjeta es el código que tipea el lector (4 a 32 letras o números).
+ * - El tope de consumo no puede ser negativo; sin tope, todo el consumo es beneficio.
+ */
+public class Mozo {
+
+    private static final int LONGITUD_MAXIMA_NOMBRE = 60;
+    private static final String FORMATO_PIN = "\\d{4,6}";
+    private static final String FORMATO_TARJETA = "[A-Za-z0-9]{4,32}";
+
+    private final MozoId id;
+    private final LocalId localId;
+    private String nombre;
+    private boolean activo;
+    private RolPersonal rol;
+    private String pinHash;
+    private String codigoTarjeta;
+    private BigDecimal topeConsumoMensual;
+
+    public Mozo(MozoId id, LocalId localId, String nombre, boolean activo) {
+        this(id, localId, nombre, activo, RolPersonal.MOZO, null);
+    }
+
+    public Mozo(MozoId id, LocalId localId, String nombre, boolean activo, RolPersonal rol, String pinHash) {
+        this(id, localId, nombre, activo, rol, pinHash, null);
+    }
+
+    /**
+     * HU-121: Constructor completo (reconstrucción desde persistencia).
+     *
+     * @param pinHash hash del PIN ya calculado (null si no tiene)
+     * @param codigoTarjeta HU-124: código de la tarjeta de fichaje (null si no tiene)
+     */
+    public Mozo(MozoId id, LocalId localId, String nombre, boolean activo, RolPersonal rol, String pinHash,
+                String codigoTarjeta) {
+        this(id, localId, nombre, activo, rol, pinHash, codigoTarjeta, null);
+    }
+
+    /**
+     * HU-132: Constructor completo con el tope mensual de consumo interno.
+     *
+     * @param topeConsumoMensual null si el empleado no tiene tope
+     */
+    public Mozo(MozoId id, LocalId localId, String nombre, boolean activo, RolPersonal rol, String pinHash,
+                String codigoTarjeta, BigDecimal topeConsumoMensual) {
+        this.id = Objects.requireNonNull(id, "El id del mozo no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.nombre = validarNombre(nombre);
+        this.activo = activo;
+        this.rol = Objects.requireNonNull(rol, "El rol no puede ser null");
+        this.pinHash = pinHash;
+        this.codigoTarjeta = codigoTarjeta;
+        this.topeConsumoMensual = validarTope(topeConsumoMensual);
+    }
+
+    /**
+     * Actualiza nombre y estado de una sola vez (edición desde la pantalla de mozos).
+     */
+    public void actualizar(String nombre, boolean activo) {
+        this.nombre = validarNombre(nombre);
+        this.activo = activo;
+    }
+
+    public void cambiarRol(RolPersonal rol) {
+        this.rol = Objects.requireNonNull(rol, "El rol es obligatorio");
+    }
+
+    /**
+     * HU-121: Define o quita (null o vacío) el PIN de autorización.
+     *
+     * @throws IllegalArgumentException si no tiene entre 4 y 6 dígitos
+     */
+    public void cambiarPin(String pin) {
+        if (pin == null || pin.isBlank()) {
+            this.pinHash = null;
+            return;
+        }
+        if (!pin.matches(FORMATO_PIN)) {
+            throw new IllegalArgumentException("El PIN debe tener entre 4 y 6 dígitos");
+        }
+        this.pinHash = hashear(pin);
+    }
+
+    public boolean tienePin() {
+        return pinHash != null;
+    }
+
+    /**
+     * HU-124: Asigna o quita (null o vacío) la tarjeta de fichaje.
+     *
+     * @throws IllegalArgumentException si el código no tiene entre 4 y 32 letras o números
+     */
+    public void asignarTarjeta(String codigo) {
+        if (codigo == null || codigo.isBlank()) {
+            this.codigoTarjeta = null;
+            return;
+        }
+        String limpio = codigo.trim();
+        if (!limpio.matches(FORMATO_TARJETA)) {
+            throw new IllegalArgumentException("El código de tarjeta debe tener entre 4 y 32 letras o números");
+        }
+        this.codigoTarjeta = limpio;
+    }
+
+    public boolean tieneTarjeta() {
+        return codigoTarjeta != null;
+    }
+
+    /**
+     * HU-132: Define o quita (null) el tope mensual de consumo interno.
+     *
+     * @throws IllegalArgumentException si el tope es negativo
+     */
+    public void definirTopeConsumo(BigDecimal tope) {
+        this.topeConsumoMensual = validarTope(tope);
+    }
+
+    public boolean tieneTopeConsumo() {
+        return topeConsumoMensual != null;
+    }
+
+    /**
+     * HU-132: Parte del consumo del mes que supera el tope y se descuenta del sueldo.
+     *
+     * @return cero si no supera el tope o si el empleado no tiene tope
+     */
+    public BigDecimal excedenteConsumo(BigDecimal consumoDelMes) {
+        Objects.requireNonNull(consumoDelMes, "El consumo del mes no puede ser null");
+        if (topeConsumoMensual == null || consumoDelMes.compareTo(topeConsumoMensual) <= 0) {
+            return BigDecimal.ZERO;
+        }
+        return consumoDelMes.subtract(topeConsumoMensual);
+    }
+
+    /**
+     * HU-121: Verifica que este mozo pueda autorizar una operación sensible con el PIN dado.
+     * HU-124: El dueño también autoriza todo lo que autoriza un encargado.
+     *
+     * @throws IllegalStateException si no es un encargado activo con PIN configurado
+     * @throws IllegalArgumentException si el PIN no coincide
+     */
+    public void validarAutorizacion(String pin) {
+        if (!activo || (rol != RolPersonal.ENCARGADO && rol != RolPersonal.DUENO)) {
+            throw new IllegalStateException(nombre + " no es un encargado activo y no puede autorizar la operación");
+        }
+        if (!tienePin()) {
+            throw new IllegalStateException("El encargado " + nombre + " no tiene PIN configurado");
+        }
+        if (!pinCoincide(pin)) {
+            throw new IllegalArgumentException("PIN de encargado incorrecto");
+        }
+    }
+
+    /**
+     * HU-140: Verifica que pueda llevar pedidos de delivery.
+     *
+     * @throws IllegalStateException si no es un repartidor activo
+     */
+    public void validarRepartidor() {
+        if (rol != RolPersonal.REPARTIDOR) {
+            throw new IllegalStateException(nombre + " no es repartidor");
+        }
+        if (!activo) {
+            throw new IllegalStateException("El repartidor " + nombre + " está inactivo");
+        }
+    }
+
+    /**
+     * HU-124: Verifica que sea el dueño quien autoriza (correcciones de fichaje).
+     *
+     * @throws IllegalStateException si no es el dueño activo con PIN configurado
+     * @throws IllegalArgumentException si el PIN no coincide
+     */
+    public void validarAutorizacionDueno(String pin) {
+        if (!activo || rol != RolPersonal.DUENO) {
+            throw new IllegalStateException("Solo el dueño puede autorizar la corrección de fichajes");
+        }
+        if (!tienePin()) {
+            throw new IllegalStateException("El dueño " + nombre + " no tiene PIN configurado");
+        }
+        if (!pinCoincide(pin)) {
+            throw new IllegalArgumentException("PIN del dueño incorrecto");
+        }
+    }
+
+    /**
+     * HU-124: Identifica al empleado por su PIN para fichar (cualquier rol).
+     *
+     * @throws IllegalStateException si está inactivo o no tiene PIN configurado
+     * @throws IllegalArgumentException si el PIN no coincide
+     */
+    public void validarPinFichaje(String pin) {
+        if (!activo) {
+            throw new IllegalStateException(nombre + " está inactivo y no puede fichar");
+        }
+        if (!tienePin()) {
+            throw new IllegalStateException(nombre + " no tiene PIN configurado para fichar");
+        }
+        if (!pinCoincide(pin)) {
+            throw new IllegalArgumentException("PIN incorrecto");
+        }
+    }
+
+    private boolean pinCoincide(String pin) {
+        return pin != null && MessageDigest.isEqual(
+            pinHash.getBytes(StandardCharsets.UTF_8), hashear(pin).getBytes(StandardCharsets.UTF_8));
+    }
+
+    private String hashear(String pin) {
+        try {
+            MessageDigest digest = MessageDigest.getInstance("SHA-256");
+            byte[] hash = digest.digest((id.getValue() + ":" + pin).getBytes(StandardCharsets.UTF_8));
+            return HexFormat.of().formatHex(hash);
+        } catch (NoSuchAlgorithmException e) {
+            throw new IllegalStateException("SHA-256 no disponible", e);
+        }
+    }
+
+    private BigDecimal validarTope(BigDecimal tope) {
+        if (tope != null && tope.signum() < 0) {
+            throw new IllegalArgumentException("El tope de consumo no puede ser negativo");
+        }
+        return tope;
+    }
+
+    private String validarNombre(String nombre) {
+        if (nombre == null || nombre.isBlank()) {
+            throw new IllegalArgumentException("El nombre del mozo no puede estar vacío");
+        }
+        String limpio = nombre.trim();
+        if (limpio.length() > LONGITUD_MAXIMA_NOMBRE) {
+            throw new IllegalArgumentException(
+                "El nombre del mozo no puede superar los " + LONGITUD_MAXIMA_NOMBRE + " caracteres"
+            );
+        }
+        return limpio;
+    }
+
+    public MozoId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public boolean isActivo() {
+        return activo;
+    }
+
+    public RolPersonal getRol() {
+        return rol;
+    }
+
+    public String getPinHash() {
+        return pinHash;
+    }
+
+    public String getCodigoTarjeta() {
+        return codigoTarjeta;
+    }
+
+    public BigDecimal getTopeConsumoMensual() {
+        return topeConsumoMensual;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        Mozo that = (Mozo) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
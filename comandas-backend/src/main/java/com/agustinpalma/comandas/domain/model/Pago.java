@@ -1,10 +1,15 @@
 package com.agustinpalma.comandas.domain.model;
 
 import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Moneda;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PagoId;
 
 import java.math.BigDecimal;
+import java.math.RoundingMode;
 import java.time.LocalDateTime;
 import java.util.Objects;
+import java.util.Set;
 
 /**
  * Value Object que representa un pago asociado a un pedido.
@@ -12,13 +17,49 @@ import java.util.Objects;
  * Un pedido puede tener múltiples pagos (pagos parciales/split).
  * La suma de todos los pagos debe coincidir exactamente con el total del pedido.
  * 
+ * HU-143: El recargo del medio de pago se cobra aparte del monto: no cuenta
+ * para cubrir el total y queda congelado en el pago al cerrar.
+ * 
+ * HU-151: Un pago en USD o EUR guarda su moneda, el monto en esa moneda y el
+ * tipo de cambio aplicado. El monto sigue siendo el equivalente en pesos: es
+ * lo que cubre el total del pedido. Solo se aceptan monedas extranjeras en
+ * efectivo, tarjeta y transferencia.
+ * 
+ * HU-152: Un pago en efectivo en pesos puede llevar el ajuste por redondeo del
+ * local (positivo o negativo). Como el recargo, no cubre el total del pedido:
+ * es un renglón aparte que explica la diferencia con lo que entró al cajón.
+ * 
+ * HU-156: Un pago con tarjeta, transferencia o QR puede llevar la referencia
+ * del comprobante (número de cupón u operación) para conciliar después con
+ * el banco o la pasarela. El efectivo no lleva referencia.
+ * 
+ * HU-159: Cada pago recuerda la caja que lo cobró (null: la caja principal).
+ * Un pago a cuenta puede entrar por una caja y la mesa cerrarse en otra: el
+ * arqueo suma cada pago en su caja.
+ * 
+ * HU-160: Cada pago tiene su id, que se conserva al convertirlo o redondearlo:
+ * los pagos a cuenta ya guardados no se vuelven a crear al sumar otro.
+ * La igualdad sigue siendo por valor.
+ * 
  * Inmutable y validado en construcción (fail fast).
  */
 public final class Pago {
 
+    private static final Set<MedioPago> ADMITEN_MONEDA_EXTRANJERA = Set.of(
+        MedioPago.EFECTIVO, MedioPago.TARJETA, MedioPago.TRANSFERENCIA);
+    private static final int LARGO_MAXIMO_REFERENCIA = 64;
+
+    private final PagoId id;
     private final MedioPago medio;
     private final BigDecimal monto;
     private final LocalDateTime fecha;
+    private final BigDecimal recargo;
+    private final Moneda moneda;
+    private final BigDecimal montoOriginal;
+    private final BigDecimal tipoCambio;
+    private final BigDecimal ajusteRedondeo;
+    private final String referencia;
+    private final CajaId cajaId;
 
     /**
      * Constructor con validación estricta.
@@ -30,9 +71,105 @@ public final class Pago {
      * @throws IllegalArgumentException si el monto es <= 0
      */
     public Pago(MedioPago medio, BigDecimal monto, LocalDateTime fecha) {
+        this(medio, monto, fecha, BigDecimal.ZERO);
+    }
+
+    /**
+     * HU-143: Pago con el recargo de su medio ya calculado.
+     *
+     * @param recargo recargo cobrado encima del monto (cero si el medio no tiene)
+     * @throws IllegalArgumentException si el recargo es negativo
+     */
+    public Pago(MedioPago medio, BigDecimal monto, LocalDateTime fecha, BigDecimal recargo) {
+        this(medio, monto, fecha, recargo, Moneda.ARS, monto, BigDecimal.ONE);
+    }
+
+    /**
+     * HU-151: Pago entregado en otra moneda (o reconstrucción desde persistencia).
+     *
+     * @param monto         equivalente en pesos, lo que cubre del total
+     * @param moneda        moneda en la que pagó el cliente
+     * @param montoOriginal monto en esa moneda
+     * @param tipoCambio    pesos por unidad de la moneda (1 para pesos)
+     * @throws IllegalArgumentException si el medio no admite moneda extranjera,
+     *                                  el monto original o el tipo de cambio no son positivos
+     */
+    public Pago(MedioPago medio, BigDecimal monto, LocalDateTime fecha, BigDecimal recargo,
+                Moneda moneda, BigDecimal montoOriginal, BigDecimal tipoCambio) {
+        this(medio, monto, fecha, recargo, moneda, montoOriginal, tipoCambio, BigDecimal.ZERO);
+    }
+
+    /**
+     * HU-152: Pago completo, con el ajuste por redondeo del efectivo (o reconstrucción desde persistencia).
+     *
+     * @param ajusteRedondeo lo que se sumó (o restó, si es negativo) al efectivo cobrado para redondearlo
+     * @throws IllegalArgumentException si el ajuste no va en un pago en efectivo en pesos
+     *                                  o deja lo cobrado en cero o menos
+     */
+    public Pago(MedioPago medio, BigDecimal monto, LocalDateTime fecha, BigDecimal recargo,
+                Moneda moneda, BigDecimal montoOriginal, BigDecimal tipoCambio, BigDecimal ajusteRedondeo) {
+        this(medio, monto, fecha, recargo, moneda, montoOriginal, tipoCambio, ajusteRedondeo, null);
+    }
+
+    /**
+     * HU-156: Pago completo, con la referencia del comprobante (o reconstrucción desde persistencia).
+     *
+     * @param referencia número de cupón u operación; null o en blanco si no hay
+     * @throws IllegalArgumentException si la referencia va en un pago en efectivo o es demasiado larga
+     */
+    public Pago(MedioPago medio, BigDecimal monto, LocalDateTime fecha, BigDecimal recargo,
+                Moneda moneda, BigDecimal montoOriginal, BigDecimal tipoCambio, BigDecimal ajusteRedondeo,
+                String referencia) {
+        this(medio, monto, fecha, recargo, moneda, montoOriginal, tipoCambio, ajusteRedondeo, referencia, null);
+    }
+
+    /**
+     * HU-159: Pago completo, con la caja que lo cobró.
+     *
+     * @param cajaId caja que cobró el pago; null para la caja principal
+     */
+    public Pago(MedioPago medio, BigDecimal monto, LocalDateTime fecha, BigDecimal recargo,
+                Moneda moneda, BigDecimal montoOriginal, BigDecimal tipoCambio, BigDecimal ajusteRedondeo,
+                String referencia, CajaId cajaId) {
+        this(PagoId.generate(), medio, monto, fecha, recargo, moneda, montoOriginal, tipoCambio, ajusteRedondeo,
+            referencia, cajaId);
+    }
+
+    /**
+     * HU-160: Reconstrucción desde persistencia, con el id del pago.
+     */
+    public Pago(PagoId id, MedioPago medio, BigDecimal monto, LocalDateTime fecha, BigDecimal recargo,
+                Moneda moneda, BigDecimal montoOriginal, BigDecimal tipoCambio, BigDecimal ajusteRedondeo,
+                String referencia, CajaId cajaId) {
+        this.id = Objects.requireNonNull(id, "El id del pago no puede ser null");
         this.medio = Objects.requireNonNull(medio, "El medio de pago no puede ser null");
         this.monto = validarMonto(Objects.requireNonNull(monto, "El monto no puede ser null"));
         this.fecha = Objects.requireNonNull(fecha, "La fecha del pago no puede ser null");
+        Objects.requireNonNull(recargo, "El recargo no puede ser null");
+        if (recargo.signum() < 0) {
+            throw new IllegalArgumentException("El recargo del pago no puede ser negativo");
+        }
+        this.recargo = recargo;
+        this.moneda = Objects.requireNonNull(moneda, "La moneda del pago no puede ser null");
+        this.montoOriginal = Objects.requireNonNull(montoOriginal, "El monto original no puede ser null");
+        this.tipoCambio = Objects.requireNonNull(tipoCambio, "El tipo de cambio no puede ser null");
+        if (moneda != Moneda.ARS && !ADMITEN_MONEDA_EXTRANJERA.contains(medio)) {
+            throw new IllegalArgumentException("El medio " + medio + " solo acepta pesos");
+        }
+        if (montoOriginal.signum() <= 0 || tipoCambio.signum() <= 0) {
+            throw new IllegalArgumentException("El monto original y el tipo de cambio del pago deben ser mayores a cero");
+        }
+        this.ajusteRedondeo = Objects.requireNonNull(ajusteRedondeo, "El ajuste por redondeo no puede ser null");
+        if (ajusteRedondeo.signum() != 0) {
+            if (medio != MedioPago.EFECTIVO || moneda != Moneda.ARS) {
+                throw new IllegalArgumentException("Solo el efectivo en pesos lleva ajuste por redondeo");
+            }
+            if (monto.add(recargo).add(ajusteRedondeo).signum() <= 0) {
+                throw new IllegalArgumentException("El ajuste por redondeo no puede dejar el pago en cero");
+            }
+        }
+        this.referencia = validarReferencia(referencia);
+        this.cajaId = cajaId;
     }
 
     private BigDecimal validarMonto(BigDecimal monto) {
@@ -44,6 +181,25 @@ public final class Pago {
         return monto;
     }
 
+    private String validarReferencia(String referencia) {
+        if (referencia == null || referencia.isBlank()) {
+            return null;
+        }
+        String limpia = referencia.strip();
+        if (medio == MedioPago.EFECTIVO) {
+            throw new IllegalArgumentException("El efectivo no lleva número de comprobante");
+        }
+        if (limpia.length() > LARGO_MAXIMO_REFERENCIA) {
+            throw new IllegalArgumentException(
+                "El número de comprobante admite hasta " + LARGO_MAXIMO_REFERENCIA + " caracteres");
+        }
+        return limpia;
+    }
+
+    public PagoId getId() {
+        return id;
+    }
+
     public MedioPago getMedio() {
         return medio;
     }
@@ -56,13 +212,136 @@ public final class Pago {
         return fecha;
     }
 
+    /**
+     * HU-143: Recargo del medio de pago, fuera del total del pedido.
+     */
+    public BigDecimal getRecargo() {
+        return recargo;
+    }
+
+    /**
+     * HU-143: Lo que efectivamente paga el cliente con este medio.
+     * HU-152: con el ajuste por redondeo del efectivo incluido.
+     */
+    public BigDecimal getMontoCobrado() {
+        return monto.add(recargo).add(ajusteRedondeo);
+    }
+
+    /**
+     * HU-152: Ajuste por redondeo del efectivo; negativo si se redondeó a favor del cliente.
+     */
+    public BigDecimal getAjusteRedondeo() {
+        return ajusteRedondeo;
+    }
+
+    public boolean tieneAjusteRedondeo() {
+        return ajusteRedondeo.signum() != 0;
+    }
+
+    /**
+     * HU-152: El mismo pago con el ajuste por redondeo indicado.
+     */
+    public Pago conAjusteRedondeo(BigDecimal ajuste) {
+        return new Pago(id, medio, monto, fecha, recargo, moneda, montoOriginal, tipoCambio, ajuste, referencia, cajaId);
+    }
+
+    /**
+     * HU-156: Número de cupón u operación del comprobante; null si no se cargó.
+     */
+    public String getReferencia() {
+        return referencia;
+    }
+
+    /**
+     * HU-156: El mismo pago con la referencia del comprobante indicada.
+     */
+    public Pago conReferencia(String referencia) {
+        return new Pago(id, medio, monto, fecha, recargo, moneda, montoOriginal, tipoCambio, ajusteRedondeo,
+            referencia, cajaId);
+    }
+
+    /**
+     * HU-159: Caja que cobró el pago; null si fue la caja principal.
+     */
+    public CajaId getCajaId() {
+        return cajaId;
+    }
+
+    /**
+     * HU-159: El mismo pago cobrado por la caja indicada (null: la caja principal).
+     */
+    public Pago conCaja(CajaId cajaId) {
+        return new Pago(id, medio, monto, fecha, recargo, moneda, montoOriginal, tipoCambio, ajusteRedondeo,
+            referencia, cajaId);
+    }
+
+    public boolean tieneRecargo() {
+        return recargo.signum() > 0;
+    }
+
+    /**
+     * HU-151: El mismo pago entregado en otra moneda. El monto en pesos no
+     * cambia; el monto original sale de dividirlo por el tipo de cambio, al centavo.
+     */
+    public Pago enMoneda(Moneda otra, BigDecimal cotizacion) {
+        Objects.requireNonNull(otra, "La moneda es obligatoria");
+        Objects.requireNonNull(cotizacion, "El tipo de cambio es obligatorio");
+        if (otra == Moneda.ARS) {
+            return new Pago(id, medio, monto, fecha, recargo, Moneda.ARS, monto, BigDecimal.ONE,
+                BigDecimal.ZERO, referencia, cajaId);
+        }
+        if (cotizacion.signum() <= 0) {
+            throw new IllegalArgumentException("El tipo de cambio debe ser mayor a cero");
+        }
+        return new Pago(id, medio, monto, fecha, recargo, otra,
+            monto.divide(cotizacion, 2, RoundingMode.HALF_UP), cotizacion, BigDecimal.ZERO, referencia, cajaId);
+    }
+
+    public Moneda getMoneda() {
+        return moneda;
+    }
+
+    /**
+     * HU-151: Monto en la moneda en que pagó el cliente (igual a monto si son pesos).
+     */
+    public BigDecimal getMontoOriginal() {
+        return montoOriginal;
+    }
+
+    /**
+     * HU-151: Pesos por unidad de la moneda del pago, congelado al cobrar.
+     */
+    public BigDecimal getTipoCambio() {
+        return tipoCambio;
+    }
+
+    /**
+     * HU-151: Lo que entregó el cliente en su moneda, recargo incluido.
+     */
+    public BigDecimal getMontoCobradoOriginal() {
+        if (moneda == Moneda.ARS) {
+            return getMontoCobrado();
+        }
+        return montoOriginal.add(recargo.divide(tipoCambio, 2, RoundingMode.HALF_UP));
+    }
+
+    public boolean esMonedaExtranjera() {
+        return moneda != Moneda.ARS;
+    }
+
     @Override
     public boolean equals(Object o) {
         if (this == o) return true;
         if (o == null || getClass() != o.getClass()) return false;
         Pago pago = (Pago) o;
         return medio == pago.medio &&
+               moneda == pago.moneda &&
                monto.compareTo(pago.monto) == 0 &&
+               recargo.compareTo(pago.recargo) == 0 &&
+               montoOriginal.compareTo(pago.montoOriginal) == 0 &&
+               ajusteRedondeo.compareTo(pago.ajusteRedondeo) == 0 &&
+               Objects.equals(referencia, pago.referencia) &&
+               Objects.equals(cajaId, pago.cajaId) &&
                Objects.equals(fecha, pago.fecha);
     }
 
@@ -73,6 +352,14 @@ public final class Pago {
 
     @Override
     public String toString() {
-        return String.format("Pago{medio=%s, monto=%s, fecha=%s}", medio, monto, fecha);
+        if (esMonedaExtranjera()) {
+            return String.format("Pago{medio=%s, monto=%s, recargo=%s, moneda=%s, montoOriginal=%s, tipoCambio=%s, fecha=%s}",
+                medio, monto, recargo, moneda, montoOriginal, tipoCambio, fecha);
+        }
+        if (tieneAjusteRedondeo()) {
+            return String.format("Pago{medio=%s, monto=%s, recargo=%s, ajusteRedondeo=%s, fecha=%s}",
+                medio, monto, recargo, ajusteRedondeo, fecha);
+        }
+        return String.format("Pago{medio=%s, monto=%s, recargo=%s, fecha=%s}", medio, monto, recargo, fecha);
     }
 }
@@ -0,0 +1,65 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.Objects;
+import java.util.Set;
+
+/**
+ * Pago (total o parcial) asentado contra una factura de proveedor.
+ *
+ * HU-131: Se paga con los mismos medios que un gasto. Si el efectivo salió
+ * de la caja, el pago queda asociado al egreso que lo registró.
+ */
+public final class PagoProveedor {
+
+    private static final Set<MedioPago> MEDIOS_PERMITIDOS = Set.of(
+        MedioPago.EFECTIVO, MedioPago.TARJETA, MedioPago.TRANSFERENCIA, MedioPago.QR
+    );
+
+    private final LocalDateTime fecha;
+    private final BigDecimal monto;
+    private final MedioPago medioPago;
+    private final MovimientoCajaId movimientoCajaId;
+
+    public PagoProveedor(LocalDateTime fecha, BigDecimal monto, MedioPago medioPago, MovimientoCajaId movimientoCajaId) {
+        this.fecha = Objects.requireNonNull(fecha, "La fecha del pago no puede ser null");
+        Objects.requireNonNull(monto, "El monto del pago no puede ser null");
+        if (monto.compareTo(BigDecimal.ZERO) <= 0) {
+            throw new IllegalArgumentException("El monto del pago debe ser mayor a cero");
+        }
+        this.monto = monto;
+        Objects.requireNonNull(medioPago, "El medio de pago no puede ser null");
+        if (!MEDIOS_PERMITIDOS.contains(medioPago)) {
+            throw new IllegalArgumentException("Medio de pago no válido para pagarle a un proveedor: " + medioPago);
+        }
+        if (movimientoCajaId != null && medioPago != MedioPago.EFECTIVO) {
+            throw new IllegalArgumentException("Solo un pago en efectivo puede salir de la caja");
+        }
+        this.medioPago = medioPago;
+        this.movimientoCajaId = movimientoCajaId;
+    }
+
+    public boolean fuePagadoConCaja() {
+        return movimientoCajaId != null;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+
+    public MedioPago getMedioPago() {
+        return medioPago;
+    }
+
+    public MovimientoCajaId getMovimientoCajaId() {
+        return movimientoCajaId;
+    }
+}
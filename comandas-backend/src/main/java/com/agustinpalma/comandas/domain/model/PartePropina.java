@@ -0,0 +1,67 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+
+import java.math.BigDecimal;
+import java.util.Objects;
+
+/**
+ * Lo que le toca a un empleado del pozo de propinas de un turno.
+ *
+ * HU-141: Se guarda el peso con el que entró al reparto para que el
+ * comprobante muestre cómo se llegó al monto aunque después cambie la regla.
+ */
+public final class PartePropina {
+
+    private final MozoId mozoId;
+    private final long minutos;
+    private final long peso;
+    private final BigDecimal monto;
+
+    public PartePropina(MozoId mozoId, long minutos, long peso, BigDecimal monto) {
+        this.mozoId = Objects.requireNonNull(mozoId, "El empleado es obligatorio");
+        if (minutos < 0 || peso < 0) {
+            throw new IllegalArgumentException("Los minutos y el peso de la parte no pueden ser negativos");
+        }
+        Objects.requireNonNull(monto, "El monto de la parte es obligatorio");
+        if (monto.signum() < 0) {
+            throw new IllegalArgumentException("El monto de la parte no puede ser negativo");
+        }
+        this.minutos = minutos;
+        this.peso = peso;
+        this.monto = monto;
+    }
+
+    public MozoId getMozoId() {
+        return mozoId;
+    }
+
+    /**
+     * @return minutos fichados dentro del turno
+     */
+    public long getMinutos() {
+        return minutos;
+    }
+
+    public long getPeso() {
+        return peso;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        PartePropina that = (PartePropina) o;
+        return minutos == that.minutos && peso == that.peso
+            && mozoId.equals(that.mozoId) && monto.compareTo(that.monto) == 0;
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(mozoId, minutos, peso, monto.stripTrailingZeros());
+    }
+}
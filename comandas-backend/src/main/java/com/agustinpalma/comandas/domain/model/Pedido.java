@@ -1,15 +1,23 @@
 package com.agustinpalma.comandas.domain.model;
 
+import com.agustinpalma.comandas.domain.exception.PedidoModificadoException;
 import com.agustinpalma.comandas.domain.model.DomainIds.*;
 import com.agustinpalma.comandas.domain.model.DomainEnums.*;
 import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.Duration;
 import java.time.LocalDateTime;
 import java.util.ArrayList;
 import java.util.Collections;
+import java.util.Comparator;
+import java.util.EnumSet;
 import java.util.List;
 import java.util.Map;
 import java.util.Objects;
 import java.util.Optional;
+import java.util.Set;
+import java.util.UUID;
+import java.util.function.Consumer;
 
 /**
  * Aggregate Root del contexto de Pedidos.
@@ -20,6 +28,18 @@ import java.util.Optional;
  */
 public class Pedido {
 
+    // HU-160: Medios que se resuelven recién al cerrar (necesitan al cliente,
+    // al empleado o la tarjeta de regalo del cierre): no se aceptan a cuenta
+    private static final Set<MedioPago> MEDIOS_SOLO_AL_CIERRE =
+        EnumSet.of(MedioPago.A_CUENTA, MedioPago.CUENTA_CORRIENTE, MedioPago.TARJETA_REGALO);
+
+    /**
+     * HU-165: Control de versión de los cambios sin versión esperada (sin terminal de
+     * por medio). No verifica nada: el guardado igual rechaza un cambio
+     * cruzado, ver {@code PedidoRepository#guardar}.
+     */
+    public static final Consumer<Pedido> SIN_CONTROL_DE_VERSION = pedido -> { };
+
     private final PedidoId id;
     private final LocalId localId;
     private final MesaId mesaId;
@@ -34,11 +54,73 @@ public class Pedido {
     private DescuentoManual descuentoGlobal;  // null si no tiene descuento global
 
     // Pagos parciales/split del pedido
+    // HU-160: con el pedido abierto, son los pagos a cuenta ya cobrados
     private final List<Pago> pagos;
 
     // HU-29: Timestamp del último envío a cocina (para cálculo de ítems "nuevos")
     private LocalDateTime ultimoEnvioCocina;
 
+    // HU-105: Canje de puntos del cliente aplicado como descuento sobre el total
+    private int puntosCanjeados;
+    private BigDecimal montoCanjePuntos = BigDecimal.ZERO;
+
+    // HU-106: Turno de caja en el que se cobró el pedido (null si se cerró sin turno abierto)
+    private TurnoCajaId turnoId;
+
+    // HU-159: Caja donde se cobró (null = caja principal de la jornada)
+    private CajaId cajaId;
+
+    // HU-108: Canal de venta y lista de precios congelada al crear el pedido
+    // (listaPreciosId null = precio de catálogo)
+    private CanalVenta canal = CanalVenta.SALON;
+    private ListaPreciosId listaPreciosId;
+    private String nombreListaPrecios;
+
+    // HU-110: Paso de servicio liberado a cocina (null = todavía no se fijó)
+    private TiempoServicio tiempoEnMarcha;
+
+    // HU-111: Atención de la mesa (mozo null = sin asignar, cubiertos 0 = no informados)
+    private MozoId mozoId;
+    private int cubiertos;
+    private BigDecimal propina = BigDecimal.ZERO;
+
+    // HU-111: Unidades ya enviadas a cocina que se quitaron del pedido
+    private int unidadesAnuladas;
+    private BigDecimal montoAnulado = BigDecimal.ZERO;
+
+    // HU-113: Último momento en que se atendió la mesa (apertura, ítems nuevos,
+    // envío a cocina o pedido de cuenta)
+    private LocalDateTime ultimaActividad;
+
+    // HU-137: Pedido marcado urgente (todas sus comandas salen con prioridad)
+    private boolean urgente;
+
+    // HU-138: Horario de retiro informado al cliente (solo TAKE_AWAY)
+    private LocalDateTime horaPrometida;
+
+    // HU-139: Entrega de delivery (el costo de envío va como ítem)
+    private String direccionEntrega;
+    private ZonaDeliveryId zonaDeliveryId;
+
+    // HU-140: Repartidor a cargo y horarios de salida y regreso del reparto
+    private MozoId repartidorId;
+    private LocalDateTime salidaReparto;
+    private LocalDateTime regresoReparto;
+
+    // HU-142: Reserva que se sentó en la mesa y seña cobrada de antemano, que se descuenta del total
+    private ReservaId reservaId;
+    private BigDecimal montoSenia = BigDecimal.ZERO;
+
+    // HU-165: Versión persistida, para detectar ediciones simultáneas desde dos terminales
+    private long version;
+
+    // HU-166: Número de comprobante de la serie de la caja que lo cobró (null si no hay serie)
+    private String numeroComprobante;
+
+    // HU-168: Cliente de la ficha vinculado al pedido y descuento de su promo de cumpleaños
+    private ClienteId clienteId;
+    private BigDecimal montoPromoCumpleanios = BigDecimal.ZERO;
+
     // Snapshot contable: se congela al cerrar para inmutabilidad financiera
     private BigDecimal montoSubtotalFinal;
     private BigDecimal montoDescuentosFinal;
@@ -51,6 +133,7 @@ public class Pedido {
         this.numero = validarNumero(numero);
         this.estado = Objects.requireNonNull(estado, "El estado del pedido no puede ser null");
         this.fechaApertura = Objects.requireNonNull(fechaApertura, "La fecha de apertura no puede ser null");
+        this.ultimaActividad = fechaApertura;
         this.items = new ArrayList<>();
         this.pagos = new ArrayList<>();
     }
@@ -73,11 +156,39 @@ public class Pedido {
      * @param fechaApertura fecha de apertura
      * @param fechaCierre fecha de cierre (null si está abierto)
      * @param items ítems del pedido (ya reconstruidos)
-     * @param pagos pagos registrados (vacío si está abierto)
+     * @param pagos pagos registrados (HU-160: los pagos a cuenta si está abierto)
      * @param descuentoGlobal descuento global aplicado (null si no tiene)
      * @param montoSubtotalFinal snapshot contable (null si está abierto)
      * @param montoDescuentosFinal snapshot contable (null si está abierto)
      * @param montoTotalFinal snapshot contable (null si está abierto)
+     * @param ultimoEnvioCocina último envío a cocina (null si nunca se envió)
+     * @param puntosCanjeados puntos canjeados en el pedido (0 si no hubo canje)
+     * @param montoCanjePuntos descuento por el canje de puntos (null o cero si no hubo canje)
+     * @param turnoId turno de caja en el que se cobró (null si no se usaron turnos)
+     * @param canal canal de venta (null = SALON, pedidos anteriores a HU-108)
+     * @param listaPreciosId lista de precios usada (null = precio de catálogo)
+     * @param nombreListaPrecios nombre de la lista al momento de crear el pedido
+     * @param tiempoEnMarcha paso de servicio liberado a cocina (null si no se fijó)
+     * @param mozoId mozo que atiende la mesa (null si no se asignó)
+     * @param cubiertos cantidad de comensales (0 si no se informó)
+     * @param propina propina registrada al cobrar (null o cero si no hubo)
+     * @param unidadesAnuladas unidades quitadas después de enviarse a cocina
+     * @param montoAnulado importe de las unidades anuladas (null o cero si no hubo)
+     * @param ultimaActividad última atención de la mesa (null = fecha de apertura)
+     * @param urgente si el pedido está marcado urgente para cocina
+     * @param horaPrometida horario de retiro informado al cliente (null si no se prometió)
+     * @param direccionEntrega dirección de entrega del delivery (null si no se cargó)
+     * @param zonaDeliveryId zona de reparto de la entrega (null si no se cargó)
+     * @param repartidorId repartidor asignado (null si no se asignó)
+     * @param salidaReparto salida del repartidor con el pedido (null si no salió)
+     * @param regresoReparto regreso del repartidor (null si no volvió)
+     * @param reservaId reserva vinculada al pedido (null si la mesa no era reservada)
+     * @param montoSenia seña de la reserva descontada del total (null o cero si no hubo)
+     * @param cajaId caja donde se cobró (null = caja principal)
+     * @param version versión persistida (sube en cada guardado)
+     * @param numeroComprobante número asignado al cerrar (null si no tiene)
+     * @param clienteId cliente vinculado (null si no se identificó)
+     * @param montoPromoCumpleanios descuento de la promo de cumpleaños (null o cero si no hubo)
      * @return Pedido completamente reconstruido
      */
     public static Pedido reconstruirDesdePersistencia(
@@ -86,7 +197,23 @@ public class Pedido {
             List<ItemPedido> items, List<Pago> pagos,
             DescuentoManual descuentoGlobal,
             BigDecimal montoSubtotalFinal, BigDecimal montoDescuentosFinal, BigDecimal montoTotalFinal,
-            LocalDateTime ultimoEnvioCocina
+            LocalDateTime ultimoEnvioCocina,
+            int puntosCanjeados, BigDecimal montoCanjePuntos,
+            TurnoCajaId turnoId,
+            CanalVenta canal, ListaPreciosId listaPreciosId, String nombreListaPrecios,
+            TiempoServicio tiempoEnMarcha,
+            MozoId mozoId, int cubiertos, BigDecimal propina,
+            int unidadesAnuladas, BigDecimal montoAnulado,
+            LocalDateTime ultimaActividad,
+            boolean urgente,
+            LocalDateTime horaPrometida,
+            String direccionEntrega, ZonaDeliveryId zonaDeliveryId,
+            MozoId repartidorId, LocalDateTime salidaReparto, LocalDateTime regresoReparto,
+            ReservaId reservaId, BigDecimal montoSenia,
+            CajaId cajaId,
+            long version,
+            String numeroComprobante,
+            ClienteId clienteId, BigDecimal montoPromoCumpleanios
     ) {
         Pedido pedido = new Pedido(id, localId, mesaId, numero, estado, fechaApertura);
         pedido.fechaCierre = fechaCierre;
@@ -102,6 +229,35 @@ public class Pedido {
         pedido.montoDescuentosFinal = montoDescuentosFinal;
         pedido.montoTotalFinal = montoTotalFinal;
         pedido.ultimoEnvioCocina = ultimoEnvioCocina;
+        pedido.puntosCanjeados = puntosCanjeados;
+        pedido.montoCanjePuntos = montoCanjePuntos != null ? montoCanjePuntos : BigDecimal.ZERO;
+        pedido.turnoId = turnoId;
+        pedido.canal = canal != null ? canal : CanalVenta.SALON;
+        pedido.listaPreciosId = listaPreciosId;
+        pedido.nombreListaPrecios = nombreListaPrecios;
+        pedido.tiempoEnMarcha = tiempoEnMarcha;
+        pedido.mozoId = mozoId;
+        pedido.cubiertos = cubiertos;
+        pedido.propina = propina != null ? propina : BigDecimal.ZERO;
+        pedido.unidadesAnuladas = unidadesAnuladas;
+        pedido.montoAnulado = montoAnulado != null ? montoAnulado : BigDecimal.ZERO;
+        if (ultimaActividad != null) {
+            pedido.ultimaActividad = ultimaActividad;
+        }
+        pedido.urgente = urgente;
+        pedido.horaPrometida = horaPrometida;
+        pedido.direccionEntrega = direccionEntrega;
+        pedido.zonaDeliveryId = zonaDeliveryId;
+        pedido.repartidorId = repartidorId;
+        pedido.salidaReparto = salidaReparto;
+        pedido.regresoReparto = regresoReparto;
+        pedido.reservaId = reservaId;
+        pedido.montoSenia = montoSenia != null ? montoSenia : BigDecimal.ZERO;
+        pedido.cajaId = cajaId;
+        pedido.version = version;
+        pedido.numeroComprobante = numeroComprobante;
+        pedido.clienteId = clienteId;
+        pedido.montoPromoCumpleanios = montoPromoCumpleanios != null ? montoPromoCumpleanios : BigDecimal.ZERO;
         
         return pedido;
     }
@@ -170,16 +326,25 @@ public class Pedido {
      * Después de esta operación, ningún ítem tiene cantidad nueva
      * hasta que se agreguen más unidades o productos.
      *
+     * HU-110: Los ítems retenidos (pasos posteriores al que está en marcha)
+     * no se marcan. El primer envío con pasos asignados fija el paso en marcha.
+     *
      * @param ahora el momento del envío
      * @throws IllegalStateException si el pedido no está ABIERTO
      */
     public void marcarComoEnviadoACocina(LocalDateTime ahora) {
         Objects.requireNonNull(ahora, "La fecha de envío a cocina no puede ser null");
         validarPermiteModificacion();
+        if (this.tiempoEnMarcha == null && items.stream().anyMatch(item -> item.getTiempoServicio() != null)) {
+            this.tiempoEnMarcha = obtenerTiempoEnMarcha();
+        }
         this.ultimoEnvioCocina = ahora;
+        registrarActividad(ahora);
         // Marcar cada ítem con su cantidad actual como "ya enviada"
         for (ItemPedido item : items) {
-            item.marcarCantidadEnviada();
+            if (!estaRetenido(item)) {
+                item.marcarCantidadEnviada();
+            }
         }
     }
 
@@ -194,12 +359,16 @@ public class Pedido {
      * - Incrementos de cantidad vía '+' (cantidadEnviadaCocina < cantidad)
      * - Merge de producto idéntico (hereda cantidadEnviadaCocina del original)
      *
+     * HU-110: Un ítem retenido no es "nuevo": sale recién cuando se marcha su paso.
+     *
+     * HU-139: El costo de envío nunca es "nuevo": no se cocina.
+     *
      * @param item el ítem a evaluar
-     * @return true si el ítem tiene cantidad sin enviar
+     * @return true si el ítem tiene cantidad sin enviar y no está retenido
      */
     public boolean esItemNuevo(ItemPedido item) {
         Objects.requireNonNull(item, "El item no puede ser null");
-        return item.tieneCantidadNueva();
+        return !item.isEnvio() && item.tieneCantidadNueva() && !estaRetenido(item);
     }
 
     /**
@@ -213,6 +382,144 @@ public class Pedido {
             .toList();
     }
 
+    // ============================================
+    // HU-110: Secuencia de pasos a cocina
+    // ============================================
+
+    /**
+     * Paso en marcha tal como está persistido.
+     * Null si el pedido todavía no envió ítems con paso asignado.
+     */
+    public TiempoServicio getTiempoEnMarcha() {
+        return tiempoEnMarcha;
+    }
+
+    /**
+     * HU-110: Paso de servicio que cocina puede preparar ahora.
+     *
+     * Mientras no se fijó (antes del primer envío con pasos), es el paso más
+     * temprano entre los ítems cargados: una mesa que solo pidió principales
+     * los manda directo, sin tener que marchar nada.
+     *
+     * @return el paso en marcha (ENTRADA si ningún ítem tiene paso)
+     */
+    public TiempoServicio obtenerTiempoEnMarcha() {
+        if (tiempoEnMarcha != null) {
+            return tiempoEnMarcha;
+        }
+        return items.stream()
+            .map(ItemPedido::getTiempoServicio)
+            .filter(Objects::nonNull)
+            .min(Comparator.naturalOrder())
+            .orElse(TiempoServicio.ENTRADA);
+    }
+
+    /**
+     * HU-110: Indica si el ítem espera a que se marche su paso.
+     *
+     * Los ítems sin paso nunca se retienen: salen con la comanda siguiente.
+     *
+     * @param item el ítem a evaluar
+     * @return true si el paso del ítem es posterior al paso en marcha
+     */
+    public boolean estaRetenido(ItemPedido item) {
+        Objects.requireNonNull(item, "El item no puede ser null");
+        return item.getTiempoServicio() != null
+            && item.getTiempoServicio().compareTo(obtenerTiempoEnMarcha()) > 0;
+    }
+
+    /**
+     * HU-110: Próximo paso con ítems retenidos pendientes de envío.
+     *
+     * @return el paso a marchar, o vacío si no queda nada retenido
+     */
+    public Optional<TiempoServicio> siguienteTiempoPendiente() {
+        return items.stream()
+            .filter(item -> item.tieneCantidadNueva() && estaRetenido(item))
+            .map(ItemPedido::getTiempoServicio)
+            .min(Comparator.naturalOrder());
+    }
+
+    /**
+     * HU-110: Marcha un paso ("marchar postres").
+     *
+     * Libera los ítems retenidos hasta ese paso inclusive; después de esto
+     * forman parte de obtenerItemsNuevos() y salen con la próxima comanda.
+     * Se puede saltear un paso: marchar POSTRE libera también los principales
+     * que siguieran retenidos.
+     *
+     * @param tiempo el paso a marchar
+     * @throws IllegalStateException si el pedido no está ABIERTO, si el paso ya
+     *         está en marcha o si no hay ítems retenidos hasta ese paso
+     */
+    public void marcharTiempo(TiempoServicio tiempo) {
+        Objects.requireNonNull(tiempo, "El tiempo de servicio no puede ser null");
+        validarPermiteModificacion();
+
+        if (tiempo.compareTo(obtenerTiempoEnMarcha()) <= 0) {
+            throw new IllegalStateException(
+                String.format("El tiempo %s ya está en marcha", tiempo)
+            );
+        }
+
+        boolean hayRetenidos = items.stream()
+            .filter(item -> item.tieneCantidadNueva() && estaRetenido(item))
+            .anyMatch(item -> item.getTiempoServicio().compareTo(tiempo) <= 0);
+        if (!hayRetenidos) {
+            throw new IllegalStateException(
+                String.format("No hay ítems pendientes para marchar %s", tiempo)
+            );
+        }
+
+        this.tiempoEnMarcha = tiempo;
+    }
+
+    /**
+     * HU-110: Cambia el paso de servicio de un ítem.
+     *
+     * Solo se permite mientras cocina no recibió ninguna unidad del ítem:
+     * reasignar algo ya comandado no cambia lo que cocina tiene en marcha.
+     *
+     * @param itemId identificador del ítem
+     * @param tiempo el nuevo paso, o null para quitarlo
+     * @throws IllegalStateException si el pedido no está ABIERTO o el ítem ya se envió
+     * @throws IllegalArgumentException si el ítem no se encuentra en el pedido
+     */
+    public void cambiarTiempoServicioItem(ItemPedidoId itemId, TiempoServicio tiempo) {
+        Objects.requireNonNull(itemId, "El itemId no puede ser null");
+        validarPermiteModificacion();
+
+        ItemPedido item = buscarItemPorId(itemId);
+        if (item.getCantidadEnviadaCocina() > 0) {
+            throw new IllegalStateException(
+                String.format("El ítem '%s' ya fue enviado a cocina", item.getNombreProducto())
+            );
+        }
+        item.asignarTiempoServicio(tiempo);
+    }
+
+    /**
+     * HU-120: Cambia la observación libre de un ítem ("bien cocida", "sin sal").
+     *
+     * Solo mientras cocina no lo recibió: la comanda ya impresa no se corrige sola.
+     *
+     * @param itemId ítem a modificar
+     * @param observacion texto para cocina, o null/vacío para quitarla
+     * @throws IllegalStateException si el pedido no está ABIERTO o el ítem ya se envió a cocina
+     */
+    public void cambiarObservacionItem(ItemPedidoId itemId, String observacion) {
+        Objects.requireNonNull(itemId, "El itemId no puede ser null");
+        validarPermiteModificacion();
+
+        ItemPedido item = buscarItemPorId(itemId);
+        if (item.getCantidadEnviadaCocina() > 0) {
+            throw new IllegalStateException(
+                String.format("El ítem '%s' ya fue enviado a cocina", item.getNombreProducto())
+            );
+        }
+        item.cambiarObservacion(observacion);
+    }
+
     /**
      * Valida que el pedido permita modificaciones.
      * Solo los pedidos en estado ABIERTO pueden ser modificados.     * 
@@ -340,12 +647,16 @@ public class Pedido {
 
         // Buscar el ítem dentro del aggregate
         ItemPedido item = buscarItemPorId(itemId);
+        validarNoEsEnvio(item);
 
         // Idempotencia: si la cantidad es igual, no hacer nada
         if (item.getCantidad() == nuevaCantidad) {
             return;
         }
 
+        // HU-111: Bajar por debajo de lo ya enviado a cocina es una anulación
+        registrarAnulacion(item, nuevaCantidad);
+
         // Cantidad 0: el usuario quiere eliminar el ítem
         if (nuevaCantidad == 0) {
             eliminarItem(itemId);
@@ -375,6 +686,62 @@ public class Pedido {
         this.items.remove(item);
     }
 
+    /**
+     * HU-20 + HU-111: Quita un ítem a pedido del usuario.
+     *
+     * A diferencia de {@link #eliminarItem}, que también se usa para reemplazar
+     * líneas al acumular cantidades, este método registra como anulación las
+     * unidades que ya habían salido a cocina.
+     *
+     * @param itemId identificador del ítem a quitar
+     * @throws IllegalStateException si el pedido NO está en estado ABIERTO
+     * @throws IllegalArgumentException si el ítem no se encuentra en el pedido
+     */
+    public void quitarItem(ItemPedidoId itemId) {
+        Objects.requireNonNull(itemId, "El itemId no puede ser null");
+        validarPermiteModificacion();
+
+        ItemPedido item = buscarItemPorId(itemId);
+        validarNoEsEnvio(item);
+        registrarAnulacion(item, 0);
+        eliminarItem(itemId);
+    }
+
+    /**
+     * HU-139: El envío sigue a la zona de entrega, no se edita a mano.
+     */
+    private void validarNoEsEnvio(ItemPedido item) {
+        if (item.isEnvio()) {
+            throw new IllegalStateException("El costo de envío se cambia eligiendo la zona de entrega");
+        }
+    }
+
+    /**
+     * Acumula las unidades enviadas a cocina que se pierden al llevar el ítem
+     * a la nueva cantidad. Se valorizan al precio de línea (base + extras) sin
+     * promociones: es lo que la cocina ya había empezado a preparar.
+     */
+    private void registrarAnulacion(ItemPedido item, int nuevaCantidad) {
+        int enviadas = Math.min(item.getCantidadEnviadaCocina(), item.getCantidad());
+        int anuladas = enviadas - Math.min(enviadas, nuevaCantidad);
+        if (anuladas <= 0) {
+            return;
+        }
+
+        BigDecimal precioUnidad = item.calcularSubtotalLinea()
+            .divide(BigDecimal.valueOf(item.getCantidad()), 2, RoundingMode.HALF_UP);
+        this.unidadesAnuladas += anuladas;
+        this.montoAnulado = this.montoAnulado.add(precioUnidad.multiply(BigDecimal.valueOf(anuladas)));
+    }
+
+    public int getUnidadesAnuladas() {
+        return unidadesAnuladas;
+    }
+
+    public BigDecimal getMontoAnulado() {
+        return montoAnulado;
+    }
+
     /**
      * HU-20/HU-21: Limpia las promociones de TODOS los ítems del pedido.
      * 
@@ -446,6 +813,16 @@ public class Pedido {
             .findFirst();
     }
 
+    /**
+     * HU-164: Indica si el ítem del borrador del POS ya está cargado en el pedido.
+     *
+     * @param borradorId id del ítem en el borrador de la terminal
+     */
+    public boolean tieneItemDeBorrador(UUID borradorId) {
+        Objects.requireNonNull(borradorId, "El borradorId no puede ser null");
+        return this.items.stream().anyMatch(item -> item.getBorradores().contains(borradorId));
+    }
+
     /**
      * Calcula el subtotal del pedido sumando los subtotales de todos los ítems.
      * Este cálculo NO incluye descuentos.
@@ -476,15 +853,19 @@ public class Pedido {
      * 1. baseGravable = Sumatoria(item.calcularPrecioFinal())
      *    -> Cada item ya incluye sus promociones automáticas (HU-10) y descuentos manuales por ítem
      * 2. montoDescuentoGlobal = descuentoGlobal.calcularMonto(baseGravable)
-     * 3. totalFinal = baseGravable - montoDescuentoGlobal
+     * 3. totalFinal = baseGravable - montoDescuentoGlobal - promo de cumpleaños (HU-168)
+     *    - montoCanjePuntos (HU-105) - montoSenia (HU-142)
      * 
      * IMPORTANTE: La base gravable usa calcularPrecioFinal() de cada ítem, que ya incluye
      * promociones automáticas y descuentos manuales por ítem. Así se respeta la jerarquía:
      * 1° Promociones automáticas (HU-10)
      * 2° Descuentos manuales por ítem
      * 3° Descuento global (sobre el total final de los ítems)
+     * 3° bis Promo de cumpleaños (monto fijo calculado al cobrar)
+     * 4° Canje de puntos (monto fijo, nunca deja el total por debajo de cero)
+     * 5° Seña de la reserva (ya cobrada; si el consumo no llega, el total queda en cero)
      * 
-     * @return el total final del pedido (subtotal de ítems - descuento global)
+     * @return el total final del pedido (subtotal de ítems - descuento global - canje de puntos - seña)
      */
     public BigDecimal calcularTotal() {
         // 1. Base gravable: suma de todos los ítems con sus descuentos aplicados
@@ -493,117 +874,752 @@ public class Pedido {
             .reduce(BigDecimal.ZERO, BigDecimal::add);
         
         // 2. Aplicar descuento global sobre la base gravable (HU-14)
+        BigDecimal total = baseGravable;
         if (descuentoGlobal != null) {
-            BigDecimal montoDescuentoGlobal = descuentoGlobal.calcularMonto(baseGravable);
-            return baseGravable.subtract(montoDescuentoGlobal);
+            total = total.subtract(descuentoGlobal.calcularMonto(baseGravable));
         }
-        
-        // Sin descuento global
-        return baseGravable;
+
+        // 3. HU-168: Restar la promo de cumpleaños del cliente
+        // 4. HU-105: Restar el canje de puntos (monto fijo congelado al canjear)
+        // 5. HU-142: Restar la seña de la reserva, que ya entró a la caja el día que se cobró
+        return total.subtract(montoPromoCumpleanios).subtract(montoCanjePuntos).subtract(montoSenia)
+            .max(BigDecimal.ZERO);
     }
 
     // ============================================
-    // HU-14: Getters y setters de descuento global
+    // HU-105: Canje de puntos
     // ============================================
 
     /**
-     * Retorna el descuento global aplicado a todo el pedido.
-     * 
-     * @return DescuentoManual o null si no tiene descuento global
+     * Aplica un canje de puntos como descuento de monto fijo sobre el total.
+     * Reemplaza cualquier canje previo del pedido.
+     *
+     * La validación del saldo del cliente es responsabilidad de ProgramaPuntosService;
+     * acá solo se protegen las invariantes del aggregate.
+     *
+     * @param puntos puntos canjeados (mayor a cero)
+     * @param monto descuento equivalente en pesos
+     * @throws IllegalStateException si el pedido no está ABIERTO
+     * @throws IllegalArgumentException si el monto es negativo o supera el total sin canje
      */
-    public DescuentoManual getDescuentoGlobal() {
-        return descuentoGlobal;
+    public void aplicarCanjePuntos(int puntos, BigDecimal monto) {
+        validarPermiteModificacion();
+        Objects.requireNonNull(monto, "El monto del canje no puede ser null");
+        if (puntos <= 0) {
+            throw new IllegalArgumentException("La cantidad de puntos canjeados debe ser mayor a cero");
+        }
+        if (monto.signum() < 0) {
+            throw new IllegalArgumentException("El monto del canje no puede ser negativo");
+        }
+
+        this.puntosCanjeados = 0;
+        this.montoCanjePuntos = BigDecimal.ZERO;
+        BigDecimal totalSinCanje = calcularTotal();
+        if (monto.compareTo(totalSinCanje) > 0) {
+            throw new IllegalArgumentException(
+                String.format("El canje ($%s) no puede superar el total del pedido ($%s)",
+                    monto.toPlainString(), totalSinCanje.toPlainString())
+            );
+        }
+
+        this.puntosCanjeados = puntos;
+        this.montoCanjePuntos = monto;
     }
 
+    public boolean tieneCanjePuntos() {
+        return puntosCanjeados > 0;
+    }
+
+    public int getPuntosCanjeados() {
+        return puntosCanjeados;
+    }
+
+    public BigDecimal getMontoCanjePuntos() {
+        return montoCanjePuntos;
+    }
+
+    // ============================================
+    // HU-142: Seña de la reserva
+    // ============================================
+
     /**
-     * Aplica un descuento global a todo el pedido.
-     * Sobrescribe cualquier descuento global previo.
-     * 
-     * HU-14: El descuento global es dinámico. Se recalcula en cada invocación de calcularTotal().
-     * 
-     * Invariante para MONTO_FIJO: el valor no puede exceder la base gravable actual
-     * del pedido (suma de precios finales de ítems), para evitar totales negativos.
-     * 
-     * @param descuentoGlobal el descuento a aplicar, o null para remover el descuento
-     * @throws IllegalArgumentException si es MONTO_FIJO y el valor excede la base gravable
+     * Vincula la reserva que se sentó en la mesa y descuenta su seña del total.
+     * La seña no es un descuento comercial: ya se cobró como ingreso de caja,
+     * por eso en la cuenta solo se cobra la diferencia. Si el pedido se reabre,
+     * la seña sigue descontada.
+     *
+     * @param senia seña cobrada al tomar la reserva (cero si no dejó seña)
+     * @throws IllegalStateException si el pedido no está ABIERTO o ya tiene una reserva
+     * @throws IllegalArgumentException si la seña es negativa
      */
-    public void aplicarDescuentoGlobal(DescuentoManual descuentoGlobal) {
-        if (descuentoGlobal != null 
-                && descuentoGlobal.getTipo() == ModoDescuento.MONTO_FIJO) {
-            BigDecimal baseGravable = items.stream()
-                .map(ItemPedido::calcularPrecioFinal)
-                .reduce(BigDecimal.ZERO, BigDecimal::add);
-            if (descuentoGlobal.getValor().compareTo(baseGravable) > 0) {
-                throw new IllegalArgumentException(
-                    String.format("El monto fijo ($%s) no puede superar la base gravable del pedido ($%s)",
-                        descuentoGlobal.getValor().toPlainString(), baseGravable.toPlainString())
-                );
-            }
+    public void vincularReserva(ReservaId reservaId, BigDecimal senia) {
+        validarPermiteModificacion();
+        Objects.requireNonNull(reservaId, "La reserva no puede ser null");
+        Objects.requireNonNull(senia, "La seña no puede ser null");
+        if (senia.signum() < 0) {
+            throw new IllegalArgumentException("La seña no puede ser negativa");
         }
-        this.descuentoGlobal = descuentoGlobal;
+        if (this.reservaId != null) {
+            throw new IllegalStateException("El pedido #" + numero + " ya tiene una reserva vinculada");
+        }
+        this.reservaId = reservaId;
+        this.montoSenia = senia;
+    }
+
+    public boolean tieneSenia() {
+        return montoSenia.signum() > 0;
     }
 
     /**
-     * Indica si este pedido tiene un descuento global aplicado.
-     * 
-     * @return true si tiene descuento global
+     * @return reserva vinculada, o null si la mesa no era reservada
      */
-    public boolean tieneDescuentoGlobal() {
-        return descuentoGlobal != null;
+    public ReservaId getReservaId() {
+        return reservaId;
+    }
+
+    public BigDecimal getMontoSenia() {
+        return montoSenia;
     }
 
+    // ============================================
+    // HU-106: Turno de caja
+    // ============================================
+
     /**
-     * Calcula el monto del descuento global aplicado a este pedido.
-     * 
-     * HU-14: Este cálculo es dinámico. Depende del total de los ítems con sus descuentos.
-     * 
-     * @return monto del descuento global, o BigDecimal.ZERO si no tiene
+     * Asocia el pedido recién cerrado al turno de caja activo.
+     * El pedido pertenece al turno en el que se cobró, no al que se abrió la mesa:
+     * es el cajón de ese turno el que recibe el efectivo.
+     *
+     * @throws IllegalStateException si el pedido no está CERRADO
      */
-    public BigDecimal calcularMontoDescuentoGlobal() {
-        if (descuentoGlobal == null) {
-            return BigDecimal.ZERO;
+    public void asignarTurno(TurnoCajaId turnoId) {
+        Objects.requireNonNull(turnoId, "El turnoId no puede ser null");
+        if (this.estado != EstadoPedido.CERRADO) {
+            throw new IllegalStateException("Solo se puede asignar un turno a un pedido cerrado");
         }
-        
-        // Base gravable = suma de ítems con sus descuentos aplicados
-        BigDecimal baseGravable = items.stream()
-            .map(ItemPedido::calcularPrecioFinal)
-            .reduce(BigDecimal.ZERO, BigDecimal::add);
-            
-        return descuentoGlobal.calcularMonto(baseGravable);
+        this.turnoId = turnoId;
+    }
+
+    public TurnoCajaId getTurnoId() {
+        return turnoId;
     }
 
+    // ============================================
+    // HU-159: Caja del cobro
+    // ============================================
+
     /**
-     * Materializa la narrativa económica del pedido como lista explícita de ajustes.
-     * 
-     * Recorre todos los mecanismos de descuento del agregado y los convierte
-     * en AjusteEconomico con monto final (snapshot monetario), eliminando
-     * la necesidad de inferir descuentos por resta subtotal - total.
-     * 
-     * El Pedido se convierte en la única fuente de verdad del relato económico.
-     * 
-     * Orden de materialización:
-     * 1. Promociones automáticas por ítem (HU-10) — snapshot fijo
-     * 2. Descuentos manuales por ítem (HU-14) — calculados dinámicamente
-     * 3. Descuento global (HU-14) — calculado dinámicamente sobre base gravable
-     * 
-     * @return lista inmutable de ajustes económicos (puede estar vacía)
+     * Asocia el pedido recién cerrado a la caja que lo cobró.
+     * Sin caja asignada el cobro es de la caja principal.
+     *
+     * @throws IllegalStateException si el pedido no está CERRADO
      */
-    public List<AjusteEconomico> obtenerAjustesEconomicos() {
-        List<AjusteEconomico> ajustes = new ArrayList<>();
-
-        // 1. Promociones automáticas por ítem (HU-10)
-        for (ItemPedido item : items) {
-            if (item.tienePromocion()) {
-                String descripcion = item.getNombrePromocion() != null
-                    ? item.getNombrePromocion()
-                    : "Promoción";
-                ajustes.add(new AjusteEconomico(
-                    AjusteEconomico.TipoAjuste.PROMOCION,
-                    AjusteEconomico.AmbitoAjuste.ITEM,
-                    descripcion,
-                    item.getMontoDescuento()
-                ));
-            }
+    public void asignarCaja(CajaId cajaId) {
+        Objects.requireNonNull(cajaId, "El cajaId no puede ser null");
+        if (this.estado != EstadoPedido.CERRADO) {
+            throw new IllegalStateException("Solo se puede asignar una caja a un pedido cerrado");
+        }
+        this.cajaId = cajaId;
+    }
+
+    public CajaId getCajaId() {
+        return cajaId;
+    }
+
+    // ============================================
+    // HU-166: Numeración de comprobantes
+    // ============================================
+
+    /**
+     * Asigna al pedido recién cerrado el número de comprobante de su serie.
+     * El número es definitivo: si el pedido se reabre y se vuelve a cobrar
+     * sale con el mismo, así la serie no queda con huecos.
+     *
+     * @throws IllegalStateException si el pedido no está CERRADO o ya tiene número
+     */
+    public void asignarNumeroComprobante(String numeroComprobante) {
+        Objects.requireNonNull(numeroComprobante, "El número de comprobante no puede ser null");
+        if (this.estado != EstadoPedido.CERRADO) {
+            throw new IllegalStateException("Solo se puede numerar un pedido cerrado");
+        }
+        if (this.numeroComprobante != null) {
+            throw new IllegalStateException("El pedido ya tiene el comprobante " + this.numeroComprobante);
+        }
+        this.numeroComprobante = numeroComprobante;
+    }
+
+    public String getNumeroComprobante() {
+        return numeroComprobante;
+    }
+
+    // ============================================
+    // HU-168: Cliente y promo de cumpleaños
+    // ============================================
+
+    /**
+     * Vincula el cliente de la ficha al pedido, sea de salón o de delivery.
+     * Reemplaza al que estuviera vinculado.
+     *
+     * @throws IllegalStateException si el pedido no está ABIERTO
+     */
+    public void vincularCliente(ClienteId clienteId) {
+        Objects.requireNonNull(clienteId, "El clienteId no puede ser null");
+        validarPermiteModificacion();
+        this.clienteId = clienteId;
+    }
+
+    /**
+     * Quita el cliente vinculado.
+     *
+     * @throws IllegalStateException si el pedido no está ABIERTO
+     */
+    public void desvincularCliente() {
+        validarPermiteModificacion();
+        this.clienteId = null;
+    }
+
+    /**
+     * Aplica el descuento de la promo de cumpleaños sobre el total.
+     * Se calcula al cobrar, con el total ya cerrado de ítems y descuentos;
+     * reemplaza cualquier promo previa del pedido.
+     *
+     * @param monto descuento en pesos (cero para quitarlo)
+     * @throws IllegalStateException si el pedido no está ABIERTO
+     * @throws IllegalArgumentException si el monto es negativo o supera el total
+     */
+    public void aplicarPromoCumpleanios(BigDecimal monto) {
+        validarPermiteModificacion();
+        Objects.requireNonNull(monto, "El monto de la promo no puede ser null");
+        if (monto.signum() < 0) {
+            throw new IllegalArgumentException("El monto de la promo no puede ser negativo");
+        }
+
+        this.montoPromoCumpleanios = BigDecimal.ZERO;
+        BigDecimal totalSinPromo = calcularTotal();
+        if (monto.compareTo(totalSinPromo) > 0) {
+            throw new IllegalArgumentException(
+                String.format("La promo de cumpleaños ($%s) no puede superar el total del pedido ($%s)",
+                    monto.toPlainString(), totalSinPromo.toPlainString())
+            );
+        }
+        this.montoPromoCumpleanios = monto;
+    }
+
+    public boolean tienePromoCumpleanios() {
+        return montoPromoCumpleanios.signum() > 0;
+    }
+
+    /**
+     * @return cliente vinculado, o null si no se identificó
+     */
+    public ClienteId getClienteId() {
+        return clienteId;
+    }
+
+    public BigDecimal getMontoPromoCumpleanios() {
+        return montoPromoCumpleanios;
+    }
+
+    // ============================================
+    // HU-108: Canal de venta y lista de precios
+    // ============================================
+
+    /**
+     * Define el canal del pedido y congela la lista de precios que le corresponde.
+     * Se invoca al crear el pedido: cambiar de lista con ítems cargados dejaría
+     * precios de dos listas mezclados en la misma comanda.
+     *
+     * @param canal canal de venta
+     * @param lista lista del canal, o null si el canal vende a precio de catálogo
+     * @throws IllegalArgumentException si la lista es de otro canal u otro local
+     * @throws IllegalStateException si el pedido ya tiene ítems
+     */
+    public void asignarCanal(CanalVenta canal, ListaPrecios lista) {
+        Objects.requireNonNull(canal, "El canal de venta no puede ser null");
+        if (!this.items.isEmpty()) {
+            throw new IllegalStateException("No se puede cambiar el canal de un pedido con ítems cargados");
+        }
+        if (lista != null) {
+            if (lista.getCanal() != canal) {
+                throw new IllegalArgumentException("La lista de precios no corresponde al canal del pedido");
+            }
+            if (!lista.getLocalId().equals(this.localId)) {
+                throw new IllegalArgumentException("La lista de precios no pertenece al local del pedido");
+            }
+        }
+        this.canal = canal;
+        this.listaPreciosId = lista != null ? lista.getId() : null;
+        this.nombreListaPrecios = lista != null ? lista.getNombre() : null;
+    }
+
+    public CanalVenta getCanal() {
+        return canal;
+    }
+
+    public ListaPreciosId getListaPreciosId() {
+        return listaPreciosId;
+    }
+
+    public String getNombreListaPrecios() {
+        return nombreListaPrecios;
+    }
+
+    // ============================================
+    // HU-111: Mozo, cubiertos y propina
+    // ============================================
+
+    /**
+     * Asigna el mozo que atiende la mesa y la cantidad de comensales.
+     *
+     * @param mozoId mozo a cargo (null = sin asignar)
+     * @param cubiertos cantidad de comensales (0 = no informado)
+     * @throws IllegalStateException si el pedido no está ABIERTO
+     * @throws IllegalArgumentException si los cubiertos están fuera de rango
+     */
+    public void asignarAtencion(MozoId mozoId, int cubiertos) {
+        validarPermiteModificacion();
+        if (cubiertos < 0 || cubiertos > 99) {
+            throw new IllegalArgumentException("La cantidad de cubiertos debe estar entre 0 y 99");
+        }
+        this.mozoId = mozoId;
+        this.cubiertos = cubiertos;
+    }
+
+    /**
+     * Registra la propina dejada al cobrar la mesa.
+     * No forma parte del total del pedido ni de los pagos: es del mozo,
+     * no una venta del local.
+     *
+     * @throws IllegalStateException si el pedido no está CERRADO
+     * @throws IllegalArgumentException si el monto es negativo
+     */
+    public void registrarPropina(BigDecimal monto) {
+        Objects.requireNonNull(monto, "El monto de la propina no puede ser null");
+        if (this.estado != EstadoPedido.CERRADO) {
+            throw new IllegalStateException("Solo se puede registrar la propina de un pedido cerrado");
+        }
+        if (monto.compareTo(BigDecimal.ZERO) < 0) {
+            throw new IllegalArgumentException("La propina no puede ser negativa");
+        }
+        this.propina = monto;
+    }
+
+    public MozoId getMozoId() {
+        return mozoId;
+    }
+
+    public int getCubiertos() {
+        return cubiertos;
+    }
+
+    public BigDecimal getPropina() {
+        return propina;
+    }
+
+    // ============================================
+    // HU-165: Ediciones simultáneas desde dos terminales
+    // ============================================
+
+    /**
+     * Versión persistida del pedido. La capa de persistencia la sube en cada
+     * guardado y rechaza el guardado si cambió desde que se leyó; la terminal
+     * la manda de vuelta con su próximo cambio.
+     */
+    public long getVersion() {
+        return version;
+    }
+
+    /**
+     * Verifica que la terminal esté cambiando el pedido que ve: si otra
+     * terminal lo guardó después de que lo cargó, el cambio se rechaza en
+     * lugar de pisar al otro.
+     *
+     * @param versionEsperada versión que tenía la terminal
+     * @throws PedidoModificadoException si el pedido cambió desde esa versión
+     */
+    public void verificarVersion(long versionEsperada) {
+        if (versionEsperada != this.version) {
+            throw new PedidoModificadoException(versionEsperada, this.version);
+        }
+    }
+
+    // ============================================
+    // HU-113: Actividad de la mesa (alerta de mesas olvidadas)
+    // ============================================
+
+    /**
+     * Registra que alguien atendió la mesa: se cargaron ítems nuevos,
+     * se envió una comanda o se pidió la cuenta.
+     * Nunca retrocede: un registro con hora anterior se ignora.
+     *
+     * @throws IllegalStateException si el pedido no está ABIERTO
+     */
+    public void registrarActividad(LocalDateTime ahora) {
+        Objects.requireNonNull(ahora, "La fecha de la actividad no puede ser null");
+        validarPermiteModificacion();
+        if (ahora.isAfter(this.ultimaActividad)) {
+            this.ultimaActividad = ahora;
+        }
+    }
+
+    /**
+     * Minutos transcurridos desde la última atención de la mesa.
+     *
+     * @param ahora momento de la consulta
+     * @return minutos completos sin actividad (0 si la hora es anterior)
+     */
+    public long minutosSinActividad(LocalDateTime ahora) {
+        Objects.requireNonNull(ahora, "La fecha de consulta no puede ser null");
+        return Math.max(0, Duration.between(ultimaActividad, ahora).toMinutes());
+    }
+
+    public LocalDateTime getUltimaActividad() {
+        return ultimaActividad;
+    }
+
+    // ============================================
+    // HU-137: Urgencia en cocina
+    // ============================================
+
+    /**
+     * Marca o desmarca el pedido completo como urgente.
+     * Afecta las comandas que salgan desde ahora; las que ya están en cocina
+     * las actualiza el caso de uso.
+     *
+     * @throws IllegalStateException si el pedido no está ABIERTO
+     */
+    public void marcarUrgente(boolean urgente) {
+        validarPermiteModificacion();
+        this.urgente = urgente;
+    }
+
+    /**
+     * Marca o desmarca un ítem como urgente.
+     *
+     * Marcarlo solo tiene sentido si le quedan unidades por enviar: lo que
+     * cocina ya recibió se apura marcando el pedido. Desmarcar siempre se permite.
+     *
+     * @throws IllegalStateException si el pedido no está ABIERTO o el ítem ya salió completo a cocina
+     * @throws IllegalArgumentException si el ítem no se encuentra en el pedido
+     */
+    public void marcarUrgenteItem(ItemPedidoId itemId, boolean urgente) {
+        Objects.requireNonNull(itemId, "El itemId no puede ser null");
+        validarPermiteModificacion();
+
+        ItemPedido item = buscarItemPorId(itemId);
+        if (urgente && !item.tieneCantidadNueva()) {
+            throw new IllegalStateException(
+                String.format("El ítem '%s' ya está en cocina: marcá urgente el pedido", item.getNombreProducto())
+            );
+        }
+        item.marcarUrgente(urgente);
+    }
+
+    public boolean isUrgente() {
+        return urgente;
+    }
+
+    // ============================================
+    // HU-138: Horario prometido de retiro
+    // ============================================
+
+    /**
+     * Registra el horario de retiro que se le informó al cliente.
+     * Se promete una sola vez: los envíos posteriores no lo corren.
+     *
+     * @throws IllegalStateException si el pedido no es para retirar, no está ABIERTO o ya tiene horario
+     */
+    public void prometerRetiro(LocalDateTime hora) {
+        Objects.requireNonNull(hora, "El horario prometido no puede ser null");
+        validarPermiteModificacion();
+        if (canal != CanalVenta.TAKE_AWAY) {
+            throw new IllegalStateException("Solo los pedidos para retirar tienen horario prometido");
+        }
+        if (horaPrometida != null) {
+            throw new IllegalStateException("El pedido ya tiene un horario de retiro prometido");
+        }
+        this.horaPrometida = hora;
+    }
+
+    /**
+     * @return horario de retiro prometido, o null si no se prometió
+     */
+    public LocalDateTime getHoraPrometida() {
+        return horaPrometida;
+    }
+
+    // ============================================
+    // HU-139: Entrega y costo de envío
+    // ============================================
+
+    /**
+     * Fija la dirección de entrega y la zona de reparto del delivery.
+     * Reemplaza el ítem de envío anterior por el costo de la zona (sin ítem
+     * si la zona no cobra envío).
+     *
+     * @throws IllegalStateException si el pedido no es delivery, no está ABIERTO o la zona está inactiva
+     * @throws IllegalArgumentException si la dirección está vacía o la zona es de otro local
+     */
+    public void asignarEntrega(String direccion, ZonaDelivery zona) {
+        Objects.requireNonNull(zona, "La zona de reparto no puede ser null");
+        validarPermiteModificacion();
+        if (canal != CanalVenta.DELIVERY) {
+            throw new IllegalStateException("Solo los pedidos de delivery tienen dirección de entrega");
+        }
+        if (direccion == null || direccion.isBlank()) {
+            throw new IllegalArgumentException("La dirección de entrega no puede estar vacía");
+        }
+        if (direccion.trim().length() > 200) {
+            throw new IllegalArgumentException("La dirección de entrega no puede superar los 200 caracteres");
+        }
+        if (!zona.getLocalId().equals(localId)) {
+            throw new IllegalArgumentException("La zona de reparto no pertenece a este local");
+        }
+        if (!zona.isActiva()) {
+            throw new IllegalStateException("La zona " + zona.getNombre() + " no está activa");
+        }
+
+        items.removeIf(ItemPedido::isEnvio);
+        if (zona.getCostoEnvio().signum() > 0) {
+            items.add(ItemPedido.crearEnvio(id, zona));
+        }
+        this.direccionEntrega = direccion.trim();
+        this.zonaDeliveryId = zona.getId();
+    }
+
+    /**
+     * Subtotal de los productos, sin el costo de envío: es lo que se compara
+     * contra el mínimo de la zona.
+     */
+    public BigDecimal calcularVentaProductos() {
+        return items.stream()
+            .filter(item -> !item.isEnvio())
+            .map(ItemPedido::calcularSubtotalLinea)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    /**
+     * @return dirección de entrega, o null si no se cargó
+     */
+    public String getDireccionEntrega() {
+        return direccionEntrega;
+    }
+
+    /**
+     * @return zona de reparto de la entrega, o null si no se cargó
+     */
+    public ZonaDeliveryId getZonaDeliveryId() {
+        return zonaDeliveryId;
+    }
+
+    // ============================================
+    // HU-140: Reparto del delivery
+    // ============================================
+
+    /**
+     * Asigna el repartidor que lleva el pedido. Se puede cambiar hasta que sale;
+     * el pedido puede estar ya cobrado.
+     *
+     * @throws IllegalStateException si el pedido no es delivery, ya salió o el empleado no es un repartidor activo
+     * @throws IllegalArgumentException si el repartidor es de otro local
+     */
+    public void asignarRepartidor(Mozo repartidor) {
+        Objects.requireNonNull(repartidor, "El repartidor no puede ser null");
+        if (canal != CanalVenta.DELIVERY) {
+            throw new IllegalStateException("Solo los pedidos de delivery se asignan a un repartidor");
+        }
+        if (!repartidor.getLocalId().equals(localId)) {
+            throw new IllegalArgumentException("El repartidor no pertenece a este local");
+        }
+        repartidor.validarRepartidor();
+        if (salidaReparto != null) {
+            throw new IllegalStateException("El pedido #" + numero + " ya salió con su repartidor");
+        }
+        this.repartidorId = repartidor.getId();
+    }
+
+    /**
+     * @throws IllegalStateException si no tiene repartidor o ya salió
+     */
+    public void registrarSalida(LocalDateTime hora) {
+        Objects.requireNonNull(hora, "La hora de salida no puede ser null");
+        if (repartidorId == null) {
+            throw new IllegalStateException("Asigná un repartidor al pedido #" + numero + " antes de despacharlo");
+        }
+        if (salidaReparto != null) {
+            throw new IllegalStateException("El pedido #" + numero + " ya salió");
+        }
+        this.salidaReparto = hora;
+    }
+
+    /**
+     * @throws IllegalStateException si el pedido no salió o ya se registró el regreso
+     * @throws IllegalArgumentException si la hora es anterior a la salida
+     */
+    public void registrarRegreso(LocalDateTime hora) {
+        Objects.requireNonNull(hora, "La hora de regreso no puede ser null");
+        if (salidaReparto == null) {
+            throw new IllegalStateException("El pedido #" + numero + " todavía no salió");
+        }
+        if (regresoReparto != null) {
+            throw new IllegalStateException("El regreso del pedido #" + numero + " ya está registrado");
+        }
+        if (hora.isBefore(salidaReparto)) {
+            throw new IllegalArgumentException("El regreso no puede ser anterior a la salida");
+        }
+        this.regresoReparto = hora;
+    }
+
+    /**
+     * @return true si el repartidor salió con el pedido y todavía no volvió
+     */
+    public boolean estaEnReparto() {
+        return salidaReparto != null && regresoReparto == null;
+    }
+
+    /**
+     * Minutos desde la salida hasta el regreso del repartidor.
+     *
+     * @throws IllegalStateException si el reparto no terminó
+     */
+    public long minutosReparto() {
+        if (salidaReparto == null || regresoReparto == null) {
+            throw new IllegalStateException("El reparto del pedido #" + numero + " no terminó");
+        }
+        return Duration.between(salidaReparto, regresoReparto).toMinutes();
+    }
+
+    /**
+     * Lo cobrado en efectivo: es lo que el repartidor trae en mano.
+     * HU-143: con el recargo del efectivo, si el local lo cobra.
+     */
+    public BigDecimal calcularCobradoEfectivo() {
+        return pagos.stream()
+            .filter(pago -> pago.getMedio() == MedioPago.EFECTIVO)
+            .map(Pago::getMontoCobrado)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    /**
+     * @return repartidor asignado, o null si no se asignó
+     */
+    public MozoId getRepartidorId() {
+        return repartidorId;
+    }
+
+    public LocalDateTime getSalidaReparto() {
+        return salidaReparto;
+    }
+
+    public LocalDateTime getRegresoReparto() {
+        return regresoReparto;
+    }
+
+    // ============================================
+    // HU-14: Getters y setters de descuento global
+    // ============================================
+
+    /**
+     * Retorna el descuento global aplicado a todo el pedido.
+     * 
+     * @return DescuentoManual o null si no tiene descuento global
+     */
+    public DescuentoManual getDescuentoGlobal() {
+        return descuentoGlobal;
+    }
+
+    /**
+     * Aplica un descuento global a todo el pedido.
+     * Sobrescribe cualquier descuento global previo.
+     * 
+     * HU-14: El descuento global es dinámico. Se recalcula en cada invocación de calcularTotal().
+     * 
+     * Invariante para MONTO_FIJO: el valor no puede exceder la base gravable actual
+     * del pedido (suma de precios finales de ítems), para evitar totales negativos.
+     * 
+     * @param descuentoGlobal el descuento a aplicar, o null para remover el descuento
+     * @throws IllegalArgumentException si es MONTO_FIJO y el valor excede la base gravable
+     */
+    public void aplicarDescuentoGlobal(DescuentoManual descuentoGlobal) {
+        if (descuentoGlobal != null 
+                && descuentoGlobal.getTipo() == ModoDescuento.MONTO_FIJO) {
+            BigDecimal baseGravable = items.stream()
+                .map(ItemPedido::calcularPrecioFinal)
+                .reduce(BigDecimal.ZERO, BigDecimal::add);
+            if (descuentoGlobal.getValor().compareTo(baseGravable) > 0) {
+                throw new IllegalArgumentException(
+                    String.format("El monto fijo ($%s) no puede superar la base gravable del pedido ($%s)",
+                        descuentoGlobal.getValor().toPlainString(), baseGravable.toPlainString())
+                );
+            }
+        }
+        this.descuentoGlobal = descuentoGlobal;
+    }
+
+    /**
+     * Indica si este pedido tiene un descuento global aplicado.
+     * 
+     * @return true si tiene descuento global
+     */
+    public boolean tieneDescuentoGlobal() {
+        return descuentoGlobal != null;
+    }
+
+    /**
+     * Calcula el monto del descuento global aplicado a este pedido.
+     * 
+     * HU-14: Este cálculo es dinámico. Depende del total de los ítems con sus descuentos.
+     * 
+     * @return monto del descuento global, o BigDecimal.ZERO si no tiene
+     */
+    public BigDecimal calcularMontoDescuentoGlobal() {
+        if (descuentoGlobal == null) {
+            return BigDecimal.ZERO;
+        }
+        
+        // Base gravable = suma de ítems con sus descuentos aplicados
+        BigDecimal baseGravable = items.stream()
+            .map(ItemPedido::calcularPrecioFinal)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+            
+        return descuentoGlobal.calcularMonto(baseGravable);
+    }
+
+    /**
+     * Materializa la narrativa económica del pedido como lista explícita de ajustes.
+     * 
+     * Recorre todos los mecanismos de descuento del agregado y los convierte
+     * en AjusteEconomico con monto final (snapshot monetario), eliminando
+     * la necesidad de inferir descuentos por resta subtotal - total.
+     * 
+     * El Pedido se convierte en la única fuente de verdad del relato económico.
+     * 
+     * Orden de materialización:
+     * 1. Promociones automáticas por ítem (HU-10) — snapshot fijo
+     * 2. Descuentos manuales por ítem (HU-14) — calculados dinámicamente
+     * 3. Descuento global (HU-14) — calculado dinámicamente sobre base gravable
+     * 3b. Promo de cumpleaños (HU-168) — calculada al cobrar
+     * 4. Canje de puntos (HU-105) — monto fijo congelado al canjear
+     * 5. Seña de la reserva (HU-142) — cobrada de antemano
+     * 
+     * @return lista inmutable de ajustes económicos (puede estar vacía)
+     */
+    public List<AjusteEconomico> obtenerAjustesEconomicos() {
+        List<AjusteEconomico> ajustes = new ArrayList<>();
+
+        // 1. Promociones automáticas por ítem (HU-10)
+        for (ItemPedido item : items) {
+            if (item.tienePromocion()) {
+                String descripcion = item.getNombrePromocion() != null
+                    ? item.getNombrePromocion()
+                    : "Promoción";
+                ajustes.add(new AjusteEconomico(
+                    AjusteEconomico.TipoAjuste.PROMOCION,
+                    AjusteEconomico.AmbitoAjuste.ITEM,
+                    descripcion,
+                    item.getMontoDescuento()
+                ));
+            }
         }
 
         // 2. Descuentos manuales por ítem (HU-14)
@@ -617,7 +1633,8 @@ public class Pedido {
                     AjusteEconomico.TipoAjuste.MANUAL,
                     AjusteEconomico.AmbitoAjuste.ITEM,
                     descripcion,
-                    item.calcularMontoDescuentoManual()
+                    item.calcularMontoDescuentoManual(),
+                    item.getDescuentoManual().getAutorizadoPor()
                 ));
             }
         }
@@ -632,7 +1649,38 @@ public class Pedido {
                 AjusteEconomico.TipoAjuste.MANUAL,
                 AjusteEconomico.AmbitoAjuste.TOTAL,
                 descripcion,
-                calcularMontoDescuentoGlobal()
+                calcularMontoDescuentoGlobal(),
+                descuentoGlobal.getAutorizadoPor()
+            ));
+        }
+
+        // 3b. Promo de cumpleaños (HU-168)
+        if (tienePromoCumpleanios()) {
+            ajustes.add(new AjusteEconomico(
+                AjusteEconomico.TipoAjuste.PROMOCION,
+                AjusteEconomico.AmbitoAjuste.TOTAL,
+                "Promo de cumpleaños",
+                montoPromoCumpleanios
+            ));
+        }
+
+        // 4. Canje de puntos (HU-105)
+        if (tieneCanjePuntos()) {
+            ajustes.add(new AjusteEconomico(
+                AjusteEconomico.TipoAjuste.MANUAL,
+                AjusteEconomico.AmbitoAjuste.TOTAL,
+                String.format("Canje de %d puntos", puntosCanjeados),
+                montoCanjePuntos
+            ));
+        }
+
+        // 5. Seña de la reserva (HU-142)
+        if (tieneSenia()) {
+            ajustes.add(new AjusteEconomico(
+                AjusteEconomico.TipoAjuste.MANUAL,
+                AjusteEconomico.AmbitoAjuste.TOTAL,
+                "Seña de la reserva",
+                montoSenia
             ));
         }
 
@@ -651,6 +1699,9 @@ public class Pedido {
      * - Solo se pueden cerrar pedidos en estado ABIERTO
      * - El pedido debe tener al menos un ítem cargado
      * - La suma de los pagos debe coincidir exactamente con el total calculado
+     * - HU-160: si hubo pagos a cuenta, los pagos cubren solo el saldo pendiente
+     *   y el pedido cerrado queda con todos (primero los pagos a cuenta)
+     * - HU-142: si la seña de la reserva cubre todo el consumo, se cierra sin pagos
      * - Los montos se congelan al cerrar: ya no se recalculan
      * - Después del cierre, el pedido se vuelve inmutable
      * 
@@ -672,12 +1723,23 @@ public class Pedido {
             throw new IllegalArgumentException("No se puede cerrar un pedido sin ítems");
         }
 
-        if (pagosRecibidos.isEmpty()) {
-            throw new IllegalArgumentException("Debe registrarse al menos un pago para cerrar el pedido");
-        }
-
         // Calcular el total actual antes de congelar
         BigDecimal totalCalculado = calcularTotal();
+
+        // HU-160: Lo que ya se cobró a cuenta no se vuelve a cobrar
+        BigDecimal pagadoACuenta = calcularPagadoACuenta();
+        BigDecimal saldo = totalCalculado.subtract(pagadoACuenta);
+        if (saldo.signum() < 0) {
+            throw new IllegalArgumentException(
+                String.format("Los pagos a cuenta (%s) superan el total del pedido (%s): anulá el que sobra antes de cerrar",
+                    pagadoACuenta.toPlainString(), totalCalculado.toPlainString())
+            );
+        }
+
+        boolean cubiertoSinCobrar = saldo.signum() == 0 && (tieneSenia() || !this.pagos.isEmpty());
+        if (pagosRecibidos.isEmpty() && !cubiertoSinCobrar) {
+            throw new IllegalArgumentException("Debe registrarse al menos un pago para cerrar el pedido");
+        }
         BigDecimal subtotalCalculado = calcularSubtotalItems();
         BigDecimal descuentosCalculados = subtotalCalculado.subtract(totalCalculado);
 
@@ -686,10 +1748,12 @@ public class Pedido {
             .map(Pago::getMonto)
             .reduce(BigDecimal.ZERO, BigDecimal::add);
 
-        if (sumaPagos.compareTo(totalCalculado) != 0) {
-            throw new IllegalArgumentException(
-                String.format("La suma de pagos (%s) no coincide con el total del pedido (%s)",
+        if (sumaPagos.compareTo(saldo) != 0) {
+            throw new IllegalArgumentException(this.pagos.isEmpty()
+                ? String.format("La suma de pagos (%s) no coincide con el total del pedido (%s)",
                     sumaPagos.toPlainString(), totalCalculado.toPlainString())
+                : String.format("La suma de pagos (%s) no coincide con el saldo pendiente (%s)",
+                    sumaPagos.toPlainString(), saldo.toPlainString())
             );
         }
 
@@ -698,8 +1762,7 @@ public class Pedido {
         this.montoDescuentosFinal = descuentosCalculados;
         this.montoTotalFinal = totalCalculado;
 
-        // Registrar pagos
-        this.pagos.clear();
+        // Registrar pagos (HU-160: a continuación de los pagos a cuenta)
         this.pagos.addAll(pagosRecibidos);
 
         // Transicionar estado
@@ -761,8 +1824,30 @@ public class Pedido {
         this.montoTotalFinal = null;
 
         // AC4: Eliminar pagos (orphanRemoval=true en JPA provocará DELETE físico)
+        // HU-160: también los pagos a cuenta; la mesa reabierta se vuelve a cobrar entera
         this.pagos.clear();
 
+        // HU-105: El canje se anula junto con el cobro (los puntos vuelven al cliente)
+        this.puntosCanjeados = 0;
+        this.montoCanjePuntos = BigDecimal.ZERO;
+
+        // HU-168: La promo de cumpleaños se vuelve a calcular en el nuevo cobro (el cliente sigue vinculado)
+        this.montoPromoCumpleanios = BigDecimal.ZERO;
+
+        // HU-106: Al volver a cobrarse se asocia al turno activo en ese momento
+        this.turnoId = null;
+
+        // HU-159: y a la caja que lo vuelva a cobrar
+        this.cajaId = null;
+
+        // HU-166: el número de comprobante se conserva (no se consume otro al volver a cobrar)
+
+        // HU-111: La propina se vuelve a informar en el nuevo cobro
+        this.propina = BigDecimal.ZERO;
+
+        // HU-113: La mesa reabierta arranca su reloj de atención desde cero
+        this.ultimaActividad = fechaReapertura;
+
         // Limpiar fecha de cierre
         this.fechaCierre = null;
         
@@ -861,19 +1946,112 @@ public class Pedido {
         this.pagos.addAll(nuevosPagos);
     }
 
+    // ============================================
+    // HU-160: Pagos a cuenta
+    // ============================================
+
+    /**
+     * Registra un pago a cuenta sobre el pedido abierto: un comensal se va
+     * antes y paga lo suyo. El pago queda en el pedido y al cerrar solo se
+     * cobra lo que falta.
+     *
+     * @throws IllegalStateException si el pedido no está ABIERTO
+     * @throws IllegalArgumentException si el medio se resuelve al cierre o el
+     *                                  monto supera el saldo pendiente
+     */
+    public void registrarPagoACuenta(Pago pago) {
+        validarPermiteModificacion();
+        Objects.requireNonNull(pago, "El pago no puede ser null");
+        if (MEDIOS_SOLO_AL_CIERRE.contains(pago.getMedio())) {
+            throw new IllegalArgumentException("El pago con " + pago.getMedio() + " se registra al cerrar la mesa");
+        }
+        BigDecimal saldo = calcularSaldoPendiente();
+        if (pago.getMonto().compareTo(saldo) > 0) {
+            throw new IllegalArgumentException(
+                String.format("El pago a cuenta (%s) supera el saldo pendiente (%s)",
+                    pago.getMonto().toPlainString(), saldo.max(BigDecimal.ZERO).toPlainString())
+            );
+        }
+        this.pagos.add(pago);
+    }
+
+    /**
+     * Anula un pago a cuenta cargado por error (o que quedó de más porque se
+     * quitaron ítems).
+     *
+     * @param indice posición del pago en {@link #getPagos()}
+     * @return el pago anulado
+     * @throws IllegalStateException si el pedido no está ABIERTO
+     * @throws IllegalArgumentException si no hay un pago en esa posición
+     */
+    public Pago anularPagoACuenta(int indice) {
+        validarPermiteModificacion();
+        if (indice < 0 || indice >= pagos.size()) {
+            throw new IllegalArgumentException("El pedido #" + numero + " no tiene ese pago a cuenta");
+        }
+        return this.pagos.remove(indice);
+    }
+
+    /**
+     * @return lo cobrado a cuenta con el pedido abierto (cero una vez cerrado)
+     */
+    public BigDecimal calcularPagadoACuenta() {
+        if (this.estado != EstadoPedido.ABIERTO) {
+            return BigDecimal.ZERO;
+        }
+        return pagos.stream()
+            .map(Pago::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    /**
+     * Lo que falta cobrar: el total vigente menos los pagos a cuenta. Se
+     * recalcula con cada cambio del pedido; es negativo si se quitaron ítems
+     * ya pagados.
+     *
+     * @return el saldo pendiente (cero una vez cerrado)
+     */
+    public BigDecimal calcularSaldoPendiente() {
+        if (this.estado != EstadoPedido.ABIERTO) {
+            return BigDecimal.ZERO;
+        }
+        return calcularTotal().subtract(calcularPagadoACuenta());
+    }
+
     // ============================================
     // Getters de pagos y snapshot contable
     // ============================================
 
     /**
      * Retorna los pagos registrados al cierre del pedido.
+     * HU-160: con el pedido abierto, los pagos a cuenta.
      * 
-     * @return lista inmutable de pagos (vacía si el pedido está abierto)
+     * @return lista inmutable de pagos (vacía si el pedido está abierto sin pagos a cuenta)
      */
     public List<Pago> getPagos() {
         return Collections.unmodifiableList(pagos);
     }
 
+    /**
+     * HU-143: Suma de los recargos por medio de pago congelados en los pagos.
+     * No forma parte del total del pedido.
+     */
+    public BigDecimal calcularTotalRecargos() {
+        return pagos.stream()
+            .map(Pago::getRecargo)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    /**
+     * HU-152: Ajuste por redondeo del efectivo (negativo si favoreció al cliente).
+     * Tampoco forma parte del total del pedido.
+     */
+    public BigDecimal calcularAjusteRedondeo() {
+        return pagos.stream()
+            .map(Pago::getAjusteRedondeo)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
     /**
      * Retorna el subtotal final congelado al cierre.
      * 
@@ -0,0 +1,145 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaGasto;
+import com.agustinpalma.comandas.domain.model.DomainEnums.ConceptoContable;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.util.EnumMap;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Optional;
+import java.util.regex.Pattern;
+
+/**
+ * Cuentas del plan de cuentas del estudio contable para cada concepto que
+ * exporta el local. Hay a lo sumo una configuración por local: la identidad
+ * es el propio LocalId.
+ *
+ * HU-167: Exportación al sistema contable (Tango / Xubio / Contabilium).
+ *
+ * Reglas de negocio:
+ * - Cada concepto puede tener un código de cuenta de 1 a 30 caracteres
+ *   (letras, dígitos, punto, guion o espacio), tal cual lo usa el estudio.
+ *   Un concepto sin código no tiene cuenta asignada.
+ * - Las cuentas son las mismas para cualquier sistema: el plan de cuentas es
+ *   del estudio, el sistema solo cambia el diseño del archivo.
+ * - El consumo interno (A_CUENTA) no es una venta y no tiene concepto.
+ */
+public class PlanCuentasContable {
+
+    private static final Pattern CUENTA_VALIDA = Pattern.compile("[A-Za-z0-9. \\-]{1,30}");
+
+    private final LocalId localId;
+    private Map<ConceptoContable, String> cuentas;
+
+    public PlanCuentasContable(LocalId localId, Map<ConceptoContable, String> cuentas) {
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.cuentas = validarCuentas(cuentas);
+    }
+
+    /**
+     * Configuración de un local que todavía no asignó cuentas.
+     */
+    public static PlanCuentasContable sinCuentas(LocalId localId) {
+        return new PlanCuentasContable(localId, Map.of());
+    }
+
+    // ============================================
+    // Validaciones
+    // ============================================
+
+    private Map<ConceptoContable, String> validarCuentas(Map<ConceptoContable, String> cuentas) {
+        Objects.requireNonNull(cuentas, "Las cuentas son obligatorias");
+        Map<ConceptoContable, String> limpias = new EnumMap<>(ConceptoContable.class);
+        cuentas.forEach((concepto, cuenta) -> {
+            Objects.requireNonNull(concepto, "El concepto de la cuenta no puede ser null");
+            if (cuenta == null || cuenta.isBlank()) {
+                return;
+            }
+            String limpia = cuenta.trim();
+            if (!CUENTA_VALIDA.matcher(limpia).matches()) {
+                throw new IllegalArgumentException(
+                    "La cuenta de " + concepto + " lleva hasta 30 letras, números, puntos o guiones");
+            }
+            limpias.put(concepto, limpia);
+        });
+        return limpias;
+    }
+
+    // ============================================
+    // Comportamiento
+    // ============================================
+
+    public void actualizar(Map<ConceptoContable, String> cuentas) {
+        this.cuentas = validarCuentas(cuentas);
+    }
+
+    /**
+     * @return la cuenta asignada al concepto, vacío si no tiene
+     */
+    public Optional<String> cuentaDe(ConceptoContable concepto) {
+        return Optional.ofNullable(cuentas.get(concepto));
+    }
+
+    /**
+     * Concepto por donde entra la plata de un cobro.
+     *
+     * @return vacío para el consumo interno (A_CUENTA), que no es una venta
+     */
+    public static Optional<ConceptoContable> conceptoDeCobro(MedioPago medio) {
+        return Optional.ofNullable(switch (medio) {
+            case EFECTIVO -> ConceptoContable.EFECTIVO;
+            case TARJETA -> ConceptoContable.TARJETA;
+            case TRANSFERENCIA -> ConceptoContable.TRANSFERENCIA;
+            case QR -> ConceptoContable.QR;
+            case CUENTA_CORRIENTE -> ConceptoContable.CUENTA_CORRIENTE_CLIENTES;
+            case TARJETA_REGALO -> ConceptoContable.TARJETA_REGALO;
+            case A_CUENTA -> null;
+        });
+    }
+
+    /**
+     * Concepto por donde sale la plata de un gasto: una compra a cuenta
+     * corriente queda como deuda con el proveedor.
+     */
+    public static ConceptoContable conceptoDePagoGasto(MedioPago medio) {
+        if (medio == MedioPago.CUENTA_CORRIENTE) {
+            return ConceptoContable.PROVEEDORES;
+        }
+        return conceptoDeCobro(medio)
+            .orElseThrow(() -> new IllegalArgumentException("El medio " + medio + " no paga gastos"));
+    }
+
+    public static ConceptoContable conceptoDeGasto(CategoriaGasto categoria) {
+        return switch (categoria) {
+            case MERCADERIA -> ConceptoContable.GASTO_MERCADERIA;
+            case ALQUILER -> ConceptoContable.GASTO_ALQUILER;
+            case SERVICIOS -> ConceptoContable.GASTO_SERVICIOS;
+            case SUELDOS -> ConceptoContable.GASTO_SUELDOS;
+            case IMPUESTOS -> ConceptoContable.GASTO_IMPUESTOS;
+            case MANTENIMIENTO -> ConceptoContable.GASTO_MANTENIMIENTO;
+            case OTROS -> ConceptoContable.GASTO_OTROS;
+        };
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    /**
+     * @return solo los conceptos con cuenta asignada
+     */
+    public Map<ConceptoContable, String> getCuentas() {
+        return Map.copyOf(cuentas);
+    }
+
+    @Override
+    public String toString() {
+        return String.format("PlanCuentasContable{localId=%s, cuentas=%s}", localId, cuentas);
+    }
+}
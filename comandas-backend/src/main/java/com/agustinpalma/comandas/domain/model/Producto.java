@@ -1,8 +1,17 @@
 package com.agustinpalma.comandas.domain.model;
 
+import com.agustinpalma.comandas.domain.model.DomainEnums.Alergeno;
 import com.agustinpalma.comandas.domain.model.DomainIds.*;
 import java.math.BigDecimal;
+import java.time.LocalTime;
+import java.util.ArrayList;
+import java.util.Collection;
+import java.util.Collections;
+import java.util.EnumSet;
+import java.util.HashSet;
+import java.util.List;
 import java.util.Objects;
+import java.util.Set;
 import java.util.regex.Pattern;
 
 /**
@@ -25,11 +34,32 @@ import java.util.regex.Pattern;
  * Regla crítica de normalización:
  * El disco de carne SOLO puede agregarse como extra a la variante máxima de su grupo.
  * Si se intenta agregar a una variante menor, el sistema convierte automáticamente al siguiente nivel.
+ * 
+ * HU-103: Un producto con componentes de combo se vende a precio cerrado;
+ * el cliente elige una opción por componente al agregarlo al pedido.
+ *
+ * HU-109: Las variantes por tamaño (pizza chica/grande, gaseosa 500/1500) usan
+ * el mismo grupo; etiquetaVariante es el rótulo corto que muestra el POS.
+ *
+ * HU-125: Un producto "86" (agotado) sigue activo en el catálogo pero no se
+ * puede cargar en pedidos hasta que se vuelva a habilitar.
+ *
+ * HU-126: {@link #admiteAutopedido()} define qué aparece en la carta del QR.
+ *
+ * HU-127: {@link #apareceEnCartaDigital()} define qué se publica en la carta
+ * digital; lo que no está disponible se muestra igual, marcado sin stock.
+ *
+ * HU-147: Los alérgenos del producto permiten avisarle al mozo cuando carga
+ * el plato para un comensal que declaró esa alergia.
+ *
+ * HU-161: Un producto con horario solo se ofrece dentro de esa franja; sin
+ * horario propio rige el de su categoría ({@link #franjaVigente(Categoria)}).
  */
 public class Producto {
 
     private static final String COLOR_HEX_DEFAULT = "#FFFFFF";
     private static final Pattern COLOR_HEX_PATTERN = Pattern.compile("^#([A-Fa-f0-9]{6}|[A-Fa-f0-9]{3})$");
+    private static final int ETIQUETA_VARIANTE_MAX = 30;
 
     private final ProductoId id;
     private final LocalId localId;
@@ -43,6 +73,7 @@ public class Producto {
     private boolean esExtra;                        // true si es un extra (huevo, queso, disco, etc.) — reclasificable
     private boolean esModificadorEstructural;       // true si este extra activa normalización de variantes al agregarse
     private Integer cantidadDiscosCarne;            // Define jerarquía de variantes (null si no aplica) — mutable para asignación tardía
+    private String etiquetaVariante;                // HU-109: Rótulo corto dentro del grupo (ej: "Grande", "1.5 L") — null si no aplica
 
     // Clasificación de catálogo
     private CategoriaId categoriaId;                // FK a la categoría del catálogo — nullable para retrocompatibilidad
@@ -54,6 +85,17 @@ public class Producto {
     // HU-22: Gestión de stock
     private int stockActual;                       // Cantidad actual en inventario (puede ser negativo por flexibilidad operativa)
     private boolean controlaStock;                 // Si es false, las operaciones de stock no tienen efecto
+    private Integer stockMinimo;                   // HU-101: Umbral de reposición (null si no se configuró alerta)
+    private boolean agotado;                       // HU-125: Marcado "86" desde una terminal, independiente del stock contado
+
+    // HU-103: Combos
+    private List<ComponenteCombo> componentesCombo = List.of(); // Vacío si el producto no es combo
+
+    // HU-147: Alérgenos
+    private Set<Alergeno> alergenos = Set.of();   // Vacío si no se cargaron
+
+    // HU-161: Disponibilidad por horario
+    private FranjaHoraria franjaHoraria;          // null = sin horario propio (rige el de la categoría)
 
     /**
      * Constructor completo con soporte para variantes, extras y modificadores estructurales.
@@ -286,6 +328,32 @@ public class Producto {
         return grupoVarianteId != null;
     }
 
+    public String getEtiquetaVariante() {
+        return etiquetaVariante;
+    }
+
+    /**
+     * Define el rótulo corto con el que el POS presenta la variante
+     * dentro de su grupo (HU-109). No reemplaza al nombre: la comanda
+     * y los reportes siguen usando el nombre completo.
+     *
+     * @param etiqueta rótulo (null o vacío para quitarlo)
+     * @throws IllegalArgumentException si supera los 30 caracteres
+     */
+    public void definirEtiquetaVariante(String etiqueta) {
+        if (etiqueta == null || etiqueta.isBlank()) {
+            this.etiquetaVariante = null;
+            return;
+        }
+        String limpia = etiqueta.trim();
+        if (limpia.length() > ETIQUETA_VARIANTE_MAX) {
+            throw new IllegalArgumentException(
+                "La etiqueta de la variante no puede superar los " + ETIQUETA_VARIANTE_MAX + " caracteres"
+            );
+        }
+        this.etiquetaVariante = limpia;
+    }
+
     // ============================================
     // Clasificación de catálogo
     // ============================================
@@ -401,6 +469,297 @@ public class Producto {
         this.controlaStock = false;
     }
 
+    // ============================================
+    // HU-101: Alertas de stock mínimo
+    // ============================================
+
+    public Integer getStockMinimo() {
+        return stockMinimo;
+    }
+
+    /**
+     * Define el umbral de stock mínimo a partir del cual el producto
+     * pasa a figurar en el listado "para reponer".
+     *
+     * @param stockMinimo umbral (null para desactivar la alerta)
+     * @throws IllegalArgumentException si el umbral es negativo
+     */
+    public void definirStockMinimo(Integer stockMinimo) {
+        if (stockMinimo != null && stockMinimo < 0) {
+            throw new IllegalArgumentException("El stock mínimo no puede ser negativo");
+        }
+        this.stockMinimo = stockMinimo;
+    }
+
+    /**
+     * Indica si el producto alcanzó o perforó su stock mínimo.
+     * Solo aplica a productos que controlan stock y tienen umbral definido.
+     */
+    public boolean estaBajoStockMinimo() {
+        return controlaStock && stockMinimo != null && stockActual <= stockMinimo;
+    }
+
+    /**
+     * Cantidad sugerida para volver a superar el umbral.
+     * Se calcula como la diferencia hasta el stock mínimo más una unidad,
+     * de modo que tras la reposición el producto salga del listado.
+     *
+     * @return unidades a reponer, o 0 si el producto no está bajo el mínimo
+     */
+    public int calcularCantidadParaReponer() {
+        if (!estaBajoStockMinimo()) {
+            return 0;
+        }
+        return stockMinimo - stockActual + 1;
+    }
+
+    // ============================================
+    // HU-125: Disponibilidad en el momento (86)
+    // ============================================
+
+    public boolean isAgotado() {
+        return agotado;
+    }
+
+    /**
+     * Marca o desmarca el producto como agotado para el servicio en curso.
+     * No toca el stock: sirve también para productos sin control de inventario.
+     */
+    public void marcarAgotado(boolean agotado) {
+        this.agotado = agotado;
+    }
+
+    /**
+     * @throws IllegalStateException si el producto está marcado como agotado
+     */
+    public void validarDisponible() {
+        if (agotado) {
+            throw new IllegalStateException(
+                String.format("No hay más '%s' (86). Avisale al cliente o elegí otro producto", nombre)
+            );
+        }
+    }
+
+    // ============================================
+    // HU-126: Autopedido por QR
+    // ============================================
+
+    /**
+     * Indica si el cliente puede pedir este producto solo, desde la carta del QR.
+     *
+     * Quedan afuera los extras y modificadores (se agregan sobre otro ítem),
+     * lo que no está disponible y los combos con opciones a elegir, que
+     * siguen pasando por el mozo.
+     */
+    public boolean admiteAutopedido() {
+        return apareceEnCartaDigital()
+            && estaDisponible()
+            && componentesCombo.stream().allMatch(ComponenteCombo::esFijo);
+    }
+
+    // ============================================
+    // HU-127: Carta digital
+    // ============================================
+
+    /**
+     * Indica si el producto se publica en la carta digital.
+     * Los extras y modificadores no se venden solos, así que no se listan.
+     */
+    public boolean apareceEnCartaDigital() {
+        return activo && !esExtra && !esModificadorEstructural;
+    }
+
+    /**
+     * Indica si hoy se puede vender: no está marcado 86 ni se quedó sin stock contado.
+     */
+    public boolean estaDisponible() {
+        boolean sinStock = controlaStock && stockActual <= 0;
+        return !agotado && !sinStock;
+    }
+
+    // ============================================
+    // HU-103: Combos y menú del día
+    // ============================================
+
+    /**
+     * Componentes del combo en el orden en que se arman (ej: Principal, Acompañamiento, Bebida).
+     *
+     * @return lista inmutable, vacía si el producto no es combo
+     */
+    public List<ComponenteCombo> getComponentesCombo() {
+        return componentesCombo;
+    }
+
+    public boolean esCombo() {
+        return !componentesCombo.isEmpty();
+    }
+
+    /**
+     * Define (o reemplaza) los componentes del combo.
+     * Una lista vacía o null convierte el producto en un producto simple.
+     *
+     * @param componentes componentes del combo
+     * @throws IllegalArgumentException si hay componentes con nombre repetido
+     *         o si el combo se incluye a sí mismo como opción
+     */
+    public void definirComponentesCombo(List<ComponenteCombo> componentes) {
+        if (componentes == null || componentes.isEmpty()) {
+            this.componentesCombo = List.of();
+            return;
+        }
+        Set<String> nombres = new HashSet<>();
+        for (ComponenteCombo componente : componentes) {
+            Objects.requireNonNull(componente, "Los componentes del combo no pueden ser null");
+            if (!nombres.add(componente.getNombre().toLowerCase())) {
+                throw new IllegalArgumentException(
+                    String.format("El combo tiene el componente '%s' repetido", componente.getNombre())
+                );
+            }
+            if (componente.admite(id)) {
+                throw new IllegalArgumentException("Un combo no puede incluirse a sí mismo como opción");
+            }
+        }
+        this.componentesCombo = List.copyOf(componentes);
+    }
+
+    /**
+     * Valida la elección del cliente y arma el snapshot de cada componente.
+     *
+     * Los productos elegidos se reciben en el mismo orden que los componentes.
+     *
+     * @param elegidos productos elegidos, uno por componente
+     * @return selecciones snapshot en el orden de los componentes
+     * @throws IllegalStateException si el producto no es combo
+     * @throws IllegalArgumentException si falta una elección o un producto no es opción de su componente
+     */
+    public List<SeleccionCombo> armarSeleccionCombo(List<Producto> elegidos) {
+        if (!esCombo()) {
+            throw new IllegalStateException(
+                String.format("El producto '%s' no es un combo", nombre)
+            );
+        }
+
+        if (elegidos == null || elegidos.size() != componentesCombo.size()) {
+            throw new IllegalArgumentException(
+                String.format("El combo '%s' requiere elegir una opción para cada uno de sus %d componentes",
+                    nombre, componentesCombo.size())
+            );
+        }
+
+        List<SeleccionCombo> selecciones = new ArrayList<>();
+        for (int i = 0; i < componentesCombo.size(); i++) {
+            ComponenteCombo componente = componentesCombo.get(i);
+            Producto elegido = Objects.requireNonNull(elegidos.get(i), "El producto elegido no puede ser null");
+            if (!componente.admite(elegido.getId())) {
+                throw new IllegalArgumentException(
+                    String.format("'%s' no es una opción válida para '%s' en el combo '%s'",
+                        elegido.getNombre(), componente.getNombre(), nombre)
+                );
+            }
+            selecciones.add(SeleccionCombo.de(componente, elegido));
+        }
+        return List.copyOf(selecciones);
+    }
+
+    // ============================================
+    // HU-147: Alérgenos
+    // ============================================
+
+    /**
+     * @return alérgenos del producto (inmutable, vacío si no se cargaron)
+     */
+    public Set<Alergeno> getAlergenos() {
+        return alergenos;
+    }
+
+    /**
+     * Reemplaza los alérgenos del producto.
+     *
+     * @param alergenos los alérgenos que contiene (null o vacío para quitarlos)
+     */
+    public void definirAlergenos(Collection<Alergeno> alergenos) {
+        this.alergenos = alergenos == null || alergenos.isEmpty()
+            ? Set.of()
+            : Collections.unmodifiableSet(EnumSet.copyOf(alergenos));
+    }
+
+    // ============================================
+    // HU-161: Disponibilidad por horario
+    // ============================================
+
+    public FranjaHoraria getFranjaHoraria() {
+        return franjaHoraria;
+    }
+
+    /**
+     * @param franjaHoraria horario en que se ofrece (null para quitarlo y usar el de la categoría)
+     */
+    public void definirFranjaHoraria(FranjaHoraria franjaHoraria) {
+        this.franjaHoraria = franjaHoraria;
+    }
+
+    /**
+     * El horario propio manda sobre el de la categoría: un café puede quedar
+     * fuera del horario de "Desayunos".
+     *
+     * @param categoria categoría del producto (null si no tiene o no se encontró)
+     * @return la franja que rige, o null si se ofrece todo el día
+     */
+    public FranjaHoraria franjaVigente(Categoria categoria) {
+        if (franjaHoraria != null) {
+            return franjaHoraria;
+        }
+        return categoria != null ? categoria.getFranjaHoraria() : null;
+    }
+
+    /**
+     * Indica si a esta hora el producto se ofrece (no dice nada del stock ni del 86).
+     */
+    public boolean seOfreceA(LocalTime hora, Categoria categoria) {
+        FranjaHoraria franja = franjaVigente(categoria);
+        return franja == null || franja.incluye(hora);
+    }
+
+    /**
+     * @throws IllegalStateException si a esta hora el producto no se ofrece
+     */
+    public void validarEnHorario(LocalTime hora, Categoria categoria) {
+        if (!seOfreceA(hora, categoria)) {
+            throw new IllegalStateException(
+                String.format("'%s' se ofrece %s. Elegí otro producto", nombre, franjaVigente(categoria))
+            );
+        }
+    }
+
+    // ============================================
+    // HU-108: Listas de precios por canal
+    // ============================================
+
+    /**
+     * Copia del producto con el precio de una lista de precios.
+     *
+     * La copia solo se usa para cotizar un ítem del pedido: los factories de
+     * ItemPedido/ExtraPedido y el motor de promociones leen getPrecio(), así
+     * el precio de la lista queda congelado en el snapshot sin tocar el catálogo.
+     *
+     * @param precioLista precio del producto en la lista del canal
+     * @return copia no persistible con el precio reemplazado
+     */
+    public Producto conPrecioDeLista(BigDecimal precioLista) {
+        Producto copia = new Producto(
+            id, localId, nombre, precioLista, activo, colorHex,
+            grupoVarianteId, esExtra, esModificadorEstructural, cantidadDiscosCarne,
+            categoriaId, permiteExtras, requiereConfiguracion, stockActual, controlaStock
+        );
+        copia.stockMinimo = this.stockMinimo;
+        copia.agotado = this.agotado;
+        copia.etiquetaVariante = this.etiquetaVariante;
+        copia.componentesCombo = this.componentesCombo;
+        copia.alergenos = this.alergenos;
+        copia.franjaHoraria = this.franjaHoraria;
+        return copia;
+    }
+
     @Override
     public boolean equals(Object o) {
         if (this == o) return true;
@@ -0,0 +1,145 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Configuración del programa de puntos de un local.
+ * Hay a lo sumo una por local: la identidad es el propio LocalId.
+ *
+ * HU-105: Programa de puntos de clientes.
+ *
+ * Reglas de negocio:
+ * - montoPorPunto: pesos que hay que gastar para ganar 1 punto (mayor a cero)
+ * - valorPunto: pesos de descuento que vale 1 punto al canjearlo (mayor a cero)
+ * - diasVencimiento: vida de los puntos desde que se ganan (null = no vencen)
+ * - Con el programa inactivo no se acumulan ni se canjean puntos,
+ *   pero el saldo de los clientes se conserva
+ *
+ * Ejemplo: montoPorPunto = 100 y valorPunto = 1 → un pedido de $4.550
+ * suma 45 puntos, que luego descuentan $45.
+ */
+public class ProgramaPuntos {
+
+    private final LocalId localId;
+    private boolean activo;
+    private BigDecimal montoPorPunto;
+    private BigDecimal valorPunto;
+    private Integer diasVencimiento;
+
+    public ProgramaPuntos(
+            LocalId localId,
+            boolean activo,
+            BigDecimal montoPorPunto,
+            BigDecimal valorPunto,
+            Integer diasVencimiento
+    ) {
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.activo = activo;
+        this.montoPorPunto = validarPositivo(montoPorPunto, "El monto por punto");
+        this.valorPunto = validarPositivo(valorPunto, "El valor del punto");
+        this.diasVencimiento = validarDiasVencimiento(diasVencimiento);
+    }
+
+    /**
+     * Programa por defecto de un local que todavía no lo configuró:
+     * inactivo, 1 punto cada $100 y cada punto vale $1, sin vencimiento.
+     */
+    public static ProgramaPuntos porDefecto(LocalId localId) {
+        return new ProgramaPuntos(localId, false, new BigDecimal("100"), BigDecimal.ONE, null);
+    }
+
+    // ============================================
+    // Validaciones
+    // ============================================
+
+    private BigDecimal validarPositivo(BigDecimal valor, String campo) {
+        if (valor == null) {
+            throw new IllegalArgumentException(campo + " es obligatorio");
+        }
+        if (valor.compareTo(BigDecimal.ZERO) <= 0) {
+            throw new IllegalArgumentException(campo + " debe ser mayor a cero");
+        }
+        return valor;
+    }
+
+    private Integer validarDiasVencimiento(Integer diasVencimiento) {
+        if (diasVencimiento != null && diasVencimiento <= 0) {
+            throw new IllegalArgumentException("Los días de vencimiento deben ser mayores a cero (o vacío para que no venzan)");
+        }
+        return diasVencimiento;
+    }
+
+    // ============================================
+    // Comportamiento
+    // ============================================
+
+    public void actualizar(boolean activo, BigDecimal montoPorPunto, BigDecimal valorPunto, Integer diasVencimiento) {
+        this.montoPorPunto = validarPositivo(montoPorPunto, "El monto por punto");
+        this.valorPunto = validarPositivo(valorPunto, "El valor del punto");
+        this.diasVencimiento = validarDiasVencimiento(diasVencimiento);
+        this.activo = activo;
+    }
+
+    /**
+     * Puntos que se ganan por un importe gastado. Se redondea hacia abajo:
+     * no se otorgan fracciones de punto.
+     */
+    public int puntosPorMonto(BigDecimal monto) {
+        Objects.requireNonNull(monto, "El monto no puede ser null");
+        if (monto.signum() <= 0) {
+            return 0;
+        }
+        return monto.divide(montoPorPunto, 0, RoundingMode.FLOOR).intValueExact();
+    }
+
+    /**
+     * Descuento en pesos equivalente a una cantidad de puntos.
+     */
+    public BigDecimal valorDe(int puntos) {
+        return valorPunto.multiply(BigDecimal.valueOf(puntos));
+    }
+
+    /**
+     * Fecha de vencimiento de los puntos ganados en una fecha dada,
+     * o null si el programa no tiene vencimiento.
+     */
+    public LocalDateTime calcularVencimiento(LocalDateTime fechaAcumulacion) {
+        Objects.requireNonNull(fechaAcumulacion, "La fecha de acumulación no puede ser null");
+        return diasVencimiento != null ? fechaAcumulacion.plusDays(diasVencimiento) : null;
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public boolean isActivo() {
+        return activo;
+    }
+
+    public BigDecimal getMontoPorPunto() {
+        return montoPorPunto;
+    }
+
+    public BigDecimal getValorPunto() {
+        return valorPunto;
+    }
+
+    public Integer getDiasVencimiento() {
+        return diasVencimiento;
+    }
+
+    @Override
+    public String toString() {
+        return String.format("ProgramaPuntos{localId=%s, activo=%s, montoPorPunto=%s, valorPunto=%s, diasVencimiento=%s}",
+            localId, activo, montoPorPunto, valorPunto, diasVencimiento);
+    }
+}
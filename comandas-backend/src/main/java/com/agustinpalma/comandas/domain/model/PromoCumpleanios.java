@@ -0,0 +1,116 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.util.Objects;
+
+/**
+ * Configuración de la promo de cumpleaños de un local.
+ * Hay a lo sumo una por local: la identidad es el propio LocalId.
+ *
+ * HU-168: Ficha de clientes con promo de cumpleaños.
+ *
+ * Si está activa, el pedido que cierra un cliente en la semana de su
+ * cumpleaños (de lunes a domingo) tiene un descuento automático sobre el
+ * total, una sola vez por cumpleaños.
+ *
+ * Reglas de negocio:
+ * - porcentaje: descuento sobre el total del pedido (entre 1 y 100)
+ * - topeDescuento: descuento máximo en pesos (null = sin tope)
+ */
+public class PromoCumpleanios {
+
+    private static final BigDecimal CIEN = new BigDecimal("100");
+
+    private final LocalId localId;
+    private boolean activa;
+    private BigDecimal porcentaje;
+    private BigDecimal topeDescuento;
+
+    public PromoCumpleanios(LocalId localId, boolean activa, BigDecimal porcentaje, BigDecimal topeDescuento) {
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.activa = activa;
+        this.porcentaje = validarPorcentaje(porcentaje);
+        this.topeDescuento = validarTope(topeDescuento);
+    }
+
+    /**
+     * Promo por defecto de un local que todavía no la configuró:
+     * inactiva, 10% sin tope.
+     */
+    public static PromoCumpleanios porDefecto(LocalId localId) {
+        return new PromoCumpleanios(localId, false, BigDecimal.TEN, null);
+    }
+
+    // ============================================
+    // Validaciones
+    // ============================================
+
+    private BigDecimal validarPorcentaje(BigDecimal porcentaje) {
+        if (porcentaje == null) {
+            throw new IllegalArgumentException("El porcentaje de la promo es obligatorio");
+        }
+        if (porcentaje.compareTo(BigDecimal.ONE) < 0 || porcentaje.compareTo(CIEN) > 0) {
+            throw new IllegalArgumentException("El porcentaje de la promo debe estar entre 1 y 100");
+        }
+        return porcentaje;
+    }
+
+    private BigDecimal validarTope(BigDecimal topeDescuento) {
+        if (topeDescuento != null && topeDescuento.signum() <= 0) {
+            throw new IllegalArgumentException("El tope de descuento debe ser mayor a cero (o vacío para no tener tope)");
+        }
+        return topeDescuento;
+    }
+
+    // ============================================
+    // Comportamiento
+    // ============================================
+
+    public void actualizar(boolean activa, BigDecimal porcentaje, BigDecimal topeDescuento) {
+        this.porcentaje = validarPorcentaje(porcentaje);
+        this.topeDescuento = validarTope(topeDescuento);
+        this.activa = activa;
+    }
+
+    /**
+     * Descuento que corresponde sobre un total, con el tope aplicado.
+     * Se redondea a centavos.
+     */
+    public BigDecimal calcularDescuento(BigDecimal total) {
+        Objects.requireNonNull(total, "El total no puede ser null");
+        if (total.signum() <= 0) {
+            return BigDecimal.ZERO;
+        }
+        BigDecimal descuento = total.multiply(porcentaje).divide(CIEN, 2, RoundingMode.HALF_UP);
+        return topeDescuento != null ? descuento.min(topeDescuento) : descuento;
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public boolean isActiva() {
+        return activa;
+    }
+
+    public BigDecimal getPorcentaje() {
+        return porcentaje;
+    }
+
+    public BigDecimal getTopeDescuento() {
+        return topeDescuento;
+    }
+
+    @Override
+    public String toString() {
+        return String.format("PromoCumpleanios{localId=%s, activa=%s, porcentaje=%s, topeDescuento=%s}",
+            localId, activa, porcentaje, topeDescuento);
+    }
+}
@@ -0,0 +1,181 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+
+import java.time.LocalDate;
+import java.util.Objects;
+import java.util.regex.Pattern;
+
+/**
+ * Proveedor al que el local le compra a cuenta corriente.
+ *
+ * HU-131: Cuenta corriente de proveedores.
+ *
+ * Reglas de negocio:
+ * - El nombre es obligatorio (máximo 100 caracteres, igual que el proveedor
+ *   de un gasto) y no se repite en el local.
+ * - El CUIT es opcional; si se carga, tiene que ser válido.
+ * - HU-134: El email es opcional; si se carga, tiene que tener formato de email.
+ * - El plazo de pago (0 a 180 días) define el vencimiento de una factura
+ *   cuando no se indica otro al registrar la compra.
+ * - No se borra: se desactiva y deja de ofrecerse para compras nuevas,
+ *   pero conserva su cuenta corriente.
+ */
+public class Proveedor {
+
+    public static final int PLAZO_PAGO_POR_DEFECTO = 30;
+
+    private static final int LONGITUD_MAXIMA_NOMBRE = 100;
+    private static final int LONGITUD_MAXIMA_TELEFONO = 30;
+    private static final int LONGITUD_MAXIMA_EMAIL = 100;
+    private static final Pattern FORMATO_EMAIL = Pattern.compile("^[^@\\s]+@[^@\\s]+\\.[^@\\s]+$");
+    private static final int PLAZO_PAGO_MAXIMO = 180;
+
+    private final ProveedorId id;
+    private final LocalId localId;
+    private String nombre;
+    private Cuit cuit;
+    private String telefono;
+    private String email;
+    private int plazoPagoDias;
+    private boolean activo;
+
+    public Proveedor(ProveedorId id, LocalId localId, String nombre, Cuit cuit, String telefono,
+                     int plazoPagoDias, boolean activo) {
+        this(id, localId, nombre, cuit, telefono, null, plazoPagoDias, activo);
+    }
+
+    public Proveedor(ProveedorId id, LocalId localId, String nombre, Cuit cuit, String telefono, String email,
+                     int plazoPagoDias, boolean activo) {
+        this.id = Objects.requireNonNull(id, "El id del proveedor no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.nombre = validarNombre(nombre);
+        this.cuit = cuit;
+        this.telefono = validarTelefono(telefono);
+        this.email = validarEmail(email);
+        this.plazoPagoDias = validarPlazo(plazoPagoDias);
+        this.activo = activo;
+    }
+
+    public static Proveedor crear(LocalId localId, String nombre, Cuit cuit, String telefono, int plazoPagoDias) {
+        return crear(localId, nombre, cuit, telefono, null, plazoPagoDias);
+    }
+
+    public static Proveedor crear(LocalId localId, String nombre, Cuit cuit, String telefono, String email,
+                                  int plazoPagoDias) {
+        return new Proveedor(ProveedorId.generate(), localId, nombre, cuit, telefono, email, plazoPagoDias, true);
+    }
+
+    public void actualizar(String nombre, Cuit cuit, String telefono, String email, int plazoPagoDias,
+                           boolean activo) {
+        this.nombre = validarNombre(nombre);
+        this.cuit = cuit;
+        this.telefono = validarTelefono(telefono);
+        this.email = validarEmail(email);
+        this.plazoPagoDias = validarPlazo(plazoPagoDias);
+        this.activo = activo;
+    }
+
+    /**
+     * Vencimiento de una factura emitida en la fecha indicada, según el plazo pactado.
+     */
+    public LocalDate vencimientoPara(LocalDate fechaEmision) {
+        return fechaEmision.plusDays(plazoPagoDias);
+    }
+
+    public boolean tieneNombre(String otroNombre) {
+        return otroNombre != null && nombre.equalsIgnoreCase(otroNombre.trim());
+    }
+
+    private String validarNombre(String nombre) {
+        if (nombre == null || nombre.isBlank()) {
+            throw new IllegalArgumentException("El nombre del proveedor no puede estar vacío");
+        }
+        String limpio = nombre.trim();
+        if (limpio.length() > LONGITUD_MAXIMA_NOMBRE) {
+            throw new IllegalArgumentException(
+                "El nombre del proveedor no puede superar los " + LONGITUD_MAXIMA_NOMBRE + " caracteres"
+            );
+        }
+        return limpio;
+    }
+
+    private String validarTelefono(String telefono) {
+        if (telefono == null || telefono.isBlank()) {
+            return null;
+        }
+        String limpio = telefono.trim();
+        if (limpio.length() > LONGITUD_MAXIMA_TELEFONO) {
+            throw new IllegalArgumentException(
+                "El teléfono no puede superar los " + LONGITUD_MAXIMA_TELEFONO + " caracteres"
+            );
+        }
+        return limpio;
+    }
+
+    private String validarEmail(String email) {
+        if (email == null || email.isBlank()) {
+            return null;
+        }
+        String limpio = email.trim();
+        if (limpio.length() > LONGITUD_MAXIMA_EMAIL || !FORMATO_EMAIL.matcher(limpio).matches()) {
+            throw new IllegalArgumentException("El email del proveedor no es válido: " + limpio);
+        }
+        return limpio;
+    }
+
+    private int validarPlazo(int plazoPagoDias) {
+        if (plazoPagoDias < 0 || plazoPagoDias > PLAZO_PAGO_MAXIMO) {
+            throw new IllegalArgumentException(
+                "El plazo de pago debe estar entre 0 y " + PLAZO_PAGO_MAXIMO + " días"
+            );
+        }
+        return plazoPagoDias;
+    }
+
+    public ProveedorId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public Cuit getCuit() {
+        return cuit;
+    }
+
+    public String getTelefono() {
+        return telefono;
+    }
+
+    public String getEmail() {
+        return email;
+    }
+
+    public int getPlazoPagoDias() {
+        return plazoPagoDias;
+    }
+
+    public boolean isActivo() {
+        return activo;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        Proveedor that = (Proveedor) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
@@ -0,0 +1,136 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.LocalDateTime;
+import java.util.EnumMap;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Set;
+
+/**
+ * Recargos porcentuales por medio de pago de un local.
+ * Hay a lo sumo una configuración por local: la identidad es el propio LocalId.
+ *
+ * HU-143: Recargo configurable por medio de pago.
+ *
+ * Reglas de negocio:
+ * - Cada medio comercial puede tener un recargo entre 0% y 100%, con hasta
+ *   dos decimales. Un medio sin configurar no tiene recargo.
+ * - El consumo interno (A_CUENTA), la cuenta corriente y la tarjeta de regalo
+ *   (ya cobrada al venderla) no llevan recargo.
+ * - El recargo se calcula sobre el monto de cada pago, al centavo, y se cobra
+ *   encima: el pago sigue cubriendo la misma parte del total del pedido.
+ * - Los cambios solo afectan a los cobros que se hagan desde ahora; lo ya
+ *   cobrado queda congelado en cada pago.
+ *
+ * Ejemplo: TARJETA = 10 → un pago de $8.000 con tarjeta cobra $800 de
+ * recargo; el cliente paga $8.800.
+ */
+public class RecargosMedioPago {
+
+    private static final BigDecimal CIEN = new BigDecimal("100");
+    private static final Set<MedioPago> SIN_RECARGO = Set.of(
+        MedioPago.A_CUENTA, MedioPago.CUENTA_CORRIENTE, MedioPago.TARJETA_REGALO);
+
+    private final LocalId localId;
+    private Map<MedioPago, BigDecimal> porcentajes;
+
+    public RecargosMedioPago(LocalId localId, Map<MedioPago, BigDecimal> porcentajes) {
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.porcentajes = validarPorcentajes(porcentajes);
+    }
+
+    /**
+     * Configuración de un local que todavía no cargó recargos.
+     */
+    public static RecargosMedioPago sinRecargos(LocalId localId) {
+        return new RecargosMedioPago(localId, Map.of());
+    }
+
+    // ============================================
+    // Validaciones
+    // ============================================
+
+    private Map<MedioPago, BigDecimal> validarPorcentajes(Map<MedioPago, BigDecimal> porcentajes) {
+        Objects.requireNonNull(porcentajes, "Los porcentajes de recargo son obligatorios");
+        Map<MedioPago, BigDecimal> limpios = new EnumMap<>(MedioPago.class);
+        porcentajes.forEach((medio, porcentaje) -> {
+            Objects.requireNonNull(medio, "El medio de pago del recargo no puede ser null");
+            if (porcentaje == null || porcentaje.signum() == 0) {
+                return;
+            }
+            if (SIN_RECARGO.contains(medio)) {
+                throw new IllegalArgumentException("El medio " + medio + " no admite recargo");
+            }
+            if (porcentaje.signum() < 0 || porcentaje.compareTo(CIEN) > 0) {
+                throw new IllegalArgumentException(
+                    "El recargo de " + medio + " tiene que estar entre 0% y 100%");
+            }
+            if (porcentaje.stripTrailingZeros().scale() > 2) {
+                throw new IllegalArgumentException(
+                    "El recargo de " + medio + " admite hasta dos decimales");
+            }
+            limpios.put(medio, porcentaje);
+        });
+        return limpios;
+    }
+
+    // ============================================
+    // Comportamiento
+    // ============================================
+
+    public void actualizar(Map<MedioPago, BigDecimal> porcentajes) {
+        this.porcentajes = validarPorcentajes(porcentajes);
+    }
+
+    /**
+     * @return el porcentaje del medio, cero si no tiene recargo
+     */
+    public BigDecimal porcentajeDe(MedioPago medio) {
+        return porcentajes.getOrDefault(medio, BigDecimal.ZERO);
+    }
+
+    /**
+     * Recargo que corresponde a un pago de ese medio, redondeado al centavo.
+     */
+    public BigDecimal calcularRecargo(MedioPago medio, BigDecimal monto) {
+        Objects.requireNonNull(medio, "El medio de pago es obligatorio");
+        Objects.requireNonNull(monto, "El monto es obligatorio");
+        BigDecimal porcentaje = porcentajeDe(medio);
+        if (porcentaje.signum() == 0) {
+            return BigDecimal.ZERO;
+        }
+        return monto.multiply(porcentaje).divide(CIEN, 2, RoundingMode.HALF_UP);
+    }
+
+    /**
+     * Arma el pago con el recargo vigente de su medio congelado.
+     */
+    public Pago cobrar(MedioPago medio, BigDecimal monto, LocalDateTime fecha) {
+        return new Pago(medio, monto, fecha, calcularRecargo(medio, monto));
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    /**
+     * @return solo los medios con recargo mayor a cero
+     */
+    public Map<MedioPago, BigDecimal> getPorcentajes() {
+        return Map.copyOf(porcentajes);
+    }
+
+    @Override
+    public String toString() {
+        return String.format("RecargosMedioPago{localId=%s, porcentajes=%s}", localId, porcentajes);
+    }
+}
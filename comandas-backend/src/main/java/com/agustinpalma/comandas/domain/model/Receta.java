@@ -0,0 +1,90 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.util.HashSet;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Set;
+
+/**
+ * Receta de un producto vendible: los insumos que lleva una unidad.
+ *
+ * HU-118: Costeo de recetas y rentabilidad por producto.
+ *
+ * Reglas de negocio:
+ * - Un producto tiene a lo sumo una receta (se identifica por el producto).
+ * - Debe tener al menos un ingrediente; una receta vacía se elimina.
+ * - Un insumo no puede aparecer dos veces: se suma en un solo renglón.
+ * - El costo teórico es la suma de cantidad × costo unitario de cada
+ *   insumo, al costo vigente (no hay costo histórico).
+ */
+public class Receta {
+
+    private final ProductoId productoId;
+    private final LocalId localId;
+    private List<IngredienteReceta> ingredientes;
+
+    public Receta(ProductoId productoId, LocalId localId, List<IngredienteReceta> ingredientes) {
+        this.productoId = Objects.requireNonNull(productoId, "El productoId no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.ingredientes = validarIngredientes(ingredientes);
+    }
+
+    public void reemplazarIngredientes(List<IngredienteReceta> ingredientes) {
+        this.ingredientes = validarIngredientes(ingredientes);
+    }
+
+    private List<IngredienteReceta> validarIngredientes(List<IngredienteReceta> ingredientes) {
+        if (ingredientes == null || ingredientes.isEmpty()) {
+            throw new IllegalArgumentException("La receta debe tener al menos un ingrediente");
+        }
+        Set<InsumoId> vistos = new HashSet<>();
+        for (IngredienteReceta ingrediente : ingredientes) {
+            Objects.requireNonNull(ingrediente, "Los ingredientes de la receta no pueden ser null");
+            if (!vistos.add(ingrediente.getInsumoId())) {
+                throw new IllegalArgumentException("La receta tiene un insumo repetido");
+            }
+        }
+        return List.copyOf(ingredientes);
+    }
+
+    /**
+     * Costo teórico de una unidad del producto, redondeado al centavo.
+     *
+     * @param insumos insumos del local indexados por id
+     * @throws IllegalStateException si la receta referencia un insumo que no está en el mapa
+     */
+    public BigDecimal calcularCosto(Map<InsumoId, Insumo> insumos) {
+        BigDecimal costo = BigDecimal.ZERO;
+        for (IngredienteReceta ingrediente : ingredientes) {
+            Insumo insumo = insumos.get(ingrediente.getInsumoId());
+            if (insumo == null) {
+                throw new IllegalStateException("La receta usa un insumo que no existe en el local");
+            }
+            costo = costo.add(insumo.getCostoUnitario().multiply(ingrediente.getCantidad()));
+        }
+        return costo.setScale(2, RoundingMode.HALF_UP);
+    }
+
+    public boolean usaInsumo(InsumoId insumoId) {
+        return ingredientes.stream().anyMatch(i -> i.getInsumoId().equals(insumoId));
+    }
+
+    public ProductoId getProductoId() {
+        return productoId;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public List<IngredienteReceta> getIngredientes() {
+        return ingredientes;
+    }
+}
@@ -0,0 +1,163 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.ModoRedondeo;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Regla de redondeo del efectivo de un local.
+ * Hay a lo sumo una configuración por local: la identidad es el propio LocalId.
+ *
+ * HU-152: Reglas de redondeo configurables en el total.
+ *
+ * Reglas de negocio:
+ * - Solo se redondea lo que el cliente paga en efectivo en pesos; tarjeta,
+ *   transferencia y moneda extranjera se cobran al centavo.
+ * - El redondeo es al múltiplo configurado (mayor a cero, hasta $1.000 y con
+ *   hasta dos decimales), hacia el más cercano, hacia abajo o hacia arriba.
+ *   Sin múltiplo configurado no se redondea.
+ * - El total del pedido no cambia: la diferencia queda como ajuste por
+ *   redondeo en el pago en efectivo, un renglón contable aparte.
+ * - El efectivo nunca se redondea a cero.
+ *
+ * Ejemplo: múltiplo 100, CERCANO → un efectivo de $12.340 se cobra $12.300
+ * y queda un ajuste de −$40.
+ */
+public class RedondeoEfectivo {
+
+    private static final BigDecimal MULTIPLO_MAXIMO = new BigDecimal("1000");
+
+    private final LocalId localId;
+    private BigDecimal multiplo;
+    private ModoRedondeo modo;
+
+    /**
+     * @param multiplo null para no redondear
+     * @param modo     null equivale a CERCANO
+     */
+    public RedondeoEfectivo(LocalId localId, BigDecimal multiplo, ModoRedondeo modo) {
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        actualizar(multiplo, modo);
+    }
+
+    /**
+     * Configuración de un local que todavía no cargó redondeo: se cobra al centavo.
+     */
+    public static RedondeoEfectivo sinRedondeo(LocalId localId) {
+        return new RedondeoEfectivo(localId, null, ModoRedondeo.CERCANO);
+    }
+
+    // ============================================
+    // Validaciones
+    // ============================================
+
+    private BigDecimal validarMultiplo(BigDecimal multiplo) {
+        if (multiplo == null || multiplo.signum() == 0) {
+            return null;
+        }
+        if (multiplo.signum() < 0 || multiplo.compareTo(MULTIPLO_MAXIMO) > 0) {
+            throw new IllegalArgumentException("El múltiplo de redondeo tiene que ser mayor a cero y de hasta $1000");
+        }
+        if (multiplo.stripTrailingZeros().scale() > 2) {
+            throw new IllegalArgumentException("El múltiplo de redondeo admite hasta dos decimales");
+        }
+        return multiplo;
+    }
+
+    // ============================================
+    // Comportamiento
+    // ============================================
+
+    public void actualizar(BigDecimal multiplo, ModoRedondeo modo) {
+        this.multiplo = validarMultiplo(multiplo);
+        this.modo = modo != null ? modo : ModoRedondeo.CERCANO;
+    }
+
+    public boolean estaActivo() {
+        return multiplo != null;
+    }
+
+    /**
+     * Lo que hay que sumar (o restar) al efectivo para llegar al múltiplo.
+     *
+     * @return cero si no hay redondeo configurado o si redondear dejaría el efectivo en cero
+     */
+    public BigDecimal calcularAjuste(BigDecimal efectivo) {
+        Objects.requireNonNull(efectivo, "El efectivo es obligatorio");
+        if (!estaActivo() || efectivo.signum() <= 0) {
+            return BigDecimal.ZERO;
+        }
+        BigDecimal redondeado = efectivo.divide(multiplo, 0, roundingMode()).multiply(multiplo);
+        if (redondeado.signum() <= 0) {
+            return BigDecimal.ZERO;
+        }
+        return redondeado.subtract(efectivo).setScale(2, RoundingMode.HALF_UP);
+    }
+
+    /**
+     * Redondea el efectivo en pesos de un cobro. El ajuste va en el pago en
+     * efectivo más grande; los demás pagos quedan igual.
+     */
+    public List<Pago> aplicar(List<Pago> pagos) {
+        Objects.requireNonNull(pagos, "Los pagos son obligatorios");
+        List<Pago> enEfectivo = pagos.stream()
+            .filter(p -> p.getMedio() == MedioPago.EFECTIVO && !p.esMonedaExtranjera())
+            .toList();
+        if (enEfectivo.isEmpty()) {
+            return pagos;
+        }
+        BigDecimal efectivo = enEfectivo.stream()
+            .map(Pago::getMontoCobrado)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+        BigDecimal ajuste = calcularAjuste(efectivo);
+        if (ajuste.signum() == 0) {
+            return pagos;
+        }
+        Pago mayor = enEfectivo.stream().max(Comparator.comparing(Pago::getMontoCobrado)).orElseThrow();
+        if (mayor.getMontoCobrado().add(ajuste).signum() <= 0) {
+            return pagos;
+        }
+        List<Pago> redondeados = new ArrayList<>(pagos);
+        redondeados.set(pagos.indexOf(mayor), mayor.conAjusteRedondeo(ajuste));
+        return List.copyOf(redondeados);
+    }
+
+    private RoundingMode roundingMode() {
+        return switch (modo) {
+            case CERCANO -> RoundingMode.HALF_UP;
+            case ABAJO -> RoundingMode.FLOOR;
+            case ARRIBA -> RoundingMode.CEILING;
+        };
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    /**
+     * @return null si no hay redondeo configurado
+     */
+    public BigDecimal getMultiplo() {
+        return multiplo;
+    }
+
+    public ModoRedondeo getModo() {
+        return modo;
+    }
+
+    @Override
+    public String toString() {
+        return String.format("RedondeoEfectivo{localId=%s, multiplo=%s, modo=%s}", localId, multiplo, modo);
+    }
+}
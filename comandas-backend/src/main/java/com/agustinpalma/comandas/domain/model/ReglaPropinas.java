@@ -0,0 +1,129 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ModoRepartoPropinas;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.util.EnumMap;
+import java.util.Map;
+import java.util.Objects;
+
+/**
+ * Regla de reparto del pozo de propinas de un local.
+ * Hay a lo sumo una por local: la identidad es el propio LocalId.
+ *
+ * HU-141: Pozo de propinas y distribución.
+ *
+ * Reglas de negocio:
+ * - modo: partes iguales, por minutos fichados dentro del turno o por puesto.
+ * - Cada rol tiene puntos (0 a 100). Un rol con cero puntos no participa del
+ *   pozo en ningún modo; en POR_PUESTO los puntos son el peso de cada empleado.
+ * - Al menos un rol tiene que participar.
+ * - Los cambios solo afectan a los turnos que se cierren desde ahora.
+ *
+ * Ejemplo: POR_PUESTO con MOZO = 2 y ENCARGADO = 1 → de un pozo de $9.000
+ * con dos mozos y un encargado, cada mozo cobra $3.600 y el encargado $1.800.
+ */
+public class ReglaPropinas {
+
+    private static final int PUNTOS_MAXIMOS = 100;
+
+    private final LocalId localId;
+    private ModoRepartoPropinas modo;
+    private Map<RolPersonal, Integer> puntosPorRol;
+
+    public ReglaPropinas(LocalId localId, ModoRepartoPropinas modo, Map<RolPersonal, Integer> puntosPorRol) {
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.modo = Objects.requireNonNull(modo, "El modo de reparto es obligatorio");
+        this.puntosPorRol = validarPuntos(puntosPorRol);
+    }
+
+    /**
+     * Regla de un local que todavía no la configuró: partes iguales entre
+     * mozos y encargados; el dueño y los repartidores no participan.
+     */
+    public static ReglaPropinas porDefecto(LocalId localId) {
+        return new ReglaPropinas(localId, ModoRepartoPropinas.PARTES_IGUALES, Map.of(
+            RolPersonal.MOZO, 1,
+            RolPersonal.ENCARGADO, 1
+        ));
+    }
+
+    // ============================================
+    // Validaciones
+    // ============================================
+
+    private Map<RolPersonal, Integer> validarPuntos(Map<RolPersonal, Integer> puntosPorRol) {
+        Objects.requireNonNull(puntosPorRol, "Los puntos por rol son obligatorios");
+        Map<RolPersonal, Integer> limpios = new EnumMap<>(RolPersonal.class);
+        for (RolPersonal rol : RolPersonal.values()) {
+            Integer puntos = puntosPorRol.get(rol);
+            int valor = puntos != null ? puntos : 0;
+            if (valor < 0 || valor > PUNTOS_MAXIMOS) {
+                throw new IllegalArgumentException(
+                    "Los puntos de " + rol + " tienen que estar entre 0 y " + PUNTOS_MAXIMOS);
+            }
+            limpios.put(rol, valor);
+        }
+        if (limpios.values().stream().allMatch(p -> p == 0)) {
+            throw new IllegalArgumentException("Al menos un puesto tiene que participar del pozo de propinas");
+        }
+        return limpios;
+    }
+
+    // ============================================
+    // Comportamiento
+    // ============================================
+
+    public void actualizar(ModoRepartoPropinas modo, Map<RolPersonal, Integer> puntosPorRol) {
+        Objects.requireNonNull(modo, "El modo de reparto es obligatorio");
+        this.puntosPorRol = validarPuntos(puntosPorRol);
+        this.modo = modo;
+    }
+
+    public boolean participa(RolPersonal rol) {
+        return puntosDe(rol) > 0;
+    }
+
+    public int puntosDe(RolPersonal rol) {
+        return puntosPorRol.getOrDefault(rol, 0);
+    }
+
+    /**
+     * Peso de un empleado en el reparto según el modo.
+     *
+     * @param minutos minutos fichados dentro del turno
+     * @return cero si su rol no participa
+     */
+    public long pesoDe(RolPersonal rol, long minutos) {
+        if (!participa(rol)) {
+            return 0;
+        }
+        return switch (modo) {
+            case PARTES_IGUALES -> 1;
+            case POR_HORAS -> minutos;
+            case POR_PUESTO -> puntosDe(rol);
+        };
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public ModoRepartoPropinas getModo() {
+        return modo;
+    }
+
+    public Map<RolPersonal, Integer> getPuntosPorRol() {
+        return Map.copyOf(puntosPorRol);
+    }
+
+    @Override
+    public String toString() {
+        return String.format("ReglaPropinas{localId=%s, modo=%s, puntosPorRol=%s}", localId, modo, puntosPorRol);
+    }
+}
@@ -0,0 +1,163 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.RendicionRepartidorId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Rendición del efectivo que un repartidor cobró en sus entregas del turno.
+ *
+ * HU-140: Asignación y seguimiento de repartidores.
+ *
+ * Reglas de negocio:
+ * - Se rinde una vez por repartidor y turno, con el turno ABIERTO: lo
+ *   rendido entra al cajón y cuenta en el arqueo del turno.
+ * - No se rinde con pedidos en la calle ni con pedidos que salieron y
+ *   todavía no se cobraron.
+ * - Esperado = lo cobrado en EFECTIVO de los pedidos que llevó en el turno.
+ * - Diferencia = Rendido − Esperado (negativo = faltante).
+ * - Si hay diferencia, las observaciones son obligatorias.
+ */
+public class RendicionRepartidor {
+
+    private static final int LONGITUD_MAXIMA_OBSERVACIONES = 200;
+
+    private final RendicionRepartidorId id;
+    private final LocalId localId;
+    private final TurnoCajaId turnoId;
+    private final MozoId repartidorId;
+    private final LocalDateTime fecha;
+    private final int pedidos;
+    private final BigDecimal esperado;
+    private final BigDecimal rendido;
+    private final String observaciones;
+
+    public RendicionRepartidor(RendicionRepartidorId id, LocalId localId, TurnoCajaId turnoId, MozoId repartidorId,
+                               LocalDateTime fecha, int pedidos, BigDecimal esperado, BigDecimal rendido,
+                               String observaciones) {
+        this.id = Objects.requireNonNull(id, "El id de la rendición no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.turnoId = Objects.requireNonNull(turnoId, "El turno de la rendición no puede ser null");
+        this.repartidorId = Objects.requireNonNull(repartidorId, "El repartidor de la rendición no puede ser null");
+        this.fecha = Objects.requireNonNull(fecha, "La fecha de la rendición no puede ser null");
+        this.pedidos = pedidos;
+        this.esperado = Objects.requireNonNull(esperado, "El efectivo esperado no puede ser null");
+        this.rendido = Objects.requireNonNull(rendido, "El efectivo rendido no puede ser null");
+        this.observaciones = observaciones;
+    }
+
+    /**
+     * Calcula lo que el repartidor tiene que traer y registra lo que entregó.
+     *
+     * @param pedidos pedidos abiertos del local y cerrados del turno; se toman
+     *                los que llevó el repartidor
+     * @throws IllegalStateException si el turno está cerrado, le quedan pedidos en la calle o sin cobrar,
+     *                               o hay diferencia sin observaciones
+     * @throws IllegalArgumentException si lo rendido es negativo
+     */
+    public static RendicionRepartidor rendir(TurnoCaja turno, Mozo repartidor, List<Pedido> pedidos,
+                                             BigDecimal rendido, String observaciones, LocalDateTime fecha) {
+        Objects.requireNonNull(turno, "El turno no puede ser null");
+        Objects.requireNonNull(repartidor, "El repartidor no puede ser null");
+        Objects.requireNonNull(pedidos, "Los pedidos no pueden ser null");
+        Objects.requireNonNull(rendido, "El efectivo rendido es obligatorio");
+        if (!turno.estaAbierto()) {
+            throw new IllegalStateException("El turno está cerrado: el efectivo se rinde antes del cierre");
+        }
+        if (rendido.signum() < 0) {
+            throw new IllegalArgumentException("El efectivo rendido no puede ser negativo");
+        }
+
+        List<Pedido> llevados = pedidos.stream()
+            .filter(p -> repartidor.getId().equals(p.getRepartidorId()) && p.getSalidaReparto() != null)
+            .toList();
+        for (Pedido pedido : llevados) {
+            if (pedido.estaEnReparto()) {
+                throw new IllegalStateException(
+                    repartidor.getNombre() + " todavía está entregando el pedido #" + pedido.getNumero());
+            }
+            if (pedido.getEstado() != EstadoPedido.CERRADO) {
+                throw new IllegalStateException("Cobrá el pedido #" + pedido.getNumero() + " antes de rendir");
+            }
+        }
+
+        List<Pedido> delTurno = llevados.stream()
+            .filter(p -> turno.getId().equals(p.getTurnoId()))
+            .toList();
+        BigDecimal esperado = delTurno.stream()
+            .map(Pedido::calcularCobradoEfectivo)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        String limpias = observaciones != null && !observaciones.isBlank() ? observaciones.trim() : null;
+        if (limpias != null && limpias.length() > LONGITUD_MAXIMA_OBSERVACIONES) {
+            throw new IllegalArgumentException(
+                "Las observaciones no pueden superar los " + LONGITUD_MAXIMA_OBSERVACIONES + " caracteres");
+        }
+        if (rendido.compareTo(esperado) != 0 && limpias == null) {
+            throw new IllegalStateException("La rendición tiene diferencia: explicá el motivo en las observaciones");
+        }
+
+        return new RendicionRepartidor(RendicionRepartidorId.generate(), turno.getLocalId(), turno.getId(),
+            repartidor.getId(), fecha, delTurno.size(), esperado, rendido, limpias);
+    }
+
+    public BigDecimal getDiferencia() {
+        return rendido.subtract(esperado);
+    }
+
+    public RendicionRepartidorId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public TurnoCajaId getTurnoId() {
+        return turnoId;
+    }
+
+    public MozoId getRepartidorId() {
+        return repartidorId;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public int getPedidos() {
+        return pedidos;
+    }
+
+    public BigDecimal getEsperado() {
+        return esperado;
+    }
+
+    public BigDecimal getRendido() {
+        return rendido;
+    }
+
+    public String getObservaciones() {
+        return observaciones;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        RendicionRepartidor that = (RendicionRepartidor) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
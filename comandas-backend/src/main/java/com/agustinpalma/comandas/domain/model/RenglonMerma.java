@@ -0,0 +1,64 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.util.Objects;
+
+/**
+ * Renglón de una merma: cuánto se perdió de un insumo.
+ *
+ * HU-135: Igual que en la toma de inventario, el nombre, la unidad y el
+ * costo son un snapshot: la merma de marzo vale lo que costaba en marzo.
+ */
+public final class RenglonMerma {
+
+    private final InsumoId insumoId;
+    private final String nombreInsumo;
+    private final UnidadInsumo unidad;
+    private final BigDecimal cantidad;
+    private final BigDecimal costoUnitario;
+
+    public RenglonMerma(InsumoId insumoId, String nombreInsumo, UnidadInsumo unidad, BigDecimal cantidad,
+                        BigDecimal costoUnitario) {
+        this.insumoId = Objects.requireNonNull(insumoId, "El insumoId no puede ser null");
+        this.nombreInsumo = Objects.requireNonNull(nombreInsumo, "El nombre del insumo no puede ser null");
+        this.unidad = Objects.requireNonNull(unidad, "La unidad del insumo no puede ser null");
+        this.cantidad = Objects.requireNonNull(cantidad, "La cantidad de la merma no puede ser null");
+        this.costoUnitario = Objects.requireNonNull(costoUnitario, "El costo unitario no puede ser null");
+    }
+
+    public static RenglonMerma de(Insumo insumo, BigDecimal cantidad) {
+        return new RenglonMerma(insumo.getId(), insumo.getNombre(), insumo.getUnidad(), cantidad,
+            insumo.getCostoUnitario());
+    }
+
+    /**
+     * Lo perdido al costo del momento, redondeado al centavo.
+     */
+    public BigDecimal getCostoValorizado() {
+        return cantidad.multiply(costoUnitario).setScale(2, RoundingMode.HALF_UP);
+    }
+
+    public InsumoId getInsumoId() {
+        return insumoId;
+    }
+
+    public String getNombreInsumo() {
+        return nombreInsumo;
+    }
+
+    public UnidadInsumo getUnidad() {
+        return unidad;
+    }
+
+    public BigDecimal getCantidad() {
+        return cantidad;
+    }
+
+    public BigDecimal getCostoUnitario() {
+        return costoUnitario;
+    }
+}
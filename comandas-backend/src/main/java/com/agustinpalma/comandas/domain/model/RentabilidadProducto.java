@@ -0,0 +1,66 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.util.Objects;
+
+/**
+ * Value Object con el margen teórico de un producto según su receta.
+ *
+ * HU-118: El margen es precio de venta menos costo de la receta. Se compara
+ * contra el precio base del catálogo (las listas por canal no entran).
+ * Un producto está "a pérdida" cuando el costo supera al precio.
+ */
+public final class RentabilidadProducto {
+
+    private static final BigDecimal CIEN = new BigDecimal("100");
+
+    private final ProductoId productoId;
+    private final String nombre;
+    private final BigDecimal precio;
+    private final BigDecimal costo;
+
+    public RentabilidadProducto(Producto producto, BigDecimal costo) {
+        Objects.requireNonNull(producto, "El producto no puede ser null");
+        this.productoId = producto.getId();
+        this.nombre = producto.getNombre();
+        this.precio = producto.getPrecio();
+        this.costo = Objects.requireNonNull(costo, "El costo no puede ser null");
+    }
+
+    public BigDecimal getMargen() {
+        return precio.subtract(costo);
+    }
+
+    /**
+     * @return margen sobre el precio de venta, con 2 decimales (0 si el producto no tiene precio)
+     */
+    public BigDecimal getMargenPorcentaje() {
+        if (precio.signum() == 0) {
+            return BigDecimal.ZERO.setScale(2);
+        }
+        return getMargen().multiply(CIEN).divide(precio, 2, RoundingMode.HALF_UP);
+    }
+
+    public boolean estaAPerdida() {
+        return costo.compareTo(precio) > 0;
+    }
+
+    public ProductoId getProductoId() {
+        return productoId;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public BigDecimal getPrecio() {
+        return precio;
+    }
+
+    public BigDecimal getCosto() {
+        return costo;
+    }
+}
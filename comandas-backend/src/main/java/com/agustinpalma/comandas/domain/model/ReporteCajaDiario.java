@@ -17,12 +17,18 @@ import java.util.Objects;
  * Inmutable y determinista: dado el mismo input, siempre produce el mismo output.
  * 
  * Definiciones:
- * - totalVentasReales: suma de pedidos cerrados excluyendo pagos A_CUENTA
+ * - totalVentasReales: suma de pedidos cerrados excluyendo pagos A_CUENTA,
+ *   neta de las devoluciones del día (HU-121)
  * - totalConsumoInterno: suma de pagos A_CUENTA (consumo de dueños/staff)
  * - totalIngresos: suma de ingresos manuales de efectivo (plataformas externas, etc.)
- * - totalEgresos: suma de egresos de caja (salidas de efectivo)
- * - balanceEfectivo: (total pagos EFECTIVO) + totalIngresos − totalEgresos
+ * - totalEgresos: suma de egresos de caja (salidas de efectivo), sin devoluciones
+ * - totalDevoluciones: HU-121, reintegros de pedidos cerrados pagados en efectivo
+ * - balanceEfectivo: (total cobrado en EFECTIVO) + totalIngresos − totalEgresos − totalDevoluciones
  * - desglosePorMedioPago: mapa con el total por cada medio de pago
+ * - totalRecargos: HU-143, recargos por medio de pago cobrados encima de las ventas
+ * - recargosPorMedioPago: HU-143, recargos discriminados por medio de pago
+ * - efectivoMonedaExtranjera: HU-151, dólares y euros en efectivo (incluidos en el balance)
+ * - totalAjusteRedondeo: HU-152, redondeo del efectivo (incluido en el balance)
  * - pedidosCerrados: lista de pedidos cerrados del día (para historial de ventas)
  */
 public final class ReporteCajaDiario {
@@ -31,35 +37,68 @@ public final class ReporteCajaDiario {
     private final BigDecimal totalConsumoInterno;
     private final BigDecimal totalIngresos;
     private final BigDecimal totalEgresos;
+    private final BigDecimal totalDevoluciones;
     private final BigDecimal balanceEfectivo;
     private final Map<MedioPago, BigDecimal> desglosePorMedioPago;
+    private final Map<MedioPago, BigDecimal> recargosPorMedioPago;
     private final List<MovimientoCaja> listaMovimientos;
     private final List<Pedido> pedidosCerrados;
+    private final List<EfectivoMoneda> efectivoMonedaExtranjera;
 
+    /**
+     * Constructor de retrocompatibilidad (sin efectivo en moneda extranjera).
+     */
     public ReporteCajaDiario(
             BigDecimal totalVentasReales,
             BigDecimal totalConsumoInterno,
             BigDecimal totalIngresos,
             BigDecimal totalEgresos,
+            BigDecimal totalDevoluciones,
             BigDecimal balanceEfectivo,
             Map<MedioPago, BigDecimal> desglosePorMedioPago,
+            Map<MedioPago, BigDecimal> recargosPorMedioPago,
             List<MovimientoCaja> listaMovimientos,
             List<Pedido> pedidosCerrados
+    ) {
+        this(totalVentasReales, totalConsumoInterno, totalIngresos, totalEgresos, totalDevoluciones,
+            balanceEfectivo, desglosePorMedioPago, recargosPorMedioPago, listaMovimientos, pedidosCerrados,
+            List.of());
+    }
+
+    public ReporteCajaDiario(
+            BigDecimal totalVentasReales,
+            BigDecimal totalConsumoInterno,
+            BigDecimal totalIngresos,
+            BigDecimal totalEgresos,
+            BigDecimal totalDevoluciones,
+            BigDecimal balanceEfectivo,
+            Map<MedioPago, BigDecimal> desglosePorMedioPago,
+            Map<MedioPago, BigDecimal> recargosPorMedioPago,
+            List<MovimientoCaja> listaMovimientos,
+            List<Pedido> pedidosCerrados,
+            List<EfectivoMoneda> efectivoMonedaExtranjera
     ) {
         this.totalVentasReales = Objects.requireNonNull(totalVentasReales, "totalVentasReales no puede ser null");
         this.totalConsumoInterno = Objects.requireNonNull(totalConsumoInterno, "totalConsumoInterno no puede ser null");
         this.totalIngresos = Objects.requireNonNull(totalIngresos, "totalIngresos no puede ser null");
         this.totalEgresos = Objects.requireNonNull(totalEgresos, "totalEgresos no puede ser null");
+        this.totalDevoluciones = Objects.requireNonNull(totalDevoluciones, "totalDevoluciones no puede ser null");
         this.balanceEfectivo = Objects.requireNonNull(balanceEfectivo, "balanceEfectivo no puede ser null");
         this.desglosePorMedioPago = Collections.unmodifiableMap(
             Objects.requireNonNull(desglosePorMedioPago, "desglosePorMedioPago no puede ser null")
         );
+        this.recargosPorMedioPago = Collections.unmodifiableMap(
+            Objects.requireNonNull(recargosPorMedioPago, "recargosPorMedioPago no puede ser null")
+        );
         this.listaMovimientos = Collections.unmodifiableList(
             Objects.requireNonNull(listaMovimientos, "listaMovimientos no puede ser null")
         );
         this.pedidosCerrados = Collections.unmodifiableList(
             Objects.requireNonNull(pedidosCerrados, "pedidosCerrados no puede ser null")
         );
+        this.efectivoMonedaExtranjera = List.copyOf(
+            Objects.requireNonNull(efectivoMonedaExtranjera, "efectivoMonedaExtranjera no puede ser null")
+        );
     }
 
     public BigDecimal getTotalVentasReales() {
@@ -78,6 +117,10 @@ public final class ReporteCajaDiario {
         return totalEgresos;
     }
 
+    public BigDecimal getTotalDevoluciones() {
+        return totalDevoluciones;
+    }
+
     public BigDecimal getBalanceEfectivo() {
         return balanceEfectivo;
     }
@@ -86,6 +129,25 @@ public final class ReporteCajaDiario {
         return desglosePorMedioPago;
     }
 
+    public Map<MedioPago, BigDecimal> getRecargosPorMedioPago() {
+        return recargosPorMedioPago;
+    }
+
+    public BigDecimal getTotalRecargos() {
+        return recargosPorMedioPago.values().stream().reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    /**
+     * HU-152: Suma de los ajustes por redondeo del efectivo; negativa si se
+     * redondeó más hacia abajo que hacia arriba.
+     */
+    public BigDecimal getTotalAjusteRedondeo() {
+        return pedidosCerrados.stream()
+            .flatMap(pedido -> pedido.getPagos().stream())
+            .map(Pago::getAjusteRedondeo)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
     public List<MovimientoCaja> getListaMovimientos() {
         return listaMovimientos;
     }
@@ -94,6 +156,23 @@ public final class ReporteCajaDiario {
         return pedidosCerrados;
     }
 
+    /**
+     * HU-151: Efectivo en cada moneda extranjera, con su equivalente en pesos.
+     */
+    public List<EfectivoMoneda> getEfectivoMonedaExtranjera() {
+        return efectivoMonedaExtranjera;
+    }
+
+    /**
+     * HU-151: Pesos físicos que tiene que haber en el cajón: el balance sin
+     * el equivalente del efectivo en moneda extranjera.
+     */
+    public BigDecimal getEfectivoPesos() {
+        return efectivoMonedaExtranjera.stream()
+            .map(EfectivoMoneda::getEquivalentePesos)
+            .reduce(balanceEfectivo, BigDecimal::subtract);
+    }
+
     @Override
     public boolean equals(Object o) {
         if (this == o) return true;
@@ -103,19 +182,21 @@ public final class ReporteCajaDiario {
                totalConsumoInterno.compareTo(that.totalConsumoInterno) == 0 &&
                totalIngresos.compareTo(that.totalIngresos) == 0 &&
                totalEgresos.compareTo(that.totalEgresos) == 0 &&
+               totalDevoluciones.compareTo(that.totalDevoluciones) == 0 &&
                balanceEfectivo.compareTo(that.balanceEfectivo) == 0;
     }
 
     @Override
     public int hashCode() {
-        return Objects.hash(totalVentasReales, totalConsumoInterno, totalIngresos, totalEgresos, balanceEfectivo);
+        return Objects.hash(totalVentasReales, totalConsumoInterno, totalIngresos, totalEgresos,
+            totalDevoluciones, balanceEfectivo);
     }
 
     @Override
     public String toString() {
         return String.format(
-            "ReporteCajaDiario{ventasReales=%s, consumoInterno=%s, ingresos=%s, egresos=%s, balanceEfectivo=%s}",
-            totalVentasReales, totalConsumoInterno, totalIngresos, totalEgresos, balanceEfectivo
+            "ReporteCajaDiario{ventasReales=%s, consumoInterno=%s, ingresos=%s, egresos=%s, devoluciones=%s, balanceEfectivo=%s}",
+            totalVentasReales, totalConsumoInterno, totalIngresos, totalEgresos, totalDevoluciones, balanceEfectivo
         );
     }
 }
@@ -0,0 +1,242 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoReserva;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ReservaId;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.Objects;
+
+/**
+ * Reserva de una mesa, con la seña que el cliente deja para asegurarla.
+ *
+ * HU-142: Señas de reservas aplicadas a la cuenta.
+ *
+ * Reglas de negocio:
+ * - El nombre del cliente es obligatorio (máximo 80 caracteres) y las personas, mayor a cero.
+ * - La mesa se puede asignar al tomar la reserva o elegirse al sentar al cliente.
+ * - La seña se cobra una sola vez, con la reserva PENDIENTE. Entra a la caja
+ *   como ingreso del día en que se cobra, no del día de la reserva.
+ * - Al sentarse, la reserva queda CUMPLIDA y vinculada al pedido de su mesa;
+ *   la seña se descuenta del total de ese pedido.
+ * - Si se cancela, la seña no se devuelve desde acá: queda en la caja del día de cobro.
+ */
+public class Reserva {
+
+    private static final int LONGITUD_MAXIMA_NOMBRE = 80;
+    private static final int LONGITUD_MAXIMA_TELEFONO = 30;
+    private static final int LONGITUD_MAXIMA_OBSERVACIONES = 200;
+
+    private final ReservaId id;
+    private final LocalId localId;
+    private final String nombreCliente;
+    private final String telefono;
+    private final LocalDateTime fechaHora;
+    private final int personas;
+    private final String observaciones;
+    private MesaId mesaId;
+    private EstadoReserva estado;
+    private BigDecimal montoSenia;
+    private LocalDateTime fechaSenia;
+    private MovimientoCajaId movimientoSeniaId;
+    private PedidoId pedidoId;
+
+    public Reserva(ReservaId id, LocalId localId, String nombreCliente, String telefono, LocalDateTime fechaHora,
+                   int personas, String observaciones, MesaId mesaId, EstadoReserva estado,
+                   BigDecimal montoSenia, LocalDateTime fechaSenia, MovimientoCajaId movimientoSeniaId,
+                   PedidoId pedidoId) {
+        this.id = Objects.requireNonNull(id, "El id de la reserva no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.nombreCliente = validarTexto(nombreCliente, LONGITUD_MAXIMA_NOMBRE, "El nombre del cliente");
+        if (this.nombreCliente == null) {
+            throw new IllegalArgumentException("El nombre del cliente es obligatorio");
+        }
+        this.telefono = validarTexto(telefono, LONGITUD_MAXIMA_TELEFONO, "El teléfono");
+        this.fechaHora = Objects.requireNonNull(fechaHora, "La fecha y hora de la reserva es obligatoria");
+        if (personas <= 0) {
+            throw new IllegalArgumentException("La reserva tiene que ser para al menos una persona");
+        }
+        this.personas = personas;
+        this.observaciones = validarTexto(observaciones, LONGITUD_MAXIMA_OBSERVACIONES, "Las observaciones");
+        this.mesaId = mesaId;
+        this.estado = Objects.requireNonNull(estado, "El estado de la reserva no puede ser null");
+        this.montoSenia = montoSenia != null ? montoSenia : BigDecimal.ZERO;
+        this.fechaSenia = fechaSenia;
+        this.movimientoSeniaId = movimientoSeniaId;
+        this.pedidoId = pedidoId;
+    }
+
+    public static Reserva crear(LocalId localId, String nombreCliente, String telefono, LocalDateTime fechaHora,
+                                int personas, String observaciones, MesaId mesaId) {
+        return new Reserva(ReservaId.generate(), localId, nombreCliente, telefono, fechaHora, personas,
+            observaciones, mesaId, EstadoReserva.PENDIENTE, BigDecimal.ZERO, null, null, null);
+    }
+
+    private static String validarTexto(String texto, int maximo, String campo) {
+        if (texto == null || texto.isBlank()) {
+            return null;
+        }
+        String limpio = texto.trim();
+        if (limpio.length() > maximo) {
+            throw new IllegalArgumentException(campo + " no puede superar los " + maximo + " caracteres");
+        }
+        return limpio;
+    }
+
+    // ============================================
+    // Comportamiento
+    // ============================================
+
+    /**
+     * Registra la seña cobrada. El movimiento de caja lo genera la capa de aplicación.
+     *
+     * @param movimientoId ingreso de caja con el que entró la seña
+     * @throws IllegalStateException si la reserva no está PENDIENTE o ya tiene seña
+     * @throws IllegalArgumentException si el monto no es positivo
+     */
+    public void registrarSenia(BigDecimal monto, MovimientoCajaId movimientoId, LocalDateTime fecha) {
+        Objects.requireNonNull(monto, "El monto de la seña es obligatorio");
+        Objects.requireNonNull(movimientoId, "El movimiento de caja de la seña es obligatorio");
+        Objects.requireNonNull(fecha, "La fecha de la seña es obligatoria");
+        validarPendiente();
+        if (tieneSenia()) {
+            throw new IllegalStateException("La reserva de " + nombreCliente + " ya tiene una seña cobrada");
+        }
+        if (monto.signum() <= 0) {
+            throw new IllegalArgumentException("La seña tiene que ser mayor a cero");
+        }
+        this.montoSenia = monto;
+        this.movimientoSeniaId = movimientoId;
+        this.fechaSenia = fecha;
+    }
+
+    /**
+     * Sienta al cliente: vincula la reserva con el pedido abierto de su mesa
+     * y le descuenta la seña.
+     *
+     * @throws IllegalStateException si la reserva no está PENDIENTE o el pedido no está abierto
+     * @throws IllegalArgumentException si el pedido es de otro local
+     */
+    public void sentar(Pedido pedido) {
+        Objects.requireNonNull(pedido, "El pedido no puede ser null");
+        validarPendiente();
+        if (!pedido.getLocalId().equals(localId)) {
+            throw new IllegalArgumentException("El pedido no pertenece a este local");
+        }
+        if (pedido.getEstado() != EstadoPedido.ABIERTO) {
+            throw new IllegalStateException("La reserva se sienta en una mesa abierta");
+        }
+        pedido.vincularReserva(id, montoSenia);
+        this.mesaId = pedido.getMesaId();
+        this.pedidoId = pedido.getId();
+        this.estado = EstadoReserva.CUMPLIDA;
+    }
+
+    /**
+     * @throws IllegalStateException si la reserva ya se cumplió o se canceló
+     */
+    public void cancelar() {
+        validarPendiente();
+        this.estado = EstadoReserva.CANCELADA;
+    }
+
+    /**
+     * @return true si la reserva sigue pendiente, está asignada a la mesa y es para ese día
+     */
+    public boolean esParaLaMesa(MesaId mesaId, LocalDate dia) {
+        return estado == EstadoReserva.PENDIENTE
+            && mesaId.equals(this.mesaId)
+            && fechaHora.toLocalDate().equals(dia);
+    }
+
+    public boolean tieneSenia() {
+        return montoSenia.signum() > 0;
+    }
+
+    private void validarPendiente() {
+        if (estado != EstadoReserva.PENDIENTE) {
+            throw new IllegalStateException("La reserva de " + nombreCliente + " está " + estado);
+        }
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public ReservaId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public String getNombreCliente() {
+        return nombreCliente;
+    }
+
+    public String getTelefono() {
+        return telefono;
+    }
+
+    public LocalDateTime getFechaHora() {
+        return fechaHora;
+    }
+
+    public int getPersonas() {
+        return personas;
+    }
+
+    public String getObservaciones() {
+        return observaciones;
+    }
+
+    /**
+     * @return mesa asignada, o null si todavía no se eligió
+     */
+    public MesaId getMesaId() {
+        return mesaId;
+    }
+
+    public EstadoReserva getEstado() {
+        return estado;
+    }
+
+    public BigDecimal getMontoSenia() {
+        return montoSenia;
+    }
+
+    public LocalDateTime getFechaSenia() {
+        return fechaSenia;
+    }
+
+    public MovimientoCajaId getMovimientoSeniaId() {
+        return movimientoSeniaId;
+    }
+
+    /**
+     * @return pedido en el que se sentó el cliente, o null si todavía no llegó
+     */
+    public PedidoId getPedidoId() {
+        return pedidoId;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        Reserva reserva = (Reserva) o;
+        return Objects.equals(id, reserva.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
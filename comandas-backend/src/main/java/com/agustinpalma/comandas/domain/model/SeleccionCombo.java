@@ -0,0 +1,92 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import java.math.BigDecimal;
+import java.util.Objects;
+
+/**
+ * Value Object que representa la opción elegida para un componente de combo
+ * dentro de un ítem de pedido.
+ *
+ * HU-103: Combos y menú del día.
+ *
+ * Reglas de negocio:
+ * - Es INMUTABLE (patrón snapshot, igual que ExtraPedido)
+ * - Captura nombre del componente, nombre del producto elegido y su precio de lista
+ * - El precio de lista NO se cobra: el combo tiene precio cerrado. Se conserva
+ *   solo como referencia para prorratear el importe del combo en reportes desglosados.
+ */
+public final class SeleccionCombo {
+
+    private final String componente;             // Snapshot del nombre del componente (ej: "Bebida")
+    private final ProductoId productoId;         // Producto elegido (para auditoría)
+    private final String nombreProducto;         // Snapshot del nombre (ej: "Coca-Cola 500ml")
+    private final BigDecimal precioReferencia;   // Precio de lista del producto al momento de la venta
+
+    public SeleccionCombo(String componente, ProductoId productoId, String nombreProducto, BigDecimal precioReferencia) {
+        if (componente == null || componente.isBlank()) {
+            throw new IllegalArgumentException("El componente de la selección no puede estar vacío");
+        }
+        if (nombreProducto == null || nombreProducto.isBlank()) {
+            throw new IllegalArgumentException("El nombre del producto elegido no puede estar vacío");
+        }
+        if (precioReferencia == null || precioReferencia.compareTo(BigDecimal.ZERO) < 0) {
+            throw new IllegalArgumentException("El precio de referencia no puede ser null ni negativo");
+        }
+        this.componente = componente.trim();
+        this.productoId = Objects.requireNonNull(productoId, "El productoId de la selección no puede ser null");
+        this.nombreProducto = nombreProducto.trim();
+        this.precioReferencia = precioReferencia;
+    }
+
+    /**
+     * Factory method que captura el snapshot del producto elegido para un componente.
+     */
+    public static SeleccionCombo de(ComponenteCombo componente, Producto elegido) {
+        Objects.requireNonNull(componente, "El componente no puede ser null");
+        Objects.requireNonNull(elegido, "El producto elegido no puede ser null");
+        return new SeleccionCombo(
+            componente.getNombre(),
+            elegido.getId(),
+            elegido.getNombre(),
+            elegido.getPrecio()
+        );
+    }
+
+    public String getComponente() {
+        return componente;
+    }
+
+    public ProductoId getProductoId() {
+        return productoId;
+    }
+
+    public String getNombreProducto() {
+        return nombreProducto;
+    }
+
+    public BigDecimal getPrecioReferencia() {
+        return precioReferencia;
+    }
+
+    // Value Object: comparación por valor (el precio de referencia no distingue platos)
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        SeleccionCombo that = (SeleccionCombo) o;
+        return Objects.equals(componente, that.componente) &&
+               Objects.equals(productoId, that.productoId);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(componente, productoId);
+    }
+
+    @Override
+    public String toString() {
+        return String.format("SeleccionCombo{componente='%s', producto='%s'}", componente, nombreProducto);
+    }
+}
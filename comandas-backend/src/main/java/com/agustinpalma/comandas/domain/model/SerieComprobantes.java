@@ -0,0 +1,218 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.time.LocalDate;
+import java.util.Objects;
+import java.util.regex.Pattern;
+
+/**
+ * Serie de numeración de los comprobantes que emite una caja.
+ *
+ * HU-166: Cada caja (punto de venta) numera sus comprobantes con su propio
+ * prefijo, así dos cajas que cierran a la vez nunca se pisan el número:
+ * "B1-00000042" en la barra, "SAL-00000042" en el salón. Con reinicio
+ * anual el año va en el número ("B1-2026-00000001") y la cuenta vuelve a 1
+ * con el primer cierre del año operativo.
+ *
+ * Reglas de negocio:
+ * - Una serie por caja; la de la caja principal no tiene caja (null).
+ * - El prefijo lleva de 1 a 8 letras o dígitos y se guarda en mayúsculas.
+ * - El próximo número va de 1 a 99.999.999 y, con el mismo prefijo, nunca
+ *   baja: se repetirían comprobantes ya emitidos. Eso vale también para un
+ *   prefijo que se dejó de usar y se vuelve a poner
+ *   ({@link #verificarPosteriorA(String, LocalDate)}).
+ * - Los números se asignan de a uno y en orden ({@link #asignar(LocalDate)});
+ *   la persistencia toma la serie bloqueada para que dos cierres simultáneos
+ *   no se lleven el mismo.
+ */
+public class SerieComprobantes {
+
+    private static final Pattern PREFIJO_VALIDO = Pattern.compile("[A-Z0-9]{1,8}");
+    private static final long NUMERO_MAXIMO = 99_999_999L;
+
+    /** Dígitos del número, completados con ceros a la izquierda. */
+    public static final int DIGITOS_NUMERO = 8;
+
+    private final LocalId localId;
+    private final CajaId cajaId;
+    private String prefijo;
+    private long proximoNumero;
+    private boolean reinicioAnual;
+    private Integer anioEnCurso;
+
+    /**
+     * @param cajaId caja de la serie (null = caja principal)
+     * @param anioEnCurso año operativo del último número asignado (null si todavía no se usó)
+     */
+    public SerieComprobantes(LocalId localId, CajaId cajaId, String prefijo, long proximoNumero,
+                             boolean reinicioAnual, Integer anioEnCurso) {
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.cajaId = cajaId;
+        this.prefijo = validarPrefijo(prefijo);
+        // Una serie que ya asignó el último número queda con el máximo + 1
+        if (proximoNumero != NUMERO_MAXIMO + 1) {
+            validarNumero(proximoNumero);
+        }
+        this.proximoNumero = proximoNumero;
+        this.reinicioAnual = reinicioAnual;
+        this.anioEnCurso = anioEnCurso;
+    }
+
+    private static String validarPrefijo(String prefijo) {
+        if (prefijo == null || prefijo.isBlank()) {
+            throw new IllegalArgumentException("El prefijo de la serie es obligatorio");
+        }
+        String limpio = prefijo.trim().toUpperCase();
+        if (!PREFIJO_VALIDO.matcher(limpio).matches()) {
+            throw new IllegalArgumentException("El prefijo de la serie lleva de 1 a 8 letras o números, sin espacios");
+        }
+        return limpio;
+    }
+
+    private static void validarNumero(long numero) {
+        if (numero < 1 || numero > NUMERO_MAXIMO) {
+            throw new IllegalArgumentException("El próximo número tiene que estar entre 1 y " + NUMERO_MAXIMO);
+        }
+    }
+
+    /**
+     * Cambia la configuración de la serie.
+     *
+     * @throws IllegalArgumentException si con el mismo prefijo se baja el próximo número
+     */
+    public void reconfigurar(String prefijo, long proximoNumero, boolean reinicioAnual) {
+        String nuevoPrefijo = validarPrefijo(prefijo);
+        validarNumero(proximoNumero);
+        if (nuevoPrefijo.equals(this.prefijo) && reinicioAnual == this.reinicioAnual
+                && proximoNumero < this.proximoNumero) {
+            throw new IllegalArgumentException(
+                "El próximo número no puede bajar de " + this.proximoNumero
+                    + ": se repetirían comprobantes ya emitidos");
+        }
+        this.prefijo = nuevoPrefijo;
+        this.proximoNumero = proximoNumero;
+        this.reinicioAnual = reinicioAnual;
+    }
+
+    /**
+     * Lo que tienen en común los números que saldrían en esa fecha: "B1-" o,
+     * con reinicio anual, "B1-2026-". Después vienen los
+     * {@link #DIGITOS_NUMERO} dígitos del número.
+     */
+    public String encabezado(LocalDate fechaOperativa) {
+        Objects.requireNonNull(fechaOperativa, "La fecha operativa es obligatoria");
+        return reinicioAnual
+            ? prefijo + "-" + anioPara(fechaOperativa) + "-"
+            : prefijo + "-";
+    }
+
+    /**
+     * Verifica que el próximo número no repita uno ya emitido con el mismo
+     * encabezado, aunque lo haya emitido otra configuración de la serie
+     * (pasar de "A" a "B" y volver a "A" con un número más bajo).
+     *
+     * @param ultimoEmitido último comprobante emitido con {@link #encabezado(LocalDate)}, o null si no hay
+     * @throws IllegalArgumentException si el próximo número no supera al último emitido
+     */
+    public void verificarPosteriorA(String ultimoEmitido, LocalDate fechaOperativa) {
+        Objects.requireNonNull(fechaOperativa, "La fecha operativa es obligatoria");
+        if (ultimoEmitido == null) {
+            return;
+        }
+        long ultimo = Long.parseLong(ultimoEmitido.substring(ultimoEmitido.length() - DIGITOS_NUMERO));
+        if (numeroPara(fechaOperativa) <= ultimo) {
+            throw new IllegalArgumentException(
+                "El último comprobante emitido con " + encabezado(fechaOperativa) + " es " + ultimoEmitido
+                    + ": el próximo número tiene que ser al menos " + (ultimo + 1));
+        }
+    }
+
+    /**
+     * Toma el próximo número de la serie.
+     *
+     * @param fechaOperativa día operativo del cierre (un cierre a la madrugada
+     *                       del 1° de enero todavía es del año anterior)
+     * @return el número formateado, listo para imprimir
+     * @throws IllegalStateException si la serie llegó al número máximo
+     */
+    public String asignar(LocalDate fechaOperativa) {
+        Objects.requireNonNull(fechaOperativa, "La fecha operativa es obligatoria");
+        int anio = fechaOperativa.getYear();
+        if (anioEnCurso == null || anio > anioEnCurso) {
+            if (reinicioAnual && anioEnCurso != null) {
+                proximoNumero = 1;
+            }
+            anioEnCurso = anio;
+        }
+        if (proximoNumero > NUMERO_MAXIMO) {
+            throw new IllegalStateException(
+                "La serie " + prefijo + " llegó al último número: configure un prefijo nuevo");
+        }
+        return formatear(proximoNumero++);
+    }
+
+    /**
+     * Número que saldría en el próximo cierre, sin consumirlo.
+     */
+    public String proximoComprobante(LocalDate fechaOperativa) {
+        Objects.requireNonNull(fechaOperativa, "La fecha operativa es obligatoria");
+        return formatear(numeroPara(fechaOperativa), anioPara(fechaOperativa));
+    }
+
+    private boolean esAnioNuevo(LocalDate fechaOperativa) {
+        return anioEnCurso == null || fechaOperativa.getYear() > anioEnCurso;
+    }
+
+    private int anioPara(LocalDate fechaOperativa) {
+        return esAnioNuevo(fechaOperativa) ? fechaOperativa.getYear() : anioEnCurso;
+    }
+
+    private long numeroPara(LocalDate fechaOperativa) {
+        return esAnioNuevo(fechaOperativa) && reinicioAnual && anioEnCurso != null ? 1 : proximoNumero;
+    }
+
+    private String formatear(long numero) {
+        return formatear(numero, anioEnCurso);
+    }
+
+    private String formatear(long numero, int anio) {
+        return reinicioAnual
+            ? String.format("%s-%d-%08d", prefijo, anio, numero)
+            : String.format("%s-%08d", prefijo, numero);
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public CajaId getCajaId() {
+        return cajaId;
+    }
+
+    public String getPrefijo() {
+        return prefijo;
+    }
+
+    public long getProximoNumero() {
+        return proximoNumero;
+    }
+
+    public boolean isReinicioAnual() {
+        return reinicioAnual;
+    }
+
+    public Integer getAnioEnCurso() {
+        return anioEnCurso;
+    }
+
+    @Override
+    public String toString() {
+        return String.format("SerieComprobantes{caja=%s, prefijo=%s, proximo=%d}", cajaId, prefijo, proximoNumero);
+    }
+}
@@ -0,0 +1,105 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+
+import java.nio.charset.StandardCharsets;
+import java.security.MessageDigest;
+import java.security.NoSuchAlgorithmException;
+import java.security.SecureRandom;
+import java.time.Duration;
+import java.time.LocalDateTime;
+import java.util.Base64;
+import java.util.HexFormat;
+import java.util.Objects;
+
+/**
+ * Sesión de un empleado que se identificó con su PIN en una terminal.
+ *
+ * HU-169: Al identificarse, la terminal recibe un token al azar y lo manda en
+ * cada request; el backend resuelve con la sesión quién opera. Conocer el id
+ * de un empleado no alcanza para hacerse pasar por él.
+ *
+ * Reglas de negocio:
+ * - La sesión vence sola: pasada la duración hay que volver a poner el PIN.
+ * - Del token se guarda solo el hash, así quien lea la base no puede usar
+ *   las sesiones abiertas.
+ */
+public class SesionEmpleado {
+
+    private static final SecureRandom ALEATORIO = new SecureRandom();
+    private static final int BYTES_TOKEN = 32;
+
+    private final String hashToken;
+    private final LocalId localId;
+    private final MozoId empleadoId;
+    private final LocalDateTime venceEn;
+
+    public SesionEmpleado(String hashToken, LocalId localId, MozoId empleadoId, LocalDateTime venceEn) {
+        this.hashToken = Objects.requireNonNull(hashToken, "El hash del token no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.empleadoId = Objects.requireNonNull(empleadoId, "El empleadoId no puede ser null");
+        this.venceEn = Objects.requireNonNull(venceEn, "El vencimiento no puede ser null");
+    }
+
+    /**
+     * Abre una sesión nueva para el empleado.
+     *
+     * @param duracion cuánto dura la sesión desde ahora
+     * @return la sesión y el token para la terminal, que después no se puede volver a obtener
+     */
+    public static Apertura abrir(LocalId localId, MozoId empleadoId, LocalDateTime ahora, Duration duracion) {
+        Objects.requireNonNull(ahora, "El momento de apertura es obligatorio");
+        Objects.requireNonNull(duracion, "La duración de la sesión es obligatoria");
+        if (duracion.isNegative() || duracion.isZero()) {
+            throw new IllegalArgumentException("La duración de la sesión tiene que ser positiva");
+        }
+        byte[] bytes = new byte[BYTES_TOKEN];
+        ALEATORIO.nextBytes(bytes);
+        String token = Base64.getUrlEncoder().withoutPadding().encodeToString(bytes);
+        return new Apertura(new SesionEmpleado(hash(token), localId, empleadoId, ahora.plus(duracion)), token);
+    }
+
+    /**
+     * @return el hash con el que se guarda y se busca la sesión del token
+     */
+    public static String hash(String token) {
+        Objects.requireNonNull(token, "El token no puede ser null");
+        try {
+            byte[] digest = MessageDigest.getInstance("SHA-256").digest(token.getBytes(StandardCharsets.UTF_8));
+            return HexFormat.of().formatHex(digest);
+        } catch (NoSuchAlgorithmException e) {
+            throw new IllegalStateException("SHA-256 no está disponible", e);
+        }
+    }
+
+    public boolean estaVigente(LocalDateTime ahora) {
+        return ahora.isBefore(venceEn);
+    }
+
+    /**
+     * Sesión recién abierta junto con su token.
+     */
+    public record Apertura(SesionEmpleado sesion, String token) {
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public String getHashToken() {
+        return hashToken;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public MozoId getEmpleadoId() {
+        return empleadoId;
+    }
+
+    public LocalDateTime getVenceEn() {
+        return venceEn;
+    }
+}
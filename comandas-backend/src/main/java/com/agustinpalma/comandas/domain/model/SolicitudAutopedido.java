@@ -0,0 +1,201 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoSolicitudAutopedido;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.SolicitudAutopedidoId;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Pedido que el cliente arma desde el QR de su mesa.
+ *
+ * HU-126: Autopedido por QR.
+ *
+ * El cliente no carga ítems directamente en el pedido de la mesa: deja una
+ * solicitud que el mozo revisa. Solo al confirmarla los ítems pasan al pedido
+ * y recién ahí salen a cocina.
+ *
+ * Reglas de negocio:
+ * - Debe tener al menos un ítem y como máximo {@link #MAXIMO_ITEMS} renglones.
+ * - Solo una solicitud PENDIENTE puede confirmarse o rechazarse, y una sola vez.
+ * - Al rechazar, el motivo es obligatorio (se le muestra al cliente).
+ */
+public class SolicitudAutopedido {
+
+    public static final int MAXIMO_ITEMS = 30;
+    /** Solicitudes sin resolver que acepta una mesa antes de frenar nuevos envíos */
+    public static final int MAXIMO_PENDIENTES_POR_MESA = 3;
+
+    private static final int LONGITUD_MAXIMA_NOMBRE = 40;
+    private static final int LONGITUD_MAXIMA_MOTIVO = 140;
+
+    private final SolicitudAutopedidoId id;
+    private final LocalId localId;
+    private final MesaId mesaId;
+    private final int numeroMesa;
+    private final String nombreCliente;
+    private final List<ItemSolicitudAutopedido> items;
+    private final LocalDateTime creadaEn;
+    private EstadoSolicitudAutopedido estado;
+    private LocalDateTime resueltaEn;
+    private MozoId resueltaPorId;
+    private String motivoRechazo;
+
+    public SolicitudAutopedido(
+            SolicitudAutopedidoId id,
+            LocalId localId,
+            MesaId mesaId,
+            int numeroMesa,
+            String nombreCliente,
+            List<ItemSolicitudAutopedido> items,
+            LocalDateTime creadaEn,
+            EstadoSolicitudAutopedido estado,
+            LocalDateTime resueltaEn,
+            MozoId resueltaPorId,
+            String motivoRechazo
+    ) {
+        this.id = Objects.requireNonNull(id, "El id de la solicitud no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.mesaId = Objects.requireNonNull(mesaId, "La solicitud debe pertenecer a una mesa");
+        this.creadaEn = Objects.requireNonNull(creadaEn, "La fecha de la solicitud es obligatoria");
+        this.estado = Objects.requireNonNull(estado, "El estado de la solicitud es obligatorio");
+        if (items == null || items.isEmpty()) {
+            throw new IllegalArgumentException("El pedido debe tener al menos un producto");
+        }
+        if (items.size() > MAXIMO_ITEMS) {
+            throw new IllegalArgumentException("El pedido no puede tener más de " + MAXIMO_ITEMS + " productos distintos");
+        }
+        this.numeroMesa = numeroMesa;
+        this.nombreCliente = normalizarNombre(nombreCliente);
+        this.items = List.copyOf(items);
+        this.resueltaEn = resueltaEn;
+        this.resueltaPorId = resueltaPorId;
+        this.motivoRechazo = motivoRechazo;
+    }
+
+    /**
+     * Crea la solicitud que el cliente acaba de enviar, a la espera del mozo.
+     */
+    public static SolicitudAutopedido enviar(LocalId localId, Mesa mesa, String nombreCliente,
+                                             List<ItemSolicitudAutopedido> items, LocalDateTime ahora) {
+        Objects.requireNonNull(mesa, "La mesa es obligatoria");
+        return new SolicitudAutopedido(SolicitudAutopedidoId.generate(), localId, mesa.getId(), mesa.getNumero(),
+            nombreCliente, items, ahora, EstadoSolicitudAutopedido.PENDIENTE, null, null, null);
+    }
+
+    // ============================================
+    // Lógica de dominio
+    // ============================================
+
+    /**
+     * El mozo validó el pedido: sus ítems pasan al pedido de la mesa.
+     *
+     * @param mozoId quién confirmó (null si se confirmó desde una terminal sin mozo elegido)
+     * @throws IllegalStateException si ya fue resuelta
+     */
+    public void confirmar(MozoId mozoId, LocalDateTime ahora) {
+        resolver(EstadoSolicitudAutopedido.CONFIRMADA, mozoId, ahora);
+    }
+
+    /**
+     * El mozo descartó el pedido (cliente que se arrepintió, pedido duplicado, broma).
+     *
+     * @throws IllegalArgumentException si no se indica el motivo
+     * @throws IllegalStateException si ya fue resuelta
+     */
+    public void rechazar(String motivo, MozoId mozoId, LocalDateTime ahora) {
+        if (motivo == null || motivo.isBlank()) {
+            throw new IllegalArgumentException("Indicá por qué se rechaza el pedido");
+        }
+        String limpio = motivo.trim();
+        resolver(EstadoSolicitudAutopedido.RECHAZADA, mozoId, ahora);
+        this.motivoRechazo = limpio.length() > LONGITUD_MAXIMA_MOTIVO
+            ? limpio.substring(0, LONGITUD_MAXIMA_MOTIVO)
+            : limpio;
+    }
+
+    private void resolver(EstadoSolicitudAutopedido nuevoEstado, MozoId mozoId, LocalDateTime ahora) {
+        Objects.requireNonNull(ahora, "La fecha es obligatoria");
+        if (!estaPendiente()) {
+            throw new IllegalStateException(
+                "El pedido de la mesa " + numeroMesa + " ya fue " + estado.name().toLowerCase());
+        }
+        this.estado = nuevoEstado;
+        this.resueltaEn = ahora;
+        this.resueltaPorId = mozoId;
+    }
+
+    public boolean estaPendiente() {
+        return estado == EstadoSolicitudAutopedido.PENDIENTE;
+    }
+
+    /**
+     * Total según los precios que el cliente vio en la carta.
+     */
+    public BigDecimal calcularTotal() {
+        return items.stream()
+            .map(ItemSolicitudAutopedido::calcularSubtotal)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    private static String normalizarNombre(String nombre) {
+        if (nombre == null || nombre.isBlank()) {
+            return null;
+        }
+        String limpio = nombre.trim();
+        return limpio.length() > LONGITUD_MAXIMA_NOMBRE ? limpio.substring(0, LONGITUD_MAXIMA_NOMBRE) : limpio;
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public SolicitudAutopedidoId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public MesaId getMesaId() {
+        return mesaId;
+    }
+
+    public int getNumeroMesa() {
+        return numeroMesa;
+    }
+
+    public String getNombreCliente() {
+        return nombreCliente;
+    }
+
+    public List<ItemSolicitudAutopedido> getItems() {
+        return items;
+    }
+
+    public LocalDateTime getCreadaEn() {
+        return creadaEn;
+    }
+
+    public EstadoSolicitudAutopedido getEstado() {
+        return estado;
+    }
+
+    public LocalDateTime getResueltaEn() {
+        return resueltaEn;
+    }
+
+    public MozoId getResueltaPorId() {
+        return resueltaPorId;
+    }
+
+    public String getMotivoRechazo() {
+        return motivoRechazo;
+    }
+}
@@ -0,0 +1,241 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoTarjetaRegalo;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TarjetaRegaloId;
+
+import java.math.BigDecimal;
+import java.security.SecureRandom;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.format.DateTimeFormatter;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Locale;
+import java.util.Objects;
+
+/**
+ * Tarjeta de regalo (gift card) prepaga con código único.
+ *
+ * HU-144: Gift cards y vouchers prepagos.
+ *
+ * Reglas de negocio:
+ * - Se vende por un monto mayor a cero que entra a la caja como ingreso del
+ *   día en que se vende. Usarla después no vuelve a sumar efectivo.
+ * - El código tiene 10 caracteres sin letras ni números que se confundan
+ *   (sin 0/O, 1/I). Se acepta escrito con guiones, espacios o en minúscula.
+ * - Sirve como pago parcial o total de un pedido hasta agotar el saldo, y
+ *   solo hasta el día de vencimiento inclusive.
+ * - Si el pedido se reabre, lo consumido en él vuelve al saldo.
+ */
+public class TarjetaRegalo {
+
+    private static final String ALFABETO = "ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+    private static final int LONGITUD_CODIGO = 10;
+    private static final int LONGITUD_MAXIMA_NOMBRE = 80;
+    private static final SecureRandom RANDOM = new SecureRandom();
+    private static final DateTimeFormatter FORMATO_FECHA = DateTimeFormatter.ofPattern("dd/MM/yyyy");
+
+    private final TarjetaRegaloId id;
+    private final LocalId localId;
+    private final String codigo;
+    private final BigDecimal montoInicial;
+    private final LocalDateTime fechaEmision;
+    private final LocalDate vencimiento;
+    private final String comprador;
+    private final String destinatario;
+    private final MovimientoCajaId movimientoCajaId;
+    private final List<ConsumoTarjetaRegalo> consumos;
+
+    public TarjetaRegalo(TarjetaRegaloId id, LocalId localId, String codigo, BigDecimal montoInicial,
+                         LocalDateTime fechaEmision, LocalDate vencimiento, String comprador, String destinatario,
+                         MovimientoCajaId movimientoCajaId, List<ConsumoTarjetaRegalo> consumos) {
+        this.id = Objects.requireNonNull(id, "El id de la tarjeta de regalo no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.codigo = normalizarCodigo(codigo);
+        if (this.codigo.length() != LONGITUD_CODIGO) {
+            throw new IllegalArgumentException("El código de la tarjeta de regalo tiene " + LONGITUD_CODIGO + " caracteres");
+        }
+        Objects.requireNonNull(montoInicial, "El monto de la tarjeta de regalo es obligatorio");
+        if (montoInicial.signum() <= 0) {
+            throw new IllegalArgumentException("El monto de la tarjeta de regalo tiene que ser mayor a cero");
+        }
+        this.montoInicial = montoInicial;
+        this.fechaEmision = Objects.requireNonNull(fechaEmision, "La fecha de emisión es obligatoria");
+        this.vencimiento = Objects.requireNonNull(vencimiento, "La fecha de vencimiento es obligatoria");
+        this.comprador = validarNombre(comprador, "El comprador");
+        this.destinatario = validarNombre(destinatario, "El destinatario");
+        this.movimientoCajaId = movimientoCajaId;
+        this.consumos = new ArrayList<>(Objects.requireNonNull(consumos, "Los consumos no pueden ser null"));
+    }
+
+    /**
+     * Emite una tarjeta nueva con código generado al azar.
+     *
+     * @param movimientoCajaId ingreso de caja con el que se cobró la tarjeta
+     * @throws IllegalArgumentException si el vencimiento no es posterior al día de emisión
+     */
+    public static TarjetaRegalo emitir(LocalId localId, BigDecimal monto, LocalDate vencimiento, String comprador,
+                                       String destinatario, MovimientoCajaId movimientoCajaId, LocalDateTime fecha) {
+        Objects.requireNonNull(vencimiento, "La fecha de vencimiento es obligatoria");
+        Objects.requireNonNull(fecha, "La fecha de emisión es obligatoria");
+        if (!vencimiento.isAfter(fecha.toLocalDate())) {
+            throw new IllegalArgumentException("El vencimiento tiene que ser posterior al día de emisión");
+        }
+        return new TarjetaRegalo(TarjetaRegaloId.generate(), localId, generarCodigo(), monto, fecha, vencimiento,
+            comprador, destinatario, movimientoCajaId, List.of());
+    }
+
+    public static String generarCodigo() {
+        StringBuilder codigo = new StringBuilder(LONGITUD_CODIGO);
+        for (int i = 0; i < LONGITUD_CODIGO; i++) {
+            codigo.append(ALFABETO.charAt(RANDOM.nextInt(ALFABETO.length())));
+        }
+        return codigo.toString();
+    }
+
+    /**
+     * Pasa el código a la forma en que se guarda: mayúsculas, sin guiones ni espacios.
+     *
+     * @throws IllegalArgumentException si el código está vacío
+     */
+    public static String normalizarCodigo(String codigo) {
+        if (codigo == null || codigo.isBlank()) {
+            throw new IllegalArgumentException("El código de la tarjeta de regalo es obligatorio");
+        }
+        return codigo.replaceAll("[\\s-]", "").toUpperCase(Locale.ROOT);
+    }
+
+    private static String validarNombre(String nombre, String campo) {
+        if (nombre == null || nombre.isBlank()) {
+            return null;
+        }
+        String limpio = nombre.trim();
+        if (limpio.length() > LONGITUD_MAXIMA_NOMBRE) {
+            throw new IllegalArgumentException(campo + " no puede superar los " + LONGITUD_MAXIMA_NOMBRE + " caracteres");
+        }
+        return limpio;
+    }
+
+    // ============================================
+    // Comportamiento
+    // ============================================
+
+    /**
+     * Descuenta del saldo lo que se paga con la tarjeta en un pedido.
+     *
+     * @throws IllegalStateException si la tarjeta venció o el saldo no alcanza
+     * @throws IllegalArgumentException si el monto no es positivo
+     */
+    public void consumir(PedidoId pedidoId, BigDecimal monto, LocalDateTime fecha) {
+        Objects.requireNonNull(monto, "El monto a consumir es obligatorio");
+        Objects.requireNonNull(fecha, "La fecha del consumo es obligatoria");
+        if (estaVencida(fecha.toLocalDate())) {
+            throw new IllegalStateException(
+                "La tarjeta de regalo " + codigo + " venció el " + vencimiento.format(FORMATO_FECHA));
+        }
+        if (monto.compareTo(getSaldo()) > 0) {
+            throw new IllegalStateException(
+                "La tarjeta de regalo " + codigo + " tiene $" + getSaldo() + " de saldo");
+        }
+        consumos.add(new ConsumoTarjetaRegalo(pedidoId, monto, fecha));
+    }
+
+    /**
+     * Devuelve al saldo lo consumido en el pedido (reapertura).
+     *
+     * @return el monto reintegrado, cero si la tarjeta no se usó en el pedido
+     */
+    public BigDecimal reintegrar(PedidoId pedidoId) {
+        Objects.requireNonNull(pedidoId, "El pedido es obligatorio");
+        BigDecimal reintegrado = consumidoEn(pedidoId);
+        consumos.removeIf(consumo -> consumo.getPedidoId().equals(pedidoId));
+        return reintegrado;
+    }
+
+    public BigDecimal consumidoEn(PedidoId pedidoId) {
+        return consumos.stream()
+            .filter(consumo -> consumo.getPedidoId().equals(pedidoId))
+            .map(ConsumoTarjetaRegalo::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    public BigDecimal getSaldo() {
+        return montoInicial.subtract(consumos.stream()
+            .map(ConsumoTarjetaRegalo::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add));
+    }
+
+    /**
+     * @return true si el día ya pasó el vencimiento (el día de vencimiento todavía sirve)
+     */
+    public boolean estaVencida(LocalDate dia) {
+        return dia.isAfter(vencimiento);
+    }
+
+    public EstadoTarjetaRegalo getEstado(LocalDate dia) {
+        if (getSaldo().signum() == 0) {
+            return EstadoTarjetaRegalo.AGOTADA;
+        }
+        return estaVencida(dia) ? EstadoTarjetaRegalo.VENCIDA : EstadoTarjetaRegalo.VIGENTE;
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public TarjetaRegaloId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public String getCodigo() {
+        return codigo;
+    }
+
+    public BigDecimal getMontoInicial() {
+        return montoInicial;
+    }
+
+    public LocalDateTime getFechaEmision() {
+        return fechaEmision;
+    }
+
+    public LocalDate getVencimiento() {
+        return vencimiento;
+    }
+
+    public String getComprador() {
+        return comprador;
+    }
+
+    public String getDestinatario() {
+        return destinatario;
+    }
+
+    public MovimientoCajaId getMovimientoCajaId() {
+        return movimientoCajaId;
+    }
+
+    public List<ConsumoTarjetaRegalo> getConsumos() {
+        return List.copyOf(consumos);
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        TarjetaRegalo that = (TarjetaRegalo) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
@@ -0,0 +1,139 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.Moneda;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.format.DateTimeFormatter;
+import java.util.EnumMap;
+import java.util.Map;
+import java.util.Objects;
+
+/**
+ * Tipos de cambio de un local para un día operativo.
+ * Hay a lo sumo una configuración por local y día: la identidad es el par
+ * LocalId + fecha.
+ *
+ * HU-151: Cobros en moneda extranjera.
+ *
+ * Reglas de negocio:
+ * - Cada moneda extranjera tiene una cotización en pesos por unidad, mayor a
+ *   cero y con hasta cuatro decimales. El peso no se cotiza.
+ * - Sin cotización cargada para el día, no se aceptan pagos en esa moneda:
+ *   no se arrastra la del día anterior.
+ * - El tipo de cambio queda congelado en cada pago; cambiarlo después no
+ *   modifica lo ya cobrado.
+ *
+ * Ejemplo: USD = 1200 → un pago que cubre $24.000 en dólares registra USD 20.
+ */
+public class TiposCambio {
+
+    private static final DateTimeFormatter FORMATO_FECHA = DateTimeFormatter.ofPattern("dd/MM");
+
+    private final LocalId localId;
+    private final LocalDate fecha;
+    private Map<Moneda, BigDecimal> cotizaciones;
+
+    public TiposCambio(LocalId localId, LocalDate fecha, Map<Moneda, BigDecimal> cotizaciones) {
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.fecha = Objects.requireNonNull(fecha, "La fecha de los tipos de cambio no puede ser null");
+        this.cotizaciones = validarCotizaciones(cotizaciones);
+    }
+
+    /**
+     * Día sin cotizaciones cargadas: solo se cobra en pesos.
+     */
+    public static TiposCambio sinCotizar(LocalId localId, LocalDate fecha) {
+        return new TiposCambio(localId, fecha, Map.of());
+    }
+
+    // ============================================
+    // Validaciones
+    // ============================================
+
+    private Map<Moneda, BigDecimal> validarCotizaciones(Map<Moneda, BigDecimal> cotizaciones) {
+        Objects.requireNonNull(cotizaciones, "Las cotizaciones son obligatorias");
+        Map<Moneda, BigDecimal> limpias = new EnumMap<>(Moneda.class);
+        cotizaciones.forEach((moneda, cotizacion) -> {
+            Objects.requireNonNull(moneda, "La moneda de la cotización no puede ser null");
+            if (cotizacion == null) {
+                return;
+            }
+            if (moneda == Moneda.ARS) {
+                throw new IllegalArgumentException("El peso no lleva tipo de cambio");
+            }
+            if (cotizacion.signum() <= 0) {
+                throw new IllegalArgumentException("La cotización del " + moneda + " debe ser mayor a cero");
+            }
+            if (cotizacion.stripTrailingZeros().scale() > 4) {
+                throw new IllegalArgumentException("La cotización del " + moneda + " admite hasta cuatro decimales");
+            }
+            limpias.put(moneda, cotizacion);
+        });
+        return limpias;
+    }
+
+    // ============================================
+    // Comportamiento
+    // ============================================
+
+    public void actualizar(Map<Moneda, BigDecimal> cotizaciones) {
+        this.cotizaciones = validarCotizaciones(cotizaciones);
+    }
+
+    /**
+     * @return pesos por unidad de la moneda (1 para pesos)
+     * @throws IllegalStateException si la moneda no tiene cotización cargada para el día
+     */
+    public BigDecimal cotizacionDe(Moneda moneda) {
+        Objects.requireNonNull(moneda, "La moneda es obligatoria");
+        if (moneda == Moneda.ARS) {
+            return BigDecimal.ONE;
+        }
+        BigDecimal cotizacion = cotizaciones.get(moneda);
+        if (cotizacion == null) {
+            throw new IllegalStateException(
+                "No hay tipo de cambio del " + moneda + " cargado para el " + fecha.format(FORMATO_FECHA));
+        }
+        return cotizacion;
+    }
+
+    /**
+     * Registra el pago en la moneda indicada con la cotización del día congelada.
+     *
+     * @throws IllegalStateException si la moneda no tiene cotización cargada
+     * @throws IllegalArgumentException si el medio del pago no admite moneda extranjera
+     */
+    public Pago convertir(Pago enPesos, Moneda moneda) {
+        Objects.requireNonNull(enPesos, "El pago es obligatorio");
+        if (moneda == null || moneda == Moneda.ARS) {
+            return enPesos;
+        }
+        return enPesos.enMoneda(moneda, cotizacionDe(moneda));
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public LocalDate getFecha() {
+        return fecha;
+    }
+
+    /**
+     * @return solo las monedas cotizadas
+     */
+    public Map<Moneda, BigDecimal> getCotizaciones() {
+        return Map.copyOf(cotizaciones);
+    }
+
+    @Override
+    public String toString() {
+        return String.format("TiposCambio{localId=%s, fecha=%s, cotizaciones=%s}", localId, fecha, cotizaciones);
+    }
+}
@@ -0,0 +1,135 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TomaInventarioId;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.HashSet;
+import java.util.List;
+import java.util.Objects;
+import java.util.Set;
+
+/**
+ * Toma de inventario físico: el conteo de los insumos en un momento dado.
+ *
+ * HU-133: Inventario físico con reporte de diferencias.
+ *
+ * Reglas de negocio:
+ * - Tiene al menos un insumo contado y ninguno repetido.
+ * - No hace falta contar todos los insumos: se puede contar solo la cámara.
+ * - El responsable es quien hizo el conteo (máximo 60 caracteres, como el nombre de un empleado).
+ * - Es inmutable: el stock ya se ajustó con su conteo; si hubo un error, se hace otra toma.
+ */
+public class TomaInventario {
+
+    private static final int LONGITUD_MAXIMA_RESPONSABLE = 60;
+
+    private final TomaInventarioId id;
+    private final LocalId localId;
+    private final LocalDateTime fecha;
+    private final String responsable;
+    private final List<ConteoInsumo> conteos;
+
+    public TomaInventario(TomaInventarioId id, LocalId localId, LocalDateTime fecha, String responsable,
+                          List<ConteoInsumo> conteos) {
+        this.id = Objects.requireNonNull(id, "El id de la toma no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.fecha = Objects.requireNonNull(fecha, "La fecha de la toma no puede ser null");
+        this.responsable = validarResponsable(responsable);
+        this.conteos = validarConteos(conteos);
+    }
+
+    public static TomaInventario registrar(LocalId localId, String responsable, List<ConteoInsumo> conteos,
+                                           LocalDateTime fecha) {
+        return new TomaInventario(TomaInventarioId.generate(), localId, fecha, responsable, conteos);
+    }
+
+    /**
+     * Suma de las diferencias valorizadas: negativa si en total faltó mercadería.
+     */
+    public BigDecimal getDiferenciaValorizada() {
+        return conteos.stream()
+            .map(ConteoInsumo::getDiferenciaValorizada)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    /**
+     * Valor de lo que faltó, en positivo.
+     */
+    public BigDecimal getFaltanteValorizado() {
+        return conteos.stream()
+            .map(ConteoInsumo::getDiferenciaValorizada)
+            .filter(d -> d.signum() < 0)
+            .reduce(BigDecimal.ZERO, BigDecimal::add)
+            .negate();
+    }
+
+    public BigDecimal getSobranteValorizado() {
+        return conteos.stream()
+            .map(ConteoInsumo::getDiferenciaValorizada)
+            .filter(d -> d.signum() > 0)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    private String validarResponsable(String responsable) {
+        if (responsable == null || responsable.isBlank()) {
+            throw new IllegalArgumentException("El responsable de la toma de inventario es obligatorio");
+        }
+        String limpio = responsable.trim();
+        if (limpio.length() > LONGITUD_MAXIMA_RESPONSABLE) {
+            throw new IllegalArgumentException(
+                "El responsable no puede superar los " + LONGITUD_MAXIMA_RESPONSABLE + " caracteres"
+            );
+        }
+        return limpio;
+    }
+
+    private List<ConteoInsumo> validarConteos(List<ConteoInsumo> conteos) {
+        if (conteos == null || conteos.isEmpty()) {
+            throw new IllegalArgumentException("La toma de inventario debe tener al menos un insumo contado");
+        }
+        Set<InsumoId> vistos = new HashSet<>();
+        for (ConteoInsumo conteo : conteos) {
+            Objects.requireNonNull(conteo, "Los conteos de la toma no pueden ser null");
+            if (!vistos.add(conteo.getInsumoId())) {
+                throw new IllegalArgumentException(conteo.getNombreInsumo() + " está contado dos veces");
+            }
+        }
+        return List.copyOf(conteos);
+    }
+
+    public TomaInventarioId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public String getResponsable() {
+        return responsable;
+    }
+
+    public List<ConteoInsumo> getConteos() {
+        return conteos;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        TomaInventario that = (TomaInventario) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
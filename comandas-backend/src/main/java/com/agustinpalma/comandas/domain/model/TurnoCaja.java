@@ -0,0 +1,391 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoTurno;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoTurno;
+import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.Comparator;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Turno de caja (mañana / tarde / noche) dentro de una jornada abierta.
+ *
+ * HU-106: Gestión de turnos con arqueo por turno.
+ * HU-123: El arqueo puede cargarse como conteo por denominación; el
+ * declarado es entonces la suma del conteo y el detalle queda guardado.
+ * HU-129: Todo faltante o sobrante queda registrado con el responsable del
+ * turno y las observaciones del cierre, para el histórico por cajero.
+ *
+ * Ciclo de vida: ABIERTO → CERRADO (irreversible).
+ *
+ * Reglas de negocio:
+ * - Solo puede haber un turno ABIERTO por local a la vez.
+ * - Cada tipo de turno se abre a lo sumo una vez por jornada.
+ * - El monto inicial es el efectivo contado en el cajón al tomar el turno.
+ * - Los pedidos cerrados y los movimientos de caja registrados mientras el
+ *   turno está abierto quedan asociados a él.
+ * - Al cerrar, el cajero declara el efectivo contado y el turno congela su
+ *   arqueo: esperado, declarado y diferencia.
+ * - Si el arqueo tiene diferencia, el responsable del turno es obligatorio.
+ *
+ * Fórmula de arqueo del turno:
+ *   Esperado   = Monto Inicial + Ventas Efectivo + Ingresos − Egresos
+ *   Diferencia = Declarado − Esperado  (negativo = faltante, positivo = sobrante)
+ */
+public class TurnoCaja {
+
+    private static final int LONGITUD_MAXIMA_OBSERVACIONES = 500;
+
+    private final TurnoCajaId id;
+    private final LocalId localId;
+    private final JornadaCajaId jornadaId;
+    private final TipoTurno tipo;
+    private final BigDecimal montoInicial;
+    private final LocalDateTime fechaApertura;
+
+    // Snapshot de arqueo — se completa al cerrar
+    private LocalDateTime fechaCierre;
+    private BigDecimal totalVentas;
+    private BigDecimal ventasEfectivo;
+    private BigDecimal totalIngresos;
+    private BigDecimal totalEgresos;
+    private BigDecimal efectivoEsperado;
+    private BigDecimal efectivoDeclarado;
+    private int pedidosCerradosCount;
+    private EstadoTurno estado;
+    private List<ConteoDenominacion> denominaciones;
+    private MozoId responsableId;
+    private String observaciones;
+
+    /**
+     * Constructor de apertura: crea un turno ABIERTO.
+     *
+     * @param montoInicial efectivo contado en el cajón al abrir (≥ 0)
+     */
+    public TurnoCaja(TurnoCajaId id, LocalId localId, JornadaCajaId jornadaId,
+                     TipoTurno tipo, BigDecimal montoInicial, LocalDateTime fechaApertura) {
+        this.id = Objects.requireNonNull(id, "El id del turno no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.jornadaId = Objects.requireNonNull(jornadaId, "El turno debe pertenecer a una jornada");
+        this.tipo = Objects.requireNonNull(tipo, "El tipo de turno es obligatorio");
+        this.montoInicial = validarNoNegativo(montoInicial, "El monto inicial del turno");
+        this.fechaApertura = Objects.requireNonNull(fechaApertura, "La fecha de apertura no puede ser null");
+        this.estado = EstadoTurno.ABIERTO;
+        this.pedidosCerradosCount = 0;
+        this.denominaciones = List.of();
+    }
+
+    /**
+     * Constructor de reconstrucción desde persistencia.
+     * Los campos del arqueo son null mientras el turno está ABIERTO.
+     */
+    public TurnoCaja(TurnoCajaId id, LocalId localId, JornadaCajaId jornadaId,
+                     TipoTurno tipo, BigDecimal montoInicial,
+                     LocalDateTime fechaApertura, LocalDateTime fechaCierre,
+                     BigDecimal totalVentas, BigDecimal ventasEfectivo,
+                     BigDecimal totalIngresos, BigDecimal totalEgresos,
+                     BigDecimal efectivoEsperado, BigDecimal efectivoDeclarado,
+                     int pedidosCerradosCount, EstadoTurno estado) {
+        this(id, localId, jornadaId, tipo, montoInicial, fechaApertura, fechaCierre,
+            totalVentas, ventasEfectivo, totalIngresos, totalEgresos,
+            efectivoEsperado, efectivoDeclarado, pedidosCerradosCount, estado, List.of());
+    }
+
+    /**
+     * HU-123: Constructor de reconstrucción con el detalle del conteo.
+     *
+     * @param denominaciones conteo por denominación (vacío si se declaró solo el total)
+     */
+    public TurnoCaja(TurnoCajaId id, LocalId localId, JornadaCajaId jornadaId,
+                     TipoTurno tipo, BigDecimal montoInicial,
+                     LocalDateTime fechaApertura, LocalDateTime fechaCierre,
+                     BigDecimal totalVentas, BigDecimal ventasEfectivo,
+                     BigDecimal totalIngresos, BigDecimal totalEgresos,
+                     BigDecimal efectivoEsperado, BigDecimal efectivoDeclarado,
+                     int pedidosCerradosCount, EstadoTurno estado,
+                     List<ConteoDenominacion> denominaciones) {
+        this(id, localId, jornadaId, tipo, montoInicial, fechaApertura, fechaCierre,
+            totalVentas, ventasEfectivo, totalIngresos, totalEgresos,
+            efectivoEsperado, efectivoDeclarado, pedidosCerradosCount, estado, denominaciones, null, null);
+    }
+
+    /**
+     * HU-129: Constructor de reconstrucción completo.
+     *
+     * @param responsableId quién respondió por el arqueo (null en turnos abiertos o sin diferencia)
+     * @param observaciones explicación de la diferencia cargada al cerrar (null si no hay)
+     */
+    public TurnoCaja(TurnoCajaId id, LocalId localId, JornadaCajaId jornadaId,
+                     TipoTurno tipo, BigDecimal montoInicial,
+                     LocalDateTime fechaApertura, LocalDateTime fechaCierre,
+                     BigDecimal totalVentas, BigDecimal ventasEfectivo,
+                     BigDecimal totalIngresos, BigDecimal totalEgresos,
+                     BigDecimal efectivoEsperado, BigDecimal efectivoDeclarado,
+                     int pedidosCerradosCount, EstadoTurno estado,
+                     List<ConteoDenominacion> denominaciones,
+                     MozoId responsableId, String observaciones) {
+        this.id = Objects.requireNonNull(id, "El id del turno no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.jornadaId = Objects.requireNonNull(jornadaId, "El turno debe pertenecer a una jornada");
+        this.tipo = Objects.requireNonNull(tipo, "El tipo de turno es obligatorio");
+        this.montoInicial = Objects.requireNonNull(montoInicial, "El monto inicial no puede ser null");
+        this.fechaApertura = Objects.requireNonNull(fechaApertura, "La fecha de apertura no puede ser null");
+        this.fechaCierre = fechaCierre;
+        this.totalVentas = totalVentas;
+        this.ventasEfectivo = ventasEfectivo;
+        this.totalIngresos = totalIngresos;
+        this.totalEgresos = totalEgresos;
+        this.efectivoEsperado = efectivoEsperado;
+        this.efectivoDeclarado = efectivoDeclarado;
+        this.pedidosCerradosCount = pedidosCerradosCount;
+        this.estado = Objects.requireNonNull(estado, "El estado no puede ser null");
+        this.denominaciones = denominaciones != null ? List.copyOf(denominaciones) : List.of();
+        this.responsableId = responsableId;
+        this.observaciones = observaciones;
+    }
+
+    // ============================================
+    // Lógica de dominio
+    // ============================================
+
+    /**
+     * Cierra el turno congelando su arqueo.
+     *
+     * @param fechaCierre          momento del cierre
+     * @param totalVentas          pagos comerciales del turno (sin A_CUENTA)
+     * @param ventasEfectivo       pagos en EFECTIVO del turno
+     * @param totalIngresos        ingresos manuales de caja del turno
+     * @param totalEgresos         egresos de caja del turno
+     * @param pedidosCerradosCount pedidos cerrados durante el turno
+     * @param efectivoDeclarado    efectivo contado por el cajero al cerrar (≥ 0)
+     * @throws IllegalStateException si el turno ya está cerrado
+     */
+    public void cerrar(LocalDateTime fechaCierre,
+                       BigDecimal totalVentas, BigDecimal ventasEfectivo,
+                       BigDecimal totalIngresos, BigDecimal totalEgresos,
+                       int pedidosCerradosCount, BigDecimal efectivoDeclarado) {
+        if (this.estado != EstadoTurno.ABIERTO) {
+            throw new IllegalStateException("El turno " + tipo + " ya fue cerrado");
+        }
+        Objects.requireNonNull(fechaCierre, "La fecha de cierre no puede ser null");
+        if (fechaCierre.isBefore(fechaApertura)) {
+            throw new IllegalArgumentException("El cierre del turno no puede ser anterior a su apertura");
+        }
+        if (pedidosCerradosCount < 0) {
+            throw new IllegalArgumentException("El conteo de pedidos cerrados no puede ser negativo");
+        }
+
+        this.totalVentas = Objects.requireNonNull(totalVentas);
+        this.ventasEfectivo = Objects.requireNonNull(ventasEfectivo);
+        this.totalIngresos = Objects.requireNonNull(totalIngresos);
+        this.totalEgresos = Objects.requireNonNull(totalEgresos);
+        this.efectivoDeclarado = validarNoNegativo(efectivoDeclarado, "El efectivo declarado");
+        this.efectivoEsperado = montoInicial
+            .add(ventasEfectivo)
+            .add(totalIngresos)
+            .subtract(totalEgresos);
+        this.pedidosCerradosCount = pedidosCerradosCount;
+        this.fechaCierre = fechaCierre;
+        this.estado = EstadoTurno.CERRADO;
+    }
+
+    /**
+     * HU-123: Cierra el turno con el conteo del cajón por denominación.
+     * El efectivo declarado es la suma del conteo; se guarda el detalle
+     * ordenado de mayor a menor denominación, sin los renglones en cero.
+     *
+     * @param conteo cantidad contada por denominación (sin denominaciones repetidas)
+     * @throws IllegalArgumentException si el conteo está vacío o repite una denominación
+     * @throws IllegalStateException si el turno ya está cerrado
+     * @see #cerrar(LocalDateTime, BigDecimal, BigDecimal, BigDecimal, BigDecimal, int, BigDecimal)
+     */
+    public void cerrarConConteo(LocalDateTime fechaCierre,
+                                BigDecimal totalVentas, BigDecimal ventasEfectivo,
+                                BigDecimal totalIngresos, BigDecimal totalEgresos,
+                                int pedidosCerradosCount, List<ConteoDenominacion> conteo) {
+        Objects.requireNonNull(conteo, "El conteo de denominaciones es obligatorio");
+        if (conteo.isEmpty()) {
+            throw new IllegalArgumentException("El conteo de denominaciones no puede estar vacío");
+        }
+        long distintas = conteo.stream()
+            .map(c -> c.getDenominacion().stripTrailingZeros())
+            .distinct()
+            .count();
+        if (distintas != conteo.size()) {
+            throw new IllegalArgumentException("El conteo repite una denominación");
+        }
+
+        BigDecimal contado = conteo.stream()
+            .map(ConteoDenominacion::subtotal)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+
+        cerrar(fechaCierre, totalVentas, ventasEfectivo, totalIngresos, totalEgresos,
+            pedidosCerradosCount, contado);
+
+        this.denominaciones = conteo.stream()
+            .filter(c -> c.getCantidad() > 0)
+            .sorted(Comparator.comparing(ConteoDenominacion::getDenominacion).reversed())
+            .toList();
+    }
+
+    /**
+     * HU-129: Registra quién responde por el arqueo recién cerrado y por qué
+     * hubo diferencia. Se llama en el mismo cierre, antes de persistir.
+     *
+     * @param responsableId cajero a cargo del turno (obligatorio si hay diferencia)
+     * @param observaciones explicación libre (opcional, máximo 500 caracteres)
+     * @throws IllegalStateException si el turno sigue abierto
+     * @throws IllegalArgumentException si hay diferencia y no se indicó responsable
+     */
+    public void registrarResponsable(MozoId responsableId, String observaciones) {
+        if (estado != EstadoTurno.CERRADO) {
+            throw new IllegalStateException("El responsable se registra al cerrar el turno");
+        }
+        if (responsableId == null && tieneDiferencia()) {
+            throw new IllegalArgumentException(
+                "La caja no cierra: indicá el responsable del turno para registrar la diferencia");
+        }
+        String limpias = observaciones == null || observaciones.isBlank() ? null : observaciones.trim();
+        if (limpias != null && limpias.length() > LONGITUD_MAXIMA_OBSERVACIONES) {
+            throw new IllegalArgumentException(
+                "Las observaciones no pueden superar " + LONGITUD_MAXIMA_OBSERVACIONES + " caracteres");
+        }
+        this.responsableId = responsableId;
+        this.observaciones = limpias;
+    }
+
+    /**
+     * HU-129: El arqueo cerrado no coincide con lo esperado (faltante o sobrante).
+     */
+    public boolean tieneDiferencia() {
+        BigDecimal diferencia = getDiferencia();
+        return diferencia != null && diferencia.signum() != 0;
+    }
+
+    public boolean estaAbierto() {
+        return estado == EstadoTurno.ABIERTO;
+    }
+
+    /**
+     * Diferencia de caja del turno: declarado − esperado.
+     * Negativo = faltante, positivo = sobrante. Null mientras está abierto.
+     */
+    public BigDecimal getDiferencia() {
+        if (efectivoDeclarado == null || efectivoEsperado == null) {
+            return null;
+        }
+        return efectivoDeclarado.subtract(efectivoEsperado);
+    }
+
+    private BigDecimal validarNoNegativo(BigDecimal monto, String campo) {
+        Objects.requireNonNull(monto, campo + " es obligatorio");
+        if (monto.compareTo(BigDecimal.ZERO) < 0) {
+            throw new IllegalArgumentException(campo + " no puede ser negativo");
+        }
+        return monto;
+    }
+
+    // ============================================
+    // Getters
+    // ============================================
+
+    public TurnoCajaId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public JornadaCajaId getJornadaId() {
+        return jornadaId;
+    }
+
+    public TipoTurno getTipo() {
+        return tipo;
+    }
+
+    public BigDecimal getMontoInicial() {
+        return montoInicial;
+    }
+
+    public LocalDateTime getFechaApertura() {
+        return fechaApertura;
+    }
+
+    public LocalDateTime getFechaCierre() {
+        return fechaCierre;
+    }
+
+    public BigDecimal getTotalVentas() {
+        return totalVentas;
+    }
+
+    public BigDecimal getVentasEfectivo() {
+        return ventasEfectivo;
+    }
+
+    public BigDecimal getTotalIngresos() {
+        return totalIngresos;
+    }
+
+    public BigDecimal getTotalEgresos() {
+        return totalEgresos;
+    }
+
+    public BigDecimal getEfectivoEsperado() {
+        return efectivoEsperado;
+    }
+
+    public BigDecimal getEfectivoDeclarado() {
+        return efectivoDeclarado;
+    }
+
+    public int getPedidosCerradosCount() {
+        return pedidosCerradosCount;
+    }
+
+    public EstadoTurno getEstado() {
+        return estado;
+    }
+
+    /**
+     * HU-123: Detalle del conteo del cierre. Vacío si se declaró solo el total.
+     */
+    public List<ConteoDenominacion> getDenominaciones() {
+        return denominaciones;
+    }
+
+    public MozoId getResponsableId() {
+        return responsableId;
+    }
+
+    public String getObservaciones() {
+        return observaciones;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        TurnoCaja that = (TurnoCaja) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+
+    @Override
+    public String toString() {
+        return String.format("TurnoCaja{tipo=%s, estado=%s, montoInicial=%s, diferencia=%s}",
+            tipo, estado, montoInicial, getDiferencia());
+    }
+}
@@ -0,0 +1,126 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.SucursalId;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.Collections;
+import java.util.EnumMap;
+import java.util.Map;
+import java.util.Objects;
+import java.util.UUID;
+
+/**
+ * Venta cerrada recibida de otra sucursal para el consolidado.
+ *
+ * HU-107: Multi-sucursal con reportes consolidados.
+ *
+ * Es una copia de solo lectura del pedido cerrado en la sucursal de origen:
+ * no participa de la caja, las jornadas ni el stock de la instalación que
+ * la recibe. Solo alimenta el reporte comparativo por sucursal.
+ *
+ * La identidad es el UUID del pedido en origen, por lo que recibir el mismo
+ * lote dos veces no duplica ventas (la sincronización es idempotente).
+ */
+public class VentaSucursal {
+
+    private final UUID pedidoOrigenId;
+    private final LocalId localId;
+    private final SucursalId sucursalId;
+    private final String nombreSucursal;
+    private final int numeroPedido;
+    private final LocalDateTime fechaCierre;
+    private final Map<MedioPago, BigDecimal> pagos;
+    private final LocalDateTime fechaRecepcion;
+
+    /**
+     * @param pedidoOrigenId UUID del pedido en la sucursal de origen
+     * @param localId        tenant que consolida (la instalación que recibe)
+     * @param pagos          monto cobrado por medio de pago (incluye A_CUENTA)
+     * @param fechaRecepcion momento en que llegó al consolidado
+     */
+    public VentaSucursal(UUID pedidoOrigenId, LocalId localId, SucursalId sucursalId,
+                         String nombreSucursal, int numeroPedido, LocalDateTime fechaCierre,
+                         Map<MedioPago, BigDecimal> pagos, LocalDateTime fechaRecepcion) {
+        this.pedidoOrigenId = Objects.requireNonNull(pedidoOrigenId, "El id del pedido de origen es obligatorio");
+        this.localId = Objects.requireNonNull(localId, "El localId es obligatorio");
+        this.sucursalId = Objects.requireNonNull(sucursalId, "La sucursal de origen es obligatoria");
+        if (nombreSucursal == null || nombreSucursal.isBlank()) {
+            throw new IllegalArgumentException("El nombre de la sucursal es obligatorio");
+        }
+        this.nombreSucursal = nombreSucursal.trim();
+        this.numeroPedido = numeroPedido;
+        this.fechaCierre = Objects.requireNonNull(fechaCierre, "La fecha de cierre es obligatoria");
+        this.fechaRecepcion = Objects.requireNonNull(fechaRecepcion, "La fecha de recepción es obligatoria");
+
+        Objects.requireNonNull(pagos, "Los pagos son obligatorios");
+        EnumMap<MedioPago, BigDecimal> copia = new EnumMap<>(MedioPago.class);
+        pagos.forEach((medio, monto) -> {
+            Objects.requireNonNull(medio, "El medio de pago es obligatorio");
+            Objects.requireNonNull(monto, "El monto del pago es obligatorio");
+            if (monto.compareTo(BigDecimal.ZERO) < 0) {
+                throw new IllegalArgumentException("El monto pagado no puede ser negativo");
+            }
+            copia.merge(medio, monto, BigDecimal::add);
+        });
+        this.pagos = Collections.unmodifiableMap(copia);
+    }
+
+    /**
+     * Venta real de la sucursal: todos los pagos salvo A_CUENTA,
+     * con el mismo criterio que el arqueo diario.
+     */
+    public BigDecimal getTotalVentas() {
+        return pagos.entrySet().stream()
+            .filter(e -> e.getKey() != MedioPago.A_CUENTA)
+            .map(Map.Entry::getValue)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    public UUID getPedidoOrigenId() {
+        return pedidoOrigenId;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public SucursalId getSucursalId() {
+        return sucursalId;
+    }
+
+    public String getNombreSucursal() {
+        return nombreSucursal;
+    }
+
+    public int getNumeroPedido() {
+        return numeroPedido;
+    }
+
+    public LocalDateTime getFechaCierre() {
+        return fechaCierre;
+    }
+
+    public Map<MedioPago, BigDecimal> getPagos() {
+        return pagos;
+    }
+
+    public LocalDateTime getFechaRecepcion() {
+        return fechaRecepcion;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        VentaSucursal that = (VentaSucursal) o;
+        return Objects.equals(pedidoOrigenId, that.pedidoOrigenId);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(pedidoOrigenId);
+    }
+}
@@ -0,0 +1,210 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ZonaDeliveryId;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.text.Normalizer;
+import java.util.LinkedHashSet;
+import java.util.List;
+import java.util.Locale;
+import java.util.Objects;
+import java.util.Optional;
+import java.util.Set;
+
+/**
+ * Zona de reparto del local, con su costo de envío y mínimo de compra.
+ *
+ * HU-139: Zonas de delivery con costo de envío.
+ *
+ * Reglas de negocio:
+ * - El nombre es obligatorio (máximo 60 caracteres) y no se repite en el local.
+ * - El costo de envío y el mínimo de compra no pueden ser negativos;
+ *   mínimo cero = sin mínimo.
+ * - Los barrios son los nombres que se buscan en la dirección del cliente
+ *   para elegir la zona sola (sin distinguir mayúsculas ni acentos). Una zona
+ *   sin barrios solo se elige a mano.
+ * - El mínimo se compara contra la venta de productos: el envío no cuenta.
+ * - No se borra: se desactiva y deja de ofrecerse para pedidos nuevos.
+ */
+public class ZonaDelivery {
+
+    private static final int LONGITUD_MAXIMA_NOMBRE = 60;
+    private static final int LONGITUD_MAXIMA_BARRIO = 60;
+
+    private final ZonaDeliveryId id;
+    private final LocalId localId;
+    private String nombre;
+    private BigDecimal costoEnvio;
+    private BigDecimal montoMinimo;
+    private List<String> barrios;
+    private boolean activa;
+
+    public ZonaDelivery(ZonaDeliveryId id, LocalId localId, String nombre, BigDecimal costoEnvio,
+                        BigDecimal montoMinimo, List<String> barrios, boolean activa) {
+        this.id = Objects.requireNonNull(id, "El id de la zona no puede ser null");
+        this.localId = Objects.requireNonNull(localId, "El localId no puede ser null");
+        this.nombre = validarNombre(nombre);
+        this.costoEnvio = validarMonto(costoEnvio, "El costo de envío");
+        this.montoMinimo = validarMonto(montoMinimo, "El mínimo de compra");
+        this.barrios = validarBarrios(barrios);
+        this.activa = activa;
+    }
+
+    public static ZonaDelivery crear(LocalId localId, String nombre, BigDecimal costoEnvio,
+                                     BigDecimal montoMinimo, List<String> barrios) {
+        return new ZonaDelivery(ZonaDeliveryId.generate(), localId, nombre, costoEnvio, montoMinimo, barrios, true);
+    }
+
+    /**
+     * Cambiar el costo no toca los pedidos que ya tienen el envío cargado.
+     */
+    public void actualizar(String nombre, BigDecimal costoEnvio, BigDecimal montoMinimo, List<String> barrios,
+                           boolean activa) {
+        this.nombre = validarNombre(nombre);
+        this.costoEnvio = validarMonto(costoEnvio, "El costo de envío");
+        this.montoMinimo = validarMonto(montoMinimo, "El mínimo de compra");
+        this.barrios = validarBarrios(barrios);
+        this.activa = activa;
+    }
+
+    /**
+     * Barrio de la zona que aparece en la dirección, el más largo si hay varios.
+     *
+     * @return el barrio encontrado, o vacío si la dirección no es de esta zona
+     */
+    public Optional<String> barrioEn(String direccion) {
+        if (direccion == null || direccion.isBlank()) {
+            return Optional.empty();
+        }
+        String texto = " " + normalizar(direccion) + " ";
+        return barrios.stream()
+            .filter(barrio -> texto.contains(" " + normalizar(barrio) + " "))
+            .max((a, b) -> Integer.compare(a.length(), b.length()));
+    }
+
+    /**
+     * @param ventaProductos subtotal del pedido sin el envío
+     * @throws IllegalStateException si no llega al mínimo de compra de la zona
+     */
+    public void validarMinimo(BigDecimal ventaProductos) {
+        Objects.requireNonNull(ventaProductos, "La venta de productos no puede ser null");
+        if (ventaProductos.compareTo(montoMinimo) < 0) {
+            throw new IllegalStateException(String.format(
+                "El pedido no llega al mínimo de $%s para la zona %s (faltan $%s)",
+                montoMinimo.setScale(2, RoundingMode.HALF_UP), nombre,
+                montoMinimo.subtract(ventaProductos).setScale(2, RoundingMode.HALF_UP)
+            ));
+        }
+    }
+
+    public boolean tieneNombre(String otroNombre) {
+        return otroNombre != null && nombre.equalsIgnoreCase(otroNombre.trim());
+    }
+
+    /**
+     * Nombre con el que el envío figura como ítem del pedido.
+     */
+    public String nombreItemEnvio() {
+        return "Envío " + nombre;
+    }
+
+    /**
+     * Minúsculas, sin acentos y sin signos: "Bº Güemes," → "b guemes".
+     */
+    private static String normalizar(String texto) {
+        return Normalizer.normalize(texto, Normalizer.Form.NFD)
+            .replaceAll("\\p{M}", "")
+            .toLowerCase(Locale.ROOT)
+            .replaceAll("[^a-z0-9]+", " ")
+            .trim();
+    }
+
+    private String validarNombre(String nombre) {
+        if (nombre == null || nombre.isBlank()) {
+            throw new IllegalArgumentException("El nombre de la zona no puede estar vacío");
+        }
+        String limpio = nombre.trim();
+        if (limpio.length() > LONGITUD_MAXIMA_NOMBRE) {
+            throw new IllegalArgumentException(
+                "El nombre de la zona no puede superar los " + LONGITUD_MAXIMA_NOMBRE + " caracteres"
+            );
+        }
+        return limpio;
+    }
+
+    private BigDecimal validarMonto(BigDecimal monto, String campo) {
+        if (monto == null) {
+            return BigDecimal.ZERO;
+        }
+        if (monto.signum() < 0) {
+            throw new IllegalArgumentException(campo + " no puede ser negativo");
+        }
+        return monto;
+    }
+
+    private List<String> validarBarrios(List<String> barrios) {
+        if (barrios == null) {
+            return List.of();
+        }
+        Set<String> limpios = new LinkedHashSet<>();
+        for (String barrio : barrios) {
+            if (barrio == null || barrio.isBlank()) {
+                continue;
+            }
+            String limpio = barrio.trim();
+            if (limpio.length() > LONGITUD_MAXIMA_BARRIO) {
+                throw new IllegalArgumentException(
+                    "El barrio no puede superar los " + LONGITUD_MAXIMA_BARRIO + " caracteres: " + limpio
+                );
+            }
+            if (normalizar(limpio).isEmpty()) {
+                throw new IllegalArgumentException("El barrio tiene que tener letras o números: " + limpio);
+            }
+            limpios.add(limpio);
+        }
+        return List.copyOf(limpios);
+    }
+
+    public ZonaDeliveryId getId() {
+        return id;
+    }
+
+    public LocalId getLocalId() {
+        return localId;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public BigDecimal getCostoEnvio() {
+        return costoEnvio;
+    }
+
+    public BigDecimal getMontoMinimo() {
+        return montoMinimo;
+    }
+
+    public List<String> getBarrios() {
+        return barrios;
+    }
+
+    public boolean isActiva() {
+        return activa;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (o == null || getClass() != o.getClass()) return false;
+        ZonaDelivery that = (ZonaDelivery) o;
+        return Objects.equals(id, that.id);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(id);
+    }
+}
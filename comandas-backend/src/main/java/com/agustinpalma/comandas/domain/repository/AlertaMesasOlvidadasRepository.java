@@ -0,0 +1,29 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.AlertaMesasOlvidadas;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de la configuración de la alerta de mesas olvidadas.
+ *
+ * HU-113: Alerta de mesas olvidadas.
+ */
+public interface AlertaMesasOlvidadasRepository {
+
+    /**
+     * Busca la configuración de la alerta del local.
+     *
+     * @param localId identificador del local
+     * @return Optional vacío si el local nunca la configuró
+     */
+    Optional<AlertaMesasOlvidadas> buscarPorLocal(LocalId localId);
+
+    /**
+     * Persiste la configuración (alta o actualización, una por local).
+     *
+     * @param alerta la configuración a guardar
+     * @return la configuración guardada
+     */
+    AlertaMesasOlvidadas guardar(AlertaMesasOlvidadas alerta);
+}
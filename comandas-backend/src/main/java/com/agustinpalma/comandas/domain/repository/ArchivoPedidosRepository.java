@@ -0,0 +1,45 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.Pedido;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del archivo de pedidos cerrados.
+ *
+ * HU-162: Los pedidos cerrados más viejos que el horizonte de retención salen
+ * de la base operativa y quedan acá, comprimidos y de solo lectura. Los
+ * reportes por fecha los siguen viendo a través de
+ * {@link PedidoRepository#buscarCerradosPorFecha}.
+ */
+public interface ArchivoPedidosRepository {
+
+    /**
+     * Guarda los pedidos en el archivo. No los borra de la base operativa.
+     *
+     * @param pedidos pedidos cerrados a archivar
+     * @param archivadoEn momento del archivado
+     */
+    void archivar(List<Pedido> pedidos, LocalDateTime archivadoEn);
+
+    /**
+     * @param localId identificador del local (tenant)
+     * @param inicio inicio del rango de cierre (inclusive)
+     * @param fin fin del rango de cierre (inclusive)
+     * @return pedidos archivados cerrados en el rango, con ítems y pagos
+     */
+    List<Pedido> buscarCerradosPorFecha(LocalId localId, LocalDateTime inicio, LocalDateTime fin);
+
+    /**
+     * @return el número de pedido más alto archivado del local, o 0 si no hay
+     */
+    int obtenerMaximoNumero(LocalId localId);
+
+    /**
+     * @return el comprobante archivado más alto del local con ese encabezado (HU-166)
+     */
+    Optional<String> buscarUltimoComprobante(LocalId localId, String encabezado);
+}
@@ -0,0 +1,32 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.AumentoPrecios;
+import com.agustinpalma.comandas.domain.model.DomainIds.AumentoPreciosId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.time.LocalDate;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de aumentos masivos de precios.
+ *
+ * HU-145: Actualización masiva de precios.
+ */
+public interface AumentoPreciosRepository {
+
+    AumentoPrecios guardar(AumentoPrecios aumento);
+
+    Optional<AumentoPrecios> buscarPorId(AumentoPreciosId id, LocalId localId);
+
+    /**
+     * Aumentos del local, del más nuevo al más viejo.
+     */
+    List<AumentoPrecios> buscarPorLocal(LocalId localId);
+
+    /**
+     * Aumentos PROGRAMADO de todos los locales con vigencia hasta el día dado,
+     * del más viejo al más nuevo (así se aplican en el orden en que se pidieron).
+     */
+    List<AumentoPrecios> buscarProgramadosHasta(LocalDate dia);
+}
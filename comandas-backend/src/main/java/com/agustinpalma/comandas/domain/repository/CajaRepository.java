@@ -0,0 +1,40 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.AperturaCaja;
+import com.agustinpalma.comandas.domain.model.Caja;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de cajas y de sus aperturas por jornada.
+ * HU-159: Varias cajas en el mismo local.
+ */
+public interface CajaRepository {
+
+    Caja guardar(Caja caja);
+
+    Optional<Caja> buscarPorId(CajaId id, LocalId localId);
+
+    /**
+     * Cajas del local ordenadas por fecha de alta.
+     */
+    List<Caja> buscarPorLocal(LocalId localId);
+
+    /**
+     * Si ya hay una caja con ese nombre en el local, sin distinguir mayúsculas.
+     */
+    boolean existePorNombre(LocalId localId, String nombre);
+
+    AperturaCaja guardarApertura(AperturaCaja apertura);
+
+    /**
+     * Aperturas de la jornada ordenadas por fecha de apertura.
+     */
+    List<AperturaCaja> buscarAperturas(JornadaCajaId jornadaId, LocalId localId);
+
+    Optional<AperturaCaja> buscarApertura(JornadaCajaId jornadaId, CajaId cajaId, LocalId localId);
+}
@@ -0,0 +1,28 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.CambioPrecioProducto;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+
+import java.time.LocalDateTime;
+import java.util.List;
+
+/**
+ * Contrato del registro de auditoría de precios del catálogo.
+ *
+ * HU-146: Historial de cambios de precios.
+ */
+public interface CambioPrecioProductoRepository {
+
+    CambioPrecioProducto guardar(CambioPrecioProducto cambio);
+
+    /**
+     * Cambios de un producto, del más nuevo al más viejo.
+     */
+    List<CambioPrecioProducto> buscarPorProducto(ProductoId productoId, LocalId localId);
+
+    /**
+     * Cambios del local con fecha en [desde, hasta), del más viejo al más nuevo.
+     */
+    List<CambioPrecioProducto> buscarPorLocalEntre(LocalId localId, LocalDateTime desde, LocalDateTime hasta);
+}
@@ -0,0 +1,40 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.CategoriaEgreso;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaEgresoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de categorías de egresos de caja.
+ * HU-130: Categorías de egresos y reporte mensual de gastos.
+ */
+public interface CategoriaEgresoRepository {
+
+    /**
+     * Persiste una categoría (alta o modificación).
+     *
+     * @param categoria la categoría a guardar
+     * @return la categoría guardada
+     */
+    CategoriaEgreso guardar(CategoriaEgreso categoria);
+
+    /**
+     * Busca una categoría por id, restringida al local.
+     *
+     * @param id identificador de la categoría
+     * @param localId identificador del local (tenant)
+     * @return la categoría si existe y pertenece al local
+     */
+    Optional<CategoriaEgreso> buscarPorId(CategoriaEgresoId id, LocalId localId);
+
+    /**
+     * Lista las categorías del local (activas e inactivas) ordenadas por nombre.
+     *
+     * @param localId identificador del local (tenant)
+     * @return categorías del local
+     */
+    List<CategoriaEgreso> buscarPorLocal(LocalId localId);
+}
@@ -0,0 +1,25 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.ClienteEnEspera;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteEnEsperaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de la lista de espera de mesas.
+ *
+ * HU-149: Lista de espera de mesas.
+ */
+public interface ClienteEnEsperaRepository {
+
+    ClienteEnEspera guardar(ClienteEnEspera cliente);
+
+    Optional<ClienteEnEspera> buscarPorId(ClienteEnEsperaId id, LocalId localId);
+
+    /**
+     * Clientes ESPERANDO o AVISADOS, por orden de llegada.
+     */
+    List<ClienteEnEspera> buscarEnLista(LocalId localId);
+}
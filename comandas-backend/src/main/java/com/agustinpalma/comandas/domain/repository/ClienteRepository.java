@@ -0,0 +1,52 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.Cliente;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de clientes con cuenta corriente.
+ * Define las operaciones de persistencia sin acoplarse a tecnologías específicas.
+ *
+ * HU-104: Cuenta corriente de clientes.
+ */
+public interface ClienteRepository {
+
+    /**
+     * Busca un cliente por su identificador y local.
+     * Útil para validar multi-tenancy.
+     *
+     * @param id identificador del cliente
+     * @param localId identificador del local
+     * @return Optional con el cliente si pertenece al local
+     */
+    Optional<Cliente> buscarPorIdYLocal(ClienteId id, LocalId localId);
+
+    /**
+     * Busca todos los clientes de un local, ordenados por nombre.
+     *
+     * @param localId identificador del local
+     * @return lista de clientes (puede estar vacía)
+     */
+    List<Cliente> buscarPorLocal(LocalId localId);
+
+    /**
+     * Persiste un cliente nuevo o actualiza uno existente.
+     *
+     * @param cliente el cliente a guardar
+     * @return el cliente guardado
+     */
+    Cliente guardar(Cliente cliente);
+
+    /**
+     * Verifica si existe un cliente con el nombre especificado en el local.
+     * La comparación es case-insensitive.
+     *
+     * @param nombre nombre del cliente
+     * @param localId identificador del local
+     * @return true si existe
+     */
+    boolean existePorNombreYLocal(String nombre, LocalId localId);
+}
@@ -0,0 +1,69 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.ComandaCocina;
+import com.agustinpalma.comandas.domain.model.DomainIds.ComandaCocinaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de comandas de cocina.
+ * HU-119: Tiempos de cocina y alertas de demora.
+ */
+public interface ComandaCocinaRepository {
+
+    /**
+     * Persiste la comanda (alta o marca de lista).
+     *
+     * @param comanda la comanda a guardar
+     * @return la comanda guardada
+     */
+    ComandaCocina guardar(ComandaCocina comanda);
+
+    /**
+     * Busca una comanda por id, restringida al local.
+     *
+     * @param id identificador de la comanda
+     * @param localId identificador del local (tenant)
+     * @return la comanda si existe y pertenece al local
+     */
+    Optional<ComandaCocina> buscarPorId(ComandaCocinaId id, LocalId localId);
+
+    /**
+     * Comandas que cocina todavía no marcó como listas, de la más vieja a la más nueva.
+     *
+     * @param localId identificador del local (tenant)
+     * @return comandas en preparación
+     */
+    List<ComandaCocina> buscarPendientes(LocalId localId);
+
+    /**
+     * HU-137: Comandas de un pedido que siguen en preparación.
+     *
+     * @param pedidoId identificador del pedido
+     * @param localId identificador del local (tenant)
+     * @return comandas del pedido todavía no listas
+     */
+    List<ComandaCocina> buscarPendientesDePedido(PedidoId pedidoId, LocalId localId);
+
+    /**
+     * HU-136: La última comanda que cocina marcó como lista.
+     *
+     * @param localId identificador del local (tenant)
+     * @return la de marca de lista más reciente, si hay alguna
+     */
+    Optional<ComandaCocina> buscarUltimaLista(LocalId localId);
+
+    /**
+     * Comandas listas enviadas dentro del rango [desde, hasta).
+     *
+     * @param localId identificador del local (tenant)
+     * @param desde inicio del rango (inclusive)
+     * @param hasta fin del rango (exclusive)
+     * @return comandas listas del período
+     */
+    List<ComandaCocina> buscarListasEnviadasEntre(LocalId localId, LocalDateTime desde, LocalDateTime hasta);
+}
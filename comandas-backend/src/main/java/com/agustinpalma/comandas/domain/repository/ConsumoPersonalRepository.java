@@ -0,0 +1,37 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.ConsumoPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de consumos internos del personal.
+ *
+ * HU-132: Límites de consumo interno del personal.
+ */
+public interface ConsumoPersonalRepository {
+
+    ConsumoPersonal guardar(ConsumoPersonal consumo);
+
+    /**
+     * Consumos del local en el rango [desde, hasta), ordenados por fecha.
+     */
+    List<ConsumoPersonal> buscarPorLocal(LocalId localId, LocalDateTime desde, LocalDateTime hasta);
+
+    /**
+     * Consumos de un empleado en el rango [desde, hasta), ordenados por fecha.
+     */
+    List<ConsumoPersonal> buscarPorEmpleado(MozoId mozoId, LocalId localId, LocalDateTime desde, LocalDateTime hasta);
+
+    /**
+     * El consumo imputado por un pedido, si lo hay (a lo sumo uno por pedido).
+     */
+    Optional<ConsumoPersonal> buscarPorPedido(PedidoId pedidoId, LocalId localId);
+
+    void eliminarPorPedido(PedidoId pedidoId, LocalId localId);
+}
@@ -0,0 +1,32 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DistribucionPropinas;
+import com.agustinpalma.comandas.domain.model.DomainIds.DistribucionPropinasId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de repartos del pozo de propinas.
+ *
+ * HU-141: Pozo de propinas y distribución.
+ */
+public interface DistribucionPropinasRepository {
+
+    DistribucionPropinas guardar(DistribucionPropinas distribucion);
+
+    Optional<DistribucionPropinas> buscarPorId(DistribucionPropinasId id, LocalId localId);
+
+    /**
+     * @return el reparto del turno, vacío si el turno no tuvo reparto
+     */
+    Optional<DistribucionPropinas> buscarPorTurno(TurnoCajaId turnoId, LocalId localId);
+
+    /**
+     * Repartos calculados en el rango [inicio, fin), del más reciente al más viejo.
+     */
+    List<DistribucionPropinas> buscarEntre(LocalId localId, LocalDateTime inicio, LocalDateTime fin);
+}
@@ -0,0 +1,62 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaProveedorId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+import com.agustinpalma.comandas.domain.model.FacturaProveedor;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.Set;
+
+/**
+ * Contrato del repositorio de facturas de proveedores y sus pagos.
+ * HU-131: Cuenta corriente de proveedores.
+ */
+public interface FacturaProveedorRepository {
+
+    /**
+     * Persiste una factura junto con sus pagos (alta o modificación).
+     *
+     * @param factura la factura a guardar
+     * @return la factura guardada
+     */
+    FacturaProveedor guardar(FacturaProveedor factura);
+
+    /**
+     * Busca una factura por id, restringida al local.
+     *
+     * @param id identificador de la factura
+     * @param localId identificador del local (tenant)
+     * @return la factura si existe y pertenece al local
+     */
+    Optional<FacturaProveedor> buscarPorId(FacturaProveedorId id, LocalId localId);
+
+    /**
+     * Facturas de un proveedor, de la que vence primero a la última.
+     *
+     * @param proveedorId identificador del proveedor
+     * @param localId identificador del local (tenant)
+     * @return todas las facturas del proveedor, pagas o no
+     */
+    List<FacturaProveedor> buscarPorProveedor(ProveedorId proveedorId, LocalId localId);
+
+    /**
+     * Facturas del local con saldo pendiente, de la que vence primero a la última.
+     *
+     * @param localId identificador del local (tenant)
+     * @return facturas impagas o pagadas en parte
+     */
+    List<FacturaProveedor> buscarImpagas(LocalId localId);
+
+    /**
+     * Egresos de caja generados por pagos a proveedores asentados en el rango.
+     * Sirve para no contar como gasto el pago de una compra que ya se contó.
+     *
+     * @param desde fecha del pago inicial (inclusive)
+     * @param hasta fecha del pago final (inclusive)
+     */
+    Set<MovimientoCajaId> buscarMovimientosCajaAsociados(LocalId localId, LocalDateTime desde, LocalDateTime hasta);
+}
@@ -0,0 +1,60 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoFactura;
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.Factura;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de facturas.
+ * HU-115: Emisión de Factura A/B/C.
+ */
+public interface FacturaRepository {
+
+    /**
+     * Persiste una factura (emisión o autorización).
+     *
+     * @param factura la factura a guardar
+     * @return la factura guardada
+     */
+    Factura guardar(Factura factura);
+
+    /**
+     * Busca una factura por id, restringida al local.
+     *
+     * @param id identificador de la factura
+     * @param localId identificador del local (tenant)
+     * @return la factura si existe y pertenece al local
+     */
+    Optional<Factura> buscarPorId(FacturaId id, LocalId localId);
+
+    /**
+     * Busca la factura emitida por un pedido.
+     *
+     * @param pedidoId identificador del pedido
+     * @param localId identificador del local (tenant)
+     * @return la factura si el pedido ya fue facturado
+     */
+    Optional<Factura> buscarPorPedido(PedidoId pedidoId, LocalId localId);
+
+    /**
+     * HU-116: Facturas emitidas en un período, para el libro IVA ventas.
+     *
+     * @param desde inicio del período (inclusive)
+     * @param hasta fin del período (exclusive)
+     * @return facturas ordenadas por fecha de emisión
+     */
+    List<Factura> buscarPorPeriodo(LocalId localId, LocalDateTime desde, LocalDateTime hasta);
+
+    /**
+     * Último número usado en una serie (local + punto de venta + letra).
+     *
+     * @return el último número emitido, o 0 si la serie no tiene facturas
+     */
+    long ultimoNumero(LocalId localId, int puntoVenta, TipoFactura tipo);
+}
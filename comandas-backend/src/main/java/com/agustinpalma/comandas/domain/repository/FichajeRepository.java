@@ -0,0 +1,53 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.FichajeId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Fichaje;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de fichajes del personal.
+ * HU-124: Control horario.
+ */
+public interface FichajeRepository {
+
+    /**
+     * Persiste un fichaje (entrada, salida o corrección).
+     *
+     * @param fichaje el fichaje a guardar
+     * @return el fichaje guardado
+     */
+    Fichaje guardar(Fichaje fichaje);
+
+    /**
+     * Busca un fichaje por id, restringido al local.
+     *
+     * @param id identificador del fichaje
+     * @param localId identificador del local (tenant)
+     * @return el fichaje si existe y pertenece al local
+     */
+    Optional<Fichaje> buscarPorId(FichajeId id, LocalId localId);
+
+    /**
+     * Último fichaje sin salida del empleado.
+     *
+     * @param localId identificador del local (tenant)
+     * @param mozoId identificador del empleado
+     * @return el fichaje abierto más reciente, si hay
+     */
+    Optional<Fichaje> buscarAbierto(LocalId localId, MozoId mozoId);
+
+    /**
+     * Fichajes cuya entrada cae en el rango [desde, hasta), ordenados por entrada.
+     *
+     * @param localId identificador del local (tenant)
+     * @param desde inicio del rango (inclusive)
+     * @param hasta fin del rango (exclusive)
+     * @return fichajes del rango
+     */
+    List<Fichaje> buscarPorRango(LocalId localId, LocalDateTime desde, LocalDateTime hasta);
+}
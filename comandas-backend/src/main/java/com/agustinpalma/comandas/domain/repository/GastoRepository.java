@@ -0,0 +1,69 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.ComprobanteAdjunto;
+import com.agustinpalma.comandas.domain.model.DomainIds.GastoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.Gasto;
+
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.Set;
+
+/**
+ * Contrato del repositorio de compras y gastos.
+ * HU-117: Compras a proveedores y gastos.
+ *
+ * El archivo del comprobante se guarda aparte del gasto para no cargarlo
+ * cada vez que se lista o se arma el reporte mensual.
+ */
+public interface GastoRepository {
+
+    /**
+     * Persiste un gasto (alta o modificación).
+     *
+     * @param gasto el gasto a guardar
+     * @return el gasto guardado
+     */
+    Gasto guardar(Gasto gasto);
+
+    /**
+     * Busca un gasto por id, restringido al local.
+     *
+     * @param id identificador del gasto
+     * @param localId identificador del local (tenant)
+     * @return el gasto si existe y pertenece al local
+     */
+    Optional<Gasto> buscarPorId(GastoId id, LocalId localId);
+
+    /**
+     * Gastos del local con fecha de comprobante dentro del rango.
+     *
+     * @param localId identificador del local (tenant)
+     * @param desde fecha inicial (inclusive)
+     * @param hasta fecha final (inclusive)
+     * @return gastos del rango, del más reciente al más antiguo
+     */
+    List<Gasto> buscarPorPeriodo(LocalId localId, LocalDate desde, LocalDate hasta);
+
+    /**
+     * Egresos de caja generados por gastos registrados en el rango.
+     * Sirve para no contar dos veces un gasto pagado con la caja.
+     *
+     * @param desde fecha de registro inicial (inclusive)
+     * @param hasta fecha de registro final (inclusive)
+     */
+    Set<MovimientoCajaId> buscarMovimientosCajaAsociados(LocalId localId, LocalDateTime desde, LocalDateTime hasta);
+
+    /**
+     * Guarda (o reemplaza) el archivo del comprobante de un gasto.
+     */
+    void guardarComprobante(GastoId id, ComprobanteAdjunto comprobante);
+
+    /**
+     * @return el archivo del comprobante, vacío si el gasto no tiene
+     */
+    Optional<ComprobanteAdjunto> buscarComprobante(GastoId id);
+}
@@ -0,0 +1,40 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.Insumo;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de insumos.
+ * HU-118: Costeo de recetas y rentabilidad por producto.
+ */
+public interface InsumoRepository {
+
+    /**
+     * Persiste un insumo (alta o modificación).
+     *
+     * @param insumo el insumo a guardar
+     * @return el insumo guardado
+     */
+    Insumo guardar(Insumo insumo);
+
+    /**
+     * Busca un insumo por id, restringido al local.
+     *
+     * @param id identificador del insumo
+     * @param localId identificador del local (tenant)
+     * @return el insumo si existe y pertenece al local
+     */
+    Optional<Insumo> buscarPorId(InsumoId id, LocalId localId);
+
+    /**
+     * Lista los insumos del local ordenados por nombre.
+     *
+     * @param localId identificador del local (tenant)
+     * @return insumos del local
+     */
+    List<Insumo> buscarPorLocal(LocalId localId);
+}
@@ -0,0 +1,24 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio del límite de jornada comercial.
+ *
+ * HU-153: Límite de jornada comercial configurable.
+ */
+public interface JornadaComercialRepository {
+
+    /**
+     * @return Optional vacío si el local nunca configuró la hora de corte
+     */
+    Optional<JornadaComercial> buscarPorLocal(LocalId localId);
+
+    /**
+     * Persiste la configuración (alta o actualización, una por local).
+     */
+    JornadaComercial guardar(JornadaComercial jornadaComercial);
+}
@@ -0,0 +1,60 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LinkPagoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.LinkPago;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de links de pago.
+ * HU-128: Links de pago para delivery.
+ */
+public interface LinkPagoRepository {
+
+    /**
+     * Persiste el link (alta, pago o cancelación).
+     *
+     * @param link el link a guardar
+     * @return el link guardado
+     */
+    LinkPago guardar(LinkPago link);
+
+    /**
+     * Busca un link por id, restringido al local.
+     *
+     * @param id identificador del link
+     * @param localId identificador del local (tenant)
+     * @return el link si existe y pertenece al local
+     */
+    Optional<LinkPago> buscarPorId(LinkPagoId id, LocalId localId);
+
+    /**
+     * Link del pedido que todavía espera el pago, si lo hay.
+     * Es lo que frena el envío a cocina.
+     *
+     * @param pedidoId pedido cobrado por link
+     * @param localId identificador del local (tenant)
+     * @return el link PENDIENTE del pedido
+     */
+    Optional<LinkPago> buscarPendientePorPedido(PedidoId pedidoId, LocalId localId);
+
+    /**
+     * Último link generado para el pedido, en cualquier estado.
+     *
+     * @param pedidoId pedido cobrado por link
+     * @param localId identificador del local (tenant)
+     * @return el link más reciente del pedido
+     */
+    Optional<LinkPago> buscarUltimoPorPedido(PedidoId pedidoId, LocalId localId);
+
+    /**
+     * Links que esperan el pago, del más viejo al más nuevo.
+     *
+     * @param localId identificador del local (tenant)
+     * @return links PENDIENTE del local
+     */
+    List<LinkPago> buscarPendientes(LocalId localId);
+}
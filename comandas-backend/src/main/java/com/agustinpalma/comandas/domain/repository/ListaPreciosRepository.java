@@ -0,0 +1,50 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.ListaPrecios;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainIds.ListaPreciosId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de listas de precios.
+ * HU-108: Listas de precios por canal.
+ */
+public interface ListaPreciosRepository {
+
+    /**
+     * Persiste una lista (alta o modificación).
+     *
+     * @param lista la lista a guardar
+     * @return la lista guardada
+     */
+    ListaPrecios guardar(ListaPrecios lista);
+
+    /**
+     * Busca la lista configurada para un canal.
+     *
+     * @param localId identificador del local (tenant)
+     * @param canal canal de venta
+     * @return la lista si existe, vacío si el canal vende a precio de catálogo
+     */
+    Optional<ListaPrecios> buscarPorLocalYCanal(LocalId localId, CanalVenta canal);
+
+    /**
+     * Busca una lista por id, restringida al local.
+     *
+     * @param id identificador de la lista
+     * @param localId identificador del local (tenant)
+     * @return la lista si existe y pertenece al local
+     */
+    Optional<ListaPrecios> buscarPorId(ListaPreciosId id, LocalId localId);
+
+    /**
+     * Lista todas las listas configuradas del local.
+     *
+     * @param localId identificador del local (tenant)
+     * @return listas del local, vacío si ninguna está configurada
+     */
+    List<ListaPrecios> buscarPorLocal(LocalId localId);
+}
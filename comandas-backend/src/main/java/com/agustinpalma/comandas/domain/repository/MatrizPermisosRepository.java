@@ -0,0 +1,29 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.MatrizPermisos;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de la matriz de permisos del local.
+ *
+ * HU-169: Matriz de permisos por rol y por empleado.
+ */
+public interface MatrizPermisosRepository {
+
+    /**
+     * Busca la matriz de permisos del local.
+     *
+     * @param localId identificador del local
+     * @return Optional vacío si el local nunca configuró la matriz
+     */
+    Optional<MatrizPermisos> buscarPorLocal(LocalId localId);
+
+    /**
+     * Persiste la matriz (alta o actualización, una por local).
+     *
+     * @param matriz la matriz a guardar
+     * @return la matriz guardada
+     */
+    MatrizPermisos guardar(MatrizPermisos matriz);
+}
@@ -0,0 +1,24 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.Merma;
+
+import java.time.LocalDateTime;
+import java.util.List;
+
+/**
+ * Contrato del repositorio de mermas.
+ * HU-135: Registro de mermas y desperdicio.
+ */
+public interface MermaRepository {
+
+    Merma guardar(Merma merma);
+
+    /**
+     * @param localId identificador del local (tenant)
+     * @param desde inicio del rango (inclusive)
+     * @param hasta fin del rango (inclusive)
+     * @return mermas registradas en el rango, de la más reciente a la más antigua
+     */
+    List<Merma> buscarPorFecha(LocalId localId, LocalDateTime desde, LocalDateTime hasta);
+}
@@ -37,6 +37,15 @@ public interface MesaRepository {
      */
     Mesa guardar(Mesa mesa);
 
+    /**
+     * HU-112: Persiste varias mesas en una sola operación.
+     * Debe tolerar que dos mesas intercambien su número (cada una toma
+     * el número que tenía la otra) pese a la restricción de unicidad.
+     *
+     * @param mesas mesas a guardar
+     */
+    void guardarTodas(List<Mesa> mesas);
+
     /**
      * Verifica si ya existe una mesa con el número dado en un local específico.
      * Necesario para validar unicidad del número de mesa dentro del local.
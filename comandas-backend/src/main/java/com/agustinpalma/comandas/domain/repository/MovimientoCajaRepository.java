@@ -2,6 +2,8 @@ package com.agustinpalma.comandas.domain.repository;
 
 import com.agustinpalma.comandas.domain.model.MovimientoCaja;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
 
 import java.time.LocalDateTime;
 import java.util.List;
@@ -31,4 +33,22 @@ public interface MovimientoCajaRepository {
      * @return lista de movimientos en el rango
      */
     List<MovimientoCaja> buscarPorFecha(LocalId localId, LocalDateTime inicio, LocalDateTime fin);
+
+    /**
+     * HU-106: Busca los movimientos de caja registrados durante un turno.
+     *
+     * @param localId identificador del local (tenant)
+     * @param turnoId identificador del turno
+     * @return movimientos del turno ordenados por fecha
+     */
+    List<MovimientoCaja> buscarPorTurno(LocalId localId, TurnoCajaId turnoId);
+
+    /**
+     * HU-121: Busca las devoluciones registradas sobre un pedido cerrado.
+     *
+     * @param localId identificador del local (tenant)
+     * @param pedidoId identificador del pedido reintegrado
+     * @return devoluciones del pedido ordenadas por fecha
+     */
+    List<MovimientoCaja> buscarDevolucionesPorPedido(LocalId localId, PedidoId pedidoId);
 }
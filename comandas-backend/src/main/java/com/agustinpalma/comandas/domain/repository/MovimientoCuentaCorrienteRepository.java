@@ -0,0 +1,35 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.MovimientoCuentaCorriente;
+import java.util.List;
+
+/**
+ * Contrato del repositorio de movimientos de cuenta corriente.
+ * Define las operaciones de persistencia sin acoplarse a tecnologías específicas.
+ *
+ * HU-104: Cuenta corriente de clientes.
+ */
+public interface MovimientoCuentaCorrienteRepository {
+
+    MovimientoCuentaCorriente guardar(MovimientoCuentaCorriente movimiento);
+
+    /**
+     * Todos los movimientos del cliente ordenados por fecha ascendente.
+     * La deuda actual se deriva de esta lista.
+     */
+    List<MovimientoCuentaCorriente> buscarPorCliente(ClienteId clienteId, LocalId localId);
+
+    /**
+     * Cargos originados por un pedido (a lo sumo uno por pedido).
+     * Se usan para revertir o reemplazar el cargo al reabrir o corregir el pedido.
+     */
+    List<MovimientoCuentaCorriente> buscarCargosPorPedido(PedidoId pedidoId, LocalId localId);
+
+    /**
+     * Elimina los cargos originados por un pedido.
+     */
+    void eliminarCargosPorPedido(PedidoId pedidoId, LocalId localId);
+}
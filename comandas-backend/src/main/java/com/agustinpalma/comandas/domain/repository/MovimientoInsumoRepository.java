@@ -0,0 +1,23 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.MovimientoInsumo;
+
+import java.util.List;
+
+/**
+ * Contrato del repositorio de movimientos de stock de insumos.
+ * HU-133: Inventario físico con reporte de diferencias.
+ */
+public interface MovimientoInsumoRepository {
+
+    MovimientoInsumo guardar(MovimientoInsumo movimiento);
+
+    /**
+     * @param insumoId insumo a consultar
+     * @param localId identificador del local (tenant)
+     * @return movimientos del insumo, del más reciente al más antiguo
+     */
+    List<MovimientoInsumo> buscarPorInsumo(InsumoId insumoId, LocalId localId);
+}
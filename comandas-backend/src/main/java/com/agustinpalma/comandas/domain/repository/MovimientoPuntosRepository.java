@@ -0,0 +1,46 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.MovimientoPuntos;
+import java.util.List;
+
+/**
+ * Contrato del repositorio de movimientos de puntos.
+ * Define las operaciones de persistencia sin acoplarse a tecnologías específicas.
+ *
+ * HU-105: Programa de puntos de clientes.
+ */
+public interface MovimientoPuntosRepository {
+
+    MovimientoPuntos guardar(MovimientoPuntos movimiento);
+
+    /**
+     * Todos los movimientos del cliente ordenados por fecha ascendente.
+     * El saldo disponible se deriva de esta lista.
+     */
+    List<MovimientoPuntos> buscarPorCliente(ClienteId clienteId, LocalId localId);
+
+    /**
+     * Todos los movimientos del local ordenados por fecha ascendente.
+     * Se usa para el reporte de puntos emitidos, canjeados y vencidos.
+     */
+    List<MovimientoPuntos> buscarPorLocal(LocalId localId);
+
+    /**
+     * Movimientos (acumulación y canje) originados por un pedido.
+     */
+    List<MovimientoPuntos> buscarPorPedido(PedidoId pedidoId, LocalId localId);
+
+    /**
+     * Elimina los movimientos originados por un pedido.
+     */
+    void eliminarPorPedido(PedidoId pedidoId, LocalId localId);
+
+    /**
+     * Elimina solo las acumulaciones de un pedido, conservando su canje.
+     * Se usa al corregir el pedido para recalcular los puntos ganados.
+     */
+    void eliminarAcumulacionesPorPedido(PedidoId pedidoId, LocalId localId);
+}
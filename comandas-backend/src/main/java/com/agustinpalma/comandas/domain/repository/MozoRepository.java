@@ -0,0 +1,49 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Mozo;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de mozos.
+ * HU-111: Reporte de desempeño por mozo.
+ */
+public interface MozoRepository {
+
+    /**
+     * Persiste un mozo (alta o modificación).
+     *
+     * @param mozo el mozo a guardar
+     * @return el mozo guardado
+     */
+    Mozo guardar(Mozo mozo);
+
+    /**
+     * Busca un mozo por id, restringido al local.
+     *
+     * @param id identificador del mozo
+     * @param localId identificador del local (tenant)
+     * @return el mozo si existe y pertenece al local
+     */
+    Optional<Mozo> buscarPorId(MozoId id, LocalId localId);
+
+    /**
+     * Lista todos los mozos del local, activos e inactivos.
+     *
+     * @param localId identificador del local (tenant)
+     * @return mozos del local
+     */
+    List<Mozo> buscarPorLocal(LocalId localId);
+
+    /**
+     * HU-124: Busca al empleado por el código de su tarjeta de fichaje.
+     *
+     * @param localId identificador del local (tenant)
+     * @param codigoTarjeta código leído por el lector
+     * @return el empleado con esa tarjeta, si hay
+     */
+    Optional<Mozo> buscarPorTarjeta(LocalId localId, String codigoTarjeta);
+}
@@ -1,9 +1,13 @@
 package com.agustinpalma.comandas.domain.repository;
 
+import com.agustinpalma.comandas.domain.exception.PedidoModificadoException;
 import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.SerieComprobantes;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
 import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
 
 import java.time.LocalDateTime;
@@ -20,8 +24,15 @@ public interface PedidoRepository {
     /**
      * Persiste un pedido nuevo o actualiza uno existente.
      *
+     * HU-165: Un pedido existente solo se actualiza si su versión sigue siendo
+     * la que se leyó; el guardado sube la versión. Esto vale también para los
+     * cambios que no traen una versión esperada
+     * ({@link com.agustinpalma.comandas.domain.model.Pedido#SIN_CONTROL_DE_VERSION}):
+     * un guardado cruzado se rechaza igual.
+     *
      * @param pedido el pedido a guardar
-     * @return el pedido guardado
+     * @return el pedido guardado, con la versión nueva
+     * @throws PedidoModificadoException si otro guardado cambió el pedido desde que se leyó
      */
     Pedido guardar(Pedido pedido);
 
@@ -36,12 +47,23 @@ public interface PedidoRepository {
     /**
      * Obtiene el siguiente número de pedido disponible para un local.
      * Calcula el máximo número existente + 1 para ese local.
+     * HU-162: incluye los pedidos archivados, para que la numeración no vuelva a empezar.
      *
      * @param localId identificador del local
      * @return el siguiente número secuencial disponible
      */
     int obtenerSiguienteNumero(LocalId localId);
 
+    /**
+     * HU-166: Último comprobante emitido en el local con ese encabezado, sea
+     * cual sea la serie que lo emitió. Incluye los pedidos archivados.
+     *
+     * @param localId identificador del local
+     * @param encabezado comienzo del número ({@link SerieComprobantes#encabezado})
+     * @return el comprobante más alto con ese encabezado, o vacío si nunca se emitió ninguno
+     */
+    Optional<String> buscarUltimoComprobante(LocalId localId, String encabezado);
+
     /**
      * Busca un pedido en un estado específico para una mesa dada.
      * Utilizado para validar que no exista otro pedido abierto antes de crear uno nuevo.
@@ -75,7 +97,71 @@ public interface PedidoRepository {
      * @param localId identificador del local (tenant)
      * @param inicio inicio del rango temporal (inclusive)
      * @param fin fin del rango temporal (inclusive)
+     * HU-162: incluye los pedidos que ya pasaron al archivo.
+     *
      * @return lista de pedidos cerrados con sus pagos cargados
      */
     List<Pedido> buscarCerradosPorFecha(LocalId localId, LocalDateTime inicio, LocalDateTime fin);
+
+    /**
+     * HU-106: Busca los pedidos cerrados (cobrados) durante un turno de caja.
+     *
+     * IMPORTANTE: igual que buscarCerradosPorFecha, debe cargar los pagos con JOIN FETCH.
+     *
+     * @param localId identificador del local (tenant)
+     * @param turnoId identificador del turno
+     * @return pedidos cerrados del turno con sus pagos cargados
+     */
+    List<Pedido> buscarCerradosPorTurno(LocalId localId, TurnoCajaId turnoId);
+
+    /**
+     * HU-168: Pedidos cerrados de un cliente de la ficha, los más recientes primero.
+     * Cubre la base operativa: los pedidos que ya pasaron al archivo (HU-162) no entran.
+     *
+     * IMPORTANTE: igual que buscarCerradosPorFecha, debe cargar los pagos con JOIN FETCH.
+     *
+     * @param clienteId cliente vinculado al pedido
+     * @param localId identificador del local (tenant)
+     * @return pedidos cerrados del cliente con sus pagos cargados
+     */
+    List<Pedido> buscarCerradosPorCliente(ClienteId clienteId, LocalId localId);
+
+    /**
+     * HU-113: Busca los pedidos abiertos de un local (una mesa ocupada cada uno).
+     *
+     * @param localId identificador del local (tenant)
+     * @return pedidos en estado ABIERTO
+     */
+    List<Pedido> buscarAbiertosPorLocal(LocalId localId);
+
+    /**
+     * HU-140: Pedidos con repartidor asignado cuya apertura cae en el rango,
+     * estén abiertos o ya cobrados.
+     *
+     * IMPORTANTE: igual que buscarCerradosPorFecha, debe cargar los pagos con JOIN FETCH.
+     *
+     * @param localId identificador del local (tenant)
+     * @param inicio inicio del rango de apertura (inclusive)
+     * @param fin fin del rango de apertura (exclusive)
+     * @return pedidos asignados a un repartidor, con sus pagos cargados
+     */
+    List<Pedido> buscarRepartosEntre(LocalId localId, LocalDateTime inicio, LocalDateTime fin);
+
+    /**
+     * HU-162: Pedidos cerrados antes del límite, de todos los locales, los más viejos primero.
+     * Los usa el archivado para sacarlos de la base operativa por lotes.
+     *
+     * @param limite fecha de cierre tope (exclusive)
+     * @param maximo tamaño del lote
+     * @return pedidos cerrados con ítems y pagos cargados
+     */
+    List<Pedido> buscarCerradosAntesDe(LocalDateTime limite, int maximo);
+
+    /**
+     * HU-162: Borra los pedidos (con sus ítems y pagos) de la base operativa.
+     * Solo lo usa el archivado, después de copiarlos al archivo.
+     *
+     * @param ids identificadores de los pedidos a borrar
+     */
+    void eliminar(List<PedidoId> ids);
 }
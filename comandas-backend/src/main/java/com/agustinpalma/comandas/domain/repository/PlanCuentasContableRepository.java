@@ -0,0 +1,24 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.PlanCuentasContable;
+
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio del plan de cuentas contable del local.
+ *
+ * HU-167: Exportación al sistema contable.
+ */
+public interface PlanCuentasContableRepository {
+
+    /**
+     * @return Optional vacío si el local nunca asignó cuentas
+     */
+    Optional<PlanCuentasContable> buscarPorLocal(LocalId localId);
+
+    /**
+     * Persiste la configuración (alta o actualización, una por local).
+     */
+    PlanCuentasContable guardar(PlanCuentasContable plan);
+}
@@ -1,16 +1,22 @@
 package com.agustinpalma.comandas.domain.repository;
 
+import com.agustinpalma.comandas.domain.model.FotoProducto;
 import com.agustinpalma.comandas.domain.model.Producto;
 import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import java.time.LocalDateTime;
 import java.util.List;
+import java.util.Map;
 import java.util.Optional;
 
 /**
  * Contrato del repositorio de productos.
  * Define las operaciones de persistencia sin acoplarse a tecnologías específicas.
  * La implementación concreta reside en la capa de infraestructura.
+ *
+ * HU-127: La foto de la carta se guarda aparte del producto para no
+ * cargarla cada vez que el POS lista el catálogo.
  */
 public interface ProductoRepository {
 
@@ -130,4 +136,28 @@ public interface ProductoRepository {
      * @return lista de productos modificadores estructurales (puede estar vacía)
      */
     List<Producto> buscarModificadoresEstructurales(LocalId localId);
+
+    /**
+     * HU-127: Guarda (o reemplaza) la foto de un producto.
+     */
+    void guardarFoto(ProductoId id, LocalId localId, FotoProducto foto);
+
+    /**
+     * HU-127: @return la foto del producto, vacío si no tiene
+     */
+    Optional<FotoProducto> buscarFoto(ProductoId id, LocalId localId);
+
+    /**
+     * HU-127: Quita la foto del producto (sin efecto si no tenía).
+     */
+    void eliminarFoto(ProductoId id, LocalId localId);
+
+    /**
+     * HU-127: Fecha de la foto vigente de cada producto que tiene foto,
+     * sin traer los archivos. La carta la usa como versión de la imagen.
+     *
+     * @param localId identificador del local
+     * @return productos con foto y la fecha de su última foto
+     */
+    Map<ProductoId, LocalDateTime> buscarVersionesFotos(LocalId localId);
 }
@@ -0,0 +1,29 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.ProgramaPuntos;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de la configuración del programa de puntos.
+ *
+ * HU-105: Programa de puntos de clientes.
+ */
+public interface ProgramaPuntosRepository {
+
+    /**
+     * Busca la configuración del programa del local.
+     *
+     * @param localId identificador del local
+     * @return Optional vacío si el local nunca configuró el programa
+     */
+    Optional<ProgramaPuntos> buscarPorLocal(LocalId localId);
+
+    /**
+     * Persiste la configuración (alta o actualización, una por local).
+     *
+     * @param programa la configuración a guardar
+     * @return la configuración guardada
+     */
+    ProgramaPuntos guardar(ProgramaPuntos programa);
+}
@@ -0,0 +1,29 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.PromoCumpleanios;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de la configuración de la promo de cumpleaños.
+ *
+ * HU-168: Ficha de clientes con promo de cumpleaños.
+ */
+public interface PromoCumpleaniosRepository {
+
+    /**
+     * Busca la configuración de la promo del local.
+     *
+     * @param localId identificador del local
+     * @return Optional vacío si el local nunca configuró la promo
+     */
+    Optional<PromoCumpleanios> buscarPorLocal(LocalId localId);
+
+    /**
+     * Persiste la configuración (alta o actualización, una por local).
+     *
+     * @param promo la configuración a guardar
+     * @return la configuración guardada
+     */
+    PromoCumpleanios guardar(PromoCumpleanios promo);
+}
@@ -0,0 +1,40 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+import com.agustinpalma.comandas.domain.model.Proveedor;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de proveedores.
+ * HU-131: Cuenta corriente de proveedores.
+ */
+public interface ProveedorRepository {
+
+    /**
+     * Persiste un proveedor (alta o modificación).
+     *
+     * @param proveedor el proveedor a guardar
+     * @return el proveedor guardado
+     */
+    Proveedor guardar(Proveedor proveedor);
+
+    /**
+     * Busca un proveedor por id, restringido al local.
+     *
+     * @param id identificador del proveedor
+     * @param localId identificador del local (tenant)
+     * @return el proveedor si existe y pertenece al local
+     */
+    Optional<Proveedor> buscarPorId(ProveedorId id, LocalId localId);
+
+    /**
+     * Lista los proveedores del local (activos e inactivos) ordenados por nombre.
+     *
+     * @param localId identificador del local (tenant)
+     * @return proveedores del local
+     */
+    List<Proveedor> buscarPorLocal(LocalId localId);
+}
@@ -0,0 +1,24 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.RecargosMedioPago;
+
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de los recargos por medio de pago.
+ *
+ * HU-143: Recargo configurable por medio de pago.
+ */
+public interface RecargosMedioPagoRepository {
+
+    /**
+     * @return Optional vacío si el local nunca configuró recargos
+     */
+    Optional<RecargosMedioPago> buscarPorLocal(LocalId localId);
+
+    /**
+     * Persiste la configuración (alta o actualización, una por local).
+     */
+    RecargosMedioPago guardar(RecargosMedioPago recargos);
+}
@@ -0,0 +1,44 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Receta;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de recetas.
+ * HU-118: Una receta por producto, identificada por el producto.
+ */
+public interface RecetaRepository {
+
+    /**
+     * Persiste la receta (alta o reemplazo de ingredientes).
+     *
+     * @param receta la receta a guardar
+     * @return la receta guardada
+     */
+    Receta guardar(Receta receta);
+
+    /**
+     * @param productoId producto dueño de la receta
+     * @param localId identificador del local (tenant)
+     * @return la receta del producto, si tiene
+     */
+    Optional<Receta> buscarPorProducto(ProductoId productoId, LocalId localId);
+
+    /**
+     * @param localId identificador del local (tenant)
+     * @return todas las recetas del local
+     */
+    List<Receta> buscarPorLocal(LocalId localId);
+
+    /**
+     * Elimina la receta del producto, si existe.
+     *
+     * @param productoId producto dueño de la receta
+     * @param localId identificador del local (tenant)
+     */
+    void eliminar(ProductoId productoId, LocalId localId);
+}
@@ -0,0 +1,24 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.RedondeoEfectivo;
+
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de la regla de redondeo del efectivo.
+ *
+ * HU-152: Reglas de redondeo configurables en el total.
+ */
+public interface RedondeoEfectivoRepository {
+
+    /**
+     * @return Optional vacío si el local nunca configuró redondeo
+     */
+    Optional<RedondeoEfectivo> buscarPorLocal(LocalId localId);
+
+    /**
+     * Persiste la configuración (alta o actualización, una por local).
+     */
+    RedondeoEfectivo guardar(RedondeoEfectivo redondeo);
+}
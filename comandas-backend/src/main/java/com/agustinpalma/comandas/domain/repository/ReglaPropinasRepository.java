@@ -0,0 +1,24 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.ReglaPropinas;
+
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de la regla de reparto de propinas.
+ *
+ * HU-141: Pozo de propinas y distribución.
+ */
+public interface ReglaPropinasRepository {
+
+    /**
+     * @return Optional vacío si el local nunca configuró la regla
+     */
+    Optional<ReglaPropinas> buscarPorLocal(LocalId localId);
+
+    /**
+     * Persiste la regla (alta o actualización, una por local).
+     */
+    ReglaPropinas guardar(ReglaPropinas regla);
+}
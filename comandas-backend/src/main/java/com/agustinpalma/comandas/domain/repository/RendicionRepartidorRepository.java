@@ -0,0 +1,22 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+import com.agustinpalma.comandas.domain.model.RendicionRepartidor;
+
+import java.util.List;
+
+/**
+ * Contrato del repositorio de rendiciones de efectivo de los repartidores.
+ *
+ * HU-140: Asignación y seguimiento de repartidores.
+ */
+public interface RendicionRepartidorRepository {
+
+    RendicionRepartidor guardar(RendicionRepartidor rendicion);
+
+    /**
+     * Rendiciones de un turno (a lo sumo una por repartidor), ordenadas por fecha.
+     */
+    List<RendicionRepartidor> buscarPorTurno(TurnoCajaId turnoId, LocalId localId);
+}
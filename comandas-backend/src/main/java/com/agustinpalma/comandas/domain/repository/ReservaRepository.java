@@ -0,0 +1,32 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ReservaId;
+import com.agustinpalma.comandas.domain.model.Reserva;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de reservas de mesa.
+ *
+ * HU-142: Señas de reservas aplicadas a la cuenta.
+ */
+public interface ReservaRepository {
+
+    Reserva guardar(Reserva reserva);
+
+    Optional<Reserva> buscarPorId(ReservaId id, LocalId localId);
+
+    /**
+     * Reservas con fecha y hora en [inicio, fin), ordenadas por horario.
+     */
+    List<Reserva> buscarEntre(LocalId localId, LocalDateTime inicio, LocalDateTime fin);
+
+    /**
+     * Reservas PENDIENTES asignadas a la mesa, ordenadas por horario.
+     */
+    List<Reserva> buscarPendientesPorMesa(MesaId mesaId, LocalId localId);
+}
@@ -0,0 +1,33 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.SerieComprobantes;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de series de numeración de comprobantes.
+ * HU-166: Una serie por caja; la de la caja principal va con cajaId null.
+ */
+public interface SerieComprobantesRepository {
+
+    SerieComprobantes guardar(SerieComprobantes serie);
+
+    /**
+     * @param cajaId caja de la serie (null = caja principal)
+     */
+    Optional<SerieComprobantes> buscar(LocalId localId, CajaId cajaId);
+
+    /**
+     * Como {@link #buscar}, pero deja la serie bloqueada hasta que termine la
+     * transacción: otro cierre de la misma caja espera a que este guarde el
+     * número que tomó. Se llama dentro de la transacción del cierre.
+     *
+     * @param cajaId caja de la serie (null = caja principal)
+     */
+    Optional<SerieComprobantes> buscarParaAsignar(LocalId localId, CajaId cajaId);
+
+    List<SerieComprobantes> buscarPorLocal(LocalId localId);
+}
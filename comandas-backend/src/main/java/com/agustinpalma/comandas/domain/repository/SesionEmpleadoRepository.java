@@ -0,0 +1,35 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.SesionEmpleado;
+
+import java.time.LocalDateTime;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de las sesiones de los empleados en las terminales.
+ *
+ * HU-169: Matriz de permisos por rol y por empleado.
+ */
+public interface SesionEmpleadoRepository {
+
+    /**
+     * @param sesion la sesión recién abierta
+     */
+    void guardar(SesionEmpleado sesion);
+
+    /**
+     * @param hashToken hash del token ({@link SesionEmpleado#hash})
+     * @return la sesión, vigente o no; vacío si no existe
+     */
+    Optional<SesionEmpleado> buscarPorHash(String hashToken);
+
+    /**
+     * Cierra la sesión. Si no existe, no hace nada.
+     */
+    void eliminar(String hashToken);
+
+    /**
+     * Borra las sesiones vencidas de todos los locales.
+     */
+    void eliminarVencidas(LocalDateTime ahora);
+}
@@ -0,0 +1,50 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.SolicitudAutopedidoId;
+import com.agustinpalma.comandas.domain.model.SolicitudAutopedido;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de pedidos armados por los clientes desde el QR.
+ * HU-126: Autopedido por QR.
+ */
+public interface SolicitudAutopedidoRepository {
+
+    /**
+     * Persiste la solicitud (alta, confirmación o rechazo).
+     *
+     * @param solicitud la solicitud a guardar
+     * @return la solicitud guardada
+     */
+    SolicitudAutopedido guardar(SolicitudAutopedido solicitud);
+
+    /**
+     * Busca una solicitud por id, restringida al local.
+     *
+     * @param id identificador de la solicitud
+     * @param localId identificador del local (tenant)
+     * @return la solicitud si existe y pertenece al local
+     */
+    Optional<SolicitudAutopedido> buscarPorId(SolicitudAutopedidoId id, LocalId localId);
+
+    /**
+     * Solicitudes que el mozo todavía no resolvió, de la más vieja a la más nueva.
+     *
+     * @param localId identificador del local (tenant)
+     * @return solicitudes pendientes de todo el salón
+     */
+    List<SolicitudAutopedido> buscarPendientes(LocalId localId);
+
+    /**
+     * Cantidad de solicitudes pendientes de una mesa.
+     *
+     * @param localId identificador del local (tenant)
+     * @param mesaId mesa desde la que se envió
+     * @return solicitudes sin resolver de esa mesa
+     */
+    long contarPendientesPorMesa(LocalId localId, MesaId mesaId);
+}
@@ -0,0 +1,35 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.TarjetaRegalo;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de tarjetas de regalo.
+ *
+ * HU-144: Gift cards y vouchers prepagos.
+ */
+public interface TarjetaRegaloRepository {
+
+    TarjetaRegalo guardar(TarjetaRegalo tarjeta);
+
+    /**
+     * @param codigo código ya normalizado
+     */
+    Optional<TarjetaRegalo> buscarPorCodigo(String codigo, LocalId localId);
+
+    boolean existeCodigo(String codigo, LocalId localId);
+
+    /**
+     * Todas las tarjetas del local, de la más nueva a la más vieja.
+     */
+    List<TarjetaRegalo> buscarPorLocal(LocalId localId);
+
+    /**
+     * Tarjetas con algún consumo en el pedido.
+     */
+    List<TarjetaRegalo> buscarUsadasEnPedido(PedidoId pedidoId, LocalId localId);
+}
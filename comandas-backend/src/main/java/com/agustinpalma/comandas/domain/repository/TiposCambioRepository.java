@@ -0,0 +1,25 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.TiposCambio;
+
+import java.time.LocalDate;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de los tipos de cambio por día.
+ *
+ * HU-151: Cobros en moneda extranjera.
+ */
+public interface TiposCambioRepository {
+
+    /**
+     * @return Optional vacío si el local no cargó cotizaciones para ese día
+     */
+    Optional<TiposCambio> buscarPorFecha(LocalId localId, LocalDate fecha);
+
+    /**
+     * Persiste las cotizaciones del día (alta o actualización, una por local y día).
+     */
+    TiposCambio guardar(TiposCambio tiposCambio);
+}
@@ -0,0 +1,36 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TomaInventarioId;
+import com.agustinpalma.comandas.domain.model.TomaInventario;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de tomas de inventario físico.
+ * HU-133: Inventario físico con reporte de diferencias.
+ */
+public interface TomaInventarioRepository {
+
+    /**
+     * Persiste una toma de inventario con sus conteos.
+     *
+     * @param toma la toma a guardar
+     * @return la toma guardada
+     */
+    TomaInventario guardar(TomaInventario toma);
+
+    /**
+     * @param id identificador de la toma
+     * @param localId identificador del local (tenant)
+     * @return la toma si existe y pertenece al local
+     */
+    Optional<TomaInventario> buscarPorId(TomaInventarioId id, LocalId localId);
+
+    /**
+     * @param localId identificador del local (tenant)
+     * @return tomas del local, de la más reciente a la más antigua
+     */
+    List<TomaInventario> buscarPorLocal(LocalId localId);
+}
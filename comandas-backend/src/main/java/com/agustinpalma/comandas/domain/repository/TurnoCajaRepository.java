@@ -0,0 +1,58 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de turnos de caja.
+ * HU-106: Gestión de turnos con arqueo por turno.
+ */
+public interface TurnoCajaRepository {
+
+    /**
+     * Persiste un turno (alta o cierre).
+     *
+     * @param turno el turno a guardar
+     * @return el turno guardado
+     */
+    TurnoCaja guardar(TurnoCaja turno);
+
+    /**
+     * Busca el turno ABIERTO de un local.
+     * Debería haber como máximo uno (invariante de negocio).
+     *
+     * @param localId identificador del local (tenant)
+     * @return el turno abierto si existe, vacío si no
+     */
+    Optional<TurnoCaja> buscarAbierto(LocalId localId);
+
+    /**
+     * Lista los turnos de una jornada ordenados por fecha de apertura.
+     *
+     * @param jornadaId identificador de la jornada
+     * @param localId identificador del local (tenant)
+     * @return turnos de la jornada, vacío si no se usaron turnos
+     */
+    List<TurnoCaja> buscarPorJornada(JornadaCajaId jornadaId, LocalId localId);
+
+    /**
+     * HU-129: Turnos cerrados en un rango, ordenados por fecha de cierre.
+     *
+     * @param localId identificador del local (tenant)
+     * @param inicio inicio del rango (inclusive)
+     * @param fin fin del rango (inclusive)
+     * @return turnos CERRADOS cuyo cierre cae en el rango
+     */
+    List<TurnoCaja> buscarCerradosPorFecha(LocalId localId, LocalDateTime inicio, LocalDateTime fin);
+
+    /**
+     * HU-141: Busca un turno del local por su id, abierto o cerrado.
+     */
+    Optional<TurnoCaja> buscarPorId(TurnoCajaId id, LocalId localId);
+}
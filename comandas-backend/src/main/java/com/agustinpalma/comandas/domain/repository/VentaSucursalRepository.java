@@ -0,0 +1,32 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.VentaSucursal;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+
+import java.time.LocalDateTime;
+import java.util.List;
+
+/**
+ * Contrato del repositorio de ventas recibidas de otras sucursales.
+ * HU-107: Multi-sucursal con reportes consolidados.
+ */
+public interface VentaSucursalRepository {
+
+    /**
+     * Persiste un lote de ventas. Si una venta ya existe (mismo pedido de
+     * origen) se reemplaza, para que reenviar un lote sea inocuo.
+     *
+     * @param ventas ventas recibidas
+     */
+    void guardarTodas(List<VentaSucursal> ventas);
+
+    /**
+     * Ventas consolidadas con cierre dentro del rango [inicio, fin).
+     *
+     * @param localId tenant que consolida
+     * @param inicio  inicio del rango (inclusive)
+     * @param fin     fin del rango (exclusive)
+     * @return ventas de todas las sucursales en el rango
+     */
+    List<VentaSucursal> buscarPorFechaCierre(LocalId localId, LocalDateTime inicio, LocalDateTime fin);
+}
@@ -0,0 +1,40 @@
+package com.agustinpalma.comandas.domain.repository;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ZonaDeliveryId;
+import com.agustinpalma.comandas.domain.model.ZonaDelivery;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Contrato del repositorio de zonas de reparto.
+ * HU-139: Zonas de delivery con costo de envío.
+ */
+public interface ZonaDeliveryRepository {
+
+    /**
+     * Persiste una zona (alta o modificación).
+     *
+     * @param zona la zona a guardar
+     * @return la zona guardada
+     */
+    ZonaDelivery guardar(ZonaDelivery zona);
+
+    /**
+     * Busca una zona por id, restringida al local.
+     *
+     * @param id identificador de la zona
+     * @param localId identificador del local (tenant)
+     * @return la zona si existe y pertenece al local
+     */
+    Optional<ZonaDelivery> buscarPorId(ZonaDeliveryId id, LocalId localId);
+
+    /**
+     * Lista las zonas del local (activas e inactivas) ordenadas por nombre.
+     *
+     * @param localId identificador del local (tenant)
+     * @return zonas del local
+     */
+    List<ZonaDelivery> buscarPorLocal(LocalId localId);
+}
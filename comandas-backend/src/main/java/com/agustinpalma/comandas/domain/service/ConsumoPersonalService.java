@@ -0,0 +1,98 @@
+package com.agustinpalma.comandas.domain.service;
+
+import com.agustinpalma.comandas.domain.model.ConsumoPersonal;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.Pedido;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Objects;
+import java.util.Optional;
+
+/**
+ * Domain Service para el consumo interno del personal.
+ *
+ * HU-132: Imputa a un empleado los pagos A_CUENTA de un pedido y calcula
+ * cuánto lleva consumido en el mes contra su tope.
+ *
+ * Superar el tope no bloquea el cierre: el excedente se descuenta del sueldo,
+ * así que solo se informa para avisarlo.
+ */
+public class ConsumoPersonalService {
+
+    /**
+     * Resultado de imputar un consumo.
+     *
+     * @param consumo el consumo a persistir
+     * @param consumoDelMes total del empleado en el mes, incluido este consumo
+     * @param excedente parte del total del mes que supera el tope (cero si no lo supera)
+     */
+    public record ConsumoImputado(ConsumoPersonal consumo, BigDecimal consumoDelMes, BigDecimal excedente) {
+
+        public boolean superaTope() {
+            return excedente.signum() > 0;
+        }
+    }
+
+    /**
+     * Suma de los pagos del pedido registrados con MedioPago.A_CUENTA.
+     */
+    public BigDecimal montoConsumoInterno(Pedido pedido) {
+        Objects.requireNonNull(pedido, "El pedido no puede ser null");
+        return pedido.getPagos().stream()
+            .filter(pago -> pago.getMedio() == MedioPago.A_CUENTA)
+            .map(Pago::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    /**
+     * Imputa al empleado el consumo A_CUENTA de un pedido cerrado.
+     *
+     * Los consumos previos del mismo pedido se ignoran al sumar el mes.
+     *
+     * @param pedido pedido ya cerrado (con sus pagos registrados)
+     * @param empleado empleado que consumió (null si no se indicó: consumo interno sin imputar)
+     * @param consumosDelMes consumos del empleado en el mes del cierre (vacía si empleado es null)
+     * @param fecha fecha del consumo
+     * @return el consumo imputado, o vacío si no hay empleado o el pedido no tiene pagos A_CUENTA
+     * @throws IllegalArgumentException si se indica empleado sin pagos A_CUENTA o es de otro local
+     * @throws IllegalStateException si el empleado está inactivo
+     */
+    public Optional<ConsumoImputado> imputarConsumo(
+            Pedido pedido,
+            Mozo empleado,
+            List<ConsumoPersonal> consumosDelMes,
+            LocalDateTime fecha
+    ) {
+        Objects.requireNonNull(consumosDelMes, "Los consumos del mes no pueden ser null");
+        Objects.requireNonNull(fecha, "La fecha del consumo no puede ser null");
+
+        BigDecimal monto = montoConsumoInterno(pedido);
+        if (empleado == null) {
+            return Optional.empty();
+        }
+        if (monto.signum() == 0) {
+            throw new IllegalArgumentException("Solo se imputa a un empleado un pedido cobrado a cuenta (consumo interno)");
+        }
+        if (!empleado.getLocalId().equals(pedido.getLocalId())) {
+            throw new IllegalArgumentException("El empleado no pertenece a este local");
+        }
+        if (!empleado.isActivo()) {
+            throw new IllegalStateException(empleado.getNombre() + " está inactivo y no puede consumir a cuenta");
+        }
+
+        BigDecimal consumoDelMes = consumosDelMes.stream()
+            .filter(c -> !pedido.getId().equals(c.getPedidoId()))
+            .map(ConsumoPersonal::getMonto)
+            .reduce(monto, BigDecimal::add);
+
+        return Optional.of(new ConsumoImputado(
+            ConsumoPersonal.registrar(empleado, pedido, monto, fecha),
+            consumoDelMes,
+            empleado.excedenteConsumo(consumoDelMes)
+        ));
+    }
+}
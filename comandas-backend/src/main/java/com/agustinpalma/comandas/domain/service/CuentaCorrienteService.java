@@ -0,0 +1,90 @@
+package com.agustinpalma.comandas.domain.service;
+
+import com.agustinpalma.comandas.domain.model.Cliente;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.MovimientoCuentaCorriente;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.Pedido;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Objects;
+import java.util.Optional;
+
+/**
+ * Domain Service para la cuenta corriente de clientes.
+ *
+ * HU-104: Traduce los pagos CUENTA_CORRIENTE de un pedido cerrado en un cargo
+ * sobre la cuenta del cliente, validando su límite de crédito.
+ *
+ * Igual que GestorStockService, no depende de repositorios: recibe el cliente
+ * y sus movimientos ya cargados por la capa de aplicación.
+ */
+public class CuentaCorrienteService {
+
+    /**
+     * Suma de los pagos del pedido registrados con MedioPago.CUENTA_CORRIENTE.
+     */
+    public BigDecimal montoACuentaCorriente(Pedido pedido) {
+        Objects.requireNonNull(pedido, "El pedido no puede ser null");
+        return pedido.getPagos().stream()
+            .filter(pago -> pago.getMedio() == MedioPago.CUENTA_CORRIENTE)
+            .map(Pago::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    /**
+     * Genera el cargo de cuenta corriente para un pedido cerrado.
+     *
+     * Los movimientos previos del mismo pedido se ignoran al calcular la deuda:
+     * en una corrección, el cargo nuevo reemplaza al anterior.
+     *
+     * @param pedido pedido ya cerrado (con sus pagos registrados)
+     * @param numeroMesa número de mesa para la descripción del cargo
+     * @param cliente cliente al que se le carga el pedido (null si no se indicó)
+     * @param movimientosCliente movimientos actuales del cliente (vacía si cliente es null)
+     * @param fecha fecha del cargo
+     * @return el cargo a persistir, o vacío si el pedido no tiene pagos a cuenta corriente
+     * @throws IllegalArgumentException si hay pagos a cuenta corriente sin cliente o de otro local
+     * @throws IllegalStateException si el cliente está inactivo o el cargo excede su límite
+     */
+    public Optional<MovimientoCuentaCorriente> registrarCargo(
+            Pedido pedido,
+            int numeroMesa,
+            Cliente cliente,
+            List<MovimientoCuentaCorriente> movimientosCliente,
+            LocalDateTime fecha
+    ) {
+        Objects.requireNonNull(movimientosCliente, "Los movimientos del cliente no pueden ser null");
+        Objects.requireNonNull(fecha, "La fecha del cargo no puede ser null");
+
+        BigDecimal monto = montoACuentaCorriente(pedido);
+        if (monto.signum() == 0) {
+            return Optional.empty();
+        }
+
+        if (cliente == null) {
+            throw new IllegalArgumentException("Para cobrar a cuenta corriente hay que indicar el cliente");
+        }
+        if (!cliente.getLocalId().equals(pedido.getLocalId())) {
+            throw new IllegalArgumentException("El cliente no pertenece a este local");
+        }
+
+        List<MovimientoCuentaCorriente> otrosMovimientos = movimientosCliente.stream()
+            .filter(m -> !pedido.getId().equals(m.getPedidoId()))
+            .toList();
+        BigDecimal deudaActual = MovimientoCuentaCorriente.calcularSaldo(otrosMovimientos);
+
+        cliente.validarCargo(deudaActual, monto);
+
+        return Optional.of(MovimientoCuentaCorriente.cargo(
+            cliente.getId(),
+            pedido.getLocalId(),
+            pedido.getId(),
+            monto,
+            fecha,
+            String.format("Pedido #%d - Mesa %d", pedido.getNumero(), numeroMesa)
+        ));
+    }
+}
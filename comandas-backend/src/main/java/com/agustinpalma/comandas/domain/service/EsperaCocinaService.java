@@ -0,0 +1,94 @@
+package com.agustinpalma.comandas.domain.service;
+
+import com.agustinpalma.comandas.domain.model.ComandaCocina;
+
+import java.time.Duration;
+import java.time.LocalDateTime;
+import java.util.Comparator;
+import java.util.List;
+import java.util.Objects;
+
+/**
+ * Domain Service para estimar la espera de un pedido nuevo en cocina.
+ *
+ * HU-138: Calcula el horario de retiro que se le promete al cliente de
+ * take away a partir de la carga actual de cocina.
+ *
+ * La estimación usa dos medidas de las comandas listas recientes:
+ * - el tiempo promedio de preparación de una comanda;
+ * - el ritmo de salida (minutos promedio entre dos comandas listas seguidas),
+ *   acotado al tiempo promedio: si cocina sacó pocas comandas fue por falta
+ *   de pedidos, no por lentitud.
+ *
+ * Un pedido nuevo espera a que salgan las comandas en cola, pero nunca menos
+ * que lo que tarda en prepararse: max(promedio, cola × ritmo). El resultado se
+ * redondea hacia arriba a múltiplos de {@link #MINUTOS_REDONDEO} para informarlo al cliente.
+ * Sin historial, el promedio es {@link ComandaCocina#MINUTOS_ALERTA_POR_DEFECTO}.
+ */
+public class EsperaCocinaService {
+
+    public static final int MINUTOS_REDONDEO = 5;
+
+    /** Comandas listas que se toman como historial reciente */
+    public static final Duration VENTANA_HISTORIAL = Duration.ofHours(2);
+
+    /**
+     * Espera estimada para un pedido que entra ahora a cocina.
+     *
+     * @param comandasEnCola comandas en preparación delante del pedido
+     * @param minutosPreparacion tiempo promedio de preparación de una comanda
+     * @param minutosEspera espera informada al cliente (redondeada)
+     * @param horaEstimada horario en que el pedido estaría listo
+     */
+    public record EsperaEstimada(int comandasEnCola, int minutosPreparacion, int minutosEspera,
+                                 LocalDateTime horaEstimada) {
+    }
+
+    /**
+     * @param enPreparacion comandas que cocina todavía no marcó como listas
+     * @param listasRecientes comandas listas de la {@link #VENTANA_HISTORIAL}
+     * @param ahora momento del cálculo
+     */
+    public EsperaEstimada estimar(List<ComandaCocina> enPreparacion, List<ComandaCocina> listasRecientes,
+                                  LocalDateTime ahora) {
+        Objects.requireNonNull(enPreparacion, "Las comandas en preparación no pueden ser null");
+        Objects.requireNonNull(listasRecientes, "Las comandas listas no pueden ser null");
+        Objects.requireNonNull(ahora, "La fecha del cálculo no puede ser null");
+
+        List<ComandaCocina> listas = listasRecientes.stream()
+            .filter(ComandaCocina::estaLista)
+            .sorted(Comparator.comparing(ComandaCocina::getListaEn))
+            .toList();
+
+        double promedio = listas.isEmpty()
+            ? ComandaCocina.MINUTOS_ALERTA_POR_DEFECTO
+            : listas.stream()
+                .mapToLong(comanda -> comanda.tiempoEnCocina(ahora).toSeconds())
+                .average()
+                .orElse(0) / 60.0;
+        double ritmo = Math.min(promedio, ritmoDeSalida(listas));
+
+        int cola = enPreparacion.size();
+        double espera = Math.max(promedio, cola * ritmo);
+        int minutosEspera = redondear(espera);
+
+        return new EsperaEstimada(cola, (int) Math.ceil(promedio), minutosEspera, ahora.plusMinutes(minutosEspera));
+    }
+
+    /**
+     * Minutos promedio entre dos comandas listas seguidas (infinito con menos de dos).
+     */
+    private double ritmoDeSalida(List<ComandaCocina> listasOrdenadas) {
+        if (listasOrdenadas.size() < 2) {
+            return Double.POSITIVE_INFINITY;
+        }
+        LocalDateTime primera = listasOrdenadas.get(0).getListaEn();
+        LocalDateTime ultima = listasOrdenadas.get(listasOrdenadas.size() - 1).getListaEn();
+        return Duration.between(primera, ultima).toSeconds() / 60.0 / (listasOrdenadas.size() - 1);
+    }
+
+    private int redondear(double minutos) {
+        int enteros = Math.max(MINUTOS_REDONDEO, (int) Math.ceil(minutos));
+        return ((enteros + MINUTOS_REDONDEO - 1) / MINUTOS_REDONDEO) * MINUTOS_REDONDEO;
+    }
+}
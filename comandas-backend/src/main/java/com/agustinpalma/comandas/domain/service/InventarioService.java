@@ -0,0 +1,411 @@
+package com.agustinpalma.comandas.domain.service;
+
+import com.agustinpalma.comandas.domain.model.ConteoInsumo;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaMerma;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoInsumo;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoInsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+import com.agustinpalma.comandas.domain.model.ExtraPedido;
+import com.agustinpalma.comandas.domain.model.IngredienteReceta;
+import com.agustinpalma.comandas.domain.model.Insumo;
+import com.agustinpalma.comandas.domain.model.ItemPedido;
+import com.agustinpalma.comandas.domain.model.Merma;
+import com.agustinpalma.comandas.domain.model.MovimientoInsumo;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.model.Proveedor;
+import com.agustinpalma.comandas.domain.model.Receta;
+import com.agustinpalma.comandas.domain.model.RenglonMerma;
+import com.agustinpalma.comandas.domain.model.TomaInventario;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+
+/**
+ * Domain Service para el stock de insumos.
+ *
+ * HU-133: Lleva el stock teórico de los insumos (lo que descuentan las
+ * recetas al vender) y lo compara con el conteo físico de una toma de inventario.
+ * HU-134: Arma las órdenes de compra sugeridas con los insumos que llegaron al mínimo.
+ * HU-135: Descuenta del stock las mermas, por insumo o por la receta de un producto.
+ * HU-148: El consumo de cada ítem respeta sus modificadores: los ingredientes
+ * quitados no se descuentan y los extras con receta suman la suya.
+ *
+ * Igual que GestorStockService, recibe los insumos y las recetas ya
+ * resueltos: la carga y la persistencia son de la capa de aplicación.
+ */
+public class InventarioService {
+
+    private static final int ESCALA_CONTEO = 3;
+
+    /**
+     * Insumos modificados y movimientos generados, para persistir.
+     */
+    public record ResultadoInsumos(List<Insumo> insumosModificados, List<MovimientoInsumo> movimientos) {
+
+        public ResultadoInsumos {
+            insumosModificados = List.copyOf(insumosModificados);
+            movimientos = List.copyOf(movimientos);
+        }
+    }
+
+    /**
+     * Resultado de confirmar una toma de inventario.
+     *
+     * @param insumosAjustados todos los insumos contados (el conteo activa su control de stock)
+     * @param movimientos un ajuste por cada insumo con diferencia
+     */
+    public record ResultadoToma(TomaInventario toma, List<Insumo> insumosAjustados, List<MovimientoInsumo> movimientos) {
+
+        public ResultadoToma {
+            insumosAjustados = List.copyOf(insumosAjustados);
+            movimientos = List.copyOf(movimientos);
+        }
+    }
+
+    /**
+     * Resultado de registrar una merma.
+     *
+     * @param insumosModificados los insumos de la merma que controlan stock
+     * @param movimientos una salida MERMA por cada uno de esos insumos
+     */
+    public record ResultadoMerma(Merma merma, List<Insumo> insumosModificados, List<MovimientoInsumo> movimientos) {
+
+        public ResultadoMerma {
+            insumosModificados = List.copyOf(insumosModificados);
+            movimientos = List.copyOf(movimientos);
+        }
+    }
+
+    /**
+     * Orden de compra sugerida para un proveedor.
+     *
+     * @param proveedor null para los insumos sin proveedor habitual
+     * @param insumos insumos bajo el mínimo, por nombre; la cantidad es {@link Insumo#getCantidadAReponer()}
+     */
+    public record OrdenCompraSugerida(Proveedor proveedor, List<Insumo> insumos) {
+
+        public OrdenCompraSugerida {
+            insumos = List.copyOf(insumos);
+        }
+
+        /**
+         * Costo de la orden al costo unitario vigente de cada insumo.
+         */
+        public BigDecimal getCostoEstimado() {
+            return insumos.stream()
+                .map(i -> i.getCantidadAReponer().multiply(i.getCostoUnitario()))
+                .reduce(BigDecimal.ZERO, BigDecimal::add)
+                .setScale(2, RoundingMode.HALF_UP);
+        }
+    }
+
+    /**
+     * Descuenta los insumos que llevan, según su receta, los productos vendidos en el pedido.
+     * Genera un movimiento CONSUMO_VENTA por insumo (sumando todos los ítems que lo usan).
+     * Solo afecta insumos que controlan stock.
+     *
+     * @param recetas recetas de los productos y extras del pedido (los que no tienen receta no consumen insumos)
+     * @param insumos insumos del local indexados por id
+     */
+    public ResultadoInsumos registrarConsumo(Pedido pedido, Map<ProductoId, Receta> recetas,
+                                             Map<InsumoId, Insumo> insumos, LocalDateTime fecha) {
+        Map<Insumo, BigDecimal> consumo = calcularConsumo(pedido, recetas, insumos);
+        return generarMovimientos(pedido, consumo, TipoMovimientoInsumo.CONSUMO_VENTA,
+            String.format("Venta - Pedido #%d", pedido.getNumero()), fecha);
+    }
+
+    /**
+     * Devuelve al stock los insumos consumidos por un pedido que se reabre.
+     * Debe invocarse antes de reabrir, mientras el pedido conserva sus ítems.
+     */
+    public ResultadoInsumos revertirConsumo(Pedido pedido, Map<ProductoId, Receta> recetas,
+                                            Map<InsumoId, Insumo> insumos, LocalDateTime fecha) {
+        Map<Insumo, BigDecimal> consumo = calcularConsumo(pedido, recetas, insumos);
+        return generarMovimientos(pedido, consumo, TipoMovimientoInsumo.REAPERTURA_PEDIDO,
+            String.format("Reapertura - Pedido #%d", pedido.getNumero()), fecha);
+    }
+
+    /**
+     * Compara el conteo físico contra el stock teórico, sin modificar nada.
+     * Sirve para revisar las diferencias antes de confirmar la toma.
+     *
+     * @param conteos cantidad contada por insumo, en la unidad del insumo
+     * @throws IllegalArgumentException si un insumo no existe en el local o un conteo es inválido
+     */
+    public List<ConteoInsumo> compararConteo(Map<InsumoId, BigDecimal> conteos, Map<InsumoId, Insumo> insumos) {
+        Objects.requireNonNull(conteos, "Los conteos no pueden ser null");
+        Objects.requireNonNull(insumos, "Los insumos no pueden ser null");
+
+        List<ConteoInsumo> resultado = new ArrayList<>();
+        conteos.forEach((insumoId, contado) -> {
+            Insumo insumo = insumos.get(insumoId);
+            if (insumo == null) {
+                throw new IllegalArgumentException("Uno de los insumos contados no existe en este local");
+            }
+            resultado.add(ConteoInsumo.de(insumo, validarConteo(insumo, contado)));
+        });
+        return resultado;
+    }
+
+    /**
+     * Confirma la toma: guarda el conteo y ajusta el stock teórico de cada insumo contado.
+     *
+     * @throws IllegalArgumentException si no se contó ningún insumo, o alguno no existe o tiene un conteo inválido
+     */
+    public ResultadoToma registrarToma(LocalId localId, String responsable, Map<InsumoId, BigDecimal> conteos,
+                                       Map<InsumoId, Insumo> insumos, LocalDateTime fecha) {
+        Objects.requireNonNull(fecha, "La fecha no puede ser null");
+
+        // El snapshot se toma antes de ajustar
+        TomaInventario toma = TomaInventario.registrar(localId, responsable, compararConteo(conteos, insumos), fecha);
+
+        List<Insumo> ajustados = new ArrayList<>();
+        List<MovimientoInsumo> movimientos = new ArrayList<>();
+        for (ConteoInsumo conteo : toma.getConteos()) {
+            Insumo insumo = insumos.get(conteo.getInsumoId());
+            BigDecimal diferencia = insumo.ajustarAConteo(conteo.getStockContado());
+            ajustados.add(insumo);
+            if (diferencia.signum() != 0) {
+                movimientos.add(new MovimientoInsumo(
+                    MovimientoInsumoId.generate(),
+                    insumo.getId(),
+                    localId,
+                    diferencia,
+                    TipoMovimientoInsumo.AJUSTE_INVENTARIO,
+                    fecha,
+                    "Toma de inventario - " + toma.getResponsable()
+                ));
+            }
+        }
+        return new ResultadoToma(toma, ajustados, movimientos);
+    }
+
+    private BigDecimal validarConteo(Insumo insumo, BigDecimal contado) {
+        if (contado == null || contado.signum() < 0) {
+            throw new IllegalArgumentException("El conteo de " + insumo.getNombre() + " no puede ser negativo");
+        }
+        if (contado.stripTrailingZeros().scale() > ESCALA_CONTEO) {
+            throw new IllegalArgumentException("El conteo admite hasta " + ESCALA_CONTEO + " decimales");
+        }
+        return contado;
+    }
+
+    /**
+     * Registra una merma cargada por insumo y descuenta lo perdido del stock.
+     * Los insumos que no controlan stock igual se valorizan en la merma.
+     *
+     * @param cantidades cantidad perdida por insumo, en la unidad del insumo
+     * @throws IllegalArgumentException si un insumo no existe en el local o una cantidad es inválida
+     */
+    public ResultadoMerma registrarMerma(LocalId localId, CategoriaMerma categoria, String motivo,
+                                         String responsable, Map<InsumoId, BigDecimal> cantidades,
+                                         Map<InsumoId, Insumo> insumos, LocalDateTime fecha) {
+        Objects.requireNonNull(cantidades, "Las cantidades no pueden ser null");
+        Objects.requireNonNull(insumos, "Los insumos no pueden ser null");
+
+        List<RenglonMerma> renglones = new ArrayList<>();
+        cantidades.forEach((insumoId, cantidad) -> {
+            Insumo insumo = insumos.get(insumoId);
+            if (insumo == null) {
+                throw new IllegalArgumentException("Uno de los insumos de la merma no existe en este local");
+            }
+            renglones.add(RenglonMerma.de(insumo, validarCantidadMerma(insumo, cantidad)));
+        });
+        Merma merma = Merma.deInsumos(localId, categoria, motivo, responsable, renglones, fecha);
+        return descontarMerma(merma, insumos);
+    }
+
+    /**
+     * Registra la merma de unidades de un producto (un plato que se cayó):
+     * se pierde lo que lleva su receta por las unidades.
+     *
+     * @param receta receta del producto; sin receta no hay insumos que descontar
+     * @throws IllegalArgumentException si el producto no tiene receta o las unidades no son positivas
+     */
+    public ResultadoMerma registrarMermaProducto(LocalId localId, CategoriaMerma categoria, String motivo,
+                                                 String responsable, Producto producto, int unidades,
+                                                 Receta receta, Map<InsumoId, Insumo> insumos,
+                                                 LocalDateTime fecha) {
+        Objects.requireNonNull(producto, "El producto no puede ser null");
+        Objects.requireNonNull(insumos, "Los insumos no pueden ser null");
+        if (receta == null) {
+            throw new IllegalArgumentException(
+                producto.getNombre() + " no tiene receta: cargá la merma por insumo"
+            );
+        }
+        if (unidades <= 0) {
+            throw new IllegalArgumentException("Las unidades perdidas deben ser mayores a cero");
+        }
+
+        BigDecimal cantidadUnidades = BigDecimal.valueOf(unidades);
+        List<RenglonMerma> renglones = new ArrayList<>();
+        for (IngredienteReceta ingrediente : receta.getIngredientes()) {
+            Insumo insumo = insumos.get(ingrediente.getInsumoId());
+            if (insumo != null) {
+                renglones.add(RenglonMerma.de(insumo, ingrediente.getCantidad().multiply(cantidadUnidades)));
+            }
+        }
+        Merma merma = Merma.deProducto(localId, categoria, motivo, responsable, producto, unidades, renglones, fecha);
+        return descontarMerma(merma, insumos);
+    }
+
+    private ResultadoMerma descontarMerma(Merma merma, Map<InsumoId, Insumo> insumos) {
+        String motivo = "Merma - " + merma.getMotivo();
+        List<Insumo> modificados = new ArrayList<>();
+        List<MovimientoInsumo> movimientos = new ArrayList<>();
+        for (RenglonMerma renglon : merma.getRenglones()) {
+            Insumo insumo = insumos.get(renglon.getInsumoId());
+            if (!insumo.isControlaStock()) {
+                continue;
+            }
+            insumo.consumir(renglon.getCantidad());
+            modificados.add(insumo);
+            movimientos.add(new MovimientoInsumo(
+                MovimientoInsumoId.generate(),
+                insumo.getId(),
+                merma.getLocalId(),
+                renglon.getCantidad().negate(),
+                TipoMovimientoInsumo.MERMA,
+                merma.getFecha(),
+                motivo
+            ));
+        }
+        return new ResultadoMerma(merma, modificados, movimientos);
+    }
+
+    private BigDecimal validarCantidadMerma(Insumo insumo, BigDecimal cantidad) {
+        if (cantidad == null || cantidad.signum() <= 0) {
+            throw new IllegalArgumentException(
+                "La cantidad perdida de " + insumo.getNombre() + " debe ser mayor a cero"
+            );
+        }
+        if (cantidad.stripTrailingZeros().scale() > ESCALA_CONTEO) {
+            throw new IllegalArgumentException("La cantidad perdida admite hasta " + ESCALA_CONTEO + " decimales");
+        }
+        return cantidad;
+    }
+
+    /**
+     * Agrupa por proveedor habitual los insumos que llegaron al stock mínimo.
+     * Los proveedores van por nombre y los insumos sin proveedor (o con uno
+     * que no es del local) quedan en una última orden sin proveedor.
+     *
+     * @param insumos insumos del local
+     * @param proveedores proveedores del local
+     */
+    public List<OrdenCompraSugerida> sugerirOrdenesCompra(List<Insumo> insumos, List<Proveedor> proveedores) {
+        Objects.requireNonNull(insumos, "Los insumos no pueden ser null");
+        Objects.requireNonNull(proveedores, "Los proveedores no pueden ser null");
+
+        Map<ProveedorId, Proveedor> proveedoresPorId = new LinkedHashMap<>();
+        proveedores.forEach(p -> proveedoresPorId.put(p.getId(), p));
+
+        Map<Proveedor, List<Insumo>> porProveedor = new LinkedHashMap<>();
+        List<Insumo> sinProveedor = new ArrayList<>();
+        insumos.stream()
+            .filter(Insumo::estaBajoMinimo)
+            .sorted(Comparator.comparing(Insumo::getNombre, String.CASE_INSENSITIVE_ORDER))
+            .forEach(insumo -> {
+                Proveedor proveedor = insumo.getProveedorHabitualId() != null
+                    ? proveedoresPorId.get(insumo.getProveedorHabitualId())
+                    : null;
+                if (proveedor == null) {
+                    sinProveedor.add(insumo);
+                } else {
+                    porProveedor.computeIfAbsent(proveedor, p -> new ArrayList<>()).add(insumo);
+                }
+            });
+
+        List<OrdenCompraSugerida> ordenes = new ArrayList<>();
+        porProveedor.entrySet().stream()
+            .sorted(Map.Entry.comparingByKey(Comparator.comparing(Proveedor::getNombre, String.CASE_INSENSITIVE_ORDER)))
+            .forEach(e -> ordenes.add(new OrdenCompraSugerida(e.getKey(), e.getValue())));
+        if (!sinProveedor.isEmpty()) {
+            ordenes.add(new OrdenCompraSugerida(null, sinProveedor));
+        }
+        return ordenes;
+    }
+
+    /**
+     * Cantidad total de cada insumo que llevan los ítems del pedido.
+     * Ignora insumos que no controlan stock y recetas con insumos que ya no existen.
+     *
+     * HU-148: De la receta del producto se saltean los ingredientes quitados
+     * ("sin cebolla"). Cada extra con receta consume la suya por unidad del
+     * ítem ("extra queso" descuenta otra porción de queso).
+     */
+    private Map<Insumo, BigDecimal> calcularConsumo(Pedido pedido, Map<ProductoId, Receta> recetas,
+                                                    Map<InsumoId, Insumo> insumos) {
+        Objects.requireNonNull(pedido, "El pedido no puede ser null");
+        Objects.requireNonNull(recetas, "Las recetas no pueden ser null");
+        Objects.requireNonNull(insumos, "Los insumos no pueden ser null");
+
+        Map<Insumo, BigDecimal> consumo = new LinkedHashMap<>();
+        for (ItemPedido item : pedido.getItems()) {
+            BigDecimal unidades = BigDecimal.valueOf(item.getCantidad());
+            Receta receta = recetas.get(item.getProductoId());
+            if (receta != null) {
+                for (IngredienteReceta ingrediente : receta.getIngredientes()) {
+                    if (!item.quitaInsumo(ingrediente.getInsumoId())) {
+                        sumarConsumo(consumo, ingrediente, unidades, insumos);
+                    }
+                }
+            }
+            for (ExtraPedido extra : item.getExtras()) {
+                Receta recetaExtra = recetas.get(extra.getProductoId());
+                if (recetaExtra == null) {
+                    continue;
+                }
+                for (IngredienteReceta ingrediente : recetaExtra.getIngredientes()) {
+                    sumarConsumo(consumo, ingrediente, unidades, insumos);
+                }
+            }
+        }
+        return consumo;
+    }
+
+    private void sumarConsumo(Map<Insumo, BigDecimal> consumo, IngredienteReceta ingrediente,
+                              BigDecimal unidades, Map<InsumoId, Insumo> insumos) {
+        Insumo insumo = insumos.get(ingrediente.getInsumoId());
+        if (insumo == null || !insumo.isControlaStock()) {
+            return;
+        }
+        consumo.merge(insumo, ingrediente.getCantidad().multiply(unidades), BigDecimal::add);
+    }
+
+    private ResultadoInsumos generarMovimientos(Pedido pedido, Map<Insumo, BigDecimal> consumo,
+                                                TipoMovimientoInsumo tipo, String motivo, LocalDateTime fecha) {
+        Objects.requireNonNull(fecha, "La fecha no puede ser null");
+
+        List<MovimientoInsumo> movimientos = new ArrayList<>();
+        consumo.forEach((insumo, cantidad) -> {
+            boolean esConsumo = tipo == TipoMovimientoInsumo.CONSUMO_VENTA;
+            if (esConsumo) {
+                insumo.consumir(cantidad);
+            } else {
+                insumo.reponer(cantidad);
+            }
+            movimientos.add(new MovimientoInsumo(
+                MovimientoInsumoId.generate(),
+                insumo.getId(),
+                pedido.getLocalId(),
+                esConsumo ? cantidad.negate() : cantidad,
+                tipo,
+                fecha,
+                motivo
+            ));
+        });
+        return new ResultadoInsumos(new ArrayList<>(consumo.keySet()), movimientos);
+    }
+}
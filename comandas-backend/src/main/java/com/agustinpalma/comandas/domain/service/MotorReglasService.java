@@ -79,7 +79,8 @@ public class MotorReglasService {
         // Generar el ItemPedido con o sin descuento
         return promoGanadora
                 .map(evaluada -> crearItemConDescuento(pedido.getId(), producto, cantidad, observacion, evaluada))
-                .orElseGet(() -> crearItemSinDescuento(pedido.getId(), producto, cantidad, observacion));
+                .orElseGet(() -> crearItemSinDescuento(pedido.getId(), producto, cantidad, observacion))
+                .registradoEn(fechaHora);
     }
     
     /**
@@ -125,7 +126,8 @@ public class MotorReglasService {
 
         if (tieneExtras) {
             // Producto personalizado → sin evaluación de promos, directo sin descuento
-            return crearItemConExtras(pedido.getId(), producto, cantidad, observacion, extras);
+            return crearItemConExtras(pedido.getId(), producto, cantidad, observacion, extras)
+                    .registradoEn(fechaHora);
         }
 
         // Construir contexto de validación desde el pedido actual
@@ -142,7 +144,8 @@ public class MotorReglasService {
         // Producto base (sin extras) → evaluar promo normalmente
         return promoGanadora
                 .map(evaluada -> crearItemConDescuentoYExtras(pedido.getId(), producto, cantidad, observacion, extras, evaluada))
-                .orElseGet(() -> crearItemConExtras(pedido.getId(), producto, cantidad, observacion, extras));
+                .orElseGet(() -> crearItemConExtras(pedido.getId(), producto, cantidad, observacion, extras))
+                .registradoEn(fechaHora);
     }
 
     /**
@@ -475,15 +478,16 @@ public class MotorReglasService {
      * mediante Pedido.limpiarPromocionesItems().
      * 
      * Algoritmo:
-     * 1. Construir contexto de validación desde el pedido actual
-     * 2. Para cada ítem del pedido:
+     * 1. Agrupar ítems por producto y por franja promocional de su momento de carga (HU-102)
+     * 2. Construir el contexto de validación de cada grupo con ese momento de carga
+     * 3. Para cada grupo:
      *    a. Evaluar qué promoción aplica (si alguna)
      *    b. Calcular el descuento usando el precioUnitario snapshot del ítem
      *    c. Aplicar la promoción ganadora directamente sobre el ítem
      * 
      * @param pedido el pedido con los ítems ya modificados y promociones limpiadas
      * @param promocionesActivas lista de promociones activas del local
-     * @param fechaHora fecha/hora actual para evaluar criterios temporales
+     * @param fechaHora fecha/hora actual (se usa para ítems legacy sin momento de carga)
      */
     public void aplicarPromociones(
             Pedido pedido,
@@ -494,9 +498,6 @@ public class MotorReglasService {
         Objects.requireNonNull(promocionesActivas, "La lista de promociones no puede ser null");
         Objects.requireNonNull(fechaHora, "La fecha/hora no puede ser null");
 
-        // Construir contexto de validación
-        ContextoValidacion contexto = construirContexto(pedido, fechaHora);
-
         // ─── AGREGACIÓN CROSS-LÍNEA ───
         // Las promociones basadas en cantidad (NxM, PrecioFijo) deben considerar la cantidad
         // TOTAL del producto en el pedido, no la cantidad de cada línea por separado.
@@ -505,15 +506,82 @@ public class MotorReglasService {
         // Las observaciones NO afectan elegibilidad — solo existen para la cocina/ticket.
         //
         // Los ítems con extras quedan excluidos (regla: producto personalizado ≠ producto base).
-        Map<UUID, List<ItemPedido>> gruposPorProducto = pedido.getItems().stream()
+        //
+        // HU-102: Las franjas horarias (Happy Hour) se evalúan según el momento en que
+        // cada línea se cargó al pedido, no según la hora del recálculo. Por eso el grupo
+        // del producto se subdivide por "franja": líneas cargadas dentro del Happy Hour
+        // suman entre sí, y las cargadas después no heredan la promo al cerrar la mesa.
+        Map<UUID, Map<Set<PromocionId>, List<ItemPedido>>> gruposPorProducto = pedido.getItems().stream()
                 .filter(item -> !item.tieneExtras())
-                .collect(Collectors.groupingBy(item -> item.getProductoId().getValue()));
-
-        for (List<ItemPedido> grupo : gruposPorProducto.values()) {
-            evaluarYAplicarPromocionAGrupo(grupo, pedido, promocionesActivas, contexto);
+                .collect(Collectors.groupingBy(
+                        item -> item.getProductoId().getValue(),
+                        LinkedHashMap::new,
+                        Collectors.groupingBy(
+                                item -> promocionesVigentesEn(pedido, promocionesActivas, momentoDeCarga(item, fechaHora)),
+                                LinkedHashMap::new,
+                                Collectors.toList()
+                        )
+                ));
+
+        for (Map<Set<PromocionId>, List<ItemPedido>> franjas : gruposPorProducto.values()) {
+            for (List<ItemPedido> grupo : franjas.values()) {
+                ContextoValidacion contexto = construirContexto(pedido, momentoDeCarga(grupo.get(0), fechaHora));
+                evaluarYAplicarPromocionAGrupo(grupo, pedido, promocionesActivas, contexto);
+            }
         }
     }
 
+    /**
+     * HU-102: Indica si un ítem ya cargado y una nueva carga en {@code ahora}
+     * caen dentro de las mismas franjas promocionales.
+     *
+     * Se usa antes de fusionar líneas con configuración idéntica: una cerveza pedida
+     * en Happy Hour y otra pedida después no deben quedar en la misma línea, porque
+     * la línea fusionada tomaría un único momento de carga.
+     *
+     * @param pedido el pedido actual
+     * @param item ítem existente candidato a fusión
+     * @param promocionesActivas promociones activas del local
+     * @param ahora momento de la nueva carga
+     * @return true si ambas cargas activan exactamente las mismas promociones
+     */
+    public boolean compartenFranjaPromocional(
+            Pedido pedido,
+            ItemPedido item,
+            List<Promocion> promocionesActivas,
+            LocalDateTime ahora
+    ) {
+        Objects.requireNonNull(item, "El ítem no puede ser null");
+        Objects.requireNonNull(ahora, "La fecha/hora no puede ser null");
+
+        return promocionesVigentesEn(pedido, promocionesActivas, momentoDeCarga(item, ahora))
+                .equals(promocionesVigentesEn(pedido, promocionesActivas, ahora));
+    }
+
+    /**
+     * Momento de referencia para evaluar criterios temporales de una línea.
+     * Los ítems legacy sin fechaAgregado se evalúan con la hora del recálculo.
+     */
+    private LocalDateTime momentoDeCarga(ItemPedido item, LocalDateTime fechaHora) {
+        return item.getFechaAgregado() != null ? item.getFechaAgregado() : fechaHora;
+    }
+
+    /**
+     * Conjunto de promociones cuyos criterios de activación se satisfacen en el momento dado.
+     * Actúa como "firma" de la franja promocional a la que pertenece una carga.
+     */
+    private Set<PromocionId> promocionesVigentesEn(
+            Pedido pedido,
+            List<Promocion> promocionesActivas,
+            LocalDateTime momento
+    ) {
+        ContextoValidacion contexto = construirContexto(pedido, momento);
+        return promocionesActivas.stream()
+                .filter(promo -> promo.puedeActivarse(contexto))
+                .map(Promocion::getId)
+                .collect(Collectors.toSet());
+    }
+
     /**
      * Evalúa y aplica la mejor promoción a un GRUPO de ítems del mismo producto.
      * 
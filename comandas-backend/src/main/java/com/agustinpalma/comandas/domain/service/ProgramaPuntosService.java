@@ -0,0 +1,288 @@
+package com.agustinpalma.comandas.domain.service;
+
+import com.agustinpalma.comandas.domain.model.Cliente;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.MovimientoPuntos;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.ProgramaPuntos;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.ArrayDeque;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.Deque;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Objects;
+import java.util.Optional;
+
+/**
+ * Domain Service del programa de puntos.
+ *
+ * HU-105: Calcula los puntos que gana un pedido cerrado, valida y aplica
+ * canjes como descuento, y deriva el saldo de un cliente a partir de sus
+ * movimientos.
+ *
+ * El saldo se resuelve por lotes FIFO: cada acumulación es un lote con su
+ * propio vencimiento y los canjes consumen primero los lotes más viejos.
+ * Así, los puntos que vencen son siempre los que el cliente no llegó a usar.
+ *
+ * Como CuentaCorrienteService, no depende de repositorios: recibe el programa,
+ * el cliente y sus movimientos ya cargados por la capa de aplicación.
+ */
+public class ProgramaPuntosService {
+
+    /**
+     * Lote de puntos todavía disponible.
+     *
+     * @param puntos puntos que quedan sin canjear en el lote
+     * @param fechaVencimiento cuándo vence el lote (null = no vence)
+     */
+    public record LotePuntos(int puntos, LocalDateTime fechaVencimiento) {}
+
+    /**
+     * Puntos que vencieron sin usarse.
+     *
+     * @param puntos cantidad vencida
+     * @param fecha momento del vencimiento
+     */
+    public record VencimientoPuntos(int puntos, LocalDateTime fecha) {}
+
+    /**
+     * Saldo de puntos de un cliente en un instante dado.
+     *
+     * @param disponible puntos que puede canjear
+     * @param lotesVigentes lotes con saldo, del más viejo al más nuevo
+     * @param vencimientos historial de puntos vencidos sin usar
+     */
+    public record SaldoPuntos(
+        int disponible,
+        List<LotePuntos> lotesVigentes,
+        List<VencimientoPuntos> vencimientos
+    ) {
+        public SaldoPuntos {
+            lotesVigentes = List.copyOf(lotesVigentes);
+            vencimientos = List.copyOf(vencimientos);
+        }
+
+        public int totalVencido() {
+            return vencimientos.stream().mapToInt(VencimientoPuntos::puntos).sum();
+        }
+    }
+
+    /**
+     * Base sobre la que se ganan puntos: lo efectivamente cobrado en el pedido.
+     * Se excluye A_CUENTA (consumo interno del personal); el descuento por canje
+     * ya está restado del total, así que los puntos usados no generan puntos nuevos.
+     */
+    public BigDecimal montoAcumulable(Pedido pedido) {
+        Objects.requireNonNull(pedido, "El pedido no puede ser null");
+        return pedido.getPagos().stream()
+            .filter(pago -> pago.getMedio() != MedioPago.A_CUENTA)
+            .map(Pago::getMonto)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+    }
+
+    /**
+     * Genera la acumulación de puntos de un pedido cerrado.
+     *
+     * @param programa configuración del programa del local
+     * @param pedido pedido ya cerrado (con sus pagos registrados)
+     * @param cliente cliente identificado en el cierre
+     * @param fecha fecha de la acumulación (desde la que corre el vencimiento)
+     * @return la acumulación a persistir, o vacío si el programa está inactivo o no alcanza para 1 punto
+     * @throws IllegalArgumentException si el cliente no pertenece al local del pedido
+     */
+    public Optional<MovimientoPuntos> registrarAcumulacion(
+            ProgramaPuntos programa,
+            Pedido pedido,
+            Cliente cliente,
+            LocalDateTime fecha
+    ) {
+        Objects.requireNonNull(programa, "El programa de puntos no puede ser null");
+        Objects.requireNonNull(cliente, "El cliente no puede ser null");
+        Objects.requireNonNull(fecha, "La fecha de acumulación no puede ser null");
+
+        if (!programa.isActivo()) {
+            return Optional.empty();
+        }
+        validarMismoLocal(pedido, cliente);
+
+        int puntos = programa.puntosPorMonto(montoAcumulable(pedido));
+        if (puntos == 0) {
+            return Optional.empty();
+        }
+
+        return Optional.of(MovimientoPuntos.acumulacion(
+            cliente.getId(),
+            pedido.getLocalId(),
+            pedido.getId(),
+            puntos,
+            fecha,
+            programa.calcularVencimiento(fecha)
+        ));
+    }
+
+    /**
+     * Valida el canje y lo aplica como descuento sobre el pedido abierto.
+     *
+     * Debe invocarse justo antes de pedido.cerrar(): el total que deben cubrir
+     * los pagos ya tiene restado el descuento por puntos.
+     *
+     * @param programa configuración del programa del local
+     * @param pedido pedido ABIERTO a punto de cerrarse
+     * @param cliente cliente que canjea
+     * @param movimientosCliente movimientos actuales del cliente
+     * @param puntos puntos a canjear
+     * @param fecha fecha del canje
+     * @return el movimiento CANJE a persistir
+     * @throws IllegalStateException si el programa está inactivo o el saldo no alcanza
+     * @throws IllegalArgumentException si los puntos son inválidos, el cliente es de otro local
+     *         o el canje supera el total del pedido
+     */
+    public MovimientoPuntos aplicarCanje(
+            ProgramaPuntos programa,
+            Pedido pedido,
+            Cliente cliente,
+            List<MovimientoPuntos> movimientosCliente,
+            int puntos,
+            LocalDateTime fecha
+    ) {
+        Objects.requireNonNull(programa, "El programa de puntos no puede ser null");
+        Objects.requireNonNull(cliente, "El cliente no puede ser null");
+        Objects.requireNonNull(movimientosCliente, "Los movimientos del cliente no pueden ser null");
+        Objects.requireNonNull(fecha, "La fecha del canje no puede ser null");
+
+        if (!programa.isActivo()) {
+            throw new IllegalStateException("El programa de puntos no está activo");
+        }
+        if (puntos <= 0) {
+            throw new IllegalArgumentException("La cantidad de puntos a canjear debe ser mayor a cero");
+        }
+        validarMismoLocal(pedido, cliente);
+
+        int disponible = calcularSaldo(movimientosCliente, fecha).disponible();
+        if (puntos > disponible) {
+            throw new IllegalStateException(
+                String.format("'%s' tiene %d puntos disponibles, no alcanza para canjear %d",
+                    cliente.getNombre(), disponible, puntos)
+            );
+        }
+
+        BigDecimal monto = programa.valorDe(puntos);
+        BigDecimal total = pedido.calcularTotal();
+        if (monto.compareTo(total) > 0) {
+            throw new IllegalArgumentException(
+                String.format("El canje de %d puntos ($%s) supera el total del pedido ($%s)",
+                    puntos, monto.toPlainString(), total.toPlainString())
+            );
+        }
+
+        pedido.aplicarCanjePuntos(puntos, monto);
+
+        return MovimientoPuntos.canje(cliente.getId(), pedido.getLocalId(), pedido.getId(), puntos, fecha, monto);
+    }
+
+    /**
+     * Deriva el saldo de puntos recorriendo los movimientos en orden cronológico.
+     *
+     * Antes de procesar cada movimiento se vencen los lotes cuya fecha de
+     * vencimiento ya pasó; al final se vencen los que pasaron hasta {@code instante}.
+     * A igual fecha, el canje se procesa antes que la acumulación, porque en el
+     * cierre de un pedido los puntos se usan antes de ganar los nuevos.
+     *
+     * @param movimientos movimientos de un único cliente (cualquier orden)
+     * @param instante momento al que se calcula el saldo
+     */
+    public SaldoPuntos calcularSaldo(List<MovimientoPuntos> movimientos, LocalDateTime instante) {
+        Objects.requireNonNull(movimientos, "Los movimientos no pueden ser null");
+        Objects.requireNonNull(instante, "El instante no puede ser null");
+
+        List<MovimientoPuntos> ordenados = movimientos.stream()
+            .sorted(Comparator.comparing(MovimientoPuntos::getFecha)
+                .thenComparing(MovimientoPuntos::esAcumulacion))
+            .toList();
+
+        Deque<LoteAbierto> lotes = new ArrayDeque<>();
+        List<VencimientoPuntos> vencidos = new ArrayList<>();
+
+        for (MovimientoPuntos movimiento : ordenados) {
+            if (movimiento.getFecha().isAfter(instante)) {
+                break;
+            }
+            vencerLotes(lotes, vencidos, movimiento.getFecha());
+
+            if (movimiento.esAcumulacion()) {
+                lotes.addLast(new LoteAbierto(movimiento.getPuntos(), movimiento.getFechaVencimiento()));
+            } else {
+                consumir(lotes, movimiento.getPuntos());
+            }
+        }
+        vencerLotes(lotes, vencidos, instante);
+
+        List<LotePuntos> vigentes = lotes.stream()
+            .map(lote -> new LotePuntos(lote.puntos, lote.fechaVencimiento))
+            .toList();
+        int disponible = vigentes.stream().mapToInt(LotePuntos::puntos).sum();
+        return new SaldoPuntos(disponible, vigentes, vencidos);
+    }
+
+    /**
+     * Lote mutable usado solo durante el cálculo del saldo.
+     */
+    private static final class LoteAbierto {
+        private int puntos;
+        private final LocalDateTime fechaVencimiento;
+
+        private LoteAbierto(int puntos, LocalDateTime fechaVencimiento) {
+            this.puntos = puntos;
+            this.fechaVencimiento = fechaVencimiento;
+        }
+
+        private boolean vencidoEn(LocalDateTime instante) {
+            return fechaVencimiento != null && !fechaVencimiento.isAfter(instante);
+        }
+    }
+
+    /**
+     * Vence todos los lotes cuya fecha de vencimiento es anterior o igual a {@code hasta}.
+     * Los lotes no están ordenados por vencimiento (el programa pudo cambiar sus días),
+     * por eso se recorren todos en lugar de cortar en el primero vigente.
+     */
+    private void vencerLotes(Deque<LoteAbierto> lotes, List<VencimientoPuntos> vencidos, LocalDateTime hasta) {
+        Iterator<LoteAbierto> it = lotes.iterator();
+        while (it.hasNext()) {
+            LoteAbierto lote = it.next();
+            if (lote.vencidoEn(hasta)) {
+                vencidos.add(new VencimientoPuntos(lote.puntos, lote.fechaVencimiento));
+                it.remove();
+            }
+        }
+    }
+
+    /**
+     * Consume puntos de los lotes más viejos primero.
+     * Si el canje supera el saldo (dato histórico inconsistente), se consume lo que hay.
+     */
+    private void consumir(Deque<LoteAbierto> lotes, int puntos) {
+        int pendiente = puntos;
+        while (pendiente > 0 && !lotes.isEmpty()) {
+            LoteAbierto lote = lotes.peekFirst();
+            int usados = Math.min(lote.puntos, pendiente);
+            lote.puntos -= usados;
+            pendiente -= usados;
+            if (lote.puntos == 0) {
+                lotes.removeFirst();
+            }
+        }
+    }
+
+    private void validarMismoLocal(Pedido pedido, Cliente cliente) {
+        Objects.requireNonNull(pedido, "El pedido no puede ser null");
+        if (!cliente.getLocalId().equals(pedido.getLocalId())) {
+            throw new IllegalArgumentException("El cliente no pertenece a este local");
+        }
+    }
+}
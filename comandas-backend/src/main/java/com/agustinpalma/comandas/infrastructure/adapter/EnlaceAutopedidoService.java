@@ -0,0 +1,145 @@
+package com.agustinpalma.comandas.infrastructure.adapter;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.google.zxing.BarcodeFormat;
+import com.google.zxing.EncodeHintType;
+import com.google.zxing.WriterException;
+import com.google.zxing.common.BitMatrix;
+import com.google.zxing.qrcode.QRCodeWriter;
+import com.google.zxing.qrcode.decoder.ErrorCorrectionLevel;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+import org.springframework.beans.factory.annotation.Value;
+import org.springframework.stereotype.Service;
+
+import javax.imageio.ImageIO;
+import java.awt.image.BufferedImage;
+import java.io.ByteArrayOutputStream;
+import java.io.IOException;
+import java.io.UncheckedIOException;
+import java.net.Inet4Address;
+import java.net.InetAddress;
+import java.net.NetworkInterface;
+import java.net.SocketException;
+import java.util.Collections;
+import java.util.Map;
+
+/**
+ * Arma el enlace y el QR de la carta de cada mesa (HU-126).
+ * HU-127: también el de la carta digital, que es solo para mirar.
+ *
+ * El celular del cliente tiene que llegar al backend por la red del local,
+ * así que el enlace usa la IP LAN de la máquina donde corre FoodFlow (no
+ * localhost). Si la detección elige la interfaz equivocada (VPN, Docker,
+ * varias placas), se fija a mano con {@code app.autopedido.url-base}.
+ *
+ * Las cartas son páginas estáticas ({@code /carta.html} para pedir,
+ * {@code /menu.html} para mirar), servidas por el mismo backend para no
+ * depender de la app de escritorio.
+ */
+@Service
+public class EnlaceAutopedidoService {
+
+    private static final Logger logger = LoggerFactory.getLogger(EnlaceAutopedidoService.class);
+
+    private static final int TAMANIO_QR_PX = 480;
+    private static final int NEGRO = 0xFF000000;
+    private static final int BLANCO = 0xFFFFFFFF;
+
+    private final String urlBaseConfigurada;
+    private final int puerto;
+
+    public EnlaceAutopedidoService(
+            @Value("${app.autopedido.url-base:}") String urlBaseConfigurada,
+            @Value("${server.port:8080}") int puerto
+    ) {
+        this.urlBaseConfigurada = urlBaseConfigurada;
+        this.puerto = puerto;
+    }
+
+    /**
+     * URL que abre el celular al escanear el QR de la mesa.
+     */
+    public String urlCarta(MesaId mesaId) {
+        return resolverUrlBase() + "/carta.html?mesa=" + mesaId.getValue();
+    }
+
+    /**
+     * HU-127: URL de la carta digital desde la mesa (sin pedir, solo mirar).
+     */
+    public String urlCartaDigital(MesaId mesaId) {
+        return resolverUrlBase() + "/menu.html?mesa=" + mesaId.getValue();
+    }
+
+    /**
+     * QR de la URL de la carta como PNG, listo para imprimir y pegar en la mesa.
+     */
+    public byte[] generarQrPng(MesaId mesaId) {
+        return generarQrPng(urlCarta(mesaId));
+    }
+
+    /**
+     * HU-127: QR de la carta digital de la mesa, como PNG.
+     */
+    public byte[] generarQrCartaDigitalPng(MesaId mesaId) {
+        return generarQrPng(urlCartaDigital(mesaId));
+    }
+
+    private byte[] generarQrPng(String url) {
+        try {
+            BitMatrix matriz = new QRCodeWriter().encode(
+                url,
+                BarcodeFormat.QR_CODE,
+                TAMANIO_QR_PX,
+                TAMANIO_QR_PX,
+                // Corrección media: aguanta algo de desgaste o manchas en la mesa
+                Map.of(EncodeHintType.ERROR_CORRECTION, ErrorCorrectionLevel.M, EncodeHintType.MARGIN, 2)
+            );
+
+            BufferedImage imagen = new BufferedImage(matriz.getWidth(), matriz.getHeight(), BufferedImage.TYPE_INT_RGB);
+            for (int x = 0; x < matriz.getWidth(); x++) {
+                for (int y = 0; y < matriz.getHeight(); y++) {
+                    imagen.setRGB(x, y, matriz.get(x, y) ? NEGRO : BLANCO);
+                }
+            }
+
+            ByteArrayOutputStream salida = new ByteArrayOutputStream();
+            ImageIO.write(imagen, "png", salida);
+            return salida.toByteArray();
+        } catch (WriterException e) {
+            throw new IllegalStateException("No se pudo generar el QR de la mesa", e);
+        } catch (IOException e) {
+            throw new UncheckedIOException(e);
+        }
+    }
+
+    private String resolverUrlBase() {
+        if (urlBaseConfigurada != null && !urlBaseConfigurada.isBlank()) {
+            return urlBaseConfigurada.replaceAll("/+$", "");
+        }
+        return "http://" + detectarIpLan() + ":" + puerto;
+    }
+
+    /**
+     * Primera IPv4 privada de una interfaz activa que no sea loopback ni virtual.
+     * Si no encuentra ninguna, cae en localhost: el QR solo funcionaría en esta misma máquina.
+     */
+    private String detectarIpLan() {
+        try {
+            for (NetworkInterface interfaz : Collections.list(NetworkInterface.getNetworkInterfaces())) {
+                if (!interfaz.isUp() || interfaz.isLoopback() || interfaz.isVirtual()) {
+                    continue;
+                }
+                for (InetAddress direccion : Collections.list(interfaz.getInetAddresses())) {
+                    if (direccion instanceof Inet4Address && direccion.isSiteLocalAddress()) {
+                        return direccion.getHostAddress();
+                    }
+                }
+            }
+        } catch (SocketException e) {
+            logger.warn("No se pudieron listar las interfaces de red: {}", e.getMessage());
+        }
+        logger.warn("No se encontró una IP de red local; los QR de autopedido apuntan a localhost");
+        return "localhost";
+    }
+}
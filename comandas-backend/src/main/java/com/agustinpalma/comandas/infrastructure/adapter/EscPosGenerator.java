@@ -4,6 +4,7 @@ import java.io.ByteArrayOutputStream;
 import java.io.IOException;
 import java.math.BigDecimal;
 import java.nio.charset.Charset;
+import java.time.LocalDate;
 import java.time.LocalDateTime;
 import java.time.format.DateTimeFormatter;
 import java.util.List;
@@ -58,6 +59,12 @@ public class EscPosGenerator {
     /** Desactivar subrayado */
     private static final byte[] UNDERLINE_OFF = { 0x1B, 0x2D, 0x00 };
 
+    /** Activar impresión invertida (blanco sobre negro) */
+    private static final byte[] REVERSE_ON = { 0x1D, 0x42, 0x01 };
+
+    /** Desactivar impresión invertida */
+    private static final byte[] REVERSE_OFF = { 0x1D, 0x42, 0x00 };
+
     /** Corte parcial de papel (deja pestaña para arrancar) */
     private static final byte[] CUT_PAPER = { 0x1D, 0x56, 0x41, 0x03 };
 
@@ -118,6 +125,11 @@ public class EscPosGenerator {
         return this;
     }
 
+    public EscPosGenerator invertido(boolean activar) {
+        escribir(activar ? REVERSE_ON : REVERSE_OFF);
+        return this;
+    }
+
     public EscPosGenerator linea(String texto) {
         escribirTexto(texto);
         escribir(FEED_LINE);
@@ -333,6 +345,19 @@ public class EscPosGenerator {
      *
      * Formato operativo: solo cantidades, nombres y observaciones.
      * SIN precios. Los ítems "nuevos" se marcan con *** NUEVO ***.
+     * HU-120: La observación de cada ítem sale destacada debajo del producto.
+     *
+     * HU-110: Si los ítems traen paso de servicio se agrupan bajo su paso
+     * (deben venir ordenados). Una marcha lleva el aviso "MARCHAR ..." arriba
+     * y, en la reimpresión, los ítems retenidos se marcan (ESPERA).
+     *
+     * HU-137: Si el pedido o algún ítem impreso es urgente, la comanda lleva
+     * una franja "URGENTE" invertida bajo el header y los ítems urgentes se
+     * marcan con "!!" para que cocina los encuentre de un vistazo.
+     *
+     * HU-147: Un ítem cargado para un comensal con alergias lleva debajo del
+     * producto una franja invertida "ALERGIA: ..." y la comanda avisa arriba
+     * que tiene ítems con alergia.
      *
      * @param data datos de la comanda
      * @param soloNuevos si true, solo imprime ítems marcados como nuevos
@@ -352,32 +377,105 @@ public class EscPosGenerator {
            .lineaDosColumnas("Mesa " + data.numeroMesa, data.fechaHora.format(TIME_FMT))
            .linea("Pedido #" + data.numeroPedido);
 
-        gen.separador();
-
-        // ── Ítems ──
+        // ── Ítems a imprimir ──
         List<ComandaItemData> itemsAImprimir = soloNuevos
                 ? data.items.stream().filter(i -> i.esNuevo).toList()
                 : data.items;
 
+        // HU-137: Franja de urgencia
+        if (data.urgente || itemsAImprimir.stream().anyMatch(ComandaItemData::urgente)) {
+            gen.centrado()
+               .negrita(true)
+               .invertido(true)
+               .tamanoDoble(true)
+               .linea(" URGENTE ")
+               .tamanoDoble(false)
+               .invertido(false)
+               .negrita(false)
+               .izquierda();
+        }
+
+        // HU-147: Aviso de alergias
+        if (itemsAImprimir.stream().anyMatch(ComandaItemData::tieneAdvertencias)) {
+            gen.centrado()
+               .negrita(true)
+               .invertido(true)
+               .linea(" ATENCION: HAY ALERGIAS ")
+               .invertido(false)
+               .negrita(false)
+               .izquierda();
+        }
+
+        // HU-110: Aviso de marcha del siguiente paso
+        if (data.marcha != null) {
+            gen.centrado()
+               .negrita(true)
+               .tamanoDoble(true)
+               .linea("MARCHAR " + data.marcha)
+               .tamanoDoble(false)
+               .negrita(false)
+               .izquierda();
+        }
+
+        gen.separador();
+
+        // ── Ítems ──
+        String tiempoActual = null;
         for (ComandaItemData item : itemsAImprimir) {
+            // HU-110: Encabezado de paso cuando cambia
+            if (item.tiempo != null && !item.tiempo.equals(tiempoActual)) {
+                gen.negrita(true)
+                   .linea("-- " + item.tiempo + " --")
+                   .negrita(false);
+                tiempoActual = item.tiempo;
+            }
+
             gen.negrita(true)
                .tamanoDoble(true);
 
-            String cantNombre = item.cantidad + "x " + item.nombreProducto;
+            String cantNombre = (item.urgente ? "!! " : "") + item.cantidad + "x " + item.nombreProducto;
             // Solo agregar badge *NUEVO* cuando se imprimen TODOS los ítems
             // (soloNuevos=false). Si soloNuevos=true, todos los ítems impresos
             // son nuevos por definición → el badge es redundante.
             if (!soloNuevos && item.esNuevo) {
                 cantNombre += "  *NUEVO*";
             }
+            if (!soloNuevos && item.enEspera) {
+                cantNombre += "  (ESPERA)";
+            }
             // Word-wrap: en tamaño doble el ancho efectivo es 24 chars
             gen.lineaConWrap(cantNombre, DOUBLE_LINE_WIDTH);
 
             gen.tamanoDoble(false)
                .negrita(false);
 
+            // HU-147: La alergia va primero y lo más visible posible
+            if (item.tieneAdvertencias()) {
+                gen.negrita(true)
+                   .invertido(true)
+                   .tamanoDoble(true)
+                   .lineaConWrap(" ALERGIA: " + String.join(", ", item.advertencias).replace('_', ' ') + " ",
+                       DOUBLE_LINE_WIDTH)
+                   .tamanoDoble(false)
+                   .invertido(false)
+                   .negrita(false);
+            }
+
+            // HU-120: La observación libre va pegada al producto, en negrita e
+            // invertida, para que no se pierda entre componentes y extras
             if (item.observaciones != null && !item.observaciones.isBlank()) {
-                gen.linea("   >> " + item.observaciones);
+                gen.negrita(true)
+                   .invertido(true)
+                   .lineaConWrap(">> " + item.observaciones.trim(), LINE_WIDTH)
+                   .invertido(false)
+                   .negrita(false);
+            }
+
+            // HU-103: Armado del combo, un componente por línea
+            if (item.componentesCombo != null) {
+                for (String componente : item.componentesCombo) {
+                    gen.linea("   - " + componente);
+                }
             }
 
             // Extras de la comanda
@@ -397,6 +495,178 @@ public class EscPosGenerator {
         return gen.build();
     }
 
+    /**
+     * Genera el buffer ESC/POS de una FACTURA (HU-115).
+     *
+     * - Header: local, CUIT y condición del emisor, letra y número
+     * - Receptor: razón social, CUIT y condición (o "Consumidor Final")
+     * - Ítems: mismas columnas que el ticket de venta
+     * - Totales: descuentos del pedido y, en la A, neto gravado e IVA discriminados
+     * - Pie: CAE y vencimiento, o la leyenda de comprobante no válido
+     *   mientras esté pendiente de autorización
+     */
+    public static byte[] generarFactura(FacturaData data) {
+        EscPosGenerator gen = new EscPosGenerator();
+
+        // ── Header del emisor ──
+        gen.centrado()
+           .negrita(true)
+           .tamanoDoble(true)
+           .linea(data.nombreLocal)
+           .tamanoDoble(false)
+           .negrita(false);
+
+        if (data.direccion != null && !data.direccion.isBlank()) {
+            gen.linea(data.direccion);
+        }
+        if (data.cuitEmisor != null && !data.cuitEmisor.isBlank()) {
+            gen.linea("CUIT: " + data.cuitEmisor);
+        }
+        gen.linea(data.condicionEmisor);
+
+        gen.separadorDoble();
+
+        gen.negrita(true)
+           .tamanoDoble(true)
+           .linea("FACTURA " + data.letra)
+           .tamanoDoble(false)
+           .linea("Nro " + data.numero)
+           .negrita(false);
+        if (data.reimpresion) {
+            gen.linea("*** REIMPRESION ***");
+        }
+
+        gen.izquierda()
+           .lineaDosColumnas("Fecha " + data.fechaEmision.format(DATE_FMT), data.fechaEmision.format(TIME_FMT));
+
+        gen.separador();
+
+        // ── Receptor ──
+        if (data.receptorCuit != null) {
+            gen.lineaConWrap(data.receptorRazonSocial != null ? data.receptorRazonSocial : "", LINE_WIDTH)
+               .linea("CUIT: " + data.receptorCuit);
+        } else {
+            gen.linea("Consumidor Final");
+        }
+        gen.linea("Cond. IVA: " + data.receptorCondicion);
+
+        gen.separador();
+
+        // ── Ítems ──
+        gen.negrita(true)
+           .linea(formatColumnaHeader())
+           .negrita(false);
+
+        for (TicketItemData item : data.items) {
+            gen.lineaItem(item.cantidad, item.descripcion, item.precioUnitario, item.importe);
+            if (item.extras != null) {
+                for (TicketExtraData extra : item.extras) {
+                    String extraDesc = "  + " + (extra.cantidad > 1 ? extra.cantidad + "x " : "") + extra.nombre;
+                    gen.lineaDosColumnas(extraDesc, "$" + formatMoney(extra.subtotal));
+                }
+            }
+        }
+
+        gen.separador();
+
+        // ── Totales ──
+        if (data.montoDescuentos.compareTo(BigDecimal.ZERO) > 0) {
+            gen.lineaDosColumnas("Descuentos", "-$" + formatMoney(data.montoDescuentos));
+        }
+        if (data.discriminaIva) {
+            gen.lineaDosColumnas("Neto gravado", "$" + formatMoney(data.importeNeto))
+               .lineaDosColumnas("IVA " + data.alicuotaIva.stripTrailingZeros().toPlainString() + "%",
+                   "$" + formatMoney(data.importeIva));
+        }
+
+        gen.separadorDoble();
+
+        gen.negrita(true)
+           .tamanoDoble(true)
+           .centrado()
+           .linea("TOTAL  $" + formatMoney(data.importeTotal))
+           .tamanoDoble(false)
+           .negrita(false);
+
+        gen.separador();
+
+        // ── Autorización ──
+        if (data.cae != null) {
+            gen.izquierda()
+               .lineaDosColumnas("CAE", data.cae)
+               .lineaDosColumnas("Vto. CAE", data.caeVencimiento.format(DATE_FMT));
+        } else {
+            gen.centrado()
+               .negrita(true)
+               .linea("PENDIENTE DE AUTORIZACION")
+               .linea("NO VALIDO COMO FACTURA")
+               .negrita(false);
+        }
+
+        gen.cortePapel();
+
+        return gen.build();
+    }
+
+    /**
+     * Genera el buffer ESC/POS del COMPROBANTE de reparto de propinas de un turno (HU-141).
+     *
+     * - Header: local, turno y horario
+     * - Pozo y regla con que se repartió
+     * - Una línea por empleado con su parte y el detalle del peso
+     * - Pie: una línea de firma por empleado para que conste que cobró
+     */
+    public static byte[] generarComprobantePropinas(ComprobantePropinasData data) {
+        EscPosGenerator gen = new EscPosGenerator();
+
+        gen.centrado()
+           .negrita(true)
+           .tamanoDoble(true)
+           .linea(data.nombreLocal)
+           .tamanoDoble(false)
+           .linea("REPARTO DE PROPINAS")
+           .negrita(false);
+
+        gen.separador();
+
+        gen.izquierda()
+           .lineaDosColumnas("Turno " + data.turno, data.cierre.format(DATE_FMT))
+           .lineaDosColumnas("Horario", data.apertura.format(TIME_FMT) + " a " + data.cierre.format(TIME_FMT))
+           .lineaDosColumnas("Reparto", data.modo);
+
+        gen.separadorDoble();
+
+        gen.negrita(true)
+           .lineaDosColumnas("POZO", "$" + formatMoney(data.pozo))
+           .negrita(false);
+
+        gen.separador();
+
+        for (ParteComprobanteData parte : data.partes) {
+            gen.lineaDosColumnas(parte.nombre, "$" + formatMoney(parte.monto))
+               .linea("  " + parte.detalle);
+        }
+        if (data.sinRepartir.compareTo(BigDecimal.ZERO) > 0) {
+            gen.negrita(true)
+               .lineaDosColumnas("Sin repartir", "$" + formatMoney(data.sinRepartir))
+               .negrita(false);
+        }
+
+        // ── Firmas ──
+        for (ParteComprobanteData parte : data.partes) {
+            gen.lineaVacia()
+               .lineaVacia()
+               .centrado()
+               .linea("_".repeat(32))
+               .linea(parte.nombre)
+               .izquierda();
+        }
+
+        gen.cortePapel();
+
+        return gen.build();
+    }
+
     // ─── Helpers privados ────────────────────────────────────────────────────────
 
     private void escribir(byte[] bytes) {
@@ -455,13 +725,76 @@ public class EscPosGenerator {
         BigDecimal subtotal
     ) {}
 
+    /**
+     * Datos para generar una factura (HU-115).
+     * cae null = pendiente de autorización; receptorCuit null = consumidor final.
+     */
+    public record FacturaData(
+        String nombreLocal,
+        String direccion,
+        String cuitEmisor,
+        String condicionEmisor,
+        String letra,
+        String numero,
+        LocalDateTime fechaEmision,
+        String receptorRazonSocial,
+        String receptorCuit,
+        String receptorCondicion,
+        List<TicketItemData> items,
+        BigDecimal montoDescuentos,
+        boolean discriminaIva,
+        BigDecimal importeNeto,
+        BigDecimal alicuotaIva,
+        BigDecimal importeIva,
+        BigDecimal importeTotal,
+        String cae,
+        LocalDate caeVencimiento,
+        boolean reimpresion
+    ) {}
+
+    /**
+     * Datos para generar el comprobante de reparto de propinas (HU-141).
+     * turno y modo vienen ya descritos para imprimir.
+     */
+    public record ComprobantePropinasData(
+        String nombreLocal,
+        String turno,
+        LocalDateTime apertura,
+        LocalDateTime cierre,
+        String modo,
+        BigDecimal pozo,
+        BigDecimal sinRepartir,
+        List<ParteComprobanteData> partes
+    ) {}
+
+    /** Parte de un empleado; detalle = "3 h 20 min", "4 h 00 min - 2 puntos", etc. */
+    public record ParteComprobanteData(
+        String nombre,
+        String detalle,
+        BigDecimal monto
+    ) {}
+
     /** Datos para generar una comanda de cocina */
     public record ComandaCocinaData(
         int numeroMesa,
         int numeroPedido,
         LocalDateTime fechaHora,
-        List<ComandaItemData> items
-    ) {}
+        List<ComandaItemData> items,
+        String marcha,
+        boolean urgente
+    ) {
+        /** Comanda de un pedido sin marca de urgencia */
+        public ComandaCocinaData(int numeroMesa, int numeroPedido, LocalDateTime fechaHora,
+                                 List<ComandaItemData> items, String marcha) {
+            this(numeroMesa, numeroPedido, fechaHora, items, marcha, false);
+        }
+
+        /** Comanda sin marcha de paso */
+        public ComandaCocinaData(int numeroMesa, int numeroPedido, LocalDateTime fechaHora,
+                                 List<ComandaItemData> items) {
+            this(numeroMesa, numeroPedido, fechaHora, items, null);
+        }
+    }
 
     /** Ítem dentro de una comanda de cocina */
     public record ComandaItemData(
@@ -469,6 +802,43 @@ public class EscPosGenerator {
         String nombreProducto,
         String observaciones,
         boolean esNuevo,
-        List<String> extras
-    ) {}
+        List<String> extras,
+        List<String> componentesCombo,
+        String tiempo,
+        boolean enEspera,
+        boolean urgente,
+        List<String> advertencias
+    ) {
+        /** Ítem sin advertencias de alergia */
+        public ComandaItemData(int cantidad, String nombreProducto, String observaciones,
+                               boolean esNuevo, List<String> extras, List<String> componentesCombo,
+                               String tiempo, boolean enEspera, boolean urgente) {
+            this(cantidad, nombreProducto, observaciones, esNuevo, extras, componentesCombo, tiempo, enEspera,
+                urgente, List.of());
+        }
+
+        /** Ítem sin marca de urgencia */
+        public ComandaItemData(int cantidad, String nombreProducto, String observaciones,
+                               boolean esNuevo, List<String> extras, List<String> componentesCombo,
+                               String tiempo, boolean enEspera) {
+            this(cantidad, nombreProducto, observaciones, esNuevo, extras, componentesCombo, tiempo, enEspera, false);
+        }
+
+        /** Ítem sin paso de servicio */
+        public ComandaItemData(int cantidad, String nombreProducto, String observaciones,
+                               boolean esNuevo, List<String> extras, List<String> componentesCombo) {
+            this(cantidad, nombreProducto, observaciones, esNuevo, extras, componentesCombo, null, false);
+        }
+
+        /** Ítem sin componentes de combo */
+        public ComandaItemData(int cantidad, String nombreProducto, String observaciones,
+                               boolean esNuevo, List<String> extras) {
+            this(cantidad, nombreProducto, observaciones, esNuevo, extras, List.of());
+        }
+
+        /** HU-147: true si el ítem se cargó para un comensal con alergias */
+        public boolean tieneAdvertencias() {
+            return advertencias != null && !advertencias.isEmpty();
+        }
+    }
 }
@@ -0,0 +1,110 @@
+package com.agustinpalma.comandas.infrastructure.adapter;
+
+import com.agustinpalma.comandas.domain.model.AsientoContable;
+import com.agustinpalma.comandas.domain.model.AsientoContable.Linea;
+import com.agustinpalma.comandas.domain.model.DomainEnums.SistemaContable;
+import com.agustinpalma.comandas.domain.model.PlanCuentasContable;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.nio.charset.Charset;
+import java.nio.charset.StandardCharsets;
+import java.text.Normalizer;
+import java.time.format.DateTimeFormatter;
+import java.util.List;
+
+/**
+ * Generador de los archivos de importación de asientos (HU-167).
+ *
+ * Una fila por línea de asiento, con el diseño que importa cada sistema:
+ * - Tango: TXT separado por ";", coma decimal y Latin-1 sin acentos
+ *   (importación de asientos de Tango Contabilidad).
+ * - Xubio: CSV separado por "," y punto decimal, en UTF-8.
+ * - Contabilium: CSV separado por ";", punto decimal y fecha ISO, en UTF-8
+ *   con BOM para que la planilla de revisión respete los acentos.
+ *
+ * Esta clase es INFRAESTRUCTURA: solo formatea; qué asientos entran lo decide el caso de uso.
+ */
+public final class ExportacionContableGenerator {
+
+    private static final String FIN_LINEA = "\r\n";
+
+    private record Diseno(String encabezado, char separador, boolean comaDecimal, DateTimeFormatter fecha,
+                          Charset charset, boolean bom, String extension, String contentType) {}
+
+    private static Diseno diseno(SistemaContable sistema) {
+        return switch (sistema) {
+            case TANGO -> new Diseno("NRO_ASIENTO;FECHA;LEYENDA;COD_CUENTA;DEBE;HABER", ';', true,
+                DateTimeFormatter.ofPattern("dd/MM/yyyy"), StandardCharsets.ISO_8859_1, false,
+                "txt", "text/plain; charset=ISO-8859-1");
+            case XUBIO -> new Diseno("Numero,Fecha,Descripcion,Cuenta,Debe,Haber", ',', false,
+                DateTimeFormatter.ofPattern("dd/MM/yyyy"), StandardCharsets.UTF_8, false,
+                "csv", "text/csv; charset=UTF-8");
+            case CONTABILIUM -> new Diseno("Asiento;Fecha;Concepto;CodigoCuenta;Debe;Haber", ';', false,
+                DateTimeFormatter.ISO_LOCAL_DATE, StandardCharsets.UTF_8, true,
+                "csv", "text/csv; charset=UTF-8");
+        };
+    }
+
+    private ExportacionContableGenerator() {
+    }
+
+    public static String extension(SistemaContable sistema) {
+        return diseno(sistema).extension();
+    }
+
+    public static String contentType(SistemaContable sistema) {
+        return diseno(sistema).contentType();
+    }
+
+    /**
+     * Los asientos se numeran correlativos desde 1 en el orden recibido.
+     *
+     * @throws IllegalStateException si un concepto no tiene cuenta en el plan
+     */
+    public static byte[] generar(List<AsientoContable> asientos, PlanCuentasContable plan, SistemaContable sistema) {
+        Diseno diseno = diseno(sistema);
+        StringBuilder archivo = new StringBuilder(diseno.bom() ? "\uFEFF" : "");
+        archivo.append(diseno.encabezado()).append(FIN_LINEA);
+
+        int numero = 0;
+        for (AsientoContable asiento : asientos) {
+            numero++;
+            String leyenda = texto(asiento.getLeyenda(), diseno);
+            for (Linea linea : asiento.getLineas()) {
+                String cuenta = plan.cuentaDe(linea.concepto())
+                    .orElseThrow(() -> new IllegalStateException("Falta la cuenta de " + linea.concepto()));
+                archivo.append(numero).append(diseno.separador())
+                       .append(asiento.getFecha().format(diseno.fecha())).append(diseno.separador())
+                       .append(leyenda).append(diseno.separador())
+                       .append(texto(cuenta, diseno)).append(diseno.separador())
+                       .append(importe(linea.debe(), diseno)).append(diseno.separador())
+                       .append(importe(linea.haber(), diseno))
+                       .append(FIN_LINEA);
+            }
+        }
+
+        return archivo.toString().getBytes(diseno.charset());
+    }
+
+    // ─── Helpers ─────────────────────────────────────────────────────────────────
+
+    private static String importe(BigDecimal monto, Diseno diseno) {
+        String plano = monto.setScale(2, RoundingMode.HALF_UP).toPlainString();
+        return diseno.comaDecimal() ? plano.replace('.', ',') : plano;
+    }
+
+    /** Latin-1 va sin acentos; el separador o las comillas obligan a entrecomillar */
+    private static String texto(String valor, Diseno diseno) {
+        String limpio = valor;
+        if (diseno.charset() == StandardCharsets.ISO_8859_1) {
+            limpio = Normalizer.normalize(valor, Normalizer.Form.NFD)
+                .replaceAll("\\p{M}", "")
+                .replaceAll("[^\\x20-\\x7E]", "");
+        }
+        if (limpio.indexOf(diseno.separador()) >= 0 || limpio.contains("\"")) {
+            return "\"" + limpio.replace("\"", "\"\"") + "\"";
+        }
+        return limpio;
+    }
+}
@@ -0,0 +1,226 @@
+package com.agustinpalma.comandas.infrastructure.adapter;
+
+import com.agustinpalma.comandas.application.dto.ReporteImagenData;
+import com.agustinpalma.comandas.application.dto.ReporteImagenData.Ancho;
+import com.agustinpalma.comandas.application.dto.ReporteImagenData.Fila;
+import com.agustinpalma.comandas.application.dto.ReporteImagenData.Seccion;
+import com.agustinpalma.comandas.application.ports.output.ReporteImagenGenerator;
+import org.xhtmlrenderer.resource.XMLResource;
+import org.xhtmlrenderer.swing.Java2DRenderer;
+
+import javax.imageio.ImageIO;
+import java.awt.image.BufferedImage;
+import java.io.ByteArrayOutputStream;
+import java.io.StringReader;
+import java.math.BigDecimal;
+import java.time.format.DateTimeFormatter;
+
+/**
+ * Implementación de {@link ReporteImagenGenerator} usando FlyingSaucer (XHTML → PNG).
+ *
+ * Es la misma técnica que {@link FlyingSaucerReportePdfAdapter}, pero con el
+ * renderer Java2D: el HTML se dibuja en una imagen del ancho pedido y el alto
+ * sale del contenido. La tipografía escala con el ancho para que el ticket y
+ * la versión para teléfono se lean igual una vez abiertas en WhatsApp.
+ *
+ * Requiere AWT en modo headless (el default de Spring Boot).
+ */
+public class FlyingSaucerReporteImagenAdapter implements ReporteImagenGenerator {
+
+    private static final DateTimeFormatter FORMATO_FECHA = DateTimeFormatter.ofPattern("dd/MM/yyyy");
+    private static final DateTimeFormatter FORMATO_FECHA_HORA = DateTimeFormatter.ofPattern("dd/MM/yyyy HH:mm");
+
+    /** Cantidad de "letras" que entran a lo ancho con el tamaño base */
+    private static final int COLUMNAS = 24;
+
+    @Override
+    public byte[] generarPng(ReporteImagenData data, Ancho ancho) {
+        String xhtml = construirXhtml(data, ancho);
+
+        try (ByteArrayOutputStream baos = new ByteArrayOutputStream()) {
+            Java2DRenderer renderer = new Java2DRenderer(
+                XMLResource.load(new StringReader(xhtml)).getDocument(), ancho.pixeles());
+            renderer.setBufferedImageType(BufferedImage.TYPE_INT_RGB);
+            ImageIO.write(renderer.getImage(), "png", baos);
+            return baos.toByteArray();
+        } catch (Exception e) {
+            throw new RuntimeException("Error al generar la imagen del reporte", e);
+        }
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Construcción del XHTML
+    // ════════════════════════════════════════════════════════════════════════════
+
+    private String construirXhtml(ReporteImagenData data, Ancho ancho) {
+        StringBuilder html = new StringBuilder();
+
+        html.append("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        html.append("<html xmlns=\"http://www.w3.org/1999/xhtml\">\n");
+        html.append("<head>\n");
+        html.append("<style type=\"text/css\">\n");
+        html.append(css(ancho.pixeles() / COLUMNAS));
+        html.append("</style>\n");
+        html.append("</head>\n");
+        html.append("<body>\n");
+
+        // ── Encabezado ─────────────────────────────────────────────────────
+        html.append("<div class=\"header\">\n");
+        html.append("  <p class=\"local\">").append(esc(data.nombreLocal())).append("</p>\n");
+        html.append("  <h1>").append(esc(data.titulo())).append("</h1>\n");
+        html.append("  <p class=\"periodo\">").append(formatPeriodo(data)).append("</p>\n");
+        html.append("</div>\n");
+
+        // ── Secciones ──────────────────────────────────────────────────────
+        for (Seccion seccion : data.secciones()) {
+            html.append("<div class=\"seccion\">\n");
+            html.append("  <h2>").append(esc(seccion.titulo())).append("</h2>\n");
+            html.append("  <table>\n");
+            if (seccion.filas().isEmpty()) {
+                html.append("    <tr><td class=\"vacio\">Sin movimientos</td></tr>\n");
+            }
+            for (Fila fila : seccion.filas()) {
+                fila(html, fila, "");
+            }
+            if (seccion.total() != null) {
+                fila(html, seccion.total(), "total");
+            }
+            html.append("  </table>\n");
+            html.append("</div>\n");
+        }
+
+        // ── Pie ────────────────────────────────────────────────────────────
+        html.append("<div class=\"footer\">Generado el ")
+            .append(data.generadoEn().format(FORMATO_FECHA_HORA))
+            .append("</div>\n");
+
+        html.append("</body>\n");
+        html.append("</html>");
+
+        return html.toString();
+    }
+
+    private void fila(StringBuilder html, Fila fila, String clase) {
+        html.append("    <tr class=\"").append(clase).append("\">\n");
+        html.append("      <td>").append(esc(fila.etiqueta())).append("</td>\n");
+        html.append("      <td class=\"detalle\">").append(esc(fila.detalle())).append("</td>\n");
+        html.append("      <td class=\"monto\">")
+            .append(fila.monto() != null ? formatMonto(fila.monto()) : "")
+            .append("</td>\n");
+        html.append("    </tr>\n");
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // CSS embebido
+    // ════════════════════════════════════════════════════════════════════════════
+
+    /**
+     * @param base tamaño de letra base en píxeles (proporcional al ancho)
+     */
+    private String css(int base) {
+        return """
+            body {
+                margin: 0;
+                padding: %1$dpx;
+                background-color: #ffffff;
+                font-family: Helvetica, Arial, sans-serif;
+                font-size: %1$dpx;
+                color: #1a1a1a;
+                line-height: 1.3;
+            }
+            .header {
+                text-align: center;
+                padding-bottom: %2$dpx;
+                border-bottom: 3px solid #c0392b;
+            }
+            .header .local {
+                margin: 0;
+                font-size: %3$dpx;
+                color: #555;
+            }
+            .header h1 {
+                margin: 2px 0;
+                font-size: %4$dpx;
+                color: #c0392b;
+            }
+            .header .periodo {
+                margin: 0;
+                font-size: %3$dpx;
+                color: #2c3e50;
+                font-weight: bold;
+            }
+            .seccion {
+                margin-top: %1$dpx;
+            }
+            .seccion h2 {
+                margin: 0 0 %2$dpx 0;
+                font-size: %3$dpx;
+                color: #2c3e50;
+                text-transform: uppercase;
+                border-bottom: 1px solid #ddd;
+            }
+            table {
+                width: 100%%;
+                border-collapse: collapse;
+            }
+            td {
+                padding: %2$dpx 0;
+                vertical-align: top;
+            }
+            .detalle {
+                text-align: right;
+                color: #777;
+                padding-right: %2$dpx;
+                white-space: nowrap;
+            }
+            .monto {
+                text-align: right;
+                font-family: monospace;
+                font-weight: bold;
+                white-space: nowrap;
+            }
+            .total td {
+                border-top: 2px solid #2c3e50;
+                font-weight: bold;
+                color: #c0392b;
+            }
+            .vacio {
+                color: #999;
+            }
+            .footer {
+                margin-top: %1$dpx;
+                padding-top: %2$dpx;
+                border-top: 1px solid #ddd;
+                text-align: center;
+                font-size: %5$dpx;
+                color: #999;
+            }
+            """.formatted(base, base / 4, base * 3 / 4, base * 3 / 2, base * 2 / 3);
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Helpers
+    // ════════════════════════════════════════════════════════════════════════════
+
+    private String formatPeriodo(ReporteImagenData data) {
+        if (data.desde().equals(data.hasta())) {
+            return data.desde().format(FORMATO_FECHA);
+        }
+        return data.desde().format(FORMATO_FECHA) + " al " + data.hasta().format(FORMATO_FECHA);
+    }
+
+    private String formatMonto(BigDecimal monto) {
+        return String.format("$%,.2f", monto);
+    }
+
+    /**
+     * Escapa caracteres XML especiales para evitar XHTML roto.
+     */
+    private String esc(String text) {
+        if (text == null) return "";
+        return text.replace("&", "&amp;")
+                   .replace("<", "&lt;")
+                   .replace(">", "&gt;")
+                   .replace("\"", "&quot;");
+    }
+}
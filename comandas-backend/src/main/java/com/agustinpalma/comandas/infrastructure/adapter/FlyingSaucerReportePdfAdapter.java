@@ -20,6 +20,7 @@ import java.util.Map;
  * - Resumen de arqueo (totales + pedidos cerrados)
  * - Desglose por medio de pago
  * - Lista de movimientos manuales (egresos + ingresos)
+ * - HU-106: Arqueo por turno con la diferencia de cada uno
  * - Pie con fecha/hora de generación
  *
  * El HTML se construye programáticamente con CSS embebido.
@@ -108,6 +109,9 @@ public class FlyingSaucerReportePdfAdapter implements ReportePdfGenerator {
         filaResumenMonto(html, "Total consumo interno", data.totalConsumoInterno());
         filaResumenMonto(html, "Total ingresos manuales", data.totalIngresos());
         filaResumenMonto(html, "Total egresos", data.totalEgresos());
+        if (data.totalAjusteRedondeo().signum() != 0) {
+            filaResumenMonto(html, "Ajuste por redondeo", data.totalAjusteRedondeo());
+        }
         html.append("    </tbody>\n");
         html.append("    <tfoot>\n");
         html.append("      <tr class=\"total\">\n");
@@ -148,6 +152,70 @@ public class FlyingSaucerReportePdfAdapter implements ReportePdfGenerator {
             html.append("</div>\n");
         }
 
+        // ── HU-143: Recargos por Medio de Pago ─────────────────────────────
+        if (!data.recargosPorMedioPago().isEmpty()) {
+            html.append("<div class=\"seccion\">\n");
+            html.append("  <h3>Recargos por Medio de Pago</h3>\n");
+            html.append("  <table class=\"tabla-desglose\">\n");
+            html.append("    <thead>\n");
+            html.append("      <tr><th>Medio de Pago</th><th class=\"monto\">Recargo</th></tr>\n");
+            html.append("    </thead>\n");
+            html.append("    <tbody>\n");
+
+            BigDecimal totalRecargos = BigDecimal.ZERO;
+            for (Map.Entry<MedioPago, BigDecimal> entry : data.recargosPorMedioPago().entrySet()) {
+                html.append("      <tr>\n");
+                html.append("        <td>").append(formatMedioPago(entry.getKey())).append("</td>\n");
+                html.append("        <td class=\"monto\">").append(formatMonto(entry.getValue())).append("</td>\n");
+                html.append("      </tr>\n");
+                totalRecargos = totalRecargos.add(entry.getValue());
+            }
+
+            html.append("    </tbody>\n");
+            html.append("    <tfoot>\n");
+            html.append("      <tr class=\"total\">\n");
+            html.append("        <td>TOTAL RECARGOS</td>\n");
+            html.append("        <td class=\"monto\">").append(formatMonto(totalRecargos)).append("</td>\n");
+            html.append("      </tr>\n");
+            html.append("    </tfoot>\n");
+            html.append("  </table>\n");
+            html.append("</div>\n");
+        }
+
+        // ── HU-151: Efectivo por Moneda ─────────────────────────────────────
+        if (!data.efectivoPorMoneda().isEmpty()) {
+            html.append("<div class=\"seccion\">\n");
+            html.append("  <h3>Efectivo por Moneda</h3>\n");
+            html.append("  <table class=\"tabla-desglose\">\n");
+            html.append("    <thead>\n");
+            html.append("      <tr><th>Moneda</th><th class=\"monto\">En el cajón</th><th class=\"monto\">Equivalente</th></tr>\n");
+            html.append("    </thead>\n");
+            html.append("    <tbody>\n");
+            html.append("      <tr>\n");
+            html.append("        <td>ARS</td>\n");
+            html.append("        <td class=\"monto\">").append(formatMonto(data.efectivoPesos())).append("</td>\n");
+            html.append("        <td class=\"monto\">").append(formatMonto(data.efectivoPesos())).append("</td>\n");
+            html.append("      </tr>\n");
+
+            for (ReporteCierreData.EfectivoMonedaDetalle efectivo : data.efectivoPorMoneda()) {
+                html.append("      <tr>\n");
+                html.append("        <td>").append(efectivo.moneda()).append("</td>\n");
+                html.append("        <td class=\"monto\">").append(String.format("%s %,.2f", efectivo.moneda(), efectivo.monto())).append("</td>\n");
+                html.append("        <td class=\"monto\">").append(formatMonto(efectivo.equivalentePesos())).append("</td>\n");
+                html.append("      </tr>\n");
+            }
+
+            html.append("    </tbody>\n");
+            html.append("    <tfoot>\n");
+            html.append("      <tr class=\"total\">\n");
+            html.append("        <td colspan=\"2\">BALANCE DE EFECTIVO</td>\n");
+            html.append("        <td class=\"monto\">").append(formatMonto(data.balanceEfectivo())).append("</td>\n");
+            html.append("      </tr>\n");
+            html.append("    </tfoot>\n");
+            html.append("  </table>\n");
+            html.append("</div>\n");
+        }
+
         // ── Movimientos Manuales ───────────────────────────────────────────
         if (!data.movimientos().isEmpty()) {
             html.append("<div class=\"seccion\">\n");
@@ -181,6 +249,54 @@ public class FlyingSaucerReportePdfAdapter implements ReportePdfGenerator {
             html.append("</div>\n");
         }
 
+        // ── HU-106: Arqueo por turno ───────────────────────────────────────
+        if (!data.turnos().isEmpty()) {
+            html.append("<div class=\"seccion\">\n");
+            html.append("  <h3>Arqueo por Turno</h3>\n");
+            html.append("  <table class=\"tabla-movimientos\">\n");
+            html.append("    <thead>\n");
+            html.append("      <tr>\n");
+            html.append("        <th>Turno</th>\n");
+            html.append("        <th>Horario</th>\n");
+            html.append("        <th class=\"monto\">Inicial</th>\n");
+            html.append("        <th class=\"monto\">Esperado</th>\n");
+            html.append("        <th class=\"monto\">Declarado</th>\n");
+            html.append("        <th class=\"monto\">Diferencia</th>\n");
+            html.append("      </tr>\n");
+            html.append("    </thead>\n");
+            html.append("    <tbody>\n");
+
+            DateTimeFormatter horaFmt = DateTimeFormatter.ofPattern("HH:mm");
+            BigDecimal diferenciaTotal = BigDecimal.ZERO;
+            for (ReporteCierreData.TurnoDetalle turno : data.turnos()) {
+                String horario = turno.fechaApertura().format(horaFmt) + " – "
+                    + (turno.fechaCierre() != null ? turno.fechaCierre().format(horaFmt) : "abierto");
+                html.append("      <tr>\n");
+                html.append("        <td>").append(formatTipoTurno(turno.tipo())).append("</td>\n");
+                html.append("        <td>").append(horario).append("</td>\n");
+                html.append("        <td class=\"monto\">").append(formatMonto(turno.montoInicial())).append("</td>\n");
+                html.append("        <td class=\"monto\">").append(formatMonto(turno.efectivoEsperado())).append("</td>\n");
+                html.append("        <td class=\"monto\">").append(formatMonto(turno.efectivoDeclarado())).append("</td>\n");
+                html.append("        <td class=\"monto ").append(claseDiferencia(turno.diferencia())).append("\">")
+                    .append(formatMonto(turno.diferencia())).append("</td>\n");
+                html.append("      </tr>\n");
+                if (turno.diferencia() != null) {
+                    diferenciaTotal = diferenciaTotal.add(turno.diferencia());
+                }
+            }
+
+            html.append("    </tbody>\n");
+            html.append("    <tfoot>\n");
+            html.append("      <tr class=\"total\">\n");
+            html.append("        <td colspan=\"5\">Diferencia total</td>\n");
+            html.append("        <td class=\"monto ").append(claseDiferencia(diferenciaTotal)).append("\">")
+                .append(formatMonto(diferenciaTotal)).append("</td>\n");
+            html.append("      </tr>\n");
+            html.append("    </tfoot>\n");
+            html.append("  </table>\n");
+            html.append("</div>\n");
+        }
+
         // ── Pie ────────────────────────────────────────────────────────────
         html.append("<div class=\"footer\">\n");
         html.append("  <p>Documento generado automáticamente por FoodFlow</p>\n");
@@ -309,6 +425,8 @@ public class FlyingSaucerReportePdfAdapter implements ReportePdfGenerator {
             }
             .tipo-egreso { color: #c0392b; font-weight: bold; }
             .tipo-ingreso { color: #27ae60; font-weight: bold; }
+            .faltante { color: #c0392b; font-weight: bold; }
+            .sobrante { color: #27ae60; font-weight: bold; }
             .footer {
                 margin-top: 30px;
                 padding-top: 10px;
@@ -350,9 +468,28 @@ public class FlyingSaucerReportePdfAdapter implements ReportePdfGenerator {
             case TRANSFERENCIA -> "Transferencia";
             case QR -> "QR";
             case A_CUENTA -> "A Cuenta (Consumo Interno)";
+            case CUENTA_CORRIENTE -> "Cuenta Corriente";
+            case TARJETA_REGALO -> "Tarjeta de Regalo";
+        };
+    }
+
+    private String formatTipoTurno(String tipo) {
+        return switch (tipo) {
+            case "MANANA" -> "Mañana";
+            case "TARDE" -> "Tarde";
+            case "NOCHE" -> "Noche";
+            default -> esc(tipo);
         };
     }
 
+    /**
+     * Negativo = faltó efectivo en el cajón; positivo = sobró.
+     */
+    private String claseDiferencia(BigDecimal diferencia) {
+        if (diferencia == null || diferencia.signum() == 0) return "";
+        return diferencia.signum() < 0 ? "faltante" : "sobrante";
+    }
+
     /**
      * Escapa caracteres XML especiales para evitar XHTML roto.
      */
@@ -0,0 +1,90 @@
+package com.agustinpalma.comandas.infrastructure.adapter;
+
+import com.agustinpalma.comandas.application.ports.output.MantenimientoBasePort;
+
+import javax.sql.DataSource;
+import java.sql.Connection;
+import java.sql.ResultSet;
+import java.sql.SQLException;
+import java.sql.Statement;
+
+/**
+ * Mantenimiento de la base con JDBC directo (HU-163).
+ *
+ * En SQLite (instalación offline) primero se vacía el WAL al archivo
+ * principal y después se corre VACUUM, ANALYZE y REINDEX. Con el pool de una
+ * sola conexión el resto de los requests esperan a que termine.
+ *
+ * En PostgreSQL se corre VACUUM (ANALYZE) y REINDEX de la base completa. Los
+ * dos tienen que ir fuera de una transacción, por eso se fuerza autocommit.
+ */
+public class JdbcMantenimientoBaseAdapter implements MantenimientoBasePort {
+
+    private static final String SQLITE = "SQLite";
+    private static final String POSTGRES = "PostgreSQL";
+
+    private final DataSource dataSource;
+
+    public JdbcMantenimientoBaseAdapter(DataSource dataSource) {
+        this.dataSource = dataSource;
+    }
+
+    @Override
+    public Resultado optimizar() {
+        try (Connection conexion = dataSource.getConnection()) {
+            boolean autoCommit = conexion.getAutoCommit();
+            conexion.setAutoCommit(true);
+            try (Statement sentencia = conexion.createStatement()) {
+                String motor = conexion.getMetaData().getDatabaseProductName();
+                if (motor.toLowerCase().contains("sqlite")) {
+                    return optimizarSqlite(sentencia);
+                }
+                if (motor.toLowerCase().contains("postgres")) {
+                    return optimizarPostgres(sentencia, conexion.getCatalog());
+                }
+                throw new IllegalStateException("El mantenimiento de la base no está soportado para " + motor);
+            } finally {
+                conexion.setAutoCommit(autoCommit);
+            }
+        } catch (SQLException e) {
+            throw new RuntimeException("Error al correr el mantenimiento de la base", e);
+        }
+    }
+
+    private Resultado optimizarSqlite(Statement sentencia) throws SQLException {
+        // Con el WAL vacío el tamaño medido es el de toda la base
+        sentencia.execute("PRAGMA wal_checkpoint(TRUNCATE)");
+        long antes = tamanioSqlite(sentencia);
+
+        sentencia.execute("VACUUM");
+        sentencia.execute("ANALYZE");
+        sentencia.execute("REINDEX");
+        sentencia.execute("PRAGMA optimize");
+        sentencia.execute("PRAGMA wal_checkpoint(TRUNCATE)");
+
+        return new Resultado(SQLITE, antes, tamanioSqlite(sentencia));
+    }
+
+    private Resultado optimizarPostgres(Statement sentencia, String base) throws SQLException {
+        long antes = tamanioPostgres(sentencia);
+
+        sentencia.execute("VACUUM (ANALYZE)");
+        sentencia.execute("REINDEX DATABASE \"" + base.replace("\"", "\"\"") + "\"");
+
+        return new Resultado(POSTGRES, antes, tamanioPostgres(sentencia));
+    }
+
+    private long tamanioSqlite(Statement sentencia) throws SQLException {
+        return consultarLong(sentencia, "PRAGMA page_count") * consultarLong(sentencia, "PRAGMA page_size");
+    }
+
+    private long tamanioPostgres(Statement sentencia) throws SQLException {
+        return consultarLong(sentencia, "SELECT pg_database_size(current_database())");
+    }
+
+    private long consultarLong(Statement sentencia, String sql) throws SQLException {
+        try (ResultSet rs = sentencia.executeQuery(sql)) {
+            return rs.next() ? rs.getLong(1) : 0L;
+        }
+    }
+}
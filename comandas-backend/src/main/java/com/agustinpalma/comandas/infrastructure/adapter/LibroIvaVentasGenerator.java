@@ -0,0 +1,193 @@
+package com.agustinpalma.comandas.infrastructure.adapter;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoFactura;
+import com.agustinpalma.comandas.domain.model.Factura;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+import java.nio.charset.Charset;
+import java.nio.charset.StandardCharsets;
+import java.text.Normalizer;
+import java.time.format.DateTimeFormatter;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Generador de archivos del libro IVA ventas (HU-116).
+ *
+ * Dos salidas sobre las mismas facturas:
+ * - CSV para planilla: una fila por comprobante y alícuota, separador ";"
+ *   y coma decimal (lo que abre Excel en es-AR sin configurar nada).
+ * - TXT de ancho fijo con el diseño de registro del régimen de información
+ *   de compras y ventas (comprobantes + alícuotas), que importan los
+ *   sistemas contables.
+ *
+ * Esta clase es INFRAESTRUCTURA: solo formatea; qué facturas entran lo decide el caso de uso.
+ */
+public final class LibroIvaVentasGenerator {
+
+    /** Codificación de los TXT: sin acentos, en una línea por registro con CRLF */
+    public static final Charset CHARSET_TXT = StandardCharsets.ISO_8859_1;
+
+    private static final DateTimeFormatter FECHA_CSV = DateTimeFormatter.ofPattern("dd/MM/yyyy");
+    private static final DateTimeFormatter FECHA_TXT = DateTimeFormatter.ofPattern("yyyyMMdd");
+    private static final String FIN_LINEA = "\r\n";
+
+    private static final Map<TipoFactura, String> CODIGO_COMPROBANTE = Map.of(
+        TipoFactura.A, "001",
+        TipoFactura.B, "006",
+        TipoFactura.C, "011"
+    );
+
+    /** Códigos de alícuota del régimen de información (por porcentaje) */
+    private static final Map<BigDecimal, String> CODIGO_ALICUOTA = Map.of(
+        new BigDecimal("0.00"), "0003",
+        new BigDecimal("2.50"), "0009",
+        new BigDecimal("5.00"), "0008",
+        new BigDecimal("10.50"), "0004",
+        new BigDecimal("21.00"), "0005",
+        new BigDecimal("27.00"), "0006"
+    );
+
+    private static final String DOC_CUIT = "80";
+    private static final String DOC_CONSUMIDOR_FINAL = "99";
+
+    private LibroIvaVentasGenerator() {
+    }
+
+    // ─── CSV ─────────────────────────────────────────────────────────────────────
+
+    /**
+     * @return CSV en UTF-8 con BOM para que la planilla respete los acentos
+     */
+    public static byte[] generarCsv(List<Factura> facturas) {
+        StringBuilder csv = new StringBuilder("\uFEFF");
+        csv.append("Fecha;Tipo;Punto de venta;Numero;CUIT;Razon social;Condicion IVA;")
+           .append("Alicuota;Neto gravado;IVA;Total;CAE").append(FIN_LINEA);
+
+        for (Factura f : facturas) {
+            csv.append(f.getFechaEmision().format(FECHA_CSV)).append(';')
+               .append(f.getTipo()).append(';')
+               .append(String.format("%05d", f.getPuntoVenta())).append(';')
+               .append(String.format("%08d", f.getNumero())).append(';')
+               .append(f.getReceptorCuit() != null ? f.getReceptorCuit().formateado() : "").append(';')
+               .append(csvTexto(razonSocial(f))).append(';')
+               .append(f.getReceptorCondicionIva()).append(';')
+               .append(decimalCsv(f.getAlicuotaIva())).append(';')
+               .append(decimalCsv(f.getImporteNeto())).append(';')
+               .append(decimalCsv(f.getImporteIva())).append(';')
+               .append(decimalCsv(f.getImporteTotal())).append(';')
+               .append(f.getCae() != null ? f.getCae() : "")
+               .append(FIN_LINEA);
+        }
+
+        return csv.toString().getBytes(StandardCharsets.UTF_8);
+    }
+
+    // ─── TXT régimen de información ──────────────────────────────────────────────
+
+    /**
+     * Registro de comprobantes: 266 caracteres por línea.
+     */
+    public static byte[] generarComprobantesTxt(List<Factura> facturas) {
+        StringBuilder txt = new StringBuilder();
+        for (Factura f : facturas) {
+            boolean discrimina = f.getTipo() != TipoFactura.C;
+            txt.append(f.getFechaEmision().format(FECHA_TXT))
+               .append(CODIGO_COMPROBANTE.get(f.getTipo()))
+               .append(numero(f.getPuntoVenta(), 5))
+               .append(numero(f.getNumero(), 20))
+               .append(numero(f.getNumero(), 20))
+               .append(f.getReceptorCuit() != null ? DOC_CUIT : DOC_CONSUMIDOR_FINAL)
+               .append(f.getReceptorCuit() != null ? numero(Long.parseLong(f.getReceptorCuit().getNumero()), 20) : numero(0, 20))
+               .append(texto(razonSocial(f), 30))
+               .append(importe(f.getImporteTotal()))
+               .append(importe(BigDecimal.ZERO))  // no gravado
+               .append(importe(BigDecimal.ZERO))  // percepción a no categorizados
+               .append(importe(discrimina ? BigDecimal.ZERO : f.getImporteTotal()))  // exentas
+               .append(importe(BigDecimal.ZERO))  // percepciones nacionales
+               .append(importe(BigDecimal.ZERO))  // percepciones IIBB
+               .append(importe(BigDecimal.ZERO))  // percepciones municipales
+               .append(importe(BigDecimal.ZERO))  // impuestos internos
+               .append("PES")
+               .append("0001000000")              // tipo de cambio 1,000000
+               .append(discrimina ? "1" : "0")    // cantidad de alícuotas
+               .append(discrimina ? " " : "E")    // código de operación
+               .append(importe(BigDecimal.ZERO))  // otros tributos
+               .append(f.getFechaEmision().format(FECHA_TXT))
+               .append(FIN_LINEA);
+        }
+        return txt.toString().getBytes(CHARSET_TXT);
+    }
+
+    /**
+     * Registro de alícuotas: 62 caracteres por línea, una por comprobante con IVA.
+     * Las facturas C no discriminan IVA y no llevan registro de alícuota.
+     */
+    public static byte[] generarAlicuotasTxt(List<Factura> facturas) {
+        StringBuilder txt = new StringBuilder();
+        for (Factura f : facturas) {
+            if (f.getTipo() == TipoFactura.C) {
+                continue;
+            }
+            txt.append(CODIGO_COMPROBANTE.get(f.getTipo()))
+               .append(numero(f.getPuntoVenta(), 5))
+               .append(numero(f.getNumero(), 20))
+               .append(importe(f.getImporteNeto()))
+               .append(codigoAlicuota(f.getAlicuotaIva()))
+               .append(importe(f.getImporteIva()))
+               .append(FIN_LINEA);
+        }
+        return txt.toString().getBytes(CHARSET_TXT);
+    }
+
+    // ─── Helpers ─────────────────────────────────────────────────────────────────
+
+    private static String razonSocial(Factura f) {
+        if (f.getReceptorCuit() == null) {
+            return "CONSUMIDOR FINAL";
+        }
+        return f.getReceptorRazonSocial() != null ? f.getReceptorRazonSocial() : "";
+    }
+
+    private static String codigoAlicuota(BigDecimal alicuota) {
+        String codigo = CODIGO_ALICUOTA.get(alicuota.setScale(2, RoundingMode.HALF_UP));
+        if (codigo == null) {
+            throw new IllegalStateException("Alícuota de IVA sin código en el régimen de información: " + alicuota);
+        }
+        return codigo;
+    }
+
+    /** 13 enteros + 2 decimales sin separador, con ceros a la izquierda */
+    private static String importe(BigDecimal monto) {
+        long centavos = monto.setScale(2, RoundingMode.HALF_UP).movePointRight(2).longValueExact();
+        return numero(centavos, 15);
+    }
+
+    private static String numero(long valor, int ancho) {
+        return String.format("%0" + ancho + "d", valor);
+    }
+
+    /** Sin acentos, en mayúsculas, recortado o completado con espacios */
+    private static String texto(String valor, int ancho) {
+        String ascii = Normalizer.normalize(valor, Normalizer.Form.NFD)
+            .replaceAll("\\p{M}", "")
+            .replaceAll("[^\\x20-\\x7E]", "")
+            .toUpperCase();
+        if (ascii.length() > ancho) {
+            return ascii.substring(0, ancho);
+        }
+        return String.format("%-" + ancho + "s", ascii);
+    }
+
+    private static String decimalCsv(BigDecimal valor) {
+        return valor.setScale(2, RoundingMode.HALF_UP).toPlainString().replace('.', ',');
+    }
+
+    private static String csvTexto(String valor) {
+        if (valor.contains(";") || valor.contains("\"")) {
+            return "\"" + valor.replace("\"", "\"\"") + "\"";
+        }
+        return valor;
+    }
+}
@@ -0,0 +1,134 @@
+package com.agustinpalma.comandas.infrastructure.adapter;
+
+import com.agustinpalma.comandas.application.ports.output.PasarelaPagoPort;
+import com.agustinpalma.comandas.domain.model.DomainEnums.ProveedorLinkPago;
+import com.fasterxml.jackson.databind.JsonNode;
+import com.fasterxml.jackson.databind.ObjectMapper;
+import com.fasterxml.jackson.databind.node.ObjectNode;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+
+import java.io.IOException;
+import java.net.URI;
+import java.net.URLEncoder;
+import java.net.http.HttpClient;
+import java.net.http.HttpRequest;
+import java.net.http.HttpResponse;
+import java.nio.charset.StandardCharsets;
+import java.time.Duration;
+import java.util.Optional;
+
+/**
+ * Pasarela de pago sobre la API de Mercado Pago (HU-128).
+ *
+ * El link es una preferencia de Checkout Pro por el total del pedido, con el
+ * id del link como external_reference. Para saber si se pagó se buscan los
+ * pagos aprobados con esa referencia.
+ *
+ * Configuración:
+ * - {@code app.pagos.mercadopago.access-token}: credencial de producción de
+ *   la cuenta del local. Vacío → Mercado Pago no disponible.
+ * - {@code app.pagos.url-notificaciones}: dirección pública de esta
+ *   instalación (dominio o túnel). Vacío → no se piden avisos y los pagos se
+ *   detectan verificando desde el salón.
+ *
+ * MODO no publica una API de cobro para comercios sin convenio, así que esa
+ * pasarela se informa como no disponible.
+ */
+public class MercadoPagoAdapter implements PasarelaPagoPort {
+
+    private static final Logger log = LoggerFactory.getLogger(MercadoPagoAdapter.class);
+
+    private static final String API = "https://api.mercadopago.com";
+    private static final Duration TIMEOUT = Duration.ofSeconds(10);
+
+    private final String accessToken;
+    private final String urlNotificaciones;
+    private final ObjectMapper objectMapper;
+    private final HttpClient httpClient;
+
+    public MercadoPagoAdapter(String accessToken, String urlNotificaciones, ObjectMapper objectMapper) {
+        this.accessToken = accessToken == null ? "" : accessToken.trim();
+        this.urlNotificaciones = urlNotificaciones == null ? "" : urlNotificaciones.trim().replaceAll("/+$", "");
+        this.objectMapper = objectMapper;
+        this.httpClient = HttpClient.newBuilder().connectTimeout(TIMEOUT).build();
+    }
+
+    @Override
+    public LinkGenerado crearLink(ProveedorLinkPago proveedor, SolicitudLink solicitud) {
+        validarProveedor(proveedor);
+
+        ObjectNode preferencia = objectMapper.createObjectNode();
+        ObjectNode item = preferencia.putArray("items").addObject();
+        item.put("title", solicitud.descripcion());
+        item.put("quantity", 1);
+        item.put("currency_id", "ARS");
+        item.put("unit_price", solicitud.monto());
+        preferencia.put("external_reference", solicitud.referencia());
+        if (!urlNotificaciones.isEmpty()) {
+            preferencia.put("notification_url",
+                urlNotificaciones + "/api/links-pago/notificaciones/" + solicitud.referencia());
+        }
+
+        HttpRequest request = autenticada(URI.create(API + "/checkout/preferences"))
+            .header("Content-Type", "application/json")
+            .POST(HttpRequest.BodyPublishers.ofString(preferencia.toString()))
+            .build();
+
+        JsonNode respuesta = enviar(request);
+        String url = respuesta.path("init_point").asText("");
+        if (url.isEmpty()) {
+            throw new PasarelaPagoNoDisponibleException("Mercado Pago no devolvió el link de pago");
+        }
+        return new LinkGenerado(url);
+    }
+
+    @Override
+    public Optional<PagoAprobado> buscarPagoAprobado(ProveedorLinkPago proveedor, String referencia) {
+        validarProveedor(proveedor);
+
+        URI uri = URI.create(API + "/v1/payments/search?status=approved&external_reference="
+            + URLEncoder.encode(referencia, StandardCharsets.UTF_8));
+        JsonNode respuesta = enviar(autenticada(uri).GET().build());
+
+        for (JsonNode pago : respuesta.path("results")) {
+            if ("approved".equals(pago.path("status").asText())) {
+                return Optional.of(new PagoAprobado(pago.path("id").asText()));
+            }
+        }
+        return Optional.empty();
+    }
+
+    private void validarProveedor(ProveedorLinkPago proveedor) {
+        if (proveedor != ProveedorLinkPago.MERCADO_PAGO) {
+            throw new PasarelaPagoNoDisponibleException(proveedor + " no está configurado en esta instalación");
+        }
+        if (accessToken.isEmpty()) {
+            throw new PasarelaPagoNoDisponibleException("Mercado Pago no está configurado en esta instalación");
+        }
+    }
+
+    private HttpRequest.Builder autenticada(URI uri) {
+        return HttpRequest.newBuilder(uri)
+            .timeout(TIMEOUT)
+            .header("Authorization", "Bearer " + accessToken);
+    }
+
+    private JsonNode enviar(HttpRequest request) {
+        try {
+            HttpResponse<String> response = httpClient.send(request, HttpResponse.BodyHandlers.ofString());
+            if (response.statusCode() >= 400) {
+                log.warn("Mercado Pago respondió {} a {}: {}", response.statusCode(), request.uri().getPath(), response.body());
+                throw new PasarelaPagoNoDisponibleException(
+                    "Mercado Pago rechazó la operación (HTTP " + response.statusCode() + ")");
+            }
+            return objectMapper.readTree(response.body());
+        } catch (IOException e) {
+            log.warn("Sin respuesta de Mercado Pago: {}", e.getMessage());
+            throw new PasarelaPagoNoDisponibleException("No hay conexión con Mercado Pago");
+        } catch (InterruptedException e) {
+            Thread.currentThread().interrupt();
+            throw new PasarelaPagoNoDisponibleException("Se interrumpió la consulta a Mercado Pago");
+        }
+    }
+}
@@ -0,0 +1,31 @@
+package com.agustinpalma.comandas.infrastructure.adapter;
+
+import com.agustinpalma.comandas.application.ports.output.ModuloFiscalPort;
+import com.agustinpalma.comandas.domain.model.Cuit;
+import com.agustinpalma.comandas.domain.model.Factura;
+
+import java.util.Optional;
+
+/**
+ * Implementación del módulo fiscal para instalaciones sin conexión al organismo
+ * (HU-115).
+ *
+ * No inventa datos: informa que el padrón y la autorización no están
+ * disponibles. El operador carga razón social y condición a mano y las
+ * facturas quedan PENDIENTE_AUTORIZACION hasta que se configure un adaptador
+ * real (web services de padrón y facturación electrónica) y se reintente.
+ */
+public class ModuloFiscalDesconectadoAdapter implements ModuloFiscalPort {
+
+    private static final String MENSAJE = "El módulo fiscal no está configurado en esta instalación";
+
+    @Override
+    public Optional<Contribuyente> consultarPadron(Cuit cuit) {
+        throw new ModuloFiscalNoDisponibleException(MENSAJE);
+    }
+
+    @Override
+    public Autorizacion autorizar(Factura factura) {
+        throw new ModuloFiscalNoDisponibleException(MENSAJE);
+    }
+}
@@ -0,0 +1,51 @@
+package com.agustinpalma.comandas.infrastructure.adapter;
+
+import com.agustinpalma.comandas.application.ports.output.ApagadoAplicacionPort;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+import org.springframework.boot.SpringApplication;
+import org.springframework.context.ConfigurableApplicationContext;
+
+import java.util.concurrent.atomic.AtomicBoolean;
+
+/**
+ * Apagado ordenado del backend con el ciclo de vida de Spring (HU-155).
+ *
+ * {@link SpringApplication#exit} cierra el contexto: Tomcat deja de aceptar
+ * requests, los emisores SSE se cierran y Hikari cierra la conexión a SQLite,
+ * que hace el checkpoint del WAL. Recién ahí termina el proceso.
+ *
+ * Corre en un hilo aparte con una pausa corta para que la respuesta del
+ * request que lo pidió llegue antes de que el servidor deje de atender.
+ */
+public class SpringApagadoAplicacionAdapter implements ApagadoAplicacionPort {
+
+    private static final Logger logger = LoggerFactory.getLogger(SpringApagadoAplicacionAdapter.class);
+    private static final long PAUSA_ANTES_DE_APAGAR_MS = 500;
+
+    private final ConfigurableApplicationContext context;
+    private final AtomicBoolean apagando = new AtomicBoolean(false);
+
+    public SpringApagadoAplicacionAdapter(ConfigurableApplicationContext context) {
+        this.context = context;
+    }
+
+    @Override
+    public void apagar() {
+        // Dos pedidos seguidos (reintento de la app de escritorio) no disparan dos apagados
+        if (!apagando.compareAndSet(false, true)) {
+            return;
+        }
+        Thread hilo = new Thread(() -> {
+            try {
+                Thread.sleep(PAUSA_ANTES_DE_APAGAR_MS);
+            } catch (InterruptedException e) {
+                Thread.currentThread().interrupt();
+            }
+            logger.warn("[Energía] Apagado ordenado del backend por corte de luz");
+            System.exit(SpringApplication.exit(context, () -> 0));
+        }, "apagado-ordenado");
+        hilo.setDaemon(false);
+        hilo.start();
+    }
+}
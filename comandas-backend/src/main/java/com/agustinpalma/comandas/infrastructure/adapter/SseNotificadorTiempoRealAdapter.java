@@ -0,0 +1,91 @@
+package com.agustinpalma.comandas.infrastructure.adapter;
+
+import com.agustinpalma.comandas.application.ports.output.NotificadorTiempoRealPort;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+import org.springframework.http.MediaType;
+import org.springframework.transaction.support.TransactionSynchronization;
+import org.springframework.transaction.support.TransactionSynchronizationManager;
+import org.springframework.web.servlet.mvc.method.annotation.SseEmitter;
+
+import java.io.IOException;
+import java.util.List;
+import java.util.Map;
+import java.util.concurrent.ConcurrentHashMap;
+import java.util.concurrent.CopyOnWriteArrayList;
+
+/**
+ * Canal en tiempo real con Server-Sent Events (HU-125).
+ *
+ * Cada terminal abre una conexión GET /api/eventos que queda viva; los
+ * eventos se escriben en todas las conexiones del local. Si el evento se
+ * publica dentro de una transacción, se envía recién después del commit
+ * para que las terminales no relean el dato viejo.
+ *
+ * Las conexiones caídas se descartan al fallar el envío; el navegador
+ * reconecta solo (EventSource).
+ */
+public class SseNotificadorTiempoRealAdapter implements NotificadorTiempoRealPort {
+
+    private static final Logger log = LoggerFactory.getLogger(SseNotificadorTiempoRealAdapter.class);
+
+    /** Sin timeout: la terminal queda escuchando todo el servicio */
+    private static final long SIN_TIMEOUT = 0L;
+
+    private final Map<LocalId, List<SseEmitter>> suscriptores = new ConcurrentHashMap<>();
+
+    /**
+     * Registra una terminal como oyente de los eventos del local.
+     */
+    public SseEmitter suscribir(LocalId localId) {
+        SseEmitter emitter = new SseEmitter(SIN_TIMEOUT);
+        List<SseEmitter> delLocal = suscriptores.computeIfAbsent(localId, id -> new CopyOnWriteArrayList<>());
+        delLocal.add(emitter);
+
+        emitter.onCompletion(() -> delLocal.remove(emitter));
+        emitter.onTimeout(() -> delLocal.remove(emitter));
+        emitter.onError(e -> delLocal.remove(emitter));
+
+        // Primer mensaje para que el proxy/WebView no deje la respuesta en buffer
+        enviar(delLocal, emitter, "conectado", Map.of("terminales", delLocal.size()));
+        return emitter;
+    }
+
+    @Override
+    public void publicar(LocalId localId, String evento, Object datos) {
+        if (TransactionSynchronizationManager.isSynchronizationActive()) {
+            TransactionSynchronizationManager.registerSynchronization(new TransactionSynchronization() {
+                @Override
+                public void afterCommit() {
+                    difundir(localId, evento, datos);
+                }
+            });
+        } else {
+            difundir(localId, evento, datos);
+        }
+    }
+
+    private void difundir(LocalId localId, String evento, Object datos) {
+        List<SseEmitter> delLocal = suscriptores.get(localId);
+        if (delLocal == null) {
+            return;
+        }
+        for (SseEmitter emitter : delLocal) {
+            enviar(delLocal, emitter, evento, datos);
+        }
+    }
+
+    private void enviar(List<SseEmitter> delLocal, SseEmitter emitter, String evento, Object datos) {
+        try {
+            emitter.send(SseEmitter.event().name(evento).data(datos, MediaType.APPLICATION_JSON));
+        } catch (IOException e) {
+            log.debug("[SSE] Terminal desconectada, se descarta: {}", e.getMessage());
+            delLocal.remove(emitter);
+            emitter.completeWithError(e);
+        } catch (IllegalStateException e) {
+            // El emitter ya estaba cerrado: solo se saca de la lista
+            delLocal.remove(emitter);
+        }
+    }
+}
@@ -1,19 +1,94 @@
 package com.agustinpalma.comandas.infrastructure.config;
 
 import com.agustinpalma.comandas.application.usecase.AbrirJornadaUseCase;
+import com.agustinpalma.comandas.application.usecase.ArchivarPedidosUseCase;
+import com.agustinpalma.comandas.application.usecase.EjecutarMantenimientoBaseUseCase;
 import com.agustinpalma.comandas.application.usecase.AbrirMesaUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarContribuyenteUseCase;
+import com.agustinpalma.comandas.application.usecase.EmitirFacturaUseCase;
+import com.agustinpalma.comandas.application.usecase.ExportarLibroIvaVentasUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarGastosUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteGastosUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarInsumosUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarRecetasUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarRentabilidadProductosUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarComandasCocinaUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarDevolucionesUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarFichajesUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteConsumoPersonalUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarTomaInventarioUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarReposicionUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarMermasUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarZonasDeliveryUseCase;
+import com.agustinpalma.comandas.application.usecase.AsignarEntregaDeliveryUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarRepartosUseCase;
+import com.agustinpalma.comandas.application.usecase.RendirEfectivoRepartidorUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteRepartidoresUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarReglaPropinasUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarReglaPropinasUseCase;
+import com.agustinpalma.comandas.application.usecase.DistribuirPropinasUseCase;
+import com.agustinpalma.comandas.application.usecase.ImprimirComprobantePropinasUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarReservasUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarRecargosMedioPagoUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarRecargosMedioPagoUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarTiposCambioUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarRedondeoEfectivoUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarRedondeoEfectivoUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarJornadaComercialUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarJornadaComercialUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarEnergiaUseCase;
+import com.agustinpalma.comandas.application.usecase.NotificarEstadoImpresoraUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarCajasUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarSeriesComprobantesUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarPlanCuentasContableUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarPlanCuentasContableUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarPromoCumpleaniosUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarPromoCumpleaniosUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarHistorialClienteUseCase;
+import com.agustinpalma.comandas.application.usecase.VincularClientePedidoUseCase;
+import com.agustinpalma.comandas.application.usecase.ExportarContabilidadUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarPagosACuentaUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarDatosTransferenciaUseCase;
+import com.agustinpalma.comandas.application.usecase.GenerarImagenReporteUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarTarjetasRegaloUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarAumentosPreciosUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarHistorialPreciosUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarListaEsperaUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarRotacionMesasUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteHorasUseCase;
+import com.agustinpalma.comandas.application.usecase.CambiarDisponibilidadProductoUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarCartaAutopedidoUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarCartaDigitalUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarAutopedidoUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarFotoProductoUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarLinksPagoUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteTiemposCocinaUseCase;
+import com.agustinpalma.comandas.application.usecase.ImprimirFacturaUseCase;
 import com.agustinpalma.comandas.application.usecase.AgregarProductoUseCase;
 import com.agustinpalma.comandas.application.usecase.AjustarStockUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarListaPreciosUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarDisposicionSalonUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarMesasOlvidadasUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarAlertaMesasOlvidadasUseCase;
+import com.agustinpalma.comandas.application.usecase.RegistrarPedidoCuentaUseCase;
 import com.agustinpalma.comandas.application.usecase.AplicarDescuentoManualUseCase;
 import com.agustinpalma.comandas.application.usecase.AsociarProductoAPromocionUseCase;
+import com.agustinpalma.comandas.application.usecase.AbrirTurnoUseCase;
 import com.agustinpalma.comandas.application.usecase.CerrarJornadaUseCase;
+import com.agustinpalma.comandas.application.usecase.CerrarTurnoUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarTurnosJornadaUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarDiferenciasCajaUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteEgresosUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarCategoriasEgresoUseCase;
 import com.agustinpalma.comandas.application.usecase.CerrarMesaUseCase;
 import com.agustinpalma.comandas.application.usecase.ConsultarHistorialJornadasUseCase;
 import com.agustinpalma.comandas.application.usecase.ConsultarPedidoCerradoUseCase;
 import com.agustinpalma.comandas.application.usecase.CorregirPedidoCerradoUseCase;
 import com.agustinpalma.comandas.application.usecase.ConsultarCategoriasUseCase;
 import com.agustinpalma.comandas.application.usecase.ConsultarDetallePedidoUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarListasPreciosUseCase;
 import com.agustinpalma.comandas.application.usecase.ConsultarMesasUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarProductosParaReponerUseCase;
 import com.agustinpalma.comandas.application.usecase.ConsultarProductosUseCase;
 import com.agustinpalma.comandas.application.usecase.CrearCategoriaUseCase;
 import com.agustinpalma.comandas.application.usecase.CrearMesaUseCase;
@@ -29,10 +104,22 @@ import com.agustinpalma.comandas.application.usecase.GenerarReportePdfJornadaUse
 import com.agustinpalma.comandas.application.usecase.ObtenerReporteVentasUseCase;
 import com.agustinpalma.comandas.application.ports.output.AnalyticsRepositoryPort;
 import com.agustinpalma.comandas.application.usecase.GestionarItemsPedidoUseCase;
+import com.agustinpalma.comandas.application.usecase.MarcarUrgenciaPedidoUseCase;
 import com.agustinpalma.comandas.application.usecase.ObtenerEstadoJornadaUseCase;
 import com.agustinpalma.comandas.application.usecase.ReabrirPedidoUseCase;
 import com.agustinpalma.comandas.application.usecase.RegistrarEgresoUseCase;
 import com.agustinpalma.comandas.application.usecase.RegistrarIngresoUseCase;
+import com.agustinpalma.comandas.application.usecase.RegistrarPagoClienteUseCase;
+import com.agustinpalma.comandas.application.usecase.RegistrarCargoCuentaCorrienteUseCase;
+import com.agustinpalma.comandas.application.usecase.ImputarConsumoPersonalUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarClientesUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarResumenCuentaClienteUseCase;
+import com.agustinpalma.comandas.application.usecase.CrearClienteUseCase;
+import com.agustinpalma.comandas.application.usecase.EditarClienteUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarProgramaPuntosUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarPuntosClienteUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarReportePuntosUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarProgramaPuntosUseCase;
 import com.agustinpalma.comandas.application.usecase.ConsultarPromocionesUseCase;
 import com.agustinpalma.comandas.application.usecase.ConsultarPromocionUseCase;
 import com.agustinpalma.comandas.application.usecase.CambiarEstadoPromocionUseCase;
@@ -40,27 +127,104 @@ import com.agustinpalma.comandas.application.usecase.CrearPromocionUseCase;
 import com.agustinpalma.comandas.application.usecase.EditarPromocionUseCase;
 import com.agustinpalma.comandas.application.usecase.EliminarPromocionUseCase;
 import com.agustinpalma.comandas.application.usecase.EnviarComandaCocinaUseCase;
+import com.agustinpalma.comandas.application.usecase.EstimarEsperaCocinaUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteSucursalesUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteMozosUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarDashboardVentasUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarMozosUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarPermisosUseCase;
+import com.agustinpalma.comandas.application.usecase.ExportarVentasCerradasUseCase;
+import com.agustinpalma.comandas.application.usecase.RecibirVentasSucursalUseCase;
 import com.agustinpalma.comandas.application.usecase.GenerarTicketVentaUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarProveedoresUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarCuentaProveedoresUseCase;
+import com.agustinpalma.comandas.domain.repository.CajaRepository;
+import com.agustinpalma.comandas.domain.repository.SerieComprobantesRepository;
+import com.agustinpalma.comandas.domain.repository.SesionEmpleadoRepository;
+import com.agustinpalma.comandas.domain.repository.PlanCuentasContableRepository;
+import com.agustinpalma.comandas.domain.repository.CategoriaEgresoRepository;
 import com.agustinpalma.comandas.domain.repository.CategoriaRepository;
+import com.agustinpalma.comandas.domain.repository.ClienteRepository;
+import com.agustinpalma.comandas.domain.repository.ConsumoPersonalRepository;
+import com.agustinpalma.comandas.domain.repository.FacturaRepository;
+import com.agustinpalma.comandas.domain.repository.FichajeRepository;
+import com.agustinpalma.comandas.domain.repository.SolicitudAutopedidoRepository;
+import com.agustinpalma.comandas.domain.repository.LinkPagoRepository;
+import com.agustinpalma.comandas.domain.repository.GastoRepository;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
+import com.agustinpalma.comandas.domain.repository.RecetaRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoInsumoRepository;
+import com.agustinpalma.comandas.domain.repository.MermaRepository;
+import com.agustinpalma.comandas.domain.repository.ZonaDeliveryRepository;
+import com.agustinpalma.comandas.domain.repository.RendicionRepartidorRepository;
+import com.agustinpalma.comandas.domain.repository.ReglaPropinasRepository;
+import com.agustinpalma.comandas.domain.repository.DistribucionPropinasRepository;
+import com.agustinpalma.comandas.domain.repository.ReservaRepository;
+import com.agustinpalma.comandas.domain.repository.RecargosMedioPagoRepository;
+import com.agustinpalma.comandas.domain.repository.TiposCambioRepository;
+import com.agustinpalma.comandas.domain.repository.RedondeoEfectivoRepository;
+import com.agustinpalma.comandas.domain.repository.TarjetaRegaloRepository;
+import com.agustinpalma.comandas.domain.repository.ArchivoPedidosRepository;
+import com.agustinpalma.comandas.domain.repository.AumentoPreciosRepository;
+import com.agustinpalma.comandas.domain.repository.CambioPrecioProductoRepository;
+import com.agustinpalma.comandas.domain.repository.ClienteEnEsperaRepository;
+import com.agustinpalma.comandas.domain.repository.TomaInventarioRepository;
+import com.agustinpalma.comandas.domain.repository.ComandaCocinaRepository;
 import com.agustinpalma.comandas.domain.repository.JornadaCajaRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
 import com.agustinpalma.comandas.domain.repository.MesaRepository;
 import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCuentaCorrienteRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoPuntosRepository;
 import com.agustinpalma.comandas.domain.repository.MovimientoStockRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.ListaPreciosRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.MatrizPermisosRepository;
+import com.agustinpalma.comandas.domain.repository.AlertaMesasOlvidadasRepository;
 import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.domain.repository.ProgramaPuntosRepository;
+import com.agustinpalma.comandas.domain.repository.PromoCumpleaniosRepository;
 import com.agustinpalma.comandas.domain.repository.PromocionRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.VentaSucursalRepository;
+import com.agustinpalma.comandas.domain.repository.ProveedorRepository;
+import com.agustinpalma.comandas.domain.repository.FacturaProveedorRepository;
+import com.agustinpalma.comandas.domain.service.ConsumoPersonalService;
+import com.agustinpalma.comandas.domain.service.InventarioService;
+import com.agustinpalma.comandas.domain.service.CuentaCorrienteService;
+import com.agustinpalma.comandas.domain.service.EsperaCocinaService;
 import com.agustinpalma.comandas.domain.service.GestorStockService;
 import com.agustinpalma.comandas.domain.service.MotorReglasService;
 import com.agustinpalma.comandas.domain.service.NormalizadorVariantesService;
+import com.agustinpalma.comandas.domain.service.ProgramaPuntosService;
 
+import com.agustinpalma.comandas.application.ports.output.ApagadoAplicacionPort;
+import com.agustinpalma.comandas.application.ports.output.CajaContextProvider;
+import com.agustinpalma.comandas.application.ports.output.EmpleadoContextProvider;
+import com.agustinpalma.comandas.application.ports.output.IdentidadSucursalProvider;
+import com.agustinpalma.comandas.application.ports.output.MantenimientoBasePort;
+import com.agustinpalma.comandas.application.ports.output.ModuloFiscalPort;
+import com.agustinpalma.comandas.application.ports.output.PasarelaPagoPort;
 import com.agustinpalma.comandas.application.ports.output.ReportePdfGenerator;
+import com.agustinpalma.comandas.application.ports.output.ReporteImagenGenerator;
+import com.agustinpalma.comandas.infrastructure.adapter.FlyingSaucerReporteImagenAdapter;
 import com.agustinpalma.comandas.infrastructure.adapter.FlyingSaucerReportePdfAdapter;
+import com.agustinpalma.comandas.infrastructure.adapter.JdbcMantenimientoBaseAdapter;
+import com.agustinpalma.comandas.infrastructure.adapter.MercadoPagoAdapter;
+import com.agustinpalma.comandas.infrastructure.adapter.ModuloFiscalDesconectadoAdapter;
+import com.agustinpalma.comandas.infrastructure.adapter.SpringApagadoAplicacionAdapter;
+import com.agustinpalma.comandas.infrastructure.adapter.SseNotificadorTiempoRealAdapter;
 
+import com.fasterxml.jackson.databind.ObjectMapper;
 import org.springframework.beans.factory.annotation.Value;
+import org.springframework.context.ConfigurableApplicationContext;
 import org.springframework.context.annotation.Bean;
 import org.springframework.context.annotation.Configuration;
 
+import javax.sql.DataSource;
 import java.time.Clock;
+import java.time.Duration;
 import java.time.LocalDate;
 
 /**
@@ -92,11 +256,21 @@ public class ApplicationConfig {
      *
      * @param mesaRepository implementación del repositorio de mesas
      * @param pedidoRepository implementación del repositorio de pedidos
+     * @param listaPreciosRepository implementación del repositorio de listas de precios (HU-108)
+     * @param mozoRepository implementación del repositorio de mozos (HU-111)
+     * @param reservaRepository implementación del repositorio de reservas (HU-142)
      * @return instancia del caso de uso lista para usar
      */
     @Bean
-    public AbrirMesaUseCase abrirMesaUseCase(MesaRepository mesaRepository, PedidoRepository pedidoRepository) {
-        return new AbrirMesaUseCase(mesaRepository, pedidoRepository);
+    public AbrirMesaUseCase abrirMesaUseCase(
+            MesaRepository mesaRepository,
+            PedidoRepository pedidoRepository,
+            ListaPreciosRepository listaPreciosRepository,
+            MozoRepository mozoRepository,
+            ReservaRepository reservaRepository
+    ) {
+        return new AbrirMesaUseCase(mesaRepository, pedidoRepository, listaPreciosRepository, mozoRepository,
+            reservaRepository);
     }
 
     /**
@@ -107,6 +281,13 @@ public class ApplicationConfig {
      * @param pedidoRepository implementación del repositorio de pedidos
      * @param promocionRepository implementación del repositorio de promociones
      * @param motorReglasService servicio de dominio para evaluar promociones
+     * @param jornadaComercialRepository hora de corte del día operativo (HU-153)
+     * @param gestionarCajasUseCase caja de la terminal que cobra (HU-159)
+     * @param gestionarPagosACuentaUseCase conversión de los pagos: recargo, tipo de cambio y redondeo (HU-143/151/152)
+     * @param gestionarSeriesComprobantesUseCase número de comprobante de la caja que cobra (HU-166)
+     * @param gestionarTarjetasRegaloUseCase saldo de las tarjetas de regalo usadas como pago (HU-144)
+     * @param registrarCargoCuentaCorrienteUseCase cargo de los pagos CUENTA_CORRIENTE (HU-104)
+     * @param imputarConsumoPersonalUseCase consumo interno del empleado (HU-132)
      * @param clock reloj del sistema
      * @return instancia del caso de uso lista para usar
      */
@@ -119,10 +300,33 @@ public class ApplicationConfig {
             MovimientoStockRepository movimientoStockRepository,
             MotorReglasService motorReglasService,
             GestorStockService gestorStockService,
+            ClienteRepository clienteRepository,
+            ProgramaPuntosRepository programaPuntosRepository,
+            MovimientoPuntosRepository movimientoPuntosRepository,
+            ProgramaPuntosService programaPuntosService,
+            TurnoCajaRepository turnoCajaRepository,
+            RecetaRepository recetaRepository,
+            InsumoRepository insumoRepository,
+            MovimientoInsumoRepository movimientoInsumoRepository,
+            InventarioService inventarioService,
+            JornadaComercialRepository jornadaComercialRepository,
+            PromoCumpleaniosRepository promoCumpleaniosRepository,
+            GestionarCajasUseCase gestionarCajasUseCase,
+            GestionarPagosACuentaUseCase gestionarPagosACuentaUseCase,
+            GestionarSeriesComprobantesUseCase gestionarSeriesComprobantesUseCase,
+            GestionarTarjetasRegaloUseCase gestionarTarjetasRegaloUseCase,
+            RegistrarCargoCuentaCorrienteUseCase registrarCargoCuentaCorrienteUseCase,
+            ImputarConsumoPersonalUseCase imputarConsumoPersonalUseCase,
             Clock clock
     ) {
         return new CerrarMesaUseCase(mesaRepository, pedidoRepository, promocionRepository,
-                productoRepository, movimientoStockRepository, motorReglasService, gestorStockService, clock);
+                productoRepository, movimientoStockRepository, motorReglasService, gestorStockService,
+                clienteRepository, programaPuntosRepository, movimientoPuntosRepository, programaPuntosService,
+                turnoCajaRepository, recetaRepository, insumoRepository, movimientoInsumoRepository,
+                inventarioService, jornadaComercialRepository, promoCumpleaniosRepository,
+                gestionarCajasUseCase, gestionarPagosACuentaUseCase, gestionarSeriesComprobantesUseCase,
+                gestionarTarjetasRegaloUseCase, registrarCargoCuentaCorrienteUseCase,
+                imputarConsumoPersonalUseCase, clock);
     }
 
     /**
@@ -149,6 +353,17 @@ public class ApplicationConfig {
         return new EliminarMesaUseCase(mesaRepository);
     }
 
+    /**
+     * HU-112: Bean del caso de uso para guardar el mapa del salón.
+     *
+     * @param mesaRepository implementación del repositorio de mesas
+     * @return instancia del caso de uso lista para usar
+     */
+    @Bean
+    public GuardarDisposicionSalonUseCase guardarDisposicionSalonUseCase(MesaRepository mesaRepository) {
+        return new GuardarDisposicionSalonUseCase(mesaRepository);
+    }
+
     /**
      * Bean del caso de uso para agregar producto a un pedido.
      * HU-10: Ahora incluye motor de reglas para aplicar promociones automáticamente.
@@ -168,6 +383,10 @@ public class ApplicationConfig {
             PromocionRepository promocionRepository,
             MotorReglasService motorReglasService,
             NormalizadorVariantesService normalizadorVariantesService,
+            ListaPreciosRepository listaPreciosRepository,
+            RecetaRepository recetaRepository,
+            InsumoRepository insumoRepository,
+            CategoriaRepository categoriaRepository,
             Clock clock
     ) {
         return new AgregarProductoUseCase(
@@ -176,6 +395,10 @@ public class ApplicationConfig {
             promocionRepository, 
             motorReglasService,
             normalizadorVariantesService,
+            listaPreciosRepository,
+            recetaRepository,
+            insumoRepository,
+            categoriaRepository,
             clock
         );
     }
@@ -232,9 +455,11 @@ public class ApplicationConfig {
     @Bean
     public ConsultarProductosUseCase consultarProductosUseCase(
             ProductoRepository productoRepository,
-            PromocionRepository promocionRepository
+            PromocionRepository promocionRepository,
+            CategoriaRepository categoriaRepository,
+            Clock clock
     ) {
-        return new ConsultarProductosUseCase(productoRepository, promocionRepository);
+        return new ConsultarProductosUseCase(productoRepository, promocionRepository, categoriaRepository, clock);
     }
 
     /**
@@ -266,11 +491,18 @@ public class ApplicationConfig {
      * Spring inyectará automáticamente la implementación JPA del repositorio.
      *
      * @param productoRepository implementación del repositorio de productos
+     * @param cambioPrecioProductoRepository HU-146: historial de precios
+     * @param gestionarPermisosUseCase HU-169: matriz de permisos para cambiar el precio
+     * @param clock reloj del sistema para la fecha del cambio de precio
      * @return instancia del caso de uso lista para usar
      */
     @Bean
-    public EditarProductoUseCase editarProductoUseCase(ProductoRepository productoRepository) {
-        return new EditarProductoUseCase(productoRepository);
+    public EditarProductoUseCase editarProductoUseCase(ProductoRepository productoRepository,
+                                                       CambioPrecioProductoRepository cambioPrecioProductoRepository,
+                                                       GestionarPermisosUseCase gestionarPermisosUseCase,
+                                                       Clock clock) {
+        return new EditarProductoUseCase(productoRepository, cambioPrecioProductoRepository,
+            gestionarPermisosUseCase, clock);
     }
 
     /**
@@ -359,9 +591,11 @@ public class ApplicationConfig {
     @Bean
     public AplicarDescuentoManualUseCase aplicarDescuentoManualUseCase(
             PedidoRepository pedidoRepository,
+            MozoRepository mozoRepository,
+            GestionarPermisosUseCase gestionarPermisosUseCase,
             Clock clock
     ) {
-        return new AplicarDescuentoManualUseCase(pedidoRepository, clock);
+        return new AplicarDescuentoManualUseCase(pedidoRepository, mozoRepository, gestionarPermisosUseCase, clock);
     }
 
     /**
@@ -371,6 +605,7 @@ public class ApplicationConfig {
      * @param pedidoRepository implementación del repositorio de pedidos
      * @param promocionRepository implementación del repositorio de promociones
      * @param motorReglasService servicio de dominio para evaluar promociones
+     * @param gestionarPermisosUseCase matriz de permisos para anular lo enviado a cocina (HU-169)
      * @param clock reloj del sistema para evaluación de criterios temporales
      * @return instancia del caso de uso lista para usar
      */
@@ -379,12 +614,14 @@ public class ApplicationConfig {
             PedidoRepository pedidoRepository,
             PromocionRepository promocionRepository,
             MotorReglasService motorReglasService,
+            GestionarPermisosUseCase gestionarPermisosUseCase,
             Clock clock
     ) {
         return new GestionarItemsPedidoUseCase(
             pedidoRepository,
             promocionRepository,
             motorReglasService,
+            gestionarPermisosUseCase,
             clock
         );
     }
@@ -405,40 +642,64 @@ public class ApplicationConfig {
             ProductoRepository productoRepository,
             MovimientoStockRepository movimientoStockRepository,
             GestorStockService gestorStockService,
+            MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository,
+            MovimientoPuntosRepository movimientoPuntosRepository,
+            FacturaRepository facturaRepository,
+            ConsumoPersonalRepository consumoPersonalRepository,
+            RecetaRepository recetaRepository,
+            InsumoRepository insumoRepository,
+            MovimientoInsumoRepository movimientoInsumoRepository,
+            InventarioService inventarioService,
+            TarjetaRegaloRepository tarjetaRegaloRepository,
             Clock clock
     ) {
         return new ReabrirPedidoUseCase(pedidoRepository, mesaRepository,
-                productoRepository, movimientoStockRepository, gestorStockService, clock);
+                productoRepository, movimientoStockRepository, gestorStockService,
+                movimientoCuentaCorrienteRepository, movimientoPuntosRepository, facturaRepository,
+                consumoPersonalRepository, recetaRepository, insumoRepository, movimientoInsumoRepository,
+                inventarioService, tarjetaRegaloRepository, clock);
     }
 
     /**
      * Bean del caso de uso para registrar egresos de caja.
      * 
      * @param movimientoCajaRepository implementación del repositorio de movimientos
+     * @param turnoCajaRepository para asociar el egreso al turno abierto (HU-106)
+     * @param categoriaEgresoRepository para validar la categoría del egreso (HU-130)
+     * @param gestionarCajasUseCase caja de la terminal que registra el egreso (HU-159)
      * @param clock reloj del sistema para timestamp del movimiento
      * @return instancia del caso de uso lista para usar
      */
     @Bean
     public RegistrarEgresoUseCase registrarEgresoUseCase(
             MovimientoCajaRepository movimientoCajaRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            CategoriaEgresoRepository categoriaEgresoRepository,
+            GestionarCajasUseCase gestionarCajasUseCase,
             Clock clock
     ) {
-        return new RegistrarEgresoUseCase(movimientoCajaRepository, clock);
+        return new RegistrarEgresoUseCase(movimientoCajaRepository, turnoCajaRepository,
+            categoriaEgresoRepository, gestionarCajasUseCase, clock);
     }
 
     /**
      * Bean del caso de uso para registrar ingresos manuales de caja.
      * 
      * @param movimientoCajaRepository implementación del repositorio de movimientos
+     * @param turnoCajaRepository para asociar el ingreso al turno abierto (HU-106)
+     * @param gestionarCajasUseCase caja de la terminal que registra el ingreso (HU-159)
      * @param clock reloj del sistema para timestamp del movimiento
      * @return instancia del caso de uso lista para usar
      */
     @Bean
     public RegistrarIngresoUseCase registrarIngresoUseCase(
             MovimientoCajaRepository movimientoCajaRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            GestionarCajasUseCase gestionarCajasUseCase,
             Clock clock
     ) {
-        return new RegistrarIngresoUseCase(movimientoCajaRepository, clock);
+        return new RegistrarIngresoUseCase(movimientoCajaRepository, turnoCajaRepository,
+            gestionarCajasUseCase, clock);
     }
 
     /**
@@ -455,9 +716,11 @@ public class ApplicationConfig {
             PedidoRepository pedidoRepository,
             MovimientoCajaRepository movimientoCajaRepository,
             MesaRepository mesaRepository,
-            JornadaCajaRepository jornadaCajaRepository
+            JornadaCajaRepository jornadaCajaRepository,
+            JornadaComercialRepository jornadaComercialRepository
     ) {
-        return new GenerarReporteCajaUseCase(pedidoRepository, movimientoCajaRepository, mesaRepository, jornadaCajaRepository);
+        return new GenerarReporteCajaUseCase(pedidoRepository, movimientoCajaRepository, mesaRepository,
+            jornadaCajaRepository, jornadaComercialRepository);
     }
 
     // ============================================
@@ -474,12 +737,14 @@ public class ApplicationConfig {
             JornadaCajaRepository jornadaCajaRepository,
             PedidoRepository pedidoRepository,
             MovimientoCajaRepository movimientoCajaRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            JornadaComercialRepository jornadaComercialRepository,
             ReportePdfGenerator reportePdfGenerator,
             MeisenProperties meisenProperties
     ) {
         return new GenerarReportePdfJornadaUseCase(
             jornadaCajaRepository, pedidoRepository, movimientoCajaRepository,
-            reportePdfGenerator, meisenProperties
+            turnoCajaRepository, jornadaComercialRepository, reportePdfGenerator, meisenProperties
         );
     }
 
@@ -491,6 +756,9 @@ public class ApplicationConfig {
      * @param pedidoRepository para calcular ventas del día
      * @param movimientoCajaRepository para calcular egresos del día
      * @param jornadaCajaRepository para buscar jornada ABIERTA y guardarla CERRADA
+     * @param turnoCajaRepository para impedir el cierre con un turno abierto (HU-106)
+     * @param jornadaComercialRepository hora de corte de la jornada comercial (HU-153)
+     * @param cajaRepository fondo inicial de cada caja abierta en la jornada (HU-159)
      * @param clock reloj del sistema para fecha de cierre
      * @return instancia del caso de uso lista para usar
      */
@@ -500,11 +768,14 @@ public class ApplicationConfig {
             PedidoRepository pedidoRepository,
             MovimientoCajaRepository movimientoCajaRepository,
             JornadaCajaRepository jornadaCajaRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            JornadaComercialRepository jornadaComercialRepository,
+            CajaRepository cajaRepository,
             Clock clock
     ) {
         return new CerrarJornadaUseCase(
             mesaRepository, pedidoRepository, movimientoCajaRepository,
-            jornadaCajaRepository, clock
+            jornadaCajaRepository, turnoCajaRepository, jornadaComercialRepository, cajaRepository, clock
         );
     }
 
@@ -526,9 +797,10 @@ public class ApplicationConfig {
     @Bean
     public AbrirJornadaUseCase abrirJornadaUseCase(
             JornadaCajaRepository jornadaCajaRepository,
+            JornadaComercialRepository jornadaComercialRepository,
             Clock clock
     ) {
-        return new AbrirJornadaUseCase(jornadaCajaRepository, clock, trialExpirationDate);
+        return new AbrirJornadaUseCase(jornadaCajaRepository, jornadaComercialRepository, clock, trialExpirationDate);
     }
 
     /**
@@ -551,9 +823,24 @@ public class ApplicationConfig {
     public CorregirPedidoCerradoUseCase corregirPedidoCerradoUseCase(
             PedidoRepository pedidoRepository,
             MesaRepository mesaRepository,
+            ClienteRepository clienteRepository,
+            MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository,
+            CuentaCorrienteService cuentaCorrienteService,
+            ProgramaPuntosRepository programaPuntosRepository,
+            MovimientoPuntosRepository movimientoPuntosRepository,
+            ProgramaPuntosService programaPuntosService,
+            FacturaRepository facturaRepository,
+            ConsumoPersonalRepository consumoPersonalRepository,
+            ConsumoPersonalService consumoPersonalService,
+            RecargosMedioPagoRepository recargosMedioPagoRepository,
+            RedondeoEfectivoRepository redondeoEfectivoRepository,
             Clock clock
     ) {
-        return new CorregirPedidoCerradoUseCase(pedidoRepository, mesaRepository, clock);
+        return new CorregirPedidoCerradoUseCase(pedidoRepository, mesaRepository, clienteRepository,
+                movimientoCuentaCorrienteRepository, cuentaCorrienteService,
+                programaPuntosRepository, movimientoPuntosRepository, programaPuntosService,
+                facturaRepository, consumoPersonalRepository, consumoPersonalService,
+                recargosMedioPagoRepository, redondeoEfectivoRepository, clock);
     }
 
     /**
@@ -593,82 +880,1605 @@ public class ApplicationConfig {
         return new AjustarStockUseCase(productoRepository, movimientoStockRepository, gestorStockService, clock);
     }
 
+    /**
+     * HU-101: Bean del caso de uso para el listado de productos bajo stock mínimo.
+     */
+    @Bean
+    public ConsultarProductosParaReponerUseCase consultarProductosParaReponerUseCase(
+            ProductoRepository productoRepository
+    ) {
+        return new ConsultarProductosParaReponerUseCase(productoRepository);
+    }
+
     // ============================================
-    // Categorías del catálogo
+    // HU-104: Cuenta corriente de clientes
     // ============================================
 
     /**
-     * Bean del caso de uso para crear categoría.
+     * HU-104: Bean del servicio de dominio para cargos a cuenta corriente.
+     * Servicio de dominio puro, usado al cerrar y al corregir pedidos.
      */
     @Bean
-    public CrearCategoriaUseCase crearCategoriaUseCase(CategoriaRepository categoriaRepository) {
-        return new CrearCategoriaUseCase(categoriaRepository);
+    public CuentaCorrienteService cuentaCorrienteService() {
+        return new CuentaCorrienteService();
     }
 
     /**
-     * Bean del caso de uso para editar categoría.
+     * HU-104: Bean del caso de uso para dar de alta un cliente.
      */
     @Bean
-    public EditarCategoriaUseCase editarCategoriaUseCase(CategoriaRepository categoriaRepository) {
-        return new EditarCategoriaUseCase(categoriaRepository);
+    public CrearClienteUseCase crearClienteUseCase(ClienteRepository clienteRepository) {
+        return new CrearClienteUseCase(clienteRepository);
     }
 
     /**
-     * Bean del caso de uso para consultar categorías de un local.
+     * HU-104: Bean del caso de uso para editar un cliente.
      */
     @Bean
-    public ConsultarCategoriasUseCase consultarCategoriasUseCase(CategoriaRepository categoriaRepository) {
-        return new ConsultarCategoriasUseCase(categoriaRepository);
+    public EditarClienteUseCase editarClienteUseCase(
+            ClienteRepository clienteRepository,
+            MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository
+    ) {
+        return new EditarClienteUseCase(clienteRepository, movimientoCuentaCorrienteRepository);
     }
 
     /**
-     * Bean del caso de uso para eliminar categoría.
+     * HU-104: Bean del caso de uso para listar clientes con su deuda.
      */
     @Bean
-    public EliminarCategoriaUseCase eliminarCategoriaUseCase(CategoriaRepository categoriaRepository) {
-        return new EliminarCategoriaUseCase(categoriaRepository);
+    public ConsultarClientesUseCase consultarClientesUseCase(
+            ClienteRepository clienteRepository,
+            MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository
+    ) {
+        return new ConsultarClientesUseCase(clienteRepository, movimientoCuentaCorrienteRepository);
     }
 
     /**
-     * HU-29: Bean del caso de uso para enviar comanda a cocina.
-     * Genera el buffer ESC/POS y marca el pedido como enviado.
+     * HU-104: Bean del caso de uso que carga a la cuenta corriente los pagos
+     * CUENTA_CORRIENTE, usado al cerrar la mesa.
      */
     @Bean
-    public EnviarComandaCocinaUseCase enviarComandaCocinaUseCase(
-        MesaRepository mesaRepository,
-        PedidoRepository pedidoRepository,
-        MeisenProperties meisenProperties
+    public RegistrarCargoCuentaCorrienteUseCase registrarCargoCuentaCorrienteUseCase(
+            MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository,
+            CuentaCorrienteService cuentaCorrienteService
     ) {
-        return new EnviarComandaCocinaUseCase(mesaRepository, pedidoRepository, meisenProperties);
+        return new RegistrarCargoCuentaCorrienteUseCase(movimientoCuentaCorrienteRepository, cuentaCorrienteService);
     }
 
     /**
-     * HU-29: Bean del caso de uso para generar ticket de venta ESC/POS.
-     * Solo lectura — no modifica estado. Reutiliza ConsultarDetallePedidoUseCase.
+     * HU-104: Bean del caso de uso para registrar pagos de deuda.
+     * Los pagos en efectivo también generan un ingreso de caja.
      */
     @Bean
-    public GenerarTicketVentaUseCase generarTicketVentaUseCase(
-        ConsultarDetallePedidoUseCase consultarDetallePedidoUseCase,
-        MeisenProperties meisenProperties
+    public RegistrarPagoClienteUseCase registrarPagoClienteUseCase(
+            ClienteRepository clienteRepository,
+            MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository,
+            MovimientoCajaRepository movimientoCajaRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            Clock clock
     ) {
-        return new GenerarTicketVentaUseCase(consultarDetallePedidoUseCase, meisenProperties);
+        return new RegistrarPagoClienteUseCase(clienteRepository, movimientoCuentaCorrienteRepository,
+                movimientoCajaRepository, turnoCajaRepository, clock);
+    }
+
+    /**
+     * HU-104: Bean del caso de uso para el resumen mensual de cuenta corriente.
+     */
+    @Bean
+    public ConsultarResumenCuentaClienteUseCase consultarResumenCuentaClienteUseCase(
+            ClienteRepository clienteRepository,
+            MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository
+    ) {
+        return new ConsultarResumenCuentaClienteUseCase(clienteRepository, movimientoCuentaCorrienteRepository);
     }
 
     // ============================================
-    // Analytics — Reporte de ventas por producto
+    // HU-105: Programa de puntos de clientes
     // ============================================
 
     /**
-     * Bean del caso de uso para obtener el reporte de ventas por producto.
-     * Operación de solo lectura que consulta el puerto de analytics.
-     *
-     * @param analyticsRepository implementación del puerto de analytics (AnalyticsRepositoryAdapter)
-     * @return instancia del caso de uso lista para usar
+     * HU-105: Bean del servicio de dominio de puntos (acumulación, canje y saldo FIFO).
      */
     @Bean
-    public ObtenerReporteVentasUseCase obtenerReporteVentasUseCase(
-            AnalyticsRepositoryPort analyticsRepository
+    public ProgramaPuntosService programaPuntosService() {
+        return new ProgramaPuntosService();
+    }
+
+    /**
+     * HU-105: Bean del caso de uso para consultar la configuración del programa.
+     */
+    @Bean
+    public ConsultarProgramaPuntosUseCase consultarProgramaPuntosUseCase(
+            ProgramaPuntosRepository programaPuntosRepository
     ) {
-        return new ObtenerReporteVentasUseCase(analyticsRepository);
+        return new ConsultarProgramaPuntosUseCase(programaPuntosRepository);
+    }
+
+    /**
+     * HU-105: Bean del caso de uso para activar o reconfigurar el programa.
+     */
+    @Bean
+    public GuardarProgramaPuntosUseCase guardarProgramaPuntosUseCase(
+            ProgramaPuntosRepository programaPuntosRepository
+    ) {
+        return new GuardarProgramaPuntosUseCase(programaPuntosRepository);
+    }
+
+    /**
+     * HU-105: Bean del caso de uso para el saldo de puntos de un cliente.
+     */
+    @Bean
+    public ConsultarPuntosClienteUseCase consultarPuntosClienteUseCase(
+            ClienteRepository clienteRepository,
+            ProgramaPuntosRepository programaPuntosRepository,
+            MovimientoPuntosRepository movimientoPuntosRepository,
+            ProgramaPuntosService programaPuntosService,
+            Clock clock
+    ) {
+        return new ConsultarPuntosClienteUseCase(clienteRepository, programaPuntosRepository,
+                movimientoPuntosRepository, programaPuntosService, clock);
+    }
+
+    /**
+     * HU-105: Bean del caso de uso para el reporte de puntos emitidos/canjeados/vencidos.
+     */
+    @Bean
+    public ConsultarReportePuntosUseCase consultarReportePuntosUseCase(
+            ProgramaPuntosRepository programaPuntosRepository,
+            MovimientoPuntosRepository movimientoPuntosRepository,
+            ProgramaPuntosService programaPuntosService,
+            Clock clock
+    ) {
+        return new ConsultarReportePuntosUseCase(programaPuntosRepository, movimientoPuntosRepository,
+                programaPuntosService, clock);
+    }
+
+    // ============================================
+    // HU-106: Turnos de caja
+    // ============================================
+
+    /**
+     * HU-106: Bean del caso de uso para abrir un turno dentro de la jornada.
+     */
+    @Bean
+    public AbrirTurnoUseCase abrirTurnoUseCase(
+            JornadaCajaRepository jornadaCajaRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            Clock clock
+    ) {
+        return new AbrirTurnoUseCase(jornadaCajaRepository, turnoCajaRepository, clock);
+    }
+
+    /**
+     * HU-106: Bean del caso de uso para cerrar el turno con su arqueo.
+     */
+    @Bean
+    public CerrarTurnoUseCase cerrarTurnoUseCase(
+            TurnoCajaRepository turnoCajaRepository,
+            PedidoRepository pedidoRepository,
+            MovimientoCajaRepository movimientoCajaRepository,
+            MozoRepository mozoRepository,
+            DistribuirPropinasUseCase distribuirPropinasUseCase,
+            Clock clock
+    ) {
+        return new CerrarTurnoUseCase(turnoCajaRepository, pedidoRepository, movimientoCajaRepository,
+            mozoRepository, distribuirPropinasUseCase, clock);
+    }
+
+    /**
+     * HU-106: Bean del caso de uso para listar turnos y consolidar la jornada.
+     */
+    @Bean
+    public ConsultarTurnosJornadaUseCase consultarTurnosJornadaUseCase(
+            JornadaCajaRepository jornadaCajaRepository,
+            TurnoCajaRepository turnoCajaRepository
+    ) {
+        return new ConsultarTurnosJornadaUseCase(jornadaCajaRepository, turnoCajaRepository);
+    }
+
+    // ============================================
+    // HU-107: Multi-sucursal y reporte consolidado
+    // ============================================
+
+    /**
+     * HU-107: Bean del caso de uso que expone las ventas cerradas a la capa de sync.
+     */
+    @Bean
+    public ExportarVentasCerradasUseCase exportarVentasCerradasUseCase(
+            PedidoRepository pedidoRepository,
+            IdentidadSucursalProvider identidadSucursalProvider,
+            Clock clock
+    ) {
+        return new ExportarVentasCerradasUseCase(pedidoRepository, identidadSucursalProvider, clock);
+    }
+
+    /**
+     * HU-107: Bean del caso de uso que recibe en el consolidado los lotes de otras sucursales.
+     */
+    @Bean
+    public RecibirVentasSucursalUseCase recibirVentasSucursalUseCase(
+            VentaSucursalRepository ventaSucursalRepository,
+            IdentidadSucursalProvider identidadSucursalProvider,
+            Clock clock
+    ) {
+        return new RecibirVentasSucursalUseCase(ventaSucursalRepository, identidadSucursalProvider, clock);
+    }
+
+    /**
+     * HU-107: Bean del caso de uso para el reporte comparativo por sucursal.
+     */
+    @Bean
+    public ConsultarReporteSucursalesUseCase consultarReporteSucursalesUseCase(
+            PedidoRepository pedidoRepository,
+            VentaSucursalRepository ventaSucursalRepository,
+            IdentidadSucursalProvider identidadSucursalProvider
+    ) {
+        return new ConsultarReporteSucursalesUseCase(pedidoRepository, ventaSucursalRepository, identidadSucursalProvider);
+    }
+
+    // ============================================
+    // HU-108: Listas de precios por canal
+    // ============================================
+
+    /**
+     * HU-108: Bean del caso de uso para consultar las listas de precios de cada canal.
+     */
+    @Bean
+    public ConsultarListasPreciosUseCase consultarListasPreciosUseCase(
+            ListaPreciosRepository listaPreciosRepository,
+            ProductoRepository productoRepository
+    ) {
+        return new ConsultarListasPreciosUseCase(listaPreciosRepository, productoRepository);
+    }
+
+    /**
+     * HU-108: Bean del caso de uso para crear o reconfigurar la lista de un canal.
+     */
+    @Bean
+    public GuardarListaPreciosUseCase guardarListaPreciosUseCase(
+            ListaPreciosRepository listaPreciosRepository,
+            ProductoRepository productoRepository
+    ) {
+        return new GuardarListaPreciosUseCase(listaPreciosRepository, productoRepository);
+    }
+
+    // ============================================
+    // HU-111: Mozos y reporte de desempeño
+    // ============================================
+
+    /**
+     * HU-111: Bean del caso de uso para el ABM de mozos.
+     */
+    @Bean
+    public GestionarMozosUseCase gestionarMozosUseCase(MozoRepository mozoRepository) {
+        return new GestionarMozosUseCase(mozoRepository);
+    }
+
+    /**
+     * HU-111: Bean del caso de uso para el reporte de desempeño por mozo.
+     */
+    @Bean
+    public ConsultarReporteMozosUseCase consultarReporteMozosUseCase(
+            PedidoRepository pedidoRepository,
+            MozoRepository mozoRepository,
+            JornadaComercialRepository jornadaComercialRepository
+    ) {
+        return new ConsultarReporteMozosUseCase(pedidoRepository, mozoRepository, jornadaComercialRepository);
+    }
+
+    // ============================================
+    // HU-113: Alerta de mesas olvidadas
+    // ============================================
+
+    /**
+     * HU-113: Bean del caso de uso que lista las mesas ocupadas sin atención.
+     */
+    @Bean
+    public ConsultarMesasOlvidadasUseCase consultarMesasOlvidadasUseCase(
+            AlertaMesasOlvidadasRepository alertaMesasOlvidadasRepository,
+            PedidoRepository pedidoRepository,
+            MesaRepository mesaRepository,
+            Clock clock
+    ) {
+        return new ConsultarMesasOlvidadasUseCase(alertaMesasOlvidadasRepository, pedidoRepository,
+                mesaRepository, clock);
+    }
+
+    /**
+     * HU-113: Bean del caso de uso para configurar la alerta.
+     */
+    @Bean
+    public GuardarAlertaMesasOlvidadasUseCase guardarAlertaMesasOlvidadasUseCase(
+            AlertaMesasOlvidadasRepository alertaMesasOlvidadasRepository
+    ) {
+        return new GuardarAlertaMesasOlvidadasUseCase(alertaMesasOlvidadasRepository);
+    }
+
+    /**
+     * HU-113: Bean del caso de uso que registra el pedido de cuenta como actividad.
+     */
+    @Bean
+    public RegistrarPedidoCuentaUseCase registrarPedidoCuentaUseCase(
+            PedidoRepository pedidoRepository,
+            Clock clock
+    ) {
+        return new RegistrarPedidoCuentaUseCase(pedidoRepository, clock);
+    }
+
+    // ============================================
+    // HU-114: Tablero de ventas en vivo
+    // ============================================
+
+    /**
+     * HU-114: Bean del caso de uso que arma el tablero de la jornada.
+     */
+    @Bean
+    public ConsultarDashboardVentasUseCase consultarDashboardVentasUseCase(
+            PedidoRepository pedidoRepository,
+            MesaRepository mesaRepository,
+            AnalyticsRepositoryPort analyticsRepository,
+            JornadaComercialRepository jornadaComercialRepository,
+            Clock clock
+    ) {
+        return new ConsultarDashboardVentasUseCase(pedidoRepository, mesaRepository, analyticsRepository,
+            jornadaComercialRepository, clock);
+    }
+
+    // ============================================
+    // HU-115: Facturación A/B/C
+    // ============================================
+
+    /**
+     * HU-115: Módulo fiscal de la instalación.
+     * Sin integración configurada, las facturas quedan pendientes de autorización.
+     */
+    @Bean
+    public ModuloFiscalPort moduloFiscalPort() {
+        return new ModuloFiscalDesconectadoAdapter();
+    }
+
+    /**
+     * HU-115: Bean del caso de uso que valida el CUIT del cliente contra el padrón.
+     */
+    @Bean
+    public ConsultarContribuyenteUseCase consultarContribuyenteUseCase(
+            ModuloFiscalPort moduloFiscalPort,
+            MeisenProperties meisenProperties
+    ) {
+        return new ConsultarContribuyenteUseCase(moduloFiscalPort, meisenProperties);
+    }
+
+    /**
+     * HU-115: Bean del caso de uso para emitir y autorizar facturas.
+     */
+    @Bean
+    public EmitirFacturaUseCase emitirFacturaUseCase(
+            PedidoRepository pedidoRepository,
+            FacturaRepository facturaRepository,
+            ModuloFiscalPort moduloFiscalPort,
+            MeisenProperties meisenProperties,
+            Clock clock
+    ) {
+        return new EmitirFacturaUseCase(pedidoRepository, facturaRepository, moduloFiscalPort,
+                meisenProperties, clock);
+    }
+
+    /**
+     * HU-115: Bean del caso de uso para imprimir y reimprimir facturas.
+     */
+    @Bean
+    public ImprimirFacturaUseCase imprimirFacturaUseCase(
+            FacturaRepository facturaRepository,
+            PedidoRepository pedidoRepository,
+            MeisenProperties meisenProperties
+    ) {
+        return new ImprimirFacturaUseCase(facturaRepository, pedidoRepository, meisenProperties);
+    }
+
+    // ============================================
+    // HU-116: Libro IVA ventas
+    // ============================================
+
+    /**
+     * HU-116: Bean del caso de uso que exporta el libro IVA ventas del mes.
+     */
+    @Bean
+    public ExportarLibroIvaVentasUseCase exportarLibroIvaVentasUseCase(FacturaRepository facturaRepository) {
+        return new ExportarLibroIvaVentasUseCase(facturaRepository);
+    }
+
+    // ============================================
+    // HU-117: Compras a proveedores y gastos
+    // ============================================
+
+    /**
+     * HU-117: Bean del caso de uso que registra compras y gastos
+     * (ingreso de stock, egreso de caja y comprobante adjunto).
+     */
+    @Bean
+    public GestionarGastosUseCase gestionarGastosUseCase(
+            GastoRepository gastoRepository,
+            ProductoRepository productoRepository,
+            MovimientoStockRepository movimientoStockRepository,
+            MovimientoCajaRepository movimientoCajaRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            ProveedorRepository proveedorRepository,
+            FacturaProveedorRepository facturaProveedorRepository,
+            GestorStockService gestorStockService,
+            Clock clock
+    ) {
+        return new GestionarGastosUseCase(gastoRepository, productoRepository, movimientoStockRepository,
+                movimientoCajaRepository, turnoCajaRepository, proveedorRepository, facturaProveedorRepository,
+                gestorStockService, clock);
+    }
+
+    /**
+     * HU-117: Bean del caso de uso del reporte mensual de gastos contra ventas.
+     */
+    @Bean
+    public ConsultarReporteGastosUseCase consultarReporteGastosUseCase(
+            GastoRepository gastoRepository,
+            JornadaCajaRepository jornadaCajaRepository,
+            MovimientoCajaRepository movimientoCajaRepository,
+            FacturaProveedorRepository facturaProveedorRepository
+    ) {
+        return new ConsultarReporteGastosUseCase(gastoRepository, jornadaCajaRepository, movimientoCajaRepository,
+                facturaProveedorRepository);
+    }
+
+    // ============================================
+    // HU-118: Costeo de recetas y rentabilidad por producto
+    // ============================================
+
+    /**
+     * HU-118: Bean del caso de uso del ABM de insumos
+     * (avisa qué productos quedan a pérdida al cambiar un costo).
+     */
+    @Bean
+    public GestionarInsumosUseCase gestionarInsumosUseCase(
+            InsumoRepository insumoRepository,
+            RecetaRepository recetaRepository,
+            ProductoRepository productoRepository
+    ) {
+        return new GestionarInsumosUseCase(insumoRepository, recetaRepository, productoRepository);
+    }
+
+    /**
+     * HU-118: Bean del caso de uso para cargar la receta de un producto.
+     */
+    @Bean
+    public GestionarRecetasUseCase gestionarRecetasUseCase(
+            RecetaRepository recetaRepository,
+            InsumoRepository insumoRepository,
+            ProductoRepository productoRepository
+    ) {
+        return new GestionarRecetasUseCase(recetaRepository, insumoRepository, productoRepository);
+    }
+
+    /**
+     * HU-118: Bean del caso de uso del margen teórico por producto.
+     */
+    @Bean
+    public ConsultarRentabilidadProductosUseCase consultarRentabilidadProductosUseCase(
+            ProductoRepository productoRepository,
+            RecetaRepository recetaRepository,
+            InsumoRepository insumoRepository
+    ) {
+        return new ConsultarRentabilidadProductosUseCase(productoRepository, recetaRepository, insumoRepository);
+    }
+
+    // ============================================
+    // HU-119: Tiempos de cocina y alertas de demora
+    // ============================================
+
+    /**
+     * HU-119: Bean del caso de uso de la pantalla de cocina
+     * (comandas en preparación y marca de lista).
+     */
+    @Bean
+    public GestionarComandasCocinaUseCase gestionarComandasCocinaUseCase(
+            ComandaCocinaRepository comandaCocinaRepository,
+            Clock clock
+    ) {
+        return new GestionarComandasCocinaUseCase(comandaCocinaRepository, clock);
+    }
+
+    /**
+     * HU-119: Bean del caso de uso del reporte de tiempos por franja horaria.
+     */
+    @Bean
+    public ConsultarReporteTiemposCocinaUseCase consultarReporteTiemposCocinaUseCase(
+            ComandaCocinaRepository comandaCocinaRepository,
+            JornadaComercialRepository jornadaComercialRepository
+    ) {
+        return new ConsultarReporteTiemposCocinaUseCase(comandaCocinaRepository, jornadaComercialRepository);
+    }
+
+    // ============================================
+    // HU-121: Devoluciones y reintegros post-cierre
+    // ============================================
+
+    /**
+     * HU-121: Bean del caso de uso de devoluciones sobre pedidos cerrados,
+     * autorizadas con el PIN de un encargado.
+     */
+    @Bean
+    public GestionarDevolucionesUseCase gestionarDevolucionesUseCase(
+            PedidoRepository pedidoRepository,
+            MozoRepository mozoRepository,
+            MovimientoCajaRepository movimientoCajaRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            Clock clock
+    ) {
+        return new GestionarDevolucionesUseCase(pedidoRepository, mozoRepository, movimientoCajaRepository,
+            turnoCajaRepository, clock);
+    }
+
+    // ============================================
+    // HU-124: Fichaje de personal (control horario)
+    // ============================================
+
+    /**
+     * HU-124: Bean del caso de uso de la terminal de fichaje y las
+     * correcciones autorizadas por el dueño.
+     */
+    @Bean
+    public GestionarFichajesUseCase gestionarFichajesUseCase(
+            FichajeRepository fichajeRepository,
+            MozoRepository mozoRepository,
+            Clock clock
+    ) {
+        return new GestionarFichajesUseCase(fichajeRepository, mozoRepository, clock);
+    }
+
+    /**
+     * HU-124: Bean del reporte mensual de horas por empleado.
+     */
+    @Bean
+    public ConsultarReporteHorasUseCase consultarReporteHorasUseCase(
+            FichajeRepository fichajeRepository,
+            MozoRepository mozoRepository,
+            Clock clock
+    ) {
+        return new ConsultarReporteHorasUseCase(fichajeRepository, mozoRepository, clock);
+    }
+
+    // ============================================
+    // HU-125: Canal en tiempo real y productos 86
+    // ============================================
+
+    /**
+     * HU-125: Canal SSE compartido. Se expone con su tipo concreto porque
+     * EventoController lo usa para registrar las terminales; los casos de
+     * uso lo reciben como NotificadorTiempoRealPort.
+     */
+    @Bean
+    public SseNotificadorTiempoRealAdapter notificadorTiempoReal() {
+        return new SseNotificadorTiempoRealAdapter();
+    }
+
+    /**
+     * HU-125: Bean del switch "sin stock" que avisa a todas las terminales.
+     */
+    @Bean
+    public CambiarDisponibilidadProductoUseCase cambiarDisponibilidadProductoUseCase(
+            ProductoRepository productoRepository,
+            SseNotificadorTiempoRealAdapter notificadorTiempoReal
+    ) {
+        return new CambiarDisponibilidadProductoUseCase(productoRepository, notificadorTiempoReal);
+    }
+
+    // ============================================
+    // HU-126: Autopedido por QR en la mesa
+    // ============================================
+
+    /**
+     * HU-126: Bean de la carta que ve el cliente al escanear el QR.
+     */
+    @Bean
+    public ConsultarCartaAutopedidoUseCase consultarCartaAutopedidoUseCase(
+            MesaRepository mesaRepository,
+            ProductoRepository productoRepository,
+            CategoriaRepository categoriaRepository,
+            ListaPreciosRepository listaPreciosRepository,
+            MeisenProperties properties,
+            Clock clock
+    ) {
+        return new ConsultarCartaAutopedidoUseCase(mesaRepository, productoRepository, categoriaRepository,
+            listaPreciosRepository, properties, clock);
+    }
+
+    /**
+     * HU-126: Bean de los pedidos del QR. Reutiliza la apertura de mesa y la
+     * carga de productos para que lo confirmado siga las reglas del POS.
+     */
+    @Bean
+    public GestionarAutopedidoUseCase gestionarAutopedidoUseCase(
+            SolicitudAutopedidoRepository solicitudAutopedidoRepository,
+            MesaRepository mesaRepository,
+            PedidoRepository pedidoRepository,
+            ProductoRepository productoRepository,
+            CategoriaRepository categoriaRepository,
+            ListaPreciosRepository listaPreciosRepository,
+            MozoRepository mozoRepository,
+            AbrirMesaUseCase abrirMesaUseCase,
+            AgregarProductoUseCase agregarProductoUseCase,
+            SseNotificadorTiempoRealAdapter notificadorTiempoReal,
+            Clock clock
+    ) {
+        return new GestionarAutopedidoUseCase(solicitudAutopedidoRepository, mesaRepository, pedidoRepository,
+            productoRepository, categoriaRepository, listaPreciosRepository, mozoRepository, abrirMesaUseCase, agregarProductoUseCase,
+            notificadorTiempoReal, clock);
+    }
+
+    // ============================================
+    // HU-127: Carta digital
+    // ============================================
+
+    /**
+     * HU-127: Bean de la carta digital, armada en cada consulta desde el catálogo.
+     */
+    @Bean
+    public ConsultarCartaDigitalUseCase consultarCartaDigitalUseCase(
+            MesaRepository mesaRepository,
+            ProductoRepository productoRepository,
+            CategoriaRepository categoriaRepository,
+            ListaPreciosRepository listaPreciosRepository,
+            MeisenProperties properties,
+            Clock clock
+    ) {
+        return new ConsultarCartaDigitalUseCase(mesaRepository, productoRepository, categoriaRepository,
+            listaPreciosRepository, properties, clock);
+    }
+
+    /**
+     * HU-127: Bean de las fotos de productos que muestra la carta.
+     */
+    @Bean
+    public GestionarFotoProductoUseCase gestionarFotoProductoUseCase(ProductoRepository productoRepository, Clock clock) {
+        return new GestionarFotoProductoUseCase(productoRepository, clock);
+    }
+
+    // ============================================
+    // HU-128: Links de pago para delivery
+    // ============================================
+
+    /**
+     * HU-128: Pasarela de los links de pago.
+     * Sin access token, Mercado Pago se informa como no configurado.
+     */
+    @Bean
+    public PasarelaPagoPort pasarelaPagoPort(
+            @Value("${app.pagos.mercadopago.access-token:}") String accessToken,
+            @Value("${app.pagos.url-notificaciones:}") String urlNotificaciones,
+            ObjectMapper objectMapper
+    ) {
+        return new MercadoPagoAdapter(accessToken, urlNotificaciones, objectMapper);
+    }
+
+    /**
+     * HU-128: Bean de los links de pago. Reutiliza el envío a cocina para
+     * que el pedido pagado salga con la comanda de siempre.
+     */
+    @Bean
+    public GestionarLinksPagoUseCase gestionarLinksPagoUseCase(
+            LinkPagoRepository linkPagoRepository,
+            MesaRepository mesaRepository,
+            PedidoRepository pedidoRepository,
+            PasarelaPagoPort pasarelaPagoPort,
+            EnviarComandaCocinaUseCase enviarComandaCocinaUseCase,
+            SseNotificadorTiempoRealAdapter notificadorTiempoReal,
+            MeisenProperties properties,
+            Clock clock
+    ) {
+        return new GestionarLinksPagoUseCase(linkPagoRepository, mesaRepository, pedidoRepository, pasarelaPagoPort,
+            enviarComandaCocinaUseCase, notificadorTiempoReal, properties, clock);
+    }
+
+    // ============================================
+    // HU-129: Registro de faltantes y sobrantes de caja
+    // ============================================
+
+    /**
+     * HU-129: Bean del histórico de diferencias de arqueo por cajero.
+     */
+    @Bean
+    public ConsultarDiferenciasCajaUseCase consultarDiferenciasCajaUseCase(
+            TurnoCajaRepository turnoCajaRepository,
+            MozoRepository mozoRepository,
+            JornadaComercialRepository jornadaComercialRepository
+    ) {
+        return new ConsultarDiferenciasCajaUseCase(turnoCajaRepository, mozoRepository, jornadaComercialRepository);
+    }
+
+    // ============================================
+    // HU-130: Categorías de egresos y reporte mensual de gastos
+    // ============================================
+
+    /**
+     * HU-130: Bean del ABM de categorías de egresos de caja.
+     */
+    @Bean
+    public GestionarCategoriasEgresoUseCase gestionarCategoriasEgresoUseCase(
+            CategoriaEgresoRepository categoriaEgresoRepository
+    ) {
+        return new GestionarCategoriasEgresoUseCase(categoriaEgresoRepository);
+    }
+
+    /**
+     * HU-130: Bean del reporte mensual de egresos por categoría contra el mes anterior.
+     */
+    @Bean
+    public ConsultarReporteEgresosUseCase consultarReporteEgresosUseCase(
+            MovimientoCajaRepository movimientoCajaRepository,
+            CategoriaEgresoRepository categoriaEgresoRepository
+    ) {
+        return new ConsultarReporteEgresosUseCase(movimientoCajaRepository, categoriaEgresoRepository);
+    }
+
+    // ============================================
+    // HU-131: Cuenta corriente de proveedores
+    // ============================================
+
+    /**
+     * HU-131: Bean del ABM de proveedores.
+     */
+    @Bean
+    public GestionarProveedoresUseCase gestionarProveedoresUseCase(ProveedorRepository proveedorRepository) {
+        return new GestionarProveedoresUseCase(proveedorRepository);
+    }
+
+    /**
+     * HU-131: Bean de la cuenta corriente de proveedores
+     * (saldos, pagos parciales y vencimientos).
+     */
+    @Bean
+    public GestionarCuentaProveedoresUseCase gestionarCuentaProveedoresUseCase(
+            FacturaProveedorRepository facturaProveedorRepository,
+            ProveedorRepository proveedorRepository,
+            MovimientoCajaRepository movimientoCajaRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            Clock clock
+    ) {
+        return new GestionarCuentaProveedoresUseCase(facturaProveedorRepository, proveedorRepository,
+                movimientoCajaRepository, turnoCajaRepository, clock);
+    }
+
+    // ============================================
+    // HU-132: Límites de consumo interno del personal
+    // ============================================
+
+    /**
+     * HU-132: Bean del servicio de dominio que imputa los pagos A_CUENTA
+     * al empleado, usado al cerrar y al corregir pedidos.
+     */
+    @Bean
+    public ConsumoPersonalService consumoPersonalService() {
+        return new ConsumoPersonalService();
+    }
+
+    /**
+     * HU-132: Bean del caso de uso que imputa el consumo interno al empleado,
+     * usado al cerrar la mesa.
+     */
+    @Bean
+    public ImputarConsumoPersonalUseCase imputarConsumoPersonalUseCase(
+            MozoRepository mozoRepository,
+            ConsumoPersonalRepository consumoPersonalRepository,
+            JornadaComercialRepository jornadaComercialRepository,
+            ConsumoPersonalService consumoPersonalService
+    ) {
+        return new ImputarConsumoPersonalUseCase(mozoRepository, consumoPersonalRepository,
+            jornadaComercialRepository, consumoPersonalService);
+    }
+
+    /**
+     * HU-132: Bean del resumen mensual de consumo interno por empleado.
+     */
+    @Bean
+    public ConsultarReporteConsumoPersonalUseCase consultarReporteConsumoPersonalUseCase(
+            ConsumoPersonalRepository consumoPersonalRepository,
+            MozoRepository mozoRepository,
+            JornadaComercialRepository jornadaComercialRepository
+    ) {
+        return new ConsultarReporteConsumoPersonalUseCase(consumoPersonalRepository, mozoRepository,
+            jornadaComercialRepository);
+    }
+
+    // ============================================
+    // HU-133: Inventario físico con reporte de diferencias
+    // ============================================
+
+    /**
+     * HU-133: Bean del servicio de dominio que mueve el stock teórico de los
+     * insumos (ventas, reaperturas y tomas de inventario).
+     */
+    @Bean
+    public InventarioService inventarioService() {
+        return new InventarioService();
+    }
+
+    /**
+     * HU-133: Bean del caso de uso de la toma de inventario físico.
+     */
+    @Bean
+    public GestionarTomaInventarioUseCase gestionarTomaInventarioUseCase(
+            TomaInventarioRepository tomaInventarioRepository,
+            InsumoRepository insumoRepository,
+            MovimientoInsumoRepository movimientoInsumoRepository,
+            InventarioService inventarioService,
+            Clock clock
+    ) {
+        return new GestionarTomaInventarioUseCase(tomaInventarioRepository, insumoRepository,
+                movimientoInsumoRepository, inventarioService, clock);
+    }
+
+    // ============================================
+    // HU-134: Órdenes de compra automáticas por stock mínimo
+    // ============================================
+
+    /**
+     * HU-134: Bean del caso de uso de stock mínimo, proveedor habitual y
+     * órdenes de compra sugeridas.
+     */
+    @Bean
+    public GestionarReposicionUseCase gestionarReposicionUseCase(
+            InsumoRepository insumoRepository,
+            ProveedorRepository proveedorRepository,
+            InventarioService inventarioService
+    ) {
+        return new GestionarReposicionUseCase(insumoRepository, proveedorRepository, inventarioService);
+    }
+
+    // ============================================
+    // HU-135: Registro de mermas y desperdicio
+    // ============================================
+
+    /**
+     * HU-135: Bean del caso de uso de mermas (registro y reporte mensual valorizado).
+     */
+    @Bean
+    public GestionarMermasUseCase gestionarMermasUseCase(
+            MermaRepository mermaRepository,
+            InsumoRepository insumoRepository,
+            MovimientoInsumoRepository movimientoInsumoRepository,
+            ProductoRepository productoRepository,
+            RecetaRepository recetaRepository,
+            InventarioService inventarioService,
+            Clock clock
+    ) {
+        return new GestionarMermasUseCase(mermaRepository, insumoRepository, movimientoInsumoRepository,
+            productoRepository, recetaRepository, inventarioService, clock);
+    }
+
+    // ============================================
+    // HU-137: Urgencia en pedidos
+    // ============================================
+
+    /**
+     * HU-137: Bean del caso de uso que marca pedidos e ítems urgentes para cocina.
+     */
+    @Bean
+    public MarcarUrgenciaPedidoUseCase marcarUrgenciaPedidoUseCase(
+            PedidoRepository pedidoRepository,
+            ComandaCocinaRepository comandaCocinaRepository
+    ) {
+        return new MarcarUrgenciaPedidoUseCase(pedidoRepository, comandaCocinaRepository);
+    }
+
+    // ============================================
+    // HU-138: Espera estimada para take away
+    // ============================================
+
+    /**
+     * HU-138: Domain service que estima la espera con la carga actual de cocina.
+     */
+    @Bean
+    public EsperaCocinaService esperaCocinaService() {
+        return new EsperaCocinaService();
+    }
+
+    /**
+     * HU-138: Bean del caso de uso que estima la espera en cocina de un pedido nuevo.
+     */
+    @Bean
+    public EstimarEsperaCocinaUseCase estimarEsperaCocinaUseCase(
+            ComandaCocinaRepository comandaCocinaRepository,
+            EsperaCocinaService esperaCocinaService,
+            Clock clock
+    ) {
+        return new EstimarEsperaCocinaUseCase(comandaCocinaRepository, esperaCocinaService, clock);
+    }
+
+    // ============================================
+    // HU-139: Zonas de delivery con costo de envío
+    // ============================================
+
+    /**
+     * HU-139: Bean del caso de uso para el ABM de zonas de reparto y el reporte de envíos.
+     */
+    @Bean
+    public GestionarZonasDeliveryUseCase gestionarZonasDeliveryUseCase(
+            ZonaDeliveryRepository zonaDeliveryRepository,
+            AnalyticsRepositoryPort analyticsRepository,
+            JornadaComercialRepository jornadaComercialRepository
+    ) {
+        return new GestionarZonasDeliveryUseCase(zonaDeliveryRepository, analyticsRepository, jornadaComercialRepository);
+    }
+
+    /**
+     * HU-139: Bean del caso de uso que carga la entrega y el costo de envío de un delivery.
+     */
+    @Bean
+    public AsignarEntregaDeliveryUseCase asignarEntregaDeliveryUseCase(
+            PedidoRepository pedidoRepository,
+            ZonaDeliveryRepository zonaDeliveryRepository
+    ) {
+        return new AsignarEntregaDeliveryUseCase(pedidoRepository, zonaDeliveryRepository);
+    }
+
+    // ============================================
+    // HU-140: Asignación y seguimiento de repartidores
+    // ============================================
+
+    /**
+     * HU-140: Bean del caso de uso que asigna el repartidor y registra salida y regreso.
+     */
+    @Bean
+    public GestionarRepartosUseCase gestionarRepartosUseCase(
+            PedidoRepository pedidoRepository,
+            MozoRepository mozoRepository,
+            Clock clock
+    ) {
+        return new GestionarRepartosUseCase(pedidoRepository, mozoRepository, clock);
+    }
+
+    /**
+     * HU-140: Bean del caso de uso para la rendición de efectivo de los repartidores por turno.
+     */
+    @Bean
+    public RendirEfectivoRepartidorUseCase rendirEfectivoRepartidorUseCase(
+            PedidoRepository pedidoRepository,
+            MozoRepository mozoRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            RendicionRepartidorRepository rendicionRepartidorRepository,
+            Clock clock
+    ) {
+        return new RendirEfectivoRepartidorUseCase(pedidoRepository, mozoRepository, turnoCajaRepository,
+            rendicionRepartidorRepository, clock);
+    }
+
+    /**
+     * HU-140: Bean del caso de uso para el reporte de entregas y tiempos por repartidor.
+     */
+    @Bean
+    public ConsultarReporteRepartidoresUseCase consultarReporteRepartidoresUseCase(
+            PedidoRepository pedidoRepository,
+            MozoRepository mozoRepository,
+            JornadaComercialRepository jornadaComercialRepository
+    ) {
+        return new ConsultarReporteRepartidoresUseCase(pedidoRepository, mozoRepository, jornadaComercialRepository);
+    }
+
+    // ============================================
+    // HU-141: Pozo de propinas y distribución
+    // ============================================
+
+    /**
+     * HU-141: Bean del caso de uso para consultar la regla de reparto de propinas.
+     */
+    @Bean
+    public ConsultarReglaPropinasUseCase consultarReglaPropinasUseCase(ReglaPropinasRepository reglaPropinasRepository) {
+        return new ConsultarReglaPropinasUseCase(reglaPropinasRepository);
+    }
+
+    /**
+     * HU-141: Bean del caso de uso para configurar la regla de reparto de propinas.
+     */
+    @Bean
+    public GuardarReglaPropinasUseCase guardarReglaPropinasUseCase(ReglaPropinasRepository reglaPropinasRepository) {
+        return new GuardarReglaPropinasUseCase(reglaPropinasRepository);
+    }
+
+    /**
+     * HU-141: Bean del caso de uso que reparte el pozo al cerrar el turno y lista los repartos.
+     */
+    @Bean
+    public DistribuirPropinasUseCase distribuirPropinasUseCase(
+            PedidoRepository pedidoRepository,
+            FichajeRepository fichajeRepository,
+            MozoRepository mozoRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            ReglaPropinasRepository reglaPropinasRepository,
+            DistribucionPropinasRepository distribucionPropinasRepository
+    ) {
+        return new DistribuirPropinasUseCase(pedidoRepository, fichajeRepository, mozoRepository,
+            turnoCajaRepository, reglaPropinasRepository, distribucionPropinasRepository);
+    }
+
+    /**
+     * HU-141: Bean del caso de uso para imprimir el comprobante de reparto de propinas.
+     */
+    @Bean
+    public ImprimirComprobantePropinasUseCase imprimirComprobantePropinasUseCase(
+            DistribucionPropinasRepository distribucionPropinasRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            MozoRepository mozoRepository,
+            MeisenProperties properties
+    ) {
+        return new ImprimirComprobantePropinasUseCase(distribucionPropinasRepository, turnoCajaRepository,
+            mozoRepository, properties);
+    }
+
+    // ============================================
+    // HU-142: Señas de reservas aplicadas a la cuenta
+    // ============================================
+
+    /**
+     * HU-142: Bean del caso de uso para tomar reservas, cobrar su seña y sentarlas en la mesa.
+     */
+    @Bean
+    public GestionarReservasUseCase gestionarReservasUseCase(
+            ReservaRepository reservaRepository,
+            MesaRepository mesaRepository,
+            PedidoRepository pedidoRepository,
+            MovimientoCajaRepository movimientoCajaRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            Clock clock
+    ) {
+        return new GestionarReservasUseCase(reservaRepository, mesaRepository, pedidoRepository,
+            movimientoCajaRepository, turnoCajaRepository, clock);
+    }
+
+    // ============================================
+    // HU-143: Recargo configurable por medio de pago
+    // ============================================
+
+    /**
+     * HU-143: Bean del caso de uso para consultar los recargos por medio de pago.
+     */
+    @Bean
+    public ConsultarRecargosMedioPagoUseCase consultarRecargosMedioPagoUseCase(
+            RecargosMedioPagoRepository recargosMedioPagoRepository
+    ) {
+        return new ConsultarRecargosMedioPagoUseCase(recargosMedioPagoRepository);
+    }
+
+    /**
+     * HU-143: Bean del caso de uso para configurar los recargos por medio de pago.
+     */
+    @Bean
+    public GuardarRecargosMedioPagoUseCase guardarRecargosMedioPagoUseCase(
+            RecargosMedioPagoRepository recargosMedioPagoRepository
+    ) {
+        return new GuardarRecargosMedioPagoUseCase(recargosMedioPagoRepository);
+    }
+
+    // ============================================
+    // HU-144: Gift cards y vouchers prepagos
+    // ============================================
+
+    /**
+     * HU-144: Bean del caso de uso para vender, validar y reportar tarjetas de regalo.
+     */
+    @Bean
+    public GestionarTarjetasRegaloUseCase gestionarTarjetasRegaloUseCase(
+            TarjetaRegaloRepository tarjetaRegaloRepository,
+            MovimientoCajaRepository movimientoCajaRepository,
+            TurnoCajaRepository turnoCajaRepository,
+            Clock clock
+    ) {
+        return new GestionarTarjetasRegaloUseCase(tarjetaRegaloRepository, movimientoCajaRepository,
+            turnoCajaRepository, clock);
+    }
+
+    // ============================================
+    // HU-145: Actualización masiva de precios
+    // ============================================
+
+    /**
+     * HU-145: Bean del caso de uso para previsualizar, programar y aplicar aumentos masivos.
+     */
+    @Bean
+    public GestionarAumentosPreciosUseCase gestionarAumentosPreciosUseCase(
+            ProductoRepository productoRepository,
+            CategoriaRepository categoriaRepository,
+            AumentoPreciosRepository aumentoPreciosRepository,
+            CambioPrecioProductoRepository cambioPrecioProductoRepository,
+            Clock clock
+    ) {
+        return new GestionarAumentosPreciosUseCase(productoRepository, categoriaRepository,
+            aumentoPreciosRepository, cambioPrecioProductoRepository, clock);
+    }
+
+    // ============================================
+    // HU-146: Historial de cambios de precios
+    // ============================================
+
+    /**
+     * HU-146: Bean del caso de uso para el historial de precios y la variación mensual del menú.
+     */
+    @Bean
+    public ConsultarHistorialPreciosUseCase consultarHistorialPreciosUseCase(
+            ProductoRepository productoRepository,
+            CambioPrecioProductoRepository cambioPrecioProductoRepository
+    ) {
+        return new ConsultarHistorialPreciosUseCase(productoRepository, cambioPrecioProductoRepository);
+    }
+
+    // ============================================
+    // HU-149: Lista de espera de mesas
+    // ============================================
+
+    /**
+     * HU-149: Bean del caso de uso para anotar clientes en espera y avisarles cuando hay mesa.
+     */
+    @Bean
+    public GestionarListaEsperaUseCase gestionarListaEsperaUseCase(
+            ClienteEnEsperaRepository clienteEnEsperaRepository,
+            MesaRepository mesaRepository,
+            ReservaRepository reservaRepository,
+            MeisenProperties meisenProperties,
+            Clock clock
+    ) {
+        return new GestionarListaEsperaUseCase(clienteEnEsperaRepository, mesaRepository, reservaRepository,
+            meisenProperties, clock);
+    }
+
+    // ============================================
+    // HU-150: Temporizador de ocupación de mesas
+    // ============================================
+
+    /**
+     * HU-150: Bean del caso de uso del reporte de rotación de mesas por franja horaria.
+     */
+    @Bean
+    public ConsultarRotacionMesasUseCase consultarRotacionMesasUseCase(
+            PedidoRepository pedidoRepository,
+            MesaRepository mesaRepository
+    ) {
+        return new ConsultarRotacionMesasUseCase(pedidoRepository, mesaRepository);
+    }
+
+    // ============================================
+    // HU-151: Soporte multi-moneda en cobros
+    // ============================================
+
+    /**
+     * HU-151: Bean del caso de uso de los tipos de cambio del día.
+     */
+    @Bean
+    public GestionarTiposCambioUseCase gestionarTiposCambioUseCase(
+            TiposCambioRepository tiposCambioRepository,
+            JornadaComercialRepository jornadaComercialRepository,
+            Clock clock
+    ) {
+        return new GestionarTiposCambioUseCase(tiposCambioRepository, jornadaComercialRepository, clock);
+    }
+
+    // ============================================
+    // HU-152: Redondeo del efectivo
+    // ============================================
+
+    /**
+     * HU-152: Bean del caso de uso para consultar el redondeo del efectivo.
+     */
+    @Bean
+    public ConsultarRedondeoEfectivoUseCase consultarRedondeoEfectivoUseCase(
+            RedondeoEfectivoRepository redondeoEfectivoRepository
+    ) {
+        return new ConsultarRedondeoEfectivoUseCase(redondeoEfectivoRepository);
+    }
+
+    /**
+     * HU-152: Bean del caso de uso para configurar el redondeo del efectivo.
+     */
+    @Bean
+    public GuardarRedondeoEfectivoUseCase guardarRedondeoEfectivoUseCase(
+            RedondeoEfectivoRepository redondeoEfectivoRepository
+    ) {
+        return new GuardarRedondeoEfectivoUseCase(redondeoEfectivoRepository);
+    }
+
+    // ============================================
+    // HU-153: Límite de jornada comercial
+    // ============================================
+
+    /**
+     * HU-153: Bean del caso de uso para consultar la hora de corte de la jornada.
+     */
+    @Bean
+    public ConsultarJornadaComercialUseCase consultarJornadaComercialUseCase(
+            JornadaComercialRepository jornadaComercialRepository,
+            Clock clock
+    ) {
+        return new ConsultarJornadaComercialUseCase(jornadaComercialRepository, clock);
+    }
+
+    /**
+     * HU-153: Bean del caso de uso para configurar la hora de corte de la jornada.
+     */
+    @Bean
+    public GuardarJornadaComercialUseCase guardarJornadaComercialUseCase(
+            JornadaComercialRepository jornadaComercialRepository,
+            Clock clock
+    ) {
+        return new GuardarJornadaComercialUseCase(jornadaComercialRepository, clock);
+    }
+
+    // ============================================
+    // HU-155: Cortes de luz con apagado ordenado
+    // ============================================
+
+    /**
+     * HU-155: Apagado ordenado del backend a pedido de la app de escritorio.
+     */
+    @Bean
+    public ApagadoAplicacionPort apagadoAplicacionPort(ConfigurableApplicationContext context) {
+        return new SpringApagadoAplicacionAdapter(context);
+    }
+
+    /**
+     * HU-155: Bean del caso de uso que avisa los cortes de luz y apaga el backend.
+     */
+    @Bean
+    public GestionarEnergiaUseCase gestionarEnergiaUseCase(
+            SseNotificadorTiempoRealAdapter notificadorTiempoReal,
+            ApagadoAplicacionPort apagadoAplicacionPort
+    ) {
+        return new GestionarEnergiaUseCase(notificadorTiempoReal, apagadoAplicacionPort);
+    }
+
+    // ============================================
+    // HU-156: Datos de pago para copiar desde el cobro
+    // ============================================
+
+    /**
+     * HU-156: Bean del caso de uso que informa alias y CVU del local para cobrar por transferencia.
+     */
+    @Bean
+    public ConsultarDatosTransferenciaUseCase consultarDatosTransferenciaUseCase(MeisenProperties meisenProperties) {
+        return new ConsultarDatosTransferenciaUseCase(meisenProperties);
+    }
+
+    // ============================================
+    // HU-157: Reportes como imagen
+    // ============================================
+
+    /**
+     * HU-157: Adapter que dibuja un reporte como PNG con FlyingSaucer.
+     */
+    @Bean
+    public ReporteImagenGenerator reporteImagenGenerator() {
+        return new FlyingSaucerReporteImagenAdapter();
+    }
+
+    /**
+     * HU-157: Bean del caso de uso que exporta ventas, caja o productos de un período como imagen.
+     */
+    @Bean
+    public GenerarImagenReporteUseCase generarImagenReporteUseCase(
+            GenerarReporteCajaUseCase generarReporteCajaUseCase,
+            ObtenerReporteVentasUseCase obtenerReporteVentasUseCase,
+            ReporteImagenGenerator reporteImagenGenerator,
+            MeisenProperties meisenProperties,
+            Clock clock
+    ) {
+        return new GenerarImagenReporteUseCase(generarReporteCajaUseCase, obtenerReporteVentasUseCase,
+            reporteImagenGenerator, meisenProperties, clock);
+    }
+
+    // ============================================
+    // HU-158: Estado de las impresoras
+    // ============================================
+
+    /**
+     * HU-158: Bean del caso de uso que avisa a las terminales cuando una impresora se queda sin papel o se desconecta.
+     */
+    @Bean
+    public NotificarEstadoImpresoraUseCase notificarEstadoImpresoraUseCase(
+            SseNotificadorTiempoRealAdapter notificadorTiempoReal
+    ) {
+        return new NotificarEstadoImpresoraUseCase(notificadorTiempoReal);
+    }
+
+    // ============================================
+    // HU-159: Varias cajas en el mismo local
+    // ============================================
+
+    /**
+     * HU-159: Bean del caso de uso de las cajas del local: alta, fondo por jornada,
+     * caja de cada cobro y arqueo por caja al cierre.
+     */
+    @Bean
+    public GestionarCajasUseCase gestionarCajasUseCase(
+            CajaRepository cajaRepository,
+            JornadaCajaRepository jornadaCajaRepository,
+            JornadaComercialRepository jornadaComercialRepository,
+            PedidoRepository pedidoRepository,
+            MovimientoCajaRepository movimientoCajaRepository,
+            CajaContextProvider cajaContextProvider,
+            Clock clock
+    ) {
+        return new GestionarCajasUseCase(cajaRepository, jornadaCajaRepository, jornadaComercialRepository,
+            pedidoRepository, movimientoCajaRepository, cajaContextProvider, clock);
+    }
+
+    // ============================================
+    // HU-160: Pagos a cuenta sobre mesas abiertas
+    // ============================================
+
+    /**
+     * HU-160: Bean del caso de uso que cobra a cuenta sobre una mesa abierta
+     * y deja al cierre solo el saldo pendiente.
+     */
+    @Bean
+    public GestionarPagosACuentaUseCase gestionarPagosACuentaUseCase(
+            PedidoRepository pedidoRepository,
+            RecargosMedioPagoRepository recargosMedioPagoRepository,
+            TiposCambioRepository tiposCambioRepository,
+            RedondeoEfectivoRepository redondeoEfectivoRepository,
+            JornadaComercialRepository jornadaComercialRepository,
+            GestionarCajasUseCase gestionarCajasUseCase,
+            SseNotificadorTiempoRealAdapter notificadorTiempoReal,
+            Clock clock
+    ) {
+        return new GestionarPagosACuentaUseCase(pedidoRepository, recargosMedioPagoRepository,
+            tiposCambioRepository, redondeoEfectivoRepository, jornadaComercialRepository, gestionarCajasUseCase,
+            notificadorTiempoReal, clock);
+    }
+
+    // ============================================
+    // Categorías del catálogo
+    // ============================================
+
+    /**
+     * Bean del caso de uso para crear categoría.
+     */
+    @Bean
+    public CrearCategoriaUseCase crearCategoriaUseCase(CategoriaRepository categoriaRepository) {
+        return new CrearCategoriaUseCase(categoriaRepository);
+    }
+
+    /**
+     * Bean del caso de uso para editar categoría.
+     */
+    @Bean
+    public EditarCategoriaUseCase editarCategoriaUseCase(CategoriaRepository categoriaRepository) {
+        return new EditarCategoriaUseCase(categoriaRepository);
+    }
+
+    /**
+     * Bean del caso de uso para consultar categorías de un local.
+     */
+    @Bean
+    public ConsultarCategoriasUseCase consultarCategoriasUseCase(CategoriaRepository categoriaRepository) {
+        return new ConsultarCategoriasUseCase(categoriaRepository);
+    }
+
+    /**
+     * Bean del caso de uso para eliminar categoría.
+     */
+    @Bean
+    public EliminarCategoriaUseCase eliminarCategoriaUseCase(CategoriaRepository categoriaRepository) {
+        return new EliminarCategoriaUseCase(categoriaRepository);
+    }
+
+    /**
+     * HU-29: Bean del caso de uso para enviar comanda a cocina.
+     * Genera el buffer ESC/POS y marca el pedido como enviado.
+     */
+    @Bean
+    public EnviarComandaCocinaUseCase enviarComandaCocinaUseCase(
+        MesaRepository mesaRepository,
+        PedidoRepository pedidoRepository,
+        MeisenProperties meisenProperties,
+        ComandaCocinaRepository comandaCocinaRepository,
+        CategoriaRepository categoriaRepository,
+        LinkPagoRepository linkPagoRepository,
+        EstimarEsperaCocinaUseCase estimarEsperaCocinaUseCase,
+        ZonaDeliveryRepository zonaDeliveryRepository
+    ) {
+        return new EnviarComandaCocinaUseCase(mesaRepository, pedidoRepository, meisenProperties,
+            comandaCocinaRepository, categoriaRepository, linkPagoRepository, estimarEsperaCocinaUseCase,
+            zonaDeliveryRepository);
+    }
+
+    /**
+     * HU-29: Bean del caso de uso para generar ticket de venta ESC/POS.
+     * Solo lectura — no modifica estado. Reutiliza ConsultarDetallePedidoUseCase.
+     */
+    @Bean
+    public GenerarTicketVentaUseCase generarTicketVentaUseCase(
+        ConsultarDetallePedidoUseCase consultarDetallePedidoUseCase,
+        MeisenProperties meisenProperties
+    ) {
+        return new GenerarTicketVentaUseCase(consultarDetallePedidoUseCase, meisenProperties);
+    }
+
+    // ============================================
+    // Analytics — Reporte de ventas por producto
+    // ============================================
+
+    /**
+     * Bean del caso de uso para obtener el reporte de ventas por producto.
+     * Operación de solo lectura que consulta el puerto de analytics.
+     *
+     * @param analyticsRepository implementación del puerto de analytics (AnalyticsRepositoryAdapter)
+     * @return instancia del caso de uso lista para usar
+     */
+    @Bean
+    public ObtenerReporteVentasUseCase obtenerReporteVentasUseCase(
+            AnalyticsRepositoryPort analyticsRepository
+    ) {
+        return new ObtenerReporteVentasUseCase(analyticsRepository);
+    }
+
+    // ============================================
+    // HU-162: Archivo de pedidos
+    // ============================================
+
+    /**
+     * HU-162: Bean del caso de uso que pasa al archivo los pedidos cerrados viejos.
+     * El horizonte sale de app.archivo.meses-retencion (0 desactiva el archivado).
+     */
+    @Bean
+    public ArchivarPedidosUseCase archivarPedidosUseCase(
+            PedidoRepository pedidoRepository,
+            ArchivoPedidosRepository archivoPedidosRepository,
+            @Value("${app.archivo.meses-retencion:12}") int mesesRetencion,
+            Clock clock
+    ) {
+        return new ArchivarPedidosUseCase(pedidoRepository, archivoPedidosRepository, mesesRetencion, clock);
+    }
+
+    // ============================================
+    // HU-163: Mantenimiento programado de la base
+    // ============================================
+
+    /**
+     * HU-163: VACUUM/ANALYZE/REINDEX sobre la base configurada (SQLite o PostgreSQL).
+     */
+    @Bean
+    public MantenimientoBasePort mantenimientoBasePort(DataSource dataSource) {
+        return new JdbcMantenimientoBaseAdapter(dataSource);
+    }
+
+    /**
+     * HU-163: Bean del caso de uso que dispara la app de escritorio en la ventana fuera de horario.
+     */
+    @Bean
+    public EjecutarMantenimientoBaseUseCase ejecutarMantenimientoBaseUseCase(
+            PedidoRepository pedidoRepository,
+            MantenimientoBasePort mantenimientoBasePort,
+            Clock clock
+    ) {
+        return new EjecutarMantenimientoBaseUseCase(pedidoRepository, mantenimientoBasePort, clock);
+    }
+
+    // ============================================
+    // HU-166: Numeración de comprobantes por caja
+    // ============================================
+
+    /**
+     * HU-166: Bean del caso de uso de las series de comprobantes: configuración
+     * por caja y asignación del número en el cierre de la mesa.
+     */
+    @Bean
+    public GestionarSeriesComprobantesUseCase gestionarSeriesComprobantesUseCase(
+            SerieComprobantesRepository serieComprobantesRepository,
+            CajaRepository cajaRepository,
+            JornadaComercialRepository jornadaComercialRepository,
+            PedidoRepository pedidoRepository,
+            Clock clock
+    ) {
+        return new GestionarSeriesComprobantesUseCase(serieComprobantesRepository, cajaRepository,
+            jornadaComercialRepository, pedidoRepository, clock);
+    }
+
+    // ============================================
+    // HU-167: Exportación al sistema contable
+    // ============================================
+
+    /**
+     * HU-167: Bean del caso de uso para consultar las cuentas contables del local.
+     */
+    @Bean
+    public ConsultarPlanCuentasContableUseCase consultarPlanCuentasContableUseCase(
+            PlanCuentasContableRepository planCuentasContableRepository
+    ) {
+        return new ConsultarPlanCuentasContableUseCase(planCuentasContableRepository);
+    }
+
+    /**
+     * HU-167: Bean del caso de uso para asignar las cuentas contables del local.
+     */
+    @Bean
+    public GuardarPlanCuentasContableUseCase guardarPlanCuentasContableUseCase(
+            PlanCuentasContableRepository planCuentasContableRepository
+    ) {
+        return new GuardarPlanCuentasContableUseCase(planCuentasContableRepository);
+    }
+
+    /**
+     * HU-167: Bean del caso de uso que exporta los asientos del mes (ventas,
+     * IVA de las facturas y gastos) al formato del sistema contable elegido.
+     */
+    @Bean
+    public ExportarContabilidadUseCase exportarContabilidadUseCase(
+            PedidoRepository pedidoRepository,
+            FacturaRepository facturaRepository,
+            GastoRepository gastoRepository,
+            PlanCuentasContableRepository planCuentasContableRepository
+    ) {
+        return new ExportarContabilidadUseCase(pedidoRepository, facturaRepository, gastoRepository,
+            planCuentasContableRepository);
+    }
+
+    // ============================================
+    // HU-168: Ficha de clientes y promo de cumpleaños
+    // ============================================
+
+    /**
+     * HU-168: Bean del caso de uso para consultar la promo de cumpleaños del local.
+     */
+    @Bean
+    public ConsultarPromoCumpleaniosUseCase consultarPromoCumpleaniosUseCase(
+            PromoCumpleaniosRepository promoCumpleaniosRepository
+    ) {
+        return new ConsultarPromoCumpleaniosUseCase(promoCumpleaniosRepository);
+    }
+
+    /**
+     * HU-168: Bean del caso de uso para configurar la promo de cumpleaños del local.
+     */
+    @Bean
+    public GuardarPromoCumpleaniosUseCase guardarPromoCumpleaniosUseCase(
+            PromoCumpleaniosRepository promoCumpleaniosRepository
+    ) {
+        return new GuardarPromoCumpleaniosUseCase(promoCumpleaniosRepository);
+    }
+
+    /**
+     * HU-168: Bean del caso de uso que arma el historial de consumo de un cliente.
+     */
+    @Bean
+    public ConsultarHistorialClienteUseCase consultarHistorialClienteUseCase(
+            ClienteRepository clienteRepository,
+            PedidoRepository pedidoRepository,
+            Clock clock
+    ) {
+        return new ConsultarHistorialClienteUseCase(clienteRepository, pedidoRepository, clock);
+    }
+
+    /**
+     * HU-168: Bean del caso de uso para vincular un cliente a un pedido abierto.
+     */
+    @Bean
+    public VincularClientePedidoUseCase vincularClientePedidoUseCase(
+            PedidoRepository pedidoRepository,
+            ClienteRepository clienteRepository
+    ) {
+        return new VincularClientePedidoUseCase(pedidoRepository, clienteRepository);
+    }
+
+    // HU-169: Matriz de permisos por rol y por empleado
+
+    /**
+     * HU-169: Bean del caso de uso de la matriz de permisos.
+     * Lo usan los casos de uso y endpoints de las acciones sensibles para exigir el permiso.
+     * La sesión de un empleado dura app.permisos.minutos-sesion desde que pone el PIN.
+     */
+    @Bean
+    public GestionarPermisosUseCase gestionarPermisosUseCase(
+            MatrizPermisosRepository matrizPermisosRepository,
+            MozoRepository mozoRepository,
+            EmpleadoContextProvider empleadoContextProvider,
+            SesionEmpleadoRepository sesionEmpleadoRepository,
+            @Value("${app.permisos.minutos-sesion:60}") long minutosSesion,
+            Clock clock
+    ) {
+        return new GestionarPermisosUseCase(matrizPermisosRepository, mozoRepository, empleadoContextProvider,
+            sesionEmpleadoRepository, Duration.ofMinutes(minutosSesion), clock);
     }
 }
@@ -0,0 +1,43 @@
+package com.agustinpalma.comandas.infrastructure.config;
+
+import com.agustinpalma.comandas.application.usecase.ArchivarPedidosUseCase;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+import org.springframework.context.annotation.Configuration;
+import org.springframework.scheduling.annotation.EnableScheduling;
+import org.springframework.scheduling.annotation.Scheduled;
+
+/**
+ * HU-162: Pasa al archivo los pedidos cerrados que superaron el horizonte de retención.
+ *
+ * La primera corrida espera unos minutos para no competir con el arranque
+ * del POS. La primera vez puede haber años de pedidos para mover: se
+ * archiva lote por lote (cada uno en su transacción) hasta que no quede
+ * ninguno, así una caída a mitad de camino no deja nada a medias.
+ */
+@Configuration
+@EnableScheduling
+public class ArchivoPedidosScheduler {
+
+    private static final Logger log = LoggerFactory.getLogger(ArchivoPedidosScheduler.class);
+
+    private final ArchivarPedidosUseCase archivarPedidosUseCase;
+
+    public ArchivoPedidosScheduler(ArchivarPedidosUseCase archivarPedidosUseCase) {
+        this.archivarPedidosUseCase = archivarPedidosUseCase;
+    }
+
+    @Scheduled(initialDelayString = "PT10M", fixedDelayString = "PT6H")
+    public void archivarVencidos() {
+        int total = 0;
+        int lote;
+        do {
+            lote = archivarPedidosUseCase.archivarLote();
+            total += lote;
+        } while (lote > 0);
+
+        if (total > 0) {
+            log.info("Se archivaron {} pedidos cerrados fuera del horizonte de retención", total);
+        }
+    }
+}
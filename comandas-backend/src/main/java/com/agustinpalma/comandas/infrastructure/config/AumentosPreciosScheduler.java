@@ -0,0 +1,36 @@
+package com.agustinpalma.comandas.infrastructure.config;
+
+import com.agustinpalma.comandas.application.usecase.GestionarAumentosPreciosUseCase;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+import org.springframework.context.annotation.Configuration;
+import org.springframework.scheduling.annotation.EnableScheduling;
+import org.springframework.scheduling.annotation.Scheduled;
+
+/**
+ * HU-145: Aplica los aumentos de precios programados cuando llega su día.
+ *
+ * Corre cada pocos minutos y no a medianoche: la app de escritorio suele
+ * estar apagada de noche, así que el aumento se aplica apenas se levanta
+ * el backend el día de vigencia (o el primer día en que se levante después).
+ */
+@Configuration
+@EnableScheduling
+public class AumentosPreciosScheduler {
+
+    private static final Logger log = LoggerFactory.getLogger(AumentosPreciosScheduler.class);
+
+    private final GestionarAumentosPreciosUseCase gestionarAumentosPreciosUseCase;
+
+    public AumentosPreciosScheduler(GestionarAumentosPreciosUseCase gestionarAumentosPreciosUseCase) {
+        this.gestionarAumentosPreciosUseCase = gestionarAumentosPreciosUseCase;
+    }
+
+    @Scheduled(initialDelayString = "PT30S", fixedDelayString = "PT5M")
+    public void aplicarProgramados() {
+        int aplicados = gestionarAumentosPreciosUseCase.aplicarProgramados();
+        if (aplicados > 0) {
+            log.info("Se aplicaron {} aumentos de precios programados", aplicados);
+        }
+    }
+}
@@ -0,0 +1,49 @@
+package com.agustinpalma.comandas.infrastructure.config;
+
+import com.agustinpalma.comandas.application.ports.output.IdentidadSucursalProvider;
+import com.agustinpalma.comandas.domain.model.DomainIds.SucursalId;
+import org.springframework.stereotype.Component;
+
+/**
+ * Adaptador de infraestructura que resuelve la identidad de la sucursal (HU-107).
+ *
+ * Lee "app.context.sucursal" a través de {@link MeisenProperties}:
+ * - Si no hay id configurado, la instalación usa su localId como sucursal
+ *   (un único local sin red de sucursales sigue funcionando igual).
+ * - Si no hay nombre, usa el nombre del local de los tickets.
+ */
+@Component
+public class ConfiguredIdentidadSucursalProvider implements IdentidadSucursalProvider {
+
+    private final SucursalId sucursalId;
+    private final String nombreSucursal;
+
+    public ConfiguredIdentidadSucursalProvider(MeisenProperties properties) {
+        var sucursal = properties.getSucursal();
+        if (sucursal.getId() != null) {
+            this.sucursalId = new SucursalId(sucursal.getId());
+        } else if (properties.getLocalId() != null) {
+            this.sucursalId = new SucursalId(properties.getLocalId());
+        } else {
+            throw new IllegalStateException(
+                "Falta 'app.context.sucursal.id' (o 'app.context.local-id'). Configurar en application.yml."
+            );
+        }
+
+        String nombre = sucursal.getNombre();
+        if (nombre == null || nombre.isBlank()) {
+            nombre = properties.getLocal().getNombreLocal();
+        }
+        this.nombreSucursal = (nombre == null || nombre.isBlank()) ? "Sucursal" : nombre.trim();
+    }
+
+    @Override
+    public SucursalId getSucursalId() {
+        return sucursalId;
+    }
+
+    @Override
+    public String getNombreSucursal() {
+        return nombreSucursal;
+    }
+}
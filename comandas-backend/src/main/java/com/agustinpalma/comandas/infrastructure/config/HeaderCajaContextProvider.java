@@ -0,0 +1,38 @@
+package com.agustinpalma.comandas.infrastructure.config;
+
+import com.agustinpalma.comandas.application.ports.output.CajaContextProvider;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import org.springframework.stereotype.Component;
+import org.springframework.web.context.request.RequestContextHolder;
+import org.springframework.web.context.request.ServletRequestAttributes;
+
+import java.util.Optional;
+
+/**
+ * Adaptador de infraestructura que resuelve la caja desde el encabezado
+ * {@value #HEADER} del request HTTP en curso (HU-159).
+ *
+ * Fuera de un request (schedulers, tareas en segundo plano) o sin el
+ * encabezado, la operación es de la caja principal.
+ */
+@Component
+public class HeaderCajaContextProvider implements CajaContextProvider {
+
+    public static final String HEADER = "X-Caja-Id";
+
+    @Override
+    public Optional<CajaId> getCajaActual() {
+        if (!(RequestContextHolder.getRequestAttributes() instanceof ServletRequestAttributes atributos)) {
+            return Optional.empty();
+        }
+        String valor = atributos.getRequest().getHeader(HEADER);
+        if (valor == null || valor.isBlank()) {
+            return Optional.empty();
+        }
+        try {
+            return Optional.of(CajaId.from(valor.trim()));
+        } catch (IllegalArgumentException e) {
+            throw new IllegalArgumentException("El encabezado " + HEADER + " no es un id de caja válido");
+        }
+    }
+}
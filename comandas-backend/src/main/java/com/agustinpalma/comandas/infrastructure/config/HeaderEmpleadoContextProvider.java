@@ -0,0 +1,49 @@
+package com.agustinpalma.comandas.infrastructure.config;
+
+import com.agustinpalma.comandas.application.ports.output.EmpleadoContextProvider;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.SesionEmpleado;
+import com.agustinpalma.comandas.domain.repository.SesionEmpleadoRepository;
+import org.springframework.stereotype.Component;
+import org.springframework.web.context.request.RequestContextHolder;
+import org.springframework.web.context.request.ServletRequestAttributes;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.util.Optional;
+
+/**
+ * Adaptador de infraestructura que resuelve el empleado desde el token de
+ * sesión del encabezado {@value #HEADER} del request HTTP en curso (HU-169).
+ *
+ * Fuera de un request (schedulers, tareas en segundo plano), sin el
+ * encabezado o con una sesión vencida o cerrada, no hay empleado identificado.
+ */
+@Component
+public class HeaderEmpleadoContextProvider implements EmpleadoContextProvider {
+
+    public static final String HEADER = "X-Sesion-Empleado";
+
+    private final SesionEmpleadoRepository sesionEmpleadoRepository;
+    private final Clock clock;
+
+    public HeaderEmpleadoContextProvider(SesionEmpleadoRepository sesionEmpleadoRepository, Clock clock) {
+        this.sesionEmpleadoRepository = sesionEmpleadoRepository;
+        this.clock = clock;
+    }
+
+    @Override
+    public Optional<MozoId> getEmpleadoActual() {
+        if (!(RequestContextHolder.getRequestAttributes() instanceof ServletRequestAttributes atributos)) {
+            return Optional.empty();
+        }
+        String token = atributos.getRequest().getHeader(HEADER);
+        if (token == null || token.isBlank()) {
+            return Optional.empty();
+        }
+        LocalDateTime ahora = LocalDateTime.now(clock);
+        return sesionEmpleadoRepository.buscarPorHash(SesionEmpleado.hash(token.trim()))
+            .filter(sesion -> sesion.estaVigente(ahora))
+            .map(SesionEmpleado::getEmpleadoId);
+    }
+}
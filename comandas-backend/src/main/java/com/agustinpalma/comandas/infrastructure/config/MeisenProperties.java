@@ -1,7 +1,10 @@
 package com.agustinpalma.comandas.infrastructure.config;
 
+import com.agustinpalma.comandas.domain.model.DomainEnums.CondicionIva;
 import org.springframework.boot.context.properties.ConfigurationProperties;
 
+import java.math.BigDecimal;
+
 import java.util.UUID;
 
 /**
@@ -11,6 +14,8 @@ import java.util.UUID;
  * Contiene:
  * - Identificación del tenant (localId)
  * - Datos de impresión del local (nombre, dirección, teléfono, CUIT, mensaje)
+ * - HU-107: Identidad de la instalación como sucursal
+ * - HU-115: Datos fiscales del emisor de facturas
  *
  * Estos valores NO se hardcodean — se leen de configuración externa.
  */
@@ -19,6 +24,8 @@ public class MeisenProperties {
 
     private UUID localId;
     private LocalProperties local = new LocalProperties();
+    private SucursalProperties sucursal = new SucursalProperties();
+    private FiscalProperties fiscal = new FiscalProperties();
 
     public UUID getLocalId() {
         return localId;
@@ -36,6 +43,84 @@ public class MeisenProperties {
         this.local = local;
     }
 
+    public SucursalProperties getSucursal() {
+        return sucursal;
+    }
+
+    public void setSucursal(SucursalProperties sucursal) {
+        this.sucursal = sucursal;
+    }
+
+    public FiscalProperties getFiscal() {
+        return fiscal;
+    }
+
+    public void setFiscal(FiscalProperties fiscal) {
+        this.fiscal = fiscal;
+    }
+
+    /**
+     * HU-115: El local como emisor de facturas.
+     * Configurable bajo "app.context.fiscal"; el CUIT es el de "app.context.local".
+     */
+    public static class FiscalProperties {
+
+        private CondicionIva condicionIva = CondicionIva.RESPONSABLE_INSCRIPTO;
+        private int puntoVenta = 1;
+        private BigDecimal alicuotaIva = new BigDecimal("21");
+
+        public CondicionIva getCondicionIva() {
+            return condicionIva;
+        }
+
+        public void setCondicionIva(CondicionIva condicionIva) {
+            this.condicionIva = condicionIva;
+        }
+
+        public int getPuntoVenta() {
+            return puntoVenta;
+        }
+
+        public void setPuntoVenta(int puntoVenta) {
+            this.puntoVenta = puntoVenta;
+        }
+
+        public BigDecimal getAlicuotaIva() {
+            return alicuotaIva;
+        }
+
+        public void setAlicuotaIva(BigDecimal alicuotaIva) {
+            this.alicuotaIva = alicuotaIva;
+        }
+    }
+
+    /**
+     * HU-107: Identidad de la instalación dentro de la red de sucursales.
+     * Configurable bajo "app.context.sucursal". En desktop, Tauri genera el
+     * id en la primera ejecución y lo inyecta por variable de entorno.
+     */
+    public static class SucursalProperties {
+
+        private UUID id;
+        private String nombre = "";
+
+        public UUID getId() {
+            return id;
+        }
+
+        public void setId(UUID id) {
+            this.id = id;
+        }
+
+        public String getNombre() {
+            return nombre;
+        }
+
+        public void setNombre(String nombre) {
+            this.nombre = nombre;
+        }
+    }
+
     /**
      * Datos del local usados para impresión de tickets.
      * Configurables bajo "app.context.local" en application.yml.
@@ -47,6 +132,8 @@ public class MeisenProperties {
         private String telefono = "";
         private String cuit = "";
         private String mensajeBienvenida = "";
+        private String aliasTransferencia = "";
+        private String cvu = "";
 
         public String getNombreLocal() {
             return nombreLocal;
@@ -87,5 +174,27 @@ public class MeisenProperties {
         public void setMensajeBienvenida(String mensajeBienvenida) {
             this.mensajeBienvenida = mensajeBienvenida;
         }
+
+        /**
+         * HU-156: Alias de la cuenta donde el local recibe transferencias.
+         */
+        public String getAliasTransferencia() {
+            return aliasTransferencia;
+        }
+
+        public void setAliasTransferencia(String aliasTransferencia) {
+            this.aliasTransferencia = aliasTransferencia;
+        }
+
+        /**
+         * HU-156: CVU o CBU de esa cuenta (22 dígitos).
+         */
+        public String getCvu() {
+            return cvu;
+        }
+
+        public void setCvu(String cvu) {
+            this.cvu = cvu;
+        }
     }
 }
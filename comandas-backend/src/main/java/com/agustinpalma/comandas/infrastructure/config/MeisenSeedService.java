@@ -62,6 +62,7 @@ public class MeisenSeedService implements CommandLineRunner {
         mesa.setLocalId(localUuid);
         mesa.setNumero(1);
         mesa.setEstado(com.agustinpalma.comandas.domain.model.DomainEnums.EstadoMesa.LIBRE);
+        mesa.setSector(com.agustinpalma.comandas.domain.model.Mesa.SECTOR_POR_DEFECTO);
         mesaRepository.save(mesa);
 
         log.info("Sistema inicializado para Local ID: {}", localUuid);
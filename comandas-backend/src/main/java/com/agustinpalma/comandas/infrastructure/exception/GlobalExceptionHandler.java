@@ -4,12 +4,15 @@ import com.agustinpalma.comandas.domain.exception.JornadaNoEncontradaException;
 import com.agustinpalma.comandas.domain.exception.JornadaYaAbiertaException;
 import com.agustinpalma.comandas.domain.exception.JornadaYaCerradaException;
 import com.agustinpalma.comandas.domain.exception.MesasAbiertasException;
+import com.agustinpalma.comandas.domain.exception.PedidoModificadoException;
+import com.agustinpalma.comandas.domain.exception.PermisoDenegadoException;
 import com.agustinpalma.comandas.domain.exception.TrialExpiredException;
 import org.slf4j.Logger;
 import org.slf4j.LoggerFactory;
 import org.springframework.http.HttpStatus;
 import org.springframework.http.ResponseEntity;
 import org.springframework.web.bind.MethodArgumentNotValidException;
+import org.springframework.web.bind.MissingRequestHeaderException;
 import org.springframework.web.bind.annotation.ExceptionHandler;
 import org.springframework.web.bind.annotation.RestControllerAdvice;
 
@@ -129,6 +132,68 @@ public class GlobalExceptionHandler {
         return ResponseEntity.status(HttpStatus.CONFLICT).body(body);
     }
 
+    /**
+     * Captura PedidoModificadoException (otra terminal cambió el pedido, HU-165).
+     * HTTP 409 Conflict.
+     *
+     * El body trae "codigo": "PEDIDO_MODIFICADO" para que el POS lo distinga de
+     * los demás 409 y ofrezca recargar el pedido antes de reintentar.
+     */
+    @ExceptionHandler(PedidoModificadoException.class)
+    public ResponseEntity<Map<String, Object>> handlePedidoModificado(PedidoModificadoException ex) {
+        logger.warn("Cambio rechazado por edición concurrente: versión {} vs {}",
+                ex.getVersionEsperada(), ex.getVersionActual());
+
+        Map<String, Object> body = new LinkedHashMap<>();
+        body.put("timestamp", LocalDateTime.now());
+        body.put("status", HttpStatus.CONFLICT.value());
+        body.put("error", "Conflict");
+        body.put("message", ex.getMessage());
+        body.put("codigo", "PEDIDO_MODIFICADO");
+        body.put("versionActual", ex.getVersionActual());
+
+        return ResponseEntity.status(HttpStatus.CONFLICT).body(body);
+    }
+
+    /**
+     * Captura MissingRequestHeaderException (ej: un cambio del pedido sin
+     * X-Pedido-Version, HU-165). HTTP 400 Bad Request.
+     */
+    @ExceptionHandler(MissingRequestHeaderException.class)
+    public ResponseEntity<Map<String, Object>> handleCabeceraFaltante(MissingRequestHeaderException ex) {
+        logger.warn("Request sin la cabecera {}", ex.getHeaderName());
+
+        Map<String, Object> body = new LinkedHashMap<>();
+        body.put("timestamp", LocalDateTime.now());
+        body.put("status", HttpStatus.BAD_REQUEST.value());
+        body.put("error", "Bad Request");
+        body.put("message", "Falta la cabecera " + ex.getHeaderName());
+
+        return ResponseEntity.status(HttpStatus.BAD_REQUEST).body(body);
+    }
+
+    /**
+     * Captura PermisoDenegadoException (el empleado no tiene la acción habilitada, HU-169).
+     * HTTP 403 Forbidden.
+     *
+     * El body trae "codigo": "PERMISO_DENEGADO" y el permiso que faltó para que
+     * el POS pida identificarse a alguien que lo tenga.
+     */
+    @ExceptionHandler(PermisoDenegadoException.class)
+    public ResponseEntity<Map<String, Object>> handlePermisoDenegado(PermisoDenegadoException ex) {
+        logger.warn("Acción rechazada por permisos: {}", ex.getPermiso());
+
+        Map<String, Object> body = new LinkedHashMap<>();
+        body.put("timestamp", LocalDateTime.now());
+        body.put("status", HttpStatus.FORBIDDEN.value());
+        body.put("error", "Forbidden");
+        body.put("message", ex.getMessage());
+        body.put("codigo", "PERMISO_DENEGADO");
+        body.put("permiso", ex.getPermiso());
+
+        return ResponseEntity.status(HttpStatus.FORBIDDEN).body(body);
+    }
+
     /**
      * Captura JornadaNoEncontradaException (jornada no existe para el ID solicitado).
      * HTTP 404 Not Found.
@@ -0,0 +1,30 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.AlertaMesasOlvidadas;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.AlertaMesasOlvidadasEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper between AlertaMesasOlvidadas domain model and its JPA entity.
+ * HU-113: Forgotten tables alert.
+ */
+@Component
+public class AlertaMesasOlvidadasMapper {
+
+    public AlertaMesasOlvidadas toDomain(AlertaMesasOlvidadasEntity entity) {
+        return new AlertaMesasOlvidadas(
+            new LocalId(entity.getLocalId()),
+            entity.isActiva(),
+            entity.getMinutosSinActividad()
+        );
+    }
+
+    public AlertaMesasOlvidadasEntity toEntity(AlertaMesasOlvidadas domain) {
+        return new AlertaMesasOlvidadasEntity(
+            domain.getLocalId().getValue(),
+            domain.isActiva(),
+            domain.getMinutosSinActividad()
+        );
+    }
+}
@@ -0,0 +1,65 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.AumentoPrecios;
+import com.agustinpalma.comandas.domain.model.CambioPrecio;
+import com.agustinpalma.comandas.domain.model.DomainIds.AumentoPreciosId;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.AumentoPreciosEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.CambioPrecioEmbeddable;
+import org.springframework.stereotype.Component;
+
+import java.util.ArrayList;
+
+/**
+ * Mapper entre entidades de dominio AumentoPrecios y entidades JPA AumentoPreciosEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class AumentoPreciosMapper {
+
+    public AumentoPrecios toDomain(AumentoPreciosEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new AumentoPrecios(
+            new AumentoPreciosId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            entity.getCategoriaId() != null ? new CategoriaId(entity.getCategoriaId()) : null,
+            entity.getPorcentaje(),
+            entity.getMultiploRedondeo(),
+            entity.getFechaVigencia(),
+            entity.getFechaCreacion(),
+            entity.getResponsable(),
+            entity.getEstado(),
+            entity.getFechaAplicacion(),
+            entity.getCambios().stream()
+                .map(c -> new CambioPrecio(new ProductoId(c.getProductoId()), c.getNombreProducto(),
+                    c.getPrecioAnterior(), c.getPrecioNuevo()))
+                .toList()
+        );
+    }
+
+    public AumentoPreciosEntity toEntity(AumentoPrecios aumento) {
+        if (aumento == null) {
+            return null;
+        }
+        return new AumentoPreciosEntity(
+            aumento.getId().getValue(),
+            aumento.getLocalId().getValue(),
+            aumento.getCategoriaId() != null ? aumento.getCategoriaId().getValue() : null,
+            aumento.getPorcentaje(),
+            aumento.getMultiploRedondeo(),
+            aumento.getFechaVigencia(),
+            aumento.getFechaCreacion(),
+            aumento.getResponsable(),
+            aumento.getEstado(),
+            aumento.getFechaAplicacion(),
+            new ArrayList<>(aumento.getCambios().stream()
+                .map(c -> new CambioPrecioEmbeddable(c.getProductoId().getValue(), c.getNombreProducto(),
+                    c.getPrecioAnterior(), c.getPrecioNuevo()))
+                .toList())
+        );
+    }
+}
@@ -0,0 +1,75 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.AperturaCaja;
+import com.agustinpalma.comandas.domain.model.Caja;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.AperturaCajaEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.CajaEntity;
+import org.springframework.stereotype.Component;
+
+import java.util.UUID;
+
+/**
+ * Mapper entre las entidades de dominio Caja/AperturaCaja y sus entidades JPA.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class CajaMapper {
+
+    public Caja toDomain(CajaEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new Caja(
+            new CajaId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            entity.getNombre(),
+            entity.getFechaAlta()
+        );
+    }
+
+    public CajaEntity toEntity(Caja caja) {
+        if (caja == null) {
+            return null;
+        }
+        return new CajaEntity(
+            caja.getId().getValue(),
+            caja.getLocalId().getValue(),
+            caja.getNombre(),
+            caja.getFechaAlta()
+        );
+    }
+
+    public AperturaCaja toDomain(AperturaCajaEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new AperturaCaja(
+            new LocalId(entity.getLocalId()),
+            new JornadaCajaId(entity.getJornadaId()),
+            new CajaId(entity.getCajaId()),
+            entity.getFondoInicial(),
+            entity.getFechaApertura()
+        );
+    }
+
+    /**
+     * La apertura no tiene id de dominio: se genera uno técnico. Las aperturas
+     * no se modifican, así que solo se persisten una vez.
+     */
+    public AperturaCajaEntity toEntity(AperturaCaja apertura) {
+        if (apertura == null) {
+            return null;
+        }
+        return new AperturaCajaEntity(
+            UUID.randomUUID(),
+            apertura.getLocalId().getValue(),
+            apertura.getJornadaId().getValue(),
+            apertura.getCajaId().getValue(),
+            apertura.getFondoInicial(),
+            apertura.getFechaApertura()
+        );
+    }
+}
@@ -0,0 +1,53 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.CambioPrecioProducto;
+import com.agustinpalma.comandas.domain.model.DomainIds.AumentoPreciosId;
+import com.agustinpalma.comandas.domain.model.DomainIds.CambioPrecioProductoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.CambioPrecioProductoEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio CambioPrecioProducto y entidades JPA CambioPrecioProductoEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class CambioPrecioProductoMapper {
+
+    public CambioPrecioProducto toDomain(CambioPrecioProductoEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new CambioPrecioProducto(
+            new CambioPrecioProductoId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            new ProductoId(entity.getProductoId()),
+            entity.getNombreProducto(),
+            entity.getPrecioAnterior(),
+            entity.getPrecioNuevo(),
+            entity.getFecha(),
+            entity.getOrigen(),
+            entity.getAumentoId() != null ? new AumentoPreciosId(entity.getAumentoId()) : null,
+            entity.getResponsable()
+        );
+    }
+
+    public CambioPrecioProductoEntity toEntity(CambioPrecioProducto cambio) {
+        if (cambio == null) {
+            return null;
+        }
+        return new CambioPrecioProductoEntity(
+            cambio.getId().getValue(),
+            cambio.getLocalId().getValue(),
+            cambio.getProductoId().getValue(),
+            cambio.getNombreProducto(),
+            cambio.getPrecioAnterior(),
+            cambio.getPrecioNuevo(),
+            cambio.getFecha(),
+            cambio.getOrigen(),
+            cambio.getAumentoId() != null ? cambio.getAumentoId().getValue() : null,
+            cambio.getResponsable()
+        );
+    }
+}
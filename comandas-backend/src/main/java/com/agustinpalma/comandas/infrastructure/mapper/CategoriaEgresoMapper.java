@@ -0,0 +1,39 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.CategoriaEgreso;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaEgresoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.CategoriaEgresoEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio CategoriaEgreso y entidades JPA CategoriaEgresoEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class CategoriaEgresoMapper {
+
+    public CategoriaEgreso toDomain(CategoriaEgresoEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new CategoriaEgreso(
+            new CategoriaEgresoId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            entity.getNombre(),
+            entity.isActiva()
+        );
+    }
+
+    public CategoriaEgresoEntity toEntity(CategoriaEgreso categoria) {
+        if (categoria == null) {
+            return null;
+        }
+        return new CategoriaEgresoEntity(
+            categoria.getId().getValue(),
+            categoria.getLocalId().getValue(),
+            categoria.getNombre(),
+            categoria.isActiva()
+        );
+    }
+}
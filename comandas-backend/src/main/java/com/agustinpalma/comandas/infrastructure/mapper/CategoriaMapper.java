@@ -3,6 +3,7 @@ package com.agustinpalma.comandas.infrastructure.mapper;
 import com.agustinpalma.comandas.domain.model.Categoria;
 import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.FranjaHoraria;
 import com.agustinpalma.comandas.infrastructure.persistence.entity.CategoriaEntity;
 import org.springframework.stereotype.Component;
 
@@ -20,7 +21,7 @@ public class CategoriaMapper {
      * @return objeto de dominio
      */
     public Categoria toDomain(CategoriaEntity entity) {
-        return new Categoria(
+        Categoria categoria = new Categoria(
             new CategoriaId(entity.getId()),
             new LocalId(entity.getLocalId()),
             entity.getNombre(),
@@ -32,6 +33,9 @@ public class CategoriaMapper {
                 ? new CategoriaId(entity.getCategoriaModificadoresId())
                 : null
         );
+        categoria.cambiarMinutosAlertaCocina(entity.getMinutosAlertaCocina());
+        categoria.cambiarFranjaHoraria(FranjaHoraria.de(entity.getHorarioDesde(), entity.getHorarioHasta()));
+        return categoria;
     }
 
     /**
@@ -41,7 +45,7 @@ public class CategoriaMapper {
      * @return entidad JPA
      */
     public CategoriaEntity toEntity(Categoria domain) {
-        return new CategoriaEntity(
+        CategoriaEntity entity = new CategoriaEntity(
             domain.getId().getValue(),
             domain.getLocalId().getValue(),
             domain.getNombre(),
@@ -53,5 +57,10 @@ public class CategoriaMapper {
                 ? domain.getCategoriaModificadoresId().getValue()
                 : null
         );
+        entity.setMinutosAlertaCocina(domain.getMinutosAlertaCocina());
+        FranjaHoraria horario = domain.getFranjaHoraria();
+        entity.setHorarioDesde(horario != null ? horario.getDesde() : null);
+        entity.setHorarioHasta(horario != null ? horario.getHasta() : null);
+        return entity;
     }
 }
@@ -0,0 +1,54 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.ClienteEnEspera;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteEnEsperaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ClienteEnEsperaEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio ClienteEnEspera y entidades JPA ClienteEnEsperaEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class ClienteEnEsperaMapper {
+
+    public ClienteEnEspera toDomain(ClienteEnEsperaEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new ClienteEnEspera(
+            new ClienteEnEsperaId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            entity.getNombreCliente(),
+            entity.getPersonas(),
+            entity.getTelefono(),
+            entity.getObservaciones(),
+            entity.getFechaAlta(),
+            entity.getEstado(),
+            entity.getMesaId() != null ? new MesaId(entity.getMesaId()) : null,
+            entity.getFechaAviso(),
+            entity.getFechaSalida()
+        );
+    }
+
+    public ClienteEnEsperaEntity toEntity(ClienteEnEspera cliente) {
+        if (cliente == null) {
+            return null;
+        }
+        return new ClienteEnEsperaEntity(
+            cliente.getId().getValue(),
+            cliente.getLocalId().getValue(),
+            cliente.getNombreCliente(),
+            cliente.getPersonas(),
+            cliente.getTelefono(),
+            cliente.getObservaciones(),
+            cliente.getFechaAlta(),
+            cliente.getEstado(),
+            cliente.getMesaId() != null ? cliente.getMesaId().getValue() : null,
+            cliente.getFechaAviso(),
+            cliente.getFechaSalida()
+        );
+    }
+}
@@ -0,0 +1,44 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.Cliente;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ClienteEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper between Cliente domain entity and ClienteEntity JPA entity.
+ * HU-104: Customer running accounts.
+ * HU-168: Customer profile.
+ */
+@Component
+public class ClienteMapper {
+
+    public Cliente toDomain(ClienteEntity entity) {
+        return new Cliente(
+            new ClienteId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            entity.getNombre(),
+            entity.getTelefono(),
+            entity.getLimiteCredito(),
+            entity.isActivo(),
+            entity.getFechaNacimiento(),
+            entity.getPreferencias(),
+            entity.getAlergias()
+        );
+    }
+
+    public ClienteEntity toEntity(Cliente domain) {
+        return new ClienteEntity(
+            domain.getId().getValue(),
+            domain.getLocalId().getValue(),
+            domain.getNombre(),
+            domain.getTelefono(),
+            domain.getLimiteCredito(),
+            domain.isActivo(),
+            domain.getFechaNacimiento(),
+            domain.getPreferencias(),
+            domain.getAlergias()
+        );
+    }
+}
@@ -0,0 +1,89 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.ComandaCocina;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Alergeno;
+import com.agustinpalma.comandas.domain.model.DomainIds.ComandaCocinaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.ItemComandaCocina;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ComandaCocinaEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ItemComandaCocinaEmbeddable;
+import org.springframework.stereotype.Component;
+
+import java.util.Arrays;
+import java.util.List;
+import java.util.Set;
+import java.util.stream.Collectors;
+
+/**
+ * Mapper entre entidades de dominio ComandaCocina y entidades JPA ComandaCocinaEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class ComandaCocinaMapper {
+
+    public ComandaCocina toDomain(ComandaCocinaEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        ComandaCocina comanda = new ComandaCocina(
+            new ComandaCocinaId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            new PedidoId(entity.getPedidoId()),
+            entity.getNumeroMesa(),
+            entity.getNumeroPedido(),
+            entity.getEnviadaEn(),
+            entity.getMinutosAlerta(),
+            entity.getItems().stream()
+                .map(i -> new ItemComandaCocina(i.getNombreProducto(), i.getCantidad(), i.getObservacion(),
+                    i.isUrgente(), advertenciasToDomain(i.getAdvertencias())))
+                .toList(),
+            entity.getListaEn(),
+            entity.isUrgente()
+        );
+        comanda.asignarHoraPrometida(entity.getPrometidaPara());
+        return comanda;
+    }
+
+    public ComandaCocinaEntity toEntity(ComandaCocina comanda) {
+        if (comanda == null) {
+            return null;
+        }
+        return new ComandaCocinaEntity(
+            comanda.getId().getValue(),
+            comanda.getLocalId().getValue(),
+            comanda.getPedidoId().getValue(),
+            comanda.getNumeroMesa(),
+            comanda.getNumeroPedido(),
+            comanda.getEnviadaEn(),
+            comanda.getMinutosAlerta(),
+            comanda.getListaEn(),
+            comanda.isPedidoUrgente(),
+            comanda.getHoraPrometida(),
+            comanda.getItems().stream()
+                .map(i -> new ItemComandaCocinaEmbeddable(i.getNombreProducto(), i.getCantidad(), i.getObservacion(),
+                    i.isUrgente(), advertenciasToColumna(i.getAdvertencias())))
+                .toList()
+        );
+    }
+
+    /**
+     * HU-147: Las alergias del renglón viajan en una columna, separadas por coma.
+     */
+    private String advertenciasToColumna(Set<Alergeno> advertencias) {
+        if (advertencias.isEmpty()) {
+            return null;
+        }
+        return advertencias.stream().map(Enum::name).collect(Collectors.joining(","));
+    }
+
+    private List<Alergeno> advertenciasToDomain(String columna) {
+        if (columna == null || columna.isBlank()) {
+            return List.of();
+        }
+        return Arrays.stream(columna.split(","))
+            .map(String::trim)
+            .map(Alergeno::valueOf)
+            .toList();
+    }
+}
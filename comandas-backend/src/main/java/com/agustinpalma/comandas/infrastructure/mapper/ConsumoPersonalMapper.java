@@ -0,0 +1,45 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.ConsumoPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.ConsumoPersonalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ConsumoPersonalEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio ConsumoPersonal y entidades JPA ConsumoPersonalEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class ConsumoPersonalMapper {
+
+    public ConsumoPersonal toDomain(ConsumoPersonalEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new ConsumoPersonal(
+            new ConsumoPersonalId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            new MozoId(entity.getMozoId()),
+            new PedidoId(entity.getPedidoId()),
+            entity.getMonto(),
+            entity.getFecha()
+        );
+    }
+
+    public ConsumoPersonalEntity toEntity(ConsumoPersonal consumo) {
+        if (consumo == null) {
+            return null;
+        }
+        return new ConsumoPersonalEntity(
+            consumo.getId().getValue(),
+            consumo.getLocalId().getValue(),
+            consumo.getMozoId().getValue(),
+            consumo.getPedidoId().getValue(),
+            consumo.getMonto(),
+            consumo.getFecha()
+        );
+    }
+}
@@ -0,0 +1,55 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DistribucionPropinas;
+import com.agustinpalma.comandas.domain.model.DomainIds.DistribucionPropinasId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+import com.agustinpalma.comandas.domain.model.PartePropina;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.DistribucionPropinasEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.PartePropinaEmbeddable;
+import org.springframework.stereotype.Component;
+
+import java.util.ArrayList;
+
+/**
+ * Mapper entre entidades de dominio DistribucionPropinas y entidades JPA DistribucionPropinasEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class DistribucionPropinasMapper {
+
+    public DistribucionPropinas toDomain(DistribucionPropinasEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new DistribucionPropinas(
+            new DistribucionPropinasId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            new TurnoCajaId(entity.getTurnoId()),
+            entity.getFecha(),
+            entity.getModo(),
+            entity.getPozo(),
+            entity.getPartes().stream()
+                .map(p -> new PartePropina(new MozoId(p.getMozoId()), p.getMinutos(), p.getPeso(), p.getMonto()))
+                .toList()
+        );
+    }
+
+    public DistribucionPropinasEntity toEntity(DistribucionPropinas distribucion) {
+        if (distribucion == null) {
+            return null;
+        }
+        return new DistribucionPropinasEntity(
+            distribucion.getId().getValue(),
+            distribucion.getLocalId().getValue(),
+            distribucion.getTurnoId().getValue(),
+            distribucion.getFecha(),
+            distribucion.getModo(),
+            distribucion.getPozo(),
+            new ArrayList<>(distribucion.getPartes().stream()
+                .map(p -> new PartePropinaEmbeddable(p.getMozoId().getValue(), p.getMinutos(), p.getPeso(), p.getMonto()))
+                .toList())
+        );
+    }
+}
@@ -0,0 +1,67 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.Cuit;
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.Factura;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.FacturaEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio Factura y entidades JPA FacturaEntity.
+ * El CUIT se persiste como los 11 dígitos, sin guiones.
+ */
+@Component
+public class FacturaMapper {
+
+    public Factura toDomain(FacturaEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new Factura(
+            new FacturaId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            new PedidoId(entity.getPedidoId()),
+            entity.getTipo(),
+            entity.getPuntoVenta(),
+            entity.getNumero(),
+            entity.getFechaEmision(),
+            entity.getReceptorCuit() != null ? Cuit.of(entity.getReceptorCuit()) : null,
+            entity.getReceptorRazonSocial(),
+            entity.getReceptorCondicionIva(),
+            entity.getAlicuotaIva(),
+            entity.getImporteNeto(),
+            entity.getImporteIva(),
+            entity.getImporteTotal(),
+            entity.getEstado(),
+            entity.getCae(),
+            entity.getCaeVencimiento()
+        );
+    }
+
+    public FacturaEntity toEntity(Factura factura) {
+        if (factura == null) {
+            return null;
+        }
+        return new FacturaEntity(
+            factura.getId().getValue(),
+            factura.getLocalId().getValue(),
+            factura.getPedidoId().getValue(),
+            factura.getTipo(),
+            factura.getPuntoVenta(),
+            factura.getNumero(),
+            factura.getFechaEmision(),
+            factura.getReceptorCuit() != null ? factura.getReceptorCuit().getNumero() : null,
+            factura.getReceptorRazonSocial(),
+            factura.getReceptorCondicionIva(),
+            factura.getAlicuotaIva(),
+            factura.getImporteNeto(),
+            factura.getImporteIva(),
+            factura.getImporteTotal(),
+            factura.getEstado(),
+            factura.getCae(),
+            factura.getCaeVencimiento()
+        );
+    }
+}
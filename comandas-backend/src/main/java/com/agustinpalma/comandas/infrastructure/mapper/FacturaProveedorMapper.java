@@ -0,0 +1,71 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaProveedorId;
+import com.agustinpalma.comandas.domain.model.DomainIds.GastoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+import com.agustinpalma.comandas.domain.model.FacturaProveedor;
+import com.agustinpalma.comandas.domain.model.PagoProveedor;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.FacturaProveedorEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.PagoProveedorEmbeddable;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio FacturaProveedor y entidades JPA FacturaProveedorEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class FacturaProveedorMapper {
+
+    public FacturaProveedor toDomain(FacturaProveedorEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new FacturaProveedor(
+            new FacturaProveedorId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            new ProveedorId(entity.getProveedorId()),
+            new GastoId(entity.getGastoId()),
+            entity.getNumero(),
+            entity.getFechaEmision(),
+            entity.getFechaVencimiento(),
+            entity.getMonto(),
+            entity.getFechaRegistro(),
+            entity.getPagos().stream()
+                .map(p -> new PagoProveedor(
+                    p.getFecha(),
+                    p.getMonto(),
+                    p.getMedioPago(),
+                    p.getMovimientoCajaId() != null ? new MovimientoCajaId(p.getMovimientoCajaId()) : null
+                ))
+                .toList()
+        );
+    }
+
+    public FacturaProveedorEntity toEntity(FacturaProveedor factura) {
+        if (factura == null) {
+            return null;
+        }
+        return new FacturaProveedorEntity(
+            factura.getId().getValue(),
+            factura.getLocalId().getValue(),
+            factura.getProveedorId().getValue(),
+            factura.getGastoId().getValue(),
+            factura.getNumero(),
+            factura.getFechaEmision(),
+            factura.getFechaVencimiento(),
+            factura.getMonto(),
+            factura.getSaldo(),
+            factura.getFechaRegistro(),
+            factura.getPagos().stream()
+                .map(p -> new PagoProveedorEmbeddable(
+                    p.getFecha(),
+                    p.getMonto(),
+                    p.getMedioPago(),
+                    p.getMovimientoCajaId() != null ? p.getMovimientoCajaId().getValue() : null
+                ))
+                .toList()
+        );
+    }
+}
@@ -0,0 +1,54 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.FichajeId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Fichaje;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.FichajeEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio Fichaje y entidades JPA FichajeEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class FichajeMapper {
+
+    public Fichaje toDomain(FichajeEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new Fichaje(
+            new FichajeId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            new MozoId(entity.getMozoId()),
+            entity.getMedio(),
+            entity.getEntrada(),
+            entity.getSalida(),
+            entity.getEntradaOriginal(),
+            entity.getSalidaOriginal(),
+            entity.getMotivoCorreccion(),
+            entity.getCorregidoPorId() != null ? new MozoId(entity.getCorregidoPorId()) : null,
+            entity.getFechaCorreccion()
+        );
+    }
+
+    public FichajeEntity toEntity(Fichaje fichaje) {
+        if (fichaje == null) {
+            return null;
+        }
+        return new FichajeEntity(
+            fichaje.getId().getValue(),
+            fichaje.getLocalId().getValue(),
+            fichaje.getMozoId().getValue(),
+            fichaje.getMedio(),
+            fichaje.getEntrada(),
+            fichaje.getSalida(),
+            fichaje.getEntradaOriginal(),
+            fichaje.getSalidaOriginal(),
+            fichaje.getMotivoCorreccion(),
+            fichaje.getCorregidoPorId() != null ? fichaje.getCorregidoPorId().getValue() : null,
+            fichaje.getFechaCorreccion()
+        );
+    }
+}
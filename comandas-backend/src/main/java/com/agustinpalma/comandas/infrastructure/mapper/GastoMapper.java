@@ -0,0 +1,63 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.GastoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Gasto;
+import com.agustinpalma.comandas.domain.model.ItemCompra;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.GastoEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ItemCompraEmbeddable;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio Gasto y entidades JPA GastoEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class GastoMapper {
+
+    public Gasto toDomain(GastoEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new Gasto(
+            new GastoId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            entity.getFecha(),
+            entity.getCategoria(),
+            entity.getProveedor(),
+            entity.getDescripcion(),
+            entity.getMonto(),
+            entity.getMedioPago(),
+            entity.getItems().stream()
+                .map(i -> new ItemCompra(new ProductoId(i.getProductoId()), i.getNombreProducto(), i.getCantidad()))
+                .toList(),
+            entity.getFechaRegistro(),
+            entity.getMovimientoCajaId() != null ? new MovimientoCajaId(entity.getMovimientoCajaId()) : null,
+            entity.getNombreArchivoComprobante()
+        );
+    }
+
+    public GastoEntity toEntity(Gasto gasto) {
+        if (gasto == null) {
+            return null;
+        }
+        return new GastoEntity(
+            gasto.getId().getValue(),
+            gasto.getLocalId().getValue(),
+            gasto.getFecha(),
+            gasto.getCategoria(),
+            gasto.getProveedor(),
+            gasto.getDescripcion(),
+            gasto.getMonto(),
+            gasto.getMedioPago(),
+            gasto.getItems().stream()
+                .map(i -> new ItemCompraEmbeddable(i.getProductoId().getValue(), i.getNombreProducto(), i.getCantidad()))
+                .toList(),
+            gasto.getFechaRegistro(),
+            gasto.getMovimientoCajaId() != null ? gasto.getMovimientoCajaId().getValue() : null,
+            gasto.getNombreArchivoComprobante()
+        );
+    }
+}
@@ -0,0 +1,50 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+import com.agustinpalma.comandas.domain.model.Insumo;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.InsumoEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio Insumo y entidades JPA InsumoEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class InsumoMapper {
+
+    public Insumo toDomain(InsumoEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new Insumo(
+            new InsumoId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            entity.getNombre(),
+            entity.getUnidad(),
+            entity.getCostoUnitario(),
+            entity.isControlaStock(),
+            entity.getStockActual(),
+            entity.getStockMinimo(),
+            entity.getProveedorHabitualId() != null ? new ProveedorId(entity.getProveedorHabitualId()) : null
+        );
+    }
+
+    public InsumoEntity toEntity(Insumo insumo) {
+        if (insumo == null) {
+            return null;
+        }
+        return new InsumoEntity(
+            insumo.getId().getValue(),
+            insumo.getLocalId().getValue(),
+            insumo.getNombre(),
+            insumo.getUnidad(),
+            insumo.getCostoUnitario(),
+            insumo.isControlaStock(),
+            insumo.getStockActual(),
+            insumo.getStockMinimo(),
+            insumo.getProveedorHabitualId() != null ? insumo.getProveedorHabitualId().getValue() : null
+        );
+    }
+}
@@ -3,11 +3,17 @@ package com.agustinpalma.comandas.infrastructure.mapper;
 import com.agustinpalma.comandas.domain.model.DescuentoManual;
 import com.agustinpalma.comandas.domain.model.DomainIds.*;
 import com.agustinpalma.comandas.domain.model.ExtraPedido;
+import com.agustinpalma.comandas.domain.model.IngredienteQuitado;
 import com.agustinpalma.comandas.domain.model.ItemPedido;
+import com.agustinpalma.comandas.domain.model.SeleccionCombo;
 import com.agustinpalma.comandas.infrastructure.persistence.entity.ExtraPedidoEmbeddable;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.IngredienteQuitadoEmbeddable;
 import com.agustinpalma.comandas.infrastructure.persistence.entity.ItemPedidoEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.SeleccionComboEmbeddable;
 import org.springframework.stereotype.Component;
 
+import java.util.ArrayList;
+import java.util.HashSet;
 import java.util.List;
 import java.util.stream.Collectors;
 
@@ -18,6 +24,7 @@ import java.util.stream.Collectors;
  * HU-10: Incluye mapeo de campos de promoción (montoDescuento, nombrePromocion, promocionId).
  * HU-14: Incluye mapeo de descuento manual dinámico (DescuentoManual VO <-> campos DB).
  * HU-05.1 + HU-22: Incluye mapeo bidireccional de extras (ExtraPedido VO <-> ExtraPedidoEmbeddable).
+ * HU-103: Incluye mapeo de opciones de combo (SeleccionCombo VO <-> SeleccionComboEmbeddable).
  */
 @Component
 public class ItemPedidoMapper {
@@ -44,7 +51,10 @@ public class ItemPedidoMapper {
                 entity.getDescManualValor(),
                 entity.getDescManualRazon(),
                 entity.getDescManualUsuarioId(),
-                entity.getDescManualFecha()
+                entity.getDescManualFecha(),
+                entity.getDescManualAutorizadoPorId() != null
+                    ? new MozoId(entity.getDescManualAutorizadoPorId()) : null,
+                entity.getDescManualAutorizadoPor()
             );
         }
 
@@ -53,7 +63,12 @@ public class ItemPedidoMapper {
             .map(this::extraEmbeddableToDomain)
             .collect(Collectors.toList());
 
-        return new ItemPedido(
+        // HU-103: Reconstruir opciones de combo
+        List<SeleccionCombo> componentesCombo = entity.getComponentesCombo().stream()
+            .map(this::seleccionEmbeddableToDomain)
+            .collect(Collectors.toList());
+
+        ItemPedido item = new ItemPedido(
             new ItemPedidoId(entity.getId()),
             new PedidoId(entity.getPedidoId()),
             new ProductoId(entity.getProductoId()),
@@ -70,8 +85,33 @@ public class ItemPedidoMapper {
             entity.getCantidadDiscosSnapshot(),
             entity.getCategoriaIdSnapshot() != null ? new CategoriaId(entity.getCategoriaIdSnapshot()) : null,
             entity.getFechaAgregado(),  // HU-29: Reconstruir timestamp (null para datos legacy)
-            entity.getCantidadEnviadaCocina()  // HU-29: Reconstruir cantidad ya enviada a cocina
+            entity.getCantidadEnviadaCocina(),  // HU-29: Reconstruir cantidad ya enviada a cocina
+            componentesCombo
         );
+
+        // HU-110: Paso de servicio (null en ítems previos)
+        item.asignarTiempoServicio(entity.getTiempoServicio());
+
+        // HU-137: Urgencia
+        item.marcarUrgente(entity.isUrgente());
+
+        // HU-139: Costo de envío
+        if (entity.isEnvio()) {
+            item.marcarComoEnvio();
+        }
+
+        // HU-147: Alergias del comensal
+        item.registrarAdvertencias(entity.getAdvertencias());
+
+        // HU-148: Ingredientes quitados
+        item.registrarIngredientesQuitados(entity.getIngredientesQuitados().stream()
+            .map(i -> new IngredienteQuitado(new InsumoId(i.getInsumoId()), i.getNombreInsumo()))
+            .toList());
+
+        // HU-164: Ítems del borrador del POS
+        item.registrarBorradores(entity.getBorradores());
+
+        return item;
     }
 
     /**
@@ -112,6 +152,8 @@ public class ItemPedidoMapper {
             entity.setDescManualRazon(dm.getRazon());
             entity.setDescManualUsuarioId(dm.getUsuarioId());
             entity.setDescManualFecha(dm.getFechaAplicacion());
+            entity.setDescManualAutorizadoPorId(dm.getAutorizadoPorId() != null ? dm.getAutorizadoPorId().getValue() : null);
+            entity.setDescManualAutorizadoPor(dm.getAutorizadoPor());
         }
 
         // Convertir extras de dominio a embeddables
@@ -120,6 +162,11 @@ public class ItemPedidoMapper {
             .collect(Collectors.toList());
         entity.setExtras(extrasEmbeddables);
 
+        // HU-103: Convertir opciones de combo a embeddables
+        entity.setComponentesCombo(domain.getComponentesCombo().stream()
+            .map(this::seleccionDomainToEmbeddable)
+            .collect(Collectors.toList()));
+
         // Snapshot de clasificación del producto
         entity.setGrupoVarianteIdSnapshot(
             domain.getGrupoVarianteIdSnapshot() != null ? domain.getGrupoVarianteIdSnapshot().getValue() : null
@@ -133,6 +180,26 @@ public class ItemPedidoMapper {
         entity.setFechaAgregado(domain.getFechaAgregado());
         entity.setCantidadEnviadaCocina(domain.getCantidadEnviadaCocina());
 
+        // HU-110: Paso de servicio
+        entity.setTiempoServicio(domain.getTiempoServicio());
+
+        // HU-137: Urgencia
+        entity.setUrgente(domain.isUrgente());
+
+        // HU-139: Costo de envío
+        entity.setEnvio(domain.isEnvio());
+
+        // HU-147: Alergias del comensal
+        entity.setAdvertencias(new HashSet<>(domain.getAdvertencias()));
+
+        // HU-148: Ingredientes quitados
+        entity.setIngredientesQuitados(domain.getIngredientesQuitados().stream()
+            .map(i -> new IngredienteQuitadoEmbeddable(i.getInsumoId().getValue(), i.getNombre()))
+            .collect(Collectors.toCollection(ArrayList::new)));
+
+        // HU-164: Ítems del borrador del POS
+        entity.setBorradores(new HashSet<>(domain.getBorradores()));
+
         return entity;
     }
 
@@ -157,4 +224,28 @@ public class ItemPedidoMapper {
             embeddable.getPrecioSnapshot()
         );
     }
+
+    /**
+     * Convierte SeleccionCombo (Value Object de dominio) a SeleccionComboEmbeddable (JPA).
+     */
+    private SeleccionComboEmbeddable seleccionDomainToEmbeddable(SeleccionCombo domain) {
+        return new SeleccionComboEmbeddable(
+            domain.getComponente(),
+            domain.getProductoId().getValue(),
+            domain.getNombreProducto(),
+            domain.getPrecioReferencia()
+        );
+    }
+
+    /**
+     * Convierte SeleccionComboEmbeddable (JPA) a SeleccionCombo (Value Object de dominio).
+     */
+    private SeleccionCombo seleccionEmbeddableToDomain(SeleccionComboEmbeddable embeddable) {
+        return new SeleccionCombo(
+            embeddable.getComponente(),
+            new ProductoId(embeddable.getProductoId()),
+            embeddable.getNombreProducto(),
+            embeddable.getPrecioReferencia()
+        );
+    }
 }
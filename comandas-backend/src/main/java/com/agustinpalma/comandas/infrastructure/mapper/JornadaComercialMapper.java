@@ -0,0 +1,34 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.JornadaComercialEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio JornadaComercial y entidades JPA JornadaComercialEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class JornadaComercialMapper {
+
+    public JornadaComercial toDomain(JornadaComercialEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new JornadaComercial(
+            new LocalId(entity.getLocalId()),
+            entity.getHoraCorte()
+        );
+    }
+
+    public JornadaComercialEntity toEntity(JornadaComercial jornada) {
+        if (jornada == null) {
+            return null;
+        }
+        return new JornadaComercialEntity(
+            jornada.getLocalId().getValue(),
+            jornada.getHoraCorte()
+        );
+    }
+}
@@ -0,0 +1,61 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LinkPagoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.LinkPago;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.LinkPagoEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio LinkPago y entidades JPA LinkPagoEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class LinkPagoMapper {
+
+    public LinkPago toDomain(LinkPagoEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new LinkPago(
+            new LinkPagoId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            new PedidoId(entity.getPedidoId()),
+            new MesaId(entity.getMesaId()),
+            entity.getNumeroMesa(),
+            entity.getNumeroPedido(),
+            entity.getProveedor(),
+            entity.getMonto(),
+            entity.getTelefonoCliente(),
+            entity.getUrl(),
+            entity.getCreadoEn(),
+            entity.getEstado(),
+            entity.getPagadoEn(),
+            entity.getIdPagoPasarela()
+        );
+    }
+
+    public LinkPagoEntity toEntity(LinkPago link) {
+        if (link == null) {
+            return null;
+        }
+        return new LinkPagoEntity(
+            link.getId().getValue(),
+            link.getLocalId().getValue(),
+            link.getPedidoId().getValue(),
+            link.getMesaId().getValue(),
+            link.getNumeroMesa(),
+            link.getNumeroPedido(),
+            link.getProveedor(),
+            link.getMonto(),
+            link.getTelefonoCliente(),
+            link.getUrl(),
+            link.getCreadoEn(),
+            link.getEstado(),
+            link.getPagadoEn(),
+            link.getIdPagoPasarela()
+        );
+    }
+}
@@ -0,0 +1,69 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.ListaPreciosId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.ListaPrecios;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ListaPreciosEntity;
+import org.springframework.stereotype.Component;
+
+import java.math.BigDecimal;
+import java.util.HashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * Mapper entre entidades de dominio ListaPrecios y entidades JPA ListaPreciosEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class ListaPreciosMapper {
+
+    /**
+     * Convierte de entidad JPA a entidad de dominio.
+     *
+     * @param entity entidad JPA
+     * @return entidad de dominio reconstruida
+     */
+    public ListaPrecios toDomain(ListaPreciosEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+
+        Map<ProductoId, BigDecimal> precios = new HashMap<>();
+        entity.getPrecios().forEach((productoId, precio) -> precios.put(new ProductoId(productoId), precio));
+
+        return new ListaPrecios(
+            new ListaPreciosId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            entity.getCanal(),
+            entity.getNombre(),
+            entity.getPorcentajeRecargo(),
+            precios
+        );
+    }
+
+    /**
+     * Convierte de entidad de dominio a entidad JPA.
+     *
+     * @param lista entidad de dominio
+     * @return entidad JPA para persistencia
+     */
+    public ListaPreciosEntity toEntity(ListaPrecios lista) {
+        if (lista == null) {
+            return null;
+        }
+
+        Map<UUID, BigDecimal> precios = new HashMap<>();
+        lista.getPreciosEspeciales().forEach((productoId, precio) -> precios.put(productoId.getValue(), precio));
+
+        return new ListaPreciosEntity(
+            lista.getId().getValue(),
+            lista.getLocalId().getValue(),
+            lista.getCanal(),
+            lista.getNombre(),
+            lista.getPorcentajeRecargo(),
+            precios
+        );
+    }
+}
@@ -0,0 +1,63 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.MatrizPermisos;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.MatrizPermisosEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.PermisoEmpleadoEmbeddable;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.PermisoRolEmbeddable;
+import org.springframework.stereotype.Component;
+
+import java.util.EnumMap;
+import java.util.EnumSet;
+import java.util.HashMap;
+import java.util.HashSet;
+import java.util.Map;
+import java.util.Set;
+
+/**
+ * Mapper entre entidades de dominio MatrizPermisos y entidades JPA MatrizPermisosEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class MatrizPermisosMapper {
+
+    public MatrizPermisos toDomain(MatrizPermisosEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        Map<RolPersonal, Set<Permiso>> permisosPorRol = new EnumMap<>(RolPersonal.class);
+        for (PermisoRolEmbeddable permiso : entity.getPermisosPorRol()) {
+            permisosPorRol.computeIfAbsent(permiso.getRol(), rol -> EnumSet.noneOf(Permiso.class))
+                .add(permiso.getPermiso());
+        }
+        Map<MozoId, Map<Permiso, Boolean>> excepciones = new HashMap<>();
+        for (PermisoEmpleadoEmbeddable excepcion : entity.getExcepciones()) {
+            excepciones.computeIfAbsent(new MozoId(excepcion.getEmpleadoId()), id -> new EnumMap<>(Permiso.class))
+                .put(excepcion.getPermiso(), excepcion.isConcedido());
+        }
+        return new MatrizPermisos(
+            new LocalId(entity.getLocalId()),
+            entity.isActiva(),
+            permisosPorRol,
+            excepciones
+        );
+    }
+
+    public MatrizPermisosEntity toEntity(MatrizPermisos matriz) {
+        if (matriz == null) {
+            return null;
+        }
+        Set<PermisoRolEmbeddable> permisosPorRol = new HashSet<>();
+        matriz.getPermisosPorRol().forEach((rol, permisos) ->
+            permisos.forEach(permiso -> permisosPorRol.add(new PermisoRolEmbeddable(rol, permiso))));
+        Set<PermisoEmpleadoEmbeddable> excepciones = new HashSet<>();
+        matriz.getExcepciones().forEach((empleadoId, permisos) ->
+            permisos.forEach((permiso, concedido) ->
+                excepciones.add(new PermisoEmpleadoEmbeddable(empleadoId.getValue(), permiso, concedido))));
+        return new MatrizPermisosEntity(matriz.getLocalId().getValue(), matriz.isActiva(), permisosPorRol,
+            excepciones);
+    }
+}
@@ -0,0 +1,71 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MermaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Merma;
+import com.agustinpalma.comandas.domain.model.RenglonMerma;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.MermaEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.RenglonMermaEmbeddable;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio Merma y entidades JPA MermaEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class MermaMapper {
+
+    public Merma toDomain(MermaEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new Merma(
+            new MermaId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            entity.getFecha(),
+            entity.getCategoria(),
+            entity.getMotivo(),
+            entity.getResponsable(),
+            entity.getProductoId() != null ? new ProductoId(entity.getProductoId()) : null,
+            entity.getNombreProducto(),
+            entity.getUnidadesProducto(),
+            entity.getRenglones().stream()
+                .map(r -> new RenglonMerma(
+                    new InsumoId(r.getInsumoId()),
+                    r.getNombreInsumo(),
+                    r.getUnidad(),
+                    r.getCantidad(),
+                    r.getCostoUnitario()
+                ))
+                .toList()
+        );
+    }
+
+    public MermaEntity toEntity(Merma merma) {
+        if (merma == null) {
+            return null;
+        }
+        return new MermaEntity(
+            merma.getId().getValue(),
+            merma.getLocalId().getValue(),
+            merma.getFecha(),
+            merma.getCategoria(),
+            merma.getMotivo(),
+            merma.getResponsable(),
+            merma.esDeProducto() ? merma.getProductoId().getValue() : null,
+            merma.getNombreProducto(),
+            merma.getUnidadesProducto(),
+            merma.getRenglones().stream()
+                .map(r -> new RenglonMermaEmbeddable(
+                    r.getInsumoId().getValue(),
+                    r.getNombreInsumo(),
+                    r.getUnidad(),
+                    r.getCantidad(),
+                    r.getCostoUnitario()
+                ))
+                .toList()
+        );
+    }
+}
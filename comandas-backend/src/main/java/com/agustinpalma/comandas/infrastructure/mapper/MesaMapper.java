@@ -29,6 +29,8 @@ public class MesaMapper {
             new LocalId(entity.getLocalId()),
             entity.getNumero()
         );
+        mesa.ubicar(entity.getSector(), entity.getPosicionX(), entity.getPosicionY());
+        mesa.definirCapacidad(entity.getCapacidad());
 
         // Reconstituir el estado (forzar transición si es necesario)
         if (entity.getEstado() == com.agustinpalma.comandas.domain.model.DomainEnums.EstadoMesa.ABIERTA) {
@@ -53,7 +55,11 @@ public class MesaMapper {
             mesa.getId().getValue(),
             mesa.getLocalId().getValue(),
             mesa.getNumero(),
-            mesa.getEstado()
+            mesa.getEstado(),
+            mesa.getSector(),
+            mesa.getPosicionX(),
+            mesa.getPosicionY(),
+            mesa.getCapacidad()
         );
     }
 }
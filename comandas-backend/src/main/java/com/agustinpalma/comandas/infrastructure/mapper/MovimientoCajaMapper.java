@@ -1,7 +1,12 @@
 package com.agustinpalma.comandas.infrastructure.mapper;
 
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaEgresoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
 import com.agustinpalma.comandas.domain.model.MovimientoCaja;
 import com.agustinpalma.comandas.infrastructure.persistence.entity.MovimientoCajaEntity;
 import org.springframework.stereotype.Component;
@@ -32,7 +37,12 @@ public class MovimientoCajaMapper {
             entity.getDescripcion(),
             entity.getFecha(),
             entity.getTipo(),
-            entity.getNumeroComprobante()
+            entity.getNumeroComprobante(),
+            entity.getTurnoId() != null ? new TurnoCajaId(entity.getTurnoId()) : null,
+            entity.getPedidoId() != null ? new PedidoId(entity.getPedidoId()) : null,
+            entity.getAutorizadoPorId() != null ? new MozoId(entity.getAutorizadoPorId()) : null,
+            entity.getCategoriaEgresoId() != null ? new CategoriaEgresoId(entity.getCategoriaEgresoId()) : null,
+            entity.getCajaId() != null ? new CajaId(entity.getCajaId()) : null
         );
     }
 
@@ -47,14 +57,22 @@ public class MovimientoCajaMapper {
             return null;
         }
 
-        return new MovimientoCajaEntity(
+        MovimientoCajaEntity entity = new MovimientoCajaEntity(
             movimiento.getId().getValue(),
             movimiento.getLocalId().getValue(),
             movimiento.getMonto(),
             movimiento.getDescripcion(),
             movimiento.getFecha(),
             movimiento.getTipo(),
-            movimiento.getNumeroComprobante()
+            movimiento.getNumeroComprobante(),
+            movimiento.getTurnoId() != null ? movimiento.getTurnoId().getValue() : null
         );
+        entity.setPedidoId(movimiento.getPedidoId() != null ? movimiento.getPedidoId().getValue() : null);
+        entity.setAutorizadoPorId(
+            movimiento.getAutorizadoPorId() != null ? movimiento.getAutorizadoPorId().getValue() : null);
+        entity.setCategoriaEgresoId(
+            movimiento.getCategoriaId() != null ? movimiento.getCategoriaId().getValue() : null);
+        entity.setCajaId(movimiento.getCajaId() != null ? movimiento.getCajaId().getValue() : null);
+        return entity;
     }
 }
@@ -0,0 +1,47 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoCuenta;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCuentaCorrienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.MovimientoCuentaCorriente;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.MovimientoCuentaCorrienteEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper between MovimientoCuentaCorriente domain entity and its JPA entity.
+ * HU-104: Customer running accounts.
+ */
+@Component
+public class MovimientoCuentaCorrienteMapper {
+
+    public MovimientoCuentaCorriente toDomain(MovimientoCuentaCorrienteEntity entity) {
+        return new MovimientoCuentaCorriente(
+            new MovimientoCuentaCorrienteId(entity.getId()),
+            new ClienteId(entity.getClienteId()),
+            new LocalId(entity.getLocalId()),
+            TipoMovimientoCuenta.valueOf(entity.getTipo()),
+            entity.getMonto(),
+            entity.getFecha(),
+            entity.getPedidoId() != null ? new PedidoId(entity.getPedidoId()) : null,
+            entity.getMedioPago() != null ? MedioPago.valueOf(entity.getMedioPago()) : null,
+            entity.getDescripcion()
+        );
+    }
+
+    public MovimientoCuentaCorrienteEntity toEntity(MovimientoCuentaCorriente domain) {
+        return new MovimientoCuentaCorrienteEntity(
+            domain.getId().getValue(),
+            domain.getClienteId().getValue(),
+            domain.getLocalId().getValue(),
+            domain.getTipo().name(),
+            domain.getMonto(),
+            domain.getFecha(),
+            domain.getPedidoId() != null ? domain.getPedidoId().getValue() : null,
+            domain.getMedioPago() != null ? domain.getMedioPago().name() : null,
+            domain.getDescripcion()
+        );
+    }
+}
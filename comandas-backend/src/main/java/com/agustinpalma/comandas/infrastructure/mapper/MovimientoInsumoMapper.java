@@ -0,0 +1,46 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoInsumoId;
+import com.agustinpalma.comandas.domain.model.MovimientoInsumo;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.MovimientoInsumoEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio MovimientoInsumo y entidades JPA MovimientoInsumoEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class MovimientoInsumoMapper {
+
+    public MovimientoInsumo toDomain(MovimientoInsumoEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new MovimientoInsumo(
+            new MovimientoInsumoId(entity.getId()),
+            new InsumoId(entity.getInsumoId()),
+            new LocalId(entity.getLocalId()),
+            entity.getCantidad(),
+            entity.getTipo(),
+            entity.getFecha(),
+            entity.getMotivo()
+        );
+    }
+
+    public MovimientoInsumoEntity toEntity(MovimientoInsumo movimiento) {
+        if (movimiento == null) {
+            return null;
+        }
+        return new MovimientoInsumoEntity(
+            movimiento.getId().getValue(),
+            movimiento.getInsumoId().getValue(),
+            movimiento.getLocalId().getValue(),
+            movimiento.getCantidad(),
+            movimiento.getTipo(),
+            movimiento.getFecha(),
+            movimiento.getMotivo()
+        );
+    }
+}
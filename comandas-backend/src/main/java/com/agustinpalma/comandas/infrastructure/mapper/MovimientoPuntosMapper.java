@@ -0,0 +1,46 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoPuntos;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoPuntosId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.MovimientoPuntos;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.MovimientoPuntosEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper between MovimientoPuntos domain entity and its JPA entity.
+ * HU-105: Customer loyalty points.
+ */
+@Component
+public class MovimientoPuntosMapper {
+
+    public MovimientoPuntos toDomain(MovimientoPuntosEntity entity) {
+        return new MovimientoPuntos(
+            new MovimientoPuntosId(entity.getId()),
+            new ClienteId(entity.getClienteId()),
+            new LocalId(entity.getLocalId()),
+            TipoMovimientoPuntos.valueOf(entity.getTipo()),
+            entity.getPuntos(),
+            entity.getFecha(),
+            new PedidoId(entity.getPedidoId()),
+            entity.getFechaVencimiento(),
+            entity.getMontoDescuento()
+        );
+    }
+
+    public MovimientoPuntosEntity toEntity(MovimientoPuntos domain) {
+        return new MovimientoPuntosEntity(
+            domain.getId().getValue(),
+            domain.getClienteId().getValue(),
+            domain.getLocalId().getValue(),
+            domain.getTipo().name(),
+            domain.getPuntos(),
+            domain.getFecha(),
+            domain.getPedidoId().getValue(),
+            domain.getFechaVencimiento(),
+            domain.getMontoDescuento()
+        );
+    }
+}
@@ -0,0 +1,47 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.MozoEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio Mozo y entidades JPA MozoEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class MozoMapper {
+
+    public Mozo toDomain(MozoEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new Mozo(
+            new MozoId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            entity.getNombre(),
+            entity.isActivo(),
+            entity.getRol(),
+            entity.getPinHash(),
+            entity.getCodigoTarjeta(),
+            entity.getTopeConsumoMensual()
+        );
+    }
+
+    public MozoEntity toEntity(Mozo mozo) {
+        if (mozo == null) {
+            return null;
+        }
+        return new MozoEntity(
+            mozo.getId().getValue(),
+            mozo.getLocalId().getValue(),
+            mozo.getNombre(),
+            mozo.isActivo(),
+            mozo.getRol(),
+            mozo.getPinHash(),
+            mozo.getCodigoTarjeta(),
+            mozo.getTopeConsumoMensual()
+        );
+    }
+}
@@ -0,0 +1,96 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ItemPedidoEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.PagoEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.PedidoArchivadoEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.PedidoEntity;
+import com.fasterxml.jackson.annotation.JsonAutoDetect.Visibility;
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.PropertyAccessor;
+import com.fasterxml.jackson.databind.DeserializationFeature;
+import com.fasterxml.jackson.databind.ObjectMapper;
+import com.fasterxml.jackson.databind.SerializationFeature;
+import com.fasterxml.jackson.datatype.jsr310.JavaTimeModule;
+import org.springframework.stereotype.Component;
+
+import java.io.ByteArrayInputStream;
+import java.io.ByteArrayOutputStream;
+import java.io.IOException;
+import java.io.InputStream;
+import java.io.OutputStream;
+import java.time.LocalDateTime;
+import java.util.zip.GZIPInputStream;
+import java.util.zip.GZIPOutputStream;
+
+/**
+ * Mapper entre Pedido y su versión archivada (HU-162).
+ *
+ * El pedido pasa primero por PedidoMapper a PedidoEntity y esa entidad se
+ * guarda como JSON comprimido con gzip: así el archivo conserva exactamente
+ * lo mismo que la base operativa y, al leerlo, se reconstruye con el mismo
+ * mapper que usan los pedidos vigentes.
+ *
+ * El JSON se arma por campos (no por getters) y se ignoran los campos
+ * desconocidos, para que un pedido archivado con una versión anterior se
+ * pueda seguir leyendo después de agregar columnas.
+ */
+@Component
+public class PedidoArchivadoMapper {
+
+    private final PedidoMapper pedidoMapper;
+    private final ObjectMapper objectMapper;
+
+    public PedidoArchivadoMapper(PedidoMapper pedidoMapper) {
+        this.pedidoMapper = pedidoMapper;
+        this.objectMapper = new ObjectMapper();
+        this.objectMapper.registerModule(new JavaTimeModule());
+        this.objectMapper.disable(SerializationFeature.WRITE_DATES_AS_TIMESTAMPS);
+        this.objectMapper.disable(DeserializationFeature.FAIL_ON_UNKNOWN_PROPERTIES);
+        this.objectMapper.setVisibility(PropertyAccessor.ALL, Visibility.NONE);
+        this.objectMapper.setVisibility(PropertyAccessor.FIELD, Visibility.ANY);
+        // La referencia al pedido padre es circular y PedidoMapper no la usa al leer
+        this.objectMapper.addMixIn(ItemPedidoEntity.class, SinPedidoPadre.class);
+        this.objectMapper.addMixIn(PagoEntity.class, SinPedidoPadre.class);
+    }
+
+    public PedidoArchivadoEntity toEntity(Pedido pedido, LocalDateTime archivadoEn) {
+        return new PedidoArchivadoEntity(
+            pedido.getId().getValue(),
+            pedido.getLocalId().getValue(),
+            pedido.getNumero(),
+            pedido.getFechaApertura(),
+            pedido.getFechaCierre(),
+            pedido.getMontoTotalFinal(),
+            pedido.getNumeroComprobante(),
+            comprimir(pedidoMapper.toEntity(pedido)),
+            archivadoEn
+        );
+    }
+
+    public Pedido toDomain(PedidoArchivadoEntity entity) {
+        return pedidoMapper.toDomain(descomprimir(entity.getContenido()));
+    }
+
+    private byte[] comprimir(PedidoEntity pedido) {
+        ByteArrayOutputStream bytes = new ByteArrayOutputStream();
+        try (OutputStream gzip = new GZIPOutputStream(bytes)) {
+            objectMapper.writeValue(gzip, pedido);
+        } catch (IOException e) {
+            throw new RuntimeException("Error al comprimir el pedido " + pedido.getId() + " para archivarlo", e);
+        }
+        return bytes.toByteArray();
+    }
+
+    private PedidoEntity descomprimir(byte[] contenido) {
+        try (InputStream gzip = new GZIPInputStream(new ByteArrayInputStream(contenido))) {
+            return objectMapper.readValue(gzip, PedidoEntity.class);
+        } catch (IOException e) {
+            throw new RuntimeException("Error al leer un pedido archivado", e);
+        }
+    }
+
+    @JsonIgnoreProperties("pedido")
+    private abstract static class SinPedidoPadre {
+    }
+}
@@ -2,9 +2,17 @@ package com.agustinpalma.comandas.infrastructure.mapper;
 
 import com.agustinpalma.comandas.domain.model.DescuentoManual;
 import com.agustinpalma.comandas.domain.model.DomainEnums.ModoDescuento;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ListaPreciosId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PagoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ReservaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ZonaDeliveryId;
 import com.agustinpalma.comandas.domain.model.ItemPedido;
 import com.agustinpalma.comandas.domain.model.Pago;
 import com.agustinpalma.comandas.domain.model.Pedido;
@@ -14,7 +22,6 @@ import com.agustinpalma.comandas.infrastructure.persistence.entity.PedidoEntity;
 
 import java.util.ArrayList;
 import java.util.List;
-import java.util.UUID;
 
 import org.springframework.stereotype.Component;
 
@@ -25,6 +32,7 @@ import org.springframework.stereotype.Component;
  * HU-07: Utiliza la relación @OneToMany para persistencia atómica de pedido + ítems.
  * HU-14: Mapea descuento global dinámico (DescuentoManual VO <-> campos DB).
  * Cierre: Mapea pagos y campos de snapshot contable.
+ * HU-108: Mapea canal de venta y lista de precios usada.
  */
 @Component
 public class PedidoMapper {
@@ -67,9 +75,17 @@ public class PedidoMapper {
         List<Pago> pagos = new ArrayList<>();
         for (PagoEntity pagoEntity : entity.getPagos()) {
             pagos.add(new Pago(
+                new PagoId(pagoEntity.getId()),  // HU-160
                 pagoEntity.getMedioPago(),
                 pagoEntity.getMonto(),
-                pagoEntity.getFecha()
+                pagoEntity.getFecha(),
+                pagoEntity.getRecargo(),
+                pagoEntity.getMoneda(),
+                pagoEntity.getMontoOriginal() != null ? pagoEntity.getMontoOriginal() : pagoEntity.getMonto(),
+                pagoEntity.getTipoCambio(),
+                pagoEntity.getAjusteRedondeo(),
+                pagoEntity.getReferencia(),
+                pagoEntity.getCajaId() != null ? new CajaId(pagoEntity.getCajaId()) : null  // HU-159
             ));
         }
 
@@ -81,7 +97,10 @@ public class PedidoMapper {
                 entity.getDescGlobalValor(),
                 entity.getDescGlobalRazon(),
                 entity.getDescGlobalUsuarioId(),
-                entity.getDescGlobalFecha()
+                entity.getDescGlobalFecha(),
+                entity.getDescGlobalAutorizadoPorId() != null
+                    ? new MozoId(entity.getDescGlobalAutorizadoPorId()) : null,
+                entity.getDescGlobalAutorizadoPor()
             );
         }
 
@@ -100,7 +119,34 @@ public class PedidoMapper {
             entity.getMontoSubtotalFinal(),
             entity.getMontoDescuentosFinal(),
             entity.getMontoTotalFinal(),
-            entity.getUltimoEnvioCocina()  // HU-29: Reconstruir timestamp
+            entity.getUltimoEnvioCocina(),  // HU-29: Reconstruir timestamp
+            entity.getPuntosCanjeados(),    // HU-105: Canje de puntos
+            entity.getMontoCanjePuntos(),
+            entity.getTurnoId() != null ? new TurnoCajaId(entity.getTurnoId()) : null,  // HU-106
+            entity.getCanal(),  // HU-108: null en pedidos previos, el dominio asume SALON
+            entity.getListaPreciosId() != null ? new ListaPreciosId(entity.getListaPreciosId()) : null,
+            entity.getNombreListaPrecios(),
+            entity.getTiempoEnMarcha(),  // HU-110: null hasta el primer envío con pasos
+            entity.getMozoId() != null ? new MozoId(entity.getMozoId()) : null,  // HU-111
+            entity.getCubiertos(),
+            entity.getPropina(),
+            entity.getUnidadesAnuladas(),
+            entity.getMontoAnulado(),
+            entity.getUltimaActividad(),  // HU-113: null en pedidos previos, el dominio usa la apertura
+            entity.isUrgente(),           // HU-137
+            entity.getHoraPrometida(),    // HU-138
+            entity.getDireccionEntrega(), // HU-139
+            entity.getZonaDeliveryId() != null ? new ZonaDeliveryId(entity.getZonaDeliveryId()) : null,
+            entity.getRepartidorId() != null ? new MozoId(entity.getRepartidorId()) : null,  // HU-140
+            entity.getSalidaReparto(),
+            entity.getRegresoReparto(),
+            entity.getReservaId() != null ? new ReservaId(entity.getReservaId()) : null,  // HU-142
+            entity.getMontoSenia(),
+            entity.getCajaId() != null ? new CajaId(entity.getCajaId()) : null,  // HU-159
+            entity.getVersion(),  // HU-165
+            entity.getNumeroComprobante(),  // HU-166
+            entity.getClienteId() != null ? new ClienteId(entity.getClienteId()) : null,  // HU-168
+            entity.getMontoPromoCumpleanios()
         );
     }
 
@@ -137,12 +183,16 @@ public class PedidoMapper {
             entity.setDescGlobalRazon(dg.getRazon());
             entity.setDescGlobalUsuarioId(dg.getUsuarioId());
             entity.setDescGlobalFecha(dg.getFechaAplicacion());
+            entity.setDescGlobalAutorizadoPorId(dg.getAutorizadoPorId() != null ? dg.getAutorizadoPorId().getValue() : null);
+            entity.setDescGlobalAutorizadoPor(dg.getAutorizadoPor());
         } else {
             entity.setDescGlobalTipo(null);
             entity.setDescGlobalValor(null);
             entity.setDescGlobalRazon(null);
             entity.setDescGlobalUsuarioId(null);
             entity.setDescGlobalFecha(null);
+            entity.setDescGlobalAutorizadoPorId(null);
+            entity.setDescGlobalAutorizadoPor(null);
         }
 
         // Snapshot contable
@@ -153,6 +203,63 @@ public class PedidoMapper {
         // HU-29: Persistir timestamp de último envío a cocina
         entity.setUltimoEnvioCocina(pedido.getUltimoEnvioCocina());
 
+        // HU-105: Persistir canje de puntos
+        entity.setPuntosCanjeados(pedido.getPuntosCanjeados());
+        entity.setMontoCanjePuntos(pedido.tieneCanjePuntos() ? pedido.getMontoCanjePuntos() : null);
+
+        // HU-106: Persistir turno de caja
+        entity.setTurnoId(pedido.getTurnoId() != null ? pedido.getTurnoId().getValue() : null);
+
+        // HU-108: Persistir canal y lista de precios congelada
+        entity.setCanal(pedido.getCanal());
+        entity.setListaPreciosId(pedido.getListaPreciosId() != null ? pedido.getListaPreciosId().getValue() : null);
+        entity.setNombreListaPrecios(pedido.getNombreListaPrecios());
+
+        // HU-110: Persistir paso en marcha
+        entity.setTiempoEnMarcha(pedido.getTiempoEnMarcha());
+
+        // HU-111: Persistir atención y anulaciones
+        entity.setMozoId(pedido.getMozoId() != null ? pedido.getMozoId().getValue() : null);
+        entity.setCubiertos(pedido.getCubiertos());
+        entity.setPropina(pedido.getPropina());
+        entity.setUnidadesAnuladas(pedido.getUnidadesAnuladas());
+        entity.setMontoAnulado(pedido.getMontoAnulado());
+
+        // HU-113: Persistir última actividad de la mesa
+        entity.setUltimaActividad(pedido.getUltimaActividad());
+
+        // HU-137: Persistir marca de urgencia
+        entity.setUrgente(pedido.isUrgente());
+
+        // HU-138: Persistir horario de retiro prometido
+        entity.setHoraPrometida(pedido.getHoraPrometida());
+
+        // HU-139: Persistir entrega de delivery
+        entity.setDireccionEntrega(pedido.getDireccionEntrega());
+        entity.setZonaDeliveryId(pedido.getZonaDeliveryId() != null ? pedido.getZonaDeliveryId().getValue() : null);
+
+        // HU-140: Persistir reparto
+        entity.setRepartidorId(pedido.getRepartidorId() != null ? pedido.getRepartidorId().getValue() : null);
+        entity.setSalidaReparto(pedido.getSalidaReparto());
+        entity.setRegresoReparto(pedido.getRegresoReparto());
+
+        // HU-142: Persistir reserva y seña
+        entity.setReservaId(pedido.getReservaId() != null ? pedido.getReservaId().getValue() : null);
+        entity.setMontoSenia(pedido.getMontoSenia());
+
+        // HU-159: Persistir caja del cobro
+        entity.setCajaId(pedido.getCajaId() != null ? pedido.getCajaId().getValue() : null);
+
+        // HU-165: Versión para detectar ediciones desde otra terminal
+        entity.setVersion(pedido.getVersion());
+
+        // HU-166: Número de comprobante de la serie de la caja
+        entity.setNumeroComprobante(pedido.getNumeroComprobante());
+
+        // HU-168: Persistir cliente vinculado y promo de cumpleaños
+        entity.setClienteId(pedido.getClienteId() != null ? pedido.getClienteId().getValue() : null);
+        entity.setMontoPromoCumpleanios(pedido.tienePromoCumpleanios() ? pedido.getMontoPromoCumpleanios() : null);
+
         // Convertir y agregar ítems
         for (ItemPedido item : pedido.getItems()) {
             ItemPedidoEntity itemEntity = itemPedidoMapper.toEntity(item);
@@ -162,10 +269,17 @@ public class PedidoMapper {
         // Convertir y agregar pagos
         for (Pago pago : pedido.getPagos()) {
             PagoEntity pagoEntity = new PagoEntity(
-                UUID.randomUUID(),  // Generar UUID manualmente para consistencia arquitectónica
+                pago.getId().getValue(),
                 pago.getMedio(),
                 pago.getMonto(),
-                pago.getFecha()
+                pago.getFecha(),
+                pago.getRecargo(),
+                pago.getMoneda(),
+                pago.getMontoOriginal(),
+                pago.getTipoCambio(),
+                pago.getAjusteRedondeo(),
+                pago.getReferencia(),
+                pago.getCajaId() != null ? pago.getCajaId().getValue() : null
             );
             entity.agregarPago(pagoEntity);
         }
@@ -0,0 +1,38 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ConceptoContable;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.PlanCuentasContable;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.PlanCuentasContableEntity;
+import org.springframework.stereotype.Component;
+
+import java.util.EnumMap;
+import java.util.Map;
+
+/**
+ * Mapper entre entidades de dominio PlanCuentasContable y entidades JPA PlanCuentasContableEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class PlanCuentasContableMapper {
+
+    public PlanCuentasContable toDomain(PlanCuentasContableEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new PlanCuentasContable(
+            new LocalId(entity.getLocalId()),
+            entity.getCuentas()
+        );
+    }
+
+    public PlanCuentasContableEntity toEntity(PlanCuentasContable plan) {
+        if (plan == null) {
+            return null;
+        }
+        // EnumMap(Map) falla con un mapa vacío que no es EnumMap
+        Map<ConceptoContable, String> cuentas = new EnumMap<>(ConceptoContable.class);
+        cuentas.putAll(plan.getCuentas());
+        return new PlanCuentasContableEntity(plan.getLocalId().getValue(), cuentas);
+    }
+}
@@ -1,15 +1,27 @@
 package com.agustinpalma.comandas.infrastructure.mapper;
 
+import com.agustinpalma.comandas.domain.model.ComponenteCombo;
 import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.FranjaHoraria;
 import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ComboOpcionEmbeddable;
 import com.agustinpalma.comandas.infrastructure.persistence.entity.ProductoEntity;
 import org.springframework.stereotype.Component;
 
+import java.util.ArrayList;
+import java.util.HashSet;
+import java.util.List;
+import java.util.Map;
+import java.util.TreeMap;
+
 /**
  * Mapper entre la entidad de dominio Producto y la entidad JPA ProductoEntity.
  * Convierte entre objetos de negocio y objetos de persistencia.
+ *
+ * HU-103: Los componentes del combo se aplanan en filas (componente, opción) y se
+ * reagrupan por orden de componente al reconstruir el dominio.
  */
 @Component
 public class ProductoMapper {
@@ -21,7 +33,7 @@ public class ProductoMapper {
      * @return objeto de dominio
      */
     public Producto toDomain(ProductoEntity entity) {
-        return new Producto(
+        Producto producto = new Producto(
             new ProductoId(entity.getId()),
             new LocalId(entity.getLocalId()),
             entity.getNombre(),
@@ -38,6 +50,13 @@ public class ProductoMapper {
             entity.getStockActual(),
             entity.isControlaStock()
         );
+        producto.definirStockMinimo(entity.getStockMinimo());
+        producto.marcarAgotado(entity.isAgotado());
+        producto.definirEtiquetaVariante(entity.getEtiquetaVariante());
+        producto.definirComponentesCombo(componentesToDomain(entity.getOpcionesCombo()));
+        producto.definirAlergenos(entity.getAlergenos());
+        producto.definirFranjaHoraria(FranjaHoraria.de(entity.getHorarioDesde(), entity.getHorarioHasta()));
+        return producto;
     }
 
     /**
@@ -47,7 +66,7 @@ public class ProductoMapper {
      * @return entidad JPA
      */
     public ProductoEntity toEntity(Producto domain) {
-        return new ProductoEntity(
+        ProductoEntity entity = new ProductoEntity(
             domain.getId().getValue(),
             domain.getLocalId().getValue(),
             domain.getNombre(),
@@ -64,5 +83,47 @@ public class ProductoMapper {
             domain.getStockActual(),
             domain.isControlaStock()
         );
+        entity.setStockMinimo(domain.getStockMinimo());
+        entity.setAgotado(domain.isAgotado());
+        entity.setEtiquetaVariante(domain.getEtiquetaVariante());
+        entity.setOpcionesCombo(componentesToEmbeddables(domain.getComponentesCombo()));
+        entity.setAlergenos(new HashSet<>(domain.getAlergenos()));
+        FranjaHoraria horario = domain.getFranjaHoraria();
+        entity.setHorarioDesde(horario != null ? horario.getDesde() : null);
+        entity.setHorarioHasta(horario != null ? horario.getHasta() : null);
+        return entity;
+    }
+
+    /**
+     * Aplana los componentes del combo en una fila por opción.
+     */
+    private List<ComboOpcionEmbeddable> componentesToEmbeddables(List<ComponenteCombo> componentes) {
+        List<ComboOpcionEmbeddable> filas = new ArrayList<>();
+        for (int orden = 0; orden < componentes.size(); orden++) {
+            ComponenteCombo componente = componentes.get(orden);
+            for (ProductoId opcion : componente.getOpciones()) {
+                filas.add(new ComboOpcionEmbeddable(orden, componente.getNombre(), opcion.getValue()));
+            }
+        }
+        return filas;
+    }
+
+    /**
+     * Reagrupa las filas por orden de componente, preservando el orden de las opciones.
+     */
+    private List<ComponenteCombo> componentesToDomain(List<ComboOpcionEmbeddable> filas) {
+        if (filas == null || filas.isEmpty()) {
+            return List.of();
+        }
+        Map<Integer, List<ComboOpcionEmbeddable>> porComponente = new TreeMap<>();
+        for (ComboOpcionEmbeddable fila : filas) {
+            porComponente.computeIfAbsent(fila.getComponenteOrden(), k -> new ArrayList<>()).add(fila);
+        }
+        return porComponente.values().stream()
+            .map(opciones -> new ComponenteCombo(
+                opciones.get(0).getComponenteNombre(),
+                opciones.stream().map(o -> new ProductoId(o.getOpcionProductoId())).toList()
+            ))
+            .toList();
     }
 }
@@ -0,0 +1,34 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.ProgramaPuntos;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ProgramaPuntosEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper between ProgramaPuntos domain model and its JPA entity.
+ * HU-105: Customer loyalty points.
+ */
+@Component
+public class ProgramaPuntosMapper {
+
+    public ProgramaPuntos toDomain(ProgramaPuntosEntity entity) {
+        return new ProgramaPuntos(
+            new LocalId(entity.getLocalId()),
+            entity.isActivo(),
+            entity.getMontoPorPunto(),
+            entity.getValorPunto(),
+            entity.getDiasVencimiento()
+        );
+    }
+
+    public ProgramaPuntosEntity toEntity(ProgramaPuntos domain) {
+        return new ProgramaPuntosEntity(
+            domain.getLocalId().getValue(),
+            domain.isActivo(),
+            domain.getMontoPorPunto(),
+            domain.getValorPunto(),
+            domain.getDiasVencimiento()
+        );
+    }
+}
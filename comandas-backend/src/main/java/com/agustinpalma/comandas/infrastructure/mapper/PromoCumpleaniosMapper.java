@@ -0,0 +1,32 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.PromoCumpleanios;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.PromoCumpleaniosEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper between PromoCumpleanios domain model and its JPA entity.
+ * HU-168: Customer profile and birthday promo.
+ */
+@Component
+public class PromoCumpleaniosMapper {
+
+    public PromoCumpleanios toDomain(PromoCumpleaniosEntity entity) {
+        return new PromoCumpleanios(
+            new LocalId(entity.getLocalId()),
+            entity.isActiva(),
+            entity.getPorcentaje(),
+            entity.getTopeDescuento()
+        );
+    }
+
+    public PromoCumpleaniosEntity toEntity(PromoCumpleanios domain) {
+        return new PromoCumpleaniosEntity(
+            domain.getLocalId().getValue(),
+            domain.isActiva(),
+            domain.getPorcentaje(),
+            domain.getTopeDescuento()
+        );
+    }
+}
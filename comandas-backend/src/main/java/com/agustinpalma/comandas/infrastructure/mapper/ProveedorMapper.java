@@ -0,0 +1,50 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.Cuit;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+import com.agustinpalma.comandas.domain.model.Proveedor;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ProveedorEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio Proveedor y entidades JPA ProveedorEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ *
+ * El CUIT se persiste como los 11 dígitos, sin guiones.
+ */
+@Component
+public class ProveedorMapper {
+
+    public Proveedor toDomain(ProveedorEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new Proveedor(
+            new ProveedorId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            entity.getNombre(),
+            entity.getCuit() != null ? Cuit.of(entity.getCuit()) : null,
+            entity.getTelefono(),
+            entity.getEmail(),
+            entity.getPlazoPagoDias(),
+            entity.isActivo()
+        );
+    }
+
+    public ProveedorEntity toEntity(Proveedor proveedor) {
+        if (proveedor == null) {
+            return null;
+        }
+        return new ProveedorEntity(
+            proveedor.getId().getValue(),
+            proveedor.getLocalId().getValue(),
+            proveedor.getNombre(),
+            proveedor.getCuit() != null ? proveedor.getCuit().getNumero() : null,
+            proveedor.getTelefono(),
+            proveedor.getEmail(),
+            proveedor.getPlazoPagoDias(),
+            proveedor.isActivo()
+        );
+    }
+}
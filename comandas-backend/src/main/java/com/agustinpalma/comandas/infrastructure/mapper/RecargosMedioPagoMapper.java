@@ -0,0 +1,39 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.RecargosMedioPago;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.RecargosMedioPagoEntity;
+import org.springframework.stereotype.Component;
+
+import java.math.BigDecimal;
+import java.util.EnumMap;
+import java.util.Map;
+
+/**
+ * Mapper entre entidades de dominio RecargosMedioPago y entidades JPA RecargosMedioPagoEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class RecargosMedioPagoMapper {
+
+    public RecargosMedioPago toDomain(RecargosMedioPagoEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new RecargosMedioPago(
+            new LocalId(entity.getLocalId()),
+            entity.getPorcentajes()
+        );
+    }
+
+    public RecargosMedioPagoEntity toEntity(RecargosMedioPago recargos) {
+        if (recargos == null) {
+            return null;
+        }
+        // EnumMap(Map) falla con un mapa vacío que no es EnumMap
+        Map<MedioPago, BigDecimal> porcentajes = new EnumMap<>(MedioPago.class);
+        porcentajes.putAll(recargos.getPorcentajes());
+        return new RecargosMedioPagoEntity(recargos.getLocalId().getValue(), porcentajes);
+    }
+}
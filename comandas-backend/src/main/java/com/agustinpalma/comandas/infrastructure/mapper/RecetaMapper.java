@@ -0,0 +1,44 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.IngredienteReceta;
+import com.agustinpalma.comandas.domain.model.Receta;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.IngredienteRecetaEmbeddable;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.RecetaEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio Receta y entidades JPA RecetaEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class RecetaMapper {
+
+    public Receta toDomain(RecetaEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new Receta(
+            new ProductoId(entity.getProductoId()),
+            new LocalId(entity.getLocalId()),
+            entity.getIngredientes().stream()
+                .map(i -> new IngredienteReceta(new InsumoId(i.getInsumoId()), i.getCantidad()))
+                .toList()
+        );
+    }
+
+    public RecetaEntity toEntity(Receta receta) {
+        if (receta == null) {
+            return null;
+        }
+        return new RecetaEntity(
+            receta.getProductoId().getValue(),
+            receta.getLocalId().getValue(),
+            receta.getIngredientes().stream()
+                .map(i -> new IngredienteRecetaEmbeddable(i.getInsumoId().getValue(), i.getCantidad()))
+                .toList()
+        );
+    }
+}
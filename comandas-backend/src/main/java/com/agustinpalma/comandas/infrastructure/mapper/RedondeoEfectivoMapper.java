@@ -0,0 +1,36 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.RedondeoEfectivo;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.RedondeoEfectivoEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio RedondeoEfectivo y entidades JPA RedondeoEfectivoEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class RedondeoEfectivoMapper {
+
+    public RedondeoEfectivo toDomain(RedondeoEfectivoEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new RedondeoEfectivo(
+            new LocalId(entity.getLocalId()),
+            entity.getMultiplo(),
+            entity.getModo()
+        );
+    }
+
+    public RedondeoEfectivoEntity toEntity(RedondeoEfectivo redondeo) {
+        if (redondeo == null) {
+            return null;
+        }
+        return new RedondeoEfectivoEntity(
+            redondeo.getLocalId().getValue(),
+            redondeo.getMultiplo(),
+            redondeo.getModo()
+        );
+    }
+}
@@ -0,0 +1,38 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.ReglaPropinas;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ReglaPropinasEntity;
+import org.springframework.stereotype.Component;
+
+import java.util.EnumMap;
+
+/**
+ * Mapper entre entidades de dominio ReglaPropinas y entidades JPA ReglaPropinasEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class ReglaPropinasMapper {
+
+    public ReglaPropinas toDomain(ReglaPropinasEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new ReglaPropinas(
+            new LocalId(entity.getLocalId()),
+            entity.getModo(),
+            entity.getPuntosPorRol()
+        );
+    }
+
+    public ReglaPropinasEntity toEntity(ReglaPropinas regla) {
+        if (regla == null) {
+            return null;
+        }
+        return new ReglaPropinasEntity(
+            regla.getLocalId().getValue(),
+            regla.getModo(),
+            new EnumMap<>(regla.getPuntosPorRol())
+        );
+    }
+}
@@ -0,0 +1,51 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.RendicionRepartidorId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+import com.agustinpalma.comandas.domain.model.RendicionRepartidor;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.RendicionRepartidorEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio RendicionRepartidor y entidades JPA RendicionRepartidorEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class RendicionRepartidorMapper {
+
+    public RendicionRepartidor toDomain(RendicionRepartidorEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new RendicionRepartidor(
+            new RendicionRepartidorId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            new TurnoCajaId(entity.getTurnoId()),
+            new MozoId(entity.getRepartidorId()),
+            entity.getFecha(),
+            entity.getPedidos(),
+            entity.getEsperado(),
+            entity.getRendido(),
+            entity.getObservaciones()
+        );
+    }
+
+    public RendicionRepartidorEntity toEntity(RendicionRepartidor rendicion) {
+        if (rendicion == null) {
+            return null;
+        }
+        return new RendicionRepartidorEntity(
+            rendicion.getId().getValue(),
+            rendicion.getLocalId().getValue(),
+            rendicion.getTurnoId().getValue(),
+            rendicion.getRepartidorId().getValue(),
+            rendicion.getFecha(),
+            rendicion.getPedidos(),
+            rendicion.getEsperado(),
+            rendicion.getRendido(),
+            rendicion.getObservaciones()
+        );
+    }
+}
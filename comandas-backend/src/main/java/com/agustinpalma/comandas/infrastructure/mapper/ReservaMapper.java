@@ -0,0 +1,60 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ReservaId;
+import com.agustinpalma.comandas.domain.model.Reserva;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ReservaEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio Reserva y entidades JPA ReservaEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class ReservaMapper {
+
+    public Reserva toDomain(ReservaEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new Reserva(
+            new ReservaId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            entity.getNombreCliente(),
+            entity.getTelefono(),
+            entity.getFechaHora(),
+            entity.getPersonas(),
+            entity.getObservaciones(),
+            entity.getMesaId() != null ? new MesaId(entity.getMesaId()) : null,
+            entity.getEstado(),
+            entity.getMontoSenia(),
+            entity.getFechaSenia(),
+            entity.getMovimientoSeniaId() != null ? new MovimientoCajaId(entity.getMovimientoSeniaId()) : null,
+            entity.getPedidoId() != null ? new PedidoId(entity.getPedidoId()) : null
+        );
+    }
+
+    public ReservaEntity toEntity(Reserva reserva) {
+        if (reserva == null) {
+            return null;
+        }
+        return new ReservaEntity(
+            reserva.getId().getValue(),
+            reserva.getLocalId().getValue(),
+            reserva.getNombreCliente(),
+            reserva.getTelefono(),
+            reserva.getFechaHora(),
+            reserva.getPersonas(),
+            reserva.getObservaciones(),
+            reserva.getMesaId() != null ? reserva.getMesaId().getValue() : null,
+            reserva.getEstado(),
+            reserva.getMontoSenia(),
+            reserva.getFechaSenia(),
+            reserva.getMovimientoSeniaId() != null ? reserva.getMovimientoSeniaId().getValue() : null,
+            reserva.getPedidoId() != null ? reserva.getPedidoId().getValue() : null
+        );
+    }
+}
@@ -0,0 +1,39 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.SerieComprobantes;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.SerieComprobantesEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre la entidad de dominio SerieComprobantes y su entidad JPA.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class SerieComprobantesMapper {
+
+    public SerieComprobantes toDomain(SerieComprobantesEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new SerieComprobantes(
+            new LocalId(entity.getLocalId()),
+            entity.getCajaId() != null ? new CajaId(entity.getCajaId()) : null,
+            entity.getPrefijo(),
+            entity.getProximoNumero(),
+            entity.isReinicioAnual(),
+            entity.getAnioEnCurso()
+        );
+    }
+
+    /**
+     * Copia el estado de la serie sobre la entidad (nueva o ya persistida).
+     */
+    public void actualizar(SerieComprobantesEntity entity, SerieComprobantes serie) {
+        entity.setPrefijo(serie.getPrefijo());
+        entity.setProximoNumero(serie.getProximoNumero());
+        entity.setReinicioAnual(serie.isReinicioAnual());
+        entity.setAnioEnCurso(serie.getAnioEnCurso());
+    }
+}
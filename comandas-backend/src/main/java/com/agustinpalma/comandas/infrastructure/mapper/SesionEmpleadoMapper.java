@@ -0,0 +1,39 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.SesionEmpleado;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.SesionEmpleadoEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio SesionEmpleado y entidades JPA SesionEmpleadoEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class SesionEmpleadoMapper {
+
+    public SesionEmpleado toDomain(SesionEmpleadoEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new SesionEmpleado(
+            entity.getHashToken(),
+            new LocalId(entity.getLocalId()),
+            new MozoId(entity.getEmpleadoId()),
+            entity.getVenceEn()
+        );
+    }
+
+    public SesionEmpleadoEntity toEntity(SesionEmpleado sesion) {
+        if (sesion == null) {
+            return null;
+        }
+        return new SesionEmpleadoEntity(
+            sesion.getHashToken(),
+            sesion.getLocalId().getValue(),
+            sesion.getEmpleadoId().getValue(),
+            sesion.getVenceEn()
+        );
+    }
+}
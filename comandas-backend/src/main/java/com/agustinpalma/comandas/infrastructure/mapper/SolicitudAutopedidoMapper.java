@@ -0,0 +1,72 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.SolicitudAutopedidoId;
+import com.agustinpalma.comandas.domain.model.ItemSolicitudAutopedido;
+import com.agustinpalma.comandas.domain.model.SolicitudAutopedido;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ItemSolicitudAutopedidoEmbeddable;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.SolicitudAutopedidoEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio SolicitudAutopedido y entidades JPA SolicitudAutopedidoEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class SolicitudAutopedidoMapper {
+
+    public SolicitudAutopedido toDomain(SolicitudAutopedidoEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new SolicitudAutopedido(
+            new SolicitudAutopedidoId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            new MesaId(entity.getMesaId()),
+            entity.getNumeroMesa(),
+            entity.getNombreCliente(),
+            entity.getItems().stream()
+                .map(i -> new ItemSolicitudAutopedido(
+                    new ProductoId(i.getProductoId()),
+                    i.getNombreProducto(),
+                    i.getPrecioUnitario(),
+                    i.getCantidad(),
+                    i.getObservacion()))
+                .toList(),
+            entity.getCreadaEn(),
+            entity.getEstado(),
+            entity.getResueltaEn(),
+            entity.getResueltaPorId() != null ? new MozoId(entity.getResueltaPorId()) : null,
+            entity.getMotivoRechazo()
+        );
+    }
+
+    public SolicitudAutopedidoEntity toEntity(SolicitudAutopedido solicitud) {
+        if (solicitud == null) {
+            return null;
+        }
+        return new SolicitudAutopedidoEntity(
+            solicitud.getId().getValue(),
+            solicitud.getLocalId().getValue(),
+            solicitud.getMesaId().getValue(),
+            solicitud.getNumeroMesa(),
+            solicitud.getNombreCliente(),
+            solicitud.getCreadaEn(),
+            solicitud.getEstado(),
+            solicitud.getResueltaEn(),
+            solicitud.getResueltaPorId() != null ? solicitud.getResueltaPorId().getValue() : null,
+            solicitud.getMotivoRechazo(),
+            solicitud.getItems().stream()
+                .map(i -> new ItemSolicitudAutopedidoEmbeddable(
+                    i.getProductoId().getValue(),
+                    i.getNombreProducto(),
+                    i.getPrecioUnitario(),
+                    i.getCantidad(),
+                    i.getObservacion()))
+                .toList()
+        );
+    }
+}
@@ -0,0 +1,61 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.ConsumoTarjetaRegalo;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TarjetaRegaloId;
+import com.agustinpalma.comandas.domain.model.TarjetaRegalo;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ConsumoTarjetaRegaloEmbeddable;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.TarjetaRegaloEntity;
+import org.springframework.stereotype.Component;
+
+import java.util.ArrayList;
+
+/**
+ * Mapper entre entidades de dominio TarjetaRegalo y entidades JPA TarjetaRegaloEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class TarjetaRegaloMapper {
+
+    public TarjetaRegalo toDomain(TarjetaRegaloEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new TarjetaRegalo(
+            new TarjetaRegaloId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            entity.getCodigo(),
+            entity.getMontoInicial(),
+            entity.getFechaEmision(),
+            entity.getVencimiento(),
+            entity.getComprador(),
+            entity.getDestinatario(),
+            entity.getMovimientoCajaId() != null ? new MovimientoCajaId(entity.getMovimientoCajaId()) : null,
+            entity.getConsumos().stream()
+                .map(c -> new ConsumoTarjetaRegalo(new PedidoId(c.getPedidoId()), c.getMonto(), c.getFecha()))
+                .toList()
+        );
+    }
+
+    public TarjetaRegaloEntity toEntity(TarjetaRegalo tarjeta) {
+        if (tarjeta == null) {
+            return null;
+        }
+        return new TarjetaRegaloEntity(
+            tarjeta.getId().getValue(),
+            tarjeta.getLocalId().getValue(),
+            tarjeta.getCodigo(),
+            tarjeta.getMontoInicial(),
+            tarjeta.getFechaEmision(),
+            tarjeta.getVencimiento(),
+            tarjeta.getComprador(),
+            tarjeta.getDestinatario(),
+            tarjeta.getMovimientoCajaId() != null ? tarjeta.getMovimientoCajaId().getValue() : null,
+            new ArrayList<>(tarjeta.getConsumos().stream()
+                .map(c -> new ConsumoTarjetaRegaloEmbeddable(c.getPedidoId().getValue(), c.getMonto(), c.getFecha()))
+                .toList())
+        );
+    }
+}
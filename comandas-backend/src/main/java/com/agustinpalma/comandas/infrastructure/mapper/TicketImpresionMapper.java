@@ -4,6 +4,7 @@ import com.agustinpalma.comandas.application.dto.AjusteEconomicoDTO;
 import com.agustinpalma.comandas.application.dto.ComandaImpresionResponse;
 import com.agustinpalma.comandas.application.dto.ComandaImpresionResponse.HeaderComanda;
 import com.agustinpalma.comandas.application.dto.ComandaImpresionResponse.ItemComanda;
+import com.agustinpalma.comandas.application.dto.ComponenteComboDetalleDTO;
 import com.agustinpalma.comandas.application.dto.DetallePedidoResponse;
 import com.agustinpalma.comandas.application.dto.ItemDetalleDTO;
 import com.agustinpalma.comandas.application.dto.TicketImpresionResponse;
@@ -145,7 +146,10 @@ public class TicketImpresionMapper {
         return new ItemComanda(
                 item.cantidad(),
                 item.nombreProducto(),
-                item.observacion()
+                item.observacion(),
+                item.componentesCombo().stream()
+                        .map(ComponenteComboDetalleDTO::descripcion)
+                        .toList()
         );
     }
 
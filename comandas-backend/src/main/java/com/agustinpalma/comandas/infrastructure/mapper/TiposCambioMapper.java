@@ -0,0 +1,44 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.Moneda;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.TiposCambio;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.TiposCambioEntity;
+import org.springframework.stereotype.Component;
+
+import java.math.BigDecimal;
+import java.util.EnumMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * Mapper entre entidades de dominio TiposCambio y entidades JPA TiposCambioEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class TiposCambioMapper {
+
+    public TiposCambio toDomain(TiposCambioEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new TiposCambio(
+            new LocalId(entity.getLocalId()),
+            entity.getFecha(),
+            entity.getCotizaciones()
+        );
+    }
+
+    /**
+     * @param id id de la fila existente para ese local y día, o uno nuevo
+     */
+    public TiposCambioEntity toEntity(TiposCambio tiposCambio, UUID id) {
+        if (tiposCambio == null) {
+            return null;
+        }
+        // EnumMap(Map) falla con un mapa vacío que no es EnumMap
+        Map<Moneda, BigDecimal> cotizaciones = new EnumMap<>(Moneda.class);
+        cotizaciones.putAll(tiposCambio.getCotizaciones());
+        return new TiposCambioEntity(id, tiposCambio.getLocalId().getValue(), tiposCambio.getFecha(), cotizaciones);
+    }
+}
@@ -0,0 +1,62 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.ConteoInsumo;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TomaInventarioId;
+import com.agustinpalma.comandas.domain.model.TomaInventario;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ConteoInsumoEmbeddable;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.TomaInventarioEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio TomaInventario y entidades JPA TomaInventarioEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class TomaInventarioMapper {
+
+    public TomaInventario toDomain(TomaInventarioEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new TomaInventario(
+            new TomaInventarioId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            entity.getFecha(),
+            entity.getResponsable(),
+            entity.getConteos().stream()
+                .map(c -> new ConteoInsumo(
+                    new InsumoId(c.getInsumoId()),
+                    c.getNombreInsumo(),
+                    c.getUnidad(),
+                    c.getStockTeorico(),
+                    c.getStockContado(),
+                    c.getCostoUnitario()
+                ))
+                .toList()
+        );
+    }
+
+    public TomaInventarioEntity toEntity(TomaInventario toma) {
+        if (toma == null) {
+            return null;
+        }
+        return new TomaInventarioEntity(
+            toma.getId().getValue(),
+            toma.getLocalId().getValue(),
+            toma.getFecha(),
+            toma.getResponsable(),
+            toma.getConteos().stream()
+                .map(c -> new ConteoInsumoEmbeddable(
+                    c.getInsumoId().getValue(),
+                    c.getNombreInsumo(),
+                    c.getUnidad(),
+                    c.getStockTeorico(),
+                    c.getStockContado(),
+                    c.getCostoUnitario()
+                ))
+                .toList()
+        );
+    }
+}
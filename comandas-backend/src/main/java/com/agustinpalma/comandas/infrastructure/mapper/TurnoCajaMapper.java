@@ -0,0 +1,90 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.ConteoDenominacion;
+import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ConteoDenominacionEmbeddable;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.TurnoCajaEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio TurnoCaja y entidades JPA TurnoCajaEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class TurnoCajaMapper {
+
+    /**
+     * Convierte de entidad JPA a entidad de dominio.
+     * Usa el constructor de reconstrucción, que respeta el arqueo ya congelado.
+     *
+     * @param entity entidad JPA
+     * @return entidad de dominio reconstruida
+     */
+    public TurnoCaja toDomain(TurnoCajaEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+
+        return new TurnoCaja(
+            new TurnoCajaId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            new JornadaCajaId(entity.getJornadaId()),
+            entity.getTipo(),
+            entity.getMontoInicial(),
+            entity.getFechaApertura(),
+            entity.getFechaCierre(),
+            entity.getTotalVentas(),
+            entity.getVentasEfectivo(),
+            entity.getTotalIngresos(),
+            entity.getTotalEgresos(),
+            entity.getEfectivoEsperado(),
+            entity.getEfectivoDeclarado(),
+            entity.getPedidosCerradosCount(),
+            entity.getEstado(),
+            entity.getDenominaciones().stream()
+                .map(d -> new ConteoDenominacion(d.getDenominacion(), d.getCantidad()))
+                .toList(),
+            entity.getResponsableId() != null ? new MozoId(entity.getResponsableId()) : null,
+            entity.getObservaciones()
+        );
+    }
+
+    /**
+     * Convierte de entidad de dominio a entidad JPA.
+     *
+     * @param turno entidad de dominio
+     * @return entidad JPA para persistencia
+     */
+    public TurnoCajaEntity toEntity(TurnoCaja turno) {
+        if (turno == null) {
+            return null;
+        }
+
+        return new TurnoCajaEntity(
+            turno.getId().getValue(),
+            turno.getLocalId().getValue(),
+            turno.getJornadaId().getValue(),
+            turno.getTipo(),
+            turno.getMontoInicial(),
+            turno.getFechaApertura(),
+            turno.getFechaCierre(),
+            turno.getTotalVentas(),
+            turno.getVentasEfectivo(),
+            turno.getTotalIngresos(),
+            turno.getTotalEgresos(),
+            turno.getEfectivoEsperado(),
+            turno.getEfectivoDeclarado(),
+            turno.getPedidosCerradosCount(),
+            turno.getEstado(),
+            turno.getDenominaciones().stream()
+                .map(d -> new ConteoDenominacionEmbeddable(d.getDenominacion(), d.getCantidad()))
+                .toList(),
+            turno.getResponsableId() != null ? turno.getResponsableId().getValue() : null,
+            turno.getObservaciones()
+        );
+    }
+}
@@ -0,0 +1,61 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.SucursalId;
+import com.agustinpalma.comandas.domain.model.VentaSucursal;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.VentaSucursalEntity;
+import org.springframework.stereotype.Component;
+
+/**
+ * Mapper entre entidades de dominio VentaSucursal y entidades JPA VentaSucursalEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class VentaSucursalMapper {
+
+    /**
+     * Convierte de entidad JPA a entidad de dominio.
+     *
+     * @param entity entidad JPA
+     * @return entidad de dominio reconstruida
+     */
+    public VentaSucursal toDomain(VentaSucursalEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+
+        return new VentaSucursal(
+            entity.getPedidoOrigenId(),
+            new LocalId(entity.getLocalId()),
+            new SucursalId(entity.getSucursalId()),
+            entity.getNombreSucursal(),
+            entity.getNumeroPedido(),
+            entity.getFechaCierre(),
+            entity.getPagos(),
+            entity.getFechaRecepcion()
+        );
+    }
+
+    /**
+     * Convierte de entidad de dominio a entidad JPA.
+     *
+     * @param venta entidad de dominio
+     * @return entidad JPA para persistencia
+     */
+    public VentaSucursalEntity toEntity(VentaSucursal venta) {
+        if (venta == null) {
+            return null;
+        }
+
+        return new VentaSucursalEntity(
+            venta.getPedidoOrigenId(),
+            venta.getLocalId().getValue(),
+            venta.getSucursalId().getValue(),
+            venta.getNombreSucursal(),
+            venta.getNumeroPedido(),
+            venta.getFechaCierre(),
+            venta.getPagos(),
+            venta.getFechaRecepcion()
+        );
+    }
+}
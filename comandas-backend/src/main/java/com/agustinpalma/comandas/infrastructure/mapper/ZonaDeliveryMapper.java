@@ -0,0 +1,47 @@
+package com.agustinpalma.comandas.infrastructure.mapper;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ZonaDeliveryId;
+import com.agustinpalma.comandas.domain.model.ZonaDelivery;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ZonaDeliveryEntity;
+import org.springframework.stereotype.Component;
+
+import java.util.ArrayList;
+
+/**
+ * Mapper entre entidades de dominio ZonaDelivery y entidades JPA ZonaDeliveryEntity.
+ * Actúa como anti-corruption layer, protegiendo el dominio de detalles de persistencia.
+ */
+@Component
+public class ZonaDeliveryMapper {
+
+    public ZonaDelivery toDomain(ZonaDeliveryEntity entity) {
+        if (entity == null) {
+            return null;
+        }
+        return new ZonaDelivery(
+            new ZonaDeliveryId(entity.getId()),
+            new LocalId(entity.getLocalId()),
+            entity.getNombre(),
+            entity.getCostoEnvio(),
+            entity.getMontoMinimo(),
+            entity.getBarrios(),
+            entity.isActiva()
+        );
+    }
+
+    public ZonaDeliveryEntity toEntity(ZonaDelivery zona) {
+        if (zona == null) {
+            return null;
+        }
+        return new ZonaDeliveryEntity(
+            zona.getId().getValue(),
+            zona.getLocalId().getValue(),
+            zona.getNombre(),
+            zona.getCostoEnvio(),
+            zona.getMontoMinimo(),
+            new ArrayList<>(zona.getBarrios()),
+            zona.isActiva()
+        );
+    }
+}
@@ -0,0 +1,43 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.AlertaMesasOlvidadas;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.repository.AlertaMesasOlvidadasRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.AlertaMesasOlvidadasMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataAlertaMesasOlvidadasRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Optional;
+
+/**
+ * JPA implementation of AlertaMesasOlvidadasRepository.
+ * HU-113: Forgotten tables alert.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class AlertaMesasOlvidadasRepositoryImpl implements AlertaMesasOlvidadasRepository {
+
+    private final SpringDataAlertaMesasOlvidadasRepository springDataRepository;
+    private final AlertaMesasOlvidadasMapper mapper;
+
+    public AlertaMesasOlvidadasRepositoryImpl(SpringDataAlertaMesasOlvidadasRepository springDataRepository,
+                                              AlertaMesasOlvidadasMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    public Optional<AlertaMesasOlvidadas> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findById(localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    @Transactional
+    public AlertaMesasOlvidadas guardar(AlertaMesasOlvidadas alerta) {
+        var entity = mapper.toEntity(alerta);
+        var guardada = springDataRepository.save(entity);
+        return mapper.toDomain(guardada);
+    }
+}
@@ -3,16 +3,25 @@ package com.agustinpalma.comandas.infrastructure.persistence;
 import com.agustinpalma.comandas.application.dto.ProductoVendidoReporte;
 import com.agustinpalma.comandas.application.ports.output.AnalyticsRepositoryPort;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.ItemPedido;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.SeleccionCombo;
+import com.agustinpalma.comandas.domain.repository.ArchivoPedidosRepository;
 import jakarta.persistence.EntityManager;
 import jakarta.persistence.PersistenceContext;
 import org.springframework.stereotype.Repository;
 import org.springframework.transaction.annotation.Transactional;
 
 import java.math.BigDecimal;
+import java.math.RoundingMode;
 import java.time.LocalDate;
 import java.time.LocalDateTime;
 import java.time.LocalTime;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.LinkedHashMap;
 import java.util.List;
+import java.util.Map;
 
 /**
  * Implementación del puerto de analytics usando consultas nativas SQL.
@@ -25,6 +34,9 @@ import java.util.List;
  * para ser compatible tanto con SQLite (prod) como PostgreSQL (dev).
  *
  * El resultado se mapea manualmente al DTO {@link ProductoVendidoReporte}.
+ *
+ * HU-162: Los pedidos archivados ya no están en items_pedido; los del rango
+ * se suman en memoria con las mismas reglas que las consultas.
  */
 @Repository
 @Transactional(readOnly = true)
@@ -32,9 +44,11 @@ public class AnalyticsRepositoryAdapter implements AnalyticsRepositoryPort {
 
     @PersistenceContext
     private final EntityManager entityManager;
+    private final ArchivoPedidosRepository archivoPedidosRepository;
 
-    public AnalyticsRepositoryAdapter(EntityManager entityManager) {
+    public AnalyticsRepositoryAdapter(EntityManager entityManager, ArchivoPedidosRepository archivoPedidosRepository) {
         this.entityManager = entityManager;
+        this.archivoPedidosRepository = archivoPedidosRepository;
     }
 
     /**
@@ -52,11 +66,16 @@ public class AnalyticsRepositoryAdapter implements AnalyticsRepositoryPort {
      * los productos de mayor ingreso.
      */
     @Override
-    @SuppressWarnings("unchecked")
     public List<ProductoVendidoReporte> obtenerVentasPorProducto(LocalDate fecha, LocalId localId) {
-        LocalDateTime inicio = fecha.atStartOfDay();
-        LocalDateTime fin = fecha.plusDays(1).atStartOfDay();
+        return obtenerVentasPorProductoEntre(fecha.atStartOfDay(), fecha.plusDays(1).atStartOfDay(), localId);
+    }
 
+    /**
+     * HU-114: Misma agrupación que el reporte diario, con el rango explícito.
+     */
+    @Override
+    public List<ProductoVendidoReporte> obtenerVentasPorProductoEntre(LocalDateTime inicio, LocalDateTime fin,
+                                                                      LocalId localId) {
         String sql = """
             SELECT ip.nombre_producto,
                    SUM(ip.cantidad),
@@ -67,10 +86,178 @@ public class AnalyticsRepositoryAdapter implements AnalyticsRepositoryPort {
               AND p.fecha_cierre >= :inicio
               AND p.fecha_cierre < :fin
               AND p.local_id = :localId
+              AND ip.es_envio = FALSE
             GROUP BY ip.nombre_producto
             ORDER BY SUM(ip.precio_unitario * ip.cantidad) DESC
             """;
 
+        return sumarArchivados(ejecutarConsulta(sql, inicio, fin, localId),
+            ventasArchivadas(inicio, fin, localId, false, false));
+    }
+
+    /**
+     * HU-139: Los ítems de envío se llaman "Envío {zona}", así que agrupar
+     * por nombre es agrupar por zona.
+     */
+    @Override
+    public List<ProductoVendidoReporte> obtenerEnviosPorZonaEntre(LocalDateTime inicio, LocalDateTime fin,
+                                                                  LocalId localId) {
+        String sql = """
+            SELECT ip.nombre_producto,
+                   SUM(ip.cantidad),
+                   SUM(ip.precio_unitario * ip.cantidad)
+            FROM items_pedido ip
+            JOIN pedidos p ON ip.pedido_id = p.id
+            WHERE p.estado = 'CERRADO'
+              AND p.fecha_cierre >= :inicio
+              AND p.fecha_cierre < :fin
+              AND p.local_id = :localId
+              AND ip.es_envio = TRUE
+            GROUP BY ip.nombre_producto
+            ORDER BY SUM(ip.precio_unitario * ip.cantidad) DESC
+            """;
+
+        return sumarArchivados(ejecutarConsulta(sql, inicio, fin, localId),
+            ventasArchivadas(inicio, fin, localId, true, false));
+    }
+
+    /**
+     * HU-103: Reporte con los combos abiertos en sus componentes.
+     *
+     * Se resuelve con dos consultas que se combinan en memoria:
+     * 1. Ítems que no son combo (igual que el reporte estándar)
+     * 2. Componentes elegidos de cada combo, con el importe del combo prorrateado
+     *    según el precio de referencia de cada componente dentro del ítem
+     *
+     * Si un mismo producto se vendió suelto y dentro de combos, sus filas se suman.
+     */
+    @Override
+    public List<ProductoVendidoReporte> obtenerVentasPorProductoDesglosandoCombos(LocalDate fecha, LocalId localId) {
+        LocalDateTime inicio = fecha.atStartOfDay();
+        LocalDateTime fin = fecha.plusDays(1).atStartOfDay();
+
+        String sqlSinCombos = """
+            SELECT ip.nombre_producto,
+                   SUM(ip.cantidad),
+                   SUM(ip.precio_unitario * ip.cantidad)
+            FROM items_pedido ip
+            JOIN pedidos p ON ip.pedido_id = p.id
+            WHERE p.estado = 'CERRADO'
+              AND p.fecha_cierre >= :inicio
+              AND p.fecha_cierre < :fin
+              AND p.local_id = :localId
+              AND NOT EXISTS (SELECT 1 FROM items_pedido_combo c WHERE c.item_pedido_id = ip.id)
+              AND ip.es_envio = FALSE
+            GROUP BY ip.nombre_producto
+            """;
+
+        String sqlComponentes = """
+            SELECT c.nombre_producto,
+                   SUM(ip.cantidad),
+                   SUM(ip.precio_unitario * ip.cantidad *
+                       CASE WHEN t.total_referencia > 0
+                            THEN c.precio_referencia / t.total_referencia
+                            ELSE 1.0 / t.componentes
+                       END)
+            FROM items_pedido_combo c
+            JOIN items_pedido ip ON c.item_pedido_id = ip.id
+            JOIN pedidos p ON ip.pedido_id = p.id
+            JOIN (SELECT item_pedido_id,
+                         SUM(precio_referencia) AS total_referencia,
+                         COUNT(*) AS componentes
+                  FROM items_pedido_combo
+                  GROUP BY item_pedido_id) t ON t.item_pedido_id = ip.id
+            WHERE p.estado = 'CERRADO'
+              AND p.fecha_cierre >= :inicio
+              AND p.fecha_cierre < :fin
+              AND p.local_id = :localId
+            GROUP BY c.nombre_producto
+            """;
+
+        List<ProductoVendidoReporte> filas = new ArrayList<>(ejecutarConsulta(sqlSinCombos, inicio, fin, localId));
+        filas.addAll(ejecutarConsulta(sqlComponentes, inicio, fin, localId));
+        filas.addAll(ventasArchivadas(inicio, fin, localId, false, true));
+        return combinar(filas);
+    }
+
+    /**
+     * Suma las filas del mismo producto y ordena por total recaudado descendente.
+     */
+    private List<ProductoVendidoReporte> combinar(List<ProductoVendidoReporte> filas) {
+        Map<String, ProductoVendidoReporte> porProducto = new LinkedHashMap<>();
+        for (ProductoVendidoReporte fila : filas) {
+            porProducto.merge(fila.productoNombre(), fila, (a, b) -> new ProductoVendidoReporte(
+                a.productoNombre(),
+                a.cantidadTotal() + b.cantidadTotal(),
+                a.totalRecaudado().add(b.totalRecaudado())
+            ));
+        }
+
+        return porProducto.values().stream()
+            .map(fila -> new ProductoVendidoReporte(
+                fila.productoNombre(),
+                fila.cantidadTotal(),
+                fila.totalRecaudado().setScale(2, RoundingMode.HALF_UP)
+            ))
+            .sorted(Comparator.comparing(ProductoVendidoReporte::totalRecaudado).reversed())
+            .toList();
+    }
+
+    private List<ProductoVendidoReporte> sumarArchivados(List<ProductoVendidoReporte> filas,
+                                                         List<ProductoVendidoReporte> archivadas) {
+        if (archivadas.isEmpty()) {
+            return filas;
+        }
+        List<ProductoVendidoReporte> todas = new ArrayList<>(filas);
+        todas.addAll(archivadas);
+        return combinar(todas);
+    }
+
+    /**
+     * HU-162: Una fila por ítem de los pedidos archivados cerrados en [inicio, fin),
+     * sin agrupar. Con desglosarCombos, cada combo se reparte entre sus
+     * componentes igual que en {@link #obtenerVentasPorProductoDesglosandoCombos}.
+     */
+    private List<ProductoVendidoReporte> ventasArchivadas(LocalDateTime inicio, LocalDateTime fin, LocalId localId,
+                                                          boolean envios, boolean desglosarCombos) {
+        List<ProductoVendidoReporte> filas = new ArrayList<>();
+        for (Pedido pedido : archivoPedidosRepository.buscarCerradosPorFecha(localId, inicio, fin)) {
+            if (!pedido.getFechaCierre().isBefore(fin)) {
+                continue;
+            }
+            for (ItemPedido item : pedido.getItems()) {
+                if (item.isEnvio() != envios) {
+                    continue;
+                }
+                BigDecimal subtotal = item.getPrecioUnitario().multiply(BigDecimal.valueOf(item.getCantidad()));
+                if (desglosarCombos && item.esCombo()) {
+                    filas.addAll(prorratearCombo(item, subtotal));
+                } else {
+                    filas.add(new ProductoVendidoReporte(item.getNombreProducto(), (long) item.getCantidad(), subtotal));
+                }
+            }
+        }
+        return filas;
+    }
+
+    private List<ProductoVendidoReporte> prorratearCombo(ItemPedido combo, BigDecimal subtotal) {
+        List<SeleccionCombo> componentes = combo.getComponentesCombo();
+        BigDecimal totalReferencia = componentes.stream()
+            .map(SeleccionCombo::getPrecioReferencia)
+            .reduce(BigDecimal.ZERO, BigDecimal::add);
+        return componentes.stream()
+            .map(componente -> new ProductoVendidoReporte(
+                componente.getNombreProducto(),
+                (long) combo.getCantidad(),
+                totalReferencia.signum() > 0
+                    ? subtotal.multiply(componente.getPrecioReferencia()).divide(totalReferencia, 10, RoundingMode.HALF_UP)
+                    : subtotal.divide(BigDecimal.valueOf(componentes.size()), 10, RoundingMode.HALF_UP)
+            ))
+            .toList();
+    }
+
+    @SuppressWarnings("unchecked")
+    private List<ProductoVendidoReporte> ejecutarConsulta(String sql, LocalDateTime inicio, LocalDateTime fin, LocalId localId) {
         List<Object[]> rows = entityManager.createNativeQuery(sql)
             .setParameter("inicio", inicio)
             .setParameter("fin", fin)
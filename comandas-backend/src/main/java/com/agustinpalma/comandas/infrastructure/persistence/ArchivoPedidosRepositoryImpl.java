@@ -0,0 +1,59 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.ArchivoPedidosRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.PedidoArchivadoMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataPedidoArchivadoRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA de ArchivoPedidosRepository.
+ * HU-162: Archivo de pedidos para mantener rápida la base operativa.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class ArchivoPedidosRepositoryImpl implements ArchivoPedidosRepository {
+
+    private final SpringDataPedidoArchivadoRepository springDataRepository;
+    private final PedidoArchivadoMapper mapper;
+
+    public ArchivoPedidosRepositoryImpl(SpringDataPedidoArchivadoRepository springDataRepository,
+                                        PedidoArchivadoMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public void archivar(List<Pedido> pedidos, LocalDateTime archivadoEn) {
+        springDataRepository.saveAll(pedidos.stream()
+            .map(pedido -> mapper.toEntity(pedido, archivadoEn))
+            .toList());
+    }
+
+    @Override
+    public List<Pedido> buscarCerradosPorFecha(LocalId localId, LocalDateTime inicio, LocalDateTime fin) {
+        return springDataRepository
+            .findByLocalIdAndFechaCierreBetweenOrderByFechaCierre(localId.getValue(), inicio, fin)
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public int obtenerMaximoNumero(LocalId localId) {
+        return springDataRepository.findMaxNumeroByLocalId(localId.getValue());
+    }
+
+    @Override
+    public Optional<String> buscarUltimoComprobante(LocalId localId, String encabezado) {
+        return springDataRepository.findUltimoComprobante(localId.getValue(),
+            PedidoRepositoryImpl.patronComprobante(encabezado));
+    }
+}
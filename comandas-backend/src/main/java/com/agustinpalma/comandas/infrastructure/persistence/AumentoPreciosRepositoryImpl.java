@@ -0,0 +1,63 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.AumentoPrecios;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoAumentoPrecios;
+import com.agustinpalma.comandas.domain.model.DomainIds.AumentoPreciosId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.repository.AumentoPreciosRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.AumentoPreciosMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataAumentoPreciosRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDate;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA de AumentoPreciosRepository.
+ * HU-145: Actualización masiva de precios.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class AumentoPreciosRepositoryImpl implements AumentoPreciosRepository {
+
+    private final SpringDataAumentoPreciosRepository springDataRepository;
+    private final AumentoPreciosMapper mapper;
+
+    public AumentoPreciosRepositoryImpl(SpringDataAumentoPreciosRepository springDataRepository,
+                                        AumentoPreciosMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public AumentoPrecios guardar(AumentoPrecios aumento) {
+        var guardado = springDataRepository.save(mapper.toEntity(aumento));
+        return mapper.toDomain(guardado);
+    }
+
+    @Override
+    public Optional<AumentoPrecios> buscarPorId(AumentoPreciosId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<AumentoPrecios> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findByLocalIdOrderByFechaCreacionDesc(localId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public List<AumentoPrecios> buscarProgramadosHasta(LocalDate dia) {
+        return springDataRepository
+            .findByEstadoAndFechaVigenciaLessThanEqualOrderByFechaCreacionAsc(EstadoAumentoPrecios.PROGRAMADO, dia)
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
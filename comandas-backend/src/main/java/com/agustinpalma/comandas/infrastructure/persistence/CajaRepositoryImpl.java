@@ -0,0 +1,82 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.AperturaCaja;
+import com.agustinpalma.comandas.domain.model.Caja;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.repository.CajaRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.CajaMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataAperturaCajaRepository;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataCajaRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA del repositorio de cajas y aperturas.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class CajaRepositoryImpl implements CajaRepository {
+
+    private final SpringDataCajaRepository cajaRepository;
+    private final SpringDataAperturaCajaRepository aperturaRepository;
+    private final CajaMapper mapper;
+
+    public CajaRepositoryImpl(SpringDataCajaRepository cajaRepository,
+                              SpringDataAperturaCajaRepository aperturaRepository,
+                              CajaMapper mapper) {
+        this.cajaRepository = cajaRepository;
+        this.aperturaRepository = aperturaRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public Caja guardar(Caja caja) {
+        return mapper.toDomain(cajaRepository.save(mapper.toEntity(caja)));
+    }
+
+    @Override
+    public Optional<Caja> buscarPorId(CajaId id, LocalId localId) {
+        return cajaRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<Caja> buscarPorLocal(LocalId localId) {
+        return cajaRepository.findByLocalIdOrderByFechaAltaAsc(localId.getValue()).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public boolean existePorNombre(LocalId localId, String nombre) {
+        return cajaRepository.existsByLocalIdAndNombreIgnoreCase(localId.getValue(), nombre.trim());
+    }
+
+    @Override
+    @Transactional
+    public AperturaCaja guardarApertura(AperturaCaja apertura) {
+        return mapper.toDomain(aperturaRepository.save(mapper.toEntity(apertura)));
+    }
+
+    @Override
+    public List<AperturaCaja> buscarAperturas(JornadaCajaId jornadaId, LocalId localId) {
+        return aperturaRepository.findByJornadaIdAndLocalIdOrderByFechaAperturaAsc(
+                jornadaId.getValue(), localId.getValue()).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public Optional<AperturaCaja> buscarApertura(JornadaCajaId jornadaId, CajaId cajaId, LocalId localId) {
+        return aperturaRepository.findByJornadaIdAndCajaIdAndLocalId(
+                jornadaId.getValue(), cajaId.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+}
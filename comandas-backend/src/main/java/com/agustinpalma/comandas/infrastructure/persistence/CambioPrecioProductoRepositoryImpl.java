@@ -0,0 +1,56 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.CambioPrecioProducto;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.repository.CambioPrecioProductoRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.CambioPrecioProductoMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataCambioPrecioProductoRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDateTime;
+import java.util.List;
+
+/**
+ * Implementación JPA de CambioPrecioProductoRepository.
+ * HU-146: Historial de cambios de precios.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class CambioPrecioProductoRepositoryImpl implements CambioPrecioProductoRepository {
+
+    private final SpringDataCambioPrecioProductoRepository springDataRepository;
+    private final CambioPrecioProductoMapper mapper;
+
+    public CambioPrecioProductoRepositoryImpl(SpringDataCambioPrecioProductoRepository springDataRepository,
+                                              CambioPrecioProductoMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public CambioPrecioProducto guardar(CambioPrecioProducto cambio) {
+        var guardado = springDataRepository.save(mapper.toEntity(cambio));
+        return mapper.toDomain(guardado);
+    }
+
+    @Override
+    public List<CambioPrecioProducto> buscarPorProducto(ProductoId productoId, LocalId localId) {
+        return springDataRepository
+            .findByProductoIdAndLocalIdOrderByFechaDesc(productoId.getValue(), localId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public List<CambioPrecioProducto> buscarPorLocalEntre(LocalId localId, LocalDateTime desde, LocalDateTime hasta) {
+        return springDataRepository
+            .findByLocalIdAndFechaGreaterThanEqualAndFechaLessThanOrderByFechaAsc(localId.getValue(), desde, hasta)
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
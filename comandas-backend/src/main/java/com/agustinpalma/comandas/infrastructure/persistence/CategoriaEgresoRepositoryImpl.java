@@ -0,0 +1,50 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.CategoriaEgreso;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaEgresoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.repository.CategoriaEgresoRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.CategoriaEgresoMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataCategoriaEgresoRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA del repositorio de categorías de egresos.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class CategoriaEgresoRepositoryImpl implements CategoriaEgresoRepository {
+
+    private final SpringDataCategoriaEgresoRepository springDataRepository;
+    private final CategoriaEgresoMapper mapper;
+
+    public CategoriaEgresoRepositoryImpl(SpringDataCategoriaEgresoRepository springDataRepository,
+                                         CategoriaEgresoMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public CategoriaEgreso guardar(CategoriaEgreso categoria) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(categoria)));
+    }
+
+    @Override
+    public Optional<CategoriaEgreso> buscarPorId(CategoriaEgresoId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<CategoriaEgreso> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findByLocalIdOrderByNombreAsc(localId.getValue()).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
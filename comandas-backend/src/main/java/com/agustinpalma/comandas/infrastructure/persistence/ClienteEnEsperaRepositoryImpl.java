@@ -0,0 +1,56 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.ClienteEnEspera;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoEspera;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteEnEsperaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.repository.ClienteEnEsperaRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.ClienteEnEsperaMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataClienteEnEsperaRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.EnumSet;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA de ClienteEnEsperaRepository.
+ * HU-149: Lista de espera de mesas.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class ClienteEnEsperaRepositoryImpl implements ClienteEnEsperaRepository {
+
+    private final SpringDataClienteEnEsperaRepository springDataRepository;
+    private final ClienteEnEsperaMapper mapper;
+
+    public ClienteEnEsperaRepositoryImpl(SpringDataClienteEnEsperaRepository springDataRepository,
+                                         ClienteEnEsperaMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public ClienteEnEspera guardar(ClienteEnEspera cliente) {
+        var guardado = springDataRepository.save(mapper.toEntity(cliente));
+        return mapper.toDomain(guardado);
+    }
+
+    @Override
+    public Optional<ClienteEnEspera> buscarPorId(ClienteEnEsperaId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<ClienteEnEspera> buscarEnLista(LocalId localId) {
+        return springDataRepository
+            .findByLocalIdAndEstadoInOrderByFechaAltaAsc(
+                localId.getValue(), EnumSet.of(EstadoEspera.ESPERANDO, EstadoEspera.AVISADO))
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
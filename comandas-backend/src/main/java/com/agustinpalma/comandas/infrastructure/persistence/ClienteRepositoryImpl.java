@@ -0,0 +1,57 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.Cliente;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.repository.ClienteRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.ClienteMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataClienteRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * JPA implementation of ClienteRepository.
+ * HU-104: Customer running accounts.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class ClienteRepositoryImpl implements ClienteRepository {
+
+    private final SpringDataClienteRepository springDataRepository;
+    private final ClienteMapper mapper;
+
+    public ClienteRepositoryImpl(SpringDataClienteRepository springDataRepository, ClienteMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    public Optional<Cliente> buscarPorIdYLocal(ClienteId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<Cliente> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findByLocalIdOrderByNombreAsc(localId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    @Transactional
+    public Cliente guardar(Cliente cliente) {
+        var entity = mapper.toEntity(cliente);
+        var guardado = springDataRepository.save(entity);
+        return mapper.toDomain(guardado);
+    }
+
+    @Override
+    public boolean existePorNombreYLocal(String nombre, LocalId localId) {
+        return springDataRepository.existsByLocalIdAndNombreIgnoreCase(localId.getValue(), nombre.trim());
+    }
+}
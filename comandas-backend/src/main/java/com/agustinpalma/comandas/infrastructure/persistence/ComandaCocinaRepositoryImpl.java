@@ -0,0 +1,76 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.ComandaCocina;
+import com.agustinpalma.comandas.domain.model.DomainIds.ComandaCocinaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.repository.ComandaCocinaRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.ComandaCocinaMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataComandaCocinaRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA del repositorio de comandas de cocina.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class ComandaCocinaRepositoryImpl implements ComandaCocinaRepository {
+
+    private final SpringDataComandaCocinaRepository springDataRepository;
+    private final ComandaCocinaMapper mapper;
+
+    public ComandaCocinaRepositoryImpl(SpringDataComandaCocinaRepository springDataRepository,
+                                       ComandaCocinaMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public ComandaCocina guardar(ComandaCocina comanda) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(comanda)));
+    }
+
+    @Override
+    public Optional<ComandaCocina> buscarPorId(ComandaCocinaId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<ComandaCocina> buscarPendientes(LocalId localId) {
+        return springDataRepository.findByLocalIdAndListaEnIsNullOrderByEnviadaEnAsc(localId.getValue()).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public List<ComandaCocina> buscarPendientesDePedido(PedidoId pedidoId, LocalId localId) {
+        return springDataRepository.findByLocalIdAndPedidoIdAndListaEnIsNull(localId.getValue(), pedidoId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public Optional<ComandaCocina> buscarUltimaLista(LocalId localId) {
+        return springDataRepository.findFirstByLocalIdAndListaEnIsNotNullOrderByListaEnDesc(localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<ComandaCocina> buscarListasEnviadasEntre(LocalId localId, LocalDateTime desde, LocalDateTime hasta) {
+        return springDataRepository
+            .findByLocalIdAndListaEnIsNotNullAndEnviadaEnGreaterThanEqualAndEnviadaEnLessThan(
+                localId.getValue(), desde, hasta)
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
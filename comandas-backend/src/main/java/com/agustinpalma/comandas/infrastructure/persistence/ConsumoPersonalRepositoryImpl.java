@@ -0,0 +1,74 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.ConsumoPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.repository.ConsumoPersonalRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.ConsumoPersonalMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataConsumoPersonalRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA de ConsumoPersonalRepository.
+ * HU-132: Límites de consumo interno del personal.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class ConsumoPersonalRepositoryImpl implements ConsumoPersonalRepository {
+
+    private final SpringDataConsumoPersonalRepository springDataRepository;
+    private final ConsumoPersonalMapper mapper;
+
+    public ConsumoPersonalRepositoryImpl(
+            SpringDataConsumoPersonalRepository springDataRepository,
+            ConsumoPersonalMapper mapper
+    ) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public ConsumoPersonal guardar(ConsumoPersonal consumo) {
+        var guardado = springDataRepository.save(mapper.toEntity(consumo));
+        return mapper.toDomain(guardado);
+    }
+
+    @Override
+    public List<ConsumoPersonal> buscarPorLocal(LocalId localId, LocalDateTime desde, LocalDateTime hasta) {
+        return springDataRepository
+            .findByLocalIdAndFechaGreaterThanEqualAndFechaLessThanOrderByFechaAsc(localId.getValue(), desde, hasta)
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public List<ConsumoPersonal> buscarPorEmpleado(MozoId mozoId, LocalId localId, LocalDateTime desde,
+                                                   LocalDateTime hasta) {
+        return springDataRepository
+            .findByMozoIdAndLocalIdAndFechaGreaterThanEqualAndFechaLessThanOrderByFechaAsc(
+                mozoId.getValue(), localId.getValue(), desde, hasta)
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public Optional<ConsumoPersonal> buscarPorPedido(PedidoId pedidoId, LocalId localId) {
+        return springDataRepository.findByPedidoIdAndLocalId(pedidoId.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    @Transactional
+    public void eliminarPorPedido(PedidoId pedidoId, LocalId localId) {
+        springDataRepository.deleteByPedidoIdAndLocalId(pedidoId.getValue(), localId.getValue());
+    }
+}
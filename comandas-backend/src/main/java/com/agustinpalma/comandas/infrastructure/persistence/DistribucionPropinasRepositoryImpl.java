@@ -0,0 +1,64 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DistribucionPropinas;
+import com.agustinpalma.comandas.domain.model.DomainIds.DistribucionPropinasId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+import com.agustinpalma.comandas.domain.repository.DistribucionPropinasRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.DistribucionPropinasMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataDistribucionPropinasRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA de DistribucionPropinasRepository.
+ * HU-141: Pozo de propinas y distribución.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class DistribucionPropinasRepositoryImpl implements DistribucionPropinasRepository {
+
+    private final SpringDataDistribucionPropinasRepository springDataRepository;
+    private final DistribucionPropinasMapper mapper;
+
+    public DistribucionPropinasRepositoryImpl(
+            SpringDataDistribucionPropinasRepository springDataRepository,
+            DistribucionPropinasMapper mapper
+    ) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public DistribucionPropinas guardar(DistribucionPropinas distribucion) {
+        var guardada = springDataRepository.save(mapper.toEntity(distribucion));
+        return mapper.toDomain(guardada);
+    }
+
+    @Override
+    public Optional<DistribucionPropinas> buscarPorId(DistribucionPropinasId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public Optional<DistribucionPropinas> buscarPorTurno(TurnoCajaId turnoId, LocalId localId) {
+        return springDataRepository.findByTurnoIdAndLocalId(turnoId.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<DistribucionPropinas> buscarEntre(LocalId localId, LocalDateTime inicio, LocalDateTime fin) {
+        return springDataRepository
+            .findByLocalIdAndFechaGreaterThanEqualAndFechaLessThanOrderByFechaDesc(
+                localId.getValue(), inicio, fin)
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
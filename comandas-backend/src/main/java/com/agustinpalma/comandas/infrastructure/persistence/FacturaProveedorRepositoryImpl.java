@@ -0,0 +1,70 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaProveedorId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+import com.agustinpalma.comandas.domain.model.FacturaProveedor;
+import com.agustinpalma.comandas.domain.repository.FacturaProveedorRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.FacturaProveedorMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataFacturaProveedorRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.Set;
+import java.util.stream.Collectors;
+
+/**
+ * Implementación JPA del repositorio de facturas de proveedores.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class FacturaProveedorRepositoryImpl implements FacturaProveedorRepository {
+
+    private final SpringDataFacturaProveedorRepository springDataRepository;
+    private final FacturaProveedorMapper mapper;
+
+    public FacturaProveedorRepositoryImpl(SpringDataFacturaProveedorRepository springDataRepository,
+                                          FacturaProveedorMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public FacturaProveedor guardar(FacturaProveedor factura) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(factura)));
+    }
+
+    @Override
+    public Optional<FacturaProveedor> buscarPorId(FacturaProveedorId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<FacturaProveedor> buscarPorProveedor(ProveedorId proveedorId, LocalId localId) {
+        return springDataRepository
+            .findByProveedorIdAndLocalIdOrderByFechaVencimientoAsc(proveedorId.getValue(), localId.getValue()).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public List<FacturaProveedor> buscarImpagas(LocalId localId) {
+        return springDataRepository.findImpagasByLocalId(localId.getValue()).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public Set<MovimientoCajaId> buscarMovimientosCajaAsociados(LocalId localId, LocalDateTime desde, LocalDateTime hasta) {
+        return springDataRepository.findMovimientosCajaAsociados(localId.getValue(), desde, hasta).stream()
+            .map(MovimientoCajaId::new)
+            .collect(Collectors.toSet());
+    }
+}
@@ -0,0 +1,63 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoFactura;
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.Factura;
+import com.agustinpalma.comandas.domain.repository.FacturaRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.FacturaMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataFacturaRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA del repositorio de facturas.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class FacturaRepositoryImpl implements FacturaRepository {
+
+    private final SpringDataFacturaRepository springDataRepository;
+    private final FacturaMapper mapper;
+
+    public FacturaRepositoryImpl(SpringDataFacturaRepository springDataRepository, FacturaMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public Factura guardar(Factura factura) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(factura)));
+    }
+
+    @Override
+    public Optional<Factura> buscarPorId(FacturaId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public Optional<Factura> buscarPorPedido(PedidoId pedidoId, LocalId localId) {
+        return springDataRepository.findByPedidoIdAndLocalId(pedidoId.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<Factura> buscarPorPeriodo(LocalId localId, LocalDateTime desde, LocalDateTime hasta) {
+        return springDataRepository.findByLocalIdAndPeriodo(localId.getValue(), desde, hasta).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public long ultimoNumero(LocalId localId, int puntoVenta, TipoFactura tipo) {
+        return springDataRepository.findUltimoNumero(localId.getValue(), puntoVenta, tipo);
+    }
+}
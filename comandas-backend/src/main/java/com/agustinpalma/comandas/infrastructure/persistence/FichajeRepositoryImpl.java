@@ -0,0 +1,60 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.FichajeId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Fichaje;
+import com.agustinpalma.comandas.domain.repository.FichajeRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.FichajeMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataFichajeRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA del repositorio de fichajes.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class FichajeRepositoryImpl implements FichajeRepository {
+
+    private final SpringDataFichajeRepository springDataRepository;
+    private final FichajeMapper mapper;
+
+    public FichajeRepositoryImpl(SpringDataFichajeRepository springDataRepository, FichajeMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public Fichaje guardar(Fichaje fichaje) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(fichaje)));
+    }
+
+    @Override
+    public Optional<Fichaje> buscarPorId(FichajeId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public Optional<Fichaje> buscarAbierto(LocalId localId, MozoId mozoId) {
+        return springDataRepository
+            .findFirstByLocalIdAndMozoIdAndSalidaIsNullOrderByEntradaDesc(localId.getValue(), mozoId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<Fichaje> buscarPorRango(LocalId localId, LocalDateTime desde, LocalDateTime hasta) {
+        return springDataRepository
+            .findByLocalIdAndEntradaGreaterThanEqualAndEntradaLessThanOrderByEntradaAsc(
+                localId.getValue(), desde, hasta).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
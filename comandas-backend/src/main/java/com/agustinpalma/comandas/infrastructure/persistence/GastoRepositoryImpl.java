@@ -0,0 +1,85 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.ComprobanteAdjunto;
+import com.agustinpalma.comandas.domain.model.DomainIds.GastoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.Gasto;
+import com.agustinpalma.comandas.domain.repository.GastoRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.GastoMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ComprobanteGastoEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataComprobanteGastoRepository;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataGastoRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.Set;
+import java.util.stream.Collectors;
+
+/**
+ * Implementación JPA del repositorio de compras y gastos.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class GastoRepositoryImpl implements GastoRepository {
+
+    private final SpringDataGastoRepository springDataRepository;
+    private final SpringDataComprobanteGastoRepository comprobanteRepository;
+    private final GastoMapper mapper;
+
+    public GastoRepositoryImpl(SpringDataGastoRepository springDataRepository,
+                               SpringDataComprobanteGastoRepository comprobanteRepository,
+                               GastoMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.comprobanteRepository = comprobanteRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public Gasto guardar(Gasto gasto) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(gasto)));
+    }
+
+    @Override
+    public Optional<Gasto> buscarPorId(GastoId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<Gasto> buscarPorPeriodo(LocalId localId, LocalDate desde, LocalDate hasta) {
+        return springDataRepository.findByLocalIdAndPeriodo(localId.getValue(), desde, hasta).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public Set<MovimientoCajaId> buscarMovimientosCajaAsociados(LocalId localId, LocalDateTime desde, LocalDateTime hasta) {
+        return springDataRepository.findMovimientosCajaAsociados(localId.getValue(), desde, hasta).stream()
+            .map(MovimientoCajaId::new)
+            .collect(Collectors.toSet());
+    }
+
+    @Override
+    @Transactional
+    public void guardarComprobante(GastoId id, ComprobanteAdjunto comprobante) {
+        comprobanteRepository.save(new ComprobanteGastoEntity(
+            id.getValue(),
+            comprobante.getNombreArchivo(),
+            comprobante.getContentType(),
+            comprobante.getContenido()
+        ));
+    }
+
+    @Override
+    public Optional<ComprobanteAdjunto> buscarComprobante(GastoId id) {
+        return comprobanteRepository.findById(id.getValue())
+            .map(e -> new ComprobanteAdjunto(e.getNombreArchivo(), e.getContentType(), e.getContenido()));
+    }
+}
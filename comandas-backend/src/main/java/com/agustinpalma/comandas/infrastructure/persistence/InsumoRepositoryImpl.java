@@ -0,0 +1,49 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.Insumo;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.InsumoMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataInsumoRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA del repositorio de insumos.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class InsumoRepositoryImpl implements InsumoRepository {
+
+    private final SpringDataInsumoRepository springDataRepository;
+    private final InsumoMapper mapper;
+
+    public InsumoRepositoryImpl(SpringDataInsumoRepository springDataRepository, InsumoMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public Insumo guardar(Insumo insumo) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(insumo)));
+    }
+
+    @Override
+    public Optional<Insumo> buscarPorId(InsumoId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<Insumo> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findByLocalIdOrderByNombreAsc(localId.getValue()).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
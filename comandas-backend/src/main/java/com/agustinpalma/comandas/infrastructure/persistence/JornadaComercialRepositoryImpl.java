@@ -0,0 +1,42 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.JornadaComercialMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataJornadaComercialRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Optional;
+
+/**
+ * Implementación JPA de JornadaComercialRepository.
+ * HU-153: Límite de jornada comercial configurable.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class JornadaComercialRepositoryImpl implements JornadaComercialRepository {
+
+    private final SpringDataJornadaComercialRepository springDataRepository;
+    private final JornadaComercialMapper mapper;
+
+    public JornadaComercialRepositoryImpl(SpringDataJornadaComercialRepository springDataRepository,
+                                          JornadaComercialMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    public Optional<JornadaComercial> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findById(localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    @Transactional
+    public JornadaComercial guardar(JornadaComercial jornada) {
+        var guardada = springDataRepository.save(mapper.toEntity(jornada));
+        return mapper.toDomain(guardada);
+    }
+}
@@ -0,0 +1,67 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoLinkPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LinkPagoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.LinkPago;
+import com.agustinpalma.comandas.domain.repository.LinkPagoRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.LinkPagoMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataLinkPagoRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA del repositorio de links de pago.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class LinkPagoRepositoryImpl implements LinkPagoRepository {
+
+    private final SpringDataLinkPagoRepository springDataRepository;
+    private final LinkPagoMapper mapper;
+
+    public LinkPagoRepositoryImpl(SpringDataLinkPagoRepository springDataRepository, LinkPagoMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public LinkPago guardar(LinkPago link) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(link)));
+    }
+
+    @Override
+    public Optional<LinkPago> buscarPorId(LinkPagoId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public Optional<LinkPago> buscarPendientePorPedido(PedidoId pedidoId, LocalId localId) {
+        return springDataRepository.findFirstByPedidoIdAndLocalIdAndEstado(
+                pedidoId.getValue(), localId.getValue(), EstadoLinkPago.PENDIENTE)
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public Optional<LinkPago> buscarUltimoPorPedido(PedidoId pedidoId, LocalId localId) {
+        return springDataRepository.findFirstByPedidoIdAndLocalIdOrderByCreadoEnDesc(
+                pedidoId.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<LinkPago> buscarPendientes(LocalId localId) {
+        return springDataRepository
+            .findByLocalIdAndEstadoOrderByCreadoEnAsc(localId.getValue(), EstadoLinkPago.PENDIENTE)
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
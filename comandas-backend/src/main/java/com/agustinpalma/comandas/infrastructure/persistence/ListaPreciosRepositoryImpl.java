@@ -0,0 +1,57 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainIds.ListaPreciosId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.ListaPrecios;
+import com.agustinpalma.comandas.domain.repository.ListaPreciosRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.ListaPreciosMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataListaPreciosRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA del repositorio de listas de precios.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class ListaPreciosRepositoryImpl implements ListaPreciosRepository {
+
+    private final SpringDataListaPreciosRepository springDataRepository;
+    private final ListaPreciosMapper mapper;
+
+    public ListaPreciosRepositoryImpl(SpringDataListaPreciosRepository springDataRepository,
+                                      ListaPreciosMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public ListaPrecios guardar(ListaPrecios lista) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(lista)));
+    }
+
+    @Override
+    public Optional<ListaPrecios> buscarPorLocalYCanal(LocalId localId, CanalVenta canal) {
+        return springDataRepository.findByLocalIdAndCanal(localId.getValue(), canal)
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public Optional<ListaPrecios> buscarPorId(ListaPreciosId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<ListaPrecios> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findByLocalId(localId.getValue()).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
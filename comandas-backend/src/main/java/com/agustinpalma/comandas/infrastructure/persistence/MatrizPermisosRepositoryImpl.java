@@ -0,0 +1,42 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.MatrizPermisos;
+import com.agustinpalma.comandas.domain.repository.MatrizPermisosRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.MatrizPermisosMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataMatrizPermisosRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Optional;
+
+/**
+ * Implementación JPA de MatrizPermisosRepository.
+ * HU-169: Matriz de permisos por rol y por empleado.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class MatrizPermisosRepositoryImpl implements MatrizPermisosRepository {
+
+    private final SpringDataMatrizPermisosRepository springDataRepository;
+    private final MatrizPermisosMapper mapper;
+
+    public MatrizPermisosRepositoryImpl(SpringDataMatrizPermisosRepository springDataRepository,
+                                        MatrizPermisosMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    public Optional<MatrizPermisos> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findById(localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    @Transactional
+    public MatrizPermisos guardar(MatrizPermisos matriz) {
+        var guardada = springDataRepository.save(mapper.toEntity(matriz));
+        return mapper.toDomain(guardada);
+    }
+}
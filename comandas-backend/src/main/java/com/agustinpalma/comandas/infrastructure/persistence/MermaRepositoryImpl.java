@@ -0,0 +1,43 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.Merma;
+import com.agustinpalma.comandas.domain.repository.MermaRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.MermaMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataMermaRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDateTime;
+import java.util.List;
+
+/**
+ * Implementación JPA del repositorio de mermas.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class MermaRepositoryImpl implements MermaRepository {
+
+    private final SpringDataMermaRepository springDataRepository;
+    private final MermaMapper mapper;
+
+    public MermaRepositoryImpl(SpringDataMermaRepository springDataRepository, MermaMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public Merma guardar(Merma merma) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(merma)));
+    }
+
+    @Override
+    public List<Merma> buscarPorFecha(LocalId localId, LocalDateTime desde, LocalDateTime hasta) {
+        return springDataRepository
+            .findByLocalIdAndFechaBetweenOrderByFechaDesc(localId.getValue(), desde, hasta).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
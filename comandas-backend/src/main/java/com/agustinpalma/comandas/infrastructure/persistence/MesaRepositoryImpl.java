@@ -5,10 +5,12 @@ import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
 import com.agustinpalma.comandas.domain.model.Mesa;
 import com.agustinpalma.comandas.domain.repository.MesaRepository;
 import com.agustinpalma.comandas.infrastructure.mapper.MesaMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.MesaEntity;
 import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataMesaRepository;
 import org.springframework.stereotype.Repository;
 import org.springframework.transaction.annotation.Transactional;
 
+import java.util.ArrayList;
 import java.util.List;
 import java.util.Optional;
 import java.util.stream.Collectors;
@@ -53,6 +55,31 @@ public class MesaRepositoryImpl implements MesaRepository {
         return mapper.toDomain(guardada);
     }
 
+    /**
+     * La restricción uk_mesa_local_numero se evalúa fila a fila, así que un
+     * intercambio de números fallaría con un UPDATE directo. Primero se
+     * mueven las mesas renumeradas a números negativos (fuera del rango
+     * válido) y recién después se escriben los definitivos.
+     */
+    @Override
+    @Transactional
+    public void guardarTodas(List<Mesa> mesas) {
+        List<MesaEntity> renumeradas = new ArrayList<>();
+        for (Mesa mesa : mesas) {
+            springDataRepository.findById(mesa.getId().getValue())
+                .filter(actual -> actual.getNumero() != mesa.getNumero())
+                .ifPresent(actual -> {
+                    actual.setNumero(-mesa.getNumero());
+                    renumeradas.add(actual);
+                });
+        }
+        if (!renumeradas.isEmpty()) {
+            springDataRepository.saveAllAndFlush(renumeradas);
+        }
+
+        springDataRepository.saveAll(mesas.stream().map(mapper::toEntity).toList());
+    }
+
     @Override
     public boolean existePorNumeroYLocal(int numero, LocalId localId) {
         return springDataRepository.existsByNumeroAndLocalId(numero, localId.getValue());
@@ -1,6 +1,8 @@
 package com.agustinpalma.comandas.infrastructure.persistence;
 
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
 import com.agustinpalma.comandas.domain.model.MovimientoCaja;
 import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
 import com.agustinpalma.comandas.infrastructure.mapper.MovimientoCajaMapper;
@@ -44,4 +46,22 @@ public class MovimientoCajaRepositoryImpl implements MovimientoCajaRepository {
             .map(mapper::toDomain)
             .toList();
     }
+
+    @Override
+    public List<MovimientoCaja> buscarPorTurno(LocalId localId, TurnoCajaId turnoId) {
+        return springDataRepository
+            .findByLocalIdAndTurnoIdOrderByFechaAsc(localId.getValue(), turnoId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public List<MovimientoCaja> buscarDevolucionesPorPedido(LocalId localId, PedidoId pedidoId) {
+        return springDataRepository
+            .findByLocalIdAndPedidoIdOrderByFechaAsc(localId.getValue(), pedidoId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
 }
@@ -0,0 +1,68 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoCuenta;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.MovimientoCuentaCorriente;
+import com.agustinpalma.comandas.domain.repository.MovimientoCuentaCorrienteRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.MovimientoCuentaCorrienteMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataMovimientoCuentaCorrienteRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+
+/**
+ * JPA implementation of MovimientoCuentaCorrienteRepository.
+ * HU-104: Customer running accounts.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class MovimientoCuentaCorrienteRepositoryImpl implements MovimientoCuentaCorrienteRepository {
+
+    private final SpringDataMovimientoCuentaCorrienteRepository springDataRepository;
+    private final MovimientoCuentaCorrienteMapper mapper;
+
+    public MovimientoCuentaCorrienteRepositoryImpl(
+            SpringDataMovimientoCuentaCorrienteRepository springDataRepository,
+            MovimientoCuentaCorrienteMapper mapper
+    ) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public MovimientoCuentaCorriente guardar(MovimientoCuentaCorriente movimiento) {
+        var entity = mapper.toEntity(movimiento);
+        var guardado = springDataRepository.save(entity);
+        return mapper.toDomain(guardado);
+    }
+
+    @Override
+    public List<MovimientoCuentaCorriente> buscarPorCliente(ClienteId clienteId, LocalId localId) {
+        return springDataRepository
+            .findByClienteIdAndLocalIdOrderByFechaAsc(clienteId.getValue(), localId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public List<MovimientoCuentaCorriente> buscarCargosPorPedido(PedidoId pedidoId, LocalId localId) {
+        return springDataRepository
+            .findByPedidoIdAndLocalIdAndTipo(pedidoId.getValue(), localId.getValue(), TipoMovimientoCuenta.CARGO.name())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    @Transactional
+    public void eliminarCargosPorPedido(PedidoId pedidoId, LocalId localId) {
+        springDataRepository.deleteByPedidoIdAndLocalIdAndTipo(
+            pedidoId.getValue(), localId.getValue(), TipoMovimientoCuenta.CARGO.name()
+        );
+    }
+}
@@ -0,0 +1,44 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.MovimientoInsumo;
+import com.agustinpalma.comandas.domain.repository.MovimientoInsumoRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.MovimientoInsumoMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataMovimientoInsumoRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+
+/**
+ * Implementación JPA del repositorio de movimientos de stock de insumos.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class MovimientoInsumoRepositoryImpl implements MovimientoInsumoRepository {
+
+    private final SpringDataMovimientoInsumoRepository springDataRepository;
+    private final MovimientoInsumoMapper mapper;
+
+    public MovimientoInsumoRepositoryImpl(SpringDataMovimientoInsumoRepository springDataRepository,
+                                          MovimientoInsumoMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public MovimientoInsumo guardar(MovimientoInsumo movimiento) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(movimiento)));
+    }
+
+    @Override
+    public List<MovimientoInsumo> buscarPorInsumo(InsumoId insumoId, LocalId localId) {
+        return springDataRepository
+            .findByInsumoIdAndLocalIdOrderByFechaDesc(insumoId.getValue(), localId.getValue()).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
@@ -0,0 +1,83 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoPuntos;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.MovimientoPuntos;
+import com.agustinpalma.comandas.domain.repository.MovimientoPuntosRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.MovimientoPuntosMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataMovimientoPuntosRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+
+/**
+ * JPA implementation of MovimientoPuntosRepository.
+ * HU-105: Customer loyalty points.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class MovimientoPuntosRepositoryImpl implements MovimientoPuntosRepository {
+
+    private final SpringDataMovimientoPuntosRepository springDataRepository;
+    private final MovimientoPuntosMapper mapper;
+
+    public MovimientoPuntosRepositoryImpl(
+            SpringDataMovimientoPuntosRepository springDataRepository,
+            MovimientoPuntosMapper mapper
+    ) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public MovimientoPuntos guardar(MovimientoPuntos movimiento) {
+        var entity = mapper.toEntity(movimiento);
+        var guardado = springDataRepository.save(entity);
+        return mapper.toDomain(guardado);
+    }
+
+    @Override
+    public List<MovimientoPuntos> buscarPorCliente(ClienteId clienteId, LocalId localId) {
+        return springDataRepository
+            .findByClienteIdAndLocalIdOrderByFechaAsc(clienteId.getValue(), localId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public List<MovimientoPuntos> buscarPorLocal(LocalId localId) {
+        return springDataRepository
+            .findByLocalIdOrderByFechaAsc(localId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public List<MovimientoPuntos> buscarPorPedido(PedidoId pedidoId, LocalId localId) {
+        return springDataRepository
+            .findByPedidoIdAndLocalId(pedidoId.getValue(), localId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    @Transactional
+    public void eliminarPorPedido(PedidoId pedidoId, LocalId localId) {
+        springDataRepository.deleteByPedidoIdAndLocalId(pedidoId.getValue(), localId.getValue());
+    }
+
+    @Override
+    @Transactional
+    public void eliminarAcumulacionesPorPedido(PedidoId pedidoId, LocalId localId) {
+        springDataRepository.deleteByPedidoIdAndLocalIdAndTipo(
+            pedidoId.getValue(), localId.getValue(), TipoMovimientoPuntos.ACUMULACION.name()
+        );
+    }
+}
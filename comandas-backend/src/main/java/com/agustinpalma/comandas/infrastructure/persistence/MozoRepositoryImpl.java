@@ -0,0 +1,55 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.MozoMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataMozoRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA del repositorio de mozos.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class MozoRepositoryImpl implements MozoRepository {
+
+    private final SpringDataMozoRepository springDataRepository;
+    private final MozoMapper mapper;
+
+    public MozoRepositoryImpl(SpringDataMozoRepository springDataRepository, MozoMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public Mozo guardar(Mozo mozo) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(mozo)));
+    }
+
+    @Override
+    public Optional<Mozo> buscarPorId(MozoId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<Mozo> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findByLocalIdOrderByNombreAsc(localId.getValue()).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public Optional<Mozo> buscarPorTarjeta(LocalId localId, String codigoTarjeta) {
+        return springDataRepository.findByLocalIdAndCodigoTarjeta(localId.getValue(), codigoTarjeta)
+            .map(mapper::toDomain);
+    }
+}
@@ -1,10 +1,15 @@
 package com.agustinpalma.comandas.infrastructure.persistence;
 
+import com.agustinpalma.comandas.domain.exception.PedidoModificadoException;
 import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
 import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.SerieComprobantes;
+import com.agustinpalma.comandas.domain.repository.ArchivoPedidosRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
 import com.agustinpalma.comandas.infrastructure.mapper.PedidoMapper;
 import com.agustinpalma.comandas.infrastructure.persistence.entity.ItemPedidoEntity;
@@ -13,17 +18,22 @@ import com.agustinpalma.comandas.infrastructure.persistence.entity.PedidoEntity;
 import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataPedidoRepository;
 import org.slf4j.Logger;
 import org.slf4j.LoggerFactory;
+import org.springframework.data.domain.PageRequest;
 import org.springframework.stereotype.Repository;
 import org.springframework.transaction.annotation.Transactional;
 
 import java.time.LocalDateTime;
 import java.util.*;
+import java.util.stream.Stream;
 
 
 /**
  * Implementación JPA del repositorio de pedidos.
  * Adaptador que conecta el contrato del dominio con Spring Data JPA.
  * Aquí SÍ viven las anotaciones de Spring, aisladas del dominio.
+ *
+ * HU-162: Las búsquedas por fecha de cierre y la numeración también miran el
+ * archivo, así los casos de uso no se enteran de qué pedidos ya se movieron.
  */
 @Repository
 @Transactional(readOnly = true)
@@ -33,10 +43,13 @@ public class PedidoRepositoryImpl implements PedidoRepository {
     
     private final SpringDataPedidoRepository springDataRepository;
     private final PedidoMapper mapper;
+    private final ArchivoPedidosRepository archivoPedidosRepository;
 
-    public PedidoRepositoryImpl(SpringDataPedidoRepository springDataRepository, PedidoMapper mapper) {
+    public PedidoRepositoryImpl(SpringDataPedidoRepository springDataRepository, PedidoMapper mapper,
+                                ArchivoPedidosRepository archivoPedidosRepository) {
         this.springDataRepository = springDataRepository;
         this.mapper = mapper;
+        this.archivoPedidosRepository = archivoPedidosRepository;
     }
 
     @Override
@@ -52,7 +65,15 @@ public class PedidoRepositoryImpl implements PedidoRepository {
             log.debug("Actualizando pedido existente ID={}", pedido.getId().getValue());
             // Actualizar entidad existente para preservar el contexto de persistencia de JPA
             var entity = entityOpt.get();
+            // HU-165: Todo guardado cuenta como cambio para las otras terminales, y
+            // solo se guarda sobre la versión que se leyó: si otro guardado se
+            // cruzó (otra terminal, otra pestaña), se rechaza en vez de pisarlo
+            if (springDataRepository.incrementarVersion(entity.getId(), pedido.getVersion()) == 0) {
+                long actual = springDataRepository.findVersionById(entity.getId()).orElse(pedido.getVersion());
+                throw new PedidoModificadoException(pedido.getVersion(), actual);
+            }
             sincronizarEntity(entity, pedido);
+            entity.setVersion(pedido.getVersion() + 1);
             log.debug("Pedido sincronizado. Items en entity: {}", entity.getItems().size());
             return mapper.toDomain(entity);
         } else {
@@ -84,6 +105,60 @@ public class PedidoRepositoryImpl implements PedidoRepository {
         entity.setMontoSubtotalFinal(pedido.getMontoSubtotalFinal());
         entity.setMontoDescuentosFinal(pedido.getMontoDescuentosFinal());
         entity.setMontoTotalFinal(pedido.getMontoTotalFinal());
+
+        // HU-105: Sincronizar canje de puntos
+        entity.setPuntosCanjeados(pedido.getPuntosCanjeados());
+        entity.setMontoCanjePuntos(pedido.tieneCanjePuntos() ? pedido.getMontoCanjePuntos() : null);
+
+        // HU-106: Sincronizar turno de caja
+        entity.setTurnoId(pedido.getTurnoId() != null ? pedido.getTurnoId().getValue() : null);
+
+        // HU-108: Sincronizar canal y lista de precios
+        entity.setCanal(pedido.getCanal());
+        entity.setListaPreciosId(pedido.getListaPreciosId() != null ? pedido.getListaPreciosId().getValue() : null);
+        entity.setNombreListaPrecios(pedido.getNombreListaPrecios());
+
+        // HU-110: Sincronizar paso en marcha
+        entity.setTiempoEnMarcha(pedido.getTiempoEnMarcha());
+
+        // HU-111: Sincronizar atención y anulaciones
+        entity.setMozoId(pedido.getMozoId() != null ? pedido.getMozoId().getValue() : null);
+        entity.setCubiertos(pedido.getCubiertos());
+        entity.setPropina(pedido.getPropina());
+        entity.setUnidadesAnuladas(pedido.getUnidadesAnuladas());
+        entity.setMontoAnulado(pedido.getMontoAnulado());
+
+        // HU-113: Sincronizar última actividad de la mesa
+        entity.setUltimaActividad(pedido.getUltimaActividad());
+
+        // HU-137: Sincronizar marca de urgencia
+        entity.setUrgente(pedido.isUrgente());
+
+        // HU-138: Sincronizar horario de retiro prometido
+        entity.setHoraPrometida(pedido.getHoraPrometida());
+
+        // HU-139: Sincronizar entrega de delivery
+        entity.setDireccionEntrega(pedido.getDireccionEntrega());
+        entity.setZonaDeliveryId(pedido.getZonaDeliveryId() != null ? pedido.getZonaDeliveryId().getValue() : null);
+
+        // HU-140: Sincronizar reparto
+        entity.setRepartidorId(pedido.getRepartidorId() != null ? pedido.getRepartidorId().getValue() : null);
+        entity.setSalidaReparto(pedido.getSalidaReparto());
+        entity.setRegresoReparto(pedido.getRegresoReparto());
+
+        // HU-142: Sincronizar reserva y seña
+        entity.setReservaId(pedido.getReservaId() != null ? pedido.getReservaId().getValue() : null);
+        entity.setMontoSenia(pedido.getMontoSenia());
+
+        // HU-159: Sincronizar caja del cobro
+        entity.setCajaId(pedido.getCajaId() != null ? pedido.getCajaId().getValue() : null);
+
+        // HU-166: Sincronizar número de comprobante
+        entity.setNumeroComprobante(pedido.getNumeroComprobante());
+
+        // HU-168: Sincronizar cliente vinculado y promo de cumpleaños
+        entity.setClienteId(pedido.getClienteId() != null ? pedido.getClienteId().getValue() : null);
+        entity.setMontoPromoCumpleanios(pedido.tienePromoCumpleanios() ? pedido.getMontoPromoCumpleanios() : null);
         
         // Sincronizar descuento global
         if (pedido.getDescuentoGlobal() != null) {
@@ -94,12 +169,16 @@ public class PedidoRepositoryImpl implements PedidoRepository {
             entity.setDescGlobalRazon(dg.getRazon());
             entity.setDescGlobalUsuarioId(dg.getUsuarioId());
             entity.setDescGlobalFecha(dg.getFechaAplicacion());
+            entity.setDescGlobalAutorizadoPorId(dg.getAutorizadoPorId() != null ? dg.getAutorizadoPorId().getValue() : null);
+            entity.setDescGlobalAutorizadoPor(dg.getAutorizadoPor());
         } else {
             entity.setDescGlobalTipo(null);
             entity.setDescGlobalValor(null);
             entity.setDescGlobalRazon(null);
             entity.setDescGlobalUsuarioId(null);
             entity.setDescGlobalFecha(null);
+            entity.setDescGlobalAutorizadoPorId(null);
+            entity.setDescGlobalAutorizadoPor(null);
         }
         
         // Sincronizar ítems: crear mapa de ítems existentes por ID
@@ -147,6 +226,12 @@ public class PedidoRepositoryImpl implements PedidoRepository {
                 
                 // HU-29: Sincronizar cantidad enviada a cocina
                 existingItem.setCantidadEnviadaCocina(domainItem.getCantidadEnviadaCocina());
+
+                // HU-110: Sincronizar paso de servicio
+                existingItem.setTiempoServicio(domainItem.getTiempoServicio());
+
+                // HU-137: Sincronizar urgencia del ítem
+                existingItem.setUrgente(domainItem.isUrgente());
                 
                 // Actualizar descuento manual si existe
                 if (domainItem.getDescuentoManual() != null) {
@@ -156,12 +241,17 @@ public class PedidoRepositoryImpl implements PedidoRepository {
                     existingItem.setDescManualRazon(dm.getRazon());
                     existingItem.setDescManualUsuarioId(dm.getUsuarioId());
                     existingItem.setDescManualFecha(dm.getFechaAplicacion());
+                    existingItem.setDescManualAutorizadoPorId(
+                        dm.getAutorizadoPorId() != null ? dm.getAutorizadoPorId().getValue() : null);
+                    existingItem.setDescManualAutorizadoPor(dm.getAutorizadoPor());
                 } else {
                     existingItem.setDescManualTipo(null);
                     existingItem.setDescManualValor(null);
                     existingItem.setDescManualRazon(null);
                     existingItem.setDescManualUsuarioId(null);
                     existingItem.setDescManualFecha(null);
+                    existingItem.setDescManualAutorizadoPorId(null);
+                    existingItem.setDescManualAutorizadoPor(null);
                 }
 
                 // HU-05.1 + HU-22: Sincronizar extras
@@ -185,14 +275,38 @@ public class PedidoRepositoryImpl implements PedidoRepository {
         log.debug("Sincronización completada. Items finales en entity: {}", entity.getItems().size());
         
         // HU-14: Sincronizar pagos (agregar al cerrar, eliminar al reabrir con orphanRemoval)
-        // La reapertura limpia pedido.getPagos(), por lo que entity.getPagos().clear() activará orphanRemoval
-        entity.getPagos().clear(); // Limpia todos los pagos del entity (orphanRemoval los eliminará físicamente)
+        // HU-160: por id, como los ítems: los pagos a cuenta ya guardados no se recrean
+        Set<UUID> pagosDominio = new HashSet<>();
         for (var pagoDominio : pedido.getPagos()) {
+            pagosDominio.add(pagoDominio.getId().getValue());
+        }
+        entity.getPagos().removeIf(pagoEntity -> !pagosDominio.contains(pagoEntity.getId()));
+
+        Map<UUID, PagoEntity> pagosExistentes = new HashMap<>();
+        for (PagoEntity pagoEntity : entity.getPagos()) {
+            pagosExistentes.put(pagoEntity.getId(), pagoEntity);
+        }
+        for (var pagoDominio : pedido.getPagos()) {
+            PagoEntity existente = pagosExistentes.get(pagoDominio.getId().getValue());
+            if (existente != null) {
+                // Redondeo y referencia pueden cambiar sin cambiar el pago
+                existente.setAjusteRedondeo(pagoDominio.getAjusteRedondeo());
+                existente.setReferencia(pagoDominio.getReferencia());
+                existente.setCajaId(pagoDominio.getCajaId() != null ? pagoDominio.getCajaId().getValue() : null);
+                continue;
+            }
             var pagoEntity = new PagoEntity(
-                UUID.randomUUID(),  // Generar UUID manualmente para consistencia con SQLite
+                pagoDominio.getId().getValue(),
                 pagoDominio.getMedio(),
                 pagoDominio.getMonto(),
-                pagoDominio.getFecha()
+                pagoDominio.getFecha(),
+                pagoDominio.getRecargo(),
+                pagoDominio.getMoneda(),
+                pagoDominio.getMontoOriginal(),
+                pagoDominio.getTipoCambio(),
+                pagoDominio.getAjusteRedondeo(),
+                pagoDominio.getReferencia(),
+                pagoDominio.getCajaId() != null ? pagoDominio.getCajaId().getValue() : null
             );
             entity.agregarPago(pagoEntity);
         }
@@ -222,16 +336,92 @@ public class PedidoRepositoryImpl implements PedidoRepository {
 
     @Override
     public int obtenerSiguienteNumero(LocalId localId) {
-        int maxNumero = springDataRepository.findMaxNumeroByLocalId(localId.getValue());
+        int maxNumero = Math.max(
+            springDataRepository.findMaxNumeroByLocalId(localId.getValue()),
+            archivoPedidosRepository.obtenerMaximoNumero(localId)
+        );
         return maxNumero + 1;
     }
 
+    @Override
+    public Optional<String> buscarUltimoComprobante(LocalId localId, String encabezado) {
+        return Stream.of(
+                springDataRepository.findUltimoComprobante(localId.getValue(), patronComprobante(encabezado)),
+                archivoPedidosRepository.buscarUltimoComprobante(localId, encabezado))
+            .flatMap(Optional::stream)
+            .max(Comparator.naturalOrder());
+    }
+
+    /**
+     * HU-166: Un "_" por dígito deja afuera los números de otro formato con
+     * el mismo comienzo ("B1-2026-00000001" no entra en "B1-________").
+     * El prefijo es solo letras y números, así que no hay comodines que escapar.
+     */
+    static String patronComprobante(String encabezado) {
+        return encabezado + "_".repeat(SerieComprobantes.DIGITOS_NUMERO);
+    }
+
     @Override
     public List<Pedido> buscarCerradosPorFecha(LocalId localId, LocalDateTime inicio, LocalDateTime fin) {
-        return springDataRepository
+        List<Pedido> cerrados = new ArrayList<>(archivoPedidosRepository.buscarCerradosPorFecha(localId, inicio, fin));
+        springDataRepository
             .findCerradosByLocalIdAndFechaCierreBetween(localId.getValue(), inicio, fin)
             .stream()
             .map(mapper::toDomain)
+            .forEach(cerrados::add);
+        return cerrados;
+    }
+
+    @Override
+    public List<Pedido> buscarAbiertosPorLocal(LocalId localId) {
+        return springDataRepository
+            .findAbiertosByLocalId(localId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public List<Pedido> buscarRepartosEntre(LocalId localId, LocalDateTime inicio, LocalDateTime fin) {
+        return springDataRepository
+            .findRepartosByLocalIdAndFechaAperturaBetween(localId.getValue(), inicio, fin)
+            .stream()
+            .map(mapper::toDomain)
             .toList();
     }
+
+    @Override
+    public List<Pedido> buscarCerradosPorTurno(LocalId localId, TurnoCajaId turnoId) {
+        return springDataRepository
+            .findCerradosByLocalIdAndTurnoId(localId.getValue(), turnoId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public List<Pedido> buscarCerradosPorCliente(ClienteId clienteId, LocalId localId) {
+        return springDataRepository
+            .findCerradosByLocalIdAndClienteId(localId.getValue(), clienteId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public List<Pedido> buscarCerradosAntesDe(LocalDateTime limite, int maximo) {
+        List<UUID> ids = springDataRepository.findIdsCerradosAntesDe(limite, PageRequest.of(0, maximo));
+        return springDataRepository.findAllById(ids)
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    @Transactional
+    public void eliminar(List<PedidoId> ids) {
+        // deleteAll por entidad (y no un DELETE masivo) para que JPA borre ítems y pagos en cascada
+        springDataRepository.deleteAll(springDataRepository.findAllById(
+            ids.stream().map(PedidoId::getValue).toList()));
+    }
 }
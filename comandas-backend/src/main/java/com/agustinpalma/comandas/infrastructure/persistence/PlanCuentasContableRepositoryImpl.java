@@ -0,0 +1,42 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.PlanCuentasContable;
+import com.agustinpalma.comandas.domain.repository.PlanCuentasContableRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.PlanCuentasContableMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataPlanCuentasContableRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Optional;
+
+/**
+ * Implementación JPA de PlanCuentasContableRepository.
+ * HU-167: Exportación al sistema contable.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class PlanCuentasContableRepositoryImpl implements PlanCuentasContableRepository {
+
+    private final SpringDataPlanCuentasContableRepository springDataRepository;
+    private final PlanCuentasContableMapper mapper;
+
+    public PlanCuentasContableRepositoryImpl(SpringDataPlanCuentasContableRepository springDataRepository,
+                                             PlanCuentasContableMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    public Optional<PlanCuentasContable> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findById(localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    @Transactional
+    public PlanCuentasContable guardar(PlanCuentasContable plan) {
+        var guardado = springDataRepository.save(mapper.toEntity(plan));
+        return mapper.toDomain(guardado);
+    }
+}
@@ -3,15 +3,21 @@ package com.agustinpalma.comandas.infrastructure.persistence;
 import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.FotoProducto;
 import com.agustinpalma.comandas.domain.model.Producto;
 import com.agustinpalma.comandas.domain.repository.ProductoRepository;
 import com.agustinpalma.comandas.infrastructure.mapper.ProductoMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.FotoProductoEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataFotoProductoRepository;
 import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataProductoRepository;
 import org.springframework.stereotype.Repository;
 import org.springframework.transaction.annotation.Transactional;
 
+import java.time.LocalDateTime;
 import java.util.List;
+import java.util.Map;
 import java.util.Optional;
+import java.util.stream.Collectors;
 
 /**
  * Implementación JPA del repositorio de productos.
@@ -23,10 +29,14 @@ import java.util.Optional;
 public class ProductoRepositoryImpl implements ProductoRepository {
 
     private final SpringDataProductoRepository springDataRepository;
+    private final SpringDataFotoProductoRepository fotoRepository;
     private final ProductoMapper mapper;
 
-    public ProductoRepositoryImpl(SpringDataProductoRepository springDataRepository, ProductoMapper mapper) {
+    public ProductoRepositoryImpl(SpringDataProductoRepository springDataRepository,
+                                  SpringDataFotoProductoRepository fotoRepository,
+                                  ProductoMapper mapper) {
         this.springDataRepository = springDataRepository;
+        this.fotoRepository = fotoRepository;
         this.mapper = mapper;
     }
 
@@ -105,6 +115,8 @@ public class ProductoRepositoryImpl implements ProductoRepository {
     @Override
     @Transactional
     public void eliminar(ProductoId id) {
+        // HU-127: SQLite (modo offline) no aplica el ON DELETE CASCADE de la migración
+        fotoRepository.deleteById(id.getValue());
         springDataRepository.deleteById(id.getValue());
     }
     
@@ -134,4 +146,37 @@ public class ProductoRepositoryImpl implements ProductoRepository {
         .map(mapper::toDomain)
         .toList();
     }
+
+    @Override
+    @Transactional
+    public void guardarFoto(ProductoId id, LocalId localId, FotoProducto foto) {
+        fotoRepository.save(new FotoProductoEntity(
+            id.getValue(),
+            localId.getValue(),
+            foto.getContentType(),
+            foto.getContenido(),
+            foto.getActualizadaEn()
+        ));
+    }
+
+    @Override
+    public Optional<FotoProducto> buscarFoto(ProductoId id, LocalId localId) {
+        return fotoRepository.findByProductoIdAndLocalId(id.getValue(), localId.getValue())
+            .map(entity -> new FotoProducto(entity.getContentType(), entity.getContenido(), entity.getActualizadaEn()));
+    }
+
+    @Override
+    @Transactional
+    public void eliminarFoto(ProductoId id, LocalId localId) {
+        fotoRepository.deleteByProductoIdAndLocalId(id.getValue(), localId.getValue());
+    }
+
+    @Override
+    public Map<ProductoId, LocalDateTime> buscarVersionesFotos(LocalId localId) {
+        return fotoRepository.findByLocalId(localId.getValue()).stream()
+            .collect(Collectors.toMap(
+                version -> new ProductoId(version.getProductoId()),
+                SpringDataFotoProductoRepository.VersionFoto::getActualizadaEn
+            ));
+    }
 }
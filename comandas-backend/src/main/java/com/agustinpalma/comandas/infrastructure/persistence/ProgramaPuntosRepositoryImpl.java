@@ -0,0 +1,42 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.ProgramaPuntos;
+import com.agustinpalma.comandas.domain.repository.ProgramaPuntosRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.ProgramaPuntosMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataProgramaPuntosRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Optional;
+
+/**
+ * JPA implementation of ProgramaPuntosRepository.
+ * HU-105: Customer loyalty points.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class ProgramaPuntosRepositoryImpl implements ProgramaPuntosRepository {
+
+    private final SpringDataProgramaPuntosRepository springDataRepository;
+    private final ProgramaPuntosMapper mapper;
+
+    public ProgramaPuntosRepositoryImpl(SpringDataProgramaPuntosRepository springDataRepository, ProgramaPuntosMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    public Optional<ProgramaPuntos> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findById(localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    @Transactional
+    public ProgramaPuntos guardar(ProgramaPuntos programa) {
+        var entity = mapper.toEntity(programa);
+        var guardado = springDataRepository.save(entity);
+        return mapper.toDomain(guardado);
+    }
+}
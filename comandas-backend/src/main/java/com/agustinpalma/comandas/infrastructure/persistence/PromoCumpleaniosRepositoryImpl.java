@@ -0,0 +1,43 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.PromoCumpleanios;
+import com.agustinpalma.comandas.domain.repository.PromoCumpleaniosRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.PromoCumpleaniosMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataPromoCumpleaniosRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Optional;
+
+/**
+ * JPA implementation of PromoCumpleaniosRepository.
+ * HU-168: Customer profile and birthday promo.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class PromoCumpleaniosRepositoryImpl implements PromoCumpleaniosRepository {
+
+    private final SpringDataPromoCumpleaniosRepository springDataRepository;
+    private final PromoCumpleaniosMapper mapper;
+
+    public PromoCumpleaniosRepositoryImpl(SpringDataPromoCumpleaniosRepository springDataRepository,
+                                          PromoCumpleaniosMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    public Optional<PromoCumpleanios> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findById(localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    @Transactional
+    public PromoCumpleanios guardar(PromoCumpleanios promo) {
+        var entity = mapper.toEntity(promo);
+        var guardado = springDataRepository.save(entity);
+        return mapper.toDomain(guardado);
+    }
+}
@@ -0,0 +1,49 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+import com.agustinpalma.comandas.domain.model.Proveedor;
+import com.agustinpalma.comandas.domain.repository.ProveedorRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.ProveedorMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataProveedorRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA del repositorio de proveedores.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class ProveedorRepositoryImpl implements ProveedorRepository {
+
+    private final SpringDataProveedorRepository springDataRepository;
+    private final ProveedorMapper mapper;
+
+    public ProveedorRepositoryImpl(SpringDataProveedorRepository springDataRepository, ProveedorMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public Proveedor guardar(Proveedor proveedor) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(proveedor)));
+    }
+
+    @Override
+    public Optional<Proveedor> buscarPorId(ProveedorId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<Proveedor> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findByLocalIdOrderByNombreAsc(localId.getValue()).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
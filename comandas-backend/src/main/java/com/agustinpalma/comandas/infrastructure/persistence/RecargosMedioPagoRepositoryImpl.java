@@ -0,0 +1,42 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.RecargosMedioPago;
+import com.agustinpalma.comandas.domain.repository.RecargosMedioPagoRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.RecargosMedioPagoMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataRecargosMedioPagoRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Optional;
+
+/**
+ * Implementación JPA de RecargosMedioPagoRepository.
+ * HU-143: Recargo configurable por medio de pago.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class RecargosMedioPagoRepositoryImpl implements RecargosMedioPagoRepository {
+
+    private final SpringDataRecargosMedioPagoRepository springDataRepository;
+    private final RecargosMedioPagoMapper mapper;
+
+    public RecargosMedioPagoRepositoryImpl(SpringDataRecargosMedioPagoRepository springDataRepository,
+                                           RecargosMedioPagoMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    public Optional<RecargosMedioPago> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findById(localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    @Transactional
+    public RecargosMedioPago guardar(RecargosMedioPago recargos) {
+        var guardado = springDataRepository.save(mapper.toEntity(recargos));
+        return mapper.toDomain(guardado);
+    }
+}
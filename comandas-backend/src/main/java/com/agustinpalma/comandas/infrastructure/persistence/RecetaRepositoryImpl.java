@@ -0,0 +1,55 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Receta;
+import com.agustinpalma.comandas.domain.repository.RecetaRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.RecetaMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataRecetaRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA del repositorio de recetas.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class RecetaRepositoryImpl implements RecetaRepository {
+
+    private final SpringDataRecetaRepository springDataRepository;
+    private final RecetaMapper mapper;
+
+    public RecetaRepositoryImpl(SpringDataRecetaRepository springDataRepository, RecetaMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public Receta guardar(Receta receta) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(receta)));
+    }
+
+    @Override
+    public Optional<Receta> buscarPorProducto(ProductoId productoId, LocalId localId) {
+        return springDataRepository.findByProductoIdAndLocalId(productoId.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<Receta> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findByLocalId(localId.getValue()).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    @Transactional
+    public void eliminar(ProductoId productoId, LocalId localId) {
+        springDataRepository.deleteByProductoIdAndLocalId(productoId.getValue(), localId.getValue());
+    }
+}
@@ -0,0 +1,42 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.RedondeoEfectivo;
+import com.agustinpalma.comandas.domain.repository.RedondeoEfectivoRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.RedondeoEfectivoMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataRedondeoEfectivoRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Optional;
+
+/**
+ * Implementación JPA de RedondeoEfectivoRepository.
+ * HU-152: Reglas de redondeo configurables en el total.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class RedondeoEfectivoRepositoryImpl implements RedondeoEfectivoRepository {
+
+    private final SpringDataRedondeoEfectivoRepository springDataRepository;
+    private final RedondeoEfectivoMapper mapper;
+
+    public RedondeoEfectivoRepositoryImpl(SpringDataRedondeoEfectivoRepository springDataRepository,
+                                          RedondeoEfectivoMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    public Optional<RedondeoEfectivo> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findById(localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    @Transactional
+    public RedondeoEfectivo guardar(RedondeoEfectivo redondeo) {
+        var guardado = springDataRepository.save(mapper.toEntity(redondeo));
+        return mapper.toDomain(guardado);
+    }
+}
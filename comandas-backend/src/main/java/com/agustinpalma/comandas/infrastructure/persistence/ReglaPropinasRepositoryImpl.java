@@ -0,0 +1,42 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.ReglaPropinas;
+import com.agustinpalma.comandas.domain.repository.ReglaPropinasRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.ReglaPropinasMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataReglaPropinasRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.Optional;
+
+/**
+ * Implementación JPA de ReglaPropinasRepository.
+ * HU-141: Pozo de propinas y distribución.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class ReglaPropinasRepositoryImpl implements ReglaPropinasRepository {
+
+    private final SpringDataReglaPropinasRepository springDataRepository;
+    private final ReglaPropinasMapper mapper;
+
+    public ReglaPropinasRepositoryImpl(SpringDataReglaPropinasRepository springDataRepository,
+                                       ReglaPropinasMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    public Optional<ReglaPropinas> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findById(localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    @Transactional
+    public ReglaPropinas guardar(ReglaPropinas regla) {
+        var guardada = springDataRepository.save(mapper.toEntity(regla));
+        return mapper.toDomain(guardada);
+    }
+}
@@ -0,0 +1,48 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+import com.agustinpalma.comandas.domain.model.RendicionRepartidor;
+import com.agustinpalma.comandas.domain.repository.RendicionRepartidorRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.RendicionRepartidorMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataRendicionRepartidorRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+
+/**
+ * Implementación JPA de RendicionRepartidorRepository.
+ * HU-140: Asignación y seguimiento de repartidores.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class RendicionRepartidorRepositoryImpl implements RendicionRepartidorRepository {
+
+    private final SpringDataRendicionRepartidorRepository springDataRepository;
+    private final RendicionRepartidorMapper mapper;
+
+    public RendicionRepartidorRepositoryImpl(
+            SpringDataRendicionRepartidorRepository springDataRepository,
+            RendicionRepartidorMapper mapper
+    ) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public RendicionRepartidor guardar(RendicionRepartidor rendicion) {
+        var guardada = springDataRepository.save(mapper.toEntity(rendicion));
+        return mapper.toDomain(guardada);
+    }
+
+    @Override
+    public List<RendicionRepartidor> buscarPorTurno(TurnoCajaId turnoId, LocalId localId) {
+        return springDataRepository
+            .findByTurnoIdAndLocalIdOrderByFechaAsc(turnoId.getValue(), localId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
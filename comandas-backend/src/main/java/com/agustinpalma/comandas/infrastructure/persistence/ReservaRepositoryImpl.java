@@ -0,0 +1,66 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoReserva;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ReservaId;
+import com.agustinpalma.comandas.domain.model.Reserva;
+import com.agustinpalma.comandas.domain.repository.ReservaRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.ReservaMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataReservaRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA de ReservaRepository.
+ * HU-142: Señas de reservas aplicadas a la cuenta.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class ReservaRepositoryImpl implements ReservaRepository {
+
+    private final SpringDataReservaRepository springDataRepository;
+    private final ReservaMapper mapper;
+
+    public ReservaRepositoryImpl(SpringDataReservaRepository springDataRepository, ReservaMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public Reserva guardar(Reserva reserva) {
+        var guardada = springDataRepository.save(mapper.toEntity(reserva));
+        return mapper.toDomain(guardada);
+    }
+
+    @Override
+    public Optional<Reserva> buscarPorId(ReservaId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<Reserva> buscarEntre(LocalId localId, LocalDateTime inicio, LocalDateTime fin) {
+        return springDataRepository
+            .findByLocalIdAndFechaHoraGreaterThanEqualAndFechaHoraLessThanOrderByFechaHoraAsc(
+                localId.getValue(), inicio, fin)
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public List<Reserva> buscarPendientesPorMesa(MesaId mesaId, LocalId localId) {
+        return springDataRepository
+            .findByMesaIdAndLocalIdAndEstadoOrderByFechaHoraAsc(
+                mesaId.getValue(), localId.getValue(), EstadoReserva.PENDIENTE)
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
@@ -0,0 +1,76 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.SerieComprobantes;
+import com.agustinpalma.comandas.domain.repository.SerieComprobantesRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.SerieComprobantesMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.SerieComprobantesEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataSerieComprobantesRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Implementación JPA del repositorio de series de comprobantes.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class SerieComprobantesRepositoryImpl implements SerieComprobantesRepository {
+
+    private final SpringDataSerieComprobantesRepository springDataRepository;
+    private final SerieComprobantesMapper mapper;
+
+    public SerieComprobantesRepositoryImpl(SpringDataSerieComprobantesRepository springDataRepository,
+                                           SerieComprobantesMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public SerieComprobantes guardar(SerieComprobantes serie) {
+        UUID localId = serie.getLocalId().getValue();
+        UUID cajaId = serie.getCajaId() != null ? serie.getCajaId().getValue() : null;
+        SerieComprobantesEntity entity = buscarEntity(localId, cajaId)
+            .orElseGet(() -> new SerieComprobantesEntity(UUID.randomUUID(), localId, cajaId));
+        mapper.actualizar(entity, serie);
+        return mapper.toDomain(springDataRepository.save(entity));
+    }
+
+    @Override
+    public Optional<SerieComprobantes> buscar(LocalId localId, CajaId cajaId) {
+        return buscarEntity(localId.getValue(), cajaId != null ? cajaId.getValue() : null)
+            .map(mapper::toDomain);
+    }
+
+    /**
+     * Corre en la transacción del cierre (no abre una propia de solo lectura):
+     * el bloqueo dura hasta que el cierre confirma o vuelve atrás.
+     */
+    @Override
+    @Transactional
+    public Optional<SerieComprobantes> buscarParaAsignar(LocalId localId, CajaId cajaId) {
+        Optional<SerieComprobantesEntity> entity = cajaId == null
+            ? springDataRepository.bloquearPrincipal(localId.getValue())
+            : springDataRepository.bloquear(localId.getValue(), cajaId.getValue());
+        return entity.map(mapper::toDomain);
+    }
+
+    @Override
+    public List<SerieComprobantes> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findByLocalId(localId.getValue()).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    private Optional<SerieComprobantesEntity> buscarEntity(UUID localId, UUID cajaId) {
+        return cajaId == null
+            ? springDataRepository.findByLocalIdAndCajaIdIsNull(localId)
+            : springDataRepository.findByLocalIdAndCajaId(localId, cajaId);
+    }
+}
@@ -0,0 +1,53 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.SesionEmpleado;
+import com.agustinpalma.comandas.domain.repository.SesionEmpleadoRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.SesionEmpleadoMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataSesionEmpleadoRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDateTime;
+import java.util.Optional;
+
+/**
+ * Implementación JPA de SesionEmpleadoRepository.
+ * HU-169: Sesiones de los empleados identificados con su PIN.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class SesionEmpleadoRepositoryImpl implements SesionEmpleadoRepository {
+
+    private final SpringDataSesionEmpleadoRepository springDataRepository;
+    private final SesionEmpleadoMapper mapper;
+
+    public SesionEmpleadoRepositoryImpl(SpringDataSesionEmpleadoRepository springDataRepository,
+                                        SesionEmpleadoMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public void guardar(SesionEmpleado sesion) {
+        springDataRepository.save(mapper.toEntity(sesion));
+    }
+
+    @Override
+    public Optional<SesionEmpleado> buscarPorHash(String hashToken) {
+        return springDataRepository.findById(hashToken)
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    @Transactional
+    public void eliminar(String hashToken) {
+        springDataRepository.deleteById(hashToken);
+    }
+
+    @Override
+    @Transactional
+    public void eliminarVencidas(LocalDateTime ahora) {
+        springDataRepository.deleteVencidas(ahora);
+    }
+}
@@ -0,0 +1,60 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoSolicitudAutopedido;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.SolicitudAutopedidoId;
+import com.agustinpalma.comandas.domain.model.SolicitudAutopedido;
+import com.agustinpalma.comandas.domain.repository.SolicitudAutopedidoRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.SolicitudAutopedidoMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataSolicitudAutopedidoRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA del repositorio de pedidos del QR.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class SolicitudAutopedidoRepositoryImpl implements SolicitudAutopedidoRepository {
+
+    private final SpringDataSolicitudAutopedidoRepository springDataRepository;
+    private final SolicitudAutopedidoMapper mapper;
+
+    public SolicitudAutopedidoRepositoryImpl(SpringDataSolicitudAutopedidoRepository springDataRepository,
+                                             SolicitudAutopedidoMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public SolicitudAutopedido guardar(SolicitudAutopedido solicitud) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(solicitud)));
+    }
+
+    @Override
+    public Optional<SolicitudAutopedido> buscarPorId(SolicitudAutopedidoId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<SolicitudAutopedido> buscarPendientes(LocalId localId) {
+        return springDataRepository
+            .findByLocalIdAndEstadoOrderByCreadaEnAsc(localId.getValue(), EstadoSolicitudAutopedido.PENDIENTE)
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public long contarPendientesPorMesa(LocalId localId, MesaId mesaId) {
+        return springDataRepository.countByLocalIdAndMesaIdAndEstado(
+            localId.getValue(), mesaId.getValue(), EstadoSolicitudAutopedido.PENDIENTE);
+    }
+}
@@ -0,0 +1,65 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.TarjetaRegalo;
+import com.agustinpalma.comandas.domain.repository.TarjetaRegaloRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.TarjetaRegaloMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataTarjetaRegaloRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA de TarjetaRegaloRepository.
+ * HU-144: Gift cards y vouchers prepagos.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class TarjetaRegaloRepositoryImpl implements TarjetaRegaloRepository {
+
+    private final SpringDataTarjetaRegaloRepository springDataRepository;
+    private final TarjetaRegaloMapper mapper;
+
+    public TarjetaRegaloRepositoryImpl(SpringDataTarjetaRegaloRepository springDataRepository,
+                                       TarjetaRegaloMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public TarjetaRegalo guardar(TarjetaRegalo tarjeta) {
+        var guardada = springDataRepository.save(mapper.toEntity(tarjeta));
+        return mapper.toDomain(guardada);
+    }
+
+    @Override
+    public Optional<TarjetaRegalo> buscarPorCodigo(String codigo, LocalId localId) {
+        return springDataRepository.findByCodigoAndLocalId(codigo, localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public boolean existeCodigo(String codigo, LocalId localId) {
+        return springDataRepository.existsByCodigoAndLocalId(codigo, localId.getValue());
+    }
+
+    @Override
+    public List<TarjetaRegalo> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findByLocalIdOrderByFechaEmisionDesc(localId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public List<TarjetaRegalo> buscarUsadasEnPedido(PedidoId pedidoId, LocalId localId) {
+        return springDataRepository.findUsadasEnPedido(pedidoId.getValue(), localId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
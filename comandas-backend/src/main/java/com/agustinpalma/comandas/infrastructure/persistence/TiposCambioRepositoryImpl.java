@@ -0,0 +1,49 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.TiposCambio;
+import com.agustinpalma.comandas.domain.repository.TiposCambioRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.TiposCambioMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.TiposCambioEntity;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataTiposCambioRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDate;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Implementación JPA de TiposCambioRepository.
+ * HU-151: Cobros en moneda extranjera.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class TiposCambioRepositoryImpl implements TiposCambioRepository {
+
+    private final SpringDataTiposCambioRepository springDataRepository;
+    private final TiposCambioMapper mapper;
+
+    public TiposCambioRepositoryImpl(SpringDataTiposCambioRepository springDataRepository,
+                                     TiposCambioMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    public Optional<TiposCambio> buscarPorFecha(LocalId localId, LocalDate fecha) {
+        return springDataRepository.findByLocalIdAndFecha(localId.getValue(), fecha)
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    @Transactional
+    public TiposCambio guardar(TiposCambio tiposCambio) {
+        // La identidad es local + día: se reutiliza la fila si ya existe
+        UUID id = springDataRepository.findByLocalIdAndFecha(tiposCambio.getLocalId().getValue(), tiposCambio.getFecha())
+            .map(TiposCambioEntity::getId)
+            .orElseGet(UUID::randomUUID);
+        var guardado = springDataRepository.save(mapper.toEntity(tiposCambio, id));
+        return mapper.toDomain(guardado);
+    }
+}
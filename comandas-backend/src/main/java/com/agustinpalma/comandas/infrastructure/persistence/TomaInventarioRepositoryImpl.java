@@ -0,0 +1,50 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TomaInventarioId;
+import com.agustinpalma.comandas.domain.model.TomaInventario;
+import com.agustinpalma.comandas.domain.repository.TomaInventarioRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.TomaInventarioMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataTomaInventarioRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA del repositorio de tomas de inventario.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class TomaInventarioRepositoryImpl implements TomaInventarioRepository {
+
+    private final SpringDataTomaInventarioRepository springDataRepository;
+    private final TomaInventarioMapper mapper;
+
+    public TomaInventarioRepositoryImpl(SpringDataTomaInventarioRepository springDataRepository,
+                                        TomaInventarioMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public TomaInventario guardar(TomaInventario toma) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(toma)));
+    }
+
+    @Override
+    public Optional<TomaInventario> buscarPorId(TomaInventarioId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<TomaInventario> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findByLocalIdOrderByFechaDesc(localId.getValue()).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
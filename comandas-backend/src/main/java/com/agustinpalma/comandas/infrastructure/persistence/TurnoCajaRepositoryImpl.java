@@ -0,0 +1,74 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoTurno;
+import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.TurnoCajaMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataTurnoCajaRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA del repositorio de turnos de caja.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class TurnoCajaRepositoryImpl implements TurnoCajaRepository {
+
+    private final SpringDataTurnoCajaRepository springDataRepository;
+    private final TurnoCajaMapper mapper;
+
+    public TurnoCajaRepositoryImpl(SpringDataTurnoCajaRepository springDataRepository,
+                                   TurnoCajaMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public TurnoCaja guardar(TurnoCaja turno) {
+        var entity = mapper.toEntity(turno);
+        var guardado = springDataRepository.save(entity);
+        return mapper.toDomain(guardado);
+    }
+
+    @Override
+    public Optional<TurnoCaja> buscarAbierto(LocalId localId) {
+        return springDataRepository.findByLocalIdAndEstado(
+            localId.getValue(), EstadoTurno.ABIERTO
+        ).map(mapper::toDomain);
+    }
+
+    @Override
+    public List<TurnoCaja> buscarPorJornada(JornadaCajaId jornadaId, LocalId localId) {
+        return springDataRepository
+            .findByJornadaIdAndLocalIdOrderByFechaAperturaAsc(jornadaId.getValue(), localId.getValue())
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public List<TurnoCaja> buscarCerradosPorFecha(LocalId localId, LocalDateTime inicio, LocalDateTime fin) {
+        return springDataRepository
+            .findByLocalIdAndEstadoAndFechaCierreBetweenOrderByFechaCierreAsc(
+                localId.getValue(), EstadoTurno.CERRADO, inicio, fin)
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+
+    @Override
+    public Optional<TurnoCaja> buscarPorId(TurnoCajaId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+}
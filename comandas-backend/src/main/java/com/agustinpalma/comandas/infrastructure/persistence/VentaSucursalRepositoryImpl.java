@@ -0,0 +1,51 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.VentaSucursal;
+import com.agustinpalma.comandas.domain.repository.VentaSucursalRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.VentaSucursalMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataVentaSucursalRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.time.LocalDateTime;
+import java.util.List;
+
+/**
+ * Implementación JPA del repositorio de ventas consolidadas de sucursales.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class VentaSucursalRepositoryImpl implements VentaSucursalRepository {
+
+    private final SpringDataVentaSucursalRepository springDataRepository;
+    private final VentaSucursalMapper mapper;
+
+    public VentaSucursalRepositoryImpl(SpringDataVentaSucursalRepository springDataRepository,
+                                       VentaSucursalMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    /**
+     * save() hace merge por clave primaria (pedido de origen): una venta
+     * reenviada reemplaza a la anterior, incluidos sus pagos.
+     */
+    @Override
+    @Transactional
+    public void guardarTodas(List<VentaSucursal> ventas) {
+        springDataRepository.saveAll(ventas.stream().map(mapper::toEntity).toList());
+    }
+
+    @Override
+    public List<VentaSucursal> buscarPorFechaCierre(LocalId localId, LocalDateTime inicio, LocalDateTime fin) {
+        return springDataRepository
+            .findByLocalIdAndFechaCierreGreaterThanEqualAndFechaCierreLessThanOrderByFechaCierreAsc(
+                localId.getValue(), inicio, fin
+            )
+            .stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
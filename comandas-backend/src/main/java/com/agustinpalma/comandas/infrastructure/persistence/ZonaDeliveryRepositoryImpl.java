@@ -0,0 +1,50 @@
+package com.agustinpalma.comandas.infrastructure.persistence;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ZonaDeliveryId;
+import com.agustinpalma.comandas.domain.model.ZonaDelivery;
+import com.agustinpalma.comandas.domain.repository.ZonaDeliveryRepository;
+import com.agustinpalma.comandas.infrastructure.mapper.ZonaDeliveryMapper;
+import com.agustinpalma.comandas.infrastructure.persistence.jpa.SpringDataZonaDeliveryRepository;
+import org.springframework.stereotype.Repository;
+import org.springframework.transaction.annotation.Transactional;
+
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Implementación JPA del repositorio de zonas de reparto.
+ * Adaptador que conecta el contrato del dominio con Spring Data JPA.
+ */
+@Repository
+@Transactional(readOnly = true)
+public class ZonaDeliveryRepositoryImpl implements ZonaDeliveryRepository {
+
+    private final SpringDataZonaDeliveryRepository springDataRepository;
+    private final ZonaDeliveryMapper mapper;
+
+    public ZonaDeliveryRepositoryImpl(SpringDataZonaDeliveryRepository springDataRepository,
+                                      ZonaDeliveryMapper mapper) {
+        this.springDataRepository = springDataRepository;
+        this.mapper = mapper;
+    }
+
+    @Override
+    @Transactional
+    public ZonaDelivery guardar(ZonaDelivery zona) {
+        return mapper.toDomain(springDataRepository.save(mapper.toEntity(zona)));
+    }
+
+    @Override
+    public Optional<ZonaDelivery> buscarPorId(ZonaDeliveryId id, LocalId localId) {
+        return springDataRepository.findByIdAndLocalId(id.getValue(), localId.getValue())
+            .map(mapper::toDomain);
+    }
+
+    @Override
+    public List<ZonaDelivery> buscarPorLocal(LocalId localId) {
+        return springDataRepository.findByLocalIdOrderByNombreAsc(localId.getValue()).stream()
+            .map(mapper::toDomain)
+            .toList();
+    }
+}
@@ -0,0 +1,40 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+import java.util.UUID;
+
+/**
+ * JPA entity for the per-local forgotten tables alert configuration.
+ * HU-113: Forgotten tables alert.
+ */
+@Entity
+@Table(name = "alertas_mesas_olvidadas")
+public class AlertaMesasOlvidadasEntity {
+
+    @Id
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "activa", nullable = false)
+    private boolean activa;
+
+    @Column(name = "minutos_sin_actividad", nullable = false)
+    private int minutosSinActividad;
+
+    public AlertaMesasOlvidadasEntity() {}
+
+    public AlertaMesasOlvidadasEntity(UUID localId, boolean activa, int minutosSinActividad) {
+        this.localId = localId;
+        this.activa = activa;
+        this.minutosSinActividad = minutosSinActividad;
+    }
+
+    public UUID getLocalId() { return localId; }
+    public void setLocalId(UUID localId) { this.localId = localId; }
+
+    public boolean isActiva() { return activa; }
+    public void setActiva(boolean activa) { this.activa = activa; }
+
+    public int getMinutosSinActividad() { return minutosSinActividad; }
+    public void setMinutosSinActividad(int minutosSinActividad) { this.minutosSinActividad = minutosSinActividad; }
+}
@@ -0,0 +1,81 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para AperturaCaja.
+ * Representa la tabla aperturas_caja en la base de datos.
+ *
+ * HU-159: Fondo inicial de cada caja en cada jornada. El id es solo técnico:
+ * la apertura se identifica por (jornada_id, caja_id).
+ */
+@Entity
+@Table(name = "aperturas_caja",
+    uniqueConstraints = @UniqueConstraint(name = "uk_aperturas_caja_jornada_caja",
+        columnNames = {"jornada_id", "caja_id"}),
+    indexes = @Index(name = "idx_aperturas_caja_jornada", columnList = "jornada_id"))
+public class AperturaCajaEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "jornada_id", nullable = false)
+    private UUID jornadaId;
+
+    @Column(name = "caja_id", nullable = false)
+    private UUID cajaId;
+
+    @Column(name = "fondo_inicial", nullable = false, precision = 12, scale = 2)
+    private BigDecimal fondoInicial;
+
+    @Column(name = "fecha_apertura", nullable = false)
+    private LocalDateTime fechaApertura;
+
+    // Constructor vacío requerido por JPA
+    protected AperturaCajaEntity() {
+    }
+
+    public AperturaCajaEntity(UUID id, UUID localId, UUID jornadaId, UUID cajaId,
+                              BigDecimal fondoInicial, LocalDateTime fechaApertura) {
+        this.id = id;
+        this.localId = localId;
+        this.jornadaId = jornadaId;
+        this.cajaId = cajaId;
+        this.fondoInicial = fondoInicial;
+        this.fechaApertura = fechaApertura;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getJornadaId() {
+        return jornadaId;
+    }
+
+    public UUID getCajaId() {
+        return cajaId;
+    }
+
+    public BigDecimal getFondoInicial() {
+        return fondoInicial;
+    }
+
+    public LocalDateTime getFechaApertura() {
+        return fechaApertura;
+    }
+}
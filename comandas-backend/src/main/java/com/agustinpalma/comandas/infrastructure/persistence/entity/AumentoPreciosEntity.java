@@ -0,0 +1,132 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoAumentoPrecios;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para AumentoPrecios.
+ * Representa la tabla aumentos_precios en la base de datos.
+ *
+ * HU-145: Aumento masivo de precios programado o aplicado.
+ */
+@Entity
+@Table(name = "aumentos_precios",
+    indexes = @Index(name = "idx_aumentos_precios_estado_vigencia", columnList = "estado, fecha_vigencia")
+)
+public class AumentoPreciosEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "categoria_id")
+    private UUID categoriaId;
+
+    @Column(name = "porcentaje", nullable = false, precision = 5, scale = 2)
+    private BigDecimal porcentaje;
+
+    @Column(name = "multiplo_redondeo", precision = 10, scale = 2)
+    private BigDecimal multiploRedondeo;
+
+    @Column(name = "fecha_vigencia", nullable = false)
+    private LocalDate fechaVigencia;
+
+    @Column(name = "fecha_creacion", nullable = false)
+    private LocalDateTime fechaCreacion;
+
+    // HU-146: quién cargó el aumento, para el historial de precios
+    @Column(name = "responsable", length = 60)
+    private String responsable;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "estado", nullable = false, length = 20)
+    private EstadoAumentoPrecios estado;
+
+    @Column(name = "fecha_aplicacion")
+    private LocalDateTime fechaAplicacion;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "aumentos_precios_cambios",
+        joinColumns = @JoinColumn(name = "aumento_id")
+    )
+    @OrderColumn(name = "orden")
+    private List<CambioPrecioEmbeddable> cambios = new ArrayList<>();
+
+    // Constructor vacío requerido por JPA
+    protected AumentoPreciosEntity() {
+    }
+
+    public AumentoPreciosEntity(UUID id, UUID localId, UUID categoriaId, BigDecimal porcentaje,
+                                BigDecimal multiploRedondeo, LocalDate fechaVigencia, LocalDateTime fechaCreacion,
+                                String responsable, EstadoAumentoPrecios estado, LocalDateTime fechaAplicacion,
+                                List<CambioPrecioEmbeddable> cambios) {
+        this.id = id;
+        this.localId = localId;
+        this.categoriaId = categoriaId;
+        this.porcentaje = porcentaje;
+        this.multiploRedondeo = multiploRedondeo;
+        this.fechaVigencia = fechaVigencia;
+        this.fechaCreacion = fechaCreacion;
+        this.responsable = responsable;
+        this.estado = estado;
+        this.fechaAplicacion = fechaAplicacion;
+        this.cambios = cambios;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getCategoriaId() {
+        return categoriaId;
+    }
+
+    public BigDecimal getPorcentaje() {
+        return porcentaje;
+    }
+
+    public BigDecimal getMultiploRedondeo() {
+        return multiploRedondeo;
+    }
+
+    public LocalDate getFechaVigencia() {
+        return fechaVigencia;
+    }
+
+    public LocalDateTime getFechaCreacion() {
+        return fechaCreacion;
+    }
+
+    public String getResponsable() {
+        return responsable;
+    }
+
+    public EstadoAumentoPrecios getEstado() {
+        return estado;
+    }
+
+    public LocalDateTime getFechaAplicacion() {
+        return fechaAplicacion;
+    }
+
+    public List<CambioPrecioEmbeddable> getCambios() {
+        return cambios;
+    }
+}
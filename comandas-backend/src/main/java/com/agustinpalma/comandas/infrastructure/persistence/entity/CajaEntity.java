@@ -0,0 +1,61 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para Caja.
+ * Representa la tabla cajas en la base de datos.
+ *
+ * HU-159: Varias cajas en el mismo local.
+ */
+@Entity
+@Table(name = "cajas", indexes = {
+    @Index(name = "idx_cajas_local", columnList = "local_id")
+})
+public class CajaEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "nombre", nullable = false, length = 40)
+    private String nombre;
+
+    @Column(name = "fecha_alta", nullable = false)
+    private LocalDateTime fechaAlta;
+
+    // Constructor vacío requerido por JPA
+    protected CajaEntity() {
+    }
+
+    public CajaEntity(UUID id, UUID localId, String nombre, LocalDateTime fechaAlta) {
+        this.id = id;
+        this.localId = localId;
+        this.nombre = nombre;
+        this.fechaAlta = fechaAlta;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public LocalDateTime getFechaAlta() {
+        return fechaAlta;
+    }
+}
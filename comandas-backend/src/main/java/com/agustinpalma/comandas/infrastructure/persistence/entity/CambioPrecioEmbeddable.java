@@ -0,0 +1,53 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * Embeddable para el precio anterior y nuevo de cada producto en un aumento masivo (HU-145).
+ */
+@Embeddable
+public class CambioPrecioEmbeddable {
+
+    @Column(name = "producto_id", nullable = false)
+    private UUID productoId;
+
+    @Column(name = "nombre_producto", nullable = false, length = 100)
+    private String nombreProducto;
+
+    @Column(name = "precio_anterior", nullable = false, precision = 10, scale = 2)
+    private BigDecimal precioAnterior;
+
+    @Column(name = "precio_nuevo", nullable = false, precision = 10, scale = 2)
+    private BigDecimal precioNuevo;
+
+    // Constructor vacío para JPA
+    public CambioPrecioEmbeddable() {}
+
+    public CambioPrecioEmbeddable(UUID productoId, String nombreProducto, BigDecimal precioAnterior,
+                                  BigDecimal precioNuevo) {
+        this.productoId = productoId;
+        this.nombreProducto = nombreProducto;
+        this.precioAnterior = precioAnterior;
+        this.precioNuevo = precioNuevo;
+    }
+
+    public UUID getProductoId() {
+        return productoId;
+    }
+
+    public String getNombreProducto() {
+        return nombreProducto;
+    }
+
+    public BigDecimal getPrecioAnterior() {
+        return precioAnterior;
+    }
+
+    public BigDecimal getPrecioNuevo() {
+        return precioNuevo;
+    }
+}
@@ -0,0 +1,117 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.OrigenCambioPrecio;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para CambioPrecioProducto.
+ * Representa la tabla historial_precios en la base de datos.
+ *
+ * HU-146: Registro de auditoría de precios del catálogo.
+ */
+@Entity
+@Table(name = "historial_precios",
+    indexes = {
+        @Index(name = "idx_historial_precios_producto", columnList = "producto_id, fecha"),
+        @Index(name = "idx_historial_precios_local_fecha", columnList = "local_id, fecha")
+    }
+)
+public class CambioPrecioProductoEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "producto_id", nullable = false)
+    private UUID productoId;
+
+    @Column(name = "nombre_producto", nullable = false, length = 100)
+    private String nombreProducto;
+
+    @Column(name = "precio_anterior", nullable = false, precision = 10, scale = 2)
+    private BigDecimal precioAnterior;
+
+    @Column(name = "precio_nuevo", nullable = false, precision = 10, scale = 2)
+    private BigDecimal precioNuevo;
+
+    @Column(name = "fecha", nullable = false)
+    private LocalDateTime fecha;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "origen", nullable = false, length = 20)
+    private OrigenCambioPrecio origen;
+
+    @Column(name = "aumento_id")
+    private UUID aumentoId;
+
+    @Column(name = "responsable", length = 60)
+    private String responsable;
+
+    // Constructor vacío requerido por JPA
+    protected CambioPrecioProductoEntity() {
+    }
+
+    public CambioPrecioProductoEntity(UUID id, UUID localId, UUID productoId, String nombreProducto,
+                                      BigDecimal precioAnterior, BigDecimal precioNuevo, LocalDateTime fecha,
+                                      OrigenCambioPrecio origen, UUID aumentoId, String responsable) {
+        this.id = id;
+        this.localId = localId;
+        this.productoId = productoId;
+        this.nombreProducto = nombreProducto;
+        this.precioAnterior = precioAnterior;
+        this.precioNuevo = precioNuevo;
+        this.fecha = fecha;
+        this.origen = origen;
+        this.aumentoId = aumentoId;
+        this.responsable = responsable;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getProductoId() {
+        return productoId;
+    }
+
+    public String getNombreProducto() {
+        return nombreProducto;
+    }
+
+    public BigDecimal getPrecioAnterior() {
+        return precioAnterior;
+    }
+
+    public BigDecimal getPrecioNuevo() {
+        return precioNuevo;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public OrigenCambioPrecio getOrigen() {
+        return origen;
+    }
+
+    public UUID getAumentoId() {
+        return aumentoId;
+    }
+
+    public String getResponsable() {
+        return responsable;
+    }
+}
@@ -0,0 +1,60 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.util.UUID;
+
+/**
+ * Entidad JPA para CategoriaEgreso.
+ * Representa la tabla categorias_egreso en la base de datos.
+ *
+ * HU-130: Categorías configurables de los egresos de caja.
+ */
+@Entity
+@Table(name = "categorias_egreso", indexes = {
+    @Index(name = "idx_categorias_egreso_local", columnList = "local_id")
+})
+public class CategoriaEgresoEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "nombre", nullable = false, length = 40)
+    private String nombre;
+
+    @Column(name = "activa", nullable = false)
+    private boolean activa;
+
+    // Constructor vacío requerido por JPA
+    protected CategoriaEgresoEntity() {
+    }
+
+    public CategoriaEgresoEntity(UUID id, UUID localId, String nombre, boolean activa) {
+        this.id = id;
+        this.localId = localId;
+        this.nombre = nombre;
+        this.activa = activa;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public boolean isActiva() {
+        return activa;
+    }
+}
@@ -1,6 +1,7 @@
 package com.agustinpalma.comandas.infrastructure.persistence.entity;
 
 import jakarta.persistence.*;
+import java.time.LocalTime;
 import java.util.UUID;
 
 /**
@@ -36,6 +37,15 @@ public class CategoriaEntity {
     @Column(name = "categoria_modificadores_id")
     private UUID categoriaModificadoresId;
 
+    @Column(name = "minutos_alerta_cocina")
+    private Integer minutosAlertaCocina;
+
+    @Column(name = "horario_desde")
+    private LocalTime horarioDesde;
+
+    @Column(name = "horario_hasta")
+    private LocalTime horarioHasta;
+
     // Constructor vacío para JPA
     public CategoriaEntity() {}
 
@@ -124,4 +134,28 @@ public class CategoriaEntity {
     public void setCategoriaModificadoresId(UUID categoriaModificadoresId) {
         this.categoriaModificadoresId = categoriaModificadoresId;
     }
+
+    public Integer getMinutosAlertaCocina() {
+        return minutosAlertaCocina;
+    }
+
+    public void setMinutosAlertaCocina(Integer minutosAlertaCocina) {
+        this.minutosAlertaCocina = minutosAlertaCocina;
+    }
+
+    public LocalTime getHorarioDesde() {
+        return horarioDesde;
+    }
+
+    public void setHorarioDesde(LocalTime horarioDesde) {
+        this.horarioDesde = horarioDesde;
+    }
+
+    public LocalTime getHorarioHasta() {
+        return horarioHasta;
+    }
+
+    public void setHorarioHasta(LocalTime horarioHasta) {
+        this.horarioHasta = horarioHasta;
+    }
 }
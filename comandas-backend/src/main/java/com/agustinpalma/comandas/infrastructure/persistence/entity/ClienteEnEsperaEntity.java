@@ -0,0 +1,123 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoEspera;
+import jakarta.persistence.*;
+
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para ClienteEnEspera.
+ * Representa la tabla lista_espera en la base de datos.
+ *
+ * HU-149: Cliente anotado en la lista de espera, con la mesa que se le avisó.
+ */
+@Entity
+@Table(name = "lista_espera",
+    indexes = {
+        @Index(name = "idx_lista_espera_local_estado", columnList = "local_id, estado, fecha_alta")
+    }
+)
+public class ClienteEnEsperaEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "nombre_cliente", nullable = false, length = 80)
+    private String nombreCliente;
+
+    @Column(name = "personas", nullable = false)
+    private int personas;
+
+    @Column(name = "telefono", length = 30)
+    private String telefono;
+
+    @Column(name = "observaciones", length = 200)
+    private String observaciones;
+
+    @Column(name = "fecha_alta", nullable = false)
+    private LocalDateTime fechaAlta;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "estado", nullable = false, length = 20)
+    private EstadoEspera estado;
+
+    @Column(name = "mesa_id")
+    private UUID mesaId;
+
+    @Column(name = "fecha_aviso")
+    private LocalDateTime fechaAviso;
+
+    @Column(name = "fecha_salida")
+    private LocalDateTime fechaSalida;
+
+    // Constructor vacío requerido por JPA
+    protected ClienteEnEsperaEntity() {
+    }
+
+    public ClienteEnEsperaEntity(UUID id, UUID localId, String nombreCliente, int personas, String telefono,
+                                 String observaciones, LocalDateTime fechaAlta, EstadoEspera estado, UUID mesaId,
+                                 LocalDateTime fechaAviso, LocalDateTime fechaSalida) {
+        this.id = id;
+        this.localId = localId;
+        this.nombreCliente = nombreCliente;
+        this.personas = personas;
+        this.telefono = telefono;
+        this.observaciones = observaciones;
+        this.fechaAlta = fechaAlta;
+        this.estado = estado;
+        this.mesaId = mesaId;
+        this.fechaAviso = fechaAviso;
+        this.fechaSalida = fechaSalida;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public String getNombreCliente() {
+        return nombreCliente;
+    }
+
+    public int getPersonas() {
+        return personas;
+    }
+
+    public String getTelefono() {
+        return telefono;
+    }
+
+    public String getObservaciones() {
+        return observaciones;
+    }
+
+    public LocalDateTime getFechaAlta() {
+        return fechaAlta;
+    }
+
+    public EstadoEspera getEstado() {
+        return estado;
+    }
+
+    public UUID getMesaId() {
+        return mesaId;
+    }
+
+    public LocalDateTime getFechaAviso() {
+        return fechaAviso;
+    }
+
+    public LocalDateTime getFechaSalida() {
+        return fechaSalida;
+    }
+}
@@ -0,0 +1,87 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.util.UUID;
+
+/**
+ * JPA entity for customers with a running account.
+ * HU-104: Customer running accounts.
+ * HU-168: Customer profile (birthday, preferences and allergies).
+ */
+@Entity
+@Table(name = "clientes")
+public class ClienteEntity {
+
+    @Id
+    @Column(name = "id", nullable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "nombre", nullable = false, length = 150)
+    private String nombre;
+
+    @Column(name = "telefono", length = 50)
+    private String telefono;
+
+    @Column(name = "limite_credito", nullable = false, precision = 10, scale = 2)
+    private BigDecimal limiteCredito;
+
+    @Column(name = "activo", nullable = false)
+    private boolean activo;
+
+    @Column(name = "fecha_nacimiento")
+    private LocalDate fechaNacimiento;
+
+    @Column(name = "preferencias", length = 300)
+    private String preferencias;
+
+    @Column(name = "alergias", length = 300)
+    private String alergias;
+
+    public ClienteEntity() {}
+
+    public ClienteEntity(UUID id, UUID localId, String nombre, String telefono,
+                         BigDecimal limiteCredito, boolean activo,
+                         LocalDate fechaNacimiento, String preferencias, String alergias) {
+        this.id = id;
+        this.localId = localId;
+        this.nombre = nombre;
+        this.telefono = telefono;
+        this.limiteCredito = limiteCredito;
+        this.activo = activo;
+        this.fechaNacimiento = fechaNacimiento;
+        this.preferencias = preferencias;
+        this.alergias = alergias;
+    }
+
+    public UUID getId() { return id; }
+    public void setId(UUID id) { this.id = id; }
+
+    public UUID getLocalId() { return localId; }
+    public void setLocalId(UUID localId) { this.localId = localId; }
+
+    public String getNombre() { return nombre; }
+    public void setNombre(String nombre) { this.nombre = nombre; }
+
+    public String getTelefono() { return telefono; }
+    public void setTelefono(String telefono) { this.telefono = telefono; }
+
+    public BigDecimal getLimiteCredito() { return limiteCredito; }
+    public void setLimiteCredito(BigDecimal limiteCredito) { this.limiteCredito = limiteCredito; }
+
+    public boolean isActivo() { return activo; }
+    public void setActivo(boolean activo) { this.activo = activo; }
+
+    public LocalDate getFechaNacimiento() { return fechaNacimiento; }
+    public void setFechaNacimiento(LocalDate fechaNacimiento) { this.fechaNacimiento = fechaNacimiento; }
+
+    public String getPreferencias() { return preferencias; }
+    public void setPreferencias(String preferencias) { this.preferencias = preferencias; }
+
+    public String getAlergias() { return alergias; }
+    public void setAlergias(String alergias) { this.alergias = alergias; }
+}
@@ -0,0 +1,132 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para ComandaCocina.
+ * Representa la tabla comandas_cocina en la base de datos.
+ *
+ * HU-119: lista_en es null mientras la comanda está en preparación.
+ * Los ítems viven en comandas_cocina_items, en el orden en que salieron.
+ * HU-137: urgente es la marca del pedido; la de cada ítem va en su renglón.
+ * HU-138: prometida_para es el horario de retiro del pedido take away (null si no tiene).
+ */
+@Entity
+@Table(name = "comandas_cocina",
+    indexes = {
+        @Index(name = "idx_comandas_cocina_local_enviada", columnList = "local_id, enviada_en")
+    }
+)
+public class ComandaCocinaEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "pedido_id", nullable = false)
+    private UUID pedidoId;
+
+    @Column(name = "numero_mesa", nullable = false)
+    private int numeroMesa;
+
+    @Column(name = "numero_pedido", nullable = false)
+    private int numeroPedido;
+
+    @Column(name = "enviada_en", nullable = false)
+    private LocalDateTime enviadaEn;
+
+    @Column(name = "minutos_alerta", nullable = false)
+    private int minutosAlerta;
+
+    @Column(name = "lista_en")
+    private LocalDateTime listaEn;
+
+    @Column(name = "urgente", nullable = false)
+    private boolean urgente;
+
+    @Column(name = "prometida_para")
+    private LocalDateTime prometidaPara;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "comandas_cocina_items",
+        joinColumns = @JoinColumn(name = "comanda_id")
+    )
+    @OrderColumn(name = "orden")
+    private List<ItemComandaCocinaEmbeddable> items = new ArrayList<>();
+
+    // Constructor vacío requerido por JPA
+    protected ComandaCocinaEntity() {
+    }
+
+    public ComandaCocinaEntity(UUID id, UUID localId, UUID pedidoId, int numeroMesa, int numeroPedido,
+                               LocalDateTime enviadaEn, int minutosAlerta, LocalDateTime listaEn,
+                               boolean urgente, LocalDateTime prometidaPara,
+                               List<ItemComandaCocinaEmbeddable> items) {
+        this.id = id;
+        this.localId = localId;
+        this.pedidoId = pedidoId;
+        this.numeroMesa = numeroMesa;
+        this.numeroPedido = numeroPedido;
+        this.enviadaEn = enviadaEn;
+        this.minutosAlerta = minutosAlerta;
+        this.listaEn = listaEn;
+        this.urgente = urgente;
+        this.prometidaPara = prometidaPara;
+        this.items = new ArrayList<>(items);
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getPedidoId() {
+        return pedidoId;
+    }
+
+    public int getNumeroMesa() {
+        return numeroMesa;
+    }
+
+    public int getNumeroPedido() {
+        return numeroPedido;
+    }
+
+    public LocalDateTime getEnviadaEn() {
+        return enviadaEn;
+    }
+
+    public int getMinutosAlerta() {
+        return minutosAlerta;
+    }
+
+    public LocalDateTime getListaEn() {
+        return listaEn;
+    }
+
+    public boolean isUrgente() {
+        return urgente;
+    }
+
+    public LocalDateTime getPrometidaPara() {
+        return prometidaPara;
+    }
+
+    public List<ItemComandaCocinaEmbeddable> getItems() {
+        return items;
+    }
+}
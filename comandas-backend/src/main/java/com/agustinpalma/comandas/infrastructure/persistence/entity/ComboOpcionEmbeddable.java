@@ -0,0 +1,59 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+
+import java.util.UUID;
+
+/**
+ * Embeddable para las opciones de los componentes de un combo (HU-103).
+ * Cada fila es una opción elegible; las filas con el mismo componenteOrden
+ * forman un componente del combo.
+ */
+@Embeddable
+public class ComboOpcionEmbeddable {
+
+    @Column(name = "componente_orden", nullable = false)
+    private int componenteOrden;
+
+    @Column(name = "componente_nombre", nullable = false, length = 100)
+    private String componenteNombre;
+
+    @Column(name = "opcion_producto_id", nullable = false)
+    private UUID opcionProductoId;
+
+    // Constructor vacío para JPA
+    public ComboOpcionEmbeddable() {}
+
+    // Constructor con parámetros
+    public ComboOpcionEmbeddable(int componenteOrden, String componenteNombre, UUID opcionProductoId) {
+        this.componenteOrden = componenteOrden;
+        this.componenteNombre = componenteNombre;
+        this.opcionProductoId = opcionProductoId;
+    }
+
+    // Getters y setters
+    public int getComponenteOrden() {
+        return componenteOrden;
+    }
+
+    public void setComponenteOrden(int componenteOrden) {
+        this.componenteOrden = componenteOrden;
+    }
+
+    public String getComponenteNombre() {
+        return componenteNombre;
+    }
+
+    public void setComponenteNombre(String componenteNombre) {
+        this.componenteNombre = componenteNombre;
+    }
+
+    public UUID getOpcionProductoId() {
+        return opcionProductoId;
+    }
+
+    public void setOpcionProductoId(UUID opcionProductoId) {
+        this.opcionProductoId = opcionProductoId;
+    }
+}
@@ -0,0 +1,59 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.util.UUID;
+
+/**
+ * Entidad JPA para el archivo del comprobante de un gasto.
+ * Representa la tabla gastos_comprobantes en la base de datos.
+ *
+ * HU-117: Tabla aparte de gastos para que listar gastos no traiga los archivos.
+ * Un comprobante por gasto: la clave primaria es el id del gasto.
+ */
+@Entity
+@Table(name = "gastos_comprobantes")
+public class ComprobanteGastoEntity {
+
+    @Id
+    @Column(name = "gasto_id", nullable = false, updatable = false)
+    private UUID gastoId;
+
+    @Column(name = "nombre_archivo", nullable = false, length = 200)
+    private String nombreArchivo;
+
+    @Column(name = "content_type", nullable = false, length = 50)
+    private String contentType;
+
+    @Column(name = "contenido", nullable = false)
+    private byte[] contenido;
+
+    // Constructor vacío requerido por JPA
+    protected ComprobanteGastoEntity() {
+    }
+
+    public ComprobanteGastoEntity(UUID gastoId, String nombreArchivo, String contentType, byte[] contenido) {
+        this.gastoId = gastoId;
+        this.nombreArchivo = nombreArchivo;
+        this.contentType = contentType;
+        this.contenido = contenido;
+    }
+
+    // Getters
+
+    public UUID getGastoId() {
+        return gastoId;
+    }
+
+    public String getNombreArchivo() {
+        return nombreArchivo;
+    }
+
+    public String getContentType() {
+        return contentType;
+    }
+
+    public byte[] getContenido() {
+        return contenido;
+    }
+}
@@ -0,0 +1,83 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para ConsumoPersonal.
+ * Representa la tabla consumos_personal en la base de datos.
+ *
+ * HU-132: Consumo interno (A_CUENTA) imputado a un empleado, uno por pedido.
+ */
+@Entity
+@Table(name = "consumos_personal",
+    uniqueConstraints = @UniqueConstraint(
+        name = "uk_consumo_personal_pedido",
+        columnNames = {"pedido_id"}
+    ),
+    indexes = @Index(name = "idx_consumos_personal_local_fecha", columnList = "local_id, fecha")
+)
+public class ConsumoPersonalEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "mozo_id", nullable = false)
+    private UUID mozoId;
+
+    @Column(name = "pedido_id", nullable = false)
+    private UUID pedidoId;
+
+    @Column(name = "monto", nullable = false, precision = 10, scale = 2)
+    private BigDecimal monto;
+
+    @Column(name = "fecha", nullable = false)
+    private LocalDateTime fecha;
+
+    // Constructor vacío requerido por JPA
+    protected ConsumoPersonalEntity() {
+    }
+
+    public ConsumoPersonalEntity(UUID id, UUID localId, UUID mozoId, UUID pedidoId, BigDecimal monto,
+                                 LocalDateTime fecha) {
+        this.id = id;
+        this.localId = localId;
+        this.mozoId = mozoId;
+        this.pedidoId = pedidoId;
+        this.monto = monto;
+        this.fecha = fecha;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getMozoId() {
+        return mozoId;
+    }
+
+    public UUID getPedidoId() {
+        return pedidoId;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+}
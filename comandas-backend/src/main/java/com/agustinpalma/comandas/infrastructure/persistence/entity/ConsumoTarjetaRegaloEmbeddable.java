@@ -0,0 +1,45 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Embeddable para cada uso de una tarjeta de regalo en un pedido (HU-144).
+ */
+@Embeddable
+public class ConsumoTarjetaRegaloEmbeddable {
+
+    @Column(name = "pedido_id", nullable = false)
+    private UUID pedidoId;
+
+    @Column(name = "monto", nullable = false, precision = 10, scale = 2)
+    private BigDecimal monto;
+
+    @Column(name = "fecha", nullable = false)
+    private LocalDateTime fecha;
+
+    // Constructor vacío para JPA
+    public ConsumoTarjetaRegaloEmbeddable() {}
+
+    public ConsumoTarjetaRegaloEmbeddable(UUID pedidoId, BigDecimal monto, LocalDateTime fecha) {
+        this.pedidoId = pedidoId;
+        this.monto = monto;
+        this.fecha = fecha;
+    }
+
+    public UUID getPedidoId() {
+        return pedidoId;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+}
@@ -0,0 +1,35 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+
+import java.math.BigDecimal;
+
+/**
+ * Embeddable para el conteo por denominación del arqueo de turno (HU-123).
+ */
+@Embeddable
+public class ConteoDenominacionEmbeddable {
+
+    @Column(name = "denominacion", nullable = false, precision = 12, scale = 2)
+    private BigDecimal denominacion;
+
+    @Column(name = "cantidad", nullable = false)
+    private int cantidad;
+
+    // Constructor vacío para JPA
+    public ConteoDenominacionEmbeddable() {}
+
+    public ConteoDenominacionEmbeddable(BigDecimal denominacion, int cantidad) {
+        this.denominacion = denominacion;
+        this.cantidad = cantidad;
+    }
+
+    public BigDecimal getDenominacion() {
+        return denominacion;
+    }
+
+    public int getCantidad() {
+        return cantidad;
+    }
+}
@@ -0,0 +1,73 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+import jakarta.persistence.EnumType;
+import jakarta.persistence.Enumerated;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * Embeddable para los renglones contados de una toma de inventario (HU-133).
+ */
+@Embeddable
+public class ConteoInsumoEmbeddable {
+
+    @Column(name = "insumo_id", nullable = false)
+    private UUID insumoId;
+
+    @Column(name = "nombre_insumo", nullable = false, length = 60)
+    private String nombreInsumo;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "unidad", nullable = false, length = 20)
+    private UnidadInsumo unidad;
+
+    @Column(name = "stock_teorico", nullable = false, precision = 12, scale = 3)
+    private BigDecimal stockTeorico;
+
+    @Column(name = "stock_contado", nullable = false, precision = 12, scale = 3)
+    private BigDecimal stockContado;
+
+    @Column(name = "costo_unitario", nullable = false, precision = 12, scale = 4)
+    private BigDecimal costoUnitario;
+
+    // Constructor vacío para JPA
+    public ConteoInsumoEmbeddable() {}
+
+    public ConteoInsumoEmbeddable(UUID insumoId, String nombreInsumo, UnidadInsumo unidad, BigDecimal stockTeorico,
+                                  BigDecimal stockContado, BigDecimal costoUnitario) {
+        this.insumoId = insumoId;
+        this.nombreInsumo = nombreInsumo;
+        this.unidad = unidad;
+        this.stockTeorico = stockTeorico;
+        this.stockContado = stockContado;
+        this.costoUnitario = costoUnitario;
+    }
+
+    public UUID getInsumoId() {
+        return insumoId;
+    }
+
+    public String getNombreInsumo() {
+        return nombreInsumo;
+    }
+
+    public UnidadInsumo getUnidad() {
+        return unidad;
+    }
+
+    public BigDecimal getStockTeorico() {
+        return stockTeorico;
+    }
+
+    public BigDecimal getStockContado() {
+        return stockContado;
+    }
+
+    public BigDecimal getCostoUnitario() {
+        return costoUnitario;
+    }
+}
@@ -0,0 +1,98 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ModoRepartoPropinas;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para DistribucionPropinas.
+ * Representa la tabla distribuciones_propinas en la base de datos.
+ *
+ * HU-141: Reparto del pozo de propinas de un turno, uno por turno.
+ */
+@Entity
+@Table(name = "distribuciones_propinas",
+    uniqueConstraints = @UniqueConstraint(name = "uk_distribucion_propinas_turno", columnNames = "turno_id"),
+    indexes = @Index(name = "idx_distribuciones_propinas_local_fecha", columnList = "local_id, fecha")
+)
+public class DistribucionPropinasEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "turno_id", nullable = false)
+    private UUID turnoId;
+
+    @Column(name = "fecha", nullable = false)
+    private LocalDateTime fecha;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "modo", nullable = false, length = 20)
+    private ModoRepartoPropinas modo;
+
+    @Column(name = "pozo", nullable = false, precision = 10, scale = 2)
+    private BigDecimal pozo;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "partes_propina",
+        joinColumns = @JoinColumn(name = "distribucion_id")
+    )
+    @OrderColumn(name = "orden")
+    private List<PartePropinaEmbeddable> partes = new ArrayList<>();
+
+    // Constructor vacío requerido por JPA
+    protected DistribucionPropinasEntity() {
+    }
+
+    public DistribucionPropinasEntity(UUID id, UUID localId, UUID turnoId, LocalDateTime fecha,
+                                      ModoRepartoPropinas modo, BigDecimal pozo,
+                                      List<PartePropinaEmbeddable> partes) {
+        this.id = id;
+        this.localId = localId;
+        this.turnoId = turnoId;
+        this.fecha = fecha;
+        this.modo = modo;
+        this.pozo = pozo;
+        this.partes = partes;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getTurnoId() {
+        return turnoId;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public ModoRepartoPropinas getModo() {
+        return modo;
+    }
+
+    public BigDecimal getPozo() {
+        return pozo;
+    }
+
+    public List<PartePropinaEmbeddable> getPartes() {
+        return partes;
+    }
+}
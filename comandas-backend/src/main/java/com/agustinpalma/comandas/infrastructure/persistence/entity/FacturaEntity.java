@@ -0,0 +1,182 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CondicionIva;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoFactura;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoFactura;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para Factura.
+ * Representa la tabla facturas en la base de datos.
+ *
+ * HU-115: Una factura por pedido; numeración única por local, punto de venta y letra.
+ */
+@Entity
+@Table(
+    name = "facturas",
+    uniqueConstraints = {
+        @UniqueConstraint(name = "uk_facturas_pedido", columnNames = "pedido_id"),
+        @UniqueConstraint(name = "uk_facturas_numeracion",
+            columnNames = {"local_id", "punto_venta", "tipo", "numero"})
+    }
+)
+public class FacturaEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "pedido_id", nullable = false)
+    private UUID pedidoId;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "tipo", nullable = false, length = 1)
+    private TipoFactura tipo;
+
+    @Column(name = "punto_venta", nullable = false)
+    private int puntoVenta;
+
+    @Column(name = "numero", nullable = false)
+    private long numero;
+
+    @Column(name = "fecha_emision", nullable = false)
+    private LocalDateTime fechaEmision;
+
+    @Column(name = "receptor_cuit", length = 11)
+    private String receptorCuit;
+
+    @Column(name = "receptor_razon_social", length = 120)
+    private String receptorRazonSocial;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "receptor_condicion_iva", nullable = false, length = 30)
+    private CondicionIva receptorCondicionIva;
+
+    @Column(name = "alicuota_iva", nullable = false, precision = 5, scale = 2)
+    private BigDecimal alicuotaIva;
+
+    @Column(name = "importe_neto", nullable = false, precision = 10, scale = 2)
+    private BigDecimal importeNeto;
+
+    @Column(name = "importe_iva", nullable = false, precision = 10, scale = 2)
+    private BigDecimal importeIva;
+
+    @Column(name = "importe_total", nullable = false, precision = 10, scale = 2)
+    private BigDecimal importeTotal;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "estado", nullable = false, length = 30)
+    private EstadoFactura estado;
+
+    @Column(name = "cae", length = 20)
+    private String cae;
+
+    @Column(name = "cae_vencimiento")
+    private LocalDate caeVencimiento;
+
+    // Constructor vacío requerido por JPA
+    protected FacturaEntity() {
+    }
+
+    public FacturaEntity(UUID id, UUID localId, UUID pedidoId, TipoFactura tipo, int puntoVenta, long numero,
+                         LocalDateTime fechaEmision, String receptorCuit, String receptorRazonSocial,
+                         CondicionIva receptorCondicionIva, BigDecimal alicuotaIva, BigDecimal importeNeto,
+                         BigDecimal importeIva, BigDecimal importeTotal, EstadoFactura estado,
+                         String cae, LocalDate caeVencimiento) {
+        this.id = id;
+        this.localId = localId;
+        this.pedidoId = pedidoId;
+        this.tipo = tipo;
+        this.puntoVenta = puntoVenta;
+        this.numero = numero;
+        this.fechaEmision = fechaEmision;
+        this.receptorCuit = receptorCuit;
+        this.receptorRazonSocial = receptorRazonSocial;
+        this.receptorCondicionIva = receptorCondicionIva;
+        this.alicuotaIva = alicuotaIva;
+        this.importeNeto = importeNeto;
+        this.importeIva = importeIva;
+        this.importeTotal = importeTotal;
+        this.estado = estado;
+        this.cae = cae;
+        this.caeVencimiento = caeVencimiento;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getPedidoId() {
+        return pedidoId;
+    }
+
+    public TipoFactura getTipo() {
+        return tipo;
+    }
+
+    public int getPuntoVenta() {
+        return puntoVenta;
+    }
+
+    public long getNumero() {
+        return numero;
+    }
+
+    public LocalDateTime getFechaEmision() {
+        return fechaEmision;
+    }
+
+    public String getReceptorCuit() {
+        return receptorCuit;
+    }
+
+    public String getReceptorRazonSocial() {
+        return receptorRazonSocial;
+    }
+
+    public CondicionIva getReceptorCondicionIva() {
+        return receptorCondicionIva;
+    }
+
+    public BigDecimal getAlicuotaIva() {
+        return alicuotaIva;
+    }
+
+    public BigDecimal getImporteNeto() {
+        return importeNeto;
+    }
+
+    public BigDecimal getImporteIva() {
+        return importeIva;
+    }
+
+    public BigDecimal getImporteTotal() {
+        return importeTotal;
+    }
+
+    public EstadoFactura getEstado() {
+        return estado;
+    }
+
+    public String getCae() {
+        return cae;
+    }
+
+    public LocalDate getCaeVencimiento() {
+        return caeVencimiento;
+    }
+}
@@ -0,0 +1,132 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para FacturaProveedor.
+ * Representa la tabla facturas_proveedor en la base de datos.
+ *
+ * HU-131: Los pagos viven en facturas_proveedor_pagos. El saldo se guarda
+ * desnormalizado para listar las impagas sin recorrer los pagos.
+ */
+@Entity
+@Table(name = "facturas_proveedor",
+    indexes = {
+        @Index(name = "idx_facturas_proveedor_local_vencimiento", columnList = "local_id, fecha_vencimiento"),
+        @Index(name = "idx_facturas_proveedor_proveedor", columnList = "proveedor_id")
+    }
+)
+public class FacturaProveedorEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "proveedor_id", nullable = false)
+    private UUID proveedorId;
+
+    @Column(name = "gasto_id", nullable = false)
+    private UUID gastoId;
+
+    @Column(name = "numero", length = 30)
+    private String numero;
+
+    @Column(name = "fecha_emision", nullable = false)
+    private LocalDate fechaEmision;
+
+    @Column(name = "fecha_vencimiento", nullable = false)
+    private LocalDate fechaVencimiento;
+
+    @Column(name = "monto", nullable = false, precision = 10, scale = 2)
+    private BigDecimal monto;
+
+    @Column(name = "saldo", nullable = false, precision = 10, scale = 2)
+    private BigDecimal saldo;
+
+    @Column(name = "fecha_registro", nullable = false)
+    private LocalDateTime fechaRegistro;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "facturas_proveedor_pagos",
+        joinColumns = @JoinColumn(name = "factura_id")
+    )
+    @OrderBy("fecha ASC")
+    private List<PagoProveedorEmbeddable> pagos = new ArrayList<>();
+
+    // Constructor vacío requerido por JPA
+    protected FacturaProveedorEntity() {
+    }
+
+    public FacturaProveedorEntity(UUID id, UUID localId, UUID proveedorId, UUID gastoId, String numero,
+                                  LocalDate fechaEmision, LocalDate fechaVencimiento, BigDecimal monto,
+                                  BigDecimal saldo, LocalDateTime fechaRegistro, List<PagoProveedorEmbeddable> pagos) {
+        this.id = id;
+        this.localId = localId;
+        this.proveedorId = proveedorId;
+        this.gastoId = gastoId;
+        this.numero = numero;
+        this.fechaEmision = fechaEmision;
+        this.fechaVencimiento = fechaVencimiento;
+        this.monto = monto;
+        this.saldo = saldo;
+        this.fechaRegistro = fechaRegistro;
+        this.pagos.addAll(pagos);
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getProveedorId() {
+        return proveedorId;
+    }
+
+    public UUID getGastoId() {
+        return gastoId;
+    }
+
+    public String getNumero() {
+        return numero;
+    }
+
+    public LocalDate getFechaEmision() {
+        return fechaEmision;
+    }
+
+    public LocalDate getFechaVencimiento() {
+        return fechaVencimiento;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+
+    public BigDecimal getSaldo() {
+        return saldo;
+    }
+
+    public LocalDateTime getFechaRegistro() {
+        return fechaRegistro;
+    }
+
+    public List<PagoProveedorEmbeddable> getPagos() {
+        return pagos;
+    }
+}
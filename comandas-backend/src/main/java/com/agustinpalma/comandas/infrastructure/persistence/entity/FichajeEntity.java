@@ -0,0 +1,126 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioFichaje;
+import jakarta.persistence.*;
+
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para Fichaje.
+ * Representa la tabla fichajes en la base de datos.
+ *
+ * HU-124: salida es null mientras el empleado está presente. Los campos *_original
+ * y de corrección quedan en null hasta que el dueño corrige el fichaje.
+ */
+@Entity
+@Table(name = "fichajes",
+    indexes = {
+        @Index(name = "idx_fichajes_local_entrada", columnList = "local_id, entrada"),
+        @Index(name = "idx_fichajes_mozo_salida", columnList = "local_id, mozo_id, salida")
+    }
+)
+public class FichajeEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "mozo_id", nullable = false)
+    private UUID mozoId;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "medio", nullable = false, length = 20)
+    private MedioFichaje medio;
+
+    @Column(name = "entrada", nullable = false)
+    private LocalDateTime entrada;
+
+    @Column(name = "salida")
+    private LocalDateTime salida;
+
+    @Column(name = "entrada_original")
+    private LocalDateTime entradaOriginal;
+
+    @Column(name = "salida_original")
+    private LocalDateTime salidaOriginal;
+
+    @Column(name = "motivo_correccion", length = 200)
+    private String motivoCorreccion;
+
+    @Column(name = "corregido_por_id")
+    private UUID corregidoPorId;
+
+    @Column(name = "fecha_correccion")
+    private LocalDateTime fechaCorreccion;
+
+    // Constructor vacío requerido por JPA
+    protected FichajeEntity() {
+    }
+
+    public FichajeEntity(UUID id, UUID localId, UUID mozoId, MedioFichaje medio,
+                         LocalDateTime entrada, LocalDateTime salida,
+                         LocalDateTime entradaOriginal, LocalDateTime salidaOriginal,
+                         String motivoCorreccion, UUID corregidoPorId, LocalDateTime fechaCorreccion) {
+        this.id = id;
+        this.localId = localId;
+        this.mozoId = mozoId;
+        this.medio = medio;
+        this.entrada = entrada;
+        this.salida = salida;
+        this.entradaOriginal = entradaOriginal;
+        this.salidaOriginal = salidaOriginal;
+        this.motivoCorreccion = motivoCorreccion;
+        this.corregidoPorId = corregidoPorId;
+        this.fechaCorreccion = fechaCorreccion;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getMozoId() {
+        return mozoId;
+    }
+
+    public MedioFichaje getMedio() {
+        return medio;
+    }
+
+    public LocalDateTime getEntrada() {
+        return entrada;
+    }
+
+    public LocalDateTime getSalida() {
+        return salida;
+    }
+
+    public LocalDateTime getEntradaOriginal() {
+        return entradaOriginal;
+    }
+
+    public LocalDateTime getSalidaOriginal() {
+        return salidaOriginal;
+    }
+
+    public String getMotivoCorreccion() {
+        return motivoCorreccion;
+    }
+
+    public UUID getCorregidoPorId() {
+        return corregidoPorId;
+    }
+
+    public LocalDateTime getFechaCorreccion() {
+        return fechaCorreccion;
+    }
+}
@@ -0,0 +1,72 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para la foto de un producto en la carta digital.
+ * Representa la tabla productos_fotos en la base de datos.
+ *
+ * HU-127: Tabla aparte de productos para que el catálogo del POS no traiga
+ * las imágenes. Una foto por producto: la clave primaria es el id del producto.
+ */
+@Entity
+@Table(
+    name = "productos_fotos",
+    indexes = @Index(name = "idx_productos_fotos_local", columnList = "local_id")
+)
+public class FotoProductoEntity {
+
+    @Id
+    @Column(name = "producto_id", nullable = false, updatable = false)
+    private UUID productoId;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "content_type", nullable = false, length = 50)
+    private String contentType;
+
+    @Column(name = "contenido", nullable = false)
+    private byte[] contenido;
+
+    @Column(name = "actualizada_en", nullable = false)
+    private LocalDateTime actualizadaEn;
+
+    // Constructor vacío requerido por JPA
+    protected FotoProductoEntity() {
+    }
+
+    public FotoProductoEntity(UUID productoId, UUID localId, String contentType, byte[] contenido,
+                              LocalDateTime actualizadaEn) {
+        this.productoId = productoId;
+        this.localId = localId;
+        this.contentType = contentType;
+        this.contenido = contenido;
+        this.actualizadaEn = actualizadaEn;
+    }
+
+    // Getters
+
+    public UUID getProductoId() {
+        return productoId;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public String getContentType() {
+        return contentType;
+    }
+
+    public byte[] getContenido() {
+        return contenido;
+    }
+
+    public LocalDateTime getActualizadaEn() {
+        return actualizadaEn;
+    }
+}
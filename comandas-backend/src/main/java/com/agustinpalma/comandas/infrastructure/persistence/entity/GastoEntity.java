@@ -0,0 +1,142 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaGasto;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para Gasto.
+ * Representa la tabla gastos en la base de datos.
+ *
+ * HU-117: Los productos comprados viven en gastos_items; el archivo del
+ * comprobante, en gastos_comprobantes (ver ComprobanteGastoEntity).
+ */
+@Entity
+@Table(name = "gastos",
+    indexes = {
+        @Index(name = "idx_gastos_local_fecha", columnList = "local_id, fecha")
+    }
+)
+public class GastoEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "fecha", nullable = false)
+    private LocalDate fecha;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "categoria", nullable = false, length = 20)
+    private CategoriaGasto categoria;
+
+    @Column(name = "proveedor", length = 100)
+    private String proveedor;
+
+    @Column(name = "descripcion", length = 200)
+    private String descripcion;
+
+    @Column(name = "monto", nullable = false, precision = 10, scale = 2)
+    private BigDecimal monto;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "medio_pago", nullable = false, length = 20)
+    private MedioPago medioPago;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "gastos_items",
+        joinColumns = @JoinColumn(name = "gasto_id")
+    )
+    private List<ItemCompraEmbeddable> items = new ArrayList<>();
+
+    @Column(name = "fecha_registro", nullable = false)
+    private LocalDateTime fechaRegistro;
+
+    @Column(name = "movimiento_caja_id")
+    private UUID movimientoCajaId;
+
+    @Column(name = "nombre_archivo_comprobante", length = 200)
+    private String nombreArchivoComprobante;
+
+    // Constructor vacío requerido por JPA
+    protected GastoEntity() {
+    }
+
+    public GastoEntity(UUID id, UUID localId, LocalDate fecha, CategoriaGasto categoria, String proveedor,
+                       String descripcion, BigDecimal monto, MedioPago medioPago, List<ItemCompraEmbeddable> items,
+                       LocalDateTime fechaRegistro, UUID movimientoCajaId, String nombreArchivoComprobante) {
+        this.id = id;
+        this.localId = localId;
+        this.fecha = fecha;
+        this.categoria = categoria;
+        this.proveedor = proveedor;
+        this.descripcion = descripcion;
+        this.monto = monto;
+        this.medioPago = medioPago;
+        this.items.addAll(items);
+        this.fechaRegistro = fechaRegistro;
+        this.movimientoCajaId = movimientoCajaId;
+        this.nombreArchivoComprobante = nombreArchivoComprobante;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public LocalDate getFecha() {
+        return fecha;
+    }
+
+    public CategoriaGasto getCategoria() {
+        return categoria;
+    }
+
+    public String getProveedor() {
+        return proveedor;
+    }
+
+    public String getDescripcion() {
+        return descripcion;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+
+    public MedioPago getMedioPago() {
+        return medioPago;
+    }
+
+    public List<ItemCompraEmbeddable> getItems() {
+        return items;
+    }
+
+    public LocalDateTime getFechaRegistro() {
+        return fechaRegistro;
+    }
+
+    public UUID getMovimientoCajaId() {
+        return movimientoCajaId;
+    }
+
+    public String getNombreArchivoComprobante() {
+        return nombreArchivoComprobante;
+    }
+}
@@ -0,0 +1,36 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+
+import java.util.UUID;
+
+/**
+ * Embeddable para los ingredientes que el cliente pidió sacar de un ítem (HU-148).
+ * Guarda el snapshot del nombre del insumo al cargar el ítem.
+ */
+@Embeddable
+public class IngredienteQuitadoEmbeddable {
+
+    @Column(name = "insumo_id", nullable = false)
+    private UUID insumoId;
+
+    @Column(name = "nombre_insumo", nullable = false, length = 60)
+    private String nombreInsumo;
+
+    // Constructor vacío para JPA
+    public IngredienteQuitadoEmbeddable() {}
+
+    public IngredienteQuitadoEmbeddable(UUID insumoId, String nombreInsumo) {
+        this.insumoId = insumoId;
+        this.nombreInsumo = nombreInsumo;
+    }
+
+    public UUID getInsumoId() {
+        return insumoId;
+    }
+
+    public String getNombreInsumo() {
+        return nombreInsumo;
+    }
+}
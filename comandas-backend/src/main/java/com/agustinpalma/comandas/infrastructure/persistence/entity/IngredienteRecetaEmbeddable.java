@@ -0,0 +1,36 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * Embeddable para los ingredientes de una receta (HU-118).
+ */
+@Embeddable
+public class IngredienteRecetaEmbeddable {
+
+    @Column(name = "insumo_id", nullable = false)
+    private UUID insumoId;
+
+    @Column(name = "cantidad", nullable = false, precision = 10, scale = 3)
+    private BigDecimal cantidad;
+
+    // Constructor vacío para JPA
+    public IngredienteRecetaEmbeddable() {}
+
+    public IngredienteRecetaEmbeddable(UUID insumoId, BigDecimal cantidad) {
+        this.insumoId = insumoId;
+        this.cantidad = cantidad;
+    }
+
+    public UUID getInsumoId() {
+        return insumoId;
+    }
+
+    public BigDecimal getCantidad() {
+        return cantidad;
+    }
+}
@@ -0,0 +1,105 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para Insumo.
+ * Representa la tabla insumos en la base de datos.
+ *
+ * HU-118: El costo se guarda con 4 decimales (costo por gramo o mililitro).
+ * HU-133: El stock teórico se guarda con 3 decimales (gramos o mililitros).
+ * HU-134: Stock mínimo y proveedor habitual para las órdenes de compra sugeridas.
+ */
+@Entity
+@Table(name = "insumos")
+public class InsumoEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "nombre", nullable = false, length = 60)
+    private String nombre;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "unidad", nullable = false, length = 20)
+    private UnidadInsumo unidad;
+
+    @Column(name = "costo_unitario", nullable = false, precision = 12, scale = 4)
+    private BigDecimal costoUnitario;
+
+    @Column(name = "controla_stock", nullable = false)
+    private boolean controlaStock;
+
+    @Column(name = "stock_actual", nullable = false, precision = 12, scale = 3)
+    private BigDecimal stockActual;
+
+    @Column(name = "stock_minimo", precision = 12, scale = 3)
+    private BigDecimal stockMinimo;
+
+    @Column(name = "proveedor_habitual_id")
+    private UUID proveedorHabitualId;
+
+    // Constructor vacío requerido por JPA
+    protected InsumoEntity() {
+    }
+
+    public InsumoEntity(UUID id, UUID localId, String nombre, UnidadInsumo unidad, BigDecimal costoUnitario,
+                        boolean controlaStock, BigDecimal stockActual, BigDecimal stockMinimo,
+                        UUID proveedorHabitualId) {
+        this.id = id;
+        this.localId = localId;
+        this.nombre = nombre;
+        this.unidad = unidad;
+        this.costoUnitario = costoUnitario;
+        this.controlaStock = controlaStock;
+        this.stockActual = stockActual;
+        this.stockMinimo = stockMinimo;
+        this.proveedorHabitualId = proveedorHabitualId;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public UnidadInsumo getUnidad() {
+        return unidad;
+    }
+
+    public BigDecimal getCostoUnitario() {
+        return costoUnitario;
+    }
+
+    public boolean isControlaStock() {
+        return controlaStock;
+    }
+
+    public BigDecimal getStockActual() {
+        return stockActual;
+    }
+
+    public BigDecimal getStockMinimo() {
+        return stockMinimo;
+    }
+
+    public UUID getProveedorHabitualId() {
+        return proveedorHabitualId;
+    }
+}
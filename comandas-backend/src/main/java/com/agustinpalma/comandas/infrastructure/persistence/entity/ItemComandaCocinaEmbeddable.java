@@ -0,0 +1,60 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+
+/**
+ * Embeddable para los ítems de una comanda de cocina (HU-119).
+ * HU-137: urgente indica que el ítem salió marcado con prioridad.
+ * HU-147: advertencias guarda las alergias del comensal separadas por coma.
+ */
+@Embeddable
+public class ItemComandaCocinaEmbeddable {
+
+    @Column(name = "nombre_producto", nullable = false, length = 100)
+    private String nombreProducto;
+
+    @Column(name = "cantidad", nullable = false)
+    private int cantidad;
+
+    @Column(name = "observacion", length = 255)
+    private String observacion;
+
+    @Column(name = "urgente", nullable = false)
+    private boolean urgente;
+
+    @Column(name = "advertencias", length = 120)
+    private String advertencias;
+
+    // Constructor vacío para JPA
+    public ItemComandaCocinaEmbeddable() {}
+
+    public ItemComandaCocinaEmbeddable(String nombreProducto, int cantidad, String observacion, boolean urgente,
+                                       String advertencias) {
+        this.nombreProducto = nombreProducto;
+        this.cantidad = cantidad;
+        this.observacion = observacion;
+        this.urgente = urgente;
+        this.advertencias = advertencias;
+    }
+
+    public String getNombreProducto() {
+        return nombreProducto;
+    }
+
+    public int getCantidad() {
+        return cantidad;
+    }
+
+    public String getObservacion() {
+        return observacion;
+    }
+
+    public boolean isUrgente() {
+        return urgente;
+    }
+
+    public String getAdvertencias() {
+        return advertencias;
+    }
+}
@@ -0,0 +1,44 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+
+import java.util.UUID;
+
+/**
+ * Embeddable para los productos de una compra de mercadería (HU-117).
+ * El nombre es el snapshot del producto al registrar la compra.
+ */
+@Embeddable
+public class ItemCompraEmbeddable {
+
+    @Column(name = "producto_id", nullable = false)
+    private UUID productoId;
+
+    @Column(name = "nombre_producto", nullable = false, length = 100)
+    private String nombreProducto;
+
+    @Column(name = "cantidad", nullable = false)
+    private int cantidad;
+
+    // Constructor vacío para JPA
+    public ItemCompraEmbeddable() {}
+
+    public ItemCompraEmbeddable(UUID productoId, String nombreProducto, int cantidad) {
+        this.productoId = productoId;
+        this.nombreProducto = nombreProducto;
+        this.cantidad = cantidad;
+    }
+
+    public UUID getProductoId() {
+        return productoId;
+    }
+
+    public String getNombreProducto() {
+        return nombreProducto;
+    }
+
+    public int getCantidad() {
+        return cantidad;
+    }
+}
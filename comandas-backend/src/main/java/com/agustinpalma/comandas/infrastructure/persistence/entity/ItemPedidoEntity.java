@@ -1,6 +1,8 @@
 package com.agustinpalma.comandas.infrastructure.persistence.entity;
 
+import com.agustinpalma.comandas.domain.model.DomainEnums.Alergeno;
 import com.agustinpalma.comandas.domain.model.DomainEnums.ModoDescuento;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TiempoServicio;
 import jakarta.persistence.*;
 import java.math.BigDecimal;
 import java.time.LocalDateTime;
@@ -139,6 +141,19 @@ public class ItemPedidoEntity {
     @Column(name = "desc_manual_fecha")
     private LocalDateTime descManualFecha;
 
+    /**
+     * HU-122: Mozo o encargado que autorizó el descuento manual.
+     * Null si no tiene descuento o no se registró quién lo autorizó.
+     */
+    @Column(name = "desc_manual_autorizado_por_id")
+    private UUID descManualAutorizadoPorId;
+
+    /**
+     * HU-122: Nombre de quien autorizó, congelado al aplicar el descuento.
+     */
+    @Column(name = "desc_manual_autorizado_por", length = 60)
+    private String descManualAutorizadoPor;
+
     // ============================================
     // HU-05.1 + HU-22: Extras del item
     // ============================================
@@ -154,6 +169,69 @@ public class ItemPedidoEntity {
     )
     private java.util.List<ExtraPedidoEmbeddable> extras = new java.util.ArrayList<>();
 
+    // ============================================
+    // HU-103: Opciones elegidas del combo
+    // ============================================
+
+    /**
+     * Opciones elegidas para cada componente si el item es un combo.
+     * Vacía para productos simples.
+     */
+    @ElementCollection
+    @CollectionTable(
+        name = "items_pedido_combo",
+        joinColumns = @JoinColumn(name = "item_pedido_id")
+    )
+    @OrderColumn(name = "orden")
+    private java.util.List<SeleccionComboEmbeddable> componentesCombo = new java.util.ArrayList<>();
+
+    // ============================================
+    // HU-147: Alergias del comensal
+    // ============================================
+
+    /**
+     * Alergias declaradas para el comensal al cargar el ítem.
+     * Vacía si no se registró ninguna advertencia.
+     */
+    @ElementCollection
+    @CollectionTable(
+        name = "items_pedido_advertencias",
+        joinColumns = @JoinColumn(name = "item_pedido_id")
+    )
+    @Enumerated(EnumType.STRING)
+    @Column(name = "alergeno", nullable = false, length = 20)
+    private java.util.Set<Alergeno> advertencias = new java.util.HashSet<>();
+
+    // ============================================
+    // HU-148: Ingredientes quitados de la receta
+    // ============================================
+
+    /**
+     * Insumos de la receta que el cliente pidió sacar, con su nombre al cargar el ítem.
+     */
+    @ElementCollection
+    @CollectionTable(
+        name = "items_pedido_ingredientes_quitados",
+        joinColumns = @JoinColumn(name = "item_pedido_id")
+    )
+    @OrderColumn(name = "orden")
+    private java.util.List<IngredienteQuitadoEmbeddable> ingredientesQuitados = new java.util.ArrayList<>();
+
+    // ============================================
+    // HU-164: Borrador del POS
+    // ============================================
+
+    /**
+     * Ids de los ítems del borrador del POS que cargaron esta línea.
+     */
+    @ElementCollection
+    @CollectionTable(
+        name = "items_pedido_borradores",
+        joinColumns = @JoinColumn(name = "item_pedido_id")
+    )
+    @Column(name = "borrador_id", nullable = false)
+    private java.util.Set<java.util.UUID> borradores = new java.util.HashSet<>();
+
     // ============================================
     // HU-29: Timestamp de creación del ítem
     // ============================================
@@ -178,6 +256,19 @@ public class ItemPedidoEntity {
     @Column(name = "cantidad_enviada_cocina", nullable = false)
     private int cantidadEnviadaCocina = 0;
 
+    // HU-110: Paso de servicio (null = sin paso)
+    @Enumerated(EnumType.STRING)
+    @Column(name = "tiempo_servicio", length = 20)
+    private TiempoServicio tiempoServicio;
+
+    // HU-137: Ítem urgente para cocina
+    @Column(name = "urgente", nullable = false)
+    private boolean urgente;
+
+    // HU-139: Ítem de costo de envío (no es un producto del catálogo)
+    @Column(name = "es_envio", nullable = false)
+    private boolean envio;
+
     // Constructor vacío para JPA
     protected ItemPedidoEntity() {}
 
@@ -358,6 +449,22 @@ public class ItemPedidoEntity {
         this.descManualFecha = descManualFecha;
     }
 
+    public UUID getDescManualAutorizadoPorId() {
+        return descManualAutorizadoPorId;
+    }
+
+    public void setDescManualAutorizadoPorId(UUID descManualAutorizadoPorId) {
+        this.descManualAutorizadoPorId = descManualAutorizadoPorId;
+    }
+
+    public String getDescManualAutorizadoPor() {
+        return descManualAutorizadoPor;
+    }
+
+    public void setDescManualAutorizadoPor(String descManualAutorizadoPor) {
+        this.descManualAutorizadoPor = descManualAutorizadoPor;
+    }
+
     public java.util.List<ExtraPedidoEmbeddable> getExtras() {
         return extras;
     }
@@ -366,6 +473,14 @@ public class ItemPedidoEntity {
         this.extras = extras;
     }
 
+    public java.util.List<SeleccionComboEmbeddable> getComponentesCombo() {
+        return componentesCombo;
+    }
+
+    public void setComponentesCombo(java.util.List<SeleccionComboEmbeddable> componentesCombo) {
+        this.componentesCombo = componentesCombo;
+    }
+
     // ============================================
     // Snapshot de clasificación: Getters y Setters
     // ============================================
@@ -417,4 +532,59 @@ public class ItemPedidoEntity {
     public void setCantidadEnviadaCocina(int cantidadEnviadaCocina) {
         this.cantidadEnviadaCocina = cantidadEnviadaCocina;
     }
+
+    // ============================================
+    // HU-110: Getter y Setter de tiempoServicio
+    // ============================================
+
+    public TiempoServicio getTiempoServicio() {
+        return tiempoServicio;
+    }
+
+    public void setTiempoServicio(TiempoServicio tiempoServicio) {
+        this.tiempoServicio = tiempoServicio;
+    }
+
+    // HU-137
+    public boolean isUrgente() {
+        return urgente;
+    }
+
+    public void setUrgente(boolean urgente) {
+        this.urgente = urgente;
+    }
+
+    // HU-139
+    public boolean isEnvio() {
+        return envio;
+    }
+
+    public void setEnvio(boolean envio) {
+        this.envio = envio;
+    }
+
+    // HU-147
+    public java.util.Set<Alergeno> getAdvertencias() {
+        return advertencias;
+    }
+
+    public void setAdvertencias(java.util.Set<Alergeno> advertencias) {
+        this.advertencias = advertencias;
+    }
+
+    public java.util.List<IngredienteQuitadoEmbeddable> getIngredientesQuitados() {
+        return ingredientesQuitados;
+    }
+
+    public void setIngredientesQuitados(java.util.List<IngredienteQuitadoEmbeddable> ingredientesQuitados) {
+        this.ingredientesQuitados = ingredientesQuitados;
+    }
+
+    public java.util.Set<java.util.UUID> getBorradores() {
+        return borradores;
+    }
+
+    public void setBorradores(java.util.Set<java.util.UUID> borradores) {
+        this.borradores = borradores;
+    }
 }
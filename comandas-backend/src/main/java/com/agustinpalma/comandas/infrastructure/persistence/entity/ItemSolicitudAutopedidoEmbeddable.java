@@ -0,0 +1,61 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * Embeddable para los ítems de un pedido del QR (HU-126).
+ */
+@Embeddable
+public class ItemSolicitudAutopedidoEmbeddable {
+
+    @Column(name = "producto_id", nullable = false)
+    private UUID productoId;
+
+    @Column(name = "nombre_producto", nullable = false, length = 100)
+    private String nombreProducto;
+
+    @Column(name = "precio_unitario", nullable = false, precision = 10, scale = 2)
+    private BigDecimal precioUnitario;
+
+    @Column(name = "cantidad", nullable = false)
+    private int cantidad;
+
+    @Column(name = "observacion", length = 140)
+    private String observacion;
+
+    // Constructor vacío para JPA
+    public ItemSolicitudAutopedidoEmbeddable() {}
+
+    public ItemSolicitudAutopedidoEmbeddable(UUID productoId, String nombreProducto, BigDecimal precioUnitario,
+                                             int cantidad, String observacion) {
+        this.productoId = productoId;
+        this.nombreProducto = nombreProducto;
+        this.precioUnitario = precioUnitario;
+        this.cantidad = cantidad;
+        this.observacion = observacion;
+    }
+
+    public UUID getProductoId() {
+        return productoId;
+    }
+
+    public String getNombreProducto() {
+        return nombreProducto;
+    }
+
+    public BigDecimal getPrecioUnitario() {
+        return precioUnitario;
+    }
+
+    public int getCantidad() {
+        return cantidad;
+    }
+
+    public String getObservacion() {
+        return observacion;
+    }
+}
@@ -0,0 +1,43 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.time.LocalTime;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para JornadaComercial, una por local.
+ * Representa la tabla jornada_comercial en la base de datos.
+ *
+ * HU-153: Límite de jornada comercial configurable.
+ */
+@Entity
+@Table(name = "jornada_comercial")
+public class JornadaComercialEntity {
+
+    @Id
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "hora_corte", nullable = false)
+    private LocalTime horaCorte;
+
+    // Constructor vacío requerido por JPA
+    protected JornadaComercialEntity() {
+    }
+
+    public JornadaComercialEntity(UUID localId, LocalTime horaCorte) {
+        this.localId = localId;
+        this.horaCorte = horaCorte;
+    }
+
+    // Getters
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public LocalTime getHoraCorte() {
+        return horaCorte;
+    }
+}
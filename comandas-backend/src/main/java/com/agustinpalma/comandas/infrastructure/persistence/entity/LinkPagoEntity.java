@@ -0,0 +1,152 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoLinkPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.ProveedorLinkPago;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para LinkPago.
+ * Representa la tabla links_pago en la base de datos.
+ *
+ * HU-128: pagado_en e id_pago_pasarela son null hasta que se acredita el pago.
+ */
+@Entity
+@Table(name = "links_pago",
+    indexes = {
+        @Index(name = "idx_links_pago_local_estado", columnList = "local_id, estado, creado_en"),
+        @Index(name = "idx_links_pago_pedido", columnList = "pedido_id")
+    }
+)
+public class LinkPagoEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "pedido_id", nullable = false)
+    private UUID pedidoId;
+
+    @Column(name = "mesa_id", nullable = false)
+    private UUID mesaId;
+
+    @Column(name = "numero_mesa", nullable = false)
+    private int numeroMesa;
+
+    @Column(name = "numero_pedido", nullable = false)
+    private int numeroPedido;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "proveedor", nullable = false, length = 20)
+    private ProveedorLinkPago proveedor;
+
+    @Column(name = "monto", nullable = false, precision = 10, scale = 2)
+    private BigDecimal monto;
+
+    @Column(name = "telefono_cliente", length = 30)
+    private String telefonoCliente;
+
+    @Column(name = "url", nullable = false, length = 500)
+    private String url;
+
+    @Column(name = "creado_en", nullable = false)
+    private LocalDateTime creadoEn;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "estado", nullable = false, length = 20)
+    private EstadoLinkPago estado;
+
+    @Column(name = "pagado_en")
+    private LocalDateTime pagadoEn;
+
+    @Column(name = "id_pago_pasarela", length = 60)
+    private String idPagoPasarela;
+
+    // Constructor vacío requerido por JPA
+    protected LinkPagoEntity() {
+    }
+
+    public LinkPagoEntity(UUID id, UUID localId, UUID pedidoId, UUID mesaId, int numeroMesa, int numeroPedido,
+                          ProveedorLinkPago proveedor, BigDecimal monto, String telefonoCliente, String url,
+                          LocalDateTime creadoEn, EstadoLinkPago estado, LocalDateTime pagadoEn,
+                          String idPagoPasarela) {
+        this.id = id;
+        this.localId = localId;
+        this.pedidoId = pedidoId;
+        this.mesaId = mesaId;
+        this.numeroMesa = numeroMesa;
+        this.numeroPedido = numeroPedido;
+        this.proveedor = proveedor;
+        this.monto = monto;
+        this.telefonoCliente = telefonoCliente;
+        this.url = url;
+        this.creadoEn = creadoEn;
+        this.estado = estado;
+        this.pagadoEn = pagadoEn;
+        this.idPagoPasarela = idPagoPasarela;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getPedidoId() {
+        return pedidoId;
+    }
+
+    public UUID getMesaId() {
+        return mesaId;
+    }
+
+    public int getNumeroMesa() {
+        return numeroMesa;
+    }
+
+    public int getNumeroPedido() {
+        return numeroPedido;
+    }
+
+    public ProveedorLinkPago getProveedor() {
+        return proveedor;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+
+    public String getTelefonoCliente() {
+        return telefonoCliente;
+    }
+
+    public String getUrl() {
+        return url;
+    }
+
+    public LocalDateTime getCreadoEn() {
+        return creadoEn;
+    }
+
+    public EstadoLinkPago getEstado() {
+        return estado;
+    }
+
+    public LocalDateTime getPagadoEn() {
+        return pagadoEn;
+    }
+
+    public String getIdPagoPasarela() {
+        return idPagoPasarela;
+    }
+}
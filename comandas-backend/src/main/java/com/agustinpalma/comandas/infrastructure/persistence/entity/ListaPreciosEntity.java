@@ -0,0 +1,91 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.util.HashMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para ListaPrecios.
+ * Representa la tabla listas_precios en la base de datos.
+ *
+ * HU-108: Una fila por canal y local. Los precios especiales por producto
+ * viven en listas_precios_productos.
+ */
+@Entity
+@Table(name = "listas_precios",
+    uniqueConstraints = {
+        @UniqueConstraint(name = "uk_lista_precios_local_canal", columnNames = {"local_id", "canal"})
+    }
+)
+public class ListaPreciosEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "canal", nullable = false, length = 20)
+    private CanalVenta canal;
+
+    @Column(name = "nombre", nullable = false, length = 100)
+    private String nombre;
+
+    @Column(name = "porcentaje_recargo", nullable = false, precision = 5, scale = 2)
+    private BigDecimal porcentajeRecargo;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "listas_precios_productos",
+        joinColumns = @JoinColumn(name = "lista_id")
+    )
+    @MapKeyColumn(name = "producto_id")
+    @Column(name = "precio", nullable = false, precision = 10, scale = 2)
+    private Map<UUID, BigDecimal> precios = new HashMap<>();
+
+    // Constructor vacío requerido por JPA
+    protected ListaPreciosEntity() {
+    }
+
+    public ListaPreciosEntity(UUID id, UUID localId, CanalVenta canal, String nombre,
+                              BigDecimal porcentajeRecargo, Map<UUID, BigDecimal> precios) {
+        this.id = id;
+        this.localId = localId;
+        this.canal = canal;
+        this.nombre = nombre;
+        this.porcentajeRecargo = porcentajeRecargo;
+        this.precios.putAll(precios);
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public CanalVenta getCanal() {
+        return canal;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public BigDecimal getPorcentajeRecargo() {
+        return porcentajeRecargo;
+    }
+
+    public Map<UUID, BigDecimal> getPrecios() {
+        return precios;
+    }
+}
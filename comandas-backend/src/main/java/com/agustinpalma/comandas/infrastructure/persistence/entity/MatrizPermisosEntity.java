@@ -0,0 +1,69 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.util.HashSet;
+import java.util.Set;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para MatrizPermisos, una por local.
+ * Representa la tabla matrices_permisos en la base de datos.
+ *
+ * HU-169: Matriz de permisos por rol y por empleado.
+ */
+@Entity
+@Table(name = "matrices_permisos")
+public class MatrizPermisosEntity {
+
+    @Id
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "activa", nullable = false)
+    private boolean activa;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "matrices_permisos_roles",
+        joinColumns = @JoinColumn(name = "local_id")
+    )
+    private Set<PermisoRolEmbeddable> permisosPorRol = new HashSet<>();
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "matrices_permisos_empleados",
+        joinColumns = @JoinColumn(name = "local_id")
+    )
+    private Set<PermisoEmpleadoEmbeddable> excepciones = new HashSet<>();
+
+    // Constructor vacío requerido por JPA
+    protected MatrizPermisosEntity() {
+    }
+
+    public MatrizPermisosEntity(UUID localId, boolean activa, Set<PermisoRolEmbeddable> permisosPorRol,
+                                Set<PermisoEmpleadoEmbeddable> excepciones) {
+        this.localId = localId;
+        this.activa = activa;
+        this.permisosPorRol = permisosPorRol;
+        this.excepciones = excepciones;
+    }
+
+    // Getters
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public boolean isActiva() {
+        return activa;
+    }
+
+    public Set<PermisoRolEmbeddable> getPermisosPorRol() {
+        return permisosPorRol;
+    }
+
+    public Set<PermisoEmpleadoEmbeddable> getExcepciones() {
+        return excepciones;
+    }
+}
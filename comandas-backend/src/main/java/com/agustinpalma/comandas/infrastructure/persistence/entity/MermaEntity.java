@@ -0,0 +1,122 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaMerma;
+import jakarta.persistence.*;
+
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para Merma.
+ * Representa la tabla mermas en la base de datos.
+ *
+ * HU-135: Los insumos perdidos viven en mermas_insumos con su costo del
+ * momento, para que el reporte mensual no cambie si el insumo se encarece.
+ */
+@Entity
+@Table(name = "mermas",
+    indexes = {
+        @Index(name = "idx_mermas_local_fecha", columnList = "local_id, fecha")
+    }
+)
+public class MermaEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "fecha", nullable = false)
+    private LocalDateTime fecha;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "categoria", nullable = false, length = 20)
+    private CategoriaMerma categoria;
+
+    @Column(name = "motivo", nullable = false, length = 100)
+    private String motivo;
+
+    @Column(name = "responsable", nullable = false, length = 60)
+    private String responsable;
+
+    @Column(name = "producto_id")
+    private UUID productoId;
+
+    @Column(name = "nombre_producto", length = 100)
+    private String nombreProducto;
+
+    @Column(name = "unidades_producto")
+    private Integer unidadesProducto;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "mermas_insumos",
+        joinColumns = @JoinColumn(name = "merma_id")
+    )
+    private List<RenglonMermaEmbeddable> renglones = new ArrayList<>();
+
+    // Constructor vacío requerido por JPA
+    protected MermaEntity() {
+    }
+
+    public MermaEntity(UUID id, UUID localId, LocalDateTime fecha, CategoriaMerma categoria, String motivo,
+                       String responsable, UUID productoId, String nombreProducto, Integer unidadesProducto,
+                       List<RenglonMermaEmbeddable> renglones) {
+        this.id = id;
+        this.localId = localId;
+        this.fecha = fecha;
+        this.categoria = categoria;
+        this.motivo = motivo;
+        this.responsable = responsable;
+        this.productoId = productoId;
+        this.nombreProducto = nombreProducto;
+        this.unidadesProducto = unidadesProducto;
+        this.renglones.addAll(renglones);
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public CategoriaMerma getCategoria() {
+        return categoria;
+    }
+
+    public String getMotivo() {
+        return motivo;
+    }
+
+    public String getResponsable() {
+        return responsable;
+    }
+
+    public UUID getProductoId() {
+        return productoId;
+    }
+
+    public String getNombreProducto() {
+        return nombreProducto;
+    }
+
+    public Integer getUnidadesProducto() {
+        return unidadesProducto;
+    }
+
+    public List<RenglonMermaEmbeddable> getRenglones() {
+        return renglones;
+    }
+}
@@ -43,4 +43,18 @@ public class MesaEntity {
     @Enumerated(EnumType.STRING)
     @Column(name = "estado", nullable = false, length = 20)
     private EstadoMesa estado;
+
+    // HU-112: Ubicación en el mapa del salón
+    @Column(name = "sector", nullable = false, length = 30)
+    private String sector;
+
+    @Column(name = "posicion_x")
+    private Integer posicionX;
+
+    @Column(name = "posicion_y")
+    private Integer posicionY;
+
+    // HU-149: Personas que entran (null = sin definir)
+    @Column(name = "capacidad")
+    private Integer capacidad;
 }
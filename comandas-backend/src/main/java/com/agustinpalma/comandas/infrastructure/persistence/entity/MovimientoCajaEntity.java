@@ -13,10 +13,16 @@ import java.util.UUID;
  * 
  * Un movimiento de caja registra un egreso de efectivo del local.
  * Incluye un número de comprobante generado automáticamente por el dominio.
+ * HU-106: turno_id referencia al turno abierto al registrarlo (null si no había).
+ * HU-121: pedido_id y autorizado_por_id solo se completan en las devoluciones.
+ * HU-130: categoria_egreso_id clasifica el egreso (null = sin categoría).
+ * HU-159: caja_id es la caja que lo registró (null = caja principal).
  */
 @Entity
 @Table(name = "movimientos_caja", indexes = {
-    @Index(name = "idx_movimiento_caja_local_fecha", columnList = "local_id, fecha")
+    @Index(name = "idx_movimiento_caja_local_fecha", columnList = "local_id, fecha"),
+    @Index(name = "idx_movimiento_caja_turno", columnList = "turno_id"),
+    @Index(name = "idx_movimiento_caja_pedido", columnList = "pedido_id")
 })
 public class MovimientoCajaEntity {
 
@@ -43,12 +49,28 @@ public class MovimientoCajaEntity {
     @Column(name = "numero_comprobante", nullable = false, length = 50, unique = true)
     private String numeroComprobante;
 
+    @Column(name = "turno_id")
+    private UUID turnoId;
+
+    @Column(name = "pedido_id")
+    private UUID pedidoId;
+
+    @Column(name = "autorizado_por_id")
+    private UUID autorizadoPorId;
+
+    @Column(name = "categoria_egreso_id")
+    private UUID categoriaEgresoId;
+
+    @Column(name = "caja_id")
+    private UUID cajaId;
+
     // Constructor vacío requerido por JPA
     protected MovimientoCajaEntity() {
     }
 
     public MovimientoCajaEntity(UUID id, UUID localId, BigDecimal monto, String descripcion,
-                                 LocalDateTime fecha, TipoMovimiento tipo, String numeroComprobante) {
+                                 LocalDateTime fecha, TipoMovimiento tipo, String numeroComprobante,
+                                 UUID turnoId) {
         this.id = id;
         this.localId = localId;
         this.monto = monto;
@@ -56,6 +78,7 @@ public class MovimientoCajaEntity {
         this.fecha = fecha;
         this.tipo = tipo;
         this.numeroComprobante = numeroComprobante;
+        this.turnoId = turnoId;
     }
 
     // Getters y setters
@@ -115,4 +138,44 @@ public class MovimientoCajaEntity {
     public void setNumeroComprobante(String numeroComprobante) {
         this.numeroComprobante = numeroComprobante;
     }
+
+    public UUID getTurnoId() {
+        return turnoId;
+    }
+
+    public void setTurnoId(UUID turnoId) {
+        this.turnoId = turnoId;
+    }
+
+    public UUID getPedidoId() {
+        return pedidoId;
+    }
+
+    public void setPedidoId(UUID pedidoId) {
+        this.pedidoId = pedidoId;
+    }
+
+    public UUID getAutorizadoPorId() {
+        return autorizadoPorId;
+    }
+
+    public void setAutorizadoPorId(UUID autorizadoPorId) {
+        this.autorizadoPorId = autorizadoPorId;
+    }
+
+    public UUID getCategoriaEgresoId() {
+        return categoriaEgresoId;
+    }
+
+    public void setCategoriaEgresoId(UUID categoriaEgresoId) {
+        this.categoriaEgresoId = categoriaEgresoId;
+    }
+
+    public UUID getCajaId() {
+        return cajaId;
+    }
+
+    public void setCajaId(UUID cajaId) {
+        this.cajaId = cajaId;
+    }
 }
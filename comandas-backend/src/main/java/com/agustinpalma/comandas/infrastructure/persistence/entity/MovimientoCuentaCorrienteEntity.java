@@ -0,0 +1,86 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * JPA entity for customer running account movements (charges and payments).
+ * HU-104: Customer running accounts.
+ */
+@Entity
+@Table(name = "movimientos_cuenta_corriente")
+public class MovimientoCuentaCorrienteEntity {
+
+    @Id
+    @Column(name = "id", nullable = false)
+    private UUID id;
+
+    @Column(name = "cliente_id", nullable = false)
+    private UUID clienteId;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "tipo", nullable = false, length = 20)
+    private String tipo;
+
+    @Column(name = "monto", nullable = false, precision = 10, scale = 2)
+    private BigDecimal monto;
+
+    @Column(name = "fecha", nullable = false)
+    private LocalDateTime fecha;
+
+    @Column(name = "pedido_id")
+    private UUID pedidoId;
+
+    @Column(name = "medio_pago", length = 30)
+    private String medioPago;
+
+    @Column(name = "descripcion", nullable = false, length = 255)
+    private String descripcion;
+
+    public MovimientoCuentaCorrienteEntity() {}
+
+    public MovimientoCuentaCorrienteEntity(UUID id, UUID clienteId, UUID localId, String tipo,
+                                           BigDecimal monto, LocalDateTime fecha, UUID pedidoId,
+                                           String medioPago, String descripcion) {
+        this.id = id;
+        this.clienteId = clienteId;
+        this.localId = localId;
+        this.tipo = tipo;
+        this.monto = monto;
+        this.fecha = fecha;
+        this.pedidoId = pedidoId;
+        this.medioPago = medioPago;
+        this.descripcion = descripcion;
+    }
+
+    public UUID getId() { return id; }
+    public void setId(UUID id) { this.id = id; }
+
+    public UUID getClienteId() { return clienteId; }
+    public void setClienteId(UUID clienteId) { this.clienteId = clienteId; }
+
+    public UUID getLocalId() { return localId; }
+    public void setLocalId(UUID localId) { this.localId = localId; }
+
+    public String getTipo() { return tipo; }
+    public void setTipo(String tipo) { this.tipo = tipo; }
+
+    public BigDecimal getMonto() { return monto; }
+    public void setMonto(BigDecimal monto) { this.monto = monto; }
+
+    public LocalDateTime getFecha() { return fecha; }
+    public void setFecha(LocalDateTime fecha) { this.fecha = fecha; }
+
+    public UUID getPedidoId() { return pedidoId; }
+    public void setPedidoId(UUID pedidoId) { this.pedidoId = pedidoId; }
+
+    public String getMedioPago() { return medioPago; }
+    public void setMedioPago(String medioPago) { this.medioPago = medioPago; }
+
+    public String getDescripcion() { return descripcion; }
+    public void setDescripcion(String descripcion) { this.descripcion = descripcion; }
+}
@@ -0,0 +1,91 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoInsumo;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para MovimientoInsumo.
+ * Representa la tabla movimientos_insumo en la base de datos.
+ *
+ * HU-133: Es el registro de auditoría del stock teórico de cada insumo.
+ */
+@Entity
+@Table(name = "movimientos_insumo",
+    indexes = {
+        @Index(name = "idx_movimientos_insumo_insumo_fecha", columnList = "insumo_id, fecha")
+    }
+)
+public class MovimientoInsumoEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "insumo_id", nullable = false)
+    private UUID insumoId;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "cantidad", nullable = false, precision = 12, scale = 3)
+    private BigDecimal cantidad;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "tipo", nullable = false, length = 30)
+    private TipoMovimientoInsumo tipo;
+
+    @Column(name = "fecha", nullable = false)
+    private LocalDateTime fecha;
+
+    @Column(name = "motivo", nullable = false, length = 120)
+    private String motivo;
+
+    // Constructor vacío requerido por JPA
+    protected MovimientoInsumoEntity() {
+    }
+
+    public MovimientoInsumoEntity(UUID id, UUID insumoId, UUID localId, BigDecimal cantidad,
+                                  TipoMovimientoInsumo tipo, LocalDateTime fecha, String motivo) {
+        this.id = id;
+        this.insumoId = insumoId;
+        this.localId = localId;
+        this.cantidad = cantidad;
+        this.tipo = tipo;
+        this.fecha = fecha;
+        this.motivo = motivo;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getInsumoId() {
+        return insumoId;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public BigDecimal getCantidad() {
+        return cantidad;
+    }
+
+    public TipoMovimientoInsumo getTipo() {
+        return tipo;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public String getMotivo() {
+        return motivo;
+    }
+}
@@ -0,0 +1,86 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * JPA entity for loyalty points movements (accruals and redemptions).
+ * HU-105: Customer loyalty points.
+ */
+@Entity
+@Table(name = "movimientos_puntos")
+public class MovimientoPuntosEntity {
+
+    @Id
+    @Column(name = "id", nullable = false)
+    private UUID id;
+
+    @Column(name = "cliente_id", nullable = false)
+    private UUID clienteId;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "tipo", nullable = false, length = 20)
+    private String tipo;
+
+    @Column(name = "puntos", nullable = false)
+    private int puntos;
+
+    @Column(name = "fecha", nullable = false)
+    private LocalDateTime fecha;
+
+    @Column(name = "pedido_id", nullable = false)
+    private UUID pedidoId;
+
+    @Column(name = "fecha_vencimiento")
+    private LocalDateTime fechaVencimiento;
+
+    @Column(name = "monto_descuento", precision = 10, scale = 2)
+    private BigDecimal montoDescuento;
+
+    public MovimientoPuntosEntity() {}
+
+    public MovimientoPuntosEntity(UUID id, UUID clienteId, UUID localId, String tipo, int puntos,
+                                  LocalDateTime fecha, UUID pedidoId, LocalDateTime fechaVencimiento,
+                                  BigDecimal montoDescuento) {
+        this.id = id;
+        this.clienteId = clienteId;
+        this.localId = localId;
+        this.tipo = tipo;
+        this.puntos = puntos;
+        this.fecha = fecha;
+        this.pedidoId = pedidoId;
+        this.fechaVencimiento = fechaVencimiento;
+        this.montoDescuento = montoDescuento;
+    }
+
+    public UUID getId() { return id; }
+    public void setId(UUID id) { this.id = id; }
+
+    public UUID getClienteId() { return clienteId; }
+    public void setClienteId(UUID clienteId) { this.clienteId = clienteId; }
+
+    public UUID getLocalId() { return localId; }
+    public void setLocalId(UUID localId) { this.localId = localId; }
+
+    public String getTipo() { return tipo; }
+    public void setTipo(String tipo) { this.tipo = tipo; }
+
+    public int getPuntos() { return puntos; }
+    public void setPuntos(int puntos) { this.puntos = puntos; }
+
+    public LocalDateTime getFecha() { return fecha; }
+    public void setFecha(LocalDateTime fecha) { this.fecha = fecha; }
+
+    public UUID getPedidoId() { return pedidoId; }
+    public void setPedidoId(UUID pedidoId) { this.pedidoId = pedidoId; }
+
+    public LocalDateTime getFechaVencimiento() { return fechaVencimiento; }
+    public void setFechaVencimiento(LocalDateTime fechaVencimiento) { this.fechaVencimiento = fechaVencimiento; }
+
+    public BigDecimal getMontoDescuento() { return montoDescuento; }
+    public void setMontoDescuento(BigDecimal montoDescuento) { this.montoDescuento = montoDescuento; }
+}
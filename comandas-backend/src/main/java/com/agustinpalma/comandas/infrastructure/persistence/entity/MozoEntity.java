@@ -0,0 +1,102 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para Mozo.
+ * Representa la tabla mozos en la base de datos.
+ *
+ * HU-111: Los pedidos referencian al mozo por mozo_id.
+ * HU-121: rol y hash del PIN de autorización.
+ * HU-124: código de la tarjeta de fichaje, único por local.
+ * HU-132: tope mensual de consumo interno (null = sin tope).
+ */
+@Entity
+@Table(name = "mozos",
+    uniqueConstraints = @UniqueConstraint(
+        name = "uk_mozo_local_tarjeta",
+        columnNames = {"local_id", "codigo_tarjeta"}
+    )
+)
+public class MozoEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "nombre", nullable = false, length = 60)
+    private String nombre;
+
+    @Column(name = "activo", nullable = false)
+    private boolean activo;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "rol", nullable = false, length = 20)
+    private RolPersonal rol;
+
+    @Column(name = "pin_hash", length = 64)
+    private String pinHash;
+
+    @Column(name = "codigo_tarjeta", length = 32)
+    private String codigoTarjeta;
+
+    @Column(name = "tope_consumo_mensual", precision = 10, scale = 2)
+    private BigDecimal topeConsumoMensual;
+
+    // Constructor vacío requerido por JPA
+    protected MozoEntity() {
+    }
+
+    public MozoEntity(UUID id, UUID localId, String nombre, boolean activo, RolPersonal rol, String pinHash,
+                      String codigoTarjeta, BigDecimal topeConsumoMensual) {
+        this.id = id;
+        this.localId = localId;
+        this.nombre = nombre;
+        this.activo = activo;
+        this.rol = rol;
+        this.pinHash = pinHash;
+        this.codigoTarjeta = codigoTarjeta;
+        this.topeConsumoMensual = topeConsumoMensual;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public boolean isActivo() {
+        return activo;
+    }
+
+    public RolPersonal getRol() {
+        return rol;
+    }
+
+    public String getPinHash() {
+        return pinHash;
+    }
+
+    public String getCodigoTarjeta() {
+        return codigoTarjeta;
+    }
+
+    public BigDecimal getTopeConsumoMensual() {
+        return topeConsumoMensual;
+    }
+}
@@ -1,6 +1,7 @@
 package com.agustinpalma.comandas.infrastructure.persistence.entity;
 
 import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Moneda;
 import jakarta.persistence.*;
 
 import java.math.BigDecimal;
@@ -13,6 +14,9 @@ import java.util.UUID;
  * 
  * Un pedido puede tener múltiples pagos (soporte split/pagos parciales).
  * La suma de todos los pagos debe coincidir con el montoTotalFinal del pedido.
+ * HU-143: El recargo del medio de pago se guarda aparte del monto.
+ * HU-151: Moneda, monto en esa moneda y tipo de cambio congelado del pago.
+ * HU-159: Caja que cobró el pago (null: la caja principal).
  */
 @Entity
 @Table(name = "pedidos_pagos", indexes = {
@@ -42,6 +46,37 @@ public class PagoEntity {
     @Column(name = "fecha", nullable = false)
     private LocalDateTime fecha;
 
+    @Column(name = "recargo", nullable = false, precision = 10, scale = 2)
+    private BigDecimal recargo = BigDecimal.ZERO;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "moneda", nullable = false, length = 3)
+    private Moneda moneda = Moneda.ARS;
+
+    /**
+     * Null en pagos anteriores a HU-151: equivale al monto.
+     */
+    @Column(name = "monto_original", precision = 12, scale = 2)
+    private BigDecimal montoOriginal;
+
+    @Column(name = "tipo_cambio", nullable = false, precision = 12, scale = 4)
+    private BigDecimal tipoCambio = BigDecimal.ONE;
+
+    /**
+     * HU-152: lo que se sumó o restó al efectivo para redondearlo.
+     */
+    @Column(name = "ajuste_redondeo", nullable = false, precision = 10, scale = 2)
+    private BigDecimal ajusteRedondeo = BigDecimal.ZERO;
+
+    /**
+     * HU-156: número de cupón u operación del comprobante.
+     */
+    @Column(name = "referencia", length = 64)
+    private String referencia;
+
+    @Column(name = "caja_id")
+    private UUID cajaId;
+
     // Constructor vacío requerido por JPA
     protected PagoEntity() {
     }
@@ -53,6 +88,39 @@ public class PagoEntity {
         this.fecha = fecha;
     }
 
+    public PagoEntity(UUID id, MedioPago medioPago, BigDecimal monto, LocalDateTime fecha, BigDecimal recargo) {
+        this(id, medioPago, monto, fecha);
+        this.recargo = recargo;
+    }
+
+    public PagoEntity(UUID id, MedioPago medioPago, BigDecimal monto, LocalDateTime fecha, BigDecimal recargo,
+                      Moneda moneda, BigDecimal montoOriginal, BigDecimal tipoCambio) {
+        this(id, medioPago, monto, fecha, recargo);
+        this.moneda = moneda;
+        this.montoOriginal = montoOriginal;
+        this.tipoCambio = tipoCambio;
+    }
+
+    public PagoEntity(UUID id, MedioPago medioPago, BigDecimal monto, LocalDateTime fecha, BigDecimal recargo,
+                      Moneda moneda, BigDecimal montoOriginal, BigDecimal tipoCambio, BigDecimal ajusteRedondeo) {
+        this(id, medioPago, monto, fecha, recargo, moneda, montoOriginal, tipoCambio);
+        this.ajusteRedondeo = ajusteRedondeo;
+    }
+
+    public PagoEntity(UUID id, MedioPago medioPago, BigDecimal monto, LocalDateTime fecha, BigDecimal recargo,
+                      Moneda moneda, BigDecimal montoOriginal, BigDecimal tipoCambio, BigDecimal ajusteRedondeo,
+                      String referencia) {
+        this(id, medioPago, monto, fecha, recargo, moneda, montoOriginal, tipoCambio, ajusteRedondeo);
+        this.referencia = referencia;
+    }
+
+    public PagoEntity(UUID id, MedioPago medioPago, BigDecimal monto, LocalDateTime fecha, BigDecimal recargo,
+                      Moneda moneda, BigDecimal montoOriginal, BigDecimal tipoCambio, BigDecimal ajusteRedondeo,
+                      String referencia, UUID cajaId) {
+        this(id, medioPago, monto, fecha, recargo, moneda, montoOriginal, tipoCambio, ajusteRedondeo, referencia);
+        this.cajaId = cajaId;
+    }
+
     // Getters y setters
 
     public UUID getId() {
@@ -90,4 +158,60 @@ public class PagoEntity {
     public void setFecha(LocalDateTime fecha) {
         this.fecha = fecha;
     }
+
+    public BigDecimal getRecargo() {
+        return recargo;
+    }
+
+    public void setRecargo(BigDecimal recargo) {
+        this.recargo = recargo;
+    }
+
+    public Moneda getMoneda() {
+        return moneda;
+    }
+
+    public void setMoneda(Moneda moneda) {
+        this.moneda = moneda;
+    }
+
+    public BigDecimal getMontoOriginal() {
+        return montoOriginal;
+    }
+
+    public void setMontoOriginal(BigDecimal montoOriginal) {
+        this.montoOriginal = montoOriginal;
+    }
+
+    public BigDecimal getTipoCambio() {
+        return tipoCambio;
+    }
+
+    public void setTipoCambio(BigDecimal tipoCambio) {
+        this.tipoCambio = tipoCambio;
+    }
+
+    public BigDecimal getAjusteRedondeo() {
+        return ajusteRedondeo;
+    }
+
+    public void setAjusteRedondeo(BigDecimal ajusteRedondeo) {
+        this.ajusteRedondeo = ajusteRedondeo;
+    }
+
+    public String getReferencia() {
+        return referencia;
+    }
+
+    public void setReferencia(String referencia) {
+        this.referencia = referencia;
+    }
+
+    public UUID getCajaId() {
+        return cajaId;
+    }
+
+    public void setCajaId(UUID cajaId) {
+        this.cajaId = cajaId;
+    }
 }
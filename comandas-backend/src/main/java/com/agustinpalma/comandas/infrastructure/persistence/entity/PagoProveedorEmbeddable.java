@@ -0,0 +1,57 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+import jakarta.persistence.EnumType;
+import jakarta.persistence.Enumerated;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Embeddable para los pagos asentados contra una factura de proveedor (HU-131).
+ */
+@Embeddable
+public class PagoProveedorEmbeddable {
+
+    @Column(name = "fecha", nullable = false)
+    private LocalDateTime fecha;
+
+    @Column(name = "monto", nullable = false, precision = 10, scale = 2)
+    private BigDecimal monto;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "medio_pago", nullable = false, length = 20)
+    private MedioPago medioPago;
+
+    @Column(name = "movimiento_caja_id")
+    private UUID movimientoCajaId;
+
+    // Constructor vacío para JPA
+    public PagoProveedorEmbeddable() {}
+
+    public PagoProveedorEmbeddable(LocalDateTime fecha, BigDecimal monto, MedioPago medioPago, UUID movimientoCajaId) {
+        this.fecha = fecha;
+        this.monto = monto;
+        this.medioPago = medioPago;
+        this.movimientoCajaId = movimientoCajaId;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+
+    public MedioPago getMedioPago() {
+        return medioPago;
+    }
+
+    public UUID getMovimientoCajaId() {
+        return movimientoCajaId;
+    }
+}
@@ -0,0 +1,52 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * Embeddable para la parte de cada empleado en el reparto de propinas (HU-141).
+ */
+@Embeddable
+public class PartePropinaEmbeddable {
+
+    @Column(name = "mozo_id", nullable = false)
+    private UUID mozoId;
+
+    @Column(name = "minutos", nullable = false)
+    private long minutos;
+
+    @Column(name = "peso", nullable = false)
+    private long peso;
+
+    @Column(name = "monto", nullable = false, precision = 10, scale = 2)
+    private BigDecimal monto;
+
+    // Constructor vacío para JPA
+    public PartePropinaEmbeddable() {}
+
+    public PartePropinaEmbeddable(UUID mozoId, long minutos, long peso, BigDecimal monto) {
+        this.mozoId = mozoId;
+        this.minutos = minutos;
+        this.peso = peso;
+        this.monto = monto;
+    }
+
+    public UUID getMozoId() {
+        return mozoId;
+    }
+
+    public long getMinutos() {
+        return minutos;
+    }
+
+    public long getPeso() {
+        return peso;
+    }
+
+    public BigDecimal getMonto() {
+        return monto;
+    }
+}
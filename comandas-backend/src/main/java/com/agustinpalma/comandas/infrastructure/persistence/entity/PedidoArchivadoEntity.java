@@ -0,0 +1,108 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para un pedido cerrado que salió de la base operativa.
+ * Representa la tabla pedidos_archivados en la base de datos.
+ *
+ * HU-162: El pedido completo (ítems, pagos, descuentos) viaja comprimido en
+ * "contenido"; las columnas sueltas son solo las que hacen falta para
+ * buscarlo por fecha sin descomprimir nada.
+ */
+@Entity
+@Table(
+    name = "pedidos_archivados",
+    indexes = {
+        @Index(name = "idx_pedidos_archivados_local_cierre", columnList = "local_id, fecha_cierre"),
+        @Index(name = "idx_pedidos_archivados_local_comprobante", columnList = "local_id, numero_comprobante")
+    }
+)
+public class PedidoArchivadoEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "numero", nullable = false)
+    private int numero;
+
+    @Column(name = "fecha_apertura", nullable = false)
+    private LocalDateTime fechaApertura;
+
+    @Column(name = "fecha_cierre", nullable = false)
+    private LocalDateTime fechaCierre;
+
+    @Column(name = "monto_total", precision = 12, scale = 2)
+    private BigDecimal montoTotal;
+
+    @Column(name = "numero_comprobante", length = 30)
+    private String numeroComprobante;
+
+    @Column(name = "contenido", nullable = false)
+    private byte[] contenido;
+
+    @Column(name = "archivado_en", nullable = false)
+    private LocalDateTime archivadoEn;
+
+    // Constructor vacío requerido por JPA
+    protected PedidoArchivadoEntity() {
+    }
+
+    public PedidoArchivadoEntity(UUID id, UUID localId, int numero, LocalDateTime fechaApertura,
+                                 LocalDateTime fechaCierre, BigDecimal montoTotal, String numeroComprobante,
+                                 byte[] contenido, LocalDateTime archivadoEn) {
+        this.id = id;
+        this.localId = localId;
+        this.numero = numero;
+        this.fechaApertura = fechaApertura;
+        this.fechaCierre = fechaCierre;
+        this.montoTotal = montoTotal;
+        this.numeroComprobante = numeroComprobante;
+        this.contenido = contenido;
+        this.archivadoEn = archivadoEn;
+    }
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public int getNumero() {
+        return numero;
+    }
+
+    public LocalDateTime getFechaApertura() {
+        return fechaApertura;
+    }
+
+    public LocalDateTime getFechaCierre() {
+        return fechaCierre;
+    }
+
+    public BigDecimal getMontoTotal() {
+        return montoTotal;
+    }
+
+    public String getNumeroComprobante() {
+        return numeroComprobante;
+    }
+
+    public byte[] getContenido() {
+        return contenido;
+    }
+
+    public LocalDateTime getArchivadoEn() {
+        return archivadoEn;
+    }
+}
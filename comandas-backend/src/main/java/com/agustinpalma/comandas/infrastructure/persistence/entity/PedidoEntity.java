@@ -1,5 +1,7 @@
 package com.agustinpalma.comandas.infrastructure.persistence.entity;
 
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TiempoServicio;
 import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
 import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
 import com.agustinpalma.comandas.domain.model.DomainEnums.ModoDescuento;
@@ -22,7 +24,9 @@ import java.util.UUID;
 @Entity
 @Table(name = "pedidos", indexes = {
     @Index(name = "idx_pedido_mesa_estado", columnList = "mesa_id, estado"),
-    @Index(name = "idx_pedido_local_numero", columnList = "local_id, numero")
+    @Index(name = "idx_pedido_local_numero", columnList = "local_id, numero"),
+    @Index(name = "uk_pedidos_local_numero_comprobante", columnList = "local_id, numero_comprobante", unique = true),
+    @Index(name = "idx_pedido_local_cliente", columnList = "local_id, cliente_id")
 })
 public class PedidoEntity {
 
@@ -94,6 +98,19 @@ public class PedidoEntity {
     @Column(name = "desc_global_fecha")
     private LocalDateTime descGlobalFecha;
 
+    /**
+     * HU-122: Mozo o encargado que autorizó el descuento global.
+     * Null si no tiene descuento o no se registró quién lo autorizó.
+     */
+    @Column(name = "desc_global_autorizado_por_id")
+    private UUID descGlobalAutorizadoPorId;
+
+    /**
+     * HU-122: Nombre de quien autorizó, congelado al aplicar el descuento.
+     */
+    @Column(name = "desc_global_autorizado_por", length = 60)
+    private String descGlobalAutorizadoPor;
+
     /**
      * HU-07: Relación bidireccional con ítems del pedido.
      * 
@@ -120,6 +137,7 @@ public class PedidoEntity {
         orphanRemoval = true,
         fetch = FetchType.LAZY
     )
+    @OrderBy("fecha ASC")  // HU-160: los pagos a cuenta primero, en el orden en que se cobraron
     private List<PagoEntity> pagos = new ArrayList<>();
 
     // ============================================
@@ -147,6 +165,123 @@ public class PedidoEntity {
     @Column(name = "ultimo_envio_cocina")
     private LocalDateTime ultimoEnvioCocina;
 
+    // ============================================
+    // HU-105: Canje de puntos aplicado al pedido
+    // ============================================
+
+    @Column(name = "puntos_canjeados", nullable = false)
+    private int puntosCanjeados;
+
+    @Column(name = "monto_canje_puntos", precision = 10, scale = 2)
+    private BigDecimal montoCanjePuntos;
+
+    // ============================================
+    // HU-106: Turno de caja en el que se cobró
+    // ============================================
+
+    @Column(name = "turno_id")
+    private UUID turnoId;
+
+    // ============================================
+    // HU-108: Canal de venta y lista de precios usada
+    // ============================================
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "canal", length = 20)
+    private CanalVenta canal;
+
+    @Column(name = "lista_precios_id")
+    private UUID listaPreciosId;
+
+    @Column(name = "nombre_lista_precios", length = 100)
+    private String nombreListaPrecios;
+
+    // ============================================
+    // HU-110: Paso de servicio en marcha
+    // ============================================
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "tiempo_en_marcha", length = 20)
+    private TiempoServicio tiempoEnMarcha;
+
+    // ============================================
+    // HU-111: Mozo, cubiertos, propina y anulaciones
+    // ============================================
+
+    @Column(name = "mozo_id")
+    private UUID mozoId;
+
+    @Column(name = "cubiertos", nullable = false)
+    private int cubiertos;
+
+    @Column(name = "propina", precision = 10, scale = 2)
+    private BigDecimal propina;
+
+    @Column(name = "unidades_anuladas", nullable = false)
+    private int unidadesAnuladas;
+
+    @Column(name = "monto_anulado", precision = 10, scale = 2)
+    private BigDecimal montoAnulado;
+
+    // ============================================
+    // HU-113: Última actividad de la mesa
+    // ============================================
+
+    @Column(name = "ultima_actividad")
+    private LocalDateTime ultimaActividad;
+
+    // HU-137: Pedido urgente para cocina
+    @Column(name = "urgente", nullable = false)
+    private boolean urgente;
+
+    // HU-138: Horario de retiro prometido (take away)
+    @Column(name = "hora_prometida")
+    private LocalDateTime horaPrometida;
+
+    // HU-139: Entrega de delivery
+    @Column(name = "direccion_entrega", length = 200)
+    private String direccionEntrega;
+
+    @Column(name = "zona_delivery_id")
+    private UUID zonaDeliveryId;
+
+    // HU-140: Reparto del delivery
+    @Column(name = "repartidor_id")
+    private UUID repartidorId;
+
+    @Column(name = "salida_reparto")
+    private LocalDateTime salidaReparto;
+
+    @Column(name = "regreso_reparto")
+    private LocalDateTime regresoReparto;
+
+    // HU-142: Reserva sentada en la mesa y su seña
+    @Column(name = "reserva_id")
+    private UUID reservaId;
+
+    @Column(name = "monto_senia", precision = 10, scale = 2)
+    private BigDecimal montoSenia;
+
+    // HU-159: Caja donde se cobró (null = caja principal)
+    @Column(name = "caja_id")
+    private UUID cajaId;
+
+    // HU-165: Sube en cada guardado para detectar ediciones desde otra terminal.
+    // Solo la sube el UPDATE condicional del repositorio, nunca el guardado de la entidad
+    @Column(name = "version", nullable = false, updatable = false)
+    private long version;
+
+    // HU-166: Número de comprobante (único en el local; la base frena cualquier duplicado)
+    @Column(name = "numero_comprobante", length = 30)
+    private String numeroComprobante;
+
+    // HU-168: Cliente vinculado y promo de cumpleaños
+    @Column(name = "cliente_id")
+    private UUID clienteId;
+
+    @Column(name = "monto_promo_cumpleanios", precision = 10, scale = 2)
+    private BigDecimal montoPromoCumpleanios;
+
     // Constructor vacío requerido por JPA
     protected PedidoEntity() {
     }
@@ -269,6 +404,22 @@ public class PedidoEntity {
         this.descGlobalFecha = descGlobalFecha;
     }
 
+    public UUID getDescGlobalAutorizadoPorId() {
+        return descGlobalAutorizadoPorId;
+    }
+
+    public void setDescGlobalAutorizadoPorId(UUID descGlobalAutorizadoPorId) {
+        this.descGlobalAutorizadoPorId = descGlobalAutorizadoPorId;
+    }
+
+    public String getDescGlobalAutorizadoPor() {
+        return descGlobalAutorizadoPor;
+    }
+
+    public void setDescGlobalAutorizadoPor(String descGlobalAutorizadoPor) {
+        this.descGlobalAutorizadoPor = descGlobalAutorizadoPor;
+    }
+
     public List<ItemPedidoEntity> getItems() {
         return items;
     }
@@ -352,4 +503,244 @@ public class PedidoEntity {
     public void setUltimoEnvioCocina(LocalDateTime ultimoEnvioCocina) {
         this.ultimoEnvioCocina = ultimoEnvioCocina;
     }
+
+    // ============================================
+    // HU-105: Getters y Setters del canje de puntos
+    // ============================================
+
+    public int getPuntosCanjeados() {
+        return puntosCanjeados;
+    }
+
+    public void setPuntosCanjeados(int puntosCanjeados) {
+        this.puntosCanjeados = puntosCanjeados;
+    }
+
+    public BigDecimal getMontoCanjePuntos() {
+        return montoCanjePuntos;
+    }
+
+    public void setMontoCanjePuntos(BigDecimal montoCanjePuntos) {
+        this.montoCanjePuntos = montoCanjePuntos;
+    }
+
+    // ============================================
+    // HU-106: Getter y Setter del turno de caja
+    // ============================================
+
+    public UUID getTurnoId() {
+        return turnoId;
+    }
+
+    public void setTurnoId(UUID turnoId) {
+        this.turnoId = turnoId;
+    }
+
+    // ============================================
+    // HU-108: Getters y Setters de canal y lista de precios
+    // ============================================
+
+    public CanalVenta getCanal() {
+        return canal;
+    }
+
+    public void setCanal(CanalVenta canal) {
+        this.canal = canal;
+    }
+
+    public UUID getListaPreciosId() {
+        return listaPreciosId;
+    }
+
+    public void setListaPreciosId(UUID listaPreciosId) {
+        this.listaPreciosId = listaPreciosId;
+    }
+
+    public String getNombreListaPrecios() {
+        return nombreListaPrecios;
+    }
+
+    public void setNombreListaPrecios(String nombreListaPrecios) {
+        this.nombreListaPrecios = nombreListaPrecios;
+    }
+
+    // ============================================
+    // HU-110: Getter y Setter de tiempoEnMarcha
+    // ============================================
+
+    public TiempoServicio getTiempoEnMarcha() {
+        return tiempoEnMarcha;
+    }
+
+    public void setTiempoEnMarcha(TiempoServicio tiempoEnMarcha) {
+        this.tiempoEnMarcha = tiempoEnMarcha;
+    }
+
+    // ============================================
+    // HU-111: Getters y Setters de atención y anulaciones
+    // ============================================
+
+    public UUID getMozoId() {
+        return mozoId;
+    }
+
+    public void setMozoId(UUID mozoId) {
+        this.mozoId = mozoId;
+    }
+
+    public int getCubiertos() {
+        return cubiertos;
+    }
+
+    public void setCubiertos(int cubiertos) {
+        this.cubiertos = cubiertos;
+    }
+
+    public BigDecimal getPropina() {
+        return propina;
+    }
+
+    public void setPropina(BigDecimal propina) {
+        this.propina = propina;
+    }
+
+    public int getUnidadesAnuladas() {
+        return unidadesAnuladas;
+    }
+
+    public void setUnidadesAnuladas(int unidadesAnuladas) {
+        this.unidadesAnuladas = unidadesAnuladas;
+    }
+
+    public BigDecimal getMontoAnulado() {
+        return montoAnulado;
+    }
+
+    public void setMontoAnulado(BigDecimal montoAnulado) {
+        this.montoAnulado = montoAnulado;
+    }
+
+    // ============================================
+    // HU-113: Getter y Setter de última actividad
+    // ============================================
+
+    public LocalDateTime getUltimaActividad() {
+        return ultimaActividad;
+    }
+
+    public void setUltimaActividad(LocalDateTime ultimaActividad) {
+        this.ultimaActividad = ultimaActividad;
+    }
+
+    public boolean isUrgente() {
+        return urgente;
+    }
+
+    public void setUrgente(boolean urgente) {
+        this.urgente = urgente;
+    }
+
+    public LocalDateTime getHoraPrometida() {
+        return horaPrometida;
+    }
+
+    public void setHoraPrometida(LocalDateTime horaPrometida) {
+        this.horaPrometida = horaPrometida;
+    }
+
+    public String getDireccionEntrega() {
+        return direccionEntrega;
+    }
+
+    public void setDireccionEntrega(String direccionEntrega) {
+        this.direccionEntrega = direccionEntrega;
+    }
+
+    public UUID getZonaDeliveryId() {
+        return zonaDeliveryId;
+    }
+
+    public void setZonaDeliveryId(UUID zonaDeliveryId) {
+        this.zonaDeliveryId = zonaDeliveryId;
+    }
+
+    public UUID getRepartidorId() {
+        return repartidorId;
+    }
+
+    public void setRepartidorId(UUID repartidorId) {
+        this.repartidorId = repartidorId;
+    }
+
+    public LocalDateTime getSalidaReparto() {
+        return salidaReparto;
+    }
+
+    public void setSalidaReparto(LocalDateTime salidaReparto) {
+        this.salidaReparto = salidaReparto;
+    }
+
+    public LocalDateTime getRegresoReparto() {
+        return regresoReparto;
+    }
+
+    public void setRegresoReparto(LocalDateTime regresoReparto) {
+        this.regresoReparto = regresoReparto;
+    }
+
+    public UUID getReservaId() {
+        return reservaId;
+    }
+
+    public void setReservaId(UUID reservaId) {
+        this.reservaId = reservaId;
+    }
+
+    public BigDecimal getMontoSenia() {
+        return montoSenia;
+    }
+
+    public void setMontoSenia(BigDecimal montoSenia) {
+        this.montoSenia = montoSenia;
+    }
+
+    public UUID getCajaId() {
+        return cajaId;
+    }
+
+    public void setCajaId(UUID cajaId) {
+        this.cajaId = cajaId;
+    }
+
+    public long getVersion() {
+        return version;
+    }
+
+    public void setVersion(long version) {
+        this.version = version;
+    }
+
+    public String getNumeroComprobante() {
+        return numeroComprobante;
+    }
+
+    public void setNumeroComprobante(String numeroComprobante) {
+        this.numeroComprobante = numeroComprobante;
+    }
+
+    public UUID getClienteId() {
+        return clienteId;
+    }
+
+    public void setClienteId(UUID clienteId) {
+        this.clienteId = clienteId;
+    }
+
+    public BigDecimal getMontoPromoCumpleanios() {
+        return montoPromoCumpleanios;
+    }
+
+    public void setMontoPromoCumpleanios(BigDecimal montoPromoCumpleanios) {
+        this.montoPromoCumpleanios = montoPromoCumpleanios;
+    }
 }
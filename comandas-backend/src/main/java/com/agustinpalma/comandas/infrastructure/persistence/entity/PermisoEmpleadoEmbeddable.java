@@ -0,0 +1,63 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+import jakarta.persistence.EnumType;
+import jakarta.persistence.Enumerated;
+
+import java.util.Objects;
+import java.util.UUID;
+
+/**
+ * Embeddable para cada excepción de un empleado a los permisos de su rol (HU-169).
+ * Va en un Set: implementa equals y hashCode por valor.
+ */
+@Embeddable
+public class PermisoEmpleadoEmbeddable {
+
+    @Column(name = "empleado_id", nullable = false)
+    private UUID empleadoId;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "permiso", nullable = false, length = 30)
+    private Permiso permiso;
+
+    @Column(name = "concedido", nullable = false)
+    private boolean concedido;
+
+    // Constructor vacío para JPA
+    public PermisoEmpleadoEmbeddable() {}
+
+    public PermisoEmpleadoEmbeddable(UUID empleadoId, Permiso permiso, boolean concedido) {
+        this.empleadoId = empleadoId;
+        this.permiso = permiso;
+        this.concedido = concedido;
+    }
+
+    public UUID getEmpleadoId() {
+        return empleadoId;
+    }
+
+    public Permiso getPermiso() {
+        return permiso;
+    }
+
+    public boolean isConcedido() {
+        return concedido;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (!(o instanceof PermisoEmpleadoEmbeddable that)) return false;
+        return concedido == that.concedido
+            && Objects.equals(empleadoId, that.empleadoId)
+            && permiso == that.permiso;
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(empleadoId, permiso, concedido);
+    }
+}
@@ -0,0 +1,54 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+import jakarta.persistence.EnumType;
+import jakarta.persistence.Enumerated;
+
+import java.util.Objects;
+
+/**
+ * Embeddable para cada permiso habilitado de un rol en la matriz de permisos (HU-169).
+ * Va en un Set: implementa equals y hashCode por valor.
+ */
+@Embeddable
+public class PermisoRolEmbeddable {
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "rol", nullable = false, length = 20)
+    private RolPersonal rol;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "permiso", nullable = false, length = 30)
+    private Permiso permiso;
+
+    // Constructor vacío para JPA
+    public PermisoRolEmbeddable() {}
+
+    public PermisoRolEmbeddable(RolPersonal rol, Permiso permiso) {
+        this.rol = rol;
+        this.permiso = permiso;
+    }
+
+    public RolPersonal getRol() {
+        return rol;
+    }
+
+    public Permiso getPermiso() {
+        return permiso;
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) return true;
+        if (!(o instanceof PermisoRolEmbeddable that)) return false;
+        return rol == that.rol && permiso == that.permiso;
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(rol, permiso);
+    }
+}
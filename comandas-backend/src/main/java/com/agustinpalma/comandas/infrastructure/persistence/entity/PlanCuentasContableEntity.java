@@ -0,0 +1,52 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ConceptoContable;
+import jakarta.persistence.*;
+
+import java.util.EnumMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para PlanCuentasContable, una por local.
+ * Representa la tabla planes_cuentas_contables en la base de datos.
+ *
+ * HU-167: Exportación al sistema contable.
+ */
+@Entity
+@Table(name = "planes_cuentas_contables")
+public class PlanCuentasContableEntity {
+
+    @Id
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "planes_cuentas_contables_cuentas",
+        joinColumns = @JoinColumn(name = "local_id")
+    )
+    @MapKeyEnumerated(EnumType.STRING)
+    @MapKeyColumn(name = "concepto", length = 30)
+    @Column(name = "cuenta", nullable = false, length = 30)
+    private Map<ConceptoContable, String> cuentas = new EnumMap<>(ConceptoContable.class);
+
+    // Constructor vacío requerido por JPA
+    protected PlanCuentasContableEntity() {
+    }
+
+    public PlanCuentasContableEntity(UUID localId, Map<ConceptoContable, String> cuentas) {
+        this.localId = localId;
+        this.cuentas = cuentas;
+    }
+
+    // Getters
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public Map<ConceptoContable, String> getCuentas() {
+        return cuentas;
+    }
+}
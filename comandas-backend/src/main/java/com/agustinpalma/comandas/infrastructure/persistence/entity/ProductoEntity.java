@@ -1,7 +1,9 @@
 package com.agustinpalma.comandas.infrastructure.persistence.entity;
 
+import com.agustinpalma.comandas.domain.model.DomainEnums.Alergeno;
 import jakarta.persistence.*;
 import java.math.BigDecimal;
+import java.time.LocalTime;
 import java.util.UUID;
 
 /**
@@ -61,6 +63,44 @@ public class ProductoEntity {
     @Column(name = "controla_stock", nullable = false)
     private boolean controlaStock = false;
 
+    // HU-101: Alertas de stock mínimo
+    @Column(name = "stock_minimo")
+    private Integer stockMinimo;
+
+    // HU-125: Producto 86
+    @Column(name = "agotado", nullable = false)
+    private boolean agotado = false;
+
+    // HU-161: Horario propio (ambos null = rige el de la categoría)
+    @Column(name = "horario_desde")
+    private LocalTime horarioDesde;
+
+    @Column(name = "horario_hasta")
+    private LocalTime horarioHasta;
+
+    // HU-109: Variantes por tamaño
+    @Column(name = "etiqueta_variante", length = 30)
+    private String etiquetaVariante;
+
+    // HU-103: Combos (vacía si el producto no es combo)
+    @ElementCollection
+    @CollectionTable(
+        name = "productos_combo_opciones",
+        joinColumns = @JoinColumn(name = "producto_id")
+    )
+    @OrderColumn(name = "posicion")
+    private java.util.List<ComboOpcionEmbeddable> opcionesCombo = new java.util.ArrayList<>();
+
+    // HU-147: Alérgenos del producto
+    @ElementCollection
+    @CollectionTable(
+        name = "productos_alergenos",
+        joinColumns = @JoinColumn(name = "producto_id")
+    )
+    @Enumerated(EnumType.STRING)
+    @Column(name = "alergeno", nullable = false, length = 20)
+    private java.util.Set<Alergeno> alergenos = new java.util.HashSet<>();
+
     // Constructor vacío para JPA
     public ProductoEntity() {}
 
@@ -207,4 +247,60 @@ public class ProductoEntity {
     public void setControlaStock(boolean controlaStock) {
         this.controlaStock = controlaStock;
     }
+
+    public Integer getStockMinimo() {
+        return stockMinimo;
+    }
+
+    public void setStockMinimo(Integer stockMinimo) {
+        this.stockMinimo = stockMinimo;
+    }
+
+    public boolean isAgotado() {
+        return agotado;
+    }
+
+    public void setAgotado(boolean agotado) {
+        this.agotado = agotado;
+    }
+
+    public LocalTime getHorarioDesde() {
+        return horarioDesde;
+    }
+
+    public void setHorarioDesde(LocalTime horarioDesde) {
+        this.horarioDesde = horarioDesde;
+    }
+
+    public LocalTime getHorarioHasta() {
+        return horarioHasta;
+    }
+
+    public void setHorarioHasta(LocalTime horarioHasta) {
+        this.horarioHasta = horarioHasta;
+    }
+
+    public String getEtiquetaVariante() {
+        return etiquetaVariante;
+    }
+
+    public void setEtiquetaVariante(String etiquetaVariante) {
+        this.etiquetaVariante = etiquetaVariante;
+    }
+
+    public java.util.List<ComboOpcionEmbeddable> getOpcionesCombo() {
+        return opcionesCombo;
+    }
+
+    public void setOpcionesCombo(java.util.List<ComboOpcionEmbeddable> opcionesCombo) {
+        this.opcionesCombo = opcionesCombo;
+    }
+
+    public java.util.Set<Alergeno> getAlergenos() {
+        return alergenos;
+    }
+
+    public void setAlergenos(java.util.Set<Alergeno> alergenos) {
+        this.alergenos = alergenos;
+    }
 }
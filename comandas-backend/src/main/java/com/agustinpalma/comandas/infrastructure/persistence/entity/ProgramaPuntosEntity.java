@@ -0,0 +1,56 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * JPA entity for the per-local loyalty points program configuration.
+ * HU-105: Customer loyalty points.
+ */
+@Entity
+@Table(name = "programas_puntos")
+public class ProgramaPuntosEntity {
+
+    @Id
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "activo", nullable = false)
+    private boolean activo;
+
+    @Column(name = "monto_por_punto", nullable = false, precision = 10, scale = 2)
+    private BigDecimal montoPorPunto;
+
+    @Column(name = "valor_punto", nullable = false, precision = 10, scale = 2)
+    private BigDecimal valorPunto;
+
+    @Column(name = "dias_vencimiento")
+    private Integer diasVencimiento;
+
+    public ProgramaPuntosEntity() {}
+
+    public ProgramaPuntosEntity(UUID localId, boolean activo, BigDecimal montoPorPunto,
+                                BigDecimal valorPunto, Integer diasVencimiento) {
+        this.localId = localId;
+        this.activo = activo;
+        this.montoPorPunto = montoPorPunto;
+        this.valorPunto = valorPunto;
+        this.diasVencimiento = diasVencimiento;
+    }
+
+    public UUID getLocalId() { return localId; }
+    public void setLocalId(UUID localId) { this.localId = localId; }
+
+    public boolean isActivo() { return activo; }
+    public void setActivo(boolean activo) { this.activo = activo; }
+
+    public BigDecimal getMontoPorPunto() { return montoPorPunto; }
+    public void setMontoPorPunto(BigDecimal montoPorPunto) { this.montoPorPunto = montoPorPunto; }
+
+    public BigDecimal getValorPunto() { return valorPunto; }
+    public void setValorPunto(BigDecimal valorPunto) { this.valorPunto = valorPunto; }
+
+    public Integer getDiasVencimiento() { return diasVencimiento; }
+    public void setDiasVencimiento(Integer diasVencimiento) { this.diasVencimiento = diasVencimiento; }
+}
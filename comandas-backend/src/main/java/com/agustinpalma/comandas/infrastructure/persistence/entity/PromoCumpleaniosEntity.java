@@ -0,0 +1,48 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * JPA entity for the per-local birthday promo configuration.
+ * HU-168: Customer profile and birthday promo.
+ */
+@Entity
+@Table(name = "promos_cumpleanios")
+public class PromoCumpleaniosEntity {
+
+    @Id
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "activa", nullable = false)
+    private boolean activa;
+
+    @Column(name = "porcentaje", nullable = false, precision = 5, scale = 2)
+    private BigDecimal porcentaje;
+
+    @Column(name = "tope_descuento", precision = 10, scale = 2)
+    private BigDecimal topeDescuento;
+
+    public PromoCumpleaniosEntity() {}
+
+    public PromoCumpleaniosEntity(UUID localId, boolean activa, BigDecimal porcentaje, BigDecimal topeDescuento) {
+        this.localId = localId;
+        this.activa = activa;
+        this.porcentaje = porcentaje;
+        this.topeDescuento = topeDescuento;
+    }
+
+    public UUID getLocalId() { return localId; }
+    public void setLocalId(UUID localId) { this.localId = localId; }
+
+    public boolean isActiva() { return activa; }
+    public void setActiva(boolean activa) { this.activa = activa; }
+
+    public BigDecimal getPorcentaje() { return porcentaje; }
+    public void setPorcentaje(BigDecimal porcentaje) { this.porcentaje = porcentaje; }
+
+    public BigDecimal getTopeDescuento() { return topeDescuento; }
+    public void setTopeDescuento(BigDecimal topeDescuento) { this.topeDescuento = topeDescuento; }
+}
@@ -0,0 +1,93 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.util.UUID;
+
+/**
+ * Entidad JPA para Proveedor.
+ * Representa la tabla proveedores en la base de datos.
+ *
+ * HU-131: Proveedores con cuenta corriente.
+ */
+@Entity
+@Table(name = "proveedores", indexes = {
+    @Index(name = "idx_proveedores_local", columnList = "local_id")
+})
+public class ProveedorEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "nombre", nullable = false, length = 100)
+    private String nombre;
+
+    @Column(name = "cuit", length = 11)
+    private String cuit;
+
+    @Column(name = "telefono", length = 30)
+    private String telefono;
+
+    @Column(name = "email", length = 100)
+    private String email;
+
+    @Column(name = "plazo_pago_dias", nullable = false)
+    private int plazoPagoDias;
+
+    @Column(name = "activo", nullable = false)
+    private boolean activo;
+
+    // Constructor vacío requerido por JPA
+    protected ProveedorEntity() {
+    }
+
+    public ProveedorEntity(UUID id, UUID localId, String nombre, String cuit, String telefono, String email,
+                           int plazoPagoDias, boolean activo) {
+        this.id = id;
+        this.localId = localId;
+        this.nombre = nombre;
+        this.cuit = cuit;
+        this.telefono = telefono;
+        this.email = email;
+        this.plazoPagoDias = plazoPagoDias;
+        this.activo = activo;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public String getCuit() {
+        return cuit;
+    }
+
+    public String getTelefono() {
+        return telefono;
+    }
+
+    public String getEmail() {
+        return email;
+    }
+
+    public int getPlazoPagoDias() {
+        return plazoPagoDias;
+    }
+
+    public boolean isActivo() {
+        return activo;
+    }
+}
@@ -0,0 +1,53 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.util.EnumMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para RecargosMedioPago, una por local.
+ * Representa la tabla recargos_medio_pago en la base de datos.
+ *
+ * HU-143: Recargo configurable por medio de pago.
+ */
+@Entity
+@Table(name = "recargos_medio_pago")
+public class RecargosMedioPagoEntity {
+
+    @Id
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "recargos_medio_pago_porcentajes",
+        joinColumns = @JoinColumn(name = "local_id")
+    )
+    @MapKeyEnumerated(EnumType.STRING)
+    @MapKeyColumn(name = "medio_pago", length = 20)
+    @Column(name = "porcentaje", nullable = false, precision = 5, scale = 2)
+    private Map<MedioPago, BigDecimal> porcentajes = new EnumMap<>(MedioPago.class);
+
+    // Constructor vacío requerido por JPA
+    protected RecargosMedioPagoEntity() {
+    }
+
+    public RecargosMedioPagoEntity(UUID localId, Map<MedioPago, BigDecimal> porcentajes) {
+        this.localId = localId;
+        this.porcentajes = porcentajes;
+    }
+
+    // Getters
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public Map<MedioPago, BigDecimal> getPorcentajes() {
+        return porcentajes;
+    }
+}
@@ -0,0 +1,61 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.util.ArrayList;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para Receta.
+ * Representa la tabla recetas en la base de datos.
+ *
+ * HU-118: Una receta por producto, por eso la clave es el producto_id.
+ * Los ingredientes viven en recetas_ingredientes.
+ */
+@Entity
+@Table(name = "recetas",
+    indexes = {
+        @Index(name = "idx_recetas_local", columnList = "local_id")
+    }
+)
+public class RecetaEntity {
+
+    @Id
+    @Column(name = "producto_id", nullable = false, updatable = false)
+    private UUID productoId;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "recetas_ingredientes",
+        joinColumns = @JoinColumn(name = "producto_id")
+    )
+    private List<IngredienteRecetaEmbeddable> ingredientes = new ArrayList<>();
+
+    // Constructor vacío requerido por JPA
+    protected RecetaEntity() {
+    }
+
+    public RecetaEntity(UUID productoId, UUID localId, List<IngredienteRecetaEmbeddable> ingredientes) {
+        this.productoId = productoId;
+        this.localId = localId;
+        this.ingredientes = new ArrayList<>(ingredientes);
+    }
+
+    // Getters
+
+    public UUID getProductoId() {
+        return productoId;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public List<IngredienteRecetaEmbeddable> getIngredientes() {
+        return ingredientes;
+    }
+}
@@ -0,0 +1,53 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ModoRedondeo;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para RedondeoEfectivo, una por local.
+ * Representa la tabla redondeo_efectivo en la base de datos.
+ *
+ * HU-152: Reglas de redondeo configurables en el total.
+ */
+@Entity
+@Table(name = "redondeo_efectivo")
+public class RedondeoEfectivoEntity {
+
+    @Id
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "multiplo", precision = 10, scale = 2)
+    private BigDecimal multiplo;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "modo", nullable = false, length = 10)
+    private ModoRedondeo modo;
+
+    // Constructor vacío requerido por JPA
+    protected RedondeoEfectivoEntity() {
+    }
+
+    public RedondeoEfectivoEntity(UUID localId, BigDecimal multiplo, ModoRedondeo modo) {
+        this.localId = localId;
+        this.multiplo = multiplo;
+        this.modo = modo;
+    }
+
+    // Getters
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public BigDecimal getMultiplo() {
+        return multiplo;
+    }
+
+    public ModoRedondeo getModo() {
+        return modo;
+    }
+}
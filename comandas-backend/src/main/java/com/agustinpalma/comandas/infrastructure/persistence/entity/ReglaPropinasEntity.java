@@ -0,0 +1,62 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.ModoRepartoPropinas;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import jakarta.persistence.*;
+
+import java.util.EnumMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para ReglaPropinas, una por local.
+ * Representa la tabla reglas_propinas en la base de datos.
+ *
+ * HU-141: Pozo de propinas y distribución.
+ */
+@Entity
+@Table(name = "reglas_propinas")
+public class ReglaPropinasEntity {
+
+    @Id
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "modo", nullable = false, length = 20)
+    private ModoRepartoPropinas modo;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "reglas_propinas_puntos",
+        joinColumns = @JoinColumn(name = "local_id")
+    )
+    @MapKeyEnumerated(EnumType.STRING)
+    @MapKeyColumn(name = "rol", length = 20)
+    @Column(name = "puntos", nullable = false)
+    private Map<RolPersonal, Integer> puntosPorRol = new EnumMap<>(RolPersonal.class);
+
+    // Constructor vacío requerido por JPA
+    protected ReglaPropinasEntity() {
+    }
+
+    public ReglaPropinasEntity(UUID localId, ModoRepartoPropinas modo, Map<RolPersonal, Integer> puntosPorRol) {
+        this.localId = localId;
+        this.modo = modo;
+        this.puntosPorRol = puntosPorRol;
+    }
+
+    // Getters
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public ModoRepartoPropinas getModo() {
+        return modo;
+    }
+
+    public Map<RolPersonal, Integer> getPuntosPorRol() {
+        return puntosPorRol;
+    }
+}
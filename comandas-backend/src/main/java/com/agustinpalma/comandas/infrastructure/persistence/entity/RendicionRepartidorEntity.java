@@ -0,0 +1,107 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para RendicionRepartidor.
+ * Representa la tabla rendiciones_repartidor en la base de datos.
+ *
+ * HU-140: Efectivo rendido por un repartidor al final del turno, una por turno.
+ */
+@Entity
+@Table(name = "rendiciones_repartidor",
+    uniqueConstraints = @UniqueConstraint(
+        name = "uk_rendicion_repartidor_turno",
+        columnNames = {"turno_id", "repartidor_id"}
+    ),
+    indexes = @Index(name = "idx_rendiciones_repartidor_local_turno", columnList = "local_id, turno_id")
+)
+public class RendicionRepartidorEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "turno_id", nullable = false)
+    private UUID turnoId;
+
+    @Column(name = "repartidor_id", nullable = false)
+    private UUID repartidorId;
+
+    @Column(name = "fecha", nullable = false)
+    private LocalDateTime fecha;
+
+    @Column(name = "pedidos", nullable = false)
+    private int pedidos;
+
+    @Column(name = "esperado", nullable = false, precision = 10, scale = 2)
+    private BigDecimal esperado;
+
+    @Column(name = "rendido", nullable = false, precision = 10, scale = 2)
+    private BigDecimal rendido;
+
+    @Column(name = "observaciones", length = 200)
+    private String observaciones;
+
+    // Constructor vacío requerido por JPA
+    protected RendicionRepartidorEntity() {
+    }
+
+    public RendicionRepartidorEntity(UUID id, UUID localId, UUID turnoId, UUID repartidorId, LocalDateTime fecha,
+                                     int pedidos, BigDecimal esperado, BigDecimal rendido, String observaciones) {
+        this.id = id;
+        this.localId = localId;
+        this.turnoId = turnoId;
+        this.repartidorId = repartidorId;
+        this.fecha = fecha;
+        this.pedidos = pedidos;
+        this.esperado = esperado;
+        this.rendido = rendido;
+        this.observaciones = observaciones;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getTurnoId() {
+        return turnoId;
+    }
+
+    public UUID getRepartidorId() {
+        return repartidorId;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public int getPedidos() {
+        return pedidos;
+    }
+
+    public BigDecimal getEsperado() {
+        return esperado;
+    }
+
+    public BigDecimal getRendido() {
+        return rendido;
+    }
+
+    public String getObservaciones() {
+        return observaciones;
+    }
+}
@@ -0,0 +1,65 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+import jakarta.persistence.EnumType;
+import jakarta.persistence.Enumerated;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * Embeddable para los insumos perdidos en una merma (HU-135).
+ */
+@Embeddable
+public class RenglonMermaEmbeddable {
+
+    @Column(name = "insumo_id", nullable = false)
+    private UUID insumoId;
+
+    @Column(name = "nombre_insumo", nullable = false, length = 60)
+    private String nombreInsumo;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "unidad", nullable = false, length = 20)
+    private UnidadInsumo unidad;
+
+    @Column(name = "cantidad", nullable = false, precision = 12, scale = 3)
+    private BigDecimal cantidad;
+
+    @Column(name = "costo_unitario", nullable = false, precision = 12, scale = 4)
+    private BigDecimal costoUnitario;
+
+    // Constructor vacío para JPA
+    public RenglonMermaEmbeddable() {}
+
+    public RenglonMermaEmbeddable(UUID insumoId, String nombreInsumo, UnidadInsumo unidad, BigDecimal cantidad,
+                                  BigDecimal costoUnitario) {
+        this.insumoId = insumoId;
+        this.nombreInsumo = nombreInsumo;
+        this.unidad = unidad;
+        this.cantidad = cantidad;
+        this.costoUnitario = costoUnitario;
+    }
+
+    public UUID getInsumoId() {
+        return insumoId;
+    }
+
+    public String getNombreInsumo() {
+        return nombreInsumo;
+    }
+
+    public UnidadInsumo getUnidad() {
+        return unidad;
+    }
+
+    public BigDecimal getCantidad() {
+        return cantidad;
+    }
+
+    public BigDecimal getCostoUnitario() {
+        return costoUnitario;
+    }
+}
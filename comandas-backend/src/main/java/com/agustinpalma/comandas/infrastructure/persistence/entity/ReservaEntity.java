@@ -0,0 +1,141 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoReserva;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para Reserva.
+ * Representa la tabla reservas en la base de datos.
+ *
+ * HU-142: Reserva de mesa con la seña cobrada y el pedido en el que se descontó.
+ */
+@Entity
+@Table(name = "reservas",
+    indexes = {
+        @Index(name = "idx_reservas_local_fecha", columnList = "local_id, fecha_hora"),
+        @Index(name = "idx_reservas_local_mesa_estado", columnList = "local_id, mesa_id, estado")
+    }
+)
+public class ReservaEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "nombre_cliente", nullable = false, length = 80)
+    private String nombreCliente;
+
+    @Column(name = "telefono", length = 30)
+    private String telefono;
+
+    @Column(name = "fecha_hora", nullable = false)
+    private LocalDateTime fechaHora;
+
+    @Column(name = "personas", nullable = false)
+    private int personas;
+
+    @Column(name = "observaciones", length = 200)
+    private String observaciones;
+
+    @Column(name = "mesa_id")
+    private UUID mesaId;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "estado", nullable = false, length = 20)
+    private EstadoReserva estado;
+
+    @Column(name = "monto_senia", nullable = false, precision = 10, scale = 2)
+    private BigDecimal montoSenia;
+
+    @Column(name = "fecha_senia")
+    private LocalDateTime fechaSenia;
+
+    @Column(name = "movimiento_senia_id")
+    private UUID movimientoSeniaId;
+
+    @Column(name = "pedido_id")
+    private UUID pedidoId;
+
+    // Constructor vacío requerido por JPA
+    protected ReservaEntity() {
+    }
+
+    public ReservaEntity(UUID id, UUID localId, String nombreCliente, String telefono, LocalDateTime fechaHora,
+                         int personas, String observaciones, UUID mesaId, EstadoReserva estado,
+                         BigDecimal montoSenia, LocalDateTime fechaSenia, UUID movimientoSeniaId, UUID pedidoId) {
+        this.id = id;
+        this.localId = localId;
+        this.nombreCliente = nombreCliente;
+        this.telefono = telefono;
+        this.fechaHora = fechaHora;
+        this.personas = personas;
+        this.observaciones = observaciones;
+        this.mesaId = mesaId;
+        this.estado = estado;
+        this.montoSenia = montoSenia;
+        this.fechaSenia = fechaSenia;
+        this.movimientoSeniaId = movimientoSeniaId;
+        this.pedidoId = pedidoId;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public String getNombreCliente() {
+        return nombreCliente;
+    }
+
+    public String getTelefono() {
+        return telefono;
+    }
+
+    public LocalDateTime getFechaHora() {
+        return fechaHora;
+    }
+
+    public int getPersonas() {
+        return personas;
+    }
+
+    public String getObservaciones() {
+        return observaciones;
+    }
+
+    public UUID getMesaId() {
+        return mesaId;
+    }
+
+    public EstadoReserva getEstado() {
+        return estado;
+    }
+
+    public BigDecimal getMontoSenia() {
+        return montoSenia;
+    }
+
+    public LocalDateTime getFechaSenia() {
+        return fechaSenia;
+    }
+
+    public UUID getMovimientoSeniaId() {
+        return movimientoSeniaId;
+    }
+
+    public UUID getPedidoId() {
+        return pedidoId;
+    }
+}
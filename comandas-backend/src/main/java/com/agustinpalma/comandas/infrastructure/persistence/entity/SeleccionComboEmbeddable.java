@@ -0,0 +1,71 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Embeddable;
+
+import java.math.BigDecimal;
+import java.util.UUID;
+
+/**
+ * Embeddable para las opciones de combo elegidas en un item de pedido (HU-103).
+ * Almacena el snapshot de cada componente en el momento de agregarse al pedido.
+ */
+@Embeddable
+public class SeleccionComboEmbeddable {
+
+    @Column(name = "componente", nullable = false, length = 100)
+    private String componente;
+
+    @Column(name = "producto_id", nullable = false)
+    private UUID productoId;
+
+    @Column(name = "nombre_producto", nullable = false, length = 100)
+    private String nombreProducto;
+
+    @Column(name = "precio_referencia", nullable = false, precision = 10, scale = 2)
+    private BigDecimal precioReferencia;
+
+    // Constructor vacío para JPA
+    public SeleccionComboEmbeddable() {}
+
+    // Constructor con parámetros
+    public SeleccionComboEmbeddable(String componente, UUID productoId, String nombreProducto, BigDecimal precioReferencia) {
+        this.componente = componente;
+        this.productoId = productoId;
+        this.nombreProducto = nombreProducto;
+        this.precioReferencia = precioReferencia;
+    }
+
+    // Getters y setters
+    public String getComponente() {
+        return componente;
+    }
+
+    public void setComponente(String componente) {
+        this.componente = componente;
+    }
+
+    public UUID getProductoId() {
+        return productoId;
+    }
+
+    public void setProductoId(UUID productoId) {
+        this.productoId = productoId;
+    }
+
+    public String getNombreProducto() {
+        return nombreProducto;
+    }
+
+    public void setNombreProducto(String nombreProducto) {
+        this.nombreProducto = nombreProducto;
+    }
+
+    public BigDecimal getPrecioReferencia() {
+        return precioReferencia;
+    }
+
+    public void setPrecioReferencia(BigDecimal precioReferencia) {
+        this.precioReferencia = precioReferencia;
+    }
+}
@@ -0,0 +1,96 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.util.UUID;
+
+/**
+ * Entidad JPA para SerieComprobantes.
+ * Representa la tabla series_comprobantes en la base de datos.
+ *
+ * HU-166: Serie de numeración de cada caja. El id es solo técnico: la serie
+ * se identifica por (local_id, caja_id), con caja_id null para la principal.
+ */
+@Entity
+@Table(name = "series_comprobantes",
+    indexes = @Index(name = "idx_series_comprobantes_local", columnList = "local_id"))
+public class SerieComprobantesEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "caja_id")
+    private UUID cajaId;
+
+    @Column(name = "prefijo", nullable = false, length = 8)
+    private String prefijo;
+
+    @Column(name = "proximo_numero", nullable = false)
+    private long proximoNumero;
+
+    @Column(name = "reinicio_anual", nullable = false)
+    private boolean reinicioAnual;
+
+    @Column(name = "anio_en_curso")
+    private Integer anioEnCurso;
+
+    // Constructor vacío requerido por JPA
+    protected SerieComprobantesEntity() {
+    }
+
+    public SerieComprobantesEntity(UUID id, UUID localId, UUID cajaId) {
+        this.id = id;
+        this.localId = localId;
+        this.cajaId = cajaId;
+    }
+
+    // Getters y Setters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getCajaId() {
+        return cajaId;
+    }
+
+    public String getPrefijo() {
+        return prefijo;
+    }
+
+    public void setPrefijo(String prefijo) {
+        this.prefijo = prefijo;
+    }
+
+    public long getProximoNumero() {
+        return proximoNumero;
+    }
+
+    public void setProximoNumero(long proximoNumero) {
+        this.proximoNumero = proximoNumero;
+    }
+
+    public boolean isReinicioAnual() {
+        return reinicioAnual;
+    }
+
+    public void setReinicioAnual(boolean reinicioAnual) {
+        this.reinicioAnual = reinicioAnual;
+    }
+
+    public Integer getAnioEnCurso() {
+        return anioEnCurso;
+    }
+
+    public void setAnioEnCurso(Integer anioEnCurso) {
+        this.anioEnCurso = anioEnCurso;
+    }
+}
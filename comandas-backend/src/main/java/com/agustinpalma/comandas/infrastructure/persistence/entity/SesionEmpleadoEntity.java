@@ -0,0 +1,60 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.time.LocalDateTime;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para SesionEmpleado.
+ * Representa la tabla sesiones_empleado en la base de datos.
+ *
+ * HU-169: Sesiones de los empleados identificados con su PIN.
+ */
+@Entity
+@Table(
+    name = "sesiones_empleado",
+    indexes = @Index(name = "idx_sesiones_empleado_vence", columnList = "vence_en")
+)
+public class SesionEmpleadoEntity {
+
+    @Id
+    @Column(name = "hash_token", nullable = false, length = 64)
+    private String hashToken;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "empleado_id", nullable = false)
+    private UUID empleadoId;
+
+    @Column(name = "vence_en", nullable = false)
+    private LocalDateTime venceEn;
+
+    // Constructor vacío requerido por JPA
+    protected SesionEmpleadoEntity() {
+    }
+
+    public SesionEmpleadoEntity(String hashToken, UUID localId, UUID empleadoId, LocalDateTime venceEn) {
+        this.hashToken = hashToken;
+        this.localId = localId;
+        this.empleadoId = empleadoId;
+        this.venceEn = venceEn;
+    }
+
+    public String getHashToken() {
+        return hashToken;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getEmpleadoId() {
+        return empleadoId;
+    }
+
+    public LocalDateTime getVenceEn() {
+        return venceEn;
+    }
+}
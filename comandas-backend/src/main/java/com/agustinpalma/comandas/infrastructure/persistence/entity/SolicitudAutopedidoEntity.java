@@ -0,0 +1,132 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoSolicitudAutopedido;
+import jakarta.persistence.*;
+
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para SolicitudAutopedido.
+ * Representa la tabla solicitudes_autopedido en la base de datos.
+ *
+ * HU-126: resuelta_en, resuelta_por_id y motivo_rechazo son null mientras
+ * el pedido espera al mozo. Los ítems viven en solicitudes_autopedido_items.
+ */
+@Entity
+@Table(name = "solicitudes_autopedido",
+    indexes = {
+        @Index(name = "idx_solicitudes_autopedido_local_estado", columnList = "local_id, estado, creada_en")
+    }
+)
+public class SolicitudAutopedidoEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "mesa_id", nullable = false)
+    private UUID mesaId;
+
+    @Column(name = "numero_mesa", nullable = false)
+    private int numeroMesa;
+
+    @Column(name = "nombre_cliente", length = 40)
+    private String nombreCliente;
+
+    @Column(name = "creada_en", nullable = false)
+    private LocalDateTime creadaEn;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "estado", nullable = false, length = 20)
+    private EstadoSolicitudAutopedido estado;
+
+    @Column(name = "resuelta_en")
+    private LocalDateTime resueltaEn;
+
+    @Column(name = "resuelta_por_id")
+    private UUID resueltaPorId;
+
+    @Column(name = "motivo_rechazo", length = 140)
+    private String motivoRechazo;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "solicitudes_autopedido_items",
+        joinColumns = @JoinColumn(name = "solicitud_id")
+    )
+    @OrderColumn(name = "orden")
+    private List<ItemSolicitudAutopedidoEmbeddable> items = new ArrayList<>();
+
+    // Constructor vacío requerido por JPA
+    protected SolicitudAutopedidoEntity() {
+    }
+
+    public SolicitudAutopedidoEntity(UUID id, UUID localId, UUID mesaId, int numeroMesa, String nombreCliente,
+                                     LocalDateTime creadaEn, EstadoSolicitudAutopedido estado,
+                                     LocalDateTime resueltaEn, UUID resueltaPorId, String motivoRechazo,
+                                     List<ItemSolicitudAutopedidoEmbeddable> items) {
+        this.id = id;
+        this.localId = localId;
+        this.mesaId = mesaId;
+        this.numeroMesa = numeroMesa;
+        this.nombreCliente = nombreCliente;
+        this.creadaEn = creadaEn;
+        this.estado = estado;
+        this.resueltaEn = resueltaEn;
+        this.resueltaPorId = resueltaPorId;
+        this.motivoRechazo = motivoRechazo;
+        this.items = new ArrayList<>(items);
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getMesaId() {
+        return mesaId;
+    }
+
+    public int getNumeroMesa() {
+        return numeroMesa;
+    }
+
+    public String getNombreCliente() {
+        return nombreCliente;
+    }
+
+    public LocalDateTime getCreadaEn() {
+        return creadaEn;
+    }
+
+    public EstadoSolicitudAutopedido getEstado() {
+        return estado;
+    }
+
+    public LocalDateTime getResueltaEn() {
+        return resueltaEn;
+    }
+
+    public UUID getResueltaPorId() {
+        return resueltaPorId;
+    }
+
+    public String getMotivoRechazo() {
+        return motivoRechazo;
+    }
+
+    public List<ItemSolicitudAutopedidoEmbeddable> getItems() {
+        return items;
+    }
+}
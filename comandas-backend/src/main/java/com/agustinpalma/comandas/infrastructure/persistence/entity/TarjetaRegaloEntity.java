@@ -0,0 +1,121 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para TarjetaRegalo.
+ * Representa la tabla tarjetas_regalo en la base de datos.
+ *
+ * HU-144: El código es único dentro del local.
+ */
+@Entity
+@Table(name = "tarjetas_regalo",
+    uniqueConstraints = @UniqueConstraint(name = "uk_tarjetas_regalo_local_codigo", columnNames = {"local_id", "codigo"})
+)
+public class TarjetaRegaloEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "codigo", nullable = false, length = 10)
+    private String codigo;
+
+    @Column(name = "monto_inicial", nullable = false, precision = 10, scale = 2)
+    private BigDecimal montoInicial;
+
+    @Column(name = "fecha_emision", nullable = false)
+    private LocalDateTime fechaEmision;
+
+    @Column(name = "vencimiento", nullable = false)
+    private LocalDate vencimiento;
+
+    @Column(name = "comprador", length = 80)
+    private String comprador;
+
+    @Column(name = "destinatario", length = 80)
+    private String destinatario;
+
+    @Column(name = "movimiento_caja_id")
+    private UUID movimientoCajaId;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "tarjetas_regalo_consumos",
+        joinColumns = @JoinColumn(name = "tarjeta_id")
+    )
+    @OrderColumn(name = "orden")
+    private List<ConsumoTarjetaRegaloEmbeddable> consumos = new ArrayList<>();
+
+    // Constructor vacío requerido por JPA
+    protected TarjetaRegaloEntity() {
+    }
+
+    public TarjetaRegaloEntity(UUID id, UUID localId, String codigo, BigDecimal montoInicial,
+                               LocalDateTime fechaEmision, LocalDate vencimiento, String comprador,
+                               String destinatario, UUID movimientoCajaId,
+                               List<ConsumoTarjetaRegaloEmbeddable> consumos) {
+        this.id = id;
+        this.localId = localId;
+        this.codigo = codigo;
+        this.montoInicial = montoInicial;
+        this.fechaEmision = fechaEmision;
+        this.vencimiento = vencimiento;
+        this.comprador = comprador;
+        this.destinatario = destinatario;
+        this.movimientoCajaId = movimientoCajaId;
+        this.consumos = consumos;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public String getCodigo() {
+        return codigo;
+    }
+
+    public BigDecimal getMontoInicial() {
+        return montoInicial;
+    }
+
+    public LocalDateTime getFechaEmision() {
+        return fechaEmision;
+    }
+
+    public LocalDate getVencimiento() {
+        return vencimiento;
+    }
+
+    public String getComprador() {
+        return comprador;
+    }
+
+    public String getDestinatario() {
+        return destinatario;
+    }
+
+    public UUID getMovimientoCajaId() {
+        return movimientoCajaId;
+    }
+
+    public List<ConsumoTarjetaRegaloEmbeddable> getConsumos() {
+        return consumos;
+    }
+}
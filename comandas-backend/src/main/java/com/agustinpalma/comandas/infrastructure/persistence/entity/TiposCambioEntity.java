@@ -0,0 +1,75 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.Moneda;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.util.EnumMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para TiposCambio, una por local y día operativo.
+ * Representa la tabla tipos_cambio en la base de datos.
+ *
+ * HU-151: Cobros en moneda extranjera.
+ */
+@Entity
+@Table(name = "tipos_cambio",
+    uniqueConstraints = @UniqueConstraint(
+        name = "uk_tipos_cambio_local_fecha",
+        columnNames = {"local_id", "fecha"}
+    )
+)
+public class TiposCambioEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "fecha", nullable = false)
+    private LocalDate fecha;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "tipos_cambio_cotizaciones",
+        joinColumns = @JoinColumn(name = "tipos_cambio_id")
+    )
+    @MapKeyEnumerated(EnumType.STRING)
+    @MapKeyColumn(name = "moneda", length = 3)
+    @Column(name = "cotizacion", nullable = false, precision = 12, scale = 4)
+    private Map<Moneda, BigDecimal> cotizaciones = new EnumMap<>(Moneda.class);
+
+    // Constructor vacío requerido por JPA
+    protected TiposCambioEntity() {
+    }
+
+    public TiposCambioEntity(UUID id, UUID localId, LocalDate fecha, Map<Moneda, BigDecimal> cotizaciones) {
+        this.id = id;
+        this.localId = localId;
+        this.fecha = fecha;
+        this.cotizaciones = cotizaciones;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public LocalDate getFecha() {
+        return fecha;
+    }
+
+    public Map<Moneda, BigDecimal> getCotizaciones() {
+        return cotizaciones;
+    }
+}
@@ -0,0 +1,79 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para TomaInventario.
+ * Representa la tabla tomas_inventario en la base de datos.
+ *
+ * HU-133: Los conteos viven en tomas_inventario_conteos con la foto del
+ * insumo al momento de contar, para que el reporte no cambie después.
+ */
+@Entity
+@Table(name = "tomas_inventario",
+    indexes = {
+        @Index(name = "idx_tomas_inventario_local_fecha", columnList = "local_id, fecha")
+    }
+)
+public class TomaInventarioEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "fecha", nullable = false)
+    private LocalDateTime fecha;
+
+    @Column(name = "responsable", nullable = false, length = 60)
+    private String responsable;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "tomas_inventario_conteos",
+        joinColumns = @JoinColumn(name = "toma_id")
+    )
+    private List<ConteoInsumoEmbeddable> conteos = new ArrayList<>();
+
+    // Constructor vacío requerido por JPA
+    protected TomaInventarioEntity() {
+    }
+
+    public TomaInventarioEntity(UUID id, UUID localId, LocalDateTime fecha, String responsable,
+                                List<ConteoInsumoEmbeddable> conteos) {
+        this.id = id;
+        this.localId = localId;
+        this.fecha = fecha;
+        this.responsable = responsable;
+        this.conteos.addAll(conteos);
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public LocalDateTime getFecha() {
+        return fecha;
+    }
+
+    public String getResponsable() {
+        return responsable;
+    }
+
+    public List<ConteoInsumoEmbeddable> getConteos() {
+        return conteos;
+    }
+}
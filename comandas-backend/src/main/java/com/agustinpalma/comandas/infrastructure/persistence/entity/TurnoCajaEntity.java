@@ -0,0 +1,206 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoTurno;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoTurno;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para TurnoCaja.
+ * Representa la tabla turnos_caja en la base de datos.
+ *
+ * HU-106: Cada registro es un turno dentro de una jornada. Los campos de
+ * arqueo quedan en null mientras el turno está ABIERTO.
+ * La combinación (jornada_id, tipo) es única.
+ *
+ * HU-123: El conteo por denominación del cierre va en arqueo_turno_denominaciones.
+ * HU-129: El responsable y las observaciones del arqueo alimentan el
+ * histórico de diferencias por cajero.
+ */
+@Entity
+@Table(name = "turnos_caja",
+    uniqueConstraints = @UniqueConstraint(
+        name = "uk_turno_jornada_tipo",
+        columnNames = {"jornada_id", "tipo"}
+    ),
+    indexes = {
+        @Index(name = "idx_turno_local_estado", columnList = "local_id, estado"),
+        @Index(name = "idx_turno_local_cierre", columnList = "local_id, fecha_cierre")
+    }
+)
+public class TurnoCajaEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "jornada_id", nullable = false)
+    private UUID jornadaId;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "tipo", nullable = false, length = 20)
+    private TipoTurno tipo;
+
+    @Column(name = "monto_inicial", nullable = false, precision = 12, scale = 2)
+    private BigDecimal montoInicial;
+
+    @Column(name = "fecha_apertura", nullable = false)
+    private LocalDateTime fechaApertura;
+
+    @Column(name = "fecha_cierre")
+    private LocalDateTime fechaCierre;
+
+    @Column(name = "total_ventas", precision = 12, scale = 2)
+    private BigDecimal totalVentas;
+
+    @Column(name = "ventas_efectivo", precision = 12, scale = 2)
+    private BigDecimal ventasEfectivo;
+
+    @Column(name = "total_ingresos", precision = 12, scale = 2)
+    private BigDecimal totalIngresos;
+
+    @Column(name = "total_egresos", precision = 12, scale = 2)
+    private BigDecimal totalEgresos;
+
+    @Column(name = "efectivo_esperado", precision = 12, scale = 2)
+    private BigDecimal efectivoEsperado;
+
+    @Column(name = "efectivo_declarado", precision = 12, scale = 2)
+    private BigDecimal efectivoDeclarado;
+
+    @Column(name = "pedidos_cerrados_count", nullable = false)
+    private int pedidosCerradosCount;
+
+    @Enumerated(EnumType.STRING)
+    @Column(name = "estado", nullable = false, length = 20)
+    private EstadoTurno estado;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "arqueo_turno_denominaciones",
+        joinColumns = @JoinColumn(name = "turno_id")
+    )
+    @OrderColumn(name = "orden")
+    private List<ConteoDenominacionEmbeddable> denominaciones = new ArrayList<>();
+
+    @Column(name = "responsable_id")
+    private UUID responsableId;
+
+    @Column(name = "observaciones_cierre", length = 500)
+    private String observaciones;
+
+    // Constructor vacío requerido por JPA
+    protected TurnoCajaEntity() {
+    }
+
+    public TurnoCajaEntity(UUID id, UUID localId, UUID jornadaId, TipoTurno tipo,
+                           BigDecimal montoInicial,
+                           LocalDateTime fechaApertura, LocalDateTime fechaCierre,
+                           BigDecimal totalVentas, BigDecimal ventasEfectivo,
+                           BigDecimal totalIngresos, BigDecimal totalEgresos,
+                           BigDecimal efectivoEsperado, BigDecimal efectivoDeclarado,
+                           int pedidosCerradosCount, EstadoTurno estado,
+                           List<ConteoDenominacionEmbeddable> denominaciones,
+                           UUID responsableId, String observaciones) {
+        this.id = id;
+        this.localId = localId;
+        this.jornadaId = jornadaId;
+        this.tipo = tipo;
+        this.montoInicial = montoInicial;
+        this.fechaApertura = fechaApertura;
+        this.fechaCierre = fechaCierre;
+        this.totalVentas = totalVentas;
+        this.ventasEfectivo = ventasEfectivo;
+        this.totalIngresos = totalIngresos;
+        this.totalEgresos = totalEgresos;
+        this.efectivoEsperado = efectivoEsperado;
+        this.efectivoDeclarado = efectivoDeclarado;
+        this.pedidosCerradosCount = pedidosCerradosCount;
+        this.estado = estado;
+        this.denominaciones = new ArrayList<>(denominaciones);
+        this.responsableId = responsableId;
+        this.observaciones = observaciones;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getJornadaId() {
+        return jornadaId;
+    }
+
+    public TipoTurno getTipo() {
+        return tipo;
+    }
+
+    public BigDecimal getMontoInicial() {
+        return montoInicial;
+    }
+
+    public LocalDateTime getFechaApertura() {
+        return fechaApertura;
+    }
+
+    public LocalDateTime getFechaCierre() {
+        return fechaCierre;
+    }
+
+    public BigDecimal getTotalVentas() {
+        return totalVentas;
+    }
+
+    public BigDecimal getVentasEfectivo() {
+        return ventasEfectivo;
+    }
+
+    public BigDecimal getTotalIngresos() {
+        return totalIngresos;
+    }
+
+    public BigDecimal getTotalEgresos() {
+        return totalEgresos;
+    }
+
+    public BigDecimal getEfectivoEsperado() {
+        return efectivoEsperado;
+    }
+
+    public BigDecimal getEfectivoDeclarado() {
+        return efectivoDeclarado;
+    }
+
+    public int getPedidosCerradosCount() {
+        return pedidosCerradosCount;
+    }
+
+    public EstadoTurno getEstado() {
+        return estado;
+    }
+
+    public List<ConteoDenominacionEmbeddable> getDenominaciones() {
+        return denominaciones;
+    }
+
+    public UUID getResponsableId() {
+        return responsableId;
+    }
+
+    public String getObservaciones() {
+        return observaciones;
+    }
+}
@@ -0,0 +1,110 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.EnumMap;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para VentaSucursal.
+ * Representa la tabla ventas_sucursales en la base de datos.
+ *
+ * HU-107: La clave primaria es el UUID del pedido en la sucursal de origen,
+ * de modo que reenviar un lote actualiza la fila en lugar de duplicarla.
+ * Los montos por medio de pago viven en ventas_sucursales_pagos.
+ */
+@Entity
+@Table(name = "ventas_sucursales",
+    indexes = {
+        @Index(name = "idx_venta_sucursal_local_fecha", columnList = "local_id, fecha_cierre")
+    }
+)
+public class VentaSucursalEntity {
+
+    @Id
+    @Column(name = "pedido_origen_id", nullable = false, updatable = false)
+    private UUID pedidoOrigenId;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "sucursal_id", nullable = false)
+    private UUID sucursalId;
+
+    @Column(name = "nombre_sucursal", nullable = false, length = 100)
+    private String nombreSucursal;
+
+    @Column(name = "numero_pedido", nullable = false)
+    private int numeroPedido;
+
+    @Column(name = "fecha_cierre", nullable = false)
+    private LocalDateTime fechaCierre;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "ventas_sucursales_pagos",
+        joinColumns = @JoinColumn(name = "venta_id")
+    )
+    @MapKeyEnumerated(EnumType.STRING)
+    @MapKeyColumn(name = "medio_pago", length = 20)
+    @Column(name = "monto", nullable = false, precision = 12, scale = 2)
+    private Map<MedioPago, BigDecimal> pagos = new EnumMap<>(MedioPago.class);
+
+    @Column(name = "fecha_recepcion", nullable = false)
+    private LocalDateTime fechaRecepcion;
+
+    // Constructor vacío requerido por JPA
+    protected VentaSucursalEntity() {
+    }
+
+    public VentaSucursalEntity(UUID pedidoOrigenId, UUID localId, UUID sucursalId,
+                               String nombreSucursal, int numeroPedido, LocalDateTime fechaCierre,
+                               Map<MedioPago, BigDecimal> pagos, LocalDateTime fechaRecepcion) {
+        this.pedidoOrigenId = pedidoOrigenId;
+        this.localId = localId;
+        this.sucursalId = sucursalId;
+        this.nombreSucursal = nombreSucursal;
+        this.numeroPedido = numeroPedido;
+        this.fechaCierre = fechaCierre;
+        this.pagos.putAll(pagos);
+        this.fechaRecepcion = fechaRecepcion;
+    }
+
+    // Getters
+
+    public UUID getPedidoOrigenId() {
+        return pedidoOrigenId;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public UUID getSucursalId() {
+        return sucursalId;
+    }
+
+    public String getNombreSucursal() {
+        return nombreSucursal;
+    }
+
+    public int getNumeroPedido() {
+        return numeroPedido;
+    }
+
+    public LocalDateTime getFechaCierre() {
+        return fechaCierre;
+    }
+
+    public Map<MedioPago, BigDecimal> getPagos() {
+        return pagos;
+    }
+
+    public LocalDateTime getFechaRecepcion() {
+        return fechaRecepcion;
+    }
+}
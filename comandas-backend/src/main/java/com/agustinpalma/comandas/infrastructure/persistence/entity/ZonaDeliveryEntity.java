@@ -0,0 +1,93 @@
+package com.agustinpalma.comandas.infrastructure.persistence.entity;
+
+import jakarta.persistence.*;
+
+import java.math.BigDecimal;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Entidad JPA para ZonaDelivery.
+ * Representa la tabla zonas_delivery en la base de datos.
+ *
+ * HU-139: Zonas de delivery con costo de envío.
+ */
+@Entity
+@Table(name = "zonas_delivery", indexes = {
+    @Index(name = "idx_zonas_delivery_local", columnList = "local_id")
+})
+public class ZonaDeliveryEntity {
+
+    @Id
+    @Column(name = "id", nullable = false, updatable = false)
+    private UUID id;
+
+    @Column(name = "local_id", nullable = false)
+    private UUID localId;
+
+    @Column(name = "nombre", nullable = false, length = 60)
+    private String nombre;
+
+    @Column(name = "costo_envio", nullable = false, precision = 10, scale = 2)
+    private BigDecimal costoEnvio;
+
+    @Column(name = "monto_minimo", nullable = false, precision = 10, scale = 2)
+    private BigDecimal montoMinimo;
+
+    @ElementCollection(fetch = FetchType.EAGER)
+    @CollectionTable(
+        name = "zonas_delivery_barrios",
+        joinColumns = @JoinColumn(name = "zona_id")
+    )
+    @Column(name = "barrio", nullable = false, length = 60)
+    private List<String> barrios = new ArrayList<>();
+
+    @Column(name = "activa", nullable = false)
+    private boolean activa;
+
+    // Constructor vacío requerido por JPA
+    protected ZonaDeliveryEntity() {
+    }
+
+    public ZonaDeliveryEntity(UUID id, UUID localId, String nombre, BigDecimal costoEnvio, BigDecimal montoMinimo,
+                              List<String> barrios, boolean activa) {
+        this.id = id;
+        this.localId = localId;
+        this.nombre = nombre;
+        this.costoEnvio = costoEnvio;
+        this.montoMinimo = montoMinimo;
+        this.barrios = barrios;
+        this.activa = activa;
+    }
+
+    // Getters
+
+    public UUID getId() {
+        return id;
+    }
+
+    public UUID getLocalId() {
+        return localId;
+    }
+
+    public String getNombre() {
+        return nombre;
+    }
+
+    public BigDecimal getCostoEnvio() {
+        return costoEnvio;
+    }
+
+    public BigDecimal getMontoMinimo() {
+        return montoMinimo;
+    }
+
+    public List<String> getBarrios() {
+        return barrios;
+    }
+
+    public boolean isActiva() {
+        return activa;
+    }
+}
@@ -0,0 +1,15 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.AlertaMesasOlvidadasEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.UUID;
+
+/**
+ * Spring Data JPA repository for the forgotten tables alert configuration.
+ * HU-113: Forgotten tables alert.
+ */
+@Repository
+public interface SpringDataAlertaMesasOlvidadasRepository extends JpaRepository<AlertaMesasOlvidadasEntity, UUID> {
+}
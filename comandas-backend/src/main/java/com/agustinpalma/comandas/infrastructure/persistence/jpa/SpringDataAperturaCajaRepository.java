@@ -0,0 +1,21 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.AperturaCajaEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para las aperturas de caja por jornada.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataAperturaCajaRepository extends JpaRepository<AperturaCajaEntity, UUID> {
+
+    List<AperturaCajaEntity> findByJornadaIdAndLocalIdOrderByFechaAperturaAsc(UUID jornadaId, UUID localId);
+
+    Optional<AperturaCajaEntity> findByJornadaIdAndCajaIdAndLocalId(UUID jornadaId, UUID cajaId, UUID localId);
+}
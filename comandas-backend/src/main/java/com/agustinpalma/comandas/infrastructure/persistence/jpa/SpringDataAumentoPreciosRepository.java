@@ -0,0 +1,26 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoAumentoPrecios;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.AumentoPreciosEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDate;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para AumentoPreciosEntity.
+ * HU-145: Actualización masiva de precios.
+ */
+@Repository
+public interface SpringDataAumentoPreciosRepository extends JpaRepository<AumentoPreciosEntity, UUID> {
+
+    Optional<AumentoPreciosEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    List<AumentoPreciosEntity> findByLocalIdOrderByFechaCreacionDesc(UUID localId);
+
+    List<AumentoPreciosEntity> findByEstadoAndFechaVigenciaLessThanEqualOrderByFechaCreacionAsc(
+        EstadoAumentoPrecios estado, LocalDate dia);
+}
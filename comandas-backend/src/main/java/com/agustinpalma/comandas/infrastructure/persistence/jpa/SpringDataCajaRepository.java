@@ -0,0 +1,23 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.CajaEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para cajas.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataCajaRepository extends JpaRepository<CajaEntity, UUID> {
+
+    Optional<CajaEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    List<CajaEntity> findByLocalIdOrderByFechaAltaAsc(UUID localId);
+
+    boolean existsByLocalIdAndNombreIgnoreCase(UUID localId, String nombre);
+}
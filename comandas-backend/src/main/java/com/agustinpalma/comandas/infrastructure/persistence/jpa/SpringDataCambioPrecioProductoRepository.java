@@ -0,0 +1,22 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.CambioPrecioProductoEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para CambioPrecioProductoEntity.
+ * HU-146: Historial de cambios de precios.
+ */
+@Repository
+public interface SpringDataCambioPrecioProductoRepository extends JpaRepository<CambioPrecioProductoEntity, UUID> {
+
+    List<CambioPrecioProductoEntity> findByProductoIdAndLocalIdOrderByFechaDesc(UUID productoId, UUID localId);
+
+    List<CambioPrecioProductoEntity> findByLocalIdAndFechaGreaterThanEqualAndFechaLessThanOrderByFechaAsc(
+        UUID localId, LocalDateTime desde, LocalDateTime hasta);
+}
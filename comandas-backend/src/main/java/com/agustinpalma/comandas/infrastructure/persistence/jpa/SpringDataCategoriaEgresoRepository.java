@@ -0,0 +1,21 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.CategoriaEgresoEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para categorías de egresos.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataCategoriaEgresoRepository extends JpaRepository<CategoriaEgresoEntity, UUID> {
+
+    Optional<CategoriaEgresoEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    List<CategoriaEgresoEntity> findByLocalIdOrderByNombreAsc(UUID localId);
+}
@@ -0,0 +1,24 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoEspera;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ClienteEnEsperaEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.Collection;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para ClienteEnEsperaEntity.
+ * HU-149: Lista de espera de mesas.
+ */
+@Repository
+public interface SpringDataClienteEnEsperaRepository extends JpaRepository<ClienteEnEsperaEntity, UUID> {
+
+    Optional<ClienteEnEsperaEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    List<ClienteEnEsperaEntity> findByLocalIdAndEstadoInOrderByFechaAltaAsc(
+        UUID localId, Collection<EstadoEspera> estados);
+}
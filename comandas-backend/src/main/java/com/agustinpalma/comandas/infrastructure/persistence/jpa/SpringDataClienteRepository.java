@@ -0,0 +1,23 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ClienteEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Spring Data JPA repository for customers.
+ * HU-104: Customer running accounts.
+ */
+@Repository
+public interface SpringDataClienteRepository extends JpaRepository<ClienteEntity, UUID> {
+
+    Optional<ClienteEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    List<ClienteEntity> findByLocalIdOrderByNombreAsc(UUID localId);
+
+    boolean existsByLocalIdAndNombreIgnoreCase(UUID localId, String nombre);
+}
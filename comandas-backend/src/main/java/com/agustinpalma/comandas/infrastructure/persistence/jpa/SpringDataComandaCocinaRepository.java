@@ -0,0 +1,29 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ComandaCocinaEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para comandas de cocina.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataComandaCocinaRepository extends JpaRepository<ComandaCocinaEntity, UUID> {
+
+    Optional<ComandaCocinaEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    List<ComandaCocinaEntity> findByLocalIdAndListaEnIsNullOrderByEnviadaEnAsc(UUID localId);
+
+    List<ComandaCocinaEntity> findByLocalIdAndPedidoIdAndListaEnIsNull(UUID localId, UUID pedidoId);
+
+    Optional<ComandaCocinaEntity> findFirstByLocalIdAndListaEnIsNotNullOrderByListaEnDesc(UUID localId);
+
+    List<ComandaCocinaEntity> findByLocalIdAndListaEnIsNotNullAndEnviadaEnGreaterThanEqualAndEnviadaEnLessThan(
+        UUID localId, LocalDateTime desde, LocalDateTime hasta);
+}
@@ -0,0 +1,14 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ComprobanteGastoEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para los archivos de comprobantes de gastos (HU-117).
+ */
+@Repository
+public interface SpringDataComprobanteGastoRepository extends JpaRepository<ComprobanteGastoEntity, UUID> {
+}
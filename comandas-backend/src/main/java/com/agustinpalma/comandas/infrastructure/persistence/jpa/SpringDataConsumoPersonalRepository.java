@@ -0,0 +1,28 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ConsumoPersonalEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para ConsumoPersonalEntity.
+ * HU-132: Límites de consumo interno del personal.
+ */
+@Repository
+public interface SpringDataConsumoPersonalRepository extends JpaRepository<ConsumoPersonalEntity, UUID> {
+
+    List<ConsumoPersonalEntity> findByLocalIdAndFechaGreaterThanEqualAndFechaLessThanOrderByFechaAsc(
+        UUID localId, LocalDateTime desde, LocalDateTime hasta);
+
+    List<ConsumoPersonalEntity> findByMozoIdAndLocalIdAndFechaGreaterThanEqualAndFechaLessThanOrderByFechaAsc(
+        UUID mozoId, UUID localId, LocalDateTime desde, LocalDateTime hasta);
+
+    Optional<ConsumoPersonalEntity> findByPedidoIdAndLocalId(UUID pedidoId, UUID localId);
+
+    void deleteByPedidoIdAndLocalId(UUID pedidoId, UUID localId);
+}
@@ -0,0 +1,25 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.DistribucionPropinasEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para DistribucionPropinasEntity.
+ * HU-141: Pozo de propinas y distribución.
+ */
+@Repository
+public interface SpringDataDistribucionPropinasRepository extends JpaRepository<DistribucionPropinasEntity, UUID> {
+
+    Optional<DistribucionPropinasEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    Optional<DistribucionPropinasEntity> findByTurnoIdAndLocalId(UUID turnoId, UUID localId);
+
+    List<DistribucionPropinasEntity> findByLocalIdAndFechaGreaterThanEqualAndFechaLessThanOrderByFechaDesc(
+        UUID localId, LocalDateTime inicio, LocalDateTime fin);
+}
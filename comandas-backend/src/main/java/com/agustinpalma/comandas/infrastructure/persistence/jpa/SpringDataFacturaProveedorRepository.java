@@ -0,0 +1,36 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.FacturaProveedorEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.data.jpa.repository.Query;
+import org.springframework.data.repository.query.Param;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para facturas de proveedores.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataFacturaProveedorRepository extends JpaRepository<FacturaProveedorEntity, UUID> {
+
+    Optional<FacturaProveedorEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    List<FacturaProveedorEntity> findByProveedorIdAndLocalIdOrderByFechaVencimientoAsc(UUID proveedorId, UUID localId);
+
+    @Query("SELECT f FROM FacturaProveedorEntity f " +
+           "WHERE f.localId = :localId AND f.saldo > 0 " +
+           "ORDER BY f.fechaVencimiento ASC")
+    List<FacturaProveedorEntity> findImpagasByLocalId(@Param("localId") UUID localId);
+
+    @Query("SELECT p.movimientoCajaId FROM FacturaProveedorEntity f JOIN f.pagos p " +
+           "WHERE f.localId = :localId AND p.movimientoCajaId IS NOT NULL " +
+           "AND p.fecha BETWEEN :desde AND :hasta")
+    List<UUID> findMovimientosCajaAsociados(@Param("localId") UUID localId,
+                                            @Param("desde") LocalDateTime desde,
+                                            @Param("hasta") LocalDateTime hasta);
+}
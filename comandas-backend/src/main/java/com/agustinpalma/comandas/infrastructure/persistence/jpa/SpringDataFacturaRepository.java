@@ -0,0 +1,41 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoFactura;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.FacturaEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.data.jpa.repository.Query;
+import org.springframework.data.repository.query.Param;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para facturas.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataFacturaRepository extends JpaRepository<FacturaEntity, UUID> {
+
+    Optional<FacturaEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    Optional<FacturaEntity> findByPedidoIdAndLocalId(UUID pedidoId, UUID localId);
+
+    @Query("SELECT f FROM FacturaEntity f " +
+           "WHERE f.localId = :localId AND f.fechaEmision >= :desde AND f.fechaEmision < :hasta " +
+           "ORDER BY f.fechaEmision ASC, f.numero ASC")
+    List<FacturaEntity> findByLocalIdAndPeriodo(@Param("localId") UUID localId,
+                                                @Param("desde") LocalDateTime desde,
+                                                @Param("hasta") LocalDateTime hasta);
+
+    /**
+     * Último número de la serie, 0 si todavía no hay facturas.
+     */
+    @Query("SELECT COALESCE(MAX(f.numero), 0) FROM FacturaEntity f " +
+           "WHERE f.localId = :localId AND f.puntoVenta = :puntoVenta AND f.tipo = :tipo")
+    long findUltimoNumero(@Param("localId") UUID localId,
+                          @Param("puntoVenta") int puntoVenta,
+                          @Param("tipo") TipoFactura tipo);
+}
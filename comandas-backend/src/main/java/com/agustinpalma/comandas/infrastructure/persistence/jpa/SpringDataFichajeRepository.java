@@ -0,0 +1,31 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.FichajeEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para fichajes del personal.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataFichajeRepository extends JpaRepository<FichajeEntity, UUID> {
+
+    Optional<FichajeEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    /**
+     * Fichaje abierto más reciente del empleado.
+     */
+    Optional<FichajeEntity> findFirstByLocalIdAndMozoIdAndSalidaIsNullOrderByEntradaDesc(UUID localId, UUID mozoId);
+
+    /**
+     * Fichajes con entrada en [desde, hasta).
+     */
+    List<FichajeEntity> findByLocalIdAndEntradaGreaterThanEqualAndEntradaLessThanOrderByEntradaAsc(
+        UUID localId, LocalDateTime desde, LocalDateTime hasta);
+}
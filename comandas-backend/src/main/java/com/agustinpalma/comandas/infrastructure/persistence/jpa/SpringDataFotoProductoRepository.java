@@ -0,0 +1,30 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.FotoProductoEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para las fotos de productos (HU-127).
+ */
+@Repository
+public interface SpringDataFotoProductoRepository extends JpaRepository<FotoProductoEntity, UUID> {
+
+    Optional<FotoProductoEntity> findByProductoIdAndLocalId(UUID productoId, UUID localId);
+
+    /** Proyección sin el archivo: solo lo necesario para versionar las URLs de la carta */
+    List<VersionFoto> findByLocalId(UUID localId);
+
+    void deleteByProductoIdAndLocalId(UUID productoId, UUID localId);
+
+    interface VersionFoto {
+        UUID getProductoId();
+
+        LocalDateTime getActualizadaEn();
+    }
+}
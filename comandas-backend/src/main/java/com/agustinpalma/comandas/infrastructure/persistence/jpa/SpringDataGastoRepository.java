@@ -0,0 +1,37 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.GastoEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.data.jpa.repository.Query;
+import org.springframework.data.repository.query.Param;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para compras y gastos.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataGastoRepository extends JpaRepository<GastoEntity, UUID> {
+
+    Optional<GastoEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    @Query("SELECT g FROM GastoEntity g " +
+           "WHERE g.localId = :localId AND g.fecha BETWEEN :desde AND :hasta " +
+           "ORDER BY g.fecha DESC, g.fechaRegistro DESC")
+    List<GastoEntity> findByLocalIdAndPeriodo(@Param("localId") UUID localId,
+                                              @Param("desde") LocalDate desde,
+                                              @Param("hasta") LocalDate hasta);
+
+    @Query("SELECT g.movimientoCajaId FROM GastoEntity g " +
+           "WHERE g.localId = :localId AND g.movimientoCajaId IS NOT NULL " +
+           "AND g.fechaRegistro BETWEEN :desde AND :hasta")
+    List<UUID> findMovimientosCajaAsociados(@Param("localId") UUID localId,
+                                            @Param("desde") LocalDateTime desde,
+                                            @Param("hasta") LocalDateTime hasta);
+}
@@ -0,0 +1,21 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.InsumoEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para insumos.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataInsumoRepository extends JpaRepository<InsumoEntity, UUID> {
+
+    Optional<InsumoEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    List<InsumoEntity> findByLocalIdOrderByNombreAsc(UUID localId);
+}
@@ -0,0 +1,15 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.JornadaComercialEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para JornadaComercialEntity.
+ * HU-153: Límite de jornada comercial configurable.
+ */
+@Repository
+public interface SpringDataJornadaComercialRepository extends JpaRepository<JornadaComercialEntity, UUID> {
+}
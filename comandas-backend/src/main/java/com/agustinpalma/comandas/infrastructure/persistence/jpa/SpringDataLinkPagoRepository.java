@@ -0,0 +1,26 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoLinkPago;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.LinkPagoEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para links de pago.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataLinkPagoRepository extends JpaRepository<LinkPagoEntity, UUID> {
+
+    Optional<LinkPagoEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    Optional<LinkPagoEntity> findFirstByPedidoIdAndLocalIdAndEstado(UUID pedidoId, UUID localId, EstadoLinkPago estado);
+
+    Optional<LinkPagoEntity> findFirstByPedidoIdAndLocalIdOrderByCreadoEnDesc(UUID pedidoId, UUID localId);
+
+    List<LinkPagoEntity> findByLocalIdAndEstadoOrderByCreadoEnAsc(UUID localId, EstadoLinkPago estado);
+}
@@ -0,0 +1,24 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ListaPreciosEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para listas de precios por canal.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataListaPreciosRepository extends JpaRepository<ListaPreciosEntity, UUID> {
+
+    Optional<ListaPreciosEntity> findByLocalIdAndCanal(UUID localId, CanalVenta canal);
+
+    Optional<ListaPreciosEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    List<ListaPreciosEntity> findByLocalId(UUID localId);
+}
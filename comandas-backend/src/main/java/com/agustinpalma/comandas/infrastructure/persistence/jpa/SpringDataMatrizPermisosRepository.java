@@ -0,0 +1,15 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.MatrizPermisosEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para MatrizPermisosEntity.
+ * HU-169: Matriz de permisos por rol y por empleado.
+ */
+@Repository
+public interface SpringDataMatrizPermisosRepository extends JpaRepository<MatrizPermisosEntity, UUID> {
+}
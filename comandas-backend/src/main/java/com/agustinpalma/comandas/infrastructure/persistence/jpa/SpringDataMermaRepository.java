@@ -0,0 +1,20 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.MermaEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para mermas.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataMermaRepository extends JpaRepository<MermaEntity, UUID> {
+
+    List<MermaEntity> findByLocalIdAndFechaBetweenOrderByFechaDesc(UUID localId, LocalDateTime desde,
+                                                                   LocalDateTime hasta);
+}
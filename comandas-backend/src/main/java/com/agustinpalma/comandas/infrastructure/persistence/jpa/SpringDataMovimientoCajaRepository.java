@@ -35,4 +35,14 @@ public interface SpringDataMovimientoCajaRepository extends JpaRepository<Movimi
         @Param("inicio") LocalDateTime inicio,
         @Param("fin") LocalDateTime fin
     );
+
+    /**
+     * HU-106: Movimientos registrados durante un turno de caja.
+     */
+    List<MovimientoCajaEntity> findByLocalIdAndTurnoIdOrderByFechaAsc(UUID localId, UUID turnoId);
+
+    /**
+     * HU-121: Devoluciones registradas sobre un pedido.
+     */
+    List<MovimientoCajaEntity> findByLocalIdAndPedidoIdOrderByFechaAsc(UUID localId, UUID pedidoId);
 }
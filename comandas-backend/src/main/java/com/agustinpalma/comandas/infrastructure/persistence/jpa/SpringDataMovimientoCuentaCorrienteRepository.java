@@ -0,0 +1,22 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.MovimientoCuentaCorrienteEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Spring Data JPA repository for customer running account movements.
+ * HU-104: Customer running accounts.
+ */
+@Repository
+public interface SpringDataMovimientoCuentaCorrienteRepository extends JpaRepository<MovimientoCuentaCorrienteEntity, UUID> {
+
+    List<MovimientoCuentaCorrienteEntity> findByClienteIdAndLocalIdOrderByFechaAsc(UUID clienteId, UUID localId);
+
+    List<MovimientoCuentaCorrienteEntity> findByPedidoIdAndLocalIdAndTipo(UUID pedidoId, UUID localId, String tipo);
+
+    void deleteByPedidoIdAndLocalIdAndTipo(UUID pedidoId, UUID localId, String tipo);
+}
@@ -0,0 +1,18 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.MovimientoInsumoEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para movimientos de stock de insumos.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataMovimientoInsumoRepository extends JpaRepository<MovimientoInsumoEntity, UUID> {
+
+    List<MovimientoInsumoEntity> findByInsumoIdAndLocalIdOrderByFechaDesc(UUID insumoId, UUID localId);
+}
@@ -0,0 +1,26 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.MovimientoPuntosEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Spring Data JPA repository for loyalty points movements.
+ * HU-105: Customer loyalty points.
+ */
+@Repository
+public interface SpringDataMovimientoPuntosRepository extends JpaRepository<MovimientoPuntosEntity, UUID> {
+
+    List<MovimientoPuntosEntity> findByClienteIdAndLocalIdOrderByFechaAsc(UUID clienteId, UUID localId);
+
+    List<MovimientoPuntosEntity> findByLocalIdOrderByFechaAsc(UUID localId);
+
+    List<MovimientoPuntosEntity> findByPedidoIdAndLocalId(UUID pedidoId, UUID localId);
+
+    void deleteByPedidoIdAndLocalId(UUID pedidoId, UUID localId);
+
+    void deleteByPedidoIdAndLocalIdAndTipo(UUID pedidoId, UUID localId, String tipo);
+}
@@ -0,0 +1,23 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.MozoEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para mozos.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataMozoRepository extends JpaRepository<MozoEntity, UUID> {
+
+    Optional<MozoEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    List<MozoEntity> findByLocalIdOrderByNombreAsc(UUID localId);
+
+    Optional<MozoEntity> findByLocalIdAndCodigoTarjeta(UUID localId, String codigoTarjeta);
+}
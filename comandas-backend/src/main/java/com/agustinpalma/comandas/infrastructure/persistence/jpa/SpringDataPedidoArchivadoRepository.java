@@ -0,0 +1,33 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.PedidoArchivadoEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.data.jpa.repository.Query;
+import org.springframework.data.repository.query.Param;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para los pedidos archivados (HU-162).
+ */
+@Repository
+public interface SpringDataPedidoArchivadoRepository extends JpaRepository<PedidoArchivadoEntity, UUID> {
+
+    List<PedidoArchivadoEntity> findByLocalIdAndFechaCierreBetweenOrderByFechaCierre(
+        UUID localId, LocalDateTime inicio, LocalDateTime fin);
+
+    @Query("SELECT COALESCE(MAX(p.numero), 0) FROM PedidoArchivadoEntity p WHERE p.localId = :localId")
+    int findMaxNumeroByLocalId(@Param("localId") UUID localId);
+
+    /**
+     * HU-166: Los números van completados con ceros, así que el máximo como
+     * texto es el más alto.
+     */
+    @Query("SELECT MAX(p.numeroComprobante) FROM PedidoArchivadoEntity p "
+        + "WHERE p.localId = :localId AND p.numeroComprobante LIKE :patron")
+    Optional<String> findUltimoComprobante(@Param("localId") UUID localId, @Param("patron") String patron);
+}
@@ -2,7 +2,9 @@ package com.agustinpalma.comandas.infrastructure.persistence.jpa;
 
 import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
 import com.agustinpalma.comandas.infrastructure.persistence.entity.PedidoEntity;
+import org.springframework.data.domain.Pageable;
 import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.data.jpa.repository.Modifying;
 import org.springframework.data.jpa.repository.Query;
 import org.springframework.data.repository.query.Param;
 import org.springframework.stereotype.Repository;
@@ -58,6 +60,19 @@ public interface SpringDataPedidoRepository extends JpaRepository<PedidoEntity,
     @Query("SELECT COALESCE(MAX(p.numero), 0) FROM PedidoEntity p WHERE p.localId = :localId")
     int findMaxNumeroByLocalId(@Param("localId") UUID localId);
 
+    /**
+     * HU-166: Último comprobante del local que coincide con el patrón. Los
+     * números van completados con ceros, así que el máximo como texto es el
+     * más alto.
+     *
+     * @param localId UUID del local
+     * @param patron patrón LIKE del número (encabezado + un "_" por dígito)
+     * @return el comprobante más alto, o vacío si no hay ninguno
+     */
+    @Query("SELECT MAX(p.numeroComprobante) FROM PedidoEntity p "
+        + "WHERE p.localId = :localId AND p.numeroComprobante LIKE :patron")
+    Optional<String> findUltimoComprobante(@Param("localId") UUID localId, @Param("patron") String patron);
+
     /**
      * Busca pedidos cerrados de un local en un rango de fechas.
      * Usa JOIN FETCH para cargar pagos eagerly y evitar N+1.
@@ -78,4 +93,107 @@ public interface SpringDataPedidoRepository extends JpaRepository<PedidoEntity,
         @Param("inicio") LocalDateTime inicio,
         @Param("fin") LocalDateTime fin
     );
+
+    /**
+     * HU-106: Pedidos cerrados (cobrados) durante un turno de caja.
+     * Usa JOIN FETCH para cargar pagos eagerly y evitar N+1.
+     *
+     * @param localId UUID del local
+     * @param turnoId UUID del turno
+     * @return lista de pedidos cerrados con pagos cargados
+     */
+    @Query("SELECT DISTINCT p FROM PedidoEntity p " +
+           "LEFT JOIN FETCH p.pagos " +
+           "WHERE p.localId = :localId " +
+           "AND p.estado = 'CERRADO' " +
+           "AND p.turnoId = :turnoId")
+    List<PedidoEntity> findCerradosByLocalIdAndTurnoId(
+        @Param("localId") UUID localId,
+        @Param("turnoId") UUID turnoId
+    );
+
+    /**
+     * HU-168: Pedidos cerrados de un cliente, los más recientes primero.
+     * Usa JOIN FETCH para cargar pagos eagerly y evitar N+1.
+     *
+     * @param localId UUID del local
+     * @param clienteId UUID del cliente
+     * @return lista de pedidos cerrados con pagos cargados
+     */
+    @Query("SELECT DISTINCT p FROM PedidoEntity p " +
+           "LEFT JOIN FETCH p.pagos " +
+           "WHERE p.localId = :localId " +
+           "AND p.estado = 'CERRADO' " +
+           "AND p.clienteId = :clienteId " +
+           "ORDER BY p.fechaCierre DESC")
+    List<PedidoEntity> findCerradosByLocalIdAndClienteId(
+        @Param("localId") UUID localId,
+        @Param("clienteId") UUID clienteId
+    );
+
+    /**
+     * HU-113: Pedidos abiertos de un local (mesas ocupadas).
+     *
+     * @param localId UUID del local
+     * @return lista de pedidos abiertos
+     */
+    @Query("SELECT p FROM PedidoEntity p WHERE p.localId = :localId AND p.estado = 'ABIERTO'")
+    List<PedidoEntity> findAbiertosByLocalId(@Param("localId") UUID localId);
+
+    /**
+     * HU-140: Pedidos asignados a un repartidor, abiertos en el rango [inicio, fin).
+     *
+     * @param localId UUID del local
+     * @param inicio inicio del rango de apertura
+     * @param fin fin del rango de apertura (exclusive)
+     * @return pedidos con repartidor y pagos cargados
+     */
+    @Query("SELECT DISTINCT p FROM PedidoEntity p " +
+           "LEFT JOIN FETCH p.pagos " +
+           "WHERE p.localId = :localId " +
+           "AND p.repartidorId IS NOT NULL " +
+           "AND p.fechaApertura >= :inicio " +
+           "AND p.fechaApertura < :fin " +
+           "ORDER BY p.fechaApertura")
+    List<PedidoEntity> findRepartosByLocalIdAndFechaAperturaBetween(
+        @Param("localId") UUID localId,
+        @Param("inicio") LocalDateTime inicio,
+        @Param("fin") LocalDateTime fin
+    );
+
+    /**
+     * HU-162: IDs de los pedidos cerrados antes del límite, los más viejos primero.
+     * Solo los IDs: paginar con JOIN FETCH obligaría a Hibernate a paginar en memoria.
+     *
+     * @param limite fecha de cierre tope (exclusive)
+     * @param pagina tamaño del lote
+     * @return IDs de los pedidos a archivar
+     */
+    @Query("SELECT p.id FROM PedidoEntity p " +
+           "WHERE p.estado = 'CERRADO' " +
+           "AND p.fechaCierre < :limite " +
+           "ORDER BY p.fechaCierre")
+    List<UUID> findIdsCerradosAntesDe(@Param("limite") LocalDateTime limite, Pageable pagina);
+
+    /**
+     * HU-165: Sube la versión del pedido solo si sigue siendo la que se leyó.
+     * El UPDATE retiene la fila hasta el commit: de dos guardados hechos sobre
+     * la misma versión, el segundo espera al primero y ya no la encuentra.
+     *
+     * @param id UUID del pedido
+     * @param version versión que se leyó antes de modificarlo
+     * @return 1 si se subió la versión, 0 si otro guardado la cambió en el medio
+     */
+    @Modifying
+    @Query("UPDATE PedidoEntity p SET p.version = p.version + 1 WHERE p.id = :id AND p.version = :version")
+    int incrementarVersion(@Param("id") UUID id, @Param("version") long version);
+
+    /**
+     * HU-165: Versión actual del pedido, para avisar contra cuál chocó un guardado.
+     *
+     * @param id UUID del pedido
+     * @return la versión, o vacío si el pedido no existe
+     */
+    @Query("SELECT p.version FROM PedidoEntity p WHERE p.id = :id")
+    Optional<Long> findVersionById(@Param("id") UUID id);
 }
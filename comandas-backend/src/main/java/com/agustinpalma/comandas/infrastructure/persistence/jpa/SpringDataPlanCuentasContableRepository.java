@@ -0,0 +1,15 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.PlanCuentasContableEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para PlanCuentasContableEntity.
+ * HU-167: Exportación al sistema contable.
+ */
+@Repository
+public interface SpringDataPlanCuentasContableRepository extends JpaRepository<PlanCuentasContableEntity, UUID> {
+}
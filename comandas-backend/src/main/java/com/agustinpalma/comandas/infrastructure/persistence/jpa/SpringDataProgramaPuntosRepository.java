@@ -0,0 +1,15 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ProgramaPuntosEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.UUID;
+
+/**
+ * Spring Data JPA repository for the loyalty points program configuration.
+ * HU-105: Customer loyalty points.
+ */
+@Repository
+public interface SpringDataProgramaPuntosRepository extends JpaRepository<ProgramaPuntosEntity, UUID> {
+}
@@ -0,0 +1,15 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.PromoCumpleaniosEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.UUID;
+
+/**
+ * Spring Data JPA repository for the birthday promo configuration.
+ * HU-168: Customer profile and birthday promo.
+ */
+@Repository
+public interface SpringDataPromoCumpleaniosRepository extends JpaRepository<PromoCumpleaniosEntity, UUID> {
+}
@@ -0,0 +1,21 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ProveedorEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para proveedores.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataProveedorRepository extends JpaRepository<ProveedorEntity, UUID> {
+
+    Optional<ProveedorEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    List<ProveedorEntity> findByLocalIdOrderByNombreAsc(UUID localId);
+}
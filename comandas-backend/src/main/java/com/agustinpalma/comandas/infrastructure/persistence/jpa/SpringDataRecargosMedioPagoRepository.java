@@ -0,0 +1,15 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.RecargosMedioPagoEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para RecargosMedioPagoEntity.
+ * HU-143: Recargo configurable por medio de pago.
+ */
+@Repository
+public interface SpringDataRecargosMedioPagoRepository extends JpaRepository<RecargosMedioPagoEntity, UUID> {
+}
@@ -0,0 +1,23 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.RecetaEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para recetas.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataRecetaRepository extends JpaRepository<RecetaEntity, UUID> {
+
+    Optional<RecetaEntity> findByProductoIdAndLocalId(UUID productoId, UUID localId);
+
+    List<RecetaEntity> findByLocalId(UUID localId);
+
+    void deleteByProductoIdAndLocalId(UUID productoId, UUID localId);
+}
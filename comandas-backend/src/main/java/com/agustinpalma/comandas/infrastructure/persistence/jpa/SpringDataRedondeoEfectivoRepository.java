@@ -0,0 +1,15 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.RedondeoEfectivoEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para RedondeoEfectivoEntity.
+ * HU-152: Reglas de redondeo configurables en el total.
+ */
+@Repository
+public interface SpringDataRedondeoEfectivoRepository extends JpaRepository<RedondeoEfectivoEntity, UUID> {
+}
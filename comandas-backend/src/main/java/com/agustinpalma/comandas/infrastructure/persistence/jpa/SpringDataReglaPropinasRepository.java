@@ -0,0 +1,15 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ReglaPropinasEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para ReglaPropinasEntity.
+ * HU-141: Pozo de propinas y distribución.
+ */
+@Repository
+public interface SpringDataReglaPropinasRepository extends JpaRepository<ReglaPropinasEntity, UUID> {
+}
@@ -0,0 +1,18 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.RendicionRepartidorEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para RendicionRepartidorEntity.
+ * HU-140: Asignación y seguimiento de repartidores.
+ */
+@Repository
+public interface SpringDataRendicionRepartidorRepository extends JpaRepository<RendicionRepartidorEntity, UUID> {
+
+    List<RendicionRepartidorEntity> findByTurnoIdAndLocalIdOrderByFechaAsc(UUID turnoId, UUID localId);
+}
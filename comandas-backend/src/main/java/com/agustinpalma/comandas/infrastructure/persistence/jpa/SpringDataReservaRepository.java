@@ -0,0 +1,27 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoReserva;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ReservaEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para ReservaEntity.
+ * HU-142: Señas de reservas aplicadas a la cuenta.
+ */
+@Repository
+public interface SpringDataReservaRepository extends JpaRepository<ReservaEntity, UUID> {
+
+    Optional<ReservaEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    List<ReservaEntity> findByLocalIdAndFechaHoraGreaterThanEqualAndFechaHoraLessThanOrderByFechaHoraAsc(
+        UUID localId, LocalDateTime inicio, LocalDateTime fin);
+
+    List<ReservaEntity> findByMesaIdAndLocalIdAndEstadoOrderByFechaHoraAsc(
+        UUID mesaId, UUID localId, EstadoReserva estado);
+}
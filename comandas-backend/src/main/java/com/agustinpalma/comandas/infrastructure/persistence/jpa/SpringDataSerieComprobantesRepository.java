@@ -0,0 +1,39 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.SerieComprobantesEntity;
+import jakarta.persistence.LockModeType;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.data.jpa.repository.Lock;
+import org.springframework.data.jpa.repository.Query;
+import org.springframework.data.repository.query.Param;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para las series de numeración de comprobantes.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ *
+ * HU-166: Las variantes "bloquear" toman la fila con SELECT ... FOR UPDATE
+ * (PostgreSQL). En SQLite la base admite un solo escritor y el pool tiene una
+ * sola conexión, así que los cierres ya se hacen de a uno.
+ */
+@Repository
+public interface SpringDataSerieComprobantesRepository extends JpaRepository<SerieComprobantesEntity, UUID> {
+
+    Optional<SerieComprobantesEntity> findByLocalIdAndCajaIdIsNull(UUID localId);
+
+    Optional<SerieComprobantesEntity> findByLocalIdAndCajaId(UUID localId, UUID cajaId);
+
+    List<SerieComprobantesEntity> findByLocalId(UUID localId);
+
+    @Lock(LockModeType.PESSIMISTIC_WRITE)
+    @Query("SELECT s FROM SerieComprobantesEntity s WHERE s.localId = :localId AND s.cajaId IS NULL")
+    Optional<SerieComprobantesEntity> bloquearPrincipal(@Param("localId") UUID localId);
+
+    @Lock(LockModeType.PESSIMISTIC_WRITE)
+    @Query("SELECT s FROM SerieComprobantesEntity s WHERE s.localId = :localId AND s.cajaId = :cajaId")
+    Optional<SerieComprobantesEntity> bloquear(@Param("localId") UUID localId, @Param("cajaId") UUID cajaId);
+}
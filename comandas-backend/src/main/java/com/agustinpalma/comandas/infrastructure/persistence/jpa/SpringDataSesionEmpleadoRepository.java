@@ -0,0 +1,22 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.SesionEmpleadoEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.data.jpa.repository.Modifying;
+import org.springframework.data.jpa.repository.Query;
+import org.springframework.data.repository.query.Param;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDateTime;
+
+/**
+ * Repositorio Spring Data JPA para SesionEmpleadoEntity.
+ * HU-169: Sesiones de los empleados identificados con su PIN.
+ */
+@Repository
+public interface SpringDataSesionEmpleadoRepository extends JpaRepository<SesionEmpleadoEntity, String> {
+
+    @Modifying
+    @Query("DELETE FROM SesionEmpleadoEntity s WHERE s.venceEn <= :ahora")
+    int deleteVencidas(@Param("ahora") LocalDateTime ahora);
+}
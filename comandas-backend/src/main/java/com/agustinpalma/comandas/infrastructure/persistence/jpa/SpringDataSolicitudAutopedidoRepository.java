@@ -0,0 +1,24 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoSolicitudAutopedido;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.SolicitudAutopedidoEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para pedidos del QR.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataSolicitudAutopedidoRepository extends JpaRepository<SolicitudAutopedidoEntity, UUID> {
+
+    Optional<SolicitudAutopedidoEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    List<SolicitudAutopedidoEntity> findByLocalIdAndEstadoOrderByCreadaEnAsc(UUID localId, EstadoSolicitudAutopedido estado);
+
+    long countByLocalIdAndMesaIdAndEstado(UUID localId, UUID mesaId, EstadoSolicitudAutopedido estado);
+}
@@ -0,0 +1,35 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.TarjetaRegaloEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.data.jpa.repository.Query;
+import org.springframework.data.repository.query.Param;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para TarjetaRegaloEntity.
+ * HU-144: Gift cards y vouchers prepagos.
+ */
+@Repository
+public interface SpringDataTarjetaRegaloRepository extends JpaRepository<TarjetaRegaloEntity, UUID> {
+
+    Optional<TarjetaRegaloEntity> findByCodigoAndLocalId(String codigo, UUID localId);
+
+    boolean existsByCodigoAndLocalId(String codigo, UUID localId);
+
+    List<TarjetaRegaloEntity> findByLocalIdOrderByFechaEmisionDesc(UUID localId);
+
+    /**
+     * Tarjetas con algún consumo en el pedido, para reintegrar el saldo al reabrirlo.
+     */
+    @Query("SELECT DISTINCT t FROM TarjetaRegaloEntity t JOIN t.consumos c " +
+           "WHERE t.localId = :localId AND c.pedidoId = :pedidoId")
+    List<TarjetaRegaloEntity> findUsadasEnPedido(
+        @Param("pedidoId") UUID pedidoId,
+        @Param("localId") UUID localId
+    );
+}
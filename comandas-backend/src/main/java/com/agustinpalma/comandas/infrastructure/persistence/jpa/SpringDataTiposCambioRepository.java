@@ -0,0 +1,19 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.TiposCambioEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDate;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para TiposCambioEntity.
+ * HU-151: Cobros en moneda extranjera.
+ */
+@Repository
+public interface SpringDataTiposCambioRepository extends JpaRepository<TiposCambioEntity, UUID> {
+
+    Optional<TiposCambioEntity> findByLocalIdAndFecha(UUID localId, LocalDate fecha);
+}
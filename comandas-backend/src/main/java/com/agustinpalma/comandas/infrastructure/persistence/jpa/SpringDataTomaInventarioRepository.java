@@ -0,0 +1,21 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.TomaInventarioEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para tomas de inventario.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataTomaInventarioRepository extends JpaRepository<TomaInventarioEntity, UUID> {
+
+    Optional<TomaInventarioEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    List<TomaInventarioEntity> findByLocalIdOrderByFechaDesc(UUID localId);
+}
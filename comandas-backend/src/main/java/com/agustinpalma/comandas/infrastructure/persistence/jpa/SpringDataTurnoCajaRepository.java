@@ -0,0 +1,37 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoTurno;
+import com.agustinpalma.comandas.infrastructure.persistence.entity.TurnoCajaEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para turnos de caja.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataTurnoCajaRepository extends JpaRepository<TurnoCajaEntity, UUID> {
+
+    /**
+     * Busca el turno ABIERTO de un local (máximo uno por invariante de negocio).
+     */
+    Optional<TurnoCajaEntity> findByLocalIdAndEstado(UUID localId, EstadoTurno estado);
+
+    /**
+     * Lista los turnos de una jornada en orden de apertura.
+     */
+    List<TurnoCajaEntity> findByJornadaIdAndLocalIdOrderByFechaAperturaAsc(UUID jornadaId, UUID localId);
+
+    /**
+     * HU-129: Turnos cerrados en un rango (ambos extremos inclusive), en orden de cierre.
+     */
+    List<TurnoCajaEntity> findByLocalIdAndEstadoAndFechaCierreBetweenOrderByFechaCierreAsc(
+        UUID localId, EstadoTurno estado, LocalDateTime inicio, LocalDateTime fin);
+
+    Optional<TurnoCajaEntity> findByIdAndLocalId(UUID id, UUID localId);
+}
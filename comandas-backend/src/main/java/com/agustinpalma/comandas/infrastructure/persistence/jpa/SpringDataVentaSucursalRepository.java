@@ -0,0 +1,24 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.VentaSucursalEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para ventas consolidadas de sucursales.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataVentaSucursalRepository extends JpaRepository<VentaSucursalEntity, UUID> {
+
+    /**
+     * Ventas con cierre en [inicio, fin), ordenadas cronológicamente.
+     */
+    List<VentaSucursalEntity> findByLocalIdAndFechaCierreGreaterThanEqualAndFechaCierreLessThanOrderByFechaCierreAsc(
+        UUID localId, LocalDateTime inicio, LocalDateTime fin
+    );
+}
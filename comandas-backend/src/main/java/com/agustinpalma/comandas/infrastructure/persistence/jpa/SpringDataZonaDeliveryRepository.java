@@ -0,0 +1,21 @@
+package com.agustinpalma.comandas.infrastructure.persistence.jpa;
+
+import com.agustinpalma.comandas.infrastructure.persistence.entity.ZonaDeliveryEntity;
+import org.springframework.data.jpa.repository.JpaRepository;
+import org.springframework.stereotype.Repository;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+/**
+ * Repositorio Spring Data JPA para zonas de reparto.
+ * Interfaz tecnológica que delega las operaciones CRUD a Spring Data.
+ */
+@Repository
+public interface SpringDataZonaDeliveryRepository extends JpaRepository<ZonaDeliveryEntity, UUID> {
+
+    Optional<ZonaDeliveryEntity> findByIdAndLocalId(UUID id, UUID localId);
+
+    List<ZonaDeliveryEntity> findByLocalIdOrderByNombreAsc(UUID localId);
+}
@@ -0,0 +1,78 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.AumentoPreciosRequest;
+import com.agustinpalma.comandas.application.dto.AumentoPreciosResponse;
+import com.agustinpalma.comandas.application.dto.VistaPreviaAumentoPreciosResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.GestionarAumentosPreciosUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarPermisosUseCase;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainIds.AumentoPreciosId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.util.List;
+
+/**
+ * Controller REST de aumentos masivos de precios.
+ * HU-145: aumento porcentual por categoría o sobre todo el catálogo, con
+ * redondeo a múltiplos y vigencia programada.
+ *
+ * Endpoints:
+ * - GET  /api/aumentos-precios                 -> Aumentos programados, aplicados y cancelados
+ * - POST /api/aumentos-precios/vista-previa    -> Precios nuevos sin aplicar nada
+ * - POST /api/aumentos-precios                 -> Confirmar (se aplica ya si la vigencia es hoy)
+ * - POST /api/aumentos-precios/{id}/cancelar   -> Cancelar un aumento programado
+ *
+ * HU-169: Confirmar o cancelar un aumento exige el permiso MODIFICAR_PRECIOS.
+ */
+@RestController
+@RequestMapping("/api/aumentos-precios")
+public class AumentoPreciosController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarAumentosPreciosUseCase gestionarAumentosPreciosUseCase;
+    private final GestionarPermisosUseCase gestionarPermisosUseCase;
+
+    public AumentoPreciosController(
+        LocalContextProvider localContextProvider,
+        GestionarAumentosPreciosUseCase gestionarAumentosPreciosUseCase,
+        GestionarPermisosUseCase gestionarPermisosUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarAumentosPreciosUseCase = gestionarAumentosPreciosUseCase;
+        this.gestionarPermisosUseCase = gestionarPermisosUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<List<AumentoPreciosResponse>> listar() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarAumentosPreciosUseCase.listar(localId));
+    }
+
+    @PostMapping("/vista-previa")
+    public ResponseEntity<VistaPreviaAumentoPreciosResponse> vistaPrevia(
+        @Valid @RequestBody AumentoPreciosRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarAumentosPreciosUseCase.vistaPrevia(localId, request));
+    }
+
+    @PostMapping
+    public ResponseEntity<AumentoPreciosResponse> confirmar(@Valid @RequestBody AumentoPreciosRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        gestionarPermisosUseCase.exigir(localId, Permiso.MODIFICAR_PRECIOS);
+        return ResponseEntity.status(HttpStatus.CREATED)
+            .body(gestionarAumentosPreciosUseCase.confirmar(localId, request));
+    }
+
+    @PostMapping("/{id}/cancelar")
+    public ResponseEntity<AumentoPreciosResponse> cancelar(@PathVariable String id) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        gestionarPermisosUseCase.exigir(localId, Permiso.MODIFICAR_PRECIOS);
+        return ResponseEntity.ok(gestionarAumentosPreciosUseCase.cancelar(localId, AumentoPreciosId.from(id)));
+    }
+}
@@ -0,0 +1,126 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.CartaAutopedidoResponse;
+import com.agustinpalma.comandas.application.dto.ResolverSolicitudAutopedidoRequest;
+import com.agustinpalma.comandas.application.dto.SolicitudAutopedidoRequest;
+import com.agustinpalma.comandas.application.dto.SolicitudAutopedidoResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarCartaAutopedidoUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarAutopedidoUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.SolicitudAutopedidoId;
+import com.agustinpalma.comandas.infrastructure.adapter.EnlaceAutopedidoService;
+import jakarta.validation.Valid;
+import org.springframework.http.CacheControl;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.MediaType;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Controller REST del autopedido por QR.
+ * HU-126: carta del cliente, pedidos pendientes de confirmación y QR de cada mesa.
+ *
+ * Endpoints que usa la carta del cliente (carta.html):
+ * - GET  /api/autopedido/mesas/{mesaId}/carta        -> Productos que se pueden pedir desde la mesa
+ * - POST /api/autopedido/mesas/{mesaId}/solicitudes  -> Envía el pedido al mozo
+ * - GET  /api/autopedido/solicitudes/{id}            -> Estado del pedido enviado
+ *
+ * Endpoints del salón:
+ * - GET  /api/autopedido/solicitudes/pendientes      -> Pedidos esperando al mozo
+ * - POST /api/autopedido/solicitudes/{id}/confirmar  -> Pasa los ítems al pedido de la mesa
+ * - POST /api/autopedido/solicitudes/{id}/rechazar   -> Descarta el pedido con un motivo
+ * - GET  /api/autopedido/mesas/{mesaId}/enlace       -> URL de la carta de la mesa
+ * - GET  /api/autopedido/mesas/{mesaId}/qr           -> QR de la mesa en PNG
+ */
+@RestController
+@RequestMapping("/api/autopedido")
+public class AutopedidoController {
+
+    private final LocalContextProvider localContextProvider;
+    private final ConsultarCartaAutopedidoUseCase consultarCartaUseCase;
+    private final GestionarAutopedidoUseCase gestionarAutopedidoUseCase;
+    private final EnlaceAutopedidoService enlaceAutopedidoService;
+
+    public AutopedidoController(
+        LocalContextProvider localContextProvider,
+        ConsultarCartaAutopedidoUseCase consultarCartaUseCase,
+        GestionarAutopedidoUseCase gestionarAutopedidoUseCase,
+        EnlaceAutopedidoService enlaceAutopedidoService
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.consultarCartaUseCase = consultarCartaUseCase;
+        this.gestionarAutopedidoUseCase = gestionarAutopedidoUseCase;
+        this.enlaceAutopedidoService = enlaceAutopedidoService;
+    }
+
+    // ── Carta del cliente ──
+
+    @GetMapping("/mesas/{mesaId}/carta")
+    public ResponseEntity<CartaAutopedidoResponse> obtenerCarta(@PathVariable String mesaId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarCartaUseCase.ejecutar(localId, MesaId.from(mesaId)));
+    }
+
+    @PostMapping("/mesas/{mesaId}/solicitudes")
+    public ResponseEntity<SolicitudAutopedidoResponse> enviar(
+        @PathVariable String mesaId,
+        @Valid @RequestBody SolicitudAutopedidoRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED)
+            .body(gestionarAutopedidoUseCase.enviar(localId, MesaId.from(mesaId), request));
+    }
+
+    @GetMapping("/solicitudes/{solicitudId}")
+    public ResponseEntity<SolicitudAutopedidoResponse> consultar(@PathVariable String solicitudId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(
+            gestionarAutopedidoUseCase.consultar(localId, SolicitudAutopedidoId.from(solicitudId)));
+    }
+
+    // ── Salón ──
+
+    @GetMapping("/solicitudes/pendientes")
+    public ResponseEntity<List<SolicitudAutopedidoResponse>> listarPendientes() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarAutopedidoUseCase.listarPendientes(localId));
+    }
+
+    @PostMapping("/solicitudes/{solicitudId}/confirmar")
+    public ResponseEntity<SolicitudAutopedidoResponse> confirmar(
+        @PathVariable String solicitudId,
+        @Valid @RequestBody ResolverSolicitudAutopedidoRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(
+            gestionarAutopedidoUseCase.confirmar(localId, SolicitudAutopedidoId.from(solicitudId), request));
+    }
+
+    @PostMapping("/solicitudes/{solicitudId}/rechazar")
+    public ResponseEntity<SolicitudAutopedidoResponse> rechazar(
+        @PathVariable String solicitudId,
+        @Valid @RequestBody ResolverSolicitudAutopedidoRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(
+            gestionarAutopedidoUseCase.rechazar(localId, SolicitudAutopedidoId.from(solicitudId), request));
+    }
+
+    @GetMapping("/mesas/{mesaId}/enlace")
+    public ResponseEntity<Map<String, String>> obtenerEnlace(@PathVariable String mesaId) {
+        return ResponseEntity.ok(Map.of("url", enlaceAutopedidoService.urlCarta(MesaId.from(mesaId))));
+    }
+
+    @GetMapping(value = "/mesas/{mesaId}/qr", produces = MediaType.IMAGE_PNG_VALUE)
+    public ResponseEntity<byte[]> obtenerQr(@PathVariable String mesaId) {
+        // Sin caché: si cambia la IP del servidor, el QR reimpreso tiene que salir con la nueva
+        return ResponseEntity.ok()
+            .cacheControl(CacheControl.noStore())
+            .body(enlaceAutopedidoService.generarQrPng(MesaId.from(mesaId)));
+    }
+}
@@ -2,9 +2,13 @@ package com.agustinpalma.comandas.presentation.rest;
 
 import com.agustinpalma.comandas.application.dto.AbrirCajaRequest;
 import com.agustinpalma.comandas.application.dto.AbrirCajaResponse;
+import com.agustinpalma.comandas.application.dto.AbrirTurnoRequest;
+import com.agustinpalma.comandas.application.dto.CerrarTurnoRequest;
 import com.agustinpalma.comandas.application.dto.CierreJornadaResponse;
 import com.agustinpalma.comandas.application.dto.CorreccionPedidoRequest;
 import com.agustinpalma.comandas.application.dto.DetallePedidoCerradoResponse;
+import com.agustinpalma.comandas.application.dto.DevolucionRequest;
+import com.agustinpalma.comandas.application.dto.DevolucionesPedidoResponse;
 import com.agustinpalma.comandas.application.dto.EgresoRequestBody;
 import com.agustinpalma.comandas.application.dto.EgresoResponse;
 import com.agustinpalma.comandas.application.dto.EstadoCajaResponse;
@@ -13,22 +17,37 @@ import com.agustinpalma.comandas.application.dto.IngresoResponse;
 import com.agustinpalma.comandas.application.dto.JornadaResumenResponse;
 import com.agustinpalma.comandas.application.dto.ProductoVendidoReporte;
 import com.agustinpalma.comandas.application.dto.ReporteCajaResponse;
+import com.agustinpalma.comandas.application.dto.ReporteDiferenciasCajaResponse;
+import com.agustinpalma.comandas.application.dto.ReporteEgresosMensualResponse;
+import com.agustinpalma.comandas.application.dto.SaldoTeoricoTurnoResponse;
+import com.agustinpalma.comandas.application.dto.TurnoCajaResponse;
 import com.agustinpalma.comandas.application.usecase.AbrirJornadaUseCase;
+import com.agustinpalma.comandas.application.usecase.AbrirTurnoUseCase;
 import com.agustinpalma.comandas.application.usecase.CerrarJornadaUseCase;
+import com.agustinpalma.comandas.application.usecase.CerrarTurnoUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarDiferenciasCajaUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteEgresosUseCase;
 import com.agustinpalma.comandas.application.usecase.ConsultarHistorialJornadasUseCase;
 import com.agustinpalma.comandas.application.usecase.ConsultarPedidoCerradoUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarTurnosJornadaUseCase;
 import com.agustinpalma.comandas.application.usecase.CorregirPedidoCerradoUseCase;
 import com.agustinpalma.comandas.application.usecase.GenerarReporteCajaUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarCajasUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarDevolucionesUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarPermisosUseCase;
 import com.agustinpalma.comandas.application.usecase.GenerarReportePdfJornadaUseCase;
 import com.agustinpalma.comandas.application.usecase.ObtenerEstadoJornadaUseCase;
 import com.agustinpalma.comandas.application.usecase.ObtenerReporteVentasUseCase;
 import com.agustinpalma.comandas.application.usecase.RegistrarEgresoUseCase;
 import com.agustinpalma.comandas.application.usecase.RegistrarIngresoUseCase;
 import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaEgresoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
 
+import jakarta.validation.Valid;
 import org.springframework.format.annotation.DateTimeFormat;
 import org.springframework.http.HttpHeaders;
 import org.springframework.http.HttpStatus;
@@ -37,6 +56,7 @@ import org.springframework.http.ResponseEntity;
 import org.springframework.web.bind.annotation.*;
 
 import java.time.LocalDate;
+import java.time.YearMonth;
 import java.util.List;
 import java.util.UUID;
 
@@ -44,6 +64,8 @@ import java.util.UUID;
  * Controller REST para operaciones de caja.
  * Expone endpoints para registrar egresos y generar reportes diarios.
  * No contiene lógica de negocio, solo coordina entre HTTP y casos de uso.
+ *
+ * HU-169: Los reportes exigen el permiso VER_REPORTES.
  */
 @RestController
 @RequestMapping("/api/caja")
@@ -61,6 +83,14 @@ public class CajaController {
     private final CorregirPedidoCerradoUseCase corregirPedidoCerradoUseCase;
     private final ConsultarHistorialJornadasUseCase consultarHistorialJornadasUseCase;
     private final ObtenerReporteVentasUseCase obtenerReporteVentasUseCase;
+    private final AbrirTurnoUseCase abrirTurnoUseCase;
+    private final CerrarTurnoUseCase cerrarTurnoUseCase;
+    private final ConsultarTurnosJornadaUseCase consultarTurnosJornadaUseCase;
+    private final GestionarDevolucionesUseCase gestionarDevolucionesUseCase;
+    private final ConsultarDiferenciasCajaUseCase consultarDiferenciasCajaUseCase;
+    private final ConsultarReporteEgresosUseCase consultarReporteEgresosUseCase;
+    private final GestionarCajasUseCase gestionarCajasUseCase;
+    private final GestionarPermisosUseCase gestionarPermisosUseCase;
 
     public CajaController(
             LocalContextProvider localContextProvider,
@@ -74,7 +104,15 @@ public class CajaController {
             ConsultarPedidoCerradoUseCase consultarPedidoCerradoUseCase,
             CorregirPedidoCerradoUseCase corregirPedidoCerradoUseCase,
             ConsultarHistorialJornadasUseCase consultarHistorialJornadasUseCase,
-            ObtenerReporteVentasUseCase obtenerReporteVentasUseCase
+            ObtenerReporteVentasUseCase obtenerReporteVentasUseCase,
+            AbrirTurnoUseCase abrirTurnoUseCase,
+            CerrarTurnoUseCase cerrarTurnoUseCase,
+            ConsultarTurnosJornadaUseCase consultarTurnosJornadaUseCase,
+            GestionarDevolucionesUseCase gestionarDevolucionesUseCase,
+            ConsultarDiferenciasCajaUseCase consultarDiferenciasCajaUseCase,
+            ConsultarReporteEgresosUseCase consultarReporteEgresosUseCase,
+            GestionarCajasUseCase gestionarCajasUseCase,
+            GestionarPermisosUseCase gestionarPermisosUseCase
     ) {
         this.localContextProvider = localContextProvider;
         this.obtenerEstadoJornadaUseCase = obtenerEstadoJornadaUseCase;
@@ -88,6 +126,14 @@ public class CajaController {
         this.corregirPedidoCerradoUseCase = corregirPedidoCerradoUseCase;
         this.consultarHistorialJornadasUseCase = consultarHistorialJornadasUseCase;
         this.obtenerReporteVentasUseCase = obtenerReporteVentasUseCase;
+        this.abrirTurnoUseCase = abrirTurnoUseCase;
+        this.cerrarTurnoUseCase = cerrarTurnoUseCase;
+        this.consultarTurnosJornadaUseCase = consultarTurnosJornadaUseCase;
+        this.gestionarDevolucionesUseCase = gestionarDevolucionesUseCase;
+        this.consultarDiferenciasCajaUseCase = consultarDiferenciasCajaUseCase;
+        this.consultarReporteEgresosUseCase = consultarReporteEgresosUseCase;
+        this.gestionarCajasUseCase = gestionarCajasUseCase;
+        this.gestionarPermisosUseCase = gestionarPermisosUseCase;
     }
 
     /**
@@ -133,17 +179,37 @@ public class CajaController {
      * 
      * POST /api/caja/egresos
      * 
-     * @param body JSON con monto y descripción del egreso
+     * @param body JSON con monto, descripción y categoría (HU-130, opcional) del egreso
      * @return 201 CREATED con los datos del movimiento registrado
      */
     @PostMapping("/egresos")
     public ResponseEntity<EgresoResponse> registrarEgreso(@RequestBody EgresoRequestBody body) {
         LocalId localId = localContextProvider.getCurrentLocalId();
 
-        EgresoResponse response = registrarEgresoUseCase.ejecutar(localId, body.monto(), body.descripcion());
+        CategoriaEgresoId categoriaId = body.categoriaId() != null && !body.categoriaId().isBlank()
+            ? CategoriaEgresoId.from(body.categoriaId())
+            : null;
+        EgresoResponse response = registrarEgresoUseCase.ejecutar(localId, body.monto(), body.descripcion(), categoriaId);
         return ResponseEntity.status(HttpStatus.CREATED).body(response);
     }
 
+    /**
+     * HU-130: Egresos del mes por categoría, contra el mes anterior.
+     *
+     * GET /api/caja/egresos/reporte-mensual?periodo=2026-03
+     *
+     * @return 200 OK con el total por categoría de ambos meses y la variación
+     */
+    @GetMapping("/egresos/reporte-mensual")
+    public ResponseEntity<ReporteEgresosMensualResponse> reporteMensualEgresos(
+            @RequestParam @DateTimeFormat(pattern = "yyyy-MM") YearMonth periodo
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        gestionarPermisosUseCase.exigir(localId, Permiso.VER_REPORTES);
+
+        return ResponseEntity.ok(consultarReporteEgresosUseCase.ejecutar(localId, periodo));
+    }
+
     /**
      * Registra un ingreso manual de caja.
      * 
@@ -176,6 +242,7 @@ public class CajaController {
             @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate fecha
     ) {
         LocalId localId = localContextProvider.getCurrentLocalId();
+        gestionarPermisosUseCase.exigir(localId, Permiso.VER_REPORTES);
 
         ReporteCajaResponse response = generarReporteCajaUseCase.ejecutar(localId, fecha);
         return ResponseEntity.ok(response);
@@ -193,7 +260,12 @@ public class CajaController {
      * - Cierre entre 00:00 y 05:59 → jornada del día anterior (turno noche)
      * - Cierre a partir de 06:00 → jornada del día actual
      * 
-     * @return 200 OK con el ID de la jornada cerrada
+     * HU-106: Si la jornada se dividió en turnos, la respuesta incluye el
+     * arqueo de cada uno y la diferencia total consolidada.
+     *
+     * HU-159: También el arqueo de cada caja y el efectivo de todos los cajones.
+     *
+     * @return 200 OK con el ID de la jornada cerrada, sus turnos y sus cajas
      * @throws MesasAbiertasException → 400 Bad Request si hay mesas abiertas
      * @throws JornadaYaCerradaException → 409 Conflict si la jornada ya fue cerrada
      */
@@ -202,7 +274,107 @@ public class CajaController {
         LocalId localId = localContextProvider.getCurrentLocalId();
 
         JornadaCajaId jornadaId = cerrarJornadaUseCase.ejecutar(localId);
-        return ResponseEntity.ok(new CierreJornadaResponse(jornadaId.getValue()));
+        return ResponseEntity.ok(consultarTurnosJornadaUseCase.consolidar(jornadaId, localId)
+            .conCajas(gestionarCajasUseCase.arqueo(jornadaId, localId)));
+    }
+
+    // ─── HU-106: Turnos de caja ───────────────────────────────────────────────
+
+    /**
+     * Lista los turnos de la jornada abierta.
+     *
+     * GET /api/caja/turnos
+     *
+     * @return 200 OK con los turnos en orden de apertura (vacío si no hay jornada)
+     */
+    @GetMapping("/turnos")
+    public ResponseEntity<List<TurnoCajaResponse>> listarTurnos() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+
+        return ResponseEntity.ok(consultarTurnosJornadaUseCase.ejecutar(localId));
+    }
+
+    /**
+     * Abre un turno dentro de la jornada en curso.
+     *
+     * POST /api/caja/turnos/abrir
+     *
+     * @param body JSON con el tipo de turno y el efectivo inicial del cajón
+     * @return 201 CREATED con el turno abierto
+     */
+    @PostMapping("/turnos/abrir")
+    public ResponseEntity<TurnoCajaResponse> abrirTurno(@RequestBody AbrirTurnoRequest body) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+
+        TurnoCajaResponse response = abrirTurnoUseCase.ejecutar(localId, body.tipo(), body.montoInicial());
+        return ResponseEntity.status(HttpStatus.CREATED).body(response);
+    }
+
+    /**
+     * Cierra el turno abierto con el efectivo contado por el cajero.
+     *
+     * POST /api/caja/turnos/cerrar
+     *
+     * HU-123: Acepta el conteo por denominación en lugar del total.
+     * HU-129: Si la caja no cierra, el body debe indicar el responsable del turno.
+     *
+     * @param body JSON con el efectivo declarado o el conteo del cajón
+     * @return 200 OK con el arqueo del turno (esperado, declarado y diferencia)
+     */
+    @PostMapping("/turnos/cerrar")
+    public ResponseEntity<TurnoCajaResponse> cerrarTurno(@RequestBody CerrarTurnoRequest body) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+
+        TurnoCajaResponse response = cerrarTurnoUseCase.ejecutar(localId, body);
+        return ResponseEntity.ok(response);
+    }
+
+    /**
+     * HU-123: Saldo teórico del turno abierto, para el asistente de conteo.
+     *
+     * GET /api/caja/turnos/saldo-teorico
+     *
+     * @return 200 OK con el efectivo esperado en el cajón
+     */
+    @GetMapping("/turnos/saldo-teorico")
+    public ResponseEntity<SaldoTeoricoTurnoResponse> saldoTeoricoTurno() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+
+        return ResponseEntity.ok(cerrarTurnoUseCase.calcularSaldoTeorico(localId));
+    }
+
+    /**
+     * HU-129: Histórico de faltantes y sobrantes por cajero.
+     *
+     * GET /api/caja/turnos/diferencias?desde=2026-03-01&hasta=2026-03-31
+     *
+     * @return 200 OK con el resumen por responsable y el detalle de los turnos que no cerraron
+     */
+    @GetMapping("/turnos/diferencias")
+    public ResponseEntity<ReporteDiferenciasCajaResponse> reporteDiferencias(
+            @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate desde,
+            @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate hasta
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+
+        return ResponseEntity.ok(consultarDiferenciasCajaUseCase.ejecutar(localId, desde, hasta));
+    }
+
+    /**
+     * Consolidado de turnos de una jornada (abierta o histórica).
+     *
+     * GET /api/caja/jornadas/{jornadaId}/turnos
+     *
+     * @param jornadaId UUID de la jornada
+     * @return 200 OK con los turnos, la diferencia total y (HU-159) el arqueo de cada caja
+     */
+    @GetMapping("/jornadas/{jornadaId}/turnos")
+    public ResponseEntity<CierreJornadaResponse> consultarTurnosJornada(@PathVariable UUID jornadaId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+
+        JornadaCajaId id = new JornadaCajaId(jornadaId);
+        return ResponseEntity.ok(consultarTurnosJornadaUseCase.consolidar(id, localId)
+            .conCajas(gestionarCajasUseCase.arqueo(id, localId)));
     }
 
     /**
@@ -252,6 +424,39 @@ public class CajaController {
         return ResponseEntity.ok(response);
     }
 
+    /**
+     * HU-121: Devoluciones registradas sobre un pedido cerrado y saldo disponible.
+     *
+     * GET /api/caja/pedidos/{pedidoId}/devoluciones
+     */
+    @GetMapping("/pedidos/{pedidoId}/devoluciones")
+    public ResponseEntity<DevolucionesPedidoResponse> listarDevoluciones(@PathVariable UUID pedidoId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarDevolucionesUseCase.listar(localId, new PedidoId(pedidoId)));
+    }
+
+    /**
+     * HU-121: Registra una devolución post-cierre autorizada por un encargado.
+     *
+     * POST /api/caja/pedidos/{pedidoId}/devoluciones
+     *
+     * El pedido no cambia: se genera un egreso asociado que se descuenta de
+     * las ventas del día en el cierre.
+     *
+     * @return 201 CREATED con las devoluciones actualizadas del pedido
+     */
+    @PostMapping("/pedidos/{pedidoId}/devoluciones")
+    public ResponseEntity<DevolucionesPedidoResponse> registrarDevolucion(
+            @PathVariable UUID pedidoId,
+            @Valid @RequestBody DevolucionRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        DevolucionesPedidoResponse response = gestionarDevolucionesUseCase.registrar(
+            localId, new PedidoId(pedidoId), request
+        );
+        return ResponseEntity.status(HttpStatus.CREATED).body(response);
+    }
+
     /**
      * Consulta el historial de jornadas cerradas por rango de fechas operativas.
      * 
@@ -289,6 +494,7 @@ public class CajaController {
     @GetMapping("/jornadas/{jornadaId}/reporte-pdf")
     public ResponseEntity<byte[]> descargarReportePdf(@PathVariable UUID jornadaId) {
         LocalId localId = localContextProvider.getCurrentLocalId();
+        gestionarPermisosUseCase.exigir(localId, Permiso.VER_REPORTES);
 
         byte[] pdf = generarReportePdfJornadaUseCase.ejecutar(
             new JornadaCajaId(jornadaId), localId
@@ -307,7 +513,7 @@ public class CajaController {
     /**
      * Reporte de ventas por producto para una fecha operativa.
      *
-     * GET /api/caja/reportes/productos?fecha=YYYY-MM-DD
+     * GET /api/caja/reportes/productos?fecha=YYYY-MM-DD[&desglosarCombos=true]
      *
      * Retorna el desglose de productos vendidos agrupando cantidades
      * y sumando totales, basándose únicamente en pedidos CERRADOS.
@@ -315,15 +521,18 @@ public class CajaController {
      * Es una consulta de analytics de solo lectura — no modifica estado.
      *
      * @param fecha fecha operativa del reporte en formato ISO
+     * @param desglosarCombos HU-103: si es true, los combos se abren en sus componentes
      * @return 200 OK con la lista de productos vendidos
      */
     @GetMapping("/reportes/productos")
     public ResponseEntity<List<ProductoVendidoReporte>> obtenerReporteVentasProductos(
-            @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate fecha
+            @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate fecha,
+            @RequestParam(defaultValue = "false") boolean desglosarCombos
     ) {
         LocalId localId = localContextProvider.getCurrentLocalId();
+        gestionarPermisosUseCase.exigir(localId, Permiso.VER_REPORTES);
 
-        List<ProductoVendidoReporte> reporte = obtenerReporteVentasUseCase.ejecutar(localId, fecha);
+        List<ProductoVendidoReporte> reporte = obtenerReporteVentasUseCase.ejecutar(localId, fecha, desglosarCombos);
         return ResponseEntity.ok(reporte);
     }
 }
@@ -0,0 +1,65 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.AbrirCajaRequest;
+import com.agustinpalma.comandas.application.dto.CajaRequest;
+import com.agustinpalma.comandas.application.dto.CajaResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.GestionarCajasUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.util.List;
+
+/**
+ * Controller REST de las cajas del local.
+ * HU-159: la barra, el mostrador y el salón cobran cada uno con su cajón.
+ *
+ * La terminal que se registra como caja guarda el id y lo manda en el
+ * encabezado X-Caja-Id de cada request: lo que cobra queda a su nombre.
+ *
+ * Endpoints:
+ * - GET  /api/caja/cajas              -> Cajas del local con su apertura en la jornada
+ * - POST /api/caja/cajas              -> Registrar esta terminal como caja
+ * - POST /api/caja/cajas/{id}/abrir   -> Declarar el fondo inicial en la jornada
+ *
+ * El arqueo de cada caja viene en el cierre de jornada (POST /api/caja/cierre-jornada).
+ */
+@RestController
+@RequestMapping("/api/caja/cajas")
+public class CajasController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarCajasUseCase gestionarCajasUseCase;
+
+    public CajasController(
+        LocalContextProvider localContextProvider,
+        GestionarCajasUseCase gestionarCajasUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarCajasUseCase = gestionarCajasUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<List<CajaResponse>> listar() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarCajasUseCase.listar(localId));
+    }
+
+    @PostMapping
+    public ResponseEntity<CajaResponse> registrar(@Valid @RequestBody CajaRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED)
+            .body(gestionarCajasUseCase.registrar(localId, request.nombre()));
+    }
+
+    @PostMapping("/{cajaId}/abrir")
+    public ResponseEntity<CajaResponse> abrir(@PathVariable String cajaId, @RequestBody AbrirCajaRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED)
+            .body(gestionarCajasUseCase.abrir(localId, CajaId.from(cajaId), request.montoInicial()));
+    }
+}
@@ -0,0 +1,66 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.CartaDigitalResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarCartaDigitalUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.infrastructure.adapter.EnlaceAutopedidoService;
+import org.springframework.http.CacheControl;
+import org.springframework.http.MediaType;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.util.Map;
+
+/**
+ * Controller REST de la carta digital.
+ * HU-127: carta generada desde el catálogo y QR imprimible por mesa.
+ *
+ * Endpoints:
+ * - GET /api/carta?mesa={mesaId}         -> Carta del momento (lo consulta menu.html)
+ * - GET /api/carta/mesas/{mesaId}/enlace -> URL de la carta digital de la mesa
+ * - GET /api/carta/mesas/{mesaId}/qr     -> QR de la carta digital de la mesa en PNG
+ *
+ * Las fotos se sirven desde /api/productos/{id}/foto.
+ */
+@RestController
+@RequestMapping("/api/carta")
+public class CartaController {
+
+    private final LocalContextProvider localContextProvider;
+    private final ConsultarCartaDigitalUseCase consultarCartaDigitalUseCase;
+    private final EnlaceAutopedidoService enlaceService;
+
+    public CartaController(
+        LocalContextProvider localContextProvider,
+        ConsultarCartaDigitalUseCase consultarCartaDigitalUseCase,
+        EnlaceAutopedidoService enlaceService
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.consultarCartaDigitalUseCase = consultarCartaDigitalUseCase;
+        this.enlaceService = enlaceService;
+    }
+
+    @GetMapping
+    public ResponseEntity<CartaDigitalResponse> obtenerCarta(@RequestParam(required = false) String mesa) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        MesaId mesaId = mesa == null || mesa.isBlank() ? null : MesaId.from(mesa);
+        // Sin caché: la página se refresca sola para mostrar precios y 86 al día
+        return ResponseEntity.ok()
+            .cacheControl(CacheControl.noStore())
+            .body(consultarCartaDigitalUseCase.ejecutar(localId, mesaId));
+    }
+
+    @GetMapping("/mesas/{mesaId}/enlace")
+    public ResponseEntity<Map<String, String>> obtenerEnlace(@PathVariable String mesaId) {
+        return ResponseEntity.ok(Map.of("url", enlaceService.urlCartaDigital(MesaId.from(mesaId))));
+    }
+
+    @GetMapping(value = "/mesas/{mesaId}/qr", produces = MediaType.IMAGE_PNG_VALUE)
+    public ResponseEntity<byte[]> obtenerQr(@PathVariable String mesaId) {
+        return ResponseEntity.ok()
+            .cacheControl(CacheControl.noStore())
+            .body(enlaceService.generarQrCartaDigitalPng(MesaId.from(mesaId)));
+    }
+}
@@ -0,0 +1,61 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.CategoriaEgresoRequest;
+import com.agustinpalma.comandas.application.dto.CategoriaEgresoResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.GestionarCategoriasEgresoUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaEgresoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.util.List;
+
+/**
+ * Controller REST de categorías de egresos de caja.
+ * HU-130: limpieza, mercadería, mantenimiento, adelantos de sueldo... configurables por local.
+ *
+ * Endpoints:
+ * - GET  /api/caja/categorias-egreso        -> Categorías del local (activas e inactivas)
+ * - POST /api/caja/categorias-egreso        -> Alta de categoría
+ * - PUT  /api/caja/categorias-egreso/{id}   -> Renombrar / activar / desactivar
+ */
+@RestController
+@RequestMapping("/api/caja/categorias-egreso")
+public class CategoriaEgresoController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarCategoriasEgresoUseCase gestionarCategoriasEgresoUseCase;
+
+    public CategoriaEgresoController(
+        LocalContextProvider localContextProvider,
+        GestionarCategoriasEgresoUseCase gestionarCategoriasEgresoUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarCategoriasEgresoUseCase = gestionarCategoriasEgresoUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<List<CategoriaEgresoResponse>> listar() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarCategoriasEgresoUseCase.listar(localId));
+    }
+
+    @PostMapping
+    public ResponseEntity<CategoriaEgresoResponse> crear(@Valid @RequestBody CategoriaEgresoRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED).body(gestionarCategoriasEgresoUseCase.crear(localId, request));
+    }
+
+    @PutMapping("/{categoriaId}")
+    public ResponseEntity<CategoriaEgresoResponse> actualizar(
+        @PathVariable String categoriaId,
+        @Valid @RequestBody CategoriaEgresoRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(
+            gestionarCategoriasEgresoUseCase.actualizar(localId, CategoriaEgresoId.from(categoriaId), request));
+    }
+}
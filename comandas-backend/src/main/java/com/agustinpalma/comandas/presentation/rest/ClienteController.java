@@ -0,0 +1,147 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.ClienteRequest;
+import com.agustinpalma.comandas.application.dto.ClienteResponse;
+import com.agustinpalma.comandas.application.dto.HistorialClienteResponse;
+import com.agustinpalma.comandas.application.dto.MovimientoCuentaCorrienteResponse;
+import com.agustinpalma.comandas.application.dto.PagoClienteRequest;
+import com.agustinpalma.comandas.application.dto.PromoCumpleaniosRequest;
+import com.agustinpalma.comandas.application.dto.PromoCumpleaniosResponse;
+import com.agustinpalma.comandas.application.dto.ResumenCuentaClienteResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarClientesUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarHistorialClienteUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarPromoCumpleaniosUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarResumenCuentaClienteUseCase;
+import com.agustinpalma.comandas.application.usecase.CrearClienteUseCase;
+import com.agustinpalma.comandas.application.usecase.EditarClienteUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarPromoCumpleaniosUseCase;
+import com.agustinpalma.comandas.application.usecase.RegistrarPagoClienteUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.YearMonth;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Controller REST para clientes con cuenta corriente.
+ * HU-104: alta/edición de clientes, pagos de deuda y resumen mensual.
+ * HU-168: historial de consumo y promo de cumpleaños.
+ */
+@RestController
+@RequestMapping("/api/clientes")
+public class ClienteController {
+
+    private final LocalContextProvider localContextProvider;
+    private final ConsultarClientesUseCase consultarClientesUseCase;
+    private final CrearClienteUseCase crearClienteUseCase;
+    private final EditarClienteUseCase editarClienteUseCase;
+    private final RegistrarPagoClienteUseCase registrarPagoClienteUseCase;
+    private final ConsultarResumenCuentaClienteUseCase consultarResumenCuentaClienteUseCase;
+    private final ConsultarHistorialClienteUseCase consultarHistorialClienteUseCase;
+    private final ConsultarPromoCumpleaniosUseCase consultarPromoCumpleaniosUseCase;
+    private final GuardarPromoCumpleaniosUseCase guardarPromoCumpleaniosUseCase;
+
+    public ClienteController(
+        LocalContextProvider localContextProvider,
+        ConsultarClientesUseCase consultarClientesUseCase,
+        CrearClienteUseCase crearClienteUseCase,
+        EditarClienteUseCase editarClienteUseCase,
+        RegistrarPagoClienteUseCase registrarPagoClienteUseCase,
+        ConsultarResumenCuentaClienteUseCase consultarResumenCuentaClienteUseCase,
+        ConsultarHistorialClienteUseCase consultarHistorialClienteUseCase,
+        ConsultarPromoCumpleaniosUseCase consultarPromoCumpleaniosUseCase,
+        GuardarPromoCumpleaniosUseCase guardarPromoCumpleaniosUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.consultarClientesUseCase = consultarClientesUseCase;
+        this.crearClienteUseCase = crearClienteUseCase;
+        this.editarClienteUseCase = editarClienteUseCase;
+        this.registrarPagoClienteUseCase = registrarPagoClienteUseCase;
+        this.consultarResumenCuentaClienteUseCase = consultarResumenCuentaClienteUseCase;
+        this.consultarHistorialClienteUseCase = consultarHistorialClienteUseCase;
+        this.consultarPromoCumpleaniosUseCase = consultarPromoCumpleaniosUseCase;
+        this.guardarPromoCumpleaniosUseCase = guardarPromoCumpleaniosUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<List<ClienteResponse>> listarClientes() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarClientesUseCase.ejecutar(localId));
+    }
+
+    @PostMapping
+    public ResponseEntity<ClienteResponse> crearCliente(@Valid @RequestBody ClienteRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        ClienteResponse cliente = crearClienteUseCase.ejecutar(localId, request);
+        return ResponseEntity.status(HttpStatus.CREATED).body(cliente);
+    }
+
+    @PutMapping("/{id}")
+    public ResponseEntity<ClienteResponse> editarCliente(
+        @PathVariable UUID id, @Valid @RequestBody ClienteRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        ClienteResponse cliente = editarClienteUseCase.ejecutar(new ClienteId(id), localId, request);
+        return ResponseEntity.ok(cliente);
+    }
+
+    /**
+     * Registra un pago total o parcial de la deuda.
+     * Si el medio es EFECTIVO, el monto también entra a la caja del día.
+     */
+    @PostMapping("/{id}/pagos")
+    public ResponseEntity<MovimientoCuentaCorrienteResponse> registrarPago(
+        @PathVariable UUID id, @Valid @RequestBody PagoClienteRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        MovimientoCuentaCorrienteResponse pago = registrarPagoClienteUseCase.ejecutar(new ClienteId(id), localId, request);
+        return ResponseEntity.status(HttpStatus.CREATED).body(pago);
+    }
+
+    /**
+     * Resumen mensual de la cuenta (saldo anterior, cargos, pagos y saldo final).
+     *
+     * @param mes período en formato YYYY-MM
+     */
+    @GetMapping("/{id}/resumen")
+    public ResponseEntity<ResumenCuentaClienteResponse> obtenerResumen(
+        @PathVariable UUID id,
+        @RequestParam @DateTimeFormat(pattern = "yyyy-MM") YearMonth mes
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarResumenCuentaClienteUseCase.ejecutar(new ClienteId(id), localId, mes));
+    }
+
+    /**
+     * HU-168: Historial de consumo (pedidos cerrados, ticket promedio y favoritos).
+     */
+    @GetMapping("/{id}/historial")
+    public ResponseEntity<HistorialClienteResponse> obtenerHistorial(@PathVariable UUID id) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarHistorialClienteUseCase.ejecutar(new ClienteId(id), localId));
+    }
+
+    /**
+     * HU-168: Configuración de la promo de cumpleaños del local.
+     */
+    @GetMapping("/promo-cumpleanios")
+    public ResponseEntity<PromoCumpleaniosResponse> obtenerPromoCumpleanios() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarPromoCumpleaniosUseCase.ejecutar(localId));
+    }
+
+    @PutMapping("/promo-cumpleanios")
+    public ResponseEntity<PromoCumpleaniosResponse> guardarPromoCumpleanios(
+        @Valid @RequestBody PromoCumpleaniosRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(guardarPromoCumpleaniosUseCase.ejecutar(localId, request));
+    }
+}
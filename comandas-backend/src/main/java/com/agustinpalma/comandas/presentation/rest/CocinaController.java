@@ -0,0 +1,88 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.ComandaCocinaResponse;
+import com.agustinpalma.comandas.application.dto.EsperaEstimadaResponse;
+import com.agustinpalma.comandas.application.dto.ReporteTiemposCocinaResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteTiemposCocinaUseCase;
+import com.agustinpalma.comandas.application.usecase.EstimarEsperaCocinaUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarComandasCocinaUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.ComandaCocinaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.LocalDate;
+import java.util.List;
+
+/**
+ * Controller REST de la pantalla de cocina.
+ * HU-119: tiempos de cocina y alertas de demora.
+ * HU-136: recuperar la última comanda lista desde el bump bar.
+ * HU-138: espera estimada para prometer el retiro de un take away.
+ *
+ * Endpoints:
+ * - GET  /api/cocina/comandas                    -> Comandas en preparación (con demora calculada)
+ * - POST /api/cocina/comandas/{id}/lista         -> Marcar comanda como lista
+ * - POST /api/cocina/comandas/recuperar          -> Volver a preparación la última lista
+ * - GET  /api/cocina/reporte?desde&hasta         -> Tiempos promedio por franja horaria
+ * - GET  /api/cocina/espera-estimada             -> Espera de un pedido que se envía ahora
+ *
+ * Las comandas se generan al enviar a cocina (POST /api/mesas/{id}/enviar-cocina
+ * y POST /api/mesas/{id}/marchar); la reimpresión no genera comanda.
+ */
+@RestController
+@RequestMapping("/api/cocina")
+public class CocinaController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarComandasCocinaUseCase gestionarComandasCocinaUseCase;
+    private final ConsultarReporteTiemposCocinaUseCase consultarReporteTiemposCocinaUseCase;
+    private final EstimarEsperaCocinaUseCase estimarEsperaCocinaUseCase;
+
+    public CocinaController(
+        LocalContextProvider localContextProvider,
+        GestionarComandasCocinaUseCase gestionarComandasCocinaUseCase,
+        ConsultarReporteTiemposCocinaUseCase consultarReporteTiemposCocinaUseCase,
+        EstimarEsperaCocinaUseCase estimarEsperaCocinaUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarComandasCocinaUseCase = gestionarComandasCocinaUseCase;
+        this.consultarReporteTiemposCocinaUseCase = consultarReporteTiemposCocinaUseCase;
+        this.estimarEsperaCocinaUseCase = estimarEsperaCocinaUseCase;
+    }
+
+    @GetMapping("/comandas")
+    public ResponseEntity<List<ComandaCocinaResponse>> listarPendientes() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarComandasCocinaUseCase.listarPendientes(localId));
+    }
+
+    @PostMapping("/comandas/{comandaId}/lista")
+    public ResponseEntity<ComandaCocinaResponse> marcarLista(@PathVariable String comandaId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarComandasCocinaUseCase.marcarLista(localId, ComandaCocinaId.from(comandaId)));
+    }
+
+    @PostMapping("/comandas/recuperar")
+    public ResponseEntity<ComandaCocinaResponse> recuperarUltima() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarComandasCocinaUseCase.recuperarUltima(localId));
+    }
+
+    @GetMapping("/reporte")
+    public ResponseEntity<ReporteTiemposCocinaResponse> obtenerReporte(
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate desde,
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate hasta
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarReporteTiemposCocinaUseCase.ejecutar(localId, desde, hasta));
+    }
+
+    @GetMapping("/espera-estimada")
+    public ResponseEntity<EsperaEstimadaResponse> estimarEspera() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(estimarEsperaCocinaUseCase.ejecutar(localId));
+    }
+}
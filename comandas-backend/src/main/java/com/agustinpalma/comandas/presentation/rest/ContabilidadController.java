@@ -0,0 +1,80 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.PlanCuentasContableRequest;
+import com.agustinpalma.comandas.application.dto.PlanCuentasContableResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarPlanCuentasContableUseCase;
+import com.agustinpalma.comandas.application.usecase.ExportarContabilidadUseCase;
+import com.agustinpalma.comandas.application.usecase.ExportarLibroIvaVentasUseCase.ArchivoExportado;
+import com.agustinpalma.comandas.application.usecase.GuardarPlanCuentasContableUseCase;
+import com.agustinpalma.comandas.domain.model.DomainEnums.SistemaContable;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.HttpHeaders;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.MediaType;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.YearMonth;
+
+/**
+ * Controller REST de la exportación contable.
+ * HU-167: asientos del mes en el formato de importación de Tango, Xubio o Contabilium.
+ *
+ * Endpoints:
+ * - GET /api/contabilidad/cuentas   -> Cuentas asignadas a cada concepto
+ * - PUT /api/contabilidad/cuentas   -> Asignar las cuentas del plan del estudio
+ * - GET /api/contabilidad/exportar?periodo=2026-03&sistema=TANGO|XUBIO|CONTABILIUM -> Archivo de asientos
+ */
+@RestController
+@RequestMapping("/api/contabilidad")
+public class ContabilidadController {
+
+    private final LocalContextProvider localContextProvider;
+    private final ConsultarPlanCuentasContableUseCase consultarPlanCuentasContableUseCase;
+    private final GuardarPlanCuentasContableUseCase guardarPlanCuentasContableUseCase;
+    private final ExportarContabilidadUseCase exportarContabilidadUseCase;
+
+    public ContabilidadController(
+        LocalContextProvider localContextProvider,
+        ConsultarPlanCuentasContableUseCase consultarPlanCuentasContableUseCase,
+        GuardarPlanCuentasContableUseCase guardarPlanCuentasContableUseCase,
+        ExportarContabilidadUseCase exportarContabilidadUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.consultarPlanCuentasContableUseCase = consultarPlanCuentasContableUseCase;
+        this.guardarPlanCuentasContableUseCase = guardarPlanCuentasContableUseCase;
+        this.exportarContabilidadUseCase = exportarContabilidadUseCase;
+    }
+
+    @GetMapping("/cuentas")
+    public ResponseEntity<PlanCuentasContableResponse> obtenerCuentas() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarPlanCuentasContableUseCase.ejecutar(localId));
+    }
+
+    @PutMapping("/cuentas")
+    public ResponseEntity<PlanCuentasContableResponse> guardarCuentas(
+        @Valid @RequestBody PlanCuentasContableRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(guardarPlanCuentasContableUseCase.ejecutar(localId, request));
+    }
+
+    @GetMapping("/exportar")
+    public ResponseEntity<byte[]> exportar(
+        @RequestParam @DateTimeFormat(pattern = "yyyy-MM") YearMonth periodo,
+        @RequestParam SistemaContable sistema
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        ArchivoExportado archivo = exportarContabilidadUseCase.ejecutar(localId, periodo, sistema);
+
+        HttpHeaders headers = new HttpHeaders();
+        headers.setContentType(MediaType.parseMediaType(archivo.contentType()));
+        headers.setContentDispositionFormData("attachment", archivo.nombre());
+
+        return new ResponseEntity<>(archivo.contenido(), headers, HttpStatus.OK);
+    }
+}
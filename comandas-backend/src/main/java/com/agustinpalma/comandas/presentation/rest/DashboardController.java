@@ -0,0 +1,50 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.DashboardVentasResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarDashboardVentasUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarPermisosUseCase;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+/**
+ * Controller REST del tablero en vivo.
+ * HU-114: ventas de la jornada agregadas en el backend.
+ *
+ * Endpoints:
+ * - GET /api/dashboard/ventas -> Jornada en curso vs mismo día de la semana pasada
+ *
+ * No recibe fecha: siempre es la jornada operativa actual (el histórico
+ * está en el reporte de caja).
+ *
+ * HU-169: Exige el permiso VER_REPORTES.
+ */
+@RestController
+@RequestMapping("/api/dashboard")
+public class DashboardController {
+
+    private final LocalContextProvider localContextProvider;
+    private final ConsultarDashboardVentasUseCase consultarDashboardVentasUseCase;
+    private final GestionarPermisosUseCase gestionarPermisosUseCase;
+
+    public DashboardController(
+        LocalContextProvider localContextProvider,
+        ConsultarDashboardVentasUseCase consultarDashboardVentasUseCase,
+        GestionarPermisosUseCase gestionarPermisosUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.consultarDashboardVentasUseCase = consultarDashboardVentasUseCase;
+        this.gestionarPermisosUseCase = gestionarPermisosUseCase;
+    }
+
+    @GetMapping("/ventas")
+    public ResponseEntity<DashboardVentasResponse> ventas() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        gestionarPermisosUseCase.exigir(localId, Permiso.VER_REPORTES);
+        return ResponseEntity.ok(consultarDashboardVentasUseCase.ejecutar(localId));
+    }
+}
@@ -0,0 +1,33 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.DatosTransferenciaResponse;
+import com.agustinpalma.comandas.application.usecase.ConsultarDatosTransferenciaUseCase;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+/**
+ * Controller REST de los datos para cobrar por transferencia.
+ * HU-156: alias y CVU del local, para copiarlos desde el cobro.
+ *
+ * Endpoints:
+ * - GET /api/caja/datos-transferencia -> Titular, CUIT, alias y CVU
+ *
+ * Se configuran bajo app.context.local (alias-transferencia, cvu).
+ */
+@RestController
+@RequestMapping("/api/caja/datos-transferencia")
+public class DatosTransferenciaController {
+
+    private final ConsultarDatosTransferenciaUseCase consultarDatosTransferenciaUseCase;
+
+    public DatosTransferenciaController(ConsultarDatosTransferenciaUseCase consultarDatosTransferenciaUseCase) {
+        this.consultarDatosTransferenciaUseCase = consultarDatosTransferenciaUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<DatosTransferenciaResponse> obtener() {
+        return ResponseEntity.ok(consultarDatosTransferenciaUseCase.ejecutar());
+    }
+}
@@ -0,0 +1,38 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.infrastructure.adapter.SseNotificadorTiempoRealAdapter;
+import org.springframework.http.MediaType;
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RestController;
+import org.springframework.web.servlet.mvc.method.annotation.SseEmitter;
+
+/**
+ * Controller del canal en tiempo real entre terminales.
+ * HU-125: cada terminal se suscribe al abrir la app y recibe los eventos
+ * del local (por ahora, los cambios de disponibilidad de productos).
+ *
+ * Endpoints:
+ * - GET /api/eventos -> Stream text/event-stream
+ */
+@RestController
+@RequestMapping("/api/eventos")
+public class EventoController {
+
+    private final LocalContextProvider localContextProvider;
+    private final SseNotificadorTiempoRealAdapter notificadorTiempoReal;
+
+    public EventoController(
+        LocalContextProvider localContextProvider,
+        SseNotificadorTiempoRealAdapter notificadorTiempoReal
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.notificadorTiempoReal = notificadorTiempoReal;
+    }
+
+    @GetMapping(produces = MediaType.TEXT_EVENT_STREAM_VALUE)
+    public SseEmitter suscribir() {
+        return notificadorTiempoReal.suscribir(localContextProvider.getCurrentLocalId());
+    }
+}
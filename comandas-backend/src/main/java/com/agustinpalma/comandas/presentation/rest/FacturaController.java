@@ -0,0 +1,116 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.ContribuyenteResponse;
+import com.agustinpalma.comandas.application.dto.EmitirFacturaRequest;
+import com.agustinpalma.comandas.application.dto.FacturaResponse;
+import com.agustinpalma.comandas.application.dto.TicketVentaEscPosResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarContribuyenteUseCase;
+import com.agustinpalma.comandas.application.usecase.EmitirFacturaUseCase;
+import com.agustinpalma.comandas.application.usecase.ExportarLibroIvaVentasUseCase;
+import com.agustinpalma.comandas.application.usecase.ExportarLibroIvaVentasUseCase.ArchivoExportado;
+import com.agustinpalma.comandas.application.usecase.ExportarLibroIvaVentasUseCase.Formato;
+import com.agustinpalma.comandas.application.usecase.ImprimirFacturaUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import jakarta.validation.Valid;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.HttpHeaders;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.MediaType;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.YearMonth;
+
+/**
+ * Controller REST de facturación.
+ * HU-115: Factura A/B/C al cerrar un pedido, validando el CUIT del cliente.
+ *
+ * Endpoints:
+ * - GET  /api/facturas/contribuyentes/{cuit}  -> Valida CUIT/CUIL y sugiere la letra
+ * - POST /api/facturas                        -> Factura un pedido cerrado
+ * - GET  /api/facturas?pedidoId=...           -> Factura del pedido (204 si no fue facturado)
+ * - POST /api/facturas/{id}/autorizar         -> Reintenta el CAE de una factura pendiente
+ * - POST /api/facturas/{id}/imprimir          -> Buffer ESC/POS (reimpresion=true para la copia)
+ * - GET  /api/facturas/libro-iva-ventas?periodo=2026-03&formato=CSV|TXT -> HU-116: libro IVA del mes
+ */
+@RestController
+@RequestMapping("/api/facturas")
+public class FacturaController {
+
+    private final LocalContextProvider localContextProvider;
+    private final ConsultarContribuyenteUseCase consultarContribuyenteUseCase;
+    private final EmitirFacturaUseCase emitirFacturaUseCase;
+    private final ImprimirFacturaUseCase imprimirFacturaUseCase;
+    private final ExportarLibroIvaVentasUseCase exportarLibroIvaVentasUseCase;
+
+    public FacturaController(
+        LocalContextProvider localContextProvider,
+        ConsultarContribuyenteUseCase consultarContribuyenteUseCase,
+        EmitirFacturaUseCase emitirFacturaUseCase,
+        ImprimirFacturaUseCase imprimirFacturaUseCase,
+        ExportarLibroIvaVentasUseCase exportarLibroIvaVentasUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.consultarContribuyenteUseCase = consultarContribuyenteUseCase;
+        this.emitirFacturaUseCase = emitirFacturaUseCase;
+        this.imprimirFacturaUseCase = imprimirFacturaUseCase;
+        this.exportarLibroIvaVentasUseCase = exportarLibroIvaVentasUseCase;
+    }
+
+    @GetMapping("/contribuyentes/{cuit}")
+    public ResponseEntity<ContribuyenteResponse> consultarContribuyente(@PathVariable String cuit) {
+        return ResponseEntity.ok(consultarContribuyenteUseCase.ejecutar(cuit));
+    }
+
+    @PostMapping
+    public ResponseEntity<FacturaResponse> emitir(@Valid @RequestBody EmitirFacturaRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED).body(emitirFacturaUseCase.emitir(localId, request));
+    }
+
+    @GetMapping
+    public ResponseEntity<FacturaResponse> buscarPorPedido(@RequestParam String pedidoId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return emitirFacturaUseCase.buscarPorPedido(localId, PedidoId.from(pedidoId))
+            .map(ResponseEntity::ok)
+            .orElseGet(() -> ResponseEntity.noContent().build());
+    }
+
+    @PostMapping("/{facturaId}/autorizar")
+    public ResponseEntity<FacturaResponse> autorizar(@PathVariable String facturaId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(emitirFacturaUseCase.reintentarAutorizacion(localId, FacturaId.from(facturaId)));
+    }
+
+    @PostMapping("/{facturaId}/imprimir")
+    public ResponseEntity<TicketVentaEscPosResponse> imprimir(
+        @PathVariable String facturaId,
+        @RequestParam(defaultValue = "false") boolean reimpresion
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        String base64 = imprimirFacturaUseCase.ejecutar(localId, FacturaId.from(facturaId), reimpresion);
+        return ResponseEntity.ok(new TicketVentaEscPosResponse(base64));
+    }
+
+    /**
+     * HU-116: Libro IVA ventas del mes para el contador.
+     * CSV para planilla o TXT del régimen de información (ZIP con comprobantes y alícuotas).
+     */
+    @GetMapping("/libro-iva-ventas")
+    public ResponseEntity<byte[]> exportarLibroIvaVentas(
+        @RequestParam @DateTimeFormat(pattern = "yyyy-MM") YearMonth periodo,
+        @RequestParam(defaultValue = "CSV") Formato formato
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        ArchivoExportado archivo = exportarLibroIvaVentasUseCase.ejecutar(localId, periodo, formato);
+
+        HttpHeaders headers = new HttpHeaders();
+        headers.setContentType(MediaType.parseMediaType(archivo.contentType()));
+        headers.setContentDispositionFormData("attachment", archivo.nombre());
+
+        return new ResponseEntity<>(archivo.contenido(), headers, HttpStatus.OK);
+    }
+}
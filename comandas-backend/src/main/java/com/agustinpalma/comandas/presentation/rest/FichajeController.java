@@ -0,0 +1,78 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.CorreccionFichajeRequest;
+import com.agustinpalma.comandas.application.dto.FichajeResponse;
+import com.agustinpalma.comandas.application.dto.MarcaFichajeResponse;
+import com.agustinpalma.comandas.application.dto.MarcarFichajeRequest;
+import com.agustinpalma.comandas.application.dto.ReporteHorasResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteHorasUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarFichajesUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.FichajeId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.YearMonth;
+
+/**
+ * Controller REST del fichaje de personal.
+ * HU-124: terminal de entrada/salida, reporte mensual y correcciones del dueño.
+ *
+ * Endpoints:
+ * - POST /api/fichajes/marcar                  -> Marca entrada o salida (PIN o tarjeta)
+ * - GET  /api/fichajes/reporte?periodo=2026-03 -> Horas por empleado en el mes
+ * - PUT  /api/fichajes/{id}                    -> Corrige un fichaje (PIN del dueño)
+ * - POST /api/fichajes                         -> Carga un fichaje a mano (PIN del dueño)
+ */
+@RestController
+@RequestMapping("/api/fichajes")
+public class FichajeController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarFichajesUseCase gestionarFichajesUseCase;
+    private final ConsultarReporteHorasUseCase consultarReporteHorasUseCase;
+
+    public FichajeController(
+        LocalContextProvider localContextProvider,
+        GestionarFichajesUseCase gestionarFichajesUseCase,
+        ConsultarReporteHorasUseCase consultarReporteHorasUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarFichajesUseCase = gestionarFichajesUseCase;
+        this.consultarReporteHorasUseCase = consultarReporteHorasUseCase;
+    }
+
+    @PostMapping("/marcar")
+    public ResponseEntity<MarcaFichajeResponse> marcar(@Valid @RequestBody MarcarFichajeRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarFichajesUseCase.marcar(localId, request));
+    }
+
+    @GetMapping("/reporte")
+    public ResponseEntity<ReporteHorasResponse> obtenerReporte(
+        @RequestParam @DateTimeFormat(pattern = "yyyy-MM") YearMonth periodo
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarReporteHorasUseCase.ejecutar(localId, periodo));
+    }
+
+    @PutMapping("/{fichajeId}")
+    public ResponseEntity<FichajeResponse> corregir(
+        @PathVariable String fichajeId,
+        @Valid @RequestBody CorreccionFichajeRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarFichajesUseCase.corregir(localId, FichajeId.from(fichajeId), request));
+    }
+
+    @PostMapping
+    public ResponseEntity<FichajeResponse> registrarManual(@Valid @RequestBody CorreccionFichajeRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED)
+            .body(gestionarFichajesUseCase.registrarManual(localId, request));
+    }
+}
@@ -0,0 +1,101 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.ComprobanteRequest;
+import com.agustinpalma.comandas.application.dto.GastoRequest;
+import com.agustinpalma.comandas.application.dto.GastoResponse;
+import com.agustinpalma.comandas.application.dto.ReporteGastosResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteGastosUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarGastosUseCase;
+import com.agustinpalma.comandas.domain.model.ComprobanteAdjunto;
+import com.agustinpalma.comandas.domain.model.DomainIds.GastoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.ContentDisposition;
+import org.springframework.http.HttpHeaders;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.MediaType;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.LocalDate;
+import java.time.YearMonth;
+import java.util.List;
+
+/**
+ * Controller REST de compras y gastos.
+ * HU-117: compras de mercadería, gastos fijos y reporte mensual contra ventas.
+ *
+ * Endpoints:
+ * - GET  /api/gastos?desde&hasta            -> Gastos con fecha de comprobante en el rango
+ * - POST /api/gastos                        -> Registra una compra o gasto
+ * - PUT  /api/gastos/{id}/comprobante       -> Adjunta o reemplaza el comprobante (Base64)
+ * - GET  /api/gastos/{id}/comprobante       -> Descarga el archivo del comprobante
+ * - GET  /api/gastos/reporte?periodo=2026-03 -> Gastos por categoría contra ventas del mes
+ */
+@RestController
+@RequestMapping("/api/gastos")
+public class GastoController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarGastosUseCase gestionarGastosUseCase;
+    private final ConsultarReporteGastosUseCase consultarReporteGastosUseCase;
+
+    public GastoController(
+        LocalContextProvider localContextProvider,
+        GestionarGastosUseCase gestionarGastosUseCase,
+        ConsultarReporteGastosUseCase consultarReporteGastosUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarGastosUseCase = gestionarGastosUseCase;
+        this.consultarReporteGastosUseCase = consultarReporteGastosUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<List<GastoResponse>> listar(
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate desde,
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate hasta
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarGastosUseCase.listar(localId, desde, hasta));
+    }
+
+    @PostMapping
+    public ResponseEntity<GastoResponse> registrar(@Valid @RequestBody GastoRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED).body(gestionarGastosUseCase.registrar(localId, request));
+    }
+
+    @PutMapping("/{gastoId}/comprobante")
+    public ResponseEntity<GastoResponse> adjuntarComprobante(
+        @PathVariable String gastoId,
+        @Valid @RequestBody ComprobanteRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarGastosUseCase.adjuntarComprobante(localId, GastoId.from(gastoId), request));
+    }
+
+    /**
+     * Se sirve inline para que el navegador muestre la foto o el PDF sin descargarlo.
+     */
+    @GetMapping("/{gastoId}/comprobante")
+    public ResponseEntity<byte[]> obtenerComprobante(@PathVariable String gastoId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        ComprobanteAdjunto comprobante = gestionarGastosUseCase.obtenerComprobante(localId, GastoId.from(gastoId));
+
+        HttpHeaders headers = new HttpHeaders();
+        headers.setContentType(MediaType.parseMediaType(comprobante.getContentType()));
+        headers.setContentDisposition(ContentDisposition.inline().filename(comprobante.getNombreArchivo()).build());
+
+        return new ResponseEntity<>(comprobante.getContenido(), headers, HttpStatus.OK);
+    }
+
+    @GetMapping("/reporte")
+    public ResponseEntity<ReporteGastosResponse> obtenerReporte(
+        @RequestParam @DateTimeFormat(pattern = "yyyy-MM") YearMonth periodo
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarReporteGastosUseCase.ejecutar(localId, periodo));
+    }
+}
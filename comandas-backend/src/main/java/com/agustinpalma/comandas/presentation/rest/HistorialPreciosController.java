@@ -0,0 +1,53 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.HistorialPreciosProductoResponse;
+import com.agustinpalma.comandas.application.dto.VariacionPreciosMenuResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarHistorialPreciosUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.YearMonth;
+
+/**
+ * Controller REST del historial de precios del catálogo.
+ * HU-146: cuándo cambió cada precio, quién lo cambió y cuánto subió el menú por mes.
+ *
+ * Endpoints:
+ * - GET /api/historial-precios/productos/{productoId}              -> Cambios de un producto
+ * - GET /api/historial-precios/variacion-mensual?desde=&hasta=     -> Variación promedio del menú (yyyy-MM)
+ */
+@RestController
+@RequestMapping("/api/historial-precios")
+public class HistorialPreciosController {
+
+    private final LocalContextProvider localContextProvider;
+    private final ConsultarHistorialPreciosUseCase consultarHistorialPreciosUseCase;
+
+    public HistorialPreciosController(
+        LocalContextProvider localContextProvider,
+        ConsultarHistorialPreciosUseCase consultarHistorialPreciosUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.consultarHistorialPreciosUseCase = consultarHistorialPreciosUseCase;
+    }
+
+    @GetMapping("/productos/{productoId}")
+    public ResponseEntity<HistorialPreciosProductoResponse> historialProducto(@PathVariable String productoId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(
+            consultarHistorialPreciosUseCase.historialProducto(localId, ProductoId.from(productoId)));
+    }
+
+    @GetMapping("/variacion-mensual")
+    public ResponseEntity<VariacionPreciosMenuResponse> variacionMensual(
+        @RequestParam @DateTimeFormat(pattern = "yyyy-MM") YearMonth desde,
+        @RequestParam @DateTimeFormat(pattern = "yyyy-MM") YearMonth hasta
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarHistorialPreciosUseCase.variacionMensual(localId, desde, hasta));
+    }
+}
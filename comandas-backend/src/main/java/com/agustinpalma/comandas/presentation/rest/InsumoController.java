@@ -0,0 +1,61 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.ActualizacionInsumoResponse;
+import com.agustinpalma.comandas.application.dto.InsumoRequest;
+import com.agustinpalma.comandas.application.dto.InsumoResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.GestionarInsumosUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.util.List;
+
+/**
+ * Controller REST de insumos.
+ * HU-118: materia prima con costo unitario para costear las recetas.
+ *
+ * Endpoints:
+ * - GET  /api/insumos        -> Insumos del local
+ * - POST /api/insumos        -> Alta de insumo
+ * - PUT  /api/insumos/{id}   -> Editar nombre / unidad / costo (devuelve los productos que quedaron a pérdida)
+ */
+@RestController
+@RequestMapping("/api/insumos")
+public class InsumoController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarInsumosUseCase gestionarInsumosUseCase;
+
+    public InsumoController(
+        LocalContextProvider localContextProvider,
+        GestionarInsumosUseCase gestionarInsumosUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarInsumosUseCase = gestionarInsumosUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<List<InsumoResponse>> listar() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarInsumosUseCase.listar(localId));
+    }
+
+    @PostMapping
+    public ResponseEntity<InsumoResponse> crear(@Valid @RequestBody InsumoRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED).body(gestionarInsumosUseCase.crear(localId, request));
+    }
+
+    @PutMapping("/{insumoId}")
+    public ResponseEntity<ActualizacionInsumoResponse> actualizar(
+        @PathVariable String insumoId,
+        @Valid @RequestBody InsumoRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarInsumosUseCase.actualizar(localId, InsumoId.from(insumoId), request));
+    }
+}
@@ -0,0 +1,135 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.InsumoResponse;
+import com.agustinpalma.comandas.application.dto.MermaRequest;
+import com.agustinpalma.comandas.application.dto.MermaResponse;
+import com.agustinpalma.comandas.application.dto.MovimientoInsumoResponse;
+import com.agustinpalma.comandas.application.dto.OrdenCompraSugeridaResponse;
+import com.agustinpalma.comandas.application.dto.ReporteMermasResponse;
+import com.agustinpalma.comandas.application.dto.ReposicionInsumoRequest;
+import com.agustinpalma.comandas.application.dto.TomaInventarioRequest;
+import com.agustinpalma.comandas.application.dto.TomaInventarioResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.GestionarMermasUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarReposicionUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarTomaInventarioUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TomaInventarioId;
+import jakarta.validation.Valid;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.LocalDate;
+import java.time.YearMonth;
+import java.util.List;
+
+/**
+ * Controller REST del inventario físico de insumos.
+ * HU-133: toma de inventario con reporte de diferencias valorizado.
+ * HU-134: órdenes de compra sugeridas por stock mínimo.
+ * HU-135: registro de mermas y reporte mensual valorizado.
+ *
+ * Endpoints:
+ * - GET  /api/inventario/tomas                          -> Tomas del local, la más reciente primero
+ * - GET  /api/inventario/tomas/{id}                     -> Reporte de diferencias de una toma
+ * - POST /api/inventario/tomas/previsualizar            -> Reporte del conteo sin ajustar el stock
+ * - POST /api/inventario/tomas                          -> Confirmar la toma y ajustar el stock
+ * - GET  /api/inventario/insumos/{insumoId}/movimientos -> Historial de stock de un insumo
+ * - PUT  /api/inventario/insumos/{insumoId}/reposicion  -> Stock mínimo y proveedor habitual
+ * - GET  /api/inventario/ordenes-compra                 -> Órdenes sugeridas por proveedor
+ * - GET  /api/inventario/mermas?desde&hasta             -> Mermas registradas en el rango
+ * - POST /api/inventario/mermas                         -> Registrar una merma y descontar el stock
+ * - GET  /api/inventario/mermas/reporte?periodo=2026-03 -> Merma valorizada del mes por categoría
+ */
+@RestController
+@RequestMapping("/api/inventario")
+public class InventarioController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarTomaInventarioUseCase gestionarTomaInventarioUseCase;
+    private final GestionarReposicionUseCase gestionarReposicionUseCase;
+    private final GestionarMermasUseCase gestionarMermasUseCase;
+
+    public InventarioController(
+        LocalContextProvider localContextProvider,
+        GestionarTomaInventarioUseCase gestionarTomaInventarioUseCase,
+        GestionarReposicionUseCase gestionarReposicionUseCase,
+        GestionarMermasUseCase gestionarMermasUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarTomaInventarioUseCase = gestionarTomaInventarioUseCase;
+        this.gestionarReposicionUseCase = gestionarReposicionUseCase;
+        this.gestionarMermasUseCase = gestionarMermasUseCase;
+    }
+
+    @GetMapping("/tomas")
+    public ResponseEntity<List<TomaInventarioResponse>> listarTomas() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarTomaInventarioUseCase.listar(localId));
+    }
+
+    @GetMapping("/tomas/{tomaId}")
+    public ResponseEntity<TomaInventarioResponse> consultarToma(@PathVariable String tomaId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarTomaInventarioUseCase.consultar(localId, TomaInventarioId.from(tomaId)));
+    }
+
+    @PostMapping("/tomas/previsualizar")
+    public ResponseEntity<TomaInventarioResponse> previsualizar(@Valid @RequestBody TomaInventarioRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarTomaInventarioUseCase.previsualizar(localId, request));
+    }
+
+    @PostMapping("/tomas")
+    public ResponseEntity<TomaInventarioResponse> confirmar(@Valid @RequestBody TomaInventarioRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED).body(gestionarTomaInventarioUseCase.confirmar(localId, request));
+    }
+
+    @GetMapping("/insumos/{insumoId}/movimientos")
+    public ResponseEntity<List<MovimientoInsumoResponse>> listarMovimientos(@PathVariable String insumoId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarTomaInventarioUseCase.listarMovimientos(localId, InsumoId.from(insumoId)));
+    }
+
+    @PutMapping("/insumos/{insumoId}/reposicion")
+    public ResponseEntity<InsumoResponse> definirReposicion(
+        @PathVariable String insumoId,
+        @Valid @RequestBody ReposicionInsumoRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarReposicionUseCase.definirReposicion(localId, InsumoId.from(insumoId), request));
+    }
+
+    @GetMapping("/ordenes-compra")
+    public ResponseEntity<List<OrdenCompraSugeridaResponse>> sugerirOrdenesCompra() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarReposicionUseCase.sugerirOrdenes(localId));
+    }
+
+    @GetMapping("/mermas")
+    public ResponseEntity<List<MermaResponse>> listarMermas(
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate desde,
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate hasta
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarMermasUseCase.listar(localId, desde, hasta));
+    }
+
+    @PostMapping("/mermas")
+    public ResponseEntity<MermaResponse> registrarMerma(@Valid @RequestBody MermaRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED).body(gestionarMermasUseCase.registrar(localId, request));
+    }
+
+    @GetMapping("/mermas/reporte")
+    public ResponseEntity<ReporteMermasResponse> reporteMermas(
+        @RequestParam @DateTimeFormat(pattern = "yyyy-MM") YearMonth periodo
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarMermasUseCase.reporteMensual(localId, periodo));
+    }
+}
@@ -0,0 +1,52 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.JornadaComercialRequest;
+import com.agustinpalma.comandas.application.dto.JornadaComercialResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarJornadaComercialUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarJornadaComercialUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+/**
+ * Controller REST del límite de la jornada comercial.
+ * HU-153: hora en que termina el día comercial (ej: 04:00 para un local que cierra a las 3).
+ *
+ * Endpoints:
+ * - GET /api/caja/jornada-comercial   -> Hora de corte vigente y jornada en curso
+ * - PUT /api/caja/jornada-comercial   -> Configurar la hora de corte
+ *
+ * La apertura y el cierre de caja y los reportes diarios cortan la jornada a esta hora.
+ */
+@RestController
+@RequestMapping("/api/caja/jornada-comercial")
+public class JornadaComercialController {
+
+    private final LocalContextProvider localContextProvider;
+    private final ConsultarJornadaComercialUseCase consultarJornadaComercialUseCase;
+    private final GuardarJornadaComercialUseCase guardarJornadaComercialUseCase;
+
+    public JornadaComercialController(
+        LocalContextProvider localContextProvider,
+        ConsultarJornadaComercialUseCase consultarJornadaComercialUseCase,
+        GuardarJornadaComercialUseCase guardarJornadaComercialUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.consultarJornadaComercialUseCase = consultarJornadaComercialUseCase;
+        this.guardarJornadaComercialUseCase = guardarJornadaComercialUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<JornadaComercialResponse> obtener() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarJornadaComercialUseCase.ejecutar(localId));
+    }
+
+    @PutMapping
+    public ResponseEntity<JornadaComercialResponse> guardar(@Valid @RequestBody JornadaComercialRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(guardarJornadaComercialUseCase.ejecutar(localId, request));
+    }
+}
@@ -0,0 +1,99 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.GenerarLinkPagoRequest;
+import com.agustinpalma.comandas.application.dto.LinkPagoResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.GestionarLinksPagoUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LinkPagoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import jakarta.validation.Valid;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.util.List;
+
+/**
+ * Controller REST de los links de pago para delivery.
+ * HU-128: el pedido pasa a cocina recién cuando se acredita el pago.
+ *
+ * Endpoints del salón:
+ * - POST /api/links-pago/mesas/{mesaId}        -> Genera el link por el total del pedido
+ * - GET  /api/links-pago/mesas/{mesaId}        -> Último link del pedido (204 si no tiene)
+ * - GET  /api/links-pago/pendientes            -> Links esperando el pago
+ * - POST /api/links-pago/verificar             -> Consulta a la pasarela todos los pendientes
+ * - POST /api/links-pago/{id}/verificar        -> Consulta a la pasarela un link
+ * - POST /api/links-pago/{id}/cancelar         -> Descarta el link
+ *
+ * Endpoint de la pasarela:
+ * - POST /api/links-pago/notificaciones/{id}   -> Aviso de pago (webhook); solo dispara la verificación
+ */
+@RestController
+@RequestMapping("/api/links-pago")
+public class LinkPagoController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarLinksPagoUseCase gestionarLinksPagoUseCase;
+
+    public LinkPagoController(
+        LocalContextProvider localContextProvider,
+        GestionarLinksPagoUseCase gestionarLinksPagoUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarLinksPagoUseCase = gestionarLinksPagoUseCase;
+    }
+
+    @PostMapping("/mesas/{mesaId}")
+    public ResponseEntity<LinkPagoResponse> generar(
+        @PathVariable String mesaId,
+        @Valid @RequestBody GenerarLinkPagoRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED)
+            .body(gestionarLinksPagoUseCase.generar(localId, MesaId.from(mesaId), request));
+    }
+
+    @GetMapping("/mesas/{mesaId}")
+    public ResponseEntity<LinkPagoResponse> consultarDeMesa(@PathVariable String mesaId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return gestionarLinksPagoUseCase.consultarDeMesa(localId, MesaId.from(mesaId))
+            .map(ResponseEntity::ok)
+            .orElseGet(() -> ResponseEntity.noContent().build());
+    }
+
+    @GetMapping("/pendientes")
+    public ResponseEntity<List<LinkPagoResponse>> listarPendientes() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarLinksPagoUseCase.listarPendientes(localId));
+    }
+
+    @PostMapping("/verificar")
+    public ResponseEntity<List<LinkPagoResponse>> verificarPendientes() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarLinksPagoUseCase.verificarPendientes(localId));
+    }
+
+    @PostMapping("/{linkId}/verificar")
+    public ResponseEntity<LinkPagoResponse> verificar(@PathVariable String linkId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarLinksPagoUseCase.verificar(localId, LinkPagoId.from(linkId)));
+    }
+
+    @PostMapping("/{linkId}/cancelar")
+    public ResponseEntity<LinkPagoResponse> cancelar(@PathVariable String linkId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarLinksPagoUseCase.cancelar(localId, LinkPagoId.from(linkId)));
+    }
+
+    /**
+     * La pasarela avisa a la URL de notificación del link, con un cuerpo que
+     * varía según el tipo de aviso. Se ignora: el estado se consulta a la pasarela.
+     */
+    @PostMapping("/notificaciones/{linkId}")
+    public ResponseEntity<Void> recibirNotificacion(@PathVariable String linkId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        gestionarLinksPagoUseCase.verificar(localId, LinkPagoId.from(linkId));
+        return ResponseEntity.ok().build();
+    }
+}
@@ -0,0 +1,76 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.AvisarClienteEnEsperaRequest;
+import com.agustinpalma.comandas.application.dto.ClienteEnEsperaRequest;
+import com.agustinpalma.comandas.application.dto.ClienteEnEsperaResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.GestionarListaEsperaUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteEnEsperaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.util.List;
+
+/**
+ * Controller REST de la lista de espera de mesas.
+ * HU-149: cuando el salón está lleno se anota al cliente y se le avisa por
+ * WhatsApp o SMS cuando se libera una mesa en la que entra su grupo.
+ *
+ * Endpoints:
+ * - GET  /api/lista-espera                -> Clientes esperando, con su espera y mesa sugerida
+ * - POST /api/lista-espera                -> Anotar un cliente
+ * - POST /api/lista-espera/{id}/avisar    -> Guardarle una mesa libre y armar el aviso
+ * - POST /api/lista-espera/{id}/sentar    -> El cliente ocupó su mesa
+ * - POST /api/lista-espera/{id}/cancelar  -> El cliente se fue
+ */
+@RestController
+@RequestMapping("/api/lista-espera")
+public class ListaEsperaController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarListaEsperaUseCase gestionarListaEsperaUseCase;
+
+    public ListaEsperaController(
+        LocalContextProvider localContextProvider,
+        GestionarListaEsperaUseCase gestionarListaEsperaUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarListaEsperaUseCase = gestionarListaEsperaUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<List<ClienteEnEsperaResponse>> listar() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarListaEsperaUseCase.listar(localId));
+    }
+
+    @PostMapping
+    public ResponseEntity<ClienteEnEsperaResponse> anotar(@Valid @RequestBody ClienteEnEsperaRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED).body(gestionarListaEsperaUseCase.anotar(localId, request));
+    }
+
+    @PostMapping("/{id}/avisar")
+    public ResponseEntity<ClienteEnEsperaResponse> avisar(
+        @PathVariable String id,
+        @Valid @RequestBody AvisarClienteEnEsperaRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarListaEsperaUseCase.avisar(localId, ClienteEnEsperaId.from(id), request));
+    }
+
+    @PostMapping("/{id}/sentar")
+    public ResponseEntity<ClienteEnEsperaResponse> sentar(@PathVariable String id) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarListaEsperaUseCase.sentar(localId, ClienteEnEsperaId.from(id)));
+    }
+
+    @PostMapping("/{id}/cancelar")
+    public ResponseEntity<ClienteEnEsperaResponse> cancelar(@PathVariable String id) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarListaEsperaUseCase.cancelar(localId, ClienteEnEsperaId.from(id)));
+    }
+}
@@ -0,0 +1,66 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.ListaPreciosRequest;
+import com.agustinpalma.comandas.application.dto.ListaPreciosResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarListasPreciosUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarPermisosUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarListaPreciosUseCase;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.util.List;
+
+/**
+ * Controller REST de listas de precios por canal.
+ * HU-108: un precio distinto para salón, take away y delivery.
+ *
+ * Endpoints:
+ * - GET /api/listas-precios          -> Una entrada por canal (configurada o no)
+ * - PUT /api/listas-precios/{canal}  -> Crear / reconfigurar la lista del canal
+ *
+ * El canal del pedido se elige al abrir la mesa (POST /api/mesas/{id}/abrir?canal=...).
+ *
+ * HU-169: Guardar una lista exige el permiso MODIFICAR_PRECIOS.
+ */
+@RestController
+@RequestMapping("/api/listas-precios")
+public class ListaPreciosController {
+
+    private final LocalContextProvider localContextProvider;
+    private final ConsultarListasPreciosUseCase consultarListasPreciosUseCase;
+    private final GuardarListaPreciosUseCase guardarListaPreciosUseCase;
+    private final GestionarPermisosUseCase gestionarPermisosUseCase;
+
+    public ListaPreciosController(
+        LocalContextProvider localContextProvider,
+        ConsultarListasPreciosUseCase consultarListasPreciosUseCase,
+        GuardarListaPreciosUseCase guardarListaPreciosUseCase,
+        GestionarPermisosUseCase gestionarPermisosUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.consultarListasPreciosUseCase = consultarListasPreciosUseCase;
+        this.guardarListaPreciosUseCase = guardarListaPreciosUseCase;
+        this.gestionarPermisosUseCase = gestionarPermisosUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<List<ListaPreciosResponse>> listar() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarListasPreciosUseCase.ejecutar(localId));
+    }
+
+    @PutMapping("/{canal}")
+    public ResponseEntity<ListaPreciosResponse> guardar(
+        @PathVariable CanalVenta canal,
+        @Valid @RequestBody ListaPreciosRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        gestionarPermisosUseCase.exigir(localId, Permiso.MODIFICAR_PRECIOS);
+        return ResponseEntity.ok(guardarListaPreciosUseCase.ejecutar(localId, canal, request));
+    }
+}
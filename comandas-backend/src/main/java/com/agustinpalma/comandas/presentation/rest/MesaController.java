@@ -2,13 +2,17 @@ package com.agustinpalma.comandas.presentation.rest;
 
 import com.agustinpalma.comandas.application.dto.AbrirMesaRequest;
 import com.agustinpalma.comandas.application.dto.AbrirMesaResponse;
+import com.agustinpalma.comandas.application.dto.CerrarMesaCommand;
 import com.agustinpalma.comandas.application.dto.CerrarMesaRequest;
 import com.agustinpalma.comandas.application.dto.CerrarMesaResponse;
 import com.agustinpalma.comandas.application.dto.ComandaImpresionResponse;
 import com.agustinpalma.comandas.application.dto.CrearMesaRequest;
 import com.agustinpalma.comandas.application.dto.DetallePedidoResponse;
+import com.agustinpalma.comandas.application.dto.DisposicionSalonRequest;
 import com.agustinpalma.comandas.application.dto.EnviarComandaResponse;
 import com.agustinpalma.comandas.application.dto.MesaResponse;
+import com.agustinpalma.comandas.application.dto.PagoRequest;
+import com.agustinpalma.comandas.application.dto.PagosACuentaResponse;
 import com.agustinpalma.comandas.application.dto.TicketImpresionResponse;
 import com.agustinpalma.comandas.application.dto.TicketVentaEscPosResponse;
 import com.agustinpalma.comandas.application.usecase.AbrirMesaUseCase;
@@ -19,10 +23,18 @@ import com.agustinpalma.comandas.application.usecase.CrearMesaUseCase;
 import com.agustinpalma.comandas.application.usecase.EliminarMesaUseCase;
 import com.agustinpalma.comandas.application.usecase.EnviarComandaCocinaUseCase;
 import com.agustinpalma.comandas.application.usecase.GenerarTicketVentaUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarPagosACuentaUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarDisposicionSalonUseCase;
+import com.agustinpalma.comandas.application.usecase.RegistrarPedidoCuentaUseCase;
 import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TiempoServicio;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
 import com.agustinpalma.comandas.infrastructure.mapper.TicketImpresionMapper;
+import jakarta.validation.Valid;
 import org.springframework.http.HttpStatus;
 import org.springframework.http.ResponseEntity;
 import org.springframework.web.bind.annotation.*;
@@ -48,6 +60,9 @@ public class MesaController {
     private final EnviarComandaCocinaUseCase enviarComandaCocinaUseCase;
     private final GenerarTicketVentaUseCase generarTicketVentaUseCase;
     private final TicketImpresionMapper ticketImpresionMapper;
+    private final GuardarDisposicionSalonUseCase guardarDisposicionSalonUseCase;
+    private final RegistrarPedidoCuentaUseCase registrarPedidoCuentaUseCase;
+    private final GestionarPagosACuentaUseCase gestionarPagosACuentaUseCase;
 
     public MesaController(
         LocalContextProvider localContextProvider,
@@ -59,7 +74,10 @@ public class MesaController {
         ConsultarDetallePedidoUseCase consultarDetallePedidoUseCase,
         EnviarComandaCocinaUseCase enviarComandaCocinaUseCase,
         GenerarTicketVentaUseCase generarTicketVentaUseCase,
-        TicketImpresionMapper ticketImpresionMapper
+        TicketImpresionMapper ticketImpresionMapper,
+        GuardarDisposicionSalonUseCase guardarDisposicionSalonUseCase,
+        RegistrarPedidoCuentaUseCase registrarPedidoCuentaUseCase,
+        GestionarPagosACuentaUseCase gestionarPagosACuentaUseCase
     ) {
         this.localContextProvider = localContextProvider;
         this.consultarMesasUseCase = consultarMesasUseCase;
@@ -71,6 +89,9 @@ public class MesaController {
         this.enviarComandaCocinaUseCase = enviarComandaCocinaUseCase;
         this.generarTicketVentaUseCase = generarTicketVentaUseCase;
         this.ticketImpresionMapper = ticketImpresionMapper;
+        this.guardarDisposicionSalonUseCase = guardarDisposicionSalonUseCase;
+        this.registrarPedidoCuentaUseCase = registrarPedidoCuentaUseCase;
+        this.gestionarPagosACuentaUseCase = gestionarPagosACuentaUseCase;
     }
 
     /**
@@ -100,14 +121,26 @@ public class MesaController {
      * TODO: Implementar autenticación/autorización para obtener el localId del usuario logueado.
      *       Por ahora se usa un localId hardcodeado para permitir testing del endpoint.
      *
+     * HU-108: ?canal=SALON|TAKE_AWAY|DELIVERY define la lista de precios del pedido.
+     * Si se omite, el pedido es de salón.
+     * HU-111: ?mozoId=...&cubiertos=N asigna el mozo a cargo y los comensales.
+     *
      * @param mesaId ID de la mesa a abrir
+     * @param canal canal de venta (opcional)
+     * @param mozoId mozo a cargo (opcional)
+     * @param cubiertos cantidad de comensales (opcional)
      * @return información de la mesa abierta y el pedido creado
      */
     @PostMapping("/{mesaId}/abrir")
-    public ResponseEntity<AbrirMesaResponse> abrirMesa(@PathVariable String mesaId) {
+    public ResponseEntity<AbrirMesaResponse> abrirMesa(
+        @PathVariable String mesaId,
+        @RequestParam(required = false) CanalVenta canal,
+        @RequestParam(required = false) String mozoId,
+        @RequestParam(required = false) Integer cubiertos
+    ) {
         LocalId localId = localContextProvider.getCurrentLocalId();
 
-        AbrirMesaRequest request = new AbrirMesaRequest(mesaId);
+        AbrirMesaRequest request = new AbrirMesaRequest(mesaId, canal, mozoId, cubiertos);
         AbrirMesaResponse response = abrirMesaUseCase.ejecutar(localId, request);
 
         return ResponseEntity.status(HttpStatus.CREATED).body(response);
@@ -119,6 +152,7 @@ public class MesaController {
      * POST /api/mesas
      *
      * Request body: { "numero": 15 }
+     * HU-112: opcionalmente { "sector": "Vereda", "posicionX": 2, "posicionY": 0 }
      *
      * TODO: Implementar autenticación/autorización para obtener el localId del usuario logueado.
      *       Por ahora se usa un localId hardcodeado para permitir testing del endpoint.
@@ -133,11 +167,32 @@ public class MesaController {
     public ResponseEntity<MesaResponse> crearMesa(@RequestBody CrearMesaRequest request) {
         LocalId localId = localContextProvider.getCurrentLocalId();
 
-        MesaResponse response = crearMesaUseCase.ejecutar(localId, request.numero());
+        MesaResponse response = crearMesaUseCase.ejecutar(
+            localId, request.numero(), request.sector(), request.posicionX(), request.posicionY()
+        );
 
         return ResponseEntity.status(HttpStatus.CREATED).body(response);
     }
 
+    /**
+     * Guarda el mapa del salón: número, sector y celda de cada mesa.
+     *
+     * PUT /api/mesas/disposicion
+     *
+     * HU-112: Editor del mapa de mesas. Se aplica todo o nada.
+     *
+     * @param request ubicación de las mesas editadas
+     * @return todas las mesas del local con la disposición vigente
+     */
+    @PutMapping("/disposicion")
+    public ResponseEntity<List<MesaResponse>> guardarDisposicion(@Valid @RequestBody DisposicionSalonRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+
+        List<MesaResponse> mesas = guardarDisposicionSalonUseCase.ejecutar(localId, request);
+
+        return ResponseEntity.ok(mesas);
+    }
+
     /**
      * Elimina una mesa del local (solo si está LIBRE).
      *
@@ -208,6 +263,19 @@ public class MesaController {
      *   ]
      * }
      *
+     * HU-104: si algún pago es CUENTA_CORRIENTE, el body debe incluir "clienteId".
+     * HU-105: con "clienteId" el cliente suma puntos; "puntosACanjear" descuenta
+     * parte de su saldo y los pagos deben cubrir el total ya descontado.
+     * HU-111: "propina" registra la propina de la mesa; no se suma a los pagos.
+     * HU-132: "empleadoConsumoId" imputa los pagos A_CUENTA a un empleado y la
+     * respuesta trae el aviso si supera su tope mensual.
+     * HU-143: cada pago se registra con el recargo vigente de su medio; los
+     * montos de "pagos" cubren el total y el recargo se cobra encima.
+     * HU-160: si hubo pagos a cuenta, "pagos" cubre solo el saldo pendiente
+     * (puede venir vacío si ya está todo pagado).
+     * HU-165: la cabecera X-Pedido-Version es obligatoria; 409 si otra terminal
+     * cambió el pedido desde que se abrió el cobro.
+     *
      * TODO: Implementar autenticación/autorización para obtener el localId del usuario logueado.
      *
      * @param mesaId ID de la mesa a cerrar
@@ -217,12 +285,20 @@ public class MesaController {
     @PostMapping("/{mesaId}/cierre")
     public ResponseEntity<CerrarMesaResponse> cerrarMesa(
             @PathVariable String mesaId,
-            @RequestBody CerrarMesaRequest request
+            @RequestBody CerrarMesaRequest request,
+            @RequestHeader(PedidoController.CABECERA_VERSION) long versionPedido
     ) {
         LocalId localId = localContextProvider.getCurrentLocalId();
 
         MesaId id = MesaId.from(mesaId);
-        CerrarMesaResponse response = cerrarMesaUseCase.ejecutar(localId, id, request.pagos());
+        ClienteId clienteId = request.clienteId() != null ? ClienteId.from(request.clienteId()) : null;
+        MozoId empleadoId = request.empleadoConsumoId() != null ? MozoId.from(request.empleadoConsumoId()) : null;
+        CerrarMesaResponse response = cerrarMesaUseCase.ejecutar(CerrarMesaCommand.de(localId, id, request.pagos())
+            .conCliente(clienteId)
+            .conCanje(request.puntosACanjear())
+            .conPropina(request.propina())
+            .conEmpleado(empleadoId)
+            .conVersion(versionPedido));
 
         return ResponseEntity.ok(response);
     }
@@ -309,16 +385,43 @@ public class MesaController {
         return ResponseEntity.ok(response);
     }
 
+    /**
+     * Marcha el siguiente paso del pedido a cocina ("marchar postres").
+     *
+     * POST /api/mesas/{mesaId}/marchar?tiempo=ENTRADA|PRINCIPAL|POSTRE
+     *
+     * HU-110: Libera los ítems retenidos hasta ese paso y genera la comanda
+     * con el aviso de marcha. Sin tiempo, marcha el siguiente paso pendiente.
+     *
+     * @param mesaId ID de la mesa
+     * @param tiempo paso a marchar (opcional)
+     * @return buffer ESC/POS codificado en Base64 + metadata del envío
+     */
+    @PostMapping("/{mesaId}/marchar")
+    public ResponseEntity<EnviarComandaResponse> marcharTiempo(
+            @PathVariable String mesaId,
+            @RequestParam(required = false) TiempoServicio tiempo) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        MesaId id = MesaId.from(mesaId);
+
+        EnviarComandaResponse response = enviarComandaCocinaUseCase.marcharTiempo(localId, id, tiempo);
+
+        return ResponseEntity.ok(response);
+    }
+
     /**
      * Genera el ticket de venta en formato ESC/POS para impresión térmica.
      *
      * POST /api/mesas/{mesaId}/imprimir-ticket
      *
      * HU-29: Ticket de venta para impresora térmica.
-     * Operación de solo lectura — no cierra mesa, no modifica estado.
+     * No cierra la mesa ni toca montos ni estado.
      * Se usa POST porque genera un recurso (buffer ESC/POS), no es idempotente
      * (incluye timestamp actual en cada generación).
      *
+     * HU-113: Imprimir el ticket es el pedido de cuenta: reinicia el reloj
+     * de la alerta de mesas olvidadas.
+     *
      * @param mesaId ID de la mesa cuyo ticket se desea generar
      * @return buffer ESC/POS codificado en Base64
      */
@@ -328,7 +431,63 @@ public class MesaController {
         MesaId id = MesaId.from(mesaId);
 
         String base64 = generarTicketVentaUseCase.ejecutar(localId, id);
+        registrarPedidoCuentaUseCase.ejecutar(localId, id);
 
         return ResponseEntity.ok(new TicketVentaEscPosResponse(base64));
     }
+
+    /**
+     * HU-160: Pagos a cuenta de la mesa y saldo pendiente.
+     *
+     * GET /api/mesas/{mesaId}/pagos-a-cuenta
+     */
+    @GetMapping("/{mesaId}/pagos-a-cuenta")
+    public ResponseEntity<PagosACuentaResponse> consultarPagosACuenta(@PathVariable String mesaId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarPagosACuentaUseCase.consultar(localId, MesaId.from(mesaId)));
+    }
+
+    /**
+     * HU-160: Cobra a cuenta sobre la mesa abierta (un comensal paga lo suyo).
+     *
+     * POST /api/mesas/{mesaId}/pagos-a-cuenta
+     *
+     * Request body: el mismo pago que en el cierre.
+     * {"medio": "EFECTIVO", "monto": 4500}
+     *
+     * Errores:
+     * - 400 si el monto supera el saldo o el medio se cobra al cerrar
+     *   (cuenta corriente, consumo interno, tarjeta de regalo)
+     * - 409 si la mesa no tiene pedido abierto, o PEDIDO_MODIFICADO si otra
+     *   terminal cambió el pedido (cabecera X-Pedido-Version, HU-165)
+     */
+    @PostMapping("/{mesaId}/pagos-a-cuenta")
+    public ResponseEntity<PagosACuentaResponse> registrarPagoACuenta(
+            @PathVariable String mesaId,
+            @RequestBody PagoRequest request,
+            @RequestHeader(PedidoController.CABECERA_VERSION) long versionPedido
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        PagosACuentaResponse response = gestionarPagosACuentaUseCase.registrar(
+            localId, MesaId.from(mesaId), request, versionPedido);
+        return ResponseEntity.status(HttpStatus.CREATED).body(response);
+    }
+
+    /**
+     * HU-160: Anula un pago a cuenta cargado por error.
+     *
+     * DELETE /api/mesas/{mesaId}/pagos-a-cuenta/{indice}
+     *
+     * @param indice posición del pago en la lista de pagos a cuenta
+     * @param versionPedido versión del pedido en la terminal (HU-165)
+     */
+    @DeleteMapping("/{mesaId}/pagos-a-cuenta/{indice}")
+    public ResponseEntity<PagosACuentaResponse> anularPagoACuenta(
+            @PathVariable String mesaId,
+            @PathVariable int indice,
+            @RequestHeader(PedidoController.CABECERA_VERSION) long versionPedido
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarPagosACuentaUseCase.anular(localId, MesaId.from(mesaId), indice, versionPedido));
+    }
 }
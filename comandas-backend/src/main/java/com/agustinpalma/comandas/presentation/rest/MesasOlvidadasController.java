@@ -0,0 +1,56 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.AlertaMesasOlvidadasRequest;
+import com.agustinpalma.comandas.application.dto.AlertaMesasOlvidadasResponse;
+import com.agustinpalma.comandas.application.dto.MesasOlvidadasResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarMesasOlvidadasUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarAlertaMesasOlvidadasUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+/**
+ * Controller REST de la alerta de mesas olvidadas.
+ * HU-113: mesas ocupadas sin atención por más de N minutos.
+ *
+ * Endpoints:
+ * - GET /api/mesas-olvidadas                -> Configuración vigente + mesas a avisar
+ * - PUT /api/mesas-olvidadas/configuracion  -> Activar / cambiar el umbral
+ *
+ * La actividad se registra sola al cargar ítems, enviar comandas
+ * e imprimir el ticket (pedido de cuenta).
+ */
+@RestController
+@RequestMapping("/api/mesas-olvidadas")
+public class MesasOlvidadasController {
+
+    private final LocalContextProvider localContextProvider;
+    private final ConsultarMesasOlvidadasUseCase consultarMesasOlvidadasUseCase;
+    private final GuardarAlertaMesasOlvidadasUseCase guardarAlertaMesasOlvidadasUseCase;
+
+    public MesasOlvidadasController(
+        LocalContextProvider localContextProvider,
+        ConsultarMesasOlvidadasUseCase consultarMesasOlvidadasUseCase,
+        GuardarAlertaMesasOlvidadasUseCase guardarAlertaMesasOlvidadasUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.consultarMesasOlvidadasUseCase = consultarMesasOlvidadasUseCase;
+        this.guardarAlertaMesasOlvidadasUseCase = guardarAlertaMesasOlvidadasUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<MesasOlvidadasResponse> consultar() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarMesasOlvidadasUseCase.ejecutar(localId));
+    }
+
+    @PutMapping("/configuracion")
+    public ResponseEntity<AlertaMesasOlvidadasResponse> guardarConfiguracion(
+        @Valid @RequestBody AlertaMesasOlvidadasRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(guardarAlertaMesasOlvidadasUseCase.ejecutar(localId, request));
+    }
+}
@@ -0,0 +1,106 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.MozoRequest;
+import com.agustinpalma.comandas.application.dto.MozoResponse;
+import com.agustinpalma.comandas.application.dto.ReporteConsumoPersonalResponse;
+import com.agustinpalma.comandas.application.dto.ReporteMozosResponse;
+import com.agustinpalma.comandas.application.dto.TopeConsumoRequest;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteConsumoPersonalUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteMozosUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarMozosUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import jakarta.validation.Valid;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.LocalDate;
+import java.time.YearMonth;
+import java.util.List;
+
+/**
+ * Controller REST de mozos.
+ * HU-111: alta de mozos y reporte de desempeño para incentivos.
+ * HU-132: tope de consumo interno y resumen mensual para descontar del sueldo.
+ *
+ * Endpoints:
+ * - GET  /api/mozos                       -> Mozos del local (activos e inactivos)
+ * - POST /api/mozos                       -> Alta de mozo
+ * - PUT  /api/mozos/{id}                  -> Editar nombre / activar / desactivar
+ * - GET  /api/mozos/reporte?desde&hasta   -> Desempeño por mozo en el rango
+ * - PUT  /api/mozos/{id}/tope-consumo     -> Fijar o quitar el tope mensual de consumo interno
+ * - GET  /api/mozos/consumo-interno?periodo=YYYY-MM -> Consumo a cuenta por empleado contra su tope
+ *
+ * El mozo se asigna al abrir la mesa (POST /api/mesas/{id}/abrir?mozoId=...&cubiertos=...).
+ */
+@RestController
+@RequestMapping("/api/mozos")
+public class MozoController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarMozosUseCase gestionarMozosUseCase;
+    private final ConsultarReporteMozosUseCase consultarReporteMozosUseCase;
+    private final ConsultarReporteConsumoPersonalUseCase consultarReporteConsumoPersonalUseCase;
+
+    public MozoController(
+        LocalContextProvider localContextProvider,
+        GestionarMozosUseCase gestionarMozosUseCase,
+        ConsultarReporteMozosUseCase consultarReporteMozosUseCase,
+        ConsultarReporteConsumoPersonalUseCase consultarReporteConsumoPersonalUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarMozosUseCase = gestionarMozosUseCase;
+        this.consultarReporteMozosUseCase = consultarReporteMozosUseCase;
+        this.consultarReporteConsumoPersonalUseCase = consultarReporteConsumoPersonalUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<List<MozoResponse>> listar() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarMozosUseCase.listar(localId));
+    }
+
+    @PostMapping
+    public ResponseEntity<MozoResponse> crear(@Valid @RequestBody MozoRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED).body(gestionarMozosUseCase.crear(localId, request));
+    }
+
+    @PutMapping("/{mozoId}")
+    public ResponseEntity<MozoResponse> actualizar(
+        @PathVariable String mozoId,
+        @Valid @RequestBody MozoRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarMozosUseCase.actualizar(localId, MozoId.from(mozoId), request));
+    }
+
+    @GetMapping("/reporte")
+    public ResponseEntity<ReporteMozosResponse> obtenerReporte(
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate desde,
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate hasta
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarReporteMozosUseCase.ejecutar(localId, desde, hasta));
+    }
+
+    @PutMapping("/{mozoId}/tope-consumo")
+    public ResponseEntity<MozoResponse> definirTopeConsumo(
+        @PathVariable String mozoId,
+        @Valid @RequestBody TopeConsumoRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarMozosUseCase.definirTopeConsumo(localId, MozoId.from(mozoId), request));
+    }
+
+    @GetMapping("/consumo-interno")
+    public ResponseEntity<ReporteConsumoPersonalResponse> obtenerConsumoInterno(
+        @RequestParam @DateTimeFormat(pattern = "yyyy-MM") YearMonth periodo
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarReporteConsumoPersonalUseCase.ejecutar(localId, periodo));
+    }
+}
@@ -3,12 +3,18 @@ package com.agustinpalma.comandas.presentation.rest;
 import com.agustinpalma.comandas.application.dto.*;
 import com.agustinpalma.comandas.application.usecase.AgregarProductoUseCase;
 import com.agustinpalma.comandas.application.usecase.AplicarDescuentoManualUseCase;
+import com.agustinpalma.comandas.application.usecase.AsignarEntregaDeliveryUseCase;
 import com.agustinpalma.comandas.application.usecase.GestionarItemsPedidoUseCase;
+import com.agustinpalma.comandas.application.usecase.MarcarUrgenciaPedidoUseCase;
 import com.agustinpalma.comandas.application.usecase.ReabrirPedidoUseCase;
+import com.agustinpalma.comandas.application.usecase.VincularClientePedidoUseCase;
 import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
 import com.agustinpalma.comandas.domain.model.DomainEnums.ModoDescuento;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ItemPedidoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
 
@@ -29,29 +35,47 @@ import java.util.UUID;
  * HU-14: Aplicar descuentos manuales y reapertura de pedidos cerrados
  * HU-20: Eliminar producto de un pedido abierto
  * HU-21: Modificar cantidad de un producto en pedido abierto
+ * HU-110: Paso de servicio de un ítem (entrada, principal, postre)
+ * HU-137: Marca de urgencia del pedido o de un ítem
+ * HU-139: Dirección y zona de entrega de un delivery
+ * HU-165: Los cambios del ticket y los descuentos viajan con la versión del
+ *         pedido que ve la terminal (cabecera X-Pedido-Version, obligatoria);
+ *         si otra terminal lo cambió en el medio, 409 con codigo PEDIDO_MODIFICADO
  */
 @RestController
 @RequestMapping("/api/pedidos")
 public class PedidoController {
 
+    /** HU-165: Versión del pedido que está mostrando la terminal */
+    public static final String CABECERA_VERSION = "X-Pedido-Version";
+
     private final LocalContextProvider localContextProvider;
     private final AgregarProductoUseCase agregarProductoUseCase;
     private final AplicarDescuentoManualUseCase aplicarDescuentoManualUseCase;
     private final GestionarItemsPedidoUseCase gestionarItemsPedidoUseCase;
     private final ReabrirPedidoUseCase reabrirPedidoUseCase;
+    private final MarcarUrgenciaPedidoUseCase marcarUrgenciaPedidoUseCase;
+    private final AsignarEntregaDeliveryUseCase asignarEntregaDeliveryUseCase;
+    private final VincularClientePedidoUseCase vincularClientePedidoUseCase;
 
     public PedidoController(
             LocalContextProvider localContextProvider,
             AgregarProductoUseCase agregarProductoUseCase,
             AplicarDescuentoManualUseCase aplicarDescuentoManualUseCase,
             GestionarItemsPedidoUseCase gestionarItemsPedidoUseCase,
-            ReabrirPedidoUseCase reabrirPedidoUseCase
+            ReabrirPedidoUseCase reabrirPedidoUseCase,
+            MarcarUrgenciaPedidoUseCase marcarUrgenciaPedidoUseCase,
+            AsignarEntregaDeliveryUseCase asignarEntregaDeliveryUseCase,
+            VincularClientePedidoUseCase vincularClientePedidoUseCase
     ) {
         this.localContextProvider = localContextProvider;
         this.agregarProductoUseCase = agregarProductoUseCase;
         this.aplicarDescuentoManualUseCase = aplicarDescuentoManualUseCase;
         this.gestionarItemsPedidoUseCase = gestionarItemsPedidoUseCase;
         this.reabrirPedidoUseCase = reabrirPedidoUseCase;
+        this.marcarUrgenciaPedidoUseCase = marcarUrgenciaPedidoUseCase;
+        this.asignarEntregaDeliveryUseCase = asignarEntregaDeliveryUseCase;
+        this.vincularClientePedidoUseCase = vincularClientePedidoUseCase;
     }
 
     // =================================================
@@ -75,13 +99,14 @@ public class PedidoController {
      *   - IllegalArgumentException → 400 Bad Request / 404 Not Found según mensaje
      * 
      * @param pedidoId ID del pedido (path variable)
-     * @param body JSON con productoId, cantidad y observaciones
+     * @param body JSON con productoId, cantidad, observaciones, extras, variante y opciones de combo
      * @return 200 OK con el pedido actualizado
      */
     @PostMapping("/{pedidoId}/items")
     public ResponseEntity<AgregarProductoResponse> agregarProducto(
         @PathVariable String pedidoId,
-        @RequestBody AgregarProductoRequestBody body
+        @RequestBody AgregarProductoRequestBody body,
+        @RequestHeader(CABECERA_VERSION) long version
     ) {
         PedidoId pedidoIdVO = new PedidoId(UUID.fromString(pedidoId));
         ProductoId productoIdVO = new ProductoId(UUID.fromString(body.productoId()));
@@ -100,16 +125,37 @@ public class PedidoController {
             varianteIdVO = new ProductoId(UUID.fromString(body.varianteId()));
         }
 
+        // HU-103: Convertir opciones de combo (puede ser null/vacío)
+        List<ProductoId> componentesComboIdsVO = null;
+        if (body.componentesComboIds() != null && !body.componentesComboIds().isEmpty()) {
+            componentesComboIdsVO = body.componentesComboIds().stream()
+                .map(id -> new ProductoId(UUID.fromString(id)))
+                .toList();
+        }
+
+        // HU-148: Ingredientes quitados de la receta (puede ser null/vacío)
+        List<InsumoId> ingredientesQuitadosVO = null;
+        if (body.ingredientesQuitados() != null && !body.ingredientesQuitados().isEmpty()) {
+            ingredientesQuitadosVO = body.ingredientesQuitados().stream()
+                .map(InsumoId::from)
+                .toList();
+        }
+
         AgregarProductoRequest request = new AgregarProductoRequest(
             pedidoIdVO,
             productoIdVO,
             body.cantidad(),
             body.observaciones(),
             extrasIdsVO,
-            varianteIdVO
+            varianteIdVO,
+            componentesComboIdsVO,
+            body.tiempoServicio(),
+            body.advertencias(),
+            ingredientesQuitadosVO,
+            body.borradorId() != null && !body.borradorId().isBlank() ? UUID.fromString(body.borradorId()) : null
         );
 
-        AgregarProductoResponse response = agregarProductoUseCase.ejecutar(request);
+        AgregarProductoResponse response = agregarProductoUseCase.ejecutar(request, version);
         return ResponseEntity.ok(response);
     }
 
@@ -124,6 +170,9 @@ public class PedidoController {
      * 
      * HU-14: El descuento es dinámico. Se recalcula cada vez que cambia el total del pedido.
      * 
+     * HU-122: Sin autorizanteId rige el tope del mozo; con autorizanteId y pin
+     * de un encargado, el descuento no tiene tope.
+     * 
      * Ejemplo de request:
      * {
      *   "porcentaje": 10.5,
@@ -138,7 +187,8 @@ public class PedidoController {
     @PostMapping("/{pedidoId}/descuento-manual")
     public ResponseEntity<AplicarDescuentoManualResponse> aplicarDescuentoGlobal(
             @PathVariable UUID pedidoId,
-            @Valid @RequestBody DescuentoManualRequestBody requestBody
+            @Valid @RequestBody DescuentoManualRequestBody requestBody,
+            @RequestHeader(CABECERA_VERSION) long version
     ) {
         ModoDescuento tipo = ModoDescuento.valueOf(requestBody.tipoDescuento());
         AplicarDescuentoManualRequest request = new AplicarDescuentoManualRequest(
@@ -147,10 +197,12 @@ public class PedidoController {
             tipo,
             requestBody.valor(),
             requestBody.razon(),
-            requestBody.usuarioId()
+            requestBody.usuarioId(),
+            requestBody.autorizanteId() != null ? new MozoId(requestBody.autorizanteId()) : null,
+            requestBody.pin()
         );
 
-        AplicarDescuentoManualResponse response = aplicarDescuentoManualUseCase.ejecutar(request);
+        AplicarDescuentoManualResponse response = aplicarDescuentoManualUseCase.ejecutar(request, version);
         return ResponseEntity.ok(response);
     }
 
@@ -177,7 +229,8 @@ public class PedidoController {
     public ResponseEntity<AplicarDescuentoManualResponse> aplicarDescuentoPorItem(
             @PathVariable UUID pedidoId,
             @PathVariable UUID itemId,
-            @Valid @RequestBody DescuentoManualRequestBody requestBody
+            @Valid @RequestBody DescuentoManualRequestBody requestBody,
+            @RequestHeader(CABECERA_VERSION) long version
     ) {
         ModoDescuento tipo = ModoDescuento.valueOf(requestBody.tipoDescuento());
         AplicarDescuentoManualRequest request = new AplicarDescuentoManualRequest(
@@ -186,10 +239,12 @@ public class PedidoController {
             tipo,
             requestBody.valor(),
             requestBody.razon(),
-            requestBody.usuarioId()
+            requestBody.usuarioId(),
+            requestBody.autorizanteId() != null ? new MozoId(requestBody.autorizanteId()) : null,
+            requestBody.pin()
         );
 
-        AplicarDescuentoManualResponse response = aplicarDescuentoManualUseCase.ejecutar(request);
+        AplicarDescuentoManualResponse response = aplicarDescuentoManualUseCase.ejecutar(request, version);
         return ResponseEntity.ok(response);
     }
 
@@ -221,7 +276,8 @@ public class PedidoController {
     public ResponseEntity<AgregarProductoResponse> modificarCantidadItem(
             @PathVariable UUID pedidoId,
             @PathVariable UUID itemId,
-            @Valid @RequestBody ModificarCantidadItemRequestBody body
+            @Valid @RequestBody ModificarCantidadItemRequestBody body,
+            @RequestHeader(CABECERA_VERSION) long version
     ) {
         ModificarCantidadItemRequest request = new ModificarCantidadItemRequest(
             new PedidoId(pedidoId),
@@ -229,7 +285,7 @@ public class PedidoController {
             body.cantidad()
         );
 
-        AgregarProductoResponse response = gestionarItemsPedidoUseCase.modificarCantidad(request);
+        AgregarProductoResponse response = gestionarItemsPedidoUseCase.modificarCantidad(request, version);
         return ResponseEntity.ok(response);
     }
 
@@ -248,14 +304,179 @@ public class PedidoController {
     @DeleteMapping("/{pedidoId}/items/{itemId}")
     public ResponseEntity<AgregarProductoResponse> eliminarItemPedido(
             @PathVariable UUID pedidoId,
-            @PathVariable UUID itemId
+            @PathVariable UUID itemId,
+            @RequestHeader(CABECERA_VERSION) long version
     ) {
         EliminarItemPedidoRequest request = new EliminarItemPedidoRequest(
             new PedidoId(pedidoId),
             new ItemPedidoId(itemId)
         );
 
-        AgregarProductoResponse response = gestionarItemsPedidoUseCase.eliminarItem(request);
+        AgregarProductoResponse response = gestionarItemsPedidoUseCase.eliminarItem(request, version);
+        return ResponseEntity.ok(response);
+    }
+
+    /**
+     * Cambia el paso de servicio de un ítem.
+     * 
+     * PATCH /api/pedidos/{pedidoId}/items/{itemId}/tiempo
+     * Body: { "tiempoServicio": "POSTRE" }  (null = sin paso)
+     * 
+     * HU-110: Solo para ítems que cocina todavía no recibió (409 si ya se envió).
+     * 
+     * @param pedidoId ID del pedido (UUID en path)
+     * @param itemId ID del ítem (UUID en path)
+     * @param body JSON con el paso de servicio
+     * @return 200 OK con el pedido actualizado (mismo DTO que AgregarProducto)
+     */
+    @PatchMapping("/{pedidoId}/items/{itemId}/tiempo")
+    public ResponseEntity<AgregarProductoResponse> cambiarTiempoServicioItem(
+            @PathVariable UUID pedidoId,
+            @PathVariable UUID itemId,
+            @RequestBody CambiarTiempoServicioRequestBody body
+    ) {
+        CambiarTiempoServicioRequest request = new CambiarTiempoServicioRequest(
+            new PedidoId(pedidoId),
+            new ItemPedidoId(itemId),
+            body.tiempoServicio()
+        );
+
+        AgregarProductoResponse response = gestionarItemsPedidoUseCase.cambiarTiempoServicio(request);
+        return ResponseEntity.ok(response);
+    }
+
+    /**
+     * Cambia la observación libre de un ítem.
+     * 
+     * PATCH /api/pedidos/{pedidoId}/items/{itemId}/observacion
+     * Body: { "observacion": "bien cocida" }  (null o vacío = sin nota)
+     * 
+     * HU-120: Solo para ítems que cocina todavía no recibió (409 si ya se envió).
+     * 
+     * @param pedidoId ID del pedido (UUID en path)
+     * @param itemId ID del ítem (UUID en path)
+     * @param body JSON con la observación
+     * @return 200 OK con el pedido actualizado (mismo DTO que AgregarProducto)
+     */
+    @PatchMapping("/{pedidoId}/items/{itemId}/observacion")
+    public ResponseEntity<AgregarProductoResponse> cambiarObservacionItem(
+            @PathVariable UUID pedidoId,
+            @PathVariable UUID itemId,
+            @Valid @RequestBody CambiarObservacionItemRequestBody body,
+            @RequestHeader(CABECERA_VERSION) long version
+    ) {
+        CambiarObservacionItemRequest request = new CambiarObservacionItemRequest(
+            new PedidoId(pedidoId),
+            new ItemPedidoId(itemId),
+            body.observacion()
+        );
+
+        AgregarProductoResponse response = gestionarItemsPedidoUseCase.cambiarObservacion(request, version);
+        return ResponseEntity.ok(response);
+    }
+
+    // =================================================
+    // ENDPOINTS - HU-137: Urgencia
+    // =================================================
+
+    /**
+     * Marca o desmarca el pedido como urgente.
+     * 
+     * PATCH /api/pedidos/{pedidoId}/urgente
+     * Body: { "urgente": true }
+     * 
+     * Las comandas del pedido que siguen en cocina pasan al tope de la pantalla.
+     * 
+     * @param pedidoId ID del pedido (UUID en path)
+     * @param body JSON con la marca
+     * @return 200 OK con el pedido actualizado (mismo DTO que AgregarProducto)
+     */
+    @PatchMapping("/{pedidoId}/urgente")
+    public ResponseEntity<AgregarProductoResponse> marcarUrgente(
+            @PathVariable UUID pedidoId,
+            @RequestBody MarcarUrgenciaRequestBody body
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        AgregarProductoResponse response = marcarUrgenciaPedidoUseCase.marcarPedido(
+            localId, new PedidoId(pedidoId), body.urgente());
+        return ResponseEntity.ok(response);
+    }
+
+    /**
+     * Marca o desmarca un ítem como urgente.
+     * 
+     * PATCH /api/pedidos/{pedidoId}/items/{itemId}/urgente
+     * Body: { "urgente": true }
+     * 
+     * Solo se puede marcar un ítem con unidades sin enviar (409 si ya salió completo).
+     * 
+     * @param pedidoId ID del pedido (UUID en path)
+     * @param itemId ID del ítem (UUID en path)
+     * @param body JSON con la marca
+     * @return 200 OK con el pedido actualizado (mismo DTO que AgregarProducto)
+     */
+    @PatchMapping("/{pedidoId}/items/{itemId}/urgente")
+    public ResponseEntity<AgregarProductoResponse> marcarUrgenteItem(
+            @PathVariable UUID pedidoId,
+            @PathVariable UUID itemId,
+            @RequestBody MarcarUrgenciaRequestBody body
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        AgregarProductoResponse response = marcarUrgenciaPedidoUseCase.marcarItem(
+            localId, new PedidoId(pedidoId), new ItemPedidoId(itemId), body.urgente());
+        return ResponseEntity.ok(response);
+    }
+
+    // =================================================
+    // ENDPOINTS - HU-139: Entrega de delivery
+    // =================================================
+
+    /**
+     * Carga la dirección de entrega y agrega el costo de envío de la zona.
+     * 
+     * PUT /api/pedidos/{pedidoId}/entrega
+     * Body: { "direccion": "Av. Colón 1234, Alberdi", "zonaId": null }
+     * 
+     * Sin zonaId, la zona se elige por los barrios de la dirección
+     * (400 si no coincide ninguna: hay que elegirla a mano).
+     * 
+     * @param pedidoId ID del pedido (UUID en path)
+     * @param request dirección y zona opcional
+     * @return 200 OK con el pedido actualizado (mismo DTO que AgregarProducto)
+     */
+    @PutMapping("/{pedidoId}/entrega")
+    public ResponseEntity<AgregarProductoResponse> asignarEntrega(
+            @PathVariable UUID pedidoId,
+            @Valid @RequestBody AsignarEntregaRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        AgregarProductoResponse response = asignarEntregaDeliveryUseCase.ejecutar(
+            localId, new PedidoId(pedidoId), request);
+        return ResponseEntity.ok(response);
+    }
+
+    // =================================================
+    // ENDPOINTS - HU-168: Cliente del pedido
+    // =================================================
+
+    /**
+     * Vincula un cliente de la ficha al pedido (salón, take away o delivery).
+     * 
+     * PUT /api/pedidos/{pedidoId}/cliente
+     * Body: { "clienteId": "uuid" }  (null para desvincular)
+     * 
+     * @param pedidoId ID del pedido (UUID en path)
+     * @param body JSON con el cliente
+     * @return 200 OK con el pedido actualizado (mismo DTO que AgregarProducto)
+     */
+    @PutMapping("/{pedidoId}/cliente")
+    public ResponseEntity<AgregarProductoResponse> vincularCliente(
+            @PathVariable UUID pedidoId,
+            @RequestBody VincularClienteRequestBody body
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        AgregarProductoResponse response = vincularClientePedidoUseCase.ejecutar(
+            localId, new PedidoId(pedidoId), body.clienteId() != null ? new ClienteId(body.clienteId()) : null);
         return ResponseEntity.ok(response);
     }
 
@@ -0,0 +1,77 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.IdentificarEmpleadoRequest;
+import com.agustinpalma.comandas.application.dto.MatrizPermisosRequest;
+import com.agustinpalma.comandas.application.dto.MatrizPermisosResponse;
+import com.agustinpalma.comandas.application.dto.SesionEmpleadoResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.GestionarPermisosUseCase;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.infrastructure.config.HeaderEmpleadoContextProvider;
+import jakarta.validation.Valid;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+/**
+ * Controller REST de la matriz de permisos.
+ * HU-169: el dueño habilita cada acción sensible por rol y por empleado.
+ *
+ * Endpoints:
+ * - GET    /api/permisos/matriz              -> Matriz del local (la por defecto si nunca se configuró)
+ * - PUT    /api/permisos/matriz              -> Guardar la matriz (activa, solo el dueño)
+ * - POST   /api/permisos/sesion              -> Identificarse con el PIN: devuelve el token de la sesión y lo que puede hacer
+ * - DELETE /api/permisos/sesion              -> Cerrar la sesión del encabezado X-Sesion-Empleado
+ * - POST   /api/permisos/verificar/{permiso} -> 204 si el empleado de la sesión X-Sesion-Empleado puede
+ *
+ * La capa Tauri usa /verificar antes de las acciones que no pasan por el
+ * backend, como abrir el cajón.
+ */
+@RestController
+@RequestMapping("/api/permisos")
+public class PermisosController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarPermisosUseCase gestionarPermisosUseCase;
+
+    public PermisosController(
+        LocalContextProvider localContextProvider,
+        GestionarPermisosUseCase gestionarPermisosUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarPermisosUseCase = gestionarPermisosUseCase;
+    }
+
+    @GetMapping("/matriz")
+    public ResponseEntity<MatrizPermisosResponse> obtenerMatriz() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarPermisosUseCase.consultar(localId));
+    }
+
+    @PutMapping("/matriz")
+    public ResponseEntity<MatrizPermisosResponse> guardarMatriz(@Valid @RequestBody MatrizPermisosRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarPermisosUseCase.guardar(localId, request));
+    }
+
+    @PostMapping("/sesion")
+    public ResponseEntity<SesionEmpleadoResponse> identificar(@Valid @RequestBody IdentificarEmpleadoRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(
+            gestionarPermisosUseCase.identificar(localId, new MozoId(request.empleadoId()), request.pin()));
+    }
+
+    @DeleteMapping("/sesion")
+    public ResponseEntity<Void> cerrarSesion(@RequestHeader(HeaderEmpleadoContextProvider.HEADER) String token) {
+        gestionarPermisosUseCase.cerrarSesion(token);
+        return ResponseEntity.noContent().build();
+    }
+
+    @PostMapping("/verificar/{permiso}")
+    public ResponseEntity<Void> verificar(@PathVariable Permiso permiso) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        gestionarPermisosUseCase.exigir(localId, permiso);
+        return ResponseEntity.noContent().build();
+    }
+}
@@ -2,27 +2,39 @@ package com.agustinpalma.comandas.presentation.rest;
 
 import com.agustinpalma.comandas.application.dto.AjustarStockRequest;
 import com.agustinpalma.comandas.application.dto.AjustarStockResponse;
+import com.agustinpalma.comandas.application.dto.DisponibilidadProductoRequest;
+import com.agustinpalma.comandas.application.dto.FotoProductoRequest;
+import com.agustinpalma.comandas.application.dto.FotoProductoResponse;
+import com.agustinpalma.comandas.application.dto.ProductoParaReponerResponse;
 import com.agustinpalma.comandas.application.dto.ProductoRequest;
 import com.agustinpalma.comandas.application.dto.ProductoResponse;
 import com.agustinpalma.comandas.application.dto.StockAjusteRequestBody;
 import com.agustinpalma.comandas.application.dto.VarianteProductoRequest;
 import com.agustinpalma.comandas.application.dto.VarianteProductoResponse;
+import com.agustinpalma.comandas.application.dto.VariantesEnLoteRequest;
 import com.agustinpalma.comandas.application.usecase.AjustarStockUseCase;
+import com.agustinpalma.comandas.application.usecase.CambiarDisponibilidadProductoUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarProductosParaReponerUseCase;
 import com.agustinpalma.comandas.application.usecase.ConsultarProductosUseCase;
 import com.agustinpalma.comandas.application.usecase.CrearProductoUseCase;
 import com.agustinpalma.comandas.application.usecase.CrearVarianteUseCase;
 import com.agustinpalma.comandas.application.usecase.EditarProductoUseCase;
 import com.agustinpalma.comandas.application.usecase.EliminarProductoUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarFotoProductoUseCase;
 import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.FotoProducto;
 import com.agustinpalma.comandas.domain.model.Producto;
 import com.agustinpalma.comandas.domain.repository.ProductoRepository;
 import jakarta.validation.Valid;
+import org.springframework.http.CacheControl;
 import org.springframework.http.HttpStatus;
+import org.springframework.http.MediaType;
 import org.springframework.http.ResponseEntity;
 import org.springframework.web.bind.annotation.*;
 
+import java.time.Duration;
 import java.util.List;
 import java.util.UUID;
 
@@ -36,6 +48,14 @@ import java.util.UUID;
  * - POST   /api/productos          -> Crear producto
  * - PUT    /api/productos/{id}     -> Editar producto
  * - DELETE /api/productos/{id}     -> Eliminar producto
+ * - GET    /api/productos/para-reponer -> Productos bajo stock mínimo (HU-101)
+ * - PATCH  /api/productos/{id}/disponibilidad -> Switch "sin stock" 86 (HU-125)
+ * - PUT    /api/productos/{id}/foto   -> Sube o reemplaza la foto de la carta (HU-127)
+ * - GET    /api/productos/{id}/foto   -> Imagen de la foto (HU-127)
+ * - DELETE /api/productos/{id}/foto   -> Quita la foto (HU-127)
+ * - POST   /api/productos/{id}/variantes      -> Crear variante
+ * - POST   /api/productos/{id}/variantes/lote -> Crear varios tamaños (HU-109)
+ * - GET    /api/productos/{id}/variantes      -> Listar variantes del grupo
  */
 @RestController
 @RequestMapping("/api/productos")
@@ -49,6 +69,9 @@ public class ProductoController {
     private final EditarProductoUseCase editarProductoUseCase;
     private final EliminarProductoUseCase eliminarProductoUseCase;
     private final AjustarStockUseCase ajustarStockUseCase;
+    private final ConsultarProductosParaReponerUseCase consultarProductosParaReponerUseCase;
+    private final CambiarDisponibilidadProductoUseCase cambiarDisponibilidadProductoUseCase;
+    private final GestionarFotoProductoUseCase gestionarFotoProductoUseCase;
 
     public ProductoController(
         LocalContextProvider localContextProvider,
@@ -58,7 +81,10 @@ public class ProductoController {
         CrearVarianteUseCase crearVarianteUseCase,
         EditarProductoUseCase editarProductoUseCase,
         EliminarProductoUseCase eliminarProductoUseCase,
-        AjustarStockUseCase ajustarStockUseCase
+        AjustarStockUseCase ajustarStockUseCase,
+        ConsultarProductosParaReponerUseCase consultarProductosParaReponerUseCase,
+        CambiarDisponibilidadProductoUseCase cambiarDisponibilidadProductoUseCase,
+        GestionarFotoProductoUseCase gestionarFotoProductoUseCase
     ) {
         this.localContextProvider = localContextProvider;
         this.productoRepository = productoRepository;
@@ -68,6 +94,9 @@ public class ProductoController {
         this.editarProductoUseCase = editarProductoUseCase;
         this.eliminarProductoUseCase = eliminarProductoUseCase;
         this.ajustarStockUseCase = ajustarStockUseCase;
+        this.consultarProductosParaReponerUseCase = consultarProductosParaReponerUseCase;
+        this.cambiarDisponibilidadProductoUseCase = cambiarDisponibilidadProductoUseCase;
+        this.gestionarFotoProductoUseCase = gestionarFotoProductoUseCase;
     }
 
     /**
@@ -223,6 +252,88 @@ public class ProductoController {
         return ResponseEntity.ok(response);
     }
 
+    /**
+     * Lista los productos que alcanzaron su stock mínimo.
+     *
+     * GET /api/productos/para-reponer
+     *
+     * HU-101: Alertas de stock mínimo. El frontend consulta este endpoint
+     * periódicamente para notificar en la terminal y exportar la lista de compras.
+     *
+     * @return productos bajo stock mínimo, ordenados por urgencia
+     */
+    @GetMapping("/para-reponer")
+    public ResponseEntity<List<ProductoParaReponerResponse>> listarParaReponer() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarProductosParaReponerUseCase.ejecutar(localId));
+    }
+
+    /**
+     * Marca o desmarca un producto como agotado (86).
+     *
+     * PATCH /api/productos/{id}/disponibilidad
+     * Body: { "agotado": true }
+     *
+     * HU-125: El cambio se difunde por /api/eventos a todas las terminales.
+     */
+    @PatchMapping("/{id}/disponibilidad")
+    public ResponseEntity<ProductoResponse> cambiarDisponibilidad(
+            @PathVariable UUID id,
+            @Valid @RequestBody DisponibilidadProductoRequest body
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(
+            cambiarDisponibilidadProductoUseCase.ejecutar(localId, new ProductoId(id), body.agotado())
+        );
+    }
+
+    // =================================================
+    // ENDPOINTS - Foto de la carta digital (HU-127)
+    // =================================================
+
+    /**
+     * Sube o reemplaza la foto del producto.
+     *
+     * PUT /api/productos/{id}/foto
+     * Body: { "contentType": "image/jpeg", "contenidoBase64": "..." }
+     */
+    @PutMapping("/{id}/foto")
+    public ResponseEntity<FotoProductoResponse> subirFoto(
+            @PathVariable UUID id,
+            @Valid @RequestBody FotoProductoRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarFotoProductoUseCase.subir(localId, new ProductoId(id), request));
+    }
+
+    /**
+     * Imagen de la foto. La carta la pide con ?v=<versión>, así que puede
+     * quedar en la caché del celular: una foto nueva cambia la URL.
+     *
+     * GET /api/productos/{id}/foto
+     */
+    @GetMapping("/{id}/foto")
+    public ResponseEntity<byte[]> obtenerFoto(@PathVariable UUID id) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        FotoProducto foto = gestionarFotoProductoUseCase.obtener(localId, new ProductoId(id));
+        return ResponseEntity.ok()
+            .contentType(MediaType.parseMediaType(foto.getContentType()))
+            .cacheControl(CacheControl.maxAge(Duration.ofDays(7)).cachePublic())
+            .body(foto.getContenido());
+    }
+
+    /**
+     * Quita la foto del producto.
+     *
+     * DELETE /api/productos/{id}/foto
+     */
+    @DeleteMapping("/{id}/foto")
+    public ResponseEntity<Void> eliminarFoto(@PathVariable UUID id) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        gestionarFotoProductoUseCase.eliminar(localId, new ProductoId(id));
+        return ResponseEntity.noContent().build();
+    }
+
     // =================================================
     // ENDPOINTS - Variantes de producto
     // =================================================
@@ -252,6 +363,31 @@ public class ProductoController {
         return ResponseEntity.status(HttpStatus.CREATED).body(response);
     }
 
+    /**
+     * HU-109: Crea varios tamaños de un producto de una sola vez.
+     * 
+     * POST /api/productos/{productoBaseId}/variantes/lote
+     * Body: { "etiquetaBase": "Chica", "variantes": [{ "etiqueta": "Grande", "precio": 8000.00 }] }
+     * 
+     * Las variantes sin nombre se llaman "{base} {etiqueta}" y las que no
+     * indican cantidadDiscosCarne toman la siguiente posición del grupo.
+     * 
+     * @param productoBaseId UUID del producto base
+     * @param request etiqueta del base y variantes a crear
+     * @return todas las variantes del grupo, con status 201 CREATED
+     */
+    @PostMapping("/{productoBaseId}/variantes/lote")
+    public ResponseEntity<List<ProductoResponse>> crearVariantesEnLote(
+            @PathVariable UUID productoBaseId,
+            @Valid @RequestBody VariantesEnLoteRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        ProductoId baseId = new ProductoId(productoBaseId);
+
+        List<ProductoResponse> response = crearVarianteUseCase.ejecutarLote(localId, baseId, request);
+        return ResponseEntity.status(HttpStatus.CREATED).body(response);
+    }
+
     /**
      * Lista todas las variantes de un producto (todas las hermanas del mismo grupo).
      * 
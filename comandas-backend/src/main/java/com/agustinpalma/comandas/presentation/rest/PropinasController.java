@@ -0,0 +1,85 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.DistribucionPropinasResponse;
+import com.agustinpalma.comandas.application.dto.ReglaPropinasRequest;
+import com.agustinpalma.comandas.application.dto.ReglaPropinasResponse;
+import com.agustinpalma.comandas.application.dto.TicketVentaEscPosResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarReglaPropinasUseCase;
+import com.agustinpalma.comandas.application.usecase.DistribuirPropinasUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarReglaPropinasUseCase;
+import com.agustinpalma.comandas.application.usecase.ImprimirComprobantePropinasUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.DistribucionPropinasId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.LocalDate;
+import java.util.List;
+
+/**
+ * Controller REST del pozo de propinas.
+ * HU-141: regla de reparto, repartos por turno y comprobante.
+ *
+ * Endpoints:
+ * - GET  /api/propinas/regla                                -> Regla vigente
+ * - PUT  /api/propinas/regla                                -> Configurar el reparto
+ * - GET  /api/propinas/distribuciones?desde&hasta           -> Repartos de los turnos cerrados
+ * - POST /api/propinas/distribuciones/{distribucionId}/imprimir -> Comprobante ESC/POS (Base64)
+ *
+ * El reparto se calcula al cerrar el turno (POST /api/caja/turnos/cerrar).
+ */
+@RestController
+@RequestMapping("/api/propinas")
+public class PropinasController {
+
+    private final LocalContextProvider localContextProvider;
+    private final ConsultarReglaPropinasUseCase consultarReglaPropinasUseCase;
+    private final GuardarReglaPropinasUseCase guardarReglaPropinasUseCase;
+    private final DistribuirPropinasUseCase distribuirPropinasUseCase;
+    private final ImprimirComprobantePropinasUseCase imprimirComprobantePropinasUseCase;
+
+    public PropinasController(
+        LocalContextProvider localContextProvider,
+        ConsultarReglaPropinasUseCase consultarReglaPropinasUseCase,
+        GuardarReglaPropinasUseCase guardarReglaPropinasUseCase,
+        DistribuirPropinasUseCase distribuirPropinasUseCase,
+        ImprimirComprobantePropinasUseCase imprimirComprobantePropinasUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.consultarReglaPropinasUseCase = consultarReglaPropinasUseCase;
+        this.guardarReglaPropinasUseCase = guardarReglaPropinasUseCase;
+        this.distribuirPropinasUseCase = distribuirPropinasUseCase;
+        this.imprimirComprobantePropinasUseCase = imprimirComprobantePropinasUseCase;
+    }
+
+    @GetMapping("/regla")
+    public ResponseEntity<ReglaPropinasResponse> obtenerRegla() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarReglaPropinasUseCase.ejecutar(localId));
+    }
+
+    @PutMapping("/regla")
+    public ResponseEntity<ReglaPropinasResponse> guardarRegla(@Valid @RequestBody ReglaPropinasRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(guardarReglaPropinasUseCase.ejecutar(localId, request));
+    }
+
+    @GetMapping("/distribuciones")
+    public ResponseEntity<List<DistribucionPropinasResponse>> listarDistribuciones(
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate desde,
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate hasta
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(distribuirPropinasUseCase.listar(localId, desde, hasta));
+    }
+
+    @PostMapping("/distribuciones/{distribucionId}/imprimir")
+    public ResponseEntity<TicketVentaEscPosResponse> imprimirComprobante(@PathVariable String distribucionId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        String base64 = imprimirComprobantePropinasUseCase.ejecutar(localId, DistribucionPropinasId.from(distribucionId));
+        return ResponseEntity.ok(new TicketVentaEscPosResponse(base64));
+    }
+}
@@ -0,0 +1,105 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.CuentaProveedorResponse;
+import com.agustinpalma.comandas.application.dto.FacturaProveedorResponse;
+import com.agustinpalma.comandas.application.dto.PagoProveedorRequest;
+import com.agustinpalma.comandas.application.dto.ProveedorRequest;
+import com.agustinpalma.comandas.application.dto.ProveedorResponse;
+import com.agustinpalma.comandas.application.dto.SaldoProveedorResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.GestionarCuentaProveedoresUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarProveedoresUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaProveedorId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+import jakarta.validation.Valid;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.util.List;
+
+/**
+ * Controller REST de proveedores y su cuenta corriente.
+ * HU-131: las facturas se generan al registrar una compra a cuenta corriente (POST /api/gastos).
+ *
+ * Endpoints:
+ * - GET  /api/proveedores                           -> Proveedores del local (activos e inactivos)
+ * - POST /api/proveedores                           -> Alta de proveedor
+ * - PUT  /api/proveedores/{id}                      -> Editar / activar / desactivar
+ * - GET  /api/proveedores/saldos                    -> Saldo de cada proveedor
+ * - GET  /api/proveedores/{id}/cuenta               -> Facturas y pagos de un proveedor
+ * - POST /api/proveedores/facturas/{id}/pagos       -> Pago total o parcial de una factura
+ * - GET  /api/proveedores/vencimientos?dias=3       -> Facturas vencidas o por vencer (recordatorios)
+ */
+@RestController
+@RequestMapping("/api/proveedores")
+public class ProveedorController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarProveedoresUseCase gestionarProveedoresUseCase;
+    private final GestionarCuentaProveedoresUseCase gestionarCuentaProveedoresUseCase;
+
+    public ProveedorController(
+        LocalContextProvider localContextProvider,
+        GestionarProveedoresUseCase gestionarProveedoresUseCase,
+        GestionarCuentaProveedoresUseCase gestionarCuentaProveedoresUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarProveedoresUseCase = gestionarProveedoresUseCase;
+        this.gestionarCuentaProveedoresUseCase = gestionarCuentaProveedoresUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<List<ProveedorResponse>> listar() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarProveedoresUseCase.listar(localId));
+    }
+
+    @PostMapping
+    public ResponseEntity<ProveedorResponse> crear(@Valid @RequestBody ProveedorRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED).body(gestionarProveedoresUseCase.crear(localId, request));
+    }
+
+    @PutMapping("/{proveedorId}")
+    public ResponseEntity<ProveedorResponse> actualizar(
+        @PathVariable String proveedorId,
+        @Valid @RequestBody ProveedorRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(
+            gestionarProveedoresUseCase.actualizar(localId, ProveedorId.from(proveedorId), request));
+    }
+
+    @GetMapping("/saldos")
+    public ResponseEntity<List<SaldoProveedorResponse>> listarSaldos() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarCuentaProveedoresUseCase.listarSaldos(localId));
+    }
+
+    @GetMapping("/{proveedorId}/cuenta")
+    public ResponseEntity<CuentaProveedorResponse> consultarCuenta(@PathVariable String proveedorId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(
+            gestionarCuentaProveedoresUseCase.consultarCuenta(localId, ProveedorId.from(proveedorId)));
+    }
+
+    @PostMapping("/facturas/{facturaId}/pagos")
+    public ResponseEntity<FacturaProveedorResponse> registrarPago(
+        @PathVariable String facturaId,
+        @Valid @RequestBody PagoProveedorRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED).body(
+            gestionarCuentaProveedoresUseCase.registrarPago(localId, FacturaProveedorId.from(facturaId), request));
+    }
+
+    @GetMapping("/vencimientos")
+    public ResponseEntity<List<FacturaProveedorResponse>> consultarVencimientos(
+        @RequestParam(defaultValue = "3") int dias
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarCuentaProveedoresUseCase.consultarVencimientos(localId, dias));
+    }
+}
@@ -0,0 +1,84 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.ProgramaPuntosRequest;
+import com.agustinpalma.comandas.application.dto.ProgramaPuntosResponse;
+import com.agustinpalma.comandas.application.dto.PuntosClienteResponse;
+import com.agustinpalma.comandas.application.dto.ReportePuntosResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarProgramaPuntosUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarPuntosClienteUseCase;
+import com.agustinpalma.comandas.application.usecase.ConsultarReportePuntosUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarProgramaPuntosUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.LocalDate;
+import java.util.UUID;
+
+/**
+ * Controller REST del programa de puntos.
+ * HU-105: configuración del programa, saldo por cliente y reporte.
+ *
+ * Endpoints:
+ * - GET /api/puntos/programa              -> Configuración vigente
+ * - PUT /api/puntos/programa              -> Activar / reconfigurar
+ * - GET /api/puntos/clientes/{clienteId}  -> Saldo disponible y vencimientos
+ * - GET /api/puntos/reporte?desde&hasta   -> Emitidos, canjeados y vencidos
+ *
+ * La acumulación y el canje ocurren al cerrar la mesa (POST /api/mesas/{id}/cierre).
+ */
+@RestController
+@RequestMapping("/api/puntos")
+public class PuntosController {
+
+    private final LocalContextProvider localContextProvider;
+    private final ConsultarProgramaPuntosUseCase consultarProgramaPuntosUseCase;
+    private final GuardarProgramaPuntosUseCase guardarProgramaPuntosUseCase;
+    private final ConsultarPuntosClienteUseCase consultarPuntosClienteUseCase;
+    private final ConsultarReportePuntosUseCase consultarReportePuntosUseCase;
+
+    public PuntosController(
+        LocalContextProvider localContextProvider,
+        ConsultarProgramaPuntosUseCase consultarProgramaPuntosUseCase,
+        GuardarProgramaPuntosUseCase guardarProgramaPuntosUseCase,
+        ConsultarPuntosClienteUseCase consultarPuntosClienteUseCase,
+        ConsultarReportePuntosUseCase consultarReportePuntosUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.consultarProgramaPuntosUseCase = consultarProgramaPuntosUseCase;
+        this.guardarProgramaPuntosUseCase = guardarProgramaPuntosUseCase;
+        this.consultarPuntosClienteUseCase = consultarPuntosClienteUseCase;
+        this.consultarReportePuntosUseCase = consultarReportePuntosUseCase;
+    }
+
+    @GetMapping("/programa")
+    public ResponseEntity<ProgramaPuntosResponse> obtenerPrograma() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarProgramaPuntosUseCase.ejecutar(localId));
+    }
+
+    @PutMapping("/programa")
+    public ResponseEntity<ProgramaPuntosResponse> guardarPrograma(@Valid @RequestBody ProgramaPuntosRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(guardarProgramaPuntosUseCase.ejecutar(localId, request));
+    }
+
+    @GetMapping("/clientes/{clienteId}")
+    public ResponseEntity<PuntosClienteResponse> obtenerPuntosCliente(@PathVariable UUID clienteId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarPuntosClienteUseCase.ejecutar(new ClienteId(clienteId), localId));
+    }
+
+    @GetMapping("/reporte")
+    public ResponseEntity<ReportePuntosResponse> obtenerReporte(
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate desde,
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate hasta
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarReportePuntosUseCase.ejecutar(localId, desde, hasta));
+    }
+}
@@ -0,0 +1,52 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.RecargosMedioPagoRequest;
+import com.agustinpalma.comandas.application.dto.RecargosMedioPagoResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarRecargosMedioPagoUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarRecargosMedioPagoUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+/**
+ * Controller REST de recargos por medio de pago.
+ * HU-143: porcentaje por medio (ej: 10% tarjeta) que se cobra encima de cada pago.
+ *
+ * Endpoints:
+ * - GET /api/caja/recargos   -> Recargos vigentes
+ * - PUT /api/caja/recargos   -> Configurar los recargos
+ *
+ * El recargo se congela en cada pago al cerrar la mesa (POST /api/mesas/{mesaId}/cierre).
+ */
+@RestController
+@RequestMapping("/api/caja/recargos")
+public class RecargosMedioPagoController {
+
+    private final LocalContextProvider localContextProvider;
+    private final ConsultarRecargosMedioPagoUseCase consultarRecargosMedioPagoUseCase;
+    private final GuardarRecargosMedioPagoUseCase guardarRecargosMedioPagoUseCase;
+
+    public RecargosMedioPagoController(
+        LocalContextProvider localContextProvider,
+        ConsultarRecargosMedioPagoUseCase consultarRecargosMedioPagoUseCase,
+        GuardarRecargosMedioPagoUseCase guardarRecargosMedioPagoUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.consultarRecargosMedioPagoUseCase = consultarRecargosMedioPagoUseCase;
+        this.guardarRecargosMedioPagoUseCase = guardarRecargosMedioPagoUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<RecargosMedioPagoResponse> obtener() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarRecargosMedioPagoUseCase.ejecutar(localId));
+    }
+
+    @PutMapping
+    public ResponseEntity<RecargosMedioPagoResponse> guardar(@Valid @RequestBody RecargosMedioPagoRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(guardarRecargosMedioPagoUseCase.ejecutar(localId, request));
+    }
+}
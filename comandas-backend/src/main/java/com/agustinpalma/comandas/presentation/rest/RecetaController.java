@@ -0,0 +1,74 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.RecetaRequest;
+import com.agustinpalma.comandas.application.dto.RecetaResponse;
+import com.agustinpalma.comandas.application.dto.RentabilidadProductoResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarRentabilidadProductosUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarRecetasUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import jakarta.validation.Valid;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.util.List;
+
+/**
+ * Controller REST de recetas y rentabilidad.
+ * HU-118: costo teórico de cada producto según su receta y margen contra el precio.
+ *
+ * Endpoints:
+ * - GET    /api/recetas/rentabilidad   -> Margen de los productos con receta (primero los de menor margen)
+ * - GET    /api/recetas/{productoId}   -> Receta del producto con su costo (204 si no tiene)
+ * - PUT    /api/recetas/{productoId}   -> Cargar o reemplazar la receta
+ * - DELETE /api/recetas/{productoId}   -> Quitar la receta
+ */
+@RestController
+@RequestMapping("/api/recetas")
+public class RecetaController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarRecetasUseCase gestionarRecetasUseCase;
+    private final ConsultarRentabilidadProductosUseCase consultarRentabilidadProductosUseCase;
+
+    public RecetaController(
+        LocalContextProvider localContextProvider,
+        GestionarRecetasUseCase gestionarRecetasUseCase,
+        ConsultarRentabilidadProductosUseCase consultarRentabilidadProductosUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarRecetasUseCase = gestionarRecetasUseCase;
+        this.consultarRentabilidadProductosUseCase = consultarRentabilidadProductosUseCase;
+    }
+
+    @GetMapping("/rentabilidad")
+    public ResponseEntity<List<RentabilidadProductoResponse>> consultarRentabilidad() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarRentabilidadProductosUseCase.ejecutar(localId));
+    }
+
+    @GetMapping("/{productoId}")
+    public ResponseEntity<RecetaResponse> obtener(@PathVariable String productoId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return gestionarRecetasUseCase.obtener(localId, ProductoId.from(productoId))
+            .map(ResponseEntity::ok)
+            .orElseGet(() -> ResponseEntity.noContent().build());
+    }
+
+    @PutMapping("/{productoId}")
+    public ResponseEntity<RecetaResponse> guardar(
+        @PathVariable String productoId,
+        @Valid @RequestBody RecetaRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarRecetasUseCase.guardar(localId, ProductoId.from(productoId), request));
+    }
+
+    @DeleteMapping("/{productoId}")
+    public ResponseEntity<Void> eliminar(@PathVariable String productoId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        gestionarRecetasUseCase.eliminar(localId, ProductoId.from(productoId));
+        return ResponseEntity.noContent().build();
+    }
+}
@@ -0,0 +1,52 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.RedondeoEfectivoRequest;
+import com.agustinpalma.comandas.application.dto.RedondeoEfectivoResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarRedondeoEfectivoUseCase;
+import com.agustinpalma.comandas.application.usecase.GuardarRedondeoEfectivoUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+/**
+ * Controller REST del redondeo del efectivo.
+ * HU-152: múltiplo (ej: $100) al que se redondea lo que se cobra en efectivo.
+ *
+ * Endpoints:
+ * - GET /api/caja/redondeo   -> Regla vigente
+ * - PUT /api/caja/redondeo   -> Configurar el redondeo
+ *
+ * El ajuste se congela en el pago en efectivo al cerrar la mesa (POST /api/mesas/{mesaId}/cierre).
+ */
+@RestController
+@RequestMapping("/api/caja/redondeo")
+public class RedondeoEfectivoController {
+
+    private final LocalContextProvider localContextProvider;
+    private final ConsultarRedondeoEfectivoUseCase consultarRedondeoEfectivoUseCase;
+    private final GuardarRedondeoEfectivoUseCase guardarRedondeoEfectivoUseCase;
+
+    public RedondeoEfectivoController(
+        LocalContextProvider localContextProvider,
+        ConsultarRedondeoEfectivoUseCase consultarRedondeoEfectivoUseCase,
+        GuardarRedondeoEfectivoUseCase guardarRedondeoEfectivoUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.consultarRedondeoEfectivoUseCase = consultarRedondeoEfectivoUseCase;
+        this.guardarRedondeoEfectivoUseCase = guardarRedondeoEfectivoUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<RedondeoEfectivoResponse> obtener() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarRedondeoEfectivoUseCase.ejecutar(localId));
+    }
+
+    @PutMapping
+    public ResponseEntity<RedondeoEfectivoResponse> guardar(@Valid @RequestBody RedondeoEfectivoRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(guardarRedondeoEfectivoUseCase.ejecutar(localId, request));
+    }
+}
@@ -0,0 +1,106 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.AsignarRepartidorRequest;
+import com.agustinpalma.comandas.application.dto.RendicionesTurnoResponse;
+import com.agustinpalma.comandas.application.dto.RendicionesTurnoResponse.RendicionRepartidorDTO;
+import com.agustinpalma.comandas.application.dto.RendirEfectivoRequest;
+import com.agustinpalma.comandas.application.dto.RepartoResponse;
+import com.agustinpalma.comandas.application.dto.ReporteRepartidoresResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteRepartidoresUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarRepartosUseCase;
+import com.agustinpalma.comandas.application.usecase.RendirEfectivoRepartidorUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import jakarta.validation.Valid;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.LocalDate;
+import java.util.List;
+
+/**
+ * Controller REST de repartos de delivery.
+ * HU-140: los repartidores son personal con rol REPARTIDOR (alta en /api/mozos).
+ *
+ * Endpoints:
+ * - GET  /api/delivery/repartos                      -> Repartos pendientes de salida o de regreso
+ * - PUT  /api/delivery/repartos/{pedidoId}/repartidor -> Asignar (o cambiar) el repartidor
+ * - POST /api/delivery/repartos/{pedidoId}/salida     -> Registrar la salida
+ * - POST /api/delivery/repartos/{pedidoId}/regreso    -> Registrar el regreso
+ * - GET  /api/delivery/rendiciones                   -> Rendición de efectivo por repartidor en el turno abierto
+ * - POST /api/delivery/rendiciones                   -> Rendir el efectivo de un repartidor
+ * - GET  /api/delivery/repartidores/reporte?desde&hasta -> Entregas y tiempos por repartidor
+ */
+@RestController
+@RequestMapping("/api/delivery")
+public class RepartoController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarRepartosUseCase gestionarRepartosUseCase;
+    private final RendirEfectivoRepartidorUseCase rendirEfectivoRepartidorUseCase;
+    private final ConsultarReporteRepartidoresUseCase consultarReporteRepartidoresUseCase;
+
+    public RepartoController(
+        LocalContextProvider localContextProvider,
+        GestionarRepartosUseCase gestionarRepartosUseCase,
+        RendirEfectivoRepartidorUseCase rendirEfectivoRepartidorUseCase,
+        ConsultarReporteRepartidoresUseCase consultarReporteRepartidoresUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarRepartosUseCase = gestionarRepartosUseCase;
+        this.rendirEfectivoRepartidorUseCase = rendirEfectivoRepartidorUseCase;
+        this.consultarReporteRepartidoresUseCase = consultarReporteRepartidoresUseCase;
+    }
+
+    @GetMapping("/repartos")
+    public ResponseEntity<List<RepartoResponse>> listarEnCurso() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarRepartosUseCase.listarEnCurso(localId));
+    }
+
+    @PutMapping("/repartos/{pedidoId}/repartidor")
+    public ResponseEntity<RepartoResponse> asignarRepartidor(
+        @PathVariable String pedidoId,
+        @Valid @RequestBody AsignarRepartidorRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(
+            gestionarRepartosUseCase.asignarRepartidor(localId, PedidoId.from(pedidoId), request));
+    }
+
+    @PostMapping("/repartos/{pedidoId}/salida")
+    public ResponseEntity<RepartoResponse> registrarSalida(@PathVariable String pedidoId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarRepartosUseCase.registrarSalida(localId, PedidoId.from(pedidoId)));
+    }
+
+    @PostMapping("/repartos/{pedidoId}/regreso")
+    public ResponseEntity<RepartoResponse> registrarRegreso(@PathVariable String pedidoId) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarRepartosUseCase.registrarRegreso(localId, PedidoId.from(pedidoId)));
+    }
+
+    @GetMapping("/rendiciones")
+    public ResponseEntity<RendicionesTurnoResponse> consultarRendiciones() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(rendirEfectivoRepartidorUseCase.consultar(localId));
+    }
+
+    @PostMapping("/rendiciones")
+    public ResponseEntity<RendicionRepartidorDTO> rendir(@Valid @RequestBody RendirEfectivoRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED).body(rendirEfectivoRepartidorUseCase.rendir(localId, request));
+    }
+
+    @GetMapping("/repartidores/reporte")
+    public ResponseEntity<ReporteRepartidoresResponse> reporte(
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate desde,
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate hasta
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarReporteRepartidoresUseCase.ejecutar(localId, desde, hasta));
+    }
+}
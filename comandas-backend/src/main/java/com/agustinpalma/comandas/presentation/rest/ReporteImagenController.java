@@ -0,0 +1,83 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.ReporteImagenData.Ancho;
+import com.agustinpalma.comandas.application.dto.ReporteImagenData.Vista;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.GenerarImagenReporteUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarPermisosUseCase;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.HttpHeaders;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.MediaType;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RequestParam;
+import org.springframework.web.bind.annotation.RestController;
+
+import java.time.LocalDate;
+
+/**
+ * Controller REST para exportar reportes como imagen.
+ * HU-157: imagen PNG para reenviar por WhatsApp.
+ *
+ * Endpoints:
+ * - GET /api/reportes/imagen?vista=VENTAS&desde=YYYY-MM-DD&hasta=YYYY-MM-DD&ancho=TELEFONO
+ *
+ * El escritorio la pide desde el comando capture_report_image y la guarda en
+ * Imágenes; en el navegador se descarga.
+ *
+ * HU-169: Exige el permiso VER_REPORTES.
+ */
+@RestController
+@RequestMapping("/api/reportes")
+public class ReporteImagenController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GenerarImagenReporteUseCase generarImagenReporteUseCase;
+    private final GestionarPermisosUseCase gestionarPermisosUseCase;
+
+    public ReporteImagenController(
+        LocalContextProvider localContextProvider,
+        GenerarImagenReporteUseCase generarImagenReporteUseCase,
+        GestionarPermisosUseCase gestionarPermisosUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.generarImagenReporteUseCase = generarImagenReporteUseCase;
+        this.gestionarPermisosUseCase = gestionarPermisosUseCase;
+    }
+
+    /**
+     * @param vista VENTAS, CAJA o PRODUCTOS
+     * @param desde primer día operativo
+     * @param hasta último día operativo (por defecto, el mismo día)
+     * @param ancho TICKET (576 px) o TELEFONO (1080 px)
+     * @return 200 OK con la imagen como image/png
+     */
+    @GetMapping("/imagen")
+    public ResponseEntity<byte[]> imagen(
+            @RequestParam Vista vista,
+            @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate desde,
+            @RequestParam(required = false) @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate hasta,
+            @RequestParam(defaultValue = "TELEFONO") Ancho ancho
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        gestionarPermisosUseCase.exigir(localId, Permiso.VER_REPORTES);
+        LocalDate fin = hasta != null ? hasta : desde;
+
+        byte[] png = generarImagenReporteUseCase.ejecutar(localId, vista, desde, fin, ancho);
+
+        HttpHeaders headers = new HttpHeaders();
+        headers.setContentType(MediaType.IMAGE_PNG);
+        headers.setContentDispositionFormData("attachment", nombreArchivo(vista, desde, fin));
+
+        return new ResponseEntity<>(png, headers, HttpStatus.OK);
+    }
+
+    private String nombreArchivo(Vista vista, LocalDate desde, LocalDate hasta) {
+        String periodo = desde.equals(hasta) ? desde.toString() : desde + "_" + hasta;
+        return String.format("%s-%s.png", vista.name().toLowerCase(), periodo);
+    }
+}
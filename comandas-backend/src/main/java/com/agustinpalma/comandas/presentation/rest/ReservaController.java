@@ -0,0 +1,87 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.ReservaRequest;
+import com.agustinpalma.comandas.application.dto.ReservaResponse;
+import com.agustinpalma.comandas.application.dto.SeniaReservaRequest;
+import com.agustinpalma.comandas.application.dto.SentarReservaRequest;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.GestionarReservasUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ReservaId;
+import jakarta.validation.Valid;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.LocalDate;
+import java.util.List;
+
+/**
+ * Controller REST de reservas de mesa.
+ * HU-142: la seña entra a la caja como ingreso del día en que se cobra y se
+ * descuenta del total del pedido en el que se sienta la reserva.
+ *
+ * Endpoints:
+ * - GET  /api/reservas?desde&hasta       -> Reservas de los días del rango
+ * - POST /api/reservas                   -> Tomar una reserva (con seña opcional)
+ * - POST /api/reservas/{id}/senia        -> Cobrar la seña de una reserva ya tomada
+ * - POST /api/reservas/{id}/sentar       -> Sentar la reserva en una mesa abierta
+ * - POST /api/reservas/{id}/cancelar     -> Cancelar la reserva
+ */
+@RestController
+@RequestMapping("/api/reservas")
+public class ReservaController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarReservasUseCase gestionarReservasUseCase;
+
+    public ReservaController(
+        LocalContextProvider localContextProvider,
+        GestionarReservasUseCase gestionarReservasUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarReservasUseCase = gestionarReservasUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<List<ReservaResponse>> listar(
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate desde,
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate hasta
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarReservasUseCase.listar(localId, desde, hasta));
+    }
+
+    @PostMapping
+    public ResponseEntity<ReservaResponse> crear(@Valid @RequestBody ReservaRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED).body(gestionarReservasUseCase.crear(localId, request));
+    }
+
+    @PostMapping("/{id}/senia")
+    public ResponseEntity<ReservaResponse> cobrarSenia(
+        @PathVariable String id,
+        @Valid @RequestBody SeniaReservaRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarReservasUseCase.cobrarSenia(localId, ReservaId.from(id), request.monto()));
+    }
+
+    @PostMapping("/{id}/sentar")
+    public ResponseEntity<ReservaResponse> sentar(
+        @PathVariable String id,
+        @Valid @RequestBody SentarReservaRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(
+            gestionarReservasUseCase.sentar(localId, ReservaId.from(id), new MesaId(request.mesaId())));
+    }
+
+    @PostMapping("/{id}/cancelar")
+    public ResponseEntity<ReservaResponse> cancelar(@PathVariable String id) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarReservasUseCase.cancelar(localId, ReservaId.from(id)));
+    }
+}
@@ -0,0 +1,46 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.ReporteRotacionMesasResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarRotacionMesasUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.DayOfWeek;
+import java.time.LocalDate;
+
+/**
+ * Controller REST del reporte de rotación de mesas.
+ * HU-150: tiempo promedio de ocupación por franja horaria para dimensionar
+ * cuántos cubiertos más se pueden tomar.
+ *
+ * Endpoints:
+ * - GET /api/rotacion-mesas?desde&hasta[&diaSemana=SATURDAY] -> Ocupaciones por hora de apertura
+ */
+@RestController
+@RequestMapping("/api/rotacion-mesas")
+public class RotacionMesasController {
+
+    private final LocalContextProvider localContextProvider;
+    private final ConsultarRotacionMesasUseCase consultarRotacionMesasUseCase;
+
+    public RotacionMesasController(
+        LocalContextProvider localContextProvider,
+        ConsultarRotacionMesasUseCase consultarRotacionMesasUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.consultarRotacionMesasUseCase = consultarRotacionMesasUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<ReporteRotacionMesasResponse> consultar(
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate desde,
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate hasta,
+        @RequestParam(required = false) DayOfWeek diaSemana
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarRotacionMesasUseCase.ejecutar(localId, desde, hasta, diaSemana));
+    }
+}
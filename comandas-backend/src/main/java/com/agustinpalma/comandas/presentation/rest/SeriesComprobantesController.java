@@ -0,0 +1,52 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.SerieComprobantesRequest;
+import com.agustinpalma.comandas.application.dto.SerieComprobantesResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.GestionarSeriesComprobantesUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.util.List;
+
+/**
+ * Controller REST de las series de numeración de comprobantes.
+ * HU-166: cada caja numera sus comprobantes con su prefijo; el número se
+ * asigna al cerrar la mesa y vuelve en la respuesta del cierre.
+ *
+ * Endpoints:
+ * - GET /api/caja/series -> Series del local con el próximo número de cada una
+ * - PUT /api/caja/series -> Crear o cambiar la serie de una caja (cajaId null = principal)
+ */
+@RestController
+@RequestMapping("/api/caja/series")
+public class SeriesComprobantesController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarSeriesComprobantesUseCase gestionarSeriesComprobantesUseCase;
+
+    public SeriesComprobantesController(
+        LocalContextProvider localContextProvider,
+        GestionarSeriesComprobantesUseCase gestionarSeriesComprobantesUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarSeriesComprobantesUseCase = gestionarSeriesComprobantesUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<List<SerieComprobantesResponse>> listar() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarSeriesComprobantesUseCase.listar(localId));
+    }
+
+    @PutMapping
+    public ResponseEntity<SerieComprobantesResponse> configurar(@Valid @RequestBody SerieComprobantesRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        CajaId cajaId = request.cajaId() != null ? CajaId.from(request.cajaId()) : null;
+        return ResponseEntity.ok(gestionarSeriesComprobantesUseCase.configurar(
+            localId, cajaId, request.prefijo(), request.proximoNumero(), request.reinicioAnual()));
+    }
+}
@@ -0,0 +1,96 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.EstadoEnergiaEvento;
+import com.agustinpalma.comandas.application.dto.EstadoImpresoraEvento;
+import com.agustinpalma.comandas.application.dto.MantenimientoBaseResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.EjecutarMantenimientoBaseUseCase;
+import com.agustinpalma.comandas.application.usecase.GestionarEnergiaUseCase;
+import com.agustinpalma.comandas.application.usecase.NotificarEstadoImpresoraUseCase;
+import jakarta.servlet.http.HttpServletRequest;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.net.InetAddress;
+import java.net.UnknownHostException;
+
+/**
+ * Controller REST de los avisos del sistema operativo de la PC servidor.
+ * HU-155: cortes de luz informados por la UPS y apagado ordenado del backend.
+ * HU-158: impresoras sin papel, con la tapa abierta o desconectadas.
+ * HU-163: mantenimiento de la base en la ventana fuera de horario.
+ *
+ * Endpoints:
+ * - POST /api/sistema/energia    -> Difundir el estado de la energía a todas las terminales
+ * - POST /api/sistema/apagar     -> Apagado ordenado del backend
+ * - POST /api/sistema/impresoras -> Difundir el cambio de estado de una impresora
+ * - POST /api/sistema/mantenimiento -> Compactar y reindexar la base (409 si hay mesas abiertas)
+ *
+ * Solo los acepta desde la misma PC: los manda la app de escritorio que
+ * lanza el backend. Una terminal de la red no puede apagar el servidor.
+ */
+@RestController
+@RequestMapping("/api/sistema")
+public class SistemaController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarEnergiaUseCase gestionarEnergiaUseCase;
+    private final NotificarEstadoImpresoraUseCase notificarEstadoImpresoraUseCase;
+    private final EjecutarMantenimientoBaseUseCase ejecutarMantenimientoBaseUseCase;
+
+    public SistemaController(
+        LocalContextProvider localContextProvider,
+        GestionarEnergiaUseCase gestionarEnergiaUseCase,
+        NotificarEstadoImpresoraUseCase notificarEstadoImpresoraUseCase,
+        EjecutarMantenimientoBaseUseCase ejecutarMantenimientoBaseUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarEnergiaUseCase = gestionarEnergiaUseCase;
+        this.notificarEstadoImpresoraUseCase = notificarEstadoImpresoraUseCase;
+        this.ejecutarMantenimientoBaseUseCase = ejecutarMantenimientoBaseUseCase;
+    }
+
+    @PostMapping("/energia")
+    public ResponseEntity<Void> notificarEnergia(@RequestBody EstadoEnergiaEvento evento, HttpServletRequest request) {
+        if (!esLocal(request)) {
+            return ResponseEntity.status(HttpStatus.FORBIDDEN).build();
+        }
+        gestionarEnergiaUseCase.notificar(localContextProvider.getCurrentLocalId(), evento);
+        return ResponseEntity.noContent().build();
+    }
+
+    @PostMapping("/apagar")
+    public ResponseEntity<Void> apagar(HttpServletRequest request) {
+        if (!esLocal(request)) {
+            return ResponseEntity.status(HttpStatus.FORBIDDEN).build();
+        }
+        gestionarEnergiaUseCase.apagar(localContextProvider.getCurrentLocalId());
+        return ResponseEntity.accepted().build();
+    }
+
+    @PostMapping("/impresoras")
+    public ResponseEntity<Void> notificarImpresora(@RequestBody EstadoImpresoraEvento evento, HttpServletRequest request) {
+        if (!esLocal(request)) {
+            return ResponseEntity.status(HttpStatus.FORBIDDEN).build();
+        }
+        notificarEstadoImpresoraUseCase.ejecutar(localContextProvider.getCurrentLocalId(), evento);
+        return ResponseEntity.noContent().build();
+    }
+
+    @PostMapping("/mantenimiento")
+    public ResponseEntity<MantenimientoBaseResponse> ejecutarMantenimiento(HttpServletRequest request) {
+        if (!esLocal(request)) {
+            return ResponseEntity.status(HttpStatus.FORBIDDEN).build();
+        }
+        return ResponseEntity.ok(ejecutarMantenimientoBaseUseCase.ejecutar(localContextProvider.getCurrentLocalId()));
+    }
+
+    private boolean esLocal(HttpServletRequest request) {
+        try {
+            return InetAddress.getByName(request.getRemoteAddr()).isLoopbackAddress();
+        } catch (UnknownHostException e) {
+            return false;
+        }
+    }
+}
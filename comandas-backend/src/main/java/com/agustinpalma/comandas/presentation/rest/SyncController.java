@@ -0,0 +1,87 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.LoteVentasSucursalDTO;
+import com.agustinpalma.comandas.application.dto.RecepcionVentasResponse;
+import com.agustinpalma.comandas.application.dto.ReporteSucursalesResponse;
+import com.agustinpalma.comandas.application.ports.output.IdentidadSucursalProvider;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.ConsultarReporteSucursalesUseCase;
+import com.agustinpalma.comandas.application.usecase.ExportarVentasCerradasUseCase;
+import com.agustinpalma.comandas.application.usecase.RecibirVentasSucursalUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.Map;
+import java.util.UUID;
+
+/**
+ * Controller REST de sincronización entre sucursales.
+ * HU-107: identidad de la instalación, intercambio de ventas cerradas y
+ * reporte comparativo.
+ *
+ * Endpoints:
+ * - GET  /api/sync/identidad                     -> Sucursal de esta instalación
+ * - GET  /api/sync/ventas-cerradas?desde&desdePedido -> Lote de ventas a enviar (lo usa Tauri)
+ * - POST /api/sync/ventas                        -> Recepción de un lote en el consolidado
+ * - GET  /api/sync/reporte-sucursales?desde&hasta -> Comparativo por sucursal
+ */
+@RestController
+@RequestMapping("/api/sync")
+public class SyncController {
+
+    private final LocalContextProvider localContextProvider;
+    private final IdentidadSucursalProvider identidadSucursalProvider;
+    private final ExportarVentasCerradasUseCase exportarVentasCerradasUseCase;
+    private final RecibirVentasSucursalUseCase recibirVentasSucursalUseCase;
+    private final ConsultarReporteSucursalesUseCase consultarReporteSucursalesUseCase;
+
+    public SyncController(
+        LocalContextProvider localContextProvider,
+        IdentidadSucursalProvider identidadSucursalProvider,
+        ExportarVentasCerradasUseCase exportarVentasCerradasUseCase,
+        RecibirVentasSucursalUseCase recibirVentasSucursalUseCase,
+        ConsultarReporteSucursalesUseCase consultarReporteSucursalesUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.identidadSucursalProvider = identidadSucursalProvider;
+        this.exportarVentasCerradasUseCase = exportarVentasCerradasUseCase;
+        this.recibirVentasSucursalUseCase = recibirVentasSucursalUseCase;
+        this.consultarReporteSucursalesUseCase = consultarReporteSucursalesUseCase;
+    }
+
+    @GetMapping("/identidad")
+    public ResponseEntity<Map<String, Object>> obtenerIdentidad() {
+        return ResponseEntity.ok(Map.of(
+            "sucursalId", identidadSucursalProvider.getSucursalId().getValue(),
+            "nombre", identidadSucursalProvider.getNombreSucursal()
+        ));
+    }
+
+    @GetMapping("/ventas-cerradas")
+    public ResponseEntity<LoteVentasSucursalDTO> exportarVentasCerradas(
+        @RequestParam(required = false) @DateTimeFormat(iso = DateTimeFormat.ISO.DATE_TIME) LocalDateTime desde,
+        @RequestParam(required = false) UUID desdePedido
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(exportarVentasCerradasUseCase.ejecutar(localId, desde, desdePedido));
+    }
+
+    @PostMapping("/ventas")
+    public ResponseEntity<RecepcionVentasResponse> recibirVentas(@RequestBody LoteVentasSucursalDTO lote) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(recibirVentasSucursalUseCase.ejecutar(localId, lote));
+    }
+
+    @GetMapping("/reporte-sucursales")
+    public ResponseEntity<ReporteSucursalesResponse> obtenerReporteSucursales(
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate desde,
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate hasta
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(consultarReporteSucursalesUseCase.ejecutar(localId, desde, hasta));
+    }
+}
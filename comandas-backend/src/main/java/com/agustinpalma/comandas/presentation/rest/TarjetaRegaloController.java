@@ -0,0 +1,62 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.EmitirTarjetaRegaloRequest;
+import com.agustinpalma.comandas.application.dto.ReporteTarjetasRegaloResponse;
+import com.agustinpalma.comandas.application.dto.TarjetaRegaloResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.GestionarTarjetasRegaloUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.LocalDate;
+
+/**
+ * Controller REST de tarjetas de regalo.
+ * HU-144: la venta entra a la caja como ingreso; el uso se cobra con el
+ * medio TARJETA_REGALO al cerrar la mesa.
+ *
+ * Endpoints:
+ * - POST /api/tarjetas-regalo                    -> Vender una tarjeta (genera el código)
+ * - GET  /api/tarjetas-regalo/{codigo}           -> Validar un código y ver su saldo
+ * - GET  /api/tarjetas-regalo/reporte?desde&hasta -> Emitidas, usadas y vencidas en el rango
+ */
+@RestController
+@RequestMapping("/api/tarjetas-regalo")
+public class TarjetaRegaloController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarTarjetasRegaloUseCase gestionarTarjetasRegaloUseCase;
+
+    public TarjetaRegaloController(
+        LocalContextProvider localContextProvider,
+        GestionarTarjetasRegaloUseCase gestionarTarjetasRegaloUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarTarjetasRegaloUseCase = gestionarTarjetasRegaloUseCase;
+    }
+
+    @PostMapping
+    public ResponseEntity<TarjetaRegaloResponse> emitir(@Valid @RequestBody EmitirTarjetaRegaloRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED).body(gestionarTarjetasRegaloUseCase.emitir(localId, request));
+    }
+
+    @GetMapping("/reporte")
+    public ResponseEntity<ReporteTarjetasRegaloResponse> reporte(
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate desde,
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate hasta
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarTarjetasRegaloUseCase.reporte(localId, desde, hasta));
+    }
+
+    @GetMapping("/{codigo}")
+    public ResponseEntity<TarjetaRegaloResponse> consultar(@PathVariable String codigo) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarTarjetasRegaloUseCase.consultar(localId, codigo));
+    }
+}
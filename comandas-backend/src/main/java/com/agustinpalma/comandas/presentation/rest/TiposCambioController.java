@@ -0,0 +1,53 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.TiposCambioRequest;
+import com.agustinpalma.comandas.application.dto.TiposCambioResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.GestionarTiposCambioUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import jakarta.validation.Valid;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.LocalDate;
+
+/**
+ * Controller REST de tipos de cambio.
+ * HU-151: cotización del día de cada moneda extranjera que se acepta en caja.
+ *
+ * Endpoints:
+ * - GET /api/caja/tipos-cambio[?fecha=YYYY-MM-DD]  -> Cotizaciones del día (hoy por defecto)
+ * - PUT /api/caja/tipos-cambio                     -> Cargar las cotizaciones de un día
+ *
+ * El tipo de cambio se congela en cada pago al cerrar la mesa (POST /api/mesas/{mesaId}/cierre).
+ */
+@RestController
+@RequestMapping("/api/caja/tipos-cambio")
+public class TiposCambioController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarTiposCambioUseCase gestionarTiposCambioUseCase;
+
+    public TiposCambioController(
+        LocalContextProvider localContextProvider,
+        GestionarTiposCambioUseCase gestionarTiposCambioUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarTiposCambioUseCase = gestionarTiposCambioUseCase;
+    }
+
+    @GetMapping
+    public ResponseEntity<TiposCambioResponse> obtener(
+        @RequestParam(required = false) @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate fecha
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarTiposCambioUseCase.consultar(localId, fecha));
+    }
+
+    @PutMapping
+    public ResponseEntity<TiposCambioResponse> guardar(@Valid @RequestBody TiposCambioRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarTiposCambioUseCase.guardar(localId, request));
+    }
+}
@@ -0,0 +1,74 @@
+package com.agustinpalma.comandas.presentation.rest;
+
+import com.agustinpalma.comandas.application.dto.ReporteEnviosResponse;
+import com.agustinpalma.comandas.application.dto.ZonaDeliveryRequest;
+import com.agustinpalma.comandas.application.dto.ZonaDeliveryResponse;
+import com.agustinpalma.comandas.application.ports.output.LocalContextProvider;
+import com.agustinpalma.comandas.application.usecase.GestionarZonasDeliveryUseCase;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ZonaDeliveryId;
+import jakarta.validation.Valid;
+import org.springframework.format.annotation.DateTimeFormat;
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.*;
+
+import java.time.LocalDate;
+import java.util.List;
+
+/**
+ * Controller REST de zonas de reparto.
+ * HU-139: la entrega de cada pedido se carga con PUT /api/pedidos/{id}/entrega.
+ *
+ * Endpoints:
+ * - GET  /api/delivery/zonas                         -> Zonas del local (activas e inactivas)
+ * - POST /api/delivery/zonas                         -> Alta de zona
+ * - PUT  /api/delivery/zonas/{id}                    -> Editar / activar / desactivar
+ * - GET  /api/delivery/envios?desde&hasta            -> Envíos cobrados por zona, aparte de los productos
+ */
+@RestController
+@RequestMapping("/api/delivery")
+public class ZonaDeliveryController {
+
+    private final LocalContextProvider localContextProvider;
+    private final GestionarZonasDeliveryUseCase gestionarZonasDeliveryUseCase;
+
+    public ZonaDeliveryController(
+        LocalContextProvider localContextProvider,
+        GestionarZonasDeliveryUseCase gestionarZonasDeliveryUseCase
+    ) {
+        this.localContextProvider = localContextProvider;
+        this.gestionarZonasDeliveryUseCase = gestionarZonasDeliveryUseCase;
+    }
+
+    @GetMapping("/zonas")
+    public ResponseEntity<List<ZonaDeliveryResponse>> listar() {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarZonasDeliveryUseCase.listar(localId));
+    }
+
+    @PostMapping("/zonas")
+    public ResponseEntity<ZonaDeliveryResponse> crear(@Valid @RequestBody ZonaDeliveryRequest request) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.status(HttpStatus.CREATED).body(gestionarZonasDeliveryUseCase.crear(localId, request));
+    }
+
+    @PutMapping("/zonas/{zonaId}")
+    public ResponseEntity<ZonaDeliveryResponse> actualizar(
+        @PathVariable String zonaId,
+        @Valid @RequestBody ZonaDeliveryRequest request
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(
+            gestionarZonasDeliveryUseCase.actualizar(localId, ZonaDeliveryId.from(zonaId), request));
+    }
+
+    @GetMapping("/envios")
+    public ResponseEntity<ReporteEnviosResponse> reporteEnvios(
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate desde,
+        @RequestParam @DateTimeFormat(iso = DateTimeFormat.ISO.DATE) LocalDate hasta
+    ) {
+        LocalId localId = localContextProvider.getCurrentLocalId();
+        return ResponseEntity.ok(gestionarZonasDeliveryUseCase.reporteEnvios(localId, desde, hasta));
+    }
+}
@@ -0,0 +1,10 @@
+-- ============================================================
+-- V18__add_stock_minimo_to_productos.sql
+-- Migración Flyway: HU-101 Alertas de stock mínimo
+-- Umbral opcional a partir del cual el producto figura "para reponer".
+-- ============================================================
+
+ALTER TABLE productos ADD COLUMN IF NOT EXISTS stock_minimo INTEGER;
+
+ALTER TABLE productos ADD CONSTRAINT chk_productos_stock_minimo_no_negativo
+    CHECK (stock_minimo IS NULL OR stock_minimo >= 0);
@@ -0,0 +1,26 @@
+-- ============================================================
+-- V19__crear_combos.sql
+-- Migración Flyway: HU-103 Combos y menú del día
+-- Componentes de combo en catálogo y opciones elegidas en cada ítem vendido.
+-- ============================================================
+
+-- Una fila por opción elegible; las filas con igual componente_orden forman un componente
+CREATE TABLE IF NOT EXISTS productos_combo_opciones (
+    producto_id         UUID NOT NULL REFERENCES productos(id) ON DELETE CASCADE,
+    posicion            INTEGER NOT NULL,
+    componente_orden    INTEGER NOT NULL,
+    componente_nombre   VARCHAR(100) NOT NULL,
+    opcion_producto_id  UUID NOT NULL,
+    PRIMARY KEY (producto_id, posicion)
+);
+
+-- Snapshot de la opción elegida por componente al vender un combo
+CREATE TABLE IF NOT EXISTS items_pedido_combo (
+    item_pedido_id      UUID NOT NULL REFERENCES items_pedido(id) ON DELETE CASCADE,
+    orden               INTEGER NOT NULL,
+    componente          VARCHAR(100) NOT NULL,
+    producto_id         UUID NOT NULL,
+    nombre_producto     VARCHAR(100) NOT NULL,
+    precio_referencia   DECIMAL(10,2) NOT NULL,
+    PRIMARY KEY (item_pedido_id, orden)
+);
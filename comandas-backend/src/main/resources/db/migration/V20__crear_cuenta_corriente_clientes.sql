@@ -0,0 +1,35 @@
+-- ============================================================
+-- V20__crear_cuenta_corriente_clientes.sql
+-- Migración Flyway: HU-104 Cuenta corriente de clientes
+-- Clientes frecuentes con límite de crédito y libro de cargos/pagos.
+-- La deuda no se almacena: se deriva de los movimientos.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS clientes (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    local_id        UUID NOT NULL,
+    nombre          VARCHAR(150) NOT NULL,
+    telefono        VARCHAR(50),
+    limite_credito  DECIMAL(10,2) NOT NULL,
+    activo          BOOLEAN NOT NULL DEFAULT true,
+    CONSTRAINT chk_cliente_limite_no_negativo CHECK (limite_credito >= 0)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_cliente_local_nombre ON clientes(local_id, LOWER(nombre));
+
+CREATE TABLE IF NOT EXISTS movimientos_cuenta_corriente (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    cliente_id      UUID NOT NULL REFERENCES clientes(id) ON DELETE CASCADE,
+    local_id        UUID NOT NULL,
+    tipo            VARCHAR(20) NOT NULL,
+    monto           DECIMAL(10,2) NOT NULL,
+    fecha           TIMESTAMP NOT NULL,
+    pedido_id       UUID,
+    medio_pago      VARCHAR(30),
+    descripcion     VARCHAR(255) NOT NULL,
+    CONSTRAINT chk_movimiento_cc_monto_positivo CHECK (monto > 0),
+    CONSTRAINT chk_movimiento_cc_tipo CHECK (tipo IN ('CARGO', 'PAGO'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_movimiento_cc_cliente ON movimientos_cuenta_corriente(cliente_id, local_id, fecha);
+CREATE INDEX IF NOT EXISTS idx_movimiento_cc_pedido ON movimientos_cuenta_corriente(pedido_id);
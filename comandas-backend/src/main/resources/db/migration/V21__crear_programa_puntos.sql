@@ -0,0 +1,39 @@
+-- ============================================================
+-- V21__crear_programa_puntos.sql
+-- Migración Flyway: HU-105 Programa de puntos de clientes
+-- Configuración por local, libro de acumulaciones/canjes y
+-- registro del canje aplicado como descuento en cada pedido.
+-- El saldo no se almacena: se deriva de los movimientos (FIFO).
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS programas_puntos (
+    local_id          UUID PRIMARY KEY,
+    activo            BOOLEAN NOT NULL DEFAULT false,
+    monto_por_punto   DECIMAL(10,2) NOT NULL,
+    valor_punto       DECIMAL(10,2) NOT NULL,
+    dias_vencimiento  INTEGER,
+    CONSTRAINT chk_programa_puntos_monto_positivo CHECK (monto_por_punto > 0),
+    CONSTRAINT chk_programa_puntos_valor_positivo CHECK (valor_punto > 0),
+    CONSTRAINT chk_programa_puntos_dias_positivo CHECK (dias_vencimiento IS NULL OR dias_vencimiento > 0)
+);
+
+CREATE TABLE IF NOT EXISTS movimientos_puntos (
+    id                UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    cliente_id        UUID NOT NULL REFERENCES clientes(id) ON DELETE CASCADE,
+    local_id          UUID NOT NULL,
+    tipo              VARCHAR(20) NOT NULL,
+    puntos            INTEGER NOT NULL,
+    fecha             TIMESTAMP NOT NULL,
+    pedido_id         UUID NOT NULL,
+    fecha_vencimiento TIMESTAMP,
+    monto_descuento   DECIMAL(10,2),
+    CONSTRAINT chk_movimiento_puntos_positivo CHECK (puntos > 0),
+    CONSTRAINT chk_movimiento_puntos_tipo CHECK (tipo IN ('ACUMULACION', 'CANJE'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_movimiento_puntos_cliente ON movimientos_puntos(cliente_id, local_id, fecha);
+CREATE INDEX IF NOT EXISTS idx_movimiento_puntos_local ON movimientos_puntos(local_id, fecha);
+CREATE INDEX IF NOT EXISTS idx_movimiento_puntos_pedido ON movimientos_puntos(pedido_id);
+
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS puntos_canjeados INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS monto_canje_puntos DECIMAL(10,2);
@@ -0,0 +1,41 @@
+-- ============================================================
+-- V22__crear_turnos_caja.sql
+-- Migración Flyway: HU-106 Turnos de caja con arqueo por turno
+-- Cada jornada puede dividirse en turnos (mañana/tarde/noche).
+-- Pedidos y movimientos de caja referencian el turno abierto
+-- al momento de registrarse; es null si no se usaron turnos.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS turnos_caja (
+    id                     UUID PRIMARY KEY,
+    local_id               UUID NOT NULL,
+    jornada_id             UUID NOT NULL REFERENCES jornadas_caja(id),
+    tipo                   VARCHAR(20) NOT NULL,
+    monto_inicial          DECIMAL(12,2) NOT NULL,
+    fecha_apertura         TIMESTAMP NOT NULL,
+    fecha_cierre           TIMESTAMP,
+    total_ventas           DECIMAL(12,2),
+    ventas_efectivo        DECIMAL(12,2),
+    total_ingresos         DECIMAL(12,2),
+    total_egresos          DECIMAL(12,2),
+    efectivo_esperado      DECIMAL(12,2),
+    efectivo_declarado     DECIMAL(12,2),
+    pedidos_cerrados_count INTEGER NOT NULL DEFAULT 0,
+    estado                 VARCHAR(20) NOT NULL,
+    CONSTRAINT uk_turno_jornada_tipo UNIQUE (jornada_id, tipo),
+    CONSTRAINT chk_turno_tipo CHECK (tipo IN ('MANANA', 'TARDE', 'NOCHE')),
+    CONSTRAINT chk_turno_estado CHECK (estado IN ('ABIERTO', 'CERRADO')),
+    CONSTRAINT chk_turno_monto_inicial CHECK (monto_inicial >= 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_turno_local_estado ON turnos_caja(local_id, estado);
+
+-- Un solo turno abierto por local
+CREATE UNIQUE INDEX IF NOT EXISTS uk_turno_abierto_por_local
+    ON turnos_caja(local_id) WHERE estado = 'ABIERTO';
+
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS turno_id UUID;
+ALTER TABLE movimientos_caja ADD COLUMN IF NOT EXISTS turno_id UUID;
+
+CREATE INDEX IF NOT EXISTS idx_pedido_turno ON pedidos(turno_id);
+CREATE INDEX IF NOT EXISTS idx_movimiento_caja_turno ON movimientos_caja(turno_id);
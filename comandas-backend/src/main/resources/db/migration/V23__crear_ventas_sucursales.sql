@@ -0,0 +1,27 @@
+-- ============================================================
+-- V23__crear_ventas_sucursales.sql
+-- Migración Flyway: HU-107 Multi-sucursal con reportes consolidados
+-- Copia resumida de los pedidos cerrados que envían otras
+-- sucursales. No se vincula con caja, jornadas ni stock.
+-- La PK es el pedido de origen: reenviar un lote es idempotente.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS ventas_sucursales (
+    pedido_origen_id UUID PRIMARY KEY,
+    local_id         UUID NOT NULL,
+    sucursal_id      UUID NOT NULL,
+    nombre_sucursal  VARCHAR(100) NOT NULL,
+    numero_pedido    INTEGER NOT NULL,
+    fecha_cierre     TIMESTAMP NOT NULL,
+    fecha_recepcion  TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_venta_sucursal_local_fecha ON ventas_sucursales(local_id, fecha_cierre);
+
+CREATE TABLE IF NOT EXISTS ventas_sucursales_pagos (
+    venta_id   UUID NOT NULL REFERENCES ventas_sucursales(pedido_origen_id) ON DELETE CASCADE,
+    medio_pago VARCHAR(20) NOT NULL,
+    monto      DECIMAL(12,2) NOT NULL,
+    PRIMARY KEY (venta_id, medio_pago),
+    CONSTRAINT chk_venta_sucursal_monto CHECK (monto >= 0)
+);
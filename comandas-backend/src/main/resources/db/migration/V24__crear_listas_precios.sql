@@ -0,0 +1,31 @@
+-- ============================================================
+-- V24__crear_listas_precios.sql
+-- Migración Flyway: HU-108 Listas de precios por canal
+-- Una lista por canal (SALON, TAKE_AWAY, DELIVERY) y local, con
+-- recargo porcentual y precios especiales por producto.
+-- El pedido congela el canal y la lista que usó al crearse;
+-- los pedidos previos quedan como SALON sin lista (catálogo).
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS listas_precios (
+    id                 UUID PRIMARY KEY,
+    local_id           UUID NOT NULL,
+    canal              VARCHAR(20) NOT NULL,
+    nombre             VARCHAR(100) NOT NULL,
+    porcentaje_recargo DECIMAL(5,2) NOT NULL DEFAULT 0,
+    CONSTRAINT uk_lista_precios_local_canal UNIQUE (local_id, canal),
+    CONSTRAINT chk_lista_precios_canal CHECK (canal IN ('SALON', 'TAKE_AWAY', 'DELIVERY')),
+    CONSTRAINT chk_lista_precios_recargo CHECK (porcentaje_recargo >= 0 AND porcentaje_recargo <= 100)
+);
+
+CREATE TABLE IF NOT EXISTS listas_precios_productos (
+    lista_id    UUID NOT NULL REFERENCES listas_precios(id) ON DELETE CASCADE,
+    producto_id UUID NOT NULL,
+    precio      DECIMAL(10,2) NOT NULL,
+    PRIMARY KEY (lista_id, producto_id),
+    CONSTRAINT chk_lista_precios_precio CHECK (precio > 0)
+);
+
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS canal VARCHAR(20);
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS lista_precios_id UUID;
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS nombre_lista_precios VARCHAR(100);
@@ -0,0 +1,9 @@
+-- ============================================================
+-- V25__add_etiqueta_variante_to_productos.sql
+-- Migración Flyway: HU-109 Variantes de producto (tamaños)
+-- Rótulo corto de la variante dentro de su grupo ("Chica",
+-- "Grande", "1.5 L") para el selector rápido del POS.
+-- Null en productos sin variantes y en variantes previas.
+-- ============================================================
+
+ALTER TABLE productos ADD COLUMN IF NOT EXISTS etiqueta_variante VARCHAR(30);
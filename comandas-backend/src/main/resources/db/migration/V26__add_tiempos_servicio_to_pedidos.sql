@@ -0,0 +1,11 @@
+-- ============================================================
+-- V26__add_tiempos_servicio_to_pedidos.sql
+-- Migración Flyway: HU-110 Secuencia de pasos a cocina
+-- Cada ítem puede llevar su paso (ENTRADA, PRINCIPAL, POSTRE);
+-- el pedido guarda hasta qué paso se liberó a cocina.
+-- Null en ambos casos para pedidos e ítems previos.
+-- ============================================================
+
+ALTER TABLE items_pedido ADD COLUMN IF NOT EXISTS tiempo_servicio VARCHAR(20);
+
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS tiempo_en_marcha VARCHAR(20);
@@ -0,0 +1,23 @@
+-- ============================================================
+-- V27__crear_mozos_y_atencion_pedidos.sql
+-- Migración Flyway: HU-111 Reporte de desempeño por mozo
+-- Alta de mozos por local. El pedido guarda el mozo que atendió
+-- la mesa, los cubiertos, la propina del cobro y las unidades
+-- anuladas después de enviarse a cocina.
+-- Los pedidos previos quedan sin mozo, sin cubiertos ni propina.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS mozos (
+    id       UUID PRIMARY KEY,
+    local_id UUID NOT NULL,
+    nombre   VARCHAR(60) NOT NULL,
+    activo   BOOLEAN NOT NULL DEFAULT TRUE
+);
+
+CREATE INDEX IF NOT EXISTS idx_mozos_local ON mozos(local_id);
+
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS mozo_id UUID;
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS cubiertos INT NOT NULL DEFAULT 0;
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS propina DECIMAL(10,2);
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS unidades_anuladas INT NOT NULL DEFAULT 0;
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS monto_anulado DECIMAL(10,2);
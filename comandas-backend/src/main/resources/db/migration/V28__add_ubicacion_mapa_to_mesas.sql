@@ -0,0 +1,12 @@
+-- ============================================================
+-- V28__add_ubicacion_mapa_to_mesas.sql
+-- Migración Flyway: HU-112 Editor del mapa de mesas
+-- Cada mesa pertenece a un sector (salón, vereda, barra...) y
+-- puede tener una celda fija (columna, fila) en el mapa.
+-- Las mesas existentes quedan en el sector "Salón" sin posición:
+-- el mapa las acomoda por número hasta que se las ubique.
+-- ============================================================
+
+ALTER TABLE mesas ADD COLUMN IF NOT EXISTS sector VARCHAR(30) NOT NULL DEFAULT 'Salón';
+ALTER TABLE mesas ADD COLUMN IF NOT EXISTS posicion_x INT;
+ALTER TABLE mesas ADD COLUMN IF NOT EXISTS posicion_y INT;
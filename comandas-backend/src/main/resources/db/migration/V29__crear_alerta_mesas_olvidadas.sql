@@ -0,0 +1,19 @@
+-- ============================================================
+-- V29__crear_alerta_mesas_olvidadas.sql
+-- Migración Flyway: HU-113 Alerta de mesas olvidadas
+-- Configuración por local del umbral de inactividad y registro
+-- de la última atención de cada pedido abierto.
+-- Los pedidos existentes toman como última actividad la apertura.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS alertas_mesas_olvidadas (
+    local_id              UUID PRIMARY KEY,
+    activa                BOOLEAN NOT NULL DEFAULT TRUE,
+    minutos_sin_actividad INT NOT NULL DEFAULT 30
+);
+
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS ultima_actividad TIMESTAMP;
+
+UPDATE pedidos
+SET ultima_actividad = fecha_apertura
+WHERE ultima_actividad IS NULL;
@@ -0,0 +1,29 @@
+-- ============================================================
+-- V30__crear_facturas.sql
+-- Migración Flyway: HU-115 Facturación A/B/C
+-- Una factura por pedido cerrado. La numeración es correlativa
+-- por local, punto de venta y letra. El CAE queda en null
+-- mientras la factura esté pendiente de autorización.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS facturas (
+    id                     UUID PRIMARY KEY,
+    local_id               UUID NOT NULL,
+    pedido_id              UUID NOT NULL,
+    tipo                   VARCHAR(1) NOT NULL,
+    punto_venta            INT NOT NULL,
+    numero                 BIGINT NOT NULL,
+    fecha_emision          TIMESTAMP NOT NULL,
+    receptor_cuit          VARCHAR(11),
+    receptor_razon_social  VARCHAR(120),
+    receptor_condicion_iva VARCHAR(30) NOT NULL,
+    alicuota_iva           DECIMAL(5,2) NOT NULL,
+    importe_neto           DECIMAL(10,2) NOT NULL,
+    importe_iva            DECIMAL(10,2) NOT NULL,
+    importe_total          DECIMAL(10,2) NOT NULL,
+    estado                 VARCHAR(30) NOT NULL,
+    cae                    VARCHAR(20),
+    cae_vencimiento        DATE,
+    CONSTRAINT uk_facturas_pedido UNIQUE (pedido_id),
+    CONSTRAINT uk_facturas_numeracion UNIQUE (local_id, punto_venta, tipo, numero)
+);
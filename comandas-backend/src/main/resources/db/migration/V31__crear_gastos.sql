@@ -0,0 +1,40 @@
+-- ============================================================
+-- V31__crear_gastos.sql
+-- Migración Flyway: HU-117 Compras a proveedores y gastos
+-- Compras de mercadería y gastos fijos con categoría, proveedor
+-- y medio de pago. Los productos comprados (ingreso de stock)
+-- van en gastos_items; el archivo del comprobante, en
+-- gastos_comprobantes para no cargarlo al listar.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS gastos (
+    id                         UUID PRIMARY KEY,
+    local_id                   UUID NOT NULL,
+    fecha                      DATE NOT NULL,
+    categoria                  VARCHAR(20) NOT NULL,
+    proveedor                  VARCHAR(100),
+    descripcion                VARCHAR(200),
+    monto                      DECIMAL(10,2) NOT NULL,
+    medio_pago                 VARCHAR(20) NOT NULL,
+    fecha_registro             TIMESTAMP NOT NULL,
+    movimiento_caja_id         UUID,
+    nombre_archivo_comprobante VARCHAR(200)
+);
+
+CREATE INDEX IF NOT EXISTS idx_gastos_local_fecha ON gastos(local_id, fecha);
+
+CREATE TABLE IF NOT EXISTS gastos_items (
+    gasto_id        UUID NOT NULL REFERENCES gastos(id) ON DELETE CASCADE,
+    producto_id     UUID NOT NULL,
+    nombre_producto VARCHAR(100) NOT NULL,
+    cantidad        INT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_gastos_items_gasto ON gastos_items(gasto_id);
+
+CREATE TABLE IF NOT EXISTS gastos_comprobantes (
+    gasto_id       UUID PRIMARY KEY REFERENCES gastos(id) ON DELETE CASCADE,
+    nombre_archivo VARCHAR(200) NOT NULL,
+    content_type   VARCHAR(50) NOT NULL,
+    contenido      BYTEA NOT NULL
+);
@@ -0,0 +1,32 @@
+-- ============================================================
+-- V32__crear_insumos_y_recetas.sql
+-- Migración Flyway: HU-118 Costeo de recetas y rentabilidad
+-- Insumos con costo por unidad de medida y recetas por
+-- producto (una por producto). El costo teórico se calcula
+-- al consultar, con el costo vigente de cada insumo.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS insumos (
+    id             UUID PRIMARY KEY,
+    local_id       UUID NOT NULL,
+    nombre         VARCHAR(60) NOT NULL,
+    unidad         VARCHAR(20) NOT NULL,
+    costo_unitario DECIMAL(12,4) NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_insumos_local ON insumos(local_id);
+
+CREATE TABLE IF NOT EXISTS recetas (
+    producto_id UUID PRIMARY KEY,
+    local_id    UUID NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_recetas_local ON recetas(local_id);
+
+CREATE TABLE IF NOT EXISTS recetas_ingredientes (
+    producto_id UUID NOT NULL REFERENCES recetas(producto_id) ON DELETE CASCADE,
+    insumo_id   UUID NOT NULL REFERENCES insumos(id),
+    cantidad    DECIMAL(10,3) NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_recetas_ingredientes_producto ON recetas_ingredientes(producto_id);
@@ -0,0 +1,31 @@
+-- ============================================================
+-- V33__crear_comandas_cocina.sql
+-- Migración Flyway: HU-119 Tiempos de cocina y alertas de demora
+-- Cada envío operativo a cocina registra una comanda con sus
+-- ítems; lista_en se completa cuando cocina la despacha.
+-- Las categorías pueden definir sus minutos de alerta.
+-- ============================================================
+
+ALTER TABLE categorias ADD COLUMN IF NOT EXISTS minutos_alerta_cocina INTEGER;
+
+CREATE TABLE IF NOT EXISTS comandas_cocina (
+    id             UUID PRIMARY KEY,
+    local_id       UUID NOT NULL,
+    pedido_id      UUID NOT NULL,
+    numero_mesa    INTEGER NOT NULL,
+    numero_pedido  INTEGER NOT NULL,
+    enviada_en     TIMESTAMP NOT NULL,
+    minutos_alerta INTEGER NOT NULL,
+    lista_en       TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_comandas_cocina_local_enviada ON comandas_cocina(local_id, enviada_en);
+
+CREATE TABLE IF NOT EXISTS comandas_cocina_items (
+    comanda_id      UUID NOT NULL REFERENCES comandas_cocina(id) ON DELETE CASCADE,
+    orden           INTEGER NOT NULL,
+    nombre_producto VARCHAR(100) NOT NULL,
+    cantidad        INTEGER NOT NULL,
+    observacion     VARCHAR(255),
+    PRIMARY KEY (comanda_id, orden)
+);
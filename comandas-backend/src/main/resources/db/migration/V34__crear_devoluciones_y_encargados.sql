@@ -0,0 +1,16 @@
+-- ============================================================
+-- V34__crear_devoluciones_y_encargados.sql
+-- Migración Flyway: HU-121 Devoluciones y reintegros post-cierre
+-- El personal tiene rol (MOZO / ENCARGADO) y un PIN hasheado
+-- con el que el encargado autoriza operaciones sensibles.
+-- Una devolución es un egreso de caja asociado al pedido cerrado
+-- y al encargado que la autorizó.
+-- ============================================================
+
+ALTER TABLE mozos ADD COLUMN IF NOT EXISTS rol VARCHAR(20) NOT NULL DEFAULT 'MOZO';
+ALTER TABLE mozos ADD COLUMN IF NOT EXISTS pin_hash VARCHAR(64);
+
+ALTER TABLE movimientos_caja ADD COLUMN IF NOT EXISTS pedido_id UUID;
+ALTER TABLE movimientos_caja ADD COLUMN IF NOT EXISTS autorizado_por_id UUID;
+
+CREATE INDEX IF NOT EXISTS idx_movimiento_caja_pedido ON movimientos_caja(pedido_id);
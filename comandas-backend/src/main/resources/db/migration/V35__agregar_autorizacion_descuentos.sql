@@ -0,0 +1,13 @@
+-- ============================================================
+-- V35__agregar_autorizacion_descuentos.sql
+-- Migración Flyway: HU-122 Motor de descuentos configurables
+-- Cada descuento manual (global o por ítem) registra quién lo
+-- autorizó: el mozo hasta su tope o el encargado con PIN.
+-- El nombre se congela al aplicar el descuento.
+-- ============================================================
+
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS desc_global_autorizado_por_id UUID;
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS desc_global_autorizado_por VARCHAR(60);
+
+ALTER TABLE items_pedido ADD COLUMN IF NOT EXISTS desc_manual_autorizado_por_id UUID;
+ALTER TABLE items_pedido ADD COLUMN IF NOT EXISTS desc_manual_autorizado_por VARCHAR(60);
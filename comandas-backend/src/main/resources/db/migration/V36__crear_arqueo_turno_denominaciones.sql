@@ -0,0 +1,15 @@
+-- ============================================================
+-- V36__crear_arqueo_turno_denominaciones.sql
+-- Migración Flyway: HU-123 Arqueo de caja con detalle de denominaciones
+-- Conteo del cajón por billete/moneda al cerrar un turno. El
+-- efectivo declarado del turno es la suma de estos renglones;
+-- los turnos cerrados solo con el total no tienen filas.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS arqueo_turno_denominaciones (
+    turno_id     UUID NOT NULL REFERENCES turnos_caja(id) ON DELETE CASCADE,
+    orden        INTEGER NOT NULL,
+    denominacion DECIMAL(12,2) NOT NULL,
+    cantidad     INTEGER NOT NULL,
+    PRIMARY KEY (turno_id, orden)
+);
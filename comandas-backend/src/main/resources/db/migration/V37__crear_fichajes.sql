@@ -0,0 +1,29 @@
+-- ============================================================
+-- V37__crear_fichajes.sql
+-- Migración Flyway: HU-124 Fichaje de personal (control horario)
+-- Cada fichaje es una entrada y su salida (null mientras el
+-- empleado está presente). Las correcciones del dueño conservan
+-- las marcas originales de la terminal. El personal puede tener
+-- una tarjeta de fichaje, única por local, y el rol DUENO.
+-- ============================================================
+
+ALTER TABLE mozos ADD COLUMN IF NOT EXISTS codigo_tarjeta VARCHAR(32);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uk_mozo_local_tarjeta ON mozos(local_id, codigo_tarjeta);
+
+CREATE TABLE IF NOT EXISTS fichajes (
+    id                UUID PRIMARY KEY,
+    local_id          UUID NOT NULL,
+    mozo_id           UUID NOT NULL REFERENCES mozos(id),
+    medio             VARCHAR(20) NOT NULL,
+    entrada           TIMESTAMP NOT NULL,
+    salida            TIMESTAMP,
+    entrada_original  TIMESTAMP,
+    salida_original   TIMESTAMP,
+    motivo_correccion VARCHAR(200),
+    corregido_por_id  UUID,
+    fecha_correccion  TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_fichajes_local_entrada ON fichajes(local_id, entrada);
+CREATE INDEX IF NOT EXISTS idx_fichajes_mozo_salida ON fichajes(local_id, mozo_id, salida);
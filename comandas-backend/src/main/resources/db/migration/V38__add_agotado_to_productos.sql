@@ -0,0 +1,9 @@
+-- ============================================================
+-- V38__add_agotado_to_productos.sql
+-- Migración Flyway: HU-125 Disponibilidad de productos (86)
+-- Marca de "sin stock" que se activa a mano desde cualquier
+-- terminal cuando la cocina avisa que no hay más. Es
+-- independiente del stock contado y del estado activo.
+-- ============================================================
+
+ALTER TABLE productos ADD COLUMN IF NOT EXISTS agotado BOOLEAN NOT NULL DEFAULT FALSE;
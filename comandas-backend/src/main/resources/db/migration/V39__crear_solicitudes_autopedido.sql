@@ -0,0 +1,35 @@
+-- ============================================================
+-- V39__crear_solicitudes_autopedido.sql
+-- Migración Flyway: HU-126 Autopedido por QR en la mesa
+-- El cliente arma su pedido desde la carta del QR y queda
+-- PENDIENTE hasta que el mozo lo confirma (sus ítems pasan al
+-- pedido de la mesa) o lo rechaza con un motivo. Los ítems
+-- guardan el nombre y el precio que vio el cliente.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS solicitudes_autopedido (
+    id              UUID PRIMARY KEY,
+    local_id        UUID NOT NULL,
+    mesa_id         UUID NOT NULL,
+    numero_mesa     INTEGER NOT NULL,
+    nombre_cliente  VARCHAR(40),
+    creada_en       TIMESTAMP NOT NULL,
+    estado          VARCHAR(20) NOT NULL,
+    resuelta_en     TIMESTAMP,
+    resuelta_por_id UUID,
+    motivo_rechazo  VARCHAR(140)
+);
+
+CREATE INDEX IF NOT EXISTS idx_solicitudes_autopedido_local_estado
+    ON solicitudes_autopedido(local_id, estado, creada_en);
+
+CREATE TABLE IF NOT EXISTS solicitudes_autopedido_items (
+    solicitud_id    UUID NOT NULL REFERENCES solicitudes_autopedido(id) ON DELETE CASCADE,
+    orden           INTEGER NOT NULL,
+    producto_id     UUID NOT NULL,
+    nombre_producto VARCHAR(100) NOT NULL,
+    precio_unitario DECIMAL(10, 2) NOT NULL,
+    cantidad        INTEGER NOT NULL,
+    observacion     VARCHAR(140),
+    PRIMARY KEY (solicitud_id, orden)
+);
@@ -0,0 +1,17 @@
+-- ============================================================
+-- V40__crear_productos_fotos.sql
+-- Migración Flyway: HU-127 Carta digital
+-- Foto de cada producto para la carta que se abre desde el QR.
+-- Va en una tabla aparte para que listar el catálogo no traiga
+-- las imágenes; actualizada_en versiona la URL de la foto.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS productos_fotos (
+    producto_id    UUID PRIMARY KEY REFERENCES productos(id) ON DELETE CASCADE,
+    local_id       UUID NOT NULL,
+    content_type   VARCHAR(50) NOT NULL,
+    contenido      BYTEA NOT NULL,
+    actualizada_en TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_productos_fotos_local ON productos_fotos(local_id);
@@ -0,0 +1,31 @@
+-- ============================================================
+-- V41__crear_links_pago.sql
+-- Migración Flyway: HU-128 Links de pago para delivery
+-- Cada link cobra el total de un pedido de delivery por una
+-- pasarela (Mercado Pago / MODO). Mientras está PENDIENTE el
+-- pedido no sale a cocina; al acreditarse queda PAGADO con el
+-- id del pago en la pasarela.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS links_pago (
+    id               UUID PRIMARY KEY,
+    local_id         UUID NOT NULL,
+    pedido_id        UUID NOT NULL,
+    mesa_id          UUID NOT NULL,
+    numero_mesa      INTEGER NOT NULL,
+    numero_pedido    INTEGER NOT NULL,
+    proveedor        VARCHAR(20) NOT NULL,
+    monto            DECIMAL(10, 2) NOT NULL,
+    telefono_cliente VARCHAR(30),
+    url              VARCHAR(500) NOT NULL,
+    creado_en        TIMESTAMP NOT NULL,
+    estado           VARCHAR(20) NOT NULL,
+    pagado_en        TIMESTAMP,
+    id_pago_pasarela VARCHAR(60)
+);
+
+CREATE INDEX IF NOT EXISTS idx_links_pago_local_estado
+    ON links_pago(local_id, estado, creado_en);
+
+CREATE INDEX IF NOT EXISTS idx_links_pago_pedido
+    ON links_pago(pedido_id);
@@ -0,0 +1,12 @@
+-- ============================================================
+-- V42__agregar_responsable_diferencias_turno.sql
+-- Migración Flyway: HU-129 Registro de faltantes y sobrantes de caja
+-- Cada arqueo de turno guarda quién respondió por la caja y las
+-- observaciones del cierre. El responsable es obligatorio (en la
+-- aplicación) cuando el declarado no coincide con el esperado.
+-- ============================================================
+
+ALTER TABLE turnos_caja ADD COLUMN IF NOT EXISTS responsable_id UUID;
+ALTER TABLE turnos_caja ADD COLUMN IF NOT EXISTS observaciones_cierre VARCHAR(500);
+
+CREATE INDEX IF NOT EXISTS idx_turno_local_cierre ON turnos_caja (local_id, fecha_cierre);
@@ -0,0 +1,18 @@
+-- ============================================================
+-- V43__crear_categorias_egreso.sql
+-- Migración Flyway: HU-130 Categorías de egresos y reporte mensual
+-- Categorías configurables por local para clasificar los
+-- egresos de caja. Los egresos existentes quedan sin categoría.
+-- Las categorías iniciales se crean al primer uso de cada local.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS categorias_egreso (
+    id       UUID PRIMARY KEY,
+    local_id UUID NOT NULL,
+    nombre   VARCHAR(40) NOT NULL,
+    activa   BOOLEAN NOT NULL DEFAULT TRUE
+);
+
+CREATE INDEX IF NOT EXISTS idx_categorias_egreso_local ON categorias_egreso(local_id);
+
+ALTER TABLE movimientos_caja ADD COLUMN IF NOT EXISTS categoria_egreso_id UUID;
@@ -0,0 +1,45 @@
+-- ============================================================
+-- V44__crear_cuenta_corriente_proveedores.sql
+-- Migración Flyway: HU-131 Cuenta corriente de proveedores
+-- Proveedores del local, facturas a pagar generadas por las
+-- compras a cuenta corriente y los pagos parciales de cada una.
+-- El saldo de la factura se guarda para listar las impagas.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS proveedores (
+    id              UUID PRIMARY KEY,
+    local_id        UUID NOT NULL,
+    nombre          VARCHAR(100) NOT NULL,
+    cuit            VARCHAR(11),
+    telefono        VARCHAR(30),
+    plazo_pago_dias INTEGER NOT NULL DEFAULT 30,
+    activo          BOOLEAN NOT NULL DEFAULT TRUE
+);
+
+CREATE INDEX IF NOT EXISTS idx_proveedores_local ON proveedores(local_id);
+
+CREATE TABLE IF NOT EXISTS facturas_proveedor (
+    id                UUID PRIMARY KEY,
+    local_id          UUID NOT NULL,
+    proveedor_id      UUID NOT NULL REFERENCES proveedores(id),
+    gasto_id          UUID NOT NULL REFERENCES gastos(id),
+    numero            VARCHAR(30),
+    fecha_emision     DATE NOT NULL,
+    fecha_vencimiento DATE NOT NULL,
+    monto             DECIMAL(10,2) NOT NULL,
+    saldo             DECIMAL(10,2) NOT NULL,
+    fecha_registro    TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_facturas_proveedor_local_vencimiento ON facturas_proveedor(local_id, fecha_vencimiento);
+CREATE INDEX IF NOT EXISTS idx_facturas_proveedor_proveedor ON facturas_proveedor(proveedor_id);
+
+CREATE TABLE IF NOT EXISTS facturas_proveedor_pagos (
+    factura_id         UUID NOT NULL REFERENCES facturas_proveedor(id) ON DELETE CASCADE,
+    fecha              TIMESTAMP NOT NULL,
+    monto              DECIMAL(10,2) NOT NULL,
+    medio_pago         VARCHAR(20) NOT NULL,
+    movimiento_caja_id UUID
+);
+
+CREATE INDEX IF NOT EXISTS idx_facturas_proveedor_pagos_factura ON facturas_proveedor_pagos(factura_id);
@@ -0,0 +1,22 @@
+-- ============================================================
+-- V45__crear_consumos_personal.sql
+-- Migración Flyway: HU-132 Límites de consumo interno del personal
+-- Cada empleado puede tener un tope mensual de consumo a cuenta.
+-- Los pagos A_CUENTA de un pedido se imputan al empleado que
+-- consumió (uno por pedido) para compararlos contra su tope.
+-- ============================================================
+
+ALTER TABLE mozos ADD COLUMN IF NOT EXISTS tope_consumo_mensual DECIMAL(10,2);
+
+CREATE TABLE IF NOT EXISTS consumos_personal (
+    id        UUID PRIMARY KEY,
+    local_id  UUID NOT NULL,
+    mozo_id   UUID NOT NULL,
+    pedido_id UUID NOT NULL,
+    monto     DECIMAL(10,2) NOT NULL,
+    fecha     TIMESTAMP NOT NULL,
+    CONSTRAINT uk_consumo_personal_pedido UNIQUE (pedido_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_consumos_personal_local_fecha ON consumos_personal(local_id, fecha);
+CREATE INDEX IF NOT EXISTS idx_consumos_personal_mozo ON consumos_personal(mozo_id);
@@ -0,0 +1,43 @@
+-- ============================================================
+-- V46__inventario_fisico_insumos.sql
+-- Migración Flyway: HU-133 Inventario físico con reporte de diferencias
+-- Los insumos llevan un stock teórico que descuentan las ventas
+-- según la receta. La toma de inventario guarda el conteo físico
+-- y ajusta el stock; cada cambio queda en movimientos_insumo.
+-- ============================================================
+
+ALTER TABLE insumos ADD COLUMN IF NOT EXISTS controla_stock BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE insumos ADD COLUMN IF NOT EXISTS stock_actual DECIMAL(12,3) NOT NULL DEFAULT 0;
+
+CREATE TABLE IF NOT EXISTS movimientos_insumo (
+    id        UUID PRIMARY KEY,
+    insumo_id UUID NOT NULL,
+    local_id  UUID NOT NULL,
+    cantidad  DECIMAL(12,3) NOT NULL,
+    tipo      VARCHAR(30) NOT NULL,
+    fecha     TIMESTAMP NOT NULL,
+    motivo    VARCHAR(120) NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_movimientos_insumo_insumo_fecha ON movimientos_insumo(insumo_id, fecha);
+
+CREATE TABLE IF NOT EXISTS tomas_inventario (
+    id          UUID PRIMARY KEY,
+    local_id    UUID NOT NULL,
+    fecha       TIMESTAMP NOT NULL,
+    responsable VARCHAR(60) NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_tomas_inventario_local_fecha ON tomas_inventario(local_id, fecha);
+
+CREATE TABLE IF NOT EXISTS tomas_inventario_conteos (
+    toma_id        UUID NOT NULL REFERENCES tomas_inventario(id) ON DELETE CASCADE,
+    insumo_id      UUID NOT NULL,
+    nombre_insumo  VARCHAR(60) NOT NULL,
+    unidad         VARCHAR(20) NOT NULL,
+    stock_teorico  DECIMAL(12,3) NOT NULL,
+    stock_contado  DECIMAL(12,3) NOT NULL,
+    costo_unitario DECIMAL(12,4) NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_tomas_inventario_conteos_toma ON tomas_inventario_conteos(toma_id);
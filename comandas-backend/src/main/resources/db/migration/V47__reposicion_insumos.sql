@@ -0,0 +1,12 @@
+-- ============================================================
+-- V47__reposicion_insumos.sql
+-- Migración Flyway: HU-134 Órdenes de compra automáticas por stock mínimo
+-- Cada insumo puede tener un stock mínimo y un proveedor habitual;
+-- los que llegan al mínimo se sugieren en la orden de su proveedor,
+-- que ahora puede tener email para enviársela.
+-- ============================================================
+
+ALTER TABLE insumos ADD COLUMN IF NOT EXISTS stock_minimo DECIMAL(12,3);
+ALTER TABLE insumos ADD COLUMN IF NOT EXISTS proveedor_habitual_id UUID REFERENCES proveedores(id);
+
+ALTER TABLE proveedores ADD COLUMN IF NOT EXISTS email VARCHAR(100);
@@ -0,0 +1,32 @@
+-- ============================================================
+-- V48__mermas.sql
+-- Migración Flyway: HU-135 Registro de mermas y desperdicio
+-- Cada merma guarda su categoría, motivo y responsable, y los
+-- insumos perdidos con el costo del momento. El descuento de
+-- stock queda en movimientos_insumo con tipo MERMA.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS mermas (
+    id                UUID PRIMARY KEY,
+    local_id          UUID NOT NULL,
+    fecha             TIMESTAMP NOT NULL,
+    categoria         VARCHAR(20) NOT NULL,
+    motivo            VARCHAR(100) NOT NULL,
+    responsable       VARCHAR(60) NOT NULL,
+    producto_id       UUID,
+    nombre_producto   VARCHAR(100),
+    unidades_producto INTEGER
+);
+
+CREATE INDEX IF NOT EXISTS idx_mermas_local_fecha ON mermas(local_id, fecha);
+
+CREATE TABLE IF NOT EXISTS mermas_insumos (
+    merma_id       UUID NOT NULL REFERENCES mermas(id) ON DELETE CASCADE,
+    insumo_id      UUID NOT NULL,
+    nombre_insumo  VARCHAR(60) NOT NULL,
+    unidad         VARCHAR(20) NOT NULL,
+    cantidad       DECIMAL(12,3) NOT NULL,
+    costo_unitario DECIMAL(12,4) NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_mermas_insumos_merma ON mermas_insumos(merma_id);
@@ -0,0 +1,19 @@
+-- ============================================================
+-- V49__urgencia_pedidos.sql
+-- Migración Flyway: HU-137 Marca de urgencia en pedidos
+-- El pedido y cada ítem pueden marcarse urgentes. Las comandas
+-- de cocina guardan la marca del pedido (se actualiza mientras
+-- están en preparación) y la de cada ítem al momento del envío.
+-- ============================================================
+
+ALTER TABLE pedidos
+    ADD COLUMN IF NOT EXISTS urgente BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE items_pedido
+    ADD COLUMN IF NOT EXISTS urgente BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE comandas_cocina
+    ADD COLUMN IF NOT EXISTS urgente BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE comandas_cocina_items
+    ADD COLUMN IF NOT EXISTS urgente BOOLEAN NOT NULL DEFAULT FALSE;
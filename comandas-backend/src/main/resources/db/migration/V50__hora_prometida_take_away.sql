@@ -0,0 +1,13 @@
+-- ============================================================
+-- V50__hora_prometida_take_away.sql
+-- Migración Flyway: HU-138 Tiempo estimado de espera para take away
+-- El pedido para retirar guarda el horario prometido al cliente
+-- en su primer envío a cocina; cada comanda lo copia para que
+-- cocina vea si se atrasa.
+-- ============================================================
+
+ALTER TABLE pedidos
+    ADD COLUMN IF NOT EXISTS hora_prometida TIMESTAMP;
+
+ALTER TABLE comandas_cocina
+    ADD COLUMN IF NOT EXISTS prometida_para TIMESTAMP;
@@ -0,0 +1,35 @@
+-- ============================================================
+-- V51__zonas_delivery.sql
+-- Migración Flyway: HU-139 Zonas de delivery con costo de envío
+-- Zonas de reparto con costo de envío, mínimo de compra y los
+-- barrios que las identifican en la dirección. El pedido de
+-- delivery guarda la dirección y la zona; el envío se carga
+-- como un ítem marcado para reportarlo aparte de los productos.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS zonas_delivery (
+    id              UUID PRIMARY KEY,
+    local_id        UUID NOT NULL,
+    nombre          VARCHAR(60) NOT NULL,
+    costo_envio     NUMERIC(10, 2) NOT NULL DEFAULT 0,
+    monto_minimo    NUMERIC(10, 2) NOT NULL DEFAULT 0,
+    activa          BOOLEAN NOT NULL DEFAULT TRUE,
+    CONSTRAINT chk_zona_delivery_costo CHECK (costo_envio >= 0),
+    CONSTRAINT chk_zona_delivery_minimo CHECK (monto_minimo >= 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_zonas_delivery_local ON zonas_delivery(local_id);
+
+CREATE TABLE IF NOT EXISTS zonas_delivery_barrios (
+    zona_id         UUID NOT NULL REFERENCES zonas_delivery(id) ON DELETE CASCADE,
+    barrio          VARCHAR(60) NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_zonas_delivery_barrios_zona ON zonas_delivery_barrios(zona_id);
+
+ALTER TABLE pedidos
+    ADD COLUMN IF NOT EXISTS direccion_entrega VARCHAR(200),
+    ADD COLUMN IF NOT EXISTS zona_delivery_id UUID;
+
+ALTER TABLE items_pedido
+    ADD COLUMN IF NOT EXISTS es_envio BOOLEAN NOT NULL DEFAULT FALSE;
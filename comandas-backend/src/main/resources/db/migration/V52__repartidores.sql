@@ -0,0 +1,30 @@
+-- ============================================================
+-- V52__repartidores.sql
+-- Migración Flyway: HU-140 Asignación y seguimiento de repartidores
+-- Los repartidores son personal con rol REPARTIDOR. El pedido de
+-- delivery guarda quién lo lleva y a qué hora salió y volvió;
+-- al final del turno cada repartidor rinde el efectivo cobrado.
+-- ============================================================
+
+ALTER TABLE pedidos
+    ADD COLUMN IF NOT EXISTS repartidor_id UUID,
+    ADD COLUMN IF NOT EXISTS salida_reparto TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS regreso_reparto TIMESTAMP;
+
+CREATE INDEX IF NOT EXISTS idx_pedidos_local_repartidor ON pedidos(local_id, repartidor_id);
+
+CREATE TABLE IF NOT EXISTS rendiciones_repartidor (
+    id              UUID PRIMARY KEY,
+    local_id        UUID NOT NULL,
+    turno_id        UUID NOT NULL,
+    repartidor_id   UUID NOT NULL,
+    fecha           TIMESTAMP NOT NULL,
+    pedidos         INTEGER NOT NULL DEFAULT 0,
+    esperado        NUMERIC(10, 2) NOT NULL,
+    rendido         NUMERIC(10, 2) NOT NULL,
+    observaciones   VARCHAR(200),
+    CONSTRAINT uk_rendicion_repartidor_turno UNIQUE (turno_id, repartidor_id),
+    CONSTRAINT chk_rendicion_repartidor_rendido CHECK (rendido >= 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_rendiciones_repartidor_local_turno ON rendiciones_repartidor(local_id, turno_id);
@@ -0,0 +1,43 @@
+-- ============================================================
+-- V53__pozo_propinas.sql
+-- Migración Flyway: HU-141 Pozo de propinas y distribución
+-- Cada local configura cómo se reparte el pozo (partes iguales,
+-- por horas fichadas o por puesto) y con qué puntos participa
+-- cada rol. Al cerrar el turno se guarda el reparto con la
+-- parte de cada empleado, para imprimir el comprobante.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS reglas_propinas (
+    local_id    UUID PRIMARY KEY,
+    modo        VARCHAR(20) NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS reglas_propinas_puntos (
+    local_id    UUID NOT NULL REFERENCES reglas_propinas(local_id) ON DELETE CASCADE,
+    rol         VARCHAR(20) NOT NULL,
+    puntos      INTEGER NOT NULL,
+    PRIMARY KEY (local_id, rol),
+    CONSTRAINT chk_reglas_propinas_puntos CHECK (puntos BETWEEN 0 AND 100)
+);
+
+CREATE TABLE IF NOT EXISTS distribuciones_propinas (
+    id          UUID PRIMARY KEY,
+    local_id    UUID NOT NULL,
+    turno_id    UUID NOT NULL,
+    fecha       TIMESTAMP NOT NULL,
+    modo        VARCHAR(20) NOT NULL,
+    pozo        NUMERIC(10, 2) NOT NULL,
+    CONSTRAINT uk_distribucion_propinas_turno UNIQUE (turno_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_distribuciones_propinas_local_fecha ON distribuciones_propinas(local_id, fecha);
+
+CREATE TABLE IF NOT EXISTS partes_propina (
+    distribucion_id UUID NOT NULL REFERENCES distribuciones_propinas(id) ON DELETE CASCADE,
+    orden           INTEGER NOT NULL,
+    mozo_id         UUID NOT NULL,
+    minutos         BIGINT NOT NULL,
+    peso            BIGINT NOT NULL,
+    monto           NUMERIC(10, 2) NOT NULL,
+    PRIMARY KEY (distribucion_id, orden)
+);
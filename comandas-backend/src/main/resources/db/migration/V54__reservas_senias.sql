@@ -0,0 +1,32 @@
+-- ============================================================
+-- V54__reservas_senias.sql
+-- Migración Flyway: HU-142 Señas de reservas aplicadas a la cuenta
+-- La reserva guarda la seña cobrada (que entró a la caja como
+-- ingreso del día) y el pedido en el que se sentó el cliente.
+-- El pedido guarda la reserva y la seña que se le descontó.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS reservas (
+    id                   UUID PRIMARY KEY,
+    local_id             UUID NOT NULL,
+    nombre_cliente       VARCHAR(80) NOT NULL,
+    telefono             VARCHAR(30),
+    fecha_hora           TIMESTAMP NOT NULL,
+    personas             INTEGER NOT NULL,
+    observaciones        VARCHAR(200),
+    mesa_id              UUID,
+    estado               VARCHAR(20) NOT NULL DEFAULT 'PENDIENTE',
+    monto_senia          NUMERIC(10, 2) NOT NULL DEFAULT 0,
+    fecha_senia          TIMESTAMP,
+    movimiento_senia_id  UUID,
+    pedido_id            UUID,
+    CONSTRAINT chk_reservas_personas CHECK (personas > 0),
+    CONSTRAINT chk_reservas_senia CHECK (monto_senia >= 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_reservas_local_fecha ON reservas(local_id, fecha_hora);
+CREATE INDEX IF NOT EXISTS idx_reservas_local_mesa_estado ON reservas(local_id, mesa_id, estado);
+
+ALTER TABLE pedidos
+    ADD COLUMN IF NOT EXISTS reserva_id UUID,
+    ADD COLUMN IF NOT EXISTS monto_senia NUMERIC(10, 2) DEFAULT 0;
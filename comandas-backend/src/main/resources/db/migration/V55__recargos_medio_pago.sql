@@ -0,0 +1,22 @@
+-- ============================================================
+-- V55__recargos_medio_pago.sql
+-- Migración Flyway: HU-143 Recargo configurable por medio de pago
+-- Cada local configura un recargo porcentual por medio de pago.
+-- Al cobrar, cada pago guarda el recargo calculado aparte de su
+-- monto: no cubre el total del pedido y queda congelado aunque
+-- después cambie la configuración.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS recargos_medio_pago (
+    local_id    UUID PRIMARY KEY
+);
+
+CREATE TABLE IF NOT EXISTS recargos_medio_pago_porcentajes (
+    local_id    UUID NOT NULL REFERENCES recargos_medio_pago(local_id) ON DELETE CASCADE,
+    medio_pago  VARCHAR(20) NOT NULL,
+    porcentaje  NUMERIC(5, 2) NOT NULL,
+    PRIMARY KEY (local_id, medio_pago),
+    CONSTRAINT chk_recargos_medio_pago_porcentaje CHECK (porcentaje > 0 AND porcentaje <= 100)
+);
+
+ALTER TABLE pedidos_pagos ADD COLUMN IF NOT EXISTS recargo NUMERIC(10, 2) NOT NULL DEFAULT 0;
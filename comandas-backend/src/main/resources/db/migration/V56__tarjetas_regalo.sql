@@ -0,0 +1,32 @@
+-- ============================================================
+-- V56__tarjetas_regalo.sql
+-- Migración Flyway: HU-144 Gift cards y vouchers prepagos
+-- Cada tarjeta tiene un código único por local y un monto que
+-- entra a la caja al venderla. Los usos quedan por pedido para
+-- descontar el saldo y reintegrarlo si el pedido se reabre.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS tarjetas_regalo (
+    id                  UUID PRIMARY KEY,
+    local_id            UUID NOT NULL,
+    codigo              VARCHAR(10) NOT NULL,
+    monto_inicial       NUMERIC(10, 2) NOT NULL,
+    fecha_emision       TIMESTAMP NOT NULL,
+    vencimiento         DATE NOT NULL,
+    comprador           VARCHAR(80),
+    destinatario        VARCHAR(80),
+    movimiento_caja_id  UUID,
+    CONSTRAINT uk_tarjetas_regalo_local_codigo UNIQUE (local_id, codigo),
+    CONSTRAINT chk_tarjetas_regalo_monto CHECK (monto_inicial > 0)
+);
+
+CREATE TABLE IF NOT EXISTS tarjetas_regalo_consumos (
+    tarjeta_id  UUID NOT NULL REFERENCES tarjetas_regalo(id) ON DELETE CASCADE,
+    orden       INTEGER NOT NULL,
+    pedido_id   UUID NOT NULL,
+    monto       NUMERIC(10, 2) NOT NULL,
+    fecha       TIMESTAMP NOT NULL,
+    PRIMARY KEY (tarjeta_id, orden)
+);
+
+CREATE INDEX IF NOT EXISTS idx_tarjetas_regalo_consumos_pedido ON tarjetas_regalo_consumos(pedido_id);
@@ -0,0 +1,32 @@
+-- ============================================================
+-- V57__aumentos_precios.sql
+-- Migración Flyway: HU-145 Actualización masiva de precios
+-- Aumentos porcentuales sobre una categoría o todo el catálogo,
+-- con redondeo a múltiplos y vigencia programada. Al aplicarse
+-- guardan el precio anterior y el nuevo de cada producto.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS aumentos_precios (
+    id                  UUID PRIMARY KEY,
+    local_id            UUID NOT NULL,
+    categoria_id        UUID,
+    porcentaje          NUMERIC(5, 2) NOT NULL,
+    multiplo_redondeo   NUMERIC(10, 2),
+    fecha_vigencia      DATE NOT NULL,
+    fecha_creacion      TIMESTAMP NOT NULL,
+    estado              VARCHAR(20) NOT NULL,
+    fecha_aplicacion    TIMESTAMP,
+    CONSTRAINT chk_aumentos_precios_porcentaje CHECK (porcentaje > 0 AND porcentaje <= 100)
+);
+
+CREATE INDEX IF NOT EXISTS idx_aumentos_precios_estado_vigencia ON aumentos_precios(estado, fecha_vigencia);
+
+CREATE TABLE IF NOT EXISTS aumentos_precios_cambios (
+    aumento_id       UUID NOT NULL REFERENCES aumentos_precios(id) ON DELETE CASCADE,
+    orden            INTEGER NOT NULL,
+    producto_id      UUID NOT NULL,
+    nombre_producto  VARCHAR(100) NOT NULL,
+    precio_anterior  NUMERIC(10, 2) NOT NULL,
+    precio_nuevo     NUMERIC(10, 2) NOT NULL,
+    PRIMARY KEY (aumento_id, orden)
+);
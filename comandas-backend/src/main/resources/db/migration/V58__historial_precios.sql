@@ -0,0 +1,35 @@
+-- ============================================================
+-- V58__historial_precios.sql
+-- Migración Flyway: HU-146 Historial de cambios de precios
+-- Registro de auditoría de precios del catálogo: cada edición
+-- de precio y cada producto tocado por un aumento masivo deja
+-- el valor anterior, el nuevo, la fecha y quién lo cambió.
+-- ============================================================
+
+ALTER TABLE aumentos_precios ADD COLUMN IF NOT EXISTS responsable VARCHAR(60);
+
+CREATE TABLE IF NOT EXISTS historial_precios (
+    id               UUID PRIMARY KEY,
+    local_id         UUID NOT NULL,
+    producto_id      UUID NOT NULL,
+    nombre_producto  VARCHAR(100) NOT NULL,
+    precio_anterior  NUMERIC(10, 2) NOT NULL,
+    precio_nuevo     NUMERIC(10, 2) NOT NULL,
+    fecha            TIMESTAMP NOT NULL,
+    origen           VARCHAR(20) NOT NULL,
+    aumento_id       UUID REFERENCES aumentos_precios(id),
+    responsable      VARCHAR(60)
+);
+
+CREATE INDEX IF NOT EXISTS idx_historial_precios_producto ON historial_precios(producto_id, fecha);
+CREATE INDEX IF NOT EXISTS idx_historial_precios_local_fecha ON historial_precios(local_id, fecha);
+
+-- Los aumentos masivos ya aplicados guardaron su detalle: pasan al historial
+INSERT INTO historial_precios (id, local_id, producto_id, nombre_producto, precio_anterior, precio_nuevo,
+                               fecha, origen, aumento_id)
+SELECT gen_random_uuid(), a.local_id, c.producto_id, c.nombre_producto, c.precio_anterior, c.precio_nuevo,
+       a.fecha_aplicacion, 'AUMENTO_MASIVO', a.id
+FROM aumentos_precios a
+JOIN aumentos_precios_cambios c ON c.aumento_id = a.id
+WHERE a.estado = 'APLICADO'
+  AND c.precio_anterior <> c.precio_nuevo;
@@ -0,0 +1,23 @@
+-- ============================================================
+-- V59__alergenos.sql
+-- Migración Flyway: HU-147 Alérgenos y advertencias en la comanda
+-- Los productos declaran sus alérgenos y cada ítem vendido guarda
+-- las alergias del comensal para el que se cargó. Las comandas
+-- de cocina copian esas alergias al momento del envío.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS productos_alergenos (
+    producto_id  UUID NOT NULL REFERENCES productos(id) ON DELETE CASCADE,
+    alergeno     VARCHAR(20) NOT NULL,
+    PRIMARY KEY (producto_id, alergeno)
+);
+
+CREATE TABLE IF NOT EXISTS items_pedido_advertencias (
+    item_pedido_id  UUID NOT NULL REFERENCES items_pedido(id) ON DELETE CASCADE,
+    alergeno        VARCHAR(20) NOT NULL,
+    PRIMARY KEY (item_pedido_id, alergeno)
+);
+
+-- Lista separada por comas (ej: "TACC,MANI"); null si el ítem no tenía advertencias
+ALTER TABLE comandas_cocina_items
+    ADD COLUMN IF NOT EXISTS advertencias VARCHAR(120);
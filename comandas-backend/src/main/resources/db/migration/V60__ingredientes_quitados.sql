@@ -0,0 +1,15 @@
+-- ============================================================
+-- V60__ingredientes_quitados.sql
+-- Migración Flyway: HU-148 Modificadores de ingredientes con impacto en stock
+-- Cada ítem vendido guarda los insumos de la receta que el cliente
+-- pidió sacar ("sin cebolla"), con el nombre al momento de la carga.
+-- Al cerrar la mesa esos insumos no se descuentan del stock teórico.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS items_pedido_ingredientes_quitados (
+    item_pedido_id  UUID NOT NULL REFERENCES items_pedido(id) ON DELETE CASCADE,
+    orden           INTEGER NOT NULL,
+    insumo_id       UUID NOT NULL,
+    nombre_insumo   VARCHAR(60) NOT NULL,
+    PRIMARY KEY (item_pedido_id, orden)
+);
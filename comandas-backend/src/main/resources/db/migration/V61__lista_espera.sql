@@ -0,0 +1,27 @@
+-- ============================================================
+-- V61__lista_espera.sql
+-- Migración Flyway: HU-149 Lista de espera de mesas
+-- Clientes anotados cuando el salón está lleno, con la mesa que se
+-- les avisó. Las mesas guardan cuántas personas entran para poder
+-- sugerir una mesa adecuada cuando se libera.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS lista_espera (
+    id              UUID PRIMARY KEY,
+    local_id        UUID NOT NULL,
+    nombre_cliente  VARCHAR(80) NOT NULL,
+    personas        INTEGER NOT NULL,
+    telefono        VARCHAR(30),
+    observaciones   VARCHAR(200),
+    fecha_alta      TIMESTAMP NOT NULL,
+    estado          VARCHAR(20) NOT NULL DEFAULT 'ESPERANDO',
+    mesa_id         UUID,
+    fecha_aviso     TIMESTAMP,
+    fecha_salida    TIMESTAMP,
+    CONSTRAINT chk_lista_espera_personas CHECK (personas > 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_lista_espera_local_estado ON lista_espera(local_id, estado, fecha_alta);
+
+ALTER TABLE mesas
+    ADD COLUMN IF NOT EXISTS capacidad INTEGER;
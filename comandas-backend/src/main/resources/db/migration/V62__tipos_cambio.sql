@@ -0,0 +1,28 @@
+-- ============================================================
+-- V62__tipos_cambio.sql
+-- Migración Flyway: HU-151 Soporte multi-moneda en cobros
+-- Cada local carga por día operativo la cotización en pesos de
+-- las monedas extranjeras que acepta. Cada pago guarda su moneda,
+-- el monto en esa moneda y el tipo de cambio aplicado; el monto
+-- en pesos sigue siendo el que cubre el total del pedido.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS tipos_cambio (
+    id          UUID PRIMARY KEY,
+    local_id    UUID NOT NULL,
+    fecha       DATE NOT NULL,
+    CONSTRAINT uk_tipos_cambio_local_fecha UNIQUE (local_id, fecha)
+);
+
+CREATE TABLE IF NOT EXISTS tipos_cambio_cotizaciones (
+    tipos_cambio_id UUID NOT NULL REFERENCES tipos_cambio(id) ON DELETE CASCADE,
+    moneda          VARCHAR(3) NOT NULL,
+    cotizacion      NUMERIC(12, 4) NOT NULL,
+    PRIMARY KEY (tipos_cambio_id, moneda),
+    CONSTRAINT chk_tipos_cambio_moneda CHECK (moneda IN ('USD', 'EUR')),
+    CONSTRAINT chk_tipos_cambio_cotizacion CHECK (cotizacion > 0)
+);
+
+ALTER TABLE pedidos_pagos ADD COLUMN IF NOT EXISTS moneda VARCHAR(3) NOT NULL DEFAULT 'ARS';
+ALTER TABLE pedidos_pagos ADD COLUMN IF NOT EXISTS monto_original NUMERIC(12, 2);
+ALTER TABLE pedidos_pagos ADD COLUMN IF NOT EXISTS tipo_cambio NUMERIC(12, 4) NOT NULL DEFAULT 1;
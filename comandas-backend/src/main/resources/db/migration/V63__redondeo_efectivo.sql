@@ -0,0 +1,17 @@
+-- ============================================================
+-- V63__redondeo_efectivo.sql
+-- Migración Flyway: HU-152 Reglas de redondeo configurables en el total
+-- Cada local puede configurar a qué múltiplo se redondea el
+-- efectivo en pesos y en qué sentido. El total del pedido no
+-- cambia: la diferencia queda en el pago como ajuste por redondeo.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS redondeo_efectivo (
+    local_id    UUID PRIMARY KEY,
+    multiplo    NUMERIC(10, 2),
+    modo        VARCHAR(10) NOT NULL DEFAULT 'CERCANO',
+    CONSTRAINT chk_redondeo_efectivo_multiplo CHECK (multiplo IS NULL OR (multiplo > 0 AND multiplo <= 1000)),
+    CONSTRAINT chk_redondeo_efectivo_modo CHECK (modo IN ('CERCANO', 'ABAJO', 'ARRIBA'))
+);
+
+ALTER TABLE pedidos_pagos ADD COLUMN IF NOT EXISTS ajuste_redondeo NUMERIC(10, 2) NOT NULL DEFAULT 0;
@@ -0,0 +1,12 @@
+-- ============================================================
+-- V64__jornada_comercial.sql
+-- Migración Flyway: HU-153 Límite de jornada comercial configurable
+-- Cada local puede configurar la hora en la que termina su día
+-- comercial. Sin fila, el corte sigue siendo a las 06:00.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS jornada_comercial (
+    local_id    UUID PRIMARY KEY,
+    hora_corte  TIME NOT NULL DEFAULT '06:00',
+    CONSTRAINT chk_jornada_comercial_hora_corte CHECK (hora_corte < '12:00')
+);
@@ -0,0 +1,9 @@
+-- ============================================================
+-- V65__referencia_pagos.sql
+-- Migración Flyway: HU-156 Comprobante de los pagos
+-- Los pagos con tarjeta, transferencia o QR guardan el número de
+-- cupón u operación que pega el cajero, para conciliar después con
+-- el banco o la pasarela. El efectivo no lleva referencia.
+-- ============================================================
+
+ALTER TABLE pedidos_pagos ADD COLUMN IF NOT EXISTS referencia VARCHAR(64);
@@ -0,0 +1,31 @@
+-- ============================================================
+-- V66__cajas.sql
+-- Migración Flyway: HU-159 Varias cajas en el mismo local
+-- Cada terminal que cobra se registra como caja y declara su
+-- propio fondo inicial en cada jornada. Los pedidos y movimientos
+-- existentes quedan sin caja: pertenecen a la caja principal.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS cajas (
+    id         UUID PRIMARY KEY,
+    local_id   UUID NOT NULL,
+    nombre     VARCHAR(40) NOT NULL,
+    fecha_alta TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_cajas_local ON cajas(local_id);
+
+CREATE TABLE IF NOT EXISTS aperturas_caja (
+    id             UUID PRIMARY KEY,
+    local_id       UUID NOT NULL,
+    jornada_id     UUID NOT NULL REFERENCES jornadas_caja(id),
+    caja_id        UUID NOT NULL REFERENCES cajas(id),
+    fondo_inicial  DECIMAL(12,2) NOT NULL,
+    fecha_apertura TIMESTAMP NOT NULL,
+    CONSTRAINT uk_aperturas_caja_jornada_caja UNIQUE (jornada_id, caja_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_aperturas_caja_jornada ON aperturas_caja(jornada_id);
+
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS caja_id UUID;
+ALTER TABLE movimientos_caja ADD COLUMN IF NOT EXISTS caja_id UUID;
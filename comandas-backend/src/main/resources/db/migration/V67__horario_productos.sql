@@ -0,0 +1,14 @@
+-- ============================================================
+-- V67__horario_productos.sql
+-- Migración Flyway: HU-161 Disponibilidad de productos por horario
+-- Productos y categorías pueden ofrecerse solo en una franja
+-- (desayunos hasta las 11, menú ejecutivo de 12 a 15). Sin
+-- horario se ofrecen todo el día; el del producto manda sobre
+-- el de su categoría.
+-- ============================================================
+
+ALTER TABLE productos ADD COLUMN IF NOT EXISTS horario_desde TIME;
+ALTER TABLE productos ADD COLUMN IF NOT EXISTS horario_hasta TIME;
+
+ALTER TABLE categorias ADD COLUMN IF NOT EXISTS horario_desde TIME;
+ALTER TABLE categorias ADD COLUMN IF NOT EXISTS horario_hasta TIME;
@@ -0,0 +1,25 @@
+-- ============================================================
+-- V68__archivo_pedidos.sql
+-- Migración Flyway: HU-162 Archivo de pedidos
+-- Los pedidos cerrados hace más que el horizonte configurado
+-- (app.archivo.meses-retencion) se mueven acá comprimidos y se
+-- borran de pedidos/items_pedido, para que las búsquedas y el
+-- mapa de mesas sigan rápidos. Los reportes por fecha los leen
+-- de las dos tablas.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS pedidos_archivados (
+    id              UUID PRIMARY KEY,
+    local_id        UUID NOT NULL,
+    numero          INTEGER NOT NULL,
+    fecha_apertura  TIMESTAMP NOT NULL,
+    fecha_cierre    TIMESTAMP NOT NULL,
+    monto_total     NUMERIC(12, 2),
+    contenido       BYTEA NOT NULL,
+    archivado_en    TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_pedidos_archivados_local_cierre ON pedidos_archivados(local_id, fecha_cierre);
+
+-- El archivado busca los cerrados más viejos de todos los locales
+CREATE INDEX IF NOT EXISTS idx_pedido_estado_cierre ON pedidos(estado, fecha_cierre);
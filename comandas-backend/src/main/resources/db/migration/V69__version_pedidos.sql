@@ -0,0 +1,9 @@
+-- ============================================================
+-- V69__version_pedidos.sql
+-- Migración Flyway: HU-165 Ediciones simultáneas de una mesa
+-- Cada guardado del pedido sube la versión. La terminal manda
+-- la versión que está mostrando y, si otra terminal lo cambió
+-- en el medio, el cambio se rechaza en lugar de pisarlo.
+-- ============================================================
+
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS version BIGINT NOT NULL DEFAULT 0;
@@ -0,0 +1,27 @@
+-- ============================================================
+-- V70__series_comprobantes.sql
+-- Migración Flyway: HU-166 Numeración de comprobantes por caja
+-- Cada caja (o la principal, con caja_id NULL) tiene su serie:
+-- prefijo, próximo número y si reinicia con el año. El número
+-- se toma al cerrar el pedido, con la fila de la serie bloqueada,
+-- en la misma transacción del cierre: si el cierre falla el
+-- número no se consume. El índice único sobre los pedidos frena
+-- cualquier duplicado.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS series_comprobantes (
+    id             UUID PRIMARY KEY,
+    local_id       UUID NOT NULL,
+    caja_id        UUID REFERENCES cajas(id),
+    prefijo        VARCHAR(8) NOT NULL,
+    proximo_numero BIGINT NOT NULL,
+    reinicio_anual BOOLEAN NOT NULL DEFAULT FALSE,
+    anio_en_curso  INTEGER
+);
+
+CREATE INDEX IF NOT EXISTS idx_series_comprobantes_local ON series_comprobantes(local_id);
+
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS numero_comprobante VARCHAR(30);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uk_pedidos_local_numero_comprobante
+    ON pedidos(local_id, numero_comprobante);
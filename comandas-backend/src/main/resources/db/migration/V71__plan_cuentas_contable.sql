@@ -0,0 +1,19 @@
+-- ============================================================
+-- V71__plan_cuentas_contable.sql
+-- Migración Flyway: HU-167 Exportación al sistema contable
+-- Cada local asigna a los conceptos que exporta (ventas, IVA,
+-- medios de cobro, categorías de gasto) la cuenta del plan de
+-- cuentas de su estudio contable. El sistema (Tango, Xubio o
+-- Contabilium) se elige en cada exportación.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS planes_cuentas_contables (
+    local_id    UUID PRIMARY KEY
+);
+
+CREATE TABLE IF NOT EXISTS planes_cuentas_contables_cuentas (
+    local_id    UUID NOT NULL REFERENCES planes_cuentas_contables(local_id) ON DELETE CASCADE,
+    concepto    VARCHAR(30) NOT NULL,
+    cuenta      VARCHAR(30) NOT NULL,
+    PRIMARY KEY (local_id, concepto)
+);
@@ -0,0 +1,25 @@
+-- ============================================================
+-- V72__ficha_clientes_cumpleanios.sql
+-- Migración Flyway: HU-168 Ficha de clientes y promo de cumpleaños
+-- El cliente suma fecha de nacimiento, preferencias y alergias.
+-- El pedido guarda el cliente vinculado (salón o delivery), que
+-- arma su historial de consumo, y el descuento de la promo de
+-- cumpleaños que se le aplicó al cobrar. La promo se configura
+-- una vez por local.
+-- ============================================================
+
+ALTER TABLE clientes ADD COLUMN IF NOT EXISTS fecha_nacimiento DATE;
+ALTER TABLE clientes ADD COLUMN IF NOT EXISTS preferencias VARCHAR(300);
+ALTER TABLE clientes ADD COLUMN IF NOT EXISTS alergias VARCHAR(300);
+
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS cliente_id UUID REFERENCES clientes(id);
+ALTER TABLE pedidos ADD COLUMN IF NOT EXISTS monto_promo_cumpleanios DECIMAL(10,2);
+
+CREATE INDEX IF NOT EXISTS idx_pedido_local_cliente ON pedidos(local_id, cliente_id);
+
+CREATE TABLE IF NOT EXISTS promos_cumpleanios (
+    local_id       UUID PRIMARY KEY,
+    activa         BOOLEAN NOT NULL DEFAULT FALSE,
+    porcentaje     DECIMAL(5,2) NOT NULL,
+    tope_descuento DECIMAL(10,2)
+);
@@ -0,0 +1,28 @@
+-- ============================================================
+-- V73__matriz_permisos.sql
+-- Migración Flyway: HU-169 Matriz de permisos por rol y por empleado
+-- El dueño habilita cada acción sensible (anular ítems, aplicar
+-- descuentos, abrir el cajón, ver reportes, modificar precios) por
+-- rol y, si hace falta, la concede o la quita a un empleado puntual.
+-- La matriz se configura una vez por local; inactiva no restringe.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS matrices_permisos (
+    local_id UUID PRIMARY KEY,
+    activa   BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE TABLE IF NOT EXISTS matrices_permisos_roles (
+    local_id UUID NOT NULL REFERENCES matrices_permisos(local_id) ON DELETE CASCADE,
+    rol      VARCHAR(20) NOT NULL,
+    permiso  VARCHAR(30) NOT NULL,
+    PRIMARY KEY (local_id, rol, permiso)
+);
+
+CREATE TABLE IF NOT EXISTS matrices_permisos_empleados (
+    local_id    UUID NOT NULL REFERENCES matrices_permisos(local_id) ON DELETE CASCADE,
+    empleado_id UUID NOT NULL REFERENCES mozos(id),
+    permiso     VARCHAR(30) NOT NULL,
+    concedido   BOOLEAN NOT NULL,
+    PRIMARY KEY (local_id, empleado_id, permiso)
+);
@@ -0,0 +1,12 @@
+-- ============================================================
+-- V74__caja_de_cada_pago.sql
+-- Migración Flyway: HU-159 Cada pago en la caja que lo cobró
+-- Un pago a cuenta puede entrar por una caja y la mesa cerrarse
+-- en otra. Los pagos existentes toman la caja de su pedido.
+-- ============================================================
+
+ALTER TABLE pedidos_pagos ADD COLUMN IF NOT EXISTS caja_id UUID;
+
+UPDATE pedidos_pagos
+SET caja_id = (SELECT p.caja_id FROM pedidos p WHERE p.id = pedidos_pagos.pedido_id)
+WHERE caja_id IS NULL;
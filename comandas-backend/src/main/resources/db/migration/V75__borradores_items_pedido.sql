@@ -0,0 +1,13 @@
+-- ============================================================
+-- V75__borradores_items_pedido.sql
+-- Migración Flyway: HU-164 Ítems del borrador del POS
+-- Cada ítem guarda los ids del borrador de la terminal que lo
+-- cargaron. Si se pierde la respuesta y el POS reintenta con el
+-- mismo id, el backend no duplica el ítem.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS items_pedido_borradores (
+    item_pedido_id  UUID NOT NULL REFERENCES items_pedido(id) ON DELETE CASCADE,
+    borrador_id     UUID NOT NULL,
+    PRIMARY KEY (item_pedido_id, borrador_id)
+);
@@ -0,0 +1,14 @@
+-- ============================================================
+-- V76__comprobantes_archivados.sql
+-- Migración Flyway: HU-166 Comprobantes de los pedidos archivados
+-- Al configurar una serie se busca el último comprobante emitido
+-- con su prefijo, también entre los archivados, para no volver a
+-- emitir un número que ya salió. Los pedidos archivados antes de
+-- esta migración quedan sin el dato: su comprobante está dentro
+-- del contenido comprimido.
+-- ============================================================
+
+ALTER TABLE pedidos_archivados ADD COLUMN IF NOT EXISTS numero_comprobante VARCHAR(30);
+
+CREATE INDEX IF NOT EXISTS idx_pedidos_archivados_local_comprobante
+    ON pedidos_archivados(local_id, numero_comprobante);
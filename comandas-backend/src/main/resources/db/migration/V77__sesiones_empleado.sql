@@ -0,0 +1,17 @@
+-- ============================================================
+-- V77__sesiones_empleado.sql
+-- Migración Flyway: HU-169 Sesiones de los empleados
+-- Al identificarse con el PIN, la terminal recibe un token y lo
+-- manda en cada request; el backend resuelve con él quién opera.
+-- Se guarda solo el hash del token. La sesión vence sola y las
+-- vencidas se borran con cada identificación nueva.
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS sesiones_empleado (
+    hash_token   VARCHAR(64) PRIMARY KEY,
+    local_id     UUID NOT NULL,
+    empleado_id  UUID NOT NULL REFERENCES mozos(id) ON DELETE CASCADE,
+    vence_en     TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_sesiones_empleado_vence ON sesiones_empleado(vence_en);
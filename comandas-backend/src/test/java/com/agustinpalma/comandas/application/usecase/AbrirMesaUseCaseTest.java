@@ -2,14 +2,25 @@ package com.agustinpalma.comandas.application.usecase;
 
 import com.agustinpalma.comandas.application.dto.AbrirMesaRequest;
 import com.agustinpalma.comandas.application.dto.AbrirMesaResponse;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
 import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoReserva;
+import com.agustinpalma.comandas.domain.model.DomainIds.ListaPreciosId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.ListaPrecios;
 import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.Mozo;
 import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Reserva;
+import com.agustinpalma.comandas.domain.repository.ListaPreciosRepository;
 import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.ReservaRepository;
 import org.junit.jupiter.api.BeforeEach;
 import org.junit.jupiter.api.DisplayName;
 import org.junit.jupiter.api.Test;
@@ -18,7 +29,11 @@ import org.mockito.ArgumentCaptor;
 import org.mockito.Mock;
 import org.mockito.junit.jupiter.MockitoExtension;
 
+import java.math.BigDecimal;
+import java.time.LocalDate;
 import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Map;
 import java.util.Optional;
 import java.util.UUID;
 
@@ -42,6 +57,15 @@ class AbrirMesaUseCaseTest {
     @Mock
     private PedidoRepository pedidoRepository;
 
+    @Mock
+    private ListaPreciosRepository listaPreciosRepository;
+
+    @Mock
+    private MozoRepository mozoRepository;
+
+    @Mock
+    private ReservaRepository reservaRepository;
+
     private AbrirMesaUseCase useCase;
 
     private LocalId localId;
@@ -50,7 +74,7 @@ class AbrirMesaUseCaseTest {
 
     @BeforeEach
     void setUp() {
-        useCase = new AbrirMesaUseCase(mesaRepository, pedidoRepository);
+        useCase = new AbrirMesaUseCase(mesaRepository, pedidoRepository, listaPreciosRepository, mozoRepository, reservaRepository);
         
         // Datos de prueba comunes
         localId = new LocalId(UUID.randomUUID());
@@ -235,13 +259,17 @@ class AbrirMesaUseCaseTest {
     @DisplayName("Debería fallar cuando se construye con repositorios null")
     void deberia_fallar_cuando_repositorios_son_null() {
         // Given/When/Then
-        assertThatThrownBy(() -> new AbrirMesaUseCase(null, pedidoRepository))
+        assertThatThrownBy(() -> new AbrirMesaUseCase(null, pedidoRepository, listaPreciosRepository, mozoRepository, reservaRepository))
             .isInstanceOf(NullPointerException.class)
             .hasMessageContaining("mesaRepository");
 
-        assertThatThrownBy(() -> new AbrirMesaUseCase(mesaRepository, null))
+        assertThatThrownBy(() -> new AbrirMesaUseCase(mesaRepository, null, listaPreciosRepository, mozoRepository, reservaRepository))
             .isInstanceOf(NullPointerException.class)
             .hasMessageContaining("pedidoRepository");
+
+        assertThatThrownBy(() -> new AbrirMesaUseCase(mesaRepository, pedidoRepository, null, mozoRepository, reservaRepository))
+            .isInstanceOf(NullPointerException.class)
+            .hasMessageContaining("listaPreciosRepository");
     }
 
     @Test
@@ -273,4 +301,149 @@ class AbrirMesaUseCaseTest {
             .isEqualTo(localId)
             .isEqualTo(mesaLibre.getLocalId());
     }
-}
\ No newline at end of file
+
+    @Test
+    @DisplayName("HU-108: Debería congelar en el pedido la lista de precios del canal elegido")
+    void deberia_congelar_lista_de_precios_del_canal() {
+        // Given: Una lista de delivery con 15% de recargo
+        ListaPrecios delivery = new ListaPrecios(
+            new ListaPreciosId(UUID.randomUUID()), localId, CanalVenta.DELIVERY,
+            "Delivery", new BigDecimal("15"), Map.of()
+        );
+        AbrirMesaRequest request = new AbrirMesaRequest(mesaId.getValue().toString(), CanalVenta.DELIVERY);
+
+        when(mesaRepository.buscarPorId(mesaId)).thenReturn(Optional.of(mesaLibre));
+        when(pedidoRepository.buscarPorMesaYEstado(mesaId, EstadoPedido.ABIERTO))
+            .thenReturn(Optional.empty());
+        when(pedidoRepository.obtenerSiguienteNumero(localId)).thenReturn(7);
+        when(listaPreciosRepository.buscarPorLocalYCanal(localId, CanalVenta.DELIVERY))
+            .thenReturn(Optional.of(delivery));
+        when(pedidoRepository.guardar(any(Pedido.class)))
+            .thenAnswer(invocation -> invocation.getArgument(0));
+        when(mesaRepository.guardar(any(Mesa.class)))
+            .thenAnswer(invocation -> invocation.getArgument(0));
+
+        // When
+        AbrirMesaResponse response = useCase.ejecutar(localId, request);
+
+        // Then
+        ArgumentCaptor<Pedido> pedidoCaptor = ArgumentCaptor.forClass(Pedido.class);
+        verify(pedidoRepository).guardar(pedidoCaptor.capture());
+
+        Pedido pedidoPersistido = pedidoCaptor.getValue();
+        assertThat(pedidoPersistido.getCanal()).isEqualTo(CanalVenta.DELIVERY);
+        assertThat(pedidoPersistido.getListaPreciosId()).isEqualTo(delivery.getId());
+        assertThat(pedidoPersistido.getNombreListaPrecios()).isEqualTo("Delivery");
+        assertThat(response.canal()).isEqualTo("DELIVERY");
+        assertThat(response.nombreListaPrecios()).isEqualTo("Delivery");
+    }
+
+    @Test
+    @DisplayName("HU-108: Sin canal informado el pedido es de salón y, sin lista, usa precio de catálogo")
+    void deberia_abrir_como_salon_sin_lista_por_defecto() {
+        // Given
+        AbrirMesaRequest request = new AbrirMesaRequest(mesaId.getValue().toString(), null);
+
+        when(mesaRepository.buscarPorId(mesaId)).thenReturn(Optional.of(mesaLibre));
+        when(pedidoRepository.buscarPorMesaYEstado(mesaId, EstadoPedido.ABIERTO))
+            .thenReturn(Optional.empty());
+        when(pedidoRepository.obtenerSiguienteNumero(localId)).thenReturn(8);
+        when(listaPreciosRepository.buscarPorLocalYCanal(localId, CanalVenta.SALON))
+            .thenReturn(Optional.empty());
+        when(pedidoRepository.guardar(any(Pedido.class)))
+            .thenAnswer(invocation -> invocation.getArgument(0));
+        when(mesaRepository.guardar(any(Mesa.class)))
+            .thenAnswer(invocation -> invocation.getArgument(0));
+
+        // When
+        AbrirMesaResponse response = useCase.ejecutar(localId, request);
+
+        // Then
+        assertThat(response.canal()).isEqualTo("SALON");
+        assertThat(response.nombreListaPrecios()).isNull();
+    }
+
+    @Test
+    @DisplayName("HU-111: Debería registrar en el pedido el mozo a cargo y los cubiertos")
+    void deberia_registrar_mozo_y_cubiertos() {
+        // Given
+        Mozo mozo = new Mozo(MozoId.generate(), localId, "Lucía", true);
+        AbrirMesaRequest request = new AbrirMesaRequest(
+            mesaId.getValue().toString(), null, mozo.getId().toString(), 4
+        );
+
+        when(mesaRepository.buscarPorId(mesaId)).thenReturn(Optional.of(mesaLibre));
+        when(pedidoRepository.buscarPorMesaYEstado(mesaId, EstadoPedido.ABIERTO))
+            .thenReturn(Optional.empty());
+        when(pedidoRepository.obtenerSiguienteNumero(localId)).thenReturn(9);
+        when(mozoRepository.buscarPorId(mozo.getId(), localId)).thenReturn(Optional.of(mozo));
+        when(pedidoRepository.guardar(any(Pedido.class)))
+            .thenAnswer(invocation -> invocation.getArgument(0));
+        when(mesaRepository.guardar(any(Mesa.class)))
+            .thenAnswer(invocation -> invocation.getArgument(0));
+
+        // When
+        useCase.ejecutar(localId, request);
+
+        // Then
+        ArgumentCaptor<Pedido> pedidoCaptor = ArgumentCaptor.forClass(Pedido.class);
+        verify(pedidoRepository).guardar(pedidoCaptor.capture());
+        assertThat(pedidoCaptor.getValue().getMozoId()).isEqualTo(mozo.getId());
+        assertThat(pedidoCaptor.getValue().getCubiertos()).isEqualTo(4);
+    }
+
+    @Test
+    @DisplayName("HU-111: Debería rechazar abrir la mesa con un mozo inactivo")
+    void deberia_rechazar_mozo_inactivo() {
+        // Given
+        Mozo mozo = new Mozo(MozoId.generate(), localId, "Lucía", false);
+        AbrirMesaRequest request = new AbrirMesaRequest(
+            mesaId.getValue().toString(), null, mozo.getId().toString(), 2
+        );
+
+        when(mesaRepository.buscarPorId(mesaId)).thenReturn(Optional.of(mesaLibre));
+        when(pedidoRepository.buscarPorMesaYEstado(mesaId, EstadoPedido.ABIERTO))
+            .thenReturn(Optional.empty());
+        when(pedidoRepository.obtenerSiguienteNumero(localId)).thenReturn(10);
+        when(mozoRepository.buscarPorId(mozo.getId(), localId)).thenReturn(Optional.of(mozo));
+
+        // When/Then
+        assertThatThrownBy(() -> useCase.ejecutar(localId, request))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("inactivo");
+
+        verify(pedidoRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("HU-142: Debería sentar la reserva del día de la mesa y descontar la seña")
+    void deberia_sentar_la_reserva_del_dia_y_descontar_la_senia() {
+        // Given: la mesa tiene una reserva para hoy con $10.000 de seña
+        AbrirMesaRequest request = new AbrirMesaRequest(mesaId.getValue().toString());
+        Reserva reserva = Reserva.crear(localId, "Familia Pérez", null, LocalDate.now().atTime(21, 0),
+            8, null, mesaId);
+        reserva.registrarSenia(new BigDecimal("10000"), MovimientoCajaId.generate(), LocalDateTime.now().minusDays(2));
+
+        when(mesaRepository.buscarPorId(mesaId)).thenReturn(Optional.of(mesaLibre));
+        when(pedidoRepository.buscarPorMesaYEstado(mesaId, EstadoPedido.ABIERTO))
+            .thenReturn(Optional.empty());
+        when(pedidoRepository.obtenerSiguienteNumero(localId)).thenReturn(7);
+        when(pedidoRepository.guardar(any(Pedido.class)))
+            .thenAnswer(invocation -> invocation.getArgument(0));
+        when(mesaRepository.guardar(any(Mesa.class)))
+            .thenAnswer(invocation -> invocation.getArgument(0));
+        when(reservaRepository.buscarPendientesPorMesa(mesaId, localId)).thenReturn(List.of(reserva));
+
+        // When
+        useCase.ejecutar(localId, request);
+
+        // Then: el pedido queda vinculado a la reserva con la seña descontada
+        ArgumentCaptor<Pedido> pedidoCaptor = ArgumentCaptor.forClass(Pedido.class);
+        verify(pedidoRepository).guardar(pedidoCaptor.capture());
+        assertThat(pedidoCaptor.getValue().getReservaId()).isEqualTo(reserva.getId());
+        assertThat(pedidoCaptor.getValue().getMontoSenia()).isEqualByComparingTo("10000");
+        assertThat(reserva.getEstado()).isEqualTo(EstadoReserva.CUMPLIDA);
+        assertThat(reserva.getPedidoId()).isEqualTo(pedidoCaptor.getValue().getId());
+        verify(reservaRepository).guardar(reserva);
+    }
+}
@@ -7,9 +7,13 @@ import com.agustinpalma.comandas.domain.model.DomainEnums.*;
 import com.agustinpalma.comandas.domain.model.DomainIds.*;
 import com.agustinpalma.comandas.domain.model.CriterioActivacion.*;
 import com.agustinpalma.comandas.domain.model.EstrategiaPromocion.*;
+import com.agustinpalma.comandas.domain.repository.CategoriaRepository;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
+import com.agustinpalma.comandas.domain.repository.ListaPreciosRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
 import com.agustinpalma.comandas.domain.repository.ProductoRepository;
 import com.agustinpalma.comandas.domain.repository.PromocionRepository;
+import com.agustinpalma.comandas.domain.repository.RecetaRepository;
 import com.agustinpalma.comandas.domain.service.MotorReglasService;
 import com.agustinpalma.comandas.domain.service.NormalizadorVariantesService;
 import org.junit.jupiter.api.BeforeEach;
@@ -24,14 +28,17 @@ import java.math.BigDecimal;
 import java.time.Clock;
 import java.time.LocalDate;
 import java.time.LocalDateTime;
+import java.time.LocalTime;
 import java.time.ZoneId;
 import java.util.Collections;
 import java.util.List;
+import java.util.Map;
 import java.util.Optional;
 import java.util.UUID;
 
 import static org.assertj.core.api.Assertions.assertThat;
 import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.assertj.core.api.Assertions.tuple;
 import static org.mockito.ArgumentMatchers.any;
 import static org.mockito.Mockito.*;
 
@@ -53,6 +60,18 @@ class AgregarProductoUseCaseTest {
     @Mock
     private PromocionRepository promocionRepository;
 
+    @Mock
+    private ListaPreciosRepository listaPreciosRepository;
+
+    @Mock
+    private RecetaRepository recetaRepository;
+
+    @Mock
+    private InsumoRepository insumoRepository;
+
+    @Mock
+    private CategoriaRepository categoriaRepository;
+
     private MotorReglasService motorReglasService;
     private NormalizadorVariantesService normalizadorVariantesService;
 
@@ -74,7 +93,7 @@ class AgregarProductoUseCaseTest {
         
         motorReglasService = new MotorReglasService();
         normalizadorVariantesService = new NormalizadorVariantesService();
-        useCase = new AgregarProductoUseCase(pedidoRepository, productoRepository, promocionRepository, motorReglasService, normalizadorVariantesService, clock);
+        useCase = new AgregarProductoUseCase(pedidoRepository, productoRepository, promocionRepository, motorReglasService, normalizadorVariantesService, listaPreciosRepository, recetaRepository, insumoRepository, categoriaRepository, clock);
         
         // Datos de prueba comunes
         localId = new LocalId(UUID.randomUUID());
@@ -678,4 +697,363 @@ class AgregarProductoUseCaseTest {
         // Verificar que nunca se intentó guardar el pedido
         verify(pedidoRepository, never()).guardar(any(Pedido.class));
     }
+
+    // ─────────────────────────────────────────────────────────────────
+    // HU-103: Combos
+    // ─────────────────────────────────────────────────────────────────
+
+    @Nested
+    @DisplayName("HU-103: Combos con elección por componente")
+    class CombosTests {
+
+        private Producto hamburguesa;
+        private Producto papas;
+        private Producto coca;
+        private Producto agua;
+        private Producto combo;
+
+        @BeforeEach
+        void setUpCombo() {
+            hamburguesa = new Producto(ProductoId.generate(), localId, "Hamburguesa", new BigDecimal("9000"), true, "#FF0000");
+            papas = new Producto(ProductoId.generate(), localId, "Papas fritas", new BigDecimal("3000"), true, "#FFFF00");
+            coca = new Producto(ProductoId.generate(), localId, "Coca-Cola", new BigDecimal("2000"), true, "#FF0000");
+            agua = new Producto(ProductoId.generate(), localId, "Agua", new BigDecimal("1500"), true, "#0000FF");
+
+            combo = new Producto(productoId, localId, "Combo Burger", new BigDecimal("12000"), true, "#FFA500");
+            combo.definirComponentesCombo(List.of(
+                new ComponenteCombo("Principal", List.of(hamburguesa.getId())),
+                new ComponenteCombo("Acompañamiento", List.of(papas.getId())),
+                new ComponenteCombo("Bebida", List.of(coca.getId(), agua.getId()))
+            ));
+        }
+
+        @Test
+        @DisplayName("Debe cobrar el combo a precio cerrado y registrar la opción elegida de cada componente")
+        void deberia_registrar_componentes_elegidos_a_precio_cerrado() {
+            // Given
+            Pedido pedido = new Pedido(pedidoId, localId, mesaId, 1, EstadoPedido.ABIERTO, LocalDateTime.now());
+
+            when(pedidoRepository.buscarPorId(pedidoId)).thenReturn(Optional.of(pedido));
+            when(productoRepository.buscarPorId(productoId)).thenReturn(Optional.of(combo));
+            when(productoRepository.buscarPorIdYLocal(hamburguesa.getId(), localId)).thenReturn(Optional.of(hamburguesa));
+            when(productoRepository.buscarPorIdYLocal(papas.getId(), localId)).thenReturn(Optional.of(papas));
+            when(productoRepository.buscarPorIdYLocal(agua.getId(), localId)).thenReturn(Optional.of(agua));
+            when(promocionRepository.buscarActivasPorLocal(localId)).thenReturn(Collections.emptyList());
+            when(pedidoRepository.guardar(any(Pedido.class))).thenAnswer(invocation -> invocation.getArgument(0));
+
+            AgregarProductoRequest request = new AgregarProductoRequest(
+                pedidoId, productoId, 1, null, null, null,
+                List.of(hamburguesa.getId(), papas.getId(), agua.getId())
+            );
+
+            // When
+            AgregarProductoResponse response = useCase.ejecutar(request);
+
+            // Then: Precio del combo, no la suma de componentes
+            assertThat(response.subtotal()).isEqualByComparingTo(new BigDecimal("12000"));
+
+            ItemPedido item = pedido.getItems().get(0);
+            assertThat(item.esCombo()).isTrue();
+            assertThat(item.getComponentesCombo())
+                .extracting(SeleccionCombo::getComponente, SeleccionCombo::getNombreProducto)
+                .containsExactly(
+                    tuple("Principal", "Hamburguesa"),
+                    tuple("Acompañamiento", "Papas fritas"),
+                    tuple("Bebida", "Agua")
+                );
+        }
+
+        @Test
+        @DisplayName("Debe crear líneas separadas para el mismo combo con distinta elección")
+        void deberia_separar_lineas_de_combos_con_distinta_eleccion() {
+            // Given
+            Pedido pedido = new Pedido(pedidoId, localId, mesaId, 1, EstadoPedido.ABIERTO, LocalDateTime.now());
+
+            when(pedidoRepository.buscarPorId(pedidoId)).thenReturn(Optional.of(pedido));
+            when(productoRepository.buscarPorId(productoId)).thenReturn(Optional.of(combo));
+            when(productoRepository.buscarPorIdYLocal(hamburguesa.getId(), localId)).thenReturn(Optional.of(hamburguesa));
+            when(productoRepository.buscarPorIdYLocal(papas.getId(), localId)).thenReturn(Optional.of(papas));
+            when(productoRepository.buscarPorIdYLocal(coca.getId(), localId)).thenReturn(Optional.of(coca));
+            when(productoRepository.buscarPorIdYLocal(agua.getId(), localId)).thenReturn(Optional.of(agua));
+            when(promocionRepository.buscarActivasPorLocal(localId)).thenReturn(Collections.emptyList());
+            when(pedidoRepository.guardar(any(Pedido.class))).thenAnswer(invocation -> invocation.getArgument(0));
+
+            // When: Un combo con Coca y otro con agua
+            useCase.ejecutar(new AgregarProductoRequest(
+                pedidoId, productoId, 1, null, null, null,
+                List.of(hamburguesa.getId(), papas.getId(), coca.getId())
+            ));
+            useCase.ejecutar(new AgregarProductoRequest(
+                pedidoId, productoId, 1, null, null, null,
+                List.of(hamburguesa.getId(), papas.getId(), agua.getId())
+            ));
+
+            // Then
+            assertThat(pedido.getItems()).hasSize(2);
+        }
+
+        @Test
+        @DisplayName("Debe rechazar una opción que no pertenece al componente")
+        void deberia_rechazar_opcion_invalida_para_el_componente() {
+            // Given: Se elige papas como bebida
+            Pedido pedido = new Pedido(pedidoId, localId, mesaId, 1, EstadoPedido.ABIERTO, LocalDateTime.now());
+
+            when(pedidoRepository.buscarPorId(pedidoId)).thenReturn(Optional.of(pedido));
+            when(productoRepository.buscarPorId(productoId)).thenReturn(Optional.of(combo));
+            when(productoRepository.buscarPorIdYLocal(hamburguesa.getId(), localId)).thenReturn(Optional.of(hamburguesa));
+            when(productoRepository.buscarPorIdYLocal(papas.getId(), localId)).thenReturn(Optional.of(papas));
+
+            AgregarProductoRequest request = new AgregarProductoRequest(
+                pedidoId, productoId, 1, null, null, null,
+                List.of(hamburguesa.getId(), papas.getId(), papas.getId())
+            );
+
+            // When / Then
+            assertThatThrownBy(() -> useCase.ejecutar(request))
+                .isInstanceOf(IllegalArgumentException.class)
+                .hasMessageContaining("no es una opción válida para 'Bebida'");
+
+            verify(pedidoRepository, never()).guardar(any(Pedido.class));
+        }
+    }
+
+    // ─────────────────────────────────────────────────────────────────
+    // HU-108: Listas de precios por canal
+    // ─────────────────────────────────────────────────────────────────
+
+    @Nested
+    @DisplayName("HU-108: Listas de precios por canal")
+    class ListasPreciosTests {
+
+        private Producto milanesa;
+        private Producto huevo;
+        private ListaPrecios delivery;
+
+        @BeforeEach
+        void setUpListas() {
+            milanesa = new Producto(productoId, localId, "Milanesa", new BigDecimal("10000"), true, "#FF0000");
+            huevo = new Producto(ProductoId.generate(), localId, "Huevo frito", new BigDecimal("1000"), true, "#FFFF00",
+                null, true, null);
+
+            // Delivery: +15% general, huevo con precio especial
+            delivery = new ListaPrecios(
+                ListaPreciosId.generate(), localId, CanalVenta.DELIVERY, "Delivery",
+                new BigDecimal("15"), Map.of(huevo.getId(), new BigDecimal("1500"))
+            );
+        }
+
+        @Test
+        @DisplayName("Debe cotizar producto y extras con la lista congelada en el pedido")
+        void deberia_cotizar_con_la_lista_del_pedido() {
+            // Given
+            Pedido pedido = new Pedido(pedidoId, localId, mesaId, 1, EstadoPedido.ABIERTO, LocalDateTime.now());
+            pedido.asignarCanal(CanalVenta.DELIVERY, delivery);
+
+            when(pedidoRepository.buscarPorId(pedidoId)).thenReturn(Optional.of(pedido));
+            when(productoRepository.buscarPorId(productoId)).thenReturn(Optional.of(milanesa));
+            when(productoRepository.buscarPorId(huevo.getId())).thenReturn(Optional.of(huevo));
+            when(listaPreciosRepository.buscarPorId(delivery.getId(), localId)).thenReturn(Optional.of(delivery));
+            when(promocionRepository.buscarActivasPorLocal(localId)).thenReturn(Collections.emptyList());
+            when(pedidoRepository.guardar(any(Pedido.class))).thenAnswer(invocation -> invocation.getArgument(0));
+
+            // When
+            useCase.ejecutar(new AgregarProductoRequest(
+                pedidoId, productoId, 1, null, List.of(huevo.getId())
+            ));
+
+            // Then: 10000 + 15% y el huevo a su precio especial
+            ItemPedido item = pedido.getItems().get(0);
+            assertThat(item.getPrecioUnitario()).isEqualByComparingTo(new BigDecimal("11500"));
+            assertThat(item.getExtras()).singleElement()
+                .extracting(ExtraPedido::getPrecioSnapshot)
+                .satisfies(precio -> assertThat(precio).isEqualByComparingTo(new BigDecimal("1500")));
+
+            // El catálogo no se modifica
+            assertThat(milanesa.getPrecio()).isEqualByComparingTo(new BigDecimal("10000"));
+        }
+
+        @Test
+        @DisplayName("Un pedido sin lista debe cotizar a precio de catálogo sin consultar listas")
+        void deberia_usar_precio_de_catalogo_sin_lista() {
+            // Given
+            Pedido pedido = new Pedido(pedidoId, localId, mesaId, 1, EstadoPedido.ABIERTO, LocalDateTime.now());
+
+            when(pedidoRepository.buscarPorId(pedidoId)).thenReturn(Optional.of(pedido));
+            when(productoRepository.buscarPorId(productoId)).thenReturn(Optional.of(milanesa));
+            when(promocionRepository.buscarActivasPorLocal(localId)).thenReturn(Collections.emptyList());
+            when(pedidoRepository.guardar(any(Pedido.class))).thenAnswer(invocation -> invocation.getArgument(0));
+
+            // When
+            useCase.ejecutar(new AgregarProductoRequest(pedidoId, productoId, 1, null));
+
+            // Then
+            assertThat(pedido.getItems().get(0).getPrecioUnitario()).isEqualByComparingTo(new BigDecimal("10000"));
+            verifyNoInteractions(listaPreciosRepository);
+        }
+    }
+
+    @Test
+    @DisplayName("HU-125 - Un producto marcado 86 no se puede cargar en el pedido")
+    void deberia_rechazar_producto_agotado() {
+        // Given: la cocina avisó que no hay más milanesas
+        Producto producto = new Producto(productoId, localId, "Milanesa", new BigDecimal("9500.00"), true, "#FFAA00");
+        producto.marcarAgotado(true);
+        Pedido pedido = new Pedido(pedidoId, localId, mesaId, 1, EstadoPedido.ABIERTO, LocalDateTime.now());
+
+        when(pedidoRepository.buscarPorId(pedidoId)).thenReturn(Optional.of(pedido));
+        when(productoRepository.buscarPorId(productoId)).thenReturn(Optional.of(producto));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.ejecutar(new AgregarProductoRequest(pedidoId, productoId, 1, null)))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("Milanesa")
+            .hasMessageContaining("86");
+        assertThat(pedido.getItems()).isEmpty();
+        verify(pedidoRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("HU-161 - Fuera del horario de su categoría el producto no se puede cargar")
+    void deberia_rechazar_producto_fuera_de_horario() {
+        // Given: son las 19:00 y los desayunos se sirven de 7 a 11
+        Categoria desayunos = new Categoria(CategoriaId.generate(), localId, "Desayunos", "#FFFFFF", false, false, 0);
+        desayunos.cambiarFranjaHoraria(new FranjaHoraria(LocalTime.of(7, 0), LocalTime.of(11, 0)));
+        Producto producto = new Producto(productoId, localId, "Medialunas", new BigDecimal("3000.00"), true, "#FFFFFF");
+        producto.actualizarCategoria(desayunos.getId());
+        Pedido pedido = new Pedido(pedidoId, localId, mesaId, 1, EstadoPedido.ABIERTO, LocalDateTime.now());
+
+        when(pedidoRepository.buscarPorId(pedidoId)).thenReturn(Optional.of(pedido));
+        when(productoRepository.buscarPorId(productoId)).thenReturn(Optional.of(producto));
+        when(categoriaRepository.buscarPorId(desayunos.getId())).thenReturn(Optional.of(desayunos));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.ejecutar(new AgregarProductoRequest(pedidoId, productoId, 1, null)))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("Medialunas")
+            .hasMessageContaining("de 07:00 a 11:00");
+        assertThat(pedido.getItems()).isEmpty();
+        verify(pedidoRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("HU-147 - El mismo plato para un comensal con alergia va en su propia línea")
+    void no_deberia_fusionar_items_con_advertencias_distintas() {
+        // Given
+        Producto producto = new Producto(productoId, localId, "Milanesa", new BigDecimal("9500.00"), true, "#FFAA00");
+        Pedido pedido = new Pedido(pedidoId, localId, mesaId, 1, EstadoPedido.ABIERTO, LocalDateTime.now());
+
+        when(pedidoRepository.buscarPorId(pedidoId)).thenReturn(Optional.of(pedido));
+        when(productoRepository.buscarPorId(productoId)).thenReturn(Optional.of(producto));
+        when(promocionRepository.buscarActivasPorLocal(localId)).thenReturn(Collections.emptyList());
+        when(pedidoRepository.guardar(any(Pedido.class))).thenAnswer(invocation -> invocation.getArgument(0));
+
+        // When: una milanesa común, otra para un celíaco y otra más para el mismo comensal
+        useCase.ejecutar(new AgregarProductoRequest(pedidoId, productoId, 1, null));
+        useCase.ejecutar(new AgregarProductoRequest(pedidoId, productoId, 1, null, null, null, null, null,
+            List.of(Alergeno.TACC)));
+        useCase.ejecutar(new AgregarProductoRequest(pedidoId, productoId, 1, null, null, null, null, null,
+            List.of(Alergeno.TACC)));
+
+        // Then
+        assertThat(pedido.getItems())
+            .extracting(ItemPedido::getCantidad, ItemPedido::getAdvertencias)
+            .containsExactlyInAnyOrder(
+                tuple(1, java.util.Set.of()),
+                tuple(2, java.util.Set.of(Alergeno.TACC))
+            );
+    }
+
+    @Test
+    @DisplayName("HU-148 - Sin cebolla guarda el ingrediente quitado y va en su propia línea")
+    void deberia_registrar_ingrediente_quitado_de_la_receta() {
+        // Given: la hamburguesa lleva carne y cebolla
+        Producto producto = new Producto(productoId, localId, "Hamburguesa", new BigDecimal("8000.00"), true, "#FFAA00");
+        Pedido pedido = new Pedido(pedidoId, localId, mesaId, 1, EstadoPedido.ABIERTO, LocalDateTime.now());
+        Insumo carne = new Insumo(InsumoId.generate(), localId, "Carne picada", UnidadInsumo.KILOGRAMO, new BigDecimal("9000"));
+        Insumo cebolla = new Insumo(InsumoId.generate(), localId, "Cebolla", UnidadInsumo.KILOGRAMO, new BigDecimal("1200"));
+        Receta receta = new Receta(productoId, localId, List.of(
+            new IngredienteReceta(carne.getId(), new BigDecimal("0.180")),
+            new IngredienteReceta(cebolla.getId(), new BigDecimal("0.030"))));
+
+        when(pedidoRepository.buscarPorId(pedidoId)).thenReturn(Optional.of(pedido));
+        when(productoRepository.buscarPorId(productoId)).thenReturn(Optional.of(producto));
+        when(promocionRepository.buscarActivasPorLocal(localId)).thenReturn(Collections.emptyList());
+        when(recetaRepository.buscarPorProducto(productoId, localId)).thenReturn(Optional.of(receta));
+        when(insumoRepository.buscarPorId(cebolla.getId(), localId)).thenReturn(Optional.of(cebolla));
+        when(pedidoRepository.guardar(any(Pedido.class))).thenAnswer(invocation -> invocation.getArgument(0));
+
+        // When: una común y una sin cebolla
+        useCase.ejecutar(new AgregarProductoRequest(pedidoId, productoId, 1, null));
+        useCase.ejecutar(new AgregarProductoRequest(pedidoId, productoId, 1, null, null, null, null, null,
+            null, List.of(cebolla.getId())));
+
+        // Then
+        assertThat(pedido.getItems()).hasSize(2);
+        ItemPedido sinCebolla = pedido.getItems().stream()
+            .filter(ItemPedido::tieneIngredientesQuitados)
+            .findFirst().orElseThrow();
+        assertThat(sinCebolla.getIngredientesQuitados())
+            .extracting(IngredienteQuitado::getNombre)
+            .containsExactly("Cebolla");
+        assertThat(sinCebolla.quitaInsumo(cebolla.getId())).isTrue();
+        assertThat(sinCebolla.quitaInsumo(carne.getId())).isFalse();
+    }
+
+    @Test
+    @DisplayName("HU-148 - No se puede quitar un insumo que no está en la receta")
+    void deberia_rechazar_quitar_insumo_fuera_de_la_receta() {
+        // Given
+        Producto producto = new Producto(productoId, localId, "Hamburguesa", new BigDecimal("8000.00"), true, "#FFAA00");
+        Pedido pedido = new Pedido(pedidoId, localId, mesaId, 1, EstadoPedido.ABIERTO, LocalDateTime.now());
+        Insumo carne = new Insumo(InsumoId.generate(), localId, "Carne picada", UnidadInsumo.KILOGRAMO, new BigDecimal("9000"));
+        Receta receta = new Receta(productoId, localId,
+            List.of(new IngredienteReceta(carne.getId(), new BigDecimal("0.180"))));
+
+        when(pedidoRepository.buscarPorId(pedidoId)).thenReturn(Optional.of(pedido));
+        when(productoRepository.buscarPorId(productoId)).thenReturn(Optional.of(producto));
+        when(recetaRepository.buscarPorProducto(productoId, localId)).thenReturn(Optional.of(receta));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.ejecutar(new AgregarProductoRequest(pedidoId, productoId, 1, null,
+            null, null, null, null, null, List.of(InsumoId.generate()))))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("no está en la receta");
+        assertThat(pedido.getItems()).isEmpty();
+    }
+
+    @Test
+    @DisplayName("HU-164 - Reintentar un ítem del borrador que ya llegó no lo carga dos veces")
+    void deberia_ignorar_el_reintento_de_un_item_del_borrador() {
+        // Given: el POS mandó dos empanadas de su borrador y se perdió la respuesta
+        Producto producto = new Producto(productoId, localId, "Empanada", new BigDecimal("25.00"), true, "#FF00FF");
+        Pedido pedido = new Pedido(pedidoId, localId, mesaId, 1, EstadoPedido.ABIERTO, LocalDateTime.now());
+        UUID primera = UUID.randomUUID();
+        UUID segunda = UUID.randomUUID();
+
+        when(pedidoRepository.buscarPorId(pedidoId)).thenReturn(Optional.of(pedido));
+        when(productoRepository.buscarPorId(productoId)).thenReturn(Optional.of(producto));
+        when(promocionRepository.buscarActivasPorLocal(localId)).thenReturn(Collections.emptyList());
+        when(pedidoRepository.guardar(any(Pedido.class)))
+            .thenAnswer(invocation -> invocation.getArgument(0));
+
+        useCase.ejecutar(conBorrador(2, primera));
+        useCase.ejecutar(conBorrador(1, segunda));
+
+        // When: al recuperar el borrador se reintentan las dos, con una versión
+        // que ya no es la del pedido (las cargas perdidas la subieron)
+        long versionVieja = pedido.getVersion() + 1;
+        useCase.ejecutar(conBorrador(2, primera), versionVieja);
+        AgregarProductoResponse response = useCase.ejecutar(conBorrador(1, segunda), versionVieja);
+
+        // Then: no es un conflicto; la línea fusionada recuerda las dos cargas y no suma de nuevo
+        assertThat(response.items()).hasSize(1);
+        assertThat(response.items().get(0).cantidad()).isEqualTo(3);
+        assertThat(pedido.getItems().get(0).getBorradores()).containsExactlyInAnyOrder(primera, segunda);
+        verify(pedidoRepository, times(2)).guardar(any(Pedido.class));
+    }
+
+    private AgregarProductoRequest conBorrador(int cantidad, UUID borradorId) {
+        return new AgregarProductoRequest(pedidoId, productoId, cantidad, "De carne",
+            null, null, null, null, null, null, borradorId);
+    }
 }
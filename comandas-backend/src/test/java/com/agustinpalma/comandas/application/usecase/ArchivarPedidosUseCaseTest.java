@@ -0,0 +1,94 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.ArchivoPedidosRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.time.Clock;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.anyInt;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso ArchivarPedidosUseCase.
+ * Valida los criterios de la HU-162 (archivo de pedidos).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Archivo de pedidos - Caso de Uso")
+class ArchivarPedidosUseCaseTest {
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private ArchivoPedidosRepository archivoPedidosRepository;
+
+    private Clock clock;
+    private LocalId localId;
+
+    @BeforeEach
+    void setUp() {
+        clock = Clock.fixed(Instant.parse("2026-10-14T15:30:00Z"), ZoneId.of("UTC"));
+        localId = new LocalId(UUID.randomUUID());
+    }
+
+    @Test
+    @DisplayName("Mueve al archivo los cerrados antes del horizonte y los borra de la base operativa")
+    void deberia_archivar_y_borrar_los_pedidos_vencidos() {
+        // Given
+        ArchivarPedidosUseCase useCase = new ArchivarPedidosUseCase(pedidoRepository, archivoPedidosRepository, 12, clock);
+        Pedido viejo = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 7, EstadoPedido.CERRADO,
+            LocalDateTime.of(2025, 3, 2, 21, 0));
+        when(pedidoRepository.buscarCerradosAntesDe(LocalDateTime.of(2025, 10, 14, 0, 0), ArchivarPedidosUseCase.TAMANIO_LOTE))
+            .thenReturn(List.of(viejo));
+
+        // When
+        int archivados = useCase.archivarLote();
+
+        // Then
+        assertThat(archivados).isEqualTo(1);
+        verify(archivoPedidosRepository).archivar(List.of(viejo), LocalDateTime.of(2026, 10, 14, 15, 30));
+        verify(pedidoRepository).eliminar(List.of(viejo.getId()));
+    }
+
+    @Test
+    @DisplayName("Sin pedidos vencidos no toca el archivo")
+    void no_deberia_archivar_si_no_hay_pedidos_vencidos() {
+        // Given
+        ArchivarPedidosUseCase useCase = new ArchivarPedidosUseCase(pedidoRepository, archivoPedidosRepository, 12, clock);
+        when(pedidoRepository.buscarCerradosAntesDe(any(), anyInt())).thenReturn(List.of());
+
+        // When / Then
+        assertThat(useCase.archivarLote()).isZero();
+        verifyNoInteractions(archivoPedidosRepository);
+        verify(pedidoRepository, never()).eliminar(any());
+    }
+
+    @Test
+    @DisplayName("Con retención 0 el archivado queda desactivado")
+    void no_deberia_archivar_con_retencion_cero() {
+        // Given
+        ArchivarPedidosUseCase useCase = new ArchivarPedidosUseCase(pedidoRepository, archivoPedidosRepository, 0, clock);
+
+        // When / Then
+        assertThat(useCase.archivarLote()).isZero();
+        verifyNoInteractions(pedidoRepository, archivoPedidosRepository);
+    }
+}
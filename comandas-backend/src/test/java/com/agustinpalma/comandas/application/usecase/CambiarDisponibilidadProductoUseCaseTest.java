@@ -0,0 +1,96 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.DisponibilidadProductoEvento;
+import com.agustinpalma.comandas.application.dto.ProductoResponse;
+import com.agustinpalma.comandas.application.ports.output.NotificadorTiempoRealPort;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso CambiarDisponibilidadProductoUseCase.
+ * Valida los criterios de la HU-125 (productos 86 en tiempo real).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Cambiar Disponibilidad de Producto - Caso de Uso")
+class CambiarDisponibilidadProductoUseCaseTest {
+
+    @Mock
+    private ProductoRepository productoRepository;
+
+    @Mock
+    private NotificadorTiempoRealPort notificador;
+
+    private CambiarDisponibilidadProductoUseCase useCase;
+
+    private LocalId localId;
+    private Producto milanesa;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new CambiarDisponibilidadProductoUseCase(productoRepository, notificador);
+        localId = new LocalId(UUID.randomUUID());
+        milanesa = new Producto(ProductoId.generate(), localId, "Milanesa", new BigDecimal("9500"), true, "#FFAA00");
+    }
+
+    @Test
+    @DisplayName("Marcar 86 guarda el producto y avisa a todas las terminales")
+    void deberia_marcar_agotado_y_notificar() {
+        // Given
+        when(productoRepository.buscarPorIdYLocal(milanesa.getId(), localId)).thenReturn(Optional.of(milanesa));
+        when(productoRepository.guardar(any())).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        ProductoResponse response = useCase.ejecutar(localId, milanesa.getId(), true);
+
+        // Then
+        assertThat(response.agotado()).isTrue();
+        verify(notificador).publicar(localId, DisponibilidadProductoEvento.NOMBRE,
+            new DisponibilidadProductoEvento(milanesa.getId().getValue().toString(), "Milanesa", true));
+    }
+
+    @Test
+    @DisplayName("Repetir la misma marca no vuelve a guardar ni a notificar")
+    void no_deberia_notificar_si_no_cambia() {
+        // Given: otra terminal ya lo marcó
+        milanesa.marcarAgotado(true);
+        when(productoRepository.buscarPorIdYLocal(milanesa.getId(), localId)).thenReturn(Optional.of(milanesa));
+
+        // When
+        ProductoResponse response = useCase.ejecutar(localId, milanesa.getId(), true);
+
+        // Then
+        assertThat(response.agotado()).isTrue();
+        verify(productoRepository, never()).guardar(any());
+        verifyNoInteractions(notificador);
+    }
+
+    @Test
+    @DisplayName("Rechaza productos de otro local")
+    void deberia_rechazar_producto_inexistente() {
+        // Given
+        ProductoId otro = ProductoId.generate();
+        when(productoRepository.buscarPorIdYLocal(otro, localId)).thenReturn(Optional.empty());
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.ejecutar(localId, otro, true))
+            .isInstanceOf(IllegalArgumentException.class);
+        verifyNoInteractions(notificador);
+    }
+}
@@ -1,45 +1,102 @@
 package com.agustinpalma.comandas.application.usecase;
 
+import com.agustinpalma.comandas.application.dto.CerrarMesaCommand;
 import com.agustinpalma.comandas.application.dto.CerrarMesaResponse;
 import com.agustinpalma.comandas.application.dto.PagoRequest;
+import com.agustinpalma.comandas.application.ports.output.NotificadorTiempoRealPort;
+import com.agustinpalma.comandas.domain.exception.PedidoModificadoException;
 import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoMesa;
 import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
 import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Moneda;
+import com.agustinpalma.comandas.domain.model.DomainEnums.ModoRedondeo;
+import com.agustinpalma.comandas.domain.model.Cliente;
+import com.agustinpalma.comandas.domain.model.ConsumoPersonal;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoCuenta;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoPuntos;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoInsumo;
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ItemPedidoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.ExtraPedido;
+import com.agustinpalma.comandas.domain.model.IngredienteQuitado;
+import com.agustinpalma.comandas.domain.model.IngredienteReceta;
+import com.agustinpalma.comandas.domain.model.Insumo;
 import com.agustinpalma.comandas.domain.model.ItemPedido;
+import com.agustinpalma.comandas.domain.model.MovimientoInsumo;
+import com.agustinpalma.comandas.domain.model.Receta;
+import com.agustinpalma.comandas.domain.model.RecargosMedioPago;
+import com.agustinpalma.comandas.domain.model.RedondeoEfectivo;
+import com.agustinpalma.comandas.domain.model.TarjetaRegalo;
+import com.agustinpalma.comandas.domain.model.TiposCambio;
 import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.MovimientoCuentaCorriente;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.MovimientoPuntos;
 import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.ProgramaPuntos;
+import com.agustinpalma.comandas.domain.model.PromoCumpleanios;
+import com.agustinpalma.comandas.domain.repository.ClienteRepository;
+import com.agustinpalma.comandas.domain.repository.ConsumoPersonalRepository;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoInsumoRepository;
+import com.agustinpalma.comandas.domain.repository.RecargosMedioPagoRepository;
+import com.agustinpalma.comandas.domain.repository.TarjetaRegaloRepository;
+import com.agustinpalma.comandas.domain.repository.TiposCambioRepository;
+import com.agustinpalma.comandas.domain.repository.RedondeoEfectivoRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.RecetaRepository;
 import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCuentaCorrienteRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoPuntosRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
 import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.domain.repository.ProgramaPuntosRepository;
 import com.agustinpalma.comandas.domain.repository.PromocionRepository;
+import com.agustinpalma.comandas.domain.repository.PromoCumpleaniosRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
 import com.agustinpalma.comandas.domain.repository.MovimientoStockRepository;
+import com.agustinpalma.comandas.domain.service.ConsumoPersonalService;
+import com.agustinpalma.comandas.domain.service.CuentaCorrienteService;
 import com.agustinpalma.comandas.domain.service.GestorStockService;
+import com.agustinpalma.comandas.domain.service.InventarioService;
 import com.agustinpalma.comandas.domain.service.MotorReglasService;
+import com.agustinpalma.comandas.domain.service.ProgramaPuntosService;
 import org.junit.jupiter.api.BeforeEach;
 import org.junit.jupiter.api.DisplayName;
 import org.junit.jupiter.api.Test;
 import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.ArgumentCaptor;
 import org.mockito.Mock;
 import org.mockito.junit.jupiter.MockitoExtension;
 
 import java.math.BigDecimal;
 import java.time.Clock;
 import java.time.Instant;
+import java.time.LocalDate;
 import java.time.LocalDateTime;
 import java.time.ZoneId;
 import java.util.Collections;
 import java.util.List;
+import java.util.Map;
 import java.util.Optional;
 import java.util.UUID;
 
 import static org.assertj.core.api.Assertions.assertThat;
 import static org.assertj.core.api.Assertions.assertThatThrownBy;
 import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
 import static org.mockito.Mockito.*;
 
 /**
@@ -74,6 +131,66 @@ class CerrarMesaUseCaseTest {
     @Mock
     private GestorStockService gestorStockService;
 
+    @Mock
+    private ClienteRepository clienteRepository;
+
+    @Mock
+    private MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository;
+
+    @Mock
+    private ProgramaPuntosRepository programaPuntosRepository;
+
+    @Mock
+    private MovimientoPuntosRepository movimientoPuntosRepository;
+
+    @Mock
+    private TurnoCajaRepository turnoCajaRepository;
+
+    @Mock
+    private MozoRepository mozoRepository;
+
+    @Mock
+    private ConsumoPersonalRepository consumoPersonalRepository;
+
+    @Mock
+    private RecetaRepository recetaRepository;
+
+    @Mock
+    private InsumoRepository insumoRepository;
+
+    @Mock
+    private MovimientoInsumoRepository movimientoInsumoRepository;
+
+    @Mock
+    private RecargosMedioPagoRepository recargosMedioPagoRepository;
+
+    @Mock
+    private TarjetaRegaloRepository tarjetaRegaloRepository;
+
+    @Mock
+    private TiposCambioRepository tiposCambioRepository;
+
+    @Mock
+    private RedondeoEfectivoRepository redondeoEfectivoRepository;
+
+    @Mock
+    private JornadaComercialRepository jornadaComercialRepository;
+
+    @Mock
+    private GestionarCajasUseCase gestionarCajasUseCase;
+
+    @Mock
+    private GestionarSeriesComprobantesUseCase gestionarSeriesComprobantesUseCase;
+
+    @Mock
+    private PromoCumpleaniosRepository promoCumpleaniosRepository;
+
+    @Mock
+    private MovimientoCajaRepository movimientoCajaRepository;
+
+    @Mock
+    private NotificadorTiempoRealPort notificador;
+
     private Clock clock;
 
     private CerrarMesaUseCase useCase;
@@ -89,7 +206,18 @@ class CerrarMesaUseCaseTest {
             Instant.parse("2026-02-06T22:00:00Z"),
             ZoneId.of("America/Argentina/Buenos_Aires")
         );
-        useCase = new CerrarMesaUseCase(mesaRepository, pedidoRepository, promocionRepository, productoRepository, movimientoStockRepository, motorReglasService, gestorStockService, clock);
+        useCase = new CerrarMesaUseCase(mesaRepository, pedidoRepository, promocionRepository, productoRepository, movimientoStockRepository, motorReglasService, gestorStockService,
+            clienteRepository, programaPuntosRepository, movimientoPuntosRepository, new ProgramaPuntosService(),
+            turnoCajaRepository, recetaRepository, insumoRepository, movimientoInsumoRepository, new InventarioService(),
+            jornadaComercialRepository, promoCumpleaniosRepository, gestionarCajasUseCase,
+            new GestionarPagosACuentaUseCase(pedidoRepository, recargosMedioPagoRepository, tiposCambioRepository,
+                redondeoEfectivoRepository, jornadaComercialRepository, gestionarCajasUseCase, notificador, clock),
+            gestionarSeriesComprobantesUseCase,
+            new GestionarTarjetasRegaloUseCase(tarjetaRegaloRepository, movimientoCajaRepository, turnoCajaRepository, clock),
+            new RegistrarCargoCuentaCorrienteUseCase(movimientoCuentaCorrienteRepository, new CuentaCorrienteService()),
+            new ImputarConsumoPersonalUseCase(mozoRepository, consumoPersonalRepository, jornadaComercialRepository,
+                new ConsumoPersonalService()),
+            clock);
         
         localIdValido = new LocalId(UUID.randomUUID());
         mesaIdValida = new MesaId(UUID.randomUUID());
@@ -117,7 +245,7 @@ class CerrarMesaUseCaseTest {
         when(mesaRepository.guardar(any(Mesa.class))).thenReturn(mesa);
 
         // When: Se ejecuta el caso de uso
-        CerrarMesaResponse response = useCase.ejecutar(localIdValido, mesaIdValida, pagos);
+        CerrarMesaResponse response = useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, pagos));
 
         // Then: La mesa y el pedido se cierran correctamente
         assertThat(response).isNotNull();
@@ -143,7 +271,7 @@ class CerrarMesaUseCaseTest {
         when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.empty());
 
         // When/Then: Debe lanzar excepción
-        assertThatThrownBy(() -> useCase.ejecutar(localIdValido, mesaIdValida, pagos))
+        assertThatThrownBy(() -> useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, pagos)))
             .isInstanceOf(IllegalStateException.class)
             .hasMessageContaining("La mesa no existe");
 
@@ -167,7 +295,7 @@ class CerrarMesaUseCaseTest {
         when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
 
         // When/Then: Debe lanzar excepción de validación de tenant
-        assertThatThrownBy(() -> useCase.ejecutar(localIdValido, mesaIdValida, pagos))
+        assertThatThrownBy(() -> useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, pagos)))
             .isInstanceOf(IllegalArgumentException.class)
             .hasMessageContaining("La mesa no pertenece a este local");
 
@@ -190,7 +318,7 @@ class CerrarMesaUseCaseTest {
         when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.empty());
 
         // When/Then: Debe lanzar excepción
-        assertThatThrownBy(() -> useCase.ejecutar(localIdValido, mesaIdValida, pagos))
+        assertThatThrownBy(() -> useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, pagos)))
             .isInstanceOf(IllegalStateException.class)
             .hasMessageContaining("La mesa no tiene un pedido abierto");
 
@@ -223,7 +351,7 @@ class CerrarMesaUseCaseTest {
         when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
 
         // When/Then: El dominio debe rechazar la finalización del pedido
-        assertThatThrownBy(() -> useCase.ejecutar(localIdValido, mesaIdValida, pagos))
+        assertThatThrownBy(() -> useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, pagos)))
             .isInstanceOf(IllegalArgumentException.class)
             .hasMessageContaining("No se puede cerrar un pedido sin ítems");
 
@@ -250,7 +378,7 @@ class CerrarMesaUseCaseTest {
         when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
 
         // When/Then: La entidad Mesa debe rechazar el cierre
-        assertThatThrownBy(() -> useCase.ejecutar(localIdValido, mesaIdValida, pagos))
+        assertThatThrownBy(() -> useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, pagos)))
             .isInstanceOf(IllegalArgumentException.class)
             .hasMessageContaining("ya está libre");
 
@@ -274,7 +402,7 @@ class CerrarMesaUseCaseTest {
         when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
 
         // When/Then: El dominio rechaza pagos vacíos
-        assertThatThrownBy(() -> useCase.ejecutar(localIdValido, mesaIdValida, pagos))
+        assertThatThrownBy(() -> useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, pagos)))
             .isInstanceOf(IllegalArgumentException.class)
             .hasMessageContaining("al menos un pago");
 
@@ -302,7 +430,7 @@ class CerrarMesaUseCaseTest {
         when(mesaRepository.guardar(any(Mesa.class))).thenReturn(mesa);
 
         // When: Se cierra la mesa
-        CerrarMesaResponse response = useCase.ejecutar(localIdValido, mesaIdValida, pagos);
+        CerrarMesaResponse response = useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, pagos));
 
         // Then: La fecha de cierre usa el clock fijo
         assertThat(response.fechaCierre()).isNotNull();
@@ -311,6 +439,593 @@ class CerrarMesaUseCaseTest {
             .isEqualTo(java.time.LocalDate.of(2026, 2, 6));
     }
 
+    @Test
+    @DisplayName("HU-104: Debe registrar un cargo en la cuenta del cliente al cerrar a cuenta corriente")
+    void deberia_registrar_cargo_cuenta_corriente_al_cerrar() {
+        // Given: Pedido de $1000 pagado $400 efectivo + $600 a cuenta corriente
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+
+        Cliente cliente = new Cliente(ClienteId.generate(), localIdValido, "Juan - Oficina 3B",
+            null, new BigDecimal("5000"), true);
+
+        List<PagoRequest> pagos = List.of(
+            new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("400")),
+            new PagoRequest(MedioPago.CUENTA_CORRIENTE, new BigDecimal("600"))
+        );
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(clienteRepository.buscarPorIdYLocal(cliente.getId(), localIdValido)).thenReturn(Optional.of(cliente));
+        when(movimientoCuentaCorrienteRepository.buscarPorCliente(cliente.getId(), localIdValido)).thenReturn(List.of());
+        when(pedidoRepository.guardar(any(Pedido.class))).thenReturn(pedido);
+        when(mesaRepository.guardar(any(Mesa.class))).thenReturn(mesa);
+
+        // When
+        useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, pagos).conCliente(cliente.getId()));
+
+        // Then: Se registra un solo cargo por la parte a cuenta corriente
+        ArgumentCaptor<MovimientoCuentaCorriente> captor = ArgumentCaptor.forClass(MovimientoCuentaCorriente.class);
+        verify(movimientoCuentaCorrienteRepository).guardar(captor.capture());
+        MovimientoCuentaCorriente cargo = captor.getValue();
+        assertThat(cargo.getTipo()).isEqualTo(TipoMovimientoCuenta.CARGO);
+        assertThat(cargo.getMonto()).isEqualByComparingTo("600");
+        assertThat(cargo.getPedidoId()).isEqualTo(pedidoIdValido);
+        assertThat(cargo.getDescripcion()).isEqualTo("Pedido #1 - Mesa 5");
+    }
+
+    @Test
+    @DisplayName("HU-104: Debe rechazar el cierre si el cargo supera el límite de crédito del cliente")
+    void deberia_rechazar_cierre_si_cargo_supera_limite_de_credito() {
+        // Given: Cliente con límite $1500 y deuda previa de $800
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+
+        Cliente cliente = new Cliente(ClienteId.generate(), localIdValido, "Juan - Oficina 3B",
+            null, new BigDecimal("1500"), true);
+        MovimientoCuentaCorriente deudaPrevia = MovimientoCuentaCorriente.cargo(
+            cliente.getId(), localIdValido, PedidoId.generate(), new BigDecimal("800"),
+            LocalDateTime.of(2026, 2, 1, 13, 0), "Pedido #0 - Mesa 2");
+
+        List<PagoRequest> pagos = List.of(
+            new PagoRequest(MedioPago.CUENTA_CORRIENTE, new BigDecimal("1000"))
+        );
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(clienteRepository.buscarPorIdYLocal(cliente.getId(), localIdValido)).thenReturn(Optional.of(cliente));
+        when(movimientoCuentaCorrienteRepository.buscarPorCliente(cliente.getId(), localIdValido))
+            .thenReturn(List.of(deudaPrevia));
+
+        // When/Then
+        assertThatThrownBy(() -> useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, pagos)
+                .conCliente(cliente.getId())))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("supera el crédito disponible");
+
+        verify(movimientoCuentaCorrienteRepository, never()).guardar(any());
+        verify(pedidoRepository, never()).guardar(any());
+        verify(mesaRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("HU-105: Debe aplicar el canje como descuento y acumular puntos sobre lo cobrado")
+    void deberia_canjear_puntos_y_acumular_sobre_lo_cobrado() {
+        // Given: Programa 1 punto cada $100, punto = $1, vencen a los 30 días.
+        //        Cliente con 300 puntos que canjea 200 en un pedido de $1000
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+
+        Cliente cliente = new Cliente(ClienteId.generate(), localIdValido, "Ana", null, BigDecimal.ZERO, true);
+        ProgramaPuntos programa = new ProgramaPuntos(localIdValido, true, new BigDecimal("100"), BigDecimal.ONE, 30);
+        MovimientoPuntos saldoPrevio = MovimientoPuntos.acumulacion(
+            cliente.getId(), localIdValido, PedidoId.generate(), 300,
+            LocalDateTime.of(2026, 2, 1, 13, 0), LocalDateTime.of(2026, 3, 3, 13, 0));
+
+        List<PagoRequest> pagos = List.of(new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("800")));
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(clienteRepository.buscarPorIdYLocal(cliente.getId(), localIdValido)).thenReturn(Optional.of(cliente));
+        when(programaPuntosRepository.buscarPorLocal(localIdValido)).thenReturn(Optional.of(programa));
+        when(movimientoPuntosRepository.buscarPorCliente(cliente.getId(), localIdValido)).thenReturn(List.of(saldoPrevio));
+        when(pedidoRepository.guardar(any(Pedido.class))).thenReturn(pedido);
+        when(mesaRepository.guardar(any(Mesa.class))).thenReturn(mesa);
+
+        // When
+        useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, pagos)
+                .conCliente(cliente.getId())
+                .conCanje(200));
+
+        // Then: El total congelado ya descuenta el canje
+        assertThat(pedido.getMontoTotalFinal()).isEqualByComparingTo("800");
+        assertThat(pedido.getPuntosCanjeados()).isEqualTo(200);
+
+        // Then: Se registra el canje y la acumulación por los $800 cobrados
+        ArgumentCaptor<MovimientoPuntos> captor = ArgumentCaptor.forClass(MovimientoPuntos.class);
+        verify(movimientoPuntosRepository, times(2)).guardar(captor.capture());
+        MovimientoPuntos canje = captor.getAllValues().get(0);
+        MovimientoPuntos acumulacion = captor.getAllValues().get(1);
+
+        assertThat(canje.getTipo()).isEqualTo(TipoMovimientoPuntos.CANJE);
+        assertThat(canje.getPuntos()).isEqualTo(200);
+        assertThat(canje.getMontoDescuento()).isEqualByComparingTo("200");
+
+        assertThat(acumulacion.getTipo()).isEqualTo(TipoMovimientoPuntos.ACUMULACION);
+        assertThat(acumulacion.getPuntos()).isEqualTo(8);
+        assertThat(acumulacion.getFechaVencimiento()).isEqualTo(LocalDateTime.of(2026, 3, 8, 19, 0));
+    }
+
+    @Test
+    @DisplayName("HU-105: Debe rechazar el cierre si el cliente no tiene puntos suficientes")
+    void deberia_rechazar_cierre_si_saldo_de_puntos_insuficiente() {
+        // Given: Cliente sin puntos que intenta canjear 100
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+
+        Cliente cliente = new Cliente(ClienteId.generate(), localIdValido, "Ana", null, BigDecimal.ZERO, true);
+        ProgramaPuntos programa = new ProgramaPuntos(localIdValido, true, new BigDecimal("100"), BigDecimal.ONE, null);
+
+        List<PagoRequest> pagos = List.of(new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("900")));
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(clienteRepository.buscarPorIdYLocal(cliente.getId(), localIdValido)).thenReturn(Optional.of(cliente));
+        when(programaPuntosRepository.buscarPorLocal(localIdValido)).thenReturn(Optional.of(programa));
+        when(movimientoPuntosRepository.buscarPorCliente(cliente.getId(), localIdValido)).thenReturn(List.of());
+
+        // When/Then
+        assertThatThrownBy(() -> useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, pagos)
+                .conCliente(cliente.getId())
+                .conCanje(100)))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("no alcanza para canjear");
+
+        verify(movimientoPuntosRepository, never()).guardar(any());
+        verify(pedidoRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("HU-132: Debe imputar el consumo a cuenta al empleado y avisar si supera su tope")
+    void deberia_imputar_consumo_interno_y_avisar_tope_superado() {
+        // Given: Empleada con tope de $1500 que ya consumió $800 en el mes; cierra $1000 a cuenta
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+
+        Mozo lucia = new Mozo(MozoId.generate(), localIdValido, "Lucía", true);
+        lucia.definirTopeConsumo(new BigDecimal("1500"));
+        Pedido pedidoAnterior = crearPedidoConItems(PedidoId.generate(), localIdValido, new MesaId(UUID.randomUUID()));
+        ConsumoPersonal consumoPrevio = ConsumoPersonal.registrar(lucia, pedidoAnterior, new BigDecimal("800"),
+            LocalDateTime.of(2026, 2, 3, 14, 0));
+
+        List<PagoRequest> pagos = List.of(
+            new PagoRequest(MedioPago.A_CUENTA, new BigDecimal("1000"))
+        );
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(mozoRepository.buscarPorId(lucia.getId(), localIdValido)).thenReturn(Optional.of(lucia));
+        when(consumoPersonalRepository.buscarPorEmpleado(eq(lucia.getId()), eq(localIdValido), any(), any()))
+            .thenReturn(List.of(consumoPrevio));
+        when(pedidoRepository.guardar(any(Pedido.class))).thenReturn(pedido);
+        when(mesaRepository.guardar(any(Mesa.class))).thenReturn(mesa);
+
+        // When
+        CerrarMesaResponse response = useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, pagos)
+                .conEmpleado(lucia.getId()));
+
+        // Then: Se registra el consumo y se avisa el excedente de $300
+        ArgumentCaptor<ConsumoPersonal> captor = ArgumentCaptor.forClass(ConsumoPersonal.class);
+        verify(consumoPersonalRepository).guardar(captor.capture());
+        assertThat(captor.getValue().getMozoId()).isEqualTo(lucia.getId());
+        assertThat(captor.getValue().getPedidoId()).isEqualTo(pedidoIdValido);
+        assertThat(captor.getValue().getMonto()).isEqualByComparingTo("1000");
+        assertThat(response.avisoConsumoInterno())
+            .contains("Lucía superó su tope")
+            .contains("se descuentan $300");
+        assertThat(response.pedidoEstado()).isEqualTo(EstadoPedido.CERRADO);
+    }
+
+    @Test
+    @DisplayName("HU-132: Debe rechazar imputar a un empleado un pedido sin pagos a cuenta")
+    void deberia_rechazar_imputar_consumo_sin_pagos_a_cuenta() {
+        // Given
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+        Mozo lucia = new Mozo(MozoId.generate(), localIdValido, "Lucía", true);
+
+        List<PagoRequest> pagos = List.of(
+            new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("1000"))
+        );
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(mozoRepository.buscarPorId(lucia.getId(), localIdValido)).thenReturn(Optional.of(lucia));
+
+        // When/Then
+        assertThatThrownBy(() -> useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, pagos)
+                .conEmpleado(lucia.getId())))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("cobrado a cuenta");
+
+        verify(consumoPersonalRepository, never()).guardar(any());
+        verify(pedidoRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("HU-133: Debe descontar los insumos de la receta y registrar el movimiento de venta")
+    void deberia_descontar_insumos_de_la_receta_al_cerrar() {
+        // Given: El producto lleva 0.180 kg de carne; hay 5 kg en stock
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+        ProductoId productoId = pedido.getItems().get(0).getProductoId();
+
+        Insumo carne = new Insumo(InsumoId.generate(), localIdValido, "Carne picada", UnidadInsumo.KILOGRAMO,
+            new BigDecimal("9000"), true, new BigDecimal("5"));
+        Receta receta = new Receta(productoId, localIdValido,
+            List.of(new IngredienteReceta(carne.getId(), new BigDecimal("0.180"))));
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(recetaRepository.buscarPorProducto(productoId, localIdValido)).thenReturn(Optional.of(receta));
+        when(insumoRepository.buscarPorLocal(localIdValido)).thenReturn(List.of(carne));
+        when(pedidoRepository.guardar(any(Pedido.class))).thenReturn(pedido);
+        when(mesaRepository.guardar(any(Mesa.class))).thenReturn(mesa);
+
+        // When
+        useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida,
+            List.of(new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("1000")))));
+
+        // Then
+        assertThat(carne.getStockActual()).isEqualByComparingTo("4.820");
+        verify(insumoRepository).guardar(carne);
+        ArgumentCaptor<MovimientoInsumo> captor = ArgumentCaptor.forClass(MovimientoInsumo.class);
+        verify(movimientoInsumoRepository).guardar(captor.capture());
+        assertThat(captor.getValue().getTipo()).isEqualTo(TipoMovimientoInsumo.CONSUMO_VENTA);
+        assertThat(captor.getValue().getCantidad()).isEqualByComparingTo("-0.180");
+        assertThat(captor.getValue().getMotivo()).isEqualTo("Venta - Pedido #1");
+    }
+
+    @Test
+    @DisplayName("HU-148: Sin cebolla no descuenta cebolla y el extra queso descuenta su receta")
+    void deberia_ajustar_consumo_de_insumos_por_modificadores() {
+        // Given: 2 hamburguesas "sin cebolla" con extra queso
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Insumo carne = new Insumo(InsumoId.generate(), localIdValido, "Carne picada", UnidadInsumo.KILOGRAMO,
+            new BigDecimal("9000"), true, new BigDecimal("5"));
+        Insumo cebolla = new Insumo(InsumoId.generate(), localIdValido, "Cebolla", UnidadInsumo.KILOGRAMO,
+            new BigDecimal("1200"), true, new BigDecimal("2"));
+        Insumo queso = new Insumo(InsumoId.generate(), localIdValido, "Queso cheddar", UnidadInsumo.KILOGRAMO,
+            new BigDecimal("12000"), true, new BigDecimal("3"));
+
+        Producto hamburguesa = new Producto(ProductoId.generate(), localIdValido, "Hamburguesa",
+            new BigDecimal("8000"), true, "#FF0000");
+        ProductoId extraQuesoId = ProductoId.generate();
+        Pedido pedido = new Pedido(pedidoIdValido, localIdValido, mesaIdValida, 1, EstadoPedido.ABIERTO, LocalDateTime.now());
+        ItemPedido item = ItemPedido.crearConExtras(ItemPedidoId.generate(), pedidoIdValido, hamburguesa, 2, null,
+            List.of(new ExtraPedido(extraQuesoId, "Extra queso", new BigDecimal("800"))));
+        item.registrarIngredientesQuitados(List.of(IngredienteQuitado.de(cebolla)));
+        pedido.agregarItem(item);
+
+        Receta recetaHamburguesa = new Receta(hamburguesa.getId(), localIdValido, List.of(
+            new IngredienteReceta(carne.getId(), new BigDecimal("0.180")),
+            new IngredienteReceta(cebolla.getId(), new BigDecimal("0.030")),
+            new IngredienteReceta(queso.getId(), new BigDecimal("0.040"))));
+        Receta recetaExtraQueso = new Receta(extraQuesoId, localIdValido,
+            List.of(new IngredienteReceta(queso.getId(), new BigDecimal("0.040"))));
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(recetaRepository.buscarPorProducto(hamburguesa.getId(), localIdValido)).thenReturn(Optional.of(recetaHamburguesa));
+        when(recetaRepository.buscarPorProducto(extraQuesoId, localIdValido)).thenReturn(Optional.of(recetaExtraQueso));
+        when(insumoRepository.buscarPorLocal(localIdValido)).thenReturn(List.of(carne, cebolla, queso));
+        when(pedidoRepository.guardar(any(Pedido.class))).thenReturn(pedido);
+        when(mesaRepository.guardar(any(Mesa.class))).thenReturn(mesa);
+
+        // When
+        useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida,
+            List.of(new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("17600")))));
+
+        // Then: la cebolla queda intacta y el queso se descuenta doble
+        assertThat(carne.getStockActual()).isEqualByComparingTo("4.640");
+        assertThat(cebolla.getStockActual()).isEqualByComparingTo("2");
+        assertThat(queso.getStockActual()).isEqualByComparingTo("2.840");
+        verify(insumoRepository, never()).guardar(cebolla);
+    }
+
+    @Test
+    @DisplayName("HU-143: Debe congelar el recargo de la tarjeta en el pago sin sumarlo al total")
+    void deberia_congelar_recargo_del_medio_de_pago_al_cerrar() {
+        // Given: 10% de recargo con tarjeta; el pedido es de $1000, se paga mitad y mitad
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(recargosMedioPagoRepository.buscarPorLocal(localIdValido)).thenReturn(Optional.of(
+            new RecargosMedioPago(localIdValido, Map.of(MedioPago.TARJETA, new BigDecimal("10")))));
+        when(pedidoRepository.guardar(any(Pedido.class))).thenReturn(pedido);
+        when(mesaRepository.guardar(any(Mesa.class))).thenReturn(mesa);
+
+        // When
+        CerrarMesaResponse response = useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, List.of(
+            new PagoRequest(MedioPago.TARJETA, new BigDecimal("500")),
+            new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("500"))
+        )));
+
+        // Then: el total queda en $1000 y el recargo va aparte, solo sobre la tarjeta
+        assertThat(response.montoTotal()).isEqualByComparingTo("1000");
+        assertThat(response.totalRecargos()).isEqualByComparingTo("50");
+        assertThat(pedido.getPagos().get(0).getRecargo()).isEqualByComparingTo("50");
+        assertThat(pedido.getPagos().get(1).tieneRecargo()).isFalse();
+    }
+
+    @Test
+    @DisplayName("HU-144: Debe descontar del saldo de la tarjeta de regalo lo que se paga con ella")
+    void deberia_consumir_saldo_de_tarjeta_de_regalo_al_cerrar() {
+        // Given: tarjeta de $800; el pedido es de $1000, se completa con efectivo
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+        TarjetaRegalo tarjeta = TarjetaRegalo.emitir(localIdValido, new BigDecimal("800"),
+            LocalDate.of(2026, 12, 31), null, null, null, LocalDateTime.of(2026, 1, 10, 12, 0));
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(tarjetaRegaloRepository.buscarPorCodigo(tarjeta.getCodigo(), localIdValido)).thenReturn(Optional.of(tarjeta));
+        when(pedidoRepository.guardar(any(Pedido.class))).thenReturn(pedido);
+        when(mesaRepository.guardar(any(Mesa.class))).thenReturn(mesa);
+
+        // When: el código se tipea en minúscula
+        useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, List.of(
+            new PagoRequest(MedioPago.TARJETA_REGALO, new BigDecimal("800"), tarjeta.getCodigo().toLowerCase()),
+            new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("200"))
+        )));
+
+        // Then: la tarjeta queda agotada y con el consumo atado al pedido
+        assertThat(tarjeta.getSaldo()).isEqualByComparingTo("0");
+        assertThat(tarjeta.consumidoEn(pedidoIdValido)).isEqualByComparingTo("800");
+        verify(tarjetaRegaloRepository).guardar(tarjeta);
+    }
+
+    @Test
+    @DisplayName("HU-144: Debe rechazar el pago con tarjeta de regalo sin código")
+    void deberia_rechazar_tarjeta_de_regalo_sin_codigo() {
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+
+        assertThatThrownBy(() -> useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, List.of(
+            new PagoRequest(MedioPago.TARJETA_REGALO, new BigDecimal("1000"))
+        )))).isInstanceOf(IllegalArgumentException.class);
+        verify(pedidoRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("HU-151: Debe congelar el tipo de cambio del día en el pago en dólares")
+    void deberia_congelar_tipo_de_cambio_en_pago_en_dolares() {
+        // Given: USD a $1250; el pedido es de $1000, se cubren $500 en dólares
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+        LocalDate hoy = LocalDate.of(2026, 2, 6);
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(tiposCambioRepository.buscarPorFecha(localIdValido, hoy)).thenReturn(Optional.of(
+            new TiposCambio(localIdValido, hoy, Map.of(Moneda.USD, new BigDecimal("1250")))));
+        when(pedidoRepository.guardar(any(Pedido.class))).thenReturn(pedido);
+        when(mesaRepository.guardar(any(Mesa.class))).thenReturn(mesa);
+
+        // When
+        useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, List.of(
+            new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("500"), null, Moneda.USD),
+            new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("500"))
+        )));
+
+        // Then: el pago en dólares cubre $500 del total y registra USD 0.40
+        assertThat(pedido.getPagos().get(0).getMoneda()).isEqualTo(Moneda.USD);
+        assertThat(pedido.getPagos().get(0).getMonto()).isEqualByComparingTo("500");
+        assertThat(pedido.getPagos().get(0).getMontoOriginal()).isEqualByComparingTo("0.40");
+        assertThat(pedido.getPagos().get(0).getTipoCambio()).isEqualByComparingTo("1250");
+        assertThat(pedido.getPagos().get(1).getMoneda()).isEqualTo(Moneda.ARS);
+    }
+
+    @Test
+    @DisplayName("HU-151: Debe rechazar el pago en una moneda sin tipo de cambio cargado para el día")
+    void deberia_rechazar_pago_en_moneda_sin_cotizacion() {
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(tiposCambioRepository.buscarPorFecha(any(), any())).thenReturn(Optional.empty());
+
+        assertThatThrownBy(() -> useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, List.of(
+            new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("1000"), null, Moneda.EUR)
+        )))).isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("EUR");
+        verify(pedidoRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("HU-152: Debe redondear el efectivo y dejar la diferencia como ajuste aparte del total")
+    void deberia_redondear_efectivo_con_ajuste_aparte() {
+        // Given: redondeo a $100 al más cercano; el pedido es de $1000, $660 con tarjeta y $340 en efectivo
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(redondeoEfectivoRepository.buscarPorLocal(localIdValido)).thenReturn(Optional.of(
+            new RedondeoEfectivo(localIdValido, new BigDecimal("100"), ModoRedondeo.CERCANO)));
+        when(pedidoRepository.guardar(any(Pedido.class))).thenReturn(pedido);
+        when(mesaRepository.guardar(any(Mesa.class))).thenReturn(mesa);
+
+        // When
+        CerrarMesaResponse response = useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida, List.of(
+            new PagoRequest(MedioPago.TARJETA, new BigDecimal("660")),
+            new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("340"))
+        )));
+
+        // Then: se cobran $300 en efectivo, el total sigue en $1000 y el ajuste es de −$40
+        assertThat(pedido.getMontoTotalFinal()).isEqualByComparingTo("1000");
+        assertThat(pedido.getPagos().get(0).tieneAjusteRedondeo()).isFalse();
+        assertThat(pedido.getPagos().get(1).getMonto()).isEqualByComparingTo("340");
+        assertThat(pedido.getPagos().get(1).getMontoCobrado()).isEqualByComparingTo("300");
+        assertThat(response.ajusteRedondeo()).isEqualByComparingTo("-40");
+    }
+
+    @Test
+    @DisplayName("HU-165: Debe rechazar el cierre si otra terminal modificó el pedido")
+    void deberia_rechazar_cierre_si_el_pedido_cambio_de_version() {
+        // Given: la terminal tiene una versión distinta de la del pedido
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+
+        // When/Then
+        assertThatThrownBy(() -> useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida,
+                List.of(new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("1000"))))
+                .conVersion(pedido.getVersion() + 3)))
+            .isInstanceOf(PedidoModificadoException.class);
+        verify(pedidoRepository, never()).guardar(any());
+    }
+
+    // ============================================
+    // HU-166: Numeración de comprobantes
+    // ============================================
+
+    @Test
+    @DisplayName("HU-166: El cierre numera el pedido con la serie de la caja que lo cobró")
+    void deberia_numerar_el_pedido_con_la_caja_que_cobra() {
+        // Given: cobra la terminal de la barra
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+        CajaId barraId = new CajaId(UUID.randomUUID());
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(gestionarCajasUseCase.cajaActual(localIdValido)).thenReturn(barraId);
+        doAnswer(invocation -> {
+            Pedido cerrado = invocation.getArgument(0);
+            assertThat(cerrado.getCajaId()).isEqualTo(barraId);
+            cerrado.asignarNumeroComprobante("B1-00000042");
+            return null;
+        }).when(gestionarSeriesComprobantesUseCase).numerar(any(Pedido.class), any());
+        when(pedidoRepository.guardar(any(Pedido.class))).thenReturn(pedido);
+        when(mesaRepository.guardar(any(Mesa.class))).thenReturn(mesa);
+
+        // When
+        CerrarMesaResponse response = useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida,
+            List.of(new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("1000")))));
+
+        // Then: se numera ya cerrado y asignado a su caja, con el momento del cierre
+        verify(gestionarSeriesComprobantesUseCase).numerar(pedido, LocalDateTime.of(2026, 2, 6, 19, 0));
+        assertThat(response.numeroComprobante()).isEqualTo("B1-00000042");
+    }
+
+    // ============================================
+    // HU-168: Promo de cumpleaños
+    // ============================================
+
+    @Test
+    @DisplayName("HU-168: El cliente vinculado que cierra en la semana de su cumpleaños tiene el descuento")
+    void deberia_aplicar_promo_en_la_semana_del_cumpleanios() {
+        // Given: cumple el miércoles 4/2 y cierra el viernes 6/2 (misma semana)
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+        Cliente cliente = new Cliente(ClienteId.generate(), localIdValido, "Ana", null, BigDecimal.ZERO, true,
+            LocalDate.of(1990, 2, 4), null, null);
+        pedido.vincularCliente(cliente.getId());
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(clienteRepository.buscarPorIdYLocal(cliente.getId(), localIdValido)).thenReturn(Optional.of(cliente));
+        when(promoCumpleaniosRepository.buscarPorLocal(localIdValido))
+            .thenReturn(Optional.of(new PromoCumpleanios(localIdValido, true, BigDecimal.TEN, null)));
+        when(pedidoRepository.guardar(any(Pedido.class))).thenReturn(pedido);
+        when(mesaRepository.guardar(any(Mesa.class))).thenReturn(mesa);
+
+        // When: se cobra sin indicar el cliente (vale el vinculado)
+        useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida,
+            List.of(new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("900")))));
+
+        // Then
+        assertThat(pedido.getMontoPromoCumpleanios()).isEqualByComparingTo("100");
+        assertThat(pedido.getMontoTotalFinal()).isEqualByComparingTo("900");
+    }
+
+    @Test
+    @DisplayName("HU-168: La promo se aplica una sola vez por cumpleaños")
+    void no_deberia_repetir_promo_en_la_misma_semana() {
+        // Given: el martes 3/2 ya cerró un pedido con la promo
+        Mesa mesa = new Mesa(mesaIdValida, localIdValido, 5);
+        mesa.abrir();
+        Pedido pedido = crearPedidoConItems(pedidoIdValido, localIdValido, mesaIdValida);
+        Cliente cliente = new Cliente(ClienteId.generate(), localIdValido, "Ana", null, BigDecimal.ZERO, true,
+            LocalDate.of(1990, 2, 4), null, null);
+
+        Pedido anterior = crearPedidoConItems(PedidoId.generate(), localIdValido, MesaId.generate());
+        anterior.vincularCliente(cliente.getId());
+        anterior.aplicarPromoCumpleanios(new BigDecimal("100"));
+        LocalDateTime martes = LocalDateTime.of(2026, 2, 3, 21, 0);
+        anterior.cerrar(List.of(new Pago(MedioPago.EFECTIVO, new BigDecimal("900"), martes)), martes);
+
+        when(mesaRepository.buscarPorId(mesaIdValida)).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaIdValida, localIdValido)).thenReturn(Optional.of(pedido));
+        when(promocionRepository.buscarActivasPorLocal(localIdValido)).thenReturn(Collections.emptyList());
+        when(clienteRepository.buscarPorIdYLocal(cliente.getId(), localIdValido)).thenReturn(Optional.of(cliente));
+        when(promoCumpleaniosRepository.buscarPorLocal(localIdValido))
+            .thenReturn(Optional.of(new PromoCumpleanios(localIdValido, true, BigDecimal.TEN, null)));
+        when(pedidoRepository.buscarCerradosPorCliente(cliente.getId(), localIdValido)).thenReturn(List.of(anterior));
+        when(pedidoRepository.guardar(any(Pedido.class))).thenReturn(pedido);
+        when(mesaRepository.guardar(any(Mesa.class))).thenReturn(mesa);
+
+        // When
+        useCase.ejecutar(CerrarMesaCommand.de(localIdValido, mesaIdValida,
+            List.of(new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("1000")))).conCliente(cliente.getId()));
+
+        // Then
+        assertThat(pedido.tienePromoCumpleanios()).isFalse();
+        assertThat(pedido.getMontoTotalFinal()).isEqualByComparingTo("1000");
+    }
+
     // --- Helpers ---
 
     /**
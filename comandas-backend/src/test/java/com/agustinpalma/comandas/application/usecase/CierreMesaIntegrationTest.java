@@ -1,6 +1,7 @@
 package com.agustinpalma.comandas.application.usecase;
 
 import com.agustinpalma.comandas.application.dto.AgregarProductoRequest;
+import com.agustinpalma.comandas.application.dto.CerrarMesaCommand;
 import com.agustinpalma.comandas.application.dto.CerrarMesaResponse;
 import com.agustinpalma.comandas.application.dto.PagoRequest;
 import com.agustinpalma.comandas.domain.model.*;
@@ -139,7 +140,7 @@ class CierreMesaIntegrationTest {
             );
 
             // When: Se cierra la mesa
-            CerrarMesaResponse response = cerrarMesaUseCase.ejecutar(localId, mesa.getId(), pagos);
+            CerrarMesaResponse response = cerrarMesaUseCase.ejecutar(CerrarMesaCommand.de(localId, mesa.getId(), pagos));
 
             // Then: La mesa queda LIBRE
             assertThat(response.mesaEstado()).isEqualTo(EstadoMesa.LIBRE);
@@ -184,7 +185,7 @@ class CierreMesaIntegrationTest {
             );
 
             // When
-            CerrarMesaResponse response = cerrarMesaUseCase.ejecutar(localId, mesa.getId(), pagos);
+            CerrarMesaResponse response = cerrarMesaUseCase.ejecutar(CerrarMesaCommand.de(localId, mesa.getId(), pagos));
 
             // Then
             assertThat(response.pedidoEstado()).isEqualTo(EstadoPedido.CERRADO);
@@ -214,7 +215,7 @@ class CierreMesaIntegrationTest {
             );
 
             // When
-            CerrarMesaResponse response = cerrarMesaUseCase.ejecutar(localId, mesa.getId(), pagos);
+            CerrarMesaResponse response = cerrarMesaUseCase.ejecutar(CerrarMesaCommand.de(localId, mesa.getId(), pagos));
 
             // Then: Pedido cerrado con total correcto
             assertThat(response.pedidoEstado()).isEqualTo(EstadoPedido.CERRADO);
@@ -253,7 +254,7 @@ class CierreMesaIntegrationTest {
             );
 
             // When
-            CerrarMesaResponse response = cerrarMesaUseCase.ejecutar(localId, mesa.getId(), pagos);
+            CerrarMesaResponse response = cerrarMesaUseCase.ejecutar(CerrarMesaCommand.de(localId, mesa.getId(), pagos));
 
             // Then
             assertThat(response.pedidoEstado()).isEqualTo(EstadoPedido.CERRADO);
@@ -281,7 +282,7 @@ class CierreMesaIntegrationTest {
             );
 
             // When/Then: Debe rechazar porque no cubre el total
-            assertThatThrownBy(() -> cerrarMesaUseCase.ejecutar(localId, mesa.getId(), pagos))
+            assertThatThrownBy(() -> cerrarMesaUseCase.ejecutar(CerrarMesaCommand.de(localId, mesa.getId(), pagos)))
                 .isInstanceOf(IllegalArgumentException.class)
                 .hasMessageContaining("no coincide con el total");
 
@@ -301,7 +302,7 @@ class CierreMesaIntegrationTest {
             );
 
             // When/Then
-            assertThatThrownBy(() -> cerrarMesaUseCase.ejecutar(localId, mesa.getId(), pagos))
+            assertThatThrownBy(() -> cerrarMesaUseCase.ejecutar(CerrarMesaCommand.de(localId, mesa.getId(), pagos)))
                 .isInstanceOf(IllegalArgumentException.class)
                 .hasMessageContaining("no coincide con el total");
 
@@ -323,7 +324,7 @@ class CierreMesaIntegrationTest {
             );
 
             // When/Then
-            assertThatThrownBy(() -> cerrarMesaUseCase.ejecutar(localId, mesa.getId(), pagos))
+            assertThatThrownBy(() -> cerrarMesaUseCase.ejecutar(CerrarMesaCommand.de(localId, mesa.getId(), pagos)))
                 .isInstanceOf(IllegalArgumentException.class)
                 .hasMessageContaining("no coincide con el total");
         }
@@ -342,7 +343,7 @@ class CierreMesaIntegrationTest {
             );
 
             // When/Then: El dominio debe rechazar
-            assertThatThrownBy(() -> cerrarMesaUseCase.ejecutar(localId, mesa.getId(), pagos))
+            assertThatThrownBy(() -> cerrarMesaUseCase.ejecutar(CerrarMesaCommand.de(localId, mesa.getId(), pagos)))
                 .isInstanceOf(IllegalArgumentException.class)
                 .hasMessageContaining("sin ítems");
 
@@ -0,0 +1,184 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.CartaDigitalResponse;
+import com.agustinpalma.comandas.application.dto.CartaDigitalResponse.CategoriaMenu;
+import com.agustinpalma.comandas.application.dto.CartaDigitalResponse.ProductoMenu;
+import com.agustinpalma.comandas.domain.model.Categoria;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.FranjaHoraria;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.CategoriaRepository;
+import com.agustinpalma.comandas.domain.repository.ListaPreciosRepository;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.time.LocalTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.Map;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso ConsultarCartaDigitalUseCase.
+ * Valida los criterios de la HU-127 (carta digital generada desde el catálogo).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Carta Digital - Caso de Uso")
+class ConsultarCartaDigitalUseCaseTest {
+
+    @Mock
+    private MesaRepository mesaRepository;
+
+    @Mock
+    private ProductoRepository productoRepository;
+
+    @Mock
+    private CategoriaRepository categoriaRepository;
+
+    @Mock
+    private ListaPreciosRepository listaPreciosRepository;
+
+    private ConsultarCartaDigitalUseCase useCase;
+
+    private LocalId localId;
+    private Categoria sandwiches;
+    private Producto milanesa;
+    private Producto lomito;
+
+    @BeforeEach
+    void setUp() {
+        // Sábado 21:30
+        Clock clock = Clock.fixed(Instant.parse("2026-03-14T21:30:00Z"), ZoneId.of("UTC"));
+        useCase = new ConsultarCartaDigitalUseCase(mesaRepository, productoRepository, categoriaRepository,
+            listaPreciosRepository, new MeisenProperties(), clock);
+
+        localId = new LocalId(UUID.randomUUID());
+        sandwiches = new Categoria(CategoriaId.generate(), localId, "Sándwiches", "#FFAA00", false, false, 0);
+        milanesa = new Producto(ProductoId.generate(), localId, "Milanesa", new BigDecimal("9500"), true, "#FFAA00");
+        lomito = new Producto(ProductoId.generate(), localId, "Lomito", new BigDecimal("12000"), true, "#FFAA00");
+        milanesa.actualizarCategoria(sandwiches.getId());
+        lomito.actualizarCategoria(sandwiches.getId());
+
+        when(listaPreciosRepository.buscarPorLocalYCanal(localId, CanalVenta.SALON)).thenReturn(Optional.empty());
+        when(categoriaRepository.buscarPorLocal(localId)).thenReturn(List.of(sandwiches));
+    }
+
+    @Test
+    @DisplayName("Un producto 86 sigue en la carta, marcado como no disponible")
+    void deberia_mostrar_agotado_como_no_disponible() {
+        // Given
+        lomito.marcarAgotado(true);
+        when(productoRepository.buscarPorLocal(localId)).thenReturn(List.of(milanesa, lomito));
+        when(productoRepository.buscarVersionesFotos(localId)).thenReturn(Map.of());
+
+        // When
+        CartaDigitalResponse carta = useCase.ejecutar(localId, null);
+
+        // Then
+        List<ProductoMenu> productos = carta.categorias().get(0).productos();
+        assertThat(productos).extracting(ProductoMenu::nombre).containsExactly("Lomito", "Milanesa");
+        assertThat(productos).extracting(ProductoMenu::disponible).containsExactly(false, true);
+    }
+
+    @Test
+    @DisplayName("Los extras y los productos inactivos no se publican")
+    void no_deberia_publicar_extras_ni_inactivos() {
+        // Given
+        Producto queso = new Producto(ProductoId.generate(), localId, "Queso extra", new BigDecimal("800"), true,
+            "#FFFFFF", null, true, null);
+        Producto discontinuado = new Producto(ProductoId.generate(), localId, "Choripán", new BigDecimal("5000"),
+            false, "#FFAA00");
+        when(productoRepository.buscarPorLocal(localId)).thenReturn(List.of(milanesa, queso, discontinuado));
+        when(productoRepository.buscarVersionesFotos(localId)).thenReturn(Map.of());
+
+        // When
+        CartaDigitalResponse carta = useCase.ejecutar(localId, null);
+
+        // Then
+        assertThat(carta.categorias()).flatExtracting(CategoriaMenu::productos)
+            .extracting(ProductoMenu::nombre)
+            .containsExactly("Milanesa");
+    }
+
+    @Test
+    @DisplayName("Lo que está fuera de horario no se publica; el horario propio manda sobre el de la categoría")
+    void no_deberia_publicar_productos_fuera_de_horario() {
+        // Given: desayunos de 7 a 11; el lomito se ofrece de 20 a 2 aunque su categoría no tenga horario
+        Categoria desayunos = new Categoria(CategoriaId.generate(), localId, "Desayunos", "#FFFFFF", false, false, 1);
+        desayunos.cambiarFranjaHoraria(new FranjaHoraria(LocalTime.of(7, 0), LocalTime.of(11, 0)));
+        Producto medialunas = new Producto(ProductoId.generate(), localId, "Medialunas", new BigDecimal("3000"), true, "#FFFFFF");
+        medialunas.actualizarCategoria(desayunos.getId());
+        lomito.definirFranjaHoraria(new FranjaHoraria(LocalTime.of(20, 0), LocalTime.of(2, 0)));
+        milanesa.definirFranjaHoraria(new FranjaHoraria(LocalTime.of(12, 0), LocalTime.of(15, 0)));
+        when(categoriaRepository.buscarPorLocal(localId)).thenReturn(List.of(sandwiches, desayunos));
+        when(productoRepository.buscarPorLocal(localId)).thenReturn(List.of(milanesa, lomito, medialunas));
+        when(productoRepository.buscarVersionesFotos(localId)).thenReturn(Map.of());
+
+        // When
+        CartaDigitalResponse carta = useCase.ejecutar(localId, null);
+
+        // Then
+        assertThat(carta.categorias()).extracting(CategoriaMenu::nombre).containsExactly("Sándwiches");
+        assertThat(carta.categorias()).flatExtracting(CategoriaMenu::productos)
+            .extracting(ProductoMenu::nombre)
+            .containsExactly("Lomito");
+    }
+
+    @Test
+    @DisplayName("Cada producto con foto trae su versión y la carta indica la mesa del QR")
+    void deberia_incluir_version_de_foto_y_mesa() {
+        // Given
+        Mesa mesa = new Mesa(MesaId.generate(), localId, 4);
+        LocalDateTime version = LocalDateTime.of(2026, 3, 10, 18, 0);
+        when(mesaRepository.buscarPorId(mesa.getId())).thenReturn(Optional.of(mesa));
+        when(productoRepository.buscarPorLocal(localId)).thenReturn(List.of(milanesa, lomito));
+        when(productoRepository.buscarVersionesFotos(localId)).thenReturn(Map.of(milanesa.getId(), version));
+
+        // When
+        CartaDigitalResponse carta = useCase.ejecutar(localId, mesa.getId());
+
+        // Then
+        assertThat(carta.numeroMesa()).isEqualTo(4);
+        assertThat(carta.categorias().get(0).productos())
+            .extracting(ProductoMenu::fotoActualizadaEn)
+            .containsExactly(null, version);
+    }
+
+    @Test
+    @DisplayName("Un QR de una mesa que ya no existe muestra la carta igual, sin número de mesa")
+    void deberia_mostrar_carta_con_mesa_inexistente() {
+        // Given
+        MesaId vieja = MesaId.generate();
+        when(mesaRepository.buscarPorId(vieja)).thenReturn(Optional.empty());
+        when(productoRepository.buscarPorLocal(localId)).thenReturn(List.of(milanesa));
+        when(productoRepository.buscarVersionesFotos(localId)).thenReturn(Map.of());
+
+        // When
+        CartaDigitalResponse carta = useCase.ejecutar(localId, vieja);
+
+        // Then
+        assertThat(carta.numeroMesa()).isNull();
+        assertThat(carta.categorias()).hasSize(1);
+        verify(mesaRepository).buscarPorId(vieja);
+    }
+}
@@ -0,0 +1,183 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.DashboardVentasResponse;
+import com.agustinpalma.comandas.application.dto.DashboardVentasResponse.VentaPorHora;
+import com.agustinpalma.comandas.application.dto.ProductoVendidoReporte;
+import com.agustinpalma.comandas.application.ports.output.AnalyticsRepositoryPort;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.LocalTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+import java.util.stream.IntStream;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso ConsultarDashboardVentasUseCase.
+ * Valida los criterios de la HU-114 (tablero de ventas en vivo).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Consultar Dashboard de Ventas - Caso de Uso")
+class ConsultarDashboardVentasUseCaseTest {
+
+    /** Martes 10/03 a la 01:30: todavía es la jornada del lunes 09/03 */
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 3, 10, 1, 30);
+    private static final LocalDateTime INICIO_JORNADA = LocalDateTime.of(2026, 3, 9, 6, 0);
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private MesaRepository mesaRepository;
+
+    @Mock
+    private AnalyticsRepositoryPort analyticsRepository;
+
+    @Mock
+    private JornadaComercialRepository jornadaComercialRepository;
+
+    private ConsultarDashboardVentasUseCase useCase;
+
+    private LocalId localId;
+    private Producto milanesa;
+
+    @BeforeEach
+    void setUp() {
+        ZoneId zona = ZoneId.of("America/Argentina/Buenos_Aires");
+        Clock clock = Clock.fixed(AHORA.atZone(zona).toInstant(), zona);
+        useCase = new ConsultarDashboardVentasUseCase(pedidoRepository, mesaRepository, analyticsRepository,
+            jornadaComercialRepository, clock);
+        localId = new LocalId(UUID.randomUUID());
+        milanesa = new Producto(ProductoId.generate(), localId, "Milanesa", new BigDecimal("1000"), true, "#AA5500");
+        when(mesaRepository.buscarPorLocal(localId)).thenReturn(List.of(
+            new Mesa(MesaId.generate(), localId, 1),
+            new Mesa(MesaId.generate(), localId, 2)
+        ));
+    }
+
+    private Pedido cerrado(int unidades, MedioPago medio, LocalDateTime cierre) {
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 1,
+                EstadoPedido.ABIERTO, cierre.minusHours(1));
+        pedido.agregarProducto(milanesa, unidades, null);
+        pedido.finalizar(medio, cierre);
+        return pedido;
+    }
+
+    @Test
+    @DisplayName("Debería comparar contra la semana pasada hasta la misma hora, dentro de la jornada 06 a 06")
+    void deberia_comparar_contra_la_semana_pasada_a_la_misma_hora() {
+        // Given: la jornada actual cobró 3000 en efectivo y 1000 en tarjeta (una después de medianoche)
+        when(pedidoRepository.buscarCerradosPorFecha(eq(localId), eq(INICIO_JORNADA), any()))
+            .thenReturn(List.of(
+                cerrado(3, MedioPago.EFECTIVO, INICIO_JORNADA.plusHours(15)),
+                cerrado(1, MedioPago.TARJETA, AHORA.minusMinutes(10)),
+                cerrado(2, MedioPago.A_CUENTA, INICIO_JORNADA.plusHours(15))
+            ));
+        // La semana pasada: 2000 antes de esta hora y 5000 más tarde
+        when(pedidoRepository.buscarCerradosPorFecha(eq(localId), eq(INICIO_JORNADA.minusWeeks(1)), any()))
+            .thenReturn(List.of(
+                cerrado(2, MedioPago.EFECTIVO, INICIO_JORNADA.minusWeeks(1).plusHours(14)),
+                cerrado(5, MedioPago.EFECTIVO, AHORA.minusWeeks(1).plusMinutes(30))
+            ));
+        when(analyticsRepository.obtenerVentasPorProductoEntre(any(), any(), eq(localId)))
+            .thenReturn(List.of());
+        when(pedidoRepository.buscarAbiertosPorLocal(localId)).thenReturn(List.of());
+
+        // When
+        DashboardVentasResponse tablero = useCase.ejecutar(localId);
+
+        // Then
+        assertThat(tablero.fechaOperativa()).isEqualTo(LocalDate.of(2026, 3, 9));
+        assertThat(tablero.ventaAcumulada()).isEqualByComparingTo("4000");
+        assertThat(tablero.ventaSemanaPasadaMismaHora()).isEqualByComparingTo("2000");
+        assertThat(tablero.ventaSemanaPasadaTotal()).isEqualByComparingTo("7000");
+        assertThat(tablero.variacionPorcentual()).isEqualByComparingTo("100");
+        assertThat(tablero.cantidadPedidos()).isEqualTo(2);
+        assertThat(tablero.ticketPromedio()).isEqualByComparingTo("2000");
+        assertThat(tablero.mediosPago()).extracting(m -> m.medio())
+            .containsExactly(MedioPago.EFECTIVO, MedioPago.TARJETA);
+        assertThat(tablero.mediosPago().get(0).porcentaje()).isEqualByComparingTo("75");
+
+        // Eje horario continuo desde las 20 hasta la 01 (incluye las 02 de la semana pasada)
+        assertThat(tablero.ventasPorHora()).extracting(VentaPorHora::hora)
+            .containsExactly(20, 21, 22, 23, 0, 1, 2);
+        assertThat(tablero.ventasPorHora().get(1).ventas()).isEqualByComparingTo("3000");
+    }
+
+    @Test
+    @DisplayName("Debería limitar el top a 10 productos e informar las mesas abiertas")
+    void deberia_limitar_top_e_informar_mesas_abiertas() {
+        // Given
+        when(pedidoRepository.buscarCerradosPorFecha(eq(localId), any(), any())).thenReturn(List.of());
+        List<ProductoVendidoReporte> productos = IntStream.rangeClosed(1, 12)
+            .mapToObj(i -> new ProductoVendidoReporte("Producto " + i, 1L, BigDecimal.valueOf(100 - i)))
+            .toList();
+        when(analyticsRepository.obtenerVentasPorProductoEntre(any(), any(), eq(localId))).thenReturn(productos);
+        Pedido abierto = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 7,
+                EstadoPedido.ABIERTO, AHORA.minusMinutes(40));
+        abierto.agregarProducto(milanesa, 2, null);
+        when(pedidoRepository.buscarAbiertosPorLocal(localId)).thenReturn(List.of(abierto));
+
+        // When
+        DashboardVentasResponse tablero = useCase.ejecutar(localId);
+
+        // Then
+        assertThat(tablero.topProductos()).hasSize(10);
+        assertThat(tablero.variacionPorcentual()).isNull();
+        assertThat(tablero.ventasPorHora()).isEmpty();
+        assertThat(tablero.mesasAbiertas().cantidad()).isEqualTo(1);
+        assertThat(tablero.mesasAbiertas().totalMesas()).isEqualTo(2);
+        assertThat(tablero.mesasAbiertas().montoEnCurso()).isEqualByComparingTo("2000");
+    }
+
+    @Test
+    @DisplayName("HU-153: Debería arrancar la jornada en la hora de corte configurada por el local")
+    void deberia_arrancar_la_jornada_en_la_hora_de_corte_del_local() {
+        // Given: el local corta a medianoche, así que a la 01:30 ya es el martes 10/03
+        JornadaComercial jornada = JornadaComercial.porDefecto(localId);
+        jornada.actualizar(LocalTime.MIDNIGHT);
+        when(jornadaComercialRepository.buscarPorLocal(localId)).thenReturn(Optional.of(jornada));
+        LocalDateTime inicio = LocalDateTime.of(2026, 3, 10, 0, 0);
+        when(pedidoRepository.buscarCerradosPorFecha(eq(localId), any(), any())).thenReturn(List.of());
+        when(pedidoRepository.buscarCerradosPorFecha(eq(localId), eq(inicio), eq(inicio.plusDays(1))))
+            .thenReturn(List.of(cerrado(1, MedioPago.EFECTIVO, inicio.plusMinutes(45))));
+        when(analyticsRepository.obtenerVentasPorProductoEntre(any(), any(), eq(localId))).thenReturn(List.of());
+        when(pedidoRepository.buscarAbiertosPorLocal(localId)).thenReturn(List.of());
+
+        // When
+        DashboardVentasResponse tablero = useCase.ejecutar(localId);
+
+        // Then
+        assertThat(tablero.fechaOperativa()).isEqualTo(LocalDate.of(2026, 3, 10));
+        assertThat(tablero.ventaAcumulada()).isEqualByComparingTo("1000");
+        assertThat(tablero.ventasPorHora()).extracting(VentaPorHora::hora).containsExactly(0, 1);
+    }
+}
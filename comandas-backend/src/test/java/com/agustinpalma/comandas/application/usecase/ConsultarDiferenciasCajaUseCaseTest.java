@@ -0,0 +1,160 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteDiferenciasCajaResponse;
+import com.agustinpalma.comandas.application.dto.ReporteDiferenciasCajaResponse.CajeroResumen;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoTurno;
+import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.TurnoCajaId;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.TurnoCaja;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.*;
+
+/**
+ * Tests unitarios para ConsultarDiferenciasCajaUseCase.
+ * HU-129: histórico de faltantes y sobrantes por cajero.
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("ConsultarDiferenciasCajaUseCase - Tests de comportamiento")
+class ConsultarDiferenciasCajaUseCaseTest {
+
+    @Mock
+    private TurnoCajaRepository turnoCajaRepository;
+
+    @Mock
+    private MozoRepository mozoRepository;
+
+    @Mock
+    private JornadaComercialRepository jornadaComercialRepository;
+
+    private ConsultarDiferenciasCajaUseCase useCase;
+
+    private LocalId localId;
+    private Mozo sofia;
+    private Mozo diego;
+    private final LocalDate dia = LocalDate.of(2026, 3, 2);
+
+    @BeforeEach
+    void setUp() {
+        useCase = new ConsultarDiferenciasCajaUseCase(turnoCajaRepository, mozoRepository, jornadaComercialRepository);
+
+        localId = new LocalId(UUID.randomUUID());
+        sofia = new Mozo(MozoId.generate(), localId, "Sofía", true);
+        diego = new Mozo(MozoId.generate(), localId, "Diego", true);
+        lenient().when(mozoRepository.buscarPorLocal(localId)).thenReturn(List.of(sofia, diego));
+    }
+
+    @Test
+    @DisplayName("Debe acumular faltantes y sobrantes por cajero, con el que más falta primero")
+    void deberia_agrupar_diferencias_por_cajero() {
+        // Given: Sofía falta 300 y 200 y sobra 50; Diego cierra justo y falta 100 una vez
+        when(turnoCajaRepository.buscarCerradosPorFecha(eq(localId), any(), any())).thenReturn(List.of(
+            turnoCerrado(diego, "10000", "10000", null, 9),
+            turnoCerrado(sofia, "10000", "9700", "Error de vuelto", 13),
+            turnoCerrado(diego, "8000", "7900", null, 17),
+            turnoCerrado(sofia, "5000", "5050", null, 20),
+            turnoCerrado(sofia, "6000", "5800", null, 23)
+        ));
+
+        // When
+        ReporteDiferenciasCajaResponse reporte = useCase.ejecutar(localId, dia, dia);
+
+        // Then
+        assertThat(reporte.cajeros()).extracting(CajeroResumen::nombre).containsExactly("Sofía", "Diego");
+
+        CajeroResumen filaSofia = reporte.cajeros().get(0);
+        assertThat(filaSofia.turnosCerrados()).isEqualTo(3);
+        assertThat(filaSofia.turnosConFaltante()).isEqualTo(2);
+        assertThat(filaSofia.turnosConSobrante()).isEqualTo(1);
+        assertThat(filaSofia.totalFaltante()).isEqualByComparingTo("500");
+        assertThat(filaSofia.totalSobrante()).isEqualByComparingTo("50");
+        assertThat(filaSofia.neto()).isEqualByComparingTo("-450");
+        assertThat(filaSofia.mayorFaltante()).isEqualByComparingTo("300");
+
+        CajeroResumen filaDiego = reporte.cajeros().get(1);
+        assertThat(filaDiego.turnosCerrados()).isEqualTo(2);
+        assertThat(filaDiego.totalFaltante()).isEqualByComparingTo("100");
+
+        // Solo los turnos que no cerraron van al detalle
+        assertThat(reporte.diferencias()).hasSize(4);
+        assertThat(reporte.diferencias().get(0).nombreResponsable()).isEqualTo("Sofía");
+        assertThat(reporte.diferencias().get(0).observaciones()).isEqualTo("Error de vuelto");
+        assertThat(reporte.totalFaltante()).isEqualByComparingTo("600");
+        assertThat(reporte.totalSobrante()).isEqualByComparingTo("50");
+    }
+
+    @Test
+    @DisplayName("Los turnos cerrados sin responsable van a una fila aparte al final")
+    void deberia_agrupar_turnos_sin_responsable_al_final() {
+        // Given: un turno histórico sin responsable, que cerró justo
+        when(turnoCajaRepository.buscarCerradosPorFecha(eq(localId), any(), any())).thenReturn(List.of(
+            turnoCerrado(null, "4000", "4000", null, 10),
+            turnoCerrado(diego, "4000", "3900", null, 18)
+        ));
+
+        // When
+        ReporteDiferenciasCajaResponse reporte = useCase.ejecutar(localId, dia, dia);
+
+        // Then
+        assertThat(reporte.cajeros()).extracting(CajeroResumen::nombre).containsExactly("Diego", "Sin responsable");
+        assertThat(reporte.cajeros().get(1).responsableId()).isNull();
+        assertThat(reporte.diferencias()).hasSize(1);
+    }
+
+    @Test
+    @DisplayName("Debe descartar los turnos cerrados justo a la medianoche del día siguiente")
+    void deberia_excluir_el_borde_superior_del_rango() {
+        // Given
+        TurnoCaja delDiaSiguiente = turnoCerrado(sofia, "1000", "900", null, 0);
+        when(turnoCajaRepository.buscarCerradosPorFecha(eq(localId), any(), any()))
+            .thenReturn(List.of(delDiaSiguiente));
+
+        // When: el turno cerró el 2/3 a las 00:00, fuera del rango del 1/3
+        ReporteDiferenciasCajaResponse reporte = useCase.ejecutar(localId, dia.minusDays(1), dia.minusDays(1));
+
+        // Then
+        assertThat(reporte.cajeros()).isEmpty();
+        assertThat(reporte.diferencias()).isEmpty();
+        verifyNoInteractions(mozoRepository);
+    }
+
+    @Test
+    @DisplayName("Debe rechazar un rango invertido")
+    void deberia_rechazar_rango_invertido() {
+        assertThatThrownBy(() -> useCase.ejecutar(localId, dia, dia.minusDays(1)))
+            .isInstanceOf(IllegalArgumentException.class);
+        verifyNoInteractions(turnoCajaRepository);
+    }
+
+    private TurnoCaja turnoCerrado(Mozo responsable, String esperado, String declarado, String observaciones,
+                                   int horaCierre) {
+        LocalDateTime cierre = dia.atTime(horaCierre, 0);
+        TurnoCaja turno = new TurnoCaja(TurnoCajaId.generate(), localId, JornadaCajaId.generate(),
+            TipoTurno.NOCHE, new BigDecimal(esperado), cierre.minusHours(1));
+        turno.cerrar(cierre, BigDecimal.ZERO, BigDecimal.ZERO, BigDecimal.ZERO, BigDecimal.ZERO, 0,
+            new BigDecimal(declarado));
+        turno.registrarResponsable(responsable != null ? responsable.getId() : null, observaciones);
+        return turno;
+    }
+}
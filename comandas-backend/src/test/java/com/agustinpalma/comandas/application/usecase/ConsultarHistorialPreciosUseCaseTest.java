@@ -0,0 +1,97 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.VariacionPreciosMenuResponse;
+import com.agustinpalma.comandas.domain.model.CambioPrecioProducto;
+import com.agustinpalma.comandas.domain.model.DomainEnums.OrigenCambioPrecio;
+import com.agustinpalma.comandas.domain.model.DomainIds.CambioPrecioProductoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.CambioPrecioProductoRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.time.YearMonth;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.*;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.when;
+
+/**
+ * Tests unitarios de ConsultarHistorialPreciosUseCase.
+ *
+ * HU-146: variación promedio del menú por mes a partir del historial de precios.
+ */
+@ExtendWith(MockitoExtension.class)
+class ConsultarHistorialPreciosUseCaseTest {
+
+    @Mock
+    private ProductoRepository productoRepository;
+
+    @Mock
+    private CambioPrecioProductoRepository cambioPrecioProductoRepository;
+
+    private ConsultarHistorialPreciosUseCase useCase;
+
+    private final LocalId localId = LocalId.generate();
+    private final Producto milanesa = producto("Milanesa", "8800");
+    private final Producto flan = producto("Flan", "3000");
+    private final Producto cerveza = producto("Cerveza", "4400");
+    private final Producto soda = producto("Soda", "1000");
+
+    @BeforeEach
+    void setUp() {
+        useCase = new ConsultarHistorialPreciosUseCase(productoRepository, cambioPrecioProductoRepository);
+    }
+
+    private Producto producto(String nombre, String precio) {
+        return new Producto(ProductoId.generate(), localId, nombre, new BigDecimal(precio), true, "#FF0000");
+    }
+
+    private CambioPrecioProducto cambio(Producto producto, String anterior, String nuevo, LocalDateTime fecha) {
+        return new CambioPrecioProducto(CambioPrecioProductoId.generate(), localId, producto.getId(),
+            producto.getNombre(), new BigDecimal(anterior), new BigDecimal(nuevo), fecha,
+            OrigenCambioPrecio.EDICION, null, null);
+    }
+
+    @Test
+    void deberia_promediar_la_variacion_sobre_todo_el_menu() {
+        // Marzo: milanesa 8000 -> 8400 -> 8800 (+10%), cerveza 4000 -> 4400 (+10%); flan y soda sin cambios
+        when(productoRepository.buscarPorLocal(localId)).thenReturn(List.of(milanesa, flan, cerveza, soda));
+        when(cambioPrecioProductoRepository.buscarPorLocalEntre(eq(localId), any(), any())).thenReturn(List.of(
+            cambio(milanesa, "8000", "8400", LocalDateTime.of(2026, 3, 2, 10, 0)),
+            cambio(cerveza, "4000", "4400", LocalDateTime.of(2026, 3, 9, 10, 0)),
+            cambio(milanesa, "8400", "8800", LocalDateTime.of(2026, 3, 20, 10, 0))
+        ));
+
+        VariacionPreciosMenuResponse reporte = useCase.variacionMensual(localId, YearMonth.of(2026, 2),
+            YearMonth.of(2026, 3));
+
+        assertEquals(2, reporte.meses().size());
+        var febrero = reporte.meses().get(0);
+        assertEquals(0, BigDecimal.ZERO.compareTo(febrero.variacionPromedio()));
+        assertEquals(0, febrero.cambios());
+
+        var marzo = reporte.meses().get(1);
+        assertEquals(4, marzo.productosEnMenu());
+        assertEquals(2, marzo.productosModificados());
+        assertEquals(3, marzo.cambios());
+        assertEquals(0, new BigDecimal("5.00").compareTo(marzo.variacionPromedio()));
+        assertEquals(0, new BigDecimal("10.00").compareTo(marzo.variacionPromedioModificados()));
+        assertEquals(0, new BigDecimal("5.00").compareTo(reporte.variacionAcumulada()));
+    }
+
+    @Test
+    void deberia_rechazar_rango_invertido() {
+        assertThrows(IllegalArgumentException.class,
+            () -> useCase.variacionMensual(localId, YearMonth.of(2026, 5), YearMonth.of(2026, 4)));
+    }
+}
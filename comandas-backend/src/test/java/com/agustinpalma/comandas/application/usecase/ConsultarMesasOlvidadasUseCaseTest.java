@@ -0,0 +1,112 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.MesasOlvidadasResponse;
+import com.agustinpalma.comandas.domain.model.AlertaMesasOlvidadas;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.AlertaMesasOlvidadasRepository;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso ConsultarMesasOlvidadasUseCase.
+ * Valida los criterios de la HU-113 (alerta de mesas olvidadas).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Consultar Mesas Olvidadas - Caso de Uso")
+class ConsultarMesasOlvidadasUseCaseTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 3, 10, 22, 0);
+
+    @Mock
+    private AlertaMesasOlvidadasRepository alertaRepository;
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private MesaRepository mesaRepository;
+
+    private ConsultarMesasOlvidadasUseCase useCase;
+
+    private LocalId localId;
+    private Mesa mesa1;
+    private Mesa mesa2;
+    private Mesa mesa3;
+
+    @BeforeEach
+    void setUp() {
+        ZoneId zona = ZoneId.of("America/Argentina/Buenos_Aires");
+        Clock clock = Clock.fixed(AHORA.atZone(zona).toInstant(), zona);
+        useCase = new ConsultarMesasOlvidadasUseCase(alertaRepository, pedidoRepository, mesaRepository, clock);
+        localId = new LocalId(UUID.randomUUID());
+        mesa1 = new Mesa(MesaId.generate(), localId, 1);
+        mesa2 = new Mesa(MesaId.generate(), localId, 2);
+        mesa3 = new Mesa(MesaId.generate(), localId, 3);
+    }
+
+    private Pedido pedidoAbierto(Mesa mesa, int numero, int minutosDesdeApertura) {
+        return new Pedido(PedidoId.generate(), localId, mesa.getId(), numero,
+                EstadoPedido.ABIERTO, AHORA.minusMinutes(minutosDesdeApertura));
+    }
+
+    @Test
+    @DisplayName("Debería informar solo las mesas que superan el umbral, de la más olvidada a la menos")
+    void deberia_informar_mesas_sobre_el_umbral_ordenadas() {
+        // Given
+        when(alertaRepository.buscarPorLocal(localId))
+            .thenReturn(Optional.of(new AlertaMesasOlvidadas(localId, true, 20)));
+        Pedido atendido = pedidoAbierto(mesa1, 1, 90);
+        atendido.registrarActividad(AHORA.minusMinutes(5));
+        Pedido olvidado = pedidoAbierto(mesa2, 2, 25);
+        Pedido masOlvidado = pedidoAbierto(mesa3, 3, 45);
+        when(pedidoRepository.buscarAbiertosPorLocal(localId)).thenReturn(List.of(atendido, olvidado, masOlvidado));
+        when(mesaRepository.buscarPorLocal(localId)).thenReturn(List.of(mesa1, mesa2, mesa3));
+
+        // When
+        MesasOlvidadasResponse respuesta = useCase.ejecutar(localId);
+
+        // Then
+        assertThat(respuesta.alertaActiva()).isTrue();
+        assertThat(respuesta.mesas())
+            .extracting(MesasOlvidadasResponse.MesaOlvidada::numeroMesa)
+            .containsExactly(3, 2);
+        assertThat(respuesta.mesas().get(0).minutosSinActividad()).isEqualTo(45);
+    }
+
+    @Test
+    @DisplayName("Con la alerta desactivada no debería informar mesas")
+    void deberia_devolver_vacio_si_la_alerta_esta_inactiva() {
+        // Given
+        when(alertaRepository.buscarPorLocal(localId))
+            .thenReturn(Optional.of(new AlertaMesasOlvidadas(localId, false, 20)));
+
+        // When
+        MesasOlvidadasResponse respuesta = useCase.ejecutar(localId);
+
+        // Then
+        assertThat(respuesta.alertaActiva()).isFalse();
+        assertThat(respuesta.mesas()).isEmpty();
+        verifyNoInteractions(pedidoRepository, mesaRepository);
+    }
+}
@@ -0,0 +1,86 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ProductoParaReponerResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.*;
+import static org.mockito.Mockito.*;
+
+/**
+ * Tests unitarios del caso de uso ConsultarProductosParaReponerUseCase.
+ *
+ * Escenarios probados (HU-101):
+ * - Solo se listan productos con control de stock y umbral alcanzado
+ * - El listado se ordena por cantidad sugerida descendente
+ */
+@ExtendWith(MockitoExtension.class)
+class ConsultarProductosParaReponerUseCaseTest {
+
+    @Mock
+    private ProductoRepository productoRepository;
+
+    private ConsultarProductosParaReponerUseCase useCase;
+
+    private LocalId localId;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new ConsultarProductosParaReponerUseCase(productoRepository);
+        localId = LocalId.generate();
+    }
+
+    @Test
+    void deberia_listar_solo_productos_bajo_stock_minimo_ordenados_por_urgencia() {
+        // Given
+        Producto cerveza = crearProducto("Cerveza", 2, 10);     // faltan 9
+        Producto gaseosa = crearProducto("Gaseosa", 4, 5);      // faltan 2
+        Producto vino = crearProducto("Vino", 20, 5);           // sobre el umbral
+        Producto agua = crearProducto("Agua", 0, null);         // sin umbral
+
+        when(productoRepository.buscarPorLocal(localId))
+            .thenReturn(List.of(gaseosa, vino, cerveza, agua));
+
+        // When
+        List<ProductoParaReponerResponse> resultado = useCase.ejecutar(localId);
+
+        // Then
+        assertEquals(2, resultado.size());
+        assertEquals("Cerveza", resultado.get(0).nombre());
+        assertEquals(9, resultado.get(0).cantidadSugerida());
+        assertEquals("Gaseosa", resultado.get(1).nombre());
+        assertEquals(2, resultado.get(1).cantidadSugerida());
+    }
+
+    @Test
+    void deberia_retornar_lista_vacia_si_no_hay_faltantes() {
+        // Given
+        when(productoRepository.buscarPorLocal(localId))
+            .thenReturn(List.of(crearProducto("Vino", 20, 5)));
+
+        // When
+        List<ProductoParaReponerResponse> resultado = useCase.ejecutar(localId);
+
+        // Then
+        assertTrue(resultado.isEmpty());
+    }
+
+    private Producto crearProducto(String nombre, int stock, Integer stockMinimo) {
+        Producto producto = new Producto(
+            ProductoId.generate(), localId, nombre, new BigDecimal("1000"), true, "#FFFFFF",
+            null, false, false, null, null, true, true, stock, true
+        );
+        producto.definirStockMinimo(stockMinimo);
+        return producto;
+    }
+}
@@ -11,6 +11,7 @@ import com.agustinpalma.comandas.domain.model.EstrategiaPromocion;
 import com.agustinpalma.comandas.domain.model.ItemPromocion;
 import com.agustinpalma.comandas.domain.model.Producto;
 import com.agustinpalma.comandas.domain.model.Promocion;
+import com.agustinpalma.comandas.domain.repository.CategoriaRepository;
 import com.agustinpalma.comandas.domain.repository.ProductoRepository;
 import com.agustinpalma.comandas.domain.repository.PromocionRepository;
 import org.junit.jupiter.api.BeforeEach;
@@ -20,6 +21,9 @@ import org.mockito.Mock;
 import org.mockito.junit.jupiter.MockitoExtension;
 
 import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.Instant;
+import java.time.ZoneId;
 import java.util.List;
 import java.util.UUID;
 
@@ -46,13 +50,17 @@ class ConsultarProductosUseCaseTest {
     @Mock
     private PromocionRepository promocionRepository;
 
+    @Mock
+    private CategoriaRepository categoriaRepository;
+
     private ConsultarProductosUseCase useCase;
 
     private LocalId localId;
 
     @BeforeEach
     void setUp() {
-        useCase = new ConsultarProductosUseCase(productoRepository, promocionRepository);
+        Clock clock = Clock.fixed(Instant.parse("2026-03-14T21:30:00Z"), ZoneId.of("UTC"));
+        useCase = new ConsultarProductosUseCase(productoRepository, promocionRepository, categoriaRepository, clock);
         localId = LocalId.generate();
         // Por defecto, no hay promociones activas (los tests de producto no necesitan promos)
         lenient().when(promocionRepository.buscarActivasPorLocal(any()))
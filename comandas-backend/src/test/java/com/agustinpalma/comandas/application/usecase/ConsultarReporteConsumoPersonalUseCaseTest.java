@@ -0,0 +1,141 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteConsumoPersonalResponse;
+import com.agustinpalma.comandas.application.dto.ReporteConsumoPersonalResponse.ConsumoEmpleado;
+import com.agustinpalma.comandas.domain.model.ConsumoPersonal;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.ConsumoPersonalRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.time.LocalTime;
+import java.time.YearMonth;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.when;
+
+/**
+ * Test unitario del caso de uso ConsultarReporteConsumoPersonalUseCase.
+ * Valida los criterios de la HU-132 (Límites de consumo interno del personal).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Reporte de Consumo Interno - Caso de Uso")
+class ConsultarReporteConsumoPersonalUseCaseTest {
+
+    private static final YearMonth MARZO = YearMonth.of(2026, 3);
+
+    @Mock
+    private ConsumoPersonalRepository consumoPersonalRepository;
+
+    @Mock
+    private MozoRepository mozoRepository;
+
+    @Mock
+    private JornadaComercialRepository jornadaComercialRepository;
+
+    private ConsultarReporteConsumoPersonalUseCase useCase;
+
+    private LocalId localId;
+    private Mozo lucia;
+    private Mozo martin;
+    private Mozo diego;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new ConsultarReporteConsumoPersonalUseCase(consumoPersonalRepository, mozoRepository,
+            jornadaComercialRepository);
+        localId = new LocalId(UUID.randomUUID());
+        lucia = new Mozo(MozoId.generate(), localId, "Lucía", true);
+        lucia.definirTopeConsumo(new BigDecimal("20000"));
+        martin = new Mozo(MozoId.generate(), localId, "Martín", true);
+        martin.definirTopeConsumo(new BigDecimal("15000"));
+        diego = new Mozo(MozoId.generate(), localId, "Diego", true);
+    }
+
+    private ConsumoPersonal consumo(Mozo empleado, String monto, int dia) {
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, new MesaId(UUID.randomUUID()), dia,
+            EstadoPedido.CERRADO, LocalDateTime.of(2026, 3, dia, 13, 0));
+        return ConsumoPersonal.registrar(empleado, pedido, new BigDecimal(monto), LocalDateTime.of(2026, 3, dia, 14, 0));
+    }
+
+    @Test
+    @DisplayName("Descuenta del sueldo solo lo que excede el tope y muestra el disponible de cada empleado")
+    void deberia_calcular_excedente_y_disponible_por_empleado() {
+        // Given: Lucía consumió $24.500 (excede $4.500); Martín tiene tope y no consumió
+        when(consumoPersonalRepository.buscarPorLocal(eq(localId), any(), any())).thenReturn(List.of(
+            consumo(lucia, "12000", 5), consumo(lucia, "12500", 19)));
+        when(mozoRepository.buscarPorLocal(localId)).thenReturn(List.of(martin, lucia, diego));
+
+        // When
+        ReporteConsumoPersonalResponse reporte = useCase.ejecutar(localId, MARZO);
+
+        // Then: Diego no tiene tope ni consumos, no aparece
+        assertThat(reporte.empleados()).extracting(ConsumoEmpleado::nombre).containsExactly("Lucía", "Martín");
+        ConsumoEmpleado filaLucia = reporte.empleados().get(0);
+        assertThat(filaLucia.consumos()).isEqualTo(2);
+        assertThat(filaLucia.consumido()).isEqualByComparingTo("24500");
+        assertThat(filaLucia.excedente()).isEqualByComparingTo("4500");
+        assertThat(filaLucia.disponible()).isEqualByComparingTo("0");
+        assertThat(reporte.empleados().get(1).disponible()).isEqualByComparingTo("15000");
+        assertThat(reporte.totalADescontar()).isEqualByComparingTo("4500");
+    }
+
+    @Test
+    @DisplayName("Sin tope, todo el consumo es beneficio: no se descuenta nada")
+    void deberia_no_descontar_nada_a_un_empleado_sin_tope() {
+        // Given
+        when(consumoPersonalRepository.buscarPorLocal(eq(localId), any(), any())).thenReturn(List.of(
+            consumo(diego, "30000", 10)));
+        when(mozoRepository.buscarPorLocal(localId)).thenReturn(List.of(diego));
+
+        // When
+        ReporteConsumoPersonalResponse reporte = useCase.ejecutar(localId, MARZO);
+
+        // Then
+        assertThat(reporte.empleados()).singleElement().satisfies(fila -> {
+            assertThat(fila.topeMensual()).isNull();
+            assertThat(fila.excedente()).isEqualByComparingTo("0");
+            assertThat(fila.disponible()).isNull();
+        });
+        assertThat(reporte.totalConsumido()).isEqualByComparingTo("30000");
+        assertThat(reporte.totalADescontar()).isEqualByComparingTo("0");
+    }
+
+    @Test
+    @DisplayName("El mes va de la primera jornada del mes a la primera del siguiente (HU-153)")
+    void deberia_liquidar_el_mes_por_jornada_comercial() {
+        // Given: el local corta la jornada a las 04:00
+        when(jornadaComercialRepository.buscarPorLocal(localId))
+            .thenReturn(Optional.of(new JornadaComercial(localId, LocalTime.of(4, 0))));
+        when(consumoPersonalRepository.buscarPorLocal(localId,
+                LocalDateTime.of(2026, 3, 1, 4, 0), LocalDateTime.of(2026, 4, 1, 4, 0)))
+            .thenReturn(List.of(consumo(diego, "1000", 31)));
+        when(mozoRepository.buscarPorLocal(localId)).thenReturn(List.of(diego));
+
+        // When
+        ReporteConsumoPersonalResponse reporte = useCase.ejecutar(localId, MARZO);
+
+        // Then
+        assertThat(reporte.totalConsumido()).isEqualByComparingTo("1000");
+    }
+}
@@ -0,0 +1,145 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteEgresosMensualResponse;
+import com.agustinpalma.comandas.application.dto.ReporteEgresosMensualResponse.CategoriaMes;
+import com.agustinpalma.comandas.domain.model.CategoriaEgreso;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimiento;
+import com.agustinpalma.comandas.domain.model.DomainIds.CategoriaEgresoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.repository.CategoriaEgresoRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.time.YearMonth;
+import java.util.List;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.*;
+
+/**
+ * Tests unitarios para ConsultarReporteEgresosUseCase.
+ * HU-130: egresos del mes por categoría contra el mes anterior.
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("ConsultarReporteEgresosUseCase - Tests de comportamiento")
+class ConsultarReporteEgresosUseCaseTest {
+
+    @Mock
+    private MovimientoCajaRepository movimientoCajaRepository;
+
+    @Mock
+    private CategoriaEgresoRepository categoriaEgresoRepository;
+
+    private ConsultarReporteEgresosUseCase useCase;
+
+    private LocalId localId;
+    private CategoriaEgreso limpieza;
+    private CategoriaEgreso mantenimiento;
+    private final YearMonth marzo = YearMonth.of(2026, 3);
+
+    @BeforeEach
+    void setUp() {
+        useCase = new ConsultarReporteEgresosUseCase(movimientoCajaRepository, categoriaEgresoRepository);
+
+        localId = new LocalId(UUID.randomUUID());
+        limpieza = CategoriaEgreso.crear(localId, "Limpieza");
+        mantenimiento = CategoriaEgreso.crear(localId, "Mantenimiento");
+        lenient().when(categoriaEgresoRepository.buscarPorLocal(localId)).thenReturn(List.of(limpieza, mantenimiento));
+    }
+
+    @Test
+    @DisplayName("Debe comparar cada categoría contra el mes anterior, con la de mayor gasto primero")
+    void deberia_comparar_por_categoria_contra_mes_anterior() {
+        // Given: en marzo limpieza 3000 (dos egresos) y mantenimiento 12000; en febrero limpieza 2000
+        YearMonth febrero = marzo.minusMonths(1);
+        egresosDelMes(marzo,
+            egreso(marzo, limpieza, "1000"), egreso(marzo, limpieza, "2000"), egreso(marzo, mantenimiento, "12000"));
+        egresosDelMes(febrero, egreso(febrero, limpieza, "2000"));
+
+        // When
+        ReporteEgresosMensualResponse reporte = useCase.ejecutar(localId, marzo);
+
+        // Then
+        assertThat(reporte.categorias()).extracting(CategoriaMes::nombre).containsExactly("Mantenimiento", "Limpieza");
+
+        CategoriaMes filaLimpieza = reporte.categorias().get(1);
+        assertThat(filaLimpieza.cantidad()).isEqualTo(2);
+        assertThat(filaLimpieza.total()).isEqualByComparingTo("3000");
+        assertThat(filaLimpieza.totalAnterior()).isEqualByComparingTo("2000");
+        assertThat(filaLimpieza.variacion()).isEqualByComparingTo("1000");
+        assertThat(filaLimpieza.variacionPorcentual()).isEqualByComparingTo("50");
+
+        // Mantenimiento no tuvo egresos en febrero: no hay porcentaje contra cero
+        assertThat(reporte.categorias().get(0).variacionPorcentual()).isNull();
+        assertThat(reporte.total()).isEqualByComparingTo("15000");
+        assertThat(reporte.totalAnterior()).isEqualByComparingTo("2000");
+        assertThat(reporte.periodoAnterior()).isEqualTo(YearMonth.of(2026, 2));
+    }
+
+    @Test
+    @DisplayName("Una categoría que solo tuvo egresos el mes anterior aparece con total cero")
+    void deberia_mostrar_categoria_sin_egresos_en_el_mes() {
+        // Given
+        YearMonth febrero = marzo.minusMonths(1);
+        egresosDelMes(marzo, egreso(marzo, limpieza, "500"));
+        egresosDelMes(febrero, egreso(febrero, mantenimiento, "4000"));
+
+        // When
+        ReporteEgresosMensualResponse reporte = useCase.ejecutar(localId, marzo);
+
+        // Then
+        CategoriaMes filaMantenimiento = reporte.categorias().stream()
+            .filter(c -> c.nombre().equals("Mantenimiento")).findFirst().orElseThrow();
+        assertThat(filaMantenimiento.total()).isEqualByComparingTo("0");
+        assertThat(filaMantenimiento.variacionPorcentual()).isEqualByComparingTo("-100");
+    }
+
+    @Test
+    @DisplayName("Los egresos sin categoría van al final y las devoluciones e ingresos no cuentan")
+    void deberia_agrupar_sin_categoria_al_final_y_excluir_devoluciones() {
+        // Given
+        MovimientoCaja devolucion = MovimientoCaja.devolucion(localId, PedidoId.generate(), new BigDecimal("9000"),
+            "Plato devuelto", marzo.atDay(10).atTime(21, 0), null, MozoId.generate());
+        MovimientoCaja ingreso = new MovimientoCaja(MovimientoCajaId.generate(), localId, new BigDecimal("5000"),
+            "Efectivo PedidosYa", marzo.atDay(10).atTime(22, 0), TipoMovimiento.INGRESO);
+        egresosDelMes(marzo, egreso(marzo, null, "700"), egreso(marzo, limpieza, "300"), devolucion, ingreso);
+        egresosDelMes(marzo.minusMonths(1));
+
+        // When
+        ReporteEgresosMensualResponse reporte = useCase.ejecutar(localId, marzo);
+
+        // Then
+        assertThat(reporte.categorias()).extracting(CategoriaMes::nombre)
+            .containsExactly("Limpieza", ConsultarReporteEgresosUseCase.SIN_CATEGORIA);
+        assertThat(reporte.categorias().get(1).categoriaId()).isNull();
+        assertThat(reporte.total()).isEqualByComparingTo("1000");
+    }
+
+    // ─── Helpers ──────────────────────────────────────────────────────────────
+
+    private void egresosDelMes(YearMonth mes, MovimientoCaja... movimientos) {
+        when(movimientoCajaRepository.buscarPorFecha(eq(localId), eq(mes.atDay(1).atStartOfDay()), any()))
+            .thenReturn(List.of(movimientos));
+    }
+
+    private MovimientoCaja egreso(YearMonth mes, CategoriaEgreso categoria, String monto) {
+        LocalDateTime fecha = mes.atDay(10).atTime(12, 0);
+        CategoriaEgresoId categoriaId = categoria != null ? categoria.getId() : null;
+        return new MovimientoCaja(MovimientoCajaId.generate(), localId, new BigDecimal(monto), "Egreso",
+            fecha, TipoMovimiento.EGRESO, null, categoriaId);
+    }
+}
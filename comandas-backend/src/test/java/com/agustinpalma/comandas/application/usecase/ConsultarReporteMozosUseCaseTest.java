@@ -0,0 +1,184 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteMozosResponse;
+import com.agustinpalma.comandas.application.dto.ReporteMozosResponse.MozoResumen;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.JornadaComercial;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.*;
+
+/**
+ * Tests unitarios para ConsultarReporteMozosUseCase.
+ * HU-111: desempeño por mozo para liquidar incentivos.
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("ConsultarReporteMozosUseCase - Tests de comportamiento")
+class ConsultarReporteMozosUseCaseTest {
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private MozoRepository mozoRepository;
+
+    @Mock
+    private JornadaComercialRepository jornadaComercialRepository;
+
+    private ConsultarReporteMozosUseCase useCase;
+
+    private LocalId localId;
+    private Producto milanesa;
+    private Mozo lucia;
+    private Mozo martin;
+    private final LocalDate dia = LocalDate.of(2026, 3, 2);
+    private int numero = 1;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new ConsultarReporteMozosUseCase(pedidoRepository, mozoRepository, jornadaComercialRepository);
+
+        localId = new LocalId(UUID.randomUUID());
+        milanesa = new Producto(ProductoId.generate(), localId, "Milanesa", new BigDecimal("5000"), true, "#AA5500");
+        lucia = new Mozo(MozoId.generate(), localId, "Lucía", true);
+        martin = new Mozo(MozoId.generate(), localId, "Martín", false);
+        lenient().when(mozoRepository.buscarPorLocal(localId)).thenReturn(List.of(lucia, martin));
+    }
+
+    @Test
+    @DisplayName("Debe sumar ventas, cubiertos y propinas por mozo, con el sin asignar al final")
+    void deberia_agrupar_por_mozo() {
+        // Given: Lucía atiende dos mesas, Martín una, y una mesa quedó sin mozo
+        when(pedidoRepository.buscarCerradosPorFecha(eq(localId), any(), any())).thenReturn(List.of(
+            pedidoCerrado(lucia.getId(), 2, 1, "500", 12),
+            pedidoCerrado(lucia.getId(), 4, 3, "1500", 14),
+            pedidoCerrado(martin.getId(), 0, 5, null, 21),
+            pedidoCerrado(null, 2, 1, null, 22)
+        ));
+
+        // When
+        ReporteMozosResponse reporte = useCase.ejecutar(localId, dia, dia);
+
+        // Then
+        assertThat(reporte.mozos()).extracting(MozoResumen::nombre)
+            .containsExactly("Martín", "Lucía", "Sin asignar");
+
+        MozoResumen resumenLucia = reporte.mozos().get(1);
+        assertThat(resumenLucia.cantidadPedidos()).isEqualTo(2);
+        assertThat(resumenLucia.ventas()).isEqualByComparingTo("20000");
+        assertThat(resumenLucia.cubiertos()).isEqualTo(6);
+        assertThat(resumenLucia.ticketPromedio()).isEqualByComparingTo("10000");
+        assertThat(resumenLucia.promedioPorCubierto()).isEqualByComparingTo("3333.33");
+        assertThat(resumenLucia.propinas()).isEqualByComparingTo("2000");
+
+        // Martín no informó cubiertos: el promedio por cubierto no se puede calcular
+        MozoResumen resumenMartin = reporte.mozos().get(0);
+        assertThat(resumenMartin.promedioPorCubierto()).isEqualByComparingTo("0");
+        assertThat(resumenMartin.mozoId()).isEqualTo(martin.getId().getValue());
+
+        assertThat(reporte.mozos().get(2).mozoId()).isNull();
+        assertThat(reporte.totalVentas()).isEqualByComparingTo("50000");
+        assertThat(reporte.totalPropinas()).isEqualByComparingTo("2000");
+    }
+
+    @Test
+    @DisplayName("Debe informar las unidades anuladas después de enviarse a cocina")
+    void deberia_informar_anulaciones() {
+        // Given: tres milanesas enviadas a cocina, después se bajan a una
+        Pedido pedido = nuevoPedido(lucia.getId(), 3);
+        pedido.agregarProducto(milanesa, 3, null);
+        pedido.marcarComoEnviadoACocina(dia.atTime(20, 0));
+        pedido.actualizarCantidadItem(pedido.getItems().get(0).getId(), 1);
+        cerrar(pedido, 21);
+        when(pedidoRepository.buscarCerradosPorFecha(eq(localId), any(), any())).thenReturn(List.of(pedido));
+
+        // When
+        ReporteMozosResponse reporte = useCase.ejecutar(localId, dia, dia);
+
+        // Then
+        MozoResumen resumen = reporte.mozos().get(0);
+        assertThat(resumen.unidadesAnuladas()).isEqualTo(2);
+        assertThat(resumen.montoAnulado()).isEqualByComparingTo("10000");
+        assertThat(resumen.ventas()).isEqualByComparingTo("5000");
+    }
+
+    @Test
+    @DisplayName("Debe descartar pedidos cerrados justo en el borde superior del rango")
+    void deberia_descartar_borde_superior() {
+        // Given: el rango va de corte a corte de la jornada comercial (HU-153, 06:00 por defecto)
+        LocalDateTime corte = dia.plusDays(1).atTime(JornadaComercial.HORA_CORTE_POR_DEFECTO);
+        Pedido delDiaSiguiente = nuevoPedido(lucia.getId(), 2);
+        delDiaSiguiente.agregarProducto(milanesa, 1, null);
+        delDiaSiguiente.cerrar(
+            List.of(new Pago(MedioPago.EFECTIVO, new BigDecimal("5000"), corte)),
+            corte
+        );
+        when(pedidoRepository.buscarCerradosPorFecha(localId, dia.atTime(JornadaComercial.HORA_CORTE_POR_DEFECTO), corte))
+            .thenReturn(List.of(delDiaSiguiente));
+
+        // When
+        ReporteMozosResponse reporte = useCase.ejecutar(localId, dia, dia);
+
+        // Then
+        assertThat(reporte.mozos()).isEmpty();
+        assertThat(reporte.totalVentas()).isEqualByComparingTo("0");
+        verifyNoInteractions(mozoRepository);
+    }
+
+    @Test
+    @DisplayName("Debe rechazar un rango invertido")
+    void deberia_rechazar_rango_invertido() {
+        assertThatThrownBy(() -> useCase.ejecutar(localId, dia, dia.minusDays(1)))
+            .isInstanceOf(IllegalArgumentException.class);
+    }
+
+    private Pedido nuevoPedido(MozoId mozoId, int cubiertos) {
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), numero++,
+            EstadoPedido.ABIERTO, dia.atTime(11, 0));
+        pedido.asignarAtencion(mozoId, cubiertos);
+        return pedido;
+    }
+
+    private Pedido pedidoCerrado(MozoId mozoId, int cubiertos, int milanesas, String propina, int hora) {
+        Pedido pedido = nuevoPedido(mozoId, cubiertos);
+        pedido.agregarProducto(milanesa, milanesas, null);
+        cerrar(pedido, hora);
+        if (propina != null) {
+            pedido.registrarPropina(new BigDecimal(propina));
+        }
+        return pedido;
+    }
+
+    private void cerrar(Pedido pedido, int hora) {
+        LocalDateTime cierre = dia.atTime(hora, 0);
+        pedido.cerrar(List.of(new Pago(MedioPago.EFECTIVO, pedido.calcularTotal(), cierre)), cierre);
+    }
+}
@@ -0,0 +1,134 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteSucursalesResponse;
+import com.agustinpalma.comandas.application.dto.ReporteSucursalesResponse.SucursalResumen;
+import com.agustinpalma.comandas.application.ports.output.IdentidadSucursalProvider;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.SucursalId;
+import com.agustinpalma.comandas.domain.model.VentaSucursal;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.VentaSucursalRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.*;
+
+/**
+ * Tests unitarios para ConsultarReporteSucursalesUseCase.
+ * HU-107: comparativo por sucursal sin mezclar cajas.
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("ConsultarReporteSucursalesUseCase - Tests de comportamiento")
+class ConsultarReporteSucursalesUseCaseTest {
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private VentaSucursalRepository ventaSucursalRepository;
+
+    @Mock
+    private IdentidadSucursalProvider identidadSucursalProvider;
+
+    private ConsultarReporteSucursalesUseCase useCase;
+
+    private LocalId localId;
+    private SucursalId centro;
+    private final LocalDate dia = LocalDate.of(2026, 2, 6);
+
+    @BeforeEach
+    void setUp() {
+        useCase = new ConsultarReporteSucursalesUseCase(
+            pedidoRepository, ventaSucursalRepository, identidadSucursalProvider
+        );
+
+        localId = new LocalId(UUID.randomUUID());
+        centro = SucursalId.generate();
+        lenient().when(identidadSucursalProvider.getSucursalId()).thenReturn(centro);
+        lenient().when(identidadSucursalProvider.getNombreSucursal()).thenReturn("Centro");
+    }
+
+    @Test
+    @DisplayName("Debe reportar cada sucursal por separado, la propia primero")
+    void deberia_reportar_una_fila_por_sucursal() {
+        // Given: la propia sin ventas; Norte con dos ventas, Sur con una
+        SucursalId norte = SucursalId.generate();
+        SucursalId sur = SucursalId.generate();
+        when(pedidoRepository.buscarCerradosPorFecha(eq(localId), any(), any())).thenReturn(List.of());
+        when(ventaSucursalRepository.buscarPorFechaCierre(localId, dia.atStartOfDay(), dia.plusDays(1).atStartOfDay()))
+            .thenReturn(List.of(
+                venta(norte, "Norte", 13, Map.of(MedioPago.EFECTIVO, new BigDecimal("3000"))),
+                venta(sur, "Sur", 14, Map.of(MedioPago.QR, new BigDecimal("1000"))),
+                venta(norte, "Norte", 15, Map.of(
+                    MedioPago.TARJETA, new BigDecimal("1000"),
+                    MedioPago.A_CUENTA, new BigDecimal("800")
+                ))
+            ));
+
+        // When
+        ReporteSucursalesResponse reporte = useCase.ejecutar(localId, dia, dia);
+
+        // Then
+        assertThat(reporte.sucursales()).extracting(SucursalResumen::nombre)
+            .containsExactly("Centro", "Norte", "Sur");
+
+        SucursalResumen propia = reporte.sucursales().get(0);
+        assertThat(propia.esEstaInstalacion()).isTrue();
+        assertThat(propia.cantidadPedidos()).isZero();
+        assertThat(propia.totalVentas()).isEqualByComparingTo("0");
+
+        SucursalResumen resumenNorte = reporte.sucursales().get(1);
+        assertThat(resumenNorte.esEstaInstalacion()).isFalse();
+        assertThat(resumenNorte.cantidadPedidos()).isEqualTo(2);
+        assertThat(resumenNorte.totalVentas()).isEqualByComparingTo("4000");
+        assertThat(resumenNorte.ticketPromedio()).isEqualByComparingTo("2000");
+        assertThat(resumenNorte.porMedioPago().get(MedioPago.A_CUENTA)).isEqualByComparingTo("800");
+        assertThat(resumenNorte.ultimaVenta()).isEqualTo(dia.atTime(15, 0));
+
+        assertThat(reporte.totalVentas()).isEqualByComparingTo("5000");
+    }
+
+    @Test
+    @DisplayName("Debe ignorar ventas consolidadas que dicen venir de esta misma sucursal")
+    void deberia_ignorar_ventas_recibidas_con_la_identidad_propia() {
+        // Given: una venta "propia" que llegó por sync (se cuenta en vivo, no del consolidado)
+        when(pedidoRepository.buscarCerradosPorFecha(eq(localId), any(), any())).thenReturn(List.of());
+        when(ventaSucursalRepository.buscarPorFechaCierre(eq(localId), any(), any()))
+            .thenReturn(List.of(venta(centro, "Centro", 12, Map.of(MedioPago.EFECTIVO, new BigDecimal("999")))));
+
+        // When
+        ReporteSucursalesResponse reporte = useCase.ejecutar(localId, dia, dia);
+
+        // Then
+        assertThat(reporte.sucursales()).hasSize(1);
+        assertThat(reporte.totalVentas()).isEqualByComparingTo("0");
+    }
+
+    @Test
+    @DisplayName("Debe rechazar un rango invertido")
+    void deberia_rechazar_rango_invertido() {
+        assertThatThrownBy(() -> useCase.ejecutar(localId, dia, dia.minusDays(1)))
+            .isInstanceOf(IllegalArgumentException.class);
+    }
+
+    private VentaSucursal venta(SucursalId sucursal, String nombre, int hora, Map<MedioPago, BigDecimal> pagos) {
+        LocalDateTime cierre = dia.atTime(hora, 0);
+        return new VentaSucursal(UUID.randomUUID(), localId, sucursal, nombre, hora, cierre, pagos, cierre.plusMinutes(5));
+    }
+}
@@ -0,0 +1,90 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteTiemposCocinaResponse;
+import com.agustinpalma.comandas.application.dto.ReporteTiemposCocinaResponse.FranjaHoraria;
+import com.agustinpalma.comandas.domain.model.ComandaCocina;
+import com.agustinpalma.comandas.domain.model.DomainIds.ComandaCocinaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.ItemComandaCocina;
+import com.agustinpalma.comandas.domain.repository.ComandaCocinaRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso ConsultarReporteTiemposCocinaUseCase.
+ * Valida los criterios de la HU-119 (Tiempos de cocina y alertas de demora).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Reporte de Tiempos de Cocina - Caso de Uso")
+class ConsultarReporteTiemposCocinaUseCaseTest {
+
+    private static final LocalDate DIA = LocalDate.of(2026, 10, 9);
+
+    @Mock
+    private ComandaCocinaRepository comandaCocinaRepository;
+
+    @Mock
+    private JornadaComercialRepository jornadaComercialRepository;
+
+    private ConsultarReporteTiemposCocinaUseCase useCase;
+
+    private LocalId localId;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new ConsultarReporteTiemposCocinaUseCase(comandaCocinaRepository, jornadaComercialRepository);
+        localId = new LocalId(UUID.randomUUID());
+    }
+
+    private ComandaCocina lista(int hora, int minuto, int minutosEnCocina) {
+        LocalDateTime envio = DIA.atTime(hora, minuto);
+        return new ComandaCocina(ComandaCocinaId.generate(), localId, PedidoId.generate(), 1, 1, envio, 15,
+                List.of(new ItemComandaCocina("Milanesa napolitana", 1, null)), envio.plusMinutes(minutosEnCocina));
+    }
+
+    @Test
+    @DisplayName("Agrupa por hora de envío con promedio, máximo y demoradas de cada franja")
+    void deberia_agrupar_por_franja_horaria() {
+        when(comandaCocinaRepository.buscarListasEnviadasEntre(
+                localId, DIA.atTime(6, 0), DIA.plusDays(1).atTime(6, 0)))
+            .thenReturn(List.of(lista(21, 10, 20), lista(20, 30, 10), lista(21, 45, 13)));
+
+        ReporteTiemposCocinaResponse reporte = useCase.ejecutar(localId, DIA, DIA);
+
+        assertThat(reporte.cantidadComandas()).isEqualTo(3);
+        assertThat(reporte.promedioMinutos()).isEqualByComparingTo("14.3");
+        assertThat(reporte.demoradas()).isEqualTo(1);
+        assertThat(reporte.franjas()).extracting(FranjaHoraria::hora).containsExactly(20, 21);
+
+        FranjaHoraria pico = reporte.franjas().get(1);
+        assertThat(pico.cantidadComandas()).isEqualTo(2);
+        assertThat(pico.promedioMinutos()).isEqualByComparingTo(new BigDecimal("16.5"));
+        assertThat(pico.maximoMinutos()).isEqualByComparingTo("20.0");
+        assertThat(pico.demoradas()).isEqualTo(1);
+    }
+
+    @Test
+    @DisplayName("Rechaza un rango con la fecha hasta anterior a desde")
+    void deberia_rechazar_rango_invertido() {
+        assertThatThrownBy(() -> useCase.ejecutar(localId, DIA, DIA.minusDays(1)))
+            .isInstanceOf(IllegalArgumentException.class);
+
+        verifyNoInteractions(comandaCocinaRepository);
+    }
+}
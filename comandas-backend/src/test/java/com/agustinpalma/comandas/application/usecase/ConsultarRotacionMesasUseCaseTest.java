@@ -0,0 +1,125 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ReporteRotacionMesasResponse;
+import com.agustinpalma.comandas.application.dto.ReporteRotacionMesasResponse.FranjaOcupacion;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.DayOfWeek;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.UUID;
+import java.util.stream.IntStream;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.when;
+
+/**
+ * Tests unitarios para ConsultarRotacionMesasUseCase.
+ * HU-150: tiempo promedio de ocupación por franja y cubiertos que todavía entran.
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("HU-150 - Rotación de mesas por franja horaria")
+class ConsultarRotacionMesasUseCaseTest {
+
+    private static final LocalDate SABADO = LocalDate.of(2026, 3, 14);
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private MesaRepository mesaRepository;
+
+    private ConsultarRotacionMesasUseCase useCase;
+
+    private LocalId localId;
+    private Producto milanesa;
+    private int numero = 1;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new ConsultarRotacionMesasUseCase(pedidoRepository, mesaRepository);
+        localId = new LocalId(UUID.randomUUID());
+        milanesa = new Producto(ProductoId.generate(), localId, "Milanesa", new BigDecimal("5000"), true, "#AA5500");
+    }
+
+    @Test
+    void deberia_promediar_la_ocupacion_por_hora_de_apertura_y_estimar_los_cubiertos_disponibles() {
+        // Given: 4 mesas; dos sábados en el rango
+        when(mesaRepository.buscarPorLocal(localId)).thenReturn(IntStream.rangeClosed(1, 4)
+            .mapToObj(n -> new Mesa(MesaId.generate(), localId, n))
+            .toList());
+        when(pedidoRepository.buscarCerradosPorFecha(eq(localId), any(), any())).thenReturn(List.of(
+            ocupacion(SABADO.minusWeeks(1).atTime(21, 0), 90, 4, CanalVenta.SALON),
+            ocupacion(SABADO.atTime(21, 10), 60, 2, CanalVenta.SALON),
+            ocupacion(SABADO.atTime(13, 0), 45, 0, CanalVenta.SALON),
+            // Viernes: fuera del filtro
+            ocupacion(SABADO.minusDays(1).atTime(21, 0), 60, 2, CanalVenta.SALON),
+            // Take away: no ocupa mesa
+            ocupacion(SABADO.atTime(21, 30), 20, 0, CanalVenta.TAKE_AWAY)
+        ));
+
+        // When
+        ReporteRotacionMesasResponse reporte = useCase.ejecutar(localId, LocalDate.of(2026, 3, 2),
+            LocalDate.of(2026, 3, 15), DayOfWeek.SATURDAY);
+
+        // Then
+        assertThat(reporte.diasAnalizados()).isEqualTo(2);
+        assertThat(reporte.ocupaciones()).isEqualTo(3);
+        assertThat(reporte.promedioMinutos()).isEqualByComparingTo("65.0");
+        assertThat(reporte.cubiertos()).isEqualTo(6);
+        assertThat(reporte.rotacionPorMesa()).isEqualByComparingTo("0.38");
+        assertThat(reporte.franjas()).extracting(FranjaOcupacion::hora).containsExactly(13, 21);
+
+        FranjaOcupacion almuerzo = reporte.franjas().get(0);
+        assertThat(almuerzo.cubiertosPromedio()).isNull();
+        assertThat(almuerzo.cubiertosDisponibles()).isNull();
+
+        // 4 mesas × 60 / 75' = 3,2 mesas por hora; se abre 1 por sábado → 2,2 × 3 cubiertos
+        FranjaOcupacion cena = reporte.franjas().get(1);
+        assertThat(cena.ocupacionesPorDia()).isEqualByComparingTo("1.0");
+        assertThat(cena.promedioMinutos()).isEqualByComparingTo("75.0");
+        assertThat(cena.cubiertosPromedio()).isEqualByComparingTo("3.0");
+        assertThat(cena.capacidadPorHora()).isEqualByComparingTo("3.2");
+        assertThat(cena.cubiertosDisponibles()).isEqualTo(6);
+    }
+
+    @Test
+    void deberia_rechazar_rango_invertido() {
+        assertThatThrownBy(() -> useCase.ejecutar(localId, SABADO, SABADO.minusDays(1), null))
+            .isInstanceOf(IllegalArgumentException.class);
+    }
+
+    private Pedido ocupacion(LocalDateTime apertura, int minutos, int cubiertos, CanalVenta canal) {
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), numero++,
+            EstadoPedido.ABIERTO, apertura);
+        pedido.asignarCanal(canal, null);
+        pedido.asignarAtencion(null, cubiertos);
+        pedido.agregarProducto(milanesa, 1, null);
+        LocalDateTime cierre = apertura.plusMinutes(minutos);
+        pedido.cerrar(List.of(new Pago(MedioPago.EFECTIVO, pedido.calcularTotal(), cierre)), cierre);
+        return pedido;
+    }
+}
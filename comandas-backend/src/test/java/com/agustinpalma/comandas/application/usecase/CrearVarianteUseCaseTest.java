@@ -1,7 +1,9 @@
 package com.agustinpalma.comandas.application.usecase;
 
 import com.agustinpalma.comandas.application.dto.VarianteProductoRequest;
+import com.agustinpalma.comandas.application.dto.ProductoResponse;
 import com.agustinpalma.comandas.application.dto.VarianteProductoResponse;
+import com.agustinpalma.comandas.application.dto.VariantesEnLoteRequest;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
 import com.agustinpalma.comandas.domain.model.Producto;
@@ -31,6 +33,7 @@ import static org.mockito.Mockito.*;
  * - Rechazo por cantidadDiscosCarne duplicada en el grupo
  * - Rechazo si el producto base es un extra
  * - Herencia de propiedades del producto base
+ * - HU-109: Nombre y posición derivados de la etiqueta, alta en lote de tamaños
  */
 @ExtendWith(MockitoExtension.class)
 class CrearVarianteUseCaseTest {
@@ -276,4 +279,112 @@ class CrearVarianteUseCaseTest {
             assertTrue(ex.getMessage().contains("No se encontró el producto base"));
         }
     }
+
+    @Nested
+    class VariantesPorTamanio {
+
+        @Test
+        void deberia_derivar_nombre_y_posicion_desde_la_etiqueta() {
+            // Given: gaseosa que ya es líder de su grupo
+            ProductoId baseId = ProductoId.generate();
+            Producto productoBase = new Producto(
+                baseId, localId, "Coca Cola", new BigDecimal("1200.00"),
+                true, "#FF0000", baseId, false, 1
+            );
+
+            VarianteProductoRequest request = new VarianteProductoRequest(
+                null, new BigDecimal("2500.00"),
+                null, null, null, null, null, null, null, "1.5 L"
+            );
+
+            when(productoRepository.buscarPorIdYLocal(baseId, localId))
+                .thenReturn(Optional.of(productoBase));
+            when(productoRepository.existePorNombreYLocal("Coca Cola 1.5 L", localId))
+                .thenReturn(false);
+            when(productoRepository.buscarPorGrupoVariante(localId, baseId))
+                .thenReturn(List.of(productoBase));
+            when(productoRepository.guardar(any(Producto.class)))
+                .thenAnswer(inv -> inv.getArgument(0));
+
+            // When
+            VarianteProductoResponse response = useCase.ejecutar(localId, baseId, request);
+
+            // Then
+            assertEquals("Coca Cola 1.5 L", response.varianteCreada().nombre());
+            assertEquals("1.5 L", response.varianteCreada().etiquetaVariante());
+            assertEquals(2, response.varianteCreada().cantidadDiscosCarne());
+        }
+
+        @Test
+        void deberia_crear_tamanios_en_lote_y_renombrar_el_base_con_su_etiqueta() {
+            // Given: pizza sin grupo todavía
+            ProductoId baseId = ProductoId.generate();
+            Producto productoBase = new Producto(
+                baseId, localId, "Pizza Muzzarella", new BigDecimal("5000.00"),
+                true, "#FFAA00"
+            );
+
+            VariantesEnLoteRequest request = new VariantesEnLoteRequest("Chica", List.of(
+                new VarianteProductoRequest(null, new BigDecimal("6500.00"),
+                    null, null, null, null, null, null, null, "Mediana"),
+                new VarianteProductoRequest(null, new BigDecimal("8000.00"),
+                    null, null, null, null, null, null, null, "Grande")
+            ));
+
+            Producto mediana = new Producto(
+                ProductoId.generate(), localId, "Pizza Muzzarella Mediana", new BigDecimal("6500.00"),
+                true, "#FFAA00", baseId, false, 2
+            );
+
+            when(productoRepository.buscarPorIdYLocal(baseId, localId))
+                .thenReturn(Optional.of(productoBase));
+            when(productoRepository.existePorNombreYLocal(any(), eq(localId)))
+                .thenReturn(false);
+            when(productoRepository.buscarPorGrupoVariante(localId, baseId))
+                .thenReturn(List.of(productoBase))          // validación de la Mediana
+                .thenReturn(List.of(productoBase, mediana)) // validación de la Grande
+                .thenReturn(List.of(productoBase, mediana)); // respuesta
+            when(productoRepository.guardar(any(Producto.class)))
+                .thenAnswer(inv -> inv.getArgument(0));
+
+            // When
+            List<ProductoResponse> grupo = useCase.ejecutarLote(localId, baseId, request);
+
+            // Then: el base quedó etiquetado y con nombre propio
+            assertEquals("Pizza Muzzarella Chica", productoBase.getNombre());
+            assertEquals("Chica", productoBase.getEtiquetaVariante());
+            assertEquals(2, grupo.size());
+
+            // Mediana en posición 2, Grande en posición 3 (nombres derivados del nombre original)
+            verify(productoRepository).guardar(argThat(p ->
+                "Pizza Muzzarella Mediana".equals(p.getNombre()) && Integer.valueOf(2).equals(p.getCantidadDiscosCarne())));
+            verify(productoRepository).guardar(argThat(p ->
+                "Pizza Muzzarella Grande".equals(p.getNombre()) && Integer.valueOf(3).equals(p.getCantidadDiscosCarne())));
+        }
+
+        @Test
+        void deberia_rechazar_variante_sin_nombre_ni_etiqueta() {
+            // Given
+            ProductoId baseId = ProductoId.generate();
+            Producto productoBase = new Producto(
+                baseId, localId, "Pizza Napolitana", new BigDecimal("5500.00"),
+                true, "#FFAA00", baseId, false, 1
+            );
+
+            VarianteProductoRequest request = new VarianteProductoRequest(
+                null, new BigDecimal("7000.00"),
+                null, null, null, null, null, null, null, "  "
+            );
+
+            when(productoRepository.buscarPorIdYLocal(baseId, localId))
+                .thenReturn(Optional.of(productoBase));
+
+            // When/Then
+            IllegalArgumentException ex = assertThrows(
+                IllegalArgumentException.class,
+                () -> useCase.ejecutar(localId, baseId, request)
+            );
+            assertTrue(ex.getMessage().contains("El nombre de la variante es obligatorio"));
+        }
+    }
 }
@@ -7,6 +7,7 @@ import com.agustinpalma.comandas.domain.model.*;
 import com.agustinpalma.comandas.domain.model.DomainEnums.*;
 import com.agustinpalma.comandas.domain.model.DomainIds.*;
 import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
 import com.agustinpalma.comandas.domain.repository.PedidoRepository;
 import com.agustinpalma.comandas.domain.repository.ProductoRepository;
 import com.agustinpalma.comandas.infrastructure.config.TestClockConfig;
@@ -35,6 +36,7 @@ import static org.assertj.core.api.Assertions.*;
  * 3. Auditoría: persistencia de usuarioId y fecha
  * 4. Sobrescritura: aplicar nuevo descuento reemplaza el anterior
  * 5. Dinamismo de descarga: eliminar ítems recalcula el descuento
+ * 6. Tope por rol (HU-122): el mozo hasta 10%, el encargado con PIN sin tope
  * 
  * Principios:
  * - Tests contra BD real (H2)
@@ -63,6 +65,9 @@ class DescuentoManualIntegrationTest {
     @Autowired
     private MesaRepository mesaRepository;
 
+    @Autowired
+    private MozoRepository mozoRepository;
+
     // Datos base del test
     private LocalId localId;
     private Mesa mesa;
@@ -70,6 +75,7 @@ class DescuentoManualIntegrationTest {
     private Producto hamburguesa;
     private Producto papas;
     private UUID usuarioId;
+    private Mozo encargado;
 
     @BeforeEach
     void setUp() {
@@ -96,6 +102,11 @@ class DescuentoManualIntegrationTest {
 
         // Usuario de auditoría
         usuarioId = UUID.randomUUID();
+
+        // HU-122: Encargado que autoriza los descuentos por encima del tope del mozo
+        encargado = new Mozo(MozoId.generate(), localId, "Laura", true, RolPersonal.ENCARGADO, null);
+        encargado.cambiarPin("4321");
+        mozoRepository.guardar(encargado);
     }
 
     // =================================================
@@ -226,7 +237,9 @@ class DescuentoManualIntegrationTest {
                     ModoDescuento.PORCENTAJE,
                     new BigDecimal("15"),
                     "Descuento auditoría test",
-                    usuarioEspecifico
+                    usuarioEspecifico,
+                    encargado.getId(),
+                    "4321"
                 )
             );
 
@@ -240,6 +253,8 @@ class DescuentoManualIntegrationTest {
             assertThat(descuento.getRazon()).isEqualTo("Descuento auditoría test");
             assertThat(descuento.getFechaAplicacion()).isNotNull();
             assertThat(descuento.getValor()).isEqualByComparingTo("15");
+            assertThat(descuento.getAutorizadoPorId()).isEqualTo(encargado.getId());
+            assertThat(descuento.getAutorizadoPor()).isEqualTo("Laura");
         }
     }
 
@@ -283,7 +298,9 @@ class DescuentoManualIntegrationTest {
                     ModoDescuento.PORCENTAJE,
                     new BigDecimal("15"),
                     "Segundo descuento (reemplazo)",
-                    usuarioId
+                    usuarioId,
+                    encargado.getId(),
+                    "4321"
                 )
             );
 
@@ -353,6 +370,85 @@ class DescuentoManualIntegrationTest {
         }
     }
 
+    // =================================================
+    // TEST 6: TOPE POR ROL (HU-122)
+    // =================================================
+
+    @Nested
+    @DisplayName("Test 6: Tope por rol - El mozo hasta 10%, el encargado sin tope")
+    class TopePorRol {
+
+        @Test
+        @DisplayName("Sin autorización de encargado, un 15% se rechaza y el pedido queda sin descuento")
+        void mozo_no_puede_superar_el_tope() {
+            agregarProductoUseCase.ejecutar(
+                new AgregarProductoRequest(pedido.getId(), hamburguesa.getId(), 1, null)
+            );
+
+            assertThatThrownBy(() -> aplicarDescuentoManualUseCase.ejecutar(
+                new AplicarDescuentoManualRequest(
+                    pedido.getId(), null, ModoDescuento.PORCENTAJE, new BigDecimal("15"),
+                    "Cliente frecuente", usuarioId
+                )
+            ))
+                .isInstanceOf(IllegalStateException.class)
+                .hasMessageContaining("autorización de un encargado");
+
+            assertThat(pedidoRepository.buscarPorId(pedido.getId()).orElseThrow().tieneDescuentoGlobal()).isFalse();
+        }
+
+        @Test
+        @DisplayName("Un monto fijo se mide contra el 10% de la base: $300 sobre $2500 supera el tope del mozo")
+        void monto_fijo_se_mide_contra_el_tope() {
+            agregarProductoUseCase.ejecutar(
+                new AgregarProductoRequest(pedido.getId(), hamburguesa.getId(), 1, null)
+            );
+
+            assertThatThrownBy(() -> aplicarDescuentoManualUseCase.ejecutar(
+                new AplicarDescuentoManualRequest(
+                    pedido.getId(), null, ModoDescuento.MONTO_FIJO, new BigDecimal("300"),
+                    "Cortesía", usuarioId
+                )
+            )).isInstanceOf(IllegalStateException.class);
+        }
+
+        @Test
+        @DisplayName("Con el PIN del encargado se aplica sin tope y el ajuste registra quién lo autorizó")
+        void encargado_autoriza_sin_tope() {
+            agregarProductoUseCase.ejecutar(
+                new AgregarProductoRequest(pedido.getId(), hamburguesa.getId(), 1, null)
+            );
+
+            AplicarDescuentoManualResponse response = aplicarDescuentoManualUseCase.ejecutar(
+                new AplicarDescuentoManualRequest(
+                    pedido.getId(), null, ModoDescuento.PORCENTAJE, new BigDecimal("50"),
+                    "Cumpleaños del dueño", usuarioId, encargado.getId(), "4321"
+                )
+            );
+
+            assertThat(response.totalFinal()).isEqualByComparingTo("1250.00");
+            Pedido recuperado = pedidoRepository.buscarPorId(pedido.getId()).orElseThrow();
+            assertThat(recuperado.obtenerAjustesEconomicos()).singleElement()
+                .satisfies(a -> assertThat(a.getAutorizadoPor()).isEqualTo("Laura"));
+        }
+
+        @Test
+        @DisplayName("Rechaza el descuento sin motivo")
+        void motivo_obligatorio() {
+            agregarProductoUseCase.ejecutar(
+                new AgregarProductoRequest(pedido.getId(), hamburguesa.getId(), 1, null)
+            );
+
+            assertThatThrownBy(() -> aplicarDescuentoManualUseCase.ejecutar(
+                new AplicarDescuentoManualRequest(
+                    pedido.getId(), null, ModoDescuento.PORCENTAJE, new BigDecimal("5"), "  ", usuarioId
+                )
+            ))
+                .isInstanceOf(IllegalArgumentException.class)
+                .hasMessageContaining("motivo");
+        }
+    }
+
     // =================================================
     // HELPERS
     // =================================================
@@ -2,17 +2,27 @@ package com.agustinpalma.comandas.application.usecase;
 
 import com.agustinpalma.comandas.application.dto.ProductoRequest;
 import com.agustinpalma.comandas.application.dto.ProductoResponse;
+import com.agustinpalma.comandas.domain.exception.PermisoDenegadoException;
+import com.agustinpalma.comandas.domain.model.CambioPrecioProducto;
+import com.agustinpalma.comandas.domain.model.DomainEnums.OrigenCambioPrecio;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
 import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.CambioPrecioProductoRepository;
 import com.agustinpalma.comandas.domain.repository.ProductoRepository;
 import org.junit.jupiter.api.BeforeEach;
 import org.junit.jupiter.api.Test;
 import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.ArgumentCaptor;
 import org.mockito.Mock;
 import org.mockito.junit.jupiter.MockitoExtension;
 
 import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
 import java.util.Optional;
 
 import static org.junit.jupiter.api.Assertions.*;
@@ -29,6 +39,8 @@ import static org.mockito.Mockito.*;
  * - Rechazo por producto de otro local (403 - seguridad multi-tenancy)
  * - Rechazo por nombre duplicado al editar (409)
  * - Permitir mismo nombre si no cambió
+ * - HU-146: El cambio de precio queda en el historial
+ * - HU-169: Cambiar el precio exige el permiso MODIFICAR_PRECIOS
  */
 @ExtendWith(MockitoExtension.class)
 class EditarProductoUseCaseTest {
@@ -36,6 +48,14 @@ class EditarProductoUseCaseTest {
     @Mock
     private ProductoRepository productoRepository;
 
+    @Mock
+    private CambioPrecioProductoRepository cambioPrecioProductoRepository;
+
+    @Mock
+    private GestionarPermisosUseCase gestionarPermisosUseCase;
+
+    private final Clock clock = Clock.fixed(Instant.parse("2026-03-10T15:00:00Z"), ZoneId.of("UTC"));
+
     private EditarProductoUseCase useCase;
 
     private LocalId localId;
@@ -44,7 +64,8 @@ class EditarProductoUseCaseTest {
 
     @BeforeEach
     void setUp() {
-        useCase = new EditarProductoUseCase(productoRepository);
+        useCase = new EditarProductoUseCase(productoRepository, cambioPrecioProductoRepository,
+            gestionarPermisosUseCase, clock);
         localId = LocalId.generate();
         otroLocalId = LocalId.generate();
         productoId = ProductoId.generate();
@@ -478,4 +499,87 @@ class EditarProductoUseCaseTest {
         // Then
         assertTrue(response.esExtra(), "Debe preservar esExtra = true cuando no se envía");
     }
+
+    // =================================================
+    // HU-146: Historial de cambios de precios
+    // =================================================
+
+    @Test
+    void deberia_registrar_el_cambio_de_precio_en_el_historial() {
+        // Given
+        Producto productoExistente = new Producto(
+            productoId, localId, "Milanesa", new BigDecimal("8000.00"), true, "#FF0000"
+        );
+        ProductoRequest request = new ProductoRequest(
+            "Milanesa", new BigDecimal("8800.00"), true, null,
+            null, null, null, null, null, null, null, null, "Encargada Ana"
+        );
+
+        when(productoRepository.buscarPorId(productoId)).thenReturn(Optional.of(productoExistente));
+        when(productoRepository.guardar(any(Producto.class)))
+            .thenAnswer(invocation -> invocation.getArgument(0));
+
+        // When
+        useCase.ejecutar(productoId, localId, request);
+
+        // Then
+        ArgumentCaptor<CambioPrecioProducto> captor = ArgumentCaptor.forClass(CambioPrecioProducto.class);
+        verify(cambioPrecioProductoRepository).guardar(captor.capture());
+        CambioPrecioProducto cambio = captor.getValue();
+        assertEquals(0, new BigDecimal("8000.00").compareTo(cambio.getPrecioAnterior()));
+        assertEquals(0, new BigDecimal("8800.00").compareTo(cambio.getPrecioNuevo()));
+        assertEquals(OrigenCambioPrecio.EDICION, cambio.getOrigen());
+        assertEquals("Encargada Ana", cambio.getResponsable());
+        assertEquals(LocalDateTime.of(2026, 3, 10, 15, 0), cambio.getFecha());
+    }
+
+    @Test
+    void no_deberia_registrar_historial_si_el_precio_no_cambia() {
+        // Given
+        Producto productoExistente = new Producto(
+            productoId, localId, "Milanesa", new BigDecimal("8000.00"), true, "#FF0000"
+        );
+        ProductoRequest request = new ProductoRequest(
+            "Milanesa Napolitana", new BigDecimal("8000"), true, null,
+            null, null, null, null, null, null
+        );
+
+        when(productoRepository.buscarPorId(productoId)).thenReturn(Optional.of(productoExistente));
+        when(productoRepository.existePorNombreYLocalExcluyendo("Milanesa Napolitana", localId, productoId))
+            .thenReturn(false);
+        when(productoRepository.guardar(any(Producto.class)))
+            .thenAnswer(invocation -> invocation.getArgument(0));
+
+        // When
+        useCase.ejecutar(productoId, localId, request);
+
+        // Then
+        verifyNoInteractions(cambioPrecioProductoRepository);
+        verifyNoInteractions(gestionarPermisosUseCase);
+    }
+
+    // =================================================
+    // HU-169: Permiso para modificar precios
+    // =================================================
+
+    @Test
+    void deberia_rechazar_el_cambio_de_precio_sin_permiso() {
+        // Given
+        Producto productoExistente = new Producto(
+            productoId, localId, "Milanesa", new BigDecimal("8000.00"), true, "#FF0000"
+        );
+        ProductoRequest request = new ProductoRequest(
+            "Milanesa", new BigDecimal("8800.00"), true, null,
+            null, null, null, null, null, null
+        );
+
+        when(productoRepository.buscarPorId(productoId)).thenReturn(Optional.of(productoExistente));
+        doThrow(new PermisoDenegadoException(Permiso.MODIFICAR_PRECIOS, "Juan no tiene permiso para modificar precios"))
+            .when(gestionarPermisosUseCase).exigir(localId, Permiso.MODIFICAR_PRECIOS);
+
+        // When / Then
+        assertThrows(PermisoDenegadoException.class, () -> useCase.ejecutar(productoId, localId, request));
+        verify(productoRepository, never()).guardar(any(Producto.class));
+        verifyNoInteractions(cambioPrecioProductoRepository);
+    }
 }
@@ -0,0 +1,84 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.MantenimientoBaseResponse;
+import com.agustinpalma.comandas.application.ports.output.MantenimientoBasePort;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.time.Clock;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso EjecutarMantenimientoBaseUseCase.
+ * Valida los criterios de la HU-163 (mantenimiento programado de la base).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Mantenimiento de la base - Caso de Uso")
+class EjecutarMantenimientoBaseUseCaseTest {
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private MantenimientoBasePort mantenimientoBasePort;
+
+    private EjecutarMantenimientoBaseUseCase useCase;
+    private LocalId localId;
+
+    @BeforeEach
+    void setUp() {
+        Clock clock = Clock.fixed(Instant.parse("2026-10-14T04:00:00Z"), ZoneId.of("UTC"));
+        useCase = new EjecutarMantenimientoBaseUseCase(pedidoRepository, mantenimientoBasePort, clock);
+        localId = new LocalId(UUID.randomUUID());
+    }
+
+    @Test
+    @DisplayName("Con el salón vacío optimiza la base e informa el espacio recuperado")
+    void deberia_optimizar_la_base_con_el_salon_vacio() {
+        // Given
+        when(pedidoRepository.buscarAbiertosPorLocal(localId)).thenReturn(List.of());
+        when(mantenimientoBasePort.optimizar())
+            .thenReturn(new MantenimientoBasePort.Resultado("SQLite", 50_000_000L, 32_000_000L));
+
+        // When
+        MantenimientoBaseResponse response = useCase.ejecutar(localId);
+
+        // Then
+        assertThat(response.motor()).isEqualTo("SQLite");
+        assertThat(response.inicio()).isEqualTo(LocalDateTime.of(2026, 10, 14, 4, 0));
+        assertThat(response.bytesRecuperados()).isEqualTo(18_000_000L);
+    }
+
+    @Test
+    @DisplayName("Con mesas abiertas rechaza el mantenimiento sin tocar la base")
+    void no_deberia_optimizar_con_mesas_abiertas() {
+        // Given
+        Pedido abierto = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 3, EstadoPedido.ABIERTO,
+            LocalDateTime.of(2026, 10, 14, 1, 30));
+        when(pedidoRepository.buscarAbiertosPorLocal(localId)).thenReturn(List.of(abierto));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.ejecutar(localId))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("mesas abiertas (1)");
+        verifyNoInteractions(mantenimientoBasePort);
+    }
+}
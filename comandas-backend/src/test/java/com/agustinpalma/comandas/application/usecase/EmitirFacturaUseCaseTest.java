@@ -0,0 +1,128 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.EmitirFacturaRequest;
+import com.agustinpalma.comandas.application.dto.FacturaResponse;
+import com.agustinpalma.comandas.application.ports.output.ModuloFiscalPort;
+import com.agustinpalma.comandas.application.ports.output.ModuloFiscalPort.Contribuyente;
+import com.agustinpalma.comandas.application.ports.output.ModuloFiscalPort.ModuloFiscalNoDisponibleException;
+import com.agustinpalma.comandas.domain.model.Cuit;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CondicionIva;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoFactura;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoFactura;
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Factura;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.FacturaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso EmitirFacturaUseCase.
+ * Valida los criterios de la HU-115 (Factura A/B/C).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Emitir Factura - Caso de Uso")
+class EmitirFacturaUseCaseTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 3, 10, 22, 0);
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private FacturaRepository facturaRepository;
+
+    @Mock
+    private ModuloFiscalPort moduloFiscal;
+
+    private EmitirFacturaUseCase useCase;
+
+    private LocalId localId;
+    private Pedido pedido;
+
+    @BeforeEach
+    void setUp() {
+        ZoneId zona = ZoneId.of("America/Argentina/Buenos_Aires");
+        Clock clock = Clock.fixed(AHORA.atZone(zona).toInstant(), zona);
+        useCase = new EmitirFacturaUseCase(pedidoRepository, facturaRepository, moduloFiscal,
+                new MeisenProperties(), clock);
+        localId = new LocalId(UUID.randomUUID());
+
+        Producto milanesa = new Producto(ProductoId.generate(), localId, "Milanesa",
+                new BigDecimal("12100"), true, "#AA5500");
+        pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 4,
+                EstadoPedido.ABIERTO, AHORA.minusHours(1));
+        pedido.agregarProducto(milanesa, 1, null);
+        pedido.finalizar(MedioPago.EFECTIVO, AHORA.minusMinutes(5));
+
+        when(pedidoRepository.buscarPorId(pedido.getId())).thenReturn(Optional.of(pedido));
+    }
+
+    @Test
+    @DisplayName("Debería emitir con los datos del padrón y quedar pendiente si no hay autorización")
+    void deberia_emitir_con_datos_del_padron_y_quedar_pendiente() {
+        // Given
+        Cuit cuit = Cuit.of("30-71234567-1");
+        when(facturaRepository.buscarPorPedido(pedido.getId(), localId)).thenReturn(Optional.empty());
+        when(moduloFiscal.consultarPadron(cuit)).thenReturn(Optional.of(
+            new Contribuyente(cuit, "Distribuidora Sur SA", CondicionIva.RESPONSABLE_INSCRIPTO)));
+        when(facturaRepository.ultimoNumero(localId, 1, TipoFactura.A)).thenReturn(41L);
+        when(moduloFiscal.autorizar(any())).thenThrow(new ModuloFiscalNoDisponibleException("sin conexión"));
+        when(facturaRepository.guardar(any())).thenAnswer(inv -> inv.getArgument(0));
+
+        // When: el operador cargó consumidor final, pero el padrón dice inscripto
+        FacturaResponse response = useCase.emitir(localId, new EmitirFacturaRequest(
+            pedido.getId().getValue(), "30712345671", null, CondicionIva.CONSUMIDOR_FINAL));
+
+        // Then
+        assertThat(response.tipo()).isEqualTo(TipoFactura.A);
+        assertThat(response.numero()).isEqualTo("00001-00000042");
+        assertThat(response.razonSocial()).isEqualTo("Distribuidora Sur SA");
+        assertThat(response.estado()).isEqualTo(EstadoFactura.PENDIENTE_AUTORIZACION);
+        assertThat(response.importeNeto()).isEqualByComparingTo("10000");
+        assertThat(response.importeIva()).isEqualByComparingTo("2100");
+    }
+
+    @Test
+    @DisplayName("Debería rechazar facturar dos veces el mismo pedido")
+    void deberia_rechazar_pedido_ya_facturado() {
+        // Given
+        Factura existente = Factura.emitir(FacturaId.generate(), pedido, CondicionIva.RESPONSABLE_INSCRIPTO,
+                1, 7, null, null, CondicionIva.CONSUMIDOR_FINAL, new BigDecimal("21"), AHORA);
+        when(facturaRepository.buscarPorPedido(pedido.getId(), localId)).thenReturn(Optional.of(existente));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.emitir(localId, new EmitirFacturaRequest(
+                pedido.getId().getValue(), null, null, CondicionIva.CONSUMIDOR_FINAL)))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("00001-00000007");
+
+        verify(facturaRepository, never()).guardar(any());
+        verifyNoInteractions(moduloFiscal);
+    }
+}
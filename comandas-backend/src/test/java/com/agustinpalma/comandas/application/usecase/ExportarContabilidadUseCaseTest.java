@@ -0,0 +1,190 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.usecase.ExportarLibroIvaVentasUseCase.ArchivoExportado;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaGasto;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CondicionIva;
+import com.agustinpalma.comandas.domain.model.DomainEnums.ConceptoContable;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoFactura;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.SistemaContable;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoFactura;
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.GastoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Factura;
+import com.agustinpalma.comandas.domain.model.Gasto;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.PlanCuentasContable;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.FacturaRepository;
+import com.agustinpalma.comandas.domain.repository.GastoRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.PlanCuentasContableRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.nio.charset.StandardCharsets;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.YearMonth;
+import java.util.EnumMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.when;
+
+/**
+ * Test unitario del caso de uso ExportarContabilidadUseCase.
+ * Valida los criterios de la HU-167 (exportación al sistema contable).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Exportar Contabilidad - Caso de Uso")
+class ExportarContabilidadUseCaseTest {
+
+    private static final YearMonth MARZO = YearMonth.of(2026, 3);
+    private static final LocalDateTime CIERRE = LocalDateTime.of(2026, 3, 5, 22, 0);
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private FacturaRepository facturaRepository;
+
+    @Mock
+    private GastoRepository gastoRepository;
+
+    @Mock
+    private PlanCuentasContableRepository planCuentasContableRepository;
+
+    private ExportarContabilidadUseCase useCase;
+
+    private LocalId localId;
+    private Producto milanesa;
+    private int numero = 1;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new ExportarContabilidadUseCase(pedidoRepository, facturaRepository, gastoRepository,
+            planCuentasContableRepository);
+        localId = new LocalId(UUID.randomUUID());
+        milanesa = new Producto(ProductoId.generate(), localId, "Milanesa", new BigDecimal("5000"), true, "#AA5500");
+
+        // Dos ventas y un consumo interno el 5/3, una factura B y el alquiler del 10/3
+        when(pedidoRepository.buscarCerradosPorFecha(eq(localId), eq(LocalDateTime.of(2026, 3, 1, 0, 0)), any()))
+            .thenReturn(List.of(
+                pedidoCerrado(new Pago(MedioPago.EFECTIVO, new BigDecimal("5000"), CIERRE)),
+                pedidoCerrado(
+                    new Pago(MedioPago.TARJETA, new BigDecimal("3000"), CIERRE),
+                    new Pago(MedioPago.EFECTIVO, new BigDecimal("2000"), CIERRE)),
+                pedidoCerrado(new Pago(MedioPago.A_CUENTA, new BigDecimal("5000"), CIERRE))
+            ));
+        when(facturaRepository.buscarPorPeriodo(localId,
+                LocalDateTime.of(2026, 3, 1, 0, 0), LocalDateTime.of(2026, 4, 1, 0, 0)))
+            .thenReturn(List.of(facturaB()));
+        when(gastoRepository.buscarPorPeriodo(localId, LocalDate.of(2026, 3, 1), LocalDate.of(2026, 3, 31)))
+            .thenReturn(List.of(new Gasto(GastoId.generate(), localId, LocalDate.of(2026, 3, 10),
+                CategoriaGasto.ALQUILER, "Inmobiliaria Centro", null, new BigDecimal("300000"),
+                MedioPago.TRANSFERENCIA, List.of(), LocalDateTime.of(2026, 3, 10, 11, 0), null, null)));
+    }
+
+    private Pedido pedidoCerrado(Pago... pagos) {
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), numero++,
+            EstadoPedido.ABIERTO, CIERRE.minusHours(1));
+        pedido.agregarProducto(milanesa, 1, null);
+        pedido.cerrar(List.of(pagos), CIERRE);
+        return pedido;
+    }
+
+    private Factura facturaB() {
+        return new Factura(FacturaId.generate(), localId, PedidoId.generate(), TipoFactura.B, 1, 7,
+            CIERRE.plusMinutes(5), null, null, CondicionIva.CONSUMIDOR_FINAL, new BigDecimal("21"),
+            new BigDecimal("4132.23"), new BigDecimal("867.77"), new BigDecimal("5000.00"),
+            EstadoFactura.AUTORIZADA, "76123456789012", LocalDate.of(2026, 3, 15));
+    }
+
+    private void conPlanCompleto() {
+        Map<ConceptoContable, String> cuentas = new EnumMap<>(ConceptoContable.class);
+        cuentas.put(ConceptoContable.VENTAS, "4.1.01");
+        cuentas.put(ConceptoContable.IVA_DEBITO_FISCAL, "2.1.05");
+        cuentas.put(ConceptoContable.EFECTIVO, "1.1.01");
+        cuentas.put(ConceptoContable.TARJETA, "1.1.04");
+        cuentas.put(ConceptoContable.TRANSFERENCIA, "1.1.02");
+        cuentas.put(ConceptoContable.GASTO_ALQUILER, "5.2.01");
+        when(planCuentasContableRepository.buscarPorLocal(localId))
+            .thenReturn(Optional.of(new PlanCuentasContable(localId, cuentas)));
+    }
+
+    @Test
+    @DisplayName("Tango: asientos de ventas, IVA y gastos con coma decimal")
+    void deberia_exportar_asientos_en_formato_tango() {
+        // Given
+        conPlanCompleto();
+
+        // When
+        ArchivoExportado archivo = useCase.ejecutar(localId, MARZO, SistemaContable.TANGO);
+
+        // Then
+        assertThat(archivo.nombre()).isEqualTo("ASIENTOS_TANGO_202603.txt");
+        List<String> lineas = new String(archivo.contenido(), StandardCharsets.ISO_8859_1).lines().toList();
+        assertThat(lineas).containsExactly(
+            "NRO_ASIENTO;FECHA;LEYENDA;COD_CUENTA;DEBE;HABER",
+            "1;05/03/2026;Ventas del 05/03/2026 (2 pedidos);1.1.01;7000,00;0,00",
+            "1;05/03/2026;Ventas del 05/03/2026 (2 pedidos);1.1.04;3000,00;0,00",
+            "1;05/03/2026;Ventas del 05/03/2026 (2 pedidos);4.1.01;0,00;10000,00",
+            "2;05/03/2026;IVA factura B 00001-00000007;4.1.01;867,77;0,00",
+            "2;05/03/2026;IVA factura B 00001-00000007;2.1.05;0,00;867,77",
+            "3;10/03/2026;Gasto alquiler: Inmobiliaria Centro;5.2.01;300000,00;0,00",
+            "3;10/03/2026;Gasto alquiler: Inmobiliaria Centro;1.1.02;0,00;300000,00"
+        );
+    }
+
+    @Test
+    @DisplayName("Xubio: separador coma y punto decimal")
+    void deberia_exportar_asientos_en_formato_xubio() {
+        // Given
+        conPlanCompleto();
+
+        // When
+        ArchivoExportado archivo = useCase.ejecutar(localId, MARZO, SistemaContable.XUBIO);
+
+        // Then
+        assertThat(archivo.nombre()).isEqualTo("ASIENTOS_XUBIO_202603.csv");
+        List<String> lineas = new String(archivo.contenido(), StandardCharsets.UTF_8).lines().toList();
+        assertThat(lineas.get(0)).isEqualTo("Numero,Fecha,Descripcion,Cuenta,Debe,Haber");
+        assertThat(lineas.get(1)).isEqualTo("1,05/03/2026,Ventas del 05/03/2026 (2 pedidos),1.1.01,7000.00,0.00");
+    }
+
+    @Test
+    @DisplayName("Rechaza exportar si un concepto del mes no tiene cuenta")
+    void deberia_rechazar_si_faltan_cuentas() {
+        // Given: el plan no tiene las cuentas de tarjeta ni de alquiler
+        when(planCuentasContableRepository.buscarPorLocal(localId)).thenReturn(Optional.of(
+            new PlanCuentasContable(localId, Map.of(
+                ConceptoContable.VENTAS, "4.1.01",
+                ConceptoContable.IVA_DEBITO_FISCAL, "2.1.05",
+                ConceptoContable.EFECTIVO, "1.1.01",
+                ConceptoContable.TRANSFERENCIA, "1.1.02"))));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.ejecutar(localId, MARZO, SistemaContable.CONTABILIUM))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("TARJETA")
+            .hasMessageContaining("GASTO_ALQUILER");
+    }
+}
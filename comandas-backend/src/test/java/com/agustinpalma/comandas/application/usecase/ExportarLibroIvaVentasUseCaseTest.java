@@ -0,0 +1,127 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.usecase.ExportarLibroIvaVentasUseCase.ArchivoExportado;
+import com.agustinpalma.comandas.application.usecase.ExportarLibroIvaVentasUseCase.Formato;
+import com.agustinpalma.comandas.domain.model.Cuit;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CondicionIva;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoFactura;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoFactura;
+import com.agustinpalma.comandas.domain.model.DomainIds.FacturaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.Factura;
+import com.agustinpalma.comandas.domain.repository.FacturaRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.io.ByteArrayInputStream;
+import java.io.IOException;
+import java.math.BigDecimal;
+import java.nio.charset.StandardCharsets;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.YearMonth;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+import java.util.zip.ZipEntry;
+import java.util.zip.ZipInputStream;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.mockito.Mockito.when;
+
+/**
+ * Test unitario del caso de uso ExportarLibroIvaVentasUseCase.
+ * Valida los criterios de la HU-116 (Libro IVA ventas).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Exportar Libro IVA Ventas - Caso de Uso")
+class ExportarLibroIvaVentasUseCaseTest {
+
+    private static final YearMonth MARZO = YearMonth.of(2026, 3);
+
+    @Mock
+    private FacturaRepository facturaRepository;
+
+    private ExportarLibroIvaVentasUseCase useCase;
+
+    private LocalId localId;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new ExportarLibroIvaVentasUseCase(facturaRepository);
+        localId = new LocalId(UUID.randomUUID());
+
+        Factura facturaA = factura(TipoFactura.A, 42, Cuit.of("30712345671"), "Distribuidora Sur SA",
+                CondicionIva.RESPONSABLE_INSCRIPTO, "10000.00", "2100.00", "12100.00", EstadoFactura.AUTORIZADA);
+        Factura facturaB = factura(TipoFactura.B, 7, null, null,
+                CondicionIva.CONSUMIDOR_FINAL, "826.45", "173.55", "1000.00", EstadoFactura.AUTORIZADA);
+        Factura pendiente = factura(TipoFactura.B, 8, null, null,
+                CondicionIva.CONSUMIDOR_FINAL, "826.45", "173.55", "1000.00", EstadoFactura.PENDIENTE_AUTORIZACION);
+
+        when(facturaRepository.buscarPorPeriodo(localId,
+                LocalDateTime.of(2026, 3, 1, 0, 0), LocalDateTime.of(2026, 4, 1, 0, 0)))
+            .thenReturn(List.of(facturaA, facturaB, pendiente));
+    }
+
+    private Factura factura(TipoFactura tipo, long numero, Cuit cuit, String razonSocial, CondicionIva condicion,
+                            String neto, String iva, String total, EstadoFactura estado) {
+        boolean autorizada = estado == EstadoFactura.AUTORIZADA;
+        return new Factura(FacturaId.generate(), localId, PedidoId.generate(), tipo, 1, numero,
+                LocalDateTime.of(2026, 3, 5, 21, 30), cuit, razonSocial, condicion, new BigDecimal("21"),
+                new BigDecimal(neto), new BigDecimal(iva), new BigDecimal(total), estado,
+                autorizada ? "76123456789012" : null, autorizada ? LocalDate.of(2026, 3, 15) : null);
+    }
+
+    @Test
+    @DisplayName("CSV: una fila por factura autorizada con coma decimal")
+    void deberia_exportar_csv_solo_con_facturas_autorizadas() {
+        // When
+        ArchivoExportado archivo = useCase.ejecutar(localId, MARZO, Formato.CSV);
+
+        // Then
+        assertThat(archivo.nombre()).isEqualTo("LIBRO_IVA_VENTAS_202603.csv");
+        List<String> lineas = new String(archivo.contenido(), StandardCharsets.UTF_8).lines().toList();
+        assertThat(lineas).hasSize(3);
+        assertThat(lineas.get(0)).startsWith("\uFEFFFecha;Tipo;");
+        assertThat(lineas.get(1)).contains("30-71234567-1", "10000,00;2100,00;12100,00");
+        assertThat(lineas.get(2)).contains(";B;00001;00000007;;CONSUMIDOR FINAL;");
+    }
+
+    @Test
+    @DisplayName("TXT: ZIP con comprobantes y alícuotas de ancho fijo")
+    void deberia_exportar_txt_del_regimen_de_informacion() throws IOException {
+        // When
+        ArchivoExportado archivo = useCase.ejecutar(localId, MARZO, Formato.TXT);
+
+        // Then
+        assertThat(archivo.contentType()).isEqualTo("application/zip");
+        Map<String, List<String>> contenido = descomprimir(archivo.contenido());
+        assertThat(contenido).containsOnlyKeys(
+                "LIBRO_IVA_VENTAS_202603_CBTE.txt", "LIBRO_IVA_VENTAS_202603_ALICUOTAS.txt");
+
+        List<String> comprobantes = contenido.get("LIBRO_IVA_VENTAS_202603_CBTE.txt");
+        assertThat(comprobantes).hasSize(2).allSatisfy(l -> assertThat(l).hasSize(266));
+        assertThat(comprobantes.get(0)).startsWith("20260305001" + "00001");
+
+        List<String> alicuotas = contenido.get("LIBRO_IVA_VENTAS_202603_ALICUOTAS.txt");
+        assertThat(alicuotas).hasSize(2).allSatisfy(l -> assertThat(l).hasSize(62));
+        assertThat(alicuotas.get(0)).endsWith("000000001000000" + "0005" + "000000000210000");
+    }
+
+    private Map<String, List<String>> descomprimir(byte[] zip) throws IOException {
+        Map<String, List<String>> archivos = new HashMap<>();
+        try (ZipInputStream in = new ZipInputStream(new ByteArrayInputStream(zip))) {
+            ZipEntry entry;
+            while ((entry = in.getNextEntry()) != null) {
+                archivos.put(entry.getName(), new String(in.readAllBytes(), StandardCharsets.ISO_8859_1).lines().toList());
+            }
+        }
+        return archivos;
+    }
+}
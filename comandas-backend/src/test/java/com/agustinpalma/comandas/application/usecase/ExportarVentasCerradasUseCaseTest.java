@@ -0,0 +1,132 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.LoteVentasSucursalDTO;
+import com.agustinpalma.comandas.application.dto.LoteVentasSucursalDTO.VentaCerradaDTO;
+import com.agustinpalma.comandas.application.ports.output.IdentidadSucursalProvider;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.SucursalId;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.*;
+
+/**
+ * Tests unitarios para ExportarVentasCerradasUseCase.
+ * HU-107: lote de ventas que la sucursal envía al consolidado.
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("ExportarVentasCerradasUseCase - Tests de comportamiento")
+class ExportarVentasCerradasUseCaseTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 2, 6, 22, 0);
+    private static final LocalDateTime CIERRE = LocalDateTime.of(2026, 2, 6, 14, 45);
+
+    private static final UUID PRIMERO = UUID.fromString("00000000-0000-0000-0000-000000000001");
+    private static final UUID SEGUNDO = UUID.fromString("00000000-0000-0000-0000-000000000002");
+    private static final UUID TERCERO = UUID.fromString("00000000-0000-0000-0000-000000000003");
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private IdentidadSucursalProvider identidadSucursalProvider;
+
+    private ExportarVentasCerradasUseCase useCase;
+
+    private LocalId localId;
+    private Producto milanesa;
+
+    @BeforeEach
+    void setUp() {
+        ZoneId zona = ZoneId.of("America/Argentina/Buenos_Aires");
+        Clock clock = Clock.fixed(AHORA.atZone(zona).toInstant(), zona);
+        useCase = new ExportarVentasCerradasUseCase(pedidoRepository, identidadSucursalProvider, clock);
+
+        localId = new LocalId(UUID.randomUUID());
+        milanesa = new Producto(ProductoId.generate(), localId, "Milanesa", new BigDecimal("1000"), true, "#AA5500");
+        lenient().when(identidadSucursalProvider.getSucursalId()).thenReturn(SucursalId.generate());
+        lenient().when(identidadSucursalProvider.getNombreSucursal()).thenReturn("Norte");
+    }
+
+    private Pedido cerrado(UUID id, int numero, LocalDateTime cierre) {
+        Pedido pedido = new Pedido(new PedidoId(id), localId, MesaId.generate(), numero,
+                EstadoPedido.ABIERTO, cierre.minusHours(1));
+        pedido.agregarProducto(milanesa, 1, null);
+        pedido.finalizar(MedioPago.EFECTIVO, cierre);
+        return pedido;
+    }
+
+    @Test
+    @DisplayName("Debe ordenar por cierre y desempatar por pedido las ventas del mismo instante")
+    void deberia_ordenar_por_cierre_y_pedido() {
+        // Given: tres ventas cerradas en el mismo segundo, más una anterior
+        when(pedidoRepository.buscarCerradosPorFecha(eq(localId), any(), eq(AHORA))).thenReturn(List.of(
+            cerrado(TERCERO, 3, CIERRE),
+            cerrado(PRIMERO, 1, CIERRE),
+            cerrado(UUID.randomUUID(), 9, CIERRE.minusMinutes(30)),
+            cerrado(SEGUNDO, 2, CIERRE)
+        ));
+
+        // When: primera sincronización
+        LoteVentasSucursalDTO lote = useCase.ejecutar(localId, null, null);
+
+        // Then
+        assertThat(lote.ventas()).extracting(VentaCerradaDTO::numeroPedido).containsExactly(9, 1, 2, 3);
+    }
+
+    @Test
+    @DisplayName("No debe perder las ventas cerradas en el mismo instante que el cursor")
+    void deberia_exportar_las_ventas_que_comparten_el_cierre_del_cursor() {
+        // Given: el lote anterior terminó en PRIMERO; SEGUNDO y TERCERO cerraron en el mismo segundo
+        when(pedidoRepository.buscarCerradosPorFecha(localId, CIERRE, AHORA)).thenReturn(List.of(
+            cerrado(PRIMERO, 1, CIERRE),
+            cerrado(SEGUNDO, 2, CIERRE),
+            cerrado(TERCERO, 3, CIERRE),
+            cerrado(UUID.randomUUID(), 4, CIERRE.plusMinutes(5))
+        ));
+
+        // When
+        LoteVentasSucursalDTO lote = useCase.ejecutar(localId, CIERRE, PRIMERO);
+
+        // Then: sale todo lo que sigue al cursor, y nada de lo ya enviado
+        assertThat(lote.ventas()).extracting(VentaCerradaDTO::numeroPedido).containsExactly(2, 3, 4);
+    }
+
+    @Test
+    @DisplayName("Un cursor sin pedido reenvía las ventas de su mismo cierre")
+    void deberia_reenviar_las_ventas_del_cierre_del_cursor_si_no_trae_pedido() {
+        // Given: cursor guardado antes del desempate
+        when(pedidoRepository.buscarCerradosPorFecha(localId, CIERRE, AHORA)).thenReturn(List.of(
+            cerrado(SEGUNDO, 2, CIERRE),
+            cerrado(PRIMERO, 1, CIERRE)
+        ));
+
+        // When
+        LoteVentasSucursalDTO lote = useCase.ejecutar(localId, CIERRE, null);
+
+        // Then: el consolidado reemplaza las que ya tenía
+        assertThat(lote.ventas()).extracting(VentaCerradaDTO::numeroPedido).containsExactly(1, 2);
+    }
+}
@@ -0,0 +1,162 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ProductoVendidoReporte;
+import com.agustinpalma.comandas.application.dto.ReporteCajaResponse;
+import com.agustinpalma.comandas.application.dto.ReporteImagenData;
+import com.agustinpalma.comandas.application.dto.ReporteImagenData.Ancho;
+import com.agustinpalma.comandas.application.dto.ReporteImagenData.Fila;
+import com.agustinpalma.comandas.application.dto.ReporteImagenData.Seccion;
+import com.agustinpalma.comandas.application.dto.ReporteImagenData.Vista;
+import com.agustinpalma.comandas.application.ports.output.ReporteImagenGenerator;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.ArgumentCaptor;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.Instant;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.Collections;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.assertj.core.api.Assertions.tuple;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.*;
+
+/**
+ * Tests unitarios para GenerarImagenReporteUseCase.
+ * HU-157: reportes como imagen para reenviar por WhatsApp.
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("GenerarImagenReporteUseCase - Tests de comportamiento")
+class GenerarImagenReporteUseCaseTest {
+
+    @Mock
+    private GenerarReporteCajaUseCase generarReporteCajaUseCase;
+
+    @Mock
+    private ObtenerReporteVentasUseCase obtenerReporteVentasUseCase;
+
+    @Mock
+    private ReporteImagenGenerator reporteImagenGenerator;
+
+    private GenerarImagenReporteUseCase useCase;
+
+    private LocalId localId;
+    private final LocalDate lunes = LocalDate.of(2026, 10, 12);
+    private final LocalDate martes = LocalDate.of(2026, 10, 13);
+
+    @BeforeEach
+    void setUp() {
+        MeisenProperties meisenProperties = new MeisenProperties();
+        meisenProperties.getLocal().setNombreLocal("Meisen");
+        Clock clock = Clock.fixed(Instant.parse("2026-10-14T02:00:00Z"), ZoneId.of("America/Argentina/Buenos_Aires"));
+
+        useCase = new GenerarImagenReporteUseCase(
+            generarReporteCajaUseCase, obtenerReporteVentasUseCase, reporteImagenGenerator, meisenProperties, clock
+        );
+        localId = new LocalId(UUID.randomUUID());
+        lenient().when(reporteImagenGenerator.generarPng(any(), any())).thenReturn(new byte[]{1});
+    }
+
+    @Test
+    @DisplayName("Debe sumar las ventas de cada día del período y ordenar los medios de pago por monto")
+    void deberia_sumar_las_ventas_del_periodo() {
+        // Given: lunes 2 pedidos por $10.000, martes 1 pedido por $5.000
+        when(generarReporteCajaUseCase.ejecutar(localId, lunes)).thenReturn(caja(
+            new BigDecimal("10000"), 2, Map.of(MedioPago.EFECTIVO, new BigDecimal("4000"), MedioPago.QR, new BigDecimal("6000"))
+        ));
+        when(generarReporteCajaUseCase.ejecutar(localId, martes)).thenReturn(caja(
+            new BigDecimal("5000"), 1, Map.of(MedioPago.EFECTIVO, new BigDecimal("5000"))
+        ));
+        when(obtenerReporteVentasUseCase.ejecutar(eq(localId), any())).thenReturn(List.of());
+
+        // When
+        useCase.ejecutar(localId, Vista.VENTAS, lunes, martes, Ancho.TELEFONO);
+
+        // Then
+        ReporteImagenData data = captureData(Ancho.TELEFONO);
+        assertThat(data.nombreLocal()).isEqualTo("Meisen");
+        assertThat(data.titulo()).isEqualTo("Ventas");
+        assertThat(data.generadoEn()).isEqualTo(LocalDateTime.of(2026, 10, 13, 23, 0));
+
+        Seccion resumen = data.secciones().get(0);
+        assertThat(resumen.total().monto()).isEqualByComparingTo("15000");
+        assertThat(resumen.filas()).extracting(Fila::etiqueta, Fila::detalle)
+            .contains(tuple("Pedidos", "3"));
+        assertThat(resumen.filas()).filteredOn(f -> f.etiqueta().equals("Ticket promedio"))
+            .extracting(Fila::monto).first().satisfies(m -> assertThat(m).isEqualByComparingTo("5000"));
+
+        Seccion medios = data.secciones().get(1);
+        assertThat(medios.filas()).extracting(Fila::etiqueta, Fila::detalle)
+            .containsExactly(tuple("Efectivo", "60%"), tuple("QR", "40%"));
+    }
+
+    @Test
+    @DisplayName("Debe juntar el mismo producto de varios días en una sola fila")
+    void deberia_juntar_productos_del_periodo() {
+        // Given
+        when(obtenerReporteVentasUseCase.ejecutar(localId, lunes)).thenReturn(List.of(
+            new ProductoVendidoReporte("Pizza", 3L, new BigDecimal("9000")),
+            new ProductoVendidoReporte("Flan", 2L, new BigDecimal("4000"))
+        ));
+        when(obtenerReporteVentasUseCase.ejecutar(localId, martes)).thenReturn(List.of(
+            new ProductoVendidoReporte("Flan", 4L, new BigDecimal("8000"))
+        ));
+
+        // When
+        useCase.ejecutar(localId, Vista.PRODUCTOS, lunes, martes, Ancho.TICKET);
+
+        // Then: la vista de productos no necesita el reporte de caja
+        verifyNoInteractions(generarReporteCajaUseCase);
+        Seccion productos = captureData(Ancho.TICKET).secciones().get(0);
+        assertThat(productos.filas()).extracting(Fila::etiqueta, Fila::detalle)
+            .containsExactly(tuple("Flan", "6 u."), tuple("Pizza", "3 u."));
+        assertThat(productos.total().monto()).isEqualByComparingTo("21000");
+    }
+
+    @Test
+    @DisplayName("Debe rechazar un período invertido o de más de 31 días")
+    void deberia_rechazar_periodos_invalidos() {
+        assertThatThrownBy(() -> useCase.ejecutar(localId, Vista.CAJA, martes, lunes, Ancho.TICKET))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("anterior");
+        assertThatThrownBy(() -> useCase.ejecutar(localId, Vista.CAJA, lunes, lunes.plusDays(31), Ancho.TICKET))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("31 días");
+
+        verifyNoInteractions(generarReporteCajaUseCase, reporteImagenGenerator);
+    }
+
+    // ── Helpers ──
+
+    private ReporteImagenData captureData(Ancho ancho) {
+        ArgumentCaptor<ReporteImagenData> captor = ArgumentCaptor.forClass(ReporteImagenData.class);
+        verify(reporteImagenGenerator).generarPng(captor.capture(), eq(ancho));
+        return captor.getValue();
+    }
+
+    private ReporteCajaResponse caja(BigDecimal ventas, int pedidos, Map<MedioPago, BigDecimal> porMedio) {
+        List<ReporteCajaResponse.VentaResumen> resumenVentas = Collections.nCopies(pedidos,
+            new ReporteCajaResponse.VentaResumen(UUID.randomUUID().toString(), 1, 1, BigDecimal.ZERO, LocalDateTime.now()));
+        return new ReporteCajaResponse(
+            ventas, BigDecimal.ZERO, BigDecimal.ZERO, BigDecimal.ZERO, BigDecimal.ZERO, BigDecimal.ZERO,
+            porMedio, BigDecimal.ZERO, Map.of(), List.of(), resumenVentas, List.of(), false,
+            BigDecimal.ZERO, List.of(), BigDecimal.ZERO
+        );
+    }
+}
@@ -0,0 +1,232 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.AbrirMesaRequest;
+import com.agustinpalma.comandas.application.dto.AbrirMesaResponse;
+import com.agustinpalma.comandas.application.dto.AgregarProductoRequest;
+import com.agustinpalma.comandas.application.dto.ResolverSolicitudAutopedidoRequest;
+import com.agustinpalma.comandas.application.dto.SolicitudAutopedidoRequest;
+import com.agustinpalma.comandas.application.dto.SolicitudAutopedidoRequest.ItemSolicitudRequest;
+import com.agustinpalma.comandas.application.dto.SolicitudAutopedidoResponse;
+import com.agustinpalma.comandas.application.ports.output.NotificadorTiempoRealPort;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoSolicitudAutopedido;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.ItemSolicitudAutopedido;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.model.SolicitudAutopedido;
+import com.agustinpalma.comandas.domain.repository.CategoriaRepository;
+import com.agustinpalma.comandas.domain.repository.ListaPreciosRepository;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.domain.repository.SolicitudAutopedidoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.ArgumentCaptor;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarAutopedidoUseCase.
+ * Valida los criterios de la HU-126 (autopedido por QR con confirmación del mozo).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Autopedido por QR - Caso de Uso")
+class GestionarAutopedidoUseCaseTest {
+
+    @Mock
+    private SolicitudAutopedidoRepository solicitudRepository;
+
+    @Mock
+    private MesaRepository mesaRepository;
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private ProductoRepository productoRepository;
+
+    @Mock
+    private CategoriaRepository categoriaRepository;
+
+    @Mock
+    private ListaPreciosRepository listaPreciosRepository;
+
+    @Mock
+    private MozoRepository mozoRepository;
+
+    @Mock
+    private AbrirMesaUseCase abrirMesaUseCase;
+
+    @Mock
+    private AgregarProductoUseCase agregarProductoUseCase;
+
+    @Mock
+    private NotificadorTiempoRealPort notificador;
+
+    private GestionarAutopedidoUseCase useCase;
+
+    private LocalId localId;
+    private Mesa mesa;
+    private Producto milanesa;
+
+    @BeforeEach
+    void setUp() {
+        Clock clock = Clock.fixed(Instant.parse("2026-03-14T21:30:00Z"), ZoneId.of("UTC"));
+        useCase = new GestionarAutopedidoUseCase(solicitudRepository, mesaRepository, pedidoRepository,
+            productoRepository, categoriaRepository, listaPreciosRepository, mozoRepository, abrirMesaUseCase,
+            agregarProductoUseCase, notificador, clock);
+
+        localId = new LocalId(UUID.randomUUID());
+        mesa = new Mesa(MesaId.generate(), localId, 7);
+        milanesa = new Producto(ProductoId.generate(), localId, "Milanesa", new BigDecimal("9500"), true, "#FFAA00");
+    }
+
+    private SolicitudAutopedidoRequest pedidoDeDos() {
+        return new SolicitudAutopedidoRequest("Caro", List.of(
+            new ItemSolicitudRequest(milanesa.getId().getValue().toString(), 2, "una sin limón")
+        ));
+    }
+
+    private SolicitudAutopedido solicitudPendiente() {
+        return SolicitudAutopedido.enviar(localId, mesa, "Caro",
+            List.of(new ItemSolicitudAutopedido(milanesa.getId(), "Milanesa", new BigDecimal("9500"), 2, "una sin limón")),
+            LocalDateTime.of(2026, 3, 14, 21, 25));
+    }
+
+    @Test
+    @DisplayName("El pedido del cliente queda pendiente y se avisa a todas las terminales")
+    void deberia_registrar_pendiente_y_notificar() {
+        // Given
+        when(mesaRepository.buscarPorId(mesa.getId())).thenReturn(Optional.of(mesa));
+        when(solicitudRepository.contarPendientesPorMesa(localId, mesa.getId())).thenReturn(0L);
+        when(listaPreciosRepository.buscarPorLocalYCanal(localId, CanalVenta.SALON)).thenReturn(Optional.empty());
+        when(productoRepository.buscarPorIdYLocal(milanesa.getId(), localId)).thenReturn(Optional.of(milanesa));
+        when(solicitudRepository.guardar(any())).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        SolicitudAutopedidoResponse response = useCase.enviar(localId, mesa.getId(), pedidoDeDos());
+
+        // Then
+        assertThat(response.estado()).isEqualTo(EstadoSolicitudAutopedido.PENDIENTE);
+        assertThat(response.numeroMesa()).isEqualTo(7);
+        assertThat(response.total()).isEqualByComparingTo("19000");
+        verify(notificador).publicar(localId, SolicitudAutopedidoResponse.EVENTO_NUEVA, response);
+        verifyNoInteractions(agregarProductoUseCase);
+    }
+
+    @Test
+    @DisplayName("Rechaza el envío si un producto se marcó 86 mientras el cliente armaba el pedido")
+    void deberia_rechazar_producto_agotado() {
+        // Given
+        milanesa.marcarAgotado(true);
+        when(mesaRepository.buscarPorId(mesa.getId())).thenReturn(Optional.of(mesa));
+        when(solicitudRepository.contarPendientesPorMesa(localId, mesa.getId())).thenReturn(0L);
+        when(listaPreciosRepository.buscarPorLocalYCanal(localId, CanalVenta.SALON)).thenReturn(Optional.empty());
+        when(productoRepository.buscarPorIdYLocal(milanesa.getId(), localId)).thenReturn(Optional.of(milanesa));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.enviar(localId, mesa.getId(), pedidoDeDos()))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("Milanesa");
+        verify(solicitudRepository, never()).guardar(any());
+        verifyNoInteractions(notificador);
+    }
+
+    @Test
+    @DisplayName("Frena los envíos de una mesa con demasiados pedidos sin confirmar")
+    void deberia_limitar_pendientes_por_mesa() {
+        // Given
+        when(mesaRepository.buscarPorId(mesa.getId())).thenReturn(Optional.of(mesa));
+        when(solicitudRepository.contarPendientesPorMesa(localId, mesa.getId()))
+            .thenReturn((long) SolicitudAutopedido.MAXIMO_PENDIENTES_POR_MESA);
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.enviar(localId, mesa.getId(), pedidoDeDos()))
+            .isInstanceOf(IllegalStateException.class);
+        verify(solicitudRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("Confirmar con la mesa libre la abre en el salón y carga los ítems al pedido")
+    void deberia_abrir_mesa_y_cargar_items_al_confirmar() {
+        // Given
+        SolicitudAutopedido solicitud = solicitudPendiente();
+        PedidoId pedidoId = PedidoId.generate();
+        when(solicitudRepository.buscarPorId(solicitud.getId(), localId)).thenReturn(Optional.of(solicitud));
+        when(pedidoRepository.buscarPorMesaYEstado(mesa.getId(), EstadoPedido.ABIERTO)).thenReturn(Optional.empty());
+        when(abrirMesaUseCase.ejecutar(eq(localId), any(AbrirMesaRequest.class))).thenReturn(new AbrirMesaResponse(
+            mesa.getId().getValue().toString(), 7, "ABIERTA", pedidoId.getValue().toString(), 12, "ABIERTO",
+            "2026-03-14T21:30", "SALON", null));
+        when(solicitudRepository.guardar(any())).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        SolicitudAutopedidoResponse response = useCase.confirmar(localId, solicitud.getId(),
+            new ResolverSolicitudAutopedidoRequest(null, null));
+
+        // Then
+        assertThat(response.estado()).isEqualTo(EstadoSolicitudAutopedido.CONFIRMADA);
+
+        ArgumentCaptor<AgregarProductoRequest> captor = ArgumentCaptor.forClass(AgregarProductoRequest.class);
+        verify(agregarProductoUseCase).ejecutar(captor.capture());
+        assertThat(captor.getValue().pedidoId()).isEqualTo(pedidoId);
+        assertThat(captor.getValue().productoId()).isEqualTo(milanesa.getId());
+        assertThat(captor.getValue().cantidad()).isEqualTo(2);
+        assertThat(captor.getValue().observaciones()).isEqualTo("una sin limón");
+
+        verify(notificador).publicar(localId, SolicitudAutopedidoResponse.EVENTO_RESUELTA, response);
+    }
+
+    @Test
+    @DisplayName("Un pedido ya confirmado desde otra terminal no se vuelve a cargar")
+    void no_deberia_confirmar_dos_veces() {
+        // Given
+        SolicitudAutopedido solicitud = solicitudPendiente();
+        solicitud.confirmar(null, LocalDateTime.of(2026, 3, 14, 21, 28));
+        when(solicitudRepository.buscarPorId(solicitud.getId(), localId)).thenReturn(Optional.of(solicitud));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.confirmar(localId, solicitud.getId(),
+            new ResolverSolicitudAutopedidoRequest(null, null)))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("mesa 7");
+        verifyNoInteractions(abrirMesaUseCase, agregarProductoUseCase, notificador);
+    }
+
+    @Test
+    @DisplayName("Rechazar exige un motivo para mostrarle al cliente")
+    void deberia_exigir_motivo_al_rechazar() {
+        // Given
+        SolicitudAutopedido solicitud = solicitudPendiente();
+        when(solicitudRepository.buscarPorId(solicitud.getId(), localId)).thenReturn(Optional.of(solicitud));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.rechazar(localId, solicitud.getId(),
+            new ResolverSolicitudAutopedidoRequest(null, "  ")))
+            .isInstanceOf(IllegalArgumentException.class);
+        assertThat(solicitud.estaPendiente()).isTrue();
+    }
+}
@@ -0,0 +1,232 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ArqueoCajaResponse;
+import com.agustinpalma.comandas.application.dto.CierreJornadaResponse;
+import com.agustinpalma.comandas.application.ports.output.CajaContextProvider;
+import com.agustinpalma.comandas.domain.model.AperturaCaja;
+import com.agustinpalma.comandas.domain.model.Caja;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimiento;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.JornadaCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.JornadaCaja;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.model.Pago;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.CajaRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaCajaRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarCajasUseCase.
+ * Valida los criterios de la HU-159 (varias cajas en el mismo local).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Gestionar Cajas - Caso de Uso")
+class GestionarCajasUseCaseTest {
+
+    @Mock
+    private CajaRepository cajaRepository;
+
+    @Mock
+    private JornadaCajaRepository jornadaCajaRepository;
+
+    @Mock
+    private JornadaComercialRepository jornadaComercialRepository;
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private MovimientoCajaRepository movimientoCajaRepository;
+
+    @Mock
+    private CajaContextProvider cajaContextProvider;
+
+    private GestionarCajasUseCase useCase;
+
+    private final LocalDateTime ahora = LocalDateTime.of(2026, 10, 14, 21, 0);
+    private LocalId localId;
+    private JornadaCaja jornada;
+    private Caja barra;
+    private Producto milanesa;
+
+    @BeforeEach
+    void setUp() {
+        Clock clock = Clock.fixed(Instant.parse("2026-10-15T00:00:00Z"), ZoneId.of("America/Argentina/Buenos_Aires"));
+        useCase = new GestionarCajasUseCase(cajaRepository, jornadaCajaRepository, jornadaComercialRepository,
+            pedidoRepository, movimientoCajaRepository, cajaContextProvider, clock);
+
+        localId = new LocalId(UUID.randomUUID());
+        jornada = new JornadaCaja(JornadaCajaId.generate(), localId, new BigDecimal("10000"), ahora.minusHours(10));
+        barra = new Caja(CajaId.generate(), localId, "Barra", ahora.minusDays(30));
+        milanesa = new Producto(ProductoId.generate(), localId, "Milanesa", new BigDecimal("5000"), true, "#AA5500");
+    }
+
+    @Test
+    @DisplayName("El cierre tiene el arqueo de cada caja y el efectivo de todos los cajones")
+    void deberia_consolidar_el_arqueo_por_caja() {
+        // Given: la principal arranca con $10.000 y la barra con $2.000
+        when(jornadaCajaRepository.buscarPorId(jornada.getId())).thenReturn(Optional.of(jornada));
+        when(cajaRepository.buscarAperturas(jornada.getId(), localId)).thenReturn(List.of(
+            new AperturaCaja(localId, jornada.getId(), barra.getId(), new BigDecimal("2000"), ahora.minusHours(9))
+        ));
+        when(cajaRepository.buscarPorId(barra.getId(), localId)).thenReturn(Optional.of(barra));
+
+        // La principal cobra una milanesa en efectivo; la barra dos y paga hielo
+        when(pedidoRepository.buscarCerradosPorFecha(eq(localId), any(), any())).thenReturn(List.of(
+            pedidoCobrado(1, null),
+            pedidoCobrado(2, barra.getId()),
+            pedidoCobrado(3, barra.getId())
+        ));
+        when(movimientoCajaRepository.buscarPorFecha(eq(localId), any(), any())).thenReturn(List.of(
+            new MovimientoCaja(MovimientoCajaId.generate(), localId, new BigDecimal("500"), "Hielo",
+                ahora.minusHours(2), TipoMovimiento.EGRESO, null, null, barra.getId())
+        ));
+
+        // When
+        List<ArqueoCajaResponse> arqueo = useCase.arqueo(jornada.getId(), localId);
+
+        // Then: 10.000 + 5.000 en la principal; 2.000 + 10.000 − 500 en la barra
+        assertThat(arqueo).extracting(ArqueoCajaResponse::nombre)
+            .containsExactly(GestionarCajasUseCase.NOMBRE_CAJA_PRINCIPAL, "Barra");
+        assertThat(arqueo.get(0).balanceEfectivo()).isEqualByComparingTo("15000");
+        assertThat(arqueo.get(0).pedidos()).isEqualTo(1);
+        assertThat(arqueo.get(1).ventasEfectivo()).isEqualByComparingTo("10000");
+        assertThat(arqueo.get(1).egresos()).isEqualByComparingTo("500");
+        assertThat(arqueo.get(1).balanceEfectivo()).isEqualByComparingTo("11500");
+
+        CierreJornadaResponse cierre = new CierreJornadaResponse(jornada.getId().getValue()).conCajas(arqueo);
+        assertThat(cierre.efectivoTotal()).isEqualByComparingTo("26500");
+    }
+
+    @Test
+    @DisplayName("Un pago a cuenta suma en la caja que lo cobró aunque la mesa se cierre en otra")
+    void deberia_arquear_cada_pago_en_su_caja() {
+        // Given: la barra cobra .000 a cuenta y la principal cierra la mesa con el resto
+        when(jornadaCajaRepository.buscarPorId(jornada.getId())).thenReturn(Optional.of(jornada));
+        when(cajaRepository.buscarAperturas(jornada.getId(), localId)).thenReturn(List.of(
+            new AperturaCaja(localId, jornada.getId(), barra.getId(), new BigDecimal("2000"), ahora.minusHours(9))
+        ));
+        when(cajaRepository.buscarPorId(barra.getId(), localId)).thenReturn(Optional.of(barra));
+
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 1,
+            EstadoPedido.ABIERTO, ahora.minusHours(3));
+        pedido.agregarProducto(milanesa, 1, null);
+        pedido.registrarPagoACuenta(new Pago(MedioPago.EFECTIVO, new BigDecimal("2000"), ahora.minusHours(2))
+            .conCaja(barra.getId()));
+        pedido.cerrar(List.of(new Pago(MedioPago.EFECTIVO, new BigDecimal("3000"), ahora.minusHours(1))),
+            ahora.minusHours(1));
+        when(pedidoRepository.buscarCerradosPorFecha(eq(localId), any(), any())).thenReturn(List.of(pedido));
+        when(movimientoCajaRepository.buscarPorFecha(eq(localId), any(), any())).thenReturn(List.of());
+
+        // When
+        List<ArqueoCajaResponse> arqueo = useCase.arqueo(jornada.getId(), localId);
+
+        // Then: el pedido cuenta en la principal; el efectivo, en cada cajón
+        assertThat(arqueo.get(0).pedidos()).isEqualTo(1);
+        assertThat(arqueo.get(0).ventasEfectivo()).isEqualByComparingTo("3000");
+        assertThat(arqueo.get(1).pedidos()).isZero();
+        assertThat(arqueo.get(1).ventasEfectivo()).isEqualByComparingTo("2000");
+    }
+
+    @Test
+    @DisplayName("Una caja que no declaró su fondo en la jornada no puede cobrar")
+    void deberia_rechazar_operar_con_una_caja_sin_abrir() {
+        // Given
+        when(cajaContextProvider.getCajaActual()).thenReturn(Optional.of(barra.getId()));
+        when(cajaRepository.buscarPorId(barra.getId(), localId)).thenReturn(Optional.of(barra));
+        when(jornadaCajaRepository.buscarAbierta(localId)).thenReturn(Optional.of(jornada));
+        when(cajaRepository.buscarApertura(jornada.getId(), barra.getId(), localId)).thenReturn(Optional.empty());
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.cajaActual(localId))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("Barra");
+    }
+
+    @Test
+    @DisplayName("Una terminal sin caja registrada opera la caja principal")
+    void deberia_usar_la_caja_principal_sin_caja_registrada() {
+        // Given
+        when(cajaContextProvider.getCajaActual()).thenReturn(Optional.empty());
+
+        // When / Then
+        assertThat(useCase.cajaActual(localId)).isNull();
+        verifyNoInteractions(cajaRepository);
+    }
+
+    @Test
+    @DisplayName("Una caja se abre una sola vez por jornada")
+    void deberia_rechazar_segunda_apertura_en_la_jornada() {
+        // Given
+        when(cajaRepository.buscarPorId(barra.getId(), localId)).thenReturn(Optional.of(barra));
+        when(jornadaCajaRepository.buscarAbierta(localId)).thenReturn(Optional.of(jornada));
+        when(cajaRepository.buscarApertura(jornada.getId(), barra.getId(), localId)).thenReturn(Optional.of(
+            new AperturaCaja(localId, jornada.getId(), barra.getId(), new BigDecimal("2000"), ahora.minusHours(9))
+        ));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.abrir(localId, barra.getId(), new BigDecimal("3000")))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("ya se abrió");
+        verify(cajaRepository, never()).guardarApertura(any());
+    }
+
+    @Test
+    @DisplayName("No se registran dos cajas con el mismo nombre")
+    void deberia_rechazar_nombre_repetido() {
+        // Given
+        when(cajaRepository.existePorNombre(localId, " barra ")).thenReturn(true);
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.registrar(localId, " barra "))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("barra");
+        verify(cajaRepository, never()).guardar(any());
+    }
+
+    // ── Helpers ──
+
+    private Pedido pedidoCobrado(int numero, CajaId cajaId) {
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), numero,
+            EstadoPedido.ABIERTO, ahora.minusHours(3));
+        pedido.agregarProducto(milanesa, 1, null);
+        pedido.cerrar(List.of(new Pago(MedioPago.EFECTIVO, pedido.calcularTotal(), ahora.minusHours(1))
+            .conCaja(cajaId)), ahora.minusHours(1));
+        if (cajaId != null) {
+            pedido.asignarCaja(cajaId);
+        }
+        return pedido;
+    }
+}
@@ -0,0 +1,98 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.CategoriaEgresoRequest;
+import com.agustinpalma.comandas.application.dto.CategoriaEgresoResponse;
+import com.agustinpalma.comandas.domain.model.CategoriaEgreso;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.repository.CategoriaEgresoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.ArgumentCaptor;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.*;
+
+/**
+ * Tests unitarios para GestionarCategoriasEgresoUseCase.
+ * HU-130: categorías configurables de los egresos de caja.
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("GestionarCategoriasEgresoUseCase - Tests de comportamiento")
+class GestionarCategoriasEgresoUseCaseTest {
+
+    @Mock
+    private CategoriaEgresoRepository categoriaEgresoRepository;
+
+    private GestionarCategoriasEgresoUseCase useCase;
+
+    private LocalId localId;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new GestionarCategoriasEgresoUseCase(categoriaEgresoRepository);
+        localId = new LocalId(UUID.randomUUID());
+    }
+
+    @Test
+    @DisplayName("La primera consulta crea las categorías de uso habitual")
+    void deberia_crear_categorias_iniciales_en_la_primera_consulta() {
+        // Given
+        List<CategoriaEgreso> iniciales = CategoriaEgreso.NOMBRES_INICIALES.stream()
+            .map(nombre -> CategoriaEgreso.crear(localId, nombre))
+            .toList();
+        when(categoriaEgresoRepository.buscarPorLocal(localId)).thenReturn(List.of(), iniciales);
+        when(categoriaEgresoRepository.guardar(any())).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        List<CategoriaEgresoResponse> categorias = useCase.listar(localId);
+
+        // Then
+        ArgumentCaptor<CategoriaEgreso> captor = ArgumentCaptor.forClass(CategoriaEgreso.class);
+        verify(categoriaEgresoRepository, times(4)).guardar(captor.capture());
+        assertThat(captor.getAllValues()).extracting(CategoriaEgreso::getNombre)
+            .containsExactly("Limpieza", "Mercadería", "Mantenimiento", "Adelantos de sueldo");
+        assertThat(categorias).hasSize(4).allMatch(CategoriaEgresoResponse::activa);
+    }
+
+    @Test
+    @DisplayName("No se puede repetir el nombre de una categoría, sin importar mayúsculas")
+    void no_deberia_repetir_nombre() {
+        // Given
+        when(categoriaEgresoRepository.buscarPorLocal(localId))
+            .thenReturn(List.of(CategoriaEgreso.crear(localId, "Limpieza")));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.crear(localId, new CategoriaEgresoRequest(" limpieza ", null)))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("Ya existe");
+        verify(categoriaEgresoRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("Desactivar una categoría conserva su nombre")
+    void deberia_desactivar_categoria() {
+        // Given
+        CategoriaEgreso limpieza = CategoriaEgreso.crear(localId, "Limpieza");
+        when(categoriaEgresoRepository.buscarPorId(limpieza.getId(), localId)).thenReturn(Optional.of(limpieza));
+        when(categoriaEgresoRepository.buscarPorLocal(localId)).thenReturn(List.of(limpieza));
+        when(categoriaEgresoRepository.guardar(any())).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        CategoriaEgresoResponse response = useCase.actualizar(localId, limpieza.getId(),
+            new CategoriaEgresoRequest("Limpieza", false));
+
+        // Then
+        assertThat(response.activa()).isFalse();
+        assertThat(response.nombre()).isEqualTo("Limpieza");
+    }
+}
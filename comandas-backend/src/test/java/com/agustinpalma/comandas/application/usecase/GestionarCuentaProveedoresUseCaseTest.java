@@ -0,0 +1,188 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.FacturaProveedorResponse;
+import com.agustinpalma.comandas.application.dto.PagoProveedorRequest;
+import com.agustinpalma.comandas.application.dto.SaldoProveedorResponse;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaGasto;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoFacturaProveedor;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimiento;
+import com.agustinpalma.comandas.domain.model.DomainIds.GastoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.FacturaProveedor;
+import com.agustinpalma.comandas.domain.model.Gasto;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.model.Proveedor;
+import com.agustinpalma.comandas.domain.repository.FacturaProveedorRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.ProveedorRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.ArgumentCaptor;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarCuentaProveedoresUseCase.
+ * Valida los criterios de la HU-131 (Cuenta corriente de proveedores).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Cuenta Corriente de Proveedores - Caso de Uso")
+class GestionarCuentaProveedoresUseCaseTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 4, 6, 10, 0);
+
+    @Mock
+    private FacturaProveedorRepository facturaProveedorRepository;
+
+    @Mock
+    private ProveedorRepository proveedorRepository;
+
+    @Mock
+    private MovimientoCajaRepository movimientoCajaRepository;
+
+    @Mock
+    private TurnoCajaRepository turnoCajaRepository;
+
+    private GestionarCuentaProveedoresUseCase useCase;
+
+    private LocalId localId;
+    private Proveedor distribuidora;
+    private Proveedor verduleria;
+
+    @BeforeEach
+    void setUp() {
+        ZoneId zona = ZoneId.of("America/Argentina/Buenos_Aires");
+        Clock clock = Clock.fixed(AHORA.atZone(zona).toInstant(), zona);
+        useCase = new GestionarCuentaProveedoresUseCase(facturaProveedorRepository, proveedorRepository,
+                movimientoCajaRepository, turnoCajaRepository, clock);
+        localId = new LocalId(UUID.randomUUID());
+        distribuidora = Proveedor.crear(localId, "Distribuidora Norte", null, null, 30);
+        verduleria = Proveedor.crear(localId, "Verdulería Don Tito", null, null, 7);
+    }
+
+    private FacturaProveedor factura(Proveedor proveedor, String monto, LocalDate emision) {
+        Gasto compra = new Gasto(GastoId.generate(), localId, emision, CategoriaGasto.MERCADERIA,
+                proveedor.getNombre(), null, new BigDecimal(monto), MedioPago.CUENTA_CORRIENTE, List.of(),
+                emision.atTime(9, 0), null, null);
+        return FacturaProveedor.desdeCompra(compra, proveedor.getId(), null, proveedor.vencimientoPara(emision));
+    }
+
+    @Test
+    @DisplayName("Pago parcial: descuenta del saldo y deja la factura pagada en parte")
+    void deberia_asentar_un_pago_parcial() {
+        // Given
+        FacturaProveedor factura = factura(distribuidora, "120000", LocalDate.of(2026, 3, 20));
+        when(facturaProveedorRepository.buscarPorId(factura.getId(), localId)).thenReturn(Optional.of(factura));
+        when(proveedorRepository.buscarPorId(distribuidora.getId(), localId)).thenReturn(Optional.of(distribuidora));
+        when(facturaProveedorRepository.guardar(any(FacturaProveedor.class))).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        FacturaProveedorResponse response = useCase.registrarPago(localId, factura.getId(),
+                new PagoProveedorRequest(new BigDecimal("50000"), MedioPago.TRANSFERENCIA, false));
+
+        // Then
+        assertThat(response.estado()).isEqualTo(EstadoFacturaProveedor.PAGADA_PARCIAL);
+        assertThat(response.saldo()).isEqualByComparingTo("70000");
+        assertThat(response.pagos()).singleElement()
+                .satisfies(p -> assertThat(p.pagadoConCaja()).isFalse());
+        verifyNoInteractions(movimientoCajaRepository);
+    }
+
+    @Test
+    @DisplayName("Pago en efectivo con la caja: registra el egreso y lo asocia al pago")
+    void deberia_registrar_egreso_de_caja_si_el_pago_sale_de_la_caja() {
+        // Given
+        FacturaProveedor factura = factura(verduleria, "18000", LocalDate.of(2026, 4, 1));
+        when(facturaProveedorRepository.buscarPorId(factura.getId(), localId)).thenReturn(Optional.of(factura));
+        when(proveedorRepository.buscarPorId(verduleria.getId(), localId)).thenReturn(Optional.of(verduleria));
+        when(turnoCajaRepository.buscarAbierto(localId)).thenReturn(Optional.empty());
+        when(movimientoCajaRepository.guardar(any(MovimientoCaja.class))).thenAnswer(inv -> inv.getArgument(0));
+        when(facturaProveedorRepository.guardar(any(FacturaProveedor.class))).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        FacturaProveedorResponse response = useCase.registrarPago(localId, factura.getId(),
+                new PagoProveedorRequest(new BigDecimal("18000"), MedioPago.EFECTIVO, true));
+
+        // Then
+        ArgumentCaptor<MovimientoCaja> egreso = ArgumentCaptor.forClass(MovimientoCaja.class);
+        verify(movimientoCajaRepository).guardar(egreso.capture());
+        assertThat(egreso.getValue().getTipo()).isEqualTo(TipoMovimiento.EGRESO);
+        assertThat(egreso.getValue().getDescripcion()).isEqualTo("Pago a proveedor - Verdulería Don Tito");
+        assertThat(factura.getPagos().get(0).getMovimientoCajaId()).isEqualTo(egreso.getValue().getId());
+        assertThat(response.estado()).isEqualTo(EstadoFacturaProveedor.PAGADA);
+    }
+
+    @Test
+    @DisplayName("Un pago mayor al saldo se rechaza sin mover la caja")
+    void deberia_rechazar_pago_mayor_al_saldo_sin_mover_la_caja() {
+        // Given
+        FacturaProveedor factura = factura(verduleria, "18000", LocalDate.of(2026, 4, 1));
+        when(facturaProveedorRepository.buscarPorId(factura.getId(), localId)).thenReturn(Optional.of(factura));
+        when(proveedorRepository.buscarPorId(verduleria.getId(), localId)).thenReturn(Optional.of(verduleria));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.registrarPago(localId, factura.getId(),
+                new PagoProveedorRequest(new BigDecimal("20000"), MedioPago.EFECTIVO, true)))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("supera el saldo");
+        verifyNoInteractions(movimientoCajaRepository);
+        verify(facturaProveedorRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("Saldos: primero el proveedor con deuda vencida, aunque deba menos")
+    void deberia_listar_primero_los_saldos_vencidos() {
+        // Given: la distribuidora vence el 19/04; la verdulería venció el 02/04
+        FacturaProveedor grande = factura(distribuidora, "300000", LocalDate.of(2026, 3, 20));
+        FacturaProveedor vencida = factura(verduleria, "25000", LocalDate.of(2026, 3, 26));
+        when(facturaProveedorRepository.buscarImpagas(localId)).thenReturn(List.of(vencida, grande));
+        when(proveedorRepository.buscarPorLocal(localId)).thenReturn(List.of(distribuidora, verduleria));
+
+        // When
+        List<SaldoProveedorResponse> saldos = useCase.listarSaldos(localId);
+
+        // Then
+        assertThat(saldos).extracting(s -> s.proveedor().nombre())
+                .containsExactly("Verdulería Don Tito", "Distribuidora Norte");
+        assertThat(saldos.get(0).saldoVencido()).isEqualByComparingTo("25000");
+        assertThat(saldos.get(1).saldoVencido()).isEqualByComparingTo("0");
+        assertThat(saldos.get(1).proximoVencimiento()).isEqualTo(LocalDate.of(2026, 4, 19));
+    }
+
+    @Test
+    @DisplayName("Vencimientos: avisa las vencidas y las que vencen dentro de la anticipación pedida")
+    void deberia_avisar_vencidas_y_proximas_a_vencer() {
+        // Given
+        FacturaProveedor vencida = factura(verduleria, "25000", LocalDate.of(2026, 3, 26));     // venció 02/04
+        FacturaProveedor porVencer = factura(verduleria, "9000", LocalDate.of(2026, 4, 1));     // vence 08/04
+        FacturaProveedor lejana = factura(distribuidora, "300000", LocalDate.of(2026, 3, 20));  // vence 19/04
+        when(facturaProveedorRepository.buscarImpagas(localId)).thenReturn(List.of(vencida, porVencer, lejana));
+        when(proveedorRepository.buscarPorLocal(localId)).thenReturn(List.of(distribuidora, verduleria));
+
+        // When
+        List<FacturaProveedorResponse> avisos = useCase.consultarVencimientos(localId, 3);
+
+        // Then
+        assertThat(avisos).extracting(FacturaProveedorResponse::diasParaVencer).containsExactly(-4L, 2L);
+        assertThat(avisos.get(0).vencida()).isTrue();
+        assertThat(avisos).allSatisfy(a -> assertThat(a.nombreProveedor()).isEqualTo("Verdulería Don Tito"));
+    }
+}
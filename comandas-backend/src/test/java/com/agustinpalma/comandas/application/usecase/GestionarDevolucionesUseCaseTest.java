@@ -0,0 +1,169 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.DevolucionRequest;
+import com.agustinpalma.comandas.application.dto.DevolucionesPedidoResponse;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.ArgumentCaptor;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarDevolucionesUseCase.
+ * Valida los criterios de la HU-121 (Devoluciones y reintegros post-cierre).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Gestionar Devoluciones - Caso de Uso")
+class GestionarDevolucionesUseCaseTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 3, 10, 23, 30);
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private MozoRepository mozoRepository;
+
+    @Mock
+    private MovimientoCajaRepository movimientoCajaRepository;
+
+    @Mock
+    private TurnoCajaRepository turnoCajaRepository;
+
+    private GestionarDevolucionesUseCase useCase;
+
+    private LocalId localId;
+    private Pedido pedido;
+    private Mozo encargado;
+
+    @BeforeEach
+    void setUp() {
+        ZoneId zona = ZoneId.of("America/Argentina/Buenos_Aires");
+        Clock clock = Clock.fixed(AHORA.atZone(zona).toInstant(), zona);
+        useCase = new GestionarDevolucionesUseCase(pedidoRepository, mozoRepository, movimientoCajaRepository,
+                turnoCajaRepository, clock);
+        localId = new LocalId(UUID.randomUUID());
+
+        Producto milanesa = new Producto(ProductoId.generate(), localId, "Milanesa",
+                new BigDecimal("12000"), true, "#AA5500");
+        pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 7,
+                EstadoPedido.ABIERTO, AHORA.minusHours(2));
+        pedido.agregarProducto(milanesa, 1, null);
+        pedido.finalizar(MedioPago.EFECTIVO, AHORA.minusHours(1));
+
+        encargado = new Mozo(MozoId.generate(), localId, "Laura", true, RolPersonal.ENCARGADO, null);
+        encargado.cambiarPin("4321");
+
+        when(pedidoRepository.buscarPorId(pedido.getId())).thenReturn(Optional.of(pedido));
+    }
+
+    private DevolucionRequest request(String monto, String pin) {
+        return new DevolucionRequest(new BigDecimal(monto), "Milanesa en mal estado",
+                encargado.getId().getValue().toString(), pin);
+    }
+
+    @Test
+    @DisplayName("Registra un egreso asociado al pedido y al encargado que autorizó")
+    void deberia_registrar_egreso_asociado_al_pedido() {
+        // Given
+        when(mozoRepository.buscarPorId(encargado.getId(), localId)).thenReturn(Optional.of(encargado));
+        when(mozoRepository.buscarPorLocal(localId)).thenReturn(List.of(encargado));
+        when(turnoCajaRepository.buscarAbierto(localId)).thenReturn(Optional.empty());
+        ArgumentCaptor<MovimientoCaja> captor = ArgumentCaptor.forClass(MovimientoCaja.class);
+        when(movimientoCajaRepository.guardar(captor.capture())).thenAnswer(inv -> inv.getArgument(0));
+        when(movimientoCajaRepository.buscarDevolucionesPorPedido(localId, pedido.getId()))
+                .thenReturn(List.of())
+                .thenAnswer(inv -> List.of(captor.getValue()));
+
+        // When
+        DevolucionesPedidoResponse response = useCase.registrar(localId, pedido.getId(), request("12000", "4321"));
+
+        // Then
+        MovimientoCaja egreso = captor.getValue();
+        assertThat(egreso.esDevolucion()).isTrue();
+        assertThat(egreso.getPedidoId()).isEqualTo(pedido.getId());
+        assertThat(egreso.getAutorizadoPorId()).isEqualTo(encargado.getId());
+        assertThat(egreso.getFecha()).isEqualTo(AHORA);
+        assertThat(egreso.getDescripcion()).contains("Milanesa en mal estado");
+        assertThat(response.totalDevuelto()).isEqualByComparingTo("12000");
+        assertThat(response.disponible()).isEqualByComparingTo("0");
+        assertThat(response.devoluciones()).singleElement()
+                .satisfies(d -> assertThat(d.autorizadoPor()).isEqualTo("Laura"));
+    }
+
+    @Test
+    @DisplayName("Rechaza la devolución si el PIN del encargado no coincide")
+    void deberia_rechazar_pin_incorrecto() {
+        // Given
+        when(mozoRepository.buscarPorId(encargado.getId(), localId)).thenReturn(Optional.of(encargado));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.registrar(localId, pedido.getId(), request("5000", "1111")))
+                .isInstanceOf(IllegalArgumentException.class)
+                .hasMessageContaining("PIN");
+        verify(movimientoCajaRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("Rechaza la devolución si un mozo sin rol de encargado intenta autorizarla")
+    void deberia_rechazar_autorizacion_de_un_mozo() {
+        // Given
+        Mozo mozo = new Mozo(encargado.getId(), localId, "Pedro", true);
+        mozo.cambiarPin("4321");
+        when(mozoRepository.buscarPorId(encargado.getId(), localId)).thenReturn(Optional.of(mozo));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.registrar(localId, pedido.getId(), request("5000", "4321")))
+                .isInstanceOf(IllegalStateException.class)
+                .hasMessageContaining("no es un encargado");
+        verify(movimientoCajaRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("No permite devolver más de lo cobrado, descontando devoluciones anteriores")
+    void deberia_rechazar_monto_mayor_al_disponible() {
+        // Given: ya se devolvieron $8.000 de $12.000
+        when(mozoRepository.buscarPorId(encargado.getId(), localId)).thenReturn(Optional.of(encargado));
+        MovimientoCaja previa = MovimientoCaja.devolucion(localId, pedido.getId(), new BigDecimal("8000"),
+                "Devolución pedido #7: bebida", AHORA.minusMinutes(30), null, encargado.getId());
+        when(movimientoCajaRepository.buscarDevolucionesPorPedido(localId, pedido.getId()))
+                .thenReturn(List.of(previa));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.registrar(localId, pedido.getId(), request("5000", "4321")))
+                .isInstanceOf(IllegalArgumentException.class)
+                .hasMessageContaining("Disponible para devolver: $4000");
+        verify(movimientoCajaRepository, never()).guardar(any());
+    }
+}
@@ -0,0 +1,86 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.EstadoEnergiaEvento;
+import com.agustinpalma.comandas.application.dto.EstadoEnergiaEvento.Estado;
+import com.agustinpalma.comandas.application.ports.output.ApagadoAplicacionPort;
+import com.agustinpalma.comandas.application.ports.output.NotificadorTiempoRealPort;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.InOrder;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.anyString;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarEnergiaUseCase.
+ * Valida los criterios de la HU-155 (cortes de luz con apagado ordenado).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Gestionar Energía - Caso de Uso")
+class GestionarEnergiaUseCaseTest {
+
+    @Mock
+    private NotificadorTiempoRealPort notificador;
+
+    @Mock
+    private ApagadoAplicacionPort apagadoAplicacion;
+
+    private GestionarEnergiaUseCase useCase;
+
+    private LocalId localId;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new GestionarEnergiaUseCase(notificador, apagadoAplicacion);
+        localId = new LocalId(UUID.randomUUID());
+    }
+
+    @Test
+    @DisplayName("Un corte de luz se avisa a todas las terminales del local")
+    void deberia_difundir_el_corte() {
+        // Given
+        EstadoEnergiaEvento corte = new EstadoEnergiaEvento(Estado.SIN_RED, 85, 60);
+
+        // When
+        useCase.notificar(localId, corte);
+
+        // Then
+        verify(notificador).publicar(localId, EstadoEnergiaEvento.NOMBRE, corte);
+        verifyNoInteractions(apagadoAplicacion);
+    }
+
+    @Test
+    @DisplayName("Una batería fuera de 0-100 se rechaza sin notificar")
+    void deberia_rechazar_bateria_invalida() {
+        // Given
+        EstadoEnergiaEvento corte = new EstadoEnergiaEvento(Estado.SIN_RED, 140, null);
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.notificar(localId, corte))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("batería");
+        verify(notificador, never()).publicar(any(), anyString(), any());
+    }
+
+    @Test
+    @DisplayName("Apagar avisa primero a las terminales y después apaga el backend")
+    void deberia_avisar_antes_de_apagar() {
+        // When
+        useCase.apagar(localId);
+
+        // Then
+        InOrder orden = inOrder(notificador, apagadoAplicacion);
+        orden.verify(notificador).publicar(localId, EstadoEnergiaEvento.NOMBRE,
+            new EstadoEnergiaEvento(Estado.APAGANDO, null, 0));
+        orden.verify(apagadoAplicacion).apagar();
+    }
+}
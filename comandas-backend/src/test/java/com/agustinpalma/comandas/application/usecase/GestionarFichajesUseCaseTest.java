@@ -0,0 +1,189 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.CorreccionFichajeRequest;
+import com.agustinpalma.comandas.application.dto.FichajeResponse;
+import com.agustinpalma.comandas.application.dto.MarcaFichajeResponse;
+import com.agustinpalma.comandas.application.dto.MarcarFichajeRequest;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioFichaje;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.FichajeId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.Fichaje;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.repository.FichajeRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.ArgumentCaptor;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarFichajesUseCase.
+ * Valida los criterios de la HU-124 (Fichaje de personal).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Gestionar Fichajes - Caso de Uso")
+class GestionarFichajesUseCaseTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 3, 10, 18, 0);
+
+    @Mock
+    private FichajeRepository fichajeRepository;
+
+    @Mock
+    private MozoRepository mozoRepository;
+
+    private GestionarFichajesUseCase useCase;
+
+    private LocalId localId;
+    private Mozo lucia;
+    private Mozo dueno;
+
+    @BeforeEach
+    void setUp() {
+        ZoneId zona = ZoneId.of("America/Argentina/Buenos_Aires");
+        Clock clock = Clock.fixed(AHORA.atZone(zona).toInstant(), zona);
+        useCase = new GestionarFichajesUseCase(fichajeRepository, mozoRepository, clock);
+        localId = new LocalId(UUID.randomUUID());
+
+        lucia = new Mozo(MozoId.generate(), localId, "Lucía", true);
+        lucia.cambiarPin("1234");
+        lucia.asignarTarjeta("A1B2C3D4");
+
+        dueno = new Mozo(MozoId.generate(), localId, "Agustín", true, RolPersonal.DUENO, null);
+        dueno.cambiarPin("9999");
+    }
+
+    @Test
+    @DisplayName("La primera marca del día es una entrada")
+    void deberia_registrar_entrada_si_no_hay_fichaje_abierto() {
+        // Given
+        when(mozoRepository.buscarPorId(lucia.getId(), localId)).thenReturn(Optional.of(lucia));
+        when(fichajeRepository.buscarAbierto(localId, lucia.getId())).thenReturn(Optional.empty());
+        ArgumentCaptor<Fichaje> captor = ArgumentCaptor.forClass(Fichaje.class);
+        when(fichajeRepository.guardar(captor.capture())).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        MarcaFichajeResponse marca = useCase.marcar(localId,
+                new MarcarFichajeRequest(lucia.getId().getValue().toString(), "1234", null));
+
+        // Then
+        assertThat(marca.tipo()).isEqualTo("ENTRADA");
+        assertThat(marca.nombreEmpleado()).isEqualTo("Lucía");
+        assertThat(captor.getValue().getEntrada()).isEqualTo(AHORA);
+        assertThat(captor.getValue().getMedio()).isEqualTo(MedioFichaje.PIN);
+        assertThat(captor.getValue().estaAbierto()).isTrue();
+    }
+
+    @Test
+    @DisplayName("Con la tarjeta cierra el fichaje abierto y devuelve las horas trabajadas")
+    void deberia_registrar_salida_con_tarjeta() {
+        // Given: entró a las 10:00
+        Fichaje abierto = new Fichaje(FichajeId.generate(), localId, lucia.getId(), MedioFichaje.PIN,
+                AHORA.minusHours(8));
+        when(mozoRepository.buscarPorTarjeta(localId, "A1B2C3D4")).thenReturn(Optional.of(lucia));
+        when(fichajeRepository.buscarAbierto(localId, lucia.getId())).thenReturn(Optional.of(abierto));
+
+        // When
+        MarcaFichajeResponse marca = useCase.marcar(localId, new MarcarFichajeRequest(null, null, "A1B2C3D4"));
+
+        // Then
+        assertThat(marca.tipo()).isEqualTo("SALIDA");
+        assertThat(marca.minutosTrabajados()).isEqualTo(480);
+        assertThat(abierto.getSalida()).isEqualTo(AHORA);
+        verify(fichajeRepository).guardar(abierto);
+    }
+
+    @Test
+    @DisplayName("Una entrada sin salida de hace más de 16 horas queda incompleta y se abre un fichaje nuevo")
+    void deberia_abrir_fichaje_nuevo_si_el_anterior_esta_vencido() {
+        // Given: se olvidó de fichar la salida ayer
+        Fichaje olvidado = new Fichaje(FichajeId.generate(), localId, lucia.getId(), MedioFichaje.PIN,
+                AHORA.minusHours(20));
+        when(mozoRepository.buscarPorId(lucia.getId(), localId)).thenReturn(Optional.of(lucia));
+        when(fichajeRepository.buscarAbierto(localId, lucia.getId())).thenReturn(Optional.of(olvidado));
+        when(fichajeRepository.guardar(any())).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        MarcaFichajeResponse marca = useCase.marcar(localId,
+                new MarcarFichajeRequest(lucia.getId().getValue().toString(), "1234", null));
+
+        // Then
+        assertThat(marca.tipo()).isEqualTo("ENTRADA");
+        assertThat(marca.quedoIncompleto()).isTrue();
+        assertThat(olvidado.estaAbierto()).isTrue();
+    }
+
+    @Test
+    @DisplayName("Rechaza la marca si el PIN no coincide")
+    void deberia_rechazar_pin_incorrecto() {
+        // Given
+        when(mozoRepository.buscarPorId(lucia.getId(), localId)).thenReturn(Optional.of(lucia));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.marcar(localId,
+                new MarcarFichajeRequest(lucia.getId().getValue().toString(), "0000", null)))
+                .isInstanceOf(IllegalArgumentException.class)
+                .hasMessageContaining("PIN");
+        verify(fichajeRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("El dueño corrige la salida olvidada y se conserva la marca original")
+    void deberia_corregir_fichaje_con_autorizacion_del_dueno() {
+        // Given
+        Fichaje olvidado = new Fichaje(FichajeId.generate(), localId, lucia.getId(), MedioFichaje.PIN,
+                AHORA.minusHours(20));
+        when(fichajeRepository.buscarPorId(olvidado.getId(), localId)).thenReturn(Optional.of(olvidado));
+        when(mozoRepository.buscarPorId(dueno.getId(), localId)).thenReturn(Optional.of(dueno));
+        when(mozoRepository.buscarPorId(lucia.getId(), localId)).thenReturn(Optional.of(lucia));
+        when(fichajeRepository.guardar(any())).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        FichajeResponse response = useCase.corregir(localId, olvidado.getId(), new CorreccionFichajeRequest(
+                null, AHORA.minusHours(20), AHORA.minusHours(12), "Se olvidó de fichar la salida",
+                dueno.getId().getValue().toString(), "9999"));
+
+        // Then
+        assertThat(response.minutosTrabajados()).isEqualTo(480);
+        assertThat(response.entradaOriginal()).isEqualTo(AHORA.minusHours(20));
+        assertThat(response.salidaOriginal()).isNull();
+        assertThat(response.corregidoPor()).isEqualTo("Agustín");
+        assertThat(response.nombreEmpleado()).isEqualTo("Lucía");
+    }
+
+    @Test
+    @DisplayName("Un encargado no puede corregir fichajes")
+    void deberia_rechazar_correccion_de_un_encargado() {
+        // Given
+        Mozo encargado = new Mozo(MozoId.generate(), localId, "Laura", true, RolPersonal.ENCARGADO, null);
+        encargado.cambiarPin("4321");
+        Fichaje fichaje = new Fichaje(FichajeId.generate(), localId, lucia.getId(), MedioFichaje.PIN,
+                AHORA.minusHours(3));
+        when(fichajeRepository.buscarPorId(fichaje.getId(), localId)).thenReturn(Optional.of(fichaje));
+        when(mozoRepository.buscarPorId(encargado.getId(), localId)).thenReturn(Optional.of(encargado));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.corregir(localId, fichaje.getId(), new CorreccionFichajeRequest(
+                null, AHORA.minusHours(4), AHORA.minusHours(1), "Llegó antes",
+                encargado.getId().getValue().toString(), "4321")))
+                .isInstanceOf(IllegalStateException.class)
+                .hasMessageContaining("Solo el dueño");
+        verify(fichajeRepository, never()).guardar(any());
+    }
+}
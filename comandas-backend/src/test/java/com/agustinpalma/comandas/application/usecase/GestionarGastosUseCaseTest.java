@@ -0,0 +1,203 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ComprobanteRequest;
+import com.agustinpalma.comandas.application.dto.GastoRequest;
+import com.agustinpalma.comandas.application.dto.GastoRequest.ItemCompraRequest;
+import com.agustinpalma.comandas.application.dto.GastoResponse;
+import com.agustinpalma.comandas.domain.model.ComprobanteAdjunto;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaGasto;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimiento;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoStock;
+import com.agustinpalma.comandas.domain.model.DomainIds.GastoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.FacturaProveedor;
+import com.agustinpalma.comandas.domain.model.Gasto;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.model.MovimientoStock;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.model.Proveedor;
+import com.agustinpalma.comandas.domain.repository.FacturaProveedorRepository;
+import com.agustinpalma.comandas.domain.repository.GastoRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoStockRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.domain.repository.ProveedorRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import com.agustinpalma.comandas.domain.service.GestorStockService;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.ArgumentCaptor;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.Base64;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarGastosUseCase.
+ * Valida los criterios de la HU-117 (Compras a proveedores y gastos).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Gestionar Gastos - Caso de Uso")
+class GestionarGastosUseCaseTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 3, 10, 11, 0);
+
+    @Mock
+    private GastoRepository gastoRepository;
+
+    @Mock
+    private ProductoRepository productoRepository;
+
+    @Mock
+    private MovimientoStockRepository movimientoStockRepository;
+
+    @Mock
+    private MovimientoCajaRepository movimientoCajaRepository;
+
+    @Mock
+    private TurnoCajaRepository turnoCajaRepository;
+
+    @Mock
+    private ProveedorRepository proveedorRepository;
+
+    @Mock
+    private FacturaProveedorRepository facturaProveedorRepository;
+
+    private GestionarGastosUseCase useCase;
+
+    private LocalId localId;
+
+    @BeforeEach
+    void setUp() {
+        ZoneId zona = ZoneId.of("America/Argentina/Buenos_Aires");
+        Clock clock = Clock.fixed(AHORA.atZone(zona).toInstant(), zona);
+        useCase = new GestionarGastosUseCase(gastoRepository, productoRepository, movimientoStockRepository,
+                movimientoCajaRepository, turnoCajaRepository, proveedorRepository, facturaProveedorRepository,
+                new GestorStockService(), clock);
+        localId = new LocalId(UUID.randomUUID());
+    }
+
+    private GastoRequest request(CategoriaGasto categoria, MedioPago medio, List<ItemCompraRequest> items,
+                                 boolean pagadoConCaja, ComprobanteRequest comprobante) {
+        return new GastoRequest(LocalDate.of(2026, 3, 9), categoria, "Distribuidora Norte", null,
+                new BigDecimal("45000"), medio, items, pagadoConCaja, comprobante);
+    }
+
+    @Test
+    @DisplayName("Compra de mercadería: ingresa los productos al stock")
+    void deberia_ingresar_stock_de_la_mercaderia_comprada() {
+        // Given
+        Producto coca = new Producto(ProductoId.generate(), localId, "Coca 500",
+                new BigDecimal("2500"), true, "#FF0000");
+        when(productoRepository.buscarPorIdYLocal(coca.getId(), localId)).thenReturn(Optional.of(coca));
+        when(gastoRepository.guardar(any(Gasto.class))).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        GastoResponse response = useCase.registrar(localId, request(CategoriaGasto.MERCADERIA,
+                MedioPago.TRANSFERENCIA, List.of(new ItemCompraRequest(coca.getId().getValue(), 24)), false, null));
+
+        // Then
+        assertThat(coca.getStockActual()).isEqualTo(24);
+        assertThat(coca.isControlaStock()).isTrue();
+        ArgumentCaptor<MovimientoStock> movimiento = ArgumentCaptor.forClass(MovimientoStock.class);
+        verify(movimientoStockRepository).guardar(movimiento.capture());
+        assertThat(movimiento.getValue().getTipo()).isEqualTo(TipoMovimientoStock.INGRESO_MERCADERIA);
+        assertThat(response.items()).singleElement()
+                .satisfies(i -> assertThat(i.nombreProducto()).isEqualTo("Coca 500"));
+        verifyNoInteractions(movimientoCajaRepository);
+    }
+
+    @Test
+    @DisplayName("Pagado con la caja: registra el egreso y lo asocia al gasto")
+    void deberia_registrar_egreso_de_caja_si_se_paga_con_la_caja() {
+        // Given
+        when(turnoCajaRepository.buscarAbierto(localId)).thenReturn(Optional.empty());
+        when(movimientoCajaRepository.guardar(any(MovimientoCaja.class))).thenAnswer(inv -> inv.getArgument(0));
+        when(gastoRepository.guardar(any(Gasto.class))).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        GastoResponse response = useCase.registrar(localId,
+                request(CategoriaGasto.SERVICIOS, MedioPago.EFECTIVO, null, true, null));
+
+        // Then
+        ArgumentCaptor<MovimientoCaja> egreso = ArgumentCaptor.forClass(MovimientoCaja.class);
+        verify(movimientoCajaRepository).guardar(egreso.capture());
+        assertThat(egreso.getValue().getTipo()).isEqualTo(TipoMovimiento.EGRESO);
+        assertThat(egreso.getValue().getMonto()).isEqualByComparingTo("45000");
+        assertThat(egreso.getValue().getDescripcion()).isEqualTo("Servicios - Distribuidora Norte");
+        assertThat(response.pagadoConCaja()).isTrue();
+    }
+
+    @Test
+    @DisplayName("Solo un gasto en efectivo puede salir de la caja")
+    void deberia_rechazar_pagar_con_caja_un_gasto_con_tarjeta() {
+        // When / Then
+        assertThatThrownBy(() -> useCase.registrar(localId,
+                request(CategoriaGasto.ALQUILER, MedioPago.TARJETA, null, true, null)))
+            .isInstanceOf(IllegalStateException.class);
+
+        verifyNoInteractions(movimientoCajaRepository, gastoRepository);
+    }
+
+    @Test
+    @DisplayName("Comprobante adjunto: se guarda el archivo junto con el gasto")
+    void deberia_guardar_el_comprobante_adjunto() {
+        // Given
+        when(gastoRepository.guardar(any(Gasto.class))).thenAnswer(inv -> inv.getArgument(0));
+        byte[] pdf = "%PDF-1.4 factura".getBytes();
+        ComprobanteRequest comprobante = new ComprobanteRequest("factura-luz.pdf", "application/pdf",
+                Base64.getEncoder().encodeToString(pdf));
+
+        // When
+        GastoResponse response = useCase.registrar(localId,
+                request(CategoriaGasto.SERVICIOS, MedioPago.TRANSFERENCIA, null, false, comprobante));
+
+        // Then
+        ArgumentCaptor<ComprobanteAdjunto> archivo = ArgumentCaptor.forClass(ComprobanteAdjunto.class);
+        verify(gastoRepository).guardarComprobante(eq(new GastoId(response.id())), archivo.capture());
+        assertThat(archivo.getValue().getContenido()).isEqualTo(pdf);
+        assertThat(response.nombreArchivoComprobante()).isEqualTo("factura-luz.pdf");
+    }
+
+    @Test
+    @DisplayName("Compra a cuenta corriente: genera la factura a pagar con el plazo del proveedor")
+    void deberia_generar_factura_a_pagar_en_compras_a_cuenta_corriente() {
+        // Given
+        Proveedor proveedor = Proveedor.crear(localId, "Distribuidora Norte", null, null, 15);
+        when(proveedorRepository.buscarPorId(proveedor.getId(), localId)).thenReturn(Optional.of(proveedor));
+        when(gastoRepository.guardar(any(Gasto.class))).thenAnswer(inv -> inv.getArgument(0));
+        GastoRequest request = new GastoRequest(LocalDate.of(2026, 3, 9), CategoriaGasto.MERCADERIA, null, null,
+                new BigDecimal("45000"), MedioPago.CUENTA_CORRIENTE, null, false, null,
+                new GastoRequest.CuentaCorrienteRequest(proveedor.getId().getValue(), "A-0002-00000871", null));
+
+        // When
+        GastoResponse response = useCase.registrar(localId, request);
+
+        // Then
+        ArgumentCaptor<FacturaProveedor> factura = ArgumentCaptor.forClass(FacturaProveedor.class);
+        verify(facturaProveedorRepository).guardar(factura.capture());
+        assertThat(factura.getValue().getGastoId().getValue()).isEqualTo(response.id());
+        assertThat(factura.getValue().getFechaVencimiento()).isEqualTo(LocalDate.of(2026, 3, 24));
+        assertThat(factura.getValue().getSaldo()).isEqualByComparingTo("45000");
+        assertThat(response.proveedor()).isEqualTo("Distribuidora Norte");
+        verifyNoInteractions(movimientoCajaRepository);
+    }
+}
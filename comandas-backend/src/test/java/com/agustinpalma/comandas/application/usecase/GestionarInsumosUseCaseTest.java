@@ -0,0 +1,110 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.ActualizacionInsumoResponse;
+import com.agustinpalma.comandas.application.dto.InsumoRequest;
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.IngredienteReceta;
+import com.agustinpalma.comandas.domain.model.Insumo;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.model.Receta;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.domain.repository.RecetaRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarInsumosUseCase.
+ * Valida los criterios de la HU-118 (Costeo de recetas y rentabilidad por producto).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Gestionar Insumos - Caso de Uso")
+class GestionarInsumosUseCaseTest {
+
+    @Mock
+    private InsumoRepository insumoRepository;
+
+    @Mock
+    private RecetaRepository recetaRepository;
+
+    @Mock
+    private ProductoRepository productoRepository;
+
+    private GestionarInsumosUseCase useCase;
+
+    private LocalId localId;
+    private Insumo queso;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new GestionarInsumosUseCase(insumoRepository, recetaRepository, productoRepository);
+        localId = new LocalId(UUID.randomUUID());
+        queso = new Insumo(InsumoId.generate(), localId, "Queso cheddar", UnidadInsumo.KILOGRAMO,
+                new BigDecimal("10000"));
+    }
+
+    private Producto producto(String nombre, String precio) {
+        return new Producto(ProductoId.generate(), localId, nombre, new BigDecimal(precio), true, null);
+    }
+
+    private Receta recetaConQueso(Producto producto, String kilos) {
+        return new Receta(producto.getId(), localId, List.of(new IngredienteReceta(queso.getId(), new BigDecimal(kilos))));
+    }
+
+    @Test
+    @DisplayName("Una suba del insumo informa los productos que pasan a venderse a pérdida")
+    void deberia_informar_productos_que_quedan_a_perdida() {
+        // Given: el cheddar sube de $10.000 a $25.000 el kilo
+        Producto fritas = producto("Papas con cheddar", "2500");   // 0.120 kg: 1200 -> 3000 (pasa a pérdida)
+        Producto burger = producto("Cheeseburger", "7000");         // 0.040 kg: 400 -> 1000 (sigue ganando)
+        Producto nachos = producto("Nachos", "1500");               // 0.200 kg: 2000 -> 5000 (ya estaba a pérdida)
+        when(insumoRepository.buscarPorLocal(localId)).thenReturn(List.of(queso));
+        when(recetaRepository.buscarPorLocal(localId)).thenReturn(List.of(
+                recetaConQueso(fritas, "0.120"), recetaConQueso(burger, "0.040"), recetaConQueso(nachos, "0.200")
+        ));
+        when(productoRepository.buscarPorLocal(localId)).thenReturn(List.of(fritas, burger, nachos));
+        when(insumoRepository.guardar(any(Insumo.class))).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        ActualizacionInsumoResponse response = useCase.actualizar(localId, queso.getId(),
+                new InsumoRequest("Queso cheddar", UnidadInsumo.KILOGRAMO, new BigDecimal("25000")));
+
+        // Then
+        assertThat(response.insumo().costoUnitario()).isEqualByComparingTo("25000");
+        assertThat(response.productosAPerdida()).singleElement().satisfies(p -> {
+            assertThat(p.nombre()).isEqualTo("Papas con cheddar");
+            assertThat(p.costo()).isEqualByComparingTo("3000");
+            assertThat(p.margen()).isEqualByComparingTo("-500");
+            assertThat(p.aPerdida()).isTrue();
+        });
+    }
+
+    @Test
+    @DisplayName("Editar un insumo de otro local falla")
+    void deberia_rechazar_insumo_inexistente_en_el_local() {
+        when(insumoRepository.buscarPorLocal(localId)).thenReturn(List.of());
+
+        assertThatThrownBy(() -> useCase.actualizar(localId, queso.getId(),
+                new InsumoRequest("Queso cheddar", UnidadInsumo.KILOGRAMO, BigDecimal.TEN)))
+                .isInstanceOf(IllegalArgumentException.class)
+                .hasMessageContaining("no existe");
+
+        verify(insumoRepository, never()).guardar(any());
+    }
+}
@@ -0,0 +1,224 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.GenerarLinkPagoRequest;
+import com.agustinpalma.comandas.application.dto.LinkPagoResponse;
+import com.agustinpalma.comandas.application.ports.output.NotificadorTiempoRealPort;
+import com.agustinpalma.comandas.application.ports.output.PasarelaPagoPort;
+import com.agustinpalma.comandas.application.ports.output.PasarelaPagoPort.LinkGenerado;
+import com.agustinpalma.comandas.application.ports.output.PasarelaPagoPort.PagoAprobado;
+import com.agustinpalma.comandas.application.ports.output.PasarelaPagoPort.PasarelaPagoNoDisponibleException;
+import com.agustinpalma.comandas.application.ports.output.PasarelaPagoPort.SolicitudLink;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CanalVenta;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoLinkPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.ProveedorLinkPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LinkPagoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.LinkPago;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.LinkPagoRepository;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.ArgumentCaptor;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.*;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarLinksPagoUseCase.
+ * Valida los criterios de la HU-128 (links de pago para delivery).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Links de Pago - Caso de Uso")
+class GestionarLinksPagoUseCaseTest {
+
+    private static final String URL_LINK = "https://www.mercadopago.com.ar/checkout/v1/redirect?pref_id=123";
+
+    @Mock
+    private LinkPagoRepository linkPagoRepository;
+
+    @Mock
+    private MesaRepository mesaRepository;
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private PasarelaPagoPort pasarelaPago;
+
+    @Mock
+    private EnviarComandaCocinaUseCase enviarComandaCocinaUseCase;
+
+    @Mock
+    private NotificadorTiempoRealPort notificador;
+
+    private GestionarLinksPagoUseCase useCase;
+
+    private LocalId localId;
+    private Mesa mesa;
+    private Pedido pedido;
+
+    @BeforeEach
+    void setUp() {
+        Clock clock = Clock.fixed(Instant.parse("2026-03-14T21:30:00Z"), ZoneId.of("UTC"));
+        MeisenProperties properties = new MeisenProperties();
+        properties.getLocal().setNombreLocal("Meisen");
+        useCase = new GestionarLinksPagoUseCase(linkPagoRepository, mesaRepository, pedidoRepository, pasarelaPago,
+            enviarComandaCocinaUseCase, notificador, properties, clock);
+
+        localId = new LocalId(UUID.randomUUID());
+        mesa = new Mesa(MesaId.generate(), localId, 21);
+        pedido = new Pedido(PedidoId.generate(), localId, mesa.getId(), 45, EstadoPedido.ABIERTO,
+            LocalDateTime.of(2026, 3, 14, 21, 0));
+        pedido.asignarCanal(CanalVenta.DELIVERY, null);
+        Producto pizza = new Producto(ProductoId.generate(), localId, "Pizza muzza", new BigDecimal("9000"), true, "#FFAA00");
+        pedido.agregarProducto(pizza, 2, null);
+    }
+
+    private LinkPago linkPendiente() {
+        return LinkPago.emitir(LinkPagoId.generate(), pedido, mesa.getNumero(), ProveedorLinkPago.MERCADO_PAGO,
+            "+54 9 11 5555-1234", URL_LINK, LocalDateTime.of(2026, 3, 14, 21, 5));
+    }
+
+    @Test
+    @DisplayName("Genera el link por el total del pedido y cancela el que había pendiente")
+    void deberia_generar_link_por_el_total_y_cancelar_el_anterior() {
+        // Given
+        LinkPago anterior = linkPendiente();
+        when(mesaRepository.buscarPorId(mesa.getId())).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesa.getId(), localId)).thenReturn(Optional.of(pedido));
+        when(linkPagoRepository.buscarPendientePorPedido(pedido.getId(), localId)).thenReturn(Optional.of(anterior));
+        when(pasarelaPago.crearLink(eq(ProveedorLinkPago.MERCADO_PAGO), any())).thenReturn(new LinkGenerado(URL_LINK));
+        when(linkPagoRepository.guardar(any())).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        LinkPagoResponse response = useCase.generar(localId, mesa.getId(),
+            new GenerarLinkPagoRequest(ProveedorLinkPago.MERCADO_PAGO, "+54 9 11 5555-1234"));
+
+        // Then
+        assertThat(anterior.getEstado()).isEqualTo(EstadoLinkPago.CANCELADO);
+        assertThat(response.estado()).isEqualTo(EstadoLinkPago.PENDIENTE);
+        assertThat(response.monto()).isEqualByComparingTo("18000");
+        assertThat(response.url()).isEqualTo(URL_LINK);
+        assertThat(response.whatsappUrl()).startsWith("https://wa.me/5491155551234?text=");
+
+        ArgumentCaptor<SolicitudLink> captor = ArgumentCaptor.forClass(SolicitudLink.class);
+        verify(pasarelaPago).crearLink(eq(ProveedorLinkPago.MERCADO_PAGO), captor.capture());
+        assertThat(captor.getValue().referencia()).isEqualTo(response.id().toString());
+        assertThat(captor.getValue().descripcion()).isEqualTo("Meisen - Pedido #45");
+    }
+
+    @Test
+    @DisplayName("Un pedido del salón no se cobra por link")
+    void no_deberia_generar_link_fuera_de_delivery() {
+        // Given
+        Pedido salon = new Pedido(PedidoId.generate(), localId, mesa.getId(), 46, EstadoPedido.ABIERTO,
+            LocalDateTime.of(2026, 3, 14, 21, 0));
+        when(mesaRepository.buscarPorId(mesa.getId())).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesa.getId(), localId)).thenReturn(Optional.of(salon));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.generar(localId, mesa.getId(),
+                new GenerarLinkPagoRequest(ProveedorLinkPago.MERCADO_PAGO, null)))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("delivery");
+        verifyNoInteractions(pasarelaPago);
+        verify(linkPagoRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("Si la pasarela no está configurada se informa sin registrar el link")
+    void deberia_informar_pasarela_no_disponible() {
+        // Given
+        when(mesaRepository.buscarPorId(mesa.getId())).thenReturn(Optional.of(mesa));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesa.getId(), localId)).thenReturn(Optional.of(pedido));
+        when(linkPagoRepository.buscarPendientePorPedido(pedido.getId(), localId)).thenReturn(Optional.empty());
+        when(pasarelaPago.crearLink(eq(ProveedorLinkPago.MODO), any()))
+            .thenThrow(new PasarelaPagoNoDisponibleException("MODO no está configurado en esta instalación"));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.generar(localId, mesa.getId(),
+                new GenerarLinkPagoRequest(ProveedorLinkPago.MODO, null)))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessage("MODO no está configurado en esta instalación");
+        verify(linkPagoRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("Al acreditarse el pago el pedido pasa a cocina y se avisa a las terminales")
+    void deberia_mandar_a_cocina_al_acreditarse_el_pago() {
+        // Given
+        LinkPago link = linkPendiente();
+        when(linkPagoRepository.buscarPorId(link.getId(), localId)).thenReturn(Optional.of(link));
+        when(pasarelaPago.buscarPagoAprobado(ProveedorLinkPago.MERCADO_PAGO, link.getId().toString()))
+            .thenReturn(Optional.of(new PagoAprobado("987654")));
+        when(pedidoRepository.buscarAbiertoPorMesa(mesa.getId(), localId)).thenReturn(Optional.of(pedido));
+
+        // When
+        LinkPagoResponse response = useCase.verificar(localId, link.getId());
+
+        // Then
+        assertThat(response.estado()).isEqualTo(EstadoLinkPago.PAGADO);
+        assertThat(link.getIdPagoPasarela()).isEqualTo("987654");
+        verify(linkPagoRepository).guardar(link);
+        verify(enviarComandaCocinaUseCase).ejecutar(localId, mesa.getId());
+        verify(notificador).publicar(eq(localId), eq(LinkPagoResponse.EVENTO_PAGADO), any(LinkPagoResponse.class));
+    }
+
+    @Test
+    @DisplayName("Mientras el cliente no paga, el pedido sigue esperando")
+    void no_deberia_mandar_a_cocina_sin_pago() {
+        // Given
+        LinkPago link = linkPendiente();
+        when(linkPagoRepository.buscarPendientes(localId)).thenReturn(List.of(link));
+        when(pasarelaPago.buscarPagoAprobado(ProveedorLinkPago.MERCADO_PAGO, link.getId().toString()))
+            .thenReturn(Optional.empty());
+
+        // When
+        List<LinkPagoResponse> acreditados = useCase.verificarPendientes(localId);
+
+        // Then
+        assertThat(acreditados).isEmpty();
+        assertThat(link.estaPendiente()).isTrue();
+        verifyNoInteractions(enviarComandaCocinaUseCase, notificador);
+    }
+
+    @Test
+    @DisplayName("Un aviso repetido de un link ya pagado no vuelve a mandar el pedido a cocina")
+    void deberia_ignorar_aviso_de_link_ya_pagado() {
+        // Given
+        LinkPago link = linkPendiente();
+        link.marcarPagado("987654", LocalDateTime.of(2026, 3, 14, 21, 10));
+        when(linkPagoRepository.buscarPorId(link.getId(), localId)).thenReturn(Optional.of(link));
+
+        // When
+        LinkPagoResponse response = useCase.verificar(localId, link.getId());
+
+        // Then
+        assertThat(response.estado()).isEqualTo(EstadoLinkPago.PAGADO);
+        verifyNoInteractions(pasarelaPago, enviarComandaCocinaUseCase, notificador);
+    }
+}
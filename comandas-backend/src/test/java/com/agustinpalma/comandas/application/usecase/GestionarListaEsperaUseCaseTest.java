@@ -0,0 +1,144 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.AvisarClienteEnEsperaRequest;
+import com.agustinpalma.comandas.application.dto.ClienteEnEsperaResponse;
+import com.agustinpalma.comandas.domain.model.ClienteEnEspera;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoEspera;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.model.Reserva;
+import com.agustinpalma.comandas.domain.repository.ClienteEnEsperaRepository;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import com.agustinpalma.comandas.domain.repository.ReservaRepository;
+import com.agustinpalma.comandas.infrastructure.config.MeisenProperties;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.time.Clock;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.Optional;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarListaEsperaUseCase.
+ * Valida los criterios de la HU-149 (lista de espera de mesas).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("HU-149 - Lista de espera de mesas")
+class GestionarListaEsperaUseCaseTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 3, 14, 21, 30);
+
+    @Mock
+    private ClienteEnEsperaRepository clienteEnEsperaRepository;
+
+    @Mock
+    private MesaRepository mesaRepository;
+
+    @Mock
+    private ReservaRepository reservaRepository;
+
+    private GestionarListaEsperaUseCase useCase;
+
+    private final LocalId localId = LocalId.generate();
+
+    @BeforeEach
+    void setUp() {
+        Clock clock = Clock.fixed(Instant.parse("2026-03-14T21:30:00Z"), ZoneId.of("UTC"));
+        MeisenProperties properties = new MeisenProperties();
+        properties.getLocal().setNombreLocal("Meisen");
+        useCase = new GestionarListaEsperaUseCase(clienteEnEsperaRepository, mesaRepository, reservaRepository,
+            properties, clock);
+    }
+
+    private Mesa mesa(int numero, Integer capacidad) {
+        Mesa mesa = new Mesa(MesaId.generate(), localId, numero);
+        mesa.definirCapacidad(capacidad);
+        return mesa;
+    }
+
+    private ClienteEnEspera cliente(String nombre, int personas, int minutosAtras) {
+        return ClienteEnEspera.anotar(localId, nombre, personas, "+54 9 11 5555-1234", null,
+            AHORA.minusMinutes(minutosAtras));
+    }
+
+    @Test
+    void deberia_sugerir_la_mesa_mas_chica_donde_entra_cada_grupo_por_orden_de_llegada() {
+        // Given: 3 libres (2, 4 y 6 lugares) + una reservada para dentro de una hora + una ocupada
+        Mesa dos = mesa(1, 2);
+        Mesa cuatro = mesa(2, 4);
+        Mesa seis = mesa(3, 6);
+        Mesa reservada = mesa(4, 2);
+        Mesa ocupada = mesa(5, 8);
+        ocupada.abrir();
+        ClienteEnEspera gomez = cliente("Gómez", 3, 40);
+        ClienteEnEspera perez = cliente("Pérez", 4, 25);
+        ClienteEnEspera lu = cliente("Lu", 2, 10);
+        ClienteEnEspera familia = cliente("Familia", 8, 5);
+
+        when(clienteEnEsperaRepository.buscarEnLista(localId)).thenReturn(List.of(gomez, perez, lu, familia));
+        when(mesaRepository.buscarPorLocal(localId)).thenReturn(List.of(dos, cuatro, seis, reservada, ocupada));
+        when(reservaRepository.buscarEntre(eq(localId), any(), any())).thenReturn(List.of(
+            Reserva.crear(localId, "Sofi", null, AHORA.plusHours(1), 2, null, reservada.getId())));
+
+        // When
+        List<ClienteEnEsperaResponse> lista = useCase.listar(localId);
+
+        // Then: Gómez (3) se lleva la de 4, Pérez (4) la de 6, Lu (2) la de 2; para 8 no hay
+        assertThat(lista).extracting(ClienteEnEsperaResponse::nombreCliente)
+            .containsExactly("Gómez", "Pérez", "Lu", "Familia");
+        assertThat(lista.get(0).minutosEsperando()).isEqualTo(40);
+        assertThat(lista.get(0).mesaSugerida().numero()).isEqualTo(2);
+        assertThat(lista.get(1).mesaSugerida().numero()).isEqualTo(3);
+        assertThat(lista.get(2).mesaSugerida().numero()).isEqualTo(1);
+        assertThat(lista.get(3).mesaSugerida()).isNull();
+    }
+
+    @Test
+    void deberia_armar_el_aviso_por_whatsapp_y_sms_al_avisar() {
+        ClienteEnEspera gomez = cliente("Gómez", 3, 40);
+        Mesa mesa = mesa(7, 4);
+        when(clienteEnEsperaRepository.buscarPorId(gomez.getId(), localId)).thenReturn(Optional.of(gomez));
+        when(clienteEnEsperaRepository.buscarEnLista(localId)).thenReturn(List.of(gomez));
+        when(mesaRepository.buscarPorId(mesa.getId())).thenReturn(Optional.of(mesa));
+        when(clienteEnEsperaRepository.guardar(any())).thenAnswer(inv -> inv.getArgument(0));
+
+        ClienteEnEsperaResponse respuesta = useCase.avisar(localId, gomez.getId(),
+            new AvisarClienteEnEsperaRequest(mesa.getId().getValue()));
+
+        assertThat(respuesta.estado()).isEqualTo(EstadoEspera.AVISADO);
+        assertThat(respuesta.numeroMesa()).isEqualTo(7);
+        assertThat(respuesta.whatsappUrl()).startsWith("https://wa.me/5491155551234?text=").contains("Meisen");
+        assertThat(respuesta.smsUrl()).startsWith("sms:5491155551234?body=");
+    }
+
+    @Test
+    void deberia_rechazar_avisar_una_mesa_ya_avisada_a_otro_cliente() {
+        ClienteEnEspera gomez = cliente("Gómez", 3, 40);
+        ClienteEnEspera perez = cliente("Pérez", 2, 20);
+        Mesa mesa = mesa(7, 4);
+        gomez.avisar(mesa, AHORA.minusMinutes(2));
+        when(clienteEnEsperaRepository.buscarPorId(perez.getId(), localId)).thenReturn(Optional.of(perez));
+        when(clienteEnEsperaRepository.buscarEnLista(localId)).thenReturn(List.of(gomez, perez));
+        when(mesaRepository.buscarPorId(mesa.getId())).thenReturn(Optional.of(mesa));
+
+        assertThatThrownBy(() -> useCase.avisar(localId, perez.getId(),
+            new AvisarClienteEnEsperaRequest(mesa.getId().getValue())))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("Gómez");
+        verify(clienteEnEsperaRepository, never()).guardar(any());
+    }
+}
@@ -0,0 +1,179 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.MermaRequest;
+import com.agustinpalma.comandas.application.dto.MermaRequest.InsumoMermaRequest;
+import com.agustinpalma.comandas.application.dto.MermaResponse;
+import com.agustinpalma.comandas.application.dto.ReporteMermasResponse;
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaMerma;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoInsumo;
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.IngredienteReceta;
+import com.agustinpalma.comandas.domain.model.Insumo;
+import com.agustinpalma.comandas.domain.model.Merma;
+import com.agustinpalma.comandas.domain.model.MovimientoInsumo;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.model.Receta;
+import com.agustinpalma.comandas.domain.model.RenglonMerma;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
+import com.agustinpalma.comandas.domain.repository.MermaRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoInsumoRepository;
+import com.agustinpalma.comandas.domain.repository.ProductoRepository;
+import com.agustinpalma.comandas.domain.repository.RecetaRepository;
+import com.agustinpalma.comandas.domain.service.InventarioService;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.ArgumentCaptor;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.time.YearMonth;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarMermasUseCase.
+ * Valida los criterios de la HU-135 (Registro de mermas y desperdicio).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Mermas - Caso de Uso")
+class GestionarMermasUseCaseTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 4, 6, 22, 15);
+
+    @Mock
+    private MermaRepository mermaRepository;
+
+    @Mock
+    private InsumoRepository insumoRepository;
+
+    @Mock
+    private MovimientoInsumoRepository movimientoInsumoRepository;
+
+    @Mock
+    private ProductoRepository productoRepository;
+
+    @Mock
+    private RecetaRepository recetaRepository;
+
+    private GestionarMermasUseCase useCase;
+
+    private LocalId localId;
+    private Insumo carne;
+    private Insumo pan;
+    private Insumo lechuga;
+
+    @BeforeEach
+    void setUp() {
+        ZoneId zona = ZoneId.of("America/Argentina/Buenos_Aires");
+        Clock clock = Clock.fixed(AHORA.atZone(zona).toInstant(), zona);
+        useCase = new GestionarMermasUseCase(mermaRepository, insumoRepository, movimientoInsumoRepository,
+                productoRepository, recetaRepository, new InventarioService(), clock);
+        localId = new LocalId(UUID.randomUUID());
+        carne = new Insumo(InsumoId.generate(), localId, "Carne picada", UnidadInsumo.KILOGRAMO,
+                new BigDecimal("9000"), true, new BigDecimal("12.500"));
+        pan = new Insumo(InsumoId.generate(), localId, "Pan de hamburguesa", UnidadInsumo.UNIDAD,
+                new BigDecimal("350"), true, new BigDecimal("80"));
+        lechuga = new Insumo(InsumoId.generate(), localId, "Lechuga", UnidadInsumo.KILOGRAMO, new BigDecimal("1800"));
+    }
+
+    @Test
+    @DisplayName("Merma por insumo: descuenta el stock y deja un movimiento MERMA con el motivo")
+    void deberia_descontar_stock_al_registrar_merma_por_insumo() {
+        // Given
+        when(insumoRepository.buscarPorLocal(localId)).thenReturn(List.of(carne, pan, lechuga));
+        when(mermaRepository.guardar(any(Merma.class))).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        MermaResponse response = useCase.registrar(localId, new MermaRequest(CategoriaMerma.VENCIMIENTO,
+                "Cortó la cadena de frío", "Marcela", null, null,
+                List.of(new InsumoMermaRequest(carne.getId().getValue(), new BigDecimal("1.5")),
+                        new InsumoMermaRequest(lechuga.getId().getValue(), new BigDecimal("2")))));
+
+        // Then: la lechuga no controla stock pero igual se valoriza
+        assertThat(response.costoTotal()).isEqualByComparingTo("17100");
+        assertThat(carne.getStockActual()).isEqualByComparingTo("11");
+        ArgumentCaptor<MovimientoInsumo> movimiento = ArgumentCaptor.forClass(MovimientoInsumo.class);
+        verify(movimientoInsumoRepository).guardar(movimiento.capture());
+        assertThat(movimiento.getValue().getTipo()).isEqualTo(TipoMovimientoInsumo.MERMA);
+        assertThat(movimiento.getValue().getCantidad()).isEqualByComparingTo("-1.5");
+        assertThat(movimiento.getValue().getMotivo()).isEqualTo("Merma - Cortó la cadena de frío");
+        verify(insumoRepository).guardar(carne);
+        verify(insumoRepository, never()).guardar(lechuga);
+    }
+
+    @Test
+    @DisplayName("Plato caído: descuenta la receta del producto por las unidades perdidas")
+    void deberia_descontar_la_receta_de_un_plato_caido() {
+        // Given: la hamburguesa lleva 0.2 kg de carne y un pan
+        Producto hamburguesa = new Producto(ProductoId.generate(), localId, "Hamburguesa",
+                new BigDecimal("8500"), true, "#AA5500");
+        Receta receta = new Receta(hamburguesa.getId(), localId, List.of(
+                new IngredienteReceta(carne.getId(), new BigDecimal("0.2")),
+                new IngredienteReceta(pan.getId(), BigDecimal.ONE)));
+        when(insumoRepository.buscarPorLocal(localId)).thenReturn(List.of(carne, pan));
+        when(productoRepository.buscarPorIdYLocal(hamburguesa.getId(), localId)).thenReturn(Optional.of(hamburguesa));
+        when(recetaRepository.buscarPorProducto(hamburguesa.getId(), localId)).thenReturn(Optional.of(receta));
+        when(mermaRepository.guardar(any(Merma.class))).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        MermaResponse response = useCase.registrar(localId, new MermaRequest(CategoriaMerma.PLATO_CAIDO,
+                "Se cayó la bandeja", "Lucas", hamburguesa.getId().getValue(), 2, null));
+
+        // Then: 0.4 kg de carne ($3600) y 2 panes ($700)
+        assertThat(response.nombreProducto()).isEqualTo("Hamburguesa");
+        assertThat(response.costoTotal()).isEqualByComparingTo("4300");
+        assertThat(carne.getStockActual()).isEqualByComparingTo("12.100");
+        assertThat(pan.getStockActual()).isEqualByComparingTo("78");
+        verify(movimientoInsumoRepository, times(2)).guardar(any());
+    }
+
+    @Test
+    @DisplayName("No se puede cargar por producto y por insumo a la vez")
+    void deberia_rechazar_merma_por_producto_y_por_insumo() {
+        // When / Then
+        assertThatThrownBy(() -> useCase.registrar(localId, new MermaRequest(CategoriaMerma.ERROR_COCINA,
+                "Pedido equivocado", "Lucas", UUID.randomUUID(), 1,
+                List.of(new InsumoMermaRequest(pan.getId().getValue(), BigDecimal.ONE)))))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("por producto o por insumo");
+        verifyNoInteractions(mermaRepository, movimientoInsumoRepository);
+    }
+
+    @Test
+    @DisplayName("Reporte mensual: merma valorizada por categoría, de mayor a menor")
+    void deberia_agrupar_la_merma_del_mes_por_categoria() {
+        // Given
+        Merma vencida = Merma.deInsumos(localId, CategoriaMerma.VENCIMIENTO, "Vencida", "Marcela",
+                List.of(RenglonMerma.de(carne, new BigDecimal("2"))), AHORA.minusDays(3));
+        Merma error = Merma.deInsumos(localId, CategoriaMerma.ERROR_COCINA, "Se quemó", "Lucas",
+                List.of(RenglonMerma.de(carne, new BigDecimal("0.5")), RenglonMerma.de(pan, new BigDecimal("2"))),
+                AHORA.minusDays(1));
+        when(mermaRepository.buscarPorFecha(any(), any(), any())).thenReturn(List.of(error, vencida));
+
+        // When
+        ReporteMermasResponse reporte = useCase.reporteMensual(localId, YearMonth.of(2026, 4));
+
+        // Then
+        assertThat(reporte.total()).isEqualByComparingTo("23200");
+        assertThat(reporte.categorias()).extracting(ReporteMermasResponse.CategoriaMes::categoria)
+                .containsExactly(CategoriaMerma.VENCIMIENTO, CategoriaMerma.ERROR_COCINA);
+        assertThat(reporte.categorias().get(0).porcentaje()).isEqualByComparingTo("77.59");
+        assertThat(reporte.insumos().get(0).nombre()).isEqualTo("Carne picada");
+        assertThat(reporte.insumos().get(0).cantidad()).isEqualByComparingTo("2.5");
+    }
+}
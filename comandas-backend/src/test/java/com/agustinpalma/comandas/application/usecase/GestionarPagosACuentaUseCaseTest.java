@@ -0,0 +1,172 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.PagoRequest;
+import com.agustinpalma.comandas.application.dto.PagosACuentaResponse;
+import com.agustinpalma.comandas.application.ports.output.NotificadorTiempoRealPort;
+import com.agustinpalma.comandas.domain.exception.PedidoModificadoException;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.RecargosMedioPagoRepository;
+import com.agustinpalma.comandas.domain.repository.RedondeoEfectivoRepository;
+import com.agustinpalma.comandas.domain.repository.TiposCambioRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.eq;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarPagosACuentaUseCase.
+ * Valida los criterios de la HU-160 (pagos a cuenta sobre mesas abiertas).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Pagos a cuenta - Caso de Uso")
+class GestionarPagosACuentaUseCaseTest {
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private RecargosMedioPagoRepository recargosMedioPagoRepository;
+
+    @Mock
+    private TiposCambioRepository tiposCambioRepository;
+
+    @Mock
+    private RedondeoEfectivoRepository redondeoEfectivoRepository;
+
+    @Mock
+    private JornadaComercialRepository jornadaComercialRepository;
+
+    @Mock
+    private GestionarCajasUseCase gestionarCajasUseCase;
+
+    @Mock
+    private NotificadorTiempoRealPort notificador;
+
+    private GestionarPagosACuentaUseCase useCase;
+
+    private LocalId localId;
+    private MesaId mesaId;
+    private Pedido pedido;
+
+    @BeforeEach
+    void setUp() {
+        Clock clock = Clock.fixed(Instant.parse("2026-10-14T22:15:00Z"), ZoneId.of("UTC"));
+        useCase = new GestionarPagosACuentaUseCase(pedidoRepository, recargosMedioPagoRepository,
+            tiposCambioRepository, redondeoEfectivoRepository, jornadaComercialRepository, gestionarCajasUseCase,
+            notificador, clock);
+
+        localId = new LocalId(UUID.randomUUID());
+        mesaId = MesaId.generate();
+        pedido = new Pedido(PedidoId.generate(), localId, mesaId, 12, EstadoPedido.ABIERTO,
+            LocalDateTime.of(2026, 10, 14, 21, 0));
+        Producto milanesa = new Producto(ProductoId.generate(), localId, "Milanesa", new BigDecimal("9500"), true, "#AA5500");
+        pedido.agregarProducto(milanesa, 3, null);
+    }
+
+    @Test
+    @DisplayName("Cobra a cuenta, deja la mesa abierta y avisa el saldo nuevo a las terminales")
+    void deberia_registrar_pago_a_cuenta_y_notificar_saldo() {
+        // Given
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaId, localId)).thenReturn(Optional.of(pedido));
+
+        // When
+        PagosACuentaResponse response = useCase.registrar(localId, mesaId,
+            new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("9500")), pedido.getVersion());
+
+        // Then
+        assertThat(response.pagadoACuenta()).isEqualByComparingTo("9500");
+        assertThat(response.saldoPendiente()).isEqualByComparingTo("19000");
+        assertThat(response.pagos()).hasSize(1);
+        assertThat(pedido.getEstado()).isEqualTo(EstadoPedido.ABIERTO);
+        verify(pedidoRepository).guardar(pedido);
+        verify(notificador).publicar(eq(localId), eq(PagosACuentaResponse.EVENTO), any(PagosACuentaResponse.class));
+    }
+
+    @Test
+    @DisplayName("El pago a cuenta queda en la caja de la terminal que lo cobró")
+    void deberia_imputar_el_pago_a_cuenta_a_la_caja_que_lo_cobra() {
+        // Given: cobra la terminal de la barra
+        CajaId barra = CajaId.generate();
+        when(gestionarCajasUseCase.cajaActual(localId)).thenReturn(barra);
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaId, localId)).thenReturn(Optional.of(pedido));
+
+        // When
+        useCase.registrar(localId, mesaId, new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("9500")), pedido.getVersion());
+
+        // Then
+        assertThat(pedido.getPagos()).singleElement()
+            .satisfies(pago -> assertThat(pago.getCajaId()).isEqualTo(barra));
+    }
+
+    @Test
+    @DisplayName("Rechaza un pago a cuenta mayor al saldo pendiente")
+    void deberia_rechazar_pago_a_cuenta_mayor_al_saldo() {
+        // Given
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaId, localId)).thenReturn(Optional.of(pedido));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.registrar(localId, mesaId,
+            new PagoRequest(MedioPago.TARJETA, new BigDecimal("30000")), pedido.getVersion()))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("saldo pendiente");
+        verify(pedidoRepository, never()).guardar(any());
+        verifyNoInteractions(notificador);
+    }
+
+    @Test
+    @DisplayName("Anula un pago a cuenta y el saldo vuelve a ser el total")
+    void deberia_anular_pago_a_cuenta() {
+        // Given
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaId, localId)).thenReturn(Optional.of(pedido));
+        useCase.registrar(localId, mesaId, new PagoRequest(MedioPago.QR, new BigDecimal("9500")), pedido.getVersion());
+
+        // When
+        PagosACuentaResponse response = useCase.anular(localId, mesaId, 0, pedido.getVersion());
+
+        // Then
+        assertThat(response.pagos()).isEmpty();
+        assertThat(response.saldoPendiente()).isEqualByComparingTo("28500");
+        assertThatThrownBy(() -> useCase.anular(localId, mesaId, 0, pedido.getVersion()))
+            .isInstanceOf(IllegalArgumentException.class);
+    }
+
+    @Test
+    @DisplayName("Rechaza el pago a cuenta si otra terminal cambió el pedido")
+    void deberia_rechazar_pago_a_cuenta_si_el_pedido_cambio() {
+        // Given: la terminal cobra sobre una versión que ya no es la del pedido
+        when(pedidoRepository.buscarAbiertoPorMesa(mesaId, localId)).thenReturn(Optional.of(pedido));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.registrar(localId, mesaId,
+            new PagoRequest(MedioPago.EFECTIVO, new BigDecimal("9500")), pedido.getVersion() + 1))
+            .isInstanceOf(PedidoModificadoException.class);
+        verify(pedidoRepository, never()).guardar(any());
+        verifyNoInteractions(notificador);
+    }
+}
@@ -0,0 +1,157 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.MatrizPermisosRequest;
+import com.agustinpalma.comandas.application.dto.SesionEmpleadoResponse;
+import com.agustinpalma.comandas.application.ports.output.EmpleadoContextProvider;
+import com.agustinpalma.comandas.domain.exception.PermisoDenegadoException;
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.MatrizPermisos;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.SesionEmpleado;
+import com.agustinpalma.comandas.domain.repository.MatrizPermisosRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.repository.SesionEmpleadoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.ArgumentCaptor;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.time.Clock;
+import java.time.Duration;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.Map;
+import java.util.Optional;
+import java.util.Set;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarPermisosUseCase.
+ * Valida los criterios de la HU-169 (sesión del empleado y matriz de permisos).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Gestionar Permisos - Caso de Uso")
+class GestionarPermisosUseCaseTest {
+
+    @Mock
+    private MatrizPermisosRepository matrizPermisosRepository;
+
+    @Mock
+    private MozoRepository mozoRepository;
+
+    @Mock
+    private EmpleadoContextProvider empleadoContextProvider;
+
+    @Mock
+    private SesionEmpleadoRepository sesionEmpleadoRepository;
+
+    private GestionarPermisosUseCase useCase;
+
+    private LocalId localId;
+    private LocalDateTime ahora;
+
+    @BeforeEach
+    void setUp() {
+        ZoneId zona = ZoneId.of("America/Argentina/Buenos_Aires");
+        Clock clock = Clock.fixed(Instant.parse("2026-10-14T22:00:00Z"), zona);
+        useCase = new GestionarPermisosUseCase(matrizPermisosRepository, mozoRepository, empleadoContextProvider,
+            sesionEmpleadoRepository, Duration.ofMinutes(60), clock);
+
+        localId = LocalId.generate();
+        ahora = LocalDateTime.now(clock);
+    }
+
+    private Mozo empleadoConPin(RolPersonal rol) {
+        Mozo empleado = new Mozo(MozoId.generate(), localId, "Juan", true, rol, null);
+        empleado.cambiarPin("1234");
+        return empleado;
+    }
+
+    @Test
+    @DisplayName("Al identificarse con el PIN abre una sesión y devuelve su token")
+    void deberia_abrir_una_sesion_con_token() {
+        // Given
+        Mozo juan = empleadoConPin(RolPersonal.MOZO);
+        when(mozoRepository.buscarPorId(juan.getId(), localId)).thenReturn(Optional.of(juan));
+        when(matrizPermisosRepository.buscarPorLocal(localId)).thenReturn(Optional.empty());
+
+        // When
+        SesionEmpleadoResponse response = useCase.identificar(localId, juan.getId(), "1234");
+
+        // Then: se guarda el hash del token, no el token
+        ArgumentCaptor<SesionEmpleado> guardada = ArgumentCaptor.forClass(SesionEmpleado.class);
+        verify(sesionEmpleadoRepository).eliminarVencidas(ahora);
+        verify(sesionEmpleadoRepository).guardar(guardada.capture());
+        assertThat(response.token()).isNotBlank();
+        assertThat(guardada.getValue().getHashToken()).isEqualTo(SesionEmpleado.hash(response.token()));
+        assertThat(guardada.getValue().getEmpleadoId()).isEqualTo(juan.getId());
+        assertThat(response.venceEn()).isEqualTo(ahora.plusMinutes(60));
+    }
+
+    @Test
+    @DisplayName("Con un PIN incorrecto no abre ninguna sesión")
+    void no_deberia_abrir_sesion_con_pin_incorrecto() {
+        // Given
+        Mozo juan = empleadoConPin(RolPersonal.MOZO);
+        when(mozoRepository.buscarPorId(juan.getId(), localId)).thenReturn(Optional.of(juan));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.identificar(localId, juan.getId(), "9999"))
+            .isInstanceOf(IllegalArgumentException.class);
+        verify(sesionEmpleadoRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("Con la matriz activa, sin una sesión del dueño no se puede cambiar")
+    void deberia_rechazar_cambiar_la_matriz_sin_sesion_del_dueno() {
+        // Given: la matriz está activa y el token no es de una sesión vigente
+        when(matrizPermisosRepository.buscarPorLocal(localId))
+            .thenReturn(Optional.of(new MatrizPermisos(localId, true, Map.of(), Map.of())));
+        when(empleadoContextProvider.getEmpleadoActual()).thenReturn(Optional.empty());
+        MatrizPermisosRequest request = new MatrizPermisosRequest(false, Map.of(), Map.of());
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.guardar(localId, request))
+            .isInstanceOf(PermisoDenegadoException.class);
+        verify(matrizPermisosRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("Con la matriz activa, el dueño de la sesión la puede cambiar")
+    void deberia_permitir_cambiar_la_matriz_al_dueno_de_la_sesion() {
+        // Given
+        Mozo dueno = empleadoConPin(RolPersonal.DUENO);
+        when(matrizPermisosRepository.buscarPorLocal(localId))
+            .thenReturn(Optional.of(new MatrizPermisos(localId, true, Map.of(), Map.of())));
+        when(empleadoContextProvider.getEmpleadoActual()).thenReturn(Optional.of(dueno.getId()));
+        when(mozoRepository.buscarPorId(dueno.getId(), localId)).thenReturn(Optional.of(dueno));
+        when(matrizPermisosRepository.guardar(any(MatrizPermisos.class))).thenAnswer(inv -> inv.getArgument(0));
+        MatrizPermisosRequest request = new MatrizPermisosRequest(true,
+            Map.of(RolPersonal.MOZO, Set.of(Permiso.APLICAR_DESCUENTOS)), Map.of());
+
+        // When
+        useCase.guardar(localId, request);
+
+        // Then
+        verify(matrizPermisosRepository).guardar(any(MatrizPermisos.class));
+    }
+
+    @Test
+    @DisplayName("Cerrar la sesión borra la del token")
+    void deberia_cerrar_la_sesion_del_token() {
+        useCase.cerrarSesion("token-de-la-terminal");
+
+        verify(sesionEmpleadoRepository).eliminar(SesionEmpleado.hash("token-de-la-terminal"));
+    }
+}
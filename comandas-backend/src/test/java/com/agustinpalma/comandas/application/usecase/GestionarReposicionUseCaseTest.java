@@ -0,0 +1,125 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.OrdenCompraSugeridaResponse;
+import com.agustinpalma.comandas.application.dto.ReposicionInsumoRequest;
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+import com.agustinpalma.comandas.domain.model.Insumo;
+import com.agustinpalma.comandas.domain.model.Proveedor;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
+import com.agustinpalma.comandas.domain.repository.ProveedorRepository;
+import com.agustinpalma.comandas.domain.service.InventarioService;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarReposicionUseCase.
+ * Valida los criterios de la HU-134 (Órdenes de compra automáticas por stock mínimo).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Órdenes de Compra por Stock Mínimo - Caso de Uso")
+class GestionarReposicionUseCaseTest {
+
+    @Mock
+    private InsumoRepository insumoRepository;
+
+    @Mock
+    private ProveedorRepository proveedorRepository;
+
+    private GestionarReposicionUseCase useCase;
+
+    private LocalId localId;
+    private Proveedor frigorifico;
+    private Proveedor panaderia;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new GestionarReposicionUseCase(insumoRepository, proveedorRepository, new InventarioService());
+        localId = new LocalId(UUID.randomUUID());
+        frigorifico = Proveedor.crear(localId, "Frigorífico Sur", null, "1144556677", "pedidos@frigosur.com", 15);
+        panaderia = Proveedor.crear(localId, "Panadería La Espiga", null, "1133221100", 7);
+    }
+
+    private Insumo insumo(String nombre, UnidadInsumo unidad, String costo, String stock, String minimo,
+                          Proveedor proveedor) {
+        return new Insumo(InsumoId.generate(), localId, nombre, unidad, new BigDecimal(costo), true,
+                new BigDecimal(stock), minimo != null ? new BigDecimal(minimo) : null,
+                proveedor != null ? proveedor.getId() : null);
+    }
+
+    @Test
+    @DisplayName("Agrupa por proveedor habitual los insumos que llegaron al mínimo")
+    void deberia_agrupar_por_proveedor_los_insumos_bajo_minimo() {
+        // Given
+        Insumo carne = insumo("Carne picada", UnidadInsumo.KILOGRAMO, "9000", "3.5", "5", frigorifico);
+        Insumo chorizo = insumo("Chorizo", UnidadInsumo.KILOGRAMO, "7000", "2", "2", frigorifico);
+        Insumo pan = insumo("Pan de hamburguesa", UnidadInsumo.UNIDAD, "350", "30.5", "40", panaderia);
+        Insumo queso = insumo("Queso cheddar", UnidadInsumo.KILOGRAMO, "12000", "8", "3", frigorifico);
+        Insumo sal = insumo("Sal", UnidadInsumo.KILOGRAMO, "800", "0.2", "1", null);
+        when(insumoRepository.buscarPorLocal(localId)).thenReturn(List.of(sal, queso, pan, chorizo, carne));
+        when(proveedorRepository.buscarPorLocal(localId)).thenReturn(List.of(panaderia, frigorifico));
+
+        // When
+        List<OrdenCompraSugeridaResponse> ordenes = useCase.sugerirOrdenes(localId);
+
+        // Then: por nombre de proveedor; la que no tiene proveedor va última
+        assertThat(ordenes).extracting(OrdenCompraSugeridaResponse::nombreProveedor)
+                .containsExactly("Frigorífico Sur", "Panadería La Espiga", null);
+
+        OrdenCompraSugeridaResponse frigo = ordenes.get(0);
+        assertThat(frigo.email()).isEqualTo("pedidos@frigosur.com");
+        assertThat(frigo.insumos()).extracting(OrdenCompraSugeridaResponse.RenglonOrdenCompra::nombre)
+                .containsExactly("Carne picada", "Chorizo");
+        assertThat(frigo.insumos().get(0).cantidadSugerida()).isEqualByComparingTo("6.5");
+        assertThat(frigo.costoEstimado()).isEqualByComparingTo("72500");
+
+        // El pan se compra por unidad: 80 - 30.5 se redondea para arriba
+        assertThat(ordenes.get(1).insumos().get(0).cantidadSugerida()).isEqualByComparingTo("50");
+    }
+
+    @Test
+    @DisplayName("Un insumo que no controla stock no se sugiere aunque tenga mínimo")
+    void no_deberia_sugerir_insumos_sin_control_de_stock() {
+        // Given
+        Insumo aceite = new Insumo(InsumoId.generate(), localId, "Aceite", UnidadInsumo.LITRO, new BigDecimal("2500"),
+                false, BigDecimal.ZERO, new BigDecimal("10"), frigorifico.getId());
+        when(insumoRepository.buscarPorLocal(localId)).thenReturn(List.of(aceite));
+        when(proveedorRepository.buscarPorLocal(localId)).thenReturn(List.of(frigorifico));
+
+        // When / Then
+        assertThat(useCase.sugerirOrdenes(localId)).isEmpty();
+    }
+
+    @Test
+    @DisplayName("Definir reposición con un proveedor de otro local se rechaza")
+    void deberia_rechazar_proveedor_inexistente_en_el_local() {
+        // Given
+        Insumo carne = insumo("Carne picada", UnidadInsumo.KILOGRAMO, "9000", "3.5", null, null);
+        ProveedorId ajeno = ProveedorId.generate();
+        when(insumoRepository.buscarPorId(carne.getId(), localId)).thenReturn(Optional.of(carne));
+        when(proveedorRepository.buscarPorId(ajeno, localId)).thenReturn(Optional.empty());
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.definirReposicion(localId, carne.getId(),
+                new ReposicionInsumoRequest(new BigDecimal("5"), ajeno.getValue())))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("El proveedor no existe");
+        verify(insumoRepository, never()).guardar(any());
+    }
+}
@@ -0,0 +1,262 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.SerieComprobantesResponse;
+import com.agustinpalma.comandas.domain.model.Caja;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.model.SerieComprobantes;
+import com.agustinpalma.comandas.domain.repository.CajaRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import com.agustinpalma.comandas.domain.repository.SerieComprobantesRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarSeriesComprobantesUseCase.
+ * Valida los criterios de la HU-166 (numeración de comprobantes por caja).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Gestionar Series de Comprobantes - Caso de Uso")
+class GestionarSeriesComprobantesUseCaseTest {
+
+    @Mock
+    private SerieComprobantesRepository serieComprobantesRepository;
+
+    @Mock
+    private CajaRepository cajaRepository;
+
+    @Mock
+    private JornadaComercialRepository jornadaComercialRepository;
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    private GestionarSeriesComprobantesUseCase useCase;
+
+    private LocalId localId;
+    private CajaId barraId;
+
+    @BeforeEach
+    void setUp() {
+        Clock clock = Clock.fixed(
+            Instant.parse("2026-10-14T22:00:00Z"),
+            ZoneId.of("America/Argentina/Buenos_Aires")
+        );
+        useCase = new GestionarSeriesComprobantesUseCase(serieComprobantesRepository, cajaRepository,
+            jornadaComercialRepository, pedidoRepository, clock);
+
+        localId = new LocalId(UUID.randomUUID());
+        barraId = new CajaId(UUID.randomUUID());
+    }
+
+    @Test
+    @DisplayName("Asigna los números de la serie de la caja en orden")
+    void deberia_asignar_numeros_consecutivos() {
+        // Given
+        SerieComprobantes serie = new SerieComprobantes(localId, barraId, "b1", 41, false, null);
+        when(serieComprobantesRepository.buscarParaAsignar(localId, barraId)).thenReturn(Optional.of(serie));
+        when(jornadaComercialRepository.buscarPorLocal(localId)).thenReturn(Optional.empty());
+
+        // When
+        String primero = useCase.asignarNumero(localId, barraId, LocalDateTime.of(2026, 10, 14, 21, 0));
+        String segundo = useCase.asignarNumero(localId, barraId, LocalDateTime.of(2026, 10, 14, 21, 5));
+
+        // Then
+        assertThat(primero).isEqualTo("B1-00000041");
+        assertThat(segundo).isEqualTo("B1-00000042");
+        assertThat(serie.getProximoNumero()).isEqualTo(43);
+        verify(serieComprobantesRepository, times(2)).guardar(serie);
+    }
+
+    @Test
+    @DisplayName("Con reinicio anual, el primer cierre del año operativo vuelve a 1")
+    void deberia_reiniciar_la_numeracion_con_el_anio_operativo() {
+        // Given: la serie ya numeró en 2026
+        SerieComprobantes serie = new SerieComprobantes(localId, null, "SAL", 950, true, 2026);
+        when(serieComprobantesRepository.buscarParaAsignar(localId, null)).thenReturn(Optional.of(serie));
+        when(jornadaComercialRepository.buscarPorLocal(localId)).thenReturn(Optional.empty());
+
+        // When: un cierre a la madrugada del 1° de enero todavía es del año anterior
+        String madrugada = useCase.asignarNumero(localId, null, LocalDateTime.of(2027, 1, 1, 2, 0));
+        String mediodia = useCase.asignarNumero(localId, null, LocalDateTime.of(2027, 1, 1, 13, 0));
+
+        // Then
+        assertThat(madrugada).isEqualTo("SAL-2026-00000950");
+        assertThat(mediodia).isEqualTo("SAL-2027-00000001");
+    }
+
+    @Test
+    @DisplayName("Una caja sin serie no recibe número de comprobante")
+    void deberia_devolver_null_si_la_caja_no_tiene_serie() {
+        when(serieComprobantesRepository.buscarParaAsignar(localId, barraId)).thenReturn(Optional.empty());
+
+        String numero = useCase.asignarNumero(localId, barraId, LocalDateTime.of(2026, 10, 14, 21, 0));
+
+        assertThat(numero).isNull();
+        verify(serieComprobantesRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("Numera el pedido cerrado con la serie de la caja que lo cobró")
+    void deberia_numerar_el_pedido_con_la_serie_de_su_caja() {
+        // Given
+        Pedido pedido = pedidoCerrado(LocalDateTime.of(2026, 10, 14, 21, 0));
+        pedido.asignarCaja(barraId);
+        SerieComprobantes serie = new SerieComprobantes(localId, barraId, "B1", 41, false, null);
+        when(serieComprobantesRepository.buscarParaAsignar(localId, barraId)).thenReturn(Optional.of(serie));
+        when(jornadaComercialRepository.buscarPorLocal(localId)).thenReturn(Optional.empty());
+
+        // When
+        useCase.numerar(pedido, pedido.getFechaCierre());
+
+        // Then
+        assertThat(pedido.getNumeroComprobante()).isEqualTo("B1-00000041");
+    }
+
+    @Test
+    @DisplayName("Un pedido de una caja sin serie queda sin número de comprobante")
+    void deberia_dejar_sin_numero_el_pedido_de_una_caja_sin_serie() {
+        Pedido pedido = pedidoCerrado(LocalDateTime.of(2026, 10, 14, 21, 0));
+        when(serieComprobantesRepository.buscarParaAsignar(localId, null)).thenReturn(Optional.empty());
+
+        useCase.numerar(pedido, pedido.getFechaCierre());
+
+        assertThat(pedido.getNumeroComprobante()).isNull();
+    }
+
+    @Test
+    @DisplayName("Un pedido reabierto conserva el número que ya tenía")
+    void deberia_conservar_el_numero_del_pedido_reabierto() {
+        // Given: el pedido ya se había numerado en su primer cierre
+        Pedido pedido = pedidoCerrado(LocalDateTime.of(2026, 10, 14, 21, 0));
+        pedido.asignarNumeroComprobante("B1-00000007");
+
+        // When
+        useCase.numerar(pedido, LocalDateTime.of(2026, 10, 14, 22, 30));
+
+        // Then: no consume otro número de la serie
+        assertThat(pedido.getNumeroComprobante()).isEqualTo("B1-00000007");
+        verify(serieComprobantesRepository, never()).buscarParaAsignar(any(), any());
+    }
+
+    @Test
+    @DisplayName("Rechaza un prefijo que ya usa la serie de otra caja")
+    void deberia_rechazar_prefijo_repetido() {
+        // Given: la caja principal ya numera con "B1"
+        when(cajaRepository.buscarPorId(barraId, localId))
+            .thenReturn(Optional.of(new Caja(barraId, localId, "Barra", LocalDateTime.of(2026, 1, 1, 10, 0))));
+        when(serieComprobantesRepository.buscar(localId, barraId)).thenReturn(Optional.empty());
+        when(serieComprobantesRepository.buscarPorLocal(localId))
+            .thenReturn(List.of(new SerieComprobantes(localId, null, "B1", 1, false, null)));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.configurar(localId, barraId, "b1", 1, false))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("B1");
+        verify(serieComprobantesRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("Rechaza bajar el próximo número con el mismo prefijo")
+    void deberia_rechazar_bajar_el_proximo_numero() {
+        // Given
+        when(serieComprobantesRepository.buscar(localId, null))
+            .thenReturn(Optional.of(new SerieComprobantes(localId, null, "A", 120, false, 2026)));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.configurar(localId, null, "A", 100, false))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("no puede bajar");
+    }
+
+    @Test
+    @DisplayName("Rechaza volver a un prefijo anterior con un número que ya se emitió")
+    void deberia_rechazar_volver_a_un_prefijo_con_un_numero_ya_emitido() {
+        // Given: la serie numeró con "A" hasta el 120 y después pasó a "B"
+        when(serieComprobantesRepository.buscar(localId, null))
+            .thenReturn(Optional.of(new SerieComprobantes(localId, null, "B", 5, false, 2026)));
+        when(serieComprobantesRepository.buscarPorLocal(localId)).thenReturn(List.of());
+        when(jornadaComercialRepository.buscarPorLocal(localId)).thenReturn(Optional.empty());
+        when(pedidoRepository.buscarUltimoComprobante(localId, "A-")).thenReturn(Optional.of("A-00000120"));
+
+        // When / Then: volver a "A" desde el 100 repetiría del 100 al 120
+        assertThatThrownBy(() -> useCase.configurar(localId, null, "A", 100, false))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("A-00000120")
+            .hasMessageContaining("121");
+        verify(serieComprobantesRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("Con reinicio anual, solo cuentan los comprobantes del mismo año")
+    void deberia_comparar_con_los_comprobantes_del_mismo_anio() {
+        // Given: "A" numeró sin año; con año, en 2026 todavía no emitió nada
+        when(serieComprobantesRepository.buscar(localId, null))
+            .thenReturn(Optional.of(new SerieComprobantes(localId, null, "B", 5, false, 2026)));
+        when(serieComprobantesRepository.buscarPorLocal(localId)).thenReturn(List.of());
+        when(serieComprobantesRepository.guardar(any(SerieComprobantes.class))).thenAnswer(inv -> inv.getArgument(0));
+        when(jornadaComercialRepository.buscarPorLocal(localId)).thenReturn(Optional.empty());
+        when(pedidoRepository.buscarUltimoComprobante(localId, "A-2026-")).thenReturn(Optional.empty());
+
+        // When
+        SerieComprobantesResponse response = useCase.configurar(localId, null, "A", 1, true);
+
+        // Then
+        assertThat(response.proximoComprobante()).isEqualTo("A-2026-00000001");
+    }
+
+    @Test
+    @DisplayName("Configura la serie de una caja y muestra el próximo comprobante")
+    void deberia_configurar_la_serie_de_una_caja() {
+        // Given
+        when(cajaRepository.buscarPorId(barraId, localId))
+            .thenReturn(Optional.of(new Caja(barraId, localId, "Barra", LocalDateTime.of(2026, 1, 1, 10, 0))));
+        when(serieComprobantesRepository.buscar(localId, barraId)).thenReturn(Optional.empty());
+        when(serieComprobantesRepository.buscarPorLocal(localId)).thenReturn(List.of());
+        when(serieComprobantesRepository.guardar(any(SerieComprobantes.class))).thenAnswer(inv -> inv.getArgument(0));
+        when(jornadaComercialRepository.buscarPorLocal(localId)).thenReturn(Optional.empty());
+
+        // When
+        SerieComprobantesResponse response = useCase.configurar(localId, barraId, "b1", 1, true);
+
+        // Then
+        assertThat(response.caja()).isEqualTo("Barra");
+        assertThat(response.prefijo()).isEqualTo("B1");
+        assertThat(response.proximoComprobante()).isEqualTo("B1-2026-00000001");
+    }
+
+    private Pedido pedidoCerrado(LocalDateTime cierre) {
+        Producto milanesa = new Producto(ProductoId.generate(), localId, "Milanesa", new BigDecimal("1000"), true, "#AA5500");
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 1,
+            EstadoPedido.ABIERTO, cierre.minusHours(1));
+        pedido.agregarProducto(milanesa, 1, null);
+        pedido.finalizar(MedioPago.EFECTIVO, cierre);
+        return pedido;
+    }
+}
@@ -0,0 +1,161 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.TomaInventarioRequest;
+import com.agustinpalma.comandas.application.dto.TomaInventarioRequest.ConteoRequest;
+import com.agustinpalma.comandas.application.dto.TomaInventarioResponse;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoInsumo;
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.Insumo;
+import com.agustinpalma.comandas.domain.model.MovimientoInsumo;
+import com.agustinpalma.comandas.domain.model.TomaInventario;
+import com.agustinpalma.comandas.domain.repository.InsumoRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoInsumoRepository;
+import com.agustinpalma.comandas.domain.repository.TomaInventarioRepository;
+import com.agustinpalma.comandas.domain.service.InventarioService;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.ArgumentCaptor;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GestionarTomaInventarioUseCase.
+ * Valida los criterios de la HU-133 (Inventario físico con reporte de diferencias).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Toma de Inventario - Caso de Uso")
+class GestionarTomaInventarioUseCaseTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 4, 6, 23, 30);
+
+    @Mock
+    private TomaInventarioRepository tomaInventarioRepository;
+
+    @Mock
+    private InsumoRepository insumoRepository;
+
+    @Mock
+    private MovimientoInsumoRepository movimientoInsumoRepository;
+
+    private GestionarTomaInventarioUseCase useCase;
+
+    private LocalId localId;
+    private Insumo carne;
+    private Insumo pan;
+    private Insumo aceite;
+
+    @BeforeEach
+    void setUp() {
+        ZoneId zona = ZoneId.of("America/Argentina/Buenos_Aires");
+        Clock clock = Clock.fixed(AHORA.atZone(zona).toInstant(), zona);
+        useCase = new GestionarTomaInventarioUseCase(tomaInventarioRepository, insumoRepository,
+                movimientoInsumoRepository, new InventarioService(), clock);
+        localId = new LocalId(UUID.randomUUID());
+        carne = new Insumo(InsumoId.generate(), localId, "Carne picada", UnidadInsumo.KILOGRAMO,
+                new BigDecimal("9000"), true, new BigDecimal("12.500"));
+        pan = new Insumo(InsumoId.generate(), localId, "Pan de hamburguesa", UnidadInsumo.UNIDAD,
+                new BigDecimal("350"), true, new BigDecimal("80"));
+        aceite = new Insumo(InsumoId.generate(), localId, "Aceite", UnidadInsumo.LITRO, new BigDecimal("2500"));
+    }
+
+    private TomaInventarioRequest request(ConteoRequest... conteos) {
+        return new TomaInventarioRequest("Marcela", List.of(conteos));
+    }
+
+    private ConteoRequest conteo(Insumo insumo, String cantidad) {
+        return new ConteoRequest(insumo.getId().getValue(), new BigDecimal(cantidad));
+    }
+
+    @Test
+    @DisplayName("Previsualizar: valoriza las diferencias sin tocar el stock")
+    void deberia_previsualizar_diferencias_valorizadas_sin_ajustar() {
+        // Given: faltan 0.5 kg de carne ($4500) y sobran 2 panes ($700)
+        when(insumoRepository.buscarPorLocal(localId)).thenReturn(List.of(carne, pan, aceite));
+
+        // When
+        TomaInventarioResponse reporte = useCase.previsualizar(localId,
+                request(conteo(carne, "12"), conteo(pan, "82")));
+
+        // Then: primero la mayor pérdida
+        assertThat(reporte.id()).isNull();
+        assertThat(reporte.insumos()).extracting(TomaInventarioResponse.RenglonInventario::nombre)
+                .containsExactly("Carne picada", "Pan de hamburguesa");
+        assertThat(reporte.insumos().get(0).diferencia()).isEqualByComparingTo("-0.5");
+        assertThat(reporte.faltanteValorizado()).isEqualByComparingTo("4500");
+        assertThat(reporte.sobranteValorizado()).isEqualByComparingTo("700");
+        assertThat(reporte.diferenciaValorizada()).isEqualByComparingTo("-3800");
+        assertThat(carne.getStockActual()).isEqualByComparingTo("12.500");
+        verifyNoInteractions(tomaInventarioRepository, movimientoInsumoRepository);
+        verify(insumoRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("Confirmar: ajusta el stock al conteo y deja un movimiento auditado por diferencia")
+    void deberia_ajustar_stock_y_registrar_movimientos_al_confirmar() {
+        // Given: el pan coincide; el aceite no controlaba stock y empieza a controlarlo
+        when(insumoRepository.buscarPorLocal(localId)).thenReturn(List.of(carne, pan, aceite));
+        when(tomaInventarioRepository.guardar(any(TomaInventario.class))).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        TomaInventarioResponse toma = useCase.confirmar(localId,
+                request(conteo(carne, "12"), conteo(pan, "80"), conteo(aceite, "4.5")));
+
+        // Then
+        assertThat(toma.id()).isNotNull();
+        assertThat(toma.insumosConDiferencia()).isEqualTo(2);
+        assertThat(carne.getStockActual()).isEqualByComparingTo("12");
+        assertThat(aceite.isControlaStock()).isTrue();
+        assertThat(aceite.getStockActual()).isEqualByComparingTo("4.5");
+        verify(insumoRepository, times(3)).guardar(any(Insumo.class));
+
+        ArgumentCaptor<MovimientoInsumo> captor = ArgumentCaptor.forClass(MovimientoInsumo.class);
+        verify(movimientoInsumoRepository, times(2)).guardar(captor.capture());
+        assertThat(captor.getAllValues()).extracting(MovimientoInsumo::getCantidad)
+                .usingElementComparator(BigDecimal::compareTo)
+                .containsExactly(new BigDecimal("-0.5"), new BigDecimal("4.5"));
+        assertThat(captor.getAllValues()).allSatisfy(m -> {
+            assertThat(m.getTipo()).isEqualTo(TipoMovimientoInsumo.AJUSTE_INVENTARIO);
+            assertThat(m.getMotivo()).isEqualTo("Toma de inventario - Marcela");
+            assertThat(m.getFecha()).isEqualTo(AHORA);
+        });
+    }
+
+    @Test
+    @DisplayName("Un insumo contado dos veces se rechaza sin ajustar nada")
+    void deberia_rechazar_insumo_contado_dos_veces() {
+        // When / Then
+        assertThatThrownBy(() -> useCase.confirmar(localId, request(conteo(carne, "12"), conteo(carne, "1"))))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("contado dos veces");
+        verifyNoInteractions(tomaInventarioRepository, movimientoInsumoRepository);
+    }
+
+    @Test
+    @DisplayName("Un insumo de otro local se rechaza")
+    void deberia_rechazar_insumo_inexistente_en_el_local() {
+        // Given
+        when(insumoRepository.buscarPorLocal(localId)).thenReturn(List.of(carne));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.confirmar(localId, request(conteo(pan, "10"))))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("no existe en este local");
+        verify(insumoRepository, never()).guardar(any());
+    }
+}
@@ -0,0 +1,123 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.DisposicionSalonRequest;
+import com.agustinpalma.comandas.application.dto.DisposicionSalonRequest.UbicacionMesa;
+import com.agustinpalma.comandas.application.dto.MesaResponse;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.Mesa;
+import com.agustinpalma.comandas.domain.repository.MesaRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.util.List;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.anyList;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso GuardarDisposicionSalonUseCase.
+ * Valida los criterios de la HU-112 (editor del mapa de mesas).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Guardar Disposición del Salón - Caso de Uso")
+class GuardarDisposicionSalonUseCaseTest {
+
+    @Mock
+    private MesaRepository mesaRepository;
+
+    private GuardarDisposicionSalonUseCase useCase;
+
+    private LocalId localId;
+    private Mesa mesa1;
+    private Mesa mesa2;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new GuardarDisposicionSalonUseCase(mesaRepository);
+        localId = new LocalId(UUID.randomUUID());
+        mesa1 = new Mesa(MesaId.generate(), localId, 1);
+        mesa2 = new Mesa(MesaId.generate(), localId, 2);
+        when(mesaRepository.buscarPorLocal(localId)).thenReturn(List.of(mesa1, mesa2));
+    }
+
+    private UbicacionMesa ubicacion(Mesa mesa, int numero, String sector, Integer x, Integer y) {
+        return new UbicacionMesa(mesa.getId().getValue(), numero, sector, x, y);
+    }
+
+    @Test
+    @DisplayName("Debería permitir intercambiar números y mover mesas de sector")
+    void deberia_intercambiar_numeros_y_mover_de_sector() {
+        // Given
+        DisposicionSalonRequest request = new DisposicionSalonRequest(List.of(
+            ubicacion(mesa1, 2, "Vereda", 0, 0),
+            ubicacion(mesa2, 1, "  ", 3, 1)
+        ));
+
+        // When
+        List<MesaResponse> respuesta = useCase.ejecutar(localId, request);
+
+        // Then
+        assertThat(mesa1.getNumero()).isEqualTo(2);
+        assertThat(mesa1.getSector()).isEqualTo("Vereda");
+        assertThat(mesa2.getNumero()).isEqualTo(1);
+        assertThat(mesa2.getSector()).isEqualTo(Mesa.SECTOR_POR_DEFECTO);
+        assertThat(mesa2.getPosicionX()).isEqualTo(3);
+        assertThat(respuesta).hasSize(2);
+        verify(mesaRepository).guardarTodas(List.of(mesa1, mesa2));
+    }
+
+    @Test
+    @DisplayName("Debería rechazar dos mesas en la misma celda del mismo sector")
+    void deberia_rechazar_mesas_superpuestas() {
+        // Given: la mesa 2 ya está en la celda (1,1) del salón
+        mesa2.ubicar("Salón", 1, 1);
+        DisposicionSalonRequest request = new DisposicionSalonRequest(List.of(
+            ubicacion(mesa1, 1, "salón", 1, 1)
+        ));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.ejecutar(localId, request))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("mismo lugar");
+        verify(mesaRepository, never()).guardarTodas(anyList());
+    }
+
+    @Test
+    @DisplayName("Debería rechazar un número repetido con una mesa no editada")
+    void deberia_rechazar_numero_repetido() {
+        // Given
+        DisposicionSalonRequest request = new DisposicionSalonRequest(List.of(
+            ubicacion(mesa1, 2, null, null, null)
+        ));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.ejecutar(localId, request))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("número 2");
+        verify(mesaRepository, never()).guardarTodas(any());
+    }
+
+    @Test
+    @DisplayName("Debería rechazar una mesa que no pertenece al local")
+    void deberia_rechazar_mesa_ajena() {
+        // Given
+        Mesa ajena = new Mesa(MesaId.generate(), new LocalId(UUID.randomUUID()), 7);
+        DisposicionSalonRequest request = new DisposicionSalonRequest(List.of(
+            ubicacion(ajena, 7, null, 0, 0)
+        ));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.ejecutar(localId, request))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("No se encontró la mesa");
+    }
+}
@@ -0,0 +1,131 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.domain.model.ConsumoPersonal;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.Mozo;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.ConsumoPersonalRepository;
+import com.agustinpalma.comandas.domain.repository.JornadaComercialRepository;
+import com.agustinpalma.comandas.domain.repository.MozoRepository;
+import com.agustinpalma.comandas.domain.service.ConsumoPersonalService;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.verify;
+import static org.mockito.Mockito.verifyNoInteractions;
+import static org.mockito.Mockito.when;
+
+/**
+ * Test unitario del caso de uso ImputarConsumoPersonalUseCase.
+ * Valida los criterios de la HU-132 (Límites de consumo interno del personal).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Imputación de Consumo Interno - Caso de Uso")
+class ImputarConsumoPersonalUseCaseTest {
+
+    @Mock
+    private MozoRepository mozoRepository;
+
+    @Mock
+    private ConsumoPersonalRepository consumoPersonalRepository;
+
+    @Mock
+    private JornadaComercialRepository jornadaComercialRepository;
+
+    private ImputarConsumoPersonalUseCase useCase;
+
+    private LocalId localId;
+    private Mozo lucia;
+    private Producto milanesa;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new ImputarConsumoPersonalUseCase(mozoRepository, consumoPersonalRepository,
+            jornadaComercialRepository, new ConsumoPersonalService());
+        localId = new LocalId(UUID.randomUUID());
+        lucia = new Mozo(MozoId.generate(), localId, "Lucía", true);
+        lucia.definirTopeConsumo(new BigDecimal("1500"));
+        milanesa = new Producto(ProductoId.generate(), localId, "Milanesa", new BigDecimal("1000"), true, "#AA5500");
+    }
+
+    private Pedido pedidoACuenta(LocalDateTime cierre) {
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, new MesaId(UUID.randomUUID()), 1,
+            EstadoPedido.ABIERTO, cierre.minusHours(1));
+        pedido.agregarProducto(milanesa, 1, null);
+        pedido.finalizar(MedioPago.A_CUENTA, cierre);
+        return pedido;
+    }
+
+    @Test
+    @DisplayName("El cierre de la madrugada del 1° suma al tope del mes que termina (HU-153)")
+    void deberia_controlar_el_tope_con_el_mes_de_la_jornada_comercial() {
+        // Given: 01/04 a las 00:30, antes del corte de las 06:00: sigue la jornada del 31/03
+        LocalDateTime cierre = LocalDateTime.of(2026, 4, 1, 0, 30);
+        Pedido pedido = pedidoACuenta(cierre);
+        ConsumoPersonal consumoDeMarzo = ConsumoPersonal.registrar(lucia, pedidoACuenta(LocalDateTime.of(2026, 3, 20, 13, 0)),
+            new BigDecimal("800"), LocalDateTime.of(2026, 3, 20, 14, 0));
+
+        when(mozoRepository.buscarPorId(lucia.getId(), localId)).thenReturn(Optional.of(lucia));
+        when(jornadaComercialRepository.buscarPorLocal(localId)).thenReturn(Optional.empty());
+        when(consumoPersonalRepository.buscarPorEmpleado(lucia.getId(), localId,
+                LocalDateTime.of(2026, 3, 1, 6, 0), LocalDateTime.of(2026, 4, 1, 6, 0)))
+            .thenReturn(List.of(consumoDeMarzo));
+
+        // When
+        String aviso = useCase.imputar(pedido, lucia.getId(), cierre);
+
+        // Then: Se controla contra lo consumido en marzo, no en abril
+        assertThat(aviso)
+            .contains("Lucía superó su tope")
+            .contains("se descuentan $300");
+        verify(consumoPersonalRepository).guardar(any(ConsumoPersonal.class));
+    }
+
+    @Test
+    @DisplayName("Dentro del tope registra el consumo sin aviso")
+    void deberia_registrar_el_consumo_sin_aviso_dentro_del_tope() {
+        // Given
+        LocalDateTime cierre = LocalDateTime.of(2026, 3, 10, 14, 0);
+        when(mozoRepository.buscarPorId(lucia.getId(), localId)).thenReturn(Optional.of(lucia));
+        when(jornadaComercialRepository.buscarPorLocal(localId)).thenReturn(Optional.empty());
+        when(consumoPersonalRepository.buscarPorEmpleado(any(), any(), any(), any())).thenReturn(List.of());
+
+        // When
+        String aviso = useCase.imputar(pedidoACuenta(cierre), lucia.getId(), cierre);
+
+        // Then
+        assertThat(aviso).isNull();
+        verify(consumoPersonalRepository).guardar(any(ConsumoPersonal.class));
+    }
+
+    @Test
+    @DisplayName("Sin empleado no imputa nada")
+    void deberia_no_imputar_sin_empleado() {
+        // When
+        String aviso = useCase.imputar(pedidoACuenta(LocalDateTime.of(2026, 3, 10, 14, 0)), null,
+            LocalDateTime.of(2026, 3, 10, 14, 0));
+
+        // Then
+        assertThat(aviso).isNull();
+        verifyNoInteractions(mozoRepository, consumoPersonalRepository, jornadaComercialRepository);
+    }
+}
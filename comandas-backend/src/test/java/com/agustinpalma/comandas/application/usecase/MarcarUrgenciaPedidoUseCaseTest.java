@@ -0,0 +1,99 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.domain.model.ComandaCocina;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoPedido;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MesaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import com.agustinpalma.comandas.domain.model.ItemComandaCocina;
+import com.agustinpalma.comandas.domain.model.Pedido;
+import com.agustinpalma.comandas.domain.model.Producto;
+import com.agustinpalma.comandas.domain.repository.ComandaCocinaRepository;
+import com.agustinpalma.comandas.domain.repository.PedidoRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso MarcarUrgenciaPedidoUseCase.
+ * Valida los criterios de la HU-137 (Marca de urgencia en pedidos).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Urgencia de Pedidos - Caso de Uso")
+class MarcarUrgenciaPedidoUseCaseTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 10, 9, 21, 30);
+
+    @Mock
+    private PedidoRepository pedidoRepository;
+
+    @Mock
+    private ComandaCocinaRepository comandaCocinaRepository;
+
+    private MarcarUrgenciaPedidoUseCase useCase;
+
+    private LocalId localId;
+    private Pedido pedido;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new MarcarUrgenciaPedidoUseCase(pedidoRepository, comandaCocinaRepository);
+        localId = new LocalId(UUID.randomUUID());
+        Producto milanesa = new Producto(ProductoId.generate(), localId, "Milanesa",
+                new BigDecimal("9500"), true, "#AA5500");
+        pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 7,
+                EstadoPedido.ABIERTO, AHORA.minusMinutes(40));
+        pedido.agregarProducto(milanesa, 1, null);
+    }
+
+    @Test
+    @DisplayName("Marcar el pedido urgente sube sus comandas que siguen en cocina")
+    void deberia_marcar_urgentes_las_comandas_en_preparacion_del_pedido() {
+        // Given
+        pedido.marcarComoEnviadoACocina(AHORA.minusMinutes(25));
+        ComandaCocina enCocina = ComandaCocina.enviar(localId, pedido.getId(), 3, 7, AHORA.minusMinutes(25), 15,
+                List.of(new ItemComandaCocina("Milanesa", 1, null)));
+        when(pedidoRepository.buscarPorId(pedido.getId())).thenReturn(Optional.of(pedido));
+        when(comandaCocinaRepository.buscarPendientesDePedido(pedido.getId(), localId)).thenReturn(List.of(enCocina));
+        when(pedidoRepository.guardar(any(Pedido.class))).thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        useCase.marcarPedido(localId, pedido.getId(), true);
+
+        // Then
+        assertThat(pedido.isUrgente()).isTrue();
+        assertThat(enCocina.esUrgente()).isTrue();
+        verify(comandaCocinaRepository).guardar(enCocina);
+    }
+
+    @Test
+    @DisplayName("Un pedido de otro local no se puede marcar")
+    void deberia_rechazar_un_pedido_de_otro_local() {
+        // Given
+        LocalId otroLocal = new LocalId(UUID.randomUUID());
+        when(pedidoRepository.buscarPorId(pedido.getId())).thenReturn(Optional.of(pedido));
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.marcarPedido(otroLocal, pedido.getId(), true))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("No se encontró el pedido");
+        assertThat(pedido.isUrgente()).isFalse();
+        verifyNoInteractions(comandaCocinaRepository);
+        verify(pedidoRepository, never()).guardar(any());
+    }
+}
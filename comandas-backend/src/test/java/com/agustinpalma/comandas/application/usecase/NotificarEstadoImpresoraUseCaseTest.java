@@ -0,0 +1,69 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.EstadoImpresoraEvento;
+import com.agustinpalma.comandas.application.dto.EstadoImpresoraEvento.Estado;
+import com.agustinpalma.comandas.application.ports.output.NotificadorTiempoRealPort;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.ArgumentMatchers.anyString;
+import static org.mockito.Mockito.*;
+
+/**
+ * Test unitario del caso de uso NotificarEstadoImpresoraUseCase.
+ * Valida los criterios de la HU-158 (estado de las impresoras).
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Notificar Estado de Impresora - Caso de Uso")
+class NotificarEstadoImpresoraUseCaseTest {
+
+    @Mock
+    private NotificadorTiempoRealPort notificador;
+
+    private NotificarEstadoImpresoraUseCase useCase;
+
+    private LocalId localId;
+
+    @BeforeEach
+    void setUp() {
+        useCase = new NotificarEstadoImpresoraUseCase(notificador);
+        localId = new LocalId(UUID.randomUUID());
+    }
+
+    @Test
+    @DisplayName("La cocina sin papel se avisa a todas las terminales del local")
+    void deberia_difundir_el_cambio_de_estado() {
+        // Given
+        EstadoImpresoraEvento sinPapel = new EstadoImpresoraEvento(
+            " cocina ", Estado.SIN_PAPEL, "La impresora de cocina se quedó sin papel");
+
+        // When
+        useCase.ejecutar(localId, sinPapel);
+
+        // Then: la estación llega sin espacios
+        verify(notificador).publicar(localId, EstadoImpresoraEvento.NOMBRE, new EstadoImpresoraEvento(
+            "cocina", Estado.SIN_PAPEL, "La impresora de cocina se quedó sin papel"));
+    }
+
+    @Test
+    @DisplayName("Un aviso sin estación se rechaza sin notificar")
+    void deberia_rechazar_aviso_sin_estacion() {
+        // Given
+        EstadoImpresoraEvento sinEstacion = new EstadoImpresoraEvento("  ", Estado.DESCONECTADA, null);
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.ejecutar(localId, sinEstacion))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("estación");
+        verify(notificador, never()).publicar(any(), anyString(), any());
+    }
+}
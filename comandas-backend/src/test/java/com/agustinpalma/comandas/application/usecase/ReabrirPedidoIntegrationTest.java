@@ -1,6 +1,7 @@
 package com.agustinpalma.comandas.application.usecase;
 
 import com.agustinpalma.comandas.application.dto.AgregarProductoRequest;
+import com.agustinpalma.comandas.application.dto.CerrarMesaCommand;
 import com.agustinpalma.comandas.application.dto.CerrarMesaResponse;
 import com.agustinpalma.comandas.application.dto.PagoRequest;
 import com.agustinpalma.comandas.application.dto.ReabrirPedidoResponse;
@@ -139,7 +140,7 @@ class ReabrirPedidoIntegrationTest {
             new PagoRequest(MedioPago.EFECTIVO, total)
         );
 
-        return cerrarMesaUseCase.ejecutar(localId, mesa.getId(), pagos);
+        return cerrarMesaUseCase.ejecutar(CerrarMesaCommand.de(localId, mesa.getId(), pagos));
     }
 
     // ============================================
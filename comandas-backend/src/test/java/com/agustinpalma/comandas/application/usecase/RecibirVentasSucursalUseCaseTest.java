@@ -0,0 +1,148 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.LoteVentasSucursalDTO;
+import com.agustinpalma.comandas.application.dto.LoteVentasSucursalDTO.VentaCerradaDTO;
+import com.agustinpalma.comandas.application.dto.RecepcionVentasResponse;
+import com.agustinpalma.comandas.application.ports.output.IdentidadSucursalProvider;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.SucursalId;
+import com.agustinpalma.comandas.domain.model.VentaSucursal;
+import com.agustinpalma.comandas.domain.repository.VentaSucursalRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.ArgumentCaptor;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.*;
+
+/**
+ * Tests unitarios para RecibirVentasSucursalUseCase.
+ * HU-107: recepción de ventas de otras sucursales en el consolidado.
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("RecibirVentasSucursalUseCase - Tests de comportamiento")
+class RecibirVentasSucursalUseCaseTest {
+
+    @Mock
+    private VentaSucursalRepository ventaSucursalRepository;
+
+    @Mock
+    private IdentidadSucursalProvider identidadSucursalProvider;
+
+    private RecibirVentasSucursalUseCase useCase;
+
+    private LocalId localId;
+    private SucursalId central;
+
+    @BeforeEach
+    void setUp() {
+        Clock clock = Clock.fixed(
+            Instant.parse("2026-02-06T22:00:00Z"),
+            ZoneId.of("America/Argentina/Buenos_Aires")
+        );
+        useCase = new RecibirVentasSucursalUseCase(ventaSucursalRepository, identidadSucursalProvider, clock);
+
+        localId = new LocalId(UUID.randomUUID());
+        central = SucursalId.generate();
+        lenient().when(identidadSucursalProvider.getSucursalId()).thenReturn(central);
+    }
+
+    @Test
+    @DisplayName("Debe guardar las ventas del lote y devolver como cursor el último cierre")
+    void deberia_guardar_ventas_y_devolver_cursor_del_ultimo_cierre() {
+        // Given: dos ventas de la sucursal Norte, con A_CUENTA en la segunda
+        UUID norte = UUID.randomUUID();
+        LocalDateTime primera = LocalDateTime.of(2026, 2, 6, 13, 10);
+        LocalDateTime segunda = LocalDateTime.of(2026, 2, 6, 14, 45);
+        LoteVentasSucursalDTO lote = new LoteVentasSucursalDTO(norte, "Norte", List.of(
+            new VentaCerradaDTO(UUID.randomUUID(), 1, primera, Map.of(MedioPago.EFECTIVO, new BigDecimal("3000"))),
+            new VentaCerradaDTO(UUID.randomUUID(), 2, segunda, Map.of(
+                MedioPago.TARJETA, new BigDecimal("1500"),
+                MedioPago.A_CUENTA, new BigDecimal("500")
+            ))
+        ));
+
+        // When
+        RecepcionVentasResponse response = useCase.ejecutar(localId, lote);
+
+        // Then
+        assertThat(response.recibidas()).isEqualTo(2);
+        assertThat(response.cursor()).isEqualTo(segunda);
+        assertThat(response.cursorPedidoId()).isEqualTo(lote.ventas().get(1).pedidoId());
+
+        @SuppressWarnings("unchecked")
+        ArgumentCaptor<List<VentaSucursal>> captor = ArgumentCaptor.forClass(List.class);
+        verify(ventaSucursalRepository).guardarTodas(captor.capture());
+        List<VentaSucursal> guardadas = captor.getValue();
+        assertThat(guardadas).allSatisfy(v -> {
+            assertThat(v.getLocalId()).isEqualTo(localId);
+            assertThat(v.getSucursalId()).isEqualTo(new SucursalId(norte));
+            assertThat(v.getNombreSucursal()).isEqualTo("Norte");
+        });
+        assertThat(guardadas.get(1).getTotalVentas()).isEqualByComparingTo("1500");
+    }
+
+    @Test
+    @DisplayName("Un lote vacío no avanza el cursor")
+    void deberia_devolver_cursor_nulo_si_el_lote_esta_vacio() {
+        // Given
+        LoteVentasSucursalDTO lote = new LoteVentasSucursalDTO(UUID.randomUUID(), "Norte", List.of());
+
+        // When
+        RecepcionVentasResponse response = useCase.ejecutar(localId, lote);
+
+        // Then
+        assertThat(response.recibidas()).isZero();
+        assertThat(response.cursor()).isNull();
+        assertThat(response.cursorPedidoId()).isNull();
+    }
+
+    @Test
+    @DisplayName("Entre ventas del mismo cierre, el cursor queda en el último pedido")
+    void deberia_desempatar_el_cursor_por_pedido() {
+        // Given: dos ventas cerradas en el mismo segundo, en cualquier orden
+        LocalDateTime cierre = LocalDateTime.of(2026, 2, 6, 14, 45);
+        UUID primero = UUID.fromString("00000000-0000-0000-0000-000000000001");
+        UUID segundo = UUID.fromString("00000000-0000-0000-0000-000000000002");
+        LoteVentasSucursalDTO lote = new LoteVentasSucursalDTO(UUID.randomUUID(), "Norte", List.of(
+            new VentaCerradaDTO(segundo, 2, cierre, Map.of(MedioPago.EFECTIVO, new BigDecimal("1000"))),
+            new VentaCerradaDTO(primero, 1, cierre, Map.of(MedioPago.EFECTIVO, new BigDecimal("2000")))
+        ));
+
+        // When
+        RecepcionVentasResponse response = useCase.ejecutar(localId, lote);
+
+        // Then: la próxima exportación sigue después de "segundo"
+        assertThat(response.cursor()).isEqualTo(cierre);
+        assertThat(response.cursorPedidoId()).isEqualTo(segundo);
+    }
+
+    @Test
+    @DisplayName("Debe rechazar un lote enviado por la propia instalación")
+    void deberia_rechazar_lote_de_la_misma_sucursal() {
+        // Given: la sync apunta por error a sí misma
+        LoteVentasSucursalDTO lote = new LoteVentasSucursalDTO(central.getValue(), "Centro", List.of());
+
+        // When / Then
+        assertThatThrownBy(() -> useCase.ejecutar(localId, lote))
+            .isInstanceOf(IllegalStateException.class)
+            .hasMessageContaining("misma sucursal");
+        verify(ventaSucursalRepository, never()).guardarTodas(any());
+    }
+}
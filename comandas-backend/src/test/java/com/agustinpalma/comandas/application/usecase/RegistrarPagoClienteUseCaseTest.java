@@ -0,0 +1,149 @@
+package com.agustinpalma.comandas.application.usecase;
+
+import com.agustinpalma.comandas.application.dto.MovimientoCuentaCorrienteResponse;
+import com.agustinpalma.comandas.application.dto.PagoClienteRequest;
+import com.agustinpalma.comandas.domain.model.Cliente;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimiento;
+import com.agustinpalma.comandas.domain.model.DomainEnums.TipoMovimientoCuenta;
+import com.agustinpalma.comandas.domain.model.DomainIds.ClienteId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import com.agustinpalma.comandas.domain.model.MovimientoCaja;
+import com.agustinpalma.comandas.domain.model.MovimientoCuentaCorriente;
+import com.agustinpalma.comandas.domain.repository.ClienteRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCajaRepository;
+import com.agustinpalma.comandas.domain.repository.MovimientoCuentaCorrienteRepository;
+import com.agustinpalma.comandas.domain.repository.TurnoCajaRepository;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.ArgumentCaptor;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.math.BigDecimal;
+import java.time.Clock;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.mockito.ArgumentMatchers.any;
+import static org.mockito.Mockito.*;
+
+/**
+ * Tests unitarios para RegistrarPagoClienteUseCase.
+ * HU-104: pagos parciales de cuenta corriente y su impacto en caja.
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("RegistrarPagoClienteUseCase - Tests de comportamiento")
+class RegistrarPagoClienteUseCaseTest {
+
+    @Mock
+    private ClienteRepository clienteRepository;
+
+    @Mock
+    private MovimientoCuentaCorrienteRepository movimientoCuentaCorrienteRepository;
+
+    @Mock
+    private MovimientoCajaRepository movimientoCajaRepository;
+
+    @Mock
+    private TurnoCajaRepository turnoCajaRepository;
+
+    private RegistrarPagoClienteUseCase useCase;
+
+    private LocalId localId;
+    private Cliente cliente;
+
+    @BeforeEach
+    void setUp() {
+        Clock clock = Clock.fixed(
+            Instant.parse("2026-02-06T22:00:00Z"),
+            ZoneId.of("America/Argentina/Buenos_Aires")
+        );
+        useCase = new RegistrarPagoClienteUseCase(
+            clienteRepository, movimientoCuentaCorrienteRepository, movimientoCajaRepository,
+            turnoCajaRepository, clock
+        );
+
+        localId = new LocalId(UUID.randomUUID());
+        cliente = new Cliente(ClienteId.generate(), localId, "Estudio Pérez", null, new BigDecimal("20000"), true);
+    }
+
+    @Test
+    @DisplayName("Debe registrar un pago parcial en efectivo y sumarlo a la caja")
+    void deberia_registrar_pago_parcial_en_efectivo_con_ingreso_de_caja() {
+        // Given: deuda de $5000
+        when(clienteRepository.buscarPorIdYLocal(cliente.getId(), localId)).thenReturn(Optional.of(cliente));
+        when(movimientoCuentaCorrienteRepository.buscarPorCliente(cliente.getId(), localId))
+            .thenReturn(List.of(cargo("5000")));
+        when(movimientoCuentaCorrienteRepository.guardar(any(MovimientoCuentaCorriente.class)))
+            .thenAnswer(inv -> inv.getArgument(0));
+
+        // When: paga $2000 en efectivo
+        MovimientoCuentaCorrienteResponse response = useCase.ejecutar(
+            cliente.getId(), localId, new PagoClienteRequest(MedioPago.EFECTIVO, new BigDecimal("2000"), null)
+        );
+
+        // Then
+        assertThat(response.tipo()).isEqualTo(TipoMovimientoCuenta.PAGO);
+        assertThat(response.monto()).isEqualByComparingTo("2000");
+        assertThat(response.medioPago()).isEqualTo(MedioPago.EFECTIVO);
+
+        ArgumentCaptor<MovimientoCaja> captor = ArgumentCaptor.forClass(MovimientoCaja.class);
+        verify(movimientoCajaRepository).guardar(captor.capture());
+        assertThat(captor.getValue().getTipo()).isEqualTo(TipoMovimiento.INGRESO);
+        assertThat(captor.getValue().getMonto()).isEqualByComparingTo("2000");
+        assertThat(captor.getValue().getDescripcion()).contains("Estudio Pérez");
+    }
+
+    @Test
+    @DisplayName("No debe generar movimiento de caja para pagos por transferencia")
+    void deberia_no_registrar_ingreso_de_caja_si_no_es_efectivo() {
+        // Given
+        when(clienteRepository.buscarPorIdYLocal(cliente.getId(), localId)).thenReturn(Optional.of(cliente));
+        when(movimientoCuentaCorrienteRepository.buscarPorCliente(cliente.getId(), localId))
+            .thenReturn(List.of(cargo("5000")));
+        when(movimientoCuentaCorrienteRepository.guardar(any(MovimientoCuentaCorriente.class)))
+            .thenAnswer(inv -> inv.getArgument(0));
+
+        // When
+        useCase.ejecutar(cliente.getId(), localId,
+            new PagoClienteRequest(MedioPago.TRANSFERENCIA, new BigDecimal("5000"), "Saldo enero"));
+
+        // Then
+        verify(movimientoCajaRepository, never()).guardar(any());
+    }
+
+    @Test
+    @DisplayName("Debe rechazar un pago mayor a la deuda actual")
+    void deberia_rechazar_pago_mayor_a_la_deuda() {
+        // Given: deuda de $1000
+        when(clienteRepository.buscarPorIdYLocal(cliente.getId(), localId)).thenReturn(Optional.of(cliente));
+        when(movimientoCuentaCorrienteRepository.buscarPorCliente(cliente.getId(), localId))
+            .thenReturn(List.of(cargo("1000")));
+
+        // When/Then
+        assertThatThrownBy(() -> useCase.ejecutar(cliente.getId(), localId,
+                new PagoClienteRequest(MedioPago.EFECTIVO, new BigDecimal("1500"), null)))
+            .isInstanceOf(IllegalArgumentException.class)
+            .hasMessageContaining("supera la deuda actual");
+
+        verify(movimientoCuentaCorrienteRepository, never()).guardar(any());
+        verify(movimientoCajaRepository, never()).guardar(any());
+    }
+
+    private MovimientoCuentaCorriente cargo(String monto) {
+        return MovimientoCuentaCorriente.cargo(
+            cliente.getId(), localId, PedidoId.generate(), new BigDecimal(monto),
+            LocalDateTime.of(2026, 2, 1, 13, 0), "Pedido #1 - Mesa 1"
+        );
+    }
+}
@@ -134,7 +134,7 @@ class StockIntegrationTest {
         List<PagoRequest> pagos = List.of(
             new PagoRequest(MedioPago.EFECTIVO, total)
         );
-        return cerrarMesaUseCase.ejecutar(localId, mesa.getId(), pagos);
+        return cerrarMesaUseCase.ejecutar(CerrarMesaCommand.de(localId, mesa.getId(), pagos));
     }
 
     // ============================================
@@ -372,7 +372,7 @@ class StockIntegrationTest {
             BigDecimal total = new BigDecimal("1500"); // 3 x $500
 
             List<PagoRequest> pagos = List.of(new PagoRequest(MedioPago.EFECTIVO, total));
-            cerrarMesaUseCase.ejecutar(localId, mesa2.getId(), pagos);
+            cerrarMesaUseCase.ejecutar(CerrarMesaCommand.de(localId, mesa2.getId(), pagos));
 
             // Then: stock negativo es permitido
             Producto actualizado = productoRepository.buscarPorId(productoPocoStock.getId())
@@ -0,0 +1,86 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.*;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para AumentoPrecios.
+ * Sin Spring, sin base de datos.
+ *
+ * HU-145: aumento porcentual con redondeo hacia arriba y vigencia programada.
+ */
+class AumentoPreciosTest {
+
+    // Miércoles
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 10, 14, 10, 0);
+
+    private final LocalId localId = LocalId.generate();
+    private final CategoriaId bebidas = CategoriaId.generate();
+
+    private Producto producto(String nombre, String precio, CategoriaId categoriaId) {
+        Producto producto = new Producto(ProductoId.generate(), localId, nombre, new BigDecimal(precio), true, "#FF0000");
+        producto.actualizarCategoria(categoriaId);
+        return producto;
+    }
+
+    @Test
+    void deberia_redondear_hacia_arriba_al_multiplo() {
+        AumentoPrecios aumento = AumentoPrecios.programar(localId, null, new BigDecimal("3"),
+            new BigDecimal("50"), AHORA.toLocalDate(), null, AHORA);
+
+        assertEquals(0, new BigDecimal("1050").compareTo(aumento.calcularPrecio(new BigDecimal("1000"))));
+        assertEquals(0, new BigDecimal("2100").compareTo(aumento.calcularPrecio(new BigDecimal("2038"))));
+        assertEquals(0, BigDecimal.ZERO.compareTo(aumento.calcularPrecio(BigDecimal.ZERO)));
+
+        AumentoPrecios sinRedondeo = AumentoPrecios.programar(localId, null, new BigDecimal("12.5"),
+            null, AHORA.toLocalDate(), null, AHORA);
+        assertEquals(0, new BigDecimal("1158.75").compareTo(sinRedondeo.calcularPrecio(new BigDecimal("1030"))));
+    }
+
+    @Test
+    void deberia_aplicar_solo_a_la_categoria_y_guardar_el_precio_anterior() {
+        Producto cerveza = producto("Cerveza", "3000", bebidas);
+        Producto milanesa = producto("Milanesa", "9000", CategoriaId.generate());
+        AumentoPrecios aumento = AumentoPrecios.programar(localId, bebidas, new BigDecimal("10"),
+            new BigDecimal("100"), AHORA.toLocalDate(), null, AHORA);
+
+        List<Producto> modificados = aumento.aplicar(List.of(cerveza, milanesa), AHORA);
+
+        assertEquals(List.of(cerveza), modificados);
+        assertEquals(0, new BigDecimal("3300").compareTo(cerveza.getPrecio()));
+        assertEquals(0, new BigDecimal("9000").compareTo(milanesa.getPrecio()));
+        assertEquals(EstadoAumentoPrecios.APLICADO, aumento.getEstado());
+        assertEquals(1, aumento.getCambios().size());
+        assertEquals(0, new BigDecimal("3000").compareTo(aumento.getCambios().get(0).getPrecioAnterior()));
+        assertThrows(IllegalStateException.class, aumento::cancelar);
+    }
+
+    @Test
+    void no_deberia_aplicar_un_aumento_programado_antes_de_su_vigencia() {
+        LocalDate viernes = AHORA.toLocalDate().plusDays(2);
+        AumentoPrecios aumento = AumentoPrecios.programar(localId, null, new BigDecimal("5"), null, viernes,
+            null, AHORA);
+        Producto cerveza = producto("Cerveza", "3000", bebidas);
+
+        assertThrows(IllegalStateException.class, () -> aumento.aplicar(List.of(cerveza), AHORA));
+        aumento.aplicar(List.of(cerveza), viernes.atTime(9, 0));
+        assertEquals(0, new BigDecimal("3150").compareTo(cerveza.getPrecio()));
+        assertThrows(IllegalArgumentException.class, () -> AumentoPrecios.programar(localId, null,
+            new BigDecimal("5"), null, AHORA.toLocalDate().minusDays(1), null, AHORA));
+    }
+
+    @Test
+    void deberia_saltear_el_fin_de_semana_para_el_proximo_dia_habil() {
+        assertEquals(LocalDate.of(2026, 10, 15), AumentoPrecios.proximoDiaHabil(LocalDate.of(2026, 10, 14)));
+        assertEquals(LocalDate.of(2026, 10, 19), AumentoPrecios.proximoDiaHabil(LocalDate.of(2026, 10, 16)));
+        assertEquals(LocalDate.of(2026, 10, 19), AumentoPrecios.proximoDiaHabil(LocalDate.of(2026, 10, 18)));
+    }
+}
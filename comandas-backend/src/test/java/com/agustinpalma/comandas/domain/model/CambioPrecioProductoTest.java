@@ -0,0 +1,56 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.*;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para CambioPrecioProducto.
+ * Sin Spring, sin base de datos.
+ *
+ * HU-146: registro de auditoría de precios del catálogo.
+ */
+class CambioPrecioProductoTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 10, 14, 10, 0);
+
+    private final LocalId localId = LocalId.generate();
+
+    @Test
+    void deberia_registrar_el_precio_anterior_y_el_nuevo() {
+        Producto producto = new Producto(ProductoId.generate(), localId, "Milanesa", new BigDecimal("8800"), true, "#FF0000");
+
+        CambioPrecioProducto cambio = CambioPrecioProducto.registrar(producto, new BigDecimal("8000"),
+            OrigenCambioPrecio.EDICION, null, "  Ana  ", AHORA);
+
+        assertEquals(0, new BigDecimal("8000").compareTo(cambio.getPrecioAnterior()));
+        assertEquals(0, new BigDecimal("8800").compareTo(cambio.getPrecioNuevo()));
+        assertEquals(0, new BigDecimal("10.00").compareTo(cambio.getVariacionPorcentual()));
+        assertEquals("Ana", cambio.getResponsable());
+        assertEquals("Milanesa", cambio.getNombreProducto());
+    }
+
+    @Test
+    void deberia_rechazar_registro_si_el_precio_no_cambio() {
+        Producto producto = new Producto(ProductoId.generate(), localId, "Flan", new BigDecimal("3000.00"), true, "#FF0000");
+
+        assertThrows(IllegalArgumentException.class, () -> CambioPrecioProducto.registrar(producto,
+            new BigDecimal("3000"), OrigenCambioPrecio.EDICION, null, null, AHORA));
+    }
+
+    @Test
+    void variacion_es_null_si_el_precio_anterior_era_cero() {
+        Producto producto = new Producto(ProductoId.generate(), localId, "Agua", new BigDecimal("500"), true, "#FF0000");
+
+        CambioPrecioProducto cambio = CambioPrecioProducto.registrar(producto, BigDecimal.ZERO,
+            OrigenCambioPrecio.AUMENTO_MASIVO, AumentoPreciosId.generate(), "", AHORA);
+
+        assertNull(cambio.getVariacionPorcentual());
+        assertNull(cambio.getResponsable());
+    }
+}
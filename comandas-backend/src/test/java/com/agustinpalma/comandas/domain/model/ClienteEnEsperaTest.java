@@ -0,0 +1,76 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.*;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.Test;
+
+import java.time.LocalDateTime;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para ClienteEnEspera.
+ * Sin Spring, sin base de datos.
+ *
+ * HU-149: lista de espera de mesas con aviso cuando se libera una mesa adecuada.
+ */
+class ClienteEnEsperaTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 10, 14, 21, 0);
+
+    private final LocalId localId = LocalId.generate();
+
+    private Mesa mesaLibre(int numero, Integer capacidad) {
+        Mesa mesa = new Mesa(MesaId.generate(), localId, numero);
+        mesa.definirCapacidad(capacidad);
+        return mesa;
+    }
+
+    @Test
+    void deberia_guardarle_la_mesa_y_contar_la_espera_hasta_que_se_sienta() {
+        ClienteEnEspera cliente = ClienteEnEspera.anotar(localId, "  Flia. Gómez ", 4, "11 5555-1234", null, AHORA);
+        Mesa mesa = mesaLibre(7, 4);
+
+        cliente.avisar(mesa, AHORA.plusMinutes(25));
+
+        assertEquals(EstadoEspera.AVISADO, cliente.getEstado());
+        assertEquals(mesa.getId(), cliente.getMesaId());
+        assertEquals("Flia. Gómez", cliente.getNombreCliente());
+        assertEquals(40, cliente.minutosEsperando(AHORA.plusMinutes(40)));
+
+        cliente.sentar(AHORA.plusMinutes(32));
+
+        assertFalse(cliente.estaEnLista());
+        assertEquals(32, cliente.minutosEsperando(AHORA.plusHours(2)));
+        assertThrows(IllegalStateException.class, () -> cliente.cancelar(AHORA.plusHours(2)));
+    }
+
+    @Test
+    void deberia_rechazar_aviso_con_mesa_ocupada_o_donde_no_entra_el_grupo() {
+        ClienteEnEspera cliente = ClienteEnEspera.anotar(localId, "Martín", 6, null, null, AHORA);
+        Mesa chica = mesaLibre(3, 4);
+        Mesa ocupada = mesaLibre(8, 8);
+        ocupada.abrir();
+
+        assertThrows(IllegalArgumentException.class, () -> cliente.avisar(chica, AHORA));
+        assertThrows(IllegalStateException.class, () -> cliente.avisar(ocupada, AHORA));
+        assertEquals(EstadoEspera.ESPERANDO, cliente.getEstado());
+    }
+
+    @Test
+    void deberia_aceptar_mesa_sin_capacidad_definida() {
+        ClienteEnEspera cliente = ClienteEnEspera.anotar(localId, "Lu", 2, null, null, AHORA);
+
+        cliente.avisar(mesaLibre(12, null), AHORA);
+
+        assertEquals(EstadoEspera.AVISADO, cliente.getEstado());
+    }
+
+    @Test
+    void deberia_rechazar_espera_sin_personas() {
+        assertThrows(IllegalArgumentException.class,
+            () -> ClienteEnEspera.anotar(localId, "Ana", 0, null, null, AHORA));
+        assertThrows(IllegalArgumentException.class,
+            () -> ClienteEnEspera.anotar(localId, " ", 2, null, null, AHORA));
+    }
+}
@@ -0,0 +1,68 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.util.Optional;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para Cliente.
+ * Sin Spring, sin base de datos.
+ *
+ * HU-168: la promo de cumpleaños vale de lunes a domingo de la semana del cumpleaños.
+ */
+class ClienteTest {
+
+    private final LocalId localId = LocalId.generate();
+
+    private Cliente nacidoEl(LocalDate fechaNacimiento) {
+        return new Cliente(ClienteId.generate(), localId, "Ana", null, BigDecimal.ZERO, true,
+            fechaNacimiento, null, null);
+    }
+
+    @Test
+    void deberia_reconocer_la_semana_del_cumpleanios_de_lunes_a_domingo() {
+        // Cumple el miércoles 4/2/2026: la semana va del lunes 2/2 al domingo 8/2
+        Cliente cliente = nacidoEl(LocalDate.of(1990, 2, 4));
+
+        assertEquals(Optional.of(LocalDate.of(2026, 2, 2)), cliente.semanaDeCumpleanios(LocalDate.of(2026, 2, 2)));
+        assertEquals(Optional.of(LocalDate.of(2026, 2, 2)), cliente.semanaDeCumpleanios(LocalDate.of(2026, 2, 8)));
+        assertTrue(cliente.semanaDeCumpleanios(LocalDate.of(2026, 2, 1)).isEmpty());
+        assertTrue(cliente.semanaDeCumpleanios(LocalDate.of(2026, 2, 9)).isEmpty());
+    }
+
+    @Test
+    void deberia_festejar_el_29_de_febrero_el_28_en_anios_no_bisiestos() {
+        // En 2027 el 28/2 cae domingo: la semana empieza el lunes 22/2
+        Cliente cliente = nacidoEl(LocalDate.of(2000, 2, 29));
+
+        assertEquals(Optional.of(LocalDate.of(2027, 2, 22)), cliente.semanaDeCumpleanios(LocalDate.of(2027, 2, 22)));
+        assertTrue(cliente.semanaDeCumpleanios(LocalDate.of(2027, 3, 1)).isEmpty());
+    }
+
+    @Test
+    void deberia_tomar_la_semana_que_cruza_el_fin_de_anio() {
+        // Cumple el miércoles 30/12/2026: la semana termina el domingo 3/1/2027
+        Cliente cliente = nacidoEl(LocalDate.of(1985, 12, 30));
+
+        assertEquals(Optional.of(LocalDate.of(2026, 12, 28)), cliente.semanaDeCumpleanios(LocalDate.of(2027, 1, 2)));
+    }
+
+    @Test
+    void deberia_validar_la_ficha() {
+        Cliente sinFecha = nacidoEl(null);
+        assertTrue(sinFecha.semanaDeCumpleanios(LocalDate.of(2026, 2, 4)).isEmpty());
+
+        assertThrows(IllegalArgumentException.class, () -> nacidoEl(LocalDate.of(1850, 1, 1)));
+        assertThrows(IllegalArgumentException.class,
+            () -> sinFecha.actualizarFicha(null, null, "x".repeat(301)));
+
+        sinFecha.actualizarFicha(LocalDate.of(1990, 5, 1), "  Mesa junto a la ventana ", " ");
+        assertEquals("Mesa junto a la ventana", sinFecha.getPreferencias());
+        assertNull(sinFecha.getAlergias());
+    }
+}
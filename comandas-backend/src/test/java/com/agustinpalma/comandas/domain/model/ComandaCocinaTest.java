@@ -0,0 +1,137 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.PedidoId;
+import org.junit.jupiter.api.Test;
+
+import java.time.Duration;
+import java.time.LocalDateTime;
+import java.util.Arrays;
+import java.util.List;
+import java.util.stream.Stream;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para ComandaCocina.
+ * Sin Spring, sin base de datos.
+ */
+class ComandaCocinaTest {
+
+    private static final LocalDateTime ENVIO = LocalDateTime.of(2026, 10, 9, 21, 0);
+
+    private ComandaCocina comanda(int minutosAlerta) {
+        return ComandaCocina.enviar(LocalId.generate(), PedidoId.generate(), 4, 120, ENVIO, minutosAlerta,
+                List.of(new ItemComandaCocina("Hamburguesa doble", 2, "Sin cebolla")));
+    }
+
+    // ============================================
+    // Tests: HU-119 Tiempos de cocina y alertas de demora
+    // ============================================
+
+    @Test
+    void deberia_tomar_el_umbral_mas_largo_de_las_categorias_o_el_por_defecto() {
+        assertEquals(25, ComandaCocina.resolverMinutosAlerta(Arrays.asList(10, null, 25)));
+        assertEquals(ComandaCocina.MINUTOS_ALERTA_POR_DEFECTO,
+                ComandaCocina.resolverMinutosAlerta(Arrays.asList(null, null)));
+        assertEquals(ComandaCocina.MINUTOS_ALERTA_POR_DEFECTO, ComandaCocina.resolverMinutosAlerta(List.of()));
+    }
+
+    @Test
+    void deberia_marcarse_demorada_al_superar_el_umbral_mientras_sigue_en_preparacion() {
+        ComandaCocina comanda = comanda(12);
+
+        assertFalse(comanda.estaDemorada(ENVIO.plusMinutes(12)));
+        assertTrue(comanda.estaDemorada(ENVIO.plusMinutes(12).plusSeconds(1)));
+        assertEquals(Duration.ofMinutes(30), comanda.tiempoEnCocina(ENVIO.plusMinutes(30)));
+    }
+
+    @Test
+    void deberia_congelar_el_tiempo_al_marcarse_lista_y_no_permitir_marcarla_dos_veces() {
+        ComandaCocina comanda = comanda(12);
+
+        comanda.marcarLista(ENVIO.plusMinutes(8));
+
+        assertTrue(comanda.estaLista());
+        assertEquals(Duration.ofMinutes(8), comanda.tiempoEnCocina(ENVIO.plusHours(2)));
+        assertFalse(comanda.estaDemorada(ENVIO.plusHours(2)));
+        assertThrows(IllegalStateException.class, () -> comanda.marcarLista(ENVIO.plusMinutes(9)));
+    }
+
+    // ============================================
+    // Tests: HU-136 Bump bar
+    // ============================================
+
+    @Test
+    void deberia_volver_a_preparacion_con_el_reloj_desde_el_envio_al_recuperarla() {
+        ComandaCocina comanda = comanda(12);
+        comanda.marcarLista(ENVIO.plusMinutes(8));
+
+        comanda.recuperar(ENVIO.plusMinutes(9));
+
+        assertFalse(comanda.estaLista());
+        assertEquals(Duration.ofMinutes(10), comanda.tiempoEnCocina(ENVIO.plusMinutes(10)));
+        assertThrows(IllegalStateException.class, () -> comanda.recuperar(ENVIO.plusMinutes(10)));
+    }
+
+    @Test
+    void no_deberia_recuperar_una_comanda_lista_hace_mas_de_media_hora() {
+        ComandaCocina comanda = comanda(12);
+        comanda.marcarLista(ENVIO.plusMinutes(8));
+
+        assertThrows(IllegalStateException.class,
+                () -> comanda.recuperar(ENVIO.plusMinutes(8 + ComandaCocina.MINUTOS_PARA_RECUPERAR + 1)));
+        assertTrue(comanda.estaLista());
+    }
+
+    // ============================================
+    // Tests: HU-137 Marca de urgencia
+    // ============================================
+
+    @Test
+    void deberia_ordenar_primero_las_urgentes_y_luego_por_antiguedad() {
+        LocalId localId = LocalId.generate();
+        ComandaCocina vieja = ComandaCocina.enviar(localId, PedidoId.generate(), 1, 10, ENVIO, 15,
+                List.of(new ItemComandaCocina("Pizza", 1, null)));
+        ComandaCocina conItemUrgente = ComandaCocina.enviar(localId, PedidoId.generate(), 2, 11, ENVIO.plusMinutes(5), 15,
+                List.of(new ItemComandaCocina("Milanesa", 1, null, true)));
+        ComandaCocina nueva = ComandaCocina.enviar(localId, PedidoId.generate(), 3, 12, ENVIO.plusMinutes(8), 15,
+                List.of(new ItemComandaCocina("Flan", 1, null)));
+        nueva.marcarUrgente(true);
+
+        List<ComandaCocina> ordenadas = Stream.of(vieja, nueva, conItemUrgente)
+                .sorted(ComandaCocina.PRIORIDAD_EN_COCINA)
+                .toList();
+
+        assertEquals(List.of(conItemUrgente, nueva, vieja), ordenadas);
+    }
+
+    @Test
+    void no_deberia_perder_la_urgencia_de_sus_items_al_desmarcar_el_pedido() {
+        ComandaCocina comanda = ComandaCocina.enviar(LocalId.generate(), PedidoId.generate(), 4, 120, ENVIO, 15,
+                List.of(new ItemComandaCocina("Milanesa", 1, null, true)), true);
+
+        comanda.marcarUrgente(false);
+
+        assertFalse(comanda.isPedidoUrgente());
+        assertTrue(comanda.esUrgente());
+    }
+
+    // ============================================
+    // Tests: HU-138 Horario prometido de retiro
+    // ============================================
+
+    @Test
+    void deberia_estar_atrasada_si_sale_de_cocina_despues_del_horario_prometido() {
+        ComandaCocina comanda = comanda(15);
+        assertFalse(comanda.estaAtrasada(ENVIO.plusHours(3)));
+
+        comanda.asignarHoraPrometida(ENVIO.plusMinutes(20));
+
+        assertFalse(comanda.estaAtrasada(ENVIO.plusMinutes(20)));
+        assertTrue(comanda.estaAtrasada(ENVIO.plusMinutes(21)));
+        // Lista a tiempo: no se atrasa aunque se consulte más tarde
+        comanda.marcarLista(ENVIO.plusMinutes(18));
+        assertFalse(comanda.estaAtrasada(ENVIO.plusMinutes(40)));
+    }
+}
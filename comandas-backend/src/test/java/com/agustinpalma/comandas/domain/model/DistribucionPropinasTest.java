@@ -0,0 +1,128 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.*;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Map;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para DistribucionPropinas.
+ * Sin Spring, sin base de datos.
+ *
+ * HU-141: el pozo del turno se reparte al centavo entre el personal que fichó.
+ */
+class DistribucionPropinasTest {
+
+    private static final LocalDateTime APERTURA = LocalDateTime.of(2026, 10, 14, 19, 0);
+    private static final LocalDateTime CIERRE = APERTURA.plusHours(4);
+
+    private final LocalId localId = LocalId.generate();
+    private final Producto pizza = new Producto(ProductoId.generate(), localId, "Pizza",
+            new BigDecimal("8000"), true, "#FF0000");
+
+    private final Mozo ana = new Mozo(MozoId.generate(), localId, "Ana", true, RolPersonal.MOZO, null);
+    private final Mozo beto = new Mozo(MozoId.generate(), localId, "Beto", true, RolPersonal.MOZO, null);
+    private final Mozo caro = new Mozo(MozoId.generate(), localId, "Caro", true, RolPersonal.ENCARGADO, null);
+    private final Mozo dani = new Mozo(MozoId.generate(), localId, "Dani", true, RolPersonal.DUENO, null);
+    private final List<Mozo> personal = List.of(ana, beto, caro, dani);
+
+    private TurnoCaja turno;
+    private List<Pedido> pedidos;
+    private List<Fichaje> fichajes;
+
+    @BeforeEach
+    void setUp() {
+        turno = new TurnoCaja(TurnoCajaId.generate(), localId, JornadaCajaId.generate(),
+                TipoTurno.NOCHE, new BigDecimal("5000"), APERTURA);
+        pedidos = List.of(conPropina(1, "600"), conPropina(2, "400"));
+        turno.cerrar(CIERRE, new BigDecimal("16000"), new BigDecimal("16000"),
+                BigDecimal.ZERO, BigDecimal.ZERO, 2, new BigDecimal("21000"));
+
+        // Ana 4h dentro del turno, Beto 3h sin fichar la salida, Caro 2h, Dani no participa
+        fichajes = List.of(
+            fichaje(ana, APERTURA.minusHours(1), CIERRE.plusMinutes(30)),
+            fichaje(beto, APERTURA.plusHours(1), null),
+            fichaje(caro, APERTURA, APERTURA.plusHours(2)),
+            fichaje(dani, APERTURA, CIERRE)
+        );
+    }
+
+    private Pedido conPropina(int numero, String propina) {
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), numero,
+                EstadoPedido.ABIERTO, APERTURA.plusMinutes(numero));
+        pedido.agregarProducto(pizza, 1, null);
+        pedido.cerrar(List.of(new Pago(MedioPago.EFECTIVO, pedido.calcularTotal(), APERTURA.plusHours(1))),
+                APERTURA.plusHours(1));
+        pedido.asignarTurno(turno.getId());
+        pedido.registrarPropina(new BigDecimal(propina));
+        return pedido;
+    }
+
+    private Fichaje fichaje(Mozo empleado, LocalDateTime entrada, LocalDateTime salida) {
+        Fichaje fichaje = new Fichaje(FichajeId.generate(), localId, empleado.getId(), MedioFichaje.PIN, entrada);
+        if (salida != null) {
+            fichaje.registrarSalida(salida);
+        }
+        return fichaje;
+    }
+
+    private BigDecimal parteDe(DistribucionPropinas distribucion, Mozo empleado) {
+        return distribucion.getPartes().stream()
+            .filter(p -> p.getMozoId().equals(empleado.getId()))
+            .map(PartePropina::getMonto)
+            .findFirst()
+            .orElse(null);
+    }
+
+    @Test
+    void deberia_repartir_en_partes_iguales_sin_perder_centavos() {
+        // When: regla por defecto (mozos y encargados, partes iguales)
+        DistribucionPropinas distribucion = DistribucionPropinas.repartir(turno, ReglaPropinas.porDefecto(localId),
+                pedidos, fichajes, personal, CIERRE);
+
+        // Then: $1000 entre tres, el centavo que sobra va a una sola parte
+        assertEquals(0, new BigDecimal("1000").compareTo(distribucion.getPozo()));
+        assertEquals(3, distribucion.getPartes().size());
+        assertNull(parteDe(distribucion, dani));
+        assertEquals(0, new BigDecimal("333.34").compareTo(distribucion.getPartes().get(0).getMonto()));
+        assertEquals(0, BigDecimal.ZERO.compareTo(distribucion.getSinRepartir()));
+    }
+
+    @Test
+    void deberia_repartir_por_minutos_fichados_dentro_del_turno() {
+        ReglaPropinas regla = new ReglaPropinas(localId, ModoRepartoPropinas.POR_HORAS,
+                Map.of(RolPersonal.MOZO, 1, RolPersonal.ENCARGADO, 1));
+
+        DistribucionPropinas distribucion = DistribucionPropinas.repartir(turno, regla, pedidos, fichajes,
+                personal, CIERRE);
+
+        // Then: 240, 180 y 120 minutos; el centavo del redondeo va al mayor resto
+        assertEquals(0, new BigDecimal("444.45").compareTo(parteDe(distribucion, ana)));
+        assertEquals(0, new BigDecimal("333.33").compareTo(parteDe(distribucion, beto)));
+        assertEquals(0, new BigDecimal("222.22").compareTo(parteDe(distribucion, caro)));
+        assertEquals(180, distribucion.getPartes().stream()
+            .filter(p -> p.getMozoId().equals(beto.getId())).findFirst().orElseThrow().getMinutos());
+    }
+
+    @Test
+    void deberia_dejar_el_pozo_sin_repartir_si_nadie_ficho_y_rechazar_un_turno_abierto() {
+        DistribucionPropinas sinPersonal = DistribucionPropinas.repartir(turno, ReglaPropinas.porDefecto(localId),
+                pedidos, List.of(), personal, CIERRE);
+        assertTrue(sinPersonal.getPartes().isEmpty());
+        assertEquals(0, new BigDecimal("1000").compareTo(sinPersonal.getSinRepartir()));
+
+        TurnoCaja abierto = new TurnoCaja(TurnoCajaId.generate(), localId, JornadaCajaId.generate(),
+                TipoTurno.MANANA, BigDecimal.ZERO, APERTURA);
+        assertThrows(IllegalStateException.class, () -> DistribucionPropinas.repartir(abierto,
+                ReglaPropinas.porDefecto(localId), pedidos, fichajes, personal, CIERRE));
+        assertThrows(IllegalArgumentException.class, () -> new ReglaPropinas(localId,
+                ModoRepartoPropinas.POR_PUESTO, Map.of(RolPersonal.MOZO, 0)));
+    }
+}
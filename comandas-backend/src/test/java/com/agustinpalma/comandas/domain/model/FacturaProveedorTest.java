@@ -0,0 +1,84 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaGasto;
+import com.agustinpalma.comandas.domain.model.DomainEnums.EstadoFacturaProveedor;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.GastoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProveedorId;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para FacturaProveedor.
+ * Sin Spring, sin base de datos.
+ */
+class FacturaProveedorTest {
+
+    private static final LocalDate EMISION = LocalDate.of(2026, 3, 1);
+    private static final LocalDateTime PAGO = LocalDateTime.of(2026, 3, 10, 12, 0);
+
+    private final LocalId localId = LocalId.generate();
+
+    private FacturaProveedor factura(String monto) {
+        Gasto compra = new Gasto(GastoId.generate(), localId, EMISION, CategoriaGasto.MERCADERIA,
+                "Distribuidora Norte", null, new BigDecimal(monto), MedioPago.CUENTA_CORRIENTE, List.of(),
+                LocalDateTime.of(2026, 3, 1, 9, 0), null, null);
+        return FacturaProveedor.desdeCompra(compra, ProveedorId.generate(), "A-0001-00001234", EMISION.plusDays(30));
+    }
+
+    // ============================================
+    // Tests: HU-131 Cuenta corriente de proveedores
+    // ============================================
+
+    @Test
+    void deberia_descontar_pagos_parciales_hasta_saldarla() {
+        FacturaProveedor factura = factura("100000");
+        assertEquals(EstadoFacturaProveedor.PENDIENTE, factura.getEstado());
+
+        factura.registrarPago(new PagoProveedor(PAGO, new BigDecimal("40000"), MedioPago.TRANSFERENCIA, null));
+        assertEquals(EstadoFacturaProveedor.PAGADA_PARCIAL, factura.getEstado());
+        assertEquals(0, new BigDecimal("60000").compareTo(factura.getSaldo()));
+
+        factura.registrarPago(new PagoProveedor(PAGO, new BigDecimal("60000"), MedioPago.EFECTIVO, null));
+        assertEquals(EstadoFacturaProveedor.PAGADA, factura.getEstado());
+        assertThrows(IllegalStateException.class,
+                () -> factura.registrarPago(new PagoProveedor(PAGO, BigDecimal.ONE, MedioPago.EFECTIVO, null)));
+    }
+
+    @Test
+    void deberia_rechazar_un_pago_mayor_al_saldo() {
+        FacturaProveedor factura = factura("100000");
+
+        assertThrows(IllegalArgumentException.class,
+                () -> factura.registrarPago(new PagoProveedor(PAGO, new BigDecimal("100000.01"), MedioPago.QR, null)));
+        assertTrue(factura.getPagos().isEmpty());
+    }
+
+    @Test
+    void deberia_vencer_solo_mientras_tenga_saldo() {
+        FacturaProveedor factura = factura("50000");
+        LocalDate despuesDelVencimiento = EMISION.plusDays(31);
+
+        assertTrue(factura.estaVencida(despuesDelVencimiento));
+        assertEquals(-1, factura.diasParaVencer(despuesDelVencimiento));
+
+        factura.registrarPago(new PagoProveedor(PAGO, new BigDecimal("50000"), MedioPago.TRANSFERENCIA, null));
+        assertFalse(factura.estaVencida(despuesDelVencimiento));
+    }
+
+    @Test
+    void deberia_salir_de_la_caja_solo_un_pago_en_efectivo() {
+        assertThrows(IllegalArgumentException.class,
+                () -> new PagoProveedor(PAGO, BigDecimal.TEN, MedioPago.TRANSFERENCIA, MovimientoCajaId.generate()));
+        assertThrows(IllegalArgumentException.class,
+                () -> new PagoProveedor(PAGO, BigDecimal.TEN, MedioPago.CUENTA_CORRIENTE, null));
+    }
+}
@@ -0,0 +1,103 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.*;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para Factura y Cuit (HU-115).
+ * Sin Spring, sin base de datos.
+ *
+ * Validan la letra según condición frente al IVA, el dígito verificador
+ * del CUIT y la discriminación del IVA incluido en el total del pedido.
+ */
+class FacturaTest {
+
+    private final LocalId localId = LocalId.generate();
+    private final Producto milanesa = new Producto(ProductoId.generate(), localId, "Milanesa",
+            new BigDecimal("12100"), true, "#AA5500");
+    private final LocalDateTime ahora = LocalDateTime.of(2026, 3, 10, 22, 0);
+
+    private Pedido pedidoCerrado() {
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 7,
+                EstadoPedido.ABIERTO, ahora.minusHours(1));
+        pedido.agregarProducto(milanesa, 1, null);
+        pedido.finalizar(MedioPago.TARJETA, ahora);
+        return pedido;
+    }
+
+    @Test
+    void deberia_determinar_la_letra_segun_emisor_y_receptor() {
+        assertEquals(TipoFactura.A, Factura.determinarTipo(CondicionIva.RESPONSABLE_INSCRIPTO, CondicionIva.RESPONSABLE_INSCRIPTO));
+        assertEquals(TipoFactura.A, Factura.determinarTipo(CondicionIva.RESPONSABLE_INSCRIPTO, CondicionIva.MONOTRIBUTO));
+        assertEquals(TipoFactura.B, Factura.determinarTipo(CondicionIva.RESPONSABLE_INSCRIPTO, CondicionIva.CONSUMIDOR_FINAL));
+        assertEquals(TipoFactura.B, Factura.determinarTipo(CondicionIva.RESPONSABLE_INSCRIPTO, CondicionIva.EXENTO));
+        assertEquals(TipoFactura.C, Factura.determinarTipo(CondicionIva.MONOTRIBUTO, CondicionIva.RESPONSABLE_INSCRIPTO));
+    }
+
+    @Test
+    void deberia_validar_el_digito_verificador_del_cuit() {
+        Cuit cuit = Cuit.of("20 12345678 6");
+
+        assertEquals("20123456786", cuit.getNumero());
+        assertEquals("20-12345678-6", cuit.formateado());
+        assertThrows(IllegalArgumentException.class, () -> Cuit.of("20-12345678-5"));
+        assertThrows(IllegalArgumentException.class, () -> Cuit.of("12-12345678-6"));
+        assertThrows(IllegalArgumentException.class, () -> Cuit.of("2012345678"));
+    }
+
+    @Test
+    void deberia_discriminar_el_iva_incluido_en_la_factura_a() {
+        // When
+        Factura factura = Factura.emitir(FacturaId.generate(), pedidoCerrado(), CondicionIva.RESPONSABLE_INSCRIPTO,
+                3, 15, Cuit.of("30-71234567-1"), "Distribuidora Sur SA", CondicionIva.RESPONSABLE_INSCRIPTO,
+                new BigDecimal("21"), ahora);
+
+        // Then: 12100 = 10000 neto + 2100 IVA
+        assertEquals(TipoFactura.A, factura.getTipo());
+        assertEquals(0, new BigDecimal("10000").compareTo(factura.getImporteNeto()));
+        assertEquals(0, new BigDecimal("2100").compareTo(factura.getImporteIva()));
+        assertEquals("00003-00000015", factura.numeroFormateado());
+        assertEquals(EstadoFactura.PENDIENTE_AUTORIZACION, factura.getEstado());
+    }
+
+    @Test
+    void deberia_exigir_cuit_salvo_consumidor_final_y_pedido_cerrado() {
+        Pedido cerrado = pedidoCerrado();
+        assertThrows(IllegalArgumentException.class, () -> Factura.emitir(FacturaId.generate(), cerrado,
+                CondicionIva.RESPONSABLE_INSCRIPTO, 1, 1, null, "Sin CUIT", CondicionIva.MONOTRIBUTO,
+                new BigDecimal("21"), ahora));
+
+        Factura consumidorFinal = Factura.emitir(FacturaId.generate(), cerrado, CondicionIva.RESPONSABLE_INSCRIPTO,
+                1, 1, null, null, CondicionIva.CONSUMIDOR_FINAL, new BigDecimal("21"), ahora);
+        assertEquals(TipoFactura.B, consumidorFinal.getTipo());
+
+        Pedido abierto = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 8,
+                EstadoPedido.ABIERTO, ahora);
+        assertThrows(IllegalStateException.class, () -> Factura.emitir(FacturaId.generate(), abierto,
+                CondicionIva.RESPONSABLE_INSCRIPTO, 1, 1, null, null, CondicionIva.CONSUMIDOR_FINAL,
+                new BigDecimal("21"), ahora));
+    }
+
+    @Test
+    void deberia_autorizar_una_sola_vez() {
+        // Given
+        Factura factura = Factura.emitir(FacturaId.generate(), pedidoCerrado(), CondicionIva.MONOTRIBUTO,
+                1, 1, null, null, CondicionIva.CONSUMIDOR_FINAL, new BigDecimal("21"), ahora);
+        assertEquals(0, BigDecimal.ZERO.compareTo(factura.getImporteIva()));
+
+        // When
+        factura.autorizar("76123456789012", LocalDate.of(2026, 3, 20));
+
+        // Then
+        assertTrue(factura.estaAutorizada());
+        assertThrows(IllegalStateException.class,
+                () -> factura.autorizar("76123456789013", LocalDate.of(2026, 3, 20)));
+    }
+}
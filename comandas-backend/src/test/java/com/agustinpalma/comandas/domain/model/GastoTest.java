@@ -0,0 +1,84 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.CategoriaGasto;
+import com.agustinpalma.comandas.domain.model.DomainEnums.MedioPago;
+import com.agustinpalma.comandas.domain.model.DomainIds.GastoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MovimientoCajaId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para Gasto.
+ * Sin Spring, sin base de datos.
+ */
+class GastoTest {
+
+    private final LocalId localId = LocalId.generate();
+
+    private Gasto gasto(CategoriaGasto categoria, String proveedor, String descripcion,
+                        MedioPago medio, List<ItemCompra> items) {
+        return new Gasto(GastoId.generate(), localId, LocalDate.of(2026, 3, 1), categoria, proveedor,
+                descripcion, new BigDecimal("350000"), medio, items, LocalDateTime.of(2026, 3, 2, 10, 0), null, null);
+    }
+
+    // ============================================
+    // Tests: HU-117 Compras a proveedores y gastos
+    // ============================================
+
+    @Test
+    void deberia_exigir_proveedor_o_descripcion() {
+        assertThrows(IllegalArgumentException.class,
+                () -> gasto(CategoriaGasto.ALQUILER, "  ", null, MedioPago.TRANSFERENCIA, List.of()));
+
+        Gasto alquiler = gasto(CategoriaGasto.ALQUILER, null, "Alquiler marzo", MedioPago.TRANSFERENCIA, List.of());
+        assertEquals("Alquiler - Alquiler marzo", alquiler.resumen());
+    }
+
+    @Test
+    void deberia_admitir_items_de_stock_solo_en_compras_de_mercaderia() {
+        List<ItemCompra> items = List.of(new ItemCompra(ProductoId.generate(), "Harina", 10));
+
+        assertThrows(IllegalArgumentException.class,
+                () -> gasto(CategoriaGasto.MANTENIMIENTO, "Ferretería", null, MedioPago.EFECTIVO, items));
+        assertEquals(1, gasto(CategoriaGasto.MERCADERIA, "Molino", null, MedioPago.EFECTIVO, items).getItems().size());
+    }
+
+    @Test
+    void deberia_rechazar_medios_de_cobro_a_clientes() {
+        assertThrows(IllegalArgumentException.class,
+                () -> gasto(CategoriaGasto.OTROS, "Varios", null, MedioPago.A_CUENTA, List.of()));
+    }
+
+    @Test
+    void deberia_asociar_egreso_de_caja_solo_a_gastos_en_efectivo() {
+        Gasto conTarjeta = gasto(CategoriaGasto.SERVICIOS, "Edesur", null, MedioPago.TARJETA, List.of());
+        assertThrows(IllegalStateException.class, () -> conTarjeta.registrarPagoConCaja(MovimientoCajaId.generate()));
+
+        Gasto enEfectivo = gasto(CategoriaGasto.SERVICIOS, "Edesur", null, MedioPago.EFECTIVO, List.of());
+        enEfectivo.registrarPagoConCaja(MovimientoCajaId.generate());
+        assertTrue(enEfectivo.fuePagadoConCaja());
+        assertThrows(IllegalStateException.class, () -> enEfectivo.registrarPagoConCaja(MovimientoCajaId.generate()));
+    }
+
+    // ============================================
+    // Tests: HU-131 Cuenta corriente de proveedores
+    // ============================================
+
+    @Test
+    void deberia_exigir_proveedor_en_compras_a_cuenta_corriente() {
+        assertThrows(IllegalArgumentException.class,
+                () -> gasto(CategoriaGasto.MERCADERIA, null, "Bebidas", MedioPago.CUENTA_CORRIENTE, List.of()));
+
+        Gasto compra = gasto(CategoriaGasto.MERCADERIA, "Distribuidora Norte", null, MedioPago.CUENTA_CORRIENTE, List.of());
+        assertTrue(compra.esACuentaCorriente());
+        assertThrows(IllegalStateException.class, () -> compra.registrarPagoConCaja(MovimientoCajaId.generate()));
+    }
+}
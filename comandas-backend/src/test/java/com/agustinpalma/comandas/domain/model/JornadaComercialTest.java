@@ -0,0 +1,63 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.Test;
+
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.LocalTime;
+import java.time.YearMonth;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para JornadaComercial.
+ * Sin Spring, sin base de datos.
+ *
+ * HU-153: el día comercial termina en la hora de corte del local, no a medianoche.
+ */
+class JornadaComercialTest {
+
+    private static final LocalDate DIA = LocalDate.of(2026, 10, 10);
+
+    private final LocalId localId = LocalId.generate();
+
+    @Test
+    void deberia_asignar_lo_anterior_al_corte_a_la_jornada_del_dia_anterior() {
+        JornadaComercial jornada = new JornadaComercial(localId, LocalTime.of(4, 0));
+
+        assertEquals(DIA, jornada.fechaOperativa(DIA.plusDays(1).atTime(3, 30)));
+        assertEquals(DIA.plusDays(1), jornada.fechaOperativa(DIA.plusDays(1).atTime(4, 0)));
+        assertEquals(DIA.atTime(4, 0), jornada.inicio(DIA));
+        assertEquals(DIA.plusDays(1).atTime(4, 0).minusNanos(1), jornada.fin(DIA));
+    }
+
+    @Test
+    void deberia_cortar_a_las_seis_sin_configurar_y_a_medianoche_en_dia_calendario() {
+        assertEquals(LocalTime.of(6, 0), JornadaComercial.porDefecto(localId).getHoraCorte());
+
+        JornadaComercial calendario = new JornadaComercial(localId, LocalTime.MIDNIGHT);
+        LocalDateTime pasadaMedianoche = DIA.plusDays(1).atTime(0, 30);
+        assertEquals(DIA.plusDays(1), calendario.fechaOperativa(pasadaMedianoche));
+        assertEquals(DIA.atStartOfDay(), calendario.inicio(DIA));
+    }
+
+    @Test
+    void deberia_asignar_la_madrugada_del_primero_al_mes_que_termina() {
+        JornadaComercial jornada = JornadaComercial.porDefecto(localId);
+
+        assertEquals(YearMonth.of(2026, 3), jornada.mesOperativo(LocalDateTime.of(2026, 4, 1, 0, 30)));
+        assertEquals(YearMonth.of(2026, 4), jornada.mesOperativo(LocalDateTime.of(2026, 4, 1, 6, 0)));
+        assertEquals(LocalDateTime.of(2026, 4, 1, 6, 0), jornada.inicio(YearMonth.of(2026, 4)));
+    }
+
+    @Test
+    void deberia_rechazar_un_corte_desde_el_mediodia() {
+        JornadaComercial jornada = JornadaComercial.porDefecto(localId);
+
+        assertThrows(IllegalArgumentException.class, () -> jornada.actualizar(LocalTime.NOON));
+        assertThrows(NullPointerException.class, () -> jornada.actualizar(null));
+        jornada.actualizar(LocalTime.of(4, 30, 15));
+        assertEquals(LocalTime.of(4, 30), jornada.getHoraCorte());
+    }
+}
@@ -0,0 +1,78 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.*;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.Map;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para ListaPrecios (HU-108).
+ * Sin Spring, sin base de datos.
+ *
+ * Validan la cotización (precio especial o base + recargo) y que el pedido
+ * congele la lista de su canal solo antes de cargar ítems.
+ */
+class ListaPreciosTest {
+
+    private final LocalId localId = LocalId.generate();
+    private final Producto pizza = new Producto(ProductoId.generate(), localId, "Pizza",
+            new BigDecimal("8999"), true, "#FF0000");
+    private final Producto flan = new Producto(ProductoId.generate(), localId, "Flan",
+            new BigDecimal("3000"), true, "#FFFF00");
+
+    private ListaPrecios delivery(String recargo, Map<ProductoId, BigDecimal> especiales) {
+        return new ListaPrecios(ListaPreciosId.generate(), localId, CanalVenta.DELIVERY,
+                "Delivery", new BigDecimal(recargo), especiales);
+    }
+
+    @Test
+    void deberia_aplicar_recargo_redondeado_a_dos_decimales() {
+        ListaPrecios lista = delivery("12.5", Map.of());
+
+        // 8999 * 1.125 = 10123.875 → 10123.88
+        assertEquals(new BigDecimal("10123.88"), lista.precioPara(pizza));
+    }
+
+    @Test
+    void deberia_priorizar_precio_especial_sobre_recargo() {
+        ListaPrecios lista = delivery("15", Map.of(flan.getId(), new BigDecimal("3200")));
+
+        assertEquals(0, new BigDecimal("3200").compareTo(lista.precioPara(flan)));
+        assertEquals(0, new BigDecimal("10348.85").compareTo(lista.precioPara(pizza)));
+    }
+
+    @Test
+    void deberia_rechazar_recargo_fuera_de_rango_y_precio_especial_no_positivo() {
+        assertThrows(IllegalArgumentException.class, () -> delivery("-5", Map.of()));
+        assertThrows(IllegalArgumentException.class, () -> delivery("150", Map.of()));
+        assertThrows(IllegalArgumentException.class,
+                () -> delivery("0", Map.of(flan.getId(), BigDecimal.ZERO)));
+    }
+
+    @Test
+    void deberia_rechazar_cambio_de_canal_con_items_cargados() {
+        // Given
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 1,
+                EstadoPedido.ABIERTO, LocalDateTime.now());
+        pedido.agregarProducto(pizza, 1, null);
+
+        // When / Then
+        assertThrows(IllegalStateException.class,
+                () -> pedido.asignarCanal(CanalVenta.DELIVERY, delivery("10", Map.of())));
+        assertEquals(CanalVenta.SALON, pedido.getCanal());
+    }
+
+    @Test
+    void deberia_rechazar_lista_de_otro_canal() {
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 1,
+                EstadoPedido.ABIERTO, LocalDateTime.now());
+
+        assertThrows(IllegalArgumentException.class,
+                () -> pedido.asignarCanal(CanalVenta.TAKE_AWAY, delivery("10", Map.of())));
+    }
+}
@@ -0,0 +1,79 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.Permiso;
+import com.agustinpalma.comandas.domain.model.DomainEnums.RolPersonal;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.Test;
+
+import java.util.EnumSet;
+import java.util.Map;
+import java.util.Set;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para MatrizPermisos.
+ * Sin Spring, sin base de datos.
+ *
+ * HU-169: permisos por rol con excepciones por empleado; el dueño siempre puede todo.
+ */
+class MatrizPermisosTest {
+
+    private final LocalId localId = LocalId.generate();
+
+    private Mozo empleado(RolPersonal rol, boolean activo) {
+        return new Mozo(MozoId.generate(), localId, "Juan", activo, rol, null);
+    }
+
+    private MatrizPermisos activaCon(Map<RolPersonal, Set<Permiso>> porRol, Map<MozoId, Map<Permiso, Boolean>> excepciones) {
+        return new MatrizPermisos(localId, true, porRol, excepciones);
+    }
+
+    @Test
+    void deberia_permitir_todo_mientras_esta_inactiva() {
+        MatrizPermisos matriz = MatrizPermisos.porDefecto(localId);
+
+        assertFalse(matriz.isActiva());
+        assertEquals(EnumSet.allOf(Permiso.class), matriz.permisosDe(empleado(RolPersonal.MOZO, true)));
+    }
+
+    @Test
+    void deberia_aplicar_los_permisos_del_rol() {
+        MatrizPermisos matriz = activaCon(Map.of(RolPersonal.MOZO, Set.of(Permiso.APLICAR_DESCUENTOS)), Map.of());
+        Mozo mozo = empleado(RolPersonal.MOZO, true);
+
+        assertTrue(matriz.permite(mozo, Permiso.APLICAR_DESCUENTOS));
+        assertFalse(matriz.permite(mozo, Permiso.ANULAR_ITEMS));
+        assertTrue(matriz.permisosDe(empleado(RolPersonal.ENCARGADO, true)).isEmpty());
+    }
+
+    @Test
+    void deberia_priorizar_la_excepcion_del_empleado_sobre_su_rol() {
+        Mozo juan = empleado(RolPersonal.MOZO, true);
+        Mozo pedro = empleado(RolPersonal.MOZO, true);
+        MatrizPermisos matriz = activaCon(
+            Map.of(RolPersonal.MOZO, Set.of(Permiso.APLICAR_DESCUENTOS)),
+            Map.of(juan.getId(), Map.of(Permiso.APLICAR_DESCUENTOS, false, Permiso.ABRIR_CAJON, true)));
+
+        assertEquals(EnumSet.of(Permiso.ABRIR_CAJON), matriz.permisosDe(juan));
+        assertEquals(EnumSet.of(Permiso.APLICAR_DESCUENTOS), matriz.permisosDe(pedro));
+    }
+
+    @Test
+    void deberia_dar_siempre_todos_los_permisos_al_dueno() {
+        Mozo dueno = empleado(RolPersonal.DUENO, true);
+        MatrizPermisos matriz = activaCon(
+            Map.of(RolPersonal.DUENO, Set.of()),
+            Map.of(dueno.getId(), Map.of(Permiso.MODIFICAR_PRECIOS, false)));
+
+        assertEquals(EnumSet.allOf(Permiso.class), matriz.permisosDe(dueno));
+        assertEquals(EnumSet.allOf(Permiso.class), matriz.getPermisosPorRol().get(RolPersonal.DUENO));
+    }
+
+    @Test
+    void deberia_negar_todo_al_empleado_inactivo() {
+        MatrizPermisos matriz = activaCon(Map.of(RolPersonal.ENCARGADO, EnumSet.allOf(Permiso.class)), Map.of());
+
+        assertTrue(matriz.permisosDe(empleado(RolPersonal.ENCARGADO, false)).isEmpty());
+    }
+}
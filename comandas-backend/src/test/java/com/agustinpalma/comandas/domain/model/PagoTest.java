@@ -0,0 +1,69 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.*;
+import com.agustinpalma.comandas.domain.model.DomainIds.CajaId;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para Pago.
+ * Sin Spring, sin base de datos.
+ *
+ * HU-156: referencia del comprobante en los pagos que no son en efectivo.
+ * HU-159: caja que cobró el pago.
+ * HU-160: id del pago, estable entre conversiones.
+ */
+class PagoTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 10, 14, 21, 0);
+
+    @Test
+    void deberia_guardar_la_referencia_pegada_sin_espacios_y_conservarla_al_convertir() {
+        Pago transferencia = new Pago(MedioPago.TRANSFERENCIA, new BigDecimal("12000"), AHORA)
+            .conReferencia("  000123456789\n");
+
+        assertEquals("000123456789", transferencia.getReferencia());
+        assertEquals("000123456789", transferencia.enMoneda(Moneda.USD, new BigDecimal("1200")).getReferencia());
+    }
+
+    @Test
+    void deberia_tomar_una_referencia_en_blanco_como_sin_comprobante() {
+        Pago tarjeta = new Pago(MedioPago.TARJETA, new BigDecimal("5000"), AHORA).conReferencia("   ");
+
+        assertNull(tarjeta.getReferencia());
+        assertNull(new Pago(MedioPago.EFECTIVO, new BigDecimal("5000"), AHORA).conReferencia(null).getReferencia());
+    }
+
+    @Test
+    void deberia_rechazar_la_referencia_en_efectivo_y_las_demasiado_largas() {
+        Pago efectivo = new Pago(MedioPago.EFECTIVO, new BigDecimal("5000"), AHORA);
+        Pago qr = new Pago(MedioPago.QR, new BigDecimal("5000"), AHORA);
+
+        assertThrows(IllegalArgumentException.class, () -> efectivo.conReferencia("123"));
+        assertThrows(IllegalArgumentException.class, () -> qr.conReferencia("9".repeat(65)));
+    }
+
+    @Test
+    void deberia_conservar_la_caja_que_cobro_al_convertir_y_redondear() {
+        CajaId barra = CajaId.generate();
+        Pago efectivo = new Pago(MedioPago.EFECTIVO, new BigDecimal("4990"), AHORA).conCaja(barra);
+
+        assertEquals(barra, efectivo.conAjusteRedondeo(new BigDecimal("10")).getCajaId());
+        assertEquals(barra, efectivo.enMoneda(Moneda.USD, new BigDecimal("1000")).getCajaId());
+        assertNull(new Pago(MedioPago.EFECTIVO, new BigDecimal("4990"), AHORA).getCajaId());
+    }
+
+    @Test
+    void deberia_conservar_el_id_al_convertir_y_generar_uno_nuevo_por_pago() {
+        Pago tarjeta = new Pago(MedioPago.TARJETA, new BigDecimal("5000"), AHORA);
+
+        assertEquals(tarjeta.getId(), tarjeta.conReferencia("123").getId());
+        assertEquals(tarjeta.getId(), tarjeta.enMoneda(Moneda.USD, new BigDecimal("1000")).getId());
+        assertEquals(tarjeta.getId(), tarjeta.conCaja(CajaId.generate()).getId());
+        assertNotEquals(tarjeta.getId(), new Pago(MedioPago.TARJETA, new BigDecimal("5000"), AHORA).getId());
+    }
+}
@@ -0,0 +1,463 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.exception.PedidoModificadoException;
+import com.agustinpalma.comandas.domain.model.DomainEnums.*;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Optional;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para Pedido.
+ * Sin Spring, sin base de datos.
+ */
+class PedidoTest {
+
+    private final LocalId localId = LocalId.generate();
+    private final Producto empanada = new Producto(ProductoId.generate(), localId, "Empanada",
+            new BigDecimal("1200"), true, "#FF9900");
+    private final Producto milanesa = new Producto(ProductoId.generate(), localId, "Milanesa",
+            new BigDecimal("9500"), true, "#AA5500");
+    private final Producto flan = new Producto(ProductoId.generate(), localId, "Flan",
+            new BigDecimal("3000"), true, "#FFFF00");
+
+    private Pedido pedido;
+
+    @BeforeEach
+    void setUp() {
+        pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 1,
+                EstadoPedido.ABIERTO, LocalDateTime.now());
+    }
+
+    private ItemPedido agregar(Producto producto, TiempoServicio tiempo) {
+        pedido.agregarProducto(producto, 1, null);
+        ItemPedido item = pedido.getItems().get(pedido.getItems().size() - 1);
+        pedido.cambiarTiempoServicioItem(item.getId(), tiempo);
+        return item;
+    }
+
+    // ============================================
+    // Tests: HU-110 Secuencia de pasos a cocina
+    // ============================================
+
+    @Test
+    void deberia_retener_pasos_posteriores_al_primero_cargado() {
+        // Given
+        ItemPedido entrada = agregar(empanada, TiempoServicio.ENTRADA);
+        ItemPedido principal = agregar(milanesa, TiempoServicio.PRINCIPAL);
+        ItemPedido postre = agregar(flan, TiempoServicio.POSTRE);
+
+        // When
+        pedido.marcarComoEnviadoACocina(LocalDateTime.now());
+
+        // Then
+        assertEquals(TiempoServicio.ENTRADA, pedido.getTiempoEnMarcha());
+        assertFalse(entrada.tieneCantidadNueva());
+        assertTrue(principal.tieneCantidadNueva());
+        assertTrue(postre.tieneCantidadNueva());
+        assertTrue(pedido.obtenerItemsNuevos().isEmpty());
+        assertEquals(Optional.of(TiempoServicio.PRINCIPAL), pedido.siguienteTiempoPendiente());
+    }
+
+    @Test
+    void deberia_liberar_el_paso_marchado_y_dejar_retenido_el_siguiente() {
+        // Given
+        agregar(empanada, TiempoServicio.ENTRADA);
+        ItemPedido principal = agregar(milanesa, TiempoServicio.PRINCIPAL);
+        ItemPedido postre = agregar(flan, TiempoServicio.POSTRE);
+        pedido.marcarComoEnviadoACocina(LocalDateTime.now());
+
+        // When
+        pedido.marcharTiempo(TiempoServicio.PRINCIPAL);
+
+        // Then
+        assertEquals(1, pedido.obtenerItemsNuevos().size());
+        assertTrue(pedido.esItemNuevo(principal));
+        assertTrue(pedido.estaRetenido(postre));
+
+        pedido.marcarComoEnviadoACocina(LocalDateTime.now());
+        assertFalse(principal.tieneCantidadNueva());
+        assertEquals(Optional.of(TiempoServicio.POSTRE), pedido.siguienteTiempoPendiente());
+    }
+
+    @Test
+    void deberia_mandar_directo_los_items_sin_paso() {
+        // Given
+        ItemPedido sinPaso = agregar(empanada, null);
+        agregar(flan, TiempoServicio.POSTRE);
+
+        // When
+        pedido.marcarComoEnviadoACocina(LocalDateTime.now());
+
+        // Then: el primer paso cargado (postre) sale con el envío
+        assertFalse(sinPaso.tieneCantidadNueva());
+        assertTrue(pedido.obtenerItemsNuevos().isEmpty());
+        assertTrue(pedido.siguienteTiempoPendiente().isEmpty());
+    }
+
+    @Test
+    void deberia_rechazar_marchar_un_paso_ya_en_marcha_o_sin_items() {
+        // Given
+        agregar(empanada, TiempoServicio.ENTRADA);
+        agregar(milanesa, TiempoServicio.PRINCIPAL);
+        pedido.marcarComoEnviadoACocina(LocalDateTime.now());
+
+        // When / Then
+        assertThrows(IllegalStateException.class, () -> pedido.marcharTiempo(TiempoServicio.ENTRADA));
+        pedido.marcharTiempo(TiempoServicio.PRINCIPAL);
+        assertThrows(IllegalStateException.class, () -> pedido.marcharTiempo(TiempoServicio.POSTRE));
+    }
+
+    @Test
+    void deberia_rechazar_cambiar_el_paso_de_un_item_ya_enviado() {
+        // Given
+        ItemPedido entrada = agregar(empanada, TiempoServicio.ENTRADA);
+        pedido.marcarComoEnviadoACocina(LocalDateTime.now());
+
+        // When / Then
+        assertThrows(IllegalStateException.class,
+                () -> pedido.cambiarTiempoServicioItem(entrada.getId(), TiempoServicio.POSTRE));
+        assertEquals(TiempoServicio.ENTRADA, entrada.getTiempoServicio());
+    }
+
+    // ============================================
+    // Tests: HU-111 Anulaciones y propina
+    // ============================================
+
+    @Test
+    void deberia_registrar_como_anulacion_solo_las_unidades_ya_enviadas() {
+        // Given: dos empanadas en cocina y una tercera sin enviar
+        pedido.agregarProducto(empanada, 2, null);
+        pedido.marcarComoEnviadoACocina(LocalDateTime.now());
+        ItemPedido item = pedido.getItems().get(0);
+        pedido.actualizarCantidadItem(item.getId(), 3);
+
+        // When: se vuelve a una (la nueva no cuenta, una de las enviadas sí)
+        pedido.actualizarCantidadItem(item.getId(), 1);
+
+        // Then
+        assertEquals(1, pedido.getUnidadesAnuladas());
+        assertEquals(0, new BigDecimal("1200").compareTo(pedido.getMontoAnulado()));
+
+        pedido.quitarItem(item.getId());
+        assertEquals(2, pedido.getUnidadesAnuladas());
+        assertEquals(0, new BigDecimal("2400").compareTo(pedido.getMontoAnulado()));
+    }
+
+    @Test
+    void deberia_quitar_sin_anulacion_un_item_que_no_salio_a_cocina() {
+        // Given
+        pedido.agregarProducto(milanesa, 2, null);
+
+        // When
+        pedido.quitarItem(pedido.getItems().get(0).getId());
+
+        // Then
+        assertTrue(pedido.getItems().isEmpty());
+        assertEquals(0, pedido.getUnidadesAnuladas());
+    }
+
+    @Test
+    void deberia_registrar_propina_solo_en_pedido_cerrado() {
+        // Given
+        pedido.agregarProducto(flan, 1, null);
+        assertThrows(IllegalStateException.class, () -> pedido.registrarPropina(new BigDecimal("300")));
+
+        // When
+        pedido.finalizar(MedioPago.EFECTIVO, LocalDateTime.now());
+        pedido.registrarPropina(new BigDecimal("300"));
+
+        // Then
+        assertEquals(0, new BigDecimal("300").compareTo(pedido.getPropina()));
+        assertThrows(IllegalArgumentException.class, () -> pedido.registrarPropina(new BigDecimal("-1")));
+
+        pedido.reabrir(LocalDateTime.now());
+        assertEquals(0, BigDecimal.ZERO.compareTo(pedido.getPropina()));
+    }
+
+    // ============================================
+    // Tests: HU-113 Alerta de mesas olvidadas
+    // ============================================
+
+    @Test
+    void deberia_medir_inactividad_desde_la_ultima_actividad_sin_retroceder() {
+        // Given
+        LocalDateTime apertura = LocalDateTime.of(2026, 3, 10, 21, 0);
+        Pedido abierto = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 2,
+                EstadoPedido.ABIERTO, apertura);
+        AlertaMesasOlvidadas alerta = new AlertaMesasOlvidadas(localId, true, 20);
+
+        // When
+        abierto.registrarActividad(apertura.plusMinutes(15));
+        abierto.registrarActividad(apertura.plusMinutes(5));
+
+        // Then
+        assertEquals(apertura.plusMinutes(15), abierto.getUltimaActividad());
+        assertEquals(20, abierto.minutosSinActividad(apertura.plusMinutes(35)));
+        assertFalse(alerta.estaOlvidada(abierto, apertura.plusMinutes(34)));
+        assertTrue(alerta.estaOlvidada(abierto, apertura.plusMinutes(35)));
+    }
+
+    @Test
+    void deberia_reiniciar_la_actividad_al_reabrir() {
+        // Given
+        pedido.agregarProducto(flan, 1, null);
+        pedido.finalizar(MedioPago.EFECTIVO, LocalDateTime.now());
+        assertThrows(IllegalStateException.class, () -> pedido.registrarActividad(LocalDateTime.now()));
+
+        // When
+        LocalDateTime reapertura = LocalDateTime.now().plusHours(1);
+        pedido.reabrir(reapertura);
+
+        // Then
+        assertEquals(reapertura, pedido.getUltimaActividad());
+    }
+
+    // ============================================
+    // Tests: HU-120 Observaciones libres por ítem
+    // ============================================
+
+    @Test
+    void deberia_normalizar_la_observacion_y_fusionar_notas_iguales() {
+        // Given
+        pedido.agregarProducto(milanesa, 1, "  bien cocida ");
+        pedido.agregarProducto(empanada, 1, "   ");
+
+        // When / Then
+        assertEquals("bien cocida", pedido.getItems().get(0).getObservacion());
+        assertNull(pedido.getItems().get(1).getObservacion());
+        assertTrue(pedido.buscarItemConMismaConfiguracion(milanesa.getId(), "bien cocida ", List.of()).isPresent());
+        assertTrue(pedido.buscarItemConMismaConfiguracion(empanada.getId(), null, List.of()).isPresent());
+        assertTrue(pedido.buscarItemConMismaConfiguracion(milanesa.getId(), "jugosa", List.of()).isEmpty());
+    }
+
+    @Test
+    void deberia_cambiar_la_observacion_solo_antes_de_enviar_a_cocina() {
+        // Given
+        pedido.agregarProducto(milanesa, 1, null);
+        ItemPedido item = pedido.getItems().get(0);
+
+        // When
+        pedido.cambiarObservacionItem(item.getId(), "sin sal");
+        pedido.marcarComoEnviadoACocina(LocalDateTime.now());
+
+        // Then
+        assertEquals("sin sal", item.getObservacion());
+        assertThrows(IllegalStateException.class, () -> pedido.cambiarObservacionItem(item.getId(), null));
+        assertThrows(IllegalArgumentException.class,
+                () -> ItemPedido.normalizarObservacion("x".repeat(ItemPedido.OBSERVACION_LONGITUD_MAXIMA + 1)));
+    }
+
+    // ============================================
+    // Tests: HU-137 Marca de urgencia
+    // ============================================
+
+    @Test
+    void deberia_marcar_urgente_un_item_solo_mientras_tenga_unidades_sin_enviar() {
+        // Given
+        pedido.agregarProducto(milanesa, 1, null);
+        ItemPedido item = pedido.getItems().get(0);
+
+        // When
+        pedido.marcarUrgenteItem(item.getId(), true);
+        pedido.marcarComoEnviadoACocina(LocalDateTime.now());
+
+        // Then
+        assertTrue(item.isUrgente());
+        assertThrows(IllegalStateException.class, () -> pedido.marcarUrgenteItem(item.getId(), true));
+        pedido.marcarUrgenteItem(item.getId(), false);
+        assertFalse(item.isUrgente());
+        // El pedido completo se puede apurar aunque todo esté en cocina
+        pedido.marcarUrgente(true);
+        assertTrue(pedido.isUrgente());
+    }
+
+    // ============================================
+    // Tests: HU-138 Horario prometido de retiro
+    // ============================================
+
+    @Test
+    void deberia_prometer_el_retiro_una_sola_vez_y_solo_en_take_away() {
+        LocalDateTime hora = LocalDateTime.of(2026, 10, 9, 21, 30);
+        assertThrows(IllegalStateException.class, () -> pedido.prometerRetiro(hora));
+
+        pedido.asignarCanal(CanalVenta.TAKE_AWAY, null);
+        pedido.prometerRetiro(hora);
+
+        assertEquals(hora, pedido.getHoraPrometida());
+        assertThrows(IllegalStateException.class, () -> pedido.prometerRetiro(hora.plusMinutes(10)));
+    }
+
+    // ============================================
+    // Tests: HU-139 Zonas de delivery con costo de envío
+    // ============================================
+
+    @Test
+    void deberia_cargar_el_envio_de_la_zona_como_item_y_reemplazarlo_al_cambiar_de_zona() {
+        // Given
+        pedido.asignarCanal(CanalVenta.DELIVERY, null);
+        pedido.agregarProducto(milanesa, 1, null);
+        ZonaDelivery centro = ZonaDelivery.crear(localId, "Centro", new BigDecimal("800"), null, List.of());
+        ZonaDelivery cerca = ZonaDelivery.crear(localId, "Cerca", BigDecimal.ZERO, null, List.of());
+
+        // When
+        pedido.asignarEntrega("San Martín 450", centro);
+
+        // Then: el envío suma al total pero no a la venta de productos ni a cocina
+        ItemPedido envio = pedido.getItems().get(1);
+        assertTrue(envio.isEnvio());
+        assertEquals("Envío Centro", envio.getNombreProducto());
+        assertEquals(0, new BigDecimal("10300").compareTo(pedido.calcularSubtotalItems()));
+        assertEquals(0, new BigDecimal("9500").compareTo(pedido.calcularVentaProductos()));
+        assertFalse(pedido.esItemNuevo(envio));
+        assertThrows(IllegalStateException.class, () -> pedido.quitarItem(envio.getId()));
+
+        // Una zona sin costo quita el envío anterior
+        pedido.asignarEntrega("San Martín 450", cerca);
+        assertEquals(1, pedido.getItems().size());
+        assertEquals(cerca.getId(), pedido.getZonaDeliveryId());
+    }
+
+    @Test
+    void deberia_rechazar_la_entrega_fuera_de_delivery() {
+        ZonaDelivery centro = ZonaDelivery.crear(localId, "Centro", new BigDecimal("800"), null, List.of());
+
+        assertThrows(IllegalStateException.class, () -> pedido.asignarEntrega("San Martín 450", centro));
+    }
+
+    // ============================================
+    // Tests: HU-140 Asignación y seguimiento de repartidores
+    // ============================================
+
+    @Test
+    void deberia_registrar_salida_y_regreso_del_repartidor() {
+        // Given
+        pedido.asignarCanal(CanalVenta.DELIVERY, null);
+        Mozo cadete = new Mozo(MozoId.generate(), localId, "Lucho", true, RolPersonal.REPARTIDOR, null);
+        Mozo otro = new Mozo(MozoId.generate(), localId, "Nico", true, RolPersonal.REPARTIDOR, null);
+        LocalDateTime salida = LocalDateTime.of(2026, 10, 14, 21, 10);
+
+        // When
+        pedido.asignarRepartidor(cadete);
+        pedido.registrarSalida(salida);
+
+        // Then: una vez en la calle no se cambia el repartidor
+        assertTrue(pedido.estaEnReparto());
+        assertThrows(IllegalStateException.class, () -> pedido.asignarRepartidor(otro));
+        assertThrows(IllegalArgumentException.class, () -> pedido.registrarRegreso(salida.minusMinutes(1)));
+
+        pedido.registrarRegreso(salida.plusMinutes(27));
+        assertFalse(pedido.estaEnReparto());
+        assertEquals(27, pedido.minutosReparto());
+        assertEquals(cadete.getId(), pedido.getRepartidorId());
+    }
+
+    @Test
+    void deberia_asignar_solo_repartidores_a_pedidos_de_delivery() {
+        Mozo cadete = new Mozo(MozoId.generate(), localId, "Lucho", true, RolPersonal.REPARTIDOR, null);
+        Mozo mozo = new Mozo(MozoId.generate(), localId, "Ana", true);
+
+        assertThrows(IllegalStateException.class, () -> pedido.asignarRepartidor(cadete));
+
+        pedido.asignarCanal(CanalVenta.DELIVERY, null);
+        assertThrows(IllegalStateException.class, () -> pedido.asignarRepartidor(mozo));
+        assertThrows(IllegalStateException.class, () -> pedido.registrarSalida(LocalDateTime.now()));
+    }
+
+    // ============================================
+    // Tests: HU-160 Pagos a cuenta sobre mesas abiertas
+    // ============================================
+
+    @Test
+    void deberia_cerrar_cobrando_solo_el_saldo_y_conservar_los_pagos_a_cuenta() {
+        // Given: 2 milanesas y un flan (22000); un comensal se va y paga lo suyo
+        pedido.agregarProducto(milanesa, 2, null);
+        pedido.agregarProducto(flan, 1, null);
+        LocalDateTime ahora = LocalDateTime.now();
+        Pago aCuenta = new Pago(MedioPago.EFECTIVO, new BigDecimal("9500"), ahora);
+
+        // When
+        pedido.registrarPagoACuenta(aCuenta);
+
+        // Then: el saldo se recalcula con cada cambio del pedido
+        assertEquals(0, new BigDecimal("12500").compareTo(pedido.calcularSaldoPendiente()));
+        pedido.agregarProducto(empanada, 1, null);
+        assertEquals(0, new BigDecimal("13700").compareTo(pedido.calcularSaldoPendiente()));
+
+        // El cierre no acepta volver a cobrar el total
+        Pago total = new Pago(MedioPago.TARJETA, new BigDecimal("23200"), ahora);
+        assertThrows(IllegalArgumentException.class, () -> pedido.cerrar(List.of(total), ahora));
+
+        Pago saldo = new Pago(MedioPago.TARJETA, new BigDecimal("13700"), ahora);
+        pedido.cerrar(List.of(saldo), ahora);
+        assertEquals(List.of(aCuenta, saldo), pedido.getPagos());
+        assertEquals(0, new BigDecimal("23200").compareTo(pedido.getMontoTotalFinal()));
+        assertEquals(0, BigDecimal.ZERO.compareTo(pedido.calcularSaldoPendiente()));
+    }
+
+    @Test
+    void deberia_rechazar_pagos_a_cuenta_que_superan_el_saldo_o_se_cobran_al_cierre() {
+        pedido.agregarProducto(milanesa, 1, null);
+        LocalDateTime ahora = LocalDateTime.now();
+
+        assertThrows(IllegalArgumentException.class,
+                () -> pedido.registrarPagoACuenta(new Pago(MedioPago.EFECTIVO, new BigDecimal("9600"), ahora)));
+        assertThrows(IllegalArgumentException.class,
+                () -> pedido.registrarPagoACuenta(new Pago(MedioPago.CUENTA_CORRIENTE, new BigDecimal("1000"), ahora)));
+
+        // Pagado entero a cuenta: se cierra sin cobrar nada más
+        pedido.registrarPagoACuenta(new Pago(MedioPago.QR, new BigDecimal("9500"), ahora));
+        pedido.cerrar(List.of(), ahora);
+        assertEquals(EstadoPedido.CERRADO, pedido.getEstado());
+        assertEquals(1, pedido.getPagos().size());
+    }
+
+    @Test
+    void deberia_pedir_anular_el_pago_a_cuenta_si_se_quitaron_items_ya_pagados() {
+        // Given
+        pedido.agregarProducto(milanesa, 1, null);
+        pedido.agregarProducto(flan, 1, null);
+        ItemPedido postre = pedido.getItems().get(1);
+        LocalDateTime ahora = LocalDateTime.now();
+        pedido.registrarPagoACuenta(new Pago(MedioPago.EFECTIVO, new BigDecimal("12500"), ahora));
+
+        // When
+        pedido.eliminarItem(postre.getId());
+
+        // Then
+        assertEquals(0, new BigDecimal("-3000").compareTo(pedido.calcularSaldoPendiente()));
+        assertThrows(IllegalArgumentException.class, () -> pedido.cerrar(List.of(), ahora));
+
+        pedido.anularPagoACuenta(0);
+        assertThrows(IllegalArgumentException.class, () -> pedido.anularPagoACuenta(0));
+        pedido.cerrar(List.of(new Pago(MedioPago.EFECTIVO, new BigDecimal("9500"), ahora)), ahora);
+        assertEquals(EstadoPedido.CERRADO, pedido.getEstado());
+    }
+
+    // ============================================
+    // Tests: HU-165 Ediciones desde dos terminales
+    // ============================================
+
+    @Test
+    void deberia_rechazar_el_cambio_si_el_pedido_cambio_desde_otra_terminal() {
+        // Given: la terminal cargó el pedido en la versión 0 y otra lo guardó después
+        Pedido actual = Pedido.reconstruirDesdePersistencia(
+                pedido.getId(), localId, pedido.getMesaId(), 1, EstadoPedido.ABIERTO,
+                pedido.getFechaApertura(), null, List.of(), List.of(), null, null, null, null, null,
+                0, null, null, null, null, null, null, null, 0, null, 0, null, null, false, null,
+                null, null, null, null, null, null, null, null, 1L, null, null, null);
+
+        // When / Then
+        PedidoModificadoException ex = assertThrows(PedidoModificadoException.class,
+                () -> actual.verificarVersion(0L));
+        assertEquals(1L, ex.getVersionActual());
+        assertDoesNotThrow(() -> actual.verificarVersion(1L));
+        assertThrows(PedidoModificadoException.class, () -> actual.verificarVersion(2L));
+    }
+}
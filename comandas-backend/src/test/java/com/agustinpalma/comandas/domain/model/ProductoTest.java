@@ -1,10 +1,12 @@
 package com.agustinpalma.comandas.domain.model;
 
+import com.agustinpalma.comandas.domain.model.DomainEnums.Alergeno;
 import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
 import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
 import org.junit.jupiter.api.Test;
 
 import java.math.BigDecimal;
+import java.util.List;
 
 import static org.junit.jupiter.api.Assertions.*;
 
@@ -449,4 +451,195 @@ class ProductoTest {
             () -> producto.asignarGrupoVariante(null, 1)
         );
     }
+
+    // ============================================
+    // Tests: HU-101 stock mínimo
+    // ============================================
+
+    @Test
+    void deberia_marcar_bajo_stock_minimo_al_alcanzar_el_umbral() {
+        // Given: producto con control de stock y umbral 5
+        Producto producto = new Producto(
+            ProductoId.generate(), LOCAL_ID_VALIDO, "Cerveza", new BigDecimal("2000"), true, "#FFAA00"
+        );
+        producto.activarControlStock();
+        producto.reponerStock(8);
+        producto.definirStockMinimo(5);
+        assertFalse(producto.estaBajoStockMinimo());
+
+        // When: se venden 3 unidades (queda exactamente en el umbral)
+        producto.descontarStock(3);
+
+        // Then
+        assertTrue(producto.estaBajoStockMinimo());
+        assertEquals(1, producto.calcularCantidadParaReponer());
+    }
+
+    @Test
+    void deberia_ignorar_stock_minimo_si_no_controla_stock() {
+        // Given
+        Producto producto = new Producto(
+            ProductoId.generate(), LOCAL_ID_VALIDO, "Agua", new BigDecimal("1000"), true, "#0000FF"
+        );
+        producto.definirStockMinimo(10);
+
+        // When / Then
+        assertFalse(producto.estaBajoStockMinimo());
+        assertEquals(0, producto.calcularCantidadParaReponer());
+    }
+
+    @Test
+    void deberia_rechazar_stock_minimo_negativo() {
+        // Given
+        Producto producto = new Producto(
+            ProductoId.generate(), LOCAL_ID_VALIDO, "Papas", new BigDecimal("1200"), true, "#FFFF00"
+        );
+
+        // When / Then
+        assertThrows(IllegalArgumentException.class, () -> producto.definirStockMinimo(-1));
+    }
+
+    // ============================================
+    // Tests: HU-103 combos
+    // ============================================
+
+    @Test
+    void deberia_armar_seleccion_de_combo_con_una_opcion_por_componente() {
+        // Given: Combo con bebida a elección
+        Producto hamburguesa = new Producto(ProductoId.generate(), LOCAL_ID_VALIDO, "Hamburguesa", new BigDecimal("9000"), true, "#FF0000");
+        Producto coca = new Producto(ProductoId.generate(), LOCAL_ID_VALIDO, "Coca-Cola", new BigDecimal("2000"), true, "#FF0000");
+        Producto agua = new Producto(ProductoId.generate(), LOCAL_ID_VALIDO, "Agua", new BigDecimal("1500"), true, "#0000FF");
+        Producto combo = new Producto(ProductoId.generate(), LOCAL_ID_VALIDO, "Combo", new BigDecimal("10000"), true, "#FFA500");
+        combo.definirComponentesCombo(List.of(
+            new ComponenteCombo("Principal", List.of(hamburguesa.getId())),
+            new ComponenteCombo("Bebida", List.of(coca.getId(), agua.getId()))
+        ));
+
+        // When
+        var seleccion = combo.armarSeleccionCombo(List.of(hamburguesa, agua));
+
+        // Then
+        assertTrue(combo.esCombo());
+        assertEquals(2, seleccion.size());
+        assertEquals("Bebida", seleccion.get(1).getComponente());
+        assertEquals("Agua", seleccion.get(1).getNombreProducto());
+        assertEquals(0, new BigDecimal("1500").compareTo(seleccion.get(1).getPrecioReferencia()));
+    }
+
+    @Test
+    void deberia_rechazar_seleccion_de_combo_incompleta() {
+        // Given
+        Producto hamburguesa = new Producto(ProductoId.generate(), LOCAL_ID_VALIDO, "Hamburguesa", new BigDecimal("9000"), true, "#FF0000");
+        Producto combo = new Producto(ProductoId.generate(), LOCAL_ID_VALIDO, "Combo", new BigDecimal("10000"), true, "#FFA500");
+        combo.definirComponentesCombo(List.of(
+            new ComponenteCombo("Principal", List.of(hamburguesa.getId())),
+            new ComponenteCombo("Bebida", List.of(ProductoId.generate()))
+        ));
+
+        // When / Then
+        assertThrows(
+            IllegalArgumentException.class,
+            () -> combo.armarSeleccionCombo(List.of(hamburguesa))
+        );
+    }
+
+    @Test
+    void deberia_rechazar_combo_que_se_incluye_a_si_mismo() {
+        // Given
+        Producto combo = new Producto(ProductoId.generate(), LOCAL_ID_VALIDO, "Combo", new BigDecimal("10000"), true, "#FFA500");
+
+        // When / Then
+        assertThrows(
+            IllegalArgumentException.class,
+            () -> combo.definirComponentesCombo(List.of(
+                new ComponenteCombo("Principal", List.of(combo.getId()))
+            ))
+        );
+    }
+
+    // ============================================
+    // Tests: HU-109 variantes por tamaño
+    // ============================================
+
+    @Test
+    void deberia_normalizar_etiqueta_de_variante() {
+        // Given
+        Producto producto = new Producto(
+            ProductoId.generate(), LOCAL_ID_VALIDO, "Coca Cola 1.5 L", new BigDecimal("2500"), true, "#FF0000"
+        );
+
+        // When
+        producto.definirEtiquetaVariante("  1.5 L ");
+
+        // Then
+        assertEquals("1.5 L", producto.getEtiquetaVariante());
+
+        // Vacía = sin etiqueta
+        producto.definirEtiquetaVariante(" ");
+        assertNull(producto.getEtiquetaVariante());
+    }
+
+    @Test
+    void deberia_rechazar_etiqueta_de_variante_demasiado_larga() {
+        // Given
+        Producto producto = new Producto(
+            ProductoId.generate(), LOCAL_ID_VALIDO, "Pizza Grande", new BigDecimal("8000"), true, "#FFAA00"
+        );
+
+        // When / Then
+        assertThrows(
+            IllegalArgumentException.class,
+            () -> producto.definirEtiquetaVariante("Grande familiar para compartir entre ocho")
+        );
+    }
+
+    // ============================================
+    // Tests: HU-125 productos 86
+    // ============================================
+
+    @Test
+    void deberia_rechazar_producto_agotado_sin_tocar_su_stock() {
+        // Given
+        Producto producto = new Producto(
+            ProductoId.generate(), LOCAL_ID_VALIDO, "Milanesa napolitana", new BigDecimal("9500"), true, "#FFAA00"
+        );
+        producto.activarControlStock();
+        producto.reponerStock(4);
+
+        // When
+        producto.marcarAgotado(true);
+
+        // Then
+        IllegalStateException error = assertThrows(IllegalStateException.class, producto::validarDisponible);
+        assertTrue(error.getMessage().contains("Milanesa napolitana"));
+        assertEquals(4, producto.getStockActual());
+        assertTrue(producto.isActivo());
+
+        // Se vuelve a habilitar
+        producto.marcarAgotado(false);
+        producto.validarDisponible();
+    }
+
+    // ============================================
+    // Tests: HU-147 alérgenos
+    // ============================================
+
+    @Test
+    void deberia_conservar_alergenos_en_la_copia_con_precio_de_lista() {
+        // Given
+        Producto producto = new Producto(
+            ProductoId.generate(), LOCAL_ID_VALIDO, "Alfajor de maní", new BigDecimal("1800"), true, "#AA5500"
+        );
+        producto.definirAlergenos(List.of(Alergeno.MANI, Alergeno.TACC, Alergeno.MANI));
+
+        // When
+        Producto copia = producto.conPrecioDeLista(new BigDecimal("2000"));
+
+        // Then: sin repetidos, en el orden del catálogo de alérgenos
+        assertEquals(List.of(Alergeno.TACC, Alergeno.MANI), List.copyOf(copia.getAlergenos()));
+
+        // Lista vacía = sin alérgenos
+        producto.definirAlergenos(List.of());
+        assertTrue(producto.getAlergenos().isEmpty());
+    }
 }
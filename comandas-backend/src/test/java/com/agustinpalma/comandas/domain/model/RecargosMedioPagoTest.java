@@ -0,0 +1,72 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.*;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+import java.util.Map;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para RecargosMedioPago.
+ * Sin Spring, sin base de datos.
+ *
+ * HU-143: el recargo se calcula al centavo y se cobra encima del pago.
+ */
+class RecargosMedioPagoTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 10, 14, 21, 0);
+
+    private final LocalId localId = LocalId.generate();
+
+    @Test
+    void deberia_congelar_el_recargo_en_el_pago_redondeado_al_centavo() {
+        RecargosMedioPago recargos = new RecargosMedioPago(localId, Map.of(
+            MedioPago.TARJETA, new BigDecimal("10"),
+            MedioPago.QR, new BigDecimal("3.5")
+        ));
+
+        Pago tarjeta = recargos.cobrar(MedioPago.TARJETA, new BigDecimal("8000"), AHORA);
+        Pago qr = recargos.cobrar(MedioPago.QR, new BigDecimal("1234.50"), AHORA);
+        Pago efectivo = recargos.cobrar(MedioPago.EFECTIVO, new BigDecimal("8000"), AHORA);
+
+        assertEquals(0, new BigDecimal("800").compareTo(tarjeta.getRecargo()));
+        assertEquals(0, new BigDecimal("8800").compareTo(tarjeta.getMontoCobrado()));
+        assertEquals(0, new BigDecimal("43.21").compareTo(qr.getRecargo()));
+        assertFalse(efectivo.tieneRecargo());
+    }
+
+    @Test
+    void deberia_descartar_los_medios_en_cero_y_rechazar_porcentajes_invalidos() {
+        RecargosMedioPago recargos = new RecargosMedioPago(localId, Map.of(MedioPago.TARJETA, BigDecimal.ZERO));
+        assertTrue(recargos.getPorcentajes().isEmpty());
+
+        assertThrows(IllegalArgumentException.class, () -> recargos.actualizar(
+            Map.of(MedioPago.TARJETA, new BigDecimal("100.5"))));
+        assertThrows(IllegalArgumentException.class, () -> recargos.actualizar(
+            Map.of(MedioPago.TARJETA, new BigDecimal("-1"))));
+        assertThrows(IllegalArgumentException.class, () -> recargos.actualizar(
+            Map.of(MedioPago.TARJETA, new BigDecimal("2.125"))));
+        assertThrows(IllegalArgumentException.class, () -> recargos.actualizar(
+            Map.of(MedioPago.A_CUENTA, new BigDecimal("5"))));
+    }
+
+    @Test
+    void deberia_dejar_el_total_del_pedido_sin_el_recargo() {
+        Producto pizza = new Producto(ProductoId.generate(), localId, "Pizza",
+            new BigDecimal("8000"), true, "#FF0000");
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), 1,
+            EstadoPedido.ABIERTO, AHORA.minusHours(1));
+        pedido.agregarProducto(pizza, 1, null);
+        RecargosMedioPago recargos = new RecargosMedioPago(localId, Map.of(MedioPago.TARJETA, new BigDecimal("10")));
+
+        pedido.cerrar(List.of(recargos.cobrar(MedioPago.TARJETA, pedido.calcularTotal(), AHORA)), AHORA);
+
+        assertEquals(0, new BigDecimal("8000").compareTo(pedido.getMontoTotalFinal()));
+        assertEquals(0, new BigDecimal("800").compareTo(pedido.calcularTotalRecargos()));
+    }
+}
@@ -0,0 +1,83 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.UnidadInsumo;
+import com.agustinpalma.comandas.domain.model.DomainIds.InsumoId;
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.ProductoId;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.Map;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para Receta y RentabilidadProducto.
+ * Sin Spring, sin base de datos.
+ */
+class RecetaTest {
+
+    private final LocalId localId = LocalId.generate();
+
+    private final Insumo pan = new Insumo(InsumoId.generate(), localId, "Pan de papa", UnidadInsumo.UNIDAD,
+            new BigDecimal("450"));
+    private final Insumo carne = new Insumo(InsumoId.generate(), localId, "Carne picada", UnidadInsumo.KILOGRAMO,
+            new BigDecimal("9800"));
+
+    private final Map<InsumoId, Insumo> insumos = Map.of(pan.getId(), pan, carne.getId(), carne);
+
+    private Producto producto(String precio) {
+        return new Producto(ProductoId.generate(), localId, "Hamburguesa simple", new BigDecimal(precio), true, null);
+    }
+
+    private Receta receta(Producto producto) {
+        return new Receta(producto.getId(), localId, List.of(
+                new IngredienteReceta(pan.getId(), BigDecimal.ONE),
+                new IngredienteReceta(carne.getId(), new BigDecimal("0.180"))
+        ));
+    }
+
+    // ============================================
+    // Tests: HU-118 Costeo de recetas y rentabilidad
+    // ============================================
+
+    @Test
+    void deberia_calcular_el_costo_teorico_con_el_costo_vigente_de_cada_insumo() {
+        Receta receta = receta(producto("6000"));
+
+        // 450 + 0.180 × 9800 = 2214
+        assertEquals(new BigDecimal("2214.00"), receta.calcularCosto(insumos));
+
+        carne.actualizar(carne.getNombre(), UnidadInsumo.KILOGRAMO, new BigDecimal("12000"));
+        assertEquals(new BigDecimal("2610.00"), receta.calcularCosto(insumos));
+    }
+
+    @Test
+    void deberia_rechazar_recetas_vacias_o_con_insumos_repetidos() {
+        ProductoId productoId = ProductoId.generate();
+
+        assertThrows(IllegalArgumentException.class, () -> new Receta(productoId, localId, List.of()));
+        assertThrows(IllegalArgumentException.class, () -> new Receta(productoId, localId, List.of(
+                new IngredienteReceta(pan.getId(), BigDecimal.ONE),
+                new IngredienteReceta(pan.getId(), BigDecimal.ONE)
+        )));
+        assertThrows(IllegalArgumentException.class,
+                () -> new IngredienteReceta(carne.getId(), new BigDecimal("0.1805")));
+    }
+
+    @Test
+    void deberia_marcar_a_perdida_cuando_el_costo_supera_al_precio() {
+        Producto barato = producto("2000");
+        RentabilidadProducto rentabilidad = new RentabilidadProducto(barato, receta(barato).calcularCosto(insumos));
+
+        assertTrue(rentabilidad.estaAPerdida());
+        assertEquals(new BigDecimal("-214.00"), rentabilidad.getMargen());
+
+        Producto bienPreciado = producto("6000");
+        RentabilidadProducto sana = new RentabilidadProducto(bienPreciado, receta(bienPreciado).calcularCosto(insumos));
+
+        assertFalse(sana.estaAPerdida());
+        assertEquals(new BigDecimal("63.10"), sana.getMargenPorcentaje());
+    }
+}
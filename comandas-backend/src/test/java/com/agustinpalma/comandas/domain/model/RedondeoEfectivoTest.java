@@ -0,0 +1,63 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.*;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para RedondeoEfectivo.
+ * Sin Spring, sin base de datos.
+ *
+ * HU-152: el efectivo se redondea al múltiplo configurado y la diferencia queda como ajuste.
+ */
+class RedondeoEfectivoTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 10, 14, 21, 0);
+
+    private final LocalId localId = LocalId.generate();
+
+    @Test
+    void deberia_calcular_el_ajuste_segun_el_modo() {
+        BigDecimal efectivo = new BigDecimal("12340");
+
+        assertEquals(0, new BigDecimal("-40").compareTo(
+            new RedondeoEfectivo(localId, new BigDecimal("100"), ModoRedondeo.CERCANO).calcularAjuste(efectivo)));
+        assertEquals(0, new BigDecimal("-40").compareTo(
+            new RedondeoEfectivo(localId, new BigDecimal("100"), ModoRedondeo.ABAJO).calcularAjuste(efectivo)));
+        assertEquals(0, new BigDecimal("60").compareTo(
+            new RedondeoEfectivo(localId, new BigDecimal("100"), ModoRedondeo.ARRIBA).calcularAjuste(efectivo)));
+        assertEquals(0, BigDecimal.ZERO.compareTo(RedondeoEfectivo.sinRedondeo(localId).calcularAjuste(efectivo)));
+    }
+
+    @Test
+    void deberia_redondear_solo_el_efectivo_en_pesos_sin_llegar_a_cero() {
+        RedondeoEfectivo redondeo = new RedondeoEfectivo(localId, new BigDecimal("100"), ModoRedondeo.ABAJO);
+        Pago tarjeta = new Pago(MedioPago.TARJETA, new BigDecimal("555"), AHORA);
+        Pago efectivo = new Pago(MedioPago.EFECTIVO, new BigDecimal("445"), AHORA);
+
+        List<Pago> pagos = redondeo.aplicar(List.of(tarjeta, efectivo));
+
+        assertEquals(tarjeta, pagos.get(0));
+        assertEquals(0, new BigDecimal("-45").compareTo(pagos.get(1).getAjusteRedondeo()));
+        assertEquals(0, new BigDecimal("400").compareTo(pagos.get(1).getMontoCobrado()));
+        assertEquals(0, BigDecimal.ZERO.compareTo(redondeo.calcularAjuste(new BigDecimal("80"))));
+        assertThrows(IllegalArgumentException.class, () -> tarjeta.conAjusteRedondeo(BigDecimal.TEN));
+    }
+
+    @Test
+    void deberia_rechazar_multiplos_invalidos() {
+        RedondeoEfectivo redondeo = RedondeoEfectivo.sinRedondeo(localId);
+
+        assertThrows(IllegalArgumentException.class, () -> redondeo.actualizar(new BigDecimal("-10"), null));
+        assertThrows(IllegalArgumentException.class, () -> redondeo.actualizar(new BigDecimal("5000"), null));
+        assertThrows(IllegalArgumentException.class, () -> redondeo.actualizar(new BigDecimal("0.005"), null));
+        redondeo.actualizar(BigDecimal.ZERO, ModoRedondeo.ARRIBA);
+        assertFalse(redondeo.estaActivo());
+    }
+}
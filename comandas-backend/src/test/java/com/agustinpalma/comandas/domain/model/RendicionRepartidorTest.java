@@ -0,0 +1,82 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.*;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para RendicionRepartidor.
+ * Sin Spring, sin base de datos.
+ *
+ * HU-140: el repartidor rinde lo cobrado en efectivo de sus entregas del turno.
+ */
+class RendicionRepartidorTest {
+
+    private static final LocalDateTime APERTURA = LocalDateTime.of(2026, 10, 14, 19, 0);
+
+    private final LocalId localId = LocalId.generate();
+    private final Producto pizza = new Producto(ProductoId.generate(), localId, "Pizza",
+            new BigDecimal("8000"), true, "#FF0000");
+
+    private TurnoCaja turno;
+    private Mozo cadete;
+
+    @BeforeEach
+    void setUp() {
+        turno = new TurnoCaja(TurnoCajaId.generate(), localId, JornadaCajaId.generate(),
+                TipoTurno.NOCHE, new BigDecimal("5000"), APERTURA);
+        cadete = new Mozo(MozoId.generate(), localId, "Lucho", true, RolPersonal.REPARTIDOR, null);
+    }
+
+    private Pedido entrega(int numero, MedioPago medio, boolean volvio) {
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, MesaId.generate(), numero,
+                EstadoPedido.ABIERTO, APERTURA.plusMinutes(numero));
+        pedido.asignarCanal(CanalVenta.DELIVERY, null);
+        pedido.agregarProducto(pizza, 1, null);
+        pedido.asignarRepartidor(cadete);
+        pedido.cerrar(List.of(new Pago(medio, pedido.calcularTotal(), APERTURA.plusHours(1))), APERTURA.plusHours(1));
+        pedido.asignarTurno(turno.getId());
+        pedido.registrarSalida(APERTURA.plusHours(1));
+        if (volvio) {
+            pedido.registrarRegreso(APERTURA.plusHours(1).plusMinutes(20));
+        }
+        return pedido;
+    }
+
+    @Test
+    void deberia_esperar_solo_el_efectivo_de_sus_entregas() {
+        // Given: dos entregas en efectivo y una pagada con QR
+        List<Pedido> pedidos = List.of(
+            entrega(1, MedioPago.EFECTIVO, true),
+            entrega(2, MedioPago.EFECTIVO, true),
+            entrega(3, MedioPago.QR, true)
+        );
+
+        // When: trae 15000 de los 16000 esperados
+        RendicionRepartidor rendicion = RendicionRepartidor.rendir(turno, cadete, pedidos,
+                new BigDecimal("15000"), "Vuelto mal dado", APERTURA.plusHours(5));
+
+        // Then
+        assertEquals(3, rendicion.getPedidos());
+        assertEquals(0, new BigDecimal("16000").compareTo(rendicion.getEsperado()));
+        assertEquals(0, new BigDecimal("-1000").compareTo(rendicion.getDiferencia()));
+    }
+
+    @Test
+    void deberia_rechazar_la_rendicion_con_pedidos_en_la_calle_o_diferencia_sin_motivo() {
+        List<Pedido> enLaCalle = List.of(entrega(1, MedioPago.EFECTIVO, false));
+        List<Pedido> entregados = List.of(entrega(2, MedioPago.EFECTIVO, true));
+
+        assertThrows(IllegalStateException.class, () -> RendicionRepartidor.rendir(turno, cadete, enLaCalle,
+                new BigDecimal("8000"), null, APERTURA.plusHours(5)));
+        assertThrows(IllegalStateException.class, () -> RendicionRepartidor.rendir(turno, cadete, entregados,
+                new BigDecimal("7000"), " ", APERTURA.plusHours(5)));
+    }
+}
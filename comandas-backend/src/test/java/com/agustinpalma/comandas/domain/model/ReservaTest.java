@@ -0,0 +1,81 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.*;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para Reserva.
+ * Sin Spring, sin base de datos.
+ *
+ * HU-142: la seña cobrada al reservar se descuenta de la cuenta de la mesa.
+ */
+class ReservaTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 10, 14, 21, 0);
+
+    private final LocalId localId = LocalId.generate();
+    private final MesaId mesaId = MesaId.generate();
+    private final Producto parrillada = new Producto(ProductoId.generate(), localId, "Parrillada",
+            new BigDecimal("12000"), true, "#FF0000");
+
+    private Reserva reserva;
+    private Pedido pedido;
+
+    @BeforeEach
+    void setUp() {
+        reserva = Reserva.crear(localId, "Cumpleaños de Sofi", "11 5555-1234", AHORA, 12, null, mesaId);
+        pedido = new Pedido(PedidoId.generate(), localId, mesaId, 1, EstadoPedido.ABIERTO, AHORA);
+        pedido.agregarProducto(parrillada, 2, null);
+    }
+
+    @Test
+    void deberia_descontar_la_senia_del_total_al_sentar_la_reserva() {
+        // Given: seña cobrada dos días antes
+        reserva.registrarSenia(new BigDecimal("5000"), MovimientoCajaId.generate(), AHORA.minusDays(2));
+
+        // When
+        reserva.sentar(pedido);
+
+        // Then: se cobra solo la diferencia y queda la trazabilidad en los dos sentidos
+        assertEquals(EstadoReserva.CUMPLIDA, reserva.getEstado());
+        assertEquals(pedido.getId(), reserva.getPedidoId());
+        assertEquals(reserva.getId(), pedido.getReservaId());
+        assertEquals(0, new BigDecimal("19000").compareTo(pedido.calcularTotal()));
+        assertTrue(pedido.obtenerAjustesEconomicos().stream()
+            .anyMatch(a -> a.getDescripcion().equals("Seña de la reserva")));
+        assertThrows(IllegalStateException.class, () -> reserva.cancelar());
+    }
+
+    @Test
+    void deberia_cerrar_sin_pagos_si_la_senia_cubre_todo_el_consumo() {
+        reserva.registrarSenia(new BigDecimal("30000"), MovimientoCajaId.generate(), AHORA.minusDays(1));
+        reserva.sentar(pedido);
+
+        pedido.cerrar(List.of(), AHORA.plusHours(2));
+
+        assertEquals(EstadoPedido.CERRADO, pedido.getEstado());
+        assertEquals(0, BigDecimal.ZERO.compareTo(pedido.getMontoTotalFinal()));
+    }
+
+    @Test
+    void deberia_cobrar_la_senia_una_sola_vez_y_solo_con_la_reserva_pendiente() {
+        reserva.registrarSenia(new BigDecimal("5000"), MovimientoCajaId.generate(), AHORA.minusDays(2));
+        assertThrows(IllegalStateException.class, () -> reserva.registrarSenia(new BigDecimal("1000"),
+                MovimientoCajaId.generate(), AHORA.minusDays(1)));
+
+        Reserva cancelada = Reserva.crear(localId, "Juan", null, AHORA, 2, null, null);
+        cancelada.cancelar();
+        assertThrows(IllegalStateException.class, () -> cancelada.registrarSenia(new BigDecimal("1000"),
+                MovimientoCajaId.generate(), AHORA));
+        assertThrows(IllegalArgumentException.class, () -> Reserva.crear(localId, " ", null, AHORA, 2, null, null));
+        assertThrows(IllegalArgumentException.class, () -> Reserva.crear(localId, "Ana", null, AHORA, 0, null, null));
+    }
+}
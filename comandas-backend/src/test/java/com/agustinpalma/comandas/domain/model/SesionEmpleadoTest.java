@@ -0,0 +1,54 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.Test;
+
+import java.time.Duration;
+import java.time.LocalDateTime;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para SesionEmpleado.
+ * Sin Spring, sin base de datos.
+ *
+ * HU-169: la terminal opera con un token al azar que vence solo.
+ */
+class SesionEmpleadoTest {
+
+    private final LocalId localId = LocalId.generate();
+    private final MozoId empleadoId = MozoId.generate();
+    private final LocalDateTime ahora = LocalDateTime.of(2026, 10, 14, 20, 0);
+
+    @Test
+    void deberia_guardar_solo_el_hash_del_token() {
+        SesionEmpleado.Apertura apertura = SesionEmpleado.abrir(localId, empleadoId, ahora, Duration.ofMinutes(60));
+
+        assertNotEquals(apertura.token(), apertura.sesion().getHashToken());
+        assertEquals(SesionEmpleado.hash(apertura.token()), apertura.sesion().getHashToken());
+        assertEquals(empleadoId, apertura.sesion().getEmpleadoId());
+    }
+
+    @Test
+    void deberia_dar_un_token_distinto_en_cada_sesion() {
+        String primero = SesionEmpleado.abrir(localId, empleadoId, ahora, Duration.ofMinutes(60)).token();
+        String segundo = SesionEmpleado.abrir(localId, empleadoId, ahora, Duration.ofMinutes(60)).token();
+
+        assertNotEquals(primero, segundo);
+        assertNotEquals(empleadoId.getValue().toString(), primero);
+    }
+
+    @Test
+    void deberia_vencer_pasada_la_duracion() {
+        SesionEmpleado sesion = SesionEmpleado.abrir(localId, empleadoId, ahora, Duration.ofMinutes(60)).sesion();
+
+        assertTrue(sesion.estaVigente(ahora.plusMinutes(59)));
+        assertFalse(sesion.estaVigente(ahora.plusMinutes(60)));
+    }
+
+    @Test
+    void deberia_rechazar_una_duracion_que_no_sea_positiva() {
+        assertThrows(IllegalArgumentException.class,
+            () -> SesionEmpleado.abrir(localId, empleadoId, ahora, Duration.ZERO));
+    }
+}
@@ -0,0 +1,79 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.*;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para TarjetaRegalo.
+ * Sin Spring, sin base de datos.
+ *
+ * HU-144: saldo que se descuenta al usarla y vuelve si el pedido se reabre.
+ */
+class TarjetaRegaloTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 10, 14, 21, 0);
+    private static final LocalDate VENCIMIENTO = LocalDate.of(2026, 12, 31);
+
+    private final LocalId localId = LocalId.generate();
+    private final PedidoId pedidoId = PedidoId.generate();
+
+    private TarjetaRegalo tarjeta;
+
+    @BeforeEach
+    void setUp() {
+        tarjeta = TarjetaRegalo.emitir(localId, new BigDecimal("10000"), VENCIMIENTO, "Ana", "Sofi",
+            MovimientoCajaId.generate(), AHORA);
+    }
+
+    @Test
+    void deberia_descontar_el_saldo_y_agotarse_al_consumirlo_todo() {
+        tarjeta.consumir(pedidoId, new BigDecimal("4000"), AHORA.plusDays(1));
+        assertEquals(0, new BigDecimal("6000").compareTo(tarjeta.getSaldo()));
+        assertEquals(EstadoTarjetaRegalo.VIGENTE, tarjeta.getEstado(AHORA.toLocalDate()));
+
+        tarjeta.consumir(PedidoId.generate(), new BigDecimal("6000"), AHORA.plusDays(2));
+        assertEquals(EstadoTarjetaRegalo.AGOTADA, tarjeta.getEstado(AHORA.toLocalDate()));
+        assertThrows(IllegalStateException.class,
+            () -> tarjeta.consumir(PedidoId.generate(), new BigDecimal("1"), AHORA.plusDays(3)));
+    }
+
+    @Test
+    void deberia_servir_hasta_el_dia_de_vencimiento_inclusive() {
+        tarjeta.consumir(pedidoId, new BigDecimal("1000"), VENCIMIENTO.atTime(23, 0));
+
+        assertThrows(IllegalStateException.class,
+            () -> tarjeta.consumir(PedidoId.generate(), new BigDecimal("1000"), VENCIMIENTO.plusDays(1).atTime(12, 0)));
+        assertEquals(EstadoTarjetaRegalo.VENCIDA, tarjeta.getEstado(VENCIMIENTO.plusDays(1)));
+    }
+
+    @Test
+    void deberia_reintegrar_lo_consumido_en_el_pedido_reabierto() {
+        PedidoId otroPedido = PedidoId.generate();
+        tarjeta.consumir(pedidoId, new BigDecimal("2500"), AHORA);
+        tarjeta.consumir(otroPedido, new BigDecimal("1500"), AHORA);
+
+        BigDecimal reintegrado = tarjeta.reintegrar(pedidoId);
+
+        assertEquals(0, new BigDecimal("2500").compareTo(reintegrado));
+        assertEquals(0, new BigDecimal("8500").compareTo(tarjeta.getSaldo()));
+        assertEquals(0, new BigDecimal("1500").compareTo(tarjeta.consumidoEn(otroPedido)));
+    }
+
+    @Test
+    void deberia_normalizar_el_codigo_tipeado_con_guiones_y_minusculas() {
+        String tipeado = tarjeta.getCodigo().substring(0, 5).toLowerCase() + "-" + tarjeta.getCodigo().substring(5);
+
+        assertEquals(tarjeta.getCodigo(), TarjetaRegalo.normalizarCodigo(tipeado));
+        assertThrows(IllegalArgumentException.class, () -> TarjetaRegalo.normalizarCodigo("  "));
+        assertThrows(IllegalArgumentException.class, () -> TarjetaRegalo.emitir(localId, new BigDecimal("100"),
+            AHORA.toLocalDate(), null, null, null, AHORA));
+    }
+}
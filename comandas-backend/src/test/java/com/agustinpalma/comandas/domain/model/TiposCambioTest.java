@@ -0,0 +1,63 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.*;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.util.Map;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para TiposCambio.
+ * Sin Spring, sin base de datos.
+ *
+ * HU-151: el pago en otra moneda conserva el monto en pesos y congela el tipo de cambio.
+ */
+class TiposCambioTest {
+
+    private static final LocalDate HOY = LocalDate.of(2026, 10, 14);
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 10, 14, 21, 0);
+
+    private final LocalId localId = LocalId.generate();
+
+    @Test
+    void deberia_registrar_el_pago_en_dolares_con_el_tipo_de_cambio_del_dia() {
+        TiposCambio tiposCambio = new TiposCambio(localId, HOY, Map.of(Moneda.USD, new BigDecimal("1200")));
+
+        Pago pago = tiposCambio.convertir(
+            new Pago(MedioPago.EFECTIVO, new BigDecimal("25000"), AHORA, new BigDecimal("1200")), Moneda.USD);
+
+        assertEquals(Moneda.USD, pago.getMoneda());
+        assertEquals(0, new BigDecimal("25000").compareTo(pago.getMonto()));
+        assertEquals(0, new BigDecimal("20.83").compareTo(pago.getMontoOriginal()));
+        assertEquals(0, new BigDecimal("21.83").compareTo(pago.getMontoCobradoOriginal()));
+        assertTrue(pago.esMonedaExtranjera());
+    }
+
+    @Test
+    void deberia_rechazar_monedas_sin_cotizar_y_medios_que_solo_aceptan_pesos() {
+        TiposCambio tiposCambio = new TiposCambio(localId, HOY, Map.of(Moneda.USD, new BigDecimal("1200")));
+
+        assertThrows(IllegalStateException.class, () -> tiposCambio.cotizacionDe(Moneda.EUR));
+        assertThrows(IllegalArgumentException.class, () -> tiposCambio.convertir(
+            new Pago(MedioPago.QR, new BigDecimal("1000"), AHORA), Moneda.USD));
+        assertEquals(0, BigDecimal.ONE.compareTo(tiposCambio.cotizacionDe(Moneda.ARS)));
+    }
+
+    @Test
+    void deberia_rechazar_cotizaciones_invalidas() {
+        TiposCambio tiposCambio = TiposCambio.sinCotizar(localId, HOY);
+
+        assertThrows(IllegalArgumentException.class, () -> tiposCambio.actualizar(
+            Map.of(Moneda.ARS, BigDecimal.ONE)));
+        assertThrows(IllegalArgumentException.class, () -> tiposCambio.actualizar(
+            Map.of(Moneda.USD, BigDecimal.ZERO)));
+        assertThrows(IllegalArgumentException.class, () -> tiposCambio.actualizar(
+            Map.of(Moneda.EUR, new BigDecimal("1300.12345"))));
+        assertTrue(tiposCambio.getCotizaciones().isEmpty());
+    }
+}
@@ -0,0 +1,161 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainEnums.*;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para TurnoCaja (HU-106, HU-123, HU-129).
+ * Sin Spring, sin base de datos.
+ *
+ * Validan el ciclo ABIERTO → CERRADO y la fórmula de arqueo:
+ *   Esperado = Inicial + Ventas Efectivo + Ingresos − Egresos
+ */
+class TurnoCajaTest {
+
+    private static final LocalDateTime APERTURA = LocalDateTime.of(2026, 3, 10, 8, 0);
+
+    private TurnoCaja nuevoTurno(String montoInicial) {
+        return new TurnoCaja(TurnoCajaId.generate(), LocalId.generate(), JornadaCajaId.generate(),
+                TipoTurno.MANANA, new BigDecimal(montoInicial), APERTURA);
+    }
+
+    @Test
+    void deberia_calcular_faltante_al_cerrar_turno() {
+        // Given
+        TurnoCaja turno = nuevoTurno("5000");
+
+        // When: 12000 efectivo, 1000 ingresos, 2500 egresos; el cajero cuenta 15300
+        turno.cerrar(APERTURA.plusHours(7),
+                new BigDecimal("20000"), new BigDecimal("12000"),
+                new BigDecimal("1000"), new BigDecimal("2500"),
+                8, new BigDecimal("15300"));
+
+        // Then: esperado 15500 → faltan 200
+        assertEquals(EstadoTurno.CERRADO, turno.getEstado());
+        assertEquals(0, new BigDecimal("15500").compareTo(turno.getEfectivoEsperado()));
+        assertEquals(0, new BigDecimal("-200").compareTo(turno.getDiferencia()));
+        assertEquals(8, turno.getPedidosCerradosCount());
+    }
+
+    @Test
+    void deberia_no_tener_diferencia_mientras_esta_abierto() {
+        TurnoCaja turno = nuevoTurno("0");
+
+        assertTrue(turno.estaAbierto());
+        assertNull(turno.getDiferencia());
+    }
+
+    @Test
+    void deberia_rechazar_cerrar_dos_veces() {
+        // Given
+        TurnoCaja turno = nuevoTurno("1000");
+        turno.cerrar(APERTURA.plusHours(1), BigDecimal.ZERO, BigDecimal.ZERO,
+                BigDecimal.ZERO, BigDecimal.ZERO, 0, new BigDecimal("1000"));
+
+        // When / Then
+        assertThrows(IllegalStateException.class, () ->
+                turno.cerrar(APERTURA.plusHours(2), BigDecimal.ZERO, BigDecimal.ZERO,
+                        BigDecimal.ZERO, BigDecimal.ZERO, 0, new BigDecimal("1000")));
+    }
+
+    @Test
+    void deberia_rechazar_monto_inicial_negativo() {
+        assertThrows(IllegalArgumentException.class, () -> nuevoTurno("-1"));
+    }
+
+    @Test
+    void deberia_declarar_la_suma_del_conteo_por_denominacion() {
+        // Given: esperado 15500
+        TurnoCaja turno = nuevoTurno("5000");
+
+        // When: 1×10000 + 2×2000 + 3×500 + 0×100 = 15500
+        turno.cerrarConConteo(APERTURA.plusHours(7),
+                new BigDecimal("20000"), new BigDecimal("12000"),
+                new BigDecimal("1000"), new BigDecimal("2500"), 8,
+                List.of(new ConteoDenominacion(new BigDecimal("500"), 3),
+                        new ConteoDenominacion(new BigDecimal("100"), 0),
+                        new ConteoDenominacion(new BigDecimal("10000"), 1),
+                        new ConteoDenominacion(new BigDecimal("2000"), 2)));
+
+        // Then: sin diferencia; el detalle queda de mayor a menor y sin renglones en cero
+        assertEquals(0, new BigDecimal("15500").compareTo(turno.getEfectivoDeclarado()));
+        assertEquals(0, BigDecimal.ZERO.compareTo(turno.getDiferencia()));
+        assertEquals(List.of(new ConteoDenominacion(new BigDecimal("10000"), 1),
+                        new ConteoDenominacion(new BigDecimal("2000"), 2),
+                        new ConteoDenominacion(new BigDecimal("500"), 3)),
+                turno.getDenominaciones());
+    }
+
+    @Test
+    void deberia_rechazar_conteo_con_denominacion_repetida() {
+        TurnoCaja turno = nuevoTurno("0");
+
+        assertThrows(IllegalArgumentException.class, () ->
+                turno.cerrarConConteo(APERTURA.plusHours(1), BigDecimal.ZERO, BigDecimal.ZERO,
+                        BigDecimal.ZERO, BigDecimal.ZERO, 0,
+                        List.of(new ConteoDenominacion(new BigDecimal("1000"), 1),
+                                new ConteoDenominacion(new BigDecimal("1000.00"), 2))));
+        assertTrue(turno.estaAbierto());
+    }
+
+    @Test
+    void deberia_rechazar_cantidad_negativa_de_billetes() {
+        assertThrows(IllegalArgumentException.class, () ->
+                new ConteoDenominacion(new BigDecimal("1000"), -1));
+    }
+
+    @Test
+    void deberia_exigir_responsable_cuando_la_caja_no_cierra() {
+        // Given: esperado 1000, contado 900
+        TurnoCaja turno = nuevoTurno("1000");
+        turno.cerrar(APERTURA.plusHours(1), BigDecimal.ZERO, BigDecimal.ZERO,
+                BigDecimal.ZERO, BigDecimal.ZERO, 0, new BigDecimal("900"));
+
+        // When / Then
+        assertThrows(IllegalArgumentException.class, () -> turno.registrarResponsable(null, "Faltan 100"));
+    }
+
+    @Test
+    void deberia_registrar_responsable_y_observaciones_de_la_diferencia() {
+        // Given
+        TurnoCaja turno = nuevoTurno("1000");
+        turno.cerrar(APERTURA.plusHours(1), BigDecimal.ZERO, BigDecimal.ZERO,
+                BigDecimal.ZERO, BigDecimal.ZERO, 0, new BigDecimal("1100"));
+        MozoId cajero = MozoId.generate();
+
+        // When
+        turno.registrarResponsable(cajero, "  Vuelto mal dado en la mesa 4  ");
+
+        // Then
+        assertTrue(turno.tieneDiferencia());
+        assertEquals(cajero, turno.getResponsableId());
+        assertEquals("Vuelto mal dado en la mesa 4", turno.getObservaciones());
+    }
+
+    @Test
+    void deberia_permitir_cerrar_sin_responsable_si_no_hay_diferencia() {
+        TurnoCaja turno = nuevoTurno("1000");
+        turno.cerrar(APERTURA.plusHours(1), BigDecimal.ZERO, BigDecimal.ZERO,
+                BigDecimal.ZERO, BigDecimal.ZERO, 0, new BigDecimal("1000"));
+
+        turno.registrarResponsable(null, " ");
+
+        assertFalse(turno.tieneDiferencia());
+        assertNull(turno.getResponsableId());
+        assertNull(turno.getObservaciones());
+    }
+
+    @Test
+    void deberia_rechazar_responsable_en_turno_abierto() {
+        TurnoCaja turno = nuevoTurno("1000");
+
+        assertThrows(IllegalStateException.class, () -> turno.registrarResponsable(MozoId.generate(), null));
+    }
+}
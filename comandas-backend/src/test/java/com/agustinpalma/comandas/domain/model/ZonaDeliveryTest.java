@@ -0,0 +1,51 @@
+package com.agustinpalma.comandas.domain.model;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.util.List;
+import java.util.Optional;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+/**
+ * Tests de dominio puro para ZonaDelivery.
+ * Sin Spring, sin base de datos.
+ */
+class ZonaDeliveryTest {
+
+    private final LocalId localId = LocalId.generate();
+
+    // ============================================
+    // Tests: HU-139 Zonas de delivery con costo de envío
+    // ============================================
+
+    @Test
+    void deberia_encontrar_el_barrio_en_la_direccion_sin_distinguir_acentos_ni_mayusculas() {
+        ZonaDelivery zona = ZonaDelivery.crear(localId, "Norte", new BigDecimal("900"), null,
+                List.of("Alberdi", "Villa Belgrano", "Güemes"));
+
+        assertEquals(Optional.of("Villa Belgrano"), zona.barrioEn("Recta Martinoli 5500, villa belgrano"));
+        assertEquals(Optional.of("Güemes"), zona.barrioEn("Bº GUEMES, Belgrano 800"));
+        // Palabra completa: "Alberdina" no es Alberdi
+        assertEquals(Optional.empty(), zona.barrioEn("Pasaje Alberdina 12"));
+    }
+
+    @Test
+    void deberia_exigir_el_minimo_en_productos() {
+        ZonaDelivery zona = ZonaDelivery.crear(localId, "Lejos", new BigDecimal("1500"), new BigDecimal("12000"),
+                List.of());
+
+        IllegalStateException error = assertThrows(IllegalStateException.class,
+                () -> zona.validarMinimo(new BigDecimal("9500")));
+        assertTrue(error.getMessage().contains("faltan $2500.00"));
+        assertDoesNotThrow(() -> zona.validarMinimo(new BigDecimal("12000")));
+    }
+
+    @Test
+    void deberia_rechazar_montos_negativos() {
+        assertThrows(IllegalArgumentException.class,
+                () -> ZonaDelivery.crear(localId, "Centro", new BigDecimal("-1"), null, List.of()));
+    }
+}
@@ -0,0 +1,87 @@
+package com.agustinpalma.comandas.domain.service;
+
+import com.agustinpalma.comandas.domain.model.ComandaCocina;
+import com.agustinpalma.comandas.domain.model.ItemComandaCocina;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import com.agustinpalma.comandas.domain.service.EsperaCocinaService.EsperaEstimada;
+
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+
+import java.time.LocalDateTime;
+import java.util.List;
+
+import static org.assertj.core.api.Assertions.*;
+
+/**
+ * Tests de la espera estimada en cocina.
+ *
+ * HU-138: Tiempo estimado de espera para take away.
+ *
+ * La espera es el promedio de preparación, o lo que tarda en salir la
+ * cola al ritmo reciente de cocina si eso es más largo.
+ */
+@DisplayName("EsperaCocinaService - Espera estimada para take away")
+class EsperaCocinaServiceTest {
+
+    private static final LocalDateTime AHORA = LocalDateTime.of(2026, 10, 9, 21, 0);
+
+    private EsperaCocinaService service;
+    private LocalId localId;
+
+    @BeforeEach
+    void setUp() {
+        service = new EsperaCocinaService();
+        localId = LocalId.generate();
+    }
+
+    @Test
+    @DisplayName("Sin historial ni cola promete el tiempo por defecto")
+    void deberia_usar_el_tiempo_por_defecto_sin_historial() {
+        // When
+        EsperaEstimada espera = service.estimar(List.of(), List.of(), AHORA);
+
+        // Then
+        assertThat(espera.comandasEnCola()).isZero();
+        assertThat(espera.minutosEspera()).isEqualTo(ComandaCocina.MINUTOS_ALERTA_POR_DEFECTO);
+        assertThat(espera.horaEstimada()).isEqualTo(AHORA.plusMinutes(15));
+    }
+
+    @Test
+    @DisplayName("Con cola larga espera a que salga al ritmo reciente y redondea a 5 minutos")
+    void deberia_sumar_la_cola_al_ritmo_de_salida() {
+        // Given: tres listas que tardaron 12 minutos, saliendo cada 4 minutos
+        List<ComandaCocina> listas = List.of(
+            lista(AHORA.minusMinutes(32), 12),
+            lista(AHORA.minusMinutes(28), 12),
+            lista(AHORA.minusMinutes(24), 12)
+        );
+        List<ComandaCocina> cola = List.of(
+            pendiente(AHORA.minusMinutes(10)), pendiente(AHORA.minusMinutes(8)),
+            pendiente(AHORA.minusMinutes(6)), pendiente(AHORA.minusMinutes(4)),
+            pendiente(AHORA.minusMinutes(2))
+        );
+
+        // When
+        EsperaEstimada espera = service.estimar(cola, listas, AHORA);
+
+        // Then: 5 en cola × 4 minutos = 20, más que los 12 de preparación
+        assertThat(espera.minutosPreparacion()).isEqualTo(12);
+        assertThat(espera.minutosEspera()).isEqualTo(20);
+
+        // Con una sola en cola alcanza con el tiempo de preparación (12 → 15)
+        assertThat(service.estimar(cola.subList(0, 1), listas, AHORA).minutosEspera()).isEqualTo(15);
+    }
+
+    private ComandaCocina pendiente(LocalDateTime enviadaEn) {
+        return ComandaCocina.enviar(localId, PedidoId.generate(), 1, 10, enviadaEn, 15,
+            List.of(new ItemComandaCocina("Hamburguesa", 1, null)));
+    }
+
+    private ComandaCocina lista(LocalDateTime enviadaEn, int minutos) {
+        ComandaCocina comanda = pendiente(enviadaEn);
+        comanda.marcarLista(enviadaEn.plusMinutes(minutos));
+        return comanda;
+    }
+}
@@ -15,6 +15,7 @@ import java.math.BigDecimal;
 import java.time.DayOfWeek;
 import java.time.LocalDate;
 import java.time.LocalDateTime;
+import java.time.LocalTime;
 import java.util.*;
 
 import static org.assertj.core.api.Assertions.*;
@@ -30,6 +31,7 @@ import static org.assertj.core.api.Assertions.*;
  * 3. Resolución de conflictos por Prioridad
  * 4. Validación de Vigencia temporal
  * 5. Cantidad Fija (2x1)
+ * 6. Franja horaria evaluada por momento de carga (HU-102)
  * 
  * Principios:
  * - Tests de dominio puros: sin Spring, sin base de datos
@@ -415,6 +417,89 @@ class MotorReglasServiceTest {
         }
     }
 
+    // =================================================
+    // Escenario 4b: FRANJA HORARIA (Happy Hour) - HU-102
+    // =================================================
+
+    @Nested
+    @DisplayName("Franja horaria: se evalúa según el momento de carga de cada ítem")
+    class FranjaHorariaTests {
+
+        // Lunes 2 de marzo de 2026
+        private final LocalDateTime lunesDentroDeFranja = LocalDateTime.of(2026, 3, 2, 19, 30);
+        private final LocalDateTime lunesFueraDeFranja = LocalDateTime.of(2026, 3, 2, 20, 30);
+        private final LocalDateTime lunesAlCierre = LocalDateTime.of(2026, 3, 2, 23, 0);
+
+        @Test
+        @DisplayName("debería conservar el 2x1 de una línea cargada en Happy Hour aunque se recalcule fuera de la franja")
+        void deberia_conservar_promo_de_linea_cargada_dentro_de_franja() {
+            // Given: 2x1 en cerveza de lunes a jueves de 18 a 20
+            Promocion happyHour = crearPromocionHappyHour("Happy Hour 2x1", cerveza.getId().getValue());
+            List<Promocion> promociones = List.of(happyHour);
+
+            ItemPedido item = motorReglas.aplicarReglas(
+                pedido, cerveza, 2, null, promociones, lunesDentroDeFranja
+            );
+            pedido.agregarItem(item);
+
+            // When: Se recalculan las promos al cerrar, a las 23hs
+            pedido.limpiarPromocionesItems();
+            motorReglas.aplicarPromociones(pedido, promociones, lunesAlCierre);
+
+            // Then: La línea mantiene el descuento con el que fue cargada
+            assertThat(item.getFechaAgregado()).isEqualTo(lunesDentroDeFranja);
+            assertThat(item.tienePromocion()).isTrue();
+            assertThat(item.getNombrePromocion()).isEqualTo("Happy Hour 2x1");
+            assertThat(item.getMontoDescuento()).isEqualByComparingTo(new BigDecimal("2500"));
+        }
+
+        @Test
+        @DisplayName("NO debería combinar para el 2x1 unidades cargadas dentro y fuera de la franja")
+        void no_deberia_combinar_unidades_de_distintas_franjas() {
+            // Given: Una cerveza pedida a las 19:30 y otra a las 20:30
+            Promocion happyHour = crearPromocionHappyHour("Happy Hour 2x1", cerveza.getId().getValue());
+            List<Promocion> promociones = List.of(happyHour);
+
+            ItemPedido primera = motorReglas.aplicarReglas(
+                pedido, cerveza, 1, null, promociones, lunesDentroDeFranja
+            );
+            pedido.agregarItem(primera);
+            ItemPedido segunda = motorReglas.aplicarReglas(
+                pedido, cerveza, 1, "bien fría", promociones, lunesFueraDeFranja
+            );
+            pedido.agregarItem(segunda);
+
+            // When
+            pedido.limpiarPromocionesItems();
+            motorReglas.aplicarPromociones(pedido, promociones, lunesAlCierre);
+
+            // Then: Ninguna línea completa el 2x1 por sí sola
+            assertThat(primera.tienePromocion()).isFalse();
+            assertThat(segunda.tienePromocion()).isFalse();
+        }
+
+        @Test
+        @DisplayName("debería indicar si una nueva carga comparte franja con un ítem existente")
+        void deberia_detectar_si_comparten_franja() {
+            // Given
+            Promocion happyHour = crearPromocionHappyHour("Happy Hour 2x1", cerveza.getId().getValue());
+            List<Promocion> promociones = List.of(happyHour);
+
+            ItemPedido existente = motorReglas.aplicarReglas(
+                pedido, cerveza, 1, null, promociones, lunesDentroDeFranja
+            );
+            pedido.agregarItem(existente);
+
+            // When / Then
+            assertThat(motorReglas.compartenFranjaPromocional(
+                pedido, existente, promociones, LocalDateTime.of(2026, 3, 2, 19, 55)
+            )).isTrue();
+            assertThat(motorReglas.compartenFranjaPromocional(
+                pedido, existente, promociones, lunesFueraDeFranja
+            )).isFalse();
+        }
+    }
+
     // =================================================
     // Escenario 5: CANTIDAD FIJA (2x1)
     // =================================================
@@ -1191,6 +1276,37 @@ class MotorReglasServiceTest {
         return promo;
     }
 
+    /**
+     * HU-102: 2x1 de lunes a jueves de 18 a 20, vigente durante 2026.
+     */
+    private Promocion crearPromocionHappyHour(String nombre, UUID productoTargetId) {
+        EstrategiaPromocion estrategia = new CantidadFija(2, 1);
+
+        CriterioActivacion trigger = new CriterioTemporal(
+            LocalDate.of(2026, 1, 1),
+            LocalDate.of(2026, 12, 31),
+            Set.of(DayOfWeek.MONDAY, DayOfWeek.TUESDAY, DayOfWeek.WEDNESDAY, DayOfWeek.THURSDAY),
+            LocalTime.of(18, 0),
+            LocalTime.of(20, 0)
+        );
+
+        Promocion promo = new Promocion(
+            PromocionId.generate(),
+            localId,
+            nombre,
+            "Promo franja horaria",
+            10,
+            EstadoPromocion.ACTIVA,
+            estrategia,
+            List.of(trigger)
+        );
+
+        ItemPromocion itemTarget = ItemPromocion.productoTarget(productoTargetId);
+        promo.definirAlcance(new AlcancePromocion(List.of(itemTarget)));
+
+        return promo;
+    }
+
     private Promocion crearPromocionCantidadFija(
             String nombre,
             int cantidadLlevas,
@@ -0,0 +1,104 @@
+package com.agustinpalma.comandas.domain.service;
+
+import com.agustinpalma.comandas.domain.model.MovimientoPuntos;
+import com.agustinpalma.comandas.domain.model.DomainIds.*;
+import com.agustinpalma.comandas.domain.service.ProgramaPuntosService.SaldoPuntos;
+
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.time.LocalDateTime;
+import java.util.List;
+
+import static org.assertj.core.api.Assertions.*;
+
+/**
+ * Tests del cálculo de saldo de puntos.
+ *
+ * HU-105: Programa de puntos de clientes.
+ *
+ * El saldo se deriva por lotes FIFO: los canjes consumen primero
+ * las acumulaciones más viejas y solo vence lo que quedó sin usar.
+ */
+@DisplayName("ProgramaPuntosService - Saldo FIFO con vencimiento")
+class ProgramaPuntosServiceTest {
+
+    private ProgramaPuntosService service;
+    private ClienteId clienteId;
+    private LocalId localId;
+
+    @BeforeEach
+    void setUp() {
+        service = new ProgramaPuntosService();
+        clienteId = ClienteId.generate();
+        localId = LocalId.generate();
+    }
+
+    @Test
+    @DisplayName("El canje consume primero el lote más viejo y vence solo el remanente")
+    void deberia_consumir_lote_mas_viejo_y_vencer_remanente() {
+        // Given: 100 puntos que vencen el 01/03 y 50 que vencen el 01/04; canje de 70 el 15/02
+        List<MovimientoPuntos> movimientos = List.of(
+            acumulacion(100, LocalDateTime.of(2026, 1, 1, 20, 0), LocalDateTime.of(2026, 3, 1, 20, 0)),
+            acumulacion(50, LocalDateTime.of(2026, 2, 1, 20, 0), LocalDateTime.of(2026, 4, 1, 20, 0)),
+            canje(70, LocalDateTime.of(2026, 2, 15, 20, 0))
+        );
+
+        // When: saldo al 10/03 (ya venció el primer lote)
+        SaldoPuntos saldo = service.calcularSaldo(movimientos, LocalDateTime.of(2026, 3, 10, 12, 0));
+
+        // Then: del primer lote vencen los 30 que no se usaron; el segundo queda intacto
+        assertThat(saldo.disponible()).isEqualTo(50);
+        assertThat(saldo.totalVencido()).isEqualTo(30);
+        assertThat(saldo.vencimientos().get(0).fecha()).isEqualTo(LocalDateTime.of(2026, 3, 1, 20, 0));
+        assertThat(saldo.lotesVigentes()).hasSize(1);
+        assertThat(saldo.lotesVigentes().get(0).fechaVencimiento()).isEqualTo(LocalDateTime.of(2026, 4, 1, 20, 0));
+    }
+
+    @Test
+    @DisplayName("Un canje posterior al vencimiento no puede usar los puntos vencidos")
+    void deberia_vencer_lote_antes_de_procesar_canje_posterior() {
+        // Given: 100 puntos vencen el 01/03; el 05/03 gana 40 y canjea 40
+        List<MovimientoPuntos> movimientos = List.of(
+            acumulacion(100, LocalDateTime.of(2026, 1, 1, 20, 0), LocalDateTime.of(2026, 3, 1, 20, 0)),
+            acumulacion(40, LocalDateTime.of(2026, 3, 5, 20, 0), null),
+            canje(40, LocalDateTime.of(2026, 3, 6, 20, 0))
+        );
+
+        // When
+        SaldoPuntos saldo = service.calcularSaldo(movimientos, LocalDateTime.of(2026, 3, 10, 12, 0));
+
+        // Then: los 100 vencieron completos y el canje consumió el lote nuevo
+        assertThat(saldo.totalVencido()).isEqualTo(100);
+        assertThat(saldo.disponible()).isZero();
+    }
+
+    @Test
+    @DisplayName("Los puntos sin vencimiento se mantienen disponibles")
+    void deberia_mantener_puntos_sin_vencimiento() {
+        // Given
+        List<MovimientoPuntos> movimientos = List.of(
+            acumulacion(25, LocalDateTime.of(2025, 1, 1, 20, 0), null)
+        );
+
+        // When
+        SaldoPuntos saldo = service.calcularSaldo(movimientos, LocalDateTime.of(2026, 3, 10, 12, 0));
+
+        // Then
+        assertThat(saldo.disponible()).isEqualTo(25);
+        assertThat(saldo.vencimientos()).isEmpty();
+        assertThat(saldo.lotesVigentes().get(0).fechaVencimiento()).isNull();
+    }
+
+    // --- Helpers ---
+
+    private MovimientoPuntos acumulacion(int puntos, LocalDateTime fecha, LocalDateTime vencimiento) {
+        return MovimientoPuntos.acumulacion(clienteId, localId, PedidoId.generate(), puntos, fecha, vencimiento);
+    }
+
+    private MovimientoPuntos canje(int puntos, LocalDateTime fecha) {
+        return MovimientoPuntos.canje(clienteId, localId, PedidoId.generate(), puntos, fecha, BigDecimal.valueOf(puntos));
+    }
+}
@@ -0,0 +1,102 @@
+package com.agustinpalma.comandas.infrastructure.config;
+
+import com.agustinpalma.comandas.domain.model.DomainIds.LocalId;
+import com.agustinpalma.comandas.domain.model.DomainIds.MozoId;
+import com.agustinpalma.comandas.domain.model.SesionEmpleado;
+import com.agustinpalma.comandas.domain.repository.SesionEmpleadoRepository;
+import org.junit.jupiter.api.AfterEach;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.DisplayName;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+import org.springframework.mock.web.MockHttpServletRequest;
+import org.springframework.web.context.request.RequestContextHolder;
+import org.springframework.web.context.request.ServletRequestAttributes;
+
+import java.time.Clock;
+import java.time.Duration;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.util.Optional;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.mockito.ArgumentMatchers.anyString;
+import static org.mockito.Mockito.when;
+
+/**
+ * Test unitario del adaptador que resuelve el empleado del token de sesión.
+ * HU-169: sin una sesión vigente no hay empleado, aunque se conozca su id.
+ */
+@ExtendWith(MockitoExtension.class)
+@DisplayName("Empleado de la sesión del request")
+class HeaderEmpleadoContextProviderTest {
+
+    @Mock
+    private SesionEmpleadoRepository sesionEmpleadoRepository;
+
+    private HeaderEmpleadoContextProvider provider;
+    private MockHttpServletRequest request;
+    private LocalDateTime ahora;
+
+    @BeforeEach
+    void setUp() {
+        Clock clock = Clock.fixed(Instant.parse("2026-10-14T22:00:00Z"), ZoneId.of("America/Argentina/Buenos_Aires"));
+        provider = new HeaderEmpleadoContextProvider(sesionEmpleadoRepository, clock);
+        ahora = LocalDateTime.now(clock);
+        request = new MockHttpServletRequest();
+        RequestContextHolder.setRequestAttributes(new ServletRequestAttributes(request));
+    }
+
+    @AfterEach
+    void tearDown() {
+        RequestContextHolder.resetRequestAttributes();
+    }
+
+    @Test
+    @DisplayName("Resuelve el empleado de una sesión vigente")
+    void deberia_resolver_el_empleado_de_la_sesion() {
+        // Given
+        MozoId empleadoId = MozoId.generate();
+        SesionEmpleado.Apertura apertura = SesionEmpleado.abrir(LocalId.generate(), empleadoId, ahora, Duration.ofMinutes(60));
+        when(sesionEmpleadoRepository.buscarPorHash(apertura.sesion().getHashToken()))
+            .thenReturn(Optional.of(apertura.sesion()));
+        request.addHeader(HeaderEmpleadoContextProvider.HEADER, apertura.token());
+
+        // When / Then
+        assertThat(provider.getEmpleadoActual()).contains(empleadoId);
+    }
+
+    @Test
+    @DisplayName("Una sesión vencida no identifica a nadie")
+    void no_deberia_resolver_una_sesion_vencida() {
+        // Given
+        SesionEmpleado.Apertura apertura = SesionEmpleado.abrir(LocalId.generate(), MozoId.generate(),
+            ahora.minusMinutes(61), Duration.ofMinutes(60));
+        when(sesionEmpleadoRepository.buscarPorHash(apertura.sesion().getHashToken()))
+            .thenReturn(Optional.of(apertura.sesion()));
+        request.addHeader(HeaderEmpleadoContextProvider.HEADER, apertura.token());
+
+        // When / Then
+        assertThat(provider.getEmpleadoActual()).isEmpty();
+    }
+
+    @Test
+    @DisplayName("El id de un empleado en lugar del token no identifica a nadie")
+    void no_deberia_aceptar_el_id_del_empleado_como_token() {
+        // Given
+        when(sesionEmpleadoRepository.buscarPorHash(anyString())).thenReturn(Optional.empty());
+        request.addHeader(HeaderEmpleadoContextProvider.HEADER, MozoId.generate().getValue().toString());
+
+        // When / Then
+        assertThat(provider.getEmpleadoActual()).isEmpty();
+    }
+
+    @Test
+    @DisplayName("Sin el encabezado no hay empleado")
+    void no_deberia_haber_empleado_sin_encabezado() {
+        assertThat(provider.getEmpleadoActual()).isEmpty();
+    }
+}
@@ -1,5 +1,6 @@
 package com.agustinpalma.comandas.infrastructure.persistence;
 
+import com.agustinpalma.comandas.domain.exception.PedidoModificadoException;
 import com.agustinpalma.comandas.domain.model.*;
 import com.agustinpalma.comandas.domain.model.DomainIds.*;
 import com.agustinpalma.comandas.domain.model.DomainEnums.*;
@@ -339,4 +340,44 @@ class PedidoRepositoryIntegrationTest {
         // Verificar que son pedidos diferentes
         assertThat(recuperadoLocal1.get().getId()).isNotEqualTo(recuperadoLocal2.get().getId());
     }
+
+    @Test
+    @DisplayName("HU-165: De dos guardados sobre la misma versión, el segundo se rechaza")
+    void deberia_rechazar_el_guardado_sobre_una_version_vieja() {
+        // GIVEN - Dos terminales leen el pedido en la misma versión
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, mesa5Id, 1, EstadoPedido.ABIERTO, LocalDateTime.now());
+        pedidoRepository.guardar(pedido);
+        Pedido terminal1 = pedidoRepository.buscarAbiertoPorMesa(mesa5Id, localId).orElseThrow();
+        Pedido terminal2 = pedidoRepository.buscarAbiertoPorMesa(mesa5Id, localId).orElseThrow();
+
+        // WHEN - La primera guarda
+        Pedido guardado = pedidoRepository.guardar(terminal1);
+
+        // THEN - La versión sube y el guardado de la segunda no pisa al de la primera
+        assertThat(guardado.getVersion()).isEqualTo(terminal1.getVersion() + 1);
+        assertThatThrownBy(() -> pedidoRepository.guardar(terminal2))
+            .isInstanceOf(PedidoModificadoException.class);
+        assertThat(pedidoRepository.buscarAbiertoPorMesa(mesa5Id, localId).orElseThrow().getVersion())
+            .isEqualTo(guardado.getVersion());
+    }
+
+    @Test
+    @DisplayName("HU-166: Busca el último comprobante emitido con un encabezado, sin mezclar formatos")
+    void deberia_buscar_el_ultimo_comprobante_por_encabezado() {
+        // GIVEN - Comprobantes del prefijo B1 con y sin año
+        guardarCerradoConComprobante(mesa5Id, 1, "B1-00000041");
+        guardarCerradoConComprobante(mesa10Id, 2, "B1-00000007");
+        guardarCerradoConComprobante(mesa5Id, 3, "B1-2026-00000099");
+
+        // WHEN / THEN
+        assertThat(pedidoRepository.buscarUltimoComprobante(localId, "B1-")).contains("B1-00000041");
+        assertThat(pedidoRepository.buscarUltimoComprobante(localId, "B1-2026-")).contains("B1-2026-00000099");
+        assertThat(pedidoRepository.buscarUltimoComprobante(localId, "B-")).isEmpty();
+    }
+
+    private void guardarCerradoConComprobante(MesaId mesaId, int numero, String comprobante) {
+        Pedido pedido = new Pedido(PedidoId.generate(), localId, mesaId, numero, EstadoPedido.CERRADO, LocalDateTime.now());
+        pedido.asignarNumeroComprobante(comprobante);
+        pedidoRepository.guardar(pedido);
+    }
 }
@@ -0,0 +1,264 @@
+//! HU-164: Recuperación del pedido a medio tomar después de un corte.
+//!
+//! Cada ítem que el mozo agrega a una mesa se anota en el borrador de esa
+//! mesa antes de mandarlo al backend, y se borra cuando el backend lo
+//! confirma. Si la app se cierra de golpe o se corta la luz en el medio (o
+//! el servidor no responde), los ítems quedan en el borrador y al volver a
+//! abrir la mesa se ofrecen para cargarlos de nuevo, sin volver a preguntarle
+//! todo a la mesa.
+//!
+//! Los borradores viven en `borradores_pedido.json` en el directorio de datos
+//! de la app. Se escriben a un archivo temporal que se fuerza a disco y se
+//! renombra: un corte en plena escritura deja el borrador anterior, nunca un
+//! archivo a medio escribir.
+
+use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::Manager;
+
+use crate::i18n::{t, Mensaje};
+
+/// Serializa las escrituras: dos ítems agregados seguidos no se pisan.
+static ARCHIVO: Mutex<()> = Mutex::new(());
+
+/// Un ítem que el backend todavía no confirmó.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemBorrador {
+    /// Generado por el frontend para poder quitarlo cuando el backend responde
+    pub id: String,
+    /// Nombre del producto, para mostrarlo al recuperar sin pedir el catálogo
+    pub nombre: String,
+    /// Cuerpo de `POST /api/pedidos/{id}/items` tal como lo armó el POS
+    pub request: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BorradorPedido {
+    pub pedido_id: String,
+    /// Segundos desde epoch del último cambio
+    pub actualizado: u64,
+    pub items: Vec<ItemBorrador>,
+}
+
+type Borradores = BTreeMap<String, BorradorPedido>;
+
+fn borradores_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("borradores_pedido.json"))
+        .map_err(|e| t(Mensaje::DirectorioDatosNoResuelto { detalle: &e.to_string() }))
+}
+
+fn leer(app: &tauri::AppHandle) -> Borradores {
+    match borradores_path(app) {
+        Ok(path) => leer_de(&path),
+        Err(e) => {
+            debug!("[Borradores] No hay borradores en {}", e);
+            Borradores::new()
+        }
+    }
+}
+
+fn leer_de(path: &Path) -> Borradores {
+    match std::fs::read_to_string(path) {
+        Ok(json) => serde_json::from_str::<Borradores>(&json).unwrap_or_else(|e| {
+            error!("[Borradores] Archivo corrupto: {}. Se descartan los borradores.", e);
+            Borradores::new()
+        }),
+        Err(e) => {
+            debug!("[Borradores] No hay borradores en {:?} ({})", path, e);
+            Borradores::new()
+        }
+    }
+}
+
+fn escribir(app: &tauri::AppHandle, borradores: &Borradores) -> Result<(), String> {
+    escribir_en(&borradores_path(app)?, borradores)
+}
+
+fn escribir_en(path: &Path, borradores: &Borradores) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| {
+            t(Mensaje::DirectorioNoCreado { ruta: &format!("{:?}", parent), detalle: &e.to_string() })
+        })?;
+    }
+
+    let json = serde_json::to_string(borradores)
+        .map_err(|e| t(Mensaje::ConfigNoSerializada { detalle: &e.to_string() }))?;
+
+    let temporal = path.with_extension("json.tmp");
+    let resultado = std::fs::File::create(&temporal)
+        .and_then(|mut archivo| {
+            archivo.write_all(json.as_bytes())?;
+            archivo.sync_all()
+        })
+        .and_then(|_| std::fs::rename(&temporal, path));
+
+    resultado.map_err(|e| {
+        let msg = t(Mensaje::ConfigNoEscrita { ruta: &format!("{:?}", path), detalle: &e.to_string() });
+        warn!("[Borradores] {}", msg);
+        msg
+    })
+}
+
+fn ahora() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+/// Lee, modifica y vuelve a escribir los borradores con el archivo tomado.
+fn modificar(app: &tauri::AppHandle, cambio: impl FnOnce(&mut Borradores)) -> Result<(), String> {
+    let _guard = ARCHIVO.lock().unwrap_or_else(|e| e.into_inner());
+    let mut borradores = leer(app);
+    cambio(&mut borradores);
+    escribir(app, &borradores)
+}
+
+/// Anota un ítem en el borrador de la mesa. Si el pedido de la mesa cambió
+/// (se cerró y se volvió a abrir) el borrador anterior se reemplaza.
+pub fn guardar_item(app: &tauri::AppHandle, mesa_id: &str, pedido_id: &str, item: ItemBorrador) -> Result<(), String> {
+    debug!("[Borradores] Mesa {}: + {} ({})", mesa_id, item.nombre, item.id);
+    modificar(app, |borradores| anotar(borradores, mesa_id, pedido_id, item))
+}
+
+fn anotar(borradores: &mut Borradores, mesa_id: &str, pedido_id: &str, item: ItemBorrador) {
+    let borrador = borradores.entry(mesa_id.to_string()).or_insert_with(|| BorradorPedido {
+        pedido_id: pedido_id.to_string(),
+        actualizado: 0,
+        items: Vec::new(),
+    });
+    if borrador.pedido_id != pedido_id {
+        borrador.pedido_id = pedido_id.to_string();
+        borrador.items.clear();
+    }
+    borrador.items.retain(|i| i.id != item.id);
+    borrador.items.push(item);
+    borrador.actualizado = ahora();
+}
+
+/// Quita un ítem ya confirmado por el backend. Sin ítems, el borrador se borra.
+pub fn quitar_item(app: &tauri::AppHandle, mesa_id: &str, item_id: &str) -> Result<(), String> {
+    modificar(app, |borradores| quitar(borradores, mesa_id, item_id))
+}
+
+fn quitar(borradores: &mut Borradores, mesa_id: &str, item_id: &str) {
+    if let Some(borrador) = borradores.get_mut(mesa_id) {
+        borrador.items.retain(|i| i.id != item_id);
+        borrador.actualizado = ahora();
+        if borrador.items.is_empty() {
+            borradores.remove(mesa_id);
+        }
+    }
+}
+
+pub fn obtener(app: &tauri::AppHandle, mesa_id: &str) -> Option<BorradorPedido> {
+    let _guard = ARCHIVO.lock().unwrap_or_else(|e| e.into_inner());
+    let borrador = leer(app).remove(mesa_id);
+    if let Some(ref b) = borrador {
+        info!("[Borradores] Mesa {}: {} ítems sin confirmar", mesa_id, b.items.len());
+    }
+    borrador
+}
+
+pub fn descartar(app: &tauri::AppHandle, mesa_id: &str) -> Result<(), String> {
+    info!("[Borradores] Mesa {}: borrador descartado", mesa_id);
+    modificar(app, |borradores| {
+        borradores.remove(mesa_id);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn archivo(nombre: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("foodflow-borradores-{}-{}", std::process::id(), nombre));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir.join("borradores_pedido.json")
+    }
+
+    fn item(id: &str) -> ItemBorrador {
+        ItemBorrador {
+            id: id.to_string(),
+            nombre: "Milanesa".to_string(),
+            request: serde_json::json!({ "productoId": "p1", "cantidad": 1 }),
+        }
+    }
+
+    fn ids(borradores: &Borradores, mesa_id: &str) -> Vec<String> {
+        borradores[mesa_id].items.iter().map(|i| i.id.clone()).collect()
+    }
+
+    #[test]
+    fn escribe_a_un_temporal_y_lo_renombra() {
+        let path = archivo("renombra");
+        let mut borradores = Borradores::new();
+        anotar(&mut borradores, "m1", "p1", item("a"));
+
+        escribir_en(&path, &borradores).unwrap();
+
+        assert_eq!(ids(&leer_de(&path), "m1"), ["a"]);
+        assert!(!path.with_extension("json.tmp").exists());
+    }
+
+    #[test]
+    fn un_corte_en_plena_escritura_deja_el_borrador_anterior() {
+        let path = archivo("corte");
+        let mut borradores = Borradores::new();
+        anotar(&mut borradores, "m1", "p1", item("a"));
+        escribir_en(&path, &borradores).unwrap();
+
+        // Se cortó la luz con el temporal a medio escribir, antes de renombrar
+        std::fs::write(path.with_extension("json.tmp"), "{\"m1\": {\"pedidoId\"").unwrap();
+        assert_eq!(ids(&leer_de(&path), "m1"), ["a"]);
+
+        // La escritura siguiente pisa el temporal viejo
+        anotar(&mut borradores, "m1", "p1", item("b"));
+        escribir_en(&path, &borradores).unwrap();
+        assert_eq!(ids(&leer_de(&path), "m1"), ["a", "b"]);
+    }
+
+    #[test]
+    fn un_archivo_corrupto_o_inexistente_no_tiene_borradores() {
+        let path = archivo("corrupto");
+        assert!(leer_de(&path).is_empty());
+
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "no es json").unwrap();
+        assert!(leer_de(&path).is_empty());
+    }
+
+    #[test]
+    fn recuperar_un_item_no_lo_duplica_y_otro_pedido_reemplaza_el_borrador() {
+        let mut borradores = Borradores::new();
+        anotar(&mut borradores, "m1", "p1", item("a"));
+        anotar(&mut borradores, "m1", "p1", item("b"));
+
+        // El mismo ítem se vuelve a anotar al reintentarlo
+        anotar(&mut borradores, "m1", "p1", item("a"));
+        assert_eq!(ids(&borradores, "m1"), ["b", "a"]);
+
+        // La mesa se cerró y se abrió otro pedido: lo anterior ya no aplica
+        anotar(&mut borradores, "m1", "p2", item("c"));
+        assert_eq!(borradores["m1"].pedido_id, "p2");
+        assert_eq!(ids(&borradores, "m1"), ["c"]);
+    }
+
+    #[test]
+    fn confirmar_el_ultimo_item_borra_el_borrador_de_la_mesa() {
+        let mut borradores = Borradores::new();
+        anotar(&mut borradores, "m1", "p1", item("a"));
+        anotar(&mut borradores, "m2", "p9", item("z"));
+
+        quitar(&mut borradores, "m1", "a");
+
+        assert!(!borradores.contains_key("m1"));
+        assert_eq!(ids(&borradores, "m2"), ["z"]);
+    }
+}
@@ -0,0 +1,400 @@
+//! HU-155: Cortes de luz en la PC servidor enchufada a una UPS.
+//!
+//! Un loop en segundo plano lee del SO si hay red eléctrica y cuánta batería
+//! queda (Linux: `/sys/class/power_supply`, Windows: `GetSystemPowerStatus`).
+//! Una UPS conectada por USB aparece como batería en los dos.
+//!
+//! Cuando se corta la luz:
+//!
+//! 1. Avisa a todas las terminales (`POST /api/sistema/energia`).
+//! 2. Si la luz no vuelve en `gracia_segundos`, o la batería baja de
+//!    `umbral_bateria`, hace el apagado seguro:
+//!    - vacía la cola de impresión: salen los tickets que ya estaban
+//!      esperando turno y se rechazan los que lleguen después,
+//!    - pide al backend el apagado ordenado (`POST /api/sistema/apagar`),
+//!      que avisa a las terminales y cierra la base,
+//!    - copia la base a `respaldos/` recién con el backend cerrado, cuando
+//!      el archivo está consistente.
+//! 3. Si la luz vuelve, reanuda la cola y, si el backend ya se había
+//!    apagado, lo vuelve a lanzar.
+//!
+//! La configuración vive en `energia_config.json` en el directorio de datos
+//! de la app, igual que la de la impresora. Viene apagado: una notebook a
+//! batería se apagaría sola a los dos minutos. El dueño lo habilita con
+//! `"habilitado": true` cuando el servidor está enchufado a una UPS.
+
+use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::Manager;
+
+use crate::i18n::{t, Mensaje};
+use crate::impresion::{self, Suspension};
+use crate::{cliente_http, AppState, BACKEND_LOCAL};
+
+/// Cuánto se espera a que el backend termine solo antes de matarlo.
+const ESPERA_APAGADO: Duration = Duration::from_secs(30);
+
+/// Respaldos de emergencia que se conservan (los más viejos se borran).
+const RESPALDOS_CONSERVADOS: usize = 5;
+
+// ─── Configuración ────────────────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EnergiaConfig {
+    /// Si está en false no se lee el estado de la energía (por defecto: solo
+    /// tiene sentido con UPS)
+    #[serde(default = "default_habilitado")]
+    pub habilitado: bool,
+
+    /// Porcentaje de batería por debajo del cual se apaga sin esperar la gracia
+    #[serde(default = "default_umbral_bateria")]
+    pub umbral_bateria: u8,
+
+    /// Cuánto se espera a que vuelva la luz antes de apagar
+    #[serde(default = "default_gracia_segundos")]
+    pub gracia_segundos: u64,
+
+    /// Cada cuántos segundos se consulta al SO
+    #[serde(default = "default_intervalo_segundos")]
+    pub intervalo_segundos: u64,
+}
+
+fn default_habilitado() -> bool {
+    false
+}
+fn default_umbral_bateria() -> u8 {
+    30
+}
+fn default_gracia_segundos() -> u64 {
+    120
+}
+fn default_intervalo_segundos() -> u64 {
+    5
+}
+
+impl Default for EnergiaConfig {
+    fn default() -> Self {
+        Self {
+            habilitado: default_habilitado(),
+            umbral_bateria: default_umbral_bateria(),
+            gracia_segundos: default_gracia_segundos(),
+            intervalo_segundos: default_intervalo_segundos(),
+        }
+    }
+}
+
+fn config_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("energia_config.json"))
+        .map_err(|e| t(Mensaje::DirectorioDatosNoResuelto { detalle: &e.to_string() }))
+}
+
+pub fn load_config(app: &tauri::AppHandle) -> EnergiaConfig {
+    match config_path(app).and_then(|path| {
+        std::fs::read_to_string(&path).map_err(|e| format!("{:?} ({})", path, e))
+    }) {
+        Ok(json) => serde_json::from_str::<EnergiaConfig>(&json).unwrap_or_else(|e| {
+            error!("[Energía] Config corrupta: {}. Usando defaults.", e);
+            EnergiaConfig::default()
+        }),
+        Err(e) => {
+            debug!("[Energía] No existe config en {}. Usando defaults.", e);
+            EnergiaConfig::default()
+        }
+    }
+}
+
+// ─── Estado del SO ────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct EstadoEnergia {
+    con_red: bool,
+    /// None si el SO no informa el porcentaje
+    bateria: Option<u8>,
+}
+
+/// Lee `/sys/class/power_supply`. Sin batería ni UPS devuelve None: no hay
+/// nada que proteger.
+#[cfg(target_os = "linux")]
+fn leer_estado() -> Option<EstadoEnergia> {
+    let leer = |dir: &Path, archivo: &str| {
+        std::fs::read_to_string(dir.join(archivo)).ok().map(|s| s.trim().to_string())
+    };
+
+    let mut red: Option<bool> = None;
+    let mut bateria: Option<u8> = None;
+    let mut descargando = false;
+    let mut hay_bateria = false;
+
+    for entrada in std::fs::read_dir("/sys/class/power_supply").ok()?.flatten() {
+        let dir = entrada.path();
+        match leer(&dir, "type").as_deref() {
+            Some("Mains") | Some("USB") => {
+                let online = leer(&dir, "online").as_deref() == Some("1");
+                red = Some(red.unwrap_or(false) || online);
+            }
+            Some("Battery") | Some("UPS") => {
+                hay_bateria = true;
+                descargando |= leer(&dir, "status").as_deref() == Some("Discharging");
+                if let Some(capacidad) = leer(&dir, "capacity").and_then(|c| c.parse::<u8>().ok()) {
+                    bateria = Some(bateria.map_or(capacidad, |b| b.min(capacidad)));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if !hay_bateria {
+        return None;
+    }
+    // Sin fuente "Mains" (UPS por USB en una PC de escritorio) manda la batería
+    Some(EstadoEnergia { con_red: red.unwrap_or(!descargando), bateria })
+}
+
+#[cfg(target_os = "windows")]
+fn leer_estado() -> Option<EstadoEnergia> {
+    #[repr(C)]
+    #[allow(non_snake_case, dead_code)]
+    struct SYSTEM_POWER_STATUS {
+        ACLineStatus: u8,
+        BatteryFlag: u8,
+        BatteryLifePercent: u8,
+        SystemStatusFlag: u8,
+        BatteryLifeTime: u32,
+        BatteryFullLifeTime: u32,
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetSystemPowerStatus(status: *mut SYSTEM_POWER_STATUS) -> i32;
+    }
+
+    const SIN_BATERIA: u8 = 128;
+    const DESCONOCIDO: u8 = 255;
+
+    let mut status = SYSTEM_POWER_STATUS {
+        ACLineStatus: DESCONOCIDO,
+        BatteryFlag: DESCONOCIDO,
+        BatteryLifePercent: DESCONOCIDO,
+        SystemStatusFlag: 0,
+        BatteryLifeTime: 0,
+        BatteryFullLifeTime: 0,
+    };
+    // SAFETY: la estructura tiene el layout de la API y vive durante la llamada
+    if unsafe { GetSystemPowerStatus(&mut status) } == 0 {
+        return None;
+    }
+    if status.BatteryFlag == DESCONOCIDO || status.BatteryFlag & SIN_BATERIA != 0 || status.ACLineStatus == DESCONOCIDO {
+        return None;
+    }
+    let bateria = (status.BatteryLifePercent <= 100).then_some(status.BatteryLifePercent);
+    Some(EstadoEnergia { con_red: status.ACLineStatus == 1, bateria })
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn leer_estado() -> Option<EstadoEnergia> {
+    None
+}
+
+// ─── Monitor ──────────────────────────────────────────────────────────────────
+
+/// Loop en segundo plano. Relee la configuración en cada vuelta, igual que
+/// la sincronización.
+pub async fn iniciar_monitor_energia(app: tauri::AppHandle) {
+    let mut corte: Option<Instant> = None;
+    let mut apagado = false;
+
+    loop {
+        let config = load_config(&app);
+        tokio::time::sleep(Duration::from_secs(config.intervalo_segundos.max(1))).await;
+
+        if !config.habilitado {
+            continue;
+        }
+        let Some(estado) = leer_estado() else {
+            continue;
+        };
+
+        match (estado.con_red, corte) {
+            (false, None) => {
+                warn!("[Energía] Corte de luz detectado (batería: {:?}%)", estado.bateria);
+                corte = Some(Instant::now());
+                notificar(&serde_json::json!({
+                    "estado": "SIN_RED",
+                    "bateria": estado.bateria,
+                    "segundosParaApagar": config.gracia_segundos,
+                }))
+                .await;
+            }
+            (false, Some(inicio)) if !apagado => {
+                let sin_gracia = inicio.elapsed() >= Duration::from_secs(config.gracia_segundos);
+                let bateria_baja = estado.bateria.is_some_and(|b| b <= config.umbral_bateria);
+                if sin_gracia || bateria_baja {
+                    warn!(
+                        "[Energía] Apagado seguro: {} s sin luz, batería {:?}%",
+                        inicio.elapsed().as_secs(),
+                        estado.bateria
+                    );
+                    apagar_seguro(&app).await;
+                    apagado = true;
+                }
+            }
+            (true, Some(_)) => {
+                info!("[Energía] Volvió la luz");
+                corte = None;
+                reanudar_impresion().await;
+                if apagado {
+                    apagado = false;
+                    match crate::start_backend(&app).await {
+                        Ok(_) => info!("[Energía] Backend relanzado tras el corte"),
+                        Err(e) => error!("[Energía] No se pudo relanzar el backend: {}", e),
+                    }
+                } else {
+                    notificar(&serde_json::json!({
+                        "estado": "RESTABLECIDA",
+                        "bateria": estado.bateria,
+                        "segundosParaApagar": null,
+                    }))
+                    .await;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Avisa a las terminales a través del backend. Al mejor esfuerzo: si el
+/// backend no responde, el apagado sigue igual.
+async fn notificar(evento: &serde_json::Value) {
+    let cliente = match cliente_http() {
+        Ok(cliente) => cliente,
+        Err(e) => {
+            warn!("[Energía] {}", e);
+            return;
+        }
+    };
+    let url = format!("{}/api/sistema/energia", BACKEND_LOCAL);
+    if let Err(e) = post(&cliente, &url).json(evento).send().await.and_then(|r| r.error_for_status()) {
+        warn!("[Energía] No se pudo avisar a las terminales: {}", e);
+    }
+}
+
+fn post(cliente: &reqwest::Client, url: &str) -> reqwest::RequestBuilder {
+    cliente.post(url).timeout(Duration::from_secs(5))
+}
+
+async fn reanudar_impresion() {
+    if let Err(e) = tauri::async_runtime::spawn_blocking(|| impresion::reanudar(Suspension::CorteDeLuz)).await {
+        error!("[Energía] No se pudo reanudar la impresión: {}", e);
+    }
+}
+
+// ─── Apagado seguro ───────────────────────────────────────────────────────────
+
+async fn apagar_seguro(app: &tauri::AppHandle) {
+    // 1. Que salga lo que está en la cola y no quede un ticket cortado a la mitad
+    if let Err(e) = tauri::async_runtime::spawn_blocking(|| impresion::suspender(Suspension::CorteDeLuz)).await {
+        error!("[Energía] No se pudo suspender la impresión: {}", e);
+    }
+
+    // 2. Apagado ordenado: el backend avisa a las terminales y cierra la base
+    let Some(mut child) = app
+        .try_state::<AppState>()
+        .and_then(|state| state.backend_process.lock().ok().and_then(|mut b| b.take()))
+    else {
+        warn!("[Energía] No hay backend corriendo para apagar");
+        return;
+    };
+
+    let url = format!("{}/api/sistema/apagar", BACKEND_LOCAL);
+    // Sin cliente HTTP no se le puede pedir: se fuerza el cierre como si no respondiera
+    let aceptado = match cliente_http() {
+        Ok(cliente) => post(&cliente, &url)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| e.to_string()),
+        Err(e) => Err(e),
+    };
+    let ordenado = match aceptado {
+        Ok(_) => match tokio::time::timeout(ESPERA_APAGADO, child.wait()).await {
+            Ok(Ok(estado)) => {
+                info!("[Energía] Backend apagado ({})", estado);
+                true
+            }
+            Ok(Err(e)) => {
+                error!("[Energía] No se pudo esperar al backend: {}", e);
+                false
+            }
+            Err(_) => {
+                warn!("[Energía] El backend no terminó en {} s", ESPERA_APAGADO.as_secs());
+                false
+            }
+        },
+        Err(e) => {
+            error!("[Energía] El backend no aceptó el apagado: {}", e);
+            false
+        }
+    };
+    if !ordenado {
+        warn!("[Energía] Se fuerza el cierre del backend");
+        if let Err(e) = child.kill().await {
+            error!("[Energía] No se pudo matar el backend: {}", e);
+        }
+    }
+
+    // 3. Respaldo de emergencia con la base ya cerrada
+    match app.path().app_data_dir() {
+        Ok(data_dir) => match respaldar(&data_dir) {
+            Ok(destino) => info!("[Energía] Respaldo de emergencia en {:?}", destino),
+            Err(e) => error!("[Energía] Falló el respaldo de emergencia: {}", e),
+        },
+        Err(e) => error!("[Energía] No se pudo resolver app_data_dir: {}", e),
+    }
+}
+
+/// Copia `comandas.db` (y el WAL, si quedó por un cierre forzado) a
+/// `respaldos/emergencia-<epoch>/` y fuerza la escritura a disco.
+fn respaldar(data_dir: &Path) -> std::io::Result<PathBuf> {
+    let epoch = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let respaldos = data_dir.join("respaldos");
+    let destino = respaldos.join(format!("emergencia-{}", epoch));
+    std::fs::create_dir_all(&destino)?;
+
+    for archivo in ["comandas.db", "comandas.db-wal"] {
+        let origen = data_dir.join(archivo);
+        if !origen.exists() {
+            continue;
+        }
+        let mut lector = std::fs::File::open(&origen)?;
+        let mut copia = std::fs::File::create(destino.join(archivo))?;
+        std::io::copy(&mut lector, &mut copia)?;
+        copia.sync_all()?;
+    }
+
+    depurar_respaldos(&respaldos);
+    Ok(destino)
+}
+
+/// Borra los respaldos de emergencia más viejos. El epoch tiene siempre la
+/// misma cantidad de dígitos, así que el orden alfabético es el cronológico.
+fn depurar_respaldos(respaldos: &Path) {
+    let Ok(entradas) = std::fs::read_dir(respaldos) else {
+        return;
+    };
+    let mut nombres: Vec<PathBuf> = entradas
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.file_name().is_some_and(|n| n.to_string_lossy().starts_with("emergencia-")))
+        .collect();
+    nombres.sort();
+    let sobrantes = nombres.len().saturating_sub(RESPALDOS_CONSERVADOS);
+    for viejo in nombres.into_iter().take(sobrantes) {
+        if let Err(e) = std::fs::remove_dir_all(&viejo) {
+            warn!("[Energía] No se pudo borrar el respaldo {:?}: {}", viejo, e);
+        }
+    }
+}
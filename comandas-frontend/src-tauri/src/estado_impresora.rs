@@ -0,0 +1,313 @@
+//! HU-158: Estado de las impresoras (sin papel, tapa abierta, desconectada).
+//!
+//! Hasta ahora la cocina se enteraba de que se acabó el papel cuando el
+//! cocinero avisaba que no le llegaba nada. Un loop en segundo plano consulta
+//! cada `monitoreo_segundos` a la impresora de cada estación:
+//!
+//! - **Red**: si el puerto 9100 no acepta la conexión, está desconectada.
+//! - **ESC/POS**: `DLE EOT 2` (causa de fuera de línea: tapa, papel, error) y
+//!   `DLE EOT 4` (sensor de papel: poco papel o sin papel). Cada respuesta es
+//!   un byte con los bits 1 y 4 en 1 y los bits 0 y 7 en 0.
+//!
+//! Cuando una estación cambia de estado se avisa a todas las terminales
+//! (`POST /api/sistema/impresoras`). El comando `printer_status` hace la
+//! misma consulta a pedido.
+//!
+//! La consulta toma la impresora igual que un ticket: nunca se mete en medio
+//! de uno, y una estación que no contesta no demora a las demás. Con la cola
+//! suspendida (corte de luz, mantenimiento) no se consulta.
+
+use log::{debug, info, warn};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::time::Duration;
+
+use crate::i18n::{t, Mensaje};
+use crate::impresion::{self, PrinterConfig};
+use crate::{cliente_http, BACKEND_LOCAL};
+
+/// Cuánto se espera la conexión y la respuesta de la impresora. Corto: la
+/// consulta ocupa la impresora.
+const ESPERA: Duration = Duration::from_secs(2);
+
+/// `DLE EOT 2` seguido de `DLE EOT 4`.
+const CONSULTA: [u8; 6] = [0x10, 0x04, 0x02, 0x10, 0x04, 0x04];
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Estado {
+    Lista,
+    PocoPapel,
+    SinPapel,
+    TapaAbierta,
+    Error,
+    Desconectada,
+    /// Conecta pero no contesta la consulta (o no la entiende)
+    SinRespuesta,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct EstadoImpresora {
+    pub estacion: String,
+    pub estado: Estado,
+    /// Texto para el operador, en el idioma vigente
+    pub detalle: String,
+}
+
+// ─── Consulta ─────────────────────────────────────────────────────────────────
+
+/// Consulta el estado de la impresora de una estación. Bloquea hasta
+/// [`ESPERA`] más lo que tarde el ticket en curso.
+pub fn consultar(config: &PrinterConfig) -> Result<EstadoImpresora, String> {
+    if let Some(motivo) = impresion::suspension() {
+        return Err(motivo);
+    }
+    let (estado, detalle) = match config.connection_type.as_str() {
+        "usb" => impresion::con_impresora(config, || consultar_usb(&config.usb_path)),
+        "tcp" => impresion::con_impresora(config, || consultar_tcp(&config.tcp_host, config.tcp_port)),
+        otro => return Err(t(Mensaje::ConexionNoSoportada { tipo: otro })),
+    };
+
+    debug!("[Impresora/Estado] {}: {:?}", config.estacion, estado);
+    Ok(EstadoImpresora {
+        estacion: config.estacion.clone(),
+        estado,
+        detalle: texto(estado, &config.estacion, detalle.as_deref()),
+    })
+}
+
+fn consultar_tcp(host: &str, port: u16) -> (Estado, Option<String>) {
+    use std::net::{SocketAddr, TcpStream};
+
+    let addr = format!("{}:{}", host, port);
+    let socket_addr: SocketAddr = match addr.parse() {
+        Ok(a) => a,
+        Err(e) => return (Estado::Desconectada, Some(e.to_string())),
+    };
+    let mut stream = match TcpStream::connect_timeout(&socket_addr, ESPERA) {
+        Ok(s) => s,
+        Err(e) => return (Estado::Desconectada, Some(impresion::detalle_io(&e))),
+    };
+
+    let mut respuesta = [0u8; 2];
+    let leido = stream
+        .set_read_timeout(Some(ESPERA))
+        .and_then(|_| stream.write_all(&CONSULTA))
+        .and_then(|_| stream.read_exact(&mut respuesta));
+    match leido {
+        Ok(()) => (decodificar(respuesta[0], respuesta[1]), None),
+        Err(e) => (Estado::SinRespuesta, Some(impresion::detalle_io(&e))),
+    }
+}
+
+/// Se abre sin bloquear: una impresora que no contesta no deja el dispositivo
+/// tomado (`usblp` admite un solo proceso a la vez y el ticket siguiente
+/// fallaría).
+#[cfg(unix)]
+fn consultar_usb(path: &str) -> (Estado, Option<String>) {
+    use std::os::unix::fs::OpenOptionsExt;
+    use std::time::Instant;
+
+    if !std::path::Path::new(path).exists() {
+        return (Estado::Desconectada, Some(t(Mensaje::UsbInexistente { ruta: path })));
+    }
+    let mut file = match std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .custom_flags(libc::O_NONBLOCK)
+        .open(path)
+    {
+        Ok(f) => f,
+        Err(e) => return (Estado::Desconectada, Some(impresion::detalle_io(&e))),
+    };
+    if let Err(e) = file.write_all(&CONSULTA).and_then(|_| file.flush()) {
+        return (Estado::Desconectada, Some(impresion::detalle_io(&e)));
+    }
+
+    let mut respuesta = [0u8; 2];
+    let mut leidos = 0;
+    let inicio = Instant::now();
+    while leidos < respuesta.len() && inicio.elapsed() < ESPERA {
+        match file.read(&mut respuesta[leidos..]) {
+            Ok(n) => leidos += n,
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {}
+            Err(e) => return (Estado::SinRespuesta, Some(impresion::detalle_io(&e))),
+        }
+        if leidos < respuesta.len() {
+            std::thread::sleep(Duration::from_millis(50));
+        }
+    }
+    if leidos < respuesta.len() {
+        return (Estado::SinRespuesta, None);
+    }
+    (decodificar(respuesta[0], respuesta[1]), None)
+}
+
+/// En Windows el puerto USB no se puede leer: solo se sabe si está conectada.
+#[cfg(not(unix))]
+fn consultar_usb(path: &str) -> (Estado, Option<String>) {
+    if std::path::Path::new(path).exists() {
+        (Estado::Lista, None)
+    } else {
+        (Estado::Desconectada, Some(t(Mensaje::UsbInexistente { ruta: path })))
+    }
+}
+
+/// `offline`: respuesta a `DLE EOT 2`. `papel`: respuesta a `DLE EOT 4`.
+fn decodificar(offline: u8, papel: u8) -> Estado {
+    let valido = |b: u8| b & 0x93 == 0x12;
+    if !valido(offline) || !valido(papel) {
+        return Estado::SinRespuesta;
+    }
+    if offline & 0x04 != 0 {
+        Estado::TapaAbierta
+    } else if offline & 0x20 != 0 || papel & 0x60 != 0 {
+        Estado::SinPapel
+    } else if offline & 0x40 != 0 {
+        Estado::Error
+    } else if papel & 0x0C != 0 {
+        Estado::PocoPapel
+    } else {
+        Estado::Lista
+    }
+}
+
+fn texto(estado: Estado, estacion: &str, detalle: Option<&str>) -> String {
+    match estado {
+        Estado::Lista => t(Mensaje::ImpresoraLista { estacion }),
+        Estado::PocoPapel => t(Mensaje::ImpresoraPocoPapel { estacion }),
+        Estado::SinPapel => t(Mensaje::ImpresoraSinPapel { estacion }),
+        Estado::TapaAbierta => t(Mensaje::ImpresoraTapaAbierta { estacion }),
+        Estado::Error => t(Mensaje::ImpresoraConError { estacion }),
+        Estado::Desconectada => t(Mensaje::ImpresoraDesconectada { estacion, detalle }),
+        Estado::SinRespuesta => t(Mensaje::ImpresoraSinRespuesta { estacion }),
+    }
+}
+
+// ─── Monitor ──────────────────────────────────────────────────────────────────
+
+/// Loop en segundo plano. Relee la configuración en cada vuelta, igual que
+/// la sincronización: una estación nueva se vigila sin reiniciar.
+pub async fn iniciar_monitor_impresoras(app: tauri::AppHandle) {
+    // Al arrancar se asume que todas andan: solo se avisa si algo falla
+    let mut ultimos: HashMap<String, Estado> = HashMap::new();
+
+    loop {
+        let config = impresion::load_config(&app);
+        if config.monitoreo_segundos == 0 {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            continue;
+        }
+        tokio::time::sleep(Duration::from_secs(config.monitoreo_segundos)).await;
+
+        // Todas a la vez: una estación que no responde no demora a las demás
+        let consultas: Vec<_> = config
+            .impresoras()
+            .map(|impresora| {
+                let impresora = impresora.clone();
+                tauri::async_runtime::spawn_blocking(move || consultar(&impresora))
+            })
+            .collect();
+
+        for consulta in consultas {
+            let resultado = match consulta.await {
+                Ok(r) => r,
+                Err(e) => {
+                    warn!("[Impresora/Estado] Falló la consulta: {}", e);
+                    continue;
+                }
+            };
+            // Cola suspendida: no se sabe nada nuevo
+            let Ok(actual) = resultado else {
+                continue;
+            };
+
+            let clave = actual.estacion.to_lowercase();
+            let anterior = ultimos.get(&clave).copied().unwrap_or(Estado::Lista);
+            if anterior != actual.estado {
+                info!(
+                    "[Impresora/Estado] {}: {:?} -> {:?}",
+                    actual.estacion, anterior, actual.estado
+                );
+                // Si no llegó el aviso, se reintenta en la próxima vuelta
+                if notificar(&actual).await {
+                    ultimos.insert(clave, actual.estado);
+                }
+            }
+        }
+    }
+}
+
+/// Avisa a las terminales a través del backend. Al mejor esfuerzo: si el
+/// backend todavía no arrancó o no hay cliente HTTP, devuelve false.
+async fn notificar(estado: &EstadoImpresora) -> bool {
+    let url = format!("{}/api/sistema/impresoras", BACKEND_LOCAL);
+    let cliente = match cliente_http() {
+        Ok(cliente) => cliente,
+        Err(e) => {
+            warn!("[Impresora/Estado] {}", e);
+            return false;
+        }
+    };
+    match cliente.post(&url).timeout(Duration::from_secs(5)).json(estado).send().await.and_then(|r| r.error_for_status()) {
+        Ok(_) => true,
+        Err(e) => {
+            warn!("[Impresora/Estado] No se pudo avisar a las terminales: {}", e);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Bytes fijos de toda respuesta DLE EOT: bits 1 y 4 en 1
+    const BASE: u8 = 0x12;
+
+    #[test]
+    fn impresora_sin_novedades_esta_lista() {
+        assert_eq!(decodificar(BASE, BASE), Estado::Lista);
+    }
+
+    #[test]
+    fn sensor_de_fin_de_rollo_avisa_poco_papel() {
+        // DLE EOT 4, bits 2 y 3: rollo por terminarse
+        assert_eq!(decodificar(BASE, BASE | 0x0C), Estado::PocoPapel);
+    }
+
+    #[test]
+    fn sin_papel_por_cualquiera_de_las_dos_respuestas() {
+        // DLE EOT 4, bits 5 y 6: no queda papel
+        assert_eq!(decodificar(BASE, BASE | 0x60), Estado::SinPapel);
+        // DLE EOT 2, bit 5: se detuvo por fin de papel
+        assert_eq!(decodificar(BASE | 0x20, BASE), Estado::SinPapel);
+        // Sin papel pesa más que poco papel
+        assert_eq!(decodificar(BASE, BASE | 0x6C), Estado::SinPapel);
+    }
+
+    #[test]
+    fn tapa_abierta_pesa_mas_que_el_resto() {
+        // DLE EOT 2, bit 2: tapa abierta
+        assert_eq!(decodificar(BASE | 0x04, BASE), Estado::TapaAbierta);
+        assert_eq!(decodificar(BASE | 0x64, BASE | 0x6C), Estado::TapaAbierta);
+    }
+
+    #[test]
+    fn error_de_la_impresora() {
+        // DLE EOT 2, bit 6: error (cuchilla, cabezal)
+        assert_eq!(decodificar(BASE | 0x40, BASE), Estado::Error);
+        assert_eq!(decodificar(BASE | 0x40, BASE | 0x0C), Estado::Error);
+    }
+
+    #[test]
+    fn bytes_fuera_de_formato_son_sin_respuesta() {
+        assert_eq!(decodificar(0x00, BASE), Estado::SinRespuesta);
+        assert_eq!(decodificar(BASE, 0xFF), Estado::SinRespuesta);
+        // Bit 7 en 1: no es una respuesta de estado
+        assert_eq!(decodificar(BASE | 0x80, BASE), Estado::SinRespuesta);
+        // Falta el bit 4
+        assert_eq!(decodificar(0x02, BASE), Estado::SinRespuesta);
+    }
+}
@@ -0,0 +1,599 @@
+//! HU-154: Textos y formatos de la capa Rust según el idioma de la instalación.
+//!
+//! Todo mensaje que llega al operador (respuestas de comandos, errores de
+//! impresora y de sincronización) sale de [`Mensaje`], con un texto por
+//! idioma. Los logs siguen en castellano: son para diagnóstico, no para el
+//! operador.
+//!
+//! El idioma por defecto es es-AR. Se cambia desde `locale_config.json` en el
+//! directorio de datos de la app (igual que la impresora y la sync), así la
+//! misma build sirve a una instalación en Brasil sin tocar código.
+//!
+//! Los importes llevan el símbolo de moneda del idioma ("$ 1.234,50" en
+//! es-AR, "R$ 1.234,50" en pt-BR), con punto de miles y coma decimal en los
+//! dos. Las fechas van día/mes/año, que es el orden de los dos idiomas.
+
+use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::RwLock;
+use tauri::Manager;
+
+// ─── Idioma ───────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    #[serde(rename = "es-AR")]
+    EsAr,
+    #[serde(rename = "pt-BR")]
+    PtBr,
+}
+
+impl Locale {
+    pub fn etiqueta(self) -> &'static str {
+        match self {
+            Locale::EsAr => "es-AR",
+            Locale::PtBr => "pt-BR",
+        }
+    }
+
+    fn formato(self) -> Formato {
+        match self {
+            Locale::EsAr => Formato { simbolo_moneda: "$", separador_miles: '.', separador_decimal: ',' },
+            Locale::PtBr => Formato { simbolo_moneda: "R$", separador_miles: '.', separador_decimal: ',' },
+        }
+    }
+}
+
+/// Convenciones numéricas de cada idioma.
+struct Formato {
+    simbolo_moneda: &'static str,
+    separador_miles: char,
+    separador_decimal: char,
+}
+
+/// Idioma vigente. Se carga al arrancar y cambia con `configurar_idioma`.
+static LOCALE: RwLock<Locale> = RwLock::new(Locale::EsAr);
+
+pub fn locale_actual() -> Locale {
+    LOCALE.read().map(|l| *l).unwrap_or_default()
+}
+
+fn establecer_locale(locale: Locale) {
+    if let Ok(mut actual) = LOCALE.write() {
+        *actual = locale;
+    }
+}
+
+// ─── Configuración ────────────────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct LocaleConfig {
+    #[serde(default)]
+    pub locale: Locale,
+}
+
+/// Lo que ve el frontend: el idioma y cómo quedan los formatos con él.
+#[derive(Debug, Serialize, Clone)]
+pub struct InfoLocale {
+    pub locale: Locale,
+    pub disponibles: Vec<Locale>,
+    pub ejemplo_numero: String,
+    pub ejemplo_importe: String,
+    pub ejemplo_fecha: String,
+}
+
+impl InfoLocale {
+    pub fn actual() -> Self {
+        Self {
+            locale: locale_actual(),
+            disponibles: vec![Locale::EsAr, Locale::PtBr],
+            ejemplo_numero: numero(locale_actual(), 1_234_567),
+            ejemplo_importe: formatear_importe(1234.5),
+            ejemplo_fecha: formatear_fecha_hora_iso("2026-03-09T21:30:00").unwrap_or_default(),
+        }
+    }
+}
+
+fn config_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("locale_config.json"))
+        .map_err(|e| t(Mensaje::DirectorioDatosNoResuelto { detalle: &e.to_string() }))
+}
+
+/// Lee el idioma persistido y lo deja vigente. Sin archivo queda es-AR.
+pub fn cargar(app: &tauri::AppHandle) -> Locale {
+    let locale = match config_path(app).and_then(|path| {
+        debug!("[I18n] Leyendo config de: {:?}", path);
+        std::fs::read_to_string(&path).map_err(|e| format!("{:?} ({})", path, e))
+    }) {
+        Ok(json) => serde_json::from_str::<LocaleConfig>(&json)
+            .map(|config| config.locale)
+            .unwrap_or_else(|e| {
+                error!("[I18n] Config corrupta: {}. Usando es-AR.", e);
+                Locale::default()
+            }),
+        Err(e) => {
+            info!("[I18n] No existe config en {}. Usando es-AR.", e);
+            Locale::default()
+        }
+    };
+    establecer_locale(locale);
+    info!("[I18n] Idioma: {}", locale.etiqueta());
+    locale
+}
+
+pub fn guardar(app: &tauri::AppHandle, locale: Locale) -> Result<(), String> {
+    let path = config_path(app)?;
+    info!("[I18n] Guardando config en {:?}: locale={}", path, locale.etiqueta());
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| {
+            t(Mensaje::DirectorioNoCreado { ruta: &format!("{:?}", parent), detalle: &e.to_string() })
+        })?;
+    }
+
+    let json = serde_json::to_string_pretty(&LocaleConfig { locale })
+        .map_err(|e| t(Mensaje::ConfigNoSerializada { detalle: &e.to_string() }))?;
+
+    std::fs::write(&path, &json).map_err(|e| {
+        let msg = t(Mensaje::ConfigNoEscrita { ruta: &format!("{:?}", path), detalle: &e.to_string() });
+        warn!("[I18n] {}", msg);
+        msg
+    })?;
+
+    establecer_locale(locale);
+    Ok(())
+}
+
+// ─── Formatos ─────────────────────────────────────────────────────────────────
+
+/// Importe a dos decimales con el símbolo del idioma vigente: 1234.5 → "$ 1.234,50".
+pub fn formatear_importe(monto: f64) -> String {
+    importe(locale_actual(), monto)
+}
+
+/// Entero con separador de miles: 12345 → "12.345", -1500 → "-1.500".
+fn numero(locale: Locale, valor: i64) -> String {
+    let signo = if valor < 0 { "-" } else { "" };
+    format!("{}{}", signo, agrupar_miles(&valor.unsigned_abs().to_string(), locale.formato().separador_miles))
+}
+
+fn importe(locale: Locale, monto: f64) -> String {
+    let formato = locale.formato();
+    let centavos = (monto * 100.0).round() as i64;
+    let signo = if centavos < 0 { "-" } else { "" };
+    let centavos = centavos.unsigned_abs();
+    format!(
+        "{}{} {}{}{:02}",
+        signo,
+        formato.simbolo_moneda,
+        agrupar_miles(&(centavos / 100).to_string(), formato.separador_miles),
+        formato.separador_decimal,
+        centavos % 100
+    )
+}
+
+/// Fecha y hora ISO 8601 del backend ("2026-03-09T21:30:00.123") en formato
+/// local, a los minutos: "09/03/2026 21:30". None si no es una fecha ISO.
+pub fn formatear_fecha_hora_iso(iso: &str) -> Option<String> {
+    let (fecha, hora) = iso.split_once('T')?;
+    let mut partes = fecha.splitn(3, '-');
+    let (anio, mes, dia) = (partes.next()?, partes.next()?, partes.next()?);
+    let hora = hora.get(0..5)?;
+    let valido = anio.len() == 4
+        && mes.len() == 2
+        && dia.len() == 2
+        && [anio, mes, dia].iter().all(|p| p.chars().all(|c| c.is_ascii_digit()));
+    if !valido {
+        return None;
+    }
+    Some(format!("{}/{}/{} {}", dia, mes, anio, hora))
+}
+
+fn agrupar_miles(digitos: &str, separador: char) -> String {
+    let mut agrupado = String::with_capacity(digitos.len() + digitos.len() / 3);
+    for (i, c) in digitos.chars().enumerate() {
+        if i > 0 && (digitos.len() - i) % 3 == 0 {
+            agrupado.push(separador);
+        }
+        agrupado.push(c);
+    }
+    agrupado
+}
+
+// ─── Catálogo de mensajes ─────────────────────────────────────────────────────
+
+/// Mensajes de la capa Rust que llegan al operador.
+///
+/// Los detalles técnicos (errores del SO, de red) van tal cual los da la
+/// librería: no se traducen.
+pub enum Mensaje<'a> {
+    // Configuración
+    DirectorioDatosNoResuelto { detalle: &'a str },
+    DirectorioLogsNoResuelto { detalle: &'a str },
+    DirectorioNoCreado { ruta: &'a str, detalle: &'a str },
+    ConfigNoSerializada { detalle: &'a str },
+    ConfigNoLeida { ruta: &'a str, detalle: &'a str },
+    ConfigNoEscrita { ruta: &'a str, detalle: &'a str },
+    ConfigImpresoraGuardada,
+    ConfigSyncGuardada,
+    IdiomaGuardado,
+
+    // Impresora
+    PayloadVacio,
+    ConexionNoSoportada { tipo: &'a str },
+    UsbInexistente { ruta: &'a str },
+    UsbNoAbierto { ruta: &'a str, detalle: &'a str },
+    UsbEscrituraFallida { ruta: &'a str, detalle: &'a str },
+    FlushFallido { destino: &'a str, detalle: &'a str },
+    ImpresoUsb { ruta: &'a str, bytes: usize },
+    DireccionInvalida { direccion: &'a str, detalle: &'a str },
+    TcpSinConexion { direccion: &'a str, detalle: &'a str, host: &'a str, puerto: u16 },
+    TcpEscrituraFallida { direccion: &'a str, detalle: &'a str },
+    ImpresoTcp { direccion: &'a str, bytes: usize },
+    ImpresionSuspendida,
+    ImpresionEnMantenimiento,
+    EstacionInexistente { estacion: &'a str },
+
+    // Estado de impresoras
+    ImpresoraLista { estacion: &'a str },
+    ImpresoraPocoPapel { estacion: &'a str },
+    ImpresoraSinPapel { estacion: &'a str },
+    ImpresoraTapaAbierta { estacion: &'a str },
+    ImpresoraConError { estacion: &'a str },
+    ImpresoraDesconectada { estacion: &'a str, detalle: Option<&'a str> },
+    ImpresoraSinRespuesta { estacion: &'a str },
+
+    // Sincronización
+    SinUrlConsolidado,
+    ClienteHttpNoCreado { detalle: &'a str },
+    BackendLocalSinRespuesta { detalle: &'a str },
+    BackendLocalRespuestaInvalida { detalle: &'a str },
+    ConsolidadoSinConexion { detalle: &'a str },
+    ConsolidadoRechazo { estado: &'a str, detalle: Option<&'a str> },
+    ConsolidadoRespuestaInvalida { detalle: &'a str },
+    SyncSinVentasNuevas,
+    SyncVentasEnviadas { cantidad: usize, ultimo_cierre: Option<&'a str> },
+
+    // Mantenimiento de la base
+    MantenimientoPospuesto { detalle: &'a str },
+    HistorialMantenimientoNoGuardado { ruta: &'a str, detalle: &'a str },
+
+    // Portapapeles
+    CopiadoAlPortapapeles,
+    PortapapelesNoDisponible { detalle: &'a str },
+    PortapapelesVacio,
+    SinNumeroOperacion,
+
+    // Reportes como imagen
+    FechaInvalida { fecha: &'a str },
+    ImagenReporteRechazada { detalle: &'a str },
+    ImagenReporteCopiada { ruta: &'a str },
+    ImagenReporteGuardada { ruta: &'a str },
+
+    // Permisos por acción
+    EmpleadoNoIdentificado { detalle: &'a str },
+    PermisoDenegado { detalle: &'a str },
+    CajonAbierto,
+}
+
+/// Texto del mensaje en el idioma vigente.
+pub fn t(mensaje: Mensaje) -> String {
+    mensaje.texto(locale_actual())
+}
+
+impl Mensaje<'_> {
+    pub fn texto(&self, locale: Locale) -> String {
+        use Locale::*;
+        use Mensaje::*;
+
+        match (self, locale) {
+            (DirectorioDatosNoResuelto { detalle }, EsAr) => format!("No se pudo obtener directorio de datos: {}", detalle),
+            (DirectorioDatosNoResuelto { detalle }, PtBr) => format!("Não foi possível obter o diretório de dados: {}", detalle),
+
+            (DirectorioLogsNoResuelto { detalle }, EsAr) => format!("No se pudo resolver directorio de logs: {}", detalle),
+            (DirectorioLogsNoResuelto { detalle }, PtBr) => format!("Não foi possível localizar o diretório de logs: {}", detalle),
+
+            (DirectorioNoCreado { ruta, detalle }, EsAr) => format!("No se pudo crear directorio {}: {}. Permisos del FS?", ruta, detalle),
+            (DirectorioNoCreado { ruta, detalle }, PtBr) => format!("Não foi possível criar o diretório {}: {}. Permissões do sistema de arquivos?", ruta, detalle),
+
+            (ConfigNoSerializada { detalle }, EsAr) => format!("Error al serializar configuración: {}", detalle),
+            (ConfigNoSerializada { detalle }, PtBr) => format!("Erro ao serializar a configuração: {}", detalle),
+
+            (ConfigNoLeida { ruta, detalle }, EsAr) => format!("No se pudo leer {}: {}", ruta, detalle),
+            (ConfigNoLeida { ruta, detalle }, PtBr) => format!("Não foi possível ler {}: {}", ruta, detalle),
+
+            (ConfigNoEscrita { ruta, detalle }, EsAr) => format!("No se pudo escribir en {}: {}", ruta, detalle),
+            (ConfigNoEscrita { ruta, detalle }, PtBr) => format!("Não foi possível gravar em {}: {}", ruta, detalle),
+
+            (ConfigImpresoraGuardada, EsAr) => "Configuración de impresora guardada".into(),
+            (ConfigImpresoraGuardada, PtBr) => "Configuração da impressora salva".into(),
+
+            (ConfigSyncGuardada, EsAr) => "Configuración de sincronización guardada. El nombre de sucursal se aplica al reiniciar.".into(),
+            (ConfigSyncGuardada, PtBr) => "Configuração de sincronização salva. O nome da filial é aplicado ao reiniciar.".into(),
+
+            (IdiomaGuardado, EsAr) => "Idioma guardado".into(),
+            (IdiomaGuardado, PtBr) => "Idioma salvo".into(),
+
+            (PayloadVacio, EsAr) => "Payload ESC/POS vacío — nada que imprimir".into(),
+            (PayloadVacio, PtBr) => "Payload ESC/POS vazio — nada para imprimir".into(),
+
+            (ConexionNoSoportada { tipo }, EsAr) => format!("Tipo de conexión no soportado: '{}'. Usá 'usb' o 'tcp'.", tipo),
+            (ConexionNoSoportada { tipo }, PtBr) => format!("Tipo de conexão não suportado: '{}'. Use 'usb' ou 'tcp'.", tipo),
+
+            (UsbInexistente { ruta }, EsAr) => format!(
+                "El dispositivo '{}' no existe. Posibles causas:\n\
+                 - La impresora no está conectada por USB\n\
+                 - El driver no creó el device node\n\
+                 - La ruta configurada es incorrecta\n\
+                 Verificar con: ls -la /dev/usb/",
+                ruta
+            ),
+            (UsbInexistente { ruta }, PtBr) => format!(
+                "O dispositivo '{}' não existe. Possíveis causas:\n\
+                 - A impressora não está conectada por USB\n\
+                 - O driver não criou o device node\n\
+                 - O caminho configurado está incorreto\n\
+                 Verifique com: ls -la /dev/usb/",
+                ruta
+            ),
+
+            (UsbNoAbierto { ruta, detalle }, EsAr) => format!(
+                "No se pudo abrir '{}': {}. Posibles causas:\n\
+                 - Sin permisos (ejecutar: sudo usermod -a -G lp $USER y reiniciar sesión)\n\
+                 - Dispositivo ocupado por otro proceso\n\
+                 - Driver USB no cargado",
+                ruta, detalle
+            ),
+            (UsbNoAbierto { ruta, detalle }, PtBr) => format!(
+                "Não foi possível abrir '{}': {}. Possíveis causas:\n\
+                 - Sem permissão (execute: sudo usermod -a -G lp $USER e reinicie a sessão)\n\
+                 - Dispositivo ocupado por outro processo\n\
+                 - Driver USB não carregado",
+                ruta, detalle
+            ),
+
+            (UsbEscrituraFallida { ruta, detalle }, EsAr) => format!(
+                "Error escribiendo en '{}': {}. La impresora puede haberse desconectado durante la escritura.",
+                ruta, detalle
+            ),
+            (UsbEscrituraFallida { ruta, detalle }, PtBr) => format!(
+                "Erro ao gravar em '{}': {}. A impressora pode ter sido desconectada durante a gravação.",
+                ruta, detalle
+            ),
+
+            (FlushFallido { destino, detalle }, EsAr) => format!(
+                "Error en flush '{}': {}. Datos parcialmente enviados — verificar impresión.",
+                destino, detalle
+            ),
+            (FlushFallido { destino, detalle }, PtBr) => format!(
+                "Erro no flush '{}': {}. Dados enviados parcialmente — verifique a impressão.",
+                destino, detalle
+            ),
+
+            (ImpresoUsb { ruta, bytes }, EsAr) => format!("Impreso vía USB [{}] ({} bytes)", ruta, numero(locale, *bytes as i64)),
+            (ImpresoUsb { ruta, bytes }, PtBr) => format!("Impresso via USB [{}] ({} bytes)", ruta, numero(locale, *bytes as i64)),
+
+            (DireccionInvalida { direccion, detalle }, EsAr) => format!(
+                "Dirección inválida '{}': {}. Verificá host y puerto en la configuración.",
+                direccion, detalle
+            ),
+            (DireccionInvalida { direccion, detalle }, PtBr) => format!(
+                "Endereço inválido '{}': {}. Verifique host e porta na configuração.",
+                direccion, detalle
+            ),
+
+            (TcpSinConexion { direccion, detalle, host, puerto }, EsAr) => format!(
+                "No se pudo conectar a '{}': {}. Posibles causas:\n\
+                 - Impresora apagada o desconectada de la red\n\
+                 - IP incorrecta (verificar con ping {})\n\
+                 - Puerto {} bloqueado por firewall\n\
+                 - Impresora en otra subred",
+                direccion, detalle, host, puerto
+            ),
+            (TcpSinConexion { direccion, detalle, host, puerto }, PtBr) => format!(
+                "Não foi possível conectar a '{}': {}. Possíveis causas:\n\
+                 - Impressora desligada ou desconectada da rede\n\
+                 - IP incorreto (verifique com ping {})\n\
+                 - Porta {} bloqueada pelo firewall\n\
+                 - Impressora em outra sub-rede",
+                direccion, detalle, host, puerto
+            ),
+
+            (TcpEscrituraFallida { direccion, detalle }, EsAr) => format!(
+                "Error enviando datos a '{}': {}. Conexión interrumpida durante la escritura.",
+                direccion, detalle
+            ),
+            (TcpEscrituraFallida { direccion, detalle }, PtBr) => format!(
+                "Erro ao enviar dados para '{}': {}. Conexão interrompida durante a gravação.",
+                direccion, detalle
+            ),
+
+            (ImpresoTcp { direccion, bytes }, EsAr) => format!("Impreso vía TCP {} ({} bytes)", direccion, numero(locale, *bytes as i64)),
+            (ImpresoTcp { direccion, bytes }, PtBr) => format!("Impresso via TCP {} ({} bytes)", direccion, numero(locale, *bytes as i64)),
+
+            (ImpresionSuspendida, EsAr) => "Impresión suspendida por corte de luz. Se reanuda cuando vuelva la luz.".into(),
+            (ImpresionSuspendida, PtBr) => "Impressão suspensa por queda de energia. Será retomada quando a energia voltar.".into(),
+            (ImpresionEnMantenimiento, EsAr) => "Impresión en pausa por el mantenimiento de la base. Se reanuda en unos minutos.".into(),
+            (ImpresionEnMantenimiento, PtBr) => "Impressão pausada pela manutenção do banco de dados. Será retomada em alguns minutos.".into(),
+
+            (EstacionInexistente { estacion }, EsAr) => format!("No hay impresora configurada para la estación '{}'", estacion),
+            (EstacionInexistente { estacion }, PtBr) => format!("Não há impressora configurada para a estação '{}'", estacion),
+
+            (ImpresoraLista { estacion }, EsAr) => format!("Impresora de {} lista", estacion),
+            (ImpresoraLista { estacion }, PtBr) => format!("Impressora de {} pronta", estacion),
+
+            (ImpresoraPocoPapel { estacion }, EsAr) => format!("A la impresora de {} le queda poco papel", estacion),
+            (ImpresoraPocoPapel { estacion }, PtBr) => format!("A impressora de {} está com pouco papel", estacion),
+
+            (ImpresoraSinPapel { estacion }, EsAr) => {
+                format!("La impresora de {} se quedó sin papel. Los tickets no van a salir hasta cambiar el rollo.", estacion)
+            }
+            (ImpresoraSinPapel { estacion }, PtBr) => {
+                format!("A impressora de {} ficou sem papel. Os tickets não vão sair até trocar a bobina.", estacion)
+            }
+
+            (ImpresoraTapaAbierta { estacion }, EsAr) => format!("La impresora de {} tiene la tapa abierta", estacion),
+            (ImpresoraTapaAbierta { estacion }, PtBr) => format!("A impressora de {} está com a tampa aberta", estacion),
+
+            (ImpresoraConError { estacion }, EsAr) => {
+                format!("La impresora de {} está en error (papel trabado o cortador). Apagala y volvé a prenderla.", estacion)
+            }
+            (ImpresoraConError { estacion }, PtBr) => {
+                format!("A impressora de {} está com erro (papel preso ou guilhotina). Desligue e ligue novamente.", estacion)
+            }
+
+            (ImpresoraDesconectada { estacion, detalle }, EsAr) => format!(
+                "La impresora de {} está desconectada o apagada{}",
+                estacion,
+                detalle.map(|d| format!(" ({})", d)).unwrap_or_default()
+            ),
+            (ImpresoraDesconectada { estacion, detalle }, PtBr) => format!(
+                "A impressora de {} está desconectada ou desligada{}",
+                estacion,
+                detalle.map(|d| format!(" ({})", d)).unwrap_or_default()
+            ),
+
+            (ImpresoraSinRespuesta { estacion }, EsAr) => format!("La impresora de {} no responde la consulta de estado", estacion),
+            (ImpresoraSinRespuesta { estacion }, PtBr) => format!("A impressora de {} não responde à consulta de status", estacion),
+
+            (SinUrlConsolidado, EsAr) => "No hay URL del consolidado configurada".into(),
+            (SinUrlConsolidado, PtBr) => "Não há URL do consolidado configurada".into(),
+
+            (ClienteHttpNoCreado { detalle }, EsAr) => format!("No se pudo crear el cliente HTTP: {}", detalle),
+            (ClienteHttpNoCreado { detalle }, PtBr) => format!("Não foi possível criar o cliente HTTP: {}", detalle),
+
+            (BackendLocalSinRespuesta { detalle }, EsAr) => format!("El backend local no respondió: {}", detalle),
+            (BackendLocalSinRespuesta { detalle }, PtBr) => format!("O backend local não respondeu: {}", detalle),
+
+            (BackendLocalRespuestaInvalida { detalle }, EsAr) => format!("Respuesta inválida del backend local: {}", detalle),
+            (BackendLocalRespuestaInvalida { detalle }, PtBr) => format!("Resposta inválida do backend local: {}", detalle),
+
+            (ConsolidadoSinConexion { detalle }, EsAr) => format!("No se pudo conectar con el consolidado: {}", detalle),
+            (ConsolidadoSinConexion { detalle }, PtBr) => format!("Não foi possível conectar ao consolidado: {}", detalle),
+
+            (ConsolidadoRechazo { estado, detalle }, EsAr) => {
+                format!("El consolidado rechazó el lote ({}): {}", estado, detalle.unwrap_or("sin detalle"))
+            }
+            (ConsolidadoRechazo { estado, detalle }, PtBr) => {
+                format!("O consolidado rejeitou o lote ({}): {}", estado, detalle.unwrap_or("sem detalhes"))
+            }
+
+            (ConsolidadoRespuestaInvalida { detalle }, EsAr) => format!("Respuesta inválida del consolidado: {}", detalle),
+            (ConsolidadoRespuestaInvalida { detalle }, PtBr) => format!("Resposta inválida do consolidado: {}", detalle),
+
+            (SyncSinVentasNuevas, EsAr) => "Sin ventas nuevas para enviar".into(),
+            (SyncSinVentasNuevas, PtBr) => "Sem vendas novas para enviar".into(),
+
+            (SyncVentasEnviadas { cantidad, ultimo_cierre }, EsAr) => match ultimo_cierre.and_then(formatear_fecha_hora_iso) {
+                Some(fecha) => format!("{} ventas enviadas al consolidado (último cierre: {})", numero(locale, *cantidad as i64), fecha),
+                None => format!("{} ventas enviadas al consolidado", numero(locale, *cantidad as i64)),
+            },
+            (SyncVentasEnviadas { cantidad, ultimo_cierre }, PtBr) => match ultimo_cierre.and_then(formatear_fecha_hora_iso) {
+                Some(fecha) => format!("{} vendas enviadas ao consolidado (último fechamento: {})", numero(locale, *cantidad as i64), fecha),
+                None => format!("{} vendas enviadas ao consolidado", numero(locale, *cantidad as i64)),
+            },
+
+            (MantenimientoPospuesto { detalle }, EsAr) => format!("El backend pospuso el mantenimiento: {}", detalle),
+            (MantenimientoPospuesto { detalle }, PtBr) => format!("O backend adiou a manutenção: {}", detalle),
+
+            (HistorialMantenimientoNoGuardado { ruta, detalle }, EsAr) => {
+                format!("No se pudo guardar el historial de mantenimiento en {}: {}", ruta, detalle)
+            }
+            (HistorialMantenimientoNoGuardado { ruta, detalle }, PtBr) => {
+                format!("Não foi possível salvar o histórico de manutenção em {}: {}", ruta, detalle)
+            }
+
+            (CopiadoAlPortapapeles, EsAr) => "Copiado".into(),
+            (CopiadoAlPortapapeles, PtBr) => "Copiado".into(),
+
+            (PortapapelesNoDisponible { detalle }, EsAr) => format!("No se pudo usar el portapapeles: {}", detalle),
+            (PortapapelesNoDisponible { detalle }, PtBr) => format!("Não foi possível usar a área de transferência: {}", detalle),
+
+            (PortapapelesVacio, EsAr) => "No hay nada copiado para pegar".into(),
+            (PortapapelesVacio, PtBr) => "Não há nada copiado para colar".into(),
+
+            (SinNumeroOperacion, EsAr) => "Lo copiado no tiene un número de operación o cupón".into(),
+            (SinNumeroOperacion, PtBr) => "O texto copiado não tem um número de operação ou comprovante".into(),
+
+            (FechaInvalida { fecha }, EsAr) => format!("Fecha inválida: '{}'. Tiene que ser AAAA-MM-DD.", fecha),
+            (FechaInvalida { fecha }, PtBr) => format!("Data inválida: '{}'. Deve ser AAAA-MM-DD.", fecha),
+
+            (ImagenReporteRechazada { detalle }, EsAr) => format!("No se pudo generar la imagen: {}", detalle),
+            (ImagenReporteRechazada { detalle }, PtBr) => format!("Não foi possível gerar a imagem: {}", detalle),
+
+            (ImagenReporteCopiada { ruta }, EsAr) => format!("Imagen copiada, lista para pegar en WhatsApp. También quedó en {}", ruta),
+            (ImagenReporteCopiada { ruta }, PtBr) => format!("Imagem copiada, pronta para colar no WhatsApp. Também foi salva em {}", ruta),
+
+            (ImagenReporteGuardada { ruta }, EsAr) => format!("Imagen guardada en {}", ruta),
+            (ImagenReporteGuardada { ruta }, PtBr) => format!("Imagem salva em {}", ruta),
+
+            (EmpleadoNoIdentificado { detalle }, EsAr) => format!("No se pudo identificar al empleado: {}", detalle),
+            (EmpleadoNoIdentificado { detalle }, PtBr) => format!("Não foi possível identificar o funcionário: {}", detalle),
+
+            (PermisoDenegado { detalle }, EsAr) => format!("Acción no permitida: {}", detalle),
+            (PermisoDenegado { detalle }, PtBr) => format!("Ação não permitida: {}", detalle),
+
+            (CajonAbierto, EsAr) => "Cajón abierto".into(),
+            (CajonAbierto, PtBr) => "Gaveta aberta".into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn agrupa_los_miles_desde_la_derecha() {
+        assert_eq!(agrupar_miles("0", '.'), "0");
+        assert_eq!(agrupar_miles("999", '.'), "999");
+        assert_eq!(agrupar_miles("1000", '.'), "1.000");
+        assert_eq!(agrupar_miles("123456", '.'), "123.456");
+        assert_eq!(agrupar_miles("1234567", '.'), "1.234.567");
+    }
+
+    #[test]
+    fn formatea_numeros_con_punto_de_miles_en_los_dos_idiomas() {
+        for locale in [Locale::EsAr, Locale::PtBr] {
+            assert_eq!(numero(locale, 0), "0");
+            assert_eq!(numero(locale, 12_345), "12.345");
+            assert_eq!(numero(locale, -1_500), "-1.500");
+            assert_eq!(numero(locale, -7), "-7");
+            assert_eq!(numero(locale, i64::MIN), "-9.223.372.036.854.775.808");
+        }
+    }
+
+    #[test]
+    fn formatea_importes_en_pesos_en_es_ar() {
+        assert_eq!(importe(Locale::EsAr, 1234.5), "$ 1.234,50");
+        assert_eq!(importe(Locale::EsAr, 0.0), "$ 0,00");
+        assert_eq!(importe(Locale::EsAr, 1_000_000.999), "$ 1.000.001,00");
+        assert_eq!(importe(Locale::EsAr, -45.1), "-$ 45,10");
+    }
+
+    #[test]
+    fn formatea_importes_en_reales_en_pt_br() {
+        assert_eq!(importe(Locale::PtBr, 1234.5), "R$ 1.234,50");
+        assert_eq!(importe(Locale::PtBr, 0.07), "R$ 0,07");
+        assert_eq!(importe(Locale::PtBr, -45.1), "-R$ 45,10");
+    }
+
+    #[test]
+    fn formatea_la_fecha_iso_del_backend_como_dia_mes_anio() {
+        assert_eq!(formatear_fecha_hora_iso("2026-03-09T21:30:00").as_deref(), Some("09/03/2026 21:30"));
+        assert_eq!(formatear_fecha_hora_iso("2026-03-09T21:30:00.123").as_deref(), Some("09/03/2026 21:30"));
+    }
+
+    #[test]
+    fn descarta_lo_que_no_es_una_fecha_iso() {
+        assert_eq!(formatear_fecha_hora_iso(""), None);
+        assert_eq!(formatear_fecha_hora_iso("2026-03-09"), None);
+        assert_eq!(formatear_fecha_hora_iso("09/03/2026T21:30"), None);
+        assert_eq!(formatear_fecha_hora_iso("2026-3-9T21:30"), None);
+        assert_eq!(formatear_fecha_hora_iso("2026-03-09T21"), None);
+    }
+}
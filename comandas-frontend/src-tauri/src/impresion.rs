@@ -4,9 +4,20 @@
 //! - **USB**: Escritura directa al dispositivo (ej: `/dev/usb/lp0` en Linux)
 //! - **TCP**: Conexión de red al puerto estándar ESC/POS (9100)
 //!
+//! Cada impresora atiende una estación (HU-158): la principal es la de caja
+//! y en `estaciones` se suman otras (cocina, barra) con su propia conexión.
+//! El estado de cada una se vigila en [`crate::estado_impresora`].
+//!
 //! La configuración se persiste en `printer_config.json` dentro del
 //! directorio de datos de la app (gestionado por Tauri).
 //!
+//! Cada impresora recibe un envío a la vez ([`con_impresora`]); una que no
+//! contesta solo demora a su estación. Ante un corte de luz (HU-155) o
+//! durante el mantenimiento de la base (HU-163) la cola se suspende: deja de
+//! aceptar tickets, los que ya estaban esperando turno salen a la impresora
+//! y los que llegan después se rechazan. Cada motivo se levanta por su lado:
+//! la cola vuelve a imprimir cuando no queda ninguno.
+//!
 //! **Logging**: Todas las operaciones se loguean a archivo vía tauri-plugin-log.
 //! Los logs quedan en el directorio de datos de la app para diagnóstico
 //! en producción (la PC del cliente no tiene consola de Tauri).
@@ -14,9 +25,14 @@
 use log::{info, warn, error, debug};
 use serde::{Deserialize, Serialize};
 use std::io::Write;
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, OnceLock};
+use std::time::Duration;
 use tauri::Manager;
 
+use crate::i18n::{t, Mensaje};
+
 // ─── Configuración ────────────────────────────────────────────────────────────
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -36,6 +52,18 @@ pub struct PrinterConfig {
     /// Puerto de la impresora de red (estándar ESC/POS: 9100)
     #[serde(default = "default_tcp_port")]
     pub tcp_port: u16,
+
+    /// Estación que atiende esta impresora (caja, cocina, barra)
+    #[serde(default = "default_estacion")]
+    pub estacion: String,
+
+    /// Impresoras de las otras estaciones. Sus propias `estaciones` se ignoran.
+    #[serde(default)]
+    pub estaciones: Vec<PrinterConfig>,
+
+    /// Cada cuántos segundos se consulta el estado de las impresoras (0: nunca)
+    #[serde(default = "default_monitoreo_segundos")]
+    pub monitoreo_segundos: u64,
 }
 
 fn default_connection_type() -> String {
@@ -50,6 +78,12 @@ fn default_tcp_host() -> String {
 fn default_tcp_port() -> u16 {
     9100
 }
+fn default_estacion() -> String {
+    "caja".into()
+}
+fn default_monitoreo_segundos() -> u64 {
+    30
+}
 
 impl Default for PrinterConfig {
     fn default() -> Self {
@@ -58,17 +92,37 @@ impl Default for PrinterConfig {
             usb_path: default_usb_path(),
             tcp_host: default_tcp_host(),
             tcp_port: default_tcp_port(),
+            estacion: default_estacion(),
+            estaciones: Vec::new(),
+            monitoreo_segundos: default_monitoreo_segundos(),
         }
     }
 }
 
+impl PrinterConfig {
+    /// La impresora principal y las de las otras estaciones.
+    pub fn impresoras(&self) -> impl Iterator<Item = &PrinterConfig> {
+        std::iter::once(self).chain(self.estaciones.iter())
+    }
+
+    /// Impresora de la estación, sin distinguir mayúsculas. Sin nombre, la principal.
+    pub fn de_estacion(&self, estacion: Option<&str>) -> Result<&PrinterConfig, String> {
+        let Some(nombre) = estacion.map(str::trim).filter(|n| !n.is_empty()) else {
+            return Ok(self);
+        };
+        self.impresoras()
+            .find(|p| p.estacion.eq_ignore_ascii_case(nombre))
+            .ok_or_else(|| t(Mensaje::EstacionInexistente { estacion: nombre }))
+    }
+}
+
 // ─── Persistencia de configuración ────────────────────────────────────────────
 
 fn config_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
     app.path()
         .app_data_dir()
         .map(|dir| dir.join("printer_config.json"))
-        .map_err(|e| format!("No se pudo obtener directorio de datos: {}", e))
+        .map_err(|e| t(Mensaje::DirectorioDatosNoResuelto { detalle: &e.to_string() }))
 }
 
 pub fn load_config(app: &tauri::AppHandle) -> PrinterConfig {
@@ -119,23 +173,23 @@ pub fn save_config(app: &tauri::AppHandle, config: &PrinterConfig) -> Result<(),
 
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent).map_err(|e| {
-            let msg = format!(
-                "No se pudo crear directorio {:?}: {}. Permisos del FS?",
-                parent, e
-            );
+            let msg = t(Mensaje::DirectorioNoCreado {
+                ruta: &format!("{:?}", parent),
+                detalle: &e.to_string(),
+            });
             error!("[Impresora] {}", msg);
             msg
         })?;
     }
 
     let json = serde_json::to_string_pretty(config).map_err(|e| {
-        let msg = format!("Error al serializar configuración: {}", e);
+        let msg = t(Mensaje::ConfigNoSerializada { detalle: &e.to_string() });
         error!("[Impresora] {}", msg);
         msg
     })?;
 
     std::fs::write(&path, &json).map_err(|e| {
-        let msg = format!("No se pudo escribir en {:?}: {}", path, e);
+        let msg = t(Mensaje::ConfigNoEscrita { ruta: &format!("{:?}", path), detalle: &e.to_string() });
         error!("[Impresora] {}", msg);
         msg
     })?;
@@ -144,12 +198,141 @@ pub fn save_config(app: &tauri::AppHandle, config: &PrinterConfig) -> Result<(),
     Ok(())
 }
 
+// ─── Cola de impresión ────────────────────────────────────────────────────────
+
+/// Por qué está suspendida la cola.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Suspension {
+    /// HU-155. Va primero: es lo que el operador tiene que saber.
+    CorteDeLuz,
+    /// HU-163
+    Mantenimiento,
+}
+
+impl Suspension {
+    fn mensaje(self) -> String {
+        match self {
+            Suspension::CorteDeLuz => t(Mensaje::ImpresionSuspendida),
+            Suspension::Mantenimiento => t(Mensaje::ImpresionEnMantenimiento),
+        }
+    }
+}
+
+/// Motivos vigentes de suspensión y cuántos tickets hay imprimiéndose o
+/// esperando turno.
+struct EstadoCola {
+    suspensiones: Vec<Suspension>,
+    pendientes: usize,
+}
+
+impl EstadoCola {
+    fn suspension(&self) -> Option<Suspension> {
+        self.suspensiones.iter().min().copied()
+    }
+}
+
+static COLA: Mutex<EstadoCola> = Mutex::new(EstadoCola { suspensiones: Vec::new(), pendientes: 0 });
+
+/// Avisa cada vez que un ticket sale de la cola.
+static VACIADA: Condvar = Condvar::new();
+
+/// Un candado por impresora, según su destino (ruta USB o `host:puerto`).
+/// Dos estaciones que comparten impresora comparten candado.
+static IMPRESORAS: OnceLock<Mutex<HashMap<String, Arc<Mutex<()>>>>> = OnceLock::new();
+
+/// Cuánto se espera al suspender a que salgan los tickets pendientes.
+const ESPERA_VACIADO: Duration = Duration::from_secs(30);
+
+fn estado_cola() -> MutexGuard<'static, EstadoCola> {
+    // Un envío que entró en pánico no invalida la cola
+    COLA.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn destino(config: &PrinterConfig) -> String {
+    match config.connection_type.as_str() {
+        "usb" => config.usb_path.clone(),
+        "tcp" => format!("{}:{}", config.tcp_host, config.tcp_port),
+        _ => config.estacion.clone(),
+    }
+}
+
+/// Corre `f` con la impresora de `config` tomada. La consulta de estado
+/// también pasa por acá: no se mezcla con un ticket.
+pub(crate) fn con_impresora<T>(config: &PrinterConfig, f: impl FnOnce() -> T) -> T {
+    let candado = {
+        let mut impresoras = IMPRESORAS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        impresoras.entry(destino(config)).or_default().clone()
+    };
+    let _tomada = candado.lock().unwrap_or_else(|e| e.into_inner());
+    f()
+}
+
+/// El motivo que se le muestra al operador si la cola está suspendida.
+pub(crate) fn suspension() -> Option<String> {
+    estado_cola().suspension().map(Suspension::mensaje)
+}
+
+/// Deja de aceptar tickets y espera (hasta [`ESPERA_VACIADO`]) a que se
+/// impriman los que ya estaban en la cola.
+/// Bloquea: llamar desde un hilo que pueda esperar.
+pub fn suspender(motivo: Suspension) {
+    let mut cola = estado_cola();
+    if !cola.suspensiones.contains(&motivo) {
+        cola.suspensiones.push(motivo);
+    }
+    let (cola, espera) = VACIADA
+        .wait_timeout_while(cola, ESPERA_VACIADO, |c| c.pendientes > 0)
+        .unwrap_or_else(|e| e.into_inner());
+    if espera.timed_out() {
+        warn!("[Impresora] Cola suspendida con {} tickets sin imprimir", cola.pendientes);
+    } else {
+        info!("[Impresora] Cola vaciada y suspendida ({:?})", motivo);
+    }
+}
+
+/// Levanta solo `motivo`: si queda otro, la cola sigue suspendida.
+pub fn reanudar(motivo: Suspension) {
+    let mut cola = estado_cola();
+    cola.suspensiones.retain(|m| *m != motivo);
+    match cola.suspension() {
+        None => info!("[Impresora] Cola reanudada"),
+        Some(otro) => info!("[Impresora] Terminó {:?}, la cola sigue suspendida por {:?}", motivo, otro),
+    }
+}
+
+/// Lugar de un ticket en la cola: lo libera aunque el envío entre en pánico.
+struct Turno;
+
+impl Turno {
+    fn tomar() -> Result<Self, String> {
+        let mut cola = estado_cola();
+        if let Some(motivo) = cola.suspension() {
+            return Err(motivo.mensaje());
+        }
+        cola.pendientes += 1;
+        Ok(Turno)
+    }
+}
+
+impl Drop for Turno {
+    fn drop(&mut self) {
+        estado_cola().pendientes -= 1;
+        VACIADA.notify_all();
+    }
+}
+
 // ─── Envío a impresora ────────────────────────────────────────────────────────
 
 pub fn enviar_a_impresora(config: &PrinterConfig, payload: &[u8]) -> Result<String, String> {
+    let _turno = Turno::tomar().inspect_err(|msg| warn!("[Impresora] {}", msg))?;
+
     info!(
-        "[Impresora] Inicio envío: {} bytes, conexión={}, usb_path={}, tcp={}:{}",
+        "[Impresora] Inicio envío: {} bytes, estación={}, conexión={}, usb_path={}, tcp={}:{}",
         payload.len(),
+        config.estacion,
         config.connection_type,
         config.usb_path,
         config.tcp_host,
@@ -157,27 +340,24 @@ pub fn enviar_a_impresora(config: &PrinterConfig, payload: &[u8]) -> Result<Stri
     );
 
     if payload.is_empty() {
-        let msg = "Payload ESC/POS vacío — nada que imprimir";
+        let msg = t(Mensaje::PayloadVacio);
         warn!("[Impresora] {}", msg);
-        return Err(msg.into());
+        return Err(msg);
     }
 
     // Log primeros bytes para diagnóstico de protocolo
     let preview: Vec<String> = payload.iter().take(20).map(|b| format!("{:02X}", b)).collect();
     debug!("[Impresora] Primeros bytes: [{}]", preview.join(" "));
 
-    let resultado = match config.connection_type.as_str() {
+    let resultado = con_impresora(config, || match config.connection_type.as_str() {
         "usb" => enviar_usb(&config.usb_path, payload),
         "tcp" => enviar_tcp(&config.tcp_host, config.tcp_port, payload),
         otro => {
-            let msg = format!(
-                "Tipo de conexión no soportado: '{}'. Usá 'usb' o 'tcp'.",
-                otro
-            );
+            let msg = t(Mensaje::ConexionNoSoportada { tipo: otro });
             error!("[Impresora] {}", msg);
             Err(msg)
         }
-    };
+    });
 
     match &resultado {
         Ok(msg) => info!("[Impresora] Envío exitoso: {}", msg),
@@ -198,14 +378,7 @@ fn enviar_usb(path: &str, payload: &[u8]) -> Result<String, String> {
     // Verificar que el dispositivo existe antes de intentar abrir
     let device_path = std::path::Path::new(path);
     if !device_path.exists() {
-        let msg = format!(
-            "El dispositivo '{}' no existe. Posibles causas:\n\
-             - La impresora no está conectada por USB\n\
-             - El driver no creó el device node\n\
-             - La ruta configurada es incorrecta\n\
-             Verificar con: ls -la /dev/usb/",
-            path
-        );
+        let msg = t(Mensaje::UsbInexistente { ruta: path });
         error!("[Impresora/USB] {}", msg);
         return Err(msg);
     }
@@ -216,13 +389,7 @@ fn enviar_usb(path: &str, payload: &[u8]) -> Result<String, String> {
         .write(true)
         .open(path)
         .map_err(|e| {
-            let msg = format!(
-                "No se pudo abrir '{}': {} (kind: {:?}). Posibles causas:\n\
-                 - Sin permisos (ejecutar: sudo usermod -a -G lp $USER y reiniciar sesión)\n\
-                 - Dispositivo ocupado por otro proceso\n\
-                 - Driver USB no cargado",
-                path, e, e.kind()
-            );
+            let msg = t(Mensaje::UsbNoAbierto { ruta: path, detalle: &detalle_io(&e) });
             error!("[Impresora/USB] {}", msg);
             msg
         })?;
@@ -230,26 +397,18 @@ fn enviar_usb(path: &str, payload: &[u8]) -> Result<String, String> {
     debug!("[Impresora/USB] Dispositivo abierto, escribiendo {} bytes...", payload.len());
 
     file.write_all(payload).map_err(|e| {
-        let msg = format!(
-            "Error escribiendo en '{}': {} (kind: {:?}). \
-             La impresora puede haberse desconectado durante la escritura.",
-            path, e, e.kind()
-        );
+        let msg = t(Mensaje::UsbEscrituraFallida { ruta: path, detalle: &detalle_io(&e) });
         error!("[Impresora/USB] {}", msg);
         msg
     })?;
 
     file.flush().map_err(|e| {
-        let msg = format!(
-            "Error en flush '{}': {} (kind: {:?}). \
-             Datos parcialmente enviados — verificar impresión.",
-            path, e, e.kind()
-        );
+        let msg = t(Mensaje::FlushFallido { destino: path, detalle: &detalle_io(&e) });
         error!("[Impresora/USB] {}", msg);
         msg
     })?;
 
-    let msg = format!("Impreso vía USB [{}] ({} bytes)", path, payload.len());
+    let msg = t(Mensaje::ImpresoUsb { ruta: path, bytes: payload.len() });
     info!("[Impresora/USB] {}", msg);
     Ok(msg)
 }
@@ -260,30 +419,24 @@ fn enviar_usb(path: &str, payload: &[u8]) -> Result<String, String> {
 /// Timeout de conexión: 5 segundos.
 fn enviar_tcp(host: &str, port: u16, payload: &[u8]) -> Result<String, String> {
     use std::net::TcpStream;
-    use std::time::Duration;
 
     let addr = format!("{}:{}", host, port);
     info!("[Impresora/TCP] Conectando a {} (timeout: 5s)...", addr);
 
-    let socket_addr: std::net::SocketAddr = addr.parse().map_err(|e| {
-        let msg = format!(
-            "Dirección inválida '{}': {}. Verificá host y puerto en la configuración.",
-            addr, e
-        );
+    let socket_addr = addr.parse::<std::net::SocketAddr>().map_err(|e| {
+        let msg = t(Mensaje::DireccionInvalida { direccion: &addr, detalle: &e.to_string() });
         error!("[Impresora/TCP] {}", msg);
         msg
     })?;
 
     let mut stream =
         TcpStream::connect_timeout(&socket_addr, Duration::from_secs(5)).map_err(|e| {
-            let msg = format!(
-                "No se pudo conectar a '{}': {} (kind: {:?}). Posibles causas:\n\
-                 - Impresora apagada o desconectada de la red\n\
-                 - IP incorrecta (verificar con ping {})\n\
-                 - Puerto {} bloqueado por firewall\n\
-                 - Impresora en otra subred",
-                addr, e, e.kind(), host, port
-            );
+            let msg = t(Mensaje::TcpSinConexion {
+                direccion: &addr,
+                detalle: &detalle_io(&e),
+                host,
+                puerto: port,
+            });
             error!("[Impresora/TCP] {}", msg);
             msg
         })?;
@@ -291,26 +444,47 @@ fn enviar_tcp(host: &str, port: u16, payload: &[u8]) -> Result<String, String> {
     debug!("[Impresora/TCP] Conexión establecida, enviando {} bytes...", payload.len());
 
     stream.write_all(payload).map_err(|e| {
-        let msg = format!(
-            "Error enviando datos a '{}': {} (kind: {:?}). \
-             Conexión interrumpida durante la escritura.",
-            addr, e, e.kind()
-        );
+        let msg = t(Mensaje::TcpEscrituraFallida { direccion: &addr, detalle: &detalle_io(&e) });
         error!("[Impresora/TCP] {}", msg);
         msg
     })?;
 
     stream.flush().map_err(|e| {
-        let msg = format!(
-            "Error en flush '{}': {} (kind: {:?}). \
-             Datos parcialmente enviados — verificar impresión.",
-            addr, e, e.kind()
-        );
+        let msg = t(Mensaje::FlushFallido { destino: &addr, detalle: &detalle_io(&e) });
         error!("[Impresora/TCP] {}", msg);
         msg
     })?;
 
-    let msg = format!("Impreso vía TCP {} ({} bytes)", addr, payload.len());
+    let msg = t(Mensaje::ImpresoTcp { direccion: &addr, bytes: payload.len() });
     info!("[Impresora/TCP] {}", msg);
     Ok(msg)
 }
+
+/// Error de E/S con su tipo, que ayuda a distinguir permisos de desconexión.
+pub(crate) fn detalle_io(e: &std::io::Error) -> String {
+    format!("{} (kind: {:?})", e, e.kind())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cada_motivo_de_suspension_se_levanta_por_separado() {
+        suspender(Suspension::Mantenimiento);
+        suspender(Suspension::CorteDeLuz);
+        assert_eq!(suspension(), Some(t(Mensaje::ImpresionSuspendida)));
+
+        // Termina el mantenimiento con la luz todavía cortada
+        reanudar(Suspension::Mantenimiento);
+        assert_eq!(Turno::tomar().err(), Some(t(Mensaje::ImpresionSuspendida)));
+
+        reanudar(Suspension::CorteDeLuz);
+        suspender(Suspension::Mantenimiento);
+        assert_eq!(suspension(), Some(t(Mensaje::ImpresionEnMantenimiento)));
+
+        reanudar(Suspension::Mantenimiento);
+        assert_eq!(suspension(), None);
+        assert!(Turno::tomar().is_ok());
+    }
+}
@@ -1,28 +1,70 @@
 use tauri::Manager;
-use std::sync::Mutex;
+use std::sync::{Mutex, OnceLock};
 use log::{info, error, warn, debug};
 
 #[cfg(target_os = "windows")]
 use std::os::windows::process::CommandExt;
 
+mod borradores;
+mod energia;
+mod estado_impresora;
+mod i18n;
 mod impresion;
+mod mantenimiento;
+mod permisos;
+mod portapapeles;
+mod reporte_imagen;
+mod sync;
+
+use i18n::{t, Mensaje};
 
 struct AppState {
     backend_process: Mutex<Option<tokio::process::Child>>,
 }
 
+// ─── Backend local ───────────────────────────────────────────────────────────
+
+/// Backend embebido de esta instalación (mismo puerto que usa el frontend).
+pub(crate) const BACKEND_LOCAL: &str = "http://localhost:8080";
+
+/// Cliente HTTP compartido (un solo pool de conexiones) por los módulos que
+/// hablan con el backend local o con el consolidado. Cada pedido fija su
+/// espera con `.timeout()`.
+pub(crate) fn cliente_http() -> Result<reqwest::Client, String> {
+    static CLIENTE: OnceLock<reqwest::Client> = OnceLock::new();
+    if let Some(cliente) = CLIENTE.get() {
+        return Ok(cliente.clone());
+    }
+    let cliente = reqwest::Client::builder()
+        .build()
+        .map_err(|e| t(Mensaje::ClienteHttpNoCreado { detalle: &e.to_string() }))?;
+    Ok(CLIENTE.get_or_init(|| cliente).clone())
+}
+
 // ─── Comandos Tauri: Impresión térmica ESC/POS ───────────────────────────────
 
 /// Envía bytes ESC/POS a la impresora térmica configurada.
 /// Invocado desde el frontend como: invoke('imprimir_ticket', { payload: [...] })
+///
+/// `estacion` elige la impresora (cocina, barra); sin ella va a la principal.
+///
+/// Corre fuera del hilo principal: si la impresora está ocupada (el monitor
+/// de estado la tiene tomada) la espera no congela la interfaz.
 #[tauri::command]
-fn imprimir_ticket(app: tauri::AppHandle, payload: Vec<u8>) -> Result<String, String> {
+async fn imprimir_ticket(app: tauri::AppHandle, payload: Vec<u8>, estacion: Option<String>) -> Result<String, String> {
     info!(
-        "[Cmd:imprimir_ticket] Recibido payload de {} bytes desde frontend",
-        payload.len()
+        "[Cmd:imprimir_ticket] Recibido payload de {} bytes desde frontend (estación: {:?})",
+        payload.len(),
+        estacion
     );
     let config = impresion::load_config(&app);
-    let resultado = impresion::enviar_a_impresora(&config, &payload);
+    let resultado = async {
+        let impresora = config.de_estacion(estacion.as_deref())?.clone();
+        tauri::async_runtime::spawn_blocking(move || impresion::enviar_a_impresora(&impresora, &payload))
+            .await
+            .map_err(|e| e.to_string())?
+    }
+    .await;
 
     if let Err(ref e) = resultado {
         error!("[Cmd:imprimir_ticket] FALLO: {}", e);
@@ -42,7 +84,7 @@ fn configurar_impresora(
         config.connection_type, config.usb_path, config.tcp_host, config.tcp_port
     );
     impresion::save_config(&app, &config)?;
-    Ok("Configuración de impresora guardada".into())
+    Ok(t(Mensaje::ConfigImpresoraGuardada))
 }
 
 /// Devuelve la configuración actual de la impresora (o defaults si no existe).
@@ -52,6 +94,182 @@ fn obtener_config_impresora(app: tauri::AppHandle) -> impresion::PrinterConfig {
     impresion::load_config(&app)
 }
 
+/// Consulta ahora si la impresora de la estación tiene papel, la tapa
+/// cerrada y está conectada (HU-158). Sin estación, la principal.
+#[tauri::command]
+async fn printer_status(
+    app: tauri::AppHandle,
+    station: Option<String>,
+) -> Result<estado_impresora::EstadoImpresora, String> {
+    debug!("[Cmd:printer_status] station={:?}", station);
+    let config = impresion::load_config(&app);
+    let impresora = config.de_estacion(station.as_deref())?.clone();
+    tauri::async_runtime::spawn_blocking(move || estado_impresora::consultar(&impresora))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Abre el cajón de dinero conectado a la impresora de la estación (HU-169).
+/// Sin cobro de por medio exige el permiso ABRIR_CAJON, validado con el backend.
+#[tauri::command]
+async fn abrir_cajon(app: tauri::AppHandle, estacion: Option<String>) -> Result<String, String> {
+    info!("[Cmd:abrir_cajon] estación: {:?}", estacion);
+    permisos::exigir(permisos::Permiso::AbrirCajon).await?;
+
+    // ESC p 0 — pulso al pin 2 (25 × 2 ms encendido, 250 × 2 ms apagado)
+    const PULSO_CAJON: [u8; 5] = [0x1b, 0x70, 0x00, 0x19, 0xfa];
+    let config = impresion::load_config(&app);
+    let impresora = config.de_estacion(estacion.as_deref())?.clone();
+    tauri::async_runtime::spawn_blocking(move || impresion::enviar_a_impresora(&impresora, &PULSO_CAJON))
+        .await
+        .map_err(|e| e.to_string())?
+        .inspect_err(|e| error!("[Cmd:abrir_cajon] FALLO: {}", e))?;
+    Ok(t(Mensaje::CajonAbierto))
+}
+
+// ─── Comandos Tauri: Permisos por acción ─────────────────────────────────────
+
+/// Identifica al empleado con su PIN (HU-169). Devuelve lo que puede hacer;
+/// desde ahí los pedidos de la capa Rust al backend viajan a su nombre.
+#[tauri::command]
+async fn identificar_empleado(empleado_id: String, pin: String) -> Result<permisos::SesionEmpleado, String> {
+    info!("[Cmd:identificar_empleado] empleado={}", empleado_id);
+    permisos::identificar(&empleado_id, &pin).await
+}
+
+/// Devuelve el empleado identificado en la terminal (null si nadie).
+#[tauri::command]
+fn obtener_sesion_empleado() -> Option<permisos::SesionEmpleado> {
+    permisos::actual()
+}
+
+/// Cierra la sesión del empleado identificado.
+#[tauri::command]
+fn cerrar_sesion_empleado() {
+    permisos::cerrar()
+}
+
+// ─── Comandos Tauri: Sincronización entre sucursales ─────────────────────────
+
+/// Devuelve la configuración de sincronización (incluye la identidad de sucursal).
+#[tauri::command]
+fn obtener_config_sync(app: tauri::AppHandle) -> sync::SyncConfig {
+    debug!("[Cmd:obtener_config_sync] Solicitada desde frontend");
+    sync::load_config(&app)
+}
+
+/// Persiste la configuración de sincronización.
+///
+/// La identidad de sucursal no se puede cambiar desde la UI. Si cambia la
+/// URL del consolidado se reinicia el cursor, para que el nuevo destino
+/// reciba también el historial reciente.
+#[tauri::command]
+fn configurar_sync(app: tauri::AppHandle, config: sync::SyncConfig) -> Result<String, String> {
+    let actual = sync::load_config(&app);
+    let cambio_central = actual.url_central.trim() != config.url_central.trim();
+    let nueva = sync::SyncConfig {
+        sucursal_id: actual.sucursal_id,
+        ultimo_cursor: if cambio_central { None } else { actual.ultimo_cursor },
+        ultimo_cursor_pedido: if cambio_central { None } else { actual.ultimo_cursor_pedido },
+        url_central: config.url_central.trim().to_string(),
+        sucursal_nombre: config.sucursal_nombre.trim().to_string(),
+        ..config
+    };
+    info!(
+        "[Cmd:configurar_sync] habilitado={}, central={}, nombre={}",
+        nueva.habilitado, nueva.url_central, nueva.sucursal_nombre
+    );
+    sync::save_config(&app, &nueva)?;
+    Ok(t(Mensaje::ConfigSyncGuardada))
+}
+
+/// Ejecuta un ciclo de sincronización sin esperar al intervalo.
+#[tauri::command]
+async fn sincronizar_ahora(app: tauri::AppHandle) -> Result<sync::ResultadoSync, String> {
+    info!("[Cmd:sincronizar_ahora] Solicitado desde frontend");
+    sync::sincronizar(&app).await.inspect_err(|e| error!("[Cmd:sincronizar_ahora] FALLO: {}", e))
+}
+
+// ─── Comandos Tauri: Idioma de la capa Rust ──────────────────────────────────
+
+/// Devuelve el idioma vigente y cómo quedan importes y fechas con él (HU-154).
+#[tauri::command]
+fn obtener_config_idioma() -> i18n::InfoLocale {
+    debug!("[Cmd:obtener_config_idioma] Solicitada desde frontend");
+    i18n::InfoLocale::actual()
+}
+
+/// Persiste el idioma. Los mensajes siguientes ya salen en el nuevo idioma.
+#[tauri::command]
+fn configurar_idioma(app: tauri::AppHandle, locale: i18n::Locale) -> Result<String, String> {
+    info!("[Cmd:configurar_idioma] locale={}", locale.etiqueta());
+    i18n::guardar(&app, locale)?;
+    Ok(t(Mensaje::IdiomaGuardado))
+}
+
+// ─── Comandos Tauri: Portapapeles del cobro ──────────────────────────────────
+
+/// Copia al portapapeles del SO un dato de cobro (alias, CVU, monto, link de pago).
+/// Funciona aunque el webview no tenga acceso al portapapeles (modo kiosco).
+#[tauri::command]
+fn copiar_al_portapapeles(app: tauri::AppHandle, texto: String) -> Result<String, String> {
+    portapapeles::copiar(&app, &texto)?;
+    Ok(t(Mensaje::CopiadoAlPortapapeles))
+}
+
+/// Devuelve el número de operación o cupón que hay en el portapapeles (HU-156).
+#[tauri::command]
+fn pegar_numero_operacion(app: tauri::AppHandle) -> Result<String, String> {
+    portapapeles::leer_numero_operacion(&app)
+}
+
+// ─── Comandos Tauri: Borradores de pedido ────────────────────────────────────
+
+/// Anota un ítem que se está mandando al backend en el borrador de la mesa
+/// (HU-164). Se escribe a disco antes de volver.
+#[tauri::command]
+fn guardar_item_borrador(
+    app: tauri::AppHandle,
+    mesa_id: String,
+    pedido_id: String,
+    item: borradores::ItemBorrador,
+) -> Result<(), String> {
+    borradores::guardar_item(&app, &mesa_id, &pedido_id, item)
+}
+
+/// Quita del borrador un ítem que el backend ya confirmó.
+#[tauri::command]
+fn quitar_item_borrador(app: tauri::AppHandle, mesa_id: String, item_id: String) -> Result<(), String> {
+    borradores::quitar_item(&app, &mesa_id, &item_id)
+}
+
+/// Devuelve los ítems de la mesa que quedaron sin confirmar (null si no hay).
+#[tauri::command]
+fn obtener_borrador(app: tauri::AppHandle, mesa_id: String) -> Option<borradores::BorradorPedido> {
+    borradores::obtener(&app, &mesa_id)
+}
+
+/// Descarta el borrador de la mesa (ya se recuperó o el mozo no lo quiere).
+#[tauri::command]
+fn descartar_borrador(app: tauri::AppHandle, mesa_id: String) -> Result<(), String> {
+    borradores::descartar(&app, &mesa_id)
+}
+
+// ─── Comandos Tauri: Reportes como imagen ────────────────────────────────────
+
+/// Guarda un reporte como PNG y lo deja en el portapapeles para pegarlo en
+/// WhatsApp (HU-157). `ancho` es TICKET o TELEFONO (por defecto).
+#[tauri::command]
+async fn capture_report_image(
+    app: tauri::AppHandle,
+    view: reporte_imagen::Vista,
+    range: reporte_imagen::Rango,
+    ancho: Option<reporte_imagen::Ancho>,
+) -> Result<String, String> {
+    info!("[Cmd:capture_report_image] view={:?} {}..{}", view, range.desde, range.hasta);
+    reporte_imagen::capturar(&app, view, &range, ancho.unwrap_or_default()).await
+}
+
 /// Devuelve la ruta al directorio de logs para que el frontend pueda
 /// mostrársela al operador cuando necesite enviar logs de diagnóstico.
 #[tauri::command]
@@ -59,7 +277,7 @@ fn obtener_ruta_logs(app: tauri::AppHandle) -> Result<String, String> {
     let log_dir = app
         .path()
         .app_log_dir()
-        .map_err(|e| format!("No se pudo resolver directorio de logs: {}", e))?;
+        .map_err(|e| t(Mensaje::DirectorioLogsNoResuelto { detalle: &e.to_string() }))?;
 
     let ruta = log_dir.to_string_lossy().to_string();
     info!("[Cmd:obtener_ruta_logs] Ruta de logs: {}", ruta);
@@ -71,6 +289,7 @@ pub fn run() {
   tauri::Builder::default()
     .plugin(tauri_plugin_store::Builder::default().build())
     .plugin(tauri_plugin_shell::init())
+    .plugin(tauri_plugin_clipboard_manager::init())
     .manage(AppState {
         backend_process: Mutex::new(None),
     })
@@ -110,6 +329,9 @@ pub fn run() {
         )?;
       }
 
+      // Idioma de los mensajes de la capa Rust (HU-154)
+      i18n::cargar(app.handle());
+
       // Lanzar backend Java embebido
       let app_handle = app.handle().clone();
       tauri::async_runtime::spawn(async move {
@@ -119,13 +341,42 @@ pub fn run() {
         }
       });
 
+      // Sincronización periódica con el consolidado (HU-107)
+      tauri::async_runtime::spawn(sync::iniciar_sincronizacion_periodica(app.handle().clone()));
+
+      // Cortes de luz con UPS: aviso y apagado seguro (HU-155)
+      tauri::async_runtime::spawn(energia::iniciar_monitor_energia(app.handle().clone()));
+
+      // Papel, tapa y conexión de la impresora de cada estación (HU-158)
+      tauri::async_runtime::spawn(estado_impresora::iniciar_monitor_impresoras(app.handle().clone()));
+
+      // Vacuum/analyze/reindex de la base en la ventana fuera de horario (HU-163)
+      tauri::async_runtime::spawn(mantenimiento::iniciar_mantenimiento_programado(app.handle().clone()));
+
       Ok(())
     })
     .invoke_handler(tauri::generate_handler![
       imprimir_ticket,
       configurar_impresora,
       obtener_config_impresora,
+      printer_status,
+      abrir_cajon,
+      identificar_empleado,
+      obtener_sesion_empleado,
+      cerrar_sesion_empleado,
       obtener_ruta_logs,
+      obtener_config_sync,
+      configurar_sync,
+      sincronizar_ahora,
+      obtener_config_idioma,
+      configurar_idioma,
+      copiar_al_portapapeles,
+      pegar_numero_operacion,
+      capture_report_image,
+      guardar_item_borrador,
+      quitar_item_borrador,
+      obtener_borrador,
+      descartar_borrador,
     ])
     .on_window_event(|window, event| {
       if let tauri::WindowEvent::CloseRequested { .. } = event {
@@ -191,6 +442,12 @@ async fn start_backend(app: &tauri::AppHandle) -> Result<(), Box<dyn std::error:
   let db_path_str = db_path.to_string_lossy().replace('\\', "/");
   info!("[Backend] Ruta SQLite: {}", db_path_str);
 
+  // ── Identidad de sucursal (HU-107) ─────────────────────────────────────
+  // Se genera en el primer arranque y se persiste en sync_config.json,
+  // así dos instalaciones del mismo local nunca comparten identidad.
+  let sync_config = sync::load_config(app);
+  info!("[Backend] Sucursal: {} ({})", sync_config.sucursal_id, sync_config.sucursal_nombre);
+
   info!("[Backend] Iniciando: {} -jar {}", java_cmd, jar_path_clean);
 
   let mut command = tokio::process::Command::new(&java_cmd);
@@ -199,6 +456,8 @@ async fn start_backend(app: &tauri::AppHandle) -> Result<(), Box<dyn std::error:
     .arg("-jar")
     .arg(&jar_path_clean)
     .env("FOODFLOW_DB_PATH", &db_path_str)
+    .env("FOODFLOW_SUCURSAL_ID", &sync_config.sucursal_id)
+    .env("FOODFLOW_SUCURSAL_NOMBRE", &sync_config.sucursal_nombre)
     .stdout(std::process::Stdio::piped())
     .stderr(std::process::Stdio::piped())
     .kill_on_drop(true);
@@ -244,7 +503,7 @@ async fn start_backend(app: &tauri::AppHandle) -> Result<(), Box<dyn std::error:
 
   // Esperar un poco para que el backend arranque
   tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-  info!("[Backend] Backend debería estar listo en http://localhost:8080");
+  info!("[Backend] Backend debería estar listo en {}", BACKEND_LOCAL);
 
   Ok(())
 }
@@ -0,0 +1,388 @@
+//! HU-163: Mantenimiento programado de la base en la ventana fuera de horario.
+//!
+//! Con los meses la base se fragmenta y el mapa de mesas y los reportes se
+//! ponen lentos. Un loop en segundo plano espera la ventana configurada
+//! (por defecto de 4 a 6 de la mañana) y, si ya pasaron `cada_dias` desde la
+//! última corrida:
+//!
+//! 1. suspende la cola de impresión (el ticket en curso termina),
+//! 2. pide al backend el mantenimiento (`POST /api/sistema/mantenimiento`):
+//!    VACUUM, ANALYZE y REINDEX con la base bloqueada mientras dura,
+//! 3. reanuda la cola y anota la duración y el espacio recuperado en
+//!    `mantenimiento_historial.json`.
+//!
+//! El backend lo rechaza si quedan mesas abiertas; en ese caso se reintenta
+//! en la vuelta siguiente mientras siga la ventana.
+//!
+//! La configuración vive en `mantenimiento_config.json` en el directorio de
+//! datos de la app, igual que la de la energía. Viene apagado: mientras dura
+//! la base queda bloqueada, y un local que cierra tarde pararía las ventas.
+//! El dueño lo habilita con `"habilitado": true` después de revisar que la
+//! ventana cae con el local cerrado.
+
+use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime};
+use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+use tauri::Manager;
+
+use crate::i18n::{t, Mensaje};
+use crate::impresion::{self, Suspension};
+use crate::{cliente_http, BACKEND_LOCAL};
+
+/// Un VACUUM de una base de varios GB puede tardar minutos.
+const ESPERA_MANTENIMIENTO: Duration = Duration::from_secs(30 * 60);
+
+/// Corridas que se conservan en el historial (las más viejas se borran).
+const CORRIDAS_CONSERVADAS: usize = 30;
+
+const FORMATO_FECHA: &str = "%Y-%m-%dT%H:%M:%S";
+
+// ─── Configuración ────────────────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MantenimientoConfig {
+    /// Si está en false no se corre nunca
+    #[serde(default = "default_habilitado")]
+    pub habilitado: bool,
+
+    /// Inicio de la ventana fuera de horario ("HH:MM", hora local)
+    #[serde(default = "default_hora_inicio")]
+    pub hora_inicio: String,
+
+    /// Fin de la ventana; si es anterior al inicio la ventana cruza la medianoche
+    #[serde(default = "default_hora_fin")]
+    pub hora_fin: String,
+
+    /// Días mínimos entre una corrida y la siguiente
+    #[serde(default = "default_cada_dias")]
+    pub cada_dias: u32,
+
+    /// Cada cuántos segundos se revisa si toca correr
+    #[serde(default = "default_intervalo_segundos")]
+    pub intervalo_segundos: u64,
+}
+
+fn default_habilitado() -> bool {
+    false
+}
+fn default_hora_inicio() -> String {
+    "04:00".to_string()
+}
+fn default_hora_fin() -> String {
+    "06:00".to_string()
+}
+fn default_cada_dias() -> u32 {
+    7
+}
+fn default_intervalo_segundos() -> u64 {
+    300
+}
+
+impl Default for MantenimientoConfig {
+    fn default() -> Self {
+        Self {
+            habilitado: default_habilitado(),
+            hora_inicio: default_hora_inicio(),
+            hora_fin: default_hora_fin(),
+            cada_dias: default_cada_dias(),
+            intervalo_segundos: default_intervalo_segundos(),
+        }
+    }
+}
+
+fn data_path(app: &tauri::AppHandle, archivo: &str) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join(archivo))
+        .map_err(|e| t(Mensaje::DirectorioDatosNoResuelto { detalle: &e.to_string() }))
+}
+
+pub fn load_config(app: &tauri::AppHandle) -> MantenimientoConfig {
+    match data_path(app, "mantenimiento_config.json").and_then(|path| {
+        std::fs::read_to_string(&path)
+            .map_err(|e| t(Mensaje::ConfigNoLeida { ruta: &format!("{:?}", path), detalle: &e.to_string() }))
+    }) {
+        Ok(json) => serde_json::from_str::<MantenimientoConfig>(&json).unwrap_or_else(|e| {
+            error!("[Mantenimiento] Config corrupta: {}. Usando defaults.", e);
+            MantenimientoConfig::default()
+        }),
+        Err(e) => {
+            debug!("[Mantenimiento] {}. Usando defaults.", e);
+            MantenimientoConfig::default()
+        }
+    }
+}
+
+// ─── Historial ────────────────────────────────────────────────────────────────
+
+/// Una corrida exitosa, tal como la informa el backend.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Corrida {
+    /// Fecha y hora local en que terminó
+    pub fecha: String,
+    pub motor: String,
+    pub milisegundos: u64,
+    pub bytes_antes: i64,
+    pub bytes_despues: i64,
+    pub bytes_recuperados: i64,
+}
+
+fn load_historial(app: &tauri::AppHandle) -> Vec<Corrida> {
+    data_path(app, "mantenimiento_historial.json")
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .map(|json| parsear_historial(&json))
+        .unwrap_or_default()
+}
+
+/// Un historial corrupto cuenta como vacío: la corrida siguiente lo reescribe.
+fn parsear_historial(json: &str) -> Vec<Corrida> {
+    serde_json::from_str(json).unwrap_or_else(|e| {
+        warn!("[Mantenimiento] Historial corrupto: {}. Se empieza de cero.", e);
+        Vec::new()
+    })
+}
+
+fn guardar_historial(app: &tauri::AppHandle, historial: &[Corrida]) -> Result<(), String> {
+    let path = data_path(app, "mantenimiento_historial.json")?;
+    let no_guardado =
+        |detalle: &str| t(Mensaje::HistorialMantenimientoNoGuardado { ruta: &format!("{:?}", path), detalle });
+    let json = serde_json::to_string_pretty(historial).map_err(|e| no_guardado(&e.to_string()))?;
+    std::fs::write(&path, json).map_err(|e| no_guardado(&e.to_string()))
+}
+
+fn ultima_corrida(historial: &[Corrida]) -> Option<NaiveDate> {
+    historial
+        .last()
+        .and_then(|c| NaiveDateTime::parse_from_str(&c.fecha, FORMATO_FECHA).ok())
+        .map(|f| f.date())
+}
+
+// ─── Ventana ──────────────────────────────────────────────────────────────────
+
+/// Inicio incluido y fin excluido, como las franjas horarias de la carta.
+/// Una hora mal escrita desactiva la ventana en lugar de correr a cualquier hora.
+fn en_ventana(config: &MantenimientoConfig, ahora: NaiveTime) -> bool {
+    let (Ok(desde), Ok(hasta)) = (
+        NaiveTime::parse_from_str(&config.hora_inicio, "%H:%M"),
+        NaiveTime::parse_from_str(&config.hora_fin, "%H:%M"),
+    ) else {
+        warn!(
+            "[Mantenimiento] Ventana inválida ({} a {}): se esperaba HH:MM",
+            config.hora_inicio, config.hora_fin
+        );
+        return false;
+    };
+    if desde <= hasta {
+        ahora >= desde && ahora < hasta
+    } else {
+        ahora >= desde || ahora < hasta
+    }
+}
+
+fn toca_correr(config: &MantenimientoConfig, historial: &[Corrida], hoy: NaiveDate) -> bool {
+    match ultima_corrida(historial) {
+        Some(ultima) => (hoy - ultima).num_days() >= i64::from(config.cada_dias.max(1)),
+        None => true,
+    }
+}
+
+// ─── Programador ──────────────────────────────────────────────────────────────
+
+/// Loop en segundo plano. Relee la configuración en cada vuelta, igual que
+/// el monitor de energía.
+pub async fn iniciar_mantenimiento_programado(app: tauri::AppHandle) {
+    loop {
+        let config = load_config(&app);
+        tokio::time::sleep(Duration::from_secs(config.intervalo_segundos.max(1))).await;
+
+        if !config.habilitado {
+            continue;
+        }
+        let ahora = Local::now().naive_local();
+        if !en_ventana(&config, ahora.time()) {
+            continue;
+        }
+        let mut historial = load_historial(&app);
+        if !toca_correr(&config, &historial, ahora.date()) {
+            continue;
+        }
+
+        info!("[Mantenimiento] Inicia el mantenimiento de la base");
+        if let Err(e) = tauri::async_runtime::spawn_blocking(|| impresion::suspender(Suspension::Mantenimiento)).await {
+            error!("[Mantenimiento] No se pudo suspender la impresión: {}", e);
+        }
+        let resultado = ejecutar().await;
+        // Si mientras tanto se cortó la luz, la cola sigue suspendida
+        if let Err(e) = tauri::async_runtime::spawn_blocking(|| impresion::reanudar(Suspension::Mantenimiento)).await {
+            error!("[Mantenimiento] No se pudo reanudar la impresión: {}", e);
+        }
+
+        match resultado {
+            Ok(corrida) => {
+                info!(
+                    "[Mantenimiento] {} optimizada en {} s: {} KB recuperados ({} KB -> {} KB)",
+                    corrida.motor,
+                    corrida.milisegundos / 1000,
+                    corrida.bytes_recuperados / 1024,
+                    corrida.bytes_antes / 1024,
+                    corrida.bytes_despues / 1024
+                );
+                historial.push(corrida);
+                let sobrantes = historial.len().saturating_sub(CORRIDAS_CONSERVADAS);
+                historial.drain(..sobrantes);
+                if let Err(e) = guardar_historial(&app, &historial) {
+                    error!("[Mantenimiento] No se pudo guardar el historial: {}", e);
+                }
+            }
+            Err(e) => warn!("[Mantenimiento] No se pudo hacer el mantenimiento: {}", e),
+        }
+    }
+}
+
+/// Respuesta del backend (MantenimientoBaseResponse).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RespuestaMantenimiento {
+    motor: String,
+    milisegundos: u64,
+    bytes_antes: i64,
+    bytes_despues: i64,
+    bytes_recuperados: i64,
+}
+
+async fn ejecutar() -> Result<Corrida, String> {
+    let url = format!("{}/api/sistema/mantenimiento", BACKEND_LOCAL);
+
+    let respuesta = cliente_http()?
+        .post(&url)
+        .timeout(ESPERA_MANTENIMIENTO)
+        .send()
+        .await
+        .map_err(|e| t(Mensaje::BackendLocalSinRespuesta { detalle: &e.to_string() }))?;
+    if respuesta.status() == reqwest::StatusCode::CONFLICT {
+        // Quedan mesas abiertas: se reintenta en la vuelta siguiente
+        let detalle = respuesta.text().await.unwrap_or_default();
+        return Err(t(Mensaje::MantenimientoPospuesto { detalle: &detalle }));
+    }
+    let r: RespuestaMantenimiento = respuesta
+        .error_for_status()
+        .map_err(|e| t(Mensaje::BackendLocalSinRespuesta { detalle: &e.to_string() }))?
+        .json()
+        .await
+        .map_err(|e| t(Mensaje::BackendLocalRespuestaInvalida { detalle: &e.to_string() }))?;
+
+    Ok(Corrida {
+        fecha: Local::now().naive_local().format(FORMATO_FECHA).to_string(),
+        motor: r.motor,
+        milisegundos: r.milisegundos,
+        bytes_antes: r.bytes_antes,
+        bytes_despues: r.bytes_despues,
+        bytes_recuperados: r.bytes_recuperados,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ventana(hora_inicio: &str, hora_fin: &str) -> MantenimientoConfig {
+        MantenimientoConfig {
+            hora_inicio: hora_inicio.to_string(),
+            hora_fin: hora_fin.to_string(),
+            ..MantenimientoConfig::default()
+        }
+    }
+
+    fn cada(dias: u32) -> MantenimientoConfig {
+        MantenimientoConfig { cada_dias: dias, ..MantenimientoConfig::default() }
+    }
+
+    fn hora(hhmm: &str) -> NaiveTime {
+        NaiveTime::parse_from_str(hhmm, "%H:%M").unwrap()
+    }
+
+    fn dia(fecha: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(fecha, "%Y-%m-%d").unwrap()
+    }
+
+    fn corrida(fecha: &str) -> Corrida {
+        Corrida {
+            fecha: fecha.to_string(),
+            motor: "SQLite".to_string(),
+            milisegundos: 1_000,
+            bytes_antes: 2_048,
+            bytes_despues: 1_024,
+            bytes_recuperados: 1_024,
+        }
+    }
+
+    #[test]
+    fn viene_apagado() {
+        assert!(!MantenimientoConfig::default().habilitado);
+        let config: MantenimientoConfig = serde_json::from_str("{}").unwrap();
+        assert!(!config.habilitado);
+    }
+
+    #[test]
+    fn la_ventana_incluye_el_inicio_y_excluye_el_fin() {
+        let config = ventana("04:00", "06:00");
+        assert!(!en_ventana(&config, hora("03:59")));
+        assert!(en_ventana(&config, hora("04:00")));
+        assert!(en_ventana(&config, hora("05:59")));
+        assert!(!en_ventana(&config, hora("06:00")));
+    }
+
+    #[test]
+    fn la_ventana_puede_cruzar_la_medianoche() {
+        let config = ventana("23:00", "02:00");
+        assert!(!en_ventana(&config, hora("22:59")));
+        assert!(en_ventana(&config, hora("23:00")));
+        assert!(en_ventana(&config, hora("00:00")));
+        assert!(en_ventana(&config, hora("01:59")));
+        assert!(!en_ventana(&config, hora("02:00")));
+        assert!(!en_ventana(&config, hora("12:00")));
+    }
+
+    #[test]
+    fn una_hora_mal_escrita_desactiva_la_ventana() {
+        for (inicio, fin) in [("4 AM", "06:00"), ("04:00", "25:00"), ("", "06:00")] {
+            let config = ventana(inicio, fin);
+            for ahora in ["00:00", "04:00", "05:00", "12:00"] {
+                assert!(!en_ventana(&config, hora(ahora)), "{} a {} a las {}", inicio, fin, ahora);
+            }
+        }
+    }
+
+    #[test]
+    fn corre_cuando_pasaron_los_dias_configurados() {
+        let historial = [corrida("2026-03-01T04:10:00"), corrida("2026-03-08T04:05:00")];
+        assert!(!toca_correr(&cada(7), &historial, dia("2026-03-14")));
+        assert!(toca_correr(&cada(7), &historial, dia("2026-03-15")));
+    }
+
+    #[test]
+    fn cada_cero_dias_corre_a_lo_sumo_una_vez_por_dia() {
+        let historial = [corrida("2026-03-08T04:05:00")];
+        assert!(!toca_correr(&cada(0), &historial, dia("2026-03-08")));
+        assert!(toca_correr(&cada(0), &historial, dia("2026-03-09")));
+    }
+
+    #[test]
+    fn sin_historial_corre() {
+        assert!(toca_correr(&cada(7), &[], dia("2026-03-08")));
+    }
+
+    #[test]
+    fn un_historial_corrupto_cuenta_como_vacio() {
+        let historial = parsear_historial("[{\"fecha\": ");
+        assert!(historial.is_empty());
+        assert!(toca_correr(&cada(7), &historial, dia("2026-03-08")));
+
+        // Una fecha ilegible en la última corrida tampoco frena el mantenimiento
+        assert!(toca_correr(&cada(7), &[corrida("ayer")], dia("2026-03-08")));
+    }
+}
@@ -0,0 +1,180 @@
+//! HU-169: Permisos por acción en la capa Rust.
+//!
+//! El empleado se identifica en la terminal con su PIN
+//! (`POST /api/permisos/sesion`) y recibe un token de sesión, que queda en
+//! memoria hasta que la cierra o se cierra la app. La sesión vive en el
+//! backend y vence sola: con un token vencido el backend vuelve a pedir el
+//! PIN. Con ella:
+//!
+//! - Las acciones que no pasan por el backend (abrir el cajón) se validan
+//!   acá antes de hacerlas, preguntándole al backend
+//!   (`POST /api/permisos/verificar/{permiso}`): la matriz vigente es la del
+//!   backend, no la que se bajó al identificarse.
+//! - Los pedidos que esta capa le hace al backend en nombre del operador
+//!   (reporte como imagen) viajan con el token en `X-Sesion-Empleado`,
+//!   igual que los del frontend.
+//!
+//! Si el backend no responde, la acción se rechaza: sin la matriz no hay
+//! forma de saber si está permitida.
+
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+use std::time::Duration;
+
+use crate::i18n::{t, Mensaje};
+use crate::{cliente_http, BACKEND_LOCAL};
+
+/// Encabezado con el token del que el backend resuelve quién opera la terminal.
+pub const HEADER_SESION: &str = "X-Sesion-Empleado";
+
+/// Espera de cada pedido al backend.
+const ESPERA: Duration = Duration::from_secs(10);
+
+/// Acción sensible que el dueño habilita por rol o por empleado.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Permiso {
+    AnularItems,
+    AplicarDescuentos,
+    AbrirCajon,
+    VerReportes,
+    ModificarPrecios,
+}
+
+impl Permiso {
+    fn parametro(self) -> &'static str {
+        match self {
+            Permiso::AnularItems => "ANULAR_ITEMS",
+            Permiso::AplicarDescuentos => "APLICAR_DESCUENTOS",
+            Permiso::AbrirCajon => "ABRIR_CAJON",
+            Permiso::VerReportes => "VER_REPORTES",
+            Permiso::ModificarPrecios => "MODIFICAR_PRECIOS",
+        }
+    }
+}
+
+/// Empleado identificado en la terminal, tal como lo devuelve el backend.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SesionEmpleado {
+    pub empleado_id: String,
+    pub nombre: String,
+    pub rol: String,
+    /// Acciones habilitadas al identificarse (todas si la matriz está inactiva)
+    pub permisos: Vec<Permiso>,
+    pub matriz_activa: bool,
+    /// Token de la sesión en el backend; es lo único que la identifica
+    pub token: String,
+    /// Vencimiento de la sesión (fecha y hora local del backend)
+    pub vence_en: String,
+}
+
+static SESION: RwLock<Option<SesionEmpleado>> = RwLock::new(None);
+
+/// Mensaje de error que explica el backend en `message`, o el estado HTTP.
+async fn detalle_rechazo(respuesta: reqwest::Response) -> String {
+    let estado = respuesta.status().to_string();
+    respuesta
+        .json::<serde_json::Value>()
+        .await
+        .ok()
+        .and_then(|v| v["message"].as_str().map(str::to_string))
+        .unwrap_or(estado)
+}
+
+// ─── Sesión ───────────────────────────────────────────────────────────────────
+
+/// Identifica al empleado con su PIN y deja la sesión abierta en la terminal.
+pub async fn identificar(empleado_id: &str, pin: &str) -> Result<SesionEmpleado, String> {
+    let url = format!("{}/api/permisos/sesion", BACKEND_LOCAL);
+    debug!("[Permisos] POST {}", url);
+
+    let respuesta = cliente_http()?
+        .post(&url)
+        .timeout(ESPERA)
+        .json(&serde_json::json!({ "empleadoId": empleado_id, "pin": pin }))
+        .send()
+        .await
+        .map_err(|e| t(Mensaje::BackendLocalSinRespuesta { detalle: &e.to_string() }))?;
+    if !respuesta.status().is_success() {
+        let detalle = detalle_rechazo(respuesta).await;
+        warn!("[Permisos] Identificación rechazada: {}", detalle);
+        return Err(t(Mensaje::EmpleadoNoIdentificado { detalle: &detalle }));
+    }
+    let sesion: SesionEmpleado = respuesta
+        .json()
+        .await
+        .map_err(|e| t(Mensaje::BackendLocalRespuestaInvalida { detalle: &e.to_string() }))?;
+
+    info!("[Permisos] Identificado {} ({}): {:?}", sesion.nombre, sesion.rol, sesion.permisos);
+    if let Ok(mut actual) = SESION.write() {
+        *actual = Some(sesion.clone());
+    }
+    Ok(sesion)
+}
+
+/// Sesión abierta en la terminal, si alguien se identificó.
+pub fn actual() -> Option<SesionEmpleado> {
+    SESION.read().ok().and_then(|s| s.clone())
+}
+
+/// Cierra la sesión: las acciones siguientes vuelven a pedir identificarse.
+/// La del backend la cierra el frontend (`DELETE /api/permisos/sesion`).
+pub fn cerrar() {
+    if let Ok(mut actual) = SESION.write() {
+        if let Some(sesion) = actual.take() {
+            info!("[Permisos] Sesión de {} cerrada", sesion.nombre);
+        }
+    }
+}
+
+/// Agrega el token de la sesión del empleado identificado, si hay sesión.
+pub fn con_empleado(pedido: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+    match actual() {
+        Some(sesion) => pedido.header(HEADER_SESION, sesion.token),
+        None => pedido,
+    }
+}
+
+// ─── Validación ───────────────────────────────────────────────────────────────
+
+/// Valida con el backend que el empleado identificado pueda hacer la acción.
+pub async fn exigir(permiso: Permiso) -> Result<(), String> {
+    let url = format!("{}/api/permisos/verificar/{}", BACKEND_LOCAL, permiso.parametro());
+    debug!("[Permisos] POST {}", url);
+
+    let respuesta = con_empleado(cliente_http()?.post(&url).timeout(ESPERA))
+        .send()
+        .await
+        .map_err(|e| t(Mensaje::BackendLocalSinRespuesta { detalle: &e.to_string() }))?;
+    if !respuesta.status().is_success() {
+        let detalle = detalle_rechazo(respuesta).await;
+        warn!("[Permisos] {:?} rechazado: {}", permiso, detalle);
+        return Err(t(Mensaje::PermisoDenegado { detalle: &detalle }));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lee_la_sesion_con_su_token_tal_como_la_devuelve_el_backend() {
+        let sesion: SesionEmpleado = serde_json::from_value(serde_json::json!({
+            "empleadoId": "5b0c6c9e-3f4a-4a57-9d1e-2f8e0a6b7c11",
+            "nombre": "Juan",
+            "rol": "MOZO",
+            "permisos": ["APLICAR_DESCUENTOS", "ABRIR_CAJON"],
+            "matrizActiva": true,
+            "token": "q3J9x0Lk",
+            "venceEn": "2026-10-14T20:00:00"
+        }))
+        .unwrap();
+
+        assert_eq!(sesion.token, "q3J9x0Lk");
+        assert_eq!(sesion.vence_en, "2026-10-14T20:00:00");
+        assert_eq!(sesion.permisos, vec![Permiso::AplicarDescuentos, Permiso::AbrirCajon]);
+    }
+}
@@ -0,0 +1,243 @@
+//! HU-156: Portapapeles nativo para los datos de cobro.
+//!
+//! En modo kiosco el webview no tiene permiso para usar
+//! `navigator.clipboard`, así que copiar el alias, el monto de una
+//! transferencia o un link de pago, y pegar el número de operación de un
+//! comprobante, pasa por el portapapeles del SO a través de
+//! `tauri-plugin-clipboard-manager`.
+//!
+//! Al pegar un comprobante, lo copiado suele ser una línea entera del home
+//! banking o de la app del posnet ("Nro. de operación: 123456789"), o el
+//! comprobante completo: se rescata el número y se descarta el resto. Manda
+//! lo que sigue a una etiqueta ("operación", "cupón", "Nro."); sin etiqueta,
+//! el token con más dígitos. Los importes, fechas, teléfonos y CBU/CVU no
+//! cuentan nunca.
+
+use log::{debug, info, warn};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+use crate::i18n::{t, Mensaje};
+
+/// Mínimo de caracteres para que un token cuente como número de operación
+/// (descarta fechas sueltas, horas o montos cortos).
+const LARGO_MINIMO_OPERACION: usize = 4;
+
+/// Mismo tope que el backend para la referencia de un pago.
+const LARGO_MAXIMO_OPERACION: usize = 64;
+
+/// Comienzos de las palabras que anuncian el número de operación.
+const ETIQUETAS: [&str; 9] = [
+    "operaci", "transacci", "comprobante", "cupón", "cupon", "referencia", "autorizaci", "nro", "n°",
+];
+
+/// Comienzos de las palabras de líneas con otros números: importes,
+/// teléfonos, cuentas, documentos y fechas.
+const DESCARTES: [&str; 14] = [
+    "monto", "importe", "total", "tel", "cel", "whatsapp", "cvu", "cbu", "alias", "cuit", "cuil", "dni", "fecha",
+    "hora",
+];
+
+pub fn copiar(app: &tauri::AppHandle, texto: &str) -> Result<(), String> {
+    app.clipboard().write_text(texto.to_string()).map_err(|e| {
+        let msg = t(Mensaje::PortapapelesNoDisponible { detalle: &e.to_string() });
+        warn!("[Portapapeles] {}", msg);
+        msg
+    })?;
+    info!("[Portapapeles] Copiados {} caracteres", texto.chars().count());
+    Ok(())
+}
+
+fn leer(app: &tauri::AppHandle) -> Result<String, String> {
+    let texto = app.clipboard().read_text().map_err(|e| {
+        let msg = t(Mensaje::PortapapelesNoDisponible { detalle: &e.to_string() });
+        warn!("[Portapapeles] {}", msg);
+        msg
+    })?;
+    let texto = texto.trim().to_string();
+    if texto.is_empty() {
+        return Err(t(Mensaje::PortapapelesVacio));
+    }
+    Ok(texto)
+}
+
+/// Lee el portapapeles y devuelve el número de operación o cupón que contiene.
+pub fn leer_numero_operacion(app: &tauri::AppHandle) -> Result<String, String> {
+    let texto = leer(app)?;
+    debug!("[Portapapeles] Pegado: {:?}", texto);
+    extraer_numero_operacion(&texto).ok_or_else(|| t(Mensaje::SinNumeroOperacion))
+}
+
+/// El número que sigue a una etiqueta (en la misma línea o, si la etiqueta
+/// quedó sola, en la siguiente). Sin etiqueta, el token con más dígitos de
+/// las líneas que no hablan de importes, teléfonos, cuentas ni fechas. Si lo
+/// pegado ya es un único token, va tal cual.
+fn extraer_numero_operacion(texto: &str) -> Option<String> {
+    let lineas: Vec<&str> = texto.lines().collect();
+
+    for (n, linea) in lineas.iter().enumerate() {
+        let tramos = tras_etiquetas(linea);
+        if let Some(token) = tramos.iter().find_map(|tramo| candidatos(tramo).into_iter().next()) {
+            return Some(token.to_string());
+        }
+        let etiqueta_sola = tramos.last().is_some_and(|tramo| !tramo.chars().any(char::is_alphanumeric));
+        if let Some(token) = lineas
+            .get(n + 1)
+            .filter(|_| etiqueta_sola)
+            .and_then(|siguiente| candidatos(siguiente).into_iter().next())
+        {
+            return Some(token.to_string());
+        }
+    }
+
+    lineas
+        .iter()
+        .filter(|linea| !linea.contains('$') && !linea.trim_start().starts_with('+'))
+        .filter(|linea| !palabras(linea).iter().any(|&(a, b)| empieza_con(&linea[a..b], &DESCARTES)))
+        .flat_map(|linea| candidatos(linea))
+        .max_by_key(|token| token.chars().filter(|c| c.is_ascii_digit()).count())
+        .map(str::to_string)
+}
+
+/// Lo que sigue a cada etiqueta de la línea, hasta el próximo importe (`$`)
+/// o palabra de descarte. Una etiqueta en la misma frase que una palabra de
+/// descarte ("Monto de la operación") no cuenta.
+fn tras_etiquetas(linea: &str) -> Vec<&str> {
+    let palabras = palabras(linea);
+    let es = |k: usize, lista: &[&str]| empieza_con(&linea[palabras[k].0..palabras[k].1], lista);
+
+    (0..palabras.len())
+        .filter(|&k| es(k, &ETIQUETAS))
+        .filter(|&k| {
+            let frase = linea[..palabras[k].0]
+                .rfind(|c: char| ".:;$".contains(c) || c.is_ascii_digit())
+                .map_or(0, |p| p + 1);
+            !(0..k).any(|j| palabras[j].0 >= frase && es(j, &DESCARTES))
+        })
+        .map(|k| {
+            let desde = palabras[k].1;
+            let hasta = (k + 1..palabras.len())
+                .find(|&j| es(j, &DESCARTES))
+                .map_or(linea.len(), |j| palabras[j].0);
+            let hasta = linea[desde..hasta].find('$').map_or(hasta, |p| desde + p);
+            &linea[desde..hasta]
+        })
+        .collect()
+}
+
+/// Rangos de las palabras (letras, y los º/° de "Nº") de la línea.
+fn palabras(linea: &str) -> Vec<(usize, usize)> {
+    let mut rangos = Vec::new();
+    let mut inicio = None;
+    for (i, c) in linea.char_indices().chain(std::iter::once((linea.len(), ' '))) {
+        let es_letra = c.is_alphabetic() || c == '°' || c == 'º';
+        match (inicio, es_letra) {
+            (None, true) => inicio = Some(i),
+            (Some(desde), false) => {
+                rangos.push((desde, i));
+                inicio = None;
+            }
+            _ => {}
+        }
+    }
+    rangos
+}
+
+fn empieza_con(palabra: &str, comienzos: &[&str]) -> bool {
+    let palabra = palabra.to_lowercase().replace('º', "°");
+    comienzos.iter().any(|c| palabra.starts_with(c))
+}
+
+/// Tokens de letras, dígitos y guiones con algún dígito y largo de número de
+/// operación. Descarta las partes de fechas y horas ("10/03/2026 21:30"), de
+/// importes ("15.000,50") y los CBU/CVU (22 dígitos).
+fn candidatos(texto: &str) -> Vec<&str> {
+    let bytes = texto.as_bytes();
+    let digito = |i: Option<usize>| i.and_then(|i| bytes.get(i)).is_some_and(u8::is_ascii_digit);
+    let separa_numeros = |sep: Option<usize>, otro_lado: Option<usize>| match sep.and_then(|i| bytes.get(i)) {
+        Some(b'/') => true,
+        Some(b'.' | b',' | b':') => digito(otro_lado),
+        _ => false,
+    };
+
+    let mut encontrados = Vec::new();
+    let mut inicio = None;
+    for i in 0..=bytes.len() {
+        let es_parte = bytes.get(i).is_some_and(|b| b.is_ascii_alphanumeric() || *b == b'-');
+        match (inicio, es_parte) {
+            (None, true) => inicio = Some(i),
+            (Some(desde), false) => {
+                inicio = None;
+                let token = texto[desde..i].trim_matches('-');
+                let pegado = separa_numeros(desde.checked_sub(1), desde.checked_sub(2))
+                    || separa_numeros(Some(i), Some(i + 1));
+                let cbu = token.len() == 22 && token.bytes().all(|b| b.is_ascii_digit());
+                if !pegado
+                    && !cbu
+                    && token.bytes().any(|b| b.is_ascii_digit())
+                    && (LARGO_MINIMO_OPERACION..=LARGO_MAXIMO_OPERACION).contains(&token.len())
+                {
+                    encontrados.push(token);
+                }
+            }
+            _ => {}
+        }
+    }
+    encontrados
+}
+
+#[cfg(test)]
+mod tests {
+    use super::extraer_numero_operacion as extraer;
+
+    #[test]
+    fn toma_el_token_pegado_solo() {
+        assert_eq!(extraer("98765432101").as_deref(), Some("98765432101"));
+        assert_eq!(extraer("  TRX-00012345 \n").as_deref(), Some("TRX-00012345"));
+    }
+
+    #[test]
+    fn rescata_la_operacion_de_un_comprobante_de_mercado_pago() {
+        let texto = "Mercado Pago\n\
+                     ¡Listo! Le transferiste a Juan Pérez\n\
+                     $ 15.000\n\
+                     CVU: 0000003100012345678901\n\
+                     Miércoles 10/03/2026 a las 21:30 hs\n\
+                     Número de operación de Mercado Pago\n\
+                     12345678901";
+        assert_eq!(extraer(texto).as_deref(), Some("12345678901"));
+    }
+
+    #[test]
+    fn rescata_la_operacion_de_una_transferencia_de_home_banking_en_una_linea() {
+        let texto = "Transferencia realizada. Importe: $ 1.250.500,00. Nro. de operación: 7788990011. \
+                     Fecha: 10/03/2026 21:30 hs. CBU destino 2850590940090418135201";
+        assert_eq!(extraer(texto).as_deref(), Some("7788990011"));
+    }
+
+    #[test]
+    fn rescata_el_cupon_del_posnet() {
+        let texto = "VISA DEBITO\nTotal $ 23500,00\nCupón Nº 0452\nLote 012";
+        assert_eq!(extraer(texto).as_deref(), Some("0452"));
+    }
+
+    #[test]
+    fn no_toma_el_telefono_aunque_tenga_mas_digitos() {
+        assert_eq!(extraer("Operación 88776655\nConsultas al 11-5555-1234").as_deref(), Some("88776655"));
+        assert_eq!(extraer("Tel: 11-5555-1234\n88776655").as_deref(), Some("88776655"));
+        assert_eq!(extraer("+54 9 11 5555-1234\n88776655").as_deref(), Some("88776655"));
+    }
+
+    #[test]
+    fn no_toma_importes_ni_fechas() {
+        assert_eq!(extraer("Total $15000\nCupón 004512").as_deref(), Some("004512"));
+        assert_eq!(extraer("Monto de la operación: 15000\nOperación 3345566").as_deref(), Some("3345566"));
+        assert_eq!(extraer("Fecha de la operación: 10/03/2026 21:30"), None);
+        assert_eq!(extraer("Pagaste 1.500,00 el 10/03/2026"), None);
+    }
+
+    #[test]
+    fn sin_numero_de_operacion_no_devuelve_nada() {
+        assert_eq!(extraer("Alias: pizzeria.don.pepe\nCVU: 0000003100012345678901\nTitular: Juan Pérez"), None);
+        assert_eq!(extraer("Monto: $ 12.500"), None);
+    }
+}
@@ -0,0 +1,169 @@
+//! HU-157: Reportes y tablero como imagen PNG.
+//!
+//! Los dueños se pasan los números con los socios por WhatsApp, como foto.
+//! El backend dibuja el reporte (`GET /api/reportes/imagen`) y acá se guarda
+//! en `Imágenes/FoodFlow` del usuario y se copia al portapapeles, para
+//! pegarlo directo en WhatsApp Desktop o Web.
+//!
+//! Si el portapapeles falla, la imagen queda guardada igual: lo único que no
+//! puede fallar es el archivo.
+//!
+//! HU-169: El pedido lleva el empleado identificado en la terminal, que
+//! necesita el permiso de ver reportes.
+
+use log::{debug, info, warn};
+use serde::Deserialize;
+use std::path::PathBuf;
+use std::time::Duration;
+use tauri::Manager;
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+use crate::i18n::{t, Mensaje};
+use crate::permisos;
+use crate::{cliente_http, BACKEND_LOCAL};
+
+/// Carpeta dentro de Imágenes (o Descargas) donde quedan los PNG.
+const CARPETA: &str = "FoodFlow";
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Vista {
+    Ventas,
+    Caja,
+    Productos,
+}
+
+impl Vista {
+    fn parametro(self) -> &'static str {
+        match self {
+            Vista::Ventas => "VENTAS",
+            Vista::Caja => "CAJA",
+            Vista::Productos => "PRODUCTOS",
+        }
+    }
+}
+
+/// Ancho de la imagen: un ticket de 80 mm o la pantalla de un teléfono.
+#[derive(Debug, Deserialize, Clone, Copy, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Ancho {
+    Ticket,
+    #[default]
+    Telefono,
+}
+
+impl Ancho {
+    fn parametro(self) -> &'static str {
+        match self {
+            Ancho::Ticket => "TICKET",
+            Ancho::Telefono => "TELEFONO",
+        }
+    }
+}
+
+/// Días operativos en ISO (`YYYY-MM-DD`), `hasta` inclusivo.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Rango {
+    pub desde: String,
+    pub hasta: String,
+}
+
+/// Pide la imagen al backend, la guarda y la copia al portapapeles.
+///
+/// Devuelve el mensaje para mostrar al operador, con la ruta del archivo.
+pub async fn capturar(app: &tauri::AppHandle, vista: Vista, rango: &Rango, ancho: Ancho) -> Result<String, String> {
+    let desde = fecha_iso(&rango.desde)?;
+    let hasta = fecha_iso(&rango.hasta)?;
+
+    let client = cliente_http()?;
+
+    let url = format!(
+        "{}/api/reportes/imagen?vista={}&desde={}&hasta={}&ancho={}",
+        BACKEND_LOCAL,
+        vista.parametro(),
+        desde,
+        hasta,
+        ancho.parametro()
+    );
+    debug!("[ReporteImagen] GET {}", url);
+
+    let respuesta = permisos::con_empleado(client.get(&url).timeout(Duration::from_secs(30)))
+        .send()
+        .await
+        .map_err(|e| t(Mensaje::BackendLocalSinRespuesta { detalle: &e.to_string() }))?;
+    if !respuesta.status().is_success() {
+        let estado = respuesta.status().to_string();
+        // El backend explica en `message` por qué rechazó el período
+        let detalle = respuesta
+            .json::<serde_json::Value>()
+            .await
+            .ok()
+            .and_then(|v| v["message"].as_str().map(str::to_string))
+            .unwrap_or(estado);
+        return Err(t(Mensaje::ImagenReporteRechazada { detalle: &detalle }));
+    }
+    let png = respuesta
+        .bytes()
+        .await
+        .map_err(|e| t(Mensaje::BackendLocalRespuestaInvalida { detalle: &e.to_string() }))?;
+
+    let ruta = carpeta_destino(app)?.join(nombre_archivo(vista, desde, hasta));
+    std::fs::write(&ruta, &png).map_err(|e| {
+        t(Mensaje::ConfigNoEscrita { ruta: &format!("{:?}", ruta), detalle: &e.to_string() })
+    })?;
+    info!("[ReporteImagen] Guardada en {:?} ({} bytes)", ruta, png.len());
+
+    let copiada = match tauri::image::Image::from_bytes(&png)
+        .map_err(|e| e.to_string())
+        .and_then(|imagen| app.clipboard().write_image(&imagen).map_err(|e| e.to_string()))
+    {
+        Ok(()) => true,
+        Err(e) => {
+            warn!("[ReporteImagen] No se pudo copiar al portapapeles: {}", e);
+            false
+        }
+    };
+
+    let ruta = ruta.to_string_lossy();
+    Ok(t(if copiada {
+        Mensaje::ImagenReporteCopiada { ruta: &ruta }
+    } else {
+        Mensaje::ImagenReporteGuardada { ruta: &ruta }
+    }))
+}
+
+/// `Imágenes/FoodFlow`, o `Descargas/FoodFlow` si el SO no tiene carpeta de imágenes.
+fn carpeta_destino(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let base = app
+        .path()
+        .picture_dir()
+        .or_else(|_| app.path().download_dir())
+        .map_err(|e| t(Mensaje::DirectorioDatosNoResuelto { detalle: &e.to_string() }))?;
+    let carpeta = base.join(CARPETA);
+    std::fs::create_dir_all(&carpeta).map_err(|e| {
+        t(Mensaje::DirectorioNoCreado { ruta: &format!("{:?}", carpeta), detalle: &e.to_string() })
+    })?;
+    Ok(carpeta)
+}
+
+/// Solo `YYYY-MM-DD`: la fecha va tal cual en la URL y en el nombre del archivo.
+/// Si el día no existe (31 de febrero) lo rechaza el backend.
+fn fecha_iso(fecha: &str) -> Result<&str, String> {
+    let valida = fecha.len() == 10
+        && fecha.char_indices().all(|(i, c)| if i == 4 || i == 7 { c == '-' } else { c.is_ascii_digit() });
+    if valida {
+        Ok(fecha)
+    } else {
+        Err(t(Mensaje::FechaInvalida { fecha }))
+    }
+}
+
+/// `ventas-2026-10-13.png` o `caja-2026-10-01_2026-10-13.png`.
+fn nombre_archivo(vista: Vista, desde: &str, hasta: &str) -> String {
+    let vista = vista.parametro().to_lowercase();
+    if desde == hasta {
+        format!("{}-{}.png", vista, desde)
+    } else {
+        format!("{}-{}_{}.png", vista, desde, hasta)
+    }
+}
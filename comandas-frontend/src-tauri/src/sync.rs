@@ -0,0 +1,258 @@
+//! HU-107: Sincronización de ventas cerradas entre sucursales.
+//!
+//! Cada instalación tiene una identidad de sucursal (UUID generado en el
+//! primer arranque) que se le pasa al backend embebido por variables de
+//! entorno. Si la sincronización está habilitada, un loop en segundo plano:
+//!
+//! 1. Pide al backend local las ventas cerradas posteriores al último cursor
+//!    (`GET /api/sync/ventas-cerradas?desde=...&desdePedido=...`). El cursor
+//!    es el cierre y el pedido de la última venta aceptada, así las ventas
+//!    cerradas en el mismo instante no se pierden entre un lote y otro.
+//! 2. Envía el lote al consolidado (`POST {url_central}/api/sync/ventas`).
+//! 3. Guarda el cursor devuelto por el consolidado.
+//!
+//! El lote viaja tal cual lo arma el backend: este módulo no interpreta las
+//! ventas. Si el consolidado no responde, el cursor no avanza y el próximo
+//! ciclo reenvía el mismo lote (la recepción es idempotente).
+//!
+//! La configuración se persiste en `sync_config.json` dentro del directorio
+//! de datos de la app, igual que la de la impresora.
+
+use log::{info, warn, error, debug};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+use tauri::Manager;
+
+use crate::i18n::{t, Mensaje};
+use crate::{cliente_http, BACKEND_LOCAL};
+
+/// Espera de cada pedido al backend local y al consolidado.
+const ESPERA: Duration = Duration::from_secs(20);
+
+// ─── Configuración ────────────────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SyncConfig {
+    /// Si está en false, la identidad se mantiene pero no se envían ventas
+    #[serde(default)]
+    pub habilitado: bool,
+
+    /// URL base del consolidado (ej: http://192.168.1.50:8080)
+    #[serde(default)]
+    pub url_central: String,
+
+    /// Identidad estable de esta instalación. Se genera una sola vez.
+    #[serde(default)]
+    pub sucursal_id: String,
+
+    /// Nombre visible en el reporte comparativo (vacío = nombre del local)
+    #[serde(default)]
+    pub sucursal_nombre: String,
+
+    /// Cada cuántos segundos se intenta sincronizar
+    #[serde(default = "default_intervalo_segundos")]
+    pub intervalo_segundos: u64,
+
+    /// Fecha de cierre de la última venta aceptada por el consolidado
+    #[serde(default)]
+    pub ultimo_cursor: Option<String>,
+
+    /// Pedido de esa venta: desempata las ventas con el mismo cierre
+    #[serde(default)]
+    pub ultimo_cursor_pedido: Option<String>,
+}
+
+fn default_intervalo_segundos() -> u64 {
+    300
+}
+
+impl Default for SyncConfig {
+    fn default() -> Self {
+        Self {
+            habilitado: false,
+            url_central: String::new(),
+            sucursal_id: String::new(),
+            sucursal_nombre: String::new(),
+            intervalo_segundos: default_intervalo_segundos(),
+            ultimo_cursor: None,
+            ultimo_cursor_pedido: None,
+        }
+    }
+}
+
+/// Resultado de un ciclo de sincronización, devuelto al frontend.
+#[derive(Debug, Serialize, Clone)]
+pub struct ResultadoSync {
+    pub enviadas: usize,
+    pub cursor: Option<String>,
+    /// Resumen para el operador, en el idioma de la instalación (HU-154)
+    pub mensaje: String,
+}
+
+impl ResultadoSync {
+    fn new(enviadas: usize, cursor: Option<String>) -> Self {
+        let mensaje = if enviadas == 0 {
+            t(Mensaje::SyncSinVentasNuevas)
+        } else {
+            t(Mensaje::SyncVentasEnviadas { cantidad: enviadas, ultimo_cierre: cursor.as_deref() })
+        };
+        Self { enviadas, cursor, mensaje }
+    }
+}
+
+// ─── Persistencia de configuración ────────────────────────────────────────────
+
+fn config_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("sync_config.json"))
+        .map_err(|e| t(Mensaje::DirectorioDatosNoResuelto { detalle: &e.to_string() }))
+}
+
+/// Lee la configuración. Si todavía no hay identidad de sucursal, la genera
+/// y la persiste en el acto para que sea la misma en todos los arranques.
+pub fn load_config(app: &tauri::AppHandle) -> SyncConfig {
+    let mut config = match config_path(app).and_then(|path| {
+        debug!("[Sync] Leyendo config de: {:?}", path);
+        std::fs::read_to_string(&path).map_err(|e| format!("{:?} ({})", path, e))
+    }) {
+        Ok(json) => serde_json::from_str::<SyncConfig>(&json).unwrap_or_else(|e| {
+            error!("[Sync] Config corrupta: {}. Usando defaults.", e);
+            SyncConfig::default()
+        }),
+        Err(e) => {
+            info!("[Sync] No existe config en {}. Usando defaults.", e);
+            SyncConfig::default()
+        }
+    };
+
+    if config.sucursal_id.trim().is_empty() {
+        config.sucursal_id = uuid::Uuid::new_v4().to_string();
+        info!("[Sync] Identidad de sucursal generada: {}", config.sucursal_id);
+        if let Err(e) = save_config(app, &config) {
+            warn!("[Sync] No se pudo persistir la identidad generada: {}", e);
+        }
+    }
+
+    config
+}
+
+pub fn save_config(app: &tauri::AppHandle, config: &SyncConfig) -> Result<(), String> {
+    let path = config_path(app)?;
+
+    info!(
+        "[Sync] Guardando config en {:?}: habilitado={}, central={}, sucursal={}",
+        path, config.habilitado, config.url_central, config.sucursal_id
+    );
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| {
+            t(Mensaje::DirectorioNoCreado { ruta: &format!("{:?}", parent), detalle: &e.to_string() })
+        })?;
+    }
+
+    let json = serde_json::to_string_pretty(config)
+        .map_err(|e| t(Mensaje::ConfigNoSerializada { detalle: &e.to_string() }))?;
+
+    std::fs::write(&path, &json).map_err(|e| {
+        let msg = t(Mensaje::ConfigNoEscrita { ruta: &format!("{:?}", path), detalle: &e.to_string() });
+        error!("[Sync] {}", msg);
+        msg
+    })
+}
+
+// ─── Sincronización ───────────────────────────────────────────────────────────
+
+/// Ejecuta un ciclo completo: exportar del backend local y enviar al consolidado.
+pub async fn sincronizar(app: &tauri::AppHandle) -> Result<ResultadoSync, String> {
+    let mut config = load_config(app);
+    let central = config.url_central.trim().trim_end_matches('/').to_string();
+    if central.is_empty() {
+        return Err(t(Mensaje::SinUrlConsolidado));
+    }
+
+    let client = cliente_http()?;
+
+    // 1. Ventas cerradas desde el último cursor
+    let url_local = format!("{}/api/sync/ventas-cerradas", BACKEND_LOCAL);
+    let mut cursor = Vec::new();
+    if let Some(desde) = &config.ultimo_cursor {
+        cursor.push(("desde", desde));
+        if let Some(pedido) = &config.ultimo_cursor_pedido {
+            cursor.push(("desdePedido", pedido));
+        }
+    }
+    debug!("[Sync] GET {} {:?}", url_local, cursor);
+
+    let lote: serde_json::Value = client
+        .get(&url_local)
+        .query(&cursor)
+        .timeout(ESPERA)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| t(Mensaje::BackendLocalSinRespuesta { detalle: &e.to_string() }))?
+        .json()
+        .await
+        .map_err(|e| t(Mensaje::BackendLocalRespuestaInvalida { detalle: &e.to_string() }))?;
+
+    let cantidad = lote["ventas"].as_array().map(|v| v.len()).unwrap_or(0);
+    if cantidad == 0 {
+        debug!("[Sync] Sin ventas nuevas desde {:?}", config.ultimo_cursor);
+        return Ok(ResultadoSync::new(0, config.ultimo_cursor));
+    }
+
+    // 2. Envío al consolidado
+    let url_central = format!("{}/api/sync/ventas", central);
+    info!("[Sync] Enviando {} ventas a {}", cantidad, url_central);
+
+    let respuesta = client
+        .post(&url_central)
+        .timeout(ESPERA)
+        .json(&lote)
+        .send()
+        .await
+        .map_err(|e| t(Mensaje::ConsolidadoSinConexion { detalle: &e.to_string() }))?;
+
+    if !respuesta.status().is_success() {
+        let status = respuesta.status();
+        let cuerpo: serde_json::Value = respuesta.json().await.unwrap_or_default();
+        return Err(t(Mensaje::ConsolidadoRechazo {
+            estado: &status.to_string(),
+            detalle: cuerpo["message"].as_str(),
+        }));
+    }
+
+    let recepcion: serde_json::Value = respuesta
+        .json()
+        .await
+        .map_err(|e| t(Mensaje::ConsolidadoRespuestaInvalida { detalle: &e.to_string() }))?;
+
+    // 3. Avanzar el cursor solo con la confirmación del consolidado
+    if let Some(cursor) = recepcion["cursor"].as_str() {
+        config.ultimo_cursor = Some(cursor.to_string());
+        config.ultimo_cursor_pedido = recepcion["cursorPedidoId"].as_str().map(str::to_string);
+        save_config(app, &config)?;
+    }
+
+    info!("[Sync] Lote aceptado: {} ventas, cursor={:?}", cantidad, config.ultimo_cursor);
+    Ok(ResultadoSync::new(cantidad, config.ultimo_cursor))
+}
+
+/// Loop en segundo plano. Relee la configuración en cada vuelta, así que
+/// habilitar/deshabilitar desde la UI no requiere reiniciar la app.
+pub async fn iniciar_sincronizacion_periodica(app: tauri::AppHandle) {
+    loop {
+        let config = load_config(&app);
+        tokio::time::sleep(Duration::from_secs(config.intervalo_segundos.max(30))).await;
+
+        if !load_config(&app).habilitado {
+            continue;
+        }
+
+        if let Err(e) = sincronizar(&app).await {
+            warn!("[Sync] Ciclo fallido, se reintenta en el próximo intervalo: {}", e);
+        }
+    }
+}
@@ -2,6 +2,7 @@ import { useState, useEffect } from 'react';
 import { X, Printer, Check, RefreshCw } from 'lucide-react';
 import { useImpresoras, useImpresoraPredeterminada, useGuardarImpresoraPredeterminada } from '../hooks/useConfiguracion';
 import useToast from '../hooks/useToast';
+import ConfigAlertaMesasOlvidadas from '../features/salon/components/ConfigAlertaMesasOlvidadas';
 
 interface AjustesModalProps {
   onClose: () => void;
@@ -13,6 +14,8 @@ interface AjustesModalProps {
  * Sección principal: Configuración de impresora.
  * - Lista impresoras detectadas por el SO (vía backend javax.print)
  * - Permite seleccionar y guardar la impresora predeterminada
+ *
+ * HU-113: Alerta de mesas olvidadas (se guarda por separado).
  */
 export default function AjustesModal({ onClose }: AjustesModalProps) {
   const toast = useToast();
@@ -124,6 +127,11 @@ export default function AjustesModal({ onClose }: AjustesModalProps) {
                 </p>
               )}
             </div>
+
+            {/* Sección: Alerta de mesas olvidadas (HU-113) */}
+            <div className="pt-5 border-t border-gray-800">
+              <ConfigAlertaMesasOlvidadas />
+            </div>
           </div>
 
           {/* Footer */}
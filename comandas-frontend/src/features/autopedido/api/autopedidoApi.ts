@@ -0,0 +1,48 @@
+import apiClient, { API_BASE_URL } from '../../../lib/apiClient';
+import type { DestinoQr, ResolverSolicitudRequest, SolicitudAutopedido } from '../types';
+
+const RUTA_DESTINO: Record<DestinoQr, string> = {
+  AUTOPEDIDO: '/autopedido',
+  CARTA: '/carta',
+};
+
+/**
+ * API client del autopedido por QR (HU-126), lado salón.
+ * Consume /api/autopedido de AutopedidoController. La carta del cliente
+ * (carta.html) la sirve el backend y no pasa por acá.
+ *
+ * HU-127: los QR también pueden apuntar a la carta digital de solo lectura
+ * (menu.html), que expone /api/carta de CartaController.
+ */
+export const autopedidoApi = {
+  listarPendientes: async (): Promise<SolicitudAutopedido[]> => {
+    const response = await apiClient.get<SolicitudAutopedido[]>('/autopedido/solicitudes/pendientes');
+    return response.data;
+  },
+
+  confirmar: async (solicitudId: string, request: ResolverSolicitudRequest = {}): Promise<SolicitudAutopedido> => {
+    const response = await apiClient.post<SolicitudAutopedido>(
+      `/autopedido/solicitudes/${solicitudId}/confirmar`,
+      request,
+    );
+    return response.data;
+  },
+
+  rechazar: async (solicitudId: string, request: ResolverSolicitudRequest): Promise<SolicitudAutopedido> => {
+    const response = await apiClient.post<SolicitudAutopedido>(
+      `/autopedido/solicitudes/${solicitudId}/rechazar`,
+      request,
+    );
+    return response.data;
+  },
+
+  /** URL que abre el QR, para mostrarla debajo del código impreso */
+  obtenerEnlace: async (mesaId: string, destino: DestinoQr = 'AUTOPEDIDO'): Promise<string> => {
+    const response = await apiClient.get<{ url: string }>(`${RUTA_DESTINO[destino]}/mesas/${mesaId}/enlace`);
+    return response.data.url;
+  },
+
+  /** El PNG se usa directo en un <img>, sin pasar por axios */
+  urlQr: (mesaId: string, destino: DestinoQr = 'AUTOPEDIDO'): string =>
+    `${API_BASE_URL}${RUTA_DESTINO[destino]}/mesas/${mesaId}/qr`,
+};
@@ -0,0 +1,192 @@
+import { useState } from 'react';
+import { Check, Loader2, QrCode, X } from 'lucide-react';
+import { useConfirmarSolicitud, useRechazarSolicitud, useSolicitudesPendientes } from '../hooks/useAutopedido';
+import { useEnviarComandaCocina } from '../../salon/hooks/useMesas';
+import { imprimirEscPos } from '../../pedido/services/printerService';
+import useToast from '../../../hooks/useToast';
+import type { SolicitudAutopedido } from '../types';
+
+const MOTIVOS_RAPIDOS = ['Pedido duplicado', 'La mesa ya lo pidió al mozo', 'No lo hicieron ellos'];
+
+function minutosDesde(fecha: string): number {
+  return Math.max(0, Math.floor((Date.now() - new Date(fecha).getTime()) / 60_000));
+}
+
+interface TarjetaSolicitudProps {
+  solicitud: SolicitudAutopedido;
+}
+
+/**
+ * Un pedido del QR con sus ítems y las dos salidas posibles.
+ *
+ * Confirmar carga los ítems en la mesa y manda la comanda a cocina en el
+ * mismo paso: el mozo ya lo revisó con el cliente, no hace falta abrir el POS.
+ */
+function TarjetaSolicitud({ solicitud }: TarjetaSolicitudProps) {
+  const toast = useToast();
+  const confirmar = useConfirmarSolicitud();
+  const rechazar = useRechazarSolicitud();
+  const enviarComandaCocina = useEnviarComandaCocina();
+  const [rechazando, setRechazando] = useState(false);
+  const [motivo, setMotivo] = useState('');
+
+  const ocupado = confirmar.isPending || rechazar.isPending || enviarComandaCocina.isPending;
+
+  const handleConfirmar = () => {
+    confirmar.mutate(
+      { solicitudId: solicitud.id },
+      {
+        onSuccess: () => {
+          enviarComandaCocina.mutate(solicitud.mesaId, {
+            onSuccess: async (data) => {
+              toast.success(`Mesa ${solicitud.numeroMesa}: pedido del QR enviado a cocina`);
+              const result = await imprimirEscPos(data.escPosBase64, `Comanda Mesa ${solicitud.numeroMesa}`);
+              if (!result.success) {
+                toast.error(`Error de impresora: ${result.message}`);
+              }
+            },
+            onError: (error: any) => {
+              // Los ítems ya están en la mesa: se puede reenviar desde el POS
+              toast.warning(
+                error?.response?.data?.message
+                  || `Mesa ${solicitud.numeroMesa}: pedido cargado, pero no se pudo mandar a cocina`,
+              );
+            },
+          });
+        },
+        onError: (error: any) => {
+          toast.error(error?.response?.data?.message || 'No se pudo confirmar el pedido');
+        },
+      },
+    );
+  };
+
+  const handleRechazar = (motivoElegido: string) => {
+    rechazar.mutate(
+      { solicitudId: solicitud.id, motivo: motivoElegido },
+      {
+        onSuccess: () => toast.info(`Mesa ${solicitud.numeroMesa}: pedido del QR rechazado`),
+        onError: (error: any) => {
+          toast.error(error?.response?.data?.message || 'No se pudo rechazar el pedido');
+        },
+      },
+    );
+  };
+
+  return (
+    <li className="bg-neutral-900 border border-amber-700/50 rounded-xl p-3 space-y-2">
+      <div className="flex items-baseline justify-between gap-2">
+        <span className="text-sm font-bold text-gray-100">
+          Mesa {solicitud.numeroMesa}
+          {solicitud.nombreCliente && (
+            <span className="font-normal text-gray-400"> · {solicitud.nombreCliente}</span>
+          )}
+        </span>
+        <span className="text-xs text-amber-400 shrink-0">hace {minutosDesde(solicitud.creadaEn)} min</span>
+      </div>
+
+      <ul className="space-y-0.5 text-sm text-gray-300">
+        {solicitud.items.map((item, i) => (
+          <li key={`${item.productoId}-${i}`}>
+            <span className="font-mono text-gray-400">{item.cantidad}×</span> {item.nombreProducto}
+            {item.observacion && <span className="block pl-6 text-xs italic text-gray-500">{item.observacion}</span>}
+          </li>
+        ))}
+      </ul>
+
+      <p className="text-right font-mono text-sm text-gray-200">
+        $ {solicitud.total.toLocaleString('es-AR')}
+      </p>
+
+      {rechazando ? (
+        <div className="space-y-2">
+          <div className="flex flex-wrap gap-1">
+            {MOTIVOS_RAPIDOS.map((m) => (
+              <button
+                key={m}
+                type="button"
+                disabled={ocupado}
+                onClick={() => handleRechazar(m)}
+                className="px-2 py-1 rounded-md text-xs bg-neutral-800 text-gray-300 hover:bg-neutral-700 disabled:opacity-50"
+              >
+                {m}
+              </button>
+            ))}
+          </div>
+          <div className="flex gap-2">
+            <input
+              value={motivo}
+              onChange={(e) => setMotivo(e.target.value)}
+              maxLength={140}
+              placeholder="Otro motivo…"
+              className="flex-1 min-w-0 h-9 px-3 rounded-lg bg-neutral-800 border border-neutral-700 text-sm text-gray-100 placeholder:text-gray-500 focus:outline-none focus:border-red-500"
+            />
+            <button
+              type="button"
+              disabled={ocupado || !motivo.trim()}
+              onClick={() => handleRechazar(motivo.trim())}
+              className="px-3 h-9 rounded-lg bg-red-600 text-white text-sm font-semibold hover:bg-red-500 disabled:opacity-50"
+            >
+              Rechazar
+            </button>
+            <button
+              type="button"
+              onClick={() => setRechazando(false)}
+              className="px-2 h-9 rounded-lg text-gray-400 hover:text-gray-200"
+              aria-label="Cancelar rechazo"
+            >
+              <X size={16} />
+            </button>
+          </div>
+        </div>
+      ) : (
+        <div className="flex gap-2">
+          <button
+            type="button"
+            disabled={ocupado}
+            onClick={handleConfirmar}
+            className="flex-1 flex items-center justify-center gap-1.5 h-10 rounded-lg bg-emerald-600 text-white text-sm font-semibold hover:bg-emerald-500 active:scale-95 disabled:opacity-60 transition-all"
+          >
+            {ocupado ? <Loader2 size={16} className="animate-spin" /> : <Check size={16} strokeWidth={2.5} />}
+            Confirmar y mandar
+          </button>
+          <button
+            type="button"
+            disabled={ocupado}
+            onClick={() => setRechazando(true)}
+            className="px-3 h-10 rounded-lg bg-neutral-800 text-gray-300 text-sm font-semibold hover:bg-neutral-700 disabled:opacity-60"
+          >
+            Rechazar
+          </button>
+        </div>
+      )}
+    </li>
+  );
+}
+
+/**
+ * Bandeja de pedidos hechos por los clientes desde el QR (HU-126).
+ *
+ * Se muestra arriba del resumen del salón solo mientras haya pedidos
+ * esperando. Nada de lo que pide el cliente llega a cocina hasta que un
+ * mozo lo confirma acá.
+ */
+export default function PanelAutopedidos() {
+  const { data: pendientes } = useSolicitudesPendientes();
+
+  if (!pendientes || pendientes.length === 0) return null;
+
+  return (
+    <section className="border-b border-neutral-800 bg-amber-950/20 p-3 space-y-2 max-h-[55%] overflow-y-auto">
+      <h2 className="flex items-center gap-2 text-xs font-bold uppercase tracking-wider text-amber-400">
+        <QrCode size={14} />
+        Pedidos por QR ({pendientes.length})
+      </h2>
+      <ul className="space-y-2">
+        {pendientes.map((solicitud) => (
+          <TarjetaSolicitud key={solicitud.id} solicitud={solicitud} />
+        ))}
+      </ul>
+    </section>
+  );
+}
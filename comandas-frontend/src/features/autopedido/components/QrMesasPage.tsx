@@ -0,0 +1,113 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Loader2, Printer } from 'lucide-react';
+import { useMesas } from '../../salon/hooks/useMesas';
+import { useEnlaceMesa } from '../hooks/useAutopedido';
+import { autopedidoApi } from '../api/autopedidoApi';
+import type { DestinoQr } from '../types';
+import type { Mesa } from '../../salon/types';
+
+const DESTINOS: { valor: DestinoQr; etiqueta: string; leyenda: string; ayuda: string }[] = [
+  {
+    valor: 'AUTOPEDIDO',
+    etiqueta: 'Carta para pedir',
+    leyenda: 'Escaneá y hacé tu pedido',
+    ayuda: 'Los pedidos que hagan los clientes llegan al salón para que el mozo los confirme.',
+  },
+  {
+    valor: 'CARTA',
+    etiqueta: 'Solo carta',
+    leyenda: 'Escaneá y mirá la carta',
+    ayuda: 'Carta con fotos y precios del catálogo; se actualiza sola con cada cambio de precio o producto sin stock.',
+  },
+];
+
+function TarjetaQr({ mesa, destino }: { mesa: Mesa; destino: (typeof DESTINOS)[number] }) {
+  const { data: enlace } = useEnlaceMesa(mesa.id, destino.valor);
+
+  return (
+    <div className="break-inside-avoid flex flex-col items-center gap-2 p-4 rounded-2xl bg-white text-neutral-900 border border-neutral-300">
+      <span className="text-2xl font-bold">Mesa {mesa.numero}</span>
+      <img
+        src={autopedidoApi.urlQr(mesa.id, destino.valor)}
+        alt={`QR de la mesa ${mesa.numero}`}
+        className="w-48 h-48"
+      />
+      <span className="text-sm font-semibold">{destino.leyenda}</span>
+      <span className="text-[10px] text-neutral-500 break-all text-center">{enlace ?? ' '}</span>
+    </div>
+  );
+}
+
+/**
+ * Hoja de QR para imprimir y pegar en cada mesa (HU-126).
+ *
+ * El QR apunta a la carta que sirve el backend en la red del local. Si cambia
+ * la IP del servidor (o se configura app.autopedido.url-base), hay que
+ * reimprimirlos: la URL debajo de cada código sirve para comprobarlo.
+ *
+ * HU-127: el QR puede llevar a la carta digital de solo lectura en lugar de
+ * la carta para pedir. Como se arma desde el catálogo en cada consulta, no
+ * hace falta reimprimir cuando cambian los precios.
+ */
+export default function QrMesasPage() {
+  const { data: mesas = [], isLoading } = useMesas();
+  const [destino, setDestino] = useState(DESTINOS[0]);
+  const ordenadas = [...mesas].sort((a, b) => a.numero - b.numero);
+
+  return (
+    <section className="max-w-6xl mx-auto px-6 py-6 space-y-6 print:p-0 print:max-w-none">
+      <header className="flex items-center justify-between print:hidden">
+        <div className="flex items-center gap-3">
+          <Link
+            to="/"
+            className="p-2 rounded-lg text-gray-400 hover:text-gray-100 hover:bg-neutral-800 transition-colors"
+            aria-label="Volver al salón"
+          >
+            <ArrowLeft size={20} />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">QR de las mesas</h1>
+            <p className="text-sm text-gray-500">{destino.ayuda}</p>
+          </div>
+        </div>
+        <div className="flex items-center gap-1 p-1 rounded-lg bg-neutral-900 border border-neutral-800 ml-auto mr-3">
+          {DESTINOS.map((d) => (
+            <button
+              key={d.valor}
+              onClick={() => setDestino(d)}
+              className={[
+                'px-3 h-8 rounded-md text-sm font-semibold transition-colors',
+                destino.valor === d.valor ? 'bg-neutral-700 text-gray-100' : 'text-gray-500 hover:text-gray-300',
+              ].join(' ')}
+            >
+              {d.etiqueta}
+            </button>
+          ))}
+        </div>
+        <button
+          onClick={() => window.print()}
+          disabled={ordenadas.length === 0}
+          className="flex items-center gap-2 px-4 h-10 bg-red-600 text-white rounded-lg text-sm font-semibold hover:bg-red-500 active:scale-95 disabled:opacity-60 transition-all"
+        >
+          <Printer size={16} />
+          Imprimir
+        </button>
+      </header>
+
+      {isLoading ? (
+        <div className="flex justify-center py-20">
+          <Loader2 size={28} className="animate-spin text-gray-500" />
+        </div>
+      ) : ordenadas.length === 0 ? (
+        <p className="text-center text-gray-500 py-20">Todavía no hay mesas en el salón.</p>
+      ) : (
+        <div className="grid grid-cols-2 md:grid-cols-3 lg:grid-cols-4 gap-4 print:grid-cols-3">
+          {ordenadas.map((mesa) => (
+            <TarjetaQr key={mesa.id} mesa={mesa} destino={destino} />
+          ))}
+        </div>
+      )}
+    </section>
+  );
+}
@@ -0,0 +1,72 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { autopedidoApi } from '../api/autopedidoApi';
+import type { DestinoQr, ResolverSolicitudRequest, SolicitudAutopedido } from '../types';
+
+export const autopedidoKeys = {
+  pendientes: ['autopedido', 'pendientes'] as const,
+  enlace: (mesaId: string, destino: DestinoQr) => ['autopedido', 'enlace', destino, mesaId] as const,
+};
+
+/**
+ * Pedidos del QR esperando al mozo.
+ * Los eventos en tiempo real invalidan la query apenas llega o se resuelve
+ * uno; el polling queda de respaldo si se corta la conexión SSE.
+ *
+ * queryKey: ['autopedido', 'pendientes']
+ */
+export function useSolicitudesPendientes() {
+  return useQuery<SolicitudAutopedido[]>({
+    queryKey: autopedidoKeys.pendientes,
+    queryFn: () => autopedidoApi.listarPendientes(),
+    refetchInterval: 30_000,
+    staleTime: 0,
+  });
+}
+
+/**
+ * Confirmar pasa los ítems al pedido de la mesa (abriéndola si estaba libre),
+ * así que también se refrescan el salón y el pedido de esa mesa.
+ */
+export function useConfirmarSolicitud() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ solicitudId, ...request }: ResolverSolicitudRequest & { solicitudId: string }) =>
+      autopedidoApi.confirmar(solicitudId, request),
+    onSuccess: (solicitud) => {
+      queryClient.invalidateQueries({ queryKey: autopedidoKeys.pendientes });
+      queryClient.invalidateQueries({ queryKey: ['mesas'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['pedido', solicitud.mesaId] });
+    },
+    onError: (error: unknown) => {
+      console.error('[useConfirmarSolicitud] Error al confirmar pedido del QR:', error);
+    },
+  });
+}
+
+export function useRechazarSolicitud() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ solicitudId, ...request }: ResolverSolicitudRequest & { solicitudId: string }) =>
+      autopedidoApi.rechazar(solicitudId, request),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: autopedidoKeys.pendientes });
+    },
+    onError: (error: unknown) => {
+      console.error('[useRechazarSolicitud] Error al rechazar pedido del QR:', error);
+    },
+  });
+}
+
+/**
+ * URL de la carta de una mesa. Depende de la IP del servidor en la red del
+ * local, así que no se cachea más allá de la pantalla de impresión.
+ */
+export function useEnlaceMesa(mesaId: string, destino: DestinoQr = 'AUTOPEDIDO') {
+  return useQuery<string>({
+    queryKey: autopedidoKeys.enlace(mesaId, destino),
+    queryFn: () => autopedidoApi.obtenerEnlace(mesaId, destino),
+    staleTime: 0,
+  });
+}
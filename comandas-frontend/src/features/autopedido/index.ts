@@ -0,0 +1,32 @@
+/**
+ * Módulo Autopedido — pedidos que arman los clientes desde el QR de la mesa
+ * y que el mozo confirma antes de mandarlos a cocina (HU-126).
+ *
+ * @example
+ * import { PanelAutopedidos, QrMesasPage } from '@/features/autopedido';
+ */
+
+// Tipos
+export type {
+  EstadoSolicitudAutopedido,
+  ItemSolicitudAutopedido,
+  SolicitudAutopedido,
+  ResolverSolicitudRequest,
+  DestinoQr,
+} from './types';
+
+// Hooks
+export {
+  autopedidoKeys,
+  useSolicitudesPendientes,
+  useConfirmarSolicitud,
+  useRechazarSolicitud,
+  useEnlaceMesa,
+} from './hooks/useAutopedido';
+
+// Componentes
+export { default as PanelAutopedidos } from './components/PanelAutopedidos';
+export { default as QrMesasPage } from './components/QrMesasPage';
+
+// API
+export { autopedidoApi } from './api/autopedidoApi';
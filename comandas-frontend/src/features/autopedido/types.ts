@@ -0,0 +1,45 @@
+// ─── Autopedido por QR (HU-126) ──────────────────────────────────────────────
+
+/**
+ * El cliente arma el pedido desde el QR de la mesa y queda PENDIENTE hasta
+ * que el mozo lo confirma (pasa al pedido de la mesa) o lo rechaza.
+ */
+export type EstadoSolicitudAutopedido = 'PENDIENTE' | 'CONFIRMADA' | 'RECHAZADA';
+
+export interface ItemSolicitudAutopedido {
+  productoId: string;
+  nombreProducto: string;
+  /** Precio que vio el cliente en la carta */
+  precioUnitario: number;
+  cantidad: number;
+  observacion: string | null;
+}
+
+/**
+ * También es el payload de los eventos en tiempo real
+ * "autopedido-nuevo" y "autopedido-resuelto".
+ */
+export interface SolicitudAutopedido {
+  id: string;
+  mesaId: string;
+  numeroMesa: number;
+  /** Opcional: ayuda a saber a quién llevarle qué */
+  nombreCliente: string | null;
+  creadaEn: string;
+  estado: EstadoSolicitudAutopedido;
+  motivoRechazo: string | null;
+  total: number;
+  items: ItemSolicitudAutopedido[];
+}
+
+export interface ResolverSolicitudRequest {
+  mozoId?: string;
+  /** Obligatorio al rechazar: se le muestra al cliente en la carta */
+  motivo?: string;
+}
+
+/**
+ * HU-127: A dónde lleva el QR impreso de la mesa.
+ * AUTOPEDIDO abre la carta para pedir; CARTA, la carta digital de solo lectura.
+ */
+export type DestinoQr = 'AUTOPEDIDO' | 'CARTA';
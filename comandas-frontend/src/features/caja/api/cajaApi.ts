@@ -4,7 +4,22 @@
  * Mapea los endpoints del backend:
  *   GET  /api/caja/reporte?fecha=YYYY-MM-DD  → Reporte / arqueo diario
  *   POST /api/caja/egresos                   → Registrar egreso de caja
+ *   GET  /api/caja/egresos/reporte-mensual   → Egresos por categoría vs mes anterior (HU-130)
+ *   GET|POST|PUT /api/caja/categorias-egreso → Categorías de egresos (HU-130)
  *   POST /api/caja/cierre-jornada            → Cierre de jornada diaria
+ *   GET  /api/caja/turnos                    → Turnos de la jornada abierta (HU-106)
+ *   POST /api/caja/turnos/abrir|cerrar       → Apertura / cierre de turno (HU-106)
+ *   GET  /api/caja/turnos/saldo-teorico      → Esperado del turno abierto (HU-123)
+ *   GET  /api/caja/turnos/diferencias        → Faltantes y sobrantes por cajero (HU-129)
+ *   GET|POST /api/caja/cajas                 → Cajas del local / registrar esta terminal (HU-159)
+ *   POST /api/caja/cajas/{id}/abrir          → Fondo inicial de la caja en la jornada (HU-159)
+ *   GET|PUT /api/caja/series                 → Numeración de comprobantes por caja (HU-166)
+ *   GET|PUT /api/caja/recargos               → Recargos por medio de pago (HU-143)
+ *   GET|PUT /api/caja/tipos-cambio           → Tipos de cambio del día (HU-151)
+ *   GET|PUT /api/caja/redondeo               → Redondeo del efectivo (HU-152)
+ *   GET|PUT /api/caja/jornada-comercial      → Límite de jornada comercial (HU-153)
+ *   GET  /api/caja/datos-transferencia       → Alias y CVU del local (HU-156)
+ *   GET  /api/reportes/imagen                → Ventas, caja o productos como PNG (HU-157)
  *
  * Patrón: devuelve `response.data` directamente (sin wrapper AxiosResponse)
  * para que TanStack Query trabaje con el tipo de dominio puro.
@@ -14,7 +29,7 @@
  */
 
 import apiClient from '../../../lib/apiClient';
-import type { AbrirCajaRequest, AbrirCajaResponse, EgresoRequest, EgresoResponse, EstadoCajaResponse, IngresoRequest, IngresoResponse, ReporteCajaResponse, DetallePedidoCerrado, CorreccionPedidoRequest, JornadaResumen, CierreJornadaResponse, ProductoVendidoReporte } from '../types';
+import type { AbrirCajaRequest, AbrirCajaResponse, EgresoRequest, EgresoResponse, EstadoCajaResponse, IngresoRequest, IngresoResponse, ReporteCajaResponse, DetallePedidoCerrado, CorreccionPedidoRequest, DevolucionRequest, DevolucionesPedido, JornadaResumen, CierreJornadaResponse, ProductoVendidoReporte, TurnoCaja, AbrirTurnoRequest, CerrarTurnoRequest, SaldoTeoricoTurno, DenominacionContada, ReporteDiferenciasCaja, CategoriaEgreso, CategoriaEgresoRequest, ReporteEgresosMensual, RecargosMedioPago, TiposCambio, TiposCambioRequest, RedondeoEfectivo, JornadaComercial, DatosTransferencia, ReporteImagenRequest, CajaLocal, ArqueoCaja, SerieComprobantes, SerieComprobantesRequest } from '../types';
 
 /**
  * Blindaje contra respuestas paginadas o envueltas.
@@ -83,6 +98,170 @@ export const cajaApi = {
     return response.data;
   },
 
+  // ─── HU-130: Categorías de egresos ─────────────────────────────────────────
+
+  /**
+   * Categorías del local, activas e inactivas.
+   * La primera consulta crea las de uso habitual.
+   *
+   * GET /api/caja/categorias-egreso
+   */
+  listarCategoriasEgreso: async (): Promise<CategoriaEgreso[]> => {
+    const response = await apiClient.get('/caja/categorias-egreso');
+    return ensureArray<CategoriaEgreso>(response.data);
+  },
+
+  /**
+   * POST /api/caja/categorias-egreso
+   *
+   * Errores esperados:
+   *   - HTTP 400: Ya existe una categoría con ese nombre
+   */
+  crearCategoriaEgreso: async (data: CategoriaEgresoRequest): Promise<CategoriaEgreso> => {
+    const response = await apiClient.post<CategoriaEgreso>('/caja/categorias-egreso', data);
+    return response.data;
+  },
+
+  /**
+   * Renombra o activa/desactiva una categoría.
+   *
+   * PUT /api/caja/categorias-egreso/{id}
+   */
+  actualizarCategoriaEgreso: async (id: string, data: CategoriaEgresoRequest): Promise<CategoriaEgreso> => {
+    const response = await apiClient.put<CategoriaEgreso>(`/caja/categorias-egreso/${id}`, data);
+    return response.data;
+  },
+
+  // ─── Recargos por medio de pago (HU-143) ────────────────────────────────────
+
+  /**
+   * GET /api/caja/recargos
+   */
+  obtenerRecargos: async (): Promise<RecargosMedioPago> => {
+    const response = await apiClient.get<RecargosMedioPago>('/caja/recargos');
+    return response.data;
+  },
+
+  /**
+   * Reemplaza los recargos del local. Los medios que no vienen quedan sin recargo.
+   *
+   * PUT /api/caja/recargos
+   *
+   * Errores esperados:
+   *   - HTTP 400: Porcentaje fuera de rango o medio que no admite recargo
+   */
+  guardarRecargos: async (data: RecargosMedioPago): Promise<RecargosMedioPago> => {
+    const response = await apiClient.put<RecargosMedioPago>('/caja/recargos', data);
+    return response.data;
+  },
+
+  // ─── Tipos de cambio (HU-151) ───────────────────────────────────────────────
+
+  /**
+   * Cotizaciones de un día operativo (hoy si se omite la fecha).
+   *
+   * GET /api/caja/tipos-cambio?fecha=YYYY-MM-DD
+   */
+  obtenerTiposCambio: async (fecha?: string): Promise<TiposCambio> => {
+    const response = await apiClient.get<TiposCambio>('/caja/tipos-cambio', {
+      params: fecha ? { fecha } : undefined,
+    });
+    return response.data;
+  },
+
+  /**
+   * Reemplaza las cotizaciones del día. Las monedas que no vienen no se aceptan ese día.
+   *
+   * PUT /api/caja/tipos-cambio
+   *
+   * Errores esperados:
+   *   - HTTP 400: Cotización no positiva, con más de cuatro decimales o del peso
+   */
+  guardarTiposCambio: async (data: TiposCambioRequest): Promise<TiposCambio> => {
+    const response = await apiClient.put<TiposCambio>('/caja/tipos-cambio', data);
+    return response.data;
+  },
+
+  // ─── Redondeo del efectivo (HU-152) ─────────────────────────────────────────
+
+  /**
+   * GET /api/caja/redondeo
+   */
+  obtenerRedondeoEfectivo: async (): Promise<RedondeoEfectivo> => {
+    const response = await apiClient.get<RedondeoEfectivo>('/caja/redondeo');
+    return response.data;
+  },
+
+  /**
+   * Reemplaza la regla de redondeo. Con multiplo null el efectivo se cobra al centavo.
+   *
+   * PUT /api/caja/redondeo
+   *
+   * Errores esperados:
+   *   - HTTP 400: Múltiplo negativo, mayor a $1000 o con más de dos decimales
+   */
+  guardarRedondeoEfectivo: async (data: RedondeoEfectivo): Promise<RedondeoEfectivo> => {
+    const response = await apiClient.put<RedondeoEfectivo>('/caja/redondeo', data);
+    return response.data;
+  },
+
+  // ─── Jornada comercial (HU-153) ─────────────────────────────────────────────
+
+  /**
+   * GET /api/caja/jornada-comercial
+   */
+  obtenerJornadaComercial: async (): Promise<JornadaComercial> => {
+    const response = await apiClient.get<JornadaComercial>('/caja/jornada-comercial');
+    return response.data;
+  },
+
+  /**
+   * Cambia la hora de corte. Vale para las jornadas que se abran y los reportes
+   * que se consulten desde ahora.
+   *
+   * PUT /api/caja/jornada-comercial
+   *
+   * Errores esperados:
+   *   - HTTP 400: Hora de corte desde el mediodía
+   */
+  guardarJornadaComercial: async (horaCorte: string): Promise<JornadaComercial> => {
+    const response = await apiClient.put<JornadaComercial>('/caja/jornada-comercial', { horaCorte });
+    return response.data;
+  },
+
+  // ─── Datos para cobrar por transferencia (HU-156) ───────────────────────────
+
+  /**
+   * GET /api/caja/datos-transferencia
+   */
+  obtenerDatosTransferencia: async (): Promise<DatosTransferencia> => {
+    const response = await apiClient.get<DatosTransferencia>('/caja/datos-transferencia');
+    return response.data;
+  },
+
+  /**
+   * GET /api/reportes/imagen?vista=VENTAS&desde=YYYY-MM-DD&hasta=YYYY-MM-DD&ancho=TELEFONO
+   */
+  descargarReporteImagen: async (request: ReporteImagenRequest): Promise<Blob> => {
+    const response = await apiClient.get('/reportes/imagen', {
+      params: request,
+      responseType: 'blob',
+    });
+    return response.data as Blob;
+  },
+
+  /**
+   * Egresos del mes por categoría contra el mes anterior.
+   *
+   * GET /api/caja/egresos/reporte-mensual?periodo=YYYY-MM
+   */
+  obtenerReporteEgresosMensual: async (periodo: string): Promise<ReporteEgresosMensual> => {
+    const response = await apiClient.get<ReporteEgresosMensual>('/caja/egresos/reporte-mensual', {
+      params: { periodo },
+    });
+    return { ...response.data, categorias: ensureArray(response.data.categorias) };
+  },
+
   /**
    * Registrar un ingreso manual de caja (entrada de efectivo).
    *
@@ -169,6 +348,34 @@ export const cajaApi = {
     return response.data;
   },
 
+  /**
+   * HU-121: Devoluciones registradas sobre un pedido cerrado.
+   *
+   * GET /api/caja/pedidos/{pedidoId}/devoluciones
+   */
+  obtenerDevoluciones: async (pedidoId: string): Promise<DevolucionesPedido> => {
+    const response = await apiClient.get<DevolucionesPedido>(
+      `/caja/pedidos/${pedidoId}/devoluciones`,
+    );
+    return response.data;
+  },
+
+  /**
+   * HU-121: Registra una devolución post-cierre autorizada con PIN de encargado.
+   *
+   * POST /api/caja/pedidos/{pedidoId}/devoluciones
+   */
+  registrarDevolucion: async (
+    pedidoId: string,
+    data: DevolucionRequest,
+  ): Promise<DevolucionesPedido> => {
+    const response = await apiClient.post<DevolucionesPedido>(
+      `/caja/pedidos/${pedidoId}/devoluciones`,
+      data,
+    );
+    return response.data;
+  },
+
   // ─── Historial de Jornadas ──────────────────────────────────────────────────
 
   /**
@@ -190,19 +397,168 @@ export const cajaApi = {
   /**
    * Obtener el desglose de ventas por producto para una fecha operativa.
    *
-   * GET /api/caja/reportes/productos?fecha=YYYY-MM-DD
+   * GET /api/caja/reportes/productos?fecha=YYYY-MM-DD&desglosarCombos=false
    *
    * Solo considera pedidos con estado CERRADO.
    * Retorna productos ordenados por total recaudado descendente.
    *
    * @param fecha - Fecha operativa del reporte (YYYY-MM-DD)
+   * @param desglosarCombos - HU-103: si true, los combos se reportan por componente
    */
-  obtenerVentasProductos: async (fecha: string): Promise<ProductoVendidoReporte[]> => {
+  obtenerVentasProductos: async (
+    fecha: string,
+    desglosarCombos = false,
+  ): Promise<ProductoVendidoReporte[]> => {
     const response = await apiClient.get('/caja/reportes/productos', {
-      params: { fecha },
+      params: { fecha, desglosarCombos },
     });
     return ensureArray<ProductoVendidoReporte>(response.data);
   },
+  // ─── HU-106: Turnos de caja ───────────────────────────────────────────────
+
+  /**
+   * Turnos de la jornada abierta, en orden de apertura.
+   *
+   * GET /api/caja/turnos
+   */
+  obtenerTurnos: async (): Promise<TurnoCaja[]> => {
+    const response = await apiClient.get('/caja/turnos');
+    return ensureArray<TurnoCaja>(response.data);
+  },
+
+  /**
+   * Abre un turno con el efectivo inicial del cajón.
+   *
+   * POST /api/caja/turnos/abrir
+   *
+   * Respuestas del backend:
+   *   - HTTP 201: Turno abierto
+   *   - HTTP 409: No hay jornada abierta, ya hay un turno abierto o el tipo ya se usó
+   */
+  abrirTurno: async (data: AbrirTurnoRequest): Promise<TurnoCaja> => {
+    const response = await apiClient.post<TurnoCaja>('/caja/turnos/abrir', data);
+    return response.data;
+  },
+
+  /**
+   * Cierra el turno abierto con el efectivo contado por el cajero.
+   *
+   * POST /api/caja/turnos/cerrar
+   *
+   * El backend congela el arqueo y devuelve esperado, declarado y diferencia.
+   * HU-123: Si se envía el conteo por denominación, el declarado es su suma.
+   */
+  cerrarTurno: async (data: CerrarTurnoRequest): Promise<TurnoCaja> => {
+    const response = await apiClient.post<TurnoCaja>('/caja/turnos/cerrar', data);
+    return { ...response.data, denominaciones: ensureArray<DenominacionContada>(response.data.denominaciones) };
+  },
+
+  /**
+   * Saldo teórico del turno abierto, sin cerrarlo (HU-123).
+   *
+   * GET /api/caja/turnos/saldo-teorico
+   *
+   * Errores esperados:
+   *   - HTTP 409: No hay turno abierto
+   */
+  obtenerSaldoTeoricoTurno: async (): Promise<SaldoTeoricoTurno> => {
+    const response = await apiClient.get<SaldoTeoricoTurno>('/caja/turnos/saldo-teorico');
+    return response.data;
+  },
+
+  /**
+   * Consolidado de turnos de una jornada (para el historial).
+   *
+   * GET /api/caja/jornadas/{jornadaId}/turnos
+   */
+  obtenerTurnosJornada: async (jornadaId: string): Promise<CierreJornadaResponse> => {
+    const response = await apiClient.get<CierreJornadaResponse>(`/caja/jornadas/${jornadaId}/turnos`);
+    return {
+      ...response.data,
+      turnos: ensureArray<TurnoCaja>(response.data.turnos),
+      cajas: ensureArray<ArqueoCaja>(response.data.cajas),
+    };
+  },
+
+  /**
+   * Faltantes y sobrantes de los turnos cerrados en el rango, por cajero (HU-129).
+   *
+   * GET /api/caja/turnos/diferencias?desde=YYYY-MM-DD&hasta=YYYY-MM-DD
+   */
+  obtenerReporteDiferencias: async (desde: string, hasta: string): Promise<ReporteDiferenciasCaja> => {
+    const response = await apiClient.get<ReporteDiferenciasCaja>('/caja/turnos/diferencias', {
+      params: { desde, hasta },
+    });
+    return {
+      ...response.data,
+      cajeros: ensureArray(response.data.cajeros),
+      diferencias: ensureArray(response.data.diferencias),
+    };
+  },
+
+  // ─── HU-159: Cajas del local ────────────────────────────────────────────────
+
+  /**
+   * Cajas del local con su apertura en la jornada en curso.
+   *
+   * GET /api/caja/cajas
+   */
+  obtenerCajas: async (): Promise<CajaLocal[]> => {
+    const response = await apiClient.get('/caja/cajas');
+    return ensureArray<CajaLocal>(response.data);
+  },
+
+  /**
+   * Registra una terminal como caja del local.
+   *
+   * POST /api/caja/cajas
+   *
+   * Errores esperados:
+   *   - HTTP 400: Nombre vacío o ya usado por otra caja
+   */
+  registrarCaja: async (nombre: string): Promise<CajaLocal> => {
+    const response = await apiClient.post<CajaLocal>('/caja/cajas', { nombre });
+    return response.data;
+  },
+
+  /**
+   * Declara el fondo inicial de una caja en la jornada en curso.
+   *
+   * POST /api/caja/cajas/{cajaId}/abrir
+   *
+   * Errores esperados:
+   *   - HTTP 409: No hay jornada abierta o la caja ya se abrió
+   */
+  abrirCajaLocal: async (cajaId: string, montoInicial: number): Promise<CajaLocal> => {
+    const response = await apiClient.post<CajaLocal>(`/caja/cajas/${cajaId}/abrir`, { montoInicial });
+    return response.data;
+  },
+
+  // ─── HU-166: Numeración de comprobantes ─────────────────────────────────────
+
+  /**
+   * Series de comprobantes del local, la de la caja principal primero.
+   *
+   * GET /api/caja/series
+   */
+  obtenerSeriesComprobantes: async (): Promise<SerieComprobantes[]> => {
+    const response = await apiClient.get('/caja/series');
+    return ensureArray<SerieComprobantes>(response.data);
+  },
+
+  /**
+   * Crea o cambia la serie de una caja.
+   *
+   * PUT /api/caja/series
+   *
+   * Errores esperados:
+   *   - HTTP 400: Prefijo inválido o ya usado por otra caja, o próximo número más bajo que el actual
+   */
+  guardarSerieComprobantes: async (data: SerieComprobantesRequest): Promise<SerieComprobantes> => {
+    const response = await apiClient.put<SerieComprobantes>('/caja/series', data);
+    return response.data;
+  },
+
   // ─── Reporte PDF ────────────────────────────────────────────────────────────
 
   /**
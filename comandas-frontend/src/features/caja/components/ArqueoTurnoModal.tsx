@@ -0,0 +1,304 @@
+import { useState } from 'react';
+import { X, Loader2, Minus, Plus, Printer } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useMozos } from '../../mozos';
+import { useCerrarTurno, useSaldoTeoricoTurno } from '../hooks/useCaja';
+import type { TipoTurno } from '../types';
+import { TURNO_LABELS, claseDiferencia } from './PanelTurnos';
+
+/** Billetes y monedas en circulación, de mayor a menor */
+const BILLETES = [20000, 10000, 2000, 1000, 500, 200, 100];
+const MONEDAS = [10, 5, 2, 1];
+
+function formatMonto(valor: number): string {
+  return valor.toLocaleString('es-AR', {
+    minimumFractionDigits: 2,
+    maximumFractionDigits: 2,
+  });
+}
+
+const labelBase = 'block text-sm font-medium text-gray-400 uppercase tracking-wide';
+
+const inputBase = [
+  'w-full bg-neutral-800 border-2 border-neutral-700 rounded-xl',
+  'text-gray-200 placeholder:text-neutral-600',
+  'focus:border-amber-500 focus:outline-none',
+  'transition-colors disabled:opacity-50',
+].join(' ');
+
+interface ArqueoTurnoModalProps {
+  tipo: TipoTurno;
+  onClose: () => void;
+}
+
+/**
+ * Asistente de conteo para cerrar el turno (HU-123).
+ *
+ * El cajero carga cuántos billetes y monedas de cada denominación hay en
+ * el cajón; el total contado se compara en vivo contra el saldo teórico
+ * del turno. Al confirmar, el backend registra el arqueo con el detalle
+ * y se imprime el comprobante.
+ *
+ * HU-129: Si la caja no cierra, hay que indicar el responsable del turno;
+ * la diferencia queda a su nombre, con las observaciones del cierre.
+ */
+export default function ArqueoTurnoModal({ tipo, onClose }: ArqueoTurnoModalProps) {
+  const toast = useToast();
+  const { data: saldo, isLoading } = useSaldoTeoricoTurno(true);
+  const { data: mozos = [] } = useMozos();
+  const cerrarTurno = useCerrarTurno();
+
+  const [cantidades, setCantidades] = useState<Record<number, number>>({});
+  const [responsableId, setResponsableId] = useState('');
+  const [observaciones, setObservaciones] = useState('');
+
+  const personal = mozos.filter((m) => m.activo);
+
+  const isPending = cerrarTurno.isPending;
+  const contado = [...BILLETES, ...MONEDAS].reduce(
+    (total, d) => total + d * (cantidades[d] ?? 0),
+    0,
+  );
+  const esperado = saldo?.efectivoEsperado ?? 0;
+  const diferencia = contado - esperado;
+  const hayDiferencia = Math.round(diferencia * 100) !== 0;
+  const faltaResponsable = hayDiferencia && responsableId === '';
+  const puedeConfirmar = !!saldo && !faltaResponsable;
+
+  const cambiarCantidad = (denominacion: number, cantidad: number) => {
+    setCantidades((prev) => ({ ...prev, [denominacion]: Math.max(0, Math.floor(cantidad) || 0) }));
+  };
+
+  const handleConfirmar = () => {
+    if (!puedeConfirmar) return;
+    cerrarTurno.mutate(
+      {
+        efectivoDeclarado: contado,
+        denominaciones: [...BILLETES, ...MONEDAS].map((d) => ({
+          denominacion: d,
+          cantidad: cantidades[d] ?? 0,
+        })),
+        responsableId: responsableId || undefined,
+        observaciones: observaciones.trim() || undefined,
+      },
+      {
+        onSuccess: (turno) => {
+          const dif = turno.diferencia ?? 0;
+          const detalle =
+            dif === 0 ? 'sin diferencia' : `${dif < 0 ? 'faltante' : 'sobrante'} $ ${formatMonto(Math.abs(dif))}`;
+          toast.success(`Turno ${TURNO_LABELS[turno.tipo].toLowerCase()} cerrado: ${detalle}`);
+          onClose();
+        },
+        onError: (err: any) =>
+          toast.error(err?.response?.data?.message || 'No se pudo cerrar el turno'),
+      },
+    );
+  };
+
+  const renderFila = (denominacion: number) => {
+    const cantidad = cantidades[denominacion] ?? 0;
+    return (
+      <li key={denominacion} className="flex items-center gap-2">
+        <span className="w-20 text-right font-mono text-sm text-gray-300 tabular-nums">
+          ${denominacion.toLocaleString('es-AR')}
+        </span>
+        <button
+          type="button"
+          onClick={() => cambiarCantidad(denominacion, cantidad - 1)}
+          disabled={isPending || cantidad === 0}
+          className="w-9 h-9 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-gray-300 flex items-center justify-center disabled:opacity-40 active:scale-95"
+          aria-label={`Quitar uno de $${denominacion}`}
+        >
+          <Minus size={14} />
+        </button>
+        <input
+          type="number"
+          inputMode="numeric"
+          min="0"
+          step="1"
+          value={cantidad === 0 ? '' : cantidad}
+          onChange={(e) => cambiarCantidad(denominacion, parseInt(e.target.value, 10))}
+          disabled={isPending}
+          placeholder="0"
+          aria-label={`Cantidad de $${denominacion}`}
+          className={[
+            'w-16 h-9 px-2 rounded-lg bg-neutral-800 border-2 border-neutral-700',
+            'text-center font-mono text-gray-200 placeholder:text-neutral-600',
+            'focus:border-amber-500 focus:outline-none disabled:opacity-50',
+          ].join(' ')}
+        />
+        <button
+          type="button"
+          onClick={() => cambiarCantidad(denominacion, cantidad + 1)}
+          disabled={isPending}
+          className="w-9 h-9 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-gray-300 flex items-center justify-center disabled:opacity-40 active:scale-95"
+          aria-label={`Agregar uno de $${denominacion}`}
+        >
+          <Plus size={14} />
+        </button>
+        <span className="flex-1 text-right font-mono text-sm tabular-nums text-gray-400">
+          {cantidad > 0 ? `$${formatMonto(denominacion * cantidad)}` : '—'}
+        </span>
+      </li>
+    );
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div
+        className="fixed inset-0 z-[60] bg-black/70 backdrop-blur-sm animate-backdrop-in"
+        onClick={isPending ? undefined : onClose}
+        aria-hidden="true"
+      />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[70] flex items-center justify-center p-4">
+        <div
+          className={[
+            'bg-neutral-900 border-2 border-neutral-700 rounded-2xl',
+            'shadow-2xl shadow-black/60',
+            'w-full max-w-md max-h-[90vh] overflow-y-auto',
+            'animate-modal-in',
+          ].join(' ')}
+          role="dialog"
+          aria-modal="true"
+          aria-labelledby="arqueo-turno-modal-title"
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-neutral-800">
+            <h2 id="arqueo-turno-modal-title" className="text-lg font-bold text-gray-100">
+              Arqueo · Turno {TURNO_LABELS[tipo].toLowerCase()}
+            </h2>
+            <button
+              type="button"
+              onClick={onClose}
+              disabled={isPending}
+              className={[
+                'w-10 h-10 rounded-xl flex items-center justify-center',
+                'text-gray-400 hover:text-gray-100 hover:bg-neutral-800',
+                'transition-colors active:scale-95',
+              ].join(' ')}
+              aria-label="Cerrar"
+            >
+              <X size={20} />
+            </button>
+          </div>
+
+          <div className="px-6 py-5 space-y-5">
+            {/* Conteo */}
+            <div className="space-y-2">
+              <p className={labelBase}>Billetes</p>
+              <ul className="space-y-1.5">{BILLETES.map(renderFila)}</ul>
+            </div>
+            <div className="space-y-2">
+              <p className={labelBase}>Monedas</p>
+              <ul className="space-y-1.5">{MONEDAS.map(renderFila)}</ul>
+            </div>
+
+            {/* Comparación contra el teórico */}
+            {isLoading || !saldo ? (
+              <div className="flex items-center justify-center gap-2 text-sm text-gray-500">
+                <Loader2 size={16} className="animate-spin" /> Calculando saldo teórico...
+              </div>
+            ) : (
+              <div className="grid grid-cols-3 gap-2 text-center">
+                <div className="rounded-xl bg-neutral-800/60 px-2 py-2">
+                  <p className="text-[10px] text-gray-500 uppercase tracking-wider">Contado</p>
+                  <p className="text-sm font-mono font-semibold text-gray-200 tabular-nums">
+                    ${formatMonto(contado)}
+                  </p>
+                </div>
+                <div className="rounded-xl bg-neutral-800/60 px-2 py-2">
+                  <p className="text-[10px] text-gray-500 uppercase tracking-wider">Esperado</p>
+                  <p className="text-sm font-mono font-semibold text-gray-200 tabular-nums">
+                    ${formatMonto(esperado)}
+                  </p>
+                </div>
+                <div className="rounded-xl bg-neutral-800/60 px-2 py-2">
+                  <p className="text-[10px] text-gray-500 uppercase tracking-wider">
+                    {diferencia === 0 ? 'Diferencia' : diferencia < 0 ? 'Faltante' : 'Sobrante'}
+                  </p>
+                  <p className={`text-sm font-mono font-semibold tabular-nums ${claseDiferencia(diferencia)}`}>
+                    ${formatMonto(Math.abs(diferencia))}
+                  </p>
+                </div>
+              </div>
+            )}
+
+            {/* Responsable y observaciones (HU-129) */}
+            <div className="space-y-2">
+              <label htmlFor="arqueo-responsable" className={labelBase}>
+                Responsable del turno {hayDiferencia ? '*' : '(opcional)'}
+              </label>
+              <select
+                id="arqueo-responsable"
+                value={responsableId}
+                onChange={(e) => setResponsableId(e.target.value)}
+                disabled={isPending}
+                className={`${inputBase} h-12 px-3 text-sm`}
+              >
+                <option value="">Seleccionar...</option>
+                {personal.map((m) => (
+                  <option key={m.id} value={m.id}>
+                    {m.nombre}
+                  </option>
+                ))}
+              </select>
+              {faltaResponsable && saldo && (
+                <p className="text-xs text-amber-400">
+                  La caja no cierra: indicá quién estuvo a cargo para registrar la diferencia.
+                </p>
+              )}
+            </div>
+            <div className="space-y-2">
+              <label htmlFor="arqueo-observaciones" className={labelBase}>
+                Observaciones
+              </label>
+              <textarea
+                id="arqueo-observaciones"
+                value={observaciones}
+                onChange={(e) => setObservaciones(e.target.value)}
+                disabled={isPending}
+                maxLength={500}
+                rows={2}
+                placeholder="Ej: Vuelto mal dado, billete dudoso..."
+                className={`${inputBase} px-4 py-3 text-sm resize-none`}
+              />
+            </div>
+
+            {/* Botón confirmar */}
+            <button
+              type="button"
+              onClick={handleConfirmar}
+              disabled={isPending || !puedeConfirmar}
+              className={[
+                'w-full h-14 rounded-xl font-semibold text-base',
+                'flex items-center justify-center gap-3',
+                'transition-all duration-150',
+                'focus:outline-none focus-visible:ring-2 focus-visible:ring-amber-400',
+                isPending
+                  ? 'bg-neutral-700 text-gray-400 cursor-wait'
+                  : puedeConfirmar
+                    ? 'bg-amber-600 hover:bg-amber-500 text-white active:scale-95'
+                    : 'bg-neutral-700 text-gray-500 cursor-not-allowed',
+              ].join(' ')}
+            >
+              {isPending ? (
+                <>
+                  <Loader2 size={20} className="animate-spin" />
+                  Registrando...
+                </>
+              ) : (
+                <>
+                  <Printer size={18} />
+                  Cerrar turno e imprimir arqueo
+                </>
+              )}
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
@@ -0,0 +1,88 @@
+import { useState } from 'react';
+import { ImageDown, Loader2, Smartphone, Receipt } from 'lucide-react';
+import { useCompartirReporteImagen } from '../hooks/useCaja';
+import type { AnchoReporteImagen, VistaReporteImagen } from '../types';
+import useToast from '../../../hooks/useToast';
+
+interface BotonCompartirImagenProps {
+  vista: VistaReporteImagen;
+  /** Día operativo inicial (YYYY-MM-DD) */
+  desde: string;
+  /** Día operativo final, inclusivo. Por defecto: el mismo que desde */
+  hasta?: string;
+  /** Texto del botón. Por defecto: "Compartir imagen" */
+  label?: string;
+}
+
+const OPCIONES_ANCHO: Array<{ ancho: AnchoReporteImagen; label: string; icon: React.ReactNode }> = [
+  { ancho: 'TELEFONO', label: 'Para teléfono', icon: <Smartphone className="h-4 w-4" /> },
+  { ancho: 'TICKET', label: 'Ancho de ticket', icon: <Receipt className="h-4 w-4" /> },
+];
+
+/**
+ * Exporta un reporte como imagen para reenviarlo por WhatsApp (HU-157).
+ *
+ * Al tocarlo se elige el ancho: teléfono (se lee bien a pantalla completa)
+ * o ticket (angosta, como el cierre impreso). En el escritorio la imagen
+ * queda en el portapapeles, lista para pegar en el chat.
+ *
+ * @example
+ * <BotonCompartirImagen vista="VENTAS" desde={fechaOperativa} />
+ */
+export default function BotonCompartirImagen({
+  vista,
+  desde,
+  hasta = desde,
+  label = 'Compartir imagen',
+}: BotonCompartirImagenProps) {
+  const toast = useToast();
+  const { mutate: compartir, isPending } = useCompartirReporteImagen();
+  const [abierto, setAbierto] = useState(false);
+
+  const handleElegir = (ancho: AnchoReporteImagen) => {
+    setAbierto(false);
+    compartir(
+      { vista, desde, hasta, ancho },
+      {
+        onSuccess: (mensaje) => toast.success(mensaje),
+        onError: (err: any) =>
+          toast.error(
+            typeof err === 'string' ? err : err?.response?.data?.message || 'No se pudo generar la imagen'
+          ),
+      }
+    );
+  };
+
+  return (
+    <div className="relative">
+      <button
+        type="button"
+        onClick={() => setAbierto((v) => !v)}
+        disabled={isPending}
+        className="flex items-center gap-2 px-3 py-2 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-gray-400 hover:text-gray-200 text-xs font-medium transition-all disabled:opacity-50"
+      >
+        {isPending ? <Loader2 size={14} className="animate-spin" /> : <ImageDown size={14} />}
+        <span className="hidden sm:inline">{label}</span>
+      </button>
+
+      {abierto && (
+        <>
+          <div className="fixed inset-0 z-10" onClick={() => setAbierto(false)} />
+          <div className="absolute right-0 z-20 mt-1 w-44 rounded-xl border border-neutral-700 bg-neutral-900 p-1 shadow-xl">
+            {OPCIONES_ANCHO.map(({ ancho, label: etiqueta, icon }) => (
+              <button
+                key={ancho}
+                type="button"
+                onClick={() => handleElegir(ancho)}
+                className="flex w-full items-center gap-2 rounded-lg px-3 py-2 text-left text-xs text-gray-300 hover:bg-neutral-800"
+              >
+                {icon}
+                {etiqueta}
+              </button>
+            ))}
+          </div>
+        </>
+      )}
+    </div>
+  );
+}
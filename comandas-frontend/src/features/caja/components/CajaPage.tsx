@@ -1,5 +1,5 @@
 import { useState, useMemo, useSyncExternalStore } from 'react';
-import { DollarSign, Calendar, History, Vault, Loader2 } from 'lucide-react';
+import { DollarSign, Calendar, History, Vault, Loader2, LockOpen } from 'lucide-react';
 import { Link } from 'react-router-dom';
 
 import {
@@ -14,6 +14,7 @@ import { MesasAbiertasError, JornadaYaCerradaError } from '../types';
 import type { PagoDetalle } from '../types';
 import useToast from '../../../hooks/useToast';
 import { getDevDateOverride, subscribeDevDate } from '../../../lib/devClock';
+import { abrirCajon } from '../../pedido/services/printerService';
 
 import AperturaCajaModal from './AperturaCajaModal';
 import PanelResumenCaja from './PanelResumenCaja';
@@ -26,9 +27,14 @@ import EgresoModal from './EgresoModal';
 import IngresoModal from './IngresoModal';
 import AlertaMesasAbiertas from './AlertaMesasAbiertas';
 import CorregirPedidoModal from './CorregirPedidoModal';
+import DevolucionModal from './DevolucionModal';
 import ConfirmarCierreModal from './ConfirmarCierreModal';
 import ReporteProductos from './ReporteProductos';
 import PantallaBloqueoLicencia from './PantallaBloqueoLicencia';
+import PanelTurnos from './PanelTurnos';
+import PanelCajas from './PanelCajas';
+import BotonCompartirImagen from './BotonCompartirImagen';
+import FacturaModal from '../../facturacion/components/FacturaModal';
 
 // ─── Utilidad ─────────────────────────────────────────────────────────────────
 
@@ -125,9 +131,21 @@ export default function CajaPage() {
   } | null>(null);
   const [pagoDetalle, setPagoDetalle] = useState<PagoDetalle | null>(null);
   const [pedidoACorregir, setPedidoACorregir] = useState<string | null>(null);
+  const [pedidoAFacturar, setPedidoAFacturar] = useState<string | null>(null);
+  const [pedidoADevolver, setPedidoADevolver] = useState<string | null>(null);
 
   // ── Handlers ──
 
+  /** HU-169: sin cobro de por medio, la capa Rust exige el permiso ABRIR_CAJON */
+  const handleAbrirCajon = async () => {
+    const result = await abrirCajon();
+    if (result.success) {
+      toast.success(result.message);
+    } else {
+      toast.error(result.message);
+    }
+  };
+
   const handleAbrirCaja = (montoInicial: number) => {
     abrirCaja.mutate({ montoInicial }, {
       onSuccess: () => {
@@ -170,11 +188,24 @@ export default function CajaPage() {
 
   const handleConfirmarCierre = () => {
     cerrarJornada.mutate(undefined, {
-      onSuccess: () => {
+      onSuccess: (data) => {
         setConfirmarCierreAbierto(false);
         setPagoDetalle(null);
         setPedidoACorregir(null);
-        toast.success('Jornada cerrada exitosamente. Se descargará el reporte PDF.');
+        // HU-106: con turnos, informar la diferencia consolidada (el detalle va en el PDF)
+        if (data.turnos?.length) {
+          const dif = data.diferenciaTotal ?? 0;
+          const detalle = dif === 0
+            ? 'sin diferencias'
+            : `diferencia total ${dif < 0 ? '-' : '+'}$${Math.abs(dif).toLocaleString('es-AR', { minimumFractionDigits: 2 })}`;
+          toast.success(`Jornada cerrada (${data.turnos.length} turnos, ${detalle}). Se descargará el reporte PDF.`);
+        } else if ((data.cajas?.length ?? 0) > 1) {
+          // HU-159: varias cajas, informar el efectivo total (el arqueo de cada una va en el historial)
+          const total = (data.efectivoTotal ?? 0).toLocaleString('es-AR', { minimumFractionDigits: 2 });
+          toast.success(`Jornada cerrada (${data.cajas!.length} cajas, efectivo total $${total}). Se descargará el reporte PDF.`);
+        } else {
+          toast.success('Jornada cerrada exitosamente. Se descargará el reporte PDF.');
+        }
       },
       onError: (error) => {
         setConfirmarCierreAbierto(false);
@@ -279,10 +310,24 @@ export default function CajaPage() {
             </div>
           </div>
 
+          {/* HU-157: Arqueo del día como imagen para WhatsApp */}
+          <div className="ml-auto">
+            <BotonCompartirImagen vista="CAJA" desde={hoy} />
+          </div>
+
+          <button
+            type="button"
+            onClick={handleAbrirCajon}
+            className="flex items-center gap-2 px-3 py-2 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-gray-400 hover:text-gray-200 text-xs font-medium transition-all"
+          >
+            <LockOpen size={14} />
+            <span className="hidden sm:inline">Abrir cajón</span>
+          </button>
+
           {/* Acceso rápido a historial de jornadas */}
           <Link
             to="/caja/historial"
-            className="ml-auto flex items-center gap-2 px-3 py-2 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-gray-400 hover:text-gray-200 text-xs font-medium transition-all"
+            className="flex items-center gap-2 px-3 py-2 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-gray-400 hover:text-gray-200 text-xs font-medium transition-all"
           >
             <History size={14} />
             <span className="hidden sm:inline">Historial</span>
@@ -304,6 +349,10 @@ export default function CajaPage() {
               cierreDeshabilitado={jornadaCerrada}
             />
 
+            <PanelTurnos habilitado={cajaAbierta && !jornadaCerrada} />
+
+            <PanelCajas habilitado={cajaAbierta && !jornadaCerrada} />
+
             <ActividadPorHoraChart
               ventas={reporte?.ventas}
               isLoading={cargandoReporte}
@@ -354,6 +403,8 @@ export default function CajaPage() {
                   ventas={reporte?.ventas ?? []}
                   isLoading={cargandoReporte}
                   onCorregirPedido={handleCorregirPedido}
+                  onFacturarPedido={setPedidoAFacturar}
+                  onDevolverPedido={setPedidoADevolver}
                 />
               </div>
             </div>
@@ -425,6 +476,14 @@ export default function CajaPage() {
         />
       )}
 
+      {pedidoAFacturar && (
+        <FacturaModal pedidoId={pedidoAFacturar} onClose={() => setPedidoAFacturar(null)} />
+      )}
+
+      {pedidoADevolver && (
+        <DevolucionModal pedidoId={pedidoADevolver} onClose={() => setPedidoADevolver(null)} />
+      )}
+
       {confirmarCierreAbierto && (
         <ConfirmarCierreModal
           onConfirmar={handleConfirmarCierre}
@@ -0,0 +1,141 @@
+import { useState } from 'react';
+import { Loader2, Plus, Tags } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useActualizarCategoriaEgreso, useCategoriasEgreso, useCrearCategoriaEgreso } from '../hooks/useCaja';
+import type { CategoriaEgreso } from '../types';
+
+const inputClass =
+  'h-9 w-full px-3 rounded-lg bg-neutral-800 border border-neutral-700 text-sm text-gray-200 focus:outline-none focus:border-red-600 disabled:opacity-50';
+
+function mensajeError(err: any, fallback: string): string {
+  return err?.response?.data?.message || fallback;
+}
+
+/**
+ * ABM de categorías de egresos de caja (HU-130).
+ *
+ * No se borran: una categoría desactivada deja de ofrecerse al registrar
+ * egresos pero sigue apareciendo en los meses en que se usó.
+ */
+export default function CategoriasEgresoPanel() {
+  const toast = useToast();
+  const { data: categorias = [], isLoading } = useCategoriasEgreso();
+  const crear = useCrearCategoriaEgreso();
+  const actualizar = useActualizarCategoriaEgreso();
+
+  const [nombre, setNombre] = useState('');
+  const [editando, setEditando] = useState<string | null>(null);
+  const [nombreEditado, setNombreEditado] = useState('');
+
+  const handleCrear = () => {
+    if (!nombre.trim()) return;
+    crear.mutate(
+      { nombre: nombre.trim() },
+      {
+        onSuccess: (categoria) => {
+          toast.success(`Categoría ${categoria.nombre} agregada`);
+          setNombre('');
+        },
+        onError: (err) => toast.error(mensajeError(err, 'No se pudo agregar la categoría')),
+      }
+    );
+  };
+
+  const guardarNombre = (categoria: CategoriaEgreso) => {
+    const nuevo = nombreEditado.trim();
+    if (!nuevo || nuevo === categoria.nombre) {
+      setEditando(null);
+      return;
+    }
+    actualizar.mutate(
+      { id: categoria.id, nombre: nuevo, activa: categoria.activa },
+      {
+        onSuccess: () => setEditando(null),
+        onError: (err) => toast.error(mensajeError(err, 'No se pudo renombrar la categoría')),
+      }
+    );
+  };
+
+  const toggleActiva = (categoria: CategoriaEgreso) => {
+    actualizar.mutate(
+      { id: categoria.id, nombre: categoria.nombre, activa: !categoria.activa },
+      { onError: (err) => toast.error(mensajeError(err, 'No se pudo actualizar la categoría')) }
+    );
+  };
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-3">
+      <div className="flex items-center gap-2">
+        <Tags size={14} className="text-gray-500" />
+        <h2 className="text-xs text-gray-500 uppercase tracking-wider font-medium">Categorías</h2>
+      </div>
+
+      <div className="flex gap-2">
+        <input
+          value={nombre}
+          onChange={(e) => setNombre(e.target.value)}
+          onKeyDown={(e) => e.key === 'Enter' && handleCrear()}
+          maxLength={40}
+          placeholder="Nueva categoría"
+          className={inputClass}
+        />
+        <button
+          type="button"
+          onClick={handleCrear}
+          disabled={crear.isPending || !nombre.trim()}
+          className="h-9 px-3 rounded-lg bg-red-600 hover:bg-red-700 text-sm text-white flex items-center gap-1.5 disabled:opacity-40"
+        >
+          {crear.isPending ? <Loader2 size={14} className="animate-spin" /> : <Plus size={14} />}
+          Agregar
+        </button>
+      </div>
+
+      {isLoading ? (
+        <div className="flex items-center gap-2 text-xs text-gray-500">
+          <Loader2 size={14} className="animate-spin" /> Cargando categorías...
+        </div>
+      ) : (
+        <ul className="divide-y divide-neutral-800 text-sm">
+          {categorias.map((categoria) => (
+            <li key={categoria.id} className="flex items-center justify-between gap-2 py-2">
+              {editando === categoria.id ? (
+                <input
+                  autoFocus
+                  value={nombreEditado}
+                  maxLength={40}
+                  onChange={(e) => setNombreEditado(e.target.value)}
+                  onBlur={() => guardarNombre(categoria)}
+                  onKeyDown={(e) => {
+                    if (e.key === 'Enter') guardarNombre(categoria);
+                    if (e.key === 'Escape') setEditando(null);
+                  }}
+                  className="h-7 flex-1 px-2 rounded-md bg-neutral-800 border border-neutral-700 text-sm text-gray-200 focus:outline-none focus:border-red-600"
+                />
+              ) : (
+                <button
+                  type="button"
+                  onClick={() => {
+                    setNombreEditado(categoria.nombre);
+                    setEditando(categoria.id);
+                  }}
+                  title="Renombrar"
+                  className={`flex-1 truncate text-left ${categoria.activa ? 'text-gray-200' : 'text-gray-600 line-through'}`}
+                >
+                  {categoria.nombre}
+                </button>
+              )}
+              <button
+                type="button"
+                onClick={() => toggleActiva(categoria)}
+                disabled={actualizar.isPending}
+                className="h-7 px-2 rounded-md border border-neutral-700 text-xs text-gray-400 hover:text-gray-200 transition-colors disabled:opacity-40"
+              >
+                {categoria.activa ? 'Desactivar' : 'Activar'}
+              </button>
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+}
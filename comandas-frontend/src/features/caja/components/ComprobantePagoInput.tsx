@@ -0,0 +1,129 @@
+import { useState } from 'react';
+import { ClipboardPaste, Copy, Loader2, Receipt } from 'lucide-react';
+import { useDatosTransferencia } from '../hooks/useCaja';
+import { copiarAlPortapapeles, montoParaCopiar, pegarNumeroOperacion } from '../../../lib/portapapeles';
+import type { MedioPago } from '../../salon/types';
+import useToast from '../../../hooks/useToast';
+
+/** Mismo tope que el backend para la referencia de un pago */
+const LARGO_MAXIMO_REFERENCIA = 64;
+
+interface ComprobantePagoInputProps {
+  medio: MedioPago;
+  referencia: string;
+  onChange: (referencia: string) => void;
+  /** Lo que el cliente tiene que transferir, recargo incluido */
+  montoATransferir: number;
+  disabled?: boolean;
+}
+
+/**
+ * Datos del comprobante de un pago con tarjeta, transferencia o QR (HU-156).
+ *
+ * En una transferencia ofrece copiar el alias, el CVU y el monto para
+ * pasárselos al cliente. En todos los medios con comprobante se carga el
+ * número de cupón u operación, a mano o pegándolo del home banking o del
+ * posnet. Va por el portapapeles nativo: en modo kiosco el webview no puede
+ * leerlo ni escribirlo.
+ */
+export default function ComprobantePagoInput({
+  medio,
+  referencia,
+  onChange,
+  montoATransferir,
+  disabled,
+}: ComprobantePagoInputProps) {
+  const toast = useToast();
+  const { data: datosTransferencia } = useDatosTransferencia();
+  const [pegando, setPegando] = useState(false);
+
+  const copiar = async (texto: string, que: string) => {
+    try {
+      await copiarAlPortapapeles(texto);
+      toast.success(`${que} copiado`);
+    } catch (err) {
+      toast.error(typeof err === 'string' ? err : `No se pudo copiar el ${que.toLowerCase()}`);
+    }
+  };
+
+  const pegar = async () => {
+    setPegando(true);
+    try {
+      onChange(await pegarNumeroOperacion());
+    } catch (err) {
+      toast.error(typeof err === 'string' ? err : (err as Error)?.message || 'No se pudo pegar el comprobante');
+    } finally {
+      setPegando(false);
+    }
+  };
+
+  const botonCopiar = (texto: string | null | undefined, que: string, etiqueta: string) =>
+    texto && (
+      <button
+        type="button"
+        onClick={() => copiar(texto, que)}
+        disabled={disabled}
+        className="
+          flex items-center gap-1 h-7 px-2 rounded-lg
+          bg-neutral-800 border border-neutral-700
+          text-[11px] text-gray-300 hover:text-gray-100 hover:border-neutral-600
+          transition-colors disabled:opacity-50
+        "
+        title={texto}
+      >
+        <Copy size={11} />
+        {etiqueta}
+      </button>
+    );
+
+  return (
+    <div className="space-y-2 pl-1">
+      {medio === 'TRANSFERENCIA' && (
+        <div className="flex flex-wrap items-center gap-1.5">
+          {botonCopiar(datosTransferencia?.alias, 'Alias', datosTransferencia?.alias ?? '')}
+          {botonCopiar(datosTransferencia?.cvu, 'CVU', 'CVU')}
+          {montoATransferir > 0 &&
+            botonCopiar(
+              montoParaCopiar(montoATransferir),
+              'Monto',
+              `$ ${montoATransferir.toLocaleString('es-AR', { minimumFractionDigits: 2 })}`
+            )}
+        </div>
+      )}
+
+      <div className="flex items-center gap-2">
+        <Receipt size={14} className="shrink-0 text-gray-500" />
+        <input
+          type="text"
+          value={referencia}
+          onChange={(e) => onChange(e.target.value)}
+          placeholder={medio === 'TARJETA' ? 'N° de cupón' : 'N° de operación'}
+          disabled={disabled}
+          maxLength={LARGO_MAXIMO_REFERENCIA}
+          className="
+            w-44 h-8 px-2
+            bg-neutral-800 border border-neutral-700
+            rounded-lg text-xs font-mono text-gray-100
+            placeholder:text-gray-600
+            focus:outline-none focus:border-red-600
+            disabled:opacity-50
+          "
+        />
+        <button
+          type="button"
+          onClick={pegar}
+          disabled={disabled || pegando}
+          className="
+            flex items-center gap-1 h-8 px-2 rounded-lg
+            text-[11px] font-semibold text-gray-400 hover:text-gray-200 hover:bg-neutral-800
+            transition-colors disabled:opacity-50
+          "
+          aria-label="Pegar número de operación"
+        >
+          {pegando ? <Loader2 size={12} className="animate-spin" /> : <ClipboardPaste size={12} />}
+          Pegar
+        </button>
+      </div>
+    </div>
+  );
+}
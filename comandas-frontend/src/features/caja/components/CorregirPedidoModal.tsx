@@ -12,7 +12,8 @@ import {
 } from 'lucide-react';
 
 import { useDetallePedidoCerrado, useCorregirPedido } from '../hooks/useCaja';
-import type { MedioPago } from '../../salon/types';
+import { MEDIOS_CON_COMPROBANTE, MEDIOS_MONEDA_EXTRANJERA, type MedioPago, type Moneda } from '../../salon/types';
+import SelectorCliente from '../../clientes/components/SelectorCliente';
 
 // ─── Constantes ───────────────────────────────────────────────────────────────
 
@@ -22,6 +23,8 @@ const MEDIOS_PAGO: { value: MedioPago; label: string }[] = [
   { value: 'TRANSFERENCIA', label: 'Transferencia' },
   { value: 'QR', label: 'QR' },
   { value: 'A_CUENTA', label: 'A Cuenta' },
+  { value: 'CUENTA_CORRIENTE', label: 'Cuenta Corriente' },
+  { value: 'TARJETA_REGALO', label: 'Tarjeta de Regalo' },
 ];
 
 // ─── Estado local ─────────────────────────────────────────────────────────────
@@ -45,6 +48,10 @@ interface PagoEditable {
   key: string;
   medio: MedioPago;
   monto: string; // string para el input
+  /** HU-151: moneda del pago original; el monto se sigue editando en pesos */
+  moneda?: Moneda;
+  /** HU-156: número de comprobante del pago original, se conserva tal cual */
+  referencia?: string;
 }
 
 // ─── Utilidades ───────────────────────────────────────────────────────────────
@@ -87,6 +94,8 @@ export default function CorregirPedidoModal({
   const [items, setItems] = useState<ItemEditable[]>([]);
   const [pagos, setPagos] = useState<PagoEditable[]>([]);
   const [error, setError] = useState<string | null>(null);
+  /** HU-104: null = conservar el cliente del cargo original */
+  const [clienteId, setClienteId] = useState<string | null>(null);
 
   // ── Inicializar estado desde detalle cargado ──
   useEffect(() => {
@@ -109,6 +118,8 @@ export default function CorregirPedidoModal({
         key: nextPagoKey(),
         medio: p.medio,
         monto: p.monto.toString(),
+        moneda: p.moneda && p.moneda !== 'ARS' ? p.moneda : undefined,
+        referencia: p.referencia ?? undefined,
       })),
     );
   }, [detalle]);
@@ -158,6 +169,7 @@ export default function CorregirPedidoModal({
   const diferencia = totalPagos - totalNeto;
   const hayDescuentos = totalDescuentos > 0.01;
   const esValido = items.length > 0 && pagos.length > 0 && Math.abs(diferencia) < 0.01;
+  const tieneCuentaCorriente = pagos.some((p) => p.medio === 'CUENTA_CORRIENTE');
 
   const hayCambios = useMemo(() => {
     if (!detalle) return false;
@@ -172,8 +184,8 @@ export default function CorregirPedidoModal({
         if (!orig) return true;
         return p.medio !== orig.medio || parseFloat(p.monto) !== orig.monto;
       });
-    return itemsCambiaron || itemsEliminados || pagosCambiaron;
-  }, [items, pagos, detalle]);
+    return itemsCambiaron || itemsEliminados || pagosCambiaron || clienteId !== null;
+  }, [items, pagos, detalle, clienteId]);
 
   // ── Handlers de ítems ──
   const cambiarCantidad = useCallback((itemId: string, delta: number) => {
@@ -199,7 +211,18 @@ export default function CorregirPedidoModal({
 
   // ── Handlers de pagos ──
   const cambiarMedioPago = useCallback((key: string, medio: MedioPago) => {
-    setPagos((prev) => prev.map((p) => (p.key === key ? { ...p, medio } : p)));
+    setPagos((prev) =>
+      prev.map((p) =>
+        p.key === key
+          ? {
+              ...p,
+              medio,
+              moneda: MEDIOS_MONEDA_EXTRANJERA.includes(medio) ? p.moneda : undefined,
+              referencia: MEDIOS_CON_COMPROBANTE.includes(medio) ? p.referencia : undefined,
+            }
+          : p,
+      ),
+    );
     setError(null);
   }, []);
 
@@ -238,7 +261,13 @@ export default function CorregirPedidoModal({
         pedidoId,
         data: {
           items: items.map((i) => ({ itemId: i.itemId, cantidad: i.cantidad })),
-          pagos: pagos.map((p) => ({ medio: p.medio, monto: parseFloat(p.monto) || 0 })),
+          pagos: pagos.map((p) => ({
+            medio: p.medio,
+            monto: parseFloat(p.monto) || 0,
+            moneda: p.moneda,
+            referencia: p.referencia,
+          })),
+          clienteId: tieneCuentaCorriente && clienteId ? clienteId : undefined,
         },
       },
       {
@@ -475,6 +504,13 @@ export default function CorregirPedidoModal({
                           ))}
                         </select>
 
+                        {/* HU-151: Se conserva el tipo de cambio con el que se cobró */}
+                        {pago.moneda && (
+                          <span className="shrink-0 text-[11px] font-mono text-sky-400/80" title="Monto en pesos; se conserva el tipo de cambio del cobro">
+                            {pago.moneda}
+                          </span>
+                        )}
+
                         {/* Monto */}
                         <div className="relative w-28">
                           <span className="absolute left-2.5 top-1/2 -translate-y-1/2 text-gray-500 text-sm">
@@ -524,6 +560,18 @@ export default function CorregirPedidoModal({
                       Ajustar monto al total (${fmt(totalNeto)})
                     </button>
                   )}
+
+                  {/* HU-104: Cliente de cuenta corriente */}
+                  {tieneCuentaCorriente && (
+                    <div className="mt-3 space-y-1.5">
+                      <p className="text-xs text-gray-500">Cliente de cuenta corriente</p>
+                      <SelectorCliente
+                        value={clienteId}
+                        onChange={setClienteId}
+                        placeholder="Mantener el cliente actual"
+                      />
+                    </div>
+                  )}
                 </section>
               </>
             )}
@@ -5,11 +5,13 @@ import {
   QrCode,
   ArrowRightLeft,
   Users,
+  BookUser,
   ChevronDown,
   ChevronUp,
   Eye,
   Printer,
   Receipt,
+  Gift,
 } from 'lucide-react';
 import type { MedioPago } from '../../salon/types';
 import type { PagoDetalle } from '../types';
@@ -81,6 +83,22 @@ const MEDIOS_CONFIG: MedioPagoConfig[] = [
     bgBadge: 'bg-neutral-800 text-gray-500 border-neutral-700',
     bgBar: 'bg-gray-600',
   },
+  {
+    key: 'CUENTA_CORRIENTE',
+    label: 'Cuenta corriente',
+    icon: <BookUser size={18} />,
+    color: 'text-amber-400',
+    bgBadge: 'bg-amber-950/50 text-amber-400 border-amber-800/40',
+    bgBar: 'bg-amber-500',
+  },
+  {
+    key: 'TARJETA_REGALO',
+    label: 'Tarjeta de regalo',
+    icon: <Gift size={18} />,
+    color: 'text-pink-400',
+    bgBadge: 'bg-pink-950/50 text-pink-400 border-pink-800/40',
+    bgBar: 'bg-pink-500',
+  },
 ];
 
 // ─── Grupo agrupado ───────────────────────────────────────────────────────────
@@ -179,10 +197,29 @@ function FilaPago({ pago, config, onVerDetalle }: FilaPagoProps) {
         </p>
       </div>
 
-      {/* Monto */}
-      <span className={`text-sm font-semibold font-mono tabular-nums shrink-0 ${config.color}`}>
-        ${fmt(pago.monto)}
-      </span>
+      {/* Monto (+ HU-143: recargo cobrado encima) */}
+      <div className="text-right shrink-0">
+        <span className={`text-sm font-semibold font-mono tabular-nums ${config.color}`}>
+          ${fmt(pago.monto)}
+        </span>
+        {pago.recargo > 0 && (
+          <p className="text-[11px] font-mono text-amber-400/80 tabular-nums">
+            +${fmt(pago.recargo)} rec.
+          </p>
+        )}
+        {/* HU-152: Redondeo del efectivo */}
+        {(pago.ajusteRedondeo ?? 0) !== 0 && (
+          <p className="text-[11px] font-mono text-sky-400/80 tabular-nums">
+            {pago.ajusteRedondeo > 0 ? '+' : '−'}${fmt(Math.abs(pago.ajusteRedondeo))} red.
+          </p>
+        )}
+        {/* HU-151: Lo que entregó el cliente en su moneda */}
+        {pago.moneda && pago.moneda !== 'ARS' && (
+          <p className="text-[11px] font-mono text-sky-400/80 tabular-nums">
+            {pago.moneda} {fmt(pago.montoOriginal)}
+          </p>
+        )}
+      </div>
 
       {/* Acciones */}
       <div className="flex items-center gap-1 shrink-0">
@@ -8,6 +8,8 @@ import {
   QrCode,
   ArrowRightLeft,
   Users,
+  BookUser,
+  Gift,
 } from 'lucide-react';
 import type { PagoDetalle, VentaResumen } from '../types';
 import type { MedioPago } from '../../salon/types';
@@ -38,6 +40,8 @@ const MEDIO_LABELS: Record<MedioPago, { label: string; icon: React.ReactNode; co
   QR: { label: 'QR', icon: <QrCode size={16} />, color: 'text-violet-400' },
   TRANSFERENCIA: { label: 'Transferencia', icon: <ArrowRightLeft size={16} />, color: 'text-cyan-400' },
   A_CUENTA: { label: 'A cuenta', icon: <Users size={16} />, color: 'text-gray-500' },
+  CUENTA_CORRIENTE: { label: 'Cuenta corriente', icon: <BookUser size={16} />, color: 'text-amber-400' },
+  TARJETA_REGALO: { label: 'Tarjeta de regalo', icon: <Gift size={16} />, color: 'text-pink-400' },
 };
 
 // ─── Props ────────────────────────────────────────────────────────────────────
@@ -138,6 +142,12 @@ export default function DetallePagoPedidoModal({
                   ${fmt(pago.monto)}
                 </span>
               </div>
+              {/* HU-156: Número de cupón u operación */}
+              {pago.referencia && (
+                <p className="mt-2 text-xs text-gray-500">
+                  Comprobante <span className="font-mono text-gray-300">{pago.referencia}</span>
+                </p>
+              )}
             </div>
 
             {/* Todos los pagos del pedido */}
@@ -0,0 +1,310 @@
+import { useState } from 'react';
+import { X, Loader2, Undo2, KeyRound } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useMozos, puedeAutorizar } from '../../mozos';
+import { useDevolucionesPedido, useRegistrarDevolucion } from '../hooks/useCaja';
+
+function formatMonto(valor: number): string {
+  return valor.toLocaleString('es-AR', {
+    minimumFractionDigits: 2,
+    maximumFractionDigits: 2,
+  });
+}
+
+function formatHora(isoFecha: string): string {
+  const d = new Date(isoFecha);
+  return `${String(d.getHours()).padStart(2, '0')}:${String(d.getMinutes()).padStart(2, '0')}`;
+}
+
+const inputBase = [
+  'w-full bg-neutral-800 border-2 border-neutral-700 rounded-xl',
+  'text-gray-200 placeholder:text-neutral-600',
+  'focus:border-amber-500 focus:outline-none',
+  'transition-colors disabled:opacity-50',
+].join(' ');
+
+const labelBase = 'block text-sm font-medium text-gray-400 uppercase tracking-wide';
+
+interface DevolucionModalProps {
+  /** Pedido cerrado sobre el que se reintegra */
+  pedidoId: string;
+  onClose: () => void;
+}
+
+/**
+ * Modal de devolución sobre un pedido ya cerrado (HU-121).
+ *
+ * Muestra lo cobrado, lo ya devuelto y lo disponible. El reintegro lo
+ * autoriza un encargado con su PIN; solo aparecen en la lista los
+ * encargados activos que tienen PIN configurado.
+ *
+ * El pedido no se modifica: se registra un egreso asociado que resta de
+ * las ventas del día y del efectivo esperado en el cierre.
+ */
+export default function DevolucionModal({ pedidoId, onClose }: DevolucionModalProps) {
+  const toast = useToast();
+  const { data: resumen, isLoading } = useDevolucionesPedido(pedidoId);
+  const { data: mozos = [] } = useMozos();
+  const registrar = useRegistrarDevolucion();
+
+  const [monto, setMonto] = useState('');
+  const [motivo, setMotivo] = useState('');
+  const [encargadoId, setEncargadoId] = useState('');
+  const [pin, setPin] = useState('');
+
+  const encargados = mozos.filter(puedeAutorizar);
+
+  const disponible = resumen?.disponible ?? 0;
+  const montoNumerico = parseFloat(monto);
+  const montoValido = !isNaN(montoNumerico) && montoNumerico > 0 && montoNumerico <= disponible;
+  const formularioValido =
+    montoValido && motivo.trim().length > 0 && encargadoId !== '' && /^\d{4,6}$/.test(pin);
+
+  const isPending = registrar.isPending;
+
+  const handleSubmit = (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!formularioValido) return;
+
+    registrar.mutate(
+      {
+        pedidoId,
+        data: { monto: montoNumerico, motivo: motivo.trim(), encargadoId, pin },
+      },
+      {
+        onSuccess: (res) => {
+          toast.success(`Devolución de $${formatMonto(montoNumerico)} registrada en el pedido #${res.numeroPedido}`);
+          onClose();
+        },
+        onError: (err: any) => {
+          setPin('');
+          toast.error(err?.response?.data?.message || 'No se pudo registrar la devolución');
+        },
+      },
+    );
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div
+        className="fixed inset-0 z-[60] bg-black/70 backdrop-blur-sm animate-backdrop-in"
+        onClick={isPending ? undefined : onClose}
+        aria-hidden="true"
+      />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[70] flex items-center justify-center p-4">
+        <div
+          className={[
+            'bg-neutral-900 border-2 border-neutral-700 rounded-2xl',
+            'shadow-2xl shadow-black/60',
+            'w-full max-w-md max-h-[90vh] overflow-y-auto',
+            'animate-modal-in',
+          ].join(' ')}
+          role="dialog"
+          aria-modal="true"
+          aria-labelledby="devolucion-modal-title"
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-neutral-800">
+            <h2 id="devolucion-modal-title" className="text-lg font-bold text-gray-100">
+              Devolución {resumen ? `· Pedido #${resumen.numeroPedido}` : ''}
+            </h2>
+            <button
+              type="button"
+              onClick={onClose}
+              disabled={isPending}
+              className={[
+                'w-10 h-10 rounded-xl flex items-center justify-center',
+                'text-gray-400 hover:text-gray-100 hover:bg-neutral-800',
+                'transition-colors active:scale-95',
+              ].join(' ')}
+              aria-label="Cerrar"
+            >
+              <X size={20} />
+            </button>
+          </div>
+
+          {isLoading || !resumen ? (
+            <div className="px-6 py-10 flex items-center justify-center gap-2 text-sm text-gray-500">
+              <Loader2 size={16} className="animate-spin" /> Cargando pedido...
+            </div>
+          ) : (
+            <form onSubmit={handleSubmit} className="px-6 py-5 space-y-5">
+              {/* Resumen del pedido */}
+              <div className="grid grid-cols-3 gap-2 text-center">
+                <div className="rounded-xl bg-neutral-800/60 px-2 py-2">
+                  <p className="text-[10px] text-gray-500 uppercase tracking-wider">Cobrado</p>
+                  <p className="text-sm font-mono font-semibold text-gray-200 tabular-nums">
+                    ${formatMonto(resumen.totalCobrado)}
+                  </p>
+                </div>
+                <div className="rounded-xl bg-neutral-800/60 px-2 py-2">
+                  <p className="text-[10px] text-gray-500 uppercase tracking-wider">Devuelto</p>
+                  <p className="text-sm font-mono font-semibold text-amber-400/90 tabular-nums">
+                    ${formatMonto(resumen.totalDevuelto)}
+                  </p>
+                </div>
+                <div className="rounded-xl bg-neutral-800/60 px-2 py-2">
+                  <p className="text-[10px] text-gray-500 uppercase tracking-wider">Disponible</p>
+                  <p className="text-sm font-mono font-semibold text-emerald-400/90 tabular-nums">
+                    ${formatMonto(resumen.disponible)}
+                  </p>
+                </div>
+              </div>
+
+              {/* Devoluciones anteriores */}
+              {resumen.devoluciones.length > 0 && (
+                <ul className="space-y-1.5">
+                  {resumen.devoluciones.map((d) => (
+                    <li key={d.id} className="flex items-center gap-2 text-xs text-gray-400">
+                      <Undo2 size={12} className="text-amber-500/70 shrink-0" />
+                      <span className="font-mono text-gray-600">{formatHora(d.fecha)}</span>
+                      <span className="flex-1 truncate">{d.descripcion}</span>
+                      {d.autorizadoPor && <span className="text-gray-600">{d.autorizadoPor}</span>}
+                      <span className="font-mono tabular-nums text-amber-400/90">
+                        −${formatMonto(d.monto)}
+                      </span>
+                    </li>
+                  ))}
+                </ul>
+              )}
+
+              {disponible <= 0 ? (
+                <p className="text-sm text-gray-500">
+                  Ya se devolvió todo lo cobrado en este pedido.
+                </p>
+              ) : (
+                <>
+                  {/* Monto */}
+                  <div className="space-y-2">
+                    <label htmlFor="devolucion-monto" className={labelBase}>
+                      Monto a devolver
+                    </label>
+                    <div className="relative">
+                      <span className="absolute left-4 top-1/2 -translate-y-1/2 text-2xl font-bold text-gray-500">
+                        $
+                      </span>
+                      <input
+                        id="devolucion-monto"
+                        type="number"
+                        inputMode="decimal"
+                        step="0.01"
+                        min="0.01"
+                        max={disponible}
+                        value={monto}
+                        onChange={(e) => setMonto(e.target.value)}
+                        disabled={isPending}
+                        placeholder="0.00"
+                        autoFocus
+                        className={`${inputBase} h-16 pl-10 pr-4 text-3xl font-bold font-mono text-right`}
+                      />
+                    </div>
+                    <button
+                      type="button"
+                      onClick={() => setMonto(String(disponible))}
+                      disabled={isPending}
+                      className="text-xs text-amber-500 hover:text-amber-400 transition-colors"
+                    >
+                      Devolver todo (${formatMonto(disponible)})
+                    </button>
+                  </div>
+
+                  {/* Motivo */}
+                  <div className="space-y-2">
+                    <label htmlFor="devolucion-motivo" className={labelBase}>
+                      Motivo
+                    </label>
+                    <textarea
+                      id="devolucion-motivo"
+                      value={motivo}
+                      onChange={(e) => setMotivo(e.target.value)}
+                      disabled={isPending}
+                      maxLength={200}
+                      rows={2}
+                      placeholder="Ej: Plato en mal estado, cobro duplicado..."
+                      className={`${inputBase} px-4 py-3 text-base resize-none`}
+                    />
+                  </div>
+
+                  {/* Autorización */}
+                  <div className="space-y-2">
+                    <label htmlFor="devolucion-encargado" className={labelBase}>
+                      Autoriza
+                    </label>
+                    {encargados.length === 0 ? (
+                      <p className="text-xs text-amber-400/90">
+                        No hay encargados con PIN configurado. Asigná el rol y el PIN desde la pantalla de mozos.
+                      </p>
+                    ) : (
+                      <div className="flex gap-2">
+                        <select
+                          id="devolucion-encargado"
+                          value={encargadoId}
+                          onChange={(e) => setEncargadoId(e.target.value)}
+                          disabled={isPending}
+                          className={`${inputBase} h-12 px-3 text-sm flex-1`}
+                        >
+                          <option value="">Encargado...</option>
+                          {encargados.map((m) => (
+                            <option key={m.id} value={m.id}>
+                              {m.nombre}
+                            </option>
+                          ))}
+                        </select>
+                        <div className="relative w-32">
+                          <KeyRound
+                            size={14}
+                            className="absolute left-3 top-1/2 -translate-y-1/2 text-gray-500"
+                          />
+                          <input
+                            type="password"
+                            inputMode="numeric"
+                            maxLength={6}
+                            value={pin}
+                            onChange={(e) => setPin(e.target.value.replace(/\D/g, ''))}
+                            disabled={isPending}
+                            placeholder="PIN"
+                            aria-label="PIN del encargado"
+                            className={`${inputBase} h-12 pl-9 pr-3 text-sm font-mono`}
+                          />
+                        </div>
+                      </div>
+                    )}
+                  </div>
+
+                  {/* Botón confirmar */}
+                  <button
+                    type="submit"
+                    disabled={isPending || !formularioValido}
+                    className={[
+                      'w-full h-14 rounded-xl font-semibold text-base',
+                      'flex items-center justify-center gap-3',
+                      'transition-all duration-150',
+                      'focus:outline-none focus-visible:ring-2 focus-visible:ring-amber-400',
+                      isPending
+                        ? 'bg-neutral-700 text-gray-400 cursor-wait'
+                        : formularioValido
+                          ? 'bg-amber-600 hover:bg-amber-500 text-white active:scale-95'
+                          : 'bg-neutral-700 text-gray-500 cursor-not-allowed',
+                    ].join(' ')}
+                  >
+                    {isPending ? (
+                      <>
+                        <Loader2 size={20} className="animate-spin" />
+                        Registrando...
+                      </>
+                    ) : (
+                      'Confirmar Devolución'
+                    )}
+                  </button>
+                </>
+              )}
+            </form>
+          )}
+        </div>
+      </div>
+    </>
+  );
+}
@@ -1,5 +1,6 @@
 import { useState } from 'react';
 import { X, Loader2, Printer } from 'lucide-react';
+import { useCategoriasEgreso } from '../hooks/useCaja';
 import type { EgresoRequest } from '../types';
 
 interface EgresoModalProps {
@@ -16,6 +17,7 @@ interface EgresoModalProps {
  *
  * Inputs:
  * - Monto: input numérico grande, visual destacado
+ * - Categoría: limpieza, mercadería, etc. (HU-130, opcional)
  * - Motivo: textarea para justificación
  *
  * El botón de confirmación refleja el estado `isPending` del hook
@@ -26,8 +28,12 @@ interface EgresoModalProps {
 export default function EgresoModal({ onClose, onConfirmar, isPending }: EgresoModalProps) {
   const [monto, setMonto] = useState('');
   const [descripcion, setDescripcion] = useState('');
+  const [categoriaId, setCategoriaId] = useState('');
   const [error, setError] = useState('');
 
+  const { data: categorias = [] } = useCategoriasEgreso();
+  const categoriasActivas = categorias.filter((c) => c.activa);
+
   const montoNumerico = parseFloat(monto);
   const montoValido = !isNaN(montoNumerico) && montoNumerico > 0;
   const descripcionValida = descripcion.trim().length >= 3;
@@ -48,6 +54,7 @@ export default function EgresoModal({ onClose, onConfirmar, isPending }: EgresoM
     onConfirmar({
       monto: montoNumerico,
       descripcion: descripcion.trim(),
+      categoriaId: categoriaId || undefined,
     });
   };
 
@@ -134,6 +141,37 @@ export default function EgresoModal({ onClose, onConfirmar, isPending }: EgresoM
               </div>
             </div>
 
+            {/* Categoría (HU-130) */}
+            <div className="space-y-2">
+              <label
+                htmlFor="egreso-categoria"
+                className="block text-sm font-medium text-gray-400 uppercase tracking-wide"
+              >
+                Categoría
+              </label>
+              <select
+                id="egreso-categoria"
+                value={categoriaId}
+                onChange={(e) => setCategoriaId(e.target.value)}
+                disabled={isPending}
+                className={[
+                  'w-full h-12 px-3',
+                  'bg-neutral-800 border-2 border-neutral-700 rounded-xl',
+                  'text-sm text-gray-200',
+                  'focus:border-amber-500 focus:outline-none',
+                  'transition-colors',
+                  'disabled:opacity-50',
+                ].join(' ')}
+              >
+                <option value="">Sin categoría</option>
+                {categoriasActivas.map((c) => (
+                  <option key={c.id} value={c.id}>
+                    {c.nombre}
+                  </option>
+                ))}
+              </select>
+            </div>
+
             {/* Motivo */}
             <div className="space-y-2">
               <label
@@ -13,12 +13,27 @@ import {
   Banknote,
   CreditCard,
   Search,
+  Store,
+  Users,
+  FileSpreadsheet,
+  ShoppingCart,
+  Scale,
+  Percent,
+  Bike,
+  Navigation,
+  Gift,
+  Coins,
+  Ruler,
+  Hash,
+  BookOpen,
 } from 'lucide-react';
 import { Link } from 'react-router-dom';
 
-import { useHistorialJornadas } from '../hooks/useCaja';
+import { useHistorialJornadas, useTurnosJornada } from '../hooks/useCaja';
 import BotonDescargarPDF from './BotonDescargarPDF';
+import { TURNO_LABELS, claseDiferencia } from './PanelTurnos';
 import type { JornadaResumen } from '../types';
+import { LibroIvaVentasModal } from '../../facturacion';
 
 // ─── Utilidades ───────────────────────────────────────────────────────────────
 
@@ -210,6 +225,70 @@ function GraficoJornadas({ jornadas, metrica }: GraficoJornadasProps) {
   );
 }
 
+// ─── Componente: Arqueo por turno (HU-106) ────────────────────────────────────
+
+/** Se monta solo con la tarjeta expandida: la query corre bajo demanda. */
+function TurnosJornada({ jornadaId }: { jornadaId: string }) {
+  const { data } = useTurnosJornada(jornadaId);
+  if (!data || data.turnos.length === 0) return null;
+
+  return (
+    <div className="mt-3 pt-3 border-t border-neutral-700/40">
+      <p className="text-[10px] text-gray-500 uppercase tracking-wide mb-2">Arqueo por turno</p>
+      <ul className="space-y-1 text-xs">
+        {data.turnos.map((t) => (
+          <li key={t.id} className="flex justify-between gap-2">
+            <span className="text-gray-400">
+              {TURNO_LABELS[t.tipo]} · esperado {formatMonto(t.efectivoEsperado ?? 0)}
+            </span>
+            <span className={`font-mono tabular-nums ${claseDiferencia(t.diferencia)}`}>
+              {formatMonto(t.diferencia ?? 0)}
+            </span>
+          </li>
+        ))}
+        <li className="flex justify-between gap-2 pt-1 border-t border-neutral-800 font-medium">
+          <span className="text-gray-300">Diferencia total</span>
+          <span className={`font-mono tabular-nums ${claseDiferencia(data.diferenciaTotal)}`}>
+            {formatMonto(data.diferenciaTotal)}
+          </span>
+        </li>
+      </ul>
+    </div>
+  );
+}
+
+// ─── Componente: Arqueo por caja (HU-159) ─────────────────────────────────────
+
+/** Solo aparece si la jornada se cobró en más de una caja. */
+function CajasJornada({ jornadaId }: { jornadaId: string }) {
+  const { data } = useTurnosJornada(jornadaId);
+  if (!data || data.cajas.length < 2) return null;
+
+  return (
+    <div className="mt-3 pt-3 border-t border-neutral-700/40">
+      <p className="text-[10px] text-gray-500 uppercase tracking-wide mb-2">Arqueo por caja</p>
+      <ul className="space-y-1 text-xs">
+        {data.cajas.map((c) => (
+          <li key={c.cajaId ?? 'principal'} className="flex justify-between gap-2">
+            <span className="text-gray-400">
+              {c.nombre} · fondo {formatMonto(c.fondoInicial)} · {c.pedidos} pedidos
+            </span>
+            <span className="font-mono tabular-nums text-gray-300">
+              {formatMonto(c.balanceEfectivo)}
+            </span>
+          </li>
+        ))}
+        <li className="flex justify-between gap-2 pt-1 border-t border-neutral-800 font-medium">
+          <span className="text-gray-300">Efectivo total</span>
+          <span className="font-mono tabular-nums text-gray-200">
+            {formatMonto(data.efectivoTotal)}
+          </span>
+        </li>
+      </ul>
+    </div>
+  );
+}
+
 // ─── Componente: Tarjeta de jornada expandible ────────────────────────────────
 
 interface JornadaCardProps {
@@ -329,6 +408,10 @@ function JornadaCard({ jornada, isExpanded, onToggle }: JornadaCardProps) {
             </div>
           </div>
 
+          <TurnosJornada jornadaId={jornada.id} />
+
+          <CajasJornada jornadaId={jornada.id} />
+
           {/* Botón descarga de reporte PDF */}
           <div className="mt-3 pt-3 border-t border-neutral-700/40 flex justify-end">
             <BotonDescargarPDF jornadaId={jornada.id} label="Descargar reporte" />
@@ -445,6 +528,7 @@ export default function HistorialJornadasPage() {
   // ── Estado de UI ──
   const [expandedId, setExpandedId] = useState<string | null>(null);
   const [metrica, setMetrica] = useState<MetricaGrafico>('totalVentasReales');
+  const [libroIvaAbierto, setLibroIvaAbierto] = useState(false);
 
   // ── Data ──
   const { data: jornadas, isLoading } = useHistorialJornadas(desde, hasta);
@@ -496,6 +580,111 @@ export default function HistorialJornadasPage() {
               Consulta de cierres de caja anteriores
             </p>
           </div>
+          <Link
+            to="/caja/mozos"
+            className="ml-auto h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Users size={14} />
+            Mozos
+          </Link>
+          <Link
+            to="/caja/gastos"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <ShoppingCart size={14} />
+            Gastos
+          </Link>
+          <Link
+            to="/caja/egresos"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Wallet size={14} />
+            Egresos
+          </Link>
+          <Link
+            to="/caja/diferencias"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Scale size={14} />
+            Diferencias
+          </Link>
+          <Link
+            to="/caja/recargos"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Percent size={14} />
+            Recargos
+          </Link>
+          <Link
+            to="/caja/tipos-cambio"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Coins size={14} />
+            Cambio
+          </Link>
+          <Link
+            to="/caja/redondeo"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Ruler size={14} />
+            Redondeo
+          </Link>
+          <Link
+            to="/caja/jornada-comercial"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Clock size={14} />
+            Jornada
+          </Link>
+          <Link
+            to="/caja/series"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Hash size={14} />
+            Numeración
+          </Link>
+          <Link
+            to="/caja/tarjetas-regalo"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Gift size={14} />
+            Gift cards
+          </Link>
+          <Link
+            to="/caja/sucursales"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Store size={14} />
+            Sucursales
+          </Link>
+          <Link
+            to="/caja/delivery"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Bike size={14} />
+            Delivery
+          </Link>
+          <Link
+            to="/caja/repartos"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Navigation size={14} />
+            Repartos
+          </Link>
+          <button
+            onClick={() => setLibroIvaAbierto(true)}
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <FileSpreadsheet size={14} />
+            Libro IVA
+          </button>
+          <Link
+            to="/caja/contabilidad"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <BookOpen size={14} />
+            Contabilidad
+          </Link>
         </header>
 
         {/* ── Controles de rango ── */}
@@ -635,6 +824,8 @@ export default function HistorialJornadasPage() {
           </div>
         </div>
       </div>
+
+      {libroIvaAbierto && <LibroIvaVentasModal onClose={() => setLibroIvaAbierto(false)} />}
     </section>
   );
 }
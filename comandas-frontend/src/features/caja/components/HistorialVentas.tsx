@@ -1,4 +1,4 @@
-import { RotateCcw, ShoppingBag } from 'lucide-react';
+import { RotateCcw, ShoppingBag, FileText, Undo2 } from 'lucide-react';
 import type { VentaResumen } from '../types';
 
 // ─── Utilidad de formato ──────────────────────────────────────────────────────
@@ -22,6 +22,10 @@ interface HistorialVentasProps {
   isLoading: boolean;
   /** Callback para abrir el modal de corrección (recibe pedidoId) */
   onCorregirPedido: (pedidoId: string) => void;
+  /** HU-115: Callback para emitir o reimprimir la factura (recibe pedidoId) */
+  onFacturarPedido: (pedidoId: string) => void;
+  /** HU-121: Callback para registrar una devolución (recibe pedidoId) */
+  onDevolverPedido: (pedidoId: string) => void;
 }
 
 /**
@@ -31,6 +35,8 @@ interface HistorialVentasProps {
  * - Hora de cierre
  * - Mesa + Nº de pedido
  * - Monto total cobrado
+ * - Botón "Factura" para emitirla o reimprimirla (HU-115)
+ * - Botón "Devolver" para reintegrar parte o todo lo cobrado (HU-121)
  * - Botón "Corregir" para reabrir el pedido
  *
  * Diseño ticket-style: filas planas con divisor sutil,
@@ -40,6 +46,8 @@ export default function HistorialVentas({
   ventas,
   isLoading,
   onCorregirPedido,
+  onFacturarPedido,
+  onDevolverPedido,
 }: HistorialVentasProps) {
 
   // ── Skeleton ────────────────────────────────────────────────────────────
@@ -118,6 +126,40 @@ export default function HistorialVentas({
               +${formatMonto(venta.total)}
             </span>
 
+            {/* HU-115: Botón Factura */}
+            <button
+              type="button"
+              onClick={() => onFacturarPedido(venta.pedidoId)}
+              className={[
+                'shrink-0 flex items-center gap-1.5 px-3 py-1.5 rounded-lg',
+                'text-xs font-medium',
+                'text-gray-400 bg-neutral-800 border border-neutral-700',
+                'hover:text-gray-200 hover:border-neutral-600',
+                'transition-all duration-150 active:scale-95',
+              ].join(' ')}
+              aria-label={`Factura del pedido #${venta.numeroPedido}`}
+            >
+              <FileText size={13} />
+              Factura
+            </button>
+
+            {/* HU-121: Botón Devolver */}
+            <button
+              type="button"
+              onClick={() => onDevolverPedido(venta.pedidoId)}
+              className={[
+                'shrink-0 flex items-center gap-1.5 px-3 py-1.5 rounded-lg',
+                'text-xs font-medium',
+                'text-gray-400 bg-neutral-800 border border-neutral-700',
+                'hover:text-amber-400 hover:border-amber-800/50 hover:bg-amber-950/20',
+                'transition-all duration-150 active:scale-95',
+              ].join(' ')}
+              aria-label={`Devolución del pedido #${venta.numeroPedido}`}
+            >
+              <Undo2 size={13} />
+              Devolver
+            </button>
+
             {/* Botón Corregir pedido */}
             <button
               type="button"
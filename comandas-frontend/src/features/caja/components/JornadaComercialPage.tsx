@@ -0,0 +1,138 @@
+import { useEffect, useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Loader2 } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useGuardarJornadaComercial, useJornadaComercial } from '../hooks/useCaja';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+/** "2026-10-10T04:00:00" → "10/10 04:00" */
+function fmtMomento(iso: string): string {
+  const [fecha, hora] = iso.split('T');
+  const [, m, d] = fecha.split('-');
+  return `${d}/${m} ${hora.slice(0, 5)}`;
+}
+
+function fmtFecha(iso: string): string {
+  const [y, m, d] = iso.split('-');
+  return `${d}/${m}/${y}`;
+}
+
+const CORTES = ['00:00', '03:00', '04:00', '05:00', '06:00'];
+
+/** Entre 00:00 y 11:59, como valida el backend */
+function esValida(hora: string): boolean {
+  return /^\d{2}:\d{2}$/.test(hora) && hora < '12:00';
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Jornada comercial (HU-153).
+ *
+ * Hora en la que termina el día del local. Lo que se cobra antes del corte
+ * suma al día anterior: caja, cierres y reportes diarios cortan ahí en lugar
+ * de a medianoche. Las jornadas ya abiertas o cerradas no se mueven.
+ */
+export default function JornadaComercialPage() {
+  const toast = useToast();
+  const { data: jornada, isLoading, isError } = useJornadaComercial();
+  const guardar = useGuardarJornadaComercial();
+
+  const [horaCorte, setHoraCorte] = useState('06:00');
+
+  useEffect(() => {
+    if (!jornada) return;
+    setHoraCorte(jornada.horaCorte.slice(0, 5));
+  }, [jornada]);
+
+  const valida = esValida(horaCorte);
+  const puedeGuardar = valida && !guardar.isPending;
+
+  const handleGuardar = () => {
+    guardar.mutate(horaCorte, {
+      onSuccess: () => toast.success('Hora de corte guardada'),
+      onError: (err: any) =>
+        toast.error(err?.response?.data?.message || 'No se pudo guardar la hora de corte'),
+    });
+  };
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/historial"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Jornada comercial</h1>
+            <p className="text-sm text-gray-500">A qué hora termina el día del local</p>
+          </div>
+        </header>
+
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando jornada...
+          </div>
+        ) : isError ? (
+          <p className="text-sm text-red-400">No se pudo cargar la jornada comercial.</p>
+        ) : (
+          <div className="max-w-xl rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-4">
+            <div className="space-y-2">
+              <label className="text-xs uppercase tracking-wider text-gray-600">Hora de corte</label>
+              <div className="flex flex-wrap items-center gap-2">
+                {CORTES.map((c) => (
+                  <button
+                    key={c}
+                    onClick={() => setHoraCorte(c)}
+                    className={`h-9 px-3 rounded-lg text-sm font-mono transition-colors ${
+                      horaCorte === c ? 'bg-red-600 text-white' : 'bg-neutral-800 text-gray-300 hover:bg-neutral-700'
+                    }`}
+                  >
+                    {c}
+                  </button>
+                ))}
+                <input
+                  type="time"
+                  value={horaCorte}
+                  onChange={(e) => setHoraCorte(e.target.value)}
+                  className={`w-32 h-9 px-2 bg-neutral-800 border rounded-lg font-mono text-gray-100 focus:outline-none ${
+                    valida ? 'border-neutral-700 focus:border-red-500/50' : 'border-red-500'
+                  }`}
+                />
+              </div>
+            </div>
+
+            {jornada && (
+              <p className="text-sm text-gray-400">
+                Jornada en curso: <span className="text-gray-200">{fmtFecha(jornada.fechaOperativa)}</span>, del{' '}
+                <span className="font-mono">{fmtMomento(jornada.inicio)}</span> al{' '}
+                <span className="font-mono">{fmtMomento(jornada.fin)}</span>.
+              </p>
+            )}
+
+            <p className="text-xs text-gray-500">
+              Entre las 00:00 y las 11:59; a las 00:00 la jornada es el día calendario. Lo cobrado antes del corte
+              suma al día anterior en la caja, los cierres y los reportes. Las jornadas ya abiertas o cerradas no
+              cambian.
+            </p>
+
+            <div className="flex justify-end">
+              <button
+                onClick={handleGuardar}
+                disabled={!puedeGuardar}
+                className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+              >
+                {guardar.isPending && <Loader2 size={16} className="animate-spin" />}
+                Guardar
+              </button>
+            </div>
+          </div>
+        )}
+      </div>
+    </section>
+  );
+}
@@ -1,5 +1,5 @@
 import { useState } from 'react';
-import { RotateCcw, Receipt, Loader2, AlertTriangle, ArrowDownCircle, ArrowUpCircle, ShoppingBag } from 'lucide-react';
+import { RotateCcw, Receipt, Loader2, AlertTriangle, ArrowDownCircle, ArrowUpCircle, ShoppingBag, Undo2 } from 'lucide-react';
 import type { MovimientoResumen } from '../types';
 
 // ─── Utilidad de formato ──────────────────────────────────────────────────────
@@ -186,6 +186,8 @@ export default function ListaMovimientosDia({
 
   const esPedido = (mov: MovimientoResumen) => mov.tipo === 'PEDIDO';
   const esIngreso = (mov: MovimientoResumen) => mov.tipo === 'INGRESO';
+  // HU-121: egreso asociado a un pedido cerrado
+  const esDevolucion = (mov: MovimientoResumen) => mov.tipo === 'EGRESO' && !!mov.pedidoId;
 
   // ── Lista ticket ────────────────────────────────────────────────────────
 
@@ -195,6 +197,7 @@ export default function ListaMovimientosDia({
         {movimientos.map((mov, idx) => {
           const pedido = esPedido(mov);
           const ingreso = esIngreso(mov);
+          const devolucion = esDevolucion(mov);
 
           return (
             <div
@@ -220,6 +223,8 @@ export default function ListaMovimientosDia({
                   <ShoppingBag size={15} />
                 ) : ingreso ? (
                   <ArrowUpCircle size={15} />
+                ) : devolucion ? (
+                  <Undo2 size={15} />
                 ) : (
                   <ArrowDownCircle size={15} />
                 )}
@@ -237,6 +242,7 @@ export default function ListaMovimientosDia({
                 </p>
                 <p className="text-[11px] text-gray-600 font-mono truncate mt-0.5">
                   {mov.numeroComprobante}
+                  {devolucion && <span className="ml-2 text-amber-500/70 font-sans">Devolución</span>}
                 </p>
               </div>
 
@@ -0,0 +1,182 @@
+import { useState } from 'react';
+import { Loader2, MonitorSmartphone, PlayCircle, Plus, Undo2 } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useCajasLocal, useRegistrarCaja, useAbrirCajaLocal } from '../hooks/useCaja';
+import { guardarCajaTerminal, obtenerCajaTerminal } from '../../../lib/cajaTerminal';
+import type { CajaLocal } from '../types';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+const inputClass =
+  'h-9 px-2 rounded-lg bg-neutral-800 border border-neutral-700 text-sm text-gray-200 focus:outline-none focus:border-red-600 disabled:opacity-50';
+
+// ─── Fila de caja ─────────────────────────────────────────────────────────────
+
+function FilaCaja({ caja, esEstaTerminal }: { caja: CajaLocal; esEstaTerminal: boolean }) {
+  return (
+    <li className="flex items-center justify-between gap-2 py-2 text-sm">
+      <p className="font-medium text-gray-200 min-w-0 truncate">
+        {caja.nombre}
+        {esEstaTerminal && (
+          <span className="ml-2 text-[10px] uppercase tracking-wide text-sky-400">esta terminal</span>
+        )}
+      </p>
+      {caja.abierta ? (
+        <span className="text-[11px] text-gray-500 shrink-0">
+          fondo $ {fmt(caja.fondoInicial ?? 0)}
+        </span>
+      ) : (
+        <span className="text-[10px] uppercase tracking-wide text-amber-400 shrink-0">sin abrir</span>
+      )}
+    </li>
+  );
+}
+
+// ─── Componente ───────────────────────────────────────────────────────────────
+
+interface PanelCajasProps {
+  /** true si hay jornada abierta (habilita la query) */
+  habilitado: boolean;
+}
+
+/**
+ * Cajas del local (HU-159).
+ *
+ * Cuando se cobra en más de una terminal, cada una se registra como caja y
+ * declara su propio fondo al empezar la jornada. Lo que cobra, los egresos
+ * y los ingresos quedan a su nombre, y al cierre cada caja tiene su arqueo.
+ * Una terminal sin caja registrada opera la caja principal, como siempre.
+ */
+export default function PanelCajas({ habilitado }: PanelCajasProps) {
+  const toast = useToast();
+  const { data: cajas = [], isLoading } = useCajasLocal(habilitado);
+  const registrarCaja = useRegistrarCaja();
+  const abrirCaja = useAbrirCajaLocal();
+
+  const [cajaTerminalId, setCajaTerminalId] = useState(obtenerCajaTerminal);
+  const [nombre, setNombre] = useState('');
+  const [montoInicial, setMontoInicial] = useState('');
+
+  const cajaTerminal = cajas.find((c) => c.id === cajaTerminalId);
+
+  const handleRegistrar = () => {
+    if (!nombre.trim()) return;
+    registrarCaja.mutate(nombre.trim(), {
+      onSuccess: (caja) => {
+        setNombre('');
+        setCajaTerminalId(caja.id);
+        toast.success(`Esta terminal cobra en ${caja.nombre}. Declará su fondo para empezar.`);
+      },
+      onError: (err: any) =>
+        toast.error(err?.response?.data?.message || 'No se pudo registrar la caja'),
+    });
+  };
+
+  const handleAbrir = () => {
+    if (!cajaTerminal) return;
+    abrirCaja.mutate(
+      { cajaId: cajaTerminal.id, montoInicial: parseFloat(montoInicial) || 0 },
+      {
+        onSuccess: (caja) => {
+          setMontoInicial('');
+          toast.success(`${caja.nombre} abierta`);
+        },
+        onError: (err: any) =>
+          toast.error(err?.response?.data?.message || 'No se pudo abrir la caja'),
+      },
+    );
+  };
+
+  const handleUsarPrincipal = () => {
+    guardarCajaTerminal(null);
+    setCajaTerminalId(null);
+    toast.info('Esta terminal vuelve a cobrar en la caja principal');
+  };
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-3">
+      <div className="flex items-center gap-2">
+        <MonitorSmartphone size={14} className="text-gray-500" />
+        <h2 className="text-xs text-gray-500 uppercase tracking-wider font-medium">Cajas</h2>
+        {isLoading && <Loader2 size={12} className="animate-spin text-gray-600" />}
+      </div>
+
+      {cajas.length > 0 && (
+        <ul className="divide-y divide-neutral-800">
+          {cajas.map((c) => (
+            <FilaCaja key={c.id} caja={c} esEstaTerminal={c.id === cajaTerminalId} />
+          ))}
+        </ul>
+      )}
+
+      {cajaTerminal ? (
+        <div className="space-y-2">
+          {!cajaTerminal.abierta && (
+            <div className="flex items-end gap-2">
+              <label className="flex-1 space-y-1 text-xs text-gray-500">
+                <span className="block">Fondo de {cajaTerminal.nombre}</span>
+                <input
+                  type="number"
+                  min="0"
+                  step="0.01"
+                  value={montoInicial}
+                  onChange={(e) => setMontoInicial(e.target.value)}
+                  disabled={abrirCaja.isPending}
+                  placeholder="0"
+                  className={`${inputClass} w-full text-right font-mono`}
+                />
+              </label>
+              <button
+                type="button"
+                onClick={handleAbrir}
+                disabled={abrirCaja.isPending}
+                className="h-9 px-3 rounded-lg bg-emerald-600 hover:bg-emerald-500 text-sm text-white flex items-center gap-1.5 disabled:opacity-40"
+              >
+                {abrirCaja.isPending ? <Loader2 size={14} className="animate-spin" /> : <PlayCircle size={14} />}
+                Abrir
+              </button>
+            </div>
+          )}
+          <button
+            type="button"
+            onClick={handleUsarPrincipal}
+            className="text-[11px] text-gray-500 hover:text-gray-300 flex items-center gap-1"
+          >
+            <Undo2 size={12} />
+            Cobrar en la caja principal
+          </button>
+        </div>
+      ) : (
+        <div className="space-y-2">
+          <p className="text-xs text-gray-600">
+            Esta terminal cobra en la caja principal. Si tiene su propio cajón, registrala como caja.
+          </p>
+          <div className="flex items-end gap-2">
+            <input
+              type="text"
+              maxLength={40}
+              value={nombre}
+              onChange={(e) => setNombre(e.target.value)}
+              disabled={registrarCaja.isPending}
+              placeholder="Ej: Barra"
+              className={`${inputClass} flex-1`}
+            />
+            <button
+              type="button"
+              onClick={handleRegistrar}
+              disabled={registrarCaja.isPending || !nombre.trim()}
+              className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-200 flex items-center gap-1.5 disabled:opacity-40"
+            >
+              {registrarCaja.isPending ? <Loader2 size={14} className="animate-spin" /> : <Plus size={14} />}
+              Registrar
+            </button>
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
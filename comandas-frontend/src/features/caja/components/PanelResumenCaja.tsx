@@ -1,4 +1,4 @@
-import { Wallet, TrendingUp, TrendingDown, ArrowDownCircle, ArrowUpCircle, Lock, Loader2, Users } from 'lucide-react';
+import { Wallet, TrendingUp, TrendingDown, ArrowDownCircle, ArrowUpCircle, Lock, Loader2, Users, Undo2, Percent, Coins, Ruler } from 'lucide-react';
 import type { ReporteCajaDerivado } from '../types';
 
 // ─── Utilidad ─────────────────────────────────────────────────────────────────
@@ -147,6 +147,80 @@ export default function PanelResumenCaja({
           </span>
         </div>
 
+        {/* HU-121: Devoluciones post-cierre (ya descontadas de las ventas) */}
+        {reporte.totalDevoluciones > 0 && (
+          <div className="flex items-center justify-between mt-2.5">
+            <div className="flex items-center gap-2">
+              <Undo2 size={16} className="text-red-500/70" />
+              <span className="text-sm text-gray-400">Devoluciones</span>
+              <span className="text-[10px] text-gray-600">(ya restadas de ventas)</span>
+            </div>
+            <span className="text-lg font-semibold font-mono text-red-400">
+              −${fmt(reporte.totalDevoluciones)}
+            </span>
+          </div>
+        )}
+
+        {/* HU-143: Recargos por medio de pago — fuera de las ventas */}
+        {reporte.totalRecargos > 0 && (
+          <div className="mt-2.5">
+            <div className="flex items-center justify-between">
+              <div className="flex items-center gap-2">
+                <Percent size={16} className="text-amber-500/70" />
+                <span className="text-sm text-gray-400">Recargos</span>
+                <span className="text-[10px] text-gray-600">(aparte de ventas)</span>
+              </div>
+              <span className="text-lg font-semibold font-mono text-amber-400">
+                +${fmt(reporte.totalRecargos)}
+              </span>
+            </div>
+            {Object.entries(reporte.recargosPorMedioPago).map(([medio, monto]) => (
+              <div key={medio} className="flex justify-between pl-6 text-xs text-gray-500">
+                <span className="capitalize">{medio.replace('_', ' ').toLowerCase()}</span>
+                <span className="font-mono">${fmt(monto ?? 0)}</span>
+              </div>
+            ))}
+          </div>
+        )}
+
+        {/* HU-152: Ajuste por redondeo del efectivo — ya incluido en el balance */}
+        {(reporte.totalAjusteRedondeo ?? 0) !== 0 && (
+          <div className="mt-2.5 flex items-center justify-between">
+            <div className="flex items-center gap-2">
+              <Ruler size={16} className="text-sky-500/70" />
+              <span className="text-sm text-gray-400">Ajuste por redondeo</span>
+              <span className="text-[10px] text-gray-600">(efectivo, aparte de ventas)</span>
+            </div>
+            <span className="text-lg font-semibold font-mono text-sky-400">
+              {reporte.totalAjusteRedondeo > 0 ? '+' : '−'}${fmt(Math.abs(reporte.totalAjusteRedondeo))}
+            </span>
+          </div>
+        )}
+
+        {/* HU-151: Efectivo por moneda — se cuenta aparte de los pesos */}
+        {reporte.efectivoPorMoneda.length > 0 && (
+          <div className="mt-2.5">
+            <div className="flex items-center justify-between">
+              <div className="flex items-center gap-2">
+                <Coins size={16} className="text-sky-500/70" />
+                <span className="text-sm text-gray-400">Pesos en el cajón</span>
+                <span className="text-[10px] text-gray-600">(sin moneda extranjera)</span>
+              </div>
+              <span className="text-lg font-semibold font-mono text-gray-300">
+                ${fmt(reporte.efectivoPesos)}
+              </span>
+            </div>
+            {reporte.efectivoPorMoneda.map((efectivo) => (
+              <div key={efectivo.moneda} className="flex justify-between pl-6 text-xs text-gray-500">
+                <span className="font-mono">
+                  {efectivo.moneda} {fmt(efectivo.monto)}
+                </span>
+                <span className="font-mono">≈ ${fmt(efectivo.equivalentePesos)}</span>
+              </div>
+            ))}
+          </div>
+        )}
+
         {/* Consumos internos (A Cuenta) — apagado */}
         {reporte.totalConsumoInterno > 0 && (
           <div className="flex items-center justify-between mt-4 pt-3 border-t border-neutral-800/60">
@@ -0,0 +1,191 @@
+import { useState } from 'react';
+import { Clock, Loader2, PlayCircle, Printer, StopCircle } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useTurnosCaja, useAbrirTurno, imprimirArqueoTurno } from '../hooks/useCaja';
+import type { TipoTurno, TurnoCaja } from '../types';
+import ArqueoTurnoModal from './ArqueoTurnoModal';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+export const TURNO_LABELS: Record<TipoTurno, string> = {
+  MANANA: 'Mañana',
+  TARDE: 'Tarde',
+  NOCHE: 'Noche',
+};
+
+const TIPOS: TipoTurno[] = ['MANANA', 'TARDE', 'NOCHE'];
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function hora(iso: string | null): string {
+  if (!iso) return '—';
+  return new Date(iso).toLocaleTimeString('es-AR', { hour: '2-digit', minute: '2-digit' });
+}
+
+/** Clase de color según signo: faltante en rojo, sobrante en verde */
+export function claseDiferencia(diferencia: number | null): string {
+  if (diferencia == null || diferencia === 0) return 'text-gray-300';
+  return diferencia < 0 ? 'text-red-400' : 'text-emerald-400';
+}
+
+const inputClass =
+  'h-9 px-2 rounded-lg bg-neutral-800 border border-neutral-700 text-sm text-gray-200 font-mono focus:outline-none focus:border-red-600 disabled:opacity-50';
+
+// ─── Fila de turno ────────────────────────────────────────────────────────────
+
+function FilaTurno({ turno }: { turno: TurnoCaja }) {
+  const abierto = turno.estado === 'ABIERTO';
+
+  return (
+    <li className="flex items-center justify-between gap-2 py-2 text-sm">
+      <div className="min-w-0">
+        <p className="font-medium text-gray-200">
+          {TURNO_LABELS[turno.tipo]}
+          {abierto && (
+            <span className="ml-2 text-[10px] uppercase tracking-wide text-emerald-400">en curso</span>
+          )}
+        </p>
+        <p className="text-[11px] text-gray-500">
+          {hora(turno.fechaApertura)} – {abierto ? 'ahora' : hora(turno.fechaCierre)}
+          <span className="mx-1 text-gray-700">·</span>
+          inicial $ {fmt(turno.montoInicial)}
+        </p>
+      </div>
+      {!abierto && turno.diferencia != null && (
+        <div className="flex items-center gap-2 shrink-0">
+          <div className="text-right">
+            <p className={`font-mono tabular-nums ${claseDiferencia(turno.diferencia)}`}>
+              {turno.diferencia > 0 ? '+' : ''}{fmt(turno.diferencia)}
+            </p>
+            <p className="text-[10px] text-gray-600">esperado $ {fmt(turno.efectivoEsperado ?? 0)}</p>
+          </div>
+          <button
+            type="button"
+            onClick={() => void imprimirArqueoTurno(turno)}
+            className="w-8 h-8 rounded-lg text-gray-500 hover:text-gray-200 hover:bg-neutral-800 flex items-center justify-center"
+            title="Reimprimir arqueo"
+            aria-label="Reimprimir arqueo"
+          >
+            <Printer size={14} />
+          </button>
+        </div>
+      )}
+    </li>
+  );
+}
+
+// ─── Componente ───────────────────────────────────────────────────────────────
+
+interface PanelTurnosProps {
+  /** true si hay jornada abierta (habilita la query) */
+  habilitado: boolean;
+}
+
+/**
+ * Turnos de caja de la jornada en curso (HU-106).
+ *
+ * Permite abrir un turno con el efectivo del cajón y cerrarlo declarando
+ * lo contado. Al cerrar se muestra la diferencia contra lo esperado.
+ * Usar turnos es opcional: sin turno abierto la caja opera como siempre.
+ *
+ * HU-123: El cierre se hace con el asistente de conteo por denominación;
+ * los turnos cerrados permiten reimprimir el comprobante de arqueo.
+ */
+export default function PanelTurnos({ habilitado }: PanelTurnosProps) {
+  const toast = useToast();
+  const { data: turnos = [], isLoading } = useTurnosCaja(habilitado);
+  const abrirTurno = useAbrirTurno();
+
+  const [montoInicial, setMontoInicial] = useState('');
+  const [arqueoAbierto, setArqueoAbierto] = useState(false);
+
+  const turnoAbierto = turnos.find((t) => t.estado === 'ABIERTO');
+  const tiposDisponibles = TIPOS.filter((tipo) => !turnos.some((t) => t.tipo === tipo));
+  const [tipo, setTipo] = useState<TipoTurno | ''>('');
+  const tipoSeleccionado = tipo && tiposDisponibles.includes(tipo) ? tipo : tiposDisponibles[0];
+
+  const handleAbrir = () => {
+    if (!tipoSeleccionado) return;
+    abrirTurno.mutate(
+      { tipo: tipoSeleccionado, montoInicial: parseFloat(montoInicial) || 0 },
+      {
+        onSuccess: (turno) => {
+          setMontoInicial('');
+          toast.success(`Turno ${TURNO_LABELS[turno.tipo].toLowerCase()} abierto`);
+        },
+        onError: (err: any) =>
+          toast.error(err?.response?.data?.message || 'No se pudo abrir el turno'),
+      },
+    );
+  };
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-3">
+      <div className="flex items-center gap-2">
+        <Clock size={14} className="text-gray-500" />
+        <h2 className="text-xs text-gray-500 uppercase tracking-wider font-medium">Turnos</h2>
+        {isLoading && <Loader2 size={12} className="animate-spin text-gray-600" />}
+      </div>
+
+      {turnos.length > 0 && (
+        <ul className="divide-y divide-neutral-800">
+          {turnos.map((t) => <FilaTurno key={t.id} turno={t} />)}
+        </ul>
+      )}
+
+      {turnoAbierto ? (
+        <button
+          type="button"
+          onClick={() => setArqueoAbierto(true)}
+          className="w-full h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-200 flex items-center justify-center gap-1.5"
+        >
+          <StopCircle size={14} />
+          Contar cajón y cerrar turno
+        </button>
+      ) : tiposDisponibles.length > 0 ? (
+        <div className="flex items-end gap-2">
+          <select
+            value={tipoSeleccionado}
+            onChange={(e) => setTipo(e.target.value as TipoTurno)}
+            disabled={abrirTurno.isPending}
+            className={`${inputClass} font-sans`}
+          >
+            {tiposDisponibles.map((t) => (
+              <option key={t} value={t}>{TURNO_LABELS[t]}</option>
+            ))}
+          </select>
+          <label className="flex-1 space-y-1 text-xs text-gray-500">
+            <span className="block">Monto inicial</span>
+            <input
+              type="number"
+              min="0"
+              step="0.01"
+              value={montoInicial}
+              onChange={(e) => setMontoInicial(e.target.value)}
+              disabled={abrirTurno.isPending}
+              placeholder="0"
+              className={`${inputClass} w-full text-right`}
+            />
+          </label>
+          <button
+            type="button"
+            onClick={handleAbrir}
+            disabled={abrirTurno.isPending}
+            className="h-9 px-3 rounded-lg bg-emerald-600 hover:bg-emerald-500 text-sm text-white flex items-center gap-1.5 disabled:opacity-40"
+          >
+            {abrirTurno.isPending ? <Loader2 size={14} className="animate-spin" /> : <PlayCircle size={14} />}
+            Abrir
+          </button>
+        </div>
+      ) : (
+        <p className="text-xs text-gray-600">Ya se usaron todos los turnos de la jornada.</p>
+      )}
+
+      {arqueoAbierto && turnoAbierto && (
+        <ArqueoTurnoModal tipo={turnoAbierto.tipo} onClose={() => setArqueoAbierto(false)} />
+      )}
+    </div>
+  );
+}
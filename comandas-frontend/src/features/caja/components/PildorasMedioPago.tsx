@@ -1,4 +1,4 @@
-import { DollarSign, CreditCard, QrCode, ArrowRightLeft } from 'lucide-react';
+import { DollarSign, CreditCard, QrCode, ArrowRightLeft, BookUser, Gift } from 'lucide-react';
 import type { MedioPago } from '../../salon/types';
 
 // ─── Utilidad ─────────────────────────────────────────────────────────────────
@@ -44,6 +44,18 @@ const MEDIOS: MedioPagoConfig[] = [
     icono: <ArrowRightLeft size={16} />,
     color: 'text-cyan-400',
   },
+  {
+    key: 'CUENTA_CORRIENTE',
+    label: 'Cta. Cte.',
+    icono: <BookUser size={16} />,
+    color: 'text-amber-400',
+  },
+  {
+    key: 'TARJETA_REGALO',
+    label: 'Gift card',
+    icono: <Gift size={16} />,
+    color: 'text-pink-400',
+  },
 ];
 
 // ─── Skeleton ─────────────────────────────────────────────────────────────────
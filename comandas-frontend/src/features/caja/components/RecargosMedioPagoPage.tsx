@@ -0,0 +1,165 @@
+import { useEffect, useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, ArrowRightLeft, CreditCard, DollarSign, Loader2, Percent, QrCode } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useGuardarRecargosMedioPago, useRecargosMedioPago } from '../hooks/useCaja';
+import type { MedioPago } from '../../salon/types';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+/** Medios que admiten recargo: a cuenta y cuenta corriente quedan afuera. */
+const MEDIOS: { medio: MedioPago; label: string; icon: React.ReactNode }[] = [
+  { medio: 'EFECTIVO', label: 'Efectivo', icon: <DollarSign size={16} /> },
+  { medio: 'TARJETA', label: 'Tarjeta', icon: <CreditCard size={16} /> },
+  { medio: 'QR', label: 'QR', icon: <QrCode size={16} /> },
+  { medio: 'TRANSFERENCIA', label: 'Transferencia', icon: <ArrowRightLeft size={16} /> },
+];
+
+const EJEMPLO = 10000;
+
+type Porcentajes = Partial<Record<MedioPago, string>>;
+
+function esValido(valor: string | undefined): boolean {
+  if (!valor) return true;
+  const n = Number(valor);
+  return Number.isFinite(n) && n >= 0 && n <= 100 && Math.abs(Math.round(n * 100) - n * 100) < 1e-9;
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Recargos porcentuales por medio de pago (HU-143).
+ *
+ * El recargo se suma encima de cada pago al cobrar y queda congelado en el
+ * pedido: cambiar el porcentaje no toca lo ya cobrado. En el cierre del día
+ * se informa aparte de las ventas.
+ */
+export default function RecargosMedioPagoPage() {
+  const toast = useToast();
+  const { data: recargos, isLoading, isError } = useRecargosMedioPago();
+  const guardar = useGuardarRecargosMedioPago();
+
+  const [porcentajes, setPorcentajes] = useState<Porcentajes>({});
+
+  useEffect(() => {
+    if (!recargos) return;
+    setPorcentajes(
+      Object.fromEntries(
+        MEDIOS.map(({ medio }) => [medio, recargos.porcentajes[medio] ? String(recargos.porcentajes[medio]) : '']),
+      ),
+    );
+  }, [recargos]);
+
+  const puedeGuardar = MEDIOS.every(({ medio }) => esValido(porcentajes[medio])) && !guardar.isPending;
+
+  const handleGuardar = () => {
+    const payload = Object.fromEntries(
+      MEDIOS.filter(({ medio }) => Number(porcentajes[medio] || 0) > 0).map(({ medio }) => [
+        medio,
+        Number(porcentajes[medio]),
+      ]),
+    ) as Partial<Record<MedioPago, number>>;
+    guardar.mutate(
+      { porcentajes: payload },
+      {
+        onSuccess: () => toast.success('Recargos guardados'),
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudieron guardar los recargos'),
+      },
+    );
+  };
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/historial"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Recargos por medio de pago</h1>
+            <p className="text-sm text-gray-500">Porcentaje que se cobra encima al pagar con cada medio</p>
+          </div>
+        </header>
+
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando recargos...
+          </div>
+        ) : isError ? (
+          <p className="text-sm text-red-400">No se pudieron cargar los recargos.</p>
+        ) : (
+          <div className="max-w-xl rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-4">
+            <table className="w-full text-sm">
+              <thead>
+                <tr className="text-[10px] uppercase tracking-wider text-gray-600">
+                  <th className="text-left font-medium pb-2">Medio</th>
+                  <th className="text-right font-medium pb-2">Recargo</th>
+                  <th className="text-right font-medium pb-2">Sobre $ {fmt(EJEMPLO)}</th>
+                </tr>
+              </thead>
+              <tbody className="divide-y divide-neutral-800 text-gray-300">
+                {MEDIOS.map(({ medio, label, icon }) => {
+                  const valor = porcentajes[medio] ?? '';
+                  const recargo = esValido(valor) ? (EJEMPLO * Number(valor || 0)) / 100 : 0;
+                  return (
+                    <tr key={medio}>
+                      <td className="py-2">
+                        <span className="flex items-center gap-2">
+                          <span className="text-gray-500">{icon}</span>
+                          {label}
+                        </span>
+                      </td>
+                      <td className="py-2 text-right">
+                        <span className="inline-flex items-center gap-1">
+                          <input
+                            type="number"
+                            min={0}
+                            max={100}
+                            step={0.01}
+                            value={valor}
+                            placeholder="0"
+                            onChange={(e) => setPorcentajes((prev) => ({ ...prev, [medio]: e.target.value }))}
+                            className={`w-20 h-9 px-2 bg-neutral-800 border rounded-lg text-right font-mono text-gray-100 focus:outline-none ${
+                              esValido(valor) ? 'border-neutral-700 focus:border-red-500/50' : 'border-red-500'
+                            }`}
+                          />
+                          <Percent size={13} className="text-gray-600" />
+                        </span>
+                      </td>
+                      <td className="py-2 text-right font-mono tabular-nums text-gray-500">
+                        {recargo > 0 ? `+ $ ${fmt(recargo)}` : '—'}
+                      </td>
+                    </tr>
+                  );
+                })}
+              </tbody>
+            </table>
+
+            <p className="text-xs text-gray-500">
+              Entre 0% y 100%, con hasta dos decimales. A cuenta y cuenta corriente no llevan recargo.
+              Los cambios valen para los cobros que se hagan desde ahora.
+            </p>
+
+            <div className="flex justify-end">
+              <button
+                onClick={handleGuardar}
+                disabled={!puedeGuardar}
+                className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+              >
+                {guardar.isPending && <Loader2 size={16} className="animate-spin" />}
+                Guardar
+              </button>
+            </div>
+          </div>
+        )}
+      </div>
+    </section>
+  );
+}
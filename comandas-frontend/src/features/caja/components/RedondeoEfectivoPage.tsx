@@ -0,0 +1,176 @@
+import { useEffect, useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Loader2 } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useGuardarRedondeoEfectivo, useRedondeoEfectivo } from '../hooks/useCaja';
+import type { ModoRedondeo } from '../types';
+import { calcularAjusteRedondeo } from '../utils/redondeo';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+const MULTIPLOS = [10, 50, 100, 500, 1000];
+
+const MODOS: { modo: ModoRedondeo; label: string }[] = [
+  { modo: 'CERCANO', label: 'Al más cercano' },
+  { modo: 'ABAJO', label: 'Hacia abajo' },
+  { modo: 'ARRIBA', label: 'Hacia arriba' },
+];
+
+const EJEMPLO = 12340;
+
+function esValido(valor: string): boolean {
+  if (!valor) return true;
+  const n = Number(valor);
+  return Number.isFinite(n) && n >= 0 && n <= 1000 && Math.abs(Math.round(n * 100) - n * 100) < 1e-6;
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Redondeo del efectivo (HU-152).
+ *
+ * A qué múltiplo se redondea lo que se cobra en efectivo en pesos. El total
+ * del pedido no cambia: la diferencia figura aparte como ajuste por redondeo
+ * en el cobro y en el arqueo. Tarjeta, transferencia y moneda extranjera se
+ * cobran al centavo.
+ */
+export default function RedondeoEfectivoPage() {
+  const toast = useToast();
+  const { data: redondeo, isLoading, isError } = useRedondeoEfectivo();
+  const guardar = useGuardarRedondeoEfectivo();
+
+  const [multiplo, setMultiplo] = useState('');
+  const [modo, setModo] = useState<ModoRedondeo>('CERCANO');
+
+  useEffect(() => {
+    if (!redondeo) return;
+    setMultiplo(redondeo.multiplo ? String(redondeo.multiplo) : '');
+    setModo(redondeo.modo);
+  }, [redondeo]);
+
+  const valido = esValido(multiplo);
+  const puedeGuardar = valido && !guardar.isPending;
+  const ajusteEjemplo = valido ? calcularAjusteRedondeo(EJEMPLO, Number(multiplo || 0) || null, modo) : 0;
+
+  const handleGuardar = () => {
+    guardar.mutate(
+      { multiplo: Number(multiplo || 0) > 0 ? Number(multiplo) : null, modo },
+      {
+        onSuccess: () => toast.success('Redondeo guardado'),
+        onError: (err: any) =>
+          toast.error(err?.response?.data?.message || 'No se pudo guardar el redondeo'),
+      },
+    );
+  };
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/historial"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Redondeo del efectivo</h1>
+            <p className="text-sm text-gray-500">Cobrar el efectivo en pesos a un múltiplo fijo</p>
+          </div>
+        </header>
+
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando redondeo...
+          </div>
+        ) : isError ? (
+          <p className="text-sm text-red-400">No se pudo cargar el redondeo.</p>
+        ) : (
+          <div className="max-w-xl rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-4">
+            <div className="space-y-2">
+              <label className="text-xs uppercase tracking-wider text-gray-600">Múltiplo</label>
+              <div className="flex flex-wrap items-center gap-2">
+                {MULTIPLOS.map((m) => (
+                  <button
+                    key={m}
+                    onClick={() => setMultiplo(String(m))}
+                    className={`h-9 px-3 rounded-lg text-sm font-mono transition-colors ${
+                      Number(multiplo) === m
+                        ? 'bg-red-600 text-white'
+                        : 'bg-neutral-800 text-gray-300 hover:bg-neutral-700'
+                    }`}
+                  >
+                    $ {m}
+                  </button>
+                ))}
+                <span className="inline-flex items-center gap-1">
+                  <span className="text-gray-600">$</span>
+                  <input
+                    type="number"
+                    min={0}
+                    max={1000}
+                    step={0.01}
+                    value={multiplo}
+                    placeholder="Sin redondeo"
+                    onChange={(e) => setMultiplo(e.target.value)}
+                    className={`w-32 h-9 px-2 bg-neutral-800 border rounded-lg text-right font-mono text-gray-100 focus:outline-none ${
+                      valido ? 'border-neutral-700 focus:border-red-500/50' : 'border-red-500'
+                    }`}
+                  />
+                </span>
+              </div>
+            </div>
+
+            <div className="space-y-2">
+              <label className="text-xs uppercase tracking-wider text-gray-600">Sentido</label>
+              <div className="flex gap-2">
+                {MODOS.map((m) => (
+                  <button
+                    key={m.modo}
+                    onClick={() => setModo(m.modo)}
+                    className={`h-9 px-3 rounded-lg text-sm transition-colors ${
+                      modo === m.modo ? 'bg-red-600 text-white' : 'bg-neutral-800 text-gray-300 hover:bg-neutral-700'
+                    }`}
+                  >
+                    {m.label}
+                  </button>
+                ))}
+              </div>
+            </div>
+
+            <p className="text-sm text-gray-400">
+              Ejemplo: un efectivo de <span className="font-mono">$ {fmt(EJEMPLO)}</span> se cobra{' '}
+              <span className="font-mono text-gray-200">$ {fmt(EJEMPLO + ajusteEjemplo)}</span>
+              {ajusteEjemplo !== 0 && (
+                <>
+                  {' '}(ajuste <span className="font-mono">{ajusteEjemplo > 0 ? '+' : '−'}$ {fmt(Math.abs(ajusteEjemplo))}</span>)
+                </>
+              )}
+              .
+            </p>
+
+            <p className="text-xs text-gray-500">
+              Hasta $ 1000, con hasta dos decimales. Dejalo vacío para cobrar al centavo. El efectivo nunca se
+              redondea a cero; los cambios valen para los cobros que se hagan desde ahora.
+            </p>
+
+            <div className="flex justify-end">
+              <button
+                onClick={handleGuardar}
+                disabled={!puedeGuardar}
+                className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+              >
+                {guardar.isPending && <Loader2 size={16} className="animate-spin" />}
+                Guardar
+              </button>
+            </div>
+          </div>
+        )}
+      </div>
+    </section>
+  );
+}
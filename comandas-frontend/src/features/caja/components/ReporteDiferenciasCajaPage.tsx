@@ -0,0 +1,188 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Calendar, Download, Loader2, Scale } from 'lucide-react';
+import { generarCsv, descargarCsv } from '../../../lib/csv';
+import { useReporteDiferenciasCaja } from '../hooks/useCaja';
+import type { ReporteDiferenciasCaja } from '../types';
+import { TURNO_LABELS, claseDiferencia } from './PanelTurnos';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function isoLocal(fecha: Date): string {
+  const y = fecha.getFullYear();
+  const m = String(fecha.getMonth() + 1).padStart(2, '0');
+  const d = String(fecha.getDate()).padStart(2, '0');
+  return `${y}-${m}-${d}`;
+}
+
+/** "Lun 27/01 22:40" */
+function formatCierre(fechaHora: string): string {
+  const d = new Date(fechaHora);
+  const dia = d.toLocaleDateString('es-AR', { weekday: 'short', day: '2-digit', month: '2-digit' });
+  const hora = d.toLocaleTimeString('es-AR', { hour: '2-digit', minute: '2-digit' });
+  return `${dia} ${hora}`;
+}
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+/**
+ * Exporta una fila por turno con diferencia, con su responsable y
+ * observaciones: es el detalle que se revisa con cada cajero.
+ */
+function exportarReporte(reporte: ReporteDiferenciasCaja) {
+  const filas: unknown[][] = reporte.diferencias.map((d) => [
+    d.fechaCierre.slice(0, 16).replace('T', ' '),
+    TURNO_LABELS[d.tipo],
+    d.nombreResponsable,
+    d.efectivoEsperado,
+    d.efectivoDeclarado,
+    d.diferencia,
+    d.observaciones ?? '',
+  ]);
+
+  descargarCsv(
+    generarCsv(
+      ['Cierre', 'Turno', 'Responsable', 'Esperado', 'Declarado', 'Diferencia', 'Observaciones'],
+      filas,
+    ),
+    `diferencias_caja_${reporte.desde}_${reporte.hasta}.csv`,
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Reporte histórico de faltantes y sobrantes de caja (HU-129).
+ *
+ * Arriba, el acumulado por responsable de turno, ordenado por faltante:
+ * un cajero que repite faltantes queda primero aunque algún sobrante
+ * compense el neto. Abajo, cada turno que no cerró con sus observaciones.
+ */
+export default function ReporteDiferenciasCajaPage() {
+  const hoy = new Date();
+  const [desde, setDesde] = useState(isoLocal(new Date(hoy.getFullYear(), hoy.getMonth(), 1)));
+  const [hasta, setHasta] = useState(isoLocal(hoy));
+
+  const { data: reporte, isLoading, isError } = useReporteDiferenciasCaja(desde, hasta);
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/historial"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Diferencias de caja</h1>
+            <p className="text-sm text-gray-500">Faltantes y sobrantes por responsable de turno</p>
+          </div>
+        </header>
+
+        <div className="flex items-center gap-3">
+          <Calendar size={14} className="text-gray-500" />
+          <input type="date" value={desde} onChange={(e) => setDesde(e.target.value)} className={inputFecha} />
+          <span className="text-gray-600 text-xs">→</span>
+          <input type="date" value={hasta} onChange={(e) => setHasta(e.target.value)} className={inputFecha} />
+          {reporte && (
+            <>
+              <span className="ml-auto text-sm text-gray-400">
+                Faltantes <span className="font-mono tabular-nums text-red-400">$ {fmt(reporte.totalFaltante)}</span>
+                {' · '}Sobrantes <span className="font-mono tabular-nums text-emerald-400">$ {fmt(reporte.totalSobrante)}</span>
+              </span>
+              <button
+                type="button"
+                onClick={() => exportarReporte(reporte)}
+                disabled={reporte.diferencias.length === 0}
+                className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors disabled:opacity-40"
+              >
+                <Download size={14} />
+                Exportar CSV
+              </button>
+            </>
+          )}
+        </div>
+
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando reporte...
+          </div>
+        ) : isError ? (
+          <p className="text-sm text-red-400">No se pudo cargar el reporte de diferencias.</p>
+        ) : reporte && reporte.cajeros.length === 0 ? (
+          <div className="flex items-center gap-2 text-xs text-gray-500">
+            <Scale size={14} />
+            No hay turnos cerrados en este rango.
+          </div>
+        ) : reporte && (
+          <>
+            <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+              <table className="w-full text-sm">
+                <thead>
+                  <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                    <th className="text-left font-medium px-4 py-2">Responsable</th>
+                    <th className="text-right font-medium px-3 py-2">Turnos</th>
+                    <th className="text-right font-medium px-3 py-2">Con faltante</th>
+                    <th className="text-right font-medium px-3 py-2">Con sobrante</th>
+                    <th className="text-right font-medium px-3 py-2">Faltante</th>
+                    <th className="text-right font-medium px-3 py-2">Sobrante</th>
+                    <th className="text-right font-medium px-3 py-2">Mayor faltante</th>
+                    <th className="text-right font-medium px-4 py-2">Neto</th>
+                  </tr>
+                </thead>
+                <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+                  {reporte.cajeros.map((c) => (
+                    <tr key={c.responsableId ?? 'sin-responsable'}>
+                      <td className={`px-4 py-2 font-sans ${c.responsableId ? 'text-gray-100' : 'text-gray-500 italic'}`}>
+                        {c.nombre}
+                      </td>
+                      <td className="text-right px-3 py-2">{c.turnosCerrados}</td>
+                      <td className={`text-right px-3 py-2 ${c.turnosConFaltante > 0 ? 'text-amber-400' : ''}`}>
+                        {c.turnosConFaltante}
+                      </td>
+                      <td className="text-right px-3 py-2">{c.turnosConSobrante}</td>
+                      <td className="text-right px-3 py-2">{c.totalFaltante ? `$ ${fmt(c.totalFaltante)}` : '—'}</td>
+                      <td className="text-right px-3 py-2">{c.totalSobrante ? `$ ${fmt(c.totalSobrante)}` : '—'}</td>
+                      <td className="text-right px-3 py-2">{c.mayorFaltante ? `$ ${fmt(c.mayorFaltante)}` : '—'}</td>
+                      <td className={`text-right px-4 py-2 ${claseDiferencia(c.neto)}`}>$ {fmt(c.neto)}</td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+
+            {reporte.diferencias.length > 0 && (
+              <div className="space-y-2">
+                <p className="text-xs font-medium text-gray-500 uppercase tracking-wider">Turnos que no cerraron</p>
+                <ul className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 divide-y divide-neutral-800">
+                  {reporte.diferencias.map((d) => (
+                    <li key={d.turnoId} className="px-4 py-2.5 flex items-start gap-4 text-sm">
+                      <span className="w-36 shrink-0 text-gray-400 font-mono text-xs pt-0.5">
+                        {formatCierre(d.fechaCierre)}
+                      </span>
+                      <span className="w-20 shrink-0 text-gray-400">{TURNO_LABELS[d.tipo]}</span>
+                      <span className={`w-40 shrink-0 ${d.responsableId ? 'text-gray-100' : 'text-gray-500 italic'}`}>
+                        {d.nombreResponsable}
+                      </span>
+                      <span className={`w-32 shrink-0 text-right font-mono tabular-nums ${claseDiferencia(d.diferencia)}`}>
+                        {d.diferencia < 0 ? '−' : '+'}$ {fmt(Math.abs(d.diferencia))}
+                      </span>
+                      <span className="flex-1 text-gray-400">{d.observaciones || '—'}</span>
+                    </li>
+                  ))}
+                </ul>
+              </div>
+            )}
+          </>
+        )}
+      </div>
+    </section>
+  );
+}
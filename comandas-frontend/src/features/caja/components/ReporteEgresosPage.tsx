@@ -0,0 +1,200 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Calendar, Download, Loader2, Wallet } from 'lucide-react';
+import { generarCsv, descargarCsv } from '../../../lib/csv';
+import { useReporteEgresosMensual } from '../hooks/useCaja';
+import type { ReporteEgresosMensual } from '../types';
+import CategoriasEgresoPanel from './CategoriasEgresoPanel';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function mesActual(): string {
+  const hoy = new Date();
+  return `${hoy.getFullYear()}-${String(hoy.getMonth() + 1).padStart(2, '0')}`;
+}
+
+/** "+ $ 1.200,00" / "− $ 300,00" */
+function fmtVariacion(variacion: number): string {
+  if (variacion === 0) return '$ 0,00';
+  return `${variacion < 0 ? '−' : '+'} $ ${fmt(Math.abs(variacion))}`;
+}
+
+function fmtPorcentaje(porcentaje: number | null): string {
+  if (porcentaje === null) return 'nuevo';
+  return `${porcentaje > 0 ? '+' : ''}${fmt(porcentaje)} %`;
+}
+
+/** Gastar más que el mes anterior se marca en rojo; gastar menos, en verde */
+function claseVariacion(variacion: number): string {
+  if (variacion > 0) return 'text-red-400';
+  if (variacion < 0) return 'text-emerald-400';
+  return 'text-gray-500';
+}
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+function exportarReporte(reporte: ReporteEgresosMensual) {
+  const filas: unknown[][] = reporte.categorias.map((c) => [
+    c.nombre,
+    c.cantidad,
+    c.total,
+    c.cantidadAnterior,
+    c.totalAnterior,
+    c.variacion,
+    c.variacionPorcentual ?? '',
+  ]);
+  filas.push([
+    'Total',
+    '',
+    reporte.total,
+    '',
+    reporte.totalAnterior,
+    reporte.variacion,
+    reporte.variacionPorcentual ?? '',
+  ]);
+
+  descargarCsv(
+    generarCsv(
+      [
+        'Categoría',
+        `Egresos ${reporte.periodo}`,
+        `Total ${reporte.periodo}`,
+        `Egresos ${reporte.periodoAnterior}`,
+        `Total ${reporte.periodoAnterior}`,
+        'Variación',
+        'Variación %',
+      ],
+      filas,
+    ),
+    `egresos_${reporte.periodo}.csv`,
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Egresos de caja del mes por categoría, contra el mes anterior (HU-130).
+ *
+ * Los egresos cargados sin categoría (los anteriores a esta HU y los que
+ * salen de gastos pagados con la caja) se agrupan al final como
+ * "Sin categoría". Al costado se administran las categorías.
+ */
+export default function ReporteEgresosPage() {
+  const [periodo, setPeriodo] = useState(mesActual);
+  const { data: reporte, isLoading, isError } = useReporteEgresosMensual(periodo);
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/historial"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Egresos de caja</h1>
+            <p className="text-sm text-gray-500">Por categoría, contra el mes anterior</p>
+          </div>
+        </header>
+
+        <div className="flex items-center gap-3">
+          <Calendar size={14} className="text-gray-500" />
+          <input
+            type="month"
+            value={periodo}
+            max={mesActual()}
+            onChange={(e) => e.target.value && setPeriodo(e.target.value)}
+            className={inputFecha}
+          />
+          {reporte && (
+            <button
+              type="button"
+              onClick={() => exportarReporte(reporte)}
+              disabled={reporte.categorias.length === 0}
+              className="ml-auto h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors disabled:opacity-40"
+            >
+              <Download size={14} />
+              Exportar CSV
+            </button>
+          )}
+        </div>
+
+        <div className="grid grid-cols-1 xl:grid-cols-12 gap-5">
+          <div className="xl:col-span-8">
+            {isLoading ? (
+              <div className="flex items-center gap-2 text-sm text-gray-500">
+                <Loader2 size={16} className="animate-spin" /> Cargando reporte...
+              </div>
+            ) : isError ? (
+              <p className="text-sm text-red-400">No se pudo cargar el reporte de egresos.</p>
+            ) : reporte && reporte.categorias.length === 0 ? (
+              <div className="flex items-center gap-2 text-xs text-gray-500">
+                <Wallet size={14} />
+                No hubo egresos de caja ni en este mes ni en el anterior.
+              </div>
+            ) : reporte && (
+              <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+                <table className="w-full text-sm">
+                  <thead>
+                    <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                      <th className="text-left font-medium px-4 py-2">Categoría</th>
+                      <th className="text-right font-medium px-3 py-2">Egresos</th>
+                      <th className="text-right font-medium px-3 py-2">{reporte.periodo}</th>
+                      <th className="text-right font-medium px-3 py-2">{reporte.periodoAnterior}</th>
+                      <th className="text-right font-medium px-3 py-2">Variación</th>
+                      <th className="text-right font-medium px-4 py-2">%</th>
+                    </tr>
+                  </thead>
+                  <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+                    {reporte.categorias.map((c) => (
+                      <tr key={c.categoriaId ?? 'sin-categoria'}>
+                        <td className={`px-4 py-2 font-sans ${c.categoriaId ? 'text-gray-100' : 'text-gray-500 italic'}`}>
+                          {c.nombre}
+                          {!c.activa && <span className="ml-2 text-[10px] text-gray-600">(inactiva)</span>}
+                        </td>
+                        <td className="text-right px-3 py-2">{c.cantidad}</td>
+                        <td className="text-right px-3 py-2">$ {fmt(c.total)}</td>
+                        <td className="text-right px-3 py-2 text-gray-500">$ {fmt(c.totalAnterior)}</td>
+                        <td className={`text-right px-3 py-2 ${claseVariacion(c.variacion)}`}>
+                          {fmtVariacion(c.variacion)}
+                        </td>
+                        <td className={`text-right px-4 py-2 ${claseVariacion(c.variacion)}`}>
+                          {fmtPorcentaje(c.variacionPorcentual)}
+                        </td>
+                      </tr>
+                    ))}
+                  </tbody>
+                  <tfoot>
+                    <tr className="border-t border-neutral-700 font-mono tabular-nums text-gray-100">
+                      <td className="px-4 py-2 font-sans font-medium">Total</td>
+                      <td />
+                      <td className="text-right px-3 py-2">$ {fmt(reporte.total)}</td>
+                      <td className="text-right px-3 py-2 text-gray-500">$ {fmt(reporte.totalAnterior)}</td>
+                      <td className={`text-right px-3 py-2 ${claseVariacion(reporte.variacion)}`}>
+                        {fmtVariacion(reporte.variacion)}
+                      </td>
+                      <td className={`text-right px-4 py-2 ${claseVariacion(reporte.variacion)}`}>
+                        {fmtPorcentaje(reporte.variacionPorcentual)}
+                      </td>
+                    </tr>
+                  </tfoot>
+                </table>
+              </div>
+            )}
+          </div>
+
+          <div className="xl:col-span-4">
+            <CategoriasEgresoPanel />
+          </div>
+        </div>
+      </div>
+    </section>
+  );
+}
@@ -2,6 +2,7 @@ import { useState, useMemo } from 'react';
 import { Package, TrendingDown } from 'lucide-react';
 
 import { useReporteVentasProductos } from '../hooks/useCaja';
+import BotonCompartirImagen from './BotonCompartirImagen';
 
 // ─── Helpers ────────────────────────────────────────────────────────────────
 
@@ -64,11 +65,13 @@ function EstadoVacio() {
  * agrupando cantidades y sumando totales (solo pedidos CERRADOS).
  *
  * Incluye un input date para seleccionar la fecha operativa.
+ * HU-103: un toggle permite desglosar los combos en sus componentes.
  * Respeta la estética FoodFlow: tema oscuro, acentos rojos, touch-friendly.
  */
 export default function ReporteProductos() {
   const [fecha, setFecha] = useState(fechaHoy);
-  const { data: productos, isPending, isError } = useReporteVentasProductos(fecha);
+  const [desglosarCombos, setDesglosarCombos] = useState(false);
+  const { data: productos, isPending, isError } = useReporteVentasProductos(fecha, desglosarCombos);
 
   // Totales agregados
   const totales = useMemo(() => {
@@ -93,13 +96,29 @@ export default function ReporteProductos() {
           </h2>
         </div>
 
-        {/* Selector de fecha */}
-        <input
-          type="date"
-          value={fecha}
-          onChange={(e) => setFecha(e.target.value)}
-          className="rounded-lg border border-neutral-600 bg-neutral-800 px-3 py-2 text-sm text-neutral-200 focus:border-red-500 focus:outline-none focus:ring-1 focus:ring-red-500"
-        />
+        <div className="flex items-center gap-3">
+          {/* Combos como unidad o por componente */}
+          <label className="flex items-center gap-2 text-sm text-neutral-400 cursor-pointer select-none">
+            <input
+              type="checkbox"
+              checked={desglosarCombos}
+              onChange={(e) => setDesglosarCombos(e.target.checked)}
+              className="h-4 w-4 rounded border-neutral-600 bg-neutral-800 accent-red-600"
+            />
+            Desglosar combos
+          </label>
+
+          {/* HU-157: Productos del día como imagen para WhatsApp */}
+          {productos && productos.length > 0 && <BotonCompartirImagen vista="PRODUCTOS" desde={fecha} />}
+
+          {/* Selector de fecha */}
+          <input
+            type="date"
+            value={fecha}
+            onChange={(e) => setFecha(e.target.value)}
+            className="rounded-lg border border-neutral-600 bg-neutral-800 px-3 py-2 text-sm text-neutral-200 focus:border-red-500 focus:outline-none focus:ring-1 focus:ring-red-500"
+          />
+        </div>
       </div>
 
       {/* Contenido */}
@@ -0,0 +1,188 @@
+import { useEffect, useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Loader2 } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useCajasLocal, useGuardarSerieComprobantes, useSeriesComprobantes } from '../hooks/useCaja';
+import type { SerieComprobantes } from '../types';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+const PREFIJO_VALIDO = /^[A-Z0-9]{1,8}$/;
+const NUMERO_MAXIMO = 99_999_999;
+
+function vistaPrevia(prefijo: string, numero: number, reinicioAnual: boolean): string {
+  const correlativo = String(numero).padStart(8, '0');
+  return reinicioAnual
+    ? `${prefijo}-${new Date().getFullYear()}-${correlativo}`
+    : `${prefijo}-${correlativo}`;
+}
+
+// ─── Fila por caja ────────────────────────────────────────────────────────────
+
+interface FilaSerieProps {
+  cajaId: string | null;
+  caja: string;
+  serie: SerieComprobantes | undefined;
+}
+
+function FilaSerie({ cajaId, caja, serie }: FilaSerieProps) {
+  const toast = useToast();
+  const guardar = useGuardarSerieComprobantes();
+
+  const [prefijo, setPrefijo] = useState('');
+  const [proximo, setProximo] = useState('1');
+  const [reinicioAnual, setReinicioAnual] = useState(false);
+
+  useEffect(() => {
+    if (!serie) return;
+    setPrefijo(serie.prefijo);
+    setProximo(String(serie.proximoNumero));
+    setReinicioAnual(serie.reinicioAnual);
+  }, [serie]);
+
+  const numero = Number(proximo);
+  const prefijoValido = PREFIJO_VALIDO.test(prefijo);
+  const numeroValido = Number.isInteger(numero) && numero >= 1 && numero <= NUMERO_MAXIMO;
+  const cambio =
+    !serie ||
+    serie.prefijo !== prefijo ||
+    serie.proximoNumero !== numero ||
+    serie.reinicioAnual !== reinicioAnual;
+  const puedeGuardar = prefijoValido && numeroValido && cambio && !guardar.isPending;
+
+  const handleGuardar = () => {
+    guardar.mutate(
+      { cajaId, prefijo, proximoNumero: numero, reinicioAnual },
+      {
+        onSuccess: (guardada) => toast.success(`${caja}: el próximo comprobante es ${guardada.proximoComprobante}`),
+        onError: (err: any) =>
+          toast.error(err?.response?.data?.message || 'No se pudo guardar la serie'),
+      },
+    );
+  };
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-3">
+      <div className="flex items-center justify-between gap-3">
+        <h2 className="text-sm font-semibold text-gray-200">{caja}</h2>
+        <span className="text-xs font-mono text-gray-500">
+          {serie
+            ? `Próximo: ${serie.proximoComprobante}`
+            : 'Sin serie: cierra sin número de comprobante'}
+        </span>
+      </div>
+
+      <div className="flex flex-wrap items-end gap-3">
+        <label className="space-y-1">
+          <span className="block text-xs uppercase tracking-wider text-gray-600">Prefijo</span>
+          <input
+            value={prefijo}
+            maxLength={8}
+            placeholder="B1"
+            onChange={(e) => setPrefijo(e.target.value.toUpperCase().replace(/\s/g, ''))}
+            className={`w-28 h-9 px-2 bg-neutral-800 border rounded-lg font-mono text-gray-100 focus:outline-none ${
+              !prefijo || prefijoValido ? 'border-neutral-700 focus:border-red-500/50' : 'border-red-500'
+            }`}
+          />
+        </label>
+
+        <label className="space-y-1">
+          <span className="block text-xs uppercase tracking-wider text-gray-600">Próximo número</span>
+          <input
+            type="number"
+            min={1}
+            max={NUMERO_MAXIMO}
+            step={1}
+            value={proximo}
+            onChange={(e) => setProximo(e.target.value)}
+            className={`w-36 h-9 px-2 bg-neutral-800 border rounded-lg text-right font-mono text-gray-100 focus:outline-none ${
+              numeroValido ? 'border-neutral-700 focus:border-red-500/50' : 'border-red-500'
+            }`}
+          />
+        </label>
+
+        <label className="h-9 flex items-center gap-2 text-sm text-gray-300">
+          <input
+            type="checkbox"
+            checked={reinicioAnual}
+            onChange={(e) => setReinicioAnual(e.target.checked)}
+            className="accent-red-600"
+          />
+          Reiniciar cada año
+        </label>
+
+        <button
+          onClick={handleGuardar}
+          disabled={!puedeGuardar}
+          className="btn-primary text-sm !min-h-[36px] px-5 ml-auto flex items-center gap-2"
+        >
+          {guardar.isPending && <Loader2 size={16} className="animate-spin" />}
+          Guardar
+        </button>
+      </div>
+
+      {prefijoValido && numeroValido && cambio && (
+        <p className="text-xs text-gray-500">
+          Con estos datos el próximo cierre sale como{' '}
+          <span className="font-mono text-gray-300">{vistaPrevia(prefijo, numero, reinicioAnual)}</span>.
+        </p>
+      )}
+    </div>
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Numeración de comprobantes por caja (HU-166).
+ *
+ * Cada caja numera sus comprobantes con su propio prefijo, así dos cajas que
+ * cierran a la vez nunca repiten número. El número se asigna al cerrar la
+ * mesa; una caja sin serie sigue cerrando sin número.
+ */
+export default function SeriesComprobantesPage() {
+  const { data: series, isLoading, isError } = useSeriesComprobantes();
+  const { data: cajas = [] } = useCajasLocal(true);
+
+  const seriePorCaja = (cajaId: string | null) => series?.find((s) => s.cajaId === cajaId);
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/historial"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Numeración de comprobantes</h1>
+            <p className="text-sm text-gray-500">Prefijo y próximo número de cada caja</p>
+          </div>
+        </header>
+
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando series...
+          </div>
+        ) : isError ? (
+          <p className="text-sm text-red-400">No se pudieron cargar las series.</p>
+        ) : (
+          <div className="max-w-3xl space-y-3">
+            <FilaSerie cajaId={null} caja="Caja principal" serie={seriePorCaja(null)} />
+            {cajas.map((caja) => (
+              <FilaSerie key={caja.id} cajaId={caja.id} caja={caja.nombre} serie={seriePorCaja(caja.id)} />
+            ))}
+
+            <p className="text-xs text-gray-500">
+              El prefijo lleva hasta 8 letras o números y no se puede repetir entre cajas. Con el mismo prefijo el
+              próximo número no puede bajar: se repetirían comprobantes ya emitidos. Con reinicio anual el año va
+              en el número y la cuenta vuelve a 1 con el primer cierre del año.
+            </p>
+          </div>
+        )}
+      </div>
+    </section>
+  );
+}
@@ -0,0 +1,174 @@
+import { useEffect, useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, DollarSign, Euro, Loader2 } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useGuardarTiposCambio, useTiposCambio } from '../hooks/useCaja';
+import type { Moneda } from '../../salon/types';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function fmtFecha(iso: string): string {
+  const [y, m, d] = iso.split('-');
+  return `${d}/${m}/${y}`;
+}
+
+/** Monedas extranjeras que se aceptan en caja: el peso no se cotiza. */
+const MONEDAS: { moneda: Moneda; label: string; icon: React.ReactNode }[] = [
+  { moneda: 'USD', label: 'Dólar', icon: <DollarSign size={16} /> },
+  { moneda: 'EUR', label: 'Euro', icon: <Euro size={16} /> },
+];
+
+const EJEMPLO = 20;
+
+type Cotizaciones = Partial<Record<Moneda, string>>;
+
+function esValida(valor: string | undefined): boolean {
+  if (!valor) return true;
+  const n = Number(valor);
+  return Number.isFinite(n) && n > 0 && Math.abs(Math.round(n * 10000) - n * 10000) < 1e-6;
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Tipos de cambio del día (HU-151).
+ *
+ * Pesos por unidad de cada moneda que se acepta al cobrar. Vale para el día
+ * operativo: al día siguiente hay que volver a cargarla, y una moneda sin
+ * cotización no se puede usar. Lo ya cobrado conserva su tipo de cambio.
+ */
+export default function TiposCambioPage() {
+  const toast = useToast();
+  const { data: tiposCambio, isLoading, isError } = useTiposCambio();
+  const guardar = useGuardarTiposCambio();
+
+  const [cotizaciones, setCotizaciones] = useState<Cotizaciones>({});
+
+  useEffect(() => {
+    if (!tiposCambio) return;
+    setCotizaciones(
+      Object.fromEntries(
+        MONEDAS.map(({ moneda }) => [
+          moneda,
+          tiposCambio.cotizaciones[moneda] ? String(tiposCambio.cotizaciones[moneda]) : '',
+        ]),
+      ),
+    );
+  }, [tiposCambio]);
+
+  const puedeGuardar = MONEDAS.every(({ moneda }) => esValida(cotizaciones[moneda])) && !guardar.isPending;
+
+  const handleGuardar = () => {
+    const payload = Object.fromEntries(
+      MONEDAS.filter(({ moneda }) => Number(cotizaciones[moneda] || 0) > 0).map(({ moneda }) => [
+        moneda,
+        Number(cotizaciones[moneda]),
+      ]),
+    ) as Partial<Record<Moneda, number>>;
+    guardar.mutate(
+      { fecha: tiposCambio?.fecha, cotizaciones: payload },
+      {
+        onSuccess: () => toast.success('Tipos de cambio guardados'),
+        onError: (err: any) =>
+          toast.error(err?.response?.data?.message || 'No se pudieron guardar los tipos de cambio'),
+      },
+    );
+  };
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/historial"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Tipos de cambio</h1>
+            <p className="text-sm text-gray-500">
+              Cotización del día{tiposCambio ? ` ${fmtFecha(tiposCambio.fecha)}` : ''} para cobrar en otra moneda
+            </p>
+          </div>
+        </header>
+
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando tipos de cambio...
+          </div>
+        ) : isError ? (
+          <p className="text-sm text-red-400">No se pudieron cargar los tipos de cambio.</p>
+        ) : (
+          <div className="max-w-xl rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-4">
+            <table className="w-full text-sm">
+              <thead>
+                <tr className="text-[10px] uppercase tracking-wider text-gray-600">
+                  <th className="text-left font-medium pb-2">Moneda</th>
+                  <th className="text-right font-medium pb-2">Pesos por unidad</th>
+                  <th className="text-right font-medium pb-2">{EJEMPLO} unidades</th>
+                </tr>
+              </thead>
+              <tbody className="divide-y divide-neutral-800 text-gray-300">
+                {MONEDAS.map(({ moneda, label, icon }) => {
+                  const valor = cotizaciones[moneda] ?? '';
+                  const equivalente = esValida(valor) ? EJEMPLO * Number(valor || 0) : 0;
+                  return (
+                    <tr key={moneda}>
+                      <td className="py-2">
+                        <span className="flex items-center gap-2">
+                          <span className="text-gray-500">{icon}</span>
+                          {label} <span className="text-xs text-gray-600 font-mono">{moneda}</span>
+                        </span>
+                      </td>
+                      <td className="py-2 text-right">
+                        <span className="inline-flex items-center gap-1">
+                          <span className="text-gray-600">$</span>
+                          <input
+                            type="number"
+                            min={0}
+                            step={0.0001}
+                            value={valor}
+                            placeholder="Sin cotizar"
+                            onChange={(e) => setCotizaciones((prev) => ({ ...prev, [moneda]: e.target.value }))}
+                            className={`w-32 h-9 px-2 bg-neutral-800 border rounded-lg text-right font-mono text-gray-100 focus:outline-none ${
+                              esValida(valor) ? 'border-neutral-700 focus:border-red-500/50' : 'border-red-500'
+                            }`}
+                          />
+                        </span>
+                      </td>
+                      <td className="py-2 text-right font-mono tabular-nums text-gray-500">
+                        {equivalente > 0 ? `$ ${fmt(equivalente)}` : '—'}
+                      </td>
+                    </tr>
+                  );
+                })}
+              </tbody>
+            </table>
+
+            <p className="text-xs text-gray-500">
+              Mayor a cero, con hasta cuatro decimales. Dejá vacía una moneda para no aceptarla hoy. Solo
+              efectivo, tarjeta y transferencia se cobran en otra moneda; los cambios valen para los cobros que
+              se hagan desde ahora.
+            </p>
+
+            <div className="flex justify-end">
+              <button
+                onClick={handleGuardar}
+                disabled={!puedeGuardar}
+                className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+              >
+                {guardar.isPending && <Loader2 size={16} className="animate-spin" />}
+                Guardar
+              </button>
+            </div>
+          </div>
+        )}
+      </div>
+    </section>
+  );
+}
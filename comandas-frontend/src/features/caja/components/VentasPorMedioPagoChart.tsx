@@ -22,6 +22,8 @@ const MEDIOS: MedioPagoBar[] = [
   { key: 'TARJETA', label: 'Tarjeta', color: 'bg-blue-500' },
   { key: 'QR', label: 'QR', color: 'bg-violet-500' },
   { key: 'TRANSFERENCIA', label: 'Transf.', color: 'bg-cyan-500' },
+  { key: 'CUENTA_CORRIENTE', label: 'Cta. Cte.', color: 'bg-amber-500' },
+  { key: 'TARJETA_REGALO', label: 'Gift card', color: 'bg-pink-500' },
 ];
 
 // ─── Props ────────────────────────────────────────────────────────────────────
@@ -10,6 +10,21 @@
  *   useRegistrarEgreso   → Mutation de egreso + impresión ESC/POS
  *   useCerrarJornada     → Mutation de cierre + manejo HTTP 400
  *   useReabrirPedido     → Mutation con invalidación cruzada (3 dominios)
+ *   useTurnosCaja        → Query de turnos de la jornada abierta (HU-106)
+ *   useAbrirTurno        → Mutation de apertura de turno
+ *   useCerrarTurno       → Mutation de cierre de turno con arqueo
+ *   useSaldoTeoricoTurno → Query del esperado del turno abierto (HU-123)
+ *   useReporteDiferenciasCaja → Query de faltantes y sobrantes por cajero (HU-129)
+ *   useCategoriasEgreso  → Query + mutations de categorías de egresos (HU-130)
+ *   useReporteEgresosMensual → Query de egresos por categoría vs mes anterior (HU-130)
+ *   useRecargosMedioPago → Query + mutation de recargos por medio de pago (HU-143)
+ *   useTiposCambio       → Query + mutation de tipos de cambio del día (HU-151)
+ *   useRedondeoEfectivo  → Query + mutation del redondeo del efectivo (HU-152)
+ *   useDatosTransferencia → Alias y CVU del local para el cobro (HU-156)
+ *   useCompartirReporteImagen → Mutation que exporta un reporte como PNG (HU-157)
+ *   useJornadaComercial  → Query + mutation de la hora de corte de la jornada (HU-153)
+ *   useCajasLocal        → Query + mutations de las cajas del local (HU-159)
+ *   useSeriesComprobantes → Query + mutation de la numeración por caja (HU-166)
  *
  * @see cajaApi.ts — capa de transporte HTTP
  * @see types.ts   — contratos de dominio (DTOs, errores)
@@ -34,11 +49,33 @@ import type {
   CierreJornadaResponse,
   DetallePedidoCerrado,
   CorreccionPedidoRequest,
+  DevolucionRequest,
+  DevolucionesPedido,
   JornadaResumen,
   ProductoVendidoReporte,
+  TurnoCaja,
+  AbrirTurnoRequest,
+  CerrarTurnoRequest,
+  SaldoTeoricoTurno,
+  ReporteDiferenciasCaja,
+  CategoriaEgreso,
+  CategoriaEgresoRequest,
+  ReporteEgresosMensual,
+  RecargosMedioPago,
+  TiposCambio,
+  TiposCambioRequest,
+  RedondeoEfectivo,
+  JornadaComercial,
+  DatosTransferencia,
+  ReporteImagenRequest,
+  CajaLocal,
+  SerieComprobantes,
+  SerieComprobantesRequest,
 } from '../types';
 import { MesasAbiertasError, JornadaYaCerradaError } from '../types';
 import { descargarPdf, nombreArchivoPdf } from '../services/pdfService';
+import { compartirReporteImagen } from '../services/reporteImagenService';
+import { guardarCajaTerminal } from '../../../lib/cajaTerminal';
 
 // ─── Query Keys (centralizadas para consistencia) ─────────────────────────────
 
@@ -51,10 +88,39 @@ export const cajaKeys = {
   reporte: (fecha: string) => ['reporte-caja', fecha] as const,
   /** Detalle de pedido cerrado para corrección */
   detallePedido: (pedidoId: string) => ['reporte-caja', 'pedido', pedidoId] as const,
+  /** HU-121: Devoluciones de un pedido cerrado */
+  devoluciones: (pedidoId: string) => ['reporte-caja', 'devoluciones', pedidoId] as const,
+  /** HU-130: Egresos por categoría del mes (bajo el prefijo raíz: cada egreso lo refresca) */
+  reporteEgresosMensual: (periodo: string) => ['reporte-caja', 'egresos-mensual', periodo] as const,
+  /** HU-130: Categorías de egresos del local */
+  categoriasEgreso: ['categorias-egreso'] as const,
   /** Historial de jornadas por rango */
   historialJornadas: (desde: string, hasta: string) => ['jornadas-caja', desde, hasta] as const,
   /** Reporte de ventas por producto filtrado por fecha */
-  reporteVentasProductos: (fecha: string) => ['reporte-ventas-productos', fecha] as const,
+  reporteVentasProductos: (fecha: string, desglosarCombos = false) =>
+    ['reporte-ventas-productos', fecha, desglosarCombos] as const,
+  /** HU-106: Turnos de la jornada abierta */
+  turnos: ['turnos-caja'] as const,
+  /** HU-123: Saldo teórico del turno abierto */
+  saldoTeoricoTurno: ['turnos-caja', 'saldo-teorico'] as const,
+  /** HU-106: Consolidado de turnos de una jornada histórica */
+  turnosJornada: (jornadaId: string) => ['turnos-caja', 'jornada', jornadaId] as const,
+  /** HU-129: Faltantes y sobrantes por cajero en un rango */
+  reporteDiferencias: (desde: string, hasta: string) => ['turnos-caja', 'diferencias', desde, hasta] as const,
+  /** HU-143: Recargos por medio de pago del local */
+  recargos: ['recargos-medio-pago'] as const,
+  /** HU-151: Tipos de cambio del día operativo actual */
+  tiposCambio: ['tipos-cambio'] as const,
+  /** HU-152: Regla de redondeo del efectivo del local */
+  redondeo: ['redondeo-efectivo'] as const,
+  /** HU-153: Hora de corte de la jornada comercial del local */
+  jornadaComercial: ['jornada-comercial'] as const,
+  /** HU-156: Alias y CVU del local */
+  datosTransferencia: ['datos-transferencia'] as const,
+  /** HU-159: Cajas del local con su apertura en la jornada */
+  cajasLocal: ['cajas-local'] as const,
+  /** HU-166: Series de comprobantes de cada caja */
+  seriesComprobantes: ['series-comprobantes'] as const,
 } as const;
 
 // ─── Impresión ESC/POS Egreso ─────────────────────────────────────────────────
@@ -371,6 +437,8 @@ export function useCerrarJornada() {
       queryClient.invalidateQueries({ queryKey: ['reporte-ventas-productos'], exact: false });
       queryClient.invalidateQueries({ queryKey: ['jornadas-caja'], exact: false });
       queryClient.invalidateQueries({ queryKey: ['mesas'], exact: false });
+      queryClient.invalidateQueries({ queryKey: cajaKeys.turnos, exact: false });
+      queryClient.invalidateQueries({ queryKey: cajaKeys.cajasLocal });
 
       // Descarga automática del PDF de cierre (fire-and-forget)
       void cajaApi.descargarReportePdf(data.jornadaId).then((blob) => {
@@ -386,6 +454,452 @@ export function useCerrarJornada() {
   });
 }
 
+// ─── HU-106: Turnos de caja ───────────────────────────────────────────────────
+
+/**
+ * Turnos de la jornada abierta (vacío si no se usan turnos).
+ *
+ * Solo se ejecuta con la caja abierta. Los montos del turno en curso
+ * se calculan recién al cerrarlo, por eso no necesita polling agresivo.
+ *
+ * @param habilitado - true si hay jornada abierta
+ */
+export function useTurnosCaja(habilitado: boolean) {
+  return useQuery<TurnoCaja[], Error>({
+    queryKey: cajaKeys.turnos,
+    queryFn: () => cajaApi.obtenerTurnos(),
+    enabled: habilitado,
+    staleTime: 30_000,
+  });
+}
+
+/**
+ * Abre un turno (mañana/tarde/noche) con el efectivo inicial del cajón.
+ *
+ * A partir de la apertura, los pedidos que se cobran y los movimientos
+ * de caja quedan asociados a este turno.
+ */
+export function useAbrirTurno() {
+  const queryClient = useQueryClient();
+
+  return useMutation<TurnoCaja, Error, AbrirTurnoRequest>({
+    mutationFn: (data) => cajaApi.abrirTurno(data),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: cajaKeys.turnos, exact: false });
+    },
+    onError: (error) => {
+      console.error('[useAbrirTurno] Error al abrir turno:', error);
+    },
+  });
+}
+
+/**
+ * HU-123: Saldo teórico del turno abierto para el asistente de conteo.
+ *
+ * Se refresca al abrir el asistente y cada 30s mientras está visible,
+ * por si entra un cobro o un egreso durante el conteo.
+ *
+ * @param habilitado - true mientras el asistente está abierto
+ */
+export function useSaldoTeoricoTurno(habilitado: boolean) {
+  return useQuery<SaldoTeoricoTurno, Error>({
+    queryKey: cajaKeys.saldoTeoricoTurno,
+    queryFn: () => cajaApi.obtenerSaldoTeoricoTurno(),
+    enabled: habilitado,
+    refetchOnMount: 'always',
+    refetchInterval: 30_000,
+  });
+}
+
+// ─── Impresión ESC/POS Arqueo de turno ────────────────────────────────────────
+
+const TURNO_TICKET: Record<TurnoCaja['tipo'], string> = {
+  MANANA: 'MAÑANA',
+  TARDE: 'TARDE',
+  NOCHE: 'NOCHE',
+};
+
+/**
+ * HU-123: Comprobante del arqueo de un turno cerrado.
+ *
+ * Lista el conteo por denominación (si lo hubo), el total contado, el
+ * esperado y la diferencia. Se imprime al cerrar con conteo y se puede
+ * reimprimir desde la lista de turnos.
+ *
+ * @param turno - Turno CERRADO (respuesta del backend)
+ */
+export async function imprimirArqueoTurno(turno: TurnoCaja): Promise<void> {
+  const fechaCierre = turno.fechaCierre
+    ? new Date(turno.fechaCierre).toLocaleString('es-AR', {
+        day: '2-digit',
+        month: '2-digit',
+        year: 'numeric',
+        hour: '2-digit',
+        minute: '2-digit',
+      })
+    : '—';
+  const monto = (valor: number | null) => `$${(valor ?? 0).toFixed(2)}`;
+  const diferencia = turno.diferencia ?? 0;
+
+  const builder = new EscPosBuilder()
+    .centrado()
+    .negrita(true)
+    .tamanoDoble(true)
+    .linea('ARQUEO DE TURNO')
+    .tamanoDoble(false)
+    .linea(`Turno ${TURNO_TICKET[turno.tipo]}`)
+    .negrita(false)
+    .lineaVacia()
+    .separador()
+    .izquierda()
+    .lineaDosColumnas('Cierre:', fechaCierre)
+    .lineaDosColumnas('Pedidos cerrados:', String(turno.pedidosCerradosCount))
+    .separador();
+
+  if (turno.denominaciones.length > 0) {
+    for (const d of turno.denominaciones) {
+      builder.lineaDosColumnas(`${d.cantidad} x $${d.denominacion}`, monto(d.subtotal));
+    }
+    builder.separador();
+  }
+
+  const base64 = builder
+    .lineaDosColumnas('Monto inicial:', monto(turno.montoInicial))
+    .lineaDosColumnas('Ventas efectivo:', monto(turno.ventasEfectivo))
+    .lineaDosColumnas('Ingresos:', monto(turno.totalIngresos))
+    .lineaDosColumnas('Egresos:', `-${monto(turno.totalEgresos)}`)
+    .separadorDoble()
+    .lineaDosColumnas('Esperado:', monto(turno.efectivoEsperado))
+    .lineaDosColumnas('Contado:', monto(turno.efectivoDeclarado))
+    .negrita(true)
+    .lineaDosColumnas(
+      diferencia === 0 ? 'Diferencia:' : diferencia < 0 ? 'Faltante:' : 'Sobrante:',
+      monto(Math.abs(diferencia)),
+    )
+    .negrita(false)
+    .separador()
+    .lineaVacia()
+    .linea('Firma cajero: ______________________')
+    .cortePapel()
+    .toBase64();
+
+  await imprimirEscPos(base64, `Arqueo turno ${TURNO_TICKET[turno.tipo].toLowerCase()}`);
+}
+
+/**
+ * Cierra el turno abierto con el efectivo declarado.
+ *
+ * La respuesta trae el arqueo congelado: la UI muestra la diferencia
+ * (faltante/sobrante) apenas se confirma.
+ *
+ * HU-123: Si el cierre se hizo con conteo por denominación, imprime el
+ * comprobante de arqueo automáticamente.
+ */
+export function useCerrarTurno() {
+  const queryClient = useQueryClient();
+
+  return useMutation<TurnoCaja, Error, CerrarTurnoRequest>({
+    mutationFn: (data) => cajaApi.cerrarTurno(data),
+    onSuccess: (turno) => {
+      queryClient.invalidateQueries({ queryKey: cajaKeys.turnos, exact: false });
+      if (turno.denominaciones.length > 0) {
+        void imprimirArqueoTurno(turno);
+      }
+    },
+    onError: (error) => {
+      console.error('[useCerrarTurno] Error al cerrar turno:', error);
+    },
+  });
+}
+
+/**
+ * Consolidado de turnos de una jornada ya cerrada.
+ * Los turnos de una jornada cerrada no cambian: staleTime largo.
+ *
+ * @param jornadaId - UUID de la jornada (null = deshabilitado)
+ */
+export function useTurnosJornada(jornadaId: string | null) {
+  return useQuery<CierreJornadaResponse, Error>({
+    queryKey: cajaKeys.turnosJornada(jornadaId ?? ''),
+    queryFn: () => cajaApi.obtenerTurnosJornada(jornadaId!),
+    enabled: !!jornadaId,
+    staleTime: 5 * 60 * 1000,
+  });
+}
+
+/**
+ * Reporte histórico de diferencias de caja por cajero (HU-129).
+ * Cuelga de la clave de turnos: al cerrar uno se refresca solo.
+ *
+ * @param desde - Fecha inicial YYYY-MM-DD
+ * @param hasta - Fecha final YYYY-MM-DD (inclusive)
+ */
+export function useReporteDiferenciasCaja(desde: string, hasta: string) {
+  return useQuery<ReporteDiferenciasCaja, Error>({
+    queryKey: cajaKeys.reporteDiferencias(desde, hasta),
+    queryFn: () => cajaApi.obtenerReporteDiferencias(desde, hasta),
+    enabled: !!desde && !!hasta && desde <= hasta,
+  });
+}
+
+// ─── HU-159: Cajas del local ─────────────────────────────────────────────────
+
+/**
+ * Cajas del local y si ya declararon su fondo en la jornada en curso.
+ *
+ * @param habilitado - true si hay jornada abierta
+ */
+export function useCajasLocal(habilitado: boolean) {
+  return useQuery<CajaLocal[], Error>({
+    queryKey: cajaKeys.cajasLocal,
+    queryFn: () => cajaApi.obtenerCajas(),
+    enabled: habilitado,
+    staleTime: 30_000,
+  });
+}
+
+/**
+ * Registra esta terminal como caja. Desde ese momento lo que cobra queda a
+ * nombre de la caja nueva.
+ */
+export function useRegistrarCaja() {
+  const queryClient = useQueryClient();
+
+  return useMutation<CajaLocal, Error, string>({
+    mutationFn: (nombre) => cajaApi.registrarCaja(nombre),
+    onSuccess: (caja) => {
+      guardarCajaTerminal(caja.id);
+      queryClient.invalidateQueries({ queryKey: cajaKeys.cajasLocal });
+    },
+    onError: (error) => {
+      console.error('[useRegistrarCaja] Error al registrar la caja:', error);
+    },
+  });
+}
+
+/**
+ * Declara el fondo inicial de una caja en la jornada en curso.
+ */
+export function useAbrirCajaLocal() {
+  const queryClient = useQueryClient();
+
+  return useMutation<CajaLocal, Error, { cajaId: string; montoInicial: number }>({
+    mutationFn: ({ cajaId, montoInicial }) => cajaApi.abrirCajaLocal(cajaId, montoInicial),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: cajaKeys.cajasLocal });
+    },
+    onError: (error) => {
+      console.error('[useAbrirCajaLocal] Error al abrir la caja:', error);
+    },
+  });
+}
+
+// ─── HU-166: Numeración de comprobantes ──────────────────────────────────────
+
+export function useSeriesComprobantes() {
+  return useQuery<SerieComprobantes[], Error>({
+    queryKey: cajaKeys.seriesComprobantes,
+    queryFn: () => cajaApi.obtenerSeriesComprobantes(),
+    staleTime: 30_000,
+  });
+}
+
+export function useGuardarSerieComprobantes() {
+  const queryClient = useQueryClient();
+
+  return useMutation<SerieComprobantes, Error, SerieComprobantesRequest>({
+    mutationFn: (data) => cajaApi.guardarSerieComprobantes(data),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: cajaKeys.seriesComprobantes });
+    },
+  });
+}
+
+// ─── HU-130: Categorías de egresos ───────────────────────────────────────────
+
+/**
+ * Categorías de egresos del local (activas e inactivas).
+ * Cambian poco: staleTime largo.
+ */
+export function useCategoriasEgreso() {
+  return useQuery<CategoriaEgreso[], Error>({
+    queryKey: cajaKeys.categoriasEgreso,
+    queryFn: () => cajaApi.listarCategoriasEgreso(),
+    staleTime: 5 * 60 * 1000,
+  });
+}
+
+export function useCrearCategoriaEgreso() {
+  const queryClient = useQueryClient();
+
+  return useMutation<CategoriaEgreso, Error, CategoriaEgresoRequest>({
+    mutationFn: (data) => cajaApi.crearCategoriaEgreso(data),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: cajaKeys.categoriasEgreso });
+    },
+  });
+}
+
+/**
+ * Renombrar o activar/desactivar. Refresca también los reportes, que
+ * muestran el nombre vigente de cada categoría.
+ */
+export function useActualizarCategoriaEgreso() {
+  const queryClient = useQueryClient();
+
+  return useMutation<CategoriaEgreso, Error, CategoriaEgresoRequest & { id: string }>({
+    mutationFn: ({ id, ...data }) => cajaApi.actualizarCategoriaEgreso(id, data),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: cajaKeys.categoriasEgreso });
+      queryClient.invalidateQueries({ queryKey: cajaKeys.all, exact: false });
+    },
+  });
+}
+
+/**
+ * Egresos del mes por categoría contra el mes anterior.
+ *
+ * @param periodo - Mes YYYY-MM
+ */
+export function useReporteEgresosMensual(periodo: string) {
+  return useQuery<ReporteEgresosMensual, Error>({
+    queryKey: cajaKeys.reporteEgresosMensual(periodo),
+    queryFn: () => cajaApi.obtenerReporteEgresosMensual(periodo),
+    enabled: !!periodo,
+  });
+}
+
+// ─── HU-143: Recargos por medio de pago ──────────────────────────────────────
+
+/**
+ * Recargos vigentes del local. El modal de cobro los usa para mostrar
+ * cuánto paga el cliente; el backend los vuelve a calcular al cerrar.
+ */
+export function useRecargosMedioPago() {
+  return useQuery<RecargosMedioPago, Error>({
+    queryKey: cajaKeys.recargos,
+    queryFn: () => cajaApi.obtenerRecargos(),
+    staleTime: 5 * 60 * 1000,
+  });
+}
+
+export function useGuardarRecargosMedioPago() {
+  const queryClient = useQueryClient();
+
+  return useMutation<RecargosMedioPago, Error, RecargosMedioPago>({
+    mutationFn: (data) => cajaApi.guardarRecargos(data),
+    onSuccess: (recargos) => {
+      queryClient.setQueryData(cajaKeys.recargos, recargos);
+    },
+  });
+}
+
+// ─── HU-151: Tipos de cambio ─────────────────────────────────────────────────
+
+/**
+ * Cotizaciones del día operativo actual. El modal de cobro las usa para
+ * pasar a pesos lo que entrega el cliente en dólares o euros.
+ */
+export function useTiposCambio() {
+  return useQuery<TiposCambio, Error>({
+    queryKey: cajaKeys.tiposCambio,
+    queryFn: () => cajaApi.obtenerTiposCambio(),
+    staleTime: 5 * 60 * 1000,
+  });
+}
+
+export function useGuardarTiposCambio() {
+  const queryClient = useQueryClient();
+
+  return useMutation<TiposCambio, Error, TiposCambioRequest>({
+    mutationFn: (data) => cajaApi.guardarTiposCambio(data),
+    onSuccess: (tiposCambio) => {
+      queryClient.setQueryData(cajaKeys.tiposCambio, tiposCambio);
+    },
+  });
+}
+
+// ─── HU-152: Redondeo del efectivo ───────────────────────────────────────────
+
+/**
+ * Regla de redondeo del local. El modal de cobro la usa para mostrar el
+ * efectivo a cobrar ya redondeado.
+ */
+export function useRedondeoEfectivo() {
+  return useQuery<RedondeoEfectivo, Error>({
+    queryKey: cajaKeys.redondeo,
+    queryFn: () => cajaApi.obtenerRedondeoEfectivo(),
+    staleTime: 5 * 60 * 1000,
+  });
+}
+
+export function useGuardarRedondeoEfectivo() {
+  const queryClient = useQueryClient();
+
+  return useMutation<RedondeoEfectivo, Error, RedondeoEfectivo>({
+    mutationFn: (data) => cajaApi.guardarRedondeoEfectivo(data),
+    onSuccess: (redondeo) => {
+      queryClient.setQueryData(cajaKeys.redondeo, redondeo);
+    },
+  });
+}
+
+// ─── HU-153: Jornada comercial ───────────────────────────────────────────────
+
+export function useJornadaComercial() {
+  return useQuery<JornadaComercial, Error>({
+    queryKey: cajaKeys.jornadaComercial,
+    queryFn: () => cajaApi.obtenerJornadaComercial(),
+    staleTime: 5 * 60 * 1000,
+  });
+}
+
+/**
+ * Al cambiar el corte se invalida todo lo que depende del día operativo:
+ * estado de caja, reportes y tipos de cambio.
+ */
+export function useGuardarJornadaComercial() {
+  const queryClient = useQueryClient();
+
+  return useMutation<JornadaComercial, Error, string>({
+    mutationFn: (horaCorte) => cajaApi.guardarJornadaComercial(horaCorte),
+    onSuccess: (jornada) => {
+      queryClient.setQueryData(cajaKeys.jornadaComercial, jornada);
+      queryClient.invalidateQueries({ queryKey: cajaKeys.all });
+      queryClient.invalidateQueries({ queryKey: cajaKeys.estadoCaja });
+      queryClient.invalidateQueries({ queryKey: cajaKeys.tiposCambio });
+    },
+  });
+}
+
+// ─── HU-156: Datos para cobrar por transferencia ─────────────────────────────
+
+/**
+ * Alias y CVU del local. Salen de la configuración del backend: no cambian
+ * durante el día.
+ */
+export function useDatosTransferencia() {
+  return useQuery<DatosTransferencia, Error>({
+    queryKey: cajaKeys.datosTransferencia,
+    queryFn: () => cajaApi.obtenerDatosTransferencia(),
+    staleTime: 30 * 60 * 1000,
+  });
+}
+
+// ─── HU-157: Reportes como imagen ────────────────────────────────────────────
+
+/**
+ * Exporta ventas, caja o productos de un período como PNG para mandar por
+ * WhatsApp. En el escritorio la imagen queda además en el portapapeles.
+ *
+ * Resuelve con el mensaje para el toast (dónde quedó la imagen).
+ */
+export function useCompartirReporteImagen() {
+  return useMutation<string, Error, ReporteImagenRequest>({
+    mutationFn: (request) => compartirReporteImagen(request),
+  });
+}
+
 // ─── useDetallePedidoCerrado ──────────────────────────────────────────────────
 
 /**
@@ -439,6 +953,46 @@ export function useCorregirPedido() {
   });
 }
 
+// ─── useDevolucionesPedido ────────────────────────────────────────────────────
+
+/**
+ * HU-121: Lo cobrado, lo devuelto y el detalle de devoluciones de un pedido cerrado.
+ */
+export function useDevolucionesPedido(pedidoId: string | null) {
+  return useQuery<DevolucionesPedido, Error>({
+    queryKey: cajaKeys.devoluciones(pedidoId ?? ''),
+    queryFn: () => cajaApi.obtenerDevoluciones(pedidoId!),
+    enabled: !!pedidoId,
+  });
+}
+
+// ─── useRegistrarDevolucion ───────────────────────────────────────────────────
+
+/**
+ * HU-121: Registrar una devolución sobre un pedido cerrado.
+ *
+ * Genera un egreso asociado al pedido, así que invalida todo el reporte
+ * de caja (ventas netas, devoluciones y balance de efectivo).
+ */
+export function useRegistrarDevolucion() {
+  const queryClient = useQueryClient();
+
+  return useMutation<
+    DevolucionesPedido,
+    Error,
+    { pedidoId: string; data: DevolucionRequest }
+  >({
+    mutationFn: ({ pedidoId, data }) => cajaApi.registrarDevolucion(pedidoId, data),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: cajaKeys.all, exact: false });
+      queryClient.invalidateQueries({ queryKey: cajaKeys.turnos });
+    },
+    onError: (error) => {
+      console.error('[useRegistrarDevolucion] Error al registrar devolución:', error);
+    },
+  });
+}
+
 // ─── useHistorialJornadas ─────────────────────────────────────────────────────
 
 /**
@@ -501,15 +1055,16 @@ export function useDescargarReportePdf() {
  * cambian con menor frecuencia que el reporte de caja en tiempo real.
  *
  * @param fecha - Fecha operativa ISO (YYYY-MM-DD). Si es falsy, la query no se ejecuta.
+ * @param desglosarCombos - HU-103: reporta los combos por componente en lugar de como unidad
  *
  * @example
  * const { data, isPending } = useReporteVentasProductos('2026-03-04');
  * // data = [{ productoNombre: 'Pizza Muzzarella', cantidadTotal: 12, totalRecaudado: 24000 }]
  */
-export function useReporteVentasProductos(fecha: string) {
+export function useReporteVentasProductos(fecha: string, desglosarCombos = false) {
   return useQuery<ProductoVendidoReporte[], Error>({
-    queryKey: cajaKeys.reporteVentasProductos(fecha),
-    queryFn: () => cajaApi.obtenerVentasProductos(fecha),
+    queryKey: cajaKeys.reporteVentasProductos(fecha, desglosarCombos),
+    queryFn: () => cajaApi.obtenerVentasProductos(fecha, desglosarCombos),
     enabled: !!fecha,
     refetchInterval: 30_000, // Polling cada 30s — sincronizado con useReporteCaja
     staleTime: 15_000,       // 15s — se considera fresco entre polls
@@ -12,6 +12,33 @@ export {
   useHistorialJornadas,
   useDescargarReportePdf,
   useReporteVentasProductos,
+  useTurnosCaja,
+  useAbrirTurno,
+  useCerrarTurno,
+  useSaldoTeoricoTurno,
+  useTurnosJornada,
+  useReporteDiferenciasCaja,
+  useCategoriasEgreso,
+  useCrearCategoriaEgreso,
+  useActualizarCategoriaEgreso,
+  useReporteEgresosMensual,
+  useRecargosMedioPago,
+  useGuardarRecargosMedioPago,
+  useTiposCambio,
+  useGuardarTiposCambio,
+  useRedondeoEfectivo,
+  useGuardarRedondeoEfectivo,
+  useJornadaComercial,
+  useGuardarJornadaComercial,
+  useDatosTransferencia,
+  useCompartirReporteImagen,
+  useDevolucionesPedido,
+  useRegistrarDevolucion,
+  useCajasLocal,
+  useRegistrarCaja,
+  useAbrirCajaLocal,
+  useSeriesComprobantes,
+  useGuardarSerieComprobantes,
 } from './hooks/useCaja';
 export { MesasAbiertasError, JornadaYaCerradaError } from './types';
 export type {
@@ -34,8 +61,49 @@ export type {
   CorreccionPedidoRequest,
   JornadaResumen,
   ProductoVendidoReporte,
+  TipoTurno,
+  EstadoTurno,
+  TurnoCaja,
+  AbrirTurnoRequest,
+  CerrarTurnoRequest,
+  ConteoDenominacionRequest,
+  DenominacionContada,
+  SaldoTeoricoTurno,
+  CajeroDiferencias,
+  DiferenciaTurno,
+  ReporteDiferenciasCaja,
+  CategoriaEgreso,
+  CategoriaEgresoRequest,
+  CategoriaEgresoMes,
+  ReporteEgresosMensual,
+  RecargosMedioPago,
+  TiposCambio,
+  TiposCambioRequest,
+  EfectivoMoneda,
+  ModoRedondeo,
+  RedondeoEfectivo,
+  JornadaComercial,
+  DatosTransferencia,
+  VistaReporteImagen,
+  AnchoReporteImagen,
+  ReporteImagenRequest,
+  DevolucionRequest,
+  DevolucionResumen,
+  DevolucionesPedido,
+  CajaLocal,
+  ArqueoCaja,
+  SerieComprobantes,
+  SerieComprobantesRequest,
 } from './types';
 export { default as CajaPage } from './components/CajaPage';
 export { default as BotonDescargarPDF } from './components/BotonDescargarPDF';
+export { default as BotonCompartirImagen } from './components/BotonCompartirImagen';
 export { default as ReporteProductos } from './components/ReporteProductos';
+export { default as ReporteDiferenciasCajaPage } from './components/ReporteDiferenciasCajaPage';
+export { default as ReporteEgresosPage } from './components/ReporteEgresosPage';
+export { default as RecargosMedioPagoPage } from './components/RecargosMedioPagoPage';
+export { default as TiposCambioPage } from './components/TiposCambioPage';
+export { default as RedondeoEfectivoPage } from './components/RedondeoEfectivoPage';
+export { default as JornadaComercialPage } from './components/JornadaComercialPage';
+export { default as SeriesComprobantesPage } from './components/SeriesComprobantesPage';
 export { CajaRoutes } from './routes';
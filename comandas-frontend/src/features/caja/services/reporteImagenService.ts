@@ -0,0 +1,49 @@
+/**
+ * Exportación de reportes como imagen PNG (HU-157).
+ *
+ * Los dueños se pasan "las ventas de hoy" con los socios por WhatsApp, como
+ * foto. El backend dibuja la imagen; acá solo se decide dónde termina:
+ *
+ * - Escritorio (Tauri): el comando `capture_report_image` la guarda en
+ *   Imágenes/FoodFlow y la deja en el portapapeles para pegarla en WhatsApp.
+ * - Navegador: se descarga como archivo, igual que el PDF de cierre.
+ *
+ * @see cajaApi.descargarReporteImagen — obtiene el PNG en el navegador
+ */
+
+import { cajaApi } from '../api/cajaApi';
+import type { ReporteImagenRequest } from '../types';
+import { descargarPdf } from './pdfService';
+
+function esTauri(): boolean {
+  return typeof window !== 'undefined' && '__TAURI_INTERNALS__' in window;
+}
+
+/**
+ * @returns mensaje para mostrar al operador (dónde quedó la imagen)
+ */
+export async function compartirReporteImagen(request: ReporteImagenRequest): Promise<string> {
+  if (esTauri()) {
+    const { invoke } = await import('@tauri-apps/api/core');
+    return invoke<string>('capture_report_image', {
+      view: request.vista,
+      range: { desde: request.desde, hasta: request.hasta },
+      ancho: request.ancho,
+    });
+  }
+
+  const blob = await cajaApi.descargarReporteImagen(request);
+  const nombre = nombreArchivoImagen(request);
+  // El anchor temporal sirve para cualquier blob, no solo PDF
+  descargarPdf(blob, nombre);
+  return `Imagen descargada: ${nombre}`;
+}
+
+/**
+ * Formato: ventas-YYYY-MM-DD.png o caja-YYYY-MM-DD_YYYY-MM-DD.png
+ * (el mismo nombre que usa el escritorio y el backend).
+ */
+export function nombreArchivoImagen({ vista, desde, hasta }: ReporteImagenRequest): string {
+  const periodo = desde === hasta ? desde : `${desde}_${hasta}`;
+  return `${vista.toLowerCase()}-${periodo}.png`;
+}
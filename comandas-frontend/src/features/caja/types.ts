@@ -7,7 +7,7 @@
  * @see backend: com.agustinpalma.comandas.application.dto
  */
 
-import type { MedioPago } from '../salon/types';
+import type { MedioPago, Moneda } from '../salon/types';
 
 // ─── Enums ────────────────────────────────────────────────────────────────────
 
@@ -25,6 +25,15 @@ export type EstadoJornada = 'ABIERTA' | 'CERRADA';
  */
 export type TipoMovimiento = 'EGRESO' | 'INGRESO';
 
+/**
+ * HU-106: Turno de caja dentro de la jornada.
+ * Cada tipo se abre a lo sumo una vez por jornada.
+ */
+export type TipoTurno = 'MANANA' | 'TARDE' | 'NOCHE';
+
+/** HU-106: ABIERTO → CERRADO (irreversible). */
+export type EstadoTurno = 'ABIERTO' | 'CERRADO';
+
 // ─── Requests ─────────────────────────────────────────────────────────────────
 
 /**
@@ -36,6 +45,70 @@ export interface AbrirCajaRequest {
   montoInicial: number;
 }
 
+/**
+ * HU-106: Body HTTP para abrir un turno.
+ * Refleja AbrirTurnoRequest del backend.
+ */
+export interface AbrirTurnoRequest {
+  tipo: TipoTurno;
+  /** Efectivo contado en el cajón al tomar el turno (>= 0) */
+  montoInicial: number;
+}
+
+/**
+ * HU-106: Body HTTP para cerrar el turno abierto.
+ * Refleja CerrarTurnoRequest del backend.
+ */
+export interface CerrarTurnoRequest {
+  /** Efectivo contado por el cajero al entregar el turno (>= 0) */
+  efectivoDeclarado: number;
+  /**
+   * HU-123: Conteo del cajón por denominación. Si viene, el backend
+   * calcula el declarado a partir de él e ignora `efectivoDeclarado`.
+   */
+  denominaciones?: ConteoDenominacionRequest[];
+  /** HU-129: Cajero a cargo del turno (obligatorio si la caja no cierra) */
+  responsableId?: string;
+  /** HU-129: Explicación del faltante o sobrante */
+  observaciones?: string;
+}
+
+/**
+ * HU-123: Renglón del conteo de cajón que se envía al cerrar el turno.
+ */
+export interface ConteoDenominacionRequest {
+  /** Valor del billete o moneda */
+  denominacion: number;
+  /** Unidades contadas (>= 0) */
+  cantidad: number;
+}
+
+/**
+ * HU-123: Renglón del conteo guardado en el arqueo del turno.
+ * Refleja TurnoCajaResponse.DenominacionContada del backend.
+ */
+export interface DenominacionContada {
+  denominacion: number;
+  cantidad: number;
+  /** denominacion × cantidad */
+  subtotal: number;
+}
+
+/**
+ * HU-123: Saldo teórico del turno abierto, para comparar el conteo antes de cerrar.
+ * Refleja SaldoTeoricoTurnoResponse del backend.
+ */
+export interface SaldoTeoricoTurno {
+  turnoId: string;
+  tipo: TipoTurno;
+  montoInicial: number;
+  ventasEfectivo: number;
+  totalIngresos: number;
+  totalEgresos: number;
+  /** Inicial + Ventas Efectivo + Ingresos − Egresos */
+  efectivoEsperado: number;
+}
+
 /**
  * Body HTTP para registrar un egreso de caja.
  * Refleja EgresoRequestBody del backend.
@@ -45,6 +118,8 @@ export interface EgresoRequest {
   monto: number;
   /** Descripción del egreso (ej: "Productos de limpieza") */
   descripcion: string;
+  /** HU-130: Categoría del egreso (omitido = sin categoría) */
+  categoriaId?: string;
 }
 
 /**
@@ -74,6 +149,51 @@ export interface EgresoResponse {
   tipo: string;
   /** Número de comprobante generado automáticamente */
   numeroComprobante: string;
+  /** HU-130: null = sin categoría */
+  categoriaId: string | null;
+}
+
+// ─── HU-130: Categorías de egresos ───────────────────────────────────────────
+
+/** Refleja CategoriaEgresoResponse del backend */
+export interface CategoriaEgreso {
+  id: string;
+  nombre: string;
+  /** Inactiva = no se ofrece al registrar egresos, pero sigue en los reportes */
+  activa: boolean;
+}
+
+export interface CategoriaEgresoRequest {
+  nombre: string;
+  activa?: boolean;
+}
+
+export interface CategoriaEgresoMes {
+  /** null = egresos sin categoría */
+  categoriaId: string | null;
+  nombre: string;
+  activa: boolean;
+  cantidad: number;
+  total: number;
+  cantidadAnterior: number;
+  totalAnterior: number;
+  /** total − totalAnterior (positivo = se gastó más) */
+  variacion: number;
+  /** null si el mes anterior no tuvo egresos en la categoría */
+  variacionPorcentual: number | null;
+}
+
+/** Refleja ReporteEgresosMensualResponse del backend */
+export interface ReporteEgresosMensual {
+  /** YYYY-MM */
+  periodo: string;
+  periodoAnterior: string;
+  /** De mayor a menor gasto del mes; "Sin categoría" al final */
+  categorias: CategoriaEgresoMes[];
+  total: number;
+  totalAnterior: number;
+  variacion: number;
+  variacionPorcentual: number | null;
 }
 
 /**
@@ -113,6 +233,8 @@ export interface MovimientoResumen {
    * Si es undefined se asume EGRESO (compatibilidad backward).
    */
   tipo?: 'EGRESO' | 'INGRESO' | 'PEDIDO';
+  /** HU-121: Solo en las devoluciones, el pedido cerrado que se reintegró */
+  pedidoId?: string | null;
 }
 
 /**
@@ -152,6 +274,16 @@ export interface PagoDetalle {
   medioPago: MedioPago;
   /** Monto individual de este pago */
   monto: number;
+  /** HU-143: Recargo del medio cobrado encima del monto */
+  recargo: number;
+  /** HU-151: Moneda en que pagó el cliente */
+  moneda: Moneda;
+  /** HU-151: Monto en esa moneda (igual a monto si son pesos) */
+  montoOriginal: number;
+  /** HU-152: Redondeo del efectivo sumado o restado al monto */
+  ajusteRedondeo: number;
+  /** HU-156: Número de cupón u operación del comprobante */
+  referencia: string | null;
   /** ISO 8601 datetime — momento en que se registró el pago */
   fecha: string;
 }
@@ -166,18 +298,24 @@ export interface PagoDetalle {
  * @param balanceEfectivo (total pagos EFECTIVO) − (total egresos)
  */
 export interface ReporteCajaResponse {
-  /** Suma de pedidos cerrados excluyendo pagos A_CUENTA */
+  /** Suma de pedidos cerrados excluyendo pagos A_CUENTA, neta de devoluciones */
   totalVentasReales: number;
   /** Suma de pagos A_CUENTA (consumo interno / empleados) */
   totalConsumoInterno: number;
   /** Suma de ingresos manuales de efectivo (plataformas externas, etc.) */
   totalIngresos: number;
-  /** Suma de egresos de caja (salidas de efectivo) */
+  /** Suma de egresos de caja (salidas de efectivo), sin devoluciones */
   totalEgresos: number;
-  /** (total pagos EFECTIVO) + totalIngresos − totalEgresos */
+  /** HU-121: Reintegros de pedidos cerrados registrados en el día */
+  totalDevoluciones: number;
+  /** (total pagos EFECTIVO) + totalIngresos − totalEgresos − totalDevoluciones */
   balanceEfectivo: number;
   /** Mapa con el total por cada medio de pago comercial */
   desglosePorMedioPago: Record<MedioPago, number>;
+  /** HU-143: Recargos por medio de pago cobrados en el día, fuera de las ventas */
+  totalRecargos: number;
+  /** HU-143: Recargo cobrado por cada medio (solo los que tuvieron) */
+  recargosPorMedioPago: Partial<Record<MedioPago, number>>;
   /** Lista de movimientos de caja del día (egresos) */
   movimientos: MovimientoResumen[];
   /** Lista de pedidos cerrados del día (historial de ventas) */
@@ -186,6 +324,60 @@ export interface ReporteCajaResponse {
   pagosDetalle: PagoDetalle[];
   /** Indica si la jornada ya fue cerrada para la fecha consultada */
   jornadaCerrada: boolean;
+  /** HU-151: Pesos físicos esperados en el cajón (balance sin moneda extranjera) */
+  efectivoPesos: number;
+  /** HU-151: Efectivo de cada moneda extranjera, para contarlo aparte */
+  efectivoPorMoneda: EfectivoMoneda[];
+  /** HU-152: Ajustes por redondeo del efectivo (incluidos en el balance) */
+  totalAjusteRedondeo: number;
+}
+
+/** HU-151: Efectivo cobrado en una moneda extranjera y su equivalente en pesos */
+export interface EfectivoMoneda {
+  moneda: Moneda;
+  monto: number;
+  equivalentePesos: number;
+}
+
+// ─── Devoluciones (HU-121) ────────────────────────────────────────────────────
+
+/**
+ * Body HTTP para registrar una devolución sobre un pedido cerrado.
+ * Refleja DevolucionRequest del backend.
+ */
+export interface DevolucionRequest {
+  /** No puede superar lo cobrado menos lo ya devuelto */
+  monto: number;
+  motivo: string;
+  /** Encargado que autoriza */
+  encargadoId: string;
+  pin: string;
+}
+
+export interface DevolucionResumen {
+  id: string;
+  monto: number;
+  descripcion: string;
+  /** ISO 8601 datetime */
+  fecha: string;
+  numeroComprobante: string;
+  /** Nombre del encargado que autorizó */
+  autorizadoPor: string | null;
+}
+
+/**
+ * Devoluciones de un pedido cerrado.
+ * Refleja DevolucionesPedidoResponse del backend.
+ */
+export interface DevolucionesPedido {
+  pedidoId: string;
+  numeroPedido: number;
+  /** Pagos comerciales del pedido (sin A_CUENTA) */
+  totalCobrado: number;
+  totalDevuelto: number;
+  /** Lo que todavía se puede devolver */
+  disponible: number;
+  devoluciones: DevolucionResumen[];
 }
 
 // ─── Estado de Caja (Apertura) ───────────────────────────────────────────────
@@ -273,12 +465,24 @@ export interface ItemDetallePedido {
 export interface PagoDetallePedido {
   medio: MedioPago;
   monto: number;
+  /** HU-143: Recargo congelado del pago */
+  recargo: number;
+  /** HU-151: Moneda en que pagó el cliente */
+  moneda: Moneda;
+  montoOriginal: number;
+  /** HU-152: Informativo; al corregir se recalcula con la regla vigente */
+  ajusteRedondeo: number;
+  /** HU-156: Número de cupón u operación; se conserva al corregir */
+  referencia: string | null;
 }
 
 /** Request para corregir un pedido cerrado. */
 export interface CorreccionPedidoRequest {
   items: { itemId: string; cantidad: number }[];
-  pagos: { medio: MedioPago; monto: number }[];
+  /** HU-151: moneda omitida = pesos; solo se admite una moneda con la que ya se cobró el pedido */
+  pagos: { medio: MedioPago; monto: number; moneda?: Moneda; referencia?: string }[];
+  /** HU-104: Cliente a cargar; si se omite se conserva el del cargo original */
+  clienteId?: string;
 }
 
 // ─── Historial de Jornadas ───────────────────────────────────────────────────
@@ -389,6 +593,96 @@ export class JornadaYaCerradaError extends Error {
 export interface CierreJornadaResponse {
   /** UUID de la jornada cerrada recién creada */
   jornadaId: string;
+  /** HU-106: Arqueo de cada turno (vacío si la jornada no usó turnos) */
+  turnos: TurnoCaja[];
+  /** HU-106: Suma de las diferencias de los turnos cerrados */
+  diferenciaTotal: number;
+  /** HU-159: Arqueo de cada caja, la principal primero */
+  cajas: ArqueoCaja[];
+  /** HU-159: Efectivo que tiene que haber entre todos los cajones */
+  efectivoTotal: number;
+}
+
+/**
+ * HU-106: Turno de caja con su arqueo.
+ * Refleja TurnoCajaResponse del backend.
+ *
+ * Los campos del arqueo son null mientras el turno está ABIERTO.
+ */
+export interface TurnoCaja {
+  id: string;
+  jornadaId: string;
+  tipo: TipoTurno;
+  estado: EstadoTurno;
+  /** ISO 8601 datetime */
+  fechaApertura: string;
+  /** ISO 8601 datetime, null si sigue abierto */
+  fechaCierre: string | null;
+  montoInicial: number;
+  totalVentas: number | null;
+  ventasEfectivo: number | null;
+  totalIngresos: number | null;
+  totalEgresos: number | null;
+  /** Inicial + Ventas Efectivo + Ingresos − Egresos */
+  efectivoEsperado: number | null;
+  efectivoDeclarado: number | null;
+  /** Declarado − Esperado (negativo = faltante, positivo = sobrante) */
+  diferencia: number | null;
+  pedidosCerradosCount: number;
+  /** HU-123: Conteo por denominación del cierre (vacío si se declaró solo el total) */
+  denominaciones: DenominacionContada[];
+  /** HU-129: Cajero que respondió por el arqueo */
+  responsableId: string | null;
+  observaciones: string | null;
+}
+
+// ─── HU-129: Histórico de diferencias de caja ────────────────────────────────
+
+/**
+ * Fila por cajero del histórico de diferencias.
+ * Refleja ReporteDiferenciasCajaResponse.CajeroResumen del backend.
+ */
+export interface CajeroDiferencias {
+  /** null = turnos cerrados sin responsable */
+  responsableId: string | null;
+  nombre: string;
+  turnosCerrados: number;
+  turnosConFaltante: number;
+  turnosConSobrante: number;
+  /** En positivo */
+  totalFaltante: number;
+  totalSobrante: number;
+  /** Sobrantes − faltantes */
+  neto: number;
+  /** El peor faltante del rango, en positivo */
+  mayorFaltante: number;
+}
+
+/**
+ * Turno que no cerró (faltante o sobrante).
+ * Refleja ReporteDiferenciasCajaResponse.DiferenciaTurno del backend.
+ */
+export interface DiferenciaTurno {
+  turnoId: string;
+  tipo: TipoTurno;
+  fechaCierre: string;
+  responsableId: string | null;
+  nombreResponsable: string;
+  efectivoEsperado: number;
+  efectivoDeclarado: number;
+  /** Declarado − Esperado (negativo = faltante) */
+  diferencia: number;
+  observaciones: string | null;
+}
+
+export interface ReporteDiferenciasCaja {
+  desde: string;
+  hasta: string;
+  /** De mayor a menor faltante acumulado; "Sin responsable" al final */
+  cajeros: CajeroDiferencias[];
+  diferencias: DiferenciaTurno[];
+  totalFaltante: number;
+  totalSobrante: number;
 }
 
 // ─── Analytics — Reporte de ventas por producto ──────────────────────────────
@@ -408,3 +702,154 @@ export interface ProductoVendidoReporte {
   /** Total recaudado bruto (precio_unitario × cantidad) */
   totalRecaudado: number;
 }
+
+// ─── Recargos por medio de pago (HU-143) ─────────────────────────────────────
+
+/**
+ * Recargo porcentual de cada medio de pago del local.
+ * Refleja RecargosMedioPagoResponse del backend: solo vienen los medios con
+ * recargo mayor a cero. A cuenta y cuenta corriente no admiten recargo.
+ */
+export interface RecargosMedioPago {
+  porcentajes: Partial<Record<MedioPago, number>>;
+}
+
+// ─── Tipos de cambio (HU-151) ─────────────────────────────────────────────────
+
+/**
+ * Cotización en pesos de cada moneda extranjera para un día operativo.
+ * Refleja TiposCambioResponse del backend: solo vienen las monedas cotizadas.
+ */
+export interface TiposCambio {
+  /** YYYY-MM-DD */
+  fecha: string;
+  cotizaciones: Partial<Record<Moneda, number>>;
+}
+
+export interface TiposCambioRequest {
+  /** YYYY-MM-DD; si se omite, el día operativo actual */
+  fecha?: string;
+  cotizaciones: Partial<Record<Moneda, number>>;
+}
+
+// ─── Redondeo del efectivo (HU-152) ──────────────────────────────────────────
+
+export type ModoRedondeo = 'CERCANO' | 'ABAJO' | 'ARRIBA';
+
+/**
+ * Regla de redondeo del efectivo en pesos del local.
+ * Refleja RedondeoEfectivoResponse del backend: multiplo null = sin redondeo.
+ */
+export interface RedondeoEfectivo {
+  multiplo: number | null;
+  modo: ModoRedondeo;
+}
+
+// ─── Jornada comercial (HU-153) ──────────────────────────────────────────────
+
+/**
+ * Hora de corte del día comercial del local.
+ * Refleja JornadaComercialResponse del backend.
+ */
+export interface JornadaComercial {
+  /** "HH:mm:ss" — lo anterior a esta hora suma al día anterior */
+  horaCorte: string;
+  /** ISO date — día de la jornada en curso */
+  fechaOperativa: string;
+  /** ISO 8601 datetime — primer instante de la jornada en curso */
+  inicio: string;
+  /** ISO 8601 datetime — último instante de la jornada en curso */
+  fin: string;
+}
+
+// ─── Datos para cobrar por transferencia (HU-156) ────────────────────────────
+
+/**
+ * Cuenta del local para que el cliente transfiera.
+ * Refleja DatosTransferenciaResponse del backend.
+ */
+export interface DatosTransferencia {
+  /** Nombre del local, como figura en la cuenta */
+  titular: string;
+  cuit: string;
+  /** null si el local no lo configuró */
+  alias: string | null;
+  /** CVU o CBU; null si el local no lo configuró */
+  cvu: string | null;
+}
+
+// ─── Reportes como imagen (HU-157) ───────────────────────────────────────────
+
+/** Reportes que se pueden exportar como PNG */
+export type VistaReporteImagen = 'VENTAS' | 'CAJA' | 'PRODUCTOS';
+
+/** TICKET: 576 px (80 mm); TELEFONO: 1080 px */
+export type AnchoReporteImagen = 'TICKET' | 'TELEFONO';
+
+/** Qué reporte exportar y de qué días operativos (YYYY-MM-DD, hasta inclusivo) */
+export interface ReporteImagenRequest {
+  vista: VistaReporteImagen;
+  desde: string;
+  hasta: string;
+  ancho: AnchoReporteImagen;
+}
+
+// ─── Varias cajas en el mismo local (HU-159) ─────────────────────────────────
+
+/**
+ * Caja del local (una terminal que cobra con su propio cajón).
+ * Refleja CajaResponse del backend.
+ */
+export interface CajaLocal {
+  id: string;
+  nombre: string;
+  /** true si ya declaró su fondo en la jornada en curso */
+  abierta: boolean;
+  /** Fondo declarado en la jornada en curso; null si no abrió */
+  fondoInicial: number | null;
+}
+
+/**
+ * Arqueo de una caja al cierre de la jornada.
+ * Refleja ArqueoCajaResponse del backend.
+ *
+ * balanceEfectivo = fondoInicial + ventasEfectivo + ingresos − egresos − devoluciones
+ */
+export interface ArqueoCaja {
+  /** null para la caja principal */
+  cajaId: string | null;
+  nombre: string;
+  fondoInicial: number;
+  ventasEfectivo: number;
+  ingresos: number;
+  egresos: number;
+  devoluciones: number;
+  balanceEfectivo: number;
+  pedidos: number;
+}
+
+// ─── Numeración de comprobantes (HU-166) ─────────────────────────────────────
+
+/**
+ * Serie de numeración de los comprobantes de una caja.
+ * Refleja SerieComprobantesResponse del backend.
+ */
+export interface SerieComprobantes {
+  /** null = caja principal */
+  cajaId: string | null;
+  caja: string;
+  prefijo: string;
+  proximoNumero: number;
+  /** true si la cuenta vuelve a 1 con el año operativo (el año va en el número) */
+  reinicioAnual: boolean;
+  /** Número que sale en el próximo cierre, ej: "B1-00000042" */
+  proximoComprobante: string;
+}
+
+export interface SerieComprobantesRequest {
+  /** null = caja principal */
+  cajaId: string | null;
+  prefijo: string;
+  proximoNumero: number;
+  reinicioAnual: boolean;
+}
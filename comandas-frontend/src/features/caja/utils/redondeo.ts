@@ -0,0 +1,19 @@
+import type { ModoRedondeo } from '../types';
+
+/**
+ * Lo que hay que sumar (o restar) al efectivo para llegar al múltiplo, igual
+ * que RedondeoEfectivo.calcularAjuste del backend (HU-152). Se calcula en
+ * centavos para no arrastrar errores de coma flotante.
+ *
+ * @returns 0 sin múltiplo o si redondear dejaría el efectivo en cero
+ */
+export function calcularAjusteRedondeo(efectivo: number, multiplo: number | null, modo: ModoRedondeo): number {
+  if (!multiplo || multiplo <= 0 || efectivo <= 0) return 0;
+  const centavos = Math.round(efectivo * 100);
+  const paso = Math.round(multiplo * 100);
+  const cociente = centavos / paso;
+  const veces = modo === 'ABAJO' ? Math.floor(cociente) : modo === 'ARRIBA' ? Math.ceil(cociente) : Math.round(cociente);
+  const redondeado = veces * paso;
+  if (redondeado <= 0) return 0;
+  return (redondeado - centavos) / 100;
+}
@@ -1,11 +1,29 @@
 import type { AxiosResponse } from 'axios';
-import apiClient from '../../../lib/apiClient';
+import apiClient, { API_BASE_URL } from '../../../lib/apiClient';
 import type {
+  ActualizacionInsumoResponse,
+  AumentoPreciosRequest,
+  AumentoPreciosResponse,
+  FotoProductoRequest,
+  FotoProductoResponse,
+  HistorialPreciosProductoResponse,
+  InsumoRequest,
+  InsumoResponse,
+  ListaPreciosRequest,
+  ListaPreciosResponse,
   ProductoResponse,
   ProductoRequest,
+  ProductoParaReponerResponse,
+  RecetaRequest,
+  RecetaResponse,
+  RentabilidadProducto,
   VarianteRequest,
   VarianteResponse,
+  VariantesEnLoteRequest,
+  VariacionPreciosMenuResponse,
+  VistaPreviaAumentoPreciosResponse,
 } from '../types';
+import type { CanalVenta } from '../../salon/types';
 
 export const productosApi = {
   listar: (categoriaId?: string | null, activo?: boolean): Promise<AxiosResponse<ProductoResponse[]>> =>
@@ -26,6 +44,21 @@ export const productosApi = {
   ajustarStock: (id: string, data: { cantidad: number; tipo: string; motivo: string }): Promise<AxiosResponse<unknown>> =>
     apiClient.patch(`/productos/${id}/stock`, data),
 
+  /**
+   * HU-125: Marca o desmarca un producto como 86.
+   * PATCH /api/productos/{id}/disponibilidad
+   * El cambio se difunde a las demás terminales por /api/eventos.
+   */
+  cambiarDisponibilidad: (id: string, agotado: boolean): Promise<AxiosResponse<ProductoResponse>> =>
+    apiClient.patch(`/productos/${id}/disponibilidad`, { agotado }),
+
+  /**
+   * HU-101: Productos que alcanzaron su stock mínimo, ordenados por urgencia.
+   * GET /api/productos/para-reponer
+   */
+  listarParaReponer: (): Promise<AxiosResponse<ProductoParaReponerResponse[]>> =>
+    apiClient.get('/productos/para-reponer'),
+
   /**
    * Lista todas las variantes de un producto (hermanas del mismo grupo).
    * GET /api/productos/{productoId}/variantes
@@ -41,4 +74,115 @@ export const productosApi = {
    */
   crearVariante: (productoBaseId: string, data: VarianteRequest): Promise<AxiosResponse<VarianteResponse>> =>
     apiClient.post(`/productos/${productoBaseId}/variantes`, data),
+
+  /**
+   * HU-109: Crea varios tamaños de un producto en una sola operación.
+   * POST /api/productos/{productoBaseId}/variantes/lote
+   * Retorna todas las variantes del grupo, incluido el base.
+   */
+  crearVariantesEnLote: (
+    productoBaseId: string,
+    data: VariantesEnLoteRequest
+  ): Promise<AxiosResponse<ProductoResponse[]>> =>
+    apiClient.post(`/productos/${productoBaseId}/variantes/lote`, data),
+
+  /**
+   * HU-108: Listas de precios, una por canal (configurada o no).
+   * GET /api/listas-precios
+   */
+  listarListasPrecios: (): Promise<AxiosResponse<ListaPreciosResponse[]>> =>
+    apiClient.get('/listas-precios'),
+
+  /**
+   * HU-108: Crea o reconfigura la lista de un canal.
+   * PUT /api/listas-precios/{canal}
+   */
+  guardarListaPrecios: (canal: CanalVenta, data: ListaPreciosRequest): Promise<AxiosResponse<ListaPreciosResponse>> =>
+    apiClient.put(`/listas-precios/${canal}`, data),
+
+  /**
+   * HU-118: Insumos del local con su costo unitario.
+   * GET /api/insumos
+   */
+  listarInsumos: (): Promise<AxiosResponse<InsumoResponse[]>> =>
+    apiClient.get('/insumos'),
+
+  crearInsumo: (data: InsumoRequest): Promise<AxiosResponse<InsumoResponse>> =>
+    apiClient.post('/insumos', data),
+
+  /**
+   * HU-118: Edita un insumo; devuelve los productos que quedaron a pérdida.
+   * PUT /api/insumos/{id}
+   */
+  editarInsumo: (id: string, data: InsumoRequest): Promise<AxiosResponse<ActualizacionInsumoResponse>> =>
+    apiClient.put(`/insumos/${id}`, data),
+
+  /**
+   * HU-118: Receta del producto (204 sin cuerpo si todavía no tiene).
+   * GET /api/recetas/{productoId}
+   */
+  consultarReceta: (productoId: string): Promise<AxiosResponse<RecetaResponse | ''>> =>
+    apiClient.get(`/recetas/${productoId}`),
+
+  guardarReceta: (productoId: string, data: RecetaRequest): Promise<AxiosResponse<RecetaResponse>> =>
+    apiClient.put(`/recetas/${productoId}`, data),
+
+  eliminarReceta: (productoId: string): Promise<AxiosResponse<void>> =>
+    apiClient.delete(`/recetas/${productoId}`),
+
+  /**
+   * HU-118: Margen teórico de los productos con receta, de menor a mayor.
+   * GET /api/recetas/rentabilidad
+   */
+  listarRentabilidad: (): Promise<AxiosResponse<RentabilidadProducto[]>> =>
+    apiClient.get('/recetas/rentabilidad'),
+
+  /**
+   * HU-127: Sube o reemplaza la foto del producto para la carta digital.
+   * PUT /api/productos/{id}/foto
+   */
+  subirFoto: (id: string, data: FotoProductoRequest): Promise<AxiosResponse<FotoProductoResponse>> =>
+    apiClient.put(`/productos/${id}/foto`, data),
+
+  eliminarFoto: (id: string): Promise<AxiosResponse<void>> =>
+    apiClient.delete(`/productos/${id}/foto`),
+
+  /** URL absoluta para usar en <img>; `version` evita mostrar la foto cacheada anterior */
+  urlFoto: (id: string, version: string | number): string =>
+    `${API_BASE_URL}/productos/${id}/foto?v=${encodeURIComponent(String(version))}`,
+
+  /**
+   * HU-145: Aumentos masivos del local, del más nuevo al más viejo.
+   * GET /api/aumentos-precios
+   */
+  listarAumentosPrecios: (): Promise<AxiosResponse<AumentoPreciosResponse[]>> =>
+    apiClient.get('/aumentos-precios'),
+
+  /** POST /api/aumentos-precios/vista-previa — no guarda nada */
+  vistaPreviaAumentoPrecios: (
+    data: AumentoPreciosRequest
+  ): Promise<AxiosResponse<VistaPreviaAumentoPreciosResponse>> =>
+    apiClient.post('/aumentos-precios/vista-previa', data),
+
+  /** POST /api/aumentos-precios — se aplica ya si la vigencia es hoy */
+  confirmarAumentoPrecios: (data: AumentoPreciosRequest): Promise<AxiosResponse<AumentoPreciosResponse>> =>
+    apiClient.post('/aumentos-precios', data),
+
+  /** POST /api/aumentos-precios/{id}/cancelar — solo los programados */
+  cancelarAumentoPrecios: (id: string): Promise<AxiosResponse<AumentoPreciosResponse>> =>
+    apiClient.post(`/aumentos-precios/${id}/cancelar`),
+
+  /**
+   * HU-146: Cambios de precio de un producto, del más nuevo al más viejo.
+   * GET /api/historial-precios/productos/{productoId}
+   */
+  historialPrecios: (productoId: string): Promise<AxiosResponse<HistorialPreciosProductoResponse>> =>
+    apiClient.get(`/historial-precios/productos/${productoId}`),
+
+  /**
+   * HU-146: Variación promedio del menú por mes.
+   * @param desde @param hasta meses YYYY-MM, inclusive
+   */
+  variacionPreciosMenu: (desde: string, hasta: string): Promise<AxiosResponse<VariacionPreciosMenuResponse>> =>
+    apiClient.get('/historial-precios/variacion-mensual', { params: { desde, hasta } }),
 };
@@ -0,0 +1,36 @@
+import { useEffect, useRef } from 'react';
+import { useProductosParaReponer } from '../hooks/useProductos';
+import useToast from '../../../hooks/useToast';
+
+/**
+ * Notificador de stock mínimo (HU-101).
+ *
+ * Componente sin UI montado en el layout principal: observa el listado
+ * "para reponer" y muestra un toast cada vez que un producto nuevo
+ * cruza su umbral (ya sea por una venta o por un ajuste manual).
+ *
+ * La primera carga solo registra el estado inicial para no disparar
+ * una alerta por cada faltante preexistente al abrir la app.
+ */
+export default function AlertaStockMinimo() {
+  const { data: items } = useProductosParaReponer();
+  const toast = useToast();
+  const notificados = useRef<Set<string> | null>(null);
+
+  useEffect(() => {
+    if (!items) return;
+
+    const actuales = new Set(items.map((i) => i.productoId));
+
+    if (notificados.current !== null) {
+      const nuevos = items.filter((i) => !notificados.current!.has(i.productoId));
+      nuevos.forEach((i) => {
+        toast.warning(`Stock mínimo alcanzado: ${i.nombre} (quedan ${i.stockActual})`, 6000);
+      });
+    }
+
+    notificados.current = actuales;
+  }, [items, toast]);
+
+  return null;
+}
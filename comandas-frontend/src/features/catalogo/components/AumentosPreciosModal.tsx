@@ -0,0 +1,376 @@
+import { useMemo, useState } from 'react';
+import { X, TrendingUp, Loader2, CalendarClock, Ban } from 'lucide-react';
+import {
+  useAumentosPrecios,
+  useVistaPreviaAumentoPrecios,
+  useConfirmarAumentoPrecios,
+  useCancelarAumentoPrecios,
+} from '../hooks/useProductos';
+import { useCategorias } from '../../categorias/hooks/useCategorias';
+import useToast from '../../../hooks/useToast';
+import type {
+  AumentoPreciosRequest,
+  AumentoPreciosResponse,
+  EstadoAumentoPrecios,
+  VistaPreviaAumentoPreciosResponse,
+} from '../types';
+
+interface AumentosPreciosModalProps {
+  onClose: () => void;
+}
+
+type Vigencia = 'hoy' | 'habil' | 'fecha';
+
+const MULTIPLOS: { valor: number | null; label: string }[] = [
+  { valor: null, label: 'Sin redondeo' },
+  { valor: 10, label: '$10' },
+  { valor: 50, label: '$50' },
+  { valor: 100, label: '$100' },
+];
+
+const ESTADO_LABELS: Record<EstadoAumentoPrecios, string> = {
+  PROGRAMADO: 'Programado',
+  APLICADO: 'Aplicado',
+  CANCELADO: 'Cancelado',
+};
+
+const ESTADO_COLORES: Record<EstadoAumentoPrecios, string> = {
+  PROGRAMADO: 'bg-amber-900/30 text-amber-400',
+  APLICADO: 'bg-emerald-900/30 text-emerald-400',
+  CANCELADO: 'bg-neutral-800 text-gray-500',
+};
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 0, maximumFractionDigits: 2 });
+}
+
+function fmtDia(iso: string): string {
+  const [y, m, d] = iso.slice(0, 10).split('-');
+  return `${d}/${m}/${y}`;
+}
+
+function hoy(): string {
+  const fecha = new Date();
+  return `${fecha.getFullYear()}-${String(fecha.getMonth() + 1).padStart(2, '0')}-${String(fecha.getDate()).padStart(2, '0')}`;
+}
+
+const inputClass =
+  'min-h-[44px] px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none';
+
+function pillClass(activa: boolean): string {
+  return `h-10 px-3 rounded-lg text-sm font-medium border transition-colors ${
+    activa
+      ? 'bg-red-600/20 border-red-600 text-red-300'
+      : 'bg-neutral-800 border-neutral-700 text-gray-400 hover:border-neutral-600'
+  }`;
+}
+
+/**
+ * Modal de aumentos masivos de precios (HU-145).
+ *
+ * Aplica un porcentaje a una categoría o a todo el catálogo, con redondeo
+ * para arriba al múltiplo elegido. Antes de confirmar hay que ver la vista
+ * previa con los precios de hoy. Con vigencia a futuro el aumento queda
+ * programado y el backend lo aplica al llegar el día, sobre los precios
+ * que haya en ese momento.
+ */
+export default function AumentosPreciosModal({ onClose }: AumentosPreciosModalProps) {
+  const toast = useToast();
+  const { data: categorias = [] } = useCategorias();
+  const { data: aumentos = [], isLoading } = useAumentosPrecios();
+  const vistaPrevia = useVistaPreviaAumentoPrecios();
+  const confirmar = useConfirmarAumentoPrecios();
+  const cancelar = useCancelarAumentoPrecios();
+
+  const [categoriaId, setCategoriaId] = useState('');
+  const [porcentaje, setPorcentaje] = useState('');
+  const [multiplo, setMultiplo] = useState<number | null>(50);
+  const [vigencia, setVigencia] = useState<Vigencia>('hoy');
+  const [fecha, setFecha] = useState(hoy);
+  const [responsable, setResponsable] = useState('');
+  const [vista, setVista] = useState<VistaPreviaAumentoPreciosResponse | null>(null);
+
+  const nombreCategoria = useMemo(() => {
+    const nombres = new Map(categorias.map((c) => [c.id, c.nombre]));
+    return (id: string | null) => (id ? nombres.get(id) ?? 'Categoría eliminada' : 'Todo el catálogo');
+  }, [categorias]);
+
+  const porcentajeNumero = Number(porcentaje);
+  const porcentajeValido = porcentaje !== '' && porcentajeNumero > 0 && porcentajeNumero <= 100;
+
+  // Cambiar qué se aumenta o cuánto invalida la vista previa
+  const cambiarForm = (aplicar: () => void) => {
+    aplicar();
+    setVista(null);
+  };
+
+  const fechaVigencia = (): string | undefined => {
+    if (vigencia === 'fecha') return fecha;
+    if (vigencia === 'habil') return vista?.proximoDiaHabil;
+    return undefined;
+  };
+
+  const armarRequest = (conVigencia: boolean): AumentoPreciosRequest => ({
+    categoriaId: categoriaId || null,
+    porcentaje: porcentajeNumero,
+    multiploRedondeo: multiplo,
+    fechaVigencia: conVigencia ? fechaVigencia() : undefined,
+    responsable: responsable.trim() || undefined,
+  });
+
+  const handleVistaPrevia = () => {
+    vistaPrevia.mutate(armarRequest(false), {
+      onSuccess: setVista,
+      onError: (err: any) =>
+        toast.error(err?.response?.data?.message || 'No se pudo calcular la vista previa'),
+    });
+  };
+
+  const handleConfirmar = () => {
+    confirmar.mutate(armarRequest(true), {
+      onSuccess: (aumento) => {
+        toast.success(
+          aumento.estado === 'APLICADO'
+            ? `Precios actualizados: ${aumento.cambios.length} productos`
+            : `Aumento programado para el ${fmtDia(aumento.fechaVigencia)}`
+        );
+        setVista(null);
+        setPorcentaje('');
+      },
+      onError: (err: any) =>
+        toast.error(err?.response?.data?.message || 'No se pudo confirmar el aumento'),
+    });
+  };
+
+  const handleCancelar = (aumento: AumentoPreciosResponse) => {
+    cancelar.mutate(aumento.id, {
+      onSuccess: () => toast.success('Aumento cancelado'),
+      onError: (err: any) =>
+        toast.error(err?.response?.data?.message || 'No se pudo cancelar el aumento'),
+    });
+  };
+
+  const cambiosReales = vista?.cambios.filter((c) => c.precioNuevo !== c.precioAnterior).length ?? 0;
+  const puedeConfirmar =
+    vista !== null && cambiosReales > 0 && (vigencia !== 'fecha' || fecha >= hoy()) && !confirmar.isPending;
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div
+        className="fixed inset-0 z-50 bg-black/60 animate-backdrop-in"
+        onClick={onClose}
+      />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-50 flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="bg-neutral-900 border border-gray-800 rounded-xl w-full max-w-2xl pointer-events-auto animate-modal-in"
+          onClick={(e) => e.stopPropagation()}
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-gray-800">
+            <div className="flex items-center gap-3">
+              <TrendingUp size={20} className="text-red-400" />
+              <div>
+                <h2 className="text-lg font-semibold text-text-primary">Aumento de precios</h2>
+                <p className="text-sm text-text-secondary">Porcentaje masivo por categoría o catálogo</p>
+              </div>
+            </div>
+            <button
+              onClick={onClose}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+            >
+              <X size={18} />
+            </button>
+          </div>
+
+          {/* Body */}
+          <div className="px-6 py-5 max-h-[65vh] overflow-y-auto space-y-5">
+            <div className="grid grid-cols-3 gap-3">
+              <label className="col-span-2 flex flex-col gap-1 text-sm text-text-secondary">
+                Alcance
+                <select
+                  value={categoriaId}
+                  onChange={(e) => cambiarForm(() => setCategoriaId(e.target.value))}
+                  className={inputClass}
+                >
+                  <option value="">Todo el catálogo</option>
+                  {categorias.map((c) => (
+                    <option key={c.id} value={c.id}>
+                      {c.nombre}
+                    </option>
+                  ))}
+                </select>
+              </label>
+              <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                Aumento %
+                <input
+                  type="number"
+                  min="0"
+                  max="100"
+                  step="any"
+                  value={porcentaje}
+                  onChange={(e) => cambiarForm(() => setPorcentaje(e.target.value))}
+                  className={`${inputClass} font-mono`}
+                />
+              </label>
+            </div>
+
+            <div className="space-y-1">
+              <p className="text-sm text-text-secondary">Redondear para arriba a</p>
+              <div className="flex flex-wrap gap-2">
+                {MULTIPLOS.map((m) => (
+                  <button
+                    key={m.label}
+                    onClick={() => cambiarForm(() => setMultiplo(m.valor))}
+                    className={pillClass(multiplo === m.valor)}
+                  >
+                    {m.label}
+                  </button>
+                ))}
+              </div>
+            </div>
+
+            <div className="space-y-1">
+              <p className="text-sm text-text-secondary">Entra en vigencia</p>
+              <div className="flex flex-wrap items-center gap-2">
+                <button onClick={() => setVigencia('hoy')} className={pillClass(vigencia === 'hoy')}>
+                  Ahora
+                </button>
+                <button onClick={() => setVigencia('habil')} className={pillClass(vigencia === 'habil')}>
+                  Próximo día hábil
+                  {vista && <span className="ml-1 font-mono text-xs">{fmtDia(vista.proximoDiaHabil)}</span>}
+                </button>
+                <button onClick={() => setVigencia('fecha')} className={pillClass(vigencia === 'fecha')}>
+                  Otra fecha
+                </button>
+                {vigencia === 'fecha' && (
+                  <input
+                    type="date"
+                    value={fecha}
+                    min={hoy()}
+                    onChange={(e) => e.target.value && setFecha(e.target.value)}
+                    className={`${inputClass} font-mono text-sm`}
+                  />
+                )}
+              </div>
+            </div>
+
+            <label className="flex flex-col gap-1 text-sm text-text-secondary">
+              ¿Quién carga el aumento? <span className="text-xs text-gray-500">(opcional, queda en el historial)</span>
+              <input
+                type="text"
+                value={responsable}
+                maxLength={60}
+                onChange={(e) => setResponsable(e.target.value)}
+                className={inputClass}
+              />
+            </label>
+
+            {/* Vista previa */}
+            {vista && (
+              <div className="rounded-xl border border-gray-800 p-3 space-y-2">
+                <p className="text-xs text-gray-500">
+                  {cambiosReales} de {vista.cambios.length} productos cambian de precio. Calculado con los precios de
+                  hoy; si se programa, se recalcula el día que entra en vigencia.
+                </p>
+                <div className="max-h-64 overflow-y-auto">
+                  <table className="w-full text-sm">
+                    <thead>
+                      <tr className="text-[10px] uppercase tracking-wider text-gray-600">
+                        <th className="text-left font-medium pb-2">Producto</th>
+                        <th className="text-right font-medium pb-2">Actual</th>
+                        <th className="text-right font-medium pb-2">Nuevo</th>
+                      </tr>
+                    </thead>
+                    <tbody className="divide-y divide-neutral-800 text-gray-300">
+                      {vista.cambios.map((c) => (
+                        <tr key={c.productoId}>
+                          <td className="py-1.5">{c.nombreProducto}</td>
+                          <td className="py-1.5 text-right font-mono tabular-nums text-gray-500">
+                            $ {fmt(c.precioAnterior)}
+                          </td>
+                          <td className="py-1.5 text-right font-mono tabular-nums text-gray-100">
+                            $ {fmt(c.precioNuevo)}
+                          </td>
+                        </tr>
+                      ))}
+                    </tbody>
+                  </table>
+                </div>
+              </div>
+            )}
+
+            {/* Historial */}
+            <div className="space-y-2">
+              <p className="text-sm font-medium text-text-primary">Aumentos</p>
+              {isLoading ? (
+                <div className="flex justify-center py-4">
+                  <Loader2 size={20} className="animate-spin text-gray-500" />
+                </div>
+              ) : aumentos.length === 0 ? (
+                <p className="text-xs text-gray-500">Todavía no se aplicó ningún aumento masivo.</p>
+              ) : (
+                <div className="divide-y divide-neutral-800">
+                  {aumentos.map((a) => (
+                    <div key={a.id} className="flex items-center gap-3 py-2 text-sm">
+                      <CalendarClock size={14} className="shrink-0 text-gray-500" />
+                      <div className="flex-1 min-w-0">
+                        <p className="text-gray-200 truncate">
+                          +{fmt(a.porcentaje)}% · {nombreCategoria(a.categoriaId)}
+                          {a.multiploRedondeo && (
+                            <span className="text-gray-500"> · redondeo $ {fmt(a.multiploRedondeo)}</span>
+                          )}
+                        </p>
+                        <p className="text-xs text-gray-500 font-mono">
+                          {a.estado === 'APLICADO' && a.fechaAplicacion
+                            ? `${fmtDia(a.fechaAplicacion)} · ${a.cambios.length} productos`
+                            : `Vigencia ${fmtDia(a.fechaVigencia)}`}
+                          {a.responsable && ` · ${a.responsable}`}
+                        </p>
+                      </div>
+                      <span className={`px-2 py-0.5 rounded-full text-[11px] ${ESTADO_COLORES[a.estado]}`}>
+                        {ESTADO_LABELS[a.estado]}
+                      </span>
+                      {a.estado === 'PROGRAMADO' && (
+                        <button
+                          onClick={() => handleCancelar(a)}
+                          disabled={cancelar.isPending}
+                          className="p-2 rounded-lg text-gray-500 hover:text-red-400 hover:bg-gray-800 transition-colors"
+                          title="Cancelar aumento"
+                        >
+                          <Ban size={16} />
+                        </button>
+                      )}
+                    </div>
+                  ))}
+                </div>
+              )}
+            </div>
+          </div>
+
+          {/* Footer */}
+          <div className="flex justify-end gap-3 px-6 py-4 border-t border-gray-800">
+            <button
+              onClick={handleVistaPrevia}
+              className="btn-secondary text-sm !min-h-[42px] px-5 flex items-center gap-2"
+              disabled={!porcentajeValido || vistaPrevia.isPending}
+            >
+              {vistaPrevia.isPending && <Loader2 size={16} className="animate-spin" />}
+              Vista previa
+            </button>
+            <button
+              onClick={handleConfirmar}
+              className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+              disabled={!puedeConfirmar}
+            >
+              {confirmar.isPending && <Loader2 size={16} className="animate-spin" />}
+              {vigencia === 'hoy' ? 'Aplicar aumento' : 'Programar aumento'}
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
@@ -54,6 +54,11 @@ interface FormState {
   esCategoriaExtra: boolean;
   /** ID de la categoría cuyos productos se muestran como modificadores (ej: salsas) */
   categoriaModificadoresId: string;
+  /** HU-119: Minutos de alerta en cocina; vacío = umbral por defecto */
+  minutosAlertaCocina: string;
+  /** HU-161: Franja horaria ("HH:mm"); ambos vacíos = todo el día */
+  horarioDesde: string;
+  horarioHasta: string;
 }
 
 const FORM_VACIO: FormState = {
@@ -62,6 +67,9 @@ const FORM_VACIO: FormState = {
   admiteVariantes: false,
   esCategoriaExtra: false,
   categoriaModificadoresId: '',
+  minutosAlertaCocina: '',
+  horarioDesde: '',
+  horarioHasta: '',
 };
 
 // ─── Componente ────────────────────────────────────────────────────────────────
@@ -113,6 +121,9 @@ export default function CategoriasModal({ onClose }: CategoriasModalProps) {
       admiteVariantes: cat.admiteVariantes,
       esCategoriaExtra: cat.esCategoriaExtra,
       categoriaModificadoresId: cat.categoriaModificadoresId ?? '',
+      minutosAlertaCocina: cat.minutosAlertaCocina ? String(cat.minutosAlertaCocina) : '',
+      horarioDesde: cat.horarioDesde?.slice(0, 5) ?? '',
+      horarioHasta: cat.horarioHasta?.slice(0, 5) ?? '',
     });
     setError(null);
     setModo('editar');
@@ -152,6 +163,22 @@ export default function CategoriasModal({ onClose }: CategoriasModalProps) {
       return;
     }
 
+    const minutosAlerta = form.minutosAlertaCocina ? Number(form.minutosAlertaCocina) : 0;
+    if (!Number.isInteger(minutosAlerta) || minutosAlerta < 0 || minutosAlerta > 240) {
+      setError('Los minutos de alerta en cocina deben estar entre 1 y 240');
+      return;
+    }
+
+    if (!form.horarioDesde !== !form.horarioHasta) {
+      setError('Indicá desde y hasta qué hora se ofrece');
+      return;
+    }
+    if (form.horarioDesde && form.horarioDesde === form.horarioHasta) {
+      setError('El horario tiene que empezar y terminar a horas distintas');
+      return;
+    }
+    const horario = { desde: form.horarioDesde || null, hasta: form.horarioHasta || null };
+
     setSaving(true);
     try {
       if (modo === 'crear') {
@@ -161,6 +188,8 @@ export default function CategoriasModal({ onClose }: CategoriasModalProps) {
           admiteVariantes: form.admiteVariantes,
           esCategoriaExtra: form.esCategoriaExtra,
           categoriaModificadoresId: form.categoriaModificadoresId || null,
+          minutosAlertaCocina: minutosAlerta || null,
+          horario,
         });
       } else if (modo === 'editar' && editandoId) {
         await editarCategoriaMutation.mutateAsync({
@@ -170,6 +199,10 @@ export default function CategoriasModal({ onClose }: CategoriasModalProps) {
           admiteVariantes: form.admiteVariantes,
           esCategoriaExtra: form.esCategoriaExtra,
           categoriaModificadoresId: form.categoriaModificadoresId || null,
+          // 0 le indica al backend que quite el umbral
+          minutosAlertaCocina: minutosAlerta,
+          // desde y hasta en null le indican al backend que quite la franja
+          horario,
         });
       }
       cancelarFormulario();
@@ -392,6 +425,65 @@ export default function CategoriasModal({ onClose }: CategoriasModalProps) {
         );
       })()}
 
+      {/* HU-119: Umbral de demora en cocina */}
+      {!form.esCategoriaExtra && (
+        <div className="flex flex-col gap-1.5">
+          <label className="text-sm text-text-secondary">Alerta de demora en cocina</label>
+          <div className="flex items-center gap-2">
+            <input
+              type="number"
+              min="1"
+              max="240"
+              step="1"
+              value={form.minutosAlertaCocina}
+              placeholder="15"
+              onChange={(e) => setForm((f) => ({ ...f, minutosAlertaCocina: e.target.value }))}
+              className="w-24 min-h-[48px] px-4 bg-background-card border border-gray-700 rounded-lg text-text-primary font-mono focus:border-primary focus:outline-none"
+            />
+            <span className="text-sm text-text-secondary">minutos</span>
+          </div>
+          <p className="text-[11px] text-gray-600 leading-tight">
+            Pasado este tiempo, la comanda se resalta como demorada en la pantalla de cocina.
+            Vacío usa 15 minutos; si la comanda mezcla categorías, rige la más larga.
+          </p>
+        </div>
+      )}
+
+      {/* HU-161: Franja horaria */}
+      {!form.esCategoriaExtra && (
+        <div className="flex flex-col gap-1.5">
+          <label className="text-sm text-text-secondary">Horario en que se ofrece</label>
+          <div className="flex items-center gap-2">
+            <input
+              type="time"
+              value={form.horarioDesde}
+              onChange={(e) => setForm((f) => ({ ...f, horarioDesde: e.target.value }))}
+              className="min-h-[48px] px-4 bg-background-card border border-gray-700 rounded-lg text-text-primary font-mono focus:border-primary focus:outline-none"
+            />
+            <span className="text-sm text-text-secondary">a</span>
+            <input
+              type="time"
+              value={form.horarioHasta}
+              onChange={(e) => setForm((f) => ({ ...f, horarioHasta: e.target.value }))}
+              className="min-h-[48px] px-4 bg-background-card border border-gray-700 rounded-lg text-text-primary font-mono focus:border-primary focus:outline-none"
+            />
+            {(form.horarioDesde || form.horarioHasta) && (
+              <button
+                type="button"
+                onClick={() => setForm((f) => ({ ...f, horarioDesde: '', horarioHasta: '' }))}
+                className="text-xs text-text-secondary hover:text-text-primary"
+              >
+                Todo el día
+              </button>
+            )}
+          </div>
+          <p className="text-[11px] text-gray-600 leading-tight">
+            Fuera de este horario los productos de la categoría no aparecen en el POS ni en la
+            carta del QR. Vacío se ofrecen todo el día; un producto con horario propio usa el suyo.
+          </p>
+        </div>
+      )}
+
       {/* Error */}
       {error && (
         <p className="text-sm text-red-500 flex items-center gap-2">
@@ -448,6 +540,9 @@ export default function CategoriasModal({ onClose }: CategoriasModalProps) {
                     Modif: {categorias.find((c) => c.id === cat.categoriaModificadoresId)?.nombre ?? '?'}
                   </span>
                 )}
+                {cat.minutosAlertaCocina && (
+                  <span className="text-orange-400/70">Cocina {cat.minutosAlertaCocina}'</span>
+                )}
                 <span className="font-mono">{cat.colorHex}</span>
               </p>
             </div>
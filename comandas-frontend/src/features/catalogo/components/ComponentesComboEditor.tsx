@@ -0,0 +1,105 @@
+import { useMemo } from 'react';
+import { Plus, Trash2 } from 'lucide-react';
+import { useProductos } from '../hooks/useProductos';
+import type { ComponenteCombo } from '../types';
+
+interface ComponentesComboEditorProps {
+  componentes: ComponenteCombo[];
+  onChange: (componentes: ComponenteCombo[]) => void;
+  /** ID del combo en edición, para no ofrecerlo como opción de sí mismo */
+  productoId?: string;
+}
+
+/**
+ * Editor de componentes de un combo (HU-103).
+ *
+ * Cada componente tiene un nombre (ej: "Bebida") y una o más opciones
+ * elegibles entre los productos del catálogo. Con una sola opción el
+ * componente queda fijo y el POS no pregunta.
+ *
+ * No se ofrecen extras ni otros combos como opción: el backend los rechaza.
+ */
+export default function ComponentesComboEditor({
+  componentes,
+  onChange,
+  productoId,
+}: ComponentesComboEditorProps) {
+  const { data: productos = [] } = useProductos(null, true);
+
+  const elegibles = useMemo(
+    () =>
+      productos.filter(
+        (p) => !p.esExtra && p.id !== productoId && !(p.componentesCombo?.length)
+      ),
+    [productos, productoId]
+  );
+
+  const actualizar = (indice: number, cambios: Partial<ComponenteCombo>) => {
+    onChange(componentes.map((c, i) => (i === indice ? { ...c, ...cambios } : c)));
+  };
+
+  const alternarOpcion = (indice: number, opcionId: string) => {
+    const opciones = componentes[indice].opcionesIds;
+    actualizar(indice, {
+      opcionesIds: opciones.includes(opcionId)
+        ? opciones.filter((id) => id !== opcionId)
+        : [...opciones, opcionId],
+    });
+  };
+
+  return (
+    <div className="space-y-3">
+      {componentes.map((componente, indice) => (
+        <div key={indice} className="rounded-lg border border-gray-700 bg-background-card p-3 space-y-2">
+          <div className="flex items-center gap-2">
+            <input
+              type="text"
+              value={componente.nombre}
+              onChange={(e) => actualizar(indice, { nombre: e.target.value })}
+              placeholder="Ej: Bebida"
+              className="flex-1 min-h-[40px] px-3 bg-neutral-900 border border-gray-700 rounded-lg text-sm text-text-primary focus:border-primary focus:outline-none"
+            />
+            <button
+              type="button"
+              onClick={() => onChange(componentes.filter((_, i) => i !== indice))}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-500 hover:text-red-400 transition-colors"
+              aria-label="Quitar componente"
+            >
+              <Trash2 size={16} />
+            </button>
+          </div>
+
+          <div className="flex flex-wrap gap-1.5 max-h-32 overflow-y-auto">
+            {elegibles.map((p) => {
+              const elegida = componente.opcionesIds.includes(p.id);
+              return (
+                <button
+                  key={p.id}
+                  type="button"
+                  onClick={() => alternarOpcion(indice, p.id)}
+                  className={[
+                    'px-2.5 py-1 rounded-full border text-xs transition-colors',
+                    elegida
+                      ? 'border-white/40 bg-white/10 text-white'
+                      : 'border-gray-700 text-gray-400 hover:border-gray-500 hover:text-gray-200',
+                  ].join(' ')}
+                >
+                  {p.nombre}
+                </button>
+              );
+            })}
+          </div>
+        </div>
+      ))}
+
+      <button
+        type="button"
+        onClick={() => onChange([...componentes, { nombre: '', opcionesIds: [] }])}
+        className="flex items-center gap-2 text-sm text-text-secondary hover:text-text-primary transition-colors"
+      >
+        <Plus size={16} />
+        Agregar componente
+      </button>
+    </div>
+  );
+}
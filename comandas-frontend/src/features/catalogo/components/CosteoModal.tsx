@@ -0,0 +1,383 @@
+import { useMemo, useState } from 'react';
+import { X, Calculator, Plus, Loader2, AlertTriangle, Check } from 'lucide-react';
+import {
+  useProductos,
+  useInsumos,
+  useCrearInsumo,
+  useEditarInsumo,
+  useRentabilidadProductos,
+} from '../hooks/useProductos';
+import useToast from '../../../hooks/useToast';
+import RecetaEditor from './RecetaEditor';
+import {
+  UNIDAD_INSUMO_LABELS,
+  type InsumoResponse,
+  type ProductoResponse,
+  type UnidadInsumo,
+} from '../types';
+
+interface CosteoModalProps {
+  onClose: () => void;
+}
+
+type Pestania = 'rentabilidad' | 'insumos';
+
+interface InsumoForm {
+  nombre: string;
+  unidad: UnidadInsumo;
+  costoUnitario: string;
+}
+
+const UNIDADES = Object.keys(UNIDAD_INSUMO_LABELS) as UnidadInsumo[];
+
+const inputClass =
+  'min-h-[40px] px-2 bg-background-card border border-gray-700 rounded-lg text-sm text-text-primary focus:border-primary focus:outline-none';
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function formDesdeInsumo(insumo?: InsumoResponse): InsumoForm {
+  return {
+    nombre: insumo?.nombre ?? '',
+    unidad: insumo?.unidad ?? 'KILOGRAMO',
+    costoUnitario: insumo ? String(insumo.costoUnitario) : '',
+  };
+}
+
+/**
+ * Modal de costeo de recetas (HU-118).
+ *
+ * - Rentabilidad: margen teórico de cada producto con receta, primero los
+ *   de menor margen. Al elegir un producto se edita su receta.
+ * - Insumos: costo por unidad de medida. Si una suba deja productos
+ *   vendiéndose a pérdida, se avisa en el momento.
+ */
+export default function CosteoModal({ onClose }: CosteoModalProps) {
+  const toast = useToast();
+  const { data: productos = [] } = useProductos();
+  const { data: insumos = [], isLoading: cargandoInsumos } = useInsumos();
+  const { data: rentabilidad = [], isLoading: cargandoRentabilidad } = useRentabilidadProductos();
+  const crearInsumo = useCrearInsumo();
+  const editarInsumo = useEditarInsumo();
+
+  const [pestania, setPestania] = useState<Pestania>('rentabilidad');
+  const [productoEditando, setProductoEditando] = useState<ProductoResponse | null>(null);
+  const [insumoEditando, setInsumoEditando] = useState<string | 'nuevo' | null>(null);
+  const [form, setForm] = useState<InsumoForm>(formDesdeInsumo());
+
+  const productosSinReceta = useMemo(() => {
+    const conReceta = new Set(rentabilidad.map((r) => r.productoId));
+    return productos
+      .filter((p) => p.activo && !conReceta.has(p.id))
+      .sort((a, b) => a.nombre.localeCompare(b.nombre));
+  }, [productos, rentabilidad]);
+
+  const abrirReceta = (productoId: string) => {
+    const producto = productos.find((p) => p.id === productoId);
+    if (producto) setProductoEditando(producto);
+  };
+
+  const empezarEdicion = (insumo?: InsumoResponse) => {
+    setInsumoEditando(insumo ? insumo.id : 'nuevo');
+    setForm(formDesdeInsumo(insumo));
+  };
+
+  const handleGuardarInsumo = () => {
+    const costoUnitario = Number(form.costoUnitario);
+    if (!form.nombre.trim()) {
+      toast.error('El nombre del insumo es obligatorio');
+      return;
+    }
+    if (form.costoUnitario === '' || Number.isNaN(costoUnitario) || costoUnitario < 0) {
+      toast.error('El costo debe ser un número mayor o igual a cero');
+      return;
+    }
+    const data = { nombre: form.nombre.trim(), unidad: form.unidad, costoUnitario };
+    const onError = (err: any) => toast.error(err?.response?.data?.message || 'Error al guardar el insumo');
+
+    if (insumoEditando === 'nuevo') {
+      crearInsumo.mutate(data, {
+        onSuccess: () => {
+          toast.success('Insumo agregado');
+          setInsumoEditando(null);
+        },
+        onError,
+      });
+      return;
+    }
+
+    editarInsumo.mutate(
+      { id: insumoEditando!, ...data },
+      {
+        onSuccess: ({ productosAPerdida }) => {
+          setInsumoEditando(null);
+          if (productosAPerdida.length === 0) {
+            toast.success('Insumo actualizado');
+            return;
+          }
+          productosAPerdida.forEach((p) =>
+            toast.warning(`${p.nombre} quedó a pérdida: cuesta $ ${fmt(p.costo)} y se vende a $ ${fmt(p.precio)}`, 8000)
+          );
+        },
+        onError,
+      }
+    );
+  };
+
+  const guardandoInsumo = crearInsumo.isPending || editarInsumo.isPending;
+
+  const filaFormulario = (key: string) => (
+    <tr key={key} className="border-b border-gray-800/50">
+      <td className="py-2 pr-2">
+        <input
+          type="text"
+          value={form.nombre}
+          maxLength={60}
+          autoFocus
+          onChange={(e) => setForm((f) => ({ ...f, nombre: e.target.value }))}
+          className={`${inputClass} w-full`}
+        />
+      </td>
+      <td className="py-2 pr-2">
+        <select
+          value={form.unidad}
+          onChange={(e) => setForm((f) => ({ ...f, unidad: e.target.value as UnidadInsumo }))}
+          className={inputClass}
+        >
+          {UNIDADES.map((u) => (
+            <option key={u} value={u}>
+              {UNIDAD_INSUMO_LABELS[u]}
+            </option>
+          ))}
+        </select>
+      </td>
+      <td className="py-2 pr-2">
+        <input
+          type="number"
+          min="0"
+          step="any"
+          value={form.costoUnitario}
+          onChange={(e) => setForm((f) => ({ ...f, costoUnitario: e.target.value }))}
+          className={`${inputClass} w-28 font-mono text-right`}
+        />
+      </td>
+      <td className="py-2 text-right whitespace-nowrap">
+        <button
+          onClick={handleGuardarInsumo}
+          disabled={guardandoInsumo}
+          className="p-2 rounded-lg text-green-400 hover:bg-gray-800 transition-colors disabled:opacity-40"
+          title="Guardar"
+        >
+          {guardandoInsumo ? <Loader2 size={16} className="animate-spin" /> : <Check size={16} />}
+        </button>
+        <button
+          onClick={() => setInsumoEditando(null)}
+          className="p-2 rounded-lg text-gray-500 hover:bg-gray-800 transition-colors"
+          title="Cancelar"
+        >
+          <X size={16} />
+        </button>
+      </td>
+    </tr>
+  );
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div
+        className="fixed inset-0 z-50 bg-black/60 animate-backdrop-in"
+        onClick={onClose}
+      />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-50 flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="bg-neutral-900 border border-gray-800 rounded-xl w-full max-w-2xl pointer-events-auto animate-modal-in"
+          onClick={(e) => e.stopPropagation()}
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-gray-800">
+            <div className="flex items-center gap-3">
+              <Calculator size={20} className="text-red-400" />
+              <div>
+                <h2 className="text-lg font-semibold text-text-primary">Costeo</h2>
+                <p className="text-sm text-text-secondary">Costo de recetas y margen por producto</p>
+              </div>
+            </div>
+            <button
+              onClick={onClose}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+            >
+              <X size={18} />
+            </button>
+          </div>
+
+          {/* Pestañas */}
+          {!productoEditando && (
+            <div className="flex gap-2 px-6 pt-4">
+              {(['rentabilidad', 'insumos'] as Pestania[]).map((p) => (
+                <button
+                  key={p}
+                  onClick={() => setPestania(p)}
+                  className={`flex-1 h-10 rounded-lg text-sm font-medium border transition-colors ${
+                    pestania === p
+                      ? 'bg-red-600/20 border-red-600 text-red-300'
+                      : 'bg-neutral-800 border-neutral-700 text-gray-400 hover:border-neutral-600'
+                  }`}
+                >
+                  {p === 'rentabilidad' ? 'Rentabilidad' : 'Insumos'}
+                </button>
+              ))}
+            </div>
+          )}
+
+          {/* Body */}
+          <div className="px-6 py-5 max-h-[60vh] overflow-y-auto space-y-4">
+            {productoEditando ? (
+              <RecetaEditor
+                producto={productoEditando}
+                insumos={insumos}
+                onVolver={() => setProductoEditando(null)}
+              />
+            ) : pestania === 'rentabilidad' ? (
+              cargandoRentabilidad ? (
+                <div className="flex justify-center py-8">
+                  <Loader2 size={24} className="animate-spin text-gray-500" />
+                </div>
+              ) : (
+                <>
+                  {insumos.length > 0 && productosSinReceta.length > 0 && (
+                    <select
+                      value=""
+                      onChange={(e) => abrirReceta(e.target.value)}
+                      className={`${inputClass} w-full`}
+                    >
+                      <option value="">Cargar receta de…</option>
+                      {productosSinReceta.map((p) => (
+                        <option key={p.id} value={p.id}>
+                          {p.nombre}
+                        </option>
+                      ))}
+                    </select>
+                  )}
+
+                  {insumos.length === 0 && !cargandoInsumos && (
+                    <p className="text-xs text-gray-500">
+                      Primero cargá los insumos con su costo en la pestaña Insumos.
+                    </p>
+                  )}
+
+                  {rentabilidad.length === 0 ? (
+                    <p className="text-center text-sm text-text-secondary py-8">
+                      Todavía no hay productos con receta.
+                    </p>
+                  ) : (
+                    <table className="w-full text-sm">
+                      <thead>
+                        <tr className="text-left text-xs text-text-secondary border-b border-gray-800">
+                          <th className="pb-2 font-medium">Producto</th>
+                          <th className="pb-2 font-medium text-right">Precio</th>
+                          <th className="pb-2 font-medium text-right">Costo</th>
+                          <th className="pb-2 font-medium text-right">Margen</th>
+                          <th className="pb-2 font-medium text-right">%</th>
+                        </tr>
+                      </thead>
+                      <tbody>
+                        {rentabilidad.map((r) => (
+                          <tr
+                            key={r.productoId}
+                            onClick={() => abrirReceta(r.productoId)}
+                            className="border-b border-gray-800/50 cursor-pointer hover:bg-neutral-800/50"
+                          >
+                            <td className="py-2 text-text-primary">
+                              <span className="flex items-center gap-1.5">
+                                {r.aPerdida && <AlertTriangle size={14} className="text-red-400 shrink-0" />}
+                                {r.nombre}
+                              </span>
+                            </td>
+                            <td className="py-2 text-right font-mono text-text-secondary">$ {fmt(r.precio)}</td>
+                            <td className="py-2 text-right font-mono text-text-secondary">$ {fmt(r.costo)}</td>
+                            <td className={`py-2 text-right font-mono ${r.aPerdida ? 'text-red-400' : 'text-text-primary'}`}>
+                              $ {fmt(r.margen)}
+                            </td>
+                            <td className={`py-2 text-right font-mono ${r.aPerdida ? 'text-red-400' : 'text-green-400'}`}>
+                              {fmt(r.margenPorcentaje)} %
+                            </td>
+                          </tr>
+                        ))}
+                      </tbody>
+                    </table>
+                  )}
+                </>
+              )
+            ) : cargandoInsumos ? (
+              <div className="flex justify-center py-8">
+                <Loader2 size={24} className="animate-spin text-gray-500" />
+              </div>
+            ) : (
+              <>
+                <div className="flex items-center justify-between">
+                  <p className="text-xs text-gray-500">Costo por kg, por litro o por unidad, según cómo se compra.</p>
+                  <button
+                    onClick={() => empezarEdicion()}
+                    disabled={insumoEditando !== null}
+                    className="flex items-center gap-1 text-xs font-medium text-red-300 hover:text-red-200 disabled:opacity-40"
+                  >
+                    <Plus size={14} />
+                    Nuevo insumo
+                  </button>
+                </div>
+
+                <table className="w-full text-sm">
+                  <thead>
+                    <tr className="text-left text-xs text-text-secondary border-b border-gray-800">
+                      <th className="pb-2 font-medium">Insumo</th>
+                      <th className="pb-2 font-medium">Unidad</th>
+                      <th className="pb-2 font-medium text-right">Costo</th>
+                      <th className="pb-2" />
+                    </tr>
+                  </thead>
+                  <tbody>
+                    {insumoEditando === 'nuevo' && filaFormulario('nuevo')}
+                    {insumos.map((insumo) =>
+                      insumoEditando === insumo.id ? (
+                        filaFormulario(insumo.id)
+                      ) : (
+                        <tr
+                          key={insumo.id}
+                          onClick={() => insumoEditando === null && empezarEdicion(insumo)}
+                          className="border-b border-gray-800/50 cursor-pointer hover:bg-neutral-800/50"
+                        >
+                          <td className="py-2 text-text-primary">{insumo.nombre}</td>
+                          <td className="py-2 text-text-secondary">{UNIDAD_INSUMO_LABELS[insumo.unidad]}</td>
+                          <td className="py-2 text-right font-mono text-text-primary">
+                            $ {insumo.costoUnitario.toLocaleString('es-AR', { maximumFractionDigits: 4 })}
+                          </td>
+                          <td />
+                        </tr>
+                      )
+                    )}
+                  </tbody>
+                </table>
+
+                {insumos.length === 0 && insumoEditando === null && (
+                  <p className="text-center text-sm text-text-secondary py-4">
+                    No hay insumos cargados.
+                  </p>
+                )}
+              </>
+            )}
+          </div>
+
+          {/* Footer */}
+          <div className="flex justify-end gap-3 px-6 py-4 border-t border-gray-800">
+            <button onClick={onClose} className="btn-secondary text-sm !min-h-[42px] px-5">
+              Cerrar
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
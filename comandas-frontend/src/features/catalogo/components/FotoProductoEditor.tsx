@@ -0,0 +1,104 @@
+import { useRef, useState } from 'react';
+import { ImagePlus, Trash2 } from 'lucide-react';
+import { productosApi } from '../api/productosApi';
+import { useEliminarFotoProducto, useSubirFotoProducto } from '../hooks/useProductos';
+import { prepararFotoProducto, TIPOS_FOTO } from '../utils/fotoProducto';
+import useToast from '../../../hooks/useToast';
+
+interface FotoProductoEditorProps {
+  productoId: string;
+}
+
+/**
+ * Foto del producto para la carta digital (HU-127).
+ *
+ * Se sube al momento, sin esperar a "Guardar Cambios": es independiente
+ * del resto de los datos del producto. El producto no informa si tiene
+ * foto, así que se intenta cargarla y si no existe se muestra el hueco.
+ */
+export default function FotoProductoEditor({ productoId }: FotoProductoEditorProps) {
+  const toast = useToast();
+  const inputRef = useRef<HTMLInputElement>(null);
+  const subirFoto = useSubirFotoProducto();
+  const eliminarFoto = useEliminarFotoProducto();
+
+  const [version, setVersion] = useState<string | number>(() => Date.now());
+  const [tieneFoto, setTieneFoto] = useState(true);
+  const ocupado = subirFoto.isPending || eliminarFoto.isPending;
+
+  const handleArchivo = async (archivo: File | undefined) => {
+    if (!archivo) return;
+    try {
+      const foto = await prepararFotoProducto(archivo);
+      const { data } = await subirFoto.mutateAsync({ productoId, ...foto });
+      setVersion(data.actualizadaEn);
+      setTieneFoto(true);
+      toast.success('Foto actualizada en la carta');
+    } catch (err: any) {
+      toast.error(err?.response?.data?.message || err?.message || 'No se pudo subir la foto');
+    } finally {
+      if (inputRef.current) inputRef.current.value = '';
+    }
+  };
+
+  const handleQuitar = () => {
+    eliminarFoto.mutate(productoId, {
+      onSuccess: () => {
+        setTieneFoto(false);
+        toast.success('Foto quitada de la carta');
+      },
+      onError: (err: any) => {
+        toast.error(err?.response?.data?.message || 'No se pudo quitar la foto');
+      },
+    });
+  };
+
+  return (
+    <div className="space-y-2">
+      <label className="text-sm text-text-secondary">Foto para la carta digital</label>
+      <div className="flex items-center gap-3">
+        {tieneFoto ? (
+          <img
+            src={productosApi.urlFoto(productoId, version)}
+            alt=""
+            onError={() => setTieneFoto(false)}
+            className="w-20 h-20 rounded-lg object-cover border border-gray-700 bg-background-card"
+          />
+        ) : (
+          <div className="w-20 h-20 rounded-lg border border-dashed border-gray-700 bg-background-card flex items-center justify-center text-gray-600">
+            <ImagePlus size={22} />
+          </div>
+        )}
+
+        <div className="flex flex-col gap-2">
+          <button
+            type="button"
+            onClick={() => inputRef.current?.click()}
+            disabled={ocupado}
+            className="px-3 py-2 rounded-lg border border-gray-700 text-sm text-text-primary hover:border-gray-500 transition-colors disabled:opacity-50"
+          >
+            {subirFoto.isPending ? 'Subiendo...' : tieneFoto ? 'Cambiar foto' : 'Subir foto'}
+          </button>
+          {tieneFoto && (
+            <button
+              type="button"
+              onClick={handleQuitar}
+              disabled={ocupado}
+              className="flex items-center gap-1.5 px-3 py-1.5 rounded-lg text-xs text-red-400 hover:bg-red-950/30 transition-colors disabled:opacity-50"
+            >
+              <Trash2 size={13} />
+              Quitar
+            </button>
+          )}
+        </div>
+      </div>
+      <input
+        ref={inputRef}
+        type="file"
+        accept={TIPOS_FOTO}
+        className="hidden"
+        onChange={(e) => handleArchivo(e.target.files?.[0])}
+      />
+    </div>
+  );
+}
@@ -0,0 +1,289 @@
+import { useMemo, useState } from 'react';
+import { X, History, Loader2, TrendingUp, Pencil } from 'lucide-react';
+import { useHistorialPrecios, useProductos, useVariacionPreciosMenu } from '../hooks/useProductos';
+import type { OrigenCambioPrecio } from '../types';
+
+interface HistorialPreciosModalProps {
+  onClose: () => void;
+}
+
+type Pestania = 'mensual' | 'producto';
+
+const MESES = ['ene', 'feb', 'mar', 'abr', 'may', 'jun', 'jul', 'ago', 'sep', 'oct', 'nov', 'dic'];
+
+const ORIGEN_LABELS: Record<OrigenCambioPrecio, string> = {
+  EDICION: 'Edición',
+  AUMENTO_MASIVO: 'Aumento masivo',
+};
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 0, maximumFractionDigits: 2 });
+}
+
+function fmtPorcentaje(valor: number | null): string {
+  if (valor === null) return '—';
+  const signo = valor > 0 ? '+' : '';
+  return `${signo}${valor.toLocaleString('es-AR', { minimumFractionDigits: 1, maximumFractionDigits: 2 })}%`;
+}
+
+function fmtFechaHora(iso: string): string {
+  const [fecha, hora = ''] = iso.split('T');
+  const [y, m, d] = fecha.split('-');
+  return `${d}/${m}/${y} ${hora.slice(0, 5)}`;
+}
+
+/** YYYY-MM → "mar 2026" */
+function fmtMes(periodo: string): string {
+  const [y, m] = periodo.split('-');
+  return `${MESES[Number(m) - 1]} ${y}`;
+}
+
+function isoMes(fecha: Date): string {
+  return `${fecha.getFullYear()}-${String(fecha.getMonth() + 1).padStart(2, '0')}`;
+}
+
+function haceOnceMeses(): string {
+  const fecha = new Date();
+  return isoMes(new Date(fecha.getFullYear(), fecha.getMonth() - 11, 1));
+}
+
+const inputClass =
+  'min-h-[40px] px-3 bg-background-card border border-gray-700 rounded-lg text-sm text-text-primary font-mono focus:border-primary focus:outline-none';
+
+// ─── Variación mensual ────────────────────────────────────────────────────────
+
+function VariacionMensual() {
+  const [desde, setDesde] = useState(haceOnceMeses);
+  const [hasta, setHasta] = useState(() => isoMes(new Date()));
+  const { data: reporte, isLoading, isError, error } = useVariacionPreciosMenu(desde, hasta);
+
+  const maximo = Math.max(1, ...(reporte?.meses ?? []).map((m) => Math.abs(m.variacionPromedio)));
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center gap-2">
+        <input
+          type="month"
+          value={desde}
+          max={hasta}
+          onChange={(e) => e.target.value && setDesde(e.target.value)}
+          className={inputClass}
+        />
+        <span className="text-xs text-gray-600">a</span>
+        <input
+          type="month"
+          value={hasta}
+          min={desde}
+          onChange={(e) => e.target.value && setHasta(e.target.value)}
+          className={inputClass}
+        />
+      </div>
+
+      {isLoading ? (
+        <div className="flex justify-center py-8">
+          <Loader2 size={24} className="animate-spin text-gray-500" />
+        </div>
+      ) : isError || !reporte ? (
+        <p className="text-sm text-red-400">
+          {(error as any)?.response?.data?.message || 'No se pudo cargar la variación de precios.'}
+        </p>
+      ) : (
+        <>
+          <div className="rounded-xl border border-gray-800 p-3">
+            <p className="text-[10px] uppercase tracking-wider text-gray-600">Variación acumulada del menú</p>
+            <p className="text-2xl font-mono font-bold text-gray-100 tabular-nums">
+              {fmtPorcentaje(reporte.variacionAcumulada)}
+            </p>
+          </div>
+
+          <table className="w-full text-sm">
+            <thead>
+              <tr className="text-[10px] uppercase tracking-wider text-gray-600">
+                <th className="text-left font-medium pb-2">Mes</th>
+                <th className="text-left font-medium pb-2 w-1/3" />
+                <th className="text-right font-medium pb-2">Menú</th>
+                <th className="text-right font-medium pb-2">Cambiaron</th>
+                <th className="text-right font-medium pb-2">Prom. cambiados</th>
+              </tr>
+            </thead>
+            <tbody className="divide-y divide-neutral-800 text-gray-300">
+              {reporte.meses.map((m) => (
+                <tr key={m.periodo}>
+                  <td className="py-2 font-mono text-gray-400">{fmtMes(m.periodo)}</td>
+                  <td className="py-2">
+                    <div className="flex items-center gap-2">
+                      <div className="flex-1 h-2 rounded-full bg-neutral-800 overflow-hidden">
+                        <div
+                          className={`h-full rounded-full ${m.variacionPromedio < 0 ? 'bg-emerald-500' : 'bg-red-500'}`}
+                          style={{ width: `${(Math.abs(m.variacionPromedio) / maximo) * 100}%` }}
+                        />
+                      </div>
+                      <span className="w-16 text-right font-mono tabular-nums text-gray-100">
+                        {fmtPorcentaje(m.variacionPromedio)}
+                      </span>
+                    </div>
+                  </td>
+                  <td className="py-2 text-right font-mono tabular-nums text-gray-500">{m.productosEnMenu}</td>
+                  <td className="py-2 text-right font-mono tabular-nums">{m.productosModificados}</td>
+                  <td className="py-2 text-right font-mono tabular-nums text-gray-400">
+                    {m.productosModificados > 0 ? fmtPorcentaje(m.variacionPromedioModificados) : '—'}
+                  </td>
+                </tr>
+              ))}
+            </tbody>
+          </table>
+
+          <p className="text-xs text-gray-500">
+            El promedio del mes es sobre todo el menú: los productos que no cambiaron cuentan como 0%.
+          </p>
+        </>
+      )}
+    </div>
+  );
+}
+
+// ─── Por producto ─────────────────────────────────────────────────────────────
+
+function HistorialProducto() {
+  const { data: productos = [] } = useProductos();
+  const [productoId, setProductoId] = useState<string | null>(null);
+  const { data: historial, isLoading } = useHistorialPrecios(productoId);
+
+  const ordenados = useMemo(
+    () => [...productos].sort((a, b) => a.nombre.localeCompare(b.nombre)),
+    [productos]
+  );
+
+  return (
+    <div className="space-y-4">
+      <select
+        value={productoId ?? ''}
+        onChange={(e) => setProductoId(e.target.value || null)}
+        className={`${inputClass} w-full font-sans`}
+      >
+        <option value="">Elegir producto…</option>
+        {ordenados.map((p) => (
+          <option key={p.id} value={p.id}>
+            {p.nombre}
+          </option>
+        ))}
+      </select>
+
+      {!productoId ? null : isLoading || !historial ? (
+        <div className="flex justify-center py-8">
+          <Loader2 size={24} className="animate-spin text-gray-500" />
+        </div>
+      ) : historial.cambios.length === 0 ? (
+        <p className="text-xs text-gray-500">
+          {historial.nombre} no cambió de precio desde que se lleva el historial. Precio actual: $ {fmt(historial.precioActual)}.
+        </p>
+      ) : (
+        <table className="w-full text-sm">
+          <thead>
+            <tr className="text-[10px] uppercase tracking-wider text-gray-600">
+              <th className="text-left font-medium pb-2">Fecha</th>
+              <th className="text-left font-medium pb-2">Quién</th>
+              <th className="text-right font-medium pb-2">Anterior</th>
+              <th className="text-right font-medium pb-2">Nuevo</th>
+              <th className="text-right font-medium pb-2">Var.</th>
+            </tr>
+          </thead>
+          <tbody className="divide-y divide-neutral-800 text-gray-300">
+            {historial.cambios.map((c) => (
+              <tr key={c.id}>
+                <td className="py-2 font-mono text-gray-400">{fmtFechaHora(c.fecha)}</td>
+                <td className="py-2">
+                  <span className="flex items-center gap-1.5">
+                    {c.origen === 'AUMENTO_MASIVO' ? (
+                      <TrendingUp size={13} className="shrink-0 text-gray-500" />
+                    ) : (
+                      <Pencil size={13} className="shrink-0 text-gray-500" />
+                    )}
+                    <span className="truncate">{c.responsable ?? ORIGEN_LABELS[c.origen]}</span>
+                  </span>
+                </td>
+                <td className="py-2 text-right font-mono tabular-nums text-gray-500">$ {fmt(c.precioAnterior)}</td>
+                <td className="py-2 text-right font-mono tabular-nums text-gray-100">$ {fmt(c.precioNuevo)}</td>
+                <td className="py-2 text-right font-mono tabular-nums">{fmtPorcentaje(c.variacionPorcentual)}</td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      )}
+    </div>
+  );
+}
+
+// ─── Modal ────────────────────────────────────────────────────────────────────
+
+/**
+ * Modal de historial de precios (HU-146).
+ *
+ * Se alimenta del registro de auditoría del catálogo: cada edición de
+ * precio y cada producto tocado por un aumento masivo (HU-145).
+ * Una pestaña muestra cuánto subió el menú mes a mes y la otra los
+ * cambios de un producto con quién los hizo.
+ */
+export default function HistorialPreciosModal({ onClose }: HistorialPreciosModalProps) {
+  const [pestania, setPestania] = useState<Pestania>('mensual');
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div
+        className="fixed inset-0 z-50 bg-black/60 animate-backdrop-in"
+        onClick={onClose}
+      />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-50 flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="bg-neutral-900 border border-gray-800 rounded-xl w-full max-w-2xl pointer-events-auto animate-modal-in"
+          onClick={(e) => e.stopPropagation()}
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-gray-800">
+            <div className="flex items-center gap-3">
+              <History size={20} className="text-red-400" />
+              <div>
+                <h2 className="text-lg font-semibold text-text-primary">Historial de precios</h2>
+                <p className="text-sm text-text-secondary">Quién cambió qué y cuánto subió el menú</p>
+              </div>
+            </div>
+            <button
+              onClick={onClose}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+            >
+              <X size={18} />
+            </button>
+          </div>
+
+          {/* Pestañas */}
+          <div className="flex gap-2 px-6 pt-4">
+            {([
+              ['mensual', 'Variación mensual'],
+              ['producto', 'Por producto'],
+            ] as const).map(([valor, label]) => (
+              <button
+                key={valor}
+                onClick={() => setPestania(valor)}
+                className={`flex-1 h-10 rounded-lg text-sm font-medium border transition-colors ${
+                  pestania === valor
+                    ? 'bg-red-600/20 border-red-600 text-red-300'
+                    : 'bg-neutral-800 border-neutral-700 text-gray-400 hover:border-neutral-600'
+                }`}
+              >
+                {label}
+              </button>
+            ))}
+          </div>
+
+          {/* Body */}
+          <div className="px-6 py-5 max-h-[65vh] overflow-y-auto">
+            {pestania === 'mensual' ? <VariacionMensual /> : <HistorialProducto />}
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
@@ -0,0 +1,265 @@
+import { useEffect, useMemo, useState } from 'react';
+import { X, Tags, Trash2, Plus, Loader2 } from 'lucide-react';
+import { useListasPrecios, useGuardarListaPrecios, useProductos } from '../hooks/useProductos';
+import useToast from '../../../hooks/useToast';
+import { CANAL_VENTA_LABELS, type CanalVenta } from '../../salon/types';
+import type { ListaPreciosResponse } from '../types';
+
+interface ListasPreciosModalProps {
+  onClose: () => void;
+}
+
+interface PrecioForm {
+  productoId: string;
+  precio: string;
+}
+
+interface FormState {
+  nombre: string;
+  porcentajeRecargo: string;
+  precios: PrecioForm[];
+}
+
+const CANALES = Object.keys(CANAL_VENTA_LABELS) as CanalVenta[];
+
+function formDesdeLista(lista: ListaPreciosResponse | undefined, canal: CanalVenta): FormState {
+  return {
+    nombre: lista?.nombre ?? CANAL_VENTA_LABELS[canal],
+    porcentajeRecargo: String(lista?.porcentajeRecargo ?? 0),
+    precios: (lista?.precios ?? []).map((p) => ({ productoId: p.productoId, precio: String(p.precio) })),
+  };
+}
+
+/**
+ * Modal de listas de precios por canal (HU-108).
+ *
+ * Una pestaña por canal (salón, take away, delivery). Cada lista tiene
+ * un recargo sobre el precio de catálogo y, opcionalmente, precios
+ * especiales por producto que reemplazan al recargo.
+ *
+ * El canal se elige al abrir la mesa; los pedidos ya abiertos conservan
+ * la lista con la que se crearon.
+ */
+export default function ListasPreciosModal({ onClose }: ListasPreciosModalProps) {
+  const toast = useToast();
+  const { data: listas = [], isLoading } = useListasPrecios();
+  const { data: productos = [] } = useProductos();
+  const guardar = useGuardarListaPrecios();
+
+  const [canal, setCanal] = useState<CanalVenta>('SALON');
+  const [form, setForm] = useState<FormState>(() => formDesdeLista(undefined, 'SALON'));
+
+  const listaActual = listas.find((l) => l.canal === canal);
+
+  // Al cambiar de pestaña (o al llegar los datos) se recarga el formulario
+  useEffect(() => {
+    setForm(formDesdeLista(listaActual, canal));
+  }, [listaActual, canal]);
+
+  const productosVendibles = useMemo(
+    () => productos.filter((p) => p.activo).sort((a, b) => a.nombre.localeCompare(b.nombre)),
+    [productos]
+  );
+  const precioCatalogo = (productoId: string) =>
+    productos.find((p) => p.id === productoId)?.precio;
+
+  const actualizarPrecio = (index: number, cambios: Partial<PrecioForm>) =>
+    setForm((f) => ({
+      ...f,
+      precios: f.precios.map((p, i) => (i === index ? { ...p, ...cambios } : p)),
+    }));
+
+  const handleGuardar = () => {
+    const recargo = Number(form.porcentajeRecargo);
+    if (!form.nombre.trim()) {
+      toast.error('El nombre de la lista es obligatorio');
+      return;
+    }
+    if (Number.isNaN(recargo) || recargo < 0 || recargo > 100) {
+      toast.error('El recargo debe estar entre 0% y 100%');
+      return;
+    }
+
+    const precios = form.precios
+      .filter((p) => p.productoId && p.precio !== '')
+      .map((p) => ({ productoId: p.productoId, precio: Number(p.precio) }));
+
+    guardar.mutate(
+      { canal, nombre: form.nombre.trim(), porcentajeRecargo: recargo, precios },
+      {
+        onSuccess: () => toast.success(`Lista de ${CANAL_VENTA_LABELS[canal]} guardada`),
+        onError: (err: any) =>
+          toast.error(err?.response?.data?.message || 'Error al guardar la lista de precios'),
+      }
+    );
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div
+        className="fixed inset-0 z-50 bg-black/60 animate-backdrop-in"
+        onClick={onClose}
+      />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-50 flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="bg-neutral-900 border border-gray-800 rounded-xl w-full max-w-xl pointer-events-auto animate-modal-in"
+          onClick={(e) => e.stopPropagation()}
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-gray-800">
+            <div className="flex items-center gap-3">
+              <Tags size={20} className="text-red-400" />
+              <div>
+                <h2 className="text-lg font-semibold text-text-primary">Listas de precios</h2>
+                <p className="text-sm text-text-secondary">Precio por canal de venta</p>
+              </div>
+            </div>
+            <button
+              onClick={onClose}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+            >
+              <X size={18} />
+            </button>
+          </div>
+
+          {/* Pestañas por canal */}
+          <div className="flex gap-2 px-6 pt-4">
+            {CANALES.map((c) => {
+              const configurada = listas.find((l) => l.canal === c)?.configurada;
+              return (
+                <button
+                  key={c}
+                  onClick={() => setCanal(c)}
+                  className={`flex-1 h-10 rounded-lg text-sm font-medium border transition-colors ${
+                    canal === c
+                      ? 'bg-red-600/20 border-red-600 text-red-300'
+                      : 'bg-neutral-800 border-neutral-700 text-gray-400 hover:border-neutral-600'
+                  }`}
+                >
+                  {CANAL_VENTA_LABELS[c]}
+                  {!configurada && <span className="ml-1 text-xs text-gray-500">(catálogo)</span>}
+                </button>
+              );
+            })}
+          </div>
+
+          {/* Body */}
+          <div className="px-6 py-5 max-h-[60vh] overflow-y-auto space-y-4">
+            {isLoading ? (
+              <div className="flex justify-center py-8">
+                <Loader2 size={24} className="animate-spin text-gray-500" />
+              </div>
+            ) : (
+              <>
+                <div className="grid grid-cols-3 gap-3">
+                  <label className="col-span-2 flex flex-col gap-1 text-sm text-text-secondary">
+                    Nombre
+                    <input
+                      type="text"
+                      value={form.nombre}
+                      onChange={(e) => setForm((f) => ({ ...f, nombre: e.target.value }))}
+                      className="min-h-[44px] px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none"
+                    />
+                  </label>
+                  <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                    Recargo %
+                    <input
+                      type="number"
+                      min="0"
+                      max="100"
+                      step="any"
+                      value={form.porcentajeRecargo}
+                      onChange={(e) => setForm((f) => ({ ...f, porcentajeRecargo: e.target.value }))}
+                      className="min-h-[44px] px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary font-mono focus:border-primary focus:outline-none"
+                    />
+                  </label>
+                </div>
+
+                <div className="space-y-2">
+                  <div className="flex items-center justify-between">
+                    <p className="text-sm font-medium text-text-primary">Precios especiales</p>
+                    <button
+                      onClick={() =>
+                        setForm((f) => ({ ...f, precios: [...f.precios, { productoId: '', precio: '' }] }))
+                      }
+                      className="flex items-center gap-1 text-xs font-medium text-red-300 hover:text-red-200"
+                    >
+                      <Plus size={14} />
+                      Agregar
+                    </button>
+                  </div>
+
+                  {form.precios.length === 0 && (
+                    <p className="text-xs text-gray-500">
+                      Sin precios especiales: todos los productos se cobran con el recargo.
+                    </p>
+                  )}
+
+                  {form.precios.map((p, index) => {
+                    const base = precioCatalogo(p.productoId);
+                    return (
+                      <div key={index} className="flex items-center gap-2">
+                        <select
+                          value={p.productoId}
+                          onChange={(e) => actualizarPrecio(index, { productoId: e.target.value })}
+                          className="flex-1 min-h-[40px] px-2 bg-background-card border border-gray-700 rounded-lg text-sm text-text-primary focus:border-primary focus:outline-none"
+                        >
+                          <option value="">Elegir producto…</option>
+                          {productosVendibles.map((prod) => (
+                            <option key={prod.id} value={prod.id}>
+                              {prod.nombre}
+                            </option>
+                          ))}
+                        </select>
+                        <input
+                          type="number"
+                          min="0"
+                          step="any"
+                          value={p.precio}
+                          placeholder={base !== undefined ? String(base) : '0'}
+                          onChange={(e) => actualizarPrecio(index, { precio: e.target.value })}
+                          className="w-28 min-h-[40px] px-2 bg-background-card border border-gray-700 rounded-lg text-sm text-text-primary font-mono focus:border-primary focus:outline-none"
+                        />
+                        <button
+                          onClick={() =>
+                            setForm((f) => ({ ...f, precios: f.precios.filter((_, i) => i !== index) }))
+                          }
+                          className="p-2 rounded-lg text-gray-500 hover:text-red-400 hover:bg-gray-800 transition-colors"
+                          title="Quitar"
+                        >
+                          <Trash2 size={16} />
+                        </button>
+                      </div>
+                    );
+                  })}
+                </div>
+              </>
+            )}
+          </div>
+
+          {/* Footer */}
+          <div className="flex justify-end gap-3 px-6 py-4 border-t border-gray-800">
+            <button
+              onClick={onClose}
+              className="btn-secondary text-sm !min-h-[42px] px-5"
+              disabled={guardar.isPending}
+            >
+              Cerrar
+            </button>
+            <button
+              onClick={handleGuardar}
+              className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+              disabled={guardar.isPending || isLoading}
+            >
+              {guardar.isPending && <Loader2 size={16} className="animate-spin" />}
+              Guardar lista
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
@@ -0,0 +1,119 @@
+import { X, Download, ShoppingCart, Loader2 } from 'lucide-react';
+import { useProductosParaReponer } from '../hooks/useProductos';
+import { generarCsv, descargarCsv } from '../../../lib/csv';
+
+interface ParaReponerModalProps {
+  onClose: () => void;
+}
+
+/**
+ * Modal con el listado "para reponer" (HU-101).
+ *
+ * Muestra los productos que alcanzaron su stock mínimo junto con la
+ * cantidad sugerida de compra, y permite exportarlo como CSV para
+ * hacer las compras del día.
+ */
+export default function ParaReponerModal({ onClose }: ParaReponerModalProps) {
+  const { data: items = [], isLoading } = useProductosParaReponer();
+
+  const handleExportar = () => {
+    const csv = generarCsv(
+      ['Producto', 'Stock actual', 'Stock mínimo', 'Cantidad sugerida'],
+      items.map((i) => [i.nombre, i.stockActual, i.stockMinimo, i.cantidadSugerida])
+    );
+    const fecha = new Date().toISOString().slice(0, 10);
+    descargarCsv(csv, `para-reponer-${fecha}.csv`);
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div
+        className="fixed inset-0 z-50 bg-black/60 animate-backdrop-in"
+        onClick={onClose}
+      />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-50 flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="bg-neutral-900 border border-gray-800 rounded-xl w-full max-w-lg pointer-events-auto animate-modal-in"
+          onClick={(e) => e.stopPropagation()}
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-gray-800">
+            <div className="flex items-center gap-3">
+              <ShoppingCart size={20} className="text-red-400" />
+              <div>
+                <h2 className="text-lg font-semibold text-text-primary">Para reponer</h2>
+                <p className="text-sm text-text-secondary">Productos en o bajo su stock mínimo</p>
+              </div>
+            </div>
+            <button
+              onClick={onClose}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+            >
+              <X size={18} />
+            </button>
+          </div>
+
+          {/* Body */}
+          <div className="px-6 py-5 max-h-[60vh] overflow-y-auto">
+            {isLoading ? (
+              <div className="flex justify-center py-8">
+                <Loader2 size={24} className="text-gray-500 animate-spin" />
+              </div>
+            ) : items.length === 0 ? (
+              <p className="text-center text-sm text-text-secondary py-8">
+                No hay productos por debajo de su stock mínimo.
+              </p>
+            ) : (
+              <table className="w-full text-sm">
+                <thead>
+                  <tr className="text-left text-xs text-text-secondary border-b border-gray-800">
+                    <th className="pb-2 font-medium">Producto</th>
+                    <th className="pb-2 font-medium text-right">Actual</th>
+                    <th className="pb-2 font-medium text-right">Mínimo</th>
+                    <th className="pb-2 font-medium text-right">Comprar</th>
+                  </tr>
+                </thead>
+                <tbody>
+                  {items.map((item) => (
+                    <tr key={item.productoId} className="border-b border-gray-800/50">
+                      <td className="py-2 text-text-primary">{item.nombre}</td>
+                      <td
+                        className={`py-2 text-right font-mono ${
+                          item.stockActual <= 0 ? 'text-red-400' : 'text-text-primary'
+                        }`}
+                      >
+                        {item.stockActual}
+                      </td>
+                      <td className="py-2 text-right font-mono text-text-secondary">{item.stockMinimo}</td>
+                      <td className="py-2 text-right font-mono font-semibold text-green-400">
+                        {item.cantidadSugerida}
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            )}
+          </div>
+
+          {/* Footer */}
+          <div className="flex items-center justify-end gap-3 px-6 py-4 border-t border-gray-800">
+            <button onClick={onClose} className="btn-secondary text-sm !min-h-[42px] px-5">
+              Cerrar
+            </button>
+            <button
+              onClick={handleExportar}
+              className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+              disabled={items.length === 0}
+            >
+              <Download size={16} />
+              <span>Exportar CSV</span>
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
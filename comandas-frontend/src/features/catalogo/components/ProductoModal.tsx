@@ -1,7 +1,10 @@
 import { useState } from 'react';
 import { X, Check, HelpCircle } from 'lucide-react';
 import { useCrearProducto, useEditarProducto } from '../hooks/useProductos';
-import type { ProductoResponse, ProductoRequest } from '../types';
+import type { ProductoResponse, ProductoRequest, ComponenteCombo, Alergeno } from '../types';
+import { ALERGENOS, ALERGENO_LABELS } from '../types';
+import ComponentesComboEditor from './ComponentesComboEditor';
+import FotoProductoEditor from './FotoProductoEditor';
 import { useCategorias } from '../../categorias/hooks/useCategorias';
 import useToast from '../../../hooks/useToast';
 
@@ -15,6 +18,11 @@ interface ProductoModalProps {
  * Modal para crear o editar un producto del catálogo.
  *
  * Campos: Nombre, Precio, Controla Stock (checkbox), Color (selector visual).
+ * HU-103: opcionalmente, componentes de combo con sus opciones.
+ * HU-127: al editar, la foto que muestra la carta digital.
+ * HU-146: si cambia el precio, se puede anotar quién lo cambió para el historial.
+ * HU-147: alérgenos del producto, para avisar al mozo ante un comensal alérgico.
+ * HU-161: horario propio en que se ofrece (si no, rige el de su categoría).
  *
  * Decisión: Se usa un selector visual de colores predefinidos
  * en lugar de un color picker completo, porque el operador
@@ -34,10 +42,19 @@ export default function ProductoModal({ producto, onClose }: ProductoModalProps)
   const [nombre, setNombre] = useState(producto?.nombre ?? '');
   const [precio, setPrecio] = useState(producto?.precio?.toString() ?? '');
   const [controlaStock, setControlaStock] = useState(producto?.controlaStock ?? false);
+  const [stockMinimo, setStockMinimo] = useState(producto?.stockMinimo?.toString() ?? '');
   const [categoriaId, setCategoriaId] = useState<string | null>(producto?.categoriaId ?? null);
   const [esModificadorEstructural, setEsModificadorEstructural] = useState(
     producto?.esModificadorEstructural ?? false
   );
+  const [componentesCombo, setComponentesCombo] = useState<ComponenteCombo[]>(
+    producto?.componentesCombo ?? []
+  );
+  const [esCombo, setEsCombo] = useState(componentesCombo.length > 0);
+  const [responsable, setResponsable] = useState('');
+  const [alergenos, setAlergenos] = useState<Alergeno[]>(producto?.alergenos ?? []);
+  const [horarioDesde, setHorarioDesde] = useState(producto?.horarioDesde?.slice(0, 5) ?? '');
+  const [horarioHasta, setHorarioHasta] = useState(producto?.horarioHasta?.slice(0, 5) ?? '');
   const [error, setError] = useState<string | null>(null);
 
   const handleGuardarProducto = () => {
@@ -55,6 +72,30 @@ export default function ProductoModal({ producto, onClose }: ProductoModalProps)
       return;
     }
 
+    const stockMinimoNum = stockMinimo.trim() === '' ? undefined : parseInt(stockMinimo, 10);
+    if (stockMinimoNum !== undefined && (isNaN(stockMinimoNum) || stockMinimoNum < 0)) {
+      setError('El stock mínimo debe ser un número mayor o igual a cero');
+      return;
+    }
+
+    if (esCombo && componentesCombo.length === 0) {
+      setError('El combo debe tener al menos un componente');
+      return;
+    }
+    if (esCombo && componentesCombo.some((c) => !c.nombre.trim() || c.opcionesIds.length === 0)) {
+      setError('Cada componente del combo necesita un nombre y al menos una opción');
+      return;
+    }
+
+    if (!horarioDesde !== !horarioHasta) {
+      setError('Indicá desde y hasta qué hora se ofrece');
+      return;
+    }
+    if (horarioDesde && horarioDesde === horarioHasta) {
+      setError('El horario tiene que empezar y terminar a horas distintas');
+      return;
+    }
+
     // Derivar esExtra y colorHex de la categoría seleccionada
     const categoriaSeleccionada = categoriaId
       ? categoriasAsignables.find((c) => c.id === categoriaId)
@@ -67,10 +108,19 @@ export default function ProductoModal({ producto, onClose }: ProductoModalProps)
       precio: precioNum,
       colorHex: categoriaSeleccionada?.colorHex ?? '#FFFFFF',
       controlaStock,
+      stockMinimo: controlaStock ? stockMinimoNum : undefined,
       esExtra,
       categoriaId: categoriaId ?? undefined,
       // Solo enviar esModificadorEstructural si es un extra; para productos normales siempre false
       esModificadorEstructural: esExtra ? esModificadorEstructural : false,
+      // Lista vacía en edición convierte un combo existente en producto simple
+      componentesCombo: esCombo
+        ? componentesCombo.map((c) => ({ ...c, nombre: c.nombre.trim() }))
+        : esEdicion ? [] : undefined,
+      responsable: responsable.trim() || undefined,
+      alergenos,
+      // Desde y hasta en null quitan el horario propio
+      horario: { desde: horarioDesde || null, hasta: horarioHasta || null },
     };
 
     if (esEdicion) {
@@ -96,6 +146,7 @@ export default function ProductoModal({ producto, onClose }: ProductoModalProps)
   };
 
   const isSaving = crearProducto.isPending || editarProducto.isPending;
+  const cambiaPrecio = esEdicion && precio !== '' && parseFloat(precio) !== producto.precio;
 
   return (
     <>
@@ -125,7 +176,7 @@ export default function ProductoModal({ producto, onClose }: ProductoModalProps)
           </div>
 
           {/* Body */}
-          <div className="px-6 py-5 space-y-5">
+          <div className="px-6 py-5 space-y-5 max-h-[70vh] overflow-y-auto">
             {/* Nombre */}
             <div className="flex flex-col gap-1">
               <label className="text-sm text-text-secondary">Nombre</label>
@@ -156,6 +207,23 @@ export default function ProductoModal({ producto, onClose }: ProductoModalProps)
               </div>
             </div>
 
+            {/* Responsable del cambio de precio (HU-146) */}
+            {cambiaPrecio && (
+              <div className="flex flex-col gap-1">
+                <label className="text-sm text-text-secondary">
+                  ¿Quién cambia el precio? <span className="text-xs text-gray-500">(queda en el historial)</span>
+                </label>
+                <input
+                  type="text"
+                  value={responsable}
+                  maxLength={60}
+                  onChange={(e) => setResponsable(e.target.value)}
+                  placeholder="Opcional"
+                  className="min-h-[44px] px-4 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none"
+                />
+              </div>
+            )}
+
             {/* Controla Stock */}
             <label className="flex items-center gap-3 cursor-pointer select-none">
               <input
@@ -168,6 +236,108 @@ export default function ProductoModal({ producto, onClose }: ProductoModalProps)
               <span className="text-xs text-text-secondary">(activa inventario)</span>
             </label>
 
+            {/* Stock mínimo (HU-101): solo tiene sentido si controla stock */}
+            {controlaStock && (
+              <div className="flex flex-col gap-1">
+                <label className="text-sm text-text-secondary">Stock mínimo</label>
+                <input
+                  type="number"
+                  value={stockMinimo}
+                  onChange={(e) => setStockMinimo(e.target.value)}
+                  placeholder="Sin alerta"
+                  min="0"
+                  className="w-full min-h-[48px] px-4 bg-background-card border border-gray-700 rounded-lg text-text-primary font-mono focus:border-primary focus:outline-none"
+                />
+                <span className="text-xs text-text-secondary">
+                  Al alcanzar este valor se avisa en pantalla y se agrega a la lista para reponer
+                </span>
+              </div>
+            )}
+
+            {/* Combo (HU-103) */}
+            <label className="flex items-center gap-3 cursor-pointer select-none">
+              <input
+                type="checkbox"
+                checked={esCombo}
+                onChange={(e) => setEsCombo(e.target.checked)}
+                className="w-5 h-5 rounded border-gray-600 bg-background-card text-primary focus:ring-primary focus:ring-offset-0"
+              />
+              <span className="text-sm text-text-primary">Es un combo</span>
+              <span className="text-xs text-text-secondary">(precio cerrado, se elige por componente)</span>
+            </label>
+
+            {esCombo && (
+              <ComponentesComboEditor
+                componentes={componentesCombo}
+                onChange={setComponentesCombo}
+                productoId={producto?.id}
+              />
+            )}
+
+            {/* Alérgenos (HU-147) */}
+            <div className="space-y-2">
+              <label className="text-sm text-text-secondary">Alérgenos</label>
+              <div className="flex flex-wrap gap-2">
+                {ALERGENOS.map((alergeno) => {
+                  const activo = alergenos.includes(alergeno);
+                  return (
+                    <button
+                      key={alergeno}
+                      type="button"
+                      onClick={() =>
+                        setAlergenos((prev) =>
+                          activo ? prev.filter((a) => a !== alergeno) : [...prev, alergeno]
+                        )
+                      }
+                      className={[
+                        'px-3 py-1.5 rounded-lg border text-xs font-medium transition-colors',
+                        activo
+                          ? 'border-amber-500 bg-amber-500/15 text-amber-300'
+                          : 'border-gray-700 bg-background-card text-gray-400 hover:border-gray-500',
+                      ].join(' ')}
+                    >
+                      {ALERGENO_LABELS[alergeno]}
+                    </button>
+                  );
+                })}
+              </div>
+            </div>
+
+            {/* Horario propio (HU-161) */}
+            <div className="flex flex-col gap-1">
+              <label className="text-sm text-text-secondary">Horario en que se ofrece</label>
+              <div className="flex items-center gap-2">
+                <input
+                  type="time"
+                  value={horarioDesde}
+                  onChange={(e) => setHorarioDesde(e.target.value)}
+                  className="min-h-[48px] px-4 bg-background-card border border-gray-700 rounded-lg text-text-primary font-mono focus:border-primary focus:outline-none"
+                />
+                <span className="text-sm text-text-secondary">a</span>
+                <input
+                  type="time"
+                  value={horarioHasta}
+                  onChange={(e) => setHorarioHasta(e.target.value)}
+                  className="min-h-[48px] px-4 bg-background-card border border-gray-700 rounded-lg text-text-primary font-mono focus:border-primary focus:outline-none"
+                />
+                {(horarioDesde || horarioHasta) && (
+                  <button
+                    type="button"
+                    onClick={() => {
+                      setHorarioDesde('');
+                      setHorarioHasta('');
+                    }}
+                    className="text-xs text-text-secondary hover:text-text-primary"
+                  >
+                    Quitar
+                  </button>
+                )}
+              </div>
+              <span className="text-xs text-text-secondary">
+                Vacío usa el horario de la categoría. Fuera de horario no aparece en el POS ni en la carta del QR
+              </span>
+            </div>
+
             {/* Selector de Categoría */}
             <div className="space-y-2">
               <label className="text-sm text-text-secondary">Categoría</label>
@@ -204,6 +374,9 @@ export default function ProductoModal({ producto, onClose }: ProductoModalProps)
               </div>
             </div>
 
+            {/* Foto para la carta digital (HU-127): los extras no se publican */}
+            {producto && !producto.esExtra && <FotoProductoEditor productoId={producto.id} />}
+
             {/* Afecta composición — solo visible cuando la categoría es de extras */}
             {(() => {
               const catSel = categoriaId ? categoriasAsignables.find((c) => c.id === categoriaId) : null;
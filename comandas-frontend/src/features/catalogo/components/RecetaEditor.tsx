@@ -0,0 +1,209 @@
+import { useEffect, useMemo, useState } from 'react';
+import { ArrowLeft, Plus, Trash2, Loader2 } from 'lucide-react';
+import { useReceta, useGuardarReceta, useEliminarReceta } from '../hooks/useProductos';
+import useToast from '../../../hooks/useToast';
+import { UNIDAD_INSUMO_LABELS, type InsumoResponse, type ProductoResponse } from '../types';
+
+interface RecetaEditorProps {
+  producto: ProductoResponse;
+  insumos: InsumoResponse[];
+  onVolver: () => void;
+}
+
+interface IngredienteForm {
+  insumoId: string;
+  cantidad: string;
+}
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+/**
+ * Editor de la receta de un producto (HU-118).
+ *
+ * El costo y el margen se recalculan en vivo con el costo vigente de los
+ * insumos, antes de guardar, para ver el impacto de cada ingrediente.
+ */
+export default function RecetaEditor({ producto, insumos, onVolver }: RecetaEditorProps) {
+  const toast = useToast();
+  const { data: receta, isLoading } = useReceta(producto.id);
+  const guardar = useGuardarReceta();
+  const eliminar = useEliminarReceta();
+
+  const [ingredientes, setIngredientes] = useState<IngredienteForm[]>([]);
+
+  useEffect(() => {
+    setIngredientes(
+      (receta?.ingredientes ?? []).map((i) => ({ insumoId: i.insumoId, cantidad: String(i.cantidad) }))
+    );
+  }, [receta]);
+
+  const insumoPorId = useMemo(() => new Map(insumos.map((i) => [i.id, i])), [insumos]);
+
+  const costo = ingredientes.reduce((total, i) => {
+    const insumo = insumoPorId.get(i.insumoId);
+    const cantidad = Number(i.cantidad);
+    return insumo && cantidad > 0 ? total + insumo.costoUnitario * cantidad : total;
+  }, 0);
+  const margen = producto.precio - costo;
+  const margenPorcentaje = producto.precio > 0 ? (margen / producto.precio) * 100 : 0;
+
+  const actualizar = (index: number, cambios: Partial<IngredienteForm>) =>
+    setIngredientes((prev) => prev.map((i, idx) => (idx === index ? { ...i, ...cambios } : i)));
+
+  const handleGuardar = () => {
+    const validos = ingredientes.filter((i) => i.insumoId && Number(i.cantidad) > 0);
+    if (validos.length === 0) {
+      toast.error('Agregá al menos un insumo con cantidad');
+      return;
+    }
+    guardar.mutate(
+      {
+        productoId: producto.id,
+        ingredientes: validos.map((i) => ({ insumoId: i.insumoId, cantidad: Number(i.cantidad) })),
+      },
+      {
+        onSuccess: ({ data }) => {
+          if (data.rentabilidad.aPerdida) {
+            toast.warning(`${producto.nombre} se vende a pérdida: cuesta $ ${fmt(data.rentabilidad.costo)}`, 6000);
+          } else {
+            toast.success('Receta guardada');
+          }
+          onVolver();
+        },
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'Error al guardar la receta'),
+      }
+    );
+  };
+
+  const handleQuitar = () =>
+    eliminar.mutate(producto.id, {
+      onSuccess: () => {
+        toast.success('Receta quitada');
+        onVolver();
+      },
+      onError: (err: any) => toast.error(err?.response?.data?.message || 'Error al quitar la receta'),
+    });
+
+  const ocupado = guardar.isPending || eliminar.isPending;
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center gap-3">
+        <button
+          onClick={onVolver}
+          className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+          title="Volver"
+        >
+          <ArrowLeft size={16} />
+        </button>
+        <div>
+          <p className="text-sm font-medium text-text-primary">{producto.nombre}</p>
+          <p className="text-xs text-text-secondary">Precio $ {fmt(producto.precio)}</p>
+        </div>
+      </div>
+
+      {isLoading ? (
+        <div className="flex justify-center py-8">
+          <Loader2 size={24} className="animate-spin text-gray-500" />
+        </div>
+      ) : (
+        <>
+          <div className="space-y-2">
+            <div className="flex items-center justify-between">
+              <p className="text-sm font-medium text-text-primary">Ingredientes por unidad</p>
+              <button
+                onClick={() => setIngredientes((prev) => [...prev, { insumoId: '', cantidad: '' }])}
+                className="flex items-center gap-1 text-xs font-medium text-red-300 hover:text-red-200"
+              >
+                <Plus size={14} />
+                Agregar
+              </button>
+            </div>
+
+            {ingredientes.length === 0 && (
+              <p className="text-xs text-gray-500">Sin receta: el producto no entra en el cálculo de márgenes.</p>
+            )}
+
+            {ingredientes.map((i, index) => {
+              const insumo = insumoPorId.get(i.insumoId);
+              return (
+                <div key={index} className="flex items-center gap-2">
+                  <select
+                    value={i.insumoId}
+                    onChange={(e) => actualizar(index, { insumoId: e.target.value })}
+                    className="flex-1 min-h-[40px] px-2 bg-background-card border border-gray-700 rounded-lg text-sm text-text-primary focus:border-primary focus:outline-none"
+                  >
+                    <option value="">Elegir insumo…</option>
+                    {insumos.map((ins) => (
+                      <option key={ins.id} value={ins.id}>
+                        {ins.nombre}
+                      </option>
+                    ))}
+                  </select>
+                  <input
+                    type="number"
+                    min="0"
+                    step="0.001"
+                    value={i.cantidad}
+                    placeholder="0"
+                    onChange={(e) => actualizar(index, { cantidad: e.target.value })}
+                    className="w-24 min-h-[40px] px-2 bg-background-card border border-gray-700 rounded-lg text-sm text-text-primary font-mono focus:border-primary focus:outline-none"
+                  />
+                  <span className="w-10 text-xs text-gray-500">
+                    {insumo ? UNIDAD_INSUMO_LABELS[insumo.unidad] : ''}
+                  </span>
+                  <button
+                    onClick={() => setIngredientes((prev) => prev.filter((_, idx) => idx !== index))}
+                    className="p-2 rounded-lg text-gray-500 hover:text-red-400 hover:bg-gray-800 transition-colors"
+                    title="Quitar"
+                  >
+                    <Trash2 size={16} />
+                  </button>
+                </div>
+              );
+            })}
+          </div>
+
+          <div className="grid grid-cols-3 gap-3 text-sm">
+            <div className="rounded-lg bg-neutral-800/60 px-3 py-2">
+              <p className="text-xs text-text-secondary">Costo</p>
+              <p className="font-mono text-text-primary">$ {fmt(costo)}</p>
+            </div>
+            <div className="rounded-lg bg-neutral-800/60 px-3 py-2">
+              <p className="text-xs text-text-secondary">Margen</p>
+              <p className={`font-mono ${margen < 0 ? 'text-red-400' : 'text-green-400'}`}>$ {fmt(margen)}</p>
+            </div>
+            <div className="rounded-lg bg-neutral-800/60 px-3 py-2">
+              <p className="text-xs text-text-secondary">Margen %</p>
+              <p className={`font-mono ${margen < 0 ? 'text-red-400' : 'text-text-primary'}`}>
+                {fmt(margenPorcentaje)} %
+              </p>
+            </div>
+          </div>
+
+          <div className="flex justify-end gap-3">
+            {receta && (
+              <button
+                onClick={handleQuitar}
+                disabled={ocupado}
+                className="btn-secondary text-sm !min-h-[42px] px-5 mr-auto"
+              >
+                Quitar receta
+              </button>
+            )}
+            <button
+              onClick={handleGuardar}
+              disabled={ocupado}
+              className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+            >
+              {guardar.isPending && <Loader2 size={16} className="animate-spin" />}
+              Guardar receta
+            </button>
+          </div>
+        </>
+      )}
+    </div>
+  );
+}
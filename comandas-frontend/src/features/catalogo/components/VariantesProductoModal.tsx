@@ -1,7 +1,7 @@
 import { useState, useMemo } from 'react';
-import { X, Plus, Layers, Loader2, Check } from 'lucide-react';
+import { X, Plus, Layers, Loader2, Check, Trash2 } from 'lucide-react';
 import type { ProductoResponse } from '../types';
-import { useVariantes, useCrearVariante } from '../hooks/useProductos';
+import { useVariantes, useCrearVariante, useCrearVariantesEnLote } from '../hooks/useProductos';
 
 // ─── Tipos ────────────────────────────────────────────────────────────────────
 
@@ -11,6 +11,15 @@ interface VariantesProductoModalProps {
   onClose: () => void;
 }
 
+type ModoAlta = 'variante' | 'tamanios';
+
+interface TamanioForm {
+  etiqueta: string;
+  precio: string;
+}
+
+const TAMANIO_VACIO: TamanioForm = { etiqueta: '', precio: '' };
+
 // ─── Helpers ──────────────────────────────────────────────────────────────────
 
 function formatPrecio(monto: number): string {
@@ -25,6 +34,8 @@ function formatPrecio(monto: number): string {
  * Permite:
  * 1. Ver las variantes existentes del grupo (si las hay)
  * 2. Crear nuevas variantes (ej: Doble, Triple)
+ * 3. HU-109: Cargar varios tamaños de una vez (ej: Chica / Mediana / Grande),
+ *    cada uno con su precio; el nombre se arma con la etiqueta
  *
  * Decisión: El formulario de creación es inline (no otro modal),
  * porque crear una variante es una operación rápida de 3 campos.
@@ -38,14 +49,21 @@ export default function VariantesProductoModal({
 }: VariantesProductoModalProps) {
   const { data: variantes = [], isLoading } = useVariantes(producto.id);
   const crearVariante = useCrearVariante();
+  const crearEnLote = useCrearVariantesEnLote();
 
   // ── Estado del formulario de nueva variante ──
   const [mostrarFormulario, setMostrarFormulario] = useState(false);
+  const [modo, setModo] = useState<ModoAlta>('variante');
   const [nombre, setNombre] = useState('');
+  const [etiqueta, setEtiqueta] = useState('');
   const [precio, setPrecio] = useState('');
   const [nivel, setNivel] = useState('');
   const [error, setError] = useState<string | null>(null);
 
+  // ── Estado del alta de tamaños en lote (HU-109) ──
+  const [etiquetaBase, setEtiquetaBase] = useState('');
+  const [tamanios, setTamanios] = useState<TamanioForm[]>([{ ...TAMANIO_VACIO }]);
+
   // Variantes ordenadas por nivel (cantidadDiscosCarne)
   const variantesOrdenadas = useMemo(
     () => [...variantes].sort((a, b) => (a.cantidadDiscosCarne ?? 0) - (b.cantidadDiscosCarne ?? 0)),
@@ -61,11 +79,22 @@ export default function VariantesProductoModal({
     return maxNivel + 1;
   }, [variantesOrdenadas]);
 
+  const limpiarFormulario = () => {
+    setNombre('');
+    setEtiqueta('');
+    setPrecio('');
+    setNivel('');
+    setEtiquetaBase('');
+    setTamanios([{ ...TAMANIO_VACIO }]);
+    setMostrarFormulario(false);
+    setError(null);
+  };
+
   const handleCrearVariante = () => {
     setError(null);
 
-    if (!nombre.trim()) {
-      setError('El nombre de la variante es obligatorio');
+    if (!nombre.trim() && !etiqueta.trim()) {
+      setError('Indicá un nombre o una etiqueta para la variante');
       return;
     }
 
@@ -75,8 +104,9 @@ export default function VariantesProductoModal({
       return;
     }
 
-    const nivelNum = parseInt(nivel || String(proximoNivel), 10);
-    if (isNaN(nivelNum) || nivelNum < 1) {
+    // Nivel vacío: el backend asigna la siguiente posición del grupo
+    const nivelNum = nivel ? parseInt(nivel, 10) : undefined;
+    if (nivelNum !== undefined && (isNaN(nivelNum) || nivelNum < 1)) {
       setError('El nivel debe ser un número mayor o igual a 1');
       return;
     }
@@ -84,20 +114,16 @@ export default function VariantesProductoModal({
     crearVariante.mutate(
       {
         productoBaseId: producto.id,
-        nombre: nombre.trim(),
+        nombre: nombre.trim() || undefined,
+        etiqueta: etiqueta.trim() || undefined,
         precio: precioNum,
         cantidadDiscosCarne: nivelNum,
         categoriaId: producto.categoriaId ?? undefined,
         colorHex: producto.colorHex,
       },
       {
-        onSuccess: () => {
-          // Limpiar formulario y mantener modal abierto para agregar más
-          setNombre('');
-          setPrecio('');
-          setNivel('');
-          setMostrarFormulario(false);
-        },
+        // Limpiar formulario y mantener modal abierto para agregar más
+        onSuccess: limpiarFormulario,
         onError: (err: any) => {
           const msg = err?.response?.data?.message || 'Error al crear la variante';
           setError(msg);
@@ -106,6 +132,52 @@ export default function VariantesProductoModal({
     );
   };
 
+  const handleCrearTamanios = () => {
+    setError(null);
+
+    const filas = tamanios.filter((t) => t.etiqueta.trim() || t.precio);
+    if (filas.length === 0) {
+      setError('Cargá al menos un tamaño');
+      return;
+    }
+    for (const fila of filas) {
+      const precioNum = parseFloat(fila.precio);
+      if (!fila.etiqueta.trim()) {
+        setError('Cada tamaño necesita una etiqueta (ej: Grande)');
+        return;
+      }
+      if (isNaN(precioNum) || precioNum <= 0) {
+        setError(`El precio de "${fila.etiqueta.trim()}" debe ser mayor a cero`);
+        return;
+      }
+    }
+
+    crearEnLote.mutate(
+      {
+        productoBaseId: producto.id,
+        etiquetaBase: etiquetaBase.trim() || undefined,
+        variantes: filas.map((fila) => ({
+          etiqueta: fila.etiqueta.trim(),
+          precio: parseFloat(fila.precio),
+          categoriaId: producto.categoriaId ?? undefined,
+          colorHex: producto.colorHex,
+        })),
+      },
+      {
+        onSuccess: limpiarFormulario,
+        onError: (err: any) => {
+          const msg = err?.response?.data?.message || 'Error al crear los tamaños';
+          setError(msg);
+        },
+      }
+    );
+  };
+
+  const actualizarTamanio = (index: number, cambios: Partial<TamanioForm>) =>
+    setTamanios((filas) => filas.map((t, i) => (i === index ? { ...t, ...cambios } : t)));
+
+  const creando = crearVariante.isPending || crearEnLote.isPending;
+
   return (
     <>
       {/* Backdrop */}
@@ -157,7 +229,7 @@ export default function VariantesProductoModal({
                     Este producto no tiene variantes todavía.
                   </p>
                   <p className="text-xs text-gray-600 mt-1">
-                    Creá variantes como Doble, Triple, etc. para agruparlas en el POS.
+                    Creá variantes como Doble, Triple o tamaños (Chica, Grande) para agruparlas en el POS.
                   </p>
                 </div>
               </div>
@@ -188,6 +260,11 @@ export default function VariantesProductoModal({
                     <div className="flex-1 min-w-0">
                       <p className="text-sm font-semibold text-gray-200 truncate">
                         {v.nombre}
+                        {v.etiquetaVariante && (
+                          <span className="ml-2 text-[10px] font-bold uppercase text-red-300 bg-red-950/30 px-1.5 py-0.5 rounded">
+                            {v.etiquetaVariante}
+                          </span>
+                        )}
                       </p>
                       {v.id === producto.id && (
                         <p className="text-[10px] text-gray-500 uppercase tracking-wider">
@@ -224,16 +301,55 @@ export default function VariantesProductoModal({
                   Nueva variante de "{producto.nombre}"
                 </p>
 
+                {/* Modo de alta */}
+                <div className="flex gap-2">
+                  {([
+                    ['variante', 'Una variante'],
+                    ['tamanios', 'Varios tamaños'],
+                  ] as const).map(([valor, label]) => (
+                    <button
+                      key={valor}
+                      type="button"
+                      onClick={() => {
+                        setModo(valor);
+                        setError(null);
+                      }}
+                      className={`flex-1 h-9 rounded-lg text-xs font-medium border transition-colors ${
+                        modo === valor
+                          ? 'bg-red-600/20 border-red-600 text-red-300'
+                          : 'bg-neutral-900 border-neutral-700 text-gray-400 hover:border-neutral-600'
+                      }`}
+                    >
+                      {label}
+                    </button>
+                  ))}
+                </div>
+
+                {modo === 'variante' ? (
+                <>
+                {/* Etiqueta */}
+                <div className="flex flex-col gap-1">
+                  <label className="text-xs text-gray-500">Etiqueta (se muestra en el POS)</label>
+                  <input
+                    type="text"
+                    value={etiqueta}
+                    onChange={(e) => setEtiqueta(e.target.value)}
+                    placeholder="Ej: Doble, Grande, 1.5 L"
+                    maxLength={30}
+                    className="min-h-[44px] px-4 bg-neutral-900 border border-neutral-700 rounded-lg text-sm text-gray-200 placeholder:text-gray-600 focus:border-red-600/50 focus:outline-none focus:ring-1 focus:ring-red-600/30"
+                    autoFocus
+                  />
+                </div>
+
                 {/* Nombre */}
                 <div className="flex flex-col gap-1">
-                  <label className="text-xs text-gray-500">Nombre</label>
+                  <label className="text-xs text-gray-500">Nombre (opcional si hay etiqueta)</label>
                   <input
                     type="text"
                     value={nombre}
                     onChange={(e) => setNombre(e.target.value)}
-                    placeholder={`Ej: ${producto.nombre} Doble`}
+                    placeholder={`${producto.nombre} ${etiqueta.trim() || 'Doble'}`}
                     className="min-h-[44px] px-4 bg-neutral-900 border border-neutral-700 rounded-lg text-sm text-gray-200 placeholder:text-gray-600 focus:border-red-600/50 focus:outline-none focus:ring-1 focus:ring-red-600/30"
-                    autoFocus
                   />
                 </div>
 
@@ -269,6 +385,74 @@ export default function VariantesProductoModal({
                     </p>
                   </div>
                 </div>
+                </>
+                ) : (
+                <>
+                {/* Etiqueta del producto base */}
+                {!producto.etiquetaVariante && (
+                  <div className="flex flex-col gap-1">
+                    <label className="text-xs text-gray-500">Tamaño de "{producto.nombre}"</label>
+                    <input
+                      type="text"
+                      value={etiquetaBase}
+                      onChange={(e) => setEtiquetaBase(e.target.value)}
+                      placeholder="Ej: Chica (opcional)"
+                      maxLength={30}
+                      className="min-h-[44px] px-4 bg-neutral-900 border border-neutral-700 rounded-lg text-sm text-gray-200 placeholder:text-gray-600 focus:border-red-600/50 focus:outline-none focus:ring-1 focus:ring-red-600/30"
+                    />
+                    <p className="text-[10px] text-gray-600">
+                      Si lo completás, el producto pasa a llamarse "{producto.nombre} {etiquetaBase.trim() || 'Chica'}".
+                    </p>
+                  </div>
+                )}
+
+                {/* Filas de tamaños */}
+                <div className="space-y-2">
+                  <label className="text-xs text-gray-500">Tamaños nuevos</label>
+                  {tamanios.map((t, index) => (
+                    <div key={index} className="flex items-center gap-2">
+                      <input
+                        type="text"
+                        value={t.etiqueta}
+                        onChange={(e) => actualizarTamanio(index, { etiqueta: e.target.value })}
+                        placeholder="Ej: Grande"
+                        maxLength={30}
+                        className="flex-1 min-h-[40px] px-3 bg-neutral-900 border border-neutral-700 rounded-lg text-sm text-gray-200 placeholder:text-gray-600 focus:border-red-600/50 focus:outline-none"
+                      />
+                      <div className="relative w-32">
+                        <span className="absolute left-3 top-1/2 -translate-y-1/2 text-gray-600 font-mono text-sm">$</span>
+                        <input
+                          type="number"
+                          value={t.precio}
+                          onChange={(e) => actualizarTamanio(index, { precio: e.target.value })}
+                          placeholder="0"
+                          min="0"
+                          step="any"
+                          className="w-full min-h-[40px] pl-7 pr-2 bg-neutral-900 border border-neutral-700 rounded-lg text-sm text-gray-200 font-mono placeholder:text-gray-600 focus:border-red-600/50 focus:outline-none"
+                        />
+                      </div>
+                      <button
+                        type="button"
+                        onClick={() => setTamanios((filas) => filas.filter((_, i) => i !== index))}
+                        disabled={tamanios.length === 1}
+                        className="p-2 rounded-lg text-gray-500 hover:text-red-400 hover:bg-neutral-800 disabled:opacity-30 transition-colors"
+                        title="Quitar"
+                      >
+                        <Trash2 size={16} />
+                      </button>
+                    </div>
+                  ))}
+                  <button
+                    type="button"
+                    onClick={() => setTamanios((filas) => [...filas, { ...TAMANIO_VACIO }])}
+                    className="flex items-center gap-1 text-xs font-medium text-red-300 hover:text-red-200"
+                  >
+                    <Plus size={14} />
+                    Agregar tamaño
+                  </button>
+                </div>
+                </>
+                )}
 
                 {/* Error */}
                 {error && (
@@ -279,25 +463,19 @@ export default function VariantesProductoModal({
                 <div className="flex items-center gap-2 pt-1">
                   <button
                     type="button"
-                    onClick={() => {
-                      setMostrarFormulario(false);
-                      setNombre('');
-                      setPrecio('');
-                      setNivel('');
-                      setError(null);
-                    }}
+                    onClick={limpiarFormulario}
                     className="flex-1 h-11 rounded-lg text-sm font-medium text-gray-400 border border-neutral-700 hover:bg-neutral-800 transition-colors"
-                    disabled={crearVariante.isPending}
+                    disabled={creando}
                   >
                     Cancelar
                   </button>
                   <button
                     type="button"
-                    onClick={handleCrearVariante}
-                    disabled={crearVariante.isPending}
+                    onClick={modo === 'variante' ? handleCrearVariante : handleCrearTamanios}
+                    disabled={creando}
                     className="flex-1 h-11 rounded-lg text-sm font-bold text-white bg-red-600 hover:bg-red-500 disabled:opacity-50 disabled:cursor-not-allowed transition-colors flex items-center justify-center gap-2"
                   >
-                    {crearVariante.isPending ? (
+                    {creando ? (
                       <>
                         <Loader2 size={16} className="animate-spin" />
                         Creando…
@@ -305,7 +483,7 @@ export default function VariantesProductoModal({
                     ) : (
                       <>
                         <Check size={16} />
-                        Crear Variante
+                        {modo === 'variante' ? 'Crear Variante' : 'Crear Tamaños'}
                       </>
                     )}
                   </button>
@@ -17,16 +17,45 @@ import {
   Palette,
   Sparkles,
   Layers,
+  ShoppingCart,
+  Tags,
+  Calculator,
+  Ban,
+  TrendingUp,
+  History,
 } from 'lucide-react';
-import { useProductos, useEditarProducto } from '../hooks/useProductos';
+import {
+  useProductos,
+  useEditarProducto,
+  useCambiarDisponibilidad,
+  useRentabilidadProductos,
+} from '../hooks/useProductos';
 import type { ProductoResponse } from '../types';
 import ProductoModal from './ProductoModal';
 import AjusteStockModal from './AjusteStockModal';
 import CategoriasModal from './CategoriasModal';
 import VariantesProductoModal from './VariantesProductoModal';
+import ParaReponerModal from './ParaReponerModal';
+import ListasPreciosModal from './ListasPreciosModal';
+import CosteoModal from './CosteoModal';
+import AumentosPreciosModal from './AumentosPreciosModal';
+import HistorialPreciosModal from './HistorialPreciosModal';
 import { useCategorias } from '../../categorias/hooks/useCategorias';
 import type { CategoriaResponse } from '../../categorias/types';
 
+/** Umbral de stock bajo para productos sin stock mínimo configurado */
+const UMBRAL_STOCK_BAJO_DEFAULT = 5;
+
+/**
+ * Indica si el producto debe resaltarse por stock bajo.
+ * HU-101: si tiene stock mínimo propio se respeta ese umbral.
+ */
+function tieneStockBajo(producto: ProductoResponse): boolean {
+  if (!producto.controlaStock) return false;
+  if (producto.stockMinimo != null) return producto.bajoStockMinimo === true;
+  return (producto.stockActual ?? 0) <= UMBRAL_STOCK_BAJO_DEFAULT;
+}
+
 // ── Tipos de ordenamiento ──
 
 type CriterioOrden = 'nombre' | 'precio' | 'stock' | 'categoria';
@@ -85,12 +114,14 @@ function ordenarProductos(
  * Organización por categorías, búsqueda, ordenamiento y doble layout
  * (grid de cards / tabla compacta). Permite acciones rápidas inline:
  * - Toggle activo/inactivo
+ * - Marcar sin stock en el momento (86)
  * - Editar datos/precio
  * - Ajustar stock
  */
 export default function VistaCatalogo() {
   const { data: productos = [], isLoading } = useProductos();
   const editarProducto = useEditarProducto();
+  const cambiarDisponibilidad = useCambiarDisponibilidad();
   const { data: categorias = [] } = useCategorias();
 
   // ── Helpers derivados de categorías del backend ──
@@ -125,14 +156,21 @@ export default function VistaCatalogo() {
   const [stockModal, setStockModal] = useState<ProductoResponse | null>(null);
   const [variantesModal, setVariantesModal] = useState<ProductoResponse | null>(null);
   const [showCategoriasModal, setShowCategoriasModal] = useState(false);
+  const [showParaReponer, setShowParaReponer] = useState(false);
+  const [showListasPrecios, setShowListasPrecios] = useState(false);
+  const [showCosteo, setShowCosteo] = useState(false);
+  const [showAumentos, setShowAumentos] = useState(false);
+  const [showHistorialPrecios, setShowHistorialPrecios] = useState(false);
+
+  // HU-118: productos cuyo costo de receta supera al precio
+  const { data: rentabilidad = [] } = useRentabilidadProductos();
+  const aPerdida = rentabilidad.filter((r) => r.aPerdida).length;
 
   // ── Métricas rápidas ──
   const metricas = useMemo(() => {
     const activos = productos.filter((p) => p.activo).length;
     const conStock = productos.filter((p) => p.controlaStock);
-    const stockBajo = conStock.filter(
-      (p) => (p.stockActual ?? 0) <= 5
-    ).length;
+    const stockBajo = conStock.filter(tieneStockBajo).length;
     const sinStock = conStock.filter(
       (p) => (p.stockActual ?? 0) === 0
     ).length;
@@ -239,6 +277,10 @@ export default function VistaCatalogo() {
     });
   };
 
+  const handleToggleAgotado = (producto: ProductoResponse) => {
+    cambiarDisponibilidad.mutate({ id: producto.id, agotado: !producto.agotado });
+  };
+
   const toggleOrden = (criterio: CriterioOrden) => {
     if (criterioOrden === criterio) {
       setDireccionOrden((d) => (d === 'asc' ? 'desc' : 'asc'));
@@ -255,7 +297,7 @@ export default function VistaCatalogo() {
 
   const renderProductoCard = (producto: ProductoResponse) => {
     const stockVal = producto.stockActual ?? 0;
-    const stockBajo = producto.controlaStock && stockVal <= 5;
+    const stockBajo = tieneStockBajo(producto);
     const stockMedio = producto.controlaStock && stockVal > 5 && stockVal <= 15;
     const tienePromos = producto.promocionesActivas && producto.promocionesActivas.length > 0;
 
@@ -309,6 +351,17 @@ export default function VistaCatalogo() {
             <h3 className="text-sm font-bold text-text-primary leading-tight line-clamp-2">
               {producto.nombre}
             </h3>
+            <button
+              onClick={() => handleToggleAgotado(producto)}
+              className={[
+                'shrink-0 ml-auto p-0.5 flex items-center gap-0.5 text-[10px] font-bold',
+                producto.agotado ? 'text-red-400' : 'text-gray-600 hover:text-gray-400',
+              ].join(' ')}
+              title={producto.agotado ? 'Volver a ofrecer' : 'Marcar sin stock (86)'}
+            >
+              <Ban size={14} />
+              86
+            </button>
             <button
               onClick={() => handleToggleActivo(producto)}
               className="shrink-0 p-0.5"
@@ -402,7 +455,7 @@ export default function VistaCatalogo() {
 
   const renderProductoFila = (producto: ProductoResponse) => {
     const stockVal = producto.stockActual ?? 0;
-    const stockBajo = producto.controlaStock && stockVal <= 5;
+    const stockBajo = tieneStockBajo(producto);
     const stockMedio = producto.controlaStock && stockVal > 5 && stockVal <= 15;
     const tienePromos = producto.promocionesActivas && producto.promocionesActivas.length > 0;
 
@@ -483,6 +536,19 @@ export default function VistaCatalogo() {
           )}
         </div>
 
+        {/* 86 */}
+        <button
+          onClick={() => handleToggleAgotado(producto)}
+          className={[
+            'shrink-0 flex items-center gap-0.5 text-[10px] font-bold',
+            producto.agotado ? 'text-red-400' : 'text-gray-600 hover:text-gray-400',
+          ].join(' ')}
+          title={producto.agotado ? 'Volver a ofrecer' : 'Marcar sin stock (86)'}
+        >
+          <Ban size={14} />
+          86
+        </button>
+
         {/* Toggle */}
         <button
           onClick={() => handleToggleActivo(producto)}
@@ -546,6 +612,31 @@ export default function VistaCatalogo() {
               </span>
             )}
           </p>
+          <button
+            onClick={() => setShowParaReponer(true)}
+            className="ml-auto flex items-center gap-2 px-3 py-1.5 rounded-lg text-xs font-medium text-red-300 border border-red-500/30 hover:bg-red-500/10 transition-colors"
+          >
+            <ShoppingCart size={14} />
+            <span>Para reponer</span>
+          </button>
+        </div>
+      )}
+
+      {/* ── Alerta de productos a pérdida (HU-118) ── */}
+      {aPerdida > 0 && (
+        <div className="flex items-center gap-3 p-3 bg-amber-500/5 border border-amber-500/20 rounded-xl">
+          <AlertTriangle size={18} className="text-amber-400 shrink-0" />
+          <p className="text-sm text-amber-400/90">
+            <strong>{aPerdida} producto{aPerdida !== 1 ? 's' : ''}</strong> se vende{aPerdida !== 1 ? 'n' : ''} a
+            pérdida según el costo de su receta
+          </p>
+          <button
+            onClick={() => setShowCosteo(true)}
+            className="ml-auto flex items-center gap-2 px-3 py-1.5 rounded-lg text-xs font-medium text-amber-300 border border-amber-500/30 hover:bg-amber-500/10 transition-colors"
+          >
+            <Calculator size={14} />
+            <span>Ver costeo</span>
+          </button>
         </div>
       )}
 
@@ -575,6 +666,38 @@ export default function VistaCatalogo() {
           <Palette size={18} />
           <span className="hidden sm:inline">Categorías</span>
         </button>
+        <button
+          onClick={() => setShowListasPrecios(true)}
+          className="btn-secondary flex items-center justify-center gap-2 px-4 whitespace-nowrap"
+          title="Listas de precios por canal"
+        >
+          <Tags size={18} />
+          <span className="hidden sm:inline">Listas de precios</span>
+        </button>
+        <button
+          onClick={() => setShowAumentos(true)}
+          className="btn-secondary flex items-center justify-center gap-2 px-4 whitespace-nowrap"
+          title="Aumento masivo de precios"
+        >
+          <TrendingUp size={18} />
+          <span className="hidden sm:inline">Aumentos</span>
+        </button>
+        <button
+          onClick={() => setShowHistorialPrecios(true)}
+          className="btn-secondary flex items-center justify-center gap-2 px-4 whitespace-nowrap"
+          title="Historial de precios y variación mensual del menú"
+        >
+          <History size={18} />
+          <span className="hidden sm:inline">Historial</span>
+        </button>
+        <button
+          onClick={() => setShowCosteo(true)}
+          className="btn-secondary flex items-center justify-center gap-2 px-4 whitespace-nowrap"
+          title="Costo de recetas y margen por producto"
+        >
+          <Calculator size={18} />
+          <span className="hidden sm:inline">Costeo</span>
+        </button>
         <button
           onClick={() => setProductoModal('nuevo')}
           className="btn-primary flex items-center justify-center gap-2 px-6 whitespace-nowrap"
@@ -825,6 +948,26 @@ export default function VistaCatalogo() {
         <CategoriasModal onClose={() => setShowCategoriasModal(false)} />
       )}
 
+      {showListasPrecios && (
+        <ListasPreciosModal onClose={() => setShowListasPrecios(false)} />
+      )}
+
+      {showParaReponer && (
+        <ParaReponerModal onClose={() => setShowParaReponer(false)} />
+      )}
+
+      {showCosteo && (
+        <CosteoModal onClose={() => setShowCosteo(false)} />
+      )}
+
+      {showAumentos && (
+        <AumentosPreciosModal onClose={() => setShowAumentos(false)} />
+      )}
+
+      {showHistorialPrecios && (
+        <HistorialPreciosModal onClose={() => setShowHistorialPrecios(false)} />
+      )}
+
       {variantesModal && (
         <VariantesProductoModal
           producto={variantesModal}
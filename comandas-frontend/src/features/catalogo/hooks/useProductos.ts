@@ -1,6 +1,26 @@
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
 import { productosApi } from '../api/productosApi';
-import type { ProductoResponse, ProductoRequest, VarianteRequest } from '../types';
+import type {
+  ActualizacionInsumoResponse,
+  AumentoPreciosRequest,
+  AumentoPreciosResponse,
+  FotoProductoRequest,
+  HistorialPreciosProductoResponse,
+  InsumoRequest,
+  InsumoResponse,
+  ListaPreciosRequest,
+  ListaPreciosResponse,
+  ProductoResponse,
+  ProductoRequest,
+  ProductoParaReponerResponse,
+  RecetaRequest,
+  RecetaResponse,
+  RentabilidadProducto,
+  VarianteRequest,
+  VariacionPreciosMenuResponse,
+  VariantesEnLoteRequest,
+} from '../types';
+import type { CanalVenta } from '../../salon/types';
 
 /**
  * Lista productos filtrados por categoriaId.
@@ -128,6 +148,7 @@ export function useEditarProducto() {
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: ['productos'], exact: false });
       queryClient.invalidateQueries({ queryKey: ['producto'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['historial-precios'] });
     },
     onError: (error: Error) => {
       console.error('[useEditarProducto] Error al editar producto:', error);
@@ -173,6 +194,44 @@ export function useAjustarStock() {
   });
 }
 
+/**
+ * HU-125: Switch rápido de "sin stock" (86).
+ * Las otras terminales se enteran por el canal en tiempo real; acá solo se
+ * refresca la terminal que hizo el cambio.
+ */
+export function useCambiarDisponibilidad() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ id, agotado }: { id: string; agotado: boolean }) =>
+      productosApi.cambiarDisponibilidad(id, agotado),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['productos'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['producto'], exact: false });
+    },
+    onError: (error: Error) => {
+      console.error('[useCambiarDisponibilidad] Error al cambiar disponibilidad:', error);
+    },
+  });
+}
+
+/**
+ * HU-101: Listado de productos bajo stock mínimo.
+ * queryKey bajo el prefijo ['productos'] para que ajustes de stock y ediciones
+ * lo invaliden automáticamente. Polling cada 60s para captar ventas cerradas
+ * desde otras pantallas.
+ */
+export function useProductosParaReponer() {
+  return useQuery<ProductoParaReponerResponse[]>({
+    queryKey: ['productos', 'para-reponer'],
+    queryFn: async () => {
+      const { data } = await productosApi.listarParaReponer();
+      return data;
+    },
+    refetchInterval: 60_000,
+  });
+}
+
 // ─── Variantes ────────────────────────────────────────────────────────────────
 
 /**
@@ -211,3 +270,296 @@ export function useCrearVariante() {
     },
   });
 }
+
+/**
+ * HU-109: Crear varios tamaños de un producto base en una sola llamada.
+ * Invalida las mismas queries que useCrearVariante.
+ */
+export function useCrearVariantesEnLote() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ productoBaseId, ...data }: { productoBaseId: string } & VariantesEnLoteRequest) =>
+      productosApi.crearVariantesEnLote(productoBaseId, data),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['productos'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['variantes'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['producto'], exact: false });
+    },
+    onError: (error: Error) => {
+      console.error('[useCrearVariantesEnLote] Error al crear tamaños:', error);
+    },
+  });
+}
+
+// ─── Listas de precios (HU-108) ──────────────────────────────────────────────
+
+/**
+ * Listas de precios del local, una por canal.
+ * Sin polling: solo se consulta desde el modal de configuración.
+ */
+export function useListasPrecios() {
+  return useQuery<ListaPreciosResponse[]>({
+    queryKey: ['listas-precios'],
+    queryFn: async () => {
+      const { data } = await productosApi.listarListasPrecios();
+      return data;
+    },
+  });
+}
+
+/**
+ * Guardar la lista de precios de un canal.
+ * Los pedidos ya abiertos toman los valores nuevos en los próximos ítems.
+ */
+export function useGuardarListaPrecios() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ canal, ...data }: { canal: CanalVenta } & ListaPreciosRequest) =>
+      productosApi.guardarListaPrecios(canal, data),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['listas-precios'] });
+    },
+    onError: (error: Error) => {
+      console.error('[useGuardarListaPrecios] Error al guardar lista de precios:', error);
+    },
+  });
+}
+
+// ─── Costeo de recetas (HU-118) ──────────────────────────────────────────────
+
+/**
+ * Insumos del local con su costo unitario.
+ * Sin polling: solo se editan desde el modal de costeo.
+ */
+export function useInsumos() {
+  return useQuery<InsumoResponse[]>({
+    queryKey: ['insumos'],
+    queryFn: async () => {
+      const { data } = await productosApi.listarInsumos();
+      return data;
+    },
+  });
+}
+
+export function useCrearInsumo() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (data: InsumoRequest) => productosApi.crearInsumo(data),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['insumos'] });
+    },
+    onError: (error: Error) => {
+      console.error('[useCrearInsumo] Error al crear insumo:', error);
+    },
+  });
+}
+
+/**
+ * Editar un insumo cambia el costo de todas las recetas que lo usan,
+ * así que refresca también recetas y rentabilidad.
+ */
+export function useEditarInsumo() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: async ({ id, ...data }: { id: string } & InsumoRequest): Promise<ActualizacionInsumoResponse> => {
+      const response = await productosApi.editarInsumo(id, data);
+      return response.data;
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['insumos'] });
+      queryClient.invalidateQueries({ queryKey: ['receta'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['productos', 'rentabilidad'] });
+    },
+    onError: (error: Error) => {
+      console.error('[useEditarInsumo] Error al editar insumo:', error);
+    },
+  });
+}
+
+/**
+ * Receta de un producto; null si todavía no tiene.
+ * queryKey: ['receta', productoId]
+ */
+export function useReceta(productoId?: string) {
+  return useQuery<RecetaResponse | null>({
+    queryKey: ['receta', productoId],
+    queryFn: async () => {
+      const response = await productosApi.consultarReceta(productoId!);
+      return response.status === 204 || !response.data ? null : response.data;
+    },
+    enabled: !!productoId,
+  });
+}
+
+export function useGuardarReceta() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ productoId, ...data }: { productoId: string } & RecetaRequest) =>
+      productosApi.guardarReceta(productoId, data),
+    onSuccess: (_, { productoId }) => {
+      queryClient.invalidateQueries({ queryKey: ['receta', productoId] });
+      queryClient.invalidateQueries({ queryKey: ['productos', 'rentabilidad'] });
+    },
+    onError: (error: Error) => {
+      console.error('[useGuardarReceta] Error al guardar receta:', error);
+    },
+  });
+}
+
+export function useEliminarReceta() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (productoId: string) => productosApi.eliminarReceta(productoId),
+    onSuccess: (_, productoId) => {
+      queryClient.invalidateQueries({ queryKey: ['receta', productoId] });
+      queryClient.invalidateQueries({ queryKey: ['productos', 'rentabilidad'] });
+    },
+    onError: (error: Error) => {
+      console.error('[useEliminarReceta] Error al quitar receta:', error);
+    },
+  });
+}
+
+/**
+ * Margen teórico de los productos con receta (primero los de menor margen).
+ * Bajo el prefijo ['productos'] para que un cambio de precio lo recalcule.
+ */
+export function useRentabilidadProductos() {
+  return useQuery<RentabilidadProducto[]>({
+    queryKey: ['productos', 'rentabilidad'],
+    queryFn: async () => {
+      const { data } = await productosApi.listarRentabilidad();
+      return data;
+    },
+  });
+}
+
+/**
+ * HU-127: Foto del producto para la carta digital.
+ * No hay query que invalidar: la carta la pide con la versión nueva en su próxima vuelta.
+ */
+export function useSubirFotoProducto() {
+  return useMutation({
+    mutationFn: ({ productoId, ...data }: { productoId: string } & FotoProductoRequest) =>
+      productosApi.subirFoto(productoId, data),
+    onError: (error: Error) => {
+      console.error('[useSubirFotoProducto] Error al subir foto:', error);
+    },
+  });
+}
+
+export function useEliminarFotoProducto() {
+  return useMutation({
+    mutationFn: (productoId: string) => productosApi.eliminarFoto(productoId),
+    onError: (error: Error) => {
+      console.error('[useEliminarFotoProducto] Error al quitar foto:', error);
+    },
+  });
+}
+
+// ─── Aumentos masivos de precios (HU-145) ────────────────────────────────────
+
+/**
+ * Aumentos programados, aplicados y cancelados del local.
+ * Polling cada 60s: los programados los aplica el backend al llegar la vigencia.
+ */
+export function useAumentosPrecios() {
+  return useQuery<AumentoPreciosResponse[]>({
+    queryKey: ['aumentos-precios'],
+    queryFn: async () => {
+      const { data } = await productosApi.listarAumentosPrecios();
+      return data;
+    },
+    refetchInterval: 60_000,
+  });
+}
+
+/**
+ * Vista previa: es una mutation porque se pide a demanda con el formulario,
+ * no se cachea.
+ */
+export function useVistaPreviaAumentoPrecios() {
+  return useMutation({
+    mutationFn: async (data: AumentoPreciosRequest) => {
+      const { data: vista } = await productosApi.vistaPreviaAumentoPrecios(data);
+      return vista;
+    },
+    onError: (error: Error) => {
+      console.error('[useVistaPreviaAumentoPrecios] Error al calcular vista previa:', error);
+    },
+  });
+}
+
+/**
+ * Confirmar un aumento. Si la vigencia es hoy los precios cambian ya,
+ * por eso se invalidan también los productos.
+ */
+export function useConfirmarAumentoPrecios() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: async (data: AumentoPreciosRequest) => {
+      const { data: aumento } = await productosApi.confirmarAumentoPrecios(data);
+      return aumento;
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['aumentos-precios'] });
+      queryClient.invalidateQueries({ queryKey: ['productos'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['historial-precios'] });
+    },
+    onError: (error: Error) => {
+      console.error('[useConfirmarAumentoPrecios] Error al confirmar aumento:', error);
+    },
+  });
+}
+
+export function useCancelarAumentoPrecios() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (id: string) => productosApi.cancelarAumentoPrecios(id),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['aumentos-precios'] });
+    },
+    onError: (error: Error) => {
+      console.error('[useCancelarAumentoPrecios] Error al cancelar aumento:', error);
+    },
+  });
+}
+
+// ─── Historial de precios (HU-146) ───────────────────────────────────────────
+
+/**
+ * Cambios de precio de un producto.
+ * queryKey: ['historial-precios', 'producto', id]; lo invalidan la edición y los aumentos.
+ */
+export function useHistorialPrecios(productoId: string | null) {
+  return useQuery<HistorialPreciosProductoResponse>({
+    queryKey: ['historial-precios', 'producto', productoId],
+    queryFn: async () => {
+      const { data } = await productosApi.historialPrecios(productoId!);
+      return data;
+    },
+    enabled: !!productoId,
+  });
+}
+
+/**
+ * Variación promedio del menú mes a mes, entre dos meses YYYY-MM inclusive.
+ */
+export function useVariacionPreciosMenu(desde: string, hasta: string) {
+  return useQuery<VariacionPreciosMenuResponse>({
+    queryKey: ['historial-precios', 'variacion', desde, hasta],
+    queryFn: async () => {
+      const { data } = await productosApi.variacionPreciosMenu(desde, hasta);
+      return data;
+    },
+    enabled: !!desde && !!hasta && desde <= hasta,
+  });
+}
@@ -6,13 +6,62 @@ export {
   useEditarProducto,
   useEliminarProducto,
   useAjustarStock,
+  useProductosParaReponer,
+  useListasPrecios,
+  useGuardarListaPrecios,
+  useInsumos,
+  useCrearInsumo,
+  useEditarInsumo,
+  useReceta,
+  useGuardarReceta,
+  useEliminarReceta,
+  useSubirFotoProducto,
+  useEliminarFotoProducto,
+  useRentabilidadProductos,
+  useAumentosPrecios,
+  useVistaPreviaAumentoPrecios,
+  useConfirmarAumentoPrecios,
+  useCancelarAumentoPrecios,
+  useHistorialPrecios,
+  useVariacionPreciosMenu,
 } from './hooks/useProductos';
 export { default as VistaCatalogo } from './components/VistaCatalogo';
 export { default as ProductoModal } from './components/ProductoModal';
 export { default as AjusteStockModal } from './components/AjusteStockModal';
+export { default as ParaReponerModal } from './components/ParaReponerModal';
+export { default as AlertaStockMinimo } from './components/AlertaStockMinimo';
+export { default as ListasPreciosModal } from './components/ListasPreciosModal';
+export { default as CosteoModal } from './components/CosteoModal';
+export { default as AumentosPreciosModal } from './components/AumentosPreciosModal';
+export { default as HistorialPreciosModal } from './components/HistorialPreciosModal';
 export type {
   ProductoRequest,
   ProductoResponse,
   PromocionActivaInfo,
+  ComponenteCombo,
+  ProductoParaReponerResponse,
   TipoMovimientoStock,
+  ListaPreciosRequest,
+  ListaPreciosResponse,
+  PrecioListaProducto,
+  UnidadInsumo,
+  InsumoRequest,
+  InsumoResponse,
+  RentabilidadProducto,
+  ActualizacionInsumoResponse,
+  RecetaRequest,
+  FotoProductoRequest,
+  FotoProductoResponse,
+  RecetaResponse,
+  IngredienteRecetaResponse,
+  EstadoAumentoPrecios,
+  AumentoPreciosRequest,
+  AumentoPreciosResponse,
+  CambioPrecioResponse,
+  VistaPreviaAumentoPreciosResponse,
+  OrigenCambioPrecio,
+  CambioPrecioProductoResponse,
+  HistorialPreciosProductoResponse,
+  VariacionPreciosMes,
+  VariacionPreciosMenuResponse,
 } from './types';
@@ -2,11 +2,15 @@
  * Tipos de dominio para el módulo Catálogo (Productos + Stock)
  *
  * Refleja los DTOs del backend: ProductoRequest, ProductoResponse,
- * StockAjusteRequestBody, AjustarStockResponse.
+ * StockAjusteRequestBody, AjustarStockResponse, ProductoParaReponerResponse,
+ * ListaPreciosRequest/Response, insumos y recetas (costeo).
  *
  * @see backend: com.agustinpalma.comandas.application.dto
  */
 
+import type { CanalVenta } from '../salon/types';
+import type { FranjaHorariaRequest } from '../categorias/types';
+
 // ─── Enums ────────────────────────────────────────────────────────────────────
 
 /**
@@ -19,6 +23,38 @@ export type TipoMovimientoStock =
   | 'AJUSTE_MANUAL'
   | 'INGRESO_MERCADERIA';
 
+/**
+ * HU-147: Alérgenos que puede contener un producto y que un comensal
+ * puede declarar al cargar su ítem.
+ */
+export type Alergeno =
+  | 'TACC'
+  | 'MANI'
+  | 'FRUTOS_SECOS'
+  | 'LACTOSA'
+  | 'HUEVO'
+  | 'PESCADO'
+  | 'MARISCOS'
+  | 'SOJA'
+  | 'SESAMO';
+
+export const ALERGENO_LABELS: Record<Alergeno, string> = {
+  TACC: 'TACC',
+  MANI: 'Maní',
+  FRUTOS_SECOS: 'Frutos secos',
+  LACTOSA: 'Lactosa',
+  HUEVO: 'Huevo',
+  PESCADO: 'Pescado',
+  MARISCOS: 'Mariscos',
+  SOJA: 'Soja',
+  SESAMO: 'Sésamo',
+};
+
+/** Orden de los alérgenos en los selectores (mismo que el backend) */
+export const ALERGENOS: Alergeno[] = [
+  'TACC', 'MANI', 'FRUTOS_SECOS', 'LACTOSA', 'HUEVO', 'PESCADO', 'MARISCOS', 'SOJA', 'SESAMO',
+];
+
 // ─── Producto ─────────────────────────────────────────────────────────────────
 
 /**
@@ -46,6 +82,30 @@ export interface ProductoRequest {
   requiereConfiguracion?: boolean;
   /** Si true, este extra afecta la composición del producto (ej: medallón extra). Default: false */
   esModificadorEstructural?: boolean;
+  /** HU-101: Umbral de reposición. Si se omite en edición, se preserva el actual */
+  stockMinimo?: number;
+  /**
+   * HU-103: Componentes del combo. Si se omite en edición, se preservan los actuales.
+   * Una lista vacía convierte el combo en producto simple.
+   */
+  componentesCombo?: ComponenteCombo[];
+  /** HU-146: Quién cambia el precio, para el historial. Opcional */
+  responsable?: string;
+  /** HU-147: Si se omite en edición, se preservan los actuales. Lista vacía = sin alérgenos */
+  alergenos?: Alergeno[];
+  /** HU-161: Franja propia del producto. En edición, omitida la preserva; desde y hasta en null la quitan */
+  horario?: FranjaHorariaRequest;
+}
+
+/**
+ * Componente de un combo (ej: "Bebida" con opciones Coca / Agua).
+ * Refleja ComponenteComboRequest y ProductoResponse.ComponenteComboInfo del backend.
+ * Si tiene una única opción, el componente es fijo y el POS no pregunta.
+ */
+export interface ComponenteCombo {
+  nombre: string;
+  /** UUIDs de los productos elegibles para este componente */
+  opcionesIds: string[];
 }
 
 /**
@@ -78,6 +138,12 @@ export interface ProductoResponse {
   stockActual: number | null;
   /** Si el producto tiene control de inventario activo */
   controlaStock: boolean | null;
+  /** HU-101: Umbral de reposición. null si no se configuró alerta */
+  stockMinimo?: number | null;
+  /** HU-101: true si el stock actual alcanzó el stock mínimo */
+  bajoStockMinimo?: boolean;
+  /** HU-125: marcado como 86 (se acabó en el momento). No se puede cargar en pedidos */
+  agotado: boolean;
   /** true si es un extra (huevo, queso, disco de carne, etc.) */
   esExtra: boolean;
   /** UUID de la categoría a la que pertenece. Puede ser null */
@@ -90,12 +156,23 @@ export interface ProductoResponse {
   grupoVarianteId?: string | null;
   /** Cantidad de discos de carne del producto (para ordenar variantes). Puede ser null */
   cantidadDiscosCarne?: number | null;
+  /** HU-109: Rótulo corto de la variante en el POS (ej: "Grande", "1.5 L"). Puede ser null */
+  etiquetaVariante?: string | null;
   /** true si este extra activa normalización de variantes (ej: disco de carne) */
   esModificadorEstructural: boolean;
   /** true si el producto está en la variante máxima y puede recibir disco extra */
   puedeAgregarDiscoExtra: boolean;
   /** Promociones activas que aplican a este producto (puede estar vacía) */
   promocionesActivas: PromocionActivaInfo[];
+  /** HU-103: Componentes si el producto es un combo (vacía si es producto simple) */
+  componentesCombo?: ComponenteCombo[];
+  /** HU-147: Alérgenos del producto (vacía si no se cargaron) */
+  alergenos?: Alergeno[];
+  /** HU-161: Franja propia del producto ("HH:mm:ss"). null = usa la de su categoría */
+  horarioDesde?: string | null;
+  horarioHasta?: string | null;
+  /** HU-161: true si ahora no se ofrece (su franja o la de su categoría no incluye la hora actual) */
+  fueraDeHorario?: boolean;
 }
 
 // ─── Stock ────────────────────────────────────────────────────────────────────
@@ -128,6 +205,20 @@ export interface AjustarStockResponse {
   fecha: string;
 }
 
+/**
+ * Fila del listado "para reponer" (HU-101).
+ * Refleja ProductoParaReponerResponse del backend.
+ */
+export interface ProductoParaReponerResponse {
+  productoId: string;
+  nombre: string;
+  categoriaId: string | null;
+  stockActual: number;
+  stockMinimo: number;
+  /** Unidades necesarias para volver a superar el umbral */
+  cantidadSugerida: number;
+}
+
 // ─── Variantes ────────────────────────────────────────────────────────────────
 
 /**
@@ -136,13 +227,18 @@ export interface AjustarStockResponse {
  *
  * El productoBaseId viaja como path param.
  * El grupoVarianteId se determina automáticamente en el use case.
+ *
+ * HU-109: Con etiqueta, el nombre puede omitirse ("{base} {etiqueta}")
+ * y sin cantidadDiscosCarne la variante va a la siguiente posición.
  */
 export interface VarianteRequest {
-  nombre: string;
+  nombre?: string;
   /** Debe ser > 0 */
   precio: number;
   /** Jerarquía de la variante (1 = simple, 2 = doble, etc.) */
-  cantidadDiscosCarne: number;
+  cantidadDiscosCarne?: number;
+  /** HU-109: Rótulo corto en el POS (máx. 30 caracteres) */
+  etiqueta?: string;
   activo?: boolean;
   colorHex?: string;
   categoriaId?: string;
@@ -161,3 +257,233 @@ export interface VarianteResponse {
   varianteCreada: ProductoResponse;
   variantesDelGrupo: ProductoResponse[];
 }
+
+/**
+ * HU-109: Alta de varios tamaños de una vez.
+ * Refleja VariantesEnLoteRequest del backend.
+ *
+ * Si viene etiquetaBase, el producto base la adopta y se renombra
+ * (ej: "Pizza Muzzarella" → "Pizza Muzzarella Chica").
+ */
+export interface VariantesEnLoteRequest {
+  etiquetaBase?: string;
+  variantes: VarianteRequest[];
+}
+
+// ─── Listas de precios por canal (HU-108) ────────────────────────────────────
+
+/**
+ * Precio especial de un producto dentro de una lista.
+ * Refleja ListaPreciosResponse.PrecioProducto del backend.
+ */
+export interface PrecioListaProducto {
+  productoId: string;
+  nombreProducto: string;
+  precioCatalogo: number;
+  precio: number;
+}
+
+/**
+ * Lista de precios de un canal.
+ * Refleja ListaPreciosResponse del backend: siempre llega una por canal;
+ * configurada = false significa que el canal vende a precio de catálogo.
+ */
+export interface ListaPreciosResponse {
+  id: string | null;
+  canal: CanalVenta;
+  configurada: boolean;
+  nombre: string | null;
+  porcentajeRecargo: number;
+  precios: PrecioListaProducto[];
+}
+
+/**
+ * DTO de entrada para guardar la lista de un canal.
+ * Los precios enviados reemplazan a los anteriores.
+ */
+export interface ListaPreciosRequest {
+  nombre: string;
+  /** 0 a 100 */
+  porcentajeRecargo: number;
+  precios: { productoId: string; precio: number }[];
+}
+
+// ─── Costeo de recetas (HU-118) ──────────────────────────────────────────────
+
+/** El costo del insumo y las cantidades de receta van en la misma unidad */
+export type UnidadInsumo = 'UNIDAD' | 'KILOGRAMO' | 'LITRO';
+
+export const UNIDAD_INSUMO_LABELS: Record<UnidadInsumo, string> = {
+  UNIDAD: 'unidad',
+  KILOGRAMO: 'kg',
+  LITRO: 'litro',
+};
+
+export interface InsumoRequest {
+  nombre: string;
+  unidad: UnidadInsumo;
+  /** Costo por unidad de medida (por kg, por litro o por unidad) */
+  costoUnitario: number;
+}
+
+export interface InsumoResponse extends InsumoRequest {
+  id: string;
+  /** HU-133: se activa con la primera toma de inventario */
+  controlaStock: boolean;
+  /** Stock teórico en la unidad del insumo (las ventas lo descuentan según la receta) */
+  stockActual: number;
+  /** HU-134: null si no se sugiere en las órdenes de compra */
+  stockMinimo: number | null;
+  proveedorHabitualId: string | null;
+  /** Controla stock y llegó al mínimo */
+  bajoMinimo: boolean;
+}
+
+/**
+ * Margen teórico de un producto según su receta.
+ * Refleja RentabilidadProductoResponse del backend.
+ */
+export interface RentabilidadProducto {
+  productoId: string;
+  nombre: string;
+  precio: number;
+  costo: number;
+  margen: number;
+  /** Margen sobre el precio de venta */
+  margenPorcentaje: number;
+  /** El costo de la receta supera al precio */
+  aPerdida: boolean;
+}
+
+/**
+ * Respuesta al editar un insumo: trae los productos que con el nuevo
+ * costo pasaron a venderse a pérdida (para avisar en el momento).
+ */
+export interface ActualizacionInsumoResponse {
+  insumo: InsumoResponse;
+  productosAPerdida: RentabilidadProducto[];
+}
+
+export interface RecetaRequest {
+  /** Cantidades en la unidad del insumo (ej: 0.180 kg) */
+  ingredientes: { insumoId: string; cantidad: number }[];
+}
+
+export interface IngredienteRecetaResponse {
+  insumoId: string;
+  nombreInsumo: string;
+  unidad: UnidadInsumo;
+  cantidad: number;
+  /** cantidad × costo unitario vigente */
+  costo: number;
+}
+
+export interface RecetaResponse {
+  productoId: string;
+  ingredientes: IngredienteRecetaResponse[];
+  rentabilidad: RentabilidadProducto;
+}
+
+// ─── Foto para la carta digital (HU-127) ─────────────────────────────────────
+
+export interface FotoProductoRequest {
+  contentType: string;
+  contenidoBase64: string;
+}
+
+export interface FotoProductoResponse {
+  productoId: string;
+  /** Versión de la foto: va en la URL para que el navegador no muestre la anterior */
+  actualizadaEn: string;
+}
+
+// ─── Aumentos masivos de precios (HU-145) ────────────────────────────────────
+
+export type EstadoAumentoPrecios = 'PROGRAMADO' | 'APLICADO' | 'CANCELADO';
+
+export interface AumentoPreciosRequest {
+  /** null = todo el catálogo */
+  categoriaId: string | null;
+  /** Mayor a 0, hasta 100 */
+  porcentaje: number;
+  /** Redondea para arriba al múltiplo (ej: 50). null = sin redondeo */
+  multiploRedondeo: number | null;
+  /** YYYY-MM-DD. Hoy o sin enviar = se aplica al confirmar */
+  fechaVigencia?: string;
+  /** HU-146: Quién carga el aumento, para el historial de precios. Opcional */
+  responsable?: string;
+}
+
+export interface CambioPrecioResponse {
+  productoId: string;
+  nombreProducto: string;
+  precioAnterior: number;
+  precioNuevo: number;
+}
+
+export interface VistaPreviaAumentoPreciosResponse {
+  cambios: CambioPrecioResponse[];
+  /** YYYY-MM-DD, lunes a viernes */
+  proximoDiaHabil: string;
+}
+
+export interface AumentoPreciosResponse {
+  id: string;
+  categoriaId: string | null;
+  porcentaje: number;
+  multiploRedondeo: number | null;
+  fechaVigencia: string;
+  fechaCreacion: string;
+  responsable: string | null;
+  estado: EstadoAumentoPrecios;
+  fechaAplicacion: string | null;
+  /** Vacío mientras está programado */
+  cambios: CambioPrecioResponse[];
+}
+
+// ─── Historial de precios (HU-146) ───────────────────────────────────────────
+
+export type OrigenCambioPrecio = 'EDICION' | 'AUMENTO_MASIVO';
+
+export interface CambioPrecioProductoResponse {
+  id: string;
+  productoId: string;
+  /** Nombre al momento del cambio */
+  nombreProducto: string;
+  precioAnterior: number;
+  precioNuevo: number;
+  /** null si el precio anterior era cero */
+  variacionPorcentual: number | null;
+  fecha: string;
+  origen: OrigenCambioPrecio;
+  aumentoId: string | null;
+  responsable: string | null;
+}
+
+export interface HistorialPreciosProductoResponse {
+  productoId: string;
+  nombre: string;
+  precioActual: number;
+  /** Del más nuevo al más viejo */
+  cambios: CambioPrecioProductoResponse[];
+}
+
+export interface VariacionPreciosMes {
+  /** YYYY-MM */
+  periodo: string;
+  /** Activos hoy más los que cambiaron en el mes */
+  productosEnMenu: number;
+  productosModificados: number;
+  cambios: number;
+  /** Sobre todo el menú: los que no cambiaron cuentan como 0% */
+  variacionPromedio: number;
+  variacionPromedioModificados: number;
+}
+
+export interface VariacionPreciosMenuResponse {
+  desde: string;
+  hasta: string;
+  meses: VariacionPreciosMes[];
+  /** Variación compuesta del rango, en porcentaje */
+  variacionAcumulada: number;
+}
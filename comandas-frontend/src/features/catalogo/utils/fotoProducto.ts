@@ -0,0 +1,47 @@
+import type { FotoProductoRequest } from '../types';
+
+/** Lado mayor de la foto: alcanza para la carta en el celular y pesa poco por WiFi */
+const LADO_MAXIMO_PX = 800;
+const CALIDAD_JPEG = 0.82;
+
+export const TIPOS_FOTO = 'image/jpeg,image/png,image/webp';
+
+/**
+ * Achica la foto elegida y la convierte a JPEG en Base64 para enviarla en el JSON.
+ * Así una foto sacada con el celular (varios MB) entra holgada en el límite de 1 MB del backend.
+ *
+ * @throws Error si el archivo no es una imagen que el navegador pueda abrir
+ */
+export function prepararFotoProducto(archivo: File): Promise<FotoProductoRequest> {
+  return new Promise((resolve, reject) => {
+    const url = URL.createObjectURL(archivo);
+    const img = new Image();
+
+    img.onload = () => {
+      URL.revokeObjectURL(url);
+      const escala = Math.min(1, LADO_MAXIMO_PX / Math.max(img.width, img.height));
+      const canvas = document.createElement('canvas');
+      canvas.width = Math.round(img.width * escala);
+      canvas.height = Math.round(img.height * escala);
+
+      const ctx = canvas.getContext('2d');
+      if (!ctx) {
+        reject(new Error('No se pudo procesar la foto'));
+        return;
+      }
+      ctx.drawImage(img, 0, 0, canvas.width, canvas.height);
+
+      // toDataURL devuelve "data:image/jpeg;base64,<contenido>"
+      const dataUrl = canvas.toDataURL('image/jpeg', CALIDAD_JPEG);
+      resolve({
+        contentType: 'image/jpeg',
+        contenidoBase64: dataUrl.substring(dataUrl.indexOf(',') + 1),
+      });
+    };
+    img.onerror = () => {
+      URL.revokeObjectURL(url);
+      reject(new Error('El archivo elegido no es una imagen válida'));
+    };
+    img.src = url;
+  });
+}
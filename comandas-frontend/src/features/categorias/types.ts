@@ -32,6 +32,17 @@ export interface CategoriaResponse {
    * Si es null, se muestran los extras genéricos.
    */
   categoriaModificadoresId?: string | null;
+  /**
+   * HU-119: Minutos que tiene cocina antes de que la comanda figure demorada.
+   * null = se usa el umbral por defecto (15').
+   */
+  minutosAlertaCocina?: number | null;
+  /**
+   * HU-161: Franja en que se ofrecen los productos de la categoría ("HH:mm:ss").
+   * null = todo el día. Si el fin es anterior al inicio, cruza la medianoche.
+   */
+  horarioDesde?: string | null;
+  horarioHasta?: string | null;
 }
 
 // ─── Request ──────────────────────────────────────────────────────────────────
@@ -48,4 +59,14 @@ export interface CategoriaRequest {
   orden?: number;
   /** UUID de la categoría de modificadores asociada. Nullable. */
   categoriaModificadoresId?: string | null;
+  /** HU-119: Minutos de alerta en cocina. Al editar, 0 quita el umbral; null no lo toca. */
+  minutosAlertaCocina?: number | null;
+  /** HU-161: Franja horaria. Al editar, desde y hasta en null la quitan; omitida no la toca. */
+  horario?: FranjaHorariaRequest;
+}
+
+/** HU-161: Horario en que se ofrece un producto o categoría ("HH:mm") */
+export interface FranjaHorariaRequest {
+  desde: string | null;
+  hasta: string | null;
 }
@@ -0,0 +1,65 @@
+import type { AxiosResponse } from 'axios';
+import apiClient from '../../../lib/apiClient';
+import type {
+  ClienteRequest,
+  ClienteResponse,
+  HistorialCliente,
+  MovimientoCuentaCorriente,
+  PagoClienteRequest,
+  ProgramaPuntos,
+  PromoCumpleanios,
+  PuntosCliente,
+  ReportePuntos,
+  ResumenCuentaCliente,
+} from '../types';
+
+/**
+ * API client de clientes con cuenta corriente (HU-104).
+ * Consume los endpoints REST de ClienteController del backend.
+ */
+export const clientesApi = {
+  listar: (): Promise<AxiosResponse<ClienteResponse[]>> =>
+    apiClient.get('/clientes'),
+
+  crear: (data: ClienteRequest): Promise<AxiosResponse<ClienteResponse>> =>
+    apiClient.post('/clientes', data),
+
+  editar: (id: string, data: ClienteRequest): Promise<AxiosResponse<ClienteResponse>> =>
+    apiClient.put(`/clientes/${id}`, data),
+
+  registrarPago: (id: string, data: PagoClienteRequest): Promise<AxiosResponse<MovimientoCuentaCorriente>> =>
+    apiClient.post(`/clientes/${id}/pagos`, data),
+
+  /** @param mes período en formato YYYY-MM */
+  obtenerResumen: (id: string, mes: string): Promise<AxiosResponse<ResumenCuentaCliente>> =>
+    apiClient.get(`/clientes/${id}/resumen`, { params: { mes } }),
+
+  /** HU-168: Pedidos cerrados, ticket promedio y productos favoritos */
+  obtenerHistorial: (id: string): Promise<AxiosResponse<HistorialCliente>> =>
+    apiClient.get(`/clientes/${id}/historial`),
+
+  obtenerPromoCumpleanios: (): Promise<AxiosResponse<PromoCumpleanios>> =>
+    apiClient.get('/clientes/promo-cumpleanios'),
+
+  guardarPromoCumpleanios: (data: PromoCumpleanios): Promise<AxiosResponse<PromoCumpleanios>> =>
+    apiClient.put('/clientes/promo-cumpleanios', data),
+};
+
+/**
+ * API client del programa de puntos (HU-105).
+ * Consume los endpoints REST de PuntosController del backend.
+ */
+export const puntosApi = {
+  obtenerPrograma: (): Promise<AxiosResponse<ProgramaPuntos>> =>
+    apiClient.get('/puntos/programa'),
+
+  guardarPrograma: (data: ProgramaPuntos): Promise<AxiosResponse<ProgramaPuntos>> =>
+    apiClient.put('/puntos/programa', data),
+
+  obtenerPuntosCliente: (clienteId: string): Promise<AxiosResponse<PuntosCliente>> =>
+    apiClient.get(`/puntos/clientes/${clienteId}`),
+
+  /** @param desde @param hasta fechas en formato YYYY-MM-DD */
+  obtenerReporte: (desde: string, hasta: string): Promise<AxiosResponse<ReportePuntos>> =>
+    apiClient.get('/puntos/reporte', { params: { desde, hasta } }),
+};
@@ -0,0 +1,218 @@
+import { useState } from 'react';
+import { X, Loader2, BookUser } from 'lucide-react';
+import type { ClienteRequest, ClienteResponse } from '../types';
+
+interface ClienteModalProps {
+  /** Cliente a editar; undefined para alta */
+  cliente?: ClienteResponse;
+  onClose: () => void;
+  onConfirmar: (data: ClienteRequest) => void;
+  isPending: boolean;
+  /** Mensaje de error del backend (ej: nombre duplicado) */
+  error?: string | null;
+}
+
+/**
+ * Alta / edición de cliente con cuenta corriente (HU-104).
+ *
+ * El límite de crédito se puede bajar por debajo de la deuda actual:
+ * el backend lo permite y solo bloquea los cargos siguientes.
+ *
+ * HU-168: La ficha suma fecha de nacimiento (para la promo de cumpleaños),
+ * preferencias y alergias, que se muestran al tomarle el pedido.
+ */
+export default function ClienteModal({ cliente, onClose, onConfirmar, isPending, error }: ClienteModalProps) {
+  const [nombre, setNombre] = useState(cliente?.nombre ?? '');
+  const [telefono, setTelefono] = useState(cliente?.telefono ?? '');
+  const [limite, setLimite] = useState(cliente ? String(cliente.limiteCredito) : '');
+  const [activo, setActivo] = useState(cliente?.activo ?? true);
+  const [fechaNacimiento, setFechaNacimiento] = useState(cliente?.fechaNacimiento ?? '');
+  const [preferencias, setPreferencias] = useState(cliente?.preferencias ?? '');
+  const [alergias, setAlergias] = useState(cliente?.alergias ?? '');
+
+  const limiteNumerico = parseFloat(limite);
+  const esValido = nombre.trim().length > 0 && !isNaN(limiteNumerico) && limiteNumerico >= 0;
+
+  const handleSubmit = (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!esValido) return;
+    onConfirmar({
+      nombre: nombre.trim(),
+      telefono: telefono.trim() || null,
+      limiteCredito: limiteNumerico,
+      activo,
+      fechaNacimiento: fechaNacimiento || null,
+      preferencias: preferencias.trim() || null,
+      alergias: alergias.trim() || null,
+    });
+  };
+
+  const inputClass = [
+    'w-full h-11 px-3',
+    'bg-neutral-800 border-2 border-neutral-700 rounded-xl',
+    'text-base text-gray-200',
+    'placeholder:text-neutral-600',
+    'focus:border-red-600 focus:outline-none',
+    'transition-colors disabled:opacity-50',
+  ].join(' ');
+
+  return (
+    <>
+      <div
+        className="fixed inset-0 z-[60] bg-black/70 backdrop-blur-sm animate-backdrop-in"
+        onClick={onClose}
+        aria-hidden="true"
+      />
+
+      <div className="fixed inset-0 z-[70] flex items-center justify-center p-4">
+        <div
+          className="bg-neutral-900 border-2 border-neutral-700 rounded-2xl shadow-2xl shadow-black/60 w-full max-w-md animate-modal-in"
+          role="dialog"
+          aria-modal="true"
+          aria-labelledby="cliente-modal-title"
+        >
+          <div className="flex items-center justify-between px-6 py-4 border-b border-neutral-800">
+            <div className="flex items-center gap-2.5">
+              <BookUser size={20} className="text-amber-400" />
+              <h2 id="cliente-modal-title" className="text-lg font-bold text-gray-100">
+                {cliente ? 'Editar cliente' : 'Nuevo cliente'}
+              </h2>
+            </div>
+            <button
+              type="button"
+              onClick={onClose}
+              disabled={isPending}
+              className="w-10 h-10 rounded-xl flex items-center justify-center text-gray-400 hover:text-gray-100 hover:bg-neutral-800 transition-colors active:scale-95"
+              aria-label="Cerrar"
+            >
+              <X size={20} />
+            </button>
+          </div>
+
+          <form onSubmit={handleSubmit} className="px-6 py-5 space-y-4">
+            <div className="space-y-1.5">
+              <label htmlFor="cliente-nombre" className="block text-sm font-medium text-gray-400">
+                Nombre
+              </label>
+              <input
+                id="cliente-nombre"
+                value={nombre}
+                onChange={(e) => setNombre(e.target.value)}
+                disabled={isPending}
+                placeholder="Ej: Juan - Oficina 3B"
+                autoFocus
+                className={inputClass}
+              />
+            </div>
+
+            <div className="space-y-1.5">
+              <label htmlFor="cliente-telefono" className="block text-sm font-medium text-gray-400">
+                Teléfono <span className="text-gray-600">(opcional)</span>
+              </label>
+              <input
+                id="cliente-telefono"
+                value={telefono}
+                onChange={(e) => setTelefono(e.target.value)}
+                disabled={isPending}
+                placeholder="Para reclamar la deuda"
+                className={inputClass}
+              />
+            </div>
+
+            <div className="space-y-1.5">
+              <label htmlFor="cliente-limite" className="block text-sm font-medium text-gray-400">
+                Límite de crédito
+              </label>
+              <input
+                id="cliente-limite"
+                type="number"
+                inputMode="decimal"
+                min="0"
+                step="0.01"
+                value={limite}
+                onChange={(e) => setLimite(e.target.value)}
+                disabled={isPending}
+                placeholder="0.00"
+                className={`${inputClass} font-mono text-right`}
+              />
+            </div>
+
+            {/* HU-168: Ficha */}
+            <div className="space-y-1.5">
+              <label htmlFor="cliente-nacimiento" className="block text-sm font-medium text-gray-400">
+                Fecha de nacimiento <span className="text-gray-600">(opcional)</span>
+              </label>
+              <input
+                id="cliente-nacimiento"
+                type="date"
+                value={fechaNacimiento}
+                max={new Date().toISOString().slice(0, 10)}
+                onChange={(e) => setFechaNacimiento(e.target.value)}
+                disabled={isPending}
+                className={inputClass}
+              />
+            </div>
+
+            <div className="space-y-1.5">
+              <label htmlFor="cliente-alergias" className="block text-sm font-medium text-gray-400">
+                Alergias <span className="text-gray-600">(opcional)</span>
+              </label>
+              <input
+                id="cliente-alergias"
+                value={alergias}
+                maxLength={300}
+                onChange={(e) => setAlergias(e.target.value)}
+                disabled={isPending}
+                placeholder="Ej: celíaco, sin maní"
+                className={inputClass}
+              />
+            </div>
+
+            <div className="space-y-1.5">
+              <label htmlFor="cliente-preferencias" className="block text-sm font-medium text-gray-400">
+                Preferencias <span className="text-gray-600">(opcional)</span>
+              </label>
+              <input
+                id="cliente-preferencias"
+                value={preferencias}
+                maxLength={300}
+                onChange={(e) => setPreferencias(e.target.value)}
+                disabled={isPending}
+                placeholder="Ej: mesa junto a la ventana"
+                className={inputClass}
+              />
+            </div>
+
+            {cliente && (
+              <label className="flex items-center gap-2 text-sm text-gray-300 cursor-pointer">
+                <input
+                  type="checkbox"
+                  checked={activo}
+                  onChange={(e) => setActivo(e.target.checked)}
+                  disabled={isPending}
+                  className="accent-red-600"
+                />
+                Puede consumir a cuenta corriente
+              </label>
+            )}
+
+            {error && (
+              <p className="text-sm text-red-400 font-medium" role="alert">
+                {error}
+              </p>
+            )}
+
+            <button
+              type="submit"
+              disabled={isPending || !esValido}
+              className="btn-primary w-full h-12 flex items-center justify-center gap-2 disabled:opacity-40"
+            >
+              {isPending && <Loader2 size={18} className="animate-spin" />}
+              {cliente ? 'Guardar cambios' : 'Crear cliente'}
+            </button>
+          </form>
+        </div>
+      </div>
+    </>
+  );
+}
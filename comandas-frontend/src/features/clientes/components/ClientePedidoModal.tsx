@@ -0,0 +1,129 @@
+import { useState } from 'react';
+import { AlertTriangle, BookUser, Loader2, X } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useVincularCliente } from '../../pedido/hooks/usePedido';
+import type { DetallePedidoResponse } from '../../pedido/types';
+import { useClientes } from '../hooks/useClientes';
+
+interface ClientePedidoModalProps {
+  pedido: DetallePedidoResponse;
+  onClose: () => void;
+}
+
+const inputClass =
+  'min-h-[44px] w-full px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none';
+
+/**
+ * Cliente de la ficha vinculado al pedido (HU-168).
+ *
+ * Sirve para el salón, el take away y el delivery: al elegirlo se ven sus
+ * alergias y preferencias, el pedido suma al historial del cliente y al
+ * cobrar se aplica la promo de cumpleaños si corresponde.
+ */
+export default function ClientePedidoModal({ pedido, onClose }: ClientePedidoModalProps) {
+  const toast = useToast();
+  const vincular = useVincularCliente();
+  const { data: clientes = [], isLoading } = useClientes();
+
+  const [clienteId, setClienteId] = useState<string | null>(pedido.clienteId);
+
+  const opciones = clientes.filter((c) => c.activo || c.id === pedido.clienteId);
+  const seleccionado = opciones.find((c) => c.id === clienteId) ?? null;
+
+  const guardar = (id: string | null) => {
+    vincular.mutate(
+      { pedidoId: pedido.pedidoId, clienteId: id },
+      {
+        onSuccess: () => {
+          toast.success(id ? 'Cliente vinculado' : 'Cliente desvinculado');
+          onClose();
+        },
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo vincular el cliente'),
+      },
+    );
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div className="fixed inset-0 z-[95] bg-black/60 animate-backdrop-in" onClick={onClose} />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[100] flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="bg-neutral-900 border border-gray-800 rounded-xl w-full max-w-md flex flex-col pointer-events-auto animate-modal-in"
+          onClick={(e) => e.stopPropagation()}
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-gray-800">
+            <div className="flex items-center gap-3">
+              <BookUser size={20} className="text-amber-400" />
+              <h2 className="text-lg font-semibold text-text-primary">Cliente del pedido</h2>
+            </div>
+            <button
+              onClick={onClose}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+            >
+              <X size={18} />
+            </button>
+          </div>
+
+          {/* Body */}
+          <div className="px-6 py-5 space-y-4">
+            {isLoading ? (
+              <div className="flex items-center gap-2 text-xs text-gray-500">
+                <Loader2 size={14} className="animate-spin" /> Cargando clientes...
+              </div>
+            ) : (
+              <select
+                value={clienteId ?? ''}
+                onChange={(e) => setClienteId(e.target.value || null)}
+                className={inputClass}
+              >
+                <option value="">Elegir cliente…</option>
+                {opciones.map((c) => (
+                  <option key={c.id} value={c.id}>
+                    {c.nombre}
+                    {c.telefono ? ` · ${c.telefono}` : ''}
+                  </option>
+                ))}
+              </select>
+            )}
+
+            {seleccionado?.alergias && (
+              <p className="flex items-start gap-2 rounded-lg border border-red-700/50 bg-red-950/30 px-3 py-2 text-sm text-red-200">
+                <AlertTriangle size={16} className="mt-0.5 shrink-0 text-red-400" />
+                {seleccionado.alergias}
+              </p>
+            )}
+            {seleccionado?.preferencias && <p className="text-sm text-gray-400">{seleccionado.preferencias}</p>}
+          </div>
+
+          {/* Footer */}
+          <div className="flex justify-end gap-3 px-6 py-4 border-t border-gray-800">
+            {pedido.clienteId && (
+              <button
+                onClick={() => guardar(null)}
+                disabled={vincular.isPending}
+                className="btn-secondary text-sm !min-h-[42px] px-5 mr-auto"
+              >
+                Desvincular
+              </button>
+            )}
+            <button onClick={onClose} className="btn-secondary text-sm !min-h-[42px] px-5">
+              Cancelar
+            </button>
+            <button
+              onClick={() => guardar(clienteId)}
+              disabled={!clienteId || clienteId === pedido.clienteId || vincular.isPending}
+              className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+            >
+              {vincular.isPending && <Loader2 size={16} className="animate-spin" />}
+              Vincular
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
@@ -0,0 +1,297 @@
+import { useState } from 'react';
+import { BookUser, Plus, Pencil, HandCoins, FileDown, Loader2, History, AlertTriangle } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { generarCsv, descargarCsv } from '../../../lib/csv';
+import {
+  useClientes,
+  useCrearCliente,
+  useEditarCliente,
+  useRegistrarPagoCliente,
+  useResumenCuentaCliente,
+} from '../hooks/useClientes';
+import ClienteModal from './ClienteModal';
+import PagoClienteModal from './PagoClienteModal';
+import ProgramaPuntosPanel from './ProgramaPuntosPanel';
+import PromoCumpleaniosPanel from './PromoCumpleaniosPanel';
+import HistorialClienteModal from './HistorialClienteModal';
+import type { ClienteResponse, ResumenCuentaCliente } from '../types';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 0, maximumFractionDigits: 2 });
+}
+
+/** Mes actual en formato YYYY-MM (input type="month") */
+function mesActual(): string {
+  const d = new Date();
+  return `${d.getFullYear()}-${String(d.getMonth() + 1).padStart(2, '0')}`;
+}
+
+function mensajeError(error: unknown, fallback: string): string {
+  return (error as any)?.response?.data?.message || fallback;
+}
+
+/**
+ * Arma el CSV del resumen mensual: saldo anterior, un renglón por
+ * movimiento con saldo acumulado y saldo final.
+ */
+function exportarResumen(resumen: ResumenCuentaCliente) {
+  let saldo = resumen.saldoAnterior;
+  const filas: unknown[][] = [['', 'Saldo anterior', '', '', saldo]];
+
+  for (const m of resumen.movimientos) {
+    const esCargo = m.tipo === 'CARGO';
+    saldo += esCargo ? m.monto : -m.monto;
+    filas.push([
+      new Date(m.fecha).toLocaleString('es-AR'),
+      m.descripcion,
+      esCargo ? m.monto : '',
+      esCargo ? '' : m.monto,
+      saldo,
+    ]);
+  }
+
+  filas.push(['', 'Saldo final', resumen.totalCargos, resumen.totalPagos, resumen.saldoFinal]);
+
+  const nombreArchivo = resumen.cliente.nombre.replace(/[^\p{L}\p{N}]+/gu, '_');
+  descargarCsv(
+    generarCsv(['Fecha', 'Detalle', 'Cargo', 'Pago', 'Saldo'], filas),
+    `cuenta_corriente_${nombreArchivo}_${resumen.periodo}.csv`,
+  );
+}
+
+// ─── Componente ───────────────────────────────────────────────────────────────
+
+/**
+ * Pantalla de clientes con cuenta corriente (HU-104).
+ *
+ * Lista los clientes con límite, deuda y crédito disponible; permite
+ * dar de alta/editar, registrar pagos parciales y exportar el resumen
+ * del mes elegido para reclamar la deuda.
+ *
+ * HU-105: debajo del listado se configura el programa de puntos.
+ *
+ * No confundir con A_CUENTA (consumo interno del personal), que no genera deuda.
+ */
+export default function ClientesPage() {
+  const toast = useToast();
+  const { data: clientes = [], isLoading } = useClientes();
+  const crear = useCrearCliente();
+  const editar = useEditarCliente();
+  const registrarPago = useRegistrarPagoCliente();
+  const resumen = useResumenCuentaCliente();
+
+  const [mes, setMes] = useState(mesActual);
+  const [editando, setEditando] = useState<ClienteResponse | 'nuevo' | null>(null);
+  const [pagando, setPagando] = useState<ClienteResponse | null>(null);
+  const [viendoHistorial, setViendoHistorial] = useState<ClienteResponse | null>(null);
+  const [exportandoId, setExportandoId] = useState<string | null>(null);
+
+  const deudaTotal = clientes.reduce((acc, c) => acc + c.deuda, 0);
+
+  const handleExportar = (cliente: ClienteResponse) => {
+    setExportandoId(cliente.id);
+    resumen.mutate(
+      { id: cliente.id, mes },
+      {
+        onSuccess: (data) => exportarResumen(data),
+        onError: (err) => toast.error(mensajeError(err, 'No se pudo obtener el resumen')),
+        onSettled: () => setExportandoId(null),
+      },
+    );
+  };
+
+  return (
+    <div className="max-w-5xl mx-auto px-4 py-6 space-y-5">
+      {/* ── Encabezado ── */}
+      <div className="flex flex-wrap items-center justify-between gap-3">
+        <div className="flex items-center gap-3">
+          <BookUser size={22} className="text-amber-400" />
+          <div>
+            <h1 className="text-xl font-bold text-gray-100">Cuentas corrientes</h1>
+            <p className="text-xs text-gray-500">
+              Deuda total a cobrar: <span className="font-mono text-gray-300">$ {fmt(deudaTotal)}</span>
+            </p>
+          </div>
+        </div>
+
+        <div className="flex items-center gap-2">
+          <label className="flex items-center gap-2 text-xs text-gray-500">
+            Resumen de
+            <input
+              type="month"
+              value={mes}
+              onChange={(e) => setMes(e.target.value)}
+              className="h-9 px-2 rounded-lg bg-neutral-800 border border-neutral-700 text-sm text-gray-200 focus:outline-none focus:border-red-600"
+            />
+          </label>
+          <button
+            type="button"
+            onClick={() => setEditando('nuevo')}
+            className="btn-primary h-9 px-3 flex items-center gap-1.5 text-sm"
+          >
+            <Plus size={16} />
+            Nuevo cliente
+          </button>
+        </div>
+      </div>
+
+      {/* ── Listado ── */}
+      {isLoading ? (
+        <div className="flex justify-center py-16">
+          <Loader2 size={24} className="animate-spin text-gray-600" />
+        </div>
+      ) : clientes.length === 0 ? (
+        <p className="text-center text-sm text-gray-600 py-16">
+          Todavía no hay clientes con cuenta corriente.
+        </p>
+      ) : (
+        <div className="rounded-2xl border border-neutral-800 overflow-hidden">
+          <table className="w-full text-sm">
+            <thead className="bg-neutral-800/50 text-xs uppercase tracking-wider text-gray-500">
+              <tr>
+                <th className="text-left font-medium px-4 py-3">Cliente</th>
+                <th className="text-right font-medium px-4 py-3">Límite</th>
+                <th className="text-right font-medium px-4 py-3">Deuda</th>
+                <th className="text-right font-medium px-4 py-3">Disponible</th>
+                <th className="px-4 py-3" />
+              </tr>
+            </thead>
+            <tbody className="divide-y divide-neutral-800">
+              {clientes.map((c) => (
+                <tr key={c.id} className={c.activo ? '' : 'opacity-50'}>
+                  <td className="px-4 py-3">
+                    <p className="font-semibold text-gray-200">{c.nombre}</p>
+                    <p className="text-xs text-gray-500">
+                      {c.telefono ?? 'Sin teléfono'}
+                      {!c.activo && ' · inactivo'}
+                    </p>
+                    {c.alergias && (
+                      <p className="flex items-center gap-1 text-xs text-red-400" title="Alergias">
+                        <AlertTriangle size={11} />
+                        {c.alergias}
+                      </p>
+                    )}
+                  </td>
+                  <td className="px-4 py-3 text-right font-mono text-gray-400">$ {fmt(c.limiteCredito)}</td>
+                  <td
+                    className={[
+                      'px-4 py-3 text-right font-mono font-semibold',
+                      c.deuda > 0 ? 'text-amber-400' : 'text-gray-500',
+                    ].join(' ')}
+                  >
+                    $ {fmt(c.deuda)}
+                  </td>
+                  <td className="px-4 py-3 text-right font-mono text-gray-400">$ {fmt(c.creditoDisponible)}</td>
+                  <td className="px-4 py-3">
+                    <div className="flex justify-end gap-1">
+                      <button
+                        type="button"
+                        onClick={() => setViendoHistorial(c)}
+                        className="p-2 rounded-lg text-gray-400 hover:text-gray-100 hover:bg-neutral-800 transition-colors"
+                        title="Historial de consumo"
+                      >
+                        <History size={16} />
+                      </button>
+                      <button
+                        type="button"
+                        onClick={() => setPagando(c)}
+                        disabled={c.deuda <= 0}
+                        className="p-2 rounded-lg text-gray-400 hover:text-emerald-400 hover:bg-neutral-800 disabled:opacity-30 disabled:cursor-not-allowed transition-colors"
+                        title="Registrar pago"
+                      >
+                        <HandCoins size={16} />
+                      </button>
+                      <button
+                        type="button"
+                        onClick={() => handleExportar(c)}
+                        disabled={exportandoId === c.id}
+                        className="p-2 rounded-lg text-gray-400 hover:text-gray-100 hover:bg-neutral-800 disabled:opacity-50 transition-colors"
+                        title="Exportar resumen del mes"
+                      >
+                        {exportandoId === c.id ? <Loader2 size={16} className="animate-spin" /> : <FileDown size={16} />}
+                      </button>
+                      <button
+                        type="button"
+                        onClick={() => setEditando(c)}
+                        className="p-2 rounded-lg text-gray-400 hover:text-gray-100 hover:bg-neutral-800 transition-colors"
+                        title="Editar"
+                      >
+                        <Pencil size={16} />
+                      </button>
+                    </div>
+                  </td>
+                </tr>
+              ))}
+            </tbody>
+          </table>
+        </div>
+      )}
+
+      {/* ── HU-105: Programa de puntos ── */}
+      <ProgramaPuntosPanel />
+
+      {/* ── HU-168: Promo de cumpleaños ── */}
+      <PromoCumpleaniosPanel />
+
+      {/* ── Modales ── */}
+      {editando && (
+        <ClienteModal
+          cliente={editando === 'nuevo' ? undefined : editando}
+          isPending={crear.isPending || editar.isPending}
+          error={
+            (crear.error && mensajeError(crear.error, 'Error al crear el cliente')) ||
+            (editar.error && mensajeError(editar.error, 'Error al editar el cliente')) ||
+            null
+          }
+          onClose={() => {
+            crear.reset();
+            editar.reset();
+            setEditando(null);
+          }}
+          onConfirmar={(data) => {
+            const opciones = {
+              onSuccess: () => {
+                toast.success(editando === 'nuevo' ? 'Cliente creado' : 'Cliente guardado');
+                setEditando(null);
+              },
+            };
+            if (editando === 'nuevo') {
+              crear.mutate(data, opciones);
+            } else {
+              editar.mutate({ id: editando.id, ...data }, opciones);
+            }
+          }}
+        />
+      )}
+
+      {viendoHistorial && (
+        <HistorialClienteModal cliente={viendoHistorial} onClose={() => setViendoHistorial(null)} />
+      )}
+
+      {pagando && (
+        <PagoClienteModal
+          cliente={pagando}
+          isPending={registrarPago.isPending}
+          error={registrarPago.error ? mensajeError(registrarPago.error, 'Error al registrar el pago') : null}
+          onClose={() => {
+            registrarPago.reset();
+            setPagando(null);
+          }}
+          onConfirmar={(data) =>
+            registrarPago.mutate(
+              { id: pagando.id, ...data },
+              {
+                onSuccess: () => {
+                  toast.success(`Pago de $ ${fmt(data.monto)} registrado`);
+                  setPagando(null);
+                },
+              },
+            )
+          }
+        />
+      )}
+    </div>
+  );
+}
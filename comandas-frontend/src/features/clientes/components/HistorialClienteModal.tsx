@@ -0,0 +1,155 @@
+import { X, Loader2, History, Cake, AlertTriangle } from 'lucide-react';
+import { useHistorialCliente } from '../hooks/useClientes';
+import type { ClienteResponse } from '../types';
+
+interface HistorialClienteModalProps {
+  cliente: ClienteResponse;
+  onClose: () => void;
+}
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 0, maximumFractionDigits: 2 });
+}
+
+function fecha(iso: string): string {
+  return new Date(iso).toLocaleDateString('es-AR', { day: '2-digit', month: '2-digit', year: 'numeric' });
+}
+
+/** YYYY-MM-DD sin pasar por UTC (si no, el día se corre en Argentina) */
+function fechaLocal(iso: string): string {
+  const [anio, mes, dia] = iso.split('-');
+  return `${dia}/${mes}/${anio}`;
+}
+
+/**
+ * Historial de consumo de un cliente (HU-168).
+ *
+ * Pedidos cerrados con el cliente vinculado, ticket promedio y los productos
+ * que más pide, junto con sus alergias y preferencias.
+ */
+export default function HistorialClienteModal({ cliente, onClose }: HistorialClienteModalProps) {
+  const { data: historial, isLoading, isError } = useHistorialCliente(cliente.id);
+
+  return (
+    <>
+      <div
+        className="fixed inset-0 z-[60] bg-black/70 backdrop-blur-sm animate-backdrop-in"
+        onClick={onClose}
+        aria-hidden="true"
+      />
+
+      <div className="fixed inset-0 z-[70] flex items-center justify-center p-4">
+        <div
+          className="bg-neutral-900 border-2 border-neutral-700 rounded-2xl shadow-2xl shadow-black/60 w-full max-w-2xl max-h-[85vh] flex flex-col animate-modal-in"
+          role="dialog"
+          aria-modal="true"
+          aria-labelledby="historial-cliente-title"
+        >
+          <div className="flex items-center justify-between px-6 py-4 border-b border-neutral-800">
+            <div className="flex items-center gap-2.5">
+              <History size={20} className="text-amber-400" />
+              <h2 id="historial-cliente-title" className="text-lg font-bold text-gray-100">
+                {cliente.nombre}
+              </h2>
+            </div>
+            <button
+              type="button"
+              onClick={onClose}
+              className="w-10 h-10 rounded-xl flex items-center justify-center text-gray-400 hover:text-gray-100 hover:bg-neutral-800 transition-colors active:scale-95"
+              aria-label="Cerrar"
+            >
+              <X size={20} />
+            </button>
+          </div>
+
+          <div className="px-6 py-5 space-y-4 overflow-y-auto">
+            {isLoading ? (
+              <div className="flex justify-center py-8">
+                <Loader2 size={20} className="animate-spin text-gray-600" />
+              </div>
+            ) : isError || !historial ? (
+              <p className="text-sm text-red-400">No se pudo cargar el historial.</p>
+            ) : (
+              <>
+                {historial.alergias && (
+                  <p className="flex items-start gap-2 rounded-xl border border-red-700/50 bg-red-950/30 px-3 py-2 text-sm text-red-200">
+                    <AlertTriangle size={16} className="mt-0.5 shrink-0 text-red-400" />
+                    {historial.alergias}
+                  </p>
+                )}
+                {historial.preferencias && <p className="text-sm text-gray-400">{historial.preferencias}</p>}
+                {historial.proximoCumpleanios && (
+                  <p className="flex items-center gap-2 text-sm text-pink-300">
+                    <Cake size={16} />
+                    Próximo cumpleaños: {fechaLocal(historial.proximoCumpleanios)}
+                  </p>
+                )}
+
+                <div className="grid grid-cols-3 gap-3 text-center">
+                  <div className="rounded-xl bg-neutral-800/50 py-2">
+                    <p className="text-xs text-gray-500">Pedidos</p>
+                    <p className="font-mono text-lg text-gray-100">{historial.cantidadPedidos}</p>
+                  </div>
+                  <div className="rounded-xl bg-neutral-800/50 py-2">
+                    <p className="text-xs text-gray-500">Ticket promedio</p>
+                    <p className="font-mono text-lg text-gray-100">$ {fmt(historial.ticketPromedio)}</p>
+                  </div>
+                  <div className="rounded-xl bg-neutral-800/50 py-2">
+                    <p className="text-xs text-gray-500">Última visita</p>
+                    <p className="font-mono text-lg text-gray-100">
+                      {historial.ultimaVisita ? fecha(historial.ultimaVisita) : '—'}
+                    </p>
+                  </div>
+                </div>
+
+                {historial.productosFavoritos.length > 0 && (
+                  <div className="space-y-1">
+                    <h3 className="text-xs uppercase tracking-wider text-gray-600">Lo que más pide</h3>
+                    <ul className="text-sm text-gray-300 space-y-0.5">
+                      {historial.productosFavoritos.map((p) => (
+                        <li key={p.nombre} className="flex justify-between">
+                          <span>{p.nombre}</span>
+                          <span className="font-mono text-gray-500">{p.unidades} u.</span>
+                        </li>
+                      ))}
+                    </ul>
+                  </div>
+                )}
+
+                {historial.pedidos.length === 0 ? (
+                  <p className="text-center text-sm text-gray-600 py-4">Todavía no cerró ningún pedido.</p>
+                ) : (
+                  <table className="w-full text-sm">
+                    <thead className="text-xs uppercase tracking-wider text-gray-500">
+                      <tr>
+                        <th className="text-left font-medium py-2">Fecha</th>
+                        <th className="text-left font-medium py-2">Pedido</th>
+                        <th className="text-right font-medium py-2">Total</th>
+                      </tr>
+                    </thead>
+                    <tbody className="divide-y divide-neutral-800">
+                      {historial.pedidos.map((p) => (
+                        <tr key={p.pedidoId}>
+                          <td className="py-2 text-gray-400">{fecha(p.fechaCierre)}</td>
+                          <td className="py-2 text-gray-300">
+                            #{p.numero}
+                            {p.promoCumpleanios > 0 && (
+                              <span className="ml-2 text-xs text-pink-300">
+                                cumpleaños −$ {fmt(p.promoCumpleanios)}
+                              </span>
+                            )}
+                          </td>
+                          <td className="py-2 text-right font-mono text-gray-200">$ {fmt(p.total)}</td>
+                        </tr>
+                      ))}
+                    </tbody>
+                  </table>
+                )}
+              </>
+            )}
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
@@ -0,0 +1,171 @@
+import { useState } from 'react';
+import { X, Loader2, HandCoins } from 'lucide-react';
+import type { MedioPago } from '../../salon/types';
+import type { ClienteResponse, PagoClienteRequest } from '../types';
+
+interface PagoClienteModalProps {
+  cliente: ClienteResponse;
+  onClose: () => void;
+  onConfirmar: (data: PagoClienteRequest) => void;
+  isPending: boolean;
+  error?: string | null;
+}
+
+/** Medios con los que se puede saldar deuda (no A_CUENTA ni CUENTA_CORRIENTE) */
+const MEDIOS_PAGO: { value: MedioPago; label: string }[] = [
+  { value: 'EFECTIVO', label: 'Efectivo' },
+  { value: 'TRANSFERENCIA', label: 'Transferencia' },
+  { value: 'TARJETA', label: 'Tarjeta' },
+  { value: 'QR', label: 'QR' },
+];
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 0, maximumFractionDigits: 2 });
+}
+
+/**
+ * Registro de pago total o parcial de la deuda (HU-104).
+ *
+ * Un pago en efectivo se refleja también como ingreso en la caja del día.
+ */
+export default function PagoClienteModal({ cliente, onClose, onConfirmar, isPending, error }: PagoClienteModalProps) {
+  const [medio, setMedio] = useState<MedioPago>('EFECTIVO');
+  const [monto, setMonto] = useState('');
+  const [descripcion, setDescripcion] = useState('');
+
+  const montoNumerico = parseFloat(monto);
+  const montoValido = !isNaN(montoNumerico) && montoNumerico > 0 && montoNumerico <= cliente.deuda;
+
+  const handleSubmit = (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!montoValido) return;
+    onConfirmar({
+      medio,
+      monto: montoNumerico,
+      descripcion: descripcion.trim() || undefined,
+    });
+  };
+
+  return (
+    <>
+      <div
+        className="fixed inset-0 z-[60] bg-black/70 backdrop-blur-sm animate-backdrop-in"
+        onClick={onClose}
+        aria-hidden="true"
+      />
+
+      <div className="fixed inset-0 z-[70] flex items-center justify-center p-4">
+        <div
+          className="bg-neutral-900 border-2 border-neutral-700 rounded-2xl shadow-2xl shadow-black/60 w-full max-w-md animate-modal-in"
+          role="dialog"
+          aria-modal="true"
+          aria-labelledby="pago-cliente-modal-title"
+        >
+          <div className="flex items-center justify-between px-6 py-4 border-b border-neutral-800">
+            <div className="flex items-center gap-2.5">
+              <HandCoins size={20} className="text-emerald-400" />
+              <div>
+                <h2 id="pago-cliente-modal-title" className="text-lg font-bold text-gray-100">
+                  Registrar pago
+                </h2>
+                <p className="text-xs text-gray-500">
+                  {cliente.nombre} · debe $ {fmt(cliente.deuda)}
+                </p>
+              </div>
+            </div>
+            <button
+              type="button"
+              onClick={onClose}
+              disabled={isPending}
+              className="w-10 h-10 rounded-xl flex items-center justify-center text-gray-400 hover:text-gray-100 hover:bg-neutral-800 transition-colors active:scale-95"
+              aria-label="Cerrar"
+            >
+              <X size={20} />
+            </button>
+          </div>
+
+          <form onSubmit={handleSubmit} className="px-6 py-5 space-y-4">
+            <div className="grid grid-cols-4 gap-2">
+              {MEDIOS_PAGO.map((m) => (
+                <button
+                  key={m.value}
+                  type="button"
+                  onClick={() => setMedio(m.value)}
+                  disabled={isPending}
+                  className={[
+                    'h-10 rounded-lg text-xs font-semibold border transition-colors',
+                    medio === m.value
+                      ? 'border-emerald-500 bg-emerald-950/30 text-emerald-300'
+                      : 'border-neutral-700 bg-neutral-800 text-gray-400 hover:border-neutral-500',
+                  ].join(' ')}
+                >
+                  {m.label}
+                </button>
+              ))}
+            </div>
+
+            <div className="space-y-1.5">
+              <div className="flex items-center justify-between">
+                <label htmlFor="pago-cliente-monto" className="text-sm font-medium text-gray-400">
+                  Monto
+                </label>
+                <button
+                  type="button"
+                  onClick={() => setMonto(cliente.deuda.toFixed(2))}
+                  className="text-xs text-gray-500 hover:text-gray-300 transition-colors"
+                >
+                  Saldar total
+                </button>
+              </div>
+              <input
+                id="pago-cliente-monto"
+                type="number"
+                inputMode="decimal"
+                min="0.01"
+                step="0.01"
+                value={monto}
+                onChange={(e) => setMonto(e.target.value)}
+                disabled={isPending}
+                placeholder="0.00"
+                autoFocus
+                className="w-full h-14 px-4 bg-neutral-800 border-2 border-neutral-700 rounded-xl text-2xl font-bold font-mono text-gray-100 text-right placeholder:text-neutral-600 focus:border-emerald-500 focus:outline-none transition-colors disabled:opacity-50"
+              />
+              {montoNumerico > cliente.deuda && (
+                <p className="text-xs text-red-400">El pago no puede superar la deuda actual.</p>
+              )}
+            </div>
+
+            <input
+              value={descripcion}
+              onChange={(e) => setDescripcion(e.target.value)}
+              disabled={isPending}
+              placeholder="Detalle (opcional), ej: Pago quincena"
+              className="w-full h-11 px-3 bg-neutral-800 border-2 border-neutral-700 rounded-xl text-sm text-gray-200 placeholder:text-neutral-600 focus:border-emerald-500 focus:outline-none transition-colors disabled:opacity-50"
+            />
+
+            {medio === 'EFECTIVO' && (
+              <p className="text-xs text-gray-500">
+                El efectivo se registra también como ingreso en la caja del día.
+              </p>
+            )}
+
+            {error && (
+              <p className="text-sm text-red-400 font-medium" role="alert">
+                {error}
+              </p>
+            )}
+
+            <button
+              type="submit"
+              disabled={isPending || !montoValido}
+              className="w-full h-12 rounded-xl font-semibold flex items-center justify-center gap-2 bg-emerald-600 hover:bg-emerald-500 text-white disabled:bg-neutral-700 disabled:text-gray-500 disabled:cursor-not-allowed transition-colors"
+            >
+              {isPending && <Loader2 size={18} className="animate-spin" />}
+              Confirmar pago
+            </button>
+          </form>
+        </div>
+      </div>
+    </>
+  );
+}
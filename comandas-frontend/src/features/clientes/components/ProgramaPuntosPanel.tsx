@@ -0,0 +1,191 @@
+import { useEffect, useState } from 'react';
+import { Star, Loader2 } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useProgramaPuntos, useGuardarProgramaPuntos, useReportePuntos } from '../hooks/useClientes';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 0, maximumFractionDigits: 2 });
+}
+
+/** Fecha local en formato YYYY-MM-DD (input type="date") */
+function isoLocal(d: Date): string {
+  return `${d.getFullYear()}-${String(d.getMonth() + 1).padStart(2, '0')}-${String(d.getDate()).padStart(2, '0')}`;
+}
+
+function primerDiaDelMes(): string {
+  const d = new Date();
+  return isoLocal(new Date(d.getFullYear(), d.getMonth(), 1));
+}
+
+const inputClass =
+  'h-9 px-2 rounded-lg bg-neutral-800 border border-neutral-700 text-sm text-gray-200 font-mono focus:outline-none focus:border-red-600 disabled:opacity-50';
+
+// ─── Componente ───────────────────────────────────────────────────────────────
+
+/**
+ * Configuración y reporte del programa de puntos (HU-105).
+ *
+ * Los clientes suman puntos sobre lo cobrado al cerrar una mesa y los
+ * canjean como descuento en el mismo cierre; acá se define la equivalencia,
+ * el vencimiento y se consulta cuánto se emitió, canjeó y venció.
+ */
+export default function ProgramaPuntosPanel() {
+  const toast = useToast();
+  const { data: programa, isLoading } = useProgramaPuntos();
+  const guardar = useGuardarProgramaPuntos();
+
+  const [activo, setActivo] = useState(false);
+  const [montoPorPunto, setMontoPorPunto] = useState('');
+  const [valorPunto, setValorPunto] = useState('');
+  const [diasVencimiento, setDiasVencimiento] = useState('');
+
+  const [desde, setDesde] = useState(primerDiaDelMes);
+  const [hasta, setHasta] = useState(() => isoLocal(new Date()));
+  const { data: reporte, isFetching: cargandoReporte } = useReportePuntos(desde, hasta);
+
+  useEffect(() => {
+    if (!programa) return;
+    setActivo(programa.activo);
+    setMontoPorPunto(String(programa.montoPorPunto));
+    setValorPunto(String(programa.valorPunto));
+    setDiasVencimiento(programa.diasVencimiento != null ? String(programa.diasVencimiento) : '');
+  }, [programa]);
+
+  const esValido =
+    parseFloat(montoPorPunto) > 0 &&
+    parseFloat(valorPunto) > 0 &&
+    (diasVencimiento === '' || parseInt(diasVencimiento, 10) > 0);
+
+  const handleGuardar = () => {
+    guardar.mutate(
+      {
+        activo,
+        montoPorPunto: parseFloat(montoPorPunto),
+        valorPunto: parseFloat(valorPunto),
+        diasVencimiento: diasVencimiento === '' ? null : parseInt(diasVencimiento, 10),
+      },
+      {
+        onSuccess: () => toast.success('Programa de puntos guardado'),
+        onError: (err: any) =>
+          toast.error(err?.response?.data?.message || 'No se pudo guardar el programa'),
+      },
+    );
+  };
+
+  if (isLoading) {
+    return (
+      <div className="flex justify-center py-8">
+        <Loader2 size={20} className="animate-spin text-gray-600" />
+      </div>
+    );
+  }
+
+  return (
+    <section className="rounded-2xl border border-neutral-800 p-4 space-y-4">
+      <div className="flex items-center gap-2">
+        <Star size={18} className="text-yellow-400" />
+        <h2 className="text-base font-bold text-gray-100">Programa de puntos</h2>
+      </div>
+
+      {/* ── Configuración ── */}
+      <div className="flex flex-wrap items-end gap-3">
+        <label className="flex items-center gap-2 h-9 text-sm text-gray-300 cursor-pointer">
+          <input
+            type="checkbox"
+            checked={activo}
+            onChange={(e) => setActivo(e.target.checked)}
+            disabled={guardar.isPending}
+            className="accent-red-600"
+          />
+          Activo
+        </label>
+
+        <label className="space-y-1 text-xs text-gray-500">
+          <span className="block">$ por cada punto</span>
+          <input
+            type="number"
+            min="0"
+            step="0.01"
+            value={montoPorPunto}
+            onChange={(e) => setMontoPorPunto(e.target.value)}
+            disabled={guardar.isPending}
+            className={`${inputClass} w-28 text-right`}
+          />
+        </label>
+
+        <label className="space-y-1 text-xs text-gray-500">
+          <span className="block">Valor de 1 punto ($)</span>
+          <input
+            type="number"
+            min="0"
+            step="0.01"
+            value={valorPunto}
+            onChange={(e) => setValorPunto(e.target.value)}
+            disabled={guardar.isPending}
+            className={`${inputClass} w-28 text-right`}
+          />
+        </label>
+
+        <label className="space-y-1 text-xs text-gray-500">
+          <span className="block">Vencen a los (días)</span>
+          <input
+            type="number"
+            min="1"
+            step="1"
+            value={diasVencimiento}
+            onChange={(e) => setDiasVencimiento(e.target.value)}
+            disabled={guardar.isPending}
+            placeholder="No vencen"
+            className={`${inputClass} w-28 text-right`}
+          />
+        </label>
+
+        <button
+          type="button"
+          onClick={handleGuardar}
+          disabled={!esValido || guardar.isPending}
+          className="btn-primary h-9 px-3 flex items-center gap-1.5 text-sm disabled:opacity-40"
+        >
+          {guardar.isPending && <Loader2 size={14} className="animate-spin" />}
+          Guardar
+        </button>
+      </div>
+
+      {/* ── Reporte ── */}
+      <div className="border-t border-neutral-800 pt-4 space-y-3">
+        <div className="flex flex-wrap items-center gap-2 text-xs text-gray-500">
+          Reporte del
+          <input type="date" value={desde} onChange={(e) => setDesde(e.target.value)} className={inputClass} />
+          al
+          <input type="date" value={hasta} onChange={(e) => setHasta(e.target.value)} className={inputClass} />
+          {cargandoReporte && <Loader2 size={14} className="animate-spin" />}
+        </div>
+
+        {reporte && (
+          <div className="grid grid-cols-2 sm:grid-cols-4 gap-3 text-center">
+            <div className="rounded-xl bg-neutral-800/50 py-2">
+              <p className="text-xs text-gray-500">Emitidos</p>
+              <p className="font-mono text-lg text-gray-100">{fmt(reporte.emitidos)}</p>
+            </div>
+            <div className="rounded-xl bg-neutral-800/50 py-2">
+              <p className="text-xs text-gray-500">Canjeados</p>
+              <p className="font-mono text-lg text-gray-100">{fmt(reporte.canjeados)}</p>
+              <p className="text-[11px] text-gray-500">$ {fmt(reporte.montoDescontado)} descontados</p>
+            </div>
+            <div className="rounded-xl bg-neutral-800/50 py-2">
+              <p className="text-xs text-gray-500">Vencidos</p>
+              <p className="font-mono text-lg text-gray-100">{fmt(reporte.vencidos)}</p>
+            </div>
+            <div className="rounded-xl bg-neutral-800/50 py-2">
+              <p className="text-xs text-gray-500">Saldo de clientes hoy</p>
+              <p className="font-mono text-lg text-yellow-400">{fmt(reporte.puntosVigentes)}</p>
+              <p className="text-[11px] text-gray-500">equivale a $ {fmt(reporte.valorPuntosVigentes)}</p>
+            </div>
+          </div>
+        )}
+      </div>
+    </section>
+  );
+}
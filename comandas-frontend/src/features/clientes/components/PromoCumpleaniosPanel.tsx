@@ -0,0 +1,122 @@
+import { useEffect, useState } from 'react';
+import { Cake, Loader2 } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { usePromoCumpleanios, useGuardarPromoCumpleanios } from '../hooks/useClientes';
+
+const inputClass =
+  'h-9 px-2 rounded-lg bg-neutral-800 border border-neutral-700 text-sm text-gray-200 font-mono focus:outline-none focus:border-red-600 disabled:opacity-50';
+
+/**
+ * Configuración de la promo de cumpleaños (HU-168).
+ *
+ * Si está activa, el pedido con cliente vinculado que se cobra en la semana
+ * del cumpleaños (de lunes a domingo) tiene el descuento automático, una
+ * sola vez por cumpleaños.
+ */
+export default function PromoCumpleaniosPanel() {
+  const toast = useToast();
+  const { data: promo, isLoading } = usePromoCumpleanios();
+  const guardar = useGuardarPromoCumpleanios();
+
+  const [activa, setActiva] = useState(false);
+  const [porcentaje, setPorcentaje] = useState('');
+  const [tope, setTope] = useState('');
+
+  useEffect(() => {
+    if (!promo) return;
+    setActiva(promo.activa);
+    setPorcentaje(String(promo.porcentaje));
+    setTope(promo.topeDescuento != null ? String(promo.topeDescuento) : '');
+  }, [promo]);
+
+  const porcentajeNumerico = parseFloat(porcentaje);
+  const esValido =
+    porcentajeNumerico >= 1 && porcentajeNumerico <= 100 && (tope === '' || parseFloat(tope) > 0);
+
+  const handleGuardar = () => {
+    guardar.mutate(
+      {
+        activa,
+        porcentaje: porcentajeNumerico,
+        topeDescuento: tope === '' ? null : parseFloat(tope),
+      },
+      {
+        onSuccess: () => toast.success('Promo de cumpleaños guardada'),
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo guardar la promo'),
+      },
+    );
+  };
+
+  if (isLoading) {
+    return (
+      <div className="flex justify-center py-8">
+        <Loader2 size={20} className="animate-spin text-gray-600" />
+      </div>
+    );
+  }
+
+  return (
+    <section className="rounded-2xl border border-neutral-800 p-4 space-y-4">
+      <div className="flex items-center gap-2">
+        <Cake size={18} className="text-pink-400" />
+        <h2 className="text-base font-bold text-gray-100">Promo de cumpleaños</h2>
+      </div>
+
+      <div className="flex flex-wrap items-end gap-3">
+        <label className="flex items-center gap-2 h-9 text-sm text-gray-300 cursor-pointer">
+          <input
+            type="checkbox"
+            checked={activa}
+            onChange={(e) => setActiva(e.target.checked)}
+            disabled={guardar.isPending}
+            className="accent-red-600"
+          />
+          Activa
+        </label>
+
+        <label className="space-y-1 text-xs text-gray-500">
+          <span className="block">Descuento (%)</span>
+          <input
+            type="number"
+            min="1"
+            max="100"
+            step="0.5"
+            value={porcentaje}
+            onChange={(e) => setPorcentaje(e.target.value)}
+            disabled={guardar.isPending}
+            className={`${inputClass} w-24 text-right`}
+          />
+        </label>
+
+        <label className="space-y-1 text-xs text-gray-500">
+          <span className="block">Tope ($)</span>
+          <input
+            type="number"
+            min="0"
+            step="0.01"
+            value={tope}
+            onChange={(e) => setTope(e.target.value)}
+            disabled={guardar.isPending}
+            placeholder="Sin tope"
+            className={`${inputClass} w-28 text-right`}
+          />
+        </label>
+
+        <button
+          type="button"
+          onClick={handleGuardar}
+          disabled={!esValido || guardar.isPending}
+          className="btn-primary h-9 px-3 flex items-center gap-1.5 text-sm disabled:opacity-40"
+        >
+          {guardar.isPending && <Loader2 size={14} className="animate-spin" />}
+          Guardar
+        </button>
+      </div>
+
+      <p className="text-xs text-gray-500">
+        Se aplica sola al cobrar un pedido con cliente vinculado en la semana de su cumpleaños (de lunes a
+        domingo), una vez por cumpleaños. Los clientes sin fecha de nacimiento no la tienen.
+      </p>
+    </section>
+  );
+}
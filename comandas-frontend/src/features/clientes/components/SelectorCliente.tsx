@@ -0,0 +1,81 @@
+import { Loader2 } from 'lucide-react';
+import { useClientes } from '../hooks/useClientes';
+
+interface SelectorClienteProps {
+  value: string | null;
+  onChange: (clienteId: string | null) => void;
+  /** Monto que se va a cargar, para advertir si excede el crédito disponible */
+  montoACargar?: number;
+  /** Texto de la opción vacía */
+  placeholder?: string;
+  disabled?: boolean;
+}
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 0, maximumFractionDigits: 2 });
+}
+
+/**
+ * Selector de cliente con cuenta corriente (HU-104).
+ *
+ * Solo ofrece clientes activos y muestra el crédito disponible de cada uno.
+ * La validación real del límite la hace el backend al cerrar; acá solo se
+ * anticipa el rechazo para que el cajero no se entere recién al confirmar.
+ */
+export default function SelectorCliente({
+  value,
+  onChange,
+  montoACargar = 0,
+  placeholder = 'Elegir cliente…',
+  disabled = false,
+}: SelectorClienteProps) {
+  const { data: clientes = [], isLoading } = useClientes();
+  const activos = clientes.filter((c) => c.activo);
+  const seleccionado = activos.find((c) => c.id === value);
+  const excede = !!seleccionado && montoACargar > seleccionado.creditoDisponible;
+
+  if (isLoading) {
+    return (
+      <div className="flex items-center gap-2 h-10 text-xs text-gray-500">
+        <Loader2 size={14} className="animate-spin" />
+        Cargando clientes…
+      </div>
+    );
+  }
+
+  return (
+    <div className="space-y-1.5">
+      <select
+        value={value ?? ''}
+        onChange={(e) => onChange(e.target.value || null)}
+        disabled={disabled}
+        className="
+          w-full h-10 px-2 rounded-lg
+          bg-neutral-800 border border-neutral-700
+          text-sm text-gray-200
+          focus:outline-none focus:border-red-600
+          disabled:opacity-50
+        "
+      >
+        <option value="">{placeholder}</option>
+        {activos.map((c) => (
+          <option key={c.id} value={c.id}>
+            {c.nombre} — disponible $ {fmt(c.creditoDisponible)}
+          </option>
+        ))}
+      </select>
+
+      {activos.length === 0 && (
+        <p className="text-xs text-gray-500">
+          No hay clientes con cuenta corriente. Dalos de alta en la sección Clientes.
+        </p>
+      )}
+
+      {excede && (
+        <p className="text-xs text-red-400">
+          El cargo de $ {fmt(montoACargar)} supera el crédito disponible de {seleccionado.nombre}.
+        </p>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,210 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { clientesApi, puntosApi } from '../api/clientesApi';
+import type {
+  ClienteRequest,
+  ClienteResponse,
+  HistorialCliente,
+  PagoClienteRequest,
+  ProgramaPuntos,
+  PromoCumpleanios,
+  PuntosCliente,
+  ReportePuntos,
+} from '../types';
+
+/**
+ * Lista los clientes del local (ordenados por nombre) con su deuda actual.
+ *
+ * queryKey: ['clientes'] — se invalida también al cerrar una mesa,
+ * porque un cierre a cuenta corriente cambia la deuda.
+ */
+export function useClientes() {
+  return useQuery<ClienteResponse[]>({
+    queryKey: ['clientes'],
+    queryFn: async () => {
+      const { data } = await clientesApi.listar();
+      return data;
+    },
+    staleTime: 30_000,
+  });
+}
+
+/**
+ * Alta de cliente.
+ */
+export function useCrearCliente() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (data: ClienteRequest) => clientesApi.crear(data),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['clientes'], exact: false });
+    },
+    onError: (error: Error) => {
+      console.error('[useCrearCliente] Error al crear cliente:', error);
+    },
+  });
+}
+
+/**
+ * Edición de cliente (nombre, teléfono, límite, activo y ficha).
+ */
+export function useEditarCliente() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ id, ...data }: { id: string } & ClienteRequest) =>
+      clientesApi.editar(id, data),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['clientes'], exact: false });
+    },
+    onError: (error: Error) => {
+      console.error('[useEditarCliente] Error al editar cliente:', error);
+    },
+  });
+}
+
+/**
+ * Registro de un pago de deuda.
+ * Invalida el reporte de caja: un pago en efectivo genera un ingreso.
+ */
+export function useRegistrarPagoCliente() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ id, ...data }: { id: string } & PagoClienteRequest) =>
+      clientesApi.registrarPago(id, data),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['clientes'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['reporte-caja'], exact: false });
+    },
+    onError: (error: Error) => {
+      console.error('[useRegistrarPagoCliente] Error al registrar pago:', error);
+    },
+  });
+}
+
+/**
+ * Resumen mensual bajo demanda (para exportar).
+ * Es una mutación porque se dispara con un click y no se cachea.
+ */
+export function useResumenCuentaCliente() {
+  return useMutation({
+    mutationFn: async ({ id, mes }: { id: string; mes: string }) => {
+      const { data } = await clientesApi.obtenerResumen(id, mes);
+      return data;
+    },
+    onError: (error: Error) => {
+      console.error('[useResumenCuentaCliente] Error al obtener resumen:', error);
+    },
+  });
+}
+
+// ─── HU-105: Programa de puntos ───────────────────────────────────────────────
+
+/**
+ * Configuración del programa de puntos del local.
+ * El backend devuelve un programa inactivo por defecto si nunca se configuró.
+ */
+export function useProgramaPuntos() {
+  return useQuery<ProgramaPuntos>({
+    queryKey: ['programa-puntos'],
+    queryFn: async () => {
+      const { data } = await puntosApi.obtenerPrograma();
+      return data;
+    },
+    staleTime: 5 * 60_000,
+  });
+}
+
+export function useGuardarProgramaPuntos() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (data: ProgramaPuntos) => puntosApi.guardarPrograma(data),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['programa-puntos'] });
+      queryClient.invalidateQueries({ queryKey: ['puntos-cliente'], exact: false });
+    },
+    onError: (error: Error) => {
+      console.error('[useGuardarProgramaPuntos] Error al guardar programa:', error);
+    },
+  });
+}
+
+/**
+ * Saldo de puntos de un cliente.
+ *
+ * queryKey: ['puntos-cliente', clienteId] — se invalida al cerrar una mesa.
+ */
+export function usePuntosCliente(clienteId: string | null) {
+  return useQuery<PuntosCliente>({
+    queryKey: ['puntos-cliente', clienteId],
+    queryFn: async () => {
+      const { data } = await puntosApi.obtenerPuntosCliente(clienteId!);
+      return data;
+    },
+    enabled: !!clienteId,
+  });
+}
+
+/**
+ * Reporte de puntos emitidos/canjeados/vencidos del rango.
+ * Fechas en formato YYYY-MM-DD.
+ */
+export function useReportePuntos(desde: string, hasta: string) {
+  return useQuery<ReportePuntos>({
+    queryKey: ['reporte-puntos', desde, hasta],
+    queryFn: async () => {
+      const { data } = await puntosApi.obtenerReporte(desde, hasta);
+      return data;
+    },
+    enabled: !!desde && !!hasta && desde <= hasta,
+  });
+}
+
+// ─── HU-168: Historial y promo de cumpleaños ──────────────────────────────────
+
+/**
+ * Historial de consumo de un cliente.
+ *
+ * queryKey: ['historial-cliente', clienteId] — se invalida al cerrar una mesa.
+ */
+export function useHistorialCliente(clienteId: string | null) {
+  return useQuery<HistorialCliente>({
+    queryKey: ['historial-cliente', clienteId],
+    queryFn: async () => {
+      const { data } = await clientesApi.obtenerHistorial(clienteId!);
+      return data;
+    },
+    enabled: !!clienteId,
+  });
+}
+
+/**
+ * Configuración de la promo de cumpleaños del local.
+ * El backend devuelve una promo inactiva por defecto si nunca se configuró.
+ */
+export function usePromoCumpleanios() {
+  return useQuery<PromoCumpleanios>({
+    queryKey: ['promo-cumpleanios'],
+    queryFn: async () => {
+      const { data } = await clientesApi.obtenerPromoCumpleanios();
+      return data;
+    },
+    staleTime: 5 * 60_000,
+  });
+}
+
+export function useGuardarPromoCumpleanios() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (data: PromoCumpleanios) => clientesApi.guardarPromoCumpleanios(data),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['promo-cumpleanios'] });
+    },
+    onError: (error: Error) => {
+      console.error('[useGuardarPromoCumpleanios] Error al guardar la promo:', error);
+    },
+  });
+}
@@ -0,0 +1,52 @@
+/**
+ * Módulo Clientes — cuenta corriente de clientes frecuentes (HU-104),
+ * programa de puntos (HU-105) y ficha con promo de cumpleaños (HU-168).
+ *
+ * @example
+ * import { useClientes, SelectorCliente } from '@/features/clientes';
+ */
+
+// Tipos
+export type {
+  ClienteResponse,
+  ClienteRequest,
+  PagoClienteRequest,
+  MovimientoCuentaCorriente,
+  ResumenCuentaCliente,
+  TipoMovimientoCuenta,
+  ProgramaPuntos,
+  PuntosCliente,
+  LotePuntos,
+  ReportePuntos,
+  HistorialCliente,
+  PedidoHistorial,
+  ProductoFavorito,
+  PromoCumpleanios,
+} from './types';
+
+// Hooks
+export {
+  useClientes,
+  useCrearCliente,
+  useEditarCliente,
+  useRegistrarPagoCliente,
+  useResumenCuentaCliente,
+  useProgramaPuntos,
+  useGuardarProgramaPuntos,
+  usePuntosCliente,
+  useReportePuntos,
+  useHistorialCliente,
+  usePromoCumpleanios,
+  useGuardarPromoCumpleanios,
+} from './hooks/useClientes';
+
+// Componentes
+export { default as ClientesPage } from './components/ClientesPage';
+export { default as SelectorCliente } from './components/SelectorCliente';
+export { default as ProgramaPuntosPanel } from './components/ProgramaPuntosPanel';
+export { default as PromoCumpleaniosPanel } from './components/PromoCumpleaniosPanel';
+export { default as HistorialClienteModal } from './components/HistorialClienteModal';
+export { default as ClientePedidoModal } from './components/ClientePedidoModal';
+
+// API
+export { clientesApi, puntosApi } from './api/clientesApi';
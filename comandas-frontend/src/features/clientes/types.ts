@@ -0,0 +1,167 @@
+/**
+ * Tipos del módulo Clientes — cuenta corriente (HU-104), puntos (HU-105)
+ * y ficha con promo de cumpleaños (HU-168).
+ *
+ * Refleja los DTOs del backend: ClienteRequest/Response, PagoClienteRequest,
+ * MovimientoCuentaCorrienteResponse, ResumenCuentaClienteResponse,
+ * ProgramaPuntosRequest/Response, PuntosClienteResponse, ReportePuntosResponse,
+ * HistorialClienteResponse y PromoCumpleaniosRequest/Response.
+ *
+ * @see backend: com.agustinpalma.comandas.application.dto
+ */
+
+import type { MedioPago } from '../salon/types';
+
+/** Cliente frecuente con su estado de deuda */
+export interface ClienteResponse {
+  id: string;
+  nombre: string;
+  telefono: string | null;
+  limiteCredito: number;
+  activo: boolean;
+  /** Saldo deudor actual (Σ cargos - Σ pagos) */
+  deuda: number;
+  /** Límite menos deuda, nunca negativo */
+  creditoDisponible: number;
+  /** HU-168: YYYY-MM-DD (null si no se cargó) */
+  fechaNacimiento: string | null;
+  /** HU-168: Notas para atenderlo (mesa, punto de la carne, etc.) */
+  preferencias: string | null;
+  /** HU-168: Se muestran al tomar el pedido */
+  alergias: string | null;
+}
+
+/** Alta / edición de cliente */
+export interface ClienteRequest {
+  nombre: string;
+  telefono?: string | null;
+  limiteCredito: number;
+  /** En edición, omitir para conservar el estado actual */
+  activo?: boolean;
+  /** HU-168: YYYY-MM-DD */
+  fechaNacimiento?: string | null;
+  preferencias?: string | null;
+  alergias?: string | null;
+}
+
+/** Pago total o parcial de la deuda */
+export interface PagoClienteRequest {
+  /** EFECTIVO suma a la caja del día; no admite A_CUENTA ni CUENTA_CORRIENTE */
+  medio: MedioPago;
+  monto: number;
+  descripcion?: string;
+}
+
+export type TipoMovimientoCuenta = 'CARGO' | 'PAGO';
+
+export interface MovimientoCuentaCorriente {
+  id: string;
+  tipo: TipoMovimientoCuenta;
+  monto: number;
+  fecha: string;
+  descripcion: string;
+  /** Solo en cargos */
+  pedidoId: string | null;
+  /** Solo en pagos */
+  medioPago: MedioPago | null;
+}
+
+/** Resumen mensual para reclamar la deuda */
+export interface ResumenCuentaCliente {
+  cliente: ClienteResponse;
+  /** Formato YYYY-MM */
+  periodo: string;
+  saldoAnterior: number;
+  totalCargos: number;
+  totalPagos: number;
+  saldoFinal: number;
+  movimientos: MovimientoCuentaCorriente[];
+}
+
+// ─── HU-105: Programa de puntos ───────────────────────────────────────────────
+
+/** Configuración del programa de puntos del local */
+export interface ProgramaPuntos {
+  activo: boolean;
+  /** Pesos a gastar para ganar 1 punto */
+  montoPorPunto: number;
+  /** Pesos de descuento que vale 1 punto */
+  valorPunto: number;
+  /** null = los puntos no vencen */
+  diasVencimiento: number | null;
+}
+
+export interface LotePuntos {
+  puntos: number;
+  /** null = no vence */
+  fechaVencimiento: string | null;
+}
+
+/** Saldo de puntos de un cliente */
+export interface PuntosCliente {
+  clienteId: string;
+  disponible: number;
+  /** Descuento en pesos equivalente al saldo disponible */
+  valorDisponible: number;
+  /** Histórico de puntos vencidos sin usar */
+  vencidos: number;
+  lotes: LotePuntos[];
+}
+
+/** Reporte de puntos emitidos, canjeados y vencidos en un rango */
+export interface ReportePuntos {
+  desde: string;
+  hasta: string;
+  emitidos: number;
+  canjeados: number;
+  vencidos: number;
+  montoDescontado: number;
+  /** Saldo total de los clientes hoy */
+  puntosVigentes: number;
+  valorPuntosVigentes: number;
+}
+
+// ─── HU-168: Historial de consumo y promo de cumpleaños ───────────────────────
+
+export interface ProductoFavorito {
+  nombre: string;
+  unidades: number;
+}
+
+export interface PedidoHistorial {
+  pedidoId: string;
+  numero: number;
+  canal: string;
+  /** ISO 8601 datetime */
+  fechaCierre: string;
+  total: number;
+  /** Descuento de cumpleaños del pedido (0 = sin promo) */
+  promoCumpleanios: number;
+}
+
+/** Historial de pedidos cerrados del cliente (más reciente primero) */
+export interface HistorialCliente {
+  clienteId: string;
+  nombre: string;
+  alergias: string | null;
+  preferencias: string | null;
+  cantidadPedidos: number;
+  totalConsumido: number;
+  ticketPromedio: number;
+  /** ISO 8601 datetime (null si nunca cerró un pedido) */
+  ultimaVisita: string | null;
+  /** YYYY-MM-DD (null sin fecha de nacimiento) */
+  proximoCumpleanios: string | null;
+  /** Hasta 5, por unidades */
+  productosFavoritos: ProductoFavorito[];
+  pedidos: PedidoHistorial[];
+}
+
+/** Descuento automático en la semana del cumpleaños del cliente vinculado */
+export interface PromoCumpleanios {
+  activa: boolean;
+  /** Entre 1 y 100 */
+  porcentaje: number;
+  /** Descuento máximo en pesos (null = sin tope) */
+  topeDescuento: number | null;
+}
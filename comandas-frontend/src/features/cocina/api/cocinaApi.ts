@@ -0,0 +1,38 @@
+import apiClient from '../../../lib/apiClient';
+import type { ComandaCocina, EsperaEstimada, ReporteTiemposCocina } from '../types';
+
+/**
+ * API client de la pantalla de cocina y su reporte de tiempos (HU-119).
+ * Consume /api/cocina de CocinaController.
+ */
+export const cocinaApi = {
+  listarPendientes: async (): Promise<ComandaCocina[]> => {
+    const response = await apiClient.get<ComandaCocina[]>('/cocina/comandas');
+    return response.data;
+  },
+
+  marcarLista: async (id: string): Promise<ComandaCocina> => {
+    const response = await apiClient.post<ComandaCocina>(`/cocina/comandas/${id}/lista`);
+    return response.data;
+  },
+
+  /** HU-136: vuelve a preparación la última comanda marcada lista */
+  recuperarUltima: async (): Promise<ComandaCocina> => {
+    const response = await apiClient.post<ComandaCocina>('/cocina/comandas/recuperar');
+    return response.data;
+  },
+
+  /** @param desde,hasta fechas ISO (YYYY-MM-DD), ambas inclusive */
+  obtenerReporte: async (desde: string, hasta: string): Promise<ReporteTiemposCocina> => {
+    const response = await apiClient.get<ReporteTiemposCocina>('/cocina/reporte', {
+      params: { desde, hasta },
+    });
+    return response.data;
+  },
+
+  /** HU-138: espera de un take away que se envía ahora, según la carga de cocina */
+  estimarEspera: async (): Promise<EsperaEstimada> => {
+    const response = await apiClient.get<EsperaEstimada>('/cocina/espera-estimada');
+    return response.data;
+  },
+};
@@ -0,0 +1,374 @@
+import { useEffect, useRef, useState } from 'react';
+import { Link } from 'react-router-dom';
+import { AlertTriangle, BarChart3, Check, ChefHat, Clock, Keyboard, Loader2, ShieldAlert } from 'lucide-react';
+import { ALERGENO_LABELS } from '../../catalogo/types';
+import useToast from '../../../hooks/useToast';
+import { reproducirSonidoAlerta } from '../../salon/utils/sonidoAlerta';
+import { useComandasCocina, useMarcarComandaLista, useRecuperarComanda } from '../hooks/useCocina';
+import type { ComandaCocina } from '../types';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function hora(iso: string): string {
+  return new Date(iso).toLocaleTimeString('es-AR', { hour: '2-digit', minute: '2-digit' });
+}
+
+/** Verde en tiempo, ámbar desde el 80% del umbral, rojo al pasarlo */
+function estiloTiempo(comanda: ComandaCocina) {
+  if (comanda.demorada) {
+    return { tarjeta: 'border-red-600 bg-red-950/40', reloj: 'text-red-400 animate-pulse' };
+  }
+  if (comanda.minutosEnCocina >= comanda.minutosAlerta * 0.8) {
+    return { tarjeta: 'border-amber-600/60 bg-neutral-800/40', reloj: 'text-amber-400' };
+  }
+  return { tarjeta: 'border-neutral-800 bg-neutral-800/40', reloj: 'text-emerald-400' };
+}
+
+// ─── Bump bar / teclado ───────────────────────────────────────────────────────
+
+type AccionTeclado =
+  | { tipo: 'anterior' }
+  | { tipo: 'siguiente' }
+  | { tipo: 'lista' }
+  | { tipo: 'listaPosicion'; posicion: number }
+  | { tipo: 'recuperar' };
+
+/**
+ * Los bump bar se presentan como un teclado: se leen las mismas teclas que
+ * un teclado numérico común (la fila de números y el keypad dan el mismo e.key).
+ */
+const ATAJOS: { teclas: string; accion: string }[] = [
+  { teclas: '← → / − +', accion: 'Elegir comanda' },
+  { teclas: 'Enter', accion: 'Marcar lista la elegida' },
+  { teclas: '1 – 9', accion: 'Marcar lista la de esa posición' },
+  { teclas: '0 / *', accion: 'Recuperar la última lista' },
+];
+
+function accionDeTecla(e: KeyboardEvent): AccionTeclado | null {
+  if (e.repeat || e.ctrlKey || e.altKey || e.metaKey) return null;
+
+  switch (e.key) {
+    case 'ArrowLeft':
+    case 'ArrowUp':
+    case '-':
+      return { tipo: 'anterior' };
+    case 'ArrowRight':
+    case 'ArrowDown':
+    case '+':
+      return { tipo: 'siguiente' };
+    case 'Enter':
+    case ' ':
+      return { tipo: 'lista' };
+    case '0':
+    case '*':
+    case 'Backspace':
+      return { tipo: 'recuperar' };
+    default: {
+      const posicion = Number(e.key);
+      return Number.isInteger(posicion) && posicion >= 1 && posicion <= 9
+        ? { tipo: 'listaPosicion', posicion }
+        : null;
+    }
+  }
+}
+
+/** Un campo de texto enfocado se queda con sus teclas */
+function esCampoEditable(target: EventTarget | null): boolean {
+  return (
+    target instanceof HTMLElement &&
+    (target.isContentEditable || ['INPUT', 'TEXTAREA', 'SELECT'].includes(target.tagName))
+  );
+}
+
+// ─── Tarjeta ──────────────────────────────────────────────────────────────────
+
+interface ComandaCardProps {
+  comanda: ComandaCocina;
+  /** 1-based: la tecla que la marca lista (solo las primeras 9) */
+  posicion: number;
+  seleccionada: boolean;
+  onLista: (comanda: ComandaCocina) => void;
+  marcando: boolean;
+}
+
+function ComandaCard({ comanda, posicion, seleccionada, onLista, marcando }: ComandaCardProps) {
+  const estilo = estiloTiempo(comanda);
+  const conAlergias = comanda.items.some((item) => item.advertencias.length > 0);
+
+  return (
+    <div
+      className={`rounded-2xl border-2 p-4 flex flex-col gap-3 ${estilo.tarjeta} ${
+        seleccionada ? 'ring-4 ring-sky-500/70' : ''
+      }`}
+    >
+      {comanda.urgente && (
+        <div className="-mx-4 -mt-4 rounded-t-xl bg-red-600 px-4 py-1.5 flex items-center gap-2 text-sm font-bold uppercase tracking-widest text-white">
+          <AlertTriangle size={16} />
+          Urgente
+        </div>
+      )}
+
+      <div className="flex items-start justify-between gap-2">
+        <div>
+          <p className="text-lg font-bold text-gray-100">
+            {posicion <= 9 && (
+              <span className="mr-2 inline-flex w-7 h-7 items-center justify-center rounded-lg bg-neutral-700 text-sm font-mono text-gray-300 align-middle">
+                {posicion}
+              </span>
+            )}
+            Mesa {comanda.numeroMesa}
+            {conAlergias && (
+              <ShieldAlert size={18} className="ml-2 inline text-amber-300 align-middle" aria-label="Hay alergias" />
+            )}
+          </p>
+          <p className="text-xs text-gray-500">
+            Pedido #{comanda.numeroPedido} · {hora(comanda.enviadaEn)}
+          </p>
+          {/* HU-138: Take away con horario prometido al cliente */}
+          {comanda.horaPrometida && (
+            <p className={`text-xs font-semibold ${comanda.atrasada ? 'text-red-400' : 'text-amber-300'}`}>
+              Retira {hora(comanda.horaPrometida)}
+              {comanda.atrasada && ' · atrasada'}
+            </p>
+          )}
+        </div>
+        <div className="text-right">
+          <p className={`text-2xl font-bold font-mono tabular-nums ${estilo.reloj}`}>
+            {comanda.minutosEnCocina}'
+          </p>
+          <p className="text-[10px] text-gray-500">de {comanda.minutosAlerta}'</p>
+        </div>
+      </div>
+
+      <ul className="flex-1 space-y-1.5">
+        {comanda.items.map((item, index) => (
+          <li key={index} className="text-sm">
+            <span className="font-mono font-semibold text-gray-100">{item.cantidad}×</span>{' '}
+            <span className={item.urgente ? 'font-semibold text-red-300' : 'text-gray-200'}>{item.nombreProducto}</span>
+            {item.urgente && (
+              <span className="ml-1.5 text-[9px] font-bold uppercase tracking-wider px-1.5 py-0.5 rounded-full bg-red-900/40 text-red-300 border border-red-700/50">
+                Urgente
+              </span>
+            )}
+            {/* HU-147: Alergias del comensal, antes que cualquier otra nota */}
+            {item.advertencias.length > 0 && (
+              <p className="ml-6 mt-0.5 flex items-center gap-1.5 text-xs font-bold uppercase tracking-wide text-amber-300">
+                <ShieldAlert size={14} className="shrink-0" />
+                Alergia: {item.advertencias.map((a) => ALERGENO_LABELS[a]).join(', ')}
+              </p>
+            )}
+            {item.observacion && <p className="ml-6 text-xs italic text-amber-300/80">{item.observacion}</p>}
+          </li>
+        ))}
+      </ul>
+
+      <button
+        type="button"
+        onClick={() => onLista(comanda)}
+        disabled={marcando}
+        className="h-12 rounded-xl bg-emerald-600 hover:bg-emerald-500 text-white font-semibold flex items-center justify-center gap-2 transition-colors disabled:opacity-50"
+      >
+        {marcando ? <Loader2 size={18} className="animate-spin" /> : <Check size={18} />}
+        Lista
+      </button>
+    </div>
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Pantalla de cocina (HU-119).
+ *
+ * Muestra las comandas enviadas que todavía no salieron, de la más vieja a
+ * la más nueva, con el tiempo que llevan en cocina. Las que superan el
+ * umbral de su categoría se resaltan en rojo. Al marcarla lista, la comanda
+ * deja la pantalla y su tiempo entra al reporte.
+ *
+ * HU-136: Se opera también con bump bar o teclado numérico (ver ATAJOS). Los
+ * atajos se escuchan en toda la ventana, sin necesidad de foco en la grilla.
+ *
+ * HU-137: Las comandas urgentes llegan primero (las ordena el backend), con
+ * una franja roja, y cada urgente nueva dispara un aviso sonoro. Una comanda
+ * que ya estaba en pantalla y se marca urgente después también suena.
+ *
+ * HU-138: Los take away muestran el horario de retiro prometido; al pasarlo
+ * la comanda figura atrasada y se avisa una vez.
+ *
+ * HU-147: Los ítems cargados para un comensal con alergias llevan un ícono
+ * de advertencia con los alérgenos, y la tarjeta lo repite junto a la mesa.
+ */
+export default function KdsPage() {
+  const toast = useToast();
+  const { data, isLoading, isError } = useComandasCocina();
+  const comandas = data ?? [];
+  const marcarLista = useMarcarComandaLista();
+  const recuperar = useRecuperarComanda();
+  const [seleccionId, setSeleccionId] = useState<string | null>(null);
+  const [verAtajos, setVerAtajos] = useState(false);
+
+  const demoradas = comandas.filter((c) => c.demorada).length;
+  const cantidadUrgentes = comandas.filter((c) => c.urgente).length;
+  const atrasadas = comandas.filter((c) => c.atrasada).length;
+
+  // HU-137: Suena una vez por comanda urgente, no en cada refresco
+  const avisadas = useRef<Set<string>>(new Set());
+  useEffect(() => {
+    if (!data) return;
+
+    const urgentes = data.filter((c) => c.urgente);
+    const nuevas = urgentes.filter((c) => !avisadas.current.has(c.id));
+    avisadas.current = new Set(urgentes.map((c) => c.id));
+    if (nuevas.length === 0) return;
+
+    reproducirSonidoAlerta();
+    nuevas.forEach((c) => toast.warning(`Urgente: Mesa ${c.numeroMesa}`, 8000));
+  }, [data, toast]);
+
+  // HU-138: Un aviso por comanda que pasa el horario prometido
+  const avisadasAtrasadas = useRef<Set<string>>(new Set());
+  useEffect(() => {
+    if (!data) return;
+
+    const enAtraso = data.filter((c) => c.atrasada);
+    enAtraso
+      .filter((c) => !avisadasAtrasadas.current.has(c.id))
+      .forEach((c) => toast.warning(`Mesa ${c.numeroMesa}: pasó la hora de retiro prometida`, 8000));
+    avisadasAtrasadas.current = new Set(enAtraso.map((c) => c.id));
+  }, [data, toast]);
+
+  // Si la elegida salió de la pantalla, la selección pasa a la primera
+  const indiceSeleccion = Math.max(0, comandas.findIndex((c) => c.id === seleccionId));
+
+  const handleLista = (comanda: ComandaCocina) =>
+    marcarLista.mutate(comanda.id, {
+      onSuccess: (lista) => toast.success(`Mesa ${lista.numeroMesa} lista en ${lista.minutosEnCocina}'`),
+      onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo marcar la comanda'),
+    });
+
+  const handleRecuperar = () =>
+    recuperar.mutate(undefined, {
+      onSuccess: (comanda) => {
+        setSeleccionId(comanda.id);
+        toast.info(`Mesa ${comanda.numeroMesa} volvió a preparación`);
+      },
+      onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo recuperar la comanda'),
+    });
+
+  // El listener se registra una sola vez; lee el estado actual por ref
+  const estado = useRef({ comandas, indiceSeleccion, handleLista, handleRecuperar, ocupado: false });
+  estado.current = {
+    comandas,
+    indiceSeleccion,
+    handleLista,
+    handleRecuperar,
+    ocupado: marcarLista.isPending || recuperar.isPending,
+  };
+
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (esCampoEditable(e.target)) return;
+      const accion = accionDeTecla(e);
+      if (!accion) return;
+      e.preventDefault();
+
+      const actual = estado.current;
+      // Un golpe doble del bump bar no debe marcar dos comandas
+      if (actual.ocupado) return;
+
+      const total = actual.comandas.length;
+      switch (accion.tipo) {
+        case 'anterior':
+          if (total > 0) setSeleccionId(actual.comandas[(actual.indiceSeleccion - 1 + total) % total].id);
+          break;
+        case 'siguiente':
+          if (total > 0) setSeleccionId(actual.comandas[(actual.indiceSeleccion + 1) % total].id);
+          break;
+        case 'lista':
+          if (total > 0) actual.handleLista(actual.comandas[actual.indiceSeleccion]);
+          break;
+        case 'listaPosicion':
+          if (accion.posicion <= total) actual.handleLista(actual.comandas[accion.posicion - 1]);
+          break;
+        case 'recuperar':
+          actual.handleRecuperar();
+          break;
+      }
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, []);
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <div className="w-10 h-10 rounded-xl bg-neutral-800 flex items-center justify-center">
+            <ChefHat size={18} className="text-red-400" />
+          </div>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Cocina</h1>
+            <p className="text-sm text-gray-500">
+              {comandas.length} en preparación
+              {cantidadUrgentes > 0 && <span className="text-red-400 font-medium"> · {cantidadUrgentes} urgentes</span>}
+              {demoradas > 0 && <span className="text-red-400 font-medium"> · {demoradas} demoradas</span>}
+              {atrasadas > 0 && <span className="text-red-400 font-medium"> · {atrasadas} atrasadas</span>}
+            </p>
+          </div>
+          <button
+            type="button"
+            onClick={() => setVerAtajos((v) => !v)}
+            className="ml-auto h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Keyboard size={14} />
+            Teclado
+          </button>
+          <Link
+            to="/cocina/reporte"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <BarChart3 size={14} />
+            Tiempos
+          </Link>
+        </header>
+
+        {verAtajos && (
+          <div className="flex flex-wrap gap-x-6 gap-y-1 rounded-2xl border border-neutral-800/60 bg-neutral-900/50 px-4 py-3 text-xs text-gray-400">
+            {ATAJOS.map((a) => (
+              <span key={a.teclas}>
+                <span className="font-mono text-gray-200">{a.teclas}</span> {a.accion}
+              </span>
+            ))}
+          </div>
+        )}
+
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando comandas...
+          </div>
+        ) : isError ? (
+          <p className="text-sm text-red-400">No se pudieron cargar las comandas.</p>
+        ) : comandas.length === 0 ? (
+          <div className="flex flex-col items-center justify-center py-20 text-gray-500 gap-3">
+            <Clock size={36} className="text-gray-600" />
+            <p className="text-sm">No hay comandas pendientes.</p>
+          </div>
+        ) : (
+          <div className="grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 2xl:grid-cols-4 gap-4">
+            {comandas.map((comanda, index) => (
+              <ComandaCard
+                key={comanda.id}
+                comanda={comanda}
+                posicion={index + 1}
+                seleccionada={index === indiceSeleccion}
+                onLista={handleLista}
+                marcando={marcarLista.isPending && marcarLista.variables === comanda.id}
+              />
+            ))}
+          </div>
+        )}
+      </div>
+    </section>
+  );
+}
@@ -0,0 +1,176 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Calendar, Clock, Download, Loader2 } from 'lucide-react';
+import { generarCsv, descargarCsv } from '../../../lib/csv';
+import { useReporteTiemposCocina } from '../hooks/useCocina';
+import type { ReporteTiemposCocina } from '../types';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmtMinutos(valor: number): string {
+  return `${valor.toLocaleString('es-AR', { minimumFractionDigits: 1, maximumFractionDigits: 1 })}'`;
+}
+
+function franja(hora: number): string {
+  const h = String(hora).padStart(2, '0');
+  return `${h}:00 – ${h}:59`;
+}
+
+function isoLocal(fecha: Date): string {
+  const y = fecha.getFullYear();
+  const m = String(fecha.getMonth() + 1).padStart(2, '0');
+  const d = String(fecha.getDate()).padStart(2, '0');
+  return `${y}-${m}-${d}`;
+}
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+const tarjeta = 'rounded-2xl bg-neutral-800/40 p-5';
+const tituloTarjeta = 'text-xs text-gray-500 uppercase tracking-wider font-medium';
+
+function exportarReporte(reporte: ReporteTiemposCocina) {
+  descargarCsv(
+    generarCsv(
+      ['Franja', 'Comandas', 'Promedio (min)', 'Máximo (min)', 'Demoradas'],
+      reporte.franjas.map((f) => [franja(f.hora), f.cantidadComandas, f.promedioMinutos, f.maximoMinutos, f.demoradas]),
+    ),
+    `tiempos_cocina_${reporte.desde}_${reporte.hasta}.csv`,
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Reporte de tiempos de cocina (HU-119).
+ *
+ * Tiempo promedio desde que la comanda llega a cocina hasta que se marca
+ * lista, por hora del envío, para detectar las franjas en que la cocina no
+ * da abasto. Solo entran las comandas marcadas como listas.
+ */
+export default function ReporteTiemposCocinaPage() {
+  const hoy = new Date();
+  const [desde, setDesde] = useState(isoLocal(new Date(hoy.getFullYear(), hoy.getMonth(), hoy.getDate() - 6)));
+  const [hasta, setHasta] = useState(isoLocal(hoy));
+
+  const { data: reporte, isLoading, isError } = useReporteTiemposCocina(desde, hasta);
+
+  const maximoPromedio = Math.max(1, ...(reporte?.franjas.map((f) => f.promedioMinutos) ?? []));
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/cocina"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Tiempos de cocina</h1>
+            <p className="text-sm text-gray-500">Promedio por franja horaria, del envío a la marca de lista</p>
+          </div>
+        </header>
+
+        <div className="flex items-center gap-3">
+          <Calendar size={14} className="text-gray-500" />
+          <input type="date" value={desde} onChange={(e) => setDesde(e.target.value)} className={inputFecha} />
+          <span className="text-gray-600 text-xs">→</span>
+          <input type="date" value={hasta} onChange={(e) => setHasta(e.target.value)} className={inputFecha} />
+          {reporte && (
+            <button
+              type="button"
+              onClick={() => exportarReporte(reporte)}
+              disabled={reporte.franjas.length === 0}
+              className="ml-auto h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors disabled:opacity-40"
+            >
+              <Download size={14} />
+              Exportar CSV
+            </button>
+          )}
+        </div>
+
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando reporte...
+          </div>
+        ) : isError ? (
+          <p className="text-sm text-red-400">No se pudo cargar el reporte de tiempos.</p>
+        ) : reporte && reporte.cantidadComandas === 0 ? (
+          <div className="flex items-center gap-2 text-xs text-gray-500">
+            <Clock size={14} />
+            No hay comandas despachadas en este rango.
+          </div>
+        ) : reporte && (
+          <>
+            <div className="grid grid-cols-1 sm:grid-cols-3 gap-4">
+              <div className={tarjeta}>
+                <p className={tituloTarjeta}>Comandas</p>
+                <p className="mt-2 text-2xl font-semibold font-mono tabular-nums text-gray-100">
+                  {reporte.cantidadComandas}
+                </p>
+              </div>
+              <div className={tarjeta}>
+                <p className={tituloTarjeta}>Tiempo promedio</p>
+                <p className="mt-2 text-2xl font-semibold font-mono tabular-nums text-gray-100">
+                  {fmtMinutos(reporte.promedioMinutos)}
+                </p>
+              </div>
+              <div className={tarjeta}>
+                <p className={tituloTarjeta}>Demoradas</p>
+                <p
+                  className={`mt-2 text-2xl font-semibold font-mono tabular-nums ${
+                    reporte.demoradas > 0 ? 'text-red-400' : 'text-gray-100'
+                  }`}
+                >
+                  {reporte.demoradas}
+                </p>
+                <p className="mt-1 text-xs text-gray-500">
+                  {Math.round((reporte.demoradas / reporte.cantidadComandas) * 100)}% del total
+                </p>
+              </div>
+            </div>
+
+            <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+              <table className="w-full text-sm">
+                <thead>
+                  <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                    <th className="text-left font-medium px-4 py-2">Franja</th>
+                    <th className="text-right font-medium px-3 py-2">Comandas</th>
+                    <th className="text-left font-medium px-3 py-2 w-1/3">Promedio</th>
+                    <th className="text-right font-medium px-3 py-2">Máximo</th>
+                    <th className="text-right font-medium px-4 py-2">Demoradas</th>
+                  </tr>
+                </thead>
+                <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+                  {reporte.franjas.map((f) => (
+                    <tr key={f.hora}>
+                      <td className="px-4 py-2 text-gray-100">{franja(f.hora)}</td>
+                      <td className="px-3 py-2 text-right">{f.cantidadComandas}</td>
+                      <td className="px-3 py-2">
+                        <div className="flex items-center gap-2">
+                          <div className="flex-1 h-2 rounded-full bg-neutral-800">
+                            <div
+                              className={`h-2 rounded-full ${f.demoradas > 0 ? 'bg-red-500' : 'bg-emerald-500'}`}
+                              style={{ width: `${(f.promedioMinutos / maximoPromedio) * 100}%` }}
+                            />
+                          </div>
+                          <span className="w-14 text-right">{fmtMinutos(f.promedioMinutos)}</span>
+                        </div>
+                      </td>
+                      <td className="px-3 py-2 text-right">{fmtMinutos(f.maximoMinutos)}</td>
+                      <td className={`px-4 py-2 text-right ${f.demoradas > 0 ? 'text-red-400' : ''}`}>
+                        {f.demoradas}
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          </>
+        )}
+      </div>
+    </section>
+  );
+}
@@ -0,0 +1,82 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { cocinaApi } from '../api/cocinaApi';
+import type { ComandaCocina, EsperaEstimada, ReporteTiemposCocina } from '../types';
+
+export const cocinaKeys = {
+  pendientes: ['cocina', 'comandas'] as const,
+  reporte: (desde: string, hasta: string) => ['cocina', 'reporte', desde, hasta] as const,
+  espera: ['cocina', 'espera'] as const,
+};
+
+/**
+ * Comandas en preparación, de la más vieja a la más nueva (HU-137: las urgentes primero).
+ * Polling cada 15s: los minutos y la marca de demora vienen del backend,
+ * así que el refresco es lo que hace avanzar el reloj en pantalla.
+ */
+export function useComandasCocina() {
+  return useQuery<ComandaCocina[]>({
+    queryKey: cocinaKeys.pendientes,
+    queryFn: cocinaApi.listarPendientes,
+    refetchInterval: 15_000,
+  });
+}
+
+export function useMarcarComandaLista() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (id: string) => cocinaApi.marcarLista(id),
+    onSuccess: (_, id) => {
+      queryClient.setQueryData<ComandaCocina[]>(cocinaKeys.pendientes, (prev) =>
+        prev?.filter((c) => c.id !== id)
+      );
+      queryClient.invalidateQueries({ queryKey: ['cocina', 'reporte'], exact: false });
+    },
+    onError: (error: unknown) => {
+      console.error('[useMarcarComandaLista] Error al marcar la comanda:', error);
+    },
+  });
+}
+
+/**
+ * HU-136: Deshace el último "lista" del local. La comanda vuelve a la
+ * pantalla en su lugar por antigüedad, así que se refresca la lista entera.
+ */
+export function useRecuperarComanda() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: () => cocinaApi.recuperarUltima(),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: cocinaKeys.pendientes });
+      queryClient.invalidateQueries({ queryKey: ['cocina', 'reporte'], exact: false });
+    },
+  });
+}
+
+/**
+ * HU-138: Espera estimada para prometer el retiro de un take away.
+ * Se refresca con la cola de cocina; solo se consulta cuando está habilitada.
+ */
+export function useEsperaEstimada(enabled = true) {
+  return useQuery<EsperaEstimada>({
+    queryKey: cocinaKeys.espera,
+    queryFn: cocinaApi.estimarEspera,
+    enabled,
+    refetchInterval: 30_000,
+  });
+}
+
+/**
+ * Tiempos de cocina por franja horaria en un rango de fechas.
+ *
+ * queryKey: ['cocina', 'reporte', desde, hasta]
+ */
+export function useReporteTiemposCocina(desde: string, hasta: string) {
+  return useQuery<ReporteTiemposCocina>({
+    queryKey: cocinaKeys.reporte(desde, hasta),
+    queryFn: () => cocinaApi.obtenerReporte(desde, hasta),
+    enabled: !!desde && !!hasta && desde <= hasta,
+    staleTime: 60_000,
+  });
+}
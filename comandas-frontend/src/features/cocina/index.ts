@@ -0,0 +1,34 @@
+/**
+ * Módulo Cocina — pantalla de comandas en preparación con alertas de
+ * demora y reporte de tiempos por franja horaria (HU-119), operable con
+ * bump bar o teclado numérico (HU-136), y espera estimada para prometer
+ * el retiro de un take away (HU-138).
+ *
+ * @example
+ * import { KdsPage, useComandasCocina } from '@/features/cocina';
+ */
+
+// Tipos
+export type {
+  ComandaCocina,
+  ItemComandaCocina,
+  EsperaEstimada,
+  FranjaHoraria,
+  ReporteTiemposCocina,
+} from './types';
+
+// Hooks
+export {
+  useComandasCocina,
+  useMarcarComandaLista,
+  useRecuperarComanda,
+  useEsperaEstimada,
+  useReporteTiemposCocina,
+} from './hooks/useCocina';
+
+// Componentes
+export { default as KdsPage } from './components/KdsPage';
+export { default as ReporteTiemposCocinaPage } from './components/ReporteTiemposCocinaPage';
+
+// API
+export { cocinaApi } from './api/cocinaApi';
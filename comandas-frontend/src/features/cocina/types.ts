@@ -0,0 +1,71 @@
+import type { Alergeno } from '../catalogo/types';
+
+// ─── Pantalla de cocina (HU-119) ─────────────────────────────────────────────
+
+export interface ItemComandaCocina {
+  nombreProducto: string;
+  /** Unidades que salieron en este envío (no el total del ítem) */
+  cantidad: number;
+  observacion: string | null;
+  /** HU-137: Salió marcado urgente */
+  urgente: boolean;
+  /** HU-147: Alergias declaradas para el comensal (vacía si no hay) */
+  advertencias: Alergeno[];
+}
+
+/** Espejo de ComandaCocinaResponse: la demora la calcula el backend */
+export interface ComandaCocina {
+  id: string;
+  numeroMesa: number;
+  numeroPedido: number;
+  /** ISO 8601 datetime */
+  enviadaEn: string;
+  /** ISO 8601 datetime, null mientras está en preparación */
+  listaEn: string | null;
+  minutosEnCocina: number;
+  /** Umbral fijado al enviar, según las categorías de los ítems */
+  minutosAlerta: number;
+  demorada: boolean;
+  /** HU-137: El pedido o algún ítem es urgente (el backend las ordena primero) */
+  urgente: boolean;
+  /** HU-138: Retiro prometido al cliente (ISO 8601), null si no es take away */
+  horaPrometida: string | null;
+  /** HU-138: Cocina pasó el horario prometido */
+  atrasada: boolean;
+  items: ItemComandaCocina[];
+}
+
+// ─── Espera estimada (HU-138) ────────────────────────────────────────────────
+
+/** Espera de un pedido que se envía ahora a cocina */
+export interface EsperaEstimada {
+  comandasEnCola: number;
+  /** Promedio reciente de preparación de una comanda */
+  minutosPreparacion: number;
+  /** Espera a informar al cliente, redondeada a 5 minutos */
+  minutosEspera: number;
+  /** ISO 8601 datetime */
+  horaEstimada: string;
+}
+
+// ─── Reporte de tiempos ──────────────────────────────────────────────────────
+
+export interface FranjaHoraria {
+  /** 0-23: la franja 20 va de 20:00 a 20:59 */
+  hora: number;
+  cantidadComandas: number;
+  promedioMinutos: number;
+  maximoMinutos: number;
+  demoradas: number;
+}
+
+export interface ReporteTiemposCocina {
+  /** YYYY-MM-DD */
+  desde: string;
+  /** YYYY-MM-DD */
+  hasta: string;
+  cantidadComandas: number;
+  promedioMinutos: number;
+  demoradas: number;
+  franjas: FranjaHoraria[];
+}
@@ -0,0 +1,14 @@
+import apiClient from '../../../lib/apiClient';
+import type { DashboardVentas } from '../types';
+
+/**
+ * API client del tablero en vivo (HU-114).
+ * Consume GET /api/dashboard/ventas de DashboardController.
+ */
+export const dashboardApi = {
+  /** Siempre la jornada operativa en curso */
+  obtenerVentas: async (): Promise<DashboardVentas> => {
+    const response = await apiClient.get<DashboardVentas>('/dashboard/ventas');
+    return response.data;
+  },
+};
@@ -0,0 +1,205 @@
+import { BarChart3, Loader2, TrendingDown, TrendingUp, UtensilsCrossed } from 'lucide-react';
+import { useDashboardVentas } from '../hooks/useDashboard';
+import type { MedioPago } from '../../salon/types';
+import VentasHoraComparadaChart from './VentasHoraComparadaChart';
+import BotonCompartirImagen from '../../caja/components/BotonCompartirImagen';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 0, maximumFractionDigits: 2 });
+}
+
+const MEDIOS: Record<MedioPago, { label: string; color: string }> = {
+  EFECTIVO: { label: 'Efectivo', color: 'bg-emerald-500' },
+  TARJETA: { label: 'Tarjeta', color: 'bg-blue-500' },
+  QR: { label: 'QR', color: 'bg-violet-500' },
+  TRANSFERENCIA: { label: 'Transf.', color: 'bg-cyan-500' },
+  CUENTA_CORRIENTE: { label: 'Cta. Cte.', color: 'bg-amber-500' },
+  TARJETA_REGALO: { label: 'Gift card', color: 'bg-pink-500' },
+  A_CUENTA: { label: 'A cuenta', color: 'bg-gray-500' },
+};
+
+const tarjeta = 'rounded-2xl bg-neutral-800/40 p-5';
+const tituloTarjeta = 'text-xs text-gray-500 uppercase tracking-wider font-medium';
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Tablero de ventas en vivo (HU-114).
+ *
+ * Venta acumulada de la jornada contra el mismo día de la semana pasada
+ * a la misma hora, ventas por hora, top 10 de productos, mezcla de medios
+ * de pago y mesas abiertas. El backend entrega todo agregado; esta
+ * pantalla solo dibuja y se refresca sola.
+ *
+ * HU-157: las ventas del día se pueden compartir como imagen.
+ */
+export default function DashboardPage() {
+  const { data: tablero, isLoading, isError, dataUpdatedAt } = useDashboardVentas();
+
+  const variacion = tablero?.variacionPorcentual ?? null;
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <div className="w-10 h-10 rounded-xl bg-neutral-800 flex items-center justify-center">
+            <BarChart3 size={18} className="text-red-400" />
+          </div>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Tablero del día</h1>
+            <p className="text-sm text-gray-500">
+              {tablero ? `Jornada ${tablero.fechaOperativa.split('-').reverse().join('/')}` : 'Jornada en curso'}
+              {dataUpdatedAt > 0 && (
+                <span className="text-gray-600">
+                  {' · '}actualizado {new Date(dataUpdatedAt).toLocaleTimeString('es-AR', { hour: '2-digit', minute: '2-digit' })}
+                </span>
+              )}
+            </p>
+          </div>
+
+          {/* HU-157: "Ventas de hoy" como imagen para WhatsApp */}
+          {tablero && (
+            <div className="ml-auto">
+              <BotonCompartirImagen vista="VENTAS" desde={tablero.fechaOperativa} />
+            </div>
+          )}
+        </header>
+
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando tablero...
+          </div>
+        ) : isError || !tablero ? (
+          <p className="text-sm text-red-400">No se pudo cargar el tablero.</p>
+        ) : (
+          <>
+            {/* ── KPIs ── */}
+            <div className="grid grid-cols-2 xl:grid-cols-4 gap-4">
+              <div className={tarjeta}>
+                <p className={tituloTarjeta}>Venta acumulada</p>
+                <p className="mt-2 text-3xl font-bold font-mono tabular-nums text-gray-100">
+                  $ {fmt(tablero.ventaAcumulada)}
+                </p>
+                {variacion === null ? (
+                  <p className="mt-1 text-xs text-gray-500">Sin ventas la semana pasada a esta hora</p>
+                ) : (
+                  <p
+                    className={`mt-1 flex items-center gap-1 text-xs font-medium ${
+                      variacion >= 0 ? 'text-emerald-400' : 'text-red-400'
+                    }`}
+                  >
+                    {variacion >= 0 ? <TrendingUp size={14} /> : <TrendingDown size={14} />}
+                    {variacion >= 0 ? '+' : ''}
+                    {fmt(variacion)}% vs semana pasada
+                  </p>
+                )}
+              </div>
+
+              <div className={tarjeta}>
+                <p className={tituloTarjeta}>Semana pasada</p>
+                <p className="mt-2 text-2xl font-semibold font-mono tabular-nums text-gray-300">
+                  $ {fmt(tablero.ventaSemanaPasadaMismaHora)}
+                </p>
+                <p className="mt-1 text-xs text-gray-500">
+                  a esta hora · cerró en $ {fmt(tablero.ventaSemanaPasadaTotal)}
+                </p>
+              </div>
+
+              <div className={tarjeta}>
+                <p className={tituloTarjeta}>Pedidos cobrados</p>
+                <p className="mt-2 text-2xl font-semibold font-mono tabular-nums text-gray-100">
+                  {tablero.cantidadPedidos}
+                </p>
+                <p className="mt-1 text-xs text-gray-500">Ticket promedio $ {fmt(tablero.ticketPromedio)}</p>
+              </div>
+
+              <div className={tarjeta}>
+                <p className={tituloTarjeta}>Mesas abiertas</p>
+                <p className="mt-2 text-2xl font-semibold font-mono tabular-nums text-gray-100">
+                  {tablero.mesasAbiertas.cantidad}
+                  <span className="text-base text-gray-500"> / {tablero.mesasAbiertas.totalMesas}</span>
+                </p>
+                <p className="mt-1 text-xs text-gray-500">
+                  $ {fmt(tablero.mesasAbiertas.montoEnCurso)} en curso sin cobrar
+                </p>
+              </div>
+            </div>
+
+            <div className="grid grid-cols-1 xl:grid-cols-12 gap-5">
+              {/* ── Ventas por hora ── */}
+              <div className={`${tarjeta} xl:col-span-8 space-y-3`}>
+                <h3 className={tituloTarjeta}>Ventas por hora</h3>
+                <VentasHoraComparadaChart ventasPorHora={tablero.ventasPorHora} />
+              </div>
+
+              {/* ── Medios de pago ── */}
+              <div className={`${tarjeta} xl:col-span-4 space-y-3`}>
+                <h3 className={tituloTarjeta}>Medios de pago</h3>
+                {tablero.mediosPago.length === 0 ? (
+                  <p className="text-xs text-gray-500">Sin cobros todavía.</p>
+                ) : (
+                  <div className="space-y-2.5">
+                    {tablero.mediosPago.map((m) => (
+                      <div key={m.medio} className="space-y-1">
+                        <div className="flex items-center justify-between text-xs">
+                          <span className="text-gray-400">{MEDIOS[m.medio].label}</span>
+                          <span className="font-mono text-gray-300">
+                            $ {fmt(m.monto)} <span className="text-gray-500">· {fmt(m.porcentaje)}%</span>
+                          </span>
+                        </div>
+                        <div className="h-5 w-full rounded-lg bg-neutral-700/40 overflow-hidden">
+                          <div
+                            className={`h-full rounded-lg ${MEDIOS[m.medio].color} transition-all duration-500 ease-out`}
+                            style={{ width: `${Math.max(m.porcentaje, 2)}%` }}
+                          />
+                        </div>
+                      </div>
+                    ))}
+                  </div>
+                )}
+              </div>
+            </div>
+
+            {/* ── Top 10 productos ── */}
+            <div className={`${tarjeta} space-y-3`}>
+              <h3 className={tituloTarjeta}>Top 10 productos</h3>
+              {tablero.topProductos.length === 0 ? (
+                <div className="flex items-center gap-2 text-xs text-gray-500">
+                  <UtensilsCrossed size={14} />
+                  Todavía no se cobró ningún producto.
+                </div>
+              ) : (
+                <ol className="grid grid-cols-1 md:grid-cols-2 gap-x-8 gap-y-2">
+                  {tablero.topProductos.map((p, index) => {
+                    const maximo = tablero.topProductos[0].totalRecaudado || 1;
+                    return (
+                      <li key={p.productoNombre} className="space-y-1">
+                        <div className="flex items-center justify-between text-sm">
+                          <span className="text-gray-300 truncate">
+                            <span className="text-gray-600 font-mono mr-2">{index + 1}.</span>
+                            {p.productoNombre}
+                          </span>
+                          <span className="font-mono tabular-nums text-gray-400 shrink-0 ml-3">
+                            {p.cantidadTotal} u · $ {fmt(p.totalRecaudado)}
+                          </span>
+                        </div>
+                        <div className="h-1.5 w-full rounded bg-neutral-700/40 overflow-hidden">
+                          <div
+                            className="h-full rounded bg-red-500/70"
+                            style={{ width: `${(p.totalRecaudado / maximo) * 100}%` }}
+                          />
+                        </div>
+                      </li>
+                    );
+                  })}
+                </ol>
+              )}
+            </div>
+          </>
+        )}
+      </div>
+    </section>
+  );
+}
@@ -0,0 +1,71 @@
+import type { VentaPorHora } from '../types';
+
+interface VentasHoraComparadaChartProps {
+  ventasPorHora: VentaPorHora[];
+}
+
+function fmtCorto(valor: number): string {
+  if (valor >= 1_000_000) return `${(valor / 1_000_000).toFixed(1)}M`;
+  if (valor >= 1_000) return `${Math.round(valor / 1_000)}k`;
+  return String(Math.round(valor));
+}
+
+/**
+ * Barras por hora de la jornada (HU-114): la barra llena es hoy, la marca
+ * gris es el mismo día de la semana pasada. Puro Tailwind, igual que los
+ * gráficos de caja.
+ */
+export default function VentasHoraComparadaChart({ ventasPorHora }: VentasHoraComparadaChartProps) {
+  if (ventasPorHora.length === 0) {
+    return <p className="text-xs text-gray-500">Todavía no hay ventas en la jornada.</p>;
+  }
+
+  const maximo = Math.max(...ventasPorHora.flatMap((h) => [h.ventas, h.ventasSemanaPasada]), 1);
+
+  return (
+    <div className="space-y-2">
+      <div className="flex items-end gap-1 h-40">
+        {ventasPorHora.map((h) => (
+          <div
+            key={h.hora}
+            className="flex-1 h-full flex flex-col items-center justify-end gap-1 group"
+            title={`${String(h.hora).padStart(2, '0')} h — hoy $ ${h.ventas.toLocaleString('es-AR')} · semana pasada $ ${h.ventasSemanaPasada.toLocaleString('es-AR')}`}
+          >
+            <span className="text-[9px] text-gray-500 font-mono opacity-0 group-hover:opacity-100 transition-opacity">
+              {h.ventas > 0 ? fmtCorto(h.ventas) : ''}
+            </span>
+            <div className="w-full relative flex items-end h-32">
+              {/* Semana pasada: marca de referencia */}
+              {h.ventasSemanaPasada > 0 && (
+                <div
+                  className="absolute inset-x-0 border-t-2 border-dashed border-gray-500/70"
+                  style={{ bottom: `${(h.ventasSemanaPasada / maximo) * 100}%` }}
+                />
+              )}
+              {/* Hoy */}
+              <div
+                className={[
+                  'w-full rounded-t transition-all duration-500 ease-out',
+                  h.ventas > 0 ? 'bg-red-500/70 group-hover:bg-red-500' : 'bg-neutral-700/30',
+                ].join(' ')}
+                style={{ height: h.ventas > 0 ? `${Math.max((h.ventas / maximo) * 100, 4)}%` : '4px' }}
+              />
+            </div>
+            <span className="text-[9px] text-gray-600 font-mono leading-none">
+              {String(h.hora).padStart(2, '0')}
+            </span>
+          </div>
+        ))}
+      </div>
+
+      <div className="flex items-center gap-4 text-[10px] text-gray-500">
+        <span className="flex items-center gap-1.5">
+          <span className="w-3 h-2 rounded-sm bg-red-500/70" /> Hoy
+        </span>
+        <span className="flex items-center gap-1.5">
+          <span className="w-3 border-t-2 border-dashed border-gray-500/70" /> Semana pasada
+        </span>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,20 @@
+import { useQuery } from '@tanstack/react-query';
+import { dashboardApi } from '../api/dashboardApi';
+import type { DashboardVentas } from '../types';
+
+export const dashboardKeys = {
+  ventas: ['dashboard', 'ventas'] as const,
+};
+
+/**
+ * Tablero de la jornada en curso.
+ * Polling cada 30s: es una pantalla para dejar abierta, y cada refresco
+ * es una sola consulta agregada en el backend.
+ */
+export function useDashboardVentas() {
+  return useQuery<DashboardVentas>({
+    queryKey: dashboardKeys.ventas,
+    queryFn: () => dashboardApi.obtenerVentas(),
+    refetchInterval: 30_000,
+  });
+}
@@ -0,0 +1,25 @@
+/**
+ * Módulo Dashboard — tablero de ventas en vivo de la jornada (HU-114).
+ *
+ * @example
+ * import { DashboardPage } from '@/features/dashboard';
+ */
+
+// Tipos
+export type {
+  DashboardVentas,
+  VentaPorHora,
+  ProductoTop,
+  MedioPagoVenta,
+  MesasAbiertasResumen,
+} from './types';
+
+// Hooks
+export { useDashboardVentas } from './hooks/useDashboard';
+
+// Componentes
+export { default as DashboardPage } from './components/DashboardPage';
+export { default as VentasHoraComparadaChart } from './components/VentasHoraComparadaChart';
+
+// API
+export { dashboardApi } from './api/dashboardApi';
@@ -0,0 +1,52 @@
+import type { MedioPago } from '../salon/types';
+
+// ─── Tablero en vivo (HU-114) ────────────────────────────────────────────────
+
+/** Ventas cerradas en una hora de la jornada, hoy y el mismo día de la semana pasada */
+export interface VentaPorHora {
+  /** 0-23 */
+  hora: number;
+  ventas: number;
+  ventasSemanaPasada: number;
+}
+
+export interface ProductoTop {
+  productoNombre: string;
+  cantidadTotal: number;
+  totalRecaudado: number;
+}
+
+export interface MedioPagoVenta {
+  medio: MedioPago;
+  monto: number;
+  /** Participación sobre la venta acumulada */
+  porcentaje: number;
+}
+
+export interface MesasAbiertasResumen {
+  cantidad: number;
+  totalMesas: number;
+  /** Consumo de las mesas ocupadas, todavía sin cobrar */
+  montoEnCurso: number;
+}
+
+/** Espejo de DashboardVentasResponse: todo viene agregado del backend */
+export interface DashboardVentas {
+  /** YYYY-MM-DD — jornada de 06:00 a 06:00 */
+  fechaOperativa: string;
+  /** ISO 8601 datetime */
+  generadoEn: string;
+  /** Ventas reales: todos los pagos salvo A_CUENTA */
+  ventaAcumulada: number;
+  ventaSemanaPasadaMismaHora: number;
+  ventaSemanaPasadaTotal: number;
+  /** null si la semana pasada no había ventas a esta hora */
+  variacionPorcentual: number | null;
+  cantidadPedidos: number;
+  ticketPromedio: number;
+  /** En orden de jornada (06 → 05), sin huecos */
+  ventasPorHora: VentaPorHora[];
+  topProductos: ProductoTop[];
+  mediosPago: MedioPagoVenta[];
+  mesasAbiertas: MesasAbiertasResumen;
+}
@@ -0,0 +1,78 @@
+import apiClient from '../../../lib/apiClient';
+import type {
+  RendicionRepartidor,
+  RendicionesTurno,
+  RendirEfectivoRequest,
+  Reparto,
+  ReporteEnvios,
+  ReporteRepartidores,
+  ZonaDelivery,
+  ZonaDeliveryRequest,
+} from '../types';
+
+/**
+ * API client de zonas de reparto (HU-139) y repartos (HU-140).
+ * Consume /api/delivery de ZonaDeliveryController y RepartoController.
+ */
+export const deliveryApi = {
+  listarZonas: async (): Promise<ZonaDelivery[]> => {
+    const response = await apiClient.get<ZonaDelivery[]>('/delivery/zonas');
+    return response.data;
+  },
+
+  crearZona: async (request: ZonaDeliveryRequest): Promise<ZonaDelivery> => {
+    const response = await apiClient.post<ZonaDelivery>('/delivery/zonas', request);
+    return response.data;
+  },
+
+  actualizarZona: async (zonaId: string, request: ZonaDeliveryRequest): Promise<ZonaDelivery> => {
+    const response = await apiClient.put<ZonaDelivery>(`/delivery/zonas/${zonaId}`, request);
+    return response.data;
+  },
+
+  /** @param desde @param hasta fechas YYYY-MM-DD, inclusive */
+  reporteEnvios: async (desde: string, hasta: string): Promise<ReporteEnvios> => {
+    const response = await apiClient.get<ReporteEnvios>('/delivery/envios', { params: { desde, hasta } });
+    return response.data;
+  },
+
+  /** Repartos pendientes de salida o de regreso */
+  listarRepartos: async (): Promise<Reparto[]> => {
+    const response = await apiClient.get<Reparto[]>('/delivery/repartos');
+    return response.data;
+  },
+
+  asignarRepartidor: async (pedidoId: string, repartidorId: string): Promise<Reparto> => {
+    const response = await apiClient.put<Reparto>(`/delivery/repartos/${pedidoId}/repartidor`, { repartidorId });
+    return response.data;
+  },
+
+  registrarSalida: async (pedidoId: string): Promise<Reparto> => {
+    const response = await apiClient.post<Reparto>(`/delivery/repartos/${pedidoId}/salida`);
+    return response.data;
+  },
+
+  registrarRegreso: async (pedidoId: string): Promise<Reparto> => {
+    const response = await apiClient.post<Reparto>(`/delivery/repartos/${pedidoId}/regreso`);
+    return response.data;
+  },
+
+  /** Rendición de efectivo por repartidor en el turno abierto */
+  consultarRendiciones: async (): Promise<RendicionesTurno> => {
+    const response = await apiClient.get<RendicionesTurno>('/delivery/rendiciones');
+    return response.data;
+  },
+
+  rendirEfectivo: async (request: RendirEfectivoRequest): Promise<RendicionRepartidor> => {
+    const response = await apiClient.post<RendicionRepartidor>('/delivery/rendiciones', request);
+    return response.data;
+  },
+
+  /** @param desde @param hasta fechas YYYY-MM-DD, inclusive */
+  reporteRepartidores: async (desde: string, hasta: string): Promise<ReporteRepartidores> => {
+    const response = await apiClient.get<ReporteRepartidores>('/delivery/repartidores/reporte', {
+      params: { desde, hasta },
+    });
+    return response.data;
+  },
+};
@@ -0,0 +1,114 @@
+import { useState } from 'react';
+import { Bike, Loader2, X } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useMozos } from '../../mozos/hooks/useMozos';
+import type { DetallePedidoResponse } from '../../pedido/types';
+import { useAsignarRepartidor } from '../hooks/useRepartos';
+
+interface AsignarRepartidorModalProps {
+  pedido: DetallePedidoResponse;
+  onClose: () => void;
+}
+
+/**
+ * Repartidor que lleva un pedido de delivery (HU-140).
+ *
+ * Se ofrecen los empleados activos con rol Repartidor. Se puede cambiar
+ * hasta que sale; la salida y el regreso se marcan en el tablero de repartos.
+ */
+export default function AsignarRepartidorModal({ pedido, onClose }: AsignarRepartidorModalProps) {
+  const toast = useToast();
+  const asignar = useAsignarRepartidor();
+  const { data: personal = [], isLoading } = useMozos();
+  const [repartidorId, setRepartidorId] = useState<string | null>(pedido.repartidorId);
+
+  const repartidores = personal.filter((m) => m.rol === 'REPARTIDOR' && m.activo);
+
+  const handleGuardar = () => {
+    if (!repartidorId) return;
+    asignar.mutate(
+      { pedidoId: pedido.pedidoId, repartidorId },
+      {
+        onSuccess: (reparto) => {
+          toast.success(`Pedido #${reparto.numeroPedido} asignado a ${reparto.repartidor}`);
+          onClose();
+        },
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo asignar el repartidor'),
+      },
+    );
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div className="fixed inset-0 z-[95] bg-black/60 animate-backdrop-in" onClick={onClose} />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[100] flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="bg-neutral-900 border border-gray-800 rounded-xl w-full max-w-md flex flex-col pointer-events-auto animate-modal-in"
+          onClick={(e) => e.stopPropagation()}
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-gray-800">
+            <div className="flex items-center gap-3">
+              <Bike size={20} className="text-red-400" />
+              <h2 className="text-lg font-semibold text-text-primary">Repartidor</h2>
+            </div>
+            <button
+              onClick={onClose}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+            >
+              <X size={18} />
+            </button>
+          </div>
+
+          {/* Body */}
+          <div className="px-6 py-5">
+            {isLoading ? (
+              <div className="flex items-center gap-2 text-xs text-gray-500">
+                <Loader2 size={14} className="animate-spin" /> Cargando repartidores...
+              </div>
+            ) : repartidores.length === 0 ? (
+              <p className="text-sm text-gray-500">
+                No hay repartidores activos. Cargalos en Personal con el rol Repartidor.
+              </p>
+            ) : (
+              <div className="grid grid-cols-2 gap-2">
+                {repartidores.map((r) => (
+                  <button
+                    key={r.id}
+                    type="button"
+                    onClick={() => setRepartidorId(r.id)}
+                    className={`min-h-[44px] px-3 rounded-lg border text-sm text-left transition-colors ${
+                      repartidorId === r.id
+                        ? 'border-red-500 bg-red-900/20 text-gray-100'
+                        : 'border-gray-700 text-gray-400 hover:border-gray-500'
+                    }`}
+                  >
+                    {r.nombre}
+                  </button>
+                ))}
+              </div>
+            )}
+          </div>
+
+          {/* Footer */}
+          <div className="flex justify-end gap-3 px-6 py-4 border-t border-gray-800">
+            <button onClick={onClose} className="btn-secondary text-sm !min-h-[42px] px-5">
+              Cancelar
+            </button>
+            <button
+              onClick={handleGuardar}
+              disabled={!repartidorId || asignar.isPending}
+              className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+            >
+              {asignar.isPending && <Loader2 size={16} className="animate-spin" />}
+              Asignar
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
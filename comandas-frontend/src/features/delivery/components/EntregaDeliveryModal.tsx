@@ -0,0 +1,161 @@
+import { useState } from 'react';
+import { AlertTriangle, Loader2, MapPin, X } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useAsignarEntrega } from '../../pedido/hooks/usePedido';
+import type { DetallePedidoResponse } from '../../pedido/types';
+import { useZonasDelivery } from '../hooks/useZonasDelivery';
+
+interface EntregaDeliveryModalProps {
+  pedido: DetallePedidoResponse;
+  onClose: () => void;
+}
+
+const inputClass =
+  'min-h-[44px] w-full px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none';
+
+/**
+ * Dirección de entrega de un pedido de delivery (HU-139).
+ *
+ * Sin zona elegida, el backend la busca por los barrios de la dirección; si
+ * no encuentra ninguna avisa y se elige a mano. Al guardar, el costo de envío
+ * de la zona queda como ítem del pedido. El mínimo de compra se controla al
+ * mandar a cocina: acá solo se avisa cuánto falta.
+ */
+export default function EntregaDeliveryModal({ pedido, onClose }: EntregaDeliveryModalProps) {
+  const toast = useToast();
+  const asignar = useAsignarEntrega();
+  const { data: zonas = [], isLoading } = useZonasDelivery();
+
+  const [direccion, setDireccion] = useState(pedido.direccionEntrega ?? '');
+  const [zonaId, setZonaId] = useState<string | null>(pedido.zonaDeliveryId);
+
+  const activas = zonas.filter((z) => z.activa || z.id === pedido.zonaDeliveryId);
+  const zona = activas.find((z) => z.id === zonaId) ?? null;
+  const ventaProductos = pedido.items
+    .filter((i) => !i.envio)
+    .reduce((acc, i) => acc + i.precioFinal, 0);
+  const faltante = zona ? zona.montoMinimo - ventaProductos : 0;
+  const puedeGuardar = direccion.trim() !== '' && !asignar.isPending;
+
+  const handleGuardar = () => {
+    asignar.mutate(
+      { pedidoId: pedido.pedidoId, direccion: direccion.trim(), zonaId },
+      {
+        onSuccess: () => {
+          toast.success('Entrega cargada');
+          onClose();
+        },
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo cargar la entrega'),
+      },
+    );
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div className="fixed inset-0 z-[95] bg-black/60 animate-backdrop-in" onClick={onClose} />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[100] flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="bg-neutral-900 border border-gray-800 rounded-xl w-full max-w-md flex flex-col pointer-events-auto animate-modal-in"
+          onClick={(e) => e.stopPropagation()}
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-gray-800">
+            <div className="flex items-center gap-3">
+              <MapPin size={20} className="text-red-400" />
+              <h2 className="text-lg font-semibold text-text-primary">Entrega</h2>
+            </div>
+            <button
+              onClick={onClose}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+            >
+              <X size={18} />
+            </button>
+          </div>
+
+          {/* Body */}
+          <div className="px-6 py-5 space-y-4">
+            <label className="flex flex-col gap-1 text-sm text-text-secondary">
+              Dirección
+              <input
+                type="text"
+                value={direccion}
+                maxLength={200}
+                autoFocus
+                placeholder="Calle, número y barrio"
+                onChange={(e) => setDireccion(e.target.value)}
+                className={inputClass}
+              />
+            </label>
+
+            <div className="space-y-1.5">
+              <p className="text-sm text-text-secondary">Zona</p>
+              {isLoading ? (
+                <div className="flex items-center gap-2 text-xs text-gray-500">
+                  <Loader2 size={14} className="animate-spin" /> Cargando zonas...
+                </div>
+              ) : (
+                <div className="grid grid-cols-2 gap-2">
+                  <button
+                    type="button"
+                    onClick={() => setZonaId(null)}
+                    className={`min-h-[44px] px-3 rounded-lg border text-sm text-left transition-colors ${
+                      zonaId === null
+                        ? 'border-red-500 bg-red-900/20 text-gray-100'
+                        : 'border-gray-700 text-gray-400 hover:border-gray-500'
+                    }`}
+                  >
+                    Por barrio
+                    <span className="block text-[11px] text-gray-500">según la dirección</span>
+                  </button>
+                  {activas.map((z) => (
+                    <button
+                      key={z.id}
+                      type="button"
+                      onClick={() => setZonaId(z.id)}
+                      className={`min-h-[44px] px-3 rounded-lg border text-sm text-left transition-colors ${
+                        zonaId === z.id
+                          ? 'border-red-500 bg-red-900/20 text-gray-100'
+                          : 'border-gray-700 text-gray-400 hover:border-gray-500'
+                      }`}
+                    >
+                      {z.nombre}
+                      <span className="block text-[11px] font-mono text-gray-500">
+                        $ {z.costoEnvio.toLocaleString('es-AR')}
+                        {z.montoMinimo > 0 && ` · mín. $ ${z.montoMinimo.toLocaleString('es-AR')}`}
+                      </span>
+                    </button>
+                  ))}
+                </div>
+              )}
+            </div>
+
+            {faltante > 0 && (
+              <p className="flex items-center gap-1.5 text-xs text-amber-400">
+                <AlertTriangle size={13} />
+                Faltan $ {faltante.toLocaleString('es-AR')} en productos para el mínimo de {zona!.nombre}
+              </p>
+            )}
+          </div>
+
+          {/* Footer */}
+          <div className="flex justify-end gap-3 px-6 py-4 border-t border-gray-800">
+            <button onClick={onClose} className="btn-secondary text-sm !min-h-[42px] px-5">
+              Cancelar
+            </button>
+            <button
+              onClick={handleGuardar}
+              disabled={!puedeGuardar}
+              className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+            >
+              {asignar.isPending && <Loader2 size={16} className="animate-spin" />}
+              Guardar
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
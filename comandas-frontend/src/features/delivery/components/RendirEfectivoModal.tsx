@@ -0,0 +1,136 @@
+import { useState } from 'react';
+import { Banknote, Loader2, X } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useRendirEfectivo } from '../hooks/useRepartos';
+import type { RendicionRepartidor } from '../types';
+
+interface RendirEfectivoModalProps {
+  rendicion: RendicionRepartidor;
+  onClose: () => void;
+}
+
+const inputClass =
+  'min-h-[44px] w-full px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none';
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+/**
+ * Rendición del efectivo de un repartidor al final del turno (HU-140).
+ *
+ * Se carga lo que entrega en mano; si no coincide con lo cobrado en
+ * efectivo de sus entregas, el motivo es obligatorio. Se rinde una vez por turno.
+ */
+export default function RendirEfectivoModal({ rendicion, onClose }: RendirEfectivoModalProps) {
+  const toast = useToast();
+  const rendir = useRendirEfectivo();
+  const [rendido, setRendido] = useState(String(rendicion.esperado));
+  const [observaciones, setObservaciones] = useState('');
+
+  const rendidoNumero = Number(rendido);
+  const valido = rendido.trim() !== '' && Number.isFinite(rendidoNumero) && rendidoNumero >= 0;
+  const diferencia = valido ? rendidoNumero - rendicion.esperado : 0;
+  const puedeGuardar = valido && (diferencia === 0 || observaciones.trim() !== '') && !rendir.isPending;
+
+  const handleGuardar = () => {
+    rendir.mutate(
+      {
+        repartidorId: rendicion.repartidorId,
+        rendido: rendidoNumero,
+        observaciones: observaciones.trim() || undefined,
+      },
+      {
+        onSuccess: () => {
+          toast.success(`${rendicion.nombre} rindió $ ${fmt(rendidoNumero)}`);
+          onClose();
+        },
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo registrar la rendición'),
+      },
+    );
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div className="fixed inset-0 z-[95] bg-black/60 animate-backdrop-in" onClick={onClose} />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[100] flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="bg-neutral-900 border border-gray-800 rounded-xl w-full max-w-md flex flex-col pointer-events-auto animate-modal-in"
+          onClick={(e) => e.stopPropagation()}
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-gray-800">
+            <div className="flex items-center gap-3">
+              <Banknote size={20} className="text-red-400" />
+              <h2 className="text-lg font-semibold text-text-primary">Rendición de {rendicion.nombre}</h2>
+            </div>
+            <button
+              onClick={onClose}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+            >
+              <X size={18} />
+            </button>
+          </div>
+
+          {/* Body */}
+          <div className="px-6 py-5 space-y-4">
+            <div className="flex items-center justify-between text-sm">
+              <span className="text-text-secondary">
+                Efectivo de {rendicion.pedidos} {rendicion.pedidos === 1 ? 'entrega' : 'entregas'}
+              </span>
+              <span className="font-mono tabular-nums text-gray-100">$ {fmt(rendicion.esperado)}</span>
+            </div>
+
+            <label className="flex flex-col gap-1 text-sm text-text-secondary">
+              Efectivo entregado
+              <input
+                type="number"
+                min={0}
+                value={rendido}
+                autoFocus
+                onChange={(e) => setRendido(e.target.value)}
+                className={`${inputClass} font-mono`}
+              />
+            </label>
+
+            {diferencia !== 0 && (
+              <>
+                <p className={`text-xs font-mono ${diferencia < 0 ? 'text-red-400' : 'text-amber-400'}`}>
+                  {diferencia < 0 ? 'Faltante' : 'Sobrante'} de $ {fmt(Math.abs(diferencia))}
+                </p>
+                <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                  Motivo
+                  <input
+                    type="text"
+                    value={observaciones}
+                    maxLength={200}
+                    onChange={(e) => setObservaciones(e.target.value)}
+                    className={inputClass}
+                  />
+                </label>
+              </>
+            )}
+          </div>
+
+          {/* Footer */}
+          <div className="flex justify-end gap-3 px-6 py-4 border-t border-gray-800">
+            <button onClick={onClose} className="btn-secondary text-sm !min-h-[42px] px-5">
+              Cancelar
+            </button>
+            <button
+              onClick={handleGuardar}
+              disabled={!puedeGuardar}
+              className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+            >
+              {rendir.isPending && <Loader2 size={16} className="animate-spin" />}
+              Rendir
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
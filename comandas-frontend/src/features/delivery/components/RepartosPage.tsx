@@ -0,0 +1,349 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Bike, Calendar, Loader2, LogIn, LogOut } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useMozos } from '../../mozos/hooks/useMozos';
+import {
+  useAsignarRepartidor,
+  useRegistrarRegreso,
+  useRegistrarSalida,
+  useRendiciones,
+  useRepartos,
+  useReporteRepartidores,
+} from '../hooks/useRepartos';
+import type { RendicionRepartidor, Reparto } from '../types';
+import RendirEfectivoModal from './RendirEfectivoModal';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function hoy(): string {
+  const fecha = new Date();
+  return `${fecha.getFullYear()}-${String(fecha.getMonth() + 1).padStart(2, '0')}-${String(fecha.getDate()).padStart(2, '0')}`;
+}
+
+function inicioDeMes(): string {
+  return `${hoy().slice(0, 7)}-01`;
+}
+
+function formatHora(iso: string): string {
+  return new Date(iso).toLocaleTimeString('es-AR', { hour: '2-digit', minute: '2-digit' });
+}
+
+function minutosDesde(iso: string): number {
+  return Math.max(0, Math.floor((Date.now() - new Date(iso).getTime()) / 60_000));
+}
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+const tarjeta = 'rounded-2xl border border-neutral-800/60 bg-neutral-900/50';
+
+// ─── Repartos en curso ────────────────────────────────────────────────────────
+
+function FilaReparto({ reparto }: { reparto: Reparto }) {
+  const toast = useToast();
+  const { data: personal = [] } = useMozos();
+  const asignar = useAsignarRepartidor();
+  const salida = useRegistrarSalida();
+  const regreso = useRegistrarRegreso();
+
+  const repartidores = personal.filter((m) => m.rol === 'REPARTIDOR' && (m.activo || m.id === reparto.repartidorId));
+  const onError = (err: any) => toast.error(err?.response?.data?.message || 'No se pudo actualizar el reparto');
+
+  return (
+    <tr>
+      <td className="px-4 py-2 font-mono text-gray-100">#{reparto.numeroPedido}</td>
+      <td className="px-3 py-2 text-gray-400 truncate max-w-[220px]">{reparto.direccionEntrega ?? '—'}</td>
+      <td className="px-3 py-2">
+        {reparto.salida ? (
+          <span className="text-gray-100">{reparto.repartidor}</span>
+        ) : (
+          <select
+            value={reparto.repartidorId ?? ''}
+            disabled={asignar.isPending}
+            onChange={(e) =>
+              asignar.mutate({ pedidoId: reparto.pedidoId, repartidorId: e.target.value }, { onError })
+            }
+            className="bg-neutral-800 border border-neutral-700 rounded-lg px-2 py-1 text-sm text-gray-200"
+          >
+            {repartidores.map((r) => (
+              <option key={r.id} value={r.id}>{r.nombre}</option>
+            ))}
+          </select>
+        )}
+      </td>
+      <td className="px-3 py-2 text-right font-mono tabular-nums">
+        {reparto.estado === 'CERRADO' ? (
+          <span className="text-gray-300">$ {fmt(reparto.cobradoEfectivo)}</span>
+        ) : (
+          <span className="text-amber-400 font-sans text-xs">Sin cobrar · $ {fmt(reparto.total)}</span>
+        )}
+      </td>
+      <td className="px-3 py-2 text-right font-mono tabular-nums text-gray-400">
+        {reparto.salida ? `${formatHora(reparto.salida)} · ${minutosDesde(reparto.salida)} min` : '—'}
+      </td>
+      <td className="px-4 py-2 text-right">
+        {reparto.salida ? (
+          <button
+            type="button"
+            disabled={regreso.isPending}
+            onClick={() => regreso.mutate(reparto.pedidoId, { onError })}
+            className="h-8 px-3 rounded-lg bg-emerald-700 hover:bg-emerald-600 text-xs text-white inline-flex items-center gap-1.5 disabled:opacity-50"
+          >
+            <LogIn size={13} />
+            Volvió
+          </button>
+        ) : (
+          <button
+            type="button"
+            disabled={salida.isPending}
+            onClick={() => salida.mutate(reparto.pedidoId, { onError })}
+            className="h-8 px-3 rounded-lg bg-red-600 hover:bg-red-700 text-xs text-white inline-flex items-center gap-1.5 disabled:opacity-50"
+          >
+            <LogOut size={13} />
+            Salió
+          </button>
+        )}
+      </td>
+    </tr>
+  );
+}
+
+function RepartosEnCurso() {
+  const { data: repartos = [], isLoading, isError } = useRepartos();
+
+  if (isLoading) {
+    return (
+      <div className="flex items-center gap-2 text-sm text-gray-500">
+        <Loader2 size={16} className="animate-spin" /> Cargando repartos...
+      </div>
+    );
+  }
+  if (isError) {
+    return <p className="text-sm text-red-400">No se pudieron cargar los repartos.</p>;
+  }
+  if (repartos.length === 0) {
+    return (
+      <div className="flex items-center gap-2 text-xs text-gray-500">
+        <Bike size={14} />
+        No hay pedidos esperando repartidor. Se asignan desde el ticket del delivery.
+      </div>
+    );
+  }
+
+  return (
+    <div className={`${tarjeta} overflow-x-auto`}>
+      <table className="w-full text-sm">
+        <thead>
+          <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+            <th className="text-left font-medium px-4 py-2">Pedido</th>
+            <th className="text-left font-medium px-3 py-2">Dirección</th>
+            <th className="text-left font-medium px-3 py-2">Repartidor</th>
+            <th className="text-right font-medium px-3 py-2">Efectivo</th>
+            <th className="text-right font-medium px-3 py-2">Salida</th>
+            <th className="px-4 py-2" />
+          </tr>
+        </thead>
+        <tbody className="divide-y divide-neutral-800 text-gray-300">
+          {repartos.map((r) => (
+            <FilaReparto key={r.pedidoId} reparto={r} />
+          ))}
+        </tbody>
+      </table>
+    </div>
+  );
+}
+
+// ─── Rendición del turno ──────────────────────────────────────────────────────
+
+function RendicionTurno() {
+  const { data, isLoading, error } = useRendiciones();
+  const [rindiendo, setRindiendo] = useState<RendicionRepartidor | null>(null);
+
+  if (isLoading) {
+    return (
+      <div className="flex items-center gap-2 text-sm text-gray-500">
+        <Loader2 size={16} className="animate-spin" /> Cargando rendiciones...
+      </div>
+    );
+  }
+  if (error || !data) {
+    const mensaje = (error as any)?.response?.data?.message;
+    return <p className="text-sm text-gray-500">{mensaje || 'No se pudo cargar la rendición del turno.'}</p>;
+  }
+
+  return (
+    <div className="space-y-3">
+      {data.repartidores.length === 0 ? (
+        <p className="text-xs text-gray-500">Ningún repartidor llevó pedidos en este turno.</p>
+      ) : (
+        <div className={`${tarjeta} divide-y divide-neutral-800`}>
+          {data.repartidores.map((r) => {
+            const pendiente = r.enLaCalle > 0 || r.sinCobrar > 0;
+            return (
+              <div key={r.repartidorId} className="flex items-center gap-3 px-4 py-3">
+                <div className="min-w-0 flex-1">
+                  <p className="text-sm text-gray-100">{r.nombre}</p>
+                  <p className="text-xs text-gray-500">
+                    {r.pedidos} {r.pedidos === 1 ? 'entrega' : 'entregas'}
+                    {r.enLaCalle > 0 && <span className="text-amber-400"> · {r.enLaCalle} en la calle</span>}
+                    {r.sinCobrar > 0 && <span className="text-amber-400"> · {r.sinCobrar} sin cobrar</span>}
+                  </p>
+                </div>
+                <span className="font-mono tabular-nums text-sm text-gray-300">$ {fmt(r.esperado)}</span>
+                {r.rendido !== null ? (
+                  <span
+                    title={r.observaciones ?? undefined}
+                    className={`text-xs font-mono w-28 text-right ${
+                      r.diferencia === 0 ? 'text-emerald-400' : r.diferencia! < 0 ? 'text-red-400' : 'text-amber-400'
+                    }`}
+                  >
+                    {r.diferencia === 0 ? 'Rindió' : `${r.diferencia! < 0 ? '−' : '+'}$ ${fmt(Math.abs(r.diferencia!))}`}
+                  </span>
+                ) : (
+                  <button
+                    type="button"
+                    disabled={pendiente}
+                    title={pendiente ? 'Tiene pedidos en la calle o sin cobrar' : undefined}
+                    onClick={() => setRindiendo(r)}
+                    className="h-8 w-28 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-xs text-gray-200 disabled:opacity-40 disabled:cursor-not-allowed"
+                  >
+                    Rendir
+                  </button>
+                )}
+              </div>
+            );
+          })}
+        </div>
+      )}
+
+      <div className="flex justify-between text-xs text-gray-500 px-1">
+        <span>Esperado $ {fmt(data.totalEsperado)}</span>
+        <span>Rendido $ {fmt(data.totalRendido)}</span>
+      </div>
+
+      {rindiendo && <RendirEfectivoModal rendicion={rindiendo} onClose={() => setRindiendo(null)} />}
+    </div>
+  );
+}
+
+// ─── Reporte por cadete ───────────────────────────────────────────────────────
+
+function ReporteRepartidores() {
+  const [desde, setDesde] = useState(inicioDeMes);
+  const [hasta, setHasta] = useState(hoy);
+  const { data: reporte, isLoading, isError } = useReporteRepartidores(desde, hasta);
+
+  return (
+    <div className="space-y-3">
+      <div className="flex items-center gap-3">
+        <Calendar size={14} className="text-gray-500" />
+        <input
+          type="date"
+          value={desde}
+          max={hasta}
+          onChange={(e) => e.target.value && setDesde(e.target.value)}
+          className={inputFecha}
+        />
+        <span className="text-xs text-gray-600">a</span>
+        <input
+          type="date"
+          value={hasta}
+          min={desde}
+          max={hoy()}
+          onChange={(e) => e.target.value && setHasta(e.target.value)}
+          className={inputFecha}
+        />
+        {reporte && (
+          <span className="ml-auto text-xs text-gray-500">
+            {reporte.entregas} entregas · {reporte.minutosPromedio} min promedio
+          </span>
+        )}
+      </div>
+
+      {isLoading ? (
+        <div className="flex items-center gap-2 text-sm text-gray-500">
+          <Loader2 size={16} className="animate-spin" /> Cargando reporte...
+        </div>
+      ) : isError ? (
+        <p className="text-sm text-red-400">No se pudo cargar el reporte de repartidores.</p>
+      ) : reporte && reporte.repartidores.length === 0 ? (
+        <p className="text-xs text-gray-500">No hubo entregas terminadas en el rango.</p>
+      ) : reporte && (
+        <div className={`${tarjeta} overflow-x-auto`}>
+          <table className="w-full text-sm">
+            <thead>
+              <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                <th className="text-left font-medium px-4 py-2">Repartidor</th>
+                <th className="text-right font-medium px-3 py-2">Entregas</th>
+                <th className="text-right font-medium px-3 py-2">Promedio</th>
+                <th className="text-right font-medium px-3 py-2">Máximo</th>
+                <th className="text-right font-medium px-4 py-2">Efectivo</th>
+              </tr>
+            </thead>
+            <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+              {reporte.repartidores.map((r) => (
+                <tr key={r.repartidorId}>
+                  <td className="px-4 py-2 font-sans text-gray-100">{r.nombre}</td>
+                  <td className="text-right px-3 py-2">{r.entregas}</td>
+                  <td className="text-right px-3 py-2">{r.minutosPromedio} min</td>
+                  <td className="text-right px-3 py-2 text-gray-400">{r.minutosMaximo} min</td>
+                  <td className="text-right px-4 py-2">$ {fmt(r.cobradoEfectivo)}</td>
+                </tr>
+              ))}
+            </tbody>
+          </table>
+        </div>
+      )}
+    </div>
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Tablero de repartos (HU-140).
+ *
+ * Arriba los pedidos asignados que esperan salir o volver, con el botón
+ * para marcar cada momento. A la derecha la rendición de efectivo de cada
+ * repartidor en el turno abierto; abajo las entregas y tiempos por cadete.
+ */
+export default function RepartosPage() {
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/historial"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Repartos</h1>
+            <p className="text-sm text-gray-500">Salida y regreso de cada pedido, rendición y tiempos por cadete</p>
+          </div>
+        </header>
+
+        <div className="grid grid-cols-1 xl:grid-cols-12 gap-5">
+          <div className="xl:col-span-8 space-y-5">
+            <RepartosEnCurso />
+            <div className="space-y-2">
+              <h2 className="text-xs uppercase tracking-wider text-gray-500">Entregas por repartidor</h2>
+              <ReporteRepartidores />
+            </div>
+          </div>
+
+          <div className="xl:col-span-4 space-y-2">
+            <h2 className="text-xs uppercase tracking-wider text-gray-500">Rendición del turno</h2>
+            <RendicionTurno />
+          </div>
+        </div>
+      </div>
+    </section>
+  );
+}
@@ -0,0 +1,177 @@
+import { useState } from 'react';
+import { X, MapPin, Loader2 } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useActualizarZonaDelivery, useCrearZonaDelivery } from '../hooks/useZonasDelivery';
+import type { ZonaDelivery } from '../types';
+
+interface ZonaDeliveryModalProps {
+  /** null = alta de una zona nueva */
+  zona: ZonaDelivery | null;
+  onClose: () => void;
+}
+
+const inputClass =
+  'min-h-[44px] w-full px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none';
+
+/**
+ * Alta y edición de una zona de reparto (HU-139).
+ *
+ * Los barrios se cargan separados por coma; son los nombres que se buscan en
+ * la dirección del cliente para elegir la zona sin que el cajero la marque.
+ * Una zona no se borra: se desactiva y deja de ofrecerse en pedidos nuevos.
+ */
+export default function ZonaDeliveryModal({ zona, onClose }: ZonaDeliveryModalProps) {
+  const toast = useToast();
+  const crear = useCrearZonaDelivery();
+  const actualizar = useActualizarZonaDelivery();
+
+  const [nombre, setNombre] = useState(zona?.nombre ?? '');
+  const [costo, setCosto] = useState(zona ? String(zona.costoEnvio) : '');
+  const [minimo, setMinimo] = useState(zona && zona.montoMinimo > 0 ? String(zona.montoMinimo) : '');
+  const [barrios, setBarrios] = useState(zona?.barrios.join(', ') ?? '');
+  const [activa, setActiva] = useState(zona?.activa ?? true);
+
+  const guardando = crear.isPending || actualizar.isPending;
+  const costoNumero = Number(costo);
+  const minimoNumero = minimo.trim() === '' ? 0 : Number(minimo);
+  const puedeGuardar =
+    nombre.trim() !== '' &&
+    costo.trim() !== '' &&
+    Number.isFinite(costoNumero) && costoNumero >= 0 &&
+    Number.isFinite(minimoNumero) && minimoNumero >= 0 &&
+    !guardando;
+
+  const handleGuardar = () => {
+    const request = {
+      nombre: nombre.trim(),
+      costoEnvio: costoNumero,
+      montoMinimo: minimoNumero,
+      barrios: barrios.split(',').map((b) => b.trim()).filter((b) => b !== ''),
+      activa,
+    };
+    const opciones = {
+      onSuccess: () => {
+        toast.success(zona ? 'Zona actualizada' : 'Zona creada');
+        onClose();
+      },
+      onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo guardar la zona'),
+    };
+
+    if (zona) {
+      actualizar.mutate({ zonaId: zona.id, request }, opciones);
+    } else {
+      crear.mutate(request, opciones);
+    }
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div className="fixed inset-0 z-[95] bg-black/60 animate-backdrop-in" onClick={onClose} />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[100] flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="bg-neutral-900 border border-gray-800 rounded-xl w-full max-w-md flex flex-col pointer-events-auto animate-modal-in"
+          onClick={(e) => e.stopPropagation()}
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-gray-800">
+            <div className="flex items-center gap-3">
+              <MapPin size={20} className="text-red-400" />
+              <h2 className="text-lg font-semibold text-text-primary">
+                {zona ? 'Editar zona' : 'Nueva zona'}
+              </h2>
+            </div>
+            <button
+              onClick={onClose}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+            >
+              <X size={18} />
+            </button>
+          </div>
+
+          {/* Body */}
+          <div className="px-6 py-5 space-y-4">
+            <label className="flex flex-col gap-1 text-sm text-text-secondary">
+              Nombre
+              <input
+                type="text"
+                value={nombre}
+                maxLength={60}
+                autoFocus
+                onChange={(e) => setNombre(e.target.value)}
+                className={inputClass}
+              />
+            </label>
+
+            <div className="grid grid-cols-2 gap-3">
+              <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                Costo de envío
+                <input
+                  type="number"
+                  min={0}
+                  value={costo}
+                  onChange={(e) => setCosto(e.target.value)}
+                  className={`${inputClass} font-mono`}
+                />
+              </label>
+              <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                Mínimo de compra
+                <input
+                  type="number"
+                  min={0}
+                  value={minimo}
+                  placeholder="Sin mínimo"
+                  onChange={(e) => setMinimo(e.target.value)}
+                  className={`${inputClass} font-mono`}
+                />
+              </label>
+            </div>
+
+            <label className="flex flex-col gap-1 text-sm text-text-secondary">
+              Barrios
+              <textarea
+                value={barrios}
+                rows={3}
+                placeholder="Alberdi, Alta Córdoba, Villa Belgrano"
+                onChange={(e) => setBarrios(e.target.value)}
+                className={`${inputClass} py-2 resize-none`}
+              />
+              <span className="text-xs text-gray-500">
+                Separados por coma. Si la dirección menciona uno, la zona se elige sola.
+              </span>
+            </label>
+
+            {zona && (
+              <label className="flex items-center gap-2 text-sm text-text-secondary">
+                <input
+                  type="checkbox"
+                  checked={activa}
+                  onChange={(e) => setActiva(e.target.checked)}
+                  className="accent-red-600"
+                />
+                Activa (se ofrece en los pedidos de delivery)
+              </label>
+            )}
+          </div>
+
+          {/* Footer */}
+          <div className="flex justify-end gap-3 px-6 py-4 border-t border-gray-800">
+            <button onClick={onClose} className="btn-secondary text-sm !min-h-[42px] px-5">
+              Cancelar
+            </button>
+            <button
+              onClick={handleGuardar}
+              disabled={!puedeGuardar}
+              className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+            >
+              {guardando && <Loader2 size={16} className="animate-spin" />}
+              Guardar
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
@@ -0,0 +1,208 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Calendar, Loader2, MapPin, Pencil, Plus } from 'lucide-react';
+import { useReporteEnvios, useZonasDelivery } from '../hooks/useZonasDelivery';
+import type { ZonaDelivery } from '../types';
+import ZonaDeliveryModal from './ZonaDeliveryModal';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function hoy(): string {
+  const fecha = new Date();
+  return `${fecha.getFullYear()}-${String(fecha.getMonth() + 1).padStart(2, '0')}-${String(fecha.getDate()).padStart(2, '0')}`;
+}
+
+function inicioDeMes(): string {
+  return `${hoy().slice(0, 7)}-01`;
+}
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+// ─── Reporte de envíos ────────────────────────────────────────────────────────
+
+function ReporteEnvios() {
+  const [desde, setDesde] = useState(inicioDeMes);
+  const [hasta, setHasta] = useState(hoy);
+  const { data: reporte, isLoading, isError } = useReporteEnvios(desde, hasta);
+
+  return (
+    <div className="space-y-3">
+      <div className="flex items-center gap-3">
+        <Calendar size={14} className="text-gray-500" />
+        <input
+          type="date"
+          value={desde}
+          max={hasta}
+          onChange={(e) => e.target.value && setDesde(e.target.value)}
+          className={inputFecha}
+        />
+        <span className="text-xs text-gray-600">a</span>
+        <input
+          type="date"
+          value={hasta}
+          min={desde}
+          max={hoy()}
+          onChange={(e) => e.target.value && setHasta(e.target.value)}
+          className={inputFecha}
+        />
+      </div>
+
+      {isLoading ? (
+        <div className="flex items-center gap-2 text-sm text-gray-500">
+          <Loader2 size={16} className="animate-spin" /> Cargando envíos...
+        </div>
+      ) : isError ? (
+        <p className="text-sm text-red-400">No se pudo cargar el reporte de envíos.</p>
+      ) : reporte && (
+        <>
+          <div className="grid grid-cols-3 gap-3">
+            {[
+              { label: 'Envíos', valor: String(reporte.envios) },
+              { label: 'Cobrado en envíos', valor: `$ ${fmt(reporte.totalEnvios)}` },
+              { label: 'Venta de productos', valor: `$ ${fmt(reporte.ventaProductos)}` },
+            ].map((card) => (
+              <div key={card.label} className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 px-4 py-3">
+                <p className="text-[10px] uppercase tracking-wider text-gray-600">{card.label}</p>
+                <p className="text-lg font-mono tabular-nums text-gray-100">{card.valor}</p>
+              </div>
+            ))}
+          </div>
+
+          {reporte.zonas.length === 0 ? (
+            <p className="text-xs text-gray-500">No se cobraron envíos en el rango.</p>
+          ) : (
+            <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+              <table className="w-full text-sm">
+                <thead>
+                  <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                    <th className="text-left font-medium px-4 py-2">Zona</th>
+                    <th className="text-right font-medium px-3 py-2">Envíos</th>
+                    <th className="text-right font-medium px-4 py-2">Cobrado</th>
+                  </tr>
+                </thead>
+                <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+                  {reporte.zonas.map((z) => (
+                    <tr key={z.zona}>
+                      <td className="px-4 py-2 font-sans text-gray-100">{z.zona}</td>
+                      <td className="text-right px-3 py-2">{z.envios}</td>
+                      <td className="text-right px-4 py-2">$ {fmt(z.total)}</td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          )}
+        </>
+      )}
+    </div>
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Zonas de delivery con costo de envío (HU-139).
+ *
+ * A la izquierda las zonas de reparto con su costo, mínimo de compra y
+ * barrios. A la derecha lo cobrado en envíos por zona, separado de la
+ * venta de productos del mismo rango.
+ */
+export default function ZonasDeliveryPage() {
+  const { data: zonas = [], isLoading, isError } = useZonasDelivery();
+  const [editando, setEditando] = useState<ZonaDelivery | null | undefined>(undefined);
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/historial"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Zonas de delivery</h1>
+            <p className="text-sm text-gray-500">Costo de envío y mínimo de compra por zona de reparto</p>
+          </div>
+          <button
+            type="button"
+            onClick={() => setEditando(null)}
+            className="ml-auto h-9 px-3 rounded-lg bg-red-600 hover:bg-red-700 text-sm text-white flex items-center gap-1.5"
+          >
+            <Plus size={14} />
+            Nueva zona
+          </button>
+        </header>
+
+        <div className="grid grid-cols-1 xl:grid-cols-12 gap-5">
+          <div className="xl:col-span-7">
+            {isLoading ? (
+              <div className="flex items-center gap-2 text-sm text-gray-500">
+                <Loader2 size={16} className="animate-spin" /> Cargando zonas...
+              </div>
+            ) : isError ? (
+              <p className="text-sm text-red-400">No se pudieron cargar las zonas.</p>
+            ) : zonas.length === 0 ? (
+              <div className="flex items-center gap-2 text-xs text-gray-500">
+                <MapPin size={14} />
+                Todavía no hay zonas de reparto cargadas.
+              </div>
+            ) : (
+              <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+                <table className="w-full text-sm">
+                  <thead>
+                    <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                      <th className="text-left font-medium px-4 py-2">Zona</th>
+                      <th className="text-right font-medium px-3 py-2">Envío</th>
+                      <th className="text-right font-medium px-3 py-2">Mínimo</th>
+                      <th className="px-4 py-2" />
+                    </tr>
+                  </thead>
+                  <tbody className="divide-y divide-neutral-800 text-gray-300">
+                    {zonas.map((z) => (
+                      <tr key={z.id}>
+                        <td className="px-4 py-2">
+                          <span className={z.activa ? 'text-gray-100' : 'text-gray-500'}>{z.nombre}</span>
+                          {!z.activa && <span className="ml-2 text-[10px] text-gray-600">(inactiva)</span>}
+                          {z.barrios.length > 0 && (
+                            <p className="text-xs text-gray-500 truncate max-w-[320px]">{z.barrios.join(', ')}</p>
+                          )}
+                        </td>
+                        <td className="text-right px-3 py-2 font-mono tabular-nums">$ {fmt(z.costoEnvio)}</td>
+                        <td className="text-right px-3 py-2 font-mono tabular-nums text-gray-400">
+                          {z.montoMinimo > 0 ? `$ ${fmt(z.montoMinimo)}` : '—'}
+                        </td>
+                        <td className="text-right px-4 py-2">
+                          <button
+                            type="button"
+                            onClick={() => setEditando(z)}
+                            className="p-1.5 rounded-lg hover:bg-neutral-800 text-gray-500 hover:text-gray-300"
+                            title="Editar zona"
+                          >
+                            <Pencil size={14} />
+                          </button>
+                        </td>
+                      </tr>
+                    ))}
+                  </tbody>
+                </table>
+              </div>
+            )}
+          </div>
+
+          <div className="xl:col-span-5">
+            <ReporteEnvios />
+          </div>
+        </div>
+      </div>
+
+      {editando !== undefined && <ZonaDeliveryModal zona={editando} onClose={() => setEditando(undefined)} />}
+    </section>
+  );
+}
@@ -0,0 +1,97 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { deliveryApi } from '../api/deliveryApi';
+import type { RendicionesTurno, RendirEfectivoRequest, Reparto, ReporteRepartidores } from '../types';
+import { deliveryKeys } from './useZonasDelivery';
+
+/**
+ * HU-140: Repartos pendientes de salida o de regreso.
+ * Se refresca solo: los pedidos se asignan desde el POS.
+ *
+ * queryKey: ['delivery', 'repartos']
+ */
+export function useRepartos() {
+  return useQuery<Reparto[]>({
+    queryKey: deliveryKeys.repartos,
+    queryFn: () => deliveryApi.listarRepartos(),
+    refetchInterval: 30_000,
+  });
+}
+
+/**
+ * HU-140: Rendición de efectivo por repartidor en el turno abierto.
+ * Sin turno abierto el backend responde 409 y no se reintenta.
+ *
+ * queryKey: ['delivery', 'rendiciones']
+ */
+export function useRendiciones() {
+  return useQuery<RendicionesTurno>({
+    queryKey: deliveryKeys.rendiciones,
+    queryFn: () => deliveryApi.consultarRendiciones(),
+    retry: false,
+  });
+}
+
+/**
+ * queryKey: ['delivery', 'repartidores', desde, hasta]
+ */
+export function useReporteRepartidores(desde: string, hasta: string) {
+  return useQuery<ReporteRepartidores>({
+    queryKey: deliveryKeys.reporteRepartidores(desde, hasta),
+    queryFn: () => deliveryApi.reporteRepartidores(desde, hasta),
+    enabled: !!desde && !!hasta && desde <= hasta,
+  });
+}
+
+/**
+ * Asignar o cambiar el repartidor. Invalida el pedido (el ticket muestra
+ * el repartidor) y el tablero de repartos.
+ */
+export function useAsignarRepartidor() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ pedidoId, repartidorId }: { pedidoId: string; repartidorId: string }) =>
+      deliveryApi.asignarRepartidor(pedidoId, repartidorId),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+      queryClient.invalidateQueries({ queryKey: deliveryKeys.repartos });
+    },
+  });
+}
+
+export function useRegistrarSalida() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (pedidoId: string) => deliveryApi.registrarSalida(pedidoId),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+      queryClient.invalidateQueries({ queryKey: deliveryKeys.repartos });
+      queryClient.invalidateQueries({ queryKey: deliveryKeys.rendiciones });
+    },
+  });
+}
+
+export function useRegistrarRegreso() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (pedidoId: string) => deliveryApi.registrarRegreso(pedidoId),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: deliveryKeys.repartos });
+      queryClient.invalidateQueries({ queryKey: deliveryKeys.rendiciones });
+      queryClient.invalidateQueries({ queryKey: ['delivery', 'repartidores'], exact: false });
+    },
+  });
+}
+
+export function useRendirEfectivo() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (request: RendirEfectivoRequest) => deliveryApi.rendirEfectivo(request),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: deliveryKeys.rendiciones });
+    },
+  });
+}
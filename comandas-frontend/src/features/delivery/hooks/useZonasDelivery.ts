@@ -0,0 +1,57 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { deliveryApi } from '../api/deliveryApi';
+import type { ReporteEnvios, ZonaDelivery, ZonaDeliveryRequest } from '../types';
+
+export const deliveryKeys = {
+  zonas: ['delivery', 'zonas'] as const,
+  envios: (desde: string, hasta: string) => ['delivery', 'envios', desde, hasta] as const,
+  repartos: ['delivery', 'repartos'] as const,
+  rendiciones: ['delivery', 'rendiciones'] as const,
+  reporteRepartidores: (desde: string, hasta: string) => ['delivery', 'repartidores', desde, hasta] as const,
+};
+
+/**
+ * Zonas de reparto del local (activas e inactivas), por nombre.
+ *
+ * queryKey: ['delivery', 'zonas']
+ */
+export function useZonasDelivery() {
+  return useQuery<ZonaDelivery[]>({
+    queryKey: deliveryKeys.zonas,
+    queryFn: () => deliveryApi.listarZonas(),
+  });
+}
+
+/**
+ * queryKey: ['delivery', 'envios', desde, hasta]
+ */
+export function useReporteEnvios(desde: string, hasta: string) {
+  return useQuery<ReporteEnvios>({
+    queryKey: deliveryKeys.envios(desde, hasta),
+    queryFn: () => deliveryApi.reporteEnvios(desde, hasta),
+    enabled: !!desde && !!hasta && desde <= hasta,
+  });
+}
+
+export function useCrearZonaDelivery() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (request: ZonaDeliveryRequest) => deliveryApi.crearZona(request),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: deliveryKeys.zonas });
+    },
+  });
+}
+
+export function useActualizarZonaDelivery() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ zonaId, request }: { zonaId: string; request: ZonaDeliveryRequest }) =>
+      deliveryApi.actualizarZona(zonaId, request),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: deliveryKeys.zonas });
+    },
+  });
+}
@@ -0,0 +1,46 @@
+/**
+ * Módulo Delivery — zonas de reparto con costo de envío y mínimo de compra,
+ * carga de la entrega en el pedido y reporte de envíos aparte de la venta
+ * de productos (HU-139). Asignación de repartidores, salida y regreso,
+ * rendición de efectivo por turno y tiempos por cadete (HU-140).
+ *
+ * @example
+ * import { ZonasDeliveryPage, EntregaDeliveryModal } from '@/features/delivery';
+ */
+
+// Tipos
+export type { ZonaDelivery, ZonaDeliveryRequest, EnviosZona, ReporteEnvios } from './types';
+export type {
+  Reparto,
+  RendicionRepartidor,
+  RendicionesTurno,
+  RendirEfectivoRequest,
+  RepartidorResumen,
+  ReporteRepartidores,
+} from './types';
+
+// Hooks
+export {
+  useZonasDelivery,
+  useReporteEnvios,
+  useCrearZonaDelivery,
+  useActualizarZonaDelivery,
+} from './hooks/useZonasDelivery';
+export {
+  useRepartos,
+  useRendiciones,
+  useReporteRepartidores,
+  useAsignarRepartidor,
+  useRegistrarSalida,
+  useRegistrarRegreso,
+  useRendirEfectivo,
+} from './hooks/useRepartos';
+
+// Componentes
+export { default as ZonasDeliveryPage } from './components/ZonasDeliveryPage';
+export { default as EntregaDeliveryModal } from './components/EntregaDeliveryModal';
+export { default as RepartosPage } from './components/RepartosPage';
+export { default as AsignarRepartidorModal } from './components/AsignarRepartidorModal';
+
+// API
+export { deliveryApi } from './api/deliveryApi';
@@ -0,0 +1,107 @@
+// ─── Zonas de delivery con costo de envío (HU-139) ───────────────────────────
+
+export interface ZonaDelivery {
+  id: string;
+  nombre: string;
+  costoEnvio: number;
+  /** Mínimo de compra en productos (0 = sin mínimo) */
+  montoMinimo: number;
+  /** Nombres que se buscan en la dirección para elegir la zona sola */
+  barrios: string[];
+  activa: boolean;
+}
+
+export interface ZonaDeliveryRequest {
+  nombre: string;
+  costoEnvio: number;
+  /** Omitido = sin mínimo */
+  montoMinimo?: number;
+  barrios?: string[];
+  /** Omitido = activa */
+  activa?: boolean;
+}
+
+export interface EnviosZona {
+  /** Nombre de la zona al momento de cada venta */
+  zona: string;
+  envios: number;
+  total: number;
+}
+
+/** Envíos cobrados en un rango, aparte de la venta de productos */
+export interface ReporteEnvios {
+  desde: string;
+  hasta: string;
+  envios: number;
+  totalEnvios: number;
+  /** Venta de productos del mismo rango, sin envíos */
+  ventaProductos: number;
+  zonas: EnviosZona[];
+}
+
+// ─── Repartidores (HU-140) ───────────────────────────────────────────────────
+
+export type EstadoPedidoReparto = 'ABIERTO' | 'CERRADO';
+
+/** Pedido de delivery asignado a un repartidor */
+export interface Reparto {
+  pedidoId: string;
+  numeroPedido: number;
+  direccionEntrega: string | null;
+  repartidorId: string | null;
+  repartidor: string | null;
+  /** ABIERTO = todavía no se cobró en caja */
+  estado: EstadoPedidoReparto;
+  total: number;
+  /** Lo cobrado en efectivo: lo que trae el repartidor */
+  cobradoEfectivo: number;
+  salida: string | null;
+  regreso: string | null;
+  minutosReparto: number | null;
+}
+
+/** Rendición de un repartidor en el turno abierto; rendido null = todavía no rindió */
+export interface RendicionRepartidor {
+  repartidorId: string;
+  nombre: string;
+  pedidos: number;
+  enLaCalle: number;
+  sinCobrar: number;
+  esperado: number;
+  rendido: number | null;
+  /** rendido − esperado (negativo = faltante) */
+  diferencia: number | null;
+  observaciones: string | null;
+}
+
+export interface RendicionesTurno {
+  turnoId: string;
+  repartidores: RendicionRepartidor[];
+  totalEsperado: number;
+  totalRendido: number;
+}
+
+export interface RendirEfectivoRequest {
+  repartidorId: string;
+  rendido: number;
+  /** Obligatorias si hay diferencia */
+  observaciones?: string;
+}
+
+export interface RepartidorResumen {
+  repartidorId: string;
+  nombre: string;
+  entregas: number;
+  minutosPromedio: number;
+  minutosMaximo: number;
+  cobradoEfectivo: number;
+}
+
+/** Entregas terminadas y tiempos de salida a regreso en un rango */
+export interface ReporteRepartidores {
+  desde: string;
+  hasta: string;
+  entregas: number;
+  minutosPromedio: number;
+  repartidores: RepartidorResumen[];
+}
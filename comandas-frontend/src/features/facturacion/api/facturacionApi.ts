@@ -0,0 +1,93 @@
+import apiClient from '../../../lib/apiClient';
+import type { Contribuyente, EmitirFacturaRequest, Factura, FormatoLibroIva, PlanCuentasContable, SistemaContable } from '../types';
+
+/**
+ * API client de facturación (HU-115, HU-116) y exportación contable (HU-167).
+ * Consume los endpoints de FacturaController y ContabilidadController.
+ */
+export const facturacionApi = {
+  /** Valida formato y dígito verificador; si hay padrón, trae razón social y condición */
+  consultarContribuyente: async (cuit: string): Promise<Contribuyente> => {
+    const response = await apiClient.get<Contribuyente>(
+      `/facturas/contribuyentes/${encodeURIComponent(cuit)}`
+    );
+    return response.data;
+  },
+
+  emitir: async (request: EmitirFacturaRequest): Promise<Factura> => {
+    const response = await apiClient.post<Factura>('/facturas', request);
+    return response.data;
+  },
+
+  /** null si el pedido no fue facturado (204) */
+  buscarPorPedido: async (pedidoId: string): Promise<Factura | null> => {
+    const response = await apiClient.get<Factura | ''>('/facturas', { params: { pedidoId } });
+    return response.status === 204 || !response.data ? null : response.data;
+  },
+
+  /** Reintenta el CAE de una factura pendiente */
+  autorizar: async (facturaId: string): Promise<Factura> => {
+    const response = await apiClient.post<Factura>(`/facturas/${facturaId}/autorizar`);
+    return response.data;
+  },
+
+  /** Buffer ESC/POS en Base64 para imprimirEscPos() */
+  generarEscPos: async (facturaId: string, reimpresion: boolean): Promise<string> => {
+    const response = await apiClient.post<{ escPosBase64: string }>(
+      `/facturas/${facturaId}/imprimir`,
+      null,
+      { params: { reimpresion } }
+    );
+    return response.data.escPosBase64;
+  },
+
+  /**
+   * Libro IVA ventas del mes (periodo "yyyy-MM").
+   * CSV para planilla; TXT devuelve un ZIP con comprobantes y alícuotas.
+   */
+  descargarLibroIvaVentas: async (periodo: string, formato: FormatoLibroIva): Promise<Blob> => {
+    const response = await apiClient.get('/facturas/libro-iva-ventas', {
+      params: { periodo, formato },
+      responseType: 'blob',
+    });
+    return response.data;
+  },
+
+  /** HU-167: Cuentas asignadas a cada concepto (solo las que tienen cuenta) */
+  obtenerPlanCuentas: async (): Promise<PlanCuentasContable> => {
+    const response = await apiClient.get<PlanCuentasContable>('/contabilidad/cuentas');
+    return response.data;
+  },
+
+  guardarPlanCuentas: async (data: PlanCuentasContable): Promise<PlanCuentasContable> => {
+    const response = await apiClient.put<PlanCuentasContable>('/contabilidad/cuentas', data);
+    return response.data;
+  },
+
+  /**
+   * HU-167: Asientos del mes (periodo "yyyy-MM") en el formato del sistema elegido.
+   *
+   * Errores esperados:
+   *   - HTTP 409: Algún concepto del mes no tiene cuenta asignada
+   */
+  descargarAsientosContables: async (periodo: string, sistema: SistemaContable): Promise<Blob> => {
+    try {
+      const response = await apiClient.get('/contabilidad/exportar', {
+        params: { periodo, sistema },
+        responseType: 'blob',
+      });
+      return response.data;
+    } catch (error: any) {
+      // Con responseType blob el error también llega como Blob: se lee el mensaje del backend
+      const data = error?.response?.data;
+      if (data instanceof Blob) {
+        const mensaje = await data
+          .text()
+          .then((texto) => JSON.parse(texto)?.message as string | undefined)
+          .catch(() => undefined);
+        if (mensaje) throw new Error(mensaje);
+      }
+      throw error;
+    }
+  },
+};
@@ -0,0 +1,212 @@
+import { useEffect, useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Download, Loader2 } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import {
+  useDescargarAsientosContables,
+  useGuardarPlanCuentasContable,
+  usePlanCuentasContable,
+} from '../hooks/useFacturacion';
+import { CONCEPTO_CONTABLE_LABELS } from '../types';
+import type { ConceptoContable, PlanCuentasContable, SistemaContable } from '../types';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+const SISTEMAS: { value: SistemaContable; label: string; detalle: string }[] = [
+  { value: 'TANGO', label: 'Tango', detalle: 'TXT de asientos' },
+  { value: 'XUBIO', label: 'Xubio', detalle: 'CSV de asientos' },
+  { value: 'CONTABILIUM', label: 'Contabilium', detalle: 'CSV de asientos' },
+];
+
+const GRUPOS: { titulo: string; conceptos: ConceptoContable[] }[] = [
+  { titulo: 'Ventas', conceptos: ['VENTAS', 'IVA_DEBITO_FISCAL'] },
+  {
+    titulo: 'Medios de cobro y pago',
+    conceptos: ['EFECTIVO', 'TARJETA', 'TRANSFERENCIA', 'QR', 'CUENTA_CORRIENTE_CLIENTES', 'TARJETA_REGALO', 'PROVEEDORES'],
+  },
+  {
+    titulo: 'Gastos',
+    conceptos: [
+      'GASTO_MERCADERIA',
+      'GASTO_ALQUILER',
+      'GASTO_SERVICIOS',
+      'GASTO_SUELDOS',
+      'GASTO_IMPUESTOS',
+      'GASTO_MANTENIMIENTO',
+      'GASTO_OTROS',
+    ],
+  },
+];
+
+const CUENTA_VALIDA = /^[A-Za-z0-9. -]{0,30}$/;
+
+/** Mes anterior en formato yyyy-MM: es el que se le pasa al contador */
+function mesAnterior(): string {
+  const hoy = new Date();
+  const d = new Date(hoy.getFullYear(), hoy.getMonth() - 1, 1);
+  return `${d.getFullYear()}-${String(d.getMonth() + 1).padStart(2, '0')}`;
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Exportación al sistema contable (HU-167).
+ *
+ * Arma los asientos del mes (ventas por día, IVA de las facturas y gastos)
+ * en el formato de importación de Tango, Xubio o Contabilium. Las cuentas de
+ * cada concepto se cargan una vez con el plan de cuentas del estudio y valen
+ * para cualquier sistema.
+ */
+export default function ExportacionContablePage() {
+  const toast = useToast();
+  const { data: plan, isLoading, isError } = usePlanCuentasContable();
+  const guardar = useGuardarPlanCuentasContable();
+  const descargar = useDescargarAsientosContables();
+
+  const [cuentas, setCuentas] = useState<PlanCuentasContable['cuentas']>({});
+  const [periodo, setPeriodo] = useState(mesAnterior);
+  const [sistema, setSistema] = useState<SistemaContable>('TANGO');
+
+  useEffect(() => {
+    if (plan) setCuentas(plan.cuentas);
+  }, [plan]);
+
+  const invalidas = Object.values(cuentas).some((c) => c && !CUENTA_VALIDA.test(c.trim()));
+  const sinGuardar = GRUPOS.some((g) =>
+    g.conceptos.some((c) => (cuentas[c] ?? '').trim() !== (plan?.cuentas[c] ?? ''))
+  );
+
+  const handleGuardar = () => {
+    guardar.mutate(
+      { cuentas },
+      {
+        onSuccess: () => toast.success('Cuentas guardadas'),
+        onError: (err: any) =>
+          toast.error(err?.response?.data?.message || 'No se pudieron guardar las cuentas'),
+      },
+    );
+  };
+
+  const handleDescargar = () => {
+    descargar.mutate(
+      { periodo, sistema },
+      {
+        onSuccess: () => toast.success('Asientos descargados'),
+        onError: (err: any) => toast.error(err?.message || 'No se pudieron generar los asientos', 8000),
+      },
+    );
+  };
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/historial"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Exportación contable</h1>
+            <p className="text-sm text-gray-500">Asientos del mes para el estudio contable</p>
+          </div>
+        </header>
+
+        {/* ── Exportar ── */}
+        <div className="max-w-3xl rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-4">
+          <div className="flex flex-wrap items-end gap-3">
+            <label className="space-y-1">
+              <span className="block text-xs uppercase tracking-wider text-gray-600">Mes</span>
+              <input
+                type="month"
+                value={periodo}
+                onChange={(e) => setPeriodo(e.target.value)}
+                className="h-9 px-2 bg-neutral-800 border border-neutral-700 rounded-lg text-gray-100 focus:border-red-500/50 focus:outline-none"
+              />
+            </label>
+            <div className="flex gap-2">
+              {SISTEMAS.map((s) => (
+                <button
+                  key={s.value}
+                  onClick={() => setSistema(s.value)}
+                  className={`px-3 py-1 rounded-lg text-sm border transition-colors ${
+                    sistema === s.value
+                      ? 'bg-red-600/20 border-red-600 text-red-300'
+                      : 'bg-neutral-800 border-neutral-700 text-gray-400 hover:border-neutral-600'
+                  }`}
+                >
+                  <span className="block font-medium">{s.label}</span>
+                  <span className="block text-xs opacity-75">{s.detalle}</span>
+                </button>
+              ))}
+            </div>
+            <button
+              onClick={handleDescargar}
+              disabled={!periodo || descargar.isPending || sinGuardar}
+              className="btn-primary text-sm !min-h-[42px] px-5 ml-auto flex items-center gap-2"
+            >
+              {descargar.isPending ? <Loader2 size={16} className="animate-spin" /> : <Download size={16} />}
+              Descargar
+            </button>
+          </div>
+          {sinGuardar && <p className="text-xs text-amber-400">Guardá las cuentas antes de exportar.</p>}
+        </div>
+
+        {/* ── Cuentas ── */}
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando cuentas...
+          </div>
+        ) : isError ? (
+          <p className="text-sm text-red-400">No se pudieron cargar las cuentas.</p>
+        ) : (
+          <div className="max-w-3xl rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-4">
+            {GRUPOS.map((grupo) => (
+              <div key={grupo.titulo} className="space-y-2">
+                <h2 className="text-xs uppercase tracking-wider text-gray-600">{grupo.titulo}</h2>
+                <div className="grid sm:grid-cols-2 gap-x-6 gap-y-2">
+                  {grupo.conceptos.map((concepto) => {
+                    const valor = cuentas[concepto] ?? '';
+                    const valida = CUENTA_VALIDA.test(valor.trim());
+                    return (
+                      <label key={concepto} className="flex items-center justify-between gap-3 text-sm text-gray-300">
+                        {CONCEPTO_CONTABLE_LABELS[concepto]}
+                        <input
+                          value={valor}
+                          maxLength={30}
+                          placeholder="Sin cuenta"
+                          onChange={(e) => setCuentas((prev) => ({ ...prev, [concepto]: e.target.value }))}
+                          className={`w-36 h-9 px-2 bg-neutral-800 border rounded-lg font-mono text-gray-100 focus:outline-none ${
+                            valida ? 'border-neutral-700 focus:border-red-500/50' : 'border-red-500'
+                          }`}
+                        />
+                      </label>
+                    );
+                  })}
+                </div>
+              </div>
+            ))}
+
+            <p className="text-xs text-gray-500">
+              El código de cuenta es el del plan de cuentas del estudio (ej: 1.1.01). Solo hace falta completar los
+              conceptos que se usan: si en el mes hay movimientos de un concepto sin cuenta, la exportación avisa
+              cuáles faltan.
+            </p>
+
+            <div className="flex justify-end">
+              <button
+                onClick={handleGuardar}
+                disabled={invalidas || !sinGuardar || guardar.isPending}
+                className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+              >
+                {guardar.isPending && <Loader2 size={16} className="animate-spin" />}
+                Guardar cuentas
+              </button>
+            </div>
+          </div>
+        )}
+      </div>
+    </section>
+  );
+}
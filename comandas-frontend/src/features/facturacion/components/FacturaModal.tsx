@@ -0,0 +1,307 @@
+import { useState } from 'react';
+import { X, FileText, Loader2, Printer, ShieldCheck, AlertTriangle, RefreshCw } from 'lucide-react';
+import {
+  useFacturaPedido,
+  useConsultarContribuyente,
+  useEmitirFactura,
+  useAutorizarFactura,
+  useImprimirFactura,
+} from '../hooks/useFacturacion';
+import useToast from '../../../hooks/useToast';
+import { CONDICION_IVA_LABELS, type CondicionIva, type Contribuyente, type Factura } from '../types';
+
+interface FacturaModalProps {
+  pedidoId: string;
+  onClose: () => void;
+}
+
+const CONDICIONES_CON_CUIT = (Object.keys(CONDICION_IVA_LABELS) as CondicionIva[]).filter(
+  (c) => c !== 'CONSUMIDOR_FINAL'
+);
+
+function formatMonto(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+/**
+ * Modal de facturación de un pedido cerrado (HU-115).
+ *
+ * Sin factura: se elige consumidor final o se ingresa el CUIT/CUIL del
+ * cliente, que se valida contra el padrón. La letra la decide el backend
+ * según la condición del local y la del cliente. Al emitir se imprime.
+ *
+ * Con factura: muestra el comprobante, permite reimprimirlo y, si quedó
+ * pendiente de autorización, reintentar el CAE.
+ */
+export default function FacturaModal({ pedidoId, onClose }: FacturaModalProps) {
+  const toast = useToast();
+  const { data: factura, isLoading } = useFacturaPedido(pedidoId);
+  const consultar = useConsultarContribuyente();
+  const emitir = useEmitirFactura();
+  const autorizar = useAutorizarFactura();
+  const imprimir = useImprimirFactura();
+
+  const [conCuit, setConCuit] = useState(false);
+  const [cuit, setCuit] = useState('');
+  const [contribuyente, setContribuyente] = useState<Contribuyente | null>(null);
+  const [razonSocial, setRazonSocial] = useState('');
+  const [condicion, setCondicion] = useState<CondicionIva>('RESPONSABLE_INSCRIPTO');
+
+  const imprimirFactura = (f: Factura, reimpresion: boolean) =>
+    imprimir.mutate(
+      { factura: f, reimpresion },
+      {
+        onError: (err: any) => toast.error(err?.message || 'Error al imprimir la factura'),
+      }
+    );
+
+  const handleValidar = () => {
+    consultar.mutate(cuit, {
+      onSuccess: (resultado) => {
+        setContribuyente(resultado);
+        setCuit(resultado.cuit);
+        if (resultado.validadoEnPadron) {
+          setRazonSocial(resultado.razonSocial ?? '');
+          setCondicion(resultado.condicionIva ?? 'RESPONSABLE_INSCRIPTO');
+        }
+      },
+      onError: (err: any) => {
+        setContribuyente(null);
+        toast.error(err?.response?.data?.message || 'CUIT inválido');
+      },
+    });
+  };
+
+  const handleEmitir = () => {
+    emitir.mutate(
+      conCuit
+        ? { pedidoId, cuit, razonSocial: razonSocial.trim() || undefined, condicionIva: condicion }
+        : { pedidoId, condicionIva: 'CONSUMIDOR_FINAL' },
+      {
+        onSuccess: (emitida) => {
+          if (emitida.estado === 'AUTORIZADA') {
+            toast.success(`Factura ${emitida.tipo} ${emitida.numero} autorizada`);
+          } else {
+            toast.warning(`Factura ${emitida.tipo} ${emitida.numero} pendiente de autorización`);
+          }
+          imprimirFactura(emitida, false);
+        },
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'Error al emitir la factura'),
+      }
+    );
+  };
+
+  const handleAutorizar = (f: Factura) =>
+    autorizar.mutate(f.id, {
+      onSuccess: (autorizada) => toast.success(`Factura ${autorizada.numero} autorizada`),
+      onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo autorizar la factura'),
+    });
+
+  const cuitValidado = contribuyente !== null && contribuyente.cuit === cuit;
+  const puedeEmitir = !conCuit || (cuitValidado && razonSocial.trim() !== '');
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div className="fixed inset-0 z-[95] bg-black/60 animate-backdrop-in" onClick={onClose} />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[100] flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="bg-neutral-900 border border-gray-800 rounded-xl w-full max-w-md pointer-events-auto animate-modal-in"
+          onClick={(e) => e.stopPropagation()}
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-gray-800">
+            <div className="flex items-center gap-3">
+              <FileText size={20} className="text-red-400" />
+              <div>
+                <h2 className="text-lg font-semibold text-text-primary">Factura</h2>
+                <p className="text-sm text-text-secondary">
+                  {factura ? `${factura.tipo} ${factura.numero}` : 'Pedido cerrado sin facturar'}
+                </p>
+              </div>
+            </div>
+            <button
+              onClick={onClose}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+            >
+              <X size={18} />
+            </button>
+          </div>
+
+          {/* Body */}
+          <div className="px-6 py-5 space-y-4">
+            {isLoading ? (
+              <div className="flex justify-center py-8">
+                <Loader2 size={24} className="animate-spin text-gray-500" />
+              </div>
+            ) : factura ? (
+              <>
+                <div className="space-y-1 text-sm">
+                  <p className="text-text-primary font-medium">
+                    {factura.cuit ? factura.razonSocial : 'Consumidor Final'}
+                  </p>
+                  {factura.cuit && <p className="text-gray-400 font-mono">CUIT {factura.cuit}</p>}
+                  <p className="text-gray-500">{CONDICION_IVA_LABELS[factura.condicionIva]}</p>
+                </div>
+
+                <div className="bg-neutral-800/40 rounded-lg px-4 py-3 space-y-1 text-sm">
+                  {factura.tipo === 'A' && (
+                    <>
+                      <div className="flex justify-between text-gray-400">
+                        <span>Neto gravado</span>
+                        <span className="font-mono">$ {formatMonto(factura.importeNeto)}</span>
+                      </div>
+                      <div className="flex justify-between text-gray-400">
+                        <span>IVA {factura.alicuotaIva}%</span>
+                        <span className="font-mono">$ {formatMonto(factura.importeIva)}</span>
+                      </div>
+                    </>
+                  )}
+                  <div className="flex justify-between text-text-primary font-semibold">
+                    <span>Total</span>
+                    <span className="font-mono">$ {formatMonto(factura.importeTotal)}</span>
+                  </div>
+                </div>
+
+                {factura.estado === 'AUTORIZADA' ? (
+                  <div className="flex items-center gap-2 text-sm text-green-400">
+                    <ShieldCheck size={16} />
+                    CAE {factura.cae} · vence {factura.caeVencimiento}
+                  </div>
+                ) : (
+                  <div className="flex items-start gap-2 text-sm text-yellow-400">
+                    <AlertTriangle size={16} className="shrink-0 mt-0.5" />
+                    Pendiente de autorización: no es válida como factura hasta obtener el CAE.
+                  </div>
+                )}
+              </>
+            ) : (
+              <>
+                <div className="flex gap-2">
+                  {[false, true].map((opcion) => (
+                    <button
+                      key={String(opcion)}
+                      onClick={() => setConCuit(opcion)}
+                      className={`flex-1 h-10 rounded-lg text-sm font-medium border transition-colors ${
+                        conCuit === opcion
+                          ? 'bg-red-600/20 border-red-600 text-red-300'
+                          : 'bg-neutral-800 border-neutral-700 text-gray-400 hover:border-neutral-600'
+                      }`}
+                    >
+                      {opcion ? 'Con CUIT' : 'Consumidor final'}
+                    </button>
+                  ))}
+                </div>
+
+                {conCuit && (
+                  <div className="space-y-3">
+                    <div className="flex gap-2">
+                      <input
+                        type="text"
+                        inputMode="numeric"
+                        value={cuit}
+                        onChange={(e) => setCuit(e.target.value)}
+                        placeholder="CUIT / CUIL"
+                        className="flex-1 min-h-[44px] px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary font-mono focus:border-primary focus:outline-none"
+                      />
+                      <button
+                        onClick={handleValidar}
+                        disabled={!cuit.trim() || consultar.isPending}
+                        className="btn-secondary text-sm !min-h-[44px] px-4 flex items-center gap-2"
+                      >
+                        {consultar.isPending && <Loader2 size={14} className="animate-spin" />}
+                        Validar
+                      </button>
+                    </div>
+
+                    {contribuyente && cuitValidado && (
+                      <>
+                        {contribuyente.validadoEnPadron ? (
+                          <p className="flex items-center gap-2 text-xs text-green-400">
+                            <ShieldCheck size={14} />
+                            Validado en padrón · Factura {contribuyente.tipoFactura}
+                          </p>
+                        ) : (
+                          <p className="flex items-center gap-2 text-xs text-yellow-400">
+                            <AlertTriangle size={14} />
+                            CUIT válido. Padrón no disponible: completar los datos del cliente.
+                          </p>
+                        )}
+                        <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                          Razón social
+                          <input
+                            type="text"
+                            value={razonSocial}
+                            maxLength={120}
+                            disabled={contribuyente.validadoEnPadron}
+                            onChange={(e) => setRazonSocial(e.target.value)}
+                            className="min-h-[44px] px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none disabled:opacity-60"
+                          />
+                        </label>
+                        <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                          Condición frente al IVA
+                          <select
+                            value={condicion}
+                            disabled={contribuyente.validadoEnPadron}
+                            onChange={(e) => setCondicion(e.target.value as CondicionIva)}
+                            className="min-h-[44px] px-2 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none disabled:opacity-60"
+                          >
+                            {CONDICIONES_CON_CUIT.map((c) => (
+                              <option key={c} value={c}>
+                                {CONDICION_IVA_LABELS[c]}
+                              </option>
+                            ))}
+                          </select>
+                        </label>
+                      </>
+                    )}
+                  </div>
+                )}
+              </>
+            )}
+          </div>
+
+          {/* Footer */}
+          <div className="flex justify-end gap-3 px-6 py-4 border-t border-gray-800">
+            <button onClick={onClose} className="btn-secondary text-sm !min-h-[42px] px-5">
+              Cerrar
+            </button>
+            {factura ? (
+              <>
+                {factura.estado === 'PENDIENTE_AUTORIZACION' && (
+                  <button
+                    onClick={() => handleAutorizar(factura)}
+                    disabled={autorizar.isPending}
+                    className="btn-secondary text-sm !min-h-[42px] px-4 flex items-center gap-2"
+                  >
+                    {autorizar.isPending ? <Loader2 size={16} className="animate-spin" /> : <RefreshCw size={16} />}
+                    Autorizar
+                  </button>
+                )}
+                <button
+                  onClick={() => imprimirFactura(factura, true)}
+                  disabled={imprimir.isPending}
+                  className="btn-primary text-sm !min-h-[42px] px-5 flex items-center gap-2"
+                >
+                  {imprimir.isPending ? <Loader2 size={16} className="animate-spin" /> : <Printer size={16} />}
+                  Reimprimir
+                </button>
+              </>
+            ) : (
+              <button
+                onClick={handleEmitir}
+                disabled={!puedeEmitir || emitir.isPending || isLoading}
+                className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+              >
+                {emitir.isPending && <Loader2 size={16} className="animate-spin" />}
+                Emitir factura
+              </button>
+            )}
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
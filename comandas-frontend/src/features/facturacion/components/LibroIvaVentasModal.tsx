@@ -0,0 +1,125 @@
+import { useState } from 'react';
+import { X, FileSpreadsheet, Loader2, Download } from 'lucide-react';
+import { useDescargarLibroIvaVentas } from '../hooks/useFacturacion';
+import useToast from '../../../hooks/useToast';
+import type { FormatoLibroIva } from '../types';
+
+interface LibroIvaVentasModalProps {
+  onClose: () => void;
+}
+
+const FORMATOS: { value: FormatoLibroIva; label: string; detalle: string }[] = [
+  { value: 'CSV', label: 'CSV', detalle: 'Planilla (Excel)' },
+  { value: 'TXT', label: 'TXT', detalle: 'Régimen de información (ZIP)' },
+];
+
+/** Mes anterior en formato yyyy-MM: es el que se le pasa al contador */
+function mesAnterior(): string {
+  const hoy = new Date();
+  const d = new Date(hoy.getFullYear(), hoy.getMonth() - 1, 1);
+  return `${d.getFullYear()}-${String(d.getMonth() + 1).padStart(2, '0')}`;
+}
+
+/**
+ * Exportación del libro IVA ventas del mes (HU-116).
+ *
+ * Solo entran las facturas autorizadas con CAE; las pendientes no son
+ * comprobantes válidos hasta que se autorizan.
+ */
+export default function LibroIvaVentasModal({ onClose }: LibroIvaVentasModalProps) {
+  const toast = useToast();
+  const descargar = useDescargarLibroIvaVentas();
+
+  const [periodo, setPeriodo] = useState(mesAnterior);
+  const [formato, setFormato] = useState<FormatoLibroIva>('CSV');
+
+  const handleDescargar = () => {
+    descargar.mutate(
+      { periodo, formato },
+      {
+        onSuccess: () => {
+          toast.success('Libro IVA ventas descargado');
+          onClose();
+        },
+        onError: () => toast.error('No se pudo generar el libro IVA ventas'),
+      }
+    );
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div className="fixed inset-0 z-[95] bg-black/60 animate-backdrop-in" onClick={onClose} />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[100] flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="bg-neutral-900 border border-gray-800 rounded-xl w-full max-w-sm pointer-events-auto animate-modal-in"
+          onClick={(e) => e.stopPropagation()}
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-gray-800">
+            <div className="flex items-center gap-3">
+              <FileSpreadsheet size={20} className="text-red-400" />
+              <div>
+                <h2 className="text-lg font-semibold text-text-primary">Libro IVA ventas</h2>
+                <p className="text-sm text-text-secondary">Comprobantes autorizados del mes</p>
+              </div>
+            </div>
+            <button
+              onClick={onClose}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+            >
+              <X size={18} />
+            </button>
+          </div>
+
+          {/* Body */}
+          <div className="px-6 py-5 space-y-4">
+            <label className="flex flex-col gap-1 text-sm text-text-secondary">
+              Mes
+              <input
+                type="month"
+                value={periodo}
+                onChange={(e) => setPeriodo(e.target.value)}
+                className="min-h-[44px] px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none"
+              />
+            </label>
+
+            <div className="flex gap-2">
+              {FORMATOS.map((f) => (
+                <button
+                  key={f.value}
+                  onClick={() => setFormato(f.value)}
+                  className={`flex-1 py-2 rounded-lg text-sm border transition-colors ${
+                    formato === f.value
+                      ? 'bg-red-600/20 border-red-600 text-red-300'
+                      : 'bg-neutral-800 border-neutral-700 text-gray-400 hover:border-neutral-600'
+                  }`}
+                >
+                  <span className="block font-medium">{f.label}</span>
+                  <span className="block text-xs opacity-75">{f.detalle}</span>
+                </button>
+              ))}
+            </div>
+          </div>
+
+          {/* Footer */}
+          <div className="flex justify-end gap-3 px-6 py-4 border-t border-gray-800">
+            <button onClick={onClose} className="btn-secondary text-sm !min-h-[42px] px-5">
+              Cancelar
+            </button>
+            <button
+              onClick={handleDescargar}
+              disabled={!periodo || descargar.isPending}
+              className="btn-primary text-sm !min-h-[42px] px-5 flex items-center gap-2"
+            >
+              {descargar.isPending ? <Loader2 size={16} className="animate-spin" /> : <Download size={16} />}
+              Descargar
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
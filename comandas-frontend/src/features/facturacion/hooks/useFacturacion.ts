@@ -0,0 +1,118 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { facturacionApi } from '../api/facturacionApi';
+import { imprimirEscPos } from '../../pedido/services/printerService';
+import { descargarPdf } from '../../caja/services/pdfService';
+import type { EmitirFacturaRequest, Factura, FormatoLibroIva, PlanCuentasContable, SistemaContable } from '../types';
+
+export const facturacionKeys = {
+  porPedido: (pedidoId: string) => ['factura-pedido', pedidoId] as const,
+  planCuentas: ['plan-cuentas-contable'] as const,
+};
+
+/**
+ * Factura de un pedido cerrado, null si todavía no se facturó.
+ *
+ * queryKey: ['factura-pedido', pedidoId]
+ */
+export function useFacturaPedido(pedidoId: string | null) {
+  return useQuery<Factura | null>({
+    queryKey: facturacionKeys.porPedido(pedidoId ?? ''),
+    queryFn: () => facturacionApi.buscarPorPedido(pedidoId!),
+    enabled: !!pedidoId,
+  });
+}
+
+/**
+ * Validación de CUIT bajo demanda (al tocar "Validar"), no al tipear.
+ */
+export function useConsultarContribuyente() {
+  return useMutation({
+    mutationFn: (cuit: string) => facturacionApi.consultarContribuyente(cuit),
+  });
+}
+
+export function useEmitirFactura() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (request: EmitirFacturaRequest) => facturacionApi.emitir(request),
+    onSuccess: (factura) => {
+      queryClient.setQueryData(facturacionKeys.porPedido(factura.pedidoId), factura);
+    },
+    onError: (error: unknown) => {
+      console.error('[useEmitirFactura] Error al emitir factura:', error);
+    },
+  });
+}
+
+export function useAutorizarFactura() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (facturaId: string) => facturacionApi.autorizar(facturaId),
+    onSuccess: (factura) => {
+      queryClient.setQueryData(facturacionKeys.porPedido(factura.pedidoId), factura);
+    },
+  });
+}
+
+/**
+ * Genera el ESC/POS de la factura y lo manda a la impresora de tickets.
+ */
+export function useImprimirFactura() {
+  return useMutation({
+    mutationFn: async ({ factura, reimpresion }: { factura: Factura; reimpresion: boolean }) => {
+      const base64 = await facturacionApi.generarEscPos(factura.id, reimpresion);
+      const result = await imprimirEscPos(base64, `Factura ${factura.tipo} ${factura.numero}`);
+      if (!result.success) {
+        throw new Error(result.message);
+      }
+      return result;
+    },
+  });
+}
+
+/**
+ * Descarga el libro IVA ventas del mes para el contador (HU-116).
+ */
+export function useDescargarLibroIvaVentas() {
+  return useMutation({
+    mutationFn: async ({ periodo, formato }: { periodo: string; formato: FormatoLibroIva }) => {
+      const blob = await facturacionApi.descargarLibroIvaVentas(periodo, formato);
+      const base = `LIBRO_IVA_VENTAS_${periodo.replace('-', '')}`;
+      descargarPdf(blob, formato === 'CSV' ? `${base}.csv` : `${base}.zip`);
+    },
+  });
+}
+
+// ─── HU-167: Exportación al sistema contable ─────────────────────────────────
+
+export function usePlanCuentasContable() {
+  return useQuery<PlanCuentasContable>({
+    queryKey: facturacionKeys.planCuentas,
+    queryFn: () => facturacionApi.obtenerPlanCuentas(),
+    staleTime: 5 * 60 * 1000,
+  });
+}
+
+export function useGuardarPlanCuentasContable() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (data: PlanCuentasContable) => facturacionApi.guardarPlanCuentas(data),
+    onSuccess: (plan) => {
+      queryClient.setQueryData(facturacionKeys.planCuentas, plan);
+    },
+  });
+}
+
+/** Tango importa TXT; Xubio y Contabilium, CSV */
+export function useDescargarAsientosContables() {
+  return useMutation({
+    mutationFn: async ({ periodo, sistema }: { periodo: string; sistema: SistemaContable }) => {
+      const blob = await facturacionApi.descargarAsientosContables(periodo, sistema);
+      const extension = sistema === 'TANGO' ? 'txt' : 'csv';
+      descargarPdf(blob, `ASIENTOS_${sistema}_${periodo.replace('-', '')}.${extension}`);
+    },
+  });
+}
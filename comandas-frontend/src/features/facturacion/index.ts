@@ -0,0 +1,43 @@
+/**
+ * Módulo Facturación — Factura A/B/C de pedidos cerrados (HU-115),
+ * libro IVA ventas (HU-116) y exportación al sistema contable (HU-167).
+ *
+ * @example
+ * import { FacturaModal } from '@/features/facturacion';
+ */
+
+// Tipos
+export type {
+  CondicionIva,
+  TipoFactura,
+  EstadoFactura,
+  Contribuyente,
+  EmitirFacturaRequest,
+  Factura,
+  FormatoLibroIva,
+  SistemaContable,
+  ConceptoContable,
+  PlanCuentasContable,
+} from './types';
+export { CONDICION_IVA_LABELS, CONCEPTO_CONTABLE_LABELS } from './types';
+
+// Hooks
+export {
+  useFacturaPedido,
+  useConsultarContribuyente,
+  useEmitirFactura,
+  useAutorizarFactura,
+  useImprimirFactura,
+  useDescargarLibroIvaVentas,
+  usePlanCuentasContable,
+  useGuardarPlanCuentasContable,
+  useDescargarAsientosContables,
+} from './hooks/useFacturacion';
+
+// Componentes
+export { default as FacturaModal } from './components/FacturaModal';
+export { default as LibroIvaVentasModal } from './components/LibroIvaVentasModal';
+export { default as ExportacionContablePage } from './components/ExportacionContablePage';
+
+// API
+export { facturacionApi } from './api/facturacionApi';
@@ -0,0 +1,105 @@
+// ─── Facturación (HU-115) ─────────────────────────────────────────────────────
+
+export type CondicionIva = 'RESPONSABLE_INSCRIPTO' | 'MONOTRIBUTO' | 'EXENTO' | 'CONSUMIDOR_FINAL';
+
+export type TipoFactura = 'A' | 'B' | 'C';
+
+export type EstadoFactura = 'PENDIENTE_AUTORIZACION' | 'AUTORIZADA';
+
+export const CONDICION_IVA_LABELS: Record<CondicionIva, string> = {
+  RESPONSABLE_INSCRIPTO: 'Responsable Inscripto',
+  MONOTRIBUTO: 'Monotributo',
+  EXENTO: 'Exento',
+  CONSUMIDOR_FINAL: 'Consumidor Final',
+};
+
+/**
+ * Resultado de validar un CUIT/CUIL.
+ * Sin padrón (validadoEnPadron = false) razón social, condición y letra llegan en null.
+ */
+export interface Contribuyente {
+  cuit: string;
+  validadoEnPadron: boolean;
+  razonSocial: string | null;
+  condicionIva: CondicionIva | null;
+  tipoFactura: TipoFactura | null;
+}
+
+export interface EmitirFacturaRequest {
+  pedidoId: string;
+  cuit?: string;
+  razonSocial?: string;
+  condicionIva: CondicionIva;
+}
+
+export interface Factura {
+  id: string;
+  pedidoId: string;
+  tipo: TipoFactura;
+  /** PPPPP-NNNNNNNN */
+  numero: string;
+  fechaEmision: string;
+  cuit: string | null;
+  razonSocial: string | null;
+  condicionIva: CondicionIva;
+  alicuotaIva: number;
+  importeNeto: number;
+  importeIva: number;
+  importeTotal: number;
+  estado: EstadoFactura;
+  cae: string | null;
+  caeVencimiento: string | null;
+}
+
+/** HU-116: CSV para planilla, TXT del régimen de información (ZIP) */
+export type FormatoLibroIva = 'CSV' | 'TXT';
+
+// ─── Exportación al sistema contable (HU-167) ────────────────────────────────
+
+export type SistemaContable = 'TANGO' | 'XUBIO' | 'CONTABILIUM';
+
+/** Concepto de los asientos al que el estudio le asigna una cuenta */
+export type ConceptoContable =
+  | 'VENTAS'
+  | 'IVA_DEBITO_FISCAL'
+  | 'EFECTIVO'
+  | 'TARJETA'
+  | 'TRANSFERENCIA'
+  | 'QR'
+  | 'CUENTA_CORRIENTE_CLIENTES'
+  | 'TARJETA_REGALO'
+  | 'PROVEEDORES'
+  | 'GASTO_MERCADERIA'
+  | 'GASTO_ALQUILER'
+  | 'GASTO_SERVICIOS'
+  | 'GASTO_SUELDOS'
+  | 'GASTO_IMPUESTOS'
+  | 'GASTO_MANTENIMIENTO'
+  | 'GASTO_OTROS';
+
+export const CONCEPTO_CONTABLE_LABELS: Record<ConceptoContable, string> = {
+  VENTAS: 'Ventas',
+  IVA_DEBITO_FISCAL: 'IVA débito fiscal',
+  EFECTIVO: 'Caja (efectivo)',
+  TARJETA: 'Tarjetas',
+  TRANSFERENCIA: 'Banco (transferencias)',
+  QR: 'Billeteras QR',
+  CUENTA_CORRIENTE_CLIENTES: 'Deudores por ventas (cuenta corriente)',
+  TARJETA_REGALO: 'Gift cards vendidas',
+  PROVEEDORES: 'Proveedores',
+  GASTO_MERCADERIA: 'Compras de mercadería',
+  GASTO_ALQUILER: 'Alquiler',
+  GASTO_SERVICIOS: 'Servicios',
+  GASTO_SUELDOS: 'Sueldos',
+  GASTO_IMPUESTOS: 'Impuestos',
+  GASTO_MANTENIMIENTO: 'Mantenimiento',
+  GASTO_OTROS: 'Otros gastos',
+};
+
+/**
+ * Cuentas del plan del estudio contable por concepto.
+ * Refleja PlanCuentasContableResponse del backend: solo los conceptos con cuenta.
+ */
+export interface PlanCuentasContable {
+  cuentas: Partial<Record<ConceptoContable, string>>;
+}
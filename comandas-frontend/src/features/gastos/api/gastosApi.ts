@@ -0,0 +1,35 @@
+import apiClient from '../../../lib/apiClient';
+import type { ComprobanteRequest, Gasto, GastoRequest, ReporteGastos } from '../types';
+
+/**
+ * API client de compras y gastos (HU-117).
+ * Consume /api/gastos de GastoController.
+ */
+export const gastosApi = {
+  /** @param desde,hasta fechas ISO (YYYY-MM-DD), ambas inclusive */
+  listar: async (desde: string, hasta: string): Promise<Gasto[]> => {
+    const response = await apiClient.get<Gasto[]>('/gastos', { params: { desde, hasta } });
+    return response.data;
+  },
+
+  registrar: async (request: GastoRequest): Promise<Gasto> => {
+    const response = await apiClient.post<Gasto>('/gastos', request);
+    return response.data;
+  },
+
+  adjuntarComprobante: async (gastoId: string, comprobante: ComprobanteRequest): Promise<Gasto> => {
+    const response = await apiClient.put<Gasto>(`/gastos/${gastoId}/comprobante`, comprobante);
+    return response.data;
+  },
+
+  obtenerComprobante: async (gastoId: string): Promise<Blob> => {
+    const response = await apiClient.get(`/gastos/${gastoId}/comprobante`, { responseType: 'blob' });
+    return response.data;
+  },
+
+  /** @param periodo mes en formato YYYY-MM */
+  obtenerReporte: async (periodo: string): Promise<ReporteGastos> => {
+    const response = await apiClient.get<ReporteGastos>('/gastos/reporte', { params: { periodo } });
+    return response.data;
+  },
+};
@@ -0,0 +1,317 @@
+import { useRef, useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Calendar, ClipboardList, Download, FileText, Loader2, Paperclip, Plus, Receipt, Truck } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { generarCsv, descargarCsv } from '../../../lib/csv';
+import { useGastos, useReporteGastos, useAdjuntarComprobante, useDescargarComprobante } from '../hooks/useGastos';
+import { leerComprobante, TIPOS_COMPROBANTE } from '../utils/comprobante';
+import { CATEGORIA_GASTO_LABELS, MEDIO_PAGO_GASTO_LABELS, type Gasto } from '../types';
+import RegistrarGastoModal from './RegistrarGastoModal';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function mesActual(): string {
+  const hoy = new Date();
+  return `${hoy.getFullYear()}-${String(hoy.getMonth() + 1).padStart(2, '0')}`;
+}
+
+/** Primer y último día del mes (YYYY-MM) */
+function rangoDelMes(periodo: string): [string, string] {
+  const [y, m] = periodo.split('-').map(Number);
+  const ultimo = new Date(y, m, 0).getDate();
+  return [`${periodo}-01`, `${periodo}-${String(ultimo).padStart(2, '0')}`];
+}
+
+function fechaCorta(iso: string): string {
+  const [y, m, d] = iso.split('-');
+  return `${d}/${m}/${y}`;
+}
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+function exportarGastos(gastos: Gasto[], periodo: string) {
+  descargarCsv(
+    generarCsv(
+      ['Fecha', 'Categoría', 'Proveedor', 'Descripción', 'Medio de pago', 'Pagado con caja', 'Monto', 'Comprobante'],
+      gastos.map((g) => [
+        fechaCorta(g.fecha),
+        CATEGORIA_GASTO_LABELS[g.categoria],
+        g.proveedor,
+        g.descripcion,
+        MEDIO_PAGO_GASTO_LABELS[g.medioPago],
+        g.pagadoConCaja ? 'Sí' : 'No',
+        g.monto,
+        g.nombreArchivoComprobante,
+      ]),
+    ),
+    `gastos_${periodo}.csv`,
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Compras a proveedores y gastos del mes (HU-117).
+ *
+ * Arriba el resultado del mes: ventas contra gastos agrupados por categoría
+ * (más los egresos de caja que se cargaron sin registrar un gasto). Abajo el
+ * detalle, donde se adjunta el comprobante si llegó después.
+ */
+export default function GastosPage() {
+  const toast = useToast();
+  const [periodo, setPeriodo] = useState(mesActual);
+  const [modalAbierto, setModalAbierto] = useState(false);
+  const [gastoAdjuntando, setGastoAdjuntando] = useState<string | null>(null);
+  const inputArchivoRef = useRef<HTMLInputElement>(null);
+
+  const [desde, hasta] = rangoDelMes(periodo);
+  const { data: reporte, isLoading: cargandoReporte, isError } = useReporteGastos(periodo);
+  const { data: gastos = [], isLoading: cargandoGastos } = useGastos(desde, hasta);
+  const adjuntar = useAdjuntarComprobante();
+  const descargar = useDescargarComprobante();
+
+  const elegirArchivo = (gastoId: string) => {
+    setGastoAdjuntando(gastoId);
+    inputArchivoRef.current?.click();
+  };
+
+  const handleArchivo = async (file: File | undefined) => {
+    if (inputArchivoRef.current) inputArchivoRef.current.value = '';
+    if (!file || !gastoAdjuntando) return;
+    try {
+      const comprobante = await leerComprobante(file);
+      await adjuntar.mutateAsync({ gastoId: gastoAdjuntando, comprobante });
+      toast.success('Comprobante adjuntado');
+    } catch (err: any) {
+      toast.error(err?.response?.data?.message || err?.message || 'No se pudo adjuntar el comprobante');
+    } finally {
+      setGastoAdjuntando(null);
+    }
+  };
+
+  const handleDescargar = (gasto: Gasto) =>
+    descargar.mutate(gasto, {
+      onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo descargar el comprobante'),
+    });
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/historial"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Gastos</h1>
+            <p className="text-sm text-gray-500">Compras y gastos contra ventas del mes</p>
+          </div>
+          <Link
+            to="/caja/inventario"
+            className="ml-auto h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <ClipboardList size={14} />
+            Inventario
+          </Link>
+          <Link
+            to="/caja/proveedores"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Truck size={14} />
+            Proveedores
+          </Link>
+          <button
+            type="button"
+            onClick={() => setModalAbierto(true)}
+            className="h-9 px-3 rounded-lg bg-red-600 hover:bg-red-700 text-sm text-white flex items-center gap-1.5"
+          >
+            <Plus size={14} />
+            Nuevo gasto
+          </button>
+        </header>
+
+        <div className="flex items-center gap-3">
+          <Calendar size={14} className="text-gray-500" />
+          <input
+            type="month"
+            value={periodo}
+            max={mesActual()}
+            onChange={(e) => e.target.value && setPeriodo(e.target.value)}
+            className={inputFecha}
+          />
+          <button
+            type="button"
+            onClick={() => exportarGastos(gastos, periodo)}
+            disabled={gastos.length === 0}
+            className="ml-auto h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors disabled:opacity-40"
+          >
+            <Download size={14} />
+            Exportar CSV
+          </button>
+        </div>
+
+        {/* Resultado del mes */}
+        {cargandoReporte ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando reporte...
+          </div>
+        ) : isError ? (
+          <p className="text-sm text-red-400">No se pudo cargar el reporte de gastos.</p>
+        ) : reporte && (
+          <div className="grid grid-cols-1 xl:grid-cols-12 gap-5">
+            <div className="xl:col-span-4 grid grid-cols-2 gap-3 content-start">
+              {[
+                { label: 'Ventas', valor: `$ ${fmt(reporte.totalVentas)}`, color: 'text-gray-100' },
+                { label: 'Gastos', valor: `$ ${fmt(reporte.totalGastos)}`, color: 'text-gray-100' },
+                {
+                  label: 'Resultado',
+                  valor: `$ ${fmt(reporte.resultado)}`,
+                  color: reporte.resultado < 0 ? 'text-red-400' : 'text-emerald-400',
+                },
+                { label: '% sobre ventas', valor: `${fmt(reporte.porcentajeGastos)} %`, color: 'text-gray-100' },
+              ].map((card) => (
+                <div key={card.label} className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 px-4 py-3">
+                  <p className="text-[10px] uppercase tracking-wider text-gray-600">{card.label}</p>
+                  <p className={`text-lg font-mono tabular-nums ${card.color}`}>{card.valor}</p>
+                </div>
+              ))}
+            </div>
+
+            <div className="xl:col-span-8 rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+              <table className="w-full text-sm">
+                <thead>
+                  <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                    <th className="text-left font-medium px-4 py-2">Categoría</th>
+                    <th className="text-right font-medium px-3 py-2">Comprobantes</th>
+                    <th className="text-right font-medium px-3 py-2">Total</th>
+                    <th className="text-right font-medium px-4 py-2">% ventas</th>
+                  </tr>
+                </thead>
+                <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+                  {reporte.categorias.map((c) => (
+                    <tr key={c.categoria}>
+                      <td className="px-4 py-2 font-sans text-gray-100">{CATEGORIA_GASTO_LABELS[c.categoria]}</td>
+                      <td className="text-right px-3 py-2">{c.cantidad}</td>
+                      <td className="text-right px-3 py-2">$ {fmt(c.total)}</td>
+                      <td className="text-right px-4 py-2">{fmt(c.porcentajeSobreVentas)} %</td>
+                    </tr>
+                  ))}
+                  {reporte.otrosEgresosCaja > 0 && (
+                    <tr>
+                      <td
+                        className="px-4 py-2 font-sans text-gray-500 italic"
+                        title="Egresos cargados en la caja sin registrar un gasto"
+                      >
+                        Otros egresos de caja
+                      </td>
+                      <td className="text-right px-3 py-2">—</td>
+                      <td className="text-right px-3 py-2">$ {fmt(reporte.otrosEgresosCaja)}</td>
+                      <td className="text-right px-4 py-2">—</td>
+                    </tr>
+                  )}
+                  {reporte.categorias.length === 0 && reporte.otrosEgresosCaja === 0 && (
+                    <tr>
+                      <td colSpan={4} className="px-4 py-3 font-sans text-xs text-gray-500">
+                        No hay gastos registrados en este mes.
+                      </td>
+                    </tr>
+                  )}
+                </tbody>
+              </table>
+            </div>
+          </div>
+        )}
+
+        {/* Detalle */}
+        {cargandoGastos ? null : gastos.length > 0 && (
+          <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+            <table className="w-full text-sm">
+              <thead>
+                <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                  <th className="text-left font-medium px-4 py-2">Fecha</th>
+                  <th className="text-left font-medium px-3 py-2">Categoría</th>
+                  <th className="text-left font-medium px-3 py-2">Detalle</th>
+                  <th className="text-left font-medium px-3 py-2">Medio</th>
+                  <th className="text-right font-medium px-3 py-2">Monto</th>
+                  <th className="text-right font-medium px-4 py-2">Comprobante</th>
+                </tr>
+              </thead>
+              <tbody className="divide-y divide-neutral-800 text-gray-300">
+                {gastos.map((g) => (
+                  <tr key={g.id}>
+                    <td className="px-4 py-2 font-mono text-xs text-gray-400">{fechaCorta(g.fecha)}</td>
+                    <td className="px-3 py-2">{CATEGORIA_GASTO_LABELS[g.categoria]}</td>
+                    <td className="px-3 py-2">
+                      <span className="text-gray-100">{g.proveedor ?? g.descripcion}</span>
+                      {g.proveedor && g.descripcion && <span className="text-gray-500"> · {g.descripcion}</span>}
+                      {g.items.length > 0 && (
+                        <p className="text-xs text-gray-500">
+                          {g.items.map((i) => `${i.cantidad} × ${i.nombreProducto}`).join(', ')}
+                        </p>
+                      )}
+                    </td>
+                    <td className="px-3 py-2 text-gray-400">
+                      {MEDIO_PAGO_GASTO_LABELS[g.medioPago]}
+                      {g.pagadoConCaja && <span className="text-xs text-gray-600"> (caja)</span>}
+                    </td>
+                    <td className="text-right px-3 py-2 font-mono tabular-nums">$ {fmt(g.monto)}</td>
+                    <td className="text-right px-4 py-2">
+                      {g.nombreArchivoComprobante ? (
+                        <button
+                          type="button"
+                          onClick={() => handleDescargar(g)}
+                          title={g.nombreArchivoComprobante}
+                          className="inline-flex items-center gap-1 text-xs text-gray-400 hover:text-gray-200"
+                        >
+                          <FileText size={14} />
+                          Ver
+                        </button>
+                      ) : (
+                        <button
+                          type="button"
+                          onClick={() => elegirArchivo(g.id)}
+                          disabled={adjuntar.isPending}
+                          className="inline-flex items-center gap-1 text-xs text-red-400 hover:text-red-300 disabled:opacity-40"
+                        >
+                          {gastoAdjuntando === g.id && adjuntar.isPending ? (
+                            <Loader2 size={14} className="animate-spin" />
+                          ) : (
+                            <Paperclip size={14} />
+                          )}
+                          Adjuntar
+                        </button>
+                      )}
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+        )}
+        {!cargandoGastos && gastos.length === 0 && (
+          <div className="flex items-center gap-2 text-xs text-gray-500">
+            <Receipt size={14} />
+            Todavía no se registraron compras ni gastos este mes.
+          </div>
+        )}
+
+        <input
+          ref={inputArchivoRef}
+          type="file"
+          accept={TIPOS_COMPROBANTE}
+          onChange={(e) => handleArchivo(e.target.files?.[0])}
+          className="hidden"
+        />
+      </div>
+
+      {modalAbierto && <RegistrarGastoModal onClose={() => setModalAbierto(false)} />}
+    </section>
+  );
+}
@@ -0,0 +1,352 @@
+import { useMemo, useState } from 'react';
+import { X, Receipt, Loader2, Plus, Trash2, Paperclip } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useProductos } from '../../catalogo/hooks/useProductos';
+import { useProveedores } from '../../proveedores/hooks/useProveedores';
+import { useRegistrarGasto } from '../hooks/useGastos';
+import { leerComprobante, TIPOS_COMPROBANTE } from '../utils/comprobante';
+import {
+  CATEGORIA_GASTO_LABELS,
+  MEDIO_PAGO_GASTO_LABELS,
+  type CategoriaGasto,
+  type MedioPagoGasto,
+} from '../types';
+
+interface RegistrarGastoModalProps {
+  onClose: () => void;
+}
+
+interface ItemForm {
+  productoId: string;
+  cantidad: number;
+}
+
+const inputClass =
+  'min-h-[44px] w-full px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none';
+
+function hoyIso(): string {
+  const d = new Date();
+  return `${d.getFullYear()}-${String(d.getMonth() + 1).padStart(2, '0')}-${String(d.getDate()).padStart(2, '0')}`;
+}
+
+/**
+ * Alta de una compra o gasto (HU-117).
+ *
+ * En compras de mercadería se pueden cargar los productos recibidos, que
+ * ingresan al stock. Si el pago fue en efectivo con la plata de la caja,
+ * se registra también el egreso de caja para que el arqueo cierre.
+ *
+ * Una compra a cuenta corriente (HU-131) se hace a un proveedor cargado y
+ * genera la factura a pagar; el vencimiento sale del plazo del proveedor
+ * si no se indica otro.
+ */
+export default function RegistrarGastoModal({ onClose }: RegistrarGastoModalProps) {
+  const toast = useToast();
+  const registrar = useRegistrarGasto();
+  const { data: productos = [] } = useProductos(null, true);
+  const { data: proveedores = [] } = useProveedores();
+
+  const [fecha, setFecha] = useState(hoyIso);
+  const [categoria, setCategoria] = useState<CategoriaGasto>('MERCADERIA');
+  const [proveedor, setProveedor] = useState('');
+  const [descripcion, setDescripcion] = useState('');
+  const [monto, setMonto] = useState('');
+  const [medioPago, setMedioPago] = useState<MedioPagoGasto>('EFECTIVO');
+  const [pagadoConCaja, setPagadoConCaja] = useState(false);
+  const [items, setItems] = useState<ItemForm[]>([]);
+  const [archivo, setArchivo] = useState<File | null>(null);
+  const [proveedorId, setProveedorId] = useState('');
+  const [numeroFactura, setNumeroFactura] = useState('');
+  const [fechaVencimiento, setFechaVencimiento] = useState('');
+
+  const productosElegibles = useMemo(() => productos.filter((p) => !p.esExtra), [productos]);
+  const proveedoresActivos = useMemo(() => proveedores.filter((p) => p.activo), [proveedores]);
+
+  const aCuentaCorriente = medioPago === 'CUENTA_CORRIENTE';
+  const montoNumero = Number(monto.replace(',', '.'));
+  const itemsValidos = items.filter((i) => i.productoId && i.cantidad > 0);
+  const puedeGuardar =
+    montoNumero > 0 &&
+    (aCuentaCorriente ? proveedorId !== '' : proveedor.trim() !== '' || descripcion.trim() !== '') &&
+    (!fechaVencimiento || fechaVencimiento >= fecha) &&
+    itemsValidos.length === items.length &&
+    !registrar.isPending;
+
+  const cambiarItem = (idx: number, cambios: Partial<ItemForm>) =>
+    setItems((prev) => prev.map((item, i) => (i === idx ? { ...item, ...cambios } : item)));
+
+  const handleGuardar = async () => {
+    let comprobante;
+    try {
+      comprobante = archivo ? await leerComprobante(archivo) : undefined;
+    } catch (err: any) {
+      toast.error(err?.message || 'No se pudo leer el comprobante');
+      return;
+    }
+
+    registrar.mutate(
+      {
+        fecha,
+        categoria,
+        // En cuenta corriente el backend toma el nombre del proveedor cargado
+        proveedor: aCuentaCorriente ? undefined : proveedor.trim() || undefined,
+        descripcion: descripcion.trim() || undefined,
+        monto: montoNumero,
+        medioPago,
+        items: categoria === 'MERCADERIA' && items.length > 0 ? items : undefined,
+        pagadoConCaja: medioPago === 'EFECTIVO' && pagadoConCaja,
+        comprobante,
+        cuentaCorriente: aCuentaCorriente
+          ? {
+              proveedorId,
+              numeroFactura: numeroFactura.trim() || undefined,
+              fechaVencimiento: fechaVencimiento || undefined,
+            }
+          : undefined,
+      },
+      {
+        onSuccess: () => {
+          toast.success('Gasto registrado');
+          onClose();
+        },
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo registrar el gasto'),
+      }
+    );
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div className="fixed inset-0 z-[95] bg-black/60 animate-backdrop-in" onClick={onClose} />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[100] flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="bg-neutral-900 border border-gray-800 rounded-xl w-full max-w-lg max-h-[90vh] flex flex-col pointer-events-auto animate-modal-in"
+          onClick={(e) => e.stopPropagation()}
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-gray-800 shrink-0">
+            <div className="flex items-center gap-3">
+              <Receipt size={20} className="text-red-400" />
+              <h2 className="text-lg font-semibold text-text-primary">Nuevo gasto</h2>
+            </div>
+            <button
+              onClick={onClose}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+            >
+              <X size={18} />
+            </button>
+          </div>
+
+          {/* Body */}
+          <div className="px-6 py-5 space-y-4 overflow-y-auto">
+            <div className="grid grid-cols-2 gap-3">
+              <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                Categoría
+                <select
+                  value={categoria}
+                  onChange={(e) => setCategoria(e.target.value as CategoriaGasto)}
+                  className={inputClass}
+                >
+                  {(Object.keys(CATEGORIA_GASTO_LABELS) as CategoriaGasto[]).map((c) => (
+                    <option key={c} value={c}>
+                      {CATEGORIA_GASTO_LABELS[c]}
+                    </option>
+                  ))}
+                </select>
+              </label>
+              <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                Fecha del comprobante
+                <input
+                  type="date"
+                  value={fecha}
+                  max={hoyIso()}
+                  onChange={(e) => setFecha(e.target.value)}
+                  className={inputClass}
+                />
+              </label>
+            </div>
+
+            <label className="flex flex-col gap-1 text-sm text-text-secondary">
+              Proveedor
+              {aCuentaCorriente ? (
+                <select value={proveedorId} onChange={(e) => setProveedorId(e.target.value)} className={inputClass}>
+                  <option value="">Elegir proveedor…</option>
+                  {proveedoresActivos.map((p) => (
+                    <option key={p.id} value={p.id}>
+                      {p.nombre}
+                    </option>
+                  ))}
+                </select>
+              ) : (
+                <input
+                  type="text"
+                  value={proveedor}
+                  maxLength={100}
+                  onChange={(e) => setProveedor(e.target.value)}
+                  className={inputClass}
+                />
+              )}
+            </label>
+
+            <label className="flex flex-col gap-1 text-sm text-text-secondary">
+              Descripción
+              <input
+                type="text"
+                value={descripcion}
+                maxLength={200}
+                placeholder={categoria === 'ALQUILER' ? 'Ej: Alquiler marzo' : undefined}
+                onChange={(e) => setDescripcion(e.target.value)}
+                className={inputClass}
+              />
+            </label>
+
+            <div className="grid grid-cols-2 gap-3">
+              <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                Monto
+                <input
+                  type="text"
+                  inputMode="decimal"
+                  value={monto}
+                  onChange={(e) => setMonto(e.target.value)}
+                  placeholder="0,00"
+                  className={`${inputClass} font-mono`}
+                />
+              </label>
+              <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                Medio de pago
+                <select
+                  value={medioPago}
+                  onChange={(e) => setMedioPago(e.target.value as MedioPagoGasto)}
+                  className={inputClass}
+                >
+                  {(Object.keys(MEDIO_PAGO_GASTO_LABELS) as MedioPagoGasto[]).map((m) => (
+                    <option key={m} value={m}>
+                      {MEDIO_PAGO_GASTO_LABELS[m]}
+                    </option>
+                  ))}
+                </select>
+              </label>
+            </div>
+
+            {medioPago === 'EFECTIVO' && (
+              <label className="flex items-center gap-2 text-sm text-text-secondary">
+                <input
+                  type="checkbox"
+                  checked={pagadoConCaja}
+                  onChange={(e) => setPagadoConCaja(e.target.checked)}
+                  className="accent-red-600"
+                />
+                Se pagó con la plata de la caja (registra el egreso)
+              </label>
+            )}
+
+            {aCuentaCorriente && (
+              <div className="grid grid-cols-2 gap-3">
+                <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                  N° de factura
+                  <input
+                    type="text"
+                    value={numeroFactura}
+                    maxLength={30}
+                    placeholder="Opcional"
+                    onChange={(e) => setNumeroFactura(e.target.value)}
+                    className={`${inputClass} font-mono`}
+                  />
+                </label>
+                <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                  Vencimiento
+                  <input
+                    type="date"
+                    value={fechaVencimiento}
+                    min={fecha}
+                    onChange={(e) => setFechaVencimiento(e.target.value)}
+                    className={inputClass}
+                  />
+                </label>
+                {proveedoresActivos.length === 0 && (
+                  <p className="col-span-2 text-xs text-amber-400">
+                    No hay proveedores cargados. Dalos de alta en Proveedores.
+                  </p>
+                )}
+              </div>
+            )}
+
+            {categoria === 'MERCADERIA' && (
+              <div className="space-y-2">
+                <div className="flex items-center justify-between">
+                  <span className="text-sm text-text-secondary">Productos que ingresan al stock</span>
+                  <button
+                    type="button"
+                    onClick={() => setItems((prev) => [...prev, { productoId: '', cantidad: 1 }])}
+                    className="text-xs text-red-400 hover:text-red-300 flex items-center gap-1"
+                  >
+                    <Plus size={14} />
+                    Agregar
+                  </button>
+                </div>
+                {items.map((item, idx) => (
+                  <div key={idx} className="flex gap-2">
+                    <select
+                      value={item.productoId}
+                      onChange={(e) => cambiarItem(idx, { productoId: e.target.value })}
+                      className={`${inputClass} flex-1`}
+                    >
+                      <option value="">Elegir producto…</option>
+                      {productosElegibles.map((p) => (
+                        <option key={p.id} value={p.id}>
+                          {p.nombre}
+                        </option>
+                      ))}
+                    </select>
+                    <input
+                      type="number"
+                      min={1}
+                      value={item.cantidad}
+                      onChange={(e) => cambiarItem(idx, { cantidad: Math.floor(Number(e.target.value)) })}
+                      className={`${inputClass} !w-24 font-mono`}
+                    />
+                    <button
+                      type="button"
+                      onClick={() => setItems((prev) => prev.filter((_, i) => i !== idx))}
+                      className="p-2 rounded-lg hover:bg-gray-800 text-gray-500"
+                    >
+                      <Trash2 size={16} />
+                    </button>
+                  </div>
+                ))}
+              </div>
+            )}
+
+            <label className="flex items-center gap-2 text-sm text-text-secondary cursor-pointer">
+              <Paperclip size={16} className="text-gray-500" />
+              <span className="truncate">{archivo ? archivo.name : 'Adjuntar comprobante (PDF o foto)'}</span>
+              <input
+                type="file"
+                accept={TIPOS_COMPROBANTE}
+                onChange={(e) => setArchivo(e.target.files?.[0] ?? null)}
+                className="hidden"
+              />
+            </label>
+          </div>
+
+          {/* Footer */}
+          <div className="flex justify-end gap-3 px-6 py-4 border-t border-gray-800 shrink-0">
+            <button onClick={onClose} className="btn-secondary text-sm !min-h-[42px] px-5">
+              Cancelar
+            </button>
+            <button
+              onClick={handleGuardar}
+              disabled={!puedeGuardar}
+              className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+            >
+              {registrar.isPending && <Loader2 size={16} className="animate-spin" />}
+              Registrar
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
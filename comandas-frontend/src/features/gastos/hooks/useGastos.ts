@@ -0,0 +1,89 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { gastosApi } from '../api/gastosApi';
+import { descargarPdf } from '../../caja/services/pdfService';
+import type { ComprobanteRequest, Gasto, GastoRequest, ReporteGastos } from '../types';
+
+export const gastosKeys = {
+  lista: (desde: string, hasta: string) => ['gastos', desde, hasta] as const,
+  reporte: (periodo: string) => ['reporte-gastos', periodo] as const,
+};
+
+/**
+ * Gastos con fecha de comprobante en el rango, del más reciente al más antiguo.
+ *
+ * queryKey: ['gastos', desde, hasta]
+ */
+export function useGastos(desde: string, hasta: string) {
+  return useQuery<Gasto[]>({
+    queryKey: gastosKeys.lista(desde, hasta),
+    queryFn: () => gastosApi.listar(desde, hasta),
+    enabled: !!desde && !!hasta && desde <= hasta,
+  });
+}
+
+/**
+ * Gastos por categoría contra ventas del mes.
+ *
+ * queryKey: ['reporte-gastos', periodo]
+ */
+export function useReporteGastos(periodo: string) {
+  return useQuery<ReporteGastos>({
+    queryKey: gastosKeys.reporte(periodo),
+    queryFn: () => gastosApi.obtenerReporte(periodo),
+    enabled: !!periodo,
+    staleTime: 60_000,
+  });
+}
+
+/**
+ * Registrar un gasto puede mover stock (mercadería), caja (pagado con la caja)
+ * y la cuenta corriente de proveedores (HU-131), así que refresca también esos dominios.
+ */
+export function useRegistrarGasto() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (request: GastoRequest) => gastosApi.registrar(request),
+    onSuccess: (gasto) => {
+      queryClient.invalidateQueries({ queryKey: ['gastos'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['reporte-gastos'], exact: false });
+      if (gasto.items.length > 0) {
+        queryClient.invalidateQueries({ queryKey: ['productos'], exact: false });
+      }
+      if (gasto.pagadoConCaja) {
+        queryClient.invalidateQueries({ queryKey: ['reporte-caja'], exact: false });
+        queryClient.invalidateQueries({ queryKey: ['turnos-caja'], exact: false });
+      }
+      if (gasto.medioPago === 'CUENTA_CORRIENTE') {
+        queryClient.invalidateQueries({ queryKey: ['proveedores'], exact: false });
+      }
+    },
+    onError: (error: unknown) => {
+      console.error('[useRegistrarGasto] Error al registrar gasto:', error);
+    },
+  });
+}
+
+export function useAdjuntarComprobante() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ gastoId, comprobante }: { gastoId: string; comprobante: ComprobanteRequest }) =>
+      gastosApi.adjuntarComprobante(gastoId, comprobante),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['gastos'], exact: false });
+    },
+  });
+}
+
+/**
+ * Descarga el archivo del comprobante con su nombre original.
+ */
+export function useDescargarComprobante() {
+  return useMutation({
+    mutationFn: async (gasto: Gasto) => {
+      const blob = await gastosApi.obtenerComprobante(gasto.id);
+      descargarPdf(blob, gasto.nombreArchivoComprobante ?? 'comprobante');
+    },
+  });
+}
@@ -0,0 +1,37 @@
+/**
+ * Módulo Gastos — compras a proveedores, gastos fijos y reporte mensual
+ * contra ventas (HU-117).
+ *
+ * @example
+ * import { GastosPage, useReporteGastos } from '@/features/gastos';
+ */
+
+// Tipos
+export type {
+  CategoriaGasto,
+  MedioPagoGasto,
+  ComprobanteRequest,
+  CuentaCorrienteRequest,
+  ItemCompra,
+  GastoRequest,
+  Gasto,
+  CategoriaResumen,
+  ReporteGastos,
+} from './types';
+export { CATEGORIA_GASTO_LABELS, MEDIO_PAGO_GASTO_LABELS } from './types';
+
+// Hooks
+export {
+  useGastos,
+  useReporteGastos,
+  useRegistrarGasto,
+  useAdjuntarComprobante,
+  useDescargarComprobante,
+} from './hooks/useGastos';
+
+// Componentes
+export { default as GastosPage } from './components/GastosPage';
+export { default as RegistrarGastoModal } from './components/RegistrarGastoModal';
+
+// API
+export { gastosApi } from './api/gastosApi';
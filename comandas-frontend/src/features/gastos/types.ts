@@ -0,0 +1,109 @@
+// ─── Compras y gastos (HU-117) ───────────────────────────────────────────────
+
+export type CategoriaGasto =
+  | 'MERCADERIA'
+  | 'ALQUILER'
+  | 'SERVICIOS'
+  | 'SUELDOS'
+  | 'IMPUESTOS'
+  | 'MANTENIMIENTO'
+  | 'OTROS';
+
+export const CATEGORIA_GASTO_LABELS: Record<CategoriaGasto, string> = {
+  MERCADERIA: 'Mercadería',
+  ALQUILER: 'Alquiler',
+  SERVICIOS: 'Servicios',
+  SUELDOS: 'Sueldos',
+  IMPUESTOS: 'Impuestos',
+  MANTENIMIENTO: 'Mantenimiento',
+  OTROS: 'Otros',
+};
+
+/**
+ * A cuenta es un medio de cobro a clientes: no aplica a gastos.
+ * Cuenta corriente deja la compra a pagar al proveedor (HU-131).
+ */
+export type MedioPagoGasto = 'EFECTIVO' | 'TARJETA' | 'TRANSFERENCIA' | 'QR' | 'CUENTA_CORRIENTE';
+
+export const MEDIO_PAGO_GASTO_LABELS: Record<MedioPagoGasto, string> = {
+  EFECTIVO: 'Efectivo',
+  TARJETA: 'Tarjeta',
+  TRANSFERENCIA: 'Transferencia',
+  QR: 'QR',
+  CUENTA_CORRIENTE: 'Cuenta corriente (a pagar)',
+};
+
+/** Archivo codificado en Base64 (PDF o imagen, hasta 5 MB) */
+export interface ComprobanteRequest {
+  nombreArchivo: string;
+  contentType: string;
+  contenidoBase64: string;
+}
+
+export interface ItemCompra {
+  productoId: string;
+  nombreProducto: string;
+  cantidad: number;
+}
+
+/** Factura a pagar que genera una compra a cuenta corriente (HU-131) */
+export interface CuentaCorrienteRequest {
+  proveedorId: string;
+  numeroFactura?: string;
+  /** YYYY-MM-DD; omitido = fecha del comprobante + plazo de pago del proveedor */
+  fechaVencimiento?: string;
+}
+
+export interface GastoRequest {
+  /** YYYY-MM-DD del comprobante; omitido = hoy */
+  fecha?: string;
+  categoria: CategoriaGasto;
+  proveedor?: string;
+  descripcion?: string;
+  monto: number;
+  medioPago: MedioPagoGasto;
+  /** Solo MERCADERIA: productos que ingresan al stock */
+  items?: { productoId: string; cantidad: number }[];
+  /** El efectivo salió de la caja: registra también el egreso de caja */
+  pagadoConCaja: boolean;
+  comprobante?: ComprobanteRequest;
+  /** Obligatorio si medioPago es CUENTA_CORRIENTE */
+  cuentaCorriente?: CuentaCorrienteRequest;
+}
+
+export interface Gasto {
+  id: string;
+  fecha: string;
+  categoria: CategoriaGasto;
+  proveedor: string | null;
+  descripcion: string | null;
+  monto: number;
+  medioPago: MedioPagoGasto;
+  items: ItemCompra[];
+  pagadoConCaja: boolean;
+  /** null si todavía no se adjuntó */
+  nombreArchivoComprobante: string | null;
+}
+
+// ─── Reporte mensual ─────────────────────────────────────────────────────────
+
+export interface CategoriaResumen {
+  categoria: CategoriaGasto;
+  cantidad: number;
+  total: number;
+  /** Sobre las ventas del mes (0 si no hubo ventas) */
+  porcentajeSobreVentas: number;
+}
+
+export interface ReporteGastos {
+  /** YYYY-MM */
+  periodo: string;
+  /** Ventas reales de las jornadas cerradas del mes */
+  totalVentas: number;
+  categorias: CategoriaResumen[];
+  /** Egresos de caja cargados sin registrar un gasto */
+  otrosEgresosCaja: number;
+  totalGastos: number;
+  resultado: number;
+  porcentajeGastos: number;
+}
@@ -0,0 +1,32 @@
+import type { ComprobanteRequest } from '../types';
+
+/** Mismo límite que valida el backend */
+export const TAMANIO_MAXIMO_COMPROBANTE = 5 * 1024 * 1024;
+
+export const TIPOS_COMPROBANTE = 'application/pdf,image/jpeg,image/png,image/webp';
+
+/**
+ * Lee el archivo elegido y lo codifica en Base64 para enviarlo en el JSON.
+ *
+ * @throws Error si supera los 5 MB
+ */
+export function leerComprobante(archivo: File): Promise<ComprobanteRequest> {
+  if (archivo.size > TAMANIO_MAXIMO_COMPROBANTE) {
+    return Promise.reject(new Error('El comprobante no puede superar los 5 MB'));
+  }
+
+  return new Promise((resolve, reject) => {
+    const reader = new FileReader();
+    reader.onload = () => {
+      // readAsDataURL devuelve "data:<tipo>;base64,<contenido>"
+      const dataUrl = String(reader.result);
+      resolve({
+        nombreArchivo: archivo.name,
+        contentType: archivo.type,
+        contenidoBase64: dataUrl.substring(dataUrl.indexOf(',') + 1),
+      });
+    };
+    reader.onerror = () => reject(new Error('No se pudo leer el archivo'));
+    reader.readAsDataURL(archivo);
+  });
+}
@@ -0,0 +1,72 @@
+import apiClient from '../../../lib/apiClient';
+import type { InsumoResponse } from '../../catalogo/types';
+import type {
+  Merma,
+  MermaRequest,
+  MovimientoInsumo,
+  OrdenCompraSugerida,
+  ReporteMermas,
+  ReposicionInsumoRequest,
+  TomaInventario,
+  TomaInventarioRequest,
+} from '../types';
+
+/**
+ * API client del inventario físico de insumos (HU-133), de las órdenes
+ * de compra por stock mínimo (HU-134) y de las mermas (HU-135).
+ * Consume /api/inventario de InventarioController.
+ */
+export const inventarioApi = {
+  listarTomas: async (): Promise<TomaInventario[]> => {
+    const response = await apiClient.get<TomaInventario[]>('/inventario/tomas');
+    return response.data;
+  },
+
+  consultarToma: async (tomaId: string): Promise<TomaInventario> => {
+    const response = await apiClient.get<TomaInventario>(`/inventario/tomas/${tomaId}`);
+    return response.data;
+  },
+
+  /** Reporte de diferencias sin ajustar el stock */
+  previsualizar: async (request: TomaInventarioRequest): Promise<TomaInventario> => {
+    const response = await apiClient.post<TomaInventario>('/inventario/tomas/previsualizar', request);
+    return response.data;
+  },
+
+  confirmar: async (request: TomaInventarioRequest): Promise<TomaInventario> => {
+    const response = await apiClient.post<TomaInventario>('/inventario/tomas', request);
+    return response.data;
+  },
+
+  listarMovimientos: async (insumoId: string): Promise<MovimientoInsumo[]> => {
+    const response = await apiClient.get<MovimientoInsumo[]>(`/inventario/insumos/${insumoId}/movimientos`);
+    return response.data;
+  },
+
+  definirReposicion: async (insumoId: string, request: ReposicionInsumoRequest): Promise<InsumoResponse> => {
+    const response = await apiClient.put<InsumoResponse>(`/inventario/insumos/${insumoId}/reposicion`, request);
+    return response.data;
+  },
+
+  sugerirOrdenesCompra: async (): Promise<OrdenCompraSugerida[]> => {
+    const response = await apiClient.get<OrdenCompraSugerida[]>('/inventario/ordenes-compra');
+    return response.data;
+  },
+
+  /** @param desde @param hasta YYYY-MM-DD, inclusive */
+  listarMermas: async (desde: string, hasta: string): Promise<Merma[]> => {
+    const response = await apiClient.get<Merma[]>('/inventario/mermas', { params: { desde, hasta } });
+    return response.data;
+  },
+
+  registrarMerma: async (request: MermaRequest): Promise<Merma> => {
+    const response = await apiClient.post<Merma>('/inventario/mermas', request);
+    return response.data;
+  },
+
+  /** @param periodo YYYY-MM */
+  reporteMermas: async (periodo: string): Promise<ReporteMermas> => {
+    const response = await apiClient.get<ReporteMermas>('/inventario/mermas/reporte', { params: { periodo } });
+    return response.data;
+  },
+};
@@ -0,0 +1,370 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Calendar, Download, Loader2, Trash } from 'lucide-react';
+import { generarCsv, descargarCsv } from '../../../lib/csv';
+import useToast from '../../../hooks/useToast';
+import { useInsumos, useProductos } from '../../catalogo/hooks/useProductos';
+import { UNIDAD_INSUMO_LABELS } from '../../catalogo/types';
+import { useMermas, useRegistrarMerma, useReporteMermas } from '../hooks/useInventario';
+import { CATEGORIA_MERMA_LABELS } from '../types';
+import type { CategoriaMerma, MermaRequest, ReporteMermas } from '../types';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function fmtCantidad(valor: number): string {
+  return valor.toLocaleString('es-AR', { maximumFractionDigits: 3 });
+}
+
+function mesActual(): string {
+  const hoy = new Date();
+  return `${hoy.getFullYear()}-${String(hoy.getMonth() + 1).padStart(2, '0')}`;
+}
+
+/** Primer y último día del mes YYYY-MM, en YYYY-MM-DD */
+function rangoDelMes(periodo: string): [string, string] {
+  const [anio, mes] = periodo.split('-').map(Number);
+  const ultimo = new Date(anio, mes, 0).getDate();
+  return [`${periodo}-01`, `${periodo}-${String(ultimo).padStart(2, '0')}`];
+}
+
+function formatFecha(fechaHora: string): string {
+  const d = new Date(fechaHora);
+  const dia = d.toLocaleDateString('es-AR', { day: '2-digit', month: '2-digit' });
+  const hora = d.toLocaleTimeString('es-AR', { hour: '2-digit', minute: '2-digit' });
+  return `${dia} ${hora}`;
+}
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+const CATEGORIAS = Object.keys(CATEGORIA_MERMA_LABELS) as CategoriaMerma[];
+
+function exportarReporte(reporte: ReporteMermas) {
+  const filas: unknown[][] = reporte.categorias.map((c) => [
+    CATEGORIA_MERMA_LABELS[c.categoria],
+    c.registros,
+    c.total,
+    c.porcentaje,
+  ]);
+  filas.push(['Total', reporte.registros, reporte.total, 100]);
+
+  descargarCsv(
+    generarCsv(['Categoría', 'Registros', 'Merma $', '% del total'], filas),
+    `mermas_${reporte.periodo}.csv`,
+  );
+}
+
+// ─── Registro ─────────────────────────────────────────────────────────────────
+
+/**
+ * Un plato caído se carga por producto (descuenta su receta); lo demás,
+ * por insumo. El motivo y el responsable son obligatorios.
+ */
+function FormularioMerma() {
+  const toast = useToast();
+  const { data: insumos = [] } = useInsumos();
+  const { data: productos = [] } = useProductos(null, true);
+  const registrar = useRegistrarMerma();
+
+  const [categoria, setCategoria] = useState<CategoriaMerma>('VENCIMIENTO');
+  const [porProducto, setPorProducto] = useState(false);
+  const [itemId, setItemId] = useState('');
+  const [cantidad, setCantidad] = useState('');
+  const [motivo, setMotivo] = useState('');
+  const [responsable, setResponsable] = useState('');
+
+  const elegirCategoria = (nueva: CategoriaMerma) => {
+    setCategoria(nueva);
+    setPorProducto(nueva === 'PLATO_CAIDO');
+    setItemId('');
+  };
+
+  const insumo = insumos.find((i) => i.id === itemId);
+  const numero = parseFloat(cantidad);
+  const valido =
+    !!itemId &&
+    numero > 0 &&
+    (!porProducto || Number.isInteger(numero)) &&
+    motivo.trim() !== '' &&
+    responsable.trim() !== '';
+
+  const guardar = () => {
+    const request: MermaRequest = {
+      categoria,
+      motivo: motivo.trim(),
+      responsable: responsable.trim(),
+      productoId: porProducto ? itemId : null,
+      unidades: porProducto ? numero : null,
+      insumos: porProducto ? null : [{ insumoId: itemId, cantidad: numero }],
+    };
+    registrar.mutate(request, {
+      onSuccess: (merma) => {
+        toast.success(`Merma registrada: $ ${fmt(merma.costoTotal)}`);
+        setItemId('');
+        setCantidad('');
+        setMotivo('');
+      },
+      onError: (err: any) => {
+        toast.error(err?.response?.data?.message || 'No se pudo registrar la merma');
+      },
+    });
+  };
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 px-4 py-3 space-y-3">
+      <div className="flex flex-wrap items-center gap-2">
+        <span className="text-xs font-semibold text-gray-500 uppercase tracking-widest mr-2">Nueva merma</span>
+        <select
+          value={categoria}
+          onChange={(e) => elegirCategoria(e.target.value as CategoriaMerma)}
+          className={inputFecha}
+        >
+          {CATEGORIAS.map((c) => (
+            <option key={c} value={c}>
+              {CATEGORIA_MERMA_LABELS[c]}
+            </option>
+          ))}
+        </select>
+        <div className="flex rounded-lg bg-neutral-800 p-0.5 text-xs">
+          {[false, true].map((opcion) => (
+            <button
+              key={String(opcion)}
+              type="button"
+              onClick={() => {
+                setPorProducto(opcion);
+                setItemId('');
+              }}
+              className={`px-3 py-1 rounded-md transition-colors ${
+                porProducto === opcion ? 'bg-neutral-700 text-gray-100' : 'text-gray-500 hover:text-gray-300'
+              }`}
+            >
+              {opcion ? 'Por producto' : 'Por insumo'}
+            </button>
+          ))}
+        </div>
+      </div>
+
+      <div className="flex flex-wrap items-center gap-2">
+        <select value={itemId} onChange={(e) => setItemId(e.target.value)} className={`${inputFecha} w-56`}>
+          <option value="">{porProducto ? 'Elegir producto…' : 'Elegir insumo…'}</option>
+          {porProducto
+            ? productos.map((p) => (
+                <option key={p.id} value={p.id}>
+                  {p.nombre}
+                </option>
+              ))
+            : insumos.map((i) => (
+                <option key={i.id} value={i.id}>
+                  {i.nombre}
+                </option>
+              ))}
+        </select>
+        <input
+          type="number"
+          min={0}
+          step={porProducto || insumo?.unidad === 'UNIDAD' ? 1 : 0.001}
+          value={cantidad}
+          onChange={(e) => setCantidad(e.target.value)}
+          placeholder={porProducto ? 'Unidades' : 'Cantidad'}
+          className={`${inputFecha} w-28 text-right`}
+        />
+        {!porProducto && insumo && (
+          <span className="text-xs text-gray-500">{UNIDAD_INSUMO_LABELS[insumo.unidad]}</span>
+        )}
+        <input
+          type="text"
+          maxLength={100}
+          value={motivo}
+          onChange={(e) => setMotivo(e.target.value)}
+          placeholder="Motivo"
+          className={`${inputFecha} flex-1 min-w-48 font-sans`}
+        />
+        <input
+          type="text"
+          maxLength={60}
+          value={responsable}
+          onChange={(e) => setResponsable(e.target.value)}
+          placeholder="Responsable"
+          className={`${inputFecha} w-40 font-sans`}
+        />
+        <button
+          type="button"
+          onClick={guardar}
+          disabled={!valido || registrar.isPending}
+          className="h-8 px-3 rounded-lg bg-red-600 hover:bg-red-700 text-xs text-white transition-colors disabled:opacity-40"
+        >
+          Registrar
+        </button>
+      </div>
+    </div>
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Registro de mermas y reporte mensual valorizado (HU-135).
+ *
+ * Cada merma descuenta el stock de lo perdido. El reporte muestra cuánta
+ * plata se fue por categoría y qué insumos pesan más, para atacar primero
+ * lo que más cuesta.
+ */
+export default function MermasPage() {
+  const [periodo, setPeriodo] = useState(mesActual);
+  const [desde, hasta] = rangoDelMes(periodo);
+  const { data: reporte, isLoading, isError } = useReporteMermas(periodo);
+  const { data: mermas = [] } = useMermas(desde, hasta);
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/inventario"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Mermas</h1>
+            <p className="text-sm text-gray-500">Mercadería perdida sin venderse, valorizada al costo</p>
+          </div>
+        </header>
+
+        <FormularioMerma />
+
+        <div className="flex items-center gap-3">
+          <Calendar size={14} className="text-gray-500" />
+          <input
+            type="month"
+            value={periodo}
+            max={mesActual()}
+            onChange={(e) => e.target.value && setPeriodo(e.target.value)}
+            className={inputFecha}
+          />
+          {reporte && (
+            <>
+              <span className="ml-auto text-sm text-gray-400">
+                Merma del mes <span className="font-mono tabular-nums text-red-400">$ {fmt(reporte.total)}</span>
+              </span>
+              <button
+                type="button"
+                onClick={() => exportarReporte(reporte)}
+                disabled={reporte.registros === 0}
+                className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors disabled:opacity-40"
+              >
+                <Download size={14} />
+                Exportar CSV
+              </button>
+            </>
+          )}
+        </div>
+
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando reporte...
+          </div>
+        ) : isError ? (
+          <p className="text-sm text-red-400">No se pudo cargar el reporte de mermas.</p>
+        ) : reporte && reporte.registros === 0 ? (
+          <div className="flex items-center gap-2 text-xs text-gray-500">
+            <Trash size={14} />
+            No se registraron mermas en este mes.
+          </div>
+        ) : reporte && (
+          <div className="grid gap-5 lg:grid-cols-2">
+            <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+              <table className="w-full text-sm">
+                <thead>
+                  <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                    <th className="text-left font-medium px-4 py-2">Categoría</th>
+                    <th className="text-right font-medium px-3 py-2">Registros</th>
+                    <th className="text-right font-medium px-3 py-2">Merma</th>
+                    <th className="text-right font-medium px-4 py-2">%</th>
+                  </tr>
+                </thead>
+                <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+                  {reporte.categorias.map((c) => (
+                    <tr key={c.categoria}>
+                      <td className="px-4 py-2 font-sans text-gray-100">{CATEGORIA_MERMA_LABELS[c.categoria]}</td>
+                      <td className="text-right px-3 py-2">{c.registros}</td>
+                      <td className="text-right px-3 py-2 text-red-400">$ {fmt(c.total)}</td>
+                      <td className="text-right px-4 py-2 text-gray-500">{fmt(c.porcentaje)}</td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+
+            <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+              <table className="w-full text-sm">
+                <thead>
+                  <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                    <th className="text-left font-medium px-4 py-2">Insumo</th>
+                    <th className="text-right font-medium px-3 py-2">Cantidad</th>
+                    <th className="text-right font-medium px-4 py-2">Merma</th>
+                  </tr>
+                </thead>
+                <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+                  {reporte.insumos.map((i) => (
+                    <tr key={i.insumoId}>
+                      <td className="px-4 py-2 font-sans text-gray-100">{i.nombre}</td>
+                      <td className="text-right px-3 py-2 text-gray-500">
+                        {fmtCantidad(i.cantidad)} {UNIDAD_INSUMO_LABELS[i.unidad]}
+                      </td>
+                      <td className="text-right px-4 py-2 text-red-400">$ {fmt(i.total)}</td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          </div>
+        )}
+
+        {mermas.length > 0 && (
+          <div className="space-y-2">
+            <h2 className="text-xs font-semibold text-gray-500 uppercase tracking-widest">Registradas en el mes</h2>
+            <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+              <table className="w-full text-sm">
+                <thead>
+                  <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                    <th className="text-left font-medium px-4 py-2">Fecha</th>
+                    <th className="text-left font-medium px-3 py-2">Categoría</th>
+                    <th className="text-left font-medium px-3 py-2">Qué se perdió</th>
+                    <th className="text-left font-medium px-3 py-2">Motivo</th>
+                    <th className="text-left font-medium px-3 py-2">Responsable</th>
+                    <th className="text-right font-medium px-4 py-2">Costo</th>
+                  </tr>
+                </thead>
+                <tbody className="divide-y divide-neutral-800 text-gray-300">
+                  {mermas.map((m) => (
+                    <tr key={m.id}>
+                      <td className="px-4 py-2 font-mono tabular-nums">{formatFecha(m.fecha)}</td>
+                      <td className="px-3 py-2 text-gray-400">{CATEGORIA_MERMA_LABELS[m.categoria]}</td>
+                      <td className="px-3 py-2 text-gray-100">
+                        {m.nombreProducto
+                          ? `${m.unidadesProducto} × ${m.nombreProducto}`
+                          : m.insumos
+                              .map((r) => `${fmtCantidad(r.cantidad)} ${UNIDAD_INSUMO_LABELS[r.unidad]} ${r.nombre}`)
+                              .join(', ')}
+                      </td>
+                      <td className="px-3 py-2 text-gray-400">{m.motivo}</td>
+                      <td className="px-3 py-2 text-gray-400">{m.responsable}</td>
+                      <td className="text-right px-4 py-2 font-mono tabular-nums text-red-400">
+                        $ {fmt(m.costoTotal)}
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          </div>
+        )}
+      </div>
+    </section>
+  );
+}
@@ -0,0 +1,317 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Copy, Loader2, Mail, MessageCircle, PackageCheck, Trash2 } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useInsumos } from '../../catalogo/hooks/useProductos';
+import { UNIDAD_INSUMO_LABELS } from '../../catalogo/types';
+import type { InsumoResponse, UnidadInsumo } from '../../catalogo/types';
+import { useProveedores } from '../../proveedores/hooks/useProveedores';
+import { useDefinirReposicion, useOrdenesCompraSugeridas } from '../hooks/useInventario';
+import type { OrdenCompraSugerida, RenglonOrdenCompra } from '../types';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function fmtCantidad(valor: number): string {
+  return valor.toLocaleString('es-AR', { maximumFractionDigits: 3 });
+}
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+const botonSecundario =
+  'h-8 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-xs text-gray-300 flex items-center gap-1.5 transition-colors disabled:opacity-40';
+
+interface RenglonEditable {
+  nombre: string;
+  unidad: UnidadInsumo;
+  cantidad: number;
+}
+
+function mensajeOrden(renglones: RenglonEditable[]): string {
+  const lineas = renglones.map((r) => `- ${fmtCantidad(r.cantidad)} ${UNIDAD_INSUMO_LABELS[r.unidad]} de ${r.nombre}`);
+  return ['Hola, les paso el siguiente pedido:', '', ...lineas, '', 'Gracias.'].join('\n');
+}
+
+/**
+ * wa.me pide el número en formato internacional sin símbolos.
+ * Un número local argentino de 10 dígitos (área + número) se toma como celular.
+ */
+function linkWhatsApp(telefono: string, mensaje: string): string {
+  let digitos = telefono.replace(/\D/g, '').replace(/^0/, '');
+  if (digitos.length === 10) {
+    digitos = `549${digitos}`;
+  }
+  return `https://wa.me/${digitos}?text=${encodeURIComponent(mensaje)}`;
+}
+
+function linkEmail(email: string, mensaje: string): string {
+  return `mailto:${email}?subject=${encodeURIComponent('Pedido de mercadería')}&body=${encodeURIComponent(mensaje)}`;
+}
+
+// ─── Orden sugerida ───────────────────────────────────────────────────────────
+
+/**
+ * Las cantidades sugeridas se pueden corregir o sacar antes de enviar;
+ * los cambios quedan solo en la pantalla.
+ */
+function TarjetaOrden({ orden }: { orden: OrdenCompraSugerida }) {
+  const toast = useToast();
+  const [cantidades, setCantidades] = useState<Record<string, string>>({});
+  const [quitados, setQuitados] = useState<string[]>([]);
+
+  // Sin editar, vale la sugerida (también para insumos que entran al refrescar)
+  const valorDe = (r: RenglonOrdenCompra) => cantidades[r.insumoId] ?? String(r.cantidadSugerida);
+
+  const renglones = orden.insumos.filter((r) => !quitados.includes(r.insumoId));
+  const editables: RenglonEditable[] = renglones
+    .map((r) => ({ nombre: r.nombre, unidad: r.unidad, cantidad: parseFloat(valorDe(r)) }))
+    .filter((r) => r.cantidad > 0);
+  const total = renglones.reduce((suma, r) => suma + (parseFloat(valorDe(r)) || 0) * r.costoUnitario, 0);
+  const mensaje = mensajeOrden(editables);
+  const vacia = editables.length === 0;
+
+  const copiar = async () => {
+    try {
+      await navigator.clipboard.writeText(mensaje);
+      toast.success('Pedido copiado');
+    } catch {
+      toast.error('No se pudo copiar el pedido');
+    }
+  };
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50">
+      <div className="flex flex-wrap items-center gap-2 px-4 py-3 border-b border-neutral-800">
+        <div className="mr-auto">
+          <h2 className="text-sm font-semibold text-gray-100">{orden.nombreProveedor ?? 'Sin proveedor habitual'}</h2>
+          <p className="text-xs text-gray-500">
+            Estimado <span className="font-mono tabular-nums text-gray-300">$ {fmt(total)}</span>
+          </p>
+        </div>
+        <button type="button" onClick={copiar} disabled={vacia} className={botonSecundario}>
+          <Copy size={13} />
+          Copiar
+        </button>
+        {orden.telefono && (
+          <a
+            href={vacia ? undefined : linkWhatsApp(orden.telefono, mensaje)}
+            target="_blank"
+            rel="noreferrer"
+            aria-disabled={vacia}
+            className={`${botonSecundario} ${vacia ? 'opacity-40 pointer-events-none' : ''}`}
+          >
+            <MessageCircle size={13} />
+            WhatsApp
+          </a>
+        )}
+        {orden.email && (
+          <a
+            href={vacia ? undefined : linkEmail(orden.email, mensaje)}
+            aria-disabled={vacia}
+            className={`${botonSecundario} ${vacia ? 'opacity-40 pointer-events-none' : ''}`}
+          >
+            <Mail size={13} />
+            Email
+          </a>
+        )}
+      </div>
+      <table className="w-full text-sm">
+        <thead>
+          <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+            <th className="text-left font-medium px-4 py-2">Insumo</th>
+            <th className="text-right font-medium px-3 py-2">Stock</th>
+            <th className="text-right font-medium px-3 py-2">Mínimo</th>
+            <th className="text-right font-medium px-3 py-2">A pedir</th>
+            <th className="w-10" />
+          </tr>
+        </thead>
+        <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+          {renglones.map((r) => (
+            <tr key={r.insumoId}>
+              <td className="px-4 py-2 font-sans text-gray-100">{r.nombre}</td>
+              <td className={`text-right px-3 py-2 ${r.stockActual < 0 ? 'text-red-400' : 'text-gray-500'}`}>
+                {fmtCantidad(r.stockActual)}
+              </td>
+              <td className="text-right px-3 py-2 text-gray-500">{fmtCantidad(r.stockMinimo)}</td>
+              <td className="text-right px-3 py-1.5">
+                <input
+                  type="number"
+                  min={0}
+                  step={r.unidad === 'UNIDAD' ? 1 : 0.001}
+                  value={valorDe(r)}
+                  onChange={(e) => setCantidades((prev) => ({ ...prev, [r.insumoId]: e.target.value }))}
+                  className={`${inputFecha} w-24 text-right`}
+                />
+                <span className="ml-1.5 text-xs text-gray-500 font-sans">{UNIDAD_INSUMO_LABELS[r.unidad]}</span>
+              </td>
+              <td className="px-2 py-2">
+                <button
+                  type="button"
+                  onClick={() => setQuitados((prev) => [...prev, r.insumoId])}
+                  className="p-1.5 rounded-lg hover:bg-neutral-800 text-gray-500 hover:text-red-400 transition-colors"
+                  aria-label={`Quitar ${r.nombre}`}
+                >
+                  <Trash2 size={14} />
+                </button>
+              </td>
+            </tr>
+          ))}
+        </tbody>
+      </table>
+    </div>
+  );
+}
+
+// ─── Mínimo y proveedor por insumo ────────────────────────────────────────────
+
+function FilaReposicion({ insumo }: { insumo: InsumoResponse }) {
+  const toast = useToast();
+  const { data: proveedores = [] } = useProveedores();
+  const definir = useDefinirReposicion();
+
+  const [minimo, setMinimo] = useState(insumo.stockMinimo != null ? String(insumo.stockMinimo) : '');
+  const [proveedorId, setProveedorId] = useState(insumo.proveedorHabitualId ?? '');
+
+  const opciones = proveedores.filter((p) => p.activo || p.id === insumo.proveedorHabitualId);
+  const minimoNumero = minimo === '' ? null : parseFloat(minimo);
+  const cambio =
+    minimoNumero !== insumo.stockMinimo || (proveedorId || null) !== insumo.proveedorHabitualId;
+  const valido = minimoNumero === null || minimoNumero > 0;
+
+  const guardar = () => {
+    definir.mutate(
+      { insumoId: insumo.id, request: { stockMinimo: minimoNumero, proveedorHabitualId: proveedorId || null } },
+      {
+        onSuccess: () => toast.success(`Reposición de ${insumo.nombre} actualizada`),
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo guardar la reposición'),
+      },
+    );
+  };
+
+  return (
+    <tr>
+      <td className="px-4 py-2 font-sans text-gray-100">
+        {insumo.nombre}
+        {insumo.bajoMinimo && <span className="ml-2 text-[10px] uppercase text-red-400">bajo mínimo</span>}
+      </td>
+      <td className="text-right px-3 py-2 text-gray-500">
+        {insumo.controlaStock
+          ? `${fmtCantidad(insumo.stockActual)} ${UNIDAD_INSUMO_LABELS[insumo.unidad]}`
+          : 'sin control'}
+      </td>
+      <td className="text-right px-3 py-1.5">
+        <input
+          type="number"
+          min={0}
+          step={insumo.unidad === 'UNIDAD' ? 1 : 0.001}
+          value={minimo}
+          onChange={(e) => setMinimo(e.target.value)}
+          placeholder="—"
+          className={`${inputFecha} w-24 text-right`}
+        />
+      </td>
+      <td className="px-3 py-1.5">
+        <select value={proveedorId} onChange={(e) => setProveedorId(e.target.value)} className={`${inputFecha} w-48`}>
+          <option value="">Sin proveedor</option>
+          {opciones.map((p) => (
+            <option key={p.id} value={p.id}>
+              {p.nombre}
+            </option>
+          ))}
+        </select>
+      </td>
+      <td className="px-4 py-1.5 text-right">
+        <button
+          type="button"
+          onClick={guardar}
+          disabled={!cambio || !valido || definir.isPending}
+          className="h-8 px-3 rounded-lg bg-red-600 hover:bg-red-700 text-xs text-white transition-colors disabled:opacity-40"
+        >
+          Guardar
+        </button>
+      </td>
+    </tr>
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Órdenes de compra sugeridas por stock mínimo (HU-134).
+ *
+ * Los insumos que llegaron al mínimo se agrupan por proveedor habitual,
+ * con lo necesario para volver al doble del mínimo. Cada orden se ajusta
+ * en pantalla y se envía por WhatsApp o email al proveedor.
+ */
+export default function OrdenesCompraPage() {
+  const { data: ordenes = [], isLoading, isError } = useOrdenesCompraSugeridas();
+  const { data: insumos = [] } = useInsumos();
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/inventario"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Órdenes de compra</h1>
+            <p className="text-sm text-gray-500">Insumos bajo el stock mínimo, por proveedor habitual</p>
+          </div>
+        </header>
+
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Calculando órdenes...
+          </div>
+        ) : isError ? (
+          <p className="text-sm text-red-400">No se pudieron calcular las órdenes de compra.</p>
+        ) : ordenes.length === 0 ? (
+          <div className="flex items-center gap-2 text-xs text-gray-500">
+            <PackageCheck size={14} />
+            Ningún insumo está bajo el mínimo.
+          </div>
+        ) : (
+          <div className="grid gap-4 xl:grid-cols-2">
+            {ordenes.map((o) => (
+              <TarjetaOrden key={o.proveedorId ?? 'sin-proveedor'} orden={o} />
+            ))}
+          </div>
+        )}
+
+        {insumos.length > 0 && (
+          <div className="space-y-3 pt-2">
+            <div className="text-xs font-semibold text-gray-500 uppercase tracking-widest">
+              Stock mínimo y proveedor habitual
+            </div>
+            <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+              <table className="w-full text-sm">
+                <thead>
+                  <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                    <th className="text-left font-medium px-4 py-2">Insumo</th>
+                    <th className="text-right font-medium px-3 py-2">Stock</th>
+                    <th className="text-right font-medium px-3 py-2">Mínimo</th>
+                    <th className="text-left font-medium px-3 py-2">Proveedor</th>
+                    <th className="px-4 py-2" />
+                  </tr>
+                </thead>
+                <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+                  {insumos.map((i) => (
+                    <FilaReposicion key={`${i.id}-${i.stockMinimo}-${i.proveedorHabitualId}`} insumo={i} />
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          </div>
+        )}
+      </div>
+    </section>
+  );
+}
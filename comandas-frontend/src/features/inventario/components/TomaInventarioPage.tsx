@@ -0,0 +1,364 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, ClipboardCheck, Download, History, Loader2, ShoppingCart, Trash } from 'lucide-react';
+import { generarCsv, descargarCsv } from '../../../lib/csv';
+import useToast from '../../../hooks/useToast';
+import { useInsumos } from '../../catalogo/hooks/useProductos';
+import { UNIDAD_INSUMO_LABELS } from '../../catalogo/types';
+import type { TomaInventario, TomaInventarioRequest } from '../types';
+import {
+  useConfirmarToma,
+  usePrevisualizarToma,
+  useTomaInventario,
+  useTomasInventario,
+} from '../hooks/useInventario';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function fmtCantidad(valor: number): string {
+  return valor.toLocaleString('es-AR', { maximumFractionDigits: 3 });
+}
+
+function formatFecha(fechaHora: string): string {
+  const d = new Date(fechaHora);
+  const dia = d.toLocaleDateString('es-AR', { day: '2-digit', month: '2-digit', year: 'numeric' });
+  const hora = d.toLocaleTimeString('es-AR', { hour: '2-digit', minute: '2-digit' });
+  return `${dia} ${hora}`;
+}
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+function exportarToma(toma: TomaInventario) {
+  const filas: unknown[][] = toma.insumos.map((r) => [
+    r.nombre,
+    UNIDAD_INSUMO_LABELS[r.unidad],
+    r.stockTeorico,
+    r.stockContado,
+    r.diferencia,
+    r.costoUnitario,
+    r.diferenciaValorizada,
+  ]);
+  filas.push(['Faltante', '', '', '', '', '', -toma.faltanteValorizado]);
+  filas.push(['Sobrante', '', '', '', '', '', toma.sobranteValorizado]);
+  filas.push(['Neto', '', '', '', '', '', toma.diferenciaValorizada]);
+
+  descargarCsv(
+    generarCsv(
+      ['Insumo', 'Unidad', 'Teórico', 'Contado', 'Diferencia', 'Costo unitario', 'Diferencia $'],
+      filas,
+    ),
+    `inventario_${toma.fecha.slice(0, 10)}.csv`,
+  );
+}
+
+// ─── Reporte de diferencias ───────────────────────────────────────────────────
+
+function ReporteDiferencias({ toma }: { toma: TomaInventario }) {
+  return (
+    <div className="space-y-3">
+      <div className="flex flex-wrap items-center gap-4 text-sm text-gray-400">
+        <span>
+          Faltante <span className="font-mono tabular-nums text-red-400">$ {fmt(toma.faltanteValorizado)}</span>
+        </span>
+        <span>
+          Sobrante <span className="font-mono tabular-nums text-emerald-400">$ {fmt(toma.sobranteValorizado)}</span>
+        </span>
+        <span>
+          Neto{' '}
+          <span
+            className={`font-mono tabular-nums ${toma.diferenciaValorizada < 0 ? 'text-red-400' : 'text-gray-100'}`}
+          >
+            $ {fmt(toma.diferenciaValorizada)}
+          </span>
+        </span>
+        <span className="text-xs text-gray-600">
+          {toma.insumosConDiferencia} de {toma.insumos.length} con diferencia
+        </span>
+        <button
+          type="button"
+          onClick={() => exportarToma(toma)}
+          className="ml-auto h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+        >
+          <Download size={14} />
+          Exportar CSV
+        </button>
+      </div>
+
+      <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+        <table className="w-full text-sm">
+          <thead>
+            <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+              <th className="text-left font-medium px-4 py-2">Insumo</th>
+              <th className="text-right font-medium px-3 py-2">Teórico</th>
+              <th className="text-right font-medium px-3 py-2">Contado</th>
+              <th className="text-right font-medium px-3 py-2">Diferencia</th>
+              <th className="text-right font-medium px-4 py-2">Valorizado</th>
+            </tr>
+          </thead>
+          <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+            {toma.insumos.map((r) => (
+              <tr key={r.insumoId}>
+                <td className="px-4 py-2 font-sans text-gray-100">{r.nombre}</td>
+                <td className="text-right px-3 py-2 text-gray-500">
+                  {fmtCantidad(r.stockTeorico)} {UNIDAD_INSUMO_LABELS[r.unidad]}
+                </td>
+                <td className="text-right px-3 py-2">{fmtCantidad(r.stockContado)}</td>
+                <td
+                  className={`text-right px-3 py-2 ${
+                    r.diferencia < 0 ? 'text-red-400' : r.diferencia > 0 ? 'text-emerald-400' : 'text-gray-600'
+                  }`}
+                >
+                  {r.diferencia > 0 ? '+' : ''}
+                  {fmtCantidad(r.diferencia)}
+                </td>
+                <td
+                  className={`text-right px-4 py-2 ${r.diferenciaValorizada < 0 ? 'text-red-400' : 'text-gray-300'}`}
+                >
+                  $ {fmt(r.diferenciaValorizada)}
+                </td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      </div>
+    </div>
+  );
+}
+
+// ─── Historial ────────────────────────────────────────────────────────────────
+
+function HistorialTomas() {
+  const { data: tomas = [], isLoading } = useTomasInventario();
+  const [tomaId, setTomaId] = useState<string | null>(null);
+  const { data: detalle } = useTomaInventario(tomaId);
+
+  if (isLoading) {
+    return (
+      <div className="flex items-center gap-2 text-sm text-gray-500">
+        <Loader2 size={16} className="animate-spin" /> Cargando tomas...
+      </div>
+    );
+  }
+
+  if (tomas.length === 0) {
+    return <p className="text-xs text-gray-500">Todavía no se hizo ninguna toma de inventario.</p>;
+  }
+
+  return (
+    <div className="space-y-4">
+      <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+        <table className="w-full text-sm">
+          <thead>
+            <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+              <th className="text-left font-medium px-4 py-2">Fecha</th>
+              <th className="text-left font-medium px-3 py-2">Responsable</th>
+              <th className="text-right font-medium px-3 py-2">Insumos</th>
+              <th className="text-right font-medium px-3 py-2">Faltante</th>
+              <th className="text-right font-medium px-4 py-2">Neto</th>
+            </tr>
+          </thead>
+          <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+            {tomas.map((t) => (
+              <tr
+                key={t.id}
+                onClick={() => setTomaId(t.id === tomaId ? null : t.id)}
+                className={`cursor-pointer transition-colors ${
+                  t.id === tomaId ? 'bg-neutral-800/60' : 'hover:bg-neutral-800/30'
+                }`}
+              >
+                <td className="px-4 py-2">{formatFecha(t.fecha)}</td>
+                <td className="px-3 py-2 font-sans text-gray-100">{t.responsable}</td>
+                <td className="text-right px-3 py-2">{t.insumos.length}</td>
+                <td className="text-right px-3 py-2 text-red-400">$ {fmt(t.faltanteValorizado)}</td>
+                <td className={`text-right px-4 py-2 ${t.diferenciaValorizada < 0 ? 'text-red-400' : ''}`}>
+                  $ {fmt(t.diferenciaValorizada)}
+                </td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      </div>
+
+      {tomaId && detalle && <ReporteDiferencias toma={detalle} />}
+    </div>
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Toma de inventario físico de insumos (HU-133).
+ *
+ * Se carga lo contado (los insumos que quedan vacíos no se cuentan),
+ * se revisa el reporte de diferencias valorizado contra el stock teórico
+ * y, al confirmar, el stock queda igual al conteo con un ajuste auditado.
+ */
+export default function TomaInventarioPage() {
+  const toast = useToast();
+  const { data: insumos = [], isLoading } = useInsumos();
+  const previsualizar = usePrevisualizarToma();
+  const confirmar = useConfirmarToma();
+
+  const [responsable, setResponsable] = useState('');
+  const [conteos, setConteos] = useState<Record<string, string>>({});
+  const [vistaPrevia, setVistaPrevia] = useState<TomaInventario | null>(null);
+
+  const cambiarConteo = (insumoId: string, valor: string) => {
+    setConteos((prev) => ({ ...prev, [insumoId]: valor }));
+    setVistaPrevia(null);
+  };
+
+  const request: TomaInventarioRequest = {
+    responsable: responsable.trim(),
+    conteos: Object.entries(conteos)
+      .filter(([, valor]) => valor !== '')
+      .map(([insumoId, valor]) => ({ insumoId, cantidad: parseFloat(valor) })),
+  };
+  const puedeEnviar = request.responsable !== '' && request.conteos.length > 0;
+
+  const verDiferencias = () => {
+    previsualizar.mutate(request, {
+      onSuccess: setVistaPrevia,
+      onError: (err: any) => {
+        toast.error(err?.response?.data?.message || 'No se pudo comparar el conteo');
+      },
+    });
+  };
+
+  const confirmarToma = () => {
+    confirmar.mutate(request, {
+      onSuccess: (toma) => {
+        toast.success(
+          toma.insumosConDiferencia === 0
+            ? 'Toma registrada: el conteo coincide con el stock'
+            : `Toma registrada: se ajustaron ${toma.insumosConDiferencia} insumos`,
+        );
+        setConteos({});
+        setVistaPrevia(null);
+      },
+      onError: (err: any) => {
+        toast.error(err?.response?.data?.message || 'No se pudo registrar la toma');
+      },
+    });
+  };
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/gastos"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Inventario</h1>
+            <p className="text-sm text-gray-500">Conteo físico de insumos contra el stock teórico</p>
+          </div>
+          <Link
+            to="/caja/inventario/compras"
+            className="ml-auto h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <ShoppingCart size={14} />
+            Órdenes de compra
+          </Link>
+          <Link
+            to="/caja/inventario/mermas"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Trash size={14} />
+            Mermas
+          </Link>
+        </header>
+
+        <div className="flex flex-wrap items-center gap-2">
+          <span className="text-xs font-semibold text-gray-500 uppercase tracking-widest mr-2">Responsable</span>
+          <input
+            type="text"
+            value={responsable}
+            maxLength={60}
+            onChange={(e) => setResponsable(e.target.value)}
+            placeholder="Quién contó"
+            className={`${inputFecha} w-48`}
+          />
+          <button
+            type="button"
+            onClick={verDiferencias}
+            disabled={!puedeEnviar || previsualizar.isPending}
+            className="ml-auto h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 transition-colors disabled:opacity-40"
+          >
+            Ver diferencias
+          </button>
+          <button
+            type="button"
+            onClick={confirmarToma}
+            disabled={!vistaPrevia || confirmar.isPending}
+            className="h-9 px-3 rounded-lg bg-red-600 hover:bg-red-700 text-sm text-white flex items-center gap-1.5 transition-colors disabled:opacity-40"
+          >
+            <ClipboardCheck size={14} />
+            Confirmar toma
+          </button>
+        </div>
+
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando insumos...
+          </div>
+        ) : insumos.length === 0 ? (
+          <p className="text-xs text-gray-500">No hay insumos cargados. Se dan de alta desde el costeo de recetas.</p>
+        ) : (
+          <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+            <table className="w-full text-sm">
+              <thead>
+                <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                  <th className="text-left font-medium px-4 py-2">Insumo</th>
+                  <th className="text-right font-medium px-3 py-2">Stock teórico</th>
+                  <th className="text-right font-medium px-4 py-2">Contado</th>
+                </tr>
+              </thead>
+              <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+                {insumos.map((i) => (
+                  <tr key={i.id}>
+                    <td className="px-4 py-2 font-sans text-gray-100">{i.nombre}</td>
+                    <td className="text-right px-3 py-2 text-gray-500">
+                      {i.controlaStock
+                        ? `${fmtCantidad(i.stockActual)} ${UNIDAD_INSUMO_LABELS[i.unidad]}`
+                        : 'sin control'}
+                    </td>
+                    <td className="text-right px-4 py-1.5">
+                      <input
+                        type="number"
+                        min={0}
+                        step={0.001}
+                        value={conteos[i.id] ?? ''}
+                        onChange={(e) => cambiarConteo(i.id, e.target.value)}
+                        placeholder="—"
+                        className={`${inputFecha} w-28 text-right`}
+                      />
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+        )}
+
+        {vistaPrevia && <ReporteDiferencias toma={vistaPrevia} />}
+
+        <div className="space-y-3 pt-2">
+          <div className="flex items-center gap-2 text-xs font-semibold text-gray-500 uppercase tracking-widest">
+            <History size={14} />
+            Tomas anteriores
+          </div>
+          <HistorialTomas />
+        </div>
+      </div>
+    </section>
+  );
+}
@@ -0,0 +1,154 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { inventarioApi } from '../api/inventarioApi';
+import type {
+  Merma,
+  MermaRequest,
+  MovimientoInsumo,
+  OrdenCompraSugerida,
+  ReporteMermas,
+  ReposicionInsumoRequest,
+  TomaInventario,
+  TomaInventarioRequest,
+} from '../types';
+
+export const inventarioKeys = {
+  tomas: ['inventario', 'tomas'] as const,
+  toma: (tomaId: string) => ['inventario', 'tomas', tomaId] as const,
+  movimientos: (insumoId: string) => ['inventario', 'movimientos', insumoId] as const,
+  ordenesCompra: ['inventario', 'ordenes-compra'] as const,
+  mermas: ['inventario', 'mermas'] as const,
+  mermasDelRango: (desde: string, hasta: string) => ['inventario', 'mermas', desde, hasta] as const,
+  reporteMermas: (periodo: string) => ['inventario', 'mermas', 'reporte', periodo] as const,
+};
+
+/**
+ * Tomas de inventario del local, la más reciente primero.
+ *
+ * queryKey: ['inventario', 'tomas']
+ */
+export function useTomasInventario() {
+  return useQuery<TomaInventario[]>({
+    queryKey: inventarioKeys.tomas,
+    queryFn: () => inventarioApi.listarTomas(),
+  });
+}
+
+/**
+ * queryKey: ['inventario', 'tomas', tomaId]
+ */
+export function useTomaInventario(tomaId: string | null) {
+  return useQuery<TomaInventario>({
+    queryKey: inventarioKeys.toma(tomaId ?? ''),
+    queryFn: () => inventarioApi.consultarToma(tomaId!),
+    enabled: !!tomaId,
+  });
+}
+
+/**
+ * Historial de stock de un insumo (ventas, reaperturas y ajustes).
+ *
+ * queryKey: ['inventario', 'movimientos', insumoId]
+ */
+export function useMovimientosInsumo(insumoId: string | null) {
+  return useQuery<MovimientoInsumo[]>({
+    queryKey: inventarioKeys.movimientos(insumoId ?? ''),
+    queryFn: () => inventarioApi.listarMovimientos(insumoId!),
+    enabled: !!insumoId,
+  });
+}
+
+/**
+ * Reporte de diferencias del conteo; no modifica nada, por eso no invalida.
+ */
+export function usePrevisualizarToma() {
+  return useMutation({
+    mutationFn: (request: TomaInventarioRequest) => inventarioApi.previsualizar(request),
+  });
+}
+
+/**
+ * Confirmar ajusta el stock de los insumos contados: se refrescan los
+ * insumos del catálogo además del historial.
+ */
+export function useConfirmarToma() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (request: TomaInventarioRequest) => inventarioApi.confirmar(request),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['inventario'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['insumos'] });
+    },
+  });
+}
+
+/**
+ * Órdenes de compra sugeridas con los insumos que llegaron al mínimo.
+ * Se recalculan en cada visita: dependen de lo que se vendió.
+ *
+ * queryKey: ['inventario', 'ordenes-compra']
+ */
+export function useOrdenesCompraSugeridas() {
+  return useQuery<OrdenCompraSugerida[]>({
+    queryKey: inventarioKeys.ordenesCompra,
+    queryFn: () => inventarioApi.sugerirOrdenesCompra(),
+    refetchOnMount: 'always',
+  });
+}
+
+/**
+ * Stock mínimo y proveedor habitual de un insumo.
+ */
+export function useDefinirReposicion() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ insumoId, request }: { insumoId: string; request: ReposicionInsumoRequest }) =>
+      inventarioApi.definirReposicion(insumoId, request),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: inventarioKeys.ordenesCompra });
+      queryClient.invalidateQueries({ queryKey: ['insumos'] });
+    },
+  });
+}
+
+/**
+ * Mermas registradas en el rango, la más reciente primero.
+ *
+ * queryKey: ['inventario', 'mermas', desde, hasta]
+ */
+export function useMermas(desde: string, hasta: string) {
+  return useQuery<Merma[]>({
+    queryKey: inventarioKeys.mermasDelRango(desde, hasta),
+    queryFn: () => inventarioApi.listarMermas(desde, hasta),
+  });
+}
+
+/**
+ * Merma valorizada del mes por categoría y por insumo.
+ *
+ * queryKey: ['inventario', 'mermas', 'reporte', periodo]
+ */
+export function useReporteMermas(periodo: string) {
+  return useQuery<ReporteMermas>({
+    queryKey: inventarioKeys.reporteMermas(periodo),
+    queryFn: () => inventarioApi.reporteMermas(periodo),
+  });
+}
+
+/**
+ * Registrar una merma descuenta stock: se refrescan las mermas, las
+ * órdenes de compra (puede dejar insumos bajo el mínimo) y los insumos.
+ */
+export function useRegistrarMerma() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (request: MermaRequest) => inventarioApi.registrarMerma(request),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: inventarioKeys.mermas });
+      queryClient.invalidateQueries({ queryKey: inventarioKeys.ordenesCompra });
+      queryClient.invalidateQueries({ queryKey: ['insumos'] });
+    },
+  });
+}
@@ -0,0 +1,53 @@
+/**
+ * Módulo Inventario — toma de inventario físico de insumos contra el stock
+ * teórico que descuentan las ventas, con reporte de diferencias valorizado
+ * (HU-133), órdenes de compra sugeridas por stock mínimo (HU-134) y
+ * registro de mermas con reporte mensual valorizado (HU-135).
+ *
+ * @example
+ * import { TomaInventarioPage, useTomasInventario } from '@/features/inventario';
+ */
+
+// Tipos
+export type {
+  ConteoInsumoRequest,
+  TomaInventarioRequest,
+  RenglonInventario,
+  TomaInventario,
+  TipoMovimientoInsumo,
+  MovimientoInsumo,
+  ReposicionInsumoRequest,
+  RenglonOrdenCompra,
+  OrdenCompraSugerida,
+  CategoriaMerma,
+  InsumoMermaRequest,
+  MermaRequest,
+  RenglonMerma,
+  Merma,
+  CategoriaMermaMes,
+  InsumoMermaMes,
+  ReporteMermas,
+} from './types';
+export { CATEGORIA_MERMA_LABELS } from './types';
+
+// Hooks
+export {
+  useTomasInventario,
+  useTomaInventario,
+  useMovimientosInsumo,
+  usePrevisualizarToma,
+  useConfirmarToma,
+  useOrdenesCompraSugeridas,
+  useDefinirReposicion,
+  useMermas,
+  useReporteMermas,
+  useRegistrarMerma,
+} from './hooks/useInventario';
+
+// Componentes
+export { default as TomaInventarioPage } from './components/TomaInventarioPage';
+export { default as OrdenesCompraPage } from './components/OrdenesCompraPage';
+export { default as MermasPage } from './components/MermasPage';
+
+// API
+export { inventarioApi } from './api/inventarioApi';
@@ -0,0 +1,175 @@
+import type { UnidadInsumo } from '../catalogo/types';
+
+// ─── Inventario físico de insumos (HU-133) ───────────────────────────────────
+
+export interface ConteoInsumoRequest {
+  insumoId: string;
+  /** En la unidad del insumo (kg, litros o unidades) */
+  cantidad: number;
+}
+
+export interface TomaInventarioRequest {
+  responsable: string;
+  /** Solo los insumos contados; los demás no se tocan */
+  conteos: ConteoInsumoRequest[];
+}
+
+export interface RenglonInventario {
+  insumoId: string;
+  nombre: string;
+  unidad: UnidadInsumo;
+  stockTeorico: number;
+  stockContado: number;
+  /** Contado − teórico: negativo = faltante */
+  diferencia: number;
+  costoUnitario: number;
+  diferenciaValorizada: number;
+}
+
+/**
+ * Reporte de diferencias de una toma.
+ * Refleja TomaInventarioResponse del backend.
+ */
+export interface TomaInventario {
+  /** null en la previsualización */
+  id: string | null;
+  fecha: string;
+  responsable: string;
+  /** De la mayor pérdida a la mayor ganancia */
+  insumos: RenglonInventario[];
+  insumosConDiferencia: number;
+  /** En positivo */
+  faltanteValorizado: number;
+  sobranteValorizado: number;
+  /** Neto: negativo si en total faltó mercadería */
+  diferenciaValorizada: number;
+}
+
+export type TipoMovimientoInsumo = 'CONSUMO_VENTA' | 'REAPERTURA_PEDIDO' | 'AJUSTE_INVENTARIO' | 'MERMA';
+
+export interface MovimientoInsumo {
+  id: string;
+  tipo: TipoMovimientoInsumo;
+  /** Positiva si entró, negativa si salió */
+  cantidad: number;
+  fecha: string;
+  motivo: string;
+}
+
+// ─── Órdenes de compra por stock mínimo (HU-134) ─────────────────────────────
+
+export interface ReposicionInsumoRequest {
+  /** null = no sugerirlo en las órdenes de compra */
+  stockMinimo: number | null;
+  proveedorHabitualId: string | null;
+}
+
+export interface RenglonOrdenCompra {
+  insumoId: string;
+  nombre: string;
+  unidad: UnidadInsumo;
+  stockActual: number;
+  stockMinimo: number;
+  /** Lo que falta para llegar al doble del mínimo */
+  cantidadSugerida: number;
+  costoUnitario: number;
+  costoEstimado: number;
+}
+
+/**
+ * Orden sugerida para un proveedor. Sin proveedor habitual, proveedorId
+ * y los datos de contacto vienen en null.
+ */
+export interface OrdenCompraSugerida {
+  proveedorId: string | null;
+  nombreProveedor: string | null;
+  telefono: string | null;
+  email: string | null;
+  insumos: RenglonOrdenCompra[];
+  costoEstimado: number;
+}
+
+// ─── Mermas y desperdicio (HU-135) ───────────────────────────────────────────
+
+export type CategoriaMerma = 'VENCIMIENTO' | 'PLATO_CAIDO' | 'ERROR_COCINA' | 'OTRO';
+
+export const CATEGORIA_MERMA_LABELS: Record<CategoriaMerma, string> = {
+  VENCIMIENTO: 'Vencimiento',
+  PLATO_CAIDO: 'Plato caído',
+  ERROR_COCINA: 'Error de cocina',
+  OTRO: 'Otro',
+};
+
+export interface InsumoMermaRequest {
+  insumoId: string;
+  /** En la unidad del insumo */
+  cantidad: number;
+}
+
+/**
+ * Por producto (productoId + unidades: se descuenta su receta) o por
+ * insumo (insumos); no las dos cosas a la vez.
+ */
+export interface MermaRequest {
+  categoria: CategoriaMerma;
+  motivo: string;
+  responsable: string;
+  productoId: string | null;
+  unidades: number | null;
+  insumos: InsumoMermaRequest[] | null;
+}
+
+export interface RenglonMerma {
+  insumoId: string;
+  nombre: string;
+  unidad: UnidadInsumo;
+  cantidad: number;
+  costoUnitario: number;
+  costoValorizado: number;
+}
+
+/**
+ * Refleja MermaResponse del backend.
+ */
+export interface Merma {
+  id: string;
+  fecha: string;
+  categoria: CategoriaMerma;
+  motivo: string;
+  responsable: string;
+  /** null si se cargó por insumo */
+  productoId: string | null;
+  nombreProducto: string | null;
+  unidadesProducto: number | null;
+  insumos: RenglonMerma[];
+  costoTotal: number;
+}
+
+export interface CategoriaMermaMes {
+  categoria: CategoriaMerma;
+  registros: number;
+  total: number;
+  /** Participación sobre el total del mes */
+  porcentaje: number;
+}
+
+export interface InsumoMermaMes {
+  insumoId: string;
+  nombre: string;
+  unidad: UnidadInsumo;
+  cantidad: number;
+  total: number;
+}
+
+/**
+ * Refleja ReporteMermasResponse del backend.
+ * Categorías e insumos vienen de mayor a menor pérdida.
+ */
+export interface ReporteMermas {
+  /** YYYY-MM */
+  periodo: string;
+  registros: number;
+  total: number;
+  categorias: CategoriaMermaMes[];
+  insumos: InsumoMermaMes[];
+}
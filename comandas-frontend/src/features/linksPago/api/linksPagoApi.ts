@@ -0,0 +1,40 @@
+import apiClient from '../../../lib/apiClient';
+import type { GenerarLinkPagoRequest, LinkPago } from '../types';
+
+/**
+ * API client de los links de pago de delivery (HU-128).
+ * Consume /api/links-pago de LinkPagoController.
+ */
+export const linksPagoApi = {
+  generar: async (mesaId: string, request: GenerarLinkPagoRequest): Promise<LinkPago> => {
+    const response = await apiClient.post<LinkPago>(`/links-pago/mesas/${mesaId}`, request);
+    return response.data;
+  },
+
+  /** Último link del pedido de la mesa; el backend responde 204 si nunca se generó */
+  consultarDeMesa: async (mesaId: string): Promise<LinkPago | null> => {
+    const response = await apiClient.get<LinkPago>(`/links-pago/mesas/${mesaId}`);
+    return response.status === 204 ? null : response.data;
+  },
+
+  listarPendientes: async (): Promise<LinkPago[]> => {
+    const response = await apiClient.get<LinkPago[]>('/links-pago/pendientes');
+    return response.data;
+  },
+
+  /** Consulta a la pasarela todos los pendientes; devuelve los que se acreditaron */
+  verificarPendientes: async (): Promise<LinkPago[]> => {
+    const response = await apiClient.post<LinkPago[]>('/links-pago/verificar');
+    return response.data;
+  },
+
+  verificar: async (linkId: string): Promise<LinkPago> => {
+    const response = await apiClient.post<LinkPago>(`/links-pago/${linkId}/verificar`);
+    return response.data;
+  },
+
+  cancelar: async (linkId: string): Promise<LinkPago> => {
+    const response = await apiClient.post<LinkPago>(`/links-pago/${linkId}/cancelar`);
+    return response.data;
+  },
+};
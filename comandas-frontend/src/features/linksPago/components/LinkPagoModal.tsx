@@ -0,0 +1,280 @@
+import { useState } from 'react';
+import { CheckCircle2, Link2, Loader2, MessageCircle, RefreshCw, X } from 'lucide-react';
+import { useCancelarLinkPago, useGenerarLinkPago, useLinkPagoMesa, useVerificarLinkPago } from '../hooks/useLinksPago';
+import { abrirEnlace } from '../utils/abrirEnlace';
+import { copiarAlPortapapeles } from '../../../lib/portapapeles';
+import { PROVEEDOR_LINK_PAGO_LABELS, type LinkPago, type ProveedorLinkPago } from '../types';
+import useToast from '../../../hooks/useToast';
+
+interface LinkPagoModalProps {
+  mesaId: string;
+  numeroMesa: number;
+  /** Total actual del pedido: si difiere del link pendiente hay que generar otro */
+  total: number;
+  onClose: () => void;
+}
+
+/**
+ * Cobro por link de un pedido telefónico de delivery (HU-128).
+ *
+ * El operador genera el link por el total y se lo manda al cliente por
+ * WhatsApp. El pedido queda retenido: sale a cocina solo cuando la pasarela
+ * confirma el pago. Cancelar el link lo libera para cobrarlo al entregar.
+ */
+export default function LinkPagoModal({ mesaId, numeroMesa, total, onClose }: LinkPagoModalProps) {
+  const toast = useToast();
+  const { data: link, isLoading } = useLinkPagoMesa(mesaId);
+  const generar = useGenerarLinkPago();
+  const verificar = useVerificarLinkPago();
+  const cancelar = useCancelarLinkPago();
+
+  const [proveedor, setProveedor] = useState<ProveedorLinkPago>('MERCADO_PAGO');
+  const [telefono, setTelefono] = useState('');
+
+  const ocupado = generar.isPending || verificar.isPending || cancelar.isPending;
+  const pendiente = link?.estado === 'PENDIENTE' ? link : null;
+  const desactualizado = pendiente !== null && pendiente.monto !== total;
+
+  const handleGenerar = () => {
+    generar.mutate(
+      { mesaId, proveedor, telefono: telefono.trim() || pendiente?.telefonoCliente || undefined },
+      {
+        onSuccess: (nuevo) => {
+          toast.success(`Link de pago generado por $ ${nuevo.monto.toLocaleString('es-AR')}`);
+          if (nuevo.whatsappUrl) abrirEnlace(nuevo.whatsappUrl).catch((e: Error) => toast.error(e.message));
+        },
+        onError: (err: any) => {
+          toast.error(err?.response?.data?.message || 'No se pudo generar el link de pago');
+        },
+      },
+    );
+  };
+
+  const handleVerificar = (actual: LinkPago) => {
+    verificar.mutate(actual.id, {
+      onSuccess: (verificado) => {
+        if (verificado.estado === 'PAGADO') {
+          toast.success(`Mesa ${numeroMesa}: pago acreditado, pasó a cocina`);
+        } else {
+          toast.info('Todavía no se acreditó el pago');
+        }
+      },
+      onError: (err: any) => {
+        toast.error(err?.response?.data?.message || 'No se pudo consultar el pago');
+      },
+    });
+  };
+
+  const handleCancelar = (actual: LinkPago) => {
+    cancelar.mutate(actual.id, {
+      onSuccess: () => toast.info('Link cancelado: el pedido ya se puede mandar a cocina'),
+      onError: (err: any) => {
+        toast.error(err?.response?.data?.message || 'No se pudo cancelar el link');
+      },
+    });
+  };
+
+  const handleCopiar = async (url: string) => {
+    try {
+      await copiarAlPortapapeles(url);
+      toast.success('Link copiado');
+    } catch (err) {
+      toast.error(typeof err === 'string' ? err : 'No se pudo copiar el link');
+    }
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div
+        className="fixed inset-0 z-[80] bg-black/60 animate-backdrop-in"
+        onClick={onClose}
+        aria-hidden="true"
+      />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[90] flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="
+            pointer-events-auto
+            w-full max-w-md
+            bg-neutral-900 rounded-2xl
+            border border-neutral-700
+            shadow-2xl shadow-black/60
+            animate-modal-in
+          "
+        >
+          {/* ── Header ── */}
+          <div className="flex items-center justify-between px-5 py-4 border-b border-neutral-800">
+            <div className="flex items-center gap-2">
+              <Link2 size={18} className="text-red-400" />
+              <h2 className="text-lg font-bold text-gray-100">
+                Link de pago · Mesa {numeroMesa}
+              </h2>
+            </div>
+            <button
+              type="button"
+              onClick={onClose}
+              disabled={ocupado}
+              className="text-gray-500 hover:text-gray-300 transition-colors disabled:opacity-50"
+            >
+              <X size={20} />
+            </button>
+          </div>
+
+          {/* ── Body ── */}
+          <div className="px-5 py-4 space-y-5">
+            {isLoading ? (
+              <div className="flex justify-center py-6">
+                <Loader2 size={22} className="animate-spin text-gray-500" />
+              </div>
+            ) : link?.estado === 'PAGADO' ? (
+              <div className="flex flex-col items-center gap-2 py-4 text-center">
+                <CheckCircle2 size={36} className="text-emerald-400" />
+                <p className="text-sm font-semibold text-gray-100">
+                  Pagado $ {link.monto.toLocaleString('es-AR')} con {PROVEEDOR_LINK_PAGO_LABELS[link.proveedor]}
+                </p>
+                <p className="text-xs text-gray-500">El pedido ya salió a cocina.</p>
+              </div>
+            ) : pendiente ? (
+              <div className="space-y-3">
+                <div className="bg-neutral-800/50 border border-neutral-700/50 rounded-xl px-4 py-3 space-y-1">
+                  <p className="text-xs text-amber-400 font-semibold uppercase tracking-widest">
+                    Esperando el pago
+                  </p>
+                  <p className="text-2xl font-bold text-gray-100 font-mono tabular-nums">
+                    $ {pendiente.monto.toLocaleString('es-AR')}
+                  </p>
+                  <button
+                    type="button"
+                    onClick={() => handleCopiar(pendiente.url)}
+                    title="Copiar link"
+                    className="block w-full text-left text-xs text-gray-500 font-mono truncate hover:text-gray-300"
+                  >
+                    {pendiente.url}
+                  </button>
+                </div>
+
+                {desactualizado && (
+                  <p className="text-xs text-amber-400/90">
+                    El pedido cambió: el total ahora es $ {total.toLocaleString('es-AR')}. Generá un link nuevo.
+                  </p>
+                )}
+
+                <div className="flex gap-2">
+                  {pendiente.whatsappUrl && (
+                    <button
+                      type="button"
+                      onClick={() => abrirEnlace(pendiente.whatsappUrl!).catch((e: Error) => toast.error(e.message))}
+                      className="flex-1 flex items-center justify-center gap-1.5 h-10 rounded-xl bg-emerald-700 text-white text-sm font-semibold hover:bg-emerald-600 transition-colors"
+                    >
+                      <MessageCircle size={16} />
+                      Enviar por WhatsApp
+                    </button>
+                  )}
+                  <button
+                    type="button"
+                    onClick={() => handleVerificar(pendiente)}
+                    disabled={ocupado}
+                    className="flex-1 flex items-center justify-center gap-1.5 h-10 rounded-xl bg-neutral-800 text-gray-300 border border-neutral-700 text-sm font-semibold hover:border-neutral-600 disabled:opacity-50 transition-colors"
+                  >
+                    {verificar.isPending ? <Loader2 size={16} className="animate-spin" /> : <RefreshCw size={16} />}
+                    Verificar pago
+                  </button>
+                </div>
+
+                <div className="flex gap-2">
+                  <button
+                    type="button"
+                    onClick={handleGenerar}
+                    disabled={ocupado}
+                    className="flex-1 h-9 rounded-xl text-xs font-medium bg-neutral-800/60 text-gray-400 border border-neutral-700/40 hover:text-gray-300 disabled:opacity-50"
+                  >
+                    Generar de nuevo
+                  </button>
+                  <button
+                    type="button"
+                    onClick={() => handleCancelar(pendiente)}
+                    disabled={ocupado}
+                    className="flex-1 h-9 rounded-xl text-xs font-medium text-red-400 hover:bg-red-950/30 disabled:opacity-50"
+                  >
+                    Cobrar al entregar
+                  </button>
+                </div>
+              </div>
+            ) : (
+              <div className="space-y-4">
+                <div className="space-y-1.5">
+                  <label className="text-xs font-semibold text-gray-500 uppercase tracking-widest">
+                    Pasarela
+                  </label>
+                  <div className="grid grid-cols-2 gap-2" role="radiogroup" aria-label="Pasarela de pago">
+                    {(Object.keys(PROVEEDOR_LINK_PAGO_LABELS) as ProveedorLinkPago[]).map((p) => (
+                      <button
+                        key={p}
+                        type="button"
+                        role="radio"
+                        aria-checked={proveedor === p}
+                        onClick={() => setProveedor(p)}
+                        className={[
+                          'h-10 rounded-xl text-sm font-semibold border transition-colors',
+                          proveedor === p
+                            ? 'bg-red-600/20 border-red-600 text-red-300'
+                            : 'bg-neutral-800 border-neutral-700 text-gray-400 hover:border-neutral-600',
+                        ].join(' ')}
+                      >
+                        {PROVEEDOR_LINK_PAGO_LABELS[p]}
+                      </button>
+                    ))}
+                  </div>
+                </div>
+
+                <div className="space-y-1.5">
+                  <label
+                    htmlFor="telefono-link-pago"
+                    className="text-xs font-semibold text-gray-500 uppercase tracking-widest"
+                  >
+                    WhatsApp del cliente
+                  </label>
+                  <input
+                    id="telefono-link-pago"
+                    type="tel"
+                    value={telefono}
+                    maxLength={30}
+                    onChange={(e) => setTelefono(e.target.value)}
+                    placeholder="Ej: 54 9 11 5555-1234"
+                    className="
+                      w-full h-10 px-3
+                      bg-neutral-800 border border-neutral-700
+                      rounded-xl text-sm font-mono text-gray-200
+                      placeholder:text-gray-600
+                      focus:outline-none focus:border-red-600 focus:ring-1 focus:ring-red-600/30
+                      transition-colors
+                    "
+                  />
+                  <p className="text-[11px] text-gray-600">
+                    Con código de país y de área. Sin teléfono, el link se copia y se manda a mano.
+                  </p>
+                </div>
+
+                {link?.estado === 'CANCELADO' && (
+                  <p className="text-xs text-gray-500">El último link se canceló.</p>
+                )}
+
+                <button
+                  type="button"
+                  onClick={handleGenerar}
+                  disabled={ocupado || total <= 0}
+                  className="w-full flex items-center justify-center gap-2 h-11 rounded-xl bg-red-600 text-white text-sm font-semibold hover:bg-red-500 disabled:opacity-50 transition-colors active:scale-[0.98]"
+                >
+                  {generar.isPending ? <Loader2 size={16} className="animate-spin" /> : <Link2 size={16} />}
+                  Generar link por $ {total.toLocaleString('es-AR')}
+                </button>
+              </div>
+            )}
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
@@ -0,0 +1,45 @@
+import { CreditCard } from 'lucide-react';
+import { useLinksPagoPendientes } from '../hooks/useLinksPago';
+import { PROVEEDOR_LINK_PAGO_LABELS } from '../types';
+
+function minutosDesde(fecha: string): number {
+  return Math.max(0, Math.floor((Date.now() - new Date(fecha).getTime()) / 60_000));
+}
+
+/**
+ * Pedidos de delivery retenidos hasta que el cliente pague el link (HU-128).
+ *
+ * Se muestra en el salón solo mientras haya links pendientes. Mantenerlo
+ * montado es lo que verifica los pagos cuando la pasarela no puede avisar.
+ */
+export default function PanelLinksPago() {
+  const { data: pendientes } = useLinksPagoPendientes();
+
+  if (!pendientes || pendientes.length === 0) return null;
+
+  return (
+    <section className="border-b border-neutral-800 bg-sky-950/20 p-3 space-y-2 max-h-[35%] overflow-y-auto">
+      <h2 className="flex items-center gap-2 text-xs font-bold uppercase tracking-wider text-sky-400">
+        <CreditCard size={14} />
+        Esperando pago ({pendientes.length})
+      </h2>
+      <ul className="space-y-1">
+        {pendientes.map((link) => (
+          <li
+            key={link.id}
+            className="flex items-baseline justify-between gap-2 bg-neutral-900 border border-sky-800/40 rounded-lg px-3 py-2"
+          >
+            <span className="text-sm text-gray-200">
+              <span className="font-bold">Mesa {link.numeroMesa}</span>
+              <span className="text-gray-500"> · {PROVEEDOR_LINK_PAGO_LABELS[link.proveedor]}</span>
+            </span>
+            <span className="text-right shrink-0">
+              <span className="block font-mono text-sm text-gray-200">$ {link.monto.toLocaleString('es-AR')}</span>
+              <span className="block text-[11px] text-sky-400">hace {minutosDesde(link.creadoEn)} min</span>
+            </span>
+          </li>
+        ))}
+      </ul>
+    </section>
+  );
+}
@@ -0,0 +1,98 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { linksPagoApi } from '../api/linksPagoApi';
+import type { GenerarLinkPagoRequest, LinkPago } from '../types';
+
+export const linksPagoKeys = {
+  pendientes: ['links-pago', 'pendientes'] as const,
+  mesa: (mesaId: string) => ['links-pago', 'mesa', mesaId] as const,
+};
+
+/**
+ * Links esperando el pago del cliente.
+ *
+ * Si la pasarela no puede avisarle a esta instalación (sin dirección
+ * pública), el pago se detecta acá: cada vuelta le pide al backend que
+ * verifique los pendientes antes de listarlos. Lo que se acredita llega al
+ * resto de las terminales por el evento "link-pago-pagado".
+ *
+ * queryKey: ['links-pago', 'pendientes']
+ */
+export function useLinksPagoPendientes() {
+  return useQuery<LinkPago[]>({
+    queryKey: linksPagoKeys.pendientes,
+    queryFn: async () => {
+      try {
+        await linksPagoApi.verificarPendientes();
+      } catch (error) {
+        console.warn('[useLinksPagoPendientes] No se pudieron verificar los pagos:', error);
+      }
+      return linksPagoApi.listarPendientes();
+    },
+    refetchInterval: 30_000,
+    staleTime: 0,
+  });
+}
+
+/**
+ * Último link del pedido de la mesa (null si nunca se generó uno).
+ *
+ * queryKey: ['links-pago', 'mesa', mesaId]
+ */
+export function useLinkPagoMesa(mesaId: string) {
+  return useQuery<LinkPago | null>({
+    queryKey: linksPagoKeys.mesa(mesaId),
+    queryFn: () => linksPagoApi.consultarDeMesa(mesaId),
+    staleTime: 0,
+  });
+}
+
+/** Refresca los links y, si se acreditó, también el salón y el pedido que pasó a cocina */
+function useInvalidarLink() {
+  const queryClient = useQueryClient();
+
+  return (link: LinkPago) => {
+    queryClient.invalidateQueries({ queryKey: linksPagoKeys.pendientes });
+    queryClient.invalidateQueries({ queryKey: linksPagoKeys.mesa(link.mesaId) });
+    if (link.estado === 'PAGADO') {
+      queryClient.invalidateQueries({ queryKey: ['mesas'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['pedido', link.mesaId] });
+    }
+  };
+}
+
+export function useGenerarLinkPago() {
+  const invalidar = useInvalidarLink();
+
+  return useMutation({
+    mutationFn: ({ mesaId, ...request }: GenerarLinkPagoRequest & { mesaId: string }) =>
+      linksPagoApi.generar(mesaId, request),
+    onSuccess: invalidar,
+    onError: (error: unknown) => {
+      console.error('[useGenerarLinkPago] Error al generar link de pago:', error);
+    },
+  });
+}
+
+export function useVerificarLinkPago() {
+  const invalidar = useInvalidarLink();
+
+  return useMutation({
+    mutationFn: (linkId: string) => linksPagoApi.verificar(linkId),
+    onSuccess: invalidar,
+    onError: (error: unknown) => {
+      console.error('[useVerificarLinkPago] Error al verificar pago:', error);
+    },
+  });
+}
+
+export function useCancelarLinkPago() {
+  const invalidar = useInvalidarLink();
+
+  return useMutation({
+    mutationFn: (linkId: string) => linksPagoApi.cancelar(linkId),
+    onSuccess: invalidar,
+    onError: (error: unknown) => {
+      console.error('[useCancelarLinkPago] Error al cancelar link de pago:', error);
+    },
+  });
+}
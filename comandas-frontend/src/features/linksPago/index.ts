@@ -0,0 +1,36 @@
+/**
+ * Módulo Links de Pago — cobro previo de los pedidos de delivery por
+ * Mercado Pago / MODO; el pedido sale a cocina al acreditarse (HU-128).
+ *
+ * @example
+ * import { LinkPagoModal, PanelLinksPago } from '@/features/linksPago';
+ */
+
+// Tipos
+export type {
+  ProveedorLinkPago,
+  EstadoLinkPago,
+  LinkPago,
+  GenerarLinkPagoRequest,
+} from './types';
+export { PROVEEDOR_LINK_PAGO_LABELS } from './types';
+
+// Hooks
+export {
+  linksPagoKeys,
+  useLinksPagoPendientes,
+  useLinkPagoMesa,
+  useGenerarLinkPago,
+  useVerificarLinkPago,
+  useCancelarLinkPago,
+} from './hooks/useLinksPago';
+
+// Componentes
+export { default as LinkPagoModal } from './components/LinkPagoModal';
+export { default as PanelLinksPago } from './components/PanelLinksPago';
+
+// Utils
+export { abrirEnlace } from './utils/abrirEnlace';
+
+// API
+export { linksPagoApi } from './api/linksPagoApi';
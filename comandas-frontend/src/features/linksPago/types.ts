@@ -0,0 +1,40 @@
+// ─── Links de pago para delivery (HU-128) ────────────────────────────────────
+
+/** MODO figura aunque hoy no tenga integración: el backend lo informa como no disponible */
+export type ProveedorLinkPago = 'MERCADO_PAGO' | 'MODO';
+
+export const PROVEEDOR_LINK_PAGO_LABELS: Record<ProveedorLinkPago, string> = {
+  MERCADO_PAGO: 'Mercado Pago',
+  MODO: 'MODO',
+};
+
+/**
+ * Mientras el link está PENDIENTE el pedido no sale a cocina.
+ * Al pasar a PAGADO el backend lo manda solo.
+ */
+export type EstadoLinkPago = 'PENDIENTE' | 'PAGADO' | 'CANCELADO';
+
+/**
+ * También es el payload del evento en tiempo real "link-pago-pagado".
+ */
+export interface LinkPago {
+  id: string;
+  pedidoId: string;
+  mesaId: string;
+  numeroMesa: number;
+  numeroPedido: number;
+  proveedor: ProveedorLinkPago;
+  monto: number;
+  telefonoCliente: string | null;
+  url: string;
+  /** wa.me con el mensaje armado; null si no se cargó teléfono */
+  whatsappUrl: string | null;
+  estado: EstadoLinkPago;
+  creadoEn: string;
+  pagadoEn: string | null;
+}
+
+export interface GenerarLinkPagoRequest {
+  proveedor: ProveedorLinkPago;
+  telefono?: string;
+}
@@ -0,0 +1,28 @@
+/**
+ * Abre un enlace externo (WhatsApp, SMS, el checkout de la pasarela).
+ *
+ * En el escritorio el webview no abre pestañas nuevas: se delega en el
+ * sistema a través del plugin shell de Tauri, que solo abre los esquemas
+ * permitidos en `plugins.shell.open` de tauri.conf.json.
+ *
+ * @throws Error con un mensaje para mostrar si el sistema no lo pudo abrir
+ */
+export async function abrirEnlace(url: string): Promise<void> {
+  if ('__TAURI_INTERNALS__' in window) {
+    const { invoke } = await import('@tauri-apps/api/core');
+    try {
+      await invoke('plugin:shell|open', { path: url });
+    } catch (e) {
+      console.error('[abrirEnlace] El sistema rechazó el enlace:', url, e);
+      throw new Error(`No se pudo abrir ${descripcion(url)}. Revisá que haya una aplicación para abrirlo.`);
+    }
+    return;
+  }
+  window.open(url, '_blank', 'noopener');
+}
+
+function descripcion(url: string): string {
+  if (url.startsWith('sms:')) return 'el SMS';
+  if (url.includes('wa.me')) return 'WhatsApp';
+  return 'el enlace';
+}
@@ -0,0 +1,34 @@
+import apiClient from '../../../lib/apiClient';
+import type { ClienteEnEspera, ClienteEnEsperaRequest } from '../types';
+
+/**
+ * API client de la lista de espera de mesas (HU-149).
+ * Consume /api/lista-espera de ListaEsperaController.
+ */
+export const listaEsperaApi = {
+  listar: async (): Promise<ClienteEnEspera[]> => {
+    const response = await apiClient.get<ClienteEnEspera[]>('/lista-espera');
+    return response.data;
+  },
+
+  anotar: async (request: ClienteEnEsperaRequest): Promise<ClienteEnEspera> => {
+    const response = await apiClient.post<ClienteEnEspera>('/lista-espera', request);
+    return response.data;
+  },
+
+  /** Le guarda la mesa libre; la respuesta trae el aviso para WhatsApp y SMS */
+  avisar: async (clienteId: string, mesaId: string): Promise<ClienteEnEspera> => {
+    const response = await apiClient.post<ClienteEnEspera>(`/lista-espera/${clienteId}/avisar`, { mesaId });
+    return response.data;
+  },
+
+  sentar: async (clienteId: string): Promise<ClienteEnEspera> => {
+    const response = await apiClient.post<ClienteEnEspera>(`/lista-espera/${clienteId}/sentar`);
+    return response.data;
+  },
+
+  cancelar: async (clienteId: string): Promise<ClienteEnEspera> => {
+    const response = await apiClient.post<ClienteEnEspera>(`/lista-espera/${clienteId}/cancelar`);
+    return response.data;
+  },
+};
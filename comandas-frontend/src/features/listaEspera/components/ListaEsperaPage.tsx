@@ -0,0 +1,333 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, BellRing, Clock, Loader2, MessageCircle, MessageSquare, Phone, Users, X } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useMesas } from '../../salon/hooks/useMesas';
+import { abrirEnlace } from '../../linksPago/utils/abrirEnlace';
+import {
+  useAnotarEnEspera,
+  useAvisarEnEspera,
+  useCancelarEnEspera,
+  useListaEspera,
+  useSentarEnEspera,
+} from '../hooks/useListaEspera';
+import { ESTADO_ESPERA_LABELS, type ClienteEnEspera } from '../types';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmtEspera(minutos: number): string {
+  if (minutos < 60) return `${minutos} min`;
+  return `${Math.floor(minutos / 60)} h ${String(minutos % 60).padStart(2, '0')} min`;
+}
+
+function hora(fechaHora: string): string {
+  return fechaHora.slice(11, 16);
+}
+
+const inputClass =
+  'min-h-[44px] w-full px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none';
+
+const ESTADO_COLORES = {
+  ESPERANDO: 'bg-amber-900/30 text-amber-400',
+  AVISADO: 'bg-green-900/30 text-green-400',
+  SENTADO: 'bg-neutral-800 text-gray-500',
+  CANCELADO: 'bg-neutral-800 text-gray-500',
+} as const;
+
+// ─── Anotar cliente ───────────────────────────────────────────────────────────
+
+function AnotarCliente() {
+  const toast = useToast();
+  const anotar = useAnotarEnEspera();
+
+  const [nombre, setNombre] = useState('');
+  const [personas, setPersonas] = useState('2');
+  const [telefono, setTelefono] = useState('');
+  const [observaciones, setObservaciones] = useState('');
+
+  const personasNumero = Number(personas);
+  const puedeGuardar =
+    nombre.trim() !== '' && Number.isInteger(personasNumero) && personasNumero > 0 && !anotar.isPending;
+
+  const handleAnotar = () => {
+    anotar.mutate(
+      {
+        nombreCliente: nombre.trim(),
+        personas: personasNumero,
+        telefono: telefono.trim() || undefined,
+        observaciones: observaciones.trim() || undefined,
+      },
+      {
+        onSuccess: (c) => {
+          toast.success(`${c.nombreCliente} anotado para ${c.personas}`);
+          setNombre('');
+          setPersonas('2');
+          setTelefono('');
+          setObservaciones('');
+        },
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo anotar al cliente'),
+      },
+    );
+  };
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-3">
+      <p className="text-[10px] uppercase tracking-wider text-gray-600">Anotar en la lista</p>
+
+      <label className="flex flex-col gap-1 text-sm text-text-secondary">
+        Nombre
+        <input type="text" value={nombre} maxLength={80} onChange={(e) => setNombre(e.target.value)} className={inputClass} />
+      </label>
+
+      <div className="grid grid-cols-3 gap-2">
+        <label className="flex flex-col gap-1 text-sm text-text-secondary">
+          Personas
+          <input type="number" min={1} value={personas} onChange={(e) => setPersonas(e.target.value)} className={`${inputClass} font-mono`} />
+        </label>
+        <label className="col-span-2 flex flex-col gap-1 text-sm text-text-secondary">
+          Teléfono
+          <input type="tel" value={telefono} maxLength={30} onChange={(e) => setTelefono(e.target.value)} className={inputClass} />
+        </label>
+      </div>
+
+      <label className="flex flex-col gap-1 text-sm text-text-secondary">
+        Observaciones
+        <input
+          type="text"
+          value={observaciones}
+          maxLength={200}
+          placeholder="Afuera, con cochecito..."
+          onChange={(e) => setObservaciones(e.target.value)}
+          className={inputClass}
+        />
+      </label>
+
+      <p className="text-xs text-gray-500">
+        Con el teléfono en formato internacional (ej: 54 9 11 5555-1234) el aviso sale por WhatsApp o SMS.
+      </p>
+
+      <div className="flex justify-end">
+        <button
+          onClick={handleAnotar}
+          disabled={!puedeGuardar}
+          className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+        >
+          {anotar.isPending && <Loader2 size={16} className="animate-spin" />}
+          Anotar
+        </button>
+      </div>
+    </div>
+  );
+}
+
+// ─── Tarjeta de cliente en espera ─────────────────────────────────────────────
+
+function TarjetaEspera({ cliente, posicion }: { cliente: ClienteEnEspera; posicion: number }) {
+  const toast = useToast();
+  const avisar = useAvisarEnEspera();
+  const sentar = useSentarEnEspera();
+  const cancelar = useCancelarEnEspera();
+  const { data: mesas = [] } = useMesas();
+  const mesasLibres = mesas.filter((m) => m.estado === 'LIBRE');
+
+  const [mesaAviso, setMesaAviso] = useState('');
+  const mesaElegida = mesaAviso || cliente.mesaSugerida?.mesaId || '';
+
+  const handleAvisar = () => {
+    avisar.mutate(
+      { clienteId: cliente.id, mesaId: mesaElegida },
+      {
+        onSuccess: (c) => {
+          setMesaAviso('');
+          if (c.whatsappUrl) {
+            abrirEnlace(c.whatsappUrl).catch((e: Error) => toast.error(e.message));
+          } else {
+            toast.success(`Mesa ${c.numeroMesa} guardada para ${c.nombreCliente}. Avisale en persona.`);
+          }
+        },
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo avisar al cliente'),
+      },
+    );
+  };
+
+  const handleSentar = () => {
+    sentar.mutate(cliente.id, {
+      onSuccess: (c) =>
+        toast.success(c.numeroMesa ? `${c.nombreCliente} pasó a la mesa ${c.numeroMesa}` : `${c.nombreCliente} ya tiene mesa`),
+      onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo sentar al cliente'),
+    });
+  };
+
+  const handleCancelar = () => {
+    cancelar.mutate(cliente.id, {
+      onSuccess: (c) => toast.success(`${c.nombreCliente} salió de la lista`),
+      onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo sacar al cliente de la lista'),
+    });
+  };
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50">
+      <div className="flex items-center gap-3 px-4 py-3">
+        <p className="w-7 text-lg font-mono tabular-nums text-gray-600">{posicion}</p>
+        <div className="min-w-0">
+          <p className="text-sm font-semibold text-gray-100 truncate">{cliente.nombreCliente}</p>
+          <p className="flex items-center gap-3 text-xs text-gray-500">
+            <span className="flex items-center gap-1">
+              <Users size={12} /> {cliente.personas}
+            </span>
+            {cliente.telefono && (
+              <span className="flex items-center gap-1">
+                <Phone size={12} /> {cliente.telefono}
+              </span>
+            )}
+            <span className="flex items-center gap-1" title={`Anotado a las ${hora(cliente.fechaAlta)}`}>
+              <Clock size={12} /> {fmtEspera(cliente.minutosEsperando)}
+            </span>
+          </p>
+        </div>
+        <span className={`ml-auto px-2 py-0.5 rounded text-[10px] font-semibold ${ESTADO_COLORES[cliente.estado]}`}>
+          {ESTADO_ESPERA_LABELS[cliente.estado]}
+          {cliente.estado === 'AVISADO' && cliente.numeroMesa && ` · mesa ${cliente.numeroMesa}`}
+        </span>
+        <button
+          type="button"
+          onClick={handleCancelar}
+          disabled={cancelar.isPending}
+          className="p-2 rounded-lg hover:bg-neutral-800 text-gray-500 hover:text-gray-300 disabled:opacity-40"
+          title="Se fue"
+        >
+          <X size={16} />
+        </button>
+      </div>
+
+      {cliente.observaciones && (
+        <p className="px-4 pb-2 text-xs text-gray-500">{cliente.observaciones}</p>
+      )}
+
+      <div className="flex flex-wrap items-center gap-3 px-4 py-2 border-t border-neutral-800 text-xs">
+        {cliente.estado === 'ESPERANDO' ? (
+          <>
+            {cliente.mesaSugerida ? (
+              <span className="flex items-center gap-1.5 text-green-400">
+                <BellRing size={13} />
+                Se liberó la mesa {cliente.mesaSugerida.numero}
+                {cliente.mesaSugerida.capacidad && ` (${cliente.mesaSugerida.capacidad} lugares)`}
+              </span>
+            ) : (
+              <span className="text-gray-500">Todavía no hay una mesa libre para {cliente.personas}</span>
+            )}
+            <span className="ml-auto flex items-center gap-2">
+              <select
+                value={mesaElegida}
+                onChange={(e) => setMesaAviso(e.target.value)}
+                className="h-8 px-2 rounded-lg bg-neutral-800 border border-neutral-700 text-gray-300 focus:outline-none"
+              >
+                <option value="">Mesa…</option>
+                {mesasLibres.map((m) => (
+                  <option key={m.id} value={m.id}>
+                    Mesa {m.numero}
+                    {m.capacidad ? ` · ${m.capacidad} lugares` : ''}
+                  </option>
+                ))}
+              </select>
+              <button
+                type="button"
+                onClick={handleAvisar}
+                disabled={!mesasLibres.some((m) => m.id === mesaElegida) || avisar.isPending}
+                className="btn-primary text-xs !min-h-[32px] px-3"
+              >
+                Avisar
+              </button>
+            </span>
+          </>
+        ) : (
+          <>
+            {cliente.whatsappUrl && (
+              <button
+                type="button"
+                onClick={() => abrirEnlace(cliente.whatsappUrl!).catch((e: Error) => toast.error(e.message))}
+                className="flex items-center gap-1.5 text-gray-400 hover:text-gray-200"
+              >
+                <MessageCircle size={13} /> WhatsApp
+              </button>
+            )}
+            {cliente.smsUrl && (
+              <button
+                type="button"
+                onClick={() => abrirEnlace(cliente.smsUrl!).catch((e: Error) => toast.error(e.message))}
+                className="flex items-center gap-1.5 text-gray-400 hover:text-gray-200"
+              >
+                <MessageSquare size={13} /> SMS
+              </button>
+            )}
+            <button
+              type="button"
+              onClick={handleSentar}
+              disabled={sentar.isPending}
+              className="ml-auto btn-primary text-xs !min-h-[32px] px-3"
+            >
+              Sentar
+            </button>
+          </>
+        )}
+      </div>
+    </div>
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Lista de espera de mesas (HU-149).
+ *
+ * Cuando el salón está lleno se anota al cliente con nombre, personas y
+ * teléfono. Cada uno muestra cuánto lleva esperando y, apenas se libera
+ * una mesa en la que entra su grupo, cuál se le puede avisar (la primera
+ * persona en llegar tiene prioridad). Avisar guarda la mesa y abre WhatsApp
+ * con el mensaje listo; también se puede mandar por SMS.
+ */
+export default function ListaEsperaPage() {
+  const { data: clientes = [], isLoading, isError } = useListaEspera();
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Lista de espera</h1>
+            <p className="text-sm text-gray-500">Clientes esperando mesa y aviso cuando se libera una</p>
+          </div>
+        </header>
+
+        <div className="grid grid-cols-1 xl:grid-cols-12 gap-5">
+          <div className="xl:col-span-4">
+            <AnotarCliente />
+          </div>
+
+          <div className="xl:col-span-8 space-y-3">
+            {isLoading ? (
+              <div className="flex items-center gap-2 text-sm text-gray-500">
+                <Loader2 size={16} className="animate-spin" /> Cargando lista de espera...
+              </div>
+            ) : isError ? (
+              <p className="text-sm text-red-400">No se pudo cargar la lista de espera.</p>
+            ) : clientes.length === 0 ? (
+              <p className="text-xs text-gray-500">No hay nadie esperando mesa.</p>
+            ) : (
+              clientes.map((c, i) => <TarjetaEspera key={c.id} cliente={c} posicion={i + 1} />)
+            )}
+            <p className="text-xs text-gray-500">
+              Solo se sugieren mesas con capacidad cargada en el mapa del salón y sin una reserva que arranca en las próximas dos horas.
+            </p>
+          </div>
+        </div>
+      </div>
+    </section>
+  );
+}
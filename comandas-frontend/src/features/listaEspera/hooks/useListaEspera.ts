@@ -0,0 +1,68 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { listaEsperaApi } from '../api/listaEsperaApi';
+import type { ClienteEnEspera, ClienteEnEsperaRequest } from '../types';
+
+export const listaEsperaKeys = {
+  all: ['lista-espera'] as const,
+};
+
+/**
+ * HU-149: Clientes esperando mesa, por orden de llegada.
+ *
+ * Se refresca cada 30 segundos: así corre el tiempo de espera y aparece la
+ * mesa sugerida apenas se libera una en la que entra el grupo.
+ *
+ * queryKey: ['lista-espera']
+ */
+export function useListaEspera() {
+  return useQuery<ClienteEnEspera[]>({
+    queryKey: listaEsperaKeys.all,
+    queryFn: listaEsperaApi.listar,
+    refetchInterval: 30_000,
+  });
+}
+
+export function useAnotarEnEspera() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (request: ClienteEnEsperaRequest) => listaEsperaApi.anotar(request),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: listaEsperaKeys.all });
+    },
+  });
+}
+
+export function useAvisarEnEspera() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ clienteId, mesaId }: { clienteId: string; mesaId: string }) =>
+      listaEsperaApi.avisar(clienteId, mesaId),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: listaEsperaKeys.all });
+    },
+  });
+}
+
+export function useSentarEnEspera() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (clienteId: string) => listaEsperaApi.sentar(clienteId),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: listaEsperaKeys.all });
+    },
+  });
+}
+
+export function useCancelarEnEspera() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (clienteId: string) => listaEsperaApi.cancelar(clienteId),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: listaEsperaKeys.all });
+    },
+  });
+}
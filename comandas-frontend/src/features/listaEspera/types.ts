@@ -0,0 +1,49 @@
+/**
+ * Tipos del módulo Lista de espera (HU-149).
+ * Espejo de los DTOs de ListaEsperaController.
+ */
+
+export type EstadoEspera = 'ESPERANDO' | 'AVISADO' | 'SENTADO' | 'CANCELADO';
+
+export const ESTADO_ESPERA_LABELS: Record<EstadoEspera, string> = {
+  ESPERANDO: 'Esperando',
+  AVISADO: 'Avisado',
+  SENTADO: 'Sentado',
+  CANCELADO: 'Cancelado',
+};
+
+/** Mesa libre en la que entra el grupo y que no le toca a nadie antes */
+export interface MesaSugerida {
+  mesaId: string;
+  numero: number;
+  sector: string;
+  capacidad: number | null;
+}
+
+export interface ClienteEnEspera {
+  id: string;
+  nombreCliente: string;
+  personas: number;
+  telefono: string | null;
+  observaciones: string | null;
+  estado: EstadoEspera;
+  /** ISO local: YYYY-MM-DDTHH:mm:ss */
+  fechaAlta: string;
+  minutosEsperando: number;
+  /** Mesa con la que se le avisó */
+  mesaId: string | null;
+  numeroMesa: number | null;
+  fechaAviso: string | null;
+  mesaSugerida: MesaSugerida | null;
+  /** wa.me con el aviso armado; null sin teléfono o sin mesa avisada */
+  whatsappUrl: string | null;
+  /** sms: con el mismo aviso */
+  smsUrl: string | null;
+}
+
+export interface ClienteEnEsperaRequest {
+  nombreCliente: string;
+  personas: number;
+  telefono?: string;
+  observaciones?: string;
+}
@@ -0,0 +1,37 @@
+import apiClient from '../../../lib/apiClient';
+import type {
+  CorreccionFichajeRequest,
+  Fichaje,
+  MarcaFichaje,
+  MarcarFichajeRequest,
+  ReporteHoras,
+} from '../types';
+
+/**
+ * API client del fichaje de personal (HU-124).
+ * Consume /api/fichajes de FichajeController.
+ */
+export const fichajesApi = {
+  marcar: async (request: MarcarFichajeRequest): Promise<MarcaFichaje> => {
+    const response = await apiClient.post<MarcaFichaje>('/fichajes/marcar', request);
+    return response.data;
+  },
+
+  /** @param periodo mes en formato YYYY-MM */
+  obtenerReporte: async (periodo: string): Promise<ReporteHoras> => {
+    const response = await apiClient.get<ReporteHoras>('/fichajes/reporte', {
+      params: { periodo },
+    });
+    return response.data;
+  },
+
+  corregir: async (fichajeId: string, request: CorreccionFichajeRequest): Promise<Fichaje> => {
+    const response = await apiClient.put<Fichaje>(`/fichajes/${fichajeId}`, request);
+    return response.data;
+  },
+
+  registrarManual: async (request: CorreccionFichajeRequest): Promise<Fichaje> => {
+    const response = await apiClient.post<Fichaje>('/fichajes', request);
+    return response.data;
+  },
+};
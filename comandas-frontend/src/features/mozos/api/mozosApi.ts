@@ -0,0 +1,45 @@
+import apiClient from '../../../lib/apiClient';
+import type { Mozo, MozoRequest, ReporteConsumoPersonal, ReporteMozos, TopeConsumoRequest } from '../types';
+
+/**
+ * API client de mozos y su reporte de desempeño (HU-111).
+ * HU-132: Tope y resumen mensual de consumo interno.
+ * Consume /api/mozos de MozoController.
+ */
+export const mozosApi = {
+  listar: async (): Promise<Mozo[]> => {
+    const response = await apiClient.get<Mozo[]>('/mozos');
+    return response.data;
+  },
+
+  crear: async (request: MozoRequest): Promise<Mozo> => {
+    const response = await apiClient.post<Mozo>('/mozos', request);
+    return response.data;
+  },
+
+  actualizar: async (id: string, request: MozoRequest): Promise<Mozo> => {
+    const response = await apiClient.put<Mozo>(`/mozos/${id}`, request);
+    return response.data;
+  },
+
+  /** @param desde,hasta fechas ISO (YYYY-MM-DD), ambas inclusive */
+  obtenerReporte: async (desde: string, hasta: string): Promise<ReporteMozos> => {
+    const response = await apiClient.get<ReporteMozos>('/mozos/reporte', {
+      params: { desde, hasta },
+    });
+    return response.data;
+  },
+
+  definirTopeConsumo: async (id: string, request: TopeConsumoRequest): Promise<Mozo> => {
+    const response = await apiClient.put<Mozo>(`/mozos/${id}/tope-consumo`, request);
+    return response.data;
+  },
+
+  /** @param periodo YYYY-MM */
+  obtenerConsumoInterno: async (periodo: string): Promise<ReporteConsumoPersonal> => {
+    const response = await apiClient.get<ReporteConsumoPersonal>('/mozos/consumo-interno', {
+      params: { periodo },
+    });
+    return response.data;
+  },
+};
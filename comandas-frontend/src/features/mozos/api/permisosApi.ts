@@ -0,0 +1,28 @@
+import apiClient from '../../../lib/apiClient';
+import type { MatrizPermisos, SesionEmpleado } from '../types';
+
+/**
+ * API client de la matriz de permisos (HU-169).
+ * Consume /api/permisos de PermisosController.
+ */
+export const permisosApi = {
+  obtenerMatriz: async (): Promise<MatrizPermisos> => {
+    const response = await apiClient.get<MatrizPermisos>('/permisos/matriz');
+    return response.data;
+  },
+
+  guardarMatriz: async (matriz: MatrizPermisos): Promise<MatrizPermisos> => {
+    const response = await apiClient.put<MatrizPermisos>('/permisos/matriz', matriz);
+    return response.data;
+  },
+
+  identificar: async (empleadoId: string, pin: string): Promise<SesionEmpleado> => {
+    const response = await apiClient.post<SesionEmpleado>('/permisos/sesion', { empleadoId, pin });
+    return response.data;
+  },
+
+  /** Cierra en el backend la sesión cuyo token manda el cliente */
+  cerrarSesion: async (): Promise<void> => {
+    await apiClient.delete('/permisos/sesion');
+  },
+};
@@ -0,0 +1,34 @@
+import apiClient from '../../../lib/apiClient';
+import type { DistribucionPropinas, ReglaPropinas } from '../types';
+
+/**
+ * API client del pozo de propinas (HU-141).
+ * Consume /api/propinas de PropinasController.
+ */
+export const propinasApi = {
+  obtenerRegla: async (): Promise<ReglaPropinas> => {
+    const response = await apiClient.get<ReglaPropinas>('/propinas/regla');
+    return response.data;
+  },
+
+  guardarRegla: async (regla: ReglaPropinas): Promise<ReglaPropinas> => {
+    const response = await apiClient.put<ReglaPropinas>('/propinas/regla', regla);
+    return response.data;
+  },
+
+  /** @param desde @param hasta fechas YYYY-MM-DD, ambas inclusive */
+  listarDistribuciones: async (desde: string, hasta: string): Promise<DistribucionPropinas[]> => {
+    const response = await apiClient.get<DistribucionPropinas[]>('/propinas/distribuciones', {
+      params: { desde, hasta },
+    });
+    return response.data;
+  },
+
+  /** Buffer ESC/POS en Base64 para imprimirEscPos() */
+  generarComprobante: async (distribucionId: string): Promise<string> => {
+    const response = await apiClient.post<{ escPosBase64: string }>(
+      `/propinas/distribuciones/${distribucionId}/imprimir`,
+    );
+    return response.data.escPosBase64;
+  },
+};
@@ -0,0 +1,267 @@
+import { useState } from 'react';
+import { X, Loader2, KeyRound } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useMozos } from '../hooks/useMozos';
+import { useCorregirFichaje, useRegistrarFichajeManual } from '../hooks/useFichajes';
+import type { Fichaje } from '../types';
+import { aInputFechaHora, horaCorta, diaCorto } from '../utils/horas';
+
+const inputBase = [
+  'w-full h-11 px-3 rounded-xl',
+  'bg-neutral-800 border-2 border-neutral-700',
+  'text-gray-200 placeholder:text-neutral-600',
+  'focus:border-amber-500 focus:outline-none',
+  'disabled:opacity-50',
+].join(' ');
+
+const labelBase = 'block text-sm font-medium text-gray-400 uppercase tracking-wide';
+
+interface CorregirFichajeModalProps {
+  /** null = alta manual de un fichaje que no se marcó en la terminal */
+  fichaje: Fichaje | null;
+  onClose: () => void;
+}
+
+/**
+ * Corrección de marcas con el PIN del dueño (HU-124).
+ *
+ * Se usa para cerrar salidas olvidadas, ajustar horarios mal marcados o
+ * cargar un día que no se fichó. El motivo queda registrado junto con las
+ * marcas originales de la terminal.
+ */
+export default function CorregirFichajeModal({ fichaje, onClose }: CorregirFichajeModalProps) {
+  const toast = useToast();
+  const { data: mozos = [] } = useMozos();
+  const corregir = useCorregirFichaje();
+  const registrarManual = useRegistrarFichajeManual();
+
+  const [mozoId, setMozoId] = useState(fichaje?.mozoId ?? '');
+  const [entrada, setEntrada] = useState(aInputFechaHora(fichaje?.entrada ?? null));
+  const [salida, setSalida] = useState(aInputFechaHora(fichaje?.salida ?? null));
+  const [motivo, setMotivo] = useState('');
+  const [duenoId, setDuenoId] = useState('');
+  const [pin, setPin] = useState('');
+
+  const empleados = mozos.filter((m) => m.activo);
+  const duenos = mozos.filter((m) => m.rol === 'DUENO' && m.activo && m.tienePin);
+
+  const isPending = corregir.isPending || registrarManual.isPending;
+  const horasValidas = !!entrada && (!salida || salida >= entrada);
+  const formularioValido =
+    !!mozoId && horasValidas && motivo.trim().length > 0 && !!duenoId && pin.length >= 4;
+
+  const handleConfirmar = () => {
+    if (!formularioValido) return;
+    const request = {
+      mozoId,
+      // datetime-local no trae segundos; el backend espera LocalDateTime completo
+      entrada: `${entrada}:00`,
+      salida: salida ? `${salida}:00` : null,
+      motivo: motivo.trim(),
+      duenoId,
+      pin,
+    };
+    const callbacks = {
+      onSuccess: () => {
+        toast.success(fichaje ? 'Fichaje corregido' : 'Fichaje cargado');
+        onClose();
+      },
+      onError: (err: any) =>
+        toast.error(err?.response?.data?.message || 'No se pudo guardar el fichaje'),
+    };
+
+    if (fichaje) {
+      corregir.mutate({ fichajeId: fichaje.id, ...request }, callbacks);
+    } else {
+      registrarManual.mutate(request, callbacks);
+    }
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div
+        className="fixed inset-0 z-[60] bg-black/70 backdrop-blur-sm animate-backdrop-in"
+        onClick={isPending ? undefined : onClose}
+        aria-hidden="true"
+      />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[70] flex items-center justify-center p-4">
+        <div
+          className={[
+            'bg-neutral-900 border-2 border-neutral-700 rounded-2xl',
+            'shadow-2xl shadow-black/60',
+            'w-full max-w-md max-h-[90vh] overflow-y-auto',
+            'animate-modal-in',
+          ].join(' ')}
+          role="dialog"
+          aria-modal="true"
+          aria-labelledby="corregir-fichaje-modal-title"
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-neutral-800">
+            <h2 id="corregir-fichaje-modal-title" className="text-lg font-bold text-gray-100">
+              {fichaje ? `Corregir fichaje · ${fichaje.nombreEmpleado}` : 'Cargar fichaje manual'}
+            </h2>
+            <button
+              type="button"
+              onClick={onClose}
+              disabled={isPending}
+              className={[
+                'w-10 h-10 rounded-xl flex items-center justify-center',
+                'text-gray-400 hover:text-gray-100 hover:bg-neutral-800',
+                'transition-colors active:scale-95',
+              ].join(' ')}
+              aria-label="Cerrar"
+            >
+              <X size={20} />
+            </button>
+          </div>
+
+          <div className="px-6 py-5 space-y-4">
+            {fichaje?.entradaOriginal && (
+              <p className="text-xs text-gray-500">
+                Marca original de la terminal: {diaCorto(fichaje.entradaOriginal)}{' '}
+                {horaCorta(fichaje.entradaOriginal)} →{' '}
+                {fichaje.salidaOriginal ? horaCorta(fichaje.salidaOriginal) : 'sin salida'}
+              </p>
+            )}
+
+            {!fichaje && (
+              <div className="space-y-2">
+                <label htmlFor="fichaje-empleado" className={labelBase}>Empleado</label>
+                <select
+                  id="fichaje-empleado"
+                  value={mozoId}
+                  onChange={(e) => setMozoId(e.target.value)}
+                  disabled={isPending}
+                  className={inputBase}
+                >
+                  <option value="">Elegí quién trabajó</option>
+                  {empleados.map((m) => (
+                    <option key={m.id} value={m.id}>
+                      {m.nombre}
+                    </option>
+                  ))}
+                </select>
+              </div>
+            )}
+
+            <div className="grid grid-cols-2 gap-3">
+              <div className="space-y-2">
+                <label htmlFor="fichaje-entrada" className={labelBase}>Entrada</label>
+                <input
+                  id="fichaje-entrada"
+                  type="datetime-local"
+                  value={entrada}
+                  onChange={(e) => setEntrada(e.target.value)}
+                  disabled={isPending}
+                  className={`${inputBase} font-mono text-sm`}
+                />
+              </div>
+              <div className="space-y-2">
+                <label htmlFor="fichaje-salida" className={labelBase}>Salida</label>
+                <input
+                  id="fichaje-salida"
+                  type="datetime-local"
+                  value={salida}
+                  min={entrada || undefined}
+                  onChange={(e) => setSalida(e.target.value)}
+                  disabled={isPending}
+                  className={`${inputBase} font-mono text-sm`}
+                />
+              </div>
+            </div>
+            {!horasValidas && entrada && (
+              <p className="text-xs text-red-400">La salida no puede ser anterior a la entrada.</p>
+            )}
+
+            <div className="space-y-2">
+              <label htmlFor="fichaje-motivo" className={labelBase}>Motivo</label>
+              <input
+                id="fichaje-motivo"
+                value={motivo}
+                onChange={(e) => setMotivo(e.target.value)}
+                maxLength={200}
+                disabled={isPending}
+                placeholder="Ej: se olvidó de fichar la salida"
+                className={inputBase}
+              />
+            </div>
+
+            {/* Autorización del dueño */}
+            <div className="space-y-2">
+              <p className={labelBase}>Autoriza</p>
+              {duenos.length === 0 ? (
+                <p className="text-xs text-amber-400">
+                  No hay un dueño con PIN configurado. Definilo en el panel de mozos.
+                </p>
+              ) : (
+                <div className="grid grid-cols-2 gap-3">
+                  <select
+                    value={duenoId}
+                    onChange={(e) => setDuenoId(e.target.value)}
+                    disabled={isPending}
+                    className={inputBase}
+                    aria-label="Dueño que autoriza"
+                  >
+                    <option value="">Dueño</option>
+                    {duenos.map((m) => (
+                      <option key={m.id} value={m.id}>
+                        {m.nombre}
+                      </option>
+                    ))}
+                  </select>
+                  <div className="relative">
+                    <KeyRound size={16} className="absolute left-3 top-1/2 -translate-y-1/2 text-gray-500" />
+                    <input
+                      type="password"
+                      inputMode="numeric"
+                      value={pin}
+                      maxLength={6}
+                      onChange={(e) => setPin(e.target.value.replace(/\D/g, ''))}
+                      disabled={isPending}
+                      placeholder="PIN"
+                      aria-label="PIN del dueño"
+                      className={`${inputBase} pl-9 font-mono`}
+                    />
+                  </div>
+                </div>
+              )}
+            </div>
+
+            {/* Botón confirmar */}
+            <button
+              type="button"
+              onClick={handleConfirmar}
+              disabled={isPending || !formularioValido}
+              className={[
+                'w-full h-14 rounded-xl font-semibold text-base',
+                'flex items-center justify-center gap-3',
+                'transition-all duration-150',
+                'focus:outline-none focus-visible:ring-2 focus-visible:ring-amber-400',
+                isPending
+                  ? 'bg-neutral-700 text-gray-400 cursor-wait'
+                  : formularioValido
+                    ? 'bg-amber-600 hover:bg-amber-500 text-white active:scale-95'
+                    : 'bg-neutral-700 text-gray-500 cursor-not-allowed',
+              ].join(' ')}
+            >
+              {isPending ? (
+                <>
+                  <Loader2 size={20} className="animate-spin" />
+                  Registrando...
+                </>
+              ) : fichaje ? (
+                'Guardar corrección'
+              ) : (
+                'Cargar fichaje'
+              )}
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
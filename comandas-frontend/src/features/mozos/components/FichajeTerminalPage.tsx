@@ -0,0 +1,283 @@
+import { useEffect, useRef, useState } from 'react';
+import { Link } from 'react-router-dom';
+import { AlertTriangle, Clock, CreditCard, Delete, FileClock, Loader2, LogIn, LogOut } from 'lucide-react';
+import { useMozos } from '../hooks/useMozos';
+import { useMarcarFichaje } from '../hooks/useFichajes';
+import type { MarcaFichaje, MarcarFichajeRequest, Mozo } from '../types';
+import { formatMinutos, horaCorta } from '../utils/horas';
+
+/** Cuánto queda en pantalla el saludo antes de volver al inicio */
+const DURACION_MENSAJE_MS = 6000;
+
+const TECLAS = ['1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+type Resultado = { ok: true; marca: MarcaFichaje } | { ok: false; mensaje: string };
+
+function useHoraActual(): Date {
+  const [ahora, setAhora] = useState(() => new Date());
+  useEffect(() => {
+    const id = setInterval(() => setAhora(new Date()), 1000);
+    return () => clearInterval(id);
+  }, []);
+  return ahora;
+}
+
+/**
+ * Terminal de fichaje (HU-124).
+ *
+ * Queda abierta en la tablet del mostrador. El lector de tarjetas se
+ * comporta como un teclado: escribe el código y un Enter sobre el campo
+ * oculto, que siempre recupera el foco. Sin tarjeta, el empleado toca su
+ * nombre y marca con su PIN. Cada marca alterna entre entrada y salida.
+ */
+export default function FichajeTerminalPage() {
+  const ahora = useHoraActual();
+  const { data: mozos = [] } = useMozos();
+  const marcar = useMarcarFichaje();
+
+  const [empleado, setEmpleado] = useState<Mozo | null>(null);
+  const [pin, setPin] = useState('');
+  const [tarjeta, setTarjeta] = useState('');
+  const [resultado, setResultado] = useState<Resultado | null>(null);
+  const inputTarjetaRef = useRef<HTMLInputElement>(null);
+
+  const activos = mozos.filter((m) => m.activo && (m.tienePin || m.tieneTarjeta));
+
+  // El lector necesita el foco en el campo oculto salvo mientras se escribe el PIN
+  useEffect(() => {
+    if (!empleado) inputTarjetaRef.current?.focus();
+  }, [empleado, resultado]);
+
+  useEffect(() => {
+    if (!resultado) return;
+    const id = setTimeout(() => setResultado(null), DURACION_MENSAJE_MS);
+    return () => clearTimeout(id);
+  }, [resultado]);
+
+  const enviar = (request: MarcarFichajeRequest) => {
+    marcar.mutate(request, {
+      onSuccess: (marca) => setResultado({ ok: true, marca }),
+      onError: (err: any) =>
+        setResultado({ ok: false, mensaje: err?.response?.data?.message || 'No se pudo registrar la marca' }),
+      onSettled: () => {
+        setEmpleado(null);
+        setPin('');
+        setTarjeta('');
+      },
+    });
+  };
+
+  const handleTarjeta = () => {
+    const codigo = tarjeta.trim();
+    setTarjeta('');
+    if (codigo) enviar({ tarjeta: codigo });
+  };
+
+  const handleTecla = (tecla: string) => {
+    if (pin.length < 6) setPin((prev) => prev + tecla);
+  };
+
+  const handleConfirmarPin = () => {
+    if (!empleado || pin.length < 4) return;
+    enviar({ mozoId: empleado.id, pin });
+  };
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full max-w-3xl mx-auto px-4 sm:px-6 py-6 space-y-6">
+        {/* Campo del lector de tarjetas: invisible pero enfocable */}
+        <input
+          ref={inputTarjetaRef}
+          value={tarjeta}
+          onChange={(e) => setTarjeta(e.target.value)}
+          onKeyDown={(e) => e.key === 'Enter' && handleTarjeta()}
+          onBlur={() => !empleado && setTimeout(() => inputTarjetaRef.current?.focus(), 0)}
+          aria-label="Lector de tarjetas"
+          autoComplete="off"
+          className="absolute opacity-0 w-px h-px pointer-events-none"
+        />
+
+        <header className="flex items-start justify-between gap-3">
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Fichaje</h1>
+            <p className="text-sm text-gray-500 flex items-center gap-1.5">
+              <CreditCard size={14} /> Pasá tu tarjeta o tocá tu nombre
+            </p>
+          </div>
+          <div className="text-right">
+            <p className="text-4xl font-mono font-bold text-gray-100 tabular-nums">
+              {ahora.toLocaleTimeString('es-AR', { hour: '2-digit', minute: '2-digit', second: '2-digit' })}
+            </p>
+            <p className="text-sm text-gray-500 capitalize">
+              {ahora.toLocaleDateString('es-AR', { weekday: 'long', day: 'numeric', month: 'long' })}
+            </p>
+          </div>
+        </header>
+
+        {/* Resultado de la última marca */}
+        {marcar.isPending && !empleado && (
+          <div className="flex items-center justify-center gap-2 text-gray-400">
+            <Loader2 size={18} className="animate-spin" /> Registrando...
+          </div>
+        )}
+        {resultado && !marcar.isPending && (
+          resultado.ok ? (
+            <div
+              className={`rounded-2xl border-2 px-6 py-5 space-y-1 ${
+                resultado.marca.tipo === 'ENTRADA'
+                  ? 'border-emerald-600/60 bg-emerald-950/40'
+                  : 'border-sky-600/60 bg-sky-950/40'
+              }`}
+              role="status"
+            >
+              <p className="text-2xl font-bold text-gray-100 flex items-center gap-3">
+                {resultado.marca.tipo === 'ENTRADA' ? (
+                  <>
+                    <LogIn size={26} className="text-emerald-400" />
+                    ¡Hola, {resultado.marca.nombreEmpleado}!
+                  </>
+                ) : (
+                  <>
+                    <LogOut size={26} className="text-sky-400" />
+                    Hasta luego, {resultado.marca.nombreEmpleado}
+                  </>
+                )}
+              </p>
+              <p className="text-gray-300">
+                {resultado.marca.tipo === 'ENTRADA' ? 'Entrada' : 'Salida'} registrada a las{' '}
+                <span className="font-mono">{horaCorta(resultado.marca.hora)}</span>
+                {resultado.marca.tipo === 'SALIDA' && (
+                  <> · trabajaste {formatMinutos(resultado.marca.minutosTrabajados)}</>
+                )}
+              </p>
+              {resultado.marca.quedoIncompleto && (
+                <p className="text-sm text-amber-400 flex items-center gap-1.5">
+                  <AlertTriangle size={14} />
+                  Tu salida anterior quedó sin marcar. Avisale al dueño para que la corrija.
+                </p>
+              )}
+            </div>
+          ) : (
+            <div className="rounded-2xl border-2 border-red-700/60 bg-red-950/40 px-6 py-5" role="alert">
+              <p className="text-lg font-semibold text-red-300 flex items-center gap-2">
+                <AlertTriangle size={20} /> {resultado.mensaje}
+              </p>
+            </div>
+          )
+        )}
+
+        {empleado ? (
+          /* Teclado de PIN */
+          <div className="rounded-2xl border-2 border-neutral-700 bg-neutral-900 p-6 space-y-4 max-w-sm mx-auto">
+            <p className="text-center text-lg font-semibold text-gray-100">{empleado.nombre}</p>
+            <p className="text-center font-mono text-3xl tracking-[0.5em] text-gray-200 h-10">
+              {'•'.repeat(pin.length)}
+            </p>
+            <div className="grid grid-cols-3 gap-2">
+              {TECLAS.map((t) => (
+                <button
+                  key={t}
+                  type="button"
+                  onClick={() => handleTecla(t)}
+                  disabled={marcar.isPending}
+                  className="h-16 rounded-xl bg-neutral-800 hover:bg-neutral-700 text-2xl font-semibold text-gray-100 active:scale-95 transition-all"
+                >
+                  {t}
+                </button>
+              ))}
+              <button
+                type="button"
+                onClick={() => {
+                  setEmpleado(null);
+                  setPin('');
+                }}
+                disabled={marcar.isPending}
+                className="h-16 rounded-xl bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-400 active:scale-95 transition-all"
+              >
+                Cancelar
+              </button>
+              <button
+                type="button"
+                onClick={() => handleTecla('0')}
+                disabled={marcar.isPending}
+                className="h-16 rounded-xl bg-neutral-800 hover:bg-neutral-700 text-2xl font-semibold text-gray-100 active:scale-95 transition-all"
+              >
+                0
+              </button>
+              <button
+                type="button"
+                onClick={() => setPin((prev) => prev.slice(0, -1))}
+                disabled={marcar.isPending || pin.length === 0}
+                className="h-16 rounded-xl bg-neutral-800 hover:bg-neutral-700 text-gray-400 flex items-center justify-center active:scale-95 transition-all disabled:opacity-40"
+                aria-label="Borrar"
+              >
+                <Delete size={22} />
+              </button>
+            </div>
+            <button
+              type="button"
+              onClick={handleConfirmarPin}
+              disabled={marcar.isPending || pin.length < 4}
+              className={[
+                'w-full h-14 rounded-xl font-semibold text-base',
+                'flex items-center justify-center gap-3 transition-all duration-150',
+                marcar.isPending
+                  ? 'bg-neutral-700 text-gray-400 cursor-wait'
+                  : pin.length >= 4
+                    ? 'bg-red-600 hover:bg-red-500 text-white active:scale-95'
+                    : 'bg-neutral-700 text-gray-500 cursor-not-allowed',
+              ].join(' ')}
+            >
+              {marcar.isPending ? (
+                <>
+                  <Loader2 size={20} className="animate-spin" />
+                  Registrando...
+                </>
+              ) : (
+                <>
+                  <Clock size={18} />
+                  Fichar
+                </>
+              )}
+            </button>
+          </div>
+        ) : (
+          /* Empleados */
+          <div className="grid grid-cols-2 sm:grid-cols-3 gap-3">
+            {activos
+              .filter((m) => m.tienePin)
+              .map((m) => (
+                <button
+                  key={m.id}
+                  type="button"
+                  onClick={() => {
+                    setResultado(null);
+                    setPin('');
+                    setEmpleado(m);
+                  }}
+                  className="h-20 rounded-2xl bg-neutral-800 hover:bg-neutral-700 border border-neutral-700 text-lg font-semibold text-gray-100 truncate px-3 active:scale-95 transition-all"
+                >
+                  {m.nombre}
+                </button>
+              ))}
+            {activos.length === 0 && (
+              <p className="col-span-full text-sm text-gray-500">
+                Nadie tiene PIN ni tarjeta todavía. Configuralos desde Caja → Historial → Mozos.
+              </p>
+            )}
+          </div>
+        )}
+
+        <div className="flex justify-end">
+          <Link
+            to="/caja/horas"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <FileClock size={14} />
+            Reporte de horas
+          </Link>
+        </div>
+      </div>
+    </section>
+  );
+}
@@ -0,0 +1,180 @@
+import { useState } from 'react';
+import { X, Loader2, KeyRound } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useMozos } from '../hooks/useMozos';
+import { useIdentificarEmpleado } from '../hooks/usePermisos';
+import { PERMISO_LABELS, type Permiso, type SesionEmpleado } from '../types';
+
+const inputBase = [
+  'w-full h-11 px-3 rounded-xl',
+  'bg-neutral-800 border-2 border-neutral-700',
+  'text-gray-200 placeholder:text-neutral-600',
+  'focus:border-amber-500 focus:outline-none',
+  'disabled:opacity-50',
+].join(' ');
+
+const labelBase = 'block text-sm font-medium text-gray-400 uppercase tracking-wide';
+
+interface IdentificarEmpleadoModalProps {
+  /** Acción que se rechazó, para explicar por qué se pide identificarse */
+  permiso?: Permiso | null;
+  onIdentificado?: (sesion: SesionEmpleado) => void;
+  onClose: () => void;
+}
+
+/**
+ * Identificación del empleado con su PIN (HU-169).
+ *
+ * Con la matriz de permisos activa, la terminal opera a nombre de quien se
+ * identificó: el backend valida con él cada acción sensible. Si una acción se
+ * rechazó, se abre con el permiso para que se identifique alguien que lo tenga
+ * y se reintente.
+ */
+export default function IdentificarEmpleadoModal({ permiso, onIdentificado, onClose }: IdentificarEmpleadoModalProps) {
+  const toast = useToast();
+  const { data: mozos = [] } = useMozos();
+  const identificar = useIdentificarEmpleado();
+
+  const [empleadoId, setEmpleadoId] = useState('');
+  const [pin, setPin] = useState('');
+
+  const empleados = mozos.filter((m) => m.activo && m.tienePin);
+  const formularioValido = !!empleadoId && /^\d{4,6}$/.test(pin);
+
+  const handleConfirmar = () => {
+    if (!formularioValido) return;
+    identificar.mutate(
+      { empleadoId, pin },
+      {
+        onSuccess: (sesion) => {
+          toast.success(`Operando como ${sesion.nombre}`);
+          onIdentificado?.(sesion);
+          onClose();
+        },
+        onError: (err: any) => {
+          setPin('');
+          // En escritorio el error llega como texto desde la capa Rust
+          toast.error(err?.response?.data?.message || (typeof err === 'string' ? err : 'No se pudo identificar'));
+        },
+      },
+    );
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div
+        className="fixed inset-0 z-[60] bg-black/70 backdrop-blur-sm animate-backdrop-in"
+        onClick={identificar.isPending ? undefined : onClose}
+        aria-hidden="true"
+      />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[70] flex items-center justify-center p-4">
+        <div
+          className={[
+            'bg-neutral-900 border-2 border-neutral-700 rounded-2xl',
+            'shadow-2xl shadow-black/60',
+            'w-full max-w-sm',
+            'animate-modal-in',
+          ].join(' ')}
+          role="dialog"
+          aria-modal="true"
+          aria-labelledby="identificar-empleado-modal-title"
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-neutral-800">
+            <h2 id="identificar-empleado-modal-title" className="text-lg font-bold text-gray-100">
+              Identificarse
+            </h2>
+            <button
+              type="button"
+              onClick={onClose}
+              disabled={identificar.isPending}
+              className={[
+                'w-10 h-10 rounded-xl flex items-center justify-center',
+                'text-gray-400 hover:text-gray-100 hover:bg-neutral-800',
+                'transition-colors active:scale-95',
+              ].join(' ')}
+              aria-label="Cerrar"
+            >
+              <X size={20} />
+            </button>
+          </div>
+
+          <div className="px-6 py-5 space-y-4">
+            {permiso && (
+              <p className="text-xs text-amber-400">
+                Para {PERMISO_LABELS[permiso].toLowerCase()} tiene que identificarse alguien con permiso.
+              </p>
+            )}
+
+            <div className="space-y-2">
+              <label htmlFor="identificar-empleado" className={labelBase}>Empleado</label>
+              {empleados.length === 0 ? (
+                <p className="text-xs text-gray-500">No hay empleados con PIN configurado.</p>
+              ) : (
+                <select
+                  id="identificar-empleado"
+                  value={empleadoId}
+                  onChange={(e) => setEmpleadoId(e.target.value)}
+                  disabled={identificar.isPending}
+                  className={inputBase}
+                >
+                  <option value="">Elegí quién opera</option>
+                  {empleados.map((m) => (
+                    <option key={m.id} value={m.id}>
+                      {m.nombre}
+                    </option>
+                  ))}
+                </select>
+              )}
+            </div>
+
+            <div className="relative">
+              <KeyRound size={16} className="absolute left-3 top-1/2 -translate-y-1/2 text-gray-500" />
+              <input
+                type="password"
+                inputMode="numeric"
+                value={pin}
+                maxLength={6}
+                onChange={(e) => setPin(e.target.value.replace(/\D/g, ''))}
+                onKeyDown={(e) => e.key === 'Enter' && handleConfirmar()}
+                disabled={identificar.isPending}
+                placeholder="PIN"
+                aria-label="PIN del empleado"
+                className={`${inputBase} pl-9 font-mono`}
+              />
+            </div>
+
+            <button
+              type="button"
+              onClick={handleConfirmar}
+              disabled={identificar.isPending || !formularioValido}
+              className={[
+                'w-full h-14 rounded-xl font-semibold text-base',
+                'flex items-center justify-center gap-3',
+                'transition-all duration-150',
+                'focus:outline-none focus-visible:ring-2 focus-visible:ring-amber-400',
+                identificar.isPending
+                  ? 'bg-neutral-700 text-gray-400 cursor-wait'
+                  : formularioValido
+                    ? 'bg-amber-600 hover:bg-amber-500 text-white active:scale-95'
+                    : 'bg-neutral-700 text-gray-500 cursor-not-allowed',
+              ].join(' ')}
+            >
+              {identificar.isPending ? (
+                <>
+                  <Loader2 size={20} className="animate-spin" />
+                  Verificando...
+                </>
+              ) : (
+                'Identificarme'
+              )}
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
@@ -0,0 +1,238 @@
+import { useState } from 'react';
+import { CreditCard, KeyRound, Loader2, Plus, Users } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useActualizarMozo, useCrearMozo, useMozos } from '../hooks/useMozos';
+import { ROL_PERSONAL_LABELS, type Mozo, type RolPersonal } from '../types';
+
+const inputClass =
+  'h-9 w-full px-3 rounded-lg bg-neutral-800 border border-neutral-700 text-sm text-gray-200 focus:outline-none focus:border-red-600 disabled:opacity-50';
+
+function mensajeError(err: any, fallback: string): string {
+  return err?.response?.data?.message || fallback;
+}
+
+/**
+ * Alta y baja lógica de mozos (HU-111).
+ *
+ * Los mozos no se borran: desactivarlos los saca de la apertura de mesas
+ * pero conserva su historial en el reporte.
+ *
+ * HU-121: Cada persona tiene un rol; los encargados definen acá el PIN con
+ * el que autorizan devoluciones.
+ *
+ * HU-124: Todos usan su PIN para fichar, así que cualquier rol puede tenerlo.
+ * La tarjeta se asigna pasándola por el lector con el campo enfocado.
+ */
+export default function MozosPanel() {
+  const toast = useToast();
+  const { data: mozos = [], isLoading } = useMozos();
+  const crear = useCrearMozo();
+  const actualizar = useActualizarMozo();
+
+  const [nombre, setNombre] = useState('');
+  const [pinEditando, setPinEditando] = useState<string | null>(null);
+  const [pin, setPin] = useState('');
+  const [tarjetaEditando, setTarjetaEditando] = useState<string | null>(null);
+  const [tarjeta, setTarjeta] = useState('');
+
+  const handleCrear = () => {
+    if (!nombre.trim()) return;
+    crear.mutate(
+      { nombre: nombre.trim() },
+      {
+        onSuccess: (mozo) => {
+          toast.success(`${mozo.nombre} agregado`);
+          setNombre('');
+        },
+        onError: (err) => toast.error(mensajeError(err, 'No se pudo agregar el mozo')),
+      }
+    );
+  };
+
+  const toggleActivo = (mozo: Mozo) => {
+    actualizar.mutate(
+      { id: mozo.id, nombre: mozo.nombre, activo: !mozo.activo },
+      { onError: (err) => toast.error(mensajeError(err, 'No se pudo actualizar el mozo')) }
+    );
+  };
+
+  const cambiarRol = (mozo: Mozo, rol: RolPersonal) => {
+    actualizar.mutate(
+      { id: mozo.id, nombre: mozo.nombre, rol },
+      { onError: (err) => toast.error(mensajeError(err, 'No se pudo cambiar el rol')) }
+    );
+  };
+
+  const guardarPin = (mozo: Mozo) => {
+    if (!/^\d{4,6}$/.test(pin)) {
+      toast.error('El PIN debe tener entre 4 y 6 dígitos');
+      return;
+    }
+    actualizar.mutate(
+      { id: mozo.id, nombre: mozo.nombre, pin },
+      {
+        onSuccess: () => {
+          toast.success(`PIN de ${mozo.nombre} actualizado`);
+          setPinEditando(null);
+          setPin('');
+        },
+        onError: (err) => toast.error(mensajeError(err, 'No se pudo guardar el PIN')),
+      }
+    );
+  };
+
+  const guardarTarjeta = (mozo: Mozo) => {
+    const codigo = tarjeta.trim();
+    if (codigo && !/^[A-Za-z0-9]{4,32}$/.test(codigo)) {
+      toast.error('El código de tarjeta debe tener entre 4 y 32 letras o números');
+      return;
+    }
+    actualizar.mutate(
+      { id: mozo.id, nombre: mozo.nombre, tarjeta: codigo },
+      {
+        onSuccess: () => {
+          toast.success(codigo ? `Tarjeta asignada a ${mozo.nombre}` : `Tarjeta de ${mozo.nombre} quitada`);
+          setTarjetaEditando(null);
+          setTarjeta('');
+        },
+        onError: (err) => toast.error(mensajeError(err, 'No se pudo guardar la tarjeta')),
+      }
+    );
+  };
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-3">
+      <div className="flex items-center gap-2">
+        <Users size={14} className="text-gray-500" />
+        <h2 className="text-xs text-gray-500 uppercase tracking-wider font-medium">Mozos</h2>
+      </div>
+
+      <div className="flex gap-2">
+        <input
+          value={nombre}
+          onChange={(e) => setNombre(e.target.value)}
+          onKeyDown={(e) => e.key === 'Enter' && handleCrear()}
+          maxLength={60}
+          placeholder="Nombre del mozo"
+          className={inputClass}
+        />
+        <button
+          type="button"
+          onClick={handleCrear}
+          disabled={crear.isPending || !nombre.trim()}
+          className="h-9 px-3 rounded-lg bg-red-600 hover:bg-red-700 text-sm text-white flex items-center gap-1.5 disabled:opacity-40"
+        >
+          {crear.isPending ? <Loader2 size={14} className="animate-spin" /> : <Plus size={14} />}
+          Agregar
+        </button>
+      </div>
+
+      {isLoading ? (
+        <div className="flex items-center gap-2 text-xs text-gray-500">
+          <Loader2 size={14} className="animate-spin" /> Cargando mozos...
+        </div>
+      ) : mozos.length === 0 ? (
+        <p className="text-xs text-gray-600">Todavía no hay mozos cargados.</p>
+      ) : (
+        <ul className="divide-y divide-neutral-800 text-sm">
+          {mozos.map((mozo) => (
+            <li key={mozo.id} className="flex items-center justify-between gap-2 py-2">
+              <span className={`flex-1 truncate ${mozo.activo ? 'text-gray-200' : 'text-gray-600 line-through'}`}>
+                {mozo.nombre}
+              </span>
+              <select
+                value={mozo.rol}
+                onChange={(e) => cambiarRol(mozo, e.target.value as RolPersonal)}
+                disabled={actualizar.isPending}
+                className="h-7 px-2 rounded-md bg-neutral-800 border border-neutral-700 text-xs text-gray-300 focus:outline-none"
+              >
+                {(Object.keys(ROL_PERSONAL_LABELS) as RolPersonal[]).map((rol) => (
+                  <option key={rol} value={rol}>
+                    {ROL_PERSONAL_LABELS[rol]}
+                  </option>
+                ))}
+              </select>
+              {pinEditando === mozo.id ? (
+                <input
+                  type="password"
+                  inputMode="numeric"
+                  autoFocus
+                  value={pin}
+                  maxLength={6}
+                  onChange={(e) => setPin(e.target.value.replace(/\D/g, ''))}
+                  onKeyDown={(e) => {
+                    if (e.key === 'Enter') guardarPin(mozo);
+                    if (e.key === 'Escape') setPinEditando(null);
+                  }}
+                  placeholder="PIN"
+                  className="h-7 w-20 px-2 rounded-md bg-neutral-800 border border-neutral-700 text-xs text-gray-200 font-mono focus:outline-none focus:border-red-600"
+                />
+              ) : (
+                <button
+                  type="button"
+                  onClick={() => {
+                    setPin('');
+                    setPinEditando(mozo.id);
+                  }}
+                  title={mozo.tienePin ? 'Cambiar PIN' : 'Definir PIN para fichar y autorizar'}
+                  className={`h-7 px-2 rounded-md border text-xs flex items-center gap-1 transition-colors ${
+                    mozo.tienePin
+                      ? 'border-neutral-700 text-gray-400 hover:text-gray-200'
+                      : 'border-dashed border-amber-700/60 text-amber-400'
+                  }`}
+                >
+                  <KeyRound size={12} />
+                  {mozo.tienePin ? 'PIN' : 'Sin PIN'}
+                </button>
+              )}
+              {tarjetaEditando === mozo.id ? (
+                <input
+                  autoFocus
+                  value={tarjeta}
+                  maxLength={32}
+                  onChange={(e) => setTarjeta(e.target.value)}
+                  onKeyDown={(e) => {
+                    if (e.key === 'Enter') guardarTarjeta(mozo);
+                    if (e.key === 'Escape') setTarjetaEditando(null);
+                  }}
+                  placeholder="Pasá la tarjeta"
+                  className="h-7 w-28 px-2 rounded-md bg-neutral-800 border border-neutral-700 text-xs text-gray-200 font-mono focus:outline-none focus:border-red-600"
+                />
+              ) : (
+                <button
+                  type="button"
+                  onClick={() => {
+                    setTarjeta('');
+                    setTarjetaEditando(mozo.id);
+                  }}
+                  title={
+                    mozo.tieneTarjeta
+                      ? 'Cambiar tarjeta (Enter vacío la quita)'
+                      : 'Asignar tarjeta de fichaje'
+                  }
+                  className={`h-7 px-2 rounded-md border text-xs flex items-center gap-1 transition-colors ${
+                    mozo.tieneTarjeta
+                      ? 'border-neutral-700 text-gray-400 hover:text-gray-200'
+                      : 'border-dashed border-neutral-700 text-gray-600 hover:text-gray-400'
+                  }`}
+                >
+                  <CreditCard size={12} />
+                </button>
+              )}
+              <label className="flex items-center gap-2 text-xs text-gray-500">
+                <input
+                  type="checkbox"
+                  checked={mozo.activo}
+                  onChange={() => toggleActivo(mozo)}
+                  disabled={actualizar.isPending}
+                  className="accent-red-600"
+                />
+                Activo
+              </label>
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,304 @@
+import { useEffect, useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Loader2, LogOut, ShieldCheck, UserCheck } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { esPermisoDenegado, obtenerSesionEmpleado } from '../../../lib/sesionEmpleado';
+import { useMozos } from '../hooks/useMozos';
+import { cerrarSesionEmpleado, useGuardarMatrizPermisos, useMatrizPermisos } from '../hooks/usePermisos';
+import {
+  PERMISO_LABELS,
+  ROL_PERSONAL_LABELS,
+  type MatrizPermisos,
+  type Permiso,
+  type RolPersonal,
+  type SesionEmpleado,
+} from '../types';
+import IdentificarEmpleadoModal from './IdentificarEmpleadoModal';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+const PERMISOS: Permiso[] = ['ANULAR_ITEMS', 'APLICAR_DESCUENTOS', 'ABRIR_CAJON', 'VER_REPORTES', 'MODIFICAR_PRECIOS'];
+
+/** El dueño siempre puede todo: no se edita */
+const ROLES_EDITABLES: RolPersonal[] = ['MOZO', 'ENCARGADO', 'REPARTIDOR'];
+
+type Excepcion = 'ROL' | 'CONCEDIDO' | 'QUITADO';
+
+const EXCEPCION_LABELS: Record<Excepcion, string> = {
+  ROL: 'Según rol',
+  CONCEDIDO: 'Sí',
+  QUITADO: 'No',
+};
+
+function excepcionDe(matriz: MatrizPermisos, empleadoId: string, permiso: Permiso): Excepcion {
+  const valor = matriz.excepciones[empleadoId]?.[permiso];
+  return valor === undefined ? 'ROL' : valor ? 'CONCEDIDO' : 'QUITADO';
+}
+
+// ─── Sesión de la terminal ────────────────────────────────────────────────────
+
+function SesionTerminal({ sesion, onIdentificar, onCerrar }: {
+  sesion: SesionEmpleado | null;
+  onIdentificar: () => void;
+  onCerrar: () => void;
+}) {
+  return (
+    <div className="flex items-center gap-3 rounded-2xl border border-neutral-800/60 bg-neutral-900/50 px-4 py-3">
+      <UserCheck size={16} className="text-gray-500" />
+      <p className="text-sm text-gray-300">
+        {sesion ? (
+          <>
+            Operando como <span className="font-semibold text-gray-100">{sesion.nombre}</span>
+            <span className="ml-2 text-[10px] text-gray-600">{sesion.rol}</span>
+          </>
+        ) : (
+          'Nadie se identificó en esta terminal'
+        )}
+      </p>
+      {sesion ? (
+        <button
+          type="button"
+          onClick={onCerrar}
+          className="ml-auto h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+        >
+          <LogOut size={14} />
+          Cerrar sesión
+        </button>
+      ) : (
+        <button
+          type="button"
+          onClick={onIdentificar}
+          className="ml-auto h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+        >
+          <UserCheck size={14} />
+          Identificarme
+        </button>
+      )}
+    </div>
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Matriz de permisos por acción (HU-169).
+ *
+ * El dueño decide qué puede hacer cada rol (anular ítems enviados a cocina,
+ * aplicar descuentos, abrir el cajón, ver reportes y modificar precios) y,
+ * para un empleado puntual, concederle o quitarle una acción por encima de
+ * su rol. Mientras la matriz está inactiva nadie necesita identificarse;
+ * una vez activa solo el dueño identificado la puede cambiar.
+ */
+export default function PermisosPage() {
+  const toast = useToast();
+  const { data: matriz, isLoading, isError } = useMatrizPermisos();
+  const { data: mozos = [] } = useMozos();
+  const guardar = useGuardarMatrizPermisos();
+
+  const [borrador, setBorrador] = useState<MatrizPermisos | null>(null);
+  const [sesion, setSesion] = useState(obtenerSesionEmpleado);
+  const [pidiendoIdentificacion, setPidiendoIdentificacion] = useState(false);
+
+  useEffect(() => {
+    if (matriz) setBorrador(matriz);
+  }, [matriz]);
+
+  const empleados = mozos.filter((m) => m.activo && m.rol !== 'DUENO');
+
+  const togglePermisoRol = (rol: RolPersonal, permiso: Permiso) => {
+    setBorrador((prev) => {
+      if (!prev) return prev;
+      const actuales = prev.permisosPorRol[rol] ?? [];
+      const nuevos = actuales.includes(permiso) ? actuales.filter((p) => p !== permiso) : [...actuales, permiso];
+      return { ...prev, permisosPorRol: { ...prev.permisosPorRol, [rol]: nuevos } };
+    });
+  };
+
+  const cambiarExcepcion = (empleadoId: string, permiso: Permiso, valor: Excepcion) => {
+    setBorrador((prev) => {
+      if (!prev) return prev;
+      const propias = { ...prev.excepciones[empleadoId] };
+      if (valor === 'ROL') {
+        delete propias[permiso];
+      } else {
+        propias[permiso] = valor === 'CONCEDIDO';
+      }
+      const excepciones = { ...prev.excepciones };
+      if (Object.keys(propias).length === 0) {
+        delete excepciones[empleadoId];
+      } else {
+        excepciones[empleadoId] = propias;
+      }
+      return { ...prev, excepciones };
+    });
+  };
+
+  const handleGuardar = () => {
+    if (!borrador) return;
+    guardar.mutate(borrador, {
+      onSuccess: () => toast.success('Permisos guardados'),
+      onError: (err: any) => {
+        toast.error(err?.response?.data?.message || 'No se pudieron guardar los permisos');
+        if (esPermisoDenegado(err)) setPidiendoIdentificacion(true);
+      },
+    });
+  };
+
+  const handleCerrarSesion = () => {
+    cerrarSesionEmpleado()
+      .then(() => setSesion(null))
+      .catch((e) => console.error('[Permisos] No se pudo cerrar la sesión:', e));
+  };
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/mozos"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Permisos</h1>
+            <p className="text-sm text-gray-500">Qué puede hacer cada puesto y cada empleado</p>
+          </div>
+        </header>
+
+        <SesionTerminal
+          sesion={sesion}
+          onIdentificar={() => setPidiendoIdentificacion(true)}
+          onCerrar={handleCerrarSesion}
+        />
+
+        {isLoading || (!borrador && !isError) ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando permisos...
+          </div>
+        ) : isError || !borrador ? (
+          <p className="text-sm text-red-400">No se pudieron cargar los permisos.</p>
+        ) : (
+          <>
+            <label className="flex items-start gap-3 rounded-2xl border border-neutral-800/60 bg-neutral-900/50 px-4 py-3 cursor-pointer">
+              <input
+                type="checkbox"
+                checked={borrador.activa}
+                onChange={(e) => setBorrador({ ...borrador, activa: e.target.checked })}
+                className="mt-1 accent-red-600"
+              />
+              <span>
+                <span className="flex items-center gap-1.5 text-sm font-semibold text-gray-100">
+                  <ShieldCheck size={15} />
+                  Exigir permisos
+                </span>
+                <span className="block text-xs text-gray-500">
+                  Cada empleado se identifica con su PIN y solo hace lo que tiene habilitado.
+                  Apagado, cualquiera puede todo.
+                </span>
+              </span>
+            </label>
+
+            <div className="grid grid-cols-1 xl:grid-cols-12 gap-5">
+              {/* Por rol */}
+              <div className="xl:col-span-5 rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+                <table className="w-full text-sm">
+                  <thead>
+                    <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                      <th className="text-left px-4 py-2 font-normal">Acción</th>
+                      {ROLES_EDITABLES.map((rol) => (
+                        <th key={rol} className="px-3 py-2 font-normal">{ROL_PERSONAL_LABELS[rol]}</th>
+                      ))}
+                      <th className="px-3 py-2 font-normal">{ROL_PERSONAL_LABELS.DUENO}</th>
+                    </tr>
+                  </thead>
+                  <tbody className="divide-y divide-neutral-800 text-gray-300">
+                    {PERMISOS.map((permiso) => (
+                      <tr key={permiso}>
+                        <td className="px-4 py-2">{PERMISO_LABELS[permiso]}</td>
+                        {ROLES_EDITABLES.map((rol) => (
+                          <td key={rol} className="px-3 py-2 text-center">
+                            <input
+                              type="checkbox"
+                              checked={(borrador.permisosPorRol[rol] ?? []).includes(permiso)}
+                              onChange={() => togglePermisoRol(rol, permiso)}
+                              aria-label={`${PERMISO_LABELS[permiso]} · ${ROL_PERSONAL_LABELS[rol]}`}
+                              className="accent-red-600"
+                            />
+                          </td>
+                        ))}
+                        <td className="px-3 py-2 text-center">
+                          <input type="checkbox" checked disabled aria-label="El dueño siempre puede" />
+                        </td>
+                      </tr>
+                    ))}
+                  </tbody>
+                </table>
+              </div>
+
+              {/* Por empleado */}
+              <div className="xl:col-span-7 rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+                {empleados.length === 0 ? (
+                  <p className="px-4 py-3 text-xs text-gray-500">No hay empleados activos.</p>
+                ) : (
+                  <table className="w-full text-sm">
+                    <thead>
+                      <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                        <th className="text-left px-4 py-2 font-normal">Empleado</th>
+                        {PERMISOS.map((permiso) => (
+                          <th key={permiso} className="px-2 py-2 font-normal">{PERMISO_LABELS[permiso]}</th>
+                        ))}
+                      </tr>
+                    </thead>
+                    <tbody className="divide-y divide-neutral-800 text-gray-300">
+                      {empleados.map((m) => (
+                        <tr key={m.id}>
+                          <td className="px-4 py-2">
+                            <span className="text-gray-100">{m.nombre}</span>
+                            <span className="ml-2 text-[10px] text-gray-600">{ROL_PERSONAL_LABELS[m.rol]}</span>
+                          </td>
+                          {PERMISOS.map((permiso) => (
+                            <td key={permiso} className="px-2 py-2 text-center">
+                              <select
+                                value={excepcionDe(borrador, m.id, permiso)}
+                                onChange={(e) => cambiarExcepcion(m.id, permiso, e.target.value as Excepcion)}
+                                aria-label={`${PERMISO_LABELS[permiso]} · ${m.nombre}`}
+                                className="h-8 px-1 bg-neutral-800 border border-neutral-700 rounded-lg text-xs text-gray-300 focus:outline-none focus:border-red-500/50"
+                              >
+                                {(Object.keys(EXCEPCION_LABELS) as Excepcion[]).map((valor) => (
+                                  <option key={valor} value={valor}>{EXCEPCION_LABELS[valor]}</option>
+                                ))}
+                              </select>
+                            </td>
+                          ))}
+                        </tr>
+                      ))}
+                    </tbody>
+                  </table>
+                )}
+              </div>
+            </div>
+
+            <div className="flex justify-end">
+              <button
+                onClick={handleGuardar}
+                disabled={guardar.isPending}
+                className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+              >
+                {guardar.isPending && <Loader2 size={16} className="animate-spin" />}
+                Guardar
+              </button>
+            </div>
+          </>
+        )}
+      </div>
+
+      {pidiendoIdentificacion && (
+        <IdentificarEmpleadoModal
+          onIdentificado={setSesion}
+          onClose={() => setPidiendoIdentificacion(false)}
+        />
+      )}
+    </section>
+  );
+}
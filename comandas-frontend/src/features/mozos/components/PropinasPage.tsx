@@ -0,0 +1,300 @@
+import { useEffect, useState } from 'react';
+import { Link } from 'react-router-dom';
+import { AlertTriangle, ArrowLeft, Calendar, HandCoins, Loader2, Printer } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import {
+  useDistribucionesPropinas,
+  useGuardarReglaPropinas,
+  useImprimirComprobantePropinas,
+  useReglaPropinas,
+} from '../hooks/usePropinas';
+import {
+  MODO_REPARTO_LABELS,
+  ROL_PERSONAL_LABELS,
+  type DistribucionPropinas,
+  type ModoRepartoPropinas,
+  type RolPersonal,
+} from '../types';
+import { diaCorto, formatMinutos, horaCorta } from '../utils/horas';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function hoy(): string {
+  const fecha = new Date();
+  return `${fecha.getFullYear()}-${String(fecha.getMonth() + 1).padStart(2, '0')}-${String(fecha.getDate()).padStart(2, '0')}`;
+}
+
+function haceUnaSemana(): string {
+  const fecha = new Date();
+  fecha.setDate(fecha.getDate() - 7);
+  return `${fecha.getFullYear()}-${String(fecha.getMonth() + 1).padStart(2, '0')}-${String(fecha.getDate()).padStart(2, '0')}`;
+}
+
+const TURNO_LABELS = { MANANA: 'Mañana', TARDE: 'Tarde', NOCHE: 'Noche' } as const;
+
+const ROLES: RolPersonal[] = ['MOZO', 'ENCARGADO', 'DUENO', 'REPARTIDOR'];
+
+const MODOS: { modo: ModoRepartoPropinas; detalle: string }[] = [
+  { modo: 'PARTES_IGUALES', detalle: 'Todos los que ficharon cobran lo mismo' },
+  { modo: 'POR_HORAS', detalle: 'Según los minutos fichados dentro del turno' },
+  { modo: 'POR_PUESTO', detalle: 'Según los puntos de cada puesto' },
+];
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+// ─── Regla de reparto ─────────────────────────────────────────────────────────
+
+function ReglaReparto() {
+  const toast = useToast();
+  const { data: regla, isLoading, isError } = useReglaPropinas();
+  const guardar = useGuardarReglaPropinas();
+
+  const [modo, setModo] = useState<ModoRepartoPropinas>('PARTES_IGUALES');
+  const [puntos, setPuntos] = useState<Record<RolPersonal, string>>({
+    MOZO: '1', ENCARGADO: '1', DUENO: '0', REPARTIDOR: '0',
+  });
+
+  useEffect(() => {
+    if (!regla) return;
+    setModo(regla.modo);
+    setPuntos({
+      MOZO: String(regla.puntosPorRol.MOZO ?? 0),
+      ENCARGADO: String(regla.puntosPorRol.ENCARGADO ?? 0),
+      DUENO: String(regla.puntosPorRol.DUENO ?? 0),
+      REPARTIDOR: String(regla.puntosPorRol.REPARTIDOR ?? 0),
+    });
+  }, [regla]);
+
+  const numeros = ROLES.map((rol) => Number(puntos[rol] || 0));
+  const puedeGuardar =
+    numeros.every((n) => Number.isInteger(n) && n >= 0 && n <= 100) &&
+    numeros.some((n) => n > 0) &&
+    !guardar.isPending;
+
+  const handleGuardar = () => {
+    guardar.mutate(
+      {
+        modo,
+        puntosPorRol: Object.fromEntries(ROLES.map((rol, i) => [rol, numeros[i]])) as Record<RolPersonal, number>,
+      },
+      {
+        onSuccess: () => toast.success('Regla de propinas guardada'),
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo guardar la regla'),
+      },
+    );
+  };
+
+  if (isLoading) {
+    return (
+      <div className="flex items-center gap-2 text-sm text-gray-500">
+        <Loader2 size={16} className="animate-spin" /> Cargando regla...
+      </div>
+    );
+  }
+  if (isError) {
+    return <p className="text-sm text-red-400">No se pudo cargar la regla de propinas.</p>;
+  }
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-4">
+      <div className="space-y-2">
+        <p className="text-[10px] uppercase tracking-wider text-gray-600">Cómo se reparte</p>
+        {MODOS.map((m) => (
+          <button
+            key={m.modo}
+            type="button"
+            onClick={() => setModo(m.modo)}
+            className={`w-full min-h-[44px] px-3 py-2 rounded-lg border text-sm text-left transition-colors ${
+              modo === m.modo
+                ? 'border-red-500 bg-red-900/20 text-gray-100'
+                : 'border-neutral-700 text-gray-400 hover:border-neutral-500'
+            }`}
+          >
+            {MODO_REPARTO_LABELS[m.modo]}
+            <span className="block text-[11px] text-gray-500">{m.detalle}</span>
+          </button>
+        ))}
+      </div>
+
+      <div className="space-y-2">
+        <p className="text-[10px] uppercase tracking-wider text-gray-600">Puntos por puesto</p>
+        {ROLES.map((rol) => (
+          <label key={rol} className="flex items-center justify-between gap-3 text-sm text-gray-300">
+            {ROL_PERSONAL_LABELS[rol]}
+            <input
+              type="number"
+              min={0}
+              max={100}
+              value={puntos[rol]}
+              onChange={(e) => setPuntos((prev) => ({ ...prev, [rol]: e.target.value }))}
+              className="w-20 h-9 px-2 bg-neutral-800 border border-neutral-700 rounded-lg text-right font-mono text-gray-100 focus:outline-none focus:border-red-500/50"
+            />
+          </label>
+        ))}
+        <p className="text-xs text-gray-500">
+          Con 0 el puesto no participa del pozo. Los puntos solo pesan en el reparto por puesto.
+        </p>
+      </div>
+
+      <div className="flex justify-end">
+        <button
+          onClick={handleGuardar}
+          disabled={!puedeGuardar}
+          className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+        >
+          {guardar.isPending && <Loader2 size={16} className="animate-spin" />}
+          Guardar
+        </button>
+      </div>
+    </div>
+  );
+}
+
+// ─── Reparto de un turno ──────────────────────────────────────────────────────
+
+function TarjetaDistribucion({ distribucion }: { distribucion: DistribucionPropinas }) {
+  const toast = useToast();
+  const imprimir = useImprimirComprobantePropinas();
+
+  const handleImprimir = () => {
+    imprimir.mutate(distribucion.id, {
+      onSuccess: () => toast.success('Comprobante enviado a la impresora'),
+      onError: (err: any) => toast.error(err?.message || 'Error al imprimir el comprobante'),
+    });
+  };
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50">
+      <div className="flex items-center gap-3 px-4 py-3 border-b border-neutral-800">
+        <div>
+          <p className="text-sm font-semibold text-gray-100">
+            {distribucion.tipoTurno ? `Turno ${TURNO_LABELS[distribucion.tipoTurno]}` : 'Turno'} ·{' '}
+            {diaCorto(distribucion.fecha)} {horaCorta(distribucion.fecha)}
+          </p>
+          <p className="text-xs text-gray-500">{MODO_REPARTO_LABELS[distribucion.modo]}</p>
+        </div>
+        <p className="ml-auto text-lg font-mono tabular-nums text-gray-100">$ {fmt(distribucion.pozo)}</p>
+        <button
+          type="button"
+          onClick={handleImprimir}
+          disabled={imprimir.isPending || distribucion.partes.length === 0}
+          className="p-2 rounded-lg hover:bg-neutral-800 text-gray-500 hover:text-gray-300 disabled:opacity-40"
+          title="Imprimir comprobante"
+        >
+          {imprimir.isPending ? <Loader2 size={16} className="animate-spin" /> : <Printer size={16} />}
+        </button>
+      </div>
+
+      {distribucion.partes.length === 0 ? (
+        <p className="px-4 py-3 text-xs text-gray-500">Nadie fichó en el turno.</p>
+      ) : (
+        <table className="w-full text-sm">
+          <tbody className="divide-y divide-neutral-800 text-gray-300">
+            {distribucion.partes.map((p) => (
+              <tr key={p.mozoId}>
+                <td className="px-4 py-2">
+                  <span className="text-gray-100">{p.nombre}</span>
+                  {p.rol && <span className="ml-2 text-[10px] text-gray-600">{ROL_PERSONAL_LABELS[p.rol]}</span>}
+                </td>
+                <td className="text-right px-3 py-2 text-xs text-gray-500">
+                  {formatMinutos(p.minutos)}
+                  {distribucion.modo === 'POR_PUESTO' && ` · ${p.peso} pts`}
+                </td>
+                <td className="text-right px-4 py-2 font-mono tabular-nums">$ {fmt(p.monto)}</td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      )}
+
+      {distribucion.sinRepartir > 0 && (
+        <p className="flex items-center gap-1.5 px-4 py-2 border-t border-neutral-800 text-xs text-amber-400">
+          <AlertTriangle size={13} />
+          Quedaron $ {fmt(distribucion.sinRepartir)} sin repartir
+        </p>
+      )}
+    </div>
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Pozo de propinas y su reparto por turno (HU-141).
+ *
+ * A la izquierda la regla del local: partes iguales, por horas fichadas o
+ * por puesto. A la derecha el reparto que se calculó al cerrar cada turno,
+ * con el comprobante para que cada empleado firme lo que cobró.
+ */
+export default function PropinasPage() {
+  const [desde, setDesde] = useState(haceUnaSemana);
+  const [hasta, setHasta] = useState(hoy);
+  const { data: distribuciones = [], isLoading, isError } = useDistribucionesPropinas(desde, hasta);
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/mozos"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Propinas</h1>
+            <p className="text-sm text-gray-500">Reparto del pozo de cada turno entre el personal</p>
+          </div>
+        </header>
+
+        <div className="grid grid-cols-1 xl:grid-cols-12 gap-5">
+          <div className="xl:col-span-4">
+            <ReglaReparto />
+          </div>
+
+          <div className="xl:col-span-8 space-y-3">
+            <div className="flex items-center gap-3">
+              <Calendar size={14} className="text-gray-500" />
+              <input
+                type="date"
+                value={desde}
+                max={hasta}
+                onChange={(e) => e.target.value && setDesde(e.target.value)}
+                className={inputFecha}
+              />
+              <span className="text-xs text-gray-600">a</span>
+              <input
+                type="date"
+                value={hasta}
+                min={desde}
+                max={hoy()}
+                onChange={(e) => e.target.value && setHasta(e.target.value)}
+                className={inputFecha}
+              />
+            </div>
+
+            {isLoading ? (
+              <div className="flex items-center gap-2 text-sm text-gray-500">
+                <Loader2 size={16} className="animate-spin" /> Cargando repartos...
+              </div>
+            ) : isError ? (
+              <p className="text-sm text-red-400">No se pudieron cargar los repartos.</p>
+            ) : distribuciones.length === 0 ? (
+              <div className="flex items-center gap-2 text-xs text-gray-500">
+                <HandCoins size={14} />
+                No se cerraron turnos en el rango.
+              </div>
+            ) : (
+              distribuciones.map((d) => <TarjetaDistribucion key={d.id} distribucion={d} />)
+            )}
+          </div>
+        </div>
+      </div>
+    </section>
+  );
+}
@@ -0,0 +1,239 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Calendar, Coffee, Download, Loader2 } from 'lucide-react';
+import { generarCsv, descargarCsv } from '../../../lib/csv';
+import useToast from '../../../hooks/useToast';
+import { useConsumoInterno, useDefinirTopeConsumo, useMozos } from '../hooks/useMozos';
+import type { ReporteConsumoPersonal } from '../types';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function mesActual(): string {
+  const hoy = new Date();
+  return `${hoy.getFullYear()}-${String(hoy.getMonth() + 1).padStart(2, '0')}`;
+}
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+function exportarReporte(reporte: ReporteConsumoPersonal) {
+  const filas: unknown[][] = reporte.empleados.map((e) => [
+    e.nombre,
+    e.topeMensual ?? '',
+    e.consumos,
+    e.consumido,
+    e.excedente,
+  ]);
+  filas.push(['Total', '', '', reporte.totalConsumido, reporte.totalADescontar]);
+
+  descargarCsv(
+    generarCsv(['Empleado', 'Tope', 'Consumos', 'Consumido', 'A descontar'], filas),
+    `consumo_interno_${reporte.periodo}.csv`,
+  );
+}
+
+// ─── Tope por empleado ────────────────────────────────────────────────────────
+
+/**
+ * Fija el tope de cualquier empleado activo, aunque todavía no haya
+ * consumido en el mes (por eso no se edita sobre la tabla).
+ */
+function EditorTope() {
+  const toast = useToast();
+  const { data: mozos = [] } = useMozos();
+  const definirTope = useDefinirTopeConsumo();
+  const activos = mozos.filter((m) => m.activo);
+
+  const [mozoId, setMozoId] = useState('');
+  const [tope, setTope] = useState('');
+
+  const seleccionar = (id: string) => {
+    setMozoId(id);
+    const actual = activos.find((m) => m.id === id)?.topeConsumoMensual;
+    setTope(actual != null ? String(actual) : '');
+  };
+
+  const guardar = (topeMensual: number | null) => {
+    definirTope.mutate(
+      { id: mozoId, topeMensual },
+      {
+        onSuccess: (mozo) => {
+          toast.success(
+            topeMensual === null
+              ? `${mozo.nombre} quedó sin tope de consumo`
+              : `Tope de ${mozo.nombre}: $ ${fmt(topeMensual)} por mes`,
+          );
+        },
+        onError: (err: any) => {
+          toast.error(err?.response?.data?.message || 'No se pudo guardar el tope');
+        },
+      },
+    );
+  };
+
+  const montoTope = parseFloat(tope);
+  const topeValido = tope !== '' && montoTope >= 0;
+
+  return (
+    <div className="flex flex-wrap items-center gap-2 rounded-2xl border border-neutral-800/60 bg-neutral-900/50 px-4 py-3">
+      <span className="text-xs font-semibold text-gray-500 uppercase tracking-widest mr-2">Tope mensual</span>
+      <select value={mozoId} onChange={(e) => seleccionar(e.target.value)} className={inputFecha}>
+        <option value="">Elegir empleado…</option>
+        {activos.map((m) => (
+          <option key={m.id} value={m.id}>
+            {m.nombre}
+          </option>
+        ))}
+      </select>
+      <input
+        type="number"
+        min={0}
+        step={0.01}
+        value={tope}
+        onChange={(e) => setTope(e.target.value)}
+        placeholder="Sin tope"
+        disabled={!mozoId}
+        className={`${inputFecha} w-32 text-right`}
+      />
+      <button
+        type="button"
+        onClick={() => guardar(montoTope)}
+        disabled={!mozoId || !topeValido || definirTope.isPending}
+        className="h-8 px-3 rounded-lg bg-red-600 hover:bg-red-700 text-xs text-white transition-colors disabled:opacity-40"
+      >
+        Guardar
+      </button>
+      <button
+        type="button"
+        onClick={() => guardar(null)}
+        disabled={!mozoId || definirTope.isPending}
+        className="h-8 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-xs text-gray-300 transition-colors disabled:opacity-40"
+      >
+        Quitar tope
+      </button>
+    </div>
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Resumen mensual del consumo a cuenta del personal (HU-132).
+ *
+ * Muestra a quien consumió en el mes y a los activos con tope aunque no
+ * hayan consumido. La columna "A descontar" es lo que excede el beneficio
+ * acordado y se liquida contra el sueldo.
+ */
+export default function ReporteConsumoPersonalPage() {
+  const [periodo, setPeriodo] = useState(mesActual);
+  const { data: reporte, isLoading, isError } = useConsumoInterno(periodo);
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/mozos"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Consumo interno</h1>
+            <p className="text-sm text-gray-500">Consumo a cuenta del personal contra su tope mensual</p>
+          </div>
+        </header>
+
+        <EditorTope />
+
+        <div className="flex items-center gap-3">
+          <Calendar size={14} className="text-gray-500" />
+          <input
+            type="month"
+            value={periodo}
+            max={mesActual()}
+            onChange={(e) => e.target.value && setPeriodo(e.target.value)}
+            className={inputFecha}
+          />
+          {reporte && (
+            <>
+              <span className="ml-auto text-sm text-gray-400">
+                A descontar{' '}
+                <span className="font-mono tabular-nums text-red-400">$ {fmt(reporte.totalADescontar)}</span>
+              </span>
+              <button
+                type="button"
+                onClick={() => exportarReporte(reporte)}
+                disabled={reporte.empleados.length === 0}
+                className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors disabled:opacity-40"
+              >
+                <Download size={14} />
+                Exportar CSV
+              </button>
+            </>
+          )}
+        </div>
+
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando reporte...
+          </div>
+        ) : isError ? (
+          <p className="text-sm text-red-400">No se pudo cargar el consumo interno.</p>
+        ) : reporte && reporte.empleados.length === 0 ? (
+          <div className="flex items-center gap-2 text-xs text-gray-500">
+            <Coffee size={14} />
+            Nadie consumió a cuenta en este mes.
+          </div>
+        ) : reporte && (
+          <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+            <table className="w-full text-sm">
+              <thead>
+                <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                  <th className="text-left font-medium px-4 py-2">Empleado</th>
+                  <th className="text-right font-medium px-3 py-2">Tope</th>
+                  <th className="text-right font-medium px-3 py-2">Consumos</th>
+                  <th className="text-right font-medium px-3 py-2">Consumido</th>
+                  <th className="text-right font-medium px-3 py-2">Disponible</th>
+                  <th className="text-right font-medium px-4 py-2">A descontar</th>
+                </tr>
+              </thead>
+              <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+                {reporte.empleados.map((e) => (
+                  <tr key={e.mozoId}>
+                    <td className="px-4 py-2 font-sans text-gray-100">{e.nombre}</td>
+                    <td className="text-right px-3 py-2 text-gray-500">
+                      {e.topeMensual !== null ? `$ ${fmt(e.topeMensual)}` : 'sin tope'}
+                    </td>
+                    <td className="text-right px-3 py-2">{e.consumos}</td>
+                    <td className="text-right px-3 py-2">$ {fmt(e.consumido)}</td>
+                    <td className="text-right px-3 py-2 text-gray-500">
+                      {e.disponible !== null ? `$ ${fmt(e.disponible)}` : '—'}
+                    </td>
+                    <td className={`text-right px-4 py-2 ${e.excedente > 0 ? 'text-red-400' : 'text-gray-600'}`}>
+                      $ {fmt(e.excedente)}
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+              <tfoot>
+                <tr className="border-t border-neutral-700 font-mono tabular-nums text-gray-100">
+                  <td className="px-4 py-2 font-sans font-medium">Total</td>
+                  <td />
+                  <td />
+                  <td className="text-right px-3 py-2">$ {fmt(reporte.totalConsumido)}</td>
+                  <td />
+                  <td className="text-right px-4 py-2 text-red-400">$ {fmt(reporte.totalADescontar)}</td>
+                </tr>
+              </tfoot>
+            </table>
+          </div>
+        )}
+      </div>
+    </section>
+  );
+}
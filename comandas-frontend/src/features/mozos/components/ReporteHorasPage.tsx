@@ -0,0 +1,214 @@
+import { Fragment, useState } from 'react';
+import { Link } from 'react-router-dom';
+import { AlertTriangle, ArrowLeft, Calendar, ChevronDown, ChevronRight, Download, Loader2, Pencil, Plus, UserX } from 'lucide-react';
+import { generarCsv, descargarCsv } from '../../../lib/csv';
+import { useReporteHoras } from '../hooks/useFichajes';
+import { MEDIO_FICHAJE_LABELS, type Fichaje, type ReporteHoras } from '../types';
+import { diaCorto, formatMinutos, horaCorta } from '../utils/horas';
+import CorregirFichajeModal from './CorregirFichajeModal';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function mesActual(): string {
+  const hoy = new Date();
+  return `${hoy.getFullYear()}-${String(hoy.getMonth() + 1).padStart(2, '0')}`;
+}
+
+/** Horas con dos decimales, como las pide el estudio contable */
+function horasDecimales(minutos: number): string {
+  return (minutos / 60).toFixed(2);
+}
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+/**
+ * Exporta una fila por fichaje, para que el contador pueda revisar
+ * día por día además del total.
+ */
+function exportarReporte(reporte: ReporteHoras) {
+  const filas: unknown[][] = reporte.empleados.flatMap((e) =>
+    e.fichajes.map((f) => [
+      e.nombre,
+      diaCorto(f.entrada),
+      horaCorta(f.entrada),
+      f.salida ? horaCorta(f.salida) : '',
+      horasDecimales(f.minutosTrabajados),
+      MEDIO_FICHAJE_LABELS[f.medio],
+      f.motivoCorreccion ?? '',
+      f.corregidoPor ?? '',
+    ]),
+  );
+
+  descargarCsv(
+    generarCsv(
+      ['Empleado', 'Día', 'Entrada', 'Salida', 'Horas', 'Medio', 'Corrección', 'Autorizó'],
+      filas,
+    ),
+    `horas_${reporte.periodo}.csv`,
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Reporte mensual de horas por empleado (HU-124).
+ *
+ * Los fichajes incompletos (entrada sin salida) no suman horas hasta que el
+ * dueño los corrige. Cada fila se despliega para ver y corregir las marcas.
+ */
+export default function ReporteHorasPage() {
+  const [periodo, setPeriodo] = useState(mesActual);
+  const [expandido, setExpandido] = useState<string | null>(null);
+  const [corrigiendo, setCorrigiendo] = useState<Fichaje | null>(null);
+  const [cargandoManual, setCargandoManual] = useState(false);
+
+  const { data: reporte, isLoading, isError } = useReporteHoras(periodo);
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/mozos"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Horas del personal</h1>
+            <p className="text-sm text-gray-500">Fichajes de entrada y salida por mes</p>
+          </div>
+          <button
+            type="button"
+            onClick={() => setCargandoManual(true)}
+            className="ml-auto h-9 px-3 rounded-lg bg-red-600 hover:bg-red-700 text-sm text-white flex items-center gap-1.5 transition-colors"
+          >
+            <Plus size={14} />
+            Cargar fichaje
+          </button>
+        </header>
+
+        <div className="flex items-center gap-3">
+          <Calendar size={14} className="text-gray-500" />
+          <input
+            type="month"
+            value={periodo}
+            max={mesActual()}
+            onChange={(e) => e.target.value && setPeriodo(e.target.value)}
+            className={inputFecha}
+          />
+          {reporte && (
+            <>
+              <span className="ml-auto text-sm text-gray-400">
+                Total <span className="font-mono tabular-nums text-gray-100">{formatMinutos(reporte.totalMinutos)}</span>
+              </span>
+              <button
+                type="button"
+                onClick={() => exportarReporte(reporte)}
+                disabled={reporte.empleados.length === 0}
+                className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors disabled:opacity-40"
+              >
+                <Download size={14} />
+                Exportar CSV
+              </button>
+            </>
+          )}
+        </div>
+
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando reporte...
+          </div>
+        ) : isError ? (
+          <p className="text-sm text-red-400">No se pudo cargar el reporte de horas.</p>
+        ) : reporte && reporte.empleados.length === 0 ? (
+          <div className="flex items-center gap-2 text-xs text-gray-500">
+            <UserX size={14} />
+            No hay fichajes en este mes.
+          </div>
+        ) : reporte && (
+          <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+            <table className="w-full text-sm">
+              <thead>
+                <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                  <th className="text-left font-medium px-4 py-2">Empleado</th>
+                  <th className="text-right font-medium px-3 py-2">Días</th>
+                  <th className="text-right font-medium px-3 py-2">Horas</th>
+                  <th className="text-right font-medium px-3 py-2">Incompletos</th>
+                  <th className="text-right font-medium px-4 py-2">Corregidos</th>
+                </tr>
+              </thead>
+              <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+                {reporte.empleados.map((e) => (
+                  <Fragment key={e.mozoId}>
+                    <tr
+                      onClick={() => setExpandido(expandido === e.mozoId ? null : e.mozoId)}
+                      className="cursor-pointer hover:bg-neutral-800/40"
+                    >
+                      <td className="px-4 py-2 font-sans text-gray-100 flex items-center gap-1.5">
+                        {expandido === e.mozoId ? <ChevronDown size={14} /> : <ChevronRight size={14} />}
+                        {e.nombre}
+                      </td>
+                      <td className="text-right px-3 py-2">{e.diasTrabajados}</td>
+                      <td className="text-right px-3 py-2">{formatMinutos(e.minutos)}</td>
+                      <td className={`text-right px-3 py-2 ${e.fichajesIncompletos > 0 ? 'text-amber-400' : ''}`}>
+                        {e.fichajesIncompletos}
+                      </td>
+                      <td className="text-right px-4 py-2">{e.fichajesCorregidos}</td>
+                    </tr>
+                    {expandido === e.mozoId && e.fichajes.map((f) => (
+                      <tr key={f.id} className="bg-neutral-950/40 text-xs">
+                        <td className="pl-10 pr-4 py-1.5 font-sans text-gray-400">
+                          {diaCorto(f.entrada)} · {horaCorta(f.entrada)} → {f.salida ? horaCorta(f.salida) : '—'}
+                          {f.incompleto && (
+                            <span className="ml-2 inline-flex items-center gap-1 text-amber-400">
+                              <AlertTriangle size={12} /> sin salida
+                            </span>
+                          )}
+                          {f.motivoCorreccion && (
+                            <span
+                              className="ml-2 text-gray-600 italic"
+                              title={`Autorizó ${f.corregidoPor ?? '—'}`}
+                            >
+                              {f.motivoCorreccion}
+                            </span>
+                          )}
+                        </td>
+                        <td className="text-right px-3 py-1.5 font-sans text-gray-600">{MEDIO_FICHAJE_LABELS[f.medio]}</td>
+                        <td className="text-right px-3 py-1.5">{f.salida ? formatMinutos(f.minutosTrabajados) : '—'}</td>
+                        <td colSpan={2} className="text-right px-4 py-1.5">
+                          <button
+                            type="button"
+                            onClick={(ev) => {
+                              ev.stopPropagation();
+                              setCorrigiendo(f);
+                            }}
+                            className="h-7 px-2 rounded-md border border-neutral-700 text-gray-400 hover:text-gray-200 inline-flex items-center gap-1 font-sans transition-colors"
+                          >
+                            <Pencil size={12} />
+                            Corregir
+                          </button>
+                        </td>
+                      </tr>
+                    ))}
+                  </Fragment>
+                ))}
+              </tbody>
+            </table>
+          </div>
+        )}
+      </div>
+
+      {(corrigiendo || cargandoManual) && (
+        <CorregirFichajeModal
+          fichaje={corrigiendo}
+          onClose={() => {
+            setCorrigiendo(null);
+            setCargandoManual(false);
+          }}
+        />
+      )}
+    </section>
+  );
+}
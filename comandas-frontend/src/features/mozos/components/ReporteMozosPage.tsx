@@ -0,0 +1,197 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Calendar, Coffee, Download, FileClock, HandCoins, Loader2, ShieldCheck, UserX } from 'lucide-react';
+import { generarCsv, descargarCsv } from '../../../lib/csv';
+import { useReporteMozos } from '../hooks/useMozos';
+import type { ReporteMozos } from '../types';
+import MozosPanel from './MozosPanel';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function isoLocal(fecha: Date): string {
+  const y = fecha.getFullYear();
+  const m = String(fecha.getMonth() + 1).padStart(2, '0');
+  const d = String(fecha.getDate()).padStart(2, '0');
+  return `${y}-${m}-${d}`;
+}
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+/**
+ * Exporta una fila por mozo con los mismos valores de la tabla,
+ * más la fila de totales al final.
+ */
+function exportarReporte(reporte: ReporteMozos) {
+  const filas: unknown[][] = reporte.mozos.map((m) => [
+    m.nombre,
+    m.cantidadPedidos,
+    m.ventas,
+    m.cubiertos,
+    m.ticketPromedio,
+    m.promedioPorCubierto,
+    m.unidadesAnuladas,
+    m.montoAnulado,
+    m.propinas,
+  ]);
+  filas.push(['Total', '', reporte.totalVentas, '', '', '', '', '', reporte.totalPropinas]);
+
+  descargarCsv(
+    generarCsv(
+      ['Mozo', 'Mesas', 'Ventas', 'Cubiertos', 'Ticket promedio', 'Por cubierto', 'Anuladas', 'Monto anulado', 'Propinas'],
+      filas,
+    ),
+    `desempeno_mozos_${reporte.desde}_${reporte.hasta}.csv`,
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Reporte de desempeño por mozo (HU-111).
+ *
+ * Ventas, cubiertos, ticket promedio, anulaciones (unidades quitadas después
+ * de salir a cocina) y propinas en el rango elegido. Se exporta a CSV para
+ * armar los incentivos de fin de mes.
+ */
+export default function ReporteMozosPage() {
+  const hoy = new Date();
+  const [desde, setDesde] = useState(isoLocal(new Date(hoy.getFullYear(), hoy.getMonth(), 1)));
+  const [hasta, setHasta] = useState(isoLocal(hoy));
+
+  const { data: reporte, isLoading, isError } = useReporteMozos(desde, hasta);
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/historial"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Mozos</h1>
+            <p className="text-sm text-gray-500">Desempeño por mozo para incentivos</p>
+          </div>
+          <Link
+            to="/caja/horas"
+            className="ml-auto h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <FileClock size={14} />
+            Horas
+          </Link>
+          <Link
+            to="/caja/consumo-interno"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <Coffee size={14} />
+            Consumo interno
+          </Link>
+          <Link
+            to="/caja/propinas"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <HandCoins size={14} />
+            Propinas
+          </Link>
+          <Link
+            to="/caja/permisos"
+            className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors"
+          >
+            <ShieldCheck size={14} />
+            Permisos
+          </Link>
+        </header>
+
+        <div className="flex items-center gap-3">
+          <Calendar size={14} className="text-gray-500" />
+          <input type="date" value={desde} onChange={(e) => setDesde(e.target.value)} className={inputFecha} />
+          <span className="text-gray-600 text-xs">→</span>
+          <input type="date" value={hasta} onChange={(e) => setHasta(e.target.value)} className={inputFecha} />
+          {reporte && (
+            <>
+              <span className="ml-auto text-sm text-gray-400">
+                Ventas <span className="font-mono tabular-nums text-gray-100">$ {fmt(reporte.totalVentas)}</span>
+                {' · '}Propinas <span className="font-mono tabular-nums text-gray-100">$ {fmt(reporte.totalPropinas)}</span>
+              </span>
+              <button
+                type="button"
+                onClick={() => exportarReporte(reporte)}
+                disabled={reporte.mozos.length === 0}
+                className="h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors disabled:opacity-40"
+              >
+                <Download size={14} />
+                Exportar CSV
+              </button>
+            </>
+          )}
+        </div>
+
+        <div className="grid grid-cols-1 xl:grid-cols-12 gap-5">
+          <div className="xl:col-span-8">
+            {isLoading ? (
+              <div className="flex items-center gap-2 text-sm text-gray-500">
+                <Loader2 size={16} className="animate-spin" /> Cargando reporte...
+              </div>
+            ) : isError ? (
+              <p className="text-sm text-red-400">No se pudo cargar el reporte de mozos.</p>
+            ) : reporte && reporte.mozos.length === 0 ? (
+              <div className="flex items-center gap-2 text-xs text-gray-500">
+                <UserX size={14} />
+                No hay mesas cobradas en este rango.
+              </div>
+            ) : reporte && (
+              <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+                <table className="w-full text-sm">
+                  <thead>
+                    <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                      <th className="text-left font-medium px-4 py-2">Mozo</th>
+                      <th className="text-right font-medium px-3 py-2">Mesas</th>
+                      <th className="text-right font-medium px-3 py-2">Ventas</th>
+                      <th className="text-right font-medium px-3 py-2">Cubiertos</th>
+                      <th className="text-right font-medium px-3 py-2">Ticket prom.</th>
+                      <th className="text-right font-medium px-3 py-2">Por cubierto</th>
+                      <th className="text-right font-medium px-3 py-2">Anuladas</th>
+                      <th className="text-right font-medium px-4 py-2">Propinas</th>
+                    </tr>
+                  </thead>
+                  <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+                    {reporte.mozos.map((m) => (
+                      <tr key={m.mozoId ?? 'sin-asignar'}>
+                        <td className={`px-4 py-2 font-sans ${m.mozoId ? 'text-gray-100' : 'text-gray-500 italic'}`}>
+                          {m.nombre}
+                        </td>
+                        <td className="text-right px-3 py-2">{m.cantidadPedidos}</td>
+                        <td className="text-right px-3 py-2">$ {fmt(m.ventas)}</td>
+                        <td className="text-right px-3 py-2">{m.cubiertos || '—'}</td>
+                        <td className="text-right px-3 py-2">$ {fmt(m.ticketPromedio)}</td>
+                        <td className="text-right px-3 py-2">{m.cubiertos ? `$ ${fmt(m.promedioPorCubierto)}` : '—'}</td>
+                        <td
+                          className={`text-right px-3 py-2 ${m.unidadesAnuladas > 0 ? 'text-amber-400' : ''}`}
+                          title={m.unidadesAnuladas > 0 ? `$ ${fmt(m.montoAnulado)}` : undefined}
+                        >
+                          {m.unidadesAnuladas}
+                        </td>
+                        <td className="text-right px-4 py-2">$ {fmt(m.propinas)}</td>
+                      </tr>
+                    ))}
+                  </tbody>
+                </table>
+              </div>
+            )}
+          </div>
+
+          <div className="xl:col-span-4">
+            <MozosPanel />
+          </div>
+        </div>
+      </div>
+    </section>
+  );
+}
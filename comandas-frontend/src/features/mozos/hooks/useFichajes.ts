@@ -0,0 +1,62 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { fichajesApi } from '../api/fichajesApi';
+import type { CorreccionFichajeRequest, MarcarFichajeRequest, ReporteHoras } from '../types';
+
+export const fichajesKeys = {
+  reporte: (periodo: string) => ['reporte-horas', periodo] as const,
+};
+
+function invalidarReportes(queryClient: ReturnType<typeof useQueryClient>) {
+  queryClient.invalidateQueries({ queryKey: ['reporte-horas'], exact: false });
+}
+
+export function useMarcarFichaje() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (request: MarcarFichajeRequest) => fichajesApi.marcar(request),
+    onSuccess: () => invalidarReportes(queryClient),
+    onError: (error: unknown) => {
+      console.error('[useMarcarFichaje] Error al fichar:', error);
+    },
+  });
+}
+
+/**
+ * Horas trabajadas por empleado en el mes, con el detalle de cada fichaje.
+ *
+ * queryKey: ['reporte-horas', periodo]
+ */
+export function useReporteHoras(periodo: string) {
+  return useQuery<ReporteHoras>({
+    queryKey: fichajesKeys.reporte(periodo),
+    queryFn: () => fichajesApi.obtenerReporte(periodo),
+    enabled: /^\d{4}-\d{2}$/.test(periodo),
+    staleTime: 60_000,
+  });
+}
+
+export function useCorregirFichaje() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ fichajeId, ...request }: CorreccionFichajeRequest & { fichajeId: string }) =>
+      fichajesApi.corregir(fichajeId, request),
+    onSuccess: () => invalidarReportes(queryClient),
+    onError: (error: unknown) => {
+      console.error('[useCorregirFichaje] Error al corregir fichaje:', error);
+    },
+  });
+}
+
+export function useRegistrarFichajeManual() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (request: CorreccionFichajeRequest) => fichajesApi.registrarManual(request),
+    onSuccess: () => invalidarReportes(queryClient),
+    onError: (error: unknown) => {
+      console.error('[useRegistrarFichajeManual] Error al cargar fichaje:', error);
+    },
+  });
+}
@@ -0,0 +1,97 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { mozosApi } from '../api/mozosApi';
+import type { Mozo, MozoRequest, ReporteConsumoPersonal, ReporteMozos } from '../types';
+
+export const mozosKeys = {
+  lista: ['mozos'] as const,
+  reporte: (desde: string, hasta: string) => ['reporte-mozos', desde, hasta] as const,
+  consumoInterno: (periodo: string) => ['consumo-interno', periodo] as const,
+};
+
+/**
+ * Mozos del local, activos e inactivos, ordenados por nombre.
+ * La apertura de mesa filtra los activos.
+ */
+export function useMozos() {
+  return useQuery<Mozo[]>({
+    queryKey: mozosKeys.lista,
+    queryFn: mozosApi.listar,
+    staleTime: 5 * 60_000,
+  });
+}
+
+export function useCrearMozo() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (request: MozoRequest) => mozosApi.crear(request),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: mozosKeys.lista });
+    },
+    onError: (error: unknown) => {
+      console.error('[useCrearMozo] Error al crear mozo:', error);
+    },
+  });
+}
+
+export function useActualizarMozo() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ id, ...request }: MozoRequest & { id: string }) => mozosApi.actualizar(id, request),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: mozosKeys.lista });
+      queryClient.invalidateQueries({ queryKey: ['reporte-mozos'], exact: false });
+    },
+    onError: (error: unknown) => {
+      console.error('[useActualizarMozo] Error al actualizar mozo:', error);
+    },
+  });
+}
+
+/**
+ * Desempeño por mozo en un rango de fechas.
+ *
+ * queryKey: ['reporte-mozos', desde, hasta]
+ */
+export function useReporteMozos(desde: string, hasta: string) {
+  return useQuery<ReporteMozos>({
+    queryKey: mozosKeys.reporte(desde, hasta),
+    queryFn: () => mozosApi.obtenerReporte(desde, hasta),
+    enabled: !!desde && !!hasta && desde <= hasta,
+    staleTime: 60_000,
+  });
+}
+
+/**
+ * HU-132: Fija o quita (null) el tope mensual de consumo a cuenta.
+ */
+export function useDefinirTopeConsumo() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ id, topeMensual }: { id: string; topeMensual: number | null }) =>
+      mozosApi.definirTopeConsumo(id, { topeMensual }),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: mozosKeys.lista });
+      queryClient.invalidateQueries({ queryKey: ['consumo-interno'], exact: false });
+    },
+    onError: (error: unknown) => {
+      console.error('[useDefinirTopeConsumo] Error al definir el tope:', error);
+    },
+  });
+}
+
+/**
+ * HU-132: Consumo a cuenta por empleado en el mes, contra su tope.
+ *
+ * queryKey: ['consumo-interno', periodo]
+ */
+export function useConsumoInterno(periodo: string) {
+  return useQuery<ReporteConsumoPersonal>({
+    queryKey: mozosKeys.consumoInterno(periodo),
+    queryFn: () => mozosApi.obtenerConsumoInterno(periodo),
+    enabled: /^\d{4}-\d{2}$/.test(periodo),
+    staleTime: 60_000,
+  });
+}
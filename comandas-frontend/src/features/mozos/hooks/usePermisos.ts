@@ -0,0 +1,75 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { guardarSesionEmpleado, obtenerSesionEmpleado } from '../../../lib/sesionEmpleado';
+import { permisosApi } from '../api/permisosApi';
+import type { MatrizPermisos, SesionEmpleado } from '../types';
+
+export const permisosKeys = {
+  matriz: ['matriz-permisos'] as const,
+};
+
+function esTauri(): boolean {
+  return typeof window !== 'undefined' && '__TAURI_INTERNALS__' in window;
+}
+
+/**
+ * Matriz de permisos del local; si nunca se configuró viene la por defecto (inactiva).
+ *
+ * queryKey: ['matriz-permisos']
+ */
+export function useMatrizPermisos() {
+  return useQuery<MatrizPermisos>({
+    queryKey: permisosKeys.matriz,
+    queryFn: () => permisosApi.obtenerMatriz(),
+    staleTime: 60_000,
+  });
+}
+
+export function useGuardarMatrizPermisos() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (matriz: MatrizPermisos) => permisosApi.guardarMatriz(matriz),
+    onSuccess: (matriz) => {
+      queryClient.setQueryData(permisosKeys.matriz, matriz);
+    },
+    onError: (error: unknown) => {
+      console.error('[useGuardarMatrizPermisos] Error al guardar la matriz:', error);
+    },
+  });
+}
+
+/**
+ * Identifica al empleado con su PIN y deja la sesión en la terminal.
+ *
+ * En escritorio la identificación pasa por la capa Rust, que también guarda
+ * la sesión para validar lo que hace sin el backend (abrir el cajón).
+ */
+export function useIdentificarEmpleado() {
+  return useMutation({
+    mutationFn: async ({ empleadoId, pin }: { empleadoId: string; pin: string }): Promise<SesionEmpleado> => {
+      if (esTauri()) {
+        const { invoke } = await import('@tauri-apps/api/core');
+        return invoke<SesionEmpleado>('identificar_empleado', { empleadoId, pin });
+      }
+      return permisosApi.identificar(empleadoId, pin);
+    },
+    onSuccess: (sesion) => guardarSesionEmpleado(sesion),
+  });
+}
+
+/**
+ * Cierra la sesión del empleado: lo siguiente vuelve a pedir identificarse.
+ * Primero la cierra en el backend, mientras el cliente todavía manda el token;
+ * si el backend no responde, igual se olvida acá y la del backend vence sola.
+ */
+export async function cerrarSesionEmpleado(): Promise<void> {
+  if (obtenerSesionEmpleado()) {
+    await permisosApi.cerrarSesion()
+      .catch((e) => console.error('[cerrarSesionEmpleado] No se pudo cerrar la sesión en el backend:', e));
+  }
+  guardarSesionEmpleado(null);
+  if (esTauri()) {
+    const { invoke } = await import('@tauri-apps/api/core');
+    await invoke('cerrar_sesion_empleado');
+  }
+}
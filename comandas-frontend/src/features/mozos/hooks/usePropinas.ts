@@ -0,0 +1,66 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { imprimirEscPos } from '../../pedido/services/printerService';
+import { propinasApi } from '../api/propinasApi';
+import type { DistribucionPropinas, ReglaPropinas } from '../types';
+
+export const propinasKeys = {
+  regla: ['regla-propinas'] as const,
+  distribuciones: (desde: string, hasta: string) => ['distribuciones-propinas', desde, hasta] as const,
+};
+
+/**
+ * Regla de reparto del local; si nunca se configuró viene la regla por defecto.
+ *
+ * queryKey: ['regla-propinas']
+ */
+export function useReglaPropinas() {
+  return useQuery<ReglaPropinas>({
+    queryKey: propinasKeys.regla,
+    queryFn: () => propinasApi.obtenerRegla(),
+    staleTime: 60_000,
+  });
+}
+
+export function useGuardarReglaPropinas() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (regla: ReglaPropinas) => propinasApi.guardarRegla(regla),
+    onSuccess: (regla) => {
+      queryClient.setQueryData(propinasKeys.regla, regla);
+    },
+    onError: (error: unknown) => {
+      console.error('[useGuardarReglaPropinas] Error al guardar la regla:', error);
+    },
+  });
+}
+
+/**
+ * Repartos de los turnos cerrados entre dos fechas, del más reciente al más viejo.
+ *
+ * queryKey: ['distribuciones-propinas', desde, hasta]
+ */
+export function useDistribucionesPropinas(desde: string, hasta: string) {
+  return useQuery<DistribucionPropinas[]>({
+    queryKey: propinasKeys.distribuciones(desde, hasta),
+    queryFn: () => propinasApi.listarDistribuciones(desde, hasta),
+    enabled: !!desde && !!hasta,
+    staleTime: 60_000,
+  });
+}
+
+/**
+ * Genera el ESC/POS del comprobante y lo manda a la impresora de tickets.
+ */
+export function useImprimirComprobantePropinas() {
+  return useMutation({
+    mutationFn: async (distribucionId: string) => {
+      const base64 = await propinasApi.generarComprobante(distribucionId);
+      const result = await imprimirEscPos(base64, 'Reparto de propinas');
+      if (!result.success) {
+        throw new Error(result.message);
+      }
+      return result;
+    },
+  });
+}
@@ -0,0 +1,78 @@
+/**
+ * Módulo Mozos — alta de mozos, asignación al abrir la mesa y reporte
+ * de desempeño para incentivos (HU-111).
+ * HU-124: Terminal de fichaje y reporte mensual de horas.
+ * HU-132: Tope y resumen mensual de consumo interno del personal.
+ * HU-141: Regla de reparto del pozo de propinas y comprobante por turno.
+ * HU-169: Matriz de permisos por rol y por empleado, e identificación con PIN.
+ *
+ * @example
+ * import { ReporteMozosPage, useMozos } from '@/features/mozos';
+ */
+
+// Tipos
+export type {
+  Mozo,
+  MozoRequest,
+  MozoResumen,
+  ReporteMozos,
+  RolPersonal,
+  Fichaje,
+  MarcaFichaje,
+  HorasEmpleado,
+  ReporteHoras,
+  ConsumoEmpleado,
+  ReporteConsumoPersonal,
+  ModoRepartoPropinas,
+  ReglaPropinas,
+  PartePropina,
+  DistribucionPropinas,
+  Permiso,
+  MatrizPermisos,
+  SesionEmpleado,
+} from './types';
+export { puedeAutorizar, PERMISO_LABELS } from './types';
+
+// Hooks
+export {
+  useMozos,
+  useCrearMozo,
+  useActualizarMozo,
+  useReporteMozos,
+  useDefinirTopeConsumo,
+  useConsumoInterno,
+} from './hooks/useMozos';
+export {
+  useMarcarFichaje,
+  useReporteHoras,
+  useCorregirFichaje,
+  useRegistrarFichajeManual,
+} from './hooks/useFichajes';
+export {
+  useReglaPropinas,
+  useGuardarReglaPropinas,
+  useDistribucionesPropinas,
+  useImprimirComprobantePropinas,
+} from './hooks/usePropinas';
+export {
+  useMatrizPermisos,
+  useGuardarMatrizPermisos,
+  useIdentificarEmpleado,
+  cerrarSesionEmpleado,
+} from './hooks/usePermisos';
+
+// Componentes
+export { default as ReporteMozosPage } from './components/ReporteMozosPage';
+export { default as MozosPanel } from './components/MozosPanel';
+export { default as FichajeTerminalPage } from './components/FichajeTerminalPage';
+export { default as ReporteHorasPage } from './components/ReporteHorasPage';
+export { default as ReporteConsumoPersonalPage } from './components/ReporteConsumoPersonalPage';
+export { default as PropinasPage } from './components/PropinasPage';
+export { default as PermisosPage } from './components/PermisosPage';
+export { default as IdentificarEmpleadoModal } from './components/IdentificarEmpleadoModal';
+
+// API
+export { mozosApi } from './api/mozosApi';
+export { fichajesApi } from './api/fichajesApi';
+export { propinasApi } from './api/propinasApi';
+export { permisosApi } from './api/permisosApi';
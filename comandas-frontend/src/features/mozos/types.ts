@@ -0,0 +1,239 @@
+import type { TipoTurno } from '../caja/types';
+import type { Permiso } from '../../lib/sesionEmpleado';
+
+// ─── Mozos (HU-111) ──────────────────────────────────────────────────────────
+
+/**
+ * HU-121: El encargado autoriza con su PIN las operaciones sensibles.
+ * HU-124: El dueño además autoriza las correcciones de fichaje.
+ * HU-140: Los repartidores llevan los pedidos de delivery.
+ */
+export type RolPersonal = 'MOZO' | 'ENCARGADO' | 'DUENO' | 'REPARTIDOR';
+
+export const ROL_PERSONAL_LABELS: Record<RolPersonal, string> = {
+  MOZO: 'Mozo',
+  ENCARGADO: 'Encargado',
+  DUENO: 'Dueño',
+  REPARTIDOR: 'Repartidor',
+};
+
+/** HU-124: Roles que autorizan operaciones sensibles con su PIN */
+export function puedeAutorizar(mozo: Mozo): boolean {
+  return (mozo.rol === 'ENCARGADO' || mozo.rol === 'DUENO') && mozo.activo && mozo.tienePin;
+}
+
+export interface Mozo {
+  id: string;
+  nombre: string;
+  /** Los inactivos no pueden tomar mesas, pero siguen en los reportes */
+  activo: boolean;
+  rol: RolPersonal;
+  /** El PIN nunca viaja al frontend, solo si está configurado */
+  tienePin: boolean;
+  /** HU-124: Tarjeta de fichaje asignada (el código tampoco se expone) */
+  tieneTarjeta: boolean;
+  /** HU-132: Tope mensual de consumo a cuenta; null = sin tope */
+  topeConsumoMensual: number | null;
+}
+
+export interface MozoRequest {
+  nombre: string;
+  activo?: boolean;
+  /** Sin enviar = sin cambios */
+  rol?: RolPersonal;
+  /** Sin enviar = sin cambios, vacío = quitar el PIN */
+  pin?: string;
+  /** HU-124: Sin enviar = sin cambios, vacío = quitar la tarjeta */
+  tarjeta?: string;
+}
+
+// ─── Reporte de desempeño ────────────────────────────────────────────────────
+
+export interface MozoResumen {
+  /** null para la fila "Sin asignar" */
+  mozoId: string | null;
+  nombre: string;
+  cantidadPedidos: number;
+  /** Total cobrado de sus pedidos, sin propinas */
+  ventas: number;
+  cubiertos: number;
+  ticketPromedio: number;
+  /** 0 si no se informaron cubiertos */
+  promedioPorCubierto: number;
+  /** Unidades quitadas después de enviarse a cocina */
+  unidadesAnuladas: number;
+  montoAnulado: number;
+  propinas: number;
+}
+
+export interface ReporteMozos {
+  desde: string;
+  hasta: string;
+  mozos: MozoResumen[];
+  totalVentas: number;
+  totalPropinas: number;
+}
+
+// ─── Fichaje de personal (HU-124) ────────────────────────────────────────────
+
+export type MedioFichaje = 'PIN' | 'TARJETA' | 'MANUAL';
+
+export const MEDIO_FICHAJE_LABELS: Record<MedioFichaje, string> = {
+  PIN: 'PIN',
+  TARJETA: 'Tarjeta',
+  MANUAL: 'Manual',
+};
+
+/** Se identifica por tarjeta o por empleado + PIN */
+export interface MarcarFichajeRequest {
+  mozoId?: string;
+  pin?: string;
+  tarjeta?: string;
+}
+
+export interface MarcaFichaje {
+  tipo: 'ENTRADA' | 'SALIDA';
+  nombreEmpleado: string;
+  hora: string;
+  /** 0 en las entradas */
+  minutosTrabajados: number;
+  /** true si había una entrada sin salida de más de 16 h que quedó para corregir */
+  quedoIncompleto: boolean;
+}
+
+export interface Fichaje {
+  id: string;
+  mozoId: string;
+  nombreEmpleado: string;
+  medio: MedioFichaje;
+  entrada: string;
+  /** null mientras el empleado sigue presente */
+  salida: string | null;
+  minutosTrabajados: number;
+  /** Entrada sin salida de más de 16 h: salida olvidada */
+  incompleto: boolean;
+  /** Marcas de la terminal antes de la primera corrección */
+  entradaOriginal: string | null;
+  salidaOriginal: string | null;
+  motivoCorreccion: string | null;
+  corregidoPor: string | null;
+  fechaCorreccion: string | null;
+}
+
+/** Corrección de un fichaje o alta manual, siempre con el PIN del dueño */
+export interface CorreccionFichajeRequest {
+  /** Solo en el alta manual */
+  mozoId?: string;
+  entrada: string;
+  salida: string | null;
+  motivo: string;
+  duenoId: string;
+  pin: string;
+}
+
+export interface HorasEmpleado {
+  mozoId: string;
+  nombre: string;
+  minutos: number;
+  diasTrabajados: number;
+  fichajesIncompletos: number;
+  fichajesCorregidos: number;
+  fichajes: Fichaje[];
+}
+
+export interface ReporteHoras {
+  /** YYYY-MM */
+  periodo: string;
+  empleados: HorasEmpleado[];
+  totalMinutos: number;
+}
+
+// ─── Consumo interno del personal (HU-132) ───────────────────────────────────
+
+/** null = quitar el tope */
+export interface TopeConsumoRequest {
+  topeMensual: number | null;
+}
+
+export interface ConsumoEmpleado {
+  mozoId: string;
+  nombre: string;
+  /** null = sin tope: todo el consumo es beneficio */
+  topeMensual: number | null;
+  consumos: number;
+  consumido: number;
+  /** Lo que supera el tope; se descuenta del sueldo */
+  excedente: number;
+  /** Lo que le queda del beneficio en el mes; null si no tiene tope */
+  disponible: number | null;
+}
+
+export interface ReporteConsumoPersonal {
+  /** YYYY-MM */
+  periodo: string;
+  empleados: ConsumoEmpleado[];
+  totalConsumido: number;
+  totalADescontar: number;
+}
+
+// ─── Pozo de propinas (HU-141) ───────────────────────────────────────────────
+
+export type ModoRepartoPropinas = 'PARTES_IGUALES' | 'POR_HORAS' | 'POR_PUESTO';
+
+export const MODO_REPARTO_LABELS: Record<ModoRepartoPropinas, string> = {
+  PARTES_IGUALES: 'Partes iguales',
+  POR_HORAS: 'Por horas trabajadas',
+  POR_PUESTO: 'Por puesto',
+};
+
+export interface ReglaPropinas {
+  modo: ModoRepartoPropinas;
+  /** 0 = el rol no participa del pozo; en POR_PUESTO es el peso de cada empleado */
+  puntosPorRol: Record<RolPersonal, number>;
+}
+
+export interface PartePropina {
+  mozoId: string;
+  nombre: string;
+  /** null si el empleado ya no existe */
+  rol: RolPersonal | null;
+  /** Minutos fichados dentro del turno */
+  minutos: number;
+  /** 1, los minutos o los puntos del puesto según el modo */
+  peso: number;
+  monto: number;
+}
+
+export interface DistribucionPropinas {
+  id: string;
+  turnoId: string;
+  tipoTurno: TipoTurno | null;
+  /** Cierre del turno */
+  fecha: string;
+  modo: ModoRepartoPropinas;
+  pozo: number;
+  /** Lo que no se repartió porque nadie fichó en el turno */
+  sinRepartir: number;
+  partes: PartePropina[];
+}
+
+// ─── Permisos por acción (HU-169) ────────────────────────────────────────────
+
+export type { Permiso, SesionEmpleado } from '../../lib/sesionEmpleado';
+
+export const PERMISO_LABELS: Record<Permiso, string> = {
+  ANULAR_ITEMS: 'Anular ítems enviados a cocina',
+  APLICAR_DESCUENTOS: 'Aplicar descuentos',
+  ABRIR_CAJON: 'Abrir el cajón',
+  VER_REPORTES: 'Ver reportes',
+  MODIFICAR_PRECIOS: 'Modificar precios',
+};
+
+export interface MatrizPermisos {
+  /** Inactiva = nadie necesita identificarse y todos pueden todo */
+  activa: boolean;
+  /** El dueño siempre tiene todos */
+  permisosPorRol: Record<RolPersonal, Permiso[]>;
+  /** Por id de empleado: true concede y false quita, por encima de su rol */
+  excepciones: Record<string, Partial<Record<Permiso, boolean>>>;
+}
@@ -0,0 +1,22 @@
+/** 485 → "8 h 05 min" */
+export function formatMinutos(minutos: number): string {
+  const h = Math.floor(minutos / 60);
+  const m = minutos % 60;
+  if (h === 0) return `${m} min`;
+  return `${h} h ${String(m).padStart(2, '0')} min`;
+}
+
+/** ISO LocalDateTime → "09:05" */
+export function horaCorta(iso: string): string {
+  return iso.slice(11, 16);
+}
+
+/** ISO LocalDateTime → "dd/mm" */
+export function diaCorto(iso: string): string {
+  return `${iso.slice(8, 10)}/${iso.slice(5, 7)}`;
+}
+
+/** ISO LocalDateTime → valor para <input type="datetime-local"> */
+export function aInputFechaHora(iso: string | null): string {
+  return iso ? iso.slice(0, 16) : '';
+}
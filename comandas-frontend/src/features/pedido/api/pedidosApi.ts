@@ -5,37 +5,96 @@ import type {
   AgregarProductoResponse,
   AplicarDescuentoManualResponse,
   DescuentoManualRequest,
+  TiempoServicio,
 } from '../types';
 
+/**
+ * HU-165: Versión del pedido que está mostrando la terminal. El backend
+ * rechaza el cambio (409 PEDIDO_MODIFICADO) si otra terminal lo modificó
+ * en el medio, y sin la cabecera responde 400.
+ */
+export function cabeceraVersion(version: number) {
+  return { headers: { 'X-Pedido-Version': String(version) } };
+}
+
 export const pedidosApi = {
   agregarProducto: (
     pedidoId: string,
     data: AgregarProductoRequest,
+    version: number,
   ): Promise<AxiosResponse<AgregarProductoResponse>> =>
-    apiClient.post(`/pedidos/${pedidoId}/items`, data),
+    apiClient.post(`/pedidos/${pedidoId}/items`, data, cabeceraVersion(version)),
 
   aplicarDescuentoGlobal: (
     pedidoId: string,
     data: DescuentoManualRequest,
+    version: number,
   ): Promise<AxiosResponse<AplicarDescuentoManualResponse>> =>
-    apiClient.post(`/pedidos/${pedidoId}/descuento-manual`, data),
+    apiClient.post(`/pedidos/${pedidoId}/descuento-manual`, data, cabeceraVersion(version)),
 
   aplicarDescuentoPorItem: (
     pedidoId: string,
     itemId: string,
     data: DescuentoManualRequest,
+    version: number,
   ): Promise<AxiosResponse<AplicarDescuentoManualResponse>> =>
-    apiClient.post(`/pedidos/${pedidoId}/items/${itemId}/descuento-manual`, data),
+    apiClient.post(`/pedidos/${pedidoId}/items/${itemId}/descuento-manual`, data, cabeceraVersion(version)),
 
   modificarCantidad: (
     pedidoId: string,
     itemId: string,
     cantidad: number,
+    version: number,
+  ): Promise<AxiosResponse<AgregarProductoResponse>> =>
+    apiClient.patch(`/pedidos/${pedidoId}/items/${itemId}`, { cantidad }, cabeceraVersion(version)),
+
+  eliminarItem: (
+    pedidoId: string,
+    itemId: string,
+    version: number,
+  ): Promise<AxiosResponse<AgregarProductoResponse>> =>
+    apiClient.delete(`/pedidos/${pedidoId}/items/${itemId}`, cabeceraVersion(version)),
+
+  /** HU-110: Cambia el paso de servicio de un ítem (null = sin paso) */
+  cambiarTiempoServicio: (
+    pedidoId: string,
+    itemId: string,
+    tiempoServicio: TiempoServicio | null,
+  ): Promise<AxiosResponse<void>> =>
+    apiClient.patch(`/pedidos/${pedidoId}/items/${itemId}/tiempo`, { tiempoServicio }),
+
+  /** HU-120: Cambia la observación libre de un ítem (null = sin observación) */
+  cambiarObservacion: (
+    pedidoId: string,
+    itemId: string,
+    observacion: string | null,
+    version: number,
+  ): Promise<AxiosResponse<AgregarProductoResponse>> =>
+    apiClient.patch(`/pedidos/${pedidoId}/items/${itemId}/observacion`, { observacion }, cabeceraVersion(version)),
+
+  /** HU-137: Marca el pedido urgente (también sus comandas en cocina) */
+  marcarUrgente: (pedidoId: string, urgente: boolean): Promise<AxiosResponse<void>> =>
+    apiClient.patch(`/pedidos/${pedidoId}/urgente`, { urgente }),
+
+  /** HU-137: Marca un ítem urgente para su próximo envío a cocina */
+  marcarUrgenteItem: (
+    pedidoId: string,
+    itemId: string,
+    urgente: boolean,
+  ): Promise<AxiosResponse<void>> =>
+    apiClient.patch(`/pedidos/${pedidoId}/items/${itemId}/urgente`, { urgente }),
+
+  /** HU-139: Carga la entrega del delivery y su costo de envío (zonaId null = por barrio) */
+  asignarEntrega: (
+    pedidoId: string,
+    direccion: string,
+    zonaId: string | null,
   ): Promise<AxiosResponse<void>> =>
-    apiClient.patch(`/pedidos/${pedidoId}/items/${itemId}`, { cantidad }),
+    apiClient.put(`/pedidos/${pedidoId}/entrega`, { direccion, zonaId }),
 
-  eliminarItem: (pedidoId: string, itemId: string): Promise<AxiosResponse<void>> =>
-    apiClient.delete(`/pedidos/${pedidoId}/items/${itemId}`),
+  /** HU-168: Vincula un cliente de la ficha al pedido (null = desvincular) */
+  vincularCliente: (pedidoId: string, clienteId: string | null): Promise<AxiosResponse<AgregarProductoResponse>> =>
+    apiClient.put(`/pedidos/${pedidoId}/cliente`, { clienteId }),
 
   reabrir: (pedidoId: string): Promise<AxiosResponse<void>> =>
     apiClient.post(`/pedidos/${pedidoId}/reapertura`),
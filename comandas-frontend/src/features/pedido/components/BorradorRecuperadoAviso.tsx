@@ -0,0 +1,102 @@
+import { History, Loader2 } from 'lucide-react';
+import { useBorradorPedido, useRecuperarBorrador, useDescartarBorrador } from '../hooks/usePedido';
+import useToast from '../../../hooks/useToast';
+
+interface BorradorRecuperadoAvisoProps {
+  mesaId: string;
+  pedidoId?: string;
+  /** HU-165: Versión del pedido que ve la terminal */
+  version?: number;
+}
+
+function horaDe(epochSegundos: number): string {
+  return new Date(epochSegundos * 1000).toLocaleTimeString('es-AR', { hour: '2-digit', minute: '2-digit' });
+}
+
+/**
+ * Aviso de ítems sin confirmar — HU-164
+ *
+ * Después de un corte de luz, un cierre de la app o con el servidor caído,
+ * los ítems que el mozo cargó y que el backend no llegó a confirmar quedan
+ * en el borrador de la mesa. Al volver a abrirla se listan para cargarlos
+ * al pedido de un toque o descartarlos si ya se cargaron a mano.
+ */
+export default function BorradorRecuperadoAviso({ mesaId, pedidoId, version }: BorradorRecuperadoAvisoProps) {
+  const toast = useToast();
+  const { data: borrador } = useBorradorPedido(mesaId);
+  const recuperar = useRecuperarBorrador();
+  const descartar = useDescartarBorrador();
+
+  if (!borrador || borrador.items.length === 0) return null;
+
+  const ocupado = recuperar.isPending || descartar.isPending;
+
+  const handleRecuperar = () => {
+    if (!pedidoId || version === undefined) return;
+    recuperar.mutate(
+      { mesaId, pedidoId, version, borrador },
+      {
+        onSuccess: (cantidad) => toast.success(`${cantidad} ítems recuperados al pedido`),
+        onError: (err: any) =>
+          toast.error(
+            err?.response?.data?.message ||
+              'El servidor sigue sin responder: los ítems quedan guardados para reintentar',
+          ),
+      },
+    );
+  };
+
+  const handleDescartar = () => {
+    descartar.mutate(mesaId, {
+      onSuccess: () => toast.info('Borrador descartado'),
+      onError: () => toast.error('No se pudo descartar el borrador'),
+    });
+  };
+
+  return (
+    <div className="mx-4 mt-4 rounded-xl border border-amber-600/50 bg-amber-950/30 px-4 py-3">
+      <div className="flex items-start gap-3">
+        <History size={18} className="mt-0.5 shrink-0 text-amber-400" />
+        <div className="flex-1 min-w-0">
+          <p className="text-sm font-semibold text-amber-200">
+            {borrador.items.length === 1
+              ? 'Quedó 1 ítem sin confirmar'
+              : `Quedaron ${borrador.items.length} ítems sin confirmar`}
+            <span className="ml-2 text-xs font-normal text-amber-400/80">
+              cargados a las {horaDe(borrador.actualizado)}
+            </span>
+          </p>
+          <ul className="mt-1 text-xs text-amber-100/80 space-y-0.5">
+            {borrador.items.map((item) => (
+              <li key={item.id} className="truncate">
+                {item.request.cantidad}x {item.nombre}
+                {item.request.observaciones && (
+                  <span className="ml-1 text-amber-400/70">({item.request.observaciones})</span>
+                )}
+              </li>
+            ))}
+          </ul>
+        </div>
+        <div className="flex shrink-0 gap-2">
+          <button
+            type="button"
+            onClick={handleDescartar}
+            disabled={ocupado}
+            className="h-9 px-3 rounded-lg text-xs text-gray-300 bg-neutral-800 border border-neutral-700 hover:border-neutral-600 disabled:opacity-40"
+          >
+            Descartar
+          </button>
+          <button
+            type="button"
+            onClick={handleRecuperar}
+            disabled={ocupado || !pedidoId}
+            className="h-9 px-3 rounded-lg text-xs font-bold text-white bg-amber-600 hover:bg-amber-500 disabled:bg-neutral-700 disabled:text-gray-500 flex items-center gap-1.5"
+          >
+            {recuperar.isPending && <Loader2 size={14} className="animate-spin" />}
+            Cargar al pedido
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -11,13 +11,29 @@ import {
   Check,
   Printer,
   Coffee,
+  BookUser,
+  Star,
+  HandCoins,
+  FileText,
+  UserRound,
+  Gift,
 } from 'lucide-react';
 import type { DetallePedidoResponse } from '../types';
 import type { TicketImpresionResponse } from '../types-impresion';
-import type { MedioPago, PagoRequest } from '../../salon/types';
+import { MEDIOS_CON_COMPROBANTE, MEDIOS_MONEDA_EXTRANJERA, type MedioPago, type Moneda, type PagoRequest } from '../../salon/types';
 import { useCerrarMesa, useObtenerTicket, useGenerarTicketEscPos } from '../../salon/hooks/useMesas';
+import { esPedidoModificado } from '../hooks/usePedido';
 import { imprimirEscPos } from '../services/printerService';
 import TicketPreview from './TicketPreview';
+import SelectorCliente from '../../clientes/components/SelectorCliente';
+import FacturaModal from '../../facturacion/components/FacturaModal';
+import { useProgramaPuntos, usePuntosCliente } from '../../clientes/hooks/useClientes';
+import { useMozos } from '../../mozos/hooks/useMozos';
+import { useRecargosMedioPago, useRedondeoEfectivo, useTiposCambio } from '../../caja/hooks/useCaja';
+import { calcularAjusteRedondeo } from '../../caja/utils/redondeo';
+import CodigoTarjetaRegaloInput from '../../tarjetasRegalo/components/CodigoTarjetaRegaloInput';
+import ComprobantePagoInput from '../../caja/components/ComprobantePagoInput';
+import { LONGITUD_CODIGO, normalizarCodigo } from '../../tarjetasRegalo/types';
 import useToast from '../../../hooks/useToast';
 
 // ─── Tipos locales ────────────────────────────────────────────────────────────
@@ -26,6 +42,12 @@ interface PagoEntry {
   id: string;
   medio: MedioPago;
   monto: string;
+  /** HU-144: solo para TARJETA_REGALO */
+  codigoTarjetaRegalo?: string;
+  /** HU-151: moneda en que se tipea el monto (pesos si se omite) */
+  moneda?: Moneda;
+  /** HU-156: cupón u operación del comprobante (tarjeta, transferencia, QR) */
+  referencia?: string;
 }
 
 interface CerrarMesaModalProps {
@@ -49,8 +71,13 @@ const MEDIOS_PAGO: Array<{
   { tipo: 'TRANSFERENCIA', icono: ArrowRightLeft, label: 'Transfer.', color: 'text-purple-400' },
   { tipo: 'QR', icono: QrCode, label: 'QR', color: 'text-yellow-400' },
   { tipo: 'A_CUENTA', icono: Coffee, label: 'A Cuenta', color: 'text-orange-400' },
+  { tipo: 'CUENTA_CORRIENTE', icono: BookUser, label: 'Cta. Cte.', color: 'text-amber-400' },
+  { tipo: 'TARJETA_REGALO', icono: Gift, label: 'Gift card', color: 'text-pink-400' },
 ];
 
+/** HU-151: Prefijo del input de monto según la moneda */
+const SIMBOLO_MONEDA: Record<Moneda, string> = { ARS: '$', USD: 'US$', EUR: '€' };
+
 // ─── Helpers ──────────────────────────────────────────────────────────────────
 
 let _nextPagoId = 0;
@@ -72,6 +99,29 @@ function nextPagoId(): string {
  * HU-04: Cerrar mesa
  * HU-12: Cierre de mesa y liquidación final
  * HU-29: Ticket de venta
+ * HU-104: Cuenta corriente — si algún pago es CUENTA_CORRIENTE hay que elegir el cliente
+ * HU-105: Programa de puntos — con el programa activo se puede identificar al cliente
+ *         para que sume puntos y, si tiene saldo, canjearlos como descuento
+ * HU-111: Propina — se informa aparte; no se suma al total ni a los pagos
+ * HU-115: Factura — si se pide, al cerrar se abre la emisión antes de volver al salón
+ * HU-132: Consumo interno — los pagos A_CUENTA se imputan al empleado que consumió;
+ *         si supera su tope mensual el cierre avisa cuánto se descuenta del sueldo
+ * HU-142: Seña de la reserva — ya viene descontada del total; si lo cubre entero
+ *         se cierra sin pagos
+ * HU-143: Recargo por medio de pago — los montos cubren el total y el recargo se
+ *         cobra encima; el backend lo recalcula y lo congela en cada pago
+ * HU-144: Tarjeta de regalo — cada pago con gift card lleva su código; se muestra
+ *         el saldo al tipearlo y el backend lo descuenta al cerrar
+ * HU-151: Moneda extranjera — efectivo, tarjeta y transferencia se pueden tipear en
+ *         USD o EUR; se pasan a pesos con la cotización del día y el backend guarda
+ *         la moneda y el tipo de cambio en el pago. El vuelto se da en pesos.
+ * HU-152: Redondeo del efectivo — el efectivo en pesos se cobra redondeado al
+ *         múltiplo del local; el total no cambia y la diferencia se muestra aparte.
+ * HU-156: Comprobante — en transferencias se copian alias, CVU y monto; tarjeta,
+ *         transferencia y QR guardan el número de cupón u operación, que se
+ *         puede pegar desde el portapapeles.
+ * HU-160: Pagos a cuenta — lo cobrado con la mesa abierta se descuenta y solo
+ *         se cobra el saldo; si ya está todo pagado se cierra sin pagos.
  */
 export default function CerrarMesaModal({
   mesaId,
@@ -84,7 +134,35 @@ export default function CerrarMesaModal({
   const obtenerTicket = useObtenerTicket();
   const generarTicketEscPos = useGenerarTicketEscPos();
 
-  const total = pedido.totalParcial;
+  // ── HU-143: Recargos por medio de pago ──
+  const { data: recargos } = useRecargosMedioPago();
+  const porcentajeRecargo = useCallback(
+    (medio: MedioPago) => recargos?.porcentajes[medio] ?? 0,
+    [recargos]
+  );
+
+  // ── HU-151: Tipos de cambio del día ──
+  const { data: tiposCambio } = useTiposCambio();
+  const monedasCotizadas = useMemo(
+    () => (['USD', 'EUR'] as Moneda[]).filter((m) => (tiposCambio?.cotizaciones[m] ?? 0) > 0),
+    [tiposCambio]
+  );
+  /** Pesos que cubre el pago: el monto tipeado por la cotización de su moneda */
+  const montoEnPesos = useCallback(
+    (p: PagoEntry) => {
+      const monto = parseFloat(p.monto) || 0;
+      if (!p.moneda || p.moneda === 'ARS') return monto;
+      return Math.round(monto * (tiposCambio?.cotizaciones[p.moneda] ?? 0) * 100) / 100;
+    },
+    [tiposCambio]
+  );
+
+  // ── HU-152: Redondeo del efectivo ──
+  const { data: redondeo } = useRedondeoEfectivo();
+
+  // ── HU-105: Programa de puntos ──
+  const { data: programa } = useProgramaPuntos();
+  const programaActivo = programa?.activo ?? false;
 
   // ── Estado del ticket preview ──
   const [ticketData, setTicketData] = useState<TicketImpresionResponse | null>(null);
@@ -95,6 +173,38 @@ export default function CerrarMesaModal({
     { id: nextPagoId(), medio: 'EFECTIVO', monto: '' },
   ]);
 
+  // ── HU-104: Cliente para pagos a cuenta corriente ──
+  const [clienteId, setClienteId] = useState<string | null>(null);
+  const [puntosInput, setPuntosInput] = useState('');
+  const { data: puntosCliente } = usePuntosCliente(programaActivo ? clienteId : null);
+
+  // ── HU-132: Empleado del consumo interno ──
+  const { data: mozos = [] } = useMozos();
+  const empleadosActivos = useMemo(() => mozos.filter((m) => m.activo), [mozos]);
+  const [empleadoConsumoId, setEmpleadoConsumoId] = useState('');
+
+  // ── HU-111: Propina del mozo (fuera del total) ──
+  const [propinaInput, setPropinaInput] = useState('');
+  const propina = Math.max(0, parseFloat(propinaInput) || 0);
+
+  // ── HU-115: Facturar al cerrar ──
+  const [facturar, setFacturar] = useState(false);
+  const [pedidoAFacturar, setPedidoAFacturar] = useState<string | null>(null);
+
+  // El descuento por puntos se resta del total: los pagos cubren solo el resto
+  const valorPunto = programa?.valorPunto ?? 0;
+  const puntosACanjear = programaActivo && clienteId ? parseInt(puntosInput, 10) || 0 : 0;
+  const descuentoPuntos = Math.round(puntosACanjear * valorPunto * 100) / 100;
+  // HU-160: lo cobrado a cuenta con la mesa abierta ya no se cobra al cerrar
+  const pagadoACuenta = pedido.pagadoACuenta ?? 0;
+  // El cierre exige al menos un pago, así que el canje siempre deja algo por cobrar
+  const maxPuntos = puntosCliente && valorPunto > 0
+    ? Math.max(0, Math.min(puntosCliente.disponible, Math.ceil((pedido.totalParcial - pagadoACuenta) / valorPunto) - 1))
+    : 0;
+  const puntosValidos = puntosACanjear >= 0 && puntosACanjear <= maxPuntos;
+
+  const total = Math.round((pedido.totalParcial - descuentoPuntos - pagadoACuenta) * 100) / 100;
+
   // ── Cargar ticket preview al montar ──
   useEffect(() => {
     obtenerTicket.mutate(mesaId, {
@@ -113,22 +223,75 @@ export default function CerrarMesaModal({
 
   // ── Cálculos de pago ──
   const sumaPagos = useMemo(
-    () => pagos.reduce((acc, p) => acc + (parseFloat(p.monto) || 0), 0),
-    [pagos]
+    () => pagos.reduce((acc, p) => acc + montoEnPesos(p), 0),
+    [pagos, montoEnPesos]
   );
 
   const diferencia = sumaPagos - total;
   const tieneEfectivo = pagos.some((p) => p.medio === 'EFECTIVO');
   const hayVuelto = diferencia > 0 && tieneEfectivo;
 
+  // HU-143: mismo cálculo que el backend, sobre el monto que queda aplicado
+  // (el efectivo que excede el total es vuelto y no lleva recargo)
+  const totalRecargos = useMemo(() => {
+    let excedente = hayVuelto ? diferencia : 0;
+    return pagos.reduce((acc, p) => {
+      let monto = montoEnPesos(p);
+      if (p.medio === 'EFECTIVO' && excedente > 0) {
+        const descontado = Math.min(monto, excedente);
+        monto -= descontado;
+        excedente -= descontado;
+      }
+      return acc + Math.round(monto * porcentajeRecargo(p.medio)) / 100;
+    }, 0);
+  }, [pagos, hayVuelto, diferencia, porcentajeRecargo, montoEnPesos]);
+
+  // HU-152: mismo cálculo que el backend, sobre el efectivo en pesos aplicado
+  // con su recargo (el vuelto no se redondea)
+  const ajusteRedondeo = useMemo(() => {
+    if (!redondeo?.multiplo) return 0;
+    let excedente = hayVuelto ? diferencia : 0;
+    const efectivoPesos = pagos.reduce((acc, p) => {
+      if (p.medio !== 'EFECTIVO') return acc;
+      let monto = montoEnPesos(p);
+      const descontado = Math.min(monto, excedente);
+      monto -= descontado;
+      excedente -= descontado;
+      if (p.moneda && p.moneda !== 'ARS') return acc;
+      return acc + monto + Math.round(monto * porcentajeRecargo(p.medio)) / 100;
+    }, 0);
+    return calcularAjusteRedondeo(efectivoPesos, redondeo.multiplo, redondeo.modo);
+  }, [redondeo, pagos, hayVuelto, diferencia, porcentajeRecargo, montoEnPesos]);
+  const vueltoEfectivo = diferencia - ajusteRedondeo;
+
+  const montoCuentaCorriente = pagos
+    .filter((p) => p.medio === 'CUENTA_CORRIENTE')
+    .reduce((acc, p) => acc + (parseFloat(p.monto) || 0), 0);
+  const requiereCliente = pagos.some((p) => p.medio === 'CUENTA_CORRIENTE');
+  const mostrarCliente = requiereCliente || programaActivo;
+  const esConsumoInterno = pagos.some((p) => p.medio === 'A_CUENTA');
+  const montoSenia = pedido.montoSenia ?? 0;
+  // HU-142 / HU-160: la seña o los pagos a cuenta cubren todo el consumo; no queda nada por cobrar
+  const cubiertoSinCobrar = total === 0 && (montoSenia > 0 || pagadoACuenta > 0);
+
   // El cierre es válido cuando la suma cubre exactamente el total,
   // o lo supera sólo si hay efectivo (vuelto)
   const pagoValido = useMemo(() => {
+    if (cubiertoSinCobrar) return sumaPagos === 0 && puntosValidos;
     if (sumaPagos < total) return false;
     if (sumaPagos > total && !tieneEfectivo) return false;
+    if (requiereCliente && !clienteId) return false;
+    if (!puntosValidos) return false;
+    // HU-144: cada pago con tarjeta de regalo necesita el código completo
+    const codigosCompletos = pagos.every(
+      (p) =>
+        p.medio !== 'TARJETA_REGALO' ||
+        normalizarCodigo(p.codigoTarjetaRegalo ?? '').length === LONGITUD_CODIGO
+    );
+    if (!codigosCompletos) return false;
     // Todos los pagos deben tener monto > 0
     return pagos.every((p) => parseFloat(p.monto) > 0);
-  }, [sumaPagos, total, tieneEfectivo, pagos]);
+  }, [sumaPagos, total, tieneEfectivo, pagos, requiereCliente, clienteId, puntosValidos, cubiertoSinCobrar]);
 
   // ── Handlers de pagos ──
 
@@ -145,7 +308,22 @@ export default function CerrarMesaModal({
 
   const handleMedioChange = useCallback((pagoId: string, medio: MedioPago) => {
     setPagos((prev) =>
-      prev.map((p) => (p.id === pagoId ? { ...p, medio } : p))
+      prev.map((p) =>
+        p.id === pagoId
+          ? {
+              ...p,
+              medio,
+              moneda: MEDIOS_MONEDA_EXTRANJERA.includes(medio) ? p.moneda : undefined,
+              referencia: MEDIOS_CON_COMPROBANTE.includes(medio) ? p.referencia : undefined,
+            }
+          : p
+      )
+    );
+  }, []);
+
+  const handleMonedaChange = useCallback((pagoId: string, moneda: Moneda) => {
+    setPagos((prev) =>
+      prev.map((p) => (p.id === pagoId ? { ...p, moneda: moneda === 'ARS' ? undefined : moneda } : p))
     );
   }, []);
 
@@ -155,6 +333,18 @@ export default function CerrarMesaModal({
     );
   }, []);
 
+  const handleReferenciaChange = useCallback((pagoId: string, referencia: string) => {
+    setPagos((prev) =>
+      prev.map((p) => (p.id === pagoId ? { ...p, referencia } : p))
+    );
+  }, []);
+
+  const handleCodigoTarjetaChange = useCallback((pagoId: string, codigoTarjetaRegalo: string) => {
+    setPagos((prev) =>
+      prev.map((p) => (p.id === pagoId ? { ...p, codigoTarjetaRegalo } : p))
+    );
+  }, []);
+
   /** Atajo: pagar todo con un solo método */
   const handlePagoRapido = useCallback(
     (medio: MedioPago) => {
@@ -171,23 +361,27 @@ export default function CerrarMesaModal({
 
     // Construir pagos para el backend
     // Si hay vuelto en efectivo, ajustar el monto del pago en efectivo al total restante
-    const pagosRequest: PagoRequest[] = pagos.map((p) => ({
+    // HU-151: el monto viaja siempre en pesos; la moneda indica en qué pagó el cliente
+    const pagosRequest: PagoRequest[] = cubiertoSinCobrar ? [] : pagos.map((p) => ({
       medio: p.medio,
-      monto: parseFloat(p.monto),
+      monto: montoEnPesos(p),
+      codigoTarjetaRegalo:
+        p.medio === 'TARJETA_REGALO' ? normalizarCodigo(p.codigoTarjetaRegalo ?? '') : undefined,
+      moneda: p.moneda,
+      referencia: MEDIOS_CON_COMPROBANTE.includes(p.medio) ? p.referencia?.trim() || undefined : undefined,
     }));
 
     // Si la suma supera el total y hay efectivo, ajustar
+    // (HU-151: preferentemente el efectivo en pesos, que es la moneda del vuelto)
     if (diferencia > 0 && tieneEfectivo) {
-      const totalSinEfectivo = pagosRequest
-        .filter((p) => p.medio !== 'EFECTIVO')
-        .reduce((acc, p) => acc + p.monto, 0);
-      const montoEfectivoNecesario = total - totalSinEfectivo;
-
-      for (const p of pagosRequest) {
-        if (p.medio === 'EFECTIVO') {
-          p.monto = Math.round(montoEfectivoNecesario * 100) / 100;
-          break;
-        }
+      const efectivo =
+        pagosRequest.find((p) => p.medio === 'EFECTIVO' && !p.moneda) ??
+        pagosRequest.find((p) => p.medio === 'EFECTIVO');
+      if (efectivo) {
+        const totalResto = pagosRequest
+          .filter((p) => p !== efectivo)
+          .reduce((acc, p) => acc + p.monto, 0);
+        efectivo.monto = Math.round((total - totalResto) * 100) / 100;
       }
     }
 
@@ -202,10 +396,25 @@ export default function CerrarMesaModal({
     }
 
     cerrarMesa.mutate(
-      { mesaId, pagos: pagosRequest },
       {
-        onSuccess: async () => {
-          toast.success(`Mesa ${pedido.numeroMesa} cerrada exitosamente`);
+        mesaId,
+        pagos: pagosRequest,
+        clienteId: mostrarCliente ? clienteId ?? undefined : undefined,
+        puntosACanjear: puntosACanjear > 0 ? puntosACanjear : undefined,
+        propina: propina > 0 ? propina : undefined,
+        empleadoConsumoId: esConsumoInterno && empleadoConsumoId ? empleadoConsumoId : undefined,
+        versionPedido: pedido.version,
+      },
+      {
+        onSuccess: async (response) => {
+          toast.success(
+            response.numeroComprobante
+              ? `Mesa ${pedido.numeroMesa} cerrada — comprobante ${response.numeroComprobante}`
+              : `Mesa ${pedido.numeroMesa} cerrada exitosamente`
+          );
+          if (response.avisoConsumoInterno) {
+            toast.warning(response.avisoConsumoInterno, 8000);
+          }
 
           // Imprimir ticket de venta con los bytes pre-generados
           if (ticketBase64) {
@@ -222,9 +431,18 @@ export default function CerrarMesaModal({
             }
           }
 
+          if (facturar) {
+            setPedidoAFacturar(pedido.pedidoId);
+            return;
+          }
           onSuccess();
         },
         onError: (error: any) => {
+          // HU-165: el pedido se refresca solo; el total del cobro se recalcula con lo nuevo
+          if (esPedidoModificado(error)) {
+            toast.warning(`${error.response.data.message} El total del cobro ya se actualizó.`, 8000);
+            return;
+          }
           const msg =
             error?.response?.data?.message || 'Error al cerrar la mesa';
           toast.error(msg);
@@ -234,19 +452,35 @@ export default function CerrarMesaModal({
   }, [
     pagoValido,
     pagos,
+    montoEnPesos,
     diferencia,
     tieneEfectivo,
     total,
     cerrarMesa,
     mesaId,
     pedido.numeroMesa,
+    pedido.pedidoId,
+    pedido.version,
+    facturar,
     toast,
     onSuccess,
     generarTicketEscPos,
+    mostrarCliente,
+    clienteId,
+    puntosACanjear,
+    propina,
+    esConsumoInterno,
+    empleadoConsumoId,
+    cubiertoSinCobrar,
   ]);
 
   const isPending = cerrarMesa.isPending;
 
+  // HU-115: Mesa ya cerrada, queda emitir la factura
+  if (pedidoAFacturar) {
+    return <FacturaModal pedidoId={pedidoAFacturar} onClose={onSuccess} />;
+  }
+
   return (
     <>
       {/* Backdrop */}
@@ -317,11 +551,30 @@ export default function CerrarMesaModal({
               {/* Total grande */}
               <div className="text-center py-3 bg-neutral-800/50 rounded-xl border border-neutral-700/50">
                 <p className="text-xs text-gray-500 uppercase tracking-widest mb-1">
-                  Total a Pagar
+                  {pagadoACuenta > 0 ? 'Saldo a Pagar' : 'Total a Pagar'}
                 </p>
                 <p className="text-3xl font-bold text-red-500 font-mono tabular-nums">
                   $ {total.toLocaleString('es-AR', { minimumFractionDigits: 2 })}
                 </p>
+                {descuentoPuntos > 0 && (
+                  <p className="text-xs text-yellow-400 mt-1">
+                    Incluye − $ {descuentoPuntos.toLocaleString('es-AR', { minimumFractionDigits: 2 })} por {puntosACanjear} puntos
+                  </p>
+                )}
+                {pagadoACuenta > 0 && (
+                  <p className="text-xs text-emerald-400 mt-1">
+                    {cubiertoSinCobrar && montoSenia === 0
+                      ? 'Ya está todo pagado a cuenta: se cierra sin cobrar'
+                      : `Incluye − $ ${pagadoACuenta.toLocaleString('es-AR', { minimumFractionDigits: 2 })} pagado a cuenta`}
+                  </p>
+                )}
+                {montoSenia > 0 && (
+                  <p className="text-xs text-green-400 mt-1">
+                    {cubiertoSinCobrar && pagadoACuenta === 0
+                      ? 'La seña de la reserva cubre el total: se cierra sin cobrar'
+                      : `Incluye − $ ${montoSenia.toLocaleString('es-AR', { minimumFractionDigits: 2 })} de seña de la reserva`}
+                  </p>
+                )}
               </div>
 
               {/* Atajos de pago rápido */}
@@ -329,7 +582,7 @@ export default function CerrarMesaModal({
                 <p className="text-xs font-semibold text-gray-500 uppercase tracking-widest mb-2">
                   Pago rápido (un solo método)
                 </p>
-                <div className="grid grid-cols-4 gap-2">
+                <div className="grid grid-cols-3 gap-2">
                   {MEDIOS_PAGO.map(({ tipo, icono: Icon, label, color }) => (
                     <button
                       key={tipo}
@@ -381,79 +634,284 @@ export default function CerrarMesaModal({
                   {pagos.map((pago, idx) => (
                     <div
                       key={pago.id}
-                      className="flex items-center gap-2 bg-neutral-800/50 rounded-xl p-2 border border-neutral-700/50"
+                      className="space-y-2 bg-neutral-800/50 rounded-xl p-2 border border-neutral-700/50"
                     >
-                      {/* Selector de medio */}
-                      <select
-                        value={pago.medio}
-                        onChange={(e) =>
-                          handleMedioChange(pago.id, e.target.value as MedioPago)
-                        }
-                        disabled={isPending}
-                        className="
-                          h-10 px-2 rounded-lg
-                          bg-neutral-800 border border-neutral-700
-                          text-sm text-gray-200
-                          focus:outline-none focus:border-red-600
-                          disabled:opacity-50
-                        "
-                      >
-                        {MEDIOS_PAGO.map(({ tipo, label }) => (
-                          <option key={tipo} value={tipo}>
-                            {label}
-                          </option>
-                        ))}
-                      </select>
-
-                      {/* Input monto */}
-                      <div className="relative flex-1">
-                        <span className="absolute left-3 top-1/2 -translate-y-1/2 text-gray-500 text-sm">
-                          $
-                        </span>
-                        <input
-                          type="number"
-                          min={0}
-                          step={0.01}
-                          value={pago.monto}
+                      <div className="flex items-center gap-2">
+                        {/* Selector de medio */}
+                        <select
+                          value={pago.medio}
                           onChange={(e) =>
-                            handleMontoChange(pago.id, e.target.value)
+                            handleMedioChange(pago.id, e.target.value as MedioPago)
                           }
-                          placeholder="0.00"
                           disabled={isPending}
                           className="
-                            w-full h-10 pl-7 pr-3
+                            h-10 px-2 rounded-lg
                             bg-neutral-800 border border-neutral-700
-                            rounded-lg text-sm font-mono text-gray-100
-                            placeholder:text-gray-600
+                            text-sm text-gray-200
                             focus:outline-none focus:border-red-600
                             disabled:opacity-50
-                            tabular-nums
                           "
-                        />
+                        >
+                          {MEDIOS_PAGO.map(({ tipo, label }) => (
+                            <option key={tipo} value={tipo}>
+                              {label}
+                            </option>
+                          ))}
+                        </select>
+
+                        {/* HU-151: Moneda (solo con cotización del día cargada) */}
+                        {monedasCotizadas.length > 0 && MEDIOS_MONEDA_EXTRANJERA.includes(pago.medio) && (
+                          <select
+                            value={pago.moneda ?? 'ARS'}
+                            onChange={(e) => handleMonedaChange(pago.id, e.target.value as Moneda)}
+                            disabled={isPending}
+                            className="
+                              h-10 px-2 rounded-lg
+                              bg-neutral-800 border border-neutral-700
+                              text-sm font-mono text-gray-200
+                              focus:outline-none focus:border-red-600
+                              disabled:opacity-50
+                            "
+                          >
+                            {(['ARS', ...monedasCotizadas] as Moneda[]).map((moneda) => (
+                              <option key={moneda} value={moneda}>
+                                {moneda}
+                              </option>
+                            ))}
+                          </select>
+                        )}
+
+                        {/* Input monto */}
+                        <div className="relative flex-1">
+                          <span className="absolute left-3 top-1/2 -translate-y-1/2 text-gray-500 text-sm">
+                            {SIMBOLO_MONEDA[pago.moneda ?? 'ARS']}
+                          </span>
+                          <input
+                            type="number"
+                            min={0}
+                            step={0.01}
+                            value={pago.monto}
+                            onChange={(e) =>
+                              handleMontoChange(pago.id, e.target.value)
+                            }
+                            placeholder="0.00"
+                            disabled={isPending}
+                            className={`
+                              w-full h-10 ${pago.moneda ? 'pl-11' : 'pl-7'} pr-3
+                              bg-neutral-800 border border-neutral-700
+                              rounded-lg text-sm font-mono text-gray-100
+                              placeholder:text-gray-600
+                              focus:outline-none focus:border-red-600
+                              disabled:opacity-50
+                              tabular-nums
+                            `}
+                          />
+                        </div>
+
+                        {/* HU-143: Recargo del medio */}
+                        {porcentajeRecargo(pago.medio) > 0 && (
+                          <span className="shrink-0 text-[11px] font-mono text-amber-400 tabular-nums">
+                            +{porcentajeRecargo(pago.medio)}%
+                          </span>
+                        )}
+
+                        {/* Eliminar (solo si hay más de 1) */}
+                        {pagos.length > 1 && (
+                          <button
+                            type="button"
+                            onClick={() => handleEliminarPago(pago.id)}
+                            disabled={isPending}
+                            className="
+                              w-9 h-9 rounded-lg shrink-0
+                              flex items-center justify-center
+                              text-gray-600 hover:text-red-400 hover:bg-red-950/30
+                              transition-colors disabled:opacity-50
+                            "
+                            aria-label={`Eliminar pago ${idx + 1}`}
+                          >
+                            <Trash2 size={14} />
+                          </button>
+                        )}
                       </div>
 
-                      {/* Eliminar (solo si hay más de 1) */}
-                      {pagos.length > 1 && (
-                        <button
-                          type="button"
-                          onClick={() => handleEliminarPago(pago.id)}
+                      {/* HU-151: Equivalente en pesos al tipo de cambio del día */}
+                      {pago.moneda && (
+                        <p className="px-1 text-[11px] font-mono text-gray-500 tabular-nums">
+                          = $ {montoEnPesos(pago).toLocaleString('es-AR', { minimumFractionDigits: 2 })}
+                          {' '}· {pago.moneda} a $ {(tiposCambio?.cotizaciones[pago.moneda] ?? 0).toLocaleString('es-AR')}
+                        </p>
+                      )}
+
+                      {/* HU-144: Código de la tarjeta de regalo */}
+                      {pago.medio === 'TARJETA_REGALO' && (
+                        <CodigoTarjetaRegaloInput
+                          codigo={pago.codigoTarjetaRegalo ?? ''}
+                          onChange={(codigo) => handleCodigoTarjetaChange(pago.id, codigo)}
+                          monto={parseFloat(pago.monto) || 0}
                           disabled={isPending}
-                          className="
-                            w-9 h-9 rounded-lg shrink-0
-                            flex items-center justify-center
-                            text-gray-600 hover:text-red-400 hover:bg-red-950/30
-                            transition-colors disabled:opacity-50
-                          "
-                          aria-label={`Eliminar pago ${idx + 1}`}
-                        >
-                          <Trash2 size={14} />
-                        </button>
+                        />
+                      )}
+
+                      {/* HU-156: Comprobante (y datos para transferir) */}
+                      {MEDIOS_CON_COMPROBANTE.includes(pago.medio) && (
+                        <ComprobantePagoInput
+                          medio={pago.medio}
+                          referencia={pago.referencia ?? ''}
+                          onChange={(referencia) => handleReferenciaChange(pago.id, referencia)}
+                          montoATransferir={
+                            pago.moneda
+                              ? parseFloat(pago.monto) || 0
+                              : montoEnPesos(pago) + Math.round(montoEnPesos(pago) * porcentajeRecargo(pago.medio)) / 100
+                          }
+                          disabled={isPending}
+                        />
                       )}
                     </div>
                   ))}
                 </div>
               </div>
 
+              {/* HU-104: Cliente de cuenta corriente / HU-105: cliente que suma puntos */}
+              {mostrarCliente && (
+                <div>
+                  <p className="text-xs font-semibold text-gray-500 uppercase tracking-widest mb-2">
+                    {requiereCliente ? 'Cliente (cuenta corriente)' : 'Identificar cliente (suma puntos)'}
+                  </p>
+                  <SelectorCliente
+                    value={clienteId}
+                    onChange={(id) => {
+                      setClienteId(id);
+                      setPuntosInput('');
+                    }}
+                    montoACargar={montoCuentaCorriente}
+                    placeholder={requiereCliente ? 'Elegir cliente…' : 'Sin identificar'}
+                    disabled={isPending}
+                  />
+
+                  {programaActivo && puntosCliente && (
+                    <div className="mt-2 flex items-center gap-2 bg-neutral-800/50 rounded-xl p-2 border border-neutral-700/50">
+                      <Star size={14} className="text-yellow-400 shrink-0" />
+                      <span className="flex-1 text-xs text-gray-400">
+                        {puntosCliente.disponible} puntos disponibles
+                        {puntosCliente.disponible > 0 && ` ($ ${puntosCliente.valorDisponible.toLocaleString('es-AR')})`}
+                      </span>
+                      {maxPuntos > 0 && (
+                        <>
+                          <input
+                            type="number"
+                            min={0}
+                            max={maxPuntos}
+                            step={1}
+                            value={puntosInput}
+                            onChange={(e) => setPuntosInput(e.target.value)}
+                            placeholder="Canjear"
+                            disabled={isPending}
+                            className="
+                              w-24 h-8 px-2
+                              bg-neutral-800 border border-neutral-700
+                              rounded-lg text-sm font-mono text-gray-100 text-right
+                              placeholder:text-gray-600
+                              focus:outline-none focus:border-red-600
+                              disabled:opacity-50
+                              tabular-nums
+                            "
+                          />
+                          <button
+                            type="button"
+                            onClick={() => setPuntosInput(String(maxPuntos))}
+                            disabled={isPending}
+                            className="text-xs font-semibold text-yellow-400 hover:text-yellow-300 disabled:opacity-50"
+                          >
+                            Máx.
+                          </button>
+                        </>
+                      )}
+                    </div>
+                  )}
+
+                  {!puntosValidos && (
+                    <p className="mt-1 text-xs text-red-400">
+                      Se pueden canjear hasta {maxPuntos} puntos en este pedido.
+                    </p>
+                  )}
+                </div>
+              )}
+
+              {/* HU-132: Empleado al que se imputa el consumo a cuenta */}
+              {esConsumoInterno && (
+                <div className="flex items-center gap-2">
+                  <UserRound size={14} className="text-orange-400 shrink-0" />
+                  <span className="flex-1 text-xs font-semibold text-gray-500 uppercase tracking-widest">
+                    Consumo de
+                  </span>
+                  <select
+                    value={empleadoConsumoId}
+                    onChange={(e) => setEmpleadoConsumoId(e.target.value)}
+                    disabled={isPending}
+                    className="
+                      w-44 h-9 px-2
+                      bg-neutral-800 border border-neutral-700
+                      rounded-lg text-sm text-gray-100
+                      focus:outline-none focus:border-red-600
+                      disabled:opacity-50
+                    "
+                  >
+                    <option value="">Sin imputar</option>
+                    {empleadosActivos.map((m) => (
+                      <option key={m.id} value={m.id}>
+                        {m.nombre}
+                      </option>
+                    ))}
+                  </select>
+                </div>
+              )}
+
+              {/* HU-111: Propina para el reporte por mozo */}
+              <div className="flex items-center gap-2">
+                <HandCoins size={14} className="text-gray-500 shrink-0" />
+                <span className="flex-1 text-xs font-semibold text-gray-500 uppercase tracking-widest">
+                  Propina
+                </span>
+                <div className="relative w-32">
+                  <span className="absolute left-3 top-1/2 -translate-y-1/2 text-gray-500 text-sm">
+                    $
+                  </span>
+                  <input
+                    type="number"
+                    min={0}
+                    step={0.01}
+                    value={propinaInput}
+                    onChange={(e) => setPropinaInput(e.target.value)}
+                    placeholder="0.00"
+                    disabled={isPending}
+                    className="
+                      w-full h-9 pl-7 pr-3
+                      bg-neutral-800 border border-neutral-700
+                      rounded-lg text-sm font-mono text-gray-100 text-right
+                      placeholder:text-gray-600
+                      focus:outline-none focus:border-red-600
+                      disabled:opacity-50
+                      tabular-nums
+                    "
+                  />
+                </div>
+              </div>
+
+              {/* HU-115: Factura A/B/C al cerrar */}
+              <label className="flex items-center gap-2 cursor-pointer select-none">
+                <FileText size={14} className="text-gray-500 shrink-0" />
+                <span className="flex-1 text-xs font-semibold text-gray-500 uppercase tracking-widest">
+                  Emitir factura
+                </span>
+                <input
+                  type="checkbox"
+                  checked={facturar}
+                  onChange={(e) => setFacturar(e.target.checked)}
+                  disabled={isPending}
+                  className="w-4 h-4 accent-red-600"
+                />
+              </label>
+
               {/* Resumen de pagos */}
               <div className="bg-neutral-800/30 rounded-xl border border-neutral-700/50 px-4 py-3 space-y-2">
                 <div className="flex justify-between text-sm">
@@ -468,6 +926,33 @@ export default function CerrarMesaModal({
                   </span>
                 </div>
 
+                {totalRecargos > 0 && (
+                  <div className="flex justify-between text-sm">
+                    <span className="text-amber-400/80">Recargos por medio de pago</span>
+                    <span className="font-mono tabular-nums text-amber-400">
+                      + $ {totalRecargos.toLocaleString('es-AR', { minimumFractionDigits: 2 })}
+                    </span>
+                  </div>
+                )}
+
+                {ajusteRedondeo !== 0 && (
+                  <div className="flex justify-between text-sm">
+                    <span className="text-sky-400/80">Ajuste por redondeo (efectivo)</span>
+                    <span className="font-mono tabular-nums text-sky-400">
+                      {ajusteRedondeo > 0 ? '+' : '−'} $ {Math.abs(ajusteRedondeo).toLocaleString('es-AR', { minimumFractionDigits: 2 })}
+                    </span>
+                  </div>
+                )}
+
+                {(totalRecargos > 0 || ajusteRedondeo !== 0) && (
+                  <div className="flex justify-between text-sm">
+                    <span className="text-gray-300 font-semibold">Cobrar al cliente</span>
+                    <span className="font-mono tabular-nums text-gray-100 font-bold">
+                      $ {(Math.min(sumaPagos, total) + totalRecargos + ajusteRedondeo).toLocaleString('es-AR', { minimumFractionDigits: 2 })}
+                    </span>
+                  </div>
+                )}
+
                 {diferencia < 0 && (
                   <div className="flex justify-between text-sm">
                     <span className="text-yellow-400/80">Falta pagar</span>
@@ -477,11 +962,11 @@ export default function CerrarMesaModal({
                   </div>
                 )}
 
-                {hayVuelto && (
+                {hayVuelto && vueltoEfectivo > 0 && (
                   <div className="flex justify-between text-sm">
                     <span className="text-green-400/80">Vuelto (Efectivo)</span>
                     <span className="font-mono tabular-nums text-green-400 font-bold">
-                      $ {diferencia.toLocaleString('es-AR', { minimumFractionDigits: 2 })}
+                      $ {vueltoEfectivo.toLocaleString('es-AR', { minimumFractionDigits: 2 })}
                     </span>
                   </div>
                 )}
@@ -0,0 +1,187 @@
+import { useMemo, useState } from 'react';
+import { X, Boxes, Loader2, Check } from 'lucide-react';
+import type { ProductoResponse } from '../../catalogo/types';
+import { useProductos } from '../../catalogo/hooks/useProductos';
+
+// ─── Tipos ────────────────────────────────────────────────────────────────────
+
+interface ComboSelectorModalProps {
+  /** Producto combo tocado en la grilla (con componentesCombo definidos) */
+  combo: ProductoResponse;
+  /** Callback con el producto elegido para cada componente, en el orden del combo */
+  onConfirmar: (componentesComboIds: string[]) => void;
+  onCerrar: () => void;
+  /** Indica si la mutación está en curso */
+  enviando?: boolean;
+}
+
+// ─── Helpers ──────────────────────────────────────────────────────────────────
+
+function formatPrecio(monto: number): string {
+  return monto.toLocaleString('es-AR');
+}
+
+// ─── Componente Principal ─────────────────────────────────────────────────────
+
+/**
+ * Modal de armado de combo (HU-103).
+ *
+ * Muestra un bloque por componente del combo (ej: Principal, Acompañamiento,
+ * Bebida) con sus opciones como botones. Los componentes con una única opción
+ * se muestran ya resueltos. El precio es el del combo: las opciones no suman.
+ *
+ * Las opciones se resuelven contra el catálogo completo de productos activos,
+ * no contra la categoría filtrada en la grilla.
+ */
+export default function ComboSelectorModal({
+  combo,
+  onConfirmar,
+  onCerrar,
+  enviando = false,
+}: ComboSelectorModalProps) {
+  const { data: catalogo = [], isLoading } = useProductos(null, true);
+  const componentes = useMemo(() => combo.componentesCombo ?? [], [combo]);
+
+  const productosPorId = useMemo(
+    () => new Map(catalogo.map((p) => [p.id, p])),
+    [catalogo]
+  );
+
+  /** Elección por índice de componente; los fijos arrancan resueltos */
+  const [elecciones, setElecciones] = useState<(string | null)[]>(() =>
+    componentes.map((c) => (c.opcionesIds.length === 1 ? c.opcionesIds[0] : null))
+  );
+
+  const completo = elecciones.every((id) => id !== null);
+
+  const elegir = (indice: number, productoId: string) => {
+    setElecciones((prev) => prev.map((id, i) => (i === indice ? productoId : id)));
+  };
+
+  return (
+    <>
+      {/* ── Backdrop ── */}
+      <div
+        className="fixed inset-0 z-[80] bg-black/60 backdrop-blur-sm"
+        onClick={onCerrar}
+        aria-hidden="true"
+      />
+
+      {/* ── Modal ── */}
+      <div
+        className="fixed inset-0 z-[90] flex items-center justify-center p-4"
+        role="dialog"
+        aria-modal="true"
+        aria-label={`Armar ${combo.nombre}`}
+      >
+        <div
+          className="
+            w-full max-w-lg
+            bg-neutral-950 border border-neutral-800
+            rounded-2xl shadow-2xl shadow-black/60
+            flex flex-col
+            max-h-[80vh]
+            animate-modal-in
+          "
+        >
+          {/* ── Cabecera ── */}
+          <header className="flex items-center justify-between gap-4 px-5 pt-5 pb-4 border-b border-neutral-800 shrink-0">
+            <div className="flex items-center gap-3 min-w-0">
+              <div className="w-10 h-10 rounded-xl bg-red-950/40 flex items-center justify-center shrink-0">
+                <Boxes size={20} className="text-red-400" />
+              </div>
+              <div className="min-w-0">
+                <h2 className="text-lg font-bold text-gray-100 truncate">
+                  {combo.nombre}
+                </h2>
+                <p className="text-xs text-gray-500">
+                  Precio cerrado $ {formatPrecio(combo.precio)} — elegí una opción por componente
+                </p>
+              </div>
+            </div>
+
+            <button
+              type="button"
+              onClick={onCerrar}
+              className="
+                w-9 h-9 rounded-xl
+                flex items-center justify-center
+                bg-neutral-800 text-gray-500
+                hover:text-gray-300 hover:bg-neutral-700
+                transition-colors active:scale-[0.93]
+              "
+              aria-label="Cerrar"
+            >
+              <X size={18} />
+            </button>
+          </header>
+
+          {/* ── Componentes ── */}
+          <div className="flex-1 overflow-y-auto px-5 py-5 space-y-5">
+            {isLoading ? (
+              <div className="flex justify-center py-8">
+                <Loader2 size={22} className="animate-spin text-gray-500" />
+              </div>
+            ) : (
+              componentes.map((componente, indice) => (
+                <section key={componente.nombre}>
+                  <p className="text-[11px] font-bold uppercase tracking-wider text-gray-500 mb-2">
+                    {componente.nombre}
+                  </p>
+                  <div className="grid grid-cols-2 gap-2">
+                    {componente.opcionesIds.map((opcionId) => {
+                      const opcion = productosPorId.get(opcionId);
+                      const elegida = elecciones[indice] === opcionId;
+                      const sinStock = !!(
+                        opcion?.agotado ||
+                        (opcion?.controlaStock &&
+                          opcion.stockActual !== null &&
+                          opcion.stockActual <= 0)
+                      );
+
+                      return (
+                        <button
+                          key={opcionId}
+                          type="button"
+                          onClick={() => elegir(indice, opcionId)}
+                          disabled={!opcion || sinStock}
+                          className={[
+                            'flex items-center justify-between gap-2',
+                            'px-3 py-3 rounded-xl text-left text-sm font-semibold',
+                            'transition-all duration-150 active:scale-[0.97]',
+                            'disabled:opacity-40 disabled:cursor-not-allowed',
+                            elegida
+                              ? 'bg-red-950/40 border-2 border-red-600 text-gray-100'
+                              : 'bg-neutral-900 border-2 border-neutral-800 text-gray-300 hover:border-red-600/60',
+                          ].join(' ')}
+                        >
+                          <span className="truncate">
+                            {opcion?.nombre ?? 'No disponible'}
+                          </span>
+                          {elegida && <Check size={16} className="text-red-400 shrink-0" />}
+                        </button>
+                      );
+                    })}
+                  </div>
+                </section>
+              ))
+            )}
+          </div>
+
+          {/* ── Footer ── */}
+          <footer className="px-5 py-4 border-t border-neutral-800 shrink-0">
+            <button
+              type="button"
+              onClick={() => onConfirmar(elecciones as string[])}
+              disabled={!completo || enviando}
+              className="btn-primary w-full flex items-center justify-center gap-2 disabled:opacity-40"
+            >
+              {enviando && <Loader2 size={16} className="animate-spin" />}
+              Agregar combo
+            </button>
+          </footer>
+        </div>
+      </div>
+    </>
+  );
+}
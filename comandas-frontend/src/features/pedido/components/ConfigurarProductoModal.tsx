@@ -1,8 +1,9 @@
 import { useState, useCallback, useMemo } from 'react';
-import { X, Minus, Plus, MessageSquare, ChefHat, Loader2, Check } from 'lucide-react';
-import type { ProductoResponse } from '../../catalogo/types';
+import { X, Minus, Plus, MessageSquare, ChefHat, Loader2, Check, ShieldAlert, Ban } from 'lucide-react';
+import { ALERGENOS, ALERGENO_LABELS } from '../../catalogo/types';
+import type { Alergeno, ProductoResponse } from '../../catalogo/types';
 import type { CategoriaResponse } from '../../categorias/types';
-import { useExtras, useModificadores } from '../../catalogo/hooks/useProductos';
+import { useExtras, useModificadores, useReceta } from '../../catalogo/hooks/useProductos';
 
 // ─── Límites operativos ───────────────────────────────────────────────────────
 
@@ -40,6 +41,10 @@ export interface ConfigurarProductoPayload {
   extrasIds: string[];
   /** ID de la variante seleccionada explícitamente */
   varianteId?: string;
+  /** HU-147: Alergias del comensal para el que se carga el ítem */
+  advertencias?: Alergeno[];
+  /** HU-148: IDs de los insumos de la receta que el cliente pidió sacar */
+  ingredientesQuitados?: string[];
 }
 
 // ─── Helpers ──────────────────────────────────────────────────────────────────
@@ -221,6 +226,9 @@ function ModificadorCheckbox({
  * 1. Agregar observaciones libres (sin cebolla, aderezo aparte, etc.)
  * 2. Seleccionar extras disponibles (huevo, queso, disco de carne)
  * 3. Definir la cantidad del producto principal
+ * 4. HU-147: Marcar las alergias del comensal (la comanda y el KDS las resaltan)
+ * 5. HU-148: Sacar ingredientes de la receta ("sin cebolla"): salen en la
+ *    comanda y no se descuentan del stock de insumos
  *
  * REGLA CRÍTICA: El frontend NO calcula normalizaciones (ej: simple → doble).
  * Esa lógica vive en NormalizadorVariantesService del backend.
@@ -242,6 +250,30 @@ export default function ConfigurarProductoModal({
   const [observaciones, setObservaciones] = useState('');
   /** Map<productoId, cantidad> para extras seleccionados */
   const [extrasSeleccionados, setExtrasSeleccionados] = useState<Record<string, number>>({});
+  /** HU-147: Alergias declaradas para el comensal */
+  const [advertencias, setAdvertencias] = useState<Alergeno[]>([]);
+
+  // HU-147: Alergias del comensal que el producto contiene según el catálogo
+  const alergenosEnConflicto = useMemo(
+    () => advertencias.filter((a) => producto.alergenos?.includes(a)),
+    [advertencias, producto.alergenos]
+  );
+
+  /** HU-148: Insumos de la receta que el cliente pidió sacar */
+  const [ingredientesQuitados, setIngredientesQuitados] = useState<string[]>([]);
+  const { data: receta } = useReceta(varianteId ?? producto.id);
+
+  const toggleIngrediente = useCallback((insumoId: string) => {
+    setIngredientesQuitados((prev) =>
+      prev.includes(insumoId) ? prev.filter((id) => id !== insumoId) : [...prev, insumoId]
+    );
+  }, []);
+
+  const toggleAdvertencia = useCallback((alergeno: Alergeno) => {
+    setAdvertencias((prev) =>
+      prev.includes(alergeno) ? prev.filter((a) => a !== alergeno) : [...prev, alergeno]
+    );
+  }, []);
 
   // ── Resolver categoría de modificadores (si existe) ──
   // Si la categoría del producto tiene categoriaModificadoresId → modo "modificadores específicos"
@@ -325,9 +357,11 @@ export default function ConfigurarProductoModal({
       observaciones: observaciones.trim() || undefined,
       extrasIds: construirExtrasIds(),
       varianteId,
+      advertencias: advertencias.length > 0 ? advertencias : undefined,
+      ingredientesQuitados: ingredientesQuitados.length > 0 ? ingredientesQuitados : undefined,
     };
     onConfirmar(payload);
-  }, [producto.id, cantidadPrincipal, observaciones, construirExtrasIds, onConfirmar, varianteId]);
+  }, [producto.id, cantidadPrincipal, observaciones, construirExtrasIds, onConfirmar, varianteId, advertencias, ingredientesQuitados]);
 
   const hayExtrasSeleccionados = Object.values(extrasSeleccionados).some((q) => q > 0);
 
@@ -372,6 +406,11 @@ export default function ConfigurarProductoModal({
               <p className="text-xl font-bold text-red-500 font-mono tabular-nums">
                 $ {formatPrecio(producto.precio)}
               </p>
+              {producto.alergenos && producto.alergenos.length > 0 && (
+                <p className="mt-1 text-xs text-amber-400/80">
+                  Contiene: {producto.alergenos.map((a) => ALERGENO_LABELS[a]).join(', ')}
+                </p>
+              )}
             </div>
 
             <button
@@ -406,6 +445,7 @@ export default function ConfigurarProductoModal({
                 onChange={(e) => setObservaciones(e.target.value)}
                 placeholder="Ej: Sin cebolla, aderezo aparte..."
                 rows={3}
+                maxLength={255}
                 className="
                   w-full px-4 py-3 rounded-xl
                   bg-neutral-800 border border-neutral-700
@@ -417,6 +457,75 @@ export default function ConfigurarProductoModal({
               />
             </section>
 
+            {/* ── HU-148: Ingredientes de la receta que se pueden sacar ── */}
+            {receta && receta.ingredientes.length > 0 && (
+              <section>
+                <div className="flex items-center gap-2 mb-2.5">
+                  <Ban size={16} className="text-gray-500" />
+                  <h3 className="text-sm font-semibold text-gray-400 uppercase tracking-wider">
+                    Sacar ingredientes
+                  </h3>
+                </div>
+                <div className="flex flex-wrap gap-2">
+                  {receta.ingredientes.map((ingrediente) => {
+                    const quitado = ingredientesQuitados.includes(ingrediente.insumoId);
+                    return (
+                      <button
+                        key={ingrediente.insumoId}
+                        type="button"
+                        onClick={() => toggleIngrediente(ingrediente.insumoId)}
+                        className={`h-9 px-3 rounded-lg text-xs font-medium border transition-colors active:scale-[0.97] ${
+                          quitado
+                            ? 'bg-red-600/20 border-red-600 text-red-300'
+                            : 'bg-neutral-800 border-neutral-700 text-gray-400 hover:border-neutral-600'
+                        }`}
+                      >
+                        {quitado ? `Sin ${ingrediente.nombreInsumo.toLowerCase()}` : ingrediente.nombreInsumo}
+                      </button>
+                    );
+                  })}
+                </div>
+              </section>
+            )}
+
+            {/* ── HU-147: Alergias del comensal ── */}
+            <section>
+              <div className="flex items-center gap-2 mb-2.5">
+                <ShieldAlert size={16} className="text-gray-500" />
+                <h3 className="text-sm font-semibold text-gray-400 uppercase tracking-wider">
+                  Alergias del comensal
+                </h3>
+              </div>
+              <div className="flex flex-wrap gap-2">
+                {ALERGENOS.map((alergeno) => {
+                  const activo = advertencias.includes(alergeno);
+                  return (
+                    <button
+                      key={alergeno}
+                      type="button"
+                      onClick={() => toggleAdvertencia(alergeno)}
+                      className={`h-9 px-3 rounded-lg text-xs font-medium border transition-colors active:scale-[0.97] ${
+                        activo
+                          ? 'bg-amber-500/20 border-amber-500 text-amber-300'
+                          : 'bg-neutral-800 border-neutral-700 text-gray-400 hover:border-neutral-600'
+                      }`}
+                    >
+                      {ALERGENO_LABELS[alergeno]}
+                    </button>
+                  );
+                })}
+              </div>
+              {alergenosEnConflicto.length > 0 && (
+                <p className="mt-2.5 flex items-start gap-2 rounded-lg border border-red-600/40 bg-red-600/10 px-3 py-2 text-xs text-red-300">
+                  <ShieldAlert size={14} className="shrink-0 mt-0.5" />
+                  <span>
+                    {producto.nombre} contiene {alergenosEnConflicto.map((a) => ALERGENO_LABELS[a]).join(', ')}.
+                    Confirmá con cocina antes de cargarlo.
+                  </span>
+                </p>
+              )}
+            </section>
+
             {/* ── Sección 2: Modificadores específicos ó Extras genéricos ── */}
             {mostrarExtras && categoriaModificadoresId ? (
             /* ── Modo MODIFICADORES (checkboxes, ej: Salsas para Panchos) ── */
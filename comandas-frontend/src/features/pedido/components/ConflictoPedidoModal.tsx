@@ -0,0 +1,105 @@
+import { AlertTriangle, RefreshCw } from 'lucide-react';
+import type { DetallePedidoResponse } from '../types';
+
+interface ConflictoPedidoModalProps {
+  /** Pedido ya refrescado con los cambios de la otra terminal */
+  pedido: DetallePedidoResponse | null;
+  /** Qué quiso hacer el operador (ej: "Agregar 2x Milanesa") */
+  cambio: string;
+  onAplicarIgual: () => void;
+  onDescartar: () => void;
+}
+
+/**
+ * Conflicto de edición concurrente — HU-165
+ *
+ * Otra terminal modificó la mesa entre que esta cargó el pedido y mandó el
+ * cambio, y el backend lo rechazó sin aplicar nada. Se muestra el ticket
+ * actualizado para que el operador decida si su cambio sigue teniendo sentido
+ * (aplicarlo sobre lo nuevo) o si ya lo hizo el otro (descartarlo), así no se
+ * duplican ni se pierden ítems sin que nadie se entere.
+ */
+export default function ConflictoPedidoModal({
+  pedido,
+  cambio,
+  onAplicarIgual,
+  onDescartar,
+}: ConflictoPedidoModalProps) {
+  return (
+    <>
+      {/* Backdrop */}
+      <div className="fixed inset-0 z-[80] bg-black/60 animate-backdrop-in" aria-hidden="true" />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[90] flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="
+            pointer-events-auto
+            w-full max-w-md
+            bg-neutral-900 rounded-2xl
+            border border-amber-600/50
+            shadow-2xl shadow-black/60
+            animate-modal-in
+          "
+        >
+          {/* ── Header ── */}
+          <div className="flex items-center gap-2 px-5 py-4 border-b border-neutral-800">
+            <AlertTriangle size={18} className="text-amber-400" />
+            <h2 className="text-lg font-bold text-gray-100">La mesa fue modificada desde otra terminal</h2>
+          </div>
+
+          <div className="px-5 py-4 space-y-4">
+            <p className="text-sm text-gray-300">
+              Tu cambio no se aplicó: <span className="font-semibold text-amber-200">{cambio}</span>.
+              Revisá cómo quedó el pedido antes de seguir.
+            </p>
+
+            {/* Ticket actualizado */}
+            <div className="bg-neutral-800/50 border border-neutral-700/50 rounded-xl px-4 py-3">
+              <p className="text-xs text-gray-500 uppercase tracking-widest mb-2">Pedido actual</p>
+              {!pedido ? (
+                <p className="flex items-center gap-2 text-sm text-gray-400">
+                  <RefreshCw size={14} className="animate-spin" /> Actualizando…
+                </p>
+              ) : pedido.items.length === 0 ? (
+                <p className="text-sm text-gray-400">Sin ítems</p>
+              ) : (
+                <ul className="max-h-48 overflow-y-auto space-y-1 text-sm text-gray-200">
+                  {pedido.items.map((item) => (
+                    <li key={item.id} className="flex justify-between gap-3">
+                      <span className="truncate">
+                        {item.cantidad}x {item.nombreProducto}
+                      </span>
+                      <span className="font-mono text-gray-400">
+                        $ {item.precioFinal.toLocaleString('es-AR', { minimumFractionDigits: 2 })}
+                      </span>
+                    </li>
+                  ))}
+                </ul>
+              )}
+            </div>
+          </div>
+
+          {/* ── Acciones ── */}
+          <div className="flex gap-3 px-5 py-4 border-t border-neutral-800">
+            <button
+              type="button"
+              onClick={onDescartar}
+              className="flex-1 h-11 rounded-xl text-sm text-gray-300 bg-neutral-800 border border-neutral-700 hover:border-neutral-600"
+            >
+              Descartar mi cambio
+            </button>
+            <button
+              type="button"
+              onClick={onAplicarIgual}
+              disabled={!pedido}
+              className="flex-1 h-11 rounded-xl text-sm font-bold text-white bg-amber-600 hover:bg-amber-500 disabled:bg-neutral-700 disabled:text-gray-500"
+            >
+              Aplicar mi cambio igual
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
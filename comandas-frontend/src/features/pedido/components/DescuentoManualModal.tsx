@@ -1,16 +1,20 @@
 import { useState, useCallback, useMemo } from 'react';
-import { X, Percent, Tag, ShoppingBag, DollarSign } from 'lucide-react';
+import { X, Percent, Tag, ShoppingBag, DollarSign, KeyRound } from 'lucide-react';
 import type { DetallePedidoResponse, ItemDetalle, TipoDescuentoManual } from '../types';
 import {
   useAplicarDescuentoGlobal,
   useAplicarDescuentoPorItem,
 } from '../hooks/usePedido';
 import useToast from '../../../hooks/useToast';
+import { useMozos, puedeAutorizar } from '../../mozos';
 
 // ─── Tipos locales ────────────────────────────────────────────────────────────
 
 type AmbitoDescuento = 'TOTAL' | 'ITEM';
 
+/** HU-122: Hasta este porcentaje el mozo descuenta sin autorización */
+const TOPE_PORCENTAJE_MOZO = 10;
+
 interface DescuentoManualModalProps {
   pedido: DetallePedidoResponse;
   onClose: () => void;
@@ -31,6 +35,10 @@ interface DescuentoManualModalProps {
  *
  * El backend recalcula montos dinámicamente.
  * El descuento no modifica precios base ni afecta la vista de cocina.
+ *
+ * HU-122: El motivo es obligatorio. Si el descuento supera el 10% de lo
+ * aplicable (un monto fijo se compara contra ese mismo 10%), lo tiene que
+ * autorizar un encargado con su PIN.
  */
 export default function DescuentoManualModal({
   pedido,
@@ -44,6 +52,11 @@ export default function DescuentoManualModal({
   const [valor, setValor] = useState<string>('');
   const [razon, setRazon] = useState('');
   const [itemSeleccionadoId, setItemSeleccionadoId] = useState<string | null>(null);
+  const [autorizanteId, setAutorizanteId] = useState('');
+  const [pin, setPin] = useState('');
+
+  const { data: mozos = [] } = useMozos();
+  const encargados = mozos.filter(puedeAutorizar);
 
   // ── Mutations ──
   const descuentoGlobal = useAplicarDescuentoGlobal();
@@ -74,17 +87,31 @@ export default function DescuentoManualModal({
 
   const excedeMonto = tipoDescuento === 'MONTO_FIJO' && valorNum > totalAplicable && valorNum > 0;
 
+  // ── HU-122: Tope del mozo ──
+  const porcentajeEfectivo =
+    tipoDescuento === 'PORCENTAJE'
+      ? valorNum
+      : totalAplicable > 0
+        ? (valorNum / totalAplicable) * 100
+        : 0;
+  const requiereEncargado = porcentajeEfectivo > TOPE_PORCENTAJE_MOZO;
+  const autorizacionValida = !requiereEncargado || (autorizanteId !== '' && /^\d{4,6}$/.test(pin));
+  const puedeAplicar = esValido && razon.trim().length > 0 && autorizacionValida;
+
   // ── Handler de aplicación ──
   const handleAplicar = useCallback(() => {
-    if (!esValido) return;
+    if (!puedeAplicar) return;
 
     const payload = {
       pedidoId: pedido.pedidoId,
+      // HU-165: si otra terminal cambió el pedido, el descuento se rechaza
+      version: pedido.version,
       tipoDescuento,
       valor: valorNum,
-      razon: razon.trim() || undefined,
+      razon: razon.trim(),
       // MVP: UUID fijo para el único operador del local (sin autenticación real)
       usuarioId: '00000000-0000-0000-0000-000000000001',
+      ...(requiereEncargado && { autorizanteId, pin }),
     };
 
     const etiqueta =
@@ -104,6 +131,7 @@ export default function DescuentoManualModal({
       onError: (error: any) => {
         const msg =
           error?.response?.data?.message || 'Error al aplicar descuento';
+        setPin('');
         toast.error(msg);
       },
     };
@@ -117,12 +145,16 @@ export default function DescuentoManualModal({
       );
     }
   }, [
-    esValido,
+    puedeAplicar,
     ambito,
     pedido.pedidoId,
+    pedido.version,
     tipoDescuento,
     valorNum,
     razon,
+    requiereEncargado,
+    autorizanteId,
+    pin,
     itemSeleccionadoId,
     descuentoGlobal,
     descuentoPorItem,
@@ -350,13 +382,14 @@ export default function DescuentoManualModal({
                 htmlFor="razon"
                 className="text-xs font-semibold text-gray-500 uppercase tracking-widest"
               >
-                Motivo{' '}
+                Motivo <span className="text-red-400">*</span>
               </label>
               <input
                 id="razon"
                 type="text"
                 value={razon}
                 onChange={(e) => setRazon(e.target.value)}
+                maxLength={255}
                 placeholder="Ej: Cliente frecuente, compensación..."
                 className="
                   w-full h-10 px-4
@@ -369,6 +402,71 @@ export default function DescuentoManualModal({
               />
             </div>
 
+            {/* HU-122: Autorización de encargado por encima del tope del mozo */}
+            {requiereEncargado && esValido && (
+              <div className="space-y-1.5">
+                <label
+                  htmlFor="autorizante"
+                  className="text-xs font-semibold text-gray-500 uppercase tracking-widest"
+                >
+                  Autoriza encargado
+                </label>
+                <p className="text-xs text-amber-400/90">
+                  Supera el {TOPE_PORCENTAJE_MOZO}% que puede aplicar un mozo.
+                </p>
+                {encargados.length === 0 ? (
+                  <p className="text-xs text-gray-500">
+                    No hay encargados con PIN configurado.
+                  </p>
+                ) : (
+                  <div className="flex gap-2">
+                    <select
+                      id="autorizante"
+                      value={autorizanteId}
+                      onChange={(e) => setAutorizanteId(e.target.value)}
+                      className="
+                        flex-1 h-10 px-3
+                        bg-neutral-800 border border-neutral-700
+                        rounded-xl text-sm text-gray-200
+                        focus:outline-none focus:border-red-600 focus:ring-1 focus:ring-red-600/30
+                        transition-colors
+                      "
+                    >
+                      <option value="">Encargado...</option>
+                      {encargados.map((m) => (
+                        <option key={m.id} value={m.id}>
+                          {m.nombre}
+                        </option>
+                      ))}
+                    </select>
+                    <div className="relative w-28">
+                      <KeyRound
+                        size={14}
+                        className="absolute left-3 top-1/2 -translate-y-1/2 text-gray-500"
+                      />
+                      <input
+                        type="password"
+                        inputMode="numeric"
+                        maxLength={6}
+                        value={pin}
+                        onChange={(e) => setPin(e.target.value.replace(/\D/g, ''))}
+                        placeholder="PIN"
+                        aria-label="PIN del encargado"
+                        className="
+                          w-full h-10 pl-9 pr-3
+                          bg-neutral-800 border border-neutral-700
+                          rounded-xl text-sm font-mono text-gray-200
+                          placeholder:text-gray-600
+                          focus:outline-none focus:border-red-600 focus:ring-1 focus:ring-red-600/30
+                          transition-colors
+                        "
+                      />
+                    </div>
+                  </div>
+                )}
+              </div>
+            )}
+
             {/* Preview del descuento */}
             {esValido && (
               <div className="bg-neutral-800/50 border border-neutral-700/50 rounded-xl px-4 py-3">
@@ -426,7 +524,7 @@ export default function DescuentoManualModal({
             <button
               type="button"
               onClick={handleAplicar}
-              disabled={!esValido || isPending}
+              disabled={!puedeAplicar || isPending}
               className="
                 flex-1 h-11 rounded-xl
                 text-sm font-bold
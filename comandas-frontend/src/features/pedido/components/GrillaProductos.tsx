@@ -1,6 +1,9 @@
 import { useMemo, useRef, useEffect } from 'react';
-import { Plus, Search, X, PackageOpen, Sparkles, Layers } from 'lucide-react';
+import { Plus, Search, X, PackageOpen, Sparkles, Layers, Ban, RotateCcw } from 'lucide-react';
 import type { ProductoResponse } from '../../catalogo/types';
+import { useCambiarDisponibilidad } from '../../catalogo/hooks/useProductos';
+import useToast from '../../../hooks/useToast';
+import { nombreDeGrupo } from '../utils/productoUtils';
 
 interface GrillaProductosProps {
   productos: ProductoResponse[];
@@ -59,6 +62,8 @@ function ProductoCard({
   cantidadVariantes,
   precioMinimo,
   tienePromoEnGrupo,
+  onCambiarAgotado,
+  cambiandoAgotado,
 }: {
   producto: ProductoResponse;
   onAgregar: () => void;
@@ -66,115 +71,145 @@ function ProductoCard({
   precioMinimo: number;
   /** true si alguna variante del grupo (o el propio producto) tiene promos */
   tienePromoEnGrupo: boolean;
+  /** HU-125: alterna la marca 86 del producto */
+  onCambiarAgotado: () => void;
+  cambiandoAgotado: boolean;
 }) {
+  const esGrupo = cantidadVariantes > 1;
+
+  // HU-125: los grupos se marcan por variante desde el catálogo
+  const agotado = !esGrupo && producto.agotado;
   const sinStock: boolean =
-    !!(producto.controlaStock && producto.stockActual !== null && producto.stockActual <= 0);
+    agotado || !!(producto.controlaStock && producto.stockActual !== null && producto.stockActual <= 0);
 
   const tienePromos = tienePromoEnGrupo;
-  const esGrupo = cantidadVariantes > 1;
 
   return (
-    <button
-      type="button"
-      onClick={onAgregar}
-      disabled={sinStock}
-      className={[
-        'group relative flex flex-col justify-between',
-        'w-full rounded-xl p-3',
-        'text-left transition-all duration-150',
-        'active:scale-[0.97] focus:outline-none focus-visible:ring-2 focus-visible:ring-red-500',
-        sinStock
-          ? 'bg-neutral-900/50 border-2 border-neutral-800 opacity-50 cursor-not-allowed'
-          : 'bg-neutral-900 border-2 border-neutral-800 hover:border-neutral-600 hover:bg-neutral-800/80 cursor-pointer',
-      ].join(' ')}
-      aria-label={`Agregar ${producto.nombre} — $${producto.precio}`}
-    >
-      {/* Badge de promoción activa */}
-      {tienePromos && (
-        <div className="absolute -top-1.5 -right-1.5 z-10 group/promo">
-          <span className="flex items-center justify-center w-6 h-6 rounded-full bg-emerald-500 shadow-lg shadow-emerald-500/30 ring-2 ring-neutral-900">
-            <Sparkles size={12} className="text-white" />
-          </span>
-          {/* Tooltip on hover */}
-          <div className="
-            invisible group-hover/promo:visible
-            absolute right-0 top-full mt-1.5
-            bg-neutral-800 border border-neutral-700 rounded-lg
-            px-3 py-2 shadow-xl shadow-black/40
-            min-w-[180px] max-w-[240px] z-50
-            pointer-events-none
-          ">
-            <p className="text-[11px] font-bold text-emerald-400 uppercase tracking-wider mb-1">
-              Promo activa
-            </p>
-            {producto.promocionesActivas.map((promo, i) => (
-              <div key={i} className="mb-1.5 last:mb-0">
-                <p className="text-sm text-gray-200 font-medium leading-snug">
-                  {promo.nombre}
-                </p>
-                {promo.descripcion && (
-                  <p className="text-xs text-gray-400 leading-snug mt-0.5">
-                    {promo.descripcion}
+    <div className="relative">
+      <button
+        type="button"
+        onClick={onAgregar}
+        disabled={sinStock}
+        className={[
+          'group relative flex flex-col justify-between',
+          'w-full rounded-xl p-3',
+          'text-left transition-all duration-150',
+          'active:scale-[0.97] focus:outline-none focus-visible:ring-2 focus-visible:ring-red-500',
+          sinStock
+            ? 'bg-neutral-900/50 border-2 border-neutral-800 opacity-50 cursor-not-allowed'
+            : 'bg-neutral-900 border-2 border-neutral-800 hover:border-neutral-600 hover:bg-neutral-800/80 cursor-pointer',
+        ].join(' ')}
+        aria-label={`Agregar ${producto.nombre} — $${producto.precio}`}
+      >
+        {/* Badge de promoción activa */}
+        {tienePromos && (
+          <div className="absolute -top-1.5 -right-1.5 z-10 group/promo">
+            <span className="flex items-center justify-center w-6 h-6 rounded-full bg-emerald-500 shadow-lg shadow-emerald-500/30 ring-2 ring-neutral-900">
+              <Sparkles size={12} className="text-white" />
+            </span>
+            {/* Tooltip on hover */}
+            <div className="
+              invisible group-hover/promo:visible
+              absolute right-0 top-full mt-1.5
+              bg-neutral-800 border border-neutral-700 rounded-lg
+              px-3 py-2 shadow-xl shadow-black/40
+              min-w-[180px] max-w-[240px] z-50
+              pointer-events-none
+            ">
+              <p className="text-[11px] font-bold text-emerald-400 uppercase tracking-wider mb-1">
+                Promo activa
+              </p>
+              {producto.promocionesActivas.map((promo, i) => (
+                <div key={i} className="mb-1.5 last:mb-0">
+                  <p className="text-sm text-gray-200 font-medium leading-snug">
+                    {promo.nombre}
                   </p>
-                )}
-              </div>
-            ))}
-            <div className="absolute -top-1 right-3 w-2 h-2 bg-neutral-800 border-l border-t border-neutral-700 rotate-45" />
+                  {promo.descripcion && (
+                    <p className="text-xs text-gray-400 leading-snug mt-0.5">
+                      {promo.descripcion}
+                    </p>
+                  )}
+                </div>
+              ))}
+              <div className="absolute -top-1 right-3 w-2 h-2 bg-neutral-800 border-l border-t border-neutral-700 rotate-45" />
+            </div>
           </div>
+        )}
+
+        {/* Badge variantes + badge "+" */}
+        <div className="flex items-start justify-between mb-2">
+          {/* Badge de variantes (reemplaza el dot de color) */}
+          {esGrupo ? (
+            <span className="flex items-center gap-1 px-1.5 py-0.5 rounded-md bg-neutral-800 border border-neutral-700">
+              <Layers size={10} className="text-gray-500" />
+              <span className="text-[10px] font-semibold text-gray-500">{cantidadVariantes}</span>
+            </span>
+          ) : (
+            <span
+              className="w-3 h-3 rounded-full shrink-0 mt-0.5 border border-white/10"
+              style={{ backgroundColor: producto.colorHex || '#FFFFFF' }}
+            />
+          )}
+          <span
+            className={[
+              'w-7 h-7 rounded-lg flex items-center justify-center',
+              'transition-colors duration-150',
+              sinStock
+                ? 'bg-neutral-800 text-neutral-600'
+                : 'bg-neutral-800 text-gray-500 group-hover:bg-red-600 group-hover:text-white',
+            ].join(' ')}
+          >
+            <Plus size={16} strokeWidth={2.5} />
+          </span>
         </div>
-      )}
 
-      {/* Badge variantes + badge "+" */}
-      <div className="flex items-start justify-between mb-2">
-        {/* Badge de variantes (reemplaza el dot de color) */}
-        {esGrupo ? (
-          <span className="flex items-center gap-1 px-1.5 py-0.5 rounded-md bg-neutral-800 border border-neutral-700">
-            <Layers size={10} className="text-gray-500" />
-            <span className="text-[10px] font-semibold text-gray-500">{cantidadVariantes}</span>
+        {/* Nombre */}
+        <p className="text-sm font-semibold text-gray-200 leading-tight line-clamp-2 mb-1">
+          {esGrupo ? nombreDeGrupo(producto) : producto.nombre}
+        </p>
+
+        {/* Precio — "desde $X" si es grupo con precios distintos */}
+        <p className="text-base font-bold text-gray-100 font-mono tabular-nums">
+          {esGrupo && precioMinimo < producto.precio ? (
+            <>
+              <span className="text-[10px] font-semibold text-gray-500 mr-1">desde</span>
+              $ {precioMinimo.toLocaleString('es-AR')}
+            </>
+          ) : (
+            <>$ {producto.precio.toLocaleString('es-AR')}</>
+          )}
+        </p>
+
+        {/* Badge sin stock */}
+        {sinStock && (
+          <span className="absolute bottom-2 right-2 text-[9px] font-bold uppercase text-red-400 bg-red-950/60 px-1.5 py-0.5 rounded">
+            {agotado ? '86 · Sin stock' : 'Sin stock'}
           </span>
-        ) : (
-          <span
-            className="w-3 h-3 rounded-full shrink-0 mt-0.5 border border-white/10"
-            style={{ backgroundColor: producto.colorHex || '#FFFFFF' }}
-          />
         )}
-        <span
+      </button>
+
+      {/* Switch 86: fuera del botón principal para seguir activo con la tarjeta deshabilitada */}
+      {!esGrupo && (
+        <button
+          type="button"
+          onClick={onCambiarAgotado}
+          disabled={cambiandoAgotado}
           className={[
-            'w-7 h-7 rounded-lg flex items-center justify-center',
-            'transition-colors duration-150',
-            sinStock
-              ? 'bg-neutral-800 text-neutral-600'
-              : 'bg-neutral-800 text-gray-500 group-hover:bg-red-600 group-hover:text-white',
+            'absolute top-2 left-7 h-6 px-1.5 rounded-md',
+            'flex items-center gap-1 text-[10px] font-bold uppercase',
+            'transition-colors active:scale-95 disabled:opacity-40',
+            agotado
+              ? 'bg-emerald-900/60 text-emerald-300 hover:bg-emerald-800/70'
+              : 'bg-neutral-800/80 text-gray-500 hover:bg-red-900/60 hover:text-red-300',
           ].join(' ')}
+          title={agotado ? 'Volver a ofrecer' : 'Marcar sin stock (86)'}
+          aria-label={agotado ? `Volver a ofrecer ${producto.nombre}` : `Marcar ${producto.nombre} como 86`}
         >
-          <Plus size={16} strokeWidth={2.5} />
-        </span>
-      </div>
-
-      {/* Nombre */}
-      <p className="text-sm font-semibold text-gray-200 leading-tight line-clamp-2 mb-1">
-        {producto.nombre}
-      </p>
-
-      {/* Precio — "desde $X" si es grupo con precios distintos */}
-      <p className="text-base font-bold text-gray-100 font-mono tabular-nums">
-        {esGrupo && precioMinimo < producto.precio ? (
-          <>
-            <span className="text-[10px] font-semibold text-gray-500 mr-1">desde</span>
-            $ {precioMinimo.toLocaleString('es-AR')}
-          </>
-        ) : (
-          <>$ {producto.precio.toLocaleString('es-AR')}</>
-        )}
-      </p>
-
-      {/* Badge sin stock */}
-      {sinStock && (
-        <span className="absolute bottom-2 right-2 text-[9px] font-bold uppercase text-red-400 bg-red-950/60 px-1.5 py-0.5 rounded">
-          Sin stock
-        </span>
+          {agotado ? <RotateCcw size={10} /> : <Ban size={10} />}
+          86
+        </button>
       )}
-    </button>
+    </div>
   );
 }
 
@@ -200,6 +235,18 @@ export default function GrillaProductos({
   totalProductos,
 }: GrillaProductosProps) {
   const inputRef = useRef<HTMLInputElement>(null);
+  const toast = useToast();
+  const cambiarDisponibilidad = useCambiarDisponibilidad();
+
+  const handleCambiarAgotado = (producto: ProductoResponse) => {
+    cambiarDisponibilidad.mutate(
+      { id: producto.id, agotado: !producto.agotado },
+      {
+        onError: (err: any) =>
+          toast.error(err?.response?.data?.message || 'No se pudo cambiar la disponibilidad'),
+      },
+    );
+  };
 
   // Foco automático al abrir el modal
   useEffect(() => {
@@ -375,6 +422,11 @@ export default function GrillaProductos({
                 cantidadVariantes={elem.cantidadVariantes}
                 precioMinimo={elem.precioMinimo}
                 tienePromoEnGrupo={elem.tienePromoEnGrupo}
+                onCambiarAgotado={() => handleCambiarAgotado(elem.producto)}
+                cambiandoAgotado={
+                  cambiarDisponibilidad.isPending &&
+                  cambiarDisponibilidad.variables?.id === elem.producto.id
+                }
               />
             ))}
           </div>
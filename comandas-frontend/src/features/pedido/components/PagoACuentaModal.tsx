@@ -0,0 +1,279 @@
+import { useState } from 'react';
+import { X, HandCoins, DollarSign, CreditCard, ArrowRightLeft, QrCode, Trash2, Loader2 } from 'lucide-react';
+import type { DetallePedidoResponse } from '../types';
+import { MEDIOS_CON_COMPROBANTE, type MedioPago } from '../../salon/types';
+import { usePagosACuenta, useRegistrarPagoACuenta, useAnularPagoACuenta } from '../../salon/hooks/useMesas';
+import { useRecargosMedioPago } from '../../caja/hooks/useCaja';
+import ComprobantePagoInput from '../../caja/components/ComprobantePagoInput';
+import useToast from '../../../hooks/useToast';
+
+// ─── Configuración ────────────────────────────────────────────────────────────
+
+/** Cuenta corriente, consumo interno y gift card se cobran recién al cerrar */
+const MEDIOS_A_CUENTA: Array<{ tipo: MedioPago; icono: typeof DollarSign; label: string }> = [
+  { tipo: 'EFECTIVO', icono: DollarSign, label: 'Efectivo' },
+  { tipo: 'TARJETA', icono: CreditCard, label: 'Tarjeta' },
+  { tipo: 'TRANSFERENCIA', icono: ArrowRightLeft, label: 'Transfer.' },
+  { tipo: 'QR', icono: QrCode, label: 'QR' },
+];
+
+const ETIQUETA_MEDIO: Partial<Record<MedioPago, string>> = Object.fromEntries(
+  MEDIOS_A_CUENTA.map((m) => [m.tipo, m.label]),
+);
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+interface PagoACuentaModalProps {
+  mesaId: string;
+  pedido: DetallePedidoResponse;
+  onClose: () => void;
+}
+
+// ─── Componente ───────────────────────────────────────────────────────────────
+
+/**
+ * Modal de pago a cuenta — HU-160
+ *
+ * El comensal que se va antes paga lo suyo y la mesa sigue abierta. Cada pago
+ * baja el saldo pendiente; al cerrar la mesa solo se cobra lo que falta.
+ * Un pago cargado por error se anula desde la lista (mientras la mesa esté
+ * abierta). El cobro entra a la caja cuando se cierra la mesa.
+ */
+export default function PagoACuentaModal({ mesaId, pedido, onClose }: PagoACuentaModalProps) {
+  const toast = useToast();
+  const { data: pagosACuenta } = usePagosACuenta(mesaId, true);
+  const { data: recargos } = useRecargosMedioPago();
+  const registrar = useRegistrarPagoACuenta();
+  const anular = useAnularPagoACuenta();
+
+  const [medio, setMedio] = useState<MedioPago>('EFECTIVO');
+  const [monto, setMonto] = useState('');
+  const [referencia, setReferencia] = useState('');
+
+  const saldo = pagosACuenta?.saldoPendiente ?? pedido.saldoPendiente ?? pedido.totalParcial;
+  const pagos = pagosACuenta?.pagos ?? [];
+  const montoNum = parseFloat(monto) || 0;
+  const porcentajeRecargo = recargos?.porcentajes[medio] ?? 0;
+  const excedeSaldo = montoNum > saldo;
+  const puedeCobrar = montoNum > 0 && !excedeSaldo && !registrar.isPending;
+
+  const handleCobrar = () => {
+    if (!puedeCobrar) return;
+    registrar.mutate(
+      {
+        mesaId,
+        versionPedido: pedido.version,
+        pago: {
+          medio,
+          monto: montoNum,
+          referencia: MEDIOS_CON_COMPROBANTE.includes(medio) ? referencia.trim() || undefined : undefined,
+        },
+      },
+      {
+        onSuccess: (response) => {
+          toast.success(`Cobrados $ ${fmt(montoNum)} a cuenta. Saldo: $ ${fmt(response.saldoPendiente)}`);
+          setMonto('');
+          setReferencia('');
+        },
+        onError: (err: any) =>
+          toast.error(err?.response?.data?.message || 'No se pudo registrar el pago a cuenta'),
+      },
+    );
+  };
+
+  const handleAnular = (indice: number) => {
+    anular.mutate(
+      { mesaId, indice, versionPedido: pedido.version },
+      {
+        onSuccess: () => toast.info('Pago a cuenta anulado'),
+        onError: (err: any) =>
+          toast.error(err?.response?.data?.message || 'No se pudo anular el pago a cuenta'),
+      },
+    );
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div
+        className="fixed inset-0 z-[80] bg-black/60 animate-backdrop-in"
+        onClick={onClose}
+        aria-hidden="true"
+      />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[90] flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="
+            pointer-events-auto
+            w-full max-w-md
+            bg-neutral-900 rounded-2xl
+            border border-neutral-700
+            shadow-2xl shadow-black/60
+            animate-modal-in
+          "
+        >
+          {/* ── Header ── */}
+          <div className="flex items-center justify-between px-5 py-4 border-b border-neutral-800">
+            <div className="flex items-center gap-2">
+              <HandCoins size={18} className="text-emerald-400" />
+              <h2 className="text-lg font-bold text-gray-100">Pago a cuenta</h2>
+            </div>
+            <button
+              type="button"
+              onClick={onClose}
+              className="text-gray-500 hover:text-gray-300 transition-colors"
+            >
+              <X size={20} />
+            </button>
+          </div>
+
+          <div className="px-5 py-4 space-y-4">
+            {/* Saldo */}
+            <div className="bg-neutral-800/50 border border-neutral-700/50 rounded-xl px-4 py-3 flex items-center justify-between">
+              <span className="text-xs text-gray-500 uppercase tracking-widest">Saldo pendiente</span>
+              <span className={`text-xl font-bold font-mono ${saldo < 0 ? 'text-red-400' : 'text-gray-100'}`}>
+                $ {fmt(saldo)}
+              </span>
+            </div>
+            {saldo < 0 && (
+              <p className="text-xs text-red-400">
+                Lo pagado supera el total del pedido: anulá el pago que sobra antes de cerrar la mesa.
+              </p>
+            )}
+
+            {/* Pagos ya cobrados */}
+            {pagos.length > 0 && (
+              <ul className="divide-y divide-neutral-800 text-sm">
+                {pagos.map((p, indice) => (
+                  <li key={`${p.fecha}-${indice}`} className="flex items-center justify-between gap-2 py-2">
+                    <span className="text-gray-300">
+                      {ETIQUETA_MEDIO[p.medio] ?? p.medio}
+                      {p.referencia && <span className="ml-2 text-[11px] text-gray-500">#{p.referencia}</span>}
+                    </span>
+                    <span className="flex items-center gap-2">
+                      <span className="font-mono text-gray-200">$ {fmt(p.monto)}</span>
+                      <button
+                        type="button"
+                        onClick={() => handleAnular(indice)}
+                        disabled={anular.isPending}
+                        title="Anular pago"
+                        className="text-gray-600 hover:text-red-400 transition-colors disabled:opacity-40"
+                      >
+                        <Trash2 size={14} />
+                      </button>
+                    </span>
+                  </li>
+                ))}
+              </ul>
+            )}
+
+            {/* Nuevo pago */}
+            <div className="grid grid-cols-4 gap-2">
+              {MEDIOS_A_CUENTA.map(({ tipo, icono: Icon, label }) => (
+                <button
+                  key={tipo}
+                  type="button"
+                  onClick={() => setMedio(tipo)}
+                  className={[
+                    'h-14 rounded-xl flex flex-col items-center justify-center gap-1 text-xs transition-colors',
+                    medio === tipo
+                      ? 'bg-emerald-950/50 border border-emerald-600/60 text-emerald-300'
+                      : 'bg-neutral-800/60 border border-neutral-700/50 text-gray-400 hover:border-neutral-600',
+                  ].join(' ')}
+                >
+                  <Icon size={16} />
+                  {label}
+                </button>
+              ))}
+            </div>
+
+            <div className="space-y-1.5">
+              <div className="flex items-center gap-2">
+                <input
+                  type="number"
+                  min="0"
+                  step="0.01"
+                  value={monto}
+                  onChange={(e) => setMonto(e.target.value)}
+                  placeholder="Monto"
+                  className="
+                    flex-1 h-11 px-3
+                    bg-neutral-800 border border-neutral-700
+                    rounded-xl text-right font-mono text-gray-200
+                    placeholder:text-gray-600
+                    focus:outline-none focus:border-red-600 focus:ring-1 focus:ring-red-600/30
+                  "
+                />
+                {saldo > 0 && (
+                  <button
+                    type="button"
+                    onClick={() => setMonto(saldo.toFixed(2))}
+                    className="h-11 px-3 rounded-xl text-xs text-gray-400 bg-neutral-800 border border-neutral-700 hover:text-gray-200"
+                  >
+                    Todo el saldo
+                  </button>
+                )}
+              </div>
+              {excedeSaldo && (
+                <p className="text-xs text-red-400">Supera el saldo pendiente.</p>
+              )}
+              {porcentajeRecargo > 0 && montoNum > 0 && (
+                <p className="text-xs text-amber-400/90">
+                  +{porcentajeRecargo}% de recargo: se cobran $ {fmt(montoNum + Math.round(montoNum * porcentajeRecargo) / 100)}
+                </p>
+              )}
+            </div>
+
+            {MEDIOS_CON_COMPROBANTE.includes(medio) && (
+              <ComprobantePagoInput
+                medio={medio}
+                referencia={referencia}
+                onChange={setReferencia}
+                montoATransferir={montoNum + Math.round(montoNum * porcentajeRecargo) / 100}
+                disabled={registrar.isPending}
+              />
+            )}
+          </div>
+
+          {/* ── Footer ── */}
+          <div className="px-5 py-4 border-t border-neutral-800 flex gap-3">
+            <button
+              type="button"
+              onClick={onClose}
+              className="
+                flex-1 h-11 rounded-xl
+                text-sm font-semibold
+                bg-neutral-800 text-gray-400
+                border border-neutral-700
+                hover:border-neutral-600 hover:text-gray-300
+                transition-colors active:scale-[0.97]
+              "
+            >
+              Listo
+            </button>
+            <button
+              type="button"
+              onClick={handleCobrar}
+              disabled={!puedeCobrar}
+              className="
+                flex-1 h-11 rounded-xl
+                text-sm font-bold
+                bg-emerald-600 text-white
+                hover:bg-emerald-500
+                disabled:bg-neutral-700 disabled:text-gray-500 disabled:cursor-not-allowed
+                transition-colors active:scale-[0.97]
+                flex items-center justify-center gap-2
+              "
+            >
+              {registrar.isPending && <Loader2 size={16} className="animate-spin" />}
+              Cobrar a cuenta
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
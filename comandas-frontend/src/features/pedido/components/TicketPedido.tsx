@@ -12,8 +12,22 @@ import {
   Loader2,
   Receipt,
   Printer,
+  Flame,
+  Link2,
+  HandCoins,
+  AlertTriangle,
+  MapPin,
+  Bike,
+  ShieldAlert,
+  BookUser,
 } from 'lucide-react';
-import type { DetallePedidoResponse, ItemDetalle, ExtraDetalle } from '../types';
+import { useState } from 'react';
+import type { DetallePedidoResponse, ItemDetalle, ExtraDetalle, TiempoServicio } from '../types';
+import { TIEMPO_SERVICIO_LABELS, TIEMPOS_SERVICIO } from '../types';
+import { CANAL_VENTA_LABELS } from '../../salon/types';
+import { ALERGENO_LABELS } from '../../catalogo/types';
+import type { EsperaEstimada } from '../../cocina/types';
+import { useClientes } from '../../clientes/hooks/useClientes';
 
 // ─── Límites operativos ───────────────────────────────────────────────────────
 
@@ -52,6 +66,13 @@ function agruparExtras(extras: ExtraDetalle[]): ExtraAgrupado[] {
   return Array.from(mapa.values());
 }
 
+/** HU-110: Siguiente valor del chip: sin paso → Entrada → Principal → Postre → sin paso */
+function siguienteTiempo(actual: TiempoServicio | null): TiempoServicio | null {
+  if (actual === null) return TIEMPOS_SERVICIO[0];
+  const index = TIEMPOS_SERVICIO.indexOf(actual);
+  return index === TIEMPOS_SERVICIO.length - 1 ? null : TIEMPOS_SERVICIO[index + 1];
+}
+
 // ─── Skeleton ─────────────────────────────────────────────────────────────────
 
 function SkeletonBar({ className = '' }: { className?: string }) {
@@ -98,6 +119,12 @@ interface TicketItemProps {
   item: ItemDetalle;
   onModificarCantidad: (itemId: string, nuevaCantidad: number) => void;
   onEliminar: (itemId: string) => void;
+  /** HU-110: Cambiar el paso de servicio (entrada / principal / postre) */
+  onCambiarTiempo: (itemId: string, tiempo: TiempoServicio | null) => void;
+  /** HU-120: Guardar la observación libre del ítem (null = quitarla) */
+  onCambiarObservacion: (itemId: string, observacion: string | null) => void;
+  /** HU-137: Marcar o desmarcar el ítem como urgente */
+  onMarcarUrgente: (itemId: string, urgente: boolean) => void;
   /** Si el ítem ya fue enviado a cocina */
   enviadoACocina?: boolean;
   /** Si el pedido permite modificaciones (ABIERTO) */
@@ -114,10 +141,30 @@ interface TicketItemProps {
  * - Si hay promo: etiqueta verde con el nombre y ahorro
  * - Controles +/- para modificar cantidad
  * - Botón eliminar
+ * - HU-110: Chip de paso de servicio (se rota con un toque mientras no salió a cocina)
+ * - HU-120: Observación libre editable en línea mientras no salió a cocina
+ * - HU-137: Chip de urgencia mientras le queden unidades por enviar (quitarlo, siempre)
  */
-function TicketItem({ item, onModificarCantidad, onEliminar, enviadoACocina = false, pedidoModificable }: TicketItemProps) {
+function TicketItem({ item, onModificarCantidad, onEliminar, onCambiarTiempo, onCambiarObservacion, onMarcarUrgente, enviadoACocina = false, pedidoModificable }: TicketItemProps) {
   const hayDescuento = item.descuentoTotal > 0;
   const enMaximo = item.cantidad >= MAX_CANTIDAD_ITEM;
+  const puedeCambiarTiempo = pedidoModificable && !item.enviadoACocina;
+  const puedeMarcarUrgente = pedidoModificable && (item.urgente || item.esNuevo || item.enEspera);
+  const [editandoObservacion, setEditandoObservacion] = useState(false);
+  const [observacion, setObservacion] = useState('');
+
+  const abrirObservacion = () => {
+    setObservacion(item.observacion ?? '');
+    setEditandoObservacion(true);
+  };
+
+  const guardarObservacion = () => {
+    setEditandoObservacion(false);
+    const nueva = observacion.trim() || null;
+    if (nueva !== (item.observacion ?? null)) {
+      onCambiarObservacion(item.id, nueva);
+    }
+  };
 
   return (
     <div className="group py-3 border-b border-neutral-800/60 last:border-b-0">
@@ -138,7 +185,11 @@ function TicketItem({ item, onModificarCantidad, onEliminar, enviadoACocina = fa
               {item.nombreProducto}
             </p>
             {/* Badge de estado cocina */}
-            {enviadoACocina ? (
+            {item.enEspera ? (
+              <span className="shrink-0 text-[9px] font-bold uppercase tracking-wider px-1.5 py-0.5 rounded-full bg-sky-900/30 text-sky-400 border border-sky-700/40">
+                En espera
+              </span>
+            ) : enviadoACocina ? (
               <span className="shrink-0 text-[9px] font-bold uppercase tracking-wider px-1.5 py-0.5 rounded-full bg-neutral-800 text-gray-500 border border-neutral-700">
                 Enviado
               </span>
@@ -149,12 +200,112 @@ function TicketItem({ item, onModificarCantidad, onEliminar, enviadoACocina = fa
             )}
           </div>
 
-          {item.observacion && (
-            <p className="text-[11px] text-gray-500 mt-0.5 italic truncate">
+          <div className="flex items-center gap-1.5">
+            {/* HU-110: Paso de servicio */}
+            {(item.tiempoServicio || puedeCambiarTiempo) && (
+              <button
+                type="button"
+                onClick={() => onCambiarTiempo(item.id, siguienteTiempo(item.tiempoServicio))}
+                disabled={!puedeCambiarTiempo}
+                title={puedeCambiarTiempo ? 'Tocá para cambiar el paso' : 'Ya salió a cocina'}
+                className={[
+                  'mt-1 text-[10px] font-semibold px-1.5 py-0.5 rounded-md border transition-colors',
+                  item.tiempoServicio
+                    ? 'bg-neutral-800 text-gray-300 border-neutral-700'
+                    : 'border-dashed border-neutral-700 text-gray-600',
+                  puedeCambiarTiempo ? 'hover:border-neutral-500' : 'cursor-default',
+                ].join(' ')}
+              >
+                {item.tiempoServicio ? TIEMPO_SERVICIO_LABELS[item.tiempoServicio] : '+ Paso'}
+              </button>
+            )}
+
+            {/* HU-120: Alta de observación libre */}
+            {puedeCambiarTiempo && !item.observacion && !editandoObservacion && (
+              <button
+                type="button"
+                onClick={abrirObservacion}
+                className="mt-1 text-[10px] font-semibold px-1.5 py-0.5 rounded-md border border-dashed border-neutral-700 text-gray-600 hover:border-neutral-500 transition-colors"
+              >
+                + Nota
+              </button>
+            )}
+
+            {/* HU-137: Urgencia del ítem */}
+            {puedeMarcarUrgente && (
+              <button
+                type="button"
+                onClick={() => onMarcarUrgente(item.id, !item.urgente)}
+                title={item.urgente ? 'Tocá para quitar la urgencia' : 'Sale destacado en la comanda'}
+                className={[
+                  'mt-1 text-[10px] font-semibold px-1.5 py-0.5 rounded-md border transition-colors',
+                  item.urgente
+                    ? 'bg-red-900/40 text-red-300 border-red-700/50 hover:border-red-500'
+                    : 'border-dashed border-neutral-700 text-gray-600 hover:border-neutral-500',
+                ].join(' ')}
+              >
+                {item.urgente ? 'Urgente' : '+ Urgente'}
+              </button>
+            )}
+          </div>
+
+          {/* HU-120: Observación libre (se edita con un toque mientras no salió a cocina) */}
+          {editandoObservacion ? (
+            <input
+              type="text"
+              autoFocus
+              value={observacion}
+              maxLength={255}
+              onChange={(e) => setObservacion(e.target.value)}
+              onBlur={guardarObservacion}
+              onKeyDown={(e) => {
+                if (e.key === 'Enter') e.currentTarget.blur();
+                if (e.key === 'Escape') setEditandoObservacion(false);
+              }}
+              placeholder="Ej: bien cocida, sin sal..."
+              className="mt-1 w-full bg-neutral-800 border border-neutral-700 rounded-md px-2 py-1 text-[11px] text-gray-200 placeholder:text-gray-600 focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50"
+            />
+          ) : item.observacion && (
+            <button
+              type="button"
+              onClick={abrirObservacion}
+              disabled={!puedeCambiarTiempo}
+              title={puedeCambiarTiempo ? 'Tocá para editar la nota' : 'Ya salió a cocina'}
+              className={[
+                'block max-w-full text-left text-[11px] text-amber-300/80 mt-0.5 italic truncate',
+                puedeCambiarTiempo ? 'hover:text-amber-200' : 'cursor-default',
+              ].join(' ')}
+            >
               &ldquo;{item.observacion}&rdquo;
+            </button>
+          )}
+
+          {/* HU-148: Ingredientes quitados de la receta */}
+          {item.ingredientesQuitados && item.ingredientesQuitados.length > 0 && (
+            <p className="mt-0.5 text-[11px] font-semibold text-gray-400 truncate">
+              {item.ingredientesQuitados.map((nombre) => `Sin ${nombre.toLowerCase()}`).join(', ')}
+            </p>
+          )}
+
+          {/* HU-147: Alergias del comensal (salen resaltadas en comanda y KDS) */}
+          {item.advertencias && item.advertencias.length > 0 && (
+            <p className="mt-0.5 flex items-center gap-1 text-[11px] font-semibold text-amber-300">
+              <ShieldAlert size={12} className="shrink-0" />
+              Alergia: {item.advertencias.map((a) => ALERGENO_LABELS[a]).join(', ')}
             </p>
           )}
 
+          {/* HU-103: Componentes elegidos del combo */}
+          {item.componentesCombo && item.componentesCombo.length > 0 && (
+            <div className="mt-1 space-y-0.5 pl-2 border-l-2 border-neutral-800">
+              {item.componentesCombo.map((c) => (
+                <p key={c.componente} className="text-[11px] text-gray-500">
+                  <span className="text-gray-600">{c.componente}:</span> {c.nombreProducto}
+                </p>
+              ))}
+            </div>
+          )}
+
           {/* Extras / Agregados como sub-elementos */}
           {item.extras && item.extras.length > 0 && (
             <div className="mt-1 space-y-0.5 pl-2 border-l-2 border-neutral-800">
@@ -279,13 +430,32 @@ interface TicketPedidoProps {
   onVolver: () => void;
   onModificarCantidad: (itemId: string, nuevaCantidad: number) => void;
   onEliminarItem: (itemId: string) => void;
+  onCambiarTiempo: (itemId: string, tiempo: TiempoServicio | null) => void;
+  onCambiarObservacion: (itemId: string, observacion: string | null) => void;
+  /** HU-137: Urgencia del pedido completo y de cada ítem */
+  onMarcarUrgente: (urgente: boolean) => void;
+  onMarcarUrgenteItem: (itemId: string, urgente: boolean) => void;
+  /** HU-138: Espera de cocina a informar antes del primer envío (solo take away) */
+  esperaEstimada?: EsperaEstimada | null;
   onAplicarDescuento: () => void;
   onCerrarMesa: () => void;
   onControlMesa: () => void;
   onMandarCocina: () => void;
   onReimprimirComanda: () => void;
+  onMarchar: () => void;
+  /** HU-128: Cobro previo por link (solo delivery) */
+  onLinkPago: () => void;
+  /** HU-160: Cobrar una parte sin cerrar (salón y mostrador) */
+  onPagoACuenta: () => void;
+  /** HU-139: Dirección de entrega y costo de envío (solo delivery) */
+  onEntrega: () => void;
+  /** HU-140: Repartidor que lleva el pedido (solo delivery) */
+  onRepartidor: () => void;
+  /** HU-168: Cliente de la ficha (historial, alergias y promo de cumpleaños) */
+  onCliente: () => void;
   enviandoCocina: boolean;
   reimprimiendo: boolean;
+  marchando: boolean;
 }
 
 /**
@@ -304,16 +474,33 @@ export default function TicketPedido({
   onVolver,
   onModificarCantidad,
   onEliminarItem,
+  onCambiarTiempo,
+  onCambiarObservacion,
+  onMarcarUrgente,
+  onMarcarUrgenteItem,
+  esperaEstimada = null,
   onAplicarDescuento,
   onCerrarMesa,
   onControlMesa,
   onMandarCocina,
   onReimprimirComanda,
+  onMarchar,
+  onLinkPago,
+  onPagoACuenta,
+  onEntrega,
+  onRepartidor,
+  onCliente,
   enviandoCocina,
   reimprimiendo,
+  marchando,
 }: TicketPedidoProps) {
+  /** HU-168: Ficha del cliente vinculado (sale del listado ya cacheado) */
+  const { data: clientes = [] } = useClientes();
+  const cliente = pedido?.clienteId ? clientes.find((c) => c.id === pedido.clienteId) ?? null : null;
   const hayItems = pedido && pedido.items.length > 0;
   const hayDescuentos = pedido && pedido.totalDescuentos > 0;
+  /** HU-160: Lo que ya se cobró con la mesa abierta */
+  const pagadoACuenta = pedido?.pagadoACuenta ?? 0;
   /**
    * HU-29: Los badges NUEVO/ENVIADO ahora provienen del backend (item.esNuevo).
    * hayItemsNuevos se usa solo para mostrar feedback visual en el botón,
@@ -323,6 +510,10 @@ export default function TicketPedido({
     ? pedido.items.some((i) => i.esNuevo)
     : false;
 
+  /** HU-139: El envío se muestra aparte, sin controles: sigue a la zona de entrega */
+  const itemsProductos = pedido ? pedido.items.filter((i) => !i.envio) : [];
+  const itemsEnvio = pedido ? pedido.items.filter((i) => i.envio) : [];
+
   /** El pedido solo se puede modificar si está ABIERTO */
   const pedidoModificable = pedido?.estado === 'ABIERTO';
 
@@ -348,25 +539,99 @@ export default function TicketPedido({
                 <span className="text-red-400 font-semibold">
                   Pedido #{pedido.numeroPedido}
                 </span>
+                {/* HU-108: Solo se destaca cuando no es un pedido de salón */}
+                {pedido.canal && pedido.canal !== 'SALON' && (
+                  <>
+                    <span className="text-neutral-700">·</span>
+                    <span className="text-amber-400 font-semibold">
+                      {pedido.nombreListaPrecios ?? CANAL_VENTA_LABELS[pedido.canal]}
+                    </span>
+                  </>
+                )}
+              </div>
+            )}
+            {/* HU-138: Retiro prometido, o la espera a informar antes de enviar */}
+            {pedido?.horaPrometida ? (
+              <div
+                className={`mt-0.5 text-xs font-semibold ${
+                  pedidoModificable && new Date(pedido.horaPrometida) < new Date()
+                    ? 'text-red-400'
+                    : 'text-emerald-400'
+                }`}
+              >
+                Retira {formatHora(pedido.horaPrometida)}
+                {pedidoModificable && new Date(pedido.horaPrometida) < new Date() && ' · pasó la hora prometida'}
+              </div>
+            ) : esperaEstimada && (
+              <div
+                className="mt-0.5 text-xs text-gray-400"
+                title={`${esperaEstimada.comandasEnCola} comandas en cola · ${esperaEstimada.minutosPreparacion} min promedio`}
+              >
+                Listo en ~{esperaEstimada.minutosEspera} min ({formatHora(esperaEstimada.horaEstimada)})
+              </div>
+            )}
+            {/* HU-139: Dirección de entrega del delivery */}
+            {pedido?.canal === 'DELIVERY' && (
+              <div className={`mt-0.5 flex items-center gap-1 text-xs ${pedido.direccionEntrega ? 'text-gray-400' : 'text-amber-400'}`}>
+                <MapPin size={11} />
+                <span className="truncate max-w-[220px]">{pedido.direccionEntrega ?? 'Sin dirección de entrega'}</span>
+              </div>
+            )}
+            {/* HU-168: Cliente vinculado, con sus alergias a la vista */}
+            {pedido && (cliente || pedidoModificable) && (
+              <button
+                type="button"
+                onClick={onCliente}
+                disabled={!pedidoModificable}
+                className="mt-0.5 flex items-center gap-1 text-xs text-gray-400 hover:text-gray-200 disabled:cursor-default"
+              >
+                <BookUser size={11} />
+                <span className="truncate max-w-[220px]">{cliente ? cliente.nombre : 'Vincular cliente'}</span>
+              </button>
+            )}
+            {cliente?.alergias && (
+              <div className="mt-0.5 flex items-center gap-1 text-xs font-semibold text-red-400">
+                <AlertTriangle size={11} />
+                <span className="truncate max-w-[220px]">Alergias: {cliente.alergias}</span>
               </div>
             )}
           </div>
 
-          <button
-            type="button"
-            onClick={onVolver}
-            className="
-              flex items-center gap-1.5
-              px-3 py-2 rounded-lg
-              text-sm font-semibold text-gray-400
-              bg-neutral-800/80 border border-neutral-700
-              hover:border-neutral-600 hover:text-gray-300
-              transition-colors active:scale-95
-            "
-          >
-            <ArrowLeft size={16} />
-            <span>Atrás</span>
-          </button>
+          <div className="flex items-center gap-2">
+            {/* HU-137: Urgencia del pedido completo (sube sus comandas en cocina) */}
+            {pedido && pedidoModificable && (
+              <button
+                type="button"
+                onClick={() => onMarcarUrgente(!pedido.urgente)}
+                title={pedido.urgente ? 'Quitar la urgencia del pedido' : 'Marcar todo el pedido como urgente'}
+                className={[
+                  'flex items-center gap-1.5 px-3 py-2 rounded-lg text-sm font-semibold border transition-colors active:scale-95',
+                  pedido.urgente
+                    ? 'bg-red-600 border-red-500 text-white hover:bg-red-700'
+                    : 'bg-neutral-800/80 border-neutral-700 text-gray-400 hover:border-neutral-600 hover:text-gray-300',
+                ].join(' ')}
+              >
+                <AlertTriangle size={16} />
+                <span>Urgente</span>
+              </button>
+            )}
+
+            <button
+              type="button"
+              onClick={onVolver}
+              className="
+                flex items-center gap-1.5
+                px-3 py-2 rounded-lg
+                text-sm font-semibold text-gray-400
+                bg-neutral-800/80 border border-neutral-700
+                hover:border-neutral-600 hover:text-gray-300
+                transition-colors active:scale-95
+              "
+            >
+              <ArrowLeft size={16} />
+              <span>Atrás</span>
+            </button>
+          </div>
         </div>
       </div>
 
@@ -392,22 +657,44 @@ export default function TicketPedido({
             <div className="flex items-center gap-2 pt-3 pb-1 text-gray-600">
               <ShoppingBag size={13} />
               <span className="text-[10px] font-bold uppercase tracking-widest">
-                Ítems ({pedido!.items.length})
+                Ítems ({itemsProductos.length})
               </span>
             </div>
 
             {/* Lista de ítems */}
-            {pedido!.items.map((item) => (
+            {itemsProductos.map((item) => (
               <TicketItem
                 key={item.id}
                 item={item}
                 onModificarCantidad={onModificarCantidad}
                 onEliminar={onEliminarItem}
-                enviadoACocina={!item.esNuevo}
+                onCambiarTiempo={onCambiarTiempo}
+                onCambiarObservacion={onCambiarObservacion}
+                onMarcarUrgente={onMarcarUrgenteItem}
+                enviadoACocina={!item.esNuevo && !item.enEspera}
                 pedidoModificable={pedidoModificable}
               />
             ))}
 
+            {/* HU-139: Costo de envío de la zona */}
+            {itemsEnvio.map((item) => (
+              <div key={item.id} className="flex items-center justify-between gap-2 py-3 border-b border-neutral-800/60 last:border-b-0">
+                <button
+                  type="button"
+                  onClick={onEntrega}
+                  disabled={!pedidoModificable}
+                  title="El envío se cambia eligiendo la zona de entrega"
+                  className="flex items-center gap-1.5 text-sm text-gray-300 hover:text-gray-100 disabled:cursor-default"
+                >
+                  <MapPin size={13} className="text-gray-500" />
+                  {item.nombreProducto}
+                </button>
+                <span className="text-sm font-mono tabular-nums text-gray-300">
+                  $ {item.precioFinal.toLocaleString('es-AR')}
+                </span>
+              </div>
+            ))}
+
             {/* Hint sutil de guía */}
             <p className="text-[10px] text-gray-700 text-center py-3 leading-relaxed">
               Usá +/- para ajustar cantidades · Deslizá abajo para cobrar
@@ -441,6 +728,24 @@ export default function TicketPedido({
               $ {pedido.totalParcial.toLocaleString('es-AR')}
             </span>
           </div>
+
+          {/* HU-160: Pagos a cuenta y lo que falta cobrar */}
+          {pagadoACuenta > 0 && (
+            <>
+              <div className="flex justify-between text-sm text-emerald-400">
+                <span>Pagado a cuenta</span>
+                <span className="font-mono tabular-nums">
+                  -$ {pagadoACuenta.toLocaleString('es-AR')}
+                </span>
+              </div>
+              <div className="flex justify-between items-baseline">
+                <span className="text-sm font-semibold text-gray-300">Saldo</span>
+                <span className="text-lg font-bold text-gray-100 font-mono tabular-nums">
+                  $ {pedido.saldoPendiente.toLocaleString('es-AR')}
+                </span>
+              </div>
+            </>
+          )}
         </div>
       )}
 
@@ -482,6 +787,130 @@ export default function TicketPedido({
           )}
         </button>
 
+        {/* HU-110: Marchar el siguiente paso — solo si hay ítems retenidos */}
+        {pedido?.siguienteTiempo && (
+          <button
+            type="button"
+            onClick={onMarchar}
+            disabled={marchando || enviandoCocina || reimprimiendo || !pedidoModificable}
+            title="Avisa a cocina que el cliente está listo para el siguiente paso"
+            className="
+              w-full flex items-center justify-center gap-2
+              h-11 rounded-xl
+              text-sm font-semibold
+              bg-sky-900/30 text-sky-300 border border-sky-700/40
+              hover:bg-sky-900/50 hover:text-sky-200
+              disabled:opacity-40 disabled:cursor-not-allowed
+              transition-colors active:scale-[0.98]
+            "
+          >
+            {marchando ? (
+              <>
+                <Loader2 size={16} className="animate-spin" />
+                <span>Marchando…</span>
+              </>
+            ) : (
+              <>
+                <Flame size={16} />
+                <span>Marchar {TIEMPO_SERVICIO_LABELS[pedido.siguienteTiempo].toLowerCase()}</span>
+                <span className="ml-1 px-1.5 py-0.5 text-[10px] rounded-full bg-sky-700/50 text-sky-200">
+                  {pedido.items.filter(i => i.enEspera && i.tiempoServicio === pedido.siguienteTiempo).length}
+                </span>
+              </>
+            )}
+          </button>
+        )}
+
+        {/* HU-139: Dirección y zona de entrega — agrega el costo de envío */}
+        {pedido?.canal === 'DELIVERY' && (
+          <button
+            type="button"
+            onClick={onEntrega}
+            disabled={!pedidoModificable}
+            title="Carga la dirección y agrega el costo de envío de la zona"
+            className="
+              w-full flex items-center justify-center gap-2
+              h-11 rounded-xl
+              text-sm font-semibold
+              bg-neutral-800 text-gray-300
+              border border-neutral-700
+              hover:bg-neutral-700 hover:text-gray-200
+              disabled:opacity-40 disabled:cursor-not-allowed
+              transition-colors active:scale-[0.98]
+            "
+          >
+            <MapPin size={16} />
+            <span>{pedido.direccionEntrega ? 'Cambiar entrega' : 'Cargar entrega'}</span>
+          </button>
+        )}
+
+        {/* HU-140: Repartidor — se cambia hasta que sale */}
+        {pedido?.canal === 'DELIVERY' && (
+          <button
+            type="button"
+            onClick={onRepartidor}
+            disabled={!!pedido.salidaReparto}
+            title={pedido.salidaReparto ? 'El repartidor ya salió con el pedido' : 'Elegí quién lleva el pedido'}
+            className="
+              w-full flex items-center justify-center gap-2
+              h-11 rounded-xl
+              text-sm font-semibold
+              bg-neutral-800 text-gray-300
+              border border-neutral-700
+              hover:bg-neutral-700 hover:text-gray-200
+              disabled:opacity-40 disabled:cursor-not-allowed
+              transition-colors active:scale-[0.98]
+            "
+          >
+            <Bike size={16} />
+            <span>{pedido.repartidorId ? 'Cambiar repartidor' : 'Asignar repartidor'}</span>
+          </button>
+        )}
+
+        {/* HU-160: Pago a cuenta — el comensal que se va antes paga lo suyo */}
+        {pedido && pedido.canal !== 'DELIVERY' && (
+          <button
+            type="button"
+            onClick={onPagoACuenta}
+            disabled={!hayItems || !pedidoModificable}
+            title="Cobra una parte sin cerrar la mesa; al cerrar solo se cobra el saldo"
+            className="
+              w-full flex items-center justify-center gap-2
+              h-11 rounded-xl
+              text-sm font-semibold
+              bg-emerald-900/30 text-emerald-300 border border-emerald-700/40
+              hover:bg-emerald-900/50 hover:text-emerald-200
+              disabled:opacity-40 disabled:cursor-not-allowed
+              transition-colors active:scale-[0.98]
+            "
+          >
+            <HandCoins size={16} />
+            <span>{pagadoACuenta > 0 ? 'Pagos a cuenta' : 'Cobrar a cuenta'}</span>
+          </button>
+        )}
+
+        {/* HU-128: Link de pago — el pedido sale a cocina al acreditarse */}
+        {pedido?.canal === 'DELIVERY' && (
+          <button
+            type="button"
+            onClick={onLinkPago}
+            disabled={!hayItems || !pedidoModificable}
+            title="Genera un link de Mercado Pago / MODO para mandarle al cliente por WhatsApp"
+            className="
+              w-full flex items-center justify-center gap-2
+              h-11 rounded-xl
+              text-sm font-semibold
+              bg-emerald-900/30 text-emerald-300 border border-emerald-700/40
+              hover:bg-emerald-900/50 hover:text-emerald-200
+              disabled:opacity-40 disabled:cursor-not-allowed
+              transition-colors active:scale-[0.98]
+            "
+          >
+            <Link2 size={16} />
+            <span>Link de Pago</span>
+          </button>
+        )}
+
         {/* Botón Reimprimir Comanda — todos los ítems */}
         <button
           type="button"
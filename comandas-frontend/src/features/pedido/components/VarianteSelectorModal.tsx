@@ -1,4 +1,4 @@
-import { useMemo } from 'react';
+import { useEffect, useMemo } from 'react';
 import { X, Layers, Sparkles } from 'lucide-react';
 import type { ProductoResponse } from '../../catalogo/types';
 
@@ -24,6 +24,9 @@ function formatPrecio(monto: number): string {
 /**
  * Extrae una etiqueta corta para la variante.
  *
+ * HU-109: Si la variante tiene etiquetaVariante cargada en el catálogo
+ * (ej: "Grande", "1.5 L"), se usa tal cual.
+ *
  * Estrategia: si el nombre de la variante contiene el nombre base como prefijo,
  * usa el sufijo como etiqueta (ej: "Hamburguesa Completa Doble" → "Doble").
  * Si no se puede derivar, usa el nombre completo.
@@ -31,6 +34,8 @@ function formatPrecio(monto: number): string {
  * Fallback por cantidadDiscosCarne si la etiqueta queda vacía.
  */
 function extraerEtiqueta(variante: ProductoResponse, nombreBase: string): string {
+  if (variante.etiquetaVariante) return variante.etiquetaVariante;
+
   const nombre = variante.nombre.trim();
 
   // Intentar encontrar un prefijo común para extraer el sufijo diferenciador
@@ -77,6 +82,7 @@ function abreviatura(etiqueta: string): string {
  * ordenadas por cantidadDiscosCarne ascendente.
  *
  * Diseño: botones grandes, touch-friendly, con precio visible.
+ * Atajos (HU-109): las teclas 1-9 eligen la variante en ese orden, Escape cierra.
  * Paleta: rojo/negro acorde a la identidad FoodFlow.
  */
 export default function VarianteSelectorModal({
@@ -114,6 +120,27 @@ export default function VarianteSelectorModal({
     return prefijo || productoBase.nombre;
   }, [variantesOrdenadas, productoBase.nombre]);
 
+  // Atajos de teclado: selección rápida sin tocar la pantalla
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') {
+        onCerrar();
+        return;
+      }
+      const indice = Number(e.key) - 1;
+      if (!Number.isInteger(indice) || indice < 0 || indice >= variantesOrdenadas.length) return;
+
+      const variante = variantesOrdenadas[indice];
+      const sinStock =
+        variante.agotado ||
+        !!(variante.controlaStock && variante.stockActual !== null && variante.stockActual <= 0);
+      if (!sinStock) onSeleccionar(variante);
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [variantesOrdenadas, onSeleccionar, onCerrar]);
+
   return (
     <>
       {/* ── Backdrop ── */}
@@ -175,10 +202,11 @@ export default function VarianteSelectorModal({
           {/* ── Botonera de variantes ── */}
           <div className="flex-1 overflow-y-auto px-5 py-5">
             <div className="grid grid-cols-1 gap-3">
-              {variantesOrdenadas.map((variante) => {
+              {variantesOrdenadas.map((variante, indice) => {
                 const etiqueta = extraerEtiqueta(variante, nombreReferencia);
                 const letra = abreviatura(etiqueta);
                 const sinStock =
+                  variante.agotado ||
                   !!(variante.controlaStock && variante.stockActual !== null && variante.stockActual <= 0);
                 const tienePromo = variante.promocionesActivas?.length > 0;
 
@@ -265,6 +293,13 @@ export default function VarianteSelectorModal({
                     <p className="text-base font-bold text-gray-100 font-mono tabular-nums shrink-0">
                       $ {formatPrecio(variante.precio)}
                     </p>
+
+                    {/* Atajo de teclado */}
+                    {indice < 9 && (
+                      <kbd className="hidden sm:flex w-6 h-6 rounded-md border border-neutral-700 bg-neutral-800 items-center justify-center text-[11px] font-mono text-gray-500 shrink-0">
+                        {indice + 1}
+                      </kbd>
+                    )}
                   </button>
                 );
               })}
@@ -1,22 +1,150 @@
-import { useMutation, useQueryClient } from '@tanstack/react-query';
+import { useMutation, useQuery, useQueryClient } from '@tanstack/react-query';
 import { pedidosApi } from '../api/pedidosApi';
-import type { AgregarProductoRequest, DescuentoManualRequest } from '../types';
+import {
+  descartarBorrador,
+  guardarItemBorrador,
+  obtenerBorrador,
+  quitarItemBorrador,
+  type BorradorPedido,
+} from '../services/borradorService';
+import type { AgregarProductoRequest, DescuentoManualRequest, TiempoServicio } from '../types';
+
+/** Mesa y nombre del producto para anotar el ítem en el borrador (HU-164) */
+interface AnotacionBorrador {
+  mesaId: string;
+  nombre: string;
+}
+
+/** Sin respuesta del servidor (caído o sin red): el ítem no llegó y vale la pena recuperarlo */
+function esErrorDeConexion(error: unknown): boolean {
+  return !(error as { response?: unknown })?.response;
+}
+
+/**
+ * HU-165: Otra terminal cambió el pedido desde que esta lo cargó y el backend
+ * rechazó el cambio sin aplicarlo. Trae la versión actual para reintentar.
+ */
+export function esPedidoModificado(error: unknown): error is { response: { data: { message: string; versionActual: number } } } {
+  const response = (error as { response?: { status?: number; data?: { codigo?: string } } })?.response;
+  return response?.status === 409 && response.data?.codigo === 'PEDIDO_MODIFICADO';
+}
+
+/**
+ * Manda un ítem al backend dejándolo antes en el borrador de la mesa (HU-164).
+ * Si el backend responde (bien o con un rechazo) el ítem sale del borrador;
+ * si no responde, queda para recuperarlo. El id del ítem viaja al backend:
+ * si el ítem llegó y lo que se perdió fue la respuesta, recuperarlo no lo
+ * carga dos veces.
+ *
+ * `itemRecuperado` es el id de un ítem que ya está en el borrador: no se
+ * vuelve a anotar (el pedido de la mesa puede ser otro y se perdería el resto).
+ */
+async function agregarConBorrador(
+  pedidoId: string,
+  anotacion: AnotacionBorrador,
+  data: AgregarProductoRequest,
+  version: number,
+  itemRecuperado?: string,
+) {
+  const itemId = itemRecuperado ?? crypto.randomUUID();
+  if (!itemRecuperado) {
+    await guardarItemBorrador(anotacion.mesaId, pedidoId, { id: itemId, nombre: anotacion.nombre, request: data })
+      .catch((e) => console.error('[Borrador] No se pudo anotar el ítem:', e));
+  }
+  try {
+    const response = await pedidosApi.agregarProducto(pedidoId, { ...data, borradorId: itemId }, version);
+    await quitarItemBorrador(anotacion.mesaId, itemId).catch(() => undefined);
+    return response;
+  } catch (error) {
+    if (!esErrorDeConexion(error)) {
+      await quitarItemBorrador(anotacion.mesaId, itemId).catch(() => undefined);
+    }
+    throw error;
+  }
+}
 
 /**
  * HU-05: Agregar producto al pedido (Aggregate Root).
  * Invalida el prefijo ['pedido'] para refrescar totales recalculados por el backend.
+ *
+ * HU-164: Con `borrador` el ítem se anota en el borrador de la mesa mientras
+ * el backend no lo confirma.
+ *
+ * HU-165: El backend rechaza el ítem si otra terminal cambió el pedido desde
+ * `version`; en ese caso se refresca el pedido para mostrar lo que cambió.
  */
 export function useAgregarProducto() {
   const queryClient = useQueryClient();
   
   return useMutation({
-    mutationFn: ({ pedidoId, ...data }: { pedidoId: string } & AgregarProductoRequest) =>
-      pedidosApi.agregarProducto(pedidoId, data),
+    mutationFn: ({ pedidoId, borrador, version, ...data }: { pedidoId: string; borrador?: AnotacionBorrador; version: number } & AgregarProductoRequest) =>
+      borrador
+        ? agregarConBorrador(pedidoId, borrador, data, version)
+        : pedidosApi.agregarProducto(pedidoId, data, version),
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
     },
-    onError: (error: Error) => {
+    onError: (error: Error, { borrador }) => {
       console.error('[useAgregarProducto] Error al agregar producto:', error);
+      if (esPedidoModificado(error)) {
+        queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+      }
+      if (borrador && esErrorDeConexion(error)) {
+        queryClient.invalidateQueries({ queryKey: ['borrador-pedido', borrador.mesaId] });
+      }
+    },
+  });
+}
+
+/**
+ * HU-164: Ítems de la mesa que quedaron sin confirmar (corte de luz, cierre
+ * de la app o servidor caído). Se lee al abrir la mesa; solo se vuelve a
+ * leer cuando un ítem no llega al backend.
+ */
+export function useBorradorPedido(mesaId: string) {
+  return useQuery<BorradorPedido | null>({
+    queryKey: ['borrador-pedido', mesaId],
+    queryFn: () => obtenerBorrador(mesaId),
+    staleTime: Infinity,
+  });
+}
+
+/**
+ * HU-164: Vuelve a mandar al pedido actual de la mesa los ítems del borrador.
+ * Se corta en el primero que no llega: el resto queda para otro intento.
+ * Cada ítem va con la versión que devolvió el anterior (HU-165).
+ */
+export function useRecuperarBorrador() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: async ({ mesaId, pedidoId, version, borrador }: { mesaId: string; pedidoId: string; version: number; borrador: BorradorPedido }) => {
+      let recuperados = 0;
+      let versionActual = version;
+      for (const item of borrador.items) {
+        const response = await agregarConBorrador(pedidoId, { mesaId, nombre: item.nombre }, item.request, versionActual, item.id);
+        versionActual = response.data.version;
+        recuperados++;
+      }
+      return recuperados;
+    },
+    onSettled: (_data, _error, { mesaId }) => {
+      queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['borrador-pedido', mesaId] });
+    },
+  });
+}
+
+/**
+ * HU-164: Descarta el borrador de la mesa (el mozo ya cargó todo de nuevo).
+ */
+export function useDescartarBorrador() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (mesaId: string) => descartarBorrador(mesaId),
+    onSuccess: (_data, mesaId) => {
+      queryClient.invalidateQueries({ queryKey: ['borrador-pedido', mesaId] });
     },
   });
 }
@@ -24,18 +152,22 @@ export function useAgregarProducto() {
 /**
  * HU-14: Aplicar descuento global al pedido.
  * Invalida el prefijo para actualizar todos los detalles y totales.
+ * HU-165: Si otra terminal cambió el pedido, se refresca sin aplicar el descuento.
  */
 export function useAplicarDescuentoGlobal() {
   const queryClient = useQueryClient();
   
   return useMutation({
-    mutationFn: ({ pedidoId, ...data }: { pedidoId: string } & DescuentoManualRequest) =>
-      pedidosApi.aplicarDescuentoGlobal(pedidoId, data),
+    mutationFn: ({ pedidoId, version, ...data }: { pedidoId: string; version: number } & DescuentoManualRequest) =>
+      pedidosApi.aplicarDescuentoGlobal(pedidoId, data, version),
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
     },
     onError: (error: Error) => {
       console.error('[useAplicarDescuentoGlobal] Error al aplicar descuento:', error);
+      if (esPedidoModificado(error)) {
+        queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+      }
     },
   });
 }
@@ -43,18 +175,22 @@ export function useAplicarDescuentoGlobal() {
 /**
  * HU-14: Aplicar descuento por ítem específico.
  * Invalida el prefijo del aggregate root.
+ * HU-165: Si otra terminal cambió el pedido, se refresca sin aplicar el descuento.
  */
 export function useAplicarDescuentoPorItem() {
   const queryClient = useQueryClient();
   
   return useMutation({
-    mutationFn: ({ pedidoId, itemId, ...data }: { pedidoId: string; itemId: string } & DescuentoManualRequest) =>
-      pedidosApi.aplicarDescuentoPorItem(pedidoId, itemId, data),
+    mutationFn: ({ pedidoId, itemId, version, ...data }: { pedidoId: string; itemId: string; version: number } & DescuentoManualRequest) =>
+      pedidosApi.aplicarDescuentoPorItem(pedidoId, itemId, data, version),
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
     },
     onError: (error: Error) => {
       console.error('[useAplicarDescuentoPorItem] Error al aplicar descuento:', error);
+      if (esPedidoModificado(error)) {
+        queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+      }
     },
   });
 }
@@ -67,13 +203,16 @@ export function useModificarCantidad() {
   const queryClient = useQueryClient();
   
   return useMutation({
-    mutationFn: ({ pedidoId, itemId, cantidad }: { pedidoId: string; itemId: string; cantidad: number }) =>
-      pedidosApi.modificarCantidad(pedidoId, itemId, cantidad),
+    mutationFn: ({ pedidoId, itemId, cantidad, version }: { pedidoId: string; itemId: string; cantidad: number; version: number }) =>
+      pedidosApi.modificarCantidad(pedidoId, itemId, cantidad, version),
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
     },
     onError: (error: Error) => {
       console.error('[useModificarCantidad] Error al modificar cantidad:', error);
+      if (esPedidoModificado(error)) {
+        queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+      }
     },
   });
 }
@@ -86,13 +225,132 @@ export function useEliminarItem() {
   const queryClient = useQueryClient();
   
   return useMutation({
-    mutationFn: ({ pedidoId, itemId }: { pedidoId: string; itemId: string }) =>
-      pedidosApi.eliminarItem(pedidoId, itemId),
+    mutationFn: ({ pedidoId, itemId, version }: { pedidoId: string; itemId: string; version: number }) =>
+      pedidosApi.eliminarItem(pedidoId, itemId, version),
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
     },
     onError: (error: Error) => {
       console.error('[useEliminarItem] Error al eliminar ítem:', error);
+      if (esPedidoModificado(error)) {
+        queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+      }
+    },
+  });
+}
+
+/**
+ * HU-110: Cambiar el paso de servicio de un ítem (entrada / principal / postre).
+ * No recalcula precios: solo cambia cuándo sale el ítem a cocina.
+ */
+export function useCambiarTiempoServicio() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ pedidoId, itemId, tiempoServicio }: { pedidoId: string; itemId: string; tiempoServicio: TiempoServicio | null }) =>
+      pedidosApi.cambiarTiempoServicio(pedidoId, itemId, tiempoServicio),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+    },
+    onError: (error: Error) => {
+      console.error('[useCambiarTiempoServicio] Error al cambiar el paso:', error);
+    },
+  });
+}
+
+/**
+ * HU-120: Cambiar la observación libre de un ítem ("bien cocida", "sin sal").
+ * Solo mientras el ítem no salió a cocina; no recalcula precios.
+ */
+export function useCambiarObservacion() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ pedidoId, itemId, observacion, version }: { pedidoId: string; itemId: string; observacion: string | null; version: number }) =>
+      pedidosApi.cambiarObservacion(pedidoId, itemId, observacion, version),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+    },
+    onError: (error: Error) => {
+      console.error('[useCambiarObservacion] Error al cambiar la observación:', error);
+      if (esPedidoModificado(error)) {
+        queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+      }
+    },
+  });
+}
+
+/**
+ * HU-137: Marcar el pedido completo como urgente (o quitar la marca).
+ * También afecta la pantalla de cocina, por eso invalida sus comandas.
+ */
+export function useMarcarUrgente() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ pedidoId, urgente }: { pedidoId: string; urgente: boolean }) =>
+      pedidosApi.marcarUrgente(pedidoId, urgente),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['cocina', 'comandas'] });
+    },
+    onError: (error: Error) => {
+      console.error('[useMarcarUrgente] Error al marcar la urgencia:', error);
+    },
+  });
+}
+
+/**
+ * HU-137: Marcar un ítem como urgente para su próximo envío a cocina.
+ */
+export function useMarcarUrgenteItem() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ pedidoId, itemId, urgente }: { pedidoId: string; itemId: string; urgente: boolean }) =>
+      pedidosApi.marcarUrgenteItem(pedidoId, itemId, urgente),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+    },
+    onError: (error: Error) => {
+      console.error('[useMarcarUrgenteItem] Error al marcar la urgencia:', error);
+    },
+  });
+}
+
+/**
+ * HU-139: Cargar la dirección de entrega y el costo de envío de un delivery.
+ */
+export function useAsignarEntrega() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ pedidoId, direccion, zonaId }: { pedidoId: string; direccion: string; zonaId: string | null }) =>
+      pedidosApi.asignarEntrega(pedidoId, direccion, zonaId),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+    },
+    onError: (error: Error) => {
+      console.error('[useAsignarEntrega] Error al cargar la entrega:', error);
+    },
+  });
+}
+
+/**
+ * HU-168: Vincular (o desvincular) un cliente de la ficha al pedido.
+ * Al cobrar, el cierre toma este cliente para la promo de cumpleaños.
+ */
+export function useVincularCliente() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ pedidoId, clienteId }: { pedidoId: string; clienteId: string | null }) =>
+      pedidosApi.vincularCliente(pedidoId, clienteId),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+    },
+    onError: (error: Error) => {
+      console.error('[useVincularCliente] Error al vincular el cliente:', error);
     },
   });
 }
@@ -18,7 +18,15 @@ export {
   useAplicarDescuentoPorItem,
   useModificarCantidad,
   useEliminarItem,
+  useCambiarTiempoServicio,
+  useCambiarObservacion,
+  useMarcarUrgente,
+  useMarcarUrgenteItem,
+  useAsignarEntrega,
   useReabrirPedido,
+  useBorradorPedido,
+  useRecuperarBorrador,
+  useDescartarBorrador,
 } from './hooks/usePedido';
 
 // API
@@ -35,4 +43,5 @@ export type {
   DescuentoManualRequest,
   DetallePedidoResponse,
   AplicarDescuentoManualResponse,
+  TiempoServicio,
 } from './types';
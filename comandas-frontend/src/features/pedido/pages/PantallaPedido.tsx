@@ -1,4 +1,4 @@
-import { useState, useCallback, useEffect, useMemo } from 'react';
+import { useState, useCallback, useEffect, useMemo, useRef } from 'react';
 import ListaCategorias from '../components/ListaCategorias';
 import GrillaProductos from '../components/GrillaProductos';
 import TicketPedido from '../components/TicketPedido';
@@ -8,19 +8,55 @@ import TicketPreviewModal from '../components/TicketPreviewModal';
 import ConfigurarProductoModal from '../components/ConfigurarProductoModal';
 import type { ConfigurarProductoPayload } from '../components/ConfigurarProductoModal';
 import VarianteSelectorModal from '../components/VarianteSelectorModal';
+import ComboSelectorModal from '../components/ComboSelectorModal';
+import BorradorRecuperadoAviso from '../components/BorradorRecuperadoAviso';
+import ConflictoPedidoModal from '../components/ConflictoPedidoModal';
+import LinkPagoModal from '../../linksPago/components/LinkPagoModal';
+import PagoACuentaModal from '../components/PagoACuentaModal';
+import EntregaDeliveryModal from '../../delivery/components/EntregaDeliveryModal';
+import AsignarRepartidorModal from '../../delivery/components/AsignarRepartidorModal';
+import ClientePedidoModal from '../../clientes/components/ClientePedidoModal';
+import { useEsperaEstimada } from '../../cocina/hooks/useCocina';
 import { useProductos } from '../../catalogo/hooks/useProductos';
 import type { ProductoResponse } from '../../catalogo/types';
-import { usePedidoMesa, useEnviarComandaCocina, useReimprimirComanda } from '../../salon/hooks/useMesas';
+import { usePedidoMesa, useEnviarComandaCocina, useReimprimirComanda, useMarcharTiempo } from '../../salon/hooks/useMesas';
 import {
   useAgregarProducto,
   useModificarCantidad,
   useEliminarItem,
+  useCambiarTiempoServicio,
+  useCambiarObservacion,
+  useMarcarUrgente,
+  useMarcarUrgenteItem,
+  esPedidoModificado,
 } from '../hooks/usePedido';
+import { TIEMPO_SERVICIO_LABELS, type AgregarProductoRequest, type TiempoServicio } from '../types';
 import { permiteAbrirModal } from '../utils/productoUtils';
 import { useCategorias } from '../../categorias/hooks/useCategorias';
 import useToast from '../../../hooks/useToast';
 import { imprimirEscPos } from '../services/printerService';
 
+/**
+ * HU-164: Si el servidor no respondió, el ítem no se perdió: quedó en el
+ * borrador de la mesa y se ofrece para cargarlo de nuevo.
+ */
+function mensajeErrorAgregar(error: any, porDefecto: string): string {
+  if (!error?.response) {
+    return 'Sin conexión con el servidor: el ítem quedó guardado para cargarlo cuando vuelva';
+  }
+  return error.response.data?.message || porDefecto;
+}
+
+/**
+ * HU-165: Cambio rechazado porque otra terminal modificó la mesa. `reintentar`
+ * lo vuelve a mandar con la versión del pedido ya refrescado.
+ */
+interface ConflictoPendiente {
+  cambio: string;
+  versionActual: number;
+  reintentar: (version: number) => void;
+}
+
 interface PantallaPedidoProps {
   mesaId: string;
   onCerrar: () => void;
@@ -43,6 +79,11 @@ interface PantallaPedidoProps {
  * 2. Si la mesa está ABIERTA → carga el pedido activo
  * 3. El operador selecciona productos → se agregan al pedido (mutation)
  * 4. El ticket se actualiza en tiempo real vía React Query invalidation
+ * 5. Mientras el backend no confirma un ítem, queda en el borrador de la mesa
+ *    para recuperarlo tras un corte (HU-164)
+ * 6. Cada cambio del ticket viaja con la versión del pedido que se está viendo;
+ *    si otra terminal lo modificó en el medio, se muestra el pedido actualizado
+ *    y el operador decide si aplica su cambio igual o lo descarta (HU-165)
  */
 export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps) {
   const toast = useToast();
@@ -53,6 +94,11 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
   const [mostrarDescuento, setMostrarDescuento] = useState(false);
   const [mostrarCierre, setMostrarCierre] = useState(false);
   const [mostrarTicketPreview, setMostrarTicketPreview] = useState(false);
+  const [mostrarLinkPago, setMostrarLinkPago] = useState(false);
+  const [mostrarPagoACuenta, setMostrarPagoACuenta] = useState(false);
+  const [mostrarEntrega, setMostrarEntrega] = useState(false);
+  const [mostrarRepartidor, setMostrarRepartidor] = useState(false);
+  const [mostrarCliente, setMostrarCliente] = useState(false);
   /** Producto seleccionado para configurar (observaciones + extras) antes de agregar */
   const [productoSeleccionado, setProductoSeleccionado] = useState<ProductoResponse | null>(null);
 
@@ -64,6 +110,13 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
   /** ID de la variante seleccionada (se propaga al ConfigurarProductoModal y al request) */
   const [varianteSeleccionadaId, setVarianteSeleccionadaId] = useState<string | undefined>(undefined);
 
+  // ── Estado de combos (HU-103) ──
+  /** Combo con componentes a elegir que abrió el selector */
+  const [comboSeleccionado, setComboSeleccionado] = useState<ProductoResponse | null>(null);
+
+  // ── Edición concurrente (HU-165) ──
+  const [conflicto, setConflicto] = useState<ConflictoPendiente | null>(null);
+
   // ── Datos del backend ──
   const { data: productos = [], isLoading: cargandoProductos } =
     useProductos(categoriaActiva, true);
@@ -71,6 +124,11 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
   const { data: pedido = null, isLoading: cargandoPedido } =
     usePedidoMesa(mesaId);
 
+  // ── HU-138: Espera para informar al cliente antes del primer envío de un take away ──
+  const { data: esperaEstimada = null } = useEsperaEstimada(
+    pedido?.canal === 'TAKE_AWAY' && pedido.estado === 'ABIERTO' && !pedido.horaPrometida
+  );
+
   // ── Categorías del backend (para resolver flags de comportamiento) ──
   const { data: categorias = [] } = useCategorias();
 
@@ -80,6 +138,79 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
   const eliminarItem = useEliminarItem();
   const enviarComandaCocina = useEnviarComandaCocina();
   const reimprimirComanda = useReimprimirComanda();
+  const marcharTiempo = useMarcharTiempo();
+  const cambiarTiempoServicio = useCambiarTiempoServicio();
+  const cambiarObservacion = useCambiarObservacion();
+  const marcarUrgente = useMarcarUrgente();
+  const marcarUrgenteItem = useMarcarUrgenteItem();
+
+  // ── HU-165: Versión del pedido que ve esta terminal ──
+  // Se queda con la mayor entre la del detalle y la que devuelve cada cambio:
+  // un segundo cambio antes del refresco no se toma como conflicto.
+  const versionRef = useRef<{ pedidoId: string; version: number } | null>(null);
+
+  const registrarVersion = useCallback((pedidoId: string, version: number) => {
+    const actual = versionRef.current;
+    if (!actual || actual.pedidoId !== pedidoId || version > actual.version) {
+      versionRef.current = { pedidoId, version };
+    }
+  }, []);
+
+  useEffect(() => {
+    if (pedido) registrarVersion(pedido.pedidoId, pedido.version);
+  }, [pedido, registrarVersion]);
+
+  // Un pedido que la terminal todavía no registró arranca en 0: si ya cambió,
+  // el backend lo rechaza como conflicto en vez de aplicarlo sin control.
+  const versionVista = useCallback(
+    (pedidoId: string) =>
+      versionRef.current?.pedidoId === pedidoId ? versionRef.current.version : 0,
+    []
+  );
+
+  /** Si el error es un conflicto HU-165, abre la resolución y devuelve true */
+  const resolverConflicto = useCallback(
+    (error: unknown, cambio: string, reintentar: (version: number) => void) => {
+      if (!esPedidoModificado(error)) return false;
+      setConflicto({ cambio, versionActual: error.response.data.versionActual, reintentar });
+      return true;
+    },
+    []
+  );
+
+  /**
+   * Agrega un ítem al pedido anotándolo en el borrador (HU-164) y con la
+   * versión que ve la terminal (HU-165).
+   */
+  const agregarAlPedido = useCallback(
+    (
+      pedidoId: string,
+      nombre: string,
+      data: AgregarProductoRequest,
+      porDefecto: string,
+      alAgregar?: () => void
+    ) => {
+      const enviar = (version: number) =>
+        agregarProducto.mutate(
+          { pedidoId, ...data, version, borrador: { mesaId, nombre } },
+          {
+            onSuccess: (response) => {
+              registrarVersion(pedidoId, response.data.version);
+              alAgregar?.();
+            },
+            onError: (error: any) => {
+              if (resolverConflicto(error, `Agregar ${data.cantidad}x ${nombre}`, enviar)) return;
+              toast.error(mensajeErrorAgregar(error, porDefecto));
+            },
+          }
+        );
+      enviar(versionVista(pedidoId));
+    },
+    [agregarProducto, mesaId, registrarVersion, resolverConflicto, toast, versionVista]
+  );
+
+  const pedidoConflictoActualizado =
+    conflicto && pedido && pedido.version >= conflicto.versionActual ? pedido : null;
 
   // ── Bloquear scroll del body mientras el modal está abierto ──
   useEffect(() => {
@@ -93,8 +224,9 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
   // Los extras (huevo, queso, disco) no se muestran en la grilla del POS
   // porque no pueden agregarse como líneas independientes. Solo se seleccionan
   // desde el modal de configuración de otro producto.
+  // HU-161: tampoco los que están fuera de su horario (el polling los vuelve a mostrar).
   const productosFiltrados = useMemo(() => {
-    const sinExtras = productos.filter((p) => !p.esExtra);
+    const sinExtras = productos.filter((p) => !p.esExtra && !p.fueraDeHorario);
     if (!busqueda.trim()) return sinExtras;
     const termino = busqueda.trim().toLowerCase();
     return sinExtras.filter((p) => p.nombre.toLowerCase().includes(termino));
@@ -102,7 +234,7 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
 
   // Total de productos sin extras (para el indicador "N de M" en la grilla)
   const totalProductosSinExtras = useMemo(
-    () => productos.filter((p) => !p.esExtra).length,
+    () => productos.filter((p) => !p.esExtra && !p.fueraDeHorario).length,
     [productos]
   );
 
@@ -111,8 +243,9 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
    *
    * 1. Si el producto pertenece a un grupo de variantes (grupoVarianteId != null)
    *    → abre VarianteSelectorModal para elegir la variante específica
-   * 2. Si permiteAbrirModal() = true → abre modal de observaciones/extras
-   * 3. Si permiteAbrirModal() = false → agrega directamente al pedido (cantidad 1, sin extras)
+   * 2. Si es un combo con algún componente a elegir → abre ComboSelectorModal (HU-103)
+   * 3. Si permiteAbrirModal() = true → abre modal de observaciones/extras
+   * 4. Si permiteAbrirModal() = false → agrega directamente al pedido (cantidad 1, sin extras)
    *
    * La decisión integra: requiereConfiguracion, permiteExtras, esExtra,
    * categoría resuelta, y grupoVarianteId para variantes.
@@ -129,7 +262,7 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
       // Si tiene variantes → abrir selector de variantes primero
       if (producto.grupoVarianteId) {
         const hermanas = productos.filter(
-          (p) => p.grupoVarianteId === producto.grupoVarianteId
+          (p) => p.grupoVarianteId === producto.grupoVarianteId && !p.fueraDeHorario
         );
         if (hermanas.length > 1) {
           setVarianteSelectorBase(producto);
@@ -139,28 +272,26 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
         // Si solo queda 1 variante visible (ej: filtro de búsqueda), actúa como producto normal
       }
 
+      // Combo con elección: el backend completa solo los componentes fijos
+      if (producto.componentesCombo?.some((c) => c.opcionesIds.length > 1)) {
+        setComboSeleccionado(producto);
+        return;
+      }
+
       // Sin variantes: flujo clásico
       if (permiteAbrirModal(producto, categorias)) {
         setVarianteSeleccionadaId(undefined);
         setProductoSeleccionado(producto);
       } else {
-        agregarProducto.mutate(
-          {
-            pedidoId: pedido.pedidoId,
-            productoId: producto.id,
-            cantidad: 1,
-          },
-          {
-            onError: (error: any) => {
-              const msg =
-                error?.response?.data?.message || 'Error al agregar producto';
-              toast.error(msg);
-            },
-          }
+        agregarAlPedido(
+          pedido.pedidoId,
+          producto.nombre,
+          { productoId: producto.id, cantidad: 1 },
+          'Error al agregar producto'
         );
       }
     },
-    [pedido, productos, toast, agregarProducto, categorias]
+    [pedido, productos, toast, agregarAlPedido, categorias]
   );
 
   /**
@@ -187,24 +318,15 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
         setProductoSeleccionado(variante);
       } else {
         // Agregar directamente con varianteId explícito
-        agregarProducto.mutate(
-          {
-            pedidoId: pedido.pedidoId,
-            productoId: variante.id,
-            cantidad: 1,
-            varianteId: variante.id,
-          },
-          {
-            onError: (error: any) => {
-              const msg =
-                error?.response?.data?.message || 'Error al agregar producto';
-              toast.error(msg);
-            },
-          }
+        agregarAlPedido(
+          pedido.pedidoId,
+          variante.nombre,
+          { productoId: variante.id, cantidad: 1, varianteId: variante.id },
+          'Error al agregar producto'
         );
       }
     },
-    [pedido, categorias, agregarProducto, toast]
+    [pedido, categorias, agregarAlPedido]
   );
 
   /**
@@ -221,68 +343,181 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
     (payload: ConfigurarProductoPayload) => {
       if (!pedido?.pedidoId) return;
 
-      agregarProducto.mutate(
+      agregarAlPedido(
+        pedido.pedidoId,
+        productoSeleccionado?.nombre ?? 'Producto',
         {
-          pedidoId: pedido.pedidoId,
           productoId: payload.productoId,
           cantidad: payload.cantidad,
           observaciones: payload.observaciones,
           extrasIds: payload.extrasIds.length > 0 ? payload.extrasIds : undefined,
           varianteId: payload.varianteId,
+          advertencias: payload.advertencias,
+          ingredientesQuitados: payload.ingredientesQuitados,
         },
+        'Error al agregar producto',
+        () => {
+          setProductoSeleccionado(null);
+          setVarianteSeleccionadaId(undefined);
+        }
+      );
+    },
+    [pedido, agregarAlPedido, productoSeleccionado]
+  );
+
+  /**
+   * Callback del selector de combos (HU-103).
+   * Agrega una unidad del combo con la opción elegida para cada componente.
+   */
+  const handleConfirmarCombo = useCallback(
+    (componentesComboIds: string[]) => {
+      if (!pedido?.pedidoId || !comboSeleccionado) return;
+
+      agregarAlPedido(
+        pedido.pedidoId,
+        comboSeleccionado.nombre,
+        { productoId: comboSeleccionado.id, cantidad: 1, componentesComboIds },
+        'Error al agregar combo',
+        () => setComboSeleccionado(null)
+      );
+    },
+    [pedido, comboSeleccionado, agregarAlPedido]
+  );
+
+  const handleModificarCantidad = useCallback(
+    (itemId: string, nuevaCantidad: number) => {
+      if (!pedido?.pedidoId) return;
+      const pedidoId = pedido.pedidoId;
+      const nombre = pedido.items.find((i) => i.id === itemId)?.nombreProducto ?? 'el ítem';
+
+      const enviar = (version: number) =>
+        modificarCantidad.mutate(
+          { pedidoId, itemId, cantidad: nuevaCantidad, version },
+          {
+            onSuccess: (response) => registrarVersion(pedidoId, response.data.version),
+            onError: (error: any) => {
+              if (resolverConflicto(error, `Dejar ${nombre} en ${nuevaCantidad}`, enviar)) return;
+              const msg =
+                error?.response?.data?.message || 'Error al modificar cantidad';
+              toast.error(msg);
+            },
+          }
+        );
+      enviar(versionVista(pedidoId));
+    },
+    [pedido, modificarCantidad, toast, registrarVersion, resolverConflicto, versionVista]
+  );
+
+  const handleEliminarItem = useCallback(
+    (itemId: string) => {
+      if (!pedido?.pedidoId) return;
+      const pedidoId = pedido.pedidoId;
+      const nombre = pedido.items.find((i) => i.id === itemId)?.nombreProducto ?? 'el ítem';
+
+      const enviar = (version: number) =>
+        eliminarItem.mutate(
+          { pedidoId, itemId, version },
+          {
+            onSuccess: (response) => {
+              registrarVersion(pedidoId, response.data.version);
+              toast.success('Ítem eliminado');
+            },
+            onError: (error: any) => {
+              if (resolverConflicto(error, `Quitar ${nombre}`, enviar)) return;
+              const msg =
+                error?.response?.data?.message || 'Error al eliminar ítem';
+              toast.error(msg);
+            },
+          }
+        );
+      enviar(versionVista(pedidoId));
+    },
+    [pedido, eliminarItem, toast, registrarVersion, resolverConflicto, versionVista]
+  );
+
+  /** HU-110: Cambiar el paso de servicio desde el chip del ticket */
+  const handleCambiarTiempo = useCallback(
+    (itemId: string, tiempoServicio: TiempoServicio | null) => {
+      if (!pedido?.pedidoId) return;
+
+      cambiarTiempoServicio.mutate(
+        { pedidoId: pedido.pedidoId, itemId, tiempoServicio },
         {
-          onSuccess: () => {
-            setProductoSeleccionado(null);
-            setVarianteSeleccionadaId(undefined);
-          },
           onError: (error: any) => {
             const msg =
-              error?.response?.data?.message || 'Error al agregar producto';
+              error?.response?.data?.message || 'Error al cambiar el paso';
             toast.error(msg);
           },
         }
       );
     },
-    [pedido, agregarProducto, toast]
+    [pedido, cambiarTiempoServicio, toast]
   );
 
-  const handleModificarCantidad = useCallback(
-    (itemId: string, nuevaCantidad: number) => {
+  /** HU-120: Guardar la observación libre editada en el ticket */
+  const handleCambiarObservacion = useCallback(
+    (itemId: string, observacion: string | null) => {
+      if (!pedido?.pedidoId) return;
+      const pedidoId = pedido.pedidoId;
+      const nombre = pedido.items.find((i) => i.id === itemId)?.nombreProducto ?? 'el ítem';
+
+      const enviar = (version: number) =>
+        cambiarObservacion.mutate(
+          { pedidoId, itemId, observacion, version },
+          {
+            onSuccess: (response) => registrarVersion(pedidoId, response.data.version),
+            onError: (error: any) => {
+              if (resolverConflicto(error, `Cambiar la observación de ${nombre}`, enviar)) return;
+              const msg =
+                error?.response?.data?.message || 'Error al guardar la observación';
+              toast.error(msg);
+            },
+          }
+        );
+      enviar(versionVista(pedidoId));
+    },
+    [pedido, cambiarObservacion, toast, registrarVersion, resolverConflicto, versionVista]
+  );
+
+  /** HU-137: Marcar el pedido completo como urgente (sube sus comandas en cocina) */
+  const handleMarcarUrgente = useCallback(
+    (urgente: boolean) => {
       if (!pedido?.pedidoId) return;
 
-      modificarCantidad.mutate(
-        { pedidoId: pedido.pedidoId, itemId, cantidad: nuevaCantidad },
+      marcarUrgente.mutate(
+        { pedidoId: pedido.pedidoId, urgente },
         {
+          onSuccess: () => {
+            if (urgente) toast.warning(`Mesa ${pedido.numeroMesa} marcada urgente`);
+          },
           onError: (error: any) => {
             const msg =
-              error?.response?.data?.message || 'Error al modificar cantidad';
+              error?.response?.data?.message || 'Error al marcar la urgencia';
             toast.error(msg);
           },
         }
       );
     },
-    [pedido, modificarCantidad, toast]
+    [pedido, marcarUrgente, toast]
   );
 
-  const handleEliminarItem = useCallback(
-    (itemId: string) => {
+  /** HU-137: Marcar un ítem como urgente para su próximo envío */
+  const handleMarcarUrgenteItem = useCallback(
+    (itemId: string, urgente: boolean) => {
       if (!pedido?.pedidoId) return;
 
-      eliminarItem.mutate(
-        { pedidoId: pedido.pedidoId, itemId },
+      marcarUrgenteItem.mutate(
+        { pedidoId: pedido.pedidoId, itemId, urgente },
         {
-          onSuccess: () => {
-            toast.success('Ítem eliminado');
-          },
           onError: (error: any) => {
             const msg =
-              error?.response?.data?.message || 'Error al eliminar ítem';
+              error?.response?.data?.message || 'Error al marcar la urgencia';
             toast.error(msg);
           },
         }
       );
     },
-    [pedido, eliminarItem, toast]
+    [pedido, marcarUrgenteItem, toast]
   );
 
   const handleAplicarDescuento = useCallback(() => {
@@ -318,6 +553,14 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
       onSuccess: async (data) => {
         toast.success(`Comanda enviada (${data.cantidadItemsNuevos} nuevos)`);
 
+        // HU-138: Horario a informar al cliente del take away
+        if (data.horaPrometida) {
+          const hora = new Date(data.horaPrometida);
+          toast.info(
+            `Retiro prometido: ${String(hora.getHours()).padStart(2, '0')}:${String(hora.getMinutes()).padStart(2, '0')}`
+          );
+        }
+
         // Enviar bytes ESC/POS a la impresora
         const result = await imprimirEscPos(
           data.escPosBase64,
@@ -363,6 +606,35 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
     });
   }, [pedido, mesaId, reimprimirComanda, toast, numeroMesa]);
 
+  /**
+   * HU-110: Marchar el siguiente paso — libera los ítems retenidos
+   * (ej: postres) y los imprime con el aviso "MARCHAR" para cocina.
+   */
+  const handleMarchar = useCallback(() => {
+    const tiempo = pedido?.siguienteTiempo;
+    if (!pedido?.pedidoId || !tiempo) return;
+
+    marcharTiempo.mutate({ mesaId, tiempo }, {
+      onSuccess: async (data) => {
+        toast.success(`${TIEMPO_SERVICIO_LABELS[tiempo]} en marcha (${data.cantidadItemsNuevos} ítems)`);
+
+        const result = await imprimirEscPos(
+          data.escPosBase64,
+          `Marcha Mesa ${numeroMesa}`
+        );
+
+        if (!result.success) {
+          toast.error(`Error de impresora: ${result.message}`);
+        }
+      },
+      onError: (error: any) => {
+        const msg =
+          error?.response?.data?.message || 'Error al marchar el paso';
+        toast.error(msg);
+      },
+    });
+  }, [pedido, mesaId, marcharTiempo, toast, numeroMesa]);
+
   /**
    * Callback tras cierre exitoso de mesa:
    * cierra todos los modales y vuelve al salón.
@@ -416,6 +688,8 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
 
           {/* ── Panel Central: Grilla de Productos (~55%) ── */}
           <main className="flex-1 overflow-y-auto bg-neutral-900/30 flex flex-col">
+            {/* HU-164: ítems que no llegaron al backend antes de un corte */}
+            <BorradorRecuperadoAviso mesaId={mesaId} pedidoId={pedido?.pedidoId} version={pedido?.version} />
             <GrillaProductos
               productos={productosFiltrados}
               cargando={cargandoProductos}
@@ -435,13 +709,25 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
               onVolver={onCerrar}
               onModificarCantidad={handleModificarCantidad}
               onEliminarItem={handleEliminarItem}
+              onCambiarTiempo={handleCambiarTiempo}
+              onCambiarObservacion={handleCambiarObservacion}
+              onMarcarUrgente={handleMarcarUrgente}
+              onMarcarUrgenteItem={handleMarcarUrgenteItem}
+              esperaEstimada={esperaEstimada}
               onAplicarDescuento={handleAplicarDescuento}
               onCerrarMesa={handleCerrarMesa}
               onControlMesa={handleControlMesa}
               onMandarCocina={handleMandarCocina}
               onReimprimirComanda={handleReimprimirComanda}
+              onMarchar={handleMarchar}
+              onLinkPago={() => setMostrarLinkPago(true)}
+              onPagoACuenta={() => setMostrarPagoACuenta(true)}
+              onEntrega={() => setMostrarEntrega(true)}
+              onRepartidor={() => setMostrarRepartidor(true)}
+              onCliente={() => setMostrarCliente(true)}
               enviandoCocina={enviarComandaCocina.isPending}
               reimprimiendo={reimprimirComanda.isPending}
+              marchando={marcharTiempo.isPending}
             />
           </aside>
         </section>
@@ -460,6 +746,16 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
         />
       )}
 
+      {/* ── Modal: Armado de Combo ── */}
+      {comboSeleccionado && (
+        <ComboSelectorModal
+          combo={comboSeleccionado}
+          onConfirmar={handleConfirmarCombo}
+          onCerrar={() => setComboSeleccionado(null)}
+          enviando={agregarProducto.isPending}
+        />
+      )}
+
       {/* ── Modal: Configurar Producto (Observaciones + Extras) ── */}
       {productoSeleccionado && (
         <ConfigurarProductoModal
@@ -491,6 +787,63 @@ export default function PantallaPedido({ mesaId, onCerrar }: PantallaPedidoProps
         />
       )}
 
+      {/* ── Modal: Link de pago de delivery (HU-128) ── */}
+      {mostrarLinkPago && pedido && (
+        <LinkPagoModal
+          mesaId={mesaId}
+          numeroMesa={numeroMesa}
+          total={pedido.totalParcial}
+          onClose={() => setMostrarLinkPago(false)}
+        />
+      )}
+
+      {/* ── Modal: Pagos a cuenta de la mesa abierta (HU-160) ── */}
+      {mostrarPagoACuenta && pedido && (
+        <PagoACuentaModal
+          mesaId={mesaId}
+          pedido={pedido}
+          onClose={() => setMostrarPagoACuenta(false)}
+        />
+      )}
+
+      {/* ── Modal: Entrega de delivery (HU-139) ── */}
+      {mostrarEntrega && pedido && (
+        <EntregaDeliveryModal
+          pedido={pedido}
+          onClose={() => setMostrarEntrega(false)}
+        />
+      )}
+
+      {/* ── Modal: Repartidor del delivery (HU-140) ── */}
+      {mostrarRepartidor && pedido && (
+        <AsignarRepartidorModal
+          pedido={pedido}
+          onClose={() => setMostrarRepartidor(false)}
+        />
+      )}
+
+      {/* ── Modal: Cliente del pedido (HU-168) ── */}
+      {mostrarCliente && pedido && (
+        <ClientePedidoModal
+          pedido={pedido}
+          onClose={() => setMostrarCliente(false)}
+        />
+      )}
+
+      {/* ── Modal: Conflicto con otra terminal (HU-165) ── */}
+      {conflicto && (
+        <ConflictoPedidoModal
+          pedido={pedidoConflictoActualizado}
+          cambio={conflicto.cambio}
+          onDescartar={() => setConflicto(null)}
+          onAplicarIgual={() => {
+            const version = pedido ? versionVista(pedido.pedidoId) : undefined;
+            setConflicto(null);
+            if (version !== undefined) conflicto.reintentar(version);
+          }}
+        />
+      )}
+
       {/* ── Modal: Cierre de Mesa y Pago ── */}
       {mostrarCierre && pedido && (
         <CerrarMesaModal
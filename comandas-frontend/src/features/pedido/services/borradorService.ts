@@ -0,0 +1,98 @@
+/**
+ * HU-164: Borrador del pedido que se está tomando, para recuperarlo después
+ * de un corte.
+ *
+ * Cada ítem se anota en el borrador de la mesa antes de mandarlo al backend
+ * y se quita cuando el backend lo confirma. En el escritorio el borrador lo
+ * guarda la capa Rust en disco (sobrevive a un cierre de golpe o a un corte
+ * de luz); en el navegador se usa localStorage.
+ */
+
+import type { AgregarProductoRequest } from '../types';
+
+export interface ItemBorrador {
+  /** Generado al agregar, para quitarlo cuando el backend confirma */
+  id: string;
+  nombre: string;
+  request: AgregarProductoRequest;
+}
+
+export interface BorradorPedido {
+  pedidoId: string;
+  /** Segundos desde epoch del último cambio */
+  actualizado: number;
+  items: ItemBorrador[];
+}
+
+const CLAVE_LOCAL = 'foodflow.borradoresPedido';
+
+function esTauri(): boolean {
+  return typeof window !== 'undefined' && '__TAURI_INTERNALS__' in window;
+}
+
+async function invocar<T>(comando: string, args: Record<string, unknown>): Promise<T> {
+  const { invoke } = await import('@tauri-apps/api/core');
+  return invoke<T>(comando, args);
+}
+
+// ─── Fallback navegador ───────────────────────────────────────────────────────
+
+function leerLocal(): Record<string, BorradorPedido> {
+  try {
+    return JSON.parse(localStorage.getItem(CLAVE_LOCAL) ?? '{}');
+  } catch {
+    return {};
+  }
+}
+
+function escribirLocal(borradores: Record<string, BorradorPedido>): void {
+  localStorage.setItem(CLAVE_LOCAL, JSON.stringify(borradores));
+}
+
+// ─── API ──────────────────────────────────────────────────────────────────────
+
+export async function guardarItemBorrador(mesaId: string, pedidoId: string, item: ItemBorrador): Promise<void> {
+  if (esTauri()) {
+    await invocar('guardar_item_borrador', { mesaId, pedidoId, item });
+    return;
+  }
+  const borradores = leerLocal();
+  const actual = borradores[mesaId];
+  const items = actual && actual.pedidoId === pedidoId ? actual.items.filter((i) => i.id !== item.id) : [];
+  borradores[mesaId] = { pedidoId, actualizado: Math.floor(Date.now() / 1000), items: [...items, item] };
+  escribirLocal(borradores);
+}
+
+export async function quitarItemBorrador(mesaId: string, itemId: string): Promise<void> {
+  if (esTauri()) {
+    await invocar('quitar_item_borrador', { mesaId, itemId });
+    return;
+  }
+  const borradores = leerLocal();
+  const actual = borradores[mesaId];
+  if (!actual) return;
+  const items = actual.items.filter((i) => i.id !== itemId);
+  if (items.length === 0) {
+    delete borradores[mesaId];
+  } else {
+    borradores[mesaId] = { ...actual, actualizado: Math.floor(Date.now() / 1000), items };
+  }
+  escribirLocal(borradores);
+}
+
+export async function obtenerBorrador(mesaId: string): Promise<BorradorPedido | null> {
+  if (esTauri()) {
+    return invocar<BorradorPedido | null>('obtener_borrador', { mesaId });
+  }
+  return leerLocal()[mesaId] ?? null;
+}
+
+export async function descartarBorrador(mesaId: string): Promise<void> {
+  if (esTauri()) {
+    await invocar('descartar_borrador', { mesaId });
+    return;
+  }
+  const borradores = leerLocal();
+  delete borradores[mesaId];
+  escribirLocal(borradores);
+}
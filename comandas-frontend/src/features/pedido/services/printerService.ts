@@ -129,6 +129,32 @@ export async function imprimirEscPos(base64: string, label: string = 'Ticket'):
   return imprimirMockConsola(bytes, label);
 }
 
+/**
+ * Abre el cajón de dinero sin cobro de por medio (HU-169).
+ *
+ * La capa Rust valida con el backend el permiso ABRIR_CAJON del empleado
+ * identificado antes de mandar el pulso a la impresora.
+ *
+ * @param estacion Estación cuya impresora tiene el cajón (sin indicar, la de tickets)
+ */
+export async function abrirCajon(estacion?: string): Promise<PrintResult> {
+  if (!esTauri()) {
+    console.log('%c🔓 MOCK CAJÓN — apertura', 'font-size: 14px; font-weight: bold; color: #ff4444;');
+    return { success: true, mode: 'mock-console', message: 'Cajón abierto (mock)' };
+  }
+
+  try {
+    const { invoke } = await import('@tauri-apps/api/core');
+    const message = await invoke<string>('abrir_cajon', { estacion: estacion ?? null });
+    await logger.info(`[PrinterService] ${message}`);
+    return { success: true, mode: 'tauri', message };
+  } catch (error) {
+    const message = typeof error === 'string' ? error : 'No se pudo abrir el cajón';
+    await logger.error(`[PrinterService] Apertura de cajón rechazada: ${message}`);
+    return { success: false, mode: 'tauri', message };
+  }
+}
+
 /**
  * Descarga el buffer ESC/POS como archivo .bin para inspección.
  * Útil para debug del protocolo sin impresora física.
@@ -126,6 +126,8 @@ export interface EnviarComandaResponse {
   cantidadItemsNuevos: number;
   /** Cantidad total de ítems en el pedido */
   cantidadItemsTotal: number;
+  /** HU-138: Retiro prometido del take away (ISO 8601), null en otros canales */
+  horaPrometida: string | null;
 }
 
 // ─── Ticket de venta ESC/POS (HU-29) ─────────────────────────────────────────
@@ -10,7 +10,22 @@
  * @see backend: com.agustinpalma.comandas.application.dto
  */
 
-import type { EstadoPedido } from '../salon/types';
+import type { CanalVenta, EstadoPedido } from '../salon/types';
+import type { Alergeno } from '../catalogo/types';
+
+// ─── Pasos de servicio (HU-110) ───────────────────────────────────────────────
+
+/** Paso de servicio de un ítem, en el orden en que se marchan a cocina */
+export type TiempoServicio = 'ENTRADA' | 'PRINCIPAL' | 'POSTRE';
+
+export const TIEMPO_SERVICIO_LABELS: Record<TiempoServicio, string> = {
+  ENTRADA: 'Entrada',
+  PRINCIPAL: 'Principal',
+  POSTRE: 'Postre',
+};
+
+/** Orden de los pasos; sirve para rotar el chip del ticket */
+export const TIEMPOS_SERVICIO: TiempoServicio[] = ['ENTRADA', 'PRINCIPAL', 'POSTRE'];
 
 // ─── Items del pedido ─────────────────────────────────────────────────────────
 
@@ -26,6 +41,17 @@ export interface ExtraDetalle {
   precio: number;
 }
 
+/**
+ * Opción elegida para un componente de combo (HU-103).
+ * Refleja ComponenteComboDetalleDTO del backend.
+ */
+export interface ComponenteComboDetalle {
+  /** Nombre del componente (ej: "Bebida") */
+  componente: string;
+  productoId: string;
+  nombreProducto: string;
+}
+
 /**
  * Ítem de un pedido (snapshot inmutable).
  * Refleja ItemPedidoDTO anidado en AgregarProductoResponse.
@@ -85,6 +111,24 @@ export interface ItemDetalle {
 
   /** HU-29: true si el ítem fue agregado después del último envío a cocina */
   esNuevo: boolean;
+
+  /** HU-103: Opciones elegidas por componente si el ítem es un combo */
+  componentesCombo?: ComponenteComboDetalle[];
+
+  /** HU-110: Paso de servicio (null = sale con la próxima comanda) */
+  tiempoServicio: TiempoServicio | null;
+  /** HU-110: true si espera a que se marche su paso */
+  enEspera: boolean;
+  /** HU-110: true si cocina ya recibió alguna unidad (el paso no se puede cambiar) */
+  enviadoACocina: boolean;
+  /** HU-137: Sale destacado en su próxima comanda */
+  urgente: boolean;
+  /** HU-139: Costo de envío del delivery (no se edita ni se quita a mano) */
+  envio: boolean;
+  /** HU-147: Alergias declaradas para el comensal del ítem */
+  advertencias?: Alergeno[];
+  /** HU-148: Nombres de los ingredientes que el cliente pidió sacar */
+  ingredientesQuitados?: string[];
 }
 
 /**
@@ -122,6 +166,8 @@ export interface AjusteEconomico {
   ambito: 'ITEM' | 'TOTAL';
   descripcion: string;
   monto: number;
+  /** HU-122: Quién autorizó el descuento manual (null en promociones o si no se registró) */
+  autorizadoPor?: string | null;
 }
 
 // ─── Requests ─────────────────────────────────────────────────────────────────
@@ -143,6 +189,19 @@ export interface AgregarProductoRequest {
    * Si es null/undefined, el backend aplica normalización automática por discos.
    */
   varianteId?: string;
+  /**
+   * HU-103: Producto elegido para cada componente del combo, en el orden del combo.
+   * Puede omitirse si todos los componentes son fijos.
+   */
+  componentesComboIds?: string[];
+  /** HU-110: Paso de servicio del ítem (se puede cambiar después desde el ticket) */
+  tiempoServicio?: TiempoServicio;
+  /** HU-147: Alergias del comensal; el ítem sale resaltado en comanda y KDS */
+  advertencias?: Alergeno[];
+  /** HU-148: Insumos de la receta a sacar ("sin cebolla"); no se descuentan del stock */
+  ingredientesQuitados?: string[];
+  /** HU-164: Id del ítem en el borrador de la terminal; un reintento con el mismo id no duplica el ítem */
+  borradorId?: string;
 }
 
 /**
@@ -176,10 +235,14 @@ export interface DescuentoManualRequest {
   tipoDescuento: TipoDescuentoManual;
   /** Valor del descuento (porcentaje 0.01–100 o monto fijo positivo) */
   valor: number;
-  /** Motivo del descuento (ej: "Cliente frecuente", "Compensación por demora") */
-  razon?: string;
+  /** Motivo del descuento (ej: "Cliente frecuente", "Compensación por demora"). Obligatorio (HU-122) */
+  razon: string;
   /** ID del usuario que aplica el descuento (auditoría) */
   usuarioId: string;
+  /** HU-122: Encargado que autoriza un descuento por encima del tope del mozo */
+  autorizanteId?: string;
+  /** HU-122: PIN del encargado (solo junto con autorizanteId) */
+  pin?: string;
 }
 
 // ─── Responses ────────────────────────────────────────────────────────────────
@@ -200,6 +263,8 @@ export interface AgregarProductoResponse {
   total: number;
   /** ISO 8601 datetime */
   fechaApertura: string;
+  /** HU-165: Versión del pedido después del cambio */
+  version: number;
 }
 
 /**
@@ -225,6 +290,36 @@ export interface DetallePedidoResponse {
   totalParcial: number;
   /** Narrativa económica: cada ajuste explica un descuento concreto */
   ajustesEconomicos: AjusteEconomico[];
+  /** HU-108: Canal de venta elegido al abrir la mesa */
+  canal: CanalVenta;
+  /** HU-108: Lista de precios usada (null = precio de catálogo) */
+  nombreListaPrecios: string | null;
+  /** HU-110: Paso liberado a cocina (null si todavía no se envió ningún paso) */
+  tiempoEnMarcha: TiempoServicio | null;
+  /** HU-110: Próximo paso a marchar (null si no hay ítems retenidos) */
+  siguienteTiempo: TiempoServicio | null;
+  /** HU-137: Pedido marcado urgente para cocina */
+  urgente: boolean;
+  /** HU-138: Retiro prometido al cliente (ISO 8601), se fija al primer envío a cocina */
+  horaPrometida: string | null;
+  /** HU-139: Dirección de entrega del delivery (null si no se cargó) */
+  direccionEntrega: string | null;
+  /** HU-139: Zona de reparto de la entrega (null si no se cargó) */
+  zonaDeliveryId: string | null;
+  /** HU-140: Repartidor asignado (null si no se asignó) */
+  repartidorId: string | null;
+  /** HU-140: Salida del repartidor (ISO 8601); ya no se cambia el repartidor */
+  salidaReparto: string | null;
+  /** HU-142: Seña de la reserva ya descontada de totalParcial (0 = sin seña) */
+  montoSenia: number;
+  /** HU-160: Lo que ya pagaron a cuenta con la mesa abierta (0 = nadie pagó) */
+  pagadoACuenta: number;
+  /** HU-160: Lo que falta cobrar (totalParcial - pagadoACuenta); negativo si se quitaron ítems ya pagados */
+  saldoPendiente: number;
+  /** HU-165: Sube con cada cambio; se manda en X-Pedido-Version para detectar ediciones de otra terminal */
+  version: number;
+  /** HU-168: Cliente de la ficha vinculado (null si no se vinculó) */
+  clienteId: string | null;
 }
 
 /**
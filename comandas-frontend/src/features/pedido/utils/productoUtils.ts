@@ -54,3 +54,21 @@ export function permiteAbrirModal(
 
   return true;
 }
+
+// ─── nombreDeGrupo ─────────────────────────────────────────────────────────────
+
+/**
+ * HU-109: Nombre a mostrar en la tarjeta de un grupo de variantes.
+ *
+ * Si el representante tiene etiqueta y su nombre termina con ella
+ * (ej: "Pizza Muzzarella Chica" con etiqueta "Chica"), la tarjeta
+ * muestra el nombre sin el tamaño: el tamaño se elige en el selector.
+ */
+export function nombreDeGrupo(producto: ProductoResponse): string {
+  const etiqueta = producto.etiquetaVariante?.trim();
+  const nombre = producto.nombre.trim();
+  if (!etiqueta || nombre.length <= etiqueta.length) return nombre;
+
+  const sufijo = ` ${etiqueta}`.toLowerCase();
+  return nombre.toLowerCase().endsWith(sufijo) ? nombre.slice(0, -sufijo.length) : nombre;
+}
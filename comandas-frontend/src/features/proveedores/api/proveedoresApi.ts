@@ -0,0 +1,51 @@
+import apiClient from '../../../lib/apiClient';
+import type {
+  CuentaProveedor,
+  FacturaProveedor,
+  PagoProveedorRequest,
+  Proveedor,
+  ProveedorRequest,
+  SaldoProveedor,
+} from '../types';
+
+/**
+ * API client de la cuenta corriente de proveedores (HU-131).
+ * Consume /api/proveedores de ProveedorController.
+ */
+export const proveedoresApi = {
+  listar: async (): Promise<Proveedor[]> => {
+    const response = await apiClient.get<Proveedor[]>('/proveedores');
+    return response.data;
+  },
+
+  crear: async (request: ProveedorRequest): Promise<Proveedor> => {
+    const response = await apiClient.post<Proveedor>('/proveedores', request);
+    return response.data;
+  },
+
+  actualizar: async (proveedorId: string, request: ProveedorRequest): Promise<Proveedor> => {
+    const response = await apiClient.put<Proveedor>(`/proveedores/${proveedorId}`, request);
+    return response.data;
+  },
+
+  listarSaldos: async (): Promise<SaldoProveedor[]> => {
+    const response = await apiClient.get<SaldoProveedor[]>('/proveedores/saldos');
+    return response.data;
+  },
+
+  consultarCuenta: async (proveedorId: string): Promise<CuentaProveedor> => {
+    const response = await apiClient.get<CuentaProveedor>(`/proveedores/${proveedorId}/cuenta`);
+    return response.data;
+  },
+
+  registrarPago: async (facturaId: string, request: PagoProveedorRequest): Promise<FacturaProveedor> => {
+    const response = await apiClient.post<FacturaProveedor>(`/proveedores/facturas/${facturaId}/pagos`, request);
+    return response.data;
+  },
+
+  /** @param dias anticipación del aviso (0 a 60) */
+  consultarVencimientos: async (dias: number): Promise<FacturaProveedor[]> => {
+    const response = await apiClient.get<FacturaProveedor[]>('/proveedores/vencimientos', { params: { dias } });
+    return response.data;
+  },
+};
@@ -0,0 +1,44 @@
+import { useEffect, useRef } from 'react';
+import { useVencimientosProveedores } from '../hooks/useProveedores';
+import useToast from '../../../hooks/useToast';
+import type { FacturaProveedor } from '../types';
+
+/** Una factura se vuelve a recordar al día siguiente, mientras siga impaga */
+const claveAviso = (f: FacturaProveedor) => `${f.id}|${new Date().toDateString()}`;
+
+function mensajeVencimiento(f: FacturaProveedor): string {
+  const factura = f.numero ? `Factura ${f.numero} de ${f.nombreProveedor}` : `Factura de ${f.nombreProveedor}`;
+  const saldo = f.saldo.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+  if (f.diasParaVencer < 0) {
+    const dias = -f.diasParaVencer;
+    return `${factura} vencida hace ${dias} ${dias === 1 ? 'día' : 'días'} — saldo $ ${saldo}`;
+  }
+  if (f.diasParaVencer === 0) return `${factura} vence hoy — saldo $ ${saldo}`;
+  return `${factura} vence en ${f.diasParaVencer} ${f.diasParaVencer === 1 ? 'día' : 'días'} — saldo $ ${saldo}`;
+}
+
+/**
+ * Recordatorio de vencimientos a proveedores (HU-131).
+ *
+ * Componente sin UI montado en el layout principal. Avisa con un toast
+ * por cada factura impaga vencida o que vence en los próximos días, una
+ * vez por día: la deuda no se resuelve sola, pero tampoco hace falta
+ * insistir en cada refresco.
+ */
+export default function AlertaVencimientosProveedores() {
+  const { data } = useVencimientosProveedores();
+  const toast = useToast();
+  const avisadas = useRef<Set<string>>(new Set());
+
+  useEffect(() => {
+    if (!data) return;
+
+    const nuevas = data.filter((f) => !avisadas.current.has(claveAviso(f)));
+    nuevas.forEach((f) => {
+      avisadas.current.add(claveAviso(f));
+      toast.warning(mensajeVencimiento(f), 10000);
+    });
+  }, [data, toast]);
+
+  return null;
+}
@@ -0,0 +1,150 @@
+import { useState } from 'react';
+import { X, Banknote, Loader2 } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useRegistrarPagoProveedor } from '../hooks/useProveedores';
+import { MEDIO_PAGO_PROVEEDOR_LABELS, type FacturaProveedor, type MedioPagoProveedor } from '../types';
+
+interface PagoFacturaModalProps {
+  factura: FacturaProveedor;
+  onClose: () => void;
+}
+
+const inputClass =
+  'min-h-[44px] w-full px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none';
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+/**
+ * Pago total o parcial de una factura de proveedor (HU-131).
+ * Propone el saldo pendiente; si sale de la caja, registra el egreso.
+ */
+export default function PagoFacturaModal({ factura, onClose }: PagoFacturaModalProps) {
+  const toast = useToast();
+  const registrar = useRegistrarPagoProveedor();
+
+  const [monto, setMonto] = useState(String(factura.saldo).replace('.', ','));
+  const [medioPago, setMedioPago] = useState<MedioPagoProveedor>('TRANSFERENCIA');
+  const [pagadoConCaja, setPagadoConCaja] = useState(false);
+
+  const montoNumero = Number(monto.replace(',', '.'));
+  const puedeGuardar = montoNumero > 0 && montoNumero <= factura.saldo && !registrar.isPending;
+
+  const handleGuardar = () => {
+    registrar.mutate(
+      {
+        facturaId: factura.id,
+        request: {
+          monto: montoNumero,
+          medioPago,
+          pagadoConCaja: medioPago === 'EFECTIVO' && pagadoConCaja,
+        },
+      },
+      {
+        onSuccess: (actualizada) => {
+          toast.success(actualizada.estado === 'PAGADA' ? 'Factura saldada' : 'Pago registrado');
+          onClose();
+        },
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo registrar el pago'),
+      }
+    );
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div className="fixed inset-0 z-[95] bg-black/60 animate-backdrop-in" onClick={onClose} />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[100] flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="bg-neutral-900 border border-gray-800 rounded-xl w-full max-w-md flex flex-col pointer-events-auto animate-modal-in"
+          onClick={(e) => e.stopPropagation()}
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-gray-800">
+            <div className="flex items-center gap-3">
+              <Banknote size={20} className="text-red-400" />
+              <div>
+                <h2 className="text-lg font-semibold text-text-primary">Registrar pago</h2>
+                <p className="text-xs text-gray-500">
+                  {factura.nombreProveedor}
+                  {factura.numero && ` · Factura ${factura.numero}`} · saldo $ {fmt(factura.saldo)}
+                </p>
+              </div>
+            </div>
+            <button
+              onClick={onClose}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+            >
+              <X size={18} />
+            </button>
+          </div>
+
+          {/* Body */}
+          <div className="px-6 py-5 space-y-4">
+            <div className="grid grid-cols-2 gap-3">
+              <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                Monto
+                <input
+                  type="text"
+                  inputMode="decimal"
+                  value={monto}
+                  autoFocus
+                  onChange={(e) => setMonto(e.target.value)}
+                  className={`${inputClass} font-mono`}
+                />
+              </label>
+              <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                Medio de pago
+                <select
+                  value={medioPago}
+                  onChange={(e) => setMedioPago(e.target.value as MedioPagoProveedor)}
+                  className={inputClass}
+                >
+                  {(Object.keys(MEDIO_PAGO_PROVEEDOR_LABELS) as MedioPagoProveedor[]).map((m) => (
+                    <option key={m} value={m}>
+                      {MEDIO_PAGO_PROVEEDOR_LABELS[m]}
+                    </option>
+                  ))}
+                </select>
+              </label>
+            </div>
+
+            {montoNumero > factura.saldo && (
+              <p className="text-xs text-red-400">El pago no puede superar el saldo pendiente.</p>
+            )}
+
+            {medioPago === 'EFECTIVO' && (
+              <label className="flex items-center gap-2 text-sm text-text-secondary">
+                <input
+                  type="checkbox"
+                  checked={pagadoConCaja}
+                  onChange={(e) => setPagadoConCaja(e.target.checked)}
+                  className="accent-red-600"
+                />
+                Se pagó con la plata de la caja (registra el egreso)
+              </label>
+            )}
+          </div>
+
+          {/* Footer */}
+          <div className="flex justify-end gap-3 px-6 py-4 border-t border-gray-800">
+            <button onClick={onClose} className="btn-secondary text-sm !min-h-[42px] px-5">
+              Cancelar
+            </button>
+            <button
+              onClick={handleGuardar}
+              disabled={!puedeGuardar}
+              className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+            >
+              {registrar.isPending && <Loader2 size={16} className="animate-spin" />}
+              Registrar pago
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
@@ -0,0 +1,185 @@
+import { useState } from 'react';
+import { X, Truck, Loader2 } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useActualizarProveedor, useCrearProveedor } from '../hooks/useProveedores';
+import type { Proveedor } from '../types';
+
+interface ProveedorModalProps {
+  /** null = alta de un proveedor nuevo */
+  proveedor: Proveedor | null;
+  onClose: () => void;
+}
+
+const inputClass =
+  'min-h-[44px] w-full px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none';
+
+/**
+ * Alta y edición de un proveedor (HU-131).
+ *
+ * Un proveedor no se borra: se desactiva para que no se ofrezca en compras
+ * nuevas, pero conserva su cuenta corriente.
+ */
+export default function ProveedorModal({ proveedor, onClose }: ProveedorModalProps) {
+  const toast = useToast();
+  const crear = useCrearProveedor();
+  const actualizar = useActualizarProveedor();
+
+  const [nombre, setNombre] = useState(proveedor?.nombre ?? '');
+  const [cuit, setCuit] = useState(proveedor?.cuit ?? '');
+  const [telefono, setTelefono] = useState(proveedor?.telefono ?? '');
+  const [email, setEmail] = useState(proveedor?.email ?? '');
+  const [plazo, setPlazo] = useState(String(proveedor?.plazoPagoDias ?? 30));
+  const [activo, setActivo] = useState(proveedor?.activo ?? true);
+
+  const guardando = crear.isPending || actualizar.isPending;
+  const plazoNumero = Number(plazo);
+  const puedeGuardar =
+    nombre.trim() !== '' && Number.isInteger(plazoNumero) && plazoNumero >= 0 && plazoNumero <= 180 && !guardando;
+
+  const handleGuardar = () => {
+    const request = {
+      nombre: nombre.trim(),
+      cuit: cuit.trim() || undefined,
+      telefono: telefono.trim() || undefined,
+      email: email.trim() || undefined,
+      plazoPagoDias: plazoNumero,
+      activo,
+    };
+    const opciones = {
+      onSuccess: () => {
+        toast.success(proveedor ? 'Proveedor actualizado' : 'Proveedor creado');
+        onClose();
+      },
+      onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo guardar el proveedor'),
+    };
+
+    if (proveedor) {
+      actualizar.mutate({ proveedorId: proveedor.id, request }, opciones);
+    } else {
+      crear.mutate(request, opciones);
+    }
+  };
+
+  return (
+    <>
+      {/* Backdrop */}
+      <div className="fixed inset-0 z-[95] bg-black/60 animate-backdrop-in" onClick={onClose} />
+
+      {/* Modal */}
+      <div className="fixed inset-0 z-[100] flex items-center justify-center p-4 pointer-events-none">
+        <div
+          className="bg-neutral-900 border border-gray-800 rounded-xl w-full max-w-md flex flex-col pointer-events-auto animate-modal-in"
+          onClick={(e) => e.stopPropagation()}
+        >
+          {/* Header */}
+          <div className="flex items-center justify-between px-6 py-4 border-b border-gray-800">
+            <div className="flex items-center gap-3">
+              <Truck size={20} className="text-red-400" />
+              <h2 className="text-lg font-semibold text-text-primary">
+                {proveedor ? 'Editar proveedor' : 'Nuevo proveedor'}
+              </h2>
+            </div>
+            <button
+              onClick={onClose}
+              className="p-2 rounded-lg hover:bg-gray-800 text-gray-400 transition-colors"
+            >
+              <X size={18} />
+            </button>
+          </div>
+
+          {/* Body */}
+          <div className="px-6 py-5 space-y-4">
+            <label className="flex flex-col gap-1 text-sm text-text-secondary">
+              Nombre
+              <input
+                type="text"
+                value={nombre}
+                maxLength={100}
+                autoFocus
+                onChange={(e) => setNombre(e.target.value)}
+                className={inputClass}
+              />
+            </label>
+
+            <div className="grid grid-cols-2 gap-3">
+              <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                CUIT
+                <input
+                  type="text"
+                  value={cuit}
+                  placeholder="Opcional"
+                  onChange={(e) => setCuit(e.target.value)}
+                  className={`${inputClass} font-mono`}
+                />
+              </label>
+              <label className="flex flex-col gap-1 text-sm text-text-secondary">
+                Teléfono
+                <input
+                  type="tel"
+                  value={telefono}
+                  maxLength={30}
+                  onChange={(e) => setTelefono(e.target.value)}
+                  className={inputClass}
+                />
+              </label>
+            </div>
+
+            <label className="flex flex-col gap-1 text-sm text-text-secondary">
+              Email
+              <input
+                type="email"
+                value={email}
+                maxLength={100}
+                placeholder="Opcional, para enviarle órdenes de compra"
+                onChange={(e) => setEmail(e.target.value)}
+                className={inputClass}
+              />
+            </label>
+
+            <label className="flex flex-col gap-1 text-sm text-text-secondary">
+              Plazo de pago (días)
+              <input
+                type="number"
+                min={0}
+                max={180}
+                value={plazo}
+                onChange={(e) => setPlazo(e.target.value)}
+                className={`${inputClass} font-mono`}
+              />
+              <span className="text-xs text-gray-500">
+                Define el vencimiento de las facturas cuando la compra no indica otro.
+              </span>
+            </label>
+
+            {proveedor && (
+              <label className="flex items-center gap-2 text-sm text-text-secondary">
+                <input
+                  type="checkbox"
+                  checked={activo}
+                  onChange={(e) => setActivo(e.target.checked)}
+                  className="accent-red-600"
+                />
+                Activo (se ofrece al registrar compras)
+              </label>
+            )}
+          </div>
+
+          {/* Footer */}
+          <div className="flex justify-end gap-3 px-6 py-4 border-t border-gray-800">
+            <button onClick={onClose} className="btn-secondary text-sm !min-h-[42px] px-5">
+              Cancelar
+            </button>
+            <button
+              onClick={handleGuardar}
+              disabled={!puedeGuardar}
+              className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+            >
+              {guardando && <Loader2 size={16} className="animate-spin" />}
+              Guardar
+            </button>
+          </div>
+        </div>
+      </div>
+    </>
+  );
+}
@@ -0,0 +1,254 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { AlertTriangle, ArrowLeft, Banknote, Loader2, Pencil, Plus, Truck } from 'lucide-react';
+import { useCuentaProveedor, useSaldosProveedores } from '../hooks/useProveedores';
+import {
+  ESTADO_FACTURA_LABELS,
+  MEDIO_PAGO_PROVEEDOR_LABELS,
+  type FacturaProveedor,
+  type Proveedor,
+} from '../types';
+import ProveedorModal from './ProveedorModal';
+import PagoFacturaModal from './PagoFacturaModal';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function fechaCorta(iso: string): string {
+  const [y, m, d] = iso.slice(0, 10).split('-');
+  return `${d}/${m}/${y}`;
+}
+
+function claseVencimiento(f: FacturaProveedor): string {
+  if (f.estado === 'PAGADA') return 'text-gray-500';
+  if (f.vencida) return 'text-red-400';
+  if (f.diasParaVencer <= 3) return 'text-amber-400';
+  return 'text-gray-300';
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Cuenta corriente de proveedores (HU-131).
+ *
+ * A la izquierda el saldo de cada proveedor, primero los que tienen deuda
+ * vencida. Al elegir uno se ven sus facturas (las que generaron las compras
+ * a cuenta corriente) y se asientan los pagos, totales o parciales.
+ */
+export default function ProveedoresPage() {
+  const { data: saldos = [], isLoading, isError } = useSaldosProveedores();
+  const [seleccionadoId, setSeleccionadoId] = useState<string | null>(null);
+  const [editando, setEditando] = useState<Proveedor | null | undefined>(undefined);
+  const [pagando, setPagando] = useState<FacturaProveedor | null>(null);
+  const { data: cuenta, isLoading: cargandoCuenta } = useCuentaProveedor(seleccionadoId);
+
+  const totalDeuda = saldos.reduce((acc, s) => acc + s.saldo, 0);
+  const totalVencido = saldos.reduce((acc, s) => acc + s.saldoVencido, 0);
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/gastos"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Proveedores</h1>
+            <p className="text-sm text-gray-500">Cuenta corriente: facturas a pagar y vencimientos</p>
+          </div>
+          <button
+            type="button"
+            onClick={() => setEditando(null)}
+            className="ml-auto h-9 px-3 rounded-lg bg-red-600 hover:bg-red-700 text-sm text-white flex items-center gap-1.5"
+          >
+            <Plus size={14} />
+            Nuevo proveedor
+          </button>
+        </header>
+
+        <div className="grid grid-cols-2 sm:grid-cols-4 gap-3">
+          {[
+            { label: 'Deuda total', valor: `$ ${fmt(totalDeuda)}`, color: 'text-gray-100' },
+            {
+              label: 'Vencido',
+              valor: `$ ${fmt(totalVencido)}`,
+              color: totalVencido > 0 ? 'text-red-400' : 'text-gray-100',
+            },
+          ].map((card) => (
+            <div key={card.label} className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 px-4 py-3">
+              <p className="text-[10px] uppercase tracking-wider text-gray-600">{card.label}</p>
+              <p className={`text-lg font-mono tabular-nums ${card.color}`}>{card.valor}</p>
+            </div>
+          ))}
+        </div>
+
+        <div className="grid grid-cols-1 xl:grid-cols-12 gap-5">
+          {/* Saldos por proveedor */}
+          <div className="xl:col-span-5">
+            {isLoading ? (
+              <div className="flex items-center gap-2 text-sm text-gray-500">
+                <Loader2 size={16} className="animate-spin" /> Cargando proveedores...
+              </div>
+            ) : isError ? (
+              <p className="text-sm text-red-400">No se pudieron cargar los proveedores.</p>
+            ) : saldos.length === 0 ? (
+              <div className="flex items-center gap-2 text-xs text-gray-500">
+                <Truck size={14} />
+                Todavía no hay proveedores cargados.
+              </div>
+            ) : (
+              <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+                <table className="w-full text-sm">
+                  <thead>
+                    <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                      <th className="text-left font-medium px-4 py-2">Proveedor</th>
+                      <th className="text-right font-medium px-3 py-2">Saldo</th>
+                      <th className="text-right font-medium px-3 py-2">Vencido</th>
+                      <th className="text-right font-medium px-4 py-2">Próx. vto.</th>
+                    </tr>
+                  </thead>
+                  <tbody className="divide-y divide-neutral-800 text-gray-300">
+                    {saldos.map((s) => (
+                      <tr
+                        key={s.proveedor.id}
+                        onClick={() => setSeleccionadoId(s.proveedor.id)}
+                        className={`cursor-pointer hover:bg-neutral-800/50 ${
+                          seleccionadoId === s.proveedor.id ? 'bg-neutral-800/70' : ''
+                        }`}
+                      >
+                        <td className="px-4 py-2">
+                          <span className={s.proveedor.activo ? 'text-gray-100' : 'text-gray-500'}>
+                            {s.proveedor.nombre}
+                          </span>
+                          {!s.proveedor.activo && <span className="ml-2 text-[10px] text-gray-600">(inactivo)</span>}
+                          {s.facturasImpagas > 0 && (
+                            <p className="text-xs text-gray-500">
+                              {s.facturasImpagas} {s.facturasImpagas === 1 ? 'factura impaga' : 'facturas impagas'}
+                            </p>
+                          )}
+                        </td>
+                        <td className="text-right px-3 py-2 font-mono tabular-nums">$ {fmt(s.saldo)}</td>
+                        <td
+                          className={`text-right px-3 py-2 font-mono tabular-nums ${
+                            s.saldoVencido > 0 ? 'text-red-400' : 'text-gray-600'
+                          }`}
+                        >
+                          $ {fmt(s.saldoVencido)}
+                        </td>
+                        <td className="text-right px-4 py-2 font-mono text-xs text-gray-400">
+                          {s.proximoVencimiento ? fechaCorta(s.proximoVencimiento) : '—'}
+                        </td>
+                      </tr>
+                    ))}
+                  </tbody>
+                </table>
+              </div>
+            )}
+          </div>
+
+          {/* Cuenta del proveedor elegido */}
+          <div className="xl:col-span-7">
+            {!seleccionadoId ? (
+              <p className="text-xs text-gray-500">Elegí un proveedor para ver sus facturas.</p>
+            ) : cargandoCuenta || !cuenta ? (
+              <div className="flex items-center gap-2 text-sm text-gray-500">
+                <Loader2 size={16} className="animate-spin" /> Cargando cuenta...
+              </div>
+            ) : (
+              <div className="space-y-3">
+                <div className="flex items-center gap-3">
+                  <div>
+                    <h2 className="text-base font-semibold text-gray-100">{cuenta.saldo.proveedor.nombre}</h2>
+                    <p className="text-xs text-gray-500">
+                      {[
+                        cuenta.saldo.proveedor.cuit && `CUIT ${cuenta.saldo.proveedor.cuit}`,
+                        cuenta.saldo.proveedor.telefono,
+                        `Plazo ${cuenta.saldo.proveedor.plazoPagoDias} días`,
+                      ]
+                        .filter(Boolean)
+                        .join(' · ')}
+                    </p>
+                  </div>
+                  <button
+                    type="button"
+                    onClick={() => setEditando(cuenta.saldo.proveedor)}
+                    className="ml-auto h-8 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-xs text-gray-300 flex items-center gap-1.5"
+                  >
+                    <Pencil size={12} />
+                    Editar
+                  </button>
+                </div>
+
+                {cuenta.facturas.length === 0 ? (
+                  <p className="text-xs text-gray-500">
+                    Sin facturas. Se generan al registrar una compra a cuenta corriente en Gastos.
+                  </p>
+                ) : (
+                  <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+                    <table className="w-full text-sm">
+                      <thead>
+                        <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                          <th className="text-left font-medium px-4 py-2">Factura</th>
+                          <th className="text-left font-medium px-3 py-2">Vencimiento</th>
+                          <th className="text-right font-medium px-3 py-2">Monto</th>
+                          <th className="text-right font-medium px-3 py-2">Saldo</th>
+                          <th className="text-right font-medium px-4 py-2" />
+                        </tr>
+                      </thead>
+                      <tbody className="divide-y divide-neutral-800 text-gray-300">
+                        {cuenta.facturas.map((f) => (
+                          <tr key={f.id} className="align-top">
+                            <td className="px-4 py-2">
+                              <span className="text-gray-100">{f.numero ?? 'Sin número'}</span>
+                              <span className="text-xs text-gray-500"> · {fechaCorta(f.fechaEmision)}</span>
+                              <p className="text-xs text-gray-500">{ESTADO_FACTURA_LABELS[f.estado]}</p>
+                              {f.pagos.map((p, idx) => (
+                                <p key={idx} className="text-xs text-gray-600">
+                                  {fechaCorta(p.fecha)} · {MEDIO_PAGO_PROVEEDOR_LABELS[p.medioPago]}
+                                  {p.pagadoConCaja && ' (caja)'} · $ {fmt(p.monto)}
+                                </p>
+                              ))}
+                            </td>
+                            <td className={`px-3 py-2 font-mono text-xs ${claseVencimiento(f)}`}>
+                              <span className="inline-flex items-center gap-1">
+                                {f.vencida && <AlertTriangle size={12} />}
+                                {fechaCorta(f.fechaVencimiento)}
+                              </span>
+                            </td>
+                            <td className="text-right px-3 py-2 font-mono tabular-nums">$ {fmt(f.monto)}</td>
+                            <td className="text-right px-3 py-2 font-mono tabular-nums">$ {fmt(f.saldo)}</td>
+                            <td className="text-right px-4 py-2">
+                              {f.estado !== 'PAGADA' && (
+                                <button
+                                  type="button"
+                                  onClick={() => setPagando(f)}
+                                  className="inline-flex items-center gap-1 text-xs text-red-400 hover:text-red-300"
+                                >
+                                  <Banknote size={14} />
+                                  Pagar
+                                </button>
+                              )}
+                            </td>
+                          </tr>
+                        ))}
+                      </tbody>
+                    </table>
+                  </div>
+                )}
+              </div>
+            )}
+          </div>
+        </div>
+      </div>
+
+      {editando !== undefined && <ProveedorModal proveedor={editando} onClose={() => setEditando(undefined)} />}
+      {pagando && <PagoFacturaModal factura={pagando} onClose={() => setPagando(null)} />}
+    </section>
+  );
+}
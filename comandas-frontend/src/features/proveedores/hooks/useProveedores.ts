@@ -0,0 +1,114 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { proveedoresApi } from '../api/proveedoresApi';
+import type {
+  CuentaProveedor,
+  FacturaProveedor,
+  PagoProveedorRequest,
+  Proveedor,
+  ProveedorRequest,
+  SaldoProveedor,
+} from '../types';
+
+export const proveedoresKeys = {
+  lista: ['proveedores'] as const,
+  saldos: ['proveedores', 'saldos'] as const,
+  cuenta: (proveedorId: string) => ['proveedores', 'cuenta', proveedorId] as const,
+  vencimientos: (dias: number) => ['proveedores', 'vencimientos', dias] as const,
+};
+
+/** Anticipación con la que se avisan los vencimientos */
+export const DIAS_AVISO_VENCIMIENTO = 3;
+
+/**
+ * Proveedores del local (activos e inactivos), por nombre.
+ *
+ * queryKey: ['proveedores']
+ */
+export function useProveedores() {
+  return useQuery<Proveedor[]>({
+    queryKey: proveedoresKeys.lista,
+    queryFn: () => proveedoresApi.listar(),
+  });
+}
+
+/**
+ * Saldo de cada proveedor, primero los que tienen deuda vencida.
+ *
+ * queryKey: ['proveedores', 'saldos']
+ */
+export function useSaldosProveedores() {
+  return useQuery<SaldoProveedor[]>({
+    queryKey: proveedoresKeys.saldos,
+    queryFn: () => proveedoresApi.listarSaldos(),
+  });
+}
+
+/**
+ * queryKey: ['proveedores', 'cuenta', proveedorId]
+ */
+export function useCuentaProveedor(proveedorId: string | null) {
+  return useQuery<CuentaProveedor>({
+    queryKey: proveedoresKeys.cuenta(proveedorId ?? ''),
+    queryFn: () => proveedoresApi.consultarCuenta(proveedorId!),
+    enabled: !!proveedorId,
+  });
+}
+
+/**
+ * Facturas vencidas o por vencer, base del recordatorio.
+ * Polling cada 5 minutos: los vencimientos son por día, no hace falta más.
+ *
+ * queryKey: ['proveedores', 'vencimientos', dias]
+ */
+export function useVencimientosProveedores(dias: number = DIAS_AVISO_VENCIMIENTO) {
+  return useQuery<FacturaProveedor[]>({
+    queryKey: proveedoresKeys.vencimientos(dias),
+    queryFn: () => proveedoresApi.consultarVencimientos(dias),
+    refetchInterval: 300_000,
+  });
+}
+
+export function useCrearProveedor() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (request: ProveedorRequest) => proveedoresApi.crear(request),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['proveedores'], exact: false });
+    },
+  });
+}
+
+export function useActualizarProveedor() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ proveedorId, request }: { proveedorId: string; request: ProveedorRequest }) =>
+      proveedoresApi.actualizar(proveedorId, request),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['proveedores'], exact: false });
+    },
+  });
+}
+
+/**
+ * Un pago con la plata de la caja registra un egreso, así que refresca también la caja.
+ */
+export function useRegistrarPagoProveedor() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ facturaId, request }: { facturaId: string; request: PagoProveedorRequest }) =>
+      proveedoresApi.registrarPago(facturaId, request),
+    onSuccess: (_factura, { request }) => {
+      queryClient.invalidateQueries({ queryKey: ['proveedores'], exact: false });
+      if (request.pagadoConCaja) {
+        queryClient.invalidateQueries({ queryKey: ['reporte-caja'], exact: false });
+        queryClient.invalidateQueries({ queryKey: ['turnos-caja'], exact: false });
+      }
+    },
+    onError: (error: unknown) => {
+      console.error('[useRegistrarPagoProveedor] Error al registrar pago:', error);
+    },
+  });
+}
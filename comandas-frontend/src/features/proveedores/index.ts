@@ -0,0 +1,40 @@
+/**
+ * Módulo Proveedores — cuenta corriente con proveedores: facturas a pagar
+ * que generan las compras, pagos parciales y recordatorio de vencimientos
+ * (HU-131).
+ *
+ * @example
+ * import { ProveedoresPage, useSaldosProveedores } from '@/features/proveedores';
+ */
+
+// Tipos
+export type {
+  Proveedor,
+  ProveedorRequest,
+  EstadoFacturaProveedor,
+  MedioPagoProveedor,
+  PagoProveedor,
+  FacturaProveedor,
+  SaldoProveedor,
+  CuentaProveedor,
+  PagoProveedorRequest,
+} from './types';
+export { ESTADO_FACTURA_LABELS, MEDIO_PAGO_PROVEEDOR_LABELS } from './types';
+
+// Hooks
+export {
+  useProveedores,
+  useSaldosProveedores,
+  useCuentaProveedor,
+  useVencimientosProveedores,
+  useCrearProveedor,
+  useActualizarProveedor,
+  useRegistrarPagoProveedor,
+} from './hooks/useProveedores';
+
+// Componentes
+export { default as ProveedoresPage } from './components/ProveedoresPage';
+export { default as AlertaVencimientosProveedores } from './components/AlertaVencimientosProveedores';
+
+// API
+export { proveedoresApi } from './api/proveedoresApi';
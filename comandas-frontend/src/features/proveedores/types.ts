@@ -0,0 +1,91 @@
+// ─── Cuenta corriente de proveedores (HU-131) ────────────────────────────────
+
+export interface Proveedor {
+  id: string;
+  nombre: string;
+  /** Formateado XX-XXXXXXXX-X, null si no se cargó */
+  cuit: string | null;
+  telefono: string | null;
+  /** HU-134: a donde se envían las órdenes de compra */
+  email: string | null;
+  /** Días para pagar una factura cuando la compra no indica el vencimiento */
+  plazoPagoDias: number;
+  activo: boolean;
+}
+
+export interface ProveedorRequest {
+  nombre: string;
+  cuit?: string;
+  telefono?: string;
+  email?: string;
+  /** Omitido = 30 días */
+  plazoPagoDias?: number;
+  /** Omitido = activo */
+  activo?: boolean;
+}
+
+export type EstadoFacturaProveedor = 'PENDIENTE' | 'PAGADA_PARCIAL' | 'PAGADA';
+
+export const ESTADO_FACTURA_LABELS: Record<EstadoFacturaProveedor, string> = {
+  PENDIENTE: 'Pendiente',
+  PAGADA_PARCIAL: 'Pago parcial',
+  PAGADA: 'Pagada',
+};
+
+/** La cuenta corriente no aplica para pagarle al proveedor: es de donde sale la deuda */
+export type MedioPagoProveedor = 'EFECTIVO' | 'TARJETA' | 'TRANSFERENCIA' | 'QR';
+
+export const MEDIO_PAGO_PROVEEDOR_LABELS: Record<MedioPagoProveedor, string> = {
+  EFECTIVO: 'Efectivo',
+  TARJETA: 'Tarjeta',
+  TRANSFERENCIA: 'Transferencia',
+  QR: 'QR',
+};
+
+export interface PagoProveedor {
+  fecha: string;
+  monto: number;
+  medioPago: MedioPagoProveedor;
+  pagadoConCaja: boolean;
+}
+
+export interface FacturaProveedor {
+  id: string;
+  proveedorId: string;
+  nombreProveedor: string;
+  /** Compra que originó la factura */
+  gastoId: string;
+  numero: string | null;
+  fechaEmision: string;
+  fechaVencimiento: string;
+  monto: number;
+  totalPagado: number;
+  saldo: number;
+  estado: EstadoFacturaProveedor;
+  vencida: boolean;
+  /** Negativo si ya venció */
+  diasParaVencer: number;
+  pagos: PagoProveedor[];
+}
+
+export interface SaldoProveedor {
+  proveedor: Proveedor;
+  facturasImpagas: number;
+  saldo: number;
+  saldoVencido: number;
+  /** null si no debe nada */
+  proximoVencimiento: string | null;
+}
+
+export interface CuentaProveedor {
+  saldo: SaldoProveedor;
+  /** Todas las facturas, de la que vence primero a la última */
+  facturas: FacturaProveedor[];
+}
+
+export interface PagoProveedorRequest {
+  monto: number;
+  medioPago: MedioPagoProveedor;
+  /** El efectivo salió de la caja: registra también el egreso de caja */
+  pagadoConCaja: boolean;
+}
@@ -0,0 +1,35 @@
+import apiClient from '../../../lib/apiClient';
+import type { Reserva, ReservaRequest } from '../types';
+
+/**
+ * API client de reservas de mesa (HU-142).
+ * Consume /api/reservas de ReservaController.
+ */
+export const reservasApi = {
+  /** @param desde @param hasta fechas YYYY-MM-DD, inclusive */
+  listar: async (desde: string, hasta: string): Promise<Reserva[]> => {
+    const response = await apiClient.get<Reserva[]>('/reservas', { params: { desde, hasta } });
+    return response.data;
+  },
+
+  crear: async (request: ReservaRequest): Promise<Reserva> => {
+    const response = await apiClient.post<Reserva>('/reservas', request);
+    return response.data;
+  },
+
+  cobrarSenia: async (reservaId: string, monto: number): Promise<Reserva> => {
+    const response = await apiClient.post<Reserva>(`/reservas/${reservaId}/senia`, { monto });
+    return response.data;
+  },
+
+  /** La mesa tiene que estar abierta: la seña se descuenta de su pedido */
+  sentar: async (reservaId: string, mesaId: string): Promise<Reserva> => {
+    const response = await apiClient.post<Reserva>(`/reservas/${reservaId}/sentar`, { mesaId });
+    return response.data;
+  },
+
+  cancelar: async (reservaId: string): Promise<Reserva> => {
+    const response = await apiClient.post<Reserva>(`/reservas/${reservaId}/cancelar`);
+    return response.data;
+  },
+};
@@ -0,0 +1,389 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Banknote, CalendarClock, Loader2, Phone, Users, X } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useMesas } from '../../salon/hooks/useMesas';
+import {
+  useCancelarReserva,
+  useCobrarSenia,
+  useCrearReserva,
+  useReservas,
+  useSentarReserva,
+} from '../hooks/useReservas';
+import { ESTADO_RESERVA_LABELS, type Reserva } from '../types';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function hoy(): string {
+  const fecha = new Date();
+  return `${fecha.getFullYear()}-${String(fecha.getMonth() + 1).padStart(2, '0')}-${String(fecha.getDate()).padStart(2, '0')}`;
+}
+
+function hora(fechaHora: string): string {
+  return fechaHora.slice(11, 16);
+}
+
+const inputClass =
+  'min-h-[44px] w-full px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none';
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+const ESTADO_COLORES = {
+  PENDIENTE: 'bg-amber-900/30 text-amber-400',
+  CUMPLIDA: 'bg-green-900/30 text-green-400',
+  CANCELADA: 'bg-neutral-800 text-gray-500',
+} as const;
+
+// ─── Nueva reserva ────────────────────────────────────────────────────────────
+
+function NuevaReserva({ dia }: { dia: string }) {
+  const toast = useToast();
+  const crear = useCrearReserva();
+  const { data: mesas = [] } = useMesas();
+
+  const [nombre, setNombre] = useState('');
+  const [telefono, setTelefono] = useState('');
+  const [fecha, setFecha] = useState(dia);
+  const [horaReserva, setHoraReserva] = useState('21:00');
+  const [personas, setPersonas] = useState('2');
+  const [mesaId, setMesaId] = useState('');
+  const [senia, setSenia] = useState('');
+  const [observaciones, setObservaciones] = useState('');
+
+  const personasNumero = Number(personas);
+  const seniaNumero = Number(senia || 0);
+  const puedeGuardar =
+    nombre.trim() !== '' &&
+    !!fecha &&
+    !!horaReserva &&
+    Number.isInteger(personasNumero) &&
+    personasNumero > 0 &&
+    Number.isFinite(seniaNumero) &&
+    seniaNumero >= 0 &&
+    !crear.isPending;
+
+  const handleGuardar = () => {
+    crear.mutate(
+      {
+        nombreCliente: nombre.trim(),
+        telefono: telefono.trim() || undefined,
+        fechaHora: `${fecha}T${horaReserva}:00`,
+        personas: personasNumero,
+        mesaId: mesaId || undefined,
+        observaciones: observaciones.trim() || undefined,
+        senia: seniaNumero > 0 ? seniaNumero : undefined,
+      },
+      {
+        onSuccess: () => {
+          toast.success(
+            seniaNumero > 0
+              ? `Reserva tomada con $ ${fmt(seniaNumero)} de seña`
+              : 'Reserva tomada',
+          );
+          setNombre('');
+          setTelefono('');
+          setPersonas('2');
+          setMesaId('');
+          setSenia('');
+          setObservaciones('');
+        },
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo tomar la reserva'),
+      },
+    );
+  };
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-3">
+      <p className="text-[10px] uppercase tracking-wider text-gray-600">Nueva reserva</p>
+
+      <label className="flex flex-col gap-1 text-sm text-text-secondary">
+        A nombre de
+        <input type="text" value={nombre} maxLength={80} onChange={(e) => setNombre(e.target.value)} className={inputClass} />
+      </label>
+
+      <label className="flex flex-col gap-1 text-sm text-text-secondary">
+        Teléfono
+        <input type="tel" value={telefono} maxLength={30} onChange={(e) => setTelefono(e.target.value)} className={inputClass} />
+      </label>
+
+      <div className="grid grid-cols-3 gap-2">
+        <label className="col-span-2 flex flex-col gap-1 text-sm text-text-secondary">
+          Día
+          <input type="date" value={fecha} min={hoy()} onChange={(e) => setFecha(e.target.value)} className={`${inputClass} font-mono`} />
+        </label>
+        <label className="flex flex-col gap-1 text-sm text-text-secondary">
+          Hora
+          <input type="time" value={horaReserva} onChange={(e) => setHoraReserva(e.target.value)} className={`${inputClass} font-mono`} />
+        </label>
+      </div>
+
+      <div className="grid grid-cols-2 gap-2">
+        <label className="flex flex-col gap-1 text-sm text-text-secondary">
+          Personas
+          <input type="number" min={1} value={personas} onChange={(e) => setPersonas(e.target.value)} className={`${inputClass} font-mono`} />
+        </label>
+        <label className="flex flex-col gap-1 text-sm text-text-secondary">
+          Mesa
+          <select value={mesaId} onChange={(e) => setMesaId(e.target.value)} className={inputClass}>
+            <option value="">Al llegar</option>
+            {mesas.map((m) => (
+              <option key={m.id} value={m.id}>
+                Mesa {m.numero}
+              </option>
+            ))}
+          </select>
+        </label>
+      </div>
+
+      <label className="flex flex-col gap-1 text-sm text-text-secondary">
+        Seña en efectivo
+        <input
+          type="number"
+          min={0}
+          value={senia}
+          placeholder="Sin seña"
+          onChange={(e) => setSenia(e.target.value)}
+          className={`${inputClass} font-mono`}
+        />
+      </label>
+
+      <label className="flex flex-col gap-1 text-sm text-text-secondary">
+        Observaciones
+        <input
+          type="text"
+          value={observaciones}
+          maxLength={200}
+          onChange={(e) => setObservaciones(e.target.value)}
+          className={inputClass}
+        />
+      </label>
+
+      <p className="text-xs text-gray-500">
+        La seña entra a la caja de hoy. Al abrir la mesa reservada se descuenta sola del total.
+      </p>
+
+      <div className="flex justify-end">
+        <button
+          onClick={handleGuardar}
+          disabled={!puedeGuardar}
+          className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+        >
+          {crear.isPending && <Loader2 size={16} className="animate-spin" />}
+          Reservar
+        </button>
+      </div>
+    </div>
+  );
+}
+
+// ─── Tarjeta de reserva ───────────────────────────────────────────────────────
+
+function TarjetaReserva({ reserva }: { reserva: Reserva }) {
+  const toast = useToast();
+  const cobrarSenia = useCobrarSenia();
+  const sentar = useSentarReserva();
+  const cancelar = useCancelarReserva();
+  const { data: mesas = [] } = useMesas();
+  const mesasAbiertas = mesas.filter((m) => m.estado === 'ABIERTA');
+
+  const [senia, setSenia] = useState('');
+  const [mesaSentar, setMesaSentar] = useState(reserva.mesaId ?? '');
+
+  const pendiente = reserva.estado === 'PENDIENTE';
+  const seniaNumero = Number(senia);
+
+  const handleCobrar = () => {
+    cobrarSenia.mutate(
+      { reservaId: reserva.id, monto: seniaNumero },
+      {
+        onSuccess: () => {
+          toast.success(`Seña de $ ${fmt(seniaNumero)} cobrada`);
+          setSenia('');
+        },
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo cobrar la seña'),
+      },
+    );
+  };
+
+  const handleSentar = () => {
+    sentar.mutate(
+      { reservaId: reserva.id, mesaId: mesaSentar },
+      {
+        onSuccess: (r) => toast.success(`${r.nombreCliente} se sentó en la mesa ${r.numeroMesa}`),
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo sentar la reserva'),
+      },
+    );
+  };
+
+  const handleCancelar = () => {
+    cancelar.mutate(reserva.id, {
+      onSuccess: () => toast.success('Reserva cancelada'),
+      onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo cancelar la reserva'),
+    });
+  };
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50">
+      <div className="flex items-center gap-3 px-4 py-3">
+        <p className="text-lg font-mono tabular-nums text-gray-100">{hora(reserva.fechaHora)}</p>
+        <div className="min-w-0">
+          <p className="text-sm font-semibold text-gray-100 truncate">{reserva.nombreCliente}</p>
+          <p className="flex items-center gap-3 text-xs text-gray-500">
+            <span className="flex items-center gap-1">
+              <Users size={12} /> {reserva.personas}
+            </span>
+            {reserva.telefono && (
+              <span className="flex items-center gap-1">
+                <Phone size={12} /> {reserva.telefono}
+              </span>
+            )}
+            <span>{reserva.numeroMesa ? `Mesa ${reserva.numeroMesa}` : 'Sin mesa'}</span>
+          </p>
+        </div>
+        <span className={`ml-auto px-2 py-0.5 rounded text-[10px] font-semibold ${ESTADO_COLORES[reserva.estado]}`}>
+          {ESTADO_RESERVA_LABELS[reserva.estado]}
+        </span>
+        {pendiente && (
+          <button
+            type="button"
+            onClick={handleCancelar}
+            disabled={cancelar.isPending}
+            className="p-2 rounded-lg hover:bg-neutral-800 text-gray-500 hover:text-gray-300 disabled:opacity-40"
+            title="Cancelar reserva"
+          >
+            <X size={16} />
+          </button>
+        )}
+      </div>
+
+      {reserva.observaciones && (
+        <p className="px-4 pb-2 text-xs text-gray-500">{reserva.observaciones}</p>
+      )}
+
+      <div className="flex flex-wrap items-center gap-3 px-4 py-2 border-t border-neutral-800 text-xs">
+        {reserva.montoSenia > 0 ? (
+          <span className="flex items-center gap-1.5 text-green-400">
+            <Banknote size={13} />
+            Seña $ {fmt(reserva.montoSenia)}
+            {reserva.estado === 'CUMPLIDA' && ' · descontada de la cuenta'}
+          </span>
+        ) : pendiente ? (
+          <span className="flex items-center gap-2">
+            <input
+              type="number"
+              min={0}
+              value={senia}
+              placeholder="Seña"
+              onChange={(e) => setSenia(e.target.value)}
+              className="w-24 h-8 px-2 bg-neutral-800 border border-neutral-700 rounded-lg text-right font-mono text-gray-100 focus:outline-none focus:border-red-500/50"
+            />
+            <button
+              type="button"
+              onClick={handleCobrar}
+              disabled={!(seniaNumero > 0) || cobrarSenia.isPending}
+              className="btn-secondary text-xs !min-h-[32px] px-3"
+            >
+              Cobrar seña
+            </button>
+          </span>
+        ) : (
+          <span className="text-gray-600">Sin seña</span>
+        )}
+
+        {pendiente && (
+          <span className="ml-auto flex items-center gap-2">
+            <select
+              value={mesaSentar}
+              onChange={(e) => setMesaSentar(e.target.value)}
+              className="h-8 px-2 bg-neutral-800 border border-neutral-700 rounded-lg text-gray-300 focus:outline-none"
+            >
+              <option value="">Mesa abierta...</option>
+              {mesasAbiertas.map((m) => (
+                <option key={m.id} value={m.id}>
+                  Mesa {m.numero}
+                </option>
+              ))}
+            </select>
+            <button
+              type="button"
+              onClick={handleSentar}
+              disabled={!mesasAbiertas.some((m) => m.id === mesaSentar) || sentar.isPending}
+              className="btn-primary text-xs !min-h-[32px] px-3"
+            >
+              Sentar
+            </button>
+          </span>
+        )}
+      </div>
+    </div>
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Reservas del salón con su seña (HU-142).
+ *
+ * La seña se cobra en efectivo al tomar la reserva (o después) y entra
+ * como ingreso a la caja de ese día. Al abrir la mesa reservada la reserva
+ * se sienta sola; si no tenía mesa, se sienta a mano en una mesa abierta.
+ * En los dos casos la seña se descuenta del total del pedido.
+ */
+export default function ReservasPage() {
+  const [dia, setDia] = useState(hoy);
+  const { data: reservas = [], isLoading, isError } = useReservas(dia, dia);
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Reservas</h1>
+            <p className="text-sm text-gray-500">Mesas reservadas y señas a descontar de la cuenta</p>
+          </div>
+        </header>
+
+        <div className="grid grid-cols-1 xl:grid-cols-12 gap-5">
+          <div className="xl:col-span-4">
+            <NuevaReserva dia={dia} />
+          </div>
+
+          <div className="xl:col-span-8 space-y-3">
+            <div className="flex items-center gap-3">
+              <CalendarClock size={14} className="text-gray-500" />
+              <input
+                type="date"
+                value={dia}
+                onChange={(e) => e.target.value && setDia(e.target.value)}
+                className={inputFecha}
+              />
+            </div>
+
+            {isLoading ? (
+              <div className="flex items-center gap-2 text-sm text-gray-500">
+                <Loader2 size={16} className="animate-spin" /> Cargando reservas...
+              </div>
+            ) : isError ? (
+              <p className="text-sm text-red-400">No se pudieron cargar las reservas.</p>
+            ) : reservas.length === 0 ? (
+              <p className="text-xs text-gray-500">No hay reservas para el día.</p>
+            ) : (
+              reservas.map((r) => <TarjetaReserva key={r.id} reserva={r} />)
+            )}
+          </div>
+        </div>
+      </div>
+    </section>
+  );
+}
@@ -0,0 +1,78 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { reservasApi } from '../api/reservasApi';
+import type { Reserva, ReservaRequest } from '../types';
+import { cajaKeys } from '../../caja/hooks/useCaja';
+
+export const reservasKeys = {
+  all: ['reservas'] as const,
+  lista: (desde: string, hasta: string) => ['reservas', desde, hasta] as const,
+};
+
+/**
+ * HU-142: Reservas de los días del rango, por horario.
+ *
+ * queryKey: ['reservas', desde, hasta]
+ */
+export function useReservas(desde: string, hasta: string) {
+  return useQuery<Reserva[]>({
+    queryKey: reservasKeys.lista(desde, hasta),
+    queryFn: () => reservasApi.listar(desde, hasta),
+    enabled: !!desde && !!hasta && desde <= hasta,
+  });
+}
+
+/**
+ * Tomar una reserva. Si deja seña entra un ingreso a la caja del día.
+ */
+export function useCrearReserva() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (request: ReservaRequest) => reservasApi.crear(request),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: reservasKeys.all, exact: false });
+      queryClient.invalidateQueries({ queryKey: cajaKeys.all, exact: false });
+    },
+  });
+}
+
+export function useCobrarSenia() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ reservaId, monto }: { reservaId: string; monto: number }) =>
+      reservasApi.cobrarSenia(reservaId, monto),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: reservasKeys.all, exact: false });
+      queryClient.invalidateQueries({ queryKey: cajaKeys.all, exact: false });
+    },
+  });
+}
+
+/**
+ * Sentar la reserva en una mesa abierta. Invalida el pedido: su total
+ * baja con la seña.
+ */
+export function useSentarReserva() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ reservaId, mesaId }: { reservaId: string; mesaId: string }) =>
+      reservasApi.sentar(reservaId, mesaId),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: reservasKeys.all, exact: false });
+      queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+    },
+  });
+}
+
+export function useCancelarReserva() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (reservaId: string) => reservasApi.cancelar(reservaId),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: reservasKeys.all, exact: false });
+    },
+  });
+}
@@ -0,0 +1,42 @@
+/**
+ * Tipos del módulo Reservas (HU-142).
+ * Espejo de los DTOs de ReservaController.
+ */
+
+export type EstadoReserva = 'PENDIENTE' | 'CUMPLIDA' | 'CANCELADA';
+
+export const ESTADO_RESERVA_LABELS: Record<EstadoReserva, string> = {
+  PENDIENTE: 'Pendiente',
+  CUMPLIDA: 'Sentada',
+  CANCELADA: 'Cancelada',
+};
+
+export interface Reserva {
+  id: string;
+  nombreCliente: string;
+  telefono: string | null;
+  /** ISO local: YYYY-MM-DDTHH:mm:ss */
+  fechaHora: string;
+  personas: number;
+  observaciones: string | null;
+  /** Mesa asignada; null = se elige al sentar al cliente */
+  mesaId: string | null;
+  numeroMesa: number | null;
+  estado: EstadoReserva;
+  /** Seña cobrada (0 = sin seña) */
+  montoSenia: number;
+  fechaSenia: string | null;
+  /** Pedido en el que se descontó la seña */
+  pedidoId: string | null;
+}
+
+export interface ReservaRequest {
+  nombreCliente: string;
+  telefono?: string;
+  fechaHora: string;
+  personas: number;
+  mesaId?: string;
+  observaciones?: string;
+  /** Seña en efectivo; entra a la caja del día */
+  senia?: number;
+}
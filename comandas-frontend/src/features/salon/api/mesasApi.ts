@@ -1,6 +1,7 @@
 import apiClient from '../../../lib/apiClient';
-import type { Mesa, CrearMesaRequest, CerrarMesaRequest, CerrarMesaResponse } from '../types';
-import type { DetallePedidoResponse } from '../../pedido/types';
+import { cabeceraVersion } from '../../pedido/api/pedidosApi';
+import type { Mesa, AperturaMesa, CrearMesaRequest, CerrarMesaRequest, CerrarMesaResponse, PagoRequest, PagosACuentaResponse, UbicacionMesa, AlertaMesasOlvidadasConfig, MesasOlvidadasResponse, DiaSemana, ReporteRotacionMesas } from '../types';
+import type { DetallePedidoResponse, TiempoServicio } from '../../pedido/types';
 import type { ComandaImpresionResponse, TicketImpresionResponse, EnviarComandaResponse, TicketVentaEscPosResponse } from '../../pedido/types-impresion';
 
 /**
@@ -25,17 +26,29 @@ export const mesasApi = {
   /**
    * HU-15: Crear nueva mesa
    */
-  crear: async (numero: number): Promise<Mesa> => {
-    const dto: CrearMesaRequest = { numero };
+  crear: async (numero: number, ubicacion: Omit<CrearMesaRequest, 'numero'> = {}): Promise<Mesa> => {
+    const dto: CrearMesaRequest = { numero, ...ubicacion };
     const response = await apiClient.post<Mesa>('/mesas', dto);
     return response.data;
   },
 
+  /**
+   * HU-112: Guardar el mapa del salón (número, sector y celda de cada mesa)
+   */
+  guardarDisposicion: async (mesas: UbicacionMesa[]): Promise<Mesa[]> => {
+    const response = await apiClient.put<Mesa[]>('/mesas/disposicion', { mesas });
+    return response.data;
+  },
+
   /**
    * HU-03: Abrir mesa y crear pedido inicial
+   * HU-108: El canal define la lista de precios del pedido (default: salón)
+   * HU-111: Mozo a cargo y cubiertos (opcionales)
    */
-  abrir: async (mesaId: string): Promise<void> => {
-    await apiClient.post(`/mesas/${mesaId}/abrir`);
+  abrir: async (mesaId: string, { canal = 'SALON', mozoId, cubiertos }: AperturaMesa = {}): Promise<void> => {
+    await apiClient.post(`/mesas/${mesaId}/abrir`, null, {
+      params: { canal, mozoId: mozoId || undefined, cubiertos: cubiertos || undefined },
+    });
   },
 
   /**
@@ -56,9 +69,47 @@ export const mesasApi = {
   /**
    * HU-04, HU-12: Cerrar mesa y finalizar pedido
    * Devuelve snapshot contable congelado con pagos registrados.
+   * HU-165: 409 si otra terminal cambió el pedido desde versionPedido mientras se cobraba.
+   */
+  cerrar: async (mesaId: string, dto: CerrarMesaRequest, versionPedido: number): Promise<CerrarMesaResponse> => {
+    const response = await apiClient.post<CerrarMesaResponse>(
+      `/mesas/${mesaId}/cierre`,
+      dto,
+      cabeceraVersion(versionPedido),
+    );
+    return response.data;
+  },
+
+  /**
+   * HU-160: Pagos a cuenta de la mesa abierta y saldo pendiente
+   */
+  consultarPagosACuenta: async (mesaId: string): Promise<PagosACuentaResponse> => {
+    const response = await apiClient.get<PagosACuentaResponse>(`/mesas/${mesaId}/pagos-a-cuenta`);
+    return response.data;
+  },
+
+  /**
+   * HU-160: Cobrar una parte sin cerrar la mesa (el comensal que se va antes)
+   * HU-165: 409 si otra terminal cambió el pedido desde versionPedido.
+   */
+  registrarPagoACuenta: async (mesaId: string, pago: PagoRequest, versionPedido: number): Promise<PagosACuentaResponse> => {
+    const response = await apiClient.post<PagosACuentaResponse>(
+      `/mesas/${mesaId}/pagos-a-cuenta`,
+      pago,
+      cabeceraVersion(versionPedido),
+    );
+    return response.data;
+  },
+
+  /**
+   * HU-160: Anular un pago a cuenta por su posición en la lista
+   * HU-165: la posición es la que ve la terminal en versionPedido.
    */
-  cerrar: async (mesaId: string, dto: CerrarMesaRequest): Promise<CerrarMesaResponse> => {
-    const response = await apiClient.post<CerrarMesaResponse>(`/mesas/${mesaId}/cierre`, dto);
+  anularPagoACuenta: async (mesaId: string, indice: number, versionPedido: number): Promise<PagosACuentaResponse> => {
+    const response = await apiClient.delete<PagosACuentaResponse>(
+      `/mesas/${mesaId}/pagos-a-cuenta/${indice}`,
+      cabeceraVersion(versionPedido),
+    );
     return response.data;
   },
 
@@ -98,12 +149,52 @@ export const mesasApi = {
     return response.data;
   },
 
+  /**
+   * HU-110: Marchar el siguiente paso a cocina ("marchar postres").
+   * Sin tiempo, el backend marcha el próximo paso pendiente.
+   */
+  marcharTiempo: async (mesaId: string, tiempo?: TiempoServicio): Promise<EnviarComandaResponse> => {
+    const response = await apiClient.post<EnviarComandaResponse>(`/mesas/${mesaId}/marchar`, null, {
+      params: tiempo ? { tiempo } : undefined,
+    });
+    return response.data;
+  },
+
   /**
    * HU-29: Generar ticket de venta ESC/POS para impresión térmica.
-   * Solo lectura — no modifica estado del pedido ni de la mesa.
+   * No modifica montos ni estado de la mesa; sí cuenta como pedido de
+   * cuenta para la alerta de mesas olvidadas (HU-113).
    */
   generarTicketEscPos: async (mesaId: string): Promise<TicketVentaEscPosResponse> => {
     const response = await apiClient.post<TicketVentaEscPosResponse>(`/mesas/${mesaId}/imprimir-ticket`);
     return response.data;
   },
+
+  /**
+   * HU-113: Mesas ocupadas sin atención por encima del umbral configurado
+   */
+  listarOlvidadas: async (): Promise<MesasOlvidadasResponse> => {
+    const response = await apiClient.get<MesasOlvidadasResponse>('/mesas-olvidadas');
+    return response.data;
+  },
+
+  /**
+   * HU-113: Activar / desactivar la alerta y cambiar el umbral
+   */
+  guardarAlertaOlvidadas: async (config: AlertaMesasOlvidadasConfig): Promise<AlertaMesasOlvidadasConfig> => {
+    const response = await apiClient.put<AlertaMesasOlvidadasConfig>('/mesas-olvidadas/configuracion', config);
+    return response.data;
+  },
+
+  /**
+   * HU-150: Rotación de mesas por hora de apertura
+   * @param desde,hasta fechas ISO (YYYY-MM-DD), ambas inclusive
+   * @param diaSemana solo ese día de la semana (undefined = todos)
+   */
+  obtenerRotacion: async (desde: string, hasta: string, diaSemana?: DiaSemana): Promise<ReporteRotacionMesas> => {
+    const response = await apiClient.get<ReporteRotacionMesas>('/rotacion-mesas', {
+      params: { desde, hasta, diaSemana },
+    });
+    return response.data;
+  },
 };
@@ -0,0 +1,53 @@
+import { useEffect, useRef } from 'react';
+import { useMesasOlvidadas } from '../hooks/useMesasOlvidadas';
+import { reproducirSonidoAlerta } from '../utils/sonidoAlerta';
+import useToast from '../../../hooks/useToast';
+import type { MesaOlvidada } from '../types';
+
+/** Una mesa se vuelve a avisar solo si registró actividad y se volvió a olvidar */
+const claveAviso = (m: MesaOlvidada) => `${m.mesaId}|${m.ultimaActividad}`;
+
+/**
+ * Notificador de mesas olvidadas (HU-113).
+ *
+ * Componente sin UI montado en el layout principal, así avisa desde
+ * cualquier pantalla. Por cada mesa ocupada que supera el umbral sin
+ * ítems nuevos ni pedido de cuenta: pitido, notificación del sistema
+ * (si el usuario la permitió) y toast.
+ *
+ * A diferencia del aviso de stock mínimo, la primera carga también
+ * avisa: una mesa que ya estaba olvidada al abrir la app sigue
+ * necesitando atención.
+ */
+export default function AlertaMesasOlvidadas() {
+  const { data } = useMesasOlvidadas();
+  const toast = useToast();
+  const avisadas = useRef<Set<string>>(new Set());
+
+  // Pedir permiso de notificaciones una sola vez, cuando la alerta está activa
+  useEffect(() => {
+    if (data?.alertaActiva && 'Notification' in window && Notification.permission === 'default') {
+      Notification.requestPermission();
+    }
+  }, [data?.alertaActiva]);
+
+  useEffect(() => {
+    if (!data) return;
+
+    const nuevas = data.mesas.filter((m) => !avisadas.current.has(claveAviso(m)));
+    avisadas.current = new Set(data.mesas.map(claveAviso));
+    if (nuevas.length === 0) return;
+
+    reproducirSonidoAlerta();
+
+    nuevas.forEach((m) => {
+      const mensaje = `Mesa ${m.numeroMesa} (${m.sector}) sin atención hace ${m.minutosSinActividad} min`;
+      toast.warning(mensaje, 10000);
+      if ('Notification' in window && Notification.permission === 'granted') {
+        new Notification('Mesa olvidada', { body: mensaje, tag: m.mesaId });
+      }
+    });
+  }, [data, toast]);
+
+  return null;
+}
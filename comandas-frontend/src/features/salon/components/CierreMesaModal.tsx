@@ -50,7 +50,7 @@ export default function CierreMesaModal({
     };
 
     cerrarMesa.mutate(
-      { mesaId, pagos: [pago] },
+      { mesaId, pagos: [pago], versionPedido: pedido.version },
       {
         onSuccess: () => {
           toast.success(`Mesa ${pedido.numeroMesa} cerrada exitosamente`);
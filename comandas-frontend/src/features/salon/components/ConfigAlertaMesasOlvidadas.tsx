@@ -0,0 +1,97 @@
+import { useEffect, useState } from 'react';
+import { BellRing } from 'lucide-react';
+import { useMesasOlvidadas, useGuardarAlertaMesasOlvidadas } from '../hooks/useMesasOlvidadas';
+import useToast from '../../../hooks/useToast';
+
+const MINUTOS_MINIMOS = 5;
+const MINUTOS_MAXIMOS = 240;
+
+/**
+ * Sección de Ajustes: alerta de mesas olvidadas (HU-113).
+ *
+ * La configuración vive en el backend y rige para todas las
+ * terminales del local, por eso se guarda aparte de la impresora
+ * (que es propia de cada equipo).
+ */
+export default function ConfigAlertaMesasOlvidadas() {
+  const toast = useToast();
+  const { data } = useMesasOlvidadas();
+  const guardar = useGuardarAlertaMesasOlvidadas();
+
+  const [activa, setActiva] = useState(true);
+  const [minutos, setMinutos] = useState('30');
+
+  useEffect(() => {
+    if (data) {
+      setActiva(data.alertaActiva);
+      setMinutos(String(data.minutosSinActividad));
+    }
+  }, [data?.alertaActiva, data?.minutosSinActividad]);
+
+  const handleGuardar = () => {
+    const valor = Number(minutos);
+    if (!Number.isInteger(valor) || valor < MINUTOS_MINIMOS || valor > MINUTOS_MAXIMOS) {
+      toast.error(`El umbral debe estar entre ${MINUTOS_MINIMOS} y ${MINUTOS_MAXIMOS} minutos`);
+      return;
+    }
+
+    guardar.mutate(
+      { activa, minutosSinActividad: valor },
+      {
+        onSuccess: () =>
+          toast.success(activa ? `Alerta de mesas olvidadas a los ${valor} min` : 'Alerta de mesas olvidadas desactivada'),
+        onError: (err: any) =>
+          toast.error(err?.response?.data?.message || 'Error al guardar la alerta'),
+      }
+    );
+  };
+
+  return (
+    <div className="space-y-3">
+      <div className="flex items-center gap-2">
+        <BellRing size={18} className="text-gray-400" />
+        <h3 className="text-sm font-semibold text-text-primary uppercase tracking-wider">
+          Mesas olvidadas
+        </h3>
+      </div>
+
+      <p className="text-xs text-gray-500">
+        Avisa con sonido y notificación cuando una mesa ocupada no carga ítems ni pide la cuenta.
+      </p>
+
+      <div className="flex items-center gap-3">
+        <label className="flex items-center gap-2 text-sm text-text-secondary cursor-pointer select-none">
+          <input
+            type="checkbox"
+            checked={activa}
+            onChange={(e) => setActiva(e.target.checked)}
+            className="w-4 h-4 accent-red-600"
+          />
+          Activa
+        </label>
+
+        <label className="flex items-center gap-2 text-sm text-text-secondary">
+          a los
+          <input
+            type="number"
+            min={MINUTOS_MINIMOS}
+            max={MINUTOS_MAXIMOS}
+            value={minutos}
+            disabled={!activa}
+            onChange={(e) => setMinutos(e.target.value)}
+            className="w-20 min-h-[40px] px-2 bg-background-card border border-gray-700 rounded-lg text-text-primary font-mono focus:border-primary focus:outline-none disabled:opacity-50"
+          />
+          min
+        </label>
+
+        <button
+          onClick={handleGuardar}
+          disabled={guardar.isPending}
+          className="ml-auto px-4 py-2 rounded-lg border border-gray-700 text-gray-300 hover:text-white hover:border-gray-500 disabled:opacity-50 transition-colors text-sm font-medium"
+        >
+          {guardar.isPending ? 'Guardando...' : 'Guardar alerta'}
+        </button>
+      </div>
+    </div>
+  );
+}
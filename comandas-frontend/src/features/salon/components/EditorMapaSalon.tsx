@@ -0,0 +1,469 @@
+import { useEffect, useMemo, useState } from 'react';
+import { Check, Loader2, Pencil, Plus, Save, Trash2, Undo2 } from 'lucide-react';
+import { useCrearMesa, useGuardarDisposicion } from '../hooks/useMesas';
+import useToast from '../../../hooks/useToast';
+import { SECTOR_POR_DEFECTO, type Mesa, type UbicacionMesa } from '../types';
+import {
+  COLUMNAS_MAPA,
+  claveSector,
+  filasDe,
+  sectoresDe,
+  ubicarEnMapa,
+} from '../utils/mapaSalon';
+
+interface EditorMapaSalonProps {
+  mesas: Mesa[];
+  onEliminar: (mesaId: string) => void;
+  onSalir: () => void;
+}
+
+type Borrador = Record<string, UbicacionMesa>;
+
+/** Fija una celda explícita para cada mesa, tal como se ve hoy el mapa */
+function borradorDesde(mesas: Mesa[]): Borrador {
+  const borrador: Borrador = {};
+  for (const sector of sectoresDe(mesas)) {
+    for (const { mesa, x, y } of ubicarEnMapa(mesas, sector)) {
+      borrador[mesa.id] = {
+        mesaId: mesa.id,
+        numero: mesa.numero,
+        sector,
+        posicionX: x,
+        posicionY: y,
+        capacidad: mesa.capacidad,
+      };
+    }
+  }
+  return borrador;
+}
+
+function aplicarBorrador(mesas: Mesa[], borrador: Borrador): Mesa[] {
+  return mesas.map((m) => (borrador[m.id] ? { ...m, ...borrador[m.id] } : m));
+}
+
+/**
+ * Modo edición del mapa del salón (HU-112).
+ *
+ * El encargado arrastra las mesas a otra celda (o las toca y después toca
+ * el destino, para pantallas táctiles), cambia números, crea mesas en una
+ * celda vacía y las agrupa por sector. Soltar una mesa sobre otra las
+ * intercambia; soltarla sobre la pestaña de un sector la mueve ahí.
+ *
+ * HU-149: También se carga cuántas personas entran en cada mesa, para que
+ * la lista de espera sugiera una mesa adecuada cuando se libera.
+ *
+ * Mover, renumerar, renombrar sectores y cambiar capacidades quedan en borrador hasta "Guardar".
+ * Crear y eliminar mesas se aplican en el acto, igual que fuera del editor.
+ * Lo guardado lo ven todas las terminales en el próximo refresco.
+ */
+export default function EditorMapaSalon({ mesas, onEliminar, onSalir }: EditorMapaSalonProps) {
+  const toast = useToast();
+  const crearMesa = useCrearMesa();
+  const guardar = useGuardarDisposicion();
+
+  const [borrador, setBorrador] = useState<Borrador>(() => borradorDesde(mesas));
+  const [sectoresNuevos, setSectoresNuevos] = useState<string[]>([]);
+  const [sectorActivo, setSectorActivo] = useState<string>(
+    () => sectoresDe(mesas)[0] ?? SECTOR_POR_DEFECTO
+  );
+  const [seleccionada, setSeleccionada] = useState<string | null>(null);
+  const [arrastrando, setArrastrando] = useState<string | null>(null);
+  const [nombreSector, setNombreSector] = useState<{ original: string | null; valor: string } | null>(null);
+
+  // Mesas creadas o eliminadas (acá o en otra terminal) entran/salen del borrador
+  // sin pisar lo que se editó y todavía no se guardó.
+  useEffect(() => {
+    setBorrador((actual) => {
+      const base = borradorDesde(mesas);
+      const siguiente: Borrador = {};
+      for (const mesa of mesas) siguiente[mesa.id] = actual[mesa.id] ?? base[mesa.id];
+      return siguiente;
+    });
+  }, [mesas]);
+
+  const mesasBorrador = useMemo(() => aplicarBorrador(mesas, borrador), [mesas, borrador]);
+
+  const sectores = useMemo(() => {
+    const conMesas = sectoresDe(mesasBorrador);
+    const claves = new Set(conMesas.map(claveSector));
+    const vacios = sectoresNuevos.filter((s) => !claves.has(claveSector(s)));
+    const todos = [...conMesas, ...vacios];
+    return todos.length > 0 ? todos : [SECTOR_POR_DEFECTO];
+  }, [mesasBorrador, sectoresNuevos]);
+
+  const sector = sectores.find((s) => claveSector(s) === claveSector(sectorActivo)) ?? sectores[0];
+  const celdas = useMemo(() => ubicarEnMapa(mesasBorrador, sector), [mesasBorrador, sector]);
+  // Una fila vacía de más para poder crecer hacia abajo
+  const filas = filasDe(celdas) + 1;
+  const ocupante = (x: number, y: number) => celdas.find((c) => c.x === x && c.y === y);
+
+  const hayCambios = Object.values(borrador).some((u) => {
+    const mesa = mesas.find((m) => m.id === u.mesaId);
+    return (
+      !mesa ||
+      mesa.numero !== u.numero ||
+      mesa.sector !== u.sector ||
+      mesa.posicionX !== u.posicionX ||
+      mesa.posicionY !== u.posicionY ||
+      mesa.capacidad !== u.capacidad
+    );
+  });
+
+  const mesaSeleccionada = mesasBorrador.find((m) => m.id === seleccionada);
+
+  // ── Acciones sobre el borrador ──
+
+  const actualizar = (mesaId: string, cambios: Partial<UbicacionMesa>) =>
+    setBorrador((b) => ({ ...b, [mesaId]: { ...b[mesaId], ...cambios } }));
+
+  /** Mueve a una celda del sector visible; si está ocupada, intercambia */
+  const moverACelda = (mesaId: string, x: number, y: number) => {
+    const origen = borrador[mesaId];
+    if (!origen) return;
+    const destino = ocupante(x, y);
+    setBorrador((b) => {
+      const siguiente = { ...b, [mesaId]: { ...b[mesaId], sector, posicionX: x, posicionY: y } };
+      if (destino && destino.mesa.id !== mesaId) {
+        const mismoSector = claveSector(origen.sector) === claveSector(sector);
+        siguiente[destino.mesa.id] = mismoSector
+          ? { ...b[destino.mesa.id], posicionX: origen.posicionX, posicionY: origen.posicionY }
+          : { ...b[destino.mesa.id], posicionX: null, posicionY: null };
+      }
+      return siguiente;
+    });
+  };
+
+  /** Pasa la mesa a otro sector; queda sin celda y se acomoda en el primer hueco */
+  const moverASector = (mesaId: string, destino: string) => {
+    if (claveSector(borrador[mesaId]?.sector ?? '') === claveSector(destino)) return;
+    actualizar(mesaId, { sector: destino, posicionX: null, posicionY: null });
+  };
+
+  const renombrarSector = (original: string, nuevo: string) => {
+    setBorrador((b) => {
+      const siguiente: Borrador = {};
+      for (const [id, u] of Object.entries(b)) {
+        siguiente[id] = claveSector(u.sector) === claveSector(original) ? { ...u, sector: nuevo } : u;
+      }
+      return siguiente;
+    });
+    setSectoresNuevos((s) => s.map((x) => (claveSector(x) === claveSector(original) ? nuevo : x)));
+  };
+
+  const confirmarNombreSector = () => {
+    if (!nombreSector) return;
+    const valor = nombreSector.valor.trim();
+    if (!valor) {
+      setNombreSector(null);
+      return;
+    }
+    if (valor.length > 30) {
+      toast.error('El nombre del sector no puede superar los 30 caracteres');
+      return;
+    }
+    const existente = sectores.find((s) => claveSector(s) === claveSector(valor));
+    if (nombreSector.original === null) {
+      if (!existente) setSectoresNuevos((s) => [...s, valor]);
+    } else if (existente && claveSector(existente) !== claveSector(nombreSector.original)) {
+      toast.error(`Ya existe el sector ${existente}`);
+      return;
+    } else {
+      renombrarSector(nombreSector.original, valor);
+    }
+    setSectorActivo(existente ?? valor);
+    setNombreSector(null);
+  };
+
+  const crearEnCelda = (x: number, y: number) => {
+    const numero = Math.max(0, ...mesasBorrador.map((m) => m.numero)) + 1;
+    crearMesa.mutate(
+      { numero, sector, posicionX: x, posicionY: y },
+      {
+        onSuccess: () => toast.success(`Mesa ${numero} creada en ${sector}`),
+        onError: (error: any) =>
+          toast.error(error?.response?.data?.message || 'Error al crear mesa'),
+      }
+    );
+  };
+
+  const handleCeldaClick = (x: number, y: number) => {
+    const enCelda = ocupante(x, y);
+    if (enCelda) {
+      setSeleccionada(enCelda.mesa.id === seleccionada ? null : enCelda.mesa.id);
+      return;
+    }
+    if (seleccionada) {
+      moverACelda(seleccionada, x, y);
+      setSeleccionada(null);
+      return;
+    }
+    crearEnCelda(x, y);
+  };
+
+  const handleGuardar = () => {
+    guardar.mutate(Object.values(borrador), {
+      onSuccess: () => {
+        toast.success('Mapa del salón guardado');
+        onSalir();
+      },
+      onError: (error: any) =>
+        toast.error(error?.response?.data?.message || 'Error al guardar el mapa del salón'),
+    });
+  };
+
+  const handleDescartar = () => {
+    setBorrador(borradorDesde(mesas));
+    setSectoresNuevos([]);
+    setSeleccionada(null);
+  };
+
+  return (
+    <div className="space-y-4">
+      {/* ── Pestañas de sectores (también reciben mesas arrastradas) ── */}
+      <div className="flex flex-wrap items-center gap-2">
+        {sectores.map((s) => {
+          const activo = claveSector(s) === claveSector(sector);
+          return (
+            <button
+              key={s}
+              type="button"
+              onClick={() => {
+                if (seleccionada && !activo) {
+                  moverASector(seleccionada, s);
+                  setSeleccionada(null);
+                }
+                setSectorActivo(s);
+              }}
+              onDragOver={(e) => e.preventDefault()}
+              onDrop={(e) => {
+                e.preventDefault();
+                if (arrastrando) moverASector(arrastrando, s);
+                setArrastrando(null);
+                setSectorActivo(s);
+              }}
+              className={[
+                'h-9 px-4 rounded-lg text-xs font-semibold border transition-colors',
+                activo
+                  ? 'bg-red-600/20 border-red-600 text-red-300'
+                  : 'bg-neutral-800 border-neutral-700 text-gray-400 hover:border-neutral-600',
+              ].join(' ')}
+            >
+              {s}
+            </button>
+          );
+        })}
+
+        {nombreSector ? (
+          <form
+            className="flex items-center gap-1"
+            onSubmit={(e) => {
+              e.preventDefault();
+              confirmarNombreSector();
+            }}
+          >
+            <input
+              autoFocus
+              value={nombreSector.valor}
+              onChange={(e) => setNombreSector({ ...nombreSector, valor: e.target.value })}
+              onKeyDown={(e) => e.key === 'Escape' && setNombreSector(null)}
+              placeholder="Vereda, Barra…"
+              maxLength={30}
+              className="h-9 w-36 px-3 rounded-lg bg-neutral-800 border border-neutral-600 text-xs text-gray-200 focus:outline-none focus:border-red-600"
+            />
+            <button
+              type="submit"
+              className="w-9 h-9 flex items-center justify-center rounded-lg bg-neutral-800 border border-neutral-700 text-gray-300 hover:border-neutral-600"
+              aria-label="Confirmar sector"
+            >
+              <Check size={14} />
+            </button>
+          </form>
+        ) : (
+          <>
+            <button
+              type="button"
+              onClick={() => setNombreSector({ original: sector, valor: sector })}
+              className="w-9 h-9 flex items-center justify-center rounded-lg text-gray-500 hover:text-gray-300 hover:bg-neutral-800"
+              title={`Renombrar ${sector}`}
+            >
+              <Pencil size={14} />
+            </button>
+            <button
+              type="button"
+              onClick={() => setNombreSector({ original: null, valor: '' })}
+              className="flex items-center gap-1 h-9 px-3 rounded-lg text-xs font-medium text-red-300 hover:text-red-200"
+            >
+              <Plus size={14} />
+              Sector
+            </button>
+          </>
+        )}
+
+        <div className="ml-auto flex items-center gap-2">
+          <button
+            type="button"
+            onClick={handleDescartar}
+            disabled={!hayCambios || guardar.isPending}
+            className="flex items-center gap-1.5 h-9 px-3 rounded-lg text-xs font-semibold bg-neutral-800 text-gray-400 border border-neutral-700 hover:border-neutral-600 disabled:opacity-40 disabled:cursor-not-allowed"
+          >
+            <Undo2 size={14} />
+            Descartar
+          </button>
+          <button
+            type="button"
+            onClick={handleGuardar}
+            disabled={!hayCambios || guardar.isPending}
+            className="flex items-center gap-1.5 h-9 px-4 rounded-lg text-xs font-bold bg-red-600 text-white hover:bg-red-500 disabled:opacity-40 disabled:cursor-not-allowed"
+          >
+            {guardar.isPending ? <Loader2 size={14} className="animate-spin" /> : <Save size={14} />}
+            Guardar mapa
+          </button>
+        </div>
+      </div>
+
+      {/* ── Celdas del sector activo ── */}
+      <div
+        className="grid gap-3"
+        style={{ gridTemplateColumns: `repeat(${COLUMNAS_MAPA}, minmax(0, 1fr))` }}
+      >
+        {Array.from({ length: filas * COLUMNAS_MAPA }).map((_, indice) => {
+          const x = indice % COLUMNAS_MAPA;
+          const y = Math.floor(indice / COLUMNAS_MAPA);
+          const enCelda = ocupante(x, y);
+          const esSeleccionada = enCelda && enCelda.mesa.id === seleccionada;
+
+          return (
+            <button
+              key={`${x},${y}`}
+              type="button"
+              onClick={() => handleCeldaClick(x, y)}
+              onDragOver={(e) => e.preventDefault()}
+              onDrop={(e) => {
+                e.preventDefault();
+                if (arrastrando) moverACelda(arrastrando, x, y);
+                setArrastrando(null);
+              }}
+              draggable={!!enCelda}
+              onDragStart={() => enCelda && setArrastrando(enCelda.mesa.id)}
+              onDragEnd={() => setArrastrando(null)}
+              className={[
+                'group aspect-square rounded-xl flex flex-col items-center justify-center gap-1',
+                'transition-colors focus:outline-none focus-visible:ring-2 focus-visible:ring-red-500',
+                enCelda
+                  ? [
+                      'border-2 cursor-grab active:cursor-grabbing',
+                      esSeleccionada
+                        ? 'bg-red-600/20 border-red-500'
+                        : enCelda.mesa.estado === 'ABIERTA'
+                          ? 'bg-red-950/40 border-red-800'
+                          : 'bg-neutral-900 border-neutral-700 hover:border-neutral-500',
+                      arrastrando === enCelda.mesa.id ? 'opacity-40' : '',
+                    ].join(' ')
+                  : seleccionada
+                    ? 'border-2 border-dashed border-red-800/60 hover:bg-red-950/30'
+                    : 'border-2 border-dashed border-neutral-800 hover:border-neutral-600',
+              ].join(' ')}
+              aria-label={enCelda ? `Mesa ${enCelda.mesa.numero}` : seleccionada ? 'Mover aquí' : 'Crear mesa aquí'}
+            >
+              {enCelda ? (
+                <>
+                  <span className="text-2xl font-extrabold tabular-nums text-gray-200">
+                    {enCelda.mesa.numero}
+                  </span>
+                  {enCelda.mesa.estado === 'ABIERTA' && (
+                    <span className="text-[10px] font-bold uppercase tracking-[0.12em] text-red-400">
+                      Ocupada
+                    </span>
+                  )}
+                </>
+              ) : (
+                <Plus
+                  size={18}
+                  className={seleccionada ? 'text-red-400/70' : 'text-neutral-700 group-hover:text-neutral-500'}
+                />
+              )}
+            </button>
+          );
+        })}
+      </div>
+
+      {/* ── Mesa seleccionada: número, capacidad, sector y baja ── */}
+      {mesaSeleccionada ? (
+        <div className="flex flex-wrap items-center gap-3 rounded-xl border border-neutral-800 bg-neutral-900 px-4 py-3">
+          <label className="flex items-center gap-2 text-xs text-gray-400">
+            Número
+            <input
+              type="number"
+              min={1}
+              value={mesaSeleccionada.numero}
+              onFocus={(e) => e.target.select()}
+              onChange={(e) => {
+                const numero = Number(e.target.value);
+                if (Number.isInteger(numero) && numero > 0) actualizar(mesaSeleccionada.id, { numero });
+              }}
+              className="w-20 h-9 px-2 rounded-lg bg-neutral-800 border border-neutral-700 text-sm text-gray-200 font-mono focus:outline-none focus:border-red-600"
+            />
+          </label>
+          <label className="flex items-center gap-2 text-xs text-gray-400">
+            Lugares
+            <input
+              type="number"
+              min={1}
+              max={50}
+              value={mesaSeleccionada.capacidad ?? ''}
+              placeholder="—"
+              onFocus={(e) => e.target.select()}
+              onChange={(e) => {
+                if (e.target.value === '') {
+                  actualizar(mesaSeleccionada.id, { capacidad: null });
+                  return;
+                }
+                const capacidad = Number(e.target.value);
+                if (Number.isInteger(capacidad) && capacidad > 0 && capacidad <= 50) {
+                  actualizar(mesaSeleccionada.id, { capacidad });
+                }
+              }}
+              className="w-16 h-9 px-2 rounded-lg bg-neutral-800 border border-neutral-700 text-sm text-gray-200 font-mono focus:outline-none focus:border-red-600"
+            />
+          </label>
+          <label className="flex items-center gap-2 text-xs text-gray-400">
+            Sector
+            <select
+              value={sector}
+              onChange={(e) => {
+                moverASector(mesaSeleccionada.id, e.target.value);
+                setSectorActivo(e.target.value);
+              }}
+              className="h-9 px-2 rounded-lg bg-neutral-800 border border-neutral-700 text-xs text-gray-300 focus:outline-none focus:border-red-600"
+            >
+              {sectores.map((s) => (
+                <option key={s} value={s}>
+                  {s}
+                </option>
+              ))}
+            </select>
+          </label>
+          <p className="text-xs text-gray-500">Tocá una celda vacía para moverla.</p>
+          {mesaSeleccionada.estado === 'LIBRE' && (
+            <button
+              type="button"
+              onClick={() => {
+                onEliminar(mesaSeleccionada.id);
+                setSeleccionada(null);
+              }}
+              className="ml-auto flex items-center gap-1.5 h-9 px-3 rounded-lg text-xs font-semibold text-red-400 border border-red-900/60 hover:bg-red-950/40"
+            >
+              <Trash2 size={14} />
+              Eliminar mesa
+            </button>
+          )}
+        </div>
+      ) : (
+        <p className="text-xs text-gray-500">
+          Arrastrá las mesas para ubicarlas, o tocá una mesa y después el lugar de destino.
+          Tocá una celda vacía para crear una mesa nueva en ese lugar.
+        </p>
+      )}
+    </div>
+  );
+}
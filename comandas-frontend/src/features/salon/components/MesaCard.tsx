@@ -1,12 +1,21 @@
-import { useState } from 'react';
+import { useEffect, useState } from 'react';
 import type { Mesa } from '../types';
-import { X } from 'lucide-react';
+import { Timer, X } from 'lucide-react';
 
 interface MesaCardProps {
   mesa: Mesa;
   onClick: (mesa: Mesa) => void;
   onEliminar?: (mesaId: string) => void;
   modoEdicion?: boolean;
+  /** ISO 8601 — apertura del pedido activo (HU-150), solo si la mesa está ABIERTA */
+  abiertaDesde?: string;
+}
+
+/** "35 min" o "1 h 05" desde la apertura hasta ahora */
+function tiempoOcupada(desde: string, ahora: number): string {
+  const minutos = Math.max(0, Math.floor((ahora - new Date(desde).getTime()) / 60_000));
+  if (minutos < 60) return `${minutos} min`;
+  return `${Math.floor(minutos / 60)} h ${String(minutos % 60).padStart(2, '0')}`;
 }
 
 /**
@@ -18,18 +27,29 @@ interface MesaCardProps {
  * - ABIERTA: acento rojo, borde iluminado → pedido activo
  *
  * La info detallada del pedido (hora, total) vive en el SidebarResumen.
+ * Una mesa ocupada muestra cuánto tiempo lleva abierta, refrescado cada 30s.
  *
  * HU-02: Visualización de estado de mesas
+ * HU-150: Temporizador de ocupación
  */
 export default function MesaCard({
   mesa,
   onClick,
   onEliminar,
   modoEdicion = false,
+  abiertaDesde,
 }: MesaCardProps) {
   const isAbierta = mesa.estado === 'ABIERTA';
   const isLibre = mesa.estado === 'LIBRE';
   const [animando, setAnimando] = useState(false);
+  const [ahora, setAhora] = useState(() => Date.now());
+
+  useEffect(() => {
+    if (!isAbierta || !abiertaDesde) return;
+    setAhora(Date.now());
+    const id = setInterval(() => setAhora(Date.now()), 30_000);
+    return () => clearInterval(id);
+  }, [isAbierta, abiertaDesde]);
 
   const handleClick = () => {
     setAnimando(true);
@@ -134,6 +154,14 @@ export default function MesaCard({
       >
         {isAbierta ? 'Ocupada' : 'Libre'}
       </span>
+
+      {/* HU-150: tiempo de ocupación */}
+      {isAbierta && abiertaDesde && (
+        <span className="absolute top-1.5 left-1.5 flex items-center gap-0.5 text-[10px] font-semibold tabular-nums text-red-300">
+          <Timer size={10} aria-hidden="true" />
+          {tiempoOcupada(abiertaDesde, ahora)}
+        </span>
+      )}
     </button>
   );
 }
@@ -1,5 +1,6 @@
 import type { Mesa } from '../types';
 import MesaCard from './MesaCard';
+import { COLUMNAS_MAPA, sectoresDe, ubicarEnMapa } from '../utils/mapaSalon';
 
 interface MesaGridProps {
   mesas: Mesa[];
@@ -8,6 +9,8 @@ interface MesaGridProps {
   onEliminar?: (mesaId: string) => void;
   isLoading?: boolean;
   isError?: boolean;
+  /** mesaId → apertura del pedido activo, para el temporizador de cada mesa (HU-150) */
+  aperturas?: Record<string, string>;
 }
 
 /**
@@ -16,8 +19,9 @@ interface MesaGridProps {
  * Componente puramente presentacional: recibe las mesas y estados de carga
  * como props, delegando el data-fetching al padre (SalonPage vía useSalonState).
  *
- * Layout: 7 columnas en desktop (simula la distribución física del local),
- * 4 en tablet, 3 en mobile.
+ * Layout: mapa de 7 columnas por sector (HU-112). Cada mesa ocupa la celda
+ * guardada en el editor; las que no tienen celda se acomodan por número.
+ * Con más de un sector, cada uno se muestra con su título.
  *
  * HU-02: Ver estado de mesas
  */
@@ -28,6 +32,7 @@ export default function MesaGrid({
   onEliminar,
   isLoading = false,
   isError = false,
+  aperturas = {},
 }: MesaGridProps) {
   // Skeleton loading: muestra placeholders pulsantes mientras carga
   if (isLoading) {
@@ -95,16 +100,34 @@ export default function MesaGrid({
     );
   }
 
+  const sectores = sectoresDe(mesas);
+
   return (
-    <div className="grid grid-cols-4 sm:grid-cols-5 lg:grid-cols-7 gap-3">
-      {mesas.map((mesa) => (
-        <MesaCard
-          key={mesa.id}
-          mesa={mesa}
-          onClick={onMesaClick}
-          modoEdicion={modoEdicion}
-          onEliminar={onEliminar}
-        />
+    <div className="space-y-6">
+      {sectores.map((sector) => (
+        <section key={sector} aria-label={sector}>
+          {sectores.length > 1 && (
+            <h2 className="mb-2 text-xs font-bold uppercase tracking-[0.12em] text-gray-500">
+              {sector}
+            </h2>
+          )}
+          <div
+            className="grid gap-3"
+            style={{ gridTemplateColumns: `repeat(${COLUMNAS_MAPA}, minmax(0, 1fr))` }}
+          >
+            {ubicarEnMapa(mesas, sector).map(({ mesa, x, y }) => (
+              <div key={mesa.id} style={{ gridColumnStart: x + 1, gridRowStart: y + 1 }}>
+                <MesaCard
+                  mesa={mesa}
+                  onClick={onMesaClick}
+                  modoEdicion={modoEdicion}
+                  onEliminar={onEliminar}
+                  abiertaDesde={aperturas[mesa.id]}
+                />
+              </div>
+            ))}
+          </div>
+        </section>
       ))}
     </div>
   );
@@ -0,0 +1,216 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Calendar, Download, Loader2, Timer } from 'lucide-react';
+import { generarCsv, descargarCsv } from '../../../lib/csv';
+import { useRotacionMesas } from '../hooks/useRotacionMesas';
+import { DIA_SEMANA_LABELS, type DiaSemana, type ReporteRotacionMesas } from '../types';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmtMinutos(valor: number): string {
+  return `${valor.toLocaleString('es-AR', { minimumFractionDigits: 1, maximumFractionDigits: 1 })}'`;
+}
+
+function fmtDecimal(valor: number | null): string {
+  return valor === null ? '—' : valor.toLocaleString('es-AR', { maximumFractionDigits: 2 });
+}
+
+function franja(hora: number): string {
+  const h = String(hora).padStart(2, '0');
+  return `${h}:00 – ${h}:59`;
+}
+
+function isoLocal(fecha: Date): string {
+  const y = fecha.getFullYear();
+  const m = String(fecha.getMonth() + 1).padStart(2, '0');
+  const d = String(fecha.getDate()).padStart(2, '0');
+  return `${y}-${m}-${d}`;
+}
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+const tarjeta = 'rounded-2xl bg-neutral-800/40 p-5';
+const tituloTarjeta = 'text-xs text-gray-500 uppercase tracking-wider font-medium';
+
+function exportarReporte(reporte: ReporteRotacionMesas) {
+  descargarCsv(
+    generarCsv(
+      ['Franja', 'Ocupaciones', 'Por día', 'Promedio (min)', 'Cubiertos por mesa', 'Mesas por hora', 'Cubiertos disponibles'],
+      reporte.franjas.map((f) => [
+        franja(f.hora),
+        f.ocupaciones,
+        f.ocupacionesPorDia,
+        f.promedioMinutos,
+        f.cubiertosPromedio ?? '',
+        f.capacidadPorHora ?? '',
+        f.cubiertosDisponibles ?? '',
+      ]),
+    ),
+    `rotacion_mesas_${reporte.desde}_${reporte.hasta}${reporte.diaSemana ? `_${reporte.diaSemana.toLowerCase()}` : ''}.csv`,
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Rotación de mesas (HU-150).
+ *
+ * Cuánto dura cada ocupación según la hora en que se abrió la mesa y
+ * cuántos cubiertos más entrarían por día en cada franja. Filtrando por día
+ * de la semana (por ejemplo, solo sábados) sirve para dimensionar un día pico.
+ * Solo cuentan los pedidos de salón cerrados.
+ */
+export default function ReporteRotacionMesasPage() {
+  const hoy = new Date();
+  const [desde, setDesde] = useState(isoLocal(new Date(hoy.getFullYear(), hoy.getMonth(), hoy.getDate() - 27)));
+  const [hasta, setHasta] = useState(isoLocal(hoy));
+  const [diaSemana, setDiaSemana] = useState<DiaSemana | ''>('');
+
+  const { data: reporte, isLoading, isError } = useRotacionMesas(desde, hasta, diaSemana || undefined);
+
+  const maximoPromedio = Math.max(1, ...(reporte?.franjas.map((f) => f.promedioMinutos) ?? []));
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Rotación de mesas</h1>
+            <p className="text-sm text-gray-500">Tiempo de ocupación por franja, de la apertura al cobro</p>
+          </div>
+        </header>
+
+        <div className="flex items-center gap-3">
+          <Calendar size={14} className="text-gray-500" />
+          <input type="date" value={desde} onChange={(e) => setDesde(e.target.value)} className={inputFecha} />
+          <span className="text-gray-600 text-xs">→</span>
+          <input type="date" value={hasta} onChange={(e) => setHasta(e.target.value)} className={inputFecha} />
+          <select
+            value={diaSemana}
+            onChange={(e) => setDiaSemana(e.target.value as DiaSemana | '')}
+            className={inputFecha}
+          >
+            <option value="">Todos los días</option>
+            {(Object.keys(DIA_SEMANA_LABELS) as DiaSemana[]).map((dia) => (
+              <option key={dia} value={dia}>
+                Solo {DIA_SEMANA_LABELS[dia].toLowerCase()}
+              </option>
+            ))}
+          </select>
+          {reporte && (
+            <button
+              type="button"
+              onClick={() => exportarReporte(reporte)}
+              disabled={reporte.franjas.length === 0}
+              className="ml-auto h-9 px-3 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-300 flex items-center gap-1.5 transition-colors disabled:opacity-40"
+            >
+              <Download size={14} />
+              Exportar CSV
+            </button>
+          )}
+        </div>
+
+        {isLoading ? (
+          <div className="flex items-center gap-2 text-sm text-gray-500">
+            <Loader2 size={16} className="animate-spin" /> Cargando reporte...
+          </div>
+        ) : isError ? (
+          <p className="text-sm text-red-400">No se pudo cargar el reporte de rotación.</p>
+        ) : reporte && reporte.ocupaciones === 0 ? (
+          <div className="flex items-center gap-2 text-xs text-gray-500">
+            <Timer size={14} />
+            No hay mesas cerradas en este rango.
+          </div>
+        ) : reporte && (
+          <>
+            <div className="grid grid-cols-1 sm:grid-cols-4 gap-4">
+              <div className={tarjeta}>
+                <p className={tituloTarjeta}>Ocupaciones</p>
+                <p className="mt-2 text-2xl font-semibold font-mono tabular-nums text-gray-100">
+                  {reporte.ocupaciones}
+                </p>
+                <p className="mt-1 text-xs text-gray-500">
+                  en {reporte.diasAnalizados} día{reporte.diasAnalizados !== 1 ? 's' : ''}
+                </p>
+              </div>
+              <div className={tarjeta}>
+                <p className={tituloTarjeta}>Tiempo promedio</p>
+                <p className="mt-2 text-2xl font-semibold font-mono tabular-nums text-gray-100">
+                  {fmtMinutos(reporte.promedioMinutos)}
+                </p>
+              </div>
+              <div className={tarjeta}>
+                <p className={tituloTarjeta}>Rotación</p>
+                <p className="mt-2 text-2xl font-semibold font-mono tabular-nums text-gray-100">
+                  {fmtDecimal(reporte.rotacionPorMesa)}
+                </p>
+                <p className="mt-1 text-xs text-gray-500">vueltas por mesa por día ({reporte.mesas} mesas)</p>
+              </div>
+              <div className={tarjeta}>
+                <p className={tituloTarjeta}>Cubiertos</p>
+                <p className="mt-2 text-2xl font-semibold font-mono tabular-nums text-gray-100">
+                  {reporte.cubiertos}
+                </p>
+              </div>
+            </div>
+
+            <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 overflow-x-auto">
+              <table className="w-full text-sm">
+                <thead>
+                  <tr className="text-[10px] uppercase tracking-wider text-gray-600 border-b border-neutral-800">
+                    <th className="text-left font-medium px-4 py-2">Franja</th>
+                    <th className="text-right font-medium px-3 py-2">Mesas / día</th>
+                    <th className="text-left font-medium px-3 py-2 w-1/3">Ocupación promedio</th>
+                    <th className="text-right font-medium px-3 py-2">Cubiertos / mesa</th>
+                    <th className="text-right font-medium px-3 py-2">Capacidad / hora</th>
+                    <th className="text-right font-medium px-4 py-2">Entran más</th>
+                  </tr>
+                </thead>
+                <tbody className="divide-y divide-neutral-800 font-mono tabular-nums text-gray-300">
+                  {reporte.franjas.map((f) => (
+                    <tr key={f.hora}>
+                      <td className="px-4 py-2 text-gray-100">{franja(f.hora)}</td>
+                      <td className="px-3 py-2 text-right">{fmtDecimal(f.ocupacionesPorDia)}</td>
+                      <td className="px-3 py-2">
+                        <div className="flex items-center gap-2">
+                          <div className="flex-1 h-2 rounded-full bg-neutral-800">
+                            <div
+                              className="h-2 rounded-full bg-red-500"
+                              style={{ width: `${(f.promedioMinutos / maximoPromedio) * 100}%` }}
+                            />
+                          </div>
+                          <span className="w-14 text-right">{fmtMinutos(f.promedioMinutos)}</span>
+                        </div>
+                      </td>
+                      <td className="px-3 py-2 text-right">{fmtDecimal(f.cubiertosPromedio)}</td>
+                      <td className="px-3 py-2 text-right">{fmtDecimal(f.capacidadPorHora)}</td>
+                      <td
+                        className={`px-4 py-2 text-right ${
+                          f.cubiertosDisponibles === 0 ? 'text-red-400' : f.cubiertosDisponibles ? 'text-emerald-400' : ''
+                        }`}
+                      >
+                        {f.cubiertosDisponibles === null ? '—' : `+${f.cubiertosDisponibles}`}
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+            <p className="text-xs text-gray-600">
+              Capacidad por hora: mesas × 60 / ocupación promedio. "Entran más" es la diferencia con las mesas
+              que se abren por día en la franja, por los cubiertos promedio. Las franjas sin cubiertos
+              informados no se estiman.
+            </p>
+          </>
+        )}
+      </div>
+    </section>
+  );
+}
@@ -1,5 +1,7 @@
-import { Plus, Trash2, X, Loader2 } from 'lucide-react';
+import { Link } from 'react-router-dom';
+import { Plus, LayoutGrid, X, Loader2, QrCode, CalendarClock, Hourglass, Timer } from 'lucide-react';
 import { useCrearMesa } from '../hooks/useMesas';
+import { useListaEspera } from '../../listaEspera/hooks/useListaEspera';
 import useToast from '../../../hooks/useToast';
 import type { Mesa } from '../types';
 
@@ -12,7 +14,11 @@ interface SalonControlsProps {
 /**
  * Controles del salón
  * HU-15: Crear nueva mesa (auto-incrementa número)
- * HU-16: Activar modo edición para eliminar mesas
+ * HU-16 / HU-112: Activar el modo edición del mapa (mover, numerar, eliminar)
+ * HU-126: Acceso a la hoja de QR de autopedido
+ * HU-142: Acceso a las reservas del día
+ * HU-149: Acceso a la lista de espera, con cuántos esperan y si hay mesa para avisar
+ * HU-150: Acceso al reporte de rotación de mesas
  */
 export default function SalonControls({
   mesas,
@@ -21,6 +27,8 @@ export default function SalonControls({
 }: SalonControlsProps) {
   const crearMesa = useCrearMesa();
   const toast = useToast();
+  const { data: enEspera = [] } = useListaEspera();
+  const hayMesaParaAvisar = enEspera.some((c) => c.mesaSugerida !== null);
 
   /** Calcula el próximo número: max(existentes) + 1, o 1 si no hay mesas */
   const calcularProximoNumero = (): number => {
@@ -69,7 +77,7 @@ export default function SalonControls({
         <span>{crearMesa.isPending ? 'Creando…' : 'Nueva Mesa'}</span>
       </button>
 
-      {/* ── Botón: Modo Eliminación ── */}
+      {/* ── Botón: Modo Edición del mapa ── */}
       <button
         onClick={onToggleModoEdicion}
         className={[
@@ -88,11 +96,86 @@ export default function SalonControls({
           </>
         ) : (
           <>
-            <Trash2 size={14} strokeWidth={2} />
-            <span>Eliminar</span>
+            <LayoutGrid size={14} strokeWidth={2} />
+            <span>Editar salón</span>
           </>
         )}
       </button>
+
+      {/* ── Link: QR de autopedido para imprimir ── */}
+      {!modoEdicion && (
+        <Link
+          to="/salon/qr"
+          className="
+            flex items-center gap-2
+            px-4 h-10 rounded-lg text-sm font-semibold
+            bg-neutral-800/80 text-gray-400 border border-neutral-700
+            hover:border-neutral-600 hover:text-gray-300
+            active:scale-95 transition-all duration-150
+          "
+        >
+          <QrCode size={14} strokeWidth={2} />
+          <span>QR de mesas</span>
+        </Link>
+      )}
+
+      {/* ── Link: reservas con seña ── */}
+      {!modoEdicion && (
+        <Link
+          to="/salon/reservas"
+          className="
+            flex items-center gap-2
+            px-4 h-10 rounded-lg text-sm font-semibold
+            bg-neutral-800/80 text-gray-400 border border-neutral-700
+            hover:border-neutral-600 hover:text-gray-300
+            active:scale-95 transition-all duration-150
+          "
+        >
+          <CalendarClock size={14} strokeWidth={2} />
+          <span>Reservas</span>
+        </Link>
+      )}
+
+      {/* ── Link: lista de espera ── */}
+      {!modoEdicion && (
+        <Link
+          to="/salon/espera"
+          className={`
+            flex items-center gap-2
+            px-4 h-10 rounded-lg text-sm font-semibold
+            bg-neutral-800/80 border
+            hover:border-neutral-600 hover:text-gray-300
+            active:scale-95 transition-all duration-150
+            ${hayMesaParaAvisar ? 'text-green-400 border-green-700' : 'text-gray-400 border-neutral-700'}
+          `}
+          title={hayMesaParaAvisar ? 'Se liberó una mesa para alguien de la lista' : undefined}
+        >
+          <Hourglass size={14} strokeWidth={2} />
+          <span>Espera</span>
+          {enEspera.length > 0 && (
+            <span className="min-w-[20px] h-5 px-1.5 rounded-full bg-neutral-700 text-[11px] font-mono text-gray-200 flex items-center justify-center">
+              {enEspera.length}
+            </span>
+          )}
+        </Link>
+      )}
+
+      {/* ── Link: rotación de mesas ── */}
+      {!modoEdicion && (
+        <Link
+          to="/salon/rotacion"
+          className="
+            flex items-center gap-2
+            px-4 h-10 rounded-lg text-sm font-semibold
+            bg-neutral-800/80 text-gray-400 border border-neutral-700
+            hover:border-neutral-600 hover:text-gray-300
+            active:scale-95 transition-all duration-150
+          "
+        >
+          <Timer size={14} strokeWidth={2} />
+          <span>Rotación</span>
+        </Link>
+      )}
     </div>
   );
 }
@@ -1,6 +1,8 @@
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
 import { mesasApi } from '../api/mesasApi';
-import type { CerrarMesaRequest, CerrarMesaResponse, PagoRequest } from '../types';
+import type { AperturaMesa, CerrarMesaRequest, CerrarMesaResponse, CrearMesaRequest, PagoRequest, PagosACuentaResponse, UbicacionMesa } from '../types';
+import type { TiempoServicio } from '../../pedido/types';
+import { esPedidoModificado } from '../../pedido/hooks/usePedido';
 import type { ComandaImpresionResponse, TicketImpresionResponse, EnviarComandaResponse, TicketVentaEscPosResponse } from '../../pedido/types-impresion';
 
 /**
@@ -38,7 +40,8 @@ export function useCrearMesa() {
   const queryClient = useQueryClient();
   
   return useMutation({
-    mutationFn: (numero: number) => mesasApi.crear(numero),
+    mutationFn: (mesa: number | CrearMesaRequest) =>
+      typeof mesa === 'number' ? mesasApi.crear(mesa) : mesasApi.crear(mesa.numero, mesa),
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: ['mesas'], exact: false });
     },
@@ -48,17 +51,39 @@ export function useCrearMesa() {
   });
 }
 
+/**
+ * HU-112: Hook para guardar el mapa del salón.
+ * Escribe directo el listado devuelto en la cache para que el mapa no
+ * "salte" a la versión anterior hasta el próximo polling.
+ */
+export function useGuardarDisposicion() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (mesas: UbicacionMesa[]) => mesasApi.guardarDisposicion(mesas),
+    onSuccess: (mesas) => {
+      queryClient.setQueryData(['mesas'], mesas);
+    },
+    onError: (error) => {
+      console.error('[useGuardarDisposicion] Error al guardar el mapa:', error);
+    },
+  });
+}
+
 /**
  * HU-03: Hook para abrir mesa y crear pedido inicial
  * Invalida mesas para refrescar estados (LIBRE → ABIERTA)
+ * HU-142: Y las reservas, porque abrir la mesa reservada sienta al cliente.
  */
 export function useAbrirMesa() {
   const queryClient = useQueryClient();
   
   return useMutation({
-    mutationFn: (mesaId: string) => mesasApi.abrir(mesaId),
+    mutationFn: ({ mesaId, ...opciones }: { mesaId: string } & AperturaMesa) =>
+      mesasApi.abrir(mesaId, opciones),
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: ['mesas'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['reservas'], exact: false });
     },
     onError: (error) => {
       console.error('[useAbrirMesa] Error al abrir mesa:', error);
@@ -74,19 +99,98 @@ export function useAbrirMesa() {
 export function useCerrarMesa() {
   const queryClient = useQueryClient();
   
-  return useMutation<CerrarMesaResponse, Error, { mesaId: string; pagos: PagoRequest[] }>({
-    mutationFn: async ({ mesaId, pagos }) => {
-      const dto: CerrarMesaRequest = { pagos };
-      return mesasApi.cerrar(mesaId, dto);
+  return useMutation<
+    CerrarMesaResponse,
+    Error,
+    {
+      mesaId: string;
+      pagos: PagoRequest[];
+      clienteId?: string;
+      puntosACanjear?: number;
+      propina?: number;
+      empleadoConsumoId?: string;
+      /** HU-165: Versión del pedido que se está cobrando */
+      versionPedido: number;
+    }
+  >({
+    mutationFn: async ({ mesaId, pagos, clienteId, puntosACanjear, propina, empleadoConsumoId, versionPedido }) => {
+      const dto: CerrarMesaRequest = { pagos, clienteId, puntosACanjear, propina, empleadoConsumoId };
+      return mesasApi.cerrar(mesaId, dto, versionPedido);
     },
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: ['mesas'], exact: false });
       queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
       queryClient.invalidateQueries({ queryKey: ['reporte-caja'], exact: false });
       queryClient.invalidateQueries({ queryKey: ['reporte-ventas-productos'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['clientes'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['puntos-cliente'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['historial-cliente'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['tarjetas-regalo'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['reporte-puntos'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['consumo-interno'], exact: false });
     },
     onError: (error) => {
       console.error('[useCerrarMesa] Error al cerrar mesa:', error);
+      if (esPedidoModificado(error)) {
+        queryClient.invalidateQueries({ queryKey: ['pedido'], exact: false });
+      }
+    },
+  });
+}
+
+/**
+ * HU-160: Pagos a cuenta de la mesa. Solo se consulta con el modal abierto.
+ */
+export function usePagosACuenta(mesaId: string | null, habilitado: boolean) {
+  return useQuery({
+    queryKey: ['pagos-a-cuenta', mesaId],
+    queryFn: () => mesasApi.consultarPagosACuenta(mesaId!),
+    enabled: !!mesaId && habilitado,
+  });
+}
+
+/**
+ * HU-160: Cobrar a cuenta. La mesa sigue abierta: se refresca el pedido
+ * para que el ticket y el cierre muestren el saldo nuevo.
+ * HU-165: Si otra terminal cambió el pedido, se refresca sin cobrar.
+ */
+export function useRegistrarPagoACuenta() {
+  const queryClient = useQueryClient();
+
+  return useMutation<PagosACuentaResponse, Error, { mesaId: string; pago: PagoRequest; versionPedido: number }>({
+    mutationFn: ({ mesaId, pago, versionPedido }) => mesasApi.registrarPagoACuenta(mesaId, pago, versionPedido),
+    onSuccess: (response, { mesaId }) => {
+      queryClient.setQueryData(['pagos-a-cuenta', mesaId], response);
+      queryClient.invalidateQueries({ queryKey: ['pedido', mesaId] });
+    },
+    onError: (error, { mesaId }) => {
+      if (esPedidoModificado(error)) {
+        queryClient.invalidateQueries({ queryKey: ['pedido', mesaId] });
+        queryClient.invalidateQueries({ queryKey: ['pagos-a-cuenta', mesaId] });
+      }
+    },
+  });
+}
+
+/**
+ * HU-160: Anular un pago a cuenta cobrado por error
+ * HU-165: La posición es la que ve la terminal; si otra terminal cambió los
+ * pagos, se refresca sin anular.
+ */
+export function useAnularPagoACuenta() {
+  const queryClient = useQueryClient();
+
+  return useMutation<PagosACuentaResponse, Error, { mesaId: string; indice: number; versionPedido: number }>({
+    mutationFn: ({ mesaId, indice, versionPedido }) => mesasApi.anularPagoACuenta(mesaId, indice, versionPedido),
+    onSuccess: (response, { mesaId }) => {
+      queryClient.setQueryData(['pagos-a-cuenta', mesaId], response);
+      queryClient.invalidateQueries({ queryKey: ['pedido', mesaId] });
+    },
+    onError: (error, { mesaId }) => {
+      if (esPedidoModificado(error)) {
+        queryClient.invalidateQueries({ queryKey: ['pedido', mesaId] });
+        queryClient.invalidateQueries({ queryKey: ['pagos-a-cuenta', mesaId] });
+      }
     },
   });
 }
@@ -150,6 +254,23 @@ export function useEnviarComandaCocina() {
   });
 }
 
+/**
+ * HU-110: Marchar el siguiente paso a cocina.
+ *
+ * Libera los ítems retenidos del paso y devuelve la comanda ESC/POS con el
+ * aviso "MARCHAR ...". Invalida el pedido para refrescar los ítems en espera.
+ */
+export function useMarcharTiempo() {
+  const queryClient = useQueryClient();
+
+  return useMutation<EnviarComandaResponse, Error, { mesaId: string; tiempo?: TiempoServicio }>({
+    mutationFn: ({ mesaId, tiempo }) => mesasApi.marcharTiempo(mesaId, tiempo),
+    onSuccess: (_data, { mesaId }) => {
+      queryClient.invalidateQueries({ queryKey: ['pedido', mesaId] });
+    },
+  });
+}
+
 /**
  * HU-29: Reimprimir comanda completa (todos los ítems, sin actualizar timestamp).
  * No invalida queries porque no muta estado.
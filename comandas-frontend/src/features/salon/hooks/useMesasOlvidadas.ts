@@ -0,0 +1,31 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { mesasApi } from '../api/mesasApi';
+import type { AlertaMesasOlvidadasConfig } from '../types';
+
+/**
+ * HU-113: Mesas ocupadas sin atención por encima del umbral.
+ * Polling cada 60s: el umbral mínimo es de 5 minutos, así que un
+ * minuto de demora en el aviso es aceptable.
+ */
+export function useMesasOlvidadas() {
+  return useQuery({
+    queryKey: ['mesas-olvidadas'],
+    queryFn: () => mesasApi.listarOlvidadas(),
+    refetchInterval: 60_000,
+  });
+}
+
+/**
+ * HU-113: Guardar la configuración de la alerta.
+ * Invalida el listado para aplicar el nuevo umbral en el acto.
+ */
+export function useGuardarAlertaMesasOlvidadas() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (config: AlertaMesasOlvidadasConfig) => mesasApi.guardarAlertaOlvidadas(config),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['mesas-olvidadas'] });
+    },
+  });
+}
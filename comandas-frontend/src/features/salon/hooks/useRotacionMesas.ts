@@ -0,0 +1,16 @@
+import { useQuery } from '@tanstack/react-query';
+import { mesasApi } from '../api/mesasApi';
+import type { DiaSemana } from '../types';
+
+/**
+ * HU-150: Rotación de mesas por franja horaria en un rango de fechas.
+ * Es un reporte histórico: sin polling.
+ */
+export function useRotacionMesas(desde: string, hasta: string, diaSemana?: DiaSemana) {
+  return useQuery({
+    queryKey: ['rotacion-mesas', desde, hasta, diaSemana ?? 'TODOS'],
+    queryFn: () => mesasApi.obtenerRotacion(desde, hasta, diaSemana),
+    enabled: !!desde && !!hasta && desde <= hasta,
+    staleTime: 60_000,
+  });
+}
@@ -1,6 +1,7 @@
 import { useState, useCallback, useMemo } from 'react';
-import type { Mesa } from '../types';
+import type { CanalVenta, Mesa } from '../types';
 import { useMesas, useAbrirMesa } from './useMesas';
+import { useMozos } from '../../mozos/hooks/useMozos';
 import useToast from '../../../hooks/useToast';
 
 /**
@@ -25,6 +26,15 @@ export function useSalonState() {
   // Mesa LIBRE pendiente de confirmación (pre-modal)
   const [mesaPendienteApertura, setMesaPendienteApertura] = useState<Mesa | null>(null);
 
+  // HU-108: Canal elegido en el pre-modal (define la lista de precios del pedido)
+  const [canalApertura, setCanalApertura] = useState<CanalVenta>('SALON');
+
+  // HU-111: Mozo a cargo y cubiertos; el mozo se recuerda entre aperturas
+  const [mozoApertura, setMozoApertura] = useState('');
+  const [cubiertosApertura, setCubiertosApertura] = useState(0);
+  const { data: mozos = [] } = useMozos();
+  const mozosActivos = useMemo(() => mozos.filter((m) => m.activo), [mozos]);
+
   const {
     data: mesas = [],
     isLoading: cargandoMesas,
@@ -51,6 +61,8 @@ export function useSalonState() {
       }
 
       // Mesa LIBRE: mostrar pre-modal de confirmación
+      setCanalApertura('SALON');
+      setCubiertosApertura(0);
       setMesaPendienteApertura(mesa);
     },
     []
@@ -61,7 +73,13 @@ export function useSalonState() {
     if (!mesaPendienteApertura) return;
 
     const mesa = mesaPendienteApertura;
-    abrirMesaMutation.mutate(mesa.id, {
+    const apertura = {
+      mesaId: mesa.id,
+      canal: canalApertura,
+      mozoId: mozosActivos.some((m) => m.id === mozoApertura) ? mozoApertura : undefined,
+      cubiertos: cubiertosApertura,
+    };
+    abrirMesaMutation.mutate(apertura, {
       onSuccess: () => {
         toast.success(`Mesa ${mesa.numero} abierta`);
         setMesaPendienteApertura(null);
@@ -73,7 +91,7 @@ export function useSalonState() {
         toast.error(mensaje);
       },
     });
-  }, [mesaPendienteApertura, abrirMesaMutation, toast]);
+  }, [mesaPendienteApertura, canalApertura, mozoApertura, mozosActivos, cubiertosApertura, abrirMesaMutation, toast]);
 
   /** Cancelar la apertura de mesa desde el pre-modal */
   const cancelarAperturaMesa = useCallback(() => {
@@ -95,6 +113,13 @@ export function useSalonState() {
     mesaSeleccionadaId,
     cerrarPedido,
     mesaPendienteApertura,
+    canalApertura,
+    setCanalApertura,
+    mozosActivos,
+    mozoApertura,
+    setMozoApertura,
+    cubiertosApertura,
+    setCubiertosApertura,
     confirmarAperturaMesa,
     cancelarAperturaMesa,
   } as const;
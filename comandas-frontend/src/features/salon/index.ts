@@ -10,8 +10,12 @@ export { default as SalonPage } from './pages/SalonPage';
 export { default as MesaCard } from './components/MesaCard';
 export { default as MesaGrid } from './components/MesaGrid';
 export { default as SalonControls } from './components/SalonControls';
+export { default as EditorMapaSalon } from './components/EditorMapaSalon';
 export { default as SidebarResumen } from './components/SidebarResumen';
 export { default as CierreMesaModal } from './components/CierreMesaModal';
+export { default as AlertaMesasOlvidadas } from './components/AlertaMesasOlvidadas';
+export { default as ConfigAlertaMesasOlvidadas } from './components/ConfigAlertaMesasOlvidadas';
+export { default as ReporteRotacionMesasPage } from './components/ReporteRotacionMesasPage';
 
 // Hooks
 export { useSalonState } from './hooks/useSalonState';
@@ -22,10 +26,13 @@ export {
   useAbrirMesa,
   useCerrarMesa,
   useEliminarMesa,
+  useGuardarDisposicion,
   useObtenerTicket,
   useObtenerComanda,
 } from './hooks/useMesas';
 export { usePedidosMesasAbiertas } from './hooks/usePedidosMesasAbiertas';
+export { useMesasOlvidadas, useGuardarAlertaMesasOlvidadas } from './hooks/useMesasOlvidadas';
+export { useRotacionMesas } from './hooks/useRotacionMesas';
 export type { ResumenMesaAbierta } from './hooks/usePedidosMesasAbiertas';
 
 // API
@@ -37,7 +44,9 @@ export type {
   EstadoMesa,
   EstadoPedido,
   MedioPago,
+  Moneda,
   CrearMesaRequest,
+  UbicacionMesa,
   AbrirMesaRequest,
   AbrirMesaResponse,
   PagoRequest,
@@ -45,4 +54,10 @@ export type {
   CerrarMesaRequest,
   CerrarMesaResponse,
   ReabrirPedidoResponse,
+  AlertaMesasOlvidadasConfig,
+  MesaOlvidada,
+  MesasOlvidadasResponse,
+  DiaSemana,
+  FranjaOcupacion,
+  ReporteRotacionMesas,
 } from './types';
@@ -1,11 +1,16 @@
-import { useState } from 'react';
+import { useMemo, useState } from 'react';
 import { useSalonState } from '../hooks/useSalonState';
 import { useEliminarMesa } from '../hooks/useMesas';
+import { usePedidosMesasAbiertas } from '../hooks/usePedidosMesasAbiertas';
 import useToast from '../../../hooks/useToast';
 import MesaGrid from '../components/MesaGrid';
 import SalonControls from '../components/SalonControls';
+import EditorMapaSalon from '../components/EditorMapaSalon';
 import SidebarResumen from '../components/SidebarResumen';
 import PantallaPedido from '../../pedido/pages/PantallaPedido';
+import PanelAutopedidos from '../../autopedido/components/PanelAutopedidos';
+import PanelLinksPago from '../../linksPago/components/PanelLinksPago';
+import { CANAL_VENTA_LABELS, type CanalVenta } from '../types';
 
 /**
  * Página principal del módulo Salón — Vista operativa de mesas.
@@ -25,6 +30,11 @@ import PantallaPedido from '../../pedido/pages/PantallaPedido';
  * HU-03: Abrir mesa
  * HU-15: Crear mesa
  * HU-16: Eliminar mesa
+ * HU-108: Elegir canal de venta al abrir la mesa
+ * HU-111: Asignar mozo y cubiertos al abrir la mesa
+ * HU-112: Editor del mapa (sectores, posición y número de cada mesa)
+ * HU-126: Pedidos por QR esperando al mozo, arriba del resumen
+ * HU-150: Cada mesa ocupada muestra cuánto tiempo lleva abierta
  */
 export default function SalonPage() {
   const [modoEdicion, setModoEdicion] = useState(false);
@@ -38,6 +48,13 @@ export default function SalonPage() {
     mesaSeleccionadaId,
     cerrarPedido,
     mesaPendienteApertura,
+    canalApertura,
+    setCanalApertura,
+    mozosActivos,
+    mozoApertura,
+    setMozoApertura,
+    cubiertosApertura,
+    setCubiertosApertura,
     confirmarAperturaMesa,
     cancelarAperturaMesa,
     abriendoMesa,
@@ -46,6 +63,13 @@ export default function SalonPage() {
   const eliminarMesa = useEliminarMesa();
   const toast = useToast();
 
+  // Misma cache ['pedido', mesaId] que el sidebar: no suma requests
+  const { resumenes } = usePedidosMesasAbiertas(mesasAbiertas);
+  const aperturas = useMemo(
+    () => Object.fromEntries(resumenes.map((r) => [r.mesaId, r.fechaApertura])),
+    [resumenes],
+  );
+
   /** HU-16: Eliminar mesa libre del salón */
   const handleEliminarMesa = (mesaId: string) => {
     const mesa = mesas.find((m) => m.id === mesaId);
@@ -84,30 +108,41 @@ export default function SalonPage() {
           {modoEdicion && (
             <div className="mx-6 mb-3 bg-red-950/30 border border-red-800 rounded-xl px-4 py-3 text-center shrink-0">
               <p className="text-red-400 text-sm font-semibold">
-                Modo Edición — Hacé click en × para eliminar mesas libres
+                Modo Edición — Los cambios del mapa se ven en todas las terminales al guardar
               </p>
             </div>
           )}
 
           {/* Grid de mesas (scroll interno) */}
           <div className="flex-1 overflow-y-auto px-6 pb-6">
-            <MesaGrid
-              mesas={mesas}
-              onMesaClick={handleMesaClick}
-              modoEdicion={modoEdicion}
-              onEliminar={handleEliminarMesa}
-              isLoading={cargandoMesas}
-              isError={errorMesas}
-            />
+            {modoEdicion && !cargandoMesas && !errorMesas ? (
+              <EditorMapaSalon
+                mesas={mesas}
+                onEliminar={handleEliminarMesa}
+                onSalir={() => setModoEdicion(false)}
+              />
+            ) : (
+              <MesaGrid
+                mesas={mesas}
+                onMesaClick={handleMesaClick}
+                isLoading={cargandoMesas}
+                isError={errorMesas}
+                aperturas={aperturas}
+              />
+            )}
           </div>
         </div>
 
         {/* ── Panel Derecho: Sidebar Resumen (25%) ── */}
-        <aside className="w-1/4 border-l border-neutral-800 bg-neutral-950 overflow-hidden">
-          <SidebarResumen
-            mesasAbiertas={mesasAbiertas}
-            onMesaClick={handleMesaClick}
-          />
+        <aside className="w-1/4 border-l border-neutral-800 bg-neutral-950 overflow-hidden flex flex-col">
+          <PanelAutopedidos />
+          <PanelLinksPago />
+          <div className="flex-1 min-h-0">
+            <SidebarResumen
+              mesasAbiertas={mesasAbiertas}
+              onMesaClick={handleMesaClick}
+            />
+          </div>
         </aside>
       </section>
 
@@ -139,6 +174,67 @@ export default function SalonPage() {
                 </span>
               </p>
 
+              {/* HU-108: Canal de venta → lista de precios del pedido */}
+              <div className="grid grid-cols-3 gap-2" role="radiogroup" aria-label="Canal de venta">
+                {(Object.keys(CANAL_VENTA_LABELS) as CanalVenta[]).map((canal) => (
+                  <button
+                    key={canal}
+                    type="button"
+                    role="radio"
+                    aria-checked={canalApertura === canal}
+                    onClick={() => setCanalApertura(canal)}
+                    className={`
+                      h-9 rounded-lg text-xs font-semibold border transition-colors
+                      ${canalApertura === canal
+                        ? 'bg-red-600/20 border-red-600 text-red-300'
+                        : 'bg-neutral-800 border-neutral-700 text-gray-400 hover:border-neutral-600'}
+                    `}
+                  >
+                    {CANAL_VENTA_LABELS[canal]}
+                  </button>
+                ))}
+              </div>
+
+              {/* HU-111: Mozo a cargo y cubiertos (para el reporte de desempeño) */}
+              <div className="flex gap-2 text-left">
+                {mozosActivos.length > 0 && (
+                  <select
+                    value={mozoApertura}
+                    onChange={(e) => setMozoApertura(e.target.value)}
+                    aria-label="Mozo"
+                    className="flex-1 h-9 px-2 rounded-lg bg-neutral-800 border border-neutral-700 text-xs text-gray-300 focus:outline-none focus:border-red-600"
+                  >
+                    <option value="">Sin mozo</option>
+                    {mozosActivos.map((m) => (
+                      <option key={m.id} value={m.id}>
+                        {m.nombre}
+                      </option>
+                    ))}
+                  </select>
+                )}
+                <div className="flex items-center gap-1">
+                  <button
+                    type="button"
+                    onClick={() => setCubiertosApertura(Math.max(0, cubiertosApertura - 1))}
+                    className="w-9 h-9 rounded-lg bg-neutral-800 border border-neutral-700 text-gray-400 hover:border-neutral-600"
+                    aria-label="Menos cubiertos"
+                  >
+                    −
+                  </button>
+                  <span className="w-16 text-center text-xs text-gray-300 tabular-nums">
+                    {cubiertosApertura > 0 ? `${cubiertosApertura} cub.` : 'Cubiertos'}
+                  </span>
+                  <button
+                    type="button"
+                    onClick={() => setCubiertosApertura(Math.min(99, cubiertosApertura + 1))}
+                    className="w-9 h-9 rounded-lg bg-neutral-800 border border-neutral-700 text-gray-400 hover:border-neutral-600"
+                    aria-label="Más cubiertos"
+                  >
+                    +
+                  </button>
+                </div>
+              </div>
+
               {/* Botones */}
               <div className="flex gap-3 pt-2">
                 <button
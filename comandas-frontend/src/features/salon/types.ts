@@ -18,8 +18,41 @@ export type EstadoPedido = 'ABIERTO' | 'CERRADO';
 /**
  * Medio de pago aceptado por el local.
  * A_CUENTA = consumo interno (empleados), no cuenta como venta real.
+ * CUENTA_CORRIENTE = cliente frecuente que paga a fin de mes (HU-104); es venta real.
+ * TARJETA_REGALO = gift card prepaga (HU-144); la plata entró a la caja al venderla.
  */
-export type MedioPago = 'EFECTIVO' | 'TARJETA' | 'TRANSFERENCIA' | 'QR' | 'A_CUENTA';
+export type MedioPago =
+  | 'EFECTIVO'
+  | 'TARJETA'
+  | 'TRANSFERENCIA'
+  | 'QR'
+  | 'A_CUENTA'
+  | 'CUENTA_CORRIENTE'
+  | 'TARJETA_REGALO';
+
+/**
+ * HU-151: Moneda en la que paga el cliente.
+ * Solo efectivo, tarjeta y transferencia aceptan USD y EUR.
+ */
+export type Moneda = 'ARS' | 'USD' | 'EUR';
+
+/** HU-151: Medios de pago que aceptan moneda extranjera */
+export const MEDIOS_MONEDA_EXTRANJERA: MedioPago[] = ['EFECTIVO', 'TARJETA', 'TRANSFERENCIA'];
+
+/** HU-156: Medios de pago que llevan número de cupón u operación */
+export const MEDIOS_CON_COMPROBANTE: MedioPago[] = ['TARJETA', 'TRANSFERENCIA', 'QR'];
+
+/**
+ * HU-108: Canal de venta del pedido.
+ * Define la lista de precios que se congela al abrir la mesa.
+ */
+export type CanalVenta = 'SALON' | 'TAKE_AWAY' | 'DELIVERY';
+
+export const CANAL_VENTA_LABELS: Record<CanalVenta, string> = {
+  SALON: 'Salón',
+  TAKE_AWAY: 'Take away',
+  DELIVERY: 'Delivery',
+};
 
 // ─── Entidades ────────────────────────────────────────────────────────────────
 
@@ -31,13 +64,38 @@ export interface Mesa {
   id: string;
   numero: number;
   estado: EstadoMesa;
+  /** HU-112: Sector del salón (salón, vereda, barra...) */
+  sector: string;
+  /** HU-112: Celda en el mapa del sector; null = se acomoda sola */
+  posicionX: number | null;
+  posicionY: number | null;
+  /** HU-149: Personas que entran; null = sin definir (no se sugiere en la lista de espera) */
+  capacidad: number | null;
 }
 
+/** HU-112: Sector de las mesas sin sector explícito (igual que en el backend) */
+export const SECTOR_POR_DEFECTO = 'Salón';
+
 // ─── Requests ─────────────────────────────────────────────────────────────────
 
 /** DTO para crear una nueva mesa */
 export interface CrearMesaRequest {
   numero: number;
+  /** HU-112: Alta directa en una celda del mapa */
+  sector?: string;
+  posicionX?: number | null;
+  posicionY?: number | null;
+}
+
+/** HU-112: Número, sector y celda de una mesa en el mapa del salón */
+export interface UbicacionMesa {
+  mesaId: string;
+  numero: number;
+  sector: string;
+  posicionX: number | null;
+  posicionY: number | null;
+  /** HU-149: Personas que entran; null = sin definir */
+  capacidad: number | null;
 }
 
 /** DTO de entrada para abrir una mesa (iniciar pedido) */
@@ -45,17 +103,44 @@ export interface AbrirMesaRequest {
   mesaId: string;
 }
 
+/** Opciones al abrir una mesa (viajan como query params) */
+export interface AperturaMesa {
+  /** HU-108: Lista de precios del pedido (default: SALON) */
+  canal?: CanalVenta;
+  /** HU-111: Mozo a cargo de la mesa */
+  mozoId?: string;
+  /** HU-111: Cantidad de comensales */
+  cubiertos?: number;
+}
+
 /** Pago individual dentro del cierre de mesa */
 export interface PagoRequest {
   /** Medio de pago utilizado */
   medio: MedioPago;
   /** Monto del pago (debe ser > 0) */
   monto: number;
+  /** HU-144: Código de la tarjeta; obligatorio si el medio es TARJETA_REGALO */
+  codigoTarjetaRegalo?: string;
+  /**
+   * HU-151: Moneda en que paga el cliente (ARS si se omite).
+   * El monto va siempre en pesos; el backend registra el equivalente en la moneda.
+   */
+  moneda?: Moneda;
+  /** HU-156: Número de cupón u operación del comprobante (no aplica al efectivo) */
+  referencia?: string;
 }
 
 /** Body HTTP para cerrar una mesa con pagos split (HU-04, HU-12). El mesaId viaja como path param. */
 export interface CerrarMesaRequest {
   pagos: PagoRequest[];
+  /** HU-104: Cliente a cargar; obligatorio si algún pago es CUENTA_CORRIENTE */
+  clienteId?: string;
+  /** HU-105: Puntos del cliente a canjear como descuento (requiere clienteId) */
+  puntosACanjear?: number;
+  /** HU-111: Propina de la mesa; no forma parte de los pagos */
+  propina?: number;
+  /** HU-132: Empleado al que se imputan los pagos A_CUENTA */
+  empleadoConsumoId?: string;
 }
 
 // ─── Responses ────────────────────────────────────────────────────────────────
@@ -76,8 +161,14 @@ export interface AbrirMesaResponse {
 export interface PagoResponse {
   medio: MedioPago;
   monto: number;
+  /** HU-143: Recargo del medio cobrado encima del monto (congelado al cerrar) */
+  recargo: number;
   /** ISO 8601 datetime */
   fecha: string;
+  /** HU-152: Redondeo del efectivo sumado o restado al monto */
+  ajusteRedondeo: number;
+  /** HU-156: Número de cupón u operación del comprobante */
+  referencia: string | null;
 }
 
 /** Respuesta al cerrar una mesa: snapshot contable congelado */
@@ -94,8 +185,31 @@ export interface CerrarMesaResponse {
   /** Total final congelado */
   montoTotal: number;
   pagos: PagoResponse[];
+  /** HU-143: Suma de los recargos por medio de pago, aparte del total */
+  totalRecargos: number;
   /** ISO 8601 datetime */
   fechaCierre: string;
+  /** HU-132: Aviso si el empleado superó su tope de consumo del mes (null si no) */
+  avisoConsumoInterno: string | null;
+  /** HU-152: Ajuste por redondeo del efectivo, aparte del total */
+  ajusteRedondeo: number;
+  /** HU-166: Número de comprobante de la serie de la caja (null si la caja no tiene serie) */
+  numeroComprobante: string | null;
+}
+
+/**
+ * HU-160: Pagos a cuenta de una mesa abierta.
+ * Refleja PagosACuentaResponse del backend (también es el payload del evento "pago-a-cuenta").
+ */
+export interface PagosACuentaResponse {
+  pedidoId: string;
+  mesaId: string;
+  total: number;
+  pagadoACuenta: number;
+  /** Negativo si se quitaron ítems ya pagados: hay que anular un pago antes de cerrar */
+  saldoPendiente: number;
+  /** En el orden en que se cobraron; la posición sirve para anularlos */
+  pagos: PagoResponse[];
 }
 
 /** Respuesta a la reapertura de un pedido cerrado (HU-14) */
@@ -113,3 +227,76 @@ export interface ReabrirPedidoResponse {
 }
 
 
+
+// ── HU-113: Alerta de mesas olvidadas ──
+
+/** Configuración de la alerta (compartida por todas las terminales del local) */
+export interface AlertaMesasOlvidadasConfig {
+  activa: boolean;
+  /** Umbral de inactividad: entre 5 y 240 minutos */
+  minutosSinActividad: number;
+}
+
+/** Mesa ocupada que superó el umbral sin ítems nuevos ni pedido de cuenta */
+export interface MesaOlvidada {
+  mesaId: string;
+  numeroMesa: number;
+  sector: string;
+  pedidoId: string;
+  numeroPedido: number;
+  /** ISO 8601 datetime — última atención registrada */
+  ultimaActividad: string;
+  minutosSinActividad: number;
+}
+
+export interface MesasOlvidadasResponse {
+  alertaActiva: boolean;
+  minutosSinActividad: number;
+  /** De la más olvidada a la menos */
+  mesas: MesaOlvidada[];
+}
+
+// ── HU-150: Rotación de mesas ──
+
+export type DiaSemana = 'MONDAY' | 'TUESDAY' | 'WEDNESDAY' | 'THURSDAY' | 'FRIDAY' | 'SATURDAY' | 'SUNDAY';
+
+export const DIA_SEMANA_LABELS: Record<DiaSemana, string> = {
+  MONDAY: 'Lunes',
+  TUESDAY: 'Martes',
+  WEDNESDAY: 'Miércoles',
+  THURSDAY: 'Jueves',
+  FRIDAY: 'Viernes',
+  SATURDAY: 'Sábado',
+  SUNDAY: 'Domingo',
+};
+
+/** Ocupaciones de mesa abiertas en una hora (la franja 21 va de 21:00 a 21:59) */
+export interface FranjaOcupacion {
+  hora: number;
+  ocupaciones: number;
+  /** Mesas abiertas en la franja, en promedio por día analizado */
+  ocupacionesPorDia: number;
+  promedioMinutos: number;
+  /** null si ninguna mesa de la franja informó cubiertos */
+  cubiertosPromedio: number | null;
+  /** Mesas que el salón puede sentar por hora con esa duración promedio */
+  capacidadPorHora: number | null;
+  /** Estimado de comensales más que entran por día en la franja */
+  cubiertosDisponibles: number | null;
+}
+
+export interface ReporteRotacionMesas {
+  /** YYYY-MM-DD */
+  desde: string;
+  /** YYYY-MM-DD */
+  hasta: string;
+  diaSemana: DiaSemana | null;
+  diasAnalizados: number;
+  mesas: number;
+  ocupaciones: number;
+  promedioMinutos: number;
+  cubiertos: number;
+  /** Ocupaciones por mesa por día */
+  rotacionPorMesa: number;
+  franjas: FranjaOcupacion[];
+}
@@ -0,0 +1,86 @@
+/**
+ * Utilidades para dibujar el mapa del salón (HU-112).
+ *
+ * El backend guarda por mesa un sector y, opcionalmente, una celda
+ * (columna, fila). Las mesas sin celda —las creadas antes del editor o con
+ * "Nueva Mesa"— se acomodan por número en los huecos libres del sector,
+ * así el salón se ve igual que la grilla de siempre hasta que alguien lo edite.
+ */
+
+import { SECTOR_POR_DEFECTO, type Mesa } from '../types';
+
+/** Columnas del mapa: replica la distribución de 7 mesas por fila del local */
+export const COLUMNAS_MAPA = 7;
+
+export interface CeldaMesa {
+  mesa: Mesa;
+  x: number;
+  y: number;
+}
+
+const claveCelda = (x: number, y: number) => `${x},${y}`;
+
+/** Clave para comparar sectores sin importar mayúsculas ni espacios */
+export const claveSector = (sector: string) => sector.trim().toLocaleLowerCase();
+
+/**
+ * Sectores presentes en las mesas: primero el sector por defecto (si tiene
+ * mesas), después el resto en el orden de su mesa de número más bajo.
+ */
+export function sectoresDe(mesas: Mesa[]): string[] {
+  const sectores = new Map<string, string>();
+  [...mesas]
+    .sort((a, b) => a.numero - b.numero)
+    .forEach((m) => {
+      const clave = claveSector(m.sector || SECTOR_POR_DEFECTO);
+      if (!sectores.has(clave)) sectores.set(clave, m.sector || SECTOR_POR_DEFECTO);
+    });
+
+  const porDefecto = claveSector(SECTOR_POR_DEFECTO);
+  return [...sectores.entries()]
+    .sort(([a], [b]) => (a === porDefecto ? -1 : b === porDefecto ? 1 : 0))
+    .map(([, nombre]) => nombre);
+}
+
+/**
+ * Celdas de las mesas de un sector.
+ * Respeta la posición guardada (si entra en el mapa y no está pisada) y
+ * completa el resto en orden de número, de izquierda a derecha y de arriba abajo.
+ */
+export function ubicarEnMapa(mesas: Mesa[], sector: string): CeldaMesa[] {
+  const delSector = mesas
+    .filter((m) => claveSector(m.sector || SECTOR_POR_DEFECTO) === claveSector(sector))
+    .sort((a, b) => a.numero - b.numero);
+
+  const ocupadas = new Set<string>();
+  const celdas: CeldaMesa[] = [];
+  const sinUbicar: Mesa[] = [];
+
+  for (const mesa of delSector) {
+    const { posicionX: x, posicionY: y } = mesa;
+    if (x != null && y != null && x < COLUMNAS_MAPA && !ocupadas.has(claveCelda(x, y))) {
+      ocupadas.add(claveCelda(x, y));
+      celdas.push({ mesa, x, y });
+    } else {
+      sinUbicar.push(mesa);
+    }
+  }
+
+  let indice = 0;
+  for (const mesa of sinUbicar) {
+    while (ocupadas.has(claveCelda(indice % COLUMNAS_MAPA, Math.floor(indice / COLUMNAS_MAPA)))) {
+      indice++;
+    }
+    const x = indice % COLUMNAS_MAPA;
+    const y = Math.floor(indice / COLUMNAS_MAPA);
+    ocupadas.add(claveCelda(x, y));
+    celdas.push({ mesa, x, y });
+  }
+
+  return celdas;
+}
+
+/** Filas ocupadas por las celdas (al menos una) */
+export function filasDe(celdas: CeldaMesa[]): number {
+  return Math.max(1, ...celdas.map((c) => c.y + 1));
+}
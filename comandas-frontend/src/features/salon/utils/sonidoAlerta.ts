@@ -0,0 +1,30 @@
+/**
+ * Sonido de aviso generado con Web Audio (HU-113).
+ * HU-137: También avisa en la pantalla de cocina cuando entra una comanda urgente.
+ *
+ * Dos pitidos cortos; no depende de archivos de audio empaquetados.
+ * Si el webview no permite reproducir (sin interacción previa del
+ * usuario), el aviso queda solo en pantalla.
+ */
+export function reproducirSonidoAlerta(): void {
+  const AudioCtx = window.AudioContext ?? (window as any).webkitAudioContext;
+  if (!AudioCtx) return;
+
+  try {
+    const ctx: AudioContext = new AudioCtx();
+    [0, 0.25].forEach((inicio) => {
+      const oscilador = ctx.createOscillator();
+      const volumen = ctx.createGain();
+      oscilador.type = 'sine';
+      oscilador.frequency.value = 880;
+      volumen.gain.setValueAtTime(0.3, ctx.currentTime + inicio);
+      volumen.gain.exponentialRampToValueAtTime(0.001, ctx.currentTime + inicio + 0.2);
+      oscilador.connect(volumen).connect(ctx.destination);
+      oscilador.start(ctx.currentTime + inicio);
+      oscilador.stop(ctx.currentTime + inicio + 0.2);
+    });
+    setTimeout(() => ctx.close(), 1000);
+  } catch (error) {
+    console.warn('[sonidoAlerta] No se pudo reproducir el aviso:', error);
+  }
+}
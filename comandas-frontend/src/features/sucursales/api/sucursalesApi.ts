@@ -0,0 +1,45 @@
+import apiClient from '../../../lib/apiClient';
+import type { ReporteSucursales, ResultadoSync, SyncConfig } from '../types';
+
+/**
+ * API client del reporte comparativo por sucursal (HU-107).
+ * Consume GET /api/sync/reporte-sucursales de SyncController.
+ *
+ * El intercambio de ventas entre instalaciones no pasa por acá:
+ * lo hace la capa de sync de Tauri (ver `sincronizacionApi`).
+ */
+export const sucursalesApi = {
+  /** @param desde,hasta fechas ISO (YYYY-MM-DD), ambas inclusive */
+  obtenerReporte: async (desde: string, hasta: string): Promise<ReporteSucursales> => {
+    const response = await apiClient.get<ReporteSucursales>('/sync/reporte-sucursales', {
+      params: { desde, hasta },
+    });
+    return response.data;
+  },
+};
+
+/** Misma detección que printerService: la sync solo existe en la app de escritorio */
+export function esTauri(): boolean {
+  return typeof window !== 'undefined' && '__TAURI_INTERNALS__' in window;
+}
+
+/**
+ * Comandos Tauri de sincronización (src-tauri/src/sync.rs).
+ * Import dinámico para no romper en navegador.
+ */
+export const sincronizacionApi = {
+  obtenerConfig: async (): Promise<SyncConfig> => {
+    const { invoke } = await import('@tauri-apps/api/core');
+    return invoke<SyncConfig>('obtener_config_sync');
+  },
+
+  guardarConfig: async (config: SyncConfig): Promise<string> => {
+    const { invoke } = await import('@tauri-apps/api/core');
+    return invoke<string>('configurar_sync', { config });
+  },
+
+  sincronizarAhora: async (): Promise<ResultadoSync> => {
+    const { invoke } = await import('@tauri-apps/api/core');
+    return invoke<ResultadoSync>('sincronizar_ahora');
+  },
+};
@@ -0,0 +1,180 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, Calendar, Loader2, Store } from 'lucide-react';
+import type { MedioPago } from '../../salon/types';
+import { esTauri } from '../api/sucursalesApi';
+import { useReporteSucursales } from '../hooks/useSucursales';
+import type { SucursalResumen } from '../types';
+import SincronizacionPanel from './SincronizacionPanel';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+const MEDIO_LABELS: Record<MedioPago, string> = {
+  EFECTIVO: 'Efectivo',
+  TARJETA: 'Tarjeta',
+  TRANSFERENCIA: 'Transferencia',
+  QR: 'QR',
+  CUENTA_CORRIENTE: 'Cta. corriente',
+  TARJETA_REGALO: 'Tarjeta de regalo',
+  A_CUENTA: 'A cuenta',
+};
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function isoLocal(fecha: Date): string {
+  const y = fecha.getFullYear();
+  const m = String(fecha.getMonth() + 1).padStart(2, '0');
+  const d = String(fecha.getDate()).padStart(2, '0');
+  return `${y}-${m}-${d}`;
+}
+
+function ultimaVenta(iso: string | null): string {
+  if (!iso) return 'sin ventas en el rango';
+  return `última venta ${new Date(iso).toLocaleString('es-AR', {
+    day: '2-digit', month: '2-digit', hour: '2-digit', minute: '2-digit',
+  })}`;
+}
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+// ─── Tarjeta de sucursal ──────────────────────────────────────────────────────
+
+function TarjetaSucursal({ sucursal, participacion }: { sucursal: SucursalResumen; participacion: number }) {
+  const medios = (Object.entries(sucursal.porMedioPago) as [MedioPago, number][])
+    .filter(([, monto]) => monto > 0)
+    .sort(([, a], [, b]) => b - a);
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-3">
+      <div className="flex items-start justify-between gap-3">
+        <div className="min-w-0">
+          <p className="font-semibold text-gray-100 truncate">
+            {sucursal.nombre}
+            {sucursal.esEstaInstalacion && (
+              <span className="ml-2 text-[10px] uppercase tracking-wide text-red-400">esta sucursal</span>
+            )}
+          </p>
+          <p className="text-[11px] text-gray-500">{ultimaVenta(sucursal.ultimaVenta)}</p>
+        </div>
+        <p className="text-lg font-mono tabular-nums text-gray-100 shrink-0">$ {fmt(sucursal.totalVentas)}</p>
+      </div>
+
+      <div className="h-1.5 rounded-full bg-neutral-800 overflow-hidden">
+        <div className="h-full bg-red-600" style={{ width: `${Math.round(participacion * 100)}%` }} />
+      </div>
+
+      <div className="grid grid-cols-3 gap-2 text-center">
+        <div>
+          <p className="text-[10px] uppercase tracking-wider text-gray-600">Pedidos</p>
+          <p className="font-mono tabular-nums text-gray-300">{sucursal.cantidadPedidos}</p>
+        </div>
+        <div>
+          <p className="text-[10px] uppercase tracking-wider text-gray-600">Ticket prom.</p>
+          <p className="font-mono tabular-nums text-gray-300">$ {fmt(sucursal.ticketPromedio)}</p>
+        </div>
+        <div>
+          <p className="text-[10px] uppercase tracking-wider text-gray-600">Participación</p>
+          <p className="font-mono tabular-nums text-gray-300">{Math.round(participacion * 100)}%</p>
+        </div>
+      </div>
+
+      {medios.length > 0 && (
+        <ul className="divide-y divide-neutral-800 text-xs">
+          {medios.map(([medio, monto]) => (
+            <li key={medio} className="flex justify-between py-1.5">
+              <span className={medio === 'A_CUENTA' ? 'text-gray-600' : 'text-gray-400'}>{MEDIO_LABELS[medio]}</span>
+              <span className="font-mono tabular-nums text-gray-300">$ {fmt(monto)}</span>
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Reporte comparativo de ventas por sucursal (HU-107).
+ *
+ * Cada sucursal se muestra por separado con sus ventas reales, pedidos y
+ * desglose por medio de pago. Las cajas no se suman entre sí: el único
+ * agregado es el total de ventas del rango.
+ */
+export default function ReporteSucursalesPage() {
+  const hoy = new Date();
+  const [desde, setDesde] = useState(isoLocal(new Date(hoy.getFullYear(), hoy.getMonth(), 1)));
+  const [hasta, setHasta] = useState(isoLocal(hoy));
+
+  const { data: reporte, isLoading, isError } = useReporteSucursales(desde, hasta);
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/historial"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Sucursales</h1>
+            <p className="text-sm text-gray-500">Comparativo de ventas por local</p>
+          </div>
+        </header>
+
+        <div className="flex items-center gap-3">
+          <Calendar size={14} className="text-gray-500" />
+          <input type="date" value={desde} onChange={(e) => setDesde(e.target.value)} className={inputFecha} />
+          <span className="text-gray-600 text-xs">→</span>
+          <input type="date" value={hasta} onChange={(e) => setHasta(e.target.value)} className={inputFecha} />
+          {reporte && (
+            <span className="ml-auto text-sm text-gray-400">
+              Total <span className="font-mono tabular-nums text-gray-100">$ {fmt(reporte.totalVentas)}</span>
+            </span>
+          )}
+        </div>
+
+        <div className="grid grid-cols-1 xl:grid-cols-12 gap-5">
+          <div className="xl:col-span-8 space-y-4">
+            {isLoading ? (
+              <div className="flex items-center gap-2 text-sm text-gray-500">
+                <Loader2 size={16} className="animate-spin" /> Cargando reporte...
+              </div>
+            ) : isError ? (
+              <p className="text-sm text-red-400">No se pudo cargar el reporte de sucursales.</p>
+            ) : reporte && (
+              <>
+                <div className="grid grid-cols-1 md:grid-cols-2 gap-4">
+                  {reporte.sucursales.map((s) => (
+                    <TarjetaSucursal
+                      key={s.sucursalId}
+                      sucursal={s}
+                      participacion={reporte.totalVentas > 0 ? s.totalVentas / reporte.totalVentas : 0}
+                    />
+                  ))}
+                </div>
+                {reporte.sucursales.length === 1 && (
+                  <div className="flex items-center gap-2 text-xs text-gray-500">
+                    <Store size={14} />
+                    Todavía no se recibieron ventas de otras sucursales en este rango.
+                  </div>
+                )}
+              </>
+            )}
+          </div>
+
+          {esTauri() && (
+            <div className="xl:col-span-4">
+              <SincronizacionPanel />
+            </div>
+          )}
+        </div>
+      </div>
+    </section>
+  );
+}
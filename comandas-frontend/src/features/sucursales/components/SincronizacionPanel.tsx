@@ -0,0 +1,140 @@
+import { useEffect, useState } from 'react';
+import { Loader2, RefreshCw, Save, Share2 } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useConfigSync, useGuardarConfigSync, useSincronizarAhora } from '../hooks/useSucursales';
+import type { SyncConfig } from '../types';
+
+const inputClass =
+  'h-9 w-full px-3 rounded-lg bg-neutral-800 border border-neutral-700 text-sm text-gray-200 focus:outline-none focus:border-red-600 disabled:opacity-50';
+
+function fechaHora(iso: string | null): string {
+  if (!iso) return 'nunca';
+  return new Date(iso).toLocaleString('es-AR', {
+    day: '2-digit', month: '2-digit', hour: '2-digit', minute: '2-digit',
+  });
+}
+
+/**
+ * Configuración de la sincronización con el consolidado (HU-107).
+ *
+ * Solo se muestra en la app de escritorio: la identidad de sucursal y el
+ * envío de ventas viven en la capa Rust (sync_config.json), no en el backend.
+ */
+export default function SincronizacionPanel() {
+  const toast = useToast();
+  const { data: config, isLoading } = useConfigSync();
+  const guardar = useGuardarConfigSync();
+  const sincronizar = useSincronizarAhora();
+
+  const [borrador, setBorrador] = useState<SyncConfig | null>(null);
+
+  useEffect(() => {
+    if (config) setBorrador(config);
+  }, [config]);
+
+  if (isLoading || !borrador) {
+    return (
+      <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 flex items-center gap-2 text-xs text-gray-500">
+        <Loader2 size={14} className="animate-spin" /> Cargando sincronización...
+      </div>
+    );
+  }
+
+  const actualizar = (cambios: Partial<SyncConfig>) =>
+    setBorrador((prev) => (prev ? { ...prev, ...cambios } : prev));
+
+  const handleGuardar = () => {
+    guardar.mutate(borrador, {
+      onSuccess: (mensaje) => toast.success(mensaje),
+      onError: (err) => toast.error(String(err) || 'No se pudo guardar la configuración'),
+    });
+  };
+
+  const handleSincronizar = () => {
+    sincronizar.mutate(undefined, {
+      onSuccess: (r) => toast.success(r.mensaje),
+      onError: (err) => toast.error(String(err) || 'No se pudo sincronizar'),
+    });
+  };
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-3">
+      <div className="flex items-center gap-2">
+        <Share2 size={14} className="text-gray-500" />
+        <h2 className="text-xs text-gray-500 uppercase tracking-wider font-medium">Sincronización</h2>
+      </div>
+
+      <p className="text-[11px] text-gray-600 font-mono break-all">
+        Sucursal {borrador.sucursal_id}
+      </p>
+
+      <label className="block space-y-1 text-xs text-gray-500">
+        <span className="block">Nombre de esta sucursal</span>
+        <input
+          value={borrador.sucursal_nombre}
+          onChange={(e) => actualizar({ sucursal_nombre: e.target.value })}
+          placeholder="Nombre del local"
+          className={inputClass}
+        />
+      </label>
+
+      <label className="block space-y-1 text-xs text-gray-500">
+        <span className="block">URL del consolidado</span>
+        <input
+          value={borrador.url_central}
+          onChange={(e) => actualizar({ url_central: e.target.value })}
+          placeholder="http://192.168.1.50:8080"
+          className={`${inputClass} font-mono`}
+        />
+      </label>
+
+      <div className="flex items-center justify-between gap-3">
+        <label className="flex items-center gap-2 text-sm text-gray-300">
+          <input
+            type="checkbox"
+            checked={borrador.habilitado}
+            onChange={(e) => actualizar({ habilitado: e.target.checked })}
+            className="accent-red-600"
+          />
+          Enviar ventas cada
+        </label>
+        <div className="flex items-center gap-1.5 text-xs text-gray-500">
+          <input
+            type="number"
+            min="1"
+            value={Math.round(borrador.intervalo_segundos / 60)}
+            onChange={(e) => actualizar({ intervalo_segundos: Math.max(1, parseInt(e.target.value) || 1) * 60 })}
+            className={`${inputClass} w-16 text-right font-mono`}
+          />
+          min
+        </div>
+      </div>
+
+      <p className="text-[11px] text-gray-600">
+        Última venta aceptada: {fechaHora(borrador.ultimo_cursor)}
+      </p>
+
+      <div className="flex gap-2">
+        <button
+          type="button"
+          onClick={handleGuardar}
+          disabled={guardar.isPending}
+          className="flex-1 h-9 rounded-lg bg-red-600 hover:bg-red-700 text-sm text-white flex items-center justify-center gap-1.5 disabled:opacity-40"
+        >
+          {guardar.isPending ? <Loader2 size={14} className="animate-spin" /> : <Save size={14} />}
+          Guardar
+        </button>
+        <button
+          type="button"
+          onClick={handleSincronizar}
+          disabled={sincronizar.isPending || !config?.url_central}
+          title={!config?.url_central ? 'Guardá la URL del consolidado primero' : undefined}
+          className="flex-1 h-9 rounded-lg bg-neutral-800 hover:bg-neutral-700 text-sm text-gray-200 flex items-center justify-center gap-1.5 disabled:opacity-40"
+        >
+          <RefreshCw size={14} className={sincronizar.isPending ? 'animate-spin' : ''} />
+          Sincronizar ahora
+        </button>
+      </div>
+    </div>
+  );
+}
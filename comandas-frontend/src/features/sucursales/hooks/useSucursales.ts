@@ -0,0 +1,68 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { esTauri, sincronizacionApi, sucursalesApi } from '../api/sucursalesApi';
+import type { ReporteSucursales, SyncConfig } from '../types';
+
+export const sucursalesKeys = {
+  reporte: (desde: string, hasta: string) => ['reporte-sucursales', desde, hasta] as const,
+  configSync: ['config-sync'] as const,
+};
+
+/**
+ * Reporte comparativo de ventas por sucursal en un rango de fechas.
+ *
+ * queryKey: ['reporte-sucursales', desde, hasta] — se invalida tras
+ * una sincronización manual para reflejar las ventas recién enviadas.
+ */
+export function useReporteSucursales(desde: string, hasta: string) {
+  return useQuery<ReporteSucursales>({
+    queryKey: sucursalesKeys.reporte(desde, hasta),
+    queryFn: () => sucursalesApi.obtenerReporte(desde, hasta),
+    enabled: !!desde && !!hasta && desde <= hasta,
+    staleTime: 60_000,
+  });
+}
+
+/**
+ * Configuración de sincronización de esta instalación.
+ * Solo disponible dentro de Tauri; en navegador la query queda deshabilitada.
+ */
+export function useConfigSync() {
+  return useQuery<SyncConfig>({
+    queryKey: sucursalesKeys.configSync,
+    queryFn: sincronizacionApi.obtenerConfig,
+    enabled: esTauri(),
+    staleTime: Infinity,
+  });
+}
+
+export function useGuardarConfigSync() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (config: SyncConfig) => sincronizacionApi.guardarConfig(config),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: sucursalesKeys.configSync });
+    },
+    onError: (error: unknown) => {
+      console.error('[useGuardarConfigSync] Error al guardar configuración:', error);
+    },
+  });
+}
+
+/**
+ * Fuerza un ciclo de sincronización sin esperar al intervalo.
+ */
+export function useSincronizarAhora() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: sincronizacionApi.sincronizarAhora,
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: sucursalesKeys.configSync });
+      queryClient.invalidateQueries({ queryKey: ['reporte-sucursales'], exact: false });
+    },
+    onError: (error: unknown) => {
+      console.error('[useSincronizarAhora] Error al sincronizar:', error);
+    },
+  });
+}
@@ -0,0 +1,30 @@
+/**
+ * Módulo Sucursales — identidad de la instalación, sincronización de
+ * ventas con el consolidado y reporte comparativo (HU-107).
+ *
+ * @example
+ * import { ReporteSucursalesPage } from '@/features/sucursales';
+ */
+
+// Tipos
+export type {
+  SucursalResumen,
+  ReporteSucursales,
+  SyncConfig,
+  ResultadoSync,
+} from './types';
+
+// Hooks
+export {
+  useReporteSucursales,
+  useConfigSync,
+  useGuardarConfigSync,
+  useSincronizarAhora,
+} from './hooks/useSucursales';
+
+// Componentes
+export { default as ReporteSucursalesPage } from './components/ReporteSucursalesPage';
+export { default as SincronizacionPanel } from './components/SincronizacionPanel';
+
+// API
+export { sucursalesApi, sincronizacionApi } from './api/sucursalesApi';
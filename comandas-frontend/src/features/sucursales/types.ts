@@ -0,0 +1,49 @@
+import type { MedioPago } from '../salon/types';
+
+// ─── Reporte comparativo (HU-107) ────────────────────────────────────────────
+
+/** Fila del reporte: una sucursal, sin mezclar con las demás */
+export interface SucursalResumen {
+  sucursalId: string;
+  nombre: string;
+  /** true para la instalación que genera el reporte (datos en vivo) */
+  esEstaInstalacion: boolean;
+  cantidadPedidos: number;
+  /** Ventas reales: todos los pagos salvo A_CUENTA */
+  totalVentas: number;
+  ticketPromedio: number;
+  porMedioPago: Partial<Record<MedioPago, number>>;
+  /** Último cierre recibido; null si no hubo ventas en el rango */
+  ultimaVenta: string | null;
+}
+
+export interface ReporteSucursales {
+  desde: string;
+  hasta: string;
+  sucursales: SucursalResumen[];
+  totalVentas: number;
+}
+
+// ─── Sincronización (comandos Tauri) ─────────────────────────────────────────
+
+/** Espejo de `sync::SyncConfig` en src-tauri */
+export interface SyncConfig {
+  habilitado: boolean;
+  /** URL base del consolidado, ej: http://192.168.1.50:8080 */
+  url_central: string;
+  /** Identidad de esta instalación (solo lectura) */
+  sucursal_id: string;
+  sucursal_nombre: string;
+  intervalo_segundos: number;
+  /** Cierre de la última venta aceptada por el consolidado */
+  ultimo_cursor: string | null;
+  /** Pedido de esa venta: desempata las ventas con el mismo cierre */
+  ultimo_cursor_pedido: string | null;
+}
+
+export interface ResultadoSync {
+  enviadas: number;
+  cursor: string | null;
+  /** HU-154: Resumen ya redactado en el idioma de la instalación */
+  mensaje: string;
+}
@@ -0,0 +1,28 @@
+import apiClient from '../../../lib/apiClient';
+import type { EmitirTarjetaRegaloRequest, ReporteTarjetasRegalo, TarjetaRegalo } from '../types';
+
+/**
+ * API client de tarjetas de regalo (HU-144).
+ * Consume /api/tarjetas-regalo de TarjetaRegaloController.
+ */
+export const tarjetasRegaloApi = {
+  /** La venta entra a la caja como ingreso del día */
+  emitir: async (request: EmitirTarjetaRegaloRequest): Promise<TarjetaRegalo> => {
+    const response = await apiClient.post<TarjetaRegalo>('/tarjetas-regalo', request);
+    return response.data;
+  },
+
+  /** 400 si el código no existe en el local */
+  consultar: async (codigo: string): Promise<TarjetaRegalo> => {
+    const response = await apiClient.get<TarjetaRegalo>(`/tarjetas-regalo/${encodeURIComponent(codigo)}`);
+    return response.data;
+  },
+
+  /** @param desde @param hasta fechas YYYY-MM-DD, inclusive */
+  reporte: async (desde: string, hasta: string): Promise<ReporteTarjetasRegalo> => {
+    const response = await apiClient.get<ReporteTarjetasRegalo>('/tarjetas-regalo/reporte', {
+      params: { desde, hasta },
+    });
+    return response.data;
+  },
+};
@@ -0,0 +1,84 @@
+import { Gift, Loader2 } from 'lucide-react';
+import { useTarjetaRegalo } from '../hooks/useTarjetasRegalo';
+import { LONGITUD_CODIGO, normalizarCodigo } from '../types';
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 0, maximumFractionDigits: 2 });
+}
+
+function fmtDia(iso: string): string {
+  const [y, m, d] = iso.split('-');
+  return `${d}/${m}/${y}`;
+}
+
+interface CodigoTarjetaRegaloInputProps {
+  codigo: string;
+  onChange: (codigo: string) => void;
+  /** Monto a cobrar con la tarjeta, para avisar si el saldo no alcanza */
+  monto: number;
+  disabled?: boolean;
+}
+
+/**
+ * Código de la tarjeta de regalo en el cierre de mesa (HU-144).
+ *
+ * Al completar los 10 caracteres consulta el saldo y el vencimiento. Es
+ * solo un aviso: el backend vuelve a validar y descuenta el saldo al cerrar.
+ */
+export default function CodigoTarjetaRegaloInput({
+  codigo,
+  onChange,
+  monto,
+  disabled,
+}: CodigoTarjetaRegaloInputProps) {
+  const { data: tarjeta, isFetching, isError, error } = useTarjetaRegalo(codigo);
+  const completo = normalizarCodigo(codigo).length === LONGITUD_CODIGO;
+
+  let aviso: React.ReactNode = null;
+  if (!completo) {
+    aviso = <span className="text-gray-600">Código de {LONGITUD_CODIGO} caracteres</span>;
+  } else if (isFetching) {
+    aviso = <Loader2 size={12} className="animate-spin text-gray-500" />;
+  } else if (isError) {
+    aviso = (
+      <span className="text-red-400">
+        {(error as any)?.response?.data?.message || 'No se pudo validar la tarjeta'}
+      </span>
+    );
+  } else if (tarjeta) {
+    if (tarjeta.estado === 'VENCIDA') {
+      aviso = <span className="text-red-400">Venció el {fmtDia(tarjeta.vencimiento)}</span>;
+    } else if (tarjeta.estado === 'AGOTADA') {
+      aviso = <span className="text-red-400">Sin saldo</span>;
+    } else {
+      aviso = (
+        <span className={monto > tarjeta.saldo ? 'text-red-400' : 'text-pink-400'}>
+          Saldo $ {fmt(tarjeta.saldo)} · vence {fmtDia(tarjeta.vencimiento)}
+        </span>
+      );
+    }
+  }
+
+  return (
+    <div className="flex items-center gap-2 pl-1">
+      <Gift size={14} className="shrink-0 text-pink-400" />
+      <input
+        type="text"
+        value={codigo}
+        onChange={(e) => onChange(e.target.value.toUpperCase())}
+        placeholder="Código de la tarjeta"
+        disabled={disabled}
+        maxLength={LONGITUD_CODIGO + 4}
+        className="
+          w-40 h-8 px-2
+          bg-neutral-800 border border-neutral-700
+          rounded-lg text-xs font-mono tracking-wider text-gray-100
+          placeholder:text-gray-600 placeholder:tracking-normal
+          focus:outline-none focus:border-red-600
+          disabled:opacity-50
+        "
+      />
+      <span className="text-[11px] truncate">{aviso}</span>
+    </div>
+  );
+}
@@ -0,0 +1,287 @@
+import { useState } from 'react';
+import { Link } from 'react-router-dom';
+import { ArrowLeft, CalendarClock, Gift, Loader2 } from 'lucide-react';
+import useToast from '../../../hooks/useToast';
+import { useEmitirTarjetaRegalo, useReporteTarjetasRegalo } from '../hooks/useTarjetasRegalo';
+import { ESTADO_TARJETA_REGALO_LABELS, type TarjetaRegalo } from '../types';
+
+// ─── Utilidades ───────────────────────────────────────────────────────────────
+
+function fmt(valor: number): string {
+  return valor.toLocaleString('es-AR', { minimumFractionDigits: 2, maximumFractionDigits: 2 });
+}
+
+function isoDia(fecha: Date): string {
+  return `${fecha.getFullYear()}-${String(fecha.getMonth() + 1).padStart(2, '0')}-${String(fecha.getDate()).padStart(2, '0')}`;
+}
+
+function hoy(): string {
+  return isoDia(new Date());
+}
+
+function primeroDelMes(): string {
+  const fecha = new Date();
+  return isoDia(new Date(fecha.getFullYear(), fecha.getMonth(), 1));
+}
+
+function enUnAnio(): string {
+  const fecha = new Date();
+  return isoDia(new Date(fecha.getFullYear() + 1, fecha.getMonth(), fecha.getDate()));
+}
+
+function fmtDia(iso: string): string {
+  const [y, m, d] = iso.slice(0, 10).split('-');
+  return `${d}/${m}/${y}`;
+}
+
+/** Agrupa el código de a 5 para dictarlo o imprimirlo: ABCDE-FGHJK */
+function codigoLegible(codigo: string): string {
+  return `${codigo.slice(0, 5)}-${codigo.slice(5)}`;
+}
+
+const inputClass =
+  'min-h-[44px] w-full px-3 bg-background-card border border-gray-700 rounded-lg text-text-primary focus:border-primary focus:outline-none';
+
+const inputFecha =
+  'bg-neutral-800 border border-neutral-700 rounded-lg px-3 py-1.5 text-xs text-gray-300 font-mono focus:outline-none focus:ring-1 focus:ring-red-500/50 focus:border-red-500/50';
+
+const ESTADO_COLORES = {
+  VIGENTE: 'bg-pink-900/30 text-pink-400',
+  AGOTADA: 'bg-neutral-800 text-gray-500',
+  VENCIDA: 'bg-red-900/30 text-red-400',
+} as const;
+
+// ─── Vender tarjeta ───────────────────────────────────────────────────────────
+
+function VenderTarjeta() {
+  const toast = useToast();
+  const emitir = useEmitirTarjetaRegalo();
+
+  const [monto, setMonto] = useState('');
+  const [vencimiento, setVencimiento] = useState(enUnAnio);
+  const [comprador, setComprador] = useState('');
+  const [destinatario, setDestinatario] = useState('');
+  const [emitida, setEmitida] = useState<TarjetaRegalo | null>(null);
+
+  const montoNumero = Number(monto);
+  const puedeEmitir =
+    Number.isFinite(montoNumero) && montoNumero > 0 && vencimiento > hoy() && !emitir.isPending;
+
+  const handleEmitir = () => {
+    emitir.mutate(
+      {
+        monto: montoNumero,
+        vencimiento,
+        comprador: comprador.trim() || undefined,
+        destinatario: destinatario.trim() || undefined,
+      },
+      {
+        onSuccess: (tarjeta) => {
+          toast.success(`Tarjeta de $ ${fmt(tarjeta.montoInicial)} vendida`);
+          setEmitida(tarjeta);
+          setMonto('');
+          setComprador('');
+          setDestinatario('');
+        },
+        onError: (err: any) => toast.error(err?.response?.data?.message || 'No se pudo vender la tarjeta'),
+      },
+    );
+  };
+
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4 space-y-3">
+      <p className="text-[10px] uppercase tracking-wider text-gray-600">Vender tarjeta</p>
+
+      <div className="grid grid-cols-2 gap-2">
+        <label className="flex flex-col gap-1 text-sm text-text-secondary">
+          Monto
+          <input
+            type="number"
+            min={0}
+            value={monto}
+            onChange={(e) => setMonto(e.target.value)}
+            className={`${inputClass} font-mono`}
+          />
+        </label>
+        <label className="flex flex-col gap-1 text-sm text-text-secondary">
+          Vence
+          <input
+            type="date"
+            value={vencimiento}
+            min={hoy()}
+            onChange={(e) => setVencimiento(e.target.value)}
+            className={`${inputClass} font-mono`}
+          />
+        </label>
+      </div>
+
+      <label className="flex flex-col gap-1 text-sm text-text-secondary">
+        Comprador
+        <input type="text" value={comprador} maxLength={80} onChange={(e) => setComprador(e.target.value)} className={inputClass} />
+      </label>
+
+      <label className="flex flex-col gap-1 text-sm text-text-secondary">
+        Para
+        <input type="text" value={destinatario} maxLength={80} onChange={(e) => setDestinatario(e.target.value)} className={inputClass} />
+      </label>
+
+      <p className="text-xs text-gray-500">
+        El monto entra a la caja de hoy. Con la tarjeta se paga después como cualquier otro medio, hasta agotar el saldo.
+      </p>
+
+      <div className="flex justify-end">
+        <button
+          onClick={handleEmitir}
+          disabled={!puedeEmitir}
+          className="btn-primary text-sm !min-h-[42px] px-6 flex items-center gap-2"
+        >
+          {emitir.isPending && <Loader2 size={16} className="animate-spin" />}
+          Vender
+        </button>
+      </div>
+
+      {emitida && (
+        <div className="rounded-xl border border-pink-800/40 bg-pink-950/20 p-3 text-center space-y-1">
+          <p className="text-[10px] uppercase tracking-wider text-pink-400/70">Código</p>
+          <p className="text-2xl font-mono font-bold tracking-widest text-pink-300">{codigoLegible(emitida.codigo)}</p>
+          <p className="text-xs text-gray-400">
+            $ {fmt(emitida.montoInicial)} · vence {fmtDia(emitida.vencimiento)}
+            {emitida.destinatario && ` · para ${emitida.destinatario}`}
+          </p>
+        </div>
+      )}
+    </div>
+  );
+}
+
+// ─── Resumen ──────────────────────────────────────────────────────────────────
+
+function Metrica({ label, cantidad, monto }: { label: string; cantidad?: number; monto: number }) {
+  return (
+    <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4">
+      <p className="text-[10px] uppercase tracking-wider text-gray-600">{label}</p>
+      <p className="text-lg font-mono font-bold text-gray-100 tabular-nums">$ {fmt(monto)}</p>
+      {cantidad !== undefined && <p className="text-xs text-gray-500">{cantidad}</p>}
+    </div>
+  );
+}
+
+// ─── Página ───────────────────────────────────────────────────────────────────
+
+/**
+ * Tarjetas de regalo y vouchers prepagos (HU-144).
+ *
+ * La venta entra a la caja como ingreso del día. Al cobrar una mesa la
+ * tarjeta se usa como medio de pago con su código y descuenta el saldo.
+ * El reporte separa lo emitido, lo usado y lo que venció sin usarse.
+ */
+export default function TarjetasRegaloPage() {
+  const [desde, setDesde] = useState(primeroDelMes);
+  const [hasta, setHasta] = useState(hoy);
+  const { data: reporte, isLoading, isError } = useReporteTarjetasRegalo(desde, hasta);
+
+  return (
+    <section className="min-h-[calc(100vh-4rem)] bg-neutral-900">
+      <div className="w-full px-4 sm:px-6 xl:px-8 py-5 space-y-5">
+        <header className="flex items-center gap-3">
+          <Link
+            to="/caja/historial"
+            className="w-10 h-10 rounded-xl bg-neutral-800 hover:bg-neutral-700 flex items-center justify-center transition-colors"
+          >
+            <ArrowLeft size={18} className="text-gray-400" />
+          </Link>
+          <div>
+            <h1 className="text-xl font-bold text-gray-100 tracking-tight">Tarjetas de regalo</h1>
+            <p className="text-sm text-gray-500">Vouchers vendidos, usados y vencidos</p>
+          </div>
+        </header>
+
+        <div className="grid grid-cols-1 xl:grid-cols-12 gap-5">
+          <div className="xl:col-span-4">
+            <VenderTarjeta />
+          </div>
+
+          <div className="xl:col-span-8 space-y-3">
+            <div className="flex items-center gap-3">
+              <CalendarClock size={14} className="text-gray-500" />
+              <input
+                type="date"
+                value={desde}
+                max={hasta}
+                onChange={(e) => e.target.value && setDesde(e.target.value)}
+                className={inputFecha}
+              />
+              <span className="text-xs text-gray-600">a</span>
+              <input
+                type="date"
+                value={hasta}
+                min={desde}
+                onChange={(e) => e.target.value && setHasta(e.target.value)}
+                className={inputFecha}
+              />
+            </div>
+
+            {isLoading ? (
+              <div className="flex items-center gap-2 text-sm text-gray-500">
+                <Loader2 size={16} className="animate-spin" /> Cargando tarjetas...
+              </div>
+            ) : isError || !reporte ? (
+              <p className="text-sm text-red-400">No se pudo cargar el reporte de tarjetas.</p>
+            ) : (
+              <>
+                <div className="grid grid-cols-2 lg:grid-cols-4 gap-3">
+                  <Metrica label="Emitidas" cantidad={reporte.cantidadEmitidas} monto={reporte.montoEmitido} />
+                  <Metrica label="Usadas" cantidad={reporte.cantidadUsos} monto={reporte.montoUsado} />
+                  <Metrica label="Vencidas sin usar" cantidad={reporte.cantidadVencidas} monto={reporte.montoVencido} />
+                  <Metrica label="Saldo vigente hoy" monto={reporte.saldoVigente} />
+                </div>
+
+                {reporte.tarjetas.length === 0 ? (
+                  <p className="text-xs text-gray-500">No hay movimientos de tarjetas en el período.</p>
+                ) : (
+                  <div className="rounded-2xl border border-neutral-800/60 bg-neutral-900/50 p-4">
+                    <table className="w-full text-sm">
+                      <thead>
+                        <tr className="text-[10px] uppercase tracking-wider text-gray-600">
+                          <th className="text-left font-medium pb-2">Código</th>
+                          <th className="text-left font-medium pb-2">Para</th>
+                          <th className="text-left font-medium pb-2">Emitida</th>
+                          <th className="text-left font-medium pb-2">Vence</th>
+                          <th className="text-right font-medium pb-2">Monto</th>
+                          <th className="text-right font-medium pb-2">Saldo</th>
+                          <th className="text-right font-medium pb-2">Estado</th>
+                        </tr>
+                      </thead>
+                      <tbody className="divide-y divide-neutral-800 text-gray-300">
+                        {reporte.tarjetas.map((t) => (
+                          <tr key={t.id}>
+                            <td className="py-2 font-mono">
+                              <span className="flex items-center gap-2">
+                                <Gift size={13} className="text-gray-500" />
+                                {codigoLegible(t.codigo)}
+                              </span>
+                            </td>
+                            <td className="py-2 text-gray-400">{t.destinatario ?? t.comprador ?? '—'}</td>
+                            <td className="py-2 font-mono text-gray-500">{fmtDia(t.fechaEmision)}</td>
+                            <td className="py-2 font-mono text-gray-500">{fmtDia(t.vencimiento)}</td>
+                            <td className="py-2 text-right font-mono tabular-nums">$ {fmt(t.montoInicial)}</td>
+                            <td className="py-2 text-right font-mono tabular-nums">$ {fmt(t.saldo)}</td>
+                            <td className="py-2 text-right">
+                              <span className={`px-2 py-0.5 rounded-full text-[11px] ${ESTADO_COLORES[t.estado]}`}>
+                                {ESTADO_TARJETA_REGALO_LABELS[t.estado]}
+                              </span>
+                            </td>
+                          </tr>
+                        ))}
+                      </tbody>
+                    </table>
+                  </div>
+                )}
+              </>
+            )}
+          </div>
+        </div>
+      </div>
+    </section>
+  );
+}
@@ -0,0 +1,55 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { tarjetasRegaloApi } from '../api/tarjetasRegaloApi';
+import { LONGITUD_CODIGO, normalizarCodigo } from '../types';
+import type { EmitirTarjetaRegaloRequest, ReporteTarjetasRegalo, TarjetaRegalo } from '../types';
+import { cajaKeys } from '../../caja/hooks/useCaja';
+
+export const tarjetasRegaloKeys = {
+  all: ['tarjetas-regalo'] as const,
+  codigo: (codigo: string) => ['tarjetas-regalo', 'codigo', codigo] as const,
+  reporte: (desde: string, hasta: string) => ['tarjetas-regalo', 'reporte', desde, hasta] as const,
+};
+
+/**
+ * HU-144: Valida el código al cobrar. Solo consulta cuando el código
+ * tiene el largo completo; sin reintentos para que el "no existe" llegue rápido.
+ *
+ * queryKey: ['tarjetas-regalo', 'codigo', codigo]
+ */
+export function useTarjetaRegalo(codigo: string) {
+  const normalizado = normalizarCodigo(codigo);
+  return useQuery<TarjetaRegalo>({
+    queryKey: tarjetasRegaloKeys.codigo(normalizado),
+    queryFn: () => tarjetasRegaloApi.consultar(normalizado),
+    enabled: normalizado.length === LONGITUD_CODIGO,
+    retry: false,
+  });
+}
+
+/**
+ * HU-144: Emitidas, usadas y vencidas en el rango.
+ *
+ * queryKey: ['tarjetas-regalo', 'reporte', desde, hasta]
+ */
+export function useReporteTarjetasRegalo(desde: string, hasta: string) {
+  return useQuery<ReporteTarjetasRegalo>({
+    queryKey: tarjetasRegaloKeys.reporte(desde, hasta),
+    queryFn: () => tarjetasRegaloApi.reporte(desde, hasta),
+    enabled: !!desde && !!hasta && desde <= hasta,
+  });
+}
+
+/**
+ * Vender una tarjeta. Entra un ingreso a la caja del día.
+ */
+export function useEmitirTarjetaRegalo() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (request: EmitirTarjetaRegaloRequest) => tarjetasRegaloApi.emitir(request),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: tarjetasRegaloKeys.all, exact: false });
+      queryClient.invalidateQueries({ queryKey: cajaKeys.all, exact: false });
+    },
+  });
+}
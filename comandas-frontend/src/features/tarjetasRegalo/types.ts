@@ -0,0 +1,58 @@
+/**
+ * Tipos del módulo Tarjetas de regalo (HU-144).
+ * Espejo de los DTOs de TarjetaRegaloController.
+ */
+
+export type EstadoTarjetaRegalo = 'VIGENTE' | 'AGOTADA' | 'VENCIDA';
+
+export const ESTADO_TARJETA_REGALO_LABELS: Record<EstadoTarjetaRegalo, string> = {
+  VIGENTE: 'Vigente',
+  AGOTADA: 'Usada',
+  VENCIDA: 'Vencida',
+};
+
+export interface TarjetaRegalo {
+  id: string;
+  /** 10 caracteres, sin 0/O ni 1/I */
+  codigo: string;
+  montoInicial: number;
+  saldo: number;
+  /** ISO local: YYYY-MM-DDTHH:mm:ss */
+  fechaEmision: string;
+  /** YYYY-MM-DD; sirve hasta ese día inclusive */
+  vencimiento: string;
+  comprador: string | null;
+  destinatario: string | null;
+  estado: EstadoTarjetaRegalo;
+  /** Cantidad de pedidos pagados con la tarjeta */
+  usos: number;
+}
+
+export interface EmitirTarjetaRegaloRequest {
+  monto: number;
+  vencimiento: string;
+  comprador?: string;
+  destinatario?: string;
+}
+
+export interface ReporteTarjetasRegalo {
+  desde: string;
+  hasta: string;
+  cantidadEmitidas: number;
+  montoEmitido: number;
+  cantidadUsos: number;
+  montoUsado: number;
+  cantidadVencidas: number;
+  /** Saldo que quedó sin usar en las tarjetas vencidas */
+  montoVencido: number;
+  /** Saldo de todas las tarjetas vigentes hoy (pasivo con los clientes) */
+  saldoVigente: number;
+  tarjetas: TarjetaRegalo[];
+}
+
+/** Misma normalización que el backend: mayúsculas, sin guiones ni espacios */
+export function normalizarCodigo(codigo: string): string {
+  return codigo.replace(/[\s-]/g, '').toUpperCase();
+}
+
+export const LONGITUD_CODIGO = 10;
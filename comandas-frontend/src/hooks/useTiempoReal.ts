@@ -0,0 +1,191 @@
+import { useEffect } from 'react';
+import { useQueryClient } from '@tanstack/react-query';
+import { API_BASE_URL } from '../lib/apiClient';
+import useToastStore from '../store/useToastStore';
+import type { ProductoResponse } from '../features/catalogo/types';
+import type { SolicitudAutopedido } from '../features/autopedido/types';
+import type { LinkPago } from '../features/linksPago/types';
+import type { PagosACuentaResponse } from '../features/salon/types';
+
+/** Payload de "producto-disponibilidad" (DisponibilidadProductoEvento) */
+interface DisponibilidadProductoEvento {
+  productoId: string;
+  nombre: string;
+  agotado: boolean;
+}
+
+/** Payload de "energia" (EstadoEnergiaEvento, HU-155) */
+interface EstadoEnergiaEvento {
+  estado: 'SIN_RED' | 'APAGANDO' | 'RESTABLECIDA';
+  bateria: number | null;
+  segundosParaApagar: number | null;
+}
+
+/** Payload de "impresora" (EstadoImpresoraEvento, HU-158) */
+interface EstadoImpresoraEvento {
+  estacion: string;
+  estado: 'LISTA' | 'POCO_PAPEL' | 'SIN_PAPEL' | 'TAPA_ABIERTA' | 'ERROR' | 'DESCONECTADA' | 'SIN_RESPUESTA';
+  detalle: string | null;
+}
+
+/**
+ * Canal en tiempo real con el backend (HU-125).
+ *
+ * Abre un EventSource a /api/eventos mientras la app está montada. El
+ * navegador reconecta solo si el backend se reinicia; mientras tanto el
+ * polling habitual de cada query sigue funcionando como respaldo.
+ *
+ * Eventos:
+ * - producto-disponibilidad: un producto se marcó o desmarcó como 86. Se
+ *   actualiza en el acto el catálogo cacheado y se avisa con un toast.
+ * - autopedido-nuevo / autopedido-resuelto (HU-126): llegó un pedido por QR
+ *   o alguien ya lo resolvió. Se refresca la bandeja del salón en todas las
+ *   terminales para que dos mozos no confirmen lo mismo.
+ * - link-pago-pagado (HU-128): el cliente pagó el link de un delivery y el
+ *   backend ya mandó el pedido a cocina.
+ * - energia (HU-155): se cortó la luz en la PC servidor, que sigue con la
+ *   UPS. El aviso queda fijo hasta que vuelve la luz; si el servidor se
+ *   apaga, el canal se corta y los pedidos abiertos quedan guardados.
+ * - impresora (HU-158): la impresora de una estación se quedó sin papel,
+ *   con la tapa abierta o desconectada. El aviso queda fijo hasta que esa
+ *   impresora vuelve a estar lista.
+ * - pago-a-cuenta (HU-160): otra terminal cobró o anuló un pago a cuenta. Se
+ *   refresca el pedido de esa mesa para que el saldo no quede viejo.
+ */
+export function useTiempoReal() {
+  const queryClient = useQueryClient();
+  const addToast = useToastStore((state) => state.addToast);
+  const removeToast = useToastStore((state) => state.removeToast);
+
+  useEffect(() => {
+    const fuente = new EventSource(`${API_BASE_URL}/eventos`);
+
+    const onDisponibilidad = (event: MessageEvent<string>) => {
+      const evento: DisponibilidadProductoEvento = JSON.parse(event.data);
+
+      // Optimista: todas las listas cacheadas reflejan el cambio sin esperar el refetch
+      queryClient.setQueriesData<ProductoResponse[]>({ queryKey: ['productos'] }, (lista) =>
+        Array.isArray(lista)
+          ? lista.map((p) => (p.id === evento.productoId ? { ...p, agotado: evento.agotado } : p))
+          : lista,
+      );
+      queryClient.invalidateQueries({ queryKey: ['productos'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['producto', evento.productoId] });
+
+      addToast(
+        evento.agotado
+          ? { message: `Sin ${evento.nombre} (86)`, type: 'warning' }
+          : { message: `${evento.nombre} disponible de nuevo`, type: 'info' },
+      );
+    };
+
+    const onAutopedidoNuevo = (event: MessageEvent<string>) => {
+      const solicitud: SolicitudAutopedido = JSON.parse(event.data);
+      queryClient.invalidateQueries({ queryKey: ['autopedido', 'pendientes'] });
+      addToast({ message: `Mesa ${solicitud.numeroMesa}: pedido por QR para confirmar`, type: 'warning', duration: 8000 });
+    };
+
+    const onAutopedidoResuelto = (event: MessageEvent<string>) => {
+      const solicitud: SolicitudAutopedido = JSON.parse(event.data);
+      queryClient.invalidateQueries({ queryKey: ['autopedido', 'pendientes'] });
+      if (solicitud.estado === 'CONFIRMADA') {
+        queryClient.invalidateQueries({ queryKey: ['mesas'], exact: false });
+        queryClient.invalidateQueries({ queryKey: ['pedido', solicitud.mesaId] });
+      }
+    };
+
+    const onLinkPagoPagado = (event: MessageEvent<string>) => {
+      const link: LinkPago = JSON.parse(event.data);
+      queryClient.invalidateQueries({ queryKey: ['links-pago'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['mesas'], exact: false });
+      queryClient.invalidateQueries({ queryKey: ['pedido', link.mesaId] });
+      addToast({ message: `Mesa ${link.numeroMesa}: pago acreditado, pasó a cocina`, type: 'success', duration: 8000 });
+    };
+
+    const onPagoACuenta = (event: MessageEvent<string>) => {
+      const evento: PagosACuentaResponse = JSON.parse(event.data);
+      queryClient.setQueryData(['pagos-a-cuenta', evento.mesaId], evento);
+      queryClient.invalidateQueries({ queryKey: ['pedido', evento.mesaId] });
+    };
+
+    // Un solo aviso fijo por corte: cada estado nuevo reemplaza al anterior
+    let avisoEnergia: number | null = null;
+    const onEnergia = (event: MessageEvent<string>) => {
+      const evento: EstadoEnergiaEvento = JSON.parse(event.data);
+      if (avisoEnergia !== null) removeToast(avisoEnergia);
+      avisoEnergia = null;
+
+      if (evento.estado === 'RESTABLECIDA') {
+        addToast({ message: 'Volvió la luz en el servidor', type: 'success', duration: 8000 });
+        return;
+      }
+      if (evento.estado === 'APAGANDO') {
+        avisoEnergia = addToast({
+          message: 'El servidor se apaga por el corte de luz. No cobres ni cargues pedidos hasta que vuelva.',
+          type: 'error',
+          duration: 0,
+        });
+        return;
+      }
+      const bateria = evento.bateria !== null ? ` (batería ${evento.bateria}%)` : '';
+      const plazo =
+        evento.segundosParaApagar !== null
+          ? ` Se apaga en ${Math.max(1, Math.round(evento.segundosParaApagar / 60))} min si no vuelve.`
+          : '';
+      avisoEnergia = addToast({
+        message: `Corte de luz: el servidor sigue con la UPS${bateria}.${plazo} Terminá lo que estés cobrando.`,
+        type: 'warning',
+        duration: 0,
+      });
+    };
+
+    // Un aviso fijo por estación: la cocina sin papel no tapa a la barra
+    const avisosImpresora = new Map<string, number>();
+    const onImpresora = (event: MessageEvent<string>) => {
+      const evento: EstadoImpresoraEvento = JSON.parse(event.data);
+      const clave = evento.estacion.toLowerCase();
+      const anterior = avisosImpresora.get(clave);
+      if (anterior !== undefined) removeToast(anterior);
+      avisosImpresora.delete(clave);
+
+      if (evento.estado === 'LISTA') {
+        // Solo se avisa si antes hubo un problema en esta estación
+        if (anterior !== undefined) {
+          addToast({ message: evento.detalle || `Impresora de ${evento.estacion} lista`, type: 'success', duration: 8000 });
+        }
+        return;
+      }
+      const leve = evento.estado === 'POCO_PAPEL' || evento.estado === 'SIN_RESPUESTA';
+      avisosImpresora.set(
+        clave,
+        addToast({
+          message: evento.detalle || `Problema con la impresora de ${evento.estacion}`,
+          type: leve ? 'warning' : 'error',
+          duration: 0,
+        }),
+      );
+    };
+
+    fuente.addEventListener('producto-disponibilidad', onDisponibilidad as EventListener);
+    fuente.addEventListener('autopedido-nuevo', onAutopedidoNuevo as EventListener);
+    fuente.addEventListener('autopedido-resuelto', onAutopedidoResuelto as EventListener);
+    fuente.addEventListener('link-pago-pagado', onLinkPagoPagado as EventListener);
+    fuente.addEventListener('pago-a-cuenta', onPagoACuenta as EventListener);
+    fuente.addEventListener('energia', onEnergia as EventListener);
+    fuente.addEventListener('impresora', onImpresora as EventListener);
+    fuente.onerror = () => {
+      console.warn('[TiempoReal] Conexión perdida, reintentando...');
+    };
+
+    return () => {
+      fuente.removeEventListener('producto-disponibilidad', onDisponibilidad as EventListener);
+      fuente.removeEventListener('autopedido-nuevo', onAutopedidoNuevo as EventListener);
+      fuente.removeEventListener('autopedido-resuelto', onAutopedidoResuelto as EventListener);
+      fuente.removeEventListener('link-pago-pagado', onLinkPagoPagado as EventListener);
+      fuente.removeEventListener('pago-a-cuenta', onPagoACuenta as EventListener);
+      fuente.removeEventListener('energia', onEnergia as EventListener);
+      fuente.removeEventListener('impresora', onImpresora as EventListener);
+      fuente.close();
+    };
+  }, [queryClient, addToast, removeToast]);
+}
@@ -1,8 +1,12 @@
 import { NavLink, Outlet } from 'react-router-dom';
-import { LayoutGrid, DollarSign, Coffee, Settings } from 'lucide-react';
+import { LayoutGrid, DollarSign, Coffee, BookUser, BarChart3, ChefHat, Clock, Settings } from 'lucide-react';
 import type { LucideIcon } from 'lucide-react';
 import { lazy, Suspense, useState } from 'react';
 import AjustesModal from '../components/AjustesModal';
+import AlertaStockMinimo from '../features/catalogo/components/AlertaStockMinimo';
+import AlertaMesasOlvidadas from '../features/salon/components/AlertaMesasOlvidadas';
+import AlertaVencimientosProveedores from '../features/proveedores/components/AlertaVencimientosProveedores';
+import { useTiempoReal } from '../hooks/useTiempoReal';
 
 // Panel de time-travel: solo se carga en desarrollo (code-split)
 const DevTimeTravelPanel = import.meta.env.DEV
@@ -22,6 +26,10 @@ const navItems: NavItem[] = [
   { to: '/',          icon: LayoutGrid, label: 'Salón' },
   { to: '/caja',      icon: DollarSign, label: 'Caja' },
   { to: '/mostrador', icon: Coffee,     label: 'Mostrador' },
+  { to: '/clientes',  icon: BookUser,   label: 'Clientes' },
+  { to: '/tablero',   icon: BarChart3,  label: 'Tablero' },
+  { to: '/cocina',    icon: ChefHat,    label: 'Cocina' },
+  { to: '/fichaje',   icon: Clock,      label: 'Fichaje' },
 ];
 
 /**
@@ -38,10 +46,13 @@ const navItems: NavItem[] = [
 export default function MainLayout() {
   const [mostrarAjustes, setMostrarAjustes] = useState(false);
 
+  // Una sola conexión SSE por terminal, compartida por todas las pantallas
+  useTiempoReal();
+
   return (
     <div className="min-h-screen bg-neutral-900 flex flex-col">
       {/* ── Navbar Superior ── */}
-      <header className="sticky top-0 z-50 bg-neutral-900 border-b border-gray-800">
+      <header className="sticky top-0 z-50 bg-neutral-900 border-b border-gray-800 print:hidden">
         <nav className="max-w-7xl mx-auto h-16 px-4 flex items-center justify-between">
           {/* Logo / Marca */}
           <span className="text-xl font-bold text-red-500 tracking-tight select-none">
@@ -96,6 +107,15 @@ export default function MainLayout() {
       {/* ── Modal de Ajustes ── */}
       {mostrarAjustes && <AjustesModal onClose={() => setMostrarAjustes(false)} />}
 
+      {/* ── Notificaciones de stock mínimo (HU-101) ── */}
+      <AlertaStockMinimo />
+
+      {/* ── Aviso de mesas sin atención (HU-113) ── */}
+      <AlertaMesasOlvidadas />
+
+      {/* ── Recordatorio de vencimientos a proveedores (HU-131) ── */}
+      <AlertaVencimientosProveedores />
+
       {/* ── Panel de Time-Travel (solo dev) ── */}
       {DevTimeTravelPanel && (
         <Suspense fallback={null}>
@@ -1,5 +1,7 @@
 import axios from 'axios';
 import type { AxiosInstance, InternalAxiosRequestConfig } from 'axios';
+import { obtenerCajaTerminal } from './cajaTerminal';
+import { obtenerSesionEmpleado } from './sesionEmpleado';
 
 // ── Base URL ─────────────────────────────────────────────────────────────────
 // En DEV, usamos '/api' relativo → Vite proxy lo reenvía a http://127.0.0.1:8080
@@ -10,6 +12,9 @@ const resolvedBaseURL = import.meta.env.PROD
   ? (import.meta.env.VITE_API_URL || 'http://localhost:8080/api')
   : '/api';
 
+/** HU-125: Lo usa el canal en tiempo real, que no pasa por axios */
+export const API_BASE_URL = resolvedBaseURL;
+
 // Cliente HTTP base para FoodFlow
 const apiClient: AxiosInstance = axios.create({
   baseURL: resolvedBaseURL,
@@ -19,7 +24,7 @@ const apiClient: AxiosInstance = axios.create({
   },
 });
 
-// ── Interceptor de REQUEST: X-Local-Id + logging de debug ────────────────────
+// ── Interceptor de REQUEST: X-Local-Id, X-Caja-Id, X-Sesion-Empleado + logging
 apiClient.interceptors.request.use(
   (config: InternalAxiosRequestConfig) => {
     const localId = import.meta.env.VITE_LOCAL_ID;
@@ -30,6 +35,18 @@ apiClient.interceptors.request.use(
 
     config.headers['X-Local-Id'] = localId;
 
+    // HU-159: lo que cobra esta terminal va a su caja (sin caja, a la principal)
+    const cajaId = obtenerCajaTerminal();
+    if (cajaId) {
+      config.headers['X-Caja-Id'] = cajaId;
+    }
+
+    // HU-169: el backend resuelve de la sesión quién hace las acciones sensibles
+    const sesion = obtenerSesionEmpleado();
+    if (sesion) {
+      config.headers['X-Sesion-Empleado'] = sesion.token;
+    }
+
     console.info(
       `[API Request] ${config.method?.toUpperCase()} ${config.baseURL}${config.url}`,
       config.data ? JSON.stringify(config.data) : '(sin body)'
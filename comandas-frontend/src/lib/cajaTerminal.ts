@@ -0,0 +1,30 @@
+/**
+ * Caja de esta terminal (HU-159).
+ *
+ * Cuando el local cobra en más de una terminal, cada una se registra como
+ * caja y guarda acá el id. El cliente HTTP lo manda en `X-Caja-Id` y el
+ * backend imputa a esa caja los cobros, egresos e ingresos. Sin caja
+ * guardada, la terminal opera la caja principal.
+ *
+ * Se guarda en el almacenamiento del webview: es de la terminal, no del
+ * usuario, y sobrevive a los reinicios.
+ */
+
+const CLAVE = 'foodflow.cajaTerminal';
+
+export function obtenerCajaTerminal(): string | null {
+  try {
+    return window.localStorage.getItem(CLAVE);
+  } catch {
+    return null;
+  }
+}
+
+/** @param cajaId null vuelve a la caja principal */
+export function guardarCajaTerminal(cajaId: string | null): void {
+  if (cajaId) {
+    window.localStorage.setItem(CLAVE, cajaId);
+  } else {
+    window.localStorage.removeItem(CLAVE);
+  }
+}
@@ -0,0 +1,54 @@
+/**
+ * Utilidades para exportar datos tabulares como CSV.
+ *
+ * El archivo se genera íntegramente en el cliente y se descarga con
+ * Blob URL + anchor click (mismo mecanismo que pdfService), lo que
+ * funciona tanto en navegador como en el WebView de Tauri.
+ *
+ * Se usa `;` como separador porque Excel en es-AR interpreta la coma
+ * como separador decimal, y se antepone BOM para que respete los acentos.
+ */
+
+const SEPARADOR = ';';
+
+function escaparCelda(valor: unknown): string {
+  if (valor === null || valor === undefined) return '';
+  const texto = String(valor);
+  if (/[";\n\r]/.test(texto)) {
+    return `"${texto.replace(/"/g, '""')}"`;
+  }
+  return texto;
+}
+
+/**
+ * Convierte encabezados + filas en el contenido de un CSV.
+ */
+export function generarCsv(encabezados: string[], filas: unknown[][]): string {
+  return [encabezados, ...filas]
+    .map((fila) => fila.map(escaparCelda).join(SEPARADOR))
+    .join('\r\n');
+}
+
+/**
+ * Descarga el contenido como archivo .csv.
+ *
+ * @param contenido - Texto CSV (ver generarCsv)
+ * @param filename  - Nombre del archivo (incluir .csv)
+ */
+export function descargarCsv(contenido: string, filename: string): void {
+  const blob = new Blob(['\uFEFF' + contenido], { type: 'text/csv;charset=utf-8' });
+  const url = URL.createObjectURL(blob);
+
+  const link = document.createElement('a');
+  link.href = url;
+  link.download = filename;
+  link.style.display = 'none';
+
+  document.body.appendChild(link);
+  link.click();
+
+  setTimeout(() => {
+    URL.revokeObjectURL(url);
+    document.body.removeChild(link);
+  }, 200);
+}
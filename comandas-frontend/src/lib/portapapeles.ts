@@ -0,0 +1,51 @@
+/**
+ * Portapapeles para los datos de cobro (HU-156).
+ *
+ * En el escritorio el webview corre en modo kiosco y no tiene permiso para
+ * usar `navigator.clipboard`: se delega en el portapapeles del SO a través
+ * de los comandos de Tauri. En el navegador se usa la API estándar.
+ *
+ * Los errores de Tauri llegan como texto ya traducido y se propagan tal cual.
+ */
+
+/** Mismo tope que el backend para la referencia de un pago */
+const LARGO_MAXIMO_REFERENCIA = 64;
+
+function esTauri(): boolean {
+  return typeof window !== 'undefined' && '__TAURI_INTERNALS__' in window;
+}
+
+export async function copiarAlPortapapeles(texto: string): Promise<void> {
+  if (esTauri()) {
+    const { invoke } = await import('@tauri-apps/api/core');
+    await invoke('copiar_al_portapapeles', { texto });
+    return;
+  }
+  await navigator.clipboard.writeText(texto);
+}
+
+/**
+ * Lee el número de operación o cupón de un comprobante copiado.
+ *
+ * En el escritorio el número se rescata de la línea copiada del home banking
+ * o del posnet; en el navegador se toma el texto pegado tal cual.
+ */
+export async function pegarNumeroOperacion(): Promise<string> {
+  if (esTauri()) {
+    const { invoke } = await import('@tauri-apps/api/core');
+    return invoke<string>('pegar_numero_operacion');
+  }
+  const texto = (await navigator.clipboard.readText()).trim();
+  if (!texto) {
+    throw new Error('El portapapeles está vacío');
+  }
+  return texto.slice(0, LARGO_MAXIMO_REFERENCIA);
+}
+
+/**
+ * Monto para pegar en el home banking o la app del cliente: sin símbolo ni
+ * separador de miles y con coma decimal solo si hay centavos.
+ */
+export function montoParaCopiar(monto: number): string {
+  return Number.isInteger(monto) ? String(monto) : monto.toFixed(2).replace('.', ',');
+}
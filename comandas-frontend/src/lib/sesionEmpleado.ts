@@ -0,0 +1,69 @@
+/**
+ * Empleado identificado en esta terminal (HU-169).
+ *
+ * Con la matriz de permisos activa, quien opera se identifica con su PIN y
+ * se guarda acá lo que devolvió el backend. El cliente HTTP manda el token
+ * de la sesión en `X-Sesion-Empleado` y el backend resuelve con él quién
+ * opera en cada acción sensible; los permisos guardados solo sirven para no
+ * ofrecer lo que va a rechazar. La sesión vence sola en el backend: pasado
+ * `venceEn`, la próxima acción sensible vuelve a pedir el PIN.
+ *
+ * Se guarda en el almacenamiento de la sesión: es de quien opera, no de la
+ * terminal, y no sobrevive a un reinicio.
+ */
+
+/** Acción sensible que el dueño habilita por rol o por empleado */
+export type Permiso =
+  | 'ANULAR_ITEMS'
+  | 'APLICAR_DESCUENTOS'
+  | 'ABRIR_CAJON'
+  | 'VER_REPORTES'
+  | 'MODIFICAR_PRECIOS';
+
+export interface SesionEmpleado {
+  empleadoId: string;
+  nombre: string;
+  rol: string;
+  /** Todas si la matriz está inactiva */
+  permisos: Permiso[];
+  matrizActiva: boolean;
+  /** Token de la sesión en el backend; es lo único que la identifica */
+  token: string;
+  /** ISO 8601, hora local del backend */
+  venceEn: string;
+}
+
+const CLAVE = 'foodflow.sesionEmpleado';
+
+export function obtenerSesionEmpleado(): SesionEmpleado | null {
+  try {
+    const guardada = window.sessionStorage.getItem(CLAVE);
+    return guardada ? (JSON.parse(guardada) as SesionEmpleado) : null;
+  } catch {
+    return null;
+  }
+}
+
+/** @param sesion null cierra la sesión */
+export function guardarSesionEmpleado(sesion: SesionEmpleado | null): void {
+  if (sesion) {
+    window.sessionStorage.setItem(CLAVE, JSON.stringify(sesion));
+  } else {
+    window.sessionStorage.removeItem(CLAVE);
+  }
+}
+
+/** Sin sesión no se sabe: se ofrece la acción y decide el backend */
+export function tienePermiso(permiso: Permiso): boolean {
+  const sesion = obtenerSesionEmpleado();
+  return !sesion || !sesion.matrizActiva || sesion.permisos.includes(permiso);
+}
+
+/**
+ * El backend rechazó la acción porque quien opera no tiene el permiso (o no
+ * se identificó). Hay que identificar a alguien que lo tenga y reintentar.
+ */
+export function esPermisoDenegado(error: unknown): error is { response: { data: { message: string; permiso: Permiso | null } } } {
+  const response = (error as { response?: { status?: number; data?: { codigo?: string } } })?.response;
+  return response?.status === 403 && response.data?.codigo === 'PERMISO_DENEGADO';
+}
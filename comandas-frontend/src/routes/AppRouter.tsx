@@ -6,7 +6,38 @@ import RequiereJornadaAbierta from '../components/RequiereJornadaAbierta';
 import SalonPage from '../features/salon/pages/SalonPage';
 import CajaPage from '../features/caja/components/CajaPage';
 import HistorialJornadasPage from '../features/caja/components/HistorialJornadasPage';
+import ReporteDiferenciasCajaPage from '../features/caja/components/ReporteDiferenciasCajaPage';
+import ReporteEgresosPage from '../features/caja/components/ReporteEgresosPage';
+import RecargosMedioPagoPage from '../features/caja/components/RecargosMedioPagoPage';
+import TiposCambioPage from '../features/caja/components/TiposCambioPage';
+import RedondeoEfectivoPage from '../features/caja/components/RedondeoEfectivoPage';
+import JornadaComercialPage from '../features/caja/components/JornadaComercialPage';
+import SeriesComprobantesPage from '../features/caja/components/SeriesComprobantesPage';
+import ExportacionContablePage from '../features/facturacion/components/ExportacionContablePage';
+import TarjetasRegaloPage from '../features/tarjetasRegalo/components/TarjetasRegaloPage';
+import ClientesPage from '../features/clientes/components/ClientesPage';
+import ReporteSucursalesPage from '../features/sucursales/components/ReporteSucursalesPage';
+import ReporteMozosPage from '../features/mozos/components/ReporteMozosPage';
+import ReporteHorasPage from '../features/mozos/components/ReporteHorasPage';
+import ReporteConsumoPersonalPage from '../features/mozos/components/ReporteConsumoPersonalPage';
+import FichajeTerminalPage from '../features/mozos/components/FichajeTerminalPage';
+import PropinasPage from '../features/mozos/components/PropinasPage';
+import PermisosPage from '../features/mozos/components/PermisosPage';
+import GastosPage from '../features/gastos/components/GastosPage';
+import ProveedoresPage from '../features/proveedores/components/ProveedoresPage';
+import ZonasDeliveryPage from '../features/delivery/components/ZonasDeliveryPage';
+import RepartosPage from '../features/delivery/components/RepartosPage';
+import TomaInventarioPage from '../features/inventario/components/TomaInventarioPage';
+import OrdenesCompraPage from '../features/inventario/components/OrdenesCompraPage';
+import MermasPage from '../features/inventario/components/MermasPage';
+import DashboardPage from '../features/dashboard/components/DashboardPage';
+import KdsPage from '../features/cocina/components/KdsPage';
+import ReporteTiemposCocinaPage from '../features/cocina/components/ReporteTiemposCocinaPage';
 import MostradorPantalla from '../pages/MostradorPantalla';
+import QrMesasPage from '../features/autopedido/components/QrMesasPage';
+import ReservasPage from '../features/reservas/components/ReservasPage';
+import ListaEsperaPage from '../features/listaEspera/components/ListaEsperaPage';
+import ReporteRotacionMesasPage from '../features/salon/components/ReporteRotacionMesasPage';
 
 // Configuración de React Query
 const queryClient = new QueryClient({
@@ -49,6 +80,78 @@ export function AppRouter() {
             {/* Caja: tiene su propio gatekeeper (blank state + apertura) */}
             <Route path="caja" element={<CajaPage />} />
             <Route path="caja/historial" element={<HistorialJornadasPage />} />
+
+            {/* HU-107: Comparativo por sucursal y sincronización */}
+            <Route path="caja/sucursales" element={<ReporteSucursalesPage />} />
+
+            {/* HU-111: Mozos y reporte de desempeño */}
+            <Route path="caja/mozos" element={<ReporteMozosPage />} />
+
+            {/* HU-124: Fichaje de personal y reporte de horas */}
+            <Route path="fichaje" element={<FichajeTerminalPage />} />
+            <Route path="caja/horas" element={<ReporteHorasPage />} />
+
+            {/* HU-132: Tope y resumen mensual de consumo interno del personal */}
+            <Route path="caja/consumo-interno" element={<ReporteConsumoPersonalPage />} />
+
+            {/* HU-141: Pozo de propinas y reparto por turno */}
+            <Route path="caja/propinas" element={<PropinasPage />} />
+
+            {/* HU-169: Matriz de permisos por rol y por empleado */}
+            <Route path="caja/permisos" element={<PermisosPage />} />
+
+            {/* HU-129: Faltantes y sobrantes de caja por responsable */}
+            <Route path="caja/diferencias" element={<ReporteDiferenciasCajaPage />} />
+            {/* HU-130: Egresos por categoría contra el mes anterior */}
+            <Route path="caja/egresos" element={<ReporteEgresosPage />} />
+            {/* HU-143: Recargos por medio de pago */}
+            <Route path="caja/recargos" element={<RecargosMedioPagoPage />} />
+            {/* HU-151: Tipos de cambio del día para cobrar en USD / EUR */}
+            <Route path="caja/tipos-cambio" element={<TiposCambioPage />} />
+            {/* HU-152: Redondeo del efectivo a cobrar */}
+            <Route path="caja/redondeo" element={<RedondeoEfectivoPage />} />
+            {/* HU-153: Hora de corte de la jornada comercial */}
+            <Route path="caja/jornada-comercial" element={<JornadaComercialPage />} />
+            {/* HU-166: Numeración de comprobantes por caja */}
+            <Route path="caja/series" element={<SeriesComprobantesPage />} />
+            {/* HU-167: Exportación al sistema contable */}
+            <Route path="caja/contabilidad" element={<ExportacionContablePage />} />
+            {/* HU-144: Gift cards y vouchers prepagos */}
+            <Route path="caja/tarjetas-regalo" element={<TarjetasRegaloPage />} />
+
+            {/* HU-117: Compras a proveedores y gastos */}
+            <Route path="caja/gastos" element={<GastosPage />} />
+            {/* HU-131: Cuenta corriente de proveedores */}
+            <Route path="caja/proveedores" element={<ProveedoresPage />} />
+            {/* HU-133: Toma de inventario físico con reporte de diferencias */}
+            <Route path="caja/inventario" element={<TomaInventarioPage />} />
+            {/* HU-134: Órdenes de compra sugeridas por stock mínimo */}
+            <Route path="caja/inventario/compras" element={<OrdenesCompraPage />} />
+            {/* HU-135: Registro de mermas y reporte mensual valorizado */}
+            <Route path="caja/inventario/mermas" element={<MermasPage />} />
+            {/* HU-139: Zonas de delivery con costo de envío */}
+            <Route path="caja/delivery" element={<ZonasDeliveryPage />} />
+            {/* HU-140: Repartidores, rendición de efectivo y tiempos por cadete */}
+            <Route path="caja/repartos" element={<RepartosPage />} />
+
+            {/* HU-126: Hoja de QR de autopedido para pegar en las mesas */}
+            <Route path="salon/qr" element={<QrMesasPage />} />
+            {/* HU-142: Reservas con seña */}
+            <Route path="salon/reservas" element={<ReservasPage />} />
+            {/* HU-149: Lista de espera de mesas */}
+            <Route path="salon/espera" element={<ListaEsperaPage />} />
+            {/* HU-150: Rotación de mesas por franja horaria */}
+            <Route path="salon/rotacion" element={<ReporteRotacionMesasPage />} />
+
+            {/* HU-104: Cuentas corrientes de clientes */}
+            <Route path="clientes" element={<ClientesPage />} />
+
+            {/* HU-114: Tablero de ventas en vivo */}
+            <Route path="tablero" element={<DashboardPage />} />
+
+            {/* HU-119: Pantalla de cocina y tiempos por franja horaria */}
+            <Route path="cocina" element={<KdsPage />} />
+            <Route path="cocina/reporte" element={<ReporteTiemposCocinaPage />} />
           </Route>
         </Routes>
       </BrowserRouter>